@@ -219,16 +219,10 @@ fn create_addressing_style() -> AddressingStyle {
 }
 
 fn create_presigned_req_option(expires_in: Option<Duration>) -> PreSignedRequestOption {
-    if let Some(expires_in) = expires_in {
-        PreSignedRequestOption {
-            expires_in,
-            addressing_style: create_addressing_style(),
-        }
-    } else {
-        PreSignedRequestOption {
-            addressing_style: create_addressing_style(),
-            ..Default::default()
-        }
+    let builder = PreSignedRequestOption::builder().addressing_style(create_addressing_style());
+    match expires_in {
+        Some(expires_in) => builder.expires_in(expires_in).build(),
+        None => builder.build(),
     }
 }
 
@@ -1294,20 +1288,22 @@ async fn test_put_object_with_presigned_url(
     bucket: &str,
     filename: &str,
 ) {
+    let body = r#"{"test":"data"}"#;
+    // Without a length, the URL is only good for an empty object.
     let req = PutObjectRequest {
         bucket: bucket.to_owned(),
         key: filename.to_owned(),
+        content_length: Some(body.len() as i64),
         ..Default::default()
     };
     let presigned_url = req.get_presigned_url(region, credentials, &create_presigned_req_option(None))
         .expect("Creating presigned url failed");
     println!("put object presigned url: {:#?}", presigned_url);
-    let mut map = HashMap::new();
-    map.insert("test", "data");
     let client = create_reqwest_client();
     let res = client
         .put(&presigned_url)
-        .json(&map)
+        .header("Content-Type", "application/json")
+        .body(body)
         .send()
         .await
         .expect("Put obj with presigned url failed");
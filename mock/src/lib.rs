@@ -34,13 +34,14 @@
 #![deny(missing_docs)]
 use std::fs::File;
 use std::io::Read;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
 use futures::FutureExt;
 use http::{header::HeaderName, HeaderMap, StatusCode};
-use rusoto_core::credential::{AwsCredentials, ProvideAwsCredentials};
+use rusoto_core::credential::{AwsCredentials, Clock, ProvideAwsCredentials};
 use rusoto_core::request::HttpResponse;
 use rusoto_core::signature::SignedRequest;
 use rusoto_core::{ByteStream, DispatchSignedRequest, HttpDispatchError};
@@ -60,6 +61,49 @@ impl ProvideAwsCredentials for MockCredentialsProvider {
     }
 }
 
+/// A clock that only moves when told to
+///
+/// Clones share the same time, so a clone can be handed to a client or
+/// `AutoRefreshingProvider` and the original advanced to expire credentials or
+/// presigned URLs without waiting.
+#[derive(Clone, Debug)]
+pub struct MockClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl MockClock {
+    /// Creates a clock stopped at `now`
+    pub fn new(now: DateTime<Utc>) -> MockClock {
+        MockClock {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    /// Sets the time of the clock and its clones
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    /// Moves the clock and its clones forward by `duration`
+    pub fn advance(&self, duration: Duration) {
+        let duration = ChronoDuration::from_std(duration).expect("duration out of range");
+        let mut now = self.now.lock().unwrap();
+        *now += duration;
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> MockClock {
+        MockClock::new(Utc::now())
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
+
 /// Composes mock API responses
 ///
 /// A Default is provided which returns an successful response with an empty body
@@ -132,9 +176,12 @@ impl MockRequestDispatcher {
     }
 
     /// Mocks a single service header that would be returned from AWS
+    ///
+    /// Mocking the same header again adds another value, as when AWS sends a header
+    /// more than once.
     pub fn with_header(mut self, key: &str, value: &str) -> MockRequestDispatcher {
         self.headers
-            .insert(key.parse::<HeaderName>().unwrap(), value.into());
+            .append(key.parse::<HeaderName>().unwrap(), value.into());
         self
     }
 }
@@ -1,15 +1,20 @@
 use std::sync::{Arc, Mutex, Weak};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::credential::{
-    Anonymous, CredentialsError, DefaultCredentialsProvider, ProvideAwsCredentials, StaticProvider,
+    Anonymous, Clock, CredentialsError, CredentialsErrorKind, DefaultCredentialsProvider,
+    ProvideAwsCredentials, StaticProvider, SystemClock,
 };
 use crate::encoding::ContentEncoding;
+use crate::error::RusotoError;
+use crate::metrics::MetricsSink;
 use crate::request::{DispatchSignedRequest, HttpClient, HttpDispatchError, HttpResponse};
-use crate::signature::SignedRequest;
+use crate::retry::{self, AdaptiveRateLimiter, Outcome, RetryMode, RetryPolicy};
+use crate::signature::{SessionTokenSigning, SignedRequest};
 
 use async_trait::async_trait;
 use lazy_static::lazy_static;
+use tokio::sync::Semaphore;
 use tokio::time;
 
 lazy_static! {
@@ -21,6 +26,13 @@ lazy_static! {
 #[derive(Clone)]
 pub struct Client {
     inner: Arc<dyn SignAndDispatch + Send + Sync>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<Arc<AdaptiveRateLimiter>>,
+    clock: Arc<dyn Clock>,
+    idempotency_tokens: bool,
+    in_flight: Option<Arc<Semaphore>>,
+    session_token_signing: Option<SessionTokenSigning>,
+    metrics_sink: Option<Arc<dyn MetricsSink>>,
 }
 
 impl Client {
@@ -28,7 +40,7 @@ impl Client {
     pub fn shared() -> Self {
         let mut lock = SHARED_CLIENT.lock().unwrap();
         if let Some(inner) = lock.upgrade() {
-            return Client { inner };
+            return Client::from_inner(inner);
         }
         let credentials_provider =
             DefaultCredentialsProvider::new().expect("failed to create credentials provider");
@@ -39,7 +51,7 @@ impl Client {
             content_encoding: Default::default(),
         });
         *lock = Arc::downgrade(&inner);
-        Client { inner }
+        Client::from_inner(inner)
     }
 
     /// Create a client from a credentials provider and request dispatcher.
@@ -53,9 +65,7 @@ impl Client {
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
         };
-        Client {
-            inner: Arc::new(inner),
-        }
+        Client::from_inner(Arc::new(inner))
     }
 
     /// Create a client from a request dispatcher without a credentials provider. The client will
@@ -72,9 +82,7 @@ impl Client {
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
         };
-        Client {
-            inner: Arc::new(inner),
-        }
+        Client::from_inner(Arc::new(inner))
     }
 
     #[cfg(feature = "encoding")]
@@ -93,20 +101,203 @@ impl Client {
             dispatcher: Arc::new(dispatcher),
             content_encoding,
         };
+        Client::from_inner(Arc::new(inner))
+    }
+
+    fn from_inner(inner: Arc<dyn SignAndDispatch + Send + Sync>) -> Self {
         Client {
-            inner: Arc::new(inner),
+            inner,
+            retry_policy: RetryPolicy::default(),
+            rate_limiter: None,
+            clock: Arc::new(SystemClock),
+            idempotency_tokens: false,
+            in_flight: None,
+            session_token_signing: None,
+            metrics_sink: None,
         }
+        .with_retry_policy(RetryPolicy::from_env())
+    }
+
+    /// Replace the retry policy, which is read from the `AWS_RETRY_MODE` and
+    /// `AWS_MAX_ATTEMPTS` environment variables by default.
+    ///
+    /// With `RetryMode::Adaptive`, the client gets its own rate limiter, which is shared
+    /// with its clones.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.rate_limiter = match retry_policy.mode() {
+            RetryMode::Adaptive => Some(Arc::new(AdaptiveRateLimiter::new())),
+            RetryMode::Legacy | RetryMode::Standard => None,
+        };
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// The policy used to retry failed requests.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Replace the clock used to date signed requests, which is the `SystemClock` by
+    /// default.
+    ///
+    /// This doesn't affect when credentials are refreshed; pass the same clock to
+    /// `AutoRefreshingProvider::with_clock` for that.
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Fill in idempotency tokens that are left empty, so that a request the client
+    /// retries isn't carried out twice. Off by default.
+    ///
+    /// This affects the operations whose service model marks a member of the input as an
+    /// idempotency token, e.g. the `client_token` of EC2 `RunInstances` or the
+    /// `client_request_token` of CloudFormation `CreateStackSet`; the documentation of
+    /// those members says so, and the changelog lists the operations. A random UUID is
+    /// generated when the request is built, and retries resend the same request, so they
+    /// reuse the token.
+    pub fn with_idempotency_tokens(mut self, enabled: bool) -> Self {
+        self.idempotency_tokens = enabled;
+        self
     }
 
-    /// Fetch credentials, sign the request and dispatch it.
+    /// Limit the number of requests the client and its clones dispatch at the same time;
+    /// further requests wait for one of them to finish. Unlimited by default.
+    ///
+    /// A request counts until its response arrives, not while its body is read, and
+    /// doesn't count while it waits to be retried.
+    ///
+    /// # Panics
+    ///
+    /// If `max_in_flight` is zero.
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        assert!(max_in_flight > 0, "max_in_flight must be greater than zero");
+        self.in_flight = Some(Arc::new(Semaphore::new(max_in_flight)));
+        self
+    }
+
+    /// Set whether the session token of temporary credentials is signed, overriding the
+    /// `session_token_signing` of the requests the client sends.
+    ///
+    /// This is a compatibility shim for S3 compatible gateways that reject signed session
+    /// tokens; AWS requires them to be signed, which is what requests do by default.
+    pub fn with_session_token_signing(
+        mut self,
+        session_token_signing: SessionTokenSigning,
+    ) -> Self {
+        self.session_token_signing = Some(session_token_signing);
+        self
+    }
+
+    /// Report the retries of the client, and the requests it could not sign for lack of
+    /// credentials, to `sink`. See `MetricsSink` for the events of the requests
+    /// themselves, which the `HttpClient` reports.
+    pub fn with_metrics_sink<S>(mut self, sink: S) -> Self
+    where
+        S: MetricsSink + 'static,
+    {
+        self.metrics_sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// A new idempotency token if the client fills them in, see `with_idempotency_tokens`.
+    pub fn idempotency_token(&self) -> Option<String> {
+        if self.idempotency_tokens {
+            Some(generate_idempotency_token())
+        } else {
+            None
+        }
+    }
+
+    /// Fetch credentials, sign the request and dispatch it, retrying it according to
+    /// the client's retry policy.
+    ///
+    /// If the service rejects the credentials as expired, e.g. with `ExpiredToken`, they
+    /// are refreshed with `ProvideAwsCredentials::force_refresh` and the request is sent
+    /// once more with the new ones. This happens at most once per request, and doesn't
+    /// count as an attempt of the retry policy.
     pub async fn sign_and_dispatch(
         &self,
-        request: SignedRequest,
+        mut request: SignedRequest,
     ) -> Result<HttpResponse, SignAndDispatchError> {
-        self.inner.sign_and_dispatch(request, None).await
+        if let Some(session_token_signing) = self.session_token_signing {
+            request.session_token_signing = session_token_signing;
+        }
+        let service = request.service.clone();
+        let mut attempt = 1;
+        let mut refreshed_credentials = false;
+        loop {
+            // Keep an unsigned copy around to resend, unless this is the last attempt.
+            let next = if attempt < self.retry_policy.max_attempts() || !refreshed_credentials {
+                request.try_clone()
+            } else {
+                None
+            };
+            if let Some(ref rate_limiter) = self.rate_limiter {
+                time::sleep(rate_limiter.acquire()).await;
+            }
+            let permit = match self.in_flight {
+                // The semaphore is never closed.
+                Some(ref in_flight) => Some(in_flight.acquire().await.unwrap()),
+                None => None,
+            };
+            let started_at = Instant::now();
+            let result = self
+                .inner
+                .sign_and_dispatch(request, None, self.clock.clone())
+                .await;
+            drop(permit);
+            if let (Some(ref sink), Err(SignAndDispatchError::Credentials(ref err))) =
+                (&self.metrics_sink, &result)
+            {
+                let error = RusotoError::Credentials(err.clone());
+                sink.on_error(&service, &error, started_at.elapsed());
+            }
+            let (result, outcome) = retry::classify(result).await;
+            if let Some(ref rate_limiter) = self.rate_limiter {
+                rate_limiter.update(outcome == Outcome::Throttled);
+            }
+            match next {
+                Some(next) if outcome == Outcome::ExpiredCredentials && !refreshed_credentials => {
+                    refreshed_credentials = true;
+                    if self.inner.force_refresh().await {
+                        request = next;
+                        continue;
+                    }
+                    return result;
+                }
+                Some(next)
+                    if outcome.is_retryable() && attempt < self.retry_policy.max_attempts() =>
+                {
+                    request = next
+                }
+                _ => return result,
+            }
+            if let Some(ref sink) = self.metrics_sink {
+                sink.on_retry(&service, attempt);
+            }
+            time::sleep(self.retry_policy.backoff(attempt)).await;
+            attempt += 1;
+        }
     }
 }
 
+/// A random (version 4) UUID.
+fn generate_idempotency_token() -> String {
+    let mut bytes: [u8; 16] = rand::random();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
 /// Error that occurs during `sign_and_dispatch`
 #[derive(Debug, PartialEq)]
 pub enum SignAndDispatchError {
@@ -122,7 +313,12 @@ trait SignAndDispatch {
         &self,
         request: SignedRequest,
         timeout: Option<Duration>,
+        clock: Arc<dyn Clock>,
     ) -> Result<HttpResponse, SignAndDispatchError>;
+
+    /// Discards cached credentials. Returns false if requests aren't signed, so there
+    /// are none to refresh.
+    async fn force_refresh(&self) -> bool;
 }
 
 struct ClientInner<P, D> {
@@ -145,6 +341,7 @@ async fn sign_and_dispatch<P, D>(
     client: ClientInner<P, D>,
     mut request: SignedRequest,
     timeout: Option<Duration>,
+    clock: Arc<dyn Clock>,
 ) -> Result<HttpResponse, SignAndDispatchError>
 where
     P: ProvideAwsCredentials + Send + Sync + 'static,
@@ -155,8 +352,11 @@ where
         let credentials = if let Some(to) = timeout {
             time::timeout(to, provider.credentials())
                 .await
-                .map_err(|_| CredentialsError {
-                    message: "Timeout getting credentials".to_owned(),
+                .map_err(|_| {
+                    CredentialsError::with_kind(
+                        CredentialsErrorKind::Network,
+                        "Timeout getting credentials",
+                    )
                 })
                 .and_then(std::convert::identity)
         } else {
@@ -166,7 +366,8 @@ where
         if credentials.is_anonymous() {
             request.complement();
         } else {
-            request.sign(&credentials)
+            request
+                .sign_at(&credentials, clock.now().into())
                 .map_err(|e| SignAndDispatchError::Credentials(CredentialsError::from(e)))?;
         }
     } else {
@@ -189,8 +390,19 @@ where
         &self,
         request: SignedRequest,
         timeout: Option<Duration>,
+        clock: Arc<dyn Clock>,
     ) -> Result<HttpResponse, SignAndDispatchError> {
-        sign_and_dispatch(self.clone(), request, timeout).await
+        sign_and_dispatch(self.clone(), request, timeout, clock).await
+    }
+
+    async fn force_refresh(&self) -> bool {
+        match self.credentials_provider {
+            Some(ref provider) => {
+                provider.force_refresh().await;
+                true
+            }
+            None => false,
+        }
     }
 }
 
@@ -200,3 +412,443 @@ fn client_is_send_and_sync() {
 
     is_send_and_sync::<Client>();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credential::AwsCredentials;
+    use crate::request::DispatchSignedRequestFuture;
+    use crate::retry::RetryMode;
+    use crate::signature::SignedRequestPayload;
+    use crate::{ByteStream, Region};
+    use bytes::Bytes;
+    use futures::FutureExt;
+    use http::{HeaderMap, StatusCode};
+    use std::convert::Infallible;
+
+    struct StatusDispatcher {
+        statuses: Mutex<Vec<u16>>,
+    }
+
+    impl StatusDispatcher {
+        fn new(mut statuses: Vec<u16>) -> Self {
+            statuses.reverse();
+            StatusDispatcher {
+                statuses: Mutex::new(statuses),
+            }
+        }
+
+        fn remaining(&self) -> usize {
+            self.statuses.lock().unwrap().len()
+        }
+    }
+
+    impl DispatchSignedRequest for StatusDispatcher {
+        fn dispatch(
+            &self,
+            _request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let status = self
+                .statuses
+                .lock()
+                .unwrap()
+                .pop()
+                .expect("no more responses");
+            futures::future::ready(Ok(HttpResponse {
+                status: StatusCode::from_u16(status).unwrap(),
+                body: ByteStream::from(Vec::new()),
+                headers: HeaderMap::<String>::default(),
+            }))
+            .boxed()
+        }
+    }
+
+    #[test]
+    fn idempotency_tokens_are_opt_in_uuids() {
+        let client = Client::new_not_signing(StatusDispatcher::new(vec![]));
+        assert_eq!(client.idempotency_token(), None);
+
+        let client = client.with_idempotency_tokens(true);
+        let token = client.idempotency_token().unwrap();
+        let groups: Vec<usize> = token.split('-').map(str::len).collect();
+        assert_eq!(groups, [8, 4, 4, 4, 12]);
+        assert!(token.chars().all(|c| c == '-' || c.is_ascii_hexdigit()));
+        assert_eq!(&token[14..15], "4");
+        assert!("89ab".contains(&token[19..20]));
+        assert_ne!(client.idempotency_token().unwrap(), token);
+    }
+
+    #[tokio::test]
+    async fn retries_resend_the_same_idempotency_token() {
+        struct BodyRecorder {
+            bodies: Mutex<Vec<Bytes>>,
+        }
+
+        impl DispatchSignedRequest for BodyRecorder {
+            fn dispatch(
+                &self,
+                request: SignedRequest,
+                _timeout: Option<Duration>,
+            ) -> DispatchSignedRequestFuture {
+                let mut bodies = self.bodies.lock().unwrap();
+                if let Some(SignedRequestPayload::Buffer(ref body)) = request.payload {
+                    bodies.push(body.clone());
+                }
+                let status = if bodies.len() == 1 { 503 } else { 200 };
+                futures::future::ready(Ok(HttpResponse {
+                    status: StatusCode::from_u16(status).unwrap(),
+                    body: ByteStream::from(Vec::new()),
+                    headers: HeaderMap::<String>::default(),
+                }))
+                .boxed()
+            }
+        }
+
+        let dispatcher = Arc::new(BodyRecorder {
+            bodies: Mutex::new(Vec::new()),
+        });
+        let client = Client::new_not_signing(dispatcher.clone())
+            .with_retry_policy(RetryPolicy::new(RetryMode::Standard))
+            .with_idempotency_tokens(true);
+        let mut request = SignedRequest::new("POST", "ec2", &Region::UsEast1, "/");
+        let token = client.idempotency_token().unwrap();
+        request.set_payload(Some(format!("ClientToken={}", token)));
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+
+        let bodies = dispatcher.bodies.lock().unwrap();
+        assert_eq!(bodies.len(), 2);
+        assert_eq!(bodies[0], format!("ClientToken={}", token));
+        assert_eq!(bodies[0], bodies[1]);
+    }
+
+    #[tokio::test]
+    async fn max_in_flight_bounds_concurrent_dispatches() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[derive(Default)]
+        struct ConcurrencyTracker {
+            in_flight: AtomicUsize,
+            max_in_flight: AtomicUsize,
+            dispatched: AtomicUsize,
+        }
+
+        impl DispatchSignedRequest for Arc<ConcurrencyTracker> {
+            fn dispatch(
+                &self,
+                _request: SignedRequest,
+                _timeout: Option<Duration>,
+            ) -> DispatchSignedRequestFuture {
+                let tracker = self.clone();
+                async move {
+                    let in_flight = tracker.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    tracker.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+                    time::sleep(Duration::from_millis(10)).await;
+                    tracker.in_flight.fetch_sub(1, Ordering::SeqCst);
+                    tracker.dispatched.fetch_add(1, Ordering::SeqCst);
+                    Ok(HttpResponse {
+                        status: StatusCode::OK,
+                        body: ByteStream::from(Vec::new()),
+                        headers: HeaderMap::<String>::default(),
+                    })
+                }
+                .boxed()
+            }
+        }
+
+        let tracker = Arc::new(ConcurrencyTracker::default());
+        let client = Client::new_not_signing(tracker.clone()).with_max_in_flight(3);
+        let burst = (0..20).map(|_| {
+            let client = client.clone();
+            async move {
+                let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+                client.sign_and_dispatch(request).await.unwrap()
+            }
+        });
+        futures::future::join_all(burst).await;
+        assert_eq!(tracker.dispatched.load(Ordering::SeqCst), 20);
+        assert_eq!(tracker.max_in_flight.load(Ordering::SeqCst), 3);
+
+        let tracker = Arc::new(ConcurrencyTracker::default());
+        let client = Client::new_not_signing(tracker.clone());
+        let burst = (0..20).map(|_| {
+            let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+            client.sign_and_dispatch(request)
+        });
+        futures::future::join_all(burst).await;
+        assert_eq!(tracker.max_in_flight.load(Ordering::SeqCst), 20);
+    }
+
+    #[tokio::test]
+    async fn overrides_session_token_signing() {
+        struct TokenHeaderRecorder {
+            requests: Mutex<Vec<(bool, String)>>,
+        }
+
+        impl DispatchSignedRequest for TokenHeaderRecorder {
+            fn dispatch(
+                &self,
+                request: SignedRequest,
+                _timeout: Option<Duration>,
+            ) -> DispatchSignedRequestFuture {
+                let authorization = &request.headers()["authorization"][0];
+                self.requests.lock().unwrap().push((
+                    request.headers().contains_key("x-amz-security-token"),
+                    String::from_utf8(authorization.clone()).unwrap(),
+                ));
+                futures::future::ready(Ok(HttpResponse {
+                    status: StatusCode::OK,
+                    body: ByteStream::from(Vec::new()),
+                    headers: HeaderMap::<String>::default(),
+                }))
+                .boxed()
+            }
+        }
+
+        let credentials = StaticProvider::new(
+            "access_key".to_owned(),
+            "secret_key".to_owned(),
+            Some("token".to_owned()),
+            None,
+        );
+        let dispatcher = Arc::new(TokenHeaderRecorder {
+            requests: Mutex::new(Vec::new()),
+        });
+        let client = Client::new_with(credentials, dispatcher.clone());
+        for client in &[
+            client.clone(),
+            client.with_session_token_signing(SessionTokenSigning::Unsigned),
+        ] {
+            let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+            client.sign_and_dispatch(request).await.unwrap();
+        }
+
+        let requests = dispatcher.requests.lock().unwrap();
+        assert!(requests[0].0 && requests[1].0);
+        assert!(requests[0].1.contains(";x-amz-security-token,"));
+        assert!(!requests[1].1.contains("x-amz-security-token"));
+    }
+
+    #[tokio::test]
+    async fn retries_transient_errors() {
+        let dispatcher = Arc::new(StatusDispatcher::new(vec![503, 500, 200]));
+        let client = Client::new_not_signing(dispatcher.clone())
+            .with_retry_policy(RetryPolicy::new(RetryMode::Standard));
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(dispatcher.remaining(), 0);
+
+        let dispatcher = Arc::new(StatusDispatcher::new(vec![503, 503, 503, 200]));
+        let client = Client::new_not_signing(dispatcher.clone())
+            .with_retry_policy(RetryPolicy::new(RetryMode::Standard).with_max_attempts(2));
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(dispatcher.remaining(), 2);
+    }
+
+    #[tokio::test]
+    async fn metrics_sink_is_notified_of_retries_and_credential_errors() {
+        #[derive(Default)]
+        struct EventSink {
+            retries: Mutex<Vec<(String, u32)>>,
+            errors: Mutex<Vec<(String, String)>>,
+        }
+
+        impl MetricsSink for Arc<EventSink> {
+            fn on_retry(&self, service: &str, attempt: u32) {
+                self.retries
+                    .lock()
+                    .unwrap()
+                    .push((service.to_owned(), attempt));
+            }
+
+            fn on_error(&self, service: &str, error: &RusotoError<Infallible>, _elapsed: Duration) {
+                assert!(matches!(error, RusotoError::Credentials(_)));
+                self.errors
+                    .lock()
+                    .unwrap()
+                    .push((service.to_owned(), error.to_string()));
+            }
+        }
+
+        struct NoCredentials;
+
+        #[async_trait]
+        impl ProvideAwsCredentials for NoCredentials {
+            async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+                Err(CredentialsError::new("no credentials"))
+            }
+        }
+
+        let sink = Arc::new(EventSink::default());
+        let client = Client::new_not_signing(StatusDispatcher::new(vec![503, 500, 200]))
+            .with_retry_policy(RetryPolicy::new(RetryMode::Standard))
+            .with_metrics_sink(sink.clone());
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+        client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(
+            *sink.retries.lock().unwrap(),
+            [("s3".to_owned(), 1), ("s3".to_owned(), 2)]
+        );
+        assert!(sink.errors.lock().unwrap().is_empty());
+
+        let sink = Arc::new(EventSink::default());
+        let client = Client::new_with(NoCredentials, StatusDispatcher::new(vec![]))
+            .with_metrics_sink(sink.clone());
+        let request = SignedRequest::new("GET", "sqs", &Region::UsEast1, "/");
+        assert!(client.sign_and_dispatch(request).await.is_err());
+        assert!(sink.retries.lock().unwrap().is_empty());
+        assert_eq!(
+            *sink.errors.lock().unwrap(),
+            [("sqs".to_owned(), "no credentials".to_owned())]
+        );
+    }
+
+    #[tokio::test]
+    async fn adaptive_mode_rate_limits_after_throttling() {
+        let dispatcher = Arc::new(StatusDispatcher::new(vec![200, 429, 200]));
+        let client = Client::new_not_signing(dispatcher.clone())
+            .with_retry_policy(RetryPolicy::new(RetryMode::Adaptive));
+        let rate_limiter = client.rate_limiter.clone().unwrap();
+
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+        client.sign_and_dispatch(request).await.unwrap();
+        assert!(!rate_limiter.is_enabled());
+
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert!(rate_limiter.is_enabled());
+        assert_eq!(dispatcher.remaining(), 0);
+
+        let client = client.with_retry_policy(RetryPolicy::new(RetryMode::Standard));
+        assert!(client.rate_limiter.is_none());
+    }
+
+    #[tokio::test]
+    async fn signs_requests_with_the_client_clock() {
+        use chrono::{DateTime, TimeZone, Utc};
+
+        #[derive(Debug)]
+        struct FixedClock(DateTime<Utc>);
+
+        impl Clock for FixedClock {
+            fn now(&self) -> DateTime<Utc> {
+                self.0
+            }
+        }
+
+        struct DateDispatcher;
+
+        impl DispatchSignedRequest for DateDispatcher {
+            fn dispatch(
+                &self,
+                request: SignedRequest,
+                _timeout: Option<Duration>,
+            ) -> DispatchSignedRequestFuture {
+                assert_eq!(
+                    request.headers()["x-amz-date"],
+                    vec![b"20200913T122640Z".to_vec()]
+                );
+                futures::future::ready(Ok(HttpResponse {
+                    status: StatusCode::OK,
+                    body: ByteStream::from(Vec::new()),
+                    headers: HeaderMap::<String>::default(),
+                }))
+                .boxed()
+            }
+        }
+
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials, DateDispatcher)
+            .with_clock(FixedClock(Utc.timestamp_opt(1_600_000_000, 0).unwrap()));
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+        client.sign_and_dispatch(request).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn refreshes_expired_credentials_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // Responds with the given error codes in order, then with 200s.
+        struct CodeDispatcher(Mutex<Vec<&'static str>>);
+
+        impl DispatchSignedRequest for CodeDispatcher {
+            fn dispatch(
+                &self,
+                _request: SignedRequest,
+                _timeout: Option<Duration>,
+            ) -> DispatchSignedRequestFuture {
+                let response = match self.0.lock().unwrap().pop() {
+                    Some(code) => HttpResponse {
+                        status: StatusCode::FORBIDDEN,
+                        body: ByteStream::from(
+                            format!("<Error><Code>{}</Code></Error>", code).into_bytes(),
+                        ),
+                        headers: HeaderMap::<String>::default(),
+                    },
+                    None => HttpResponse {
+                        status: StatusCode::OK,
+                        body: ByteStream::from(Vec::new()),
+                        headers: HeaderMap::<String>::default(),
+                    },
+                };
+                futures::future::ready(Ok(response)).boxed()
+            }
+        }
+
+        #[derive(Default)]
+        struct CountingProvider {
+            refreshes: AtomicUsize,
+        }
+
+        #[async_trait]
+        impl ProvideAwsCredentials for CountingProvider {
+            async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+                Ok(AwsCredentials::new("key", "secret", None, None))
+            }
+
+            async fn force_refresh(&self) {
+                self.refreshes.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let send = |codes: Vec<&'static str>| async move {
+            let provider = Arc::new(CountingProvider::default());
+            let client = Client::new_with(provider.clone(), CodeDispatcher(Mutex::new(codes)));
+            let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+            let status = client.sign_and_dispatch(request).await.unwrap().status;
+            (status, provider.refreshes.load(Ordering::SeqCst))
+        };
+
+        assert_eq!(send(vec!["ExpiredToken"]).await, (StatusCode::OK, 1));
+        assert_eq!(send(vec!["InvalidAccessKeyId"]).await, (StatusCode::OK, 1));
+        // a second expiry in a row is returned instead of looping
+        assert_eq!(
+            send(vec!["ExpiredToken", "ExpiredToken"]).await,
+            (StatusCode::FORBIDDEN, 1)
+        );
+        assert_eq!(send(vec!["AccessDenied"]).await, (StatusCode::FORBIDDEN, 0));
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_client_errors_or_streams() {
+        let dispatcher = Arc::new(StatusDispatcher::new(vec![403, 200]));
+        let client = Client::new_not_signing(dispatcher.clone());
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::FORBIDDEN);
+        assert_eq!(dispatcher.remaining(), 1);
+
+        let dispatcher = Arc::new(StatusDispatcher::new(vec![503, 200]));
+        let client = Client::new_not_signing(dispatcher.clone());
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/");
+        request.set_payload_stream(ByteStream::from(b"body".to_vec()));
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(dispatcher.remaining(), 1);
+    }
+}
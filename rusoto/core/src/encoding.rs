@@ -1,3 +1,5 @@
+//! Request payload encoding, plus base64 and hex helpers for checksum and ETag workflows.
+
 use crate::signature::SignedRequest;
 #[cfg(feature = "encoding")]
 use crate::signature::SignedRequestPayload;
@@ -6,9 +8,10 @@ use flate2::{write::GzEncoder, Compression};
 #[cfg(feature = "encoding")]
 use std::io::Write;
 
-// Default compression level for gzip defined same as flate2
+/// Default compression level for gzip defined same as flate2
 pub const DEFAULT_GZIP_COMPRESSION_LEVEL: u32 = 6;
 
+/// Compression applied to request payloads before they are signed and sent.
 #[derive(Debug, Clone)]
 pub enum ContentEncoding {
     /// Indicates the identity function (i.e., no compression or modification)
@@ -33,6 +36,9 @@ impl Default for ContentEncoding {
 }
 
 impl ContentEncoding {
+    /// Compresses the payload of `request` in place and sets its `Content-Encoding` header.
+    ///
+    /// Requests that already have a `Content-Encoding` header are left untouched.
     #[allow(warnings)] // Ignore request is not being used
     pub fn encode(&self, request: &mut SignedRequest) {
         match self {
@@ -42,6 +48,12 @@ impl ContentEncoding {
             }
             #[cfg(feature = "encoding")]
             ContentEncoding::Gzip(min_payload_size, level) => {
+                // The payload is already encoded, e.g. pre-compressed content uploaded
+                // with `Content-Encoding: gzip`. Compressing it again would make the body
+                // disagree with the header that gets signed.
+                if request.headers().contains_key("content-encoding") {
+                    return;
+                }
                 match request.payload {
                     None => return,
                     Some(SignedRequestPayload::Buffer(ref payload)) => {
@@ -62,8 +74,7 @@ impl ContentEncoding {
                         if payload.len() < payload_compressed.len() {
                             return;
                         }
-                        let payload_compressed = bytes::Bytes::from(payload_compressed);
-                        request.payload = Some(SignedRequestPayload::Buffer(payload_compressed));
+                        request.set_payload(Some(payload_compressed));
                     }
                     Some(SignedRequestPayload::Stream(ref stream)) => {
                         // Stream compression is not supported yet
@@ -75,3 +86,78 @@ impl ContentEncoding {
         }
     }
 }
+
+/// Encodes `data` as standard, padded base64, e.g. for a `Content-MD5` header.
+pub fn base64_encode<T: AsRef<[u8]>>(data: T) -> String {
+    base64::encode(data)
+}
+
+/// Decodes standard, padded base64. Returns `None` if `data` is not valid base64.
+pub fn base64_decode<T: AsRef<[u8]>>(data: T) -> Option<Vec<u8>> {
+    base64::decode(data).ok()
+}
+
+/// Encodes `data` as lowercase hex, the format used by SigV4 and S3 ETags.
+pub fn hex_encode<T: AsRef<[u8]>>(data: T) -> String {
+    hex::encode(data)
+}
+
+/// Decodes hex in either case. Returns `None` if `data` is not valid hex.
+pub fn hex_decode<T: AsRef<[u8]>>(data: T) -> Option<Vec<u8>> {
+    hex::decode(data).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_round_trip() {
+        assert_eq!(base64_encode(b"rusoto"), "cnVzb3Rv");
+        assert_eq!(base64_decode("cnVzb3Rv"), Some(b"rusoto".to_vec()));
+        assert_eq!(base64_decode("not base64!"), None);
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn gzip_leaves_encoded_payloads_untouched() {
+        use crate::Region;
+
+        let payload = bytes::Bytes::from(vec![b'a'; 1024]);
+        let encoding = ContentEncoding::Gzip(None, DEFAULT_GZIP_COMPRESSION_LEVEL);
+
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/key");
+        request.set_payload(Some(payload.clone()));
+        encoding.encode(&mut request);
+        assert_eq!(
+            request.headers()["content-encoding"],
+            vec![b"gzip".to_vec()]
+        );
+        match request.payload {
+            Some(SignedRequestPayload::Buffer(ref compressed)) => {
+                assert!(compressed.len() < payload.len())
+            }
+            _ => panic!("expected a buffered payload"),
+        }
+
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/key");
+        request.add_header("Content-Encoding", "gzip");
+        request.set_payload(Some(payload.clone()));
+        encoding.encode(&mut request);
+        assert_eq!(
+            request.headers()["content-encoding"],
+            vec![b"gzip".to_vec()]
+        );
+        match request.payload {
+            Some(SignedRequestPayload::Buffer(ref unchanged)) => assert_eq!(unchanged, &payload),
+            _ => panic!("expected a buffered payload"),
+        }
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        assert_eq!(hex_encode([0xde, 0xad, 0xbe, 0xef]), "deadbeef");
+        assert_eq!(hex_decode("DEADbeef"), Some(vec![0xde, 0xad, 0xbe, 0xef]));
+        assert_eq!(hex_decode("xyz"), None);
+    }
+}
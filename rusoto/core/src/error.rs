@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::error::Error;
 use std::fmt;
 use std::io;
@@ -7,6 +8,7 @@ use crate::credential::CredentialsError;
 use super::proto::xml::util::XmlParseError;
 use super::request::{BufferedHttpResponse, HttpDispatchError};
 use crate::client::SignAndDispatchError;
+use crate::retry::error_code;
 
 /// Generic error type returned by all rusoto requests.
 #[derive(Debug, PartialEq)]
@@ -32,9 +34,36 @@ pub enum RusotoError<E> {
 /// Result carrying a generic `RusotoError`.
 pub type RusotoResult<T, E> = Result<T, RusotoError<E>>;
 
+/// The error code AWS returned for an error of a service, e.g. `NoSuchBucket` or
+/// `ResourceNotFoundException`.
+///
+/// Implemented by the error types of the generated service crates, so that
+/// `RusotoError::error_code` works for their errors.
+pub trait ServiceErrorCode {
+    /// The code the error was parsed from.
+    fn error_code(&self) -> &str;
+}
+
 /// Header used by AWS on responses to identify the request
 pub const AWS_REQUEST_ID_HEADER: &str = "x-amzn-requestid";
 
+impl<E: ServiceErrorCode> RusotoError<E> {
+    /// The error code AWS returned, e.g. for logging, or as a label of metrics with few
+    /// distinct values.
+    ///
+    /// The code of a `Service` error is the one it was parsed from, and that of an
+    /// `Unknown` error is parsed from its response: from the `x-amzn-ErrorType` header,
+    /// or from the body of JSON and XML errors. Errors that didn't come from AWS, e.g.
+    /// `HttpDispatch` and `Credentials`, have none.
+    pub fn error_code(&self) -> Option<Cow<'_, str>> {
+        match *self {
+            RusotoError::Service(ref err) => Some(Cow::Borrowed(err.error_code())),
+            RusotoError::Unknown(ref response) => error_code(response).map(Cow::Owned),
+            _ => None,
+        }
+    }
+}
+
 impl<E> From<XmlParseError> for RusotoError<E> {
     fn from(err: XmlParseError) -> Self {
         let XmlParseError(message) = err;
@@ -101,6 +130,7 @@ impl<E: Error + 'static> Error for RusotoError<E> {
             RusotoError::Service(ref err) => Some(err),
             RusotoError::Credentials(ref err) => Some(err),
             RusotoError::HttpDispatch(ref err) => Some(err),
+            RusotoError::InvalidDnsName(ref err) => Some(err),
             _ => None,
         }
     }
@@ -132,3 +162,97 @@ impl From<io::Error> for InvalidDnsNameError {
         InvalidDnsNameError::new(err.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_dns_name_is_the_source() {
+        let error: RusotoError<InvalidDnsNameError> =
+            RusotoError::InvalidDnsName(InvalidDnsNameError::new("bad.bucket".to_owned()));
+        let source = error.source().expect("InvalidDnsName should have a source");
+        assert_eq!(source.to_string(), "bad.bucket");
+
+        let error: RusotoError<InvalidDnsNameError> =
+            RusotoError::ParseError("unexpected element".to_owned());
+        assert!(error.source().is_none());
+    }
+
+    #[test]
+    fn error_code_of_each_variant() {
+        #[derive(Debug, PartialEq)]
+        enum GetItemError {
+            ResourceNotFound(String),
+        }
+
+        impl ServiceErrorCode for GetItemError {
+            fn error_code(&self) -> &str {
+                match *self {
+                    GetItemError::ResourceNotFound(_) => "ResourceNotFoundException",
+                }
+            }
+        }
+
+        let unknown = |status, header: Option<&str>, body: &str| {
+            let mut headers = http::HeaderMap::default();
+            if let Some(header) = header {
+                headers.insert("x-amzn-ErrorType", header.to_owned());
+            }
+            RusotoError::<GetItemError>::Unknown(BufferedHttpResponse {
+                status: http::StatusCode::from_u16(status).unwrap(),
+                body: body.to_owned().into(),
+                headers,
+            })
+        };
+
+        let error = RusotoError::Service(GetItemError::ResourceNotFound("gone".to_owned()));
+        assert_eq!(
+            error.error_code().as_deref(),
+            Some("ResourceNotFoundException")
+        );
+
+        let throttled = unknown(
+            400,
+            None,
+            "<ErrorResponse><Error><Code>Throttling</Code></Error></ErrorResponse>",
+        );
+        assert_eq!(throttled.error_code().as_deref(), Some("Throttling"));
+        let slow_down = unknown(
+            503,
+            None,
+            "<Error><Code>SlowDown</Code><Message>Please reduce your request rate.</Message></Error>",
+        );
+        assert_eq!(slow_down.error_code().as_deref(), Some("SlowDown"));
+        let json = unknown(
+            400,
+            None,
+            r#"{"__type":"com.amazonaws.dynamodb.v20120810#ProvisionedThroughputExceededException"}"#,
+        );
+        assert_eq!(
+            json.error_code().as_deref(),
+            Some("ProvisionedThroughputExceededException")
+        );
+        let header = unknown(
+            429,
+            Some("TooManyRequestsException:http://internal.amazon.com/"),
+            "",
+        );
+        assert_eq!(
+            header.error_code().as_deref(),
+            Some("TooManyRequestsException")
+        );
+        assert_eq!(unknown(502, None, "Bad Gateway").error_code(), None);
+
+        let client_side: Vec<RusotoError<GetItemError>> = vec![
+            RusotoError::HttpDispatch(HttpDispatchError::new("connection reset")),
+            RusotoError::Credentials(CredentialsError::new("no credentials")),
+            RusotoError::Validation("bucket name too long".to_owned()),
+            RusotoError::ParseError("unexpected element".to_owned()),
+            RusotoError::Blocking,
+        ];
+        for error in client_side {
+            assert_eq!(error.error_code(), None);
+        }
+    }
+}
@@ -26,26 +26,33 @@ use hyper_tls as tls;
 
 mod client;
 mod error;
+mod raw_request;
 mod stream;
 
 pub mod event_stream;
+pub mod metrics;
 pub mod param;
 #[doc(hidden)]
 pub mod region;
 pub mod request;
+pub mod retry;
 #[doc(hidden)]
 pub mod signature;
 
 pub use crate::client::Client;
-#[doc(hidden)]
 pub mod encoding;
 #[doc(hidden)]
 pub mod proto;
 #[doc(hidden)]
 pub mod serialization;
 
-pub use crate::error::{InvalidDnsNameError, RusotoError, RusotoResult};
+pub use crate::error::{InvalidDnsNameError, RusotoError, RusotoResult, ServiceErrorCode};
+pub use crate::metrics::MetricsSink;
+pub use crate::raw_request::RawRequestBuilder;
 pub use crate::region::Region;
-pub use crate::request::{DispatchSignedRequest, HttpClient, HttpConfig, HttpDispatchError};
+pub use crate::request::{
+    DispatchSignedRequest, HttpClient, HttpConfig, HttpDispatchError, IpFamily, RawParts,
+};
+pub use crate::retry::{RetryMode, RetryPolicy};
 pub use crate::stream::ByteStream;
 pub use rusoto_credential as credential;
@@ -0,0 +1,36 @@
+//! Hooks for collecting request metrics.
+
+use std::convert::Infallible;
+use std::time::Duration;
+
+use http::StatusCode;
+
+use crate::error::RusotoError;
+use crate::signature::SignedRequest;
+
+/// Receives metrics about the requests sent by an [`HttpClient`](../request/struct.HttpClient.html)
+/// or a [`Client`](../struct.Client.html).
+///
+/// Register a sink with `HttpClient::set_metrics_sink` for the requests it sends, and
+/// with `Client::with_metrics_sink` for the retries and credential errors of the client;
+/// registering the same sink with both reports each event once. All methods default to
+/// doing nothing, so implementors only need to override the events they care about.
+/// Clients without a sink don't pay for any of this.
+pub trait MetricsSink: Send + Sync {
+    /// Called right before a request is sent. The request has already been signed, so
+    /// its headers and payload can be inspected, e.g. to count bytes sent.
+    fn on_request_start(&self, _request: &SignedRequest) {}
+
+    /// Called once the response headers have been received, whatever the status code.
+    /// AWS service errors are reported here as non-2xx statuses.
+    fn on_response(&self, _service: &str, _status: StatusCode, _elapsed: Duration) {}
+
+    /// Called by a `Client` when a request is about to be retried, before it waits for
+    /// the backoff. `attempt` starts at 1 for the first retry.
+    fn on_retry(&self, _service: &str, _attempt: u32) {}
+
+    /// Called when a request failed before a response was received: by an `HttpClient`
+    /// when it could not be dispatched, e.g. on connection failures or timeouts, and by
+    /// a `Client` when the credentials to sign it could not be loaded.
+    fn on_error(&self, _service: &str, _error: &RusotoError<Infallible>, _elapsed: Duration) {}
+}
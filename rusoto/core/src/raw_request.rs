@@ -0,0 +1,215 @@
+use std::convert::Infallible;
+
+use bytes::Bytes;
+
+use crate::client::Client;
+use crate::error::RusotoError;
+use crate::param::Params;
+use crate::region::Region;
+use crate::request::BufferedHttpResponse;
+use crate::signature::SignedRequest;
+
+/// Assembles a request to any AWS service, to be signed and sent by a `Client`, for APIs
+/// no service crate covers yet.
+///
+/// The request is sent to the regional endpoint of the service, e.g.
+/// `newservice.eu-west-1.amazonaws.com`, unless `hostname` says otherwise, and signed for
+/// the service name.
+///
+/// ```rust,no_run
+/// use rusoto_core::{Client, RawRequestBuilder, Region};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let response = RawRequestBuilder::new("newservice", Region::EuWest1)
+///     .method("POST")
+///     .path("/widgets")
+///     .query("dryRun", "true")
+///     .header("content-type", "application/json")
+///     .body(r#"{"name": "sprocket"}"#)
+///     .send(&Client::shared())
+///     .await?;
+/// println!("{}: {}", response.status, response.body_as_str());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RawRequestBuilder {
+    service: String,
+    region: Region,
+    method: String,
+    path: String,
+    hostname: Option<String>,
+    headers: Vec<(String, String)>,
+    params: Params,
+    body: Option<Bytes>,
+}
+
+impl RawRequestBuilder {
+    /// Starts a `GET` request of `/` to `service` in `region`.
+    pub fn new(service: &str, region: Region) -> Self {
+        RawRequestBuilder {
+            service: service.to_owned(),
+            region,
+            method: "GET".to_owned(),
+            path: "/".to_owned(),
+            hostname: None,
+            headers: Vec::new(),
+            params: Params::new(),
+            body: None,
+        }
+    }
+
+    /// Sets the HTTP method, e.g. `POST`.
+    pub fn method(mut self, method: &str) -> Self {
+        self.method = method.to_owned();
+        self
+    }
+
+    /// Sets the path, which is URI-encoded when the request is signed.
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = path.to_owned();
+        self
+    }
+
+    /// Sends the request to `hostname` instead of the regional endpoint of the service.
+    pub fn hostname(mut self, hostname: &str) -> Self {
+        self.hostname = Some(hostname.to_owned());
+        self
+    }
+
+    /// Adds a header, which is signed along with the request.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Adds a query parameter, replacing any earlier one of the same name.
+    pub fn query(mut self, name: &str, value: &str) -> Self {
+        self.params.insert(name.to_owned(), Some(value.to_owned()));
+        self
+    }
+
+    /// Sets the body.
+    pub fn body<B: Into<Bytes>>(mut self, body: B) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// The unsigned request, which `Client::sign_and_dispatch` signs when it sends it.
+    pub fn build(self) -> SignedRequest {
+        let mut request = SignedRequest::new(&self.method, &self.service, &self.region, &self.path);
+        request.set_hostname(self.hostname);
+        for (name, value) in self.headers.iter() {
+            request.add_header(name, value);
+        }
+        request.set_params(self.params);
+        request.set_payload(self.body);
+        request
+    }
+
+    /// Signs and sends the request with `client`, and buffers the response.
+    ///
+    /// The response is returned whatever its status, as there is no service model to
+    /// tell errors apart; check `status` before using the body. Responses the retry
+    /// policy of the client considers transient, such as throttling errors, are retried
+    /// first.
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> Result<BufferedHttpResponse, RusotoError<Infallible>> {
+        let mut response = client.sign_and_dispatch(self.build()).await?;
+        Ok(response.buffer().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use futures::FutureExt;
+    use http::{HeaderMap, StatusCode};
+
+    use super::*;
+    use crate::credential::StaticProvider;
+    use crate::request::{DispatchSignedRequest, DispatchSignedRequestFuture, HttpResponse};
+    use crate::signature::SignedRequestPayload;
+    use crate::ByteStream;
+
+    /// Records the requests it is given, answering each with a 404.
+    #[derive(Default)]
+    struct Recorder {
+        requests: Mutex<Vec<SignedRequest>>,
+    }
+
+    impl DispatchSignedRequest for Recorder {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            self.requests.lock().unwrap().push(request);
+            let mut headers = HeaderMap::<String>::default();
+            headers.insert("x-amzn-requestid", "abc123".to_owned());
+            futures::future::ready(Ok(HttpResponse {
+                status: StatusCode::NOT_FOUND,
+                body: ByteStream::from(b"{\"message\": \"No such widget\"}".to_vec()),
+                headers,
+            }))
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn raw_request_is_signed_and_sent() {
+        let dispatcher = Arc::new(Recorder::default());
+        let credentials = StaticProvider::new_minimal("access_key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials, dispatcher.clone());
+
+        let response = RawRequestBuilder::new("newservice", Region::EuWest1)
+            .method("POST")
+            .path("/widgets")
+            .query("dryRun", "true")
+            .header("content-type", "application/json")
+            .body("{\"name\": \"sprocket\"}")
+            .send(&client)
+            .await
+            .unwrap();
+        assert_eq!(response.status, StatusCode::NOT_FOUND);
+        assert_eq!(response.body_as_str(), "{\"message\": \"No such widget\"}");
+        assert_eq!(response.headers["x-amzn-requestid"], "abc123");
+
+        let requests = dispatcher.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        assert_eq!(request.method(), "POST");
+        assert_eq!(request.hostname(), "newservice.eu-west-1.amazonaws.com");
+        assert_eq!(request.path(), "/widgets");
+        assert_eq!(request.canonical_query_string(), "dryRun=true");
+        assert_eq!(
+            request.headers()["content-type"],
+            [b"application/json".to_vec()]
+        );
+        match request.payload {
+            Some(SignedRequestPayload::Buffer(ref body)) => {
+                assert_eq!(&body[..], b"{\"name\": \"sprocket\"}")
+            }
+            _ => panic!("expected a buffered body"),
+        }
+        let authorization = String::from_utf8_lossy(&request.headers()["authorization"][0]);
+        assert!(authorization.starts_with("AWS4-HMAC-SHA256 Credential=access_key/"));
+        assert!(authorization.contains("/eu-west-1/newservice/aws4_request"));
+        assert!(authorization.contains("content-type"));
+    }
+
+    #[test]
+    fn raw_request_hostname() {
+        let request = RawRequestBuilder::new("newservice", Region::UsEast1)
+            .hostname("api.newservice.example.com")
+            .build();
+        assert_eq!(request.hostname(), "api.newservice.example.com");
+        assert_eq!(request.method(), "GET");
+        assert_eq!(request.path(), "/");
+        assert!(request.payload.is_none());
+    }
+}
@@ -8,15 +8,17 @@ use std::fmt;
 use std::future::Future;
 use std::io;
 use std::io::Error as IoError;
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::pin::Pin;
 use std::rc::Rc;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use bytes::{Bytes, BytesMut};
 use futures::{FutureExt, StreamExt};
 use http::header::{HeaderName, HeaderValue};
-use http::{HeaderMap, Method, StatusCode};
+use http::{HeaderMap, Method, StatusCode, Uri};
+use hyper::client::connect::dns::GaiResolver;
 use hyper::client::connect::Connect;
 use hyper::client::Builder as HyperBuilder;
 use hyper::client::HttpConnector;
@@ -28,7 +30,9 @@ use tokio::time;
 use log::Level::Debug;
 use log::*;
 
-use crate::signature::SignedRequest;
+use crate::error::RusotoError;
+use crate::metrics::MetricsSink;
+use crate::signature::{SignedRequest, SignedRequestPayload};
 use crate::stream::ByteStream;
 use crate::tls::HttpsConnector;
 
@@ -146,9 +150,12 @@ pub struct HttpDispatchError {
 }
 
 impl HttpDispatchError {
-    /// Construct a new HttpDispatchError for testing purposes
-    pub fn new(message: String) -> HttpDispatchError {
-        HttpDispatchError { message }
+    /// Creates an error with the given message, e.g. for the transport errors of a
+    /// custom `DispatchSignedRequest`.
+    pub fn new<S: Into<String>>(message: S) -> HttpDispatchError {
+        HttpDispatchError {
+            message: message.into(),
+        }
     }
 }
 
@@ -176,6 +183,22 @@ impl From<IoError> for HttpDispatchError {
     }
 }
 
+/// Converts the errors of other HTTP clients, e.g. with `?` in a custom
+/// `DispatchSignedRequest`. The message includes the sources of the error, which clients
+/// such as reqwest leave out of their own message.
+impl From<Box<dyn Error + Send + Sync>> for HttpDispatchError {
+    fn from(err: Box<dyn Error + Send + Sync>) -> HttpDispatchError {
+        let mut message = err.to_string();
+        let mut source = err.source();
+        while let Some(cause) = source {
+            message.push_str(": ");
+            message.push_str(&cause.to_string());
+            source = cause.source();
+        }
+        HttpDispatchError { message }
+    }
+}
+
 /// Type returned from `dispatch` for a `DispatchSignedRequest` implementor
 pub type DispatchSignedRequestFuture =
     Pin<Box<dyn Future<Output = Result<HttpResponse, HttpDispatchError>> + Send>>;
@@ -183,6 +206,9 @@ pub type DispatchSignedRequestFuture =
 /// Trait for implementing HTTP Request/Response
 pub trait DispatchSignedRequest {
     /// Dispatch Request, and then return a Response
+    ///
+    /// Dropping the returned future cancels the request. For `HttpClient`, the underlying
+    /// connection is closed right away instead of waiting for the server to respond.
     fn dispatch(
         &self,
         request: SignedRequest,
@@ -215,6 +241,50 @@ pub struct HttpClient<C = HttpsConnector<HttpConnector>> {
     inner: HyperClient<C, Body>,
     local_agent_prepend: Option<String>,
     local_agent_append: Option<String>,
+    metrics_sink: Option<Arc<dyn MetricsSink>>,
+    raw_exchange_hook: Option<RawExchangeHook>,
+    show_raw_credentials: bool,
+    url_rewrite: Option<UrlRewrite>,
+}
+
+/// The head of a message passed to the hook set with `HttpClient::on_raw_exchange`.
+#[derive(Debug)]
+pub enum RawParts<'a> {
+    /// A request, right before it is sent.
+    Request(&'a http::request::Parts),
+    /// A response, as received and before it is parsed.
+    Response(&'a http::response::Parts),
+}
+
+/// Headers carrying credentials, hidden from raw exchange hooks unless
+/// `HttpClient::unsafe_show_credentials_in_raw_exchange` is set.
+const REDACTED_HEADERS: &[&str] = &["authorization", "x-amz-security-token"];
+
+type RawExchangeHook = Arc<dyn Fn(RawParts<'_>, &Bytes) + Send + Sync>;
+
+type UrlRewrite = Arc<dyn Fn(&mut Uri) + Send + Sync>;
+
+struct RawExchange {
+    hook: RawExchangeHook,
+    show_credentials: bool,
+}
+
+impl RawExchange {
+    fn show_request(&self, request: &HyperRequest<Body>, body: &Bytes) {
+        let (mut parts, ()) = HyperRequest::new(()).into_parts();
+        parts.method = request.method().clone();
+        parts.uri = request.uri().clone();
+        parts.version = request.version();
+        parts.headers = request.headers().clone();
+        if !self.show_credentials {
+            for name in REDACTED_HEADERS {
+                if let Some(value) = parts.headers.get_mut(*name) {
+                    *value = HeaderValue::from_static("<redacted>");
+                }
+            }
+        }
+        (self.hook)(RawParts::Request(&parts), body);
+    }
 }
 
 impl HttpClient {
@@ -231,12 +301,7 @@ impl HttpClient {
 
     /// Create a tls-enabled http client.
     pub fn new_with_config(config: HttpConfig) -> Result<Self, TlsError> {
-        #[cfg(feature = "native-tls")]
-        let connector = HttpsConnector::new();
-
-        #[cfg(feature = "rustls")]
-        let connector = HttpsConnector::with_native_roots();
-
+        let connector = https_connector(&config)?;
         Ok(Self::from_connector_with_config(connector, config))
     }
 
@@ -269,6 +334,10 @@ where
             inner,
             local_agent_prepend: None,
             local_agent_append: None,
+            metrics_sink: None,
+            raw_exchange_hook: None,
+            show_raw_credentials: false,
+            url_rewrite: None,
         }
     }
 
@@ -282,12 +351,20 @@ where
         config
             .pool_idle_timeout
             .map(|t| builder.pool_idle_timeout(t));
+        config
+            .pool_max_idle_per_host
+            .map(|max| builder.pool_max_idle_per_host(max));
+        builder.http2_only(config.http2_only);
         let inner = builder.build(connector);
 
         HttpClient {
             inner,
             local_agent_prepend: None,
             local_agent_append: None,
+            metrics_sink: None,
+            raw_exchange_hook: None,
+            show_raw_credentials: false,
+            url_rewrite: None,
         }
     }
 
@@ -298,14 +375,68 @@ where
             inner,
             local_agent_prepend: None,
             local_agent_append: None,
+            metrics_sink: None,
+            raw_exchange_hook: None,
+            show_raw_credentials: false,
+            url_rewrite: None,
         }
     }
+
+    /// Sets a sink that is notified about every request sent by this client.
+    pub fn set_metrics_sink<S>(&mut self, sink: S)
+    where
+        S: MetricsSink + 'static,
+    {
+        self.metrics_sink = Some(Arc::new(sink));
+    }
+
+    /// Calls `hook` with every request right before it is sent, and with every
+    /// response before it is parsed, for debugging signature and protocol issues.
+    ///
+    /// The `Authorization` and `X-Amz-Security-Token` headers are redacted unless
+    /// `unsafe_show_credentials_in_raw_exchange` is set. Streamed request bodies are
+    /// passed as empty, while response bodies are read into memory before the hook is
+    /// called, so avoid setting a hook when downloading large objects. Clients without
+    /// a hook don't pay for any of this.
+    pub fn on_raw_exchange<F>(&mut self, hook: F)
+    where
+        F: Fn(RawParts<'_>, &Bytes) + Send + Sync + 'static,
+    {
+        self.raw_exchange_hook = Some(Arc::new(hook));
+    }
+
+    /// Passes the `Authorization` and `X-Amz-Security-Token` headers to the hook set
+    /// with `on_raw_exchange` as they are, instead of redacting them.
+    ///
+    /// Anything the hook logs can then be used to make requests with your
+    /// credentials, so only enable this for local debugging.
+    pub fn unsafe_show_credentials_in_raw_exchange(&mut self, show: bool) {
+        self.show_raw_credentials = show;
+    }
+
+    /// Calls `rewrite` with the URL of every request after it is signed, so that it can
+    /// be sent elsewhere, e.g. to a service mesh sidecar or a test server.
+    ///
+    /// Only the connection follows the rewritten URL: the headers are sent as signed,
+    /// with the `Host` of the original URL, so the service the request is for still
+    /// accepts the signature. Rewriting the path or query breaks the signature.
+    pub fn set_url_rewrite<F>(&mut self, rewrite: F)
+    where
+        F: Fn(&mut Uri) + Send + Sync + 'static,
+    {
+        self.url_rewrite = Some(Arc::new(rewrite));
+    }
 }
 
 /// Configuration options for the HTTP Client
 pub struct HttpConfig {
     read_buf_size: Option<usize>,
     pool_idle_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    http2_only: bool,
+    ip_family: IpFamily,
+    happy_eyeballs_timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
 }
 
 impl HttpConfig {
@@ -314,6 +445,11 @@ impl HttpConfig {
         HttpConfig {
             read_buf_size: None,
             pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            http2_only: false,
+            ip_family: IpFamily::Any,
+            happy_eyeballs_timeout: Some(Duration::from_millis(300)),
+            connect_timeout: None,
         }
     }
     /// Sets the size of the read buffer for inbound data
@@ -327,12 +463,87 @@ impl HttpConfig {
     /// Some AWS services, [like S3](https://aws.amazon.com/premiumsupport/knowledge-center/s3-socket-connection-timeout-error/)
     /// require this value to match the one configured at the server's level
     /// in order to avoid connection closed errors.
+    ///
+    /// If unset, hyper's default of 90 seconds is used.
     pub fn pool_idle_timeout<D>(&mut self, timeout: D)
     where
         D: Into<Option<Duration>>,
     {
         self.pool_idle_timeout = timeout.into();
     }
+
+    /// Sets the maximum number of idle connections kept alive per host.
+    /// Lowering this bounds the number of open sockets in high fan-out
+    /// applications, at the cost of more frequent reconnects.
+    ///
+    /// If unset, hyper's default (no limit) is used.
+    pub fn pool_max_idle_per_host(&mut self, max: usize) {
+        self.pool_max_idle_per_host = Some(max);
+    }
+
+    /// Use HTTP/2 for all requests, so that concurrent requests to the same
+    /// host are multiplexed over a single connection.
+    ///
+    /// HTTP/2 is negotiated through ALPN when the client is created with
+    /// `HttpClient::new_with_config`. Only enable this for endpoints known
+    /// to support HTTP/2, as requests to HTTP/1.1-only endpoints will fail with
+    /// an `HttpDispatchError` naming the HTTP/2 protocol error, as will requests the
+    /// server refuses by closing the connection with GOAWAY. Defaults to `false`.
+    pub fn http2_only(&mut self, enabled: bool) {
+        self.http2_only = enabled;
+    }
+
+    /// Restricts connections to addresses of one IP family, e.g. to reach the
+    /// dualstack S3 endpoints over IPv6 on a network where IPv4 is broken.
+    ///
+    /// Applies to clients created with `HttpClient::new_with_config`. To resolve
+    /// hostnames some other way, pass an `HttpConnector` built with
+    /// `HttpConnector::new_with_resolver` to `HttpClient::from_connector_with_config`.
+    /// Defaults to `IpFamily::Any`, i.e. the system's preference.
+    pub fn ip_family(&mut self, family: IpFamily) {
+        self.ip_family = family;
+    }
+
+    /// Sets how long to wait for a connection to the preferred IP family before also
+    /// trying the other one in parallel ("happy eyeballs", RFC 6555), when a hostname
+    /// resolves to both IPv4 and IPv6 addresses. `None` tries the addresses one by one.
+    /// Has no effect when `ip_family` restricts connections to one family, as there is
+    /// no other family to try.
+    ///
+    /// Applies to clients created with `HttpClient::new_with_config`. Defaults to
+    /// hyper's 300 milliseconds.
+    pub fn happy_eyeballs_timeout<D>(&mut self, timeout: D)
+    where
+        D: Into<Option<Duration>>,
+    {
+        self.happy_eyeballs_timeout = timeout.into();
+    }
+
+    /// Sets a timeout for establishing a TCP connection. When a hostname resolves to
+    /// several addresses the timeout is divided evenly between them, so that an
+    /// unreachable address fails over to the next one instead of stalling the request.
+    ///
+    /// Applies to clients created with `HttpClient::new_with_config`. If unset,
+    /// connection attempts only end when the operating system gives up on them.
+    pub fn connect_timeout<D>(&mut self, timeout: D)
+    where
+        D: Into<Option<Duration>>,
+    {
+        self.connect_timeout = timeout.into();
+    }
+}
+
+/// The IP address families connections may use, see `HttpConfig::ip_family`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IpFamily {
+    /// Connect to any address the hostname resolves to, in the order the system
+    /// resolver returns them.
+    #[default]
+    Any,
+    /// Only connect to IPv4 addresses.
+    Ipv4Only,
+    /// Only connect to IPv6 addresses.
+    Ipv6Only,
 }
 
 impl Default for HttpConfig {
@@ -342,11 +553,110 @@ impl Default for HttpConfig {
     }
 }
 
+/// Builds the TCP connector for `config`.
+fn http_connector(config: &HttpConfig) -> HttpConnector {
+    http_connector_with_resolver(config, GaiResolver::new())
+}
+
+fn http_connector_with_resolver<R>(config: &HttpConfig, resolver: R) -> HttpConnector<R> {
+    let mut http = HttpConnector::new_with_resolver(resolver);
+    http.enforce_http(false);
+    // Binding to the unspecified address of one family makes hyper skip the
+    // resolved addresses of the other, but only when it sorts them for happy
+    // eyeballs, so that stays on; there is nothing left to fall back to anyway.
+    let happy_eyeballs_timeout = match config.ip_family {
+        IpFamily::Any => config.happy_eyeballs_timeout,
+        IpFamily::Ipv4Only => {
+            http.set_local_address(Some(Ipv4Addr::UNSPECIFIED.into()));
+            Some(config.happy_eyeballs_timeout.unwrap_or_default())
+        }
+        IpFamily::Ipv6Only => {
+            http.set_local_address(Some(Ipv6Addr::UNSPECIFIED.into()));
+            Some(config.happy_eyeballs_timeout.unwrap_or_default())
+        }
+    };
+    http.set_happy_eyeballs_timeout(happy_eyeballs_timeout);
+    http.set_connect_timeout(config.connect_timeout);
+    http
+}
+
+/// Builds a TLS connector for `config`, offering HTTP/2 during the ALPN handshake
+/// if `http2_only` is set.
+#[cfg(feature = "native-tls")]
+fn https_connector(config: &HttpConfig) -> Result<HttpsConnector<HttpConnector>, TlsError> {
+    let mut tls = native_tls_crate::TlsConnector::builder();
+    if config.http2_only {
+        tls.request_alpns(&["h2"]);
+    }
+    let tls = tls.build().map_err(|err| TlsError {
+        message: format!("Couldn't create TLS connector: {}", err),
+    })?;
+    Ok(HttpsConnector::from((http_connector(config), tls.into())))
+}
+
+/// Builds a TLS connector for `config` that trusts the system's root certificates and
+/// offers HTTP/2 and HTTP/1.1 during the ALPN handshake, like
+/// `HttpsConnector::with_native_roots`.
+#[cfg(feature = "rustls")]
+fn https_connector(config: &HttpConfig) -> Result<HttpsConnector<HttpConnector>, TlsError> {
+    let mut tls = rustls_crate::ClientConfig::new();
+    tls.root_store = match rustls_native_certs::load_native_certs() {
+        Ok(store) => store,
+        Err((Some(store), err)) => {
+            warn!("Couldn't load all root certificates: {}", err);
+            store
+        }
+        Err((None, err)) => {
+            return Err(TlsError {
+                message: format!("Couldn't load root certificates: {}", err),
+            })
+        }
+    };
+    if tls.root_store.is_empty() {
+        return Err(TlsError {
+            message: "No root certificates found".to_owned(),
+        });
+    }
+    tls.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    tls.ct_logs = Some(&ct_logs::LOGS);
+    Ok(HttpsConnector::from((http_connector(config), tls)))
+}
+
+/// Describes an error returned by hyper for a request. HTTP/2 errors are called out, as
+/// they are easily mistaken for network errors: a GOAWAY frame from the server means it
+/// is shutting the connection down, while protocol errors usually mean the endpoint
+/// doesn't speak HTTP/2 at all.
+fn dispatch_error(err: HyperError) -> HttpDispatchError {
+    let mut source = err.source();
+    while let Some(cause) = source {
+        if let Some(h2_err) = cause.downcast_ref::<h2::Error>() {
+            let message = match h2_err.reason() {
+                Some(reason) if h2_err.is_go_away() && h2_err.is_remote() => format!(
+                    "Error during dispatch: the server closed the HTTP/2 connection with GOAWAY ({:?}): {}",
+                    reason, err
+                ),
+                Some(reason) => format!(
+                    "HTTP/2 protocol error ({:?}) during dispatch, check that the endpoint supports HTTP/2 when using HttpConfig::http2_only: {}",
+                    reason, err
+                ),
+                None => break,
+            };
+            return HttpDispatchError { message };
+        }
+        source = cause.source();
+    }
+    HttpDispatchError {
+        message: format!("Error during dispatch: {}", err),
+    }
+}
+
 async fn http_client_dispatch<'a, C>(
     client: HyperClient<C, Body>,
     request: SignedRequest,
     timeout: Option<Duration>,
     user_agent: HeaderValue,
+    raw_exchange: Option<RawExchange>,
+    url_rewrite: Option<UrlRewrite>,
 ) -> Result<HttpResponse, HttpDispatchError>
 where
     C: Connect + Send + Sync + Clone + 'static,
@@ -394,15 +704,31 @@ where
         hyper_headers.insert("user-agent", user_agent);
     }
 
+    // Hyper derives the Host header from the URI if it's missing, which is the SNI host
+    // or the rewritten URL when set rather than the hostname the request is for.
+    if (request.sni_host.is_some() || url_rewrite.is_some()) && !hyper_headers.contains_key("host")
+    {
+        let host = HeaderValue::from_str(&request.hostname()).map_err(|err| HttpDispatchError {
+            message: format!("error parsing header value: {}", err),
+        })?;
+        hyper_headers.insert("host", host);
+    }
+
     let mut final_uri = format!(
         "{}://{}{}",
         request.scheme(),
-        request.hostname(),
+        request.connect_host(),
         request.canonical_path()
     );
     if !request.canonical_query_string().is_empty() {
         final_uri = final_uri + &format!("?{}", request.canonical_query_string());
     }
+    let mut final_uri = final_uri.parse::<Uri>().map_err(|err| HttpDispatchError {
+        message: format!("error parsing request URI: {}", err),
+    })?;
+    if let Some(rewrite) = url_rewrite {
+        rewrite(&mut final_uri);
+    }
 
     if log_enabled!(Debug) {
         debug!(
@@ -416,6 +742,11 @@ where
 
     let http_request_builder = HyperRequest::builder().method(hyper_method).uri(final_uri);
 
+    let raw_request_body = match (&raw_exchange, &request.payload) {
+        (Some(_), Some(SignedRequestPayload::Buffer(bytes))) => bytes.clone(),
+        _ => Bytes::new(),
+    };
+
     let try_http_request = if let Some(p) = request.payload {
         http_request_builder.body(p.into_body())
     } else {
@@ -428,6 +759,10 @@ where
 
     *http_request.headers_mut() = hyper_headers;
 
+    if let Some(ref raw) = raw_exchange {
+        raw.show_request(&http_request, &raw_request_body);
+    }
+
     let f = client.request(http_request);
 
     let try_resp = match timeout {
@@ -441,9 +776,20 @@ where
             Ok(try_req) => try_req,
         },
     };
-    let resp = try_resp.map_err(|e| HttpDispatchError {
-        message: format!("Error during dispatch: {}", e),
-    })?;
+    let resp = try_resp.map_err(dispatch_error)?;
+    let resp = match raw_exchange {
+        None => resp,
+        Some(raw) => {
+            let (parts, body) = resp.into_parts();
+            let body = hyper::body::to_bytes(body)
+                .await
+                .map_err(|e| HttpDispatchError {
+                    message: format!("Error obtaining body: {}", e),
+                })?;
+            (raw.hook)(RawParts::Response(&parts), &body);
+            HyperResponse::from_parts(parts, Body::from(body))
+        }
+    };
     Ok(HttpResponse::from_hyper(resp).await)
 }
 
@@ -457,7 +803,51 @@ where
         timeout: Option<Duration>,
     ) -> DispatchSignedRequestFuture {
         let user_agent = build_user_agent(&self.local_agent_prepend, &self.local_agent_append);
-        http_client_dispatch::<C>(self.inner.clone(), request, timeout, user_agent).boxed()
+        let raw_exchange = self.raw_exchange_hook.clone().map(|hook| RawExchange {
+            hook,
+            show_credentials: self.show_raw_credentials,
+        });
+        let sink = match self.metrics_sink {
+            Some(ref sink) => sink.clone(),
+            None => {
+                return http_client_dispatch::<C>(
+                    self.inner.clone(),
+                    request,
+                    timeout,
+                    user_agent,
+                    raw_exchange,
+                    self.url_rewrite.clone(),
+                )
+                .boxed()
+            }
+        };
+
+        sink.on_request_start(&request);
+        let service = request.service.clone();
+        let started_at = Instant::now();
+        let dispatch = http_client_dispatch::<C>(
+            self.inner.clone(),
+            request,
+            timeout,
+            user_agent,
+            raw_exchange,
+            self.url_rewrite.clone(),
+        );
+        async move {
+            let result = dispatch.await;
+            match result {
+                Ok(ref response) => {
+                    sink.on_response(&service, response.status, started_at.elapsed())
+                }
+                Err(ref err) => sink.on_error(
+                    &service,
+                    &RusotoError::HttpDispatch(err.clone()),
+                    started_at.elapsed(),
+                ),
+            }
+            result
+        }
+        .boxed()
     }
 }
 
@@ -494,6 +884,8 @@ mod tests {
     use super::*;
     use crate::signature::SignedRequest;
     use crate::Region;
+    use std::convert::Infallible;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[test]
     fn http_client_is_send_and_sync() {
@@ -502,6 +894,226 @@ mod tests {
         is_send_and_sync::<HttpClient>();
     }
 
+    #[test]
+    fn http_config_sets_pool_options() {
+        let mut config = HttpConfig::new();
+        config.pool_idle_timeout(Duration::from_secs(30));
+        config.pool_max_idle_per_host(8);
+        assert_eq!(config.pool_idle_timeout, Some(Duration::from_secs(30)));
+        assert_eq!(config.pool_max_idle_per_host, Some(8));
+        assert!(HttpClient::new_with_config(config).is_ok());
+    }
+
+    #[tokio::test]
+    async fn http_config_restricts_ip_family() {
+        use hyper::service::Service;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let uri: hyper::Uri = format!("http://{}", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        let connect = |family| {
+            let mut config = HttpConfig::new();
+            config.ip_family(family);
+            http_connector(&config).call(uri.clone())
+        };
+
+        assert!(connect(IpFamily::Any).await.is_ok());
+        assert!(connect(IpFamily::Ipv4Only).await.is_ok());
+        assert!(connect(IpFamily::Ipv6Only).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn http_config_restricts_ip_family_without_happy_eyeballs() {
+        use hyper::client::connect::dns::Name;
+        use hyper::service::Service;
+        use std::net::SocketAddr;
+        use std::task::{Context, Poll};
+
+        // Resolves every hostname to the loopback addresses of both families.
+        #[derive(Clone)]
+        struct DualStackResolver;
+
+        impl Service<Name> for DualStackResolver {
+            type Response = std::vec::IntoIter<SocketAddr>;
+            type Error = io::Error;
+            type Future = futures::future::Ready<io::Result<Self::Response>>;
+
+            fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+                Poll::Ready(Ok(()))
+            }
+
+            fn call(&mut self, _name: Name) -> Self::Future {
+                let addrs: Vec<SocketAddr> = vec![
+                    (Ipv6Addr::LOCALHOST, 0).into(),
+                    (Ipv4Addr::LOCALHOST, 0).into(),
+                ];
+                futures::future::ready(Ok(addrs.into_iter()))
+            }
+        }
+
+        let ipv4 = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = ipv4.local_addr().unwrap().port();
+        let ipv6 = tokio::net::TcpListener::bind(("::1", port)).await.unwrap();
+        let uri: hyper::Uri = format!("http://dualstack.example.com:{}", port)
+            .parse()
+            .unwrap();
+        let connect = |family| {
+            let mut config = HttpConfig::new();
+            config.ip_family(family);
+            config.happy_eyeballs_timeout(None);
+            http_connector_with_resolver(&config, DualStackResolver).call(uri.clone())
+        };
+
+        let _connection = connect(IpFamily::Ipv4Only).await.unwrap();
+        time::timeout(Duration::from_secs(5), ipv4.accept())
+            .await
+            .expect("connected over IPv6")
+            .unwrap();
+        let _connection = connect(IpFamily::Ipv6Only).await.unwrap();
+        time::timeout(Duration::from_secs(5), ipv6.accept())
+            .await
+            .expect("connected over IPv4")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn http_config_tunes_connection_attempts() {
+        use hyper::service::Service;
+
+        let mut config = HttpConfig::new();
+        assert_eq!(
+            config.happy_eyeballs_timeout,
+            Some(Duration::from_millis(300))
+        );
+        assert_eq!(config.connect_timeout, None);
+        config.happy_eyeballs_timeout(Duration::from_millis(50));
+        config.connect_timeout(Duration::from_secs(2));
+        assert_eq!(
+            config.happy_eyeballs_timeout,
+            Some(Duration::from_millis(50))
+        );
+        assert_eq!(config.connect_timeout, Some(Duration::from_secs(2)));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let uri: hyper::Uri = format!("http://{}", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        assert!(http_connector(&config).call(uri.clone()).await.is_ok());
+        config.happy_eyeballs_timeout(None);
+        assert!(http_connector(&config).call(uri).await.is_ok());
+    }
+
+    #[test]
+    fn http_config_enables_http2() {
+        let mut config = HttpConfig::new();
+        assert!(!config.http2_only);
+        config.http2_only(true);
+        assert!(config.http2_only);
+        assert!(HttpClient::new_with_config(config).is_ok());
+    }
+
+    /// A client speaking HTTP/2 with prior knowledge to `address`.
+    fn http2_client_and_region(
+        address: std::net::SocketAddr,
+    ) -> (HttpClient<HttpConnector>, Region) {
+        let mut config = HttpConfig::new();
+        config.http2_only(true);
+        let client = HttpClient::from_connector_with_config(HttpConnector::new(), config);
+        let region = Region::Custom {
+            name: "local".to_owned(),
+            endpoint: format!("http://{}", address),
+        };
+        (client, region)
+    }
+
+    #[tokio::test]
+    async fn http2_only_dispatches_over_http2() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let (client, region) = http2_client_and_region(listener.local_addr().unwrap());
+        let (received, receiver) = tokio::sync::oneshot::channel();
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut connection = h2::server::handshake(socket).await.unwrap();
+            let (request, mut respond) = connection.accept().await.unwrap().unwrap();
+            received
+                .send((request.version(), request.uri().path().to_owned()))
+                .unwrap();
+            let response = http::Response::builder().status(200).body(()).unwrap();
+            let mut body = respond.send_response(response, false).unwrap();
+            body.send_data(Bytes::from_static(b"over h2"), true)
+                .unwrap();
+            while connection.accept().await.is_some() {}
+        });
+
+        let request = SignedRequest::new("GET", "s3", &region, "/key");
+        let mut response = client.dispatch(request, None).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        let response = response.buffer().await.unwrap();
+        assert_eq!(response.body_as_str(), "over h2");
+
+        let (version, path) = receiver.await.unwrap();
+        assert_eq!(version, http::Version::HTTP_2);
+        assert_eq!(path, "/key");
+    }
+
+    #[tokio::test]
+    async fn http2_goaway_is_reported() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let (client, region) = http2_client_and_region(listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut connection = h2::server::handshake(socket).await.unwrap();
+            connection.abrupt_shutdown(h2::Reason::ENHANCE_YOUR_CALM);
+            while connection.accept().await.is_some() {}
+            // closing the socket with unread frames would reset it before the client
+            // reads the GOAWAY
+            time::sleep(Duration::from_secs(5)).await;
+        });
+
+        let request = SignedRequest::new("GET", "s3", &region, "/key");
+        let err = match client.dispatch(request, None).await {
+            Ok(response) => panic!("expected an error, got {}", response.status),
+            Err(err) => err,
+        };
+        assert!(
+            err.to_string().contains(
+                "the server closed the HTTP/2 connection with GOAWAY (ENHANCE_YOUR_CALM)"
+            ),
+            "{}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn http2_only_to_http1_endpoint_is_reported() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let (client, region) = http2_client_and_region(listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = socket.read(&mut buf).await;
+            let _ = socket
+                .write_all(b"HTTP/1.1 400 Bad Request\r\ncontent-length: 0\r\n\r\n")
+                .await;
+        });
+
+        let request = SignedRequest::new("GET", "s3", &region, "/key");
+        let err = match client.dispatch(request, None).await {
+            Ok(response) => panic!("expected an error, got {}", response.status),
+            Err(err) => err,
+        };
+        assert!(
+            err.to_string().contains(
+                "check that the endpoint supports HTTP/2 when using HttpConfig::http2_only"
+            ),
+            "{}",
+            err
+        );
+    }
+
     #[test]
     fn custom_region_http() {
         let a_region = Region::Custom {
@@ -546,6 +1158,257 @@ mod tests {
         assert_eq!("localhost", request.hostname());
     }
 
+    #[derive(Default)]
+    struct CountingSink {
+        started: AtomicUsize,
+        responses: AtomicUsize,
+        errors: AtomicUsize,
+    }
+
+    impl MetricsSink for Arc<CountingSink> {
+        fn on_request_start(&self, _request: &SignedRequest) {
+            self.started.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_response(&self, _service: &str, _status: StatusCode, _elapsed: Duration) {
+            self.responses.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_error(&self, service: &str, error: &RusotoError<Infallible>, _elapsed: Duration) {
+            assert_eq!(service, "s3");
+            assert!(matches!(error, RusotoError::HttpDispatch(_)));
+            self.errors.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[tokio::test]
+    async fn metrics_sink_is_notified_of_dispatch_errors() {
+        let sink = Arc::new(CountingSink::default());
+        let mut client = HttpClient::new().unwrap();
+        client.set_metrics_sink(sink.clone());
+
+        // nothing listens on port 1, so the connection is refused
+        let region = Region::Custom {
+            name: "local".to_owned(),
+            endpoint: "http://127.0.0.1:1".to_owned(),
+        };
+        let request = SignedRequest::new("GET", "s3", &region, "/");
+        assert!(client.dispatch(request, None).await.is_err());
+
+        assert_eq!(sink.started.load(Ordering::SeqCst), 1);
+        assert_eq!(sink.responses.load(Ordering::SeqCst), 0);
+        assert_eq!(sink.errors.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn dropping_dispatch_future_closes_connection() {
+        use tokio::io::AsyncReadExt;
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let region = Region::Custom {
+            name: "local".to_owned(),
+            endpoint: format!("http://{}", listener.local_addr().unwrap()),
+        };
+        let client = HttpClient::new().unwrap();
+        let request = SignedRequest::new("GET", "s3", &region, "/");
+        let in_flight = tokio::spawn(client.dispatch(request, None));
+
+        // read the request but never respond, so it stays in flight
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buf = [0u8; 1024];
+        assert!(socket.read(&mut buf).await.unwrap() > 0);
+
+        in_flight.abort();
+        let read = time::timeout(Duration::from_secs(5), socket.read(&mut buf))
+            .await
+            .expect("connection was not closed after the request was dropped");
+        assert_eq!(read.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn sni_host_is_connected_to_with_the_signed_host_header() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 4096];
+            let read = socket.read(&mut buf).await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .await
+                .unwrap();
+            String::from_utf8_lossy(&buf[..read]).to_lowercase()
+        });
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/key");
+        request.scheme = Some("http".to_owned());
+        request.set_hostname(Some("bucket.s3.amazonaws.com".to_owned()));
+        request.set_sni_host(Some(address.to_string()));
+        request.complement();
+        let client = HttpClient::new().unwrap();
+        let response = client.dispatch(request, None).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+
+        let received = server.await.unwrap();
+        assert!(
+            received.starts_with("get /key http/1.1\r\n"),
+            "{}",
+            received
+        );
+        assert!(
+            received.contains("\r\nhost: bucket.s3.amazonaws.com\r\n"),
+            "{}",
+            received
+        );
+    }
+
+    #[tokio::test]
+    async fn url_rewrite_changes_the_connection_but_not_the_signature() {
+        use crate::credential::AwsCredentials;
+        use std::sync::Mutex;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 4096];
+            let read = socket.read(&mut buf).await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .await
+                .unwrap();
+            String::from_utf8_lossy(&buf[..read]).to_lowercase()
+        });
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/key");
+        request.scheme = Some("http".to_owned());
+        request.set_hostname(Some("bucket.s3.amazonaws.com".to_owned()));
+        request.add_param("versionId", "v1");
+        let credentials = AwsCredentials::new("access_key", "secret_key", None, None);
+        request.sign(&credentials).unwrap();
+        let authorization =
+            String::from_utf8(request.headers()["authorization"][0].clone()).unwrap();
+
+        let original = Arc::new(Mutex::new(None));
+        let mut client = HttpClient::new().unwrap();
+        let seen = original.clone();
+        client.set_url_rewrite(move |uri| {
+            *seen.lock().unwrap() = Some(uri.to_string());
+            let mut parts = uri.clone().into_parts();
+            parts.authority = Some(address.to_string().parse().unwrap());
+            *uri = Uri::from_parts(parts).unwrap();
+        });
+        let response = client.dispatch(request, None).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+
+        assert_eq!(
+            original.lock().unwrap().as_deref(),
+            Some("http://bucket.s3.amazonaws.com/key?versionId=v1")
+        );
+        let received = server.await.unwrap();
+        assert!(
+            received.starts_with("get /key?versionid=v1 http/1.1\r\n"),
+            "{}",
+            received
+        );
+        assert!(
+            received.contains("\r\nhost: bucket.s3.amazonaws.com\r\n"),
+            "{}",
+            received
+        );
+        assert!(
+            received.contains(&format!(
+                "\r\nauthorization: {}\r\n",
+                authorization.to_lowercase()
+            )),
+            "{}",
+            received
+        );
+    }
+
+    async fn exchange_with_raw_hook(show_credentials: bool) -> (Vec<String>, HttpResponse) {
+        use std::sync::Mutex;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let region = Region::Custom {
+            name: "local".to_owned(),
+            endpoint: format!("http://{}", listener.local_addr().unwrap()),
+        };
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 4096];
+            let _ = socket.read(&mut buf).await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 404 Not Found\r\ncontent-length: 9\r\nx-amz-request-id: abc\r\n\r\nNoSuchKey")
+                .await
+                .unwrap();
+        });
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut client = HttpClient::from_connector(HttpConnector::new());
+        let hook_seen = seen.clone();
+        client.on_raw_exchange(move |parts, body| {
+            let line = match parts {
+                RawParts::Request(parts) => format!(
+                    "{} {} {:?} {:?}",
+                    parts.method,
+                    parts.uri.path(),
+                    parts.headers.get("authorization"),
+                    body
+                ),
+                RawParts::Response(parts) => format!(
+                    "{} {:?} {:?}",
+                    parts.status,
+                    parts.headers.get("x-amz-request-id"),
+                    body
+                ),
+            };
+            hook_seen.lock().unwrap().push(line);
+        });
+        client.unsafe_show_credentials_in_raw_exchange(show_credentials);
+
+        let mut request = SignedRequest::new("PUT", "s3", &region, "/bucket/key");
+        request.add_header("authorization", "AWS4-HMAC-SHA256 Signature=secret");
+        request.set_payload(Some("hello"));
+        let response = client.dispatch(request, None).await.unwrap();
+        let seen = seen.lock().unwrap().clone();
+        (seen, response)
+    }
+
+    #[tokio::test]
+    async fn raw_exchange_hook_sees_redacted_requests_and_responses() {
+        let (seen, mut response) = exchange_with_raw_hook(false).await;
+        assert_eq!(
+            seen,
+            vec![
+                r#"PUT /bucket/key Some("<redacted>") b"hello""#.to_owned(),
+                r#"404 Not Found Some("abc") b"NoSuchKey""#.to_owned(),
+            ]
+        );
+
+        // the body is still there for rusoto to parse
+        let response = response.buffer().await.unwrap();
+        assert_eq!(response.status, StatusCode::NOT_FOUND);
+        assert_eq!(response.body_as_str(), "NoSuchKey");
+    }
+
+    #[tokio::test]
+    async fn raw_exchange_hook_can_show_credentials() {
+        let (seen, _) = exchange_with_raw_hook(true).await;
+        assert_eq!(
+            seen[0],
+            r#"PUT /bucket/key Some("AWS4-HMAC-SHA256 Signature=secret") b"hello""#
+        );
+    }
+
     #[test]
     fn from_io_error_preserves_error_message() {
         let io_error = ::std::io::Error::new(::std::io::ErrorKind::Other, "my error message");
@@ -553,6 +1416,35 @@ mod tests {
         assert_eq!(error.to_string(), "my error message")
     }
 
+    #[test]
+    fn from_boxed_error_includes_sources() {
+        #[derive(Debug)]
+        struct ConnectError(::std::io::Error);
+
+        impl fmt::Display for ConnectError {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("error trying to connect")
+            }
+        }
+
+        impl Error for ConnectError {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let io_error = ::std::io::Error::new(::std::io::ErrorKind::Other, "connection refused");
+        let boxed: Box<dyn Error + Send + Sync> = Box::new(ConnectError(io_error));
+        assert_eq!(
+            HttpDispatchError::from(boxed).to_string(),
+            "error trying to connect: connection refused"
+        );
+        assert_eq!(
+            HttpDispatchError::new("timed out"),
+            HttpDispatchError::new("timed out".to_owned())
+        );
+    }
+
     #[test]
     fn building_user_agents() {
         let base = format!("{}", *DEFAULT_USER_AGENT);
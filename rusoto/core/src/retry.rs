@@ -0,0 +1,676 @@
+//! Retrying requests that failed with transient errors.
+
+use std::env;
+use std::fmt;
+use std::future::Future;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use http::StatusCode;
+use log::warn;
+use serde_json::Value;
+use xml::reader::{EventReader, XmlEvent};
+
+use crate::client::SignAndDispatchError;
+use crate::request::{BufferedHttpResponse, HttpResponse};
+use crate::{ByteStream, RusotoError};
+
+/// How failed requests are retried, named after the retry modes of the other AWS SDKs.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RetryMode {
+    /// Makes up to 5 attempts by default.
+    Legacy,
+    /// Makes up to 3 attempts by default.
+    #[default]
+    Standard,
+    /// Like `Standard`, and also limits the request rate on the client side while the
+    /// service is throttling requests.
+    Adaptive,
+}
+
+impl RetryMode {
+    /// The maximum number of attempts per request used in this mode unless configured
+    /// otherwise.
+    pub fn default_max_attempts(self) -> u32 {
+        match self {
+            RetryMode::Legacy => 5,
+            RetryMode::Standard | RetryMode::Adaptive => 3,
+        }
+    }
+}
+
+impl FromStr for RetryMode {
+    type Err = ParseRetryModeError;
+
+    fn from_str(s: &str) -> Result<RetryMode, ParseRetryModeError> {
+        match s.to_ascii_lowercase().as_str() {
+            "legacy" => Ok(RetryMode::Legacy),
+            "standard" => Ok(RetryMode::Standard),
+            "adaptive" => Ok(RetryMode::Adaptive),
+            _ => Err(ParseRetryModeError { mode: s.to_owned() }),
+        }
+    }
+}
+
+/// An error produced when parsing an unknown retry mode.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseRetryModeError {
+    mode: String,
+}
+
+impl std::error::Error for ParseRetryModeError {}
+
+impl fmt::Display for ParseRetryModeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Unknown retry mode {:?}, expected legacy, standard or adaptive",
+            self.mode
+        )
+    }
+}
+
+/// Decides whether, and after how long, a failed request is sent again.
+///
+/// Connection errors, timeouts, throttling errors and responses with status 429, 500,
+/// 502, 503 or 504 are retried, waiting a random delay of up to 100ms, 200ms, 400ms...
+/// capped at 20 seconds between attempts. Requests with a streaming payload are never
+/// retried, because the stream can't be read again.
+///
+/// Clients read their policy from the environment with `RetryPolicy::from_env`. Use
+/// `Client::with_retry_policy` to configure it in code instead.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    mode: RetryMode,
+    max_attempts: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new(RetryMode::default())
+    }
+}
+
+const BASE_DELAY: Duration = Duration::from_millis(100);
+const MAX_BACKOFF: Duration = Duration::from_secs(20);
+
+impl RetryPolicy {
+    /// Creates a policy for the given mode, with its default maximum number of attempts.
+    pub fn new(mode: RetryMode) -> Self {
+        RetryPolicy {
+            mode,
+            max_attempts: mode.default_max_attempts(),
+        }
+    }
+
+    /// Creates a policy that sends every request only once.
+    pub fn never() -> Self {
+        RetryPolicy::default().with_max_attempts(1)
+    }
+
+    /// Creates a policy from the `AWS_RETRY_MODE` and `AWS_MAX_ATTEMPTS` environment
+    /// variables, as used by the other AWS SDKs and the AWS CLI.
+    ///
+    /// Unset variables fall back to the `standard` mode and that mode's default number
+    /// of attempts. Invalid values are logged and ignored.
+    pub fn from_env() -> Self {
+        RetryPolicy::from_lookup(|name| env::var(name).ok())
+    }
+
+    fn from_lookup<F>(lookup: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let mode = match lookup("AWS_RETRY_MODE").filter(|mode| !mode.is_empty()) {
+            Some(mode) => mode.parse().unwrap_or_else(|err| {
+                warn!("Ignoring AWS_RETRY_MODE: {}", err);
+                RetryMode::default()
+            }),
+            None => RetryMode::default(),
+        };
+        let mut policy = RetryPolicy::new(mode);
+        if let Some(max_attempts) = lookup("AWS_MAX_ATTEMPTS").filter(|value| !value.is_empty()) {
+            match max_attempts.parse::<u32>() {
+                Ok(max_attempts) if max_attempts > 0 => policy.max_attempts = max_attempts,
+                _ => warn!(
+                    "Ignoring AWS_MAX_ATTEMPTS: {:?} is not a positive integer",
+                    max_attempts
+                ),
+            }
+        }
+        policy
+    }
+
+    /// Sets the maximum number of attempts per request, including the first one.
+    /// Values below 1 are treated as 1.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// The retry mode.
+    pub fn mode(&self) -> RetryMode {
+        self.mode
+    }
+
+    /// The maximum number of attempts per request, including the first one.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// The delay before the given retry, where `attempt` is the number of attempts
+    /// made so far.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let ceiling = (BASE_DELAY * 2u32.pow(exponent)).min(MAX_BACKOFF);
+        ceiling.mul_f64(rand::random::<f64>())
+    }
+
+    /// Runs `operation` until it succeeds, fails with an error that retrying won't fix,
+    /// or the policy runs out of attempts, waiting between attempts like the client
+    /// does between requests.
+    ///
+    /// This is for retrying steps made of more than a request, e.g. a page of a listing
+    /// whose body was cut off while it was read, which the client can't retry itself.
+    /// Errors are retried if they are transient as described above.
+    pub async fn retry<T, E, F, Fut>(&self, mut operation: F) -> Result<T, RusotoError<E>>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, RusotoError<E>>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match operation().await {
+                Err(ref err) if attempt < self.max_attempts && is_transient(err) => {
+                    tokio::time::sleep(self.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Whether an operation failed with an error that may go away when it is retried.
+fn is_transient<E>(err: &RusotoError<E>) -> bool {
+    match err {
+        RusotoError::HttpDispatch(_) => true,
+        RusotoError::Unknown(response) => outcome(response).is_retryable(),
+        _ => false,
+    }
+}
+
+/// What a request attempt resulted in, as far as retrying is concerned.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Outcome {
+    /// The request succeeded, or failed in a way that retrying won't fix.
+    Done,
+    /// The request failed with a transient error, e.g. a dropped connection.
+    TransientError,
+    /// The service rejected the request because too many are being sent.
+    Throttled,
+    /// The service rejected the credentials the request was signed with as expired.
+    /// Retrying only helps after refreshing them, see `Client::sign_and_dispatch`.
+    ExpiredCredentials,
+}
+
+impl Outcome {
+    pub(crate) fn is_retryable(self) -> bool {
+        self == Outcome::TransientError || self == Outcome::Throttled
+    }
+}
+
+/// Error codes AWS services use for throttling, whatever the status code.
+const THROTTLING_ERROR_CODES: [&str; 14] = [
+    "Throttling",
+    "ThrottlingException",
+    "ThrottledException",
+    "RequestThrottledException",
+    "TooManyRequestsException",
+    "ProvisionedThroughputExceededException",
+    "TransactionInProgressException",
+    "RequestLimitExceeded",
+    "BandwidthLimitExceeded",
+    "LimitExceededException",
+    "RequestThrottled",
+    "SlowDown",
+    "PriorRequestNotComplete",
+    "EC2ThrottledException",
+];
+
+/// Error codes AWS services use when the credentials a request was signed with have
+/// expired, or have been rotated out. Other authorization failures, like `AccessDenied`,
+/// won't be fixed by new credentials.
+const EXPIRED_CREDENTIALS_ERROR_CODES: [&str; 4] = [
+    "ExpiredToken",
+    "ExpiredTokenException",
+    "InvalidAccessKeyId",
+    "InvalidClientTokenId",
+];
+
+/// Classifies the result of an attempt. The bodies of error responses are buffered to
+/// look for throttling error codes, so the returned response may differ from the given
+/// one, but has the same contents.
+pub(crate) async fn classify(
+    result: Result<HttpResponse, SignAndDispatchError>,
+) -> (Result<HttpResponse, SignAndDispatchError>, Outcome) {
+    let mut response = match result {
+        Ok(response) if response.status.is_client_error() || response.status.is_server_error() => {
+            response
+        }
+        Ok(response) => return (Ok(response), Outcome::Done),
+        Err(SignAndDispatchError::Dispatch(err)) => {
+            return (
+                Err(SignAndDispatchError::Dispatch(err)),
+                Outcome::TransientError,
+            )
+        }
+        Err(err) => return (Err(err), Outcome::Done),
+    };
+    let buffered = match response.buffer().await {
+        Ok(buffered) => buffered,
+        Err(err) => {
+            return (
+                Err(SignAndDispatchError::Dispatch(err)),
+                Outcome::TransientError,
+            )
+        }
+    };
+
+    let outcome = outcome(&buffered);
+    let response = HttpResponse {
+        status: buffered.status,
+        body: ByteStream::from(buffered.body.to_vec()),
+        headers: buffered.headers,
+    };
+    (Ok(response), outcome)
+}
+
+/// Classifies an error response.
+fn outcome(response: &BufferedHttpResponse) -> Outcome {
+    let code = error_code(response);
+    let has_code = |codes: &[&str]| matches!(code.as_deref(), Some(code) if codes.contains(&code));
+    let throttled =
+        response.status == StatusCode::TOO_MANY_REQUESTS || has_code(&THROTTLING_ERROR_CODES);
+    if throttled {
+        Outcome::Throttled
+    } else if has_code(&EXPIRED_CREDENTIALS_ERROR_CODES) {
+        Outcome::ExpiredCredentials
+    } else {
+        match response.status {
+            StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT => Outcome::TransientError,
+            _ => Outcome::Done,
+        }
+    }
+}
+
+/// Best effort extraction of the error code of a JSON or XML error response.
+pub(crate) fn error_code(response: &BufferedHttpResponse) -> Option<String> {
+    if let Some(error_type) = response.headers.get("x-amzn-errortype") {
+        return error_type.split(':').next().map(str::to_owned);
+    }
+
+    if let Ok(json) = serde_json::from_slice::<Value>(&response.body) {
+        return json
+            .get("__type")
+            .or_else(|| json.get("code"))
+            .or_else(|| json.get("Code"))
+            .and_then(Value::as_str)
+            .and_then(|code| code.rsplit('#').next())
+            .map(str::to_owned);
+    }
+
+    // XML errors have a `Code` element, nested differently depending on the protocol.
+    let mut in_code = false;
+    for event in EventReader::new(response.body.as_ref()) {
+        match event {
+            Ok(XmlEvent::StartElement { ref name, .. }) => in_code = name.local_name == "Code",
+            Ok(XmlEvent::Characters(code)) if in_code => return Some(code),
+            Ok(XmlEvent::EndElement { .. }) => in_code = false,
+            Err(_) => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Client-side rate limiting for the adaptive retry mode.
+///
+/// Requests take a token from a bucket before being sent. The bucket is only enforced
+/// once the service has throttled a request. From then on its fill rate follows the
+/// CUBIC algorithm used by the other AWS SDKs: it drops to 70% of the measured request
+/// rate on each throttling response, and grows back while requests succeed.
+pub(crate) struct AdaptiveRateLimiter {
+    start: Instant,
+    state: Mutex<RateLimiterState>,
+}
+
+struct RateLimiterState {
+    enabled: bool,
+    fill_rate: f64,
+    max_capacity: f64,
+    capacity: f64,
+    last_refill: f64,
+    measured_rate: f64,
+    last_rate_bucket: f64,
+    request_count: u32,
+    last_max_rate: f64,
+    last_throttle: f64,
+    time_window: f64,
+}
+
+const MIN_FILL_RATE: f64 = 0.5;
+const MIN_CAPACITY: f64 = 1.0;
+const SMOOTH: f64 = 0.8;
+const BETA: f64 = 0.7;
+const SCALE_CONSTANT: f64 = 0.4;
+
+impl AdaptiveRateLimiter {
+    pub(crate) fn new() -> Self {
+        AdaptiveRateLimiter {
+            start: Instant::now(),
+            state: Mutex::new(RateLimiterState {
+                enabled: false,
+                fill_rate: MIN_FILL_RATE,
+                max_capacity: MIN_CAPACITY,
+                capacity: MIN_CAPACITY,
+                last_refill: 0.0,
+                measured_rate: 0.0,
+                last_rate_bucket: 0.0,
+                request_count: 0,
+                last_max_rate: 0.0,
+                last_throttle: 0.0,
+                time_window: 0.0,
+            }),
+        }
+    }
+
+    fn now(&self) -> f64 {
+        self.start.elapsed().as_secs_f64()
+    }
+
+    /// Takes a token for the next request, returning how long to wait before sending it.
+    pub(crate) fn acquire(&self) -> Duration {
+        let now = self.now();
+        self.state.lock().unwrap().acquire(now)
+    }
+
+    #[cfg(test)]
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.state.lock().unwrap().enabled
+    }
+
+    /// Adjusts the rate after a response was received.
+    pub(crate) fn update(&self, throttled: bool) {
+        let now = self.now();
+        self.state.lock().unwrap().update(throttled, now)
+    }
+}
+
+impl RateLimiterState {
+    fn acquire(&mut self, now: f64) -> Duration {
+        if !self.enabled {
+            return Duration::from_secs(0);
+        }
+        self.refill(now);
+        // The token is taken right away, so requests waiting concurrently queue up
+        // behind each other instead of all waking up for the same token.
+        self.capacity -= 1.0;
+        if self.capacity >= 0.0 {
+            Duration::from_secs(0)
+        } else {
+            Duration::from_secs_f64(-self.capacity / self.fill_rate)
+        }
+    }
+
+    fn update(&mut self, throttled: bool, now: f64) {
+        self.update_measured_rate(now);
+        let rate = if throttled {
+            let rate = if self.enabled {
+                self.measured_rate.min(self.fill_rate)
+            } else {
+                self.measured_rate
+            };
+            self.last_max_rate = rate;
+            self.update_time_window();
+            self.last_throttle = now;
+            self.enabled = true;
+            rate * BETA
+        } else {
+            self.update_time_window();
+            let elapsed = now - self.last_throttle;
+            SCALE_CONSTANT * (elapsed - self.time_window).powi(3) + self.last_max_rate
+        };
+        self.set_fill_rate(rate.min(2.0 * self.measured_rate), now);
+    }
+
+    fn refill(&mut self, now: f64) {
+        let elapsed = now - self.last_refill;
+        self.capacity = (self.capacity + elapsed * self.fill_rate).min(self.max_capacity);
+        self.last_refill = now;
+    }
+
+    fn set_fill_rate(&mut self, rate: f64, now: f64) {
+        self.refill(now);
+        self.fill_rate = rate.max(MIN_FILL_RATE);
+        self.max_capacity = rate.max(MIN_CAPACITY);
+        self.capacity = self.capacity.min(self.max_capacity);
+    }
+
+    fn update_time_window(&mut self) {
+        self.time_window = (self.last_max_rate * (1.0 - BETA) / SCALE_CONSTANT).cbrt();
+    }
+
+    fn update_measured_rate(&mut self, now: f64) {
+        // Requests are counted in half-second buckets.
+        let bucket = (now * 2.0).floor() / 2.0;
+        self.request_count += 1;
+        if bucket > self.last_rate_bucket {
+            let rate = f64::from(self.request_count) / (bucket - self.last_rate_bucket);
+            self.measured_rate = rate * SMOOTH + self.measured_rate * (1.0 - SMOOTH);
+            self.request_count = 0;
+            self.last_rate_bucket = bucket;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy_from(vars: &[(&str, &str)]) -> RetryPolicy {
+        RetryPolicy::from_lookup(|name| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn policy_from_environment() {
+        assert_eq!(policy_from(&[]), RetryPolicy::new(RetryMode::Standard));
+        assert_eq!(policy_from(&[]).max_attempts(), 3);
+
+        let policy = policy_from(&[("AWS_RETRY_MODE", "legacy")]);
+        assert_eq!(policy.mode(), RetryMode::Legacy);
+        assert_eq!(policy.max_attempts(), 5);
+
+        let policy = policy_from(&[("AWS_RETRY_MODE", "Adaptive"), ("AWS_MAX_ATTEMPTS", "7")]);
+        assert_eq!(policy.mode(), RetryMode::Adaptive);
+        assert_eq!(policy.max_attempts(), 7);
+
+        let policy = policy_from(&[("AWS_RETRY_MODE", "eager"), ("AWS_MAX_ATTEMPTS", "0")]);
+        assert_eq!(policy, RetryPolicy::new(RetryMode::Standard));
+    }
+
+    fn buffered(status: u16, headers: &[(&'static str, &str)], body: &str) -> BufferedHttpResponse {
+        let mut header_map = http::HeaderMap::default();
+        for (name, value) in headers {
+            header_map.insert(*name, value.to_string());
+        }
+        BufferedHttpResponse {
+            status: StatusCode::from_u16(status).unwrap(),
+            body: body.to_owned().into(),
+            headers: header_map,
+        }
+    }
+
+    #[test]
+    fn error_codes() {
+        let json = r#"{"__type":"com.amazonaws.dynamodb.v20120810#ProvisionedThroughputExceededException","message":"Slow down"}"#;
+        assert_eq!(
+            error_code(&buffered(400, &[], json)).as_deref(),
+            Some("ProvisionedThroughputExceededException")
+        );
+
+        let xml = r#"<ErrorResponse><Error><Type>Sender</Type><Code>Throttling</Code><Message>Rate exceeded</Message></Error></ErrorResponse>"#;
+        assert_eq!(
+            error_code(&buffered(400, &[], xml)).as_deref(),
+            Some("Throttling")
+        );
+
+        let header = [(
+            "x-amzn-errortype",
+            "TooManyRequestsException:http://internal.amazon.com/",
+        )];
+        assert_eq!(
+            error_code(&buffered(429, &header, "")).as_deref(),
+            Some("TooManyRequestsException")
+        );
+
+        assert_eq!(error_code(&buffered(500, &[], "")), None);
+    }
+
+    #[tokio::test]
+    async fn classify_keeps_the_error_body() {
+        let body = "<Error><Code>SlowDown</Code></Error>";
+        let response = HttpResponse {
+            status: StatusCode::SERVICE_UNAVAILABLE,
+            body: ByteStream::from(body.as_bytes().to_vec()),
+            headers: http::HeaderMap::default(),
+        };
+        let (result, outcome) = classify(Ok(response)).await;
+        assert_eq!(outcome, Outcome::Throttled);
+        let buffered = result.unwrap().buffer().await.unwrap();
+        assert_eq!(buffered.body_as_str(), body);
+
+        let response = HttpResponse {
+            status: StatusCode::BAD_REQUEST,
+            body: ByteStream::from(b"<Error><Code>InvalidArgument</Code></Error>".to_vec()),
+            headers: http::HeaderMap::default(),
+        };
+        assert_eq!(classify(Ok(response)).await.1, Outcome::Done);
+
+        let response = HttpResponse {
+            status: StatusCode::BAD_REQUEST,
+            body: ByteStream::from(b"<Error><Code>ExpiredToken</Code></Error>".to_vec()),
+            headers: http::HeaderMap::default(),
+        };
+        let outcome = classify(Ok(response)).await.1;
+        assert_eq!(outcome, Outcome::ExpiredCredentials);
+        assert!(!outcome.is_retryable());
+
+        let response = HttpResponse {
+            status: StatusCode::FORBIDDEN,
+            body: ByteStream::from(b"<Error><Code>AccessDenied</Code></Error>".to_vec()),
+            headers: http::HeaderMap::default(),
+        };
+        assert_eq!(classify(Ok(response)).await.1, Outcome::Done);
+    }
+
+    #[test]
+    fn rate_limiter_is_disabled_until_throttled() {
+        let mut state = AdaptiveRateLimiter::new().state.into_inner().unwrap();
+        for i in 0..100 {
+            let now = f64::from(i) * 0.01;
+            assert_eq!(state.acquire(now), Duration::from_secs(0));
+            state.update(false, now);
+        }
+        assert!(!state.enabled);
+    }
+
+    #[test]
+    fn rate_limiter_backs_off_on_throttling_and_recovers() {
+        let mut state = AdaptiveRateLimiter::new().state.into_inner().unwrap();
+        // 10 requests per second, the last one throttled.
+        for i in 1..=20 {
+            let now = f64::from(i) * 0.1;
+            state.acquire(now);
+            state.update(false, now);
+        }
+        let measured = state.measured_rate;
+        assert!(measured > 5.0, "{}", measured);
+        state.update(true, 2.05);
+        assert!(state.enabled);
+        assert!((state.fill_rate - measured * BETA).abs() < 1e-9);
+        let throttled_rate = state.fill_rate;
+
+        // Sending faster than the fill rate has to wait.
+        let waits: Vec<Duration> = (0..20).map(|_| state.acquire(2.1)).collect();
+        assert!(waits.last().unwrap() > &Duration::from_secs(1));
+
+        // Successes grow the rate back, capped at twice the measured rate.
+        let mut now = 2.1;
+        for _ in 0..40 {
+            now += 0.1;
+            state.update(false, now);
+        }
+        assert!(state.fill_rate > throttled_rate);
+        assert!(state.fill_rate <= 2.0 * state.measured_rate + 1e-9);
+    }
+
+    #[tokio::test]
+    async fn retry_repeats_operations_that_fail_transiently() {
+        use crate::request::HttpDispatchError;
+        use std::cell::Cell;
+
+        let attempts = Cell::new(0);
+        let fail_with = |errors: Vec<RusotoError<()>>| {
+            attempts.set(0);
+            let errors = std::cell::RefCell::new(errors.into_iter());
+            let attempts = &attempts;
+            async move {
+                RetryPolicy::new(RetryMode::Standard)
+                    .retry(|| {
+                        attempts.set(attempts.get() + 1);
+                        let next = errors.borrow_mut().next();
+                        async move { next.map_or(Ok(attempts.get()), Err) }
+                    })
+                    .await
+            }
+        };
+
+        let dropped = || RusotoError::HttpDispatch(HttpDispatchError::new("connection reset"));
+        assert_eq!(fail_with(vec![dropped()]).await, Ok(2));
+        assert_eq!(attempts.get(), 2);
+        let unavailable = || RusotoError::Unknown(buffered(503, &[], ""));
+        assert_eq!(fail_with(vec![unavailable(), dropped()]).await, Ok(3));
+
+        // Standard mode makes three attempts.
+        let result = fail_with(vec![dropped(), dropped(), unavailable(), dropped()]).await;
+        assert_eq!(result, Err(unavailable()));
+        assert_eq!(attempts.get(), 3);
+
+        let permanent: [fn() -> RusotoError<()>; 3] = [
+            || RusotoError::Service(()),
+            || RusotoError::Unknown(buffered(403, &[], "")),
+            || RusotoError::ParseError("invalid XML".to_owned()),
+        ];
+        for permanent in &permanent {
+            assert_eq!(fail_with(vec![permanent()]).await, Err(permanent()));
+            assert_eq!(attempts.get(), 1);
+        }
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let policy = RetryPolicy::default();
+        assert!(policy.backoff(1) <= BASE_DELAY);
+        assert!(policy.backoff(3) <= BASE_DELAY * 4);
+        assert!(policy.backoff(u32::MAX) <= MAX_BACKOFF);
+    }
+}
@@ -0,0 +1,33 @@
+//! The source of the current time for signing requests and expiring credentials.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+/// Tells the current time.
+///
+/// `AutoRefreshingProvider` uses a clock to decide when its cached credentials have
+/// expired, and `rusoto_core::Client` uses one to date the requests it signs. Both
+/// default to `SystemClock`; tests can substitute a clock they control, e.g. to expire
+/// credentials without waiting.
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The system clock, i.e. `Utc::now()`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
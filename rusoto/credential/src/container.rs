@@ -8,7 +8,7 @@ use hyper::{Body, Request};
 use crate::request::HttpClient;
 use crate::{
     non_empty_env_var, parse_credentials_from_aws_service, AwsCredentials, CredentialsError,
-    ProvideAwsCredentials,
+    CredentialsErrorKind, ProvideAwsCredentials,
 };
 
 // The following constants are documented in AWS' ECS developers guide,
@@ -76,21 +76,21 @@ impl Default for ContainerProvider {
 #[async_trait]
 impl ProvideAwsCredentials for ContainerProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        let req = request_from_env_vars().map_err(|err| CredentialsError {
-            message: format!(
-                "Could not get request from environment: {}",
-                err.to_string()
-            ),
+        let req = request_from_env_vars().map_err(|err| {
+            CredentialsError::with_kind(
+                err.kind(),
+                format!("Could not get request from environment: {}", err),
+            )
         })?;
         let resp = self
             .client
             .request(req, self.timeout)
             .await
-            .map_err(|err| CredentialsError {
-                message: format!(
-                    "Could not get credentials from container: {}",
-                    err.to_string()
-                ),
+            .map_err(|err| {
+                CredentialsError::with_kind(
+                    err.kind(),
+                    format!("Could not get credentials from container: {}", err),
+                )
             })?;
         parse_credentials_from_aws_service(&resp)
     }
@@ -110,29 +110,35 @@ fn request_from_env_vars() -> Result<Request<Body>, CredentialsError> {
                             request.headers_mut().insert("authorization", parsed_token);
                         }
                         Err(err) => {
-                            return Err(CredentialsError::new(format!(
-                                "failed to parse token: {}",
-                                err
-                            )));
+                            return Err(CredentialsError::with_kind(
+                                CredentialsErrorKind::Parse,
+                                format!("failed to parse token: {}", err),
+                            ));
                         }
                     }
                 }
                 Ok(request)
             }
-            None => Err(CredentialsError::new(format!(
-                "Neither environment variable '{}' nor '{}' is set",
-                AWS_CONTAINER_CREDENTIALS_FULL_URI, AWS_CONTAINER_CREDENTIALS_RELATIVE_URI
-            ))),
+            None => Err(CredentialsError::with_kind(
+                CredentialsErrorKind::NotFound,
+                format!(
+                    "Neither environment variable '{}' nor '{}' is set",
+                    AWS_CONTAINER_CREDENTIALS_FULL_URI, AWS_CONTAINER_CREDENTIALS_RELATIVE_URI
+                ),
+            )),
         },
     }
 }
 
 fn new_request(uri: &str, env_var_name: &str) -> Result<Request<Body>, CredentialsError> {
     Request::get(uri).body(Body::empty()).map_err(|error| {
-        CredentialsError::new(format!(
-            "Error while parsing URI '{}' derived from environment variable '{}': {}",
-            uri, env_var_name, error
-        ))
+        CredentialsError::with_kind(
+            CredentialsErrorKind::Parse,
+            format!(
+                "Error while parsing URI '{}' derived from environment variable '{}': {}",
+                uri, env_var_name, error
+            ),
+        )
     })
 }
 
@@ -2,7 +2,10 @@
 use async_trait::async_trait;
 use chrono::{DateTime, FixedOffset, Utc};
 
-use crate::{non_empty_env_var, AwsCredentials, CredentialsError, ProvideAwsCredentials};
+use crate::{
+    non_empty_env_var, AwsCredentials, CredentialsError, CredentialsErrorKind,
+    ProvideAwsCredentials,
+};
 
 /// Provides AWS credentials from environment variables.
 ///
@@ -139,10 +142,10 @@ impl ProvideAwsCredentials for EnvironmentProvider {
                 DateTime::<FixedOffset>::parse_from_rfc3339(&val)
                     .map(|dt| dt.with_timezone(&Utc))
                     .map_err(|e| {
-                        CredentialsError::new(format!(
-                            "Invalid {} in environment '{}': {}",
-                            var_name, val, e
-                        ))
+                        CredentialsError::with_kind(
+                            CredentialsErrorKind::Parse,
+                            format!("Invalid {} in environment '{}': {}", var_name, val, e),
+                        )
                     })?,
             ),
             _ => None,
@@ -153,8 +156,12 @@ impl ProvideAwsCredentials for EnvironmentProvider {
 
 /// Force an error if we do not see the particular variable name in the env.
 fn get_critical_variable(var_name: String) -> Result<String, CredentialsError> {
-    non_empty_env_var(&var_name)
-        .ok_or_else(|| CredentialsError::new(format!("No (or empty) {} in environment", var_name)))
+    non_empty_env_var(&var_name).ok_or_else(|| {
+        CredentialsError::with_kind(
+            CredentialsErrorKind::NotFound,
+            format!("No (or empty) {} in environment", var_name),
+        )
+    })
 }
 
 #[cfg(test)]
@@ -217,7 +224,10 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.err(),
-            Some(CredentialsError::new(E_NO_ACCESS_KEY_ID))
+            Some(CredentialsError::with_kind(
+                CredentialsErrorKind::NotFound,
+                E_NO_ACCESS_KEY_ID
+            ))
         );
     }
 
@@ -232,7 +242,10 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.err(),
-            Some(CredentialsError::new(E_NO_SECRET_ACCESS_KEY))
+            Some(CredentialsError::with_kind(
+                CredentialsErrorKind::NotFound,
+                E_NO_SECRET_ACCESS_KEY
+            ))
         );
     }
 
@@ -246,7 +259,10 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.err(),
-            Some(CredentialsError::new(E_NO_ACCESS_KEY_ID))
+            Some(CredentialsError::with_kind(
+                CredentialsErrorKind::NotFound,
+                E_NO_ACCESS_KEY_ID
+            ))
         );
     }
 
@@ -264,7 +280,10 @@ mod tests {
         env::remove_var(AWS_CREDENTIAL_EXPIRATION);
         assert!(result.is_err());
         assert!(match &result.err() {
-            &Some(CredentialsError { ref message }) => message.starts_with(E_INVALID_EXPIRATION),
+            &Some(CredentialsError {
+                ref message,
+                kind: CredentialsErrorKind::Parse,
+            }) => message.starts_with(E_INVALID_EXPIRATION),
             _ => false,
         });
     }
@@ -6,11 +6,13 @@ use std::time::Duration;
 
 use crate::request::HttpClient;
 use crate::{
-    parse_credentials_from_aws_service, AwsCredentials, CredentialsError, ProvideAwsCredentials,
+    parse_credentials_from_aws_service, AwsCredentials, CredentialsError, CredentialsErrorKind,
+    ProvideAwsCredentials,
 };
 
 const AWS_CREDENTIALS_PROVIDER_IP: &str = "169.254.169.254";
 const AWS_CREDENTIALS_PROVIDER_PATH: &str = "latest/meta-data/iam/security-credentials";
+const AWS_REGION_PATH: &str = "latest/meta-data/placement/region";
 
 /// Provides AWS credentials from a resource's IAM role.
 ///
@@ -66,6 +68,26 @@ impl InstanceMetadataProvider {
     pub fn set_ip_addr_with_port(&mut self, ip: &str, port: &str) {
         self.metadata_ip_addr = format!("{}:{}", ip, port);
     }
+
+    /// Get the region of the instance, e.g. `us-east-1`, from the instance metadata
+    /// service.
+    ///
+    /// For the full region resolution chain, use `rusoto_core::Region::from_env_or_instance_metadata`.
+    pub async fn region(&self) -> Result<String, CredentialsError> {
+        let region_address = format!("http://{}/{}", self.metadata_ip_addr, AWS_REGION_PATH);
+        let uri = match region_address.parse::<Uri>() {
+            Ok(u) => u,
+            Err(e) => return Err(CredentialsError::with_kind(CredentialsErrorKind::Parse, e)),
+        };
+
+        let region = self.client.get(uri, self.timeout).await.map_err(|err| {
+            CredentialsError::with_kind(
+                err.kind(),
+                format!("Could not get region from instance metadata: {}", err),
+            )
+        })?;
+        Ok(region.trim().to_owned())
+    }
 }
 
 impl Default for InstanceMetadataProvider {
@@ -79,8 +101,11 @@ impl ProvideAwsCredentials for InstanceMetadataProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
         let role_name = get_role_name(&self.client, self.timeout, &self.metadata_ip_addr)
             .await
-            .map_err(|err| CredentialsError {
-                message: format!("Could not get credentials from iam: {}", err.to_string()),
+            .map_err(|err| {
+                CredentialsError::with_kind(
+                    err.kind(),
+                    format!("Could not get credentials from iam: {}", err),
+                )
             })?;
 
         let cred_str = get_credentials_from_role(
@@ -90,8 +115,11 @@ impl ProvideAwsCredentials for InstanceMetadataProvider {
             &self.metadata_ip_addr,
         )
         .await
-        .map_err(|err| CredentialsError {
-            message: format!("Could not get credentials from iam: {}", err.to_string()),
+        .map_err(|err| {
+            CredentialsError::with_kind(
+                err.kind(),
+                format!("Could not get credentials from iam: {}", err),
+            )
         })?;
 
         parse_credentials_from_aws_service(&cred_str)
@@ -107,10 +135,10 @@ async fn get_role_name(
     let role_name_address = format!("http://{}/{}/", ip_addr, AWS_CREDENTIALS_PROVIDER_PATH);
     let uri = match role_name_address.parse::<Uri>() {
         Ok(u) => u,
-        Err(e) => return Err(CredentialsError::new(e)),
+        Err(e) => return Err(CredentialsError::with_kind(CredentialsErrorKind::Parse, e)),
     };
 
-    Ok(client.get(uri, timeout).await?)
+    client.get(uri, timeout).await
 }
 
 /// Gets the credentials for an EC2 Instances IAM Role.
@@ -127,8 +155,8 @@ async fn get_credentials_from_role(
 
     let uri = match credentials_provider_url.parse::<Uri>() {
         Ok(u) => u,
-        Err(e) => return Err(CredentialsError::new(e)),
+        Err(e) => return Err(CredentialsError::with_kind(CredentialsErrorKind::Parse, e)),
     };
 
-    Ok(client.get(uri, timeout).await?)
+    client.get(uri, timeout).await
 }
@@ -7,21 +7,27 @@
 
 //! Types for loading and managing AWS access credentials for API requests.
 
+pub use crate::clock::{Clock, SystemClock};
 pub use crate::container::ContainerProvider;
 pub use crate::environment::EnvironmentProvider;
 pub use crate::instance_metadata::InstanceMetadataProvider;
 pub use crate::profile::ProfileProvider;
+pub use crate::secret_source::{SecretSource, SecretSourceProvider, SourcedCredentials};
 pub use crate::secrets::Secret;
+pub use crate::shared_provider::SharedCredentialsProvider;
 pub use crate::static_provider::StaticProvider;
 pub use crate::variable::Variable;
 
 pub mod claims;
+mod clock;
 mod container;
 mod environment;
 mod instance_metadata;
 mod profile;
 mod request;
+mod secret_source;
 mod secrets;
+mod shared_provider;
 mod static_provider;
 #[cfg(test)]
 pub(crate) mod test_utils;
@@ -32,7 +38,7 @@ use std::collections::BTreeMap;
 use std::env::{var as env_var, VarError};
 use std::error::Error;
 use std::fmt;
-use std::io::Error as IoError;
+use std::io::{Error as IoError, ErrorKind as IoErrorKind};
 use std::string::FromUtf8Error;
 use std::sync::Arc;
 use std::time::Duration;
@@ -118,14 +124,14 @@ impl AwsCredentials {
         &self.token
     }
 
-    /// Determine whether or not the credentials are expired.
-    fn credentials_are_expired(&self) -> bool {
+    /// Determine whether or not the credentials are expired at `now`.
+    fn credentials_are_expired(&self, now: DateTime<Utc>) -> bool {
         match self.expires_at {
             Some(ref e) =>
             // This is a rough hack to hopefully avoid someone requesting creds then sitting on them
             // before issuing the request:
             {
-                *e < Utc::now() + ChronoDuration::seconds(20)
+                *e < now + ChronoDuration::seconds(20)
             }
             None => false,
         }
@@ -162,21 +168,54 @@ impl fmt::Debug for AwsCredentials {
 pub struct CredentialsError {
     /// The underlying error message for the credentials error.
     pub message: String,
+    kind: CredentialsErrorKind,
+}
+
+/// The category of a `CredentialsError`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CredentialsErrorKind {
+    /// The provider has no credentials configured, e.g. an environment variable,
+    /// profile or IAM role is missing. `ChainProvider` moves on to the next provider.
+    NotFound,
+    /// The credentials source couldn't be reached, e.g. because of a connection,
+    /// TLS or timeout error. Retrying may succeed.
+    Network,
+    /// The credentials, or the response they were fetched from, couldn't be parsed.
+    Parse,
+    /// The credentials source refused to hand out credentials.
+    Denied,
+    /// Any other failure.
+    Other,
 }
 
 impl CredentialsError {
-    /// Creates a new Credentials Error.
+    /// Creates a new Credentials Error of kind `CredentialsErrorKind::Other`.
     ///
     /// * `message` - The Error message for this CredentialsError.
     pub fn new<S>(message: S) -> CredentialsError
+    where
+        S: ToString,
+    {
+        CredentialsError::with_kind(CredentialsErrorKind::Other, message)
+    }
+
+    /// Creates a new Credentials Error of the given kind.
+    pub fn with_kind<S>(kind: CredentialsErrorKind, message: S) -> CredentialsError
     where
         S: ToString,
     {
         CredentialsError {
             message: message.to_string(),
+            kind,
         }
     }
 
+    /// What kind of failure this is, e.g. to retry network errors but not missing
+    /// configuration.
+    pub fn kind(&self) -> CredentialsErrorKind {
+        self.kind
+    }
+
     /// Merge a string (context) with the current error.
     fn merge_with_str(&mut self, other: &str) {
         if !self.message.is_empty() {
@@ -185,9 +224,13 @@ impl CredentialsError {
         self.message.push_str(other);
     }
 
-    /// Merge another error with the current error.
+    /// Merge another error with the current error. The result is only of kind
+    /// `NotFound` if both errors are.
     fn merge_with(&mut self, other: &Self) {
-        self.merge_with_str(&other.message)
+        self.merge_with_str(&other.message);
+        if self.kind == CredentialsErrorKind::NotFound {
+            self.kind = other.kind;
+        }
     }
 }
 
@@ -201,37 +244,86 @@ impl Error for CredentialsError {}
 
 impl From<ParseError> for CredentialsError {
     fn from(err: ParseError) -> CredentialsError {
-        CredentialsError::new(err)
+        CredentialsError::with_kind(CredentialsErrorKind::Parse, err)
     }
 }
 
 impl From<IoError> for CredentialsError {
     fn from(err: IoError) -> CredentialsError {
-        CredentialsError::new(err)
+        let kind = match err.kind() {
+            IoErrorKind::NotFound => CredentialsErrorKind::NotFound,
+            IoErrorKind::PermissionDenied => CredentialsErrorKind::Denied,
+            IoErrorKind::InvalidData => CredentialsErrorKind::Parse,
+            IoErrorKind::AddrNotAvailable
+            | IoErrorKind::BrokenPipe
+            | IoErrorKind::ConnectionAborted
+            | IoErrorKind::ConnectionRefused
+            | IoErrorKind::ConnectionReset
+            | IoErrorKind::NotConnected
+            | IoErrorKind::TimedOut => CredentialsErrorKind::Network,
+            _ => CredentialsErrorKind::Other,
+        };
+        CredentialsError::with_kind(kind, err)
     }
 }
 
 impl From<HyperError> for CredentialsError {
     fn from(err: HyperError) -> CredentialsError {
-        CredentialsError::new(format!("Couldn't connect to credentials provider: {}", err))
+        CredentialsError::with_kind(
+            CredentialsErrorKind::Network,
+            format!("Couldn't connect to credentials provider: {}", err),
+        )
     }
 }
 
 impl From<serde_json::Error> for CredentialsError {
     fn from(err: serde_json::Error) -> CredentialsError {
-        CredentialsError::new(err)
+        CredentialsError::with_kind(CredentialsErrorKind::Parse, err)
     }
 }
 
 impl From<VarError> for CredentialsError {
     fn from(err: VarError) -> CredentialsError {
-        CredentialsError::new(err)
+        let kind = match err {
+            VarError::NotPresent => CredentialsErrorKind::NotFound,
+            VarError::NotUnicode(_) => CredentialsErrorKind::Parse,
+        };
+        CredentialsError::with_kind(kind, err)
     }
 }
 
 impl From<FromUtf8Error> for CredentialsError {
     fn from(err: FromUtf8Error) -> CredentialsError {
-        CredentialsError::new(err)
+        CredentialsError::with_kind(CredentialsErrorKind::Parse, err)
+    }
+}
+
+/// When credentials should be fetched again, independent of their own expiry.
+///
+/// Sources like Vault hand out leases that should be renewed before the AWS credentials
+/// they issued expire. `AutoRefreshingProvider` refreshes at the earlier of the hint and
+/// the expiry of the credentials.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RefreshHint {
+    refresh_at: Option<DateTime<Utc>>,
+}
+
+impl RefreshHint {
+    /// A hint to refresh the credentials at `refresh_at`.
+    pub fn at(refresh_at: DateTime<Utc>) -> RefreshHint {
+        RefreshHint {
+            refresh_at: Some(refresh_at),
+        }
+    }
+
+    /// Get a reference to the time the credentials should be refreshed at, if any.
+    pub fn refresh_at(&self) -> &Option<DateTime<Utc>> {
+        &self.refresh_at
+    }
+
+    /// Determine whether or not the credentials should be refreshed at `now`.
+    fn is_due(&self, now: DateTime<Utc>) -> bool {
+        matches!(self.refresh_at, Some(refresh_at) if refresh_at <= now)
     }
 }
 
@@ -240,6 +332,24 @@ impl From<FromUtf8Error> for CredentialsError {
 pub trait ProvideAwsCredentials {
     /// Produce a new `AwsCredentials` future.
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError>;
+
+    /// Produce new `AwsCredentials` along with a hint of when to refresh them.
+    ///
+    /// Defaults to the `credentials` without a hint, so they are only refreshed once
+    /// they expire. Providers that know better, e.g. because the lease the credentials
+    /// were issued under ends sooner, override this.
+    async fn credentials_with_refresh_hint(
+        &self,
+    ) -> Result<(AwsCredentials, RefreshHint), CredentialsError> {
+        let credentials = self.credentials().await?;
+        Ok((credentials, RefreshHint::default()))
+    }
+
+    /// Discard any cached credentials, so that the next call to `credentials` fetches
+    /// new ones, e.g. after AWS rejected the cached ones as expired.
+    ///
+    /// Does nothing by default, as most providers don't cache credentials.
+    async fn force_refresh(&self) {}
 }
 
 #[async_trait]
@@ -247,18 +357,33 @@ impl<P: ProvideAwsCredentials + Send + Sync> ProvideAwsCredentials for Arc<P> {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
         P::credentials(self).await
     }
+
+    async fn credentials_with_refresh_hint(
+        &self,
+    ) -> Result<(AwsCredentials, RefreshHint), CredentialsError> {
+        P::credentials_with_refresh_hint(self).await
+    }
+
+    async fn force_refresh(&self) {
+        P::force_refresh(self).await
+    }
 }
 
+type CachedCredentials = Result<(AwsCredentials, RefreshHint), CredentialsError>;
+
 /// Wrapper for `ProvideAwsCredentials` that caches the credentials returned by the
 /// wrapped provider.  Each time the credentials are accessed, they are checked to see if
 /// they have expired, in which case they are retrieved from the wrapped provider again.
+/// Credentials are also retrieved again once the `RefreshHint` the wrapped provider
+/// returned with them is due, if that is sooner.
 ///
 /// In order to access the wrapped provider, for instance to set a timeout, the `get_ref`
 /// and `get_mut` methods can be used.
 #[derive(Debug, Clone)]
 pub struct AutoRefreshingProvider<P: ProvideAwsCredentials + 'static> {
     credentials_provider: P,
-    current_credentials: Arc<Mutex<Option<Result<AwsCredentials, CredentialsError>>>>,
+    current_credentials: Arc<Mutex<Option<CachedCredentials>>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl<P: ProvideAwsCredentials + 'static> AutoRefreshingProvider<P> {
@@ -267,9 +392,17 @@ impl<P: ProvideAwsCredentials + 'static> AutoRefreshingProvider<P> {
         Ok(AutoRefreshingProvider {
             credentials_provider: provider,
             current_credentials: Arc::new(Mutex::new(None)),
+            clock: Arc::new(SystemClock),
         })
     }
 
+    /// Replace the clock used to check whether the cached credentials have expired,
+    /// which is the `SystemClock` by default.
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
     /// Get a shared reference to the wrapped provider.
     pub fn get_ref(&self) -> &P {
         &self.credentials_provider
@@ -289,25 +422,40 @@ impl<P: ProvideAwsCredentials + Send + Sync + 'static> ProvideAwsCredentials
     for AutoRefreshingProvider<P>
 {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let (creds, _) = self.credentials_with_refresh_hint().await?;
+        Ok(creds)
+    }
+
+    async fn credentials_with_refresh_hint(
+        &self,
+    ) -> Result<(AwsCredentials, RefreshHint), CredentialsError> {
         loop {
             let mut guard = self.current_credentials.lock().await;
             match guard.as_ref() {
                 // no result from the future yet, let's keep using it
                 None => {
-                    let res = self.credentials_provider.credentials().await;
+                    let res = self
+                        .credentials_provider
+                        .credentials_with_refresh_hint()
+                        .await;
                     *guard = Some(res);
                 }
                 Some(Err(e)) => return Err(e.clone()),
-                Some(Ok(creds)) => {
-                    if creds.credentials_are_expired() {
+                Some(Ok((creds, hint))) => {
+                    let now = self.clock.now();
+                    if creds.credentials_are_expired(now) || hint.is_due(now) {
                         *guard = None;
                     } else {
-                        return Ok(creds.clone());
+                        return Ok((creds.clone(), *hint));
                     };
                 }
             }
         }
     }
+
+    async fn force_refresh(&self) {
+        *self.current_credentials.lock().await = None;
+    }
 }
 
 /// Wraps a `ChainProvider` in an `AutoRefreshingProvider`.
@@ -340,6 +488,10 @@ impl ProvideAwsCredentials for DefaultCredentialsProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
         self.0.credentials().await
     }
+
+    async fn force_refresh(&self) {
+        self.0.force_refresh().await
+    }
 }
 
 /// Provides AWS credentials from multiple possible sources using a priority order.
@@ -396,10 +548,10 @@ impl ChainProvider {
 async fn chain_provider_credentials(
     provider: ChainProvider,
 ) -> Result<AwsCredentials, CredentialsError> {
-    let mut err = CredentialsError {
-        message: "Couldn't find AWS credentials in environment, credentials file, or IAM role"
-            .to_owned(),
-    };
+    let mut err = CredentialsError::with_kind(
+        CredentialsErrorKind::NotFound,
+        "Couldn't find AWS credentials in environment, credentials file, or IAM role",
+    );
     match provider.environment_provider.credentials().await {
         Ok(creds) => return Ok(creds),
         Err(e) => err.merge_with(&e),
@@ -506,6 +658,136 @@ mod tests {
         is_send_and_sync::<DefaultCredentialsProvider>();
     }
 
+    #[derive(Debug)]
+    struct TestClock(std::sync::Mutex<DateTime<Utc>>);
+
+    impl Clock for TestClock {
+        fn now(&self) -> DateTime<Utc> {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn auto_refreshing_provider_refreshes_expired_credentials() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // Hands out credentials that are valid for an hour from the clock's time.
+        struct CountingProvider {
+            clock: Arc<TestClock>,
+            calls: AtomicUsize,
+        }
+
+        #[async_trait]
+        impl ProvideAwsCredentials for CountingProvider {
+            async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                Ok(AwsCredentials::new(
+                    "key",
+                    "secret",
+                    None,
+                    Some(self.clock.now() + ChronoDuration::hours(1)),
+                ))
+            }
+        }
+
+        let start = Utc::now();
+        let clock = Arc::new(TestClock(std::sync::Mutex::new(start)));
+        let provider = AutoRefreshingProvider::new(CountingProvider {
+            clock: clock.clone(),
+            calls: AtomicUsize::new(0),
+        })
+        .unwrap()
+        .with_clock(clock.clone());
+
+        provider.credentials().await.unwrap();
+        provider.credentials().await.unwrap();
+        assert_eq!(provider.get_ref().calls.load(Ordering::SeqCst), 1);
+
+        *clock.0.lock().unwrap() = start + ChronoDuration::hours(1);
+        provider.credentials().await.unwrap();
+        assert_eq!(provider.get_ref().calls.load(Ordering::SeqCst), 2);
+
+        provider.force_refresh().await;
+        provider.credentials().await.unwrap();
+        provider.credentials().await.unwrap();
+        assert_eq!(provider.get_ref().calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn auto_refreshing_provider_honors_sooner_refresh_hints() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // Hands out credentials that are valid for an hour, leased for `lease`.
+        struct LeasingProvider {
+            clock: Arc<TestClock>,
+            lease: ChronoDuration,
+            calls: AtomicUsize,
+        }
+
+        #[async_trait]
+        impl ProvideAwsCredentials for LeasingProvider {
+            async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+                self.credentials_with_refresh_hint()
+                    .await
+                    .map(|(creds, _)| creds)
+            }
+
+            async fn credentials_with_refresh_hint(
+                &self,
+            ) -> Result<(AwsCredentials, RefreshHint), CredentialsError> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                let now = self.clock.now();
+                let creds = AwsCredentials::new(
+                    "key",
+                    "secret",
+                    None,
+                    Some(now + ChronoDuration::hours(1)),
+                );
+                Ok((creds, RefreshHint::at(now + self.lease)))
+            }
+        }
+
+        let start = Utc::now();
+        let clock = Arc::new(TestClock(std::sync::Mutex::new(start)));
+        let refreshing = |lease| {
+            AutoRefreshingProvider::new(LeasingProvider {
+                clock: clock.clone(),
+                lease,
+                calls: AtomicUsize::new(0),
+            })
+            .unwrap()
+            .with_clock(clock.clone())
+        };
+
+        let short_lease = refreshing(ChronoDuration::minutes(10));
+        let (_, hint) = short_lease.credentials_with_refresh_hint().await.unwrap();
+        assert_eq!(
+            hint.refresh_at(),
+            &Some(start + ChronoDuration::minutes(10))
+        );
+        let long_lease = refreshing(ChronoDuration::hours(2));
+        long_lease.credentials().await.unwrap();
+
+        *clock.0.lock().unwrap() = start + ChronoDuration::minutes(10);
+        short_lease.credentials().await.unwrap();
+        long_lease.credentials().await.unwrap();
+        assert_eq!(short_lease.get_ref().calls.load(Ordering::SeqCst), 2);
+        assert_eq!(long_lease.get_ref().calls.load(Ordering::SeqCst), 1);
+
+        // the credentials' own expiry still applies when the hint is later
+        *clock.0.lock().unwrap() = start + ChronoDuration::hours(1);
+        long_lease.credentials().await.unwrap();
+        assert_eq!(long_lease.get_ref().calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn providers_without_refresh_hints_default_to_none() {
+        let provider = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let (creds, hint) = provider.credentials_with_refresh_hint().await.unwrap();
+        assert_eq!(creds.aws_access_key_id(), "key");
+        assert_eq!(hint, RefreshHint::default());
+    }
+
     #[tokio::test]
     async fn profile_provider_finds_right_credentials_in_file() {
         let _guard = lock_env();
@@ -581,6 +863,96 @@ mod tests {
         }
     }
 
+    #[test]
+    fn credentials_error_kinds() {
+        use std::io::ErrorKind;
+
+        let io_kind = |kind| CredentialsError::from(IoError::new(kind, "error")).kind();
+        assert_eq!(io_kind(ErrorKind::NotFound), CredentialsErrorKind::NotFound);
+        assert_eq!(io_kind(ErrorKind::TimedOut), CredentialsErrorKind::Network);
+        assert_eq!(
+            io_kind(ErrorKind::ConnectionRefused),
+            CredentialsErrorKind::Network
+        );
+        assert_eq!(
+            io_kind(ErrorKind::PermissionDenied),
+            CredentialsErrorKind::Denied
+        );
+        assert_eq!(
+            CredentialsError::from(VarError::NotPresent).kind(),
+            CredentialsErrorKind::NotFound
+        );
+        assert_eq!(
+            CredentialsError::from(serde_json::from_str::<AwsCredentials>("{").unwrap_err()).kind(),
+            CredentialsErrorKind::Parse
+        );
+        assert_eq!(
+            CredentialsError::new("error").kind(),
+            CredentialsErrorKind::Other
+        );
+    }
+
+    #[test]
+    fn merged_credentials_error_is_not_found_only_if_all_are() {
+        let not_found = || CredentialsError::with_kind(CredentialsErrorKind::NotFound, "missing");
+
+        let mut err = not_found();
+        err.merge_with(&not_found());
+        assert_eq!(err.kind(), CredentialsErrorKind::NotFound);
+
+        err.merge_with(&CredentialsError::with_kind(
+            CredentialsErrorKind::Network,
+            "timed out",
+        ));
+        err.merge_with(&CredentialsError::with_kind(
+            CredentialsErrorKind::Denied,
+            "forbidden",
+        ));
+        err.merge_with(&not_found());
+        assert_eq!(err.kind(), CredentialsErrorKind::Network);
+        assert_eq!(err.message, "missing;missing;timed out;forbidden;missing");
+    }
+
+    #[tokio::test]
+    async fn instance_metadata_connection_errors_are_network_errors() {
+        // Nothing listens on the port once the listener is dropped.
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let mut provider = InstanceMetadataProvider::new();
+        provider.set_ip_addr_with_port("127.0.0.1", &port.to_string());
+
+        let err = provider.credentials().await.unwrap_err();
+        assert_eq!(err.kind(), CredentialsErrorKind::Network, "{}", err);
+        let err = provider.region().await.unwrap_err();
+        assert_eq!(err.kind(), CredentialsErrorKind::Network, "{}", err);
+    }
+
+    #[tokio::test]
+    async fn instance_metadata_provides_the_region() {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 1024];
+            let read = socket.read(&mut buf).unwrap();
+            socket
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 12\r\n\r\neu-central-1")
+                .unwrap();
+            String::from_utf8_lossy(&buf[..read]).into_owned()
+        });
+        let mut provider = InstanceMetadataProvider::new();
+        provider.set_ip_addr_with_port("127.0.0.1", &port.to_string());
+
+        assert_eq!(provider.region().await.unwrap(), "eu-central-1");
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /latest/meta-data/placement/region HTTP/1.1"));
+    }
+
     #[cfg(test)]
     quickcheck! {
         fn test_aws_credentials_secrets_not_in_debug(
@@ -11,7 +11,10 @@ use dirs_next::home_dir;
 use serde::Deserialize;
 use tokio::process::Command;
 
-use crate::{non_empty_env_var, AwsCredentials, CredentialsError, ProvideAwsCredentials};
+use crate::{
+    non_empty_env_var, AwsCredentials, CredentialsError, CredentialsErrorKind,
+    ProvideAwsCredentials,
+};
 
 const AWS_CONFIG_FILE: &str = "AWS_CONFIG_FILE";
 const AWS_PROFILE: &str = "AWS_PROFILE";
@@ -131,7 +134,10 @@ impl ProfileProvider {
                 home_path.push("config");
                 Ok(home_path)
             }
-            None => Err(CredentialsError::new("Failed to determine home directory.")),
+            None => Err(CredentialsError::with_kind(
+                CredentialsErrorKind::NotFound,
+                "Failed to determine home directory.",
+            )),
         }
     }
 
@@ -153,7 +159,10 @@ impl ProfileProvider {
                 home_path.push("credentials");
                 Ok(home_path)
             }
-            None => Err(CredentialsError::new("Failed to determine home directory.")),
+            None => Err(CredentialsError::with_kind(
+                CredentialsErrorKind::NotFound,
+                "Failed to determine home directory.",
+            )),
         }
     }
 
@@ -222,9 +231,12 @@ impl ProvideAwsCredentials for ProfileProvider {
             Ok(None) => {
                 // credential_process is not set, parse the credentials file
                 parse_credentials_file(self.file_path()).and_then(|mut profiles| {
-                    profiles
-                        .remove(self.profile())
-                        .ok_or_else(|| CredentialsError::new("profile not found"))
+                    profiles.remove(self.profile()).ok_or_else(|| {
+                        CredentialsError::with_kind(
+                            CredentialsErrorKind::NotFound,
+                            "profile not found",
+                        )
+                    })
                 })
             }
             Err(err) => Err(err),
@@ -245,10 +257,13 @@ fn parse_credential_process_output(v: &[u8]) -> Result<AwsCredentials, Credentia
     if output.version == 1 {
         Ok(output.creds)
     } else {
-        Err(CredentialsError::new(format!(
-            "Unsupported version '{}' for credential process provider, supported versions: 1",
-            output.version
-        )))
+        Err(CredentialsError::with_kind(
+            CredentialsErrorKind::Parse,
+            format!(
+                "Unsupported version '{}' for credential process provider, supported versions: 1",
+                output.version
+            ),
+        ))
     }
 }
 
@@ -316,10 +331,13 @@ fn parse_credentials_file(
 ) -> Result<HashMap<String, AwsCredentials>, CredentialsError> {
     match fs::metadata(file_path) {
         Err(_) => {
-            return Err(CredentialsError::new(format!(
-                "Couldn't stat credentials file: [ {:?} ]. Non existant, or no permission.",
-                file_path
-            )));
+            return Err(CredentialsError::with_kind(
+                CredentialsErrorKind::NotFound,
+                format!(
+                    "Couldn't stat credentials file: [ {:?} ]. Non existant, or no permission.",
+                    file_path
+                ),
+            ));
         }
         Ok(metadata) => {
             if !metadata.is_file() {
@@ -408,20 +426,29 @@ fn parse_credentials_file(
     }
 
     if profiles.is_empty() {
-        return Err(CredentialsError::new("No credentials found."));
+        return Err(CredentialsError::with_kind(
+            CredentialsErrorKind::NotFound,
+            "No credentials found.",
+        ));
     }
 
     Ok(profiles)
 }
 
 fn parse_command_str(s: &str) -> Result<Command, CredentialsError> {
-    let args = shlex::split(s)
-        .ok_or_else(|| CredentialsError::new("Unable to parse credential_process value."))?;
+    let args = shlex::split(s).ok_or_else(|| {
+        CredentialsError::with_kind(
+            CredentialsErrorKind::Parse,
+            "Unable to parse credential_process value.",
+        )
+    })?;
     let mut iter = args.iter();
-    let mut command = Command::new(
-        iter.next()
-            .ok_or_else(|| CredentialsError::new("credential_process value is empty."))?,
-    );
+    let mut command = Command::new(iter.next().ok_or_else(|| {
+        CredentialsError::with_kind(
+            CredentialsErrorKind::Parse,
+            "credential_process value is empty.",
+        )
+    })?);
     command.args(iter);
     Ok(command)
 }
@@ -572,6 +599,34 @@ mod tests {
         env::remove_var(AWS_SHARED_CREDENTIALS_FILE);
     }
 
+    #[tokio::test]
+    async fn profile_provider_files_via_environment_variables() -> std::io::Result<()> {
+        use std::io::Write;
+        use tempfile::NamedTempFile;
+
+        let _guard = lock_env();
+        let mut credentials = NamedTempFile::new()?;
+        writeln!(
+            credentials,
+            "[default]\naws_access_key_id = temp_access_key\naws_secret_access_key = temp_secret_key"
+        )?;
+        let mut config = NamedTempFile::new()?;
+        writeln!(config, "[default]\nregion = eu-north-1")?;
+        env::set_var(AWS_SHARED_CREDENTIALS_FILE, credentials.path());
+        env::set_var(AWS_CONFIG_FILE, config.path());
+
+        let provider = ProfileProvider::new().unwrap();
+        assert_eq!(provider.file_path(), credentials.path());
+        let creds = provider.credentials().await.unwrap();
+        assert_eq!(creds.aws_access_key_id(), "temp_access_key");
+        assert_eq!(creds.aws_secret_access_key(), "temp_secret_key");
+        assert_eq!(ProfileProvider::region(), Ok(Some("eu-north-1".to_owned())));
+
+        env::remove_var(AWS_SHARED_CREDENTIALS_FILE);
+        env::remove_var(AWS_CONFIG_FILE);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn profile_provider_profile_name_via_environment_variable() {
         let _guard = lock_env();
@@ -600,7 +655,10 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.err(),
-            Some(CredentialsError::new("profile not found"))
+            Some(CredentialsError::with_kind(
+                CredentialsErrorKind::NotFound,
+                "profile not found"
+            ))
         );
     }
 
@@ -641,7 +699,10 @@ mod tests {
         let result = super::parse_credentials_file(Path::new("tests/sample-data/no_credentials"));
         assert_eq!(
             result.err(),
-            Some(CredentialsError::new("No credentials found."))
+            Some(CredentialsError::with_kind(
+                CredentialsErrorKind::NotFound,
+                "No credentials found."
+            ))
         )
     }
 
@@ -650,7 +711,8 @@ mod tests {
         let result = super::parse_credentials_file(Path::new("/bad/file/path"));
         assert_eq!(
             result.err(),
-            Some(CredentialsError::new(
+            Some(CredentialsError::with_kind(
+                CredentialsErrorKind::NotFound,
                 "Couldn\'t stat credentials file: [ \"/bad/file/path\" ]. Non existant, or no permission.",
             ))
         );
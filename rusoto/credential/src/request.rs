@@ -1,12 +1,16 @@
-use std::io::Error as IoError;
-use std::io::ErrorKind;
 use std::time::Duration;
 
 use futures::StreamExt;
 use hyper::client::HttpConnector;
-use hyper::{Body, Client as HyperClient, Request, Uri};
+use hyper::header::{HeaderMap, AUTHORIZATION, LOCATION};
+use hyper::{Body, Client as HyperClient, Method, Request, Response, StatusCode, Uri};
 use tokio::time;
 
+use crate::{CredentialsError, CredentialsErrorKind};
+
+/// The most redirects followed for a single request.
+const MAX_REDIRECTS: usize = 5;
+
 /// Http client for use in a credentials provider.
 #[derive(Debug, Clone)]
 pub struct HttpClient {
@@ -21,34 +25,290 @@ impl HttpClient {
         }
     }
 
-    pub async fn get(&self, uri: Uri, timeout: Duration) -> Result<String, IoError> {
+    pub async fn get(&self, uri: Uri, timeout: Duration) -> Result<String, CredentialsError> {
         match Request::get(uri).body(Body::empty()) {
             Ok(request) => self.request(request, timeout).await,
-            Err(err) => Err(IoError::new(
-                ErrorKind::Other,
+            Err(err) => Err(CredentialsError::with_kind(
+                CredentialsErrorKind::Parse,
                 format!("Invalid request: {}", err),
             )),
         }
     }
 
-    pub async fn request(&self, req: Request<Body>, timeout: Duration) -> Result<String, IoError> {
-        match time::timeout(timeout, self.inner.request(req)).await {
-            Err(_elapsed) => Err(IoError::new(ErrorKind::TimedOut, "Request timed out")),
-            Ok(try_resp) => {
-                let mut resp = try_resp.map_err(|err| {
-                    IoError::new(ErrorKind::Other, format!("Response failed: {}", err))
-                })?;
-                let body = resp.body_mut();
-                let mut text = vec![];
-                while let Some(chunk) = body.next().await {
-                    let chunk = chunk.map_err(|err| {
-                        IoError::new(ErrorKind::Other, format!("Could not get chunk: {}", err))
-                    })?;
-                    text.extend(chunk.to_vec());
-                }
-                String::from_utf8(text)
-                    .map_err(|_| IoError::new(ErrorKind::InvalidData, "Non UTF-8 Data returned"))
+    /// Sends `req` and returns the body of the response.
+    ///
+    /// Redirects, e.g. from a proxy in front of the credentials endpoint, are followed up
+    /// to `MAX_REDIRECTS` times, but only to the same scheme, and the `Authorization`
+    /// header is only sent again to the same host. The requests of credential providers
+    /// have no body, so redirected requests are sent without one. `timeout` applies to
+    /// each request.
+    pub async fn request(
+        &self,
+        mut req: Request<Body>,
+        timeout: Duration,
+    ) -> Result<String, CredentialsError> {
+        let mut redirects = 0;
+        loop {
+            let (method, uri, headers) = (
+                req.method().clone(),
+                req.uri().clone(),
+                req.headers().clone(),
+            );
+            let resp = match time::timeout(timeout, self.inner.request(req)).await {
+                Err(_elapsed) => Err(CredentialsError::with_kind(
+                    CredentialsErrorKind::Network,
+                    "Request timed out",
+                )),
+                Ok(try_resp) => try_resp.map_err(|err| {
+                    CredentialsError::with_kind(
+                        CredentialsErrorKind::Network,
+                        format!("Response failed: {}", err),
+                    )
+                }),
+            }?;
+            if !is_redirect(resp.status()) {
+                return read_response(resp).await;
             }
+            if redirects == MAX_REDIRECTS {
+                return Err(CredentialsError::with_kind(
+                    CredentialsErrorKind::Other,
+                    format!(
+                        "Response failed with status {}: more than {} redirects",
+                        resp.status(),
+                        MAX_REDIRECTS
+                    ),
+                ));
+            }
+            redirects += 1;
+            req = redirect(&method, &uri, headers, &resp)?;
         }
     }
 }
+
+fn is_redirect(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::MOVED_PERMANENTLY
+            | StatusCode::FOUND
+            | StatusCode::SEE_OTHER
+            | StatusCode::TEMPORARY_REDIRECT
+            | StatusCode::PERMANENT_REDIRECT
+    )
+}
+
+/// Builds the request that follows the redirect `resp` answered a request with.
+fn redirect(
+    method: &Method,
+    uri: &Uri,
+    mut headers: HeaderMap,
+    resp: &Response<Body>,
+) -> Result<Request<Body>, CredentialsError> {
+    let status = resp.status();
+    let error = |message: String| {
+        CredentialsError::with_kind(
+            CredentialsErrorKind::Other,
+            format!("Response failed with status {}: {}", status, message),
+        )
+    };
+    let location = resp
+        .headers()
+        .get(LOCATION)
+        .and_then(|location| location.to_str().ok())
+        .ok_or_else(|| error("redirect without a valid Location".to_owned()))?;
+    let target = resolve_location(uri, location)
+        .ok_or_else(|| error(format!("redirect to invalid location {}", location)))?;
+    if target.scheme() != uri.scheme() {
+        return Err(error(format!(
+            "refusing to follow redirect from {} to {}",
+            uri, target
+        )));
+    }
+    if target.authority() != uri.authority() {
+        headers.remove(AUTHORIZATION);
+    }
+    let method = if status == StatusCode::SEE_OTHER {
+        Method::GET
+    } else {
+        method.clone()
+    };
+    let mut req = Request::builder()
+        .method(method)
+        .uri(target)
+        .body(Body::empty())
+        .map_err(|err| error(format!("invalid redirect: {}", err)))?;
+    *req.headers_mut() = headers;
+    Ok(req)
+}
+
+/// Resolves the `Location` of a redirect, an absolute URI or an absolute path.
+fn resolve_location(base: &Uri, location: &str) -> Option<Uri> {
+    let location: Uri = location.parse().ok()?;
+    if location.scheme().is_some() {
+        return Some(location);
+    }
+    if location.authority().is_some() || !location.path().starts_with('/') {
+        return None;
+    }
+    let mut parts = base.clone().into_parts();
+    parts.path_and_query = location.path_and_query().cloned();
+    Uri::from_parts(parts).ok()
+}
+
+async fn read_response(mut resp: Response<Body>) -> Result<String, CredentialsError> {
+    let kind = match resp.status() {
+        status if status.is_success() => None,
+        StatusCode::NOT_FOUND => Some(CredentialsErrorKind::NotFound),
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Some(CredentialsErrorKind::Denied),
+        _ => Some(CredentialsErrorKind::Other),
+    };
+    if let Some(kind) = kind {
+        return Err(CredentialsError::with_kind(
+            kind,
+            format!("Response failed with status {}", resp.status()),
+        ));
+    }
+    let body = resp.body_mut();
+    let mut text = vec![];
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk.map_err(|err| {
+            CredentialsError::with_kind(
+                CredentialsErrorKind::Network,
+                format!("Could not get chunk: {}", err),
+            )
+        })?;
+        text.extend(chunk.to_vec());
+    }
+    String::from_utf8(text).map_err(|_| {
+        CredentialsError::with_kind(CredentialsErrorKind::Parse, "Non UTF-8 Data returned")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Answers one connection per response, given the port of the server, and returns the
+    /// head of each request.
+    fn serve<F>(responses: F) -> (u16, thread::JoinHandle<Vec<String>>)
+    where
+        F: FnOnce(u16) -> Vec<String>,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let responses = responses(port);
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut head = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    head.push_str(&line.to_ascii_lowercase());
+                }
+                requests.push(head);
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        (port, handle)
+    }
+
+    fn redirect_to(location: &str) -> String {
+        format!(
+            "HTTP/1.1 302 Found\r\nLocation: {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            location
+        )
+    }
+
+    fn ok(body: &str) -> String {
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+    }
+
+    fn get(uri: String) -> Request<Body> {
+        Request::get(uri)
+            .header(AUTHORIZATION, "secret")
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn follows_redirects_to_the_same_host() {
+        let (port, server) = serve(|_| vec![redirect_to("/final"), ok("credentials")]);
+        let client = HttpClient::new();
+        let uri = format!("http://127.0.0.1:{}/start", port);
+        let body = client
+            .request(get(uri), Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(body, "credentials");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("get /start "));
+        assert!(requests[1].starts_with("get /final "));
+        assert!(requests[1].contains("authorization: secret"));
+    }
+
+    #[tokio::test]
+    async fn drops_authorization_on_redirects_to_other_hosts() {
+        let (port, server) = serve(|port| {
+            let location = format!("http://localhost:{}/final", port);
+            vec![redirect_to(&location), ok("credentials")]
+        });
+        let client = HttpClient::new();
+        let uri = format!("http://127.0.0.1:{}/start", port);
+        let body = client
+            .request(get(uri), Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(body, "credentials");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].contains("authorization: secret"));
+        assert!(requests[1].starts_with("get /final "));
+        assert!(!requests[1].contains("authorization"));
+    }
+
+    #[tokio::test]
+    async fn reports_redirects_it_does_not_follow() {
+        let client = HttpClient::new();
+
+        let (port, server) = serve(|_| vec![redirect_to("https://example.com/")]);
+        let uri = format!("http://127.0.0.1:{}/", port);
+        let err = client
+            .request(get(uri), Duration::from_secs(5))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), CredentialsErrorKind::Other);
+        assert!(
+            err.message.contains("refusing to follow redirect"),
+            "{}",
+            err.message
+        );
+        server.join().unwrap();
+
+        let (port, server) = serve(|_| vec![redirect_to("/again"); MAX_REDIRECTS + 1]);
+        let uri = format!("http://127.0.0.1:{}/", port);
+        let err = client
+            .request(get(uri), Duration::from_secs(5))
+            .await
+            .unwrap_err();
+        assert!(
+            err.message.ends_with("more than 5 redirects"),
+            "{}",
+            err.message
+        );
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,93 @@
+//! Provides credentials that can be replaced while clients are using them.
+//! For those who receive credentials from a source rusoto doesn't support, e.g.
+//! pushed by a sidecar process.
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+
+use crate::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
+
+/// Provides AWS credentials that can be swapped out at any time with `set`.
+///
+/// Clones share the same credentials, so a clone can be handed to a client while the
+/// original is kept to rotate them. Every request signed after `set` returns uses the
+/// new credentials, without rebuilding the client or its connection pool.
+///
+/// ```rust
+/// use rusoto_credential::{AwsCredentials, SharedCredentialsProvider};
+///
+/// let provider = SharedCredentialsProvider::new(AwsCredentials::new("key", "secret", None, None));
+/// let for_client = provider.clone();
+/// // ... build a client with `for_client`, then later:
+/// provider.set(AwsCredentials::new("new_key", "new_secret", None, None));
+/// assert_eq!(for_client.get().aws_access_key_id(), "new_key");
+/// ```
+#[derive(Clone, Debug)]
+pub struct SharedCredentialsProvider {
+    credentials: Arc<RwLock<AwsCredentials>>,
+}
+
+impl SharedCredentialsProvider {
+    /// Creates a provider handing out `credentials` until they are replaced.
+    pub fn new(credentials: AwsCredentials) -> SharedCredentialsProvider {
+        SharedCredentialsProvider {
+            credentials: Arc::new(RwLock::new(credentials)),
+        }
+    }
+
+    /// Replaces the credentials of this provider and all its clones.
+    pub fn set(&self, credentials: AwsCredentials) {
+        *self
+            .credentials
+            .write()
+            .expect("shared credentials lock poisoned") = credentials;
+    }
+
+    /// Returns the current credentials.
+    pub fn get(&self) -> AwsCredentials {
+        self.credentials
+            .read()
+            .expect("shared credentials lock poisoned")
+            .clone()
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for SharedCredentialsProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        Ok(self.get())
+    }
+}
+
+impl From<AwsCredentials> for SharedCredentialsProvider {
+    fn from(credentials: AwsCredentials) -> Self {
+        SharedCredentialsProvider::new(credentials)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn clones_see_replaced_credentials() {
+        let provider =
+            SharedCredentialsProvider::from(AwsCredentials::new("old", "secret", None, None));
+        let clone = provider.clone();
+        assert_eq!(
+            clone.credentials().await.unwrap().aws_access_key_id(),
+            "old"
+        );
+
+        provider.set(AwsCredentials::new(
+            "new",
+            "new_secret",
+            Some("token".to_owned()),
+            None,
+        ));
+        let credentials = clone.credentials().await.unwrap();
+        assert_eq!(credentials.aws_access_key_id(), "new");
+        assert_eq!(credentials.aws_secret_access_key(), "new_secret");
+        assert_eq!(credentials.token(), &Some("token".to_owned()));
+    }
+}
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -128,6 +128,7 @@ pub struct ApplyArchiveRuleRequest {
     #[serde(rename = "analyzerArn")]
     pub analyzer_arn: String,
     /// <p>A client token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -166,6 +167,7 @@ pub struct CreateAnalyzerRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub archive_rules: Option<Vec<InlineArchiveRule>>,
     /// <p>A client token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -196,6 +198,7 @@ pub struct CreateArchiveRuleRequest {
     #[serde(rename = "analyzerName")]
     pub analyzer_name: String,
     /// <p>A client token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -236,6 +239,7 @@ pub struct DeleteAnalyzerRequest {
     #[serde(rename = "analyzerName")]
     pub analyzer_name: String,
     /// <p>A client token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -249,6 +253,7 @@ pub struct DeleteArchiveRuleRequest {
     #[serde(rename = "analyzerName")]
     pub analyzer_name: String,
     /// <p>A client token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -711,6 +716,7 @@ pub struct UpdateArchiveRuleRequest {
     #[serde(rename = "analyzerName")]
     pub analyzer_name: String,
     /// <p>A client token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -730,6 +736,7 @@ pub struct UpdateFindingsRequest {
     #[serde(rename = "analyzerArn")]
     pub analyzer_arn: String,
     /// <p>A client token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -803,6 +810,16 @@ impl fmt::Display for ApplyArchiveRuleError {
     }
 }
 impl Error for ApplyArchiveRuleError {}
+impl ServiceErrorCode for ApplyArchiveRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ApplyArchiveRuleError::AccessDenied(_) => "AccessDeniedException",
+            ApplyArchiveRuleError::InternalServer(_) => "InternalServerException",
+            ApplyArchiveRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ApplyArchiveRuleError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateAnalyzer
 #[derive(Debug, PartialEq)]
 pub enum CreateAnalyzerError {
@@ -857,6 +874,17 @@ impl fmt::Display for CreateAnalyzerError {
     }
 }
 impl Error for CreateAnalyzerError {}
+impl ServiceErrorCode for CreateAnalyzerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAnalyzerError::AccessDenied(_) => "AccessDeniedException",
+            CreateAnalyzerError::Conflict(_) => "ConflictException",
+            CreateAnalyzerError::InternalServer(_) => "InternalServerException",
+            CreateAnalyzerError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateAnalyzerError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateArchiveRule
 #[derive(Debug, PartialEq)]
 pub enum CreateArchiveRuleError {
@@ -919,6 +947,18 @@ impl fmt::Display for CreateArchiveRuleError {
     }
 }
 impl Error for CreateArchiveRuleError {}
+impl ServiceErrorCode for CreateArchiveRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateArchiveRuleError::AccessDenied(_) => "AccessDeniedException",
+            CreateArchiveRuleError::Conflict(_) => "ConflictException",
+            CreateArchiveRuleError::InternalServer(_) => "InternalServerException",
+            CreateArchiveRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateArchiveRuleError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateArchiveRuleError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteAnalyzer
 #[derive(Debug, PartialEq)]
 pub enum DeleteAnalyzerError {
@@ -967,6 +1007,16 @@ impl fmt::Display for DeleteAnalyzerError {
     }
 }
 impl Error for DeleteAnalyzerError {}
+impl ServiceErrorCode for DeleteAnalyzerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAnalyzerError::AccessDenied(_) => "AccessDeniedException",
+            DeleteAnalyzerError::InternalServer(_) => "InternalServerException",
+            DeleteAnalyzerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteAnalyzerError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteArchiveRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteArchiveRuleError {
@@ -1015,6 +1065,16 @@ impl fmt::Display for DeleteArchiveRuleError {
     }
 }
 impl Error for DeleteArchiveRuleError {}
+impl ServiceErrorCode for DeleteArchiveRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteArchiveRuleError::AccessDenied(_) => "AccessDeniedException",
+            DeleteArchiveRuleError::InternalServer(_) => "InternalServerException",
+            DeleteArchiveRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteArchiveRuleError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetAnalyzedResource
 #[derive(Debug, PartialEq)]
 pub enum GetAnalyzedResourceError {
@@ -1065,6 +1125,16 @@ impl fmt::Display for GetAnalyzedResourceError {
     }
 }
 impl Error for GetAnalyzedResourceError {}
+impl ServiceErrorCode for GetAnalyzedResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAnalyzedResourceError::AccessDenied(_) => "AccessDeniedException",
+            GetAnalyzedResourceError::InternalServer(_) => "InternalServerException",
+            GetAnalyzedResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetAnalyzedResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetAnalyzer
 #[derive(Debug, PartialEq)]
 pub enum GetAnalyzerError {
@@ -1113,6 +1183,16 @@ impl fmt::Display for GetAnalyzerError {
     }
 }
 impl Error for GetAnalyzerError {}
+impl ServiceErrorCode for GetAnalyzerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAnalyzerError::AccessDenied(_) => "AccessDeniedException",
+            GetAnalyzerError::InternalServer(_) => "InternalServerException",
+            GetAnalyzerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetAnalyzerError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetArchiveRule
 #[derive(Debug, PartialEq)]
 pub enum GetArchiveRuleError {
@@ -1161,6 +1241,16 @@ impl fmt::Display for GetArchiveRuleError {
     }
 }
 impl Error for GetArchiveRuleError {}
+impl ServiceErrorCode for GetArchiveRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetArchiveRuleError::AccessDenied(_) => "AccessDeniedException",
+            GetArchiveRuleError::InternalServer(_) => "InternalServerException",
+            GetArchiveRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetArchiveRuleError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetFinding
 #[derive(Debug, PartialEq)]
 pub enum GetFindingError {
@@ -1209,6 +1299,16 @@ impl fmt::Display for GetFindingError {
     }
 }
 impl Error for GetFindingError {}
+impl ServiceErrorCode for GetFindingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFindingError::AccessDenied(_) => "AccessDeniedException",
+            GetFindingError::InternalServer(_) => "InternalServerException",
+            GetFindingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetFindingError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListAnalyzedResources
 #[derive(Debug, PartialEq)]
 pub enum ListAnalyzedResourcesError {
@@ -1261,6 +1361,16 @@ impl fmt::Display for ListAnalyzedResourcesError {
     }
 }
 impl Error for ListAnalyzedResourcesError {}
+impl ServiceErrorCode for ListAnalyzedResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAnalyzedResourcesError::AccessDenied(_) => "AccessDeniedException",
+            ListAnalyzedResourcesError::InternalServer(_) => "InternalServerException",
+            ListAnalyzedResourcesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListAnalyzedResourcesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListAnalyzers
 #[derive(Debug, PartialEq)]
 pub enum ListAnalyzersError {
@@ -1303,6 +1413,15 @@ impl fmt::Display for ListAnalyzersError {
     }
 }
 impl Error for ListAnalyzersError {}
+impl ServiceErrorCode for ListAnalyzersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAnalyzersError::AccessDenied(_) => "AccessDeniedException",
+            ListAnalyzersError::InternalServer(_) => "InternalServerException",
+            ListAnalyzersError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListArchiveRules
 #[derive(Debug, PartialEq)]
 pub enum ListArchiveRulesError {
@@ -1345,6 +1464,15 @@ impl fmt::Display for ListArchiveRulesError {
     }
 }
 impl Error for ListArchiveRulesError {}
+impl ServiceErrorCode for ListArchiveRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListArchiveRulesError::AccessDenied(_) => "AccessDeniedException",
+            ListArchiveRulesError::InternalServer(_) => "InternalServerException",
+            ListArchiveRulesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListFindings
 #[derive(Debug, PartialEq)]
 pub enum ListFindingsError {
@@ -1393,6 +1521,16 @@ impl fmt::Display for ListFindingsError {
     }
 }
 impl Error for ListFindingsError {}
+impl ServiceErrorCode for ListFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFindingsError::AccessDenied(_) => "AccessDeniedException",
+            ListFindingsError::InternalServer(_) => "InternalServerException",
+            ListFindingsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListFindingsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1443,6 +1581,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            ListTagsForResourceError::InternalServer(_) => "InternalServerException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StartResourceScan
 #[derive(Debug, PartialEq)]
 pub enum StartResourceScanError {
@@ -1491,6 +1639,16 @@ impl fmt::Display for StartResourceScanError {
     }
 }
 impl Error for StartResourceScanError {}
+impl ServiceErrorCode for StartResourceScanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartResourceScanError::AccessDenied(_) => "AccessDeniedException",
+            StartResourceScanError::InternalServer(_) => "InternalServerException",
+            StartResourceScanError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartResourceScanError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1539,6 +1697,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AccessDenied(_) => "AccessDeniedException",
+            TagResourceError::InternalServer(_) => "InternalServerException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1587,6 +1755,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AccessDenied(_) => "AccessDeniedException",
+            UntagResourceError::InternalServer(_) => "InternalServerException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateArchiveRule
 #[derive(Debug, PartialEq)]
 pub enum UpdateArchiveRuleError {
@@ -1635,6 +1813,16 @@ impl fmt::Display for UpdateArchiveRuleError {
     }
 }
 impl Error for UpdateArchiveRuleError {}
+impl ServiceErrorCode for UpdateArchiveRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateArchiveRuleError::AccessDenied(_) => "AccessDeniedException",
+            UpdateArchiveRuleError::InternalServer(_) => "InternalServerException",
+            UpdateArchiveRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateArchiveRuleError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateFindings
 #[derive(Debug, PartialEq)]
 pub enum UpdateFindingsError {
@@ -1683,6 +1871,16 @@ impl fmt::Display for UpdateFindingsError {
     }
 }
 impl Error for UpdateFindingsError {}
+impl ServiceErrorCode for UpdateFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFindingsError::AccessDenied(_) => "AccessDeniedException",
+            UpdateFindingsError::InternalServer(_) => "InternalServerException",
+            UpdateFindingsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateFindingsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Access Analyzer API. Access Analyzer clients implement this trait.
 #[async_trait]
 pub trait AccessAnalyzer {
@@ -1846,6 +2044,10 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         &self,
         input: ApplyArchiveRuleRequest,
     ) -> Result<(), RusotoError<ApplyArchiveRuleError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = "/archive-rule";
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
@@ -1876,6 +2078,10 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         &self,
         input: CreateAnalyzerRequest,
     ) -> Result<CreateAnalyzerResponse, RusotoError<CreateAnalyzerError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = "/analyzer";
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
@@ -1907,6 +2113,10 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         &self,
         input: CreateArchiveRuleRequest,
     ) -> Result<(), RusotoError<CreateArchiveRuleError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/analyzer/{analyzer_name}/archive-rule",
             analyzer_name = input.analyzer_name
@@ -1940,6 +2150,10 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         &self,
         input: DeleteAnalyzerRequest,
     ) -> Result<(), RusotoError<DeleteAnalyzerError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/analyzer/{analyzer_name}",
             analyzer_name = input.analyzer_name
@@ -1977,6 +2191,10 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         &self,
         input: DeleteArchiveRuleRequest,
     ) -> Result<(), RusotoError<DeleteArchiveRuleError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/analyzer/{analyzer_name}/archive-rule/{rule_name}",
             analyzer_name = input.analyzer_name,
@@ -2409,6 +2627,10 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         &self,
         input: UpdateArchiveRuleRequest,
     ) -> Result<(), RusotoError<UpdateArchiveRuleError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/analyzer/{analyzer_name}/archive-rule/{rule_name}",
             analyzer_name = input.analyzer_name,
@@ -2443,6 +2665,10 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         &self,
         input: UpdateFindingsRequest,
     ) -> Result<(), RusotoError<UpdateFindingsError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = "/finding";
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -787,6 +787,16 @@ impl fmt::Display for CreateCertificateAuthorityError {
     }
 }
 impl Error for CreateCertificateAuthorityError {}
+impl ServiceErrorCode for CreateCertificateAuthorityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCertificateAuthorityError::InvalidArgs(_) => "InvalidArgsException",
+            CreateCertificateAuthorityError::InvalidPolicy(_) => "InvalidPolicyException",
+            CreateCertificateAuthorityError::InvalidTag(_) => "InvalidTagException",
+            CreateCertificateAuthorityError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateCertificateAuthorityAuditReport
 #[derive(Debug, PartialEq)]
 pub enum CreateCertificateAuthorityAuditReportError {
@@ -873,6 +883,24 @@ impl fmt::Display for CreateCertificateAuthorityAuditReportError {
     }
 }
 impl Error for CreateCertificateAuthorityAuditReportError {}
+impl ServiceErrorCode for CreateCertificateAuthorityAuditReportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCertificateAuthorityAuditReportError::InvalidArgs(_) => "InvalidArgsException",
+            CreateCertificateAuthorityAuditReportError::InvalidArn(_) => "InvalidArnException",
+            CreateCertificateAuthorityAuditReportError::InvalidState(_) => "InvalidStateException",
+            CreateCertificateAuthorityAuditReportError::RequestFailed(_) => {
+                "RequestFailedException"
+            }
+            CreateCertificateAuthorityAuditReportError::RequestInProgress(_) => {
+                "RequestInProgressException"
+            }
+            CreateCertificateAuthorityAuditReportError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by CreatePermission
 #[derive(Debug, PartialEq)]
 pub enum CreatePermissionError {
@@ -935,6 +963,18 @@ impl fmt::Display for CreatePermissionError {
     }
 }
 impl Error for CreatePermissionError {}
+impl ServiceErrorCode for CreatePermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePermissionError::InvalidArn(_) => "InvalidArnException",
+            CreatePermissionError::InvalidState(_) => "InvalidStateException",
+            CreatePermissionError::LimitExceeded(_) => "LimitExceededException",
+            CreatePermissionError::PermissionAlreadyExists(_) => "PermissionAlreadyExistsException",
+            CreatePermissionError::RequestFailed(_) => "RequestFailedException",
+            CreatePermissionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteCertificateAuthority
 #[derive(Debug, PartialEq)]
 pub enum DeleteCertificateAuthorityError {
@@ -995,6 +1035,18 @@ impl fmt::Display for DeleteCertificateAuthorityError {
     }
 }
 impl Error for DeleteCertificateAuthorityError {}
+impl ServiceErrorCode for DeleteCertificateAuthorityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCertificateAuthorityError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteCertificateAuthorityError::InvalidArn(_) => "InvalidArnException",
+            DeleteCertificateAuthorityError::InvalidState(_) => "InvalidStateException",
+            DeleteCertificateAuthorityError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeletePermission
 #[derive(Debug, PartialEq)]
 pub enum DeletePermissionError {
@@ -1043,6 +1095,16 @@ impl fmt::Display for DeletePermissionError {
     }
 }
 impl Error for DeletePermissionError {}
+impl ServiceErrorCode for DeletePermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePermissionError::InvalidArn(_) => "InvalidArnException",
+            DeletePermissionError::InvalidState(_) => "InvalidStateException",
+            DeletePermissionError::RequestFailed(_) => "RequestFailedException",
+            DeletePermissionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeletePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeletePolicyError {
@@ -1103,6 +1165,18 @@ impl fmt::Display for DeletePolicyError {
     }
 }
 impl Error for DeletePolicyError {}
+impl ServiceErrorCode for DeletePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePolicyError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeletePolicyError::InvalidArn(_) => "InvalidArnException",
+            DeletePolicyError::InvalidState(_) => "InvalidStateException",
+            DeletePolicyError::LockoutPrevented(_) => "LockoutPreventedException",
+            DeletePolicyError::RequestFailed(_) => "RequestFailedException",
+            DeletePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeCertificateAuthority
 #[derive(Debug, PartialEq)]
 pub enum DescribeCertificateAuthorityError {
@@ -1147,6 +1221,14 @@ impl fmt::Display for DescribeCertificateAuthorityError {
     }
 }
 impl Error for DescribeCertificateAuthorityError {}
+impl ServiceErrorCode for DescribeCertificateAuthorityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCertificateAuthorityError::InvalidArn(_) => "InvalidArnException",
+            DescribeCertificateAuthorityError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeCertificateAuthorityAuditReport
 #[derive(Debug, PartialEq)]
 pub enum DescribeCertificateAuthorityAuditReportError {
@@ -1203,6 +1285,17 @@ impl fmt::Display for DescribeCertificateAuthorityAuditReportError {
     }
 }
 impl Error for DescribeCertificateAuthorityAuditReportError {}
+impl ServiceErrorCode for DescribeCertificateAuthorityAuditReportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCertificateAuthorityAuditReportError::InvalidArgs(_) => "InvalidArgsException",
+            DescribeCertificateAuthorityAuditReportError::InvalidArn(_) => "InvalidArnException",
+            DescribeCertificateAuthorityAuditReportError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by GetCertificate
 #[derive(Debug, PartialEq)]
 pub enum GetCertificateError {
@@ -1257,6 +1350,17 @@ impl fmt::Display for GetCertificateError {
     }
 }
 impl Error for GetCertificateError {}
+impl ServiceErrorCode for GetCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCertificateError::InvalidArn(_) => "InvalidArnException",
+            GetCertificateError::InvalidState(_) => "InvalidStateException",
+            GetCertificateError::RequestFailed(_) => "RequestFailedException",
+            GetCertificateError::RequestInProgress(_) => "RequestInProgressException",
+            GetCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetCertificateAuthorityCertificate
 #[derive(Debug, PartialEq)]
 pub enum GetCertificateAuthorityCertificateError {
@@ -1313,6 +1417,17 @@ impl fmt::Display for GetCertificateAuthorityCertificateError {
     }
 }
 impl Error for GetCertificateAuthorityCertificateError {}
+impl ServiceErrorCode for GetCertificateAuthorityCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCertificateAuthorityCertificateError::InvalidArn(_) => "InvalidArnException",
+            GetCertificateAuthorityCertificateError::InvalidState(_) => "InvalidStateException",
+            GetCertificateAuthorityCertificateError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by GetCertificateAuthorityCsr
 #[derive(Debug, PartialEq)]
 pub enum GetCertificateAuthorityCsrError {
@@ -1379,6 +1494,17 @@ impl fmt::Display for GetCertificateAuthorityCsrError {
     }
 }
 impl Error for GetCertificateAuthorityCsrError {}
+impl ServiceErrorCode for GetCertificateAuthorityCsrError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCertificateAuthorityCsrError::InvalidArn(_) => "InvalidArnException",
+            GetCertificateAuthorityCsrError::InvalidState(_) => "InvalidStateException",
+            GetCertificateAuthorityCsrError::RequestFailed(_) => "RequestFailedException",
+            GetCertificateAuthorityCsrError::RequestInProgress(_) => "RequestInProgressException",
+            GetCertificateAuthorityCsrError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetPolicyError {
@@ -1427,6 +1553,16 @@ impl fmt::Display for GetPolicyError {
     }
 }
 impl Error for GetPolicyError {}
+impl ServiceErrorCode for GetPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPolicyError::InvalidArn(_) => "InvalidArnException",
+            GetPolicyError::InvalidState(_) => "InvalidStateException",
+            GetPolicyError::RequestFailed(_) => "RequestFailedException",
+            GetPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ImportCertificateAuthorityCertificate
 #[derive(Debug, PartialEq)]
 pub enum ImportCertificateAuthorityCertificateError {
@@ -1543,6 +1679,35 @@ impl fmt::Display for ImportCertificateAuthorityCertificateError {
     }
 }
 impl Error for ImportCertificateAuthorityCertificateError {}
+impl ServiceErrorCode for ImportCertificateAuthorityCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportCertificateAuthorityCertificateError::CertificateMismatch(_) => {
+                "CertificateMismatchException"
+            }
+            ImportCertificateAuthorityCertificateError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            ImportCertificateAuthorityCertificateError::InvalidArn(_) => "InvalidArnException",
+            ImportCertificateAuthorityCertificateError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            ImportCertificateAuthorityCertificateError::InvalidState(_) => "InvalidStateException",
+            ImportCertificateAuthorityCertificateError::MalformedCertificate(_) => {
+                "MalformedCertificateException"
+            }
+            ImportCertificateAuthorityCertificateError::RequestFailed(_) => {
+                "RequestFailedException"
+            }
+            ImportCertificateAuthorityCertificateError::RequestInProgress(_) => {
+                "RequestInProgressException"
+            }
+            ImportCertificateAuthorityCertificateError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by IssueCertificate
 #[derive(Debug, PartialEq)]
 pub enum IssueCertificateError {
@@ -1603,6 +1768,18 @@ impl fmt::Display for IssueCertificateError {
     }
 }
 impl Error for IssueCertificateError {}
+impl ServiceErrorCode for IssueCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            IssueCertificateError::InvalidArgs(_) => "InvalidArgsException",
+            IssueCertificateError::InvalidArn(_) => "InvalidArnException",
+            IssueCertificateError::InvalidState(_) => "InvalidStateException",
+            IssueCertificateError::LimitExceeded(_) => "LimitExceededException",
+            IssueCertificateError::MalformedCSR(_) => "MalformedCSRException",
+            IssueCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListCertificateAuthorities
 #[derive(Debug, PartialEq)]
 pub enum ListCertificateAuthoritiesError {
@@ -1637,6 +1814,13 @@ impl fmt::Display for ListCertificateAuthoritiesError {
     }
 }
 impl Error for ListCertificateAuthoritiesError {}
+impl ServiceErrorCode for ListCertificateAuthoritiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCertificateAuthoritiesError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListPermissions
 #[derive(Debug, PartialEq)]
 pub enum ListPermissionsError {
@@ -1691,6 +1875,17 @@ impl fmt::Display for ListPermissionsError {
     }
 }
 impl Error for ListPermissionsError {}
+impl ServiceErrorCode for ListPermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPermissionsError::InvalidArn(_) => "InvalidArnException",
+            ListPermissionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListPermissionsError::InvalidState(_) => "InvalidStateException",
+            ListPermissionsError::RequestFailed(_) => "RequestFailedException",
+            ListPermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
 pub enum ListTagsError {
@@ -1733,6 +1928,15 @@ impl fmt::Display for ListTagsError {
     }
 }
 impl Error for ListTagsError {}
+impl ServiceErrorCode for ListTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsError::InvalidArn(_) => "InvalidArnException",
+            ListTagsError::InvalidState(_) => "InvalidStateException",
+            ListTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutPolicyError {
@@ -1799,6 +2003,19 @@ impl fmt::Display for PutPolicyError {
     }
 }
 impl Error for PutPolicyError {}
+impl ServiceErrorCode for PutPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutPolicyError::ConcurrentModification(_) => "ConcurrentModificationException",
+            PutPolicyError::InvalidArn(_) => "InvalidArnException",
+            PutPolicyError::InvalidPolicy(_) => "InvalidPolicyException",
+            PutPolicyError::InvalidState(_) => "InvalidStateException",
+            PutPolicyError::LockoutPrevented(_) => "LockoutPreventedException",
+            PutPolicyError::RequestFailed(_) => "RequestFailedException",
+            PutPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RestoreCertificateAuthority
 #[derive(Debug, PartialEq)]
 pub enum RestoreCertificateAuthorityError {
@@ -1849,6 +2066,15 @@ impl fmt::Display for RestoreCertificateAuthorityError {
     }
 }
 impl Error for RestoreCertificateAuthorityError {}
+impl ServiceErrorCode for RestoreCertificateAuthorityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreCertificateAuthorityError::InvalidArn(_) => "InvalidArnException",
+            RestoreCertificateAuthorityError::InvalidState(_) => "InvalidStateException",
+            RestoreCertificateAuthorityError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RevokeCertificate
 #[derive(Debug, PartialEq)]
 pub enum RevokeCertificateError {
@@ -1931,6 +2157,23 @@ impl fmt::Display for RevokeCertificateError {
     }
 }
 impl Error for RevokeCertificateError {}
+impl ServiceErrorCode for RevokeCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RevokeCertificateError::ConcurrentModification(_) => "ConcurrentModificationException",
+            RevokeCertificateError::InvalidArn(_) => "InvalidArnException",
+            RevokeCertificateError::InvalidRequest(_) => "InvalidRequestException",
+            RevokeCertificateError::InvalidState(_) => "InvalidStateException",
+            RevokeCertificateError::LimitExceeded(_) => "LimitExceededException",
+            RevokeCertificateError::RequestAlreadyProcessed(_) => {
+                "RequestAlreadyProcessedException"
+            }
+            RevokeCertificateError::RequestFailed(_) => "RequestFailedException",
+            RevokeCertificateError::RequestInProgress(_) => "RequestInProgressException",
+            RevokeCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagCertificateAuthority
 #[derive(Debug, PartialEq)]
 pub enum TagCertificateAuthorityError {
@@ -1989,6 +2232,17 @@ impl fmt::Display for TagCertificateAuthorityError {
     }
 }
 impl Error for TagCertificateAuthorityError {}
+impl ServiceErrorCode for TagCertificateAuthorityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagCertificateAuthorityError::InvalidArn(_) => "InvalidArnException",
+            TagCertificateAuthorityError::InvalidState(_) => "InvalidStateException",
+            TagCertificateAuthorityError::InvalidTag(_) => "InvalidTagException",
+            TagCertificateAuthorityError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagCertificateAuthorityError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UntagCertificateAuthority
 #[derive(Debug, PartialEq)]
 pub enum UntagCertificateAuthorityError {
@@ -2045,6 +2299,16 @@ impl fmt::Display for UntagCertificateAuthorityError {
     }
 }
 impl Error for UntagCertificateAuthorityError {}
+impl ServiceErrorCode for UntagCertificateAuthorityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagCertificateAuthorityError::InvalidArn(_) => "InvalidArnException",
+            UntagCertificateAuthorityError::InvalidState(_) => "InvalidStateException",
+            UntagCertificateAuthorityError::InvalidTag(_) => "InvalidTagException",
+            UntagCertificateAuthorityError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateCertificateAuthority
 #[derive(Debug, PartialEq)]
 pub enum UpdateCertificateAuthorityError {
@@ -2121,6 +2385,20 @@ impl fmt::Display for UpdateCertificateAuthorityError {
     }
 }
 impl Error for UpdateCertificateAuthorityError {}
+impl ServiceErrorCode for UpdateCertificateAuthorityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCertificateAuthorityError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            UpdateCertificateAuthorityError::InvalidArgs(_) => "InvalidArgsException",
+            UpdateCertificateAuthorityError::InvalidArn(_) => "InvalidArnException",
+            UpdateCertificateAuthorityError::InvalidPolicy(_) => "InvalidPolicyException",
+            UpdateCertificateAuthorityError::InvalidState(_) => "InvalidStateException",
+            UpdateCertificateAuthorityError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the ACM-PCA API. ACM-PCA clients implement this trait.
 #[async_trait]
 pub trait AcmPca {
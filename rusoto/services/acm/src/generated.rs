@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -653,6 +653,18 @@ impl fmt::Display for AddTagsToCertificateError {
     }
 }
 impl Error for AddTagsToCertificateError {}
+impl ServiceErrorCode for AddTagsToCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsToCertificateError::InvalidArn(_) => "InvalidArnException",
+            AddTagsToCertificateError::InvalidParameter(_) => "InvalidParameterException",
+            AddTagsToCertificateError::InvalidTag(_) => "InvalidTagException",
+            AddTagsToCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AddTagsToCertificateError::TagPolicy(_) => "TagPolicyException",
+            AddTagsToCertificateError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by DeleteCertificate
 #[derive(Debug, PartialEq)]
 pub enum DeleteCertificateError {
@@ -695,6 +707,15 @@ impl fmt::Display for DeleteCertificateError {
     }
 }
 impl Error for DeleteCertificateError {}
+impl ServiceErrorCode for DeleteCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCertificateError::InvalidArn(_) => "InvalidArnException",
+            DeleteCertificateError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeCertificate
 #[derive(Debug, PartialEq)]
 pub enum DescribeCertificateError {
@@ -733,6 +754,14 @@ impl fmt::Display for DescribeCertificateError {
     }
 }
 impl Error for DescribeCertificateError {}
+impl ServiceErrorCode for DescribeCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCertificateError::InvalidArn(_) => "InvalidArnException",
+            DescribeCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ExportCertificate
 #[derive(Debug, PartialEq)]
 pub enum ExportCertificateError {
@@ -775,6 +804,15 @@ impl fmt::Display for ExportCertificateError {
     }
 }
 impl Error for ExportCertificateError {}
+impl ServiceErrorCode for ExportCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExportCertificateError::InvalidArn(_) => "InvalidArnException",
+            ExportCertificateError::RequestInProgress(_) => "RequestInProgressException",
+            ExportCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetCertificate
 #[derive(Debug, PartialEq)]
 pub enum GetCertificateError {
@@ -817,6 +855,15 @@ impl fmt::Display for GetCertificateError {
     }
 }
 impl Error for GetCertificateError {}
+impl ServiceErrorCode for GetCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCertificateError::InvalidArn(_) => "InvalidArnException",
+            GetCertificateError::RequestInProgress(_) => "RequestInProgressException",
+            GetCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ImportCertificate
 #[derive(Debug, PartialEq)]
 pub enum ImportCertificateError {
@@ -877,6 +924,18 @@ impl fmt::Display for ImportCertificateError {
     }
 }
 impl Error for ImportCertificateError {}
+impl ServiceErrorCode for ImportCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportCertificateError::InvalidParameter(_) => "InvalidParameterException",
+            ImportCertificateError::InvalidTag(_) => "InvalidTagException",
+            ImportCertificateError::LimitExceeded(_) => "LimitExceededException",
+            ImportCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ImportCertificateError::TagPolicy(_) => "TagPolicyException",
+            ImportCertificateError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by ListCertificates
 #[derive(Debug, PartialEq)]
 pub enum ListCertificatesError {
@@ -907,6 +966,13 @@ impl fmt::Display for ListCertificatesError {
     }
 }
 impl Error for ListCertificatesError {}
+impl ServiceErrorCode for ListCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCertificatesError::InvalidArgs(_) => "InvalidArgsException",
+        }
+    }
+}
 /// Errors returned by ListTagsForCertificate
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForCertificateError {
@@ -945,6 +1011,14 @@ impl fmt::Display for ListTagsForCertificateError {
     }
 }
 impl Error for ListTagsForCertificateError {}
+impl ServiceErrorCode for ListTagsForCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForCertificateError::InvalidArn(_) => "InvalidArnException",
+            ListTagsForCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RemoveTagsFromCertificate
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromCertificateError {
@@ -1007,6 +1081,17 @@ impl fmt::Display for RemoveTagsFromCertificateError {
     }
 }
 impl Error for RemoveTagsFromCertificateError {}
+impl ServiceErrorCode for RemoveTagsFromCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsFromCertificateError::InvalidArn(_) => "InvalidArnException",
+            RemoveTagsFromCertificateError::InvalidParameter(_) => "InvalidParameterException",
+            RemoveTagsFromCertificateError::InvalidTag(_) => "InvalidTagException",
+            RemoveTagsFromCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RemoveTagsFromCertificateError::TagPolicy(_) => "TagPolicyException",
+        }
+    }
+}
 /// Errors returned by RenewCertificate
 #[derive(Debug, PartialEq)]
 pub enum RenewCertificateError {
@@ -1043,6 +1128,14 @@ impl fmt::Display for RenewCertificateError {
     }
 }
 impl Error for RenewCertificateError {}
+impl ServiceErrorCode for RenewCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RenewCertificateError::InvalidArn(_) => "InvalidArnException",
+            RenewCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RequestCertificate
 #[derive(Debug, PartialEq)]
 pub enum RequestCertificateError {
@@ -1113,6 +1206,21 @@ impl fmt::Display for RequestCertificateError {
     }
 }
 impl Error for RequestCertificateError {}
+impl ServiceErrorCode for RequestCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RequestCertificateError::InvalidArn(_) => "InvalidArnException",
+            RequestCertificateError::InvalidDomainValidationOptions(_) => {
+                "InvalidDomainValidationOptionsException"
+            }
+            RequestCertificateError::InvalidParameter(_) => "InvalidParameterException",
+            RequestCertificateError::InvalidTag(_) => "InvalidTagException",
+            RequestCertificateError::LimitExceeded(_) => "LimitExceededException",
+            RequestCertificateError::TagPolicy(_) => "TagPolicyException",
+            RequestCertificateError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by ResendValidationEmail
 #[derive(Debug, PartialEq)]
 pub enum ResendValidationEmailError {
@@ -1167,6 +1275,18 @@ impl fmt::Display for ResendValidationEmailError {
     }
 }
 impl Error for ResendValidationEmailError {}
+impl ServiceErrorCode for ResendValidationEmailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResendValidationEmailError::InvalidArn(_) => "InvalidArnException",
+            ResendValidationEmailError::InvalidDomainValidationOptions(_) => {
+                "InvalidDomainValidationOptionsException"
+            }
+            ResendValidationEmailError::InvalidState(_) => "InvalidStateException",
+            ResendValidationEmailError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateCertificateOptions
 #[derive(Debug, PartialEq)]
 pub enum UpdateCertificateOptionsError {
@@ -1221,6 +1341,16 @@ impl fmt::Display for UpdateCertificateOptionsError {
     }
 }
 impl Error for UpdateCertificateOptionsError {}
+impl ServiceErrorCode for UpdateCertificateOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCertificateOptionsError::InvalidArn(_) => "InvalidArnException",
+            UpdateCertificateOptionsError::InvalidState(_) => "InvalidStateException",
+            UpdateCertificateOptionsError::LimitExceeded(_) => "LimitExceededException",
+            UpdateCertificateOptionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the ACM API. ACM clients implement this trait.
 #[async_trait]
 pub trait Acm {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -440,6 +440,7 @@ pub struct Content {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateAddressBookRequest {
     /// <p>A unique, user-specified identifier for the request that ensures idempotency.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -465,6 +466,7 @@ pub struct CreateAddressBookResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateBusinessReportScheduleRequest {
     /// <p>The client request token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -509,6 +511,7 @@ pub struct CreateBusinessReportScheduleResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateConferenceProviderRequest {
     /// <p>The request token of the client.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -544,6 +547,7 @@ pub struct CreateConferenceProviderResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateContactRequest {
     /// <p>A unique, user-specified identifier for this request that ensures idempotency.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -600,6 +604,7 @@ pub struct CreateEndOfMeetingReminder {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateGatewayGroupRequest {
     /// <p> A unique, user-specified identifier for the request that ensures idempotency.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>The description of the gateway group.</p>
@@ -660,6 +665,7 @@ pub struct CreateNetworkProfileRequest {
     #[serde(rename = "CertificateAuthorityArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub certificate_authority_arn: Option<String>,
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>The current password of the Wi-Fi network.</p>
@@ -709,6 +715,7 @@ pub struct CreateProfileRequest {
     #[serde(rename = "Address")]
     pub address: String,
     /// <p>The user-specified token that is used during the creation of a profile.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -778,6 +785,7 @@ pub struct CreateRequireCheckIn {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateRoomRequest {
     /// <p>A unique, user-specified identifier for this request that ensures idempotency. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -815,6 +823,7 @@ pub struct CreateRoomResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateSkillGroupRequest {
     /// <p>A unique, user-specified identifier for this request that ensures idempotency. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -844,6 +853,7 @@ pub struct CreateSkillGroupResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateUserRequest {
     /// <p>A unique, user-specified identifier for this request that ensures idempotency. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -2803,6 +2813,7 @@ pub struct SearchUsersResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SendAnnouncementRequest {
     /// <p>The unique, user-specified identifier for the request that ensures idempotency.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>The announcement content. This can contain only one of the three possible announcement types (text, SSML or audio).</p>
@@ -3581,6 +3592,15 @@ impl fmt::Display for ApproveSkillError {
     }
 }
 impl Error for ApproveSkillError {}
+impl ServiceErrorCode for ApproveSkillError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ApproveSkillError::ConcurrentModification(_) => "ConcurrentModificationException",
+            ApproveSkillError::LimitExceeded(_) => "LimitExceededException",
+            ApproveSkillError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by AssociateContactWithAddressBook
 #[derive(Debug, PartialEq)]
 pub enum AssociateContactWithAddressBookError {
@@ -3617,6 +3637,13 @@ impl fmt::Display for AssociateContactWithAddressBookError {
     }
 }
 impl Error for AssociateContactWithAddressBookError {}
+impl ServiceErrorCode for AssociateContactWithAddressBookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateContactWithAddressBookError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by AssociateDeviceWithNetworkProfile
 #[derive(Debug, PartialEq)]
 pub enum AssociateDeviceWithNetworkProfileError {
@@ -3671,6 +3698,19 @@ impl fmt::Display for AssociateDeviceWithNetworkProfileError {
     }
 }
 impl Error for AssociateDeviceWithNetworkProfileError {}
+impl ServiceErrorCode for AssociateDeviceWithNetworkProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateDeviceWithNetworkProfileError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AssociateDeviceWithNetworkProfileError::DeviceNotRegistered(_) => {
+                "DeviceNotRegisteredException"
+            }
+            AssociateDeviceWithNetworkProfileError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by AssociateDeviceWithRoom
 #[derive(Debug, PartialEq)]
 pub enum AssociateDeviceWithRoomError {
@@ -3721,6 +3761,17 @@ impl fmt::Display for AssociateDeviceWithRoomError {
     }
 }
 impl Error for AssociateDeviceWithRoomError {}
+impl ServiceErrorCode for AssociateDeviceWithRoomError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateDeviceWithRoomError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AssociateDeviceWithRoomError::DeviceNotRegistered(_) => "DeviceNotRegisteredException",
+            AssociateDeviceWithRoomError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by AssociateSkillGroupWithRoom
 #[derive(Debug, PartialEq)]
 pub enum AssociateSkillGroupWithRoomError {
@@ -3757,6 +3808,15 @@ impl fmt::Display for AssociateSkillGroupWithRoomError {
     }
 }
 impl Error for AssociateSkillGroupWithRoomError {}
+impl ServiceErrorCode for AssociateSkillGroupWithRoomError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateSkillGroupWithRoomError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+        }
+    }
+}
 /// Errors returned by AssociateSkillWithSkillGroup
 #[derive(Debug, PartialEq)]
 pub enum AssociateSkillWithSkillGroupError {
@@ -3809,6 +3869,17 @@ impl fmt::Display for AssociateSkillWithSkillGroupError {
     }
 }
 impl Error for AssociateSkillWithSkillGroupError {}
+impl ServiceErrorCode for AssociateSkillWithSkillGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateSkillWithSkillGroupError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AssociateSkillWithSkillGroupError::NotFound(_) => "NotFoundException",
+            AssociateSkillWithSkillGroupError::SkillNotLinked(_) => "SkillNotLinkedException",
+        }
+    }
+}
 /// Errors returned by AssociateSkillWithUsers
 #[derive(Debug, PartialEq)]
 pub enum AssociateSkillWithUsersError {
@@ -3849,6 +3920,16 @@ impl fmt::Display for AssociateSkillWithUsersError {
     }
 }
 impl Error for AssociateSkillWithUsersError {}
+impl ServiceErrorCode for AssociateSkillWithUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateSkillWithUsersError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AssociateSkillWithUsersError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateAddressBook
 #[derive(Debug, PartialEq)]
 pub enum CreateAddressBookError {
@@ -3885,6 +3966,14 @@ impl fmt::Display for CreateAddressBookError {
     }
 }
 impl Error for CreateAddressBookError {}
+impl ServiceErrorCode for CreateAddressBookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAddressBookError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateAddressBookError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateBusinessReportSchedule
 #[derive(Debug, PartialEq)]
 pub enum CreateBusinessReportScheduleError {
@@ -3919,6 +4008,13 @@ impl fmt::Display for CreateBusinessReportScheduleError {
     }
 }
 impl Error for CreateBusinessReportScheduleError {}
+impl ServiceErrorCode for CreateBusinessReportScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBusinessReportScheduleError::AlreadyExists(_) => "AlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by CreateConferenceProvider
 #[derive(Debug, PartialEq)]
 pub enum CreateConferenceProviderError {
@@ -3951,6 +4047,13 @@ impl fmt::Display for CreateConferenceProviderError {
     }
 }
 impl Error for CreateConferenceProviderError {}
+impl ServiceErrorCode for CreateConferenceProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConferenceProviderError::AlreadyExists(_) => "AlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by CreateContact
 #[derive(Debug, PartialEq)]
 pub enum CreateContactError {
@@ -3987,6 +4090,14 @@ impl fmt::Display for CreateContactError {
     }
 }
 impl Error for CreateContactError {}
+impl ServiceErrorCode for CreateContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateContactError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateContactError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateGatewayGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateGatewayGroupError {
@@ -4023,6 +4134,14 @@ impl fmt::Display for CreateGatewayGroupError {
     }
 }
 impl Error for CreateGatewayGroupError {}
+impl ServiceErrorCode for CreateGatewayGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGatewayGroupError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateGatewayGroupError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateNetworkProfile
 #[derive(Debug, PartialEq)]
 pub enum CreateNetworkProfileError {
@@ -4087,6 +4206,23 @@ impl fmt::Display for CreateNetworkProfileError {
     }
 }
 impl Error for CreateNetworkProfileError {}
+impl ServiceErrorCode for CreateNetworkProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateNetworkProfileError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateNetworkProfileError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            CreateNetworkProfileError::InvalidCertificateAuthority(_) => {
+                "InvalidCertificateAuthorityException"
+            }
+            CreateNetworkProfileError::InvalidServiceLinkedRoleState(_) => {
+                "InvalidServiceLinkedRoleStateException"
+            }
+            CreateNetworkProfileError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateProfile
 #[derive(Debug, PartialEq)]
 pub enum CreateProfileError {
@@ -4131,6 +4267,15 @@ impl fmt::Display for CreateProfileError {
     }
 }
 impl Error for CreateProfileError {}
+impl ServiceErrorCode for CreateProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProfileError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateProfileError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateProfileError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateRoom
 #[derive(Debug, PartialEq)]
 pub enum CreateRoomError {
@@ -4167,6 +4312,14 @@ impl fmt::Display for CreateRoomError {
     }
 }
 impl Error for CreateRoomError {}
+impl ServiceErrorCode for CreateRoomError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRoomError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateRoomError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateSkillGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateSkillGroupError {
@@ -4211,6 +4364,15 @@ impl fmt::Display for CreateSkillGroupError {
     }
 }
 impl Error for CreateSkillGroupError {}
+impl ServiceErrorCode for CreateSkillGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSkillGroupError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateSkillGroupError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateSkillGroupError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
 pub enum CreateUserError {
@@ -4253,6 +4415,15 @@ impl fmt::Display for CreateUserError {
     }
 }
 impl Error for CreateUserError {}
+impl ServiceErrorCode for CreateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateUserError::LimitExceeded(_) => "LimitExceededException",
+            CreateUserError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteAddressBook
 #[derive(Debug, PartialEq)]
 pub enum DeleteAddressBookError {
@@ -4291,6 +4462,14 @@ impl fmt::Display for DeleteAddressBookError {
     }
 }
 impl Error for DeleteAddressBookError {}
+impl ServiceErrorCode for DeleteAddressBookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAddressBookError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteAddressBookError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteBusinessReportSchedule
 #[derive(Debug, PartialEq)]
 pub enum DeleteBusinessReportScheduleError {
@@ -4335,6 +4514,16 @@ impl fmt::Display for DeleteBusinessReportScheduleError {
     }
 }
 impl Error for DeleteBusinessReportScheduleError {}
+impl ServiceErrorCode for DeleteBusinessReportScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBusinessReportScheduleError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteBusinessReportScheduleError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteConferenceProvider
 #[derive(Debug, PartialEq)]
 pub enum DeleteConferenceProviderError {
@@ -4365,6 +4554,13 @@ impl fmt::Display for DeleteConferenceProviderError {
     }
 }
 impl Error for DeleteConferenceProviderError {}
+impl ServiceErrorCode for DeleteConferenceProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConferenceProviderError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteContact
 #[derive(Debug, PartialEq)]
 pub enum DeleteContactError {
@@ -4403,6 +4599,14 @@ impl fmt::Display for DeleteContactError {
     }
 }
 impl Error for DeleteContactError {}
+impl ServiceErrorCode for DeleteContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteContactError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteContactError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteDevice
 #[derive(Debug, PartialEq)]
 pub enum DeleteDeviceError {
@@ -4447,6 +4651,17 @@ impl fmt::Display for DeleteDeviceError {
     }
 }
 impl Error for DeleteDeviceError {}
+impl ServiceErrorCode for DeleteDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDeviceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteDeviceError::InvalidCertificateAuthority(_) => {
+                "InvalidCertificateAuthorityException"
+            }
+            DeleteDeviceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteDeviceUsageData
 #[derive(Debug, PartialEq)]
 pub enum DeleteDeviceUsageDataError {
@@ -4491,6 +4706,15 @@ impl fmt::Display for DeleteDeviceUsageDataError {
     }
 }
 impl Error for DeleteDeviceUsageDataError {}
+impl ServiceErrorCode for DeleteDeviceUsageDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDeviceUsageDataError::DeviceNotRegistered(_) => "DeviceNotRegisteredException",
+            DeleteDeviceUsageDataError::LimitExceeded(_) => "LimitExceededException",
+            DeleteDeviceUsageDataError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteGatewayGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteGatewayGroupError {
@@ -4523,6 +4747,13 @@ impl fmt::Display for DeleteGatewayGroupError {
     }
 }
 impl Error for DeleteGatewayGroupError {}
+impl ServiceErrorCode for DeleteGatewayGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGatewayGroupError::ResourceAssociated(_) => "ResourceAssociatedException",
+        }
+    }
+}
 /// Errors returned by DeleteNetworkProfile
 #[derive(Debug, PartialEq)]
 pub enum DeleteNetworkProfileError {
@@ -4567,6 +4798,17 @@ impl fmt::Display for DeleteNetworkProfileError {
     }
 }
 impl Error for DeleteNetworkProfileError {}
+impl ServiceErrorCode for DeleteNetworkProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteNetworkProfileError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteNetworkProfileError::NotFound(_) => "NotFoundException",
+            DeleteNetworkProfileError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteProfile
 #[derive(Debug, PartialEq)]
 pub enum DeleteProfileError {
@@ -4605,6 +4847,14 @@ impl fmt::Display for DeleteProfileError {
     }
 }
 impl Error for DeleteProfileError {}
+impl ServiceErrorCode for DeleteProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProfileError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteProfileError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteRoom
 #[derive(Debug, PartialEq)]
 pub enum DeleteRoomError {
@@ -4641,6 +4891,14 @@ impl fmt::Display for DeleteRoomError {
     }
 }
 impl Error for DeleteRoomError {}
+impl ServiceErrorCode for DeleteRoomError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRoomError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteRoomError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteRoomSkillParameter
 #[derive(Debug, PartialEq)]
 pub enum DeleteRoomSkillParameterError {
@@ -4675,6 +4933,15 @@ impl fmt::Display for DeleteRoomSkillParameterError {
     }
 }
 impl Error for DeleteRoomSkillParameterError {}
+impl ServiceErrorCode for DeleteRoomSkillParameterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRoomSkillParameterError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteSkillAuthorization
 #[derive(Debug, PartialEq)]
 pub enum DeleteSkillAuthorizationError {
@@ -4715,6 +4982,16 @@ impl fmt::Display for DeleteSkillAuthorizationError {
     }
 }
 impl Error for DeleteSkillAuthorizationError {}
+impl ServiceErrorCode for DeleteSkillAuthorizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSkillAuthorizationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteSkillAuthorizationError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteSkillGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteSkillGroupError {
@@ -4753,6 +5030,14 @@ impl fmt::Display for DeleteSkillGroupError {
     }
 }
 impl Error for DeleteSkillGroupError {}
+impl ServiceErrorCode for DeleteSkillGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSkillGroupError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteSkillGroupError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserError {
@@ -4789,6 +5074,14 @@ impl fmt::Display for DeleteUserError {
     }
 }
 impl Error for DeleteUserError {}
+impl ServiceErrorCode for DeleteUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteUserError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateContactFromAddressBook
 #[derive(Debug, PartialEq)]
 pub enum DisassociateContactFromAddressBookError {}
@@ -4813,6 +5106,11 @@ impl fmt::Display for DisassociateContactFromAddressBookError {
     }
 }
 impl Error for DisassociateContactFromAddressBookError {}
+impl ServiceErrorCode for DisassociateContactFromAddressBookError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisassociateDeviceFromRoom
 #[derive(Debug, PartialEq)]
 pub enum DisassociateDeviceFromRoomError {
@@ -4859,6 +5157,18 @@ impl fmt::Display for DisassociateDeviceFromRoomError {
     }
 }
 impl Error for DisassociateDeviceFromRoomError {}
+impl ServiceErrorCode for DisassociateDeviceFromRoomError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateDeviceFromRoomError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DisassociateDeviceFromRoomError::DeviceNotRegistered(_) => {
+                "DeviceNotRegisteredException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateSkillFromSkillGroup
 #[derive(Debug, PartialEq)]
 pub enum DisassociateSkillFromSkillGroupError {
@@ -4903,6 +5213,16 @@ impl fmt::Display for DisassociateSkillFromSkillGroupError {
     }
 }
 impl Error for DisassociateSkillFromSkillGroupError {}
+impl ServiceErrorCode for DisassociateSkillFromSkillGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateSkillFromSkillGroupError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DisassociateSkillFromSkillGroupError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateSkillFromUsers
 #[derive(Debug, PartialEq)]
 pub enum DisassociateSkillFromUsersError {
@@ -4945,6 +5265,16 @@ impl fmt::Display for DisassociateSkillFromUsersError {
     }
 }
 impl Error for DisassociateSkillFromUsersError {}
+impl ServiceErrorCode for DisassociateSkillFromUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateSkillFromUsersError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DisassociateSkillFromUsersError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateSkillGroupFromRoom
 #[derive(Debug, PartialEq)]
 pub enum DisassociateSkillGroupFromRoomError {
@@ -4981,6 +5311,15 @@ impl fmt::Display for DisassociateSkillGroupFromRoomError {
     }
 }
 impl Error for DisassociateSkillGroupFromRoomError {}
+impl ServiceErrorCode for DisassociateSkillGroupFromRoomError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateSkillGroupFromRoomError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+        }
+    }
+}
 /// Errors returned by ForgetSmartHomeAppliances
 #[derive(Debug, PartialEq)]
 pub enum ForgetSmartHomeAppliancesError {
@@ -5011,6 +5350,13 @@ impl fmt::Display for ForgetSmartHomeAppliancesError {
     }
 }
 impl Error for ForgetSmartHomeAppliancesError {}
+impl ServiceErrorCode for ForgetSmartHomeAppliancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ForgetSmartHomeAppliancesError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetAddressBook
 #[derive(Debug, PartialEq)]
 pub enum GetAddressBookError {
@@ -5041,6 +5387,13 @@ impl fmt::Display for GetAddressBookError {
     }
 }
 impl Error for GetAddressBookError {}
+impl ServiceErrorCode for GetAddressBookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAddressBookError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetConferencePreference
 #[derive(Debug, PartialEq)]
 pub enum GetConferencePreferenceError {
@@ -5071,6 +5424,13 @@ impl fmt::Display for GetConferencePreferenceError {
     }
 }
 impl Error for GetConferencePreferenceError {}
+impl ServiceErrorCode for GetConferencePreferenceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConferencePreferenceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetConferenceProvider
 #[derive(Debug, PartialEq)]
 pub enum GetConferenceProviderError {
@@ -5101,6 +5461,13 @@ impl fmt::Display for GetConferenceProviderError {
     }
 }
 impl Error for GetConferenceProviderError {}
+impl ServiceErrorCode for GetConferenceProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConferenceProviderError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetContact
 #[derive(Debug, PartialEq)]
 pub enum GetContactError {
@@ -5131,6 +5498,13 @@ impl fmt::Display for GetContactError {
     }
 }
 impl Error for GetContactError {}
+impl ServiceErrorCode for GetContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContactError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetDevice
 #[derive(Debug, PartialEq)]
 pub enum GetDeviceError {
@@ -5161,6 +5535,13 @@ impl fmt::Display for GetDeviceError {
     }
 }
 impl Error for GetDeviceError {}
+impl ServiceErrorCode for GetDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeviceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetGateway
 #[derive(Debug, PartialEq)]
 pub enum GetGatewayError {
@@ -5191,6 +5572,13 @@ impl fmt::Display for GetGatewayError {
     }
 }
 impl Error for GetGatewayError {}
+impl ServiceErrorCode for GetGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGatewayError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetGatewayGroup
 #[derive(Debug, PartialEq)]
 pub enum GetGatewayGroupError {
@@ -5221,6 +5609,13 @@ impl fmt::Display for GetGatewayGroupError {
     }
 }
 impl Error for GetGatewayGroupError {}
+impl ServiceErrorCode for GetGatewayGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGatewayGroupError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetInvitationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetInvitationConfigurationError {
@@ -5253,6 +5648,13 @@ impl fmt::Display for GetInvitationConfigurationError {
     }
 }
 impl Error for GetInvitationConfigurationError {}
+impl ServiceErrorCode for GetInvitationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInvitationConfigurationError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetNetworkProfile
 #[derive(Debug, PartialEq)]
 pub enum GetNetworkProfileError {
@@ -5293,6 +5695,16 @@ impl fmt::Display for GetNetworkProfileError {
     }
 }
 impl Error for GetNetworkProfileError {}
+impl ServiceErrorCode for GetNetworkProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetNetworkProfileError::InvalidSecretsManagerResource(_) => {
+                "InvalidSecretsManagerResourceException"
+            }
+            GetNetworkProfileError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetProfile
 #[derive(Debug, PartialEq)]
 pub enum GetProfileError {
@@ -5323,6 +5735,13 @@ impl fmt::Display for GetProfileError {
     }
 }
 impl Error for GetProfileError {}
+impl ServiceErrorCode for GetProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetProfileError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetRoom
 #[derive(Debug, PartialEq)]
 pub enum GetRoomError {
@@ -5353,6 +5772,13 @@ impl fmt::Display for GetRoomError {
     }
 }
 impl Error for GetRoomError {}
+impl ServiceErrorCode for GetRoomError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRoomError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetRoomSkillParameter
 #[derive(Debug, PartialEq)]
 pub enum GetRoomSkillParameterError {
@@ -5383,6 +5809,13 @@ impl fmt::Display for GetRoomSkillParameterError {
     }
 }
 impl Error for GetRoomSkillParameterError {}
+impl ServiceErrorCode for GetRoomSkillParameterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRoomSkillParameterError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetSkillGroup
 #[derive(Debug, PartialEq)]
 pub enum GetSkillGroupError {
@@ -5413,6 +5846,13 @@ impl fmt::Display for GetSkillGroupError {
     }
 }
 impl Error for GetSkillGroupError {}
+impl ServiceErrorCode for GetSkillGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSkillGroupError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListBusinessReportSchedules
 #[derive(Debug, PartialEq)]
 pub enum ListBusinessReportSchedulesError {}
@@ -5437,6 +5877,11 @@ impl fmt::Display for ListBusinessReportSchedulesError {
     }
 }
 impl Error for ListBusinessReportSchedulesError {}
+impl ServiceErrorCode for ListBusinessReportSchedulesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListConferenceProviders
 #[derive(Debug, PartialEq)]
 pub enum ListConferenceProvidersError {}
@@ -5459,6 +5904,11 @@ impl fmt::Display for ListConferenceProvidersError {
     }
 }
 impl Error for ListConferenceProvidersError {}
+impl ServiceErrorCode for ListConferenceProvidersError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListDeviceEvents
 #[derive(Debug, PartialEq)]
 pub enum ListDeviceEventsError {
@@ -5489,6 +5939,13 @@ impl fmt::Display for ListDeviceEventsError {
     }
 }
 impl Error for ListDeviceEventsError {}
+impl ServiceErrorCode for ListDeviceEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeviceEventsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListGatewayGroups
 #[derive(Debug, PartialEq)]
 pub enum ListGatewayGroupsError {}
@@ -5511,6 +5968,11 @@ impl fmt::Display for ListGatewayGroupsError {
     }
 }
 impl Error for ListGatewayGroupsError {}
+impl ServiceErrorCode for ListGatewayGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListGateways
 #[derive(Debug, PartialEq)]
 pub enum ListGatewaysError {}
@@ -5533,6 +5995,11 @@ impl fmt::Display for ListGatewaysError {
     }
 }
 impl Error for ListGatewaysError {}
+impl ServiceErrorCode for ListGatewaysError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListSkills
 #[derive(Debug, PartialEq)]
 pub enum ListSkillsError {}
@@ -5555,6 +6022,11 @@ impl fmt::Display for ListSkillsError {
     }
 }
 impl Error for ListSkillsError {}
+impl ServiceErrorCode for ListSkillsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListSkillsStoreCategories
 #[derive(Debug, PartialEq)]
 pub enum ListSkillsStoreCategoriesError {}
@@ -5577,6 +6049,11 @@ impl fmt::Display for ListSkillsStoreCategoriesError {
     }
 }
 impl Error for ListSkillsStoreCategoriesError {}
+impl ServiceErrorCode for ListSkillsStoreCategoriesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListSkillsStoreSkillsByCategory
 #[derive(Debug, PartialEq)]
 pub enum ListSkillsStoreSkillsByCategoryError {}
@@ -5601,6 +6078,11 @@ impl fmt::Display for ListSkillsStoreSkillsByCategoryError {
     }
 }
 impl Error for ListSkillsStoreSkillsByCategoryError {}
+impl ServiceErrorCode for ListSkillsStoreSkillsByCategoryError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListSmartHomeAppliances
 #[derive(Debug, PartialEq)]
 pub enum ListSmartHomeAppliancesError {
@@ -5631,6 +6113,13 @@ impl fmt::Display for ListSmartHomeAppliancesError {
     }
 }
 impl Error for ListSmartHomeAppliancesError {}
+impl ServiceErrorCode for ListSmartHomeAppliancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSmartHomeAppliancesError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
 pub enum ListTagsError {
@@ -5661,6 +6150,13 @@ impl fmt::Display for ListTagsError {
     }
 }
 impl Error for ListTagsError {}
+impl ServiceErrorCode for ListTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by PutConferencePreference
 #[derive(Debug, PartialEq)]
 pub enum PutConferencePreferenceError {
@@ -5691,6 +6187,13 @@ impl fmt::Display for PutConferencePreferenceError {
     }
 }
 impl Error for PutConferencePreferenceError {}
+impl ServiceErrorCode for PutConferencePreferenceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutConferencePreferenceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by PutInvitationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutInvitationConfigurationError {
@@ -5733,6 +6236,16 @@ impl fmt::Display for PutInvitationConfigurationError {
     }
 }
 impl Error for PutInvitationConfigurationError {}
+impl ServiceErrorCode for PutInvitationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutInvitationConfigurationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            PutInvitationConfigurationError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by PutRoomSkillParameter
 #[derive(Debug, PartialEq)]
 pub enum PutRoomSkillParameterError {
@@ -5765,6 +6278,15 @@ impl fmt::Display for PutRoomSkillParameterError {
     }
 }
 impl Error for PutRoomSkillParameterError {}
+impl ServiceErrorCode for PutRoomSkillParameterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRoomSkillParameterError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+        }
+    }
+}
 /// Errors returned by PutSkillAuthorization
 #[derive(Debug, PartialEq)]
 pub enum PutSkillAuthorizationError {
@@ -5803,6 +6325,16 @@ impl fmt::Display for PutSkillAuthorizationError {
     }
 }
 impl Error for PutSkillAuthorizationError {}
+impl ServiceErrorCode for PutSkillAuthorizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutSkillAuthorizationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            PutSkillAuthorizationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by RegisterAVSDevice
 #[derive(Debug, PartialEq)]
 pub enum RegisterAVSDeviceError {
@@ -5853,6 +6385,16 @@ impl fmt::Display for RegisterAVSDeviceError {
     }
 }
 impl Error for RegisterAVSDeviceError {}
+impl ServiceErrorCode for RegisterAVSDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterAVSDeviceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            RegisterAVSDeviceError::InvalidDevice(_) => "InvalidDeviceException",
+            RegisterAVSDeviceError::LimitExceeded(_) => "LimitExceededException",
+            RegisterAVSDeviceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by RejectSkill
 #[derive(Debug, PartialEq)]
 pub enum RejectSkillError {
@@ -5889,6 +6431,14 @@ impl fmt::Display for RejectSkillError {
     }
 }
 impl Error for RejectSkillError {}
+impl ServiceErrorCode for RejectSkillError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RejectSkillError::ConcurrentModification(_) => "ConcurrentModificationException",
+            RejectSkillError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ResolveRoom
 #[derive(Debug, PartialEq)]
 pub enum ResolveRoomError {
@@ -5919,6 +6469,13 @@ impl fmt::Display for ResolveRoomError {
     }
 }
 impl Error for ResolveRoomError {}
+impl ServiceErrorCode for ResolveRoomError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResolveRoomError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by RevokeInvitation
 #[derive(Debug, PartialEq)]
 pub enum RevokeInvitationError {
@@ -5957,6 +6514,14 @@ impl fmt::Display for RevokeInvitationError {
     }
 }
 impl Error for RevokeInvitationError {}
+impl ServiceErrorCode for RevokeInvitationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RevokeInvitationError::ConcurrentModification(_) => "ConcurrentModificationException",
+            RevokeInvitationError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by SearchAddressBooks
 #[derive(Debug, PartialEq)]
 pub enum SearchAddressBooksError {}
@@ -5979,6 +6544,11 @@ impl fmt::Display for SearchAddressBooksError {
     }
 }
 impl Error for SearchAddressBooksError {}
+impl ServiceErrorCode for SearchAddressBooksError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SearchContacts
 #[derive(Debug, PartialEq)]
 pub enum SearchContactsError {}
@@ -6001,6 +6571,11 @@ impl fmt::Display for SearchContactsError {
     }
 }
 impl Error for SearchContactsError {}
+impl ServiceErrorCode for SearchContactsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SearchDevices
 #[derive(Debug, PartialEq)]
 pub enum SearchDevicesError {}
@@ -6023,6 +6598,11 @@ impl fmt::Display for SearchDevicesError {
     }
 }
 impl Error for SearchDevicesError {}
+impl ServiceErrorCode for SearchDevicesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SearchNetworkProfiles
 #[derive(Debug, PartialEq)]
 pub enum SearchNetworkProfilesError {}
@@ -6045,6 +6625,11 @@ impl fmt::Display for SearchNetworkProfilesError {
     }
 }
 impl Error for SearchNetworkProfilesError {}
+impl ServiceErrorCode for SearchNetworkProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SearchProfiles
 #[derive(Debug, PartialEq)]
 pub enum SearchProfilesError {}
@@ -6067,6 +6652,11 @@ impl fmt::Display for SearchProfilesError {
     }
 }
 impl Error for SearchProfilesError {}
+impl ServiceErrorCode for SearchProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SearchRooms
 #[derive(Debug, PartialEq)]
 pub enum SearchRoomsError {}
@@ -6089,6 +6679,11 @@ impl fmt::Display for SearchRoomsError {
     }
 }
 impl Error for SearchRoomsError {}
+impl ServiceErrorCode for SearchRoomsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SearchSkillGroups
 #[derive(Debug, PartialEq)]
 pub enum SearchSkillGroupsError {}
@@ -6111,6 +6706,11 @@ impl fmt::Display for SearchSkillGroupsError {
     }
 }
 impl Error for SearchSkillGroupsError {}
+impl ServiceErrorCode for SearchSkillGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SearchUsers
 #[derive(Debug, PartialEq)]
 pub enum SearchUsersError {}
@@ -6133,6 +6733,11 @@ impl fmt::Display for SearchUsersError {
     }
 }
 impl Error for SearchUsersError {}
+impl ServiceErrorCode for SearchUsersError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SendAnnouncement
 #[derive(Debug, PartialEq)]
 pub enum SendAnnouncementError {
@@ -6169,6 +6774,14 @@ impl fmt::Display for SendAnnouncementError {
     }
 }
 impl Error for SendAnnouncementError {}
+impl ServiceErrorCode for SendAnnouncementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendAnnouncementError::AlreadyExists(_) => "AlreadyExistsException",
+            SendAnnouncementError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by SendInvitation
 #[derive(Debug, PartialEq)]
 pub enum SendInvitationError {
@@ -6213,6 +6826,15 @@ impl fmt::Display for SendInvitationError {
     }
 }
 impl Error for SendInvitationError {}
+impl ServiceErrorCode for SendInvitationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendInvitationError::ConcurrentModification(_) => "ConcurrentModificationException",
+            SendInvitationError::InvalidUserStatus(_) => "InvalidUserStatusException",
+            SendInvitationError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by StartDeviceSync
 #[derive(Debug, PartialEq)]
 pub enum StartDeviceSyncError {
@@ -6243,6 +6865,13 @@ impl fmt::Display for StartDeviceSyncError {
     }
 }
 impl Error for StartDeviceSyncError {}
+impl ServiceErrorCode for StartDeviceSyncError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDeviceSyncError::DeviceNotRegistered(_) => "DeviceNotRegisteredException",
+        }
+    }
+}
 /// Errors returned by StartSmartHomeApplianceDiscovery
 #[derive(Debug, PartialEq)]
 pub enum StartSmartHomeApplianceDiscoveryError {
@@ -6277,6 +6906,13 @@ impl fmt::Display for StartSmartHomeApplianceDiscoveryError {
     }
 }
 impl Error for StartSmartHomeApplianceDiscoveryError {}
+impl ServiceErrorCode for StartSmartHomeApplianceDiscoveryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartSmartHomeApplianceDiscoveryError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -6307,6 +6943,13 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -6337,6 +6980,13 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateAddressBook
 #[derive(Debug, PartialEq)]
 pub enum UpdateAddressBookError {
@@ -6381,6 +7031,15 @@ impl fmt::Display for UpdateAddressBookError {
     }
 }
 impl Error for UpdateAddressBookError {}
+impl ServiceErrorCode for UpdateAddressBookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAddressBookError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateAddressBookError::NameInUse(_) => "NameInUseException",
+            UpdateAddressBookError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateBusinessReportSchedule
 #[derive(Debug, PartialEq)]
 pub enum UpdateBusinessReportScheduleError {
@@ -6425,6 +7084,16 @@ impl fmt::Display for UpdateBusinessReportScheduleError {
     }
 }
 impl Error for UpdateBusinessReportScheduleError {}
+impl ServiceErrorCode for UpdateBusinessReportScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateBusinessReportScheduleError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            UpdateBusinessReportScheduleError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateConferenceProvider
 #[derive(Debug, PartialEq)]
 pub enum UpdateConferenceProviderError {
@@ -6455,6 +7124,13 @@ impl fmt::Display for UpdateConferenceProviderError {
     }
 }
 impl Error for UpdateConferenceProviderError {}
+impl ServiceErrorCode for UpdateConferenceProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConferenceProviderError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateContact
 #[derive(Debug, PartialEq)]
 pub enum UpdateContactError {
@@ -6493,6 +7169,14 @@ impl fmt::Display for UpdateContactError {
     }
 }
 impl Error for UpdateContactError {}
+impl ServiceErrorCode for UpdateContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateContactError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateContactError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateDevice
 #[derive(Debug, PartialEq)]
 pub enum UpdateDeviceError {
@@ -6535,6 +7219,15 @@ impl fmt::Display for UpdateDeviceError {
     }
 }
 impl Error for UpdateDeviceError {}
+impl ServiceErrorCode for UpdateDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDeviceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateDeviceError::DeviceNotRegistered(_) => "DeviceNotRegisteredException",
+            UpdateDeviceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateGateway
 #[derive(Debug, PartialEq)]
 pub enum UpdateGatewayError {
@@ -6571,6 +7264,14 @@ impl fmt::Display for UpdateGatewayError {
     }
 }
 impl Error for UpdateGatewayError {}
+impl ServiceErrorCode for UpdateGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGatewayError::NameInUse(_) => "NameInUseException",
+            UpdateGatewayError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateGatewayGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateGatewayGroupError {
@@ -6607,6 +7308,14 @@ impl fmt::Display for UpdateGatewayGroupError {
     }
 }
 impl Error for UpdateGatewayGroupError {}
+impl ServiceErrorCode for UpdateGatewayGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGatewayGroupError::NameInUse(_) => "NameInUseException",
+            UpdateGatewayGroupError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateNetworkProfile
 #[derive(Debug, PartialEq)]
 pub enum UpdateNetworkProfileError {
@@ -6671,6 +7380,23 @@ impl fmt::Display for UpdateNetworkProfileError {
     }
 }
 impl Error for UpdateNetworkProfileError {}
+impl ServiceErrorCode for UpdateNetworkProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateNetworkProfileError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            UpdateNetworkProfileError::InvalidCertificateAuthority(_) => {
+                "InvalidCertificateAuthorityException"
+            }
+            UpdateNetworkProfileError::InvalidSecretsManagerResource(_) => {
+                "InvalidSecretsManagerResourceException"
+            }
+            UpdateNetworkProfileError::NameInUse(_) => "NameInUseException",
+            UpdateNetworkProfileError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateProfile
 #[derive(Debug, PartialEq)]
 pub enum UpdateProfileError {
@@ -6715,6 +7441,15 @@ impl fmt::Display for UpdateProfileError {
     }
 }
 impl Error for UpdateProfileError {}
+impl ServiceErrorCode for UpdateProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateProfileError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateProfileError::NameInUse(_) => "NameInUseException",
+            UpdateProfileError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateRoom
 #[derive(Debug, PartialEq)]
 pub enum UpdateRoomError {
@@ -6751,6 +7486,14 @@ impl fmt::Display for UpdateRoomError {
     }
 }
 impl Error for UpdateRoomError {}
+impl ServiceErrorCode for UpdateRoomError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRoomError::NameInUse(_) => "NameInUseException",
+            UpdateRoomError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateSkillGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateSkillGroupError {
@@ -6795,6 +7538,15 @@ impl fmt::Display for UpdateSkillGroupError {
     }
 }
 impl Error for UpdateSkillGroupError {}
+impl ServiceErrorCode for UpdateSkillGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSkillGroupError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateSkillGroupError::NameInUse(_) => "NameInUseException",
+            UpdateSkillGroupError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Alexa For Business API. Alexa For Business clients implement this trait.
 #[async_trait]
 pub trait AlexaForBusiness {
@@ -7575,6 +8327,10 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         &self,
         input: CreateAddressBookRequest,
     ) -> Result<CreateAddressBookResponse, RusotoError<CreateAddressBookError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateAddressBook");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -7594,6 +8350,10 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateBusinessReportScheduleRequest,
     ) -> Result<CreateBusinessReportScheduleResponse, RusotoError<CreateBusinessReportScheduleError>>
     {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -7616,6 +8376,10 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         &self,
         input: CreateConferenceProviderRequest,
     ) -> Result<CreateConferenceProviderResponse, RusotoError<CreateConferenceProviderError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateConferenceProvider");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -7635,6 +8399,10 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         &self,
         input: CreateContactRequest,
     ) -> Result<CreateContactResponse, RusotoError<CreateContactError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateContact");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -7653,6 +8421,12 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         &self,
         input: CreateGatewayGroupRequest,
     ) -> Result<CreateGatewayGroupResponse, RusotoError<CreateGatewayGroupError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateGatewayGroup");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -7671,6 +8445,12 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         &self,
         input: CreateNetworkProfileRequest,
     ) -> Result<CreateNetworkProfileResponse, RusotoError<CreateNetworkProfileError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -7690,6 +8470,10 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         &self,
         input: CreateProfileRequest,
     ) -> Result<CreateProfileResponse, RusotoError<CreateProfileError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateProfile");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -7708,6 +8492,10 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         &self,
         input: CreateRoomRequest,
     ) -> Result<CreateRoomResponse, RusotoError<CreateRoomError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateRoom");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -7726,6 +8514,10 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         &self,
         input: CreateSkillGroupRequest,
     ) -> Result<CreateSkillGroupResponse, RusotoError<CreateSkillGroupError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateSkillGroup");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -7744,6 +8536,10 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         &self,
         input: CreateUserRequest,
     ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateUser");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -8899,6 +9695,12 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         &self,
         input: SendAnnouncementRequest,
     ) -> Result<SendAnnouncementResponse, RusotoError<SendAnnouncementError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AlexaForBusiness.SendAnnouncement");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1752,6 +1752,17 @@ impl fmt::Display for CreateAppError {
     }
 }
 impl Error for CreateAppError {}
+impl ServiceErrorCode for CreateAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAppError::BadRequest(_) => "BadRequestException",
+            CreateAppError::DependentServiceFailure(_) => "DependentServiceFailureException",
+            CreateAppError::InternalFailure(_) => "InternalFailureException",
+            CreateAppError::LimitExceeded(_) => "LimitExceededException",
+            CreateAppError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateBackendEnvironment
 #[derive(Debug, PartialEq)]
 pub enum CreateBackendEnvironmentError {
@@ -1812,6 +1823,17 @@ impl fmt::Display for CreateBackendEnvironmentError {
     }
 }
 impl Error for CreateBackendEnvironmentError {}
+impl ServiceErrorCode for CreateBackendEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBackendEnvironmentError::BadRequest(_) => "BadRequestException",
+            CreateBackendEnvironmentError::InternalFailure(_) => "InternalFailureException",
+            CreateBackendEnvironmentError::LimitExceeded(_) => "LimitExceededException",
+            CreateBackendEnvironmentError::NotFound(_) => "NotFoundException",
+            CreateBackendEnvironmentError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateBranch
 #[derive(Debug, PartialEq)]
 pub enum CreateBranchError {
@@ -1874,6 +1896,18 @@ impl fmt::Display for CreateBranchError {
     }
 }
 impl Error for CreateBranchError {}
+impl ServiceErrorCode for CreateBranchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBranchError::BadRequest(_) => "BadRequestException",
+            CreateBranchError::DependentServiceFailure(_) => "DependentServiceFailureException",
+            CreateBranchError::InternalFailure(_) => "InternalFailureException",
+            CreateBranchError::LimitExceeded(_) => "LimitExceededException",
+            CreateBranchError::NotFound(_) => "NotFoundException",
+            CreateBranchError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateDeployment
 #[derive(Debug, PartialEq)]
 pub enum CreateDeploymentError {
@@ -1922,6 +1956,16 @@ impl fmt::Display for CreateDeploymentError {
     }
 }
 impl Error for CreateDeploymentError {}
+impl ServiceErrorCode for CreateDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeploymentError::BadRequest(_) => "BadRequestException",
+            CreateDeploymentError::InternalFailure(_) => "InternalFailureException",
+            CreateDeploymentError::LimitExceeded(_) => "LimitExceededException",
+            CreateDeploymentError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateDomainAssociation
 #[derive(Debug, PartialEq)]
 pub enum CreateDomainAssociationError {
@@ -1992,6 +2036,20 @@ impl fmt::Display for CreateDomainAssociationError {
     }
 }
 impl Error for CreateDomainAssociationError {}
+impl ServiceErrorCode for CreateDomainAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDomainAssociationError::BadRequest(_) => "BadRequestException",
+            CreateDomainAssociationError::DependentServiceFailure(_) => {
+                "DependentServiceFailureException"
+            }
+            CreateDomainAssociationError::InternalFailure(_) => "InternalFailureException",
+            CreateDomainAssociationError::LimitExceeded(_) => "LimitExceededException",
+            CreateDomainAssociationError::NotFound(_) => "NotFoundException",
+            CreateDomainAssociationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateWebhook
 #[derive(Debug, PartialEq)]
 pub enum CreateWebhookError {
@@ -2054,6 +2112,18 @@ impl fmt::Display for CreateWebhookError {
     }
 }
 impl Error for CreateWebhookError {}
+impl ServiceErrorCode for CreateWebhookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateWebhookError::BadRequest(_) => "BadRequestException",
+            CreateWebhookError::DependentServiceFailure(_) => "DependentServiceFailureException",
+            CreateWebhookError::InternalFailure(_) => "InternalFailureException",
+            CreateWebhookError::LimitExceeded(_) => "LimitExceededException",
+            CreateWebhookError::NotFound(_) => "NotFoundException",
+            CreateWebhookError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteApp
 #[derive(Debug, PartialEq)]
 pub enum DeleteAppError {
@@ -2108,6 +2178,17 @@ impl fmt::Display for DeleteAppError {
     }
 }
 impl Error for DeleteAppError {}
+impl ServiceErrorCode for DeleteAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAppError::BadRequest(_) => "BadRequestException",
+            DeleteAppError::DependentServiceFailure(_) => "DependentServiceFailureException",
+            DeleteAppError::InternalFailure(_) => "InternalFailureException",
+            DeleteAppError::NotFound(_) => "NotFoundException",
+            DeleteAppError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteBackendEnvironment
 #[derive(Debug, PartialEq)]
 pub enum DeleteBackendEnvironmentError {
@@ -2170,6 +2251,19 @@ impl fmt::Display for DeleteBackendEnvironmentError {
     }
 }
 impl Error for DeleteBackendEnvironmentError {}
+impl ServiceErrorCode for DeleteBackendEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBackendEnvironmentError::BadRequest(_) => "BadRequestException",
+            DeleteBackendEnvironmentError::DependentServiceFailure(_) => {
+                "DependentServiceFailureException"
+            }
+            DeleteBackendEnvironmentError::InternalFailure(_) => "InternalFailureException",
+            DeleteBackendEnvironmentError::NotFound(_) => "NotFoundException",
+            DeleteBackendEnvironmentError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteBranch
 #[derive(Debug, PartialEq)]
 pub enum DeleteBranchError {
@@ -2226,6 +2320,17 @@ impl fmt::Display for DeleteBranchError {
     }
 }
 impl Error for DeleteBranchError {}
+impl ServiceErrorCode for DeleteBranchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBranchError::BadRequest(_) => "BadRequestException",
+            DeleteBranchError::DependentServiceFailure(_) => "DependentServiceFailureException",
+            DeleteBranchError::InternalFailure(_) => "InternalFailureException",
+            DeleteBranchError::NotFound(_) => "NotFoundException",
+            DeleteBranchError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteDomainAssociation
 #[derive(Debug, PartialEq)]
 pub enum DeleteDomainAssociationError {
@@ -2288,6 +2393,19 @@ impl fmt::Display for DeleteDomainAssociationError {
     }
 }
 impl Error for DeleteDomainAssociationError {}
+impl ServiceErrorCode for DeleteDomainAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDomainAssociationError::BadRequest(_) => "BadRequestException",
+            DeleteDomainAssociationError::DependentServiceFailure(_) => {
+                "DependentServiceFailureException"
+            }
+            DeleteDomainAssociationError::InternalFailure(_) => "InternalFailureException",
+            DeleteDomainAssociationError::NotFound(_) => "NotFoundException",
+            DeleteDomainAssociationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteJob
 #[derive(Debug, PartialEq)]
 pub enum DeleteJobError {
@@ -2342,6 +2460,17 @@ impl fmt::Display for DeleteJobError {
     }
 }
 impl Error for DeleteJobError {}
+impl ServiceErrorCode for DeleteJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteJobError::BadRequest(_) => "BadRequestException",
+            DeleteJobError::InternalFailure(_) => "InternalFailureException",
+            DeleteJobError::LimitExceeded(_) => "LimitExceededException",
+            DeleteJobError::NotFound(_) => "NotFoundException",
+            DeleteJobError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteWebhook
 #[derive(Debug, PartialEq)]
 pub enum DeleteWebhookError {
@@ -2396,6 +2525,17 @@ impl fmt::Display for DeleteWebhookError {
     }
 }
 impl Error for DeleteWebhookError {}
+impl ServiceErrorCode for DeleteWebhookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteWebhookError::BadRequest(_) => "BadRequestException",
+            DeleteWebhookError::InternalFailure(_) => "InternalFailureException",
+            DeleteWebhookError::LimitExceeded(_) => "LimitExceededException",
+            DeleteWebhookError::NotFound(_) => "NotFoundException",
+            DeleteWebhookError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GenerateAccessLogs
 #[derive(Debug, PartialEq)]
 pub enum GenerateAccessLogsError {
@@ -2444,6 +2584,16 @@ impl fmt::Display for GenerateAccessLogsError {
     }
 }
 impl Error for GenerateAccessLogsError {}
+impl ServiceErrorCode for GenerateAccessLogsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GenerateAccessLogsError::BadRequest(_) => "BadRequestException",
+            GenerateAccessLogsError::InternalFailure(_) => "InternalFailureException",
+            GenerateAccessLogsError::NotFound(_) => "NotFoundException",
+            GenerateAccessLogsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetApp
 #[derive(Debug, PartialEq)]
 pub enum GetAppError {
@@ -2490,6 +2640,16 @@ impl fmt::Display for GetAppError {
     }
 }
 impl Error for GetAppError {}
+impl ServiceErrorCode for GetAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAppError::BadRequest(_) => "BadRequestException",
+            GetAppError::InternalFailure(_) => "InternalFailureException",
+            GetAppError::NotFound(_) => "NotFoundException",
+            GetAppError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetArtifactUrl
 #[derive(Debug, PartialEq)]
 pub enum GetArtifactUrlError {
@@ -2544,6 +2704,17 @@ impl fmt::Display for GetArtifactUrlError {
     }
 }
 impl Error for GetArtifactUrlError {}
+impl ServiceErrorCode for GetArtifactUrlError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetArtifactUrlError::BadRequest(_) => "BadRequestException",
+            GetArtifactUrlError::InternalFailure(_) => "InternalFailureException",
+            GetArtifactUrlError::LimitExceeded(_) => "LimitExceededException",
+            GetArtifactUrlError::NotFound(_) => "NotFoundException",
+            GetArtifactUrlError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetBackendEnvironment
 #[derive(Debug, PartialEq)]
 pub enum GetBackendEnvironmentError {
@@ -2594,6 +2765,16 @@ impl fmt::Display for GetBackendEnvironmentError {
     }
 }
 impl Error for GetBackendEnvironmentError {}
+impl ServiceErrorCode for GetBackendEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBackendEnvironmentError::BadRequest(_) => "BadRequestException",
+            GetBackendEnvironmentError::InternalFailure(_) => "InternalFailureException",
+            GetBackendEnvironmentError::NotFound(_) => "NotFoundException",
+            GetBackendEnvironmentError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetBranch
 #[derive(Debug, PartialEq)]
 pub enum GetBranchError {
@@ -2642,6 +2823,16 @@ impl fmt::Display for GetBranchError {
     }
 }
 impl Error for GetBranchError {}
+impl ServiceErrorCode for GetBranchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBranchError::BadRequest(_) => "BadRequestException",
+            GetBranchError::InternalFailure(_) => "InternalFailureException",
+            GetBranchError::NotFound(_) => "NotFoundException",
+            GetBranchError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetDomainAssociation
 #[derive(Debug, PartialEq)]
 pub enum GetDomainAssociationError {
@@ -2692,6 +2883,16 @@ impl fmt::Display for GetDomainAssociationError {
     }
 }
 impl Error for GetDomainAssociationError {}
+impl ServiceErrorCode for GetDomainAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDomainAssociationError::BadRequest(_) => "BadRequestException",
+            GetDomainAssociationError::InternalFailure(_) => "InternalFailureException",
+            GetDomainAssociationError::NotFound(_) => "NotFoundException",
+            GetDomainAssociationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetJob
 #[derive(Debug, PartialEq)]
 pub enum GetJobError {
@@ -2744,6 +2945,17 @@ impl fmt::Display for GetJobError {
     }
 }
 impl Error for GetJobError {}
+impl ServiceErrorCode for GetJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetJobError::BadRequest(_) => "BadRequestException",
+            GetJobError::InternalFailure(_) => "InternalFailureException",
+            GetJobError::LimitExceeded(_) => "LimitExceededException",
+            GetJobError::NotFound(_) => "NotFoundException",
+            GetJobError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetWebhook
 #[derive(Debug, PartialEq)]
 pub enum GetWebhookError {
@@ -2798,6 +3010,17 @@ impl fmt::Display for GetWebhookError {
     }
 }
 impl Error for GetWebhookError {}
+impl ServiceErrorCode for GetWebhookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetWebhookError::BadRequest(_) => "BadRequestException",
+            GetWebhookError::InternalFailure(_) => "InternalFailureException",
+            GetWebhookError::LimitExceeded(_) => "LimitExceededException",
+            GetWebhookError::NotFound(_) => "NotFoundException",
+            GetWebhookError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListApps
 #[derive(Debug, PartialEq)]
 pub enum ListAppsError {
@@ -2840,6 +3063,15 @@ impl fmt::Display for ListAppsError {
     }
 }
 impl Error for ListAppsError {}
+impl ServiceErrorCode for ListAppsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAppsError::BadRequest(_) => "BadRequestException",
+            ListAppsError::InternalFailure(_) => "InternalFailureException",
+            ListAppsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListArtifacts
 #[derive(Debug, PartialEq)]
 pub enum ListArtifactsError {
@@ -2888,6 +3120,16 @@ impl fmt::Display for ListArtifactsError {
     }
 }
 impl Error for ListArtifactsError {}
+impl ServiceErrorCode for ListArtifactsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListArtifactsError::BadRequest(_) => "BadRequestException",
+            ListArtifactsError::InternalFailure(_) => "InternalFailureException",
+            ListArtifactsError::LimitExceeded(_) => "LimitExceededException",
+            ListArtifactsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListBackendEnvironments
 #[derive(Debug, PartialEq)]
 pub enum ListBackendEnvironmentsError {
@@ -2934,6 +3176,15 @@ impl fmt::Display for ListBackendEnvironmentsError {
     }
 }
 impl Error for ListBackendEnvironmentsError {}
+impl ServiceErrorCode for ListBackendEnvironmentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBackendEnvironmentsError::BadRequest(_) => "BadRequestException",
+            ListBackendEnvironmentsError::InternalFailure(_) => "InternalFailureException",
+            ListBackendEnvironmentsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListBranches
 #[derive(Debug, PartialEq)]
 pub enum ListBranchesError {
@@ -2976,6 +3227,15 @@ impl fmt::Display for ListBranchesError {
     }
 }
 impl Error for ListBranchesError {}
+impl ServiceErrorCode for ListBranchesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBranchesError::BadRequest(_) => "BadRequestException",
+            ListBranchesError::InternalFailure(_) => "InternalFailureException",
+            ListBranchesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListDomainAssociations
 #[derive(Debug, PartialEq)]
 pub enum ListDomainAssociationsError {
@@ -3020,6 +3280,15 @@ impl fmt::Display for ListDomainAssociationsError {
     }
 }
 impl Error for ListDomainAssociationsError {}
+impl ServiceErrorCode for ListDomainAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDomainAssociationsError::BadRequest(_) => "BadRequestException",
+            ListDomainAssociationsError::InternalFailure(_) => "InternalFailureException",
+            ListDomainAssociationsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
 pub enum ListJobsError {
@@ -3068,6 +3337,16 @@ impl fmt::Display for ListJobsError {
     }
 }
 impl Error for ListJobsError {}
+impl ServiceErrorCode for ListJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJobsError::BadRequest(_) => "BadRequestException",
+            ListJobsError::InternalFailure(_) => "InternalFailureException",
+            ListJobsError::LimitExceeded(_) => "LimitExceededException",
+            ListJobsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3112,6 +3391,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::InternalFailure(_) => "InternalFailureException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListWebhooks
 #[derive(Debug, PartialEq)]
 pub enum ListWebhooksError {
@@ -3160,6 +3448,16 @@ impl fmt::Display for ListWebhooksError {
     }
 }
 impl Error for ListWebhooksError {}
+impl ServiceErrorCode for ListWebhooksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListWebhooksError::BadRequest(_) => "BadRequestException",
+            ListWebhooksError::InternalFailure(_) => "InternalFailureException",
+            ListWebhooksError::LimitExceeded(_) => "LimitExceededException",
+            ListWebhooksError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by StartDeployment
 #[derive(Debug, PartialEq)]
 pub enum StartDeploymentError {
@@ -3214,6 +3512,17 @@ impl fmt::Display for StartDeploymentError {
     }
 }
 impl Error for StartDeploymentError {}
+impl ServiceErrorCode for StartDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDeploymentError::BadRequest(_) => "BadRequestException",
+            StartDeploymentError::InternalFailure(_) => "InternalFailureException",
+            StartDeploymentError::LimitExceeded(_) => "LimitExceededException",
+            StartDeploymentError::NotFound(_) => "NotFoundException",
+            StartDeploymentError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by StartJob
 #[derive(Debug, PartialEq)]
 pub enum StartJobError {
@@ -3268,6 +3577,17 @@ impl fmt::Display for StartJobError {
     }
 }
 impl Error for StartJobError {}
+impl ServiceErrorCode for StartJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartJobError::BadRequest(_) => "BadRequestException",
+            StartJobError::InternalFailure(_) => "InternalFailureException",
+            StartJobError::LimitExceeded(_) => "LimitExceededException",
+            StartJobError::NotFound(_) => "NotFoundException",
+            StartJobError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by StopJob
 #[derive(Debug, PartialEq)]
 pub enum StopJobError {
@@ -3322,6 +3642,17 @@ impl fmt::Display for StopJobError {
     }
 }
 impl Error for StopJobError {}
+impl ServiceErrorCode for StopJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopJobError::BadRequest(_) => "BadRequestException",
+            StopJobError::InternalFailure(_) => "InternalFailureException",
+            StopJobError::LimitExceeded(_) => "LimitExceededException",
+            StopJobError::NotFound(_) => "NotFoundException",
+            StopJobError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -3364,6 +3695,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::InternalFailure(_) => "InternalFailureException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3406,6 +3746,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::InternalFailure(_) => "InternalFailureException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateApp
 #[derive(Debug, PartialEq)]
 pub enum UpdateAppError {
@@ -3454,6 +3803,16 @@ impl fmt::Display for UpdateAppError {
     }
 }
 impl Error for UpdateAppError {}
+impl ServiceErrorCode for UpdateAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAppError::BadRequest(_) => "BadRequestException",
+            UpdateAppError::InternalFailure(_) => "InternalFailureException",
+            UpdateAppError::NotFound(_) => "NotFoundException",
+            UpdateAppError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateBranch
 #[derive(Debug, PartialEq)]
 pub enum UpdateBranchError {
@@ -3510,6 +3869,17 @@ impl fmt::Display for UpdateBranchError {
     }
 }
 impl Error for UpdateBranchError {}
+impl ServiceErrorCode for UpdateBranchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateBranchError::BadRequest(_) => "BadRequestException",
+            UpdateBranchError::DependentServiceFailure(_) => "DependentServiceFailureException",
+            UpdateBranchError::InternalFailure(_) => "InternalFailureException",
+            UpdateBranchError::NotFound(_) => "NotFoundException",
+            UpdateBranchError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateDomainAssociation
 #[derive(Debug, PartialEq)]
 pub enum UpdateDomainAssociationError {
@@ -3572,6 +3942,19 @@ impl fmt::Display for UpdateDomainAssociationError {
     }
 }
 impl Error for UpdateDomainAssociationError {}
+impl ServiceErrorCode for UpdateDomainAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDomainAssociationError::BadRequest(_) => "BadRequestException",
+            UpdateDomainAssociationError::DependentServiceFailure(_) => {
+                "DependentServiceFailureException"
+            }
+            UpdateDomainAssociationError::InternalFailure(_) => "InternalFailureException",
+            UpdateDomainAssociationError::NotFound(_) => "NotFoundException",
+            UpdateDomainAssociationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateWebhook
 #[derive(Debug, PartialEq)]
 pub enum UpdateWebhookError {
@@ -3628,6 +4011,17 @@ impl fmt::Display for UpdateWebhookError {
     }
 }
 impl Error for UpdateWebhookError {}
+impl ServiceErrorCode for UpdateWebhookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateWebhookError::BadRequest(_) => "BadRequestException",
+            UpdateWebhookError::DependentServiceFailure(_) => "DependentServiceFailureException",
+            UpdateWebhookError::InternalFailure(_) => "InternalFailureException",
+            UpdateWebhookError::NotFound(_) => "NotFoundException",
+            UpdateWebhookError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amplify API. Amplify clients implement this trait.
 #[async_trait]
 pub trait Amplify {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -3757,6 +3757,18 @@ impl fmt::Display for CreateApiKeyError {
     }
 }
 impl Error for CreateApiKeyError {}
+impl ServiceErrorCode for CreateApiKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApiKeyError::BadRequest(_) => "BadRequestException",
+            CreateApiKeyError::Conflict(_) => "ConflictException",
+            CreateApiKeyError::LimitExceeded(_) => "LimitExceededException",
+            CreateApiKeyError::NotFound(_) => "NotFoundException",
+            CreateApiKeyError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateApiKeyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum CreateAuthorizerError {
@@ -3811,6 +3823,17 @@ impl fmt::Display for CreateAuthorizerError {
     }
 }
 impl Error for CreateAuthorizerError {}
+impl ServiceErrorCode for CreateAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAuthorizerError::BadRequest(_) => "BadRequestException",
+            CreateAuthorizerError::LimitExceeded(_) => "LimitExceededException",
+            CreateAuthorizerError::NotFound(_) => "NotFoundException",
+            CreateAuthorizerError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateAuthorizerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateBasePathMapping
 #[derive(Debug, PartialEq)]
 pub enum CreateBasePathMappingError {
@@ -3867,6 +3890,17 @@ impl fmt::Display for CreateBasePathMappingError {
     }
 }
 impl Error for CreateBasePathMappingError {}
+impl ServiceErrorCode for CreateBasePathMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBasePathMappingError::BadRequest(_) => "BadRequestException",
+            CreateBasePathMappingError::Conflict(_) => "ConflictException",
+            CreateBasePathMappingError::NotFound(_) => "NotFoundException",
+            CreateBasePathMappingError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateBasePathMappingError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateDeployment
 #[derive(Debug, PartialEq)]
 pub enum CreateDeploymentError {
@@ -3933,6 +3967,19 @@ impl fmt::Display for CreateDeploymentError {
     }
 }
 impl Error for CreateDeploymentError {}
+impl ServiceErrorCode for CreateDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeploymentError::BadRequest(_) => "BadRequestException",
+            CreateDeploymentError::Conflict(_) => "ConflictException",
+            CreateDeploymentError::LimitExceeded(_) => "LimitExceededException",
+            CreateDeploymentError::NotFound(_) => "NotFoundException",
+            CreateDeploymentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateDeploymentError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateDeploymentError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateDocumentationPart
 #[derive(Debug, PartialEq)]
 pub enum CreateDocumentationPartError {
@@ -3999,6 +4046,18 @@ impl fmt::Display for CreateDocumentationPartError {
     }
 }
 impl Error for CreateDocumentationPartError {}
+impl ServiceErrorCode for CreateDocumentationPartError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDocumentationPartError::BadRequest(_) => "BadRequestException",
+            CreateDocumentationPartError::Conflict(_) => "ConflictException",
+            CreateDocumentationPartError::LimitExceeded(_) => "LimitExceededException",
+            CreateDocumentationPartError::NotFound(_) => "NotFoundException",
+            CreateDocumentationPartError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateDocumentationPartError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateDocumentationVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateDocumentationVersionError {
@@ -4069,6 +4128,18 @@ impl fmt::Display for CreateDocumentationVersionError {
     }
 }
 impl Error for CreateDocumentationVersionError {}
+impl ServiceErrorCode for CreateDocumentationVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDocumentationVersionError::BadRequest(_) => "BadRequestException",
+            CreateDocumentationVersionError::Conflict(_) => "ConflictException",
+            CreateDocumentationVersionError::LimitExceeded(_) => "LimitExceededException",
+            CreateDocumentationVersionError::NotFound(_) => "NotFoundException",
+            CreateDocumentationVersionError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateDocumentationVersionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateDomainName
 #[derive(Debug, PartialEq)]
 pub enum CreateDomainNameError {
@@ -4117,6 +4188,16 @@ impl fmt::Display for CreateDomainNameError {
     }
 }
 impl Error for CreateDomainNameError {}
+impl ServiceErrorCode for CreateDomainNameError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDomainNameError::BadRequest(_) => "BadRequestException",
+            CreateDomainNameError::Conflict(_) => "ConflictException",
+            CreateDomainNameError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateDomainNameError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateModel
 #[derive(Debug, PartialEq)]
 pub enum CreateModelError {
@@ -4177,6 +4258,18 @@ impl fmt::Display for CreateModelError {
     }
 }
 impl Error for CreateModelError {}
+impl ServiceErrorCode for CreateModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateModelError::BadRequest(_) => "BadRequestException",
+            CreateModelError::Conflict(_) => "ConflictException",
+            CreateModelError::LimitExceeded(_) => "LimitExceededException",
+            CreateModelError::NotFound(_) => "NotFoundException",
+            CreateModelError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateModelError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateRequestValidator
 #[derive(Debug, PartialEq)]
 pub enum CreateRequestValidatorError {
@@ -4235,6 +4328,17 @@ impl fmt::Display for CreateRequestValidatorError {
     }
 }
 impl Error for CreateRequestValidatorError {}
+impl ServiceErrorCode for CreateRequestValidatorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRequestValidatorError::BadRequest(_) => "BadRequestException",
+            CreateRequestValidatorError::LimitExceeded(_) => "LimitExceededException",
+            CreateRequestValidatorError::NotFound(_) => "NotFoundException",
+            CreateRequestValidatorError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateRequestValidatorError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateResource
 #[derive(Debug, PartialEq)]
 pub enum CreateResourceError {
@@ -4295,6 +4399,18 @@ impl fmt::Display for CreateResourceError {
     }
 }
 impl Error for CreateResourceError {}
+impl ServiceErrorCode for CreateResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateResourceError::BadRequest(_) => "BadRequestException",
+            CreateResourceError::Conflict(_) => "ConflictException",
+            CreateResourceError::LimitExceeded(_) => "LimitExceededException",
+            CreateResourceError::NotFound(_) => "NotFoundException",
+            CreateResourceError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateResourceError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateRestApi
 #[derive(Debug, PartialEq)]
 pub enum CreateRestApiError {
@@ -4343,6 +4459,16 @@ impl fmt::Display for CreateRestApiError {
     }
 }
 impl Error for CreateRestApiError {}
+impl ServiceErrorCode for CreateRestApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRestApiError::BadRequest(_) => "BadRequestException",
+            CreateRestApiError::LimitExceeded(_) => "LimitExceededException",
+            CreateRestApiError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateRestApiError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateStage
 #[derive(Debug, PartialEq)]
 pub enum CreateStageError {
@@ -4403,6 +4529,18 @@ impl fmt::Display for CreateStageError {
     }
 }
 impl Error for CreateStageError {}
+impl ServiceErrorCode for CreateStageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStageError::BadRequest(_) => "BadRequestException",
+            CreateStageError::Conflict(_) => "ConflictException",
+            CreateStageError::LimitExceeded(_) => "LimitExceededException",
+            CreateStageError::NotFound(_) => "NotFoundException",
+            CreateStageError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateStageError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateUsagePlan
 #[derive(Debug, PartialEq)]
 pub enum CreateUsagePlanError {
@@ -4463,6 +4601,18 @@ impl fmt::Display for CreateUsagePlanError {
     }
 }
 impl Error for CreateUsagePlanError {}
+impl ServiceErrorCode for CreateUsagePlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUsagePlanError::BadRequest(_) => "BadRequestException",
+            CreateUsagePlanError::Conflict(_) => "ConflictException",
+            CreateUsagePlanError::LimitExceeded(_) => "LimitExceededException",
+            CreateUsagePlanError::NotFound(_) => "NotFoundException",
+            CreateUsagePlanError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateUsagePlanError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateUsagePlanKey
 #[derive(Debug, PartialEq)]
 pub enum CreateUsagePlanKeyError {
@@ -4517,6 +4667,17 @@ impl fmt::Display for CreateUsagePlanKeyError {
     }
 }
 impl Error for CreateUsagePlanKeyError {}
+impl ServiceErrorCode for CreateUsagePlanKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUsagePlanKeyError::BadRequest(_) => "BadRequestException",
+            CreateUsagePlanKeyError::Conflict(_) => "ConflictException",
+            CreateUsagePlanKeyError::NotFound(_) => "NotFoundException",
+            CreateUsagePlanKeyError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateUsagePlanKeyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateVpcLink
 #[derive(Debug, PartialEq)]
 pub enum CreateVpcLinkError {
@@ -4559,6 +4720,15 @@ impl fmt::Display for CreateVpcLinkError {
     }
 }
 impl Error for CreateVpcLinkError {}
+impl ServiceErrorCode for CreateVpcLinkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVpcLinkError::BadRequest(_) => "BadRequestException",
+            CreateVpcLinkError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateVpcLinkError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteApiKey
 #[derive(Debug, PartialEq)]
 pub enum DeleteApiKeyError {
@@ -4601,6 +4771,15 @@ impl fmt::Display for DeleteApiKeyError {
     }
 }
 impl Error for DeleteApiKeyError {}
+impl ServiceErrorCode for DeleteApiKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApiKeyError::NotFound(_) => "NotFoundException",
+            DeleteApiKeyError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteApiKeyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum DeleteAuthorizerError {
@@ -4655,6 +4834,17 @@ impl fmt::Display for DeleteAuthorizerError {
     }
 }
 impl Error for DeleteAuthorizerError {}
+impl ServiceErrorCode for DeleteAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAuthorizerError::BadRequest(_) => "BadRequestException",
+            DeleteAuthorizerError::Conflict(_) => "ConflictException",
+            DeleteAuthorizerError::NotFound(_) => "NotFoundException",
+            DeleteAuthorizerError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteAuthorizerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteBasePathMapping
 #[derive(Debug, PartialEq)]
 pub enum DeleteBasePathMappingError {
@@ -4711,6 +4901,17 @@ impl fmt::Display for DeleteBasePathMappingError {
     }
 }
 impl Error for DeleteBasePathMappingError {}
+impl ServiceErrorCode for DeleteBasePathMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBasePathMappingError::BadRequest(_) => "BadRequestException",
+            DeleteBasePathMappingError::Conflict(_) => "ConflictException",
+            DeleteBasePathMappingError::NotFound(_) => "NotFoundException",
+            DeleteBasePathMappingError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteBasePathMappingError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteClientCertificate
 #[derive(Debug, PartialEq)]
 pub enum DeleteClientCertificateError {
@@ -4763,6 +4964,16 @@ impl fmt::Display for DeleteClientCertificateError {
     }
 }
 impl Error for DeleteClientCertificateError {}
+impl ServiceErrorCode for DeleteClientCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteClientCertificateError::BadRequest(_) => "BadRequestException",
+            DeleteClientCertificateError::NotFound(_) => "NotFoundException",
+            DeleteClientCertificateError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteClientCertificateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteDeployment
 #[derive(Debug, PartialEq)]
 pub enum DeleteDeploymentError {
@@ -4811,6 +5022,16 @@ impl fmt::Display for DeleteDeploymentError {
     }
 }
 impl Error for DeleteDeploymentError {}
+impl ServiceErrorCode for DeleteDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDeploymentError::BadRequest(_) => "BadRequestException",
+            DeleteDeploymentError::NotFound(_) => "NotFoundException",
+            DeleteDeploymentError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteDeploymentError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteDocumentationPart
 #[derive(Debug, PartialEq)]
 pub enum DeleteDocumentationPartError {
@@ -4869,6 +5090,17 @@ impl fmt::Display for DeleteDocumentationPartError {
     }
 }
 impl Error for DeleteDocumentationPartError {}
+impl ServiceErrorCode for DeleteDocumentationPartError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDocumentationPartError::BadRequest(_) => "BadRequestException",
+            DeleteDocumentationPartError::Conflict(_) => "ConflictException",
+            DeleteDocumentationPartError::NotFound(_) => "NotFoundException",
+            DeleteDocumentationPartError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteDocumentationPartError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteDocumentationVersion
 #[derive(Debug, PartialEq)]
 pub enum DeleteDocumentationVersionError {
@@ -4931,6 +5163,17 @@ impl fmt::Display for DeleteDocumentationVersionError {
     }
 }
 impl Error for DeleteDocumentationVersionError {}
+impl ServiceErrorCode for DeleteDocumentationVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDocumentationVersionError::BadRequest(_) => "BadRequestException",
+            DeleteDocumentationVersionError::Conflict(_) => "ConflictException",
+            DeleteDocumentationVersionError::NotFound(_) => "NotFoundException",
+            DeleteDocumentationVersionError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteDocumentationVersionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteDomainName
 #[derive(Debug, PartialEq)]
 pub enum DeleteDomainNameError {
@@ -4979,6 +5222,16 @@ impl fmt::Display for DeleteDomainNameError {
     }
 }
 impl Error for DeleteDomainNameError {}
+impl ServiceErrorCode for DeleteDomainNameError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDomainNameError::BadRequest(_) => "BadRequestException",
+            DeleteDomainNameError::NotFound(_) => "NotFoundException",
+            DeleteDomainNameError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteDomainNameError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteGatewayResponse
 #[derive(Debug, PartialEq)]
 pub enum DeleteGatewayResponseError {
@@ -5035,6 +5288,17 @@ impl fmt::Display for DeleteGatewayResponseError {
     }
 }
 impl Error for DeleteGatewayResponseError {}
+impl ServiceErrorCode for DeleteGatewayResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGatewayResponseError::BadRequest(_) => "BadRequestException",
+            DeleteGatewayResponseError::Conflict(_) => "ConflictException",
+            DeleteGatewayResponseError::NotFound(_) => "NotFoundException",
+            DeleteGatewayResponseError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteGatewayResponseError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteIntegration
 #[derive(Debug, PartialEq)]
 pub enum DeleteIntegrationError {
@@ -5083,6 +5347,16 @@ impl fmt::Display for DeleteIntegrationError {
     }
 }
 impl Error for DeleteIntegrationError {}
+impl ServiceErrorCode for DeleteIntegrationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIntegrationError::Conflict(_) => "ConflictException",
+            DeleteIntegrationError::NotFound(_) => "NotFoundException",
+            DeleteIntegrationError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteIntegrationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteIntegrationResponse
 #[derive(Debug, PartialEq)]
 pub enum DeleteIntegrationResponseError {
@@ -5143,6 +5417,17 @@ impl fmt::Display for DeleteIntegrationResponseError {
     }
 }
 impl Error for DeleteIntegrationResponseError {}
+impl ServiceErrorCode for DeleteIntegrationResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIntegrationResponseError::BadRequest(_) => "BadRequestException",
+            DeleteIntegrationResponseError::Conflict(_) => "ConflictException",
+            DeleteIntegrationResponseError::NotFound(_) => "NotFoundException",
+            DeleteIntegrationResponseError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteIntegrationResponseError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteMethod
 #[derive(Debug, PartialEq)]
 pub enum DeleteMethodError {
@@ -5191,6 +5476,16 @@ impl fmt::Display for DeleteMethodError {
     }
 }
 impl Error for DeleteMethodError {}
+impl ServiceErrorCode for DeleteMethodError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMethodError::Conflict(_) => "ConflictException",
+            DeleteMethodError::NotFound(_) => "NotFoundException",
+            DeleteMethodError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteMethodError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteMethodResponse
 #[derive(Debug, PartialEq)]
 pub enum DeleteMethodResponseError {
@@ -5247,6 +5542,17 @@ impl fmt::Display for DeleteMethodResponseError {
     }
 }
 impl Error for DeleteMethodResponseError {}
+impl ServiceErrorCode for DeleteMethodResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMethodResponseError::BadRequest(_) => "BadRequestException",
+            DeleteMethodResponseError::Conflict(_) => "ConflictException",
+            DeleteMethodResponseError::NotFound(_) => "NotFoundException",
+            DeleteMethodResponseError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteMethodResponseError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteModel
 #[derive(Debug, PartialEq)]
 pub enum DeleteModelError {
@@ -5301,6 +5607,17 @@ impl fmt::Display for DeleteModelError {
     }
 }
 impl Error for DeleteModelError {}
+impl ServiceErrorCode for DeleteModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteModelError::BadRequest(_) => "BadRequestException",
+            DeleteModelError::Conflict(_) => "ConflictException",
+            DeleteModelError::NotFound(_) => "NotFoundException",
+            DeleteModelError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteModelError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteRequestValidator
 #[derive(Debug, PartialEq)]
 pub enum DeleteRequestValidatorError {
@@ -5357,6 +5674,17 @@ impl fmt::Display for DeleteRequestValidatorError {
     }
 }
 impl Error for DeleteRequestValidatorError {}
+impl ServiceErrorCode for DeleteRequestValidatorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRequestValidatorError::BadRequest(_) => "BadRequestException",
+            DeleteRequestValidatorError::Conflict(_) => "ConflictException",
+            DeleteRequestValidatorError::NotFound(_) => "NotFoundException",
+            DeleteRequestValidatorError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteRequestValidatorError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteResource
 #[derive(Debug, PartialEq)]
 pub enum DeleteResourceError {
@@ -5411,6 +5739,17 @@ impl fmt::Display for DeleteResourceError {
     }
 }
 impl Error for DeleteResourceError {}
+impl ServiceErrorCode for DeleteResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResourceError::BadRequest(_) => "BadRequestException",
+            DeleteResourceError::Conflict(_) => "ConflictException",
+            DeleteResourceError::NotFound(_) => "NotFoundException",
+            DeleteResourceError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteResourceError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteRestApi
 #[derive(Debug, PartialEq)]
 pub enum DeleteRestApiError {
@@ -5459,6 +5798,16 @@ impl fmt::Display for DeleteRestApiError {
     }
 }
 impl Error for DeleteRestApiError {}
+impl ServiceErrorCode for DeleteRestApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRestApiError::BadRequest(_) => "BadRequestException",
+            DeleteRestApiError::NotFound(_) => "NotFoundException",
+            DeleteRestApiError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteRestApiError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteStage
 #[derive(Debug, PartialEq)]
 pub enum DeleteStageError {
@@ -5507,6 +5856,16 @@ impl fmt::Display for DeleteStageError {
     }
 }
 impl Error for DeleteStageError {}
+impl ServiceErrorCode for DeleteStageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStageError::BadRequest(_) => "BadRequestException",
+            DeleteStageError::NotFound(_) => "NotFoundException",
+            DeleteStageError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteStageError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteUsagePlan
 #[derive(Debug, PartialEq)]
 pub enum DeleteUsagePlanError {
@@ -5555,6 +5914,16 @@ impl fmt::Display for DeleteUsagePlanError {
     }
 }
 impl Error for DeleteUsagePlanError {}
+impl ServiceErrorCode for DeleteUsagePlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUsagePlanError::BadRequest(_) => "BadRequestException",
+            DeleteUsagePlanError::NotFound(_) => "NotFoundException",
+            DeleteUsagePlanError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteUsagePlanError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteUsagePlanKey
 #[derive(Debug, PartialEq)]
 pub enum DeleteUsagePlanKeyError {
@@ -5609,6 +5978,17 @@ impl fmt::Display for DeleteUsagePlanKeyError {
     }
 }
 impl Error for DeleteUsagePlanKeyError {}
+impl ServiceErrorCode for DeleteUsagePlanKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUsagePlanKeyError::BadRequest(_) => "BadRequestException",
+            DeleteUsagePlanKeyError::Conflict(_) => "ConflictException",
+            DeleteUsagePlanKeyError::NotFound(_) => "NotFoundException",
+            DeleteUsagePlanKeyError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteUsagePlanKeyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteVpcLink
 #[derive(Debug, PartialEq)]
 pub enum DeleteVpcLinkError {
@@ -5657,6 +6037,16 @@ impl fmt::Display for DeleteVpcLinkError {
     }
 }
 impl Error for DeleteVpcLinkError {}
+impl ServiceErrorCode for DeleteVpcLinkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVpcLinkError::BadRequest(_) => "BadRequestException",
+            DeleteVpcLinkError::NotFound(_) => "NotFoundException",
+            DeleteVpcLinkError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteVpcLinkError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by FlushStageAuthorizersCache
 #[derive(Debug, PartialEq)]
 pub enum FlushStageAuthorizersCacheError {
@@ -5713,6 +6103,16 @@ impl fmt::Display for FlushStageAuthorizersCacheError {
     }
 }
 impl Error for FlushStageAuthorizersCacheError {}
+impl ServiceErrorCode for FlushStageAuthorizersCacheError {
+    fn error_code(&self) -> &str {
+        match *self {
+            FlushStageAuthorizersCacheError::BadRequest(_) => "BadRequestException",
+            FlushStageAuthorizersCacheError::NotFound(_) => "NotFoundException",
+            FlushStageAuthorizersCacheError::TooManyRequests(_) => "TooManyRequestsException",
+            FlushStageAuthorizersCacheError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by FlushStageCache
 #[derive(Debug, PartialEq)]
 pub enum FlushStageCacheError {
@@ -5761,6 +6161,16 @@ impl fmt::Display for FlushStageCacheError {
     }
 }
 impl Error for FlushStageCacheError {}
+impl ServiceErrorCode for FlushStageCacheError {
+    fn error_code(&self) -> &str {
+        match *self {
+            FlushStageCacheError::BadRequest(_) => "BadRequestException",
+            FlushStageCacheError::NotFound(_) => "NotFoundException",
+            FlushStageCacheError::TooManyRequests(_) => "TooManyRequestsException",
+            FlushStageCacheError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GenerateClientCertificate
 #[derive(Debug, PartialEq)]
 pub enum GenerateClientCertificateError {
@@ -5809,6 +6219,15 @@ impl fmt::Display for GenerateClientCertificateError {
     }
 }
 impl Error for GenerateClientCertificateError {}
+impl ServiceErrorCode for GenerateClientCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GenerateClientCertificateError::LimitExceeded(_) => "LimitExceededException",
+            GenerateClientCertificateError::TooManyRequests(_) => "TooManyRequestsException",
+            GenerateClientCertificateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetAccount
 #[derive(Debug, PartialEq)]
 pub enum GetAccountError {
@@ -5851,6 +6270,15 @@ impl fmt::Display for GetAccountError {
     }
 }
 impl Error for GetAccountError {}
+impl ServiceErrorCode for GetAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAccountError::NotFound(_) => "NotFoundException",
+            GetAccountError::TooManyRequests(_) => "TooManyRequestsException",
+            GetAccountError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetApiKey
 #[derive(Debug, PartialEq)]
 pub enum GetApiKeyError {
@@ -5893,6 +6321,15 @@ impl fmt::Display for GetApiKeyError {
     }
 }
 impl Error for GetApiKeyError {}
+impl ServiceErrorCode for GetApiKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetApiKeyError::NotFound(_) => "NotFoundException",
+            GetApiKeyError::TooManyRequests(_) => "TooManyRequestsException",
+            GetApiKeyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetApiKeys
 #[derive(Debug, PartialEq)]
 pub enum GetApiKeysError {
@@ -5935,6 +6372,15 @@ impl fmt::Display for GetApiKeysError {
     }
 }
 impl Error for GetApiKeysError {}
+impl ServiceErrorCode for GetApiKeysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetApiKeysError::BadRequest(_) => "BadRequestException",
+            GetApiKeysError::TooManyRequests(_) => "TooManyRequestsException",
+            GetApiKeysError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum GetAuthorizerError {
@@ -5977,6 +6423,15 @@ impl fmt::Display for GetAuthorizerError {
     }
 }
 impl Error for GetAuthorizerError {}
+impl ServiceErrorCode for GetAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAuthorizerError::NotFound(_) => "NotFoundException",
+            GetAuthorizerError::TooManyRequests(_) => "TooManyRequestsException",
+            GetAuthorizerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetAuthorizers
 #[derive(Debug, PartialEq)]
 pub enum GetAuthorizersError {
@@ -6025,6 +6480,16 @@ impl fmt::Display for GetAuthorizersError {
     }
 }
 impl Error for GetAuthorizersError {}
+impl ServiceErrorCode for GetAuthorizersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAuthorizersError::BadRequest(_) => "BadRequestException",
+            GetAuthorizersError::NotFound(_) => "NotFoundException",
+            GetAuthorizersError::TooManyRequests(_) => "TooManyRequestsException",
+            GetAuthorizersError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetBasePathMapping
 #[derive(Debug, PartialEq)]
 pub enum GetBasePathMappingError {
@@ -6067,6 +6532,15 @@ impl fmt::Display for GetBasePathMappingError {
     }
 }
 impl Error for GetBasePathMappingError {}
+impl ServiceErrorCode for GetBasePathMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBasePathMappingError::NotFound(_) => "NotFoundException",
+            GetBasePathMappingError::TooManyRequests(_) => "TooManyRequestsException",
+            GetBasePathMappingError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetBasePathMappings
 #[derive(Debug, PartialEq)]
 pub enum GetBasePathMappingsError {
@@ -6109,6 +6583,15 @@ impl fmt::Display for GetBasePathMappingsError {
     }
 }
 impl Error for GetBasePathMappingsError {}
+impl ServiceErrorCode for GetBasePathMappingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBasePathMappingsError::NotFound(_) => "NotFoundException",
+            GetBasePathMappingsError::TooManyRequests(_) => "TooManyRequestsException",
+            GetBasePathMappingsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetClientCertificate
 #[derive(Debug, PartialEq)]
 pub enum GetClientCertificateError {
@@ -6153,6 +6636,15 @@ impl fmt::Display for GetClientCertificateError {
     }
 }
 impl Error for GetClientCertificateError {}
+impl ServiceErrorCode for GetClientCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetClientCertificateError::NotFound(_) => "NotFoundException",
+            GetClientCertificateError::TooManyRequests(_) => "TooManyRequestsException",
+            GetClientCertificateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetClientCertificates
 #[derive(Debug, PartialEq)]
 pub enum GetClientCertificatesError {
@@ -6197,6 +6689,15 @@ impl fmt::Display for GetClientCertificatesError {
     }
 }
 impl Error for GetClientCertificatesError {}
+impl ServiceErrorCode for GetClientCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetClientCertificatesError::BadRequest(_) => "BadRequestException",
+            GetClientCertificatesError::TooManyRequests(_) => "TooManyRequestsException",
+            GetClientCertificatesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetDeployment
 #[derive(Debug, PartialEq)]
 pub enum GetDeploymentError {
@@ -6245,6 +6746,16 @@ impl fmt::Display for GetDeploymentError {
     }
 }
 impl Error for GetDeploymentError {}
+impl ServiceErrorCode for GetDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeploymentError::NotFound(_) => "NotFoundException",
+            GetDeploymentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetDeploymentError::TooManyRequests(_) => "TooManyRequestsException",
+            GetDeploymentError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetDeployments
 #[derive(Debug, PartialEq)]
 pub enum GetDeploymentsError {
@@ -6299,6 +6810,17 @@ impl fmt::Display for GetDeploymentsError {
     }
 }
 impl Error for GetDeploymentsError {}
+impl ServiceErrorCode for GetDeploymentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeploymentsError::BadRequest(_) => "BadRequestException",
+            GetDeploymentsError::NotFound(_) => "NotFoundException",
+            GetDeploymentsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetDeploymentsError::TooManyRequests(_) => "TooManyRequestsException",
+            GetDeploymentsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetDocumentationPart
 #[derive(Debug, PartialEq)]
 pub enum GetDocumentationPartError {
@@ -6343,6 +6865,15 @@ impl fmt::Display for GetDocumentationPartError {
     }
 }
 impl Error for GetDocumentationPartError {}
+impl ServiceErrorCode for GetDocumentationPartError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDocumentationPartError::NotFound(_) => "NotFoundException",
+            GetDocumentationPartError::TooManyRequests(_) => "TooManyRequestsException",
+            GetDocumentationPartError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetDocumentationParts
 #[derive(Debug, PartialEq)]
 pub enum GetDocumentationPartsError {
@@ -6393,6 +6924,16 @@ impl fmt::Display for GetDocumentationPartsError {
     }
 }
 impl Error for GetDocumentationPartsError {}
+impl ServiceErrorCode for GetDocumentationPartsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDocumentationPartsError::BadRequest(_) => "BadRequestException",
+            GetDocumentationPartsError::NotFound(_) => "NotFoundException",
+            GetDocumentationPartsError::TooManyRequests(_) => "TooManyRequestsException",
+            GetDocumentationPartsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetDocumentationVersion
 #[derive(Debug, PartialEq)]
 pub enum GetDocumentationVersionError {
@@ -6439,6 +6980,15 @@ impl fmt::Display for GetDocumentationVersionError {
     }
 }
 impl Error for GetDocumentationVersionError {}
+impl ServiceErrorCode for GetDocumentationVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDocumentationVersionError::NotFound(_) => "NotFoundException",
+            GetDocumentationVersionError::TooManyRequests(_) => "TooManyRequestsException",
+            GetDocumentationVersionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetDocumentationVersions
 #[derive(Debug, PartialEq)]
 pub enum GetDocumentationVersionsError {
@@ -6491,6 +7041,16 @@ impl fmt::Display for GetDocumentationVersionsError {
     }
 }
 impl Error for GetDocumentationVersionsError {}
+impl ServiceErrorCode for GetDocumentationVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDocumentationVersionsError::BadRequest(_) => "BadRequestException",
+            GetDocumentationVersionsError::NotFound(_) => "NotFoundException",
+            GetDocumentationVersionsError::TooManyRequests(_) => "TooManyRequestsException",
+            GetDocumentationVersionsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetDomainName
 #[derive(Debug, PartialEq)]
 pub enum GetDomainNameError {
@@ -6539,6 +7099,16 @@ impl fmt::Display for GetDomainNameError {
     }
 }
 impl Error for GetDomainNameError {}
+impl ServiceErrorCode for GetDomainNameError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDomainNameError::NotFound(_) => "NotFoundException",
+            GetDomainNameError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetDomainNameError::TooManyRequests(_) => "TooManyRequestsException",
+            GetDomainNameError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetDomainNames
 #[derive(Debug, PartialEq)]
 pub enum GetDomainNamesError {
@@ -6581,6 +7151,15 @@ impl fmt::Display for GetDomainNamesError {
     }
 }
 impl Error for GetDomainNamesError {}
+impl ServiceErrorCode for GetDomainNamesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDomainNamesError::BadRequest(_) => "BadRequestException",
+            GetDomainNamesError::TooManyRequests(_) => "TooManyRequestsException",
+            GetDomainNamesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetExport
 #[derive(Debug, PartialEq)]
 pub enum GetExportError {
@@ -6635,6 +7214,17 @@ impl fmt::Display for GetExportError {
     }
 }
 impl Error for GetExportError {}
+impl ServiceErrorCode for GetExportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetExportError::BadRequest(_) => "BadRequestException",
+            GetExportError::Conflict(_) => "ConflictException",
+            GetExportError::NotFound(_) => "NotFoundException",
+            GetExportError::TooManyRequests(_) => "TooManyRequestsException",
+            GetExportError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetGatewayResponse
 #[derive(Debug, PartialEq)]
 pub enum GetGatewayResponseError {
@@ -6677,6 +7267,15 @@ impl fmt::Display for GetGatewayResponseError {
     }
 }
 impl Error for GetGatewayResponseError {}
+impl ServiceErrorCode for GetGatewayResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGatewayResponseError::NotFound(_) => "NotFoundException",
+            GetGatewayResponseError::TooManyRequests(_) => "TooManyRequestsException",
+            GetGatewayResponseError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetGatewayResponses
 #[derive(Debug, PartialEq)]
 pub enum GetGatewayResponsesError {
@@ -6725,6 +7324,16 @@ impl fmt::Display for GetGatewayResponsesError {
     }
 }
 impl Error for GetGatewayResponsesError {}
+impl ServiceErrorCode for GetGatewayResponsesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGatewayResponsesError::BadRequest(_) => "BadRequestException",
+            GetGatewayResponsesError::NotFound(_) => "NotFoundException",
+            GetGatewayResponsesError::TooManyRequests(_) => "TooManyRequestsException",
+            GetGatewayResponsesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetIntegration
 #[derive(Debug, PartialEq)]
 pub enum GetIntegrationError {
@@ -6767,6 +7376,15 @@ impl fmt::Display for GetIntegrationError {
     }
 }
 impl Error for GetIntegrationError {}
+impl ServiceErrorCode for GetIntegrationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIntegrationError::NotFound(_) => "NotFoundException",
+            GetIntegrationError::TooManyRequests(_) => "TooManyRequestsException",
+            GetIntegrationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetIntegrationResponse
 #[derive(Debug, PartialEq)]
 pub enum GetIntegrationResponseError {
@@ -6811,6 +7429,15 @@ impl fmt::Display for GetIntegrationResponseError {
     }
 }
 impl Error for GetIntegrationResponseError {}
+impl ServiceErrorCode for GetIntegrationResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIntegrationResponseError::NotFound(_) => "NotFoundException",
+            GetIntegrationResponseError::TooManyRequests(_) => "TooManyRequestsException",
+            GetIntegrationResponseError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetMethod
 #[derive(Debug, PartialEq)]
 pub enum GetMethodError {
@@ -6853,6 +7480,15 @@ impl fmt::Display for GetMethodError {
     }
 }
 impl Error for GetMethodError {}
+impl ServiceErrorCode for GetMethodError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMethodError::NotFound(_) => "NotFoundException",
+            GetMethodError::TooManyRequests(_) => "TooManyRequestsException",
+            GetMethodError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetMethodResponse
 #[derive(Debug, PartialEq)]
 pub enum GetMethodResponseError {
@@ -6895,6 +7531,15 @@ impl fmt::Display for GetMethodResponseError {
     }
 }
 impl Error for GetMethodResponseError {}
+impl ServiceErrorCode for GetMethodResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMethodResponseError::NotFound(_) => "NotFoundException",
+            GetMethodResponseError::TooManyRequests(_) => "TooManyRequestsException",
+            GetMethodResponseError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetModel
 #[derive(Debug, PartialEq)]
 pub enum GetModelError {
@@ -6937,6 +7582,15 @@ impl fmt::Display for GetModelError {
     }
 }
 impl Error for GetModelError {}
+impl ServiceErrorCode for GetModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetModelError::NotFound(_) => "NotFoundException",
+            GetModelError::TooManyRequests(_) => "TooManyRequestsException",
+            GetModelError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetModelTemplate
 #[derive(Debug, PartialEq)]
 pub enum GetModelTemplateError {
@@ -6985,6 +7639,16 @@ impl fmt::Display for GetModelTemplateError {
     }
 }
 impl Error for GetModelTemplateError {}
+impl ServiceErrorCode for GetModelTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetModelTemplateError::BadRequest(_) => "BadRequestException",
+            GetModelTemplateError::NotFound(_) => "NotFoundException",
+            GetModelTemplateError::TooManyRequests(_) => "TooManyRequestsException",
+            GetModelTemplateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetModels
 #[derive(Debug, PartialEq)]
 pub enum GetModelsError {
@@ -7033,6 +7697,16 @@ impl fmt::Display for GetModelsError {
     }
 }
 impl Error for GetModelsError {}
+impl ServiceErrorCode for GetModelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetModelsError::BadRequest(_) => "BadRequestException",
+            GetModelsError::NotFound(_) => "NotFoundException",
+            GetModelsError::TooManyRequests(_) => "TooManyRequestsException",
+            GetModelsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetRequestValidator
 #[derive(Debug, PartialEq)]
 pub enum GetRequestValidatorError {
@@ -7075,6 +7749,15 @@ impl fmt::Display for GetRequestValidatorError {
     }
 }
 impl Error for GetRequestValidatorError {}
+impl ServiceErrorCode for GetRequestValidatorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRequestValidatorError::NotFound(_) => "NotFoundException",
+            GetRequestValidatorError::TooManyRequests(_) => "TooManyRequestsException",
+            GetRequestValidatorError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetRequestValidators
 #[derive(Debug, PartialEq)]
 pub enum GetRequestValidatorsError {
@@ -7125,6 +7808,16 @@ impl fmt::Display for GetRequestValidatorsError {
     }
 }
 impl Error for GetRequestValidatorsError {}
+impl ServiceErrorCode for GetRequestValidatorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRequestValidatorsError::BadRequest(_) => "BadRequestException",
+            GetRequestValidatorsError::NotFound(_) => "NotFoundException",
+            GetRequestValidatorsError::TooManyRequests(_) => "TooManyRequestsException",
+            GetRequestValidatorsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetResource
 #[derive(Debug, PartialEq)]
 pub enum GetResourceError {
@@ -7167,6 +7860,15 @@ impl fmt::Display for GetResourceError {
     }
 }
 impl Error for GetResourceError {}
+impl ServiceErrorCode for GetResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourceError::NotFound(_) => "NotFoundException",
+            GetResourceError::TooManyRequests(_) => "TooManyRequestsException",
+            GetResourceError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetResources
 #[derive(Debug, PartialEq)]
 pub enum GetResourcesError {
@@ -7215,6 +7917,16 @@ impl fmt::Display for GetResourcesError {
     }
 }
 impl Error for GetResourcesError {}
+impl ServiceErrorCode for GetResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourcesError::BadRequest(_) => "BadRequestException",
+            GetResourcesError::NotFound(_) => "NotFoundException",
+            GetResourcesError::TooManyRequests(_) => "TooManyRequestsException",
+            GetResourcesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetRestApi
 #[derive(Debug, PartialEq)]
 pub enum GetRestApiError {
@@ -7257,6 +7969,15 @@ impl fmt::Display for GetRestApiError {
     }
 }
 impl Error for GetRestApiError {}
+impl ServiceErrorCode for GetRestApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRestApiError::NotFound(_) => "NotFoundException",
+            GetRestApiError::TooManyRequests(_) => "TooManyRequestsException",
+            GetRestApiError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetRestApis
 #[derive(Debug, PartialEq)]
 pub enum GetRestApisError {
@@ -7299,6 +8020,15 @@ impl fmt::Display for GetRestApisError {
     }
 }
 impl Error for GetRestApisError {}
+impl ServiceErrorCode for GetRestApisError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRestApisError::BadRequest(_) => "BadRequestException",
+            GetRestApisError::TooManyRequests(_) => "TooManyRequestsException",
+            GetRestApisError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetSdk
 #[derive(Debug, PartialEq)]
 pub enum GetSdkError {
@@ -7349,6 +8079,17 @@ impl fmt::Display for GetSdkError {
     }
 }
 impl Error for GetSdkError {}
+impl ServiceErrorCode for GetSdkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSdkError::BadRequest(_) => "BadRequestException",
+            GetSdkError::Conflict(_) => "ConflictException",
+            GetSdkError::NotFound(_) => "NotFoundException",
+            GetSdkError::TooManyRequests(_) => "TooManyRequestsException",
+            GetSdkError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetSdkType
 #[derive(Debug, PartialEq)]
 pub enum GetSdkTypeError {
@@ -7391,6 +8132,15 @@ impl fmt::Display for GetSdkTypeError {
     }
 }
 impl Error for GetSdkTypeError {}
+impl ServiceErrorCode for GetSdkTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSdkTypeError::NotFound(_) => "NotFoundException",
+            GetSdkTypeError::TooManyRequests(_) => "TooManyRequestsException",
+            GetSdkTypeError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetSdkTypes
 #[derive(Debug, PartialEq)]
 pub enum GetSdkTypesError {
@@ -7427,6 +8177,14 @@ impl fmt::Display for GetSdkTypesError {
     }
 }
 impl Error for GetSdkTypesError {}
+impl ServiceErrorCode for GetSdkTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSdkTypesError::TooManyRequests(_) => "TooManyRequestsException",
+            GetSdkTypesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetStage
 #[derive(Debug, PartialEq)]
 pub enum GetStageError {
@@ -7469,6 +8227,15 @@ impl fmt::Display for GetStageError {
     }
 }
 impl Error for GetStageError {}
+impl ServiceErrorCode for GetStageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetStageError::NotFound(_) => "NotFoundException",
+            GetStageError::TooManyRequests(_) => "TooManyRequestsException",
+            GetStageError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetStages
 #[derive(Debug, PartialEq)]
 pub enum GetStagesError {
@@ -7511,6 +8278,15 @@ impl fmt::Display for GetStagesError {
     }
 }
 impl Error for GetStagesError {}
+impl ServiceErrorCode for GetStagesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetStagesError::NotFound(_) => "NotFoundException",
+            GetStagesError::TooManyRequests(_) => "TooManyRequestsException",
+            GetStagesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetTags
 #[derive(Debug, PartialEq)]
 pub enum GetTagsError {
@@ -7565,6 +8341,17 @@ impl fmt::Display for GetTagsError {
     }
 }
 impl Error for GetTagsError {}
+impl ServiceErrorCode for GetTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTagsError::BadRequest(_) => "BadRequestException",
+            GetTagsError::LimitExceeded(_) => "LimitExceededException",
+            GetTagsError::NotFound(_) => "NotFoundException",
+            GetTagsError::TooManyRequests(_) => "TooManyRequestsException",
+            GetTagsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetUsage
 #[derive(Debug, PartialEq)]
 pub enum GetUsageError {
@@ -7613,6 +8400,16 @@ impl fmt::Display for GetUsageError {
     }
 }
 impl Error for GetUsageError {}
+impl ServiceErrorCode for GetUsageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUsageError::BadRequest(_) => "BadRequestException",
+            GetUsageError::NotFound(_) => "NotFoundException",
+            GetUsageError::TooManyRequests(_) => "TooManyRequestsException",
+            GetUsageError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetUsagePlan
 #[derive(Debug, PartialEq)]
 pub enum GetUsagePlanError {
@@ -7661,6 +8458,16 @@ impl fmt::Display for GetUsagePlanError {
     }
 }
 impl Error for GetUsagePlanError {}
+impl ServiceErrorCode for GetUsagePlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUsagePlanError::BadRequest(_) => "BadRequestException",
+            GetUsagePlanError::NotFound(_) => "NotFoundException",
+            GetUsagePlanError::TooManyRequests(_) => "TooManyRequestsException",
+            GetUsagePlanError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetUsagePlanKey
 #[derive(Debug, PartialEq)]
 pub enum GetUsagePlanKeyError {
@@ -7709,6 +8516,16 @@ impl fmt::Display for GetUsagePlanKeyError {
     }
 }
 impl Error for GetUsagePlanKeyError {}
+impl ServiceErrorCode for GetUsagePlanKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUsagePlanKeyError::BadRequest(_) => "BadRequestException",
+            GetUsagePlanKeyError::NotFound(_) => "NotFoundException",
+            GetUsagePlanKeyError::TooManyRequests(_) => "TooManyRequestsException",
+            GetUsagePlanKeyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetUsagePlanKeys
 #[derive(Debug, PartialEq)]
 pub enum GetUsagePlanKeysError {
@@ -7757,6 +8574,16 @@ impl fmt::Display for GetUsagePlanKeysError {
     }
 }
 impl Error for GetUsagePlanKeysError {}
+impl ServiceErrorCode for GetUsagePlanKeysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUsagePlanKeysError::BadRequest(_) => "BadRequestException",
+            GetUsagePlanKeysError::NotFound(_) => "NotFoundException",
+            GetUsagePlanKeysError::TooManyRequests(_) => "TooManyRequestsException",
+            GetUsagePlanKeysError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetUsagePlans
 #[derive(Debug, PartialEq)]
 pub enum GetUsagePlansError {
@@ -7811,6 +8638,17 @@ impl fmt::Display for GetUsagePlansError {
     }
 }
 impl Error for GetUsagePlansError {}
+impl ServiceErrorCode for GetUsagePlansError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUsagePlansError::BadRequest(_) => "BadRequestException",
+            GetUsagePlansError::Conflict(_) => "ConflictException",
+            GetUsagePlansError::NotFound(_) => "NotFoundException",
+            GetUsagePlansError::TooManyRequests(_) => "TooManyRequestsException",
+            GetUsagePlansError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetVpcLink
 #[derive(Debug, PartialEq)]
 pub enum GetVpcLinkError {
@@ -7853,6 +8691,15 @@ impl fmt::Display for GetVpcLinkError {
     }
 }
 impl Error for GetVpcLinkError {}
+impl ServiceErrorCode for GetVpcLinkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVpcLinkError::NotFound(_) => "NotFoundException",
+            GetVpcLinkError::TooManyRequests(_) => "TooManyRequestsException",
+            GetVpcLinkError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetVpcLinks
 #[derive(Debug, PartialEq)]
 pub enum GetVpcLinksError {
@@ -7895,6 +8742,15 @@ impl fmt::Display for GetVpcLinksError {
     }
 }
 impl Error for GetVpcLinksError {}
+impl ServiceErrorCode for GetVpcLinksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVpcLinksError::BadRequest(_) => "BadRequestException",
+            GetVpcLinksError::TooManyRequests(_) => "TooManyRequestsException",
+            GetVpcLinksError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ImportApiKeys
 #[derive(Debug, PartialEq)]
 pub enum ImportApiKeysError {
@@ -7955,6 +8811,18 @@ impl fmt::Display for ImportApiKeysError {
     }
 }
 impl Error for ImportApiKeysError {}
+impl ServiceErrorCode for ImportApiKeysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportApiKeysError::BadRequest(_) => "BadRequestException",
+            ImportApiKeysError::Conflict(_) => "ConflictException",
+            ImportApiKeysError::LimitExceeded(_) => "LimitExceededException",
+            ImportApiKeysError::NotFound(_) => "NotFoundException",
+            ImportApiKeysError::TooManyRequests(_) => "TooManyRequestsException",
+            ImportApiKeysError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ImportDocumentationParts
 #[derive(Debug, PartialEq)]
 pub enum ImportDocumentationPartsError {
@@ -8015,6 +8883,17 @@ impl fmt::Display for ImportDocumentationPartsError {
     }
 }
 impl Error for ImportDocumentationPartsError {}
+impl ServiceErrorCode for ImportDocumentationPartsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportDocumentationPartsError::BadRequest(_) => "BadRequestException",
+            ImportDocumentationPartsError::LimitExceeded(_) => "LimitExceededException",
+            ImportDocumentationPartsError::NotFound(_) => "NotFoundException",
+            ImportDocumentationPartsError::TooManyRequests(_) => "TooManyRequestsException",
+            ImportDocumentationPartsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ImportRestApi
 #[derive(Debug, PartialEq)]
 pub enum ImportRestApiError {
@@ -8069,6 +8948,17 @@ impl fmt::Display for ImportRestApiError {
     }
 }
 impl Error for ImportRestApiError {}
+impl ServiceErrorCode for ImportRestApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportRestApiError::BadRequest(_) => "BadRequestException",
+            ImportRestApiError::Conflict(_) => "ConflictException",
+            ImportRestApiError::LimitExceeded(_) => "LimitExceededException",
+            ImportRestApiError::TooManyRequests(_) => "TooManyRequestsException",
+            ImportRestApiError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by PutGatewayResponse
 #[derive(Debug, PartialEq)]
 pub enum PutGatewayResponseError {
@@ -8123,6 +9013,17 @@ impl fmt::Display for PutGatewayResponseError {
     }
 }
 impl Error for PutGatewayResponseError {}
+impl ServiceErrorCode for PutGatewayResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutGatewayResponseError::BadRequest(_) => "BadRequestException",
+            PutGatewayResponseError::LimitExceeded(_) => "LimitExceededException",
+            PutGatewayResponseError::NotFound(_) => "NotFoundException",
+            PutGatewayResponseError::TooManyRequests(_) => "TooManyRequestsException",
+            PutGatewayResponseError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by PutIntegration
 #[derive(Debug, PartialEq)]
 pub enum PutIntegrationError {
@@ -8177,6 +9078,17 @@ impl fmt::Display for PutIntegrationError {
     }
 }
 impl Error for PutIntegrationError {}
+impl ServiceErrorCode for PutIntegrationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutIntegrationError::BadRequest(_) => "BadRequestException",
+            PutIntegrationError::Conflict(_) => "ConflictException",
+            PutIntegrationError::NotFound(_) => "NotFoundException",
+            PutIntegrationError::TooManyRequests(_) => "TooManyRequestsException",
+            PutIntegrationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by PutIntegrationResponse
 #[derive(Debug, PartialEq)]
 pub enum PutIntegrationResponseError {
@@ -8241,6 +9153,18 @@ impl fmt::Display for PutIntegrationResponseError {
     }
 }
 impl Error for PutIntegrationResponseError {}
+impl ServiceErrorCode for PutIntegrationResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutIntegrationResponseError::BadRequest(_) => "BadRequestException",
+            PutIntegrationResponseError::Conflict(_) => "ConflictException",
+            PutIntegrationResponseError::LimitExceeded(_) => "LimitExceededException",
+            PutIntegrationResponseError::NotFound(_) => "NotFoundException",
+            PutIntegrationResponseError::TooManyRequests(_) => "TooManyRequestsException",
+            PutIntegrationResponseError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by PutMethod
 #[derive(Debug, PartialEq)]
 pub enum PutMethodError {
@@ -8301,6 +9225,18 @@ impl fmt::Display for PutMethodError {
     }
 }
 impl Error for PutMethodError {}
+impl ServiceErrorCode for PutMethodError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutMethodError::BadRequest(_) => "BadRequestException",
+            PutMethodError::Conflict(_) => "ConflictException",
+            PutMethodError::LimitExceeded(_) => "LimitExceededException",
+            PutMethodError::NotFound(_) => "NotFoundException",
+            PutMethodError::TooManyRequests(_) => "TooManyRequestsException",
+            PutMethodError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by PutMethodResponse
 #[derive(Debug, PartialEq)]
 pub enum PutMethodResponseError {
@@ -8361,6 +9297,18 @@ impl fmt::Display for PutMethodResponseError {
     }
 }
 impl Error for PutMethodResponseError {}
+impl ServiceErrorCode for PutMethodResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutMethodResponseError::BadRequest(_) => "BadRequestException",
+            PutMethodResponseError::Conflict(_) => "ConflictException",
+            PutMethodResponseError::LimitExceeded(_) => "LimitExceededException",
+            PutMethodResponseError::NotFound(_) => "NotFoundException",
+            PutMethodResponseError::TooManyRequests(_) => "TooManyRequestsException",
+            PutMethodResponseError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by PutRestApi
 #[derive(Debug, PartialEq)]
 pub enum PutRestApiError {
@@ -8421,6 +9369,18 @@ impl fmt::Display for PutRestApiError {
     }
 }
 impl Error for PutRestApiError {}
+impl ServiceErrorCode for PutRestApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRestApiError::BadRequest(_) => "BadRequestException",
+            PutRestApiError::Conflict(_) => "ConflictException",
+            PutRestApiError::LimitExceeded(_) => "LimitExceededException",
+            PutRestApiError::NotFound(_) => "NotFoundException",
+            PutRestApiError::TooManyRequests(_) => "TooManyRequestsException",
+            PutRestApiError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -8481,6 +9441,18 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::Conflict(_) => "ConflictException",
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+            TagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+            TagResourceError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by TestInvokeAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum TestInvokeAuthorizerError {
@@ -8531,6 +9503,16 @@ impl fmt::Display for TestInvokeAuthorizerError {
     }
 }
 impl Error for TestInvokeAuthorizerError {}
+impl ServiceErrorCode for TestInvokeAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TestInvokeAuthorizerError::BadRequest(_) => "BadRequestException",
+            TestInvokeAuthorizerError::NotFound(_) => "NotFoundException",
+            TestInvokeAuthorizerError::TooManyRequests(_) => "TooManyRequestsException",
+            TestInvokeAuthorizerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by TestInvokeMethod
 #[derive(Debug, PartialEq)]
 pub enum TestInvokeMethodError {
@@ -8579,6 +9561,16 @@ impl fmt::Display for TestInvokeMethodError {
     }
 }
 impl Error for TestInvokeMethodError {}
+impl ServiceErrorCode for TestInvokeMethodError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TestInvokeMethodError::BadRequest(_) => "BadRequestException",
+            TestInvokeMethodError::NotFound(_) => "NotFoundException",
+            TestInvokeMethodError::TooManyRequests(_) => "TooManyRequestsException",
+            TestInvokeMethodError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -8633,6 +9625,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::Conflict(_) => "ConflictException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+            UntagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+            UntagResourceError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateAccount
 #[derive(Debug, PartialEq)]
 pub enum UpdateAccountError {
@@ -8681,6 +9684,16 @@ impl fmt::Display for UpdateAccountError {
     }
 }
 impl Error for UpdateAccountError {}
+impl ServiceErrorCode for UpdateAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAccountError::BadRequest(_) => "BadRequestException",
+            UpdateAccountError::NotFound(_) => "NotFoundException",
+            UpdateAccountError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateAccountError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateApiKey
 #[derive(Debug, PartialEq)]
 pub enum UpdateApiKeyError {
@@ -8735,6 +9748,17 @@ impl fmt::Display for UpdateApiKeyError {
     }
 }
 impl Error for UpdateApiKeyError {}
+impl ServiceErrorCode for UpdateApiKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApiKeyError::BadRequest(_) => "BadRequestException",
+            UpdateApiKeyError::Conflict(_) => "ConflictException",
+            UpdateApiKeyError::NotFound(_) => "NotFoundException",
+            UpdateApiKeyError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateApiKeyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum UpdateAuthorizerError {
@@ -8783,6 +9807,16 @@ impl fmt::Display for UpdateAuthorizerError {
     }
 }
 impl Error for UpdateAuthorizerError {}
+impl ServiceErrorCode for UpdateAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAuthorizerError::BadRequest(_) => "BadRequestException",
+            UpdateAuthorizerError::NotFound(_) => "NotFoundException",
+            UpdateAuthorizerError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateAuthorizerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateBasePathMapping
 #[derive(Debug, PartialEq)]
 pub enum UpdateBasePathMappingError {
@@ -8839,6 +9873,17 @@ impl fmt::Display for UpdateBasePathMappingError {
     }
 }
 impl Error for UpdateBasePathMappingError {}
+impl ServiceErrorCode for UpdateBasePathMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateBasePathMappingError::BadRequest(_) => "BadRequestException",
+            UpdateBasePathMappingError::Conflict(_) => "ConflictException",
+            UpdateBasePathMappingError::NotFound(_) => "NotFoundException",
+            UpdateBasePathMappingError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateBasePathMappingError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateClientCertificate
 #[derive(Debug, PartialEq)]
 pub enum UpdateClientCertificateError {
@@ -8891,6 +9936,16 @@ impl fmt::Display for UpdateClientCertificateError {
     }
 }
 impl Error for UpdateClientCertificateError {}
+impl ServiceErrorCode for UpdateClientCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateClientCertificateError::BadRequest(_) => "BadRequestException",
+            UpdateClientCertificateError::NotFound(_) => "NotFoundException",
+            UpdateClientCertificateError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateClientCertificateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateDeployment
 #[derive(Debug, PartialEq)]
 pub enum UpdateDeploymentError {
@@ -8945,6 +10000,17 @@ impl fmt::Display for UpdateDeploymentError {
     }
 }
 impl Error for UpdateDeploymentError {}
+impl ServiceErrorCode for UpdateDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDeploymentError::BadRequest(_) => "BadRequestException",
+            UpdateDeploymentError::NotFound(_) => "NotFoundException",
+            UpdateDeploymentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateDeploymentError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateDeploymentError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateDocumentationPart
 #[derive(Debug, PartialEq)]
 pub enum UpdateDocumentationPartError {
@@ -9011,6 +10077,18 @@ impl fmt::Display for UpdateDocumentationPartError {
     }
 }
 impl Error for UpdateDocumentationPartError {}
+impl ServiceErrorCode for UpdateDocumentationPartError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDocumentationPartError::BadRequest(_) => "BadRequestException",
+            UpdateDocumentationPartError::Conflict(_) => "ConflictException",
+            UpdateDocumentationPartError::LimitExceeded(_) => "LimitExceededException",
+            UpdateDocumentationPartError::NotFound(_) => "NotFoundException",
+            UpdateDocumentationPartError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateDocumentationPartError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateDocumentationVersion
 #[derive(Debug, PartialEq)]
 pub enum UpdateDocumentationVersionError {
@@ -9073,6 +10151,17 @@ impl fmt::Display for UpdateDocumentationVersionError {
     }
 }
 impl Error for UpdateDocumentationVersionError {}
+impl ServiceErrorCode for UpdateDocumentationVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDocumentationVersionError::BadRequest(_) => "BadRequestException",
+            UpdateDocumentationVersionError::Conflict(_) => "ConflictException",
+            UpdateDocumentationVersionError::NotFound(_) => "NotFoundException",
+            UpdateDocumentationVersionError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateDocumentationVersionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateDomainName
 #[derive(Debug, PartialEq)]
 pub enum UpdateDomainNameError {
@@ -9127,6 +10216,17 @@ impl fmt::Display for UpdateDomainNameError {
     }
 }
 impl Error for UpdateDomainNameError {}
+impl ServiceErrorCode for UpdateDomainNameError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDomainNameError::BadRequest(_) => "BadRequestException",
+            UpdateDomainNameError::Conflict(_) => "ConflictException",
+            UpdateDomainNameError::NotFound(_) => "NotFoundException",
+            UpdateDomainNameError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateDomainNameError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateGatewayResponse
 #[derive(Debug, PartialEq)]
 pub enum UpdateGatewayResponseError {
@@ -9177,6 +10277,16 @@ impl fmt::Display for UpdateGatewayResponseError {
     }
 }
 impl Error for UpdateGatewayResponseError {}
+impl ServiceErrorCode for UpdateGatewayResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGatewayResponseError::BadRequest(_) => "BadRequestException",
+            UpdateGatewayResponseError::NotFound(_) => "NotFoundException",
+            UpdateGatewayResponseError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateGatewayResponseError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateIntegration
 #[derive(Debug, PartialEq)]
 pub enum UpdateIntegrationError {
@@ -9231,6 +10341,17 @@ impl fmt::Display for UpdateIntegrationError {
     }
 }
 impl Error for UpdateIntegrationError {}
+impl ServiceErrorCode for UpdateIntegrationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateIntegrationError::BadRequest(_) => "BadRequestException",
+            UpdateIntegrationError::Conflict(_) => "ConflictException",
+            UpdateIntegrationError::NotFound(_) => "NotFoundException",
+            UpdateIntegrationError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateIntegrationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateIntegrationResponse
 #[derive(Debug, PartialEq)]
 pub enum UpdateIntegrationResponseError {
@@ -9291,6 +10412,17 @@ impl fmt::Display for UpdateIntegrationResponseError {
     }
 }
 impl Error for UpdateIntegrationResponseError {}
+impl ServiceErrorCode for UpdateIntegrationResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateIntegrationResponseError::BadRequest(_) => "BadRequestException",
+            UpdateIntegrationResponseError::Conflict(_) => "ConflictException",
+            UpdateIntegrationResponseError::NotFound(_) => "NotFoundException",
+            UpdateIntegrationResponseError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateIntegrationResponseError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateMethod
 #[derive(Debug, PartialEq)]
 pub enum UpdateMethodError {
@@ -9345,6 +10477,17 @@ impl fmt::Display for UpdateMethodError {
     }
 }
 impl Error for UpdateMethodError {}
+impl ServiceErrorCode for UpdateMethodError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMethodError::BadRequest(_) => "BadRequestException",
+            UpdateMethodError::Conflict(_) => "ConflictException",
+            UpdateMethodError::NotFound(_) => "NotFoundException",
+            UpdateMethodError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateMethodError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateMethodResponse
 #[derive(Debug, PartialEq)]
 pub enum UpdateMethodResponseError {
@@ -9407,6 +10550,18 @@ impl fmt::Display for UpdateMethodResponseError {
     }
 }
 impl Error for UpdateMethodResponseError {}
+impl ServiceErrorCode for UpdateMethodResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMethodResponseError::BadRequest(_) => "BadRequestException",
+            UpdateMethodResponseError::Conflict(_) => "ConflictException",
+            UpdateMethodResponseError::LimitExceeded(_) => "LimitExceededException",
+            UpdateMethodResponseError::NotFound(_) => "NotFoundException",
+            UpdateMethodResponseError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateMethodResponseError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateModel
 #[derive(Debug, PartialEq)]
 pub enum UpdateModelError {
@@ -9461,6 +10616,17 @@ impl fmt::Display for UpdateModelError {
     }
 }
 impl Error for UpdateModelError {}
+impl ServiceErrorCode for UpdateModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateModelError::BadRequest(_) => "BadRequestException",
+            UpdateModelError::Conflict(_) => "ConflictException",
+            UpdateModelError::NotFound(_) => "NotFoundException",
+            UpdateModelError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateModelError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateRequestValidator
 #[derive(Debug, PartialEq)]
 pub enum UpdateRequestValidatorError {
@@ -9511,6 +10677,16 @@ impl fmt::Display for UpdateRequestValidatorError {
     }
 }
 impl Error for UpdateRequestValidatorError {}
+impl ServiceErrorCode for UpdateRequestValidatorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRequestValidatorError::BadRequest(_) => "BadRequestException",
+            UpdateRequestValidatorError::NotFound(_) => "NotFoundException",
+            UpdateRequestValidatorError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateRequestValidatorError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateResource
 #[derive(Debug, PartialEq)]
 pub enum UpdateResourceError {
@@ -9565,6 +10741,17 @@ impl fmt::Display for UpdateResourceError {
     }
 }
 impl Error for UpdateResourceError {}
+impl ServiceErrorCode for UpdateResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateResourceError::BadRequest(_) => "BadRequestException",
+            UpdateResourceError::Conflict(_) => "ConflictException",
+            UpdateResourceError::NotFound(_) => "NotFoundException",
+            UpdateResourceError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateResourceError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateRestApi
 #[derive(Debug, PartialEq)]
 pub enum UpdateRestApiError {
@@ -9619,6 +10806,17 @@ impl fmt::Display for UpdateRestApiError {
     }
 }
 impl Error for UpdateRestApiError {}
+impl ServiceErrorCode for UpdateRestApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRestApiError::BadRequest(_) => "BadRequestException",
+            UpdateRestApiError::Conflict(_) => "ConflictException",
+            UpdateRestApiError::NotFound(_) => "NotFoundException",
+            UpdateRestApiError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateRestApiError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateStage
 #[derive(Debug, PartialEq)]
 pub enum UpdateStageError {
@@ -9673,6 +10871,17 @@ impl fmt::Display for UpdateStageError {
     }
 }
 impl Error for UpdateStageError {}
+impl ServiceErrorCode for UpdateStageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateStageError::BadRequest(_) => "BadRequestException",
+            UpdateStageError::Conflict(_) => "ConflictException",
+            UpdateStageError::NotFound(_) => "NotFoundException",
+            UpdateStageError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateStageError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateUsage
 #[derive(Debug, PartialEq)]
 pub enum UpdateUsageError {
@@ -9721,6 +10930,16 @@ impl fmt::Display for UpdateUsageError {
     }
 }
 impl Error for UpdateUsageError {}
+impl ServiceErrorCode for UpdateUsageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUsageError::BadRequest(_) => "BadRequestException",
+            UpdateUsageError::NotFound(_) => "NotFoundException",
+            UpdateUsageError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateUsageError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateUsagePlan
 #[derive(Debug, PartialEq)]
 pub enum UpdateUsagePlanError {
@@ -9775,6 +10994,17 @@ impl fmt::Display for UpdateUsagePlanError {
     }
 }
 impl Error for UpdateUsagePlanError {}
+impl ServiceErrorCode for UpdateUsagePlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUsagePlanError::BadRequest(_) => "BadRequestException",
+            UpdateUsagePlanError::Conflict(_) => "ConflictException",
+            UpdateUsagePlanError::NotFound(_) => "NotFoundException",
+            UpdateUsagePlanError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateUsagePlanError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateVpcLink
 #[derive(Debug, PartialEq)]
 pub enum UpdateVpcLinkError {
@@ -9829,6 +11059,17 @@ impl fmt::Display for UpdateVpcLinkError {
     }
 }
 impl Error for UpdateVpcLinkError {}
+impl ServiceErrorCode for UpdateVpcLinkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateVpcLinkError::BadRequest(_) => "BadRequestException",
+            UpdateVpcLinkError::Conflict(_) => "ConflictException",
+            UpdateVpcLinkError::NotFound(_) => "NotFoundException",
+            UpdateVpcLinkError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateVpcLinkError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon API Gateway API. Amazon API Gateway clients implement this trait.
 #[async_trait]
 pub trait ApiGateway {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -122,6 +122,15 @@ impl fmt::Display for DeleteConnectionError {
     }
 }
 impl Error for DeleteConnectionError {}
+impl ServiceErrorCode for DeleteConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConnectionError::Forbidden(_) => "ForbiddenException",
+            DeleteConnectionError::Gone(_) => "GoneException",
+            DeleteConnectionError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetConnection
 #[derive(Debug, PartialEq)]
 pub enum GetConnectionError {
@@ -162,6 +171,15 @@ impl fmt::Display for GetConnectionError {
     }
 }
 impl Error for GetConnectionError {}
+impl ServiceErrorCode for GetConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConnectionError::Forbidden(_) => "ForbiddenException",
+            GetConnectionError::Gone(_) => "GoneException",
+            GetConnectionError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by PostToConnection
 #[derive(Debug, PartialEq)]
 pub enum PostToConnectionError {
@@ -210,6 +228,16 @@ impl fmt::Display for PostToConnectionError {
     }
 }
 impl Error for PostToConnectionError {}
+impl ServiceErrorCode for PostToConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PostToConnectionError::Forbidden(_) => "ForbiddenException",
+            PostToConnectionError::Gone(_) => "GoneException",
+            PostToConnectionError::LimitExceeded(_) => "LimitExceededException",
+            PostToConnectionError::PayloadTooLarge(_) => "PayloadTooLargeException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AmazonApiGatewayManagementApi API. AmazonApiGatewayManagementApi clients implement this trait.
 #[async_trait]
 pub trait ApiGatewayManagementApi {
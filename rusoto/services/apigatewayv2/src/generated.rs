@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -4128,6 +4128,16 @@ impl fmt::Display for CreateApiError {
     }
 }
 impl Error for CreateApiError {}
+impl ServiceErrorCode for CreateApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApiError::BadRequest(_) => "BadRequestException",
+            CreateApiError::Conflict(_) => "ConflictException",
+            CreateApiError::NotFound(_) => "NotFoundException",
+            CreateApiError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateApiMapping
 #[derive(Debug, PartialEq)]
 pub enum CreateApiMappingError {
@@ -4176,6 +4186,16 @@ impl fmt::Display for CreateApiMappingError {
     }
 }
 impl Error for CreateApiMappingError {}
+impl ServiceErrorCode for CreateApiMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApiMappingError::BadRequest(_) => "BadRequestException",
+            CreateApiMappingError::Conflict(_) => "ConflictException",
+            CreateApiMappingError::NotFound(_) => "NotFoundException",
+            CreateApiMappingError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum CreateAuthorizerError {
@@ -4224,6 +4244,16 @@ impl fmt::Display for CreateAuthorizerError {
     }
 }
 impl Error for CreateAuthorizerError {}
+impl ServiceErrorCode for CreateAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAuthorizerError::BadRequest(_) => "BadRequestException",
+            CreateAuthorizerError::Conflict(_) => "ConflictException",
+            CreateAuthorizerError::NotFound(_) => "NotFoundException",
+            CreateAuthorizerError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateDeployment
 #[derive(Debug, PartialEq)]
 pub enum CreateDeploymentError {
@@ -4272,6 +4302,16 @@ impl fmt::Display for CreateDeploymentError {
     }
 }
 impl Error for CreateDeploymentError {}
+impl ServiceErrorCode for CreateDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeploymentError::BadRequest(_) => "BadRequestException",
+            CreateDeploymentError::Conflict(_) => "ConflictException",
+            CreateDeploymentError::NotFound(_) => "NotFoundException",
+            CreateDeploymentError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateDomainName
 #[derive(Debug, PartialEq)]
 pub enum CreateDomainNameError {
@@ -4325,6 +4365,17 @@ impl fmt::Display for CreateDomainNameError {
     }
 }
 impl Error for CreateDomainNameError {}
+impl ServiceErrorCode for CreateDomainNameError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDomainNameError::AccessDenied(_) => "AccessDeniedException",
+            CreateDomainNameError::BadRequest(_) => "BadRequestException",
+            CreateDomainNameError::Conflict(_) => "ConflictException",
+            CreateDomainNameError::NotFound(_) => "NotFoundException",
+            CreateDomainNameError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateIntegration
 #[derive(Debug, PartialEq)]
 pub enum CreateIntegrationError {
@@ -4373,6 +4424,16 @@ impl fmt::Display for CreateIntegrationError {
     }
 }
 impl Error for CreateIntegrationError {}
+impl ServiceErrorCode for CreateIntegrationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateIntegrationError::BadRequest(_) => "BadRequestException",
+            CreateIntegrationError::Conflict(_) => "ConflictException",
+            CreateIntegrationError::NotFound(_) => "NotFoundException",
+            CreateIntegrationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateIntegrationResponse
 #[derive(Debug, PartialEq)]
 pub enum CreateIntegrationResponseError {
@@ -4425,6 +4486,16 @@ impl fmt::Display for CreateIntegrationResponseError {
     }
 }
 impl Error for CreateIntegrationResponseError {}
+impl ServiceErrorCode for CreateIntegrationResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateIntegrationResponseError::BadRequest(_) => "BadRequestException",
+            CreateIntegrationResponseError::Conflict(_) => "ConflictException",
+            CreateIntegrationResponseError::NotFound(_) => "NotFoundException",
+            CreateIntegrationResponseError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateModel
 #[derive(Debug, PartialEq)]
 pub enum CreateModelError {
@@ -4473,6 +4544,16 @@ impl fmt::Display for CreateModelError {
     }
 }
 impl Error for CreateModelError {}
+impl ServiceErrorCode for CreateModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateModelError::BadRequest(_) => "BadRequestException",
+            CreateModelError::Conflict(_) => "ConflictException",
+            CreateModelError::NotFound(_) => "NotFoundException",
+            CreateModelError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateRoute
 #[derive(Debug, PartialEq)]
 pub enum CreateRouteError {
@@ -4521,6 +4602,16 @@ impl fmt::Display for CreateRouteError {
     }
 }
 impl Error for CreateRouteError {}
+impl ServiceErrorCode for CreateRouteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRouteError::BadRequest(_) => "BadRequestException",
+            CreateRouteError::Conflict(_) => "ConflictException",
+            CreateRouteError::NotFound(_) => "NotFoundException",
+            CreateRouteError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateRouteResponse
 #[derive(Debug, PartialEq)]
 pub enum CreateRouteResponseError {
@@ -4569,6 +4660,16 @@ impl fmt::Display for CreateRouteResponseError {
     }
 }
 impl Error for CreateRouteResponseError {}
+impl ServiceErrorCode for CreateRouteResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRouteResponseError::BadRequest(_) => "BadRequestException",
+            CreateRouteResponseError::Conflict(_) => "ConflictException",
+            CreateRouteResponseError::NotFound(_) => "NotFoundException",
+            CreateRouteResponseError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateStage
 #[derive(Debug, PartialEq)]
 pub enum CreateStageError {
@@ -4617,6 +4718,16 @@ impl fmt::Display for CreateStageError {
     }
 }
 impl Error for CreateStageError {}
+impl ServiceErrorCode for CreateStageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStageError::BadRequest(_) => "BadRequestException",
+            CreateStageError::Conflict(_) => "ConflictException",
+            CreateStageError::NotFound(_) => "NotFoundException",
+            CreateStageError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateVpcLink
 #[derive(Debug, PartialEq)]
 pub enum CreateVpcLinkError {
@@ -4653,6 +4764,14 @@ impl fmt::Display for CreateVpcLinkError {
     }
 }
 impl Error for CreateVpcLinkError {}
+impl ServiceErrorCode for CreateVpcLinkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVpcLinkError::BadRequest(_) => "BadRequestException",
+            CreateVpcLinkError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteAccessLogSettings
 #[derive(Debug, PartialEq)]
 pub enum DeleteAccessLogSettingsError {
@@ -4691,6 +4810,14 @@ impl fmt::Display for DeleteAccessLogSettingsError {
     }
 }
 impl Error for DeleteAccessLogSettingsError {}
+impl ServiceErrorCode for DeleteAccessLogSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAccessLogSettingsError::NotFound(_) => "NotFoundException",
+            DeleteAccessLogSettingsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteApi
 #[derive(Debug, PartialEq)]
 pub enum DeleteApiError {
@@ -4727,6 +4854,14 @@ impl fmt::Display for DeleteApiError {
     }
 }
 impl Error for DeleteApiError {}
+impl ServiceErrorCode for DeleteApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApiError::NotFound(_) => "NotFoundException",
+            DeleteApiError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteApiMapping
 #[derive(Debug, PartialEq)]
 pub enum DeleteApiMappingError {
@@ -4769,6 +4904,15 @@ impl fmt::Display for DeleteApiMappingError {
     }
 }
 impl Error for DeleteApiMappingError {}
+impl ServiceErrorCode for DeleteApiMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApiMappingError::BadRequest(_) => "BadRequestException",
+            DeleteApiMappingError::NotFound(_) => "NotFoundException",
+            DeleteApiMappingError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum DeleteAuthorizerError {
@@ -4805,6 +4949,14 @@ impl fmt::Display for DeleteAuthorizerError {
     }
 }
 impl Error for DeleteAuthorizerError {}
+impl ServiceErrorCode for DeleteAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAuthorizerError::NotFound(_) => "NotFoundException",
+            DeleteAuthorizerError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteCorsConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteCorsConfigurationError {
@@ -4843,6 +4995,14 @@ impl fmt::Display for DeleteCorsConfigurationError {
     }
 }
 impl Error for DeleteCorsConfigurationError {}
+impl ServiceErrorCode for DeleteCorsConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCorsConfigurationError::NotFound(_) => "NotFoundException",
+            DeleteCorsConfigurationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteDeployment
 #[derive(Debug, PartialEq)]
 pub enum DeleteDeploymentError {
@@ -4879,6 +5039,14 @@ impl fmt::Display for DeleteDeploymentError {
     }
 }
 impl Error for DeleteDeploymentError {}
+impl ServiceErrorCode for DeleteDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDeploymentError::NotFound(_) => "NotFoundException",
+            DeleteDeploymentError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteDomainName
 #[derive(Debug, PartialEq)]
 pub enum DeleteDomainNameError {
@@ -4915,6 +5083,14 @@ impl fmt::Display for DeleteDomainNameError {
     }
 }
 impl Error for DeleteDomainNameError {}
+impl ServiceErrorCode for DeleteDomainNameError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDomainNameError::NotFound(_) => "NotFoundException",
+            DeleteDomainNameError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteIntegration
 #[derive(Debug, PartialEq)]
 pub enum DeleteIntegrationError {
@@ -4951,6 +5127,14 @@ impl fmt::Display for DeleteIntegrationError {
     }
 }
 impl Error for DeleteIntegrationError {}
+impl ServiceErrorCode for DeleteIntegrationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIntegrationError::NotFound(_) => "NotFoundException",
+            DeleteIntegrationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteIntegrationResponse
 #[derive(Debug, PartialEq)]
 pub enum DeleteIntegrationResponseError {
@@ -4989,6 +5173,14 @@ impl fmt::Display for DeleteIntegrationResponseError {
     }
 }
 impl Error for DeleteIntegrationResponseError {}
+impl ServiceErrorCode for DeleteIntegrationResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIntegrationResponseError::NotFound(_) => "NotFoundException",
+            DeleteIntegrationResponseError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteModel
 #[derive(Debug, PartialEq)]
 pub enum DeleteModelError {
@@ -5025,6 +5217,14 @@ impl fmt::Display for DeleteModelError {
     }
 }
 impl Error for DeleteModelError {}
+impl ServiceErrorCode for DeleteModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteModelError::NotFound(_) => "NotFoundException",
+            DeleteModelError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteRoute
 #[derive(Debug, PartialEq)]
 pub enum DeleteRouteError {
@@ -5061,6 +5261,14 @@ impl fmt::Display for DeleteRouteError {
     }
 }
 impl Error for DeleteRouteError {}
+impl ServiceErrorCode for DeleteRouteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRouteError::NotFound(_) => "NotFoundException",
+            DeleteRouteError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteRouteRequestParameter
 #[derive(Debug, PartialEq)]
 pub enum DeleteRouteRequestParameterError {
@@ -5103,6 +5311,14 @@ impl fmt::Display for DeleteRouteRequestParameterError {
     }
 }
 impl Error for DeleteRouteRequestParameterError {}
+impl ServiceErrorCode for DeleteRouteRequestParameterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRouteRequestParameterError::NotFound(_) => "NotFoundException",
+            DeleteRouteRequestParameterError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteRouteResponse
 #[derive(Debug, PartialEq)]
 pub enum DeleteRouteResponseError {
@@ -5139,6 +5355,14 @@ impl fmt::Display for DeleteRouteResponseError {
     }
 }
 impl Error for DeleteRouteResponseError {}
+impl ServiceErrorCode for DeleteRouteResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRouteResponseError::NotFound(_) => "NotFoundException",
+            DeleteRouteResponseError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteRouteSettings
 #[derive(Debug, PartialEq)]
 pub enum DeleteRouteSettingsError {
@@ -5175,6 +5399,14 @@ impl fmt::Display for DeleteRouteSettingsError {
     }
 }
 impl Error for DeleteRouteSettingsError {}
+impl ServiceErrorCode for DeleteRouteSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRouteSettingsError::NotFound(_) => "NotFoundException",
+            DeleteRouteSettingsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteStage
 #[derive(Debug, PartialEq)]
 pub enum DeleteStageError {
@@ -5211,6 +5443,14 @@ impl fmt::Display for DeleteStageError {
     }
 }
 impl Error for DeleteStageError {}
+impl ServiceErrorCode for DeleteStageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStageError::NotFound(_) => "NotFoundException",
+            DeleteStageError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteVpcLink
 #[derive(Debug, PartialEq)]
 pub enum DeleteVpcLinkError {
@@ -5247,6 +5487,14 @@ impl fmt::Display for DeleteVpcLinkError {
     }
 }
 impl Error for DeleteVpcLinkError {}
+impl ServiceErrorCode for DeleteVpcLinkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVpcLinkError::NotFound(_) => "NotFoundException",
+            DeleteVpcLinkError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ExportApi
 #[derive(Debug, PartialEq)]
 pub enum ExportApiError {
@@ -5289,6 +5537,15 @@ impl fmt::Display for ExportApiError {
     }
 }
 impl Error for ExportApiError {}
+impl ServiceErrorCode for ExportApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExportApiError::BadRequest(_) => "BadRequestException",
+            ExportApiError::NotFound(_) => "NotFoundException",
+            ExportApiError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetApi
 #[derive(Debug, PartialEq)]
 pub enum GetApiError {
@@ -5323,6 +5580,14 @@ impl fmt::Display for GetApiError {
     }
 }
 impl Error for GetApiError {}
+impl ServiceErrorCode for GetApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetApiError::NotFound(_) => "NotFoundException",
+            GetApiError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetApiMapping
 #[derive(Debug, PartialEq)]
 pub enum GetApiMappingError {
@@ -5365,6 +5630,15 @@ impl fmt::Display for GetApiMappingError {
     }
 }
 impl Error for GetApiMappingError {}
+impl ServiceErrorCode for GetApiMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetApiMappingError::BadRequest(_) => "BadRequestException",
+            GetApiMappingError::NotFound(_) => "NotFoundException",
+            GetApiMappingError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetApiMappings
 #[derive(Debug, PartialEq)]
 pub enum GetApiMappingsError {
@@ -5407,6 +5681,15 @@ impl fmt::Display for GetApiMappingsError {
     }
 }
 impl Error for GetApiMappingsError {}
+impl ServiceErrorCode for GetApiMappingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetApiMappingsError::BadRequest(_) => "BadRequestException",
+            GetApiMappingsError::NotFound(_) => "NotFoundException",
+            GetApiMappingsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetApis
 #[derive(Debug, PartialEq)]
 pub enum GetApisError {
@@ -5449,6 +5732,15 @@ impl fmt::Display for GetApisError {
     }
 }
 impl Error for GetApisError {}
+impl ServiceErrorCode for GetApisError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetApisError::BadRequest(_) => "BadRequestException",
+            GetApisError::NotFound(_) => "NotFoundException",
+            GetApisError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum GetAuthorizerError {
@@ -5485,6 +5777,14 @@ impl fmt::Display for GetAuthorizerError {
     }
 }
 impl Error for GetAuthorizerError {}
+impl ServiceErrorCode for GetAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAuthorizerError::NotFound(_) => "NotFoundException",
+            GetAuthorizerError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetAuthorizers
 #[derive(Debug, PartialEq)]
 pub enum GetAuthorizersError {
@@ -5527,6 +5827,15 @@ impl fmt::Display for GetAuthorizersError {
     }
 }
 impl Error for GetAuthorizersError {}
+impl ServiceErrorCode for GetAuthorizersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAuthorizersError::BadRequest(_) => "BadRequestException",
+            GetAuthorizersError::NotFound(_) => "NotFoundException",
+            GetAuthorizersError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetDeployment
 #[derive(Debug, PartialEq)]
 pub enum GetDeploymentError {
@@ -5563,6 +5872,14 @@ impl fmt::Display for GetDeploymentError {
     }
 }
 impl Error for GetDeploymentError {}
+impl ServiceErrorCode for GetDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeploymentError::NotFound(_) => "NotFoundException",
+            GetDeploymentError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetDeployments
 #[derive(Debug, PartialEq)]
 pub enum GetDeploymentsError {
@@ -5605,6 +5922,15 @@ impl fmt::Display for GetDeploymentsError {
     }
 }
 impl Error for GetDeploymentsError {}
+impl ServiceErrorCode for GetDeploymentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeploymentsError::BadRequest(_) => "BadRequestException",
+            GetDeploymentsError::NotFound(_) => "NotFoundException",
+            GetDeploymentsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetDomainName
 #[derive(Debug, PartialEq)]
 pub enum GetDomainNameError {
@@ -5641,6 +5967,14 @@ impl fmt::Display for GetDomainNameError {
     }
 }
 impl Error for GetDomainNameError {}
+impl ServiceErrorCode for GetDomainNameError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDomainNameError::NotFound(_) => "NotFoundException",
+            GetDomainNameError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetDomainNames
 #[derive(Debug, PartialEq)]
 pub enum GetDomainNamesError {
@@ -5683,6 +6017,15 @@ impl fmt::Display for GetDomainNamesError {
     }
 }
 impl Error for GetDomainNamesError {}
+impl ServiceErrorCode for GetDomainNamesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDomainNamesError::BadRequest(_) => "BadRequestException",
+            GetDomainNamesError::NotFound(_) => "NotFoundException",
+            GetDomainNamesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetIntegration
 #[derive(Debug, PartialEq)]
 pub enum GetIntegrationError {
@@ -5719,6 +6062,14 @@ impl fmt::Display for GetIntegrationError {
     }
 }
 impl Error for GetIntegrationError {}
+impl ServiceErrorCode for GetIntegrationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIntegrationError::NotFound(_) => "NotFoundException",
+            GetIntegrationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetIntegrationResponse
 #[derive(Debug, PartialEq)]
 pub enum GetIntegrationResponseError {
@@ -5757,6 +6108,14 @@ impl fmt::Display for GetIntegrationResponseError {
     }
 }
 impl Error for GetIntegrationResponseError {}
+impl ServiceErrorCode for GetIntegrationResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIntegrationResponseError::NotFound(_) => "NotFoundException",
+            GetIntegrationResponseError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetIntegrationResponses
 #[derive(Debug, PartialEq)]
 pub enum GetIntegrationResponsesError {
@@ -5801,6 +6160,15 @@ impl fmt::Display for GetIntegrationResponsesError {
     }
 }
 impl Error for GetIntegrationResponsesError {}
+impl ServiceErrorCode for GetIntegrationResponsesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIntegrationResponsesError::BadRequest(_) => "BadRequestException",
+            GetIntegrationResponsesError::NotFound(_) => "NotFoundException",
+            GetIntegrationResponsesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetIntegrations
 #[derive(Debug, PartialEq)]
 pub enum GetIntegrationsError {
@@ -5843,6 +6211,15 @@ impl fmt::Display for GetIntegrationsError {
     }
 }
 impl Error for GetIntegrationsError {}
+impl ServiceErrorCode for GetIntegrationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIntegrationsError::BadRequest(_) => "BadRequestException",
+            GetIntegrationsError::NotFound(_) => "NotFoundException",
+            GetIntegrationsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetModel
 #[derive(Debug, PartialEq)]
 pub enum GetModelError {
@@ -5879,6 +6256,14 @@ impl fmt::Display for GetModelError {
     }
 }
 impl Error for GetModelError {}
+impl ServiceErrorCode for GetModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetModelError::NotFound(_) => "NotFoundException",
+            GetModelError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetModelTemplate
 #[derive(Debug, PartialEq)]
 pub enum GetModelTemplateError {
@@ -5915,6 +6300,14 @@ impl fmt::Display for GetModelTemplateError {
     }
 }
 impl Error for GetModelTemplateError {}
+impl ServiceErrorCode for GetModelTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetModelTemplateError::NotFound(_) => "NotFoundException",
+            GetModelTemplateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetModels
 #[derive(Debug, PartialEq)]
 pub enum GetModelsError {
@@ -5957,6 +6350,15 @@ impl fmt::Display for GetModelsError {
     }
 }
 impl Error for GetModelsError {}
+impl ServiceErrorCode for GetModelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetModelsError::BadRequest(_) => "BadRequestException",
+            GetModelsError::NotFound(_) => "NotFoundException",
+            GetModelsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetRoute
 #[derive(Debug, PartialEq)]
 pub enum GetRouteError {
@@ -5993,6 +6395,14 @@ impl fmt::Display for GetRouteError {
     }
 }
 impl Error for GetRouteError {}
+impl ServiceErrorCode for GetRouteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRouteError::NotFound(_) => "NotFoundException",
+            GetRouteError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetRouteResponse
 #[derive(Debug, PartialEq)]
 pub enum GetRouteResponseError {
@@ -6029,6 +6439,14 @@ impl fmt::Display for GetRouteResponseError {
     }
 }
 impl Error for GetRouteResponseError {}
+impl ServiceErrorCode for GetRouteResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRouteResponseError::NotFound(_) => "NotFoundException",
+            GetRouteResponseError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetRouteResponses
 #[derive(Debug, PartialEq)]
 pub enum GetRouteResponsesError {
@@ -6071,6 +6489,15 @@ impl fmt::Display for GetRouteResponsesError {
     }
 }
 impl Error for GetRouteResponsesError {}
+impl ServiceErrorCode for GetRouteResponsesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRouteResponsesError::BadRequest(_) => "BadRequestException",
+            GetRouteResponsesError::NotFound(_) => "NotFoundException",
+            GetRouteResponsesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetRoutes
 #[derive(Debug, PartialEq)]
 pub enum GetRoutesError {
@@ -6113,6 +6540,15 @@ impl fmt::Display for GetRoutesError {
     }
 }
 impl Error for GetRoutesError {}
+impl ServiceErrorCode for GetRoutesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRoutesError::BadRequest(_) => "BadRequestException",
+            GetRoutesError::NotFound(_) => "NotFoundException",
+            GetRoutesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetStage
 #[derive(Debug, PartialEq)]
 pub enum GetStageError {
@@ -6149,6 +6585,14 @@ impl fmt::Display for GetStageError {
     }
 }
 impl Error for GetStageError {}
+impl ServiceErrorCode for GetStageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetStageError::NotFound(_) => "NotFoundException",
+            GetStageError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetStages
 #[derive(Debug, PartialEq)]
 pub enum GetStagesError {
@@ -6191,6 +6635,15 @@ impl fmt::Display for GetStagesError {
     }
 }
 impl Error for GetStagesError {}
+impl ServiceErrorCode for GetStagesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetStagesError::BadRequest(_) => "BadRequestException",
+            GetStagesError::NotFound(_) => "NotFoundException",
+            GetStagesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetTags
 #[derive(Debug, PartialEq)]
 pub enum GetTagsError {
@@ -6239,6 +6692,16 @@ impl fmt::Display for GetTagsError {
     }
 }
 impl Error for GetTagsError {}
+impl ServiceErrorCode for GetTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTagsError::BadRequest(_) => "BadRequestException",
+            GetTagsError::Conflict(_) => "ConflictException",
+            GetTagsError::NotFound(_) => "NotFoundException",
+            GetTagsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetVpcLink
 #[derive(Debug, PartialEq)]
 pub enum GetVpcLinkError {
@@ -6275,6 +6738,14 @@ impl fmt::Display for GetVpcLinkError {
     }
 }
 impl Error for GetVpcLinkError {}
+impl ServiceErrorCode for GetVpcLinkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVpcLinkError::NotFound(_) => "NotFoundException",
+            GetVpcLinkError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetVpcLinks
 #[derive(Debug, PartialEq)]
 pub enum GetVpcLinksError {
@@ -6311,6 +6782,14 @@ impl fmt::Display for GetVpcLinksError {
     }
 }
 impl Error for GetVpcLinksError {}
+impl ServiceErrorCode for GetVpcLinksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVpcLinksError::BadRequest(_) => "BadRequestException",
+            GetVpcLinksError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ImportApi
 #[derive(Debug, PartialEq)]
 pub enum ImportApiError {
@@ -6359,6 +6838,16 @@ impl fmt::Display for ImportApiError {
     }
 }
 impl Error for ImportApiError {}
+impl ServiceErrorCode for ImportApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportApiError::BadRequest(_) => "BadRequestException",
+            ImportApiError::Conflict(_) => "ConflictException",
+            ImportApiError::NotFound(_) => "NotFoundException",
+            ImportApiError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ReimportApi
 #[derive(Debug, PartialEq)]
 pub enum ReimportApiError {
@@ -6407,6 +6896,16 @@ impl fmt::Display for ReimportApiError {
     }
 }
 impl Error for ReimportApiError {}
+impl ServiceErrorCode for ReimportApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ReimportApiError::BadRequest(_) => "BadRequestException",
+            ReimportApiError::Conflict(_) => "ConflictException",
+            ReimportApiError::NotFound(_) => "NotFoundException",
+            ReimportApiError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ResetAuthorizersCache
 #[derive(Debug, PartialEq)]
 pub enum ResetAuthorizersCacheError {
@@ -6445,6 +6944,14 @@ impl fmt::Display for ResetAuthorizersCacheError {
     }
 }
 impl Error for ResetAuthorizersCacheError {}
+impl ServiceErrorCode for ResetAuthorizersCacheError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetAuthorizersCacheError::NotFound(_) => "NotFoundException",
+            ResetAuthorizersCacheError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -6493,6 +7000,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::Conflict(_) => "ConflictException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+            TagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -6541,6 +7058,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::Conflict(_) => "ConflictException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+            UntagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateApi
 #[derive(Debug, PartialEq)]
 pub enum UpdateApiError {
@@ -6589,6 +7116,16 @@ impl fmt::Display for UpdateApiError {
     }
 }
 impl Error for UpdateApiError {}
+impl ServiceErrorCode for UpdateApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApiError::BadRequest(_) => "BadRequestException",
+            UpdateApiError::Conflict(_) => "ConflictException",
+            UpdateApiError::NotFound(_) => "NotFoundException",
+            UpdateApiError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateApiMapping
 #[derive(Debug, PartialEq)]
 pub enum UpdateApiMappingError {
@@ -6637,6 +7174,16 @@ impl fmt::Display for UpdateApiMappingError {
     }
 }
 impl Error for UpdateApiMappingError {}
+impl ServiceErrorCode for UpdateApiMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApiMappingError::BadRequest(_) => "BadRequestException",
+            UpdateApiMappingError::Conflict(_) => "ConflictException",
+            UpdateApiMappingError::NotFound(_) => "NotFoundException",
+            UpdateApiMappingError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum UpdateAuthorizerError {
@@ -6685,6 +7232,16 @@ impl fmt::Display for UpdateAuthorizerError {
     }
 }
 impl Error for UpdateAuthorizerError {}
+impl ServiceErrorCode for UpdateAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAuthorizerError::BadRequest(_) => "BadRequestException",
+            UpdateAuthorizerError::Conflict(_) => "ConflictException",
+            UpdateAuthorizerError::NotFound(_) => "NotFoundException",
+            UpdateAuthorizerError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateDeployment
 #[derive(Debug, PartialEq)]
 pub enum UpdateDeploymentError {
@@ -6733,6 +7290,16 @@ impl fmt::Display for UpdateDeploymentError {
     }
 }
 impl Error for UpdateDeploymentError {}
+impl ServiceErrorCode for UpdateDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDeploymentError::BadRequest(_) => "BadRequestException",
+            UpdateDeploymentError::Conflict(_) => "ConflictException",
+            UpdateDeploymentError::NotFound(_) => "NotFoundException",
+            UpdateDeploymentError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateDomainName
 #[derive(Debug, PartialEq)]
 pub enum UpdateDomainNameError {
@@ -6781,6 +7348,16 @@ impl fmt::Display for UpdateDomainNameError {
     }
 }
 impl Error for UpdateDomainNameError {}
+impl ServiceErrorCode for UpdateDomainNameError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDomainNameError::BadRequest(_) => "BadRequestException",
+            UpdateDomainNameError::Conflict(_) => "ConflictException",
+            UpdateDomainNameError::NotFound(_) => "NotFoundException",
+            UpdateDomainNameError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateIntegration
 #[derive(Debug, PartialEq)]
 pub enum UpdateIntegrationError {
@@ -6829,6 +7406,16 @@ impl fmt::Display for UpdateIntegrationError {
     }
 }
 impl Error for UpdateIntegrationError {}
+impl ServiceErrorCode for UpdateIntegrationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateIntegrationError::BadRequest(_) => "BadRequestException",
+            UpdateIntegrationError::Conflict(_) => "ConflictException",
+            UpdateIntegrationError::NotFound(_) => "NotFoundException",
+            UpdateIntegrationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateIntegrationResponse
 #[derive(Debug, PartialEq)]
 pub enum UpdateIntegrationResponseError {
@@ -6881,6 +7468,16 @@ impl fmt::Display for UpdateIntegrationResponseError {
     }
 }
 impl Error for UpdateIntegrationResponseError {}
+impl ServiceErrorCode for UpdateIntegrationResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateIntegrationResponseError::BadRequest(_) => "BadRequestException",
+            UpdateIntegrationResponseError::Conflict(_) => "ConflictException",
+            UpdateIntegrationResponseError::NotFound(_) => "NotFoundException",
+            UpdateIntegrationResponseError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateModel
 #[derive(Debug, PartialEq)]
 pub enum UpdateModelError {
@@ -6929,6 +7526,16 @@ impl fmt::Display for UpdateModelError {
     }
 }
 impl Error for UpdateModelError {}
+impl ServiceErrorCode for UpdateModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateModelError::BadRequest(_) => "BadRequestException",
+            UpdateModelError::Conflict(_) => "ConflictException",
+            UpdateModelError::NotFound(_) => "NotFoundException",
+            UpdateModelError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateRoute
 #[derive(Debug, PartialEq)]
 pub enum UpdateRouteError {
@@ -6977,6 +7584,16 @@ impl fmt::Display for UpdateRouteError {
     }
 }
 impl Error for UpdateRouteError {}
+impl ServiceErrorCode for UpdateRouteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRouteError::BadRequest(_) => "BadRequestException",
+            UpdateRouteError::Conflict(_) => "ConflictException",
+            UpdateRouteError::NotFound(_) => "NotFoundException",
+            UpdateRouteError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateRouteResponse
 #[derive(Debug, PartialEq)]
 pub enum UpdateRouteResponseError {
@@ -7025,6 +7642,16 @@ impl fmt::Display for UpdateRouteResponseError {
     }
 }
 impl Error for UpdateRouteResponseError {}
+impl ServiceErrorCode for UpdateRouteResponseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRouteResponseError::BadRequest(_) => "BadRequestException",
+            UpdateRouteResponseError::Conflict(_) => "ConflictException",
+            UpdateRouteResponseError::NotFound(_) => "NotFoundException",
+            UpdateRouteResponseError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateStage
 #[derive(Debug, PartialEq)]
 pub enum UpdateStageError {
@@ -7073,6 +7700,16 @@ impl fmt::Display for UpdateStageError {
     }
 }
 impl Error for UpdateStageError {}
+impl ServiceErrorCode for UpdateStageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateStageError::BadRequest(_) => "BadRequestException",
+            UpdateStageError::Conflict(_) => "ConflictException",
+            UpdateStageError::NotFound(_) => "NotFoundException",
+            UpdateStageError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateVpcLink
 #[derive(Debug, PartialEq)]
 pub enum UpdateVpcLinkError {
@@ -7115,6 +7752,15 @@ impl fmt::Display for UpdateVpcLinkError {
     }
 }
 impl Error for UpdateVpcLinkError {}
+impl ServiceErrorCode for UpdateVpcLinkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateVpcLinkError::BadRequest(_) => "BadRequestException",
+            UpdateVpcLinkError::NotFound(_) => "NotFoundException",
+            UpdateVpcLinkError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AmazonApiGatewayV2 API. AmazonApiGatewayV2 clients implement this trait.
 #[async_trait]
 pub trait ApiGatewayV2 {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1062,6 +1062,14 @@ impl fmt::Display for CreateApplicationError {
     }
 }
 impl Error for CreateApplicationError {}
+impl ServiceErrorCode for CreateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApplicationError::BadRequest(_) => "BadRequestException",
+            CreateApplicationError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by CreateConfigurationProfile
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationProfileError {
@@ -1112,6 +1120,15 @@ impl fmt::Display for CreateConfigurationProfileError {
     }
 }
 impl Error for CreateConfigurationProfileError {}
+impl ServiceErrorCode for CreateConfigurationProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConfigurationProfileError::BadRequest(_) => "BadRequestException",
+            CreateConfigurationProfileError::InternalServer(_) => "InternalServerException",
+            CreateConfigurationProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateDeploymentStrategy
 #[derive(Debug, PartialEq)]
 pub enum CreateDeploymentStrategyError {
@@ -1150,6 +1167,14 @@ impl fmt::Display for CreateDeploymentStrategyError {
     }
 }
 impl Error for CreateDeploymentStrategyError {}
+impl ServiceErrorCode for CreateDeploymentStrategyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeploymentStrategyError::BadRequest(_) => "BadRequestException",
+            CreateDeploymentStrategyError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by CreateEnvironment
 #[derive(Debug, PartialEq)]
 pub enum CreateEnvironmentError {
@@ -1192,6 +1217,15 @@ impl fmt::Display for CreateEnvironmentError {
     }
 }
 impl Error for CreateEnvironmentError {}
+impl ServiceErrorCode for CreateEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEnvironmentError::BadRequest(_) => "BadRequestException",
+            CreateEnvironmentError::InternalServer(_) => "InternalServerException",
+            CreateEnvironmentError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateHostedConfigurationVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateHostedConfigurationVersionError {
@@ -1274,6 +1308,22 @@ impl fmt::Display for CreateHostedConfigurationVersionError {
     }
 }
 impl Error for CreateHostedConfigurationVersionError {}
+impl ServiceErrorCode for CreateHostedConfigurationVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHostedConfigurationVersionError::BadRequest(_) => "BadRequestException",
+            CreateHostedConfigurationVersionError::Conflict(_) => "ConflictException",
+            CreateHostedConfigurationVersionError::InternalServer(_) => "InternalServerException",
+            CreateHostedConfigurationVersionError::PayloadTooLarge(_) => "PayloadTooLargeException",
+            CreateHostedConfigurationVersionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            CreateHostedConfigurationVersionError::ServiceQuotaExceeded(_) => {
+                "ServiceQuotaExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteApplication
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationError {
@@ -1316,6 +1366,15 @@ impl fmt::Display for DeleteApplicationError {
     }
 }
 impl Error for DeleteApplicationError {}
+impl ServiceErrorCode for DeleteApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationError::BadRequest(_) => "BadRequestException",
+            DeleteApplicationError::InternalServer(_) => "InternalServerException",
+            DeleteApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteConfigurationProfile
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationProfileError {
@@ -1372,6 +1431,16 @@ impl fmt::Display for DeleteConfigurationProfileError {
     }
 }
 impl Error for DeleteConfigurationProfileError {}
+impl ServiceErrorCode for DeleteConfigurationProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigurationProfileError::BadRequest(_) => "BadRequestException",
+            DeleteConfigurationProfileError::Conflict(_) => "ConflictException",
+            DeleteConfigurationProfileError::InternalServer(_) => "InternalServerException",
+            DeleteConfigurationProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteDeploymentStrategy
 #[derive(Debug, PartialEq)]
 pub enum DeleteDeploymentStrategyError {
@@ -1418,6 +1487,15 @@ impl fmt::Display for DeleteDeploymentStrategyError {
     }
 }
 impl Error for DeleteDeploymentStrategyError {}
+impl ServiceErrorCode for DeleteDeploymentStrategyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDeploymentStrategyError::BadRequest(_) => "BadRequestException",
+            DeleteDeploymentStrategyError::InternalServer(_) => "InternalServerException",
+            DeleteDeploymentStrategyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteEnvironment
 #[derive(Debug, PartialEq)]
 pub enum DeleteEnvironmentError {
@@ -1466,6 +1544,16 @@ impl fmt::Display for DeleteEnvironmentError {
     }
 }
 impl Error for DeleteEnvironmentError {}
+impl ServiceErrorCode for DeleteEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEnvironmentError::BadRequest(_) => "BadRequestException",
+            DeleteEnvironmentError::Conflict(_) => "ConflictException",
+            DeleteEnvironmentError::InternalServer(_) => "InternalServerException",
+            DeleteEnvironmentError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteHostedConfigurationVersion
 #[derive(Debug, PartialEq)]
 pub enum DeleteHostedConfigurationVersionError {
@@ -1520,6 +1608,17 @@ impl fmt::Display for DeleteHostedConfigurationVersionError {
     }
 }
 impl Error for DeleteHostedConfigurationVersionError {}
+impl ServiceErrorCode for DeleteHostedConfigurationVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteHostedConfigurationVersionError::BadRequest(_) => "BadRequestException",
+            DeleteHostedConfigurationVersionError::InternalServer(_) => "InternalServerException",
+            DeleteHostedConfigurationVersionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by GetApplication
 #[derive(Debug, PartialEq)]
 pub enum GetApplicationError {
@@ -1562,6 +1661,15 @@ impl fmt::Display for GetApplicationError {
     }
 }
 impl Error for GetApplicationError {}
+impl ServiceErrorCode for GetApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetApplicationError::BadRequest(_) => "BadRequestException",
+            GetApplicationError::InternalServer(_) => "InternalServerException",
+            GetApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetConfigurationError {
@@ -1604,6 +1712,15 @@ impl fmt::Display for GetConfigurationError {
     }
 }
 impl Error for GetConfigurationError {}
+impl ServiceErrorCode for GetConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConfigurationError::BadRequest(_) => "BadRequestException",
+            GetConfigurationError::InternalServer(_) => "InternalServerException",
+            GetConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetConfigurationProfile
 #[derive(Debug, PartialEq)]
 pub enum GetConfigurationProfileError {
@@ -1650,6 +1767,15 @@ impl fmt::Display for GetConfigurationProfileError {
     }
 }
 impl Error for GetConfigurationProfileError {}
+impl ServiceErrorCode for GetConfigurationProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConfigurationProfileError::BadRequest(_) => "BadRequestException",
+            GetConfigurationProfileError::InternalServer(_) => "InternalServerException",
+            GetConfigurationProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetDeployment
 #[derive(Debug, PartialEq)]
 pub enum GetDeploymentError {
@@ -1692,6 +1818,15 @@ impl fmt::Display for GetDeploymentError {
     }
 }
 impl Error for GetDeploymentError {}
+impl ServiceErrorCode for GetDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeploymentError::BadRequest(_) => "BadRequestException",
+            GetDeploymentError::InternalServer(_) => "InternalServerException",
+            GetDeploymentError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetDeploymentStrategy
 #[derive(Debug, PartialEq)]
 pub enum GetDeploymentStrategyError {
@@ -1738,6 +1873,15 @@ impl fmt::Display for GetDeploymentStrategyError {
     }
 }
 impl Error for GetDeploymentStrategyError {}
+impl ServiceErrorCode for GetDeploymentStrategyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeploymentStrategyError::BadRequest(_) => "BadRequestException",
+            GetDeploymentStrategyError::InternalServer(_) => "InternalServerException",
+            GetDeploymentStrategyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetEnvironment
 #[derive(Debug, PartialEq)]
 pub enum GetEnvironmentError {
@@ -1780,6 +1924,15 @@ impl fmt::Display for GetEnvironmentError {
     }
 }
 impl Error for GetEnvironmentError {}
+impl ServiceErrorCode for GetEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEnvironmentError::BadRequest(_) => "BadRequestException",
+            GetEnvironmentError::InternalServer(_) => "InternalServerException",
+            GetEnvironmentError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetHostedConfigurationVersion
 #[derive(Debug, PartialEq)]
 pub enum GetHostedConfigurationVersionError {
@@ -1832,6 +1985,15 @@ impl fmt::Display for GetHostedConfigurationVersionError {
     }
 }
 impl Error for GetHostedConfigurationVersionError {}
+impl ServiceErrorCode for GetHostedConfigurationVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetHostedConfigurationVersionError::BadRequest(_) => "BadRequestException",
+            GetHostedConfigurationVersionError::InternalServer(_) => "InternalServerException",
+            GetHostedConfigurationVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListApplications
 #[derive(Debug, PartialEq)]
 pub enum ListApplicationsError {
@@ -1868,6 +2030,14 @@ impl fmt::Display for ListApplicationsError {
     }
 }
 impl Error for ListApplicationsError {}
+impl ServiceErrorCode for ListApplicationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListApplicationsError::BadRequest(_) => "BadRequestException",
+            ListApplicationsError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by ListConfigurationProfiles
 #[derive(Debug, PartialEq)]
 pub enum ListConfigurationProfilesError {
@@ -1916,6 +2086,15 @@ impl fmt::Display for ListConfigurationProfilesError {
     }
 }
 impl Error for ListConfigurationProfilesError {}
+impl ServiceErrorCode for ListConfigurationProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListConfigurationProfilesError::BadRequest(_) => "BadRequestException",
+            ListConfigurationProfilesError::InternalServer(_) => "InternalServerException",
+            ListConfigurationProfilesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListDeploymentStrategies
 #[derive(Debug, PartialEq)]
 pub enum ListDeploymentStrategiesError {
@@ -1954,6 +2133,14 @@ impl fmt::Display for ListDeploymentStrategiesError {
     }
 }
 impl Error for ListDeploymentStrategiesError {}
+impl ServiceErrorCode for ListDeploymentStrategiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeploymentStrategiesError::BadRequest(_) => "BadRequestException",
+            ListDeploymentStrategiesError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by ListDeployments
 #[derive(Debug, PartialEq)]
 pub enum ListDeploymentsError {
@@ -1996,6 +2183,15 @@ impl fmt::Display for ListDeploymentsError {
     }
 }
 impl Error for ListDeploymentsError {}
+impl ServiceErrorCode for ListDeploymentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeploymentsError::BadRequest(_) => "BadRequestException",
+            ListDeploymentsError::InternalServer(_) => "InternalServerException",
+            ListDeploymentsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListEnvironments
 #[derive(Debug, PartialEq)]
 pub enum ListEnvironmentsError {
@@ -2038,6 +2234,15 @@ impl fmt::Display for ListEnvironmentsError {
     }
 }
 impl Error for ListEnvironmentsError {}
+impl ServiceErrorCode for ListEnvironmentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEnvironmentsError::BadRequest(_) => "BadRequestException",
+            ListEnvironmentsError::InternalServer(_) => "InternalServerException",
+            ListEnvironmentsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListHostedConfigurationVersions
 #[derive(Debug, PartialEq)]
 pub enum ListHostedConfigurationVersionsError {
@@ -2092,6 +2297,17 @@ impl fmt::Display for ListHostedConfigurationVersionsError {
     }
 }
 impl Error for ListHostedConfigurationVersionsError {}
+impl ServiceErrorCode for ListHostedConfigurationVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListHostedConfigurationVersionsError::BadRequest(_) => "BadRequestException",
+            ListHostedConfigurationVersionsError::InternalServer(_) => "InternalServerException",
+            ListHostedConfigurationVersionsError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2136,6 +2352,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::InternalServer(_) => "InternalServerException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartDeployment
 #[derive(Debug, PartialEq)]
 pub enum StartDeploymentError {
@@ -2184,6 +2409,16 @@ impl fmt::Display for StartDeploymentError {
     }
 }
 impl Error for StartDeploymentError {}
+impl ServiceErrorCode for StartDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDeploymentError::BadRequest(_) => "BadRequestException",
+            StartDeploymentError::Conflict(_) => "ConflictException",
+            StartDeploymentError::InternalServer(_) => "InternalServerException",
+            StartDeploymentError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopDeployment
 #[derive(Debug, PartialEq)]
 pub enum StopDeploymentError {
@@ -2226,6 +2461,15 @@ impl fmt::Display for StopDeploymentError {
     }
 }
 impl Error for StopDeploymentError {}
+impl ServiceErrorCode for StopDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopDeploymentError::BadRequest(_) => "BadRequestException",
+            StopDeploymentError::InternalServer(_) => "InternalServerException",
+            StopDeploymentError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2268,6 +2512,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::InternalServer(_) => "InternalServerException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2310,6 +2563,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::InternalServer(_) => "InternalServerException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
 pub enum UpdateApplicationError {
@@ -2352,6 +2614,15 @@ impl fmt::Display for UpdateApplicationError {
     }
 }
 impl Error for UpdateApplicationError {}
+impl ServiceErrorCode for UpdateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApplicationError::BadRequest(_) => "BadRequestException",
+            UpdateApplicationError::InternalServer(_) => "InternalServerException",
+            UpdateApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateConfigurationProfile
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationProfileError {
@@ -2402,6 +2673,15 @@ impl fmt::Display for UpdateConfigurationProfileError {
     }
 }
 impl Error for UpdateConfigurationProfileError {}
+impl ServiceErrorCode for UpdateConfigurationProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConfigurationProfileError::BadRequest(_) => "BadRequestException",
+            UpdateConfigurationProfileError::InternalServer(_) => "InternalServerException",
+            UpdateConfigurationProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateDeploymentStrategy
 #[derive(Debug, PartialEq)]
 pub enum UpdateDeploymentStrategyError {
@@ -2448,6 +2728,15 @@ impl fmt::Display for UpdateDeploymentStrategyError {
     }
 }
 impl Error for UpdateDeploymentStrategyError {}
+impl ServiceErrorCode for UpdateDeploymentStrategyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDeploymentStrategyError::BadRequest(_) => "BadRequestException",
+            UpdateDeploymentStrategyError::InternalServer(_) => "InternalServerException",
+            UpdateDeploymentStrategyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateEnvironment
 #[derive(Debug, PartialEq)]
 pub enum UpdateEnvironmentError {
@@ -2490,6 +2779,15 @@ impl fmt::Display for UpdateEnvironmentError {
     }
 }
 impl Error for UpdateEnvironmentError {}
+impl ServiceErrorCode for UpdateEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateEnvironmentError::BadRequest(_) => "BadRequestException",
+            UpdateEnvironmentError::InternalServer(_) => "InternalServerException",
+            UpdateEnvironmentError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ValidateConfiguration
 #[derive(Debug, PartialEq)]
 pub enum ValidateConfigurationError {
@@ -2536,6 +2834,15 @@ impl fmt::Display for ValidateConfigurationError {
     }
 }
 impl Error for ValidateConfigurationError {}
+impl ServiceErrorCode for ValidateConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ValidateConfigurationError::BadRequest(_) => "BadRequestException",
+            ValidateConfigurationError::InternalServer(_) => "InternalServerException",
+            ValidateConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AppConfig API. AppConfig clients implement this trait.
 #[async_trait]
 pub trait AppConfig {
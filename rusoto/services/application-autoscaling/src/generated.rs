@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -736,6 +736,15 @@ impl fmt::Display for DeleteScalingPolicyError {
     }
 }
 impl Error for DeleteScalingPolicyError {}
+impl ServiceErrorCode for DeleteScalingPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteScalingPolicyError::ConcurrentUpdate(_) => "ConcurrentUpdateException",
+            DeleteScalingPolicyError::InternalService(_) => "InternalServiceException",
+            DeleteScalingPolicyError::ObjectNotFound(_) => "ObjectNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteScheduledAction
 #[derive(Debug, PartialEq)]
 pub enum DeleteScheduledActionError {
@@ -784,6 +793,15 @@ impl fmt::Display for DeleteScheduledActionError {
     }
 }
 impl Error for DeleteScheduledActionError {}
+impl ServiceErrorCode for DeleteScheduledActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteScheduledActionError::ConcurrentUpdate(_) => "ConcurrentUpdateException",
+            DeleteScheduledActionError::InternalService(_) => "InternalServiceException",
+            DeleteScheduledActionError::ObjectNotFound(_) => "ObjectNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeregisterScalableTarget
 #[derive(Debug, PartialEq)]
 pub enum DeregisterScalableTargetError {
@@ -832,6 +850,15 @@ impl fmt::Display for DeregisterScalableTargetError {
     }
 }
 impl Error for DeregisterScalableTargetError {}
+impl ServiceErrorCode for DeregisterScalableTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterScalableTargetError::ConcurrentUpdate(_) => "ConcurrentUpdateException",
+            DeregisterScalableTargetError::InternalService(_) => "InternalServiceException",
+            DeregisterScalableTargetError::ObjectNotFound(_) => "ObjectNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeScalableTargets
 #[derive(Debug, PartialEq)]
 pub enum DescribeScalableTargetsError {
@@ -880,6 +907,15 @@ impl fmt::Display for DescribeScalableTargetsError {
     }
 }
 impl Error for DescribeScalableTargetsError {}
+impl ServiceErrorCode for DescribeScalableTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeScalableTargetsError::ConcurrentUpdate(_) => "ConcurrentUpdateException",
+            DescribeScalableTargetsError::InternalService(_) => "InternalServiceException",
+            DescribeScalableTargetsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by DescribeScalingActivities
 #[derive(Debug, PartialEq)]
 pub enum DescribeScalingActivitiesError {
@@ -928,6 +964,15 @@ impl fmt::Display for DescribeScalingActivitiesError {
     }
 }
 impl Error for DescribeScalingActivitiesError {}
+impl ServiceErrorCode for DescribeScalingActivitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeScalingActivitiesError::ConcurrentUpdate(_) => "ConcurrentUpdateException",
+            DescribeScalingActivitiesError::InternalService(_) => "InternalServiceException",
+            DescribeScalingActivitiesError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by DescribeScalingPolicies
 #[derive(Debug, PartialEq)]
 pub enum DescribeScalingPoliciesError {
@@ -984,6 +1029,18 @@ impl fmt::Display for DescribeScalingPoliciesError {
     }
 }
 impl Error for DescribeScalingPoliciesError {}
+impl ServiceErrorCode for DescribeScalingPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeScalingPoliciesError::ConcurrentUpdate(_) => "ConcurrentUpdateException",
+            DescribeScalingPoliciesError::FailedResourceAccess(_) => {
+                "FailedResourceAccessException"
+            }
+            DescribeScalingPoliciesError::InternalService(_) => "InternalServiceException",
+            DescribeScalingPoliciesError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by DescribeScheduledActions
 #[derive(Debug, PartialEq)]
 pub enum DescribeScheduledActionsError {
@@ -1032,6 +1089,15 @@ impl fmt::Display for DescribeScheduledActionsError {
     }
 }
 impl Error for DescribeScheduledActionsError {}
+impl ServiceErrorCode for DescribeScheduledActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeScheduledActionsError::ConcurrentUpdate(_) => "ConcurrentUpdateException",
+            DescribeScheduledActionsError::InternalService(_) => "InternalServiceException",
+            DescribeScheduledActionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by PutScalingPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutScalingPolicyError {
@@ -1088,6 +1154,17 @@ impl fmt::Display for PutScalingPolicyError {
     }
 }
 impl Error for PutScalingPolicyError {}
+impl ServiceErrorCode for PutScalingPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutScalingPolicyError::ConcurrentUpdate(_) => "ConcurrentUpdateException",
+            PutScalingPolicyError::FailedResourceAccess(_) => "FailedResourceAccessException",
+            PutScalingPolicyError::InternalService(_) => "InternalServiceException",
+            PutScalingPolicyError::LimitExceeded(_) => "LimitExceededException",
+            PutScalingPolicyError::ObjectNotFound(_) => "ObjectNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutScheduledAction
 #[derive(Debug, PartialEq)]
 pub enum PutScheduledActionError {
@@ -1136,6 +1213,16 @@ impl fmt::Display for PutScheduledActionError {
     }
 }
 impl Error for PutScheduledActionError {}
+impl ServiceErrorCode for PutScheduledActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutScheduledActionError::ConcurrentUpdate(_) => "ConcurrentUpdateException",
+            PutScheduledActionError::InternalService(_) => "InternalServiceException",
+            PutScheduledActionError::LimitExceeded(_) => "LimitExceededException",
+            PutScheduledActionError::ObjectNotFound(_) => "ObjectNotFoundException",
+        }
+    }
+}
 /// Errors returned by RegisterScalableTarget
 #[derive(Debug, PartialEq)]
 pub enum RegisterScalableTargetError {
@@ -1184,6 +1271,15 @@ impl fmt::Display for RegisterScalableTargetError {
     }
 }
 impl Error for RegisterScalableTargetError {}
+impl ServiceErrorCode for RegisterScalableTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterScalableTargetError::ConcurrentUpdate(_) => "ConcurrentUpdateException",
+            RegisterScalableTargetError::InternalService(_) => "InternalServiceException",
+            RegisterScalableTargetError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Application Auto Scaling API. Application Auto Scaling clients implement this trait.
 #[async_trait]
 pub trait ApplicationAutoScaling {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1147,6 +1147,17 @@ impl fmt::Display for CreateApplicationError {
     }
 }
 impl Error for CreateApplicationError {}
+impl ServiceErrorCode for CreateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApplicationError::AccessDenied(_) => "AccessDeniedException",
+            CreateApplicationError::InternalServer(_) => "InternalServerException",
+            CreateApplicationError::ResourceInUse(_) => "ResourceInUseException",
+            CreateApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateApplicationError::TagsAlreadyExist(_) => "TagsAlreadyExistException",
+        }
+    }
+}
 /// Errors returned by CreateComponent
 #[derive(Debug, PartialEq)]
 pub enum CreateComponentError {
@@ -1189,6 +1200,15 @@ impl fmt::Display for CreateComponentError {
     }
 }
 impl Error for CreateComponentError {}
+impl ServiceErrorCode for CreateComponentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateComponentError::InternalServer(_) => "InternalServerException",
+            CreateComponentError::ResourceInUse(_) => "ResourceInUseException",
+            CreateComponentError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateLogPattern
 #[derive(Debug, PartialEq)]
 pub enum CreateLogPatternError {
@@ -1231,6 +1251,15 @@ impl fmt::Display for CreateLogPatternError {
     }
 }
 impl Error for CreateLogPatternError {}
+impl ServiceErrorCode for CreateLogPatternError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLogPatternError::InternalServer(_) => "InternalServerException",
+            CreateLogPatternError::ResourceInUse(_) => "ResourceInUseException",
+            CreateLogPatternError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteApplication
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationError {
@@ -1273,6 +1302,15 @@ impl fmt::Display for DeleteApplicationError {
     }
 }
 impl Error for DeleteApplicationError {}
+impl ServiceErrorCode for DeleteApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationError::BadRequest(_) => "BadRequestException",
+            DeleteApplicationError::InternalServer(_) => "InternalServerException",
+            DeleteApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteComponent
 #[derive(Debug, PartialEq)]
 pub enum DeleteComponentError {
@@ -1309,6 +1347,14 @@ impl fmt::Display for DeleteComponentError {
     }
 }
 impl Error for DeleteComponentError {}
+impl ServiceErrorCode for DeleteComponentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteComponentError::InternalServer(_) => "InternalServerException",
+            DeleteComponentError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteLogPattern
 #[derive(Debug, PartialEq)]
 pub enum DeleteLogPatternError {
@@ -1351,6 +1397,15 @@ impl fmt::Display for DeleteLogPatternError {
     }
 }
 impl Error for DeleteLogPatternError {}
+impl ServiceErrorCode for DeleteLogPatternError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLogPatternError::BadRequest(_) => "BadRequestException",
+            DeleteLogPatternError::InternalServer(_) => "InternalServerException",
+            DeleteLogPatternError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeApplication
 #[derive(Debug, PartialEq)]
 pub enum DescribeApplicationError {
@@ -1389,6 +1444,14 @@ impl fmt::Display for DescribeApplicationError {
     }
 }
 impl Error for DescribeApplicationError {}
+impl ServiceErrorCode for DescribeApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeApplicationError::InternalServer(_) => "InternalServerException",
+            DescribeApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeComponent
 #[derive(Debug, PartialEq)]
 pub enum DescribeComponentError {
@@ -1425,6 +1488,14 @@ impl fmt::Display for DescribeComponentError {
     }
 }
 impl Error for DescribeComponentError {}
+impl ServiceErrorCode for DescribeComponentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeComponentError::InternalServer(_) => "InternalServerException",
+            DescribeComponentError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeComponentConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribeComponentConfigurationError {
@@ -1471,6 +1542,14 @@ impl fmt::Display for DescribeComponentConfigurationError {
     }
 }
 impl Error for DescribeComponentConfigurationError {}
+impl ServiceErrorCode for DescribeComponentConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeComponentConfigurationError::InternalServer(_) => "InternalServerException",
+            DescribeComponentConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeComponentConfigurationRecommendation
 #[derive(Debug, PartialEq)]
 pub enum DescribeComponentConfigurationRecommendationError {
@@ -1519,6 +1598,18 @@ impl fmt::Display for DescribeComponentConfigurationRecommendationError {
     }
 }
 impl Error for DescribeComponentConfigurationRecommendationError {}
+impl ServiceErrorCode for DescribeComponentConfigurationRecommendationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeComponentConfigurationRecommendationError::InternalServer(_) => {
+                "InternalServerException"
+            }
+            DescribeComponentConfigurationRecommendationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeLogPattern
 #[derive(Debug, PartialEq)]
 pub enum DescribeLogPatternError {
@@ -1555,6 +1646,14 @@ impl fmt::Display for DescribeLogPatternError {
     }
 }
 impl Error for DescribeLogPatternError {}
+impl ServiceErrorCode for DescribeLogPatternError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLogPatternError::InternalServer(_) => "InternalServerException",
+            DescribeLogPatternError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeObservation
 #[derive(Debug, PartialEq)]
 pub enum DescribeObservationError {
@@ -1593,6 +1692,14 @@ impl fmt::Display for DescribeObservationError {
     }
 }
 impl Error for DescribeObservationError {}
+impl ServiceErrorCode for DescribeObservationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeObservationError::InternalServer(_) => "InternalServerException",
+            DescribeObservationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeProblem
 #[derive(Debug, PartialEq)]
 pub enum DescribeProblemError {
@@ -1629,6 +1736,14 @@ impl fmt::Display for DescribeProblemError {
     }
 }
 impl Error for DescribeProblemError {}
+impl ServiceErrorCode for DescribeProblemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProblemError::InternalServer(_) => "InternalServerException",
+            DescribeProblemError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeProblemObservations
 #[derive(Debug, PartialEq)]
 pub enum DescribeProblemObservationsError {
@@ -1671,6 +1786,14 @@ impl fmt::Display for DescribeProblemObservationsError {
     }
 }
 impl Error for DescribeProblemObservationsError {}
+impl ServiceErrorCode for DescribeProblemObservationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProblemObservationsError::InternalServer(_) => "InternalServerException",
+            DescribeProblemObservationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListApplications
 #[derive(Debug, PartialEq)]
 pub enum ListApplicationsError {
@@ -1701,6 +1824,13 @@ impl fmt::Display for ListApplicationsError {
     }
 }
 impl Error for ListApplicationsError {}
+impl ServiceErrorCode for ListApplicationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListApplicationsError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by ListComponents
 #[derive(Debug, PartialEq)]
 pub enum ListComponentsError {
@@ -1737,6 +1867,14 @@ impl fmt::Display for ListComponentsError {
     }
 }
 impl Error for ListComponentsError {}
+impl ServiceErrorCode for ListComponentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListComponentsError::InternalServer(_) => "InternalServerException",
+            ListComponentsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListConfigurationHistory
 #[derive(Debug, PartialEq)]
 pub enum ListConfigurationHistoryError {
@@ -1777,6 +1915,14 @@ impl fmt::Display for ListConfigurationHistoryError {
     }
 }
 impl Error for ListConfigurationHistoryError {}
+impl ServiceErrorCode for ListConfigurationHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListConfigurationHistoryError::InternalServer(_) => "InternalServerException",
+            ListConfigurationHistoryError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListLogPatternSets
 #[derive(Debug, PartialEq)]
 pub enum ListLogPatternSetsError {
@@ -1813,6 +1959,14 @@ impl fmt::Display for ListLogPatternSetsError {
     }
 }
 impl Error for ListLogPatternSetsError {}
+impl ServiceErrorCode for ListLogPatternSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLogPatternSetsError::InternalServer(_) => "InternalServerException",
+            ListLogPatternSetsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListLogPatterns
 #[derive(Debug, PartialEq)]
 pub enum ListLogPatternsError {
@@ -1849,6 +2003,14 @@ impl fmt::Display for ListLogPatternsError {
     }
 }
 impl Error for ListLogPatternsError {}
+impl ServiceErrorCode for ListLogPatternsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLogPatternsError::InternalServer(_) => "InternalServerException",
+            ListLogPatternsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListProblems
 #[derive(Debug, PartialEq)]
 pub enum ListProblemsError {
@@ -1885,6 +2047,14 @@ impl fmt::Display for ListProblemsError {
     }
 }
 impl Error for ListProblemsError {}
+impl ServiceErrorCode for ListProblemsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProblemsError::InternalServer(_) => "InternalServerException",
+            ListProblemsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1917,6 +2087,13 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1953,6 +2130,14 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1983,6 +2168,13 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
 pub enum UpdateApplicationError {
@@ -2019,6 +2211,14 @@ impl fmt::Display for UpdateApplicationError {
     }
 }
 impl Error for UpdateApplicationError {}
+impl ServiceErrorCode for UpdateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApplicationError::InternalServer(_) => "InternalServerException",
+            UpdateApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateComponent
 #[derive(Debug, PartialEq)]
 pub enum UpdateComponentError {
@@ -2061,6 +2261,15 @@ impl fmt::Display for UpdateComponentError {
     }
 }
 impl Error for UpdateComponentError {}
+impl ServiceErrorCode for UpdateComponentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateComponentError::InternalServer(_) => "InternalServerException",
+            UpdateComponentError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateComponentError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateComponentConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateComponentConfigurationError {
@@ -2105,6 +2314,14 @@ impl fmt::Display for UpdateComponentConfigurationError {
     }
 }
 impl Error for UpdateComponentConfigurationError {}
+impl ServiceErrorCode for UpdateComponentConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateComponentConfigurationError::InternalServer(_) => "InternalServerException",
+            UpdateComponentConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateLogPattern
 #[derive(Debug, PartialEq)]
 pub enum UpdateLogPatternError {
@@ -2147,6 +2364,15 @@ impl fmt::Display for UpdateLogPatternError {
     }
 }
 impl Error for UpdateLogPatternError {}
+impl ServiceErrorCode for UpdateLogPatternError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateLogPatternError::InternalServer(_) => "InternalServerException",
+            UpdateLogPatternError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateLogPatternError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Application Insights API. Application Insights clients implement this trait.
 #[async_trait]
 pub trait ApplicationInsights {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -107,6 +107,7 @@ pub struct ClientPolicyTls {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateGatewayRouteInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -145,6 +146,7 @@ pub struct CreateGatewayRouteOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateMeshInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -175,6 +177,7 @@ pub struct CreateMeshOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateRouteInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -213,6 +216,7 @@ pub struct CreateRouteOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateVirtualGatewayInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -248,6 +252,7 @@ pub struct CreateVirtualGatewayOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateVirtualNodeInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -284,6 +289,7 @@ pub struct CreateVirtualNodeOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateVirtualRouterInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -320,6 +326,7 @@ pub struct CreateVirtualRouterOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateVirtualServiceInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -1866,6 +1873,7 @@ pub struct UntagResourceOutput {}
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateGatewayRouteInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -1900,6 +1908,7 @@ pub struct UpdateGatewayRouteOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateMeshInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -1925,6 +1934,7 @@ pub struct UpdateMeshOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateRouteInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -1959,6 +1969,7 @@ pub struct UpdateRouteOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateVirtualGatewayInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -1990,6 +2001,7 @@ pub struct UpdateVirtualGatewayOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateVirtualNodeInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -2022,6 +2034,7 @@ pub struct UpdateVirtualNodeOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateVirtualRouterInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -2054,6 +2067,7 @@ pub struct UpdateVirtualRouterOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateVirtualServiceInput {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Up to 36 letters, numbers, hyphens, and underscores are allowed.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -2813,6 +2827,20 @@ impl fmt::Display for CreateGatewayRouteError {
     }
 }
 impl Error for CreateGatewayRouteError {}
+impl ServiceErrorCode for CreateGatewayRouteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGatewayRouteError::BadRequest(_) => "BadRequestException",
+            CreateGatewayRouteError::Conflict(_) => "ConflictException",
+            CreateGatewayRouteError::Forbidden(_) => "ForbiddenException",
+            CreateGatewayRouteError::InternalServerError(_) => "InternalServerErrorException",
+            CreateGatewayRouteError::LimitExceeded(_) => "LimitExceededException",
+            CreateGatewayRouteError::NotFound(_) => "NotFoundException",
+            CreateGatewayRouteError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateGatewayRouteError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateMesh
 #[derive(Debug, PartialEq)]
 pub enum CreateMeshError {
@@ -2885,6 +2913,20 @@ impl fmt::Display for CreateMeshError {
     }
 }
 impl Error for CreateMeshError {}
+impl ServiceErrorCode for CreateMeshError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMeshError::BadRequest(_) => "BadRequestException",
+            CreateMeshError::Conflict(_) => "ConflictException",
+            CreateMeshError::Forbidden(_) => "ForbiddenException",
+            CreateMeshError::InternalServerError(_) => "InternalServerErrorException",
+            CreateMeshError::LimitExceeded(_) => "LimitExceededException",
+            CreateMeshError::NotFound(_) => "NotFoundException",
+            CreateMeshError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateMeshError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateRoute
 #[derive(Debug, PartialEq)]
 pub enum CreateRouteError {
@@ -2957,6 +2999,20 @@ impl fmt::Display for CreateRouteError {
     }
 }
 impl Error for CreateRouteError {}
+impl ServiceErrorCode for CreateRouteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRouteError::BadRequest(_) => "BadRequestException",
+            CreateRouteError::Conflict(_) => "ConflictException",
+            CreateRouteError::Forbidden(_) => "ForbiddenException",
+            CreateRouteError::InternalServerError(_) => "InternalServerErrorException",
+            CreateRouteError::LimitExceeded(_) => "LimitExceededException",
+            CreateRouteError::NotFound(_) => "NotFoundException",
+            CreateRouteError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateRouteError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateVirtualGateway
 #[derive(Debug, PartialEq)]
 pub enum CreateVirtualGatewayError {
@@ -3035,6 +3091,20 @@ impl fmt::Display for CreateVirtualGatewayError {
     }
 }
 impl Error for CreateVirtualGatewayError {}
+impl ServiceErrorCode for CreateVirtualGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVirtualGatewayError::BadRequest(_) => "BadRequestException",
+            CreateVirtualGatewayError::Conflict(_) => "ConflictException",
+            CreateVirtualGatewayError::Forbidden(_) => "ForbiddenException",
+            CreateVirtualGatewayError::InternalServerError(_) => "InternalServerErrorException",
+            CreateVirtualGatewayError::LimitExceeded(_) => "LimitExceededException",
+            CreateVirtualGatewayError::NotFound(_) => "NotFoundException",
+            CreateVirtualGatewayError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateVirtualGatewayError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateVirtualNode
 #[derive(Debug, PartialEq)]
 pub enum CreateVirtualNodeError {
@@ -3111,6 +3181,20 @@ impl fmt::Display for CreateVirtualNodeError {
     }
 }
 impl Error for CreateVirtualNodeError {}
+impl ServiceErrorCode for CreateVirtualNodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVirtualNodeError::BadRequest(_) => "BadRequestException",
+            CreateVirtualNodeError::Conflict(_) => "ConflictException",
+            CreateVirtualNodeError::Forbidden(_) => "ForbiddenException",
+            CreateVirtualNodeError::InternalServerError(_) => "InternalServerErrorException",
+            CreateVirtualNodeError::LimitExceeded(_) => "LimitExceededException",
+            CreateVirtualNodeError::NotFound(_) => "NotFoundException",
+            CreateVirtualNodeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateVirtualNodeError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateVirtualRouter
 #[derive(Debug, PartialEq)]
 pub enum CreateVirtualRouterError {
@@ -3187,6 +3271,20 @@ impl fmt::Display for CreateVirtualRouterError {
     }
 }
 impl Error for CreateVirtualRouterError {}
+impl ServiceErrorCode for CreateVirtualRouterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVirtualRouterError::BadRequest(_) => "BadRequestException",
+            CreateVirtualRouterError::Conflict(_) => "ConflictException",
+            CreateVirtualRouterError::Forbidden(_) => "ForbiddenException",
+            CreateVirtualRouterError::InternalServerError(_) => "InternalServerErrorException",
+            CreateVirtualRouterError::LimitExceeded(_) => "LimitExceededException",
+            CreateVirtualRouterError::NotFound(_) => "NotFoundException",
+            CreateVirtualRouterError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateVirtualRouterError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateVirtualService
 #[derive(Debug, PartialEq)]
 pub enum CreateVirtualServiceError {
@@ -3265,6 +3363,20 @@ impl fmt::Display for CreateVirtualServiceError {
     }
 }
 impl Error for CreateVirtualServiceError {}
+impl ServiceErrorCode for CreateVirtualServiceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVirtualServiceError::BadRequest(_) => "BadRequestException",
+            CreateVirtualServiceError::Conflict(_) => "ConflictException",
+            CreateVirtualServiceError::Forbidden(_) => "ForbiddenException",
+            CreateVirtualServiceError::InternalServerError(_) => "InternalServerErrorException",
+            CreateVirtualServiceError::LimitExceeded(_) => "LimitExceededException",
+            CreateVirtualServiceError::NotFound(_) => "NotFoundException",
+            CreateVirtualServiceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateVirtualServiceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteGatewayRoute
 #[derive(Debug, PartialEq)]
 pub enum DeleteGatewayRouteError {
@@ -3335,6 +3447,19 @@ impl fmt::Display for DeleteGatewayRouteError {
     }
 }
 impl Error for DeleteGatewayRouteError {}
+impl ServiceErrorCode for DeleteGatewayRouteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGatewayRouteError::BadRequest(_) => "BadRequestException",
+            DeleteGatewayRouteError::Forbidden(_) => "ForbiddenException",
+            DeleteGatewayRouteError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteGatewayRouteError::NotFound(_) => "NotFoundException",
+            DeleteGatewayRouteError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteGatewayRouteError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteGatewayRouteError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteMesh
 #[derive(Debug, PartialEq)]
 pub enum DeleteMeshError {
@@ -3401,6 +3526,19 @@ impl fmt::Display for DeleteMeshError {
     }
 }
 impl Error for DeleteMeshError {}
+impl ServiceErrorCode for DeleteMeshError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMeshError::BadRequest(_) => "BadRequestException",
+            DeleteMeshError::Forbidden(_) => "ForbiddenException",
+            DeleteMeshError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteMeshError::NotFound(_) => "NotFoundException",
+            DeleteMeshError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteMeshError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteMeshError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteRoute
 #[derive(Debug, PartialEq)]
 pub enum DeleteRouteError {
@@ -3467,6 +3605,19 @@ impl fmt::Display for DeleteRouteError {
     }
 }
 impl Error for DeleteRouteError {}
+impl ServiceErrorCode for DeleteRouteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRouteError::BadRequest(_) => "BadRequestException",
+            DeleteRouteError::Forbidden(_) => "ForbiddenException",
+            DeleteRouteError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteRouteError::NotFound(_) => "NotFoundException",
+            DeleteRouteError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteRouteError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteRouteError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteVirtualGateway
 #[derive(Debug, PartialEq)]
 pub enum DeleteVirtualGatewayError {
@@ -3539,6 +3690,19 @@ impl fmt::Display for DeleteVirtualGatewayError {
     }
 }
 impl Error for DeleteVirtualGatewayError {}
+impl ServiceErrorCode for DeleteVirtualGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVirtualGatewayError::BadRequest(_) => "BadRequestException",
+            DeleteVirtualGatewayError::Forbidden(_) => "ForbiddenException",
+            DeleteVirtualGatewayError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteVirtualGatewayError::NotFound(_) => "NotFoundException",
+            DeleteVirtualGatewayError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteVirtualGatewayError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteVirtualGatewayError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteVirtualNode
 #[derive(Debug, PartialEq)]
 pub enum DeleteVirtualNodeError {
@@ -3609,6 +3773,19 @@ impl fmt::Display for DeleteVirtualNodeError {
     }
 }
 impl Error for DeleteVirtualNodeError {}
+impl ServiceErrorCode for DeleteVirtualNodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVirtualNodeError::BadRequest(_) => "BadRequestException",
+            DeleteVirtualNodeError::Forbidden(_) => "ForbiddenException",
+            DeleteVirtualNodeError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteVirtualNodeError::NotFound(_) => "NotFoundException",
+            DeleteVirtualNodeError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteVirtualNodeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteVirtualNodeError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteVirtualRouter
 #[derive(Debug, PartialEq)]
 pub enum DeleteVirtualRouterError {
@@ -3679,6 +3856,19 @@ impl fmt::Display for DeleteVirtualRouterError {
     }
 }
 impl Error for DeleteVirtualRouterError {}
+impl ServiceErrorCode for DeleteVirtualRouterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVirtualRouterError::BadRequest(_) => "BadRequestException",
+            DeleteVirtualRouterError::Forbidden(_) => "ForbiddenException",
+            DeleteVirtualRouterError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteVirtualRouterError::NotFound(_) => "NotFoundException",
+            DeleteVirtualRouterError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteVirtualRouterError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteVirtualRouterError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteVirtualService
 #[derive(Debug, PartialEq)]
 pub enum DeleteVirtualServiceError {
@@ -3751,6 +3941,19 @@ impl fmt::Display for DeleteVirtualServiceError {
     }
 }
 impl Error for DeleteVirtualServiceError {}
+impl ServiceErrorCode for DeleteVirtualServiceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVirtualServiceError::BadRequest(_) => "BadRequestException",
+            DeleteVirtualServiceError::Forbidden(_) => "ForbiddenException",
+            DeleteVirtualServiceError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteVirtualServiceError::NotFound(_) => "NotFoundException",
+            DeleteVirtualServiceError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteVirtualServiceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteVirtualServiceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeGatewayRoute
 #[derive(Debug, PartialEq)]
 pub enum DescribeGatewayRouteError {
@@ -3817,6 +4020,18 @@ impl fmt::Display for DescribeGatewayRouteError {
     }
 }
 impl Error for DescribeGatewayRouteError {}
+impl ServiceErrorCode for DescribeGatewayRouteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGatewayRouteError::BadRequest(_) => "BadRequestException",
+            DescribeGatewayRouteError::Forbidden(_) => "ForbiddenException",
+            DescribeGatewayRouteError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeGatewayRouteError::NotFound(_) => "NotFoundException",
+            DescribeGatewayRouteError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeGatewayRouteError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeMesh
 #[derive(Debug, PartialEq)]
 pub enum DescribeMeshError {
@@ -3877,6 +4092,18 @@ impl fmt::Display for DescribeMeshError {
     }
 }
 impl Error for DescribeMeshError {}
+impl ServiceErrorCode for DescribeMeshError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMeshError::BadRequest(_) => "BadRequestException",
+            DescribeMeshError::Forbidden(_) => "ForbiddenException",
+            DescribeMeshError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeMeshError::NotFound(_) => "NotFoundException",
+            DescribeMeshError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeMeshError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeRoute
 #[derive(Debug, PartialEq)]
 pub enum DescribeRouteError {
@@ -3937,6 +4164,18 @@ impl fmt::Display for DescribeRouteError {
     }
 }
 impl Error for DescribeRouteError {}
+impl ServiceErrorCode for DescribeRouteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRouteError::BadRequest(_) => "BadRequestException",
+            DescribeRouteError::Forbidden(_) => "ForbiddenException",
+            DescribeRouteError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeRouteError::NotFound(_) => "NotFoundException",
+            DescribeRouteError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeRouteError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeVirtualGateway
 #[derive(Debug, PartialEq)]
 pub enum DescribeVirtualGatewayError {
@@ -4003,6 +4242,18 @@ impl fmt::Display for DescribeVirtualGatewayError {
     }
 }
 impl Error for DescribeVirtualGatewayError {}
+impl ServiceErrorCode for DescribeVirtualGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeVirtualGatewayError::BadRequest(_) => "BadRequestException",
+            DescribeVirtualGatewayError::Forbidden(_) => "ForbiddenException",
+            DescribeVirtualGatewayError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeVirtualGatewayError::NotFound(_) => "NotFoundException",
+            DescribeVirtualGatewayError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeVirtualGatewayError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeVirtualNode
 #[derive(Debug, PartialEq)]
 pub enum DescribeVirtualNodeError {
@@ -4067,6 +4318,18 @@ impl fmt::Display for DescribeVirtualNodeError {
     }
 }
 impl Error for DescribeVirtualNodeError {}
+impl ServiceErrorCode for DescribeVirtualNodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeVirtualNodeError::BadRequest(_) => "BadRequestException",
+            DescribeVirtualNodeError::Forbidden(_) => "ForbiddenException",
+            DescribeVirtualNodeError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeVirtualNodeError::NotFound(_) => "NotFoundException",
+            DescribeVirtualNodeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeVirtualNodeError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeVirtualRouter
 #[derive(Debug, PartialEq)]
 pub enum DescribeVirtualRouterError {
@@ -4133,6 +4396,18 @@ impl fmt::Display for DescribeVirtualRouterError {
     }
 }
 impl Error for DescribeVirtualRouterError {}
+impl ServiceErrorCode for DescribeVirtualRouterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeVirtualRouterError::BadRequest(_) => "BadRequestException",
+            DescribeVirtualRouterError::Forbidden(_) => "ForbiddenException",
+            DescribeVirtualRouterError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeVirtualRouterError::NotFound(_) => "NotFoundException",
+            DescribeVirtualRouterError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeVirtualRouterError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeVirtualService
 #[derive(Debug, PartialEq)]
 pub enum DescribeVirtualServiceError {
@@ -4199,6 +4474,18 @@ impl fmt::Display for DescribeVirtualServiceError {
     }
 }
 impl Error for DescribeVirtualServiceError {}
+impl ServiceErrorCode for DescribeVirtualServiceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeVirtualServiceError::BadRequest(_) => "BadRequestException",
+            DescribeVirtualServiceError::Forbidden(_) => "ForbiddenException",
+            DescribeVirtualServiceError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeVirtualServiceError::NotFound(_) => "NotFoundException",
+            DescribeVirtualServiceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeVirtualServiceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListGatewayRoutes
 #[derive(Debug, PartialEq)]
 pub enum ListGatewayRoutesError {
@@ -4263,6 +4550,18 @@ impl fmt::Display for ListGatewayRoutesError {
     }
 }
 impl Error for ListGatewayRoutesError {}
+impl ServiceErrorCode for ListGatewayRoutesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGatewayRoutesError::BadRequest(_) => "BadRequestException",
+            ListGatewayRoutesError::Forbidden(_) => "ForbiddenException",
+            ListGatewayRoutesError::InternalServerError(_) => "InternalServerErrorException",
+            ListGatewayRoutesError::NotFound(_) => "NotFoundException",
+            ListGatewayRoutesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListGatewayRoutesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListMeshes
 #[derive(Debug, PartialEq)]
 pub enum ListMeshesError {
@@ -4323,6 +4622,18 @@ impl fmt::Display for ListMeshesError {
     }
 }
 impl Error for ListMeshesError {}
+impl ServiceErrorCode for ListMeshesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMeshesError::BadRequest(_) => "BadRequestException",
+            ListMeshesError::Forbidden(_) => "ForbiddenException",
+            ListMeshesError::InternalServerError(_) => "InternalServerErrorException",
+            ListMeshesError::NotFound(_) => "NotFoundException",
+            ListMeshesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListMeshesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListRoutes
 #[derive(Debug, PartialEq)]
 pub enum ListRoutesError {
@@ -4383,6 +4694,18 @@ impl fmt::Display for ListRoutesError {
     }
 }
 impl Error for ListRoutesError {}
+impl ServiceErrorCode for ListRoutesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRoutesError::BadRequest(_) => "BadRequestException",
+            ListRoutesError::Forbidden(_) => "ForbiddenException",
+            ListRoutesError::InternalServerError(_) => "InternalServerErrorException",
+            ListRoutesError::NotFound(_) => "NotFoundException",
+            ListRoutesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListRoutesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -4447,6 +4770,18 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::Forbidden(_) => "ForbiddenException",
+            ListTagsForResourceError::InternalServerError(_) => "InternalServerErrorException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+            ListTagsForResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListTagsForResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListVirtualGateways
 #[derive(Debug, PartialEq)]
 pub enum ListVirtualGatewaysError {
@@ -4511,6 +4846,18 @@ impl fmt::Display for ListVirtualGatewaysError {
     }
 }
 impl Error for ListVirtualGatewaysError {}
+impl ServiceErrorCode for ListVirtualGatewaysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVirtualGatewaysError::BadRequest(_) => "BadRequestException",
+            ListVirtualGatewaysError::Forbidden(_) => "ForbiddenException",
+            ListVirtualGatewaysError::InternalServerError(_) => "InternalServerErrorException",
+            ListVirtualGatewaysError::NotFound(_) => "NotFoundException",
+            ListVirtualGatewaysError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListVirtualGatewaysError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListVirtualNodes
 #[derive(Debug, PartialEq)]
 pub enum ListVirtualNodesError {
@@ -4573,6 +4920,18 @@ impl fmt::Display for ListVirtualNodesError {
     }
 }
 impl Error for ListVirtualNodesError {}
+impl ServiceErrorCode for ListVirtualNodesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVirtualNodesError::BadRequest(_) => "BadRequestException",
+            ListVirtualNodesError::Forbidden(_) => "ForbiddenException",
+            ListVirtualNodesError::InternalServerError(_) => "InternalServerErrorException",
+            ListVirtualNodesError::NotFound(_) => "NotFoundException",
+            ListVirtualNodesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListVirtualNodesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListVirtualRouters
 #[derive(Debug, PartialEq)]
 pub enum ListVirtualRoutersError {
@@ -4637,6 +4996,18 @@ impl fmt::Display for ListVirtualRoutersError {
     }
 }
 impl Error for ListVirtualRoutersError {}
+impl ServiceErrorCode for ListVirtualRoutersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVirtualRoutersError::BadRequest(_) => "BadRequestException",
+            ListVirtualRoutersError::Forbidden(_) => "ForbiddenException",
+            ListVirtualRoutersError::InternalServerError(_) => "InternalServerErrorException",
+            ListVirtualRoutersError::NotFound(_) => "NotFoundException",
+            ListVirtualRoutersError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListVirtualRoutersError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListVirtualServices
 #[derive(Debug, PartialEq)]
 pub enum ListVirtualServicesError {
@@ -4701,6 +5072,18 @@ impl fmt::Display for ListVirtualServicesError {
     }
 }
 impl Error for ListVirtualServicesError {}
+impl ServiceErrorCode for ListVirtualServicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVirtualServicesError::BadRequest(_) => "BadRequestException",
+            ListVirtualServicesError::Forbidden(_) => "ForbiddenException",
+            ListVirtualServicesError::InternalServerError(_) => "InternalServerErrorException",
+            ListVirtualServicesError::NotFound(_) => "NotFoundException",
+            ListVirtualServicesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListVirtualServicesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -4767,6 +5150,19 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::Forbidden(_) => "ForbiddenException",
+            TagResourceError::InternalServerError(_) => "InternalServerErrorException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+            TagResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            TagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+            TagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -4827,6 +5223,18 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::Forbidden(_) => "ForbiddenException",
+            UntagResourceError::InternalServerError(_) => "InternalServerErrorException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+            UntagResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UntagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateGatewayRoute
 #[derive(Debug, PartialEq)]
 pub enum UpdateGatewayRouteError {
@@ -4903,6 +5311,20 @@ impl fmt::Display for UpdateGatewayRouteError {
     }
 }
 impl Error for UpdateGatewayRouteError {}
+impl ServiceErrorCode for UpdateGatewayRouteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGatewayRouteError::BadRequest(_) => "BadRequestException",
+            UpdateGatewayRouteError::Conflict(_) => "ConflictException",
+            UpdateGatewayRouteError::Forbidden(_) => "ForbiddenException",
+            UpdateGatewayRouteError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateGatewayRouteError::LimitExceeded(_) => "LimitExceededException",
+            UpdateGatewayRouteError::NotFound(_) => "NotFoundException",
+            UpdateGatewayRouteError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateGatewayRouteError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateMesh
 #[derive(Debug, PartialEq)]
 pub enum UpdateMeshError {
@@ -4969,6 +5391,19 @@ impl fmt::Display for UpdateMeshError {
     }
 }
 impl Error for UpdateMeshError {}
+impl ServiceErrorCode for UpdateMeshError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMeshError::BadRequest(_) => "BadRequestException",
+            UpdateMeshError::Conflict(_) => "ConflictException",
+            UpdateMeshError::Forbidden(_) => "ForbiddenException",
+            UpdateMeshError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateMeshError::NotFound(_) => "NotFoundException",
+            UpdateMeshError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateMeshError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateRoute
 #[derive(Debug, PartialEq)]
 pub enum UpdateRouteError {
@@ -5041,6 +5476,20 @@ impl fmt::Display for UpdateRouteError {
     }
 }
 impl Error for UpdateRouteError {}
+impl ServiceErrorCode for UpdateRouteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRouteError::BadRequest(_) => "BadRequestException",
+            UpdateRouteError::Conflict(_) => "ConflictException",
+            UpdateRouteError::Forbidden(_) => "ForbiddenException",
+            UpdateRouteError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateRouteError::LimitExceeded(_) => "LimitExceededException",
+            UpdateRouteError::NotFound(_) => "NotFoundException",
+            UpdateRouteError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateRouteError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateVirtualGateway
 #[derive(Debug, PartialEq)]
 pub enum UpdateVirtualGatewayError {
@@ -5119,6 +5568,20 @@ impl fmt::Display for UpdateVirtualGatewayError {
     }
 }
 impl Error for UpdateVirtualGatewayError {}
+impl ServiceErrorCode for UpdateVirtualGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateVirtualGatewayError::BadRequest(_) => "BadRequestException",
+            UpdateVirtualGatewayError::Conflict(_) => "ConflictException",
+            UpdateVirtualGatewayError::Forbidden(_) => "ForbiddenException",
+            UpdateVirtualGatewayError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateVirtualGatewayError::LimitExceeded(_) => "LimitExceededException",
+            UpdateVirtualGatewayError::NotFound(_) => "NotFoundException",
+            UpdateVirtualGatewayError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateVirtualGatewayError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateVirtualNode
 #[derive(Debug, PartialEq)]
 pub enum UpdateVirtualNodeError {
@@ -5195,6 +5658,20 @@ impl fmt::Display for UpdateVirtualNodeError {
     }
 }
 impl Error for UpdateVirtualNodeError {}
+impl ServiceErrorCode for UpdateVirtualNodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateVirtualNodeError::BadRequest(_) => "BadRequestException",
+            UpdateVirtualNodeError::Conflict(_) => "ConflictException",
+            UpdateVirtualNodeError::Forbidden(_) => "ForbiddenException",
+            UpdateVirtualNodeError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateVirtualNodeError::LimitExceeded(_) => "LimitExceededException",
+            UpdateVirtualNodeError::NotFound(_) => "NotFoundException",
+            UpdateVirtualNodeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateVirtualNodeError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateVirtualRouter
 #[derive(Debug, PartialEq)]
 pub enum UpdateVirtualRouterError {
@@ -5271,6 +5748,20 @@ impl fmt::Display for UpdateVirtualRouterError {
     }
 }
 impl Error for UpdateVirtualRouterError {}
+impl ServiceErrorCode for UpdateVirtualRouterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateVirtualRouterError::BadRequest(_) => "BadRequestException",
+            UpdateVirtualRouterError::Conflict(_) => "ConflictException",
+            UpdateVirtualRouterError::Forbidden(_) => "ForbiddenException",
+            UpdateVirtualRouterError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateVirtualRouterError::LimitExceeded(_) => "LimitExceededException",
+            UpdateVirtualRouterError::NotFound(_) => "NotFoundException",
+            UpdateVirtualRouterError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateVirtualRouterError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateVirtualService
 #[derive(Debug, PartialEq)]
 pub enum UpdateVirtualServiceError {
@@ -5349,6 +5840,20 @@ impl fmt::Display for UpdateVirtualServiceError {
     }
 }
 impl Error for UpdateVirtualServiceError {}
+impl ServiceErrorCode for UpdateVirtualServiceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateVirtualServiceError::BadRequest(_) => "BadRequestException",
+            UpdateVirtualServiceError::Conflict(_) => "ConflictException",
+            UpdateVirtualServiceError::Forbidden(_) => "ForbiddenException",
+            UpdateVirtualServiceError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateVirtualServiceError::LimitExceeded(_) => "LimitExceededException",
+            UpdateVirtualServiceError::NotFound(_) => "NotFoundException",
+            UpdateVirtualServiceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateVirtualServiceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS App Mesh API. AWS App Mesh clients implement this trait.
 #[async_trait]
 pub trait AppMesh {
@@ -5626,6 +6131,10 @@ impl AppMesh for AppMeshClient {
         &self,
         input: CreateGatewayRouteInput,
     ) -> Result<CreateGatewayRouteOutput, RusotoError<CreateGatewayRouteError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualGateway/{virtual_gateway_name}/gatewayRoutes",
             mesh_name = input.mesh_name,
@@ -5667,6 +6176,10 @@ impl AppMesh for AppMeshClient {
         &self,
         input: CreateMeshInput,
     ) -> Result<CreateMeshOutput, RusotoError<CreateMeshError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = "/v20190125/meshes";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
@@ -5698,6 +6211,10 @@ impl AppMesh for AppMeshClient {
         &self,
         input: CreateRouteInput,
     ) -> Result<CreateRouteOutput, RusotoError<CreateRouteError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes",
             mesh_name = input.mesh_name,
@@ -5739,6 +6256,10 @@ impl AppMesh for AppMeshClient {
         &self,
         input: CreateVirtualGatewayInput,
     ) -> Result<CreateVirtualGatewayOutput, RusotoError<CreateVirtualGatewayError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualGateways",
             mesh_name = input.mesh_name
@@ -5779,6 +6300,10 @@ impl AppMesh for AppMeshClient {
         &self,
         input: CreateVirtualNodeInput,
     ) -> Result<CreateVirtualNodeOutput, RusotoError<CreateVirtualNodeError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualNodes",
             mesh_name = input.mesh_name
@@ -5819,6 +6344,10 @@ impl AppMesh for AppMeshClient {
         &self,
         input: CreateVirtualRouterInput,
     ) -> Result<CreateVirtualRouterOutput, RusotoError<CreateVirtualRouterError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualRouters",
             mesh_name = input.mesh_name
@@ -5859,6 +6388,10 @@ impl AppMesh for AppMeshClient {
         &self,
         input: CreateVirtualServiceInput,
     ) -> Result<CreateVirtualServiceOutput, RusotoError<CreateVirtualServiceError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualServices",
             mesh_name = input.mesh_name
@@ -6816,6 +7349,10 @@ impl AppMesh for AppMeshClient {
         &self,
         input: UpdateGatewayRouteInput,
     ) -> Result<UpdateGatewayRouteOutput, RusotoError<UpdateGatewayRouteError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!("/v20190125/meshes/{mesh_name}/virtualGateway/{virtual_gateway_name}/gatewayRoutes/{gateway_route_name}", gateway_route_name = input.gateway_route_name, mesh_name = input.mesh_name, virtual_gateway_name = input.virtual_gateway_name);
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
@@ -6853,6 +7390,10 @@ impl AppMesh for AppMeshClient {
         &self,
         input: UpdateMeshInput,
     ) -> Result<UpdateMeshOutput, RusotoError<UpdateMeshError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
@@ -6884,6 +7425,10 @@ impl AppMesh for AppMeshClient {
         &self,
         input: UpdateRouteInput,
     ) -> Result<UpdateRouteOutput, RusotoError<UpdateRouteError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes/{route_name}",
             mesh_name = input.mesh_name,
@@ -6926,6 +7471,10 @@ impl AppMesh for AppMeshClient {
         &self,
         input: UpdateVirtualGatewayInput,
     ) -> Result<UpdateVirtualGatewayOutput, RusotoError<UpdateVirtualGatewayError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualGateways/{virtual_gateway_name}",
             mesh_name = input.mesh_name,
@@ -6967,6 +7516,10 @@ impl AppMesh for AppMeshClient {
         &self,
         input: UpdateVirtualNodeInput,
     ) -> Result<UpdateVirtualNodeOutput, RusotoError<UpdateVirtualNodeError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualNodes/{virtual_node_name}",
             mesh_name = input.mesh_name,
@@ -7008,6 +7561,10 @@ impl AppMesh for AppMeshClient {
         &self,
         input: UpdateVirtualRouterInput,
     ) -> Result<UpdateVirtualRouterOutput, RusotoError<UpdateVirtualRouterError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualRouters/{virtual_router_name}",
             mesh_name = input.mesh_name,
@@ -7049,6 +7606,10 @@ impl AppMesh for AppMeshClient {
         &self,
         input: UpdateVirtualServiceInput,
     ) -> Result<UpdateVirtualServiceOutput, RusotoError<UpdateVirtualServiceError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualServices/{virtual_service_name}",
             mesh_name = input.mesh_name,
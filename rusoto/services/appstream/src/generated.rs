@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2083,6 +2083,18 @@ impl fmt::Display for AssociateFleetError {
     }
 }
 impl Error for AssociateFleetError {}
+impl ServiceErrorCode for AssociateFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateFleetError::ConcurrentModification(_) => "ConcurrentModificationException",
+            AssociateFleetError::IncompatibleImage(_) => "IncompatibleImageException",
+            AssociateFleetError::InvalidAccountStatus(_) => "InvalidAccountStatusException",
+            AssociateFleetError::LimitExceeded(_) => "LimitExceededException",
+            AssociateFleetError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            AssociateFleetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by BatchAssociateUserStack
 #[derive(Debug, PartialEq)]
 pub enum BatchAssociateUserStackError {
@@ -2127,6 +2139,18 @@ impl fmt::Display for BatchAssociateUserStackError {
     }
 }
 impl Error for BatchAssociateUserStackError {}
+impl ServiceErrorCode for BatchAssociateUserStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchAssociateUserStackError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            BatchAssociateUserStackError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+        }
+    }
+}
 /// Errors returned by BatchDisassociateUserStack
 #[derive(Debug, PartialEq)]
 pub enum BatchDisassociateUserStackError {
@@ -2173,6 +2197,18 @@ impl fmt::Display for BatchDisassociateUserStackError {
     }
 }
 impl Error for BatchDisassociateUserStackError {}
+impl ServiceErrorCode for BatchDisassociateUserStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDisassociateUserStackError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            BatchDisassociateUserStackError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+        }
+    }
+}
 /// Errors returned by CopyImage
 #[derive(Debug, PartialEq)]
 pub enum CopyImageError {
@@ -2233,6 +2269,18 @@ impl fmt::Display for CopyImageError {
     }
 }
 impl Error for CopyImageError {}
+impl ServiceErrorCode for CopyImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyImageError::IncompatibleImage(_) => "IncompatibleImageException",
+            CopyImageError::InvalidAccountStatus(_) => "InvalidAccountStatusException",
+            CopyImageError::LimitExceeded(_) => "LimitExceededException",
+            CopyImageError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CopyImageError::ResourceNotAvailable(_) => "ResourceNotAvailableException",
+            CopyImageError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateDirectoryConfig
 #[derive(Debug, PartialEq)]
 pub enum CreateDirectoryConfigError {
@@ -2301,6 +2349,22 @@ impl fmt::Display for CreateDirectoryConfigError {
     }
 }
 impl Error for CreateDirectoryConfigError {}
+impl ServiceErrorCode for CreateDirectoryConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDirectoryConfigError::InvalidAccountStatus(_) => "InvalidAccountStatusException",
+            CreateDirectoryConfigError::InvalidRole(_) => "InvalidRoleException",
+            CreateDirectoryConfigError::LimitExceeded(_) => "LimitExceededException",
+            CreateDirectoryConfigError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            CreateDirectoryConfigError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateDirectoryConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateFleet
 #[derive(Debug, PartialEq)]
 pub enum CreateFleetError {
@@ -2393,6 +2457,25 @@ impl fmt::Display for CreateFleetError {
     }
 }
 impl Error for CreateFleetError {}
+impl ServiceErrorCode for CreateFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFleetError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateFleetError::IncompatibleImage(_) => "IncompatibleImageException",
+            CreateFleetError::InvalidAccountStatus(_) => "InvalidAccountStatusException",
+            CreateFleetError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            CreateFleetError::InvalidRole(_) => "InvalidRoleException",
+            CreateFleetError::LimitExceeded(_) => "LimitExceededException",
+            CreateFleetError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            CreateFleetError::RequestLimitExceeded(_) => "RequestLimitExceededException",
+            CreateFleetError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateFleetError::ResourceNotAvailable(_) => "ResourceNotAvailableException",
+            CreateFleetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateImageBuilder
 #[derive(Debug, PartialEq)]
 pub enum CreateImageBuilderError {
@@ -2501,6 +2584,25 @@ impl fmt::Display for CreateImageBuilderError {
     }
 }
 impl Error for CreateImageBuilderError {}
+impl ServiceErrorCode for CreateImageBuilderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateImageBuilderError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateImageBuilderError::IncompatibleImage(_) => "IncompatibleImageException",
+            CreateImageBuilderError::InvalidAccountStatus(_) => "InvalidAccountStatusException",
+            CreateImageBuilderError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            CreateImageBuilderError::InvalidRole(_) => "InvalidRoleException",
+            CreateImageBuilderError::LimitExceeded(_) => "LimitExceededException",
+            CreateImageBuilderError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            CreateImageBuilderError::RequestLimitExceeded(_) => "RequestLimitExceededException",
+            CreateImageBuilderError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateImageBuilderError::ResourceNotAvailable(_) => "ResourceNotAvailableException",
+            CreateImageBuilderError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateImageBuilderStreamingURL
 #[derive(Debug, PartialEq)]
 pub enum CreateImageBuilderStreamingURLError {
@@ -2547,6 +2649,16 @@ impl fmt::Display for CreateImageBuilderStreamingURLError {
     }
 }
 impl Error for CreateImageBuilderStreamingURLError {}
+impl ServiceErrorCode for CreateImageBuilderStreamingURLError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateImageBuilderStreamingURLError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            CreateImageBuilderStreamingURLError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateStack
 #[derive(Debug, PartialEq)]
 pub enum CreateStackError {
@@ -2615,6 +2727,21 @@ impl fmt::Display for CreateStackError {
     }
 }
 impl Error for CreateStackError {}
+impl ServiceErrorCode for CreateStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStackError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateStackError::InvalidAccountStatus(_) => "InvalidAccountStatusException",
+            CreateStackError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            CreateStackError::InvalidRole(_) => "InvalidRoleException",
+            CreateStackError::LimitExceeded(_) => "LimitExceededException",
+            CreateStackError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateStackError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateStreamingURL
 #[derive(Debug, PartialEq)]
 pub enum CreateStreamingURLError {
@@ -2671,6 +2798,18 @@ impl fmt::Display for CreateStreamingURLError {
     }
 }
 impl Error for CreateStreamingURLError {}
+impl ServiceErrorCode for CreateStreamingURLError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStreamingURLError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            CreateStreamingURLError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            CreateStreamingURLError::ResourceNotAvailable(_) => "ResourceNotAvailableException",
+            CreateStreamingURLError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateUsageReportSubscription
 #[derive(Debug, PartialEq)]
 pub enum CreateUsageReportSubscriptionError {
@@ -2723,6 +2862,17 @@ impl fmt::Display for CreateUsageReportSubscriptionError {
     }
 }
 impl Error for CreateUsageReportSubscriptionError {}
+impl ServiceErrorCode for CreateUsageReportSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUsageReportSubscriptionError::InvalidAccountStatus(_) => {
+                "InvalidAccountStatusException"
+            }
+            CreateUsageReportSubscriptionError::InvalidRole(_) => "InvalidRoleException",
+            CreateUsageReportSubscriptionError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
 pub enum CreateUserError {
@@ -2779,6 +2929,19 @@ impl fmt::Display for CreateUserError {
     }
 }
 impl Error for CreateUserError {}
+impl ServiceErrorCode for CreateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserError::InvalidAccountStatus(_) => "InvalidAccountStatusException",
+            CreateUserError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            CreateUserError::LimitExceeded(_) => "LimitExceededException",
+            CreateUserError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            CreateUserError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by DeleteDirectoryConfig
 #[derive(Debug, PartialEq)]
 pub enum DeleteDirectoryConfigError {
@@ -2817,6 +2980,14 @@ impl fmt::Display for DeleteDirectoryConfigError {
     }
 }
 impl Error for DeleteDirectoryConfigError {}
+impl ServiceErrorCode for DeleteDirectoryConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDirectoryConfigError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteDirectoryConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteFleet
 #[derive(Debug, PartialEq)]
 pub enum DeleteFleetError {
@@ -2859,6 +3030,15 @@ impl fmt::Display for DeleteFleetError {
     }
 }
 impl Error for DeleteFleetError {}
+impl ServiceErrorCode for DeleteFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFleetError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteFleetError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteFleetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteImage
 #[derive(Debug, PartialEq)]
 pub enum DeleteImageError {
@@ -2907,6 +3087,16 @@ impl fmt::Display for DeleteImageError {
     }
 }
 impl Error for DeleteImageError {}
+impl ServiceErrorCode for DeleteImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteImageError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteImageError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            DeleteImageError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteImageError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteImageBuilder
 #[derive(Debug, PartialEq)]
 pub enum DeleteImageBuilderError {
@@ -2953,6 +3143,15 @@ impl fmt::Display for DeleteImageBuilderError {
     }
 }
 impl Error for DeleteImageBuilderError {}
+impl ServiceErrorCode for DeleteImageBuilderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteImageBuilderError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteImageBuilderError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            DeleteImageBuilderError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteImagePermissions
 #[derive(Debug, PartialEq)]
 pub enum DeleteImagePermissionsError {
@@ -2993,6 +3192,14 @@ impl fmt::Display for DeleteImagePermissionsError {
     }
 }
 impl Error for DeleteImagePermissionsError {}
+impl ServiceErrorCode for DeleteImagePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteImagePermissionsError::ResourceNotAvailable(_) => "ResourceNotAvailableException",
+            DeleteImagePermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteStack
 #[derive(Debug, PartialEq)]
 pub enum DeleteStackError {
@@ -3035,6 +3242,15 @@ impl fmt::Display for DeleteStackError {
     }
 }
 impl Error for DeleteStackError {}
+impl ServiceErrorCode for DeleteStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStackError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteStackError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteStackError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteUsageReportSubscription
 #[derive(Debug, PartialEq)]
 pub enum DeleteUsageReportSubscriptionError {
@@ -3081,6 +3297,16 @@ impl fmt::Display for DeleteUsageReportSubscriptionError {
     }
 }
 impl Error for DeleteUsageReportSubscriptionError {}
+impl ServiceErrorCode for DeleteUsageReportSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUsageReportSubscriptionError::InvalidAccountStatus(_) => {
+                "InvalidAccountStatusException"
+            }
+            DeleteUsageReportSubscriptionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserError {
@@ -3111,6 +3337,13 @@ impl fmt::Display for DeleteUserError {
     }
 }
 impl Error for DeleteUserError {}
+impl ServiceErrorCode for DeleteUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeDirectoryConfigs
 #[derive(Debug, PartialEq)]
 pub enum DescribeDirectoryConfigsError {
@@ -3143,6 +3376,13 @@ impl fmt::Display for DescribeDirectoryConfigsError {
     }
 }
 impl Error for DescribeDirectoryConfigsError {}
+impl ServiceErrorCode for DescribeDirectoryConfigsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDirectoryConfigsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeFleets
 #[derive(Debug, PartialEq)]
 pub enum DescribeFleetsError {
@@ -3173,6 +3413,13 @@ impl fmt::Display for DescribeFleetsError {
     }
 }
 impl Error for DescribeFleetsError {}
+impl ServiceErrorCode for DescribeFleetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFleetsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeImageBuilders
 #[derive(Debug, PartialEq)]
 pub enum DescribeImageBuildersError {
@@ -3205,6 +3452,13 @@ impl fmt::Display for DescribeImageBuildersError {
     }
 }
 impl Error for DescribeImageBuildersError {}
+impl ServiceErrorCode for DescribeImageBuildersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeImageBuildersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeImagePermissions
 #[derive(Debug, PartialEq)]
 pub enum DescribeImagePermissionsError {
@@ -3237,6 +3491,13 @@ impl fmt::Display for DescribeImagePermissionsError {
     }
 }
 impl Error for DescribeImagePermissionsError {}
+impl ServiceErrorCode for DescribeImagePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeImagePermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeImages
 #[derive(Debug, PartialEq)]
 pub enum DescribeImagesError {
@@ -3275,6 +3536,16 @@ impl fmt::Display for DescribeImagesError {
     }
 }
 impl Error for DescribeImagesError {}
+impl ServiceErrorCode for DescribeImagesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeImagesError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            DescribeImagesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeSessions
 #[derive(Debug, PartialEq)]
 pub enum DescribeSessionsError {
@@ -3307,6 +3578,15 @@ impl fmt::Display for DescribeSessionsError {
     }
 }
 impl Error for DescribeSessionsError {}
+impl ServiceErrorCode for DescribeSessionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSessionsError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeStacks
 #[derive(Debug, PartialEq)]
 pub enum DescribeStacksError {
@@ -3337,6 +3617,13 @@ impl fmt::Display for DescribeStacksError {
     }
 }
 impl Error for DescribeStacksError {}
+impl ServiceErrorCode for DescribeStacksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStacksError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeUsageReportSubscriptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeUsageReportSubscriptionsError {
@@ -3383,6 +3670,18 @@ impl fmt::Display for DescribeUsageReportSubscriptionsError {
     }
 }
 impl Error for DescribeUsageReportSubscriptionsError {}
+impl ServiceErrorCode for DescribeUsageReportSubscriptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUsageReportSubscriptionsError::InvalidAccountStatus(_) => {
+                "InvalidAccountStatusException"
+            }
+            DescribeUsageReportSubscriptionsError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeUserStackAssociations
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserStackAssociationsError {
@@ -3429,6 +3728,18 @@ impl fmt::Display for DescribeUserStackAssociationsError {
     }
 }
 impl Error for DescribeUserStackAssociationsError {}
+impl ServiceErrorCode for DescribeUserStackAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserStackAssociationsError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            DescribeUserStackAssociationsError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeUsers
 #[derive(Debug, PartialEq)]
 pub enum DescribeUsersError {
@@ -3467,6 +3778,16 @@ impl fmt::Display for DescribeUsersError {
     }
 }
 impl Error for DescribeUsersError {}
+impl ServiceErrorCode for DescribeUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUsersError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            DescribeUsersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisableUser
 #[derive(Debug, PartialEq)]
 pub enum DisableUserError {
@@ -3497,6 +3818,13 @@ impl fmt::Display for DisableUserError {
     }
 }
 impl Error for DisableUserError {}
+impl ServiceErrorCode for DisableUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateFleet
 #[derive(Debug, PartialEq)]
 pub enum DisassociateFleetError {
@@ -3549,6 +3877,16 @@ impl fmt::Display for DisassociateFleetError {
     }
 }
 impl Error for DisassociateFleetError {}
+impl ServiceErrorCode for DisassociateFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateFleetError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DisassociateFleetError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            DisassociateFleetError::ResourceInUse(_) => "ResourceInUseException",
+            DisassociateFleetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by EnableUser
 #[derive(Debug, PartialEq)]
 pub enum EnableUserError {
@@ -3585,6 +3923,14 @@ impl fmt::Display for EnableUserError {
     }
 }
 impl Error for EnableUserError {}
+impl ServiceErrorCode for EnableUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableUserError::InvalidAccountStatus(_) => "InvalidAccountStatusException",
+            EnableUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ExpireSession
 #[derive(Debug, PartialEq)]
 pub enum ExpireSessionError {}
@@ -3607,6 +3953,11 @@ impl fmt::Display for ExpireSessionError {
     }
 }
 impl Error for ExpireSessionError {}
+impl ServiceErrorCode for ExpireSessionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListAssociatedFleets
 #[derive(Debug, PartialEq)]
 pub enum ListAssociatedFleetsError {}
@@ -3629,6 +3980,11 @@ impl fmt::Display for ListAssociatedFleetsError {
     }
 }
 impl Error for ListAssociatedFleetsError {}
+impl ServiceErrorCode for ListAssociatedFleetsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListAssociatedStacks
 #[derive(Debug, PartialEq)]
 pub enum ListAssociatedStacksError {}
@@ -3651,6 +4007,11 @@ impl fmt::Display for ListAssociatedStacksError {
     }
 }
 impl Error for ListAssociatedStacksError {}
+impl ServiceErrorCode for ListAssociatedStacksError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3683,6 +4044,13 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartFleet
 #[derive(Debug, PartialEq)]
 pub enum StartFleetError {
@@ -3755,6 +4123,20 @@ impl fmt::Display for StartFleetError {
     }
 }
 impl Error for StartFleetError {}
+impl ServiceErrorCode for StartFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartFleetError::ConcurrentModification(_) => "ConcurrentModificationException",
+            StartFleetError::InvalidAccountStatus(_) => "InvalidAccountStatusException",
+            StartFleetError::InvalidRole(_) => "InvalidRoleException",
+            StartFleetError::LimitExceeded(_) => "LimitExceededException",
+            StartFleetError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            StartFleetError::RequestLimitExceeded(_) => "RequestLimitExceededException",
+            StartFleetError::ResourceNotAvailable(_) => "ResourceNotAvailableException",
+            StartFleetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartImageBuilder
 #[derive(Debug, PartialEq)]
 pub enum StartImageBuilderError {
@@ -3815,6 +4197,17 @@ impl fmt::Display for StartImageBuilderError {
     }
 }
 impl Error for StartImageBuilderError {}
+impl ServiceErrorCode for StartImageBuilderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartImageBuilderError::ConcurrentModification(_) => "ConcurrentModificationException",
+            StartImageBuilderError::IncompatibleImage(_) => "IncompatibleImageException",
+            StartImageBuilderError::InvalidAccountStatus(_) => "InvalidAccountStatusException",
+            StartImageBuilderError::ResourceNotAvailable(_) => "ResourceNotAvailableException",
+            StartImageBuilderError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopFleet
 #[derive(Debug, PartialEq)]
 pub enum StopFleetError {
@@ -3851,6 +4244,14 @@ impl fmt::Display for StopFleetError {
     }
 }
 impl Error for StopFleetError {}
+impl ServiceErrorCode for StopFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopFleetError::ConcurrentModification(_) => "ConcurrentModificationException",
+            StopFleetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopImageBuilder
 #[derive(Debug, PartialEq)]
 pub enum StopImageBuilderError {
@@ -3897,6 +4298,15 @@ impl fmt::Display for StopImageBuilderError {
     }
 }
 impl Error for StopImageBuilderError {}
+impl ServiceErrorCode for StopImageBuilderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopImageBuilderError::ConcurrentModification(_) => "ConcurrentModificationException",
+            StopImageBuilderError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            StopImageBuilderError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -3939,6 +4349,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InvalidAccountStatus(_) => "InvalidAccountStatusException",
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3969,6 +4388,13 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateDirectoryConfig
 #[derive(Debug, PartialEq)]
 pub enum UpdateDirectoryConfigError {
@@ -4029,6 +4455,21 @@ impl fmt::Display for UpdateDirectoryConfigError {
     }
 }
 impl Error for UpdateDirectoryConfigError {}
+impl ServiceErrorCode for UpdateDirectoryConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDirectoryConfigError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            UpdateDirectoryConfigError::InvalidRole(_) => "InvalidRoleException",
+            UpdateDirectoryConfigError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            UpdateDirectoryConfigError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateDirectoryConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateFleet
 #[derive(Debug, PartialEq)]
 pub enum UpdateFleetError {
@@ -4121,6 +4562,25 @@ impl fmt::Display for UpdateFleetError {
     }
 }
 impl Error for UpdateFleetError {}
+impl ServiceErrorCode for UpdateFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFleetError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateFleetError::IncompatibleImage(_) => "IncompatibleImageException",
+            UpdateFleetError::InvalidAccountStatus(_) => "InvalidAccountStatusException",
+            UpdateFleetError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            UpdateFleetError::InvalidRole(_) => "InvalidRoleException",
+            UpdateFleetError::LimitExceeded(_) => "LimitExceededException",
+            UpdateFleetError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            UpdateFleetError::RequestLimitExceeded(_) => "RequestLimitExceededException",
+            UpdateFleetError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateFleetError::ResourceNotAvailable(_) => "ResourceNotAvailableException",
+            UpdateFleetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateImagePermissions
 #[derive(Debug, PartialEq)]
 pub enum UpdateImagePermissionsError {
@@ -4169,6 +4629,15 @@ impl fmt::Display for UpdateImagePermissionsError {
     }
 }
 impl Error for UpdateImagePermissionsError {}
+impl ServiceErrorCode for UpdateImagePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateImagePermissionsError::LimitExceeded(_) => "LimitExceededException",
+            UpdateImagePermissionsError::ResourceNotAvailable(_) => "ResourceNotAvailableException",
+            UpdateImagePermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateStack
 #[derive(Debug, PartialEq)]
 pub enum UpdateStackError {
@@ -4249,6 +4718,23 @@ impl fmt::Display for UpdateStackError {
     }
 }
 impl Error for UpdateStackError {}
+impl ServiceErrorCode for UpdateStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateStackError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateStackError::IncompatibleImage(_) => "IncompatibleImageException",
+            UpdateStackError::InvalidAccountStatus(_) => "InvalidAccountStatusException",
+            UpdateStackError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            UpdateStackError::InvalidRole(_) => "InvalidRoleException",
+            UpdateStackError::LimitExceeded(_) => "LimitExceededException",
+            UpdateStackError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            UpdateStackError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateStackError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon AppStream API. Amazon AppStream clients implement this trait.
 #[async_trait]
 pub trait AppStream {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1712,6 +1712,17 @@ impl fmt::Display for CreateApiCacheError {
     }
 }
 impl Error for CreateApiCacheError {}
+impl ServiceErrorCode for CreateApiCacheError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApiCacheError::BadRequest(_) => "BadRequestException",
+            CreateApiCacheError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateApiCacheError::InternalFailure(_) => "InternalFailureException",
+            CreateApiCacheError::NotFound(_) => "NotFoundException",
+            CreateApiCacheError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateApiKey
 #[derive(Debug, PartialEq)]
 pub enum CreateApiKeyError {
@@ -1780,6 +1791,19 @@ impl fmt::Display for CreateApiKeyError {
     }
 }
 impl Error for CreateApiKeyError {}
+impl ServiceErrorCode for CreateApiKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApiKeyError::ApiKeyLimitExceeded(_) => "ApiKeyLimitExceededException",
+            CreateApiKeyError::ApiKeyValidityOutOfBounds(_) => "ApiKeyValidityOutOfBoundsException",
+            CreateApiKeyError::BadRequest(_) => "BadRequestException",
+            CreateApiKeyError::InternalFailure(_) => "InternalFailureException",
+            CreateApiKeyError::LimitExceeded(_) => "LimitExceededException",
+            CreateApiKeyError::NotFound(_) => "NotFoundException",
+            CreateApiKeyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateDataSource
 #[derive(Debug, PartialEq)]
 pub enum CreateDataSourceError {
@@ -1836,6 +1860,17 @@ impl fmt::Display for CreateDataSourceError {
     }
 }
 impl Error for CreateDataSourceError {}
+impl ServiceErrorCode for CreateDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDataSourceError::BadRequest(_) => "BadRequestException",
+            CreateDataSourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateDataSourceError::InternalFailure(_) => "InternalFailureException",
+            CreateDataSourceError::NotFound(_) => "NotFoundException",
+            CreateDataSourceError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateFunction
 #[derive(Debug, PartialEq)]
 pub enum CreateFunctionError {
@@ -1886,6 +1921,16 @@ impl fmt::Display for CreateFunctionError {
     }
 }
 impl Error for CreateFunctionError {}
+impl ServiceErrorCode for CreateFunctionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFunctionError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateFunctionError::InternalFailure(_) => "InternalFailureException",
+            CreateFunctionError::NotFound(_) => "NotFoundException",
+            CreateFunctionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateGraphqlApi
 #[derive(Debug, PartialEq)]
 pub enum CreateGraphqlApiError {
@@ -1948,6 +1993,18 @@ impl fmt::Display for CreateGraphqlApiError {
     }
 }
 impl Error for CreateGraphqlApiError {}
+impl ServiceErrorCode for CreateGraphqlApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGraphqlApiError::ApiLimitExceeded(_) => "ApiLimitExceededException",
+            CreateGraphqlApiError::BadRequest(_) => "BadRequestException",
+            CreateGraphqlApiError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateGraphqlApiError::InternalFailure(_) => "InternalFailureException",
+            CreateGraphqlApiError::LimitExceeded(_) => "LimitExceededException",
+            CreateGraphqlApiError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateResolver
 #[derive(Debug, PartialEq)]
 pub enum CreateResolverError {
@@ -1998,6 +2055,16 @@ impl fmt::Display for CreateResolverError {
     }
 }
 impl Error for CreateResolverError {}
+impl ServiceErrorCode for CreateResolverError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateResolverError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateResolverError::InternalFailure(_) => "InternalFailureException",
+            CreateResolverError::NotFound(_) => "NotFoundException",
+            CreateResolverError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateType
 #[derive(Debug, PartialEq)]
 pub enum CreateTypeError {
@@ -2052,6 +2119,17 @@ impl fmt::Display for CreateTypeError {
     }
 }
 impl Error for CreateTypeError {}
+impl ServiceErrorCode for CreateTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTypeError::BadRequest(_) => "BadRequestException",
+            CreateTypeError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateTypeError::InternalFailure(_) => "InternalFailureException",
+            CreateTypeError::NotFound(_) => "NotFoundException",
+            CreateTypeError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteApiCache
 #[derive(Debug, PartialEq)]
 pub enum DeleteApiCacheError {
@@ -2108,6 +2186,17 @@ impl fmt::Display for DeleteApiCacheError {
     }
 }
 impl Error for DeleteApiCacheError {}
+impl ServiceErrorCode for DeleteApiCacheError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApiCacheError::BadRequest(_) => "BadRequestException",
+            DeleteApiCacheError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteApiCacheError::InternalFailure(_) => "InternalFailureException",
+            DeleteApiCacheError::NotFound(_) => "NotFoundException",
+            DeleteApiCacheError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteApiKey
 #[derive(Debug, PartialEq)]
 pub enum DeleteApiKeyError {
@@ -2156,6 +2245,16 @@ impl fmt::Display for DeleteApiKeyError {
     }
 }
 impl Error for DeleteApiKeyError {}
+impl ServiceErrorCode for DeleteApiKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApiKeyError::BadRequest(_) => "BadRequestException",
+            DeleteApiKeyError::InternalFailure(_) => "InternalFailureException",
+            DeleteApiKeyError::NotFound(_) => "NotFoundException",
+            DeleteApiKeyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteDataSource
 #[derive(Debug, PartialEq)]
 pub enum DeleteDataSourceError {
@@ -2212,6 +2311,17 @@ impl fmt::Display for DeleteDataSourceError {
     }
 }
 impl Error for DeleteDataSourceError {}
+impl ServiceErrorCode for DeleteDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDataSourceError::BadRequest(_) => "BadRequestException",
+            DeleteDataSourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteDataSourceError::InternalFailure(_) => "InternalFailureException",
+            DeleteDataSourceError::NotFound(_) => "NotFoundException",
+            DeleteDataSourceError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteFunction
 #[derive(Debug, PartialEq)]
 pub enum DeleteFunctionError {
@@ -2262,6 +2372,16 @@ impl fmt::Display for DeleteFunctionError {
     }
 }
 impl Error for DeleteFunctionError {}
+impl ServiceErrorCode for DeleteFunctionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFunctionError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteFunctionError::InternalFailure(_) => "InternalFailureException",
+            DeleteFunctionError::NotFound(_) => "NotFoundException",
+            DeleteFunctionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteGraphqlApi
 #[derive(Debug, PartialEq)]
 pub enum DeleteGraphqlApiError {
@@ -2324,6 +2444,18 @@ impl fmt::Display for DeleteGraphqlApiError {
     }
 }
 impl Error for DeleteGraphqlApiError {}
+impl ServiceErrorCode for DeleteGraphqlApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGraphqlApiError::AccessDenied(_) => "AccessDeniedException",
+            DeleteGraphqlApiError::BadRequest(_) => "BadRequestException",
+            DeleteGraphqlApiError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteGraphqlApiError::InternalFailure(_) => "InternalFailureException",
+            DeleteGraphqlApiError::NotFound(_) => "NotFoundException",
+            DeleteGraphqlApiError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteResolver
 #[derive(Debug, PartialEq)]
 pub enum DeleteResolverError {
@@ -2374,6 +2506,16 @@ impl fmt::Display for DeleteResolverError {
     }
 }
 impl Error for DeleteResolverError {}
+impl ServiceErrorCode for DeleteResolverError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResolverError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteResolverError::InternalFailure(_) => "InternalFailureException",
+            DeleteResolverError::NotFound(_) => "NotFoundException",
+            DeleteResolverError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteType
 #[derive(Debug, PartialEq)]
 pub enum DeleteTypeError {
@@ -2428,6 +2570,17 @@ impl fmt::Display for DeleteTypeError {
     }
 }
 impl Error for DeleteTypeError {}
+impl ServiceErrorCode for DeleteTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTypeError::BadRequest(_) => "BadRequestException",
+            DeleteTypeError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteTypeError::InternalFailure(_) => "InternalFailureException",
+            DeleteTypeError::NotFound(_) => "NotFoundException",
+            DeleteTypeError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by FlushApiCache
 #[derive(Debug, PartialEq)]
 pub enum FlushApiCacheError {
@@ -2484,6 +2637,17 @@ impl fmt::Display for FlushApiCacheError {
     }
 }
 impl Error for FlushApiCacheError {}
+impl ServiceErrorCode for FlushApiCacheError {
+    fn error_code(&self) -> &str {
+        match *self {
+            FlushApiCacheError::BadRequest(_) => "BadRequestException",
+            FlushApiCacheError::ConcurrentModification(_) => "ConcurrentModificationException",
+            FlushApiCacheError::InternalFailure(_) => "InternalFailureException",
+            FlushApiCacheError::NotFound(_) => "NotFoundException",
+            FlushApiCacheError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetApiCache
 #[derive(Debug, PartialEq)]
 pub enum GetApiCacheError {
@@ -2538,6 +2702,17 @@ impl fmt::Display for GetApiCacheError {
     }
 }
 impl Error for GetApiCacheError {}
+impl ServiceErrorCode for GetApiCacheError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetApiCacheError::BadRequest(_) => "BadRequestException",
+            GetApiCacheError::ConcurrentModification(_) => "ConcurrentModificationException",
+            GetApiCacheError::InternalFailure(_) => "InternalFailureException",
+            GetApiCacheError::NotFound(_) => "NotFoundException",
+            GetApiCacheError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetDataSource
 #[derive(Debug, PartialEq)]
 pub enum GetDataSourceError {
@@ -2594,6 +2769,17 @@ impl fmt::Display for GetDataSourceError {
     }
 }
 impl Error for GetDataSourceError {}
+impl ServiceErrorCode for GetDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDataSourceError::BadRequest(_) => "BadRequestException",
+            GetDataSourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            GetDataSourceError::InternalFailure(_) => "InternalFailureException",
+            GetDataSourceError::NotFound(_) => "NotFoundException",
+            GetDataSourceError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetFunction
 #[derive(Debug, PartialEq)]
 pub enum GetFunctionError {
@@ -2636,6 +2822,15 @@ impl fmt::Display for GetFunctionError {
     }
 }
 impl Error for GetFunctionError {}
+impl ServiceErrorCode for GetFunctionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFunctionError::ConcurrentModification(_) => "ConcurrentModificationException",
+            GetFunctionError::NotFound(_) => "NotFoundException",
+            GetFunctionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetGraphqlApi
 #[derive(Debug, PartialEq)]
 pub enum GetGraphqlApiError {
@@ -2690,6 +2885,17 @@ impl fmt::Display for GetGraphqlApiError {
     }
 }
 impl Error for GetGraphqlApiError {}
+impl ServiceErrorCode for GetGraphqlApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGraphqlApiError::AccessDenied(_) => "AccessDeniedException",
+            GetGraphqlApiError::BadRequest(_) => "BadRequestException",
+            GetGraphqlApiError::InternalFailure(_) => "InternalFailureException",
+            GetGraphqlApiError::NotFound(_) => "NotFoundException",
+            GetGraphqlApiError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetIntrospectionSchema
 #[derive(Debug, PartialEq)]
 pub enum GetIntrospectionSchemaError {
@@ -2742,6 +2948,16 @@ impl fmt::Display for GetIntrospectionSchemaError {
     }
 }
 impl Error for GetIntrospectionSchemaError {}
+impl ServiceErrorCode for GetIntrospectionSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIntrospectionSchemaError::GraphQLSchema(_) => "GraphQLSchemaException",
+            GetIntrospectionSchemaError::InternalFailure(_) => "InternalFailureException",
+            GetIntrospectionSchemaError::NotFound(_) => "NotFoundException",
+            GetIntrospectionSchemaError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetResolver
 #[derive(Debug, PartialEq)]
 pub enum GetResolverError {
@@ -2784,6 +3000,15 @@ impl fmt::Display for GetResolverError {
     }
 }
 impl Error for GetResolverError {}
+impl ServiceErrorCode for GetResolverError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResolverError::ConcurrentModification(_) => "ConcurrentModificationException",
+            GetResolverError::NotFound(_) => "NotFoundException",
+            GetResolverError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetSchemaCreationStatus
 #[derive(Debug, PartialEq)]
 pub enum GetSchemaCreationStatusError {
@@ -2836,6 +3061,16 @@ impl fmt::Display for GetSchemaCreationStatusError {
     }
 }
 impl Error for GetSchemaCreationStatusError {}
+impl ServiceErrorCode for GetSchemaCreationStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSchemaCreationStatusError::BadRequest(_) => "BadRequestException",
+            GetSchemaCreationStatusError::InternalFailure(_) => "InternalFailureException",
+            GetSchemaCreationStatusError::NotFound(_) => "NotFoundException",
+            GetSchemaCreationStatusError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetType
 #[derive(Debug, PartialEq)]
 pub enum GetTypeError {
@@ -2890,6 +3125,17 @@ impl fmt::Display for GetTypeError {
     }
 }
 impl Error for GetTypeError {}
+impl ServiceErrorCode for GetTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTypeError::BadRequest(_) => "BadRequestException",
+            GetTypeError::ConcurrentModification(_) => "ConcurrentModificationException",
+            GetTypeError::InternalFailure(_) => "InternalFailureException",
+            GetTypeError::NotFound(_) => "NotFoundException",
+            GetTypeError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListApiKeys
 #[derive(Debug, PartialEq)]
 pub enum ListApiKeysError {
@@ -2938,6 +3184,16 @@ impl fmt::Display for ListApiKeysError {
     }
 }
 impl Error for ListApiKeysError {}
+impl ServiceErrorCode for ListApiKeysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListApiKeysError::BadRequest(_) => "BadRequestException",
+            ListApiKeysError::InternalFailure(_) => "InternalFailureException",
+            ListApiKeysError::NotFound(_) => "NotFoundException",
+            ListApiKeysError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListDataSources
 #[derive(Debug, PartialEq)]
 pub enum ListDataSourcesError {
@@ -2986,6 +3242,16 @@ impl fmt::Display for ListDataSourcesError {
     }
 }
 impl Error for ListDataSourcesError {}
+impl ServiceErrorCode for ListDataSourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDataSourcesError::BadRequest(_) => "BadRequestException",
+            ListDataSourcesError::InternalFailure(_) => "InternalFailureException",
+            ListDataSourcesError::NotFound(_) => "NotFoundException",
+            ListDataSourcesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListFunctions
 #[derive(Debug, PartialEq)]
 pub enum ListFunctionsError {
@@ -3034,6 +3300,16 @@ impl fmt::Display for ListFunctionsError {
     }
 }
 impl Error for ListFunctionsError {}
+impl ServiceErrorCode for ListFunctionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFunctionsError::BadRequest(_) => "BadRequestException",
+            ListFunctionsError::InternalFailure(_) => "InternalFailureException",
+            ListFunctionsError::NotFound(_) => "NotFoundException",
+            ListFunctionsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListGraphqlApis
 #[derive(Debug, PartialEq)]
 pub enum ListGraphqlApisError {
@@ -3076,6 +3352,15 @@ impl fmt::Display for ListGraphqlApisError {
     }
 }
 impl Error for ListGraphqlApisError {}
+impl ServiceErrorCode for ListGraphqlApisError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGraphqlApisError::BadRequest(_) => "BadRequestException",
+            ListGraphqlApisError::InternalFailure(_) => "InternalFailureException",
+            ListGraphqlApisError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListResolvers
 #[derive(Debug, PartialEq)]
 pub enum ListResolversError {
@@ -3124,6 +3409,16 @@ impl fmt::Display for ListResolversError {
     }
 }
 impl Error for ListResolversError {}
+impl ServiceErrorCode for ListResolversError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResolversError::BadRequest(_) => "BadRequestException",
+            ListResolversError::InternalFailure(_) => "InternalFailureException",
+            ListResolversError::NotFound(_) => "NotFoundException",
+            ListResolversError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListResolversByFunction
 #[derive(Debug, PartialEq)]
 pub enum ListResolversByFunctionError {
@@ -3176,6 +3471,16 @@ impl fmt::Display for ListResolversByFunctionError {
     }
 }
 impl Error for ListResolversByFunctionError {}
+impl ServiceErrorCode for ListResolversByFunctionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResolversByFunctionError::BadRequest(_) => "BadRequestException",
+            ListResolversByFunctionError::InternalFailure(_) => "InternalFailureException",
+            ListResolversByFunctionError::NotFound(_) => "NotFoundException",
+            ListResolversByFunctionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3236,6 +3541,18 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::InternalFailure(_) => "InternalFailureException",
+            ListTagsForResourceError::LimitExceeded(_) => "LimitExceededException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+            ListTagsForResourceError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListTypes
 #[derive(Debug, PartialEq)]
 pub enum ListTypesError {
@@ -3290,6 +3607,17 @@ impl fmt::Display for ListTypesError {
     }
 }
 impl Error for ListTypesError {}
+impl ServiceErrorCode for ListTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTypesError::BadRequest(_) => "BadRequestException",
+            ListTypesError::ConcurrentModification(_) => "ConcurrentModificationException",
+            ListTypesError::InternalFailure(_) => "InternalFailureException",
+            ListTypesError::NotFound(_) => "NotFoundException",
+            ListTypesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by StartSchemaCreation
 #[derive(Debug, PartialEq)]
 pub enum StartSchemaCreationError {
@@ -3346,6 +3674,19 @@ impl fmt::Display for StartSchemaCreationError {
     }
 }
 impl Error for StartSchemaCreationError {}
+impl ServiceErrorCode for StartSchemaCreationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartSchemaCreationError::BadRequest(_) => "BadRequestException",
+            StartSchemaCreationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            StartSchemaCreationError::InternalFailure(_) => "InternalFailureException",
+            StartSchemaCreationError::NotFound(_) => "NotFoundException",
+            StartSchemaCreationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -3406,6 +3747,18 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AccessDenied(_) => "AccessDeniedException",
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::InternalFailure(_) => "InternalFailureException",
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+            TagResourceError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3466,6 +3819,18 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AccessDenied(_) => "AccessDeniedException",
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::InternalFailure(_) => "InternalFailureException",
+            UntagResourceError::LimitExceeded(_) => "LimitExceededException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+            UntagResourceError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateApiCache
 #[derive(Debug, PartialEq)]
 pub enum UpdateApiCacheError {
@@ -3522,6 +3887,17 @@ impl fmt::Display for UpdateApiCacheError {
     }
 }
 impl Error for UpdateApiCacheError {}
+impl ServiceErrorCode for UpdateApiCacheError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApiCacheError::BadRequest(_) => "BadRequestException",
+            UpdateApiCacheError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateApiCacheError::InternalFailure(_) => "InternalFailureException",
+            UpdateApiCacheError::NotFound(_) => "NotFoundException",
+            UpdateApiCacheError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateApiKey
 #[derive(Debug, PartialEq)]
 pub enum UpdateApiKeyError {
@@ -3584,6 +3960,18 @@ impl fmt::Display for UpdateApiKeyError {
     }
 }
 impl Error for UpdateApiKeyError {}
+impl ServiceErrorCode for UpdateApiKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApiKeyError::ApiKeyValidityOutOfBounds(_) => "ApiKeyValidityOutOfBoundsException",
+            UpdateApiKeyError::BadRequest(_) => "BadRequestException",
+            UpdateApiKeyError::InternalFailure(_) => "InternalFailureException",
+            UpdateApiKeyError::LimitExceeded(_) => "LimitExceededException",
+            UpdateApiKeyError::NotFound(_) => "NotFoundException",
+            UpdateApiKeyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateDataSource
 #[derive(Debug, PartialEq)]
 pub enum UpdateDataSourceError {
@@ -3640,6 +4028,17 @@ impl fmt::Display for UpdateDataSourceError {
     }
 }
 impl Error for UpdateDataSourceError {}
+impl ServiceErrorCode for UpdateDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDataSourceError::BadRequest(_) => "BadRequestException",
+            UpdateDataSourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateDataSourceError::InternalFailure(_) => "InternalFailureException",
+            UpdateDataSourceError::NotFound(_) => "NotFoundException",
+            UpdateDataSourceError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateFunction
 #[derive(Debug, PartialEq)]
 pub enum UpdateFunctionError {
@@ -3690,6 +4089,16 @@ impl fmt::Display for UpdateFunctionError {
     }
 }
 impl Error for UpdateFunctionError {}
+impl ServiceErrorCode for UpdateFunctionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFunctionError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateFunctionError::InternalFailure(_) => "InternalFailureException",
+            UpdateFunctionError::NotFound(_) => "NotFoundException",
+            UpdateFunctionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateGraphqlApi
 #[derive(Debug, PartialEq)]
 pub enum UpdateGraphqlApiError {
@@ -3752,6 +4161,18 @@ impl fmt::Display for UpdateGraphqlApiError {
     }
 }
 impl Error for UpdateGraphqlApiError {}
+impl ServiceErrorCode for UpdateGraphqlApiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGraphqlApiError::AccessDenied(_) => "AccessDeniedException",
+            UpdateGraphqlApiError::BadRequest(_) => "BadRequestException",
+            UpdateGraphqlApiError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateGraphqlApiError::InternalFailure(_) => "InternalFailureException",
+            UpdateGraphqlApiError::NotFound(_) => "NotFoundException",
+            UpdateGraphqlApiError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateResolver
 #[derive(Debug, PartialEq)]
 pub enum UpdateResolverError {
@@ -3802,6 +4223,16 @@ impl fmt::Display for UpdateResolverError {
     }
 }
 impl Error for UpdateResolverError {}
+impl ServiceErrorCode for UpdateResolverError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateResolverError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateResolverError::InternalFailure(_) => "InternalFailureException",
+            UpdateResolverError::NotFound(_) => "NotFoundException",
+            UpdateResolverError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateType
 #[derive(Debug, PartialEq)]
 pub enum UpdateTypeError {
@@ -3856,6 +4287,17 @@ impl fmt::Display for UpdateTypeError {
     }
 }
 impl Error for UpdateTypeError {}
+impl ServiceErrorCode for UpdateTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTypeError::BadRequest(_) => "BadRequestException",
+            UpdateTypeError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateTypeError::InternalFailure(_) => "InternalFailureException",
+            UpdateTypeError::NotFound(_) => "NotFoundException",
+            UpdateTypeError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWSAppSync API. AWSAppSync clients implement this trait.
 #[async_trait]
 pub trait AppSync {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -184,6 +184,7 @@ pub struct CreateDataCatalogOutput {}
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateNamedQueryInput {
     /// <p><p>A unique case-sensitive string used to ensure the request to create the query is idempotent (executes only once). If another <code>CreateNamedQuery</code> request is received, the same response is returned and another query is not created. If a parameter has changed, for example, the <code>QueryString</code>, an error is returned.</p> <important> <p>This token is listed as not required because AWS SDKs (for example the AWS SDK for Java) auto-generate the token for users. If you are not using the AWS SDK or the AWS CLI, you must provide this token or the action will fail.</p> </important></p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -316,6 +317,7 @@ pub struct DeleteDataCatalogOutput {}
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteNamedQueryInput {
     /// <p>The unique ID of the query to delete.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "NamedQueryId")]
     pub named_query_id: String,
 }
@@ -909,6 +911,7 @@ pub struct Row {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartQueryExecutionInput {
     /// <p><p>A unique case-sensitive string used to ensure the request to create the query is idempotent (executes only once). If another <code>StartQueryExecution</code> request is received, the same response is returned and another query is not created. If a parameter has changed, for example, the <code>QueryString</code>, an error is returned.</p> <important> <p>This token is listed as not required because AWS SDKs (for example the AWS SDK for Java) auto-generate the token for users. If you are not using the AWS SDK or the AWS CLI, you must provide this token or the action will fail.</p> </important></p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -942,6 +945,7 @@ pub struct StartQueryExecutionOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StopQueryExecutionInput {
     /// <p>The unique ID of the query execution to stop.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "QueryExecutionId")]
     pub query_execution_id: String,
 }
@@ -1247,6 +1251,14 @@ impl fmt::Display for BatchGetNamedQueryError {
     }
 }
 impl Error for BatchGetNamedQueryError {}
+impl ServiceErrorCode for BatchGetNamedQueryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetNamedQueryError::InternalServer(_) => "InternalServerException",
+            BatchGetNamedQueryError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by BatchGetQueryExecution
 #[derive(Debug, PartialEq)]
 pub enum BatchGetQueryExecutionError {
@@ -1287,6 +1299,14 @@ impl fmt::Display for BatchGetQueryExecutionError {
     }
 }
 impl Error for BatchGetQueryExecutionError {}
+impl ServiceErrorCode for BatchGetQueryExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetQueryExecutionError::InternalServer(_) => "InternalServerException",
+            BatchGetQueryExecutionError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateDataCatalog
 #[derive(Debug, PartialEq)]
 pub enum CreateDataCatalogError {
@@ -1323,6 +1343,14 @@ impl fmt::Display for CreateDataCatalogError {
     }
 }
 impl Error for CreateDataCatalogError {}
+impl ServiceErrorCode for CreateDataCatalogError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDataCatalogError::InternalServer(_) => "InternalServerException",
+            CreateDataCatalogError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateNamedQuery
 #[derive(Debug, PartialEq)]
 pub enum CreateNamedQueryError {
@@ -1359,6 +1387,14 @@ impl fmt::Display for CreateNamedQueryError {
     }
 }
 impl Error for CreateNamedQueryError {}
+impl ServiceErrorCode for CreateNamedQueryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateNamedQueryError::InternalServer(_) => "InternalServerException",
+            CreateNamedQueryError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateWorkGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateWorkGroupError {
@@ -1395,6 +1431,14 @@ impl fmt::Display for CreateWorkGroupError {
     }
 }
 impl Error for CreateWorkGroupError {}
+impl ServiceErrorCode for CreateWorkGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateWorkGroupError::InternalServer(_) => "InternalServerException",
+            CreateWorkGroupError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteDataCatalog
 #[derive(Debug, PartialEq)]
 pub enum DeleteDataCatalogError {
@@ -1431,6 +1475,14 @@ impl fmt::Display for DeleteDataCatalogError {
     }
 }
 impl Error for DeleteDataCatalogError {}
+impl ServiceErrorCode for DeleteDataCatalogError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDataCatalogError::InternalServer(_) => "InternalServerException",
+            DeleteDataCatalogError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteNamedQuery
 #[derive(Debug, PartialEq)]
 pub enum DeleteNamedQueryError {
@@ -1467,6 +1519,14 @@ impl fmt::Display for DeleteNamedQueryError {
     }
 }
 impl Error for DeleteNamedQueryError {}
+impl ServiceErrorCode for DeleteNamedQueryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteNamedQueryError::InternalServer(_) => "InternalServerException",
+            DeleteNamedQueryError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteWorkGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteWorkGroupError {
@@ -1503,6 +1563,14 @@ impl fmt::Display for DeleteWorkGroupError {
     }
 }
 impl Error for DeleteWorkGroupError {}
+impl ServiceErrorCode for DeleteWorkGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteWorkGroupError::InternalServer(_) => "InternalServerException",
+            DeleteWorkGroupError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by GetDataCatalog
 #[derive(Debug, PartialEq)]
 pub enum GetDataCatalogError {
@@ -1539,6 +1607,14 @@ impl fmt::Display for GetDataCatalogError {
     }
 }
 impl Error for GetDataCatalogError {}
+impl ServiceErrorCode for GetDataCatalogError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDataCatalogError::InternalServer(_) => "InternalServerException",
+            GetDataCatalogError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by GetDatabase
 #[derive(Debug, PartialEq)]
 pub enum GetDatabaseError {
@@ -1581,6 +1657,15 @@ impl fmt::Display for GetDatabaseError {
     }
 }
 impl Error for GetDatabaseError {}
+impl ServiceErrorCode for GetDatabaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDatabaseError::InternalServer(_) => "InternalServerException",
+            GetDatabaseError::InvalidRequest(_) => "InvalidRequestException",
+            GetDatabaseError::Metadata(_) => "MetadataException",
+        }
+    }
+}
 /// Errors returned by GetNamedQuery
 #[derive(Debug, PartialEq)]
 pub enum GetNamedQueryError {
@@ -1617,6 +1702,14 @@ impl fmt::Display for GetNamedQueryError {
     }
 }
 impl Error for GetNamedQueryError {}
+impl ServiceErrorCode for GetNamedQueryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetNamedQueryError::InternalServer(_) => "InternalServerException",
+            GetNamedQueryError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by GetQueryExecution
 #[derive(Debug, PartialEq)]
 pub enum GetQueryExecutionError {
@@ -1653,6 +1746,14 @@ impl fmt::Display for GetQueryExecutionError {
     }
 }
 impl Error for GetQueryExecutionError {}
+impl ServiceErrorCode for GetQueryExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetQueryExecutionError::InternalServer(_) => "InternalServerException",
+            GetQueryExecutionError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by GetQueryResults
 #[derive(Debug, PartialEq)]
 pub enum GetQueryResultsError {
@@ -1689,6 +1790,14 @@ impl fmt::Display for GetQueryResultsError {
     }
 }
 impl Error for GetQueryResultsError {}
+impl ServiceErrorCode for GetQueryResultsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetQueryResultsError::InternalServer(_) => "InternalServerException",
+            GetQueryResultsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by GetTableMetadata
 #[derive(Debug, PartialEq)]
 pub enum GetTableMetadataError {
@@ -1731,6 +1840,15 @@ impl fmt::Display for GetTableMetadataError {
     }
 }
 impl Error for GetTableMetadataError {}
+impl ServiceErrorCode for GetTableMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTableMetadataError::InternalServer(_) => "InternalServerException",
+            GetTableMetadataError::InvalidRequest(_) => "InvalidRequestException",
+            GetTableMetadataError::Metadata(_) => "MetadataException",
+        }
+    }
+}
 /// Errors returned by GetWorkGroup
 #[derive(Debug, PartialEq)]
 pub enum GetWorkGroupError {
@@ -1767,6 +1885,14 @@ impl fmt::Display for GetWorkGroupError {
     }
 }
 impl Error for GetWorkGroupError {}
+impl ServiceErrorCode for GetWorkGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetWorkGroupError::InternalServer(_) => "InternalServerException",
+            GetWorkGroupError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListDataCatalogs
 #[derive(Debug, PartialEq)]
 pub enum ListDataCatalogsError {
@@ -1803,6 +1929,14 @@ impl fmt::Display for ListDataCatalogsError {
     }
 }
 impl Error for ListDataCatalogsError {}
+impl ServiceErrorCode for ListDataCatalogsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDataCatalogsError::InternalServer(_) => "InternalServerException",
+            ListDataCatalogsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListDatabases
 #[derive(Debug, PartialEq)]
 pub enum ListDatabasesError {
@@ -1845,6 +1979,15 @@ impl fmt::Display for ListDatabasesError {
     }
 }
 impl Error for ListDatabasesError {}
+impl ServiceErrorCode for ListDatabasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDatabasesError::InternalServer(_) => "InternalServerException",
+            ListDatabasesError::InvalidRequest(_) => "InvalidRequestException",
+            ListDatabasesError::Metadata(_) => "MetadataException",
+        }
+    }
+}
 /// Errors returned by ListNamedQueries
 #[derive(Debug, PartialEq)]
 pub enum ListNamedQueriesError {
@@ -1881,6 +2024,14 @@ impl fmt::Display for ListNamedQueriesError {
     }
 }
 impl Error for ListNamedQueriesError {}
+impl ServiceErrorCode for ListNamedQueriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListNamedQueriesError::InternalServer(_) => "InternalServerException",
+            ListNamedQueriesError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListQueryExecutions
 #[derive(Debug, PartialEq)]
 pub enum ListQueryExecutionsError {
@@ -1917,6 +2068,14 @@ impl fmt::Display for ListQueryExecutionsError {
     }
 }
 impl Error for ListQueryExecutionsError {}
+impl ServiceErrorCode for ListQueryExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListQueryExecutionsError::InternalServer(_) => "InternalServerException",
+            ListQueryExecutionsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListTableMetadata
 #[derive(Debug, PartialEq)]
 pub enum ListTableMetadataError {
@@ -1959,6 +2118,15 @@ impl fmt::Display for ListTableMetadataError {
     }
 }
 impl Error for ListTableMetadataError {}
+impl ServiceErrorCode for ListTableMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTableMetadataError::InternalServer(_) => "InternalServerException",
+            ListTableMetadataError::InvalidRequest(_) => "InvalidRequestException",
+            ListTableMetadataError::Metadata(_) => "MetadataException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2003,6 +2171,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalServer(_) => "InternalServerException",
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListWorkGroups
 #[derive(Debug, PartialEq)]
 pub enum ListWorkGroupsError {
@@ -2039,6 +2216,14 @@ impl fmt::Display for ListWorkGroupsError {
     }
 }
 impl Error for ListWorkGroupsError {}
+impl ServiceErrorCode for ListWorkGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListWorkGroupsError::InternalServer(_) => "InternalServerException",
+            ListWorkGroupsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by StartQueryExecution
 #[derive(Debug, PartialEq)]
 pub enum StartQueryExecutionError {
@@ -2081,6 +2266,15 @@ impl fmt::Display for StartQueryExecutionError {
     }
 }
 impl Error for StartQueryExecutionError {}
+impl ServiceErrorCode for StartQueryExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartQueryExecutionError::InternalServer(_) => "InternalServerException",
+            StartQueryExecutionError::InvalidRequest(_) => "InvalidRequestException",
+            StartQueryExecutionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StopQueryExecution
 #[derive(Debug, PartialEq)]
 pub enum StopQueryExecutionError {
@@ -2117,6 +2311,14 @@ impl fmt::Display for StopQueryExecutionError {
     }
 }
 impl Error for StopQueryExecutionError {}
+impl ServiceErrorCode for StopQueryExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopQueryExecutionError::InternalServer(_) => "InternalServerException",
+            StopQueryExecutionError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2159,6 +2361,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalServer(_) => "InternalServerException",
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2201,6 +2412,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalServer(_) => "InternalServerException",
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateDataCatalog
 #[derive(Debug, PartialEq)]
 pub enum UpdateDataCatalogError {
@@ -2237,6 +2457,14 @@ impl fmt::Display for UpdateDataCatalogError {
     }
 }
 impl Error for UpdateDataCatalogError {}
+impl ServiceErrorCode for UpdateDataCatalogError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDataCatalogError::InternalServer(_) => "InternalServerException",
+            UpdateDataCatalogError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateWorkGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateWorkGroupError {
@@ -2273,6 +2501,14 @@ impl fmt::Display for UpdateWorkGroupError {
     }
 }
 impl Error for UpdateWorkGroupError {}
+impl ServiceErrorCode for UpdateWorkGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateWorkGroupError::InternalServer(_) => "InternalServerException",
+            UpdateWorkGroupError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Athena API. Amazon Athena clients implement this trait.
 #[async_trait]
 pub trait Athena {
@@ -2544,6 +2780,10 @@ impl Athena for AthenaClient {
         &self,
         input: CreateNamedQueryInput,
     ) -> Result<CreateNamedQueryOutput, RusotoError<CreateNamedQueryError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AmazonAthena.CreateNamedQuery");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -2598,6 +2838,12 @@ impl Athena for AthenaClient {
         &self,
         input: DeleteNamedQueryInput,
     ) -> Result<DeleteNamedQueryOutput, RusotoError<DeleteNamedQueryError>> {
+        let mut input = input;
+        if input.named_query_id.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.named_query_id = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AmazonAthena.DeleteNamedQuery");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -2886,6 +3132,10 @@ impl Athena for AthenaClient {
         &self,
         input: StartQueryExecutionInput,
     ) -> Result<StartQueryExecutionOutput, RusotoError<StartQueryExecutionError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AmazonAthena.StartQueryExecution");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -2904,6 +3154,12 @@ impl Athena for AthenaClient {
         &self,
         input: StopQueryExecutionInput,
     ) -> Result<StopQueryExecutionOutput, RusotoError<StopQueryExecutionError>> {
+        let mut input = input;
+        if input.query_execution_id.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.query_execution_id = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AmazonAthena.StopQueryExecution");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -547,6 +547,15 @@ impl fmt::Display for CreateScalingPlanError {
     }
 }
 impl Error for CreateScalingPlanError {}
+impl ServiceErrorCode for CreateScalingPlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateScalingPlanError::ConcurrentUpdate(_) => "ConcurrentUpdateException",
+            CreateScalingPlanError::InternalService(_) => "InternalServiceException",
+            CreateScalingPlanError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DeleteScalingPlan
 #[derive(Debug, PartialEq)]
 pub enum DeleteScalingPlanError {
@@ -589,6 +598,15 @@ impl fmt::Display for DeleteScalingPlanError {
     }
 }
 impl Error for DeleteScalingPlanError {}
+impl ServiceErrorCode for DeleteScalingPlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteScalingPlanError::ConcurrentUpdate(_) => "ConcurrentUpdateException",
+            DeleteScalingPlanError::InternalService(_) => "InternalServiceException",
+            DeleteScalingPlanError::ObjectNotFound(_) => "ObjectNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeScalingPlanResources
 #[derive(Debug, PartialEq)]
 pub enum DescribeScalingPlanResourcesError {
@@ -643,6 +661,15 @@ impl fmt::Display for DescribeScalingPlanResourcesError {
     }
 }
 impl Error for DescribeScalingPlanResourcesError {}
+impl ServiceErrorCode for DescribeScalingPlanResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeScalingPlanResourcesError::ConcurrentUpdate(_) => "ConcurrentUpdateException",
+            DescribeScalingPlanResourcesError::InternalService(_) => "InternalServiceException",
+            DescribeScalingPlanResourcesError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by DescribeScalingPlans
 #[derive(Debug, PartialEq)]
 pub enum DescribeScalingPlansError {
@@ -691,6 +718,15 @@ impl fmt::Display for DescribeScalingPlansError {
     }
 }
 impl Error for DescribeScalingPlansError {}
+impl ServiceErrorCode for DescribeScalingPlansError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeScalingPlansError::ConcurrentUpdate(_) => "ConcurrentUpdateException",
+            DescribeScalingPlansError::InternalService(_) => "InternalServiceException",
+            DescribeScalingPlansError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by GetScalingPlanResourceForecastData
 #[derive(Debug, PartialEq)]
 pub enum GetScalingPlanResourceForecastDataError {
@@ -727,6 +763,15 @@ impl fmt::Display for GetScalingPlanResourceForecastDataError {
     }
 }
 impl Error for GetScalingPlanResourceForecastDataError {}
+impl ServiceErrorCode for GetScalingPlanResourceForecastDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetScalingPlanResourceForecastDataError::InternalService(_) => {
+                "InternalServiceException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateScalingPlan
 #[derive(Debug, PartialEq)]
 pub enum UpdateScalingPlanError {
@@ -769,6 +814,15 @@ impl fmt::Display for UpdateScalingPlanError {
     }
 }
 impl Error for UpdateScalingPlanError {}
+impl ServiceErrorCode for UpdateScalingPlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateScalingPlanError::ConcurrentUpdate(_) => "ConcurrentUpdateException",
+            UpdateScalingPlanError::InternalService(_) => "InternalServiceException",
+            UpdateScalingPlanError::ObjectNotFound(_) => "ObjectNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Auto Scaling Plans API. AWS Auto Scaling Plans clients implement this trait.
 #[async_trait]
 pub trait AutoscalingPlans {
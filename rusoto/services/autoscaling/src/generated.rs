@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -7685,6 +7685,14 @@ impl fmt::Display for AttachInstancesError {
     }
 }
 impl Error for AttachInstancesError {}
+impl ServiceErrorCode for AttachInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachInstancesError::ResourceContentionFault(_) => "ResourceContention",
+            AttachInstancesError::ServiceLinkedRoleFailure(_) => "ServiceLinkedRoleFailure",
+        }
+    }
+}
 /// Errors returned by AttachLoadBalancerTargetGroups
 #[derive(Debug, PartialEq)]
 pub enum AttachLoadBalancerTargetGroupsError {
@@ -7747,6 +7755,16 @@ impl fmt::Display for AttachLoadBalancerTargetGroupsError {
     }
 }
 impl Error for AttachLoadBalancerTargetGroupsError {}
+impl ServiceErrorCode for AttachLoadBalancerTargetGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachLoadBalancerTargetGroupsError::ResourceContentionFault(_) => "ResourceContention",
+            AttachLoadBalancerTargetGroupsError::ServiceLinkedRoleFailure(_) => {
+                "ServiceLinkedRoleFailure"
+            }
+        }
+    }
+}
 /// Errors returned by AttachLoadBalancers
 #[derive(Debug, PartialEq)]
 pub enum AttachLoadBalancersError {
@@ -7801,6 +7819,14 @@ impl fmt::Display for AttachLoadBalancersError {
     }
 }
 impl Error for AttachLoadBalancersError {}
+impl ServiceErrorCode for AttachLoadBalancersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachLoadBalancersError::ResourceContentionFault(_) => "ResourceContention",
+            AttachLoadBalancersError::ServiceLinkedRoleFailure(_) => "ServiceLinkedRoleFailure",
+        }
+    }
+}
 /// Errors returned by BatchDeleteScheduledAction
 #[derive(Debug, PartialEq)]
 pub enum BatchDeleteScheduledActionError {
@@ -7851,6 +7877,13 @@ impl fmt::Display for BatchDeleteScheduledActionError {
     }
 }
 impl Error for BatchDeleteScheduledActionError {}
+impl ServiceErrorCode for BatchDeleteScheduledActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDeleteScheduledActionError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by BatchPutScheduledUpdateGroupAction
 #[derive(Debug, PartialEq)]
 pub enum BatchPutScheduledUpdateGroupActionError {
@@ -7925,6 +7958,17 @@ impl fmt::Display for BatchPutScheduledUpdateGroupActionError {
     }
 }
 impl Error for BatchPutScheduledUpdateGroupActionError {}
+impl ServiceErrorCode for BatchPutScheduledUpdateGroupActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchPutScheduledUpdateGroupActionError::AlreadyExistsFault(_) => "AlreadyExists",
+            BatchPutScheduledUpdateGroupActionError::LimitExceededFault(_) => "LimitExceeded",
+            BatchPutScheduledUpdateGroupActionError::ResourceContentionFault(_) => {
+                "ResourceContention"
+            }
+        }
+    }
+}
 /// Errors returned by CancelInstanceRefresh
 #[derive(Debug, PartialEq)]
 pub enum CancelInstanceRefreshError {
@@ -7993,6 +8037,17 @@ impl fmt::Display for CancelInstanceRefreshError {
     }
 }
 impl Error for CancelInstanceRefreshError {}
+impl ServiceErrorCode for CancelInstanceRefreshError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelInstanceRefreshError::ActiveInstanceRefreshNotFoundFault(_) => {
+                "ActiveInstanceRefreshNotFound"
+            }
+            CancelInstanceRefreshError::LimitExceededFault(_) => "LimitExceeded",
+            CancelInstanceRefreshError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by CompleteLifecycleAction
 #[derive(Debug, PartialEq)]
 pub enum CompleteLifecycleActionError {
@@ -8041,6 +8096,13 @@ impl fmt::Display for CompleteLifecycleActionError {
     }
 }
 impl Error for CompleteLifecycleActionError {}
+impl ServiceErrorCode for CompleteLifecycleActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CompleteLifecycleActionError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by CreateAutoScalingGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateAutoScalingGroupError {
@@ -8117,6 +8179,16 @@ impl fmt::Display for CreateAutoScalingGroupError {
     }
 }
 impl Error for CreateAutoScalingGroupError {}
+impl ServiceErrorCode for CreateAutoScalingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAutoScalingGroupError::AlreadyExistsFault(_) => "AlreadyExists",
+            CreateAutoScalingGroupError::LimitExceededFault(_) => "LimitExceeded",
+            CreateAutoScalingGroupError::ResourceContentionFault(_) => "ResourceContention",
+            CreateAutoScalingGroupError::ServiceLinkedRoleFailure(_) => "ServiceLinkedRoleFailure",
+        }
+    }
+}
 /// Errors returned by CreateLaunchConfiguration
 #[derive(Debug, PartialEq)]
 pub enum CreateLaunchConfigurationError {
@@ -8185,6 +8257,15 @@ impl fmt::Display for CreateLaunchConfigurationError {
     }
 }
 impl Error for CreateLaunchConfigurationError {}
+impl ServiceErrorCode for CreateLaunchConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLaunchConfigurationError::AlreadyExistsFault(_) => "AlreadyExists",
+            CreateLaunchConfigurationError::LimitExceededFault(_) => "LimitExceeded",
+            CreateLaunchConfigurationError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by CreateOrUpdateTags
 #[derive(Debug, PartialEq)]
 pub enum CreateOrUpdateTagsError {
@@ -8253,6 +8334,16 @@ impl fmt::Display for CreateOrUpdateTagsError {
     }
 }
 impl Error for CreateOrUpdateTagsError {}
+impl ServiceErrorCode for CreateOrUpdateTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateOrUpdateTagsError::AlreadyExistsFault(_) => "AlreadyExists",
+            CreateOrUpdateTagsError::LimitExceededFault(_) => "LimitExceeded",
+            CreateOrUpdateTagsError::ResourceContentionFault(_) => "ResourceContention",
+            CreateOrUpdateTagsError::ResourceInUseFault(_) => "ResourceInUse",
+        }
+    }
+}
 /// Errors returned by DeleteAutoScalingGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteAutoScalingGroupError {
@@ -8321,6 +8412,17 @@ impl fmt::Display for DeleteAutoScalingGroupError {
     }
 }
 impl Error for DeleteAutoScalingGroupError {}
+impl ServiceErrorCode for DeleteAutoScalingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAutoScalingGroupError::ResourceContentionFault(_) => "ResourceContention",
+            DeleteAutoScalingGroupError::ResourceInUseFault(_) => "ResourceInUse",
+            DeleteAutoScalingGroupError::ScalingActivityInProgressFault(_) => {
+                "ScalingActivityInProgress"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteLaunchConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteLaunchConfigurationError {
@@ -8379,6 +8481,14 @@ impl fmt::Display for DeleteLaunchConfigurationError {
     }
 }
 impl Error for DeleteLaunchConfigurationError {}
+impl ServiceErrorCode for DeleteLaunchConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLaunchConfigurationError::ResourceContentionFault(_) => "ResourceContention",
+            DeleteLaunchConfigurationError::ResourceInUseFault(_) => "ResourceInUse",
+        }
+    }
+}
 /// Errors returned by DeleteLifecycleHook
 #[derive(Debug, PartialEq)]
 pub enum DeleteLifecycleHookError {
@@ -8423,6 +8533,13 @@ impl fmt::Display for DeleteLifecycleHookError {
     }
 }
 impl Error for DeleteLifecycleHookError {}
+impl ServiceErrorCode for DeleteLifecycleHookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLifecycleHookError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DeleteNotificationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteNotificationConfigurationError {
@@ -8473,6 +8590,15 @@ impl fmt::Display for DeleteNotificationConfigurationError {
     }
 }
 impl Error for DeleteNotificationConfigurationError {}
+impl ServiceErrorCode for DeleteNotificationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteNotificationConfigurationError::ResourceContentionFault(_) => {
+                "ResourceContention"
+            }
+        }
+    }
+}
 /// Errors returned by DeletePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeletePolicyError {
@@ -8525,6 +8651,14 @@ impl fmt::Display for DeletePolicyError {
     }
 }
 impl Error for DeletePolicyError {}
+impl ServiceErrorCode for DeletePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePolicyError::ResourceContentionFault(_) => "ResourceContention",
+            DeletePolicyError::ServiceLinkedRoleFailure(_) => "ServiceLinkedRoleFailure",
+        }
+    }
+}
 /// Errors returned by DeleteScheduledAction
 #[derive(Debug, PartialEq)]
 pub enum DeleteScheduledActionError {
@@ -8573,6 +8707,13 @@ impl fmt::Display for DeleteScheduledActionError {
     }
 }
 impl Error for DeleteScheduledActionError {}
+impl ServiceErrorCode for DeleteScheduledActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteScheduledActionError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
 pub enum DeleteTagsError {
@@ -8625,6 +8766,14 @@ impl fmt::Display for DeleteTagsError {
     }
 }
 impl Error for DeleteTagsError {}
+impl ServiceErrorCode for DeleteTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTagsError::ResourceContentionFault(_) => "ResourceContention",
+            DeleteTagsError::ResourceInUseFault(_) => "ResourceInUse",
+        }
+    }
+}
 /// Errors returned by DescribeAccountLimits
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountLimitsError {
@@ -8673,6 +8822,13 @@ impl fmt::Display for DescribeAccountLimitsError {
     }
 }
 impl Error for DescribeAccountLimitsError {}
+impl ServiceErrorCode for DescribeAccountLimitsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAccountLimitsError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DescribeAdjustmentTypes
 #[derive(Debug, PartialEq)]
 pub enum DescribeAdjustmentTypesError {
@@ -8721,6 +8877,13 @@ impl fmt::Display for DescribeAdjustmentTypesError {
     }
 }
 impl Error for DescribeAdjustmentTypesError {}
+impl ServiceErrorCode for DescribeAdjustmentTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAdjustmentTypesError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DescribeAutoScalingGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeAutoScalingGroupsError {
@@ -8777,6 +8940,14 @@ impl fmt::Display for DescribeAutoScalingGroupsError {
     }
 }
 impl Error for DescribeAutoScalingGroupsError {}
+impl ServiceErrorCode for DescribeAutoScalingGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAutoScalingGroupsError::InvalidNextToken(_) => "InvalidNextToken",
+            DescribeAutoScalingGroupsError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DescribeAutoScalingInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeAutoScalingInstancesError {
@@ -8839,6 +9010,14 @@ impl fmt::Display for DescribeAutoScalingInstancesError {
     }
 }
 impl Error for DescribeAutoScalingInstancesError {}
+impl ServiceErrorCode for DescribeAutoScalingInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAutoScalingInstancesError::InvalidNextToken(_) => "InvalidNextToken",
+            DescribeAutoScalingInstancesError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DescribeAutoScalingNotificationTypes
 #[derive(Debug, PartialEq)]
 pub enum DescribeAutoScalingNotificationTypesError {
@@ -8889,6 +9068,15 @@ impl fmt::Display for DescribeAutoScalingNotificationTypesError {
     }
 }
 impl Error for DescribeAutoScalingNotificationTypesError {}
+impl ServiceErrorCode for DescribeAutoScalingNotificationTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAutoScalingNotificationTypesError::ResourceContentionFault(_) => {
+                "ResourceContention"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeInstanceRefreshes
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstanceRefreshesError {
@@ -8945,6 +9133,14 @@ impl fmt::Display for DescribeInstanceRefreshesError {
     }
 }
 impl Error for DescribeInstanceRefreshesError {}
+impl ServiceErrorCode for DescribeInstanceRefreshesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInstanceRefreshesError::InvalidNextToken(_) => "InvalidNextToken",
+            DescribeInstanceRefreshesError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DescribeLaunchConfigurations
 #[derive(Debug, PartialEq)]
 pub enum DescribeLaunchConfigurationsError {
@@ -9007,6 +9203,14 @@ impl fmt::Display for DescribeLaunchConfigurationsError {
     }
 }
 impl Error for DescribeLaunchConfigurationsError {}
+impl ServiceErrorCode for DescribeLaunchConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLaunchConfigurationsError::InvalidNextToken(_) => "InvalidNextToken",
+            DescribeLaunchConfigurationsError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DescribeLifecycleHookTypes
 #[derive(Debug, PartialEq)]
 pub enum DescribeLifecycleHookTypesError {
@@ -9057,6 +9261,13 @@ impl fmt::Display for DescribeLifecycleHookTypesError {
     }
 }
 impl Error for DescribeLifecycleHookTypesError {}
+impl ServiceErrorCode for DescribeLifecycleHookTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLifecycleHookTypesError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DescribeLifecycleHooks
 #[derive(Debug, PartialEq)]
 pub enum DescribeLifecycleHooksError {
@@ -9105,6 +9316,13 @@ impl fmt::Display for DescribeLifecycleHooksError {
     }
 }
 impl Error for DescribeLifecycleHooksError {}
+impl ServiceErrorCode for DescribeLifecycleHooksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLifecycleHooksError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DescribeLoadBalancerTargetGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancerTargetGroupsError {
@@ -9155,6 +9373,15 @@ impl fmt::Display for DescribeLoadBalancerTargetGroupsError {
     }
 }
 impl Error for DescribeLoadBalancerTargetGroupsError {}
+impl ServiceErrorCode for DescribeLoadBalancerTargetGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLoadBalancerTargetGroupsError::ResourceContentionFault(_) => {
+                "ResourceContention"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeLoadBalancers
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancersError {
@@ -9203,6 +9430,13 @@ impl fmt::Display for DescribeLoadBalancersError {
     }
 }
 impl Error for DescribeLoadBalancersError {}
+impl ServiceErrorCode for DescribeLoadBalancersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLoadBalancersError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DescribeMetricCollectionTypes
 #[derive(Debug, PartialEq)]
 pub enum DescribeMetricCollectionTypesError {
@@ -9253,6 +9487,13 @@ impl fmt::Display for DescribeMetricCollectionTypesError {
     }
 }
 impl Error for DescribeMetricCollectionTypesError {}
+impl ServiceErrorCode for DescribeMetricCollectionTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMetricCollectionTypesError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DescribeNotificationConfigurations
 #[derive(Debug, PartialEq)]
 pub enum DescribeNotificationConfigurationsError {
@@ -9315,6 +9556,16 @@ impl fmt::Display for DescribeNotificationConfigurationsError {
     }
 }
 impl Error for DescribeNotificationConfigurationsError {}
+impl ServiceErrorCode for DescribeNotificationConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeNotificationConfigurationsError::InvalidNextToken(_) => "InvalidNextToken",
+            DescribeNotificationConfigurationsError::ResourceContentionFault(_) => {
+                "ResourceContention"
+            }
+        }
+    }
+}
 /// Errors returned by DescribePolicies
 #[derive(Debug, PartialEq)]
 pub enum DescribePoliciesError {
@@ -9375,6 +9626,15 @@ impl fmt::Display for DescribePoliciesError {
     }
 }
 impl Error for DescribePoliciesError {}
+impl ServiceErrorCode for DescribePoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePoliciesError::InvalidNextToken(_) => "InvalidNextToken",
+            DescribePoliciesError::ResourceContentionFault(_) => "ResourceContention",
+            DescribePoliciesError::ServiceLinkedRoleFailure(_) => "ServiceLinkedRoleFailure",
+        }
+    }
+}
 /// Errors returned by DescribeScalingActivities
 #[derive(Debug, PartialEq)]
 pub enum DescribeScalingActivitiesError {
@@ -9431,6 +9691,14 @@ impl fmt::Display for DescribeScalingActivitiesError {
     }
 }
 impl Error for DescribeScalingActivitiesError {}
+impl ServiceErrorCode for DescribeScalingActivitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeScalingActivitiesError::InvalidNextToken(_) => "InvalidNextToken",
+            DescribeScalingActivitiesError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DescribeScalingProcessTypes
 #[derive(Debug, PartialEq)]
 pub enum DescribeScalingProcessTypesError {
@@ -9481,6 +9749,13 @@ impl fmt::Display for DescribeScalingProcessTypesError {
     }
 }
 impl Error for DescribeScalingProcessTypesError {}
+impl ServiceErrorCode for DescribeScalingProcessTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeScalingProcessTypesError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DescribeScheduledActions
 #[derive(Debug, PartialEq)]
 pub enum DescribeScheduledActionsError {
@@ -9537,6 +9812,14 @@ impl fmt::Display for DescribeScheduledActionsError {
     }
 }
 impl Error for DescribeScheduledActionsError {}
+impl ServiceErrorCode for DescribeScheduledActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeScheduledActionsError::InvalidNextToken(_) => "InvalidNextToken",
+            DescribeScheduledActionsError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
 pub enum DescribeTagsError {
@@ -9589,6 +9872,14 @@ impl fmt::Display for DescribeTagsError {
     }
 }
 impl Error for DescribeTagsError {}
+impl ServiceErrorCode for DescribeTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTagsError::InvalidNextToken(_) => "InvalidNextToken",
+            DescribeTagsError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DescribeTerminationPolicyTypes
 #[derive(Debug, PartialEq)]
 pub enum DescribeTerminationPolicyTypesError {
@@ -9639,6 +9930,13 @@ impl fmt::Display for DescribeTerminationPolicyTypesError {
     }
 }
 impl Error for DescribeTerminationPolicyTypesError {}
+impl ServiceErrorCode for DescribeTerminationPolicyTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTerminationPolicyTypesError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DetachInstances
 #[derive(Debug, PartialEq)]
 pub enum DetachInstancesError {
@@ -9683,6 +9981,13 @@ impl fmt::Display for DetachInstancesError {
     }
 }
 impl Error for DetachInstancesError {}
+impl ServiceErrorCode for DetachInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachInstancesError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DetachLoadBalancerTargetGroups
 #[derive(Debug, PartialEq)]
 pub enum DetachLoadBalancerTargetGroupsError {
@@ -9733,6 +10038,13 @@ impl fmt::Display for DetachLoadBalancerTargetGroupsError {
     }
 }
 impl Error for DetachLoadBalancerTargetGroupsError {}
+impl ServiceErrorCode for DetachLoadBalancerTargetGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachLoadBalancerTargetGroupsError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DetachLoadBalancers
 #[derive(Debug, PartialEq)]
 pub enum DetachLoadBalancersError {
@@ -9777,6 +10089,13 @@ impl fmt::Display for DetachLoadBalancersError {
     }
 }
 impl Error for DetachLoadBalancersError {}
+impl ServiceErrorCode for DetachLoadBalancersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachLoadBalancersError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by DisableMetricsCollection
 #[derive(Debug, PartialEq)]
 pub enum DisableMetricsCollectionError {
@@ -9825,6 +10144,13 @@ impl fmt::Display for DisableMetricsCollectionError {
     }
 }
 impl Error for DisableMetricsCollectionError {}
+impl ServiceErrorCode for DisableMetricsCollectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableMetricsCollectionError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by EnableMetricsCollection
 #[derive(Debug, PartialEq)]
 pub enum EnableMetricsCollectionError {
@@ -9873,6 +10199,13 @@ impl fmt::Display for EnableMetricsCollectionError {
     }
 }
 impl Error for EnableMetricsCollectionError {}
+impl ServiceErrorCode for EnableMetricsCollectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableMetricsCollectionError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by EnterStandby
 #[derive(Debug, PartialEq)]
 pub enum EnterStandbyError {
@@ -9917,6 +10250,13 @@ impl fmt::Display for EnterStandbyError {
     }
 }
 impl Error for EnterStandbyError {}
+impl ServiceErrorCode for EnterStandbyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnterStandbyError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by ExecutePolicy
 #[derive(Debug, PartialEq)]
 pub enum ExecutePolicyError {
@@ -9971,6 +10311,14 @@ impl fmt::Display for ExecutePolicyError {
     }
 }
 impl Error for ExecutePolicyError {}
+impl ServiceErrorCode for ExecutePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExecutePolicyError::ResourceContentionFault(_) => "ResourceContention",
+            ExecutePolicyError::ScalingActivityInProgressFault(_) => "ScalingActivityInProgress",
+        }
+    }
+}
 /// Errors returned by ExitStandby
 #[derive(Debug, PartialEq)]
 pub enum ExitStandbyError {
@@ -10015,6 +10363,13 @@ impl fmt::Display for ExitStandbyError {
     }
 }
 impl Error for ExitStandbyError {}
+impl ServiceErrorCode for ExitStandbyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExitStandbyError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by PutLifecycleHook
 #[derive(Debug, PartialEq)]
 pub enum PutLifecycleHookError {
@@ -10067,6 +10422,14 @@ impl fmt::Display for PutLifecycleHookError {
     }
 }
 impl Error for PutLifecycleHookError {}
+impl ServiceErrorCode for PutLifecycleHookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutLifecycleHookError::LimitExceededFault(_) => "LimitExceeded",
+            PutLifecycleHookError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by PutNotificationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutNotificationConfigurationError {
@@ -10141,6 +10504,17 @@ impl fmt::Display for PutNotificationConfigurationError {
     }
 }
 impl Error for PutNotificationConfigurationError {}
+impl ServiceErrorCode for PutNotificationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutNotificationConfigurationError::LimitExceededFault(_) => "LimitExceeded",
+            PutNotificationConfigurationError::ResourceContentionFault(_) => "ResourceContention",
+            PutNotificationConfigurationError::ServiceLinkedRoleFailure(_) => {
+                "ServiceLinkedRoleFailure"
+            }
+        }
+    }
+}
 /// Errors returned by PutScalingPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutScalingPolicyError {
@@ -10201,6 +10575,15 @@ impl fmt::Display for PutScalingPolicyError {
     }
 }
 impl Error for PutScalingPolicyError {}
+impl ServiceErrorCode for PutScalingPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutScalingPolicyError::LimitExceededFault(_) => "LimitExceeded",
+            PutScalingPolicyError::ResourceContentionFault(_) => "ResourceContention",
+            PutScalingPolicyError::ServiceLinkedRoleFailure(_) => "ServiceLinkedRoleFailure",
+        }
+    }
+}
 /// Errors returned by PutScheduledUpdateGroupAction
 #[derive(Debug, PartialEq)]
 pub enum PutScheduledUpdateGroupActionError {
@@ -10275,6 +10658,15 @@ impl fmt::Display for PutScheduledUpdateGroupActionError {
     }
 }
 impl Error for PutScheduledUpdateGroupActionError {}
+impl ServiceErrorCode for PutScheduledUpdateGroupActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutScheduledUpdateGroupActionError::AlreadyExistsFault(_) => "AlreadyExists",
+            PutScheduledUpdateGroupActionError::LimitExceededFault(_) => "LimitExceeded",
+            PutScheduledUpdateGroupActionError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by RecordLifecycleActionHeartbeat
 #[derive(Debug, PartialEq)]
 pub enum RecordLifecycleActionHeartbeatError {
@@ -10325,6 +10717,13 @@ impl fmt::Display for RecordLifecycleActionHeartbeatError {
     }
 }
 impl Error for RecordLifecycleActionHeartbeatError {}
+impl ServiceErrorCode for RecordLifecycleActionHeartbeatError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RecordLifecycleActionHeartbeatError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by ResumeProcesses
 #[derive(Debug, PartialEq)]
 pub enum ResumeProcessesError {
@@ -10377,6 +10776,14 @@ impl fmt::Display for ResumeProcessesError {
     }
 }
 impl Error for ResumeProcessesError {}
+impl ServiceErrorCode for ResumeProcessesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResumeProcessesError::ResourceContentionFault(_) => "ResourceContention",
+            ResumeProcessesError::ResourceInUseFault(_) => "ResourceInUse",
+        }
+    }
+}
 /// Errors returned by SetDesiredCapacity
 #[derive(Debug, PartialEq)]
 pub enum SetDesiredCapacityError {
@@ -10433,6 +10840,16 @@ impl fmt::Display for SetDesiredCapacityError {
     }
 }
 impl Error for SetDesiredCapacityError {}
+impl ServiceErrorCode for SetDesiredCapacityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetDesiredCapacityError::ResourceContentionFault(_) => "ResourceContention",
+            SetDesiredCapacityError::ScalingActivityInProgressFault(_) => {
+                "ScalingActivityInProgress"
+            }
+        }
+    }
+}
 /// Errors returned by SetInstanceHealth
 #[derive(Debug, PartialEq)]
 pub enum SetInstanceHealthError {
@@ -10477,6 +10894,13 @@ impl fmt::Display for SetInstanceHealthError {
     }
 }
 impl Error for SetInstanceHealthError {}
+impl ServiceErrorCode for SetInstanceHealthError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetInstanceHealthError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by SetInstanceProtection
 #[derive(Debug, PartialEq)]
 pub enum SetInstanceProtectionError {
@@ -10533,6 +10957,14 @@ impl fmt::Display for SetInstanceProtectionError {
     }
 }
 impl Error for SetInstanceProtectionError {}
+impl ServiceErrorCode for SetInstanceProtectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetInstanceProtectionError::LimitExceededFault(_) => "LimitExceeded",
+            SetInstanceProtectionError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by StartInstanceRefresh
 #[derive(Debug, PartialEq)]
 pub enum StartInstanceRefreshError {
@@ -10599,6 +11031,17 @@ impl fmt::Display for StartInstanceRefreshError {
     }
 }
 impl Error for StartInstanceRefreshError {}
+impl ServiceErrorCode for StartInstanceRefreshError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartInstanceRefreshError::InstanceRefreshInProgressFault(_) => {
+                "InstanceRefreshInProgress"
+            }
+            StartInstanceRefreshError::LimitExceededFault(_) => "LimitExceeded",
+            StartInstanceRefreshError::ResourceContentionFault(_) => "ResourceContention",
+        }
+    }
+}
 /// Errors returned by SuspendProcesses
 #[derive(Debug, PartialEq)]
 pub enum SuspendProcessesError {
@@ -10651,6 +11094,14 @@ impl fmt::Display for SuspendProcessesError {
     }
 }
 impl Error for SuspendProcessesError {}
+impl ServiceErrorCode for SuspendProcessesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SuspendProcessesError::ResourceContentionFault(_) => "ResourceContention",
+            SuspendProcessesError::ResourceInUseFault(_) => "ResourceInUse",
+        }
+    }
+}
 /// Errors returned by TerminateInstanceInAutoScalingGroup
 #[derive(Debug, PartialEq)]
 pub enum TerminateInstanceInAutoScalingGroupError {
@@ -10711,6 +11162,18 @@ impl fmt::Display for TerminateInstanceInAutoScalingGroupError {
     }
 }
 impl Error for TerminateInstanceInAutoScalingGroupError {}
+impl ServiceErrorCode for TerminateInstanceInAutoScalingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TerminateInstanceInAutoScalingGroupError::ResourceContentionFault(_) => {
+                "ResourceContention"
+            }
+            TerminateInstanceInAutoScalingGroupError::ScalingActivityInProgressFault(_) => {
+                "ScalingActivityInProgress"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateAutoScalingGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateAutoScalingGroupError {
@@ -10783,6 +11246,17 @@ impl fmt::Display for UpdateAutoScalingGroupError {
     }
 }
 impl Error for UpdateAutoScalingGroupError {}
+impl ServiceErrorCode for UpdateAutoScalingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAutoScalingGroupError::ResourceContentionFault(_) => "ResourceContention",
+            UpdateAutoScalingGroupError::ScalingActivityInProgressFault(_) => {
+                "ScalingActivityInProgress"
+            }
+            UpdateAutoScalingGroupError::ServiceLinkedRoleFailure(_) => "ServiceLinkedRoleFailure",
+        }
+    }
+}
 /// Trait representing the capabilities of the Auto Scaling API. Auto Scaling clients implement this trait.
 #[async_trait]
 pub trait Autoscaling {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2207,6 +2207,17 @@ impl fmt::Display for CreateBackupPlanError {
     }
 }
 impl Error for CreateBackupPlanError {}
+impl ServiceErrorCode for CreateBackupPlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBackupPlanError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateBackupPlanError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateBackupPlanError::LimitExceeded(_) => "LimitExceededException",
+            CreateBackupPlanError::MissingParameterValue(_) => "MissingParameterValueException",
+            CreateBackupPlanError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CreateBackupSelection
 #[derive(Debug, PartialEq)]
 pub enum CreateBackupSelectionError {
@@ -2267,6 +2278,21 @@ impl fmt::Display for CreateBackupSelectionError {
     }
 }
 impl Error for CreateBackupSelectionError {}
+impl ServiceErrorCode for CreateBackupSelectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBackupSelectionError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateBackupSelectionError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            CreateBackupSelectionError::LimitExceeded(_) => "LimitExceededException",
+            CreateBackupSelectionError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            CreateBackupSelectionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CreateBackupVault
 #[derive(Debug, PartialEq)]
 pub enum CreateBackupVaultError {
@@ -2327,6 +2353,17 @@ impl fmt::Display for CreateBackupVaultError {
     }
 }
 impl Error for CreateBackupVaultError {}
+impl ServiceErrorCode for CreateBackupVaultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBackupVaultError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateBackupVaultError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateBackupVaultError::LimitExceeded(_) => "LimitExceededException",
+            CreateBackupVaultError::MissingParameterValue(_) => "MissingParameterValueException",
+            CreateBackupVaultError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteBackupPlan
 #[derive(Debug, PartialEq)]
 pub enum DeleteBackupPlanError {
@@ -2385,6 +2422,17 @@ impl fmt::Display for DeleteBackupPlanError {
     }
 }
 impl Error for DeleteBackupPlanError {}
+impl ServiceErrorCode for DeleteBackupPlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBackupPlanError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteBackupPlanError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteBackupPlanError::MissingParameterValue(_) => "MissingParameterValueException",
+            DeleteBackupPlanError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteBackupPlanError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteBackupSelection
 #[derive(Debug, PartialEq)]
 pub enum DeleteBackupSelectionError {
@@ -2441,6 +2489,20 @@ impl fmt::Display for DeleteBackupSelectionError {
     }
 }
 impl Error for DeleteBackupSelectionError {}
+impl ServiceErrorCode for DeleteBackupSelectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBackupSelectionError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteBackupSelectionError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            DeleteBackupSelectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteBackupSelectionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteBackupVault
 #[derive(Debug, PartialEq)]
 pub enum DeleteBackupVaultError {
@@ -2501,6 +2563,17 @@ impl fmt::Display for DeleteBackupVaultError {
     }
 }
 impl Error for DeleteBackupVaultError {}
+impl ServiceErrorCode for DeleteBackupVaultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBackupVaultError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteBackupVaultError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteBackupVaultError::MissingParameterValue(_) => "MissingParameterValueException",
+            DeleteBackupVaultError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteBackupVaultError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteBackupVaultAccessPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteBackupVaultAccessPolicyError {
@@ -2567,6 +2640,22 @@ impl fmt::Display for DeleteBackupVaultAccessPolicyError {
     }
 }
 impl Error for DeleteBackupVaultAccessPolicyError {}
+impl ServiceErrorCode for DeleteBackupVaultAccessPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBackupVaultAccessPolicyError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteBackupVaultAccessPolicyError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            DeleteBackupVaultAccessPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteBackupVaultAccessPolicyError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteBackupVaultNotifications
 #[derive(Debug, PartialEq)]
 pub enum DeleteBackupVaultNotificationsError {
@@ -2633,6 +2722,22 @@ impl fmt::Display for DeleteBackupVaultNotificationsError {
     }
 }
 impl Error for DeleteBackupVaultNotificationsError {}
+impl ServiceErrorCode for DeleteBackupVaultNotificationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBackupVaultNotificationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteBackupVaultNotificationsError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            DeleteBackupVaultNotificationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteBackupVaultNotificationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteRecoveryPoint
 #[derive(Debug, PartialEq)]
 pub enum DeleteRecoveryPointError {
@@ -2695,6 +2800,17 @@ impl fmt::Display for DeleteRecoveryPointError {
     }
 }
 impl Error for DeleteRecoveryPointError {}
+impl ServiceErrorCode for DeleteRecoveryPointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRecoveryPointError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteRecoveryPointError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteRecoveryPointError::MissingParameterValue(_) => "MissingParameterValueException",
+            DeleteRecoveryPointError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteRecoveryPointError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeBackupJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeBackupJobError {
@@ -2755,6 +2871,17 @@ impl fmt::Display for DescribeBackupJobError {
     }
 }
 impl Error for DescribeBackupJobError {}
+impl ServiceErrorCode for DescribeBackupJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBackupJobError::DependencyFailure(_) => "DependencyFailureException",
+            DescribeBackupJobError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeBackupJobError::MissingParameterValue(_) => "MissingParameterValueException",
+            DescribeBackupJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeBackupJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeBackupVault
 #[derive(Debug, PartialEq)]
 pub enum DescribeBackupVaultError {
@@ -2811,6 +2938,16 @@ impl fmt::Display for DescribeBackupVaultError {
     }
 }
 impl Error for DescribeBackupVaultError {}
+impl ServiceErrorCode for DescribeBackupVaultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBackupVaultError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeBackupVaultError::MissingParameterValue(_) => "MissingParameterValueException",
+            DescribeBackupVaultError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeBackupVaultError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeCopyJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeCopyJobError {
@@ -2863,6 +3000,16 @@ impl fmt::Display for DescribeCopyJobError {
     }
 }
 impl Error for DescribeCopyJobError {}
+impl ServiceErrorCode for DescribeCopyJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCopyJobError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeCopyJobError::MissingParameterValue(_) => "MissingParameterValueException",
+            DescribeCopyJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeCopyJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeGlobalSettings
 #[derive(Debug, PartialEq)]
 pub enum DescribeGlobalSettingsError {
@@ -2895,6 +3042,13 @@ impl fmt::Display for DescribeGlobalSettingsError {
     }
 }
 impl Error for DescribeGlobalSettingsError {}
+impl ServiceErrorCode for DescribeGlobalSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGlobalSettingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeProtectedResource
 #[derive(Debug, PartialEq)]
 pub enum DescribeProtectedResourceError {
@@ -2955,6 +3109,20 @@ impl fmt::Display for DescribeProtectedResourceError {
     }
 }
 impl Error for DescribeProtectedResourceError {}
+impl ServiceErrorCode for DescribeProtectedResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProtectedResourceError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeProtectedResourceError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            DescribeProtectedResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeProtectedResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeRecoveryPoint
 #[derive(Debug, PartialEq)]
 pub enum DescribeRecoveryPointError {
@@ -3011,6 +3179,20 @@ impl fmt::Display for DescribeRecoveryPointError {
     }
 }
 impl Error for DescribeRecoveryPointError {}
+impl ServiceErrorCode for DescribeRecoveryPointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRecoveryPointError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeRecoveryPointError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            DescribeRecoveryPointError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeRecoveryPointError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeRegionSettings
 #[derive(Debug, PartialEq)]
 pub enum DescribeRegionSettingsError {
@@ -3043,6 +3225,13 @@ impl fmt::Display for DescribeRegionSettingsError {
     }
 }
 impl Error for DescribeRegionSettingsError {}
+impl ServiceErrorCode for DescribeRegionSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRegionSettingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeRestoreJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeRestoreJobError {
@@ -3105,6 +3294,17 @@ impl fmt::Display for DescribeRestoreJobError {
     }
 }
 impl Error for DescribeRestoreJobError {}
+impl ServiceErrorCode for DescribeRestoreJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRestoreJobError::DependencyFailure(_) => "DependencyFailureException",
+            DescribeRestoreJobError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeRestoreJobError::MissingParameterValue(_) => "MissingParameterValueException",
+            DescribeRestoreJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeRestoreJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ExportBackupPlanTemplate
 #[derive(Debug, PartialEq)]
 pub enum ExportBackupPlanTemplateError {
@@ -3165,6 +3365,20 @@ impl fmt::Display for ExportBackupPlanTemplateError {
     }
 }
 impl Error for ExportBackupPlanTemplateError {}
+impl ServiceErrorCode for ExportBackupPlanTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExportBackupPlanTemplateError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ExportBackupPlanTemplateError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            ExportBackupPlanTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ExportBackupPlanTemplateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetBackupPlan
 #[derive(Debug, PartialEq)]
 pub enum GetBackupPlanError {
@@ -3213,6 +3427,16 @@ impl fmt::Display for GetBackupPlanError {
     }
 }
 impl Error for GetBackupPlanError {}
+impl ServiceErrorCode for GetBackupPlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBackupPlanError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetBackupPlanError::MissingParameterValue(_) => "MissingParameterValueException",
+            GetBackupPlanError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetBackupPlanError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetBackupPlanFromJSON
 #[derive(Debug, PartialEq)]
 pub enum GetBackupPlanFromJSONError {
@@ -3275,6 +3499,21 @@ impl fmt::Display for GetBackupPlanFromJSONError {
     }
 }
 impl Error for GetBackupPlanFromJSONError {}
+impl ServiceErrorCode for GetBackupPlanFromJSONError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBackupPlanFromJSONError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetBackupPlanFromJSONError::InvalidRequest(_) => "InvalidRequestException",
+            GetBackupPlanFromJSONError::LimitExceeded(_) => "LimitExceededException",
+            GetBackupPlanFromJSONError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            GetBackupPlanFromJSONError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetBackupPlanFromTemplate
 #[derive(Debug, PartialEq)]
 pub enum GetBackupPlanFromTemplateError {
@@ -3335,6 +3574,20 @@ impl fmt::Display for GetBackupPlanFromTemplateError {
     }
 }
 impl Error for GetBackupPlanFromTemplateError {}
+impl ServiceErrorCode for GetBackupPlanFromTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBackupPlanFromTemplateError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetBackupPlanFromTemplateError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            GetBackupPlanFromTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetBackupPlanFromTemplateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetBackupSelection
 #[derive(Debug, PartialEq)]
 pub enum GetBackupSelectionError {
@@ -3389,6 +3642,16 @@ impl fmt::Display for GetBackupSelectionError {
     }
 }
 impl Error for GetBackupSelectionError {}
+impl ServiceErrorCode for GetBackupSelectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBackupSelectionError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetBackupSelectionError::MissingParameterValue(_) => "MissingParameterValueException",
+            GetBackupSelectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetBackupSelectionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetBackupVaultAccessPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetBackupVaultAccessPolicyError {
@@ -3453,6 +3716,20 @@ impl fmt::Display for GetBackupVaultAccessPolicyError {
     }
 }
 impl Error for GetBackupVaultAccessPolicyError {}
+impl ServiceErrorCode for GetBackupVaultAccessPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBackupVaultAccessPolicyError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetBackupVaultAccessPolicyError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            GetBackupVaultAccessPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetBackupVaultAccessPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetBackupVaultNotifications
 #[derive(Debug, PartialEq)]
 pub enum GetBackupVaultNotificationsError {
@@ -3517,6 +3794,22 @@ impl fmt::Display for GetBackupVaultNotificationsError {
     }
 }
 impl Error for GetBackupVaultNotificationsError {}
+impl ServiceErrorCode for GetBackupVaultNotificationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBackupVaultNotificationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetBackupVaultNotificationsError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            GetBackupVaultNotificationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetBackupVaultNotificationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by GetRecoveryPointRestoreMetadata
 #[derive(Debug, PartialEq)]
 pub enum GetRecoveryPointRestoreMetadataError {
@@ -3583,6 +3876,24 @@ impl fmt::Display for GetRecoveryPointRestoreMetadataError {
     }
 }
 impl Error for GetRecoveryPointRestoreMetadataError {}
+impl ServiceErrorCode for GetRecoveryPointRestoreMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRecoveryPointRestoreMetadataError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetRecoveryPointRestoreMetadataError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            GetRecoveryPointRestoreMetadataError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            GetRecoveryPointRestoreMetadataError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by GetSupportedResourceTypes
 #[derive(Debug, PartialEq)]
 pub enum GetSupportedResourceTypesError {
@@ -3615,6 +3926,13 @@ impl fmt::Display for GetSupportedResourceTypesError {
     }
 }
 impl Error for GetSupportedResourceTypesError {}
+impl ServiceErrorCode for GetSupportedResourceTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSupportedResourceTypesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListBackupJobs
 #[derive(Debug, PartialEq)]
 pub enum ListBackupJobsError {
@@ -3653,6 +3971,14 @@ impl fmt::Display for ListBackupJobsError {
     }
 }
 impl Error for ListBackupJobsError {}
+impl ServiceErrorCode for ListBackupJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBackupJobsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListBackupJobsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListBackupPlanTemplates
 #[derive(Debug, PartialEq)]
 pub enum ListBackupPlanTemplatesError {
@@ -3713,6 +4039,20 @@ impl fmt::Display for ListBackupPlanTemplatesError {
     }
 }
 impl Error for ListBackupPlanTemplatesError {}
+impl ServiceErrorCode for ListBackupPlanTemplatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBackupPlanTemplatesError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListBackupPlanTemplatesError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            ListBackupPlanTemplatesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListBackupPlanTemplatesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListBackupPlanVersions
 #[derive(Debug, PartialEq)]
 pub enum ListBackupPlanVersionsError {
@@ -3769,6 +4109,20 @@ impl fmt::Display for ListBackupPlanVersionsError {
     }
 }
 impl Error for ListBackupPlanVersionsError {}
+impl ServiceErrorCode for ListBackupPlanVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBackupPlanVersionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListBackupPlanVersionsError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            ListBackupPlanVersionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListBackupPlanVersionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListBackupPlans
 #[derive(Debug, PartialEq)]
 pub enum ListBackupPlansError {
@@ -3821,6 +4175,16 @@ impl fmt::Display for ListBackupPlansError {
     }
 }
 impl Error for ListBackupPlansError {}
+impl ServiceErrorCode for ListBackupPlansError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBackupPlansError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListBackupPlansError::MissingParameterValue(_) => "MissingParameterValueException",
+            ListBackupPlansError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListBackupPlansError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListBackupSelections
 #[derive(Debug, PartialEq)]
 pub enum ListBackupSelectionsError {
@@ -3877,6 +4241,16 @@ impl fmt::Display for ListBackupSelectionsError {
     }
 }
 impl Error for ListBackupSelectionsError {}
+impl ServiceErrorCode for ListBackupSelectionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBackupSelectionsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListBackupSelectionsError::MissingParameterValue(_) => "MissingParameterValueException",
+            ListBackupSelectionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListBackupSelectionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListBackupVaults
 #[derive(Debug, PartialEq)]
 pub enum ListBackupVaultsError {
@@ -3929,6 +4303,16 @@ impl fmt::Display for ListBackupVaultsError {
     }
 }
 impl Error for ListBackupVaultsError {}
+impl ServiceErrorCode for ListBackupVaultsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBackupVaultsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListBackupVaultsError::MissingParameterValue(_) => "MissingParameterValueException",
+            ListBackupVaultsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListBackupVaultsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListCopyJobs
 #[derive(Debug, PartialEq)]
 pub enum ListCopyJobsError {
@@ -3965,6 +4349,14 @@ impl fmt::Display for ListCopyJobsError {
     }
 }
 impl Error for ListCopyJobsError {}
+impl ServiceErrorCode for ListCopyJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCopyJobsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListCopyJobsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListProtectedResources
 #[derive(Debug, PartialEq)]
 pub enum ListProtectedResourcesError {
@@ -4005,6 +4397,16 @@ impl fmt::Display for ListProtectedResourcesError {
     }
 }
 impl Error for ListProtectedResourcesError {}
+impl ServiceErrorCode for ListProtectedResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProtectedResourcesError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListProtectedResourcesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListRecoveryPointsByBackupVault
 #[derive(Debug, PartialEq)]
 pub enum ListRecoveryPointsByBackupVaultError {
@@ -4071,6 +4473,24 @@ impl fmt::Display for ListRecoveryPointsByBackupVaultError {
     }
 }
 impl Error for ListRecoveryPointsByBackupVaultError {}
+impl ServiceErrorCode for ListRecoveryPointsByBackupVaultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRecoveryPointsByBackupVaultError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListRecoveryPointsByBackupVaultError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            ListRecoveryPointsByBackupVaultError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            ListRecoveryPointsByBackupVaultError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by ListRecoveryPointsByResource
 #[derive(Debug, PartialEq)]
 pub enum ListRecoveryPointsByResourceError {
@@ -4137,6 +4557,22 @@ impl fmt::Display for ListRecoveryPointsByResourceError {
     }
 }
 impl Error for ListRecoveryPointsByResourceError {}
+impl ServiceErrorCode for ListRecoveryPointsByResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRecoveryPointsByResourceError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListRecoveryPointsByResourceError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            ListRecoveryPointsByResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListRecoveryPointsByResourceError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by ListRestoreJobs
 #[derive(Debug, PartialEq)]
 pub enum ListRestoreJobsError {
@@ -4189,6 +4625,16 @@ impl fmt::Display for ListRestoreJobsError {
     }
 }
 impl Error for ListRestoreJobsError {}
+impl ServiceErrorCode for ListRestoreJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRestoreJobsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListRestoreJobsError::MissingParameterValue(_) => "MissingParameterValueException",
+            ListRestoreJobsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListRestoreJobsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
 pub enum ListTagsError {
@@ -4237,6 +4683,16 @@ impl fmt::Display for ListTagsError {
     }
 }
 impl Error for ListTagsError {}
+impl ServiceErrorCode for ListTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListTagsError::MissingParameterValue(_) => "MissingParameterValueException",
+            ListTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by PutBackupVaultAccessPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutBackupVaultAccessPolicyError {
@@ -4301,6 +4757,20 @@ impl fmt::Display for PutBackupVaultAccessPolicyError {
     }
 }
 impl Error for PutBackupVaultAccessPolicyError {}
+impl ServiceErrorCode for PutBackupVaultAccessPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutBackupVaultAccessPolicyError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            PutBackupVaultAccessPolicyError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            PutBackupVaultAccessPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutBackupVaultAccessPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by PutBackupVaultNotifications
 #[derive(Debug, PartialEq)]
 pub enum PutBackupVaultNotificationsError {
@@ -4365,6 +4835,22 @@ impl fmt::Display for PutBackupVaultNotificationsError {
     }
 }
 impl Error for PutBackupVaultNotificationsError {}
+impl ServiceErrorCode for PutBackupVaultNotificationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutBackupVaultNotificationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            PutBackupVaultNotificationsError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            PutBackupVaultNotificationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutBackupVaultNotificationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by StartBackupJob
 #[derive(Debug, PartialEq)]
 pub enum StartBackupJobError {
@@ -4429,6 +4915,18 @@ impl fmt::Display for StartBackupJobError {
     }
 }
 impl Error for StartBackupJobError {}
+impl ServiceErrorCode for StartBackupJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartBackupJobError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            StartBackupJobError::InvalidRequest(_) => "InvalidRequestException",
+            StartBackupJobError::LimitExceeded(_) => "LimitExceededException",
+            StartBackupJobError::MissingParameterValue(_) => "MissingParameterValueException",
+            StartBackupJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartBackupJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by StartCopyJob
 #[derive(Debug, PartialEq)]
 pub enum StartCopyJobError {
@@ -4483,6 +4981,17 @@ impl fmt::Display for StartCopyJobError {
     }
 }
 impl Error for StartCopyJobError {}
+impl ServiceErrorCode for StartCopyJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartCopyJobError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            StartCopyJobError::LimitExceeded(_) => "LimitExceededException",
+            StartCopyJobError::MissingParameterValue(_) => "MissingParameterValueException",
+            StartCopyJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartCopyJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by StartRestoreJob
 #[derive(Debug, PartialEq)]
 pub enum StartRestoreJobError {
@@ -4535,6 +5044,16 @@ impl fmt::Display for StartRestoreJobError {
     }
 }
 impl Error for StartRestoreJobError {}
+impl ServiceErrorCode for StartRestoreJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartRestoreJobError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            StartRestoreJobError::MissingParameterValue(_) => "MissingParameterValueException",
+            StartRestoreJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartRestoreJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by StopBackupJob
 #[derive(Debug, PartialEq)]
 pub enum StopBackupJobError {
@@ -4589,6 +5108,17 @@ impl fmt::Display for StopBackupJobError {
     }
 }
 impl Error for StopBackupJobError {}
+impl ServiceErrorCode for StopBackupJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopBackupJobError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            StopBackupJobError::InvalidRequest(_) => "InvalidRequestException",
+            StopBackupJobError::MissingParameterValue(_) => "MissingParameterValueException",
+            StopBackupJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StopBackupJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -4643,6 +5173,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::MissingParameterValue(_) => "MissingParameterValueException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -4691,6 +5232,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UntagResourceError::MissingParameterValue(_) => "MissingParameterValueException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by UpdateBackupPlan
 #[derive(Debug, PartialEq)]
 pub enum UpdateBackupPlanError {
@@ -4743,6 +5294,16 @@ impl fmt::Display for UpdateBackupPlanError {
     }
 }
 impl Error for UpdateBackupPlanError {}
+impl ServiceErrorCode for UpdateBackupPlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateBackupPlanError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateBackupPlanError::MissingParameterValue(_) => "MissingParameterValueException",
+            UpdateBackupPlanError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateBackupPlanError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by UpdateGlobalSettings
 #[derive(Debug, PartialEq)]
 pub enum UpdateGlobalSettingsError {
@@ -4797,6 +5358,16 @@ impl fmt::Display for UpdateGlobalSettingsError {
     }
 }
 impl Error for UpdateGlobalSettingsError {}
+impl ServiceErrorCode for UpdateGlobalSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGlobalSettingsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateGlobalSettingsError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateGlobalSettingsError::MissingParameterValue(_) => "MissingParameterValueException",
+            UpdateGlobalSettingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by UpdateRecoveryPointLifecycle
 #[derive(Debug, PartialEq)]
 pub enum UpdateRecoveryPointLifecycleError {
@@ -4863,6 +5434,22 @@ impl fmt::Display for UpdateRecoveryPointLifecycleError {
     }
 }
 impl Error for UpdateRecoveryPointLifecycleError {}
+impl ServiceErrorCode for UpdateRecoveryPointLifecycleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRecoveryPointLifecycleError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateRecoveryPointLifecycleError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            UpdateRecoveryPointLifecycleError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateRecoveryPointLifecycleError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateRegionSettings
 #[derive(Debug, PartialEq)]
 pub enum UpdateRegionSettingsError {
@@ -4911,6 +5498,15 @@ impl fmt::Display for UpdateRegionSettingsError {
     }
 }
 impl Error for UpdateRegionSettingsError {}
+impl ServiceErrorCode for UpdateRegionSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRegionSettingsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateRegionSettingsError::MissingParameterValue(_) => "MissingParameterValueException",
+            UpdateRegionSettingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Backup API. AWS Backup clients implement this trait.
 #[async_trait]
 pub trait Backup {
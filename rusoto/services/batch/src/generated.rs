@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1656,6 +1656,14 @@ impl fmt::Display for CancelJobError {
     }
 }
 impl Error for CancelJobError {}
+impl ServiceErrorCode for CancelJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelJobError::Client(_) => "ClientException",
+            CancelJobError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by CreateComputeEnvironment
 #[derive(Debug, PartialEq)]
 pub enum CreateComputeEnvironmentError {
@@ -1692,6 +1700,14 @@ impl fmt::Display for CreateComputeEnvironmentError {
     }
 }
 impl Error for CreateComputeEnvironmentError {}
+impl ServiceErrorCode for CreateComputeEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateComputeEnvironmentError::Client(_) => "ClientException",
+            CreateComputeEnvironmentError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by CreateJobQueue
 #[derive(Debug, PartialEq)]
 pub enum CreateJobQueueError {
@@ -1728,6 +1744,14 @@ impl fmt::Display for CreateJobQueueError {
     }
 }
 impl Error for CreateJobQueueError {}
+impl ServiceErrorCode for CreateJobQueueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateJobQueueError::Client(_) => "ClientException",
+            CreateJobQueueError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DeleteComputeEnvironment
 #[derive(Debug, PartialEq)]
 pub enum DeleteComputeEnvironmentError {
@@ -1764,6 +1788,14 @@ impl fmt::Display for DeleteComputeEnvironmentError {
     }
 }
 impl Error for DeleteComputeEnvironmentError {}
+impl ServiceErrorCode for DeleteComputeEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteComputeEnvironmentError::Client(_) => "ClientException",
+            DeleteComputeEnvironmentError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DeleteJobQueue
 #[derive(Debug, PartialEq)]
 pub enum DeleteJobQueueError {
@@ -1800,6 +1832,14 @@ impl fmt::Display for DeleteJobQueueError {
     }
 }
 impl Error for DeleteJobQueueError {}
+impl ServiceErrorCode for DeleteJobQueueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteJobQueueError::Client(_) => "ClientException",
+            DeleteJobQueueError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DeregisterJobDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeregisterJobDefinitionError {
@@ -1836,6 +1876,14 @@ impl fmt::Display for DeregisterJobDefinitionError {
     }
 }
 impl Error for DeregisterJobDefinitionError {}
+impl ServiceErrorCode for DeregisterJobDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterJobDefinitionError::Client(_) => "ClientException",
+            DeregisterJobDefinitionError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeComputeEnvironments
 #[derive(Debug, PartialEq)]
 pub enum DescribeComputeEnvironmentsError {
@@ -1874,6 +1922,14 @@ impl fmt::Display for DescribeComputeEnvironmentsError {
     }
 }
 impl Error for DescribeComputeEnvironmentsError {}
+impl ServiceErrorCode for DescribeComputeEnvironmentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeComputeEnvironmentsError::Client(_) => "ClientException",
+            DescribeComputeEnvironmentsError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeJobDefinitions
 #[derive(Debug, PartialEq)]
 pub enum DescribeJobDefinitionsError {
@@ -1910,6 +1966,14 @@ impl fmt::Display for DescribeJobDefinitionsError {
     }
 }
 impl Error for DescribeJobDefinitionsError {}
+impl ServiceErrorCode for DescribeJobDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeJobDefinitionsError::Client(_) => "ClientException",
+            DescribeJobDefinitionsError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeJobQueues
 #[derive(Debug, PartialEq)]
 pub enum DescribeJobQueuesError {
@@ -1946,6 +2010,14 @@ impl fmt::Display for DescribeJobQueuesError {
     }
 }
 impl Error for DescribeJobQueuesError {}
+impl ServiceErrorCode for DescribeJobQueuesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeJobQueuesError::Client(_) => "ClientException",
+            DescribeJobQueuesError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeJobs
 #[derive(Debug, PartialEq)]
 pub enum DescribeJobsError {
@@ -1982,6 +2054,14 @@ impl fmt::Display for DescribeJobsError {
     }
 }
 impl Error for DescribeJobsError {}
+impl ServiceErrorCode for DescribeJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeJobsError::Client(_) => "ClientException",
+            DescribeJobsError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
 pub enum ListJobsError {
@@ -2014,6 +2094,14 @@ impl fmt::Display for ListJobsError {
     }
 }
 impl Error for ListJobsError {}
+impl ServiceErrorCode for ListJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJobsError::Client(_) => "ClientException",
+            ListJobsError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2050,6 +2138,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::Client(_) => "ClientException",
+            ListTagsForResourceError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by RegisterJobDefinition
 #[derive(Debug, PartialEq)]
 pub enum RegisterJobDefinitionError {
@@ -2086,6 +2182,14 @@ impl fmt::Display for RegisterJobDefinitionError {
     }
 }
 impl Error for RegisterJobDefinitionError {}
+impl ServiceErrorCode for RegisterJobDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterJobDefinitionError::Client(_) => "ClientException",
+            RegisterJobDefinitionError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by SubmitJob
 #[derive(Debug, PartialEq)]
 pub enum SubmitJobError {
@@ -2118,6 +2222,14 @@ impl fmt::Display for SubmitJobError {
     }
 }
 impl Error for SubmitJobError {}
+impl ServiceErrorCode for SubmitJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SubmitJobError::Client(_) => "ClientException",
+            SubmitJobError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2154,6 +2266,14 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::Client(_) => "ClientException",
+            TagResourceError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by TerminateJob
 #[derive(Debug, PartialEq)]
 pub enum TerminateJobError {
@@ -2190,6 +2310,14 @@ impl fmt::Display for TerminateJobError {
     }
 }
 impl Error for TerminateJobError {}
+impl ServiceErrorCode for TerminateJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TerminateJobError::Client(_) => "ClientException",
+            TerminateJobError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2226,6 +2354,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::Client(_) => "ClientException",
+            UntagResourceError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by UpdateComputeEnvironment
 #[derive(Debug, PartialEq)]
 pub enum UpdateComputeEnvironmentError {
@@ -2262,6 +2398,14 @@ impl fmt::Display for UpdateComputeEnvironmentError {
     }
 }
 impl Error for UpdateComputeEnvironmentError {}
+impl ServiceErrorCode for UpdateComputeEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateComputeEnvironmentError::Client(_) => "ClientException",
+            UpdateComputeEnvironmentError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by UpdateJobQueue
 #[derive(Debug, PartialEq)]
 pub enum UpdateJobQueueError {
@@ -2298,6 +2442,14 @@ impl fmt::Display for UpdateJobQueueError {
     }
 }
 impl Error for UpdateJobQueueError {}
+impl ServiceErrorCode for UpdateJobQueueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateJobQueueError::Client(_) => "ClientException",
+            UpdateJobQueueError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Batch API. AWS Batch clients implement this trait.
 #[async_trait]
 pub trait Batch {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1045,6 +1045,17 @@ impl fmt::Display for CreateBudgetError {
     }
 }
 impl Error for CreateBudgetError {}
+impl ServiceErrorCode for CreateBudgetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBudgetError::AccessDenied(_) => "AccessDeniedException",
+            CreateBudgetError::CreationLimitExceeded(_) => "CreationLimitExceededException",
+            CreateBudgetError::DuplicateRecord(_) => "DuplicateRecordException",
+            CreateBudgetError::InternalError(_) => "InternalErrorException",
+            CreateBudgetError::InvalidParameter(_) => "InvalidParameterException",
+        }
+    }
+}
 /// Errors returned by CreateBudgetAction
 #[derive(Debug, PartialEq)]
 pub enum CreateBudgetActionError {
@@ -1107,6 +1118,18 @@ impl fmt::Display for CreateBudgetActionError {
     }
 }
 impl Error for CreateBudgetActionError {}
+impl ServiceErrorCode for CreateBudgetActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBudgetActionError::AccessDenied(_) => "AccessDeniedException",
+            CreateBudgetActionError::CreationLimitExceeded(_) => "CreationLimitExceededException",
+            CreateBudgetActionError::DuplicateRecord(_) => "DuplicateRecordException",
+            CreateBudgetActionError::InternalError(_) => "InternalErrorException",
+            CreateBudgetActionError::InvalidParameter(_) => "InvalidParameterException",
+            CreateBudgetActionError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateNotification
 #[derive(Debug, PartialEq)]
 pub enum CreateNotificationError {
@@ -1169,6 +1192,18 @@ impl fmt::Display for CreateNotificationError {
     }
 }
 impl Error for CreateNotificationError {}
+impl ServiceErrorCode for CreateNotificationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateNotificationError::AccessDenied(_) => "AccessDeniedException",
+            CreateNotificationError::CreationLimitExceeded(_) => "CreationLimitExceededException",
+            CreateNotificationError::DuplicateRecord(_) => "DuplicateRecordException",
+            CreateNotificationError::InternalError(_) => "InternalErrorException",
+            CreateNotificationError::InvalidParameter(_) => "InvalidParameterException",
+            CreateNotificationError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateSubscriber
 #[derive(Debug, PartialEq)]
 pub enum CreateSubscriberError {
@@ -1231,6 +1266,18 @@ impl fmt::Display for CreateSubscriberError {
     }
 }
 impl Error for CreateSubscriberError {}
+impl ServiceErrorCode for CreateSubscriberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSubscriberError::AccessDenied(_) => "AccessDeniedException",
+            CreateSubscriberError::CreationLimitExceeded(_) => "CreationLimitExceededException",
+            CreateSubscriberError::DuplicateRecord(_) => "DuplicateRecordException",
+            CreateSubscriberError::InternalError(_) => "InternalErrorException",
+            CreateSubscriberError::InvalidParameter(_) => "InvalidParameterException",
+            CreateSubscriberError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteBudget
 #[derive(Debug, PartialEq)]
 pub enum DeleteBudgetError {
@@ -1279,6 +1326,16 @@ impl fmt::Display for DeleteBudgetError {
     }
 }
 impl Error for DeleteBudgetError {}
+impl ServiceErrorCode for DeleteBudgetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBudgetError::AccessDenied(_) => "AccessDeniedException",
+            DeleteBudgetError::InternalError(_) => "InternalErrorException",
+            DeleteBudgetError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteBudgetError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteBudgetAction
 #[derive(Debug, PartialEq)]
 pub enum DeleteBudgetActionError {
@@ -1333,6 +1390,17 @@ impl fmt::Display for DeleteBudgetActionError {
     }
 }
 impl Error for DeleteBudgetActionError {}
+impl ServiceErrorCode for DeleteBudgetActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBudgetActionError::AccessDenied(_) => "AccessDeniedException",
+            DeleteBudgetActionError::InternalError(_) => "InternalErrorException",
+            DeleteBudgetActionError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteBudgetActionError::NotFound(_) => "NotFoundException",
+            DeleteBudgetActionError::ResourceLocked(_) => "ResourceLockedException",
+        }
+    }
+}
 /// Errors returned by DeleteNotification
 #[derive(Debug, PartialEq)]
 pub enum DeleteNotificationError {
@@ -1381,6 +1449,16 @@ impl fmt::Display for DeleteNotificationError {
     }
 }
 impl Error for DeleteNotificationError {}
+impl ServiceErrorCode for DeleteNotificationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteNotificationError::AccessDenied(_) => "AccessDeniedException",
+            DeleteNotificationError::InternalError(_) => "InternalErrorException",
+            DeleteNotificationError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteNotificationError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteSubscriber
 #[derive(Debug, PartialEq)]
 pub enum DeleteSubscriberError {
@@ -1429,6 +1507,16 @@ impl fmt::Display for DeleteSubscriberError {
     }
 }
 impl Error for DeleteSubscriberError {}
+impl ServiceErrorCode for DeleteSubscriberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSubscriberError::AccessDenied(_) => "AccessDeniedException",
+            DeleteSubscriberError::InternalError(_) => "InternalErrorException",
+            DeleteSubscriberError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteSubscriberError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeBudget
 #[derive(Debug, PartialEq)]
 pub enum DescribeBudgetError {
@@ -1477,6 +1565,16 @@ impl fmt::Display for DescribeBudgetError {
     }
 }
 impl Error for DescribeBudgetError {}
+impl ServiceErrorCode for DescribeBudgetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBudgetError::AccessDenied(_) => "AccessDeniedException",
+            DescribeBudgetError::InternalError(_) => "InternalErrorException",
+            DescribeBudgetError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeBudgetError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeBudgetAction
 #[derive(Debug, PartialEq)]
 pub enum DescribeBudgetActionError {
@@ -1527,6 +1625,16 @@ impl fmt::Display for DescribeBudgetActionError {
     }
 }
 impl Error for DescribeBudgetActionError {}
+impl ServiceErrorCode for DescribeBudgetActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBudgetActionError::AccessDenied(_) => "AccessDeniedException",
+            DescribeBudgetActionError::InternalError(_) => "InternalErrorException",
+            DescribeBudgetActionError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeBudgetActionError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeBudgetActionHistories
 #[derive(Debug, PartialEq)]
 pub enum DescribeBudgetActionHistoriesError {
@@ -1597,6 +1705,17 @@ impl fmt::Display for DescribeBudgetActionHistoriesError {
     }
 }
 impl Error for DescribeBudgetActionHistoriesError {}
+impl ServiceErrorCode for DescribeBudgetActionHistoriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBudgetActionHistoriesError::AccessDenied(_) => "AccessDeniedException",
+            DescribeBudgetActionHistoriesError::InternalError(_) => "InternalErrorException",
+            DescribeBudgetActionHistoriesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeBudgetActionHistoriesError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeBudgetActionHistoriesError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeBudgetActionsForAccount
 #[derive(Debug, PartialEq)]
 pub enum DescribeBudgetActionsForAccountError {
@@ -1661,6 +1780,20 @@ impl fmt::Display for DescribeBudgetActionsForAccountError {
     }
 }
 impl Error for DescribeBudgetActionsForAccountError {}
+impl ServiceErrorCode for DescribeBudgetActionsForAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBudgetActionsForAccountError::AccessDenied(_) => "AccessDeniedException",
+            DescribeBudgetActionsForAccountError::InternalError(_) => "InternalErrorException",
+            DescribeBudgetActionsForAccountError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribeBudgetActionsForAccountError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeBudgetActionsForBudget
 #[derive(Debug, PartialEq)]
 pub enum DescribeBudgetActionsForBudgetError {
@@ -1731,6 +1864,17 @@ impl fmt::Display for DescribeBudgetActionsForBudgetError {
     }
 }
 impl Error for DescribeBudgetActionsForBudgetError {}
+impl ServiceErrorCode for DescribeBudgetActionsForBudgetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBudgetActionsForBudgetError::AccessDenied(_) => "AccessDeniedException",
+            DescribeBudgetActionsForBudgetError::InternalError(_) => "InternalErrorException",
+            DescribeBudgetActionsForBudgetError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeBudgetActionsForBudgetError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeBudgetActionsForBudgetError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeBudgetPerformanceHistory
 #[derive(Debug, PartialEq)]
 pub enum DescribeBudgetPerformanceHistoryError {
@@ -1815,6 +1959,24 @@ impl fmt::Display for DescribeBudgetPerformanceHistoryError {
     }
 }
 impl Error for DescribeBudgetPerformanceHistoryError {}
+impl ServiceErrorCode for DescribeBudgetPerformanceHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBudgetPerformanceHistoryError::AccessDenied(_) => "AccessDeniedException",
+            DescribeBudgetPerformanceHistoryError::ExpiredNextToken(_) => {
+                "ExpiredNextTokenException"
+            }
+            DescribeBudgetPerformanceHistoryError::InternalError(_) => "InternalErrorException",
+            DescribeBudgetPerformanceHistoryError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribeBudgetPerformanceHistoryError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            DescribeBudgetPerformanceHistoryError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeBudgets
 #[derive(Debug, PartialEq)]
 pub enum DescribeBudgetsError {
@@ -1875,6 +2037,18 @@ impl fmt::Display for DescribeBudgetsError {
     }
 }
 impl Error for DescribeBudgetsError {}
+impl ServiceErrorCode for DescribeBudgetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBudgetsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeBudgetsError::ExpiredNextToken(_) => "ExpiredNextTokenException",
+            DescribeBudgetsError::InternalError(_) => "InternalErrorException",
+            DescribeBudgetsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeBudgetsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeBudgetsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeNotificationsForBudget
 #[derive(Debug, PartialEq)]
 pub enum DescribeNotificationsForBudgetError {
@@ -1955,6 +2129,18 @@ impl fmt::Display for DescribeNotificationsForBudgetError {
     }
 }
 impl Error for DescribeNotificationsForBudgetError {}
+impl ServiceErrorCode for DescribeNotificationsForBudgetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeNotificationsForBudgetError::AccessDenied(_) => "AccessDeniedException",
+            DescribeNotificationsForBudgetError::ExpiredNextToken(_) => "ExpiredNextTokenException",
+            DescribeNotificationsForBudgetError::InternalError(_) => "InternalErrorException",
+            DescribeNotificationsForBudgetError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeNotificationsForBudgetError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeNotificationsForBudgetError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeSubscribersForNotification
 #[derive(Debug, PartialEq)]
 pub enum DescribeSubscribersForNotificationError {
@@ -2039,6 +2225,24 @@ impl fmt::Display for DescribeSubscribersForNotificationError {
     }
 }
 impl Error for DescribeSubscribersForNotificationError {}
+impl ServiceErrorCode for DescribeSubscribersForNotificationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSubscribersForNotificationError::AccessDenied(_) => "AccessDeniedException",
+            DescribeSubscribersForNotificationError::ExpiredNextToken(_) => {
+                "ExpiredNextTokenException"
+            }
+            DescribeSubscribersForNotificationError::InternalError(_) => "InternalErrorException",
+            DescribeSubscribersForNotificationError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribeSubscribersForNotificationError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            DescribeSubscribersForNotificationError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ExecuteBudgetAction
 #[derive(Debug, PartialEq)]
 pub enum ExecuteBudgetActionError {
@@ -2095,6 +2299,17 @@ impl fmt::Display for ExecuteBudgetActionError {
     }
 }
 impl Error for ExecuteBudgetActionError {}
+impl ServiceErrorCode for ExecuteBudgetActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExecuteBudgetActionError::AccessDenied(_) => "AccessDeniedException",
+            ExecuteBudgetActionError::InternalError(_) => "InternalErrorException",
+            ExecuteBudgetActionError::InvalidParameter(_) => "InvalidParameterException",
+            ExecuteBudgetActionError::NotFound(_) => "NotFoundException",
+            ExecuteBudgetActionError::ResourceLocked(_) => "ResourceLockedException",
+        }
+    }
+}
 /// Errors returned by UpdateBudget
 #[derive(Debug, PartialEq)]
 pub enum UpdateBudgetError {
@@ -2143,6 +2358,16 @@ impl fmt::Display for UpdateBudgetError {
     }
 }
 impl Error for UpdateBudgetError {}
+impl ServiceErrorCode for UpdateBudgetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateBudgetError::AccessDenied(_) => "AccessDeniedException",
+            UpdateBudgetError::InternalError(_) => "InternalErrorException",
+            UpdateBudgetError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateBudgetError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateBudgetAction
 #[derive(Debug, PartialEq)]
 pub enum UpdateBudgetActionError {
@@ -2197,6 +2422,17 @@ impl fmt::Display for UpdateBudgetActionError {
     }
 }
 impl Error for UpdateBudgetActionError {}
+impl ServiceErrorCode for UpdateBudgetActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateBudgetActionError::AccessDenied(_) => "AccessDeniedException",
+            UpdateBudgetActionError::InternalError(_) => "InternalErrorException",
+            UpdateBudgetActionError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateBudgetActionError::NotFound(_) => "NotFoundException",
+            UpdateBudgetActionError::ResourceLocked(_) => "ResourceLockedException",
+        }
+    }
+}
 /// Errors returned by UpdateNotification
 #[derive(Debug, PartialEq)]
 pub enum UpdateNotificationError {
@@ -2251,6 +2487,17 @@ impl fmt::Display for UpdateNotificationError {
     }
 }
 impl Error for UpdateNotificationError {}
+impl ServiceErrorCode for UpdateNotificationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateNotificationError::AccessDenied(_) => "AccessDeniedException",
+            UpdateNotificationError::DuplicateRecord(_) => "DuplicateRecordException",
+            UpdateNotificationError::InternalError(_) => "InternalErrorException",
+            UpdateNotificationError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateNotificationError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateSubscriber
 #[derive(Debug, PartialEq)]
 pub enum UpdateSubscriberError {
@@ -2305,6 +2552,17 @@ impl fmt::Display for UpdateSubscriberError {
     }
 }
 impl Error for UpdateSubscriberError {}
+impl ServiceErrorCode for UpdateSubscriberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSubscriberError::AccessDenied(_) => "AccessDeniedException",
+            UpdateSubscriberError::DuplicateRecord(_) => "DuplicateRecordException",
+            UpdateSubscriberError::InternalError(_) => "InternalErrorException",
+            UpdateSubscriberError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateSubscriberError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWSBudgets API. AWSBudgets clients implement this trait.
 #[async_trait]
 pub trait Budgets {
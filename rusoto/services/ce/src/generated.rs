@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2690,6 +2690,13 @@ impl fmt::Display for CreateAnomalyMonitorError {
     }
 }
 impl Error for CreateAnomalyMonitorError {}
+impl ServiceErrorCode for CreateAnomalyMonitorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAnomalyMonitorError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateAnomalySubscription
 #[derive(Debug, PartialEq)]
 pub enum CreateAnomalySubscriptionError {
@@ -2730,6 +2737,14 @@ impl fmt::Display for CreateAnomalySubscriptionError {
     }
 }
 impl Error for CreateAnomalySubscriptionError {}
+impl ServiceErrorCode for CreateAnomalySubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAnomalySubscriptionError::LimitExceeded(_) => "LimitExceededException",
+            CreateAnomalySubscriptionError::UnknownMonitor(_) => "UnknownMonitorException",
+        }
+    }
+}
 /// Errors returned by CreateCostCategoryDefinition
 #[derive(Debug, PartialEq)]
 pub enum CreateCostCategoryDefinitionError {
@@ -2774,6 +2789,16 @@ impl fmt::Display for CreateCostCategoryDefinitionError {
     }
 }
 impl Error for CreateCostCategoryDefinitionError {}
+impl ServiceErrorCode for CreateCostCategoryDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCostCategoryDefinitionError::LimitExceeded(_) => "LimitExceededException",
+            CreateCostCategoryDefinitionError::ServiceQuotaExceeded(_) => {
+                "ServiceQuotaExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteAnomalyMonitor
 #[derive(Debug, PartialEq)]
 pub enum DeleteAnomalyMonitorError {
@@ -2810,6 +2835,14 @@ impl fmt::Display for DeleteAnomalyMonitorError {
     }
 }
 impl Error for DeleteAnomalyMonitorError {}
+impl ServiceErrorCode for DeleteAnomalyMonitorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAnomalyMonitorError::LimitExceeded(_) => "LimitExceededException",
+            DeleteAnomalyMonitorError::UnknownMonitor(_) => "UnknownMonitorException",
+        }
+    }
+}
 /// Errors returned by DeleteAnomalySubscription
 #[derive(Debug, PartialEq)]
 pub enum DeleteAnomalySubscriptionError {
@@ -2852,6 +2885,16 @@ impl fmt::Display for DeleteAnomalySubscriptionError {
     }
 }
 impl Error for DeleteAnomalySubscriptionError {}
+impl ServiceErrorCode for DeleteAnomalySubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAnomalySubscriptionError::LimitExceeded(_) => "LimitExceededException",
+            DeleteAnomalySubscriptionError::UnknownSubscription(_) => {
+                "UnknownSubscriptionException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteCostCategoryDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteCostCategoryDefinitionError {
@@ -2896,6 +2939,14 @@ impl fmt::Display for DeleteCostCategoryDefinitionError {
     }
 }
 impl Error for DeleteCostCategoryDefinitionError {}
+impl ServiceErrorCode for DeleteCostCategoryDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCostCategoryDefinitionError::LimitExceeded(_) => "LimitExceededException",
+            DeleteCostCategoryDefinitionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeCostCategoryDefinition
 #[derive(Debug, PartialEq)]
 pub enum DescribeCostCategoryDefinitionError {
@@ -2940,6 +2991,14 @@ impl fmt::Display for DescribeCostCategoryDefinitionError {
     }
 }
 impl Error for DescribeCostCategoryDefinitionError {}
+impl ServiceErrorCode for DescribeCostCategoryDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCostCategoryDefinitionError::LimitExceeded(_) => "LimitExceededException",
+            DescribeCostCategoryDefinitionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetAnomalies
 #[derive(Debug, PartialEq)]
 pub enum GetAnomaliesError {
@@ -2976,6 +3035,14 @@ impl fmt::Display for GetAnomaliesError {
     }
 }
 impl Error for GetAnomaliesError {}
+impl ServiceErrorCode for GetAnomaliesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAnomaliesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetAnomaliesError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetAnomalyMonitors
 #[derive(Debug, PartialEq)]
 pub enum GetAnomalyMonitorsError {
@@ -3018,6 +3085,15 @@ impl fmt::Display for GetAnomalyMonitorsError {
     }
 }
 impl Error for GetAnomalyMonitorsError {}
+impl ServiceErrorCode for GetAnomalyMonitorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAnomalyMonitorsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetAnomalyMonitorsError::LimitExceeded(_) => "LimitExceededException",
+            GetAnomalyMonitorsError::UnknownMonitor(_) => "UnknownMonitorException",
+        }
+    }
+}
 /// Errors returned by GetAnomalySubscriptions
 #[derive(Debug, PartialEq)]
 pub enum GetAnomalySubscriptionsError {
@@ -3066,6 +3142,15 @@ impl fmt::Display for GetAnomalySubscriptionsError {
     }
 }
 impl Error for GetAnomalySubscriptionsError {}
+impl ServiceErrorCode for GetAnomalySubscriptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAnomalySubscriptionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetAnomalySubscriptionsError::LimitExceeded(_) => "LimitExceededException",
+            GetAnomalySubscriptionsError::UnknownSubscription(_) => "UnknownSubscriptionException",
+        }
+    }
+}
 /// Errors returned by GetCostAndUsage
 #[derive(Debug, PartialEq)]
 pub enum GetCostAndUsageError {
@@ -3120,6 +3205,17 @@ impl fmt::Display for GetCostAndUsageError {
     }
 }
 impl Error for GetCostAndUsageError {}
+impl ServiceErrorCode for GetCostAndUsageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCostAndUsageError::BillExpiration(_) => "BillExpirationException",
+            GetCostAndUsageError::DataUnavailable(_) => "DataUnavailableException",
+            GetCostAndUsageError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetCostAndUsageError::LimitExceeded(_) => "LimitExceededException",
+            GetCostAndUsageError::RequestChanged(_) => "RequestChangedException",
+        }
+    }
+}
 /// Errors returned by GetCostAndUsageWithResources
 #[derive(Debug, PartialEq)]
 pub enum GetCostAndUsageWithResourcesError {
@@ -3188,6 +3284,17 @@ impl fmt::Display for GetCostAndUsageWithResourcesError {
     }
 }
 impl Error for GetCostAndUsageWithResourcesError {}
+impl ServiceErrorCode for GetCostAndUsageWithResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCostAndUsageWithResourcesError::BillExpiration(_) => "BillExpirationException",
+            GetCostAndUsageWithResourcesError::DataUnavailable(_) => "DataUnavailableException",
+            GetCostAndUsageWithResourcesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetCostAndUsageWithResourcesError::LimitExceeded(_) => "LimitExceededException",
+            GetCostAndUsageWithResourcesError::RequestChanged(_) => "RequestChangedException",
+        }
+    }
+}
 /// Errors returned by GetCostForecast
 #[derive(Debug, PartialEq)]
 pub enum GetCostForecastError {
@@ -3224,6 +3331,14 @@ impl fmt::Display for GetCostForecastError {
     }
 }
 impl Error for GetCostForecastError {}
+impl ServiceErrorCode for GetCostForecastError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCostForecastError::DataUnavailable(_) => "DataUnavailableException",
+            GetCostForecastError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetDimensionValues
 #[derive(Debug, PartialEq)]
 pub enum GetDimensionValuesError {
@@ -3278,6 +3393,17 @@ impl fmt::Display for GetDimensionValuesError {
     }
 }
 impl Error for GetDimensionValuesError {}
+impl ServiceErrorCode for GetDimensionValuesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDimensionValuesError::BillExpiration(_) => "BillExpirationException",
+            GetDimensionValuesError::DataUnavailable(_) => "DataUnavailableException",
+            GetDimensionValuesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetDimensionValuesError::LimitExceeded(_) => "LimitExceededException",
+            GetDimensionValuesError::RequestChanged(_) => "RequestChangedException",
+        }
+    }
+}
 /// Errors returned by GetReservationCoverage
 #[derive(Debug, PartialEq)]
 pub enum GetReservationCoverageError {
@@ -3326,6 +3452,15 @@ impl fmt::Display for GetReservationCoverageError {
     }
 }
 impl Error for GetReservationCoverageError {}
+impl ServiceErrorCode for GetReservationCoverageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetReservationCoverageError::DataUnavailable(_) => "DataUnavailableException",
+            GetReservationCoverageError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetReservationCoverageError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetReservationPurchaseRecommendation
 #[derive(Debug, PartialEq)]
 pub enum GetReservationPurchaseRecommendationError {
@@ -3382,6 +3517,19 @@ impl fmt::Display for GetReservationPurchaseRecommendationError {
     }
 }
 impl Error for GetReservationPurchaseRecommendationError {}
+impl ServiceErrorCode for GetReservationPurchaseRecommendationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetReservationPurchaseRecommendationError::DataUnavailable(_) => {
+                "DataUnavailableException"
+            }
+            GetReservationPurchaseRecommendationError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            GetReservationPurchaseRecommendationError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetReservationUtilization
 #[derive(Debug, PartialEq)]
 pub enum GetReservationUtilizationError {
@@ -3430,6 +3578,15 @@ impl fmt::Display for GetReservationUtilizationError {
     }
 }
 impl Error for GetReservationUtilizationError {}
+impl ServiceErrorCode for GetReservationUtilizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetReservationUtilizationError::DataUnavailable(_) => "DataUnavailableException",
+            GetReservationUtilizationError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetReservationUtilizationError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetRightsizingRecommendation
 #[derive(Debug, PartialEq)]
 pub enum GetRightsizingRecommendationError {
@@ -3474,6 +3631,14 @@ impl fmt::Display for GetRightsizingRecommendationError {
     }
 }
 impl Error for GetRightsizingRecommendationError {}
+impl ServiceErrorCode for GetRightsizingRecommendationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRightsizingRecommendationError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetRightsizingRecommendationError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetSavingsPlansCoverage
 #[derive(Debug, PartialEq)]
 pub enum GetSavingsPlansCoverageError {
@@ -3522,6 +3687,15 @@ impl fmt::Display for GetSavingsPlansCoverageError {
     }
 }
 impl Error for GetSavingsPlansCoverageError {}
+impl ServiceErrorCode for GetSavingsPlansCoverageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSavingsPlansCoverageError::DataUnavailable(_) => "DataUnavailableException",
+            GetSavingsPlansCoverageError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetSavingsPlansCoverageError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetSavingsPlansPurchaseRecommendation
 #[derive(Debug, PartialEq)]
 pub enum GetSavingsPlansPurchaseRecommendationError {
@@ -3568,6 +3742,18 @@ impl fmt::Display for GetSavingsPlansPurchaseRecommendationError {
     }
 }
 impl Error for GetSavingsPlansPurchaseRecommendationError {}
+impl ServiceErrorCode for GetSavingsPlansPurchaseRecommendationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSavingsPlansPurchaseRecommendationError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            GetSavingsPlansPurchaseRecommendationError::LimitExceeded(_) => {
+                "LimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by GetSavingsPlansUtilization
 #[derive(Debug, PartialEq)]
 pub enum GetSavingsPlansUtilizationError {
@@ -3610,6 +3796,14 @@ impl fmt::Display for GetSavingsPlansUtilizationError {
     }
 }
 impl Error for GetSavingsPlansUtilizationError {}
+impl ServiceErrorCode for GetSavingsPlansUtilizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSavingsPlansUtilizationError::DataUnavailable(_) => "DataUnavailableException",
+            GetSavingsPlansUtilizationError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetSavingsPlansUtilizationDetails
 #[derive(Debug, PartialEq)]
 pub enum GetSavingsPlansUtilizationDetailsError {
@@ -3666,6 +3860,19 @@ impl fmt::Display for GetSavingsPlansUtilizationDetailsError {
     }
 }
 impl Error for GetSavingsPlansUtilizationDetailsError {}
+impl ServiceErrorCode for GetSavingsPlansUtilizationDetailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSavingsPlansUtilizationDetailsError::DataUnavailable(_) => {
+                "DataUnavailableException"
+            }
+            GetSavingsPlansUtilizationDetailsError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            GetSavingsPlansUtilizationDetailsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetTags
 #[derive(Debug, PartialEq)]
 pub enum GetTagsError {
@@ -3720,6 +3927,17 @@ impl fmt::Display for GetTagsError {
     }
 }
 impl Error for GetTagsError {}
+impl ServiceErrorCode for GetTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTagsError::BillExpiration(_) => "BillExpirationException",
+            GetTagsError::DataUnavailable(_) => "DataUnavailableException",
+            GetTagsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetTagsError::LimitExceeded(_) => "LimitExceededException",
+            GetTagsError::RequestChanged(_) => "RequestChangedException",
+        }
+    }
+}
 /// Errors returned by GetUsageForecast
 #[derive(Debug, PartialEq)]
 pub enum GetUsageForecastError {
@@ -3764,6 +3982,15 @@ impl fmt::Display for GetUsageForecastError {
     }
 }
 impl Error for GetUsageForecastError {}
+impl ServiceErrorCode for GetUsageForecastError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUsageForecastError::DataUnavailable(_) => "DataUnavailableException",
+            GetUsageForecastError::LimitExceeded(_) => "LimitExceededException",
+            GetUsageForecastError::UnresolvableUsageUnit(_) => "UnresolvableUsageUnitException",
+        }
+    }
+}
 /// Errors returned by ListCostCategoryDefinitions
 #[derive(Debug, PartialEq)]
 pub enum ListCostCategoryDefinitionsError {
@@ -3798,6 +4025,13 @@ impl fmt::Display for ListCostCategoryDefinitionsError {
     }
 }
 impl Error for ListCostCategoryDefinitionsError {}
+impl ServiceErrorCode for ListCostCategoryDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCostCategoryDefinitionsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by ProvideAnomalyFeedback
 #[derive(Debug, PartialEq)]
 pub enum ProvideAnomalyFeedbackError {
@@ -3830,6 +4064,13 @@ impl fmt::Display for ProvideAnomalyFeedbackError {
     }
 }
 impl Error for ProvideAnomalyFeedbackError {}
+impl ServiceErrorCode for ProvideAnomalyFeedbackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ProvideAnomalyFeedbackError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by UpdateAnomalyMonitor
 #[derive(Debug, PartialEq)]
 pub enum UpdateAnomalyMonitorError {
@@ -3866,6 +4107,14 @@ impl fmt::Display for UpdateAnomalyMonitorError {
     }
 }
 impl Error for UpdateAnomalyMonitorError {}
+impl ServiceErrorCode for UpdateAnomalyMonitorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAnomalyMonitorError::LimitExceeded(_) => "LimitExceededException",
+            UpdateAnomalyMonitorError::UnknownMonitor(_) => "UnknownMonitorException",
+        }
+    }
+}
 /// Errors returned by UpdateAnomalySubscription
 #[derive(Debug, PartialEq)]
 pub enum UpdateAnomalySubscriptionError {
@@ -3916,6 +4165,17 @@ impl fmt::Display for UpdateAnomalySubscriptionError {
     }
 }
 impl Error for UpdateAnomalySubscriptionError {}
+impl ServiceErrorCode for UpdateAnomalySubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAnomalySubscriptionError::LimitExceeded(_) => "LimitExceededException",
+            UpdateAnomalySubscriptionError::UnknownMonitor(_) => "UnknownMonitorException",
+            UpdateAnomalySubscriptionError::UnknownSubscription(_) => {
+                "UnknownSubscriptionException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateCostCategoryDefinition
 #[derive(Debug, PartialEq)]
 pub enum UpdateCostCategoryDefinitionError {
@@ -3970,6 +4230,17 @@ impl fmt::Display for UpdateCostCategoryDefinitionError {
     }
 }
 impl Error for UpdateCostCategoryDefinitionError {}
+impl ServiceErrorCode for UpdateCostCategoryDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCostCategoryDefinitionError::LimitExceeded(_) => "LimitExceededException",
+            UpdateCostCategoryDefinitionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateCostCategoryDefinitionError::ServiceQuotaExceeded(_) => {
+                "ServiceQuotaExceededException"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Cost Explorer API. AWS Cost Explorer clients implement this trait.
 #[async_trait]
 pub trait CostExplorer {
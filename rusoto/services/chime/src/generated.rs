@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -876,6 +876,7 @@ pub struct CreateAppInstanceAdminResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateAppInstanceRequest {
     /// <p>The <code>ClientRequestToken</code> of the app instance.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>The metadata of the app instance. Limited to a 1KB string in UTF-8.</p>
@@ -906,6 +907,7 @@ pub struct CreateAppInstanceUserRequest {
     #[serde(rename = "AppInstanceUserId")]
     pub app_instance_user_id: String,
     /// <p>The token assigned to the user requesting an app instance.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>The request's metadata. Limited to a 1KB string in UTF-8.</p>
@@ -1087,6 +1089,7 @@ pub struct CreateChannelRequest {
     #[serde(rename = "AppInstanceArn")]
     pub app_instance_arn: String,
     /// <p>The client token for the request. An <code>Idempotency</code> token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>The metadata of the creation request. Limited to 1KB and UTF-8.</p>
@@ -1148,6 +1151,7 @@ pub struct CreateMeetingDialOutResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateMeetingRequest {
     /// <p>The unique identifier for the client request. Use a different token for different meetings.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>The external meeting ID.</p>
@@ -1189,6 +1193,7 @@ pub struct CreateMeetingWithAttendeesRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attendees: Option<Vec<CreateAttendeeRequestItem>>,
     /// <p>The unique identifier for the client request. Use a different token for different meetings.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>The external meeting ID.</p>
@@ -1325,6 +1330,7 @@ pub struct CreateRoomRequest {
     #[serde(rename = "AccountId")]
     pub account_id: String,
     /// <p>The idempotency token for the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -4459,6 +4465,7 @@ pub struct SendChannelMessageRequest {
     #[serde(rename = "ChannelArn")]
     pub channel_arn: String,
     /// <p>The <code>Idempotency</code> token for each client request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>The content of the message.</p>
@@ -5516,6 +5523,24 @@ impl fmt::Display for AssociatePhoneNumberWithUserError {
     }
 }
 impl Error for AssociatePhoneNumberWithUserError {}
+impl ServiceErrorCode for AssociatePhoneNumberWithUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociatePhoneNumberWithUserError::AccessDenied(_) => "AccessDeniedException",
+            AssociatePhoneNumberWithUserError::BadRequest(_) => "BadRequestException",
+            AssociatePhoneNumberWithUserError::Forbidden(_) => "ForbiddenException",
+            AssociatePhoneNumberWithUserError::NotFound(_) => "NotFoundException",
+            AssociatePhoneNumberWithUserError::ServiceFailure(_) => "ServiceFailureException",
+            AssociatePhoneNumberWithUserError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            AssociatePhoneNumberWithUserError::ThrottledClient(_) => "ThrottledClientException",
+            AssociatePhoneNumberWithUserError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by AssociatePhoneNumbersWithVoiceConnector
 #[derive(Debug, PartialEq)]
 pub enum AssociatePhoneNumbersWithVoiceConnectorError {
@@ -5622,6 +5647,30 @@ impl fmt::Display for AssociatePhoneNumbersWithVoiceConnectorError {
     }
 }
 impl Error for AssociatePhoneNumbersWithVoiceConnectorError {}
+impl ServiceErrorCode for AssociatePhoneNumbersWithVoiceConnectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociatePhoneNumbersWithVoiceConnectorError::AccessDenied(_) => {
+                "AccessDeniedException"
+            }
+            AssociatePhoneNumbersWithVoiceConnectorError::BadRequest(_) => "BadRequestException",
+            AssociatePhoneNumbersWithVoiceConnectorError::Forbidden(_) => "ForbiddenException",
+            AssociatePhoneNumbersWithVoiceConnectorError::NotFound(_) => "NotFoundException",
+            AssociatePhoneNumbersWithVoiceConnectorError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            AssociatePhoneNumbersWithVoiceConnectorError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            AssociatePhoneNumbersWithVoiceConnectorError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            AssociatePhoneNumbersWithVoiceConnectorError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by AssociatePhoneNumbersWithVoiceConnectorGroup
 #[derive(Debug, PartialEq)]
 pub enum AssociatePhoneNumbersWithVoiceConnectorGroupError {
@@ -5732,6 +5781,32 @@ impl fmt::Display for AssociatePhoneNumbersWithVoiceConnectorGroupError {
     }
 }
 impl Error for AssociatePhoneNumbersWithVoiceConnectorGroupError {}
+impl ServiceErrorCode for AssociatePhoneNumbersWithVoiceConnectorGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociatePhoneNumbersWithVoiceConnectorGroupError::AccessDenied(_) => {
+                "AccessDeniedException"
+            }
+            AssociatePhoneNumbersWithVoiceConnectorGroupError::BadRequest(_) => {
+                "BadRequestException"
+            }
+            AssociatePhoneNumbersWithVoiceConnectorGroupError::Forbidden(_) => "ForbiddenException",
+            AssociatePhoneNumbersWithVoiceConnectorGroupError::NotFound(_) => "NotFoundException",
+            AssociatePhoneNumbersWithVoiceConnectorGroupError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            AssociatePhoneNumbersWithVoiceConnectorGroupError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            AssociatePhoneNumbersWithVoiceConnectorGroupError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            AssociatePhoneNumbersWithVoiceConnectorGroupError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by AssociateSigninDelegateGroupsWithAccount
 #[derive(Debug, PartialEq)]
 pub enum AssociateSigninDelegateGroupsWithAccountError {
@@ -5828,6 +5903,27 @@ impl fmt::Display for AssociateSigninDelegateGroupsWithAccountError {
     }
 }
 impl Error for AssociateSigninDelegateGroupsWithAccountError {}
+impl ServiceErrorCode for AssociateSigninDelegateGroupsWithAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateSigninDelegateGroupsWithAccountError::BadRequest(_) => "BadRequestException",
+            AssociateSigninDelegateGroupsWithAccountError::Forbidden(_) => "ForbiddenException",
+            AssociateSigninDelegateGroupsWithAccountError::NotFound(_) => "NotFoundException",
+            AssociateSigninDelegateGroupsWithAccountError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            AssociateSigninDelegateGroupsWithAccountError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            AssociateSigninDelegateGroupsWithAccountError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            AssociateSigninDelegateGroupsWithAccountError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by BatchCreateAttendee
 #[derive(Debug, PartialEq)]
 pub enum BatchCreateAttendeeError {
@@ -5906,6 +6002,20 @@ impl fmt::Display for BatchCreateAttendeeError {
     }
 }
 impl Error for BatchCreateAttendeeError {}
+impl ServiceErrorCode for BatchCreateAttendeeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchCreateAttendeeError::BadRequest(_) => "BadRequestException",
+            BatchCreateAttendeeError::Forbidden(_) => "ForbiddenException",
+            BatchCreateAttendeeError::NotFound(_) => "NotFoundException",
+            BatchCreateAttendeeError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            BatchCreateAttendeeError::ServiceFailure(_) => "ServiceFailureException",
+            BatchCreateAttendeeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            BatchCreateAttendeeError::ThrottledClient(_) => "ThrottledClientException",
+            BatchCreateAttendeeError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by BatchCreateRoomMembership
 #[derive(Debug, PartialEq)]
 pub enum BatchCreateRoomMembershipError {
@@ -5982,6 +6092,19 @@ impl fmt::Display for BatchCreateRoomMembershipError {
     }
 }
 impl Error for BatchCreateRoomMembershipError {}
+impl ServiceErrorCode for BatchCreateRoomMembershipError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchCreateRoomMembershipError::BadRequest(_) => "BadRequestException",
+            BatchCreateRoomMembershipError::Forbidden(_) => "ForbiddenException",
+            BatchCreateRoomMembershipError::NotFound(_) => "NotFoundException",
+            BatchCreateRoomMembershipError::ServiceFailure(_) => "ServiceFailureException",
+            BatchCreateRoomMembershipError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            BatchCreateRoomMembershipError::ThrottledClient(_) => "ThrottledClientException",
+            BatchCreateRoomMembershipError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by BatchDeletePhoneNumber
 #[derive(Debug, PartialEq)]
 pub enum BatchDeletePhoneNumberError {
@@ -6056,6 +6179,19 @@ impl fmt::Display for BatchDeletePhoneNumberError {
     }
 }
 impl Error for BatchDeletePhoneNumberError {}
+impl ServiceErrorCode for BatchDeletePhoneNumberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDeletePhoneNumberError::BadRequest(_) => "BadRequestException",
+            BatchDeletePhoneNumberError::Forbidden(_) => "ForbiddenException",
+            BatchDeletePhoneNumberError::NotFound(_) => "NotFoundException",
+            BatchDeletePhoneNumberError::ServiceFailure(_) => "ServiceFailureException",
+            BatchDeletePhoneNumberError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            BatchDeletePhoneNumberError::ThrottledClient(_) => "ThrottledClientException",
+            BatchDeletePhoneNumberError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by BatchSuspendUser
 #[derive(Debug, PartialEq)]
 pub enum BatchSuspendUserError {
@@ -6122,6 +6258,19 @@ impl fmt::Display for BatchSuspendUserError {
     }
 }
 impl Error for BatchSuspendUserError {}
+impl ServiceErrorCode for BatchSuspendUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchSuspendUserError::BadRequest(_) => "BadRequestException",
+            BatchSuspendUserError::Forbidden(_) => "ForbiddenException",
+            BatchSuspendUserError::NotFound(_) => "NotFoundException",
+            BatchSuspendUserError::ServiceFailure(_) => "ServiceFailureException",
+            BatchSuspendUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            BatchSuspendUserError::ThrottledClient(_) => "ThrottledClientException",
+            BatchSuspendUserError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by BatchUnsuspendUser
 #[derive(Debug, PartialEq)]
 pub enum BatchUnsuspendUserError {
@@ -6192,6 +6341,19 @@ impl fmt::Display for BatchUnsuspendUserError {
     }
 }
 impl Error for BatchUnsuspendUserError {}
+impl ServiceErrorCode for BatchUnsuspendUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchUnsuspendUserError::BadRequest(_) => "BadRequestException",
+            BatchUnsuspendUserError::Forbidden(_) => "ForbiddenException",
+            BatchUnsuspendUserError::NotFound(_) => "NotFoundException",
+            BatchUnsuspendUserError::ServiceFailure(_) => "ServiceFailureException",
+            BatchUnsuspendUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            BatchUnsuspendUserError::ThrottledClient(_) => "ThrottledClientException",
+            BatchUnsuspendUserError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by BatchUpdatePhoneNumber
 #[derive(Debug, PartialEq)]
 pub enum BatchUpdatePhoneNumberError {
@@ -6266,6 +6428,19 @@ impl fmt::Display for BatchUpdatePhoneNumberError {
     }
 }
 impl Error for BatchUpdatePhoneNumberError {}
+impl ServiceErrorCode for BatchUpdatePhoneNumberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchUpdatePhoneNumberError::BadRequest(_) => "BadRequestException",
+            BatchUpdatePhoneNumberError::Forbidden(_) => "ForbiddenException",
+            BatchUpdatePhoneNumberError::NotFound(_) => "NotFoundException",
+            BatchUpdatePhoneNumberError::ServiceFailure(_) => "ServiceFailureException",
+            BatchUpdatePhoneNumberError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            BatchUpdatePhoneNumberError::ThrottledClient(_) => "ThrottledClientException",
+            BatchUpdatePhoneNumberError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by BatchUpdateUser
 #[derive(Debug, PartialEq)]
 pub enum BatchUpdateUserError {
@@ -6332,6 +6507,19 @@ impl fmt::Display for BatchUpdateUserError {
     }
 }
 impl Error for BatchUpdateUserError {}
+impl ServiceErrorCode for BatchUpdateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchUpdateUserError::BadRequest(_) => "BadRequestException",
+            BatchUpdateUserError::Forbidden(_) => "ForbiddenException",
+            BatchUpdateUserError::NotFound(_) => "NotFoundException",
+            BatchUpdateUserError::ServiceFailure(_) => "ServiceFailureException",
+            BatchUpdateUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            BatchUpdateUserError::ThrottledClient(_) => "ThrottledClientException",
+            BatchUpdateUserError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateAccount
 #[derive(Debug, PartialEq)]
 pub enum CreateAccountError {
@@ -6398,6 +6586,19 @@ impl fmt::Display for CreateAccountError {
     }
 }
 impl Error for CreateAccountError {}
+impl ServiceErrorCode for CreateAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAccountError::BadRequest(_) => "BadRequestException",
+            CreateAccountError::Forbidden(_) => "ForbiddenException",
+            CreateAccountError::NotFound(_) => "NotFoundException",
+            CreateAccountError::ServiceFailure(_) => "ServiceFailureException",
+            CreateAccountError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateAccountError::ThrottledClient(_) => "ThrottledClientException",
+            CreateAccountError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateAppInstance
 #[derive(Debug, PartialEq)]
 pub enum CreateAppInstanceError {
@@ -6476,6 +6677,20 @@ impl fmt::Display for CreateAppInstanceError {
     }
 }
 impl Error for CreateAppInstanceError {}
+impl ServiceErrorCode for CreateAppInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAppInstanceError::BadRequest(_) => "BadRequestException",
+            CreateAppInstanceError::Conflict(_) => "ConflictException",
+            CreateAppInstanceError::Forbidden(_) => "ForbiddenException",
+            CreateAppInstanceError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateAppInstanceError::ServiceFailure(_) => "ServiceFailureException",
+            CreateAppInstanceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateAppInstanceError::ThrottledClient(_) => "ThrottledClientException",
+            CreateAppInstanceError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateAppInstanceAdmin
 #[derive(Debug, PartialEq)]
 pub enum CreateAppInstanceAdminError {
@@ -6558,6 +6773,22 @@ impl fmt::Display for CreateAppInstanceAdminError {
     }
 }
 impl Error for CreateAppInstanceAdminError {}
+impl ServiceErrorCode for CreateAppInstanceAdminError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAppInstanceAdminError::BadRequest(_) => "BadRequestException",
+            CreateAppInstanceAdminError::Conflict(_) => "ConflictException",
+            CreateAppInstanceAdminError::Forbidden(_) => "ForbiddenException",
+            CreateAppInstanceAdminError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            CreateAppInstanceAdminError::ServiceFailure(_) => "ServiceFailureException",
+            CreateAppInstanceAdminError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateAppInstanceAdminError::ThrottledClient(_) => "ThrottledClientException",
+            CreateAppInstanceAdminError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateAppInstanceUser
 #[derive(Debug, PartialEq)]
 pub enum CreateAppInstanceUserError {
@@ -6640,6 +6871,22 @@ impl fmt::Display for CreateAppInstanceUserError {
     }
 }
 impl Error for CreateAppInstanceUserError {}
+impl ServiceErrorCode for CreateAppInstanceUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAppInstanceUserError::BadRequest(_) => "BadRequestException",
+            CreateAppInstanceUserError::Conflict(_) => "ConflictException",
+            CreateAppInstanceUserError::Forbidden(_) => "ForbiddenException",
+            CreateAppInstanceUserError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            CreateAppInstanceUserError::ServiceFailure(_) => "ServiceFailureException",
+            CreateAppInstanceUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateAppInstanceUserError::ThrottledClient(_) => "ThrottledClientException",
+            CreateAppInstanceUserError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateAttendee
 #[derive(Debug, PartialEq)]
 pub enum CreateAttendeeError {
@@ -6714,6 +6961,20 @@ impl fmt::Display for CreateAttendeeError {
     }
 }
 impl Error for CreateAttendeeError {}
+impl ServiceErrorCode for CreateAttendeeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAttendeeError::BadRequest(_) => "BadRequestException",
+            CreateAttendeeError::Forbidden(_) => "ForbiddenException",
+            CreateAttendeeError::NotFound(_) => "NotFoundException",
+            CreateAttendeeError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateAttendeeError::ServiceFailure(_) => "ServiceFailureException",
+            CreateAttendeeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateAttendeeError::ThrottledClient(_) => "ThrottledClientException",
+            CreateAttendeeError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateBot
 #[derive(Debug, PartialEq)]
 pub enum CreateBotError {
@@ -6786,6 +7047,20 @@ impl fmt::Display for CreateBotError {
     }
 }
 impl Error for CreateBotError {}
+impl ServiceErrorCode for CreateBotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBotError::BadRequest(_) => "BadRequestException",
+            CreateBotError::Forbidden(_) => "ForbiddenException",
+            CreateBotError::NotFound(_) => "NotFoundException",
+            CreateBotError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateBotError::ServiceFailure(_) => "ServiceFailureException",
+            CreateBotError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateBotError::ThrottledClient(_) => "ThrottledClientException",
+            CreateBotError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateChannel
 #[derive(Debug, PartialEq)]
 pub enum CreateChannelError {
@@ -6858,6 +7133,20 @@ impl fmt::Display for CreateChannelError {
     }
 }
 impl Error for CreateChannelError {}
+impl ServiceErrorCode for CreateChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateChannelError::BadRequest(_) => "BadRequestException",
+            CreateChannelError::Conflict(_) => "ConflictException",
+            CreateChannelError::Forbidden(_) => "ForbiddenException",
+            CreateChannelError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateChannelError::ServiceFailure(_) => "ServiceFailureException",
+            CreateChannelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateChannelError::ThrottledClient(_) => "ThrottledClientException",
+            CreateChannelError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateChannelBan
 #[derive(Debug, PartialEq)]
 pub enum CreateChannelBanError {
@@ -6932,6 +7221,20 @@ impl fmt::Display for CreateChannelBanError {
     }
 }
 impl Error for CreateChannelBanError {}
+impl ServiceErrorCode for CreateChannelBanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateChannelBanError::BadRequest(_) => "BadRequestException",
+            CreateChannelBanError::Conflict(_) => "ConflictException",
+            CreateChannelBanError::Forbidden(_) => "ForbiddenException",
+            CreateChannelBanError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateChannelBanError::ServiceFailure(_) => "ServiceFailureException",
+            CreateChannelBanError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateChannelBanError::ThrottledClient(_) => "ThrottledClientException",
+            CreateChannelBanError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateChannelMembership
 #[derive(Debug, PartialEq)]
 pub enum CreateChannelMembershipError {
@@ -7016,6 +7319,22 @@ impl fmt::Display for CreateChannelMembershipError {
     }
 }
 impl Error for CreateChannelMembershipError {}
+impl ServiceErrorCode for CreateChannelMembershipError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateChannelMembershipError::BadRequest(_) => "BadRequestException",
+            CreateChannelMembershipError::Conflict(_) => "ConflictException",
+            CreateChannelMembershipError::Forbidden(_) => "ForbiddenException",
+            CreateChannelMembershipError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            CreateChannelMembershipError::ServiceFailure(_) => "ServiceFailureException",
+            CreateChannelMembershipError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateChannelMembershipError::ThrottledClient(_) => "ThrottledClientException",
+            CreateChannelMembershipError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateChannelModerator
 #[derive(Debug, PartialEq)]
 pub enum CreateChannelModeratorError {
@@ -7098,6 +7417,22 @@ impl fmt::Display for CreateChannelModeratorError {
     }
 }
 impl Error for CreateChannelModeratorError {}
+impl ServiceErrorCode for CreateChannelModeratorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateChannelModeratorError::BadRequest(_) => "BadRequestException",
+            CreateChannelModeratorError::Conflict(_) => "ConflictException",
+            CreateChannelModeratorError::Forbidden(_) => "ForbiddenException",
+            CreateChannelModeratorError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            CreateChannelModeratorError::ServiceFailure(_) => "ServiceFailureException",
+            CreateChannelModeratorError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateChannelModeratorError::ThrottledClient(_) => "ThrottledClientException",
+            CreateChannelModeratorError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateMeeting
 #[derive(Debug, PartialEq)]
 pub enum CreateMeetingError {
@@ -7164,6 +7499,19 @@ impl fmt::Display for CreateMeetingError {
     }
 }
 impl Error for CreateMeetingError {}
+impl ServiceErrorCode for CreateMeetingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMeetingError::BadRequest(_) => "BadRequestException",
+            CreateMeetingError::Forbidden(_) => "ForbiddenException",
+            CreateMeetingError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateMeetingError::ServiceFailure(_) => "ServiceFailureException",
+            CreateMeetingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateMeetingError::ThrottledClient(_) => "ThrottledClientException",
+            CreateMeetingError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateMeetingDialOut
 #[derive(Debug, PartialEq)]
 pub enum CreateMeetingDialOutError {
@@ -7238,6 +7586,19 @@ impl fmt::Display for CreateMeetingDialOutError {
     }
 }
 impl Error for CreateMeetingDialOutError {}
+impl ServiceErrorCode for CreateMeetingDialOutError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMeetingDialOutError::BadRequest(_) => "BadRequestException",
+            CreateMeetingDialOutError::Forbidden(_) => "ForbiddenException",
+            CreateMeetingDialOutError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateMeetingDialOutError::ServiceFailure(_) => "ServiceFailureException",
+            CreateMeetingDialOutError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateMeetingDialOutError::ThrottledClient(_) => "ThrottledClientException",
+            CreateMeetingDialOutError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateMeetingWithAttendees
 #[derive(Debug, PartialEq)]
 pub enum CreateMeetingWithAttendeesError {
@@ -7326,6 +7687,21 @@ impl fmt::Display for CreateMeetingWithAttendeesError {
     }
 }
 impl Error for CreateMeetingWithAttendeesError {}
+impl ServiceErrorCode for CreateMeetingWithAttendeesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMeetingWithAttendeesError::BadRequest(_) => "BadRequestException",
+            CreateMeetingWithAttendeesError::Forbidden(_) => "ForbiddenException",
+            CreateMeetingWithAttendeesError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            CreateMeetingWithAttendeesError::ServiceFailure(_) => "ServiceFailureException",
+            CreateMeetingWithAttendeesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateMeetingWithAttendeesError::ThrottledClient(_) => "ThrottledClientException",
+            CreateMeetingWithAttendeesError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreatePhoneNumberOrder
 #[derive(Debug, PartialEq)]
 pub enum CreatePhoneNumberOrderError {
@@ -7408,6 +7784,22 @@ impl fmt::Display for CreatePhoneNumberOrderError {
     }
 }
 impl Error for CreatePhoneNumberOrderError {}
+impl ServiceErrorCode for CreatePhoneNumberOrderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePhoneNumberOrderError::AccessDenied(_) => "AccessDeniedException",
+            CreatePhoneNumberOrderError::BadRequest(_) => "BadRequestException",
+            CreatePhoneNumberOrderError::Forbidden(_) => "ForbiddenException",
+            CreatePhoneNumberOrderError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            CreatePhoneNumberOrderError::ServiceFailure(_) => "ServiceFailureException",
+            CreatePhoneNumberOrderError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreatePhoneNumberOrderError::ThrottledClient(_) => "ThrottledClientException",
+            CreatePhoneNumberOrderError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateProxySession
 #[derive(Debug, PartialEq)]
 pub enum CreateProxySessionError {
@@ -7478,6 +7870,19 @@ impl fmt::Display for CreateProxySessionError {
     }
 }
 impl Error for CreateProxySessionError {}
+impl ServiceErrorCode for CreateProxySessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProxySessionError::BadRequest(_) => "BadRequestException",
+            CreateProxySessionError::Forbidden(_) => "ForbiddenException",
+            CreateProxySessionError::NotFound(_) => "NotFoundException",
+            CreateProxySessionError::ServiceFailure(_) => "ServiceFailureException",
+            CreateProxySessionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateProxySessionError::ThrottledClient(_) => "ThrottledClientException",
+            CreateProxySessionError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateRoom
 #[derive(Debug, PartialEq)]
 pub enum CreateRoomError {
@@ -7550,6 +7955,20 @@ impl fmt::Display for CreateRoomError {
     }
 }
 impl Error for CreateRoomError {}
+impl ServiceErrorCode for CreateRoomError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRoomError::BadRequest(_) => "BadRequestException",
+            CreateRoomError::Forbidden(_) => "ForbiddenException",
+            CreateRoomError::NotFound(_) => "NotFoundException",
+            CreateRoomError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateRoomError::ServiceFailure(_) => "ServiceFailureException",
+            CreateRoomError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateRoomError::ThrottledClient(_) => "ThrottledClientException",
+            CreateRoomError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateRoomMembership
 #[derive(Debug, PartialEq)]
 pub enum CreateRoomMembershipError {
@@ -7636,6 +8055,21 @@ impl fmt::Display for CreateRoomMembershipError {
     }
 }
 impl Error for CreateRoomMembershipError {}
+impl ServiceErrorCode for CreateRoomMembershipError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRoomMembershipError::BadRequest(_) => "BadRequestException",
+            CreateRoomMembershipError::Conflict(_) => "ConflictException",
+            CreateRoomMembershipError::Forbidden(_) => "ForbiddenException",
+            CreateRoomMembershipError::NotFound(_) => "NotFoundException",
+            CreateRoomMembershipError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateRoomMembershipError::ServiceFailure(_) => "ServiceFailureException",
+            CreateRoomMembershipError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateRoomMembershipError::ThrottledClient(_) => "ThrottledClientException",
+            CreateRoomMembershipError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateSipMediaApplication
 #[derive(Debug, PartialEq)]
 pub enum CreateSipMediaApplicationError {
@@ -7730,6 +8164,23 @@ impl fmt::Display for CreateSipMediaApplicationError {
     }
 }
 impl Error for CreateSipMediaApplicationError {}
+impl ServiceErrorCode for CreateSipMediaApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSipMediaApplicationError::AccessDenied(_) => "AccessDeniedException",
+            CreateSipMediaApplicationError::BadRequest(_) => "BadRequestException",
+            CreateSipMediaApplicationError::Conflict(_) => "ConflictException",
+            CreateSipMediaApplicationError::Forbidden(_) => "ForbiddenException",
+            CreateSipMediaApplicationError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            CreateSipMediaApplicationError::ServiceFailure(_) => "ServiceFailureException",
+            CreateSipMediaApplicationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateSipMediaApplicationError::ThrottledClient(_) => "ThrottledClientException",
+            CreateSipMediaApplicationError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateSipMediaApplicationCall
 #[derive(Debug, PartialEq)]
 pub enum CreateSipMediaApplicationCallError {
@@ -7820,6 +8271,25 @@ impl fmt::Display for CreateSipMediaApplicationCallError {
     }
 }
 impl Error for CreateSipMediaApplicationCallError {}
+impl ServiceErrorCode for CreateSipMediaApplicationCallError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSipMediaApplicationCallError::BadRequest(_) => "BadRequestException",
+            CreateSipMediaApplicationCallError::Forbidden(_) => "ForbiddenException",
+            CreateSipMediaApplicationCallError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            CreateSipMediaApplicationCallError::ServiceFailure(_) => "ServiceFailureException",
+            CreateSipMediaApplicationCallError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            CreateSipMediaApplicationCallError::ThrottledClient(_) => "ThrottledClientException",
+            CreateSipMediaApplicationCallError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateSipRule
 #[derive(Debug, PartialEq)]
 pub enum CreateSipRuleError {
@@ -7898,6 +8368,21 @@ impl fmt::Display for CreateSipRuleError {
     }
 }
 impl Error for CreateSipRuleError {}
+impl ServiceErrorCode for CreateSipRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSipRuleError::AccessDenied(_) => "AccessDeniedException",
+            CreateSipRuleError::BadRequest(_) => "BadRequestException",
+            CreateSipRuleError::Conflict(_) => "ConflictException",
+            CreateSipRuleError::Forbidden(_) => "ForbiddenException",
+            CreateSipRuleError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateSipRuleError::ServiceFailure(_) => "ServiceFailureException",
+            CreateSipRuleError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateSipRuleError::ThrottledClient(_) => "ThrottledClientException",
+            CreateSipRuleError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
 pub enum CreateUserError {
@@ -7970,6 +8455,20 @@ impl fmt::Display for CreateUserError {
     }
 }
 impl Error for CreateUserError {}
+impl ServiceErrorCode for CreateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserError::BadRequest(_) => "BadRequestException",
+            CreateUserError::Conflict(_) => "ConflictException",
+            CreateUserError::Forbidden(_) => "ForbiddenException",
+            CreateUserError::NotFound(_) => "NotFoundException",
+            CreateUserError::ServiceFailure(_) => "ServiceFailureException",
+            CreateUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateUserError::ThrottledClient(_) => "ThrottledClientException",
+            CreateUserError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateVoiceConnector
 #[derive(Debug, PartialEq)]
 pub enum CreateVoiceConnectorError {
@@ -8050,6 +8549,20 @@ impl fmt::Display for CreateVoiceConnectorError {
     }
 }
 impl Error for CreateVoiceConnectorError {}
+impl ServiceErrorCode for CreateVoiceConnectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVoiceConnectorError::AccessDenied(_) => "AccessDeniedException",
+            CreateVoiceConnectorError::BadRequest(_) => "BadRequestException",
+            CreateVoiceConnectorError::Forbidden(_) => "ForbiddenException",
+            CreateVoiceConnectorError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateVoiceConnectorError::ServiceFailure(_) => "ServiceFailureException",
+            CreateVoiceConnectorError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateVoiceConnectorError::ThrottledClient(_) => "ThrottledClientException",
+            CreateVoiceConnectorError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by CreateVoiceConnectorGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateVoiceConnectorGroupError {
@@ -8138,6 +8651,22 @@ impl fmt::Display for CreateVoiceConnectorGroupError {
     }
 }
 impl Error for CreateVoiceConnectorGroupError {}
+impl ServiceErrorCode for CreateVoiceConnectorGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVoiceConnectorGroupError::AccessDenied(_) => "AccessDeniedException",
+            CreateVoiceConnectorGroupError::BadRequest(_) => "BadRequestException",
+            CreateVoiceConnectorGroupError::Forbidden(_) => "ForbiddenException",
+            CreateVoiceConnectorGroupError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            CreateVoiceConnectorGroupError::ServiceFailure(_) => "ServiceFailureException",
+            CreateVoiceConnectorGroupError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateVoiceConnectorGroupError::ThrottledClient(_) => "ThrottledClientException",
+            CreateVoiceConnectorGroupError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteAccount
 #[derive(Debug, PartialEq)]
 pub enum DeleteAccountError {
@@ -8210,6 +8739,20 @@ impl fmt::Display for DeleteAccountError {
     }
 }
 impl Error for DeleteAccountError {}
+impl ServiceErrorCode for DeleteAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAccountError::BadRequest(_) => "BadRequestException",
+            DeleteAccountError::Forbidden(_) => "ForbiddenException",
+            DeleteAccountError::NotFound(_) => "NotFoundException",
+            DeleteAccountError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteAccountError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteAccountError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteAccountError::UnauthorizedClient(_) => "UnauthorizedClientException",
+            DeleteAccountError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by DeleteAppInstance
 #[derive(Debug, PartialEq)]
 pub enum DeleteAppInstanceError {
@@ -8274,6 +8817,18 @@ impl fmt::Display for DeleteAppInstanceError {
     }
 }
 impl Error for DeleteAppInstanceError {}
+impl ServiceErrorCode for DeleteAppInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAppInstanceError::BadRequest(_) => "BadRequestException",
+            DeleteAppInstanceError::Forbidden(_) => "ForbiddenException",
+            DeleteAppInstanceError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteAppInstanceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteAppInstanceError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteAppInstanceError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteAppInstanceAdmin
 #[derive(Debug, PartialEq)]
 pub enum DeleteAppInstanceAdminError {
@@ -8356,6 +8911,22 @@ impl fmt::Display for DeleteAppInstanceAdminError {
     }
 }
 impl Error for DeleteAppInstanceAdminError {}
+impl ServiceErrorCode for DeleteAppInstanceAdminError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAppInstanceAdminError::BadRequest(_) => "BadRequestException",
+            DeleteAppInstanceAdminError::Conflict(_) => "ConflictException",
+            DeleteAppInstanceAdminError::Forbidden(_) => "ForbiddenException",
+            DeleteAppInstanceAdminError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            DeleteAppInstanceAdminError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteAppInstanceAdminError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteAppInstanceAdminError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteAppInstanceAdminError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteAppInstanceStreamingConfigurations
 #[derive(Debug, PartialEq)]
 pub enum DeleteAppInstanceStreamingConfigurationsError {
@@ -8452,6 +9023,27 @@ impl fmt::Display for DeleteAppInstanceStreamingConfigurationsError {
     }
 }
 impl Error for DeleteAppInstanceStreamingConfigurationsError {}
+impl ServiceErrorCode for DeleteAppInstanceStreamingConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAppInstanceStreamingConfigurationsError::BadRequest(_) => "BadRequestException",
+            DeleteAppInstanceStreamingConfigurationsError::Forbidden(_) => "ForbiddenException",
+            DeleteAppInstanceStreamingConfigurationsError::NotFound(_) => "NotFoundException",
+            DeleteAppInstanceStreamingConfigurationsError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            DeleteAppInstanceStreamingConfigurationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DeleteAppInstanceStreamingConfigurationsError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            DeleteAppInstanceStreamingConfigurationsError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteAppInstanceUser
 #[derive(Debug, PartialEq)]
 pub enum DeleteAppInstanceUserError {
@@ -8520,6 +9112,18 @@ impl fmt::Display for DeleteAppInstanceUserError {
     }
 }
 impl Error for DeleteAppInstanceUserError {}
+impl ServiceErrorCode for DeleteAppInstanceUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAppInstanceUserError::BadRequest(_) => "BadRequestException",
+            DeleteAppInstanceUserError::Forbidden(_) => "ForbiddenException",
+            DeleteAppInstanceUserError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteAppInstanceUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteAppInstanceUserError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteAppInstanceUserError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteAttendee
 #[derive(Debug, PartialEq)]
 pub enum DeleteAttendeeError {
@@ -8586,6 +9190,19 @@ impl fmt::Display for DeleteAttendeeError {
     }
 }
 impl Error for DeleteAttendeeError {}
+impl ServiceErrorCode for DeleteAttendeeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAttendeeError::BadRequest(_) => "BadRequestException",
+            DeleteAttendeeError::Forbidden(_) => "ForbiddenException",
+            DeleteAttendeeError::NotFound(_) => "NotFoundException",
+            DeleteAttendeeError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteAttendeeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteAttendeeError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteAttendeeError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteChannel
 #[derive(Debug, PartialEq)]
 pub enum DeleteChannelError {
@@ -8646,6 +9263,18 @@ impl fmt::Display for DeleteChannelError {
     }
 }
 impl Error for DeleteChannelError {}
+impl ServiceErrorCode for DeleteChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteChannelError::BadRequest(_) => "BadRequestException",
+            DeleteChannelError::Forbidden(_) => "ForbiddenException",
+            DeleteChannelError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteChannelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteChannelError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteChannelError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteChannelBan
 #[derive(Debug, PartialEq)]
 pub enum DeleteChannelBanError {
@@ -8706,6 +9335,18 @@ impl fmt::Display for DeleteChannelBanError {
     }
 }
 impl Error for DeleteChannelBanError {}
+impl ServiceErrorCode for DeleteChannelBanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteChannelBanError::BadRequest(_) => "BadRequestException",
+            DeleteChannelBanError::Forbidden(_) => "ForbiddenException",
+            DeleteChannelBanError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteChannelBanError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteChannelBanError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteChannelBanError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteChannelMembership
 #[derive(Debug, PartialEq)]
 pub enum DeleteChannelMembershipError {
@@ -8774,6 +9415,18 @@ impl fmt::Display for DeleteChannelMembershipError {
     }
 }
 impl Error for DeleteChannelMembershipError {}
+impl ServiceErrorCode for DeleteChannelMembershipError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteChannelMembershipError::BadRequest(_) => "BadRequestException",
+            DeleteChannelMembershipError::Forbidden(_) => "ForbiddenException",
+            DeleteChannelMembershipError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteChannelMembershipError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteChannelMembershipError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteChannelMembershipError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteChannelMessage
 #[derive(Debug, PartialEq)]
 pub enum DeleteChannelMessageError {
@@ -8840,6 +9493,18 @@ impl fmt::Display for DeleteChannelMessageError {
     }
 }
 impl Error for DeleteChannelMessageError {}
+impl ServiceErrorCode for DeleteChannelMessageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteChannelMessageError::BadRequest(_) => "BadRequestException",
+            DeleteChannelMessageError::Forbidden(_) => "ForbiddenException",
+            DeleteChannelMessageError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteChannelMessageError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteChannelMessageError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteChannelMessageError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteChannelModerator
 #[derive(Debug, PartialEq)]
 pub enum DeleteChannelModeratorError {
@@ -8908,6 +9573,18 @@ impl fmt::Display for DeleteChannelModeratorError {
     }
 }
 impl Error for DeleteChannelModeratorError {}
+impl ServiceErrorCode for DeleteChannelModeratorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteChannelModeratorError::BadRequest(_) => "BadRequestException",
+            DeleteChannelModeratorError::Forbidden(_) => "ForbiddenException",
+            DeleteChannelModeratorError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteChannelModeratorError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteChannelModeratorError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteChannelModeratorError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteEventsConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteEventsConfigurationError {
@@ -8980,6 +9657,20 @@ impl fmt::Display for DeleteEventsConfigurationError {
     }
 }
 impl Error for DeleteEventsConfigurationError {}
+impl ServiceErrorCode for DeleteEventsConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEventsConfigurationError::BadRequest(_) => "BadRequestException",
+            DeleteEventsConfigurationError::Forbidden(_) => "ForbiddenException",
+            DeleteEventsConfigurationError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            DeleteEventsConfigurationError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteEventsConfigurationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteEventsConfigurationError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteMeeting
 #[derive(Debug, PartialEq)]
 pub enum DeleteMeetingError {
@@ -9046,6 +9737,19 @@ impl fmt::Display for DeleteMeetingError {
     }
 }
 impl Error for DeleteMeetingError {}
+impl ServiceErrorCode for DeleteMeetingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMeetingError::BadRequest(_) => "BadRequestException",
+            DeleteMeetingError::Forbidden(_) => "ForbiddenException",
+            DeleteMeetingError::NotFound(_) => "NotFoundException",
+            DeleteMeetingError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteMeetingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteMeetingError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteMeetingError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeletePhoneNumber
 #[derive(Debug, PartialEq)]
 pub enum DeletePhoneNumberError {
@@ -9116,6 +9820,19 @@ impl fmt::Display for DeletePhoneNumberError {
     }
 }
 impl Error for DeletePhoneNumberError {}
+impl ServiceErrorCode for DeletePhoneNumberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePhoneNumberError::BadRequest(_) => "BadRequestException",
+            DeletePhoneNumberError::Forbidden(_) => "ForbiddenException",
+            DeletePhoneNumberError::NotFound(_) => "NotFoundException",
+            DeletePhoneNumberError::ServiceFailure(_) => "ServiceFailureException",
+            DeletePhoneNumberError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeletePhoneNumberError::ThrottledClient(_) => "ThrottledClientException",
+            DeletePhoneNumberError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteProxySession
 #[derive(Debug, PartialEq)]
 pub enum DeleteProxySessionError {
@@ -9186,6 +9903,19 @@ impl fmt::Display for DeleteProxySessionError {
     }
 }
 impl Error for DeleteProxySessionError {}
+impl ServiceErrorCode for DeleteProxySessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProxySessionError::BadRequest(_) => "BadRequestException",
+            DeleteProxySessionError::Forbidden(_) => "ForbiddenException",
+            DeleteProxySessionError::NotFound(_) => "NotFoundException",
+            DeleteProxySessionError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteProxySessionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteProxySessionError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteProxySessionError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteRoom
 #[derive(Debug, PartialEq)]
 pub enum DeleteRoomError {
@@ -9252,6 +9982,19 @@ impl fmt::Display for DeleteRoomError {
     }
 }
 impl Error for DeleteRoomError {}
+impl ServiceErrorCode for DeleteRoomError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRoomError::BadRequest(_) => "BadRequestException",
+            DeleteRoomError::Forbidden(_) => "ForbiddenException",
+            DeleteRoomError::NotFound(_) => "NotFoundException",
+            DeleteRoomError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteRoomError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteRoomError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteRoomError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteRoomMembership
 #[derive(Debug, PartialEq)]
 pub enum DeleteRoomMembershipError {
@@ -9324,6 +10067,19 @@ impl fmt::Display for DeleteRoomMembershipError {
     }
 }
 impl Error for DeleteRoomMembershipError {}
+impl ServiceErrorCode for DeleteRoomMembershipError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRoomMembershipError::BadRequest(_) => "BadRequestException",
+            DeleteRoomMembershipError::Forbidden(_) => "ForbiddenException",
+            DeleteRoomMembershipError::NotFound(_) => "NotFoundException",
+            DeleteRoomMembershipError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteRoomMembershipError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteRoomMembershipError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteRoomMembershipError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteSipMediaApplication
 #[derive(Debug, PartialEq)]
 pub enum DeleteSipMediaApplicationError {
@@ -9406,6 +10162,20 @@ impl fmt::Display for DeleteSipMediaApplicationError {
     }
 }
 impl Error for DeleteSipMediaApplicationError {}
+impl ServiceErrorCode for DeleteSipMediaApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSipMediaApplicationError::BadRequest(_) => "BadRequestException",
+            DeleteSipMediaApplicationError::Conflict(_) => "ConflictException",
+            DeleteSipMediaApplicationError::Forbidden(_) => "ForbiddenException",
+            DeleteSipMediaApplicationError::NotFound(_) => "NotFoundException",
+            DeleteSipMediaApplicationError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteSipMediaApplicationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteSipMediaApplicationError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteSipMediaApplicationError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteSipRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteSipRuleError {
@@ -9478,6 +10248,20 @@ impl fmt::Display for DeleteSipRuleError {
     }
 }
 impl Error for DeleteSipRuleError {}
+impl ServiceErrorCode for DeleteSipRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSipRuleError::BadRequest(_) => "BadRequestException",
+            DeleteSipRuleError::Conflict(_) => "ConflictException",
+            DeleteSipRuleError::Forbidden(_) => "ForbiddenException",
+            DeleteSipRuleError::NotFound(_) => "NotFoundException",
+            DeleteSipRuleError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteSipRuleError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteSipRuleError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteSipRuleError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteVoiceConnector
 #[derive(Debug, PartialEq)]
 pub enum DeleteVoiceConnectorError {
@@ -9556,6 +10340,20 @@ impl fmt::Display for DeleteVoiceConnectorError {
     }
 }
 impl Error for DeleteVoiceConnectorError {}
+impl ServiceErrorCode for DeleteVoiceConnectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVoiceConnectorError::BadRequest(_) => "BadRequestException",
+            DeleteVoiceConnectorError::Conflict(_) => "ConflictException",
+            DeleteVoiceConnectorError::Forbidden(_) => "ForbiddenException",
+            DeleteVoiceConnectorError::NotFound(_) => "NotFoundException",
+            DeleteVoiceConnectorError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteVoiceConnectorError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteVoiceConnectorError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteVoiceConnectorError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteVoiceConnectorEmergencyCallingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteVoiceConnectorEmergencyCallingConfigurationError {
@@ -9660,12 +10458,39 @@ impl fmt::Display for DeleteVoiceConnectorEmergencyCallingConfigurationError {
     }
 }
 impl Error for DeleteVoiceConnectorEmergencyCallingConfigurationError {}
-/// Errors returned by DeleteVoiceConnectorGroup
-#[derive(Debug, PartialEq)]
-pub enum DeleteVoiceConnectorGroupError {
-    /// <p>The input parameters don't match the service's restrictions.</p>
-    BadRequest(String),
-    /// <p>The request could not be processed because of conflict in the current state of the resource.</p>
+impl ServiceErrorCode for DeleteVoiceConnectorEmergencyCallingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVoiceConnectorEmergencyCallingConfigurationError::BadRequest(_) => {
+                "BadRequestException"
+            }
+            DeleteVoiceConnectorEmergencyCallingConfigurationError::Forbidden(_) => {
+                "ForbiddenException"
+            }
+            DeleteVoiceConnectorEmergencyCallingConfigurationError::NotFound(_) => {
+                "NotFoundException"
+            }
+            DeleteVoiceConnectorEmergencyCallingConfigurationError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            DeleteVoiceConnectorEmergencyCallingConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DeleteVoiceConnectorEmergencyCallingConfigurationError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            DeleteVoiceConnectorEmergencyCallingConfigurationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
+/// Errors returned by DeleteVoiceConnectorGroup
+#[derive(Debug, PartialEq)]
+pub enum DeleteVoiceConnectorGroupError {
+    /// <p>The input parameters don't match the service's restrictions.</p>
+    BadRequest(String),
+    /// <p>The request could not be processed because of conflict in the current state of the resource.</p>
     Conflict(String),
     /// <p>The client is permanently forbidden from making the request.</p>
     Forbidden(String),
@@ -9742,6 +10567,20 @@ impl fmt::Display for DeleteVoiceConnectorGroupError {
     }
 }
 impl Error for DeleteVoiceConnectorGroupError {}
+impl ServiceErrorCode for DeleteVoiceConnectorGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVoiceConnectorGroupError::BadRequest(_) => "BadRequestException",
+            DeleteVoiceConnectorGroupError::Conflict(_) => "ConflictException",
+            DeleteVoiceConnectorGroupError::Forbidden(_) => "ForbiddenException",
+            DeleteVoiceConnectorGroupError::NotFound(_) => "NotFoundException",
+            DeleteVoiceConnectorGroupError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteVoiceConnectorGroupError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteVoiceConnectorGroupError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteVoiceConnectorGroupError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteVoiceConnectorOrigination
 #[derive(Debug, PartialEq)]
 pub enum DeleteVoiceConnectorOriginationError {
@@ -9832,6 +10671,23 @@ impl fmt::Display for DeleteVoiceConnectorOriginationError {
     }
 }
 impl Error for DeleteVoiceConnectorOriginationError {}
+impl ServiceErrorCode for DeleteVoiceConnectorOriginationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVoiceConnectorOriginationError::BadRequest(_) => "BadRequestException",
+            DeleteVoiceConnectorOriginationError::Forbidden(_) => "ForbiddenException",
+            DeleteVoiceConnectorOriginationError::NotFound(_) => "NotFoundException",
+            DeleteVoiceConnectorOriginationError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteVoiceConnectorOriginationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DeleteVoiceConnectorOriginationError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteVoiceConnectorOriginationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteVoiceConnectorProxy
 #[derive(Debug, PartialEq)]
 pub enum DeleteVoiceConnectorProxyError {
@@ -9908,6 +10764,19 @@ impl fmt::Display for DeleteVoiceConnectorProxyError {
     }
 }
 impl Error for DeleteVoiceConnectorProxyError {}
+impl ServiceErrorCode for DeleteVoiceConnectorProxyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVoiceConnectorProxyError::BadRequest(_) => "BadRequestException",
+            DeleteVoiceConnectorProxyError::Forbidden(_) => "ForbiddenException",
+            DeleteVoiceConnectorProxyError::NotFound(_) => "NotFoundException",
+            DeleteVoiceConnectorProxyError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteVoiceConnectorProxyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteVoiceConnectorProxyError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteVoiceConnectorProxyError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DeleteVoiceConnectorStreamingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteVoiceConnectorStreamingConfigurationError {
@@ -10008,6 +10877,27 @@ impl fmt::Display for DeleteVoiceConnectorStreamingConfigurationError {
     }
 }
 impl Error for DeleteVoiceConnectorStreamingConfigurationError {}
+impl ServiceErrorCode for DeleteVoiceConnectorStreamingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVoiceConnectorStreamingConfigurationError::BadRequest(_) => "BadRequestException",
+            DeleteVoiceConnectorStreamingConfigurationError::Forbidden(_) => "ForbiddenException",
+            DeleteVoiceConnectorStreamingConfigurationError::NotFound(_) => "NotFoundException",
+            DeleteVoiceConnectorStreamingConfigurationError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            DeleteVoiceConnectorStreamingConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DeleteVoiceConnectorStreamingConfigurationError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            DeleteVoiceConnectorStreamingConfigurationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteVoiceConnectorTermination
 #[derive(Debug, PartialEq)]
 pub enum DeleteVoiceConnectorTerminationError {
@@ -10098,6 +10988,23 @@ impl fmt::Display for DeleteVoiceConnectorTerminationError {
     }
 }
 impl Error for DeleteVoiceConnectorTerminationError {}
+impl ServiceErrorCode for DeleteVoiceConnectorTerminationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVoiceConnectorTerminationError::BadRequest(_) => "BadRequestException",
+            DeleteVoiceConnectorTerminationError::Forbidden(_) => "ForbiddenException",
+            DeleteVoiceConnectorTerminationError::NotFound(_) => "NotFoundException",
+            DeleteVoiceConnectorTerminationError::ServiceFailure(_) => "ServiceFailureException",
+            DeleteVoiceConnectorTerminationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DeleteVoiceConnectorTerminationError::ThrottledClient(_) => "ThrottledClientException",
+            DeleteVoiceConnectorTerminationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteVoiceConnectorTerminationCredentials
 #[derive(Debug, PartialEq)]
 pub enum DeleteVoiceConnectorTerminationCredentialsError {
@@ -10198,6 +11105,27 @@ impl fmt::Display for DeleteVoiceConnectorTerminationCredentialsError {
     }
 }
 impl Error for DeleteVoiceConnectorTerminationCredentialsError {}
+impl ServiceErrorCode for DeleteVoiceConnectorTerminationCredentialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVoiceConnectorTerminationCredentialsError::BadRequest(_) => "BadRequestException",
+            DeleteVoiceConnectorTerminationCredentialsError::Forbidden(_) => "ForbiddenException",
+            DeleteVoiceConnectorTerminationCredentialsError::NotFound(_) => "NotFoundException",
+            DeleteVoiceConnectorTerminationCredentialsError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            DeleteVoiceConnectorTerminationCredentialsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DeleteVoiceConnectorTerminationCredentialsError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            DeleteVoiceConnectorTerminationCredentialsError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeAppInstance
 #[derive(Debug, PartialEq)]
 pub enum DescribeAppInstanceError {
@@ -10262,6 +11190,18 @@ impl fmt::Display for DescribeAppInstanceError {
     }
 }
 impl Error for DescribeAppInstanceError {}
+impl ServiceErrorCode for DescribeAppInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAppInstanceError::BadRequest(_) => "BadRequestException",
+            DescribeAppInstanceError::Forbidden(_) => "ForbiddenException",
+            DescribeAppInstanceError::ServiceFailure(_) => "ServiceFailureException",
+            DescribeAppInstanceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeAppInstanceError::ThrottledClient(_) => "ThrottledClientException",
+            DescribeAppInstanceError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DescribeAppInstanceAdmin
 #[derive(Debug, PartialEq)]
 pub enum DescribeAppInstanceAdminError {
@@ -10330,6 +11270,18 @@ impl fmt::Display for DescribeAppInstanceAdminError {
     }
 }
 impl Error for DescribeAppInstanceAdminError {}
+impl ServiceErrorCode for DescribeAppInstanceAdminError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAppInstanceAdminError::BadRequest(_) => "BadRequestException",
+            DescribeAppInstanceAdminError::Forbidden(_) => "ForbiddenException",
+            DescribeAppInstanceAdminError::ServiceFailure(_) => "ServiceFailureException",
+            DescribeAppInstanceAdminError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeAppInstanceAdminError::ThrottledClient(_) => "ThrottledClientException",
+            DescribeAppInstanceAdminError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DescribeAppInstanceUser
 #[derive(Debug, PartialEq)]
 pub enum DescribeAppInstanceUserError {
@@ -10398,6 +11350,18 @@ impl fmt::Display for DescribeAppInstanceUserError {
     }
 }
 impl Error for DescribeAppInstanceUserError {}
+impl ServiceErrorCode for DescribeAppInstanceUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAppInstanceUserError::BadRequest(_) => "BadRequestException",
+            DescribeAppInstanceUserError::Forbidden(_) => "ForbiddenException",
+            DescribeAppInstanceUserError::ServiceFailure(_) => "ServiceFailureException",
+            DescribeAppInstanceUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeAppInstanceUserError::ThrottledClient(_) => "ThrottledClientException",
+            DescribeAppInstanceUserError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DescribeChannel
 #[derive(Debug, PartialEq)]
 pub enum DescribeChannelError {
@@ -10458,6 +11422,18 @@ impl fmt::Display for DescribeChannelError {
     }
 }
 impl Error for DescribeChannelError {}
+impl ServiceErrorCode for DescribeChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeChannelError::BadRequest(_) => "BadRequestException",
+            DescribeChannelError::Forbidden(_) => "ForbiddenException",
+            DescribeChannelError::ServiceFailure(_) => "ServiceFailureException",
+            DescribeChannelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeChannelError::ThrottledClient(_) => "ThrottledClientException",
+            DescribeChannelError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DescribeChannelBan
 #[derive(Debug, PartialEq)]
 pub enum DescribeChannelBanError {
@@ -10528,6 +11504,19 @@ impl fmt::Display for DescribeChannelBanError {
     }
 }
 impl Error for DescribeChannelBanError {}
+impl ServiceErrorCode for DescribeChannelBanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeChannelBanError::BadRequest(_) => "BadRequestException",
+            DescribeChannelBanError::Forbidden(_) => "ForbiddenException",
+            DescribeChannelBanError::NotFound(_) => "NotFoundException",
+            DescribeChannelBanError::ServiceFailure(_) => "ServiceFailureException",
+            DescribeChannelBanError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeChannelBanError::ThrottledClient(_) => "ThrottledClientException",
+            DescribeChannelBanError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DescribeChannelMembership
 #[derive(Debug, PartialEq)]
 pub enum DescribeChannelMembershipError {
@@ -10604,6 +11593,19 @@ impl fmt::Display for DescribeChannelMembershipError {
     }
 }
 impl Error for DescribeChannelMembershipError {}
+impl ServiceErrorCode for DescribeChannelMembershipError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeChannelMembershipError::BadRequest(_) => "BadRequestException",
+            DescribeChannelMembershipError::Forbidden(_) => "ForbiddenException",
+            DescribeChannelMembershipError::NotFound(_) => "NotFoundException",
+            DescribeChannelMembershipError::ServiceFailure(_) => "ServiceFailureException",
+            DescribeChannelMembershipError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeChannelMembershipError::ThrottledClient(_) => "ThrottledClientException",
+            DescribeChannelMembershipError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DescribeChannelMembershipForAppInstanceUser
 #[derive(Debug, PartialEq)]
 pub enum DescribeChannelMembershipForAppInstanceUserError {
@@ -10694,6 +11696,28 @@ impl fmt::Display for DescribeChannelMembershipForAppInstanceUserError {
     }
 }
 impl Error for DescribeChannelMembershipForAppInstanceUserError {}
+impl ServiceErrorCode for DescribeChannelMembershipForAppInstanceUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeChannelMembershipForAppInstanceUserError::BadRequest(_) => {
+                "BadRequestException"
+            }
+            DescribeChannelMembershipForAppInstanceUserError::Forbidden(_) => "ForbiddenException",
+            DescribeChannelMembershipForAppInstanceUserError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            DescribeChannelMembershipForAppInstanceUserError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DescribeChannelMembershipForAppInstanceUserError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            DescribeChannelMembershipForAppInstanceUserError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeChannelModeratedByAppInstanceUser
 #[derive(Debug, PartialEq)]
 pub enum DescribeChannelModeratedByAppInstanceUserError {
@@ -10780,6 +11804,26 @@ impl fmt::Display for DescribeChannelModeratedByAppInstanceUserError {
     }
 }
 impl Error for DescribeChannelModeratedByAppInstanceUserError {}
+impl ServiceErrorCode for DescribeChannelModeratedByAppInstanceUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeChannelModeratedByAppInstanceUserError::BadRequest(_) => "BadRequestException",
+            DescribeChannelModeratedByAppInstanceUserError::Forbidden(_) => "ForbiddenException",
+            DescribeChannelModeratedByAppInstanceUserError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            DescribeChannelModeratedByAppInstanceUserError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DescribeChannelModeratedByAppInstanceUserError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            DescribeChannelModeratedByAppInstanceUserError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeChannelModerator
 #[derive(Debug, PartialEq)]
 pub enum DescribeChannelModeratorError {
@@ -10854,6 +11898,19 @@ impl fmt::Display for DescribeChannelModeratorError {
     }
 }
 impl Error for DescribeChannelModeratorError {}
+impl ServiceErrorCode for DescribeChannelModeratorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeChannelModeratorError::BadRequest(_) => "BadRequestException",
+            DescribeChannelModeratorError::Forbidden(_) => "ForbiddenException",
+            DescribeChannelModeratorError::NotFound(_) => "NotFoundException",
+            DescribeChannelModeratorError::ServiceFailure(_) => "ServiceFailureException",
+            DescribeChannelModeratorError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeChannelModeratorError::ThrottledClient(_) => "ThrottledClientException",
+            DescribeChannelModeratorError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by DisassociatePhoneNumberFromUser
 #[derive(Debug, PartialEq)]
 pub enum DisassociatePhoneNumberFromUserError {
@@ -10944,6 +12001,23 @@ impl fmt::Display for DisassociatePhoneNumberFromUserError {
     }
 }
 impl Error for DisassociatePhoneNumberFromUserError {}
+impl ServiceErrorCode for DisassociatePhoneNumberFromUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociatePhoneNumberFromUserError::BadRequest(_) => "BadRequestException",
+            DisassociatePhoneNumberFromUserError::Forbidden(_) => "ForbiddenException",
+            DisassociatePhoneNumberFromUserError::NotFound(_) => "NotFoundException",
+            DisassociatePhoneNumberFromUserError::ServiceFailure(_) => "ServiceFailureException",
+            DisassociatePhoneNumberFromUserError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DisassociatePhoneNumberFromUserError::ThrottledClient(_) => "ThrottledClientException",
+            DisassociatePhoneNumberFromUserError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociatePhoneNumbersFromVoiceConnector
 #[derive(Debug, PartialEq)]
 pub enum DisassociatePhoneNumbersFromVoiceConnectorError {
@@ -11044,6 +12118,27 @@ impl fmt::Display for DisassociatePhoneNumbersFromVoiceConnectorError {
     }
 }
 impl Error for DisassociatePhoneNumbersFromVoiceConnectorError {}
+impl ServiceErrorCode for DisassociatePhoneNumbersFromVoiceConnectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociatePhoneNumbersFromVoiceConnectorError::BadRequest(_) => "BadRequestException",
+            DisassociatePhoneNumbersFromVoiceConnectorError::Forbidden(_) => "ForbiddenException",
+            DisassociatePhoneNumbersFromVoiceConnectorError::NotFound(_) => "NotFoundException",
+            DisassociatePhoneNumbersFromVoiceConnectorError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            DisassociatePhoneNumbersFromVoiceConnectorError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DisassociatePhoneNumbersFromVoiceConnectorError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            DisassociatePhoneNumbersFromVoiceConnectorError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociatePhoneNumbersFromVoiceConnectorGroup
 #[derive(Debug, PartialEq)]
 pub enum DisassociatePhoneNumbersFromVoiceConnectorGroupError {
@@ -11148,6 +12243,33 @@ impl fmt::Display for DisassociatePhoneNumbersFromVoiceConnectorGroupError {
     }
 }
 impl Error for DisassociatePhoneNumbersFromVoiceConnectorGroupError {}
+impl ServiceErrorCode for DisassociatePhoneNumbersFromVoiceConnectorGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociatePhoneNumbersFromVoiceConnectorGroupError::BadRequest(_) => {
+                "BadRequestException"
+            }
+            DisassociatePhoneNumbersFromVoiceConnectorGroupError::Forbidden(_) => {
+                "ForbiddenException"
+            }
+            DisassociatePhoneNumbersFromVoiceConnectorGroupError::NotFound(_) => {
+                "NotFoundException"
+            }
+            DisassociatePhoneNumbersFromVoiceConnectorGroupError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            DisassociatePhoneNumbersFromVoiceConnectorGroupError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DisassociatePhoneNumbersFromVoiceConnectorGroupError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            DisassociatePhoneNumbersFromVoiceConnectorGroupError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateSigninDelegateGroupsFromAccount
 #[derive(Debug, PartialEq)]
 pub enum DisassociateSigninDelegateGroupsFromAccountError {
@@ -11248,6 +12370,29 @@ impl fmt::Display for DisassociateSigninDelegateGroupsFromAccountError {
     }
 }
 impl Error for DisassociateSigninDelegateGroupsFromAccountError {}
+impl ServiceErrorCode for DisassociateSigninDelegateGroupsFromAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateSigninDelegateGroupsFromAccountError::BadRequest(_) => {
+                "BadRequestException"
+            }
+            DisassociateSigninDelegateGroupsFromAccountError::Forbidden(_) => "ForbiddenException",
+            DisassociateSigninDelegateGroupsFromAccountError::NotFound(_) => "NotFoundException",
+            DisassociateSigninDelegateGroupsFromAccountError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            DisassociateSigninDelegateGroupsFromAccountError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DisassociateSigninDelegateGroupsFromAccountError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            DisassociateSigninDelegateGroupsFromAccountError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAccount
 #[derive(Debug, PartialEq)]
 pub enum GetAccountError {
@@ -11314,6 +12459,19 @@ impl fmt::Display for GetAccountError {
     }
 }
 impl Error for GetAccountError {}
+impl ServiceErrorCode for GetAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAccountError::BadRequest(_) => "BadRequestException",
+            GetAccountError::Forbidden(_) => "ForbiddenException",
+            GetAccountError::NotFound(_) => "NotFoundException",
+            GetAccountError::ServiceFailure(_) => "ServiceFailureException",
+            GetAccountError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetAccountError::ThrottledClient(_) => "ThrottledClientException",
+            GetAccountError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetAccountSettings
 #[derive(Debug, PartialEq)]
 pub enum GetAccountSettingsError {
@@ -11384,6 +12542,19 @@ impl fmt::Display for GetAccountSettingsError {
     }
 }
 impl Error for GetAccountSettingsError {}
+impl ServiceErrorCode for GetAccountSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAccountSettingsError::BadRequest(_) => "BadRequestException",
+            GetAccountSettingsError::Forbidden(_) => "ForbiddenException",
+            GetAccountSettingsError::NotFound(_) => "NotFoundException",
+            GetAccountSettingsError::ServiceFailure(_) => "ServiceFailureException",
+            GetAccountSettingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetAccountSettingsError::ThrottledClient(_) => "ThrottledClientException",
+            GetAccountSettingsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetAppInstanceRetentionSettings
 #[derive(Debug, PartialEq)]
 pub enum GetAppInstanceRetentionSettingsError {
@@ -11474,6 +12645,23 @@ impl fmt::Display for GetAppInstanceRetentionSettingsError {
     }
 }
 impl Error for GetAppInstanceRetentionSettingsError {}
+impl ServiceErrorCode for GetAppInstanceRetentionSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAppInstanceRetentionSettingsError::BadRequest(_) => "BadRequestException",
+            GetAppInstanceRetentionSettingsError::Forbidden(_) => "ForbiddenException",
+            GetAppInstanceRetentionSettingsError::NotFound(_) => "NotFoundException",
+            GetAppInstanceRetentionSettingsError::ServiceFailure(_) => "ServiceFailureException",
+            GetAppInstanceRetentionSettingsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetAppInstanceRetentionSettingsError::ThrottledClient(_) => "ThrottledClientException",
+            GetAppInstanceRetentionSettingsError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAppInstanceStreamingConfigurations
 #[derive(Debug, PartialEq)]
 pub enum GetAppInstanceStreamingConfigurationsError {
@@ -11570,6 +12758,27 @@ impl fmt::Display for GetAppInstanceStreamingConfigurationsError {
     }
 }
 impl Error for GetAppInstanceStreamingConfigurationsError {}
+impl ServiceErrorCode for GetAppInstanceStreamingConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAppInstanceStreamingConfigurationsError::BadRequest(_) => "BadRequestException",
+            GetAppInstanceStreamingConfigurationsError::Forbidden(_) => "ForbiddenException",
+            GetAppInstanceStreamingConfigurationsError::NotFound(_) => "NotFoundException",
+            GetAppInstanceStreamingConfigurationsError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            GetAppInstanceStreamingConfigurationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetAppInstanceStreamingConfigurationsError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            GetAppInstanceStreamingConfigurationsError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAttendee
 #[derive(Debug, PartialEq)]
 pub enum GetAttendeeError {
@@ -11636,6 +12845,19 @@ impl fmt::Display for GetAttendeeError {
     }
 }
 impl Error for GetAttendeeError {}
+impl ServiceErrorCode for GetAttendeeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAttendeeError::BadRequest(_) => "BadRequestException",
+            GetAttendeeError::Forbidden(_) => "ForbiddenException",
+            GetAttendeeError::NotFound(_) => "NotFoundException",
+            GetAttendeeError::ServiceFailure(_) => "ServiceFailureException",
+            GetAttendeeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetAttendeeError::ThrottledClient(_) => "ThrottledClientException",
+            GetAttendeeError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetBot
 #[derive(Debug, PartialEq)]
 pub enum GetBotError {
@@ -11700,6 +12922,19 @@ impl fmt::Display for GetBotError {
     }
 }
 impl Error for GetBotError {}
+impl ServiceErrorCode for GetBotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBotError::BadRequest(_) => "BadRequestException",
+            GetBotError::Forbidden(_) => "ForbiddenException",
+            GetBotError::NotFound(_) => "NotFoundException",
+            GetBotError::ServiceFailure(_) => "ServiceFailureException",
+            GetBotError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetBotError::ThrottledClient(_) => "ThrottledClientException",
+            GetBotError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetChannelMessage
 #[derive(Debug, PartialEq)]
 pub enum GetChannelMessageError {
@@ -11770,6 +13005,19 @@ impl fmt::Display for GetChannelMessageError {
     }
 }
 impl Error for GetChannelMessageError {}
+impl ServiceErrorCode for GetChannelMessageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetChannelMessageError::BadRequest(_) => "BadRequestException",
+            GetChannelMessageError::Forbidden(_) => "ForbiddenException",
+            GetChannelMessageError::NotFound(_) => "NotFoundException",
+            GetChannelMessageError::ServiceFailure(_) => "ServiceFailureException",
+            GetChannelMessageError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetChannelMessageError::ThrottledClient(_) => "ThrottledClientException",
+            GetChannelMessageError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetEventsConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetEventsConfigurationError {
@@ -11844,6 +13092,21 @@ impl fmt::Display for GetEventsConfigurationError {
     }
 }
 impl Error for GetEventsConfigurationError {}
+impl ServiceErrorCode for GetEventsConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEventsConfigurationError::BadRequest(_) => "BadRequestException",
+            GetEventsConfigurationError::Forbidden(_) => "ForbiddenException",
+            GetEventsConfigurationError::NotFound(_) => "NotFoundException",
+            GetEventsConfigurationError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            GetEventsConfigurationError::ServiceFailure(_) => "ServiceFailureException",
+            GetEventsConfigurationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetEventsConfigurationError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetGlobalSettings
 #[derive(Debug, PartialEq)]
 pub enum GetGlobalSettingsError {
@@ -11908,6 +13171,18 @@ impl fmt::Display for GetGlobalSettingsError {
     }
 }
 impl Error for GetGlobalSettingsError {}
+impl ServiceErrorCode for GetGlobalSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGlobalSettingsError::BadRequest(_) => "BadRequestException",
+            GetGlobalSettingsError::Forbidden(_) => "ForbiddenException",
+            GetGlobalSettingsError::ServiceFailure(_) => "ServiceFailureException",
+            GetGlobalSettingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetGlobalSettingsError::ThrottledClient(_) => "ThrottledClientException",
+            GetGlobalSettingsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetMeeting
 #[derive(Debug, PartialEq)]
 pub enum GetMeetingError {
@@ -11974,6 +13249,19 @@ impl fmt::Display for GetMeetingError {
     }
 }
 impl Error for GetMeetingError {}
+impl ServiceErrorCode for GetMeetingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMeetingError::BadRequest(_) => "BadRequestException",
+            GetMeetingError::Forbidden(_) => "ForbiddenException",
+            GetMeetingError::NotFound(_) => "NotFoundException",
+            GetMeetingError::ServiceFailure(_) => "ServiceFailureException",
+            GetMeetingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetMeetingError::ThrottledClient(_) => "ThrottledClientException",
+            GetMeetingError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetMessagingSessionEndpoint
 #[derive(Debug, PartialEq)]
 pub enum GetMessagingSessionEndpointError {
@@ -12044,6 +13332,21 @@ impl fmt::Display for GetMessagingSessionEndpointError {
     }
 }
 impl Error for GetMessagingSessionEndpointError {}
+impl ServiceErrorCode for GetMessagingSessionEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMessagingSessionEndpointError::Forbidden(_) => "ForbiddenException",
+            GetMessagingSessionEndpointError::ServiceFailure(_) => "ServiceFailureException",
+            GetMessagingSessionEndpointError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetMessagingSessionEndpointError::ThrottledClient(_) => "ThrottledClientException",
+            GetMessagingSessionEndpointError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by GetPhoneNumber
 #[derive(Debug, PartialEq)]
 pub enum GetPhoneNumberError {
@@ -12110,6 +13413,19 @@ impl fmt::Display for GetPhoneNumberError {
     }
 }
 impl Error for GetPhoneNumberError {}
+impl ServiceErrorCode for GetPhoneNumberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPhoneNumberError::BadRequest(_) => "BadRequestException",
+            GetPhoneNumberError::Forbidden(_) => "ForbiddenException",
+            GetPhoneNumberError::NotFound(_) => "NotFoundException",
+            GetPhoneNumberError::ServiceFailure(_) => "ServiceFailureException",
+            GetPhoneNumberError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetPhoneNumberError::ThrottledClient(_) => "ThrottledClientException",
+            GetPhoneNumberError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetPhoneNumberOrder
 #[derive(Debug, PartialEq)]
 pub enum GetPhoneNumberOrderError {
@@ -12180,6 +13496,19 @@ impl fmt::Display for GetPhoneNumberOrderError {
     }
 }
 impl Error for GetPhoneNumberOrderError {}
+impl ServiceErrorCode for GetPhoneNumberOrderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPhoneNumberOrderError::BadRequest(_) => "BadRequestException",
+            GetPhoneNumberOrderError::Forbidden(_) => "ForbiddenException",
+            GetPhoneNumberOrderError::NotFound(_) => "NotFoundException",
+            GetPhoneNumberOrderError::ServiceFailure(_) => "ServiceFailureException",
+            GetPhoneNumberOrderError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetPhoneNumberOrderError::ThrottledClient(_) => "ThrottledClientException",
+            GetPhoneNumberOrderError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetPhoneNumberSettings
 #[derive(Debug, PartialEq)]
 pub enum GetPhoneNumberSettingsError {
@@ -12248,6 +13577,18 @@ impl fmt::Display for GetPhoneNumberSettingsError {
     }
 }
 impl Error for GetPhoneNumberSettingsError {}
+impl ServiceErrorCode for GetPhoneNumberSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPhoneNumberSettingsError::BadRequest(_) => "BadRequestException",
+            GetPhoneNumberSettingsError::Forbidden(_) => "ForbiddenException",
+            GetPhoneNumberSettingsError::ServiceFailure(_) => "ServiceFailureException",
+            GetPhoneNumberSettingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetPhoneNumberSettingsError::ThrottledClient(_) => "ThrottledClientException",
+            GetPhoneNumberSettingsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetProxySession
 #[derive(Debug, PartialEq)]
 pub enum GetProxySessionError {
@@ -12314,6 +13655,19 @@ impl fmt::Display for GetProxySessionError {
     }
 }
 impl Error for GetProxySessionError {}
+impl ServiceErrorCode for GetProxySessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetProxySessionError::BadRequest(_) => "BadRequestException",
+            GetProxySessionError::Forbidden(_) => "ForbiddenException",
+            GetProxySessionError::NotFound(_) => "NotFoundException",
+            GetProxySessionError::ServiceFailure(_) => "ServiceFailureException",
+            GetProxySessionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetProxySessionError::ThrottledClient(_) => "ThrottledClientException",
+            GetProxySessionError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetRetentionSettings
 #[derive(Debug, PartialEq)]
 pub enum GetRetentionSettingsError {
@@ -12386,6 +13740,19 @@ impl fmt::Display for GetRetentionSettingsError {
     }
 }
 impl Error for GetRetentionSettingsError {}
+impl ServiceErrorCode for GetRetentionSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRetentionSettingsError::BadRequest(_) => "BadRequestException",
+            GetRetentionSettingsError::Forbidden(_) => "ForbiddenException",
+            GetRetentionSettingsError::NotFound(_) => "NotFoundException",
+            GetRetentionSettingsError::ServiceFailure(_) => "ServiceFailureException",
+            GetRetentionSettingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetRetentionSettingsError::ThrottledClient(_) => "ThrottledClientException",
+            GetRetentionSettingsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetRoom
 #[derive(Debug, PartialEq)]
 pub enum GetRoomError {
@@ -12452,6 +13819,19 @@ impl fmt::Display for GetRoomError {
     }
 }
 impl Error for GetRoomError {}
+impl ServiceErrorCode for GetRoomError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRoomError::BadRequest(_) => "BadRequestException",
+            GetRoomError::Forbidden(_) => "ForbiddenException",
+            GetRoomError::NotFound(_) => "NotFoundException",
+            GetRoomError::ServiceFailure(_) => "ServiceFailureException",
+            GetRoomError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetRoomError::ThrottledClient(_) => "ThrottledClientException",
+            GetRoomError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetSipMediaApplication
 #[derive(Debug, PartialEq)]
 pub enum GetSipMediaApplicationError {
@@ -12526,6 +13906,19 @@ impl fmt::Display for GetSipMediaApplicationError {
     }
 }
 impl Error for GetSipMediaApplicationError {}
+impl ServiceErrorCode for GetSipMediaApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSipMediaApplicationError::BadRequest(_) => "BadRequestException",
+            GetSipMediaApplicationError::Forbidden(_) => "ForbiddenException",
+            GetSipMediaApplicationError::NotFound(_) => "NotFoundException",
+            GetSipMediaApplicationError::ServiceFailure(_) => "ServiceFailureException",
+            GetSipMediaApplicationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetSipMediaApplicationError::ThrottledClient(_) => "ThrottledClientException",
+            GetSipMediaApplicationError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetSipMediaApplicationLoggingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetSipMediaApplicationLoggingConfigurationError {
@@ -12626,6 +14019,27 @@ impl fmt::Display for GetSipMediaApplicationLoggingConfigurationError {
     }
 }
 impl Error for GetSipMediaApplicationLoggingConfigurationError {}
+impl ServiceErrorCode for GetSipMediaApplicationLoggingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSipMediaApplicationLoggingConfigurationError::BadRequest(_) => "BadRequestException",
+            GetSipMediaApplicationLoggingConfigurationError::Forbidden(_) => "ForbiddenException",
+            GetSipMediaApplicationLoggingConfigurationError::NotFound(_) => "NotFoundException",
+            GetSipMediaApplicationLoggingConfigurationError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            GetSipMediaApplicationLoggingConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetSipMediaApplicationLoggingConfigurationError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            GetSipMediaApplicationLoggingConfigurationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by GetSipRule
 #[derive(Debug, PartialEq)]
 pub enum GetSipRuleError {
@@ -12692,6 +14106,19 @@ impl fmt::Display for GetSipRuleError {
     }
 }
 impl Error for GetSipRuleError {}
+impl ServiceErrorCode for GetSipRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSipRuleError::BadRequest(_) => "BadRequestException",
+            GetSipRuleError::Forbidden(_) => "ForbiddenException",
+            GetSipRuleError::NotFound(_) => "NotFoundException",
+            GetSipRuleError::ServiceFailure(_) => "ServiceFailureException",
+            GetSipRuleError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetSipRuleError::ThrottledClient(_) => "ThrottledClientException",
+            GetSipRuleError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetUser
 #[derive(Debug, PartialEq)]
 pub enum GetUserError {
@@ -12758,6 +14185,19 @@ impl fmt::Display for GetUserError {
     }
 }
 impl Error for GetUserError {}
+impl ServiceErrorCode for GetUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUserError::BadRequest(_) => "BadRequestException",
+            GetUserError::Forbidden(_) => "ForbiddenException",
+            GetUserError::NotFound(_) => "NotFoundException",
+            GetUserError::ServiceFailure(_) => "ServiceFailureException",
+            GetUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetUserError::ThrottledClient(_) => "ThrottledClientException",
+            GetUserError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetUserSettings
 #[derive(Debug, PartialEq)]
 pub enum GetUserSettingsError {
@@ -12824,6 +14264,19 @@ impl fmt::Display for GetUserSettingsError {
     }
 }
 impl Error for GetUserSettingsError {}
+impl ServiceErrorCode for GetUserSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUserSettingsError::BadRequest(_) => "BadRequestException",
+            GetUserSettingsError::Forbidden(_) => "ForbiddenException",
+            GetUserSettingsError::NotFound(_) => "NotFoundException",
+            GetUserSettingsError::ServiceFailure(_) => "ServiceFailureException",
+            GetUserSettingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetUserSettingsError::ThrottledClient(_) => "ThrottledClientException",
+            GetUserSettingsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetVoiceConnector
 #[derive(Debug, PartialEq)]
 pub enum GetVoiceConnectorError {
@@ -12894,6 +14347,19 @@ impl fmt::Display for GetVoiceConnectorError {
     }
 }
 impl Error for GetVoiceConnectorError {}
+impl ServiceErrorCode for GetVoiceConnectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVoiceConnectorError::BadRequest(_) => "BadRequestException",
+            GetVoiceConnectorError::Forbidden(_) => "ForbiddenException",
+            GetVoiceConnectorError::NotFound(_) => "NotFoundException",
+            GetVoiceConnectorError::ServiceFailure(_) => "ServiceFailureException",
+            GetVoiceConnectorError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetVoiceConnectorError::ThrottledClient(_) => "ThrottledClientException",
+            GetVoiceConnectorError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetVoiceConnectorEmergencyCallingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetVoiceConnectorEmergencyCallingConfigurationError {
@@ -12998,6 +14464,31 @@ impl fmt::Display for GetVoiceConnectorEmergencyCallingConfigurationError {
     }
 }
 impl Error for GetVoiceConnectorEmergencyCallingConfigurationError {}
+impl ServiceErrorCode for GetVoiceConnectorEmergencyCallingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVoiceConnectorEmergencyCallingConfigurationError::BadRequest(_) => {
+                "BadRequestException"
+            }
+            GetVoiceConnectorEmergencyCallingConfigurationError::Forbidden(_) => {
+                "ForbiddenException"
+            }
+            GetVoiceConnectorEmergencyCallingConfigurationError::NotFound(_) => "NotFoundException",
+            GetVoiceConnectorEmergencyCallingConfigurationError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            GetVoiceConnectorEmergencyCallingConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetVoiceConnectorEmergencyCallingConfigurationError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            GetVoiceConnectorEmergencyCallingConfigurationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by GetVoiceConnectorGroup
 #[derive(Debug, PartialEq)]
 pub enum GetVoiceConnectorGroupError {
@@ -13072,6 +14563,19 @@ impl fmt::Display for GetVoiceConnectorGroupError {
     }
 }
 impl Error for GetVoiceConnectorGroupError {}
+impl ServiceErrorCode for GetVoiceConnectorGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVoiceConnectorGroupError::BadRequest(_) => "BadRequestException",
+            GetVoiceConnectorGroupError::Forbidden(_) => "ForbiddenException",
+            GetVoiceConnectorGroupError::NotFound(_) => "NotFoundException",
+            GetVoiceConnectorGroupError::ServiceFailure(_) => "ServiceFailureException",
+            GetVoiceConnectorGroupError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetVoiceConnectorGroupError::ThrottledClient(_) => "ThrottledClientException",
+            GetVoiceConnectorGroupError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetVoiceConnectorLoggingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetVoiceConnectorLoggingConfigurationError {
@@ -13168,6 +14672,27 @@ impl fmt::Display for GetVoiceConnectorLoggingConfigurationError {
     }
 }
 impl Error for GetVoiceConnectorLoggingConfigurationError {}
+impl ServiceErrorCode for GetVoiceConnectorLoggingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVoiceConnectorLoggingConfigurationError::BadRequest(_) => "BadRequestException",
+            GetVoiceConnectorLoggingConfigurationError::Forbidden(_) => "ForbiddenException",
+            GetVoiceConnectorLoggingConfigurationError::NotFound(_) => "NotFoundException",
+            GetVoiceConnectorLoggingConfigurationError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            GetVoiceConnectorLoggingConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetVoiceConnectorLoggingConfigurationError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            GetVoiceConnectorLoggingConfigurationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by GetVoiceConnectorOrigination
 #[derive(Debug, PartialEq)]
 pub enum GetVoiceConnectorOriginationError {
@@ -13254,6 +14779,23 @@ impl fmt::Display for GetVoiceConnectorOriginationError {
     }
 }
 impl Error for GetVoiceConnectorOriginationError {}
+impl ServiceErrorCode for GetVoiceConnectorOriginationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVoiceConnectorOriginationError::BadRequest(_) => "BadRequestException",
+            GetVoiceConnectorOriginationError::Forbidden(_) => "ForbiddenException",
+            GetVoiceConnectorOriginationError::NotFound(_) => "NotFoundException",
+            GetVoiceConnectorOriginationError::ServiceFailure(_) => "ServiceFailureException",
+            GetVoiceConnectorOriginationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetVoiceConnectorOriginationError::ThrottledClient(_) => "ThrottledClientException",
+            GetVoiceConnectorOriginationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by GetVoiceConnectorProxy
 #[derive(Debug, PartialEq)]
 pub enum GetVoiceConnectorProxyError {
@@ -13328,6 +14870,19 @@ impl fmt::Display for GetVoiceConnectorProxyError {
     }
 }
 impl Error for GetVoiceConnectorProxyError {}
+impl ServiceErrorCode for GetVoiceConnectorProxyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVoiceConnectorProxyError::BadRequest(_) => "BadRequestException",
+            GetVoiceConnectorProxyError::Forbidden(_) => "ForbiddenException",
+            GetVoiceConnectorProxyError::NotFound(_) => "NotFoundException",
+            GetVoiceConnectorProxyError::ServiceFailure(_) => "ServiceFailureException",
+            GetVoiceConnectorProxyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetVoiceConnectorProxyError::ThrottledClient(_) => "ThrottledClientException",
+            GetVoiceConnectorProxyError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by GetVoiceConnectorStreamingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetVoiceConnectorStreamingConfigurationError {
@@ -13424,6 +14979,27 @@ impl fmt::Display for GetVoiceConnectorStreamingConfigurationError {
     }
 }
 impl Error for GetVoiceConnectorStreamingConfigurationError {}
+impl ServiceErrorCode for GetVoiceConnectorStreamingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVoiceConnectorStreamingConfigurationError::BadRequest(_) => "BadRequestException",
+            GetVoiceConnectorStreamingConfigurationError::Forbidden(_) => "ForbiddenException",
+            GetVoiceConnectorStreamingConfigurationError::NotFound(_) => "NotFoundException",
+            GetVoiceConnectorStreamingConfigurationError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            GetVoiceConnectorStreamingConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetVoiceConnectorStreamingConfigurationError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            GetVoiceConnectorStreamingConfigurationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by GetVoiceConnectorTermination
 #[derive(Debug, PartialEq)]
 pub enum GetVoiceConnectorTerminationError {
@@ -13510,6 +15086,23 @@ impl fmt::Display for GetVoiceConnectorTerminationError {
     }
 }
 impl Error for GetVoiceConnectorTerminationError {}
+impl ServiceErrorCode for GetVoiceConnectorTerminationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVoiceConnectorTerminationError::BadRequest(_) => "BadRequestException",
+            GetVoiceConnectorTerminationError::Forbidden(_) => "ForbiddenException",
+            GetVoiceConnectorTerminationError::NotFound(_) => "NotFoundException",
+            GetVoiceConnectorTerminationError::ServiceFailure(_) => "ServiceFailureException",
+            GetVoiceConnectorTerminationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetVoiceConnectorTerminationError::ThrottledClient(_) => "ThrottledClientException",
+            GetVoiceConnectorTerminationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by GetVoiceConnectorTerminationHealth
 #[derive(Debug, PartialEq)]
 pub enum GetVoiceConnectorTerminationHealthError {
@@ -13602,6 +15195,25 @@ impl fmt::Display for GetVoiceConnectorTerminationHealthError {
     }
 }
 impl Error for GetVoiceConnectorTerminationHealthError {}
+impl ServiceErrorCode for GetVoiceConnectorTerminationHealthError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVoiceConnectorTerminationHealthError::BadRequest(_) => "BadRequestException",
+            GetVoiceConnectorTerminationHealthError::Forbidden(_) => "ForbiddenException",
+            GetVoiceConnectorTerminationHealthError::NotFound(_) => "NotFoundException",
+            GetVoiceConnectorTerminationHealthError::ServiceFailure(_) => "ServiceFailureException",
+            GetVoiceConnectorTerminationHealthError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetVoiceConnectorTerminationHealthError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            GetVoiceConnectorTerminationHealthError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by InviteUsers
 #[derive(Debug, PartialEq)]
 pub enum InviteUsersError {
@@ -13668,6 +15280,19 @@ impl fmt::Display for InviteUsersError {
     }
 }
 impl Error for InviteUsersError {}
+impl ServiceErrorCode for InviteUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InviteUsersError::BadRequest(_) => "BadRequestException",
+            InviteUsersError::Forbidden(_) => "ForbiddenException",
+            InviteUsersError::NotFound(_) => "NotFoundException",
+            InviteUsersError::ServiceFailure(_) => "ServiceFailureException",
+            InviteUsersError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            InviteUsersError::ThrottledClient(_) => "ThrottledClientException",
+            InviteUsersError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListAccounts
 #[derive(Debug, PartialEq)]
 pub enum ListAccountsError {
@@ -13734,6 +15359,19 @@ impl fmt::Display for ListAccountsError {
     }
 }
 impl Error for ListAccountsError {}
+impl ServiceErrorCode for ListAccountsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAccountsError::BadRequest(_) => "BadRequestException",
+            ListAccountsError::Forbidden(_) => "ForbiddenException",
+            ListAccountsError::NotFound(_) => "NotFoundException",
+            ListAccountsError::ServiceFailure(_) => "ServiceFailureException",
+            ListAccountsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListAccountsError::ThrottledClient(_) => "ThrottledClientException",
+            ListAccountsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListAppInstanceAdmins
 #[derive(Debug, PartialEq)]
 pub enum ListAppInstanceAdminsError {
@@ -13810,6 +15448,21 @@ impl fmt::Display for ListAppInstanceAdminsError {
     }
 }
 impl Error for ListAppInstanceAdminsError {}
+impl ServiceErrorCode for ListAppInstanceAdminsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAppInstanceAdminsError::BadRequest(_) => "BadRequestException",
+            ListAppInstanceAdminsError::Forbidden(_) => "ForbiddenException",
+            ListAppInstanceAdminsError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            ListAppInstanceAdminsError::ServiceFailure(_) => "ServiceFailureException",
+            ListAppInstanceAdminsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListAppInstanceAdminsError::ThrottledClient(_) => "ThrottledClientException",
+            ListAppInstanceAdminsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListAppInstanceUsers
 #[derive(Debug, PartialEq)]
 pub enum ListAppInstanceUsersError {
@@ -13876,6 +15529,18 @@ impl fmt::Display for ListAppInstanceUsersError {
     }
 }
 impl Error for ListAppInstanceUsersError {}
+impl ServiceErrorCode for ListAppInstanceUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAppInstanceUsersError::BadRequest(_) => "BadRequestException",
+            ListAppInstanceUsersError::Forbidden(_) => "ForbiddenException",
+            ListAppInstanceUsersError::ServiceFailure(_) => "ServiceFailureException",
+            ListAppInstanceUsersError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListAppInstanceUsersError::ThrottledClient(_) => "ThrottledClientException",
+            ListAppInstanceUsersError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListAppInstances
 #[derive(Debug, PartialEq)]
 pub enum ListAppInstancesError {
@@ -13936,6 +15601,18 @@ impl fmt::Display for ListAppInstancesError {
     }
 }
 impl Error for ListAppInstancesError {}
+impl ServiceErrorCode for ListAppInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAppInstancesError::BadRequest(_) => "BadRequestException",
+            ListAppInstancesError::Forbidden(_) => "ForbiddenException",
+            ListAppInstancesError::ServiceFailure(_) => "ServiceFailureException",
+            ListAppInstancesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListAppInstancesError::ThrottledClient(_) => "ThrottledClientException",
+            ListAppInstancesError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListAttendeeTags
 #[derive(Debug, PartialEq)]
 pub enum ListAttendeeTagsError {
@@ -14002,6 +15679,19 @@ impl fmt::Display for ListAttendeeTagsError {
     }
 }
 impl Error for ListAttendeeTagsError {}
+impl ServiceErrorCode for ListAttendeeTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAttendeeTagsError::BadRequest(_) => "BadRequestException",
+            ListAttendeeTagsError::Forbidden(_) => "ForbiddenException",
+            ListAttendeeTagsError::NotFound(_) => "NotFoundException",
+            ListAttendeeTagsError::ServiceFailure(_) => "ServiceFailureException",
+            ListAttendeeTagsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListAttendeeTagsError::ThrottledClient(_) => "ThrottledClientException",
+            ListAttendeeTagsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListAttendees
 #[derive(Debug, PartialEq)]
 pub enum ListAttendeesError {
@@ -14068,6 +15758,19 @@ impl fmt::Display for ListAttendeesError {
     }
 }
 impl Error for ListAttendeesError {}
+impl ServiceErrorCode for ListAttendeesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAttendeesError::BadRequest(_) => "BadRequestException",
+            ListAttendeesError::Forbidden(_) => "ForbiddenException",
+            ListAttendeesError::NotFound(_) => "NotFoundException",
+            ListAttendeesError::ServiceFailure(_) => "ServiceFailureException",
+            ListAttendeesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListAttendeesError::ThrottledClient(_) => "ThrottledClientException",
+            ListAttendeesError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListBots
 #[derive(Debug, PartialEq)]
 pub enum ListBotsError {
@@ -14134,6 +15837,19 @@ impl fmt::Display for ListBotsError {
     }
 }
 impl Error for ListBotsError {}
+impl ServiceErrorCode for ListBotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBotsError::BadRequest(_) => "BadRequestException",
+            ListBotsError::Forbidden(_) => "ForbiddenException",
+            ListBotsError::NotFound(_) => "NotFoundException",
+            ListBotsError::ServiceFailure(_) => "ServiceFailureException",
+            ListBotsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListBotsError::ThrottledClient(_) => "ThrottledClientException",
+            ListBotsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListChannelBans
 #[derive(Debug, PartialEq)]
 pub enum ListChannelBansError {
@@ -14194,6 +15910,18 @@ impl fmt::Display for ListChannelBansError {
     }
 }
 impl Error for ListChannelBansError {}
+impl ServiceErrorCode for ListChannelBansError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListChannelBansError::BadRequest(_) => "BadRequestException",
+            ListChannelBansError::Forbidden(_) => "ForbiddenException",
+            ListChannelBansError::ServiceFailure(_) => "ServiceFailureException",
+            ListChannelBansError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListChannelBansError::ThrottledClient(_) => "ThrottledClientException",
+            ListChannelBansError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListChannelMemberships
 #[derive(Debug, PartialEq)]
 pub enum ListChannelMembershipsError {
@@ -14262,6 +15990,18 @@ impl fmt::Display for ListChannelMembershipsError {
     }
 }
 impl Error for ListChannelMembershipsError {}
+impl ServiceErrorCode for ListChannelMembershipsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListChannelMembershipsError::BadRequest(_) => "BadRequestException",
+            ListChannelMembershipsError::Forbidden(_) => "ForbiddenException",
+            ListChannelMembershipsError::ServiceFailure(_) => "ServiceFailureException",
+            ListChannelMembershipsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListChannelMembershipsError::ThrottledClient(_) => "ThrottledClientException",
+            ListChannelMembershipsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListChannelMembershipsForAppInstanceUser
 #[derive(Debug, PartialEq)]
 pub enum ListChannelMembershipsForAppInstanceUserError {
@@ -14348,6 +16088,26 @@ impl fmt::Display for ListChannelMembershipsForAppInstanceUserError {
     }
 }
 impl Error for ListChannelMembershipsForAppInstanceUserError {}
+impl ServiceErrorCode for ListChannelMembershipsForAppInstanceUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListChannelMembershipsForAppInstanceUserError::BadRequest(_) => "BadRequestException",
+            ListChannelMembershipsForAppInstanceUserError::Forbidden(_) => "ForbiddenException",
+            ListChannelMembershipsForAppInstanceUserError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            ListChannelMembershipsForAppInstanceUserError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            ListChannelMembershipsForAppInstanceUserError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            ListChannelMembershipsForAppInstanceUserError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by ListChannelMessages
 #[derive(Debug, PartialEq)]
 pub enum ListChannelMessagesError {
@@ -14412,6 +16172,18 @@ impl fmt::Display for ListChannelMessagesError {
     }
 }
 impl Error for ListChannelMessagesError {}
+impl ServiceErrorCode for ListChannelMessagesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListChannelMessagesError::BadRequest(_) => "BadRequestException",
+            ListChannelMessagesError::Forbidden(_) => "ForbiddenException",
+            ListChannelMessagesError::ServiceFailure(_) => "ServiceFailureException",
+            ListChannelMessagesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListChannelMessagesError::ThrottledClient(_) => "ThrottledClientException",
+            ListChannelMessagesError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListChannelModerators
 #[derive(Debug, PartialEq)]
 pub enum ListChannelModeratorsError {
@@ -14480,6 +16252,18 @@ impl fmt::Display for ListChannelModeratorsError {
     }
 }
 impl Error for ListChannelModeratorsError {}
+impl ServiceErrorCode for ListChannelModeratorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListChannelModeratorsError::BadRequest(_) => "BadRequestException",
+            ListChannelModeratorsError::Forbidden(_) => "ForbiddenException",
+            ListChannelModeratorsError::ServiceFailure(_) => "ServiceFailureException",
+            ListChannelModeratorsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListChannelModeratorsError::ThrottledClient(_) => "ThrottledClientException",
+            ListChannelModeratorsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListChannels
 #[derive(Debug, PartialEq)]
 pub enum ListChannelsError {
@@ -14540,6 +16324,18 @@ impl fmt::Display for ListChannelsError {
     }
 }
 impl Error for ListChannelsError {}
+impl ServiceErrorCode for ListChannelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListChannelsError::BadRequest(_) => "BadRequestException",
+            ListChannelsError::Forbidden(_) => "ForbiddenException",
+            ListChannelsError::ServiceFailure(_) => "ServiceFailureException",
+            ListChannelsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListChannelsError::ThrottledClient(_) => "ThrottledClientException",
+            ListChannelsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListChannelsModeratedByAppInstanceUser
 #[derive(Debug, PartialEq)]
 pub enum ListChannelsModeratedByAppInstanceUserError {
@@ -14626,6 +16422,26 @@ impl fmt::Display for ListChannelsModeratedByAppInstanceUserError {
     }
 }
 impl Error for ListChannelsModeratedByAppInstanceUserError {}
+impl ServiceErrorCode for ListChannelsModeratedByAppInstanceUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListChannelsModeratedByAppInstanceUserError::BadRequest(_) => "BadRequestException",
+            ListChannelsModeratedByAppInstanceUserError::Forbidden(_) => "ForbiddenException",
+            ListChannelsModeratedByAppInstanceUserError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            ListChannelsModeratedByAppInstanceUserError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            ListChannelsModeratedByAppInstanceUserError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            ListChannelsModeratedByAppInstanceUserError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by ListMeetingTags
 #[derive(Debug, PartialEq)]
 pub enum ListMeetingTagsError {
@@ -14692,6 +16508,19 @@ impl fmt::Display for ListMeetingTagsError {
     }
 }
 impl Error for ListMeetingTagsError {}
+impl ServiceErrorCode for ListMeetingTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMeetingTagsError::BadRequest(_) => "BadRequestException",
+            ListMeetingTagsError::Forbidden(_) => "ForbiddenException",
+            ListMeetingTagsError::NotFound(_) => "NotFoundException",
+            ListMeetingTagsError::ServiceFailure(_) => "ServiceFailureException",
+            ListMeetingTagsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListMeetingTagsError::ThrottledClient(_) => "ThrottledClientException",
+            ListMeetingTagsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListMeetings
 #[derive(Debug, PartialEq)]
 pub enum ListMeetingsError {
@@ -14752,6 +16581,18 @@ impl fmt::Display for ListMeetingsError {
     }
 }
 impl Error for ListMeetingsError {}
+impl ServiceErrorCode for ListMeetingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMeetingsError::BadRequest(_) => "BadRequestException",
+            ListMeetingsError::Forbidden(_) => "ForbiddenException",
+            ListMeetingsError::ServiceFailure(_) => "ServiceFailureException",
+            ListMeetingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListMeetingsError::ThrottledClient(_) => "ThrottledClientException",
+            ListMeetingsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListPhoneNumberOrders
 #[derive(Debug, PartialEq)]
 pub enum ListPhoneNumberOrdersError {
@@ -14820,6 +16661,18 @@ impl fmt::Display for ListPhoneNumberOrdersError {
     }
 }
 impl Error for ListPhoneNumberOrdersError {}
+impl ServiceErrorCode for ListPhoneNumberOrdersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPhoneNumberOrdersError::BadRequest(_) => "BadRequestException",
+            ListPhoneNumberOrdersError::Forbidden(_) => "ForbiddenException",
+            ListPhoneNumberOrdersError::ServiceFailure(_) => "ServiceFailureException",
+            ListPhoneNumberOrdersError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListPhoneNumberOrdersError::ThrottledClient(_) => "ThrottledClientException",
+            ListPhoneNumberOrdersError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListPhoneNumbers
 #[derive(Debug, PartialEq)]
 pub enum ListPhoneNumbersError {
@@ -14880,6 +16733,18 @@ impl fmt::Display for ListPhoneNumbersError {
     }
 }
 impl Error for ListPhoneNumbersError {}
+impl ServiceErrorCode for ListPhoneNumbersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPhoneNumbersError::BadRequest(_) => "BadRequestException",
+            ListPhoneNumbersError::Forbidden(_) => "ForbiddenException",
+            ListPhoneNumbersError::ServiceFailure(_) => "ServiceFailureException",
+            ListPhoneNumbersError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListPhoneNumbersError::ThrottledClient(_) => "ThrottledClientException",
+            ListPhoneNumbersError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListProxySessions
 #[derive(Debug, PartialEq)]
 pub enum ListProxySessionsError {
@@ -14950,6 +16815,19 @@ impl fmt::Display for ListProxySessionsError {
     }
 }
 impl Error for ListProxySessionsError {}
+impl ServiceErrorCode for ListProxySessionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProxySessionsError::BadRequest(_) => "BadRequestException",
+            ListProxySessionsError::Forbidden(_) => "ForbiddenException",
+            ListProxySessionsError::NotFound(_) => "NotFoundException",
+            ListProxySessionsError::ServiceFailure(_) => "ServiceFailureException",
+            ListProxySessionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListProxySessionsError::ThrottledClient(_) => "ThrottledClientException",
+            ListProxySessionsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListRoomMemberships
 #[derive(Debug, PartialEq)]
 pub enum ListRoomMembershipsError {
@@ -15020,6 +16898,19 @@ impl fmt::Display for ListRoomMembershipsError {
     }
 }
 impl Error for ListRoomMembershipsError {}
+impl ServiceErrorCode for ListRoomMembershipsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRoomMembershipsError::BadRequest(_) => "BadRequestException",
+            ListRoomMembershipsError::Forbidden(_) => "ForbiddenException",
+            ListRoomMembershipsError::NotFound(_) => "NotFoundException",
+            ListRoomMembershipsError::ServiceFailure(_) => "ServiceFailureException",
+            ListRoomMembershipsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListRoomMembershipsError::ThrottledClient(_) => "ThrottledClientException",
+            ListRoomMembershipsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListRooms
 #[derive(Debug, PartialEq)]
 pub enum ListRoomsError {
@@ -15086,6 +16977,19 @@ impl fmt::Display for ListRoomsError {
     }
 }
 impl Error for ListRoomsError {}
+impl ServiceErrorCode for ListRoomsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRoomsError::BadRequest(_) => "BadRequestException",
+            ListRoomsError::Forbidden(_) => "ForbiddenException",
+            ListRoomsError::NotFound(_) => "NotFoundException",
+            ListRoomsError::ServiceFailure(_) => "ServiceFailureException",
+            ListRoomsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListRoomsError::ThrottledClient(_) => "ThrottledClientException",
+            ListRoomsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListSipMediaApplications
 #[derive(Debug, PartialEq)]
 pub enum ListSipMediaApplicationsError {
@@ -15154,6 +17058,18 @@ impl fmt::Display for ListSipMediaApplicationsError {
     }
 }
 impl Error for ListSipMediaApplicationsError {}
+impl ServiceErrorCode for ListSipMediaApplicationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSipMediaApplicationsError::BadRequest(_) => "BadRequestException",
+            ListSipMediaApplicationsError::Forbidden(_) => "ForbiddenException",
+            ListSipMediaApplicationsError::ServiceFailure(_) => "ServiceFailureException",
+            ListSipMediaApplicationsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListSipMediaApplicationsError::ThrottledClient(_) => "ThrottledClientException",
+            ListSipMediaApplicationsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListSipRules
 #[derive(Debug, PartialEq)]
 pub enum ListSipRulesError {
@@ -15214,6 +17130,18 @@ impl fmt::Display for ListSipRulesError {
     }
 }
 impl Error for ListSipRulesError {}
+impl ServiceErrorCode for ListSipRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSipRulesError::BadRequest(_) => "BadRequestException",
+            ListSipRulesError::Forbidden(_) => "ForbiddenException",
+            ListSipRulesError::ServiceFailure(_) => "ServiceFailureException",
+            ListSipRulesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListSipRulesError::ThrottledClient(_) => "ThrottledClientException",
+            ListSipRulesError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -15278,6 +17206,18 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::Forbidden(_) => "ForbiddenException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+            ListTagsForResourceError::ServiceFailure(_) => "ServiceFailureException",
+            ListTagsForResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListTagsForResourceError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListUsers
 #[derive(Debug, PartialEq)]
 pub enum ListUsersError {
@@ -15344,6 +17284,19 @@ impl fmt::Display for ListUsersError {
     }
 }
 impl Error for ListUsersError {}
+impl ServiceErrorCode for ListUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUsersError::BadRequest(_) => "BadRequestException",
+            ListUsersError::Forbidden(_) => "ForbiddenException",
+            ListUsersError::NotFound(_) => "NotFoundException",
+            ListUsersError::ServiceFailure(_) => "ServiceFailureException",
+            ListUsersError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListUsersError::ThrottledClient(_) => "ThrottledClientException",
+            ListUsersError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListVoiceConnectorGroups
 #[derive(Debug, PartialEq)]
 pub enum ListVoiceConnectorGroupsError {
@@ -15412,6 +17365,18 @@ impl fmt::Display for ListVoiceConnectorGroupsError {
     }
 }
 impl Error for ListVoiceConnectorGroupsError {}
+impl ServiceErrorCode for ListVoiceConnectorGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVoiceConnectorGroupsError::BadRequest(_) => "BadRequestException",
+            ListVoiceConnectorGroupsError::Forbidden(_) => "ForbiddenException",
+            ListVoiceConnectorGroupsError::ServiceFailure(_) => "ServiceFailureException",
+            ListVoiceConnectorGroupsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListVoiceConnectorGroupsError::ThrottledClient(_) => "ThrottledClientException",
+            ListVoiceConnectorGroupsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ListVoiceConnectorTerminationCredentials
 #[derive(Debug, PartialEq)]
 pub enum ListVoiceConnectorTerminationCredentialsError {
@@ -15508,6 +17473,27 @@ impl fmt::Display for ListVoiceConnectorTerminationCredentialsError {
     }
 }
 impl Error for ListVoiceConnectorTerminationCredentialsError {}
+impl ServiceErrorCode for ListVoiceConnectorTerminationCredentialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVoiceConnectorTerminationCredentialsError::BadRequest(_) => "BadRequestException",
+            ListVoiceConnectorTerminationCredentialsError::Forbidden(_) => "ForbiddenException",
+            ListVoiceConnectorTerminationCredentialsError::NotFound(_) => "NotFoundException",
+            ListVoiceConnectorTerminationCredentialsError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            ListVoiceConnectorTerminationCredentialsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            ListVoiceConnectorTerminationCredentialsError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            ListVoiceConnectorTerminationCredentialsError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by ListVoiceConnectors
 #[derive(Debug, PartialEq)]
 pub enum ListVoiceConnectorsError {
@@ -15572,6 +17558,18 @@ impl fmt::Display for ListVoiceConnectorsError {
     }
 }
 impl Error for ListVoiceConnectorsError {}
+impl ServiceErrorCode for ListVoiceConnectorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVoiceConnectorsError::BadRequest(_) => "BadRequestException",
+            ListVoiceConnectorsError::Forbidden(_) => "ForbiddenException",
+            ListVoiceConnectorsError::ServiceFailure(_) => "ServiceFailureException",
+            ListVoiceConnectorsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListVoiceConnectorsError::ThrottledClient(_) => "ThrottledClientException",
+            ListVoiceConnectorsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by LogoutUser
 #[derive(Debug, PartialEq)]
 pub enum LogoutUserError {
@@ -15638,6 +17636,19 @@ impl fmt::Display for LogoutUserError {
     }
 }
 impl Error for LogoutUserError {}
+impl ServiceErrorCode for LogoutUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            LogoutUserError::BadRequest(_) => "BadRequestException",
+            LogoutUserError::Forbidden(_) => "ForbiddenException",
+            LogoutUserError::NotFound(_) => "NotFoundException",
+            LogoutUserError::ServiceFailure(_) => "ServiceFailureException",
+            LogoutUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            LogoutUserError::ThrottledClient(_) => "ThrottledClientException",
+            LogoutUserError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by PutAppInstanceRetentionSettings
 #[derive(Debug, PartialEq)]
 pub enum PutAppInstanceRetentionSettingsError {
@@ -15736,6 +17747,24 @@ impl fmt::Display for PutAppInstanceRetentionSettingsError {
     }
 }
 impl Error for PutAppInstanceRetentionSettingsError {}
+impl ServiceErrorCode for PutAppInstanceRetentionSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAppInstanceRetentionSettingsError::BadRequest(_) => "BadRequestException",
+            PutAppInstanceRetentionSettingsError::Conflict(_) => "ConflictException",
+            PutAppInstanceRetentionSettingsError::Forbidden(_) => "ForbiddenException",
+            PutAppInstanceRetentionSettingsError::NotFound(_) => "NotFoundException",
+            PutAppInstanceRetentionSettingsError::ServiceFailure(_) => "ServiceFailureException",
+            PutAppInstanceRetentionSettingsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            PutAppInstanceRetentionSettingsError::ThrottledClient(_) => "ThrottledClientException",
+            PutAppInstanceRetentionSettingsError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by PutAppInstanceStreamingConfigurations
 #[derive(Debug, PartialEq)]
 pub enum PutAppInstanceStreamingConfigurationsError {
@@ -15832,6 +17861,27 @@ impl fmt::Display for PutAppInstanceStreamingConfigurationsError {
     }
 }
 impl Error for PutAppInstanceStreamingConfigurationsError {}
+impl ServiceErrorCode for PutAppInstanceStreamingConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAppInstanceStreamingConfigurationsError::BadRequest(_) => "BadRequestException",
+            PutAppInstanceStreamingConfigurationsError::Forbidden(_) => "ForbiddenException",
+            PutAppInstanceStreamingConfigurationsError::NotFound(_) => "NotFoundException",
+            PutAppInstanceStreamingConfigurationsError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            PutAppInstanceStreamingConfigurationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            PutAppInstanceStreamingConfigurationsError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            PutAppInstanceStreamingConfigurationsError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by PutEventsConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutEventsConfigurationError {
@@ -15906,6 +17956,21 @@ impl fmt::Display for PutEventsConfigurationError {
     }
 }
 impl Error for PutEventsConfigurationError {}
+impl ServiceErrorCode for PutEventsConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutEventsConfigurationError::BadRequest(_) => "BadRequestException",
+            PutEventsConfigurationError::Forbidden(_) => "ForbiddenException",
+            PutEventsConfigurationError::NotFound(_) => "NotFoundException",
+            PutEventsConfigurationError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            PutEventsConfigurationError::ServiceFailure(_) => "ServiceFailureException",
+            PutEventsConfigurationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            PutEventsConfigurationError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by PutRetentionSettings
 #[derive(Debug, PartialEq)]
 pub enum PutRetentionSettingsError {
@@ -15984,6 +18049,20 @@ impl fmt::Display for PutRetentionSettingsError {
     }
 }
 impl Error for PutRetentionSettingsError {}
+impl ServiceErrorCode for PutRetentionSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRetentionSettingsError::BadRequest(_) => "BadRequestException",
+            PutRetentionSettingsError::Conflict(_) => "ConflictException",
+            PutRetentionSettingsError::Forbidden(_) => "ForbiddenException",
+            PutRetentionSettingsError::NotFound(_) => "NotFoundException",
+            PutRetentionSettingsError::ServiceFailure(_) => "ServiceFailureException",
+            PutRetentionSettingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            PutRetentionSettingsError::ThrottledClient(_) => "ThrottledClientException",
+            PutRetentionSettingsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by PutSipMediaApplicationLoggingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutSipMediaApplicationLoggingConfigurationError {
@@ -16084,6 +18163,27 @@ impl fmt::Display for PutSipMediaApplicationLoggingConfigurationError {
     }
 }
 impl Error for PutSipMediaApplicationLoggingConfigurationError {}
+impl ServiceErrorCode for PutSipMediaApplicationLoggingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutSipMediaApplicationLoggingConfigurationError::BadRequest(_) => "BadRequestException",
+            PutSipMediaApplicationLoggingConfigurationError::Forbidden(_) => "ForbiddenException",
+            PutSipMediaApplicationLoggingConfigurationError::NotFound(_) => "NotFoundException",
+            PutSipMediaApplicationLoggingConfigurationError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            PutSipMediaApplicationLoggingConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            PutSipMediaApplicationLoggingConfigurationError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            PutSipMediaApplicationLoggingConfigurationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by PutVoiceConnectorEmergencyCallingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutVoiceConnectorEmergencyCallingConfigurationError {
@@ -16188,6 +18288,31 @@ impl fmt::Display for PutVoiceConnectorEmergencyCallingConfigurationError {
     }
 }
 impl Error for PutVoiceConnectorEmergencyCallingConfigurationError {}
+impl ServiceErrorCode for PutVoiceConnectorEmergencyCallingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutVoiceConnectorEmergencyCallingConfigurationError::BadRequest(_) => {
+                "BadRequestException"
+            }
+            PutVoiceConnectorEmergencyCallingConfigurationError::Forbidden(_) => {
+                "ForbiddenException"
+            }
+            PutVoiceConnectorEmergencyCallingConfigurationError::NotFound(_) => "NotFoundException",
+            PutVoiceConnectorEmergencyCallingConfigurationError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            PutVoiceConnectorEmergencyCallingConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            PutVoiceConnectorEmergencyCallingConfigurationError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            PutVoiceConnectorEmergencyCallingConfigurationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by PutVoiceConnectorLoggingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutVoiceConnectorLoggingConfigurationError {
@@ -16284,6 +18409,27 @@ impl fmt::Display for PutVoiceConnectorLoggingConfigurationError {
     }
 }
 impl Error for PutVoiceConnectorLoggingConfigurationError {}
+impl ServiceErrorCode for PutVoiceConnectorLoggingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutVoiceConnectorLoggingConfigurationError::BadRequest(_) => "BadRequestException",
+            PutVoiceConnectorLoggingConfigurationError::Forbidden(_) => "ForbiddenException",
+            PutVoiceConnectorLoggingConfigurationError::NotFound(_) => "NotFoundException",
+            PutVoiceConnectorLoggingConfigurationError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            PutVoiceConnectorLoggingConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            PutVoiceConnectorLoggingConfigurationError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            PutVoiceConnectorLoggingConfigurationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by PutVoiceConnectorOrigination
 #[derive(Debug, PartialEq)]
 pub enum PutVoiceConnectorOriginationError {
@@ -16370,6 +18516,23 @@ impl fmt::Display for PutVoiceConnectorOriginationError {
     }
 }
 impl Error for PutVoiceConnectorOriginationError {}
+impl ServiceErrorCode for PutVoiceConnectorOriginationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutVoiceConnectorOriginationError::BadRequest(_) => "BadRequestException",
+            PutVoiceConnectorOriginationError::Forbidden(_) => "ForbiddenException",
+            PutVoiceConnectorOriginationError::NotFound(_) => "NotFoundException",
+            PutVoiceConnectorOriginationError::ServiceFailure(_) => "ServiceFailureException",
+            PutVoiceConnectorOriginationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            PutVoiceConnectorOriginationError::ThrottledClient(_) => "ThrottledClientException",
+            PutVoiceConnectorOriginationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by PutVoiceConnectorProxy
 #[derive(Debug, PartialEq)]
 pub enum PutVoiceConnectorProxyError {
@@ -16450,6 +18613,20 @@ impl fmt::Display for PutVoiceConnectorProxyError {
     }
 }
 impl Error for PutVoiceConnectorProxyError {}
+impl ServiceErrorCode for PutVoiceConnectorProxyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutVoiceConnectorProxyError::AccessDenied(_) => "AccessDeniedException",
+            PutVoiceConnectorProxyError::BadRequest(_) => "BadRequestException",
+            PutVoiceConnectorProxyError::Forbidden(_) => "ForbiddenException",
+            PutVoiceConnectorProxyError::NotFound(_) => "NotFoundException",
+            PutVoiceConnectorProxyError::ServiceFailure(_) => "ServiceFailureException",
+            PutVoiceConnectorProxyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            PutVoiceConnectorProxyError::ThrottledClient(_) => "ThrottledClientException",
+            PutVoiceConnectorProxyError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by PutVoiceConnectorStreamingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutVoiceConnectorStreamingConfigurationError {
@@ -16546,6 +18723,27 @@ impl fmt::Display for PutVoiceConnectorStreamingConfigurationError {
     }
 }
 impl Error for PutVoiceConnectorStreamingConfigurationError {}
+impl ServiceErrorCode for PutVoiceConnectorStreamingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutVoiceConnectorStreamingConfigurationError::BadRequest(_) => "BadRequestException",
+            PutVoiceConnectorStreamingConfigurationError::Forbidden(_) => "ForbiddenException",
+            PutVoiceConnectorStreamingConfigurationError::NotFound(_) => "NotFoundException",
+            PutVoiceConnectorStreamingConfigurationError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            PutVoiceConnectorStreamingConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            PutVoiceConnectorStreamingConfigurationError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            PutVoiceConnectorStreamingConfigurationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by PutVoiceConnectorTermination
 #[derive(Debug, PartialEq)]
 pub enum PutVoiceConnectorTerminationError {
@@ -16640,6 +18838,24 @@ impl fmt::Display for PutVoiceConnectorTerminationError {
     }
 }
 impl Error for PutVoiceConnectorTerminationError {}
+impl ServiceErrorCode for PutVoiceConnectorTerminationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutVoiceConnectorTerminationError::AccessDenied(_) => "AccessDeniedException",
+            PutVoiceConnectorTerminationError::BadRequest(_) => "BadRequestException",
+            PutVoiceConnectorTerminationError::Forbidden(_) => "ForbiddenException",
+            PutVoiceConnectorTerminationError::NotFound(_) => "NotFoundException",
+            PutVoiceConnectorTerminationError::ServiceFailure(_) => "ServiceFailureException",
+            PutVoiceConnectorTerminationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            PutVoiceConnectorTerminationError::ThrottledClient(_) => "ThrottledClientException",
+            PutVoiceConnectorTerminationError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by PutVoiceConnectorTerminationCredentials
 #[derive(Debug, PartialEq)]
 pub enum PutVoiceConnectorTerminationCredentialsError {
@@ -16736,6 +18952,27 @@ impl fmt::Display for PutVoiceConnectorTerminationCredentialsError {
     }
 }
 impl Error for PutVoiceConnectorTerminationCredentialsError {}
+impl ServiceErrorCode for PutVoiceConnectorTerminationCredentialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutVoiceConnectorTerminationCredentialsError::BadRequest(_) => "BadRequestException",
+            PutVoiceConnectorTerminationCredentialsError::Forbidden(_) => "ForbiddenException",
+            PutVoiceConnectorTerminationCredentialsError::NotFound(_) => "NotFoundException",
+            PutVoiceConnectorTerminationCredentialsError::ServiceFailure(_) => {
+                "ServiceFailureException"
+            }
+            PutVoiceConnectorTerminationCredentialsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            PutVoiceConnectorTerminationCredentialsError::ThrottledClient(_) => {
+                "ThrottledClientException"
+            }
+            PutVoiceConnectorTerminationCredentialsError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by RedactChannelMessage
 #[derive(Debug, PartialEq)]
 pub enum RedactChannelMessageError {
@@ -16802,6 +19039,18 @@ impl fmt::Display for RedactChannelMessageError {
     }
 }
 impl Error for RedactChannelMessageError {}
+impl ServiceErrorCode for RedactChannelMessageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RedactChannelMessageError::BadRequest(_) => "BadRequestException",
+            RedactChannelMessageError::Forbidden(_) => "ForbiddenException",
+            RedactChannelMessageError::ServiceFailure(_) => "ServiceFailureException",
+            RedactChannelMessageError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RedactChannelMessageError::ThrottledClient(_) => "ThrottledClientException",
+            RedactChannelMessageError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by RedactConversationMessage
 #[derive(Debug, PartialEq)]
 pub enum RedactConversationMessageError {
@@ -16878,6 +19127,19 @@ impl fmt::Display for RedactConversationMessageError {
     }
 }
 impl Error for RedactConversationMessageError {}
+impl ServiceErrorCode for RedactConversationMessageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RedactConversationMessageError::BadRequest(_) => "BadRequestException",
+            RedactConversationMessageError::Forbidden(_) => "ForbiddenException",
+            RedactConversationMessageError::NotFound(_) => "NotFoundException",
+            RedactConversationMessageError::ServiceFailure(_) => "ServiceFailureException",
+            RedactConversationMessageError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RedactConversationMessageError::ThrottledClient(_) => "ThrottledClientException",
+            RedactConversationMessageError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by RedactRoomMessage
 #[derive(Debug, PartialEq)]
 pub enum RedactRoomMessageError {
@@ -16948,6 +19210,19 @@ impl fmt::Display for RedactRoomMessageError {
     }
 }
 impl Error for RedactRoomMessageError {}
+impl ServiceErrorCode for RedactRoomMessageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RedactRoomMessageError::BadRequest(_) => "BadRequestException",
+            RedactRoomMessageError::Forbidden(_) => "ForbiddenException",
+            RedactRoomMessageError::NotFound(_) => "NotFoundException",
+            RedactRoomMessageError::ServiceFailure(_) => "ServiceFailureException",
+            RedactRoomMessageError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RedactRoomMessageError::ThrottledClient(_) => "ThrottledClientException",
+            RedactRoomMessageError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by RegenerateSecurityToken
 #[derive(Debug, PartialEq)]
 pub enum RegenerateSecurityTokenError {
@@ -17022,6 +19297,19 @@ impl fmt::Display for RegenerateSecurityTokenError {
     }
 }
 impl Error for RegenerateSecurityTokenError {}
+impl ServiceErrorCode for RegenerateSecurityTokenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegenerateSecurityTokenError::BadRequest(_) => "BadRequestException",
+            RegenerateSecurityTokenError::Forbidden(_) => "ForbiddenException",
+            RegenerateSecurityTokenError::NotFound(_) => "NotFoundException",
+            RegenerateSecurityTokenError::ServiceFailure(_) => "ServiceFailureException",
+            RegenerateSecurityTokenError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RegenerateSecurityTokenError::ThrottledClient(_) => "ThrottledClientException",
+            RegenerateSecurityTokenError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by ResetPersonalPIN
 #[derive(Debug, PartialEq)]
 pub enum ResetPersonalPINError {
@@ -17088,6 +19376,19 @@ impl fmt::Display for ResetPersonalPINError {
     }
 }
 impl Error for ResetPersonalPINError {}
+impl ServiceErrorCode for ResetPersonalPINError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetPersonalPINError::BadRequest(_) => "BadRequestException",
+            ResetPersonalPINError::Forbidden(_) => "ForbiddenException",
+            ResetPersonalPINError::NotFound(_) => "NotFoundException",
+            ResetPersonalPINError::ServiceFailure(_) => "ServiceFailureException",
+            ResetPersonalPINError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ResetPersonalPINError::ThrottledClient(_) => "ThrottledClientException",
+            ResetPersonalPINError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by RestorePhoneNumber
 #[derive(Debug, PartialEq)]
 pub enum RestorePhoneNumberError {
@@ -17166,6 +19467,20 @@ impl fmt::Display for RestorePhoneNumberError {
     }
 }
 impl Error for RestorePhoneNumberError {}
+impl ServiceErrorCode for RestorePhoneNumberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestorePhoneNumberError::BadRequest(_) => "BadRequestException",
+            RestorePhoneNumberError::Forbidden(_) => "ForbiddenException",
+            RestorePhoneNumberError::NotFound(_) => "NotFoundException",
+            RestorePhoneNumberError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            RestorePhoneNumberError::ServiceFailure(_) => "ServiceFailureException",
+            RestorePhoneNumberError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RestorePhoneNumberError::ThrottledClient(_) => "ThrottledClientException",
+            RestorePhoneNumberError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by SearchAvailablePhoneNumbers
 #[derive(Debug, PartialEq)]
 pub enum SearchAvailablePhoneNumbersError {
@@ -17252,6 +19567,23 @@ impl fmt::Display for SearchAvailablePhoneNumbersError {
     }
 }
 impl Error for SearchAvailablePhoneNumbersError {}
+impl ServiceErrorCode for SearchAvailablePhoneNumbersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchAvailablePhoneNumbersError::AccessDenied(_) => "AccessDeniedException",
+            SearchAvailablePhoneNumbersError::BadRequest(_) => "BadRequestException",
+            SearchAvailablePhoneNumbersError::Forbidden(_) => "ForbiddenException",
+            SearchAvailablePhoneNumbersError::ServiceFailure(_) => "ServiceFailureException",
+            SearchAvailablePhoneNumbersError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            SearchAvailablePhoneNumbersError::ThrottledClient(_) => "ThrottledClientException",
+            SearchAvailablePhoneNumbersError::UnauthorizedClient(_) => {
+                "UnauthorizedClientException"
+            }
+        }
+    }
+}
 /// Errors returned by SendChannelMessage
 #[derive(Debug, PartialEq)]
 pub enum SendChannelMessageError {
@@ -17322,6 +19654,19 @@ impl fmt::Display for SendChannelMessageError {
     }
 }
 impl Error for SendChannelMessageError {}
+impl ServiceErrorCode for SendChannelMessageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendChannelMessageError::BadRequest(_) => "BadRequestException",
+            SendChannelMessageError::Conflict(_) => "ConflictException",
+            SendChannelMessageError::Forbidden(_) => "ForbiddenException",
+            SendChannelMessageError::ServiceFailure(_) => "ServiceFailureException",
+            SendChannelMessageError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            SendChannelMessageError::ThrottledClient(_) => "ThrottledClientException",
+            SendChannelMessageError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by TagAttendee
 #[derive(Debug, PartialEq)]
 pub enum TagAttendeeError {
@@ -17394,6 +19739,20 @@ impl fmt::Display for TagAttendeeError {
     }
 }
 impl Error for TagAttendeeError {}
+impl ServiceErrorCode for TagAttendeeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagAttendeeError::BadRequest(_) => "BadRequestException",
+            TagAttendeeError::Forbidden(_) => "ForbiddenException",
+            TagAttendeeError::NotFound(_) => "NotFoundException",
+            TagAttendeeError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            TagAttendeeError::ServiceFailure(_) => "ServiceFailureException",
+            TagAttendeeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            TagAttendeeError::ThrottledClient(_) => "ThrottledClientException",
+            TagAttendeeError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by TagMeeting
 #[derive(Debug, PartialEq)]
 pub enum TagMeetingError {
@@ -17466,6 +19825,20 @@ impl fmt::Display for TagMeetingError {
     }
 }
 impl Error for TagMeetingError {}
+impl ServiceErrorCode for TagMeetingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagMeetingError::BadRequest(_) => "BadRequestException",
+            TagMeetingError::Forbidden(_) => "ForbiddenException",
+            TagMeetingError::NotFound(_) => "NotFoundException",
+            TagMeetingError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            TagMeetingError::ServiceFailure(_) => "ServiceFailureException",
+            TagMeetingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            TagMeetingError::ThrottledClient(_) => "ThrottledClientException",
+            TagMeetingError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -17526,6 +19899,18 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::Forbidden(_) => "ForbiddenException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+            TagResourceError::ServiceFailure(_) => "ServiceFailureException",
+            TagResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            TagResourceError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UntagAttendee
 #[derive(Debug, PartialEq)]
 pub enum UntagAttendeeError {
@@ -17592,6 +19977,19 @@ impl fmt::Display for UntagAttendeeError {
     }
 }
 impl Error for UntagAttendeeError {}
+impl ServiceErrorCode for UntagAttendeeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagAttendeeError::BadRequest(_) => "BadRequestException",
+            UntagAttendeeError::Forbidden(_) => "ForbiddenException",
+            UntagAttendeeError::NotFound(_) => "NotFoundException",
+            UntagAttendeeError::ServiceFailure(_) => "ServiceFailureException",
+            UntagAttendeeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UntagAttendeeError::ThrottledClient(_) => "ThrottledClientException",
+            UntagAttendeeError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UntagMeeting
 #[derive(Debug, PartialEq)]
 pub enum UntagMeetingError {
@@ -17658,6 +20056,19 @@ impl fmt::Display for UntagMeetingError {
     }
 }
 impl Error for UntagMeetingError {}
+impl ServiceErrorCode for UntagMeetingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagMeetingError::BadRequest(_) => "BadRequestException",
+            UntagMeetingError::Forbidden(_) => "ForbiddenException",
+            UntagMeetingError::NotFound(_) => "NotFoundException",
+            UntagMeetingError::ServiceFailure(_) => "ServiceFailureException",
+            UntagMeetingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UntagMeetingError::ThrottledClient(_) => "ThrottledClientException",
+            UntagMeetingError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -17718,6 +20129,18 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::Forbidden(_) => "ForbiddenException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+            UntagResourceError::ServiceFailure(_) => "ServiceFailureException",
+            UntagResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UntagResourceError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateAccount
 #[derive(Debug, PartialEq)]
 pub enum UpdateAccountError {
@@ -17784,6 +20207,19 @@ impl fmt::Display for UpdateAccountError {
     }
 }
 impl Error for UpdateAccountError {}
+impl ServiceErrorCode for UpdateAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAccountError::BadRequest(_) => "BadRequestException",
+            UpdateAccountError::Forbidden(_) => "ForbiddenException",
+            UpdateAccountError::NotFound(_) => "NotFoundException",
+            UpdateAccountError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateAccountError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateAccountError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateAccountError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateAccountSettings
 #[derive(Debug, PartialEq)]
 pub enum UpdateAccountSettingsError {
@@ -17864,6 +20300,20 @@ impl fmt::Display for UpdateAccountSettingsError {
     }
 }
 impl Error for UpdateAccountSettingsError {}
+impl ServiceErrorCode for UpdateAccountSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAccountSettingsError::BadRequest(_) => "BadRequestException",
+            UpdateAccountSettingsError::Conflict(_) => "ConflictException",
+            UpdateAccountSettingsError::Forbidden(_) => "ForbiddenException",
+            UpdateAccountSettingsError::NotFound(_) => "NotFoundException",
+            UpdateAccountSettingsError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateAccountSettingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateAccountSettingsError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateAccountSettingsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateAppInstance
 #[derive(Debug, PartialEq)]
 pub enum UpdateAppInstanceError {
@@ -17934,6 +20384,19 @@ impl fmt::Display for UpdateAppInstanceError {
     }
 }
 impl Error for UpdateAppInstanceError {}
+impl ServiceErrorCode for UpdateAppInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAppInstanceError::BadRequest(_) => "BadRequestException",
+            UpdateAppInstanceError::Conflict(_) => "ConflictException",
+            UpdateAppInstanceError::Forbidden(_) => "ForbiddenException",
+            UpdateAppInstanceError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateAppInstanceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateAppInstanceError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateAppInstanceError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateAppInstanceUser
 #[derive(Debug, PartialEq)]
 pub enum UpdateAppInstanceUserError {
@@ -18008,6 +20471,19 @@ impl fmt::Display for UpdateAppInstanceUserError {
     }
 }
 impl Error for UpdateAppInstanceUserError {}
+impl ServiceErrorCode for UpdateAppInstanceUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAppInstanceUserError::BadRequest(_) => "BadRequestException",
+            UpdateAppInstanceUserError::Conflict(_) => "ConflictException",
+            UpdateAppInstanceUserError::Forbidden(_) => "ForbiddenException",
+            UpdateAppInstanceUserError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateAppInstanceUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateAppInstanceUserError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateAppInstanceUserError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateBot
 #[derive(Debug, PartialEq)]
 pub enum UpdateBotError {
@@ -18074,6 +20550,19 @@ impl fmt::Display for UpdateBotError {
     }
 }
 impl Error for UpdateBotError {}
+impl ServiceErrorCode for UpdateBotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateBotError::BadRequest(_) => "BadRequestException",
+            UpdateBotError::Forbidden(_) => "ForbiddenException",
+            UpdateBotError::NotFound(_) => "NotFoundException",
+            UpdateBotError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateBotError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateBotError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateBotError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateChannel
 #[derive(Debug, PartialEq)]
 pub enum UpdateChannelError {
@@ -18140,6 +20629,19 @@ impl fmt::Display for UpdateChannelError {
     }
 }
 impl Error for UpdateChannelError {}
+impl ServiceErrorCode for UpdateChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateChannelError::BadRequest(_) => "BadRequestException",
+            UpdateChannelError::Conflict(_) => "ConflictException",
+            UpdateChannelError::Forbidden(_) => "ForbiddenException",
+            UpdateChannelError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateChannelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateChannelError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateChannelError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateChannelMessage
 #[derive(Debug, PartialEq)]
 pub enum UpdateChannelMessageError {
@@ -18212,6 +20714,19 @@ impl fmt::Display for UpdateChannelMessageError {
     }
 }
 impl Error for UpdateChannelMessageError {}
+impl ServiceErrorCode for UpdateChannelMessageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateChannelMessageError::BadRequest(_) => "BadRequestException",
+            UpdateChannelMessageError::Conflict(_) => "ConflictException",
+            UpdateChannelMessageError::Forbidden(_) => "ForbiddenException",
+            UpdateChannelMessageError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateChannelMessageError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateChannelMessageError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateChannelMessageError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateChannelReadMarker
 #[derive(Debug, PartialEq)]
 pub enum UpdateChannelReadMarkerError {
@@ -18286,6 +20801,19 @@ impl fmt::Display for UpdateChannelReadMarkerError {
     }
 }
 impl Error for UpdateChannelReadMarkerError {}
+impl ServiceErrorCode for UpdateChannelReadMarkerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateChannelReadMarkerError::BadRequest(_) => "BadRequestException",
+            UpdateChannelReadMarkerError::Conflict(_) => "ConflictException",
+            UpdateChannelReadMarkerError::Forbidden(_) => "ForbiddenException",
+            UpdateChannelReadMarkerError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateChannelReadMarkerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateChannelReadMarkerError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateChannelReadMarkerError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateGlobalSettings
 #[derive(Debug, PartialEq)]
 pub enum UpdateGlobalSettingsError {
@@ -18352,6 +20880,18 @@ impl fmt::Display for UpdateGlobalSettingsError {
     }
 }
 impl Error for UpdateGlobalSettingsError {}
+impl ServiceErrorCode for UpdateGlobalSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGlobalSettingsError::BadRequest(_) => "BadRequestException",
+            UpdateGlobalSettingsError::Forbidden(_) => "ForbiddenException",
+            UpdateGlobalSettingsError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateGlobalSettingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateGlobalSettingsError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateGlobalSettingsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdatePhoneNumber
 #[derive(Debug, PartialEq)]
 pub enum UpdatePhoneNumberError {
@@ -18422,6 +20962,19 @@ impl fmt::Display for UpdatePhoneNumberError {
     }
 }
 impl Error for UpdatePhoneNumberError {}
+impl ServiceErrorCode for UpdatePhoneNumberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePhoneNumberError::BadRequest(_) => "BadRequestException",
+            UpdatePhoneNumberError::Forbidden(_) => "ForbiddenException",
+            UpdatePhoneNumberError::NotFound(_) => "NotFoundException",
+            UpdatePhoneNumberError::ServiceFailure(_) => "ServiceFailureException",
+            UpdatePhoneNumberError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdatePhoneNumberError::ThrottledClient(_) => "ThrottledClientException",
+            UpdatePhoneNumberError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdatePhoneNumberSettings
 #[derive(Debug, PartialEq)]
 pub enum UpdatePhoneNumberSettingsError {
@@ -18492,6 +21045,18 @@ impl fmt::Display for UpdatePhoneNumberSettingsError {
     }
 }
 impl Error for UpdatePhoneNumberSettingsError {}
+impl ServiceErrorCode for UpdatePhoneNumberSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePhoneNumberSettingsError::BadRequest(_) => "BadRequestException",
+            UpdatePhoneNumberSettingsError::Forbidden(_) => "ForbiddenException",
+            UpdatePhoneNumberSettingsError::ServiceFailure(_) => "ServiceFailureException",
+            UpdatePhoneNumberSettingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdatePhoneNumberSettingsError::ThrottledClient(_) => "ThrottledClientException",
+            UpdatePhoneNumberSettingsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateProxySession
 #[derive(Debug, PartialEq)]
 pub enum UpdateProxySessionError {
@@ -18562,6 +21127,19 @@ impl fmt::Display for UpdateProxySessionError {
     }
 }
 impl Error for UpdateProxySessionError {}
+impl ServiceErrorCode for UpdateProxySessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateProxySessionError::BadRequest(_) => "BadRequestException",
+            UpdateProxySessionError::Forbidden(_) => "ForbiddenException",
+            UpdateProxySessionError::NotFound(_) => "NotFoundException",
+            UpdateProxySessionError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateProxySessionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateProxySessionError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateProxySessionError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateRoom
 #[derive(Debug, PartialEq)]
 pub enum UpdateRoomError {
@@ -18628,6 +21206,19 @@ impl fmt::Display for UpdateRoomError {
     }
 }
 impl Error for UpdateRoomError {}
+impl ServiceErrorCode for UpdateRoomError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRoomError::BadRequest(_) => "BadRequestException",
+            UpdateRoomError::Forbidden(_) => "ForbiddenException",
+            UpdateRoomError::NotFound(_) => "NotFoundException",
+            UpdateRoomError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateRoomError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateRoomError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateRoomError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateRoomMembership
 #[derive(Debug, PartialEq)]
 pub enum UpdateRoomMembershipError {
@@ -18700,6 +21291,19 @@ impl fmt::Display for UpdateRoomMembershipError {
     }
 }
 impl Error for UpdateRoomMembershipError {}
+impl ServiceErrorCode for UpdateRoomMembershipError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRoomMembershipError::BadRequest(_) => "BadRequestException",
+            UpdateRoomMembershipError::Forbidden(_) => "ForbiddenException",
+            UpdateRoomMembershipError::NotFound(_) => "NotFoundException",
+            UpdateRoomMembershipError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateRoomMembershipError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateRoomMembershipError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateRoomMembershipError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateSipMediaApplication
 #[derive(Debug, PartialEq)]
 pub enum UpdateSipMediaApplicationError {
@@ -18782,6 +21386,20 @@ impl fmt::Display for UpdateSipMediaApplicationError {
     }
 }
 impl Error for UpdateSipMediaApplicationError {}
+impl ServiceErrorCode for UpdateSipMediaApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSipMediaApplicationError::BadRequest(_) => "BadRequestException",
+            UpdateSipMediaApplicationError::Conflict(_) => "ConflictException",
+            UpdateSipMediaApplicationError::Forbidden(_) => "ForbiddenException",
+            UpdateSipMediaApplicationError::NotFound(_) => "NotFoundException",
+            UpdateSipMediaApplicationError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateSipMediaApplicationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateSipMediaApplicationError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateSipMediaApplicationError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateSipRule
 #[derive(Debug, PartialEq)]
 pub enum UpdateSipRuleError {
@@ -18860,6 +21478,21 @@ impl fmt::Display for UpdateSipRuleError {
     }
 }
 impl Error for UpdateSipRuleError {}
+impl ServiceErrorCode for UpdateSipRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSipRuleError::BadRequest(_) => "BadRequestException",
+            UpdateSipRuleError::Conflict(_) => "ConflictException",
+            UpdateSipRuleError::Forbidden(_) => "ForbiddenException",
+            UpdateSipRuleError::NotFound(_) => "NotFoundException",
+            UpdateSipRuleError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            UpdateSipRuleError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateSipRuleError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateSipRuleError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateSipRuleError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateUser
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserError {
@@ -18926,6 +21559,19 @@ impl fmt::Display for UpdateUserError {
     }
 }
 impl Error for UpdateUserError {}
+impl ServiceErrorCode for UpdateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserError::BadRequest(_) => "BadRequestException",
+            UpdateUserError::Forbidden(_) => "ForbiddenException",
+            UpdateUserError::NotFound(_) => "NotFoundException",
+            UpdateUserError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateUserError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateUserError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateUserSettings
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserSettingsError {
@@ -18996,6 +21642,19 @@ impl fmt::Display for UpdateUserSettingsError {
     }
 }
 impl Error for UpdateUserSettingsError {}
+impl ServiceErrorCode for UpdateUserSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserSettingsError::BadRequest(_) => "BadRequestException",
+            UpdateUserSettingsError::Forbidden(_) => "ForbiddenException",
+            UpdateUserSettingsError::NotFound(_) => "NotFoundException",
+            UpdateUserSettingsError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateUserSettingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateUserSettingsError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateUserSettingsError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateVoiceConnector
 #[derive(Debug, PartialEq)]
 pub enum UpdateVoiceConnectorError {
@@ -19068,6 +21727,19 @@ impl fmt::Display for UpdateVoiceConnectorError {
     }
 }
 impl Error for UpdateVoiceConnectorError {}
+impl ServiceErrorCode for UpdateVoiceConnectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateVoiceConnectorError::BadRequest(_) => "BadRequestException",
+            UpdateVoiceConnectorError::Forbidden(_) => "ForbiddenException",
+            UpdateVoiceConnectorError::NotFound(_) => "NotFoundException",
+            UpdateVoiceConnectorError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateVoiceConnectorError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateVoiceConnectorError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateVoiceConnectorError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Errors returned by UpdateVoiceConnectorGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateVoiceConnectorGroupError {
@@ -19150,6 +21822,20 @@ impl fmt::Display for UpdateVoiceConnectorGroupError {
     }
 }
 impl Error for UpdateVoiceConnectorGroupError {}
+impl ServiceErrorCode for UpdateVoiceConnectorGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateVoiceConnectorGroupError::BadRequest(_) => "BadRequestException",
+            UpdateVoiceConnectorGroupError::Conflict(_) => "ConflictException",
+            UpdateVoiceConnectorGroupError::Forbidden(_) => "ForbiddenException",
+            UpdateVoiceConnectorGroupError::NotFound(_) => "NotFoundException",
+            UpdateVoiceConnectorGroupError::ServiceFailure(_) => "ServiceFailureException",
+            UpdateVoiceConnectorGroupError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateVoiceConnectorGroupError::ThrottledClient(_) => "ThrottledClientException",
+            UpdateVoiceConnectorGroupError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Chime API. Amazon Chime clients implement this trait.
 #[async_trait]
 pub trait Chime {
@@ -20812,6 +23498,12 @@ impl Chime for ChimeClient {
         &self,
         input: CreateAppInstanceRequest,
     ) -> Result<CreateAppInstanceResponse, RusotoError<CreateAppInstanceError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = "/app-instances";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
@@ -20877,6 +23569,12 @@ impl Chime for ChimeClient {
         &self,
         input: CreateAppInstanceUserRequest,
     ) -> Result<CreateAppInstanceUserResponse, RusotoError<CreateAppInstanceUserError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = "/app-instance-users";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
@@ -20973,6 +23671,12 @@ impl Chime for ChimeClient {
         &self,
         input: CreateChannelRequest,
     ) -> Result<CreateChannelResponse, RusotoError<CreateChannelError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
@@ -21106,6 +23810,12 @@ impl Chime for ChimeClient {
         &self,
         input: CreateMeetingRequest,
     ) -> Result<CreateMeetingResponse, RusotoError<CreateMeetingError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = "/meetings";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
@@ -21172,6 +23882,12 @@ impl Chime for ChimeClient {
         input: CreateMeetingWithAttendeesRequest,
     ) -> Result<CreateMeetingWithAttendeesResponse, RusotoError<CreateMeetingWithAttendeesError>>
     {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = "/meetings";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
@@ -21272,6 +23988,10 @@ impl Chime for ChimeClient {
         &self,
         input: CreateRoomRequest,
     ) -> Result<CreateRoomResponse, RusotoError<CreateRoomError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/accounts/{account_id}/rooms",
             account_id = input.account_id
@@ -25699,6 +28419,12 @@ impl Chime for ChimeClient {
         &self,
         input: SendChannelMessageRequest,
     ) -> Result<SendChannelMessageResponse, RusotoError<SendChannelMessageError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = format!(
             "/channels/{channel_arn}/messages",
             channel_arn = input.channel_arn
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -503,6 +503,19 @@ impl fmt::Display for CreateEnvironmentEC2Error {
     }
 }
 impl Error for CreateEnvironmentEC2Error {}
+impl ServiceErrorCode for CreateEnvironmentEC2Error {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEnvironmentEC2Error::BadRequest(_) => "BadRequestException",
+            CreateEnvironmentEC2Error::Conflict(_) => "ConflictException",
+            CreateEnvironmentEC2Error::Forbidden(_) => "ForbiddenException",
+            CreateEnvironmentEC2Error::InternalServerError(_) => "InternalServerErrorException",
+            CreateEnvironmentEC2Error::LimitExceeded(_) => "LimitExceededException",
+            CreateEnvironmentEC2Error::NotFound(_) => "NotFoundException",
+            CreateEnvironmentEC2Error::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateEnvironmentMembership
 #[derive(Debug, PartialEq)]
 pub enum CreateEnvironmentMembershipError {
@@ -587,6 +600,21 @@ impl fmt::Display for CreateEnvironmentMembershipError {
     }
 }
 impl Error for CreateEnvironmentMembershipError {}
+impl ServiceErrorCode for CreateEnvironmentMembershipError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEnvironmentMembershipError::BadRequest(_) => "BadRequestException",
+            CreateEnvironmentMembershipError::Conflict(_) => "ConflictException",
+            CreateEnvironmentMembershipError::Forbidden(_) => "ForbiddenException",
+            CreateEnvironmentMembershipError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            CreateEnvironmentMembershipError::LimitExceeded(_) => "LimitExceededException",
+            CreateEnvironmentMembershipError::NotFound(_) => "NotFoundException",
+            CreateEnvironmentMembershipError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteEnvironment
 #[derive(Debug, PartialEq)]
 pub enum DeleteEnvironmentError {
@@ -655,6 +683,19 @@ impl fmt::Display for DeleteEnvironmentError {
     }
 }
 impl Error for DeleteEnvironmentError {}
+impl ServiceErrorCode for DeleteEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEnvironmentError::BadRequest(_) => "BadRequestException",
+            DeleteEnvironmentError::Conflict(_) => "ConflictException",
+            DeleteEnvironmentError::Forbidden(_) => "ForbiddenException",
+            DeleteEnvironmentError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteEnvironmentError::LimitExceeded(_) => "LimitExceededException",
+            DeleteEnvironmentError::NotFound(_) => "NotFoundException",
+            DeleteEnvironmentError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteEnvironmentMembership
 #[derive(Debug, PartialEq)]
 pub enum DeleteEnvironmentMembershipError {
@@ -739,6 +780,21 @@ impl fmt::Display for DeleteEnvironmentMembershipError {
     }
 }
 impl Error for DeleteEnvironmentMembershipError {}
+impl ServiceErrorCode for DeleteEnvironmentMembershipError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEnvironmentMembershipError::BadRequest(_) => "BadRequestException",
+            DeleteEnvironmentMembershipError::Conflict(_) => "ConflictException",
+            DeleteEnvironmentMembershipError::Forbidden(_) => "ForbiddenException",
+            DeleteEnvironmentMembershipError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DeleteEnvironmentMembershipError::LimitExceeded(_) => "LimitExceededException",
+            DeleteEnvironmentMembershipError::NotFound(_) => "NotFoundException",
+            DeleteEnvironmentMembershipError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeEnvironmentMemberships
 #[derive(Debug, PartialEq)]
 pub enum DescribeEnvironmentMembershipsError {
@@ -825,6 +881,21 @@ impl fmt::Display for DescribeEnvironmentMembershipsError {
     }
 }
 impl Error for DescribeEnvironmentMembershipsError {}
+impl ServiceErrorCode for DescribeEnvironmentMembershipsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEnvironmentMembershipsError::BadRequest(_) => "BadRequestException",
+            DescribeEnvironmentMembershipsError::Conflict(_) => "ConflictException",
+            DescribeEnvironmentMembershipsError::Forbidden(_) => "ForbiddenException",
+            DescribeEnvironmentMembershipsError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DescribeEnvironmentMembershipsError::LimitExceeded(_) => "LimitExceededException",
+            DescribeEnvironmentMembershipsError::NotFound(_) => "NotFoundException",
+            DescribeEnvironmentMembershipsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeEnvironmentStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeEnvironmentStatusError {
@@ -901,6 +972,21 @@ impl fmt::Display for DescribeEnvironmentStatusError {
     }
 }
 impl Error for DescribeEnvironmentStatusError {}
+impl ServiceErrorCode for DescribeEnvironmentStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEnvironmentStatusError::BadRequest(_) => "BadRequestException",
+            DescribeEnvironmentStatusError::Conflict(_) => "ConflictException",
+            DescribeEnvironmentStatusError::Forbidden(_) => "ForbiddenException",
+            DescribeEnvironmentStatusError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DescribeEnvironmentStatusError::LimitExceeded(_) => "LimitExceededException",
+            DescribeEnvironmentStatusError::NotFound(_) => "NotFoundException",
+            DescribeEnvironmentStatusError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeEnvironments
 #[derive(Debug, PartialEq)]
 pub enum DescribeEnvironmentsError {
@@ -971,6 +1057,19 @@ impl fmt::Display for DescribeEnvironmentsError {
     }
 }
 impl Error for DescribeEnvironmentsError {}
+impl ServiceErrorCode for DescribeEnvironmentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEnvironmentsError::BadRequest(_) => "BadRequestException",
+            DescribeEnvironmentsError::Conflict(_) => "ConflictException",
+            DescribeEnvironmentsError::Forbidden(_) => "ForbiddenException",
+            DescribeEnvironmentsError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeEnvironmentsError::LimitExceeded(_) => "LimitExceededException",
+            DescribeEnvironmentsError::NotFound(_) => "NotFoundException",
+            DescribeEnvironmentsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListEnvironments
 #[derive(Debug, PartialEq)]
 pub enum ListEnvironmentsError {
@@ -1039,6 +1138,19 @@ impl fmt::Display for ListEnvironmentsError {
     }
 }
 impl Error for ListEnvironmentsError {}
+impl ServiceErrorCode for ListEnvironmentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEnvironmentsError::BadRequest(_) => "BadRequestException",
+            ListEnvironmentsError::Conflict(_) => "ConflictException",
+            ListEnvironmentsError::Forbidden(_) => "ForbiddenException",
+            ListEnvironmentsError::InternalServerError(_) => "InternalServerErrorException",
+            ListEnvironmentsError::LimitExceeded(_) => "LimitExceededException",
+            ListEnvironmentsError::NotFound(_) => "NotFoundException",
+            ListEnvironmentsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1083,6 +1195,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::InternalServerError(_) => "InternalServerErrorException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1131,6 +1252,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::ConcurrentAccess(_) => "ConcurrentAccessException",
+            TagResourceError::InternalServerError(_) => "InternalServerErrorException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1179,6 +1310,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::ConcurrentAccess(_) => "ConcurrentAccessException",
+            UntagResourceError::InternalServerError(_) => "InternalServerErrorException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateEnvironment
 #[derive(Debug, PartialEq)]
 pub enum UpdateEnvironmentError {
@@ -1247,6 +1388,19 @@ impl fmt::Display for UpdateEnvironmentError {
     }
 }
 impl Error for UpdateEnvironmentError {}
+impl ServiceErrorCode for UpdateEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateEnvironmentError::BadRequest(_) => "BadRequestException",
+            UpdateEnvironmentError::Conflict(_) => "ConflictException",
+            UpdateEnvironmentError::Forbidden(_) => "ForbiddenException",
+            UpdateEnvironmentError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateEnvironmentError::LimitExceeded(_) => "LimitExceededException",
+            UpdateEnvironmentError::NotFound(_) => "NotFoundException",
+            UpdateEnvironmentError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateEnvironmentMembership
 #[derive(Debug, PartialEq)]
 pub enum UpdateEnvironmentMembershipError {
@@ -1331,6 +1485,21 @@ impl fmt::Display for UpdateEnvironmentMembershipError {
     }
 }
 impl Error for UpdateEnvironmentMembershipError {}
+impl ServiceErrorCode for UpdateEnvironmentMembershipError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateEnvironmentMembershipError::BadRequest(_) => "BadRequestException",
+            UpdateEnvironmentMembershipError::Conflict(_) => "ConflictException",
+            UpdateEnvironmentMembershipError::Forbidden(_) => "ForbiddenException",
+            UpdateEnvironmentMembershipError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            UpdateEnvironmentMembershipError::LimitExceeded(_) => "LimitExceededException",
+            UpdateEnvironmentMembershipError::NotFound(_) => "NotFoundException",
+            UpdateEnvironmentMembershipError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Cloud9 API. AWS Cloud9 clients implement this trait.
 #[async_trait]
 pub trait Cloud9 {
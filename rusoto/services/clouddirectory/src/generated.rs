@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -3289,6 +3289,20 @@ impl fmt::Display for AddFacetToObjectError {
     }
 }
 impl Error for AddFacetToObjectError {}
+impl ServiceErrorCode for AddFacetToObjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddFacetToObjectError::AccessDenied(_) => "AccessDeniedException",
+            AddFacetToObjectError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            AddFacetToObjectError::FacetValidation(_) => "FacetValidationException",
+            AddFacetToObjectError::InternalService(_) => "InternalServiceException",
+            AddFacetToObjectError::InvalidArn(_) => "InvalidArnException",
+            AddFacetToObjectError::LimitExceeded(_) => "LimitExceededException",
+            AddFacetToObjectError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AddFacetToObjectError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ApplySchema
 #[derive(Debug, PartialEq)]
 pub enum ApplySchemaError {
@@ -3361,6 +3375,20 @@ impl fmt::Display for ApplySchemaError {
     }
 }
 impl Error for ApplySchemaError {}
+impl ServiceErrorCode for ApplySchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ApplySchemaError::AccessDenied(_) => "AccessDeniedException",
+            ApplySchemaError::InternalService(_) => "InternalServiceException",
+            ApplySchemaError::InvalidArn(_) => "InvalidArnException",
+            ApplySchemaError::InvalidAttachment(_) => "InvalidAttachmentException",
+            ApplySchemaError::LimitExceeded(_) => "LimitExceededException",
+            ApplySchemaError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ApplySchemaError::RetryableConflict(_) => "RetryableConflictException",
+            ApplySchemaError::SchemaAlreadyExists(_) => "SchemaAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by AttachObject
 #[derive(Debug, PartialEq)]
 pub enum AttachObjectError {
@@ -3445,6 +3473,22 @@ impl fmt::Display for AttachObjectError {
     }
 }
 impl Error for AttachObjectError {}
+impl ServiceErrorCode for AttachObjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachObjectError::AccessDenied(_) => "AccessDeniedException",
+            AttachObjectError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            AttachObjectError::FacetValidation(_) => "FacetValidationException",
+            AttachObjectError::InternalService(_) => "InternalServiceException",
+            AttachObjectError::InvalidArn(_) => "InvalidArnException",
+            AttachObjectError::InvalidAttachment(_) => "InvalidAttachmentException",
+            AttachObjectError::LimitExceeded(_) => "LimitExceededException",
+            AttachObjectError::LinkNameAlreadyInUse(_) => "LinkNameAlreadyInUseException",
+            AttachObjectError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AttachObjectError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by AttachPolicy
 #[derive(Debug, PartialEq)]
 pub enum AttachPolicyError {
@@ -3517,6 +3561,20 @@ impl fmt::Display for AttachPolicyError {
     }
 }
 impl Error for AttachPolicyError {}
+impl ServiceErrorCode for AttachPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachPolicyError::AccessDenied(_) => "AccessDeniedException",
+            AttachPolicyError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            AttachPolicyError::InternalService(_) => "InternalServiceException",
+            AttachPolicyError::InvalidArn(_) => "InvalidArnException",
+            AttachPolicyError::LimitExceeded(_) => "LimitExceededException",
+            AttachPolicyError::NotPolicy(_) => "NotPolicyException",
+            AttachPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AttachPolicyError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by AttachToIndex
 #[derive(Debug, PartialEq)]
 pub enum AttachToIndexError {
@@ -3609,6 +3667,23 @@ impl fmt::Display for AttachToIndexError {
     }
 }
 impl Error for AttachToIndexError {}
+impl ServiceErrorCode for AttachToIndexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachToIndexError::AccessDenied(_) => "AccessDeniedException",
+            AttachToIndexError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            AttachToIndexError::IndexedAttributeMissing(_) => "IndexedAttributeMissingException",
+            AttachToIndexError::InternalService(_) => "InternalServiceException",
+            AttachToIndexError::InvalidArn(_) => "InvalidArnException",
+            AttachToIndexError::InvalidAttachment(_) => "InvalidAttachmentException",
+            AttachToIndexError::LimitExceeded(_) => "LimitExceededException",
+            AttachToIndexError::LinkNameAlreadyInUse(_) => "LinkNameAlreadyInUseException",
+            AttachToIndexError::NotIndex(_) => "NotIndexException",
+            AttachToIndexError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AttachToIndexError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by AttachTypedLink
 #[derive(Debug, PartialEq)]
 pub enum AttachTypedLinkError {
@@ -3687,6 +3762,21 @@ impl fmt::Display for AttachTypedLinkError {
     }
 }
 impl Error for AttachTypedLinkError {}
+impl ServiceErrorCode for AttachTypedLinkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachTypedLinkError::AccessDenied(_) => "AccessDeniedException",
+            AttachTypedLinkError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            AttachTypedLinkError::FacetValidation(_) => "FacetValidationException",
+            AttachTypedLinkError::InternalService(_) => "InternalServiceException",
+            AttachTypedLinkError::InvalidArn(_) => "InvalidArnException",
+            AttachTypedLinkError::InvalidAttachment(_) => "InvalidAttachmentException",
+            AttachTypedLinkError::LimitExceeded(_) => "LimitExceededException",
+            AttachTypedLinkError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AttachTypedLinkError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by BatchRead
 #[derive(Debug, PartialEq)]
 pub enum BatchReadError {
@@ -3747,6 +3837,18 @@ impl fmt::Display for BatchReadError {
     }
 }
 impl Error for BatchReadError {}
+impl ServiceErrorCode for BatchReadError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchReadError::AccessDenied(_) => "AccessDeniedException",
+            BatchReadError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            BatchReadError::InternalService(_) => "InternalServiceException",
+            BatchReadError::InvalidArn(_) => "InvalidArnException",
+            BatchReadError::LimitExceeded(_) => "LimitExceededException",
+            BatchReadError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by BatchWrite
 #[derive(Debug, PartialEq)]
 pub enum BatchWriteError {
@@ -3813,6 +3915,19 @@ impl fmt::Display for BatchWriteError {
     }
 }
 impl Error for BatchWriteError {}
+impl ServiceErrorCode for BatchWriteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchWriteError::AccessDenied(_) => "AccessDeniedException",
+            BatchWriteError::BatchWrite(_) => "BatchWriteException",
+            BatchWriteError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            BatchWriteError::InternalService(_) => "InternalServiceException",
+            BatchWriteError::InvalidArn(_) => "InvalidArnException",
+            BatchWriteError::LimitExceeded(_) => "LimitExceededException",
+            BatchWriteError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by CreateDirectory
 #[derive(Debug, PartialEq)]
 pub enum CreateDirectoryError {
@@ -3881,6 +3996,19 @@ impl fmt::Display for CreateDirectoryError {
     }
 }
 impl Error for CreateDirectoryError {}
+impl ServiceErrorCode for CreateDirectoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDirectoryError::AccessDenied(_) => "AccessDeniedException",
+            CreateDirectoryError::DirectoryAlreadyExists(_) => "DirectoryAlreadyExistsException",
+            CreateDirectoryError::InternalService(_) => "InternalServiceException",
+            CreateDirectoryError::InvalidArn(_) => "InvalidArnException",
+            CreateDirectoryError::LimitExceeded(_) => "LimitExceededException",
+            CreateDirectoryError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateDirectoryError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by CreateFacet
 #[derive(Debug, PartialEq)]
 pub enum CreateFacetError {
@@ -3959,6 +4087,21 @@ impl fmt::Display for CreateFacetError {
     }
 }
 impl Error for CreateFacetError {}
+impl ServiceErrorCode for CreateFacetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFacetError::AccessDenied(_) => "AccessDeniedException",
+            CreateFacetError::FacetAlreadyExists(_) => "FacetAlreadyExistsException",
+            CreateFacetError::FacetValidation(_) => "FacetValidationException",
+            CreateFacetError::InternalService(_) => "InternalServiceException",
+            CreateFacetError::InvalidArn(_) => "InvalidArnException",
+            CreateFacetError::InvalidRule(_) => "InvalidRuleException",
+            CreateFacetError::LimitExceeded(_) => "LimitExceededException",
+            CreateFacetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateFacetError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by CreateIndex
 #[derive(Debug, PartialEq)]
 pub enum CreateIndexError {
@@ -4043,6 +4186,22 @@ impl fmt::Display for CreateIndexError {
     }
 }
 impl Error for CreateIndexError {}
+impl ServiceErrorCode for CreateIndexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateIndexError::AccessDenied(_) => "AccessDeniedException",
+            CreateIndexError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            CreateIndexError::FacetValidation(_) => "FacetValidationException",
+            CreateIndexError::InternalService(_) => "InternalServiceException",
+            CreateIndexError::InvalidArn(_) => "InvalidArnException",
+            CreateIndexError::LimitExceeded(_) => "LimitExceededException",
+            CreateIndexError::LinkNameAlreadyInUse(_) => "LinkNameAlreadyInUseException",
+            CreateIndexError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateIndexError::RetryableConflict(_) => "RetryableConflictException",
+            CreateIndexError::UnsupportedIndexType(_) => "UnsupportedIndexTypeException",
+        }
+    }
+}
 /// Errors returned by CreateObject
 #[derive(Debug, PartialEq)]
 pub enum CreateObjectError {
@@ -4127,6 +4286,22 @@ impl fmt::Display for CreateObjectError {
     }
 }
 impl Error for CreateObjectError {}
+impl ServiceErrorCode for CreateObjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateObjectError::AccessDenied(_) => "AccessDeniedException",
+            CreateObjectError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            CreateObjectError::FacetValidation(_) => "FacetValidationException",
+            CreateObjectError::InternalService(_) => "InternalServiceException",
+            CreateObjectError::InvalidArn(_) => "InvalidArnException",
+            CreateObjectError::LimitExceeded(_) => "LimitExceededException",
+            CreateObjectError::LinkNameAlreadyInUse(_) => "LinkNameAlreadyInUseException",
+            CreateObjectError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateObjectError::RetryableConflict(_) => "RetryableConflictException",
+            CreateObjectError::UnsupportedIndexType(_) => "UnsupportedIndexTypeException",
+        }
+    }
+}
 /// Errors returned by CreateSchema
 #[derive(Debug, PartialEq)]
 pub enum CreateSchemaError {
@@ -4187,6 +4362,18 @@ impl fmt::Display for CreateSchemaError {
     }
 }
 impl Error for CreateSchemaError {}
+impl ServiceErrorCode for CreateSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSchemaError::AccessDenied(_) => "AccessDeniedException",
+            CreateSchemaError::InternalService(_) => "InternalServiceException",
+            CreateSchemaError::InvalidArn(_) => "InvalidArnException",
+            CreateSchemaError::LimitExceeded(_) => "LimitExceededException",
+            CreateSchemaError::RetryableConflict(_) => "RetryableConflictException",
+            CreateSchemaError::SchemaAlreadyExists(_) => "SchemaAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by CreateTypedLinkFacet
 #[derive(Debug, PartialEq)]
 pub enum CreateTypedLinkFacetError {
@@ -4275,6 +4462,21 @@ impl fmt::Display for CreateTypedLinkFacetError {
     }
 }
 impl Error for CreateTypedLinkFacetError {}
+impl ServiceErrorCode for CreateTypedLinkFacetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTypedLinkFacetError::AccessDenied(_) => "AccessDeniedException",
+            CreateTypedLinkFacetError::FacetAlreadyExists(_) => "FacetAlreadyExistsException",
+            CreateTypedLinkFacetError::FacetValidation(_) => "FacetValidationException",
+            CreateTypedLinkFacetError::InternalService(_) => "InternalServiceException",
+            CreateTypedLinkFacetError::InvalidArn(_) => "InvalidArnException",
+            CreateTypedLinkFacetError::InvalidRule(_) => "InvalidRuleException",
+            CreateTypedLinkFacetError::LimitExceeded(_) => "LimitExceededException",
+            CreateTypedLinkFacetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateTypedLinkFacetError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by DeleteDirectory
 #[derive(Debug, PartialEq)]
 pub enum DeleteDirectoryError {
@@ -4349,6 +4551,20 @@ impl fmt::Display for DeleteDirectoryError {
     }
 }
 impl Error for DeleteDirectoryError {}
+impl ServiceErrorCode for DeleteDirectoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDirectoryError::AccessDenied(_) => "AccessDeniedException",
+            DeleteDirectoryError::DirectoryDeleted(_) => "DirectoryDeletedException",
+            DeleteDirectoryError::DirectoryNotDisabled(_) => "DirectoryNotDisabledException",
+            DeleteDirectoryError::InternalService(_) => "InternalServiceException",
+            DeleteDirectoryError::InvalidArn(_) => "InvalidArnException",
+            DeleteDirectoryError::LimitExceeded(_) => "LimitExceededException",
+            DeleteDirectoryError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDirectoryError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by DeleteFacet
 #[derive(Debug, PartialEq)]
 pub enum DeleteFacetError {
@@ -4421,6 +4637,20 @@ impl fmt::Display for DeleteFacetError {
     }
 }
 impl Error for DeleteFacetError {}
+impl ServiceErrorCode for DeleteFacetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFacetError::AccessDenied(_) => "AccessDeniedException",
+            DeleteFacetError::FacetInUse(_) => "FacetInUseException",
+            DeleteFacetError::FacetNotFound(_) => "FacetNotFoundException",
+            DeleteFacetError::InternalService(_) => "InternalServiceException",
+            DeleteFacetError::InvalidArn(_) => "InvalidArnException",
+            DeleteFacetError::LimitExceeded(_) => "LimitExceededException",
+            DeleteFacetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteFacetError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by DeleteObject
 #[derive(Debug, PartialEq)]
 pub enum DeleteObjectError {
@@ -4493,6 +4723,20 @@ impl fmt::Display for DeleteObjectError {
     }
 }
 impl Error for DeleteObjectError {}
+impl ServiceErrorCode for DeleteObjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteObjectError::AccessDenied(_) => "AccessDeniedException",
+            DeleteObjectError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            DeleteObjectError::InternalService(_) => "InternalServiceException",
+            DeleteObjectError::InvalidArn(_) => "InvalidArnException",
+            DeleteObjectError::LimitExceeded(_) => "LimitExceededException",
+            DeleteObjectError::ObjectNotDetached(_) => "ObjectNotDetachedException",
+            DeleteObjectError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteObjectError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by DeleteSchema
 #[derive(Debug, PartialEq)]
 pub enum DeleteSchemaError {
@@ -4559,6 +4803,19 @@ impl fmt::Display for DeleteSchemaError {
     }
 }
 impl Error for DeleteSchemaError {}
+impl ServiceErrorCode for DeleteSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSchemaError::AccessDenied(_) => "AccessDeniedException",
+            DeleteSchemaError::InternalService(_) => "InternalServiceException",
+            DeleteSchemaError::InvalidArn(_) => "InvalidArnException",
+            DeleteSchemaError::LimitExceeded(_) => "LimitExceededException",
+            DeleteSchemaError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteSchemaError::RetryableConflict(_) => "RetryableConflictException",
+            DeleteSchemaError::StillContainsLinks(_) => "StillContainsLinksException",
+        }
+    }
+}
 /// Errors returned by DeleteTypedLinkFacet
 #[derive(Debug, PartialEq)]
 pub enum DeleteTypedLinkFacetError {
@@ -4631,6 +4888,19 @@ impl fmt::Display for DeleteTypedLinkFacetError {
     }
 }
 impl Error for DeleteTypedLinkFacetError {}
+impl ServiceErrorCode for DeleteTypedLinkFacetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTypedLinkFacetError::AccessDenied(_) => "AccessDeniedException",
+            DeleteTypedLinkFacetError::FacetNotFound(_) => "FacetNotFoundException",
+            DeleteTypedLinkFacetError::InternalService(_) => "InternalServiceException",
+            DeleteTypedLinkFacetError::InvalidArn(_) => "InvalidArnException",
+            DeleteTypedLinkFacetError::LimitExceeded(_) => "LimitExceededException",
+            DeleteTypedLinkFacetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteTypedLinkFacetError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by DetachFromIndex
 #[derive(Debug, PartialEq)]
 pub enum DetachFromIndexError {
@@ -4711,6 +4981,21 @@ impl fmt::Display for DetachFromIndexError {
     }
 }
 impl Error for DetachFromIndexError {}
+impl ServiceErrorCode for DetachFromIndexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachFromIndexError::AccessDenied(_) => "AccessDeniedException",
+            DetachFromIndexError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            DetachFromIndexError::InternalService(_) => "InternalServiceException",
+            DetachFromIndexError::InvalidArn(_) => "InvalidArnException",
+            DetachFromIndexError::LimitExceeded(_) => "LimitExceededException",
+            DetachFromIndexError::NotIndex(_) => "NotIndexException",
+            DetachFromIndexError::ObjectAlreadyDetached(_) => "ObjectAlreadyDetachedException",
+            DetachFromIndexError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DetachFromIndexError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by DetachObject
 #[derive(Debug, PartialEq)]
 pub enum DetachObjectError {
@@ -4783,6 +5068,20 @@ impl fmt::Display for DetachObjectError {
     }
 }
 impl Error for DetachObjectError {}
+impl ServiceErrorCode for DetachObjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachObjectError::AccessDenied(_) => "AccessDeniedException",
+            DetachObjectError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            DetachObjectError::InternalService(_) => "InternalServiceException",
+            DetachObjectError::InvalidArn(_) => "InvalidArnException",
+            DetachObjectError::LimitExceeded(_) => "LimitExceededException",
+            DetachObjectError::NotNode(_) => "NotNodeException",
+            DetachObjectError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DetachObjectError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by DetachPolicy
 #[derive(Debug, PartialEq)]
 pub enum DetachPolicyError {
@@ -4855,6 +5154,20 @@ impl fmt::Display for DetachPolicyError {
     }
 }
 impl Error for DetachPolicyError {}
+impl ServiceErrorCode for DetachPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachPolicyError::AccessDenied(_) => "AccessDeniedException",
+            DetachPolicyError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            DetachPolicyError::InternalService(_) => "InternalServiceException",
+            DetachPolicyError::InvalidArn(_) => "InvalidArnException",
+            DetachPolicyError::LimitExceeded(_) => "LimitExceededException",
+            DetachPolicyError::NotPolicy(_) => "NotPolicyException",
+            DetachPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DetachPolicyError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by DetachTypedLink
 #[derive(Debug, PartialEq)]
 pub enum DetachTypedLinkError {
@@ -4927,6 +5240,20 @@ impl fmt::Display for DetachTypedLinkError {
     }
 }
 impl Error for DetachTypedLinkError {}
+impl ServiceErrorCode for DetachTypedLinkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachTypedLinkError::AccessDenied(_) => "AccessDeniedException",
+            DetachTypedLinkError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            DetachTypedLinkError::FacetValidation(_) => "FacetValidationException",
+            DetachTypedLinkError::InternalService(_) => "InternalServiceException",
+            DetachTypedLinkError::InvalidArn(_) => "InvalidArnException",
+            DetachTypedLinkError::LimitExceeded(_) => "LimitExceededException",
+            DetachTypedLinkError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DetachTypedLinkError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by DisableDirectory
 #[derive(Debug, PartialEq)]
 pub enum DisableDirectoryError {
@@ -4993,6 +5320,19 @@ impl fmt::Display for DisableDirectoryError {
     }
 }
 impl Error for DisableDirectoryError {}
+impl ServiceErrorCode for DisableDirectoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableDirectoryError::AccessDenied(_) => "AccessDeniedException",
+            DisableDirectoryError::DirectoryDeleted(_) => "DirectoryDeletedException",
+            DisableDirectoryError::InternalService(_) => "InternalServiceException",
+            DisableDirectoryError::InvalidArn(_) => "InvalidArnException",
+            DisableDirectoryError::LimitExceeded(_) => "LimitExceededException",
+            DisableDirectoryError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DisableDirectoryError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by EnableDirectory
 #[derive(Debug, PartialEq)]
 pub enum EnableDirectoryError {
@@ -5059,6 +5399,19 @@ impl fmt::Display for EnableDirectoryError {
     }
 }
 impl Error for EnableDirectoryError {}
+impl ServiceErrorCode for EnableDirectoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableDirectoryError::AccessDenied(_) => "AccessDeniedException",
+            EnableDirectoryError::DirectoryDeleted(_) => "DirectoryDeletedException",
+            EnableDirectoryError::InternalService(_) => "InternalServiceException",
+            EnableDirectoryError::InvalidArn(_) => "InvalidArnException",
+            EnableDirectoryError::LimitExceeded(_) => "LimitExceededException",
+            EnableDirectoryError::ResourceNotFound(_) => "ResourceNotFoundException",
+            EnableDirectoryError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by GetAppliedSchemaVersion
 #[derive(Debug, PartialEq)]
 pub enum GetAppliedSchemaVersionError {
@@ -5129,6 +5482,18 @@ impl fmt::Display for GetAppliedSchemaVersionError {
     }
 }
 impl Error for GetAppliedSchemaVersionError {}
+impl ServiceErrorCode for GetAppliedSchemaVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAppliedSchemaVersionError::AccessDenied(_) => "AccessDeniedException",
+            GetAppliedSchemaVersionError::InternalService(_) => "InternalServiceException",
+            GetAppliedSchemaVersionError::InvalidArn(_) => "InvalidArnException",
+            GetAppliedSchemaVersionError::LimitExceeded(_) => "LimitExceededException",
+            GetAppliedSchemaVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetAppliedSchemaVersionError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by GetDirectory
 #[derive(Debug, PartialEq)]
 pub enum GetDirectoryError {
@@ -5183,6 +5548,17 @@ impl fmt::Display for GetDirectoryError {
     }
 }
 impl Error for GetDirectoryError {}
+impl ServiceErrorCode for GetDirectoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDirectoryError::AccessDenied(_) => "AccessDeniedException",
+            GetDirectoryError::InternalService(_) => "InternalServiceException",
+            GetDirectoryError::InvalidArn(_) => "InvalidArnException",
+            GetDirectoryError::LimitExceeded(_) => "LimitExceededException",
+            GetDirectoryError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by GetFacet
 #[derive(Debug, PartialEq)]
 pub enum GetFacetError {
@@ -5249,6 +5625,19 @@ impl fmt::Display for GetFacetError {
     }
 }
 impl Error for GetFacetError {}
+impl ServiceErrorCode for GetFacetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFacetError::AccessDenied(_) => "AccessDeniedException",
+            GetFacetError::FacetNotFound(_) => "FacetNotFoundException",
+            GetFacetError::InternalService(_) => "InternalServiceException",
+            GetFacetError::InvalidArn(_) => "InvalidArnException",
+            GetFacetError::LimitExceeded(_) => "LimitExceededException",
+            GetFacetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetFacetError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by GetLinkAttributes
 #[derive(Debug, PartialEq)]
 pub enum GetLinkAttributesError {
@@ -5323,6 +5712,20 @@ impl fmt::Display for GetLinkAttributesError {
     }
 }
 impl Error for GetLinkAttributesError {}
+impl ServiceErrorCode for GetLinkAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLinkAttributesError::AccessDenied(_) => "AccessDeniedException",
+            GetLinkAttributesError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            GetLinkAttributesError::FacetValidation(_) => "FacetValidationException",
+            GetLinkAttributesError::InternalService(_) => "InternalServiceException",
+            GetLinkAttributesError::InvalidArn(_) => "InvalidArnException",
+            GetLinkAttributesError::LimitExceeded(_) => "LimitExceededException",
+            GetLinkAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetLinkAttributesError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by GetObjectAttributes
 #[derive(Debug, PartialEq)]
 pub enum GetObjectAttributesError {
@@ -5401,6 +5804,20 @@ impl fmt::Display for GetObjectAttributesError {
     }
 }
 impl Error for GetObjectAttributesError {}
+impl ServiceErrorCode for GetObjectAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetObjectAttributesError::AccessDenied(_) => "AccessDeniedException",
+            GetObjectAttributesError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            GetObjectAttributesError::FacetValidation(_) => "FacetValidationException",
+            GetObjectAttributesError::InternalService(_) => "InternalServiceException",
+            GetObjectAttributesError::InvalidArn(_) => "InvalidArnException",
+            GetObjectAttributesError::LimitExceeded(_) => "LimitExceededException",
+            GetObjectAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetObjectAttributesError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by GetObjectInformation
 #[derive(Debug, PartialEq)]
 pub enum GetObjectInformationError {
@@ -5475,6 +5892,19 @@ impl fmt::Display for GetObjectInformationError {
     }
 }
 impl Error for GetObjectInformationError {}
+impl ServiceErrorCode for GetObjectInformationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetObjectInformationError::AccessDenied(_) => "AccessDeniedException",
+            GetObjectInformationError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            GetObjectInformationError::InternalService(_) => "InternalServiceException",
+            GetObjectInformationError::InvalidArn(_) => "InvalidArnException",
+            GetObjectInformationError::LimitExceeded(_) => "LimitExceededException",
+            GetObjectInformationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetObjectInformationError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by GetSchemaAsJson
 #[derive(Debug, PartialEq)]
 pub enum GetSchemaAsJsonError {
@@ -5535,6 +5965,18 @@ impl fmt::Display for GetSchemaAsJsonError {
     }
 }
 impl Error for GetSchemaAsJsonError {}
+impl ServiceErrorCode for GetSchemaAsJsonError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSchemaAsJsonError::AccessDenied(_) => "AccessDeniedException",
+            GetSchemaAsJsonError::InternalService(_) => "InternalServiceException",
+            GetSchemaAsJsonError::InvalidArn(_) => "InvalidArnException",
+            GetSchemaAsJsonError::LimitExceeded(_) => "LimitExceededException",
+            GetSchemaAsJsonError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetSchemaAsJsonError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by GetTypedLinkFacetInformation
 #[derive(Debug, PartialEq)]
 pub enum GetTypedLinkFacetInformationError {
@@ -5631,6 +6073,20 @@ impl fmt::Display for GetTypedLinkFacetInformationError {
     }
 }
 impl Error for GetTypedLinkFacetInformationError {}
+impl ServiceErrorCode for GetTypedLinkFacetInformationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTypedLinkFacetInformationError::AccessDenied(_) => "AccessDeniedException",
+            GetTypedLinkFacetInformationError::FacetNotFound(_) => "FacetNotFoundException",
+            GetTypedLinkFacetInformationError::InternalService(_) => "InternalServiceException",
+            GetTypedLinkFacetInformationError::InvalidArn(_) => "InvalidArnException",
+            GetTypedLinkFacetInformationError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetTypedLinkFacetInformationError::LimitExceeded(_) => "LimitExceededException",
+            GetTypedLinkFacetInformationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetTypedLinkFacetInformationError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListAppliedSchemaArns
 #[derive(Debug, PartialEq)]
 pub enum ListAppliedSchemaArnsError {
@@ -5705,6 +6161,19 @@ impl fmt::Display for ListAppliedSchemaArnsError {
     }
 }
 impl Error for ListAppliedSchemaArnsError {}
+impl ServiceErrorCode for ListAppliedSchemaArnsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAppliedSchemaArnsError::AccessDenied(_) => "AccessDeniedException",
+            ListAppliedSchemaArnsError::InternalService(_) => "InternalServiceException",
+            ListAppliedSchemaArnsError::InvalidArn(_) => "InvalidArnException",
+            ListAppliedSchemaArnsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListAppliedSchemaArnsError::LimitExceeded(_) => "LimitExceededException",
+            ListAppliedSchemaArnsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListAppliedSchemaArnsError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListAttachedIndices
 #[derive(Debug, PartialEq)]
 pub enum ListAttachedIndicesError {
@@ -5777,6 +6246,19 @@ impl fmt::Display for ListAttachedIndicesError {
     }
 }
 impl Error for ListAttachedIndicesError {}
+impl ServiceErrorCode for ListAttachedIndicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAttachedIndicesError::AccessDenied(_) => "AccessDeniedException",
+            ListAttachedIndicesError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            ListAttachedIndicesError::InternalService(_) => "InternalServiceException",
+            ListAttachedIndicesError::InvalidArn(_) => "InvalidArnException",
+            ListAttachedIndicesError::LimitExceeded(_) => "LimitExceededException",
+            ListAttachedIndicesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListAttachedIndicesError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListDevelopmentSchemaArns
 #[derive(Debug, PartialEq)]
 pub enum ListDevelopmentSchemaArnsError {
@@ -5857,6 +6339,19 @@ impl fmt::Display for ListDevelopmentSchemaArnsError {
     }
 }
 impl Error for ListDevelopmentSchemaArnsError {}
+impl ServiceErrorCode for ListDevelopmentSchemaArnsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDevelopmentSchemaArnsError::AccessDenied(_) => "AccessDeniedException",
+            ListDevelopmentSchemaArnsError::InternalService(_) => "InternalServiceException",
+            ListDevelopmentSchemaArnsError::InvalidArn(_) => "InvalidArnException",
+            ListDevelopmentSchemaArnsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListDevelopmentSchemaArnsError::LimitExceeded(_) => "LimitExceededException",
+            ListDevelopmentSchemaArnsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListDevelopmentSchemaArnsError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListDirectories
 #[derive(Debug, PartialEq)]
 pub enum ListDirectoriesError {
@@ -5917,6 +6412,18 @@ impl fmt::Display for ListDirectoriesError {
     }
 }
 impl Error for ListDirectoriesError {}
+impl ServiceErrorCode for ListDirectoriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDirectoriesError::AccessDenied(_) => "AccessDeniedException",
+            ListDirectoriesError::InternalService(_) => "InternalServiceException",
+            ListDirectoriesError::InvalidArn(_) => "InvalidArnException",
+            ListDirectoriesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListDirectoriesError::LimitExceeded(_) => "LimitExceededException",
+            ListDirectoriesError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListFacetAttributes
 #[derive(Debug, PartialEq)]
 pub enum ListFacetAttributesError {
@@ -5995,6 +6502,20 @@ impl fmt::Display for ListFacetAttributesError {
     }
 }
 impl Error for ListFacetAttributesError {}
+impl ServiceErrorCode for ListFacetAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFacetAttributesError::AccessDenied(_) => "AccessDeniedException",
+            ListFacetAttributesError::FacetNotFound(_) => "FacetNotFoundException",
+            ListFacetAttributesError::InternalService(_) => "InternalServiceException",
+            ListFacetAttributesError::InvalidArn(_) => "InvalidArnException",
+            ListFacetAttributesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListFacetAttributesError::LimitExceeded(_) => "LimitExceededException",
+            ListFacetAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListFacetAttributesError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListFacetNames
 #[derive(Debug, PartialEq)]
 pub enum ListFacetNamesError {
@@ -6061,6 +6582,19 @@ impl fmt::Display for ListFacetNamesError {
     }
 }
 impl Error for ListFacetNamesError {}
+impl ServiceErrorCode for ListFacetNamesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFacetNamesError::AccessDenied(_) => "AccessDeniedException",
+            ListFacetNamesError::InternalService(_) => "InternalServiceException",
+            ListFacetNamesError::InvalidArn(_) => "InvalidArnException",
+            ListFacetNamesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListFacetNamesError::LimitExceeded(_) => "LimitExceededException",
+            ListFacetNamesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListFacetNamesError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListIncomingTypedLinks
 #[derive(Debug, PartialEq)]
 pub enum ListIncomingTypedLinksError {
@@ -6153,6 +6687,21 @@ impl fmt::Display for ListIncomingTypedLinksError {
     }
 }
 impl Error for ListIncomingTypedLinksError {}
+impl ServiceErrorCode for ListIncomingTypedLinksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListIncomingTypedLinksError::AccessDenied(_) => "AccessDeniedException",
+            ListIncomingTypedLinksError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            ListIncomingTypedLinksError::FacetValidation(_) => "FacetValidationException",
+            ListIncomingTypedLinksError::InternalService(_) => "InternalServiceException",
+            ListIncomingTypedLinksError::InvalidArn(_) => "InvalidArnException",
+            ListIncomingTypedLinksError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListIncomingTypedLinksError::LimitExceeded(_) => "LimitExceededException",
+            ListIncomingTypedLinksError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListIncomingTypedLinksError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListIndex
 #[derive(Debug, PartialEq)]
 pub enum ListIndexError {
@@ -6237,6 +6786,22 @@ impl fmt::Display for ListIndexError {
     }
 }
 impl Error for ListIndexError {}
+impl ServiceErrorCode for ListIndexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListIndexError::AccessDenied(_) => "AccessDeniedException",
+            ListIndexError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            ListIndexError::FacetValidation(_) => "FacetValidationException",
+            ListIndexError::InternalService(_) => "InternalServiceException",
+            ListIndexError::InvalidArn(_) => "InvalidArnException",
+            ListIndexError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListIndexError::LimitExceeded(_) => "LimitExceededException",
+            ListIndexError::NotIndex(_) => "NotIndexException",
+            ListIndexError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListIndexError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListManagedSchemaArns
 #[derive(Debug, PartialEq)]
 pub enum ListManagedSchemaArnsError {
@@ -6297,6 +6862,17 @@ impl fmt::Display for ListManagedSchemaArnsError {
     }
 }
 impl Error for ListManagedSchemaArnsError {}
+impl ServiceErrorCode for ListManagedSchemaArnsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListManagedSchemaArnsError::AccessDenied(_) => "AccessDeniedException",
+            ListManagedSchemaArnsError::InternalService(_) => "InternalServiceException",
+            ListManagedSchemaArnsError::InvalidArn(_) => "InvalidArnException",
+            ListManagedSchemaArnsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListManagedSchemaArnsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListObjectAttributes
 #[derive(Debug, PartialEq)]
 pub enum ListObjectAttributesError {
@@ -6387,6 +6963,21 @@ impl fmt::Display for ListObjectAttributesError {
     }
 }
 impl Error for ListObjectAttributesError {}
+impl ServiceErrorCode for ListObjectAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListObjectAttributesError::AccessDenied(_) => "AccessDeniedException",
+            ListObjectAttributesError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            ListObjectAttributesError::FacetValidation(_) => "FacetValidationException",
+            ListObjectAttributesError::InternalService(_) => "InternalServiceException",
+            ListObjectAttributesError::InvalidArn(_) => "InvalidArnException",
+            ListObjectAttributesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListObjectAttributesError::LimitExceeded(_) => "LimitExceededException",
+            ListObjectAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListObjectAttributesError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListObjectChildren
 #[derive(Debug, PartialEq)]
 pub enum ListObjectChildrenError {
@@ -6469,6 +7060,21 @@ impl fmt::Display for ListObjectChildrenError {
     }
 }
 impl Error for ListObjectChildrenError {}
+impl ServiceErrorCode for ListObjectChildrenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListObjectChildrenError::AccessDenied(_) => "AccessDeniedException",
+            ListObjectChildrenError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            ListObjectChildrenError::InternalService(_) => "InternalServiceException",
+            ListObjectChildrenError::InvalidArn(_) => "InvalidArnException",
+            ListObjectChildrenError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListObjectChildrenError::LimitExceeded(_) => "LimitExceededException",
+            ListObjectChildrenError::NotNode(_) => "NotNodeException",
+            ListObjectChildrenError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListObjectChildrenError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListObjectParentPaths
 #[derive(Debug, PartialEq)]
 pub enum ListObjectParentPathsError {
@@ -6551,6 +7157,20 @@ impl fmt::Display for ListObjectParentPathsError {
     }
 }
 impl Error for ListObjectParentPathsError {}
+impl ServiceErrorCode for ListObjectParentPathsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListObjectParentPathsError::AccessDenied(_) => "AccessDeniedException",
+            ListObjectParentPathsError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            ListObjectParentPathsError::InternalService(_) => "InternalServiceException",
+            ListObjectParentPathsError::InvalidArn(_) => "InvalidArnException",
+            ListObjectParentPathsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListObjectParentPathsError::LimitExceeded(_) => "LimitExceededException",
+            ListObjectParentPathsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListObjectParentPathsError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListObjectParents
 #[derive(Debug, PartialEq)]
 pub enum ListObjectParentsError {
@@ -6633,6 +7253,21 @@ impl fmt::Display for ListObjectParentsError {
     }
 }
 impl Error for ListObjectParentsError {}
+impl ServiceErrorCode for ListObjectParentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListObjectParentsError::AccessDenied(_) => "AccessDeniedException",
+            ListObjectParentsError::CannotListParentOfRoot(_) => "CannotListParentOfRootException",
+            ListObjectParentsError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            ListObjectParentsError::InternalService(_) => "InternalServiceException",
+            ListObjectParentsError::InvalidArn(_) => "InvalidArnException",
+            ListObjectParentsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListObjectParentsError::LimitExceeded(_) => "LimitExceededException",
+            ListObjectParentsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListObjectParentsError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListObjectPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListObjectPoliciesError {
@@ -6709,6 +7344,20 @@ impl fmt::Display for ListObjectPoliciesError {
     }
 }
 impl Error for ListObjectPoliciesError {}
+impl ServiceErrorCode for ListObjectPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListObjectPoliciesError::AccessDenied(_) => "AccessDeniedException",
+            ListObjectPoliciesError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            ListObjectPoliciesError::InternalService(_) => "InternalServiceException",
+            ListObjectPoliciesError::InvalidArn(_) => "InvalidArnException",
+            ListObjectPoliciesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListObjectPoliciesError::LimitExceeded(_) => "LimitExceededException",
+            ListObjectPoliciesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListObjectPoliciesError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListOutgoingTypedLinks
 #[derive(Debug, PartialEq)]
 pub enum ListOutgoingTypedLinksError {
@@ -6801,6 +7450,21 @@ impl fmt::Display for ListOutgoingTypedLinksError {
     }
 }
 impl Error for ListOutgoingTypedLinksError {}
+impl ServiceErrorCode for ListOutgoingTypedLinksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOutgoingTypedLinksError::AccessDenied(_) => "AccessDeniedException",
+            ListOutgoingTypedLinksError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            ListOutgoingTypedLinksError::FacetValidation(_) => "FacetValidationException",
+            ListOutgoingTypedLinksError::InternalService(_) => "InternalServiceException",
+            ListOutgoingTypedLinksError::InvalidArn(_) => "InvalidArnException",
+            ListOutgoingTypedLinksError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListOutgoingTypedLinksError::LimitExceeded(_) => "LimitExceededException",
+            ListOutgoingTypedLinksError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListOutgoingTypedLinksError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListPolicyAttachments
 #[derive(Debug, PartialEq)]
 pub enum ListPolicyAttachmentsError {
@@ -6889,6 +7553,21 @@ impl fmt::Display for ListPolicyAttachmentsError {
     }
 }
 impl Error for ListPolicyAttachmentsError {}
+impl ServiceErrorCode for ListPolicyAttachmentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPolicyAttachmentsError::AccessDenied(_) => "AccessDeniedException",
+            ListPolicyAttachmentsError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            ListPolicyAttachmentsError::InternalService(_) => "InternalServiceException",
+            ListPolicyAttachmentsError::InvalidArn(_) => "InvalidArnException",
+            ListPolicyAttachmentsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListPolicyAttachmentsError::LimitExceeded(_) => "LimitExceededException",
+            ListPolicyAttachmentsError::NotPolicy(_) => "NotPolicyException",
+            ListPolicyAttachmentsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListPolicyAttachmentsError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListPublishedSchemaArns
 #[derive(Debug, PartialEq)]
 pub enum ListPublishedSchemaArnsError {
@@ -6967,6 +7646,19 @@ impl fmt::Display for ListPublishedSchemaArnsError {
     }
 }
 impl Error for ListPublishedSchemaArnsError {}
+impl ServiceErrorCode for ListPublishedSchemaArnsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPublishedSchemaArnsError::AccessDenied(_) => "AccessDeniedException",
+            ListPublishedSchemaArnsError::InternalService(_) => "InternalServiceException",
+            ListPublishedSchemaArnsError::InvalidArn(_) => "InvalidArnException",
+            ListPublishedSchemaArnsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListPublishedSchemaArnsError::LimitExceeded(_) => "LimitExceededException",
+            ListPublishedSchemaArnsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListPublishedSchemaArnsError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -7039,6 +7731,19 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            ListTagsForResourceError::InternalService(_) => "InternalServiceException",
+            ListTagsForResourceError::InvalidArn(_) => "InvalidArnException",
+            ListTagsForResourceError::InvalidTaggingRequest(_) => "InvalidTaggingRequestException",
+            ListTagsForResourceError::LimitExceeded(_) => "LimitExceededException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForResourceError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListTypedLinkFacetAttributes
 #[derive(Debug, PartialEq)]
 pub enum ListTypedLinkFacetAttributesError {
@@ -7135,6 +7840,20 @@ impl fmt::Display for ListTypedLinkFacetAttributesError {
     }
 }
 impl Error for ListTypedLinkFacetAttributesError {}
+impl ServiceErrorCode for ListTypedLinkFacetAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTypedLinkFacetAttributesError::AccessDenied(_) => "AccessDeniedException",
+            ListTypedLinkFacetAttributesError::FacetNotFound(_) => "FacetNotFoundException",
+            ListTypedLinkFacetAttributesError::InternalService(_) => "InternalServiceException",
+            ListTypedLinkFacetAttributesError::InvalidArn(_) => "InvalidArnException",
+            ListTypedLinkFacetAttributesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListTypedLinkFacetAttributesError::LimitExceeded(_) => "LimitExceededException",
+            ListTypedLinkFacetAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTypedLinkFacetAttributesError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by ListTypedLinkFacetNames
 #[derive(Debug, PartialEq)]
 pub enum ListTypedLinkFacetNamesError {
@@ -7213,6 +7932,19 @@ impl fmt::Display for ListTypedLinkFacetNamesError {
     }
 }
 impl Error for ListTypedLinkFacetNamesError {}
+impl ServiceErrorCode for ListTypedLinkFacetNamesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTypedLinkFacetNamesError::AccessDenied(_) => "AccessDeniedException",
+            ListTypedLinkFacetNamesError::InternalService(_) => "InternalServiceException",
+            ListTypedLinkFacetNamesError::InvalidArn(_) => "InvalidArnException",
+            ListTypedLinkFacetNamesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListTypedLinkFacetNamesError::LimitExceeded(_) => "LimitExceededException",
+            ListTypedLinkFacetNamesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTypedLinkFacetNamesError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by LookupPolicy
 #[derive(Debug, PartialEq)]
 pub enum LookupPolicyError {
@@ -7285,6 +8017,20 @@ impl fmt::Display for LookupPolicyError {
     }
 }
 impl Error for LookupPolicyError {}
+impl ServiceErrorCode for LookupPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            LookupPolicyError::AccessDenied(_) => "AccessDeniedException",
+            LookupPolicyError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            LookupPolicyError::InternalService(_) => "InternalServiceException",
+            LookupPolicyError::InvalidArn(_) => "InvalidArnException",
+            LookupPolicyError::InvalidNextToken(_) => "InvalidNextTokenException",
+            LookupPolicyError::LimitExceeded(_) => "LimitExceededException",
+            LookupPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            LookupPolicyError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by PublishSchema
 #[derive(Debug, PartialEq)]
 pub enum PublishSchemaError {
@@ -7353,6 +8099,19 @@ impl fmt::Display for PublishSchemaError {
     }
 }
 impl Error for PublishSchemaError {}
+impl ServiceErrorCode for PublishSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PublishSchemaError::AccessDenied(_) => "AccessDeniedException",
+            PublishSchemaError::InternalService(_) => "InternalServiceException",
+            PublishSchemaError::InvalidArn(_) => "InvalidArnException",
+            PublishSchemaError::LimitExceeded(_) => "LimitExceededException",
+            PublishSchemaError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PublishSchemaError::RetryableConflict(_) => "RetryableConflictException",
+            PublishSchemaError::SchemaAlreadyPublished(_) => "SchemaAlreadyPublishedException",
+        }
+    }
+}
 /// Errors returned by PutSchemaFromJson
 #[derive(Debug, PartialEq)]
 pub enum PutSchemaFromJsonError {
@@ -7419,6 +8178,19 @@ impl fmt::Display for PutSchemaFromJsonError {
     }
 }
 impl Error for PutSchemaFromJsonError {}
+impl ServiceErrorCode for PutSchemaFromJsonError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutSchemaFromJsonError::AccessDenied(_) => "AccessDeniedException",
+            PutSchemaFromJsonError::InternalService(_) => "InternalServiceException",
+            PutSchemaFromJsonError::InvalidArn(_) => "InvalidArnException",
+            PutSchemaFromJsonError::InvalidRule(_) => "InvalidRuleException",
+            PutSchemaFromJsonError::InvalidSchemaDoc(_) => "InvalidSchemaDocException",
+            PutSchemaFromJsonError::LimitExceeded(_) => "LimitExceededException",
+            PutSchemaFromJsonError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by RemoveFacetFromObject
 #[derive(Debug, PartialEq)]
 pub enum RemoveFacetFromObjectError {
@@ -7501,6 +8273,20 @@ impl fmt::Display for RemoveFacetFromObjectError {
     }
 }
 impl Error for RemoveFacetFromObjectError {}
+impl ServiceErrorCode for RemoveFacetFromObjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveFacetFromObjectError::AccessDenied(_) => "AccessDeniedException",
+            RemoveFacetFromObjectError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            RemoveFacetFromObjectError::FacetValidation(_) => "FacetValidationException",
+            RemoveFacetFromObjectError::InternalService(_) => "InternalServiceException",
+            RemoveFacetFromObjectError::InvalidArn(_) => "InvalidArnException",
+            RemoveFacetFromObjectError::LimitExceeded(_) => "LimitExceededException",
+            RemoveFacetFromObjectError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RemoveFacetFromObjectError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -7567,6 +8353,19 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AccessDenied(_) => "AccessDeniedException",
+            TagResourceError::InternalService(_) => "InternalServiceException",
+            TagResourceError::InvalidArn(_) => "InvalidArnException",
+            TagResourceError::InvalidTaggingRequest(_) => "InvalidTaggingRequestException",
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -7633,6 +8432,19 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AccessDenied(_) => "AccessDeniedException",
+            UntagResourceError::InternalService(_) => "InternalServiceException",
+            UntagResourceError::InvalidArn(_) => "InvalidArnException",
+            UntagResourceError::InvalidTaggingRequest(_) => "InvalidTaggingRequestException",
+            UntagResourceError::LimitExceeded(_) => "LimitExceededException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by UpdateFacet
 #[derive(Debug, PartialEq)]
 pub enum UpdateFacetError {
@@ -7717,6 +8529,22 @@ impl fmt::Display for UpdateFacetError {
     }
 }
 impl Error for UpdateFacetError {}
+impl ServiceErrorCode for UpdateFacetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFacetError::AccessDenied(_) => "AccessDeniedException",
+            UpdateFacetError::FacetNotFound(_) => "FacetNotFoundException",
+            UpdateFacetError::FacetValidation(_) => "FacetValidationException",
+            UpdateFacetError::InternalService(_) => "InternalServiceException",
+            UpdateFacetError::InvalidArn(_) => "InvalidArnException",
+            UpdateFacetError::InvalidFacetUpdate(_) => "InvalidFacetUpdateException",
+            UpdateFacetError::InvalidRule(_) => "InvalidRuleException",
+            UpdateFacetError::LimitExceeded(_) => "LimitExceededException",
+            UpdateFacetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateFacetError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by UpdateLinkAttributes
 #[derive(Debug, PartialEq)]
 pub enum UpdateLinkAttributesError {
@@ -7799,6 +8627,20 @@ impl fmt::Display for UpdateLinkAttributesError {
     }
 }
 impl Error for UpdateLinkAttributesError {}
+impl ServiceErrorCode for UpdateLinkAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateLinkAttributesError::AccessDenied(_) => "AccessDeniedException",
+            UpdateLinkAttributesError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            UpdateLinkAttributesError::FacetValidation(_) => "FacetValidationException",
+            UpdateLinkAttributesError::InternalService(_) => "InternalServiceException",
+            UpdateLinkAttributesError::InvalidArn(_) => "InvalidArnException",
+            UpdateLinkAttributesError::LimitExceeded(_) => "LimitExceededException",
+            UpdateLinkAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateLinkAttributesError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by UpdateObjectAttributes
 #[derive(Debug, PartialEq)]
 pub enum UpdateObjectAttributesError {
@@ -7891,6 +8733,21 @@ impl fmt::Display for UpdateObjectAttributesError {
     }
 }
 impl Error for UpdateObjectAttributesError {}
+impl ServiceErrorCode for UpdateObjectAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateObjectAttributesError::AccessDenied(_) => "AccessDeniedException",
+            UpdateObjectAttributesError::DirectoryNotEnabled(_) => "DirectoryNotEnabledException",
+            UpdateObjectAttributesError::FacetValidation(_) => "FacetValidationException",
+            UpdateObjectAttributesError::InternalService(_) => "InternalServiceException",
+            UpdateObjectAttributesError::InvalidArn(_) => "InvalidArnException",
+            UpdateObjectAttributesError::LimitExceeded(_) => "LimitExceededException",
+            UpdateObjectAttributesError::LinkNameAlreadyInUse(_) => "LinkNameAlreadyInUseException",
+            UpdateObjectAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateObjectAttributesError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by UpdateSchema
 #[derive(Debug, PartialEq)]
 pub enum UpdateSchemaError {
@@ -7951,6 +8808,18 @@ impl fmt::Display for UpdateSchemaError {
     }
 }
 impl Error for UpdateSchemaError {}
+impl ServiceErrorCode for UpdateSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSchemaError::AccessDenied(_) => "AccessDeniedException",
+            UpdateSchemaError::InternalService(_) => "InternalServiceException",
+            UpdateSchemaError::InvalidArn(_) => "InvalidArnException",
+            UpdateSchemaError::LimitExceeded(_) => "LimitExceededException",
+            UpdateSchemaError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateSchemaError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by UpdateTypedLinkFacet
 #[derive(Debug, PartialEq)]
 pub enum UpdateTypedLinkFacetError {
@@ -8045,6 +8914,22 @@ impl fmt::Display for UpdateTypedLinkFacetError {
     }
 }
 impl Error for UpdateTypedLinkFacetError {}
+impl ServiceErrorCode for UpdateTypedLinkFacetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTypedLinkFacetError::AccessDenied(_) => "AccessDeniedException",
+            UpdateTypedLinkFacetError::FacetNotFound(_) => "FacetNotFoundException",
+            UpdateTypedLinkFacetError::FacetValidation(_) => "FacetValidationException",
+            UpdateTypedLinkFacetError::InternalService(_) => "InternalServiceException",
+            UpdateTypedLinkFacetError::InvalidArn(_) => "InvalidArnException",
+            UpdateTypedLinkFacetError::InvalidFacetUpdate(_) => "InvalidFacetUpdateException",
+            UpdateTypedLinkFacetError::InvalidRule(_) => "InvalidRuleException",
+            UpdateTypedLinkFacetError::LimitExceeded(_) => "LimitExceededException",
+            UpdateTypedLinkFacetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateTypedLinkFacetError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Errors returned by UpgradeAppliedSchema
 #[derive(Debug, PartialEq)]
 pub enum UpgradeAppliedSchemaError {
@@ -8129,6 +9014,20 @@ impl fmt::Display for UpgradeAppliedSchemaError {
     }
 }
 impl Error for UpgradeAppliedSchemaError {}
+impl ServiceErrorCode for UpgradeAppliedSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpgradeAppliedSchemaError::AccessDenied(_) => "AccessDeniedException",
+            UpgradeAppliedSchemaError::IncompatibleSchema(_) => "IncompatibleSchemaException",
+            UpgradeAppliedSchemaError::InternalService(_) => "InternalServiceException",
+            UpgradeAppliedSchemaError::InvalidArn(_) => "InvalidArnException",
+            UpgradeAppliedSchemaError::InvalidAttachment(_) => "InvalidAttachmentException",
+            UpgradeAppliedSchemaError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpgradeAppliedSchemaError::RetryableConflict(_) => "RetryableConflictException",
+            UpgradeAppliedSchemaError::SchemaAlreadyExists(_) => "SchemaAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by UpgradePublishedSchema
 #[derive(Debug, PartialEq)]
 pub enum UpgradePublishedSchemaError {
@@ -8213,6 +9112,20 @@ impl fmt::Display for UpgradePublishedSchemaError {
     }
 }
 impl Error for UpgradePublishedSchemaError {}
+impl ServiceErrorCode for UpgradePublishedSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpgradePublishedSchemaError::AccessDenied(_) => "AccessDeniedException",
+            UpgradePublishedSchemaError::IncompatibleSchema(_) => "IncompatibleSchemaException",
+            UpgradePublishedSchemaError::InternalService(_) => "InternalServiceException",
+            UpgradePublishedSchemaError::InvalidArn(_) => "InvalidArnException",
+            UpgradePublishedSchemaError::InvalidAttachment(_) => "InvalidAttachmentException",
+            UpgradePublishedSchemaError::LimitExceeded(_) => "LimitExceededException",
+            UpgradePublishedSchemaError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpgradePublishedSchemaError::RetryableConflict(_) => "RetryableConflictException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon CloudDirectory API. Amazon CloudDirectory clients implement this trait.
 #[async_trait]
 pub trait CloudDirectory {
@@ -1,12 +1,14 @@
 extern crate rusoto_mock;
 
-use crate::generated::{CloudFormation, CloudFormationClient, ListStacksInput};
+use crate::generated::{
+    CloudFormation, CloudFormationClient, CreateStackSetInput, ListStacksInput,
+};
 
 use self::rusoto_mock::*;
 use rusoto_core::param::Params;
 use rusoto_core::signature::SignedRequest;
 use rusoto_core::signature::SignedRequestPayload;
-use rusoto_core::Region;
+use rusoto_core::{Client, Region};
 use serde_urlencoded;
 
 #[tokio::test]
@@ -77,3 +79,59 @@ async fn should_serialize_list_parameters_in_request_body() {
     let client = CloudFormationClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
     let _result = client.list_stacks(request).await.unwrap();
 }
+
+fn create_stack_set_mock() -> MockRequestDispatcher {
+    MockRequestDispatcher::with_status(200).with_body(
+        r#"<CreateStackSetResponse>
+            <CreateStackSetResult><StackSetId>stack-set:1</StackSetId></CreateStackSetResult>
+            <ResponseMetadata><RequestId>b9b4b068-example</RequestId></ResponseMetadata>
+        </CreateStackSetResponse>"#,
+    )
+}
+
+fn client_request_token(request: &SignedRequest) -> Option<String> {
+    if let Some(SignedRequestPayload::Buffer(ref buffer)) = request.payload {
+        let params: Params = serde_urlencoded::from_bytes(buffer).unwrap();
+        params.get("ClientRequestToken").cloned().flatten()
+    } else {
+        panic!("Unexpected request.payload: {:?}", request.payload);
+    }
+}
+
+#[tokio::test]
+async fn should_fill_in_idempotency_tokens() {
+    let mock = create_stack_set_mock().with_request_checker(|request: &SignedRequest| {
+        let token = client_request_token(request).expect("a client request token");
+        assert_eq!(36, token.len());
+    });
+    let client = Client::new_with(MockCredentialsProvider, mock).with_idempotency_tokens(true);
+    let client = CloudFormationClient::new_with_client(client, Region::UsEast1);
+    let request = CreateStackSetInput {
+        stack_set_name: "stack-set".to_owned(),
+        ..Default::default()
+    };
+    client.create_stack_set(request).await.unwrap();
+
+    // A token the caller sets is kept, and none is made up unless the client is set up to.
+    let mock = create_stack_set_mock().with_request_checker(|request: &SignedRequest| {
+        assert_eq!(Some("token".to_owned()), client_request_token(request));
+    });
+    let client = Client::new_with(MockCredentialsProvider, mock).with_idempotency_tokens(true);
+    let client = CloudFormationClient::new_with_client(client, Region::UsEast1);
+    let request = CreateStackSetInput {
+        stack_set_name: "stack-set".to_owned(),
+        client_request_token: Some("token".to_owned()),
+        ..Default::default()
+    };
+    client.create_stack_set(request).await.unwrap();
+
+    let mock = create_stack_set_mock().with_request_checker(|request: &SignedRequest| {
+        assert_eq!(None, client_request_token(request));
+    });
+    let client = CloudFormationClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = CreateStackSetInput {
+        stack_set_name: "stack-set".to_owned(),
+        ..Default::default()
+    };
+    client.create_stack_set(request).await.unwrap();
+}
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -986,6 +986,7 @@ pub struct CreateStackInstancesInput {
     /// <p>[<code>Service-managed</code> permissions] The AWS Organizations accounts for which to create stack instances in the specified Regions.</p> <p>You can specify <code>Accounts</code> or <code>DeploymentTargets</code>, but not both.</p>
     pub deployment_targets: Option<DeploymentTargets>,
     /// <p>The unique identifier for this stack set operation. </p> <p>The operation ID also functions as an idempotency token, to ensure that AWS CloudFormation performs the stack set operation only once, even if you retry the request multiple times. You might retry stack set operation requests to ensure that AWS CloudFormation successfully received them.</p> <p>If you don't specify an operation ID, the SDK generates one automatically. </p> <p>Repeating this stack set operation with a new operation ID retries all stack instances whose status is <code>OUTDATED</code>. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub operation_id: Option<String>,
     /// <p>Preferences for how AWS CloudFormation performs this stack set operation.</p>
     pub operation_preferences: Option<StackSetOperationPreferences>,
@@ -1115,6 +1116,7 @@ pub struct CreateStackSetInput {
     /// <p><p>In some cases, you must explicitly acknowledge that your stack set template contains certain capabilities in order for AWS CloudFormation to create the stack set and related stack instances.</p> <ul> <li> <p> <code>CAPABILITY<em>IAM</code> and <code>CAPABILITY</em>NAMED<em>IAM</code> </p> <p>Some stack templates might include resources that can affect permissions in your AWS account; for example, by creating new AWS Identity and Access Management (IAM) users. For those stack sets, you must explicitly acknowledge this by specifying one of these capabilities.</p> <p>The following IAM resources require you to specify either the <code>CAPABILITY</em>IAM</code> or <code>CAPABILITY<em>NAMED</em>IAM</code> capability.</p> <ul> <li> <p>If you have IAM resources, you can specify either capability. </p> </li> <li> <p>If you have IAM resources with custom names, you <i>must</i> specify <code>CAPABILITY<em>NAMED</em>IAM</code>. </p> </li> <li> <p>If you don&#39;t specify either of these capabilities, AWS CloudFormation returns an <code>InsufficientCapabilities</code> error.</p> </li> </ul> <p>If your stack template contains these resources, we recommend that you review all permissions associated with them and edit their permissions if necessary.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-accesskey.html"> AWS::IAM::AccessKey</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-group.html"> AWS::IAM::Group</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-instanceprofile.html"> AWS::IAM::InstanceProfile</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-policy.html"> AWS::IAM::Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-role.html"> AWS::IAM::Role</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-user.html"> AWS::IAM::User</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-addusertogroup.html"> AWS::IAM::UserToGroupAddition</a> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p> </li> <li> <p> <code>CAPABILITY<em>AUTO</em>EXPAND</code> </p> <p>Some templates contain macros. If your stack template contains one or more macros, and you choose to create a stack directly from the processed template, without first reviewing the resulting changes in a change set, you must acknowledge this capability. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-macros.html">Using AWS CloudFormation Macros to Perform Custom Processing on Templates</a>.</p> <note> <p>Stack sets do not currently support macros in stack templates. (This includes the <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/create-reusable-transform-function-snippets-and-add-to-your-template-with-aws-include-transform.html">AWS::Include</a> and <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/transform-aws-serverless.html">AWS::Serverless</a> transforms, which are macros hosted by AWS CloudFormation.) Even if you specify this capability, if you include a macro in your template the stack set operation will fail.</p> </note> </li> </ul></p>
     pub capabilities: Option<Vec<String>>,
     /// <p>A unique identifier for this <code>CreateStackSet</code> request. Specify this token if you plan to retry requests so that AWS CloudFormation knows that you're not attempting to create another stack set with the same name. You might retry <code>CreateStackSet</code> requests to ensure that AWS CloudFormation successfully received them.</p> <p>If you don't specify an operation ID, the SDK generates one automatically. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_request_token: Option<String>,
     /// <p>A description of the stack set. You can use the description to identify the stack set's purpose or other important information.</p>
     pub description: Option<String>,
@@ -1332,6 +1334,7 @@ pub struct DeleteStackInstancesInput {
     /// <p>[<code>Service-managed</code> permissions] The AWS Organizations accounts from which to delete stack instances.</p> <p>You can specify <code>Accounts</code> or <code>DeploymentTargets</code>, but not both.</p>
     pub deployment_targets: Option<DeploymentTargets>,
     /// <p>The unique identifier for this stack set operation. </p> <p>If you don't specify an operation ID, the SDK generates one automatically. </p> <p>The operation ID also functions as an idempotency token, to ensure that AWS CloudFormation performs the stack set operation only once, even if you retry the request multiple times. You can retry stack set operation requests to ensure that AWS CloudFormation successfully received them.</p> <p>Repeating this stack set operation with a new operation ID retries all stack instances whose status is <code>OUTDATED</code>. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub operation_id: Option<String>,
     /// <p>Preferences for how AWS CloudFormation performs this stack set operation.</p>
     pub operation_preferences: Option<StackSetOperationPreferences>,
@@ -2841,6 +2844,7 @@ impl DetectStackResourceDriftOutputDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DetectStackSetDriftInput {
     /// <p> <i>The ID of the stack set operation.</i> </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub operation_id: Option<String>,
     pub operation_preferences: Option<StackSetOperationPreferences>,
     /// <p>The name of the stack set on which to perform the drift detection operation.</p>
@@ -9040,6 +9044,7 @@ pub struct UpdateStackInstancesInput {
     /// <p>[<code>Service-managed</code> permissions] The AWS Organizations accounts for which you want to update parameter values for stack instances. If your update targets OUs, the overridden parameter values only apply to the accounts that are currently in the target OUs and their child OUs. Accounts added to the target OUs and their child OUs in the future won't use the overridden values.</p> <p>You can specify <code>Accounts</code> or <code>DeploymentTargets</code>, but not both.</p>
     pub deployment_targets: Option<DeploymentTargets>,
     /// <p>The unique identifier for this stack set operation. </p> <p>The operation ID also functions as an idempotency token, to ensure that AWS CloudFormation performs the stack set operation only once, even if you retry the request multiple times. You might retry stack set operation requests to ensure that AWS CloudFormation successfully received them.</p> <p>If you don't specify an operation ID, the SDK generates one automatically. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub operation_id: Option<String>,
     /// <p>Preferences for how AWS CloudFormation performs this stack set operation.</p>
     pub operation_preferences: Option<StackSetOperationPreferences>,
@@ -9177,6 +9182,7 @@ pub struct UpdateStackSetInput {
     /// <p>The name of the IAM execution role to use to update the stack set. If you do not specify an execution role, AWS CloudFormation uses the <code>AWSCloudFormationStackSetExecutionRole</code> role for the stack set operation.</p> <p>Specify an IAM role only if you are using customized execution roles to control which stack resources users and groups can include in their stack sets. </p> <p> If you specify a customized execution role, AWS CloudFormation uses that role to update the stack. If you do not specify a customized execution role, AWS CloudFormation performs the update using the role previously associated with the stack set, so long as you have permissions to perform operations on the stack set.</p>
     pub execution_role_name: Option<String>,
     /// <p>The unique ID for this stack set operation. </p> <p>The operation ID also functions as an idempotency token, to ensure that AWS CloudFormation performs the stack set operation only once, even if you retry the request multiple times. You might retry stack set operation requests to ensure that AWS CloudFormation successfully received them.</p> <p>If you don't specify an operation ID, AWS CloudFormation generates one automatically.</p> <p>Repeating this stack set operation with a new operation ID retries all stack instances whose status is <code>OUTDATED</code>. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub operation_id: Option<String>,
     /// <p>Preferences for how AWS CloudFormation performs this stack set operation.</p>
     pub operation_preferences: Option<StackSetOperationPreferences>,
@@ -9550,6 +9556,13 @@ impl fmt::Display for CancelUpdateStackError {
     }
 }
 impl Error for CancelUpdateStackError {}
+impl ServiceErrorCode for CancelUpdateStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelUpdateStackError::TokenAlreadyExists(_) => "TokenAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by ContinueUpdateRollback
 #[derive(Debug, PartialEq)]
 pub enum ContinueUpdateRollbackError {
@@ -9594,6 +9607,13 @@ impl fmt::Display for ContinueUpdateRollbackError {
     }
 }
 impl Error for ContinueUpdateRollbackError {}
+impl ServiceErrorCode for ContinueUpdateRollbackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ContinueUpdateRollbackError::TokenAlreadyExists(_) => "TokenAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by CreateChangeSet
 #[derive(Debug, PartialEq)]
 pub enum CreateChangeSetError {
@@ -9654,6 +9674,17 @@ impl fmt::Display for CreateChangeSetError {
     }
 }
 impl Error for CreateChangeSetError {}
+impl ServiceErrorCode for CreateChangeSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateChangeSetError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateChangeSetError::InsufficientCapabilities(_) => {
+                "InsufficientCapabilitiesException"
+            }
+            CreateChangeSetError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateStack
 #[derive(Debug, PartialEq)]
 pub enum CreateStackError {
@@ -9722,6 +9753,16 @@ impl fmt::Display for CreateStackError {
     }
 }
 impl Error for CreateStackError {}
+impl ServiceErrorCode for CreateStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStackError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateStackError::InsufficientCapabilities(_) => "InsufficientCapabilitiesException",
+            CreateStackError::LimitExceeded(_) => "LimitExceededException",
+            CreateStackError::TokenAlreadyExists(_) => "TokenAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by CreateStackInstances
 #[derive(Debug, PartialEq)]
 pub enum CreateStackInstancesError {
@@ -9810,6 +9851,20 @@ impl fmt::Display for CreateStackInstancesError {
     }
 }
 impl Error for CreateStackInstancesError {}
+impl ServiceErrorCode for CreateStackInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStackInstancesError::InvalidOperation(_) => "InvalidOperationException",
+            CreateStackInstancesError::LimitExceeded(_) => "LimitExceededException",
+            CreateStackInstancesError::OperationIdAlreadyExists(_) => {
+                "OperationIdAlreadyExistsException"
+            }
+            CreateStackInstancesError::OperationInProgress(_) => "OperationInProgressException",
+            CreateStackInstancesError::StackSetNotFound(_) => "StackSetNotFoundException",
+            CreateStackInstancesError::StaleRequest(_) => "StaleRequestException",
+        }
+    }
+}
 /// Errors returned by CreateStackSet
 #[derive(Debug, PartialEq)]
 pub enum CreateStackSetError {
@@ -9870,6 +9925,15 @@ impl fmt::Display for CreateStackSetError {
     }
 }
 impl Error for CreateStackSetError {}
+impl ServiceErrorCode for CreateStackSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStackSetError::CreatedButModified(_) => "CreatedButModifiedException",
+            CreateStackSetError::LimitExceeded(_) => "LimitExceededException",
+            CreateStackSetError::NameAlreadyExists(_) => "NameAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by DeleteChangeSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteChangeSetError {
@@ -9914,6 +9978,13 @@ impl fmt::Display for DeleteChangeSetError {
     }
 }
 impl Error for DeleteChangeSetError {}
+impl ServiceErrorCode for DeleteChangeSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteChangeSetError::InvalidChangeSetStatus(_) => "InvalidChangeSetStatus",
+        }
+    }
+}
 /// Errors returned by DeleteStack
 #[derive(Debug, PartialEq)]
 pub enum DeleteStackError {
@@ -9958,6 +10029,13 @@ impl fmt::Display for DeleteStackError {
     }
 }
 impl Error for DeleteStackError {}
+impl ServiceErrorCode for DeleteStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStackError::TokenAlreadyExists(_) => "TokenAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by DeleteStackInstances
 #[derive(Debug, PartialEq)]
 pub enum DeleteStackInstancesError {
@@ -10038,6 +10116,19 @@ impl fmt::Display for DeleteStackInstancesError {
     }
 }
 impl Error for DeleteStackInstancesError {}
+impl ServiceErrorCode for DeleteStackInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStackInstancesError::InvalidOperation(_) => "InvalidOperationException",
+            DeleteStackInstancesError::OperationIdAlreadyExists(_) => {
+                "OperationIdAlreadyExistsException"
+            }
+            DeleteStackInstancesError::OperationInProgress(_) => "OperationInProgressException",
+            DeleteStackInstancesError::StackSetNotFound(_) => "StackSetNotFoundException",
+            DeleteStackInstancesError::StaleRequest(_) => "StaleRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteStackSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteStackSetError {
@@ -10090,6 +10181,14 @@ impl fmt::Display for DeleteStackSetError {
     }
 }
 impl Error for DeleteStackSetError {}
+impl ServiceErrorCode for DeleteStackSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStackSetError::OperationInProgress(_) => "OperationInProgressException",
+            DeleteStackSetError::StackSetNotEmpty(_) => "StackSetNotEmptyException",
+        }
+    }
+}
 /// Errors returned by DeregisterType
 #[derive(Debug, PartialEq)]
 pub enum DeregisterTypeError {
@@ -10142,6 +10241,14 @@ impl fmt::Display for DeregisterTypeError {
     }
 }
 impl Error for DeregisterTypeError {}
+impl ServiceErrorCode for DeregisterTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterTypeError::CFNRegistry(_) => "CFNRegistryException",
+            DeregisterTypeError::TypeNotFound(_) => "TypeNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeAccountLimits
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountLimitsError {}
@@ -10176,6 +10283,11 @@ impl fmt::Display for DescribeAccountLimitsError {
     }
 }
 impl Error for DescribeAccountLimitsError {}
+impl ServiceErrorCode for DescribeAccountLimitsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeChangeSet
 #[derive(Debug, PartialEq)]
 pub enum DescribeChangeSetError {
@@ -10220,6 +10332,13 @@ impl fmt::Display for DescribeChangeSetError {
     }
 }
 impl Error for DescribeChangeSetError {}
+impl ServiceErrorCode for DescribeChangeSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeChangeSetError::ChangeSetNotFound(_) => "ChangeSetNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeStackDriftDetectionStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeStackDriftDetectionStatusError {}
@@ -10256,6 +10375,11 @@ impl fmt::Display for DescribeStackDriftDetectionStatusError {
     }
 }
 impl Error for DescribeStackDriftDetectionStatusError {}
+impl ServiceErrorCode for DescribeStackDriftDetectionStatusError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeStackEvents
 #[derive(Debug, PartialEq)]
 pub enum DescribeStackEventsError {}
@@ -10290,6 +10414,11 @@ impl fmt::Display for DescribeStackEventsError {
     }
 }
 impl Error for DescribeStackEventsError {}
+impl ServiceErrorCode for DescribeStackEventsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeStackInstance
 #[derive(Debug, PartialEq)]
 pub enum DescribeStackInstanceError {
@@ -10342,6 +10471,16 @@ impl fmt::Display for DescribeStackInstanceError {
     }
 }
 impl Error for DescribeStackInstanceError {}
+impl ServiceErrorCode for DescribeStackInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStackInstanceError::StackInstanceNotFound(_) => {
+                "StackInstanceNotFoundException"
+            }
+            DescribeStackInstanceError::StackSetNotFound(_) => "StackSetNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeStackResource
 #[derive(Debug, PartialEq)]
 pub enum DescribeStackResourceError {}
@@ -10376,6 +10515,11 @@ impl fmt::Display for DescribeStackResourceError {
     }
 }
 impl Error for DescribeStackResourceError {}
+impl ServiceErrorCode for DescribeStackResourceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeStackResourceDrifts
 #[derive(Debug, PartialEq)]
 pub enum DescribeStackResourceDriftsError {}
@@ -10412,6 +10556,11 @@ impl fmt::Display for DescribeStackResourceDriftsError {
     }
 }
 impl Error for DescribeStackResourceDriftsError {}
+impl ServiceErrorCode for DescribeStackResourceDriftsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeStackResources
 #[derive(Debug, PartialEq)]
 pub enum DescribeStackResourcesError {}
@@ -10446,6 +10595,11 @@ impl fmt::Display for DescribeStackResourcesError {
     }
 }
 impl Error for DescribeStackResourcesError {}
+impl ServiceErrorCode for DescribeStackResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeStackSet
 #[derive(Debug, PartialEq)]
 pub enum DescribeStackSetError {
@@ -10490,6 +10644,13 @@ impl fmt::Display for DescribeStackSetError {
     }
 }
 impl Error for DescribeStackSetError {}
+impl ServiceErrorCode for DescribeStackSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStackSetError::StackSetNotFound(_) => "StackSetNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeStackSetOperation
 #[derive(Debug, PartialEq)]
 pub enum DescribeStackSetOperationError {
@@ -10542,6 +10703,14 @@ impl fmt::Display for DescribeStackSetOperationError {
     }
 }
 impl Error for DescribeStackSetOperationError {}
+impl ServiceErrorCode for DescribeStackSetOperationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStackSetOperationError::OperationNotFound(_) => "OperationNotFoundException",
+            DescribeStackSetOperationError::StackSetNotFound(_) => "StackSetNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeStacks
 #[derive(Debug, PartialEq)]
 pub enum DescribeStacksError {}
@@ -10576,6 +10745,11 @@ impl fmt::Display for DescribeStacksError {
     }
 }
 impl Error for DescribeStacksError {}
+impl ServiceErrorCode for DescribeStacksError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeType
 #[derive(Debug, PartialEq)]
 pub enum DescribeTypeError {
@@ -10628,6 +10802,14 @@ impl fmt::Display for DescribeTypeError {
     }
 }
 impl Error for DescribeTypeError {}
+impl ServiceErrorCode for DescribeTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTypeError::CFNRegistry(_) => "CFNRegistryException",
+            DescribeTypeError::TypeNotFound(_) => "TypeNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeTypeRegistration
 #[derive(Debug, PartialEq)]
 pub enum DescribeTypeRegistrationError {
@@ -10672,6 +10854,13 @@ impl fmt::Display for DescribeTypeRegistrationError {
     }
 }
 impl Error for DescribeTypeRegistrationError {}
+impl ServiceErrorCode for DescribeTypeRegistrationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTypeRegistrationError::CFNRegistry(_) => "CFNRegistryException",
+        }
+    }
+}
 /// Errors returned by DetectStackDrift
 #[derive(Debug, PartialEq)]
 pub enum DetectStackDriftError {}
@@ -10706,6 +10895,11 @@ impl fmt::Display for DetectStackDriftError {
     }
 }
 impl Error for DetectStackDriftError {}
+impl ServiceErrorCode for DetectStackDriftError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DetectStackResourceDrift
 #[derive(Debug, PartialEq)]
 pub enum DetectStackResourceDriftError {}
@@ -10740,6 +10934,11 @@ impl fmt::Display for DetectStackResourceDriftError {
     }
 }
 impl Error for DetectStackResourceDriftError {}
+impl ServiceErrorCode for DetectStackResourceDriftError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DetectStackSetDrift
 #[derive(Debug, PartialEq)]
 pub enum DetectStackSetDriftError {
@@ -10800,6 +10999,15 @@ impl fmt::Display for DetectStackSetDriftError {
     }
 }
 impl Error for DetectStackSetDriftError {}
+impl ServiceErrorCode for DetectStackSetDriftError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectStackSetDriftError::InvalidOperation(_) => "InvalidOperationException",
+            DetectStackSetDriftError::OperationInProgress(_) => "OperationInProgressException",
+            DetectStackSetDriftError::StackSetNotFound(_) => "StackSetNotFoundException",
+        }
+    }
+}
 /// Errors returned by EstimateTemplateCost
 #[derive(Debug, PartialEq)]
 pub enum EstimateTemplateCostError {}
@@ -10834,6 +11042,11 @@ impl fmt::Display for EstimateTemplateCostError {
     }
 }
 impl Error for EstimateTemplateCostError {}
+impl ServiceErrorCode for EstimateTemplateCostError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ExecuteChangeSet
 #[derive(Debug, PartialEq)]
 pub enum ExecuteChangeSetError {
@@ -10902,6 +11115,18 @@ impl fmt::Display for ExecuteChangeSetError {
     }
 }
 impl Error for ExecuteChangeSetError {}
+impl ServiceErrorCode for ExecuteChangeSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExecuteChangeSetError::ChangeSetNotFound(_) => "ChangeSetNotFound",
+            ExecuteChangeSetError::InsufficientCapabilities(_) => {
+                "InsufficientCapabilitiesException"
+            }
+            ExecuteChangeSetError::InvalidChangeSetStatus(_) => "InvalidChangeSetStatus",
+            ExecuteChangeSetError::TokenAlreadyExists(_) => "TokenAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by GetStackPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetStackPolicyError {}
@@ -10936,6 +11161,11 @@ impl fmt::Display for GetStackPolicyError {
     }
 }
 impl Error for GetStackPolicyError {}
+impl ServiceErrorCode for GetStackPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetTemplate
 #[derive(Debug, PartialEq)]
 pub enum GetTemplateError {
@@ -10980,6 +11210,13 @@ impl fmt::Display for GetTemplateError {
     }
 }
 impl Error for GetTemplateError {}
+impl ServiceErrorCode for GetTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTemplateError::ChangeSetNotFound(_) => "ChangeSetNotFound",
+        }
+    }
+}
 /// Errors returned by GetTemplateSummary
 #[derive(Debug, PartialEq)]
 pub enum GetTemplateSummaryError {
@@ -11024,6 +11261,13 @@ impl fmt::Display for GetTemplateSummaryError {
     }
 }
 impl Error for GetTemplateSummaryError {}
+impl ServiceErrorCode for GetTemplateSummaryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTemplateSummaryError::StackSetNotFound(_) => "StackSetNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListChangeSets
 #[derive(Debug, PartialEq)]
 pub enum ListChangeSetsError {}
@@ -11058,6 +11302,11 @@ impl fmt::Display for ListChangeSetsError {
     }
 }
 impl Error for ListChangeSetsError {}
+impl ServiceErrorCode for ListChangeSetsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListExports
 #[derive(Debug, PartialEq)]
 pub enum ListExportsError {}
@@ -11092,6 +11341,11 @@ impl fmt::Display for ListExportsError {
     }
 }
 impl Error for ListExportsError {}
+impl ServiceErrorCode for ListExportsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListImports
 #[derive(Debug, PartialEq)]
 pub enum ListImportsError {}
@@ -11126,6 +11380,11 @@ impl fmt::Display for ListImportsError {
     }
 }
 impl Error for ListImportsError {}
+impl ServiceErrorCode for ListImportsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListStackInstances
 #[derive(Debug, PartialEq)]
 pub enum ListStackInstancesError {
@@ -11170,6 +11429,13 @@ impl fmt::Display for ListStackInstancesError {
     }
 }
 impl Error for ListStackInstancesError {}
+impl ServiceErrorCode for ListStackInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStackInstancesError::StackSetNotFound(_) => "StackSetNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListStackResources
 #[derive(Debug, PartialEq)]
 pub enum ListStackResourcesError {}
@@ -11204,6 +11470,11 @@ impl fmt::Display for ListStackResourcesError {
     }
 }
 impl Error for ListStackResourcesError {}
+impl ServiceErrorCode for ListStackResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListStackSetOperationResults
 #[derive(Debug, PartialEq)]
 pub enum ListStackSetOperationResultsError {
@@ -11266,6 +11537,14 @@ impl fmt::Display for ListStackSetOperationResultsError {
     }
 }
 impl Error for ListStackSetOperationResultsError {}
+impl ServiceErrorCode for ListStackSetOperationResultsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStackSetOperationResultsError::OperationNotFound(_) => "OperationNotFoundException",
+            ListStackSetOperationResultsError::StackSetNotFound(_) => "StackSetNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListStackSetOperations
 #[derive(Debug, PartialEq)]
 pub enum ListStackSetOperationsError {
@@ -11310,6 +11589,13 @@ impl fmt::Display for ListStackSetOperationsError {
     }
 }
 impl Error for ListStackSetOperationsError {}
+impl ServiceErrorCode for ListStackSetOperationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStackSetOperationsError::StackSetNotFound(_) => "StackSetNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListStackSets
 #[derive(Debug, PartialEq)]
 pub enum ListStackSetsError {}
@@ -11344,6 +11630,11 @@ impl fmt::Display for ListStackSetsError {
     }
 }
 impl Error for ListStackSetsError {}
+impl ServiceErrorCode for ListStackSetsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListStacks
 #[derive(Debug, PartialEq)]
 pub enum ListStacksError {}
@@ -11378,6 +11669,11 @@ impl fmt::Display for ListStacksError {
     }
 }
 impl Error for ListStacksError {}
+impl ServiceErrorCode for ListStacksError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListTypeRegistrations
 #[derive(Debug, PartialEq)]
 pub enum ListTypeRegistrationsError {
@@ -11422,6 +11718,13 @@ impl fmt::Display for ListTypeRegistrationsError {
     }
 }
 impl Error for ListTypeRegistrationsError {}
+impl ServiceErrorCode for ListTypeRegistrationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTypeRegistrationsError::CFNRegistry(_) => "CFNRegistryException",
+        }
+    }
+}
 /// Errors returned by ListTypeVersions
 #[derive(Debug, PartialEq)]
 pub enum ListTypeVersionsError {
@@ -11466,6 +11769,13 @@ impl fmt::Display for ListTypeVersionsError {
     }
 }
 impl Error for ListTypeVersionsError {}
+impl ServiceErrorCode for ListTypeVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTypeVersionsError::CFNRegistry(_) => "CFNRegistryException",
+        }
+    }
+}
 /// Errors returned by ListTypes
 #[derive(Debug, PartialEq)]
 pub enum ListTypesError {
@@ -11510,6 +11820,13 @@ impl fmt::Display for ListTypesError {
     }
 }
 impl Error for ListTypesError {}
+impl ServiceErrorCode for ListTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTypesError::CFNRegistry(_) => "CFNRegistryException",
+        }
+    }
+}
 /// Errors returned by RecordHandlerProgress
 #[derive(Debug, PartialEq)]
 pub enum RecordHandlerProgressError {
@@ -11568,6 +11885,14 @@ impl fmt::Display for RecordHandlerProgressError {
     }
 }
 impl Error for RecordHandlerProgressError {}
+impl ServiceErrorCode for RecordHandlerProgressError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RecordHandlerProgressError::InvalidStateTransition(_) => "InvalidStateTransition",
+            RecordHandlerProgressError::OperationStatusCheckFailed(_) => "ConditionalCheckFailed",
+        }
+    }
+}
 /// Errors returned by RegisterType
 #[derive(Debug, PartialEq)]
 pub enum RegisterTypeError {
@@ -11612,6 +11937,13 @@ impl fmt::Display for RegisterTypeError {
     }
 }
 impl Error for RegisterTypeError {}
+impl ServiceErrorCode for RegisterTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterTypeError::CFNRegistry(_) => "CFNRegistryException",
+        }
+    }
+}
 /// Errors returned by SetStackPolicy
 #[derive(Debug, PartialEq)]
 pub enum SetStackPolicyError {}
@@ -11646,6 +11978,11 @@ impl fmt::Display for SetStackPolicyError {
     }
 }
 impl Error for SetStackPolicyError {}
+impl ServiceErrorCode for SetStackPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SetTypeDefaultVersion
 #[derive(Debug, PartialEq)]
 pub enum SetTypeDefaultVersionError {
@@ -11698,6 +12035,14 @@ impl fmt::Display for SetTypeDefaultVersionError {
     }
 }
 impl Error for SetTypeDefaultVersionError {}
+impl ServiceErrorCode for SetTypeDefaultVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetTypeDefaultVersionError::CFNRegistry(_) => "CFNRegistryException",
+            SetTypeDefaultVersionError::TypeNotFound(_) => "TypeNotFoundException",
+        }
+    }
+}
 /// Errors returned by SignalResource
 #[derive(Debug, PartialEq)]
 pub enum SignalResourceError {}
@@ -11732,6 +12077,11 @@ impl fmt::Display for SignalResourceError {
     }
 }
 impl Error for SignalResourceError {}
+impl ServiceErrorCode for SignalResourceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by StopStackSetOperation
 #[derive(Debug, PartialEq)]
 pub enum StopStackSetOperationError {
@@ -11792,6 +12142,15 @@ impl fmt::Display for StopStackSetOperationError {
     }
 }
 impl Error for StopStackSetOperationError {}
+impl ServiceErrorCode for StopStackSetOperationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopStackSetOperationError::InvalidOperation(_) => "InvalidOperationException",
+            StopStackSetOperationError::OperationNotFound(_) => "OperationNotFoundException",
+            StopStackSetOperationError::StackSetNotFound(_) => "StackSetNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateStack
 #[derive(Debug, PartialEq)]
 pub enum UpdateStackError {
@@ -11844,6 +12203,14 @@ impl fmt::Display for UpdateStackError {
     }
 }
 impl Error for UpdateStackError {}
+impl ServiceErrorCode for UpdateStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateStackError::InsufficientCapabilities(_) => "InsufficientCapabilitiesException",
+            UpdateStackError::TokenAlreadyExists(_) => "TokenAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by UpdateStackInstances
 #[derive(Debug, PartialEq)]
 pub enum UpdateStackInstancesError {
@@ -11932,6 +12299,20 @@ impl fmt::Display for UpdateStackInstancesError {
     }
 }
 impl Error for UpdateStackInstancesError {}
+impl ServiceErrorCode for UpdateStackInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateStackInstancesError::InvalidOperation(_) => "InvalidOperationException",
+            UpdateStackInstancesError::OperationIdAlreadyExists(_) => {
+                "OperationIdAlreadyExistsException"
+            }
+            UpdateStackInstancesError::OperationInProgress(_) => "OperationInProgressException",
+            UpdateStackInstancesError::StackInstanceNotFound(_) => "StackInstanceNotFoundException",
+            UpdateStackInstancesError::StackSetNotFound(_) => "StackSetNotFoundException",
+            UpdateStackInstancesError::StaleRequest(_) => "StaleRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateStackSet
 #[derive(Debug, PartialEq)]
 pub enum UpdateStackSetError {
@@ -12016,6 +12397,18 @@ impl fmt::Display for UpdateStackSetError {
     }
 }
 impl Error for UpdateStackSetError {}
+impl ServiceErrorCode for UpdateStackSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateStackSetError::InvalidOperation(_) => "InvalidOperationException",
+            UpdateStackSetError::OperationIdAlreadyExists(_) => "OperationIdAlreadyExistsException",
+            UpdateStackSetError::OperationInProgress(_) => "OperationInProgressException",
+            UpdateStackSetError::StackInstanceNotFound(_) => "StackInstanceNotFoundException",
+            UpdateStackSetError::StackSetNotFound(_) => "StackSetNotFoundException",
+            UpdateStackSetError::StaleRequest(_) => "StaleRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateTerminationProtection
 #[derive(Debug, PartialEq)]
 pub enum UpdateTerminationProtectionError {}
@@ -12052,6 +12445,11 @@ impl fmt::Display for UpdateTerminationProtectionError {
     }
 }
 impl Error for UpdateTerminationProtectionError {}
+impl ServiceErrorCode for UpdateTerminationProtectionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ValidateTemplate
 #[derive(Debug, PartialEq)]
 pub enum ValidateTemplateError {}
@@ -12086,6 +12484,11 @@ impl fmt::Display for ValidateTemplateError {
     }
 }
 impl Error for ValidateTemplateError {}
+impl ServiceErrorCode for ValidateTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Trait representing the capabilities of the AWS CloudFormation API. AWS CloudFormation clients implement this trait.
 #[async_trait]
 pub trait CloudFormation {
@@ -12570,6 +12973,10 @@ impl CloudFormation for CloudFormationClient {
         &self,
         input: CreateStackInstancesInput,
     ) -> Result<CreateStackInstancesOutput, RusotoError<CreateStackInstancesError>> {
+        let mut input = input;
+        if input.operation_id.is_none() {
+            input.operation_id = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
         let params = self.new_params("CreateStackInstances");
         let mut params = params;
@@ -12603,6 +13010,10 @@ impl CloudFormation for CloudFormationClient {
         &self,
         input: CreateStackSetInput,
     ) -> Result<CreateStackSetOutput, RusotoError<CreateStackSetError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
         let params = self.new_params("CreateStackSet");
         let mut params = params;
@@ -12676,6 +13087,10 @@ impl CloudFormation for CloudFormationClient {
         &self,
         input: DeleteStackInstancesInput,
     ) -> Result<DeleteStackInstancesOutput, RusotoError<DeleteStackInstancesError>> {
+        let mut input = input;
+        if input.operation_id.is_none() {
+            input.operation_id = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
         let params = self.new_params("DeleteStackInstances");
         let mut params = params;
@@ -13244,6 +13659,10 @@ impl CloudFormation for CloudFormationClient {
         &self,
         input: DetectStackSetDriftInput,
     ) -> Result<DetectStackSetDriftOutput, RusotoError<DetectStackSetDriftError>> {
+        let mut input = input;
+        if input.operation_id.is_none() {
+            input.operation_id = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
         let params = self.new_params("DetectStackSetDrift");
         let mut params = params;
@@ -13971,6 +14390,10 @@ impl CloudFormation for CloudFormationClient {
         &self,
         input: UpdateStackInstancesInput,
     ) -> Result<UpdateStackInstancesOutput, RusotoError<UpdateStackInstancesError>> {
+        let mut input = input;
+        if input.operation_id.is_none() {
+            input.operation_id = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
         let params = self.new_params("UpdateStackInstances");
         let mut params = params;
@@ -14004,6 +14427,10 @@ impl CloudFormation for CloudFormationClient {
         &self,
         input: UpdateStackSetInput,
     ) -> Result<UpdateStackSetOutput, RusotoError<UpdateStackSetError>> {
+        let mut input = input;
+        if input.operation_id.is_none() {
+            input.operation_id = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
         let params = self.new_params("UpdateStackSet");
         let mut params = params;
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -11438,6 +11438,22 @@ impl fmt::Display for CreateCachePolicyError {
     }
 }
 impl Error for CreateCachePolicyError {}
+impl ServiceErrorCode for CreateCachePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCachePolicyError::AccessDenied(_) => "AccessDenied",
+            CreateCachePolicyError::CachePolicyAlreadyExists(_) => "CachePolicyAlreadyExists",
+            CreateCachePolicyError::InconsistentQuantities(_) => "InconsistentQuantities",
+            CreateCachePolicyError::InvalidArgument(_) => "InvalidArgument",
+            CreateCachePolicyError::TooManyCachePolicies(_) => "TooManyCachePolicies",
+            CreateCachePolicyError::TooManyCookiesInCachePolicy(_) => "TooManyCookiesInCachePolicy",
+            CreateCachePolicyError::TooManyHeadersInCachePolicy(_) => "TooManyHeadersInCachePolicy",
+            CreateCachePolicyError::TooManyQueryStringsInCachePolicy(_) => {
+                "TooManyQueryStringsInCachePolicy"
+            }
+        }
+    }
+}
 /// Errors returned by CreateCloudFrontOriginAccessIdentity
 #[derive(Debug, PartialEq)]
 pub enum CreateCloudFrontOriginAccessIdentityError {
@@ -11491,6 +11507,17 @@ CreateCloudFrontOriginAccessIdentityError::TooManyCloudFrontOriginAccessIdentiti
     }
 }
 impl Error for CreateCloudFrontOriginAccessIdentityError {}
+impl ServiceErrorCode for CreateCloudFrontOriginAccessIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityAlreadyExists(_) => "CloudFrontOriginAccessIdentityAlreadyExists",
+            CreateCloudFrontOriginAccessIdentityError::InconsistentQuantities(_) => "InconsistentQuantities",
+            CreateCloudFrontOriginAccessIdentityError::InvalidArgument(_) => "InvalidArgument",
+            CreateCloudFrontOriginAccessIdentityError::MissingBody(_) => "MissingBody",
+            CreateCloudFrontOriginAccessIdentityError::TooManyCloudFrontOriginAccessIdentities(_) => "TooManyCloudFrontOriginAccessIdentities",
+        }
+    }
+}
 /// Errors returned by CreateDistribution
 #[derive(Debug, PartialEq)]
 pub enum CreateDistributionError {
@@ -11683,6 +11710,64 @@ CreateDistributionError::TrustedSignerDoesNotExist(ref cause) => write!(f, "{}",
     }
 }
 impl Error for CreateDistributionError {}
+impl ServiceErrorCode for CreateDistributionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDistributionError::AccessDenied(_) => "AccessDenied",
+            CreateDistributionError::CNAMEAlreadyExists(_) => "CNAMEAlreadyExists",
+            CreateDistributionError::DistributionAlreadyExists(_) => "DistributionAlreadyExists",
+            CreateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(_) => "IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior",
+            CreateDistributionError::InconsistentQuantities(_) => "InconsistentQuantities",
+            CreateDistributionError::InvalidArgument(_) => "InvalidArgument",
+            CreateDistributionError::InvalidDefaultRootObject(_) => "InvalidDefaultRootObject",
+            CreateDistributionError::InvalidErrorCode(_) => "InvalidErrorCode",
+            CreateDistributionError::InvalidForwardCookies(_) => "InvalidForwardCookies",
+            CreateDistributionError::InvalidGeoRestrictionParameter(_) => "InvalidGeoRestrictionParameter",
+            CreateDistributionError::InvalidHeadersForS3Origin(_) => "InvalidHeadersForS3Origin",
+            CreateDistributionError::InvalidLambdaFunctionAssociation(_) => "InvalidLambdaFunctionAssociation",
+            CreateDistributionError::InvalidLocationCode(_) => "InvalidLocationCode",
+            CreateDistributionError::InvalidMinimumProtocolVersion(_) => "InvalidMinimumProtocolVersion",
+            CreateDistributionError::InvalidOrigin(_) => "InvalidOrigin",
+            CreateDistributionError::InvalidOriginAccessIdentity(_) => "InvalidOriginAccessIdentity",
+            CreateDistributionError::InvalidOriginKeepaliveTimeout(_) => "InvalidOriginKeepaliveTimeout",
+            CreateDistributionError::InvalidOriginReadTimeout(_) => "InvalidOriginReadTimeout",
+            CreateDistributionError::InvalidProtocolSettings(_) => "InvalidProtocolSettings",
+            CreateDistributionError::InvalidQueryStringParameters(_) => "InvalidQueryStringParameters",
+            CreateDistributionError::InvalidRelativePath(_) => "InvalidRelativePath",
+            CreateDistributionError::InvalidRequiredProtocol(_) => "InvalidRequiredProtocol",
+            CreateDistributionError::InvalidResponseCode(_) => "InvalidResponseCode",
+            CreateDistributionError::InvalidTTLOrder(_) => "InvalidTTLOrder",
+            CreateDistributionError::InvalidViewerCertificate(_) => "InvalidViewerCertificate",
+            CreateDistributionError::InvalidWebACLId(_) => "InvalidWebACLId",
+            CreateDistributionError::MissingBody(_) => "MissingBody",
+            CreateDistributionError::NoSuchCachePolicy(_) => "NoSuchCachePolicy",
+            CreateDistributionError::NoSuchFieldLevelEncryptionConfig(_) => "NoSuchFieldLevelEncryptionConfig",
+            CreateDistributionError::NoSuchOrigin(_) => "NoSuchOrigin",
+            CreateDistributionError::NoSuchOriginRequestPolicy(_) => "NoSuchOriginRequestPolicy",
+            CreateDistributionError::TooManyCacheBehaviors(_) => "TooManyCacheBehaviors",
+            CreateDistributionError::TooManyCertificates(_) => "TooManyCertificates",
+            CreateDistributionError::TooManyCookieNamesInWhiteList(_) => "TooManyCookieNamesInWhiteList",
+            CreateDistributionError::TooManyDistributionCNAMEs(_) => "TooManyDistributionCNAMEs",
+            CreateDistributionError::TooManyDistributions(_) => "TooManyDistributions",
+            CreateDistributionError::TooManyDistributionsAssociatedToCachePolicy(_) => "TooManyDistributionsAssociatedToCachePolicy",
+            CreateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(_) => "TooManyDistributionsAssociatedToFieldLevelEncryptionConfig",
+            CreateDistributionError::TooManyDistributionsAssociatedToKeyGroup(_) => "TooManyDistributionsAssociatedToKeyGroup",
+            CreateDistributionError::TooManyDistributionsAssociatedToOriginRequestPolicy(_) => "TooManyDistributionsAssociatedToOriginRequestPolicy",
+            CreateDistributionError::TooManyDistributionsWithLambdaAssociations(_) => "TooManyDistributionsWithLambdaAssociations",
+            CreateDistributionError::TooManyDistributionsWithSingleFunctionARN(_) => "TooManyDistributionsWithSingleFunctionARN",
+            CreateDistributionError::TooManyHeadersInForwardedValues(_) => "TooManyHeadersInForwardedValues",
+            CreateDistributionError::TooManyKeyGroupsAssociatedToDistribution(_) => "TooManyKeyGroupsAssociatedToDistribution",
+            CreateDistributionError::TooManyLambdaFunctionAssociations(_) => "TooManyLambdaFunctionAssociations",
+            CreateDistributionError::TooManyOriginCustomHeaders(_) => "TooManyOriginCustomHeaders",
+            CreateDistributionError::TooManyOriginGroupsPerDistribution(_) => "TooManyOriginGroupsPerDistribution",
+            CreateDistributionError::TooManyOrigins(_) => "TooManyOrigins",
+            CreateDistributionError::TooManyQueryStringParameters(_) => "TooManyQueryStringParameters",
+            CreateDistributionError::TooManyTrustedSigners(_) => "TooManyTrustedSigners",
+            CreateDistributionError::TrustedKeyGroupDoesNotExist(_) => "TrustedKeyGroupDoesNotExist",
+            CreateDistributionError::TrustedSignerDoesNotExist(_) => "TrustedSignerDoesNotExist",
+        }
+    }
+}
 /// Errors returned by CreateDistributionWithTags
 #[derive(Debug, PartialEq)]
 pub enum CreateDistributionWithTagsError {
@@ -11880,6 +11965,65 @@ CreateDistributionWithTagsError::TrustedSignerDoesNotExist(ref cause) => write!(
     }
 }
 impl Error for CreateDistributionWithTagsError {}
+impl ServiceErrorCode for CreateDistributionWithTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDistributionWithTagsError::AccessDenied(_) => "AccessDenied",
+            CreateDistributionWithTagsError::CNAMEAlreadyExists(_) => "CNAMEAlreadyExists",
+            CreateDistributionWithTagsError::DistributionAlreadyExists(_) => "DistributionAlreadyExists",
+            CreateDistributionWithTagsError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(_) => "IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior",
+            CreateDistributionWithTagsError::InconsistentQuantities(_) => "InconsistentQuantities",
+            CreateDistributionWithTagsError::InvalidArgument(_) => "InvalidArgument",
+            CreateDistributionWithTagsError::InvalidDefaultRootObject(_) => "InvalidDefaultRootObject",
+            CreateDistributionWithTagsError::InvalidErrorCode(_) => "InvalidErrorCode",
+            CreateDistributionWithTagsError::InvalidForwardCookies(_) => "InvalidForwardCookies",
+            CreateDistributionWithTagsError::InvalidGeoRestrictionParameter(_) => "InvalidGeoRestrictionParameter",
+            CreateDistributionWithTagsError::InvalidHeadersForS3Origin(_) => "InvalidHeadersForS3Origin",
+            CreateDistributionWithTagsError::InvalidLambdaFunctionAssociation(_) => "InvalidLambdaFunctionAssociation",
+            CreateDistributionWithTagsError::InvalidLocationCode(_) => "InvalidLocationCode",
+            CreateDistributionWithTagsError::InvalidMinimumProtocolVersion(_) => "InvalidMinimumProtocolVersion",
+            CreateDistributionWithTagsError::InvalidOrigin(_) => "InvalidOrigin",
+            CreateDistributionWithTagsError::InvalidOriginAccessIdentity(_) => "InvalidOriginAccessIdentity",
+            CreateDistributionWithTagsError::InvalidOriginKeepaliveTimeout(_) => "InvalidOriginKeepaliveTimeout",
+            CreateDistributionWithTagsError::InvalidOriginReadTimeout(_) => "InvalidOriginReadTimeout",
+            CreateDistributionWithTagsError::InvalidProtocolSettings(_) => "InvalidProtocolSettings",
+            CreateDistributionWithTagsError::InvalidQueryStringParameters(_) => "InvalidQueryStringParameters",
+            CreateDistributionWithTagsError::InvalidRelativePath(_) => "InvalidRelativePath",
+            CreateDistributionWithTagsError::InvalidRequiredProtocol(_) => "InvalidRequiredProtocol",
+            CreateDistributionWithTagsError::InvalidResponseCode(_) => "InvalidResponseCode",
+            CreateDistributionWithTagsError::InvalidTTLOrder(_) => "InvalidTTLOrder",
+            CreateDistributionWithTagsError::InvalidTagging(_) => "InvalidTagging",
+            CreateDistributionWithTagsError::InvalidViewerCertificate(_) => "InvalidViewerCertificate",
+            CreateDistributionWithTagsError::InvalidWebACLId(_) => "InvalidWebACLId",
+            CreateDistributionWithTagsError::MissingBody(_) => "MissingBody",
+            CreateDistributionWithTagsError::NoSuchCachePolicy(_) => "NoSuchCachePolicy",
+            CreateDistributionWithTagsError::NoSuchFieldLevelEncryptionConfig(_) => "NoSuchFieldLevelEncryptionConfig",
+            CreateDistributionWithTagsError::NoSuchOrigin(_) => "NoSuchOrigin",
+            CreateDistributionWithTagsError::NoSuchOriginRequestPolicy(_) => "NoSuchOriginRequestPolicy",
+            CreateDistributionWithTagsError::TooManyCacheBehaviors(_) => "TooManyCacheBehaviors",
+            CreateDistributionWithTagsError::TooManyCertificates(_) => "TooManyCertificates",
+            CreateDistributionWithTagsError::TooManyCookieNamesInWhiteList(_) => "TooManyCookieNamesInWhiteList",
+            CreateDistributionWithTagsError::TooManyDistributionCNAMEs(_) => "TooManyDistributionCNAMEs",
+            CreateDistributionWithTagsError::TooManyDistributions(_) => "TooManyDistributions",
+            CreateDistributionWithTagsError::TooManyDistributionsAssociatedToCachePolicy(_) => "TooManyDistributionsAssociatedToCachePolicy",
+            CreateDistributionWithTagsError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(_) => "TooManyDistributionsAssociatedToFieldLevelEncryptionConfig",
+            CreateDistributionWithTagsError::TooManyDistributionsAssociatedToKeyGroup(_) => "TooManyDistributionsAssociatedToKeyGroup",
+            CreateDistributionWithTagsError::TooManyDistributionsAssociatedToOriginRequestPolicy(_) => "TooManyDistributionsAssociatedToOriginRequestPolicy",
+            CreateDistributionWithTagsError::TooManyDistributionsWithLambdaAssociations(_) => "TooManyDistributionsWithLambdaAssociations",
+            CreateDistributionWithTagsError::TooManyDistributionsWithSingleFunctionARN(_) => "TooManyDistributionsWithSingleFunctionARN",
+            CreateDistributionWithTagsError::TooManyHeadersInForwardedValues(_) => "TooManyHeadersInForwardedValues",
+            CreateDistributionWithTagsError::TooManyKeyGroupsAssociatedToDistribution(_) => "TooManyKeyGroupsAssociatedToDistribution",
+            CreateDistributionWithTagsError::TooManyLambdaFunctionAssociations(_) => "TooManyLambdaFunctionAssociations",
+            CreateDistributionWithTagsError::TooManyOriginCustomHeaders(_) => "TooManyOriginCustomHeaders",
+            CreateDistributionWithTagsError::TooManyOriginGroupsPerDistribution(_) => "TooManyOriginGroupsPerDistribution",
+            CreateDistributionWithTagsError::TooManyOrigins(_) => "TooManyOrigins",
+            CreateDistributionWithTagsError::TooManyQueryStringParameters(_) => "TooManyQueryStringParameters",
+            CreateDistributionWithTagsError::TooManyTrustedSigners(_) => "TooManyTrustedSigners",
+            CreateDistributionWithTagsError::TrustedKeyGroupDoesNotExist(_) => "TrustedKeyGroupDoesNotExist",
+            CreateDistributionWithTagsError::TrustedSignerDoesNotExist(_) => "TrustedSignerDoesNotExist",
+        }
+    }
+}
 /// Errors returned by CreateFieldLevelEncryptionConfig
 #[derive(Debug, PartialEq)]
 pub enum CreateFieldLevelEncryptionConfigError {
@@ -11942,6 +12086,20 @@ CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfil
     }
 }
 impl Error for CreateFieldLevelEncryptionConfigError {}
+impl ServiceErrorCode for CreateFieldLevelEncryptionConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigAlreadyExists(_) => "FieldLevelEncryptionConfigAlreadyExists",
+            CreateFieldLevelEncryptionConfigError::InconsistentQuantities(_) => "InconsistentQuantities",
+            CreateFieldLevelEncryptionConfigError::InvalidArgument(_) => "InvalidArgument",
+            CreateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(_) => "NoSuchFieldLevelEncryptionProfile",
+            CreateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(_) => "QueryArgProfileEmpty",
+            CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionConfigs(_) => "TooManyFieldLevelEncryptionConfigs",
+            CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(_) => "TooManyFieldLevelEncryptionContentTypeProfiles",
+            CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(_) => "TooManyFieldLevelEncryptionQueryArgProfiles",
+        }
+    }
+}
 /// Errors returned by CreateFieldLevelEncryptionProfile
 #[derive(Debug, PartialEq)]
 pub enum CreateFieldLevelEncryptionProfileError {
@@ -12004,6 +12162,20 @@ CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionProfiles(ref
     }
 }
 impl Error for CreateFieldLevelEncryptionProfileError {}
+impl ServiceErrorCode for CreateFieldLevelEncryptionProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(_) => "FieldLevelEncryptionProfileAlreadyExists",
+            CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(_) => "FieldLevelEncryptionProfileSizeExceeded",
+            CreateFieldLevelEncryptionProfileError::InconsistentQuantities(_) => "InconsistentQuantities",
+            CreateFieldLevelEncryptionProfileError::InvalidArgument(_) => "InvalidArgument",
+            CreateFieldLevelEncryptionProfileError::NoSuchPublicKey(_) => "NoSuchPublicKey",
+            CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(_) => "TooManyFieldLevelEncryptionEncryptionEntities",
+            CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(_) => "TooManyFieldLevelEncryptionFieldPatterns",
+            CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionProfiles(_) => "TooManyFieldLevelEncryptionProfiles",
+        }
+    }
+}
 /// Errors returned by CreateInvalidation
 #[derive(Debug, PartialEq)]
 pub enum CreateInvalidationError {
@@ -12100,6 +12272,21 @@ impl fmt::Display for CreateInvalidationError {
     }
 }
 impl Error for CreateInvalidationError {}
+impl ServiceErrorCode for CreateInvalidationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateInvalidationError::AccessDenied(_) => "AccessDenied",
+            CreateInvalidationError::BatchTooLarge(_) => "BatchTooLarge",
+            CreateInvalidationError::InconsistentQuantities(_) => "InconsistentQuantities",
+            CreateInvalidationError::InvalidArgument(_) => "InvalidArgument",
+            CreateInvalidationError::MissingBody(_) => "MissingBody",
+            CreateInvalidationError::NoSuchDistribution(_) => "NoSuchDistribution",
+            CreateInvalidationError::TooManyInvalidationsInProgress(_) => {
+                "TooManyInvalidationsInProgress"
+            }
+        }
+    }
+}
 /// Errors returned by CreateKeyGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateKeyGroupError {
@@ -12168,6 +12355,16 @@ impl fmt::Display for CreateKeyGroupError {
     }
 }
 impl Error for CreateKeyGroupError {}
+impl ServiceErrorCode for CreateKeyGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateKeyGroupError::InvalidArgument(_) => "InvalidArgument",
+            CreateKeyGroupError::KeyGroupAlreadyExists(_) => "KeyGroupAlreadyExists",
+            CreateKeyGroupError::TooManyKeyGroups(_) => "TooManyKeyGroups",
+            CreateKeyGroupError::TooManyPublicKeysInKeyGroup(_) => "TooManyPublicKeysInKeyGroup",
+        }
+    }
+}
 /// Errors returned by CreateMonitoringSubscription
 #[derive(Debug, PartialEq)]
 pub enum CreateMonitoringSubscriptionError {
@@ -12226,6 +12423,14 @@ impl fmt::Display for CreateMonitoringSubscriptionError {
     }
 }
 impl Error for CreateMonitoringSubscriptionError {}
+impl ServiceErrorCode for CreateMonitoringSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMonitoringSubscriptionError::AccessDenied(_) => "AccessDenied",
+            CreateMonitoringSubscriptionError::NoSuchDistribution(_) => "NoSuchDistribution",
+        }
+    }
+}
 /// Errors returned by CreateOriginRequestPolicy
 #[derive(Debug, PartialEq)]
 pub enum CreateOriginRequestPolicyError {
@@ -12348,6 +12553,30 @@ impl fmt::Display for CreateOriginRequestPolicyError {
     }
 }
 impl Error for CreateOriginRequestPolicyError {}
+impl ServiceErrorCode for CreateOriginRequestPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateOriginRequestPolicyError::AccessDenied(_) => "AccessDenied",
+            CreateOriginRequestPolicyError::InconsistentQuantities(_) => "InconsistentQuantities",
+            CreateOriginRequestPolicyError::InvalidArgument(_) => "InvalidArgument",
+            CreateOriginRequestPolicyError::OriginRequestPolicyAlreadyExists(_) => {
+                "OriginRequestPolicyAlreadyExists"
+            }
+            CreateOriginRequestPolicyError::TooManyCookiesInOriginRequestPolicy(_) => {
+                "TooManyCookiesInOriginRequestPolicy"
+            }
+            CreateOriginRequestPolicyError::TooManyHeadersInOriginRequestPolicy(_) => {
+                "TooManyHeadersInOriginRequestPolicy"
+            }
+            CreateOriginRequestPolicyError::TooManyOriginRequestPolicies(_) => {
+                "TooManyOriginRequestPolicies"
+            }
+            CreateOriginRequestPolicyError::TooManyQueryStringsInOriginRequestPolicy(_) => {
+                "TooManyQueryStringsInOriginRequestPolicy"
+            }
+        }
+    }
+}
 /// Errors returned by CreatePublicKey
 #[derive(Debug, PartialEq)]
 pub enum CreatePublicKeyError {
@@ -12408,6 +12637,15 @@ impl fmt::Display for CreatePublicKeyError {
     }
 }
 impl Error for CreatePublicKeyError {}
+impl ServiceErrorCode for CreatePublicKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePublicKeyError::InvalidArgument(_) => "InvalidArgument",
+            CreatePublicKeyError::PublicKeyAlreadyExists(_) => "PublicKeyAlreadyExists",
+            CreatePublicKeyError::TooManyPublicKeys(_) => "TooManyPublicKeys",
+        }
+    }
+}
 /// Errors returned by CreateRealtimeLogConfig
 #[derive(Debug, PartialEq)]
 pub enum CreateRealtimeLogConfigError {
@@ -12484,6 +12722,20 @@ impl fmt::Display for CreateRealtimeLogConfigError {
     }
 }
 impl Error for CreateRealtimeLogConfigError {}
+impl ServiceErrorCode for CreateRealtimeLogConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRealtimeLogConfigError::AccessDenied(_) => "AccessDenied",
+            CreateRealtimeLogConfigError::InvalidArgument(_) => "InvalidArgument",
+            CreateRealtimeLogConfigError::RealtimeLogConfigAlreadyExists(_) => {
+                "RealtimeLogConfigAlreadyExists"
+            }
+            CreateRealtimeLogConfigError::TooManyRealtimeLogConfigs(_) => {
+                "TooManyRealtimeLogConfigs"
+            }
+        }
+    }
+}
 /// Errors returned by CreateStreamingDistribution
 #[derive(Debug, PartialEq)]
 pub enum CreateStreamingDistributionError {
@@ -12650,6 +12902,34 @@ impl fmt::Display for CreateStreamingDistributionError {
     }
 }
 impl Error for CreateStreamingDistributionError {}
+impl ServiceErrorCode for CreateStreamingDistributionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStreamingDistributionError::AccessDenied(_) => "AccessDenied",
+            CreateStreamingDistributionError::CNAMEAlreadyExists(_) => "CNAMEAlreadyExists",
+            CreateStreamingDistributionError::InconsistentQuantities(_) => "InconsistentQuantities",
+            CreateStreamingDistributionError::InvalidArgument(_) => "InvalidArgument",
+            CreateStreamingDistributionError::InvalidOrigin(_) => "InvalidOrigin",
+            CreateStreamingDistributionError::InvalidOriginAccessIdentity(_) => {
+                "InvalidOriginAccessIdentity"
+            }
+            CreateStreamingDistributionError::MissingBody(_) => "MissingBody",
+            CreateStreamingDistributionError::StreamingDistributionAlreadyExists(_) => {
+                "StreamingDistributionAlreadyExists"
+            }
+            CreateStreamingDistributionError::TooManyStreamingDistributionCNAMEs(_) => {
+                "TooManyStreamingDistributionCNAMEs"
+            }
+            CreateStreamingDistributionError::TooManyStreamingDistributions(_) => {
+                "TooManyStreamingDistributions"
+            }
+            CreateStreamingDistributionError::TooManyTrustedSigners(_) => "TooManyTrustedSigners",
+            CreateStreamingDistributionError::TrustedSignerDoesNotExist(_) => {
+                "TrustedSignerDoesNotExist"
+            }
+        }
+    }
+}
 /// Errors returned by CreateStreamingDistributionWithTags
 #[derive(Debug, PartialEq)]
 pub enum CreateStreamingDistributionWithTagsError {
@@ -12753,6 +13033,39 @@ impl fmt::Display for CreateStreamingDistributionWithTagsError {
     }
 }
 impl Error for CreateStreamingDistributionWithTagsError {}
+impl ServiceErrorCode for CreateStreamingDistributionWithTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStreamingDistributionWithTagsError::AccessDenied(_) => "AccessDenied",
+            CreateStreamingDistributionWithTagsError::CNAMEAlreadyExists(_) => "CNAMEAlreadyExists",
+            CreateStreamingDistributionWithTagsError::InconsistentQuantities(_) => {
+                "InconsistentQuantities"
+            }
+            CreateStreamingDistributionWithTagsError::InvalidArgument(_) => "InvalidArgument",
+            CreateStreamingDistributionWithTagsError::InvalidOrigin(_) => "InvalidOrigin",
+            CreateStreamingDistributionWithTagsError::InvalidOriginAccessIdentity(_) => {
+                "InvalidOriginAccessIdentity"
+            }
+            CreateStreamingDistributionWithTagsError::InvalidTagging(_) => "InvalidTagging",
+            CreateStreamingDistributionWithTagsError::MissingBody(_) => "MissingBody",
+            CreateStreamingDistributionWithTagsError::StreamingDistributionAlreadyExists(_) => {
+                "StreamingDistributionAlreadyExists"
+            }
+            CreateStreamingDistributionWithTagsError::TooManyStreamingDistributionCNAMEs(_) => {
+                "TooManyStreamingDistributionCNAMEs"
+            }
+            CreateStreamingDistributionWithTagsError::TooManyStreamingDistributions(_) => {
+                "TooManyStreamingDistributions"
+            }
+            CreateStreamingDistributionWithTagsError::TooManyTrustedSigners(_) => {
+                "TooManyTrustedSigners"
+            }
+            CreateStreamingDistributionWithTagsError::TrustedSignerDoesNotExist(_) => {
+                "TrustedSignerDoesNotExist"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteCachePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteCachePolicyError {
@@ -12837,6 +13150,18 @@ impl fmt::Display for DeleteCachePolicyError {
     }
 }
 impl Error for DeleteCachePolicyError {}
+impl ServiceErrorCode for DeleteCachePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCachePolicyError::AccessDenied(_) => "AccessDenied",
+            DeleteCachePolicyError::CachePolicyInUse(_) => "CachePolicyInUse",
+            DeleteCachePolicyError::IllegalDelete(_) => "IllegalDelete",
+            DeleteCachePolicyError::InvalidIfMatchVersion(_) => "InvalidIfMatchVersion",
+            DeleteCachePolicyError::NoSuchCachePolicy(_) => "NoSuchCachePolicy",
+            DeleteCachePolicyError::PreconditionFailed(_) => "PreconditionFailed",
+        }
+    }
+}
 /// Errors returned by DeleteCloudFrontOriginAccessIdentity
 #[derive(Debug, PartialEq)]
 pub enum DeleteCloudFrontOriginAccessIdentityError {
@@ -12900,6 +13225,25 @@ impl fmt::Display for DeleteCloudFrontOriginAccessIdentityError {
     }
 }
 impl Error for DeleteCloudFrontOriginAccessIdentityError {}
+impl ServiceErrorCode for DeleteCloudFrontOriginAccessIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCloudFrontOriginAccessIdentityError::AccessDenied(_) => "AccessDenied",
+            DeleteCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityInUse(_) => {
+                "CloudFrontOriginAccessIdentityInUse"
+            }
+            DeleteCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(_) => {
+                "InvalidIfMatchVersion"
+            }
+            DeleteCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(_) => {
+                "NoSuchCloudFrontOriginAccessIdentity"
+            }
+            DeleteCloudFrontOriginAccessIdentityError::PreconditionFailed(_) => {
+                "PreconditionFailed"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDistribution
 #[derive(Debug, PartialEq)]
 pub enum DeleteDistributionError {
@@ -12976,6 +13320,17 @@ impl fmt::Display for DeleteDistributionError {
     }
 }
 impl Error for DeleteDistributionError {}
+impl ServiceErrorCode for DeleteDistributionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDistributionError::AccessDenied(_) => "AccessDenied",
+            DeleteDistributionError::DistributionNotDisabled(_) => "DistributionNotDisabled",
+            DeleteDistributionError::InvalidIfMatchVersion(_) => "InvalidIfMatchVersion",
+            DeleteDistributionError::NoSuchDistribution(_) => "NoSuchDistribution",
+            DeleteDistributionError::PreconditionFailed(_) => "PreconditionFailed",
+        }
+    }
+}
 /// Errors returned by DeleteFieldLevelEncryptionConfig
 #[derive(Debug, PartialEq)]
 pub enum DeleteFieldLevelEncryptionConfigError {
@@ -13074,6 +13429,23 @@ impl fmt::Display for DeleteFieldLevelEncryptionConfigError {
     }
 }
 impl Error for DeleteFieldLevelEncryptionConfigError {}
+impl ServiceErrorCode for DeleteFieldLevelEncryptionConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFieldLevelEncryptionConfigError::AccessDenied(_) => "AccessDenied",
+            DeleteFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigInUse(_) => {
+                "FieldLevelEncryptionConfigInUse"
+            }
+            DeleteFieldLevelEncryptionConfigError::InvalidIfMatchVersion(_) => {
+                "InvalidIfMatchVersion"
+            }
+            DeleteFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(_) => {
+                "NoSuchFieldLevelEncryptionConfig"
+            }
+            DeleteFieldLevelEncryptionConfigError::PreconditionFailed(_) => "PreconditionFailed",
+        }
+    }
+}
 /// Errors returned by DeleteFieldLevelEncryptionProfile
 #[derive(Debug, PartialEq)]
 pub enum DeleteFieldLevelEncryptionProfileError {
@@ -13168,6 +13540,23 @@ impl fmt::Display for DeleteFieldLevelEncryptionProfileError {
     }
 }
 impl Error for DeleteFieldLevelEncryptionProfileError {}
+impl ServiceErrorCode for DeleteFieldLevelEncryptionProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFieldLevelEncryptionProfileError::AccessDenied(_) => "AccessDenied",
+            DeleteFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileInUse(_) => {
+                "FieldLevelEncryptionProfileInUse"
+            }
+            DeleteFieldLevelEncryptionProfileError::InvalidIfMatchVersion(_) => {
+                "InvalidIfMatchVersion"
+            }
+            DeleteFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(_) => {
+                "NoSuchFieldLevelEncryptionProfile"
+            }
+            DeleteFieldLevelEncryptionProfileError::PreconditionFailed(_) => "PreconditionFailed",
+        }
+    }
+}
 /// Errors returned by DeleteKeyGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteKeyGroupError {
@@ -13236,6 +13625,16 @@ impl fmt::Display for DeleteKeyGroupError {
     }
 }
 impl Error for DeleteKeyGroupError {}
+impl ServiceErrorCode for DeleteKeyGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteKeyGroupError::InvalidIfMatchVersion(_) => "InvalidIfMatchVersion",
+            DeleteKeyGroupError::NoSuchResource(_) => "NoSuchResource",
+            DeleteKeyGroupError::PreconditionFailed(_) => "PreconditionFailed",
+            DeleteKeyGroupError::ResourceInUse(_) => "ResourceInUse",
+        }
+    }
+}
 /// Errors returned by DeleteMonitoringSubscription
 #[derive(Debug, PartialEq)]
 pub enum DeleteMonitoringSubscriptionError {
@@ -13294,6 +13693,14 @@ impl fmt::Display for DeleteMonitoringSubscriptionError {
     }
 }
 impl Error for DeleteMonitoringSubscriptionError {}
+impl ServiceErrorCode for DeleteMonitoringSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMonitoringSubscriptionError::AccessDenied(_) => "AccessDenied",
+            DeleteMonitoringSubscriptionError::NoSuchDistribution(_) => "NoSuchDistribution",
+        }
+    }
+}
 /// Errors returned by DeleteOriginRequestPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteOriginRequestPolicyError {
@@ -13392,6 +13799,22 @@ impl fmt::Display for DeleteOriginRequestPolicyError {
     }
 }
 impl Error for DeleteOriginRequestPolicyError {}
+impl ServiceErrorCode for DeleteOriginRequestPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteOriginRequestPolicyError::AccessDenied(_) => "AccessDenied",
+            DeleteOriginRequestPolicyError::IllegalDelete(_) => "IllegalDelete",
+            DeleteOriginRequestPolicyError::InvalidIfMatchVersion(_) => "InvalidIfMatchVersion",
+            DeleteOriginRequestPolicyError::NoSuchOriginRequestPolicy(_) => {
+                "NoSuchOriginRequestPolicy"
+            }
+            DeleteOriginRequestPolicyError::OriginRequestPolicyInUse(_) => {
+                "OriginRequestPolicyInUse"
+            }
+            DeleteOriginRequestPolicyError::PreconditionFailed(_) => "PreconditionFailed",
+        }
+    }
+}
 /// Errors returned by DeletePublicKey
 #[derive(Debug, PartialEq)]
 pub enum DeletePublicKeyError {
@@ -13468,6 +13891,17 @@ impl fmt::Display for DeletePublicKeyError {
     }
 }
 impl Error for DeletePublicKeyError {}
+impl ServiceErrorCode for DeletePublicKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePublicKeyError::AccessDenied(_) => "AccessDenied",
+            DeletePublicKeyError::InvalidIfMatchVersion(_) => "InvalidIfMatchVersion",
+            DeletePublicKeyError::NoSuchPublicKey(_) => "NoSuchPublicKey",
+            DeletePublicKeyError::PreconditionFailed(_) => "PreconditionFailed",
+            DeletePublicKeyError::PublicKeyInUse(_) => "PublicKeyInUse",
+        }
+    }
+}
 /// Errors returned by DeleteRealtimeLogConfig
 #[derive(Debug, PartialEq)]
 pub enum DeleteRealtimeLogConfigError {
@@ -13544,6 +13978,16 @@ impl fmt::Display for DeleteRealtimeLogConfigError {
     }
 }
 impl Error for DeleteRealtimeLogConfigError {}
+impl ServiceErrorCode for DeleteRealtimeLogConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRealtimeLogConfigError::AccessDenied(_) => "AccessDenied",
+            DeleteRealtimeLogConfigError::InvalidArgument(_) => "InvalidArgument",
+            DeleteRealtimeLogConfigError::NoSuchRealtimeLogConfig(_) => "NoSuchRealtimeLogConfig",
+            DeleteRealtimeLogConfigError::RealtimeLogConfigInUse(_) => "RealtimeLogConfigInUse",
+        }
+    }
+}
 /// Errors returned by DeleteStreamingDistribution
 #[derive(Debug, PartialEq)]
 pub enum DeleteStreamingDistributionError {
@@ -13638,6 +14082,21 @@ impl fmt::Display for DeleteStreamingDistributionError {
     }
 }
 impl Error for DeleteStreamingDistributionError {}
+impl ServiceErrorCode for DeleteStreamingDistributionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStreamingDistributionError::AccessDenied(_) => "AccessDenied",
+            DeleteStreamingDistributionError::InvalidIfMatchVersion(_) => "InvalidIfMatchVersion",
+            DeleteStreamingDistributionError::NoSuchStreamingDistribution(_) => {
+                "NoSuchStreamingDistribution"
+            }
+            DeleteStreamingDistributionError::PreconditionFailed(_) => "PreconditionFailed",
+            DeleteStreamingDistributionError::StreamingDistributionNotDisabled(_) => {
+                "StreamingDistributionNotDisabled"
+            }
+        }
+    }
+}
 /// Errors returned by GetCachePolicy
 #[derive(Debug, PartialEq)]
 pub enum GetCachePolicyError {
@@ -13690,6 +14149,14 @@ impl fmt::Display for GetCachePolicyError {
     }
 }
 impl Error for GetCachePolicyError {}
+impl ServiceErrorCode for GetCachePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCachePolicyError::AccessDenied(_) => "AccessDenied",
+            GetCachePolicyError::NoSuchCachePolicy(_) => "NoSuchCachePolicy",
+        }
+    }
+}
 /// Errors returned by GetCachePolicyConfig
 #[derive(Debug, PartialEq)]
 pub enum GetCachePolicyConfigError {
@@ -13742,6 +14209,14 @@ impl fmt::Display for GetCachePolicyConfigError {
     }
 }
 impl Error for GetCachePolicyConfigError {}
+impl ServiceErrorCode for GetCachePolicyConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCachePolicyConfigError::AccessDenied(_) => "AccessDenied",
+            GetCachePolicyConfigError::NoSuchCachePolicy(_) => "NoSuchCachePolicy",
+        }
+    }
+}
 /// Errors returned by GetCloudFrontOriginAccessIdentity
 #[derive(Debug, PartialEq)]
 pub enum GetCloudFrontOriginAccessIdentityError {
@@ -13790,6 +14265,16 @@ impl fmt::Display for GetCloudFrontOriginAccessIdentityError {
     }
 }
 impl Error for GetCloudFrontOriginAccessIdentityError {}
+impl ServiceErrorCode for GetCloudFrontOriginAccessIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCloudFrontOriginAccessIdentityError::AccessDenied(_) => "AccessDenied",
+            GetCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(_) => {
+                "NoSuchCloudFrontOriginAccessIdentity"
+            }
+        }
+    }
+}
 /// Errors returned by GetCloudFrontOriginAccessIdentityConfig
 #[derive(Debug, PartialEq)]
 pub enum GetCloudFrontOriginAccessIdentityConfigError {
@@ -13838,6 +14323,16 @@ impl fmt::Display for GetCloudFrontOriginAccessIdentityConfigError {
     }
 }
 impl Error for GetCloudFrontOriginAccessIdentityConfigError {}
+impl ServiceErrorCode for GetCloudFrontOriginAccessIdentityConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCloudFrontOriginAccessIdentityConfigError::AccessDenied(_) => "AccessDenied",
+            GetCloudFrontOriginAccessIdentityConfigError::NoSuchCloudFrontOriginAccessIdentity(
+                _,
+            ) => "NoSuchCloudFrontOriginAccessIdentity",
+        }
+    }
+}
 /// Errors returned by GetDistribution
 #[derive(Debug, PartialEq)]
 pub enum GetDistributionError {
@@ -13890,6 +14385,14 @@ impl fmt::Display for GetDistributionError {
     }
 }
 impl Error for GetDistributionError {}
+impl ServiceErrorCode for GetDistributionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDistributionError::AccessDenied(_) => "AccessDenied",
+            GetDistributionError::NoSuchDistribution(_) => "NoSuchDistribution",
+        }
+    }
+}
 /// Errors returned by GetDistributionConfig
 #[derive(Debug, PartialEq)]
 pub enum GetDistributionConfigError {
@@ -13942,6 +14445,14 @@ impl fmt::Display for GetDistributionConfigError {
     }
 }
 impl Error for GetDistributionConfigError {}
+impl ServiceErrorCode for GetDistributionConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDistributionConfigError::AccessDenied(_) => "AccessDenied",
+            GetDistributionConfigError::NoSuchDistribution(_) => "NoSuchDistribution",
+        }
+    }
+}
 /// Errors returned by GetFieldLevelEncryption
 #[derive(Debug, PartialEq)]
 pub enum GetFieldLevelEncryptionError {
@@ -13998,6 +14509,16 @@ impl fmt::Display for GetFieldLevelEncryptionError {
     }
 }
 impl Error for GetFieldLevelEncryptionError {}
+impl ServiceErrorCode for GetFieldLevelEncryptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFieldLevelEncryptionError::AccessDenied(_) => "AccessDenied",
+            GetFieldLevelEncryptionError::NoSuchFieldLevelEncryptionConfig(_) => {
+                "NoSuchFieldLevelEncryptionConfig"
+            }
+        }
+    }
+}
 /// Errors returned by GetFieldLevelEncryptionConfig
 #[derive(Debug, PartialEq)]
 pub enum GetFieldLevelEncryptionConfigError {
@@ -14056,6 +14577,16 @@ impl fmt::Display for GetFieldLevelEncryptionConfigError {
     }
 }
 impl Error for GetFieldLevelEncryptionConfigError {}
+impl ServiceErrorCode for GetFieldLevelEncryptionConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFieldLevelEncryptionConfigError::AccessDenied(_) => "AccessDenied",
+            GetFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(_) => {
+                "NoSuchFieldLevelEncryptionConfig"
+            }
+        }
+    }
+}
 /// Errors returned by GetFieldLevelEncryptionProfile
 #[derive(Debug, PartialEq)]
 pub enum GetFieldLevelEncryptionProfileError {
@@ -14114,6 +14645,16 @@ impl fmt::Display for GetFieldLevelEncryptionProfileError {
     }
 }
 impl Error for GetFieldLevelEncryptionProfileError {}
+impl ServiceErrorCode for GetFieldLevelEncryptionProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFieldLevelEncryptionProfileError::AccessDenied(_) => "AccessDenied",
+            GetFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(_) => {
+                "NoSuchFieldLevelEncryptionProfile"
+            }
+        }
+    }
+}
 /// Errors returned by GetFieldLevelEncryptionProfileConfig
 #[derive(Debug, PartialEq)]
 pub enum GetFieldLevelEncryptionProfileConfigError {
@@ -14162,6 +14703,16 @@ impl fmt::Display for GetFieldLevelEncryptionProfileConfigError {
     }
 }
 impl Error for GetFieldLevelEncryptionProfileConfigError {}
+impl ServiceErrorCode for GetFieldLevelEncryptionProfileConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFieldLevelEncryptionProfileConfigError::AccessDenied(_) => "AccessDenied",
+            GetFieldLevelEncryptionProfileConfigError::NoSuchFieldLevelEncryptionProfile(_) => {
+                "NoSuchFieldLevelEncryptionProfile"
+            }
+        }
+    }
+}
 /// Errors returned by GetInvalidation
 #[derive(Debug, PartialEq)]
 pub enum GetInvalidationError {
@@ -14222,6 +14773,15 @@ impl fmt::Display for GetInvalidationError {
     }
 }
 impl Error for GetInvalidationError {}
+impl ServiceErrorCode for GetInvalidationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInvalidationError::AccessDenied(_) => "AccessDenied",
+            GetInvalidationError::NoSuchDistribution(_) => "NoSuchDistribution",
+            GetInvalidationError::NoSuchInvalidation(_) => "NoSuchInvalidation",
+        }
+    }
+}
 /// Errors returned by GetKeyGroup
 #[derive(Debug, PartialEq)]
 pub enum GetKeyGroupError {
@@ -14266,6 +14826,13 @@ impl fmt::Display for GetKeyGroupError {
     }
 }
 impl Error for GetKeyGroupError {}
+impl ServiceErrorCode for GetKeyGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetKeyGroupError::NoSuchResource(_) => "NoSuchResource",
+        }
+    }
+}
 /// Errors returned by GetKeyGroupConfig
 #[derive(Debug, PartialEq)]
 pub enum GetKeyGroupConfigError {
@@ -14310,6 +14877,13 @@ impl fmt::Display for GetKeyGroupConfigError {
     }
 }
 impl Error for GetKeyGroupConfigError {}
+impl ServiceErrorCode for GetKeyGroupConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetKeyGroupConfigError::NoSuchResource(_) => "NoSuchResource",
+        }
+    }
+}
 /// Errors returned by GetMonitoringSubscription
 #[derive(Debug, PartialEq)]
 pub enum GetMonitoringSubscriptionError {
@@ -14364,6 +14938,14 @@ impl fmt::Display for GetMonitoringSubscriptionError {
     }
 }
 impl Error for GetMonitoringSubscriptionError {}
+impl ServiceErrorCode for GetMonitoringSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMonitoringSubscriptionError::AccessDenied(_) => "AccessDenied",
+            GetMonitoringSubscriptionError::NoSuchDistribution(_) => "NoSuchDistribution",
+        }
+    }
+}
 /// Errors returned by GetOriginRequestPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetOriginRequestPolicyError {
@@ -14420,6 +15002,16 @@ impl fmt::Display for GetOriginRequestPolicyError {
     }
 }
 impl Error for GetOriginRequestPolicyError {}
+impl ServiceErrorCode for GetOriginRequestPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOriginRequestPolicyError::AccessDenied(_) => "AccessDenied",
+            GetOriginRequestPolicyError::NoSuchOriginRequestPolicy(_) => {
+                "NoSuchOriginRequestPolicy"
+            }
+        }
+    }
+}
 /// Errors returned by GetOriginRequestPolicyConfig
 #[derive(Debug, PartialEq)]
 pub enum GetOriginRequestPolicyConfigError {
@@ -14478,6 +15070,16 @@ impl fmt::Display for GetOriginRequestPolicyConfigError {
     }
 }
 impl Error for GetOriginRequestPolicyConfigError {}
+impl ServiceErrorCode for GetOriginRequestPolicyConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOriginRequestPolicyConfigError::AccessDenied(_) => "AccessDenied",
+            GetOriginRequestPolicyConfigError::NoSuchOriginRequestPolicy(_) => {
+                "NoSuchOriginRequestPolicy"
+            }
+        }
+    }
+}
 /// Errors returned by GetPublicKey
 #[derive(Debug, PartialEq)]
 pub enum GetPublicKeyError {
@@ -14530,6 +15132,14 @@ impl fmt::Display for GetPublicKeyError {
     }
 }
 impl Error for GetPublicKeyError {}
+impl ServiceErrorCode for GetPublicKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPublicKeyError::AccessDenied(_) => "AccessDenied",
+            GetPublicKeyError::NoSuchPublicKey(_) => "NoSuchPublicKey",
+        }
+    }
+}
 /// Errors returned by GetPublicKeyConfig
 #[derive(Debug, PartialEq)]
 pub enum GetPublicKeyConfigError {
@@ -14582,6 +15192,14 @@ impl fmt::Display for GetPublicKeyConfigError {
     }
 }
 impl Error for GetPublicKeyConfigError {}
+impl ServiceErrorCode for GetPublicKeyConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPublicKeyConfigError::AccessDenied(_) => "AccessDenied",
+            GetPublicKeyConfigError::NoSuchPublicKey(_) => "NoSuchPublicKey",
+        }
+    }
+}
 /// Errors returned by GetRealtimeLogConfig
 #[derive(Debug, PartialEq)]
 pub enum GetRealtimeLogConfigError {
@@ -14644,6 +15262,15 @@ impl fmt::Display for GetRealtimeLogConfigError {
     }
 }
 impl Error for GetRealtimeLogConfigError {}
+impl ServiceErrorCode for GetRealtimeLogConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRealtimeLogConfigError::AccessDenied(_) => "AccessDenied",
+            GetRealtimeLogConfigError::InvalidArgument(_) => "InvalidArgument",
+            GetRealtimeLogConfigError::NoSuchRealtimeLogConfig(_) => "NoSuchRealtimeLogConfig",
+        }
+    }
+}
 /// Errors returned by GetStreamingDistribution
 #[derive(Debug, PartialEq)]
 pub enum GetStreamingDistributionError {
@@ -14700,6 +15327,16 @@ impl fmt::Display for GetStreamingDistributionError {
     }
 }
 impl Error for GetStreamingDistributionError {}
+impl ServiceErrorCode for GetStreamingDistributionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetStreamingDistributionError::AccessDenied(_) => "AccessDenied",
+            GetStreamingDistributionError::NoSuchStreamingDistribution(_) => {
+                "NoSuchStreamingDistribution"
+            }
+        }
+    }
+}
 /// Errors returned by GetStreamingDistributionConfig
 #[derive(Debug, PartialEq)]
 pub enum GetStreamingDistributionConfigError {
@@ -14758,6 +15395,16 @@ impl fmt::Display for GetStreamingDistributionConfigError {
     }
 }
 impl Error for GetStreamingDistributionConfigError {}
+impl ServiceErrorCode for GetStreamingDistributionConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetStreamingDistributionConfigError::AccessDenied(_) => "AccessDenied",
+            GetStreamingDistributionConfigError::NoSuchStreamingDistribution(_) => {
+                "NoSuchStreamingDistribution"
+            }
+        }
+    }
+}
 /// Errors returned by ListCachePolicies
 #[derive(Debug, PartialEq)]
 pub enum ListCachePoliciesError {
@@ -14818,6 +15465,15 @@ impl fmt::Display for ListCachePoliciesError {
     }
 }
 impl Error for ListCachePoliciesError {}
+impl ServiceErrorCode for ListCachePoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCachePoliciesError::AccessDenied(_) => "AccessDenied",
+            ListCachePoliciesError::InvalidArgument(_) => "InvalidArgument",
+            ListCachePoliciesError::NoSuchCachePolicy(_) => "NoSuchCachePolicy",
+        }
+    }
+}
 /// Errors returned by ListCloudFrontOriginAccessIdentities
 #[derive(Debug, PartialEq)]
 pub enum ListCloudFrontOriginAccessIdentitiesError {
@@ -14868,6 +15524,13 @@ impl fmt::Display for ListCloudFrontOriginAccessIdentitiesError {
     }
 }
 impl Error for ListCloudFrontOriginAccessIdentitiesError {}
+impl ServiceErrorCode for ListCloudFrontOriginAccessIdentitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCloudFrontOriginAccessIdentitiesError::InvalidArgument(_) => "InvalidArgument",
+        }
+    }
+}
 /// Errors returned by ListDistributions
 #[derive(Debug, PartialEq)]
 pub enum ListDistributionsError {
@@ -14912,6 +15575,13 @@ impl fmt::Display for ListDistributionsError {
     }
 }
 impl Error for ListDistributionsError {}
+impl ServiceErrorCode for ListDistributionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDistributionsError::InvalidArgument(_) => "InvalidArgument",
+        }
+    }
+}
 /// Errors returned by ListDistributionsByCachePolicyId
 #[derive(Debug, PartialEq)]
 pub enum ListDistributionsByCachePolicyIdError {
@@ -14986,6 +15656,15 @@ impl fmt::Display for ListDistributionsByCachePolicyIdError {
     }
 }
 impl Error for ListDistributionsByCachePolicyIdError {}
+impl ServiceErrorCode for ListDistributionsByCachePolicyIdError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDistributionsByCachePolicyIdError::AccessDenied(_) => "AccessDenied",
+            ListDistributionsByCachePolicyIdError::InvalidArgument(_) => "InvalidArgument",
+            ListDistributionsByCachePolicyIdError::NoSuchCachePolicy(_) => "NoSuchCachePolicy",
+        }
+    }
+}
 /// Errors returned by ListDistributionsByKeyGroup
 #[derive(Debug, PartialEq)]
 pub enum ListDistributionsByKeyGroupError {
@@ -15040,6 +15719,14 @@ impl fmt::Display for ListDistributionsByKeyGroupError {
     }
 }
 impl Error for ListDistributionsByKeyGroupError {}
+impl ServiceErrorCode for ListDistributionsByKeyGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDistributionsByKeyGroupError::InvalidArgument(_) => "InvalidArgument",
+            ListDistributionsByKeyGroupError::NoSuchResource(_) => "NoSuchResource",
+        }
+    }
+}
 /// Errors returned by ListDistributionsByOriginRequestPolicyId
 #[derive(Debug, PartialEq)]
 pub enum ListDistributionsByOriginRequestPolicyIdError {
@@ -15112,6 +15799,17 @@ impl fmt::Display for ListDistributionsByOriginRequestPolicyIdError {
     }
 }
 impl Error for ListDistributionsByOriginRequestPolicyIdError {}
+impl ServiceErrorCode for ListDistributionsByOriginRequestPolicyIdError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDistributionsByOriginRequestPolicyIdError::AccessDenied(_) => "AccessDenied",
+            ListDistributionsByOriginRequestPolicyIdError::InvalidArgument(_) => "InvalidArgument",
+            ListDistributionsByOriginRequestPolicyIdError::NoSuchOriginRequestPolicy(_) => {
+                "NoSuchOriginRequestPolicy"
+            }
+        }
+    }
+}
 /// Errors returned by ListDistributionsByRealtimeLogConfig
 #[derive(Debug, PartialEq)]
 pub enum ListDistributionsByRealtimeLogConfigError {
@@ -15162,6 +15860,13 @@ impl fmt::Display for ListDistributionsByRealtimeLogConfigError {
     }
 }
 impl Error for ListDistributionsByRealtimeLogConfigError {}
+impl ServiceErrorCode for ListDistributionsByRealtimeLogConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDistributionsByRealtimeLogConfigError::InvalidArgument(_) => "InvalidArgument",
+        }
+    }
+}
 /// Errors returned by ListDistributionsByWebACLId
 #[derive(Debug, PartialEq)]
 pub enum ListDistributionsByWebACLIdError {
@@ -15216,6 +15921,14 @@ impl fmt::Display for ListDistributionsByWebACLIdError {
     }
 }
 impl Error for ListDistributionsByWebACLIdError {}
+impl ServiceErrorCode for ListDistributionsByWebACLIdError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDistributionsByWebACLIdError::InvalidArgument(_) => "InvalidArgument",
+            ListDistributionsByWebACLIdError::InvalidWebACLId(_) => "InvalidWebACLId",
+        }
+    }
+}
 /// Errors returned by ListFieldLevelEncryptionConfigs
 #[derive(Debug, PartialEq)]
 pub enum ListFieldLevelEncryptionConfigsError {
@@ -15266,6 +15979,13 @@ impl fmt::Display for ListFieldLevelEncryptionConfigsError {
     }
 }
 impl Error for ListFieldLevelEncryptionConfigsError {}
+impl ServiceErrorCode for ListFieldLevelEncryptionConfigsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFieldLevelEncryptionConfigsError::InvalidArgument(_) => "InvalidArgument",
+        }
+    }
+}
 /// Errors returned by ListFieldLevelEncryptionProfiles
 #[derive(Debug, PartialEq)]
 pub enum ListFieldLevelEncryptionProfilesError {
@@ -15316,6 +16036,13 @@ impl fmt::Display for ListFieldLevelEncryptionProfilesError {
     }
 }
 impl Error for ListFieldLevelEncryptionProfilesError {}
+impl ServiceErrorCode for ListFieldLevelEncryptionProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFieldLevelEncryptionProfilesError::InvalidArgument(_) => "InvalidArgument",
+        }
+    }
+}
 /// Errors returned by ListInvalidations
 #[derive(Debug, PartialEq)]
 pub enum ListInvalidationsError {
@@ -15376,6 +16103,15 @@ impl fmt::Display for ListInvalidationsError {
     }
 }
 impl Error for ListInvalidationsError {}
+impl ServiceErrorCode for ListInvalidationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInvalidationsError::AccessDenied(_) => "AccessDenied",
+            ListInvalidationsError::InvalidArgument(_) => "InvalidArgument",
+            ListInvalidationsError::NoSuchDistribution(_) => "NoSuchDistribution",
+        }
+    }
+}
 /// Errors returned by ListKeyGroups
 #[derive(Debug, PartialEq)]
 pub enum ListKeyGroupsError {
@@ -15420,6 +16156,13 @@ impl fmt::Display for ListKeyGroupsError {
     }
 }
 impl Error for ListKeyGroupsError {}
+impl ServiceErrorCode for ListKeyGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListKeyGroupsError::InvalidArgument(_) => "InvalidArgument",
+        }
+    }
+}
 /// Errors returned by ListOriginRequestPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListOriginRequestPoliciesError {
@@ -15484,6 +16227,17 @@ impl fmt::Display for ListOriginRequestPoliciesError {
     }
 }
 impl Error for ListOriginRequestPoliciesError {}
+impl ServiceErrorCode for ListOriginRequestPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOriginRequestPoliciesError::AccessDenied(_) => "AccessDenied",
+            ListOriginRequestPoliciesError::InvalidArgument(_) => "InvalidArgument",
+            ListOriginRequestPoliciesError::NoSuchOriginRequestPolicy(_) => {
+                "NoSuchOriginRequestPolicy"
+            }
+        }
+    }
+}
 /// Errors returned by ListPublicKeys
 #[derive(Debug, PartialEq)]
 pub enum ListPublicKeysError {
@@ -15528,6 +16282,13 @@ impl fmt::Display for ListPublicKeysError {
     }
 }
 impl Error for ListPublicKeysError {}
+impl ServiceErrorCode for ListPublicKeysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPublicKeysError::InvalidArgument(_) => "InvalidArgument",
+        }
+    }
+}
 /// Errors returned by ListRealtimeLogConfigs
 #[derive(Debug, PartialEq)]
 pub enum ListRealtimeLogConfigsError {
@@ -15592,6 +16353,15 @@ impl fmt::Display for ListRealtimeLogConfigsError {
     }
 }
 impl Error for ListRealtimeLogConfigsError {}
+impl ServiceErrorCode for ListRealtimeLogConfigsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRealtimeLogConfigsError::AccessDenied(_) => "AccessDenied",
+            ListRealtimeLogConfigsError::InvalidArgument(_) => "InvalidArgument",
+            ListRealtimeLogConfigsError::NoSuchRealtimeLogConfig(_) => "NoSuchRealtimeLogConfig",
+        }
+    }
+}
 /// Errors returned by ListStreamingDistributions
 #[derive(Debug, PartialEq)]
 pub enum ListStreamingDistributionsError {
@@ -15638,6 +16408,13 @@ impl fmt::Display for ListStreamingDistributionsError {
     }
 }
 impl Error for ListStreamingDistributionsError {}
+impl ServiceErrorCode for ListStreamingDistributionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStreamingDistributionsError::InvalidArgument(_) => "InvalidArgument",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -15706,6 +16483,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AccessDenied(_) => "AccessDenied",
+            ListTagsForResourceError::InvalidArgument(_) => "InvalidArgument",
+            ListTagsForResourceError::InvalidTagging(_) => "InvalidTagging",
+            ListTagsForResourceError::NoSuchResource(_) => "NoSuchResource",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -15774,6 +16561,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AccessDenied(_) => "AccessDenied",
+            TagResourceError::InvalidArgument(_) => "InvalidArgument",
+            TagResourceError::InvalidTagging(_) => "InvalidTagging",
+            TagResourceError::NoSuchResource(_) => "NoSuchResource",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -15842,6 +16639,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AccessDenied(_) => "AccessDenied",
+            UntagResourceError::InvalidArgument(_) => "InvalidArgument",
+            UntagResourceError::InvalidTagging(_) => "InvalidTagging",
+            UntagResourceError::NoSuchResource(_) => "NoSuchResource",
+        }
+    }
+}
 /// Errors returned by UpdateCachePolicy
 #[derive(Debug, PartialEq)]
 pub enum UpdateCachePolicyError {
@@ -15978,6 +16785,25 @@ impl fmt::Display for UpdateCachePolicyError {
     }
 }
 impl Error for UpdateCachePolicyError {}
+impl ServiceErrorCode for UpdateCachePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCachePolicyError::AccessDenied(_) => "AccessDenied",
+            UpdateCachePolicyError::CachePolicyAlreadyExists(_) => "CachePolicyAlreadyExists",
+            UpdateCachePolicyError::IllegalUpdate(_) => "IllegalUpdate",
+            UpdateCachePolicyError::InconsistentQuantities(_) => "InconsistentQuantities",
+            UpdateCachePolicyError::InvalidArgument(_) => "InvalidArgument",
+            UpdateCachePolicyError::InvalidIfMatchVersion(_) => "InvalidIfMatchVersion",
+            UpdateCachePolicyError::NoSuchCachePolicy(_) => "NoSuchCachePolicy",
+            UpdateCachePolicyError::PreconditionFailed(_) => "PreconditionFailed",
+            UpdateCachePolicyError::TooManyCookiesInCachePolicy(_) => "TooManyCookiesInCachePolicy",
+            UpdateCachePolicyError::TooManyHeadersInCachePolicy(_) => "TooManyHeadersInCachePolicy",
+            UpdateCachePolicyError::TooManyQueryStringsInCachePolicy(_) => {
+                "TooManyQueryStringsInCachePolicy"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateCloudFrontOriginAccessIdentity
 #[derive(Debug, PartialEq)]
 pub enum UpdateCloudFrontOriginAccessIdentityError {
@@ -16056,6 +16882,28 @@ impl fmt::Display for UpdateCloudFrontOriginAccessIdentityError {
     }
 }
 impl Error for UpdateCloudFrontOriginAccessIdentityError {}
+impl ServiceErrorCode for UpdateCloudFrontOriginAccessIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCloudFrontOriginAccessIdentityError::AccessDenied(_) => "AccessDenied",
+            UpdateCloudFrontOriginAccessIdentityError::IllegalUpdate(_) => "IllegalUpdate",
+            UpdateCloudFrontOriginAccessIdentityError::InconsistentQuantities(_) => {
+                "InconsistentQuantities"
+            }
+            UpdateCloudFrontOriginAccessIdentityError::InvalidArgument(_) => "InvalidArgument",
+            UpdateCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(_) => {
+                "InvalidIfMatchVersion"
+            }
+            UpdateCloudFrontOriginAccessIdentityError::MissingBody(_) => "MissingBody",
+            UpdateCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(_) => {
+                "NoSuchCloudFrontOriginAccessIdentity"
+            }
+            UpdateCloudFrontOriginAccessIdentityError::PreconditionFailed(_) => {
+                "PreconditionFailed"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateDistribution
 #[derive(Debug, PartialEq)]
 pub enum UpdateDistributionError {
@@ -16248,6 +17096,64 @@ UpdateDistributionError::TrustedSignerDoesNotExist(ref cause) => write!(f, "{}",
     }
 }
 impl Error for UpdateDistributionError {}
+impl ServiceErrorCode for UpdateDistributionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDistributionError::AccessDenied(_) => "AccessDenied",
+            UpdateDistributionError::CNAMEAlreadyExists(_) => "CNAMEAlreadyExists",
+            UpdateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(_) => "IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior",
+            UpdateDistributionError::IllegalUpdate(_) => "IllegalUpdate",
+            UpdateDistributionError::InconsistentQuantities(_) => "InconsistentQuantities",
+            UpdateDistributionError::InvalidArgument(_) => "InvalidArgument",
+            UpdateDistributionError::InvalidDefaultRootObject(_) => "InvalidDefaultRootObject",
+            UpdateDistributionError::InvalidErrorCode(_) => "InvalidErrorCode",
+            UpdateDistributionError::InvalidForwardCookies(_) => "InvalidForwardCookies",
+            UpdateDistributionError::InvalidGeoRestrictionParameter(_) => "InvalidGeoRestrictionParameter",
+            UpdateDistributionError::InvalidHeadersForS3Origin(_) => "InvalidHeadersForS3Origin",
+            UpdateDistributionError::InvalidIfMatchVersion(_) => "InvalidIfMatchVersion",
+            UpdateDistributionError::InvalidLambdaFunctionAssociation(_) => "InvalidLambdaFunctionAssociation",
+            UpdateDistributionError::InvalidLocationCode(_) => "InvalidLocationCode",
+            UpdateDistributionError::InvalidMinimumProtocolVersion(_) => "InvalidMinimumProtocolVersion",
+            UpdateDistributionError::InvalidOriginAccessIdentity(_) => "InvalidOriginAccessIdentity",
+            UpdateDistributionError::InvalidOriginKeepaliveTimeout(_) => "InvalidOriginKeepaliveTimeout",
+            UpdateDistributionError::InvalidOriginReadTimeout(_) => "InvalidOriginReadTimeout",
+            UpdateDistributionError::InvalidQueryStringParameters(_) => "InvalidQueryStringParameters",
+            UpdateDistributionError::InvalidRelativePath(_) => "InvalidRelativePath",
+            UpdateDistributionError::InvalidRequiredProtocol(_) => "InvalidRequiredProtocol",
+            UpdateDistributionError::InvalidResponseCode(_) => "InvalidResponseCode",
+            UpdateDistributionError::InvalidTTLOrder(_) => "InvalidTTLOrder",
+            UpdateDistributionError::InvalidViewerCertificate(_) => "InvalidViewerCertificate",
+            UpdateDistributionError::InvalidWebACLId(_) => "InvalidWebACLId",
+            UpdateDistributionError::MissingBody(_) => "MissingBody",
+            UpdateDistributionError::NoSuchCachePolicy(_) => "NoSuchCachePolicy",
+            UpdateDistributionError::NoSuchDistribution(_) => "NoSuchDistribution",
+            UpdateDistributionError::NoSuchFieldLevelEncryptionConfig(_) => "NoSuchFieldLevelEncryptionConfig",
+            UpdateDistributionError::NoSuchOrigin(_) => "NoSuchOrigin",
+            UpdateDistributionError::NoSuchOriginRequestPolicy(_) => "NoSuchOriginRequestPolicy",
+            UpdateDistributionError::PreconditionFailed(_) => "PreconditionFailed",
+            UpdateDistributionError::TooManyCacheBehaviors(_) => "TooManyCacheBehaviors",
+            UpdateDistributionError::TooManyCertificates(_) => "TooManyCertificates",
+            UpdateDistributionError::TooManyCookieNamesInWhiteList(_) => "TooManyCookieNamesInWhiteList",
+            UpdateDistributionError::TooManyDistributionCNAMEs(_) => "TooManyDistributionCNAMEs",
+            UpdateDistributionError::TooManyDistributionsAssociatedToCachePolicy(_) => "TooManyDistributionsAssociatedToCachePolicy",
+            UpdateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(_) => "TooManyDistributionsAssociatedToFieldLevelEncryptionConfig",
+            UpdateDistributionError::TooManyDistributionsAssociatedToKeyGroup(_) => "TooManyDistributionsAssociatedToKeyGroup",
+            UpdateDistributionError::TooManyDistributionsAssociatedToOriginRequestPolicy(_) => "TooManyDistributionsAssociatedToOriginRequestPolicy",
+            UpdateDistributionError::TooManyDistributionsWithLambdaAssociations(_) => "TooManyDistributionsWithLambdaAssociations",
+            UpdateDistributionError::TooManyDistributionsWithSingleFunctionARN(_) => "TooManyDistributionsWithSingleFunctionARN",
+            UpdateDistributionError::TooManyHeadersInForwardedValues(_) => "TooManyHeadersInForwardedValues",
+            UpdateDistributionError::TooManyKeyGroupsAssociatedToDistribution(_) => "TooManyKeyGroupsAssociatedToDistribution",
+            UpdateDistributionError::TooManyLambdaFunctionAssociations(_) => "TooManyLambdaFunctionAssociations",
+            UpdateDistributionError::TooManyOriginCustomHeaders(_) => "TooManyOriginCustomHeaders",
+            UpdateDistributionError::TooManyOriginGroupsPerDistribution(_) => "TooManyOriginGroupsPerDistribution",
+            UpdateDistributionError::TooManyOrigins(_) => "TooManyOrigins",
+            UpdateDistributionError::TooManyQueryStringParameters(_) => "TooManyQueryStringParameters",
+            UpdateDistributionError::TooManyTrustedSigners(_) => "TooManyTrustedSigners",
+            UpdateDistributionError::TrustedKeyGroupDoesNotExist(_) => "TrustedKeyGroupDoesNotExist",
+            UpdateDistributionError::TrustedSignerDoesNotExist(_) => "TrustedSignerDoesNotExist",
+        }
+    }
+}
 /// Errors returned by UpdateFieldLevelEncryptionConfig
 #[derive(Debug, PartialEq)]
 pub enum UpdateFieldLevelEncryptionConfigError {
@@ -16319,6 +17225,23 @@ UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfil
     }
 }
 impl Error for UpdateFieldLevelEncryptionConfigError {}
+impl ServiceErrorCode for UpdateFieldLevelEncryptionConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFieldLevelEncryptionConfigError::AccessDenied(_) => "AccessDenied",
+            UpdateFieldLevelEncryptionConfigError::IllegalUpdate(_) => "IllegalUpdate",
+            UpdateFieldLevelEncryptionConfigError::InconsistentQuantities(_) => "InconsistentQuantities",
+            UpdateFieldLevelEncryptionConfigError::InvalidArgument(_) => "InvalidArgument",
+            UpdateFieldLevelEncryptionConfigError::InvalidIfMatchVersion(_) => "InvalidIfMatchVersion",
+            UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(_) => "NoSuchFieldLevelEncryptionConfig",
+            UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(_) => "NoSuchFieldLevelEncryptionProfile",
+            UpdateFieldLevelEncryptionConfigError::PreconditionFailed(_) => "PreconditionFailed",
+            UpdateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(_) => "QueryArgProfileEmpty",
+            UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(_) => "TooManyFieldLevelEncryptionContentTypeProfiles",
+            UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(_) => "TooManyFieldLevelEncryptionQueryArgProfiles",
+        }
+    }
+}
 /// Errors returned by UpdateFieldLevelEncryptionProfile
 #[derive(Debug, PartialEq)]
 pub enum UpdateFieldLevelEncryptionProfileError {
@@ -16393,6 +17316,24 @@ UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns
     }
 }
 impl Error for UpdateFieldLevelEncryptionProfileError {}
+impl ServiceErrorCode for UpdateFieldLevelEncryptionProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFieldLevelEncryptionProfileError::AccessDenied(_) => "AccessDenied",
+            UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(_) => "FieldLevelEncryptionProfileAlreadyExists",
+            UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(_) => "FieldLevelEncryptionProfileSizeExceeded",
+            UpdateFieldLevelEncryptionProfileError::IllegalUpdate(_) => "IllegalUpdate",
+            UpdateFieldLevelEncryptionProfileError::InconsistentQuantities(_) => "InconsistentQuantities",
+            UpdateFieldLevelEncryptionProfileError::InvalidArgument(_) => "InvalidArgument",
+            UpdateFieldLevelEncryptionProfileError::InvalidIfMatchVersion(_) => "InvalidIfMatchVersion",
+            UpdateFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(_) => "NoSuchFieldLevelEncryptionProfile",
+            UpdateFieldLevelEncryptionProfileError::NoSuchPublicKey(_) => "NoSuchPublicKey",
+            UpdateFieldLevelEncryptionProfileError::PreconditionFailed(_) => "PreconditionFailed",
+            UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(_) => "TooManyFieldLevelEncryptionEncryptionEntities",
+            UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(_) => "TooManyFieldLevelEncryptionFieldPatterns",
+        }
+    }
+}
 /// Errors returned by UpdateKeyGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateKeyGroupError {
@@ -16477,6 +17418,18 @@ impl fmt::Display for UpdateKeyGroupError {
     }
 }
 impl Error for UpdateKeyGroupError {}
+impl ServiceErrorCode for UpdateKeyGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateKeyGroupError::InvalidArgument(_) => "InvalidArgument",
+            UpdateKeyGroupError::InvalidIfMatchVersion(_) => "InvalidIfMatchVersion",
+            UpdateKeyGroupError::KeyGroupAlreadyExists(_) => "KeyGroupAlreadyExists",
+            UpdateKeyGroupError::NoSuchResource(_) => "NoSuchResource",
+            UpdateKeyGroupError::PreconditionFailed(_) => "PreconditionFailed",
+            UpdateKeyGroupError::TooManyPublicKeysInKeyGroup(_) => "TooManyPublicKeysInKeyGroup",
+        }
+    }
+}
 /// Errors returned by UpdateOriginRequestPolicy
 #[derive(Debug, PartialEq)]
 pub enum UpdateOriginRequestPolicyError {
@@ -16629,6 +17582,33 @@ impl fmt::Display for UpdateOriginRequestPolicyError {
     }
 }
 impl Error for UpdateOriginRequestPolicyError {}
+impl ServiceErrorCode for UpdateOriginRequestPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateOriginRequestPolicyError::AccessDenied(_) => "AccessDenied",
+            UpdateOriginRequestPolicyError::IllegalUpdate(_) => "IllegalUpdate",
+            UpdateOriginRequestPolicyError::InconsistentQuantities(_) => "InconsistentQuantities",
+            UpdateOriginRequestPolicyError::InvalidArgument(_) => "InvalidArgument",
+            UpdateOriginRequestPolicyError::InvalidIfMatchVersion(_) => "InvalidIfMatchVersion",
+            UpdateOriginRequestPolicyError::NoSuchOriginRequestPolicy(_) => {
+                "NoSuchOriginRequestPolicy"
+            }
+            UpdateOriginRequestPolicyError::OriginRequestPolicyAlreadyExists(_) => {
+                "OriginRequestPolicyAlreadyExists"
+            }
+            UpdateOriginRequestPolicyError::PreconditionFailed(_) => "PreconditionFailed",
+            UpdateOriginRequestPolicyError::TooManyCookiesInOriginRequestPolicy(_) => {
+                "TooManyCookiesInOriginRequestPolicy"
+            }
+            UpdateOriginRequestPolicyError::TooManyHeadersInOriginRequestPolicy(_) => {
+                "TooManyHeadersInOriginRequestPolicy"
+            }
+            UpdateOriginRequestPolicyError::TooManyQueryStringsInOriginRequestPolicy(_) => {
+                "TooManyQueryStringsInOriginRequestPolicy"
+            }
+        }
+    }
+}
 /// Errors returned by UpdatePublicKey
 #[derive(Debug, PartialEq)]
 pub enum UpdatePublicKeyError {
@@ -16725,6 +17705,21 @@ impl fmt::Display for UpdatePublicKeyError {
     }
 }
 impl Error for UpdatePublicKeyError {}
+impl ServiceErrorCode for UpdatePublicKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePublicKeyError::AccessDenied(_) => "AccessDenied",
+            UpdatePublicKeyError::CannotChangeImmutablePublicKeyFields(_) => {
+                "CannotChangeImmutablePublicKeyFields"
+            }
+            UpdatePublicKeyError::IllegalUpdate(_) => "IllegalUpdate",
+            UpdatePublicKeyError::InvalidArgument(_) => "InvalidArgument",
+            UpdatePublicKeyError::InvalidIfMatchVersion(_) => "InvalidIfMatchVersion",
+            UpdatePublicKeyError::NoSuchPublicKey(_) => "NoSuchPublicKey",
+            UpdatePublicKeyError::PreconditionFailed(_) => "PreconditionFailed",
+        }
+    }
+}
 /// Errors returned by UpdateRealtimeLogConfig
 #[derive(Debug, PartialEq)]
 pub enum UpdateRealtimeLogConfigError {
@@ -16789,6 +17784,15 @@ impl fmt::Display for UpdateRealtimeLogConfigError {
     }
 }
 impl Error for UpdateRealtimeLogConfigError {}
+impl ServiceErrorCode for UpdateRealtimeLogConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRealtimeLogConfigError::AccessDenied(_) => "AccessDenied",
+            UpdateRealtimeLogConfigError::InvalidArgument(_) => "InvalidArgument",
+            UpdateRealtimeLogConfigError::NoSuchRealtimeLogConfig(_) => "NoSuchRealtimeLogConfig",
+        }
+    }
+}
 /// Errors returned by UpdateStreamingDistribution
 #[derive(Debug, PartialEq)]
 pub enum UpdateStreamingDistributionError {
@@ -16967,6 +17971,33 @@ impl fmt::Display for UpdateStreamingDistributionError {
     }
 }
 impl Error for UpdateStreamingDistributionError {}
+impl ServiceErrorCode for UpdateStreamingDistributionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateStreamingDistributionError::AccessDenied(_) => "AccessDenied",
+            UpdateStreamingDistributionError::CNAMEAlreadyExists(_) => "CNAMEAlreadyExists",
+            UpdateStreamingDistributionError::IllegalUpdate(_) => "IllegalUpdate",
+            UpdateStreamingDistributionError::InconsistentQuantities(_) => "InconsistentQuantities",
+            UpdateStreamingDistributionError::InvalidArgument(_) => "InvalidArgument",
+            UpdateStreamingDistributionError::InvalidIfMatchVersion(_) => "InvalidIfMatchVersion",
+            UpdateStreamingDistributionError::InvalidOriginAccessIdentity(_) => {
+                "InvalidOriginAccessIdentity"
+            }
+            UpdateStreamingDistributionError::MissingBody(_) => "MissingBody",
+            UpdateStreamingDistributionError::NoSuchStreamingDistribution(_) => {
+                "NoSuchStreamingDistribution"
+            }
+            UpdateStreamingDistributionError::PreconditionFailed(_) => "PreconditionFailed",
+            UpdateStreamingDistributionError::TooManyStreamingDistributionCNAMEs(_) => {
+                "TooManyStreamingDistributionCNAMEs"
+            }
+            UpdateStreamingDistributionError::TooManyTrustedSigners(_) => "TooManyTrustedSigners",
+            UpdateStreamingDistributionError::TrustedSignerDoesNotExist(_) => {
+                "TrustedSignerDoesNotExist"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the CloudFront API. CloudFront clients implement this trait.
 #[async_trait]
 pub trait CloudFront {
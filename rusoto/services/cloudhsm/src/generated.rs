@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -680,6 +680,15 @@ impl fmt::Display for AddTagsToResourceError {
     }
 }
 impl Error for AddTagsToResourceError {}
+impl ServiceErrorCode for AddTagsToResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsToResourceError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            AddTagsToResourceError::CloudHsmService(_) => "CloudHsmServiceException",
+            AddTagsToResourceError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateHapg
 #[derive(Debug, PartialEq)]
 pub enum CreateHapgError {
@@ -722,6 +731,15 @@ impl fmt::Display for CreateHapgError {
     }
 }
 impl Error for CreateHapgError {}
+impl ServiceErrorCode for CreateHapgError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHapgError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            CreateHapgError::CloudHsmService(_) => "CloudHsmServiceException",
+            CreateHapgError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateHsm
 #[derive(Debug, PartialEq)]
 pub enum CreateHsmError {
@@ -764,6 +782,15 @@ impl fmt::Display for CreateHsmError {
     }
 }
 impl Error for CreateHsmError {}
+impl ServiceErrorCode for CreateHsmError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHsmError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            CreateHsmError::CloudHsmService(_) => "CloudHsmServiceException",
+            CreateHsmError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateLunaClient
 #[derive(Debug, PartialEq)]
 pub enum CreateLunaClientError {
@@ -806,6 +833,15 @@ impl fmt::Display for CreateLunaClientError {
     }
 }
 impl Error for CreateLunaClientError {}
+impl ServiceErrorCode for CreateLunaClientError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLunaClientError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            CreateLunaClientError::CloudHsmService(_) => "CloudHsmServiceException",
+            CreateLunaClientError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteHapg
 #[derive(Debug, PartialEq)]
 pub enum DeleteHapgError {
@@ -848,6 +884,15 @@ impl fmt::Display for DeleteHapgError {
     }
 }
 impl Error for DeleteHapgError {}
+impl ServiceErrorCode for DeleteHapgError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteHapgError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            DeleteHapgError::CloudHsmService(_) => "CloudHsmServiceException",
+            DeleteHapgError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteHsm
 #[derive(Debug, PartialEq)]
 pub enum DeleteHsmError {
@@ -890,6 +935,15 @@ impl fmt::Display for DeleteHsmError {
     }
 }
 impl Error for DeleteHsmError {}
+impl ServiceErrorCode for DeleteHsmError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteHsmError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            DeleteHsmError::CloudHsmService(_) => "CloudHsmServiceException",
+            DeleteHsmError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteLunaClient
 #[derive(Debug, PartialEq)]
 pub enum DeleteLunaClientError {
@@ -932,6 +986,15 @@ impl fmt::Display for DeleteLunaClientError {
     }
 }
 impl Error for DeleteLunaClientError {}
+impl ServiceErrorCode for DeleteLunaClientError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLunaClientError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            DeleteLunaClientError::CloudHsmService(_) => "CloudHsmServiceException",
+            DeleteLunaClientError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeHapg
 #[derive(Debug, PartialEq)]
 pub enum DescribeHapgError {
@@ -974,6 +1037,15 @@ impl fmt::Display for DescribeHapgError {
     }
 }
 impl Error for DescribeHapgError {}
+impl ServiceErrorCode for DescribeHapgError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeHapgError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            DescribeHapgError::CloudHsmService(_) => "CloudHsmServiceException",
+            DescribeHapgError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeHsm
 #[derive(Debug, PartialEq)]
 pub enum DescribeHsmError {
@@ -1016,6 +1088,15 @@ impl fmt::Display for DescribeHsmError {
     }
 }
 impl Error for DescribeHsmError {}
+impl ServiceErrorCode for DescribeHsmError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeHsmError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            DescribeHsmError::CloudHsmService(_) => "CloudHsmServiceException",
+            DescribeHsmError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeLunaClient
 #[derive(Debug, PartialEq)]
 pub enum DescribeLunaClientError {
@@ -1058,6 +1139,15 @@ impl fmt::Display for DescribeLunaClientError {
     }
 }
 impl Error for DescribeLunaClientError {}
+impl ServiceErrorCode for DescribeLunaClientError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLunaClientError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            DescribeLunaClientError::CloudHsmService(_) => "CloudHsmServiceException",
+            DescribeLunaClientError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by GetConfig
 #[derive(Debug, PartialEq)]
 pub enum GetConfigError {
@@ -1100,6 +1190,15 @@ impl fmt::Display for GetConfigError {
     }
 }
 impl Error for GetConfigError {}
+impl ServiceErrorCode for GetConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConfigError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            GetConfigError::CloudHsmService(_) => "CloudHsmServiceException",
+            GetConfigError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListAvailableZones
 #[derive(Debug, PartialEq)]
 pub enum ListAvailableZonesError {
@@ -1142,6 +1241,15 @@ impl fmt::Display for ListAvailableZonesError {
     }
 }
 impl Error for ListAvailableZonesError {}
+impl ServiceErrorCode for ListAvailableZonesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAvailableZonesError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            ListAvailableZonesError::CloudHsmService(_) => "CloudHsmServiceException",
+            ListAvailableZonesError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListHapgs
 #[derive(Debug, PartialEq)]
 pub enum ListHapgsError {
@@ -1184,6 +1292,15 @@ impl fmt::Display for ListHapgsError {
     }
 }
 impl Error for ListHapgsError {}
+impl ServiceErrorCode for ListHapgsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListHapgsError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            ListHapgsError::CloudHsmService(_) => "CloudHsmServiceException",
+            ListHapgsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListHsms
 #[derive(Debug, PartialEq)]
 pub enum ListHsmsError {
@@ -1226,6 +1343,15 @@ impl fmt::Display for ListHsmsError {
     }
 }
 impl Error for ListHsmsError {}
+impl ServiceErrorCode for ListHsmsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListHsmsError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            ListHsmsError::CloudHsmService(_) => "CloudHsmServiceException",
+            ListHsmsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListLunaClients
 #[derive(Debug, PartialEq)]
 pub enum ListLunaClientsError {
@@ -1268,6 +1394,15 @@ impl fmt::Display for ListLunaClientsError {
     }
 }
 impl Error for ListLunaClientsError {}
+impl ServiceErrorCode for ListLunaClientsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLunaClientsError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            ListLunaClientsError::CloudHsmService(_) => "CloudHsmServiceException",
+            ListLunaClientsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1312,6 +1447,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            ListTagsForResourceError::CloudHsmService(_) => "CloudHsmServiceException",
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ModifyHapg
 #[derive(Debug, PartialEq)]
 pub enum ModifyHapgError {
@@ -1354,6 +1498,15 @@ impl fmt::Display for ModifyHapgError {
     }
 }
 impl Error for ModifyHapgError {}
+impl ServiceErrorCode for ModifyHapgError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyHapgError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            ModifyHapgError::CloudHsmService(_) => "CloudHsmServiceException",
+            ModifyHapgError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ModifyHsm
 #[derive(Debug, PartialEq)]
 pub enum ModifyHsmError {
@@ -1396,6 +1549,15 @@ impl fmt::Display for ModifyHsmError {
     }
 }
 impl Error for ModifyHsmError {}
+impl ServiceErrorCode for ModifyHsmError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyHsmError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            ModifyHsmError::CloudHsmService(_) => "CloudHsmServiceException",
+            ModifyHsmError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ModifyLunaClient
 #[derive(Debug, PartialEq)]
 pub enum ModifyLunaClientError {
@@ -1426,6 +1588,13 @@ impl fmt::Display for ModifyLunaClientError {
     }
 }
 impl Error for ModifyLunaClientError {}
+impl ServiceErrorCode for ModifyLunaClientError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyLunaClientError::CloudHsmService(_) => "CloudHsmServiceException",
+        }
+    }
+}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromResourceError {
@@ -1474,6 +1643,15 @@ impl fmt::Display for RemoveTagsFromResourceError {
     }
 }
 impl Error for RemoveTagsFromResourceError {}
+impl ServiceErrorCode for RemoveTagsFromResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsFromResourceError::CloudHsmInternal(_) => "CloudHsmInternalException",
+            RemoveTagsFromResourceError::CloudHsmService(_) => "CloudHsmServiceException",
+            RemoveTagsFromResourceError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Trait representing the capabilities of the CloudHSM API. CloudHSM clients implement this trait.
 #[async_trait]
 pub trait CloudHsm {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -690,6 +690,22 @@ impl fmt::Display for CopyBackupToRegionError {
     }
 }
 impl Error for CopyBackupToRegionError {}
+impl ServiceErrorCode for CopyBackupToRegionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyBackupToRegionError::CloudHsmAccessDenied(_) => "CloudHsmAccessDeniedException",
+            CopyBackupToRegionError::CloudHsmInternalFailure(_) => {
+                "CloudHsmInternalFailureException"
+            }
+            CopyBackupToRegionError::CloudHsmInvalidRequest(_) => "CloudHsmInvalidRequestException",
+            CopyBackupToRegionError::CloudHsmResourceNotFound(_) => {
+                "CloudHsmResourceNotFoundException"
+            }
+            CopyBackupToRegionError::CloudHsmService(_) => "CloudHsmServiceException",
+            CopyBackupToRegionError::CloudHsmTag(_) => "CloudHsmTagException",
+        }
+    }
+}
 /// Errors returned by CreateCluster
 #[derive(Debug, PartialEq)]
 pub enum CreateClusterError {
@@ -756,6 +772,18 @@ impl fmt::Display for CreateClusterError {
     }
 }
 impl Error for CreateClusterError {}
+impl ServiceErrorCode for CreateClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateClusterError::CloudHsmAccessDenied(_) => "CloudHsmAccessDeniedException",
+            CreateClusterError::CloudHsmInternalFailure(_) => "CloudHsmInternalFailureException",
+            CreateClusterError::CloudHsmInvalidRequest(_) => "CloudHsmInvalidRequestException",
+            CreateClusterError::CloudHsmResourceNotFound(_) => "CloudHsmResourceNotFoundException",
+            CreateClusterError::CloudHsmService(_) => "CloudHsmServiceException",
+            CreateClusterError::CloudHsmTag(_) => "CloudHsmTagException",
+        }
+    }
+}
 /// Errors returned by CreateHsm
 #[derive(Debug, PartialEq)]
 pub enum CreateHsmError {
@@ -810,6 +838,17 @@ impl fmt::Display for CreateHsmError {
     }
 }
 impl Error for CreateHsmError {}
+impl ServiceErrorCode for CreateHsmError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHsmError::CloudHsmAccessDenied(_) => "CloudHsmAccessDeniedException",
+            CreateHsmError::CloudHsmInternalFailure(_) => "CloudHsmInternalFailureException",
+            CreateHsmError::CloudHsmInvalidRequest(_) => "CloudHsmInvalidRequestException",
+            CreateHsmError::CloudHsmResourceNotFound(_) => "CloudHsmResourceNotFoundException",
+            CreateHsmError::CloudHsmService(_) => "CloudHsmServiceException",
+        }
+    }
+}
 /// Errors returned by DeleteBackup
 #[derive(Debug, PartialEq)]
 pub enum DeleteBackupError {
@@ -868,6 +907,17 @@ impl fmt::Display for DeleteBackupError {
     }
 }
 impl Error for DeleteBackupError {}
+impl ServiceErrorCode for DeleteBackupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBackupError::CloudHsmAccessDenied(_) => "CloudHsmAccessDeniedException",
+            DeleteBackupError::CloudHsmInternalFailure(_) => "CloudHsmInternalFailureException",
+            DeleteBackupError::CloudHsmInvalidRequest(_) => "CloudHsmInvalidRequestException",
+            DeleteBackupError::CloudHsmResourceNotFound(_) => "CloudHsmResourceNotFoundException",
+            DeleteBackupError::CloudHsmService(_) => "CloudHsmServiceException",
+        }
+    }
+}
 /// Errors returned by DeleteCluster
 #[derive(Debug, PartialEq)]
 pub enum DeleteClusterError {
@@ -934,6 +984,18 @@ impl fmt::Display for DeleteClusterError {
     }
 }
 impl Error for DeleteClusterError {}
+impl ServiceErrorCode for DeleteClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteClusterError::CloudHsmAccessDenied(_) => "CloudHsmAccessDeniedException",
+            DeleteClusterError::CloudHsmInternalFailure(_) => "CloudHsmInternalFailureException",
+            DeleteClusterError::CloudHsmInvalidRequest(_) => "CloudHsmInvalidRequestException",
+            DeleteClusterError::CloudHsmResourceNotFound(_) => "CloudHsmResourceNotFoundException",
+            DeleteClusterError::CloudHsmService(_) => "CloudHsmServiceException",
+            DeleteClusterError::CloudHsmTag(_) => "CloudHsmTagException",
+        }
+    }
+}
 /// Errors returned by DeleteHsm
 #[derive(Debug, PartialEq)]
 pub enum DeleteHsmError {
@@ -988,6 +1050,17 @@ impl fmt::Display for DeleteHsmError {
     }
 }
 impl Error for DeleteHsmError {}
+impl ServiceErrorCode for DeleteHsmError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteHsmError::CloudHsmAccessDenied(_) => "CloudHsmAccessDeniedException",
+            DeleteHsmError::CloudHsmInternalFailure(_) => "CloudHsmInternalFailureException",
+            DeleteHsmError::CloudHsmInvalidRequest(_) => "CloudHsmInvalidRequestException",
+            DeleteHsmError::CloudHsmResourceNotFound(_) => "CloudHsmResourceNotFoundException",
+            DeleteHsmError::CloudHsmService(_) => "CloudHsmServiceException",
+        }
+    }
+}
 /// Errors returned by DescribeBackups
 #[derive(Debug, PartialEq)]
 pub enum DescribeBackupsError {
@@ -1056,6 +1129,20 @@ impl fmt::Display for DescribeBackupsError {
     }
 }
 impl Error for DescribeBackupsError {}
+impl ServiceErrorCode for DescribeBackupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBackupsError::CloudHsmAccessDenied(_) => "CloudHsmAccessDeniedException",
+            DescribeBackupsError::CloudHsmInternalFailure(_) => "CloudHsmInternalFailureException",
+            DescribeBackupsError::CloudHsmInvalidRequest(_) => "CloudHsmInvalidRequestException",
+            DescribeBackupsError::CloudHsmResourceNotFound(_) => {
+                "CloudHsmResourceNotFoundException"
+            }
+            DescribeBackupsError::CloudHsmService(_) => "CloudHsmServiceException",
+            DescribeBackupsError::CloudHsmTag(_) => "CloudHsmTagException",
+        }
+    }
+}
 /// Errors returned by DescribeClusters
 #[derive(Debug, PartialEq)]
 pub enum DescribeClustersError {
@@ -1116,6 +1203,17 @@ impl fmt::Display for DescribeClustersError {
     }
 }
 impl Error for DescribeClustersError {}
+impl ServiceErrorCode for DescribeClustersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClustersError::CloudHsmAccessDenied(_) => "CloudHsmAccessDeniedException",
+            DescribeClustersError::CloudHsmInternalFailure(_) => "CloudHsmInternalFailureException",
+            DescribeClustersError::CloudHsmInvalidRequest(_) => "CloudHsmInvalidRequestException",
+            DescribeClustersError::CloudHsmService(_) => "CloudHsmServiceException",
+            DescribeClustersError::CloudHsmTag(_) => "CloudHsmTagException",
+        }
+    }
+}
 /// Errors returned by InitializeCluster
 #[derive(Debug, PartialEq)]
 pub enum InitializeClusterError {
@@ -1178,6 +1276,21 @@ impl fmt::Display for InitializeClusterError {
     }
 }
 impl Error for InitializeClusterError {}
+impl ServiceErrorCode for InitializeClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InitializeClusterError::CloudHsmAccessDenied(_) => "CloudHsmAccessDeniedException",
+            InitializeClusterError::CloudHsmInternalFailure(_) => {
+                "CloudHsmInternalFailureException"
+            }
+            InitializeClusterError::CloudHsmInvalidRequest(_) => "CloudHsmInvalidRequestException",
+            InitializeClusterError::CloudHsmResourceNotFound(_) => {
+                "CloudHsmResourceNotFoundException"
+            }
+            InitializeClusterError::CloudHsmService(_) => "CloudHsmServiceException",
+        }
+    }
+}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
 pub enum ListTagsError {
@@ -1238,6 +1351,18 @@ impl fmt::Display for ListTagsError {
     }
 }
 impl Error for ListTagsError {}
+impl ServiceErrorCode for ListTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsError::CloudHsmAccessDenied(_) => "CloudHsmAccessDeniedException",
+            ListTagsError::CloudHsmInternalFailure(_) => "CloudHsmInternalFailureException",
+            ListTagsError::CloudHsmInvalidRequest(_) => "CloudHsmInvalidRequestException",
+            ListTagsError::CloudHsmResourceNotFound(_) => "CloudHsmResourceNotFoundException",
+            ListTagsError::CloudHsmService(_) => "CloudHsmServiceException",
+            ListTagsError::CloudHsmTag(_) => "CloudHsmTagException",
+        }
+    }
+}
 /// Errors returned by ModifyBackupAttributes
 #[derive(Debug, PartialEq)]
 pub enum ModifyBackupAttributesError {
@@ -1308,6 +1433,23 @@ impl fmt::Display for ModifyBackupAttributesError {
     }
 }
 impl Error for ModifyBackupAttributesError {}
+impl ServiceErrorCode for ModifyBackupAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyBackupAttributesError::CloudHsmAccessDenied(_) => "CloudHsmAccessDeniedException",
+            ModifyBackupAttributesError::CloudHsmInternalFailure(_) => {
+                "CloudHsmInternalFailureException"
+            }
+            ModifyBackupAttributesError::CloudHsmInvalidRequest(_) => {
+                "CloudHsmInvalidRequestException"
+            }
+            ModifyBackupAttributesError::CloudHsmResourceNotFound(_) => {
+                "CloudHsmResourceNotFoundException"
+            }
+            ModifyBackupAttributesError::CloudHsmService(_) => "CloudHsmServiceException",
+        }
+    }
+}
 /// Errors returned by ModifyCluster
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterError {
@@ -1368,6 +1510,17 @@ impl fmt::Display for ModifyClusterError {
     }
 }
 impl Error for ModifyClusterError {}
+impl ServiceErrorCode for ModifyClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyClusterError::CloudHsmAccessDenied(_) => "CloudHsmAccessDeniedException",
+            ModifyClusterError::CloudHsmInternalFailure(_) => "CloudHsmInternalFailureException",
+            ModifyClusterError::CloudHsmInvalidRequest(_) => "CloudHsmInvalidRequestException",
+            ModifyClusterError::CloudHsmResourceNotFound(_) => "CloudHsmResourceNotFoundException",
+            ModifyClusterError::CloudHsmService(_) => "CloudHsmServiceException",
+        }
+    }
+}
 /// Errors returned by RestoreBackup
 #[derive(Debug, PartialEq)]
 pub enum RestoreBackupError {
@@ -1428,6 +1581,17 @@ impl fmt::Display for RestoreBackupError {
     }
 }
 impl Error for RestoreBackupError {}
+impl ServiceErrorCode for RestoreBackupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreBackupError::CloudHsmAccessDenied(_) => "CloudHsmAccessDeniedException",
+            RestoreBackupError::CloudHsmInternalFailure(_) => "CloudHsmInternalFailureException",
+            RestoreBackupError::CloudHsmInvalidRequest(_) => "CloudHsmInvalidRequestException",
+            RestoreBackupError::CloudHsmResourceNotFound(_) => "CloudHsmResourceNotFoundException",
+            RestoreBackupError::CloudHsmService(_) => "CloudHsmServiceException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1490,6 +1654,18 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::CloudHsmAccessDenied(_) => "CloudHsmAccessDeniedException",
+            TagResourceError::CloudHsmInternalFailure(_) => "CloudHsmInternalFailureException",
+            TagResourceError::CloudHsmInvalidRequest(_) => "CloudHsmInvalidRequestException",
+            TagResourceError::CloudHsmResourceNotFound(_) => "CloudHsmResourceNotFoundException",
+            TagResourceError::CloudHsmService(_) => "CloudHsmServiceException",
+            TagResourceError::CloudHsmTag(_) => "CloudHsmTagException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1556,6 +1732,18 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::CloudHsmAccessDenied(_) => "CloudHsmAccessDeniedException",
+            UntagResourceError::CloudHsmInternalFailure(_) => "CloudHsmInternalFailureException",
+            UntagResourceError::CloudHsmInvalidRequest(_) => "CloudHsmInvalidRequestException",
+            UntagResourceError::CloudHsmResourceNotFound(_) => "CloudHsmResourceNotFoundException",
+            UntagResourceError::CloudHsmService(_) => "CloudHsmServiceException",
+            UntagResourceError::CloudHsmTag(_) => "CloudHsmTagException",
+        }
+    }
+}
 /// Trait representing the capabilities of the CloudHSM V2 API. CloudHSM V2 clients implement this trait.
 #[async_trait]
 pub trait CloudHsmv2 {
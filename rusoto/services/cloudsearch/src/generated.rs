@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -4148,6 +4148,15 @@ impl fmt::Display for BuildSuggestersError {
     }
 }
 impl Error for BuildSuggestersError {}
+impl ServiceErrorCode for BuildSuggestersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BuildSuggestersError::Base(_) => "BaseException",
+            BuildSuggestersError::Internal(_) => "InternalException",
+            BuildSuggestersError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by CreateDomain
 #[derive(Debug, PartialEq)]
 pub enum CreateDomainError {
@@ -4206,6 +4215,15 @@ impl fmt::Display for CreateDomainError {
     }
 }
 impl Error for CreateDomainError {}
+impl ServiceErrorCode for CreateDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDomainError::Base(_) => "BaseException",
+            CreateDomainError::Internal(_) => "InternalException",
+            CreateDomainError::LimitExceeded(_) => "LimitExceeded",
+        }
+    }
+}
 /// Errors returned by DefineAnalysisScheme
 #[derive(Debug, PartialEq)]
 pub enum DefineAnalysisSchemeError {
@@ -4282,6 +4300,17 @@ impl fmt::Display for DefineAnalysisSchemeError {
     }
 }
 impl Error for DefineAnalysisSchemeError {}
+impl ServiceErrorCode for DefineAnalysisSchemeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DefineAnalysisSchemeError::Base(_) => "BaseException",
+            DefineAnalysisSchemeError::Internal(_) => "InternalException",
+            DefineAnalysisSchemeError::InvalidType(_) => "InvalidType",
+            DefineAnalysisSchemeError::LimitExceeded(_) => "LimitExceeded",
+            DefineAnalysisSchemeError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DefineExpression
 #[derive(Debug, PartialEq)]
 pub enum DefineExpressionError {
@@ -4358,6 +4387,17 @@ impl fmt::Display for DefineExpressionError {
     }
 }
 impl Error for DefineExpressionError {}
+impl ServiceErrorCode for DefineExpressionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DefineExpressionError::Base(_) => "BaseException",
+            DefineExpressionError::Internal(_) => "InternalException",
+            DefineExpressionError::InvalidType(_) => "InvalidType",
+            DefineExpressionError::LimitExceeded(_) => "LimitExceeded",
+            DefineExpressionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DefineIndexField
 #[derive(Debug, PartialEq)]
 pub enum DefineIndexFieldError {
@@ -4434,6 +4474,17 @@ impl fmt::Display for DefineIndexFieldError {
     }
 }
 impl Error for DefineIndexFieldError {}
+impl ServiceErrorCode for DefineIndexFieldError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DefineIndexFieldError::Base(_) => "BaseException",
+            DefineIndexFieldError::Internal(_) => "InternalException",
+            DefineIndexFieldError::InvalidType(_) => "InvalidType",
+            DefineIndexFieldError::LimitExceeded(_) => "LimitExceeded",
+            DefineIndexFieldError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DefineSuggester
 #[derive(Debug, PartialEq)]
 pub enum DefineSuggesterError {
@@ -4510,6 +4561,17 @@ impl fmt::Display for DefineSuggesterError {
     }
 }
 impl Error for DefineSuggesterError {}
+impl ServiceErrorCode for DefineSuggesterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DefineSuggesterError::Base(_) => "BaseException",
+            DefineSuggesterError::Internal(_) => "InternalException",
+            DefineSuggesterError::InvalidType(_) => "InvalidType",
+            DefineSuggesterError::LimitExceeded(_) => "LimitExceeded",
+            DefineSuggesterError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteAnalysisScheme
 #[derive(Debug, PartialEq)]
 pub enum DeleteAnalysisSchemeError {
@@ -4578,6 +4640,16 @@ impl fmt::Display for DeleteAnalysisSchemeError {
     }
 }
 impl Error for DeleteAnalysisSchemeError {}
+impl ServiceErrorCode for DeleteAnalysisSchemeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAnalysisSchemeError::Base(_) => "BaseException",
+            DeleteAnalysisSchemeError::Internal(_) => "InternalException",
+            DeleteAnalysisSchemeError::InvalidType(_) => "InvalidType",
+            DeleteAnalysisSchemeError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteDomain
 #[derive(Debug, PartialEq)]
 pub enum DeleteDomainError {
@@ -4628,6 +4700,14 @@ impl fmt::Display for DeleteDomainError {
     }
 }
 impl Error for DeleteDomainError {}
+impl ServiceErrorCode for DeleteDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDomainError::Base(_) => "BaseException",
+            DeleteDomainError::Internal(_) => "InternalException",
+        }
+    }
+}
 /// Errors returned by DeleteExpression
 #[derive(Debug, PartialEq)]
 pub enum DeleteExpressionError {
@@ -4696,6 +4776,16 @@ impl fmt::Display for DeleteExpressionError {
     }
 }
 impl Error for DeleteExpressionError {}
+impl ServiceErrorCode for DeleteExpressionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteExpressionError::Base(_) => "BaseException",
+            DeleteExpressionError::Internal(_) => "InternalException",
+            DeleteExpressionError::InvalidType(_) => "InvalidType",
+            DeleteExpressionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteIndexField
 #[derive(Debug, PartialEq)]
 pub enum DeleteIndexFieldError {
@@ -4764,6 +4854,16 @@ impl fmt::Display for DeleteIndexFieldError {
     }
 }
 impl Error for DeleteIndexFieldError {}
+impl ServiceErrorCode for DeleteIndexFieldError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIndexFieldError::Base(_) => "BaseException",
+            DeleteIndexFieldError::Internal(_) => "InternalException",
+            DeleteIndexFieldError::InvalidType(_) => "InvalidType",
+            DeleteIndexFieldError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteSuggester
 #[derive(Debug, PartialEq)]
 pub enum DeleteSuggesterError {
@@ -4832,6 +4932,16 @@ impl fmt::Display for DeleteSuggesterError {
     }
 }
 impl Error for DeleteSuggesterError {}
+impl ServiceErrorCode for DeleteSuggesterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSuggesterError::Base(_) => "BaseException",
+            DeleteSuggesterError::Internal(_) => "InternalException",
+            DeleteSuggesterError::InvalidType(_) => "InvalidType",
+            DeleteSuggesterError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeAnalysisSchemes
 #[derive(Debug, PartialEq)]
 pub enum DescribeAnalysisSchemesError {
@@ -4892,6 +5002,15 @@ impl fmt::Display for DescribeAnalysisSchemesError {
     }
 }
 impl Error for DescribeAnalysisSchemesError {}
+impl ServiceErrorCode for DescribeAnalysisSchemesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAnalysisSchemesError::Base(_) => "BaseException",
+            DescribeAnalysisSchemesError::Internal(_) => "InternalException",
+            DescribeAnalysisSchemesError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeAvailabilityOptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeAvailabilityOptionsError {
@@ -4984,6 +5103,18 @@ impl fmt::Display for DescribeAvailabilityOptionsError {
     }
 }
 impl Error for DescribeAvailabilityOptionsError {}
+impl ServiceErrorCode for DescribeAvailabilityOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAvailabilityOptionsError::Base(_) => "BaseException",
+            DescribeAvailabilityOptionsError::DisabledOperation(_) => "DisabledAction",
+            DescribeAvailabilityOptionsError::Internal(_) => "InternalException",
+            DescribeAvailabilityOptionsError::InvalidType(_) => "InvalidType",
+            DescribeAvailabilityOptionsError::LimitExceeded(_) => "LimitExceeded",
+            DescribeAvailabilityOptionsError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeDomainEndpointOptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeDomainEndpointOptionsError {
@@ -5070,6 +5201,17 @@ impl fmt::Display for DescribeDomainEndpointOptionsError {
     }
 }
 impl Error for DescribeDomainEndpointOptionsError {}
+impl ServiceErrorCode for DescribeDomainEndpointOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDomainEndpointOptionsError::Base(_) => "BaseException",
+            DescribeDomainEndpointOptionsError::DisabledOperation(_) => "DisabledAction",
+            DescribeDomainEndpointOptionsError::Internal(_) => "InternalException",
+            DescribeDomainEndpointOptionsError::LimitExceeded(_) => "LimitExceeded",
+            DescribeDomainEndpointOptionsError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeDomains
 #[derive(Debug, PartialEq)]
 pub enum DescribeDomainsError {
@@ -5122,6 +5264,14 @@ impl fmt::Display for DescribeDomainsError {
     }
 }
 impl Error for DescribeDomainsError {}
+impl ServiceErrorCode for DescribeDomainsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDomainsError::Base(_) => "BaseException",
+            DescribeDomainsError::Internal(_) => "InternalException",
+        }
+    }
+}
 /// Errors returned by DescribeExpressions
 #[derive(Debug, PartialEq)]
 pub enum DescribeExpressionsError {
@@ -5182,6 +5332,15 @@ impl fmt::Display for DescribeExpressionsError {
     }
 }
 impl Error for DescribeExpressionsError {}
+impl ServiceErrorCode for DescribeExpressionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeExpressionsError::Base(_) => "BaseException",
+            DescribeExpressionsError::Internal(_) => "InternalException",
+            DescribeExpressionsError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeIndexFields
 #[derive(Debug, PartialEq)]
 pub enum DescribeIndexFieldsError {
@@ -5242,6 +5401,15 @@ impl fmt::Display for DescribeIndexFieldsError {
     }
 }
 impl Error for DescribeIndexFieldsError {}
+impl ServiceErrorCode for DescribeIndexFieldsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeIndexFieldsError::Base(_) => "BaseException",
+            DescribeIndexFieldsError::Internal(_) => "InternalException",
+            DescribeIndexFieldsError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeScalingParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeScalingParametersError {
@@ -5302,6 +5470,15 @@ impl fmt::Display for DescribeScalingParametersError {
     }
 }
 impl Error for DescribeScalingParametersError {}
+impl ServiceErrorCode for DescribeScalingParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeScalingParametersError::Base(_) => "BaseException",
+            DescribeScalingParametersError::Internal(_) => "InternalException",
+            DescribeScalingParametersError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeServiceAccessPolicies
 #[derive(Debug, PartialEq)]
 pub enum DescribeServiceAccessPoliciesError {
@@ -5368,6 +5545,15 @@ impl fmt::Display for DescribeServiceAccessPoliciesError {
     }
 }
 impl Error for DescribeServiceAccessPoliciesError {}
+impl ServiceErrorCode for DescribeServiceAccessPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeServiceAccessPoliciesError::Base(_) => "BaseException",
+            DescribeServiceAccessPoliciesError::Internal(_) => "InternalException",
+            DescribeServiceAccessPoliciesError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeSuggesters
 #[derive(Debug, PartialEq)]
 pub enum DescribeSuggestersError {
@@ -5428,6 +5614,15 @@ impl fmt::Display for DescribeSuggestersError {
     }
 }
 impl Error for DescribeSuggestersError {}
+impl ServiceErrorCode for DescribeSuggestersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSuggestersError::Base(_) => "BaseException",
+            DescribeSuggestersError::Internal(_) => "InternalException",
+            DescribeSuggestersError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by IndexDocuments
 #[derive(Debug, PartialEq)]
 pub enum IndexDocumentsError {
@@ -5488,6 +5683,15 @@ impl fmt::Display for IndexDocumentsError {
     }
 }
 impl Error for IndexDocumentsError {}
+impl ServiceErrorCode for IndexDocumentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            IndexDocumentsError::Base(_) => "BaseException",
+            IndexDocumentsError::Internal(_) => "InternalException",
+            IndexDocumentsError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by ListDomainNames
 #[derive(Debug, PartialEq)]
 pub enum ListDomainNamesError {
@@ -5532,6 +5736,13 @@ impl fmt::Display for ListDomainNamesError {
     }
 }
 impl Error for ListDomainNamesError {}
+impl ServiceErrorCode for ListDomainNamesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDomainNamesError::Base(_) => "BaseException",
+        }
+    }
+}
 /// Errors returned by UpdateAvailabilityOptions
 #[derive(Debug, PartialEq)]
 pub enum UpdateAvailabilityOptionsError {
@@ -5624,6 +5835,19 @@ impl fmt::Display for UpdateAvailabilityOptionsError {
     }
 }
 impl Error for UpdateAvailabilityOptionsError {}
+impl ServiceErrorCode for UpdateAvailabilityOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAvailabilityOptionsError::Base(_) => "BaseException",
+            UpdateAvailabilityOptionsError::DisabledOperation(_) => "DisabledAction",
+            UpdateAvailabilityOptionsError::Internal(_) => "InternalException",
+            UpdateAvailabilityOptionsError::InvalidType(_) => "InvalidType",
+            UpdateAvailabilityOptionsError::LimitExceeded(_) => "LimitExceeded",
+            UpdateAvailabilityOptionsError::ResourceNotFound(_) => "ResourceNotFound",
+            UpdateAvailabilityOptionsError::Validation(_) => "ValidationException",
+        }
+    }
+}
 /// Errors returned by UpdateDomainEndpointOptions
 #[derive(Debug, PartialEq)]
 pub enum UpdateDomainEndpointOptionsError {
@@ -5724,6 +5948,19 @@ impl fmt::Display for UpdateDomainEndpointOptionsError {
     }
 }
 impl Error for UpdateDomainEndpointOptionsError {}
+impl ServiceErrorCode for UpdateDomainEndpointOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDomainEndpointOptionsError::Base(_) => "BaseException",
+            UpdateDomainEndpointOptionsError::DisabledOperation(_) => "DisabledAction",
+            UpdateDomainEndpointOptionsError::Internal(_) => "InternalException",
+            UpdateDomainEndpointOptionsError::InvalidType(_) => "InvalidType",
+            UpdateDomainEndpointOptionsError::LimitExceeded(_) => "LimitExceeded",
+            UpdateDomainEndpointOptionsError::ResourceNotFound(_) => "ResourceNotFound",
+            UpdateDomainEndpointOptionsError::Validation(_) => "ValidationException",
+        }
+    }
+}
 /// Errors returned by UpdateScalingParameters
 #[derive(Debug, PartialEq)]
 pub enum UpdateScalingParametersError {
@@ -5800,6 +6037,17 @@ impl fmt::Display for UpdateScalingParametersError {
     }
 }
 impl Error for UpdateScalingParametersError {}
+impl ServiceErrorCode for UpdateScalingParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateScalingParametersError::Base(_) => "BaseException",
+            UpdateScalingParametersError::Internal(_) => "InternalException",
+            UpdateScalingParametersError::InvalidType(_) => "InvalidType",
+            UpdateScalingParametersError::LimitExceeded(_) => "LimitExceeded",
+            UpdateScalingParametersError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateServiceAccessPolicies
 #[derive(Debug, PartialEq)]
 pub enum UpdateServiceAccessPoliciesError {
@@ -5880,6 +6128,17 @@ impl fmt::Display for UpdateServiceAccessPoliciesError {
     }
 }
 impl Error for UpdateServiceAccessPoliciesError {}
+impl ServiceErrorCode for UpdateServiceAccessPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateServiceAccessPoliciesError::Base(_) => "BaseException",
+            UpdateServiceAccessPoliciesError::Internal(_) => "InternalException",
+            UpdateServiceAccessPoliciesError::InvalidType(_) => "InvalidType",
+            UpdateServiceAccessPoliciesError::LimitExceeded(_) => "LimitExceeded",
+            UpdateServiceAccessPoliciesError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon CloudSearch API. Amazon CloudSearch clients implement this trait.
 #[async_trait]
 pub trait CloudSearch {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -384,6 +384,13 @@ impl fmt::Display for SearchError {
     }
 }
 impl Error for SearchError {}
+impl ServiceErrorCode for SearchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchError::Search(_) => "SearchException",
+        }
+    }
+}
 /// Errors returned by Suggest
 #[derive(Debug, PartialEq)]
 pub enum SuggestError {
@@ -412,6 +419,13 @@ impl fmt::Display for SuggestError {
     }
 }
 impl Error for SuggestError {}
+impl ServiceErrorCode for SuggestError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SuggestError::Search(_) => "SearchException",
+        }
+    }
+}
 /// Errors returned by UploadDocuments
 #[derive(Debug, PartialEq)]
 pub enum UploadDocumentsError {
@@ -442,6 +456,13 @@ impl fmt::Display for UploadDocumentsError {
     }
 }
 impl Error for UploadDocumentsError {}
+impl ServiceErrorCode for UploadDocumentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UploadDocumentsError::DocumentService(_) => "DocumentServiceException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon CloudSearch Domain API. Amazon CloudSearch Domain clients implement this trait.
 #[async_trait]
 pub trait CloudSearchDomain {
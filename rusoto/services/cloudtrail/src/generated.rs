@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1059,6 +1059,23 @@ impl fmt::Display for AddTagsError {
     }
 }
 impl Error for AddTagsError {}
+impl ServiceErrorCode for AddTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsError::CloudTrailARNInvalid(_) => "CloudTrailARNInvalidException",
+            AddTagsError::InvalidTagParameter(_) => "InvalidTagParameterException",
+            AddTagsError::InvalidTrailName(_) => "InvalidTrailNameException",
+            AddTagsError::NotOrganizationMasterAccount(_) => {
+                "NotOrganizationMasterAccountException"
+            }
+            AddTagsError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            AddTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AddTagsError::ResourceTypeNotSupported(_) => "ResourceTypeNotSupportedException",
+            AddTagsError::TagsLimitExceeded(_) => "TagsLimitExceededException",
+            AddTagsError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by CreateTrail
 #[derive(Debug, PartialEq)]
 pub enum CreateTrailError {
@@ -1279,6 +1296,66 @@ impl fmt::Display for CreateTrailError {
     }
 }
 impl Error for CreateTrailError {}
+impl ServiceErrorCode for CreateTrailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTrailError::CloudTrailAccessNotEnabled(_) => {
+                "CloudTrailAccessNotEnabledException"
+            }
+            CreateTrailError::CloudTrailInvalidClientTokenId(_) => {
+                "CloudTrailInvalidClientTokenIdException"
+            }
+            CreateTrailError::CloudWatchLogsDeliveryUnavailable(_) => {
+                "CloudWatchLogsDeliveryUnavailableException"
+            }
+            CreateTrailError::InsufficientDependencyServiceAccessPermission(_) => {
+                "InsufficientDependencyServiceAccessPermissionException"
+            }
+            CreateTrailError::InsufficientEncryptionPolicy(_) => {
+                "InsufficientEncryptionPolicyException"
+            }
+            CreateTrailError::InsufficientS3BucketPolicy(_) => {
+                "InsufficientS3BucketPolicyException"
+            }
+            CreateTrailError::InsufficientSnsTopicPolicy(_) => {
+                "InsufficientSnsTopicPolicyException"
+            }
+            CreateTrailError::InvalidCloudWatchLogsLogGroupArn(_) => {
+                "InvalidCloudWatchLogsLogGroupArnException"
+            }
+            CreateTrailError::InvalidCloudWatchLogsRoleArn(_) => {
+                "InvalidCloudWatchLogsRoleArnException"
+            }
+            CreateTrailError::InvalidKmsKeyId(_) => "InvalidKmsKeyIdException",
+            CreateTrailError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            CreateTrailError::InvalidS3BucketName(_) => "InvalidS3BucketNameException",
+            CreateTrailError::InvalidS3Prefix(_) => "InvalidS3PrefixException",
+            CreateTrailError::InvalidSnsTopicName(_) => "InvalidSnsTopicNameException",
+            CreateTrailError::InvalidTagParameter(_) => "InvalidTagParameterException",
+            CreateTrailError::InvalidTrailName(_) => "InvalidTrailNameException",
+            CreateTrailError::Kms(_) => "KmsException",
+            CreateTrailError::KmsKeyDisabled(_) => "KmsKeyDisabledException",
+            CreateTrailError::KmsKeyNotFound(_) => "KmsKeyNotFoundException",
+            CreateTrailError::MaximumNumberOfTrailsExceeded(_) => {
+                "MaximumNumberOfTrailsExceededException"
+            }
+            CreateTrailError::NotOrganizationMasterAccount(_) => {
+                "NotOrganizationMasterAccountException"
+            }
+            CreateTrailError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            CreateTrailError::OrganizationNotInAllFeaturesMode(_) => {
+                "OrganizationNotInAllFeaturesModeException"
+            }
+            CreateTrailError::OrganizationsNotInUse(_) => "OrganizationsNotInUseException",
+            CreateTrailError::S3BucketDoesNotExist(_) => "S3BucketDoesNotExistException",
+            CreateTrailError::TrailAlreadyExists(_) => "TrailAlreadyExistsException",
+            CreateTrailError::TrailNotProvided(_) => "TrailNotProvidedException",
+            CreateTrailError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DeleteTrail
 #[derive(Debug, PartialEq)]
 pub enum DeleteTrailError {
@@ -1351,6 +1428,23 @@ impl fmt::Display for DeleteTrailError {
     }
 }
 impl Error for DeleteTrailError {}
+impl ServiceErrorCode for DeleteTrailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTrailError::InsufficientDependencyServiceAccessPermission(_) => {
+                "InsufficientDependencyServiceAccessPermissionException"
+            }
+            DeleteTrailError::InvalidHomeRegion(_) => "InvalidHomeRegionException",
+            DeleteTrailError::InvalidTrailName(_) => "InvalidTrailNameException",
+            DeleteTrailError::NotOrganizationMasterAccount(_) => {
+                "NotOrganizationMasterAccountException"
+            }
+            DeleteTrailError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            DeleteTrailError::TrailNotFound(_) => "TrailNotFoundException",
+            DeleteTrailError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DescribeTrails
 #[derive(Debug, PartialEq)]
 pub enum DescribeTrailsError {
@@ -1395,6 +1489,15 @@ impl fmt::Display for DescribeTrailsError {
     }
 }
 impl Error for DescribeTrailsError {}
+impl ServiceErrorCode for DescribeTrailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTrailsError::InvalidTrailName(_) => "InvalidTrailNameException",
+            DescribeTrailsError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            DescribeTrailsError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by GetEventSelectors
 #[derive(Debug, PartialEq)]
 pub enum GetEventSelectorsError {
@@ -1447,6 +1550,16 @@ impl fmt::Display for GetEventSelectorsError {
     }
 }
 impl Error for GetEventSelectorsError {}
+impl ServiceErrorCode for GetEventSelectorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEventSelectorsError::InvalidTrailName(_) => "InvalidTrailNameException",
+            GetEventSelectorsError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            GetEventSelectorsError::TrailNotFound(_) => "TrailNotFoundException",
+            GetEventSelectorsError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by GetInsightSelectors
 #[derive(Debug, PartialEq)]
 pub enum GetInsightSelectorsError {
@@ -1509,6 +1622,17 @@ impl fmt::Display for GetInsightSelectorsError {
     }
 }
 impl Error for GetInsightSelectorsError {}
+impl ServiceErrorCode for GetInsightSelectorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInsightSelectorsError::InsightNotEnabled(_) => "InsightNotEnabledException",
+            GetInsightSelectorsError::InvalidTrailName(_) => "InvalidTrailNameException",
+            GetInsightSelectorsError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            GetInsightSelectorsError::TrailNotFound(_) => "TrailNotFoundException",
+            GetInsightSelectorsError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by GetTrail
 #[derive(Debug, PartialEq)]
 pub enum GetTrailError {
@@ -1557,6 +1681,16 @@ impl fmt::Display for GetTrailError {
     }
 }
 impl Error for GetTrailError {}
+impl ServiceErrorCode for GetTrailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTrailError::InvalidTrailName(_) => "InvalidTrailNameException",
+            GetTrailError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            GetTrailError::TrailNotFound(_) => "TrailNotFoundException",
+            GetTrailError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by GetTrailStatus
 #[derive(Debug, PartialEq)]
 pub enum GetTrailStatusError {
@@ -1607,6 +1741,16 @@ impl fmt::Display for GetTrailStatusError {
     }
 }
 impl Error for GetTrailStatusError {}
+impl ServiceErrorCode for GetTrailStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTrailStatusError::InvalidTrailName(_) => "InvalidTrailNameException",
+            GetTrailStatusError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            GetTrailStatusError::TrailNotFound(_) => "TrailNotFoundException",
+            GetTrailStatusError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by ListPublicKeys
 #[derive(Debug, PartialEq)]
 pub enum ListPublicKeysError {
@@ -1657,6 +1801,16 @@ impl fmt::Display for ListPublicKeysError {
     }
 }
 impl Error for ListPublicKeysError {}
+impl ServiceErrorCode for ListPublicKeysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPublicKeysError::InvalidTimeRange(_) => "InvalidTimeRangeException",
+            ListPublicKeysError::InvalidToken(_) => "InvalidTokenException",
+            ListPublicKeysError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            ListPublicKeysError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
 pub enum ListTagsError {
@@ -1723,6 +1877,19 @@ impl fmt::Display for ListTagsError {
     }
 }
 impl Error for ListTagsError {}
+impl ServiceErrorCode for ListTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsError::CloudTrailARNInvalid(_) => "CloudTrailARNInvalidException",
+            ListTagsError::InvalidToken(_) => "InvalidTokenException",
+            ListTagsError::InvalidTrailName(_) => "InvalidTrailNameException",
+            ListTagsError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            ListTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsError::ResourceTypeNotSupported(_) => "ResourceTypeNotSupportedException",
+            ListTagsError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by ListTrails
 #[derive(Debug, PartialEq)]
 pub enum ListTrailsError {
@@ -1759,6 +1926,14 @@ impl fmt::Display for ListTrailsError {
     }
 }
 impl Error for ListTrailsError {}
+impl ServiceErrorCode for ListTrailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTrailsError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            ListTrailsError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by LookupEvents
 #[derive(Debug, PartialEq)]
 pub enum LookupEventsError {
@@ -1827,6 +2002,19 @@ impl fmt::Display for LookupEventsError {
     }
 }
 impl Error for LookupEventsError {}
+impl ServiceErrorCode for LookupEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            LookupEventsError::InvalidEventCategory(_) => "InvalidEventCategoryException",
+            LookupEventsError::InvalidLookupAttributes(_) => "InvalidLookupAttributesException",
+            LookupEventsError::InvalidMaxResults(_) => "InvalidMaxResultsException",
+            LookupEventsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            LookupEventsError::InvalidTimeRange(_) => "InvalidTimeRangeException",
+            LookupEventsError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            LookupEventsError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by PutEventSelectors
 #[derive(Debug, PartialEq)]
 pub enum PutEventSelectorsError {
@@ -1915,6 +2103,24 @@ impl fmt::Display for PutEventSelectorsError {
     }
 }
 impl Error for PutEventSelectorsError {}
+impl ServiceErrorCode for PutEventSelectorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutEventSelectorsError::InsufficientDependencyServiceAccessPermission(_) => {
+                "InsufficientDependencyServiceAccessPermissionException"
+            }
+            PutEventSelectorsError::InvalidEventSelectors(_) => "InvalidEventSelectorsException",
+            PutEventSelectorsError::InvalidHomeRegion(_) => "InvalidHomeRegionException",
+            PutEventSelectorsError::InvalidTrailName(_) => "InvalidTrailNameException",
+            PutEventSelectorsError::NotOrganizationMasterAccount(_) => {
+                "NotOrganizationMasterAccountException"
+            }
+            PutEventSelectorsError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            PutEventSelectorsError::TrailNotFound(_) => "TrailNotFoundException",
+            PutEventSelectorsError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by PutInsightSelectors
 #[derive(Debug, PartialEq)]
 pub enum PutInsightSelectorsError {
@@ -2015,6 +2221,29 @@ impl fmt::Display for PutInsightSelectorsError {
     }
 }
 impl Error for PutInsightSelectorsError {}
+impl ServiceErrorCode for PutInsightSelectorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutInsightSelectorsError::InsufficientEncryptionPolicy(_) => {
+                "InsufficientEncryptionPolicyException"
+            }
+            PutInsightSelectorsError::InsufficientS3BucketPolicy(_) => {
+                "InsufficientS3BucketPolicyException"
+            }
+            PutInsightSelectorsError::InvalidHomeRegion(_) => "InvalidHomeRegionException",
+            PutInsightSelectorsError::InvalidInsightSelectors(_) => {
+                "InvalidInsightSelectorsException"
+            }
+            PutInsightSelectorsError::InvalidTrailName(_) => "InvalidTrailNameException",
+            PutInsightSelectorsError::NotOrganizationMasterAccount(_) => {
+                "NotOrganizationMasterAccountException"
+            }
+            PutInsightSelectorsError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            PutInsightSelectorsError::TrailNotFound(_) => "TrailNotFoundException",
+            PutInsightSelectorsError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by RemoveTags
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsError {
@@ -2089,6 +2318,22 @@ impl fmt::Display for RemoveTagsError {
     }
 }
 impl Error for RemoveTagsError {}
+impl ServiceErrorCode for RemoveTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsError::CloudTrailARNInvalid(_) => "CloudTrailARNInvalidException",
+            RemoveTagsError::InvalidTagParameter(_) => "InvalidTagParameterException",
+            RemoveTagsError::InvalidTrailName(_) => "InvalidTrailNameException",
+            RemoveTagsError::NotOrganizationMasterAccount(_) => {
+                "NotOrganizationMasterAccountException"
+            }
+            RemoveTagsError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            RemoveTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RemoveTagsError::ResourceTypeNotSupported(_) => "ResourceTypeNotSupportedException",
+            RemoveTagsError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by StartLogging
 #[derive(Debug, PartialEq)]
 pub enum StartLoggingError {
@@ -2161,6 +2406,23 @@ impl fmt::Display for StartLoggingError {
     }
 }
 impl Error for StartLoggingError {}
+impl ServiceErrorCode for StartLoggingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartLoggingError::InsufficientDependencyServiceAccessPermission(_) => {
+                "InsufficientDependencyServiceAccessPermissionException"
+            }
+            StartLoggingError::InvalidHomeRegion(_) => "InvalidHomeRegionException",
+            StartLoggingError::InvalidTrailName(_) => "InvalidTrailNameException",
+            StartLoggingError::NotOrganizationMasterAccount(_) => {
+                "NotOrganizationMasterAccountException"
+            }
+            StartLoggingError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            StartLoggingError::TrailNotFound(_) => "TrailNotFoundException",
+            StartLoggingError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by StopLogging
 #[derive(Debug, PartialEq)]
 pub enum StopLoggingError {
@@ -2233,6 +2495,23 @@ impl fmt::Display for StopLoggingError {
     }
 }
 impl Error for StopLoggingError {}
+impl ServiceErrorCode for StopLoggingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopLoggingError::InsufficientDependencyServiceAccessPermission(_) => {
+                "InsufficientDependencyServiceAccessPermissionException"
+            }
+            StopLoggingError::InvalidHomeRegion(_) => "InvalidHomeRegionException",
+            StopLoggingError::InvalidTrailName(_) => "InvalidTrailNameException",
+            StopLoggingError::NotOrganizationMasterAccount(_) => {
+                "NotOrganizationMasterAccountException"
+            }
+            StopLoggingError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            StopLoggingError::TrailNotFound(_) => "TrailNotFoundException",
+            StopLoggingError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by UpdateTrail
 #[derive(Debug, PartialEq)]
 pub enum UpdateTrailError {
@@ -2451,6 +2730,64 @@ impl fmt::Display for UpdateTrailError {
     }
 }
 impl Error for UpdateTrailError {}
+impl ServiceErrorCode for UpdateTrailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTrailError::CloudTrailAccessNotEnabled(_) => {
+                "CloudTrailAccessNotEnabledException"
+            }
+            UpdateTrailError::CloudTrailInvalidClientTokenId(_) => {
+                "CloudTrailInvalidClientTokenIdException"
+            }
+            UpdateTrailError::CloudWatchLogsDeliveryUnavailable(_) => {
+                "CloudWatchLogsDeliveryUnavailableException"
+            }
+            UpdateTrailError::InsufficientDependencyServiceAccessPermission(_) => {
+                "InsufficientDependencyServiceAccessPermissionException"
+            }
+            UpdateTrailError::InsufficientEncryptionPolicy(_) => {
+                "InsufficientEncryptionPolicyException"
+            }
+            UpdateTrailError::InsufficientS3BucketPolicy(_) => {
+                "InsufficientS3BucketPolicyException"
+            }
+            UpdateTrailError::InsufficientSnsTopicPolicy(_) => {
+                "InsufficientSnsTopicPolicyException"
+            }
+            UpdateTrailError::InvalidCloudWatchLogsLogGroupArn(_) => {
+                "InvalidCloudWatchLogsLogGroupArnException"
+            }
+            UpdateTrailError::InvalidCloudWatchLogsRoleArn(_) => {
+                "InvalidCloudWatchLogsRoleArnException"
+            }
+            UpdateTrailError::InvalidEventSelectors(_) => "InvalidEventSelectorsException",
+            UpdateTrailError::InvalidHomeRegion(_) => "InvalidHomeRegionException",
+            UpdateTrailError::InvalidKmsKeyId(_) => "InvalidKmsKeyIdException",
+            UpdateTrailError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            UpdateTrailError::InvalidS3BucketName(_) => "InvalidS3BucketNameException",
+            UpdateTrailError::InvalidS3Prefix(_) => "InvalidS3PrefixException",
+            UpdateTrailError::InvalidSnsTopicName(_) => "InvalidSnsTopicNameException",
+            UpdateTrailError::InvalidTrailName(_) => "InvalidTrailNameException",
+            UpdateTrailError::Kms(_) => "KmsException",
+            UpdateTrailError::KmsKeyDisabled(_) => "KmsKeyDisabledException",
+            UpdateTrailError::KmsKeyNotFound(_) => "KmsKeyNotFoundException",
+            UpdateTrailError::NotOrganizationMasterAccount(_) => {
+                "NotOrganizationMasterAccountException"
+            }
+            UpdateTrailError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            UpdateTrailError::OrganizationNotInAllFeaturesMode(_) => {
+                "OrganizationNotInAllFeaturesModeException"
+            }
+            UpdateTrailError::OrganizationsNotInUse(_) => "OrganizationsNotInUseException",
+            UpdateTrailError::S3BucketDoesNotExist(_) => "S3BucketDoesNotExistException",
+            UpdateTrailError::TrailNotFound(_) => "TrailNotFoundException",
+            UpdateTrailError::TrailNotProvided(_) => "TrailNotProvidedException",
+            UpdateTrailError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Trait representing the capabilities of the CloudTrail API. CloudTrail clients implement this trait.
 #[async_trait]
 pub trait CloudTrail {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -4762,6 +4762,13 @@ impl fmt::Display for DeleteAlarmsError {
     }
 }
 impl Error for DeleteAlarmsError {}
+impl ServiceErrorCode for DeleteAlarmsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAlarmsError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteAnomalyDetector
 #[derive(Debug, PartialEq)]
 pub enum DeleteAnomalyDetectorError {
@@ -4834,6 +4841,16 @@ impl fmt::Display for DeleteAnomalyDetectorError {
     }
 }
 impl Error for DeleteAnomalyDetectorError {}
+impl ServiceErrorCode for DeleteAnomalyDetectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAnomalyDetectorError::InternalServiceFault(_) => "InternalServiceError",
+            DeleteAnomalyDetectorError::InvalidParameterValue(_) => "InvalidParameterValue",
+            DeleteAnomalyDetectorError::MissingRequiredParameter(_) => "MissingParameter",
+            DeleteAnomalyDetectorError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteDashboards
 #[derive(Debug, PartialEq)]
 pub enum DeleteDashboardsError {
@@ -4894,6 +4911,15 @@ impl fmt::Display for DeleteDashboardsError {
     }
 }
 impl Error for DeleteDashboardsError {}
+impl ServiceErrorCode for DeleteDashboardsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDashboardsError::DashboardNotFoundError(_) => "ResourceNotFound",
+            DeleteDashboardsError::InternalServiceFault(_) => "InternalServiceError",
+            DeleteDashboardsError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by DeleteInsightRules
 #[derive(Debug, PartialEq)]
 pub enum DeleteInsightRulesError {
@@ -4946,6 +4972,14 @@ impl fmt::Display for DeleteInsightRulesError {
     }
 }
 impl Error for DeleteInsightRulesError {}
+impl ServiceErrorCode for DeleteInsightRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInsightRulesError::InvalidParameterValue(_) => "InvalidParameterValue",
+            DeleteInsightRulesError::MissingRequiredParameter(_) => "MissingParameter",
+        }
+    }
+}
 /// Errors returned by DescribeAlarmHistory
 #[derive(Debug, PartialEq)]
 pub enum DescribeAlarmHistoryError {
@@ -4990,6 +5024,13 @@ impl fmt::Display for DescribeAlarmHistoryError {
     }
 }
 impl Error for DescribeAlarmHistoryError {}
+impl ServiceErrorCode for DescribeAlarmHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAlarmHistoryError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DescribeAlarms
 #[derive(Debug, PartialEq)]
 pub enum DescribeAlarmsError {
@@ -5034,6 +5075,13 @@ impl fmt::Display for DescribeAlarmsError {
     }
 }
 impl Error for DescribeAlarmsError {}
+impl ServiceErrorCode for DescribeAlarmsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAlarmsError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DescribeAlarmsForMetric
 #[derive(Debug, PartialEq)]
 pub enum DescribeAlarmsForMetricError {}
@@ -5068,6 +5116,11 @@ impl fmt::Display for DescribeAlarmsForMetricError {
     }
 }
 impl Error for DescribeAlarmsForMetricError {}
+impl ServiceErrorCode for DescribeAlarmsForMetricError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeAnomalyDetectors
 #[derive(Debug, PartialEq)]
 pub enum DescribeAnomalyDetectorsError {
@@ -5136,6 +5189,15 @@ impl fmt::Display for DescribeAnomalyDetectorsError {
     }
 }
 impl Error for DescribeAnomalyDetectorsError {}
+impl ServiceErrorCode for DescribeAnomalyDetectorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAnomalyDetectorsError::InternalServiceFault(_) => "InternalServiceError",
+            DescribeAnomalyDetectorsError::InvalidNextToken(_) => "InvalidNextToken",
+            DescribeAnomalyDetectorsError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by DescribeInsightRules
 #[derive(Debug, PartialEq)]
 pub enum DescribeInsightRulesError {
@@ -5180,6 +5242,13 @@ impl fmt::Display for DescribeInsightRulesError {
     }
 }
 impl Error for DescribeInsightRulesError {}
+impl ServiceErrorCode for DescribeInsightRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInsightRulesError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DisableAlarmActions
 #[derive(Debug, PartialEq)]
 pub enum DisableAlarmActionsError {}
@@ -5214,6 +5283,11 @@ impl fmt::Display for DisableAlarmActionsError {
     }
 }
 impl Error for DisableAlarmActionsError {}
+impl ServiceErrorCode for DisableAlarmActionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisableInsightRules
 #[derive(Debug, PartialEq)]
 pub enum DisableInsightRulesError {
@@ -5268,6 +5342,14 @@ impl fmt::Display for DisableInsightRulesError {
     }
 }
 impl Error for DisableInsightRulesError {}
+impl ServiceErrorCode for DisableInsightRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableInsightRulesError::InvalidParameterValue(_) => "InvalidParameterValue",
+            DisableInsightRulesError::MissingRequiredParameter(_) => "MissingParameter",
+        }
+    }
+}
 /// Errors returned by EnableAlarmActions
 #[derive(Debug, PartialEq)]
 pub enum EnableAlarmActionsError {}
@@ -5302,6 +5384,11 @@ impl fmt::Display for EnableAlarmActionsError {
     }
 }
 impl Error for EnableAlarmActionsError {}
+impl ServiceErrorCode for EnableAlarmActionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by EnableInsightRules
 #[derive(Debug, PartialEq)]
 pub enum EnableInsightRulesError {
@@ -5362,6 +5449,15 @@ impl fmt::Display for EnableInsightRulesError {
     }
 }
 impl Error for EnableInsightRulesError {}
+impl ServiceErrorCode for EnableInsightRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableInsightRulesError::InvalidParameterValue(_) => "InvalidParameterValue",
+            EnableInsightRulesError::LimitExceeded(_) => "LimitExceededException",
+            EnableInsightRulesError::MissingRequiredParameter(_) => "MissingParameter",
+        }
+    }
+}
 /// Errors returned by GetDashboard
 #[derive(Debug, PartialEq)]
 pub enum GetDashboardError {
@@ -5422,6 +5518,15 @@ impl fmt::Display for GetDashboardError {
     }
 }
 impl Error for GetDashboardError {}
+impl ServiceErrorCode for GetDashboardError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDashboardError::DashboardNotFoundError(_) => "ResourceNotFound",
+            GetDashboardError::InternalServiceFault(_) => "InternalServiceError",
+            GetDashboardError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by GetInsightRuleReport
 #[derive(Debug, PartialEq)]
 pub enum GetInsightRuleReportError {
@@ -5486,6 +5591,15 @@ impl fmt::Display for GetInsightRuleReportError {
     }
 }
 impl Error for GetInsightRuleReportError {}
+impl ServiceErrorCode for GetInsightRuleReportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInsightRuleReportError::InvalidParameterValue(_) => "InvalidParameterValue",
+            GetInsightRuleReportError::MissingRequiredParameter(_) => "MissingParameter",
+            GetInsightRuleReportError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetMetricData
 #[derive(Debug, PartialEq)]
 pub enum GetMetricDataError {
@@ -5530,6 +5644,13 @@ impl fmt::Display for GetMetricDataError {
     }
 }
 impl Error for GetMetricDataError {}
+impl ServiceErrorCode for GetMetricDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMetricDataError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by GetMetricStatistics
 #[derive(Debug, PartialEq)]
 pub enum GetMetricStatisticsError {
@@ -5604,6 +5725,18 @@ impl fmt::Display for GetMetricStatisticsError {
     }
 }
 impl Error for GetMetricStatisticsError {}
+impl ServiceErrorCode for GetMetricStatisticsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMetricStatisticsError::InternalServiceFault(_) => "InternalServiceError",
+            GetMetricStatisticsError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            GetMetricStatisticsError::InvalidParameterValue(_) => "InvalidParameterValue",
+            GetMetricStatisticsError::MissingRequiredParameter(_) => "MissingParameter",
+        }
+    }
+}
 /// Errors returned by GetMetricWidgetImage
 #[derive(Debug, PartialEq)]
 pub enum GetMetricWidgetImageError {}
@@ -5638,6 +5771,11 @@ impl fmt::Display for GetMetricWidgetImageError {
     }
 }
 impl Error for GetMetricWidgetImageError {}
+impl ServiceErrorCode for GetMetricWidgetImageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListDashboards
 #[derive(Debug, PartialEq)]
 pub enum ListDashboardsError {
@@ -5690,6 +5828,14 @@ impl fmt::Display for ListDashboardsError {
     }
 }
 impl Error for ListDashboardsError {}
+impl ServiceErrorCode for ListDashboardsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDashboardsError::InternalServiceFault(_) => "InternalServiceError",
+            ListDashboardsError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by ListMetrics
 #[derive(Debug, PartialEq)]
 pub enum ListMetricsError {
@@ -5742,6 +5888,14 @@ impl fmt::Display for ListMetricsError {
     }
 }
 impl Error for ListMetricsError {}
+impl ServiceErrorCode for ListMetricsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMetricsError::InternalServiceFault(_) => "InternalServiceError",
+            ListMetricsError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -5802,6 +5956,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalServiceFault(_) => "InternalServiceError",
+            ListTagsForResourceError::InvalidParameterValue(_) => "InvalidParameterValue",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutAnomalyDetector
 #[derive(Debug, PartialEq)]
 pub enum PutAnomalyDetectorError {
@@ -5870,6 +6033,16 @@ impl fmt::Display for PutAnomalyDetectorError {
     }
 }
 impl Error for PutAnomalyDetectorError {}
+impl ServiceErrorCode for PutAnomalyDetectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAnomalyDetectorError::InternalServiceFault(_) => "InternalServiceError",
+            PutAnomalyDetectorError::InvalidParameterValue(_) => "InvalidParameterValue",
+            PutAnomalyDetectorError::LimitExceeded(_) => "LimitExceededException",
+            PutAnomalyDetectorError::MissingRequiredParameter(_) => "MissingParameter",
+        }
+    }
+}
 /// Errors returned by PutCompositeAlarm
 #[derive(Debug, PartialEq)]
 pub enum PutCompositeAlarmError {
@@ -5914,6 +6087,13 @@ impl fmt::Display for PutCompositeAlarmError {
     }
 }
 impl Error for PutCompositeAlarmError {}
+impl ServiceErrorCode for PutCompositeAlarmError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutCompositeAlarmError::LimitExceededFault(_) => "LimitExceeded",
+        }
+    }
+}
 /// Errors returned by PutDashboard
 #[derive(Debug, PartialEq)]
 pub enum PutDashboardError {
@@ -5966,6 +6146,14 @@ impl fmt::Display for PutDashboardError {
     }
 }
 impl Error for PutDashboardError {}
+impl ServiceErrorCode for PutDashboardError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutDashboardError::DashboardInvalidInputError(_) => "InvalidParameterInput",
+            PutDashboardError::InternalServiceFault(_) => "InternalServiceError",
+        }
+    }
+}
 /// Errors returned by PutInsightRule
 #[derive(Debug, PartialEq)]
 pub enum PutInsightRuleError {
@@ -6026,6 +6214,15 @@ impl fmt::Display for PutInsightRuleError {
     }
 }
 impl Error for PutInsightRuleError {}
+impl ServiceErrorCode for PutInsightRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutInsightRuleError::InvalidParameterValue(_) => "InvalidParameterValue",
+            PutInsightRuleError::LimitExceeded(_) => "LimitExceededException",
+            PutInsightRuleError::MissingRequiredParameter(_) => "MissingParameter",
+        }
+    }
+}
 /// Errors returned by PutMetricAlarm
 #[derive(Debug, PartialEq)]
 pub enum PutMetricAlarmError {
@@ -6070,6 +6267,13 @@ impl fmt::Display for PutMetricAlarmError {
     }
 }
 impl Error for PutMetricAlarmError {}
+impl ServiceErrorCode for PutMetricAlarmError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutMetricAlarmError::LimitExceededFault(_) => "LimitExceeded",
+        }
+    }
+}
 /// Errors returned by PutMetricData
 #[derive(Debug, PartialEq)]
 pub enum PutMetricDataError {
@@ -6138,6 +6342,16 @@ impl fmt::Display for PutMetricDataError {
     }
 }
 impl Error for PutMetricDataError {}
+impl ServiceErrorCode for PutMetricDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutMetricDataError::InternalServiceFault(_) => "InternalServiceError",
+            PutMetricDataError::InvalidParameterCombination(_) => "InvalidParameterCombination",
+            PutMetricDataError::InvalidParameterValue(_) => "InvalidParameterValue",
+            PutMetricDataError::MissingRequiredParameter(_) => "MissingParameter",
+        }
+    }
+}
 /// Errors returned by SetAlarmState
 #[derive(Debug, PartialEq)]
 pub enum SetAlarmStateError {
@@ -6190,6 +6404,14 @@ impl fmt::Display for SetAlarmStateError {
     }
 }
 impl Error for SetAlarmStateError {}
+impl ServiceErrorCode for SetAlarmStateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetAlarmStateError::InvalidFormatFault(_) => "InvalidFormat",
+            SetAlarmStateError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -6258,6 +6480,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            TagResourceError::InternalServiceFault(_) => "InternalServiceError",
+            TagResourceError::InvalidParameterValue(_) => "InvalidParameterValue",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -6326,6 +6558,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UntagResourceError::InternalServiceFault(_) => "InternalServiceError",
+            UntagResourceError::InvalidParameterValue(_) => "InvalidParameterValue",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the CloudWatch API. CloudWatch clients implement this trait.
 #[async_trait]
 pub trait CloudWatch {
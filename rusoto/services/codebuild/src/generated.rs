@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2895,6 +2895,13 @@ impl fmt::Display for BatchDeleteBuildsError {
     }
 }
 impl Error for BatchDeleteBuildsError {}
+impl ServiceErrorCode for BatchDeleteBuildsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDeleteBuildsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by BatchGetBuildBatches
 #[derive(Debug, PartialEq)]
 pub enum BatchGetBuildBatchesError {
@@ -2925,6 +2932,13 @@ impl fmt::Display for BatchGetBuildBatchesError {
     }
 }
 impl Error for BatchGetBuildBatchesError {}
+impl ServiceErrorCode for BatchGetBuildBatchesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetBuildBatchesError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by BatchGetBuilds
 #[derive(Debug, PartialEq)]
 pub enum BatchGetBuildsError {
@@ -2955,6 +2969,13 @@ impl fmt::Display for BatchGetBuildsError {
     }
 }
 impl Error for BatchGetBuildsError {}
+impl ServiceErrorCode for BatchGetBuildsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetBuildsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by BatchGetProjects
 #[derive(Debug, PartialEq)]
 pub enum BatchGetProjectsError {
@@ -2985,6 +3006,13 @@ impl fmt::Display for BatchGetProjectsError {
     }
 }
 impl Error for BatchGetProjectsError {}
+impl ServiceErrorCode for BatchGetProjectsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetProjectsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by BatchGetReportGroups
 #[derive(Debug, PartialEq)]
 pub enum BatchGetReportGroupsError {
@@ -3015,6 +3043,13 @@ impl fmt::Display for BatchGetReportGroupsError {
     }
 }
 impl Error for BatchGetReportGroupsError {}
+impl ServiceErrorCode for BatchGetReportGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetReportGroupsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by BatchGetReports
 #[derive(Debug, PartialEq)]
 pub enum BatchGetReportsError {
@@ -3045,6 +3080,13 @@ impl fmt::Display for BatchGetReportsError {
     }
 }
 impl Error for BatchGetReportsError {}
+impl ServiceErrorCode for BatchGetReportsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetReportsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by CreateProject
 #[derive(Debug, PartialEq)]
 pub enum CreateProjectError {
@@ -3087,6 +3129,15 @@ impl fmt::Display for CreateProjectError {
     }
 }
 impl Error for CreateProjectError {}
+impl ServiceErrorCode for CreateProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProjectError::AccountLimitExceeded(_) => "AccountLimitExceededException",
+            CreateProjectError::InvalidInput(_) => "InvalidInputException",
+            CreateProjectError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by CreateReportGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateReportGroupError {
@@ -3133,6 +3184,15 @@ impl fmt::Display for CreateReportGroupError {
     }
 }
 impl Error for CreateReportGroupError {}
+impl ServiceErrorCode for CreateReportGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateReportGroupError::AccountLimitExceeded(_) => "AccountLimitExceededException",
+            CreateReportGroupError::InvalidInput(_) => "InvalidInputException",
+            CreateReportGroupError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by CreateWebhook
 #[derive(Debug, PartialEq)]
 pub enum CreateWebhookError {
@@ -3181,6 +3241,16 @@ impl fmt::Display for CreateWebhookError {
     }
 }
 impl Error for CreateWebhookError {}
+impl ServiceErrorCode for CreateWebhookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateWebhookError::InvalidInput(_) => "InvalidInputException",
+            CreateWebhookError::OAuthProvider(_) => "OAuthProviderException",
+            CreateWebhookError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateWebhookError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteBuildBatch
 #[derive(Debug, PartialEq)]
 pub enum DeleteBuildBatchError {
@@ -3211,6 +3281,13 @@ impl fmt::Display for DeleteBuildBatchError {
     }
 }
 impl Error for DeleteBuildBatchError {}
+impl ServiceErrorCode for DeleteBuildBatchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBuildBatchError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DeleteProject
 #[derive(Debug, PartialEq)]
 pub enum DeleteProjectError {
@@ -3241,6 +3318,13 @@ impl fmt::Display for DeleteProjectError {
     }
 }
 impl Error for DeleteProjectError {}
+impl ServiceErrorCode for DeleteProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProjectError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DeleteReport
 #[derive(Debug, PartialEq)]
 pub enum DeleteReportError {
@@ -3271,6 +3355,13 @@ impl fmt::Display for DeleteReportError {
     }
 }
 impl Error for DeleteReportError {}
+impl ServiceErrorCode for DeleteReportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteReportError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DeleteReportGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteReportGroupError {
@@ -3301,6 +3392,13 @@ impl fmt::Display for DeleteReportGroupError {
     }
 }
 impl Error for DeleteReportGroupError {}
+impl ServiceErrorCode for DeleteReportGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteReportGroupError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DeleteResourcePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteResourcePolicyError {
@@ -3331,6 +3429,13 @@ impl fmt::Display for DeleteResourcePolicyError {
     }
 }
 impl Error for DeleteResourcePolicyError {}
+impl ServiceErrorCode for DeleteResourcePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResourcePolicyError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DeleteSourceCredentials
 #[derive(Debug, PartialEq)]
 pub enum DeleteSourceCredentialsError {
@@ -3371,6 +3476,14 @@ impl fmt::Display for DeleteSourceCredentialsError {
     }
 }
 impl Error for DeleteSourceCredentialsError {}
+impl ServiceErrorCode for DeleteSourceCredentialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSourceCredentialsError::InvalidInput(_) => "InvalidInputException",
+            DeleteSourceCredentialsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteWebhook
 #[derive(Debug, PartialEq)]
 pub enum DeleteWebhookError {
@@ -3413,6 +3526,15 @@ impl fmt::Display for DeleteWebhookError {
     }
 }
 impl Error for DeleteWebhookError {}
+impl ServiceErrorCode for DeleteWebhookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteWebhookError::InvalidInput(_) => "InvalidInputException",
+            DeleteWebhookError::OAuthProvider(_) => "OAuthProviderException",
+            DeleteWebhookError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeCodeCoverages
 #[derive(Debug, PartialEq)]
 pub enum DescribeCodeCoveragesError {
@@ -3443,6 +3565,13 @@ impl fmt::Display for DescribeCodeCoveragesError {
     }
 }
 impl Error for DescribeCodeCoveragesError {}
+impl ServiceErrorCode for DescribeCodeCoveragesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCodeCoveragesError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DescribeTestCases
 #[derive(Debug, PartialEq)]
 pub enum DescribeTestCasesError {
@@ -3479,6 +3608,14 @@ impl fmt::Display for DescribeTestCasesError {
     }
 }
 impl Error for DescribeTestCasesError {}
+impl ServiceErrorCode for DescribeTestCasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTestCasesError::InvalidInput(_) => "InvalidInputException",
+            DescribeTestCasesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetReportGroupTrend
 #[derive(Debug, PartialEq)]
 pub enum GetReportGroupTrendError {
@@ -3517,6 +3654,14 @@ impl fmt::Display for GetReportGroupTrendError {
     }
 }
 impl Error for GetReportGroupTrendError {}
+impl ServiceErrorCode for GetReportGroupTrendError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetReportGroupTrendError::InvalidInput(_) => "InvalidInputException",
+            GetReportGroupTrendError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetResourcePolicy
 #[derive(Debug, PartialEq)]
 pub enum GetResourcePolicyError {
@@ -3553,6 +3698,14 @@ impl fmt::Display for GetResourcePolicyError {
     }
 }
 impl Error for GetResourcePolicyError {}
+impl ServiceErrorCode for GetResourcePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourcePolicyError::InvalidInput(_) => "InvalidInputException",
+            GetResourcePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ImportSourceCredentials
 #[derive(Debug, PartialEq)]
 pub enum ImportSourceCredentialsError {
@@ -3603,6 +3756,19 @@ impl fmt::Display for ImportSourceCredentialsError {
     }
 }
 impl Error for ImportSourceCredentialsError {}
+impl ServiceErrorCode for ImportSourceCredentialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportSourceCredentialsError::AccountLimitExceeded(_) => {
+                "AccountLimitExceededException"
+            }
+            ImportSourceCredentialsError::InvalidInput(_) => "InvalidInputException",
+            ImportSourceCredentialsError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+        }
+    }
+}
 /// Errors returned by InvalidateProjectCache
 #[derive(Debug, PartialEq)]
 pub enum InvalidateProjectCacheError {
@@ -3641,6 +3807,14 @@ impl fmt::Display for InvalidateProjectCacheError {
     }
 }
 impl Error for InvalidateProjectCacheError {}
+impl ServiceErrorCode for InvalidateProjectCacheError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InvalidateProjectCacheError::InvalidInput(_) => "InvalidInputException",
+            InvalidateProjectCacheError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListBuildBatches
 #[derive(Debug, PartialEq)]
 pub enum ListBuildBatchesError {
@@ -3671,6 +3845,13 @@ impl fmt::Display for ListBuildBatchesError {
     }
 }
 impl Error for ListBuildBatchesError {}
+impl ServiceErrorCode for ListBuildBatchesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBuildBatchesError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ListBuildBatchesForProject
 #[derive(Debug, PartialEq)]
 pub enum ListBuildBatchesForProjectError {
@@ -3713,6 +3894,14 @@ impl fmt::Display for ListBuildBatchesForProjectError {
     }
 }
 impl Error for ListBuildBatchesForProjectError {}
+impl ServiceErrorCode for ListBuildBatchesForProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBuildBatchesForProjectError::InvalidInput(_) => "InvalidInputException",
+            ListBuildBatchesForProjectError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListBuilds
 #[derive(Debug, PartialEq)]
 pub enum ListBuildsError {
@@ -3743,6 +3932,13 @@ impl fmt::Display for ListBuildsError {
     }
 }
 impl Error for ListBuildsError {}
+impl ServiceErrorCode for ListBuildsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBuildsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ListBuildsForProject
 #[derive(Debug, PartialEq)]
 pub enum ListBuildsForProjectError {
@@ -3781,6 +3977,14 @@ impl fmt::Display for ListBuildsForProjectError {
     }
 }
 impl Error for ListBuildsForProjectError {}
+impl ServiceErrorCode for ListBuildsForProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBuildsForProjectError::InvalidInput(_) => "InvalidInputException",
+            ListBuildsForProjectError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListCuratedEnvironmentImages
 #[derive(Debug, PartialEq)]
 pub enum ListCuratedEnvironmentImagesError {}
@@ -3805,6 +4009,11 @@ impl fmt::Display for ListCuratedEnvironmentImagesError {
     }
 }
 impl Error for ListCuratedEnvironmentImagesError {}
+impl ServiceErrorCode for ListCuratedEnvironmentImagesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListProjects
 #[derive(Debug, PartialEq)]
 pub enum ListProjectsError {
@@ -3835,6 +4044,13 @@ impl fmt::Display for ListProjectsError {
     }
 }
 impl Error for ListProjectsError {}
+impl ServiceErrorCode for ListProjectsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProjectsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ListReportGroups
 #[derive(Debug, PartialEq)]
 pub enum ListReportGroupsError {
@@ -3865,6 +4081,13 @@ impl fmt::Display for ListReportGroupsError {
     }
 }
 impl Error for ListReportGroupsError {}
+impl ServiceErrorCode for ListReportGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListReportGroupsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ListReports
 #[derive(Debug, PartialEq)]
 pub enum ListReportsError {
@@ -3895,6 +4118,13 @@ impl fmt::Display for ListReportsError {
     }
 }
 impl Error for ListReportsError {}
+impl ServiceErrorCode for ListReportsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListReportsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ListReportsForReportGroup
 #[derive(Debug, PartialEq)]
 pub enum ListReportsForReportGroupError {
@@ -3935,6 +4165,14 @@ impl fmt::Display for ListReportsForReportGroupError {
     }
 }
 impl Error for ListReportsForReportGroupError {}
+impl ServiceErrorCode for ListReportsForReportGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListReportsForReportGroupError::InvalidInput(_) => "InvalidInputException",
+            ListReportsForReportGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListSharedProjects
 #[derive(Debug, PartialEq)]
 pub enum ListSharedProjectsError {
@@ -3965,6 +4203,13 @@ impl fmt::Display for ListSharedProjectsError {
     }
 }
 impl Error for ListSharedProjectsError {}
+impl ServiceErrorCode for ListSharedProjectsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSharedProjectsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ListSharedReportGroups
 #[derive(Debug, PartialEq)]
 pub enum ListSharedReportGroupsError {
@@ -3995,6 +4240,13 @@ impl fmt::Display for ListSharedReportGroupsError {
     }
 }
 impl Error for ListSharedReportGroupsError {}
+impl ServiceErrorCode for ListSharedReportGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSharedReportGroupsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ListSourceCredentials
 #[derive(Debug, PartialEq)]
 pub enum ListSourceCredentialsError {
@@ -4025,6 +4277,13 @@ impl fmt::Display for ListSourceCredentialsError {
     }
 }
 impl Error for ListSourceCredentialsError {}
+impl ServiceErrorCode for ListSourceCredentialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSourceCredentialsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by PutResourcePolicy
 #[derive(Debug, PartialEq)]
 pub enum PutResourcePolicyError {
@@ -4061,6 +4320,14 @@ impl fmt::Display for PutResourcePolicyError {
     }
 }
 impl Error for PutResourcePolicyError {}
+impl ServiceErrorCode for PutResourcePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutResourcePolicyError::InvalidInput(_) => "InvalidInputException",
+            PutResourcePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RetryBuild
 #[derive(Debug, PartialEq)]
 pub enum RetryBuildError {
@@ -4103,6 +4370,15 @@ impl fmt::Display for RetryBuildError {
     }
 }
 impl Error for RetryBuildError {}
+impl ServiceErrorCode for RetryBuildError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RetryBuildError::AccountLimitExceeded(_) => "AccountLimitExceededException",
+            RetryBuildError::InvalidInput(_) => "InvalidInputException",
+            RetryBuildError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RetryBuildBatch
 #[derive(Debug, PartialEq)]
 pub enum RetryBuildBatchError {
@@ -4139,6 +4415,14 @@ impl fmt::Display for RetryBuildBatchError {
     }
 }
 impl Error for RetryBuildBatchError {}
+impl ServiceErrorCode for RetryBuildBatchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RetryBuildBatchError::InvalidInput(_) => "InvalidInputException",
+            RetryBuildBatchError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartBuild
 #[derive(Debug, PartialEq)]
 pub enum StartBuildError {
@@ -4181,6 +4465,15 @@ impl fmt::Display for StartBuildError {
     }
 }
 impl Error for StartBuildError {}
+impl ServiceErrorCode for StartBuildError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartBuildError::AccountLimitExceeded(_) => "AccountLimitExceededException",
+            StartBuildError::InvalidInput(_) => "InvalidInputException",
+            StartBuildError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartBuildBatch
 #[derive(Debug, PartialEq)]
 pub enum StartBuildBatchError {
@@ -4217,6 +4510,14 @@ impl fmt::Display for StartBuildBatchError {
     }
 }
 impl Error for StartBuildBatchError {}
+impl ServiceErrorCode for StartBuildBatchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartBuildBatchError::InvalidInput(_) => "InvalidInputException",
+            StartBuildBatchError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopBuild
 #[derive(Debug, PartialEq)]
 pub enum StopBuildError {
@@ -4253,6 +4554,14 @@ impl fmt::Display for StopBuildError {
     }
 }
 impl Error for StopBuildError {}
+impl ServiceErrorCode for StopBuildError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopBuildError::InvalidInput(_) => "InvalidInputException",
+            StopBuildError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopBuildBatch
 #[derive(Debug, PartialEq)]
 pub enum StopBuildBatchError {
@@ -4289,6 +4598,14 @@ impl fmt::Display for StopBuildBatchError {
     }
 }
 impl Error for StopBuildBatchError {}
+impl ServiceErrorCode for StopBuildBatchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopBuildBatchError::InvalidInput(_) => "InvalidInputException",
+            StopBuildBatchError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateProject
 #[derive(Debug, PartialEq)]
 pub enum UpdateProjectError {
@@ -4325,6 +4642,14 @@ impl fmt::Display for UpdateProjectError {
     }
 }
 impl Error for UpdateProjectError {}
+impl ServiceErrorCode for UpdateProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateProjectError::InvalidInput(_) => "InvalidInputException",
+            UpdateProjectError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateReportGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateReportGroupError {
@@ -4361,6 +4686,14 @@ impl fmt::Display for UpdateReportGroupError {
     }
 }
 impl Error for UpdateReportGroupError {}
+impl ServiceErrorCode for UpdateReportGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateReportGroupError::InvalidInput(_) => "InvalidInputException",
+            UpdateReportGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateWebhook
 #[derive(Debug, PartialEq)]
 pub enum UpdateWebhookError {
@@ -4403,6 +4736,15 @@ impl fmt::Display for UpdateWebhookError {
     }
 }
 impl Error for UpdateWebhookError {}
+impl ServiceErrorCode for UpdateWebhookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateWebhookError::InvalidInput(_) => "InvalidInputException",
+            UpdateWebhookError::OAuthProvider(_) => "OAuthProviderException",
+            UpdateWebhookError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS CodeBuild API. AWS CodeBuild clients implement this trait.
 #[async_trait]
 pub trait CodeBuild {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -818,6 +818,7 @@ pub struct CreatePullRequestApprovalRuleOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreatePullRequestInput {
     /// <p><p>A unique, client-generated idempotency token that, when provided in a request, ensures the request cannot be repeated with a changed parameter. If a request is received with the same parameters and a token is included, the request returns information about the initial request that used that token.</p> <note> <p>The AWS SDKs prepopulate client request tokens. If you are using an AWS SDK, an idempotency token is created for you.</p> </note></p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -2574,6 +2575,7 @@ pub struct PostCommentForComparedCommitInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub before_commit_id: Option<String>,
     /// <p>A unique, client-generated idempotency token that, when provided in a request, ensures the request cannot be repeated with a changed parameter. If a request is received with the same parameters and a token is included, the request returns information about the initial request that used that token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -2632,6 +2634,7 @@ pub struct PostCommentForPullRequestInput {
     #[serde(rename = "beforeCommitId")]
     pub before_commit_id: String,
     /// <p>A unique, client-generated idempotency token that, when provided in a request, ensures the request cannot be repeated with a changed parameter. If a request is received with the same parameters and a token is included, the request returns information about the initial request that used that token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -2691,6 +2694,7 @@ pub struct PostCommentForPullRequestOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PostCommentReplyInput {
     /// <p>A unique, client-generated idempotency token that, when provided in a request, ensures the request cannot be repeated with a changed parameter. If a request is received with the same parameters and a token is included, the request returns information about the initial request that used that token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -3626,6 +3630,24 @@ AssociateApprovalRuleTemplateWithRepositoryError::RepositoryNameRequired(ref cau
     }
 }
 impl Error for AssociateApprovalRuleTemplateWithRepositoryError {}
+impl ServiceErrorCode for AssociateApprovalRuleTemplateWithRepositoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateApprovalRuleTemplateWithRepositoryError::ApprovalRuleTemplateDoesNotExist(_) => "ApprovalRuleTemplateDoesNotExistException",
+            AssociateApprovalRuleTemplateWithRepositoryError::ApprovalRuleTemplateNameRequired(_) => "ApprovalRuleTemplateNameRequiredException",
+            AssociateApprovalRuleTemplateWithRepositoryError::EncryptionIntegrityChecksFailed(_) => "EncryptionIntegrityChecksFailedException",
+            AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            AssociateApprovalRuleTemplateWithRepositoryError::InvalidApprovalRuleTemplateName(_) => "InvalidApprovalRuleTemplateNameException",
+            AssociateApprovalRuleTemplateWithRepositoryError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            AssociateApprovalRuleTemplateWithRepositoryError::MaximumRuleTemplatesAssociatedWithRepository(_) => "MaximumRuleTemplatesAssociatedWithRepositoryException",
+            AssociateApprovalRuleTemplateWithRepositoryError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            AssociateApprovalRuleTemplateWithRepositoryError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by BatchAssociateApprovalRuleTemplateWithRepositories
 #[derive(Debug, PartialEq)]
 pub enum BatchAssociateApprovalRuleTemplateWithRepositoriesError {
@@ -3692,6 +3714,22 @@ BatchAssociateApprovalRuleTemplateWithRepositoriesError::RepositoryNamesRequired
     }
 }
 impl Error for BatchAssociateApprovalRuleTemplateWithRepositoriesError {}
+impl ServiceErrorCode for BatchAssociateApprovalRuleTemplateWithRepositoriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchAssociateApprovalRuleTemplateWithRepositoriesError::ApprovalRuleTemplateDoesNotExist(_) => "ApprovalRuleTemplateDoesNotExistException",
+            BatchAssociateApprovalRuleTemplateWithRepositoriesError::ApprovalRuleTemplateNameRequired(_) => "ApprovalRuleTemplateNameRequiredException",
+            BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionIntegrityChecksFailed(_) => "EncryptionIntegrityChecksFailedException",
+            BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            BatchAssociateApprovalRuleTemplateWithRepositoriesError::InvalidApprovalRuleTemplateName(_) => "InvalidApprovalRuleTemplateNameException",
+            BatchAssociateApprovalRuleTemplateWithRepositoriesError::MaximumRepositoryNamesExceeded(_) => "MaximumRepositoryNamesExceededException",
+            BatchAssociateApprovalRuleTemplateWithRepositoriesError::RepositoryNamesRequired(_) => "RepositoryNamesRequiredException",
+        }
+    }
+}
 /// Errors returned by BatchDescribeMergeConflicts
 #[derive(Debug, PartialEq)]
 pub enum BatchDescribeMergeConflictsError {
@@ -3926,6 +3964,71 @@ impl fmt::Display for BatchDescribeMergeConflictsError {
     }
 }
 impl Error for BatchDescribeMergeConflictsError {}
+impl ServiceErrorCode for BatchDescribeMergeConflictsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDescribeMergeConflictsError::CommitDoesNotExist(_) => {
+                "CommitDoesNotExistException"
+            }
+            BatchDescribeMergeConflictsError::CommitRequired(_) => "CommitRequiredException",
+            BatchDescribeMergeConflictsError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            BatchDescribeMergeConflictsError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            BatchDescribeMergeConflictsError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            BatchDescribeMergeConflictsError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            BatchDescribeMergeConflictsError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            BatchDescribeMergeConflictsError::InvalidCommit(_) => "InvalidCommitException",
+            BatchDescribeMergeConflictsError::InvalidConflictDetailLevel(_) => {
+                "InvalidConflictDetailLevelException"
+            }
+            BatchDescribeMergeConflictsError::InvalidConflictResolutionStrategy(_) => {
+                "InvalidConflictResolutionStrategyException"
+            }
+            BatchDescribeMergeConflictsError::InvalidContinuationToken(_) => {
+                "InvalidContinuationTokenException"
+            }
+            BatchDescribeMergeConflictsError::InvalidMaxConflictFiles(_) => {
+                "InvalidMaxConflictFilesException"
+            }
+            BatchDescribeMergeConflictsError::InvalidMaxMergeHunks(_) => {
+                "InvalidMaxMergeHunksException"
+            }
+            BatchDescribeMergeConflictsError::InvalidMergeOption(_) => {
+                "InvalidMergeOptionException"
+            }
+            BatchDescribeMergeConflictsError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            BatchDescribeMergeConflictsError::MaximumFileContentToLoadExceeded(_) => {
+                "MaximumFileContentToLoadExceededException"
+            }
+            BatchDescribeMergeConflictsError::MaximumItemsToCompareExceeded(_) => {
+                "MaximumItemsToCompareExceededException"
+            }
+            BatchDescribeMergeConflictsError::MergeOptionRequired(_) => {
+                "MergeOptionRequiredException"
+            }
+            BatchDescribeMergeConflictsError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            BatchDescribeMergeConflictsError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+            BatchDescribeMergeConflictsError::TipsDivergenceExceeded(_) => {
+                "TipsDivergenceExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by BatchDisassociateApprovalRuleTemplateFromRepositories
 #[derive(Debug, PartialEq)]
 pub enum BatchDisassociateApprovalRuleTemplateFromRepositoriesError {
@@ -3992,6 +4095,22 @@ BatchDisassociateApprovalRuleTemplateFromRepositoriesError::RepositoryNamesRequi
     }
 }
 impl Error for BatchDisassociateApprovalRuleTemplateFromRepositoriesError {}
+impl ServiceErrorCode for BatchDisassociateApprovalRuleTemplateFromRepositoriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDisassociateApprovalRuleTemplateFromRepositoriesError::ApprovalRuleTemplateDoesNotExist(_) => "ApprovalRuleTemplateDoesNotExistException",
+            BatchDisassociateApprovalRuleTemplateFromRepositoriesError::ApprovalRuleTemplateNameRequired(_) => "ApprovalRuleTemplateNameRequiredException",
+            BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionIntegrityChecksFailed(_) => "EncryptionIntegrityChecksFailedException",
+            BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            BatchDisassociateApprovalRuleTemplateFromRepositoriesError::InvalidApprovalRuleTemplateName(_) => "InvalidApprovalRuleTemplateNameException",
+            BatchDisassociateApprovalRuleTemplateFromRepositoriesError::MaximumRepositoryNamesExceeded(_) => "MaximumRepositoryNamesExceededException",
+            BatchDisassociateApprovalRuleTemplateFromRepositoriesError::RepositoryNamesRequired(_) => "RepositoryNamesRequiredException",
+        }
+    }
+}
 /// Errors returned by BatchGetCommits
 #[derive(Debug, PartialEq)]
 pub enum BatchGetCommitsError {
@@ -4098,6 +4217,28 @@ impl fmt::Display for BatchGetCommitsError {
     }
 }
 impl Error for BatchGetCommitsError {}
+impl ServiceErrorCode for BatchGetCommitsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetCommitsError::CommitIdsLimitExceeded(_) => "CommitIdsLimitExceededException",
+            BatchGetCommitsError::CommitIdsListRequired(_) => "CommitIdsListRequiredException",
+            BatchGetCommitsError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            BatchGetCommitsError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            BatchGetCommitsError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            BatchGetCommitsError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            BatchGetCommitsError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            BatchGetCommitsError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            BatchGetCommitsError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            BatchGetCommitsError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by BatchGetRepositories
 #[derive(Debug, PartialEq)]
 pub enum BatchGetRepositoriesError {
@@ -4194,6 +4335,30 @@ impl fmt::Display for BatchGetRepositoriesError {
     }
 }
 impl Error for BatchGetRepositoriesError {}
+impl ServiceErrorCode for BatchGetRepositoriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetRepositoriesError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            BatchGetRepositoriesError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            BatchGetRepositoriesError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            BatchGetRepositoriesError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            BatchGetRepositoriesError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            BatchGetRepositoriesError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            BatchGetRepositoriesError::MaximumRepositoryNamesExceeded(_) => {
+                "MaximumRepositoryNamesExceededException"
+            }
+            BatchGetRepositoriesError::RepositoryNamesRequired(_) => {
+                "RepositoryNamesRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateApprovalRuleTemplate
 #[derive(Debug, PartialEq)]
 pub enum CreateApprovalRuleTemplateError {
@@ -4298,6 +4463,33 @@ impl fmt::Display for CreateApprovalRuleTemplateError {
     }
 }
 impl Error for CreateApprovalRuleTemplateError {}
+impl ServiceErrorCode for CreateApprovalRuleTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApprovalRuleTemplateError::ApprovalRuleTemplateContentRequired(_) => {
+                "ApprovalRuleTemplateContentRequiredException"
+            }
+            CreateApprovalRuleTemplateError::ApprovalRuleTemplateNameAlreadyExists(_) => {
+                "ApprovalRuleTemplateNameAlreadyExistsException"
+            }
+            CreateApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(_) => {
+                "ApprovalRuleTemplateNameRequiredException"
+            }
+            CreateApprovalRuleTemplateError::InvalidApprovalRuleTemplateContent(_) => {
+                "InvalidApprovalRuleTemplateContentException"
+            }
+            CreateApprovalRuleTemplateError::InvalidApprovalRuleTemplateDescription(_) => {
+                "InvalidApprovalRuleTemplateDescriptionException"
+            }
+            CreateApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(_) => {
+                "InvalidApprovalRuleTemplateNameException"
+            }
+            CreateApprovalRuleTemplateError::NumberOfRuleTemplatesExceeded(_) => {
+                "NumberOfRuleTemplatesExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateBranch
 #[derive(Debug, PartialEq)]
 pub enum CreateBranchError {
@@ -4412,6 +4604,28 @@ impl fmt::Display for CreateBranchError {
     }
 }
 impl Error for CreateBranchError {}
+impl ServiceErrorCode for CreateBranchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBranchError::BranchNameExists(_) => "BranchNameExistsException",
+            CreateBranchError::BranchNameRequired(_) => "BranchNameRequiredException",
+            CreateBranchError::CommitDoesNotExist(_) => "CommitDoesNotExistException",
+            CreateBranchError::CommitIdRequired(_) => "CommitIdRequiredException",
+            CreateBranchError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            CreateBranchError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            CreateBranchError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            CreateBranchError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            CreateBranchError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            CreateBranchError::InvalidBranchName(_) => "InvalidBranchNameException",
+            CreateBranchError::InvalidCommitId(_) => "InvalidCommitIdException",
+            CreateBranchError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            CreateBranchError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            CreateBranchError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by CreateCommit
 #[derive(Debug, PartialEq)]
 pub enum CreateCommitError {
@@ -4700,6 +4914,70 @@ impl fmt::Display for CreateCommitError {
     }
 }
 impl Error for CreateCommitError {}
+impl ServiceErrorCode for CreateCommitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCommitError::BranchDoesNotExist(_) => "BranchDoesNotExistException",
+            CreateCommitError::BranchNameIsTagName(_) => "BranchNameIsTagNameException",
+            CreateCommitError::BranchNameRequired(_) => "BranchNameRequiredException",
+            CreateCommitError::CommitMessageLengthExceeded(_) => {
+                "CommitMessageLengthExceededException"
+            }
+            CreateCommitError::DirectoryNameConflictsWithFileName(_) => {
+                "DirectoryNameConflictsWithFileNameException"
+            }
+            CreateCommitError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            CreateCommitError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            CreateCommitError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            CreateCommitError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            CreateCommitError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            CreateCommitError::FileContentAndSourceFileSpecified(_) => {
+                "FileContentAndSourceFileSpecifiedException"
+            }
+            CreateCommitError::FileContentSizeLimitExceeded(_) => {
+                "FileContentSizeLimitExceededException"
+            }
+            CreateCommitError::FileDoesNotExist(_) => "FileDoesNotExistException",
+            CreateCommitError::FileEntryRequired(_) => "FileEntryRequiredException",
+            CreateCommitError::FileModeRequired(_) => "FileModeRequiredException",
+            CreateCommitError::FileNameConflictsWithDirectoryName(_) => {
+                "FileNameConflictsWithDirectoryNameException"
+            }
+            CreateCommitError::FilePathConflictsWithSubmodulePath(_) => {
+                "FilePathConflictsWithSubmodulePathException"
+            }
+            CreateCommitError::FolderContentSizeLimitExceeded(_) => {
+                "FolderContentSizeLimitExceededException"
+            }
+            CreateCommitError::InvalidBranchName(_) => "InvalidBranchNameException",
+            CreateCommitError::InvalidDeletionParameter(_) => "InvalidDeletionParameterException",
+            CreateCommitError::InvalidEmail(_) => "InvalidEmailException",
+            CreateCommitError::InvalidFileMode(_) => "InvalidFileModeException",
+            CreateCommitError::InvalidParentCommitId(_) => "InvalidParentCommitIdException",
+            CreateCommitError::InvalidPath(_) => "InvalidPathException",
+            CreateCommitError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            CreateCommitError::MaximumFileEntriesExceeded(_) => {
+                "MaximumFileEntriesExceededException"
+            }
+            CreateCommitError::NameLengthExceeded(_) => "NameLengthExceededException",
+            CreateCommitError::NoChange(_) => "NoChangeException",
+            CreateCommitError::ParentCommitDoesNotExist(_) => "ParentCommitDoesNotExistException",
+            CreateCommitError::ParentCommitIdOutdated(_) => "ParentCommitIdOutdatedException",
+            CreateCommitError::ParentCommitIdRequired(_) => "ParentCommitIdRequiredException",
+            CreateCommitError::PathRequired(_) => "PathRequiredException",
+            CreateCommitError::PutFileEntryConflict(_) => "PutFileEntryConflictException",
+            CreateCommitError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            CreateCommitError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+            CreateCommitError::RestrictedSourceFile(_) => "RestrictedSourceFileException",
+            CreateCommitError::SamePathRequest(_) => "SamePathRequestException",
+            CreateCommitError::SourceFileOrContentRequired(_) => {
+                "SourceFileOrContentRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by CreatePullRequest
 #[derive(Debug, PartialEq)]
 pub enum CreatePullRequestError {
@@ -4922,6 +5200,57 @@ impl fmt::Display for CreatePullRequestError {
     }
 }
 impl Error for CreatePullRequestError {}
+impl ServiceErrorCode for CreatePullRequestError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePullRequestError::ClientRequestTokenRequired(_) => {
+                "ClientRequestTokenRequiredException"
+            }
+            CreatePullRequestError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            CreatePullRequestError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            CreatePullRequestError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            CreatePullRequestError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            CreatePullRequestError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            CreatePullRequestError::IdempotencyParameterMismatch(_) => {
+                "IdempotencyParameterMismatchException"
+            }
+            CreatePullRequestError::InvalidClientRequestToken(_) => {
+                "InvalidClientRequestTokenException"
+            }
+            CreatePullRequestError::InvalidDescription(_) => "InvalidDescriptionException",
+            CreatePullRequestError::InvalidReferenceName(_) => "InvalidReferenceNameException",
+            CreatePullRequestError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            CreatePullRequestError::InvalidTarget(_) => "InvalidTargetException",
+            CreatePullRequestError::InvalidTargets(_) => "InvalidTargetsException",
+            CreatePullRequestError::InvalidTitle(_) => "InvalidTitleException",
+            CreatePullRequestError::MaximumOpenPullRequestsExceeded(_) => {
+                "MaximumOpenPullRequestsExceededException"
+            }
+            CreatePullRequestError::MultipleRepositoriesInPullRequest(_) => {
+                "MultipleRepositoriesInPullRequestException"
+            }
+            CreatePullRequestError::ReferenceDoesNotExist(_) => "ReferenceDoesNotExistException",
+            CreatePullRequestError::ReferenceNameRequired(_) => "ReferenceNameRequiredException",
+            CreatePullRequestError::ReferenceTypeNotSupported(_) => {
+                "ReferenceTypeNotSupportedException"
+            }
+            CreatePullRequestError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            CreatePullRequestError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+            CreatePullRequestError::SourceAndDestinationAreSame(_) => {
+                "SourceAndDestinationAreSameException"
+            }
+            CreatePullRequestError::TargetRequired(_) => "TargetRequiredException",
+            CreatePullRequestError::TargetsRequired(_) => "TargetsRequiredException",
+            CreatePullRequestError::TitleRequired(_) => "TitleRequiredException",
+        }
+    }
+}
 /// Errors returned by CreatePullRequestApprovalRule
 #[derive(Debug, PartialEq)]
 pub enum CreatePullRequestApprovalRuleError {
@@ -5100,6 +5429,57 @@ impl fmt::Display for CreatePullRequestApprovalRuleError {
     }
 }
 impl Error for CreatePullRequestApprovalRuleError {}
+impl ServiceErrorCode for CreatePullRequestApprovalRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePullRequestApprovalRuleError::ApprovalRuleContentRequired(_) => {
+                "ApprovalRuleContentRequiredException"
+            }
+            CreatePullRequestApprovalRuleError::ApprovalRuleNameAlreadyExists(_) => {
+                "ApprovalRuleNameAlreadyExistsException"
+            }
+            CreatePullRequestApprovalRuleError::ApprovalRuleNameRequired(_) => {
+                "ApprovalRuleNameRequiredException"
+            }
+            CreatePullRequestApprovalRuleError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            CreatePullRequestApprovalRuleError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            CreatePullRequestApprovalRuleError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            CreatePullRequestApprovalRuleError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            CreatePullRequestApprovalRuleError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            CreatePullRequestApprovalRuleError::InvalidApprovalRuleContent(_) => {
+                "InvalidApprovalRuleContentException"
+            }
+            CreatePullRequestApprovalRuleError::InvalidApprovalRuleName(_) => {
+                "InvalidApprovalRuleNameException"
+            }
+            CreatePullRequestApprovalRuleError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            CreatePullRequestApprovalRuleError::NumberOfRulesExceeded(_) => {
+                "NumberOfRulesExceededException"
+            }
+            CreatePullRequestApprovalRuleError::PullRequestAlreadyClosed(_) => {
+                "PullRequestAlreadyClosedException"
+            }
+            CreatePullRequestApprovalRuleError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            CreatePullRequestApprovalRuleError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateRepository
 #[derive(Debug, PartialEq)]
 pub enum CreateRepositoryError {
@@ -5234,6 +5614,34 @@ impl fmt::Display for CreateRepositoryError {
     }
 }
 impl Error for CreateRepositoryError {}
+impl ServiceErrorCode for CreateRepositoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRepositoryError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            CreateRepositoryError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            CreateRepositoryError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            CreateRepositoryError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            CreateRepositoryError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            CreateRepositoryError::InvalidRepositoryDescription(_) => {
+                "InvalidRepositoryDescriptionException"
+            }
+            CreateRepositoryError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            CreateRepositoryError::InvalidSystemTagUsage(_) => "InvalidSystemTagUsageException",
+            CreateRepositoryError::InvalidTagsMap(_) => "InvalidTagsMapException",
+            CreateRepositoryError::RepositoryLimitExceeded(_) => "RepositoryLimitExceededException",
+            CreateRepositoryError::RepositoryNameExists(_) => "RepositoryNameExistsException",
+            CreateRepositoryError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+            CreateRepositoryError::TagPolicy(_) => "TagPolicyException",
+            CreateRepositoryError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by CreateUnreferencedMergeCommit
 #[derive(Debug, PartialEq)]
 pub enum CreateUnreferencedMergeCommitError {
@@ -5618,6 +6026,106 @@ impl fmt::Display for CreateUnreferencedMergeCommitError {
     }
 }
 impl Error for CreateUnreferencedMergeCommitError {}
+impl ServiceErrorCode for CreateUnreferencedMergeCommitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUnreferencedMergeCommitError::CommitDoesNotExist(_) => {
+                "CommitDoesNotExistException"
+            }
+            CreateUnreferencedMergeCommitError::CommitMessageLengthExceeded(_) => {
+                "CommitMessageLengthExceededException"
+            }
+            CreateUnreferencedMergeCommitError::CommitRequired(_) => "CommitRequiredException",
+            CreateUnreferencedMergeCommitError::ConcurrentReferenceUpdate(_) => {
+                "ConcurrentReferenceUpdateException"
+            }
+            CreateUnreferencedMergeCommitError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            CreateUnreferencedMergeCommitError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            CreateUnreferencedMergeCommitError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            CreateUnreferencedMergeCommitError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            CreateUnreferencedMergeCommitError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            CreateUnreferencedMergeCommitError::FileContentSizeLimitExceeded(_) => {
+                "FileContentSizeLimitExceededException"
+            }
+            CreateUnreferencedMergeCommitError::FileModeRequired(_) => "FileModeRequiredException",
+            CreateUnreferencedMergeCommitError::FolderContentSizeLimitExceeded(_) => {
+                "FolderContentSizeLimitExceededException"
+            }
+            CreateUnreferencedMergeCommitError::InvalidCommit(_) => "InvalidCommitException",
+            CreateUnreferencedMergeCommitError::InvalidConflictDetailLevel(_) => {
+                "InvalidConflictDetailLevelException"
+            }
+            CreateUnreferencedMergeCommitError::InvalidConflictResolution(_) => {
+                "InvalidConflictResolutionException"
+            }
+            CreateUnreferencedMergeCommitError::InvalidConflictResolutionStrategy(_) => {
+                "InvalidConflictResolutionStrategyException"
+            }
+            CreateUnreferencedMergeCommitError::InvalidEmail(_) => "InvalidEmailException",
+            CreateUnreferencedMergeCommitError::InvalidFileMode(_) => "InvalidFileModeException",
+            CreateUnreferencedMergeCommitError::InvalidMergeOption(_) => {
+                "InvalidMergeOptionException"
+            }
+            CreateUnreferencedMergeCommitError::InvalidPath(_) => "InvalidPathException",
+            CreateUnreferencedMergeCommitError::InvalidReplacementContent(_) => {
+                "InvalidReplacementContentException"
+            }
+            CreateUnreferencedMergeCommitError::InvalidReplacementType(_) => {
+                "InvalidReplacementTypeException"
+            }
+            CreateUnreferencedMergeCommitError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            CreateUnreferencedMergeCommitError::ManualMergeRequired(_) => {
+                "ManualMergeRequiredException"
+            }
+            CreateUnreferencedMergeCommitError::MaximumConflictResolutionEntriesExceeded(_) => {
+                "MaximumConflictResolutionEntriesExceededException"
+            }
+            CreateUnreferencedMergeCommitError::MaximumFileContentToLoadExceeded(_) => {
+                "MaximumFileContentToLoadExceededException"
+            }
+            CreateUnreferencedMergeCommitError::MaximumItemsToCompareExceeded(_) => {
+                "MaximumItemsToCompareExceededException"
+            }
+            CreateUnreferencedMergeCommitError::MergeOptionRequired(_) => {
+                "MergeOptionRequiredException"
+            }
+            CreateUnreferencedMergeCommitError::MultipleConflictResolutionEntries(_) => {
+                "MultipleConflictResolutionEntriesException"
+            }
+            CreateUnreferencedMergeCommitError::NameLengthExceeded(_) => {
+                "NameLengthExceededException"
+            }
+            CreateUnreferencedMergeCommitError::PathRequired(_) => "PathRequiredException",
+            CreateUnreferencedMergeCommitError::ReplacementContentRequired(_) => {
+                "ReplacementContentRequiredException"
+            }
+            CreateUnreferencedMergeCommitError::ReplacementTypeRequired(_) => {
+                "ReplacementTypeRequiredException"
+            }
+            CreateUnreferencedMergeCommitError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            CreateUnreferencedMergeCommitError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+            CreateUnreferencedMergeCommitError::TipsDivergenceExceeded(_) => {
+                "TipsDivergenceExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteApprovalRuleTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeleteApprovalRuleTemplateError {
@@ -5674,6 +6182,21 @@ impl fmt::Display for DeleteApprovalRuleTemplateError {
     }
 }
 impl Error for DeleteApprovalRuleTemplateError {}
+impl ServiceErrorCode for DeleteApprovalRuleTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApprovalRuleTemplateError::ApprovalRuleTemplateInUse(_) => {
+                "ApprovalRuleTemplateInUseException"
+            }
+            DeleteApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(_) => {
+                "ApprovalRuleTemplateNameRequiredException"
+            }
+            DeleteApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(_) => {
+                "InvalidApprovalRuleTemplateNameException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteBranch
 #[derive(Debug, PartialEq)]
 pub enum DeleteBranchError {
@@ -5772,6 +6295,27 @@ impl fmt::Display for DeleteBranchError {
     }
 }
 impl Error for DeleteBranchError {}
+impl ServiceErrorCode for DeleteBranchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBranchError::BranchNameRequired(_) => "BranchNameRequiredException",
+            DeleteBranchError::DefaultBranchCannotBeDeleted(_) => {
+                "DefaultBranchCannotBeDeletedException"
+            }
+            DeleteBranchError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            DeleteBranchError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            DeleteBranchError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            DeleteBranchError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            DeleteBranchError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            DeleteBranchError::InvalidBranchName(_) => "InvalidBranchNameException",
+            DeleteBranchError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            DeleteBranchError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            DeleteBranchError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by DeleteCommentContent
 #[derive(Debug, PartialEq)]
 pub enum DeleteCommentContentError {
@@ -5826,6 +6370,16 @@ impl fmt::Display for DeleteCommentContentError {
     }
 }
 impl Error for DeleteCommentContentError {}
+impl ServiceErrorCode for DeleteCommentContentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCommentContentError::CommentDeleted(_) => "CommentDeletedException",
+            DeleteCommentContentError::CommentDoesNotExist(_) => "CommentDoesNotExistException",
+            DeleteCommentContentError::CommentIdRequired(_) => "CommentIdRequiredException",
+            DeleteCommentContentError::InvalidCommentId(_) => "InvalidCommentIdException",
+        }
+    }
+}
 /// Errors returned by DeleteFile
 #[derive(Debug, PartialEq)]
 pub enum DeleteFileError {
@@ -5988,6 +6542,38 @@ impl fmt::Display for DeleteFileError {
     }
 }
 impl Error for DeleteFileError {}
+impl ServiceErrorCode for DeleteFileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFileError::BranchDoesNotExist(_) => "BranchDoesNotExistException",
+            DeleteFileError::BranchNameIsTagName(_) => "BranchNameIsTagNameException",
+            DeleteFileError::BranchNameRequired(_) => "BranchNameRequiredException",
+            DeleteFileError::CommitMessageLengthExceeded(_) => {
+                "CommitMessageLengthExceededException"
+            }
+            DeleteFileError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            DeleteFileError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            DeleteFileError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            DeleteFileError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            DeleteFileError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            DeleteFileError::FileDoesNotExist(_) => "FileDoesNotExistException",
+            DeleteFileError::InvalidBranchName(_) => "InvalidBranchNameException",
+            DeleteFileError::InvalidEmail(_) => "InvalidEmailException",
+            DeleteFileError::InvalidParentCommitId(_) => "InvalidParentCommitIdException",
+            DeleteFileError::InvalidPath(_) => "InvalidPathException",
+            DeleteFileError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            DeleteFileError::NameLengthExceeded(_) => "NameLengthExceededException",
+            DeleteFileError::ParentCommitDoesNotExist(_) => "ParentCommitDoesNotExistException",
+            DeleteFileError::ParentCommitIdOutdated(_) => "ParentCommitIdOutdatedException",
+            DeleteFileError::ParentCommitIdRequired(_) => "ParentCommitIdRequiredException",
+            DeleteFileError::PathRequired(_) => "PathRequiredException",
+            DeleteFileError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            DeleteFileError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by DeletePullRequestApprovalRule
 #[derive(Debug, PartialEq)]
 pub enum DeletePullRequestApprovalRuleError {
@@ -6138,6 +6724,48 @@ impl fmt::Display for DeletePullRequestApprovalRuleError {
     }
 }
 impl Error for DeletePullRequestApprovalRuleError {}
+impl ServiceErrorCode for DeletePullRequestApprovalRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePullRequestApprovalRuleError::ApprovalRuleNameRequired(_) => {
+                "ApprovalRuleNameRequiredException"
+            }
+            DeletePullRequestApprovalRuleError::CannotDeleteApprovalRuleFromTemplate(_) => {
+                "CannotDeleteApprovalRuleFromTemplateException"
+            }
+            DeletePullRequestApprovalRuleError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            DeletePullRequestApprovalRuleError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            DeletePullRequestApprovalRuleError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            DeletePullRequestApprovalRuleError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            DeletePullRequestApprovalRuleError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            DeletePullRequestApprovalRuleError::InvalidApprovalRuleName(_) => {
+                "InvalidApprovalRuleNameException"
+            }
+            DeletePullRequestApprovalRuleError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            DeletePullRequestApprovalRuleError::PullRequestAlreadyClosed(_) => {
+                "PullRequestAlreadyClosedException"
+            }
+            DeletePullRequestApprovalRuleError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            DeletePullRequestApprovalRuleError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteRepository
 #[derive(Debug, PartialEq)]
 pub enum DeleteRepositoryError {
@@ -6220,6 +6848,25 @@ impl fmt::Display for DeleteRepositoryError {
     }
 }
 impl Error for DeleteRepositoryError {}
+impl ServiceErrorCode for DeleteRepositoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRepositoryError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            DeleteRepositoryError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            DeleteRepositoryError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            DeleteRepositoryError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            DeleteRepositoryError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            DeleteRepositoryError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            DeleteRepositoryError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by DescribeMergeConflicts
 #[derive(Debug, PartialEq)]
 pub enum DescribeMergeConflictsError {
@@ -6446,6 +7093,63 @@ impl fmt::Display for DescribeMergeConflictsError {
     }
 }
 impl Error for DescribeMergeConflictsError {}
+impl ServiceErrorCode for DescribeMergeConflictsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMergeConflictsError::CommitDoesNotExist(_) => "CommitDoesNotExistException",
+            DescribeMergeConflictsError::CommitRequired(_) => "CommitRequiredException",
+            DescribeMergeConflictsError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            DescribeMergeConflictsError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            DescribeMergeConflictsError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            DescribeMergeConflictsError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            DescribeMergeConflictsError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            DescribeMergeConflictsError::FileDoesNotExist(_) => "FileDoesNotExistException",
+            DescribeMergeConflictsError::InvalidCommit(_) => "InvalidCommitException",
+            DescribeMergeConflictsError::InvalidConflictDetailLevel(_) => {
+                "InvalidConflictDetailLevelException"
+            }
+            DescribeMergeConflictsError::InvalidConflictResolutionStrategy(_) => {
+                "InvalidConflictResolutionStrategyException"
+            }
+            DescribeMergeConflictsError::InvalidContinuationToken(_) => {
+                "InvalidContinuationTokenException"
+            }
+            DescribeMergeConflictsError::InvalidMaxMergeHunks(_) => "InvalidMaxMergeHunksException",
+            DescribeMergeConflictsError::InvalidMergeOption(_) => "InvalidMergeOptionException",
+            DescribeMergeConflictsError::InvalidPath(_) => "InvalidPathException",
+            DescribeMergeConflictsError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            DescribeMergeConflictsError::MaximumFileContentToLoadExceeded(_) => {
+                "MaximumFileContentToLoadExceededException"
+            }
+            DescribeMergeConflictsError::MaximumItemsToCompareExceeded(_) => {
+                "MaximumItemsToCompareExceededException"
+            }
+            DescribeMergeConflictsError::MergeOptionRequired(_) => "MergeOptionRequiredException",
+            DescribeMergeConflictsError::PathRequired(_) => "PathRequiredException",
+            DescribeMergeConflictsError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            DescribeMergeConflictsError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+            DescribeMergeConflictsError::TipsDivergenceExceeded(_) => {
+                "TipsDivergenceExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribePullRequestEvents
 #[derive(Debug, PartialEq)]
 pub enum DescribePullRequestEventsError {
@@ -6594,6 +7298,45 @@ impl fmt::Display for DescribePullRequestEventsError {
     }
 }
 impl Error for DescribePullRequestEventsError {}
+impl ServiceErrorCode for DescribePullRequestEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePullRequestEventsError::ActorDoesNotExist(_) => "ActorDoesNotExistException",
+            DescribePullRequestEventsError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            DescribePullRequestEventsError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            DescribePullRequestEventsError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            DescribePullRequestEventsError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            DescribePullRequestEventsError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            DescribePullRequestEventsError::InvalidActorArn(_) => "InvalidActorArnException",
+            DescribePullRequestEventsError::InvalidContinuationToken(_) => {
+                "InvalidContinuationTokenException"
+            }
+            DescribePullRequestEventsError::InvalidMaxResults(_) => "InvalidMaxResultsException",
+            DescribePullRequestEventsError::InvalidPullRequestEventType(_) => {
+                "InvalidPullRequestEventTypeException"
+            }
+            DescribePullRequestEventsError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            DescribePullRequestEventsError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            DescribePullRequestEventsError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateApprovalRuleTemplateFromRepository
 #[derive(Debug, PartialEq)]
 pub enum DisassociateApprovalRuleTemplateFromRepositoryError {
@@ -6664,6 +7407,23 @@ DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryNameRequired(ref
     }
 }
 impl Error for DisassociateApprovalRuleTemplateFromRepositoryError {}
+impl ServiceErrorCode for DisassociateApprovalRuleTemplateFromRepositoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateApprovalRuleTemplateFromRepositoryError::ApprovalRuleTemplateDoesNotExist(_) => "ApprovalRuleTemplateDoesNotExistException",
+            DisassociateApprovalRuleTemplateFromRepositoryError::ApprovalRuleTemplateNameRequired(_) => "ApprovalRuleTemplateNameRequiredException",
+            DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionIntegrityChecksFailed(_) => "EncryptionIntegrityChecksFailedException",
+            DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            DisassociateApprovalRuleTemplateFromRepositoryError::InvalidApprovalRuleTemplateName(_) => "InvalidApprovalRuleTemplateNameException",
+            DisassociateApprovalRuleTemplateFromRepositoryError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by EvaluatePullRequestApprovalRules
 #[derive(Debug, PartialEq)]
 pub enum EvaluatePullRequestApprovalRulesError {
@@ -6802,6 +7562,45 @@ impl fmt::Display for EvaluatePullRequestApprovalRulesError {
     }
 }
 impl Error for EvaluatePullRequestApprovalRulesError {}
+impl ServiceErrorCode for EvaluatePullRequestApprovalRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EvaluatePullRequestApprovalRulesError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            EvaluatePullRequestApprovalRulesError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            EvaluatePullRequestApprovalRulesError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            EvaluatePullRequestApprovalRulesError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            EvaluatePullRequestApprovalRulesError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            EvaluatePullRequestApprovalRulesError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            EvaluatePullRequestApprovalRulesError::InvalidRevisionId(_) => {
+                "InvalidRevisionIdException"
+            }
+            EvaluatePullRequestApprovalRulesError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            EvaluatePullRequestApprovalRulesError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+            EvaluatePullRequestApprovalRulesError::RevisionIdRequired(_) => {
+                "RevisionIdRequiredException"
+            }
+            EvaluatePullRequestApprovalRulesError::RevisionNotCurrent(_) => {
+                "RevisionNotCurrentException"
+            }
+        }
+    }
+}
 /// Errors returned by GetApprovalRuleTemplate
 #[derive(Debug, PartialEq)]
 pub enum GetApprovalRuleTemplateError {
@@ -6856,6 +7655,21 @@ impl fmt::Display for GetApprovalRuleTemplateError {
     }
 }
 impl Error for GetApprovalRuleTemplateError {}
+impl ServiceErrorCode for GetApprovalRuleTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetApprovalRuleTemplateError::ApprovalRuleTemplateDoesNotExist(_) => {
+                "ApprovalRuleTemplateDoesNotExistException"
+            }
+            GetApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(_) => {
+                "ApprovalRuleTemplateNameRequiredException"
+            }
+            GetApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(_) => {
+                "InvalidApprovalRuleTemplateNameException"
+            }
+        }
+    }
+}
 /// Errors returned by GetBlob
 #[derive(Debug, PartialEq)]
 pub enum GetBlobError {
@@ -6954,6 +7768,26 @@ impl fmt::Display for GetBlobError {
     }
 }
 impl Error for GetBlobError {}
+impl ServiceErrorCode for GetBlobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBlobError::BlobIdDoesNotExist(_) => "BlobIdDoesNotExistException",
+            GetBlobError::BlobIdRequired(_) => "BlobIdRequiredException",
+            GetBlobError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetBlobError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            GetBlobError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            GetBlobError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            GetBlobError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            GetBlobError::FileTooLarge(_) => "FileTooLargeException",
+            GetBlobError::InvalidBlobId(_) => "InvalidBlobIdException",
+            GetBlobError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            GetBlobError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            GetBlobError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by GetBranch
 #[derive(Debug, PartialEq)]
 pub enum GetBranchError {
@@ -7046,6 +7880,25 @@ impl fmt::Display for GetBranchError {
     }
 }
 impl Error for GetBranchError {}
+impl ServiceErrorCode for GetBranchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBranchError::BranchDoesNotExist(_) => "BranchDoesNotExistException",
+            GetBranchError::BranchNameRequired(_) => "BranchNameRequiredException",
+            GetBranchError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetBranchError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            GetBranchError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            GetBranchError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            GetBranchError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            GetBranchError::InvalidBranchName(_) => "InvalidBranchNameException",
+            GetBranchError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            GetBranchError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            GetBranchError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by GetComment
 #[derive(Debug, PartialEq)]
 pub enum GetCommentError {
@@ -7128,6 +7981,23 @@ impl fmt::Display for GetCommentError {
     }
 }
 impl Error for GetCommentError {}
+impl ServiceErrorCode for GetCommentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCommentError::CommentDeleted(_) => "CommentDeletedException",
+            GetCommentError::CommentDoesNotExist(_) => "CommentDoesNotExistException",
+            GetCommentError::CommentIdRequired(_) => "CommentIdRequiredException",
+            GetCommentError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetCommentError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            GetCommentError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            GetCommentError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            GetCommentError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            GetCommentError::InvalidCommentId(_) => "InvalidCommentIdException",
+        }
+    }
+}
 /// Errors returned by GetCommentReactions
 #[derive(Debug, PartialEq)]
 pub enum GetCommentReactionsError {
@@ -7206,6 +8076,23 @@ impl fmt::Display for GetCommentReactionsError {
     }
 }
 impl Error for GetCommentReactionsError {}
+impl ServiceErrorCode for GetCommentReactionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCommentReactionsError::CommentDeleted(_) => "CommentDeletedException",
+            GetCommentReactionsError::CommentDoesNotExist(_) => "CommentDoesNotExistException",
+            GetCommentReactionsError::CommentIdRequired(_) => "CommentIdRequiredException",
+            GetCommentReactionsError::InvalidCommentId(_) => "InvalidCommentIdException",
+            GetCommentReactionsError::InvalidContinuationToken(_) => {
+                "InvalidContinuationTokenException"
+            }
+            GetCommentReactionsError::InvalidMaxResults(_) => "InvalidMaxResultsException",
+            GetCommentReactionsError::InvalidReactionUserArn(_) => {
+                "InvalidReactionUserArnException"
+            }
+        }
+    }
+}
 /// Errors returned by GetCommentsForComparedCommit
 #[derive(Debug, PartialEq)]
 pub enum GetCommentsForComparedCommitError {
@@ -7360,16 +8247,55 @@ impl fmt::Display for GetCommentsForComparedCommitError {
     }
 }
 impl Error for GetCommentsForComparedCommitError {}
-/// Errors returned by GetCommentsForPullRequest
-#[derive(Debug, PartialEq)]
-pub enum GetCommentsForPullRequestError {
-    /// <p>The specified commit does not exist or no commit was specified, and the specified repository has no default branch.</p>
-    CommitDoesNotExist(String),
-    /// <p>A commit ID was not specified.</p>
-    CommitIdRequired(String),
-    /// <p>An encryption integrity check failed.</p>
-    EncryptionIntegrityChecksFailed(String),
-    /// <p>An encryption key could not be accessed.</p>
+impl ServiceErrorCode for GetCommentsForComparedCommitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCommentsForComparedCommitError::CommitDoesNotExist(_) => {
+                "CommitDoesNotExistException"
+            }
+            GetCommentsForComparedCommitError::CommitIdRequired(_) => "CommitIdRequiredException",
+            GetCommentsForComparedCommitError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetCommentsForComparedCommitError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            GetCommentsForComparedCommitError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            GetCommentsForComparedCommitError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            GetCommentsForComparedCommitError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            GetCommentsForComparedCommitError::InvalidCommitId(_) => "InvalidCommitIdException",
+            GetCommentsForComparedCommitError::InvalidContinuationToken(_) => {
+                "InvalidContinuationTokenException"
+            }
+            GetCommentsForComparedCommitError::InvalidMaxResults(_) => "InvalidMaxResultsException",
+            GetCommentsForComparedCommitError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            GetCommentsForComparedCommitError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            GetCommentsForComparedCommitError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+        }
+    }
+}
+/// Errors returned by GetCommentsForPullRequest
+#[derive(Debug, PartialEq)]
+pub enum GetCommentsForPullRequestError {
+    /// <p>The specified commit does not exist or no commit was specified, and the specified repository has no default branch.</p>
+    CommitDoesNotExist(String),
+    /// <p>A commit ID was not specified.</p>
+    CommitIdRequired(String),
+    /// <p>An encryption integrity check failed.</p>
+    EncryptionIntegrityChecksFailed(String),
+    /// <p>An encryption key could not be accessed.</p>
     EncryptionKeyAccessDenied(String),
     /// <p>The encryption key is disabled.</p>
     EncryptionKeyDisabled(String),
@@ -7548,6 +8474,55 @@ impl fmt::Display for GetCommentsForPullRequestError {
     }
 }
 impl Error for GetCommentsForPullRequestError {}
+impl ServiceErrorCode for GetCommentsForPullRequestError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCommentsForPullRequestError::CommitDoesNotExist(_) => "CommitDoesNotExistException",
+            GetCommentsForPullRequestError::CommitIdRequired(_) => "CommitIdRequiredException",
+            GetCommentsForPullRequestError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetCommentsForPullRequestError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            GetCommentsForPullRequestError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            GetCommentsForPullRequestError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            GetCommentsForPullRequestError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            GetCommentsForPullRequestError::InvalidCommitId(_) => "InvalidCommitIdException",
+            GetCommentsForPullRequestError::InvalidContinuationToken(_) => {
+                "InvalidContinuationTokenException"
+            }
+            GetCommentsForPullRequestError::InvalidMaxResults(_) => "InvalidMaxResultsException",
+            GetCommentsForPullRequestError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            GetCommentsForPullRequestError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            GetCommentsForPullRequestError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            GetCommentsForPullRequestError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+            GetCommentsForPullRequestError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            GetCommentsForPullRequestError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+            GetCommentsForPullRequestError::RepositoryNotAssociatedWithPullRequest(_) => {
+                "RepositoryNotAssociatedWithPullRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by GetCommit
 #[derive(Debug, PartialEq)]
 pub enum GetCommitError {
@@ -7640,6 +8615,25 @@ impl fmt::Display for GetCommitError {
     }
 }
 impl Error for GetCommitError {}
+impl ServiceErrorCode for GetCommitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCommitError::CommitIdDoesNotExist(_) => "CommitIdDoesNotExistException",
+            GetCommitError::CommitIdRequired(_) => "CommitIdRequiredException",
+            GetCommitError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetCommitError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            GetCommitError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            GetCommitError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            GetCommitError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            GetCommitError::InvalidCommitId(_) => "InvalidCommitIdException",
+            GetCommitError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            GetCommitError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            GetCommitError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by GetDifferences
 #[derive(Debug, PartialEq)]
 pub enum GetDifferencesError {
@@ -7780,6 +8774,32 @@ impl fmt::Display for GetDifferencesError {
     }
 }
 impl Error for GetDifferencesError {}
+impl ServiceErrorCode for GetDifferencesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDifferencesError::CommitDoesNotExist(_) => "CommitDoesNotExistException",
+            GetDifferencesError::CommitRequired(_) => "CommitRequiredException",
+            GetDifferencesError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetDifferencesError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            GetDifferencesError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            GetDifferencesError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            GetDifferencesError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            GetDifferencesError::InvalidCommit(_) => "InvalidCommitException",
+            GetDifferencesError::InvalidCommitId(_) => "InvalidCommitIdException",
+            GetDifferencesError::InvalidContinuationToken(_) => "InvalidContinuationTokenException",
+            GetDifferencesError::InvalidMaxResults(_) => "InvalidMaxResultsException",
+            GetDifferencesError::InvalidPath(_) => "InvalidPathException",
+            GetDifferencesError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            GetDifferencesError::PathDoesNotExist(_) => "PathDoesNotExistException",
+            GetDifferencesError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            GetDifferencesError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by GetFile
 #[derive(Debug, PartialEq)]
 pub enum GetFileError {
@@ -7890,6 +8910,28 @@ impl fmt::Display for GetFileError {
     }
 }
 impl Error for GetFileError {}
+impl ServiceErrorCode for GetFileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFileError::CommitDoesNotExist(_) => "CommitDoesNotExistException",
+            GetFileError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetFileError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            GetFileError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            GetFileError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            GetFileError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            GetFileError::FileDoesNotExist(_) => "FileDoesNotExistException",
+            GetFileError::FileTooLarge(_) => "FileTooLargeException",
+            GetFileError::InvalidCommit(_) => "InvalidCommitException",
+            GetFileError::InvalidPath(_) => "InvalidPathException",
+            GetFileError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            GetFileError::PathRequired(_) => "PathRequiredException",
+            GetFileError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            GetFileError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by GetFolder
 #[derive(Debug, PartialEq)]
 pub enum GetFolderError {
@@ -7994,6 +9036,27 @@ impl fmt::Display for GetFolderError {
     }
 }
 impl Error for GetFolderError {}
+impl ServiceErrorCode for GetFolderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFolderError::CommitDoesNotExist(_) => "CommitDoesNotExistException",
+            GetFolderError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetFolderError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            GetFolderError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            GetFolderError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            GetFolderError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            GetFolderError::FolderDoesNotExist(_) => "FolderDoesNotExistException",
+            GetFolderError::InvalidCommit(_) => "InvalidCommitException",
+            GetFolderError::InvalidPath(_) => "InvalidPathException",
+            GetFolderError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            GetFolderError::PathRequired(_) => "PathRequiredException",
+            GetFolderError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            GetFolderError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by GetMergeCommit
 #[derive(Debug, PartialEq)]
 pub enum GetMergeCommitError {
@@ -8120,6 +9183,33 @@ impl fmt::Display for GetMergeCommitError {
     }
 }
 impl Error for GetMergeCommitError {}
+impl ServiceErrorCode for GetMergeCommitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMergeCommitError::CommitDoesNotExist(_) => "CommitDoesNotExistException",
+            GetMergeCommitError::CommitRequired(_) => "CommitRequiredException",
+            GetMergeCommitError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetMergeCommitError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            GetMergeCommitError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            GetMergeCommitError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            GetMergeCommitError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            GetMergeCommitError::InvalidCommit(_) => "InvalidCommitException",
+            GetMergeCommitError::InvalidConflictDetailLevel(_) => {
+                "InvalidConflictDetailLevelException"
+            }
+            GetMergeCommitError::InvalidConflictResolutionStrategy(_) => {
+                "InvalidConflictResolutionStrategyException"
+            }
+            GetMergeCommitError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            GetMergeCommitError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            GetMergeCommitError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by GetMergeConflicts
 #[derive(Debug, PartialEq)]
 pub enum GetMergeConflictsError {
@@ -8328,6 +9418,56 @@ impl fmt::Display for GetMergeConflictsError {
     }
 }
 impl Error for GetMergeConflictsError {}
+impl ServiceErrorCode for GetMergeConflictsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMergeConflictsError::CommitDoesNotExist(_) => "CommitDoesNotExistException",
+            GetMergeConflictsError::CommitRequired(_) => "CommitRequiredException",
+            GetMergeConflictsError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetMergeConflictsError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            GetMergeConflictsError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            GetMergeConflictsError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            GetMergeConflictsError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            GetMergeConflictsError::InvalidCommit(_) => "InvalidCommitException",
+            GetMergeConflictsError::InvalidConflictDetailLevel(_) => {
+                "InvalidConflictDetailLevelException"
+            }
+            GetMergeConflictsError::InvalidConflictResolutionStrategy(_) => {
+                "InvalidConflictResolutionStrategyException"
+            }
+            GetMergeConflictsError::InvalidContinuationToken(_) => {
+                "InvalidContinuationTokenException"
+            }
+            GetMergeConflictsError::InvalidDestinationCommitSpecifier(_) => {
+                "InvalidDestinationCommitSpecifierException"
+            }
+            GetMergeConflictsError::InvalidMaxConflictFiles(_) => {
+                "InvalidMaxConflictFilesException"
+            }
+            GetMergeConflictsError::InvalidMergeOption(_) => "InvalidMergeOptionException",
+            GetMergeConflictsError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            GetMergeConflictsError::InvalidSourceCommitSpecifier(_) => {
+                "InvalidSourceCommitSpecifierException"
+            }
+            GetMergeConflictsError::MaximumFileContentToLoadExceeded(_) => {
+                "MaximumFileContentToLoadExceededException"
+            }
+            GetMergeConflictsError::MaximumItemsToCompareExceeded(_) => {
+                "MaximumItemsToCompareExceededException"
+            }
+            GetMergeConflictsError::MergeOptionRequired(_) => "MergeOptionRequiredException",
+            GetMergeConflictsError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            GetMergeConflictsError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+            GetMergeConflictsError::TipsDivergenceExceeded(_) => "TipsDivergenceExceededException",
+        }
+    }
+}
 /// Errors returned by GetMergeOptions
 #[derive(Debug, PartialEq)]
 pub enum GetMergeOptionsError {
@@ -8482,6 +9622,42 @@ impl fmt::Display for GetMergeOptionsError {
     }
 }
 impl Error for GetMergeOptionsError {}
+impl ServiceErrorCode for GetMergeOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMergeOptionsError::CommitDoesNotExist(_) => "CommitDoesNotExistException",
+            GetMergeOptionsError::CommitRequired(_) => "CommitRequiredException",
+            GetMergeOptionsError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetMergeOptionsError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            GetMergeOptionsError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            GetMergeOptionsError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            GetMergeOptionsError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            GetMergeOptionsError::InvalidCommit(_) => "InvalidCommitException",
+            GetMergeOptionsError::InvalidConflictDetailLevel(_) => {
+                "InvalidConflictDetailLevelException"
+            }
+            GetMergeOptionsError::InvalidConflictResolutionStrategy(_) => {
+                "InvalidConflictResolutionStrategyException"
+            }
+            GetMergeOptionsError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            GetMergeOptionsError::MaximumFileContentToLoadExceeded(_) => {
+                "MaximumFileContentToLoadExceededException"
+            }
+            GetMergeOptionsError::MaximumItemsToCompareExceeded(_) => {
+                "MaximumItemsToCompareExceededException"
+            }
+            GetMergeOptionsError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            GetMergeOptionsError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+            GetMergeOptionsError::TipsDivergenceExceeded(_) => "TipsDivergenceExceededException",
+        }
+    }
+}
 /// Errors returned by GetPullRequest
 #[derive(Debug, PartialEq)]
 pub enum GetPullRequestError {
@@ -8570,6 +9746,24 @@ impl fmt::Display for GetPullRequestError {
     }
 }
 impl Error for GetPullRequestError {}
+impl ServiceErrorCode for GetPullRequestError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPullRequestError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetPullRequestError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            GetPullRequestError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            GetPullRequestError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            GetPullRequestError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            GetPullRequestError::InvalidPullRequestId(_) => "InvalidPullRequestIdException",
+            GetPullRequestError::PullRequestDoesNotExist(_) => "PullRequestDoesNotExistException",
+            GetPullRequestError::PullRequestIdRequired(_) => "PullRequestIdRequiredException",
+        }
+    }
+}
 /// Errors returned by GetPullRequestApprovalStates
 #[derive(Debug, PartialEq)]
 pub enum GetPullRequestApprovalStatesError {
@@ -8696,6 +9890,40 @@ impl fmt::Display for GetPullRequestApprovalStatesError {
     }
 }
 impl Error for GetPullRequestApprovalStatesError {}
+impl ServiceErrorCode for GetPullRequestApprovalStatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPullRequestApprovalStatesError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetPullRequestApprovalStatesError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            GetPullRequestApprovalStatesError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            GetPullRequestApprovalStatesError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            GetPullRequestApprovalStatesError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            GetPullRequestApprovalStatesError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            GetPullRequestApprovalStatesError::InvalidRevisionId(_) => "InvalidRevisionIdException",
+            GetPullRequestApprovalStatesError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            GetPullRequestApprovalStatesError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+            GetPullRequestApprovalStatesError::RevisionIdRequired(_) => {
+                "RevisionIdRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by GetPullRequestOverrideState
 #[derive(Debug, PartialEq)]
 pub enum GetPullRequestOverrideStateError {
@@ -8822,6 +10050,40 @@ impl fmt::Display for GetPullRequestOverrideStateError {
     }
 }
 impl Error for GetPullRequestOverrideStateError {}
+impl ServiceErrorCode for GetPullRequestOverrideStateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPullRequestOverrideStateError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetPullRequestOverrideStateError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            GetPullRequestOverrideStateError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            GetPullRequestOverrideStateError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            GetPullRequestOverrideStateError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            GetPullRequestOverrideStateError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            GetPullRequestOverrideStateError::InvalidRevisionId(_) => "InvalidRevisionIdException",
+            GetPullRequestOverrideStateError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            GetPullRequestOverrideStateError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+            GetPullRequestOverrideStateError::RevisionIdRequired(_) => {
+                "RevisionIdRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by GetRepository
 #[derive(Debug, PartialEq)]
 pub enum GetRepositoryError {
@@ -8906,6 +10168,24 @@ impl fmt::Display for GetRepositoryError {
     }
 }
 impl Error for GetRepositoryError {}
+impl ServiceErrorCode for GetRepositoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRepositoryError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetRepositoryError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            GetRepositoryError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            GetRepositoryError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            GetRepositoryError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            GetRepositoryError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            GetRepositoryError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            GetRepositoryError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by GetRepositoryTriggers
 #[derive(Debug, PartialEq)]
 pub enum GetRepositoryTriggersError {
@@ -9000,6 +10280,36 @@ impl fmt::Display for GetRepositoryTriggersError {
     }
 }
 impl Error for GetRepositoryTriggersError {}
+impl ServiceErrorCode for GetRepositoryTriggersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRepositoryTriggersError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            GetRepositoryTriggersError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            GetRepositoryTriggersError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            GetRepositoryTriggersError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            GetRepositoryTriggersError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            GetRepositoryTriggersError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            GetRepositoryTriggersError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            GetRepositoryTriggersError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by ListApprovalRuleTemplates
 #[derive(Debug, PartialEq)]
 pub enum ListApprovalRuleTemplatesError {
@@ -9042,6 +10352,16 @@ impl fmt::Display for ListApprovalRuleTemplatesError {
     }
 }
 impl Error for ListApprovalRuleTemplatesError {}
+impl ServiceErrorCode for ListApprovalRuleTemplatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListApprovalRuleTemplatesError::InvalidContinuationToken(_) => {
+                "InvalidContinuationTokenException"
+            }
+            ListApprovalRuleTemplatesError::InvalidMaxResults(_) => "InvalidMaxResultsException",
+        }
+    }
+}
 /// Errors returned by ListAssociatedApprovalRuleTemplatesForRepository
 #[derive(Debug, PartialEq)]
 pub enum ListAssociatedApprovalRuleTemplatesForRepositoryError {
@@ -9108,6 +10428,22 @@ ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryNameRequired(re
     }
 }
 impl Error for ListAssociatedApprovalRuleTemplatesForRepositoryError {}
+impl ServiceErrorCode for ListAssociatedApprovalRuleTemplatesForRepositoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionIntegrityChecksFailed(_) => "EncryptionIntegrityChecksFailedException",
+            ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidContinuationToken(_) => "InvalidContinuationTokenException",
+            ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidMaxResults(_) => "InvalidMaxResultsException",
+            ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by ListBranches
 #[derive(Debug, PartialEq)]
 pub enum ListBranchesError {
@@ -9194,6 +10530,23 @@ impl fmt::Display for ListBranchesError {
     }
 }
 impl Error for ListBranchesError {}
+impl ServiceErrorCode for ListBranchesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBranchesError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            ListBranchesError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            ListBranchesError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            ListBranchesError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            ListBranchesError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            ListBranchesError::InvalidContinuationToken(_) => "InvalidContinuationTokenException",
+            ListBranchesError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            ListBranchesError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            ListBranchesError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by ListPullRequests
 #[derive(Debug, PartialEq)]
 pub enum ListPullRequestsError {
@@ -9318,6 +10671,35 @@ impl fmt::Display for ListPullRequestsError {
     }
 }
 impl Error for ListPullRequestsError {}
+impl ServiceErrorCode for ListPullRequestsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPullRequestsError::AuthorDoesNotExist(_) => "AuthorDoesNotExistException",
+            ListPullRequestsError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            ListPullRequestsError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            ListPullRequestsError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            ListPullRequestsError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            ListPullRequestsError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            ListPullRequestsError::InvalidAuthorArn(_) => "InvalidAuthorArnException",
+            ListPullRequestsError::InvalidContinuationToken(_) => {
+                "InvalidContinuationTokenException"
+            }
+            ListPullRequestsError::InvalidMaxResults(_) => "InvalidMaxResultsException",
+            ListPullRequestsError::InvalidPullRequestStatus(_) => {
+                "InvalidPullRequestStatusException"
+            }
+            ListPullRequestsError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            ListPullRequestsError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            ListPullRequestsError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+        }
+    }
+}
 /// Errors returned by ListRepositories
 #[derive(Debug, PartialEq)]
 pub enum ListRepositoriesError {
@@ -9362,6 +10744,17 @@ impl fmt::Display for ListRepositoriesError {
     }
 }
 impl Error for ListRepositoriesError {}
+impl ServiceErrorCode for ListRepositoriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRepositoriesError::InvalidContinuationToken(_) => {
+                "InvalidContinuationTokenException"
+            }
+            ListRepositoriesError::InvalidOrder(_) => "InvalidOrderException",
+            ListRepositoriesError::InvalidSortBy(_) => "InvalidSortByException",
+        }
+    }
+}
 /// Errors returned by ListRepositoriesForApprovalRuleTemplate
 #[derive(Debug, PartialEq)]
 pub enum ListRepositoriesForApprovalRuleTemplateError {
@@ -9498,6 +10891,42 @@ impl fmt::Display for ListRepositoriesForApprovalRuleTemplateError {
     }
 }
 impl Error for ListRepositoriesForApprovalRuleTemplateError {}
+impl ServiceErrorCode for ListRepositoriesForApprovalRuleTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRepositoriesForApprovalRuleTemplateError::ApprovalRuleTemplateDoesNotExist(_) => {
+                "ApprovalRuleTemplateDoesNotExistException"
+            }
+            ListRepositoriesForApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(_) => {
+                "ApprovalRuleTemplateNameRequiredException"
+            }
+            ListRepositoriesForApprovalRuleTemplateError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            ListRepositoriesForApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(_) => {
+                "InvalidApprovalRuleTemplateNameException"
+            }
+            ListRepositoriesForApprovalRuleTemplateError::InvalidContinuationToken(_) => {
+                "InvalidContinuationTokenException"
+            }
+            ListRepositoriesForApprovalRuleTemplateError::InvalidMaxResults(_) => {
+                "InvalidMaxResultsException"
+            }
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -9554,6 +10983,18 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            ListTagsForResourceError::InvalidResourceArn(_) => "InvalidResourceArnException",
+            ListTagsForResourceError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            ListTagsForResourceError::ResourceArnRequired(_) => "ResourceArnRequiredException",
+        }
+    }
+}
 /// Errors returned by MergeBranchesByFastForward
 #[derive(Debug, PartialEq)]
 pub enum MergeBranchesByFastForwardError {
@@ -9764,6 +11205,57 @@ impl fmt::Display for MergeBranchesByFastForwardError {
     }
 }
 impl Error for MergeBranchesByFastForwardError {}
+impl ServiceErrorCode for MergeBranchesByFastForwardError {
+    fn error_code(&self) -> &str {
+        match *self {
+            MergeBranchesByFastForwardError::BranchDoesNotExist(_) => "BranchDoesNotExistException",
+            MergeBranchesByFastForwardError::BranchNameIsTagName(_) => {
+                "BranchNameIsTagNameException"
+            }
+            MergeBranchesByFastForwardError::BranchNameRequired(_) => "BranchNameRequiredException",
+            MergeBranchesByFastForwardError::CommitDoesNotExist(_) => "CommitDoesNotExistException",
+            MergeBranchesByFastForwardError::CommitRequired(_) => "CommitRequiredException",
+            MergeBranchesByFastForwardError::ConcurrentReferenceUpdate(_) => {
+                "ConcurrentReferenceUpdateException"
+            }
+            MergeBranchesByFastForwardError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            MergeBranchesByFastForwardError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            MergeBranchesByFastForwardError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            MergeBranchesByFastForwardError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            MergeBranchesByFastForwardError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            MergeBranchesByFastForwardError::InvalidBranchName(_) => "InvalidBranchNameException",
+            MergeBranchesByFastForwardError::InvalidCommit(_) => "InvalidCommitException",
+            MergeBranchesByFastForwardError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            MergeBranchesByFastForwardError::InvalidTargetBranch(_) => {
+                "InvalidTargetBranchException"
+            }
+            MergeBranchesByFastForwardError::ManualMergeRequired(_) => {
+                "ManualMergeRequiredException"
+            }
+            MergeBranchesByFastForwardError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            MergeBranchesByFastForwardError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+            MergeBranchesByFastForwardError::TipsDivergenceExceeded(_) => {
+                "TipsDivergenceExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by MergeBranchesBySquash
 #[derive(Debug, PartialEq)]
 pub enum MergeBranchesBySquashError {
@@ -10128,6 +11620,99 @@ impl fmt::Display for MergeBranchesBySquashError {
     }
 }
 impl Error for MergeBranchesBySquashError {}
+impl ServiceErrorCode for MergeBranchesBySquashError {
+    fn error_code(&self) -> &str {
+        match *self {
+            MergeBranchesBySquashError::BranchDoesNotExist(_) => "BranchDoesNotExistException",
+            MergeBranchesBySquashError::BranchNameIsTagName(_) => "BranchNameIsTagNameException",
+            MergeBranchesBySquashError::BranchNameRequired(_) => "BranchNameRequiredException",
+            MergeBranchesBySquashError::CommitDoesNotExist(_) => "CommitDoesNotExistException",
+            MergeBranchesBySquashError::CommitMessageLengthExceeded(_) => {
+                "CommitMessageLengthExceededException"
+            }
+            MergeBranchesBySquashError::CommitRequired(_) => "CommitRequiredException",
+            MergeBranchesBySquashError::ConcurrentReferenceUpdate(_) => {
+                "ConcurrentReferenceUpdateException"
+            }
+            MergeBranchesBySquashError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            MergeBranchesBySquashError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            MergeBranchesBySquashError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            MergeBranchesBySquashError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            MergeBranchesBySquashError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            MergeBranchesBySquashError::FileContentSizeLimitExceeded(_) => {
+                "FileContentSizeLimitExceededException"
+            }
+            MergeBranchesBySquashError::FileModeRequired(_) => "FileModeRequiredException",
+            MergeBranchesBySquashError::FolderContentSizeLimitExceeded(_) => {
+                "FolderContentSizeLimitExceededException"
+            }
+            MergeBranchesBySquashError::InvalidBranchName(_) => "InvalidBranchNameException",
+            MergeBranchesBySquashError::InvalidCommit(_) => "InvalidCommitException",
+            MergeBranchesBySquashError::InvalidConflictDetailLevel(_) => {
+                "InvalidConflictDetailLevelException"
+            }
+            MergeBranchesBySquashError::InvalidConflictResolution(_) => {
+                "InvalidConflictResolutionException"
+            }
+            MergeBranchesBySquashError::InvalidConflictResolutionStrategy(_) => {
+                "InvalidConflictResolutionStrategyException"
+            }
+            MergeBranchesBySquashError::InvalidEmail(_) => "InvalidEmailException",
+            MergeBranchesBySquashError::InvalidFileMode(_) => "InvalidFileModeException",
+            MergeBranchesBySquashError::InvalidPath(_) => "InvalidPathException",
+            MergeBranchesBySquashError::InvalidReplacementContent(_) => {
+                "InvalidReplacementContentException"
+            }
+            MergeBranchesBySquashError::InvalidReplacementType(_) => {
+                "InvalidReplacementTypeException"
+            }
+            MergeBranchesBySquashError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            MergeBranchesBySquashError::InvalidTargetBranch(_) => "InvalidTargetBranchException",
+            MergeBranchesBySquashError::ManualMergeRequired(_) => "ManualMergeRequiredException",
+            MergeBranchesBySquashError::MaximumConflictResolutionEntriesExceeded(_) => {
+                "MaximumConflictResolutionEntriesExceededException"
+            }
+            MergeBranchesBySquashError::MaximumFileContentToLoadExceeded(_) => {
+                "MaximumFileContentToLoadExceededException"
+            }
+            MergeBranchesBySquashError::MaximumItemsToCompareExceeded(_) => {
+                "MaximumItemsToCompareExceededException"
+            }
+            MergeBranchesBySquashError::MultipleConflictResolutionEntries(_) => {
+                "MultipleConflictResolutionEntriesException"
+            }
+            MergeBranchesBySquashError::NameLengthExceeded(_) => "NameLengthExceededException",
+            MergeBranchesBySquashError::PathRequired(_) => "PathRequiredException",
+            MergeBranchesBySquashError::ReplacementContentRequired(_) => {
+                "ReplacementContentRequiredException"
+            }
+            MergeBranchesBySquashError::ReplacementTypeRequired(_) => {
+                "ReplacementTypeRequiredException"
+            }
+            MergeBranchesBySquashError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            MergeBranchesBySquashError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+            MergeBranchesBySquashError::TipsDivergenceExceeded(_) => {
+                "TipsDivergenceExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by MergeBranchesByThreeWay
 #[derive(Debug, PartialEq)]
 pub enum MergeBranchesByThreeWayError {
@@ -10512,6 +12097,99 @@ impl fmt::Display for MergeBranchesByThreeWayError {
     }
 }
 impl Error for MergeBranchesByThreeWayError {}
+impl ServiceErrorCode for MergeBranchesByThreeWayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            MergeBranchesByThreeWayError::BranchDoesNotExist(_) => "BranchDoesNotExistException",
+            MergeBranchesByThreeWayError::BranchNameIsTagName(_) => "BranchNameIsTagNameException",
+            MergeBranchesByThreeWayError::BranchNameRequired(_) => "BranchNameRequiredException",
+            MergeBranchesByThreeWayError::CommitDoesNotExist(_) => "CommitDoesNotExistException",
+            MergeBranchesByThreeWayError::CommitMessageLengthExceeded(_) => {
+                "CommitMessageLengthExceededException"
+            }
+            MergeBranchesByThreeWayError::CommitRequired(_) => "CommitRequiredException",
+            MergeBranchesByThreeWayError::ConcurrentReferenceUpdate(_) => {
+                "ConcurrentReferenceUpdateException"
+            }
+            MergeBranchesByThreeWayError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            MergeBranchesByThreeWayError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            MergeBranchesByThreeWayError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            MergeBranchesByThreeWayError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            MergeBranchesByThreeWayError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            MergeBranchesByThreeWayError::FileContentSizeLimitExceeded(_) => {
+                "FileContentSizeLimitExceededException"
+            }
+            MergeBranchesByThreeWayError::FileModeRequired(_) => "FileModeRequiredException",
+            MergeBranchesByThreeWayError::FolderContentSizeLimitExceeded(_) => {
+                "FolderContentSizeLimitExceededException"
+            }
+            MergeBranchesByThreeWayError::InvalidBranchName(_) => "InvalidBranchNameException",
+            MergeBranchesByThreeWayError::InvalidCommit(_) => "InvalidCommitException",
+            MergeBranchesByThreeWayError::InvalidConflictDetailLevel(_) => {
+                "InvalidConflictDetailLevelException"
+            }
+            MergeBranchesByThreeWayError::InvalidConflictResolution(_) => {
+                "InvalidConflictResolutionException"
+            }
+            MergeBranchesByThreeWayError::InvalidConflictResolutionStrategy(_) => {
+                "InvalidConflictResolutionStrategyException"
+            }
+            MergeBranchesByThreeWayError::InvalidEmail(_) => "InvalidEmailException",
+            MergeBranchesByThreeWayError::InvalidFileMode(_) => "InvalidFileModeException",
+            MergeBranchesByThreeWayError::InvalidPath(_) => "InvalidPathException",
+            MergeBranchesByThreeWayError::InvalidReplacementContent(_) => {
+                "InvalidReplacementContentException"
+            }
+            MergeBranchesByThreeWayError::InvalidReplacementType(_) => {
+                "InvalidReplacementTypeException"
+            }
+            MergeBranchesByThreeWayError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            MergeBranchesByThreeWayError::InvalidTargetBranch(_) => "InvalidTargetBranchException",
+            MergeBranchesByThreeWayError::ManualMergeRequired(_) => "ManualMergeRequiredException",
+            MergeBranchesByThreeWayError::MaximumConflictResolutionEntriesExceeded(_) => {
+                "MaximumConflictResolutionEntriesExceededException"
+            }
+            MergeBranchesByThreeWayError::MaximumFileContentToLoadExceeded(_) => {
+                "MaximumFileContentToLoadExceededException"
+            }
+            MergeBranchesByThreeWayError::MaximumItemsToCompareExceeded(_) => {
+                "MaximumItemsToCompareExceededException"
+            }
+            MergeBranchesByThreeWayError::MultipleConflictResolutionEntries(_) => {
+                "MultipleConflictResolutionEntriesException"
+            }
+            MergeBranchesByThreeWayError::NameLengthExceeded(_) => "NameLengthExceededException",
+            MergeBranchesByThreeWayError::PathRequired(_) => "PathRequiredException",
+            MergeBranchesByThreeWayError::ReplacementContentRequired(_) => {
+                "ReplacementContentRequiredException"
+            }
+            MergeBranchesByThreeWayError::ReplacementTypeRequired(_) => {
+                "ReplacementTypeRequiredException"
+            }
+            MergeBranchesByThreeWayError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            MergeBranchesByThreeWayError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+            MergeBranchesByThreeWayError::TipsDivergenceExceeded(_) => {
+                "TipsDivergenceExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by MergePullRequestByFastForward
 #[derive(Debug, PartialEq)]
 pub enum MergePullRequestByFastForwardError {
@@ -10736,6 +12414,67 @@ impl fmt::Display for MergePullRequestByFastForwardError {
     }
 }
 impl Error for MergePullRequestByFastForwardError {}
+impl ServiceErrorCode for MergePullRequestByFastForwardError {
+    fn error_code(&self) -> &str {
+        match *self {
+            MergePullRequestByFastForwardError::ConcurrentReferenceUpdate(_) => {
+                "ConcurrentReferenceUpdateException"
+            }
+            MergePullRequestByFastForwardError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            MergePullRequestByFastForwardError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            MergePullRequestByFastForwardError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            MergePullRequestByFastForwardError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            MergePullRequestByFastForwardError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            MergePullRequestByFastForwardError::InvalidCommitId(_) => "InvalidCommitIdException",
+            MergePullRequestByFastForwardError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            MergePullRequestByFastForwardError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            MergePullRequestByFastForwardError::ManualMergeRequired(_) => {
+                "ManualMergeRequiredException"
+            }
+            MergePullRequestByFastForwardError::PullRequestAlreadyClosed(_) => {
+                "PullRequestAlreadyClosedException"
+            }
+            MergePullRequestByFastForwardError::PullRequestApprovalRulesNotSatisfied(_) => {
+                "PullRequestApprovalRulesNotSatisfiedException"
+            }
+            MergePullRequestByFastForwardError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            MergePullRequestByFastForwardError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+            MergePullRequestByFastForwardError::ReferenceDoesNotExist(_) => {
+                "ReferenceDoesNotExistException"
+            }
+            MergePullRequestByFastForwardError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            MergePullRequestByFastForwardError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+            MergePullRequestByFastForwardError::RepositoryNotAssociatedWithPullRequest(_) => {
+                "RepositoryNotAssociatedWithPullRequestException"
+            }
+            MergePullRequestByFastForwardError::TipOfSourceReferenceIsDifferent(_) => {
+                "TipOfSourceReferenceIsDifferentException"
+            }
+        }
+    }
+}
 /// Errors returned by MergePullRequestBySquash
 #[derive(Debug, PartialEq)]
 pub enum MergePullRequestBySquashError {
@@ -11132,6 +12871,112 @@ impl fmt::Display for MergePullRequestBySquashError {
     }
 }
 impl Error for MergePullRequestBySquashError {}
+impl ServiceErrorCode for MergePullRequestBySquashError {
+    fn error_code(&self) -> &str {
+        match *self {
+            MergePullRequestBySquashError::CommitMessageLengthExceeded(_) => {
+                "CommitMessageLengthExceededException"
+            }
+            MergePullRequestBySquashError::ConcurrentReferenceUpdate(_) => {
+                "ConcurrentReferenceUpdateException"
+            }
+            MergePullRequestBySquashError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            MergePullRequestBySquashError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            MergePullRequestBySquashError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            MergePullRequestBySquashError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            MergePullRequestBySquashError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            MergePullRequestBySquashError::FileContentSizeLimitExceeded(_) => {
+                "FileContentSizeLimitExceededException"
+            }
+            MergePullRequestBySquashError::FolderContentSizeLimitExceeded(_) => {
+                "FolderContentSizeLimitExceededException"
+            }
+            MergePullRequestBySquashError::InvalidCommitId(_) => "InvalidCommitIdException",
+            MergePullRequestBySquashError::InvalidConflictDetailLevel(_) => {
+                "InvalidConflictDetailLevelException"
+            }
+            MergePullRequestBySquashError::InvalidConflictResolution(_) => {
+                "InvalidConflictResolutionException"
+            }
+            MergePullRequestBySquashError::InvalidConflictResolutionStrategy(_) => {
+                "InvalidConflictResolutionStrategyException"
+            }
+            MergePullRequestBySquashError::InvalidEmail(_) => "InvalidEmailException",
+            MergePullRequestBySquashError::InvalidFileMode(_) => "InvalidFileModeException",
+            MergePullRequestBySquashError::InvalidPath(_) => "InvalidPathException",
+            MergePullRequestBySquashError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            MergePullRequestBySquashError::InvalidReplacementContent(_) => {
+                "InvalidReplacementContentException"
+            }
+            MergePullRequestBySquashError::InvalidReplacementType(_) => {
+                "InvalidReplacementTypeException"
+            }
+            MergePullRequestBySquashError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            MergePullRequestBySquashError::ManualMergeRequired(_) => "ManualMergeRequiredException",
+            MergePullRequestBySquashError::MaximumConflictResolutionEntriesExceeded(_) => {
+                "MaximumConflictResolutionEntriesExceededException"
+            }
+            MergePullRequestBySquashError::MaximumFileContentToLoadExceeded(_) => {
+                "MaximumFileContentToLoadExceededException"
+            }
+            MergePullRequestBySquashError::MaximumItemsToCompareExceeded(_) => {
+                "MaximumItemsToCompareExceededException"
+            }
+            MergePullRequestBySquashError::MultipleConflictResolutionEntries(_) => {
+                "MultipleConflictResolutionEntriesException"
+            }
+            MergePullRequestBySquashError::NameLengthExceeded(_) => "NameLengthExceededException",
+            MergePullRequestBySquashError::PathRequired(_) => "PathRequiredException",
+            MergePullRequestBySquashError::PullRequestAlreadyClosed(_) => {
+                "PullRequestAlreadyClosedException"
+            }
+            MergePullRequestBySquashError::PullRequestApprovalRulesNotSatisfied(_) => {
+                "PullRequestApprovalRulesNotSatisfiedException"
+            }
+            MergePullRequestBySquashError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            MergePullRequestBySquashError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+            MergePullRequestBySquashError::ReplacementContentRequired(_) => {
+                "ReplacementContentRequiredException"
+            }
+            MergePullRequestBySquashError::ReplacementTypeRequired(_) => {
+                "ReplacementTypeRequiredException"
+            }
+            MergePullRequestBySquashError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            MergePullRequestBySquashError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+            MergePullRequestBySquashError::RepositoryNotAssociatedWithPullRequest(_) => {
+                "RepositoryNotAssociatedWithPullRequestException"
+            }
+            MergePullRequestBySquashError::TipOfSourceReferenceIsDifferent(_) => {
+                "TipOfSourceReferenceIsDifferentException"
+            }
+            MergePullRequestBySquashError::TipsDivergenceExceeded(_) => {
+                "TipsDivergenceExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by MergePullRequestByThreeWay
 #[derive(Debug, PartialEq)]
 pub enum MergePullRequestByThreeWayError {
@@ -11534,6 +13379,114 @@ impl fmt::Display for MergePullRequestByThreeWayError {
     }
 }
 impl Error for MergePullRequestByThreeWayError {}
+impl ServiceErrorCode for MergePullRequestByThreeWayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            MergePullRequestByThreeWayError::CommitMessageLengthExceeded(_) => {
+                "CommitMessageLengthExceededException"
+            }
+            MergePullRequestByThreeWayError::ConcurrentReferenceUpdate(_) => {
+                "ConcurrentReferenceUpdateException"
+            }
+            MergePullRequestByThreeWayError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            MergePullRequestByThreeWayError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            MergePullRequestByThreeWayError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            MergePullRequestByThreeWayError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            MergePullRequestByThreeWayError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            MergePullRequestByThreeWayError::FileContentSizeLimitExceeded(_) => {
+                "FileContentSizeLimitExceededException"
+            }
+            MergePullRequestByThreeWayError::FolderContentSizeLimitExceeded(_) => {
+                "FolderContentSizeLimitExceededException"
+            }
+            MergePullRequestByThreeWayError::InvalidCommitId(_) => "InvalidCommitIdException",
+            MergePullRequestByThreeWayError::InvalidConflictDetailLevel(_) => {
+                "InvalidConflictDetailLevelException"
+            }
+            MergePullRequestByThreeWayError::InvalidConflictResolution(_) => {
+                "InvalidConflictResolutionException"
+            }
+            MergePullRequestByThreeWayError::InvalidConflictResolutionStrategy(_) => {
+                "InvalidConflictResolutionStrategyException"
+            }
+            MergePullRequestByThreeWayError::InvalidEmail(_) => "InvalidEmailException",
+            MergePullRequestByThreeWayError::InvalidFileMode(_) => "InvalidFileModeException",
+            MergePullRequestByThreeWayError::InvalidPath(_) => "InvalidPathException",
+            MergePullRequestByThreeWayError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            MergePullRequestByThreeWayError::InvalidReplacementContent(_) => {
+                "InvalidReplacementContentException"
+            }
+            MergePullRequestByThreeWayError::InvalidReplacementType(_) => {
+                "InvalidReplacementTypeException"
+            }
+            MergePullRequestByThreeWayError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            MergePullRequestByThreeWayError::ManualMergeRequired(_) => {
+                "ManualMergeRequiredException"
+            }
+            MergePullRequestByThreeWayError::MaximumConflictResolutionEntriesExceeded(_) => {
+                "MaximumConflictResolutionEntriesExceededException"
+            }
+            MergePullRequestByThreeWayError::MaximumFileContentToLoadExceeded(_) => {
+                "MaximumFileContentToLoadExceededException"
+            }
+            MergePullRequestByThreeWayError::MaximumItemsToCompareExceeded(_) => {
+                "MaximumItemsToCompareExceededException"
+            }
+            MergePullRequestByThreeWayError::MultipleConflictResolutionEntries(_) => {
+                "MultipleConflictResolutionEntriesException"
+            }
+            MergePullRequestByThreeWayError::NameLengthExceeded(_) => "NameLengthExceededException",
+            MergePullRequestByThreeWayError::PathRequired(_) => "PathRequiredException",
+            MergePullRequestByThreeWayError::PullRequestAlreadyClosed(_) => {
+                "PullRequestAlreadyClosedException"
+            }
+            MergePullRequestByThreeWayError::PullRequestApprovalRulesNotSatisfied(_) => {
+                "PullRequestApprovalRulesNotSatisfiedException"
+            }
+            MergePullRequestByThreeWayError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            MergePullRequestByThreeWayError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+            MergePullRequestByThreeWayError::ReplacementContentRequired(_) => {
+                "ReplacementContentRequiredException"
+            }
+            MergePullRequestByThreeWayError::ReplacementTypeRequired(_) => {
+                "ReplacementTypeRequiredException"
+            }
+            MergePullRequestByThreeWayError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            MergePullRequestByThreeWayError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+            MergePullRequestByThreeWayError::RepositoryNotAssociatedWithPullRequest(_) => {
+                "RepositoryNotAssociatedWithPullRequestException"
+            }
+            MergePullRequestByThreeWayError::TipOfSourceReferenceIsDifferent(_) => {
+                "TipOfSourceReferenceIsDifferentException"
+            }
+            MergePullRequestByThreeWayError::TipsDivergenceExceeded(_) => {
+                "TipsDivergenceExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by OverridePullRequestApprovalRules
 #[derive(Debug, PartialEq)]
 pub enum OverridePullRequestApprovalRulesError {
@@ -11712,6 +13665,57 @@ impl fmt::Display for OverridePullRequestApprovalRulesError {
     }
 }
 impl Error for OverridePullRequestApprovalRulesError {}
+impl ServiceErrorCode for OverridePullRequestApprovalRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            OverridePullRequestApprovalRulesError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            OverridePullRequestApprovalRulesError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            OverridePullRequestApprovalRulesError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            OverridePullRequestApprovalRulesError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            OverridePullRequestApprovalRulesError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            OverridePullRequestApprovalRulesError::InvalidOverrideStatus(_) => {
+                "InvalidOverrideStatusException"
+            }
+            OverridePullRequestApprovalRulesError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            OverridePullRequestApprovalRulesError::InvalidRevisionId(_) => {
+                "InvalidRevisionIdException"
+            }
+            OverridePullRequestApprovalRulesError::OverrideAlreadySet(_) => {
+                "OverrideAlreadySetException"
+            }
+            OverridePullRequestApprovalRulesError::OverrideStatusRequired(_) => {
+                "OverrideStatusRequiredException"
+            }
+            OverridePullRequestApprovalRulesError::PullRequestAlreadyClosed(_) => {
+                "PullRequestAlreadyClosedException"
+            }
+            OverridePullRequestApprovalRulesError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            OverridePullRequestApprovalRulesError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+            OverridePullRequestApprovalRulesError::RevisionIdRequired(_) => {
+                "RevisionIdRequiredException"
+            }
+            OverridePullRequestApprovalRulesError::RevisionNotCurrent(_) => {
+                "RevisionNotCurrentException"
+            }
+        }
+    }
+}
 /// Errors returned by PostCommentForComparedCommit
 #[derive(Debug, PartialEq)]
 pub enum PostCommentForComparedCommitError {
@@ -11964,6 +13968,71 @@ impl fmt::Display for PostCommentForComparedCommitError {
     }
 }
 impl Error for PostCommentForComparedCommitError {}
+impl ServiceErrorCode for PostCommentForComparedCommitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PostCommentForComparedCommitError::BeforeCommitIdAndAfterCommitIdAreSame(_) => {
+                "BeforeCommitIdAndAfterCommitIdAreSameException"
+            }
+            PostCommentForComparedCommitError::ClientRequestTokenRequired(_) => {
+                "ClientRequestTokenRequiredException"
+            }
+            PostCommentForComparedCommitError::CommentContentRequired(_) => {
+                "CommentContentRequiredException"
+            }
+            PostCommentForComparedCommitError::CommentContentSizeLimitExceeded(_) => {
+                "CommentContentSizeLimitExceededException"
+            }
+            PostCommentForComparedCommitError::CommitDoesNotExist(_) => {
+                "CommitDoesNotExistException"
+            }
+            PostCommentForComparedCommitError::CommitIdRequired(_) => "CommitIdRequiredException",
+            PostCommentForComparedCommitError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            PostCommentForComparedCommitError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            PostCommentForComparedCommitError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            PostCommentForComparedCommitError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            PostCommentForComparedCommitError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            PostCommentForComparedCommitError::IdempotencyParameterMismatch(_) => {
+                "IdempotencyParameterMismatchException"
+            }
+            PostCommentForComparedCommitError::InvalidClientRequestToken(_) => {
+                "InvalidClientRequestTokenException"
+            }
+            PostCommentForComparedCommitError::InvalidCommitId(_) => "InvalidCommitIdException",
+            PostCommentForComparedCommitError::InvalidFileLocation(_) => {
+                "InvalidFileLocationException"
+            }
+            PostCommentForComparedCommitError::InvalidFilePosition(_) => {
+                "InvalidFilePositionException"
+            }
+            PostCommentForComparedCommitError::InvalidPath(_) => "InvalidPathException",
+            PostCommentForComparedCommitError::InvalidRelativeFileVersionEnum(_) => {
+                "InvalidRelativeFileVersionEnumException"
+            }
+            PostCommentForComparedCommitError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            PostCommentForComparedCommitError::PathDoesNotExist(_) => "PathDoesNotExistException",
+            PostCommentForComparedCommitError::PathRequired(_) => "PathRequiredException",
+            PostCommentForComparedCommitError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            PostCommentForComparedCommitError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by PostCommentForPullRequest
 #[derive(Debug, PartialEq)]
 pub enum PostCommentForPullRequestError {
@@ -12250,6 +14319,81 @@ impl fmt::Display for PostCommentForPullRequestError {
     }
 }
 impl Error for PostCommentForPullRequestError {}
+impl ServiceErrorCode for PostCommentForPullRequestError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PostCommentForPullRequestError::BeforeCommitIdAndAfterCommitIdAreSame(_) => {
+                "BeforeCommitIdAndAfterCommitIdAreSameException"
+            }
+            PostCommentForPullRequestError::ClientRequestTokenRequired(_) => {
+                "ClientRequestTokenRequiredException"
+            }
+            PostCommentForPullRequestError::CommentContentRequired(_) => {
+                "CommentContentRequiredException"
+            }
+            PostCommentForPullRequestError::CommentContentSizeLimitExceeded(_) => {
+                "CommentContentSizeLimitExceededException"
+            }
+            PostCommentForPullRequestError::CommitDoesNotExist(_) => "CommitDoesNotExistException",
+            PostCommentForPullRequestError::CommitIdRequired(_) => "CommitIdRequiredException",
+            PostCommentForPullRequestError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            PostCommentForPullRequestError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            PostCommentForPullRequestError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            PostCommentForPullRequestError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            PostCommentForPullRequestError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            PostCommentForPullRequestError::IdempotencyParameterMismatch(_) => {
+                "IdempotencyParameterMismatchException"
+            }
+            PostCommentForPullRequestError::InvalidClientRequestToken(_) => {
+                "InvalidClientRequestTokenException"
+            }
+            PostCommentForPullRequestError::InvalidCommitId(_) => "InvalidCommitIdException",
+            PostCommentForPullRequestError::InvalidFileLocation(_) => {
+                "InvalidFileLocationException"
+            }
+            PostCommentForPullRequestError::InvalidFilePosition(_) => {
+                "InvalidFilePositionException"
+            }
+            PostCommentForPullRequestError::InvalidPath(_) => "InvalidPathException",
+            PostCommentForPullRequestError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            PostCommentForPullRequestError::InvalidRelativeFileVersionEnum(_) => {
+                "InvalidRelativeFileVersionEnumException"
+            }
+            PostCommentForPullRequestError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            PostCommentForPullRequestError::PathDoesNotExist(_) => "PathDoesNotExistException",
+            PostCommentForPullRequestError::PathRequired(_) => "PathRequiredException",
+            PostCommentForPullRequestError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            PostCommentForPullRequestError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+            PostCommentForPullRequestError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            PostCommentForPullRequestError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+            PostCommentForPullRequestError::RepositoryNotAssociatedWithPullRequest(_) => {
+                "RepositoryNotAssociatedWithPullRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by PostCommentReply
 #[derive(Debug, PartialEq)]
 pub enum PostCommentReplyError {
@@ -12338,6 +14482,28 @@ impl fmt::Display for PostCommentReplyError {
     }
 }
 impl Error for PostCommentReplyError {}
+impl ServiceErrorCode for PostCommentReplyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PostCommentReplyError::ClientRequestTokenRequired(_) => {
+                "ClientRequestTokenRequiredException"
+            }
+            PostCommentReplyError::CommentContentRequired(_) => "CommentContentRequiredException",
+            PostCommentReplyError::CommentContentSizeLimitExceeded(_) => {
+                "CommentContentSizeLimitExceededException"
+            }
+            PostCommentReplyError::CommentDoesNotExist(_) => "CommentDoesNotExistException",
+            PostCommentReplyError::CommentIdRequired(_) => "CommentIdRequiredException",
+            PostCommentReplyError::IdempotencyParameterMismatch(_) => {
+                "IdempotencyParameterMismatchException"
+            }
+            PostCommentReplyError::InvalidClientRequestToken(_) => {
+                "InvalidClientRequestTokenException"
+            }
+            PostCommentReplyError::InvalidCommentId(_) => "InvalidCommentIdException",
+        }
+    }
+}
 /// Errors returned by PutCommentReaction
 #[derive(Debug, PartialEq)]
 pub enum PutCommentReactionError {
@@ -12414,6 +14580,19 @@ impl fmt::Display for PutCommentReactionError {
     }
 }
 impl Error for PutCommentReactionError {}
+impl ServiceErrorCode for PutCommentReactionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutCommentReactionError::CommentDeleted(_) => "CommentDeletedException",
+            PutCommentReactionError::CommentDoesNotExist(_) => "CommentDoesNotExistException",
+            PutCommentReactionError::CommentIdRequired(_) => "CommentIdRequiredException",
+            PutCommentReactionError::InvalidCommentId(_) => "InvalidCommentIdException",
+            PutCommentReactionError::InvalidReactionValue(_) => "InvalidReactionValueException",
+            PutCommentReactionError::ReactionLimitExceeded(_) => "ReactionLimitExceededException",
+            PutCommentReactionError::ReactionValueRequired(_) => "ReactionValueRequiredException",
+        }
+    }
+}
 /// Errors returned by PutFile
 #[derive(Debug, PartialEq)]
 pub enum PutFileError {
@@ -12630,6 +14809,54 @@ impl fmt::Display for PutFileError {
     }
 }
 impl Error for PutFileError {}
+impl ServiceErrorCode for PutFileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutFileError::BranchDoesNotExist(_) => "BranchDoesNotExistException",
+            PutFileError::BranchNameIsTagName(_) => "BranchNameIsTagNameException",
+            PutFileError::BranchNameRequired(_) => "BranchNameRequiredException",
+            PutFileError::CommitMessageLengthExceeded(_) => "CommitMessageLengthExceededException",
+            PutFileError::DirectoryNameConflictsWithFileName(_) => {
+                "DirectoryNameConflictsWithFileNameException"
+            }
+            PutFileError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            PutFileError::EncryptionKeyAccessDenied(_) => "EncryptionKeyAccessDeniedException",
+            PutFileError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            PutFileError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            PutFileError::EncryptionKeyUnavailable(_) => "EncryptionKeyUnavailableException",
+            PutFileError::FileContentRequired(_) => "FileContentRequiredException",
+            PutFileError::FileContentSizeLimitExceeded(_) => {
+                "FileContentSizeLimitExceededException"
+            }
+            PutFileError::FileNameConflictsWithDirectoryName(_) => {
+                "FileNameConflictsWithDirectoryNameException"
+            }
+            PutFileError::FilePathConflictsWithSubmodulePath(_) => {
+                "FilePathConflictsWithSubmodulePathException"
+            }
+            PutFileError::FolderContentSizeLimitExceeded(_) => {
+                "FolderContentSizeLimitExceededException"
+            }
+            PutFileError::InvalidBranchName(_) => "InvalidBranchNameException",
+            PutFileError::InvalidDeletionParameter(_) => "InvalidDeletionParameterException",
+            PutFileError::InvalidEmail(_) => "InvalidEmailException",
+            PutFileError::InvalidFileMode(_) => "InvalidFileModeException",
+            PutFileError::InvalidParentCommitId(_) => "InvalidParentCommitIdException",
+            PutFileError::InvalidPath(_) => "InvalidPathException",
+            PutFileError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            PutFileError::NameLengthExceeded(_) => "NameLengthExceededException",
+            PutFileError::ParentCommitDoesNotExist(_) => "ParentCommitDoesNotExistException",
+            PutFileError::ParentCommitIdOutdated(_) => "ParentCommitIdOutdatedException",
+            PutFileError::ParentCommitIdRequired(_) => "ParentCommitIdRequiredException",
+            PutFileError::PathRequired(_) => "PathRequiredException",
+            PutFileError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            PutFileError::RepositoryNameRequired(_) => "RepositoryNameRequiredException",
+            PutFileError::SameFileContent(_) => "SameFileContentException",
+        }
+    }
+}
 /// Errors returned by PutRepositoryTriggers
 #[derive(Debug, PartialEq)]
 pub enum PutRepositoryTriggersError {
@@ -12858,6 +15085,75 @@ impl fmt::Display for PutRepositoryTriggersError {
     }
 }
 impl Error for PutRepositoryTriggersError {}
+impl ServiceErrorCode for PutRepositoryTriggersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRepositoryTriggersError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            PutRepositoryTriggersError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            PutRepositoryTriggersError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            PutRepositoryTriggersError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            PutRepositoryTriggersError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            PutRepositoryTriggersError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            PutRepositoryTriggersError::InvalidRepositoryTriggerBranchName(_) => {
+                "InvalidRepositoryTriggerBranchNameException"
+            }
+            PutRepositoryTriggersError::InvalidRepositoryTriggerCustomData(_) => {
+                "InvalidRepositoryTriggerCustomDataException"
+            }
+            PutRepositoryTriggersError::InvalidRepositoryTriggerDestinationArn(_) => {
+                "InvalidRepositoryTriggerDestinationArnException"
+            }
+            PutRepositoryTriggersError::InvalidRepositoryTriggerEvents(_) => {
+                "InvalidRepositoryTriggerEventsException"
+            }
+            PutRepositoryTriggersError::InvalidRepositoryTriggerName(_) => {
+                "InvalidRepositoryTriggerNameException"
+            }
+            PutRepositoryTriggersError::InvalidRepositoryTriggerRegion(_) => {
+                "InvalidRepositoryTriggerRegionException"
+            }
+            PutRepositoryTriggersError::MaximumBranchesExceeded(_) => {
+                "MaximumBranchesExceededException"
+            }
+            PutRepositoryTriggersError::MaximumRepositoryTriggersExceeded(_) => {
+                "MaximumRepositoryTriggersExceededException"
+            }
+            PutRepositoryTriggersError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            PutRepositoryTriggersError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+            PutRepositoryTriggersError::RepositoryTriggerBranchNameListRequired(_) => {
+                "RepositoryTriggerBranchNameListRequiredException"
+            }
+            PutRepositoryTriggersError::RepositoryTriggerDestinationArnRequired(_) => {
+                "RepositoryTriggerDestinationArnRequiredException"
+            }
+            PutRepositoryTriggersError::RepositoryTriggerEventsListRequired(_) => {
+                "RepositoryTriggerEventsListRequiredException"
+            }
+            PutRepositoryTriggersError::RepositoryTriggerNameRequired(_) => {
+                "RepositoryTriggerNameRequiredException"
+            }
+            PutRepositoryTriggersError::RepositoryTriggersListRequired(_) => {
+                "RepositoryTriggersListRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -12936,6 +15232,21 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            TagResourceError::InvalidResourceArn(_) => "InvalidResourceArnException",
+            TagResourceError::InvalidSystemTagUsage(_) => "InvalidSystemTagUsageException",
+            TagResourceError::InvalidTagsMap(_) => "InvalidTagsMapException",
+            TagResourceError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            TagResourceError::ResourceArnRequired(_) => "ResourceArnRequiredException",
+            TagResourceError::TagPolicy(_) => "TagPolicyException",
+            TagResourceError::TagsMapRequired(_) => "TagsMapRequiredException",
+            TagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by TestRepositoryTriggers
 #[derive(Debug, PartialEq)]
 pub enum TestRepositoryTriggersError {
@@ -13170,6 +15481,75 @@ impl fmt::Display for TestRepositoryTriggersError {
     }
 }
 impl Error for TestRepositoryTriggersError {}
+impl ServiceErrorCode for TestRepositoryTriggersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TestRepositoryTriggersError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            TestRepositoryTriggersError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            TestRepositoryTriggersError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            TestRepositoryTriggersError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            TestRepositoryTriggersError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            TestRepositoryTriggersError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            TestRepositoryTriggersError::InvalidRepositoryTriggerBranchName(_) => {
+                "InvalidRepositoryTriggerBranchNameException"
+            }
+            TestRepositoryTriggersError::InvalidRepositoryTriggerCustomData(_) => {
+                "InvalidRepositoryTriggerCustomDataException"
+            }
+            TestRepositoryTriggersError::InvalidRepositoryTriggerDestinationArn(_) => {
+                "InvalidRepositoryTriggerDestinationArnException"
+            }
+            TestRepositoryTriggersError::InvalidRepositoryTriggerEvents(_) => {
+                "InvalidRepositoryTriggerEventsException"
+            }
+            TestRepositoryTriggersError::InvalidRepositoryTriggerName(_) => {
+                "InvalidRepositoryTriggerNameException"
+            }
+            TestRepositoryTriggersError::InvalidRepositoryTriggerRegion(_) => {
+                "InvalidRepositoryTriggerRegionException"
+            }
+            TestRepositoryTriggersError::MaximumBranchesExceeded(_) => {
+                "MaximumBranchesExceededException"
+            }
+            TestRepositoryTriggersError::MaximumRepositoryTriggersExceeded(_) => {
+                "MaximumRepositoryTriggersExceededException"
+            }
+            TestRepositoryTriggersError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            TestRepositoryTriggersError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+            TestRepositoryTriggersError::RepositoryTriggerBranchNameListRequired(_) => {
+                "RepositoryTriggerBranchNameListRequiredException"
+            }
+            TestRepositoryTriggersError::RepositoryTriggerDestinationArnRequired(_) => {
+                "RepositoryTriggerDestinationArnRequiredException"
+            }
+            TestRepositoryTriggersError::RepositoryTriggerEventsListRequired(_) => {
+                "RepositoryTriggerEventsListRequiredException"
+            }
+            TestRepositoryTriggersError::RepositoryTriggerNameRequired(_) => {
+                "RepositoryTriggerNameRequiredException"
+            }
+            TestRepositoryTriggersError::RepositoryTriggersListRequired(_) => {
+                "RepositoryTriggersListRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -13250,6 +15630,21 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            UntagResourceError::InvalidResourceArn(_) => "InvalidResourceArnException",
+            UntagResourceError::InvalidSystemTagUsage(_) => "InvalidSystemTagUsageException",
+            UntagResourceError::InvalidTagKeysList(_) => "InvalidTagKeysListException",
+            UntagResourceError::RepositoryDoesNotExist(_) => "RepositoryDoesNotExistException",
+            UntagResourceError::ResourceArnRequired(_) => "ResourceArnRequiredException",
+            UntagResourceError::TagKeysListRequired(_) => "TagKeysListRequiredException",
+            UntagResourceError::TagPolicy(_) => "TagPolicyException",
+            UntagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UpdateApprovalRuleTemplateContent
 #[derive(Debug, PartialEq)]
 pub enum UpdateApprovalRuleTemplateContentError {
@@ -13346,6 +15741,30 @@ impl fmt::Display for UpdateApprovalRuleTemplateContentError {
     }
 }
 impl Error for UpdateApprovalRuleTemplateContentError {}
+impl ServiceErrorCode for UpdateApprovalRuleTemplateContentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApprovalRuleTemplateContentError::ApprovalRuleTemplateContentRequired(_) => {
+                "ApprovalRuleTemplateContentRequiredException"
+            }
+            UpdateApprovalRuleTemplateContentError::ApprovalRuleTemplateDoesNotExist(_) => {
+                "ApprovalRuleTemplateDoesNotExistException"
+            }
+            UpdateApprovalRuleTemplateContentError::ApprovalRuleTemplateNameRequired(_) => {
+                "ApprovalRuleTemplateNameRequiredException"
+            }
+            UpdateApprovalRuleTemplateContentError::InvalidApprovalRuleTemplateContent(_) => {
+                "InvalidApprovalRuleTemplateContentException"
+            }
+            UpdateApprovalRuleTemplateContentError::InvalidApprovalRuleTemplateName(_) => {
+                "InvalidApprovalRuleTemplateNameException"
+            }
+            UpdateApprovalRuleTemplateContentError::InvalidRuleContentSha256(_) => {
+                "InvalidRuleContentSha256Exception"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateApprovalRuleTemplateDescription
 #[derive(Debug, PartialEq)]
 pub enum UpdateApprovalRuleTemplateDescriptionError {
@@ -13396,6 +15815,24 @@ impl fmt::Display for UpdateApprovalRuleTemplateDescriptionError {
     }
 }
 impl Error for UpdateApprovalRuleTemplateDescriptionError {}
+impl ServiceErrorCode for UpdateApprovalRuleTemplateDescriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApprovalRuleTemplateDescriptionError::ApprovalRuleTemplateDoesNotExist(_) => {
+                "ApprovalRuleTemplateDoesNotExistException"
+            }
+            UpdateApprovalRuleTemplateDescriptionError::ApprovalRuleTemplateNameRequired(_) => {
+                "ApprovalRuleTemplateNameRequiredException"
+            }
+            UpdateApprovalRuleTemplateDescriptionError::InvalidApprovalRuleTemplateDescription(
+                _,
+            ) => "InvalidApprovalRuleTemplateDescriptionException",
+            UpdateApprovalRuleTemplateDescriptionError::InvalidApprovalRuleTemplateName(_) => {
+                "InvalidApprovalRuleTemplateNameException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateApprovalRuleTemplateName
 #[derive(Debug, PartialEq)]
 pub enum UpdateApprovalRuleTemplateNameError {
@@ -13470,6 +15907,24 @@ impl fmt::Display for UpdateApprovalRuleTemplateNameError {
     }
 }
 impl Error for UpdateApprovalRuleTemplateNameError {}
+impl ServiceErrorCode for UpdateApprovalRuleTemplateNameError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApprovalRuleTemplateNameError::ApprovalRuleTemplateDoesNotExist(_) => {
+                "ApprovalRuleTemplateDoesNotExistException"
+            }
+            UpdateApprovalRuleTemplateNameError::ApprovalRuleTemplateNameAlreadyExists(_) => {
+                "ApprovalRuleTemplateNameAlreadyExistsException"
+            }
+            UpdateApprovalRuleTemplateNameError::ApprovalRuleTemplateNameRequired(_) => {
+                "ApprovalRuleTemplateNameRequiredException"
+            }
+            UpdateApprovalRuleTemplateNameError::InvalidApprovalRuleTemplateName(_) => {
+                "InvalidApprovalRuleTemplateNameException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateComment
 #[derive(Debug, PartialEq)]
 pub enum UpdateCommentError {
@@ -13544,6 +15999,23 @@ impl fmt::Display for UpdateCommentError {
     }
 }
 impl Error for UpdateCommentError {}
+impl ServiceErrorCode for UpdateCommentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCommentError::CommentContentRequired(_) => "CommentContentRequiredException",
+            UpdateCommentError::CommentContentSizeLimitExceeded(_) => {
+                "CommentContentSizeLimitExceededException"
+            }
+            UpdateCommentError::CommentDeleted(_) => "CommentDeletedException",
+            UpdateCommentError::CommentDoesNotExist(_) => "CommentDoesNotExistException",
+            UpdateCommentError::CommentIdRequired(_) => "CommentIdRequiredException",
+            UpdateCommentError::CommentNotCreatedByCaller(_) => {
+                "CommentNotCreatedByCallerException"
+            }
+            UpdateCommentError::InvalidCommentId(_) => "InvalidCommentIdException",
+        }
+    }
+}
 /// Errors returned by UpdateDefaultBranch
 #[derive(Debug, PartialEq)]
 pub enum UpdateDefaultBranchError {
@@ -13660,6 +16132,33 @@ impl fmt::Display for UpdateDefaultBranchError {
     }
 }
 impl Error for UpdateDefaultBranchError {}
+impl ServiceErrorCode for UpdateDefaultBranchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDefaultBranchError::BranchDoesNotExist(_) => "BranchDoesNotExistException",
+            UpdateDefaultBranchError::BranchNameRequired(_) => "BranchNameRequiredException",
+            UpdateDefaultBranchError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            UpdateDefaultBranchError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            UpdateDefaultBranchError::EncryptionKeyDisabled(_) => "EncryptionKeyDisabledException",
+            UpdateDefaultBranchError::EncryptionKeyNotFound(_) => "EncryptionKeyNotFoundException",
+            UpdateDefaultBranchError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            UpdateDefaultBranchError::InvalidBranchName(_) => "InvalidBranchNameException",
+            UpdateDefaultBranchError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            UpdateDefaultBranchError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            UpdateDefaultBranchError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdatePullRequestApprovalRuleContent
 #[derive(Debug, PartialEq)]
 pub enum UpdatePullRequestApprovalRuleContentError {
@@ -13864,6 +16363,60 @@ impl fmt::Display for UpdatePullRequestApprovalRuleContentError {
     }
 }
 impl Error for UpdatePullRequestApprovalRuleContentError {}
+impl ServiceErrorCode for UpdatePullRequestApprovalRuleContentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePullRequestApprovalRuleContentError::ApprovalRuleContentRequired(_) => {
+                "ApprovalRuleContentRequiredException"
+            }
+            UpdatePullRequestApprovalRuleContentError::ApprovalRuleDoesNotExist(_) => {
+                "ApprovalRuleDoesNotExistException"
+            }
+            UpdatePullRequestApprovalRuleContentError::ApprovalRuleNameRequired(_) => {
+                "ApprovalRuleNameRequiredException"
+            }
+            UpdatePullRequestApprovalRuleContentError::CannotModifyApprovalRuleFromTemplate(_) => {
+                "CannotModifyApprovalRuleFromTemplateException"
+            }
+            UpdatePullRequestApprovalRuleContentError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            UpdatePullRequestApprovalRuleContentError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            UpdatePullRequestApprovalRuleContentError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            UpdatePullRequestApprovalRuleContentError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            UpdatePullRequestApprovalRuleContentError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            UpdatePullRequestApprovalRuleContentError::InvalidApprovalRuleContent(_) => {
+                "InvalidApprovalRuleContentException"
+            }
+            UpdatePullRequestApprovalRuleContentError::InvalidApprovalRuleName(_) => {
+                "InvalidApprovalRuleNameException"
+            }
+            UpdatePullRequestApprovalRuleContentError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            UpdatePullRequestApprovalRuleContentError::InvalidRuleContentSha256(_) => {
+                "InvalidRuleContentSha256Exception"
+            }
+            UpdatePullRequestApprovalRuleContentError::PullRequestAlreadyClosed(_) => {
+                "PullRequestAlreadyClosedException"
+            }
+            UpdatePullRequestApprovalRuleContentError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            UpdatePullRequestApprovalRuleContentError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdatePullRequestApprovalState
 #[derive(Debug, PartialEq)]
 pub enum UpdatePullRequestApprovalStateError {
@@ -14056,6 +16609,60 @@ impl fmt::Display for UpdatePullRequestApprovalStateError {
     }
 }
 impl Error for UpdatePullRequestApprovalStateError {}
+impl ServiceErrorCode for UpdatePullRequestApprovalStateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePullRequestApprovalStateError::ApprovalStateRequired(_) => {
+                "ApprovalStateRequiredException"
+            }
+            UpdatePullRequestApprovalStateError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            UpdatePullRequestApprovalStateError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            UpdatePullRequestApprovalStateError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            UpdatePullRequestApprovalStateError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            UpdatePullRequestApprovalStateError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            UpdatePullRequestApprovalStateError::InvalidApprovalState(_) => {
+                "InvalidApprovalStateException"
+            }
+            UpdatePullRequestApprovalStateError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            UpdatePullRequestApprovalStateError::InvalidRevisionId(_) => {
+                "InvalidRevisionIdException"
+            }
+            UpdatePullRequestApprovalStateError::MaximumNumberOfApprovalsExceeded(_) => {
+                "MaximumNumberOfApprovalsExceededException"
+            }
+            UpdatePullRequestApprovalStateError::PullRequestAlreadyClosed(_) => {
+                "PullRequestAlreadyClosedException"
+            }
+            UpdatePullRequestApprovalStateError::PullRequestCannotBeApprovedByAuthor(_) => {
+                "PullRequestCannotBeApprovedByAuthorException"
+            }
+            UpdatePullRequestApprovalStateError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            UpdatePullRequestApprovalStateError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+            UpdatePullRequestApprovalStateError::RevisionIdRequired(_) => {
+                "RevisionIdRequiredException"
+            }
+            UpdatePullRequestApprovalStateError::RevisionNotCurrent(_) => {
+                "RevisionNotCurrentException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdatePullRequestDescription
 #[derive(Debug, PartialEq)]
 pub enum UpdatePullRequestDescriptionError {
@@ -14132,6 +16739,27 @@ impl fmt::Display for UpdatePullRequestDescriptionError {
     }
 }
 impl Error for UpdatePullRequestDescriptionError {}
+impl ServiceErrorCode for UpdatePullRequestDescriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePullRequestDescriptionError::InvalidDescription(_) => {
+                "InvalidDescriptionException"
+            }
+            UpdatePullRequestDescriptionError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            UpdatePullRequestDescriptionError::PullRequestAlreadyClosed(_) => {
+                "PullRequestAlreadyClosedException"
+            }
+            UpdatePullRequestDescriptionError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            UpdatePullRequestDescriptionError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdatePullRequestStatus
 #[derive(Debug, PartialEq)]
 pub enum UpdatePullRequestStatusError {
@@ -14264,6 +16892,45 @@ impl fmt::Display for UpdatePullRequestStatusError {
     }
 }
 impl Error for UpdatePullRequestStatusError {}
+impl ServiceErrorCode for UpdatePullRequestStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePullRequestStatusError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            UpdatePullRequestStatusError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            UpdatePullRequestStatusError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            UpdatePullRequestStatusError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            UpdatePullRequestStatusError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            UpdatePullRequestStatusError::InvalidPullRequestId(_) => {
+                "InvalidPullRequestIdException"
+            }
+            UpdatePullRequestStatusError::InvalidPullRequestStatus(_) => {
+                "InvalidPullRequestStatusException"
+            }
+            UpdatePullRequestStatusError::InvalidPullRequestStatusUpdate(_) => {
+                "InvalidPullRequestStatusUpdateException"
+            }
+            UpdatePullRequestStatusError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            UpdatePullRequestStatusError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+            UpdatePullRequestStatusError::PullRequestStatusRequired(_) => {
+                "PullRequestStatusRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdatePullRequestTitle
 #[derive(Debug, PartialEq)]
 pub enum UpdatePullRequestTitleError {
@@ -14338,6 +17005,24 @@ impl fmt::Display for UpdatePullRequestTitleError {
     }
 }
 impl Error for UpdatePullRequestTitleError {}
+impl ServiceErrorCode for UpdatePullRequestTitleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePullRequestTitleError::InvalidPullRequestId(_) => "InvalidPullRequestIdException",
+            UpdatePullRequestTitleError::InvalidTitle(_) => "InvalidTitleException",
+            UpdatePullRequestTitleError::PullRequestAlreadyClosed(_) => {
+                "PullRequestAlreadyClosedException"
+            }
+            UpdatePullRequestTitleError::PullRequestDoesNotExist(_) => {
+                "PullRequestDoesNotExistException"
+            }
+            UpdatePullRequestTitleError::PullRequestIdRequired(_) => {
+                "PullRequestIdRequiredException"
+            }
+            UpdatePullRequestTitleError::TitleRequired(_) => "TitleRequiredException",
+        }
+    }
+}
 /// Errors returned by UpdateRepositoryDescription
 #[derive(Debug, PartialEq)]
 pub enum UpdateRepositoryDescriptionError {
@@ -14454,6 +17139,39 @@ impl fmt::Display for UpdateRepositoryDescriptionError {
     }
 }
 impl Error for UpdateRepositoryDescriptionError {}
+impl ServiceErrorCode for UpdateRepositoryDescriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRepositoryDescriptionError::EncryptionIntegrityChecksFailed(_) => {
+                "EncryptionIntegrityChecksFailedException"
+            }
+            UpdateRepositoryDescriptionError::EncryptionKeyAccessDenied(_) => {
+                "EncryptionKeyAccessDeniedException"
+            }
+            UpdateRepositoryDescriptionError::EncryptionKeyDisabled(_) => {
+                "EncryptionKeyDisabledException"
+            }
+            UpdateRepositoryDescriptionError::EncryptionKeyNotFound(_) => {
+                "EncryptionKeyNotFoundException"
+            }
+            UpdateRepositoryDescriptionError::EncryptionKeyUnavailable(_) => {
+                "EncryptionKeyUnavailableException"
+            }
+            UpdateRepositoryDescriptionError::InvalidRepositoryDescription(_) => {
+                "InvalidRepositoryDescriptionException"
+            }
+            UpdateRepositoryDescriptionError::InvalidRepositoryName(_) => {
+                "InvalidRepositoryNameException"
+            }
+            UpdateRepositoryDescriptionError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            UpdateRepositoryDescriptionError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateRepositoryName
 #[derive(Debug, PartialEq)]
 pub enum UpdateRepositoryNameError {
@@ -14510,6 +17228,20 @@ impl fmt::Display for UpdateRepositoryNameError {
     }
 }
 impl Error for UpdateRepositoryNameError {}
+impl ServiceErrorCode for UpdateRepositoryNameError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRepositoryNameError::InvalidRepositoryName(_) => "InvalidRepositoryNameException",
+            UpdateRepositoryNameError::RepositoryDoesNotExist(_) => {
+                "RepositoryDoesNotExistException"
+            }
+            UpdateRepositoryNameError::RepositoryNameExists(_) => "RepositoryNameExistsException",
+            UpdateRepositoryNameError::RepositoryNameRequired(_) => {
+                "RepositoryNameRequiredException"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the CodeCommit API. CodeCommit clients implement this trait.
 #[async_trait]
 pub trait CodeCommit {
@@ -15243,6 +17975,10 @@ impl CodeCommit for CodeCommitClient {
         &self,
         input: CreatePullRequestInput,
     ) -> Result<CreatePullRequestOutput, RusotoError<CreatePullRequestError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "CodeCommit_20150413.CreatePullRequest");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -16205,6 +18941,10 @@ impl CodeCommit for CodeCommitClient {
         input: PostCommentForComparedCommitInput,
     ) -> Result<PostCommentForComparedCommitOutput, RusotoError<PostCommentForComparedCommitError>>
     {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -16227,6 +18967,10 @@ impl CodeCommit for CodeCommitClient {
         &self,
         input: PostCommentForPullRequestInput,
     ) -> Result<PostCommentForPullRequestOutput, RusotoError<PostCommentForPullRequestError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -16249,6 +18993,10 @@ impl CodeCommit for CodeCommitClient {
         &self,
         input: PostCommentReplyInput,
     ) -> Result<PostCommentReplyOutput, RusotoError<PostCommentReplyError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "CodeCommit_20150413.PostCommentReply");
         let encoded = serde_json::to_string(&input).unwrap();
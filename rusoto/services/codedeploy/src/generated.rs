@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2504,6 +2504,27 @@ impl fmt::Display for AddTagsToOnPremisesInstancesError {
     }
 }
 impl Error for AddTagsToOnPremisesInstancesError {}
+impl ServiceErrorCode for AddTagsToOnPremisesInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsToOnPremisesInstancesError::InstanceLimitExceeded(_) => {
+                "InstanceLimitExceededException"
+            }
+            AddTagsToOnPremisesInstancesError::InstanceNameRequired(_) => {
+                "InstanceNameRequiredException"
+            }
+            AddTagsToOnPremisesInstancesError::InstanceNotRegistered(_) => {
+                "InstanceNotRegisteredException"
+            }
+            AddTagsToOnPremisesInstancesError::InvalidInstanceName(_) => {
+                "InvalidInstanceNameException"
+            }
+            AddTagsToOnPremisesInstancesError::InvalidTag(_) => "InvalidTagException",
+            AddTagsToOnPremisesInstancesError::TagLimitExceeded(_) => "TagLimitExceededException",
+            AddTagsToOnPremisesInstancesError::TagRequired(_) => "TagRequiredException",
+        }
+    }
+}
 /// Errors returned by BatchGetApplicationRevisions
 #[derive(Debug, PartialEq)]
 pub enum BatchGetApplicationRevisionsError {
@@ -2588,6 +2609,26 @@ impl fmt::Display for BatchGetApplicationRevisionsError {
     }
 }
 impl Error for BatchGetApplicationRevisionsError {}
+impl ServiceErrorCode for BatchGetApplicationRevisionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetApplicationRevisionsError::ApplicationDoesNotExist(_) => {
+                "ApplicationDoesNotExistException"
+            }
+            BatchGetApplicationRevisionsError::ApplicationNameRequired(_) => {
+                "ApplicationNameRequiredException"
+            }
+            BatchGetApplicationRevisionsError::BatchLimitExceeded(_) => {
+                "BatchLimitExceededException"
+            }
+            BatchGetApplicationRevisionsError::InvalidApplicationName(_) => {
+                "InvalidApplicationNameException"
+            }
+            BatchGetApplicationRevisionsError::InvalidRevision(_) => "InvalidRevisionException",
+            BatchGetApplicationRevisionsError::RevisionRequired(_) => "RevisionRequiredException",
+        }
+    }
+}
 /// Errors returned by BatchGetApplications
 #[derive(Debug, PartialEq)]
 pub enum BatchGetApplicationsError {
@@ -2644,6 +2685,22 @@ impl fmt::Display for BatchGetApplicationsError {
     }
 }
 impl Error for BatchGetApplicationsError {}
+impl ServiceErrorCode for BatchGetApplicationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetApplicationsError::ApplicationDoesNotExist(_) => {
+                "ApplicationDoesNotExistException"
+            }
+            BatchGetApplicationsError::ApplicationNameRequired(_) => {
+                "ApplicationNameRequiredException"
+            }
+            BatchGetApplicationsError::BatchLimitExceeded(_) => "BatchLimitExceededException",
+            BatchGetApplicationsError::InvalidApplicationName(_) => {
+                "InvalidApplicationNameException"
+            }
+        }
+    }
+}
 /// Errors returned by BatchGetDeploymentGroups
 #[derive(Debug, PartialEq)]
 pub enum BatchGetDeploymentGroupsError {
@@ -2736,6 +2793,31 @@ impl fmt::Display for BatchGetDeploymentGroupsError {
     }
 }
 impl Error for BatchGetDeploymentGroupsError {}
+impl ServiceErrorCode for BatchGetDeploymentGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetDeploymentGroupsError::ApplicationDoesNotExist(_) => {
+                "ApplicationDoesNotExistException"
+            }
+            BatchGetDeploymentGroupsError::ApplicationNameRequired(_) => {
+                "ApplicationNameRequiredException"
+            }
+            BatchGetDeploymentGroupsError::BatchLimitExceeded(_) => "BatchLimitExceededException",
+            BatchGetDeploymentGroupsError::DeploymentConfigDoesNotExist(_) => {
+                "DeploymentConfigDoesNotExistException"
+            }
+            BatchGetDeploymentGroupsError::DeploymentGroupNameRequired(_) => {
+                "DeploymentGroupNameRequiredException"
+            }
+            BatchGetDeploymentGroupsError::InvalidApplicationName(_) => {
+                "InvalidApplicationNameException"
+            }
+            BatchGetDeploymentGroupsError::InvalidDeploymentGroupName(_) => {
+                "InvalidDeploymentGroupNameException"
+            }
+        }
+    }
+}
 /// Errors returned by BatchGetDeploymentInstances
 #[derive(Debug, PartialEq)]
 pub enum BatchGetDeploymentInstancesError {
@@ -2832,6 +2914,33 @@ impl fmt::Display for BatchGetDeploymentInstancesError {
     }
 }
 impl Error for BatchGetDeploymentInstancesError {}
+impl ServiceErrorCode for BatchGetDeploymentInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetDeploymentInstancesError::BatchLimitExceeded(_) => {
+                "BatchLimitExceededException"
+            }
+            BatchGetDeploymentInstancesError::DeploymentDoesNotExist(_) => {
+                "DeploymentDoesNotExistException"
+            }
+            BatchGetDeploymentInstancesError::DeploymentIdRequired(_) => {
+                "DeploymentIdRequiredException"
+            }
+            BatchGetDeploymentInstancesError::InstanceIdRequired(_) => {
+                "InstanceIdRequiredException"
+            }
+            BatchGetDeploymentInstancesError::InvalidComputePlatform(_) => {
+                "InvalidComputePlatformException"
+            }
+            BatchGetDeploymentInstancesError::InvalidDeploymentId(_) => {
+                "InvalidDeploymentIdException"
+            }
+            BatchGetDeploymentInstancesError::InvalidInstanceName(_) => {
+                "InvalidInstanceNameException"
+            }
+        }
+    }
+}
 /// Errors returned by BatchGetDeploymentTargets
 #[derive(Debug, PartialEq)]
 pub enum BatchGetDeploymentTargetsError {
@@ -2946,6 +3055,39 @@ impl fmt::Display for BatchGetDeploymentTargetsError {
     }
 }
 impl Error for BatchGetDeploymentTargetsError {}
+impl ServiceErrorCode for BatchGetDeploymentTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetDeploymentTargetsError::DeploymentDoesNotExist(_) => {
+                "DeploymentDoesNotExistException"
+            }
+            BatchGetDeploymentTargetsError::DeploymentIdRequired(_) => {
+                "DeploymentIdRequiredException"
+            }
+            BatchGetDeploymentTargetsError::DeploymentNotStarted(_) => {
+                "DeploymentNotStartedException"
+            }
+            BatchGetDeploymentTargetsError::DeploymentTargetDoesNotExist(_) => {
+                "DeploymentTargetDoesNotExistException"
+            }
+            BatchGetDeploymentTargetsError::DeploymentTargetIdRequired(_) => {
+                "DeploymentTargetIdRequiredException"
+            }
+            BatchGetDeploymentTargetsError::DeploymentTargetListSizeExceeded(_) => {
+                "DeploymentTargetListSizeExceededException"
+            }
+            BatchGetDeploymentTargetsError::InstanceDoesNotExist(_) => {
+                "InstanceDoesNotExistException"
+            }
+            BatchGetDeploymentTargetsError::InvalidDeploymentId(_) => {
+                "InvalidDeploymentIdException"
+            }
+            BatchGetDeploymentTargetsError::InvalidDeploymentTargetId(_) => {
+                "InvalidDeploymentTargetIdException"
+            }
+        }
+    }
+}
 /// Errors returned by BatchGetDeployments
 #[derive(Debug, PartialEq)]
 pub enum BatchGetDeploymentsError {
@@ -2994,6 +3136,15 @@ impl fmt::Display for BatchGetDeploymentsError {
     }
 }
 impl Error for BatchGetDeploymentsError {}
+impl ServiceErrorCode for BatchGetDeploymentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetDeploymentsError::BatchLimitExceeded(_) => "BatchLimitExceededException",
+            BatchGetDeploymentsError::DeploymentIdRequired(_) => "DeploymentIdRequiredException",
+            BatchGetDeploymentsError::InvalidDeploymentId(_) => "InvalidDeploymentIdException",
+        }
+    }
+}
 /// Errors returned by BatchGetOnPremisesInstances
 #[derive(Debug, PartialEq)]
 pub enum BatchGetOnPremisesInstancesError {
@@ -3050,6 +3201,21 @@ impl fmt::Display for BatchGetOnPremisesInstancesError {
     }
 }
 impl Error for BatchGetOnPremisesInstancesError {}
+impl ServiceErrorCode for BatchGetOnPremisesInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetOnPremisesInstancesError::BatchLimitExceeded(_) => {
+                "BatchLimitExceededException"
+            }
+            BatchGetOnPremisesInstancesError::InstanceNameRequired(_) => {
+                "InstanceNameRequiredException"
+            }
+            BatchGetOnPremisesInstancesError::InvalidInstanceName(_) => {
+                "InvalidInstanceNameException"
+            }
+        }
+    }
+}
 /// Errors returned by ContinueDeployment
 #[derive(Debug, PartialEq)]
 pub enum ContinueDeploymentError {
@@ -3144,6 +3310,30 @@ impl fmt::Display for ContinueDeploymentError {
     }
 }
 impl Error for ContinueDeploymentError {}
+impl ServiceErrorCode for ContinueDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ContinueDeploymentError::DeploymentAlreadyCompleted(_) => {
+                "DeploymentAlreadyCompletedException"
+            }
+            ContinueDeploymentError::DeploymentDoesNotExist(_) => "DeploymentDoesNotExistException",
+            ContinueDeploymentError::DeploymentIdRequired(_) => "DeploymentIdRequiredException",
+            ContinueDeploymentError::DeploymentIsNotInReadyState(_) => {
+                "DeploymentIsNotInReadyStateException"
+            }
+            ContinueDeploymentError::InvalidDeploymentId(_) => "InvalidDeploymentIdException",
+            ContinueDeploymentError::InvalidDeploymentStatus(_) => {
+                "InvalidDeploymentStatusException"
+            }
+            ContinueDeploymentError::InvalidDeploymentWaitType(_) => {
+                "InvalidDeploymentWaitTypeException"
+            }
+            ContinueDeploymentError::UnsupportedActionForDeploymentType(_) => {
+                "UnsupportedActionForDeploymentTypeException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateApplication
 #[derive(Debug, PartialEq)]
 pub enum CreateApplicationError {
@@ -3214,6 +3404,24 @@ impl fmt::Display for CreateApplicationError {
     }
 }
 impl Error for CreateApplicationError {}
+impl ServiceErrorCode for CreateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApplicationError::ApplicationAlreadyExists(_) => {
+                "ApplicationAlreadyExistsException"
+            }
+            CreateApplicationError::ApplicationLimitExceeded(_) => {
+                "ApplicationLimitExceededException"
+            }
+            CreateApplicationError::ApplicationNameRequired(_) => {
+                "ApplicationNameRequiredException"
+            }
+            CreateApplicationError::InvalidApplicationName(_) => "InvalidApplicationNameException",
+            CreateApplicationError::InvalidComputePlatform(_) => "InvalidComputePlatformException",
+            CreateApplicationError::InvalidTagsToAdd(_) => "InvalidTagsToAddException",
+        }
+    }
+}
 /// Errors returned by CreateDeployment
 #[derive(Debug, PartialEq)]
 pub enum CreateDeploymentError {
@@ -3428,6 +3636,58 @@ impl fmt::Display for CreateDeploymentError {
     }
 }
 impl Error for CreateDeploymentError {}
+impl ServiceErrorCode for CreateDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeploymentError::ApplicationDoesNotExist(_) => "ApplicationDoesNotExistException",
+            CreateDeploymentError::ApplicationNameRequired(_) => "ApplicationNameRequiredException",
+            CreateDeploymentError::DeploymentConfigDoesNotExist(_) => {
+                "DeploymentConfigDoesNotExistException"
+            }
+            CreateDeploymentError::DeploymentGroupDoesNotExist(_) => {
+                "DeploymentGroupDoesNotExistException"
+            }
+            CreateDeploymentError::DeploymentGroupNameRequired(_) => {
+                "DeploymentGroupNameRequiredException"
+            }
+            CreateDeploymentError::DeploymentLimitExceeded(_) => "DeploymentLimitExceededException",
+            CreateDeploymentError::DescriptionTooLong(_) => "DescriptionTooLongException",
+            CreateDeploymentError::InvalidApplicationName(_) => "InvalidApplicationNameException",
+            CreateDeploymentError::InvalidAutoRollbackConfig(_) => {
+                "InvalidAutoRollbackConfigException"
+            }
+            CreateDeploymentError::InvalidAutoScalingGroup(_) => "InvalidAutoScalingGroupException",
+            CreateDeploymentError::InvalidDeploymentConfigName(_) => {
+                "InvalidDeploymentConfigNameException"
+            }
+            CreateDeploymentError::InvalidDeploymentGroupName(_) => {
+                "InvalidDeploymentGroupNameException"
+            }
+            CreateDeploymentError::InvalidFileExistsBehavior(_) => {
+                "InvalidFileExistsBehaviorException"
+            }
+            CreateDeploymentError::InvalidGitHubAccountToken(_) => {
+                "InvalidGitHubAccountTokenException"
+            }
+            CreateDeploymentError::InvalidIgnoreApplicationStopFailuresValue(_) => {
+                "InvalidIgnoreApplicationStopFailuresValueException"
+            }
+            CreateDeploymentError::InvalidLoadBalancerInfo(_) => "InvalidLoadBalancerInfoException",
+            CreateDeploymentError::InvalidRevision(_) => "InvalidRevisionException",
+            CreateDeploymentError::InvalidRole(_) => "InvalidRoleException",
+            CreateDeploymentError::InvalidTargetInstances(_) => "InvalidTargetInstancesException",
+            CreateDeploymentError::InvalidTrafficRoutingConfiguration(_) => {
+                "InvalidTrafficRoutingConfigurationException"
+            }
+            CreateDeploymentError::InvalidUpdateOutdatedInstancesOnlyValue(_) => {
+                "InvalidUpdateOutdatedInstancesOnlyValueException"
+            }
+            CreateDeploymentError::RevisionDoesNotExist(_) => "RevisionDoesNotExistException",
+            CreateDeploymentError::RevisionRequired(_) => "RevisionRequiredException",
+            CreateDeploymentError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateDeploymentConfig
 #[derive(Debug, PartialEq)]
 pub enum CreateDeploymentConfigError {
@@ -3522,6 +3782,33 @@ impl fmt::Display for CreateDeploymentConfigError {
     }
 }
 impl Error for CreateDeploymentConfigError {}
+impl ServiceErrorCode for CreateDeploymentConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeploymentConfigError::DeploymentConfigAlreadyExists(_) => {
+                "DeploymentConfigAlreadyExistsException"
+            }
+            CreateDeploymentConfigError::DeploymentConfigLimitExceeded(_) => {
+                "DeploymentConfigLimitExceededException"
+            }
+            CreateDeploymentConfigError::DeploymentConfigNameRequired(_) => {
+                "DeploymentConfigNameRequiredException"
+            }
+            CreateDeploymentConfigError::InvalidComputePlatform(_) => {
+                "InvalidComputePlatformException"
+            }
+            CreateDeploymentConfigError::InvalidDeploymentConfigName(_) => {
+                "InvalidDeploymentConfigNameException"
+            }
+            CreateDeploymentConfigError::InvalidMinimumHealthyHostValue(_) => {
+                "InvalidMinimumHealthyHostValueException"
+            }
+            CreateDeploymentConfigError::InvalidTrafficRoutingConfiguration(_) => {
+                "InvalidTrafficRoutingConfigurationException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDeploymentGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateDeploymentGroupError {
@@ -3838,6 +4125,89 @@ impl fmt::Display for CreateDeploymentGroupError {
     }
 }
 impl Error for CreateDeploymentGroupError {}
+impl ServiceErrorCode for CreateDeploymentGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeploymentGroupError::AlarmsLimitExceeded(_) => "AlarmsLimitExceededException",
+            CreateDeploymentGroupError::ApplicationDoesNotExist(_) => {
+                "ApplicationDoesNotExistException"
+            }
+            CreateDeploymentGroupError::ApplicationNameRequired(_) => {
+                "ApplicationNameRequiredException"
+            }
+            CreateDeploymentGroupError::DeploymentConfigDoesNotExist(_) => {
+                "DeploymentConfigDoesNotExistException"
+            }
+            CreateDeploymentGroupError::DeploymentGroupAlreadyExists(_) => {
+                "DeploymentGroupAlreadyExistsException"
+            }
+            CreateDeploymentGroupError::DeploymentGroupLimitExceeded(_) => {
+                "DeploymentGroupLimitExceededException"
+            }
+            CreateDeploymentGroupError::DeploymentGroupNameRequired(_) => {
+                "DeploymentGroupNameRequiredException"
+            }
+            CreateDeploymentGroupError::ECSServiceMappingLimitExceeded(_) => {
+                "ECSServiceMappingLimitExceededException"
+            }
+            CreateDeploymentGroupError::InvalidAlarmConfig(_) => "InvalidAlarmConfigException",
+            CreateDeploymentGroupError::InvalidApplicationName(_) => {
+                "InvalidApplicationNameException"
+            }
+            CreateDeploymentGroupError::InvalidAutoRollbackConfig(_) => {
+                "InvalidAutoRollbackConfigException"
+            }
+            CreateDeploymentGroupError::InvalidAutoScalingGroup(_) => {
+                "InvalidAutoScalingGroupException"
+            }
+            CreateDeploymentGroupError::InvalidBlueGreenDeploymentConfiguration(_) => {
+                "InvalidBlueGreenDeploymentConfigurationException"
+            }
+            CreateDeploymentGroupError::InvalidDeploymentConfigName(_) => {
+                "InvalidDeploymentConfigNameException"
+            }
+            CreateDeploymentGroupError::InvalidDeploymentGroupName(_) => {
+                "InvalidDeploymentGroupNameException"
+            }
+            CreateDeploymentGroupError::InvalidDeploymentStyle(_) => {
+                "InvalidDeploymentStyleException"
+            }
+            CreateDeploymentGroupError::InvalidEC2TagCombination(_) => {
+                "InvalidEC2TagCombinationException"
+            }
+            CreateDeploymentGroupError::InvalidEC2Tag(_) => "InvalidEC2TagException",
+            CreateDeploymentGroupError::InvalidECSService(_) => "InvalidECSServiceException",
+            CreateDeploymentGroupError::InvalidInput(_) => "InvalidInputException",
+            CreateDeploymentGroupError::InvalidLoadBalancerInfo(_) => {
+                "InvalidLoadBalancerInfoException"
+            }
+            CreateDeploymentGroupError::InvalidOnPremisesTagCombination(_) => {
+                "InvalidOnPremisesTagCombinationException"
+            }
+            CreateDeploymentGroupError::InvalidRole(_) => "InvalidRoleException",
+            CreateDeploymentGroupError::InvalidTag(_) => "InvalidTagException",
+            CreateDeploymentGroupError::InvalidTagsToAdd(_) => "InvalidTagsToAddException",
+            CreateDeploymentGroupError::InvalidTargetGroupPair(_) => {
+                "InvalidTargetGroupPairException"
+            }
+            CreateDeploymentGroupError::InvalidTrafficRoutingConfiguration(_) => {
+                "InvalidTrafficRoutingConfigurationException"
+            }
+            CreateDeploymentGroupError::InvalidTriggerConfig(_) => "InvalidTriggerConfigException",
+            CreateDeploymentGroupError::LifecycleHookLimitExceeded(_) => {
+                "LifecycleHookLimitExceededException"
+            }
+            CreateDeploymentGroupError::RoleRequired(_) => "RoleRequiredException",
+            CreateDeploymentGroupError::TagSetListLimitExceeded(_) => {
+                "TagSetListLimitExceededException"
+            }
+            CreateDeploymentGroupError::Throttling(_) => "ThrottlingException",
+            CreateDeploymentGroupError::TriggerTargetsLimitExceeded(_) => {
+                "TriggerTargetsLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteApplication
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationError {
@@ -3884,6 +4254,17 @@ impl fmt::Display for DeleteApplicationError {
     }
 }
 impl Error for DeleteApplicationError {}
+impl ServiceErrorCode for DeleteApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationError::ApplicationNameRequired(_) => {
+                "ApplicationNameRequiredException"
+            }
+            DeleteApplicationError::InvalidApplicationName(_) => "InvalidApplicationNameException",
+            DeleteApplicationError::InvalidRole(_) => "InvalidRoleException",
+        }
+    }
+}
 /// Errors returned by DeleteDeploymentConfig
 #[derive(Debug, PartialEq)]
 pub enum DeleteDeploymentConfigError {
@@ -3944,6 +4325,22 @@ impl fmt::Display for DeleteDeploymentConfigError {
     }
 }
 impl Error for DeleteDeploymentConfigError {}
+impl ServiceErrorCode for DeleteDeploymentConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDeploymentConfigError::DeploymentConfigInUse(_) => {
+                "DeploymentConfigInUseException"
+            }
+            DeleteDeploymentConfigError::DeploymentConfigNameRequired(_) => {
+                "DeploymentConfigNameRequiredException"
+            }
+            DeleteDeploymentConfigError::InvalidDeploymentConfigName(_) => {
+                "InvalidDeploymentConfigNameException"
+            }
+            DeleteDeploymentConfigError::InvalidOperation(_) => "InvalidOperationException",
+        }
+    }
+}
 /// Errors returned by DeleteDeploymentGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteDeploymentGroupError {
@@ -4012,6 +4409,25 @@ impl fmt::Display for DeleteDeploymentGroupError {
     }
 }
 impl Error for DeleteDeploymentGroupError {}
+impl ServiceErrorCode for DeleteDeploymentGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDeploymentGroupError::ApplicationNameRequired(_) => {
+                "ApplicationNameRequiredException"
+            }
+            DeleteDeploymentGroupError::DeploymentGroupNameRequired(_) => {
+                "DeploymentGroupNameRequiredException"
+            }
+            DeleteDeploymentGroupError::InvalidApplicationName(_) => {
+                "InvalidApplicationNameException"
+            }
+            DeleteDeploymentGroupError::InvalidDeploymentGroupName(_) => {
+                "InvalidDeploymentGroupNameException"
+            }
+            DeleteDeploymentGroupError::InvalidRole(_) => "InvalidRoleException",
+        }
+    }
+}
 /// Errors returned by DeleteGitHubAccountToken
 #[derive(Debug, PartialEq)]
 pub enum DeleteGitHubAccountTokenError {
@@ -4084,6 +4500,25 @@ impl fmt::Display for DeleteGitHubAccountTokenError {
     }
 }
 impl Error for DeleteGitHubAccountTokenError {}
+impl ServiceErrorCode for DeleteGitHubAccountTokenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGitHubAccountTokenError::GitHubAccountTokenDoesNotExist(_) => {
+                "GitHubAccountTokenDoesNotExistException"
+            }
+            DeleteGitHubAccountTokenError::GitHubAccountTokenNameRequired(_) => {
+                "GitHubAccountTokenNameRequiredException"
+            }
+            DeleteGitHubAccountTokenError::InvalidGitHubAccountTokenName(_) => {
+                "InvalidGitHubAccountTokenNameException"
+            }
+            DeleteGitHubAccountTokenError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            DeleteGitHubAccountTokenError::ResourceValidation(_) => "ResourceValidationException",
+        }
+    }
+}
 /// Errors returned by DeleteResourcesByExternalId
 #[derive(Debug, PartialEq)]
 pub enum DeleteResourcesByExternalIdError {}
@@ -4108,6 +4543,11 @@ impl fmt::Display for DeleteResourcesByExternalIdError {
     }
 }
 impl Error for DeleteResourcesByExternalIdError {}
+impl ServiceErrorCode for DeleteResourcesByExternalIdError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeregisterOnPremisesInstance
 #[derive(Debug, PartialEq)]
 pub enum DeregisterOnPremisesInstanceError {
@@ -4154,6 +4594,18 @@ impl fmt::Display for DeregisterOnPremisesInstanceError {
     }
 }
 impl Error for DeregisterOnPremisesInstanceError {}
+impl ServiceErrorCode for DeregisterOnPremisesInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterOnPremisesInstanceError::InstanceNameRequired(_) => {
+                "InstanceNameRequiredException"
+            }
+            DeregisterOnPremisesInstanceError::InvalidInstanceName(_) => {
+                "InvalidInstanceNameException"
+            }
+        }
+    }
+}
 /// Errors returned by GetApplication
 #[derive(Debug, PartialEq)]
 pub enum GetApplicationError {
@@ -4202,6 +4654,15 @@ impl fmt::Display for GetApplicationError {
     }
 }
 impl Error for GetApplicationError {}
+impl ServiceErrorCode for GetApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetApplicationError::ApplicationDoesNotExist(_) => "ApplicationDoesNotExistException",
+            GetApplicationError::ApplicationNameRequired(_) => "ApplicationNameRequiredException",
+            GetApplicationError::InvalidApplicationName(_) => "InvalidApplicationNameException",
+        }
+    }
+}
 /// Errors returned by GetApplicationRevision
 #[derive(Debug, PartialEq)]
 pub enum GetApplicationRevisionError {
@@ -4280,6 +4741,24 @@ impl fmt::Display for GetApplicationRevisionError {
     }
 }
 impl Error for GetApplicationRevisionError {}
+impl ServiceErrorCode for GetApplicationRevisionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetApplicationRevisionError::ApplicationDoesNotExist(_) => {
+                "ApplicationDoesNotExistException"
+            }
+            GetApplicationRevisionError::ApplicationNameRequired(_) => {
+                "ApplicationNameRequiredException"
+            }
+            GetApplicationRevisionError::InvalidApplicationName(_) => {
+                "InvalidApplicationNameException"
+            }
+            GetApplicationRevisionError::InvalidRevision(_) => "InvalidRevisionException",
+            GetApplicationRevisionError::RevisionDoesNotExist(_) => "RevisionDoesNotExistException",
+            GetApplicationRevisionError::RevisionRequired(_) => "RevisionRequiredException",
+        }
+    }
+}
 /// Errors returned by GetDeployment
 #[derive(Debug, PartialEq)]
 pub enum GetDeploymentError {
@@ -4324,6 +4803,15 @@ impl fmt::Display for GetDeploymentError {
     }
 }
 impl Error for GetDeploymentError {}
+impl ServiceErrorCode for GetDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeploymentError::DeploymentDoesNotExist(_) => "DeploymentDoesNotExistException",
+            GetDeploymentError::DeploymentIdRequired(_) => "DeploymentIdRequiredException",
+            GetDeploymentError::InvalidDeploymentId(_) => "InvalidDeploymentIdException",
+        }
+    }
+}
 /// Errors returned by GetDeploymentConfig
 #[derive(Debug, PartialEq)]
 pub enum GetDeploymentConfigError {
@@ -4386,6 +4874,24 @@ impl fmt::Display for GetDeploymentConfigError {
     }
 }
 impl Error for GetDeploymentConfigError {}
+impl ServiceErrorCode for GetDeploymentConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeploymentConfigError::DeploymentConfigDoesNotExist(_) => {
+                "DeploymentConfigDoesNotExistException"
+            }
+            GetDeploymentConfigError::DeploymentConfigNameRequired(_) => {
+                "DeploymentConfigNameRequiredException"
+            }
+            GetDeploymentConfigError::InvalidComputePlatform(_) => {
+                "InvalidComputePlatformException"
+            }
+            GetDeploymentConfigError::InvalidDeploymentConfigName(_) => {
+                "InvalidDeploymentConfigNameException"
+            }
+        }
+    }
+}
 /// Errors returned by GetDeploymentGroup
 #[derive(Debug, PartialEq)]
 pub enum GetDeploymentGroupError {
@@ -4474,6 +4980,31 @@ impl fmt::Display for GetDeploymentGroupError {
     }
 }
 impl Error for GetDeploymentGroupError {}
+impl ServiceErrorCode for GetDeploymentGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeploymentGroupError::ApplicationDoesNotExist(_) => {
+                "ApplicationDoesNotExistException"
+            }
+            GetDeploymentGroupError::ApplicationNameRequired(_) => {
+                "ApplicationNameRequiredException"
+            }
+            GetDeploymentGroupError::DeploymentConfigDoesNotExist(_) => {
+                "DeploymentConfigDoesNotExistException"
+            }
+            GetDeploymentGroupError::DeploymentGroupDoesNotExist(_) => {
+                "DeploymentGroupDoesNotExistException"
+            }
+            GetDeploymentGroupError::DeploymentGroupNameRequired(_) => {
+                "DeploymentGroupNameRequiredException"
+            }
+            GetDeploymentGroupError::InvalidApplicationName(_) => "InvalidApplicationNameException",
+            GetDeploymentGroupError::InvalidDeploymentGroupName(_) => {
+                "InvalidDeploymentGroupNameException"
+            }
+        }
+    }
+}
 /// Errors returned by GetDeploymentInstance
 #[derive(Debug, PartialEq)]
 pub enum GetDeploymentInstanceError {
@@ -4554,6 +5085,23 @@ impl fmt::Display for GetDeploymentInstanceError {
     }
 }
 impl Error for GetDeploymentInstanceError {}
+impl ServiceErrorCode for GetDeploymentInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeploymentInstanceError::DeploymentDoesNotExist(_) => {
+                "DeploymentDoesNotExistException"
+            }
+            GetDeploymentInstanceError::DeploymentIdRequired(_) => "DeploymentIdRequiredException",
+            GetDeploymentInstanceError::InstanceDoesNotExist(_) => "InstanceDoesNotExistException",
+            GetDeploymentInstanceError::InstanceIdRequired(_) => "InstanceIdRequiredException",
+            GetDeploymentInstanceError::InvalidComputePlatform(_) => {
+                "InvalidComputePlatformException"
+            }
+            GetDeploymentInstanceError::InvalidDeploymentId(_) => "InvalidDeploymentIdException",
+            GetDeploymentInstanceError::InvalidInstanceName(_) => "InvalidInstanceNameException",
+        }
+    }
+}
 /// Errors returned by GetDeploymentTarget
 #[derive(Debug, PartialEq)]
 pub enum GetDeploymentTargetError {
@@ -4648,6 +5196,28 @@ impl fmt::Display for GetDeploymentTargetError {
     }
 }
 impl Error for GetDeploymentTargetError {}
+impl ServiceErrorCode for GetDeploymentTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeploymentTargetError::DeploymentDoesNotExist(_) => {
+                "DeploymentDoesNotExistException"
+            }
+            GetDeploymentTargetError::DeploymentIdRequired(_) => "DeploymentIdRequiredException",
+            GetDeploymentTargetError::DeploymentNotStarted(_) => "DeploymentNotStartedException",
+            GetDeploymentTargetError::DeploymentTargetDoesNotExist(_) => {
+                "DeploymentTargetDoesNotExistException"
+            }
+            GetDeploymentTargetError::DeploymentTargetIdRequired(_) => {
+                "DeploymentTargetIdRequiredException"
+            }
+            GetDeploymentTargetError::InvalidDeploymentId(_) => "InvalidDeploymentIdException",
+            GetDeploymentTargetError::InvalidDeploymentTargetId(_) => {
+                "InvalidDeploymentTargetIdException"
+            }
+            GetDeploymentTargetError::InvalidInstanceName(_) => "InvalidInstanceNameException",
+        }
+    }
+}
 /// Errors returned by GetOnPremisesInstance
 #[derive(Debug, PartialEq)]
 pub enum GetOnPremisesInstanceError {
@@ -4696,6 +5266,17 @@ impl fmt::Display for GetOnPremisesInstanceError {
     }
 }
 impl Error for GetOnPremisesInstanceError {}
+impl ServiceErrorCode for GetOnPremisesInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOnPremisesInstanceError::InstanceNameRequired(_) => "InstanceNameRequiredException",
+            GetOnPremisesInstanceError::InstanceNotRegistered(_) => {
+                "InstanceNotRegisteredException"
+            }
+            GetOnPremisesInstanceError::InvalidInstanceName(_) => "InvalidInstanceNameException",
+        }
+    }
+}
 /// Errors returned by ListApplicationRevisions
 #[derive(Debug, PartialEq)]
 pub enum ListApplicationRevisionsError {
@@ -4814,6 +5395,36 @@ impl fmt::Display for ListApplicationRevisionsError {
     }
 }
 impl Error for ListApplicationRevisionsError {}
+impl ServiceErrorCode for ListApplicationRevisionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListApplicationRevisionsError::ApplicationDoesNotExist(_) => {
+                "ApplicationDoesNotExistException"
+            }
+            ListApplicationRevisionsError::ApplicationNameRequired(_) => {
+                "ApplicationNameRequiredException"
+            }
+            ListApplicationRevisionsError::BucketNameFilterRequired(_) => {
+                "BucketNameFilterRequiredException"
+            }
+            ListApplicationRevisionsError::InvalidApplicationName(_) => {
+                "InvalidApplicationNameException"
+            }
+            ListApplicationRevisionsError::InvalidBucketNameFilter(_) => {
+                "InvalidBucketNameFilterException"
+            }
+            ListApplicationRevisionsError::InvalidDeployedStateFilter(_) => {
+                "InvalidDeployedStateFilterException"
+            }
+            ListApplicationRevisionsError::InvalidKeyPrefixFilter(_) => {
+                "InvalidKeyPrefixFilterException"
+            }
+            ListApplicationRevisionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListApplicationRevisionsError::InvalidSortBy(_) => "InvalidSortByException",
+            ListApplicationRevisionsError::InvalidSortOrder(_) => "InvalidSortOrderException",
+        }
+    }
+}
 /// Errors returned by ListApplications
 #[derive(Debug, PartialEq)]
 pub enum ListApplicationsError {
@@ -4844,6 +5455,13 @@ impl fmt::Display for ListApplicationsError {
     }
 }
 impl Error for ListApplicationsError {}
+impl ServiceErrorCode for ListApplicationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListApplicationsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListDeploymentConfigs
 #[derive(Debug, PartialEq)]
 pub enum ListDeploymentConfigsError {
@@ -4876,6 +5494,13 @@ impl fmt::Display for ListDeploymentConfigsError {
     }
 }
 impl Error for ListDeploymentConfigsError {}
+impl ServiceErrorCode for ListDeploymentConfigsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeploymentConfigsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListDeploymentGroups
 #[derive(Debug, PartialEq)]
 pub enum ListDeploymentGroupsError {
@@ -4932,6 +5557,22 @@ impl fmt::Display for ListDeploymentGroupsError {
     }
 }
 impl Error for ListDeploymentGroupsError {}
+impl ServiceErrorCode for ListDeploymentGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeploymentGroupsError::ApplicationDoesNotExist(_) => {
+                "ApplicationDoesNotExistException"
+            }
+            ListDeploymentGroupsError::ApplicationNameRequired(_) => {
+                "ApplicationNameRequiredException"
+            }
+            ListDeploymentGroupsError::InvalidApplicationName(_) => {
+                "InvalidApplicationNameException"
+            }
+            ListDeploymentGroupsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListDeploymentInstances
 #[derive(Debug, PartialEq)]
 pub enum ListDeploymentInstancesError {
@@ -5046,6 +5687,36 @@ impl fmt::Display for ListDeploymentInstancesError {
     }
 }
 impl Error for ListDeploymentInstancesError {}
+impl ServiceErrorCode for ListDeploymentInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeploymentInstancesError::DeploymentDoesNotExist(_) => {
+                "DeploymentDoesNotExistException"
+            }
+            ListDeploymentInstancesError::DeploymentIdRequired(_) => {
+                "DeploymentIdRequiredException"
+            }
+            ListDeploymentInstancesError::DeploymentNotStarted(_) => {
+                "DeploymentNotStartedException"
+            }
+            ListDeploymentInstancesError::InvalidComputePlatform(_) => {
+                "InvalidComputePlatformException"
+            }
+            ListDeploymentInstancesError::InvalidDeploymentId(_) => "InvalidDeploymentIdException",
+            ListDeploymentInstancesError::InvalidDeploymentInstanceType(_) => {
+                "InvalidDeploymentInstanceTypeException"
+            }
+            ListDeploymentInstancesError::InvalidInstanceStatus(_) => {
+                "InvalidInstanceStatusException"
+            }
+            ListDeploymentInstancesError::InvalidInstanceType(_) => "InvalidInstanceTypeException",
+            ListDeploymentInstancesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListDeploymentInstancesError::InvalidTargetFilterName(_) => {
+                "InvalidTargetFilterNameException"
+            }
+        }
+    }
+}
 /// Errors returned by ListDeploymentTargets
 #[derive(Debug, PartialEq)]
 pub enum ListDeploymentTargetsError {
@@ -5136,6 +5807,26 @@ impl fmt::Display for ListDeploymentTargetsError {
     }
 }
 impl Error for ListDeploymentTargetsError {}
+impl ServiceErrorCode for ListDeploymentTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeploymentTargetsError::DeploymentDoesNotExist(_) => {
+                "DeploymentDoesNotExistException"
+            }
+            ListDeploymentTargetsError::DeploymentIdRequired(_) => "DeploymentIdRequiredException",
+            ListDeploymentTargetsError::DeploymentNotStarted(_) => "DeploymentNotStartedException",
+            ListDeploymentTargetsError::InvalidDeploymentId(_) => "InvalidDeploymentIdException",
+            ListDeploymentTargetsError::InvalidDeploymentInstanceType(_) => {
+                "InvalidDeploymentInstanceTypeException"
+            }
+            ListDeploymentTargetsError::InvalidInstanceStatus(_) => {
+                "InvalidInstanceStatusException"
+            }
+            ListDeploymentTargetsError::InvalidInstanceType(_) => "InvalidInstanceTypeException",
+            ListDeploymentTargetsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListDeployments
 #[derive(Debug, PartialEq)]
 pub enum ListDeploymentsError {
@@ -5240,6 +5931,29 @@ impl fmt::Display for ListDeploymentsError {
     }
 }
 impl Error for ListDeploymentsError {}
+impl ServiceErrorCode for ListDeploymentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeploymentsError::ApplicationDoesNotExist(_) => "ApplicationDoesNotExistException",
+            ListDeploymentsError::ApplicationNameRequired(_) => "ApplicationNameRequiredException",
+            ListDeploymentsError::DeploymentGroupDoesNotExist(_) => {
+                "DeploymentGroupDoesNotExistException"
+            }
+            ListDeploymentsError::DeploymentGroupNameRequired(_) => {
+                "DeploymentGroupNameRequiredException"
+            }
+            ListDeploymentsError::InvalidApplicationName(_) => "InvalidApplicationNameException",
+            ListDeploymentsError::InvalidDeploymentGroupName(_) => {
+                "InvalidDeploymentGroupNameException"
+            }
+            ListDeploymentsError::InvalidDeploymentStatus(_) => "InvalidDeploymentStatusException",
+            ListDeploymentsError::InvalidExternalId(_) => "InvalidExternalIdException",
+            ListDeploymentsError::InvalidInput(_) => "InvalidInputException",
+            ListDeploymentsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListDeploymentsError::InvalidTimeRange(_) => "InvalidTimeRangeException",
+        }
+    }
+}
 /// Errors returned by ListGitHubAccountTokenNames
 #[derive(Debug, PartialEq)]
 pub enum ListGitHubAccountTokenNamesError {
@@ -5294,6 +6008,19 @@ impl fmt::Display for ListGitHubAccountTokenNamesError {
     }
 }
 impl Error for ListGitHubAccountTokenNamesError {}
+impl ServiceErrorCode for ListGitHubAccountTokenNamesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGitHubAccountTokenNamesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListGitHubAccountTokenNamesError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            ListGitHubAccountTokenNamesError::ResourceValidation(_) => {
+                "ResourceValidationException"
+            }
+        }
+    }
+}
 /// Errors returned by ListOnPremisesInstances
 #[derive(Debug, PartialEq)]
 pub enum ListOnPremisesInstancesError {
@@ -5344,6 +6071,17 @@ impl fmt::Display for ListOnPremisesInstancesError {
     }
 }
 impl Error for ListOnPremisesInstancesError {}
+impl ServiceErrorCode for ListOnPremisesInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOnPremisesInstancesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListOnPremisesInstancesError::InvalidRegistrationStatus(_) => {
+                "InvalidRegistrationStatusException"
+            }
+            ListOnPremisesInstancesError::InvalidTagFilter(_) => "InvalidTagFilterException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -5388,6 +6126,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::ArnNotSupported(_) => "ArnNotSupportedException",
+            ListTagsForResourceError::InvalidArn(_) => "InvalidArnException",
+            ListTagsForResourceError::ResourceArnRequired(_) => "ResourceArnRequiredException",
+        }
+    }
+}
 /// Errors returned by PutLifecycleEventHookExecutionStatus
 #[derive(Debug, PartialEq)]
 pub enum PutLifecycleEventHookExecutionStatusError {
@@ -5456,6 +6203,33 @@ impl fmt::Display for PutLifecycleEventHookExecutionStatusError {
     }
 }
 impl Error for PutLifecycleEventHookExecutionStatusError {}
+impl ServiceErrorCode for PutLifecycleEventHookExecutionStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutLifecycleEventHookExecutionStatusError::DeploymentDoesNotExist(_) => {
+                "DeploymentDoesNotExistException"
+            }
+            PutLifecycleEventHookExecutionStatusError::DeploymentIdRequired(_) => {
+                "DeploymentIdRequiredException"
+            }
+            PutLifecycleEventHookExecutionStatusError::InvalidDeploymentId(_) => {
+                "InvalidDeploymentIdException"
+            }
+            PutLifecycleEventHookExecutionStatusError::InvalidLifecycleEventHookExecutionId(_) => {
+                "InvalidLifecycleEventHookExecutionIdException"
+            }
+            PutLifecycleEventHookExecutionStatusError::InvalidLifecycleEventHookExecutionStatus(
+                _,
+            ) => "InvalidLifecycleEventHookExecutionStatusException",
+            PutLifecycleEventHookExecutionStatusError::LifecycleEventAlreadyCompleted(_) => {
+                "LifecycleEventAlreadyCompletedException"
+            }
+            PutLifecycleEventHookExecutionStatusError::UnsupportedActionForDeploymentType(_) => {
+                "UnsupportedActionForDeploymentTypeException"
+            }
+        }
+    }
+}
 /// Errors returned by RegisterApplicationRevision
 #[derive(Debug, PartialEq)]
 pub enum RegisterApplicationRevisionError {
@@ -5538,6 +6312,26 @@ impl fmt::Display for RegisterApplicationRevisionError {
     }
 }
 impl Error for RegisterApplicationRevisionError {}
+impl ServiceErrorCode for RegisterApplicationRevisionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterApplicationRevisionError::ApplicationDoesNotExist(_) => {
+                "ApplicationDoesNotExistException"
+            }
+            RegisterApplicationRevisionError::ApplicationNameRequired(_) => {
+                "ApplicationNameRequiredException"
+            }
+            RegisterApplicationRevisionError::DescriptionTooLong(_) => {
+                "DescriptionTooLongException"
+            }
+            RegisterApplicationRevisionError::InvalidApplicationName(_) => {
+                "InvalidApplicationNameException"
+            }
+            RegisterApplicationRevisionError::InvalidRevision(_) => "InvalidRevisionException",
+            RegisterApplicationRevisionError::RevisionRequired(_) => "RevisionRequiredException",
+        }
+    }
+}
 /// Errors returned by RegisterOnPremisesInstance
 #[derive(Debug, PartialEq)]
 pub enum RegisterOnPremisesInstanceError {
@@ -5660,6 +6454,36 @@ impl fmt::Display for RegisterOnPremisesInstanceError {
     }
 }
 impl Error for RegisterOnPremisesInstanceError {}
+impl ServiceErrorCode for RegisterOnPremisesInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterOnPremisesInstanceError::IamArnRequired(_) => "IamArnRequiredException",
+            RegisterOnPremisesInstanceError::IamSessionArnAlreadyRegistered(_) => {
+                "IamSessionArnAlreadyRegisteredException"
+            }
+            RegisterOnPremisesInstanceError::IamUserArnAlreadyRegistered(_) => {
+                "IamUserArnAlreadyRegisteredException"
+            }
+            RegisterOnPremisesInstanceError::IamUserArnRequired(_) => "IamUserArnRequiredException",
+            RegisterOnPremisesInstanceError::InstanceNameAlreadyRegistered(_) => {
+                "InstanceNameAlreadyRegisteredException"
+            }
+            RegisterOnPremisesInstanceError::InstanceNameRequired(_) => {
+                "InstanceNameRequiredException"
+            }
+            RegisterOnPremisesInstanceError::InvalidIamSessionArn(_) => {
+                "InvalidIamSessionArnException"
+            }
+            RegisterOnPremisesInstanceError::InvalidIamUserArn(_) => "InvalidIamUserArnException",
+            RegisterOnPremisesInstanceError::InvalidInstanceName(_) => {
+                "InvalidInstanceNameException"
+            }
+            RegisterOnPremisesInstanceError::MultipleIamArnsProvided(_) => {
+                "MultipleIamArnsProvidedException"
+            }
+        }
+    }
+}
 /// Errors returned by RemoveTagsFromOnPremisesInstances
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromOnPremisesInstancesError {
@@ -5754,6 +6578,29 @@ impl fmt::Display for RemoveTagsFromOnPremisesInstancesError {
     }
 }
 impl Error for RemoveTagsFromOnPremisesInstancesError {}
+impl ServiceErrorCode for RemoveTagsFromOnPremisesInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsFromOnPremisesInstancesError::InstanceLimitExceeded(_) => {
+                "InstanceLimitExceededException"
+            }
+            RemoveTagsFromOnPremisesInstancesError::InstanceNameRequired(_) => {
+                "InstanceNameRequiredException"
+            }
+            RemoveTagsFromOnPremisesInstancesError::InstanceNotRegistered(_) => {
+                "InstanceNotRegisteredException"
+            }
+            RemoveTagsFromOnPremisesInstancesError::InvalidInstanceName(_) => {
+                "InvalidInstanceNameException"
+            }
+            RemoveTagsFromOnPremisesInstancesError::InvalidTag(_) => "InvalidTagException",
+            RemoveTagsFromOnPremisesInstancesError::TagLimitExceeded(_) => {
+                "TagLimitExceededException"
+            }
+            RemoveTagsFromOnPremisesInstancesError::TagRequired(_) => "TagRequiredException",
+        }
+    }
+}
 /// Errors returned by SkipWaitTimeForInstanceTermination
 #[derive(Debug, PartialEq)]
 pub enum SkipWaitTimeForInstanceTerminationError {
@@ -5844,6 +6691,30 @@ impl fmt::Display for SkipWaitTimeForInstanceTerminationError {
     }
 }
 impl Error for SkipWaitTimeForInstanceTerminationError {}
+impl ServiceErrorCode for SkipWaitTimeForInstanceTerminationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SkipWaitTimeForInstanceTerminationError::DeploymentAlreadyCompleted(_) => {
+                "DeploymentAlreadyCompletedException"
+            }
+            SkipWaitTimeForInstanceTerminationError::DeploymentDoesNotExist(_) => {
+                "DeploymentDoesNotExistException"
+            }
+            SkipWaitTimeForInstanceTerminationError::DeploymentIdRequired(_) => {
+                "DeploymentIdRequiredException"
+            }
+            SkipWaitTimeForInstanceTerminationError::DeploymentNotStarted(_) => {
+                "DeploymentNotStartedException"
+            }
+            SkipWaitTimeForInstanceTerminationError::InvalidDeploymentId(_) => {
+                "InvalidDeploymentIdException"
+            }
+            SkipWaitTimeForInstanceTerminationError::UnsupportedActionForDeploymentType(_) => {
+                "UnsupportedActionForDeploymentTypeException"
+            }
+        }
+    }
+}
 /// Errors returned by StopDeployment
 #[derive(Debug, PartialEq)]
 pub enum StopDeploymentError {
@@ -5914,6 +6785,24 @@ impl fmt::Display for StopDeploymentError {
     }
 }
 impl Error for StopDeploymentError {}
+impl ServiceErrorCode for StopDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopDeploymentError::DeploymentAlreadyCompleted(_) => {
+                "DeploymentAlreadyCompletedException"
+            }
+            StopDeploymentError::DeploymentDoesNotExist(_) => "DeploymentDoesNotExistException",
+            StopDeploymentError::DeploymentGroupDoesNotExist(_) => {
+                "DeploymentGroupDoesNotExistException"
+            }
+            StopDeploymentError::DeploymentIdRequired(_) => "DeploymentIdRequiredException",
+            StopDeploymentError::InvalidDeploymentId(_) => "InvalidDeploymentIdException",
+            StopDeploymentError::UnsupportedActionForDeploymentType(_) => {
+                "UnsupportedActionForDeploymentTypeException"
+            }
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -5990,6 +6879,24 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::ApplicationDoesNotExist(_) => "ApplicationDoesNotExistException",
+            TagResourceError::ArnNotSupported(_) => "ArnNotSupportedException",
+            TagResourceError::DeploymentConfigDoesNotExist(_) => {
+                "DeploymentConfigDoesNotExistException"
+            }
+            TagResourceError::DeploymentGroupDoesNotExist(_) => {
+                "DeploymentGroupDoesNotExistException"
+            }
+            TagResourceError::InvalidArn(_) => "InvalidArnException",
+            TagResourceError::InvalidTagsToAdd(_) => "InvalidTagsToAddException",
+            TagResourceError::ResourceArnRequired(_) => "ResourceArnRequiredException",
+            TagResourceError::TagRequired(_) => "TagRequiredException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -6068,6 +6975,24 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ApplicationDoesNotExist(_) => "ApplicationDoesNotExistException",
+            UntagResourceError::ArnNotSupported(_) => "ArnNotSupportedException",
+            UntagResourceError::DeploymentConfigDoesNotExist(_) => {
+                "DeploymentConfigDoesNotExistException"
+            }
+            UntagResourceError::DeploymentGroupDoesNotExist(_) => {
+                "DeploymentGroupDoesNotExistException"
+            }
+            UntagResourceError::InvalidArn(_) => "InvalidArnException",
+            UntagResourceError::InvalidTagsToAdd(_) => "InvalidTagsToAddException",
+            UntagResourceError::ResourceArnRequired(_) => "ResourceArnRequiredException",
+            UntagResourceError::TagRequired(_) => "TagRequiredException",
+        }
+    }
+}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
 pub enum UpdateApplicationError {
@@ -6124,6 +7049,22 @@ impl fmt::Display for UpdateApplicationError {
     }
 }
 impl Error for UpdateApplicationError {}
+impl ServiceErrorCode for UpdateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApplicationError::ApplicationAlreadyExists(_) => {
+                "ApplicationAlreadyExistsException"
+            }
+            UpdateApplicationError::ApplicationDoesNotExist(_) => {
+                "ApplicationDoesNotExistException"
+            }
+            UpdateApplicationError::ApplicationNameRequired(_) => {
+                "ApplicationNameRequiredException"
+            }
+            UpdateApplicationError::InvalidApplicationName(_) => "InvalidApplicationNameException",
+        }
+    }
+}
 /// Errors returned by UpdateDeploymentGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateDeploymentGroupError {
@@ -6426,6 +7367,87 @@ impl fmt::Display for UpdateDeploymentGroupError {
     }
 }
 impl Error for UpdateDeploymentGroupError {}
+impl ServiceErrorCode for UpdateDeploymentGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDeploymentGroupError::AlarmsLimitExceeded(_) => "AlarmsLimitExceededException",
+            UpdateDeploymentGroupError::ApplicationDoesNotExist(_) => {
+                "ApplicationDoesNotExistException"
+            }
+            UpdateDeploymentGroupError::ApplicationNameRequired(_) => {
+                "ApplicationNameRequiredException"
+            }
+            UpdateDeploymentGroupError::DeploymentConfigDoesNotExist(_) => {
+                "DeploymentConfigDoesNotExistException"
+            }
+            UpdateDeploymentGroupError::DeploymentGroupAlreadyExists(_) => {
+                "DeploymentGroupAlreadyExistsException"
+            }
+            UpdateDeploymentGroupError::DeploymentGroupDoesNotExist(_) => {
+                "DeploymentGroupDoesNotExistException"
+            }
+            UpdateDeploymentGroupError::DeploymentGroupNameRequired(_) => {
+                "DeploymentGroupNameRequiredException"
+            }
+            UpdateDeploymentGroupError::ECSServiceMappingLimitExceeded(_) => {
+                "ECSServiceMappingLimitExceededException"
+            }
+            UpdateDeploymentGroupError::InvalidAlarmConfig(_) => "InvalidAlarmConfigException",
+            UpdateDeploymentGroupError::InvalidApplicationName(_) => {
+                "InvalidApplicationNameException"
+            }
+            UpdateDeploymentGroupError::InvalidAutoRollbackConfig(_) => {
+                "InvalidAutoRollbackConfigException"
+            }
+            UpdateDeploymentGroupError::InvalidAutoScalingGroup(_) => {
+                "InvalidAutoScalingGroupException"
+            }
+            UpdateDeploymentGroupError::InvalidBlueGreenDeploymentConfiguration(_) => {
+                "InvalidBlueGreenDeploymentConfigurationException"
+            }
+            UpdateDeploymentGroupError::InvalidDeploymentConfigName(_) => {
+                "InvalidDeploymentConfigNameException"
+            }
+            UpdateDeploymentGroupError::InvalidDeploymentGroupName(_) => {
+                "InvalidDeploymentGroupNameException"
+            }
+            UpdateDeploymentGroupError::InvalidDeploymentStyle(_) => {
+                "InvalidDeploymentStyleException"
+            }
+            UpdateDeploymentGroupError::InvalidEC2TagCombination(_) => {
+                "InvalidEC2TagCombinationException"
+            }
+            UpdateDeploymentGroupError::InvalidEC2Tag(_) => "InvalidEC2TagException",
+            UpdateDeploymentGroupError::InvalidECSService(_) => "InvalidECSServiceException",
+            UpdateDeploymentGroupError::InvalidInput(_) => "InvalidInputException",
+            UpdateDeploymentGroupError::InvalidLoadBalancerInfo(_) => {
+                "InvalidLoadBalancerInfoException"
+            }
+            UpdateDeploymentGroupError::InvalidOnPremisesTagCombination(_) => {
+                "InvalidOnPremisesTagCombinationException"
+            }
+            UpdateDeploymentGroupError::InvalidRole(_) => "InvalidRoleException",
+            UpdateDeploymentGroupError::InvalidTag(_) => "InvalidTagException",
+            UpdateDeploymentGroupError::InvalidTargetGroupPair(_) => {
+                "InvalidTargetGroupPairException"
+            }
+            UpdateDeploymentGroupError::InvalidTrafficRoutingConfiguration(_) => {
+                "InvalidTrafficRoutingConfigurationException"
+            }
+            UpdateDeploymentGroupError::InvalidTriggerConfig(_) => "InvalidTriggerConfigException",
+            UpdateDeploymentGroupError::LifecycleHookLimitExceeded(_) => {
+                "LifecycleHookLimitExceededException"
+            }
+            UpdateDeploymentGroupError::TagSetListLimitExceeded(_) => {
+                "TagSetListLimitExceededException"
+            }
+            UpdateDeploymentGroupError::Throttling(_) => "ThrottlingException",
+            UpdateDeploymentGroupError::TriggerTargetsLimitExceeded(_) => {
+                "TriggerTargetsLimitExceededException"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the CodeDeploy API. CodeDeploy clients implement this trait.
 #[async_trait]
 pub trait CodeDeploy {
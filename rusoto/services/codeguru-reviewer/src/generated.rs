@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -29,6 +29,7 @@ use serde_json;
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AssociateRepositoryRequest {
     /// <p>Amazon CodeGuru Reviewer uses this value to prevent the accidental creation of duplicate repository associations if there are failures and retries. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -202,6 +203,7 @@ pub struct CommitDiffSourceCodeType {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateCodeReviewRequest {
     /// <p> Amazon CodeGuru Reviewer uses this value to prevent the accidental creation of duplicate code reviews if there are failures and retries. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -818,6 +820,16 @@ impl fmt::Display for AssociateRepositoryError {
     }
 }
 impl Error for AssociateRepositoryError {}
+impl ServiceErrorCode for AssociateRepositoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateRepositoryError::AccessDenied(_) => "AccessDeniedException",
+            AssociateRepositoryError::Conflict(_) => "ConflictException",
+            AssociateRepositoryError::InternalServer(_) => "InternalServerException",
+            AssociateRepositoryError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateCodeReview
 #[derive(Debug, PartialEq)]
 pub enum CreateCodeReviewError {
@@ -872,6 +884,17 @@ impl fmt::Display for CreateCodeReviewError {
     }
 }
 impl Error for CreateCodeReviewError {}
+impl ServiceErrorCode for CreateCodeReviewError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCodeReviewError::AccessDenied(_) => "AccessDeniedException",
+            CreateCodeReviewError::Conflict(_) => "ConflictException",
+            CreateCodeReviewError::InternalServer(_) => "InternalServerException",
+            CreateCodeReviewError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateCodeReviewError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeCodeReview
 #[derive(Debug, PartialEq)]
 pub enum DescribeCodeReviewError {
@@ -920,6 +943,16 @@ impl fmt::Display for DescribeCodeReviewError {
     }
 }
 impl Error for DescribeCodeReviewError {}
+impl ServiceErrorCode for DescribeCodeReviewError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCodeReviewError::AccessDenied(_) => "AccessDeniedException",
+            DescribeCodeReviewError::InternalServer(_) => "InternalServerException",
+            DescribeCodeReviewError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeCodeReviewError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeRecommendationFeedback
 #[derive(Debug, PartialEq)]
 pub enum DescribeRecommendationFeedbackError {
@@ -982,6 +1015,16 @@ impl fmt::Display for DescribeRecommendationFeedbackError {
     }
 }
 impl Error for DescribeRecommendationFeedbackError {}
+impl ServiceErrorCode for DescribeRecommendationFeedbackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRecommendationFeedbackError::AccessDenied(_) => "AccessDeniedException",
+            DescribeRecommendationFeedbackError::InternalServer(_) => "InternalServerException",
+            DescribeRecommendationFeedbackError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeRecommendationFeedbackError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeRepositoryAssociation
 #[derive(Debug, PartialEq)]
 pub enum DescribeRepositoryAssociationError {
@@ -1040,6 +1083,16 @@ impl fmt::Display for DescribeRepositoryAssociationError {
     }
 }
 impl Error for DescribeRepositoryAssociationError {}
+impl ServiceErrorCode for DescribeRepositoryAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRepositoryAssociationError::AccessDenied(_) => "AccessDeniedException",
+            DescribeRepositoryAssociationError::InternalServer(_) => "InternalServerException",
+            DescribeRepositoryAssociationError::NotFound(_) => "NotFoundException",
+            DescribeRepositoryAssociationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DisassociateRepository
 #[derive(Debug, PartialEq)]
 pub enum DisassociateRepositoryError {
@@ -1096,6 +1149,17 @@ impl fmt::Display for DisassociateRepositoryError {
     }
 }
 impl Error for DisassociateRepositoryError {}
+impl ServiceErrorCode for DisassociateRepositoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateRepositoryError::AccessDenied(_) => "AccessDeniedException",
+            DisassociateRepositoryError::Conflict(_) => "ConflictException",
+            DisassociateRepositoryError::InternalServer(_) => "InternalServerException",
+            DisassociateRepositoryError::NotFound(_) => "NotFoundException",
+            DisassociateRepositoryError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListCodeReviews
 #[derive(Debug, PartialEq)]
 pub enum ListCodeReviewsError {
@@ -1138,6 +1202,15 @@ impl fmt::Display for ListCodeReviewsError {
     }
 }
 impl Error for ListCodeReviewsError {}
+impl ServiceErrorCode for ListCodeReviewsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCodeReviewsError::AccessDenied(_) => "AccessDeniedException",
+            ListCodeReviewsError::InternalServer(_) => "InternalServerException",
+            ListCodeReviewsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListRecommendationFeedback
 #[derive(Debug, PartialEq)]
 pub enum ListRecommendationFeedbackError {
@@ -1196,6 +1269,16 @@ impl fmt::Display for ListRecommendationFeedbackError {
     }
 }
 impl Error for ListRecommendationFeedbackError {}
+impl ServiceErrorCode for ListRecommendationFeedbackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRecommendationFeedbackError::AccessDenied(_) => "AccessDeniedException",
+            ListRecommendationFeedbackError::InternalServer(_) => "InternalServerException",
+            ListRecommendationFeedbackError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListRecommendationFeedbackError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListRecommendations
 #[derive(Debug, PartialEq)]
 pub enum ListRecommendationsError {
@@ -1246,6 +1329,16 @@ impl fmt::Display for ListRecommendationsError {
     }
 }
 impl Error for ListRecommendationsError {}
+impl ServiceErrorCode for ListRecommendationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRecommendationsError::AccessDenied(_) => "AccessDeniedException",
+            ListRecommendationsError::InternalServer(_) => "InternalServerException",
+            ListRecommendationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListRecommendationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListRepositoryAssociations
 #[derive(Debug, PartialEq)]
 pub enum ListRepositoryAssociationsError {
@@ -1288,6 +1381,14 @@ impl fmt::Display for ListRepositoryAssociationsError {
     }
 }
 impl Error for ListRepositoryAssociationsError {}
+impl ServiceErrorCode for ListRepositoryAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRepositoryAssociationsError::InternalServer(_) => "InternalServerException",
+            ListRepositoryAssociationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1326,6 +1427,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalServer(_) => "InternalServerException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutRecommendationFeedback
 #[derive(Debug, PartialEq)]
 pub enum PutRecommendationFeedbackError {
@@ -1382,6 +1491,16 @@ impl fmt::Display for PutRecommendationFeedbackError {
     }
 }
 impl Error for PutRecommendationFeedbackError {}
+impl ServiceErrorCode for PutRecommendationFeedbackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRecommendationFeedbackError::AccessDenied(_) => "AccessDeniedException",
+            PutRecommendationFeedbackError::InternalServer(_) => "InternalServerException",
+            PutRecommendationFeedbackError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutRecommendationFeedbackError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1418,6 +1537,14 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalServer(_) => "InternalServerException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1454,6 +1581,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalServer(_) => "InternalServerException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the CodeGuruReviewer API. CodeGuruReviewer clients implement this trait.
 #[async_trait]
 pub trait CodeGuruReviewer {
@@ -1593,6 +1728,10 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
         &self,
         input: AssociateRepositoryRequest,
     ) -> Result<AssociateRepositoryResponse, RusotoError<AssociateRepositoryError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = "/associations";
 
         let mut request =
@@ -1625,6 +1764,10 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
         &self,
         input: CreateCodeReviewRequest,
     ) -> Result<CreateCodeReviewResponse, RusotoError<CreateCodeReviewError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = "/codereviews";
 
         let mut request =
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -223,6 +223,7 @@ pub struct CreateProfilingGroupRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub agent_orchestration_config: Option<AgentOrchestrationConfig>,
     /// <p> Amazon CodeGuru Profiler uses this universally unique identifier (UUID) to prevent the accidental creation of duplicate profiling groups if there are failures and retries. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     pub client_token: String,
     /// <p> The compute platform of the profiling group. Use <code>AWSLambda</code> if your application runs on AWS Lambda. Use <code>Default</code> if your application runs on a compute platform that is not AWS Lambda, such an Amazon EC2 instance, an on-premises server, or a different platform. If not specified, <code>Default</code> is used. </p>
@@ -708,6 +709,7 @@ pub struct PostAgentProfileRequest {
     #[serde(rename = "contentType")]
     pub content_type: String,
     /// <p> Amazon CodeGuru Profiler uses this universally unique identifier (UUID) to prevent the accidental submission of duplicate profiling data if there are failures and retries. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "profileToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub profile_token: Option<String>,
@@ -1044,6 +1046,19 @@ impl fmt::Display for AddNotificationChannelsError {
     }
 }
 impl Error for AddNotificationChannelsError {}
+impl ServiceErrorCode for AddNotificationChannelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddNotificationChannelsError::Conflict(_) => "ConflictException",
+            AddNotificationChannelsError::InternalServer(_) => "InternalServerException",
+            AddNotificationChannelsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AddNotificationChannelsError::ServiceQuotaExceeded(_) => {
+                "ServiceQuotaExceededException"
+            }
+            AddNotificationChannelsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by BatchGetFrameMetricData
 #[derive(Debug, PartialEq)]
 pub enum BatchGetFrameMetricDataError {
@@ -1090,6 +1105,15 @@ impl fmt::Display for BatchGetFrameMetricDataError {
     }
 }
 impl Error for BatchGetFrameMetricDataError {}
+impl ServiceErrorCode for BatchGetFrameMetricDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetFrameMetricDataError::InternalServer(_) => "InternalServerException",
+            BatchGetFrameMetricDataError::ResourceNotFound(_) => "ResourceNotFoundException",
+            BatchGetFrameMetricDataError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ConfigureAgent
 #[derive(Debug, PartialEq)]
 pub enum ConfigureAgentError {
@@ -1132,6 +1156,15 @@ impl fmt::Display for ConfigureAgentError {
     }
 }
 impl Error for ConfigureAgentError {}
+impl ServiceErrorCode for ConfigureAgentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ConfigureAgentError::InternalServer(_) => "InternalServerException",
+            ConfigureAgentError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ConfigureAgentError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateProfilingGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateProfilingGroupError {
@@ -1182,6 +1215,16 @@ impl fmt::Display for CreateProfilingGroupError {
     }
 }
 impl Error for CreateProfilingGroupError {}
+impl ServiceErrorCode for CreateProfilingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProfilingGroupError::Conflict(_) => "ConflictException",
+            CreateProfilingGroupError::InternalServer(_) => "InternalServerException",
+            CreateProfilingGroupError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateProfilingGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteProfilingGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteProfilingGroupError {
@@ -1226,6 +1269,15 @@ impl fmt::Display for DeleteProfilingGroupError {
     }
 }
 impl Error for DeleteProfilingGroupError {}
+impl ServiceErrorCode for DeleteProfilingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProfilingGroupError::InternalServer(_) => "InternalServerException",
+            DeleteProfilingGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteProfilingGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeProfilingGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeProfilingGroupError {
@@ -1272,6 +1324,15 @@ impl fmt::Display for DescribeProfilingGroupError {
     }
 }
 impl Error for DescribeProfilingGroupError {}
+impl ServiceErrorCode for DescribeProfilingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProfilingGroupError::InternalServer(_) => "InternalServerException",
+            DescribeProfilingGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeProfilingGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetFindingsReportAccountSummary
 #[derive(Debug, PartialEq)]
 pub enum GetFindingsReportAccountSummaryError {
@@ -1316,6 +1377,14 @@ impl fmt::Display for GetFindingsReportAccountSummaryError {
     }
 }
 impl Error for GetFindingsReportAccountSummaryError {}
+impl ServiceErrorCode for GetFindingsReportAccountSummaryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFindingsReportAccountSummaryError::InternalServer(_) => "InternalServerException",
+            GetFindingsReportAccountSummaryError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetNotificationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetNotificationConfigurationError {
@@ -1368,6 +1437,15 @@ impl fmt::Display for GetNotificationConfigurationError {
     }
 }
 impl Error for GetNotificationConfigurationError {}
+impl ServiceErrorCode for GetNotificationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetNotificationConfigurationError::InternalServer(_) => "InternalServerException",
+            GetNotificationConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetNotificationConfigurationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetPolicyError {
@@ -1410,6 +1488,15 @@ impl fmt::Display for GetPolicyError {
     }
 }
 impl Error for GetPolicyError {}
+impl ServiceErrorCode for GetPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPolicyError::InternalServer(_) => "InternalServerException",
+            GetPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetPolicyError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetProfile
 #[derive(Debug, PartialEq)]
 pub enum GetProfileError {
@@ -1452,6 +1539,15 @@ impl fmt::Display for GetProfileError {
     }
 }
 impl Error for GetProfileError {}
+impl ServiceErrorCode for GetProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetProfileError::InternalServer(_) => "InternalServerException",
+            GetProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetProfileError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetRecommendations
 #[derive(Debug, PartialEq)]
 pub enum GetRecommendationsError {
@@ -1494,6 +1590,15 @@ impl fmt::Display for GetRecommendationsError {
     }
 }
 impl Error for GetRecommendationsError {}
+impl ServiceErrorCode for GetRecommendationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRecommendationsError::InternalServer(_) => "InternalServerException",
+            GetRecommendationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetRecommendationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListFindingsReports
 #[derive(Debug, PartialEq)]
 pub enum ListFindingsReportsError {
@@ -1538,6 +1643,15 @@ impl fmt::Display for ListFindingsReportsError {
     }
 }
 impl Error for ListFindingsReportsError {}
+impl ServiceErrorCode for ListFindingsReportsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFindingsReportsError::InternalServer(_) => "InternalServerException",
+            ListFindingsReportsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListFindingsReportsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListProfileTimes
 #[derive(Debug, PartialEq)]
 pub enum ListProfileTimesError {
@@ -1580,6 +1694,15 @@ impl fmt::Display for ListProfileTimesError {
     }
 }
 impl Error for ListProfileTimesError {}
+impl ServiceErrorCode for ListProfileTimesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProfileTimesError::InternalServer(_) => "InternalServerException",
+            ListProfileTimesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListProfileTimesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListProfilingGroups
 #[derive(Debug, PartialEq)]
 pub enum ListProfilingGroupsError {
@@ -1616,6 +1739,14 @@ impl fmt::Display for ListProfilingGroupsError {
     }
 }
 impl Error for ListProfilingGroupsError {}
+impl ServiceErrorCode for ListProfilingGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProfilingGroupsError::InternalServer(_) => "InternalServerException",
+            ListProfilingGroupsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1654,6 +1785,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalServer(_) => "InternalServerException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PostAgentProfile
 #[derive(Debug, PartialEq)]
 pub enum PostAgentProfileError {
@@ -1696,6 +1835,15 @@ impl fmt::Display for PostAgentProfileError {
     }
 }
 impl Error for PostAgentProfileError {}
+impl ServiceErrorCode for PostAgentProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PostAgentProfileError::InternalServer(_) => "InternalServerException",
+            PostAgentProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PostAgentProfileError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by PutPermission
 #[derive(Debug, PartialEq)]
 pub enum PutPermissionError {
@@ -1744,6 +1892,16 @@ impl fmt::Display for PutPermissionError {
     }
 }
 impl Error for PutPermissionError {}
+impl ServiceErrorCode for PutPermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutPermissionError::Conflict(_) => "ConflictException",
+            PutPermissionError::InternalServer(_) => "InternalServerException",
+            PutPermissionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutPermissionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by RemoveNotificationChannel
 #[derive(Debug, PartialEq)]
 pub enum RemoveNotificationChannelError {
@@ -1792,6 +1950,15 @@ impl fmt::Display for RemoveNotificationChannelError {
     }
 }
 impl Error for RemoveNotificationChannelError {}
+impl ServiceErrorCode for RemoveNotificationChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveNotificationChannelError::InternalServer(_) => "InternalServerException",
+            RemoveNotificationChannelError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RemoveNotificationChannelError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by RemovePermission
 #[derive(Debug, PartialEq)]
 pub enum RemovePermissionError {
@@ -1840,6 +2007,16 @@ impl fmt::Display for RemovePermissionError {
     }
 }
 impl Error for RemovePermissionError {}
+impl ServiceErrorCode for RemovePermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemovePermissionError::Conflict(_) => "ConflictException",
+            RemovePermissionError::InternalServer(_) => "InternalServerException",
+            RemovePermissionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RemovePermissionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by SubmitFeedback
 #[derive(Debug, PartialEq)]
 pub enum SubmitFeedbackError {
@@ -1882,6 +2059,15 @@ impl fmt::Display for SubmitFeedbackError {
     }
 }
 impl Error for SubmitFeedbackError {}
+impl ServiceErrorCode for SubmitFeedbackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SubmitFeedbackError::InternalServer(_) => "InternalServerException",
+            SubmitFeedbackError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SubmitFeedbackError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1918,6 +2104,14 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalServer(_) => "InternalServerException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1954,6 +2148,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalServer(_) => "InternalServerException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateProfilingGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateProfilingGroupError {
@@ -2004,6 +2206,16 @@ impl fmt::Display for UpdateProfilingGroupError {
     }
 }
 impl Error for UpdateProfilingGroupError {}
+impl ServiceErrorCode for UpdateProfilingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateProfilingGroupError::Conflict(_) => "ConflictException",
+            UpdateProfilingGroupError::InternalServer(_) => "InternalServerException",
+            UpdateProfilingGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateProfilingGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon CodeGuru Profiler API. Amazon CodeGuru Profiler clients implement this trait.
 #[async_trait]
 pub trait CodeGuruProfiler {
@@ -2314,6 +2526,12 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
         &self,
         input: CreateProfilingGroupRequest,
     ) -> Result<CreateProfilingGroupResponse, RusotoError<CreateProfilingGroupError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/profilingGroups";
 
         let mut request =
@@ -2781,6 +2999,10 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
         &self,
         input: PostAgentProfileRequest,
     ) -> Result<PostAgentProfileResponse, RusotoError<PostAgentProfileError>> {
+        let mut input = input;
+        if input.profile_token.is_none() {
+            input.profile_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/profilingGroups/{profiling_group_name}/agentProfile",
             profiling_group_name = input.profiling_group_name
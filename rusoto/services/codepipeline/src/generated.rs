@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1788,6 +1788,7 @@ pub struct StageState {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartPipelineExecutionInput {
     /// <p>The system-generated unique ID used to identify a unique execution request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -2080,6 +2081,14 @@ impl fmt::Display for AcknowledgeJobError {
     }
 }
 impl Error for AcknowledgeJobError {}
+impl ServiceErrorCode for AcknowledgeJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcknowledgeJobError::InvalidNonce(_) => "InvalidNonceException",
+            AcknowledgeJobError::JobNotFound(_) => "JobNotFoundException",
+        }
+    }
+}
 /// Errors returned by AcknowledgeThirdPartyJob
 #[derive(Debug, PartialEq)]
 pub enum AcknowledgeThirdPartyJobError {
@@ -2128,6 +2137,15 @@ impl fmt::Display for AcknowledgeThirdPartyJobError {
     }
 }
 impl Error for AcknowledgeThirdPartyJobError {}
+impl ServiceErrorCode for AcknowledgeThirdPartyJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcknowledgeThirdPartyJobError::InvalidClientToken(_) => "InvalidClientTokenException",
+            AcknowledgeThirdPartyJobError::InvalidNonce(_) => "InvalidNonceException",
+            AcknowledgeThirdPartyJobError::JobNotFound(_) => "JobNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateCustomActionType
 #[derive(Debug, PartialEq)]
 pub enum CreateCustomActionTypeError {
@@ -2182,6 +2200,18 @@ impl fmt::Display for CreateCustomActionTypeError {
     }
 }
 impl Error for CreateCustomActionTypeError {}
+impl ServiceErrorCode for CreateCustomActionTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCustomActionTypeError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            CreateCustomActionTypeError::InvalidTags(_) => "InvalidTagsException",
+            CreateCustomActionTypeError::LimitExceeded(_) => "LimitExceededException",
+            CreateCustomActionTypeError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by CreatePipeline
 #[derive(Debug, PartialEq)]
 pub enum CreatePipelineError {
@@ -2268,6 +2298,23 @@ impl fmt::Display for CreatePipelineError {
     }
 }
 impl Error for CreatePipelineError {}
+impl ServiceErrorCode for CreatePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePipelineError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreatePipelineError::InvalidActionDeclaration(_) => "InvalidActionDeclarationException",
+            CreatePipelineError::InvalidBlockerDeclaration(_) => {
+                "InvalidBlockerDeclarationException"
+            }
+            CreatePipelineError::InvalidStageDeclaration(_) => "InvalidStageDeclarationException",
+            CreatePipelineError::InvalidStructure(_) => "InvalidStructureException",
+            CreatePipelineError::InvalidTags(_) => "InvalidTagsException",
+            CreatePipelineError::LimitExceeded(_) => "LimitExceededException",
+            CreatePipelineError::PipelineNameInUse(_) => "PipelineNameInUseException",
+            CreatePipelineError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by DeleteCustomActionType
 #[derive(Debug, PartialEq)]
 pub enum DeleteCustomActionTypeError {
@@ -2302,6 +2349,15 @@ impl fmt::Display for DeleteCustomActionTypeError {
     }
 }
 impl Error for DeleteCustomActionTypeError {}
+impl ServiceErrorCode for DeleteCustomActionTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCustomActionTypeError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+        }
+    }
+}
 /// Errors returned by DeletePipeline
 #[derive(Debug, PartialEq)]
 pub enum DeletePipelineError {
@@ -2334,6 +2390,13 @@ impl fmt::Display for DeletePipelineError {
     }
 }
 impl Error for DeletePipelineError {}
+impl ServiceErrorCode for DeletePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePipelineError::ConcurrentModification(_) => "ConcurrentModificationException",
+        }
+    }
+}
 /// Errors returned by DeleteWebhook
 #[derive(Debug, PartialEq)]
 pub enum DeleteWebhookError {
@@ -2366,6 +2429,13 @@ impl fmt::Display for DeleteWebhookError {
     }
 }
 impl Error for DeleteWebhookError {}
+impl ServiceErrorCode for DeleteWebhookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteWebhookError::ConcurrentModification(_) => "ConcurrentModificationException",
+        }
+    }
+}
 /// Errors returned by DeregisterWebhookWithThirdParty
 #[derive(Debug, PartialEq)]
 pub enum DeregisterWebhookWithThirdPartyError {
@@ -2402,6 +2472,13 @@ impl fmt::Display for DeregisterWebhookWithThirdPartyError {
     }
 }
 impl Error for DeregisterWebhookWithThirdPartyError {}
+impl ServiceErrorCode for DeregisterWebhookWithThirdPartyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterWebhookWithThirdPartyError::WebhookNotFound(_) => "WebhookNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisableStageTransition
 #[derive(Debug, PartialEq)]
 pub enum DisableStageTransitionError {
@@ -2442,6 +2519,14 @@ impl fmt::Display for DisableStageTransitionError {
     }
 }
 impl Error for DisableStageTransitionError {}
+impl ServiceErrorCode for DisableStageTransitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableStageTransitionError::PipelineNotFound(_) => "PipelineNotFoundException",
+            DisableStageTransitionError::StageNotFound(_) => "StageNotFoundException",
+        }
+    }
+}
 /// Errors returned by EnableStageTransition
 #[derive(Debug, PartialEq)]
 pub enum EnableStageTransitionError {
@@ -2480,6 +2565,14 @@ impl fmt::Display for EnableStageTransitionError {
     }
 }
 impl Error for EnableStageTransitionError {}
+impl ServiceErrorCode for EnableStageTransitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableStageTransitionError::PipelineNotFound(_) => "PipelineNotFoundException",
+            EnableStageTransitionError::StageNotFound(_) => "StageNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetJobDetails
 #[derive(Debug, PartialEq)]
 pub enum GetJobDetailsError {
@@ -2510,6 +2603,13 @@ impl fmt::Display for GetJobDetailsError {
     }
 }
 impl Error for GetJobDetailsError {}
+impl ServiceErrorCode for GetJobDetailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetJobDetailsError::JobNotFound(_) => "JobNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetPipeline
 #[derive(Debug, PartialEq)]
 pub enum GetPipelineError {
@@ -2546,6 +2646,14 @@ impl fmt::Display for GetPipelineError {
     }
 }
 impl Error for GetPipelineError {}
+impl ServiceErrorCode for GetPipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPipelineError::PipelineNotFound(_) => "PipelineNotFoundException",
+            GetPipelineError::PipelineVersionNotFound(_) => "PipelineVersionNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetPipelineExecution
 #[derive(Debug, PartialEq)]
 pub enum GetPipelineExecutionError {
@@ -2588,6 +2696,16 @@ impl fmt::Display for GetPipelineExecutionError {
     }
 }
 impl Error for GetPipelineExecutionError {}
+impl ServiceErrorCode for GetPipelineExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPipelineExecutionError::PipelineExecutionNotFound(_) => {
+                "PipelineExecutionNotFoundException"
+            }
+            GetPipelineExecutionError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetPipelineState
 #[derive(Debug, PartialEq)]
 pub enum GetPipelineStateError {
@@ -2618,6 +2736,13 @@ impl fmt::Display for GetPipelineStateError {
     }
 }
 impl Error for GetPipelineStateError {}
+impl ServiceErrorCode for GetPipelineStateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPipelineStateError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetThirdPartyJobDetails
 #[derive(Debug, PartialEq)]
 pub enum GetThirdPartyJobDetailsError {
@@ -2662,6 +2787,15 @@ impl fmt::Display for GetThirdPartyJobDetailsError {
     }
 }
 impl Error for GetThirdPartyJobDetailsError {}
+impl ServiceErrorCode for GetThirdPartyJobDetailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetThirdPartyJobDetailsError::InvalidClientToken(_) => "InvalidClientTokenException",
+            GetThirdPartyJobDetailsError::InvalidJob(_) => "InvalidJobException",
+            GetThirdPartyJobDetailsError::JobNotFound(_) => "JobNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListActionExecutions
 #[derive(Debug, PartialEq)]
 pub enum ListActionExecutionsError {
@@ -2712,6 +2846,17 @@ impl fmt::Display for ListActionExecutionsError {
     }
 }
 impl Error for ListActionExecutionsError {}
+impl ServiceErrorCode for ListActionExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListActionExecutionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListActionExecutionsError::PipelineExecutionNotFound(_) => {
+                "PipelineExecutionNotFoundException"
+            }
+            ListActionExecutionsError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListActionTypes
 #[derive(Debug, PartialEq)]
 pub enum ListActionTypesError {
@@ -2742,6 +2887,13 @@ impl fmt::Display for ListActionTypesError {
     }
 }
 impl Error for ListActionTypesError {}
+impl ServiceErrorCode for ListActionTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListActionTypesError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListPipelineExecutions
 #[derive(Debug, PartialEq)]
 pub enum ListPipelineExecutionsError {
@@ -2782,6 +2934,14 @@ impl fmt::Display for ListPipelineExecutionsError {
     }
 }
 impl Error for ListPipelineExecutionsError {}
+impl ServiceErrorCode for ListPipelineExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPipelineExecutionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListPipelineExecutionsError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListPipelines
 #[derive(Debug, PartialEq)]
 pub enum ListPipelinesError {
@@ -2812,6 +2972,13 @@ impl fmt::Display for ListPipelinesError {
     }
 }
 impl Error for ListPipelinesError {}
+impl ServiceErrorCode for ListPipelinesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPipelinesError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2858,6 +3025,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InvalidArn(_) => "InvalidArnException",
+            ListTagsForResourceError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListWebhooks
 #[derive(Debug, PartialEq)]
 pub enum ListWebhooksError {
@@ -2888,6 +3064,13 @@ impl fmt::Display for ListWebhooksError {
     }
 }
 impl Error for ListWebhooksError {}
+impl ServiceErrorCode for ListWebhooksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListWebhooksError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by PollForJobs
 #[derive(Debug, PartialEq)]
 pub enum PollForJobsError {
@@ -2918,6 +3101,13 @@ impl fmt::Display for PollForJobsError {
     }
 }
 impl Error for PollForJobsError {}
+impl ServiceErrorCode for PollForJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PollForJobsError::ActionTypeNotFound(_) => "ActionTypeNotFoundException",
+        }
+    }
+}
 /// Errors returned by PollForThirdPartyJobs
 #[derive(Debug, PartialEq)]
 pub enum PollForThirdPartyJobsError {
@@ -2950,6 +3140,13 @@ impl fmt::Display for PollForThirdPartyJobsError {
     }
 }
 impl Error for PollForThirdPartyJobsError {}
+impl ServiceErrorCode for PollForThirdPartyJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PollForThirdPartyJobsError::ActionTypeNotFound(_) => "ActionTypeNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutActionRevision
 #[derive(Debug, PartialEq)]
 pub enum PutActionRevisionError {
@@ -2992,6 +3189,15 @@ impl fmt::Display for PutActionRevisionError {
     }
 }
 impl Error for PutActionRevisionError {}
+impl ServiceErrorCode for PutActionRevisionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutActionRevisionError::ActionNotFound(_) => "ActionNotFoundException",
+            PutActionRevisionError::PipelineNotFound(_) => "PipelineNotFoundException",
+            PutActionRevisionError::StageNotFound(_) => "StageNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutApprovalResult
 #[derive(Debug, PartialEq)]
 pub enum PutApprovalResultError {
@@ -3050,6 +3256,19 @@ impl fmt::Display for PutApprovalResultError {
     }
 }
 impl Error for PutApprovalResultError {}
+impl ServiceErrorCode for PutApprovalResultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutApprovalResultError::ActionNotFound(_) => "ActionNotFoundException",
+            PutApprovalResultError::ApprovalAlreadyCompleted(_) => {
+                "ApprovalAlreadyCompletedException"
+            }
+            PutApprovalResultError::InvalidApprovalToken(_) => "InvalidApprovalTokenException",
+            PutApprovalResultError::PipelineNotFound(_) => "PipelineNotFoundException",
+            PutApprovalResultError::StageNotFound(_) => "StageNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutJobFailureResult
 #[derive(Debug, PartialEq)]
 pub enum PutJobFailureResultError {
@@ -3086,6 +3305,14 @@ impl fmt::Display for PutJobFailureResultError {
     }
 }
 impl Error for PutJobFailureResultError {}
+impl ServiceErrorCode for PutJobFailureResultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutJobFailureResultError::InvalidJobState(_) => "InvalidJobStateException",
+            PutJobFailureResultError::JobNotFound(_) => "JobNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutJobSuccessResult
 #[derive(Debug, PartialEq)]
 pub enum PutJobSuccessResultError {
@@ -3132,6 +3359,17 @@ impl fmt::Display for PutJobSuccessResultError {
     }
 }
 impl Error for PutJobSuccessResultError {}
+impl ServiceErrorCode for PutJobSuccessResultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutJobSuccessResultError::InvalidJobState(_) => "InvalidJobStateException",
+            PutJobSuccessResultError::JobNotFound(_) => "JobNotFoundException",
+            PutJobSuccessResultError::OutputVariablesSizeExceeded(_) => {
+                "OutputVariablesSizeExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by PutThirdPartyJobFailureResult
 #[derive(Debug, PartialEq)]
 pub enum PutThirdPartyJobFailureResultError {
@@ -3186,6 +3424,17 @@ impl fmt::Display for PutThirdPartyJobFailureResultError {
     }
 }
 impl Error for PutThirdPartyJobFailureResultError {}
+impl ServiceErrorCode for PutThirdPartyJobFailureResultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutThirdPartyJobFailureResultError::InvalidClientToken(_) => {
+                "InvalidClientTokenException"
+            }
+            PutThirdPartyJobFailureResultError::InvalidJobState(_) => "InvalidJobStateException",
+            PutThirdPartyJobFailureResultError::JobNotFound(_) => "JobNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutThirdPartyJobSuccessResult
 #[derive(Debug, PartialEq)]
 pub enum PutThirdPartyJobSuccessResultError {
@@ -3240,6 +3489,17 @@ impl fmt::Display for PutThirdPartyJobSuccessResultError {
     }
 }
 impl Error for PutThirdPartyJobSuccessResultError {}
+impl ServiceErrorCode for PutThirdPartyJobSuccessResultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutThirdPartyJobSuccessResultError::InvalidClientToken(_) => {
+                "InvalidClientTokenException"
+            }
+            PutThirdPartyJobSuccessResultError::InvalidJobState(_) => "InvalidJobStateException",
+            PutThirdPartyJobSuccessResultError::JobNotFound(_) => "JobNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutWebhook
 #[derive(Debug, PartialEq)]
 pub enum PutWebhookError {
@@ -3312,6 +3572,23 @@ impl fmt::Display for PutWebhookError {
     }
 }
 impl Error for PutWebhookError {}
+impl ServiceErrorCode for PutWebhookError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutWebhookError::ConcurrentModification(_) => "ConcurrentModificationException",
+            PutWebhookError::InvalidTags(_) => "InvalidTagsException",
+            PutWebhookError::InvalidWebhookAuthenticationParameters(_) => {
+                "InvalidWebhookAuthenticationParametersException"
+            }
+            PutWebhookError::InvalidWebhookFilterPattern(_) => {
+                "InvalidWebhookFilterPatternException"
+            }
+            PutWebhookError::LimitExceeded(_) => "LimitExceededException",
+            PutWebhookError::PipelineNotFound(_) => "PipelineNotFoundException",
+            PutWebhookError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by RegisterWebhookWithThirdParty
 #[derive(Debug, PartialEq)]
 pub enum RegisterWebhookWithThirdPartyError {
@@ -3348,6 +3625,13 @@ impl fmt::Display for RegisterWebhookWithThirdPartyError {
     }
 }
 impl Error for RegisterWebhookWithThirdPartyError {}
+impl ServiceErrorCode for RegisterWebhookWithThirdPartyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterWebhookWithThirdPartyError::WebhookNotFound(_) => "WebhookNotFoundException",
+        }
+    }
+}
 /// Errors returned by RetryStageExecution
 #[derive(Debug, PartialEq)]
 pub enum RetryStageExecutionError {
@@ -3410,6 +3694,19 @@ impl fmt::Display for RetryStageExecutionError {
     }
 }
 impl Error for RetryStageExecutionError {}
+impl ServiceErrorCode for RetryStageExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RetryStageExecutionError::Conflict(_) => "ConflictException",
+            RetryStageExecutionError::NotLatestPipelineExecution(_) => {
+                "NotLatestPipelineExecutionException"
+            }
+            RetryStageExecutionError::PipelineNotFound(_) => "PipelineNotFoundException",
+            RetryStageExecutionError::StageNotFound(_) => "StageNotFoundException",
+            RetryStageExecutionError::StageNotRetryable(_) => "StageNotRetryableException",
+        }
+    }
+}
 /// Errors returned by StartPipelineExecution
 #[derive(Debug, PartialEq)]
 pub enum StartPipelineExecutionError {
@@ -3448,6 +3745,14 @@ impl fmt::Display for StartPipelineExecutionError {
     }
 }
 impl Error for StartPipelineExecutionError {}
+impl ServiceErrorCode for StartPipelineExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartPipelineExecutionError::Conflict(_) => "ConflictException",
+            StartPipelineExecutionError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopPipelineExecution
 #[derive(Debug, PartialEq)]
 pub enum StopPipelineExecutionError {
@@ -3504,6 +3809,20 @@ impl fmt::Display for StopPipelineExecutionError {
     }
 }
 impl Error for StopPipelineExecutionError {}
+impl ServiceErrorCode for StopPipelineExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopPipelineExecutionError::Conflict(_) => "ConflictException",
+            StopPipelineExecutionError::DuplicatedStopRequest(_) => {
+                "DuplicatedStopRequestException"
+            }
+            StopPipelineExecutionError::PipelineExecutionNotStoppable(_) => {
+                "PipelineExecutionNotStoppableException"
+            }
+            StopPipelineExecutionError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -3558,6 +3877,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            TagResourceError::InvalidArn(_) => "InvalidArnException",
+            TagResourceError::InvalidTags(_) => "InvalidTagsException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3608,6 +3938,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UntagResourceError::InvalidArn(_) => "InvalidArnException",
+            UntagResourceError::InvalidTags(_) => "InvalidTagsException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdatePipeline
 #[derive(Debug, PartialEq)]
 pub enum UpdatePipelineError {
@@ -3668,6 +4008,19 @@ impl fmt::Display for UpdatePipelineError {
     }
 }
 impl Error for UpdatePipelineError {}
+impl ServiceErrorCode for UpdatePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePipelineError::InvalidActionDeclaration(_) => "InvalidActionDeclarationException",
+            UpdatePipelineError::InvalidBlockerDeclaration(_) => {
+                "InvalidBlockerDeclarationException"
+            }
+            UpdatePipelineError::InvalidStageDeclaration(_) => "InvalidStageDeclarationException",
+            UpdatePipelineError::InvalidStructure(_) => "InvalidStructureException",
+            UpdatePipelineError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Trait representing the capabilities of the CodePipeline API. CodePipeline clients implement this trait.
 #[async_trait]
 pub trait CodePipeline {
@@ -4555,6 +4908,10 @@ impl CodePipeline for CodePipelineClient {
         &self,
         input: StartPipelineExecutionInput,
     ) -> Result<StartPipelineExecutionOutput, RusotoError<StartPipelineExecutionError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
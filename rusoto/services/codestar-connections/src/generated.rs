@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -452,6 +452,15 @@ impl fmt::Display for CreateConnectionError {
     }
 }
 impl Error for CreateConnectionError {}
+impl ServiceErrorCode for CreateConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConnectionError::LimitExceeded(_) => "LimitExceededException",
+            CreateConnectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateConnectionError::ResourceUnavailable(_) => "ResourceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CreateHost
 #[derive(Debug, PartialEq)]
 pub enum CreateHostError {
@@ -482,6 +491,13 @@ impl fmt::Display for CreateHostError {
     }
 }
 impl Error for CreateHostError {}
+impl ServiceErrorCode for CreateHostError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHostError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DeleteConnection
 #[derive(Debug, PartialEq)]
 pub enum DeleteConnectionError {
@@ -512,6 +528,13 @@ impl fmt::Display for DeleteConnectionError {
     }
 }
 impl Error for DeleteConnectionError {}
+impl ServiceErrorCode for DeleteConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConnectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteHost
 #[derive(Debug, PartialEq)]
 pub enum DeleteHostError {
@@ -548,6 +571,14 @@ impl fmt::Display for DeleteHostError {
     }
 }
 impl Error for DeleteHostError {}
+impl ServiceErrorCode for DeleteHostError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteHostError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteHostError::ResourceUnavailable(_) => "ResourceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetConnection
 #[derive(Debug, PartialEq)]
 pub enum GetConnectionError {
@@ -584,6 +615,14 @@ impl fmt::Display for GetConnectionError {
     }
 }
 impl Error for GetConnectionError {}
+impl ServiceErrorCode for GetConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConnectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetConnectionError::ResourceUnavailable(_) => "ResourceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetHost
 #[derive(Debug, PartialEq)]
 pub enum GetHostError {
@@ -620,6 +659,14 @@ impl fmt::Display for GetHostError {
     }
 }
 impl Error for GetHostError {}
+impl ServiceErrorCode for GetHostError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetHostError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetHostError::ResourceUnavailable(_) => "ResourceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListConnections
 #[derive(Debug, PartialEq)]
 pub enum ListConnectionsError {}
@@ -642,6 +689,11 @@ impl fmt::Display for ListConnectionsError {
     }
 }
 impl Error for ListConnectionsError {}
+impl ServiceErrorCode for ListConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListHosts
 #[derive(Debug, PartialEq)]
 pub enum ListHostsError {}
@@ -664,6 +716,11 @@ impl fmt::Display for ListHostsError {
     }
 }
 impl Error for ListHostsError {}
+impl ServiceErrorCode for ListHostsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -696,6 +753,13 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -732,6 +796,14 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -762,6 +834,13 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateHost
 #[derive(Debug, PartialEq)]
 pub enum UpdateHostError {
@@ -810,6 +889,16 @@ impl fmt::Display for UpdateHostError {
     }
 }
 impl Error for UpdateHostError {}
+impl ServiceErrorCode for UpdateHostError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateHostError::Conflict(_) => "ConflictException",
+            UpdateHostError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateHostError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            UpdateHostError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS CodeStar connections API. AWS CodeStar connections clients implement this trait.
 #[async_trait]
 pub trait CodeStarConnections {
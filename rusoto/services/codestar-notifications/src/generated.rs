@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -28,6 +28,7 @@ use serde_json;
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateNotificationRuleRequest {
     /// <p><p>A unique, client-generated idempotency token that, when provided in a request, ensures the request cannot be repeated with a changed parameter. If a request with the same parameters is received and a token is included, the request returns information about the initial request that used that token.</p> <note> <p>The AWS SDKs prepopulate client request tokens. If you are using an AWS SDK, an idempotency token is created for you.</p> </note></p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -538,6 +539,21 @@ impl fmt::Display for CreateNotificationRuleError {
     }
 }
 impl Error for CreateNotificationRuleError {}
+impl ServiceErrorCode for CreateNotificationRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateNotificationRuleError::AccessDenied(_) => "AccessDeniedException",
+            CreateNotificationRuleError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            CreateNotificationRuleError::Configuration(_) => "ConfigurationException",
+            CreateNotificationRuleError::LimitExceeded(_) => "LimitExceededException",
+            CreateNotificationRuleError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteNotificationRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteNotificationRuleError {
@@ -580,6 +596,16 @@ impl fmt::Display for DeleteNotificationRuleError {
     }
 }
 impl Error for DeleteNotificationRuleError {}
+impl ServiceErrorCode for DeleteNotificationRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteNotificationRuleError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteNotificationRuleError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DeleteTarget
 #[derive(Debug, PartialEq)]
 pub enum DeleteTargetError {}
@@ -602,6 +628,11 @@ impl fmt::Display for DeleteTargetError {
     }
 }
 impl Error for DeleteTargetError {}
+impl ServiceErrorCode for DeleteTargetError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeNotificationRule
 #[derive(Debug, PartialEq)]
 pub enum DescribeNotificationRuleError {
@@ -634,6 +665,13 @@ impl fmt::Display for DescribeNotificationRuleError {
     }
 }
 impl Error for DescribeNotificationRuleError {}
+impl ServiceErrorCode for DescribeNotificationRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeNotificationRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListEventTypes
 #[derive(Debug, PartialEq)]
 pub enum ListEventTypesError {
@@ -664,6 +702,13 @@ impl fmt::Display for ListEventTypesError {
     }
 }
 impl Error for ListEventTypesError {}
+impl ServiceErrorCode for ListEventTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEventTypesError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListNotificationRules
 #[derive(Debug, PartialEq)]
 pub enum ListNotificationRulesError {
@@ -696,6 +741,13 @@ impl fmt::Display for ListNotificationRulesError {
     }
 }
 impl Error for ListNotificationRulesError {}
+impl ServiceErrorCode for ListNotificationRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListNotificationRulesError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -728,6 +780,13 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTargets
 #[derive(Debug, PartialEq)]
 pub enum ListTargetsError {
@@ -758,6 +817,13 @@ impl fmt::Display for ListTargetsError {
     }
 }
 impl Error for ListTargetsError {}
+impl ServiceErrorCode for ListTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTargetsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by Subscribe
 #[derive(Debug, PartialEq)]
 pub enum SubscribeError {
@@ -788,6 +854,13 @@ impl fmt::Display for SubscribeError {
     }
 }
 impl Error for SubscribeError {}
+impl ServiceErrorCode for SubscribeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SubscribeError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -824,6 +897,14 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by Unsubscribe
 #[derive(Debug, PartialEq)]
 pub enum UnsubscribeError {}
@@ -846,6 +927,11 @@ impl fmt::Display for UnsubscribeError {
     }
 }
 impl Error for UnsubscribeError {}
+impl ServiceErrorCode for UnsubscribeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -884,6 +970,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateNotificationRule
 #[derive(Debug, PartialEq)]
 pub enum UpdateNotificationRuleError {
@@ -916,6 +1010,13 @@ impl fmt::Display for UpdateNotificationRuleError {
     }
 }
 impl Error for UpdateNotificationRuleError {}
+impl ServiceErrorCode for UpdateNotificationRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateNotificationRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS CodeStar Notifications API. AWS CodeStar Notifications clients implement this trait.
 #[async_trait]
 pub trait CodeStarNotifications {
@@ -1043,6 +1144,10 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
         &self,
         input: CreateNotificationRuleRequest,
     ) -> Result<CreateNotificationRuleResult, RusotoError<CreateNotificationRuleError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = "/createNotificationRule";
 
         let mut request =
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -851,6 +851,22 @@ impl fmt::Display for AssociateTeamMemberError {
     }
 }
 impl Error for AssociateTeamMemberError {}
+impl ServiceErrorCode for AssociateTeamMemberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateTeamMemberError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AssociateTeamMemberError::InvalidServiceRole(_) => "InvalidServiceRoleException",
+            AssociateTeamMemberError::LimitExceeded(_) => "LimitExceededException",
+            AssociateTeamMemberError::ProjectConfiguration(_) => "ProjectConfigurationException",
+            AssociateTeamMemberError::ProjectNotFound(_) => "ProjectNotFoundException",
+            AssociateTeamMemberError::TeamMemberAlreadyAssociated(_) => {
+                "TeamMemberAlreadyAssociatedException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateProject
 #[derive(Debug, PartialEq)]
 pub enum CreateProjectError {
@@ -913,6 +929,18 @@ impl fmt::Display for CreateProjectError {
     }
 }
 impl Error for CreateProjectError {}
+impl ServiceErrorCode for CreateProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProjectError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateProjectError::InvalidServiceRole(_) => "InvalidServiceRoleException",
+            CreateProjectError::LimitExceeded(_) => "LimitExceededException",
+            CreateProjectError::ProjectAlreadyExists(_) => "ProjectAlreadyExistsException",
+            CreateProjectError::ProjectConfiguration(_) => "ProjectConfigurationException",
+            CreateProjectError::ProjectCreationFailed(_) => "ProjectCreationFailedException",
+        }
+    }
+}
 /// Errors returned by CreateUserProfile
 #[derive(Debug, PartialEq)]
 pub enum CreateUserProfileError {
@@ -945,6 +973,15 @@ impl fmt::Display for CreateUserProfileError {
     }
 }
 impl Error for CreateUserProfileError {}
+impl ServiceErrorCode for CreateUserProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserProfileError::UserProfileAlreadyExists(_) => {
+                "UserProfileAlreadyExistsException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteProject
 #[derive(Debug, PartialEq)]
 pub enum DeleteProjectError {
@@ -983,6 +1020,14 @@ impl fmt::Display for DeleteProjectError {
     }
 }
 impl Error for DeleteProjectError {}
+impl ServiceErrorCode for DeleteProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProjectError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteProjectError::InvalidServiceRole(_) => "InvalidServiceRoleException",
+        }
+    }
+}
 /// Errors returned by DeleteUserProfile
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserProfileError {}
@@ -1005,6 +1050,11 @@ impl fmt::Display for DeleteUserProfileError {
     }
 }
 impl Error for DeleteUserProfileError {}
+impl ServiceErrorCode for DeleteUserProfileError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeProject
 #[derive(Debug, PartialEq)]
 pub enum DescribeProjectError {
@@ -1057,6 +1107,16 @@ impl fmt::Display for DescribeProjectError {
     }
 }
 impl Error for DescribeProjectError {}
+impl ServiceErrorCode for DescribeProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProjectError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DescribeProjectError::InvalidServiceRole(_) => "InvalidServiceRoleException",
+            DescribeProjectError::ProjectConfiguration(_) => "ProjectConfigurationException",
+            DescribeProjectError::ProjectNotFound(_) => "ProjectNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeUserProfile
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserProfileError {
@@ -1089,6 +1149,13 @@ impl fmt::Display for DescribeUserProfileError {
     }
 }
 impl Error for DescribeUserProfileError {}
+impl ServiceErrorCode for DescribeUserProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserProfileError::UserProfileNotFound(_) => "UserProfileNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateTeamMember
 #[derive(Debug, PartialEq)]
 pub enum DisassociateTeamMemberError {
@@ -1139,6 +1206,17 @@ impl fmt::Display for DisassociateTeamMemberError {
     }
 }
 impl Error for DisassociateTeamMemberError {}
+impl ServiceErrorCode for DisassociateTeamMemberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateTeamMemberError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DisassociateTeamMemberError::InvalidServiceRole(_) => "InvalidServiceRoleException",
+            DisassociateTeamMemberError::ProjectNotFound(_) => "ProjectNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListProjects
 #[derive(Debug, PartialEq)]
 pub enum ListProjectsError {
@@ -1169,6 +1247,13 @@ impl fmt::Display for ListProjectsError {
     }
 }
 impl Error for ListProjectsError {}
+impl ServiceErrorCode for ListProjectsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProjectsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListResources
 #[derive(Debug, PartialEq)]
 pub enum ListResourcesError {
@@ -1205,6 +1290,14 @@ impl fmt::Display for ListResourcesError {
     }
 }
 impl Error for ListResourcesError {}
+impl ServiceErrorCode for ListResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourcesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListResourcesError::ProjectNotFound(_) => "ProjectNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTagsForProject
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForProjectError {
@@ -1241,6 +1334,14 @@ impl fmt::Display for ListTagsForProjectError {
     }
 }
 impl Error for ListTagsForProjectError {}
+impl ServiceErrorCode for ListTagsForProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForProjectError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListTagsForProjectError::ProjectNotFound(_) => "ProjectNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTeamMembers
 #[derive(Debug, PartialEq)]
 pub enum ListTeamMembersError {
@@ -1277,6 +1378,14 @@ impl fmt::Display for ListTeamMembersError {
     }
 }
 impl Error for ListTeamMembersError {}
+impl ServiceErrorCode for ListTeamMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTeamMembersError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListTeamMembersError::ProjectNotFound(_) => "ProjectNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListUserProfiles
 #[derive(Debug, PartialEq)]
 pub enum ListUserProfilesError {
@@ -1307,6 +1416,13 @@ impl fmt::Display for ListUserProfilesError {
     }
 }
 impl Error for ListUserProfilesError {}
+impl ServiceErrorCode for ListUserProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUserProfilesError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by TagProject
 #[derive(Debug, PartialEq)]
 pub enum TagProjectError {
@@ -1349,6 +1465,15 @@ impl fmt::Display for TagProjectError {
     }
 }
 impl Error for TagProjectError {}
+impl ServiceErrorCode for TagProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagProjectError::ConcurrentModification(_) => "ConcurrentModificationException",
+            TagProjectError::LimitExceeded(_) => "LimitExceededException",
+            TagProjectError::ProjectNotFound(_) => "ProjectNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagProject
 #[derive(Debug, PartialEq)]
 pub enum UntagProjectError {
@@ -1391,6 +1516,15 @@ impl fmt::Display for UntagProjectError {
     }
 }
 impl Error for UntagProjectError {}
+impl ServiceErrorCode for UntagProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagProjectError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UntagProjectError::LimitExceeded(_) => "LimitExceededException",
+            UntagProjectError::ProjectNotFound(_) => "ProjectNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateProject
 #[derive(Debug, PartialEq)]
 pub enum UpdateProjectError {
@@ -1421,6 +1555,13 @@ impl fmt::Display for UpdateProjectError {
     }
 }
 impl Error for UpdateProjectError {}
+impl ServiceErrorCode for UpdateProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateProjectError::ProjectNotFound(_) => "ProjectNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateTeamMember
 #[derive(Debug, PartialEq)]
 pub enum UpdateTeamMemberError {
@@ -1485,6 +1626,18 @@ impl fmt::Display for UpdateTeamMemberError {
     }
 }
 impl Error for UpdateTeamMemberError {}
+impl ServiceErrorCode for UpdateTeamMemberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTeamMemberError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateTeamMemberError::InvalidServiceRole(_) => "InvalidServiceRoleException",
+            UpdateTeamMemberError::LimitExceeded(_) => "LimitExceededException",
+            UpdateTeamMemberError::ProjectConfiguration(_) => "ProjectConfigurationException",
+            UpdateTeamMemberError::ProjectNotFound(_) => "ProjectNotFoundException",
+            UpdateTeamMemberError::TeamMemberNotFound(_) => "TeamMemberNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateUserProfile
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserProfileError {
@@ -1517,6 +1670,13 @@ impl fmt::Display for UpdateUserProfileError {
     }
 }
 impl Error for UpdateUserProfileError {}
+impl ServiceErrorCode for UpdateUserProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserProfileError::UserProfileNotFound(_) => "UserProfileNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the CodeStar API. CodeStar clients implement this trait.
 #[async_trait]
 pub trait CodeStar {
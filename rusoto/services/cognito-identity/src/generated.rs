@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -747,6 +747,18 @@ impl fmt::Display for CreateIdentityPoolError {
     }
 }
 impl Error for CreateIdentityPoolError {}
+impl ServiceErrorCode for CreateIdentityPoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateIdentityPoolError::InternalError(_) => "InternalErrorException",
+            CreateIdentityPoolError::InvalidParameter(_) => "InvalidParameterException",
+            CreateIdentityPoolError::LimitExceeded(_) => "LimitExceededException",
+            CreateIdentityPoolError::NotAuthorized(_) => "NotAuthorizedException",
+            CreateIdentityPoolError::ResourceConflict(_) => "ResourceConflictException",
+            CreateIdentityPoolError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteIdentities
 #[derive(Debug, PartialEq)]
 pub enum DeleteIdentitiesError {
@@ -789,6 +801,15 @@ impl fmt::Display for DeleteIdentitiesError {
     }
 }
 impl Error for DeleteIdentitiesError {}
+impl ServiceErrorCode for DeleteIdentitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIdentitiesError::InternalError(_) => "InternalErrorException",
+            DeleteIdentitiesError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteIdentitiesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteIdentityPool
 #[derive(Debug, PartialEq)]
 pub enum DeleteIdentityPoolError {
@@ -843,6 +864,17 @@ impl fmt::Display for DeleteIdentityPoolError {
     }
 }
 impl Error for DeleteIdentityPoolError {}
+impl ServiceErrorCode for DeleteIdentityPoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIdentityPoolError::InternalError(_) => "InternalErrorException",
+            DeleteIdentityPoolError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteIdentityPoolError::NotAuthorized(_) => "NotAuthorizedException",
+            DeleteIdentityPoolError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteIdentityPoolError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeIdentity
 #[derive(Debug, PartialEq)]
 pub enum DescribeIdentityError {
@@ -897,6 +929,17 @@ impl fmt::Display for DescribeIdentityError {
     }
 }
 impl Error for DescribeIdentityError {}
+impl ServiceErrorCode for DescribeIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeIdentityError::InternalError(_) => "InternalErrorException",
+            DescribeIdentityError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeIdentityError::NotAuthorized(_) => "NotAuthorizedException",
+            DescribeIdentityError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeIdentityError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeIdentityPool
 #[derive(Debug, PartialEq)]
 pub enum DescribeIdentityPoolError {
@@ -957,6 +1000,17 @@ impl fmt::Display for DescribeIdentityPoolError {
     }
 }
 impl Error for DescribeIdentityPoolError {}
+impl ServiceErrorCode for DescribeIdentityPoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeIdentityPoolError::InternalError(_) => "InternalErrorException",
+            DescribeIdentityPoolError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeIdentityPoolError::NotAuthorized(_) => "NotAuthorizedException",
+            DescribeIdentityPoolError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeIdentityPoolError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetCredentialsForIdentity
 #[derive(Debug, PartialEq)]
 pub enum GetCredentialsForIdentityError {
@@ -1047,6 +1101,22 @@ impl fmt::Display for GetCredentialsForIdentityError {
     }
 }
 impl Error for GetCredentialsForIdentityError {}
+impl ServiceErrorCode for GetCredentialsForIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCredentialsForIdentityError::ExternalService(_) => "ExternalServiceException",
+            GetCredentialsForIdentityError::InternalError(_) => "InternalErrorException",
+            GetCredentialsForIdentityError::InvalidIdentityPoolConfiguration(_) => {
+                "InvalidIdentityPoolConfigurationException"
+            }
+            GetCredentialsForIdentityError::InvalidParameter(_) => "InvalidParameterException",
+            GetCredentialsForIdentityError::NotAuthorized(_) => "NotAuthorizedException",
+            GetCredentialsForIdentityError::ResourceConflict(_) => "ResourceConflictException",
+            GetCredentialsForIdentityError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetCredentialsForIdentityError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetId
 #[derive(Debug, PartialEq)]
 pub enum GetIdError {
@@ -1119,6 +1189,20 @@ impl fmt::Display for GetIdError {
     }
 }
 impl Error for GetIdError {}
+impl ServiceErrorCode for GetIdError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIdError::ExternalService(_) => "ExternalServiceException",
+            GetIdError::InternalError(_) => "InternalErrorException",
+            GetIdError::InvalidParameter(_) => "InvalidParameterException",
+            GetIdError::LimitExceeded(_) => "LimitExceededException",
+            GetIdError::NotAuthorized(_) => "NotAuthorizedException",
+            GetIdError::ResourceConflict(_) => "ResourceConflictException",
+            GetIdError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetIdError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetIdentityPoolRoles
 #[derive(Debug, PartialEq)]
 pub enum GetIdentityPoolRolesError {
@@ -1187,6 +1271,18 @@ impl fmt::Display for GetIdentityPoolRolesError {
     }
 }
 impl Error for GetIdentityPoolRolesError {}
+impl ServiceErrorCode for GetIdentityPoolRolesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIdentityPoolRolesError::InternalError(_) => "InternalErrorException",
+            GetIdentityPoolRolesError::InvalidParameter(_) => "InvalidParameterException",
+            GetIdentityPoolRolesError::NotAuthorized(_) => "NotAuthorizedException",
+            GetIdentityPoolRolesError::ResourceConflict(_) => "ResourceConflictException",
+            GetIdentityPoolRolesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetIdentityPoolRolesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetOpenIdToken
 #[derive(Debug, PartialEq)]
 pub enum GetOpenIdTokenError {
@@ -1253,6 +1349,19 @@ impl fmt::Display for GetOpenIdTokenError {
     }
 }
 impl Error for GetOpenIdTokenError {}
+impl ServiceErrorCode for GetOpenIdTokenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOpenIdTokenError::ExternalService(_) => "ExternalServiceException",
+            GetOpenIdTokenError::InternalError(_) => "InternalErrorException",
+            GetOpenIdTokenError::InvalidParameter(_) => "InvalidParameterException",
+            GetOpenIdTokenError::NotAuthorized(_) => "NotAuthorizedException",
+            GetOpenIdTokenError::ResourceConflict(_) => "ResourceConflictException",
+            GetOpenIdTokenError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetOpenIdTokenError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetOpenIdTokenForDeveloperIdentity
 #[derive(Debug, PartialEq)]
 pub enum GetOpenIdTokenForDeveloperIdentityError {
@@ -1351,6 +1460,29 @@ impl fmt::Display for GetOpenIdTokenForDeveloperIdentityError {
     }
 }
 impl Error for GetOpenIdTokenForDeveloperIdentityError {}
+impl ServiceErrorCode for GetOpenIdTokenForDeveloperIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOpenIdTokenForDeveloperIdentityError::DeveloperUserAlreadyRegistered(_) => {
+                "DeveloperUserAlreadyRegisteredException"
+            }
+            GetOpenIdTokenForDeveloperIdentityError::InternalError(_) => "InternalErrorException",
+            GetOpenIdTokenForDeveloperIdentityError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            GetOpenIdTokenForDeveloperIdentityError::NotAuthorized(_) => "NotAuthorizedException",
+            GetOpenIdTokenForDeveloperIdentityError::ResourceConflict(_) => {
+                "ResourceConflictException"
+            }
+            GetOpenIdTokenForDeveloperIdentityError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            GetOpenIdTokenForDeveloperIdentityError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by ListIdentities
 #[derive(Debug, PartialEq)]
 pub enum ListIdentitiesError {
@@ -1405,6 +1537,17 @@ impl fmt::Display for ListIdentitiesError {
     }
 }
 impl Error for ListIdentitiesError {}
+impl ServiceErrorCode for ListIdentitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListIdentitiesError::InternalError(_) => "InternalErrorException",
+            ListIdentitiesError::InvalidParameter(_) => "InvalidParameterException",
+            ListIdentitiesError::NotAuthorized(_) => "NotAuthorizedException",
+            ListIdentitiesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListIdentitiesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListIdentityPools
 #[derive(Debug, PartialEq)]
 pub enum ListIdentityPoolsError {
@@ -1459,6 +1602,17 @@ impl fmt::Display for ListIdentityPoolsError {
     }
 }
 impl Error for ListIdentityPoolsError {}
+impl ServiceErrorCode for ListIdentityPoolsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListIdentityPoolsError::InternalError(_) => "InternalErrorException",
+            ListIdentityPoolsError::InvalidParameter(_) => "InvalidParameterException",
+            ListIdentityPoolsError::NotAuthorized(_) => "NotAuthorizedException",
+            ListIdentityPoolsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListIdentityPoolsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1517,6 +1671,17 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalError(_) => "InternalErrorException",
+            ListTagsForResourceError::InvalidParameter(_) => "InvalidParameterException",
+            ListTagsForResourceError::NotAuthorized(_) => "NotAuthorizedException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by LookupDeveloperIdentity
 #[derive(Debug, PartialEq)]
 pub enum LookupDeveloperIdentityError {
@@ -1589,6 +1754,18 @@ impl fmt::Display for LookupDeveloperIdentityError {
     }
 }
 impl Error for LookupDeveloperIdentityError {}
+impl ServiceErrorCode for LookupDeveloperIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            LookupDeveloperIdentityError::InternalError(_) => "InternalErrorException",
+            LookupDeveloperIdentityError::InvalidParameter(_) => "InvalidParameterException",
+            LookupDeveloperIdentityError::NotAuthorized(_) => "NotAuthorizedException",
+            LookupDeveloperIdentityError::ResourceConflict(_) => "ResourceConflictException",
+            LookupDeveloperIdentityError::ResourceNotFound(_) => "ResourceNotFoundException",
+            LookupDeveloperIdentityError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by MergeDeveloperIdentities
 #[derive(Debug, PartialEq)]
 pub enum MergeDeveloperIdentitiesError {
@@ -1661,6 +1838,18 @@ impl fmt::Display for MergeDeveloperIdentitiesError {
     }
 }
 impl Error for MergeDeveloperIdentitiesError {}
+impl ServiceErrorCode for MergeDeveloperIdentitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            MergeDeveloperIdentitiesError::InternalError(_) => "InternalErrorException",
+            MergeDeveloperIdentitiesError::InvalidParameter(_) => "InvalidParameterException",
+            MergeDeveloperIdentitiesError::NotAuthorized(_) => "NotAuthorizedException",
+            MergeDeveloperIdentitiesError::ResourceConflict(_) => "ResourceConflictException",
+            MergeDeveloperIdentitiesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            MergeDeveloperIdentitiesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by SetIdentityPoolRoles
 #[derive(Debug, PartialEq)]
 pub enum SetIdentityPoolRolesError {
@@ -1737,6 +1926,21 @@ impl fmt::Display for SetIdentityPoolRolesError {
     }
 }
 impl Error for SetIdentityPoolRolesError {}
+impl ServiceErrorCode for SetIdentityPoolRolesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetIdentityPoolRolesError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            SetIdentityPoolRolesError::InternalError(_) => "InternalErrorException",
+            SetIdentityPoolRolesError::InvalidParameter(_) => "InvalidParameterException",
+            SetIdentityPoolRolesError::NotAuthorized(_) => "NotAuthorizedException",
+            SetIdentityPoolRolesError::ResourceConflict(_) => "ResourceConflictException",
+            SetIdentityPoolRolesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SetIdentityPoolRolesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1791,6 +1995,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalError(_) => "InternalErrorException",
+            TagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            TagResourceError::NotAuthorized(_) => "NotAuthorizedException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UnlinkDeveloperIdentity
 #[derive(Debug, PartialEq)]
 pub enum UnlinkDeveloperIdentityError {
@@ -1863,6 +2078,18 @@ impl fmt::Display for UnlinkDeveloperIdentityError {
     }
 }
 impl Error for UnlinkDeveloperIdentityError {}
+impl ServiceErrorCode for UnlinkDeveloperIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UnlinkDeveloperIdentityError::InternalError(_) => "InternalErrorException",
+            UnlinkDeveloperIdentityError::InvalidParameter(_) => "InvalidParameterException",
+            UnlinkDeveloperIdentityError::NotAuthorized(_) => "NotAuthorizedException",
+            UnlinkDeveloperIdentityError::ResourceConflict(_) => "ResourceConflictException",
+            UnlinkDeveloperIdentityError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UnlinkDeveloperIdentityError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UnlinkIdentity
 #[derive(Debug, PartialEq)]
 pub enum UnlinkIdentityError {
@@ -1929,6 +2156,19 @@ impl fmt::Display for UnlinkIdentityError {
     }
 }
 impl Error for UnlinkIdentityError {}
+impl ServiceErrorCode for UnlinkIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UnlinkIdentityError::ExternalService(_) => "ExternalServiceException",
+            UnlinkIdentityError::InternalError(_) => "InternalErrorException",
+            UnlinkIdentityError::InvalidParameter(_) => "InvalidParameterException",
+            UnlinkIdentityError::NotAuthorized(_) => "NotAuthorizedException",
+            UnlinkIdentityError::ResourceConflict(_) => "ResourceConflictException",
+            UnlinkIdentityError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UnlinkIdentityError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1983,6 +2223,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalError(_) => "InternalErrorException",
+            UntagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            UntagResourceError::NotAuthorized(_) => "NotAuthorizedException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateIdentityPool
 #[derive(Debug, PartialEq)]
 pub enum UpdateIdentityPoolError {
@@ -2057,6 +2308,20 @@ impl fmt::Display for UpdateIdentityPoolError {
     }
 }
 impl Error for UpdateIdentityPoolError {}
+impl ServiceErrorCode for UpdateIdentityPoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateIdentityPoolError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateIdentityPoolError::InternalError(_) => "InternalErrorException",
+            UpdateIdentityPoolError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateIdentityPoolError::LimitExceeded(_) => "LimitExceededException",
+            UpdateIdentityPoolError::NotAuthorized(_) => "NotAuthorizedException",
+            UpdateIdentityPoolError::ResourceConflict(_) => "ResourceConflictException",
+            UpdateIdentityPoolError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateIdentityPoolError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Cognito Identity API. Amazon Cognito Identity clients implement this trait.
 #[async_trait]
 pub trait CognitoIdentity {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -4350,6 +4350,18 @@ impl fmt::Display for AddCustomAttributesError {
     }
 }
 impl Error for AddCustomAttributesError {}
+impl ServiceErrorCode for AddCustomAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddCustomAttributesError::InternalError(_) => "InternalErrorException",
+            AddCustomAttributesError::InvalidParameter(_) => "InvalidParameterException",
+            AddCustomAttributesError::NotAuthorized(_) => "NotAuthorizedException",
+            AddCustomAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AddCustomAttributesError::TooManyRequests(_) => "TooManyRequestsException",
+            AddCustomAttributesError::UserImportInProgress(_) => "UserImportInProgressException",
+        }
+    }
+}
 /// Errors returned by AdminAddUserToGroup
 #[derive(Debug, PartialEq)]
 pub enum AdminAddUserToGroupError {
@@ -4414,6 +4426,18 @@ impl fmt::Display for AdminAddUserToGroupError {
     }
 }
 impl Error for AdminAddUserToGroupError {}
+impl ServiceErrorCode for AdminAddUserToGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminAddUserToGroupError::InternalError(_) => "InternalErrorException",
+            AdminAddUserToGroupError::InvalidParameter(_) => "InvalidParameterException",
+            AdminAddUserToGroupError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminAddUserToGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminAddUserToGroupError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminAddUserToGroupError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminConfirmSignUp
 #[derive(Debug, PartialEq)]
 pub enum AdminConfirmSignUpError {
@@ -4510,6 +4534,23 @@ impl fmt::Display for AdminConfirmSignUpError {
     }
 }
 impl Error for AdminConfirmSignUpError {}
+impl ServiceErrorCode for AdminConfirmSignUpError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminConfirmSignUpError::InternalError(_) => "InternalErrorException",
+            AdminConfirmSignUpError::InvalidLambdaResponse(_) => "InvalidLambdaResponseException",
+            AdminConfirmSignUpError::InvalidParameter(_) => "InvalidParameterException",
+            AdminConfirmSignUpError::LimitExceeded(_) => "LimitExceededException",
+            AdminConfirmSignUpError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminConfirmSignUpError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminConfirmSignUpError::TooManyFailedAttempts(_) => "TooManyFailedAttemptsException",
+            AdminConfirmSignUpError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminConfirmSignUpError::UnexpectedLambda(_) => "UnexpectedLambdaException",
+            AdminConfirmSignUpError::UserLambdaValidation(_) => "UserLambdaValidationException",
+            AdminConfirmSignUpError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminCreateUser
 #[derive(Debug, PartialEq)]
 pub enum AdminCreateUserError {
@@ -4642,6 +4683,32 @@ impl fmt::Display for AdminCreateUserError {
     }
 }
 impl Error for AdminCreateUserError {}
+impl ServiceErrorCode for AdminCreateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminCreateUserError::CodeDeliveryFailure(_) => "CodeDeliveryFailureException",
+            AdminCreateUserError::InternalError(_) => "InternalErrorException",
+            AdminCreateUserError::InvalidLambdaResponse(_) => "InvalidLambdaResponseException",
+            AdminCreateUserError::InvalidParameter(_) => "InvalidParameterException",
+            AdminCreateUserError::InvalidPassword(_) => "InvalidPasswordException",
+            AdminCreateUserError::InvalidSmsRoleAccessPolicy(_) => {
+                "InvalidSmsRoleAccessPolicyException"
+            }
+            AdminCreateUserError::InvalidSmsRoleTrustRelationship(_) => {
+                "InvalidSmsRoleTrustRelationshipException"
+            }
+            AdminCreateUserError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminCreateUserError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            AdminCreateUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminCreateUserError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminCreateUserError::UnexpectedLambda(_) => "UnexpectedLambdaException",
+            AdminCreateUserError::UnsupportedUserState(_) => "UnsupportedUserStateException",
+            AdminCreateUserError::UserLambdaValidation(_) => "UserLambdaValidationException",
+            AdminCreateUserError::UserNotFound(_) => "UserNotFoundException",
+            AdminCreateUserError::UsernameExists(_) => "UsernameExistsException",
+        }
+    }
+}
 /// Errors returned by AdminDeleteUser
 #[derive(Debug, PartialEq)]
 pub enum AdminDeleteUserError {
@@ -4702,6 +4769,18 @@ impl fmt::Display for AdminDeleteUserError {
     }
 }
 impl Error for AdminDeleteUserError {}
+impl ServiceErrorCode for AdminDeleteUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminDeleteUserError::InternalError(_) => "InternalErrorException",
+            AdminDeleteUserError::InvalidParameter(_) => "InvalidParameterException",
+            AdminDeleteUserError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminDeleteUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminDeleteUserError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminDeleteUserError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminDeleteUserAttributes
 #[derive(Debug, PartialEq)]
 pub enum AdminDeleteUserAttributesError {
@@ -4774,6 +4853,18 @@ impl fmt::Display for AdminDeleteUserAttributesError {
     }
 }
 impl Error for AdminDeleteUserAttributesError {}
+impl ServiceErrorCode for AdminDeleteUserAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminDeleteUserAttributesError::InternalError(_) => "InternalErrorException",
+            AdminDeleteUserAttributesError::InvalidParameter(_) => "InvalidParameterException",
+            AdminDeleteUserAttributesError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminDeleteUserAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminDeleteUserAttributesError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminDeleteUserAttributesError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminDisableProviderForUser
 #[derive(Debug, PartialEq)]
 pub enum AdminDisableProviderForUserError {
@@ -4856,6 +4947,19 @@ impl fmt::Display for AdminDisableProviderForUserError {
     }
 }
 impl Error for AdminDisableProviderForUserError {}
+impl ServiceErrorCode for AdminDisableProviderForUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminDisableProviderForUserError::AliasExists(_) => "AliasExistsException",
+            AdminDisableProviderForUserError::InternalError(_) => "InternalErrorException",
+            AdminDisableProviderForUserError::InvalidParameter(_) => "InvalidParameterException",
+            AdminDisableProviderForUserError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminDisableProviderForUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminDisableProviderForUserError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminDisableProviderForUserError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminDisableUser
 #[derive(Debug, PartialEq)]
 pub enum AdminDisableUserError {
@@ -4916,6 +5020,18 @@ impl fmt::Display for AdminDisableUserError {
     }
 }
 impl Error for AdminDisableUserError {}
+impl ServiceErrorCode for AdminDisableUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminDisableUserError::InternalError(_) => "InternalErrorException",
+            AdminDisableUserError::InvalidParameter(_) => "InvalidParameterException",
+            AdminDisableUserError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminDisableUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminDisableUserError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminDisableUserError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminEnableUser
 #[derive(Debug, PartialEq)]
 pub enum AdminEnableUserError {
@@ -4976,6 +5092,18 @@ impl fmt::Display for AdminEnableUserError {
     }
 }
 impl Error for AdminEnableUserError {}
+impl ServiceErrorCode for AdminEnableUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminEnableUserError::InternalError(_) => "InternalErrorException",
+            AdminEnableUserError::InvalidParameter(_) => "InvalidParameterException",
+            AdminEnableUserError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminEnableUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminEnableUserError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminEnableUserError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminForgetDevice
 #[derive(Debug, PartialEq)]
 pub enum AdminForgetDeviceError {
@@ -5046,6 +5174,21 @@ impl fmt::Display for AdminForgetDeviceError {
     }
 }
 impl Error for AdminForgetDeviceError {}
+impl ServiceErrorCode for AdminForgetDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminForgetDeviceError::InternalError(_) => "InternalErrorException",
+            AdminForgetDeviceError::InvalidParameter(_) => "InvalidParameterException",
+            AdminForgetDeviceError::InvalidUserPoolConfiguration(_) => {
+                "InvalidUserPoolConfigurationException"
+            }
+            AdminForgetDeviceError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminForgetDeviceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminForgetDeviceError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminForgetDeviceError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminGetDevice
 #[derive(Debug, PartialEq)]
 pub enum AdminGetDeviceError {
@@ -5108,6 +5251,20 @@ impl fmt::Display for AdminGetDeviceError {
     }
 }
 impl Error for AdminGetDeviceError {}
+impl ServiceErrorCode for AdminGetDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminGetDeviceError::InternalError(_) => "InternalErrorException",
+            AdminGetDeviceError::InvalidParameter(_) => "InvalidParameterException",
+            AdminGetDeviceError::InvalidUserPoolConfiguration(_) => {
+                "InvalidUserPoolConfigurationException"
+            }
+            AdminGetDeviceError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminGetDeviceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminGetDeviceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by AdminGetUser
 #[derive(Debug, PartialEq)]
 pub enum AdminGetUserError {
@@ -5168,6 +5325,18 @@ impl fmt::Display for AdminGetUserError {
     }
 }
 impl Error for AdminGetUserError {}
+impl ServiceErrorCode for AdminGetUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminGetUserError::InternalError(_) => "InternalErrorException",
+            AdminGetUserError::InvalidParameter(_) => "InvalidParameterException",
+            AdminGetUserError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminGetUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminGetUserError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminGetUserError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminInitiateAuth
 #[derive(Debug, PartialEq)]
 pub enum AdminInitiateAuthError {
@@ -5298,6 +5467,33 @@ impl fmt::Display for AdminInitiateAuthError {
     }
 }
 impl Error for AdminInitiateAuthError {}
+impl ServiceErrorCode for AdminInitiateAuthError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminInitiateAuthError::InternalError(_) => "InternalErrorException",
+            AdminInitiateAuthError::InvalidLambdaResponse(_) => "InvalidLambdaResponseException",
+            AdminInitiateAuthError::InvalidParameter(_) => "InvalidParameterException",
+            AdminInitiateAuthError::InvalidSmsRoleAccessPolicy(_) => {
+                "InvalidSmsRoleAccessPolicyException"
+            }
+            AdminInitiateAuthError::InvalidSmsRoleTrustRelationship(_) => {
+                "InvalidSmsRoleTrustRelationshipException"
+            }
+            AdminInitiateAuthError::InvalidUserPoolConfiguration(_) => {
+                "InvalidUserPoolConfigurationException"
+            }
+            AdminInitiateAuthError::MFAMethodNotFound(_) => "MFAMethodNotFoundException",
+            AdminInitiateAuthError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminInitiateAuthError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            AdminInitiateAuthError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminInitiateAuthError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminInitiateAuthError::UnexpectedLambda(_) => "UnexpectedLambdaException",
+            AdminInitiateAuthError::UserLambdaValidation(_) => "UserLambdaValidationException",
+            AdminInitiateAuthError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            AdminInitiateAuthError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminLinkProviderForUser
 #[derive(Debug, PartialEq)]
 pub enum AdminLinkProviderForUserError {
@@ -5386,6 +5582,20 @@ impl fmt::Display for AdminLinkProviderForUserError {
     }
 }
 impl Error for AdminLinkProviderForUserError {}
+impl ServiceErrorCode for AdminLinkProviderForUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminLinkProviderForUserError::AliasExists(_) => "AliasExistsException",
+            AdminLinkProviderForUserError::InternalError(_) => "InternalErrorException",
+            AdminLinkProviderForUserError::InvalidParameter(_) => "InvalidParameterException",
+            AdminLinkProviderForUserError::LimitExceeded(_) => "LimitExceededException",
+            AdminLinkProviderForUserError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminLinkProviderForUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminLinkProviderForUserError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminLinkProviderForUserError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminListDevices
 #[derive(Debug, PartialEq)]
 pub enum AdminListDevicesError {
@@ -5450,6 +5660,20 @@ impl fmt::Display for AdminListDevicesError {
     }
 }
 impl Error for AdminListDevicesError {}
+impl ServiceErrorCode for AdminListDevicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminListDevicesError::InternalError(_) => "InternalErrorException",
+            AdminListDevicesError::InvalidParameter(_) => "InvalidParameterException",
+            AdminListDevicesError::InvalidUserPoolConfiguration(_) => {
+                "InvalidUserPoolConfigurationException"
+            }
+            AdminListDevicesError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminListDevicesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminListDevicesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by AdminListGroupsForUser
 #[derive(Debug, PartialEq)]
 pub enum AdminListGroupsForUserError {
@@ -5520,6 +5744,18 @@ impl fmt::Display for AdminListGroupsForUserError {
     }
 }
 impl Error for AdminListGroupsForUserError {}
+impl ServiceErrorCode for AdminListGroupsForUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminListGroupsForUserError::InternalError(_) => "InternalErrorException",
+            AdminListGroupsForUserError::InvalidParameter(_) => "InvalidParameterException",
+            AdminListGroupsForUserError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminListGroupsForUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminListGroupsForUserError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminListGroupsForUserError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminListUserAuthEvents
 #[derive(Debug, PartialEq)]
 pub enum AdminListUserAuthEventsError {
@@ -5602,6 +5838,21 @@ impl fmt::Display for AdminListUserAuthEventsError {
     }
 }
 impl Error for AdminListUserAuthEventsError {}
+impl ServiceErrorCode for AdminListUserAuthEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminListUserAuthEventsError::InternalError(_) => "InternalErrorException",
+            AdminListUserAuthEventsError::InvalidParameter(_) => "InvalidParameterException",
+            AdminListUserAuthEventsError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminListUserAuthEventsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminListUserAuthEventsError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminListUserAuthEventsError::UserNotFound(_) => "UserNotFoundException",
+            AdminListUserAuthEventsError::UserPoolAddOnNotEnabled(_) => {
+                "UserPoolAddOnNotEnabledException"
+            }
+        }
+    }
+}
 /// Errors returned by AdminRemoveUserFromGroup
 #[derive(Debug, PartialEq)]
 pub enum AdminRemoveUserFromGroupError {
@@ -5674,6 +5925,18 @@ impl fmt::Display for AdminRemoveUserFromGroupError {
     }
 }
 impl Error for AdminRemoveUserFromGroupError {}
+impl ServiceErrorCode for AdminRemoveUserFromGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminRemoveUserFromGroupError::InternalError(_) => "InternalErrorException",
+            AdminRemoveUserFromGroupError::InvalidParameter(_) => "InvalidParameterException",
+            AdminRemoveUserFromGroupError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminRemoveUserFromGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminRemoveUserFromGroupError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminRemoveUserFromGroupError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminResetUserPassword
 #[derive(Debug, PartialEq)]
 pub enum AdminResetUserPasswordError {
@@ -5806,6 +6069,33 @@ impl fmt::Display for AdminResetUserPasswordError {
     }
 }
 impl Error for AdminResetUserPasswordError {}
+impl ServiceErrorCode for AdminResetUserPasswordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminResetUserPasswordError::InternalError(_) => "InternalErrorException",
+            AdminResetUserPasswordError::InvalidEmailRoleAccessPolicy(_) => {
+                "InvalidEmailRoleAccessPolicyException"
+            }
+            AdminResetUserPasswordError::InvalidLambdaResponse(_) => {
+                "InvalidLambdaResponseException"
+            }
+            AdminResetUserPasswordError::InvalidParameter(_) => "InvalidParameterException",
+            AdminResetUserPasswordError::InvalidSmsRoleAccessPolicy(_) => {
+                "InvalidSmsRoleAccessPolicyException"
+            }
+            AdminResetUserPasswordError::InvalidSmsRoleTrustRelationship(_) => {
+                "InvalidSmsRoleTrustRelationshipException"
+            }
+            AdminResetUserPasswordError::LimitExceeded(_) => "LimitExceededException",
+            AdminResetUserPasswordError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminResetUserPasswordError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminResetUserPasswordError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminResetUserPasswordError::UnexpectedLambda(_) => "UnexpectedLambdaException",
+            AdminResetUserPasswordError::UserLambdaValidation(_) => "UserLambdaValidationException",
+            AdminResetUserPasswordError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminRespondToAuthChallenge
 #[derive(Debug, PartialEq)]
 pub enum AdminRespondToAuthChallengeError {
@@ -6008,6 +6298,46 @@ impl fmt::Display for AdminRespondToAuthChallengeError {
     }
 }
 impl Error for AdminRespondToAuthChallengeError {}
+impl ServiceErrorCode for AdminRespondToAuthChallengeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminRespondToAuthChallengeError::AliasExists(_) => "AliasExistsException",
+            AdminRespondToAuthChallengeError::CodeMismatch(_) => "CodeMismatchException",
+            AdminRespondToAuthChallengeError::ExpiredCode(_) => "ExpiredCodeException",
+            AdminRespondToAuthChallengeError::InternalError(_) => "InternalErrorException",
+            AdminRespondToAuthChallengeError::InvalidLambdaResponse(_) => {
+                "InvalidLambdaResponseException"
+            }
+            AdminRespondToAuthChallengeError::InvalidParameter(_) => "InvalidParameterException",
+            AdminRespondToAuthChallengeError::InvalidPassword(_) => "InvalidPasswordException",
+            AdminRespondToAuthChallengeError::InvalidSmsRoleAccessPolicy(_) => {
+                "InvalidSmsRoleAccessPolicyException"
+            }
+            AdminRespondToAuthChallengeError::InvalidSmsRoleTrustRelationship(_) => {
+                "InvalidSmsRoleTrustRelationshipException"
+            }
+            AdminRespondToAuthChallengeError::InvalidUserPoolConfiguration(_) => {
+                "InvalidUserPoolConfigurationException"
+            }
+            AdminRespondToAuthChallengeError::MFAMethodNotFound(_) => "MFAMethodNotFoundException",
+            AdminRespondToAuthChallengeError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminRespondToAuthChallengeError::PasswordResetRequired(_) => {
+                "PasswordResetRequiredException"
+            }
+            AdminRespondToAuthChallengeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminRespondToAuthChallengeError::SoftwareTokenMFANotFound(_) => {
+                "SoftwareTokenMFANotFoundException"
+            }
+            AdminRespondToAuthChallengeError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminRespondToAuthChallengeError::UnexpectedLambda(_) => "UnexpectedLambdaException",
+            AdminRespondToAuthChallengeError::UserLambdaValidation(_) => {
+                "UserLambdaValidationException"
+            }
+            AdminRespondToAuthChallengeError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            AdminRespondToAuthChallengeError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminSetUserMFAPreference
 #[derive(Debug, PartialEq)]
 pub enum AdminSetUserMFAPreferenceError {
@@ -6090,6 +6420,21 @@ impl fmt::Display for AdminSetUserMFAPreferenceError {
     }
 }
 impl Error for AdminSetUserMFAPreferenceError {}
+impl ServiceErrorCode for AdminSetUserMFAPreferenceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminSetUserMFAPreferenceError::InternalError(_) => "InternalErrorException",
+            AdminSetUserMFAPreferenceError::InvalidParameter(_) => "InvalidParameterException",
+            AdminSetUserMFAPreferenceError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminSetUserMFAPreferenceError::PasswordResetRequired(_) => {
+                "PasswordResetRequiredException"
+            }
+            AdminSetUserMFAPreferenceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminSetUserMFAPreferenceError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            AdminSetUserMFAPreferenceError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminSetUserPassword
 #[derive(Debug, PartialEq)]
 pub enum AdminSetUserPasswordError {
@@ -6164,6 +6509,19 @@ impl fmt::Display for AdminSetUserPasswordError {
     }
 }
 impl Error for AdminSetUserPasswordError {}
+impl ServiceErrorCode for AdminSetUserPasswordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminSetUserPasswordError::InternalError(_) => "InternalErrorException",
+            AdminSetUserPasswordError::InvalidParameter(_) => "InvalidParameterException",
+            AdminSetUserPasswordError::InvalidPassword(_) => "InvalidPasswordException",
+            AdminSetUserPasswordError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminSetUserPasswordError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminSetUserPasswordError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminSetUserPasswordError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminSetUserSettings
 #[derive(Debug, PartialEq)]
 pub enum AdminSetUserSettingsError {
@@ -6222,6 +6580,17 @@ impl fmt::Display for AdminSetUserSettingsError {
     }
 }
 impl Error for AdminSetUserSettingsError {}
+impl ServiceErrorCode for AdminSetUserSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminSetUserSettingsError::InternalError(_) => "InternalErrorException",
+            AdminSetUserSettingsError::InvalidParameter(_) => "InvalidParameterException",
+            AdminSetUserSettingsError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminSetUserSettingsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminSetUserSettingsError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminUpdateAuthEventFeedback
 #[derive(Debug, PartialEq)]
 pub enum AdminUpdateAuthEventFeedbackError {
@@ -6310,6 +6679,21 @@ impl fmt::Display for AdminUpdateAuthEventFeedbackError {
     }
 }
 impl Error for AdminUpdateAuthEventFeedbackError {}
+impl ServiceErrorCode for AdminUpdateAuthEventFeedbackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminUpdateAuthEventFeedbackError::InternalError(_) => "InternalErrorException",
+            AdminUpdateAuthEventFeedbackError::InvalidParameter(_) => "InvalidParameterException",
+            AdminUpdateAuthEventFeedbackError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminUpdateAuthEventFeedbackError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminUpdateAuthEventFeedbackError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminUpdateAuthEventFeedbackError::UserNotFound(_) => "UserNotFoundException",
+            AdminUpdateAuthEventFeedbackError::UserPoolAddOnNotEnabled(_) => {
+                "UserPoolAddOnNotEnabledException"
+            }
+        }
+    }
+}
 /// Errors returned by AdminUpdateDeviceStatus
 #[derive(Debug, PartialEq)]
 pub enum AdminUpdateDeviceStatusError {
@@ -6392,6 +6776,21 @@ impl fmt::Display for AdminUpdateDeviceStatusError {
     }
 }
 impl Error for AdminUpdateDeviceStatusError {}
+impl ServiceErrorCode for AdminUpdateDeviceStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminUpdateDeviceStatusError::InternalError(_) => "InternalErrorException",
+            AdminUpdateDeviceStatusError::InvalidParameter(_) => "InvalidParameterException",
+            AdminUpdateDeviceStatusError::InvalidUserPoolConfiguration(_) => {
+                "InvalidUserPoolConfigurationException"
+            }
+            AdminUpdateDeviceStatusError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminUpdateDeviceStatusError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminUpdateDeviceStatusError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminUpdateDeviceStatusError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminUpdateUserAttributes
 #[derive(Debug, PartialEq)]
 pub enum AdminUpdateUserAttributesError {
@@ -6530,6 +6929,35 @@ impl fmt::Display for AdminUpdateUserAttributesError {
     }
 }
 impl Error for AdminUpdateUserAttributesError {}
+impl ServiceErrorCode for AdminUpdateUserAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminUpdateUserAttributesError::AliasExists(_) => "AliasExistsException",
+            AdminUpdateUserAttributesError::InternalError(_) => "InternalErrorException",
+            AdminUpdateUserAttributesError::InvalidEmailRoleAccessPolicy(_) => {
+                "InvalidEmailRoleAccessPolicyException"
+            }
+            AdminUpdateUserAttributesError::InvalidLambdaResponse(_) => {
+                "InvalidLambdaResponseException"
+            }
+            AdminUpdateUserAttributesError::InvalidParameter(_) => "InvalidParameterException",
+            AdminUpdateUserAttributesError::InvalidSmsRoleAccessPolicy(_) => {
+                "InvalidSmsRoleAccessPolicyException"
+            }
+            AdminUpdateUserAttributesError::InvalidSmsRoleTrustRelationship(_) => {
+                "InvalidSmsRoleTrustRelationshipException"
+            }
+            AdminUpdateUserAttributesError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminUpdateUserAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminUpdateUserAttributesError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminUpdateUserAttributesError::UnexpectedLambda(_) => "UnexpectedLambdaException",
+            AdminUpdateUserAttributesError::UserLambdaValidation(_) => {
+                "UserLambdaValidationException"
+            }
+            AdminUpdateUserAttributesError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AdminUserGlobalSignOut
 #[derive(Debug, PartialEq)]
 pub enum AdminUserGlobalSignOutError {
@@ -6600,6 +7028,18 @@ impl fmt::Display for AdminUserGlobalSignOutError {
     }
 }
 impl Error for AdminUserGlobalSignOutError {}
+impl ServiceErrorCode for AdminUserGlobalSignOutError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdminUserGlobalSignOutError::InternalError(_) => "InternalErrorException",
+            AdminUserGlobalSignOutError::InvalidParameter(_) => "InvalidParameterException",
+            AdminUserGlobalSignOutError::NotAuthorized(_) => "NotAuthorizedException",
+            AdminUserGlobalSignOutError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AdminUserGlobalSignOutError::TooManyRequests(_) => "TooManyRequestsException",
+            AdminUserGlobalSignOutError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by AssociateSoftwareToken
 #[derive(Debug, PartialEq)]
 pub enum AssociateSoftwareTokenError {
@@ -6676,6 +7116,22 @@ impl fmt::Display for AssociateSoftwareTokenError {
     }
 }
 impl Error for AssociateSoftwareTokenError {}
+impl ServiceErrorCode for AssociateSoftwareTokenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateSoftwareTokenError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AssociateSoftwareTokenError::InternalError(_) => "InternalErrorException",
+            AssociateSoftwareTokenError::InvalidParameter(_) => "InvalidParameterException",
+            AssociateSoftwareTokenError::NotAuthorized(_) => "NotAuthorizedException",
+            AssociateSoftwareTokenError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateSoftwareTokenError::SoftwareTokenMFANotFound(_) => {
+                "SoftwareTokenMFANotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by ChangePassword
 #[derive(Debug, PartialEq)]
 pub enum ChangePasswordError {
@@ -6762,6 +7218,22 @@ impl fmt::Display for ChangePasswordError {
     }
 }
 impl Error for ChangePasswordError {}
+impl ServiceErrorCode for ChangePasswordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ChangePasswordError::InternalError(_) => "InternalErrorException",
+            ChangePasswordError::InvalidParameter(_) => "InvalidParameterException",
+            ChangePasswordError::InvalidPassword(_) => "InvalidPasswordException",
+            ChangePasswordError::LimitExceeded(_) => "LimitExceededException",
+            ChangePasswordError::NotAuthorized(_) => "NotAuthorizedException",
+            ChangePasswordError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            ChangePasswordError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ChangePasswordError::TooManyRequests(_) => "TooManyRequestsException",
+            ChangePasswordError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            ChangePasswordError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by ConfirmDevice
 #[derive(Debug, PartialEq)]
 pub enum ConfirmDeviceError {
@@ -6860,6 +7332,26 @@ impl fmt::Display for ConfirmDeviceError {
     }
 }
 impl Error for ConfirmDeviceError {}
+impl ServiceErrorCode for ConfirmDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ConfirmDeviceError::InternalError(_) => "InternalErrorException",
+            ConfirmDeviceError::InvalidLambdaResponse(_) => "InvalidLambdaResponseException",
+            ConfirmDeviceError::InvalidParameter(_) => "InvalidParameterException",
+            ConfirmDeviceError::InvalidPassword(_) => "InvalidPasswordException",
+            ConfirmDeviceError::InvalidUserPoolConfiguration(_) => {
+                "InvalidUserPoolConfigurationException"
+            }
+            ConfirmDeviceError::NotAuthorized(_) => "NotAuthorizedException",
+            ConfirmDeviceError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            ConfirmDeviceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ConfirmDeviceError::TooManyRequests(_) => "TooManyRequestsException",
+            ConfirmDeviceError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            ConfirmDeviceError::UserNotFound(_) => "UserNotFoundException",
+            ConfirmDeviceError::UsernameExists(_) => "UsernameExistsException",
+        }
+    }
+}
 /// Errors returned by ConfirmForgotPassword
 #[derive(Debug, PartialEq)]
 pub enum ConfirmForgotPasswordError {
@@ -6992,6 +7484,31 @@ impl fmt::Display for ConfirmForgotPasswordError {
     }
 }
 impl Error for ConfirmForgotPasswordError {}
+impl ServiceErrorCode for ConfirmForgotPasswordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ConfirmForgotPasswordError::CodeMismatch(_) => "CodeMismatchException",
+            ConfirmForgotPasswordError::ExpiredCode(_) => "ExpiredCodeException",
+            ConfirmForgotPasswordError::InternalError(_) => "InternalErrorException",
+            ConfirmForgotPasswordError::InvalidLambdaResponse(_) => {
+                "InvalidLambdaResponseException"
+            }
+            ConfirmForgotPasswordError::InvalidParameter(_) => "InvalidParameterException",
+            ConfirmForgotPasswordError::InvalidPassword(_) => "InvalidPasswordException",
+            ConfirmForgotPasswordError::LimitExceeded(_) => "LimitExceededException",
+            ConfirmForgotPasswordError::NotAuthorized(_) => "NotAuthorizedException",
+            ConfirmForgotPasswordError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ConfirmForgotPasswordError::TooManyFailedAttempts(_) => {
+                "TooManyFailedAttemptsException"
+            }
+            ConfirmForgotPasswordError::TooManyRequests(_) => "TooManyRequestsException",
+            ConfirmForgotPasswordError::UnexpectedLambda(_) => "UnexpectedLambdaException",
+            ConfirmForgotPasswordError::UserLambdaValidation(_) => "UserLambdaValidationException",
+            ConfirmForgotPasswordError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            ConfirmForgotPasswordError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by ConfirmSignUp
 #[derive(Debug, PartialEq)]
 pub enum ConfirmSignUpError {
@@ -7100,6 +7617,26 @@ impl fmt::Display for ConfirmSignUpError {
     }
 }
 impl Error for ConfirmSignUpError {}
+impl ServiceErrorCode for ConfirmSignUpError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ConfirmSignUpError::AliasExists(_) => "AliasExistsException",
+            ConfirmSignUpError::CodeMismatch(_) => "CodeMismatchException",
+            ConfirmSignUpError::ExpiredCode(_) => "ExpiredCodeException",
+            ConfirmSignUpError::InternalError(_) => "InternalErrorException",
+            ConfirmSignUpError::InvalidLambdaResponse(_) => "InvalidLambdaResponseException",
+            ConfirmSignUpError::InvalidParameter(_) => "InvalidParameterException",
+            ConfirmSignUpError::LimitExceeded(_) => "LimitExceededException",
+            ConfirmSignUpError::NotAuthorized(_) => "NotAuthorizedException",
+            ConfirmSignUpError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ConfirmSignUpError::TooManyFailedAttempts(_) => "TooManyFailedAttemptsException",
+            ConfirmSignUpError::TooManyRequests(_) => "TooManyRequestsException",
+            ConfirmSignUpError::UnexpectedLambda(_) => "UnexpectedLambdaException",
+            ConfirmSignUpError::UserLambdaValidation(_) => "UserLambdaValidationException",
+            ConfirmSignUpError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateGroupError {
@@ -7166,6 +7703,19 @@ impl fmt::Display for CreateGroupError {
     }
 }
 impl Error for CreateGroupError {}
+impl ServiceErrorCode for CreateGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGroupError::GroupExists(_) => "GroupExistsException",
+            CreateGroupError::InternalError(_) => "InternalErrorException",
+            CreateGroupError::InvalidParameter(_) => "InvalidParameterException",
+            CreateGroupError::LimitExceeded(_) => "LimitExceededException",
+            CreateGroupError::NotAuthorized(_) => "NotAuthorizedException",
+            CreateGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateGroupError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateIdentityProvider
 #[derive(Debug, PartialEq)]
 pub enum CreateIdentityProviderError {
@@ -7246,6 +7796,19 @@ impl fmt::Display for CreateIdentityProviderError {
     }
 }
 impl Error for CreateIdentityProviderError {}
+impl ServiceErrorCode for CreateIdentityProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateIdentityProviderError::DuplicateProvider(_) => "DuplicateProviderException",
+            CreateIdentityProviderError::InternalError(_) => "InternalErrorException",
+            CreateIdentityProviderError::InvalidParameter(_) => "InvalidParameterException",
+            CreateIdentityProviderError::LimitExceeded(_) => "LimitExceededException",
+            CreateIdentityProviderError::NotAuthorized(_) => "NotAuthorizedException",
+            CreateIdentityProviderError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateIdentityProviderError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateResourceServer
 #[derive(Debug, PartialEq)]
 pub enum CreateResourceServerError {
@@ -7312,6 +7875,18 @@ impl fmt::Display for CreateResourceServerError {
     }
 }
 impl Error for CreateResourceServerError {}
+impl ServiceErrorCode for CreateResourceServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateResourceServerError::InternalError(_) => "InternalErrorException",
+            CreateResourceServerError::InvalidParameter(_) => "InvalidParameterException",
+            CreateResourceServerError::LimitExceeded(_) => "LimitExceededException",
+            CreateResourceServerError::NotAuthorized(_) => "NotAuthorizedException",
+            CreateResourceServerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateResourceServerError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateUserImportJob
 #[derive(Debug, PartialEq)]
 pub enum CreateUserImportJobError {
@@ -7384,6 +7959,19 @@ impl fmt::Display for CreateUserImportJobError {
     }
 }
 impl Error for CreateUserImportJobError {}
+impl ServiceErrorCode for CreateUserImportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserImportJobError::InternalError(_) => "InternalErrorException",
+            CreateUserImportJobError::InvalidParameter(_) => "InvalidParameterException",
+            CreateUserImportJobError::LimitExceeded(_) => "LimitExceededException",
+            CreateUserImportJobError::NotAuthorized(_) => "NotAuthorizedException",
+            CreateUserImportJobError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            CreateUserImportJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateUserImportJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateUserPool
 #[derive(Debug, PartialEq)]
 pub enum CreateUserPoolError {
@@ -7470,6 +8058,27 @@ impl fmt::Display for CreateUserPoolError {
     }
 }
 impl Error for CreateUserPoolError {}
+impl ServiceErrorCode for CreateUserPoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserPoolError::InternalError(_) => "InternalErrorException",
+            CreateUserPoolError::InvalidEmailRoleAccessPolicy(_) => {
+                "InvalidEmailRoleAccessPolicyException"
+            }
+            CreateUserPoolError::InvalidParameter(_) => "InvalidParameterException",
+            CreateUserPoolError::InvalidSmsRoleAccessPolicy(_) => {
+                "InvalidSmsRoleAccessPolicyException"
+            }
+            CreateUserPoolError::InvalidSmsRoleTrustRelationship(_) => {
+                "InvalidSmsRoleTrustRelationshipException"
+            }
+            CreateUserPoolError::LimitExceeded(_) => "LimitExceededException",
+            CreateUserPoolError::NotAuthorized(_) => "NotAuthorizedException",
+            CreateUserPoolError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateUserPoolError::UserPoolTagging(_) => "UserPoolTaggingException",
+        }
+    }
+}
 /// Errors returned by CreateUserPoolClient
 #[derive(Debug, PartialEq)]
 pub enum CreateUserPoolClientError {
@@ -7552,6 +8161,20 @@ impl fmt::Display for CreateUserPoolClientError {
     }
 }
 impl Error for CreateUserPoolClientError {}
+impl ServiceErrorCode for CreateUserPoolClientError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserPoolClientError::InternalError(_) => "InternalErrorException",
+            CreateUserPoolClientError::InvalidOAuthFlow(_) => "InvalidOAuthFlowException",
+            CreateUserPoolClientError::InvalidParameter(_) => "InvalidParameterException",
+            CreateUserPoolClientError::LimitExceeded(_) => "LimitExceededException",
+            CreateUserPoolClientError::NotAuthorized(_) => "NotAuthorizedException",
+            CreateUserPoolClientError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateUserPoolClientError::ScopeDoesNotExist(_) => "ScopeDoesNotExistException",
+            CreateUserPoolClientError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateUserPoolDomain
 #[derive(Debug, PartialEq)]
 pub enum CreateUserPoolDomainError {
@@ -7610,6 +8233,17 @@ impl fmt::Display for CreateUserPoolDomainError {
     }
 }
 impl Error for CreateUserPoolDomainError {}
+impl ServiceErrorCode for CreateUserPoolDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserPoolDomainError::InternalError(_) => "InternalErrorException",
+            CreateUserPoolDomainError::InvalidParameter(_) => "InvalidParameterException",
+            CreateUserPoolDomainError::LimitExceeded(_) => "LimitExceededException",
+            CreateUserPoolDomainError::NotAuthorized(_) => "NotAuthorizedException",
+            CreateUserPoolDomainError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteGroupError {
@@ -7664,6 +8298,17 @@ impl fmt::Display for DeleteGroupError {
     }
 }
 impl Error for DeleteGroupError {}
+impl ServiceErrorCode for DeleteGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGroupError::InternalError(_) => "InternalErrorException",
+            DeleteGroupError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteGroupError::NotAuthorized(_) => "NotAuthorizedException",
+            DeleteGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteGroupError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteIdentityProvider
 #[derive(Debug, PartialEq)]
 pub enum DeleteIdentityProviderError {
@@ -7738,6 +8383,20 @@ impl fmt::Display for DeleteIdentityProviderError {
     }
 }
 impl Error for DeleteIdentityProviderError {}
+impl ServiceErrorCode for DeleteIdentityProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIdentityProviderError::InternalError(_) => "InternalErrorException",
+            DeleteIdentityProviderError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteIdentityProviderError::NotAuthorized(_) => "NotAuthorizedException",
+            DeleteIdentityProviderError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteIdentityProviderError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteIdentityProviderError::UnsupportedIdentityProvider(_) => {
+                "UnsupportedIdentityProviderException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteResourceServer
 #[derive(Debug, PartialEq)]
 pub enum DeleteResourceServerError {
@@ -7798,6 +8457,17 @@ impl fmt::Display for DeleteResourceServerError {
     }
 }
 impl Error for DeleteResourceServerError {}
+impl ServiceErrorCode for DeleteResourceServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResourceServerError::InternalError(_) => "InternalErrorException",
+            DeleteResourceServerError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteResourceServerError::NotAuthorized(_) => "NotAuthorizedException",
+            DeleteResourceServerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteResourceServerError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserError {
@@ -7870,6 +8540,20 @@ impl fmt::Display for DeleteUserError {
     }
 }
 impl Error for DeleteUserError {}
+impl ServiceErrorCode for DeleteUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserError::InternalError(_) => "InternalErrorException",
+            DeleteUserError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteUserError::NotAuthorized(_) => "NotAuthorizedException",
+            DeleteUserError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            DeleteUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteUserError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteUserError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            DeleteUserError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteUserAttributes
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserAttributesError {
@@ -7952,6 +8636,20 @@ impl fmt::Display for DeleteUserAttributesError {
     }
 }
 impl Error for DeleteUserAttributesError {}
+impl ServiceErrorCode for DeleteUserAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserAttributesError::InternalError(_) => "InternalErrorException",
+            DeleteUserAttributesError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteUserAttributesError::NotAuthorized(_) => "NotAuthorizedException",
+            DeleteUserAttributesError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            DeleteUserAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteUserAttributesError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteUserAttributesError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            DeleteUserAttributesError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteUserPool
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserPoolError {
@@ -8012,6 +8710,18 @@ impl fmt::Display for DeleteUserPoolError {
     }
 }
 impl Error for DeleteUserPoolError {}
+impl ServiceErrorCode for DeleteUserPoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserPoolError::InternalError(_) => "InternalErrorException",
+            DeleteUserPoolError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteUserPoolError::NotAuthorized(_) => "NotAuthorizedException",
+            DeleteUserPoolError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteUserPoolError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteUserPoolError::UserImportInProgress(_) => "UserImportInProgressException",
+        }
+    }
+}
 /// Errors returned by DeleteUserPoolClient
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserPoolClientError {
@@ -8072,6 +8782,17 @@ impl fmt::Display for DeleteUserPoolClientError {
     }
 }
 impl Error for DeleteUserPoolClientError {}
+impl ServiceErrorCode for DeleteUserPoolClientError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserPoolClientError::InternalError(_) => "InternalErrorException",
+            DeleteUserPoolClientError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteUserPoolClientError::NotAuthorized(_) => "NotAuthorizedException",
+            DeleteUserPoolClientError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteUserPoolClientError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteUserPoolDomain
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserPoolDomainError {
@@ -8124,6 +8845,16 @@ impl fmt::Display for DeleteUserPoolDomainError {
     }
 }
 impl Error for DeleteUserPoolDomainError {}
+impl ServiceErrorCode for DeleteUserPoolDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserPoolDomainError::InternalError(_) => "InternalErrorException",
+            DeleteUserPoolDomainError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteUserPoolDomainError::NotAuthorized(_) => "NotAuthorizedException",
+            DeleteUserPoolDomainError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeIdentityProvider
 #[derive(Debug, PartialEq)]
 pub enum DescribeIdentityProviderError {
@@ -8188,6 +8919,17 @@ impl fmt::Display for DescribeIdentityProviderError {
     }
 }
 impl Error for DescribeIdentityProviderError {}
+impl ServiceErrorCode for DescribeIdentityProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeIdentityProviderError::InternalError(_) => "InternalErrorException",
+            DescribeIdentityProviderError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeIdentityProviderError::NotAuthorized(_) => "NotAuthorizedException",
+            DescribeIdentityProviderError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeIdentityProviderError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeResourceServer
 #[derive(Debug, PartialEq)]
 pub enum DescribeResourceServerError {
@@ -8252,6 +8994,17 @@ impl fmt::Display for DescribeResourceServerError {
     }
 }
 impl Error for DescribeResourceServerError {}
+impl ServiceErrorCode for DescribeResourceServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeResourceServerError::InternalError(_) => "InternalErrorException",
+            DescribeResourceServerError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeResourceServerError::NotAuthorized(_) => "NotAuthorizedException",
+            DescribeResourceServerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeResourceServerError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeRiskConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribeRiskConfigurationError {
@@ -8326,6 +9079,20 @@ impl fmt::Display for DescribeRiskConfigurationError {
     }
 }
 impl Error for DescribeRiskConfigurationError {}
+impl ServiceErrorCode for DescribeRiskConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRiskConfigurationError::InternalError(_) => "InternalErrorException",
+            DescribeRiskConfigurationError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeRiskConfigurationError::NotAuthorized(_) => "NotAuthorizedException",
+            DescribeRiskConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeRiskConfigurationError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribeRiskConfigurationError::UserPoolAddOnNotEnabled(_) => {
+                "UserPoolAddOnNotEnabledException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeUserImportJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserImportJobError {
@@ -8386,6 +9153,17 @@ impl fmt::Display for DescribeUserImportJobError {
     }
 }
 impl Error for DescribeUserImportJobError {}
+impl ServiceErrorCode for DescribeUserImportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserImportJobError::InternalError(_) => "InternalErrorException",
+            DescribeUserImportJobError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeUserImportJobError::NotAuthorized(_) => "NotAuthorizedException",
+            DescribeUserImportJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeUserImportJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeUserPool
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserPoolError {
@@ -8446,6 +9224,18 @@ impl fmt::Display for DescribeUserPoolError {
     }
 }
 impl Error for DescribeUserPoolError {}
+impl ServiceErrorCode for DescribeUserPoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserPoolError::InternalError(_) => "InternalErrorException",
+            DescribeUserPoolError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeUserPoolError::NotAuthorized(_) => "NotAuthorizedException",
+            DescribeUserPoolError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeUserPoolError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribeUserPoolError::UserPoolTagging(_) => "UserPoolTaggingException",
+        }
+    }
+}
 /// Errors returned by DescribeUserPoolClient
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserPoolClientError {
@@ -8510,6 +9300,17 @@ impl fmt::Display for DescribeUserPoolClientError {
     }
 }
 impl Error for DescribeUserPoolClientError {}
+impl ServiceErrorCode for DescribeUserPoolClientError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserPoolClientError::InternalError(_) => "InternalErrorException",
+            DescribeUserPoolClientError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeUserPoolClientError::NotAuthorized(_) => "NotAuthorizedException",
+            DescribeUserPoolClientError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeUserPoolClientError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeUserPoolDomain
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserPoolDomainError {
@@ -8566,6 +9367,16 @@ impl fmt::Display for DescribeUserPoolDomainError {
     }
 }
 impl Error for DescribeUserPoolDomainError {}
+impl ServiceErrorCode for DescribeUserPoolDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserPoolDomainError::InternalError(_) => "InternalErrorException",
+            DescribeUserPoolDomainError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeUserPoolDomainError::NotAuthorized(_) => "NotAuthorizedException",
+            DescribeUserPoolDomainError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ForgetDevice
 #[derive(Debug, PartialEq)]
 pub enum ForgetDeviceError {
@@ -8646,6 +9457,23 @@ impl fmt::Display for ForgetDeviceError {
     }
 }
 impl Error for ForgetDeviceError {}
+impl ServiceErrorCode for ForgetDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ForgetDeviceError::InternalError(_) => "InternalErrorException",
+            ForgetDeviceError::InvalidParameter(_) => "InvalidParameterException",
+            ForgetDeviceError::InvalidUserPoolConfiguration(_) => {
+                "InvalidUserPoolConfigurationException"
+            }
+            ForgetDeviceError::NotAuthorized(_) => "NotAuthorizedException",
+            ForgetDeviceError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            ForgetDeviceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ForgetDeviceError::TooManyRequests(_) => "TooManyRequestsException",
+            ForgetDeviceError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            ForgetDeviceError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by ForgotPassword
 #[derive(Debug, PartialEq)]
 pub enum ForgotPasswordError {
@@ -8770,6 +9598,33 @@ impl fmt::Display for ForgotPasswordError {
     }
 }
 impl Error for ForgotPasswordError {}
+impl ServiceErrorCode for ForgotPasswordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ForgotPasswordError::CodeDeliveryFailure(_) => "CodeDeliveryFailureException",
+            ForgotPasswordError::InternalError(_) => "InternalErrorException",
+            ForgotPasswordError::InvalidEmailRoleAccessPolicy(_) => {
+                "InvalidEmailRoleAccessPolicyException"
+            }
+            ForgotPasswordError::InvalidLambdaResponse(_) => "InvalidLambdaResponseException",
+            ForgotPasswordError::InvalidParameter(_) => "InvalidParameterException",
+            ForgotPasswordError::InvalidSmsRoleAccessPolicy(_) => {
+                "InvalidSmsRoleAccessPolicyException"
+            }
+            ForgotPasswordError::InvalidSmsRoleTrustRelationship(_) => {
+                "InvalidSmsRoleTrustRelationshipException"
+            }
+            ForgotPasswordError::LimitExceeded(_) => "LimitExceededException",
+            ForgotPasswordError::NotAuthorized(_) => "NotAuthorizedException",
+            ForgotPasswordError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ForgotPasswordError::TooManyRequests(_) => "TooManyRequestsException",
+            ForgotPasswordError::UnexpectedLambda(_) => "UnexpectedLambdaException",
+            ForgotPasswordError::UserLambdaValidation(_) => "UserLambdaValidationException",
+            ForgotPasswordError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            ForgotPasswordError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetCSVHeader
 #[derive(Debug, PartialEq)]
 pub enum GetCSVHeaderError {
@@ -8824,6 +9679,17 @@ impl fmt::Display for GetCSVHeaderError {
     }
 }
 impl Error for GetCSVHeaderError {}
+impl ServiceErrorCode for GetCSVHeaderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCSVHeaderError::InternalError(_) => "InternalErrorException",
+            GetCSVHeaderError::InvalidParameter(_) => "InvalidParameterException",
+            GetCSVHeaderError::NotAuthorized(_) => "NotAuthorizedException",
+            GetCSVHeaderError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetCSVHeaderError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetDevice
 #[derive(Debug, PartialEq)]
 pub enum GetDeviceError {
@@ -8904,6 +9770,23 @@ impl fmt::Display for GetDeviceError {
     }
 }
 impl Error for GetDeviceError {}
+impl ServiceErrorCode for GetDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeviceError::InternalError(_) => "InternalErrorException",
+            GetDeviceError::InvalidParameter(_) => "InvalidParameterException",
+            GetDeviceError::InvalidUserPoolConfiguration(_) => {
+                "InvalidUserPoolConfigurationException"
+            }
+            GetDeviceError::NotAuthorized(_) => "NotAuthorizedException",
+            GetDeviceError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            GetDeviceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetDeviceError::TooManyRequests(_) => "TooManyRequestsException",
+            GetDeviceError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            GetDeviceError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetGroup
 #[derive(Debug, PartialEq)]
 pub enum GetGroupError {
@@ -8958,6 +9841,17 @@ impl fmt::Display for GetGroupError {
     }
 }
 impl Error for GetGroupError {}
+impl ServiceErrorCode for GetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGroupError::InternalError(_) => "InternalErrorException",
+            GetGroupError::InvalidParameter(_) => "InvalidParameterException",
+            GetGroupError::NotAuthorized(_) => "NotAuthorizedException",
+            GetGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetGroupError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetIdentityProviderByIdentifier
 #[derive(Debug, PartialEq)]
 pub enum GetIdentityProviderByIdentifierError {
@@ -9034,6 +9928,21 @@ impl fmt::Display for GetIdentityProviderByIdentifierError {
     }
 }
 impl Error for GetIdentityProviderByIdentifierError {}
+impl ServiceErrorCode for GetIdentityProviderByIdentifierError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIdentityProviderByIdentifierError::InternalError(_) => "InternalErrorException",
+            GetIdentityProviderByIdentifierError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            GetIdentityProviderByIdentifierError::NotAuthorized(_) => "NotAuthorizedException",
+            GetIdentityProviderByIdentifierError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            GetIdentityProviderByIdentifierError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetSigningCertificate
 #[derive(Debug, PartialEq)]
 pub enum GetSigningCertificateError {
@@ -9080,6 +9989,15 @@ impl fmt::Display for GetSigningCertificateError {
     }
 }
 impl Error for GetSigningCertificateError {}
+impl ServiceErrorCode for GetSigningCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSigningCertificateError::InternalError(_) => "InternalErrorException",
+            GetSigningCertificateError::InvalidParameter(_) => "InvalidParameterException",
+            GetSigningCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetUICustomization
 #[derive(Debug, PartialEq)]
 pub enum GetUICustomizationError {
@@ -9134,6 +10052,17 @@ impl fmt::Display for GetUICustomizationError {
     }
 }
 impl Error for GetUICustomizationError {}
+impl ServiceErrorCode for GetUICustomizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUICustomizationError::InternalError(_) => "InternalErrorException",
+            GetUICustomizationError::InvalidParameter(_) => "InvalidParameterException",
+            GetUICustomizationError::NotAuthorized(_) => "NotAuthorizedException",
+            GetUICustomizationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetUICustomizationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetUser
 #[derive(Debug, PartialEq)]
 pub enum GetUserError {
@@ -9206,6 +10135,20 @@ impl fmt::Display for GetUserError {
     }
 }
 impl Error for GetUserError {}
+impl ServiceErrorCode for GetUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUserError::InternalError(_) => "InternalErrorException",
+            GetUserError::InvalidParameter(_) => "InvalidParameterException",
+            GetUserError::NotAuthorized(_) => "NotAuthorizedException",
+            GetUserError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            GetUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetUserError::TooManyRequests(_) => "TooManyRequestsException",
+            GetUserError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            GetUserError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetUserAttributeVerificationCode
 #[derive(Debug, PartialEq)]
 pub enum GetUserAttributeVerificationCodeError {
@@ -9396,6 +10339,50 @@ impl fmt::Display for GetUserAttributeVerificationCodeError {
     }
 }
 impl Error for GetUserAttributeVerificationCodeError {}
+impl ServiceErrorCode for GetUserAttributeVerificationCodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUserAttributeVerificationCodeError::CodeDeliveryFailure(_) => {
+                "CodeDeliveryFailureException"
+            }
+            GetUserAttributeVerificationCodeError::InternalError(_) => "InternalErrorException",
+            GetUserAttributeVerificationCodeError::InvalidEmailRoleAccessPolicy(_) => {
+                "InvalidEmailRoleAccessPolicyException"
+            }
+            GetUserAttributeVerificationCodeError::InvalidLambdaResponse(_) => {
+                "InvalidLambdaResponseException"
+            }
+            GetUserAttributeVerificationCodeError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            GetUserAttributeVerificationCodeError::InvalidSmsRoleAccessPolicy(_) => {
+                "InvalidSmsRoleAccessPolicyException"
+            }
+            GetUserAttributeVerificationCodeError::InvalidSmsRoleTrustRelationship(_) => {
+                "InvalidSmsRoleTrustRelationshipException"
+            }
+            GetUserAttributeVerificationCodeError::LimitExceeded(_) => "LimitExceededException",
+            GetUserAttributeVerificationCodeError::NotAuthorized(_) => "NotAuthorizedException",
+            GetUserAttributeVerificationCodeError::PasswordResetRequired(_) => {
+                "PasswordResetRequiredException"
+            }
+            GetUserAttributeVerificationCodeError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            GetUserAttributeVerificationCodeError::TooManyRequests(_) => "TooManyRequestsException",
+            GetUserAttributeVerificationCodeError::UnexpectedLambda(_) => {
+                "UnexpectedLambdaException"
+            }
+            GetUserAttributeVerificationCodeError::UserLambdaValidation(_) => {
+                "UserLambdaValidationException"
+            }
+            GetUserAttributeVerificationCodeError::UserNotConfirmed(_) => {
+                "UserNotConfirmedException"
+            }
+            GetUserAttributeVerificationCodeError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetUserPoolMfaConfig
 #[derive(Debug, PartialEq)]
 pub enum GetUserPoolMfaConfigError {
@@ -9456,6 +10443,17 @@ impl fmt::Display for GetUserPoolMfaConfigError {
     }
 }
 impl Error for GetUserPoolMfaConfigError {}
+impl ServiceErrorCode for GetUserPoolMfaConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUserPoolMfaConfigError::InternalError(_) => "InternalErrorException",
+            GetUserPoolMfaConfigError::InvalidParameter(_) => "InvalidParameterException",
+            GetUserPoolMfaConfigError::NotAuthorized(_) => "NotAuthorizedException",
+            GetUserPoolMfaConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetUserPoolMfaConfigError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GlobalSignOut
 #[derive(Debug, PartialEq)]
 pub enum GlobalSignOutError {
@@ -9522,6 +10520,19 @@ impl fmt::Display for GlobalSignOutError {
     }
 }
 impl Error for GlobalSignOutError {}
+impl ServiceErrorCode for GlobalSignOutError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GlobalSignOutError::InternalError(_) => "InternalErrorException",
+            GlobalSignOutError::InvalidParameter(_) => "InvalidParameterException",
+            GlobalSignOutError::NotAuthorized(_) => "NotAuthorizedException",
+            GlobalSignOutError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            GlobalSignOutError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GlobalSignOutError::TooManyRequests(_) => "TooManyRequestsException",
+            GlobalSignOutError::UserNotConfirmed(_) => "UserNotConfirmedException",
+        }
+    }
+}
 /// Errors returned by InitiateAuth
 #[derive(Debug, PartialEq)]
 pub enum InitiateAuthError {
@@ -9636,6 +10647,32 @@ impl fmt::Display for InitiateAuthError {
     }
 }
 impl Error for InitiateAuthError {}
+impl ServiceErrorCode for InitiateAuthError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InitiateAuthError::InternalError(_) => "InternalErrorException",
+            InitiateAuthError::InvalidLambdaResponse(_) => "InvalidLambdaResponseException",
+            InitiateAuthError::InvalidParameter(_) => "InvalidParameterException",
+            InitiateAuthError::InvalidSmsRoleAccessPolicy(_) => {
+                "InvalidSmsRoleAccessPolicyException"
+            }
+            InitiateAuthError::InvalidSmsRoleTrustRelationship(_) => {
+                "InvalidSmsRoleTrustRelationshipException"
+            }
+            InitiateAuthError::InvalidUserPoolConfiguration(_) => {
+                "InvalidUserPoolConfigurationException"
+            }
+            InitiateAuthError::NotAuthorized(_) => "NotAuthorizedException",
+            InitiateAuthError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            InitiateAuthError::ResourceNotFound(_) => "ResourceNotFoundException",
+            InitiateAuthError::TooManyRequests(_) => "TooManyRequestsException",
+            InitiateAuthError::UnexpectedLambda(_) => "UnexpectedLambdaException",
+            InitiateAuthError::UserLambdaValidation(_) => "UserLambdaValidationException",
+            InitiateAuthError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            InitiateAuthError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListDevices
 #[derive(Debug, PartialEq)]
 pub enum ListDevicesError {
@@ -9716,6 +10753,23 @@ impl fmt::Display for ListDevicesError {
     }
 }
 impl Error for ListDevicesError {}
+impl ServiceErrorCode for ListDevicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDevicesError::InternalError(_) => "InternalErrorException",
+            ListDevicesError::InvalidParameter(_) => "InvalidParameterException",
+            ListDevicesError::InvalidUserPoolConfiguration(_) => {
+                "InvalidUserPoolConfigurationException"
+            }
+            ListDevicesError::NotAuthorized(_) => "NotAuthorizedException",
+            ListDevicesError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            ListDevicesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListDevicesError::TooManyRequests(_) => "TooManyRequestsException",
+            ListDevicesError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            ListDevicesError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListGroups
 #[derive(Debug, PartialEq)]
 pub enum ListGroupsError {
@@ -9770,6 +10824,17 @@ impl fmt::Display for ListGroupsError {
     }
 }
 impl Error for ListGroupsError {}
+impl ServiceErrorCode for ListGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGroupsError::InternalError(_) => "InternalErrorException",
+            ListGroupsError::InvalidParameter(_) => "InvalidParameterException",
+            ListGroupsError::NotAuthorized(_) => "NotAuthorizedException",
+            ListGroupsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListGroupsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListIdentityProviders
 #[derive(Debug, PartialEq)]
 pub enum ListIdentityProvidersError {
@@ -9830,6 +10895,17 @@ impl fmt::Display for ListIdentityProvidersError {
     }
 }
 impl Error for ListIdentityProvidersError {}
+impl ServiceErrorCode for ListIdentityProvidersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListIdentityProvidersError::InternalError(_) => "InternalErrorException",
+            ListIdentityProvidersError::InvalidParameter(_) => "InvalidParameterException",
+            ListIdentityProvidersError::NotAuthorized(_) => "NotAuthorizedException",
+            ListIdentityProvidersError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListIdentityProvidersError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListResourceServers
 #[derive(Debug, PartialEq)]
 pub enum ListResourceServersError {
@@ -9888,6 +10964,17 @@ impl fmt::Display for ListResourceServersError {
     }
 }
 impl Error for ListResourceServersError {}
+impl ServiceErrorCode for ListResourceServersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourceServersError::InternalError(_) => "InternalErrorException",
+            ListResourceServersError::InvalidParameter(_) => "InvalidParameterException",
+            ListResourceServersError::NotAuthorized(_) => "NotAuthorizedException",
+            ListResourceServersError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListResourceServersError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -9946,6 +11033,17 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalError(_) => "InternalErrorException",
+            ListTagsForResourceError::InvalidParameter(_) => "InvalidParameterException",
+            ListTagsForResourceError::NotAuthorized(_) => "NotAuthorizedException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListUserImportJobs
 #[derive(Debug, PartialEq)]
 pub enum ListUserImportJobsError {
@@ -10000,6 +11098,17 @@ impl fmt::Display for ListUserImportJobsError {
     }
 }
 impl Error for ListUserImportJobsError {}
+impl ServiceErrorCode for ListUserImportJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUserImportJobsError::InternalError(_) => "InternalErrorException",
+            ListUserImportJobsError::InvalidParameter(_) => "InvalidParameterException",
+            ListUserImportJobsError::NotAuthorized(_) => "NotAuthorizedException",
+            ListUserImportJobsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListUserImportJobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListUserPoolClients
 #[derive(Debug, PartialEq)]
 pub enum ListUserPoolClientsError {
@@ -10058,6 +11167,17 @@ impl fmt::Display for ListUserPoolClientsError {
     }
 }
 impl Error for ListUserPoolClientsError {}
+impl ServiceErrorCode for ListUserPoolClientsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUserPoolClientsError::InternalError(_) => "InternalErrorException",
+            ListUserPoolClientsError::InvalidParameter(_) => "InvalidParameterException",
+            ListUserPoolClientsError::NotAuthorized(_) => "NotAuthorizedException",
+            ListUserPoolClientsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListUserPoolClientsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListUserPools
 #[derive(Debug, PartialEq)]
 pub enum ListUserPoolsError {
@@ -10106,6 +11226,16 @@ impl fmt::Display for ListUserPoolsError {
     }
 }
 impl Error for ListUserPoolsError {}
+impl ServiceErrorCode for ListUserPoolsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUserPoolsError::InternalError(_) => "InternalErrorException",
+            ListUserPoolsError::InvalidParameter(_) => "InvalidParameterException",
+            ListUserPoolsError::NotAuthorized(_) => "NotAuthorizedException",
+            ListUserPoolsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListUsers
 #[derive(Debug, PartialEq)]
 pub enum ListUsersError {
@@ -10160,6 +11290,17 @@ impl fmt::Display for ListUsersError {
     }
 }
 impl Error for ListUsersError {}
+impl ServiceErrorCode for ListUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUsersError::InternalError(_) => "InternalErrorException",
+            ListUsersError::InvalidParameter(_) => "InvalidParameterException",
+            ListUsersError::NotAuthorized(_) => "NotAuthorizedException",
+            ListUsersError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListUsersError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListUsersInGroup
 #[derive(Debug, PartialEq)]
 pub enum ListUsersInGroupError {
@@ -10214,6 +11355,17 @@ impl fmt::Display for ListUsersInGroupError {
     }
 }
 impl Error for ListUsersInGroupError {}
+impl ServiceErrorCode for ListUsersInGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUsersInGroupError::InternalError(_) => "InternalErrorException",
+            ListUsersInGroupError::InvalidParameter(_) => "InvalidParameterException",
+            ListUsersInGroupError::NotAuthorized(_) => "NotAuthorizedException",
+            ListUsersInGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListUsersInGroupError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ResendConfirmationCode
 #[derive(Debug, PartialEq)]
 pub enum ResendConfirmationCodeError {
@@ -10354,6 +11506,34 @@ impl fmt::Display for ResendConfirmationCodeError {
     }
 }
 impl Error for ResendConfirmationCodeError {}
+impl ServiceErrorCode for ResendConfirmationCodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResendConfirmationCodeError::CodeDeliveryFailure(_) => "CodeDeliveryFailureException",
+            ResendConfirmationCodeError::InternalError(_) => "InternalErrorException",
+            ResendConfirmationCodeError::InvalidEmailRoleAccessPolicy(_) => {
+                "InvalidEmailRoleAccessPolicyException"
+            }
+            ResendConfirmationCodeError::InvalidLambdaResponse(_) => {
+                "InvalidLambdaResponseException"
+            }
+            ResendConfirmationCodeError::InvalidParameter(_) => "InvalidParameterException",
+            ResendConfirmationCodeError::InvalidSmsRoleAccessPolicy(_) => {
+                "InvalidSmsRoleAccessPolicyException"
+            }
+            ResendConfirmationCodeError::InvalidSmsRoleTrustRelationship(_) => {
+                "InvalidSmsRoleTrustRelationshipException"
+            }
+            ResendConfirmationCodeError::LimitExceeded(_) => "LimitExceededException",
+            ResendConfirmationCodeError::NotAuthorized(_) => "NotAuthorizedException",
+            ResendConfirmationCodeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ResendConfirmationCodeError::TooManyRequests(_) => "TooManyRequestsException",
+            ResendConfirmationCodeError::UnexpectedLambda(_) => "UnexpectedLambdaException",
+            ResendConfirmationCodeError::UserLambdaValidation(_) => "UserLambdaValidationException",
+            ResendConfirmationCodeError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by RespondToAuthChallenge
 #[derive(Debug, PartialEq)]
 pub enum RespondToAuthChallengeError {
@@ -10538,6 +11718,44 @@ impl fmt::Display for RespondToAuthChallengeError {
     }
 }
 impl Error for RespondToAuthChallengeError {}
+impl ServiceErrorCode for RespondToAuthChallengeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RespondToAuthChallengeError::AliasExists(_) => "AliasExistsException",
+            RespondToAuthChallengeError::CodeMismatch(_) => "CodeMismatchException",
+            RespondToAuthChallengeError::ExpiredCode(_) => "ExpiredCodeException",
+            RespondToAuthChallengeError::InternalError(_) => "InternalErrorException",
+            RespondToAuthChallengeError::InvalidLambdaResponse(_) => {
+                "InvalidLambdaResponseException"
+            }
+            RespondToAuthChallengeError::InvalidParameter(_) => "InvalidParameterException",
+            RespondToAuthChallengeError::InvalidPassword(_) => "InvalidPasswordException",
+            RespondToAuthChallengeError::InvalidSmsRoleAccessPolicy(_) => {
+                "InvalidSmsRoleAccessPolicyException"
+            }
+            RespondToAuthChallengeError::InvalidSmsRoleTrustRelationship(_) => {
+                "InvalidSmsRoleTrustRelationshipException"
+            }
+            RespondToAuthChallengeError::InvalidUserPoolConfiguration(_) => {
+                "InvalidUserPoolConfigurationException"
+            }
+            RespondToAuthChallengeError::MFAMethodNotFound(_) => "MFAMethodNotFoundException",
+            RespondToAuthChallengeError::NotAuthorized(_) => "NotAuthorizedException",
+            RespondToAuthChallengeError::PasswordResetRequired(_) => {
+                "PasswordResetRequiredException"
+            }
+            RespondToAuthChallengeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RespondToAuthChallengeError::SoftwareTokenMFANotFound(_) => {
+                "SoftwareTokenMFANotFoundException"
+            }
+            RespondToAuthChallengeError::TooManyRequests(_) => "TooManyRequestsException",
+            RespondToAuthChallengeError::UnexpectedLambda(_) => "UnexpectedLambdaException",
+            RespondToAuthChallengeError::UserLambdaValidation(_) => "UserLambdaValidationException",
+            RespondToAuthChallengeError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            RespondToAuthChallengeError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by SetRiskConfiguration
 #[derive(Debug, PartialEq)]
 pub enum SetRiskConfigurationError {
@@ -10624,6 +11842,24 @@ impl fmt::Display for SetRiskConfigurationError {
     }
 }
 impl Error for SetRiskConfigurationError {}
+impl ServiceErrorCode for SetRiskConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetRiskConfigurationError::CodeDeliveryFailure(_) => "CodeDeliveryFailureException",
+            SetRiskConfigurationError::InternalError(_) => "InternalErrorException",
+            SetRiskConfigurationError::InvalidEmailRoleAccessPolicy(_) => {
+                "InvalidEmailRoleAccessPolicyException"
+            }
+            SetRiskConfigurationError::InvalidParameter(_) => "InvalidParameterException",
+            SetRiskConfigurationError::NotAuthorized(_) => "NotAuthorizedException",
+            SetRiskConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SetRiskConfigurationError::TooManyRequests(_) => "TooManyRequestsException",
+            SetRiskConfigurationError::UserPoolAddOnNotEnabled(_) => {
+                "UserPoolAddOnNotEnabledException"
+            }
+        }
+    }
+}
 /// Errors returned by SetUICustomization
 #[derive(Debug, PartialEq)]
 pub enum SetUICustomizationError {
@@ -10678,6 +11914,17 @@ impl fmt::Display for SetUICustomizationError {
     }
 }
 impl Error for SetUICustomizationError {}
+impl ServiceErrorCode for SetUICustomizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetUICustomizationError::InternalError(_) => "InternalErrorException",
+            SetUICustomizationError::InvalidParameter(_) => "InvalidParameterException",
+            SetUICustomizationError::NotAuthorized(_) => "NotAuthorizedException",
+            SetUICustomizationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SetUICustomizationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by SetUserMFAPreference
 #[derive(Debug, PartialEq)]
 pub enum SetUserMFAPreferenceError {
@@ -10752,6 +11999,19 @@ impl fmt::Display for SetUserMFAPreferenceError {
     }
 }
 impl Error for SetUserMFAPreferenceError {}
+impl ServiceErrorCode for SetUserMFAPreferenceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetUserMFAPreferenceError::InternalError(_) => "InternalErrorException",
+            SetUserMFAPreferenceError::InvalidParameter(_) => "InvalidParameterException",
+            SetUserMFAPreferenceError::NotAuthorized(_) => "NotAuthorizedException",
+            SetUserMFAPreferenceError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            SetUserMFAPreferenceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SetUserMFAPreferenceError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            SetUserMFAPreferenceError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by SetUserPoolMfaConfig
 #[derive(Debug, PartialEq)]
 pub enum SetUserPoolMfaConfigError {
@@ -10832,6 +12092,23 @@ impl fmt::Display for SetUserPoolMfaConfigError {
     }
 }
 impl Error for SetUserPoolMfaConfigError {}
+impl ServiceErrorCode for SetUserPoolMfaConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetUserPoolMfaConfigError::InternalError(_) => "InternalErrorException",
+            SetUserPoolMfaConfigError::InvalidParameter(_) => "InvalidParameterException",
+            SetUserPoolMfaConfigError::InvalidSmsRoleAccessPolicy(_) => {
+                "InvalidSmsRoleAccessPolicyException"
+            }
+            SetUserPoolMfaConfigError::InvalidSmsRoleTrustRelationship(_) => {
+                "InvalidSmsRoleTrustRelationshipException"
+            }
+            SetUserPoolMfaConfigError::NotAuthorized(_) => "NotAuthorizedException",
+            SetUserPoolMfaConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SetUserPoolMfaConfigError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by SetUserSettings
 #[derive(Debug, PartialEq)]
 pub enum SetUserSettingsError {
@@ -10900,6 +12177,19 @@ impl fmt::Display for SetUserSettingsError {
     }
 }
 impl Error for SetUserSettingsError {}
+impl ServiceErrorCode for SetUserSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetUserSettingsError::InternalError(_) => "InternalErrorException",
+            SetUserSettingsError::InvalidParameter(_) => "InvalidParameterException",
+            SetUserSettingsError::NotAuthorized(_) => "NotAuthorizedException",
+            SetUserSettingsError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            SetUserSettingsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SetUserSettingsError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            SetUserSettingsError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by SignUp
 #[derive(Debug, PartialEq)]
 pub enum SignUpError {
@@ -11010,6 +12300,28 @@ impl fmt::Display for SignUpError {
     }
 }
 impl Error for SignUpError {}
+impl ServiceErrorCode for SignUpError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SignUpError::CodeDeliveryFailure(_) => "CodeDeliveryFailureException",
+            SignUpError::InternalError(_) => "InternalErrorException",
+            SignUpError::InvalidEmailRoleAccessPolicy(_) => "InvalidEmailRoleAccessPolicyException",
+            SignUpError::InvalidLambdaResponse(_) => "InvalidLambdaResponseException",
+            SignUpError::InvalidParameter(_) => "InvalidParameterException",
+            SignUpError::InvalidPassword(_) => "InvalidPasswordException",
+            SignUpError::InvalidSmsRoleAccessPolicy(_) => "InvalidSmsRoleAccessPolicyException",
+            SignUpError::InvalidSmsRoleTrustRelationship(_) => {
+                "InvalidSmsRoleTrustRelationshipException"
+            }
+            SignUpError::NotAuthorized(_) => "NotAuthorizedException",
+            SignUpError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SignUpError::TooManyRequests(_) => "TooManyRequestsException",
+            SignUpError::UnexpectedLambda(_) => "UnexpectedLambdaException",
+            SignUpError::UserLambdaValidation(_) => "UserLambdaValidationException",
+            SignUpError::UsernameExists(_) => "UsernameExistsException",
+        }
+    }
+}
 /// Errors returned by StartUserImportJob
 #[derive(Debug, PartialEq)]
 pub enum StartUserImportJobError {
@@ -11072,6 +12384,18 @@ impl fmt::Display for StartUserImportJobError {
     }
 }
 impl Error for StartUserImportJobError {}
+impl ServiceErrorCode for StartUserImportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartUserImportJobError::InternalError(_) => "InternalErrorException",
+            StartUserImportJobError::InvalidParameter(_) => "InvalidParameterException",
+            StartUserImportJobError::NotAuthorized(_) => "NotAuthorizedException",
+            StartUserImportJobError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            StartUserImportJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartUserImportJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StopUserImportJob
 #[derive(Debug, PartialEq)]
 pub enum StopUserImportJobError {
@@ -11134,6 +12458,18 @@ impl fmt::Display for StopUserImportJobError {
     }
 }
 impl Error for StopUserImportJobError {}
+impl ServiceErrorCode for StopUserImportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopUserImportJobError::InternalError(_) => "InternalErrorException",
+            StopUserImportJobError::InvalidParameter(_) => "InvalidParameterException",
+            StopUserImportJobError::NotAuthorized(_) => "NotAuthorizedException",
+            StopUserImportJobError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            StopUserImportJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StopUserImportJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -11188,6 +12524,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalError(_) => "InternalErrorException",
+            TagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            TagResourceError::NotAuthorized(_) => "NotAuthorizedException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -11242,6 +12589,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalError(_) => "InternalErrorException",
+            UntagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            UntagResourceError::NotAuthorized(_) => "NotAuthorizedException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateAuthEventFeedback
 #[derive(Debug, PartialEq)]
 pub enum UpdateAuthEventFeedbackError {
@@ -11324,6 +12682,21 @@ impl fmt::Display for UpdateAuthEventFeedbackError {
     }
 }
 impl Error for UpdateAuthEventFeedbackError {}
+impl ServiceErrorCode for UpdateAuthEventFeedbackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAuthEventFeedbackError::InternalError(_) => "InternalErrorException",
+            UpdateAuthEventFeedbackError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateAuthEventFeedbackError::NotAuthorized(_) => "NotAuthorizedException",
+            UpdateAuthEventFeedbackError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateAuthEventFeedbackError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateAuthEventFeedbackError::UserNotFound(_) => "UserNotFoundException",
+            UpdateAuthEventFeedbackError::UserPoolAddOnNotEnabled(_) => {
+                "UserPoolAddOnNotEnabledException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateDeviceStatus
 #[derive(Debug, PartialEq)]
 pub enum UpdateDeviceStatusError {
@@ -11408,6 +12781,23 @@ impl fmt::Display for UpdateDeviceStatusError {
     }
 }
 impl Error for UpdateDeviceStatusError {}
+impl ServiceErrorCode for UpdateDeviceStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDeviceStatusError::InternalError(_) => "InternalErrorException",
+            UpdateDeviceStatusError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateDeviceStatusError::InvalidUserPoolConfiguration(_) => {
+                "InvalidUserPoolConfigurationException"
+            }
+            UpdateDeviceStatusError::NotAuthorized(_) => "NotAuthorizedException",
+            UpdateDeviceStatusError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            UpdateDeviceStatusError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDeviceStatusError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateDeviceStatusError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            UpdateDeviceStatusError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateGroupError {
@@ -11462,6 +12852,17 @@ impl fmt::Display for UpdateGroupError {
     }
 }
 impl Error for UpdateGroupError {}
+impl ServiceErrorCode for UpdateGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGroupError::InternalError(_) => "InternalErrorException",
+            UpdateGroupError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateGroupError::NotAuthorized(_) => "NotAuthorizedException",
+            UpdateGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateGroupError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateIdentityProvider
 #[derive(Debug, PartialEq)]
 pub enum UpdateIdentityProviderError {
@@ -11536,6 +12937,20 @@ impl fmt::Display for UpdateIdentityProviderError {
     }
 }
 impl Error for UpdateIdentityProviderError {}
+impl ServiceErrorCode for UpdateIdentityProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateIdentityProviderError::InternalError(_) => "InternalErrorException",
+            UpdateIdentityProviderError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateIdentityProviderError::NotAuthorized(_) => "NotAuthorizedException",
+            UpdateIdentityProviderError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateIdentityProviderError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateIdentityProviderError::UnsupportedIdentityProvider(_) => {
+                "UnsupportedIdentityProviderException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateResourceServer
 #[derive(Debug, PartialEq)]
 pub enum UpdateResourceServerError {
@@ -11596,6 +13011,17 @@ impl fmt::Display for UpdateResourceServerError {
     }
 }
 impl Error for UpdateResourceServerError {}
+impl ServiceErrorCode for UpdateResourceServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateResourceServerError::InternalError(_) => "InternalErrorException",
+            UpdateResourceServerError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateResourceServerError::NotAuthorized(_) => "NotAuthorizedException",
+            UpdateResourceServerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateResourceServerError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateUserAttributes
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserAttributesError {
@@ -11758,6 +13184,36 @@ impl fmt::Display for UpdateUserAttributesError {
     }
 }
 impl Error for UpdateUserAttributesError {}
+impl ServiceErrorCode for UpdateUserAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserAttributesError::AliasExists(_) => "AliasExistsException",
+            UpdateUserAttributesError::CodeDeliveryFailure(_) => "CodeDeliveryFailureException",
+            UpdateUserAttributesError::CodeMismatch(_) => "CodeMismatchException",
+            UpdateUserAttributesError::ExpiredCode(_) => "ExpiredCodeException",
+            UpdateUserAttributesError::InternalError(_) => "InternalErrorException",
+            UpdateUserAttributesError::InvalidEmailRoleAccessPolicy(_) => {
+                "InvalidEmailRoleAccessPolicyException"
+            }
+            UpdateUserAttributesError::InvalidLambdaResponse(_) => "InvalidLambdaResponseException",
+            UpdateUserAttributesError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateUserAttributesError::InvalidSmsRoleAccessPolicy(_) => {
+                "InvalidSmsRoleAccessPolicyException"
+            }
+            UpdateUserAttributesError::InvalidSmsRoleTrustRelationship(_) => {
+                "InvalidSmsRoleTrustRelationshipException"
+            }
+            UpdateUserAttributesError::NotAuthorized(_) => "NotAuthorizedException",
+            UpdateUserAttributesError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            UpdateUserAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateUserAttributesError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateUserAttributesError::UnexpectedLambda(_) => "UnexpectedLambdaException",
+            UpdateUserAttributesError::UserLambdaValidation(_) => "UserLambdaValidationException",
+            UpdateUserAttributesError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            UpdateUserAttributesError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateUserPool
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserPoolError {
@@ -11858,6 +13314,29 @@ impl fmt::Display for UpdateUserPoolError {
     }
 }
 impl Error for UpdateUserPoolError {}
+impl ServiceErrorCode for UpdateUserPoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserPoolError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateUserPoolError::InternalError(_) => "InternalErrorException",
+            UpdateUserPoolError::InvalidEmailRoleAccessPolicy(_) => {
+                "InvalidEmailRoleAccessPolicyException"
+            }
+            UpdateUserPoolError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateUserPoolError::InvalidSmsRoleAccessPolicy(_) => {
+                "InvalidSmsRoleAccessPolicyException"
+            }
+            UpdateUserPoolError::InvalidSmsRoleTrustRelationship(_) => {
+                "InvalidSmsRoleTrustRelationshipException"
+            }
+            UpdateUserPoolError::NotAuthorized(_) => "NotAuthorizedException",
+            UpdateUserPoolError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateUserPoolError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateUserPoolError::UserImportInProgress(_) => "UserImportInProgressException",
+            UpdateUserPoolError::UserPoolTagging(_) => "UserPoolTaggingException",
+        }
+    }
+}
 /// Errors returned by UpdateUserPoolClient
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserPoolClientError {
@@ -11942,6 +13421,22 @@ impl fmt::Display for UpdateUserPoolClientError {
     }
 }
 impl Error for UpdateUserPoolClientError {}
+impl ServiceErrorCode for UpdateUserPoolClientError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserPoolClientError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            UpdateUserPoolClientError::InternalError(_) => "InternalErrorException",
+            UpdateUserPoolClientError::InvalidOAuthFlow(_) => "InvalidOAuthFlowException",
+            UpdateUserPoolClientError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateUserPoolClientError::NotAuthorized(_) => "NotAuthorizedException",
+            UpdateUserPoolClientError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateUserPoolClientError::ScopeDoesNotExist(_) => "ScopeDoesNotExistException",
+            UpdateUserPoolClientError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateUserPoolDomain
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserPoolDomainError {
@@ -12002,6 +13497,17 @@ impl fmt::Display for UpdateUserPoolDomainError {
     }
 }
 impl Error for UpdateUserPoolDomainError {}
+impl ServiceErrorCode for UpdateUserPoolDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserPoolDomainError::InternalError(_) => "InternalErrorException",
+            UpdateUserPoolDomainError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateUserPoolDomainError::NotAuthorized(_) => "NotAuthorizedException",
+            UpdateUserPoolDomainError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateUserPoolDomainError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by VerifySoftwareToken
 #[derive(Debug, PartialEq)]
 pub enum VerifySoftwareTokenError {
@@ -12114,6 +13620,30 @@ impl fmt::Display for VerifySoftwareTokenError {
     }
 }
 impl Error for VerifySoftwareTokenError {}
+impl ServiceErrorCode for VerifySoftwareTokenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            VerifySoftwareTokenError::CodeMismatch(_) => "CodeMismatchException",
+            VerifySoftwareTokenError::EnableSoftwareTokenMFA(_) => {
+                "EnableSoftwareTokenMFAException"
+            }
+            VerifySoftwareTokenError::InternalError(_) => "InternalErrorException",
+            VerifySoftwareTokenError::InvalidParameter(_) => "InvalidParameterException",
+            VerifySoftwareTokenError::InvalidUserPoolConfiguration(_) => {
+                "InvalidUserPoolConfigurationException"
+            }
+            VerifySoftwareTokenError::NotAuthorized(_) => "NotAuthorizedException",
+            VerifySoftwareTokenError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            VerifySoftwareTokenError::ResourceNotFound(_) => "ResourceNotFoundException",
+            VerifySoftwareTokenError::SoftwareTokenMFANotFound(_) => {
+                "SoftwareTokenMFANotFoundException"
+            }
+            VerifySoftwareTokenError::TooManyRequests(_) => "TooManyRequestsException",
+            VerifySoftwareTokenError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            VerifySoftwareTokenError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by VerifyUserAttribute
 #[derive(Debug, PartialEq)]
 pub enum VerifyUserAttributeError {
@@ -12212,6 +13742,23 @@ impl fmt::Display for VerifyUserAttributeError {
     }
 }
 impl Error for VerifyUserAttributeError {}
+impl ServiceErrorCode for VerifyUserAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            VerifyUserAttributeError::CodeMismatch(_) => "CodeMismatchException",
+            VerifyUserAttributeError::ExpiredCode(_) => "ExpiredCodeException",
+            VerifyUserAttributeError::InternalError(_) => "InternalErrorException",
+            VerifyUserAttributeError::InvalidParameter(_) => "InvalidParameterException",
+            VerifyUserAttributeError::LimitExceeded(_) => "LimitExceededException",
+            VerifyUserAttributeError::NotAuthorized(_) => "NotAuthorizedException",
+            VerifyUserAttributeError::PasswordResetRequired(_) => "PasswordResetRequiredException",
+            VerifyUserAttributeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            VerifyUserAttributeError::TooManyRequests(_) => "TooManyRequestsException",
+            VerifyUserAttributeError::UserNotConfirmed(_) => "UserNotConfirmedException",
+            VerifyUserAttributeError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Cognito Identity Provider API. Amazon Cognito Identity Provider clients implement this trait.
 #[async_trait]
 pub trait CognitoIdentityProvider {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -749,6 +749,18 @@ impl fmt::Display for BulkPublishError {
     }
 }
 impl Error for BulkPublishError {}
+impl ServiceErrorCode for BulkPublishError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BulkPublishError::AlreadyStreamed(_) => "AlreadyStreamedException",
+            BulkPublishError::DuplicateRequest(_) => "DuplicateRequestException",
+            BulkPublishError::InternalError(_) => "InternalErrorException",
+            BulkPublishError::InvalidParameter(_) => "InvalidParameterException",
+            BulkPublishError::NotAuthorized(_) => "NotAuthorizedException",
+            BulkPublishError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteDataset
 #[derive(Debug, PartialEq)]
 pub enum DeleteDatasetError {
@@ -809,6 +821,18 @@ impl fmt::Display for DeleteDatasetError {
     }
 }
 impl Error for DeleteDatasetError {}
+impl ServiceErrorCode for DeleteDatasetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDatasetError::InternalError(_) => "InternalErrorException",
+            DeleteDatasetError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteDatasetError::NotAuthorized(_) => "NotAuthorizedException",
+            DeleteDatasetError::ResourceConflict(_) => "ResourceConflictException",
+            DeleteDatasetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDatasetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeDataset
 #[derive(Debug, PartialEq)]
 pub enum DescribeDatasetError {
@@ -863,6 +887,17 @@ impl fmt::Display for DescribeDatasetError {
     }
 }
 impl Error for DescribeDatasetError {}
+impl ServiceErrorCode for DescribeDatasetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDatasetError::InternalError(_) => "InternalErrorException",
+            DescribeDatasetError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeDatasetError::NotAuthorized(_) => "NotAuthorizedException",
+            DescribeDatasetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDatasetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeIdentityPoolUsage
 #[derive(Debug, PartialEq)]
 pub enum DescribeIdentityPoolUsageError {
@@ -927,6 +962,17 @@ impl fmt::Display for DescribeIdentityPoolUsageError {
     }
 }
 impl Error for DescribeIdentityPoolUsageError {}
+impl ServiceErrorCode for DescribeIdentityPoolUsageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeIdentityPoolUsageError::InternalError(_) => "InternalErrorException",
+            DescribeIdentityPoolUsageError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeIdentityPoolUsageError::NotAuthorized(_) => "NotAuthorizedException",
+            DescribeIdentityPoolUsageError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeIdentityPoolUsageError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeIdentityUsage
 #[derive(Debug, PartialEq)]
 pub enum DescribeIdentityUsageError {
@@ -987,6 +1033,17 @@ impl fmt::Display for DescribeIdentityUsageError {
     }
 }
 impl Error for DescribeIdentityUsageError {}
+impl ServiceErrorCode for DescribeIdentityUsageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeIdentityUsageError::InternalError(_) => "InternalErrorException",
+            DescribeIdentityUsageError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeIdentityUsageError::NotAuthorized(_) => "NotAuthorizedException",
+            DescribeIdentityUsageError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeIdentityUsageError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetBulkPublishDetails
 #[derive(Debug, PartialEq)]
 pub enum GetBulkPublishDetailsError {
@@ -1039,6 +1096,16 @@ impl fmt::Display for GetBulkPublishDetailsError {
     }
 }
 impl Error for GetBulkPublishDetailsError {}
+impl ServiceErrorCode for GetBulkPublishDetailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBulkPublishDetailsError::InternalError(_) => "InternalErrorException",
+            GetBulkPublishDetailsError::InvalidParameter(_) => "InvalidParameterException",
+            GetBulkPublishDetailsError::NotAuthorized(_) => "NotAuthorizedException",
+            GetBulkPublishDetailsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetCognitoEvents
 #[derive(Debug, PartialEq)]
 pub enum GetCognitoEventsError {
@@ -1093,6 +1160,17 @@ impl fmt::Display for GetCognitoEventsError {
     }
 }
 impl Error for GetCognitoEventsError {}
+impl ServiceErrorCode for GetCognitoEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCognitoEventsError::InternalError(_) => "InternalErrorException",
+            GetCognitoEventsError::InvalidParameter(_) => "InvalidParameterException",
+            GetCognitoEventsError::NotAuthorized(_) => "NotAuthorizedException",
+            GetCognitoEventsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetCognitoEventsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetIdentityPoolConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetIdentityPoolConfigurationError {
@@ -1163,6 +1241,17 @@ impl fmt::Display for GetIdentityPoolConfigurationError {
     }
 }
 impl Error for GetIdentityPoolConfigurationError {}
+impl ServiceErrorCode for GetIdentityPoolConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIdentityPoolConfigurationError::InternalError(_) => "InternalErrorException",
+            GetIdentityPoolConfigurationError::InvalidParameter(_) => "InvalidParameterException",
+            GetIdentityPoolConfigurationError::NotAuthorized(_) => "NotAuthorizedException",
+            GetIdentityPoolConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetIdentityPoolConfigurationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListDatasets
 #[derive(Debug, PartialEq)]
 pub enum ListDatasetsError {
@@ -1211,6 +1300,16 @@ impl fmt::Display for ListDatasetsError {
     }
 }
 impl Error for ListDatasetsError {}
+impl ServiceErrorCode for ListDatasetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDatasetsError::InternalError(_) => "InternalErrorException",
+            ListDatasetsError::InvalidParameter(_) => "InvalidParameterException",
+            ListDatasetsError::NotAuthorized(_) => "NotAuthorizedException",
+            ListDatasetsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListIdentityPoolUsage
 #[derive(Debug, PartialEq)]
 pub enum ListIdentityPoolUsageError {
@@ -1263,6 +1362,16 @@ impl fmt::Display for ListIdentityPoolUsageError {
     }
 }
 impl Error for ListIdentityPoolUsageError {}
+impl ServiceErrorCode for ListIdentityPoolUsageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListIdentityPoolUsageError::InternalError(_) => "InternalErrorException",
+            ListIdentityPoolUsageError::InvalidParameter(_) => "InvalidParameterException",
+            ListIdentityPoolUsageError::NotAuthorized(_) => "NotAuthorizedException",
+            ListIdentityPoolUsageError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListRecords
 #[derive(Debug, PartialEq)]
 pub enum ListRecordsError {
@@ -1311,6 +1420,16 @@ impl fmt::Display for ListRecordsError {
     }
 }
 impl Error for ListRecordsError {}
+impl ServiceErrorCode for ListRecordsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRecordsError::InternalError(_) => "InternalErrorException",
+            ListRecordsError::InvalidParameter(_) => "InvalidParameterException",
+            ListRecordsError::NotAuthorized(_) => "NotAuthorizedException",
+            ListRecordsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by RegisterDevice
 #[derive(Debug, PartialEq)]
 pub enum RegisterDeviceError {
@@ -1371,6 +1490,18 @@ impl fmt::Display for RegisterDeviceError {
     }
 }
 impl Error for RegisterDeviceError {}
+impl ServiceErrorCode for RegisterDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterDeviceError::InternalError(_) => "InternalErrorException",
+            RegisterDeviceError::InvalidConfiguration(_) => "InvalidConfigurationException",
+            RegisterDeviceError::InvalidParameter(_) => "InvalidParameterException",
+            RegisterDeviceError::NotAuthorized(_) => "NotAuthorizedException",
+            RegisterDeviceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RegisterDeviceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by SetCognitoEvents
 #[derive(Debug, PartialEq)]
 pub enum SetCognitoEventsError {
@@ -1425,6 +1556,17 @@ impl fmt::Display for SetCognitoEventsError {
     }
 }
 impl Error for SetCognitoEventsError {}
+impl ServiceErrorCode for SetCognitoEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetCognitoEventsError::InternalError(_) => "InternalErrorException",
+            SetCognitoEventsError::InvalidParameter(_) => "InvalidParameterException",
+            SetCognitoEventsError::NotAuthorized(_) => "NotAuthorizedException",
+            SetCognitoEventsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SetCognitoEventsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by SetIdentityPoolConfiguration
 #[derive(Debug, PartialEq)]
 pub enum SetIdentityPoolConfigurationError {
@@ -1505,6 +1647,20 @@ impl fmt::Display for SetIdentityPoolConfigurationError {
     }
 }
 impl Error for SetIdentityPoolConfigurationError {}
+impl ServiceErrorCode for SetIdentityPoolConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetIdentityPoolConfigurationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            SetIdentityPoolConfigurationError::InternalError(_) => "InternalErrorException",
+            SetIdentityPoolConfigurationError::InvalidParameter(_) => "InvalidParameterException",
+            SetIdentityPoolConfigurationError::NotAuthorized(_) => "NotAuthorizedException",
+            SetIdentityPoolConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SetIdentityPoolConfigurationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by SubscribeToDataset
 #[derive(Debug, PartialEq)]
 pub enum SubscribeToDatasetError {
@@ -1567,6 +1723,18 @@ impl fmt::Display for SubscribeToDatasetError {
     }
 }
 impl Error for SubscribeToDatasetError {}
+impl ServiceErrorCode for SubscribeToDatasetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SubscribeToDatasetError::InternalError(_) => "InternalErrorException",
+            SubscribeToDatasetError::InvalidConfiguration(_) => "InvalidConfigurationException",
+            SubscribeToDatasetError::InvalidParameter(_) => "InvalidParameterException",
+            SubscribeToDatasetError::NotAuthorized(_) => "NotAuthorizedException",
+            SubscribeToDatasetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SubscribeToDatasetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UnsubscribeFromDataset
 #[derive(Debug, PartialEq)]
 pub enum UnsubscribeFromDatasetError {
@@ -1639,6 +1807,18 @@ impl fmt::Display for UnsubscribeFromDatasetError {
     }
 }
 impl Error for UnsubscribeFromDatasetError {}
+impl ServiceErrorCode for UnsubscribeFromDatasetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UnsubscribeFromDatasetError::InternalError(_) => "InternalErrorException",
+            UnsubscribeFromDatasetError::InvalidConfiguration(_) => "InvalidConfigurationException",
+            UnsubscribeFromDatasetError::InvalidParameter(_) => "InvalidParameterException",
+            UnsubscribeFromDatasetError::NotAuthorized(_) => "NotAuthorizedException",
+            UnsubscribeFromDatasetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UnsubscribeFromDatasetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateRecords
 #[derive(Debug, PartialEq)]
 pub enum UpdateRecordsError {
@@ -1719,6 +1899,23 @@ impl fmt::Display for UpdateRecordsError {
     }
 }
 impl Error for UpdateRecordsError {}
+impl ServiceErrorCode for UpdateRecordsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRecordsError::InternalError(_) => "InternalErrorException",
+            UpdateRecordsError::InvalidLambdaFunctionOutput(_) => {
+                "InvalidLambdaFunctionOutputException"
+            }
+            UpdateRecordsError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateRecordsError::LambdaThrottled(_) => "LambdaThrottledException",
+            UpdateRecordsError::LimitExceeded(_) => "LimitExceededException",
+            UpdateRecordsError::NotAuthorized(_) => "NotAuthorizedException",
+            UpdateRecordsError::ResourceConflict(_) => "ResourceConflictException",
+            UpdateRecordsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateRecordsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Cognito Sync API. Amazon Cognito Sync clients implement this trait.
 #[async_trait]
 pub trait CognitoSync {
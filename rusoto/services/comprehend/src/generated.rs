@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -348,6 +348,7 @@ pub struct ClassifyDocumentResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateDocumentClassifierRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -398,6 +399,7 @@ pub struct CreateDocumentClassifierResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateEndpointRequest {
     /// <p>An idempotency token provided by the customer. If this token matches a previous endpoint creation request, Amazon Comprehend will not return a <code>ResourceInUseException</code>. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -429,6 +431,7 @@ pub struct CreateEndpointResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateEntityRecognizerRequest {
     /// <p> A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -2292,6 +2295,7 @@ pub struct SentimentScore {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartDocumentClassificationJobRequest {
     /// <p>A unique identifier for the request. If you do not set the client request token, Amazon Comprehend generates one.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -2338,6 +2342,7 @@ pub struct StartDocumentClassificationJobResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartDominantLanguageDetectionJobRequest {
     /// <p>A unique identifier for the request. If you do not set the client request token, Amazon Comprehend generates one.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -2381,6 +2386,7 @@ pub struct StartDominantLanguageDetectionJobResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartEntitiesDetectionJobRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -2431,6 +2437,7 @@ pub struct StartEntitiesDetectionJobResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartEventsDetectionJobRequest {
     /// <p>An unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -2472,6 +2479,7 @@ pub struct StartEventsDetectionJobResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartKeyPhrasesDetectionJobRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -2518,6 +2526,7 @@ pub struct StartKeyPhrasesDetectionJobResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartPiiEntitiesDetectionJobRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -2563,6 +2572,7 @@ pub struct StartPiiEntitiesDetectionJobResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartSentimentDetectionJobRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -2609,6 +2619,7 @@ pub struct StartSentimentDetectionJobResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartTopicsDetectionJobRequest {
     /// <p>A unique identifier for the request. If you do not set the client request token, Amazon Comprehend generates one.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -3034,6 +3045,20 @@ impl fmt::Display for BatchDetectDominantLanguageError {
     }
 }
 impl Error for BatchDetectDominantLanguageError {}
+impl ServiceErrorCode for BatchDetectDominantLanguageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDetectDominantLanguageError::BatchSizeLimitExceeded(_) => {
+                "BatchSizeLimitExceededException"
+            }
+            BatchDetectDominantLanguageError::InternalServer(_) => "InternalServerException",
+            BatchDetectDominantLanguageError::InvalidRequest(_) => "InvalidRequestException",
+            BatchDetectDominantLanguageError::TextSizeLimitExceeded(_) => {
+                "TextSizeLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by BatchDetectEntities
 #[derive(Debug, PartialEq)]
 pub enum BatchDetectEntitiesError {
@@ -3094,6 +3119,19 @@ impl fmt::Display for BatchDetectEntitiesError {
     }
 }
 impl Error for BatchDetectEntitiesError {}
+impl ServiceErrorCode for BatchDetectEntitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDetectEntitiesError::BatchSizeLimitExceeded(_) => {
+                "BatchSizeLimitExceededException"
+            }
+            BatchDetectEntitiesError::InternalServer(_) => "InternalServerException",
+            BatchDetectEntitiesError::InvalidRequest(_) => "InvalidRequestException",
+            BatchDetectEntitiesError::TextSizeLimitExceeded(_) => "TextSizeLimitExceededException",
+            BatchDetectEntitiesError::UnsupportedLanguage(_) => "UnsupportedLanguageException",
+        }
+    }
+}
 /// Errors returned by BatchDetectKeyPhrases
 #[derive(Debug, PartialEq)]
 pub enum BatchDetectKeyPhrasesError {
@@ -3158,6 +3196,21 @@ impl fmt::Display for BatchDetectKeyPhrasesError {
     }
 }
 impl Error for BatchDetectKeyPhrasesError {}
+impl ServiceErrorCode for BatchDetectKeyPhrasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDetectKeyPhrasesError::BatchSizeLimitExceeded(_) => {
+                "BatchSizeLimitExceededException"
+            }
+            BatchDetectKeyPhrasesError::InternalServer(_) => "InternalServerException",
+            BatchDetectKeyPhrasesError::InvalidRequest(_) => "InvalidRequestException",
+            BatchDetectKeyPhrasesError::TextSizeLimitExceeded(_) => {
+                "TextSizeLimitExceededException"
+            }
+            BatchDetectKeyPhrasesError::UnsupportedLanguage(_) => "UnsupportedLanguageException",
+        }
+    }
+}
 /// Errors returned by BatchDetectSentiment
 #[derive(Debug, PartialEq)]
 pub enum BatchDetectSentimentError {
@@ -3218,6 +3271,19 @@ impl fmt::Display for BatchDetectSentimentError {
     }
 }
 impl Error for BatchDetectSentimentError {}
+impl ServiceErrorCode for BatchDetectSentimentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDetectSentimentError::BatchSizeLimitExceeded(_) => {
+                "BatchSizeLimitExceededException"
+            }
+            BatchDetectSentimentError::InternalServer(_) => "InternalServerException",
+            BatchDetectSentimentError::InvalidRequest(_) => "InvalidRequestException",
+            BatchDetectSentimentError::TextSizeLimitExceeded(_) => "TextSizeLimitExceededException",
+            BatchDetectSentimentError::UnsupportedLanguage(_) => "UnsupportedLanguageException",
+        }
+    }
+}
 /// Errors returned by BatchDetectSyntax
 #[derive(Debug, PartialEq)]
 pub enum BatchDetectSyntaxError {
@@ -3278,6 +3344,17 @@ impl fmt::Display for BatchDetectSyntaxError {
     }
 }
 impl Error for BatchDetectSyntaxError {}
+impl ServiceErrorCode for BatchDetectSyntaxError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDetectSyntaxError::BatchSizeLimitExceeded(_) => "BatchSizeLimitExceededException",
+            BatchDetectSyntaxError::InternalServer(_) => "InternalServerException",
+            BatchDetectSyntaxError::InvalidRequest(_) => "InvalidRequestException",
+            BatchDetectSyntaxError::TextSizeLimitExceeded(_) => "TextSizeLimitExceededException",
+            BatchDetectSyntaxError::UnsupportedLanguage(_) => "UnsupportedLanguageException",
+        }
+    }
+}
 /// Errors returned by ClassifyDocument
 #[derive(Debug, PartialEq)]
 pub enum ClassifyDocumentError {
@@ -3330,6 +3407,16 @@ impl fmt::Display for ClassifyDocumentError {
     }
 }
 impl Error for ClassifyDocumentError {}
+impl ServiceErrorCode for ClassifyDocumentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ClassifyDocumentError::InternalServer(_) => "InternalServerException",
+            ClassifyDocumentError::InvalidRequest(_) => "InvalidRequestException",
+            ClassifyDocumentError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            ClassifyDocumentError::TextSizeLimitExceeded(_) => "TextSizeLimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateDocumentClassifier
 #[derive(Debug, PartialEq)]
 pub enum CreateDocumentClassifierError {
@@ -3420,6 +3507,22 @@ impl fmt::Display for CreateDocumentClassifierError {
     }
 }
 impl Error for CreateDocumentClassifierError {}
+impl ServiceErrorCode for CreateDocumentClassifierError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDocumentClassifierError::InternalServer(_) => "InternalServerException",
+            CreateDocumentClassifierError::InvalidRequest(_) => "InvalidRequestException",
+            CreateDocumentClassifierError::KmsKeyValidation(_) => "KmsKeyValidationException",
+            CreateDocumentClassifierError::ResourceInUse(_) => "ResourceInUseException",
+            CreateDocumentClassifierError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            CreateDocumentClassifierError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateDocumentClassifierError::TooManyTags(_) => "TooManyTagsException",
+            CreateDocumentClassifierError::UnsupportedLanguage(_) => "UnsupportedLanguageException",
+        }
+    }
+}
 /// Errors returned by CreateEndpoint
 #[derive(Debug, PartialEq)]
 pub enum CreateEndpointError {
@@ -3494,6 +3597,20 @@ impl fmt::Display for CreateEndpointError {
     }
 }
 impl Error for CreateEndpointError {}
+impl ServiceErrorCode for CreateEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEndpointError::InternalServer(_) => "InternalServerException",
+            CreateEndpointError::InvalidRequest(_) => "InvalidRequestException",
+            CreateEndpointError::ResourceInUse(_) => "ResourceInUseException",
+            CreateEndpointError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateEndpointError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateEndpointError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            CreateEndpointError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateEndpointError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by CreateEntityRecognizer
 #[derive(Debug, PartialEq)]
 pub enum CreateEntityRecognizerError {
@@ -3580,6 +3697,22 @@ impl fmt::Display for CreateEntityRecognizerError {
     }
 }
 impl Error for CreateEntityRecognizerError {}
+impl ServiceErrorCode for CreateEntityRecognizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEntityRecognizerError::InternalServer(_) => "InternalServerException",
+            CreateEntityRecognizerError::InvalidRequest(_) => "InvalidRequestException",
+            CreateEntityRecognizerError::KmsKeyValidation(_) => "KmsKeyValidationException",
+            CreateEntityRecognizerError::ResourceInUse(_) => "ResourceInUseException",
+            CreateEntityRecognizerError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            CreateEntityRecognizerError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateEntityRecognizerError::TooManyTags(_) => "TooManyTagsException",
+            CreateEntityRecognizerError::UnsupportedLanguage(_) => "UnsupportedLanguageException",
+        }
+    }
+}
 /// Errors returned by DeleteDocumentClassifier
 #[derive(Debug, PartialEq)]
 pub enum DeleteDocumentClassifierError {
@@ -3652,6 +3785,18 @@ impl fmt::Display for DeleteDocumentClassifierError {
     }
 }
 impl Error for DeleteDocumentClassifierError {}
+impl ServiceErrorCode for DeleteDocumentClassifierError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDocumentClassifierError::InternalServer(_) => "InternalServerException",
+            DeleteDocumentClassifierError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteDocumentClassifierError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteDocumentClassifierError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDocumentClassifierError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            DeleteDocumentClassifierError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DeleteEndpointError {
@@ -3706,6 +3851,17 @@ impl fmt::Display for DeleteEndpointError {
     }
 }
 impl Error for DeleteEndpointError {}
+impl ServiceErrorCode for DeleteEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEndpointError::InternalServer(_) => "InternalServerException",
+            DeleteEndpointError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteEndpointError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteEndpointError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteEndpointError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteEntityRecognizer
 #[derive(Debug, PartialEq)]
 pub enum DeleteEntityRecognizerError {
@@ -3778,6 +3934,18 @@ impl fmt::Display for DeleteEntityRecognizerError {
     }
 }
 impl Error for DeleteEntityRecognizerError {}
+impl ServiceErrorCode for DeleteEntityRecognizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEntityRecognizerError::InternalServer(_) => "InternalServerException",
+            DeleteEntityRecognizerError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteEntityRecognizerError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteEntityRecognizerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteEntityRecognizerError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            DeleteEntityRecognizerError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeDocumentClassificationJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeDocumentClassificationJobError {
@@ -3844,6 +4012,18 @@ impl fmt::Display for DescribeDocumentClassificationJobError {
     }
 }
 impl Error for DescribeDocumentClassificationJobError {}
+impl ServiceErrorCode for DescribeDocumentClassificationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDocumentClassificationJobError::InternalServer(_) => "InternalServerException",
+            DescribeDocumentClassificationJobError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeDocumentClassificationJobError::JobNotFound(_) => "JobNotFoundException",
+            DescribeDocumentClassificationJobError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDocumentClassifier
 #[derive(Debug, PartialEq)]
 pub enum DescribeDocumentClassifierError {
@@ -3902,6 +4082,16 @@ impl fmt::Display for DescribeDocumentClassifierError {
     }
 }
 impl Error for DescribeDocumentClassifierError {}
+impl ServiceErrorCode for DescribeDocumentClassifierError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDocumentClassifierError::InternalServer(_) => "InternalServerException",
+            DescribeDocumentClassifierError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeDocumentClassifierError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDocumentClassifierError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeDominantLanguageDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeDominantLanguageDetectionJobError {
@@ -3968,6 +4158,22 @@ impl fmt::Display for DescribeDominantLanguageDetectionJobError {
     }
 }
 impl Error for DescribeDominantLanguageDetectionJobError {}
+impl ServiceErrorCode for DescribeDominantLanguageDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDominantLanguageDetectionJobError::InternalServer(_) => {
+                "InternalServerException"
+            }
+            DescribeDominantLanguageDetectionJobError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            DescribeDominantLanguageDetectionJobError::JobNotFound(_) => "JobNotFoundException",
+            DescribeDominantLanguageDetectionJobError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DescribeEndpointError {
@@ -4016,6 +4222,16 @@ impl fmt::Display for DescribeEndpointError {
     }
 }
 impl Error for DescribeEndpointError {}
+impl ServiceErrorCode for DescribeEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEndpointError::InternalServer(_) => "InternalServerException",
+            DescribeEndpointError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeEndpointError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeEndpointError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeEntitiesDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeEntitiesDetectionJobError {
@@ -4074,6 +4290,16 @@ impl fmt::Display for DescribeEntitiesDetectionJobError {
     }
 }
 impl Error for DescribeEntitiesDetectionJobError {}
+impl ServiceErrorCode for DescribeEntitiesDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEntitiesDetectionJobError::InternalServer(_) => "InternalServerException",
+            DescribeEntitiesDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeEntitiesDetectionJobError::JobNotFound(_) => "JobNotFoundException",
+            DescribeEntitiesDetectionJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeEntityRecognizer
 #[derive(Debug, PartialEq)]
 pub enum DescribeEntityRecognizerError {
@@ -4130,6 +4356,16 @@ impl fmt::Display for DescribeEntityRecognizerError {
     }
 }
 impl Error for DescribeEntityRecognizerError {}
+impl ServiceErrorCode for DescribeEntityRecognizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEntityRecognizerError::InternalServer(_) => "InternalServerException",
+            DescribeEntityRecognizerError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeEntityRecognizerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeEntityRecognizerError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeEventsDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventsDetectionJobError {
@@ -4188,6 +4424,16 @@ impl fmt::Display for DescribeEventsDetectionJobError {
     }
 }
 impl Error for DescribeEventsDetectionJobError {}
+impl ServiceErrorCode for DescribeEventsDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventsDetectionJobError::InternalServer(_) => "InternalServerException",
+            DescribeEventsDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeEventsDetectionJobError::JobNotFound(_) => "JobNotFoundException",
+            DescribeEventsDetectionJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeKeyPhrasesDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeKeyPhrasesDetectionJobError {
@@ -4252,6 +4498,16 @@ impl fmt::Display for DescribeKeyPhrasesDetectionJobError {
     }
 }
 impl Error for DescribeKeyPhrasesDetectionJobError {}
+impl ServiceErrorCode for DescribeKeyPhrasesDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeKeyPhrasesDetectionJobError::InternalServer(_) => "InternalServerException",
+            DescribeKeyPhrasesDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeKeyPhrasesDetectionJobError::JobNotFound(_) => "JobNotFoundException",
+            DescribeKeyPhrasesDetectionJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribePiiEntitiesDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum DescribePiiEntitiesDetectionJobError {
@@ -4316,6 +4572,16 @@ impl fmt::Display for DescribePiiEntitiesDetectionJobError {
     }
 }
 impl Error for DescribePiiEntitiesDetectionJobError {}
+impl ServiceErrorCode for DescribePiiEntitiesDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePiiEntitiesDetectionJobError::InternalServer(_) => "InternalServerException",
+            DescribePiiEntitiesDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            DescribePiiEntitiesDetectionJobError::JobNotFound(_) => "JobNotFoundException",
+            DescribePiiEntitiesDetectionJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeSentimentDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeSentimentDetectionJobError {
@@ -4376,6 +4642,16 @@ impl fmt::Display for DescribeSentimentDetectionJobError {
     }
 }
 impl Error for DescribeSentimentDetectionJobError {}
+impl ServiceErrorCode for DescribeSentimentDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSentimentDetectionJobError::InternalServer(_) => "InternalServerException",
+            DescribeSentimentDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeSentimentDetectionJobError::JobNotFound(_) => "JobNotFoundException",
+            DescribeSentimentDetectionJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeTopicsDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeTopicsDetectionJobError {
@@ -4434,6 +4710,16 @@ impl fmt::Display for DescribeTopicsDetectionJobError {
     }
 }
 impl Error for DescribeTopicsDetectionJobError {}
+impl ServiceErrorCode for DescribeTopicsDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTopicsDetectionJobError::InternalServer(_) => "InternalServerException",
+            DescribeTopicsDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeTopicsDetectionJobError::JobNotFound(_) => "JobNotFoundException",
+            DescribeTopicsDetectionJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DetectDominantLanguage
 #[derive(Debug, PartialEq)]
 pub enum DetectDominantLanguageError {
@@ -4482,6 +4768,17 @@ impl fmt::Display for DetectDominantLanguageError {
     }
 }
 impl Error for DetectDominantLanguageError {}
+impl ServiceErrorCode for DetectDominantLanguageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectDominantLanguageError::InternalServer(_) => "InternalServerException",
+            DetectDominantLanguageError::InvalidRequest(_) => "InvalidRequestException",
+            DetectDominantLanguageError::TextSizeLimitExceeded(_) => {
+                "TextSizeLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by DetectEntities
 #[derive(Debug, PartialEq)]
 pub enum DetectEntitiesError {
@@ -4538,6 +4835,17 @@ impl fmt::Display for DetectEntitiesError {
     }
 }
 impl Error for DetectEntitiesError {}
+impl ServiceErrorCode for DetectEntitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectEntitiesError::InternalServer(_) => "InternalServerException",
+            DetectEntitiesError::InvalidRequest(_) => "InvalidRequestException",
+            DetectEntitiesError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            DetectEntitiesError::TextSizeLimitExceeded(_) => "TextSizeLimitExceededException",
+            DetectEntitiesError::UnsupportedLanguage(_) => "UnsupportedLanguageException",
+        }
+    }
+}
 /// Errors returned by DetectKeyPhrases
 #[derive(Debug, PartialEq)]
 pub enum DetectKeyPhrasesError {
@@ -4590,6 +4898,16 @@ impl fmt::Display for DetectKeyPhrasesError {
     }
 }
 impl Error for DetectKeyPhrasesError {}
+impl ServiceErrorCode for DetectKeyPhrasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectKeyPhrasesError::InternalServer(_) => "InternalServerException",
+            DetectKeyPhrasesError::InvalidRequest(_) => "InvalidRequestException",
+            DetectKeyPhrasesError::TextSizeLimitExceeded(_) => "TextSizeLimitExceededException",
+            DetectKeyPhrasesError::UnsupportedLanguage(_) => "UnsupportedLanguageException",
+        }
+    }
+}
 /// Errors returned by DetectPiiEntities
 #[derive(Debug, PartialEq)]
 pub enum DetectPiiEntitiesError {
@@ -4642,6 +4960,16 @@ impl fmt::Display for DetectPiiEntitiesError {
     }
 }
 impl Error for DetectPiiEntitiesError {}
+impl ServiceErrorCode for DetectPiiEntitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectPiiEntitiesError::InternalServer(_) => "InternalServerException",
+            DetectPiiEntitiesError::InvalidRequest(_) => "InvalidRequestException",
+            DetectPiiEntitiesError::TextSizeLimitExceeded(_) => "TextSizeLimitExceededException",
+            DetectPiiEntitiesError::UnsupportedLanguage(_) => "UnsupportedLanguageException",
+        }
+    }
+}
 /// Errors returned by DetectSentiment
 #[derive(Debug, PartialEq)]
 pub enum DetectSentimentError {
@@ -4692,6 +5020,16 @@ impl fmt::Display for DetectSentimentError {
     }
 }
 impl Error for DetectSentimentError {}
+impl ServiceErrorCode for DetectSentimentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectSentimentError::InternalServer(_) => "InternalServerException",
+            DetectSentimentError::InvalidRequest(_) => "InvalidRequestException",
+            DetectSentimentError::TextSizeLimitExceeded(_) => "TextSizeLimitExceededException",
+            DetectSentimentError::UnsupportedLanguage(_) => "UnsupportedLanguageException",
+        }
+    }
+}
 /// Errors returned by DetectSyntax
 #[derive(Debug, PartialEq)]
 pub enum DetectSyntaxError {
@@ -4740,6 +5078,16 @@ impl fmt::Display for DetectSyntaxError {
     }
 }
 impl Error for DetectSyntaxError {}
+impl ServiceErrorCode for DetectSyntaxError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectSyntaxError::InternalServer(_) => "InternalServerException",
+            DetectSyntaxError::InvalidRequest(_) => "InvalidRequestException",
+            DetectSyntaxError::TextSizeLimitExceeded(_) => "TextSizeLimitExceededException",
+            DetectSyntaxError::UnsupportedLanguage(_) => "UnsupportedLanguageException",
+        }
+    }
+}
 /// Errors returned by ListDocumentClassificationJobs
 #[derive(Debug, PartialEq)]
 pub enum ListDocumentClassificationJobsError {
@@ -4804,6 +5152,16 @@ impl fmt::Display for ListDocumentClassificationJobsError {
     }
 }
 impl Error for ListDocumentClassificationJobsError {}
+impl ServiceErrorCode for ListDocumentClassificationJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDocumentClassificationJobsError::InternalServer(_) => "InternalServerException",
+            ListDocumentClassificationJobsError::InvalidFilter(_) => "InvalidFilterException",
+            ListDocumentClassificationJobsError::InvalidRequest(_) => "InvalidRequestException",
+            ListDocumentClassificationJobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListDocumentClassifiers
 #[derive(Debug, PartialEq)]
 pub enum ListDocumentClassifiersError {
@@ -4860,6 +5218,16 @@ impl fmt::Display for ListDocumentClassifiersError {
     }
 }
 impl Error for ListDocumentClassifiersError {}
+impl ServiceErrorCode for ListDocumentClassifiersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDocumentClassifiersError::InternalServer(_) => "InternalServerException",
+            ListDocumentClassifiersError::InvalidFilter(_) => "InvalidFilterException",
+            ListDocumentClassifiersError::InvalidRequest(_) => "InvalidRequestException",
+            ListDocumentClassifiersError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListDominantLanguageDetectionJobs
 #[derive(Debug, PartialEq)]
 pub enum ListDominantLanguageDetectionJobsError {
@@ -4926,6 +5294,18 @@ impl fmt::Display for ListDominantLanguageDetectionJobsError {
     }
 }
 impl Error for ListDominantLanguageDetectionJobsError {}
+impl ServiceErrorCode for ListDominantLanguageDetectionJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDominantLanguageDetectionJobsError::InternalServer(_) => "InternalServerException",
+            ListDominantLanguageDetectionJobsError::InvalidFilter(_) => "InvalidFilterException",
+            ListDominantLanguageDetectionJobsError::InvalidRequest(_) => "InvalidRequestException",
+            ListDominantLanguageDetectionJobsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by ListEndpoints
 #[derive(Debug, PartialEq)]
 pub enum ListEndpointsError {
@@ -4968,6 +5348,15 @@ impl fmt::Display for ListEndpointsError {
     }
 }
 impl Error for ListEndpointsError {}
+impl ServiceErrorCode for ListEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEndpointsError::InternalServer(_) => "InternalServerException",
+            ListEndpointsError::InvalidRequest(_) => "InvalidRequestException",
+            ListEndpointsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListEntitiesDetectionJobs
 #[derive(Debug, PartialEq)]
 pub enum ListEntitiesDetectionJobsError {
@@ -5024,6 +5413,16 @@ impl fmt::Display for ListEntitiesDetectionJobsError {
     }
 }
 impl Error for ListEntitiesDetectionJobsError {}
+impl ServiceErrorCode for ListEntitiesDetectionJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEntitiesDetectionJobsError::InternalServer(_) => "InternalServerException",
+            ListEntitiesDetectionJobsError::InvalidFilter(_) => "InvalidFilterException",
+            ListEntitiesDetectionJobsError::InvalidRequest(_) => "InvalidRequestException",
+            ListEntitiesDetectionJobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListEntityRecognizers
 #[derive(Debug, PartialEq)]
 pub enum ListEntityRecognizersError {
@@ -5078,6 +5477,16 @@ impl fmt::Display for ListEntityRecognizersError {
     }
 }
 impl Error for ListEntityRecognizersError {}
+impl ServiceErrorCode for ListEntityRecognizersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEntityRecognizersError::InternalServer(_) => "InternalServerException",
+            ListEntityRecognizersError::InvalidFilter(_) => "InvalidFilterException",
+            ListEntityRecognizersError::InvalidRequest(_) => "InvalidRequestException",
+            ListEntityRecognizersError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListEventsDetectionJobs
 #[derive(Debug, PartialEq)]
 pub enum ListEventsDetectionJobsError {
@@ -5134,6 +5543,16 @@ impl fmt::Display for ListEventsDetectionJobsError {
     }
 }
 impl Error for ListEventsDetectionJobsError {}
+impl ServiceErrorCode for ListEventsDetectionJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEventsDetectionJobsError::InternalServer(_) => "InternalServerException",
+            ListEventsDetectionJobsError::InvalidFilter(_) => "InvalidFilterException",
+            ListEventsDetectionJobsError::InvalidRequest(_) => "InvalidRequestException",
+            ListEventsDetectionJobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListKeyPhrasesDetectionJobs
 #[derive(Debug, PartialEq)]
 pub enum ListKeyPhrasesDetectionJobsError {
@@ -5192,6 +5611,16 @@ impl fmt::Display for ListKeyPhrasesDetectionJobsError {
     }
 }
 impl Error for ListKeyPhrasesDetectionJobsError {}
+impl ServiceErrorCode for ListKeyPhrasesDetectionJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListKeyPhrasesDetectionJobsError::InternalServer(_) => "InternalServerException",
+            ListKeyPhrasesDetectionJobsError::InvalidFilter(_) => "InvalidFilterException",
+            ListKeyPhrasesDetectionJobsError::InvalidRequest(_) => "InvalidRequestException",
+            ListKeyPhrasesDetectionJobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListPiiEntitiesDetectionJobs
 #[derive(Debug, PartialEq)]
 pub enum ListPiiEntitiesDetectionJobsError {
@@ -5250,6 +5679,16 @@ impl fmt::Display for ListPiiEntitiesDetectionJobsError {
     }
 }
 impl Error for ListPiiEntitiesDetectionJobsError {}
+impl ServiceErrorCode for ListPiiEntitiesDetectionJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPiiEntitiesDetectionJobsError::InternalServer(_) => "InternalServerException",
+            ListPiiEntitiesDetectionJobsError::InvalidFilter(_) => "InvalidFilterException",
+            ListPiiEntitiesDetectionJobsError::InvalidRequest(_) => "InvalidRequestException",
+            ListPiiEntitiesDetectionJobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListSentimentDetectionJobs
 #[derive(Debug, PartialEq)]
 pub enum ListSentimentDetectionJobsError {
@@ -5308,6 +5747,16 @@ impl fmt::Display for ListSentimentDetectionJobsError {
     }
 }
 impl Error for ListSentimentDetectionJobsError {}
+impl ServiceErrorCode for ListSentimentDetectionJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSentimentDetectionJobsError::InternalServer(_) => "InternalServerException",
+            ListSentimentDetectionJobsError::InvalidFilter(_) => "InvalidFilterException",
+            ListSentimentDetectionJobsError::InvalidRequest(_) => "InvalidRequestException",
+            ListSentimentDetectionJobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -5352,6 +5801,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalServer(_) => "InternalServerException",
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTopicsDetectionJobs
 #[derive(Debug, PartialEq)]
 pub enum ListTopicsDetectionJobsError {
@@ -5408,6 +5866,16 @@ impl fmt::Display for ListTopicsDetectionJobsError {
     }
 }
 impl Error for ListTopicsDetectionJobsError {}
+impl ServiceErrorCode for ListTopicsDetectionJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTopicsDetectionJobsError::InternalServer(_) => "InternalServerException",
+            ListTopicsDetectionJobsError::InvalidFilter(_) => "InvalidFilterException",
+            ListTopicsDetectionJobsError::InvalidRequest(_) => "InvalidRequestException",
+            ListTopicsDetectionJobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StartDocumentClassificationJob
 #[derive(Debug, PartialEq)]
 pub enum StartDocumentClassificationJobError {
@@ -5494,6 +5962,20 @@ impl fmt::Display for StartDocumentClassificationJobError {
     }
 }
 impl Error for StartDocumentClassificationJobError {}
+impl ServiceErrorCode for StartDocumentClassificationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDocumentClassificationJobError::InternalServer(_) => "InternalServerException",
+            StartDocumentClassificationJobError::InvalidRequest(_) => "InvalidRequestException",
+            StartDocumentClassificationJobError::KmsKeyValidation(_) => "KmsKeyValidationException",
+            StartDocumentClassificationJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartDocumentClassificationJobError::ResourceUnavailable(_) => {
+                "ResourceUnavailableException"
+            }
+            StartDocumentClassificationJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StartDominantLanguageDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum StartDominantLanguageDetectionJobError {
@@ -5560,6 +6042,20 @@ impl fmt::Display for StartDominantLanguageDetectionJobError {
     }
 }
 impl Error for StartDominantLanguageDetectionJobError {}
+impl ServiceErrorCode for StartDominantLanguageDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDominantLanguageDetectionJobError::InternalServer(_) => "InternalServerException",
+            StartDominantLanguageDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            StartDominantLanguageDetectionJobError::KmsKeyValidation(_) => {
+                "KmsKeyValidationException"
+            }
+            StartDominantLanguageDetectionJobError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by StartEntitiesDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum StartEntitiesDetectionJobError {
@@ -5634,6 +6130,20 @@ impl fmt::Display for StartEntitiesDetectionJobError {
     }
 }
 impl Error for StartEntitiesDetectionJobError {}
+impl ServiceErrorCode for StartEntitiesDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartEntitiesDetectionJobError::InternalServer(_) => "InternalServerException",
+            StartEntitiesDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            StartEntitiesDetectionJobError::KmsKeyValidation(_) => "KmsKeyValidationException",
+            StartEntitiesDetectionJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartEntitiesDetectionJobError::ResourceUnavailable(_) => {
+                "ResourceUnavailableException"
+            }
+            StartEntitiesDetectionJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StartEventsDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum StartEventsDetectionJobError {
@@ -5690,6 +6200,16 @@ impl fmt::Display for StartEventsDetectionJobError {
     }
 }
 impl Error for StartEventsDetectionJobError {}
+impl ServiceErrorCode for StartEventsDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartEventsDetectionJobError::InternalServer(_) => "InternalServerException",
+            StartEventsDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            StartEventsDetectionJobError::KmsKeyValidation(_) => "KmsKeyValidationException",
+            StartEventsDetectionJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StartKeyPhrasesDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum StartKeyPhrasesDetectionJobError {
@@ -5748,6 +6268,16 @@ impl fmt::Display for StartKeyPhrasesDetectionJobError {
     }
 }
 impl Error for StartKeyPhrasesDetectionJobError {}
+impl ServiceErrorCode for StartKeyPhrasesDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartKeyPhrasesDetectionJobError::InternalServer(_) => "InternalServerException",
+            StartKeyPhrasesDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            StartKeyPhrasesDetectionJobError::KmsKeyValidation(_) => "KmsKeyValidationException",
+            StartKeyPhrasesDetectionJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StartPiiEntitiesDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum StartPiiEntitiesDetectionJobError {
@@ -5808,6 +6338,16 @@ impl fmt::Display for StartPiiEntitiesDetectionJobError {
     }
 }
 impl Error for StartPiiEntitiesDetectionJobError {}
+impl ServiceErrorCode for StartPiiEntitiesDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartPiiEntitiesDetectionJobError::InternalServer(_) => "InternalServerException",
+            StartPiiEntitiesDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            StartPiiEntitiesDetectionJobError::KmsKeyValidation(_) => "KmsKeyValidationException",
+            StartPiiEntitiesDetectionJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StartSentimentDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum StartSentimentDetectionJobError {
@@ -5866,6 +6406,16 @@ impl fmt::Display for StartSentimentDetectionJobError {
     }
 }
 impl Error for StartSentimentDetectionJobError {}
+impl ServiceErrorCode for StartSentimentDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartSentimentDetectionJobError::InternalServer(_) => "InternalServerException",
+            StartSentimentDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            StartSentimentDetectionJobError::KmsKeyValidation(_) => "KmsKeyValidationException",
+            StartSentimentDetectionJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StartTopicsDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum StartTopicsDetectionJobError {
@@ -5922,6 +6472,16 @@ impl fmt::Display for StartTopicsDetectionJobError {
     }
 }
 impl Error for StartTopicsDetectionJobError {}
+impl ServiceErrorCode for StartTopicsDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartTopicsDetectionJobError::InternalServer(_) => "InternalServerException",
+            StartTopicsDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            StartTopicsDetectionJobError::KmsKeyValidation(_) => "KmsKeyValidationException",
+            StartTopicsDetectionJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StopDominantLanguageDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum StopDominantLanguageDetectionJobError {
@@ -5976,6 +6536,15 @@ impl fmt::Display for StopDominantLanguageDetectionJobError {
     }
 }
 impl Error for StopDominantLanguageDetectionJobError {}
+impl ServiceErrorCode for StopDominantLanguageDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopDominantLanguageDetectionJobError::InternalServer(_) => "InternalServerException",
+            StopDominantLanguageDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            StopDominantLanguageDetectionJobError::JobNotFound(_) => "JobNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopEntitiesDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum StopEntitiesDetectionJobError {
@@ -6024,6 +6593,15 @@ impl fmt::Display for StopEntitiesDetectionJobError {
     }
 }
 impl Error for StopEntitiesDetectionJobError {}
+impl ServiceErrorCode for StopEntitiesDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopEntitiesDetectionJobError::InternalServer(_) => "InternalServerException",
+            StopEntitiesDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            StopEntitiesDetectionJobError::JobNotFound(_) => "JobNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopEventsDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum StopEventsDetectionJobError {
@@ -6070,6 +6648,15 @@ impl fmt::Display for StopEventsDetectionJobError {
     }
 }
 impl Error for StopEventsDetectionJobError {}
+impl ServiceErrorCode for StopEventsDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopEventsDetectionJobError::InternalServer(_) => "InternalServerException",
+            StopEventsDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            StopEventsDetectionJobError::JobNotFound(_) => "JobNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopKeyPhrasesDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum StopKeyPhrasesDetectionJobError {
@@ -6120,6 +6707,15 @@ impl fmt::Display for StopKeyPhrasesDetectionJobError {
     }
 }
 impl Error for StopKeyPhrasesDetectionJobError {}
+impl ServiceErrorCode for StopKeyPhrasesDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopKeyPhrasesDetectionJobError::InternalServer(_) => "InternalServerException",
+            StopKeyPhrasesDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            StopKeyPhrasesDetectionJobError::JobNotFound(_) => "JobNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopPiiEntitiesDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum StopPiiEntitiesDetectionJobError {
@@ -6170,6 +6766,15 @@ impl fmt::Display for StopPiiEntitiesDetectionJobError {
     }
 }
 impl Error for StopPiiEntitiesDetectionJobError {}
+impl ServiceErrorCode for StopPiiEntitiesDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopPiiEntitiesDetectionJobError::InternalServer(_) => "InternalServerException",
+            StopPiiEntitiesDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            StopPiiEntitiesDetectionJobError::JobNotFound(_) => "JobNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopSentimentDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum StopSentimentDetectionJobError {
@@ -6218,6 +6823,15 @@ impl fmt::Display for StopSentimentDetectionJobError {
     }
 }
 impl Error for StopSentimentDetectionJobError {}
+impl ServiceErrorCode for StopSentimentDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopSentimentDetectionJobError::InternalServer(_) => "InternalServerException",
+            StopSentimentDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            StopSentimentDetectionJobError::JobNotFound(_) => "JobNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopTrainingDocumentClassifier
 #[derive(Debug, PartialEq)]
 pub enum StopTrainingDocumentClassifierError {
@@ -6284,6 +6898,16 @@ impl fmt::Display for StopTrainingDocumentClassifierError {
     }
 }
 impl Error for StopTrainingDocumentClassifierError {}
+impl ServiceErrorCode for StopTrainingDocumentClassifierError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopTrainingDocumentClassifierError::InternalServer(_) => "InternalServerException",
+            StopTrainingDocumentClassifierError::InvalidRequest(_) => "InvalidRequestException",
+            StopTrainingDocumentClassifierError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StopTrainingDocumentClassifierError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StopTrainingEntityRecognizer
 #[derive(Debug, PartialEq)]
 pub enum StopTrainingEntityRecognizerError {
@@ -6344,6 +6968,16 @@ impl fmt::Display for StopTrainingEntityRecognizerError {
     }
 }
 impl Error for StopTrainingEntityRecognizerError {}
+impl ServiceErrorCode for StopTrainingEntityRecognizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopTrainingEntityRecognizerError::InternalServer(_) => "InternalServerException",
+            StopTrainingEntityRecognizerError::InvalidRequest(_) => "InvalidRequestException",
+            StopTrainingEntityRecognizerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StopTrainingEntityRecognizerError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -6398,6 +7032,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            TagResourceError::InternalServer(_) => "InternalServerException",
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -6454,6 +7099,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UntagResourceError::InternalServer(_) => "InternalServerException",
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::TooManyTagKeys(_) => "TooManyTagKeysException",
+        }
+    }
+}
 /// Errors returned by UpdateEndpoint
 #[derive(Debug, PartialEq)]
 pub enum UpdateEndpointError {
@@ -6522,6 +7178,19 @@ impl fmt::Display for UpdateEndpointError {
     }
 }
 impl Error for UpdateEndpointError {}
+impl ServiceErrorCode for UpdateEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateEndpointError::InternalServer(_) => "InternalServerException",
+            UpdateEndpointError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateEndpointError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateEndpointError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            UpdateEndpointError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateEndpointError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            UpdateEndpointError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Comprehend API. Amazon Comprehend clients implement this trait.
 #[async_trait]
 pub trait Comprehend {
@@ -7078,6 +7747,10 @@ impl Comprehend for ComprehendClient {
         &self,
         input: CreateDocumentClassifierRequest,
     ) -> Result<CreateDocumentClassifierResponse, RusotoError<CreateDocumentClassifierError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -7100,6 +7773,10 @@ impl Comprehend for ComprehendClient {
         &self,
         input: CreateEndpointRequest,
     ) -> Result<CreateEndpointResponse, RusotoError<CreateEndpointError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "Comprehend_20171127.CreateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -7118,6 +7795,10 @@ impl Comprehend for ComprehendClient {
         &self,
         input: CreateEntityRecognizerRequest,
     ) -> Result<CreateEntityRecognizerResponse, RusotoError<CreateEntityRecognizerError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "Comprehend_20171127.CreateEntityRecognizer");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -7837,6 +8518,10 @@ impl Comprehend for ComprehendClient {
         StartDocumentClassificationJobResponse,
         RusotoError<StartDocumentClassificationJobError>,
     > {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -7862,6 +8547,10 @@ impl Comprehend for ComprehendClient {
         StartDominantLanguageDetectionJobResponse,
         RusotoError<StartDominantLanguageDetectionJobError>,
     > {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -7888,6 +8577,10 @@ impl Comprehend for ComprehendClient {
         input: StartEntitiesDetectionJobRequest,
     ) -> Result<StartEntitiesDetectionJobResponse, RusotoError<StartEntitiesDetectionJobError>>
     {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -7910,6 +8603,10 @@ impl Comprehend for ComprehendClient {
         &self,
         input: StartEventsDetectionJobRequest,
     ) -> Result<StartEventsDetectionJobResponse, RusotoError<StartEventsDetectionJobError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -7933,6 +8630,10 @@ impl Comprehend for ComprehendClient {
         input: StartKeyPhrasesDetectionJobRequest,
     ) -> Result<StartKeyPhrasesDetectionJobResponse, RusotoError<StartKeyPhrasesDetectionJobError>>
     {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -7956,6 +8657,10 @@ impl Comprehend for ComprehendClient {
         input: StartPiiEntitiesDetectionJobRequest,
     ) -> Result<StartPiiEntitiesDetectionJobResponse, RusotoError<StartPiiEntitiesDetectionJobError>>
     {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -7979,6 +8684,10 @@ impl Comprehend for ComprehendClient {
         input: StartSentimentDetectionJobRequest,
     ) -> Result<StartSentimentDetectionJobResponse, RusotoError<StartSentimentDetectionJobError>>
     {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -8001,6 +8710,10 @@ impl Comprehend for ComprehendClient {
         &self,
         input: StartTopicsDetectionJobRequest,
     ) -> Result<StartTopicsDetectionJobResponse, RusotoError<StartTopicsDetectionJobError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -800,6 +800,7 @@ pub struct RxNormTrait {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartEntitiesDetectionV2JobRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend Medical generates one.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -838,6 +839,7 @@ pub struct StartEntitiesDetectionV2JobResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartICD10CMInferenceJobRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend Medical generates one.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -876,6 +878,7 @@ pub struct StartICD10CMInferenceJobResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartPHIDetectionJobRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend Medical generates one.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -914,6 +917,7 @@ pub struct StartPHIDetectionJobResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartRxNormInferenceJobRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend Medical generates one.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -1110,6 +1114,16 @@ impl fmt::Display for DescribeEntitiesDetectionV2JobError {
     }
 }
 impl Error for DescribeEntitiesDetectionV2JobError {}
+impl ServiceErrorCode for DescribeEntitiesDetectionV2JobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEntitiesDetectionV2JobError::InternalServer(_) => "InternalServerException",
+            DescribeEntitiesDetectionV2JobError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeEntitiesDetectionV2JobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeEntitiesDetectionV2JobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeICD10CMInferenceJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeICD10CMInferenceJobError {
@@ -1168,6 +1182,16 @@ impl fmt::Display for DescribeICD10CMInferenceJobError {
     }
 }
 impl Error for DescribeICD10CMInferenceJobError {}
+impl ServiceErrorCode for DescribeICD10CMInferenceJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeICD10CMInferenceJobError::InternalServer(_) => "InternalServerException",
+            DescribeICD10CMInferenceJobError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeICD10CMInferenceJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeICD10CMInferenceJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribePHIDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum DescribePHIDetectionJobError {
@@ -1224,6 +1248,16 @@ impl fmt::Display for DescribePHIDetectionJobError {
     }
 }
 impl Error for DescribePHIDetectionJobError {}
+impl ServiceErrorCode for DescribePHIDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePHIDetectionJobError::InternalServer(_) => "InternalServerException",
+            DescribePHIDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            DescribePHIDetectionJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribePHIDetectionJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeRxNormInferenceJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeRxNormInferenceJobError {
@@ -1282,6 +1316,16 @@ impl fmt::Display for DescribeRxNormInferenceJobError {
     }
 }
 impl Error for DescribeRxNormInferenceJobError {}
+impl ServiceErrorCode for DescribeRxNormInferenceJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRxNormInferenceJobError::InternalServer(_) => "InternalServerException",
+            DescribeRxNormInferenceJobError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeRxNormInferenceJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeRxNormInferenceJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DetectEntities
 #[derive(Debug, PartialEq)]
 pub enum DetectEntitiesError {
@@ -1344,6 +1388,18 @@ impl fmt::Display for DetectEntitiesError {
     }
 }
 impl Error for DetectEntitiesError {}
+impl ServiceErrorCode for DetectEntitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectEntitiesError::InternalServer(_) => "InternalServerException",
+            DetectEntitiesError::InvalidEncoding(_) => "InvalidEncodingException",
+            DetectEntitiesError::InvalidRequest(_) => "InvalidRequestException",
+            DetectEntitiesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DetectEntitiesError::TextSizeLimitExceeded(_) => "TextSizeLimitExceededException",
+            DetectEntitiesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DetectEntitiesV2
 #[derive(Debug, PartialEq)]
 pub enum DetectEntitiesV2Error {
@@ -1406,6 +1462,18 @@ impl fmt::Display for DetectEntitiesV2Error {
     }
 }
 impl Error for DetectEntitiesV2Error {}
+impl ServiceErrorCode for DetectEntitiesV2Error {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectEntitiesV2Error::InternalServer(_) => "InternalServerException",
+            DetectEntitiesV2Error::InvalidEncoding(_) => "InvalidEncodingException",
+            DetectEntitiesV2Error::InvalidRequest(_) => "InvalidRequestException",
+            DetectEntitiesV2Error::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DetectEntitiesV2Error::TextSizeLimitExceeded(_) => "TextSizeLimitExceededException",
+            DetectEntitiesV2Error::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DetectPHI
 #[derive(Debug, PartialEq)]
 pub enum DetectPHIError {
@@ -1466,6 +1534,18 @@ impl fmt::Display for DetectPHIError {
     }
 }
 impl Error for DetectPHIError {}
+impl ServiceErrorCode for DetectPHIError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectPHIError::InternalServer(_) => "InternalServerException",
+            DetectPHIError::InvalidEncoding(_) => "InvalidEncodingException",
+            DetectPHIError::InvalidRequest(_) => "InvalidRequestException",
+            DetectPHIError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DetectPHIError::TextSizeLimitExceeded(_) => "TextSizeLimitExceededException",
+            DetectPHIError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by InferICD10CM
 #[derive(Debug, PartialEq)]
 pub enum InferICD10CMError {
@@ -1526,6 +1606,18 @@ impl fmt::Display for InferICD10CMError {
     }
 }
 impl Error for InferICD10CMError {}
+impl ServiceErrorCode for InferICD10CMError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InferICD10CMError::InternalServer(_) => "InternalServerException",
+            InferICD10CMError::InvalidEncoding(_) => "InvalidEncodingException",
+            InferICD10CMError::InvalidRequest(_) => "InvalidRequestException",
+            InferICD10CMError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            InferICD10CMError::TextSizeLimitExceeded(_) => "TextSizeLimitExceededException",
+            InferICD10CMError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by InferRxNorm
 #[derive(Debug, PartialEq)]
 pub enum InferRxNormError {
@@ -1586,6 +1678,18 @@ impl fmt::Display for InferRxNormError {
     }
 }
 impl Error for InferRxNormError {}
+impl ServiceErrorCode for InferRxNormError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InferRxNormError::InternalServer(_) => "InternalServerException",
+            InferRxNormError::InvalidEncoding(_) => "InvalidEncodingException",
+            InferRxNormError::InvalidRequest(_) => "InvalidRequestException",
+            InferRxNormError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            InferRxNormError::TextSizeLimitExceeded(_) => "TextSizeLimitExceededException",
+            InferRxNormError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListEntitiesDetectionV2Jobs
 #[derive(Debug, PartialEq)]
 pub enum ListEntitiesDetectionV2JobsError {
@@ -1636,6 +1740,15 @@ impl fmt::Display for ListEntitiesDetectionV2JobsError {
     }
 }
 impl Error for ListEntitiesDetectionV2JobsError {}
+impl ServiceErrorCode for ListEntitiesDetectionV2JobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEntitiesDetectionV2JobsError::InternalServer(_) => "InternalServerException",
+            ListEntitiesDetectionV2JobsError::InvalidRequest(_) => "InvalidRequestException",
+            ListEntitiesDetectionV2JobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListICD10CMInferenceJobs
 #[derive(Debug, PartialEq)]
 pub enum ListICD10CMInferenceJobsError {
@@ -1684,6 +1797,15 @@ impl fmt::Display for ListICD10CMInferenceJobsError {
     }
 }
 impl Error for ListICD10CMInferenceJobsError {}
+impl ServiceErrorCode for ListICD10CMInferenceJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListICD10CMInferenceJobsError::InternalServer(_) => "InternalServerException",
+            ListICD10CMInferenceJobsError::InvalidRequest(_) => "InvalidRequestException",
+            ListICD10CMInferenceJobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListPHIDetectionJobs
 #[derive(Debug, PartialEq)]
 pub enum ListPHIDetectionJobsError {
@@ -1728,6 +1850,15 @@ impl fmt::Display for ListPHIDetectionJobsError {
     }
 }
 impl Error for ListPHIDetectionJobsError {}
+impl ServiceErrorCode for ListPHIDetectionJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPHIDetectionJobsError::InternalServer(_) => "InternalServerException",
+            ListPHIDetectionJobsError::InvalidRequest(_) => "InvalidRequestException",
+            ListPHIDetectionJobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListRxNormInferenceJobs
 #[derive(Debug, PartialEq)]
 pub enum ListRxNormInferenceJobsError {
@@ -1776,6 +1907,15 @@ impl fmt::Display for ListRxNormInferenceJobsError {
     }
 }
 impl Error for ListRxNormInferenceJobsError {}
+impl ServiceErrorCode for ListRxNormInferenceJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRxNormInferenceJobsError::InternalServer(_) => "InternalServerException",
+            ListRxNormInferenceJobsError::InvalidRequest(_) => "InvalidRequestException",
+            ListRxNormInferenceJobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StartEntitiesDetectionV2Job
 #[derive(Debug, PartialEq)]
 pub enum StartEntitiesDetectionV2JobError {
@@ -1834,6 +1974,16 @@ impl fmt::Display for StartEntitiesDetectionV2JobError {
     }
 }
 impl Error for StartEntitiesDetectionV2JobError {}
+impl ServiceErrorCode for StartEntitiesDetectionV2JobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartEntitiesDetectionV2JobError::InternalServer(_) => "InternalServerException",
+            StartEntitiesDetectionV2JobError::InvalidRequest(_) => "InvalidRequestException",
+            StartEntitiesDetectionV2JobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartEntitiesDetectionV2JobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StartICD10CMInferenceJob
 #[derive(Debug, PartialEq)]
 pub enum StartICD10CMInferenceJobError {
@@ -1890,6 +2040,16 @@ impl fmt::Display for StartICD10CMInferenceJobError {
     }
 }
 impl Error for StartICD10CMInferenceJobError {}
+impl ServiceErrorCode for StartICD10CMInferenceJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartICD10CMInferenceJobError::InternalServer(_) => "InternalServerException",
+            StartICD10CMInferenceJobError::InvalidRequest(_) => "InvalidRequestException",
+            StartICD10CMInferenceJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartICD10CMInferenceJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StartPHIDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum StartPHIDetectionJobError {
@@ -1942,6 +2102,16 @@ impl fmt::Display for StartPHIDetectionJobError {
     }
 }
 impl Error for StartPHIDetectionJobError {}
+impl ServiceErrorCode for StartPHIDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartPHIDetectionJobError::InternalServer(_) => "InternalServerException",
+            StartPHIDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            StartPHIDetectionJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartPHIDetectionJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StartRxNormInferenceJob
 #[derive(Debug, PartialEq)]
 pub enum StartRxNormInferenceJobError {
@@ -1998,6 +2168,16 @@ impl fmt::Display for StartRxNormInferenceJobError {
     }
 }
 impl Error for StartRxNormInferenceJobError {}
+impl ServiceErrorCode for StartRxNormInferenceJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartRxNormInferenceJobError::InternalServer(_) => "InternalServerException",
+            StartRxNormInferenceJobError::InvalidRequest(_) => "InvalidRequestException",
+            StartRxNormInferenceJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartRxNormInferenceJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StopEntitiesDetectionV2Job
 #[derive(Debug, PartialEq)]
 pub enum StopEntitiesDetectionV2JobError {
@@ -2048,6 +2228,15 @@ impl fmt::Display for StopEntitiesDetectionV2JobError {
     }
 }
 impl Error for StopEntitiesDetectionV2JobError {}
+impl ServiceErrorCode for StopEntitiesDetectionV2JobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopEntitiesDetectionV2JobError::InternalServer(_) => "InternalServerException",
+            StopEntitiesDetectionV2JobError::InvalidRequest(_) => "InvalidRequestException",
+            StopEntitiesDetectionV2JobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopICD10CMInferenceJob
 #[derive(Debug, PartialEq)]
 pub enum StopICD10CMInferenceJobError {
@@ -2096,6 +2285,15 @@ impl fmt::Display for StopICD10CMInferenceJobError {
     }
 }
 impl Error for StopICD10CMInferenceJobError {}
+impl ServiceErrorCode for StopICD10CMInferenceJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopICD10CMInferenceJobError::InternalServer(_) => "InternalServerException",
+            StopICD10CMInferenceJobError::InvalidRequest(_) => "InvalidRequestException",
+            StopICD10CMInferenceJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopPHIDetectionJob
 #[derive(Debug, PartialEq)]
 pub enum StopPHIDetectionJobError {
@@ -2140,6 +2338,15 @@ impl fmt::Display for StopPHIDetectionJobError {
     }
 }
 impl Error for StopPHIDetectionJobError {}
+impl ServiceErrorCode for StopPHIDetectionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopPHIDetectionJobError::InternalServer(_) => "InternalServerException",
+            StopPHIDetectionJobError::InvalidRequest(_) => "InvalidRequestException",
+            StopPHIDetectionJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopRxNormInferenceJob
 #[derive(Debug, PartialEq)]
 pub enum StopRxNormInferenceJobError {
@@ -2188,6 +2395,15 @@ impl fmt::Display for StopRxNormInferenceJobError {
     }
 }
 impl Error for StopRxNormInferenceJobError {}
+impl ServiceErrorCode for StopRxNormInferenceJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopRxNormInferenceJobError::InternalServer(_) => "InternalServerException",
+            StopRxNormInferenceJobError::InvalidRequest(_) => "InvalidRequestException",
+            StopRxNormInferenceJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the ComprehendMedical API. ComprehendMedical clients implement this trait.
 #[async_trait]
 pub trait ComprehendMedical {
@@ -2641,6 +2857,10 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: StartEntitiesDetectionV2JobRequest,
     ) -> Result<StartEntitiesDetectionV2JobResponse, RusotoError<StartEntitiesDetectionV2JobError>>
     {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -2663,6 +2883,10 @@ impl ComprehendMedical for ComprehendMedicalClient {
         &self,
         input: StartICD10CMInferenceJobRequest,
     ) -> Result<StartICD10CMInferenceJobResponse, RusotoError<StartICD10CMInferenceJobError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -2685,6 +2909,10 @@ impl ComprehendMedical for ComprehendMedicalClient {
         &self,
         input: StartPHIDetectionJobRequest,
     ) -> Result<StartPHIDetectionJobResponse, RusotoError<StartPHIDetectionJobError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -2707,6 +2935,10 @@ impl ComprehendMedical for ComprehendMedicalClient {
         &self,
         input: StartRxNormInferenceJobRequest,
     ) -> Result<StartRxNormInferenceJobResponse, RusotoError<StartRxNormInferenceJobError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
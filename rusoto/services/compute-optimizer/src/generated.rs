@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -996,6 +996,28 @@ impl fmt::Display for DescribeRecommendationExportJobsError {
     }
 }
 impl Error for DescribeRecommendationExportJobsError {}
+impl ServiceErrorCode for DescribeRecommendationExportJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRecommendationExportJobsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeRecommendationExportJobsError::InternalServer(_) => "InternalServerException",
+            DescribeRecommendationExportJobsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeRecommendationExportJobsError::MissingAuthenticationToken(_) => {
+                "MissingAuthenticationToken"
+            }
+            DescribeRecommendationExportJobsError::OptInRequired(_) => "OptInRequiredException",
+            DescribeRecommendationExportJobsError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DescribeRecommendationExportJobsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DescribeRecommendationExportJobsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ExportAutoScalingGroupRecommendations
 #[derive(Debug, PartialEq)]
 pub enum ExportAutoScalingGroupRecommendationsError {
@@ -1104,6 +1126,32 @@ impl fmt::Display for ExportAutoScalingGroupRecommendationsError {
     }
 }
 impl Error for ExportAutoScalingGroupRecommendationsError {}
+impl ServiceErrorCode for ExportAutoScalingGroupRecommendationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExportAutoScalingGroupRecommendationsError::AccessDenied(_) => "AccessDeniedException",
+            ExportAutoScalingGroupRecommendationsError::InternalServer(_) => {
+                "InternalServerException"
+            }
+            ExportAutoScalingGroupRecommendationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ExportAutoScalingGroupRecommendationsError::LimitExceeded(_) => {
+                "LimitExceededException"
+            }
+            ExportAutoScalingGroupRecommendationsError::MissingAuthenticationToken(_) => {
+                "MissingAuthenticationToken"
+            }
+            ExportAutoScalingGroupRecommendationsError::OptInRequired(_) => {
+                "OptInRequiredException"
+            }
+            ExportAutoScalingGroupRecommendationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            ExportAutoScalingGroupRecommendationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ExportEC2InstanceRecommendations
 #[derive(Debug, PartialEq)]
 pub enum ExportEC2InstanceRecommendationsError {
@@ -1208,6 +1256,26 @@ impl fmt::Display for ExportEC2InstanceRecommendationsError {
     }
 }
 impl Error for ExportEC2InstanceRecommendationsError {}
+impl ServiceErrorCode for ExportEC2InstanceRecommendationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExportEC2InstanceRecommendationsError::AccessDenied(_) => "AccessDeniedException",
+            ExportEC2InstanceRecommendationsError::InternalServer(_) => "InternalServerException",
+            ExportEC2InstanceRecommendationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ExportEC2InstanceRecommendationsError::LimitExceeded(_) => "LimitExceededException",
+            ExportEC2InstanceRecommendationsError::MissingAuthenticationToken(_) => {
+                "MissingAuthenticationToken"
+            }
+            ExportEC2InstanceRecommendationsError::OptInRequired(_) => "OptInRequiredException",
+            ExportEC2InstanceRecommendationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            ExportEC2InstanceRecommendationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetAutoScalingGroupRecommendations
 #[derive(Debug, PartialEq)]
 pub enum GetAutoScalingGroupRecommendationsError {
@@ -1316,6 +1384,28 @@ impl fmt::Display for GetAutoScalingGroupRecommendationsError {
     }
 }
 impl Error for GetAutoScalingGroupRecommendationsError {}
+impl ServiceErrorCode for GetAutoScalingGroupRecommendationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAutoScalingGroupRecommendationsError::AccessDenied(_) => "AccessDeniedException",
+            GetAutoScalingGroupRecommendationsError::InternalServer(_) => "InternalServerException",
+            GetAutoScalingGroupRecommendationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetAutoScalingGroupRecommendationsError::MissingAuthenticationToken(_) => {
+                "MissingAuthenticationToken"
+            }
+            GetAutoScalingGroupRecommendationsError::OptInRequired(_) => "OptInRequiredException",
+            GetAutoScalingGroupRecommendationsError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            GetAutoScalingGroupRecommendationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetAutoScalingGroupRecommendationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetEBSVolumeRecommendations
 #[derive(Debug, PartialEq)]
 pub enum GetEBSVolumeRecommendationsError {
@@ -1412,6 +1502,26 @@ impl fmt::Display for GetEBSVolumeRecommendationsError {
     }
 }
 impl Error for GetEBSVolumeRecommendationsError {}
+impl ServiceErrorCode for GetEBSVolumeRecommendationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEBSVolumeRecommendationsError::AccessDenied(_) => "AccessDeniedException",
+            GetEBSVolumeRecommendationsError::InternalServer(_) => "InternalServerException",
+            GetEBSVolumeRecommendationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetEBSVolumeRecommendationsError::MissingAuthenticationToken(_) => {
+                "MissingAuthenticationToken"
+            }
+            GetEBSVolumeRecommendationsError::OptInRequired(_) => "OptInRequiredException",
+            GetEBSVolumeRecommendationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetEBSVolumeRecommendationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetEBSVolumeRecommendationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetEC2InstanceRecommendations
 #[derive(Debug, PartialEq)]
 pub enum GetEC2InstanceRecommendationsError {
@@ -1510,6 +1620,26 @@ impl fmt::Display for GetEC2InstanceRecommendationsError {
     }
 }
 impl Error for GetEC2InstanceRecommendationsError {}
+impl ServiceErrorCode for GetEC2InstanceRecommendationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEC2InstanceRecommendationsError::AccessDenied(_) => "AccessDeniedException",
+            GetEC2InstanceRecommendationsError::InternalServer(_) => "InternalServerException",
+            GetEC2InstanceRecommendationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetEC2InstanceRecommendationsError::MissingAuthenticationToken(_) => {
+                "MissingAuthenticationToken"
+            }
+            GetEC2InstanceRecommendationsError::OptInRequired(_) => "OptInRequiredException",
+            GetEC2InstanceRecommendationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetEC2InstanceRecommendationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetEC2InstanceRecommendationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetEC2RecommendationProjectedMetrics
 #[derive(Debug, PartialEq)]
 pub enum GetEC2RecommendationProjectedMetricsError {
@@ -1618,6 +1748,30 @@ impl fmt::Display for GetEC2RecommendationProjectedMetricsError {
     }
 }
 impl Error for GetEC2RecommendationProjectedMetricsError {}
+impl ServiceErrorCode for GetEC2RecommendationProjectedMetricsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEC2RecommendationProjectedMetricsError::AccessDenied(_) => "AccessDeniedException",
+            GetEC2RecommendationProjectedMetricsError::InternalServer(_) => {
+                "InternalServerException"
+            }
+            GetEC2RecommendationProjectedMetricsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetEC2RecommendationProjectedMetricsError::MissingAuthenticationToken(_) => {
+                "MissingAuthenticationToken"
+            }
+            GetEC2RecommendationProjectedMetricsError::OptInRequired(_) => "OptInRequiredException",
+            GetEC2RecommendationProjectedMetricsError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            GetEC2RecommendationProjectedMetricsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetEC2RecommendationProjectedMetricsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetEnrollmentStatus
 #[derive(Debug, PartialEq)]
 pub enum GetEnrollmentStatusError {
@@ -1686,6 +1840,18 @@ impl fmt::Display for GetEnrollmentStatusError {
     }
 }
 impl Error for GetEnrollmentStatusError {}
+impl ServiceErrorCode for GetEnrollmentStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEnrollmentStatusError::AccessDenied(_) => "AccessDeniedException",
+            GetEnrollmentStatusError::InternalServer(_) => "InternalServerException",
+            GetEnrollmentStatusError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetEnrollmentStatusError::MissingAuthenticationToken(_) => "MissingAuthenticationToken",
+            GetEnrollmentStatusError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetEnrollmentStatusError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetRecommendationSummaries
 #[derive(Debug, PartialEq)]
 pub enum GetRecommendationSummariesError {
@@ -1774,6 +1940,23 @@ impl fmt::Display for GetRecommendationSummariesError {
     }
 }
 impl Error for GetRecommendationSummariesError {}
+impl ServiceErrorCode for GetRecommendationSummariesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRecommendationSummariesError::AccessDenied(_) => "AccessDeniedException",
+            GetRecommendationSummariesError::InternalServer(_) => "InternalServerException",
+            GetRecommendationSummariesError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetRecommendationSummariesError::MissingAuthenticationToken(_) => {
+                "MissingAuthenticationToken"
+            }
+            GetRecommendationSummariesError::OptInRequired(_) => "OptInRequiredException",
+            GetRecommendationSummariesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetRecommendationSummariesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateEnrollmentStatus
 #[derive(Debug, PartialEq)]
 pub enum UpdateEnrollmentStatusError {
@@ -1844,6 +2027,22 @@ impl fmt::Display for UpdateEnrollmentStatusError {
     }
 }
 impl Error for UpdateEnrollmentStatusError {}
+impl ServiceErrorCode for UpdateEnrollmentStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateEnrollmentStatusError::AccessDenied(_) => "AccessDeniedException",
+            UpdateEnrollmentStatusError::InternalServer(_) => "InternalServerException",
+            UpdateEnrollmentStatusError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateEnrollmentStatusError::MissingAuthenticationToken(_) => {
+                "MissingAuthenticationToken"
+            }
+            UpdateEnrollmentStatusError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateEnrollmentStatusError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Compute Optimizer API. AWS Compute Optimizer clients implement this trait.
 #[async_trait]
 pub trait ComputeOptimizer {
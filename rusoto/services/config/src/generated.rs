@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3885,6 +3885,15 @@ impl fmt::Display for BatchGetAggregateResourceConfigError {
     }
 }
 impl Error for BatchGetAggregateResourceConfigError {}
+impl ServiceErrorCode for BatchGetAggregateResourceConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetAggregateResourceConfigError::NoSuchConfigurationAggregator(_) => {
+                "NoSuchConfigurationAggregatorException"
+            }
+        }
+    }
+}
 /// Errors returned by BatchGetResourceConfig
 #[derive(Debug, PartialEq)]
 pub enum BatchGetResourceConfigError {
@@ -3919,6 +3928,15 @@ impl fmt::Display for BatchGetResourceConfigError {
     }
 }
 impl Error for BatchGetResourceConfigError {}
+impl ServiceErrorCode for BatchGetResourceConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetResourceConfigError::NoAvailableConfigurationRecorder(_) => {
+                "NoAvailableConfigurationRecorderException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteAggregationAuthorization
 #[derive(Debug, PartialEq)]
 pub enum DeleteAggregationAuthorizationError {
@@ -3955,6 +3973,15 @@ impl fmt::Display for DeleteAggregationAuthorizationError {
     }
 }
 impl Error for DeleteAggregationAuthorizationError {}
+impl ServiceErrorCode for DeleteAggregationAuthorizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAggregationAuthorizationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteConfigRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigRuleError {
@@ -3991,6 +4018,14 @@ impl fmt::Display for DeleteConfigRuleError {
     }
 }
 impl Error for DeleteConfigRuleError {}
+impl ServiceErrorCode for DeleteConfigRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigRuleError::NoSuchConfigRule(_) => "NoSuchConfigRuleException",
+            DeleteConfigRuleError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteConfigurationAggregator
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationAggregatorError {
@@ -4027,6 +4062,15 @@ impl fmt::Display for DeleteConfigurationAggregatorError {
     }
 }
 impl Error for DeleteConfigurationAggregatorError {}
+impl ServiceErrorCode for DeleteConfigurationAggregatorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigurationAggregatorError::NoSuchConfigurationAggregator(_) => {
+                "NoSuchConfigurationAggregatorException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteConfigurationRecorder
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationRecorderError {
@@ -4063,6 +4107,15 @@ impl fmt::Display for DeleteConfigurationRecorderError {
     }
 }
 impl Error for DeleteConfigurationRecorderError {}
+impl ServiceErrorCode for DeleteConfigurationRecorderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigurationRecorderError::NoSuchConfigurationRecorder(_) => {
+                "NoSuchConfigurationRecorderException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteConformancePack
 #[derive(Debug, PartialEq)]
 pub enum DeleteConformancePackError {
@@ -4101,6 +4154,16 @@ impl fmt::Display for DeleteConformancePackError {
     }
 }
 impl Error for DeleteConformancePackError {}
+impl ServiceErrorCode for DeleteConformancePackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConformancePackError::NoSuchConformancePack(_) => {
+                "NoSuchConformancePackException"
+            }
+            DeleteConformancePackError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteDeliveryChannel
 #[derive(Debug, PartialEq)]
 pub enum DeleteDeliveryChannelError {
@@ -4143,6 +4206,18 @@ impl fmt::Display for DeleteDeliveryChannelError {
     }
 }
 impl Error for DeleteDeliveryChannelError {}
+impl ServiceErrorCode for DeleteDeliveryChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDeliveryChannelError::LastDeliveryChannelDeleteFailed(_) => {
+                "LastDeliveryChannelDeleteFailedException"
+            }
+            DeleteDeliveryChannelError::NoSuchDeliveryChannel(_) => {
+                "NoSuchDeliveryChannelException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteEvaluationResults
 #[derive(Debug, PartialEq)]
 pub enum DeleteEvaluationResultsError {
@@ -4183,6 +4258,14 @@ impl fmt::Display for DeleteEvaluationResultsError {
     }
 }
 impl Error for DeleteEvaluationResultsError {}
+impl ServiceErrorCode for DeleteEvaluationResultsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEvaluationResultsError::NoSuchConfigRule(_) => "NoSuchConfigRuleException",
+            DeleteEvaluationResultsError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteOrganizationConfigRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteOrganizationConfigRuleError {
@@ -4237,6 +4320,19 @@ impl fmt::Display for DeleteOrganizationConfigRuleError {
     }
 }
 impl Error for DeleteOrganizationConfigRuleError {}
+impl ServiceErrorCode for DeleteOrganizationConfigRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteOrganizationConfigRuleError::NoSuchOrganizationConfigRule(_) => {
+                "NoSuchOrganizationConfigRuleException"
+            }
+            DeleteOrganizationConfigRuleError::OrganizationAccessDenied(_) => {
+                "OrganizationAccessDeniedException"
+            }
+            DeleteOrganizationConfigRuleError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteOrganizationConformancePack
 #[derive(Debug, PartialEq)]
 pub enum DeleteOrganizationConformancePackError {
@@ -4295,6 +4391,19 @@ impl fmt::Display for DeleteOrganizationConformancePackError {
     }
 }
 impl Error for DeleteOrganizationConformancePackError {}
+impl ServiceErrorCode for DeleteOrganizationConformancePackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteOrganizationConformancePackError::NoSuchOrganizationConformancePack(_) => {
+                "NoSuchOrganizationConformancePackException"
+            }
+            DeleteOrganizationConformancePackError::OrganizationAccessDenied(_) => {
+                "OrganizationAccessDeniedException"
+            }
+            DeleteOrganizationConformancePackError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeletePendingAggregationRequest
 #[derive(Debug, PartialEq)]
 pub enum DeletePendingAggregationRequestError {
@@ -4331,6 +4440,15 @@ impl fmt::Display for DeletePendingAggregationRequestError {
     }
 }
 impl Error for DeletePendingAggregationRequestError {}
+impl ServiceErrorCode for DeletePendingAggregationRequestError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePendingAggregationRequestError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteRemediationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteRemediationConfigurationError {
@@ -4389,6 +4507,21 @@ impl fmt::Display for DeleteRemediationConfigurationError {
     }
 }
 impl Error for DeleteRemediationConfigurationError {}
+impl ServiceErrorCode for DeleteRemediationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRemediationConfigurationError::InsufficientPermissions(_) => {
+                "InsufficientPermissionsException"
+            }
+            DeleteRemediationConfigurationError::NoSuchRemediationConfiguration(_) => {
+                "NoSuchRemediationConfigurationException"
+            }
+            DeleteRemediationConfigurationError::RemediationInProgress(_) => {
+                "RemediationInProgressException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteRemediationExceptions
 #[derive(Debug, PartialEq)]
 pub enum DeleteRemediationExceptionsError {
@@ -4425,6 +4558,15 @@ impl fmt::Display for DeleteRemediationExceptionsError {
     }
 }
 impl Error for DeleteRemediationExceptionsError {}
+impl ServiceErrorCode for DeleteRemediationExceptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRemediationExceptionsError::NoSuchRemediation(_) => {
+                "NoSuchRemediationExceptionException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteResourceConfig
 #[derive(Debug, PartialEq)]
 pub enum DeleteResourceConfigError {
@@ -4459,6 +4601,15 @@ impl fmt::Display for DeleteResourceConfigError {
     }
 }
 impl Error for DeleteResourceConfigError {}
+impl ServiceErrorCode for DeleteResourceConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResourceConfigError::NoRunningConfigurationRecorder(_) => {
+                "NoRunningConfigurationRecorderException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteRetentionConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteRetentionConfigurationError {
@@ -4505,6 +4656,18 @@ impl fmt::Display for DeleteRetentionConfigurationError {
     }
 }
 impl Error for DeleteRetentionConfigurationError {}
+impl ServiceErrorCode for DeleteRetentionConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRetentionConfigurationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteRetentionConfigurationError::NoSuchRetentionConfiguration(_) => {
+                "NoSuchRetentionConfigurationException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteStoredQuery
 #[derive(Debug, PartialEq)]
 pub enum DeleteStoredQueryError {
@@ -4535,6 +4698,13 @@ impl fmt::Display for DeleteStoredQueryError {
     }
 }
 impl Error for DeleteStoredQueryError {}
+impl ServiceErrorCode for DeleteStoredQueryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStoredQueryError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeliverConfigSnapshot
 #[derive(Debug, PartialEq)]
 pub enum DeliverConfigSnapshotError {
@@ -4587,6 +4757,21 @@ impl fmt::Display for DeliverConfigSnapshotError {
     }
 }
 impl Error for DeliverConfigSnapshotError {}
+impl ServiceErrorCode for DeliverConfigSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeliverConfigSnapshotError::NoAvailableConfigurationRecorder(_) => {
+                "NoAvailableConfigurationRecorderException"
+            }
+            DeliverConfigSnapshotError::NoRunningConfigurationRecorder(_) => {
+                "NoRunningConfigurationRecorderException"
+            }
+            DeliverConfigSnapshotError::NoSuchDeliveryChannel(_) => {
+                "NoSuchDeliveryChannelException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeAggregateComplianceByConfigRules
 #[derive(Debug, PartialEq)]
 pub enum DescribeAggregateComplianceByConfigRulesError {
@@ -4643,6 +4828,21 @@ impl fmt::Display for DescribeAggregateComplianceByConfigRulesError {
     }
 }
 impl Error for DescribeAggregateComplianceByConfigRulesError {}
+impl ServiceErrorCode for DescribeAggregateComplianceByConfigRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAggregateComplianceByConfigRulesError::InvalidLimit(_) => {
+                "InvalidLimitException"
+            }
+            DescribeAggregateComplianceByConfigRulesError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribeAggregateComplianceByConfigRulesError::NoSuchConfigurationAggregator(_) => {
+                "NoSuchConfigurationAggregatorException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeAggregationAuthorizations
 #[derive(Debug, PartialEq)]
 pub enum DescribeAggregationAuthorizationsError {
@@ -4699,6 +4899,19 @@ impl fmt::Display for DescribeAggregationAuthorizationsError {
     }
 }
 impl Error for DescribeAggregationAuthorizationsError {}
+impl ServiceErrorCode for DescribeAggregationAuthorizationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAggregationAuthorizationsError::InvalidLimit(_) => "InvalidLimitException",
+            DescribeAggregationAuthorizationsError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribeAggregationAuthorizationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeComplianceByConfigRule
 #[derive(Debug, PartialEq)]
 pub enum DescribeComplianceByConfigRuleError {
@@ -4755,6 +4968,17 @@ impl fmt::Display for DescribeComplianceByConfigRuleError {
     }
 }
 impl Error for DescribeComplianceByConfigRuleError {}
+impl ServiceErrorCode for DescribeComplianceByConfigRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeComplianceByConfigRuleError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeComplianceByConfigRuleError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeComplianceByConfigRuleError::NoSuchConfigRule(_) => "NoSuchConfigRuleException",
+        }
+    }
+}
 /// Errors returned by DescribeComplianceByResource
 #[derive(Debug, PartialEq)]
 pub enum DescribeComplianceByResourceError {
@@ -4801,6 +5025,16 @@ impl fmt::Display for DescribeComplianceByResourceError {
     }
 }
 impl Error for DescribeComplianceByResourceError {}
+impl ServiceErrorCode for DescribeComplianceByResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeComplianceByResourceError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeComplianceByResourceError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeConfigRuleEvaluationStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigRuleEvaluationStatusError {
@@ -4857,6 +5091,21 @@ impl fmt::Display for DescribeConfigRuleEvaluationStatusError {
     }
 }
 impl Error for DescribeConfigRuleEvaluationStatusError {}
+impl ServiceErrorCode for DescribeConfigRuleEvaluationStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConfigRuleEvaluationStatusError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribeConfigRuleEvaluationStatusError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeConfigRuleEvaluationStatusError::NoSuchConfigRule(_) => {
+                "NoSuchConfigRuleException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeConfigRules
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigRulesError {
@@ -4897,6 +5146,14 @@ impl fmt::Display for DescribeConfigRulesError {
     }
 }
 impl Error for DescribeConfigRulesError {}
+impl ServiceErrorCode for DescribeConfigRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConfigRulesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeConfigRulesError::NoSuchConfigRule(_) => "NoSuchConfigRuleException",
+        }
+    }
+}
 /// Errors returned by DescribeConfigurationAggregatorSourcesStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigurationAggregatorSourcesStatusError {
@@ -4947,6 +5204,24 @@ impl fmt::Display for DescribeConfigurationAggregatorSourcesStatusError {
     }
 }
 impl Error for DescribeConfigurationAggregatorSourcesStatusError {}
+impl ServiceErrorCode for DescribeConfigurationAggregatorSourcesStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConfigurationAggregatorSourcesStatusError::InvalidLimit(_) => {
+                "InvalidLimitException"
+            }
+            DescribeConfigurationAggregatorSourcesStatusError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribeConfigurationAggregatorSourcesStatusError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeConfigurationAggregatorSourcesStatusError::NoSuchConfigurationAggregator(_) => {
+                "NoSuchConfigurationAggregatorException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeConfigurationAggregators
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigurationAggregatorsError {
@@ -5015,6 +5290,22 @@ impl fmt::Display for DescribeConfigurationAggregatorsError {
     }
 }
 impl Error for DescribeConfigurationAggregatorsError {}
+impl ServiceErrorCode for DescribeConfigurationAggregatorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConfigurationAggregatorsError::InvalidLimit(_) => "InvalidLimitException",
+            DescribeConfigurationAggregatorsError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribeConfigurationAggregatorsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeConfigurationAggregatorsError::NoSuchConfigurationAggregator(_) => {
+                "NoSuchConfigurationAggregatorException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeConfigurationRecorderStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigurationRecorderStatusError {
@@ -5053,6 +5344,15 @@ impl fmt::Display for DescribeConfigurationRecorderStatusError {
     }
 }
 impl Error for DescribeConfigurationRecorderStatusError {}
+impl ServiceErrorCode for DescribeConfigurationRecorderStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConfigurationRecorderStatusError::NoSuchConfigurationRecorder(_) => {
+                "NoSuchConfigurationRecorderException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeConfigurationRecorders
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigurationRecordersError {
@@ -5089,6 +5389,15 @@ impl fmt::Display for DescribeConfigurationRecordersError {
     }
 }
 impl Error for DescribeConfigurationRecordersError {}
+impl ServiceErrorCode for DescribeConfigurationRecordersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConfigurationRecordersError::NoSuchConfigurationRecorder(_) => {
+                "NoSuchConfigurationRecorderException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeConformancePackCompliance
 #[derive(Debug, PartialEq)]
 pub enum DescribeConformancePackComplianceError {
@@ -5167,6 +5476,25 @@ impl fmt::Display for DescribeConformancePackComplianceError {
     }
 }
 impl Error for DescribeConformancePackComplianceError {}
+impl ServiceErrorCode for DescribeConformancePackComplianceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConformancePackComplianceError::InvalidLimit(_) => "InvalidLimitException",
+            DescribeConformancePackComplianceError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribeConformancePackComplianceError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeConformancePackComplianceError::NoSuchConfigRuleInConformancePack(_) => {
+                "NoSuchConfigRuleInConformancePackException"
+            }
+            DescribeConformancePackComplianceError::NoSuchConformancePack(_) => {
+                "NoSuchConformancePackException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeConformancePackStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeConformancePackStatusError {
@@ -5221,6 +5549,17 @@ impl fmt::Display for DescribeConformancePackStatusError {
     }
 }
 impl Error for DescribeConformancePackStatusError {}
+impl ServiceErrorCode for DescribeConformancePackStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConformancePackStatusError::InvalidLimit(_) => "InvalidLimitException",
+            DescribeConformancePackStatusError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeConformancePackStatusError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeConformancePacks
 #[derive(Debug, PartialEq)]
 pub enum DescribeConformancePacksError {
@@ -5281,6 +5620,20 @@ impl fmt::Display for DescribeConformancePacksError {
     }
 }
 impl Error for DescribeConformancePacksError {}
+impl ServiceErrorCode for DescribeConformancePacksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConformancePacksError::InvalidLimit(_) => "InvalidLimitException",
+            DescribeConformancePacksError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeConformancePacksError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeConformancePacksError::NoSuchConformancePack(_) => {
+                "NoSuchConformancePackException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDeliveryChannelStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeDeliveryChannelStatusError {
@@ -5317,6 +5670,15 @@ impl fmt::Display for DescribeDeliveryChannelStatusError {
     }
 }
 impl Error for DescribeDeliveryChannelStatusError {}
+impl ServiceErrorCode for DescribeDeliveryChannelStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDeliveryChannelStatusError::NoSuchDeliveryChannel(_) => {
+                "NoSuchDeliveryChannelException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDeliveryChannels
 #[derive(Debug, PartialEq)]
 pub enum DescribeDeliveryChannelsError {
@@ -5351,6 +5713,15 @@ impl fmt::Display for DescribeDeliveryChannelsError {
     }
 }
 impl Error for DescribeDeliveryChannelsError {}
+impl ServiceErrorCode for DescribeDeliveryChannelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDeliveryChannelsError::NoSuchDeliveryChannel(_) => {
+                "NoSuchDeliveryChannelException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeOrganizationConfigRuleStatuses
 #[derive(Debug, PartialEq)]
 pub enum DescribeOrganizationConfigRuleStatusesError {
@@ -5421,6 +5792,22 @@ impl fmt::Display for DescribeOrganizationConfigRuleStatusesError {
     }
 }
 impl Error for DescribeOrganizationConfigRuleStatusesError {}
+impl ServiceErrorCode for DescribeOrganizationConfigRuleStatusesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeOrganizationConfigRuleStatusesError::InvalidLimit(_) => "InvalidLimitException",
+            DescribeOrganizationConfigRuleStatusesError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribeOrganizationConfigRuleStatusesError::NoSuchOrganizationConfigRule(_) => {
+                "NoSuchOrganizationConfigRuleException"
+            }
+            DescribeOrganizationConfigRuleStatusesError::OrganizationAccessDenied(_) => {
+                "OrganizationAccessDeniedException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeOrganizationConfigRules
 #[derive(Debug, PartialEq)]
 pub enum DescribeOrganizationConfigRulesError {
@@ -5485,6 +5872,22 @@ impl fmt::Display for DescribeOrganizationConfigRulesError {
     }
 }
 impl Error for DescribeOrganizationConfigRulesError {}
+impl ServiceErrorCode for DescribeOrganizationConfigRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeOrganizationConfigRulesError::InvalidLimit(_) => "InvalidLimitException",
+            DescribeOrganizationConfigRulesError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribeOrganizationConfigRulesError::NoSuchOrganizationConfigRule(_) => {
+                "NoSuchOrganizationConfigRuleException"
+            }
+            DescribeOrganizationConfigRulesError::OrganizationAccessDenied(_) => {
+                "OrganizationAccessDeniedException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeOrganizationConformancePackStatuses
 #[derive(Debug, PartialEq)]
 pub enum DescribeOrganizationConformancePackStatusesError {
@@ -5535,6 +5938,24 @@ impl fmt::Display for DescribeOrganizationConformancePackStatusesError {
     }
 }
 impl Error for DescribeOrganizationConformancePackStatusesError {}
+impl ServiceErrorCode for DescribeOrganizationConformancePackStatusesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeOrganizationConformancePackStatusesError::InvalidLimit(_) => {
+                "InvalidLimitException"
+            }
+            DescribeOrganizationConformancePackStatusesError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribeOrganizationConformancePackStatusesError::NoSuchOrganizationConformancePack(
+                _,
+            ) => "NoSuchOrganizationConformancePackException",
+            DescribeOrganizationConformancePackStatusesError::OrganizationAccessDenied(_) => {
+                "OrganizationAccessDeniedException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeOrganizationConformancePacks
 #[derive(Debug, PartialEq)]
 pub enum DescribeOrganizationConformancePacksError {
@@ -5603,6 +6024,22 @@ impl fmt::Display for DescribeOrganizationConformancePacksError {
     }
 }
 impl Error for DescribeOrganizationConformancePacksError {}
+impl ServiceErrorCode for DescribeOrganizationConformancePacksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeOrganizationConformancePacksError::InvalidLimit(_) => "InvalidLimitException",
+            DescribeOrganizationConformancePacksError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribeOrganizationConformancePacksError::NoSuchOrganizationConformancePack(_) => {
+                "NoSuchOrganizationConformancePackException"
+            }
+            DescribeOrganizationConformancePacksError::OrganizationAccessDenied(_) => {
+                "OrganizationAccessDeniedException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribePendingAggregationRequests
 #[derive(Debug, PartialEq)]
 pub enum DescribePendingAggregationRequestsError {
@@ -5659,6 +6096,19 @@ impl fmt::Display for DescribePendingAggregationRequestsError {
     }
 }
 impl Error for DescribePendingAggregationRequestsError {}
+impl ServiceErrorCode for DescribePendingAggregationRequestsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePendingAggregationRequestsError::InvalidLimit(_) => "InvalidLimitException",
+            DescribePendingAggregationRequestsError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribePendingAggregationRequestsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeRemediationConfigurations
 #[derive(Debug, PartialEq)]
 pub enum DescribeRemediationConfigurationsError {}
@@ -5683,6 +6133,11 @@ impl fmt::Display for DescribeRemediationConfigurationsError {
     }
 }
 impl Error for DescribeRemediationConfigurationsError {}
+impl ServiceErrorCode for DescribeRemediationConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeRemediationExceptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeRemediationExceptionsError {
@@ -5729,6 +6184,16 @@ impl fmt::Display for DescribeRemediationExceptionsError {
     }
 }
 impl Error for DescribeRemediationExceptionsError {}
+impl ServiceErrorCode for DescribeRemediationExceptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRemediationExceptionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeRemediationExceptionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeRemediationExecutionStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeRemediationExecutionStatusError {
@@ -5777,6 +6242,18 @@ impl fmt::Display for DescribeRemediationExecutionStatusError {
     }
 }
 impl Error for DescribeRemediationExecutionStatusError {}
+impl ServiceErrorCode for DescribeRemediationExecutionStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRemediationExecutionStatusError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribeRemediationExecutionStatusError::NoSuchRemediationConfiguration(_) => {
+                "NoSuchRemediationConfigurationException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeRetentionConfigurations
 #[derive(Debug, PartialEq)]
 pub enum DescribeRetentionConfigurationsError {
@@ -5833,6 +6310,21 @@ impl fmt::Display for DescribeRetentionConfigurationsError {
     }
 }
 impl Error for DescribeRetentionConfigurationsError {}
+impl ServiceErrorCode for DescribeRetentionConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRetentionConfigurationsError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            DescribeRetentionConfigurationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeRetentionConfigurationsError::NoSuchRetentionConfiguration(_) => {
+                "NoSuchRetentionConfigurationException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAggregateComplianceDetailsByConfigRule
 #[derive(Debug, PartialEq)]
 pub enum GetAggregateComplianceDetailsByConfigRuleError {
@@ -5889,6 +6381,21 @@ impl fmt::Display for GetAggregateComplianceDetailsByConfigRuleError {
     }
 }
 impl Error for GetAggregateComplianceDetailsByConfigRuleError {}
+impl ServiceErrorCode for GetAggregateComplianceDetailsByConfigRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAggregateComplianceDetailsByConfigRuleError::InvalidLimit(_) => {
+                "InvalidLimitException"
+            }
+            GetAggregateComplianceDetailsByConfigRuleError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            GetAggregateComplianceDetailsByConfigRuleError::NoSuchConfigurationAggregator(_) => {
+                "NoSuchConfigurationAggregatorException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAggregateConfigRuleComplianceSummary
 #[derive(Debug, PartialEq)]
 pub enum GetAggregateConfigRuleComplianceSummaryError {
@@ -5947,6 +6454,21 @@ impl fmt::Display for GetAggregateConfigRuleComplianceSummaryError {
     }
 }
 impl Error for GetAggregateConfigRuleComplianceSummaryError {}
+impl ServiceErrorCode for GetAggregateConfigRuleComplianceSummaryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAggregateConfigRuleComplianceSummaryError::InvalidLimit(_) => {
+                "InvalidLimitException"
+            }
+            GetAggregateConfigRuleComplianceSummaryError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            GetAggregateConfigRuleComplianceSummaryError::NoSuchConfigurationAggregator(_) => {
+                "NoSuchConfigurationAggregatorException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAggregateDiscoveredResourceCounts
 #[derive(Debug, PartialEq)]
 pub enum GetAggregateDiscoveredResourceCountsError {
@@ -6005,6 +6527,19 @@ impl fmt::Display for GetAggregateDiscoveredResourceCountsError {
     }
 }
 impl Error for GetAggregateDiscoveredResourceCountsError {}
+impl ServiceErrorCode for GetAggregateDiscoveredResourceCountsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAggregateDiscoveredResourceCountsError::InvalidLimit(_) => "InvalidLimitException",
+            GetAggregateDiscoveredResourceCountsError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            GetAggregateDiscoveredResourceCountsError::NoSuchConfigurationAggregator(_) => {
+                "NoSuchConfigurationAggregatorException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAggregateResourceConfig
 #[derive(Debug, PartialEq)]
 pub enum GetAggregateResourceConfigError {
@@ -6061,6 +6596,21 @@ impl fmt::Display for GetAggregateResourceConfigError {
     }
 }
 impl Error for GetAggregateResourceConfigError {}
+impl ServiceErrorCode for GetAggregateResourceConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAggregateResourceConfigError::NoSuchConfigurationAggregator(_) => {
+                "NoSuchConfigurationAggregatorException"
+            }
+            GetAggregateResourceConfigError::OversizedConfigurationItem(_) => {
+                "OversizedConfigurationItemException"
+            }
+            GetAggregateResourceConfigError::ResourceNotDiscovered(_) => {
+                "ResourceNotDiscoveredException"
+            }
+        }
+    }
+}
 /// Errors returned by GetComplianceDetailsByConfigRule
 #[derive(Debug, PartialEq)]
 pub enum GetComplianceDetailsByConfigRuleError {
@@ -6117,6 +6667,21 @@ impl fmt::Display for GetComplianceDetailsByConfigRuleError {
     }
 }
 impl Error for GetComplianceDetailsByConfigRuleError {}
+impl ServiceErrorCode for GetComplianceDetailsByConfigRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetComplianceDetailsByConfigRuleError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            GetComplianceDetailsByConfigRuleError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetComplianceDetailsByConfigRuleError::NoSuchConfigRule(_) => {
+                "NoSuchConfigRuleException"
+            }
+        }
+    }
+}
 /// Errors returned by GetComplianceDetailsByResource
 #[derive(Debug, PartialEq)]
 pub enum GetComplianceDetailsByResourceError {
@@ -6153,6 +6718,15 @@ impl fmt::Display for GetComplianceDetailsByResourceError {
     }
 }
 impl Error for GetComplianceDetailsByResourceError {}
+impl ServiceErrorCode for GetComplianceDetailsByResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetComplianceDetailsByResourceError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+        }
+    }
+}
 /// Errors returned by GetComplianceSummaryByConfigRule
 #[derive(Debug, PartialEq)]
 pub enum GetComplianceSummaryByConfigRuleError {}
@@ -6177,6 +6751,11 @@ impl fmt::Display for GetComplianceSummaryByConfigRuleError {
     }
 }
 impl Error for GetComplianceSummaryByConfigRuleError {}
+impl ServiceErrorCode for GetComplianceSummaryByConfigRuleError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetComplianceSummaryByResourceType
 #[derive(Debug, PartialEq)]
 pub enum GetComplianceSummaryByResourceTypeError {
@@ -6213,6 +6792,15 @@ impl fmt::Display for GetComplianceSummaryByResourceTypeError {
     }
 }
 impl Error for GetComplianceSummaryByResourceTypeError {}
+impl ServiceErrorCode for GetComplianceSummaryByResourceTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetComplianceSummaryByResourceTypeError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+        }
+    }
+}
 /// Errors returned by GetConformancePackComplianceDetails
 #[derive(Debug, PartialEq)]
 pub enum GetConformancePackComplianceDetailsError {
@@ -6291,6 +6879,25 @@ impl fmt::Display for GetConformancePackComplianceDetailsError {
     }
 }
 impl Error for GetConformancePackComplianceDetailsError {}
+impl ServiceErrorCode for GetConformancePackComplianceDetailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConformancePackComplianceDetailsError::InvalidLimit(_) => "InvalidLimitException",
+            GetConformancePackComplianceDetailsError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            GetConformancePackComplianceDetailsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetConformancePackComplianceDetailsError::NoSuchConfigRuleInConformancePack(_) => {
+                "NoSuchConfigRuleInConformancePackException"
+            }
+            GetConformancePackComplianceDetailsError::NoSuchConformancePack(_) => {
+                "NoSuchConformancePackException"
+            }
+        }
+    }
+}
 /// Errors returned by GetConformancePackComplianceSummary
 #[derive(Debug, PartialEq)]
 pub enum GetConformancePackComplianceSummaryError {
@@ -6347,6 +6954,19 @@ impl fmt::Display for GetConformancePackComplianceSummaryError {
     }
 }
 impl Error for GetConformancePackComplianceSummaryError {}
+impl ServiceErrorCode for GetConformancePackComplianceSummaryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConformancePackComplianceSummaryError::InvalidLimit(_) => "InvalidLimitException",
+            GetConformancePackComplianceSummaryError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            GetConformancePackComplianceSummaryError::NoSuchConformancePack(_) => {
+                "NoSuchConformancePackException"
+            }
+        }
+    }
+}
 /// Errors returned by GetDiscoveredResourceCounts
 #[derive(Debug, PartialEq)]
 pub enum GetDiscoveredResourceCountsError {
@@ -6389,6 +7009,14 @@ impl fmt::Display for GetDiscoveredResourceCountsError {
     }
 }
 impl Error for GetDiscoveredResourceCountsError {}
+impl ServiceErrorCode for GetDiscoveredResourceCountsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDiscoveredResourceCountsError::InvalidLimit(_) => "InvalidLimitException",
+            GetDiscoveredResourceCountsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by GetOrganizationConfigRuleDetailedStatus
 #[derive(Debug, PartialEq)]
 pub enum GetOrganizationConfigRuleDetailedStatusError {
@@ -6459,6 +7087,24 @@ impl fmt::Display for GetOrganizationConfigRuleDetailedStatusError {
     }
 }
 impl Error for GetOrganizationConfigRuleDetailedStatusError {}
+impl ServiceErrorCode for GetOrganizationConfigRuleDetailedStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOrganizationConfigRuleDetailedStatusError::InvalidLimit(_) => {
+                "InvalidLimitException"
+            }
+            GetOrganizationConfigRuleDetailedStatusError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            GetOrganizationConfigRuleDetailedStatusError::NoSuchOrganizationConfigRule(_) => {
+                "NoSuchOrganizationConfigRuleException"
+            }
+            GetOrganizationConfigRuleDetailedStatusError::OrganizationAccessDenied(_) => {
+                "OrganizationAccessDeniedException"
+            }
+        }
+    }
+}
 /// Errors returned by GetOrganizationConformancePackDetailedStatus
 #[derive(Debug, PartialEq)]
 pub enum GetOrganizationConformancePackDetailedStatusError {
@@ -6501,6 +7147,16 @@ GetOrganizationConformancePackDetailedStatusError::OrganizationAccessDenied(ref
     }
 }
 impl Error for GetOrganizationConformancePackDetailedStatusError {}
+impl ServiceErrorCode for GetOrganizationConformancePackDetailedStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOrganizationConformancePackDetailedStatusError::InvalidLimit(_) => "InvalidLimitException",
+            GetOrganizationConformancePackDetailedStatusError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetOrganizationConformancePackDetailedStatusError::NoSuchOrganizationConformancePack(_) => "NoSuchOrganizationConformancePackException",
+            GetOrganizationConformancePackDetailedStatusError::OrganizationAccessDenied(_) => "OrganizationAccessDeniedException",
+        }
+    }
+}
 /// Errors returned by GetResourceConfigHistory
 #[derive(Debug, PartialEq)]
 pub enum GetResourceConfigHistoryError {
@@ -6569,6 +7225,21 @@ impl fmt::Display for GetResourceConfigHistoryError {
     }
 }
 impl Error for GetResourceConfigHistoryError {}
+impl ServiceErrorCode for GetResourceConfigHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourceConfigHistoryError::InvalidLimit(_) => "InvalidLimitException",
+            GetResourceConfigHistoryError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetResourceConfigHistoryError::InvalidTimeRange(_) => "InvalidTimeRangeException",
+            GetResourceConfigHistoryError::NoAvailableConfigurationRecorder(_) => {
+                "NoAvailableConfigurationRecorderException"
+            }
+            GetResourceConfigHistoryError::ResourceNotDiscovered(_) => {
+                "ResourceNotDiscoveredException"
+            }
+        }
+    }
+}
 /// Errors returned by GetStoredQuery
 #[derive(Debug, PartialEq)]
 pub enum GetStoredQueryError {
@@ -6599,6 +7270,13 @@ impl fmt::Display for GetStoredQueryError {
     }
 }
 impl Error for GetStoredQueryError {}
+impl ServiceErrorCode for GetStoredQueryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetStoredQueryError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListAggregateDiscoveredResources
 #[derive(Debug, PartialEq)]
 pub enum ListAggregateDiscoveredResourcesError {
@@ -6657,6 +7335,19 @@ impl fmt::Display for ListAggregateDiscoveredResourcesError {
     }
 }
 impl Error for ListAggregateDiscoveredResourcesError {}
+impl ServiceErrorCode for ListAggregateDiscoveredResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAggregateDiscoveredResourcesError::InvalidLimit(_) => "InvalidLimitException",
+            ListAggregateDiscoveredResourcesError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            ListAggregateDiscoveredResourcesError::NoSuchConfigurationAggregator(_) => {
+                "NoSuchConfigurationAggregatorException"
+            }
+        }
+    }
+}
 /// Errors returned by ListDiscoveredResources
 #[derive(Debug, PartialEq)]
 pub enum ListDiscoveredResourcesError {
@@ -6707,6 +7398,17 @@ impl fmt::Display for ListDiscoveredResourcesError {
     }
 }
 impl Error for ListDiscoveredResourcesError {}
+impl ServiceErrorCode for ListDiscoveredResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDiscoveredResourcesError::InvalidLimit(_) => "InvalidLimitException",
+            ListDiscoveredResourcesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListDiscoveredResourcesError::NoAvailableConfigurationRecorder(_) => {
+                "NoAvailableConfigurationRecorderException"
+            }
+        }
+    }
+}
 /// Errors returned by ListStoredQueries
 #[derive(Debug, PartialEq)]
 pub enum ListStoredQueriesError {
@@ -6737,6 +7439,13 @@ impl fmt::Display for ListStoredQueriesError {
     }
 }
 impl Error for ListStoredQueriesError {}
+impl ServiceErrorCode for ListStoredQueriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStoredQueriesError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -6783,6 +7492,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InvalidLimit(_) => "InvalidLimitException",
+            ListTagsForResourceError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutAggregationAuthorization
 #[derive(Debug, PartialEq)]
 pub enum PutAggregationAuthorizationError {
@@ -6819,6 +7537,15 @@ impl fmt::Display for PutAggregationAuthorizationError {
     }
 }
 impl Error for PutAggregationAuthorizationError {}
+impl ServiceErrorCode for PutAggregationAuthorizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAggregationAuthorizationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+        }
+    }
+}
 /// Errors returned by PutConfigRule
 #[derive(Debug, PartialEq)]
 pub enum PutConfigRuleError {
@@ -6881,6 +7608,21 @@ impl fmt::Display for PutConfigRuleError {
     }
 }
 impl Error for PutConfigRuleError {}
+impl ServiceErrorCode for PutConfigRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutConfigRuleError::InsufficientPermissions(_) => "InsufficientPermissionsException",
+            PutConfigRuleError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            PutConfigRuleError::MaxNumberOfConfigRulesExceeded(_) => {
+                "MaxNumberOfConfigRulesExceededException"
+            }
+            PutConfigRuleError::NoAvailableConfigurationRecorder(_) => {
+                "NoAvailableConfigurationRecorderException"
+            }
+            PutConfigRuleError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by PutConfigurationAggregator
 #[derive(Debug, PartialEq)]
 pub enum PutConfigurationAggregatorError {
@@ -6963,6 +7705,26 @@ impl fmt::Display for PutConfigurationAggregatorError {
     }
 }
 impl Error for PutConfigurationAggregatorError {}
+impl ServiceErrorCode for PutConfigurationAggregatorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutConfigurationAggregatorError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            PutConfigurationAggregatorError::InvalidRole(_) => "InvalidRoleException",
+            PutConfigurationAggregatorError::LimitExceeded(_) => "LimitExceededException",
+            PutConfigurationAggregatorError::NoAvailableOrganization(_) => {
+                "NoAvailableOrganizationException"
+            }
+            PutConfigurationAggregatorError::OrganizationAccessDenied(_) => {
+                "OrganizationAccessDeniedException"
+            }
+            PutConfigurationAggregatorError::OrganizationAllFeaturesNotEnabled(_) => {
+                "OrganizationAllFeaturesNotEnabledException"
+            }
+        }
+    }
+}
 /// Errors returned by PutConfigurationRecorder
 #[derive(Debug, PartialEq)]
 pub enum PutConfigurationRecorderError {
@@ -7027,6 +7789,22 @@ impl fmt::Display for PutConfigurationRecorderError {
     }
 }
 impl Error for PutConfigurationRecorderError {}
+impl ServiceErrorCode for PutConfigurationRecorderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutConfigurationRecorderError::InvalidConfigurationRecorderName(_) => {
+                "InvalidConfigurationRecorderNameException"
+            }
+            PutConfigurationRecorderError::InvalidRecordingGroup(_) => {
+                "InvalidRecordingGroupException"
+            }
+            PutConfigurationRecorderError::InvalidRole(_) => "InvalidRoleException",
+            PutConfigurationRecorderError::MaxNumberOfConfigurationRecordersExceeded(_) => {
+                "MaxNumberOfConfigurationRecordersExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by PutConformancePack
 #[derive(Debug, PartialEq)]
 pub enum PutConformancePackError {
@@ -7093,6 +7871,23 @@ impl fmt::Display for PutConformancePackError {
     }
 }
 impl Error for PutConformancePackError {}
+impl ServiceErrorCode for PutConformancePackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutConformancePackError::ConformancePackTemplateValidation(_) => {
+                "ConformancePackTemplateValidationException"
+            }
+            PutConformancePackError::InsufficientPermissions(_) => {
+                "InsufficientPermissionsException"
+            }
+            PutConformancePackError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            PutConformancePackError::MaxNumberOfConformancePacksExceeded(_) => {
+                "MaxNumberOfConformancePacksExceededException"
+            }
+            PutConformancePackError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by PutDeliveryChannel
 #[derive(Debug, PartialEq)]
 pub enum PutDeliveryChannelError {
@@ -7179,6 +7974,27 @@ impl fmt::Display for PutDeliveryChannelError {
     }
 }
 impl Error for PutDeliveryChannelError {}
+impl ServiceErrorCode for PutDeliveryChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutDeliveryChannelError::InsufficientDeliveryPolicy(_) => {
+                "InsufficientDeliveryPolicyException"
+            }
+            PutDeliveryChannelError::InvalidDeliveryChannelName(_) => {
+                "InvalidDeliveryChannelNameException"
+            }
+            PutDeliveryChannelError::InvalidS3KeyPrefix(_) => "InvalidS3KeyPrefixException",
+            PutDeliveryChannelError::InvalidSNSTopicARN(_) => "InvalidSNSTopicARNException",
+            PutDeliveryChannelError::MaxNumberOfDeliveryChannelsExceeded(_) => {
+                "MaxNumberOfDeliveryChannelsExceededException"
+            }
+            PutDeliveryChannelError::NoAvailableConfigurationRecorder(_) => {
+                "NoAvailableConfigurationRecorderException"
+            }
+            PutDeliveryChannelError::NoSuchBucket(_) => "NoSuchBucketException",
+        }
+    }
+}
 /// Errors returned by PutEvaluations
 #[derive(Debug, PartialEq)]
 pub enum PutEvaluationsError {
@@ -7223,6 +8039,15 @@ impl fmt::Display for PutEvaluationsError {
     }
 }
 impl Error for PutEvaluationsError {}
+impl ServiceErrorCode for PutEvaluationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutEvaluationsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            PutEvaluationsError::InvalidResultToken(_) => "InvalidResultTokenException",
+            PutEvaluationsError::NoSuchConfigRule(_) => "NoSuchConfigRuleException",
+        }
+    }
+}
 /// Errors returned by PutExternalEvaluation
 #[derive(Debug, PartialEq)]
 pub enum PutExternalEvaluationError {
@@ -7263,6 +8088,16 @@ impl fmt::Display for PutExternalEvaluationError {
     }
 }
 impl Error for PutExternalEvaluationError {}
+impl ServiceErrorCode for PutExternalEvaluationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutExternalEvaluationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            PutExternalEvaluationError::NoSuchConfigRule(_) => "NoSuchConfigRuleException",
+        }
+    }
+}
 /// Errors returned by PutOrganizationConfigRule
 #[derive(Debug, PartialEq)]
 pub enum PutOrganizationConfigRuleError {
@@ -7357,6 +8192,31 @@ impl fmt::Display for PutOrganizationConfigRuleError {
     }
 }
 impl Error for PutOrganizationConfigRuleError {}
+impl ServiceErrorCode for PutOrganizationConfigRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutOrganizationConfigRuleError::InsufficientPermissions(_) => {
+                "InsufficientPermissionsException"
+            }
+            PutOrganizationConfigRuleError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            PutOrganizationConfigRuleError::MaxNumberOfOrganizationConfigRulesExceeded(_) => {
+                "MaxNumberOfOrganizationConfigRulesExceededException"
+            }
+            PutOrganizationConfigRuleError::NoAvailableOrganization(_) => {
+                "NoAvailableOrganizationException"
+            }
+            PutOrganizationConfigRuleError::OrganizationAccessDenied(_) => {
+                "OrganizationAccessDeniedException"
+            }
+            PutOrganizationConfigRuleError::OrganizationAllFeaturesNotEnabled(_) => {
+                "OrganizationAllFeaturesNotEnabledException"
+            }
+            PutOrganizationConfigRuleError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by PutOrganizationConformancePack
 #[derive(Debug, PartialEq)]
 pub enum PutOrganizationConformancePackError {
@@ -7411,6 +8271,19 @@ PutOrganizationConformancePackError::ResourceInUse(ref cause) => write!(f, "{}",
     }
 }
 impl Error for PutOrganizationConformancePackError {}
+impl ServiceErrorCode for PutOrganizationConformancePackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutOrganizationConformancePackError::InsufficientPermissions(_) => "InsufficientPermissionsException",
+            PutOrganizationConformancePackError::MaxNumberOfOrganizationConformancePacksExceeded(_) => "MaxNumberOfOrganizationConformancePacksExceededException",
+            PutOrganizationConformancePackError::NoAvailableOrganization(_) => "NoAvailableOrganizationException",
+            PutOrganizationConformancePackError::OrganizationAccessDenied(_) => "OrganizationAccessDeniedException",
+            PutOrganizationConformancePackError::OrganizationAllFeaturesNotEnabled(_) => "OrganizationAllFeaturesNotEnabledException",
+            PutOrganizationConformancePackError::OrganizationConformancePackTemplateValidation(_) => "OrganizationConformancePackTemplateValidationException",
+            PutOrganizationConformancePackError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by PutRemediationConfigurations
 #[derive(Debug, PartialEq)]
 pub enum PutRemediationConfigurationsError {
@@ -7457,6 +8330,18 @@ impl fmt::Display for PutRemediationConfigurationsError {
     }
 }
 impl Error for PutRemediationConfigurationsError {}
+impl ServiceErrorCode for PutRemediationConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRemediationConfigurationsError::InsufficientPermissions(_) => {
+                "InsufficientPermissionsException"
+            }
+            PutRemediationConfigurationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+        }
+    }
+}
 /// Errors returned by PutRemediationExceptions
 #[derive(Debug, PartialEq)]
 pub enum PutRemediationExceptionsError {
@@ -7501,6 +8386,18 @@ impl fmt::Display for PutRemediationExceptionsError {
     }
 }
 impl Error for PutRemediationExceptionsError {}
+impl ServiceErrorCode for PutRemediationExceptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRemediationExceptionsError::InsufficientPermissions(_) => {
+                "InsufficientPermissionsException"
+            }
+            PutRemediationExceptionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+        }
+    }
+}
 /// Errors returned by PutResourceConfig
 #[derive(Debug, PartialEq)]
 pub enum PutResourceConfigError {
@@ -7551,6 +8448,21 @@ impl fmt::Display for PutResourceConfigError {
     }
 }
 impl Error for PutResourceConfigError {}
+impl ServiceErrorCode for PutResourceConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutResourceConfigError::InsufficientPermissions(_) => {
+                "InsufficientPermissionsException"
+            }
+            PutResourceConfigError::MaxActiveResourcesExceeded(_) => {
+                "MaxActiveResourcesExceededException"
+            }
+            PutResourceConfigError::NoRunningConfigurationRecorder(_) => {
+                "NoRunningConfigurationRecorderException"
+            }
+        }
+    }
+}
 /// Errors returned by PutRetentionConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutRetentionConfigurationError {
@@ -7597,6 +8509,18 @@ impl fmt::Display for PutRetentionConfigurationError {
     }
 }
 impl Error for PutRetentionConfigurationError {}
+impl ServiceErrorCode for PutRetentionConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRetentionConfigurationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            PutRetentionConfigurationError::MaxNumberOfRetentionConfigurationsExceeded(_) => {
+                "MaxNumberOfRetentionConfigurationsExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by PutStoredQuery
 #[derive(Debug, PartialEq)]
 pub enum PutStoredQueryError {
@@ -7637,6 +8561,16 @@ impl fmt::Display for PutStoredQueryError {
     }
 }
 impl Error for PutStoredQueryError {}
+impl ServiceErrorCode for PutStoredQueryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutStoredQueryError::ResourceConcurrentModification(_) => {
+                "ResourceConcurrentModificationException"
+            }
+            PutStoredQueryError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by SelectAggregateResourceConfig
 #[derive(Debug, PartialEq)]
 pub enum SelectAggregateResourceConfigError {
@@ -7701,6 +8635,20 @@ impl fmt::Display for SelectAggregateResourceConfigError {
     }
 }
 impl Error for SelectAggregateResourceConfigError {}
+impl ServiceErrorCode for SelectAggregateResourceConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SelectAggregateResourceConfigError::InvalidExpression(_) => {
+                "InvalidExpressionException"
+            }
+            SelectAggregateResourceConfigError::InvalidLimit(_) => "InvalidLimitException",
+            SelectAggregateResourceConfigError::InvalidNextToken(_) => "InvalidNextTokenException",
+            SelectAggregateResourceConfigError::NoSuchConfigurationAggregator(_) => {
+                "NoSuchConfigurationAggregatorException"
+            }
+        }
+    }
+}
 /// Errors returned by SelectResourceConfig
 #[derive(Debug, PartialEq)]
 pub enum SelectResourceConfigError {
@@ -7747,6 +8695,15 @@ impl fmt::Display for SelectResourceConfigError {
     }
 }
 impl Error for SelectResourceConfigError {}
+impl ServiceErrorCode for SelectResourceConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SelectResourceConfigError::InvalidExpression(_) => "InvalidExpressionException",
+            SelectResourceConfigError::InvalidLimit(_) => "InvalidLimitException",
+            SelectResourceConfigError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by StartConfigRulesEvaluation
 #[derive(Debug, PartialEq)]
 pub enum StartConfigRulesEvaluationError {
@@ -7807,6 +8764,18 @@ impl fmt::Display for StartConfigRulesEvaluationError {
     }
 }
 impl Error for StartConfigRulesEvaluationError {}
+impl ServiceErrorCode for StartConfigRulesEvaluationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartConfigRulesEvaluationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            StartConfigRulesEvaluationError::LimitExceeded(_) => "LimitExceededException",
+            StartConfigRulesEvaluationError::NoSuchConfigRule(_) => "NoSuchConfigRuleException",
+            StartConfigRulesEvaluationError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by StartConfigurationRecorder
 #[derive(Debug, PartialEq)]
 pub enum StartConfigurationRecorderError {
@@ -7853,6 +8822,18 @@ impl fmt::Display for StartConfigurationRecorderError {
     }
 }
 impl Error for StartConfigurationRecorderError {}
+impl ServiceErrorCode for StartConfigurationRecorderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartConfigurationRecorderError::NoAvailableDeliveryChannel(_) => {
+                "NoAvailableDeliveryChannelException"
+            }
+            StartConfigurationRecorderError::NoSuchConfigurationRecorder(_) => {
+                "NoSuchConfigurationRecorderException"
+            }
+        }
+    }
+}
 /// Errors returned by StartRemediationExecution
 #[derive(Debug, PartialEq)]
 pub enum StartRemediationExecutionError {
@@ -7907,6 +8888,21 @@ impl fmt::Display for StartRemediationExecutionError {
     }
 }
 impl Error for StartRemediationExecutionError {}
+impl ServiceErrorCode for StartRemediationExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartRemediationExecutionError::InsufficientPermissions(_) => {
+                "InsufficientPermissionsException"
+            }
+            StartRemediationExecutionError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            StartRemediationExecutionError::NoSuchRemediationConfiguration(_) => {
+                "NoSuchRemediationConfigurationException"
+            }
+        }
+    }
+}
 /// Errors returned by StopConfigurationRecorder
 #[derive(Debug, PartialEq)]
 pub enum StopConfigurationRecorderError {
@@ -7941,6 +8937,15 @@ impl fmt::Display for StopConfigurationRecorderError {
     }
 }
 impl Error for StopConfigurationRecorderError {}
+impl ServiceErrorCode for StopConfigurationRecorderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopConfigurationRecorderError::NoSuchConfigurationRecorder(_) => {
+                "NoSuchConfigurationRecorderException"
+            }
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -7977,6 +8982,14 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -8007,6 +9020,13 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Config Service API. Config Service clients implement this trait.
 #[async_trait]
 pub trait ConfigService {
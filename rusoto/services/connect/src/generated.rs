@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2480,6 +2480,7 @@ pub struct StartChatContactRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attributes: Option<::std::collections::HashMap<String, String>>,
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -2544,6 +2545,7 @@ pub struct StartOutboundVoiceContactRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attributes: Option<::std::collections::HashMap<String, String>>,
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request. The token is valid for 7 days after creation. If a contact is already started, the contact ID is returned. If the contact is disconnected, a new contact is started.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -2583,6 +2585,7 @@ pub struct StartTaskContactRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attributes: Option<::std::collections::HashMap<String, String>>,
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -3193,6 +3196,21 @@ impl fmt::Display for AssociateApprovedOriginError {
     }
 }
 impl Error for AssociateApprovedOriginError {}
+impl ServiceErrorCode for AssociateApprovedOriginError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateApprovedOriginError::InternalService(_) => "InternalServiceException",
+            AssociateApprovedOriginError::InvalidParameter(_) => "InvalidParameterException",
+            AssociateApprovedOriginError::InvalidRequest(_) => "InvalidRequestException",
+            AssociateApprovedOriginError::ResourceConflict(_) => "ResourceConflictException",
+            AssociateApprovedOriginError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateApprovedOriginError::ServiceQuotaExceeded(_) => {
+                "ServiceQuotaExceededException"
+            }
+            AssociateApprovedOriginError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by AssociateInstanceStorageConfig
 #[derive(Debug, PartialEq)]
 pub enum AssociateInstanceStorageConfigError {
@@ -3277,6 +3295,18 @@ impl fmt::Display for AssociateInstanceStorageConfigError {
     }
 }
 impl Error for AssociateInstanceStorageConfigError {}
+impl ServiceErrorCode for AssociateInstanceStorageConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateInstanceStorageConfigError::InternalService(_) => "InternalServiceException",
+            AssociateInstanceStorageConfigError::InvalidParameter(_) => "InvalidParameterException",
+            AssociateInstanceStorageConfigError::InvalidRequest(_) => "InvalidRequestException",
+            AssociateInstanceStorageConfigError::ResourceConflict(_) => "ResourceConflictException",
+            AssociateInstanceStorageConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateInstanceStorageConfigError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by AssociateLambdaFunction
 #[derive(Debug, PartialEq)]
 pub enum AssociateLambdaFunctionError {
@@ -3355,6 +3385,21 @@ impl fmt::Display for AssociateLambdaFunctionError {
     }
 }
 impl Error for AssociateLambdaFunctionError {}
+impl ServiceErrorCode for AssociateLambdaFunctionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateLambdaFunctionError::InternalService(_) => "InternalServiceException",
+            AssociateLambdaFunctionError::InvalidParameter(_) => "InvalidParameterException",
+            AssociateLambdaFunctionError::InvalidRequest(_) => "InvalidRequestException",
+            AssociateLambdaFunctionError::ResourceConflict(_) => "ResourceConflictException",
+            AssociateLambdaFunctionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateLambdaFunctionError::ServiceQuotaExceeded(_) => {
+                "ServiceQuotaExceededException"
+            }
+            AssociateLambdaFunctionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by AssociateLexBot
 #[derive(Debug, PartialEq)]
 pub enum AssociateLexBotError {
@@ -3423,6 +3468,19 @@ impl fmt::Display for AssociateLexBotError {
     }
 }
 impl Error for AssociateLexBotError {}
+impl ServiceErrorCode for AssociateLexBotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateLexBotError::InternalService(_) => "InternalServiceException",
+            AssociateLexBotError::InvalidParameter(_) => "InvalidParameterException",
+            AssociateLexBotError::InvalidRequest(_) => "InvalidRequestException",
+            AssociateLexBotError::ResourceConflict(_) => "ResourceConflictException",
+            AssociateLexBotError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateLexBotError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            AssociateLexBotError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by AssociateRoutingProfileQueues
 #[derive(Debug, PartialEq)]
 pub enum AssociateRoutingProfileQueuesError {
@@ -3495,6 +3553,17 @@ impl fmt::Display for AssociateRoutingProfileQueuesError {
     }
 }
 impl Error for AssociateRoutingProfileQueuesError {}
+impl ServiceErrorCode for AssociateRoutingProfileQueuesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateRoutingProfileQueuesError::InternalService(_) => "InternalServiceException",
+            AssociateRoutingProfileQueuesError::InvalidParameter(_) => "InvalidParameterException",
+            AssociateRoutingProfileQueuesError::InvalidRequest(_) => "InvalidRequestException",
+            AssociateRoutingProfileQueuesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateRoutingProfileQueuesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by AssociateSecurityKey
 #[derive(Debug, PartialEq)]
 pub enum AssociateSecurityKeyError {
@@ -3571,6 +3640,19 @@ impl fmt::Display for AssociateSecurityKeyError {
     }
 }
 impl Error for AssociateSecurityKeyError {}
+impl ServiceErrorCode for AssociateSecurityKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateSecurityKeyError::InternalService(_) => "InternalServiceException",
+            AssociateSecurityKeyError::InvalidParameter(_) => "InvalidParameterException",
+            AssociateSecurityKeyError::InvalidRequest(_) => "InvalidRequestException",
+            AssociateSecurityKeyError::ResourceConflict(_) => "ResourceConflictException",
+            AssociateSecurityKeyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateSecurityKeyError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            AssociateSecurityKeyError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateContactFlow
 #[derive(Debug, PartialEq)]
 pub enum CreateContactFlowError {
@@ -3645,6 +3727,20 @@ impl fmt::Display for CreateContactFlowError {
     }
 }
 impl Error for CreateContactFlowError {}
+impl ServiceErrorCode for CreateContactFlowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateContactFlowError::DuplicateResource(_) => "DuplicateResourceException",
+            CreateContactFlowError::InternalService(_) => "InternalServiceException",
+            CreateContactFlowError::InvalidContactFlow(_) => "InvalidContactFlowException",
+            CreateContactFlowError::InvalidParameter(_) => "InvalidParameterException",
+            CreateContactFlowError::InvalidRequest(_) => "InvalidRequestException",
+            CreateContactFlowError::LimitExceeded(_) => "LimitExceededException",
+            CreateContactFlowError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateContactFlowError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateInstance
 #[derive(Debug, PartialEq)]
 pub enum CreateInstanceError {
@@ -3699,6 +3795,17 @@ impl fmt::Display for CreateInstanceError {
     }
 }
 impl Error for CreateInstanceError {}
+impl ServiceErrorCode for CreateInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateInstanceError::InternalService(_) => "InternalServiceException",
+            CreateInstanceError::InvalidRequest(_) => "InvalidRequestException",
+            CreateInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateInstanceError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateInstanceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateIntegrationAssociation
 #[derive(Debug, PartialEq)]
 pub enum CreateIntegrationAssociationError {
@@ -3769,6 +3876,17 @@ impl fmt::Display for CreateIntegrationAssociationError {
     }
 }
 impl Error for CreateIntegrationAssociationError {}
+impl ServiceErrorCode for CreateIntegrationAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateIntegrationAssociationError::DuplicateResource(_) => "DuplicateResourceException",
+            CreateIntegrationAssociationError::InternalService(_) => "InternalServiceException",
+            CreateIntegrationAssociationError::InvalidRequest(_) => "InvalidRequestException",
+            CreateIntegrationAssociationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateIntegrationAssociationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateQuickConnect
 #[derive(Debug, PartialEq)]
 pub enum CreateQuickConnectError {
@@ -3837,6 +3955,19 @@ impl fmt::Display for CreateQuickConnectError {
     }
 }
 impl Error for CreateQuickConnectError {}
+impl ServiceErrorCode for CreateQuickConnectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateQuickConnectError::DuplicateResource(_) => "DuplicateResourceException",
+            CreateQuickConnectError::InternalService(_) => "InternalServiceException",
+            CreateQuickConnectError::InvalidParameter(_) => "InvalidParameterException",
+            CreateQuickConnectError::InvalidRequest(_) => "InvalidRequestException",
+            CreateQuickConnectError::LimitExceeded(_) => "LimitExceededException",
+            CreateQuickConnectError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateQuickConnectError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateRoutingProfile
 #[derive(Debug, PartialEq)]
 pub enum CreateRoutingProfileError {
@@ -3911,6 +4042,19 @@ impl fmt::Display for CreateRoutingProfileError {
     }
 }
 impl Error for CreateRoutingProfileError {}
+impl ServiceErrorCode for CreateRoutingProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRoutingProfileError::DuplicateResource(_) => "DuplicateResourceException",
+            CreateRoutingProfileError::InternalService(_) => "InternalServiceException",
+            CreateRoutingProfileError::InvalidParameter(_) => "InvalidParameterException",
+            CreateRoutingProfileError::InvalidRequest(_) => "InvalidRequestException",
+            CreateRoutingProfileError::LimitExceeded(_) => "LimitExceededException",
+            CreateRoutingProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateRoutingProfileError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateUseCase
 #[derive(Debug, PartialEq)]
 pub enum CreateUseCaseError {
@@ -3965,6 +4109,17 @@ impl fmt::Display for CreateUseCaseError {
     }
 }
 impl Error for CreateUseCaseError {}
+impl ServiceErrorCode for CreateUseCaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUseCaseError::DuplicateResource(_) => "DuplicateResourceException",
+            CreateUseCaseError::InternalService(_) => "InternalServiceException",
+            CreateUseCaseError::InvalidRequest(_) => "InvalidRequestException",
+            CreateUseCaseError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateUseCaseError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
 pub enum CreateUserError {
@@ -4031,6 +4186,19 @@ impl fmt::Display for CreateUserError {
     }
 }
 impl Error for CreateUserError {}
+impl ServiceErrorCode for CreateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserError::DuplicateResource(_) => "DuplicateResourceException",
+            CreateUserError::InternalService(_) => "InternalServiceException",
+            CreateUserError::InvalidParameter(_) => "InvalidParameterException",
+            CreateUserError::InvalidRequest(_) => "InvalidRequestException",
+            CreateUserError::LimitExceeded(_) => "LimitExceededException",
+            CreateUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateUserError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateUserHierarchyGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateUserHierarchyGroupError {
@@ -4109,6 +4277,19 @@ impl fmt::Display for CreateUserHierarchyGroupError {
     }
 }
 impl Error for CreateUserHierarchyGroupError {}
+impl ServiceErrorCode for CreateUserHierarchyGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserHierarchyGroupError::DuplicateResource(_) => "DuplicateResourceException",
+            CreateUserHierarchyGroupError::InternalService(_) => "InternalServiceException",
+            CreateUserHierarchyGroupError::InvalidParameter(_) => "InvalidParameterException",
+            CreateUserHierarchyGroupError::InvalidRequest(_) => "InvalidRequestException",
+            CreateUserHierarchyGroupError::LimitExceeded(_) => "LimitExceededException",
+            CreateUserHierarchyGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateUserHierarchyGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteInstance
 #[derive(Debug, PartialEq)]
 pub enum DeleteInstanceError {
@@ -4151,6 +4332,15 @@ impl fmt::Display for DeleteInstanceError {
     }
 }
 impl Error for DeleteInstanceError {}
+impl ServiceErrorCode for DeleteInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInstanceError::InternalService(_) => "InternalServiceException",
+            DeleteInstanceError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteIntegrationAssociation
 #[derive(Debug, PartialEq)]
 pub enum DeleteIntegrationAssociationError {
@@ -4211,6 +4401,16 @@ impl fmt::Display for DeleteIntegrationAssociationError {
     }
 }
 impl Error for DeleteIntegrationAssociationError {}
+impl ServiceErrorCode for DeleteIntegrationAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIntegrationAssociationError::InternalService(_) => "InternalServiceException",
+            DeleteIntegrationAssociationError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteIntegrationAssociationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteIntegrationAssociationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteQuickConnect
 #[derive(Debug, PartialEq)]
 pub enum DeleteQuickConnectError {
@@ -4265,6 +4465,17 @@ impl fmt::Display for DeleteQuickConnectError {
     }
 }
 impl Error for DeleteQuickConnectError {}
+impl ServiceErrorCode for DeleteQuickConnectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteQuickConnectError::InternalService(_) => "InternalServiceException",
+            DeleteQuickConnectError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteQuickConnectError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteQuickConnectError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteQuickConnectError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteUseCase
 #[derive(Debug, PartialEq)]
 pub enum DeleteUseCaseError {
@@ -4313,6 +4524,16 @@ impl fmt::Display for DeleteUseCaseError {
     }
 }
 impl Error for DeleteUseCaseError {}
+impl ServiceErrorCode for DeleteUseCaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUseCaseError::InternalService(_) => "InternalServiceException",
+            DeleteUseCaseError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteUseCaseError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteUseCaseError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserError {
@@ -4367,6 +4588,17 @@ impl fmt::Display for DeleteUserError {
     }
 }
 impl Error for DeleteUserError {}
+impl ServiceErrorCode for DeleteUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserError::InternalService(_) => "InternalServiceException",
+            DeleteUserError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteUserError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteUserError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteUserHierarchyGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserHierarchyGroupError {
@@ -4437,6 +4669,18 @@ impl fmt::Display for DeleteUserHierarchyGroupError {
     }
 }
 impl Error for DeleteUserHierarchyGroupError {}
+impl ServiceErrorCode for DeleteUserHierarchyGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserHierarchyGroupError::InternalService(_) => "InternalServiceException",
+            DeleteUserHierarchyGroupError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteUserHierarchyGroupError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteUserHierarchyGroupError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteUserHierarchyGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteUserHierarchyGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeContactFlow
 #[derive(Debug, PartialEq)]
 pub enum DescribeContactFlowError {
@@ -4503,6 +4747,20 @@ impl fmt::Display for DescribeContactFlowError {
     }
 }
 impl Error for DescribeContactFlowError {}
+impl ServiceErrorCode for DescribeContactFlowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeContactFlowError::ContactFlowNotPublished(_) => {
+                "ContactFlowNotPublishedException"
+            }
+            DescribeContactFlowError::InternalService(_) => "InternalServiceException",
+            DescribeContactFlowError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeContactFlowError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeContactFlowError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeContactFlowError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeInstance
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstanceError {
@@ -4545,6 +4803,15 @@ impl fmt::Display for DescribeInstanceError {
     }
 }
 impl Error for DescribeInstanceError {}
+impl ServiceErrorCode for DescribeInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInstanceError::InternalService(_) => "InternalServiceException",
+            DescribeInstanceError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeInstanceAttribute
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstanceAttributeError {
@@ -4609,6 +4876,17 @@ impl fmt::Display for DescribeInstanceAttributeError {
     }
 }
 impl Error for DescribeInstanceAttributeError {}
+impl ServiceErrorCode for DescribeInstanceAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInstanceAttributeError::InternalService(_) => "InternalServiceException",
+            DescribeInstanceAttributeError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeInstanceAttributeError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeInstanceAttributeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeInstanceAttributeError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeInstanceStorageConfig
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstanceStorageConfigError {
@@ -4681,6 +4959,17 @@ impl fmt::Display for DescribeInstanceStorageConfigError {
     }
 }
 impl Error for DescribeInstanceStorageConfigError {}
+impl ServiceErrorCode for DescribeInstanceStorageConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInstanceStorageConfigError::InternalService(_) => "InternalServiceException",
+            DescribeInstanceStorageConfigError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeInstanceStorageConfigError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeInstanceStorageConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeInstanceStorageConfigError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeQuickConnect
 #[derive(Debug, PartialEq)]
 pub enum DescribeQuickConnectError {
@@ -4741,6 +5030,17 @@ impl fmt::Display for DescribeQuickConnectError {
     }
 }
 impl Error for DescribeQuickConnectError {}
+impl ServiceErrorCode for DescribeQuickConnectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeQuickConnectError::InternalService(_) => "InternalServiceException",
+            DescribeQuickConnectError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeQuickConnectError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeQuickConnectError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeQuickConnectError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeRoutingProfile
 #[derive(Debug, PartialEq)]
 pub enum DescribeRoutingProfileError {
@@ -4803,6 +5103,17 @@ impl fmt::Display for DescribeRoutingProfileError {
     }
 }
 impl Error for DescribeRoutingProfileError {}
+impl ServiceErrorCode for DescribeRoutingProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRoutingProfileError::InternalService(_) => "InternalServiceException",
+            DescribeRoutingProfileError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeRoutingProfileError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeRoutingProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeRoutingProfileError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeUser
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserError {
@@ -4857,6 +5168,17 @@ impl fmt::Display for DescribeUserError {
     }
 }
 impl Error for DescribeUserError {}
+impl ServiceErrorCode for DescribeUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserError::InternalService(_) => "InternalServiceException",
+            DescribeUserError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeUserError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeUserError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeUserHierarchyGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserHierarchyGroupError {
@@ -4923,6 +5245,17 @@ impl fmt::Display for DescribeUserHierarchyGroupError {
     }
 }
 impl Error for DescribeUserHierarchyGroupError {}
+impl ServiceErrorCode for DescribeUserHierarchyGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserHierarchyGroupError::InternalService(_) => "InternalServiceException",
+            DescribeUserHierarchyGroupError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeUserHierarchyGroupError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeUserHierarchyGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeUserHierarchyGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeUserHierarchyStructure
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserHierarchyStructureError {
@@ -4997,6 +5330,17 @@ impl fmt::Display for DescribeUserHierarchyStructureError {
     }
 }
 impl Error for DescribeUserHierarchyStructureError {}
+impl ServiceErrorCode for DescribeUserHierarchyStructureError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserHierarchyStructureError::InternalService(_) => "InternalServiceException",
+            DescribeUserHierarchyStructureError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeUserHierarchyStructureError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeUserHierarchyStructureError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeUserHierarchyStructureError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DisassociateApprovedOrigin
 #[derive(Debug, PartialEq)]
 pub enum DisassociateApprovedOriginError {
@@ -5063,6 +5407,17 @@ impl fmt::Display for DisassociateApprovedOriginError {
     }
 }
 impl Error for DisassociateApprovedOriginError {}
+impl ServiceErrorCode for DisassociateApprovedOriginError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateApprovedOriginError::InternalService(_) => "InternalServiceException",
+            DisassociateApprovedOriginError::InvalidParameter(_) => "InvalidParameterException",
+            DisassociateApprovedOriginError::InvalidRequest(_) => "InvalidRequestException",
+            DisassociateApprovedOriginError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DisassociateApprovedOriginError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DisassociateInstanceStorageConfig
 #[derive(Debug, PartialEq)]
 pub enum DisassociateInstanceStorageConfigError {
@@ -5137,6 +5492,23 @@ impl fmt::Display for DisassociateInstanceStorageConfigError {
     }
 }
 impl Error for DisassociateInstanceStorageConfigError {}
+impl ServiceErrorCode for DisassociateInstanceStorageConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateInstanceStorageConfigError::InternalService(_) => {
+                "InternalServiceException"
+            }
+            DisassociateInstanceStorageConfigError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            DisassociateInstanceStorageConfigError::InvalidRequest(_) => "InvalidRequestException",
+            DisassociateInstanceStorageConfigError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DisassociateInstanceStorageConfigError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DisassociateLambdaFunction
 #[derive(Debug, PartialEq)]
 pub enum DisassociateLambdaFunctionError {
@@ -5203,6 +5575,17 @@ impl fmt::Display for DisassociateLambdaFunctionError {
     }
 }
 impl Error for DisassociateLambdaFunctionError {}
+impl ServiceErrorCode for DisassociateLambdaFunctionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateLambdaFunctionError::InternalService(_) => "InternalServiceException",
+            DisassociateLambdaFunctionError::InvalidParameter(_) => "InvalidParameterException",
+            DisassociateLambdaFunctionError::InvalidRequest(_) => "InvalidRequestException",
+            DisassociateLambdaFunctionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DisassociateLambdaFunctionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DisassociateLexBot
 #[derive(Debug, PartialEq)]
 pub enum DisassociateLexBotError {
@@ -5257,6 +5640,17 @@ impl fmt::Display for DisassociateLexBotError {
     }
 }
 impl Error for DisassociateLexBotError {}
+impl ServiceErrorCode for DisassociateLexBotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateLexBotError::InternalService(_) => "InternalServiceException",
+            DisassociateLexBotError::InvalidParameter(_) => "InvalidParameterException",
+            DisassociateLexBotError::InvalidRequest(_) => "InvalidRequestException",
+            DisassociateLexBotError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DisassociateLexBotError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DisassociateRoutingProfileQueues
 #[derive(Debug, PartialEq)]
 pub enum DisassociateRoutingProfileQueuesError {
@@ -5331,6 +5725,21 @@ impl fmt::Display for DisassociateRoutingProfileQueuesError {
     }
 }
 impl Error for DisassociateRoutingProfileQueuesError {}
+impl ServiceErrorCode for DisassociateRoutingProfileQueuesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateRoutingProfileQueuesError::InternalService(_) => "InternalServiceException",
+            DisassociateRoutingProfileQueuesError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            DisassociateRoutingProfileQueuesError::InvalidRequest(_) => "InvalidRequestException",
+            DisassociateRoutingProfileQueuesError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DisassociateRoutingProfileQueuesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DisassociateSecurityKey
 #[derive(Debug, PartialEq)]
 pub enum DisassociateSecurityKeyError {
@@ -5393,6 +5802,17 @@ impl fmt::Display for DisassociateSecurityKeyError {
     }
 }
 impl Error for DisassociateSecurityKeyError {}
+impl ServiceErrorCode for DisassociateSecurityKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateSecurityKeyError::InternalService(_) => "InternalServiceException",
+            DisassociateSecurityKeyError::InvalidParameter(_) => "InvalidParameterException",
+            DisassociateSecurityKeyError::InvalidRequest(_) => "InvalidRequestException",
+            DisassociateSecurityKeyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DisassociateSecurityKeyError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetContactAttributes
 #[derive(Debug, PartialEq)]
 pub enum GetContactAttributesError {
@@ -5439,6 +5859,15 @@ impl fmt::Display for GetContactAttributesError {
     }
 }
 impl Error for GetContactAttributesError {}
+impl ServiceErrorCode for GetContactAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContactAttributesError::InternalService(_) => "InternalServiceException",
+            GetContactAttributesError::InvalidRequest(_) => "InvalidRequestException",
+            GetContactAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetCurrentMetricData
 #[derive(Debug, PartialEq)]
 pub enum GetCurrentMetricDataError {
@@ -5499,6 +5928,17 @@ impl fmt::Display for GetCurrentMetricDataError {
     }
 }
 impl Error for GetCurrentMetricDataError {}
+impl ServiceErrorCode for GetCurrentMetricDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCurrentMetricDataError::InternalService(_) => "InternalServiceException",
+            GetCurrentMetricDataError::InvalidParameter(_) => "InvalidParameterException",
+            GetCurrentMetricDataError::InvalidRequest(_) => "InvalidRequestException",
+            GetCurrentMetricDataError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetCurrentMetricDataError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetFederationToken
 #[derive(Debug, PartialEq)]
 pub enum GetFederationTokenError {
@@ -5561,6 +6001,18 @@ impl fmt::Display for GetFederationTokenError {
     }
 }
 impl Error for GetFederationTokenError {}
+impl ServiceErrorCode for GetFederationTokenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFederationTokenError::DuplicateResource(_) => "DuplicateResourceException",
+            GetFederationTokenError::InternalService(_) => "InternalServiceException",
+            GetFederationTokenError::InvalidParameter(_) => "InvalidParameterException",
+            GetFederationTokenError::InvalidRequest(_) => "InvalidRequestException",
+            GetFederationTokenError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetFederationTokenError::UserNotFound(_) => "UserNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetMetricData
 #[derive(Debug, PartialEq)]
 pub enum GetMetricDataError {
@@ -5615,6 +6067,17 @@ impl fmt::Display for GetMetricDataError {
     }
 }
 impl Error for GetMetricDataError {}
+impl ServiceErrorCode for GetMetricDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMetricDataError::InternalService(_) => "InternalServiceException",
+            GetMetricDataError::InvalidParameter(_) => "InvalidParameterException",
+            GetMetricDataError::InvalidRequest(_) => "InvalidRequestException",
+            GetMetricDataError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetMetricDataError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListApprovedOrigins
 #[derive(Debug, PartialEq)]
 pub enum ListApprovedOriginsError {
@@ -5673,6 +6136,17 @@ impl fmt::Display for ListApprovedOriginsError {
     }
 }
 impl Error for ListApprovedOriginsError {}
+impl ServiceErrorCode for ListApprovedOriginsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListApprovedOriginsError::InternalService(_) => "InternalServiceException",
+            ListApprovedOriginsError::InvalidParameter(_) => "InvalidParameterException",
+            ListApprovedOriginsError::InvalidRequest(_) => "InvalidRequestException",
+            ListApprovedOriginsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListApprovedOriginsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListContactFlows
 #[derive(Debug, PartialEq)]
 pub enum ListContactFlowsError {
@@ -5727,6 +6201,17 @@ impl fmt::Display for ListContactFlowsError {
     }
 }
 impl Error for ListContactFlowsError {}
+impl ServiceErrorCode for ListContactFlowsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListContactFlowsError::InternalService(_) => "InternalServiceException",
+            ListContactFlowsError::InvalidParameter(_) => "InvalidParameterException",
+            ListContactFlowsError::InvalidRequest(_) => "InvalidRequestException",
+            ListContactFlowsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListContactFlowsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListHoursOfOperations
 #[derive(Debug, PartialEq)]
 pub enum ListHoursOfOperationsError {
@@ -5789,6 +6274,17 @@ impl fmt::Display for ListHoursOfOperationsError {
     }
 }
 impl Error for ListHoursOfOperationsError {}
+impl ServiceErrorCode for ListHoursOfOperationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListHoursOfOperationsError::InternalService(_) => "InternalServiceException",
+            ListHoursOfOperationsError::InvalidParameter(_) => "InvalidParameterException",
+            ListHoursOfOperationsError::InvalidRequest(_) => "InvalidRequestException",
+            ListHoursOfOperationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListHoursOfOperationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListInstanceAttributes
 #[derive(Debug, PartialEq)]
 pub enum ListInstanceAttributesError {
@@ -5851,6 +6347,17 @@ impl fmt::Display for ListInstanceAttributesError {
     }
 }
 impl Error for ListInstanceAttributesError {}
+impl ServiceErrorCode for ListInstanceAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInstanceAttributesError::InternalService(_) => "InternalServiceException",
+            ListInstanceAttributesError::InvalidParameter(_) => "InvalidParameterException",
+            ListInstanceAttributesError::InvalidRequest(_) => "InvalidRequestException",
+            ListInstanceAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListInstanceAttributesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListInstanceStorageConfigs
 #[derive(Debug, PartialEq)]
 pub enum ListInstanceStorageConfigsError {
@@ -5917,6 +6424,17 @@ impl fmt::Display for ListInstanceStorageConfigsError {
     }
 }
 impl Error for ListInstanceStorageConfigsError {}
+impl ServiceErrorCode for ListInstanceStorageConfigsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInstanceStorageConfigsError::InternalService(_) => "InternalServiceException",
+            ListInstanceStorageConfigsError::InvalidParameter(_) => "InvalidParameterException",
+            ListInstanceStorageConfigsError::InvalidRequest(_) => "InvalidRequestException",
+            ListInstanceStorageConfigsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListInstanceStorageConfigsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListInstances
 #[derive(Debug, PartialEq)]
 pub enum ListInstancesError {
@@ -5953,6 +6471,14 @@ impl fmt::Display for ListInstancesError {
     }
 }
 impl Error for ListInstancesError {}
+impl ServiceErrorCode for ListInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInstancesError::InternalService(_) => "InternalServiceException",
+            ListInstancesError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListIntegrationAssociations
 #[derive(Debug, PartialEq)]
 pub enum ListIntegrationAssociationsError {
@@ -6011,6 +6537,16 @@ impl fmt::Display for ListIntegrationAssociationsError {
     }
 }
 impl Error for ListIntegrationAssociationsError {}
+impl ServiceErrorCode for ListIntegrationAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListIntegrationAssociationsError::InternalService(_) => "InternalServiceException",
+            ListIntegrationAssociationsError::InvalidRequest(_) => "InvalidRequestException",
+            ListIntegrationAssociationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListIntegrationAssociationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListLambdaFunctions
 #[derive(Debug, PartialEq)]
 pub enum ListLambdaFunctionsError {
@@ -6069,6 +6605,17 @@ impl fmt::Display for ListLambdaFunctionsError {
     }
 }
 impl Error for ListLambdaFunctionsError {}
+impl ServiceErrorCode for ListLambdaFunctionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLambdaFunctionsError::InternalService(_) => "InternalServiceException",
+            ListLambdaFunctionsError::InvalidParameter(_) => "InvalidParameterException",
+            ListLambdaFunctionsError::InvalidRequest(_) => "InvalidRequestException",
+            ListLambdaFunctionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListLambdaFunctionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListLexBots
 #[derive(Debug, PartialEq)]
 pub enum ListLexBotsError {
@@ -6123,6 +6670,17 @@ impl fmt::Display for ListLexBotsError {
     }
 }
 impl Error for ListLexBotsError {}
+impl ServiceErrorCode for ListLexBotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLexBotsError::InternalService(_) => "InternalServiceException",
+            ListLexBotsError::InvalidParameter(_) => "InvalidParameterException",
+            ListLexBotsError::InvalidRequest(_) => "InvalidRequestException",
+            ListLexBotsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListLexBotsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListPhoneNumbers
 #[derive(Debug, PartialEq)]
 pub enum ListPhoneNumbersError {
@@ -6177,6 +6735,17 @@ impl fmt::Display for ListPhoneNumbersError {
     }
 }
 impl Error for ListPhoneNumbersError {}
+impl ServiceErrorCode for ListPhoneNumbersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPhoneNumbersError::InternalService(_) => "InternalServiceException",
+            ListPhoneNumbersError::InvalidParameter(_) => "InvalidParameterException",
+            ListPhoneNumbersError::InvalidRequest(_) => "InvalidRequestException",
+            ListPhoneNumbersError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListPhoneNumbersError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListPrompts
 #[derive(Debug, PartialEq)]
 pub enum ListPromptsError {
@@ -6231,6 +6800,17 @@ impl fmt::Display for ListPromptsError {
     }
 }
 impl Error for ListPromptsError {}
+impl ServiceErrorCode for ListPromptsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPromptsError::InternalService(_) => "InternalServiceException",
+            ListPromptsError::InvalidParameter(_) => "InvalidParameterException",
+            ListPromptsError::InvalidRequest(_) => "InvalidRequestException",
+            ListPromptsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListPromptsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListQueues
 #[derive(Debug, PartialEq)]
 pub enum ListQueuesError {
@@ -6285,6 +6865,17 @@ impl fmt::Display for ListQueuesError {
     }
 }
 impl Error for ListQueuesError {}
+impl ServiceErrorCode for ListQueuesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListQueuesError::InternalService(_) => "InternalServiceException",
+            ListQueuesError::InvalidParameter(_) => "InvalidParameterException",
+            ListQueuesError::InvalidRequest(_) => "InvalidRequestException",
+            ListQueuesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListQueuesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListQuickConnects
 #[derive(Debug, PartialEq)]
 pub enum ListQuickConnectsError {
@@ -6339,6 +6930,17 @@ impl fmt::Display for ListQuickConnectsError {
     }
 }
 impl Error for ListQuickConnectsError {}
+impl ServiceErrorCode for ListQuickConnectsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListQuickConnectsError::InternalService(_) => "InternalServiceException",
+            ListQuickConnectsError::InvalidParameter(_) => "InvalidParameterException",
+            ListQuickConnectsError::InvalidRequest(_) => "InvalidRequestException",
+            ListQuickConnectsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListQuickConnectsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListRoutingProfileQueues
 #[derive(Debug, PartialEq)]
 pub enum ListRoutingProfileQueuesError {
@@ -6401,6 +7003,17 @@ impl fmt::Display for ListRoutingProfileQueuesError {
     }
 }
 impl Error for ListRoutingProfileQueuesError {}
+impl ServiceErrorCode for ListRoutingProfileQueuesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRoutingProfileQueuesError::InternalService(_) => "InternalServiceException",
+            ListRoutingProfileQueuesError::InvalidParameter(_) => "InvalidParameterException",
+            ListRoutingProfileQueuesError::InvalidRequest(_) => "InvalidRequestException",
+            ListRoutingProfileQueuesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListRoutingProfileQueuesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListRoutingProfiles
 #[derive(Debug, PartialEq)]
 pub enum ListRoutingProfilesError {
@@ -6459,6 +7072,17 @@ impl fmt::Display for ListRoutingProfilesError {
     }
 }
 impl Error for ListRoutingProfilesError {}
+impl ServiceErrorCode for ListRoutingProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRoutingProfilesError::InternalService(_) => "InternalServiceException",
+            ListRoutingProfilesError::InvalidParameter(_) => "InvalidParameterException",
+            ListRoutingProfilesError::InvalidRequest(_) => "InvalidRequestException",
+            ListRoutingProfilesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListRoutingProfilesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListSecurityKeys
 #[derive(Debug, PartialEq)]
 pub enum ListSecurityKeysError {
@@ -6513,6 +7137,17 @@ impl fmt::Display for ListSecurityKeysError {
     }
 }
 impl Error for ListSecurityKeysError {}
+impl ServiceErrorCode for ListSecurityKeysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSecurityKeysError::InternalService(_) => "InternalServiceException",
+            ListSecurityKeysError::InvalidParameter(_) => "InvalidParameterException",
+            ListSecurityKeysError::InvalidRequest(_) => "InvalidRequestException",
+            ListSecurityKeysError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListSecurityKeysError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListSecurityProfiles
 #[derive(Debug, PartialEq)]
 pub enum ListSecurityProfilesError {
@@ -6573,6 +7208,17 @@ impl fmt::Display for ListSecurityProfilesError {
     }
 }
 impl Error for ListSecurityProfilesError {}
+impl ServiceErrorCode for ListSecurityProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSecurityProfilesError::InternalService(_) => "InternalServiceException",
+            ListSecurityProfilesError::InvalidParameter(_) => "InvalidParameterException",
+            ListSecurityProfilesError::InvalidRequest(_) => "InvalidRequestException",
+            ListSecurityProfilesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListSecurityProfilesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -6631,6 +7277,17 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalService(_) => "InternalServiceException",
+            ListTagsForResourceError::InvalidParameter(_) => "InvalidParameterException",
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListUseCases
 #[derive(Debug, PartialEq)]
 pub enum ListUseCasesError {
@@ -6679,6 +7336,16 @@ impl fmt::Display for ListUseCasesError {
     }
 }
 impl Error for ListUseCasesError {}
+impl ServiceErrorCode for ListUseCasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUseCasesError::InternalService(_) => "InternalServiceException",
+            ListUseCasesError::InvalidRequest(_) => "InvalidRequestException",
+            ListUseCasesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListUseCasesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListUserHierarchyGroups
 #[derive(Debug, PartialEq)]
 pub enum ListUserHierarchyGroupsError {
@@ -6741,6 +7408,17 @@ impl fmt::Display for ListUserHierarchyGroupsError {
     }
 }
 impl Error for ListUserHierarchyGroupsError {}
+impl ServiceErrorCode for ListUserHierarchyGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUserHierarchyGroupsError::InternalService(_) => "InternalServiceException",
+            ListUserHierarchyGroupsError::InvalidParameter(_) => "InvalidParameterException",
+            ListUserHierarchyGroupsError::InvalidRequest(_) => "InvalidRequestException",
+            ListUserHierarchyGroupsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListUserHierarchyGroupsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListUsers
 #[derive(Debug, PartialEq)]
 pub enum ListUsersError {
@@ -6795,6 +7473,17 @@ impl fmt::Display for ListUsersError {
     }
 }
 impl Error for ListUsersError {}
+impl ServiceErrorCode for ListUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUsersError::InternalService(_) => "InternalServiceException",
+            ListUsersError::InvalidParameter(_) => "InvalidParameterException",
+            ListUsersError::InvalidRequest(_) => "InvalidRequestException",
+            ListUsersError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListUsersError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ResumeContactRecording
 #[derive(Debug, PartialEq)]
 pub enum ResumeContactRecordingError {
@@ -6843,6 +7532,15 @@ impl fmt::Display for ResumeContactRecordingError {
     }
 }
 impl Error for ResumeContactRecordingError {}
+impl ServiceErrorCode for ResumeContactRecordingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResumeContactRecordingError::InternalService(_) => "InternalServiceException",
+            ResumeContactRecordingError::InvalidRequest(_) => "InvalidRequestException",
+            ResumeContactRecordingError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartChatContact
 #[derive(Debug, PartialEq)]
 pub enum StartChatContactError {
@@ -6897,6 +7595,17 @@ impl fmt::Display for StartChatContactError {
     }
 }
 impl Error for StartChatContactError {}
+impl ServiceErrorCode for StartChatContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartChatContactError::InternalService(_) => "InternalServiceException",
+            StartChatContactError::InvalidParameter(_) => "InvalidParameterException",
+            StartChatContactError::InvalidRequest(_) => "InvalidRequestException",
+            StartChatContactError::LimitExceeded(_) => "LimitExceededException",
+            StartChatContactError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartContactRecording
 #[derive(Debug, PartialEq)]
 pub enum StartContactRecordingError {
@@ -6953,6 +7662,16 @@ impl fmt::Display for StartContactRecordingError {
     }
 }
 impl Error for StartContactRecordingError {}
+impl ServiceErrorCode for StartContactRecordingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartContactRecordingError::InternalService(_) => "InternalServiceException",
+            StartContactRecordingError::InvalidParameter(_) => "InvalidParameterException",
+            StartContactRecordingError::InvalidRequest(_) => "InvalidRequestException",
+            StartContactRecordingError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartOutboundVoiceContact
 #[derive(Debug, PartialEq)]
 pub enum StartOutboundVoiceContactError {
@@ -7037,6 +7756,23 @@ impl fmt::Display for StartOutboundVoiceContactError {
     }
 }
 impl Error for StartOutboundVoiceContactError {}
+impl ServiceErrorCode for StartOutboundVoiceContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartOutboundVoiceContactError::DestinationNotAllowed(_) => {
+                "DestinationNotAllowedException"
+            }
+            StartOutboundVoiceContactError::InternalService(_) => "InternalServiceException",
+            StartOutboundVoiceContactError::InvalidParameter(_) => "InvalidParameterException",
+            StartOutboundVoiceContactError::InvalidRequest(_) => "InvalidRequestException",
+            StartOutboundVoiceContactError::LimitExceeded(_) => "LimitExceededException",
+            StartOutboundVoiceContactError::OutboundContactNotPermitted(_) => {
+                "OutboundContactNotPermittedException"
+            }
+            StartOutboundVoiceContactError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartTaskContact
 #[derive(Debug, PartialEq)]
 pub enum StartTaskContactError {
@@ -7099,6 +7835,18 @@ impl fmt::Display for StartTaskContactError {
     }
 }
 impl Error for StartTaskContactError {}
+impl ServiceErrorCode for StartTaskContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartTaskContactError::InternalService(_) => "InternalServiceException",
+            StartTaskContactError::InvalidParameter(_) => "InvalidParameterException",
+            StartTaskContactError::InvalidRequest(_) => "InvalidRequestException",
+            StartTaskContactError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartTaskContactError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            StartTaskContactError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StopContact
 #[derive(Debug, PartialEq)]
 pub enum StopContactError {
@@ -7153,6 +7901,17 @@ impl fmt::Display for StopContactError {
     }
 }
 impl Error for StopContactError {}
+impl ServiceErrorCode for StopContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopContactError::ContactNotFound(_) => "ContactNotFoundException",
+            StopContactError::InternalService(_) => "InternalServiceException",
+            StopContactError::InvalidParameter(_) => "InvalidParameterException",
+            StopContactError::InvalidRequest(_) => "InvalidRequestException",
+            StopContactError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopContactRecording
 #[derive(Debug, PartialEq)]
 pub enum StopContactRecordingError {
@@ -7199,6 +7958,15 @@ impl fmt::Display for StopContactRecordingError {
     }
 }
 impl Error for StopContactRecordingError {}
+impl ServiceErrorCode for StopContactRecordingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopContactRecordingError::InternalService(_) => "InternalServiceException",
+            StopContactRecordingError::InvalidRequest(_) => "InvalidRequestException",
+            StopContactRecordingError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by SuspendContactRecording
 #[derive(Debug, PartialEq)]
 pub enum SuspendContactRecordingError {
@@ -7247,6 +8015,15 @@ impl fmt::Display for SuspendContactRecordingError {
     }
 }
 impl Error for SuspendContactRecordingError {}
+impl ServiceErrorCode for SuspendContactRecordingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SuspendContactRecordingError::InternalService(_) => "InternalServiceException",
+            SuspendContactRecordingError::InvalidRequest(_) => "InvalidRequestException",
+            SuspendContactRecordingError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -7301,6 +8078,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalService(_) => "InternalServiceException",
+            TagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -7355,6 +8143,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalService(_) => "InternalServiceException",
+            UntagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateContactAttributes
 #[derive(Debug, PartialEq)]
 pub enum UpdateContactAttributesError {
@@ -7411,6 +8210,16 @@ impl fmt::Display for UpdateContactAttributesError {
     }
 }
 impl Error for UpdateContactAttributesError {}
+impl ServiceErrorCode for UpdateContactAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateContactAttributesError::InternalService(_) => "InternalServiceException",
+            UpdateContactAttributesError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateContactAttributesError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateContactAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateContactFlowContent
 #[derive(Debug, PartialEq)]
 pub enum UpdateContactFlowContentError {
@@ -7481,6 +8290,18 @@ impl fmt::Display for UpdateContactFlowContentError {
     }
 }
 impl Error for UpdateContactFlowContentError {}
+impl ServiceErrorCode for UpdateContactFlowContentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateContactFlowContentError::InternalService(_) => "InternalServiceException",
+            UpdateContactFlowContentError::InvalidContactFlow(_) => "InvalidContactFlowException",
+            UpdateContactFlowContentError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateContactFlowContentError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateContactFlowContentError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateContactFlowContentError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateContactFlowName
 #[derive(Debug, PartialEq)]
 pub enum UpdateContactFlowNameError {
@@ -7551,6 +8372,18 @@ impl fmt::Display for UpdateContactFlowNameError {
     }
 }
 impl Error for UpdateContactFlowNameError {}
+impl ServiceErrorCode for UpdateContactFlowNameError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateContactFlowNameError::DuplicateResource(_) => "DuplicateResourceException",
+            UpdateContactFlowNameError::InternalService(_) => "InternalServiceException",
+            UpdateContactFlowNameError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateContactFlowNameError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateContactFlowNameError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateContactFlowNameError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateInstanceAttribute
 #[derive(Debug, PartialEq)]
 pub enum UpdateInstanceAttributeError {
@@ -7613,6 +8446,17 @@ impl fmt::Display for UpdateInstanceAttributeError {
     }
 }
 impl Error for UpdateInstanceAttributeError {}
+impl ServiceErrorCode for UpdateInstanceAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateInstanceAttributeError::InternalService(_) => "InternalServiceException",
+            UpdateInstanceAttributeError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateInstanceAttributeError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateInstanceAttributeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateInstanceAttributeError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateInstanceStorageConfig
 #[derive(Debug, PartialEq)]
 pub enum UpdateInstanceStorageConfigError {
@@ -7679,6 +8523,17 @@ impl fmt::Display for UpdateInstanceStorageConfigError {
     }
 }
 impl Error for UpdateInstanceStorageConfigError {}
+impl ServiceErrorCode for UpdateInstanceStorageConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateInstanceStorageConfigError::InternalService(_) => "InternalServiceException",
+            UpdateInstanceStorageConfigError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateInstanceStorageConfigError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateInstanceStorageConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateInstanceStorageConfigError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateQuickConnectConfig
 #[derive(Debug, PartialEq)]
 pub enum UpdateQuickConnectConfigError {
@@ -7741,6 +8596,17 @@ impl fmt::Display for UpdateQuickConnectConfigError {
     }
 }
 impl Error for UpdateQuickConnectConfigError {}
+impl ServiceErrorCode for UpdateQuickConnectConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateQuickConnectConfigError::InternalService(_) => "InternalServiceException",
+            UpdateQuickConnectConfigError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateQuickConnectConfigError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateQuickConnectConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateQuickConnectConfigError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateQuickConnectName
 #[derive(Debug, PartialEq)]
 pub enum UpdateQuickConnectNameError {
@@ -7803,6 +8669,17 @@ impl fmt::Display for UpdateQuickConnectNameError {
     }
 }
 impl Error for UpdateQuickConnectNameError {}
+impl ServiceErrorCode for UpdateQuickConnectNameError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateQuickConnectNameError::InternalService(_) => "InternalServiceException",
+            UpdateQuickConnectNameError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateQuickConnectNameError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateQuickConnectNameError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateQuickConnectNameError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateRoutingProfileConcurrency
 #[derive(Debug, PartialEq)]
 pub enum UpdateRoutingProfileConcurrencyError {
@@ -7877,6 +8754,21 @@ impl fmt::Display for UpdateRoutingProfileConcurrencyError {
     }
 }
 impl Error for UpdateRoutingProfileConcurrencyError {}
+impl ServiceErrorCode for UpdateRoutingProfileConcurrencyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRoutingProfileConcurrencyError::InternalService(_) => "InternalServiceException",
+            UpdateRoutingProfileConcurrencyError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            UpdateRoutingProfileConcurrencyError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateRoutingProfileConcurrencyError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            UpdateRoutingProfileConcurrencyError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateRoutingProfileDefaultOutboundQueue
 #[derive(Debug, PartialEq)]
 pub enum UpdateRoutingProfileDefaultOutboundQueueError {
@@ -7953,6 +8845,25 @@ impl fmt::Display for UpdateRoutingProfileDefaultOutboundQueueError {
     }
 }
 impl Error for UpdateRoutingProfileDefaultOutboundQueueError {}
+impl ServiceErrorCode for UpdateRoutingProfileDefaultOutboundQueueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRoutingProfileDefaultOutboundQueueError::InternalService(_) => {
+                "InternalServiceException"
+            }
+            UpdateRoutingProfileDefaultOutboundQueueError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            UpdateRoutingProfileDefaultOutboundQueueError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            UpdateRoutingProfileDefaultOutboundQueueError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            UpdateRoutingProfileDefaultOutboundQueueError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateRoutingProfileName
 #[derive(Debug, PartialEq)]
 pub enum UpdateRoutingProfileNameError {
@@ -8023,6 +8934,18 @@ impl fmt::Display for UpdateRoutingProfileNameError {
     }
 }
 impl Error for UpdateRoutingProfileNameError {}
+impl ServiceErrorCode for UpdateRoutingProfileNameError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRoutingProfileNameError::DuplicateResource(_) => "DuplicateResourceException",
+            UpdateRoutingProfileNameError::InternalService(_) => "InternalServiceException",
+            UpdateRoutingProfileNameError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateRoutingProfileNameError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateRoutingProfileNameError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateRoutingProfileNameError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateRoutingProfileQueues
 #[derive(Debug, PartialEq)]
 pub enum UpdateRoutingProfileQueuesError {
@@ -8089,6 +9012,17 @@ impl fmt::Display for UpdateRoutingProfileQueuesError {
     }
 }
 impl Error for UpdateRoutingProfileQueuesError {}
+impl ServiceErrorCode for UpdateRoutingProfileQueuesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRoutingProfileQueuesError::InternalService(_) => "InternalServiceException",
+            UpdateRoutingProfileQueuesError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateRoutingProfileQueuesError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateRoutingProfileQueuesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateRoutingProfileQueuesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateUserHierarchy
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserHierarchyError {
@@ -8147,6 +9081,17 @@ impl fmt::Display for UpdateUserHierarchyError {
     }
 }
 impl Error for UpdateUserHierarchyError {}
+impl ServiceErrorCode for UpdateUserHierarchyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserHierarchyError::InternalService(_) => "InternalServiceException",
+            UpdateUserHierarchyError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateUserHierarchyError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateUserHierarchyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateUserHierarchyError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateUserHierarchyGroupName
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserHierarchyGroupNameError {
@@ -8227,6 +9172,18 @@ impl fmt::Display for UpdateUserHierarchyGroupNameError {
     }
 }
 impl Error for UpdateUserHierarchyGroupNameError {}
+impl ServiceErrorCode for UpdateUserHierarchyGroupNameError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserHierarchyGroupNameError::DuplicateResource(_) => "DuplicateResourceException",
+            UpdateUserHierarchyGroupNameError::InternalService(_) => "InternalServiceException",
+            UpdateUserHierarchyGroupNameError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateUserHierarchyGroupNameError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateUserHierarchyGroupNameError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateUserHierarchyGroupNameError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateUserHierarchyStructure
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserHierarchyStructureError {
@@ -8305,6 +9262,18 @@ impl fmt::Display for UpdateUserHierarchyStructureError {
     }
 }
 impl Error for UpdateUserHierarchyStructureError {}
+impl ServiceErrorCode for UpdateUserHierarchyStructureError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserHierarchyStructureError::InternalService(_) => "InternalServiceException",
+            UpdateUserHierarchyStructureError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateUserHierarchyStructureError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateUserHierarchyStructureError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateUserHierarchyStructureError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateUserHierarchyStructureError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateUserIdentityInfo
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserIdentityInfoError {
@@ -8367,6 +9336,17 @@ impl fmt::Display for UpdateUserIdentityInfoError {
     }
 }
 impl Error for UpdateUserIdentityInfoError {}
+impl ServiceErrorCode for UpdateUserIdentityInfoError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserIdentityInfoError::InternalService(_) => "InternalServiceException",
+            UpdateUserIdentityInfoError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateUserIdentityInfoError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateUserIdentityInfoError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateUserIdentityInfoError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateUserPhoneConfig
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserPhoneConfigError {
@@ -8429,6 +9409,17 @@ impl fmt::Display for UpdateUserPhoneConfigError {
     }
 }
 impl Error for UpdateUserPhoneConfigError {}
+impl ServiceErrorCode for UpdateUserPhoneConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserPhoneConfigError::InternalService(_) => "InternalServiceException",
+            UpdateUserPhoneConfigError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateUserPhoneConfigError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateUserPhoneConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateUserPhoneConfigError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateUserRoutingProfile
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserRoutingProfileError {
@@ -8491,6 +9482,17 @@ impl fmt::Display for UpdateUserRoutingProfileError {
     }
 }
 impl Error for UpdateUserRoutingProfileError {}
+impl ServiceErrorCode for UpdateUserRoutingProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserRoutingProfileError::InternalService(_) => "InternalServiceException",
+            UpdateUserRoutingProfileError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateUserRoutingProfileError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateUserRoutingProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateUserRoutingProfileError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateUserSecurityProfiles
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserSecurityProfilesError {
@@ -8557,6 +9559,17 @@ impl fmt::Display for UpdateUserSecurityProfilesError {
     }
 }
 impl Error for UpdateUserSecurityProfilesError {}
+impl ServiceErrorCode for UpdateUserSecurityProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserSecurityProfilesError::InternalService(_) => "InternalServiceException",
+            UpdateUserSecurityProfilesError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateUserSecurityProfilesError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateUserSecurityProfilesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateUserSecurityProfilesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Connect API. Amazon Connect clients implement this trait.
 #[async_trait]
 pub trait Connect {
@@ -11304,6 +12317,10 @@ impl Connect for ConnectClient {
         &self,
         input: StartChatContactRequest,
     ) -> Result<StartChatContactResponse, RusotoError<StartChatContactError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = "/contact/chat";
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
@@ -11367,6 +12384,10 @@ impl Connect for ConnectClient {
         input: StartOutboundVoiceContactRequest,
     ) -> Result<StartOutboundVoiceContactResponse, RusotoError<StartOutboundVoiceContactError>>
     {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = "/contact/outbound-voice";
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
@@ -11398,6 +12419,10 @@ impl Connect for ConnectClient {
         &self,
         input: StartTaskContactRequest,
     ) -> Result<StartTaskContactResponse, RusotoError<StartTaskContactError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = "/contact/task";
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
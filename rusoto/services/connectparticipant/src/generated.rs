@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -53,6 +53,7 @@ pub struct CompleteAttachmentUploadRequest {
     #[serde(rename = "AttachmentIds")]
     pub attachment_ids: Vec<String>,
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     pub client_token: String,
     /// <p>The authentication token associated with the participant's connection.</p>
@@ -106,6 +107,7 @@ pub struct CreateParticipantConnectionResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DisconnectParticipantRequest {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -237,6 +239,7 @@ pub struct Item {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SendEventRequest {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -269,6 +272,7 @@ pub struct SendEventResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SendMessageRequest {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -306,6 +310,7 @@ pub struct StartAttachmentUploadRequest {
     #[serde(rename = "AttachmentSizeInBytes")]
     pub attachment_size_in_bytes: i64,
     /// <p>A unique case sensitive identifier to support idempotency of request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     pub client_token: String,
     /// <p>The authentication token associated with the participant's connection.</p>
@@ -441,6 +446,19 @@ impl fmt::Display for CompleteAttachmentUploadError {
     }
 }
 impl Error for CompleteAttachmentUploadError {}
+impl ServiceErrorCode for CompleteAttachmentUploadError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CompleteAttachmentUploadError::AccessDenied(_) => "AccessDeniedException",
+            CompleteAttachmentUploadError::Conflict(_) => "ConflictException",
+            CompleteAttachmentUploadError::InternalServer(_) => "InternalServerException",
+            CompleteAttachmentUploadError::ServiceQuotaExceeded(_) => {
+                "ServiceQuotaExceededException"
+            }
+            CompleteAttachmentUploadError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateParticipantConnection
 #[derive(Debug, PartialEq)]
 pub enum CreateParticipantConnectionError {
@@ -491,6 +509,15 @@ impl fmt::Display for CreateParticipantConnectionError {
     }
 }
 impl Error for CreateParticipantConnectionError {}
+impl ServiceErrorCode for CreateParticipantConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateParticipantConnectionError::AccessDenied(_) => "AccessDeniedException",
+            CreateParticipantConnectionError::InternalServer(_) => "InternalServerException",
+            CreateParticipantConnectionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DisconnectParticipant
 #[derive(Debug, PartialEq)]
 pub enum DisconnectParticipantError {
@@ -535,6 +562,15 @@ impl fmt::Display for DisconnectParticipantError {
     }
 }
 impl Error for DisconnectParticipantError {}
+impl ServiceErrorCode for DisconnectParticipantError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisconnectParticipantError::AccessDenied(_) => "AccessDeniedException",
+            DisconnectParticipantError::InternalServer(_) => "InternalServerException",
+            DisconnectParticipantError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetAttachment
 #[derive(Debug, PartialEq)]
 pub enum GetAttachmentError {
@@ -577,6 +613,15 @@ impl fmt::Display for GetAttachmentError {
     }
 }
 impl Error for GetAttachmentError {}
+impl ServiceErrorCode for GetAttachmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAttachmentError::AccessDenied(_) => "AccessDeniedException",
+            GetAttachmentError::InternalServer(_) => "InternalServerException",
+            GetAttachmentError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetTranscript
 #[derive(Debug, PartialEq)]
 pub enum GetTranscriptError {
@@ -619,6 +664,15 @@ impl fmt::Display for GetTranscriptError {
     }
 }
 impl Error for GetTranscriptError {}
+impl ServiceErrorCode for GetTranscriptError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTranscriptError::AccessDenied(_) => "AccessDeniedException",
+            GetTranscriptError::InternalServer(_) => "InternalServerException",
+            GetTranscriptError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by SendEvent
 #[derive(Debug, PartialEq)]
 pub enum SendEventError {
@@ -661,6 +715,15 @@ impl fmt::Display for SendEventError {
     }
 }
 impl Error for SendEventError {}
+impl ServiceErrorCode for SendEventError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendEventError::AccessDenied(_) => "AccessDeniedException",
+            SendEventError::InternalServer(_) => "InternalServerException",
+            SendEventError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by SendMessage
 #[derive(Debug, PartialEq)]
 pub enum SendMessageError {
@@ -703,6 +766,15 @@ impl fmt::Display for SendMessageError {
     }
 }
 impl Error for SendMessageError {}
+impl ServiceErrorCode for SendMessageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendMessageError::AccessDenied(_) => "AccessDeniedException",
+            SendMessageError::InternalServer(_) => "InternalServerException",
+            SendMessageError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StartAttachmentUpload
 #[derive(Debug, PartialEq)]
 pub enum StartAttachmentUploadError {
@@ -755,6 +827,16 @@ impl fmt::Display for StartAttachmentUploadError {
     }
 }
 impl Error for StartAttachmentUploadError {}
+impl ServiceErrorCode for StartAttachmentUploadError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartAttachmentUploadError::AccessDenied(_) => "AccessDeniedException",
+            StartAttachmentUploadError::InternalServer(_) => "InternalServerException",
+            StartAttachmentUploadError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            StartAttachmentUploadError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Connect Participant API. Amazon Connect Participant clients implement this trait.
 #[async_trait]
 pub trait ConnectParticipant {
@@ -852,6 +934,12 @@ impl ConnectParticipant for ConnectParticipantClient {
         &self,
         input: CompleteAttachmentUploadRequest,
     ) -> Result<CompleteAttachmentUploadResponse, RusotoError<CompleteAttachmentUploadError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/participant/complete-attachment-upload";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
@@ -919,6 +1007,10 @@ impl ConnectParticipant for ConnectParticipantClient {
         &self,
         input: DisconnectParticipantRequest,
     ) -> Result<DisconnectParticipantResponse, RusotoError<DisconnectParticipantError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = "/participant/disconnect";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
@@ -1018,6 +1110,10 @@ impl ConnectParticipant for ConnectParticipantClient {
         &self,
         input: SendEventRequest,
     ) -> Result<SendEventResponse, RusotoError<SendEventError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = "/participant/event";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
@@ -1051,6 +1147,10 @@ impl ConnectParticipant for ConnectParticipantClient {
         &self,
         input: SendMessageRequest,
     ) -> Result<SendMessageResponse, RusotoError<SendMessageError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = "/participant/message";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
@@ -1084,6 +1184,12 @@ impl ConnectParticipant for ConnectParticipantClient {
         &self,
         input: StartAttachmentUploadRequest,
     ) -> Result<StartAttachmentUploadResponse, RusotoError<StartAttachmentUploadError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/participant/start-attachment-upload";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
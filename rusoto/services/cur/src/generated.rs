@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -187,6 +187,13 @@ impl fmt::Display for DeleteReportDefinitionError {
     }
 }
 impl Error for DeleteReportDefinitionError {}
+impl ServiceErrorCode for DeleteReportDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteReportDefinitionError::InternalError(_) => "InternalErrorException",
+        }
+    }
+}
 /// Errors returned by DescribeReportDefinitions
 #[derive(Debug, PartialEq)]
 pub enum DescribeReportDefinitionsError {
@@ -219,6 +226,13 @@ impl fmt::Display for DescribeReportDefinitionsError {
     }
 }
 impl Error for DescribeReportDefinitionsError {}
+impl ServiceErrorCode for DescribeReportDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReportDefinitionsError::InternalError(_) => "InternalErrorException",
+        }
+    }
+}
 /// Errors returned by ModifyReportDefinition
 #[derive(Debug, PartialEq)]
 pub enum ModifyReportDefinitionError {
@@ -251,6 +265,13 @@ impl fmt::Display for ModifyReportDefinitionError {
     }
 }
 impl Error for ModifyReportDefinitionError {}
+impl ServiceErrorCode for ModifyReportDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyReportDefinitionError::InternalError(_) => "InternalErrorException",
+        }
+    }
+}
 /// Errors returned by PutReportDefinition
 #[derive(Debug, PartialEq)]
 pub enum PutReportDefinitionError {
@@ -297,6 +318,15 @@ impl fmt::Display for PutReportDefinitionError {
     }
 }
 impl Error for PutReportDefinitionError {}
+impl ServiceErrorCode for PutReportDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutReportDefinitionError::DuplicateReportName(_) => "DuplicateReportNameException",
+            PutReportDefinitionError::InternalError(_) => "InternalErrorException",
+            PutReportDefinitionError::ReportLimitReached(_) => "ReportLimitReachedException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Cost and Usage Report Service API. AWS Cost and Usage Report Service clients implement this trait.
 #[async_trait]
 pub trait CostAndUsageReport {
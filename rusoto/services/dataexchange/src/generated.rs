@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1307,6 +1307,16 @@ impl fmt::Display for CancelJobError {
     }
 }
 impl Error for CancelJobError {}
+impl ServiceErrorCode for CancelJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelJobError::Conflict(_) => "ConflictException",
+            CancelJobError::InternalServer(_) => "InternalServerException",
+            CancelJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateDataSet
 #[derive(Debug, PartialEq)]
 pub enum CreateDataSetError {
@@ -1355,6 +1365,16 @@ impl fmt::Display for CreateDataSetError {
     }
 }
 impl Error for CreateDataSetError {}
+impl ServiceErrorCode for CreateDataSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDataSetError::AccessDenied(_) => "AccessDeniedException",
+            CreateDataSetError::InternalServer(_) => "InternalServerException",
+            CreateDataSetError::ServiceLimitExceeded(_) => "ServiceLimitExceededException",
+            CreateDataSetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateJob
 #[derive(Debug, PartialEq)]
 pub enum CreateJobError {
@@ -1403,6 +1423,16 @@ impl fmt::Display for CreateJobError {
     }
 }
 impl Error for CreateJobError {}
+impl ServiceErrorCode for CreateJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateJobError::AccessDenied(_) => "AccessDeniedException",
+            CreateJobError::InternalServer(_) => "InternalServerException",
+            CreateJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateRevision
 #[derive(Debug, PartialEq)]
 pub enum CreateRevisionError {
@@ -1451,6 +1481,16 @@ impl fmt::Display for CreateRevisionError {
     }
 }
 impl Error for CreateRevisionError {}
+impl ServiceErrorCode for CreateRevisionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRevisionError::AccessDenied(_) => "AccessDeniedException",
+            CreateRevisionError::InternalServer(_) => "InternalServerException",
+            CreateRevisionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateRevisionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteAsset
 #[derive(Debug, PartialEq)]
 pub enum DeleteAssetError {
@@ -1505,6 +1545,17 @@ impl fmt::Display for DeleteAssetError {
     }
 }
 impl Error for DeleteAssetError {}
+impl ServiceErrorCode for DeleteAssetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAssetError::AccessDenied(_) => "AccessDeniedException",
+            DeleteAssetError::Conflict(_) => "ConflictException",
+            DeleteAssetError::InternalServer(_) => "InternalServerException",
+            DeleteAssetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteAssetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteDataSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteDataSetError {
@@ -1559,6 +1610,17 @@ impl fmt::Display for DeleteDataSetError {
     }
 }
 impl Error for DeleteDataSetError {}
+impl ServiceErrorCode for DeleteDataSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDataSetError::AccessDenied(_) => "AccessDeniedException",
+            DeleteDataSetError::Conflict(_) => "ConflictException",
+            DeleteDataSetError::InternalServer(_) => "InternalServerException",
+            DeleteDataSetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDataSetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteRevision
 #[derive(Debug, PartialEq)]
 pub enum DeleteRevisionError {
@@ -1613,6 +1675,17 @@ impl fmt::Display for DeleteRevisionError {
     }
 }
 impl Error for DeleteRevisionError {}
+impl ServiceErrorCode for DeleteRevisionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRevisionError::AccessDenied(_) => "AccessDeniedException",
+            DeleteRevisionError::Conflict(_) => "ConflictException",
+            DeleteRevisionError::InternalServer(_) => "InternalServerException",
+            DeleteRevisionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteRevisionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetAsset
 #[derive(Debug, PartialEq)]
 pub enum GetAssetError {
@@ -1655,6 +1728,15 @@ impl fmt::Display for GetAssetError {
     }
 }
 impl Error for GetAssetError {}
+impl ServiceErrorCode for GetAssetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAssetError::InternalServer(_) => "InternalServerException",
+            GetAssetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetAssetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetDataSet
 #[derive(Debug, PartialEq)]
 pub enum GetDataSetError {
@@ -1697,6 +1779,15 @@ impl fmt::Display for GetDataSetError {
     }
 }
 impl Error for GetDataSetError {}
+impl ServiceErrorCode for GetDataSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDataSetError::InternalServer(_) => "InternalServerException",
+            GetDataSetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetDataSetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetJob
 #[derive(Debug, PartialEq)]
 pub enum GetJobError {
@@ -1739,6 +1830,15 @@ impl fmt::Display for GetJobError {
     }
 }
 impl Error for GetJobError {}
+impl ServiceErrorCode for GetJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetJobError::InternalServer(_) => "InternalServerException",
+            GetJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetRevision
 #[derive(Debug, PartialEq)]
 pub enum GetRevisionError {
@@ -1781,6 +1881,15 @@ impl fmt::Display for GetRevisionError {
     }
 }
 impl Error for GetRevisionError {}
+impl ServiceErrorCode for GetRevisionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRevisionError::InternalServer(_) => "InternalServerException",
+            GetRevisionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetRevisionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDataSetRevisions
 #[derive(Debug, PartialEq)]
 pub enum ListDataSetRevisionsError {
@@ -1825,6 +1934,15 @@ impl fmt::Display for ListDataSetRevisionsError {
     }
 }
 impl Error for ListDataSetRevisionsError {}
+impl ServiceErrorCode for ListDataSetRevisionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDataSetRevisionsError::InternalServer(_) => "InternalServerException",
+            ListDataSetRevisionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListDataSetRevisionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDataSets
 #[derive(Debug, PartialEq)]
 pub enum ListDataSetsError {
@@ -1867,6 +1985,15 @@ impl fmt::Display for ListDataSetsError {
     }
 }
 impl Error for ListDataSetsError {}
+impl ServiceErrorCode for ListDataSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDataSetsError::InternalServer(_) => "InternalServerException",
+            ListDataSetsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListDataSetsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
 pub enum ListJobsError {
@@ -1909,6 +2036,15 @@ impl fmt::Display for ListJobsError {
     }
 }
 impl Error for ListJobsError {}
+impl ServiceErrorCode for ListJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJobsError::InternalServer(_) => "InternalServerException",
+            ListJobsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListJobsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListRevisionAssets
 #[derive(Debug, PartialEq)]
 pub enum ListRevisionAssetsError {
@@ -1951,6 +2087,15 @@ impl fmt::Display for ListRevisionAssetsError {
     }
 }
 impl Error for ListRevisionAssetsError {}
+impl ServiceErrorCode for ListRevisionAssetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRevisionAssetsError::InternalServer(_) => "InternalServerException",
+            ListRevisionAssetsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListRevisionAssetsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {}
@@ -1973,6 +2118,11 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by StartJob
 #[derive(Debug, PartialEq)]
 pub enum StartJobError {
@@ -2027,6 +2177,17 @@ impl fmt::Display for StartJobError {
     }
 }
 impl Error for StartJobError {}
+impl ServiceErrorCode for StartJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartJobError::AccessDenied(_) => "AccessDeniedException",
+            StartJobError::Conflict(_) => "ConflictException",
+            StartJobError::InternalServer(_) => "InternalServerException",
+            StartJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {}
@@ -2049,6 +2210,11 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {}
@@ -2071,6 +2237,11 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UpdateAsset
 #[derive(Debug, PartialEq)]
 pub enum UpdateAssetError {
@@ -2125,6 +2296,17 @@ impl fmt::Display for UpdateAssetError {
     }
 }
 impl Error for UpdateAssetError {}
+impl ServiceErrorCode for UpdateAssetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAssetError::AccessDenied(_) => "AccessDeniedException",
+            UpdateAssetError::Conflict(_) => "ConflictException",
+            UpdateAssetError::InternalServer(_) => "InternalServerException",
+            UpdateAssetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateAssetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateDataSet
 #[derive(Debug, PartialEq)]
 pub enum UpdateDataSetError {
@@ -2173,6 +2355,16 @@ impl fmt::Display for UpdateDataSetError {
     }
 }
 impl Error for UpdateDataSetError {}
+impl ServiceErrorCode for UpdateDataSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDataSetError::AccessDenied(_) => "AccessDeniedException",
+            UpdateDataSetError::InternalServer(_) => "InternalServerException",
+            UpdateDataSetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDataSetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateRevision
 #[derive(Debug, PartialEq)]
 pub enum UpdateRevisionError {
@@ -2227,6 +2419,17 @@ impl fmt::Display for UpdateRevisionError {
     }
 }
 impl Error for UpdateRevisionError {}
+impl ServiceErrorCode for UpdateRevisionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRevisionError::AccessDenied(_) => "AccessDeniedException",
+            UpdateRevisionError::Conflict(_) => "ConflictException",
+            UpdateRevisionError::InternalServer(_) => "InternalServerException",
+            UpdateRevisionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateRevisionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Data Exchange API. AWS Data Exchange clients implement this trait.
 #[async_trait]
 pub trait DataExchange {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -797,6 +797,16 @@ impl fmt::Display for ActivatePipelineError {
     }
 }
 impl Error for ActivatePipelineError {}
+impl ServiceErrorCode for ActivatePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ActivatePipelineError::InternalServiceError(_) => "InternalServiceError",
+            ActivatePipelineError::InvalidRequest(_) => "InvalidRequestException",
+            ActivatePipelineError::PipelineDeleted(_) => "PipelineDeletedException",
+            ActivatePipelineError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by AddTags
 #[derive(Debug, PartialEq)]
 pub enum AddTagsError {
@@ -845,6 +855,16 @@ impl fmt::Display for AddTagsError {
     }
 }
 impl Error for AddTagsError {}
+impl ServiceErrorCode for AddTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsError::InternalServiceError(_) => "InternalServiceError",
+            AddTagsError::InvalidRequest(_) => "InvalidRequestException",
+            AddTagsError::PipelineDeleted(_) => "PipelineDeletedException",
+            AddTagsError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreatePipeline
 #[derive(Debug, PartialEq)]
 pub enum CreatePipelineError {
@@ -881,6 +901,14 @@ impl fmt::Display for CreatePipelineError {
     }
 }
 impl Error for CreatePipelineError {}
+impl ServiceErrorCode for CreatePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePipelineError::InternalServiceError(_) => "InternalServiceError",
+            CreatePipelineError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DeactivatePipeline
 #[derive(Debug, PartialEq)]
 pub enum DeactivatePipelineError {
@@ -931,6 +959,16 @@ impl fmt::Display for DeactivatePipelineError {
     }
 }
 impl Error for DeactivatePipelineError {}
+impl ServiceErrorCode for DeactivatePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeactivatePipelineError::InternalServiceError(_) => "InternalServiceError",
+            DeactivatePipelineError::InvalidRequest(_) => "InvalidRequestException",
+            DeactivatePipelineError::PipelineDeleted(_) => "PipelineDeletedException",
+            DeactivatePipelineError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeletePipeline
 #[derive(Debug, PartialEq)]
 pub enum DeletePipelineError {
@@ -973,6 +1011,15 @@ impl fmt::Display for DeletePipelineError {
     }
 }
 impl Error for DeletePipelineError {}
+impl ServiceErrorCode for DeletePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePipelineError::InternalServiceError(_) => "InternalServiceError",
+            DeletePipelineError::InvalidRequest(_) => "InvalidRequestException",
+            DeletePipelineError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeObjects
 #[derive(Debug, PartialEq)]
 pub enum DescribeObjectsError {
@@ -1023,6 +1070,16 @@ impl fmt::Display for DescribeObjectsError {
     }
 }
 impl Error for DescribeObjectsError {}
+impl ServiceErrorCode for DescribeObjectsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeObjectsError::InternalServiceError(_) => "InternalServiceError",
+            DescribeObjectsError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeObjectsError::PipelineDeleted(_) => "PipelineDeletedException",
+            DescribeObjectsError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribePipelines
 #[derive(Debug, PartialEq)]
 pub enum DescribePipelinesError {
@@ -1073,6 +1130,16 @@ impl fmt::Display for DescribePipelinesError {
     }
 }
 impl Error for DescribePipelinesError {}
+impl ServiceErrorCode for DescribePipelinesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePipelinesError::InternalServiceError(_) => "InternalServiceError",
+            DescribePipelinesError::InvalidRequest(_) => "InvalidRequestException",
+            DescribePipelinesError::PipelineDeleted(_) => "PipelineDeletedException",
+            DescribePipelinesError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by EvaluateExpression
 #[derive(Debug, PartialEq)]
 pub enum EvaluateExpressionError {
@@ -1129,6 +1196,17 @@ impl fmt::Display for EvaluateExpressionError {
     }
 }
 impl Error for EvaluateExpressionError {}
+impl ServiceErrorCode for EvaluateExpressionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EvaluateExpressionError::InternalServiceError(_) => "InternalServiceError",
+            EvaluateExpressionError::InvalidRequest(_) => "InvalidRequestException",
+            EvaluateExpressionError::PipelineDeleted(_) => "PipelineDeletedException",
+            EvaluateExpressionError::PipelineNotFound(_) => "PipelineNotFoundException",
+            EvaluateExpressionError::TaskNotFound(_) => "TaskNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetPipelineDefinition
 #[derive(Debug, PartialEq)]
 pub enum GetPipelineDefinitionError {
@@ -1185,6 +1263,16 @@ impl fmt::Display for GetPipelineDefinitionError {
     }
 }
 impl Error for GetPipelineDefinitionError {}
+impl ServiceErrorCode for GetPipelineDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPipelineDefinitionError::InternalServiceError(_) => "InternalServiceError",
+            GetPipelineDefinitionError::InvalidRequest(_) => "InvalidRequestException",
+            GetPipelineDefinitionError::PipelineDeleted(_) => "PipelineDeletedException",
+            GetPipelineDefinitionError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListPipelines
 #[derive(Debug, PartialEq)]
 pub enum ListPipelinesError {
@@ -1221,6 +1309,14 @@ impl fmt::Display for ListPipelinesError {
     }
 }
 impl Error for ListPipelinesError {}
+impl ServiceErrorCode for ListPipelinesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPipelinesError::InternalServiceError(_) => "InternalServiceError",
+            ListPipelinesError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by PollForTask
 #[derive(Debug, PartialEq)]
 pub enum PollForTaskError {
@@ -1263,6 +1359,15 @@ impl fmt::Display for PollForTaskError {
     }
 }
 impl Error for PollForTaskError {}
+impl ServiceErrorCode for PollForTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PollForTaskError::InternalServiceError(_) => "InternalServiceError",
+            PollForTaskError::InvalidRequest(_) => "InvalidRequestException",
+            PollForTaskError::TaskNotFound(_) => "TaskNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutPipelineDefinition
 #[derive(Debug, PartialEq)]
 pub enum PutPipelineDefinitionError {
@@ -1319,6 +1424,16 @@ impl fmt::Display for PutPipelineDefinitionError {
     }
 }
 impl Error for PutPipelineDefinitionError {}
+impl ServiceErrorCode for PutPipelineDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutPipelineDefinitionError::InternalServiceError(_) => "InternalServiceError",
+            PutPipelineDefinitionError::InvalidRequest(_) => "InvalidRequestException",
+            PutPipelineDefinitionError::PipelineDeleted(_) => "PipelineDeletedException",
+            PutPipelineDefinitionError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by QueryObjects
 #[derive(Debug, PartialEq)]
 pub enum QueryObjectsError {
@@ -1367,6 +1482,16 @@ impl fmt::Display for QueryObjectsError {
     }
 }
 impl Error for QueryObjectsError {}
+impl ServiceErrorCode for QueryObjectsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            QueryObjectsError::InternalServiceError(_) => "InternalServiceError",
+            QueryObjectsError::InvalidRequest(_) => "InvalidRequestException",
+            QueryObjectsError::PipelineDeleted(_) => "PipelineDeletedException",
+            QueryObjectsError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by RemoveTags
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsError {
@@ -1415,6 +1540,16 @@ impl fmt::Display for RemoveTagsError {
     }
 }
 impl Error for RemoveTagsError {}
+impl ServiceErrorCode for RemoveTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsError::InternalServiceError(_) => "InternalServiceError",
+            RemoveTagsError::InvalidRequest(_) => "InvalidRequestException",
+            RemoveTagsError::PipelineDeleted(_) => "PipelineDeletedException",
+            RemoveTagsError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by ReportTaskProgress
 #[derive(Debug, PartialEq)]
 pub enum ReportTaskProgressError {
@@ -1471,6 +1606,17 @@ impl fmt::Display for ReportTaskProgressError {
     }
 }
 impl Error for ReportTaskProgressError {}
+impl ServiceErrorCode for ReportTaskProgressError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ReportTaskProgressError::InternalServiceError(_) => "InternalServiceError",
+            ReportTaskProgressError::InvalidRequest(_) => "InvalidRequestException",
+            ReportTaskProgressError::PipelineDeleted(_) => "PipelineDeletedException",
+            ReportTaskProgressError::PipelineNotFound(_) => "PipelineNotFoundException",
+            ReportTaskProgressError::TaskNotFound(_) => "TaskNotFoundException",
+        }
+    }
+}
 /// Errors returned by ReportTaskRunnerHeartbeat
 #[derive(Debug, PartialEq)]
 pub enum ReportTaskRunnerHeartbeatError {
@@ -1513,6 +1659,14 @@ impl fmt::Display for ReportTaskRunnerHeartbeatError {
     }
 }
 impl Error for ReportTaskRunnerHeartbeatError {}
+impl ServiceErrorCode for ReportTaskRunnerHeartbeatError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ReportTaskRunnerHeartbeatError::InternalServiceError(_) => "InternalServiceError",
+            ReportTaskRunnerHeartbeatError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by SetStatus
 #[derive(Debug, PartialEq)]
 pub enum SetStatusError {
@@ -1561,6 +1715,16 @@ impl fmt::Display for SetStatusError {
     }
 }
 impl Error for SetStatusError {}
+impl ServiceErrorCode for SetStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetStatusError::InternalServiceError(_) => "InternalServiceError",
+            SetStatusError::InvalidRequest(_) => "InvalidRequestException",
+            SetStatusError::PipelineDeleted(_) => "PipelineDeletedException",
+            SetStatusError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Errors returned by SetTaskStatus
 #[derive(Debug, PartialEq)]
 pub enum SetTaskStatusError {
@@ -1615,6 +1779,17 @@ impl fmt::Display for SetTaskStatusError {
     }
 }
 impl Error for SetTaskStatusError {}
+impl ServiceErrorCode for SetTaskStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetTaskStatusError::InternalServiceError(_) => "InternalServiceError",
+            SetTaskStatusError::InvalidRequest(_) => "InvalidRequestException",
+            SetTaskStatusError::PipelineDeleted(_) => "PipelineDeletedException",
+            SetTaskStatusError::PipelineNotFound(_) => "PipelineNotFoundException",
+            SetTaskStatusError::TaskNotFound(_) => "TaskNotFoundException",
+        }
+    }
+}
 /// Errors returned by ValidatePipelineDefinition
 #[derive(Debug, PartialEq)]
 pub enum ValidatePipelineDefinitionError {
@@ -1675,6 +1850,16 @@ impl fmt::Display for ValidatePipelineDefinitionError {
     }
 }
 impl Error for ValidatePipelineDefinitionError {}
+impl ServiceErrorCode for ValidatePipelineDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ValidatePipelineDefinitionError::InternalServiceError(_) => "InternalServiceError",
+            ValidatePipelineDefinitionError::InvalidRequest(_) => "InvalidRequestException",
+            ValidatePipelineDefinitionError::PipelineDeleted(_) => "PipelineDeletedException",
+            ValidatePipelineDefinitionError::PipelineNotFound(_) => "PipelineNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Data Pipeline API. AWS Data Pipeline clients implement this trait.
 #[async_trait]
 pub trait DataPipeline {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1427,6 +1427,14 @@ impl fmt::Display for CancelTaskExecutionError {
     }
 }
 impl Error for CancelTaskExecutionError {}
+impl ServiceErrorCode for CancelTaskExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelTaskExecutionError::Internal(_) => "InternalException",
+            CancelTaskExecutionError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateAgent
 #[derive(Debug, PartialEq)]
 pub enum CreateAgentError {
@@ -1463,6 +1471,14 @@ impl fmt::Display for CreateAgentError {
     }
 }
 impl Error for CreateAgentError {}
+impl ServiceErrorCode for CreateAgentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAgentError::Internal(_) => "InternalException",
+            CreateAgentError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateLocationEfs
 #[derive(Debug, PartialEq)]
 pub enum CreateLocationEfsError {
@@ -1499,6 +1515,14 @@ impl fmt::Display for CreateLocationEfsError {
     }
 }
 impl Error for CreateLocationEfsError {}
+impl ServiceErrorCode for CreateLocationEfsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLocationEfsError::Internal(_) => "InternalException",
+            CreateLocationEfsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateLocationFsxWindows
 #[derive(Debug, PartialEq)]
 pub enum CreateLocationFsxWindowsError {
@@ -1537,6 +1561,14 @@ impl fmt::Display for CreateLocationFsxWindowsError {
     }
 }
 impl Error for CreateLocationFsxWindowsError {}
+impl ServiceErrorCode for CreateLocationFsxWindowsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLocationFsxWindowsError::Internal(_) => "InternalException",
+            CreateLocationFsxWindowsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateLocationNfs
 #[derive(Debug, PartialEq)]
 pub enum CreateLocationNfsError {
@@ -1573,6 +1605,14 @@ impl fmt::Display for CreateLocationNfsError {
     }
 }
 impl Error for CreateLocationNfsError {}
+impl ServiceErrorCode for CreateLocationNfsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLocationNfsError::Internal(_) => "InternalException",
+            CreateLocationNfsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateLocationObjectStorage
 #[derive(Debug, PartialEq)]
 pub enum CreateLocationObjectStorageError {
@@ -1615,6 +1655,14 @@ impl fmt::Display for CreateLocationObjectStorageError {
     }
 }
 impl Error for CreateLocationObjectStorageError {}
+impl ServiceErrorCode for CreateLocationObjectStorageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLocationObjectStorageError::Internal(_) => "InternalException",
+            CreateLocationObjectStorageError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateLocationS3
 #[derive(Debug, PartialEq)]
 pub enum CreateLocationS3Error {
@@ -1651,6 +1699,14 @@ impl fmt::Display for CreateLocationS3Error {
     }
 }
 impl Error for CreateLocationS3Error {}
+impl ServiceErrorCode for CreateLocationS3Error {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLocationS3Error::Internal(_) => "InternalException",
+            CreateLocationS3Error::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateLocationSmb
 #[derive(Debug, PartialEq)]
 pub enum CreateLocationSmbError {
@@ -1687,6 +1743,14 @@ impl fmt::Display for CreateLocationSmbError {
     }
 }
 impl Error for CreateLocationSmbError {}
+impl ServiceErrorCode for CreateLocationSmbError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLocationSmbError::Internal(_) => "InternalException",
+            CreateLocationSmbError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateTask
 #[derive(Debug, PartialEq)]
 pub enum CreateTaskError {
@@ -1723,6 +1787,14 @@ impl fmt::Display for CreateTaskError {
     }
 }
 impl Error for CreateTaskError {}
+impl ServiceErrorCode for CreateTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTaskError::Internal(_) => "InternalException",
+            CreateTaskError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteAgent
 #[derive(Debug, PartialEq)]
 pub enum DeleteAgentError {
@@ -1759,6 +1831,14 @@ impl fmt::Display for DeleteAgentError {
     }
 }
 impl Error for DeleteAgentError {}
+impl ServiceErrorCode for DeleteAgentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAgentError::Internal(_) => "InternalException",
+            DeleteAgentError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteLocation
 #[derive(Debug, PartialEq)]
 pub enum DeleteLocationError {
@@ -1795,6 +1875,14 @@ impl fmt::Display for DeleteLocationError {
     }
 }
 impl Error for DeleteLocationError {}
+impl ServiceErrorCode for DeleteLocationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLocationError::Internal(_) => "InternalException",
+            DeleteLocationError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteTask
 #[derive(Debug, PartialEq)]
 pub enum DeleteTaskError {
@@ -1831,6 +1919,14 @@ impl fmt::Display for DeleteTaskError {
     }
 }
 impl Error for DeleteTaskError {}
+impl ServiceErrorCode for DeleteTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTaskError::Internal(_) => "InternalException",
+            DeleteTaskError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeAgent
 #[derive(Debug, PartialEq)]
 pub enum DescribeAgentError {
@@ -1867,6 +1963,14 @@ impl fmt::Display for DescribeAgentError {
     }
 }
 impl Error for DescribeAgentError {}
+impl ServiceErrorCode for DescribeAgentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAgentError::Internal(_) => "InternalException",
+            DescribeAgentError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeLocationEfs
 #[derive(Debug, PartialEq)]
 pub enum DescribeLocationEfsError {
@@ -1903,6 +2007,14 @@ impl fmt::Display for DescribeLocationEfsError {
     }
 }
 impl Error for DescribeLocationEfsError {}
+impl ServiceErrorCode for DescribeLocationEfsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLocationEfsError::Internal(_) => "InternalException",
+            DescribeLocationEfsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeLocationFsxWindows
 #[derive(Debug, PartialEq)]
 pub enum DescribeLocationFsxWindowsError {
@@ -1943,6 +2055,14 @@ impl fmt::Display for DescribeLocationFsxWindowsError {
     }
 }
 impl Error for DescribeLocationFsxWindowsError {}
+impl ServiceErrorCode for DescribeLocationFsxWindowsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLocationFsxWindowsError::Internal(_) => "InternalException",
+            DescribeLocationFsxWindowsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeLocationNfs
 #[derive(Debug, PartialEq)]
 pub enum DescribeLocationNfsError {
@@ -1979,6 +2099,14 @@ impl fmt::Display for DescribeLocationNfsError {
     }
 }
 impl Error for DescribeLocationNfsError {}
+impl ServiceErrorCode for DescribeLocationNfsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLocationNfsError::Internal(_) => "InternalException",
+            DescribeLocationNfsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeLocationObjectStorage
 #[derive(Debug, PartialEq)]
 pub enum DescribeLocationObjectStorageError {
@@ -2021,6 +2149,14 @@ impl fmt::Display for DescribeLocationObjectStorageError {
     }
 }
 impl Error for DescribeLocationObjectStorageError {}
+impl ServiceErrorCode for DescribeLocationObjectStorageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLocationObjectStorageError::Internal(_) => "InternalException",
+            DescribeLocationObjectStorageError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeLocationS3
 #[derive(Debug, PartialEq)]
 pub enum DescribeLocationS3Error {
@@ -2057,6 +2193,14 @@ impl fmt::Display for DescribeLocationS3Error {
     }
 }
 impl Error for DescribeLocationS3Error {}
+impl ServiceErrorCode for DescribeLocationS3Error {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLocationS3Error::Internal(_) => "InternalException",
+            DescribeLocationS3Error::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeLocationSmb
 #[derive(Debug, PartialEq)]
 pub enum DescribeLocationSmbError {
@@ -2093,6 +2237,14 @@ impl fmt::Display for DescribeLocationSmbError {
     }
 }
 impl Error for DescribeLocationSmbError {}
+impl ServiceErrorCode for DescribeLocationSmbError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLocationSmbError::Internal(_) => "InternalException",
+            DescribeLocationSmbError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeTask
 #[derive(Debug, PartialEq)]
 pub enum DescribeTaskError {
@@ -2129,6 +2281,14 @@ impl fmt::Display for DescribeTaskError {
     }
 }
 impl Error for DescribeTaskError {}
+impl ServiceErrorCode for DescribeTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTaskError::Internal(_) => "InternalException",
+            DescribeTaskError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeTaskExecution
 #[derive(Debug, PartialEq)]
 pub enum DescribeTaskExecutionError {
@@ -2167,6 +2327,14 @@ impl fmt::Display for DescribeTaskExecutionError {
     }
 }
 impl Error for DescribeTaskExecutionError {}
+impl ServiceErrorCode for DescribeTaskExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTaskExecutionError::Internal(_) => "InternalException",
+            DescribeTaskExecutionError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListAgents
 #[derive(Debug, PartialEq)]
 pub enum ListAgentsError {
@@ -2203,6 +2371,14 @@ impl fmt::Display for ListAgentsError {
     }
 }
 impl Error for ListAgentsError {}
+impl ServiceErrorCode for ListAgentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAgentsError::Internal(_) => "InternalException",
+            ListAgentsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListLocations
 #[derive(Debug, PartialEq)]
 pub enum ListLocationsError {
@@ -2239,6 +2415,14 @@ impl fmt::Display for ListLocationsError {
     }
 }
 impl Error for ListLocationsError {}
+impl ServiceErrorCode for ListLocationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLocationsError::Internal(_) => "InternalException",
+            ListLocationsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2275,6 +2459,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::Internal(_) => "InternalException",
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListTaskExecutions
 #[derive(Debug, PartialEq)]
 pub enum ListTaskExecutionsError {
@@ -2311,6 +2503,14 @@ impl fmt::Display for ListTaskExecutionsError {
     }
 }
 impl Error for ListTaskExecutionsError {}
+impl ServiceErrorCode for ListTaskExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTaskExecutionsError::Internal(_) => "InternalException",
+            ListTaskExecutionsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListTasks
 #[derive(Debug, PartialEq)]
 pub enum ListTasksError {
@@ -2347,6 +2547,14 @@ impl fmt::Display for ListTasksError {
     }
 }
 impl Error for ListTasksError {}
+impl ServiceErrorCode for ListTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTasksError::Internal(_) => "InternalException",
+            ListTasksError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by StartTaskExecution
 #[derive(Debug, PartialEq)]
 pub enum StartTaskExecutionError {
@@ -2383,6 +2591,14 @@ impl fmt::Display for StartTaskExecutionError {
     }
 }
 impl Error for StartTaskExecutionError {}
+impl ServiceErrorCode for StartTaskExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartTaskExecutionError::Internal(_) => "InternalException",
+            StartTaskExecutionError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2419,6 +2635,14 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::Internal(_) => "InternalException",
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2455,6 +2679,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::Internal(_) => "InternalException",
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateAgent
 #[derive(Debug, PartialEq)]
 pub enum UpdateAgentError {
@@ -2491,6 +2723,14 @@ impl fmt::Display for UpdateAgentError {
     }
 }
 impl Error for UpdateAgentError {}
+impl ServiceErrorCode for UpdateAgentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAgentError::Internal(_) => "InternalException",
+            UpdateAgentError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateTask
 #[derive(Debug, PartialEq)]
 pub enum UpdateTaskError {
@@ -2527,6 +2767,14 @@ impl fmt::Display for UpdateTaskError {
     }
 }
 impl Error for UpdateTaskError {}
+impl ServiceErrorCode for UpdateTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTaskError::Internal(_) => "InternalException",
+            UpdateTaskError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateTaskExecution
 #[derive(Debug, PartialEq)]
 pub enum UpdateTaskExecutionError {
@@ -2563,6 +2811,14 @@ impl fmt::Display for UpdateTaskExecutionError {
     }
 }
 impl Error for UpdateTaskExecutionError {}
+impl ServiceErrorCode for UpdateTaskExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTaskExecutionError::Internal(_) => "InternalException",
+            UpdateTaskExecutionError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Trait representing the capabilities of the DataSync API. DataSync clients implement this trait.
 #[async_trait]
 pub trait DataSync {
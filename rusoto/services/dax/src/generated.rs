@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1112,6 +1112,40 @@ impl fmt::Display for CreateClusterError {
     }
 }
 impl Error for CreateClusterError {}
+impl ServiceErrorCode for CreateClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateClusterError::ClusterAlreadyExistsFault(_) => "ClusterAlreadyExistsFault",
+            CreateClusterError::ClusterQuotaForCustomerExceededFault(_) => {
+                "ClusterQuotaForCustomerExceededFault"
+            }
+            CreateClusterError::InsufficientClusterCapacityFault(_) => {
+                "InsufficientClusterCapacityFault"
+            }
+            CreateClusterError::InvalidClusterStateFault(_) => "InvalidClusterStateFault",
+            CreateClusterError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            CreateClusterError::InvalidParameterGroupStateFault(_) => {
+                "InvalidParameterGroupStateFault"
+            }
+            CreateClusterError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateClusterError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            CreateClusterError::NodeQuotaForClusterExceededFault(_) => {
+                "NodeQuotaForClusterExceededFault"
+            }
+            CreateClusterError::NodeQuotaForCustomerExceededFault(_) => {
+                "NodeQuotaForCustomerExceededFault"
+            }
+            CreateClusterError::ParameterGroupNotFoundFault(_) => "ParameterGroupNotFoundFault",
+            CreateClusterError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+            CreateClusterError::SubnetGroupNotFoundFault(_) => "SubnetGroupNotFoundFault",
+            CreateClusterError::TagQuotaPerResourceExceeded(_) => "TagQuotaPerResourceExceeded",
+        }
+    }
+}
 /// Errors returned by CreateParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateParameterGroupError {
@@ -1194,6 +1228,28 @@ impl fmt::Display for CreateParameterGroupError {
     }
 }
 impl Error for CreateParameterGroupError {}
+impl ServiceErrorCode for CreateParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateParameterGroupError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            CreateParameterGroupError::InvalidParameterGroupStateFault(_) => {
+                "InvalidParameterGroupStateFault"
+            }
+            CreateParameterGroupError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateParameterGroupError::ParameterGroupAlreadyExistsFault(_) => {
+                "ParameterGroupAlreadyExistsFault"
+            }
+            CreateParameterGroupError::ParameterGroupQuotaExceededFault(_) => {
+                "ParameterGroupQuotaExceededFault"
+            }
+            CreateParameterGroupError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by CreateSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateSubnetGroupError {
@@ -1262,6 +1318,23 @@ impl fmt::Display for CreateSubnetGroupError {
     }
 }
 impl Error for CreateSubnetGroupError {}
+impl ServiceErrorCode for CreateSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSubnetGroupError::InvalidSubnet(_) => "InvalidSubnet",
+            CreateSubnetGroupError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+            CreateSubnetGroupError::SubnetGroupAlreadyExistsFault(_) => {
+                "SubnetGroupAlreadyExistsFault"
+            }
+            CreateSubnetGroupError::SubnetGroupQuotaExceededFault(_) => {
+                "SubnetGroupQuotaExceededFault"
+            }
+            CreateSubnetGroupError::SubnetQuotaExceededFault(_) => "SubnetQuotaExceededFault",
+        }
+    }
+}
 /// Errors returned by DecreaseReplicationFactor
 #[derive(Debug, PartialEq)]
 pub enum DecreaseReplicationFactorError {
@@ -1344,6 +1417,26 @@ impl fmt::Display for DecreaseReplicationFactorError {
     }
 }
 impl Error for DecreaseReplicationFactorError {}
+impl ServiceErrorCode for DecreaseReplicationFactorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DecreaseReplicationFactorError::ClusterNotFoundFault(_) => "ClusterNotFoundFault",
+            DecreaseReplicationFactorError::InvalidClusterStateFault(_) => {
+                "InvalidClusterStateFault"
+            }
+            DecreaseReplicationFactorError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            DecreaseReplicationFactorError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DecreaseReplicationFactorError::NodeNotFoundFault(_) => "NodeNotFoundFault",
+            DecreaseReplicationFactorError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteCluster
 #[derive(Debug, PartialEq)]
 pub enum DeleteClusterError {
@@ -1404,6 +1497,21 @@ impl fmt::Display for DeleteClusterError {
     }
 }
 impl Error for DeleteClusterError {}
+impl ServiceErrorCode for DeleteClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteClusterError::ClusterNotFoundFault(_) => "ClusterNotFoundFault",
+            DeleteClusterError::InvalidClusterStateFault(_) => "InvalidClusterStateFault",
+            DeleteClusterError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            DeleteClusterError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteClusterError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteParameterGroupError {
@@ -1476,6 +1584,25 @@ impl fmt::Display for DeleteParameterGroupError {
     }
 }
 impl Error for DeleteParameterGroupError {}
+impl ServiceErrorCode for DeleteParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteParameterGroupError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            DeleteParameterGroupError::InvalidParameterGroupStateFault(_) => {
+                "InvalidParameterGroupStateFault"
+            }
+            DeleteParameterGroupError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteParameterGroupError::ParameterGroupNotFoundFault(_) => {
+                "ParameterGroupNotFoundFault"
+            }
+            DeleteParameterGroupError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteSubnetGroupError {
@@ -1526,6 +1653,17 @@ impl fmt::Display for DeleteSubnetGroupError {
     }
 }
 impl Error for DeleteSubnetGroupError {}
+impl ServiceErrorCode for DeleteSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSubnetGroupError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+            DeleteSubnetGroupError::SubnetGroupInUseFault(_) => "SubnetGroupInUseFault",
+            DeleteSubnetGroupError::SubnetGroupNotFoundFault(_) => "SubnetGroupNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeClusters
 #[derive(Debug, PartialEq)]
 pub enum DescribeClustersError {
@@ -1584,6 +1722,20 @@ impl fmt::Display for DescribeClustersError {
     }
 }
 impl Error for DescribeClustersError {}
+impl ServiceErrorCode for DescribeClustersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClustersError::ClusterNotFoundFault(_) => "ClusterNotFoundFault",
+            DescribeClustersError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            DescribeClustersError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeClustersError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDefaultParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeDefaultParametersError {
@@ -1638,6 +1790,21 @@ impl fmt::Display for DescribeDefaultParametersError {
     }
 }
 impl Error for DescribeDefaultParametersError {}
+impl ServiceErrorCode for DescribeDefaultParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDefaultParametersError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            DescribeDefaultParametersError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeDefaultParametersError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventsError {
@@ -1688,6 +1855,19 @@ impl fmt::Display for DescribeEventsError {
     }
 }
 impl Error for DescribeEventsError {}
+impl ServiceErrorCode for DescribeEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventsError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            DescribeEventsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeEventsError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeParameterGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeParameterGroupsError {
@@ -1752,6 +1932,24 @@ impl fmt::Display for DescribeParameterGroupsError {
     }
 }
 impl Error for DescribeParameterGroupsError {}
+impl ServiceErrorCode for DescribeParameterGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeParameterGroupsError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            DescribeParameterGroupsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeParameterGroupsError::ParameterGroupNotFoundFault(_) => {
+                "ParameterGroupNotFoundFault"
+            }
+            DescribeParameterGroupsError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeParametersError {
@@ -1814,6 +2012,22 @@ impl fmt::Display for DescribeParametersError {
     }
 }
 impl Error for DescribeParametersError {}
+impl ServiceErrorCode for DescribeParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeParametersError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            DescribeParametersError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeParametersError::ParameterGroupNotFoundFault(_) => {
+                "ParameterGroupNotFoundFault"
+            }
+            DescribeParametersError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeSubnetGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeSubnetGroupsError {
@@ -1858,6 +2072,16 @@ impl fmt::Display for DescribeSubnetGroupsError {
     }
 }
 impl Error for DescribeSubnetGroupsError {}
+impl ServiceErrorCode for DescribeSubnetGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSubnetGroupsError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+            DescribeSubnetGroupsError::SubnetGroupNotFoundFault(_) => "SubnetGroupNotFoundFault",
+        }
+    }
+}
 /// Errors returned by IncreaseReplicationFactor
 #[derive(Debug, PartialEq)]
 pub enum IncreaseReplicationFactorError {
@@ -1972,6 +2196,37 @@ impl fmt::Display for IncreaseReplicationFactorError {
     }
 }
 impl Error for IncreaseReplicationFactorError {}
+impl ServiceErrorCode for IncreaseReplicationFactorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            IncreaseReplicationFactorError::ClusterNotFoundFault(_) => "ClusterNotFoundFault",
+            IncreaseReplicationFactorError::InsufficientClusterCapacityFault(_) => {
+                "InsufficientClusterCapacityFault"
+            }
+            IncreaseReplicationFactorError::InvalidClusterStateFault(_) => {
+                "InvalidClusterStateFault"
+            }
+            IncreaseReplicationFactorError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            IncreaseReplicationFactorError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            IncreaseReplicationFactorError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            IncreaseReplicationFactorError::NodeQuotaForClusterExceededFault(_) => {
+                "NodeQuotaForClusterExceededFault"
+            }
+            IncreaseReplicationFactorError::NodeQuotaForCustomerExceededFault(_) => {
+                "NodeQuotaForCustomerExceededFault"
+            }
+            IncreaseReplicationFactorError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
 pub enum ListTagsError {
@@ -2036,6 +2291,18 @@ impl fmt::Display for ListTagsError {
     }
 }
 impl Error for ListTagsError {}
+impl ServiceErrorCode for ListTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsError::ClusterNotFoundFault(_) => "ClusterNotFoundFault",
+            ListTagsError::InvalidARNFault(_) => "InvalidARNFault",
+            ListTagsError::InvalidClusterStateFault(_) => "InvalidClusterStateFault",
+            ListTagsError::InvalidParameterCombination(_) => "InvalidParameterCombinationException",
+            ListTagsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListTagsError::ServiceLinkedRoleNotFoundFault(_) => "ServiceLinkedRoleNotFoundFault",
+        }
+    }
+}
 /// Errors returned by RebootNode
 #[derive(Debug, PartialEq)]
 pub enum RebootNodeError {
@@ -2100,6 +2367,20 @@ impl fmt::Display for RebootNodeError {
     }
 }
 impl Error for RebootNodeError {}
+impl ServiceErrorCode for RebootNodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RebootNodeError::ClusterNotFoundFault(_) => "ClusterNotFoundFault",
+            RebootNodeError::InvalidClusterStateFault(_) => "InvalidClusterStateFault",
+            RebootNodeError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            RebootNodeError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            RebootNodeError::NodeNotFoundFault(_) => "NodeNotFoundFault",
+            RebootNodeError::ServiceLinkedRoleNotFoundFault(_) => "ServiceLinkedRoleNotFoundFault",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2174,6 +2455,21 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::ClusterNotFoundFault(_) => "ClusterNotFoundFault",
+            TagResourceError::InvalidARNFault(_) => "InvalidARNFault",
+            TagResourceError::InvalidClusterStateFault(_) => "InvalidClusterStateFault",
+            TagResourceError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            TagResourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            TagResourceError::ServiceLinkedRoleNotFoundFault(_) => "ServiceLinkedRoleNotFoundFault",
+            TagResourceError::TagQuotaPerResourceExceeded(_) => "TagQuotaPerResourceExceeded",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2246,6 +2542,23 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ClusterNotFoundFault(_) => "ClusterNotFoundFault",
+            UntagResourceError::InvalidARNFault(_) => "InvalidARNFault",
+            UntagResourceError::InvalidClusterStateFault(_) => "InvalidClusterStateFault",
+            UntagResourceError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            UntagResourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UntagResourceError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+            UntagResourceError::TagNotFoundFault(_) => "TagNotFoundFault",
+        }
+    }
+}
 /// Errors returned by UpdateCluster
 #[derive(Debug, PartialEq)]
 pub enum UpdateClusterError {
@@ -2324,6 +2637,25 @@ impl fmt::Display for UpdateClusterError {
     }
 }
 impl Error for UpdateClusterError {}
+impl ServiceErrorCode for UpdateClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateClusterError::ClusterNotFoundFault(_) => "ClusterNotFoundFault",
+            UpdateClusterError::InvalidClusterStateFault(_) => "InvalidClusterStateFault",
+            UpdateClusterError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            UpdateClusterError::InvalidParameterGroupStateFault(_) => {
+                "InvalidParameterGroupStateFault"
+            }
+            UpdateClusterError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateClusterError::ParameterGroupNotFoundFault(_) => "ParameterGroupNotFoundFault",
+            UpdateClusterError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateParameterGroupError {
@@ -2396,6 +2728,25 @@ impl fmt::Display for UpdateParameterGroupError {
     }
 }
 impl Error for UpdateParameterGroupError {}
+impl ServiceErrorCode for UpdateParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateParameterGroupError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            UpdateParameterGroupError::InvalidParameterGroupStateFault(_) => {
+                "InvalidParameterGroupStateFault"
+            }
+            UpdateParameterGroupError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateParameterGroupError::ParameterGroupNotFoundFault(_) => {
+                "ParameterGroupNotFoundFault"
+            }
+            UpdateParameterGroupError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateSubnetGroupError {
@@ -2458,6 +2809,19 @@ impl fmt::Display for UpdateSubnetGroupError {
     }
 }
 impl Error for UpdateSubnetGroupError {}
+impl ServiceErrorCode for UpdateSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSubnetGroupError::InvalidSubnet(_) => "InvalidSubnet",
+            UpdateSubnetGroupError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+            UpdateSubnetGroupError::SubnetGroupNotFoundFault(_) => "SubnetGroupNotFoundFault",
+            UpdateSubnetGroupError::SubnetInUse(_) => "SubnetInUse",
+            UpdateSubnetGroupError::SubnetQuotaExceededFault(_) => "SubnetQuotaExceededFault",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon DAX API. Amazon DAX clients implement this trait.
 #[async_trait]
 pub trait DynamodbAccelerator {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -361,6 +361,15 @@ impl fmt::Display for AcceptInvitationError {
     }
 }
 impl Error for AcceptInvitationError {}
+impl ServiceErrorCode for AcceptInvitationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptInvitationError::Conflict(_) => "ConflictException",
+            AcceptInvitationError::InternalServer(_) => "InternalServerException",
+            AcceptInvitationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateGraph
 #[derive(Debug, PartialEq)]
 pub enum CreateGraphError {
@@ -403,6 +412,15 @@ impl fmt::Display for CreateGraphError {
     }
 }
 impl Error for CreateGraphError {}
+impl ServiceErrorCode for CreateGraphError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGraphError::Conflict(_) => "ConflictException",
+            CreateGraphError::InternalServer(_) => "InternalServerException",
+            CreateGraphError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+        }
+    }
+}
 /// Errors returned by CreateMembers
 #[derive(Debug, PartialEq)]
 pub enum CreateMembersError {
@@ -445,6 +463,15 @@ impl fmt::Display for CreateMembersError {
     }
 }
 impl Error for CreateMembersError {}
+impl ServiceErrorCode for CreateMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMembersError::InternalServer(_) => "InternalServerException",
+            CreateMembersError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateMembersError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+        }
+    }
+}
 /// Errors returned by DeleteGraph
 #[derive(Debug, PartialEq)]
 pub enum DeleteGraphError {
@@ -481,6 +508,14 @@ impl fmt::Display for DeleteGraphError {
     }
 }
 impl Error for DeleteGraphError {}
+impl ServiceErrorCode for DeleteGraphError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGraphError::InternalServer(_) => "InternalServerException",
+            DeleteGraphError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteMembers
 #[derive(Debug, PartialEq)]
 pub enum DeleteMembersError {
@@ -523,6 +558,15 @@ impl fmt::Display for DeleteMembersError {
     }
 }
 impl Error for DeleteMembersError {}
+impl ServiceErrorCode for DeleteMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMembersError::Conflict(_) => "ConflictException",
+            DeleteMembersError::InternalServer(_) => "InternalServerException",
+            DeleteMembersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateMembership
 #[derive(Debug, PartialEq)]
 pub enum DisassociateMembershipError {
@@ -569,6 +613,15 @@ impl fmt::Display for DisassociateMembershipError {
     }
 }
 impl Error for DisassociateMembershipError {}
+impl ServiceErrorCode for DisassociateMembershipError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateMembershipError::Conflict(_) => "ConflictException",
+            DisassociateMembershipError::InternalServer(_) => "InternalServerException",
+            DisassociateMembershipError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetMembers
 #[derive(Debug, PartialEq)]
 pub enum GetMembersError {
@@ -605,6 +658,14 @@ impl fmt::Display for GetMembersError {
     }
 }
 impl Error for GetMembersError {}
+impl ServiceErrorCode for GetMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMembersError::InternalServer(_) => "InternalServerException",
+            GetMembersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListGraphs
 #[derive(Debug, PartialEq)]
 pub enum ListGraphsError {
@@ -635,6 +696,13 @@ impl fmt::Display for ListGraphsError {
     }
 }
 impl Error for ListGraphsError {}
+impl ServiceErrorCode for ListGraphsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGraphsError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by ListInvitations
 #[derive(Debug, PartialEq)]
 pub enum ListInvitationsError {
@@ -665,6 +733,13 @@ impl fmt::Display for ListInvitationsError {
     }
 }
 impl Error for ListInvitationsError {}
+impl ServiceErrorCode for ListInvitationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInvitationsError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by ListMembers
 #[derive(Debug, PartialEq)]
 pub enum ListMembersError {
@@ -701,6 +776,14 @@ impl fmt::Display for ListMembersError {
     }
 }
 impl Error for ListMembersError {}
+impl ServiceErrorCode for ListMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMembersError::InternalServer(_) => "InternalServerException",
+            ListMembersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RejectInvitation
 #[derive(Debug, PartialEq)]
 pub enum RejectInvitationError {
@@ -743,6 +826,15 @@ impl fmt::Display for RejectInvitationError {
     }
 }
 impl Error for RejectInvitationError {}
+impl ServiceErrorCode for RejectInvitationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RejectInvitationError::Conflict(_) => "ConflictException",
+            RejectInvitationError::InternalServer(_) => "InternalServerException",
+            RejectInvitationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartMonitoringMember
 #[derive(Debug, PartialEq)]
 pub enum StartMonitoringMemberError {
@@ -797,6 +889,16 @@ impl fmt::Display for StartMonitoringMemberError {
     }
 }
 impl Error for StartMonitoringMemberError {}
+impl ServiceErrorCode for StartMonitoringMemberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartMonitoringMemberError::Conflict(_) => "ConflictException",
+            StartMonitoringMemberError::InternalServer(_) => "InternalServerException",
+            StartMonitoringMemberError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartMonitoringMemberError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Detective API. Amazon Detective clients implement this trait.
 #[async_trait]
 pub trait Detective {
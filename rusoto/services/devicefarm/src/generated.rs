@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3476,6 +3476,16 @@ impl fmt::Display for CreateDevicePoolError {
     }
 }
 impl Error for CreateDevicePoolError {}
+impl ServiceErrorCode for CreateDevicePoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDevicePoolError::Argument(_) => "ArgumentException",
+            CreateDevicePoolError::LimitExceeded(_) => "LimitExceededException",
+            CreateDevicePoolError::NotFound(_) => "NotFoundException",
+            CreateDevicePoolError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by CreateInstanceProfile
 #[derive(Debug, PartialEq)]
 pub enum CreateInstanceProfileError {
@@ -3526,6 +3536,16 @@ impl fmt::Display for CreateInstanceProfileError {
     }
 }
 impl Error for CreateInstanceProfileError {}
+impl ServiceErrorCode for CreateInstanceProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateInstanceProfileError::Argument(_) => "ArgumentException",
+            CreateInstanceProfileError::LimitExceeded(_) => "LimitExceededException",
+            CreateInstanceProfileError::NotFound(_) => "NotFoundException",
+            CreateInstanceProfileError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by CreateNetworkProfile
 #[derive(Debug, PartialEq)]
 pub enum CreateNetworkProfileError {
@@ -3574,6 +3594,16 @@ impl fmt::Display for CreateNetworkProfileError {
     }
 }
 impl Error for CreateNetworkProfileError {}
+impl ServiceErrorCode for CreateNetworkProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateNetworkProfileError::Argument(_) => "ArgumentException",
+            CreateNetworkProfileError::LimitExceeded(_) => "LimitExceededException",
+            CreateNetworkProfileError::NotFound(_) => "NotFoundException",
+            CreateNetworkProfileError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by CreateProject
 #[derive(Debug, PartialEq)]
 pub enum CreateProjectError {
@@ -3628,6 +3658,17 @@ impl fmt::Display for CreateProjectError {
     }
 }
 impl Error for CreateProjectError {}
+impl ServiceErrorCode for CreateProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProjectError::Argument(_) => "ArgumentException",
+            CreateProjectError::LimitExceeded(_) => "LimitExceededException",
+            CreateProjectError::NotFound(_) => "NotFoundException",
+            CreateProjectError::ServiceAccount(_) => "ServiceAccountException",
+            CreateProjectError::TagOperation(_) => "TagOperationException",
+        }
+    }
+}
 /// Errors returned by CreateRemoteAccessSession
 #[derive(Debug, PartialEq)]
 pub enum CreateRemoteAccessSessionError {
@@ -3680,6 +3721,16 @@ impl fmt::Display for CreateRemoteAccessSessionError {
     }
 }
 impl Error for CreateRemoteAccessSessionError {}
+impl ServiceErrorCode for CreateRemoteAccessSessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRemoteAccessSessionError::Argument(_) => "ArgumentException",
+            CreateRemoteAccessSessionError::LimitExceeded(_) => "LimitExceededException",
+            CreateRemoteAccessSessionError::NotFound(_) => "NotFoundException",
+            CreateRemoteAccessSessionError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by CreateTestGridProject
 #[derive(Debug, PartialEq)]
 pub enum CreateTestGridProjectError {
@@ -3712,6 +3763,13 @@ impl fmt::Display for CreateTestGridProjectError {
     }
 }
 impl Error for CreateTestGridProjectError {}
+impl ServiceErrorCode for CreateTestGridProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTestGridProjectError::InternalService(_) => "InternalServiceException",
+        }
+    }
+}
 /// Errors returned by CreateTestGridUrl
 #[derive(Debug, PartialEq)]
 pub enum CreateTestGridUrlError {
@@ -3754,6 +3812,15 @@ impl fmt::Display for CreateTestGridUrlError {
     }
 }
 impl Error for CreateTestGridUrlError {}
+impl ServiceErrorCode for CreateTestGridUrlError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTestGridUrlError::Argument(_) => "ArgumentException",
+            CreateTestGridUrlError::InternalService(_) => "InternalServiceException",
+            CreateTestGridUrlError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateUpload
 #[derive(Debug, PartialEq)]
 pub enum CreateUploadError {
@@ -3802,6 +3869,16 @@ impl fmt::Display for CreateUploadError {
     }
 }
 impl Error for CreateUploadError {}
+impl ServiceErrorCode for CreateUploadError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUploadError::Argument(_) => "ArgumentException",
+            CreateUploadError::LimitExceeded(_) => "LimitExceededException",
+            CreateUploadError::NotFound(_) => "NotFoundException",
+            CreateUploadError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by CreateVPCEConfiguration
 #[derive(Debug, PartialEq)]
 pub enum CreateVPCEConfigurationError {
@@ -3848,6 +3925,15 @@ impl fmt::Display for CreateVPCEConfigurationError {
     }
 }
 impl Error for CreateVPCEConfigurationError {}
+impl ServiceErrorCode for CreateVPCEConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVPCEConfigurationError::Argument(_) => "ArgumentException",
+            CreateVPCEConfigurationError::LimitExceeded(_) => "LimitExceededException",
+            CreateVPCEConfigurationError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by DeleteDevicePool
 #[derive(Debug, PartialEq)]
 pub enum DeleteDevicePoolError {
@@ -3896,6 +3982,16 @@ impl fmt::Display for DeleteDevicePoolError {
     }
 }
 impl Error for DeleteDevicePoolError {}
+impl ServiceErrorCode for DeleteDevicePoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDevicePoolError::Argument(_) => "ArgumentException",
+            DeleteDevicePoolError::LimitExceeded(_) => "LimitExceededException",
+            DeleteDevicePoolError::NotFound(_) => "NotFoundException",
+            DeleteDevicePoolError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by DeleteInstanceProfile
 #[derive(Debug, PartialEq)]
 pub enum DeleteInstanceProfileError {
@@ -3946,6 +4042,16 @@ impl fmt::Display for DeleteInstanceProfileError {
     }
 }
 impl Error for DeleteInstanceProfileError {}
+impl ServiceErrorCode for DeleteInstanceProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInstanceProfileError::Argument(_) => "ArgumentException",
+            DeleteInstanceProfileError::LimitExceeded(_) => "LimitExceededException",
+            DeleteInstanceProfileError::NotFound(_) => "NotFoundException",
+            DeleteInstanceProfileError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by DeleteNetworkProfile
 #[derive(Debug, PartialEq)]
 pub enum DeleteNetworkProfileError {
@@ -3994,6 +4100,16 @@ impl fmt::Display for DeleteNetworkProfileError {
     }
 }
 impl Error for DeleteNetworkProfileError {}
+impl ServiceErrorCode for DeleteNetworkProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteNetworkProfileError::Argument(_) => "ArgumentException",
+            DeleteNetworkProfileError::LimitExceeded(_) => "LimitExceededException",
+            DeleteNetworkProfileError::NotFound(_) => "NotFoundException",
+            DeleteNetworkProfileError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by DeleteProject
 #[derive(Debug, PartialEq)]
 pub enum DeleteProjectError {
@@ -4042,6 +4158,16 @@ impl fmt::Display for DeleteProjectError {
     }
 }
 impl Error for DeleteProjectError {}
+impl ServiceErrorCode for DeleteProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProjectError::Argument(_) => "ArgumentException",
+            DeleteProjectError::LimitExceeded(_) => "LimitExceededException",
+            DeleteProjectError::NotFound(_) => "NotFoundException",
+            DeleteProjectError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by DeleteRemoteAccessSession
 #[derive(Debug, PartialEq)]
 pub enum DeleteRemoteAccessSessionError {
@@ -4094,6 +4220,16 @@ impl fmt::Display for DeleteRemoteAccessSessionError {
     }
 }
 impl Error for DeleteRemoteAccessSessionError {}
+impl ServiceErrorCode for DeleteRemoteAccessSessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRemoteAccessSessionError::Argument(_) => "ArgumentException",
+            DeleteRemoteAccessSessionError::LimitExceeded(_) => "LimitExceededException",
+            DeleteRemoteAccessSessionError::NotFound(_) => "NotFoundException",
+            DeleteRemoteAccessSessionError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by DeleteRun
 #[derive(Debug, PartialEq)]
 pub enum DeleteRunError {
@@ -4142,6 +4278,16 @@ impl fmt::Display for DeleteRunError {
     }
 }
 impl Error for DeleteRunError {}
+impl ServiceErrorCode for DeleteRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRunError::Argument(_) => "ArgumentException",
+            DeleteRunError::LimitExceeded(_) => "LimitExceededException",
+            DeleteRunError::NotFound(_) => "NotFoundException",
+            DeleteRunError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by DeleteTestGridProject
 #[derive(Debug, PartialEq)]
 pub enum DeleteTestGridProjectError {
@@ -4192,6 +4338,16 @@ impl fmt::Display for DeleteTestGridProjectError {
     }
 }
 impl Error for DeleteTestGridProjectError {}
+impl ServiceErrorCode for DeleteTestGridProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTestGridProjectError::Argument(_) => "ArgumentException",
+            DeleteTestGridProjectError::CannotDelete(_) => "CannotDeleteException",
+            DeleteTestGridProjectError::InternalService(_) => "InternalServiceException",
+            DeleteTestGridProjectError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteUpload
 #[derive(Debug, PartialEq)]
 pub enum DeleteUploadError {
@@ -4240,6 +4396,16 @@ impl fmt::Display for DeleteUploadError {
     }
 }
 impl Error for DeleteUploadError {}
+impl ServiceErrorCode for DeleteUploadError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUploadError::Argument(_) => "ArgumentException",
+            DeleteUploadError::LimitExceeded(_) => "LimitExceededException",
+            DeleteUploadError::NotFound(_) => "NotFoundException",
+            DeleteUploadError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by DeleteVPCEConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteVPCEConfigurationError {
@@ -4292,6 +4458,16 @@ impl fmt::Display for DeleteVPCEConfigurationError {
     }
 }
 impl Error for DeleteVPCEConfigurationError {}
+impl ServiceErrorCode for DeleteVPCEConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVPCEConfigurationError::Argument(_) => "ArgumentException",
+            DeleteVPCEConfigurationError::InvalidOperation(_) => "InvalidOperationException",
+            DeleteVPCEConfigurationError::NotFound(_) => "NotFoundException",
+            DeleteVPCEConfigurationError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetAccountSettings
 #[derive(Debug, PartialEq)]
 pub enum GetAccountSettingsError {
@@ -4340,6 +4516,16 @@ impl fmt::Display for GetAccountSettingsError {
     }
 }
 impl Error for GetAccountSettingsError {}
+impl ServiceErrorCode for GetAccountSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAccountSettingsError::Argument(_) => "ArgumentException",
+            GetAccountSettingsError::LimitExceeded(_) => "LimitExceededException",
+            GetAccountSettingsError::NotFound(_) => "NotFoundException",
+            GetAccountSettingsError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetDevice
 #[derive(Debug, PartialEq)]
 pub enum GetDeviceError {
@@ -4388,6 +4574,16 @@ impl fmt::Display for GetDeviceError {
     }
 }
 impl Error for GetDeviceError {}
+impl ServiceErrorCode for GetDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeviceError::Argument(_) => "ArgumentException",
+            GetDeviceError::LimitExceeded(_) => "LimitExceededException",
+            GetDeviceError::NotFound(_) => "NotFoundException",
+            GetDeviceError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetDeviceInstance
 #[derive(Debug, PartialEq)]
 pub enum GetDeviceInstanceError {
@@ -4436,6 +4632,16 @@ impl fmt::Display for GetDeviceInstanceError {
     }
 }
 impl Error for GetDeviceInstanceError {}
+impl ServiceErrorCode for GetDeviceInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeviceInstanceError::Argument(_) => "ArgumentException",
+            GetDeviceInstanceError::LimitExceeded(_) => "LimitExceededException",
+            GetDeviceInstanceError::NotFound(_) => "NotFoundException",
+            GetDeviceInstanceError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetDevicePool
 #[derive(Debug, PartialEq)]
 pub enum GetDevicePoolError {
@@ -4484,6 +4690,16 @@ impl fmt::Display for GetDevicePoolError {
     }
 }
 impl Error for GetDevicePoolError {}
+impl ServiceErrorCode for GetDevicePoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDevicePoolError::Argument(_) => "ArgumentException",
+            GetDevicePoolError::LimitExceeded(_) => "LimitExceededException",
+            GetDevicePoolError::NotFound(_) => "NotFoundException",
+            GetDevicePoolError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetDevicePoolCompatibility
 #[derive(Debug, PartialEq)]
 pub enum GetDevicePoolCompatibilityError {
@@ -4538,6 +4754,16 @@ impl fmt::Display for GetDevicePoolCompatibilityError {
     }
 }
 impl Error for GetDevicePoolCompatibilityError {}
+impl ServiceErrorCode for GetDevicePoolCompatibilityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDevicePoolCompatibilityError::Argument(_) => "ArgumentException",
+            GetDevicePoolCompatibilityError::LimitExceeded(_) => "LimitExceededException",
+            GetDevicePoolCompatibilityError::NotFound(_) => "NotFoundException",
+            GetDevicePoolCompatibilityError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetInstanceProfile
 #[derive(Debug, PartialEq)]
 pub enum GetInstanceProfileError {
@@ -4586,6 +4812,16 @@ impl fmt::Display for GetInstanceProfileError {
     }
 }
 impl Error for GetInstanceProfileError {}
+impl ServiceErrorCode for GetInstanceProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInstanceProfileError::Argument(_) => "ArgumentException",
+            GetInstanceProfileError::LimitExceeded(_) => "LimitExceededException",
+            GetInstanceProfileError::NotFound(_) => "NotFoundException",
+            GetInstanceProfileError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetJob
 #[derive(Debug, PartialEq)]
 pub enum GetJobError {
@@ -4630,6 +4866,16 @@ impl fmt::Display for GetJobError {
     }
 }
 impl Error for GetJobError {}
+impl ServiceErrorCode for GetJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetJobError::Argument(_) => "ArgumentException",
+            GetJobError::LimitExceeded(_) => "LimitExceededException",
+            GetJobError::NotFound(_) => "NotFoundException",
+            GetJobError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetNetworkProfile
 #[derive(Debug, PartialEq)]
 pub enum GetNetworkProfileError {
@@ -4678,6 +4924,16 @@ impl fmt::Display for GetNetworkProfileError {
     }
 }
 impl Error for GetNetworkProfileError {}
+impl ServiceErrorCode for GetNetworkProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetNetworkProfileError::Argument(_) => "ArgumentException",
+            GetNetworkProfileError::LimitExceeded(_) => "LimitExceededException",
+            GetNetworkProfileError::NotFound(_) => "NotFoundException",
+            GetNetworkProfileError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetOfferingStatus
 #[derive(Debug, PartialEq)]
 pub enum GetOfferingStatusError {
@@ -4732,6 +4988,17 @@ impl fmt::Display for GetOfferingStatusError {
     }
 }
 impl Error for GetOfferingStatusError {}
+impl ServiceErrorCode for GetOfferingStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOfferingStatusError::Argument(_) => "ArgumentException",
+            GetOfferingStatusError::LimitExceeded(_) => "LimitExceededException",
+            GetOfferingStatusError::NotEligible(_) => "NotEligibleException",
+            GetOfferingStatusError::NotFound(_) => "NotFoundException",
+            GetOfferingStatusError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetProject
 #[derive(Debug, PartialEq)]
 pub enum GetProjectError {
@@ -4780,6 +5047,16 @@ impl fmt::Display for GetProjectError {
     }
 }
 impl Error for GetProjectError {}
+impl ServiceErrorCode for GetProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetProjectError::Argument(_) => "ArgumentException",
+            GetProjectError::LimitExceeded(_) => "LimitExceededException",
+            GetProjectError::NotFound(_) => "NotFoundException",
+            GetProjectError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetRemoteAccessSession
 #[derive(Debug, PartialEq)]
 pub enum GetRemoteAccessSessionError {
@@ -4832,6 +5109,16 @@ impl fmt::Display for GetRemoteAccessSessionError {
     }
 }
 impl Error for GetRemoteAccessSessionError {}
+impl ServiceErrorCode for GetRemoteAccessSessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRemoteAccessSessionError::Argument(_) => "ArgumentException",
+            GetRemoteAccessSessionError::LimitExceeded(_) => "LimitExceededException",
+            GetRemoteAccessSessionError::NotFound(_) => "NotFoundException",
+            GetRemoteAccessSessionError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetRun
 #[derive(Debug, PartialEq)]
 pub enum GetRunError {
@@ -4876,6 +5163,16 @@ impl fmt::Display for GetRunError {
     }
 }
 impl Error for GetRunError {}
+impl ServiceErrorCode for GetRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRunError::Argument(_) => "ArgumentException",
+            GetRunError::LimitExceeded(_) => "LimitExceededException",
+            GetRunError::NotFound(_) => "NotFoundException",
+            GetRunError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetSuite
 #[derive(Debug, PartialEq)]
 pub enum GetSuiteError {
@@ -4924,6 +5221,16 @@ impl fmt::Display for GetSuiteError {
     }
 }
 impl Error for GetSuiteError {}
+impl ServiceErrorCode for GetSuiteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSuiteError::Argument(_) => "ArgumentException",
+            GetSuiteError::LimitExceeded(_) => "LimitExceededException",
+            GetSuiteError::NotFound(_) => "NotFoundException",
+            GetSuiteError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetTest
 #[derive(Debug, PartialEq)]
 pub enum GetTestError {
@@ -4972,6 +5279,16 @@ impl fmt::Display for GetTestError {
     }
 }
 impl Error for GetTestError {}
+impl ServiceErrorCode for GetTestError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTestError::Argument(_) => "ArgumentException",
+            GetTestError::LimitExceeded(_) => "LimitExceededException",
+            GetTestError::NotFound(_) => "NotFoundException",
+            GetTestError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetTestGridProject
 #[derive(Debug, PartialEq)]
 pub enum GetTestGridProjectError {
@@ -5014,6 +5331,15 @@ impl fmt::Display for GetTestGridProjectError {
     }
 }
 impl Error for GetTestGridProjectError {}
+impl ServiceErrorCode for GetTestGridProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTestGridProjectError::Argument(_) => "ArgumentException",
+            GetTestGridProjectError::InternalService(_) => "InternalServiceException",
+            GetTestGridProjectError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetTestGridSession
 #[derive(Debug, PartialEq)]
 pub enum GetTestGridSessionError {
@@ -5056,6 +5382,15 @@ impl fmt::Display for GetTestGridSessionError {
     }
 }
 impl Error for GetTestGridSessionError {}
+impl ServiceErrorCode for GetTestGridSessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTestGridSessionError::Argument(_) => "ArgumentException",
+            GetTestGridSessionError::InternalService(_) => "InternalServiceException",
+            GetTestGridSessionError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetUpload
 #[derive(Debug, PartialEq)]
 pub enum GetUploadError {
@@ -5104,6 +5439,16 @@ impl fmt::Display for GetUploadError {
     }
 }
 impl Error for GetUploadError {}
+impl ServiceErrorCode for GetUploadError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUploadError::Argument(_) => "ArgumentException",
+            GetUploadError::LimitExceeded(_) => "LimitExceededException",
+            GetUploadError::NotFound(_) => "NotFoundException",
+            GetUploadError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by GetVPCEConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetVPCEConfigurationError {
@@ -5146,6 +5491,15 @@ impl fmt::Display for GetVPCEConfigurationError {
     }
 }
 impl Error for GetVPCEConfigurationError {}
+impl ServiceErrorCode for GetVPCEConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVPCEConfigurationError::Argument(_) => "ArgumentException",
+            GetVPCEConfigurationError::NotFound(_) => "NotFoundException",
+            GetVPCEConfigurationError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by InstallToRemoteAccessSession
 #[derive(Debug, PartialEq)]
 pub enum InstallToRemoteAccessSessionError {
@@ -5204,6 +5558,16 @@ impl fmt::Display for InstallToRemoteAccessSessionError {
     }
 }
 impl Error for InstallToRemoteAccessSessionError {}
+impl ServiceErrorCode for InstallToRemoteAccessSessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InstallToRemoteAccessSessionError::Argument(_) => "ArgumentException",
+            InstallToRemoteAccessSessionError::LimitExceeded(_) => "LimitExceededException",
+            InstallToRemoteAccessSessionError::NotFound(_) => "NotFoundException",
+            InstallToRemoteAccessSessionError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListArtifacts
 #[derive(Debug, PartialEq)]
 pub enum ListArtifactsError {
@@ -5252,6 +5616,16 @@ impl fmt::Display for ListArtifactsError {
     }
 }
 impl Error for ListArtifactsError {}
+impl ServiceErrorCode for ListArtifactsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListArtifactsError::Argument(_) => "ArgumentException",
+            ListArtifactsError::LimitExceeded(_) => "LimitExceededException",
+            ListArtifactsError::NotFound(_) => "NotFoundException",
+            ListArtifactsError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListDeviceInstances
 #[derive(Debug, PartialEq)]
 pub enum ListDeviceInstancesError {
@@ -5300,6 +5674,16 @@ impl fmt::Display for ListDeviceInstancesError {
     }
 }
 impl Error for ListDeviceInstancesError {}
+impl ServiceErrorCode for ListDeviceInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeviceInstancesError::Argument(_) => "ArgumentException",
+            ListDeviceInstancesError::LimitExceeded(_) => "LimitExceededException",
+            ListDeviceInstancesError::NotFound(_) => "NotFoundException",
+            ListDeviceInstancesError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListDevicePools
 #[derive(Debug, PartialEq)]
 pub enum ListDevicePoolsError {
@@ -5348,6 +5732,16 @@ impl fmt::Display for ListDevicePoolsError {
     }
 }
 impl Error for ListDevicePoolsError {}
+impl ServiceErrorCode for ListDevicePoolsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDevicePoolsError::Argument(_) => "ArgumentException",
+            ListDevicePoolsError::LimitExceeded(_) => "LimitExceededException",
+            ListDevicePoolsError::NotFound(_) => "NotFoundException",
+            ListDevicePoolsError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListDevices
 #[derive(Debug, PartialEq)]
 pub enum ListDevicesError {
@@ -5396,6 +5790,16 @@ impl fmt::Display for ListDevicesError {
     }
 }
 impl Error for ListDevicesError {}
+impl ServiceErrorCode for ListDevicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDevicesError::Argument(_) => "ArgumentException",
+            ListDevicesError::LimitExceeded(_) => "LimitExceededException",
+            ListDevicesError::NotFound(_) => "NotFoundException",
+            ListDevicesError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListInstanceProfiles
 #[derive(Debug, PartialEq)]
 pub enum ListInstanceProfilesError {
@@ -5444,6 +5848,16 @@ impl fmt::Display for ListInstanceProfilesError {
     }
 }
 impl Error for ListInstanceProfilesError {}
+impl ServiceErrorCode for ListInstanceProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInstanceProfilesError::Argument(_) => "ArgumentException",
+            ListInstanceProfilesError::LimitExceeded(_) => "LimitExceededException",
+            ListInstanceProfilesError::NotFound(_) => "NotFoundException",
+            ListInstanceProfilesError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
 pub enum ListJobsError {
@@ -5492,6 +5906,16 @@ impl fmt::Display for ListJobsError {
     }
 }
 impl Error for ListJobsError {}
+impl ServiceErrorCode for ListJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJobsError::Argument(_) => "ArgumentException",
+            ListJobsError::LimitExceeded(_) => "LimitExceededException",
+            ListJobsError::NotFound(_) => "NotFoundException",
+            ListJobsError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListNetworkProfiles
 #[derive(Debug, PartialEq)]
 pub enum ListNetworkProfilesError {
@@ -5540,6 +5964,16 @@ impl fmt::Display for ListNetworkProfilesError {
     }
 }
 impl Error for ListNetworkProfilesError {}
+impl ServiceErrorCode for ListNetworkProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListNetworkProfilesError::Argument(_) => "ArgumentException",
+            ListNetworkProfilesError::LimitExceeded(_) => "LimitExceededException",
+            ListNetworkProfilesError::NotFound(_) => "NotFoundException",
+            ListNetworkProfilesError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListOfferingPromotions
 #[derive(Debug, PartialEq)]
 pub enum ListOfferingPromotionsError {
@@ -5598,6 +6032,17 @@ impl fmt::Display for ListOfferingPromotionsError {
     }
 }
 impl Error for ListOfferingPromotionsError {}
+impl ServiceErrorCode for ListOfferingPromotionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOfferingPromotionsError::Argument(_) => "ArgumentException",
+            ListOfferingPromotionsError::LimitExceeded(_) => "LimitExceededException",
+            ListOfferingPromotionsError::NotEligible(_) => "NotEligibleException",
+            ListOfferingPromotionsError::NotFound(_) => "NotFoundException",
+            ListOfferingPromotionsError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListOfferingTransactions
 #[derive(Debug, PartialEq)]
 pub enum ListOfferingTransactionsError {
@@ -5658,6 +6103,17 @@ impl fmt::Display for ListOfferingTransactionsError {
     }
 }
 impl Error for ListOfferingTransactionsError {}
+impl ServiceErrorCode for ListOfferingTransactionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOfferingTransactionsError::Argument(_) => "ArgumentException",
+            ListOfferingTransactionsError::LimitExceeded(_) => "LimitExceededException",
+            ListOfferingTransactionsError::NotEligible(_) => "NotEligibleException",
+            ListOfferingTransactionsError::NotFound(_) => "NotFoundException",
+            ListOfferingTransactionsError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListOfferings
 #[derive(Debug, PartialEq)]
 pub enum ListOfferingsError {
@@ -5712,6 +6168,17 @@ impl fmt::Display for ListOfferingsError {
     }
 }
 impl Error for ListOfferingsError {}
+impl ServiceErrorCode for ListOfferingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOfferingsError::Argument(_) => "ArgumentException",
+            ListOfferingsError::LimitExceeded(_) => "LimitExceededException",
+            ListOfferingsError::NotEligible(_) => "NotEligibleException",
+            ListOfferingsError::NotFound(_) => "NotFoundException",
+            ListOfferingsError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListProjects
 #[derive(Debug, PartialEq)]
 pub enum ListProjectsError {
@@ -5760,6 +6227,16 @@ impl fmt::Display for ListProjectsError {
     }
 }
 impl Error for ListProjectsError {}
+impl ServiceErrorCode for ListProjectsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProjectsError::Argument(_) => "ArgumentException",
+            ListProjectsError::LimitExceeded(_) => "LimitExceededException",
+            ListProjectsError::NotFound(_) => "NotFoundException",
+            ListProjectsError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListRemoteAccessSessions
 #[derive(Debug, PartialEq)]
 pub enum ListRemoteAccessSessionsError {
@@ -5812,6 +6289,16 @@ impl fmt::Display for ListRemoteAccessSessionsError {
     }
 }
 impl Error for ListRemoteAccessSessionsError {}
+impl ServiceErrorCode for ListRemoteAccessSessionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRemoteAccessSessionsError::Argument(_) => "ArgumentException",
+            ListRemoteAccessSessionsError::LimitExceeded(_) => "LimitExceededException",
+            ListRemoteAccessSessionsError::NotFound(_) => "NotFoundException",
+            ListRemoteAccessSessionsError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListRuns
 #[derive(Debug, PartialEq)]
 pub enum ListRunsError {
@@ -5860,6 +6347,16 @@ impl fmt::Display for ListRunsError {
     }
 }
 impl Error for ListRunsError {}
+impl ServiceErrorCode for ListRunsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRunsError::Argument(_) => "ArgumentException",
+            ListRunsError::LimitExceeded(_) => "LimitExceededException",
+            ListRunsError::NotFound(_) => "NotFoundException",
+            ListRunsError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListSamples
 #[derive(Debug, PartialEq)]
 pub enum ListSamplesError {
@@ -5908,6 +6405,16 @@ impl fmt::Display for ListSamplesError {
     }
 }
 impl Error for ListSamplesError {}
+impl ServiceErrorCode for ListSamplesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSamplesError::Argument(_) => "ArgumentException",
+            ListSamplesError::LimitExceeded(_) => "LimitExceededException",
+            ListSamplesError::NotFound(_) => "NotFoundException",
+            ListSamplesError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListSuites
 #[derive(Debug, PartialEq)]
 pub enum ListSuitesError {
@@ -5956,6 +6463,16 @@ impl fmt::Display for ListSuitesError {
     }
 }
 impl Error for ListSuitesError {}
+impl ServiceErrorCode for ListSuitesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSuitesError::Argument(_) => "ArgumentException",
+            ListSuitesError::LimitExceeded(_) => "LimitExceededException",
+            ListSuitesError::NotFound(_) => "NotFoundException",
+            ListSuitesError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -5998,6 +6515,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::Argument(_) => "ArgumentException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+            ListTagsForResourceError::TagOperation(_) => "TagOperationException",
+        }
+    }
+}
 /// Errors returned by ListTestGridProjects
 #[derive(Debug, PartialEq)]
 pub enum ListTestGridProjectsError {
@@ -6036,6 +6562,14 @@ impl fmt::Display for ListTestGridProjectsError {
     }
 }
 impl Error for ListTestGridProjectsError {}
+impl ServiceErrorCode for ListTestGridProjectsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTestGridProjectsError::Argument(_) => "ArgumentException",
+            ListTestGridProjectsError::InternalService(_) => "InternalServiceException",
+        }
+    }
+}
 /// Errors returned by ListTestGridSessionActions
 #[derive(Debug, PartialEq)]
 pub enum ListTestGridSessionActionsError {
@@ -6082,6 +6616,15 @@ impl fmt::Display for ListTestGridSessionActionsError {
     }
 }
 impl Error for ListTestGridSessionActionsError {}
+impl ServiceErrorCode for ListTestGridSessionActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTestGridSessionActionsError::Argument(_) => "ArgumentException",
+            ListTestGridSessionActionsError::InternalService(_) => "InternalServiceException",
+            ListTestGridSessionActionsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTestGridSessionArtifacts
 #[derive(Debug, PartialEq)]
 pub enum ListTestGridSessionArtifactsError {
@@ -6132,6 +6675,15 @@ impl fmt::Display for ListTestGridSessionArtifactsError {
     }
 }
 impl Error for ListTestGridSessionArtifactsError {}
+impl ServiceErrorCode for ListTestGridSessionArtifactsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTestGridSessionArtifactsError::Argument(_) => "ArgumentException",
+            ListTestGridSessionArtifactsError::InternalService(_) => "InternalServiceException",
+            ListTestGridSessionArtifactsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTestGridSessions
 #[derive(Debug, PartialEq)]
 pub enum ListTestGridSessionsError {
@@ -6176,6 +6728,15 @@ impl fmt::Display for ListTestGridSessionsError {
     }
 }
 impl Error for ListTestGridSessionsError {}
+impl ServiceErrorCode for ListTestGridSessionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTestGridSessionsError::Argument(_) => "ArgumentException",
+            ListTestGridSessionsError::InternalService(_) => "InternalServiceException",
+            ListTestGridSessionsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTests
 #[derive(Debug, PartialEq)]
 pub enum ListTestsError {
@@ -6224,6 +6785,16 @@ impl fmt::Display for ListTestsError {
     }
 }
 impl Error for ListTestsError {}
+impl ServiceErrorCode for ListTestsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTestsError::Argument(_) => "ArgumentException",
+            ListTestsError::LimitExceeded(_) => "LimitExceededException",
+            ListTestsError::NotFound(_) => "NotFoundException",
+            ListTestsError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListUniqueProblems
 #[derive(Debug, PartialEq)]
 pub enum ListUniqueProblemsError {
@@ -6272,6 +6843,16 @@ impl fmt::Display for ListUniqueProblemsError {
     }
 }
 impl Error for ListUniqueProblemsError {}
+impl ServiceErrorCode for ListUniqueProblemsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUniqueProblemsError::Argument(_) => "ArgumentException",
+            ListUniqueProblemsError::LimitExceeded(_) => "LimitExceededException",
+            ListUniqueProblemsError::NotFound(_) => "NotFoundException",
+            ListUniqueProblemsError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListUploads
 #[derive(Debug, PartialEq)]
 pub enum ListUploadsError {
@@ -6320,6 +6901,16 @@ impl fmt::Display for ListUploadsError {
     }
 }
 impl Error for ListUploadsError {}
+impl ServiceErrorCode for ListUploadsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUploadsError::Argument(_) => "ArgumentException",
+            ListUploadsError::LimitExceeded(_) => "LimitExceededException",
+            ListUploadsError::NotFound(_) => "NotFoundException",
+            ListUploadsError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ListVPCEConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListVPCEConfigurationsError {
@@ -6358,6 +6949,14 @@ impl fmt::Display for ListVPCEConfigurationsError {
     }
 }
 impl Error for ListVPCEConfigurationsError {}
+impl ServiceErrorCode for ListVPCEConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVPCEConfigurationsError::Argument(_) => "ArgumentException",
+            ListVPCEConfigurationsError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by PurchaseOffering
 #[derive(Debug, PartialEq)]
 pub enum PurchaseOfferingError {
@@ -6412,6 +7011,17 @@ impl fmt::Display for PurchaseOfferingError {
     }
 }
 impl Error for PurchaseOfferingError {}
+impl ServiceErrorCode for PurchaseOfferingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PurchaseOfferingError::Argument(_) => "ArgumentException",
+            PurchaseOfferingError::LimitExceeded(_) => "LimitExceededException",
+            PurchaseOfferingError::NotEligible(_) => "NotEligibleException",
+            PurchaseOfferingError::NotFound(_) => "NotFoundException",
+            PurchaseOfferingError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by RenewOffering
 #[derive(Debug, PartialEq)]
 pub enum RenewOfferingError {
@@ -6466,6 +7076,17 @@ impl fmt::Display for RenewOfferingError {
     }
 }
 impl Error for RenewOfferingError {}
+impl ServiceErrorCode for RenewOfferingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RenewOfferingError::Argument(_) => "ArgumentException",
+            RenewOfferingError::LimitExceeded(_) => "LimitExceededException",
+            RenewOfferingError::NotEligible(_) => "NotEligibleException",
+            RenewOfferingError::NotFound(_) => "NotFoundException",
+            RenewOfferingError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by ScheduleRun
 #[derive(Debug, PartialEq)]
 pub enum ScheduleRunError {
@@ -6520,6 +7141,17 @@ impl fmt::Display for ScheduleRunError {
     }
 }
 impl Error for ScheduleRunError {}
+impl ServiceErrorCode for ScheduleRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ScheduleRunError::Argument(_) => "ArgumentException",
+            ScheduleRunError::Idempotency(_) => "IdempotencyException",
+            ScheduleRunError::LimitExceeded(_) => "LimitExceededException",
+            ScheduleRunError::NotFound(_) => "NotFoundException",
+            ScheduleRunError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by StopJob
 #[derive(Debug, PartialEq)]
 pub enum StopJobError {
@@ -6568,6 +7200,16 @@ impl fmt::Display for StopJobError {
     }
 }
 impl Error for StopJobError {}
+impl ServiceErrorCode for StopJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopJobError::Argument(_) => "ArgumentException",
+            StopJobError::LimitExceeded(_) => "LimitExceededException",
+            StopJobError::NotFound(_) => "NotFoundException",
+            StopJobError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by StopRemoteAccessSession
 #[derive(Debug, PartialEq)]
 pub enum StopRemoteAccessSessionError {
@@ -6620,6 +7262,16 @@ impl fmt::Display for StopRemoteAccessSessionError {
     }
 }
 impl Error for StopRemoteAccessSessionError {}
+impl ServiceErrorCode for StopRemoteAccessSessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopRemoteAccessSessionError::Argument(_) => "ArgumentException",
+            StopRemoteAccessSessionError::LimitExceeded(_) => "LimitExceededException",
+            StopRemoteAccessSessionError::NotFound(_) => "NotFoundException",
+            StopRemoteAccessSessionError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by StopRun
 #[derive(Debug, PartialEq)]
 pub enum StopRunError {
@@ -6668,6 +7320,16 @@ impl fmt::Display for StopRunError {
     }
 }
 impl Error for StopRunError {}
+impl ServiceErrorCode for StopRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopRunError::Argument(_) => "ArgumentException",
+            StopRunError::LimitExceeded(_) => "LimitExceededException",
+            StopRunError::NotFound(_) => "NotFoundException",
+            StopRunError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -6722,6 +7384,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::Argument(_) => "ArgumentException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+            TagResourceError::TagOperation(_) => "TagOperationException",
+            TagResourceError::TagPolicy(_) => "TagPolicyException",
+            TagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -6764,6 +7437,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::Argument(_) => "ArgumentException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+            UntagResourceError::TagOperation(_) => "TagOperationException",
+        }
+    }
+}
 /// Errors returned by UpdateDeviceInstance
 #[derive(Debug, PartialEq)]
 pub enum UpdateDeviceInstanceError {
@@ -6812,6 +7494,16 @@ impl fmt::Display for UpdateDeviceInstanceError {
     }
 }
 impl Error for UpdateDeviceInstanceError {}
+impl ServiceErrorCode for UpdateDeviceInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDeviceInstanceError::Argument(_) => "ArgumentException",
+            UpdateDeviceInstanceError::LimitExceeded(_) => "LimitExceededException",
+            UpdateDeviceInstanceError::NotFound(_) => "NotFoundException",
+            UpdateDeviceInstanceError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by UpdateDevicePool
 #[derive(Debug, PartialEq)]
 pub enum UpdateDevicePoolError {
@@ -6860,6 +7552,16 @@ impl fmt::Display for UpdateDevicePoolError {
     }
 }
 impl Error for UpdateDevicePoolError {}
+impl ServiceErrorCode for UpdateDevicePoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDevicePoolError::Argument(_) => "ArgumentException",
+            UpdateDevicePoolError::LimitExceeded(_) => "LimitExceededException",
+            UpdateDevicePoolError::NotFound(_) => "NotFoundException",
+            UpdateDevicePoolError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by UpdateInstanceProfile
 #[derive(Debug, PartialEq)]
 pub enum UpdateInstanceProfileError {
@@ -6910,6 +7612,16 @@ impl fmt::Display for UpdateInstanceProfileError {
     }
 }
 impl Error for UpdateInstanceProfileError {}
+impl ServiceErrorCode for UpdateInstanceProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateInstanceProfileError::Argument(_) => "ArgumentException",
+            UpdateInstanceProfileError::LimitExceeded(_) => "LimitExceededException",
+            UpdateInstanceProfileError::NotFound(_) => "NotFoundException",
+            UpdateInstanceProfileError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by UpdateNetworkProfile
 #[derive(Debug, PartialEq)]
 pub enum UpdateNetworkProfileError {
@@ -6958,6 +7670,16 @@ impl fmt::Display for UpdateNetworkProfileError {
     }
 }
 impl Error for UpdateNetworkProfileError {}
+impl ServiceErrorCode for UpdateNetworkProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateNetworkProfileError::Argument(_) => "ArgumentException",
+            UpdateNetworkProfileError::LimitExceeded(_) => "LimitExceededException",
+            UpdateNetworkProfileError::NotFound(_) => "NotFoundException",
+            UpdateNetworkProfileError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by UpdateProject
 #[derive(Debug, PartialEq)]
 pub enum UpdateProjectError {
@@ -7006,6 +7728,16 @@ impl fmt::Display for UpdateProjectError {
     }
 }
 impl Error for UpdateProjectError {}
+impl ServiceErrorCode for UpdateProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateProjectError::Argument(_) => "ArgumentException",
+            UpdateProjectError::LimitExceeded(_) => "LimitExceededException",
+            UpdateProjectError::NotFound(_) => "NotFoundException",
+            UpdateProjectError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by UpdateTestGridProject
 #[derive(Debug, PartialEq)]
 pub enum UpdateTestGridProjectError {
@@ -7050,6 +7782,15 @@ impl fmt::Display for UpdateTestGridProjectError {
     }
 }
 impl Error for UpdateTestGridProjectError {}
+impl ServiceErrorCode for UpdateTestGridProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTestGridProjectError::Argument(_) => "ArgumentException",
+            UpdateTestGridProjectError::InternalService(_) => "InternalServiceException",
+            UpdateTestGridProjectError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateUpload
 #[derive(Debug, PartialEq)]
 pub enum UpdateUploadError {
@@ -7098,6 +7839,16 @@ impl fmt::Display for UpdateUploadError {
     }
 }
 impl Error for UpdateUploadError {}
+impl ServiceErrorCode for UpdateUploadError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUploadError::Argument(_) => "ArgumentException",
+            UpdateUploadError::LimitExceeded(_) => "LimitExceededException",
+            UpdateUploadError::NotFound(_) => "NotFoundException",
+            UpdateUploadError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Errors returned by UpdateVPCEConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateVPCEConfigurationError {
@@ -7150,6 +7901,16 @@ impl fmt::Display for UpdateVPCEConfigurationError {
     }
 }
 impl Error for UpdateVPCEConfigurationError {}
+impl ServiceErrorCode for UpdateVPCEConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateVPCEConfigurationError::Argument(_) => "ArgumentException",
+            UpdateVPCEConfigurationError::InvalidOperation(_) => "InvalidOperationException",
+            UpdateVPCEConfigurationError::NotFound(_) => "NotFoundException",
+            UpdateVPCEConfigurationError::ServiceAccount(_) => "ServiceAccountException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Device Farm API. AWS Device Farm clients implement this trait.
 #[async_trait]
 pub trait DeviceFarm {
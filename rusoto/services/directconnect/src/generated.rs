@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2150,6 +2150,18 @@ impl fmt::Display for AcceptDirectConnectGatewayAssociationProposalError {
     }
 }
 impl Error for AcceptDirectConnectGatewayAssociationProposalError {}
+impl ServiceErrorCode for AcceptDirectConnectGatewayAssociationProposalError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptDirectConnectGatewayAssociationProposalError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            AcceptDirectConnectGatewayAssociationProposalError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by AllocateConnectionOnInterconnect
 #[derive(Debug, PartialEq)]
 pub enum AllocateConnectionOnInterconnectError {
@@ -2196,6 +2208,18 @@ impl fmt::Display for AllocateConnectionOnInterconnectError {
     }
 }
 impl Error for AllocateConnectionOnInterconnectError {}
+impl ServiceErrorCode for AllocateConnectionOnInterconnectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AllocateConnectionOnInterconnectError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            AllocateConnectionOnInterconnectError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by AllocateHostedConnection
 #[derive(Debug, PartialEq)]
 pub enum AllocateHostedConnectionError {
@@ -2252,6 +2276,16 @@ impl fmt::Display for AllocateHostedConnectionError {
     }
 }
 impl Error for AllocateHostedConnectionError {}
+impl ServiceErrorCode for AllocateHostedConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AllocateHostedConnectionError::DirectConnectClient(_) => "DirectConnectClientException",
+            AllocateHostedConnectionError::DirectConnectServer(_) => "DirectConnectServerException",
+            AllocateHostedConnectionError::DuplicateTagKeys(_) => "DuplicateTagKeysException",
+            AllocateHostedConnectionError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by AllocatePrivateVirtualInterface
 #[derive(Debug, PartialEq)]
 pub enum AllocatePrivateVirtualInterfaceError {
@@ -2316,6 +2350,22 @@ impl fmt::Display for AllocatePrivateVirtualInterfaceError {
     }
 }
 impl Error for AllocatePrivateVirtualInterfaceError {}
+impl ServiceErrorCode for AllocatePrivateVirtualInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AllocatePrivateVirtualInterfaceError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            AllocatePrivateVirtualInterfaceError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+            AllocatePrivateVirtualInterfaceError::DuplicateTagKeys(_) => {
+                "DuplicateTagKeysException"
+            }
+            AllocatePrivateVirtualInterfaceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by AllocatePublicVirtualInterface
 #[derive(Debug, PartialEq)]
 pub enum AllocatePublicVirtualInterfaceError {
@@ -2380,6 +2430,20 @@ impl fmt::Display for AllocatePublicVirtualInterfaceError {
     }
 }
 impl Error for AllocatePublicVirtualInterfaceError {}
+impl ServiceErrorCode for AllocatePublicVirtualInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AllocatePublicVirtualInterfaceError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            AllocatePublicVirtualInterfaceError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+            AllocatePublicVirtualInterfaceError::DuplicateTagKeys(_) => "DuplicateTagKeysException",
+            AllocatePublicVirtualInterfaceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by AllocateTransitVirtualInterface
 #[derive(Debug, PartialEq)]
 pub enum AllocateTransitVirtualInterfaceError {
@@ -2444,6 +2508,22 @@ impl fmt::Display for AllocateTransitVirtualInterfaceError {
     }
 }
 impl Error for AllocateTransitVirtualInterfaceError {}
+impl ServiceErrorCode for AllocateTransitVirtualInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AllocateTransitVirtualInterfaceError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            AllocateTransitVirtualInterfaceError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+            AllocateTransitVirtualInterfaceError::DuplicateTagKeys(_) => {
+                "DuplicateTagKeysException"
+            }
+            AllocateTransitVirtualInterfaceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by AssociateConnectionWithLag
 #[derive(Debug, PartialEq)]
 pub enum AssociateConnectionWithLagError {
@@ -2490,6 +2570,18 @@ impl fmt::Display for AssociateConnectionWithLagError {
     }
 }
 impl Error for AssociateConnectionWithLagError {}
+impl ServiceErrorCode for AssociateConnectionWithLagError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateConnectionWithLagError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            AssociateConnectionWithLagError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by AssociateHostedConnection
 #[derive(Debug, PartialEq)]
 pub enum AssociateHostedConnectionError {
@@ -2534,6 +2626,18 @@ impl fmt::Display for AssociateHostedConnectionError {
     }
 }
 impl Error for AssociateHostedConnectionError {}
+impl ServiceErrorCode for AssociateHostedConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateHostedConnectionError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            AssociateHostedConnectionError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by AssociateVirtualInterface
 #[derive(Debug, PartialEq)]
 pub enum AssociateVirtualInterfaceError {
@@ -2578,6 +2682,18 @@ impl fmt::Display for AssociateVirtualInterfaceError {
     }
 }
 impl Error for AssociateVirtualInterfaceError {}
+impl ServiceErrorCode for AssociateVirtualInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateVirtualInterfaceError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            AssociateVirtualInterfaceError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by ConfirmConnection
 #[derive(Debug, PartialEq)]
 pub enum ConfirmConnectionError {
@@ -2618,6 +2734,14 @@ impl fmt::Display for ConfirmConnectionError {
     }
 }
 impl Error for ConfirmConnectionError {}
+impl ServiceErrorCode for ConfirmConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ConfirmConnectionError::DirectConnectClient(_) => "DirectConnectClientException",
+            ConfirmConnectionError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by ConfirmPrivateVirtualInterface
 #[derive(Debug, PartialEq)]
 pub enum ConfirmPrivateVirtualInterfaceError {
@@ -2664,6 +2788,18 @@ impl fmt::Display for ConfirmPrivateVirtualInterfaceError {
     }
 }
 impl Error for ConfirmPrivateVirtualInterfaceError {}
+impl ServiceErrorCode for ConfirmPrivateVirtualInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ConfirmPrivateVirtualInterfaceError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            ConfirmPrivateVirtualInterfaceError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by ConfirmPublicVirtualInterface
 #[derive(Debug, PartialEq)]
 pub enum ConfirmPublicVirtualInterfaceError {
@@ -2710,6 +2846,18 @@ impl fmt::Display for ConfirmPublicVirtualInterfaceError {
     }
 }
 impl Error for ConfirmPublicVirtualInterfaceError {}
+impl ServiceErrorCode for ConfirmPublicVirtualInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ConfirmPublicVirtualInterfaceError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            ConfirmPublicVirtualInterfaceError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by ConfirmTransitVirtualInterface
 #[derive(Debug, PartialEq)]
 pub enum ConfirmTransitVirtualInterfaceError {
@@ -2756,6 +2904,18 @@ impl fmt::Display for ConfirmTransitVirtualInterfaceError {
     }
 }
 impl Error for ConfirmTransitVirtualInterfaceError {}
+impl ServiceErrorCode for ConfirmTransitVirtualInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ConfirmTransitVirtualInterfaceError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            ConfirmTransitVirtualInterfaceError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateBGPPeer
 #[derive(Debug, PartialEq)]
 pub enum CreateBGPPeerError {
@@ -2792,6 +2952,14 @@ impl fmt::Display for CreateBGPPeerError {
     }
 }
 impl Error for CreateBGPPeerError {}
+impl ServiceErrorCode for CreateBGPPeerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBGPPeerError::DirectConnectClient(_) => "DirectConnectClientException",
+            CreateBGPPeerError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by CreateConnection
 #[derive(Debug, PartialEq)]
 pub enum CreateConnectionError {
@@ -2844,6 +3012,16 @@ impl fmt::Display for CreateConnectionError {
     }
 }
 impl Error for CreateConnectionError {}
+impl ServiceErrorCode for CreateConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConnectionError::DirectConnectClient(_) => "DirectConnectClientException",
+            CreateConnectionError::DirectConnectServer(_) => "DirectConnectServerException",
+            CreateConnectionError::DuplicateTagKeys(_) => "DuplicateTagKeysException",
+            CreateConnectionError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by CreateDirectConnectGateway
 #[derive(Debug, PartialEq)]
 pub enum CreateDirectConnectGatewayError {
@@ -2890,6 +3068,18 @@ impl fmt::Display for CreateDirectConnectGatewayError {
     }
 }
 impl Error for CreateDirectConnectGatewayError {}
+impl ServiceErrorCode for CreateDirectConnectGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDirectConnectGatewayError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            CreateDirectConnectGatewayError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDirectConnectGatewayAssociation
 #[derive(Debug, PartialEq)]
 pub enum CreateDirectConnectGatewayAssociationError {
@@ -2936,6 +3126,18 @@ impl fmt::Display for CreateDirectConnectGatewayAssociationError {
     }
 }
 impl Error for CreateDirectConnectGatewayAssociationError {}
+impl ServiceErrorCode for CreateDirectConnectGatewayAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDirectConnectGatewayAssociationError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            CreateDirectConnectGatewayAssociationError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDirectConnectGatewayAssociationProposal
 #[derive(Debug, PartialEq)]
 pub enum CreateDirectConnectGatewayAssociationProposalError {
@@ -2986,6 +3188,18 @@ impl fmt::Display for CreateDirectConnectGatewayAssociationProposalError {
     }
 }
 impl Error for CreateDirectConnectGatewayAssociationProposalError {}
+impl ServiceErrorCode for CreateDirectConnectGatewayAssociationProposalError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDirectConnectGatewayAssociationProposalError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            CreateDirectConnectGatewayAssociationProposalError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateInterconnect
 #[derive(Debug, PartialEq)]
 pub enum CreateInterconnectError {
@@ -3038,6 +3252,16 @@ impl fmt::Display for CreateInterconnectError {
     }
 }
 impl Error for CreateInterconnectError {}
+impl ServiceErrorCode for CreateInterconnectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateInterconnectError::DirectConnectClient(_) => "DirectConnectClientException",
+            CreateInterconnectError::DirectConnectServer(_) => "DirectConnectServerException",
+            CreateInterconnectError::DuplicateTagKeys(_) => "DuplicateTagKeysException",
+            CreateInterconnectError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by CreateLag
 #[derive(Debug, PartialEq)]
 pub enum CreateLagError {
@@ -3086,6 +3310,16 @@ impl fmt::Display for CreateLagError {
     }
 }
 impl Error for CreateLagError {}
+impl ServiceErrorCode for CreateLagError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLagError::DirectConnectClient(_) => "DirectConnectClientException",
+            CreateLagError::DirectConnectServer(_) => "DirectConnectServerException",
+            CreateLagError::DuplicateTagKeys(_) => "DuplicateTagKeysException",
+            CreateLagError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by CreatePrivateVirtualInterface
 #[derive(Debug, PartialEq)]
 pub enum CreatePrivateVirtualInterfaceError {
@@ -3150,6 +3384,20 @@ impl fmt::Display for CreatePrivateVirtualInterfaceError {
     }
 }
 impl Error for CreatePrivateVirtualInterfaceError {}
+impl ServiceErrorCode for CreatePrivateVirtualInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePrivateVirtualInterfaceError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            CreatePrivateVirtualInterfaceError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+            CreatePrivateVirtualInterfaceError::DuplicateTagKeys(_) => "DuplicateTagKeysException",
+            CreatePrivateVirtualInterfaceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by CreatePublicVirtualInterface
 #[derive(Debug, PartialEq)]
 pub enum CreatePublicVirtualInterfaceError {
@@ -3214,6 +3462,20 @@ impl fmt::Display for CreatePublicVirtualInterfaceError {
     }
 }
 impl Error for CreatePublicVirtualInterfaceError {}
+impl ServiceErrorCode for CreatePublicVirtualInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePublicVirtualInterfaceError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            CreatePublicVirtualInterfaceError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+            CreatePublicVirtualInterfaceError::DuplicateTagKeys(_) => "DuplicateTagKeysException",
+            CreatePublicVirtualInterfaceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by CreateTransitVirtualInterface
 #[derive(Debug, PartialEq)]
 pub enum CreateTransitVirtualInterfaceError {
@@ -3278,6 +3540,20 @@ impl fmt::Display for CreateTransitVirtualInterfaceError {
     }
 }
 impl Error for CreateTransitVirtualInterfaceError {}
+impl ServiceErrorCode for CreateTransitVirtualInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTransitVirtualInterfaceError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            CreateTransitVirtualInterfaceError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+            CreateTransitVirtualInterfaceError::DuplicateTagKeys(_) => "DuplicateTagKeysException",
+            CreateTransitVirtualInterfaceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by DeleteBGPPeer
 #[derive(Debug, PartialEq)]
 pub enum DeleteBGPPeerError {
@@ -3314,6 +3590,14 @@ impl fmt::Display for DeleteBGPPeerError {
     }
 }
 impl Error for DeleteBGPPeerError {}
+impl ServiceErrorCode for DeleteBGPPeerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBGPPeerError::DirectConnectClient(_) => "DirectConnectClientException",
+            DeleteBGPPeerError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by DeleteConnection
 #[derive(Debug, PartialEq)]
 pub enum DeleteConnectionError {
@@ -3354,6 +3638,14 @@ impl fmt::Display for DeleteConnectionError {
     }
 }
 impl Error for DeleteConnectionError {}
+impl ServiceErrorCode for DeleteConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConnectionError::DirectConnectClient(_) => "DirectConnectClientException",
+            DeleteConnectionError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by DeleteDirectConnectGateway
 #[derive(Debug, PartialEq)]
 pub enum DeleteDirectConnectGatewayError {
@@ -3400,6 +3692,18 @@ impl fmt::Display for DeleteDirectConnectGatewayError {
     }
 }
 impl Error for DeleteDirectConnectGatewayError {}
+impl ServiceErrorCode for DeleteDirectConnectGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDirectConnectGatewayError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            DeleteDirectConnectGatewayError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDirectConnectGatewayAssociation
 #[derive(Debug, PartialEq)]
 pub enum DeleteDirectConnectGatewayAssociationError {
@@ -3446,6 +3750,18 @@ impl fmt::Display for DeleteDirectConnectGatewayAssociationError {
     }
 }
 impl Error for DeleteDirectConnectGatewayAssociationError {}
+impl ServiceErrorCode for DeleteDirectConnectGatewayAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDirectConnectGatewayAssociationError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            DeleteDirectConnectGatewayAssociationError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDirectConnectGatewayAssociationProposal
 #[derive(Debug, PartialEq)]
 pub enum DeleteDirectConnectGatewayAssociationProposalError {
@@ -3496,6 +3812,18 @@ impl fmt::Display for DeleteDirectConnectGatewayAssociationProposalError {
     }
 }
 impl Error for DeleteDirectConnectGatewayAssociationProposalError {}
+impl ServiceErrorCode for DeleteDirectConnectGatewayAssociationProposalError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDirectConnectGatewayAssociationProposalError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            DeleteDirectConnectGatewayAssociationProposalError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteInterconnect
 #[derive(Debug, PartialEq)]
 pub enum DeleteInterconnectError {
@@ -3536,6 +3864,14 @@ impl fmt::Display for DeleteInterconnectError {
     }
 }
 impl Error for DeleteInterconnectError {}
+impl ServiceErrorCode for DeleteInterconnectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInterconnectError::DirectConnectClient(_) => "DirectConnectClientException",
+            DeleteInterconnectError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by DeleteLag
 #[derive(Debug, PartialEq)]
 pub enum DeleteLagError {
@@ -3572,6 +3908,14 @@ impl fmt::Display for DeleteLagError {
     }
 }
 impl Error for DeleteLagError {}
+impl ServiceErrorCode for DeleteLagError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLagError::DirectConnectClient(_) => "DirectConnectClientException",
+            DeleteLagError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by DeleteVirtualInterface
 #[derive(Debug, PartialEq)]
 pub enum DeleteVirtualInterfaceError {
@@ -3612,6 +3956,14 @@ impl fmt::Display for DeleteVirtualInterfaceError {
     }
 }
 impl Error for DeleteVirtualInterfaceError {}
+impl ServiceErrorCode for DeleteVirtualInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVirtualInterfaceError::DirectConnectClient(_) => "DirectConnectClientException",
+            DeleteVirtualInterfaceError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by DescribeConnectionLoa
 #[derive(Debug, PartialEq)]
 pub enum DescribeConnectionLoaError {
@@ -3652,6 +4004,14 @@ impl fmt::Display for DescribeConnectionLoaError {
     }
 }
 impl Error for DescribeConnectionLoaError {}
+impl ServiceErrorCode for DescribeConnectionLoaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConnectionLoaError::DirectConnectClient(_) => "DirectConnectClientException",
+            DescribeConnectionLoaError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by DescribeConnections
 #[derive(Debug, PartialEq)]
 pub enum DescribeConnectionsError {
@@ -3692,6 +4052,14 @@ impl fmt::Display for DescribeConnectionsError {
     }
 }
 impl Error for DescribeConnectionsError {}
+impl ServiceErrorCode for DescribeConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConnectionsError::DirectConnectClient(_) => "DirectConnectClientException",
+            DescribeConnectionsError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by DescribeConnectionsOnInterconnect
 #[derive(Debug, PartialEq)]
 pub enum DescribeConnectionsOnInterconnectError {
@@ -3738,6 +4106,18 @@ impl fmt::Display for DescribeConnectionsOnInterconnectError {
     }
 }
 impl Error for DescribeConnectionsOnInterconnectError {}
+impl ServiceErrorCode for DescribeConnectionsOnInterconnectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConnectionsOnInterconnectError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            DescribeConnectionsOnInterconnectError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDirectConnectGatewayAssociationProposals
 #[derive(Debug, PartialEq)]
 pub enum DescribeDirectConnectGatewayAssociationProposalsError {
@@ -3788,6 +4168,18 @@ impl fmt::Display for DescribeDirectConnectGatewayAssociationProposalsError {
     }
 }
 impl Error for DescribeDirectConnectGatewayAssociationProposalsError {}
+impl ServiceErrorCode for DescribeDirectConnectGatewayAssociationProposalsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDirectConnectGatewayAssociationProposalsError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            DescribeDirectConnectGatewayAssociationProposalsError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDirectConnectGatewayAssociations
 #[derive(Debug, PartialEq)]
 pub enum DescribeDirectConnectGatewayAssociationsError {
@@ -3834,6 +4226,18 @@ impl fmt::Display for DescribeDirectConnectGatewayAssociationsError {
     }
 }
 impl Error for DescribeDirectConnectGatewayAssociationsError {}
+impl ServiceErrorCode for DescribeDirectConnectGatewayAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDirectConnectGatewayAssociationsError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            DescribeDirectConnectGatewayAssociationsError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDirectConnectGatewayAttachments
 #[derive(Debug, PartialEq)]
 pub enum DescribeDirectConnectGatewayAttachmentsError {
@@ -3880,6 +4284,18 @@ impl fmt::Display for DescribeDirectConnectGatewayAttachmentsError {
     }
 }
 impl Error for DescribeDirectConnectGatewayAttachmentsError {}
+impl ServiceErrorCode for DescribeDirectConnectGatewayAttachmentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDirectConnectGatewayAttachmentsError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            DescribeDirectConnectGatewayAttachmentsError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDirectConnectGateways
 #[derive(Debug, PartialEq)]
 pub enum DescribeDirectConnectGatewaysError {
@@ -3926,6 +4342,18 @@ impl fmt::Display for DescribeDirectConnectGatewaysError {
     }
 }
 impl Error for DescribeDirectConnectGatewaysError {}
+impl ServiceErrorCode for DescribeDirectConnectGatewaysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDirectConnectGatewaysError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            DescribeDirectConnectGatewaysError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeHostedConnections
 #[derive(Debug, PartialEq)]
 pub enum DescribeHostedConnectionsError {
@@ -3970,6 +4398,18 @@ impl fmt::Display for DescribeHostedConnectionsError {
     }
 }
 impl Error for DescribeHostedConnectionsError {}
+impl ServiceErrorCode for DescribeHostedConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeHostedConnectionsError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            DescribeHostedConnectionsError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeInterconnectLoa
 #[derive(Debug, PartialEq)]
 pub enum DescribeInterconnectLoaError {
@@ -4010,6 +4450,14 @@ impl fmt::Display for DescribeInterconnectLoaError {
     }
 }
 impl Error for DescribeInterconnectLoaError {}
+impl ServiceErrorCode for DescribeInterconnectLoaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInterconnectLoaError::DirectConnectClient(_) => "DirectConnectClientException",
+            DescribeInterconnectLoaError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by DescribeInterconnects
 #[derive(Debug, PartialEq)]
 pub enum DescribeInterconnectsError {
@@ -4050,6 +4498,14 @@ impl fmt::Display for DescribeInterconnectsError {
     }
 }
 impl Error for DescribeInterconnectsError {}
+impl ServiceErrorCode for DescribeInterconnectsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInterconnectsError::DirectConnectClient(_) => "DirectConnectClientException",
+            DescribeInterconnectsError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by DescribeLags
 #[derive(Debug, PartialEq)]
 pub enum DescribeLagsError {
@@ -4086,6 +4542,14 @@ impl fmt::Display for DescribeLagsError {
     }
 }
 impl Error for DescribeLagsError {}
+impl ServiceErrorCode for DescribeLagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLagsError::DirectConnectClient(_) => "DirectConnectClientException",
+            DescribeLagsError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by DescribeLoa
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoaError {
@@ -4122,6 +4586,14 @@ impl fmt::Display for DescribeLoaError {
     }
 }
 impl Error for DescribeLoaError {}
+impl ServiceErrorCode for DescribeLoaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLoaError::DirectConnectClient(_) => "DirectConnectClientException",
+            DescribeLoaError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by DescribeLocations
 #[derive(Debug, PartialEq)]
 pub enum DescribeLocationsError {
@@ -4162,6 +4634,14 @@ impl fmt::Display for DescribeLocationsError {
     }
 }
 impl Error for DescribeLocationsError {}
+impl ServiceErrorCode for DescribeLocationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLocationsError::DirectConnectClient(_) => "DirectConnectClientException",
+            DescribeLocationsError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
 pub enum DescribeTagsError {
@@ -4198,6 +4678,14 @@ impl fmt::Display for DescribeTagsError {
     }
 }
 impl Error for DescribeTagsError {}
+impl ServiceErrorCode for DescribeTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTagsError::DirectConnectClient(_) => "DirectConnectClientException",
+            DescribeTagsError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by DescribeVirtualGateways
 #[derive(Debug, PartialEq)]
 pub enum DescribeVirtualGatewaysError {
@@ -4238,6 +4726,14 @@ impl fmt::Display for DescribeVirtualGatewaysError {
     }
 }
 impl Error for DescribeVirtualGatewaysError {}
+impl ServiceErrorCode for DescribeVirtualGatewaysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeVirtualGatewaysError::DirectConnectClient(_) => "DirectConnectClientException",
+            DescribeVirtualGatewaysError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by DescribeVirtualInterfaces
 #[derive(Debug, PartialEq)]
 pub enum DescribeVirtualInterfacesError {
@@ -4282,6 +4778,18 @@ impl fmt::Display for DescribeVirtualInterfacesError {
     }
 }
 impl Error for DescribeVirtualInterfacesError {}
+impl ServiceErrorCode for DescribeVirtualInterfacesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeVirtualInterfacesError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            DescribeVirtualInterfacesError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateConnectionFromLag
 #[derive(Debug, PartialEq)]
 pub enum DisassociateConnectionFromLagError {
@@ -4328,6 +4836,18 @@ impl fmt::Display for DisassociateConnectionFromLagError {
     }
 }
 impl Error for DisassociateConnectionFromLagError {}
+impl ServiceErrorCode for DisassociateConnectionFromLagError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateConnectionFromLagError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            DisassociateConnectionFromLagError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by ListVirtualInterfaceTestHistory
 #[derive(Debug, PartialEq)]
 pub enum ListVirtualInterfaceTestHistoryError {
@@ -4374,6 +4894,18 @@ impl fmt::Display for ListVirtualInterfaceTestHistoryError {
     }
 }
 impl Error for ListVirtualInterfaceTestHistoryError {}
+impl ServiceErrorCode for ListVirtualInterfaceTestHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVirtualInterfaceTestHistoryError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            ListVirtualInterfaceTestHistoryError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by StartBgpFailoverTest
 #[derive(Debug, PartialEq)]
 pub enum StartBgpFailoverTestError {
@@ -4414,6 +4946,14 @@ impl fmt::Display for StartBgpFailoverTestError {
     }
 }
 impl Error for StartBgpFailoverTestError {}
+impl ServiceErrorCode for StartBgpFailoverTestError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartBgpFailoverTestError::DirectConnectClient(_) => "DirectConnectClientException",
+            StartBgpFailoverTestError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by StopBgpFailoverTest
 #[derive(Debug, PartialEq)]
 pub enum StopBgpFailoverTestError {
@@ -4454,6 +4994,14 @@ impl fmt::Display for StopBgpFailoverTestError {
     }
 }
 impl Error for StopBgpFailoverTestError {}
+impl ServiceErrorCode for StopBgpFailoverTestError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopBgpFailoverTestError::DirectConnectClient(_) => "DirectConnectClientException",
+            StopBgpFailoverTestError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -4502,6 +5050,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::DirectConnectClient(_) => "DirectConnectClientException",
+            TagResourceError::DirectConnectServer(_) => "DirectConnectServerException",
+            TagResourceError::DuplicateTagKeys(_) => "DuplicateTagKeysException",
+            TagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -4538,6 +5096,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::DirectConnectClient(_) => "DirectConnectClientException",
+            UntagResourceError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by UpdateDirectConnectGatewayAssociation
 #[derive(Debug, PartialEq)]
 pub enum UpdateDirectConnectGatewayAssociationError {
@@ -4584,6 +5150,18 @@ impl fmt::Display for UpdateDirectConnectGatewayAssociationError {
     }
 }
 impl Error for UpdateDirectConnectGatewayAssociationError {}
+impl ServiceErrorCode for UpdateDirectConnectGatewayAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDirectConnectGatewayAssociationError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            UpdateDirectConnectGatewayAssociationError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateLag
 #[derive(Debug, PartialEq)]
 pub enum UpdateLagError {
@@ -4620,6 +5198,14 @@ impl fmt::Display for UpdateLagError {
     }
 }
 impl Error for UpdateLagError {}
+impl ServiceErrorCode for UpdateLagError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateLagError::DirectConnectClient(_) => "DirectConnectClientException",
+            UpdateLagError::DirectConnectServer(_) => "DirectConnectServerException",
+        }
+    }
+}
 /// Errors returned by UpdateVirtualInterfaceAttributes
 #[derive(Debug, PartialEq)]
 pub enum UpdateVirtualInterfaceAttributesError {
@@ -4666,6 +5252,18 @@ impl fmt::Display for UpdateVirtualInterfaceAttributesError {
     }
 }
 impl Error for UpdateVirtualInterfaceAttributesError {}
+impl ServiceErrorCode for UpdateVirtualInterfaceAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateVirtualInterfaceAttributesError::DirectConnectClient(_) => {
+                "DirectConnectClientException"
+            }
+            UpdateVirtualInterfaceAttributesError::DirectConnectServer(_) => {
+                "DirectConnectServerException"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Direct Connect API. AWS Direct Connect clients implement this trait.
 #[async_trait]
 pub trait DirectConnect {
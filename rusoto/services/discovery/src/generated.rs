@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -951,6 +951,7 @@ pub struct StartExportTaskResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartImportTaskRequest {
     /// <p>Optional. A unique token that you can provide to prevent the same import request from occurring more than once. If you don't provide a token, a token is automatically generated.</p> <p>Sending more than one <code>StartImportTask</code> request with the same client request token will return information about the original import task with that client request token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -1131,6 +1132,27 @@ impl fmt::Display for AssociateConfigurationItemsToApplicationError {
     }
 }
 impl Error for AssociateConfigurationItemsToApplicationError {}
+impl ServiceErrorCode for AssociateConfigurationItemsToApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateConfigurationItemsToApplicationError::AuthorizationError(_) => {
+                "AuthorizationErrorException"
+            }
+            AssociateConfigurationItemsToApplicationError::HomeRegionNotSet(_) => {
+                "HomeRegionNotSetException"
+            }
+            AssociateConfigurationItemsToApplicationError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            AssociateConfigurationItemsToApplicationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            AssociateConfigurationItemsToApplicationError::ServerInternalError(_) => {
+                "ServerInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by BatchDeleteImportData
 #[derive(Debug, PartialEq)]
 pub enum BatchDeleteImportDataError {
@@ -1195,6 +1217,19 @@ impl fmt::Display for BatchDeleteImportDataError {
     }
 }
 impl Error for BatchDeleteImportDataError {}
+impl ServiceErrorCode for BatchDeleteImportDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDeleteImportDataError::AuthorizationError(_) => "AuthorizationErrorException",
+            BatchDeleteImportDataError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            BatchDeleteImportDataError::InvalidParameter(_) => "InvalidParameterException",
+            BatchDeleteImportDataError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            BatchDeleteImportDataError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by CreateApplication
 #[derive(Debug, PartialEq)]
 pub enum CreateApplicationError {
@@ -1255,6 +1290,17 @@ impl fmt::Display for CreateApplicationError {
     }
 }
 impl Error for CreateApplicationError {}
+impl ServiceErrorCode for CreateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApplicationError::AuthorizationError(_) => "AuthorizationErrorException",
+            CreateApplicationError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            CreateApplicationError::InvalidParameter(_) => "InvalidParameterException",
+            CreateApplicationError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateApplicationError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by CreateTags
 #[derive(Debug, PartialEq)]
 pub enum CreateTagsError {
@@ -1315,6 +1361,18 @@ impl fmt::Display for CreateTagsError {
     }
 }
 impl Error for CreateTagsError {}
+impl ServiceErrorCode for CreateTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTagsError::AuthorizationError(_) => "AuthorizationErrorException",
+            CreateTagsError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            CreateTagsError::InvalidParameter(_) => "InvalidParameterException",
+            CreateTagsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateTagsError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by DeleteApplications
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationsError {
@@ -1375,6 +1433,17 @@ impl fmt::Display for DeleteApplicationsError {
     }
 }
 impl Error for DeleteApplicationsError {}
+impl ServiceErrorCode for DeleteApplicationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationsError::AuthorizationError(_) => "AuthorizationErrorException",
+            DeleteApplicationsError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            DeleteApplicationsError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteApplicationsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteApplicationsError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
 pub enum DeleteTagsError {
@@ -1435,6 +1504,18 @@ impl fmt::Display for DeleteTagsError {
     }
 }
 impl Error for DeleteTagsError {}
+impl ServiceErrorCode for DeleteTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTagsError::AuthorizationError(_) => "AuthorizationErrorException",
+            DeleteTagsError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            DeleteTagsError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteTagsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteTagsError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by DescribeAgents
 #[derive(Debug, PartialEq)]
 pub enum DescribeAgentsError {
@@ -1491,6 +1572,17 @@ impl fmt::Display for DescribeAgentsError {
     }
 }
 impl Error for DescribeAgentsError {}
+impl ServiceErrorCode for DescribeAgentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAgentsError::AuthorizationError(_) => "AuthorizationErrorException",
+            DescribeAgentsError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            DescribeAgentsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeAgentsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeAgentsError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by DescribeConfigurations
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigurationsError {
@@ -1555,6 +1647,19 @@ impl fmt::Display for DescribeConfigurationsError {
     }
 }
 impl Error for DescribeConfigurationsError {}
+impl ServiceErrorCode for DescribeConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConfigurationsError::AuthorizationError(_) => "AuthorizationErrorException",
+            DescribeConfigurationsError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            DescribeConfigurationsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeConfigurationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeConfigurationsError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by DescribeContinuousExports
 #[derive(Debug, PartialEq)]
 pub enum DescribeContinuousExportsError {
@@ -1641,6 +1746,25 @@ impl fmt::Display for DescribeContinuousExportsError {
     }
 }
 impl Error for DescribeContinuousExportsError {}
+impl ServiceErrorCode for DescribeContinuousExportsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeContinuousExportsError::AuthorizationError(_) => "AuthorizationErrorException",
+            DescribeContinuousExportsError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            DescribeContinuousExportsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeContinuousExportsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeContinuousExportsError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            DescribeContinuousExportsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeContinuousExportsError::ServerInternalError(_) => {
+                "ServerInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeExportConfigurations
 #[derive(Debug, PartialEq)]
 pub enum DescribeExportConfigurationsError {
@@ -1727,6 +1851,24 @@ impl fmt::Display for DescribeExportConfigurationsError {
     }
 }
 impl Error for DescribeExportConfigurationsError {}
+impl ServiceErrorCode for DescribeExportConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeExportConfigurationsError::AuthorizationError(_) => {
+                "AuthorizationErrorException"
+            }
+            DescribeExportConfigurationsError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            DescribeExportConfigurationsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeExportConfigurationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeExportConfigurationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeExportConfigurationsError::ServerInternalError(_) => {
+                "ServerInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeExportTasks
 #[derive(Debug, PartialEq)]
 pub enum DescribeExportTasksError {
@@ -1791,6 +1933,17 @@ impl fmt::Display for DescribeExportTasksError {
     }
 }
 impl Error for DescribeExportTasksError {}
+impl ServiceErrorCode for DescribeExportTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeExportTasksError::AuthorizationError(_) => "AuthorizationErrorException",
+            DescribeExportTasksError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            DescribeExportTasksError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeExportTasksError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeExportTasksError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by DescribeImportTasks
 #[derive(Debug, PartialEq)]
 pub enum DescribeImportTasksError {
@@ -1855,6 +2008,17 @@ impl fmt::Display for DescribeImportTasksError {
     }
 }
 impl Error for DescribeImportTasksError {}
+impl ServiceErrorCode for DescribeImportTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeImportTasksError::AuthorizationError(_) => "AuthorizationErrorException",
+            DescribeImportTasksError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            DescribeImportTasksError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeImportTasksError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeImportTasksError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
 pub enum DescribeTagsError {
@@ -1915,6 +2079,18 @@ impl fmt::Display for DescribeTagsError {
     }
 }
 impl Error for DescribeTagsError {}
+impl ServiceErrorCode for DescribeTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTagsError::AuthorizationError(_) => "AuthorizationErrorException",
+            DescribeTagsError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            DescribeTagsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeTagsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeTagsError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by DisassociateConfigurationItemsFromApplication
 #[derive(Debug, PartialEq)]
 pub enum DisassociateConfigurationItemsFromApplicationError {
@@ -2001,6 +2177,27 @@ impl fmt::Display for DisassociateConfigurationItemsFromApplicationError {
     }
 }
 impl Error for DisassociateConfigurationItemsFromApplicationError {}
+impl ServiceErrorCode for DisassociateConfigurationItemsFromApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateConfigurationItemsFromApplicationError::AuthorizationError(_) => {
+                "AuthorizationErrorException"
+            }
+            DisassociateConfigurationItemsFromApplicationError::HomeRegionNotSet(_) => {
+                "HomeRegionNotSetException"
+            }
+            DisassociateConfigurationItemsFromApplicationError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            DisassociateConfigurationItemsFromApplicationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DisassociateConfigurationItemsFromApplicationError::ServerInternalError(_) => {
+                "ServerInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by ExportConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ExportConfigurationsError {
@@ -2073,6 +2270,18 @@ impl fmt::Display for ExportConfigurationsError {
     }
 }
 impl Error for ExportConfigurationsError {}
+impl ServiceErrorCode for ExportConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExportConfigurationsError::AuthorizationError(_) => "AuthorizationErrorException",
+            ExportConfigurationsError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            ExportConfigurationsError::InvalidParameter(_) => "InvalidParameterException",
+            ExportConfigurationsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ExportConfigurationsError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            ExportConfigurationsError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by GetDiscoverySummary
 #[derive(Debug, PartialEq)]
 pub enum GetDiscoverySummaryError {
@@ -2137,6 +2346,17 @@ impl fmt::Display for GetDiscoverySummaryError {
     }
 }
 impl Error for GetDiscoverySummaryError {}
+impl ServiceErrorCode for GetDiscoverySummaryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDiscoverySummaryError::AuthorizationError(_) => "AuthorizationErrorException",
+            GetDiscoverySummaryError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            GetDiscoverySummaryError::InvalidParameter(_) => "InvalidParameterException",
+            GetDiscoverySummaryError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetDiscoverySummaryError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by ListConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListConfigurationsError {
@@ -2203,6 +2423,18 @@ impl fmt::Display for ListConfigurationsError {
     }
 }
 impl Error for ListConfigurationsError {}
+impl ServiceErrorCode for ListConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListConfigurationsError::AuthorizationError(_) => "AuthorizationErrorException",
+            ListConfigurationsError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            ListConfigurationsError::InvalidParameter(_) => "InvalidParameterException",
+            ListConfigurationsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListConfigurationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListConfigurationsError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by ListServerNeighbors
 #[derive(Debug, PartialEq)]
 pub enum ListServerNeighborsError {
@@ -2267,6 +2499,17 @@ impl fmt::Display for ListServerNeighborsError {
     }
 }
 impl Error for ListServerNeighborsError {}
+impl ServiceErrorCode for ListServerNeighborsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListServerNeighborsError::AuthorizationError(_) => "AuthorizationErrorException",
+            ListServerNeighborsError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            ListServerNeighborsError::InvalidParameter(_) => "InvalidParameterException",
+            ListServerNeighborsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListServerNeighborsError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by StartContinuousExport
 #[derive(Debug, PartialEq)]
 pub enum StartContinuousExportError {
@@ -2351,6 +2594,24 @@ impl fmt::Display for StartContinuousExportError {
     }
 }
 impl Error for StartContinuousExportError {}
+impl ServiceErrorCode for StartContinuousExportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartContinuousExportError::AuthorizationError(_) => "AuthorizationErrorException",
+            StartContinuousExportError::ConflictError(_) => "ConflictErrorException",
+            StartContinuousExportError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            StartContinuousExportError::InvalidParameter(_) => "InvalidParameterException",
+            StartContinuousExportError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            StartContinuousExportError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            StartContinuousExportError::ResourceInUse(_) => "ResourceInUseException",
+            StartContinuousExportError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by StartDataCollectionByAgentIds
 #[derive(Debug, PartialEq)]
 pub enum StartDataCollectionByAgentIdsError {
@@ -2427,6 +2688,23 @@ impl fmt::Display for StartDataCollectionByAgentIdsError {
     }
 }
 impl Error for StartDataCollectionByAgentIdsError {}
+impl ServiceErrorCode for StartDataCollectionByAgentIdsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDataCollectionByAgentIdsError::AuthorizationError(_) => {
+                "AuthorizationErrorException"
+            }
+            StartDataCollectionByAgentIdsError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            StartDataCollectionByAgentIdsError::InvalidParameter(_) => "InvalidParameterException",
+            StartDataCollectionByAgentIdsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            StartDataCollectionByAgentIdsError::ServerInternalError(_) => {
+                "ServerInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by StartExportTask
 #[derive(Debug, PartialEq)]
 pub enum StartExportTaskError {
@@ -2491,6 +2769,18 @@ impl fmt::Display for StartExportTaskError {
     }
 }
 impl Error for StartExportTaskError {}
+impl ServiceErrorCode for StartExportTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartExportTaskError::AuthorizationError(_) => "AuthorizationErrorException",
+            StartExportTaskError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            StartExportTaskError::InvalidParameter(_) => "InvalidParameterException",
+            StartExportTaskError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            StartExportTaskError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            StartExportTaskError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by StartImportTask
 #[derive(Debug, PartialEq)]
 pub enum StartImportTaskError {
@@ -2553,6 +2843,18 @@ impl fmt::Display for StartImportTaskError {
     }
 }
 impl Error for StartImportTaskError {}
+impl ServiceErrorCode for StartImportTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartImportTaskError::AuthorizationError(_) => "AuthorizationErrorException",
+            StartImportTaskError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            StartImportTaskError::InvalidParameter(_) => "InvalidParameterException",
+            StartImportTaskError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            StartImportTaskError::ResourceInUse(_) => "ResourceInUseException",
+            StartImportTaskError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by StopContinuousExport
 #[derive(Debug, PartialEq)]
 pub enum StopContinuousExportError {
@@ -2639,6 +2941,20 @@ impl fmt::Display for StopContinuousExportError {
     }
 }
 impl Error for StopContinuousExportError {}
+impl ServiceErrorCode for StopContinuousExportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopContinuousExportError::AuthorizationError(_) => "AuthorizationErrorException",
+            StopContinuousExportError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            StopContinuousExportError::InvalidParameter(_) => "InvalidParameterException",
+            StopContinuousExportError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            StopContinuousExportError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            StopContinuousExportError::ResourceInUse(_) => "ResourceInUseException",
+            StopContinuousExportError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StopContinuousExportError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Errors returned by StopDataCollectionByAgentIds
 #[derive(Debug, PartialEq)]
 pub enum StopDataCollectionByAgentIdsError {
@@ -2715,6 +3031,23 @@ impl fmt::Display for StopDataCollectionByAgentIdsError {
     }
 }
 impl Error for StopDataCollectionByAgentIdsError {}
+impl ServiceErrorCode for StopDataCollectionByAgentIdsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopDataCollectionByAgentIdsError::AuthorizationError(_) => {
+                "AuthorizationErrorException"
+            }
+            StopDataCollectionByAgentIdsError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            StopDataCollectionByAgentIdsError::InvalidParameter(_) => "InvalidParameterException",
+            StopDataCollectionByAgentIdsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            StopDataCollectionByAgentIdsError::ServerInternalError(_) => {
+                "ServerInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
 pub enum UpdateApplicationError {
@@ -2775,6 +3108,17 @@ impl fmt::Display for UpdateApplicationError {
     }
 }
 impl Error for UpdateApplicationError {}
+impl ServiceErrorCode for UpdateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApplicationError::AuthorizationError(_) => "AuthorizationErrorException",
+            UpdateApplicationError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            UpdateApplicationError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateApplicationError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateApplicationError::ServerInternalError(_) => "ServerInternalErrorException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Application Discovery Service API. AWS Application Discovery Service clients implement this trait.
 #[async_trait]
 pub trait Discovery {
@@ -3434,6 +3778,10 @@ impl Discovery for DiscoveryClient {
         &self,
         input: StartImportTaskRequest,
     ) -> Result<StartImportTaskResponse, RusotoError<StartImportTaskError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
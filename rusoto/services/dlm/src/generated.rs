@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -582,6 +582,15 @@ impl fmt::Display for CreateLifecyclePolicyError {
     }
 }
 impl Error for CreateLifecyclePolicyError {}
+impl ServiceErrorCode for CreateLifecyclePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLifecyclePolicyError::InternalServer(_) => "InternalServerException",
+            CreateLifecyclePolicyError::InvalidRequest(_) => "InvalidRequestException",
+            CreateLifecyclePolicyError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DeleteLifecyclePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteLifecyclePolicyError {
@@ -628,6 +637,15 @@ impl fmt::Display for DeleteLifecyclePolicyError {
     }
 }
 impl Error for DeleteLifecyclePolicyError {}
+impl ServiceErrorCode for DeleteLifecyclePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLifecyclePolicyError::InternalServer(_) => "InternalServerException",
+            DeleteLifecyclePolicyError::LimitExceeded(_) => "LimitExceededException",
+            DeleteLifecyclePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetLifecyclePolicies
 #[derive(Debug, PartialEq)]
 pub enum GetLifecyclePoliciesError {
@@ -678,6 +696,16 @@ impl fmt::Display for GetLifecyclePoliciesError {
     }
 }
 impl Error for GetLifecyclePoliciesError {}
+impl ServiceErrorCode for GetLifecyclePoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLifecyclePoliciesError::InternalServer(_) => "InternalServerException",
+            GetLifecyclePoliciesError::InvalidRequest(_) => "InvalidRequestException",
+            GetLifecyclePoliciesError::LimitExceeded(_) => "LimitExceededException",
+            GetLifecyclePoliciesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetLifecyclePolicy
 #[derive(Debug, PartialEq)]
 pub enum GetLifecyclePolicyError {
@@ -720,6 +748,15 @@ impl fmt::Display for GetLifecyclePolicyError {
     }
 }
 impl Error for GetLifecyclePolicyError {}
+impl ServiceErrorCode for GetLifecyclePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLifecyclePolicyError::InternalServer(_) => "InternalServerException",
+            GetLifecyclePolicyError::LimitExceeded(_) => "LimitExceededException",
+            GetLifecyclePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -764,6 +801,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalServer(_) => "InternalServerException",
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -806,6 +852,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalServer(_) => "InternalServerException",
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -848,6 +903,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalServer(_) => "InternalServerException",
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateLifecyclePolicy
 #[derive(Debug, PartialEq)]
 pub enum UpdateLifecyclePolicyError {
@@ -902,6 +966,16 @@ impl fmt::Display for UpdateLifecyclePolicyError {
     }
 }
 impl Error for UpdateLifecyclePolicyError {}
+impl ServiceErrorCode for UpdateLifecyclePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateLifecyclePolicyError::InternalServer(_) => "InternalServerException",
+            UpdateLifecyclePolicyError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateLifecyclePolicyError::LimitExceeded(_) => "LimitExceededException",
+            UpdateLifecyclePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon DLM API. Amazon DLM clients implement this trait.
 #[async_trait]
 pub trait Dlm {
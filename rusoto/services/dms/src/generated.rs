@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3862,6 +3862,13 @@ impl fmt::Display for AddTagsToResourceError {
     }
 }
 impl Error for AddTagsToResourceError {}
+impl ServiceErrorCode for AddTagsToResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsToResourceError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by ApplyPendingMaintenanceAction
 #[derive(Debug, PartialEq)]
 pub enum ApplyPendingMaintenanceActionError {
@@ -3898,6 +3905,13 @@ impl fmt::Display for ApplyPendingMaintenanceActionError {
     }
 }
 impl Error for ApplyPendingMaintenanceActionError {}
+impl ServiceErrorCode for ApplyPendingMaintenanceActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ApplyPendingMaintenanceActionError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by CancelReplicationTaskAssessmentRun
 #[derive(Debug, PartialEq)]
 pub enum CancelReplicationTaskAssessmentRunError {
@@ -3954,6 +3968,19 @@ impl fmt::Display for CancelReplicationTaskAssessmentRunError {
     }
 }
 impl Error for CancelReplicationTaskAssessmentRunError {}
+impl ServiceErrorCode for CancelReplicationTaskAssessmentRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelReplicationTaskAssessmentRunError::AccessDeniedFault(_) => "AccessDeniedFault",
+            CancelReplicationTaskAssessmentRunError::InvalidResourceStateFault(_) => {
+                "InvalidResourceStateFault"
+            }
+            CancelReplicationTaskAssessmentRunError::ResourceNotFoundFault(_) => {
+                "ResourceNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by CreateEndpoint
 #[derive(Debug, PartialEq)]
 pub enum CreateEndpointError {
@@ -4030,6 +4057,19 @@ impl fmt::Display for CreateEndpointError {
     }
 }
 impl Error for CreateEndpointError {}
+impl ServiceErrorCode for CreateEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEndpointError::AccessDeniedFault(_) => "AccessDeniedFault",
+            CreateEndpointError::InvalidResourceStateFault(_) => "InvalidResourceStateFault",
+            CreateEndpointError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            CreateEndpointError::ResourceAlreadyExistsFault(_) => "ResourceAlreadyExistsFault",
+            CreateEndpointError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+            CreateEndpointError::ResourceQuotaExceededFault(_) => "ResourceQuotaExceededFault",
+            CreateEndpointError::S3AccessDeniedFault(_) => "S3AccessDeniedFault",
+        }
+    }
+}
 /// Errors returned by CreateEventSubscription
 #[derive(Debug, PartialEq)]
 pub enum CreateEventSubscriptionError {
@@ -4142,6 +4182,26 @@ impl fmt::Display for CreateEventSubscriptionError {
     }
 }
 impl Error for CreateEventSubscriptionError {}
+impl ServiceErrorCode for CreateEventSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEventSubscriptionError::KMSAccessDeniedFault(_) => "KMSAccessDeniedFault",
+            CreateEventSubscriptionError::KMSDisabledFault(_) => "KMSDisabledFault",
+            CreateEventSubscriptionError::KMSInvalidStateFault(_) => "KMSInvalidStateFault",
+            CreateEventSubscriptionError::KMSNotFoundFault(_) => "KMSNotFoundFault",
+            CreateEventSubscriptionError::KMSThrottlingFault(_) => "KMSThrottlingFault",
+            CreateEventSubscriptionError::ResourceAlreadyExistsFault(_) => {
+                "ResourceAlreadyExistsFault"
+            }
+            CreateEventSubscriptionError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+            CreateEventSubscriptionError::ResourceQuotaExceededFault(_) => {
+                "ResourceQuotaExceededFault"
+            }
+            CreateEventSubscriptionError::SNSInvalidTopicFault(_) => "SNSInvalidTopicFault",
+            CreateEventSubscriptionError::SNSNoAuthorizationFault(_) => "SNSNoAuthorizationFault",
+        }
+    }
+}
 /// Errors returned by CreateReplicationInstance
 #[derive(Debug, PartialEq)]
 pub enum CreateReplicationInstanceError {
@@ -4264,6 +4324,36 @@ impl fmt::Display for CreateReplicationInstanceError {
     }
 }
 impl Error for CreateReplicationInstanceError {}
+impl ServiceErrorCode for CreateReplicationInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateReplicationInstanceError::AccessDeniedFault(_) => "AccessDeniedFault",
+            CreateReplicationInstanceError::InsufficientResourceCapacityFault(_) => {
+                "InsufficientResourceCapacityFault"
+            }
+            CreateReplicationInstanceError::InvalidResourceStateFault(_) => {
+                "InvalidResourceStateFault"
+            }
+            CreateReplicationInstanceError::InvalidSubnet(_) => "InvalidSubnet",
+            CreateReplicationInstanceError::KMSKeyNotAccessibleFault(_) => {
+                "KMSKeyNotAccessibleFault"
+            }
+            CreateReplicationInstanceError::ReplicationSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "ReplicationSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            CreateReplicationInstanceError::ResourceAlreadyExistsFault(_) => {
+                "ResourceAlreadyExistsFault"
+            }
+            CreateReplicationInstanceError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+            CreateReplicationInstanceError::ResourceQuotaExceededFault(_) => {
+                "ResourceQuotaExceededFault"
+            }
+            CreateReplicationInstanceError::StorageQuotaExceededFault(_) => {
+                "StorageQuotaExceededFault"
+            }
+        }
+    }
+}
 /// Errors returned by CreateReplicationSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateReplicationSubnetGroupError {
@@ -4348,6 +4438,24 @@ impl fmt::Display for CreateReplicationSubnetGroupError {
     }
 }
 impl Error for CreateReplicationSubnetGroupError {}
+impl ServiceErrorCode for CreateReplicationSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateReplicationSubnetGroupError::AccessDeniedFault(_) => "AccessDeniedFault",
+            CreateReplicationSubnetGroupError::InvalidSubnet(_) => "InvalidSubnet",
+            CreateReplicationSubnetGroupError::ReplicationSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "ReplicationSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            CreateReplicationSubnetGroupError::ResourceAlreadyExistsFault(_) => {
+                "ResourceAlreadyExistsFault"
+            }
+            CreateReplicationSubnetGroupError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+            CreateReplicationSubnetGroupError::ResourceQuotaExceededFault(_) => {
+                "ResourceQuotaExceededFault"
+            }
+        }
+    }
+}
 /// Errors returned by CreateReplicationTask
 #[derive(Debug, PartialEq)]
 pub enum CreateReplicationTaskError {
@@ -4428,6 +4536,22 @@ impl fmt::Display for CreateReplicationTaskError {
     }
 }
 impl Error for CreateReplicationTaskError {}
+impl ServiceErrorCode for CreateReplicationTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateReplicationTaskError::AccessDeniedFault(_) => "AccessDeniedFault",
+            CreateReplicationTaskError::InvalidResourceStateFault(_) => "InvalidResourceStateFault",
+            CreateReplicationTaskError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            CreateReplicationTaskError::ResourceAlreadyExistsFault(_) => {
+                "ResourceAlreadyExistsFault"
+            }
+            CreateReplicationTaskError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+            CreateReplicationTaskError::ResourceQuotaExceededFault(_) => {
+                "ResourceQuotaExceededFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteCertificate
 #[derive(Debug, PartialEq)]
 pub enum DeleteCertificateError {
@@ -4468,6 +4592,14 @@ impl fmt::Display for DeleteCertificateError {
     }
 }
 impl Error for DeleteCertificateError {}
+impl ServiceErrorCode for DeleteCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCertificateError::InvalidResourceStateFault(_) => "InvalidResourceStateFault",
+            DeleteCertificateError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DeleteConnection
 #[derive(Debug, PartialEq)]
 pub enum DeleteConnectionError {
@@ -4514,6 +4646,15 @@ impl fmt::Display for DeleteConnectionError {
     }
 }
 impl Error for DeleteConnectionError {}
+impl ServiceErrorCode for DeleteConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConnectionError::AccessDeniedFault(_) => "AccessDeniedFault",
+            DeleteConnectionError::InvalidResourceStateFault(_) => "InvalidResourceStateFault",
+            DeleteConnectionError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DeleteEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DeleteEndpointError {
@@ -4554,6 +4695,14 @@ impl fmt::Display for DeleteEndpointError {
     }
 }
 impl Error for DeleteEndpointError {}
+impl ServiceErrorCode for DeleteEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEndpointError::InvalidResourceStateFault(_) => "InvalidResourceStateFault",
+            DeleteEndpointError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DeleteEventSubscription
 #[derive(Debug, PartialEq)]
 pub enum DeleteEventSubscriptionError {
@@ -4598,6 +4747,16 @@ impl fmt::Display for DeleteEventSubscriptionError {
     }
 }
 impl Error for DeleteEventSubscriptionError {}
+impl ServiceErrorCode for DeleteEventSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEventSubscriptionError::InvalidResourceStateFault(_) => {
+                "InvalidResourceStateFault"
+            }
+            DeleteEventSubscriptionError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DeleteReplicationInstance
 #[derive(Debug, PartialEq)]
 pub enum DeleteReplicationInstanceError {
@@ -4642,6 +4801,16 @@ impl fmt::Display for DeleteReplicationInstanceError {
     }
 }
 impl Error for DeleteReplicationInstanceError {}
+impl ServiceErrorCode for DeleteReplicationInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteReplicationInstanceError::InvalidResourceStateFault(_) => {
+                "InvalidResourceStateFault"
+            }
+            DeleteReplicationInstanceError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DeleteReplicationSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteReplicationSubnetGroupError {
@@ -4688,6 +4857,16 @@ impl fmt::Display for DeleteReplicationSubnetGroupError {
     }
 }
 impl Error for DeleteReplicationSubnetGroupError {}
+impl ServiceErrorCode for DeleteReplicationSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteReplicationSubnetGroupError::InvalidResourceStateFault(_) => {
+                "InvalidResourceStateFault"
+            }
+            DeleteReplicationSubnetGroupError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DeleteReplicationTask
 #[derive(Debug, PartialEq)]
 pub enum DeleteReplicationTaskError {
@@ -4730,6 +4909,14 @@ impl fmt::Display for DeleteReplicationTaskError {
     }
 }
 impl Error for DeleteReplicationTaskError {}
+impl ServiceErrorCode for DeleteReplicationTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteReplicationTaskError::InvalidResourceStateFault(_) => "InvalidResourceStateFault",
+            DeleteReplicationTaskError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DeleteReplicationTaskAssessmentRun
 #[derive(Debug, PartialEq)]
 pub enum DeleteReplicationTaskAssessmentRunError {
@@ -4786,6 +4973,19 @@ impl fmt::Display for DeleteReplicationTaskAssessmentRunError {
     }
 }
 impl Error for DeleteReplicationTaskAssessmentRunError {}
+impl ServiceErrorCode for DeleteReplicationTaskAssessmentRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteReplicationTaskAssessmentRunError::AccessDeniedFault(_) => "AccessDeniedFault",
+            DeleteReplicationTaskAssessmentRunError::InvalidResourceStateFault(_) => {
+                "InvalidResourceStateFault"
+            }
+            DeleteReplicationTaskAssessmentRunError::ResourceNotFoundFault(_) => {
+                "ResourceNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeAccountAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountAttributesError {}
@@ -4808,6 +5008,11 @@ impl fmt::Display for DescribeAccountAttributesError {
     }
 }
 impl Error for DescribeAccountAttributesError {}
+impl ServiceErrorCode for DescribeAccountAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeApplicableIndividualAssessments
 #[derive(Debug, PartialEq)]
 pub enum DescribeApplicableIndividualAssessmentsError {
@@ -4868,6 +5073,21 @@ impl fmt::Display for DescribeApplicableIndividualAssessmentsError {
     }
 }
 impl Error for DescribeApplicableIndividualAssessmentsError {}
+impl ServiceErrorCode for DescribeApplicableIndividualAssessmentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeApplicableIndividualAssessmentsError::AccessDeniedFault(_) => {
+                "AccessDeniedFault"
+            }
+            DescribeApplicableIndividualAssessmentsError::InvalidResourceStateFault(_) => {
+                "InvalidResourceStateFault"
+            }
+            DescribeApplicableIndividualAssessmentsError::ResourceNotFoundFault(_) => {
+                "ResourceNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeCertificates
 #[derive(Debug, PartialEq)]
 pub enum DescribeCertificatesError {
@@ -4900,6 +5120,13 @@ impl fmt::Display for DescribeCertificatesError {
     }
 }
 impl Error for DescribeCertificatesError {}
+impl ServiceErrorCode for DescribeCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCertificatesError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeConnections
 #[derive(Debug, PartialEq)]
 pub enum DescribeConnectionsError {
@@ -4932,6 +5159,13 @@ impl fmt::Display for DescribeConnectionsError {
     }
 }
 impl Error for DescribeConnectionsError {}
+impl ServiceErrorCode for DescribeConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConnectionsError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeEndpointTypes
 #[derive(Debug, PartialEq)]
 pub enum DescribeEndpointTypesError {}
@@ -4954,6 +5188,11 @@ impl fmt::Display for DescribeEndpointTypesError {
     }
 }
 impl Error for DescribeEndpointTypesError {}
+impl ServiceErrorCode for DescribeEndpointTypesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEndpoints
 #[derive(Debug, PartialEq)]
 pub enum DescribeEndpointsError {
@@ -4986,6 +5225,13 @@ impl fmt::Display for DescribeEndpointsError {
     }
 }
 impl Error for DescribeEndpointsError {}
+impl ServiceErrorCode for DescribeEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEndpointsError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeEventCategories
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventCategoriesError {}
@@ -5008,6 +5254,11 @@ impl fmt::Display for DescribeEventCategoriesError {
     }
 }
 impl Error for DescribeEventCategoriesError {}
+impl ServiceErrorCode for DescribeEventCategoriesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEventSubscriptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventSubscriptionsError {
@@ -5044,6 +5295,13 @@ impl fmt::Display for DescribeEventSubscriptionsError {
     }
 }
 impl Error for DescribeEventSubscriptionsError {}
+impl ServiceErrorCode for DescribeEventSubscriptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventSubscriptionsError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventsError {}
@@ -5066,6 +5324,11 @@ impl fmt::Display for DescribeEventsError {
     }
 }
 impl Error for DescribeEventsError {}
+impl ServiceErrorCode for DescribeEventsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeOrderableReplicationInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeOrderableReplicationInstancesError {}
@@ -5090,6 +5353,11 @@ impl fmt::Display for DescribeOrderableReplicationInstancesError {
     }
 }
 impl Error for DescribeOrderableReplicationInstancesError {}
+impl ServiceErrorCode for DescribeOrderableReplicationInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribePendingMaintenanceActions
 #[derive(Debug, PartialEq)]
 pub enum DescribePendingMaintenanceActionsError {
@@ -5126,6 +5394,15 @@ impl fmt::Display for DescribePendingMaintenanceActionsError {
     }
 }
 impl Error for DescribePendingMaintenanceActionsError {}
+impl ServiceErrorCode for DescribePendingMaintenanceActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePendingMaintenanceActionsError::ResourceNotFoundFault(_) => {
+                "ResourceNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeRefreshSchemasStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeRefreshSchemasStatusError {
@@ -5172,6 +5449,16 @@ impl fmt::Display for DescribeRefreshSchemasStatusError {
     }
 }
 impl Error for DescribeRefreshSchemasStatusError {}
+impl ServiceErrorCode for DescribeRefreshSchemasStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRefreshSchemasStatusError::InvalidResourceStateFault(_) => {
+                "InvalidResourceStateFault"
+            }
+            DescribeRefreshSchemasStatusError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeReplicationInstanceTaskLogs
 #[derive(Debug, PartialEq)]
 pub enum DescribeReplicationInstanceTaskLogsError {
@@ -5220,6 +5507,18 @@ impl fmt::Display for DescribeReplicationInstanceTaskLogsError {
     }
 }
 impl Error for DescribeReplicationInstanceTaskLogsError {}
+impl ServiceErrorCode for DescribeReplicationInstanceTaskLogsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReplicationInstanceTaskLogsError::InvalidResourceStateFault(_) => {
+                "InvalidResourceStateFault"
+            }
+            DescribeReplicationInstanceTaskLogsError::ResourceNotFoundFault(_) => {
+                "ResourceNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeReplicationInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeReplicationInstancesError {
@@ -5256,6 +5555,13 @@ impl fmt::Display for DescribeReplicationInstancesError {
     }
 }
 impl Error for DescribeReplicationInstancesError {}
+impl ServiceErrorCode for DescribeReplicationInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReplicationInstancesError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeReplicationSubnetGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeReplicationSubnetGroupsError {
@@ -5292,6 +5598,15 @@ impl fmt::Display for DescribeReplicationSubnetGroupsError {
     }
 }
 impl Error for DescribeReplicationSubnetGroupsError {}
+impl ServiceErrorCode for DescribeReplicationSubnetGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReplicationSubnetGroupsError::ResourceNotFoundFault(_) => {
+                "ResourceNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeReplicationTaskAssessmentResults
 #[derive(Debug, PartialEq)]
 pub enum DescribeReplicationTaskAssessmentResultsError {
@@ -5330,6 +5645,15 @@ impl fmt::Display for DescribeReplicationTaskAssessmentResultsError {
     }
 }
 impl Error for DescribeReplicationTaskAssessmentResultsError {}
+impl ServiceErrorCode for DescribeReplicationTaskAssessmentResultsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReplicationTaskAssessmentResultsError::ResourceNotFoundFault(_) => {
+                "ResourceNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeReplicationTaskAssessmentRuns
 #[derive(Debug, PartialEq)]
 pub enum DescribeReplicationTaskAssessmentRunsError {
@@ -5366,6 +5690,15 @@ impl fmt::Display for DescribeReplicationTaskAssessmentRunsError {
     }
 }
 impl Error for DescribeReplicationTaskAssessmentRunsError {}
+impl ServiceErrorCode for DescribeReplicationTaskAssessmentRunsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReplicationTaskAssessmentRunsError::ResourceNotFoundFault(_) => {
+                "ResourceNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeReplicationTaskIndividualAssessments
 #[derive(Debug, PartialEq)]
 pub enum DescribeReplicationTaskIndividualAssessmentsError {
@@ -5404,6 +5737,15 @@ impl fmt::Display for DescribeReplicationTaskIndividualAssessmentsError {
     }
 }
 impl Error for DescribeReplicationTaskIndividualAssessmentsError {}
+impl ServiceErrorCode for DescribeReplicationTaskIndividualAssessmentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReplicationTaskIndividualAssessmentsError::ResourceNotFoundFault(_) => {
+                "ResourceNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeReplicationTasks
 #[derive(Debug, PartialEq)]
 pub enum DescribeReplicationTasksError {
@@ -5438,6 +5780,13 @@ impl fmt::Display for DescribeReplicationTasksError {
     }
 }
 impl Error for DescribeReplicationTasksError {}
+impl ServiceErrorCode for DescribeReplicationTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReplicationTasksError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeSchemas
 #[derive(Debug, PartialEq)]
 pub enum DescribeSchemasError {
@@ -5478,6 +5827,14 @@ impl fmt::Display for DescribeSchemasError {
     }
 }
 impl Error for DescribeSchemasError {}
+impl ServiceErrorCode for DescribeSchemasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSchemasError::InvalidResourceStateFault(_) => "InvalidResourceStateFault",
+            DescribeSchemasError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeTableStatistics
 #[derive(Debug, PartialEq)]
 pub enum DescribeTableStatisticsError {
@@ -5522,6 +5879,16 @@ impl fmt::Display for DescribeTableStatisticsError {
     }
 }
 impl Error for DescribeTableStatisticsError {}
+impl ServiceErrorCode for DescribeTableStatisticsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTableStatisticsError::InvalidResourceStateFault(_) => {
+                "InvalidResourceStateFault"
+            }
+            DescribeTableStatisticsError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by ImportCertificate
 #[derive(Debug, PartialEq)]
 pub enum ImportCertificateError {
@@ -5570,6 +5937,15 @@ impl fmt::Display for ImportCertificateError {
     }
 }
 impl Error for ImportCertificateError {}
+impl ServiceErrorCode for ImportCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportCertificateError::InvalidCertificateFault(_) => "InvalidCertificateFault",
+            ImportCertificateError::ResourceAlreadyExistsFault(_) => "ResourceAlreadyExistsFault",
+            ImportCertificateError::ResourceQuotaExceededFault(_) => "ResourceQuotaExceededFault",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -5602,6 +5978,13 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by ModifyEndpoint
 #[derive(Debug, PartialEq)]
 pub enum ModifyEndpointError {
@@ -5664,6 +6047,17 @@ impl fmt::Display for ModifyEndpointError {
     }
 }
 impl Error for ModifyEndpointError {}
+impl ServiceErrorCode for ModifyEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyEndpointError::AccessDeniedFault(_) => "AccessDeniedFault",
+            ModifyEndpointError::InvalidResourceStateFault(_) => "InvalidResourceStateFault",
+            ModifyEndpointError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            ModifyEndpointError::ResourceAlreadyExistsFault(_) => "ResourceAlreadyExistsFault",
+            ModifyEndpointError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by ModifyEventSubscription
 #[derive(Debug, PartialEq)]
 pub enum ModifyEventSubscriptionError {
@@ -5766,6 +6160,23 @@ impl fmt::Display for ModifyEventSubscriptionError {
     }
 }
 impl Error for ModifyEventSubscriptionError {}
+impl ServiceErrorCode for ModifyEventSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyEventSubscriptionError::KMSAccessDeniedFault(_) => "KMSAccessDeniedFault",
+            ModifyEventSubscriptionError::KMSDisabledFault(_) => "KMSDisabledFault",
+            ModifyEventSubscriptionError::KMSInvalidStateFault(_) => "KMSInvalidStateFault",
+            ModifyEventSubscriptionError::KMSNotFoundFault(_) => "KMSNotFoundFault",
+            ModifyEventSubscriptionError::KMSThrottlingFault(_) => "KMSThrottlingFault",
+            ModifyEventSubscriptionError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+            ModifyEventSubscriptionError::ResourceQuotaExceededFault(_) => {
+                "ResourceQuotaExceededFault"
+            }
+            ModifyEventSubscriptionError::SNSInvalidTopicFault(_) => "SNSInvalidTopicFault",
+            ModifyEventSubscriptionError::SNSNoAuthorizationFault(_) => "SNSNoAuthorizationFault",
+        }
+    }
+}
 /// Errors returned by ModifyReplicationInstance
 #[derive(Debug, PartialEq)]
 pub enum ModifyReplicationInstanceError {
@@ -5858,6 +6269,29 @@ impl fmt::Display for ModifyReplicationInstanceError {
     }
 }
 impl Error for ModifyReplicationInstanceError {}
+impl ServiceErrorCode for ModifyReplicationInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyReplicationInstanceError::AccessDeniedFault(_) => "AccessDeniedFault",
+            ModifyReplicationInstanceError::InsufficientResourceCapacityFault(_) => {
+                "InsufficientResourceCapacityFault"
+            }
+            ModifyReplicationInstanceError::InvalidResourceStateFault(_) => {
+                "InvalidResourceStateFault"
+            }
+            ModifyReplicationInstanceError::ResourceAlreadyExistsFault(_) => {
+                "ResourceAlreadyExistsFault"
+            }
+            ModifyReplicationInstanceError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+            ModifyReplicationInstanceError::StorageQuotaExceededFault(_) => {
+                "StorageQuotaExceededFault"
+            }
+            ModifyReplicationInstanceError::UpgradeDependencyFailureFault(_) => {
+                "UpgradeDependencyFailureFault"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyReplicationSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyReplicationSubnetGroupError {
@@ -5942,6 +6376,22 @@ impl fmt::Display for ModifyReplicationSubnetGroupError {
     }
 }
 impl Error for ModifyReplicationSubnetGroupError {}
+impl ServiceErrorCode for ModifyReplicationSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyReplicationSubnetGroupError::AccessDeniedFault(_) => "AccessDeniedFault",
+            ModifyReplicationSubnetGroupError::InvalidSubnet(_) => "InvalidSubnet",
+            ModifyReplicationSubnetGroupError::ReplicationSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "ReplicationSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            ModifyReplicationSubnetGroupError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+            ModifyReplicationSubnetGroupError::ResourceQuotaExceededFault(_) => {
+                "ResourceQuotaExceededFault"
+            }
+            ModifyReplicationSubnetGroupError::SubnetAlreadyInUse(_) => "SubnetAlreadyInUse",
+        }
+    }
+}
 /// Errors returned by ModifyReplicationTask
 #[derive(Debug, PartialEq)]
 pub enum ModifyReplicationTaskError {
@@ -6004,6 +6454,18 @@ impl fmt::Display for ModifyReplicationTaskError {
     }
 }
 impl Error for ModifyReplicationTaskError {}
+impl ServiceErrorCode for ModifyReplicationTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyReplicationTaskError::InvalidResourceStateFault(_) => "InvalidResourceStateFault",
+            ModifyReplicationTaskError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            ModifyReplicationTaskError::ResourceAlreadyExistsFault(_) => {
+                "ResourceAlreadyExistsFault"
+            }
+            ModifyReplicationTaskError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by MoveReplicationTask
 #[derive(Debug, PartialEq)]
 pub enum MoveReplicationTaskError {
@@ -6054,6 +6516,15 @@ impl fmt::Display for MoveReplicationTaskError {
     }
 }
 impl Error for MoveReplicationTaskError {}
+impl ServiceErrorCode for MoveReplicationTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            MoveReplicationTaskError::AccessDeniedFault(_) => "AccessDeniedFault",
+            MoveReplicationTaskError::InvalidResourceStateFault(_) => "InvalidResourceStateFault",
+            MoveReplicationTaskError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by RebootReplicationInstance
 #[derive(Debug, PartialEq)]
 pub enum RebootReplicationInstanceError {
@@ -6098,6 +6569,16 @@ impl fmt::Display for RebootReplicationInstanceError {
     }
 }
 impl Error for RebootReplicationInstanceError {}
+impl ServiceErrorCode for RebootReplicationInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RebootReplicationInstanceError::InvalidResourceStateFault(_) => {
+                "InvalidResourceStateFault"
+            }
+            RebootReplicationInstanceError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by RefreshSchemas
 #[derive(Debug, PartialEq)]
 pub enum RefreshSchemasError {
@@ -6154,6 +6635,16 @@ impl fmt::Display for RefreshSchemasError {
     }
 }
 impl Error for RefreshSchemasError {}
+impl ServiceErrorCode for RefreshSchemasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RefreshSchemasError::InvalidResourceStateFault(_) => "InvalidResourceStateFault",
+            RefreshSchemasError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            RefreshSchemasError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+            RefreshSchemasError::ResourceQuotaExceededFault(_) => "ResourceQuotaExceededFault",
+        }
+    }
+}
 /// Errors returned by ReloadTables
 #[derive(Debug, PartialEq)]
 pub enum ReloadTablesError {
@@ -6192,6 +6683,14 @@ impl fmt::Display for ReloadTablesError {
     }
 }
 impl Error for ReloadTablesError {}
+impl ServiceErrorCode for ReloadTablesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ReloadTablesError::InvalidResourceStateFault(_) => "InvalidResourceStateFault",
+            ReloadTablesError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromResourceError {
@@ -6224,6 +6723,13 @@ impl fmt::Display for RemoveTagsFromResourceError {
     }
 }
 impl Error for RemoveTagsFromResourceError {}
+impl ServiceErrorCode for RemoveTagsFromResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsFromResourceError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by StartReplicationTask
 #[derive(Debug, PartialEq)]
 pub enum StartReplicationTaskError {
@@ -6274,6 +6780,15 @@ impl fmt::Display for StartReplicationTaskError {
     }
 }
 impl Error for StartReplicationTaskError {}
+impl ServiceErrorCode for StartReplicationTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartReplicationTaskError::AccessDeniedFault(_) => "AccessDeniedFault",
+            StartReplicationTaskError::InvalidResourceStateFault(_) => "InvalidResourceStateFault",
+            StartReplicationTaskError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by StartReplicationTaskAssessment
 #[derive(Debug, PartialEq)]
 pub enum StartReplicationTaskAssessmentError {
@@ -6320,6 +6835,18 @@ impl fmt::Display for StartReplicationTaskAssessmentError {
     }
 }
 impl Error for StartReplicationTaskAssessmentError {}
+impl ServiceErrorCode for StartReplicationTaskAssessmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartReplicationTaskAssessmentError::InvalidResourceStateFault(_) => {
+                "InvalidResourceStateFault"
+            }
+            StartReplicationTaskAssessmentError::ResourceNotFoundFault(_) => {
+                "ResourceNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by StartReplicationTaskAssessmentRun
 #[derive(Debug, PartialEq)]
 pub enum StartReplicationTaskAssessmentRunError {
@@ -6464,6 +6991,38 @@ impl fmt::Display for StartReplicationTaskAssessmentRunError {
     }
 }
 impl Error for StartReplicationTaskAssessmentRunError {}
+impl ServiceErrorCode for StartReplicationTaskAssessmentRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartReplicationTaskAssessmentRunError::AccessDeniedFault(_) => "AccessDeniedFault",
+            StartReplicationTaskAssessmentRunError::InvalidResourceStateFault(_) => {
+                "InvalidResourceStateFault"
+            }
+            StartReplicationTaskAssessmentRunError::KMSAccessDeniedFault(_) => {
+                "KMSAccessDeniedFault"
+            }
+            StartReplicationTaskAssessmentRunError::KMSDisabledFault(_) => "KMSDisabledFault",
+            StartReplicationTaskAssessmentRunError::KMSFault(_) => "KMSFault",
+            StartReplicationTaskAssessmentRunError::KMSInvalidStateFault(_) => {
+                "KMSInvalidStateFault"
+            }
+            StartReplicationTaskAssessmentRunError::KMSKeyNotAccessibleFault(_) => {
+                "KMSKeyNotAccessibleFault"
+            }
+            StartReplicationTaskAssessmentRunError::KMSNotFoundFault(_) => "KMSNotFoundFault",
+            StartReplicationTaskAssessmentRunError::ResourceAlreadyExistsFault(_) => {
+                "ResourceAlreadyExistsFault"
+            }
+            StartReplicationTaskAssessmentRunError::ResourceNotFoundFault(_) => {
+                "ResourceNotFoundFault"
+            }
+            StartReplicationTaskAssessmentRunError::S3AccessDeniedFault(_) => "S3AccessDeniedFault",
+            StartReplicationTaskAssessmentRunError::S3ResourceNotFoundFault(_) => {
+                "S3ResourceNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by StopReplicationTask
 #[derive(Debug, PartialEq)]
 pub enum StopReplicationTaskError {
@@ -6506,6 +7065,14 @@ impl fmt::Display for StopReplicationTaskError {
     }
 }
 impl Error for StopReplicationTaskError {}
+impl ServiceErrorCode for StopReplicationTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopReplicationTaskError::InvalidResourceStateFault(_) => "InvalidResourceStateFault",
+            StopReplicationTaskError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by TestConnection
 #[derive(Debug, PartialEq)]
 pub enum TestConnectionError {
@@ -6562,6 +7129,16 @@ impl fmt::Display for TestConnectionError {
     }
 }
 impl Error for TestConnectionError {}
+impl ServiceErrorCode for TestConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TestConnectionError::InvalidResourceStateFault(_) => "InvalidResourceStateFault",
+            TestConnectionError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            TestConnectionError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+            TestConnectionError::ResourceQuotaExceededFault(_) => "ResourceQuotaExceededFault",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Database Migration Service API. AWS Database Migration Service clients implement this trait.
 #[async_trait]
 pub trait DatabaseMigrationService {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -5884,6 +5884,15 @@ impl fmt::Display for AddTagsToResourceError {
     }
 }
 impl Error for AddTagsToResourceError {}
+impl ServiceErrorCode for AddTagsToResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsToResourceError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            AddTagsToResourceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            AddTagsToResourceError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+        }
+    }
+}
 /// Errors returned by ApplyPendingMaintenanceAction
 #[derive(Debug, PartialEq)]
 pub enum ApplyPendingMaintenanceActionError {
@@ -5958,6 +5967,19 @@ impl fmt::Display for ApplyPendingMaintenanceActionError {
     }
 }
 impl Error for ApplyPendingMaintenanceActionError {}
+impl ServiceErrorCode for ApplyPendingMaintenanceActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ApplyPendingMaintenanceActionError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            ApplyPendingMaintenanceActionError::InvalidDBInstanceStateFault(_) => {
+                "InvalidDBInstanceState"
+            }
+            ApplyPendingMaintenanceActionError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by CopyDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum CopyDBClusterParameterGroupError {
@@ -6032,6 +6054,21 @@ impl fmt::Display for CopyDBClusterParameterGroupError {
     }
 }
 impl Error for CopyDBClusterParameterGroupError {}
+impl ServiceErrorCode for CopyDBClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(_) => {
+                "DBParameterGroupAlreadyExists"
+            }
+            CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(_) => {
+                "DBParameterGroupQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CopyDBClusterSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CopyDBClusterSnapshotError {
@@ -6140,6 +6177,26 @@ impl fmt::Display for CopyDBClusterSnapshotError {
     }
 }
 impl Error for CopyDBClusterSnapshotError {}
+impl ServiceErrorCode for CopyDBClusterSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(_) => {
+                "DBClusterSnapshotAlreadyExistsFault"
+            }
+            CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            CopyDBClusterSnapshotError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by CreateDBCluster
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterError {
@@ -6312,6 +6369,35 @@ impl fmt::Display for CreateDBClusterError {
     }
 }
 impl Error for CreateDBClusterError {}
+impl ServiceErrorCode for CreateDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBClusterError::DBClusterAlreadyExistsFault(_) => "DBClusterAlreadyExistsFault",
+            CreateDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            CreateDBClusterError::DBClusterParameterGroupNotFoundFault(_) => {
+                "DBClusterParameterGroupNotFound"
+            }
+            CreateDBClusterError::DBClusterQuotaExceededFault(_) => "DBClusterQuotaExceededFault",
+            CreateDBClusterError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            CreateDBClusterError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            CreateDBClusterError::InsufficientStorageClusterCapacityFault(_) => {
+                "InsufficientStorageClusterCapacity"
+            }
+            CreateDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            CreateDBClusterError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            CreateDBClusterError::InvalidDBSubnetGroupStateFault(_) => {
+                "InvalidDBSubnetGroupStateFault"
+            }
+            CreateDBClusterError::InvalidSubnet(_) => "InvalidSubnet",
+            CreateDBClusterError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            CreateDBClusterError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            CreateDBClusterError::StorageQuotaExceededFault(_) => "StorageQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by CreateDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterParameterGroupError {
@@ -6374,6 +6460,18 @@ impl fmt::Display for CreateDBClusterParameterGroupError {
     }
 }
 impl Error for CreateDBClusterParameterGroupError {}
+impl ServiceErrorCode for CreateDBClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(_) => {
+                "DBParameterGroupAlreadyExists"
+            }
+            CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(_) => {
+                "DBParameterGroupQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDBClusterSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterSnapshotError {
@@ -6470,6 +6568,23 @@ impl fmt::Display for CreateDBClusterSnapshotError {
     }
 }
 impl Error for CreateDBClusterSnapshotError {}
+impl ServiceErrorCode for CreateDBClusterSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBClusterSnapshotError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(_) => {
+                "DBClusterSnapshotAlreadyExistsFault"
+            }
+            CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            CreateDBClusterSnapshotError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by CreateDBInstance
 #[derive(Debug, PartialEq)]
 pub enum CreateDBInstanceError {
@@ -6652,6 +6767,31 @@ impl fmt::Display for CreateDBInstanceError {
     }
 }
 impl Error for CreateDBInstanceError {}
+impl ServiceErrorCode for CreateDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBInstanceError::AuthorizationNotFoundFault(_) => "AuthorizationNotFound",
+            CreateDBInstanceError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            CreateDBInstanceError::DBInstanceAlreadyExistsFault(_) => "DBInstanceAlreadyExists",
+            CreateDBInstanceError::DBParameterGroupNotFoundFault(_) => "DBParameterGroupNotFound",
+            CreateDBInstanceError::DBSecurityGroupNotFoundFault(_) => "DBSecurityGroupNotFound",
+            CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            CreateDBInstanceError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            CreateDBInstanceError::InstanceQuotaExceededFault(_) => "InstanceQuotaExceeded",
+            CreateDBInstanceError::InsufficientDBInstanceCapacityFault(_) => {
+                "InsufficientDBInstanceCapacity"
+            }
+            CreateDBInstanceError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            CreateDBInstanceError::InvalidSubnet(_) => "InvalidSubnet",
+            CreateDBInstanceError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            CreateDBInstanceError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            CreateDBInstanceError::StorageQuotaExceededFault(_) => "StorageQuotaExceeded",
+            CreateDBInstanceError::StorageTypeNotSupportedFault(_) => "StorageTypeNotSupported",
+        }
+    }
+}
 /// Errors returned by CreateDBSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateDBSubnetGroupError {
@@ -6744,6 +6884,23 @@ impl fmt::Display for CreateDBSubnetGroupError {
     }
 }
 impl Error for CreateDBSubnetGroupError {}
+impl ServiceErrorCode for CreateDBSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(_) => {
+                "DBSubnetGroupAlreadyExists"
+            }
+            CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(_) => {
+                "DBSubnetGroupQuotaExceeded"
+            }
+            CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(_) => "DBSubnetQuotaExceededFault",
+            CreateDBSubnetGroupError::InvalidSubnet(_) => "InvalidSubnet",
+        }
+    }
+}
 /// Errors returned by DeleteDBCluster
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterError {
@@ -6828,6 +6985,21 @@ impl fmt::Display for DeleteDBClusterError {
     }
 }
 impl Error for DeleteDBClusterError {}
+impl ServiceErrorCode for DeleteDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(_) => {
+                "DBClusterSnapshotAlreadyExistsFault"
+            }
+            DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            DeleteDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            DeleteDBClusterError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by DeleteDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterParameterGroupError {
@@ -6890,6 +7062,18 @@ impl fmt::Display for DeleteDBClusterParameterGroupError {
     }
 }
 impl Error for DeleteDBClusterParameterGroupError {}
+impl ServiceErrorCode for DeleteDBClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(_) => {
+                "InvalidDBParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDBClusterSnapshot
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterSnapshotError {
@@ -6950,6 +7134,18 @@ impl fmt::Display for DeleteDBClusterSnapshotError {
     }
 }
 impl Error for DeleteDBClusterSnapshotError {}
+impl ServiceErrorCode for DeleteDBClusterSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDBInstance
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBInstanceError {
@@ -7032,6 +7228,17 @@ impl fmt::Display for DeleteDBInstanceError {
     }
 }
 impl Error for DeleteDBInstanceError {}
+impl ServiceErrorCode for DeleteDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBInstanceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(_) => "DBSnapshotAlreadyExists",
+            DeleteDBInstanceError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            DeleteDBInstanceError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            DeleteDBInstanceError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by DeleteDBSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBSubnetGroupError {
@@ -7104,6 +7311,17 @@ impl fmt::Display for DeleteDBSubnetGroupError {
     }
 }
 impl Error for DeleteDBSubnetGroupError {}
+impl ServiceErrorCode for DeleteDBSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(_) => {
+                "InvalidDBSubnetGroupStateFault"
+            }
+            DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(_) => "InvalidDBSubnetStateFault",
+        }
+    }
+}
 /// Errors returned by DescribeCertificates
 #[derive(Debug, PartialEq)]
 pub enum DescribeCertificatesError {
@@ -7152,6 +7370,13 @@ impl fmt::Display for DescribeCertificatesError {
     }
 }
 impl Error for DescribeCertificatesError {}
+impl ServiceErrorCode for DescribeCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCertificatesError::CertificateNotFoundFault(_) => "CertificateNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeDBClusterParameterGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterParameterGroupsError {
@@ -7202,6 +7427,15 @@ impl fmt::Display for DescribeDBClusterParameterGroupsError {
     }
 }
 impl Error for DescribeDBClusterParameterGroupsError {}
+impl ServiceErrorCode for DescribeDBClusterParameterGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBClusterParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterParametersError {
@@ -7252,6 +7486,15 @@ impl fmt::Display for DescribeDBClusterParametersError {
     }
 }
 impl Error for DescribeDBClusterParametersError {}
+impl ServiceErrorCode for DescribeDBClusterParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBClusterSnapshotAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterSnapshotAttributesError {
@@ -7300,6 +7543,15 @@ impl fmt::Display for DescribeDBClusterSnapshotAttributesError {
     }
 }
 impl Error for DescribeDBClusterSnapshotAttributesError {}
+impl ServiceErrorCode for DescribeDBClusterSnapshotAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBClusterSnapshots
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterSnapshotsError {
@@ -7350,6 +7602,15 @@ impl fmt::Display for DescribeDBClusterSnapshotsError {
     }
 }
 impl Error for DescribeDBClusterSnapshotsError {}
+impl ServiceErrorCode for DescribeDBClusterSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBClusters
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClustersError {
@@ -7394,6 +7655,13 @@ impl fmt::Display for DescribeDBClustersError {
     }
 }
 impl Error for DescribeDBClustersError {}
+impl ServiceErrorCode for DescribeDBClustersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClustersError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeDBEngineVersions
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBEngineVersionsError {}
@@ -7428,6 +7696,11 @@ impl fmt::Display for DescribeDBEngineVersionsError {
     }
 }
 impl Error for DescribeDBEngineVersionsError {}
+impl ServiceErrorCode for DescribeDBEngineVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeDBInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBInstancesError {
@@ -7472,6 +7745,13 @@ impl fmt::Display for DescribeDBInstancesError {
     }
 }
 impl Error for DescribeDBInstancesError {}
+impl ServiceErrorCode for DescribeDBInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBInstancesError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeDBSubnetGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBSubnetGroupsError {
@@ -7520,6 +7800,15 @@ impl fmt::Display for DescribeDBSubnetGroupsError {
     }
 }
 impl Error for DescribeDBSubnetGroupsError {}
+impl ServiceErrorCode for DescribeDBSubnetGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(_) => {
+                "DBSubnetGroupNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeEngineDefaultClusterParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeEngineDefaultClusterParametersError {}
@@ -7556,6 +7845,11 @@ impl fmt::Display for DescribeEngineDefaultClusterParametersError {
     }
 }
 impl Error for DescribeEngineDefaultClusterParametersError {}
+impl ServiceErrorCode for DescribeEngineDefaultClusterParametersError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEventCategories
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventCategoriesError {}
@@ -7590,6 +7884,11 @@ impl fmt::Display for DescribeEventCategoriesError {
     }
 }
 impl Error for DescribeEventCategoriesError {}
+impl ServiceErrorCode for DescribeEventCategoriesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventsError {}
@@ -7624,6 +7923,11 @@ impl fmt::Display for DescribeEventsError {
     }
 }
 impl Error for DescribeEventsError {}
+impl ServiceErrorCode for DescribeEventsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeOrderableDBInstanceOptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeOrderableDBInstanceOptionsError {}
@@ -7660,6 +7964,11 @@ impl fmt::Display for DescribeOrderableDBInstanceOptionsError {
     }
 }
 impl Error for DescribeOrderableDBInstanceOptionsError {}
+impl ServiceErrorCode for DescribeOrderableDBInstanceOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribePendingMaintenanceActions
 #[derive(Debug, PartialEq)]
 pub enum DescribePendingMaintenanceActionsError {
@@ -7710,6 +8019,15 @@ impl fmt::Display for DescribePendingMaintenanceActionsError {
     }
 }
 impl Error for DescribePendingMaintenanceActionsError {}
+impl ServiceErrorCode for DescribePendingMaintenanceActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePendingMaintenanceActionsError::ResourceNotFoundFault(_) => {
+                "ResourceNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by FailoverDBCluster
 #[derive(Debug, PartialEq)]
 pub enum FailoverDBClusterError {
@@ -7776,6 +8094,15 @@ impl fmt::Display for FailoverDBClusterError {
     }
 }
 impl Error for FailoverDBClusterError {}
+impl ServiceErrorCode for FailoverDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            FailoverDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            FailoverDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            FailoverDBClusterError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -7836,6 +8163,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            ListTagsForResourceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            ListTagsForResourceError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+        }
+    }
+}
 /// Errors returned by ModifyDBCluster
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterError {
@@ -7972,6 +8308,29 @@ impl fmt::Display for ModifyDBClusterError {
     }
 }
 impl Error for ModifyDBClusterError {}
+impl ServiceErrorCode for ModifyDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBClusterError::DBClusterAlreadyExistsFault(_) => "DBClusterAlreadyExistsFault",
+            ModifyDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(_) => {
+                "DBClusterParameterGroupNotFound"
+            }
+            ModifyDBClusterError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            ModifyDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            ModifyDBClusterError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            ModifyDBClusterError::InvalidDBSecurityGroupStateFault(_) => {
+                "InvalidDBSecurityGroupState"
+            }
+            ModifyDBClusterError::InvalidDBSubnetGroupStateFault(_) => {
+                "InvalidDBSubnetGroupStateFault"
+            }
+            ModifyDBClusterError::InvalidSubnet(_) => "InvalidSubnet",
+            ModifyDBClusterError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            ModifyDBClusterError::StorageQuotaExceededFault(_) => "StorageQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by ModifyDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterParameterGroupError {
@@ -8034,6 +8393,18 @@ impl fmt::Display for ModifyDBClusterParameterGroupError {
     }
 }
 impl Error for ModifyDBClusterParameterGroupError {}
+impl ServiceErrorCode for ModifyDBClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(_) => {
+                "InvalidDBParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyDBClusterSnapshotAttribute
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterSnapshotAttributeError {
@@ -8106,6 +8477,21 @@ impl fmt::Display for ModifyDBClusterSnapshotAttributeError {
     }
 }
 impl Error for ModifyDBClusterSnapshotAttributeError {}
+impl ServiceErrorCode for ModifyDBClusterSnapshotAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(_) => {
+                "SharedSnapshotQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyDBInstance
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBInstanceError {
@@ -8278,6 +8664,31 @@ impl fmt::Display for ModifyDBInstanceError {
     }
 }
 impl Error for ModifyDBInstanceError {}
+impl ServiceErrorCode for ModifyDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBInstanceError::AuthorizationNotFoundFault(_) => "AuthorizationNotFound",
+            ModifyDBInstanceError::CertificateNotFoundFault(_) => "CertificateNotFound",
+            ModifyDBInstanceError::DBInstanceAlreadyExistsFault(_) => "DBInstanceAlreadyExists",
+            ModifyDBInstanceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            ModifyDBInstanceError::DBParameterGroupNotFoundFault(_) => "DBParameterGroupNotFound",
+            ModifyDBInstanceError::DBSecurityGroupNotFoundFault(_) => "DBSecurityGroupNotFound",
+            ModifyDBInstanceError::DBUpgradeDependencyFailureFault(_) => {
+                "DBUpgradeDependencyFailure"
+            }
+            ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(_) => {
+                "InsufficientDBInstanceCapacity"
+            }
+            ModifyDBInstanceError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(_) => {
+                "InvalidDBSecurityGroupState"
+            }
+            ModifyDBInstanceError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            ModifyDBInstanceError::StorageQuotaExceededFault(_) => "StorageQuotaExceeded",
+            ModifyDBInstanceError::StorageTypeNotSupportedFault(_) => "StorageTypeNotSupported",
+        }
+    }
+}
 /// Errors returned by ModifyDBSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBSubnetGroupError {
@@ -8366,6 +8777,19 @@ impl fmt::Display for ModifyDBSubnetGroupError {
     }
 }
 impl Error for ModifyDBSubnetGroupError {}
+impl ServiceErrorCode for ModifyDBSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(_) => "DBSubnetQuotaExceededFault",
+            ModifyDBSubnetGroupError::InvalidSubnet(_) => "InvalidSubnet",
+            ModifyDBSubnetGroupError::SubnetAlreadyInUse(_) => "SubnetAlreadyInUse",
+        }
+    }
+}
 /// Errors returned by RebootDBInstance
 #[derive(Debug, PartialEq)]
 pub enum RebootDBInstanceError {
@@ -8420,6 +8844,14 @@ impl fmt::Display for RebootDBInstanceError {
     }
 }
 impl Error for RebootDBInstanceError {}
+impl ServiceErrorCode for RebootDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RebootDBInstanceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            RebootDBInstanceError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+        }
+    }
+}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromResourceError {
@@ -8492,6 +8924,15 @@ impl fmt::Display for RemoveTagsFromResourceError {
     }
 }
 impl Error for RemoveTagsFromResourceError {}
+impl ServiceErrorCode for RemoveTagsFromResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsFromResourceError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            RemoveTagsFromResourceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            RemoveTagsFromResourceError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+        }
+    }
+}
 /// Errors returned by ResetDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum ResetDBClusterParameterGroupError {
@@ -8554,6 +8995,18 @@ impl fmt::Display for ResetDBClusterParameterGroupError {
     }
 }
 impl Error for ResetDBClusterParameterGroupError {}
+impl ServiceErrorCode for ResetDBClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(_) => {
+                "InvalidDBParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by RestoreDBClusterFromSnapshot
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBClusterFromSnapshotError {
@@ -8754,6 +9207,48 @@ impl fmt::Display for RestoreDBClusterFromSnapshotError {
     }
 }
 impl Error for RestoreDBClusterFromSnapshotError {}
+impl ServiceErrorCode for RestoreDBClusterFromSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(_) => {
+                "DBClusterAlreadyExistsFault"
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(_) => {
+                "DBClusterQuotaExceededFault"
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+            RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(_) => {
+                "DBSubnetGroupNotFoundFault"
+            }
+            RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(_) => {
+                "InsufficientDBClusterCapacityFault"
+            }
+            RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(_) => {
+                "InsufficientStorageClusterCapacity"
+            }
+            RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(_) => {
+                "InvalidDBSnapshotState"
+            }
+            RestoreDBClusterFromSnapshotError::InvalidRestoreFault(_) => "InvalidRestoreFault",
+            RestoreDBClusterFromSnapshotError::InvalidSubnet(_) => "InvalidSubnet",
+            RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(_) => {
+                "KMSKeyNotAccessibleFault"
+            }
+            RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(_) => {
+                "StorageQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by RestoreDBClusterToPointInTime
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBClusterToPointInTimeError {
@@ -8966,6 +9461,53 @@ impl fmt::Display for RestoreDBClusterToPointInTimeError {
     }
 }
 impl Error for RestoreDBClusterToPointInTimeError {}
+impl ServiceErrorCode for RestoreDBClusterToPointInTimeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(_) => {
+                "DBClusterAlreadyExistsFault"
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(_) => {
+                "DBClusterNotFoundFault"
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(_) => {
+                "DBClusterQuotaExceededFault"
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(_) => {
+                "DBSubnetGroupNotFoundFault"
+            }
+            RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(_) => {
+                "InsufficientDBClusterCapacityFault"
+            }
+            RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(_) => {
+                "InsufficientStorageClusterCapacity"
+            }
+            RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(_) => {
+                "InvalidDBSnapshotState"
+            }
+            RestoreDBClusterToPointInTimeError::InvalidRestoreFault(_) => "InvalidRestoreFault",
+            RestoreDBClusterToPointInTimeError::InvalidSubnet(_) => "InvalidSubnet",
+            RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(_) => {
+                "KMSKeyNotAccessibleFault"
+            }
+            RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(_) => {
+                "StorageQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by StartDBCluster
 #[derive(Debug, PartialEq)]
 pub enum StartDBClusterError {
@@ -9026,6 +9568,15 @@ impl fmt::Display for StartDBClusterError {
     }
 }
 impl Error for StartDBClusterError {}
+impl ServiceErrorCode for StartDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            StartDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            StartDBClusterError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+        }
+    }
+}
 /// Errors returned by StopDBCluster
 #[derive(Debug, PartialEq)]
 pub enum StopDBClusterError {
@@ -9086,6 +9637,15 @@ impl fmt::Display for StopDBClusterError {
     }
 }
 impl Error for StopDBClusterError {}
+impl ServiceErrorCode for StopDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            StopDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            StopDBClusterError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon DocDB API. Amazon DocDB clients implement this trait.
 #[async_trait]
 pub trait Docdb {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2346,6 +2346,19 @@ impl fmt::Display for AcceptSharedDirectoryError {
     }
 }
 impl Error for AcceptSharedDirectoryError {}
+impl ServiceErrorCode for AcceptSharedDirectoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptSharedDirectoryError::Client(_) => "ClientException",
+            AcceptSharedDirectoryError::DirectoryAlreadyShared(_) => {
+                "DirectoryAlreadySharedException"
+            }
+            AcceptSharedDirectoryError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            AcceptSharedDirectoryError::InvalidParameter(_) => "InvalidParameterException",
+            AcceptSharedDirectoryError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by AddIpRoutes
 #[derive(Debug, PartialEq)]
 pub enum AddIpRoutesError {
@@ -2412,6 +2425,19 @@ impl fmt::Display for AddIpRoutesError {
     }
 }
 impl Error for AddIpRoutesError {}
+impl ServiceErrorCode for AddIpRoutesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddIpRoutesError::Client(_) => "ClientException",
+            AddIpRoutesError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            AddIpRoutesError::EntityAlreadyExists(_) => "EntityAlreadyExistsException",
+            AddIpRoutesError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            AddIpRoutesError::InvalidParameter(_) => "InvalidParameterException",
+            AddIpRoutesError::IpRouteLimitExceeded(_) => "IpRouteLimitExceededException",
+            AddIpRoutesError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by AddRegion
 #[derive(Debug, PartialEq)]
 pub enum AddRegionError {
@@ -2494,6 +2520,22 @@ impl fmt::Display for AddRegionError {
     }
 }
 impl Error for AddRegionError {}
+impl ServiceErrorCode for AddRegionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddRegionError::AccessDenied(_) => "AccessDeniedException",
+            AddRegionError::Client(_) => "ClientException",
+            AddRegionError::DirectoryAlreadyInRegion(_) => "DirectoryAlreadyInRegionException",
+            AddRegionError::DirectoryDoesNotExist(_) => "DirectoryDoesNotExistException",
+            AddRegionError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            AddRegionError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            AddRegionError::InvalidParameter(_) => "InvalidParameterException",
+            AddRegionError::RegionLimitExceeded(_) => "RegionLimitExceededException",
+            AddRegionError::Service(_) => "ServiceException",
+            AddRegionError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by AddTagsToResource
 #[derive(Debug, PartialEq)]
 pub enum AddTagsToResourceError {
@@ -2550,6 +2592,17 @@ impl fmt::Display for AddTagsToResourceError {
     }
 }
 impl Error for AddTagsToResourceError {}
+impl ServiceErrorCode for AddTagsToResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsToResourceError::Client(_) => "ClientException",
+            AddTagsToResourceError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            AddTagsToResourceError::InvalidParameter(_) => "InvalidParameterException",
+            AddTagsToResourceError::Service(_) => "ServiceException",
+            AddTagsToResourceError::TagLimitExceeded(_) => "TagLimitExceededException",
+        }
+    }
+}
 /// Errors returned by CancelSchemaExtension
 #[derive(Debug, PartialEq)]
 pub enum CancelSchemaExtensionError {
@@ -2594,6 +2647,15 @@ impl fmt::Display for CancelSchemaExtensionError {
     }
 }
 impl Error for CancelSchemaExtensionError {}
+impl ServiceErrorCode for CancelSchemaExtensionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelSchemaExtensionError::Client(_) => "ClientException",
+            CancelSchemaExtensionError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            CancelSchemaExtensionError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by ConnectDirectory
 #[derive(Debug, PartialEq)]
 pub enum ConnectDirectoryError {
@@ -2644,6 +2706,16 @@ impl fmt::Display for ConnectDirectoryError {
     }
 }
 impl Error for ConnectDirectoryError {}
+impl ServiceErrorCode for ConnectDirectoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ConnectDirectoryError::Client(_) => "ClientException",
+            ConnectDirectoryError::DirectoryLimitExceeded(_) => "DirectoryLimitExceededException",
+            ConnectDirectoryError::InvalidParameter(_) => "InvalidParameterException",
+            ConnectDirectoryError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by CreateAlias
 #[derive(Debug, PartialEq)]
 pub enum CreateAliasError {
@@ -2698,6 +2770,17 @@ impl fmt::Display for CreateAliasError {
     }
 }
 impl Error for CreateAliasError {}
+impl ServiceErrorCode for CreateAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAliasError::Client(_) => "ClientException",
+            CreateAliasError::EntityAlreadyExists(_) => "EntityAlreadyExistsException",
+            CreateAliasError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            CreateAliasError::InvalidParameter(_) => "InvalidParameterException",
+            CreateAliasError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by CreateComputer
 #[derive(Debug, PartialEq)]
 pub enum CreateComputerError {
@@ -2770,6 +2853,20 @@ impl fmt::Display for CreateComputerError {
     }
 }
 impl Error for CreateComputerError {}
+impl ServiceErrorCode for CreateComputerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateComputerError::AuthenticationFailed(_) => "AuthenticationFailedException",
+            CreateComputerError::Client(_) => "ClientException",
+            CreateComputerError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            CreateComputerError::EntityAlreadyExists(_) => "EntityAlreadyExistsException",
+            CreateComputerError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            CreateComputerError::InvalidParameter(_) => "InvalidParameterException",
+            CreateComputerError::Service(_) => "ServiceException",
+            CreateComputerError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by CreateConditionalForwarder
 #[derive(Debug, PartialEq)]
 pub enum CreateConditionalForwarderError {
@@ -2856,6 +2953,25 @@ impl fmt::Display for CreateConditionalForwarderError {
     }
 }
 impl Error for CreateConditionalForwarderError {}
+impl ServiceErrorCode for CreateConditionalForwarderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConditionalForwarderError::Client(_) => "ClientException",
+            CreateConditionalForwarderError::DirectoryUnavailable(_) => {
+                "DirectoryUnavailableException"
+            }
+            CreateConditionalForwarderError::EntityAlreadyExists(_) => {
+                "EntityAlreadyExistsException"
+            }
+            CreateConditionalForwarderError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            CreateConditionalForwarderError::InvalidParameter(_) => "InvalidParameterException",
+            CreateConditionalForwarderError::Service(_) => "ServiceException",
+            CreateConditionalForwarderError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDirectory
 #[derive(Debug, PartialEq)]
 pub enum CreateDirectoryError {
@@ -2906,6 +3022,16 @@ impl fmt::Display for CreateDirectoryError {
     }
 }
 impl Error for CreateDirectoryError {}
+impl ServiceErrorCode for CreateDirectoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDirectoryError::Client(_) => "ClientException",
+            CreateDirectoryError::DirectoryLimitExceeded(_) => "DirectoryLimitExceededException",
+            CreateDirectoryError::InvalidParameter(_) => "InvalidParameterException",
+            CreateDirectoryError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by CreateLogSubscription
 #[derive(Debug, PartialEq)]
 pub enum CreateLogSubscriptionError {
@@ -2976,6 +3102,20 @@ impl fmt::Display for CreateLogSubscriptionError {
     }
 }
 impl Error for CreateLogSubscriptionError {}
+impl ServiceErrorCode for CreateLogSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLogSubscriptionError::Client(_) => "ClientException",
+            CreateLogSubscriptionError::EntityAlreadyExists(_) => "EntityAlreadyExistsException",
+            CreateLogSubscriptionError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            CreateLogSubscriptionError::InsufficientPermissions(_) => {
+                "InsufficientPermissionsException"
+            }
+            CreateLogSubscriptionError::Service(_) => "ServiceException",
+            CreateLogSubscriptionError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by CreateMicrosoftAD
 #[derive(Debug, PartialEq)]
 pub enum CreateMicrosoftADError {
@@ -3034,6 +3174,17 @@ impl fmt::Display for CreateMicrosoftADError {
     }
 }
 impl Error for CreateMicrosoftADError {}
+impl ServiceErrorCode for CreateMicrosoftADError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMicrosoftADError::Client(_) => "ClientException",
+            CreateMicrosoftADError::DirectoryLimitExceeded(_) => "DirectoryLimitExceededException",
+            CreateMicrosoftADError::InvalidParameter(_) => "InvalidParameterException",
+            CreateMicrosoftADError::Service(_) => "ServiceException",
+            CreateMicrosoftADError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by CreateSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateSnapshotError {
@@ -3090,6 +3241,17 @@ impl fmt::Display for CreateSnapshotError {
     }
 }
 impl Error for CreateSnapshotError {}
+impl ServiceErrorCode for CreateSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSnapshotError::Client(_) => "ClientException",
+            CreateSnapshotError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            CreateSnapshotError::InvalidParameter(_) => "InvalidParameterException",
+            CreateSnapshotError::Service(_) => "ServiceException",
+            CreateSnapshotError::SnapshotLimitExceeded(_) => "SnapshotLimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateTrust
 #[derive(Debug, PartialEq)]
 pub enum CreateTrustError {
@@ -3150,6 +3312,18 @@ impl fmt::Display for CreateTrustError {
     }
 }
 impl Error for CreateTrustError {}
+impl ServiceErrorCode for CreateTrustError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTrustError::Client(_) => "ClientException",
+            CreateTrustError::EntityAlreadyExists(_) => "EntityAlreadyExistsException",
+            CreateTrustError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            CreateTrustError::InvalidParameter(_) => "InvalidParameterException",
+            CreateTrustError::Service(_) => "ServiceException",
+            CreateTrustError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DeleteConditionalForwarder
 #[derive(Debug, PartialEq)]
 pub enum DeleteConditionalForwarderError {
@@ -3226,6 +3400,22 @@ impl fmt::Display for DeleteConditionalForwarderError {
     }
 }
 impl Error for DeleteConditionalForwarderError {}
+impl ServiceErrorCode for DeleteConditionalForwarderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConditionalForwarderError::Client(_) => "ClientException",
+            DeleteConditionalForwarderError::DirectoryUnavailable(_) => {
+                "DirectoryUnavailableException"
+            }
+            DeleteConditionalForwarderError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            DeleteConditionalForwarderError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteConditionalForwarderError::Service(_) => "ServiceException",
+            DeleteConditionalForwarderError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDirectory
 #[derive(Debug, PartialEq)]
 pub enum DeleteDirectoryError {
@@ -3268,6 +3458,15 @@ impl fmt::Display for DeleteDirectoryError {
     }
 }
 impl Error for DeleteDirectoryError {}
+impl ServiceErrorCode for DeleteDirectoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDirectoryError::Client(_) => "ClientException",
+            DeleteDirectoryError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            DeleteDirectoryError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by DeleteLogSubscription
 #[derive(Debug, PartialEq)]
 pub enum DeleteLogSubscriptionError {
@@ -3320,6 +3519,16 @@ impl fmt::Display for DeleteLogSubscriptionError {
     }
 }
 impl Error for DeleteLogSubscriptionError {}
+impl ServiceErrorCode for DeleteLogSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLogSubscriptionError::Client(_) => "ClientException",
+            DeleteLogSubscriptionError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            DeleteLogSubscriptionError::Service(_) => "ServiceException",
+            DeleteLogSubscriptionError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DeleteSnapshot
 #[derive(Debug, PartialEq)]
 pub enum DeleteSnapshotError {
@@ -3368,6 +3577,16 @@ impl fmt::Display for DeleteSnapshotError {
     }
 }
 impl Error for DeleteSnapshotError {}
+impl ServiceErrorCode for DeleteSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSnapshotError::Client(_) => "ClientException",
+            DeleteSnapshotError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            DeleteSnapshotError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteSnapshotError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by DeleteTrust
 #[derive(Debug, PartialEq)]
 pub enum DeleteTrustError {
@@ -3422,6 +3641,17 @@ impl fmt::Display for DeleteTrustError {
     }
 }
 impl Error for DeleteTrustError {}
+impl ServiceErrorCode for DeleteTrustError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTrustError::Client(_) => "ClientException",
+            DeleteTrustError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            DeleteTrustError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteTrustError::Service(_) => "ServiceException",
+            DeleteTrustError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DeregisterCertificate
 #[derive(Debug, PartialEq)]
 pub enum DeregisterCertificateError {
@@ -3508,6 +3738,24 @@ impl fmt::Display for DeregisterCertificateError {
     }
 }
 impl Error for DeregisterCertificateError {}
+impl ServiceErrorCode for DeregisterCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterCertificateError::CertificateDoesNotExist(_) => {
+                "CertificateDoesNotExistException"
+            }
+            DeregisterCertificateError::CertificateInUse(_) => "CertificateInUseException",
+            DeregisterCertificateError::Client(_) => "ClientException",
+            DeregisterCertificateError::DirectoryDoesNotExist(_) => {
+                "DirectoryDoesNotExistException"
+            }
+            DeregisterCertificateError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            DeregisterCertificateError::InvalidParameter(_) => "InvalidParameterException",
+            DeregisterCertificateError::Service(_) => "ServiceException",
+            DeregisterCertificateError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DeregisterEventTopic
 #[derive(Debug, PartialEq)]
 pub enum DeregisterEventTopicError {
@@ -3560,6 +3808,16 @@ impl fmt::Display for DeregisterEventTopicError {
     }
 }
 impl Error for DeregisterEventTopicError {}
+impl ServiceErrorCode for DeregisterEventTopicError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterEventTopicError::Client(_) => "ClientException",
+            DeregisterEventTopicError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            DeregisterEventTopicError::InvalidParameter(_) => "InvalidParameterException",
+            DeregisterEventTopicError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by DescribeCertificate
 #[derive(Debug, PartialEq)]
 pub enum DescribeCertificateError {
@@ -3628,6 +3886,20 @@ impl fmt::Display for DescribeCertificateError {
     }
 }
 impl Error for DescribeCertificateError {}
+impl ServiceErrorCode for DescribeCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCertificateError::CertificateDoesNotExist(_) => {
+                "CertificateDoesNotExistException"
+            }
+            DescribeCertificateError::Client(_) => "ClientException",
+            DescribeCertificateError::DirectoryDoesNotExist(_) => "DirectoryDoesNotExistException",
+            DescribeCertificateError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeCertificateError::Service(_) => "ServiceException",
+            DescribeCertificateError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DescribeConditionalForwarders
 #[derive(Debug, PartialEq)]
 pub enum DescribeConditionalForwardersError {
@@ -3710,6 +3982,24 @@ impl fmt::Display for DescribeConditionalForwardersError {
     }
 }
 impl Error for DescribeConditionalForwardersError {}
+impl ServiceErrorCode for DescribeConditionalForwardersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConditionalForwardersError::Client(_) => "ClientException",
+            DescribeConditionalForwardersError::DirectoryUnavailable(_) => {
+                "DirectoryUnavailableException"
+            }
+            DescribeConditionalForwardersError::EntityDoesNotExist(_) => {
+                "EntityDoesNotExistException"
+            }
+            DescribeConditionalForwardersError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeConditionalForwardersError::Service(_) => "ServiceException",
+            DescribeConditionalForwardersError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDirectories
 #[derive(Debug, PartialEq)]
 pub enum DescribeDirectoriesError {
@@ -3770,6 +4060,17 @@ impl fmt::Display for DescribeDirectoriesError {
     }
 }
 impl Error for DescribeDirectoriesError {}
+impl ServiceErrorCode for DescribeDirectoriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDirectoriesError::Client(_) => "ClientException",
+            DescribeDirectoriesError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            DescribeDirectoriesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeDirectoriesError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeDirectoriesError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by DescribeDomainControllers
 #[derive(Debug, PartialEq)]
 pub enum DescribeDomainControllersError {
@@ -3840,6 +4141,20 @@ impl fmt::Display for DescribeDomainControllersError {
     }
 }
 impl Error for DescribeDomainControllersError {}
+impl ServiceErrorCode for DescribeDomainControllersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDomainControllersError::Client(_) => "ClientException",
+            DescribeDomainControllersError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            DescribeDomainControllersError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeDomainControllersError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeDomainControllersError::Service(_) => "ServiceException",
+            DescribeDomainControllersError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeEventTopics
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventTopicsError {
@@ -3892,6 +4207,16 @@ impl fmt::Display for DescribeEventTopicsError {
     }
 }
 impl Error for DescribeEventTopicsError {}
+impl ServiceErrorCode for DescribeEventTopicsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventTopicsError::Client(_) => "ClientException",
+            DescribeEventTopicsError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            DescribeEventTopicsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeEventTopicsError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by DescribeLDAPSSettings
 #[derive(Debug, PartialEq)]
 pub enum DescribeLDAPSSettingsError {
@@ -3960,6 +4285,20 @@ impl fmt::Display for DescribeLDAPSSettingsError {
     }
 }
 impl Error for DescribeLDAPSSettingsError {}
+impl ServiceErrorCode for DescribeLDAPSSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLDAPSSettingsError::Client(_) => "ClientException",
+            DescribeLDAPSSettingsError::DirectoryDoesNotExist(_) => {
+                "DirectoryDoesNotExistException"
+            }
+            DescribeLDAPSSettingsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeLDAPSSettingsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeLDAPSSettingsError::Service(_) => "ServiceException",
+            DescribeLDAPSSettingsError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DescribeRegions
 #[derive(Debug, PartialEq)]
 pub enum DescribeRegionsError {
@@ -4030,6 +4369,19 @@ impl fmt::Display for DescribeRegionsError {
     }
 }
 impl Error for DescribeRegionsError {}
+impl ServiceErrorCode for DescribeRegionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRegionsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeRegionsError::Client(_) => "ClientException",
+            DescribeRegionsError::DirectoryDoesNotExist(_) => "DirectoryDoesNotExistException",
+            DescribeRegionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeRegionsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeRegionsError::Service(_) => "ServiceException",
+            DescribeRegionsError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DescribeSharedDirectories
 #[derive(Debug, PartialEq)]
 pub enum DescribeSharedDirectoriesError {
@@ -4100,6 +4452,20 @@ impl fmt::Display for DescribeSharedDirectoriesError {
     }
 }
 impl Error for DescribeSharedDirectoriesError {}
+impl ServiceErrorCode for DescribeSharedDirectoriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSharedDirectoriesError::Client(_) => "ClientException",
+            DescribeSharedDirectoriesError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            DescribeSharedDirectoriesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeSharedDirectoriesError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeSharedDirectoriesError::Service(_) => "ServiceException",
+            DescribeSharedDirectoriesError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeSnapshots
 #[derive(Debug, PartialEq)]
 pub enum DescribeSnapshotsError {
@@ -4156,6 +4522,17 @@ impl fmt::Display for DescribeSnapshotsError {
     }
 }
 impl Error for DescribeSnapshotsError {}
+impl ServiceErrorCode for DescribeSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSnapshotsError::Client(_) => "ClientException",
+            DescribeSnapshotsError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            DescribeSnapshotsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeSnapshotsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeSnapshotsError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by DescribeTrusts
 #[derive(Debug, PartialEq)]
 pub enum DescribeTrustsError {
@@ -4216,6 +4593,18 @@ impl fmt::Display for DescribeTrustsError {
     }
 }
 impl Error for DescribeTrustsError {}
+impl ServiceErrorCode for DescribeTrustsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTrustsError::Client(_) => "ClientException",
+            DescribeTrustsError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            DescribeTrustsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeTrustsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeTrustsError::Service(_) => "ServiceException",
+            DescribeTrustsError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DisableClientAuthentication
 #[derive(Debug, PartialEq)]
 pub enum DisableClientAuthenticationError {
@@ -4292,6 +4681,24 @@ impl fmt::Display for DisableClientAuthenticationError {
     }
 }
 impl Error for DisableClientAuthenticationError {}
+impl ServiceErrorCode for DisableClientAuthenticationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableClientAuthenticationError::AccessDenied(_) => "AccessDeniedException",
+            DisableClientAuthenticationError::Client(_) => "ClientException",
+            DisableClientAuthenticationError::DirectoryDoesNotExist(_) => {
+                "DirectoryDoesNotExistException"
+            }
+            DisableClientAuthenticationError::InvalidClientAuthStatus(_) => {
+                "InvalidClientAuthStatusException"
+            }
+            DisableClientAuthenticationError::Service(_) => "ServiceException",
+            DisableClientAuthenticationError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DisableLDAPS
 #[derive(Debug, PartialEq)]
 pub enum DisableLDAPSError {
@@ -4358,6 +4765,19 @@ impl fmt::Display for DisableLDAPSError {
     }
 }
 impl Error for DisableLDAPSError {}
+impl ServiceErrorCode for DisableLDAPSError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableLDAPSError::Client(_) => "ClientException",
+            DisableLDAPSError::DirectoryDoesNotExist(_) => "DirectoryDoesNotExistException",
+            DisableLDAPSError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            DisableLDAPSError::InvalidLDAPSStatus(_) => "InvalidLDAPSStatusException",
+            DisableLDAPSError::InvalidParameter(_) => "InvalidParameterException",
+            DisableLDAPSError::Service(_) => "ServiceException",
+            DisableLDAPSError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DisableRadius
 #[derive(Debug, PartialEq)]
 pub enum DisableRadiusError {
@@ -4400,6 +4820,15 @@ impl fmt::Display for DisableRadiusError {
     }
 }
 impl Error for DisableRadiusError {}
+impl ServiceErrorCode for DisableRadiusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableRadiusError::Client(_) => "ClientException",
+            DisableRadiusError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            DisableRadiusError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by DisableSso
 #[derive(Debug, PartialEq)]
 pub enum DisableSsoError {
@@ -4452,6 +4881,17 @@ impl fmt::Display for DisableSsoError {
     }
 }
 impl Error for DisableSsoError {}
+impl ServiceErrorCode for DisableSsoError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableSsoError::AuthenticationFailed(_) => "AuthenticationFailedException",
+            DisableSsoError::Client(_) => "ClientException",
+            DisableSsoError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            DisableSsoError::InsufficientPermissions(_) => "InsufficientPermissionsException",
+            DisableSsoError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by EnableClientAuthentication
 #[derive(Debug, PartialEq)]
 pub enum EnableClientAuthenticationError {
@@ -4538,6 +4978,27 @@ impl fmt::Display for EnableClientAuthenticationError {
     }
 }
 impl Error for EnableClientAuthenticationError {}
+impl ServiceErrorCode for EnableClientAuthenticationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableClientAuthenticationError::AccessDenied(_) => "AccessDeniedException",
+            EnableClientAuthenticationError::Client(_) => "ClientException",
+            EnableClientAuthenticationError::DirectoryDoesNotExist(_) => {
+                "DirectoryDoesNotExistException"
+            }
+            EnableClientAuthenticationError::InvalidClientAuthStatus(_) => {
+                "InvalidClientAuthStatusException"
+            }
+            EnableClientAuthenticationError::NoAvailableCertificate(_) => {
+                "NoAvailableCertificateException"
+            }
+            EnableClientAuthenticationError::Service(_) => "ServiceException",
+            EnableClientAuthenticationError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by EnableLDAPS
 #[derive(Debug, PartialEq)]
 pub enum EnableLDAPSError {
@@ -4610,6 +5071,20 @@ impl fmt::Display for EnableLDAPSError {
     }
 }
 impl Error for EnableLDAPSError {}
+impl ServiceErrorCode for EnableLDAPSError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableLDAPSError::Client(_) => "ClientException",
+            EnableLDAPSError::DirectoryDoesNotExist(_) => "DirectoryDoesNotExistException",
+            EnableLDAPSError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            EnableLDAPSError::InvalidLDAPSStatus(_) => "InvalidLDAPSStatusException",
+            EnableLDAPSError::InvalidParameter(_) => "InvalidParameterException",
+            EnableLDAPSError::NoAvailableCertificate(_) => "NoAvailableCertificateException",
+            EnableLDAPSError::Service(_) => "ServiceException",
+            EnableLDAPSError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by EnableRadius
 #[derive(Debug, PartialEq)]
 pub enum EnableRadiusError {
@@ -4664,6 +5139,17 @@ impl fmt::Display for EnableRadiusError {
     }
 }
 impl Error for EnableRadiusError {}
+impl ServiceErrorCode for EnableRadiusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableRadiusError::Client(_) => "ClientException",
+            EnableRadiusError::EntityAlreadyExists(_) => "EntityAlreadyExistsException",
+            EnableRadiusError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            EnableRadiusError::InvalidParameter(_) => "InvalidParameterException",
+            EnableRadiusError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by EnableSso
 #[derive(Debug, PartialEq)]
 pub enum EnableSsoError {
@@ -4716,6 +5202,17 @@ impl fmt::Display for EnableSsoError {
     }
 }
 impl Error for EnableSsoError {}
+impl ServiceErrorCode for EnableSsoError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableSsoError::AuthenticationFailed(_) => "AuthenticationFailedException",
+            EnableSsoError::Client(_) => "ClientException",
+            EnableSsoError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            EnableSsoError::InsufficientPermissions(_) => "InsufficientPermissionsException",
+            EnableSsoError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by GetDirectoryLimits
 #[derive(Debug, PartialEq)]
 pub enum GetDirectoryLimitsError {
@@ -4760,6 +5257,15 @@ impl fmt::Display for GetDirectoryLimitsError {
     }
 }
 impl Error for GetDirectoryLimitsError {}
+impl ServiceErrorCode for GetDirectoryLimitsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDirectoryLimitsError::Client(_) => "ClientException",
+            GetDirectoryLimitsError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            GetDirectoryLimitsError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by GetSnapshotLimits
 #[derive(Debug, PartialEq)]
 pub enum GetSnapshotLimitsError {
@@ -4804,6 +5310,15 @@ impl fmt::Display for GetSnapshotLimitsError {
     }
 }
 impl Error for GetSnapshotLimitsError {}
+impl ServiceErrorCode for GetSnapshotLimitsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSnapshotLimitsError::Client(_) => "ClientException",
+            GetSnapshotLimitsError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            GetSnapshotLimitsError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by ListCertificates
 #[derive(Debug, PartialEq)]
 pub enum ListCertificatesError {
@@ -4868,6 +5383,18 @@ impl fmt::Display for ListCertificatesError {
     }
 }
 impl Error for ListCertificatesError {}
+impl ServiceErrorCode for ListCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCertificatesError::Client(_) => "ClientException",
+            ListCertificatesError::DirectoryDoesNotExist(_) => "DirectoryDoesNotExistException",
+            ListCertificatesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListCertificatesError::InvalidParameter(_) => "InvalidParameterException",
+            ListCertificatesError::Service(_) => "ServiceException",
+            ListCertificatesError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by ListIpRoutes
 #[derive(Debug, PartialEq)]
 pub enum ListIpRoutesError {
@@ -4922,6 +5449,17 @@ impl fmt::Display for ListIpRoutesError {
     }
 }
 impl Error for ListIpRoutesError {}
+impl ServiceErrorCode for ListIpRoutesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListIpRoutesError::Client(_) => "ClientException",
+            ListIpRoutesError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            ListIpRoutesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListIpRoutesError::InvalidParameter(_) => "InvalidParameterException",
+            ListIpRoutesError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by ListLogSubscriptions
 #[derive(Debug, PartialEq)]
 pub enum ListLogSubscriptionsError {
@@ -4974,6 +5512,16 @@ impl fmt::Display for ListLogSubscriptionsError {
     }
 }
 impl Error for ListLogSubscriptionsError {}
+impl ServiceErrorCode for ListLogSubscriptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLogSubscriptionsError::Client(_) => "ClientException",
+            ListLogSubscriptionsError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            ListLogSubscriptionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListLogSubscriptionsError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by ListSchemaExtensions
 #[derive(Debug, PartialEq)]
 pub enum ListSchemaExtensionsError {
@@ -5026,6 +5574,16 @@ impl fmt::Display for ListSchemaExtensionsError {
     }
 }
 impl Error for ListSchemaExtensionsError {}
+impl ServiceErrorCode for ListSchemaExtensionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSchemaExtensionsError::Client(_) => "ClientException",
+            ListSchemaExtensionsError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            ListSchemaExtensionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListSchemaExtensionsError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -5086,6 +5644,17 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::Client(_) => "ClientException",
+            ListTagsForResourceError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            ListTagsForResourceError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListTagsForResourceError::InvalidParameter(_) => "InvalidParameterException",
+            ListTagsForResourceError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by RegisterCertificate
 #[derive(Debug, PartialEq)]
 pub enum RegisterCertificateError {
@@ -5178,6 +5747,25 @@ impl fmt::Display for RegisterCertificateError {
     }
 }
 impl Error for RegisterCertificateError {}
+impl ServiceErrorCode for RegisterCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterCertificateError::CertificateAlreadyExists(_) => {
+                "CertificateAlreadyExistsException"
+            }
+            RegisterCertificateError::CertificateLimitExceeded(_) => {
+                "CertificateLimitExceededException"
+            }
+            RegisterCertificateError::Client(_) => "ClientException",
+            RegisterCertificateError::DirectoryDoesNotExist(_) => "DirectoryDoesNotExistException",
+            RegisterCertificateError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            RegisterCertificateError::InvalidCertificate(_) => "InvalidCertificateException",
+            RegisterCertificateError::InvalidParameter(_) => "InvalidParameterException",
+            RegisterCertificateError::Service(_) => "ServiceException",
+            RegisterCertificateError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by RegisterEventTopic
 #[derive(Debug, PartialEq)]
 pub enum RegisterEventTopicError {
@@ -5228,6 +5816,16 @@ impl fmt::Display for RegisterEventTopicError {
     }
 }
 impl Error for RegisterEventTopicError {}
+impl ServiceErrorCode for RegisterEventTopicError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterEventTopicError::Client(_) => "ClientException",
+            RegisterEventTopicError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            RegisterEventTopicError::InvalidParameter(_) => "InvalidParameterException",
+            RegisterEventTopicError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by RejectSharedDirectory
 #[derive(Debug, PartialEq)]
 pub enum RejectSharedDirectoryError {
@@ -5288,6 +5886,19 @@ impl fmt::Display for RejectSharedDirectoryError {
     }
 }
 impl Error for RejectSharedDirectoryError {}
+impl ServiceErrorCode for RejectSharedDirectoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RejectSharedDirectoryError::Client(_) => "ClientException",
+            RejectSharedDirectoryError::DirectoryAlreadyShared(_) => {
+                "DirectoryAlreadySharedException"
+            }
+            RejectSharedDirectoryError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            RejectSharedDirectoryError::InvalidParameter(_) => "InvalidParameterException",
+            RejectSharedDirectoryError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by RemoveIpRoutes
 #[derive(Debug, PartialEq)]
 pub enum RemoveIpRoutesError {
@@ -5342,6 +5953,17 @@ impl fmt::Display for RemoveIpRoutesError {
     }
 }
 impl Error for RemoveIpRoutesError {}
+impl ServiceErrorCode for RemoveIpRoutesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveIpRoutesError::Client(_) => "ClientException",
+            RemoveIpRoutesError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            RemoveIpRoutesError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            RemoveIpRoutesError::InvalidParameter(_) => "InvalidParameterException",
+            RemoveIpRoutesError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by RemoveRegion
 #[derive(Debug, PartialEq)]
 pub enum RemoveRegionError {
@@ -5402,6 +6024,18 @@ impl fmt::Display for RemoveRegionError {
     }
 }
 impl Error for RemoveRegionError {}
+impl ServiceErrorCode for RemoveRegionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveRegionError::AccessDenied(_) => "AccessDeniedException",
+            RemoveRegionError::Client(_) => "ClientException",
+            RemoveRegionError::DirectoryDoesNotExist(_) => "DirectoryDoesNotExistException",
+            RemoveRegionError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            RemoveRegionError::Service(_) => "ServiceException",
+            RemoveRegionError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromResourceError {
@@ -5454,6 +6088,16 @@ impl fmt::Display for RemoveTagsFromResourceError {
     }
 }
 impl Error for RemoveTagsFromResourceError {}
+impl ServiceErrorCode for RemoveTagsFromResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsFromResourceError::Client(_) => "ClientException",
+            RemoveTagsFromResourceError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            RemoveTagsFromResourceError::InvalidParameter(_) => "InvalidParameterException",
+            RemoveTagsFromResourceError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by ResetUserPassword
 #[derive(Debug, PartialEq)]
 pub enum ResetUserPasswordError {
@@ -5526,6 +6170,19 @@ impl fmt::Display for ResetUserPasswordError {
     }
 }
 impl Error for ResetUserPasswordError {}
+impl ServiceErrorCode for ResetUserPasswordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetUserPasswordError::Client(_) => "ClientException",
+            ResetUserPasswordError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            ResetUserPasswordError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            ResetUserPasswordError::InvalidPassword(_) => "InvalidPasswordException",
+            ResetUserPasswordError::Service(_) => "ServiceException",
+            ResetUserPasswordError::UnsupportedOperation(_) => "UnsupportedOperationException",
+            ResetUserPasswordError::UserDoesNotExist(_) => "UserDoesNotExistException",
+        }
+    }
+}
 /// Errors returned by RestoreFromSnapshot
 #[derive(Debug, PartialEq)]
 pub enum RestoreFromSnapshotError {
@@ -5578,6 +6235,16 @@ impl fmt::Display for RestoreFromSnapshotError {
     }
 }
 impl Error for RestoreFromSnapshotError {}
+impl ServiceErrorCode for RestoreFromSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreFromSnapshotError::Client(_) => "ClientException",
+            RestoreFromSnapshotError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            RestoreFromSnapshotError::InvalidParameter(_) => "InvalidParameterException",
+            RestoreFromSnapshotError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by ShareDirectory
 #[derive(Debug, PartialEq)]
 pub enum ShareDirectoryError {
@@ -5664,6 +6331,22 @@ impl fmt::Display for ShareDirectoryError {
     }
 }
 impl Error for ShareDirectoryError {}
+impl ServiceErrorCode for ShareDirectoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ShareDirectoryError::AccessDenied(_) => "AccessDeniedException",
+            ShareDirectoryError::Client(_) => "ClientException",
+            ShareDirectoryError::DirectoryAlreadyShared(_) => "DirectoryAlreadySharedException",
+            ShareDirectoryError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            ShareDirectoryError::InvalidParameter(_) => "InvalidParameterException",
+            ShareDirectoryError::InvalidTarget(_) => "InvalidTargetException",
+            ShareDirectoryError::Organizations(_) => "OrganizationsException",
+            ShareDirectoryError::Service(_) => "ServiceException",
+            ShareDirectoryError::ShareLimitExceeded(_) => "ShareLimitExceededException",
+            ShareDirectoryError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by StartSchemaExtension
 #[derive(Debug, PartialEq)]
 pub enum StartSchemaExtensionError {
@@ -5732,6 +6415,18 @@ impl fmt::Display for StartSchemaExtensionError {
     }
 }
 impl Error for StartSchemaExtensionError {}
+impl ServiceErrorCode for StartSchemaExtensionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartSchemaExtensionError::Client(_) => "ClientException",
+            StartSchemaExtensionError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            StartSchemaExtensionError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            StartSchemaExtensionError::InvalidParameter(_) => "InvalidParameterException",
+            StartSchemaExtensionError::Service(_) => "ServiceException",
+            StartSchemaExtensionError::SnapshotLimitExceeded(_) => "SnapshotLimitExceededException",
+        }
+    }
+}
 /// Errors returned by UnshareDirectory
 #[derive(Debug, PartialEq)]
 pub enum UnshareDirectoryError {
@@ -5786,6 +6481,17 @@ impl fmt::Display for UnshareDirectoryError {
     }
 }
 impl Error for UnshareDirectoryError {}
+impl ServiceErrorCode for UnshareDirectoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UnshareDirectoryError::Client(_) => "ClientException",
+            UnshareDirectoryError::DirectoryNotShared(_) => "DirectoryNotSharedException",
+            UnshareDirectoryError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            UnshareDirectoryError::InvalidTarget(_) => "InvalidTargetException",
+            UnshareDirectoryError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by UpdateConditionalForwarder
 #[derive(Debug, PartialEq)]
 pub enum UpdateConditionalForwarderError {
@@ -5862,6 +6568,22 @@ impl fmt::Display for UpdateConditionalForwarderError {
     }
 }
 impl Error for UpdateConditionalForwarderError {}
+impl ServiceErrorCode for UpdateConditionalForwarderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConditionalForwarderError::Client(_) => "ClientException",
+            UpdateConditionalForwarderError::DirectoryUnavailable(_) => {
+                "DirectoryUnavailableException"
+            }
+            UpdateConditionalForwarderError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            UpdateConditionalForwarderError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateConditionalForwarderError::Service(_) => "ServiceException",
+            UpdateConditionalForwarderError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateNumberOfDomainControllers
 #[derive(Debug, PartialEq)]
 pub enum UpdateNumberOfDomainControllersError {
@@ -5956,6 +6678,29 @@ impl fmt::Display for UpdateNumberOfDomainControllersError {
     }
 }
 impl Error for UpdateNumberOfDomainControllersError {}
+impl ServiceErrorCode for UpdateNumberOfDomainControllersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateNumberOfDomainControllersError::Client(_) => "ClientException",
+            UpdateNumberOfDomainControllersError::DirectoryUnavailable(_) => {
+                "DirectoryUnavailableException"
+            }
+            UpdateNumberOfDomainControllersError::DomainControllerLimitExceeded(_) => {
+                "DomainControllerLimitExceededException"
+            }
+            UpdateNumberOfDomainControllersError::EntityDoesNotExist(_) => {
+                "EntityDoesNotExistException"
+            }
+            UpdateNumberOfDomainControllersError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            UpdateNumberOfDomainControllersError::Service(_) => "ServiceException",
+            UpdateNumberOfDomainControllersError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateRadius
 #[derive(Debug, PartialEq)]
 pub enum UpdateRadiusError {
@@ -6004,6 +6749,16 @@ impl fmt::Display for UpdateRadiusError {
     }
 }
 impl Error for UpdateRadiusError {}
+impl ServiceErrorCode for UpdateRadiusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRadiusError::Client(_) => "ClientException",
+            UpdateRadiusError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            UpdateRadiusError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateRadiusError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by UpdateTrust
 #[derive(Debug, PartialEq)]
 pub enum UpdateTrustError {
@@ -6052,6 +6807,16 @@ impl fmt::Display for UpdateTrustError {
     }
 }
 impl Error for UpdateTrustError {}
+impl ServiceErrorCode for UpdateTrustError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTrustError::Client(_) => "ClientException",
+            UpdateTrustError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            UpdateTrustError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateTrustError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by VerifyTrust
 #[derive(Debug, PartialEq)]
 pub enum VerifyTrustError {
@@ -6106,6 +6871,17 @@ impl fmt::Display for VerifyTrustError {
     }
 }
 impl Error for VerifyTrustError {}
+impl ServiceErrorCode for VerifyTrustError {
+    fn error_code(&self) -> &str {
+        match *self {
+            VerifyTrustError::Client(_) => "ClientException",
+            VerifyTrustError::EntityDoesNotExist(_) => "EntityDoesNotExistException",
+            VerifyTrustError::InvalidParameter(_) => "InvalidParameterException",
+            VerifyTrustError::Service(_) => "ServiceException",
+            VerifyTrustError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Directory Service API. Directory Service clients implement this trait.
 #[async_trait]
 pub trait DirectoryService {
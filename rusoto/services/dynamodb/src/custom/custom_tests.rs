@@ -1,4 +1,9 @@
-use crate::generated::AttributeValue;
+extern crate rusoto_mock;
+
+use crate::generated::{AttributeValue, DynamoDb, DynamoDbClient, GetItemError, GetItemInput};
+
+use self::rusoto_mock::*;
+use rusoto_core::{Region, RusotoError};
 
 #[test]
 fn attribute_value_default_is_empty() {
@@ -46,3 +51,28 @@ fn attribute_value_with_binary_set() {
     let serialized = serde_json::to_string(&all_default).unwrap();
     assert_eq!(&serialized, r#"{"BS":["Zm9v","YmFy","YmF6"]}"#);
 }
+
+#[tokio::test]
+async fn service_errors_have_their_error_code() {
+    let mock = MockRequestDispatcher::with_status(400).with_body(
+        r#"{"__type":"com.amazonaws.dynamodb.v20120810#ResourceNotFoundException","message":"Requested resource not found"}"#,
+    );
+    let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let err = client
+        .get_item(GetItemInput {
+            table_name: "table".to_owned(),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        RusotoError::Service(GetItemError::ResourceNotFound(
+            "Requested resource not found".to_owned()
+        ))
+    );
+    assert_eq!(
+        err.error_code().as_deref(),
+        Some("ResourceNotFoundException")
+    );
+}
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1242,6 +1242,7 @@ pub struct ExecuteStatementOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ExecuteTransactionInput {
     /// <p> Set this value to get remaining results, if <code>NextToken</code> was returned in the statement response. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -1381,6 +1382,7 @@ pub struct ExportSummary {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ExportTableToPointInTimeInput {
     /// <p>Providing a <code>ClientToken</code> makes the call to <code>ExportTableToPointInTimeInput</code> idempotent, meaning that multiple identical calls have the same effect as one single call.</p> <p>A client token is valid for 8 hours after the first request that uses it is completed. After 8 hours, any request with the same client token is treated as a new request. Do not resubmit the same request with the same client token for more than 8 hours, or the result might not be idempotent.</p> <p>If you submit a request with the same client token but a change in other parameters within the 8-hour idempotency window, DynamoDB returns an <code>IdempotentParameterMismatch</code> exception.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -3150,6 +3152,7 @@ pub struct TransactWriteItem {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct TransactWriteItemsInput {
     /// <p>Providing a <code>ClientRequestToken</code> makes the call to <code>TransactWriteItems</code> idempotent, meaning that multiple identical calls have the same effect as one single call.</p> <p>Although multiple identical calls using the same client request token produce the same result on the server (no side effects), the responses to the calls might not be the same. If the <code>ReturnConsumedCapacity&gt;</code> parameter is set, then the initial <code>TransactWriteItems</code> call returns the amount of write capacity units consumed in making the changes. Subsequent <code>TransactWriteItems</code> calls with the same client token return the number of read capacity units consumed in reading the item.</p> <p>A client request token is valid for 10 minutes after the first request that uses it is completed. After 10 minutes, any request with the same client token is treated as a new request. Do not resubmit the same request with the same client token for more than 10 minutes, or the result might not be idempotent.</p> <p>If you submit a request with the same client token but a change in other parameters within the 10-minute idempotency window, DynamoDB returns an <code>IdempotentParameterMismatch</code> exception.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -3587,6 +3590,14 @@ impl fmt::Display for BatchExecuteStatementError {
     }
 }
 impl Error for BatchExecuteStatementError {}
+impl ServiceErrorCode for BatchExecuteStatementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchExecuteStatementError::InternalServerError(_) => "InternalServerError",
+            BatchExecuteStatementError::RequestLimitExceeded(_) => "RequestLimitExceeded",
+        }
+    }
+}
 /// Errors returned by BatchGetItem
 #[derive(Debug, PartialEq)]
 pub enum BatchGetItemError {
@@ -3637,6 +3648,18 @@ impl fmt::Display for BatchGetItemError {
     }
 }
 impl Error for BatchGetItemError {}
+impl ServiceErrorCode for BatchGetItemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetItemError::InternalServerError(_) => "InternalServerError",
+            BatchGetItemError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            BatchGetItemError::RequestLimitExceeded(_) => "RequestLimitExceeded",
+            BatchGetItemError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by BatchWriteItem
 #[derive(Debug, PartialEq)]
 pub enum BatchWriteItemError {
@@ -3697,6 +3720,21 @@ impl fmt::Display for BatchWriteItemError {
     }
 }
 impl Error for BatchWriteItemError {}
+impl ServiceErrorCode for BatchWriteItemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchWriteItemError::InternalServerError(_) => "InternalServerError",
+            BatchWriteItemError::ItemCollectionSizeLimitExceeded(_) => {
+                "ItemCollectionSizeLimitExceededException"
+            }
+            BatchWriteItemError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            BatchWriteItemError::RequestLimitExceeded(_) => "RequestLimitExceeded",
+            BatchWriteItemError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateBackup
 #[derive(Debug, PartialEq)]
 pub enum CreateBackupError {
@@ -3759,6 +3797,20 @@ impl fmt::Display for CreateBackupError {
     }
 }
 impl Error for CreateBackupError {}
+impl ServiceErrorCode for CreateBackupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBackupError::BackupInUse(_) => "BackupInUseException",
+            CreateBackupError::ContinuousBackupsUnavailable(_) => {
+                "ContinuousBackupsUnavailableException"
+            }
+            CreateBackupError::InternalServerError(_) => "InternalServerError",
+            CreateBackupError::LimitExceeded(_) => "LimitExceededException",
+            CreateBackupError::TableInUse(_) => "TableInUseException",
+            CreateBackupError::TableNotFound(_) => "TableNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateGlobalTable
 #[derive(Debug, PartialEq)]
 pub enum CreateGlobalTableError {
@@ -3811,6 +3863,18 @@ impl fmt::Display for CreateGlobalTableError {
     }
 }
 impl Error for CreateGlobalTableError {}
+impl ServiceErrorCode for CreateGlobalTableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGlobalTableError::GlobalTableAlreadyExists(_) => {
+                "GlobalTableAlreadyExistsException"
+            }
+            CreateGlobalTableError::InternalServerError(_) => "InternalServerError",
+            CreateGlobalTableError::LimitExceeded(_) => "LimitExceededException",
+            CreateGlobalTableError::TableNotFound(_) => "TableNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateTable
 #[derive(Debug, PartialEq)]
 pub enum CreateTableError {
@@ -3853,6 +3917,15 @@ impl fmt::Display for CreateTableError {
     }
 }
 impl Error for CreateTableError {}
+impl ServiceErrorCode for CreateTableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTableError::InternalServerError(_) => "InternalServerError",
+            CreateTableError::LimitExceeded(_) => "LimitExceededException",
+            CreateTableError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteBackup
 #[derive(Debug, PartialEq)]
 pub enum DeleteBackupError {
@@ -3901,6 +3974,16 @@ impl fmt::Display for DeleteBackupError {
     }
 }
 impl Error for DeleteBackupError {}
+impl ServiceErrorCode for DeleteBackupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBackupError::BackupInUse(_) => "BackupInUseException",
+            DeleteBackupError::BackupNotFound(_) => "BackupNotFoundException",
+            DeleteBackupError::InternalServerError(_) => "InternalServerError",
+            DeleteBackupError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DeleteItem
 #[derive(Debug, PartialEq)]
 pub enum DeleteItemError {
@@ -3971,6 +4054,23 @@ impl fmt::Display for DeleteItemError {
     }
 }
 impl Error for DeleteItemError {}
+impl ServiceErrorCode for DeleteItemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteItemError::ConditionalCheckFailed(_) => "ConditionalCheckFailedException",
+            DeleteItemError::InternalServerError(_) => "InternalServerError",
+            DeleteItemError::ItemCollectionSizeLimitExceeded(_) => {
+                "ItemCollectionSizeLimitExceededException"
+            }
+            DeleteItemError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DeleteItemError::RequestLimitExceeded(_) => "RequestLimitExceeded",
+            DeleteItemError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteItemError::TransactionConflict(_) => "TransactionConflictException",
+        }
+    }
+}
 /// Errors returned by DeleteTable
 #[derive(Debug, PartialEq)]
 pub enum DeleteTableError {
@@ -4019,6 +4119,16 @@ impl fmt::Display for DeleteTableError {
     }
 }
 impl Error for DeleteTableError {}
+impl ServiceErrorCode for DeleteTableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTableError::InternalServerError(_) => "InternalServerError",
+            DeleteTableError::LimitExceeded(_) => "LimitExceededException",
+            DeleteTableError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteTableError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeBackup
 #[derive(Debug, PartialEq)]
 pub enum DescribeBackupError {
@@ -4055,6 +4165,14 @@ impl fmt::Display for DescribeBackupError {
     }
 }
 impl Error for DescribeBackupError {}
+impl ServiceErrorCode for DescribeBackupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBackupError::BackupNotFound(_) => "BackupNotFoundException",
+            DescribeBackupError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeContinuousBackups
 #[derive(Debug, PartialEq)]
 pub enum DescribeContinuousBackupsError {
@@ -4097,6 +4215,14 @@ impl fmt::Display for DescribeContinuousBackupsError {
     }
 }
 impl Error for DescribeContinuousBackupsError {}
+impl ServiceErrorCode for DescribeContinuousBackupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeContinuousBackupsError::InternalServerError(_) => "InternalServerError",
+            DescribeContinuousBackupsError::TableNotFound(_) => "TableNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeContributorInsights
 #[derive(Debug, PartialEq)]
 pub enum DescribeContributorInsightsError {
@@ -4141,6 +4267,14 @@ impl fmt::Display for DescribeContributorInsightsError {
     }
 }
 impl Error for DescribeContributorInsightsError {}
+impl ServiceErrorCode for DescribeContributorInsightsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeContributorInsightsError::InternalServerError(_) => "InternalServerError",
+            DescribeContributorInsightsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeEndpoints
 #[derive(Debug, PartialEq)]
 pub enum DescribeEndpointsError {}
@@ -4163,6 +4297,11 @@ impl fmt::Display for DescribeEndpointsError {
     }
 }
 impl Error for DescribeEndpointsError {}
+impl ServiceErrorCode for DescribeEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeExport
 #[derive(Debug, PartialEq)]
 pub enum DescribeExportError {
@@ -4205,6 +4344,15 @@ impl fmt::Display for DescribeExportError {
     }
 }
 impl Error for DescribeExportError {}
+impl ServiceErrorCode for DescribeExportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeExportError::ExportNotFound(_) => "ExportNotFoundException",
+            DescribeExportError::InternalServerError(_) => "InternalServerError",
+            DescribeExportError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DescribeGlobalTable
 #[derive(Debug, PartialEq)]
 pub enum DescribeGlobalTableError {
@@ -4245,6 +4393,14 @@ impl fmt::Display for DescribeGlobalTableError {
     }
 }
 impl Error for DescribeGlobalTableError {}
+impl ServiceErrorCode for DescribeGlobalTableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGlobalTableError::GlobalTableNotFound(_) => "GlobalTableNotFoundException",
+            DescribeGlobalTableError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeGlobalTableSettings
 #[derive(Debug, PartialEq)]
 pub enum DescribeGlobalTableSettingsError {
@@ -4291,6 +4447,16 @@ impl fmt::Display for DescribeGlobalTableSettingsError {
     }
 }
 impl Error for DescribeGlobalTableSettingsError {}
+impl ServiceErrorCode for DescribeGlobalTableSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGlobalTableSettingsError::GlobalTableNotFound(_) => {
+                "GlobalTableNotFoundException"
+            }
+            DescribeGlobalTableSettingsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeKinesisStreamingDestination
 #[derive(Debug, PartialEq)]
 pub enum DescribeKinesisStreamingDestinationError {
@@ -4337,6 +4503,18 @@ impl fmt::Display for DescribeKinesisStreamingDestinationError {
     }
 }
 impl Error for DescribeKinesisStreamingDestinationError {}
+impl ServiceErrorCode for DescribeKinesisStreamingDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeKinesisStreamingDestinationError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+            DescribeKinesisStreamingDestinationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeLimits
 #[derive(Debug, PartialEq)]
 pub enum DescribeLimitsError {
@@ -4367,6 +4545,13 @@ impl fmt::Display for DescribeLimitsError {
     }
 }
 impl Error for DescribeLimitsError {}
+impl ServiceErrorCode for DescribeLimitsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLimitsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeTable
 #[derive(Debug, PartialEq)]
 pub enum DescribeTableError {
@@ -4403,6 +4588,14 @@ impl fmt::Display for DescribeTableError {
     }
 }
 impl Error for DescribeTableError {}
+impl ServiceErrorCode for DescribeTableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTableError::InternalServerError(_) => "InternalServerError",
+            DescribeTableError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeTableReplicaAutoScaling
 #[derive(Debug, PartialEq)]
 pub enum DescribeTableReplicaAutoScalingError {
@@ -4449,6 +4642,16 @@ impl fmt::Display for DescribeTableReplicaAutoScalingError {
     }
 }
 impl Error for DescribeTableReplicaAutoScalingError {}
+impl ServiceErrorCode for DescribeTableReplicaAutoScalingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTableReplicaAutoScalingError::InternalServerError(_) => "InternalServerError",
+            DescribeTableReplicaAutoScalingError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeTimeToLive
 #[derive(Debug, PartialEq)]
 pub enum DescribeTimeToLiveError {
@@ -4487,6 +4690,14 @@ impl fmt::Display for DescribeTimeToLiveError {
     }
 }
 impl Error for DescribeTimeToLiveError {}
+impl ServiceErrorCode for DescribeTimeToLiveError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTimeToLiveError::InternalServerError(_) => "InternalServerError",
+            DescribeTimeToLiveError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisableKinesisStreamingDestination
 #[derive(Debug, PartialEq)]
 pub enum DisableKinesisStreamingDestinationError {
@@ -4553,6 +4764,20 @@ impl fmt::Display for DisableKinesisStreamingDestinationError {
     }
 }
 impl Error for DisableKinesisStreamingDestinationError {}
+impl ServiceErrorCode for DisableKinesisStreamingDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableKinesisStreamingDestinationError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+            DisableKinesisStreamingDestinationError::LimitExceeded(_) => "LimitExceededException",
+            DisableKinesisStreamingDestinationError::ResourceInUse(_) => "ResourceInUseException",
+            DisableKinesisStreamingDestinationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by EnableKinesisStreamingDestination
 #[derive(Debug, PartialEq)]
 pub enum EnableKinesisStreamingDestinationError {
@@ -4619,6 +4844,18 @@ impl fmt::Display for EnableKinesisStreamingDestinationError {
     }
 }
 impl Error for EnableKinesisStreamingDestinationError {}
+impl ServiceErrorCode for EnableKinesisStreamingDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableKinesisStreamingDestinationError::InternalServerError(_) => "InternalServerError",
+            EnableKinesisStreamingDestinationError::LimitExceeded(_) => "LimitExceededException",
+            EnableKinesisStreamingDestinationError::ResourceInUse(_) => "ResourceInUseException",
+            EnableKinesisStreamingDestinationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by ExecuteStatement
 #[derive(Debug, PartialEq)]
 pub enum ExecuteStatementError {
@@ -4707,6 +4944,24 @@ impl fmt::Display for ExecuteStatementError {
     }
 }
 impl Error for ExecuteStatementError {}
+impl ServiceErrorCode for ExecuteStatementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExecuteStatementError::ConditionalCheckFailed(_) => "ConditionalCheckFailedException",
+            ExecuteStatementError::DuplicateItem(_) => "DuplicateItemException",
+            ExecuteStatementError::InternalServerError(_) => "InternalServerError",
+            ExecuteStatementError::ItemCollectionSizeLimitExceeded(_) => {
+                "ItemCollectionSizeLimitExceededException"
+            }
+            ExecuteStatementError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            ExecuteStatementError::RequestLimitExceeded(_) => "RequestLimitExceeded",
+            ExecuteStatementError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ExecuteStatementError::TransactionConflict(_) => "TransactionConflictException",
+        }
+    }
+}
 /// Errors returned by ExecuteTransaction
 #[derive(Debug, PartialEq)]
 pub enum ExecuteTransactionError {
@@ -4789,6 +5044,23 @@ impl fmt::Display for ExecuteTransactionError {
     }
 }
 impl Error for ExecuteTransactionError {}
+impl ServiceErrorCode for ExecuteTransactionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExecuteTransactionError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            ExecuteTransactionError::InternalServerError(_) => "InternalServerError",
+            ExecuteTransactionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            ExecuteTransactionError::RequestLimitExceeded(_) => "RequestLimitExceeded",
+            ExecuteTransactionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ExecuteTransactionError::TransactionCanceled(_) => "TransactionCanceledException",
+            ExecuteTransactionError::TransactionInProgress(_) => "TransactionInProgressException",
+        }
+    }
+}
 /// Errors returned by ExportTableToPointInTime
 #[derive(Debug, PartialEq)]
 pub enum ExportTableToPointInTimeError {
@@ -4863,6 +5135,20 @@ impl fmt::Display for ExportTableToPointInTimeError {
     }
 }
 impl Error for ExportTableToPointInTimeError {}
+impl ServiceErrorCode for ExportTableToPointInTimeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExportTableToPointInTimeError::ExportConflict(_) => "ExportConflictException",
+            ExportTableToPointInTimeError::InternalServerError(_) => "InternalServerError",
+            ExportTableToPointInTimeError::InvalidExportTime(_) => "InvalidExportTimeException",
+            ExportTableToPointInTimeError::LimitExceeded(_) => "LimitExceededException",
+            ExportTableToPointInTimeError::PointInTimeRecoveryUnavailable(_) => {
+                "PointInTimeRecoveryUnavailableException"
+            }
+            ExportTableToPointInTimeError::TableNotFound(_) => "TableNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetItem
 #[derive(Debug, PartialEq)]
 pub enum GetItemError {
@@ -4913,6 +5199,18 @@ impl fmt::Display for GetItemError {
     }
 }
 impl Error for GetItemError {}
+impl ServiceErrorCode for GetItemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetItemError::InternalServerError(_) => "InternalServerError",
+            GetItemError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            GetItemError::RequestLimitExceeded(_) => "RequestLimitExceeded",
+            GetItemError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListBackups
 #[derive(Debug, PartialEq)]
 pub enum ListBackupsError {
@@ -4943,6 +5241,13 @@ impl fmt::Display for ListBackupsError {
     }
 }
 impl Error for ListBackupsError {}
+impl ServiceErrorCode for ListBackupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBackupsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by ListContributorInsights
 #[derive(Debug, PartialEq)]
 pub enum ListContributorInsightsError {
@@ -4983,6 +5288,14 @@ impl fmt::Display for ListContributorInsightsError {
     }
 }
 impl Error for ListContributorInsightsError {}
+impl ServiceErrorCode for ListContributorInsightsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListContributorInsightsError::InternalServerError(_) => "InternalServerError",
+            ListContributorInsightsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListExports
 #[derive(Debug, PartialEq)]
 pub enum ListExportsError {
@@ -5019,6 +5332,14 @@ impl fmt::Display for ListExportsError {
     }
 }
 impl Error for ListExportsError {}
+impl ServiceErrorCode for ListExportsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListExportsError::InternalServerError(_) => "InternalServerError",
+            ListExportsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by ListGlobalTables
 #[derive(Debug, PartialEq)]
 pub enum ListGlobalTablesError {
@@ -5051,6 +5372,13 @@ impl fmt::Display for ListGlobalTablesError {
     }
 }
 impl Error for ListGlobalTablesError {}
+impl ServiceErrorCode for ListGlobalTablesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGlobalTablesError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by ListTables
 #[derive(Debug, PartialEq)]
 pub enum ListTablesError {
@@ -5081,6 +5409,13 @@ impl fmt::Display for ListTablesError {
     }
 }
 impl Error for ListTablesError {}
+impl ServiceErrorCode for ListTablesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTablesError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by ListTagsOfResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsOfResourceError {
@@ -5119,6 +5454,14 @@ impl fmt::Display for ListTagsOfResourceError {
     }
 }
 impl Error for ListTagsOfResourceError {}
+impl ServiceErrorCode for ListTagsOfResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsOfResourceError::InternalServerError(_) => "InternalServerError",
+            ListTagsOfResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutItem
 #[derive(Debug, PartialEq)]
 pub enum PutItemError {
@@ -5189,6 +5532,23 @@ impl fmt::Display for PutItemError {
     }
 }
 impl Error for PutItemError {}
+impl ServiceErrorCode for PutItemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutItemError::ConditionalCheckFailed(_) => "ConditionalCheckFailedException",
+            PutItemError::InternalServerError(_) => "InternalServerError",
+            PutItemError::ItemCollectionSizeLimitExceeded(_) => {
+                "ItemCollectionSizeLimitExceededException"
+            }
+            PutItemError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            PutItemError::RequestLimitExceeded(_) => "RequestLimitExceeded",
+            PutItemError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutItemError::TransactionConflict(_) => "TransactionConflictException",
+        }
+    }
+}
 /// Errors returned by Query
 #[derive(Debug, PartialEq)]
 pub enum QueryError {
@@ -5237,6 +5597,18 @@ impl fmt::Display for QueryError {
     }
 }
 impl Error for QueryError {}
+impl ServiceErrorCode for QueryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            QueryError::InternalServerError(_) => "InternalServerError",
+            QueryError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            QueryError::RequestLimitExceeded(_) => "RequestLimitExceeded",
+            QueryError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RestoreTableFromBackup
 #[derive(Debug, PartialEq)]
 pub enum RestoreTableFromBackupError {
@@ -5305,6 +5677,18 @@ impl fmt::Display for RestoreTableFromBackupError {
     }
 }
 impl Error for RestoreTableFromBackupError {}
+impl ServiceErrorCode for RestoreTableFromBackupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreTableFromBackupError::BackupInUse(_) => "BackupInUseException",
+            RestoreTableFromBackupError::BackupNotFound(_) => "BackupNotFoundException",
+            RestoreTableFromBackupError::InternalServerError(_) => "InternalServerError",
+            RestoreTableFromBackupError::LimitExceeded(_) => "LimitExceededException",
+            RestoreTableFromBackupError::TableAlreadyExists(_) => "TableAlreadyExistsException",
+            RestoreTableFromBackupError::TableInUse(_) => "TableInUseException",
+        }
+    }
+}
 /// Errors returned by RestoreTableToPointInTime
 #[derive(Debug, PartialEq)]
 pub enum RestoreTableToPointInTimeError {
@@ -5389,6 +5773,21 @@ impl fmt::Display for RestoreTableToPointInTimeError {
     }
 }
 impl Error for RestoreTableToPointInTimeError {}
+impl ServiceErrorCode for RestoreTableToPointInTimeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreTableToPointInTimeError::InternalServerError(_) => "InternalServerError",
+            RestoreTableToPointInTimeError::InvalidRestoreTime(_) => "InvalidRestoreTimeException",
+            RestoreTableToPointInTimeError::LimitExceeded(_) => "LimitExceededException",
+            RestoreTableToPointInTimeError::PointInTimeRecoveryUnavailable(_) => {
+                "PointInTimeRecoveryUnavailableException"
+            }
+            RestoreTableToPointInTimeError::TableAlreadyExists(_) => "TableAlreadyExistsException",
+            RestoreTableToPointInTimeError::TableInUse(_) => "TableInUseException",
+            RestoreTableToPointInTimeError::TableNotFound(_) => "TableNotFoundException",
+        }
+    }
+}
 /// Errors returned by Scan
 #[derive(Debug, PartialEq)]
 pub enum ScanError {
@@ -5437,6 +5836,16 @@ impl fmt::Display for ScanError {
     }
 }
 impl Error for ScanError {}
+impl ServiceErrorCode for ScanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ScanError::InternalServerError(_) => "InternalServerError",
+            ScanError::ProvisionedThroughputExceeded(_) => "ProvisionedThroughputExceededException",
+            ScanError::RequestLimitExceeded(_) => "RequestLimitExceeded",
+            ScanError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -5485,6 +5894,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalServerError(_) => "InternalServerError",
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::ResourceInUse(_) => "ResourceInUseException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TransactGetItems
 #[derive(Debug, PartialEq)]
 pub enum TransactGetItemsError {
@@ -5549,6 +5968,19 @@ impl fmt::Display for TransactGetItemsError {
     }
 }
 impl Error for TransactGetItemsError {}
+impl ServiceErrorCode for TransactGetItemsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TransactGetItemsError::InternalServerError(_) => "InternalServerError",
+            TransactGetItemsError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            TransactGetItemsError::RequestLimitExceeded(_) => "RequestLimitExceeded",
+            TransactGetItemsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TransactGetItemsError::TransactionCanceled(_) => "TransactionCanceledException",
+        }
+    }
+}
 /// Errors returned by TransactWriteItems
 #[derive(Debug, PartialEq)]
 pub enum TransactWriteItemsError {
@@ -5631,6 +6063,23 @@ impl fmt::Display for TransactWriteItemsError {
     }
 }
 impl Error for TransactWriteItemsError {}
+impl ServiceErrorCode for TransactWriteItemsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TransactWriteItemsError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            TransactWriteItemsError::InternalServerError(_) => "InternalServerError",
+            TransactWriteItemsError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            TransactWriteItemsError::RequestLimitExceeded(_) => "RequestLimitExceeded",
+            TransactWriteItemsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TransactWriteItemsError::TransactionCanceled(_) => "TransactionCanceledException",
+            TransactWriteItemsError::TransactionInProgress(_) => "TransactionInProgressException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -5679,6 +6128,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalServerError(_) => "InternalServerError",
+            UntagResourceError::LimitExceeded(_) => "LimitExceededException",
+            UntagResourceError::ResourceInUse(_) => "ResourceInUseException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateContinuousBackups
 #[derive(Debug, PartialEq)]
 pub enum UpdateContinuousBackupsError {
@@ -5729,6 +6188,17 @@ impl fmt::Display for UpdateContinuousBackupsError {
     }
 }
 impl Error for UpdateContinuousBackupsError {}
+impl ServiceErrorCode for UpdateContinuousBackupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateContinuousBackupsError::ContinuousBackupsUnavailable(_) => {
+                "ContinuousBackupsUnavailableException"
+            }
+            UpdateContinuousBackupsError::InternalServerError(_) => "InternalServerError",
+            UpdateContinuousBackupsError::TableNotFound(_) => "TableNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateContributorInsights
 #[derive(Debug, PartialEq)]
 pub enum UpdateContributorInsightsError {
@@ -5771,6 +6241,14 @@ impl fmt::Display for UpdateContributorInsightsError {
     }
 }
 impl Error for UpdateContributorInsightsError {}
+impl ServiceErrorCode for UpdateContributorInsightsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateContributorInsightsError::InternalServerError(_) => "InternalServerError",
+            UpdateContributorInsightsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateGlobalTable
 #[derive(Debug, PartialEq)]
 pub enum UpdateGlobalTableError {
@@ -5831,6 +6309,17 @@ impl fmt::Display for UpdateGlobalTableError {
     }
 }
 impl Error for UpdateGlobalTableError {}
+impl ServiceErrorCode for UpdateGlobalTableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGlobalTableError::GlobalTableNotFound(_) => "GlobalTableNotFoundException",
+            UpdateGlobalTableError::InternalServerError(_) => "InternalServerError",
+            UpdateGlobalTableError::ReplicaAlreadyExists(_) => "ReplicaAlreadyExistsException",
+            UpdateGlobalTableError::ReplicaNotFound(_) => "ReplicaNotFoundException",
+            UpdateGlobalTableError::TableNotFound(_) => "TableNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateGlobalTableSettings
 #[derive(Debug, PartialEq)]
 pub enum UpdateGlobalTableSettingsError {
@@ -5907,6 +6396,20 @@ impl fmt::Display for UpdateGlobalTableSettingsError {
     }
 }
 impl Error for UpdateGlobalTableSettingsError {}
+impl ServiceErrorCode for UpdateGlobalTableSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGlobalTableSettingsError::GlobalTableNotFound(_) => {
+                "GlobalTableNotFoundException"
+            }
+            UpdateGlobalTableSettingsError::IndexNotFound(_) => "IndexNotFoundException",
+            UpdateGlobalTableSettingsError::InternalServerError(_) => "InternalServerError",
+            UpdateGlobalTableSettingsError::LimitExceeded(_) => "LimitExceededException",
+            UpdateGlobalTableSettingsError::ReplicaNotFound(_) => "ReplicaNotFoundException",
+            UpdateGlobalTableSettingsError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by UpdateItem
 #[derive(Debug, PartialEq)]
 pub enum UpdateItemError {
@@ -5977,6 +6480,23 @@ impl fmt::Display for UpdateItemError {
     }
 }
 impl Error for UpdateItemError {}
+impl ServiceErrorCode for UpdateItemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateItemError::ConditionalCheckFailed(_) => "ConditionalCheckFailedException",
+            UpdateItemError::InternalServerError(_) => "InternalServerError",
+            UpdateItemError::ItemCollectionSizeLimitExceeded(_) => {
+                "ItemCollectionSizeLimitExceededException"
+            }
+            UpdateItemError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            UpdateItemError::RequestLimitExceeded(_) => "RequestLimitExceeded",
+            UpdateItemError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateItemError::TransactionConflict(_) => "TransactionConflictException",
+        }
+    }
+}
 /// Errors returned by UpdateTable
 #[derive(Debug, PartialEq)]
 pub enum UpdateTableError {
@@ -6025,6 +6545,16 @@ impl fmt::Display for UpdateTableError {
     }
 }
 impl Error for UpdateTableError {}
+impl ServiceErrorCode for UpdateTableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTableError::InternalServerError(_) => "InternalServerError",
+            UpdateTableError::LimitExceeded(_) => "LimitExceededException",
+            UpdateTableError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateTableError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateTableReplicaAutoScaling
 #[derive(Debug, PartialEq)]
 pub enum UpdateTableReplicaAutoScalingError {
@@ -6087,6 +6617,16 @@ impl fmt::Display for UpdateTableReplicaAutoScalingError {
     }
 }
 impl Error for UpdateTableReplicaAutoScalingError {}
+impl ServiceErrorCode for UpdateTableReplicaAutoScalingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTableReplicaAutoScalingError::InternalServerError(_) => "InternalServerError",
+            UpdateTableReplicaAutoScalingError::LimitExceeded(_) => "LimitExceededException",
+            UpdateTableReplicaAutoScalingError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateTableReplicaAutoScalingError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateTimeToLive
 #[derive(Debug, PartialEq)]
 pub enum UpdateTimeToLiveError {
@@ -6137,6 +6677,16 @@ impl fmt::Display for UpdateTimeToLiveError {
     }
 }
 impl Error for UpdateTimeToLiveError {}
+impl ServiceErrorCode for UpdateTimeToLiveError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTimeToLiveError::InternalServerError(_) => "InternalServerError",
+            UpdateTimeToLiveError::LimitExceeded(_) => "LimitExceededException",
+            UpdateTimeToLiveError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateTimeToLiveError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the DynamoDB API. DynamoDB clients implement this trait.
 #[async_trait]
 pub trait DynamoDb {
@@ -6968,6 +7518,10 @@ impl DynamoDb for DynamoDbClient {
         &self,
         input: ExecuteTransactionInput,
     ) -> Result<ExecuteTransactionOutput, RusotoError<ExecuteTransactionError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "DynamoDB_20120810.ExecuteTransaction");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -6986,6 +7540,10 @@ impl DynamoDb for DynamoDbClient {
         &self,
         input: ExportTableToPointInTimeInput,
     ) -> Result<ExportTableToPointInTimeOutput, RusotoError<ExportTableToPointInTimeError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "DynamoDB_20120810.ExportTableToPointInTime");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -7256,6 +7814,10 @@ impl DynamoDb for DynamoDbClient {
         &self,
         input: TransactWriteItemsInput,
     ) -> Result<TransactWriteItemsOutput, RusotoError<TransactWriteItemsError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "DynamoDB_20120810.TransactWriteItems");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -444,6 +444,14 @@ impl fmt::Display for DescribeStreamError {
     }
 }
 impl Error for DescribeStreamError {}
+impl ServiceErrorCode for DescribeStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStreamError::InternalServerError(_) => "InternalServerError",
+            DescribeStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetRecords
 #[derive(Debug, PartialEq)]
 pub enum GetRecordsError {
@@ -498,6 +506,17 @@ impl fmt::Display for GetRecordsError {
     }
 }
 impl Error for GetRecordsError {}
+impl ServiceErrorCode for GetRecordsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRecordsError::ExpiredIterator(_) => "ExpiredIteratorException",
+            GetRecordsError::InternalServerError(_) => "InternalServerError",
+            GetRecordsError::LimitExceeded(_) => "LimitExceededException",
+            GetRecordsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetRecordsError::TrimmedDataAccess(_) => "TrimmedDataAccessException",
+        }
+    }
+}
 /// Errors returned by GetShardIterator
 #[derive(Debug, PartialEq)]
 pub enum GetShardIteratorError {
@@ -542,6 +561,15 @@ impl fmt::Display for GetShardIteratorError {
     }
 }
 impl Error for GetShardIteratorError {}
+impl ServiceErrorCode for GetShardIteratorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetShardIteratorError::InternalServerError(_) => "InternalServerError",
+            GetShardIteratorError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetShardIteratorError::TrimmedDataAccess(_) => "TrimmedDataAccessException",
+        }
+    }
+}
 /// Errors returned by ListStreams
 #[derive(Debug, PartialEq)]
 pub enum ListStreamsError {
@@ -578,6 +606,14 @@ impl fmt::Display for ListStreamsError {
     }
 }
 impl Error for ListStreamsError {}
+impl ServiceErrorCode for ListStreamsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStreamsError::InternalServerError(_) => "InternalServerError",
+            ListStreamsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon DynamoDB Streams API. Amazon DynamoDB Streams clients implement this trait.
 #[async_trait]
 pub trait DynamoDbStreams {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -258,6 +258,7 @@ pub struct PutSnapshotBlockResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartSnapshotRequest {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request. Idempotency ensures that an API request completes only once. With an idempotent request, if the original request completes successfully. The subsequent retries with the same client token return the result from the original successful request and they have no additional effect.</p> <p>If you do not specify a client token, one is automatically generated by the AWS SDK.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-direct-api-idempotency.html"> Idempotency for StartSnapshot API</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -404,6 +405,17 @@ impl fmt::Display for CompleteSnapshotError {
     }
 }
 impl Error for CompleteSnapshotError {}
+impl ServiceErrorCode for CompleteSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CompleteSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            CompleteSnapshotError::InternalServer(_) => "InternalServerException",
+            CompleteSnapshotError::RequestThrottled(_) => "RequestThrottledException",
+            CompleteSnapshotError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CompleteSnapshotError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+        }
+    }
+}
 /// Errors returned by GetSnapshotBlock
 #[derive(Debug, PartialEq)]
 pub enum GetSnapshotBlockError {
@@ -460,6 +472,17 @@ impl fmt::Display for GetSnapshotBlockError {
     }
 }
 impl Error for GetSnapshotBlockError {}
+impl ServiceErrorCode for GetSnapshotBlockError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSnapshotBlockError::AccessDenied(_) => "AccessDeniedException",
+            GetSnapshotBlockError::InternalServer(_) => "InternalServerException",
+            GetSnapshotBlockError::RequestThrottled(_) => "RequestThrottledException",
+            GetSnapshotBlockError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetSnapshotBlockError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+        }
+    }
+}
 /// Errors returned by ListChangedBlocks
 #[derive(Debug, PartialEq)]
 pub enum ListChangedBlocksError {
@@ -516,6 +539,17 @@ impl fmt::Display for ListChangedBlocksError {
     }
 }
 impl Error for ListChangedBlocksError {}
+impl ServiceErrorCode for ListChangedBlocksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListChangedBlocksError::AccessDenied(_) => "AccessDeniedException",
+            ListChangedBlocksError::InternalServer(_) => "InternalServerException",
+            ListChangedBlocksError::RequestThrottled(_) => "RequestThrottledException",
+            ListChangedBlocksError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListChangedBlocksError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+        }
+    }
+}
 /// Errors returned by ListSnapshotBlocks
 #[derive(Debug, PartialEq)]
 pub enum ListSnapshotBlocksError {
@@ -572,6 +606,17 @@ impl fmt::Display for ListSnapshotBlocksError {
     }
 }
 impl Error for ListSnapshotBlocksError {}
+impl ServiceErrorCode for ListSnapshotBlocksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSnapshotBlocksError::AccessDenied(_) => "AccessDeniedException",
+            ListSnapshotBlocksError::InternalServer(_) => "InternalServerException",
+            ListSnapshotBlocksError::RequestThrottled(_) => "RequestThrottledException",
+            ListSnapshotBlocksError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListSnapshotBlocksError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+        }
+    }
+}
 /// Errors returned by PutSnapshotBlock
 #[derive(Debug, PartialEq)]
 pub enum PutSnapshotBlockError {
@@ -628,6 +673,17 @@ impl fmt::Display for PutSnapshotBlockError {
     }
 }
 impl Error for PutSnapshotBlockError {}
+impl ServiceErrorCode for PutSnapshotBlockError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutSnapshotBlockError::AccessDenied(_) => "AccessDeniedException",
+            PutSnapshotBlockError::InternalServer(_) => "InternalServerException",
+            PutSnapshotBlockError::RequestThrottled(_) => "RequestThrottledException",
+            PutSnapshotBlockError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutSnapshotBlockError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+        }
+    }
+}
 /// Errors returned by StartSnapshot
 #[derive(Debug, PartialEq)]
 pub enum StartSnapshotError {
@@ -696,6 +752,19 @@ impl fmt::Display for StartSnapshotError {
     }
 }
 impl Error for StartSnapshotError {}
+impl ServiceErrorCode for StartSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            StartSnapshotError::ConcurrentLimitExceeded(_) => "ConcurrentLimitExceededException",
+            StartSnapshotError::Conflict(_) => "ConflictException",
+            StartSnapshotError::InternalServer(_) => "InternalServerException",
+            StartSnapshotError::RequestThrottled(_) => "RequestThrottledException",
+            StartSnapshotError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartSnapshotError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon EBS API. Amazon EBS clients implement this trait.
 #[async_trait]
 pub trait Ebs {
@@ -1003,6 +1072,10 @@ impl Ebs for EbsClient {
         &self,
         input: StartSnapshotRequest,
     ) -> Result<StartSnapshotResponse, RusotoError<StartSnapshotError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = "/snapshots";
 
         let mut request = SignedRequest::new("POST", "ebs", &self.region, &request_uri);
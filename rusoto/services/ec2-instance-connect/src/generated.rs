@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -141,6 +141,17 @@ impl fmt::Display for SendSSHPublicKeyError {
     }
 }
 impl Error for SendSSHPublicKeyError {}
+impl ServiceErrorCode for SendSSHPublicKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendSSHPublicKeyError::Auth(_) => "AuthException",
+            SendSSHPublicKeyError::EC2InstanceNotFound(_) => "EC2InstanceNotFoundException",
+            SendSSHPublicKeyError::InvalidArgs(_) => "InvalidArgsException",
+            SendSSHPublicKeyError::Service(_) => "ServiceException",
+            SendSSHPublicKeyError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the EC2 Instance Connect API. EC2 Instance Connect clients implement this trait.
 #[async_trait]
 pub trait Ec2InstanceConnect {
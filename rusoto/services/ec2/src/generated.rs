@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -2103,6 +2103,7 @@ impl AssociateAddressResultDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct AssociateClientVpnTargetNetworkRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/Run_Instance_Idempotency.html">How to Ensure Idempotency</a>.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: Option<String>,
     /// <p>The ID of the Client VPN endpoint.</p>
     pub client_vpn_endpoint_id: String,
@@ -3319,6 +3320,7 @@ pub struct AuthorizeClientVpnIngressRequest {
     /// <p>Indicates whether to grant access to all clients. Specify <code>true</code> to grant all clients who successfully establish a VPN connection access to the network. Must be set to <code>true</code> if <code>AccessGroupId</code> is not specified.</p>
     pub authorize_all_groups: Option<bool>,
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/Run_Instance_Idempotency.html">How to Ensure Idempotency</a>.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: Option<String>,
     /// <p>The ID of the Client VPN endpoint.</p>
     pub client_vpn_endpoint_id: String,
@@ -7906,6 +7908,7 @@ impl CreateCapacityReservationResultDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateCarrierGatewayRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Run_Instance_Idempotency.html">How to Ensure Idempotency</a>.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: Option<String>,
     /// <p>Checks whether you have the required permissions for the action, without actually making the request, and provides an error response. If you have the required permissions, the error response is <code>DryRunOperation</code>. Otherwise, it is <code>UnauthorizedOperation</code>.</p>
     pub dry_run: Option<bool>,
@@ -7984,6 +7987,7 @@ pub struct CreateClientVpnEndpointRequest {
     /// <p>The options for managing connection authorization for new client connections.</p>
     pub client_connect_options: Option<ClientConnectOptions>,
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/Run_Instance_Idempotency.html">How to Ensure Idempotency</a>.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: Option<String>,
     /// <p><p>Information about the client connection logging options.</p> <p>If you enable client connection logging, data about client connections is sent to a Cloudwatch Logs log stream. The following information is logged:</p> <ul> <li> <p>Client connection requests</p> </li> <li> <p>Client connection results (successful and unsuccessful)</p> </li> <li> <p>Reasons for unsuccessful client connection requests</p> </li> <li> <p>Client connection termination time</p> </li> </ul></p>
     pub connection_log_options: ConnectionLogOptions,
@@ -8142,6 +8146,7 @@ impl CreateClientVpnEndpointResultDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateClientVpnRouteRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/Run_Instance_Idempotency.html">How to Ensure Idempotency</a>.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: Option<String>,
     /// <p>The ID of the Client VPN endpoint to which to add the route.</p>
     pub client_vpn_endpoint_id: String,
@@ -9680,6 +9685,7 @@ pub struct CreateManagedPrefixListRequest {
     /// <p>The IP address type.</p> <p>Valid Values: <code>IPv4</code> | <code>IPv6</code> </p>
     pub address_family: String,
     /// <p>Unique, case-sensitive identifier you provide to ensure the idempotency of the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/Run_Instance_Idempotency.html">Ensuring Idempotency</a>.</p> <p>Constraints: Up to 255 UTF-8 characters in length.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: Option<String>,
     /// <p>Checks whether you have the required permissions for the action, without actually making the request, and provides an error response. If you have the required permissions, the error response is <code>DryRunOperation</code>. Otherwise, it is <code>UnauthorizedOperation</code>.</p>
     pub dry_run: Option<bool>,
@@ -9773,6 +9779,7 @@ pub struct CreateNatGatewayRequest {
     /// <p>The allocation ID of an Elastic IP address to associate with the NAT gateway. If the Elastic IP address is associated with another resource, you must first disassociate it.</p>
     pub allocation_id: String,
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/Run_Instance_Idempotency.html">How to Ensure Idempotency</a>.</p> <p>Constraint: Maximum 64 ASCII characters.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: Option<String>,
     /// <p>Checks whether you have the required permissions for the action, without actually making the request, and provides an error response. If you have the required permissions, the error response is <code>DryRunOperation</code>. Otherwise, it is <code>UnauthorizedOperation</code>.</p>
     pub dry_run: Option<bool>,
@@ -9974,6 +9981,7 @@ impl CreateNetworkAclResultDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateNetworkInsightsPathRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/Run_Instance_Idempotency.html">How to Ensure Idempotency</a>.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: String,
     /// <p>The AWS resource that is the destination of the path.</p>
     pub destination: String,
@@ -10994,6 +11002,7 @@ impl CreateTagsRequestSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateTrafficMirrorFilterRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/Run_Instance_Idempotency.html">How to Ensure Idempotency</a>.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: Option<String>,
     /// <p>The description of the Traffic Mirror filter.</p>
     pub description: Option<String>,
@@ -11075,6 +11084,7 @@ impl CreateTrafficMirrorFilterResultDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateTrafficMirrorFilterRuleRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/Run_Instance_Idempotency.html">How to Ensure Idempotency</a>.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: Option<String>,
     /// <p>The description of the Traffic Mirror rule.</p>
     pub description: Option<String>,
@@ -11200,6 +11210,7 @@ impl CreateTrafficMirrorFilterRuleResultDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateTrafficMirrorSessionRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/Run_Instance_Idempotency.html">How to Ensure Idempotency</a>.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: Option<String>,
     /// <p>The description of the Traffic Mirror session.</p>
     pub description: Option<String>,
@@ -11315,6 +11326,7 @@ impl CreateTrafficMirrorSessionResultDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateTrafficMirrorTargetRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/Run_Instance_Idempotency.html">How to Ensure Idempotency</a>.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: Option<String>,
     /// <p>The description of the Traffic Mirror target.</p>
     pub description: Option<String>,
@@ -31500,6 +31512,7 @@ impl ExportEnvironmentDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct ExportImageRequest {
     /// <p>Token to enable idempotency for export image requests.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: Option<String>,
     /// <p>A description of the image being exported. The maximum length is 255 characters.</p>
     pub description: Option<String>,
@@ -54427,6 +54440,7 @@ impl PurchaseReservedInstancesOfferingResultDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PurchaseScheduledInstancesRequest {
     /// <p>Unique, case-sensitive identifier that ensures the idempotency of the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/Run_Instance_Idempotency.html">Ensuring Idempotency</a>.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: Option<String>,
     /// <p>Checks whether you have the required permissions for the action, without actually making the request, and provides an error response. If you have the required permissions, the error response is <code>DryRunOperation</code>. Otherwise, it is <code>UnauthorizedOperation</code>.</p>
     pub dry_run: Option<bool>,
@@ -59201,6 +59215,7 @@ pub struct RunInstancesRequest {
     /// <p>Information about the Capacity Reservation targeting option. If you do not specify this parameter, the instance's Capacity Reservation preference defaults to <code>open</code>, which enables it to run in any open Capacity Reservation that has matching attributes (instance type, platform, Availability Zone).</p>
     pub capacity_reservation_specification: Option<CapacityReservationSpecification>,
     /// <p>Unique, case-sensitive identifier you provide to ensure the idempotency of the request. If you do not specify a client token, a randomly generated token is used for the request to ensure idempotency.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/Run_Instance_Idempotency.html">Ensuring Idempotency</a>.</p> <p>Constraints: Maximum 64 ASCII characters</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: Option<String>,
     /// <p>The CPU options for the instance. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/instance-optimize-cpu.html">Optimizing CPU options</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
     pub cpu_options: Option<CpuOptionsRequest>,
@@ -59480,6 +59495,7 @@ impl RunInstancesRequestSerializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RunScheduledInstancesRequest {
     /// <p>Unique, case-sensitive identifier that ensures the idempotency of the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/Run_Instance_Idempotency.html">Ensuring Idempotency</a>.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: Option<String>,
     /// <p>Checks whether you have the required permissions for the action, without actually making the request, and provides an error response. If you have the required permissions, the error response is <code>DryRunOperation</code>. Otherwise, it is <code>UnauthorizedOperation</code>.</p>
     pub dry_run: Option<bool>,
@@ -63712,6 +63728,7 @@ impl StartInstancesResultDeserializer {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartNetworkInsightsAnalysisRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/Run_Instance_Idempotency.html">How to Ensure Idempotency</a>.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     pub client_token: String,
     /// <p>Checks whether you have the required permissions for the action, without actually making the request, and provides an error response. If you have the required permissions, the error response is <code>DryRunOperation</code>. Otherwise, it is <code>UnauthorizedOperation</code>.</p>
     pub dry_run: Option<bool>,
@@ -72619,6 +72636,11 @@ impl fmt::Display for AcceptReservedInstancesExchangeQuoteError {
     }
 }
 impl Error for AcceptReservedInstancesExchangeQuoteError {}
+impl ServiceErrorCode for AcceptReservedInstancesExchangeQuoteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AcceptTransitGatewayMulticastDomainAssociations
 #[derive(Debug, PartialEq)]
 pub enum AcceptTransitGatewayMulticastDomainAssociationsError {}
@@ -72656,6 +72678,11 @@ impl fmt::Display for AcceptTransitGatewayMulticastDomainAssociationsError {
     }
 }
 impl Error for AcceptTransitGatewayMulticastDomainAssociationsError {}
+impl ServiceErrorCode for AcceptTransitGatewayMulticastDomainAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AcceptTransitGatewayPeeringAttachment
 #[derive(Debug, PartialEq)]
 pub enum AcceptTransitGatewayPeeringAttachmentError {}
@@ -72693,6 +72720,11 @@ impl fmt::Display for AcceptTransitGatewayPeeringAttachmentError {
     }
 }
 impl Error for AcceptTransitGatewayPeeringAttachmentError {}
+impl ServiceErrorCode for AcceptTransitGatewayPeeringAttachmentError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AcceptTransitGatewayVpcAttachment
 #[derive(Debug, PartialEq)]
 pub enum AcceptTransitGatewayVpcAttachmentError {}
@@ -72730,6 +72762,11 @@ impl fmt::Display for AcceptTransitGatewayVpcAttachmentError {
     }
 }
 impl Error for AcceptTransitGatewayVpcAttachmentError {}
+impl ServiceErrorCode for AcceptTransitGatewayVpcAttachmentError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AcceptVpcEndpointConnections
 #[derive(Debug, PartialEq)]
 pub enum AcceptVpcEndpointConnectionsError {}
@@ -72767,6 +72804,11 @@ impl fmt::Display for AcceptVpcEndpointConnectionsError {
     }
 }
 impl Error for AcceptVpcEndpointConnectionsError {}
+impl ServiceErrorCode for AcceptVpcEndpointConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AcceptVpcPeeringConnection
 #[derive(Debug, PartialEq)]
 pub enum AcceptVpcPeeringConnectionError {}
@@ -72804,6 +72846,11 @@ impl fmt::Display for AcceptVpcPeeringConnectionError {
     }
 }
 impl Error for AcceptVpcPeeringConnectionError {}
+impl ServiceErrorCode for AcceptVpcPeeringConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AdvertiseByoipCidr
 #[derive(Debug, PartialEq)]
 pub enum AdvertiseByoipCidrError {}
@@ -72839,6 +72886,11 @@ impl fmt::Display for AdvertiseByoipCidrError {
     }
 }
 impl Error for AdvertiseByoipCidrError {}
+impl ServiceErrorCode for AdvertiseByoipCidrError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AllocateAddress
 #[derive(Debug, PartialEq)]
 pub enum AllocateAddressError {}
@@ -72874,6 +72926,11 @@ impl fmt::Display for AllocateAddressError {
     }
 }
 impl Error for AllocateAddressError {}
+impl ServiceErrorCode for AllocateAddressError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AllocateHosts
 #[derive(Debug, PartialEq)]
 pub enum AllocateHostsError {}
@@ -72909,6 +72966,11 @@ impl fmt::Display for AllocateHostsError {
     }
 }
 impl Error for AllocateHostsError {}
+impl ServiceErrorCode for AllocateHostsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ApplySecurityGroupsToClientVpnTargetNetwork
 #[derive(Debug, PartialEq)]
 pub enum ApplySecurityGroupsToClientVpnTargetNetworkError {}
@@ -72946,6 +73008,11 @@ impl fmt::Display for ApplySecurityGroupsToClientVpnTargetNetworkError {
     }
 }
 impl Error for ApplySecurityGroupsToClientVpnTargetNetworkError {}
+impl ServiceErrorCode for ApplySecurityGroupsToClientVpnTargetNetworkError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AssignIpv6Addresses
 #[derive(Debug, PartialEq)]
 pub enum AssignIpv6AddressesError {}
@@ -72981,6 +73048,11 @@ impl fmt::Display for AssignIpv6AddressesError {
     }
 }
 impl Error for AssignIpv6AddressesError {}
+impl ServiceErrorCode for AssignIpv6AddressesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AssignPrivateIpAddresses
 #[derive(Debug, PartialEq)]
 pub enum AssignPrivateIpAddressesError {}
@@ -73016,6 +73088,11 @@ impl fmt::Display for AssignPrivateIpAddressesError {
     }
 }
 impl Error for AssignPrivateIpAddressesError {}
+impl ServiceErrorCode for AssignPrivateIpAddressesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AssociateAddress
 #[derive(Debug, PartialEq)]
 pub enum AssociateAddressError {}
@@ -73051,6 +73128,11 @@ impl fmt::Display for AssociateAddressError {
     }
 }
 impl Error for AssociateAddressError {}
+impl ServiceErrorCode for AssociateAddressError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AssociateClientVpnTargetNetwork
 #[derive(Debug, PartialEq)]
 pub enum AssociateClientVpnTargetNetworkError {}
@@ -73088,6 +73170,11 @@ impl fmt::Display for AssociateClientVpnTargetNetworkError {
     }
 }
 impl Error for AssociateClientVpnTargetNetworkError {}
+impl ServiceErrorCode for AssociateClientVpnTargetNetworkError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AssociateDhcpOptions
 #[derive(Debug, PartialEq)]
 pub enum AssociateDhcpOptionsError {}
@@ -73123,6 +73210,11 @@ impl fmt::Display for AssociateDhcpOptionsError {
     }
 }
 impl Error for AssociateDhcpOptionsError {}
+impl ServiceErrorCode for AssociateDhcpOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AssociateEnclaveCertificateIamRole
 #[derive(Debug, PartialEq)]
 pub enum AssociateEnclaveCertificateIamRoleError {}
@@ -73160,6 +73252,11 @@ impl fmt::Display for AssociateEnclaveCertificateIamRoleError {
     }
 }
 impl Error for AssociateEnclaveCertificateIamRoleError {}
+impl ServiceErrorCode for AssociateEnclaveCertificateIamRoleError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AssociateIamInstanceProfile
 #[derive(Debug, PartialEq)]
 pub enum AssociateIamInstanceProfileError {}
@@ -73197,6 +73294,11 @@ impl fmt::Display for AssociateIamInstanceProfileError {
     }
 }
 impl Error for AssociateIamInstanceProfileError {}
+impl ServiceErrorCode for AssociateIamInstanceProfileError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AssociateRouteTable
 #[derive(Debug, PartialEq)]
 pub enum AssociateRouteTableError {}
@@ -73232,6 +73334,11 @@ impl fmt::Display for AssociateRouteTableError {
     }
 }
 impl Error for AssociateRouteTableError {}
+impl ServiceErrorCode for AssociateRouteTableError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AssociateSubnetCidrBlock
 #[derive(Debug, PartialEq)]
 pub enum AssociateSubnetCidrBlockError {}
@@ -73267,6 +73374,11 @@ impl fmt::Display for AssociateSubnetCidrBlockError {
     }
 }
 impl Error for AssociateSubnetCidrBlockError {}
+impl ServiceErrorCode for AssociateSubnetCidrBlockError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AssociateTransitGatewayMulticastDomain
 #[derive(Debug, PartialEq)]
 pub enum AssociateTransitGatewayMulticastDomainError {}
@@ -73304,6 +73416,11 @@ impl fmt::Display for AssociateTransitGatewayMulticastDomainError {
     }
 }
 impl Error for AssociateTransitGatewayMulticastDomainError {}
+impl ServiceErrorCode for AssociateTransitGatewayMulticastDomainError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AssociateTransitGatewayRouteTable
 #[derive(Debug, PartialEq)]
 pub enum AssociateTransitGatewayRouteTableError {}
@@ -73341,6 +73458,11 @@ impl fmt::Display for AssociateTransitGatewayRouteTableError {
     }
 }
 impl Error for AssociateTransitGatewayRouteTableError {}
+impl ServiceErrorCode for AssociateTransitGatewayRouteTableError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AssociateVpcCidrBlock
 #[derive(Debug, PartialEq)]
 pub enum AssociateVpcCidrBlockError {}
@@ -73376,6 +73498,11 @@ impl fmt::Display for AssociateVpcCidrBlockError {
     }
 }
 impl Error for AssociateVpcCidrBlockError {}
+impl ServiceErrorCode for AssociateVpcCidrBlockError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AttachClassicLinkVpc
 #[derive(Debug, PartialEq)]
 pub enum AttachClassicLinkVpcError {}
@@ -73411,6 +73538,11 @@ impl fmt::Display for AttachClassicLinkVpcError {
     }
 }
 impl Error for AttachClassicLinkVpcError {}
+impl ServiceErrorCode for AttachClassicLinkVpcError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AttachInternetGateway
 #[derive(Debug, PartialEq)]
 pub enum AttachInternetGatewayError {}
@@ -73446,6 +73578,11 @@ impl fmt::Display for AttachInternetGatewayError {
     }
 }
 impl Error for AttachInternetGatewayError {}
+impl ServiceErrorCode for AttachInternetGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AttachNetworkInterface
 #[derive(Debug, PartialEq)]
 pub enum AttachNetworkInterfaceError {}
@@ -73481,6 +73618,11 @@ impl fmt::Display for AttachNetworkInterfaceError {
     }
 }
 impl Error for AttachNetworkInterfaceError {}
+impl ServiceErrorCode for AttachNetworkInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AttachVolume
 #[derive(Debug, PartialEq)]
 pub enum AttachVolumeError {}
@@ -73516,6 +73658,11 @@ impl fmt::Display for AttachVolumeError {
     }
 }
 impl Error for AttachVolumeError {}
+impl ServiceErrorCode for AttachVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AttachVpnGateway
 #[derive(Debug, PartialEq)]
 pub enum AttachVpnGatewayError {}
@@ -73551,6 +73698,11 @@ impl fmt::Display for AttachVpnGatewayError {
     }
 }
 impl Error for AttachVpnGatewayError {}
+impl ServiceErrorCode for AttachVpnGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AuthorizeClientVpnIngress
 #[derive(Debug, PartialEq)]
 pub enum AuthorizeClientVpnIngressError {}
@@ -73586,6 +73738,11 @@ impl fmt::Display for AuthorizeClientVpnIngressError {
     }
 }
 impl Error for AuthorizeClientVpnIngressError {}
+impl ServiceErrorCode for AuthorizeClientVpnIngressError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AuthorizeSecurityGroupEgress
 #[derive(Debug, PartialEq)]
 pub enum AuthorizeSecurityGroupEgressError {}
@@ -73623,6 +73780,11 @@ impl fmt::Display for AuthorizeSecurityGroupEgressError {
     }
 }
 impl Error for AuthorizeSecurityGroupEgressError {}
+impl ServiceErrorCode for AuthorizeSecurityGroupEgressError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AuthorizeSecurityGroupIngress
 #[derive(Debug, PartialEq)]
 pub enum AuthorizeSecurityGroupIngressError {}
@@ -73660,6 +73822,11 @@ impl fmt::Display for AuthorizeSecurityGroupIngressError {
     }
 }
 impl Error for AuthorizeSecurityGroupIngressError {}
+impl ServiceErrorCode for AuthorizeSecurityGroupIngressError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by BundleInstance
 #[derive(Debug, PartialEq)]
 pub enum BundleInstanceError {}
@@ -73695,6 +73862,11 @@ impl fmt::Display for BundleInstanceError {
     }
 }
 impl Error for BundleInstanceError {}
+impl ServiceErrorCode for BundleInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CancelBundleTask
 #[derive(Debug, PartialEq)]
 pub enum CancelBundleTaskError {}
@@ -73730,6 +73902,11 @@ impl fmt::Display for CancelBundleTaskError {
     }
 }
 impl Error for CancelBundleTaskError {}
+impl ServiceErrorCode for CancelBundleTaskError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CancelCapacityReservation
 #[derive(Debug, PartialEq)]
 pub enum CancelCapacityReservationError {}
@@ -73765,6 +73942,11 @@ impl fmt::Display for CancelCapacityReservationError {
     }
 }
 impl Error for CancelCapacityReservationError {}
+impl ServiceErrorCode for CancelCapacityReservationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CancelConversionTask
 #[derive(Debug, PartialEq)]
 pub enum CancelConversionTaskError {}
@@ -73800,6 +73982,11 @@ impl fmt::Display for CancelConversionTaskError {
     }
 }
 impl Error for CancelConversionTaskError {}
+impl ServiceErrorCode for CancelConversionTaskError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CancelExportTask
 #[derive(Debug, PartialEq)]
 pub enum CancelExportTaskError {}
@@ -73835,6 +74022,11 @@ impl fmt::Display for CancelExportTaskError {
     }
 }
 impl Error for CancelExportTaskError {}
+impl ServiceErrorCode for CancelExportTaskError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CancelImportTask
 #[derive(Debug, PartialEq)]
 pub enum CancelImportTaskError {}
@@ -73870,6 +74062,11 @@ impl fmt::Display for CancelImportTaskError {
     }
 }
 impl Error for CancelImportTaskError {}
+impl ServiceErrorCode for CancelImportTaskError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CancelReservedInstancesListing
 #[derive(Debug, PartialEq)]
 pub enum CancelReservedInstancesListingError {}
@@ -73907,6 +74104,11 @@ impl fmt::Display for CancelReservedInstancesListingError {
     }
 }
 impl Error for CancelReservedInstancesListingError {}
+impl ServiceErrorCode for CancelReservedInstancesListingError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CancelSpotFleetRequests
 #[derive(Debug, PartialEq)]
 pub enum EC2CancelSpotFleetRequestsError {}
@@ -73944,6 +74146,11 @@ impl fmt::Display for EC2CancelSpotFleetRequestsError {
     }
 }
 impl Error for EC2CancelSpotFleetRequestsError {}
+impl ServiceErrorCode for EC2CancelSpotFleetRequestsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CancelSpotInstanceRequests
 #[derive(Debug, PartialEq)]
 pub enum CancelSpotInstanceRequestsError {}
@@ -73981,6 +74188,11 @@ impl fmt::Display for CancelSpotInstanceRequestsError {
     }
 }
 impl Error for CancelSpotInstanceRequestsError {}
+impl ServiceErrorCode for CancelSpotInstanceRequestsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ConfirmProductInstance
 #[derive(Debug, PartialEq)]
 pub enum ConfirmProductInstanceError {}
@@ -74016,6 +74228,11 @@ impl fmt::Display for ConfirmProductInstanceError {
     }
 }
 impl Error for ConfirmProductInstanceError {}
+impl ServiceErrorCode for ConfirmProductInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CopyFpgaImage
 #[derive(Debug, PartialEq)]
 pub enum CopyFpgaImageError {}
@@ -74051,6 +74268,11 @@ impl fmt::Display for CopyFpgaImageError {
     }
 }
 impl Error for CopyFpgaImageError {}
+impl ServiceErrorCode for CopyFpgaImageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CopyImage
 #[derive(Debug, PartialEq)]
 pub enum CopyImageError {}
@@ -74086,6 +74308,11 @@ impl fmt::Display for CopyImageError {
     }
 }
 impl Error for CopyImageError {}
+impl ServiceErrorCode for CopyImageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CopySnapshot
 #[derive(Debug, PartialEq)]
 pub enum CopySnapshotError {}
@@ -74121,6 +74348,11 @@ impl fmt::Display for CopySnapshotError {
     }
 }
 impl Error for CopySnapshotError {}
+impl ServiceErrorCode for CopySnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateCapacityReservation
 #[derive(Debug, PartialEq)]
 pub enum CreateCapacityReservationError {}
@@ -74156,6 +74388,11 @@ impl fmt::Display for CreateCapacityReservationError {
     }
 }
 impl Error for CreateCapacityReservationError {}
+impl ServiceErrorCode for CreateCapacityReservationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateCarrierGateway
 #[derive(Debug, PartialEq)]
 pub enum CreateCarrierGatewayError {}
@@ -74191,6 +74428,11 @@ impl fmt::Display for CreateCarrierGatewayError {
     }
 }
 impl Error for CreateCarrierGatewayError {}
+impl ServiceErrorCode for CreateCarrierGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateClientVpnEndpoint
 #[derive(Debug, PartialEq)]
 pub enum CreateClientVpnEndpointError {}
@@ -74226,6 +74468,11 @@ impl fmt::Display for CreateClientVpnEndpointError {
     }
 }
 impl Error for CreateClientVpnEndpointError {}
+impl ServiceErrorCode for CreateClientVpnEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateClientVpnRoute
 #[derive(Debug, PartialEq)]
 pub enum CreateClientVpnRouteError {}
@@ -74261,6 +74508,11 @@ impl fmt::Display for CreateClientVpnRouteError {
     }
 }
 impl Error for CreateClientVpnRouteError {}
+impl ServiceErrorCode for CreateClientVpnRouteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateCustomerGateway
 #[derive(Debug, PartialEq)]
 pub enum CreateCustomerGatewayError {}
@@ -74296,6 +74548,11 @@ impl fmt::Display for CreateCustomerGatewayError {
     }
 }
 impl Error for CreateCustomerGatewayError {}
+impl ServiceErrorCode for CreateCustomerGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateDefaultSubnet
 #[derive(Debug, PartialEq)]
 pub enum CreateDefaultSubnetError {}
@@ -74331,6 +74588,11 @@ impl fmt::Display for CreateDefaultSubnetError {
     }
 }
 impl Error for CreateDefaultSubnetError {}
+impl ServiceErrorCode for CreateDefaultSubnetError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateDefaultVpc
 #[derive(Debug, PartialEq)]
 pub enum CreateDefaultVpcError {}
@@ -74366,6 +74628,11 @@ impl fmt::Display for CreateDefaultVpcError {
     }
 }
 impl Error for CreateDefaultVpcError {}
+impl ServiceErrorCode for CreateDefaultVpcError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateDhcpOptions
 #[derive(Debug, PartialEq)]
 pub enum CreateDhcpOptionsError {}
@@ -74401,6 +74668,11 @@ impl fmt::Display for CreateDhcpOptionsError {
     }
 }
 impl Error for CreateDhcpOptionsError {}
+impl ServiceErrorCode for CreateDhcpOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateEgressOnlyInternetGateway
 #[derive(Debug, PartialEq)]
 pub enum CreateEgressOnlyInternetGatewayError {}
@@ -74438,6 +74710,11 @@ impl fmt::Display for CreateEgressOnlyInternetGatewayError {
     }
 }
 impl Error for CreateEgressOnlyInternetGatewayError {}
+impl ServiceErrorCode for CreateEgressOnlyInternetGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateFleet
 #[derive(Debug, PartialEq)]
 pub enum CreateFleetError {}
@@ -74473,6 +74750,11 @@ impl fmt::Display for CreateFleetError {
     }
 }
 impl Error for CreateFleetError {}
+impl ServiceErrorCode for CreateFleetError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateFlowLogs
 #[derive(Debug, PartialEq)]
 pub enum CreateFlowLogsError {}
@@ -74508,6 +74790,11 @@ impl fmt::Display for CreateFlowLogsError {
     }
 }
 impl Error for CreateFlowLogsError {}
+impl ServiceErrorCode for CreateFlowLogsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateFpgaImage
 #[derive(Debug, PartialEq)]
 pub enum CreateFpgaImageError {}
@@ -74543,6 +74830,11 @@ impl fmt::Display for CreateFpgaImageError {
     }
 }
 impl Error for CreateFpgaImageError {}
+impl ServiceErrorCode for CreateFpgaImageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateImage
 #[derive(Debug, PartialEq)]
 pub enum CreateImageError {}
@@ -74578,6 +74870,11 @@ impl fmt::Display for CreateImageError {
     }
 }
 impl Error for CreateImageError {}
+impl ServiceErrorCode for CreateImageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateInstanceExportTask
 #[derive(Debug, PartialEq)]
 pub enum CreateInstanceExportTaskError {}
@@ -74613,6 +74910,11 @@ impl fmt::Display for CreateInstanceExportTaskError {
     }
 }
 impl Error for CreateInstanceExportTaskError {}
+impl ServiceErrorCode for CreateInstanceExportTaskError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateInternetGateway
 #[derive(Debug, PartialEq)]
 pub enum CreateInternetGatewayError {}
@@ -74648,6 +74950,11 @@ impl fmt::Display for CreateInternetGatewayError {
     }
 }
 impl Error for CreateInternetGatewayError {}
+impl ServiceErrorCode for CreateInternetGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateKeyPair
 #[derive(Debug, PartialEq)]
 pub enum CreateKeyPairError {}
@@ -74683,6 +74990,11 @@ impl fmt::Display for CreateKeyPairError {
     }
 }
 impl Error for CreateKeyPairError {}
+impl ServiceErrorCode for CreateKeyPairError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateLaunchTemplate
 #[derive(Debug, PartialEq)]
 pub enum CreateLaunchTemplateError {}
@@ -74718,6 +75030,11 @@ impl fmt::Display for CreateLaunchTemplateError {
     }
 }
 impl Error for CreateLaunchTemplateError {}
+impl ServiceErrorCode for CreateLaunchTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateLaunchTemplateVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateLaunchTemplateVersionError {}
@@ -74755,6 +75072,11 @@ impl fmt::Display for CreateLaunchTemplateVersionError {
     }
 }
 impl Error for CreateLaunchTemplateVersionError {}
+impl ServiceErrorCode for CreateLaunchTemplateVersionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateLocalGatewayRoute
 #[derive(Debug, PartialEq)]
 pub enum CreateLocalGatewayRouteError {}
@@ -74790,6 +75112,11 @@ impl fmt::Display for CreateLocalGatewayRouteError {
     }
 }
 impl Error for CreateLocalGatewayRouteError {}
+impl ServiceErrorCode for CreateLocalGatewayRouteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateLocalGatewayRouteTableVpcAssociation
 #[derive(Debug, PartialEq)]
 pub enum CreateLocalGatewayRouteTableVpcAssociationError {}
@@ -74827,6 +75154,11 @@ impl fmt::Display for CreateLocalGatewayRouteTableVpcAssociationError {
     }
 }
 impl Error for CreateLocalGatewayRouteTableVpcAssociationError {}
+impl ServiceErrorCode for CreateLocalGatewayRouteTableVpcAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateManagedPrefixList
 #[derive(Debug, PartialEq)]
 pub enum CreateManagedPrefixListError {}
@@ -74862,6 +75194,11 @@ impl fmt::Display for CreateManagedPrefixListError {
     }
 }
 impl Error for CreateManagedPrefixListError {}
+impl ServiceErrorCode for CreateManagedPrefixListError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateNatGateway
 #[derive(Debug, PartialEq)]
 pub enum CreateNatGatewayError {}
@@ -74897,6 +75234,11 @@ impl fmt::Display for CreateNatGatewayError {
     }
 }
 impl Error for CreateNatGatewayError {}
+impl ServiceErrorCode for CreateNatGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateNetworkAcl
 #[derive(Debug, PartialEq)]
 pub enum CreateNetworkAclError {}
@@ -74932,6 +75274,11 @@ impl fmt::Display for CreateNetworkAclError {
     }
 }
 impl Error for CreateNetworkAclError {}
+impl ServiceErrorCode for CreateNetworkAclError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateNetworkAclEntry
 #[derive(Debug, PartialEq)]
 pub enum CreateNetworkAclEntryError {}
@@ -74967,6 +75314,11 @@ impl fmt::Display for CreateNetworkAclEntryError {
     }
 }
 impl Error for CreateNetworkAclEntryError {}
+impl ServiceErrorCode for CreateNetworkAclEntryError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateNetworkInsightsPath
 #[derive(Debug, PartialEq)]
 pub enum CreateNetworkInsightsPathError {}
@@ -75002,6 +75354,11 @@ impl fmt::Display for CreateNetworkInsightsPathError {
     }
 }
 impl Error for CreateNetworkInsightsPathError {}
+impl ServiceErrorCode for CreateNetworkInsightsPathError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateNetworkInterface
 #[derive(Debug, PartialEq)]
 pub enum CreateNetworkInterfaceError {}
@@ -75037,6 +75394,11 @@ impl fmt::Display for CreateNetworkInterfaceError {
     }
 }
 impl Error for CreateNetworkInterfaceError {}
+impl ServiceErrorCode for CreateNetworkInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateNetworkInterfacePermission
 #[derive(Debug, PartialEq)]
 pub enum CreateNetworkInterfacePermissionError {}
@@ -75074,6 +75436,11 @@ impl fmt::Display for CreateNetworkInterfacePermissionError {
     }
 }
 impl Error for CreateNetworkInterfacePermissionError {}
+impl ServiceErrorCode for CreateNetworkInterfacePermissionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreatePlacementGroup
 #[derive(Debug, PartialEq)]
 pub enum CreatePlacementGroupError {}
@@ -75109,6 +75476,11 @@ impl fmt::Display for CreatePlacementGroupError {
     }
 }
 impl Error for CreatePlacementGroupError {}
+impl ServiceErrorCode for CreatePlacementGroupError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateReservedInstancesListing
 #[derive(Debug, PartialEq)]
 pub enum CreateReservedInstancesListingError {}
@@ -75146,6 +75518,11 @@ impl fmt::Display for CreateReservedInstancesListingError {
     }
 }
 impl Error for CreateReservedInstancesListingError {}
+impl ServiceErrorCode for CreateReservedInstancesListingError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateRoute
 #[derive(Debug, PartialEq)]
 pub enum CreateRouteError {}
@@ -75181,6 +75558,11 @@ impl fmt::Display for CreateRouteError {
     }
 }
 impl Error for CreateRouteError {}
+impl ServiceErrorCode for CreateRouteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateRouteTable
 #[derive(Debug, PartialEq)]
 pub enum CreateRouteTableError {}
@@ -75216,6 +75598,11 @@ impl fmt::Display for CreateRouteTableError {
     }
 }
 impl Error for CreateRouteTableError {}
+impl ServiceErrorCode for CreateRouteTableError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateSecurityGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateSecurityGroupError {}
@@ -75251,6 +75638,11 @@ impl fmt::Display for CreateSecurityGroupError {
     }
 }
 impl Error for CreateSecurityGroupError {}
+impl ServiceErrorCode for CreateSecurityGroupError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateSnapshotError {}
@@ -75286,6 +75678,11 @@ impl fmt::Display for CreateSnapshotError {
     }
 }
 impl Error for CreateSnapshotError {}
+impl ServiceErrorCode for CreateSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateSnapshots
 #[derive(Debug, PartialEq)]
 pub enum CreateSnapshotsError {}
@@ -75321,6 +75718,11 @@ impl fmt::Display for CreateSnapshotsError {
     }
 }
 impl Error for CreateSnapshotsError {}
+impl ServiceErrorCode for CreateSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateSpotDatafeedSubscription
 #[derive(Debug, PartialEq)]
 pub enum CreateSpotDatafeedSubscriptionError {}
@@ -75358,6 +75760,11 @@ impl fmt::Display for CreateSpotDatafeedSubscriptionError {
     }
 }
 impl Error for CreateSpotDatafeedSubscriptionError {}
+impl ServiceErrorCode for CreateSpotDatafeedSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateSubnet
 #[derive(Debug, PartialEq)]
 pub enum CreateSubnetError {}
@@ -75393,6 +75800,11 @@ impl fmt::Display for CreateSubnetError {
     }
 }
 impl Error for CreateSubnetError {}
+impl ServiceErrorCode for CreateSubnetError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateTags
 #[derive(Debug, PartialEq)]
 pub enum CreateTagsError {}
@@ -75428,6 +75840,11 @@ impl fmt::Display for CreateTagsError {
     }
 }
 impl Error for CreateTagsError {}
+impl ServiceErrorCode for CreateTagsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateTrafficMirrorFilter
 #[derive(Debug, PartialEq)]
 pub enum CreateTrafficMirrorFilterError {}
@@ -75463,6 +75880,11 @@ impl fmt::Display for CreateTrafficMirrorFilterError {
     }
 }
 impl Error for CreateTrafficMirrorFilterError {}
+impl ServiceErrorCode for CreateTrafficMirrorFilterError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateTrafficMirrorFilterRule
 #[derive(Debug, PartialEq)]
 pub enum CreateTrafficMirrorFilterRuleError {}
@@ -75500,6 +75922,11 @@ impl fmt::Display for CreateTrafficMirrorFilterRuleError {
     }
 }
 impl Error for CreateTrafficMirrorFilterRuleError {}
+impl ServiceErrorCode for CreateTrafficMirrorFilterRuleError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateTrafficMirrorSession
 #[derive(Debug, PartialEq)]
 pub enum CreateTrafficMirrorSessionError {}
@@ -75537,6 +75964,11 @@ impl fmt::Display for CreateTrafficMirrorSessionError {
     }
 }
 impl Error for CreateTrafficMirrorSessionError {}
+impl ServiceErrorCode for CreateTrafficMirrorSessionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateTrafficMirrorTarget
 #[derive(Debug, PartialEq)]
 pub enum CreateTrafficMirrorTargetError {}
@@ -75572,6 +76004,11 @@ impl fmt::Display for CreateTrafficMirrorTargetError {
     }
 }
 impl Error for CreateTrafficMirrorTargetError {}
+impl ServiceErrorCode for CreateTrafficMirrorTargetError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateTransitGateway
 #[derive(Debug, PartialEq)]
 pub enum CreateTransitGatewayError {}
@@ -75607,6 +76044,11 @@ impl fmt::Display for CreateTransitGatewayError {
     }
 }
 impl Error for CreateTransitGatewayError {}
+impl ServiceErrorCode for CreateTransitGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateTransitGatewayConnect
 #[derive(Debug, PartialEq)]
 pub enum CreateTransitGatewayConnectError {}
@@ -75644,6 +76086,11 @@ impl fmt::Display for CreateTransitGatewayConnectError {
     }
 }
 impl Error for CreateTransitGatewayConnectError {}
+impl ServiceErrorCode for CreateTransitGatewayConnectError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateTransitGatewayConnectPeer
 #[derive(Debug, PartialEq)]
 pub enum CreateTransitGatewayConnectPeerError {}
@@ -75681,6 +76128,11 @@ impl fmt::Display for CreateTransitGatewayConnectPeerError {
     }
 }
 impl Error for CreateTransitGatewayConnectPeerError {}
+impl ServiceErrorCode for CreateTransitGatewayConnectPeerError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateTransitGatewayMulticastDomain
 #[derive(Debug, PartialEq)]
 pub enum CreateTransitGatewayMulticastDomainError {}
@@ -75718,6 +76170,11 @@ impl fmt::Display for CreateTransitGatewayMulticastDomainError {
     }
 }
 impl Error for CreateTransitGatewayMulticastDomainError {}
+impl ServiceErrorCode for CreateTransitGatewayMulticastDomainError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateTransitGatewayPeeringAttachment
 #[derive(Debug, PartialEq)]
 pub enum CreateTransitGatewayPeeringAttachmentError {}
@@ -75755,6 +76212,11 @@ impl fmt::Display for CreateTransitGatewayPeeringAttachmentError {
     }
 }
 impl Error for CreateTransitGatewayPeeringAttachmentError {}
+impl ServiceErrorCode for CreateTransitGatewayPeeringAttachmentError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateTransitGatewayPrefixListReference
 #[derive(Debug, PartialEq)]
 pub enum CreateTransitGatewayPrefixListReferenceError {}
@@ -75792,6 +76254,11 @@ impl fmt::Display for CreateTransitGatewayPrefixListReferenceError {
     }
 }
 impl Error for CreateTransitGatewayPrefixListReferenceError {}
+impl ServiceErrorCode for CreateTransitGatewayPrefixListReferenceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateTransitGatewayRoute
 #[derive(Debug, PartialEq)]
 pub enum CreateTransitGatewayRouteError {}
@@ -75827,6 +76294,11 @@ impl fmt::Display for CreateTransitGatewayRouteError {
     }
 }
 impl Error for CreateTransitGatewayRouteError {}
+impl ServiceErrorCode for CreateTransitGatewayRouteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateTransitGatewayRouteTable
 #[derive(Debug, PartialEq)]
 pub enum CreateTransitGatewayRouteTableError {}
@@ -75864,6 +76336,11 @@ impl fmt::Display for CreateTransitGatewayRouteTableError {
     }
 }
 impl Error for CreateTransitGatewayRouteTableError {}
+impl ServiceErrorCode for CreateTransitGatewayRouteTableError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateTransitGatewayVpcAttachment
 #[derive(Debug, PartialEq)]
 pub enum CreateTransitGatewayVpcAttachmentError {}
@@ -75901,6 +76378,11 @@ impl fmt::Display for CreateTransitGatewayVpcAttachmentError {
     }
 }
 impl Error for CreateTransitGatewayVpcAttachmentError {}
+impl ServiceErrorCode for CreateTransitGatewayVpcAttachmentError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateVolume
 #[derive(Debug, PartialEq)]
 pub enum CreateVolumeError {}
@@ -75936,6 +76418,11 @@ impl fmt::Display for CreateVolumeError {
     }
 }
 impl Error for CreateVolumeError {}
+impl ServiceErrorCode for CreateVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateVpc
 #[derive(Debug, PartialEq)]
 pub enum CreateVpcError {}
@@ -75971,6 +76458,11 @@ impl fmt::Display for CreateVpcError {
     }
 }
 impl Error for CreateVpcError {}
+impl ServiceErrorCode for CreateVpcError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateVpcEndpoint
 #[derive(Debug, PartialEq)]
 pub enum CreateVpcEndpointError {}
@@ -76006,6 +76498,11 @@ impl fmt::Display for CreateVpcEndpointError {
     }
 }
 impl Error for CreateVpcEndpointError {}
+impl ServiceErrorCode for CreateVpcEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateVpcEndpointConnectionNotification
 #[derive(Debug, PartialEq)]
 pub enum CreateVpcEndpointConnectionNotificationError {}
@@ -76043,6 +76540,11 @@ impl fmt::Display for CreateVpcEndpointConnectionNotificationError {
     }
 }
 impl Error for CreateVpcEndpointConnectionNotificationError {}
+impl ServiceErrorCode for CreateVpcEndpointConnectionNotificationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateVpcEndpointServiceConfiguration
 #[derive(Debug, PartialEq)]
 pub enum CreateVpcEndpointServiceConfigurationError {}
@@ -76080,6 +76582,11 @@ impl fmt::Display for CreateVpcEndpointServiceConfigurationError {
     }
 }
 impl Error for CreateVpcEndpointServiceConfigurationError {}
+impl ServiceErrorCode for CreateVpcEndpointServiceConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateVpcPeeringConnection
 #[derive(Debug, PartialEq)]
 pub enum CreateVpcPeeringConnectionError {}
@@ -76117,6 +76624,11 @@ impl fmt::Display for CreateVpcPeeringConnectionError {
     }
 }
 impl Error for CreateVpcPeeringConnectionError {}
+impl ServiceErrorCode for CreateVpcPeeringConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateVpnConnection
 #[derive(Debug, PartialEq)]
 pub enum CreateVpnConnectionError {}
@@ -76152,6 +76664,11 @@ impl fmt::Display for CreateVpnConnectionError {
     }
 }
 impl Error for CreateVpnConnectionError {}
+impl ServiceErrorCode for CreateVpnConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateVpnConnectionRoute
 #[derive(Debug, PartialEq)]
 pub enum CreateVpnConnectionRouteError {}
@@ -76187,6 +76704,11 @@ impl fmt::Display for CreateVpnConnectionRouteError {
     }
 }
 impl Error for CreateVpnConnectionRouteError {}
+impl ServiceErrorCode for CreateVpnConnectionRouteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateVpnGateway
 #[derive(Debug, PartialEq)]
 pub enum CreateVpnGatewayError {}
@@ -76222,6 +76744,11 @@ impl fmt::Display for CreateVpnGatewayError {
     }
 }
 impl Error for CreateVpnGatewayError {}
+impl ServiceErrorCode for CreateVpnGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteCarrierGateway
 #[derive(Debug, PartialEq)]
 pub enum DeleteCarrierGatewayError {}
@@ -76257,6 +76784,11 @@ impl fmt::Display for DeleteCarrierGatewayError {
     }
 }
 impl Error for DeleteCarrierGatewayError {}
+impl ServiceErrorCode for DeleteCarrierGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteClientVpnEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DeleteClientVpnEndpointError {}
@@ -76292,6 +76824,11 @@ impl fmt::Display for DeleteClientVpnEndpointError {
     }
 }
 impl Error for DeleteClientVpnEndpointError {}
+impl ServiceErrorCode for DeleteClientVpnEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteClientVpnRoute
 #[derive(Debug, PartialEq)]
 pub enum DeleteClientVpnRouteError {}
@@ -76327,6 +76864,11 @@ impl fmt::Display for DeleteClientVpnRouteError {
     }
 }
 impl Error for DeleteClientVpnRouteError {}
+impl ServiceErrorCode for DeleteClientVpnRouteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteCustomerGateway
 #[derive(Debug, PartialEq)]
 pub enum DeleteCustomerGatewayError {}
@@ -76362,6 +76904,11 @@ impl fmt::Display for DeleteCustomerGatewayError {
     }
 }
 impl Error for DeleteCustomerGatewayError {}
+impl ServiceErrorCode for DeleteCustomerGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteDhcpOptions
 #[derive(Debug, PartialEq)]
 pub enum DeleteDhcpOptionsError {}
@@ -76397,6 +76944,11 @@ impl fmt::Display for DeleteDhcpOptionsError {
     }
 }
 impl Error for DeleteDhcpOptionsError {}
+impl ServiceErrorCode for DeleteDhcpOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteEgressOnlyInternetGateway
 #[derive(Debug, PartialEq)]
 pub enum DeleteEgressOnlyInternetGatewayError {}
@@ -76434,6 +76986,11 @@ impl fmt::Display for DeleteEgressOnlyInternetGatewayError {
     }
 }
 impl Error for DeleteEgressOnlyInternetGatewayError {}
+impl ServiceErrorCode for DeleteEgressOnlyInternetGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteFleets
 #[derive(Debug, PartialEq)]
 pub enum DeleteFleetsError {}
@@ -76469,6 +77026,11 @@ impl fmt::Display for DeleteFleetsError {
     }
 }
 impl Error for DeleteFleetsError {}
+impl ServiceErrorCode for DeleteFleetsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteFlowLogs
 #[derive(Debug, PartialEq)]
 pub enum DeleteFlowLogsError {}
@@ -76504,6 +77066,11 @@ impl fmt::Display for DeleteFlowLogsError {
     }
 }
 impl Error for DeleteFlowLogsError {}
+impl ServiceErrorCode for DeleteFlowLogsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteFpgaImage
 #[derive(Debug, PartialEq)]
 pub enum DeleteFpgaImageError {}
@@ -76539,6 +77106,11 @@ impl fmt::Display for DeleteFpgaImageError {
     }
 }
 impl Error for DeleteFpgaImageError {}
+impl ServiceErrorCode for DeleteFpgaImageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteInternetGateway
 #[derive(Debug, PartialEq)]
 pub enum DeleteInternetGatewayError {}
@@ -76574,6 +77146,11 @@ impl fmt::Display for DeleteInternetGatewayError {
     }
 }
 impl Error for DeleteInternetGatewayError {}
+impl ServiceErrorCode for DeleteInternetGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteKeyPair
 #[derive(Debug, PartialEq)]
 pub enum DeleteKeyPairError {}
@@ -76609,6 +77186,11 @@ impl fmt::Display for DeleteKeyPairError {
     }
 }
 impl Error for DeleteKeyPairError {}
+impl ServiceErrorCode for DeleteKeyPairError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteLaunchTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeleteLaunchTemplateError {}
@@ -76644,6 +77226,11 @@ impl fmt::Display for DeleteLaunchTemplateError {
     }
 }
 impl Error for DeleteLaunchTemplateError {}
+impl ServiceErrorCode for DeleteLaunchTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteLaunchTemplateVersions
 #[derive(Debug, PartialEq)]
 pub enum DeleteLaunchTemplateVersionsError {}
@@ -76681,6 +77268,11 @@ impl fmt::Display for DeleteLaunchTemplateVersionsError {
     }
 }
 impl Error for DeleteLaunchTemplateVersionsError {}
+impl ServiceErrorCode for DeleteLaunchTemplateVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteLocalGatewayRoute
 #[derive(Debug, PartialEq)]
 pub enum DeleteLocalGatewayRouteError {}
@@ -76716,6 +77308,11 @@ impl fmt::Display for DeleteLocalGatewayRouteError {
     }
 }
 impl Error for DeleteLocalGatewayRouteError {}
+impl ServiceErrorCode for DeleteLocalGatewayRouteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteLocalGatewayRouteTableVpcAssociation
 #[derive(Debug, PartialEq)]
 pub enum DeleteLocalGatewayRouteTableVpcAssociationError {}
@@ -76753,6 +77350,11 @@ impl fmt::Display for DeleteLocalGatewayRouteTableVpcAssociationError {
     }
 }
 impl Error for DeleteLocalGatewayRouteTableVpcAssociationError {}
+impl ServiceErrorCode for DeleteLocalGatewayRouteTableVpcAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteManagedPrefixList
 #[derive(Debug, PartialEq)]
 pub enum DeleteManagedPrefixListError {}
@@ -76788,6 +77390,11 @@ impl fmt::Display for DeleteManagedPrefixListError {
     }
 }
 impl Error for DeleteManagedPrefixListError {}
+impl ServiceErrorCode for DeleteManagedPrefixListError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteNatGateway
 #[derive(Debug, PartialEq)]
 pub enum DeleteNatGatewayError {}
@@ -76823,6 +77430,11 @@ impl fmt::Display for DeleteNatGatewayError {
     }
 }
 impl Error for DeleteNatGatewayError {}
+impl ServiceErrorCode for DeleteNatGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteNetworkAcl
 #[derive(Debug, PartialEq)]
 pub enum DeleteNetworkAclError {}
@@ -76858,6 +77470,11 @@ impl fmt::Display for DeleteNetworkAclError {
     }
 }
 impl Error for DeleteNetworkAclError {}
+impl ServiceErrorCode for DeleteNetworkAclError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteNetworkAclEntry
 #[derive(Debug, PartialEq)]
 pub enum DeleteNetworkAclEntryError {}
@@ -76893,6 +77510,11 @@ impl fmt::Display for DeleteNetworkAclEntryError {
     }
 }
 impl Error for DeleteNetworkAclEntryError {}
+impl ServiceErrorCode for DeleteNetworkAclEntryError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteNetworkInsightsAnalysis
 #[derive(Debug, PartialEq)]
 pub enum DeleteNetworkInsightsAnalysisError {}
@@ -76930,6 +77552,11 @@ impl fmt::Display for DeleteNetworkInsightsAnalysisError {
     }
 }
 impl Error for DeleteNetworkInsightsAnalysisError {}
+impl ServiceErrorCode for DeleteNetworkInsightsAnalysisError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteNetworkInsightsPath
 #[derive(Debug, PartialEq)]
 pub enum DeleteNetworkInsightsPathError {}
@@ -76965,6 +77592,11 @@ impl fmt::Display for DeleteNetworkInsightsPathError {
     }
 }
 impl Error for DeleteNetworkInsightsPathError {}
+impl ServiceErrorCode for DeleteNetworkInsightsPathError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteNetworkInterface
 #[derive(Debug, PartialEq)]
 pub enum DeleteNetworkInterfaceError {}
@@ -77000,6 +77632,11 @@ impl fmt::Display for DeleteNetworkInterfaceError {
     }
 }
 impl Error for DeleteNetworkInterfaceError {}
+impl ServiceErrorCode for DeleteNetworkInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteNetworkInterfacePermission
 #[derive(Debug, PartialEq)]
 pub enum DeleteNetworkInterfacePermissionError {}
@@ -77037,6 +77674,11 @@ impl fmt::Display for DeleteNetworkInterfacePermissionError {
     }
 }
 impl Error for DeleteNetworkInterfacePermissionError {}
+impl ServiceErrorCode for DeleteNetworkInterfacePermissionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeletePlacementGroup
 #[derive(Debug, PartialEq)]
 pub enum DeletePlacementGroupError {}
@@ -77072,6 +77714,11 @@ impl fmt::Display for DeletePlacementGroupError {
     }
 }
 impl Error for DeletePlacementGroupError {}
+impl ServiceErrorCode for DeletePlacementGroupError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteQueuedReservedInstances
 #[derive(Debug, PartialEq)]
 pub enum DeleteQueuedReservedInstancesError {}
@@ -77109,6 +77756,11 @@ impl fmt::Display for DeleteQueuedReservedInstancesError {
     }
 }
 impl Error for DeleteQueuedReservedInstancesError {}
+impl ServiceErrorCode for DeleteQueuedReservedInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteRoute
 #[derive(Debug, PartialEq)]
 pub enum DeleteRouteError {}
@@ -77144,6 +77796,11 @@ impl fmt::Display for DeleteRouteError {
     }
 }
 impl Error for DeleteRouteError {}
+impl ServiceErrorCode for DeleteRouteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteRouteTable
 #[derive(Debug, PartialEq)]
 pub enum DeleteRouteTableError {}
@@ -77179,6 +77836,11 @@ impl fmt::Display for DeleteRouteTableError {
     }
 }
 impl Error for DeleteRouteTableError {}
+impl ServiceErrorCode for DeleteRouteTableError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteSecurityGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteSecurityGroupError {}
@@ -77214,6 +77876,11 @@ impl fmt::Display for DeleteSecurityGroupError {
     }
 }
 impl Error for DeleteSecurityGroupError {}
+impl ServiceErrorCode for DeleteSecurityGroupError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteSnapshot
 #[derive(Debug, PartialEq)]
 pub enum DeleteSnapshotError {}
@@ -77249,6 +77916,11 @@ impl fmt::Display for DeleteSnapshotError {
     }
 }
 impl Error for DeleteSnapshotError {}
+impl ServiceErrorCode for DeleteSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteSpotDatafeedSubscription
 #[derive(Debug, PartialEq)]
 pub enum DeleteSpotDatafeedSubscriptionError {}
@@ -77286,6 +77958,11 @@ impl fmt::Display for DeleteSpotDatafeedSubscriptionError {
     }
 }
 impl Error for DeleteSpotDatafeedSubscriptionError {}
+impl ServiceErrorCode for DeleteSpotDatafeedSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteSubnet
 #[derive(Debug, PartialEq)]
 pub enum DeleteSubnetError {}
@@ -77321,6 +77998,11 @@ impl fmt::Display for DeleteSubnetError {
     }
 }
 impl Error for DeleteSubnetError {}
+impl ServiceErrorCode for DeleteSubnetError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
 pub enum DeleteTagsError {}
@@ -77356,6 +78038,11 @@ impl fmt::Display for DeleteTagsError {
     }
 }
 impl Error for DeleteTagsError {}
+impl ServiceErrorCode for DeleteTagsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTrafficMirrorFilter
 #[derive(Debug, PartialEq)]
 pub enum DeleteTrafficMirrorFilterError {}
@@ -77391,6 +78078,11 @@ impl fmt::Display for DeleteTrafficMirrorFilterError {
     }
 }
 impl Error for DeleteTrafficMirrorFilterError {}
+impl ServiceErrorCode for DeleteTrafficMirrorFilterError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTrafficMirrorFilterRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteTrafficMirrorFilterRuleError {}
@@ -77428,6 +78120,11 @@ impl fmt::Display for DeleteTrafficMirrorFilterRuleError {
     }
 }
 impl Error for DeleteTrafficMirrorFilterRuleError {}
+impl ServiceErrorCode for DeleteTrafficMirrorFilterRuleError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTrafficMirrorSession
 #[derive(Debug, PartialEq)]
 pub enum DeleteTrafficMirrorSessionError {}
@@ -77465,6 +78162,11 @@ impl fmt::Display for DeleteTrafficMirrorSessionError {
     }
 }
 impl Error for DeleteTrafficMirrorSessionError {}
+impl ServiceErrorCode for DeleteTrafficMirrorSessionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTrafficMirrorTarget
 #[derive(Debug, PartialEq)]
 pub enum DeleteTrafficMirrorTargetError {}
@@ -77500,6 +78202,11 @@ impl fmt::Display for DeleteTrafficMirrorTargetError {
     }
 }
 impl Error for DeleteTrafficMirrorTargetError {}
+impl ServiceErrorCode for DeleteTrafficMirrorTargetError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTransitGateway
 #[derive(Debug, PartialEq)]
 pub enum DeleteTransitGatewayError {}
@@ -77535,6 +78242,11 @@ impl fmt::Display for DeleteTransitGatewayError {
     }
 }
 impl Error for DeleteTransitGatewayError {}
+impl ServiceErrorCode for DeleteTransitGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTransitGatewayConnect
 #[derive(Debug, PartialEq)]
 pub enum DeleteTransitGatewayConnectError {}
@@ -77572,6 +78284,11 @@ impl fmt::Display for DeleteTransitGatewayConnectError {
     }
 }
 impl Error for DeleteTransitGatewayConnectError {}
+impl ServiceErrorCode for DeleteTransitGatewayConnectError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTransitGatewayConnectPeer
 #[derive(Debug, PartialEq)]
 pub enum DeleteTransitGatewayConnectPeerError {}
@@ -77609,6 +78326,11 @@ impl fmt::Display for DeleteTransitGatewayConnectPeerError {
     }
 }
 impl Error for DeleteTransitGatewayConnectPeerError {}
+impl ServiceErrorCode for DeleteTransitGatewayConnectPeerError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTransitGatewayMulticastDomain
 #[derive(Debug, PartialEq)]
 pub enum DeleteTransitGatewayMulticastDomainError {}
@@ -77646,6 +78368,11 @@ impl fmt::Display for DeleteTransitGatewayMulticastDomainError {
     }
 }
 impl Error for DeleteTransitGatewayMulticastDomainError {}
+impl ServiceErrorCode for DeleteTransitGatewayMulticastDomainError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTransitGatewayPeeringAttachment
 #[derive(Debug, PartialEq)]
 pub enum DeleteTransitGatewayPeeringAttachmentError {}
@@ -77683,6 +78410,11 @@ impl fmt::Display for DeleteTransitGatewayPeeringAttachmentError {
     }
 }
 impl Error for DeleteTransitGatewayPeeringAttachmentError {}
+impl ServiceErrorCode for DeleteTransitGatewayPeeringAttachmentError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTransitGatewayPrefixListReference
 #[derive(Debug, PartialEq)]
 pub enum DeleteTransitGatewayPrefixListReferenceError {}
@@ -77720,6 +78452,11 @@ impl fmt::Display for DeleteTransitGatewayPrefixListReferenceError {
     }
 }
 impl Error for DeleteTransitGatewayPrefixListReferenceError {}
+impl ServiceErrorCode for DeleteTransitGatewayPrefixListReferenceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTransitGatewayRoute
 #[derive(Debug, PartialEq)]
 pub enum DeleteTransitGatewayRouteError {}
@@ -77755,6 +78492,11 @@ impl fmt::Display for DeleteTransitGatewayRouteError {
     }
 }
 impl Error for DeleteTransitGatewayRouteError {}
+impl ServiceErrorCode for DeleteTransitGatewayRouteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTransitGatewayRouteTable
 #[derive(Debug, PartialEq)]
 pub enum DeleteTransitGatewayRouteTableError {}
@@ -77792,6 +78534,11 @@ impl fmt::Display for DeleteTransitGatewayRouteTableError {
     }
 }
 impl Error for DeleteTransitGatewayRouteTableError {}
+impl ServiceErrorCode for DeleteTransitGatewayRouteTableError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTransitGatewayVpcAttachment
 #[derive(Debug, PartialEq)]
 pub enum DeleteTransitGatewayVpcAttachmentError {}
@@ -77829,6 +78576,11 @@ impl fmt::Display for DeleteTransitGatewayVpcAttachmentError {
     }
 }
 impl Error for DeleteTransitGatewayVpcAttachmentError {}
+impl ServiceErrorCode for DeleteTransitGatewayVpcAttachmentError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteVolume
 #[derive(Debug, PartialEq)]
 pub enum DeleteVolumeError {}
@@ -77864,6 +78616,11 @@ impl fmt::Display for DeleteVolumeError {
     }
 }
 impl Error for DeleteVolumeError {}
+impl ServiceErrorCode for DeleteVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteVpc
 #[derive(Debug, PartialEq)]
 pub enum DeleteVpcError {}
@@ -77899,6 +78656,11 @@ impl fmt::Display for DeleteVpcError {
     }
 }
 impl Error for DeleteVpcError {}
+impl ServiceErrorCode for DeleteVpcError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteVpcEndpointConnectionNotifications
 #[derive(Debug, PartialEq)]
 pub enum DeleteVpcEndpointConnectionNotificationsError {}
@@ -77936,6 +78698,11 @@ impl fmt::Display for DeleteVpcEndpointConnectionNotificationsError {
     }
 }
 impl Error for DeleteVpcEndpointConnectionNotificationsError {}
+impl ServiceErrorCode for DeleteVpcEndpointConnectionNotificationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteVpcEndpointServiceConfigurations
 #[derive(Debug, PartialEq)]
 pub enum DeleteVpcEndpointServiceConfigurationsError {}
@@ -77973,6 +78740,11 @@ impl fmt::Display for DeleteVpcEndpointServiceConfigurationsError {
     }
 }
 impl Error for DeleteVpcEndpointServiceConfigurationsError {}
+impl ServiceErrorCode for DeleteVpcEndpointServiceConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteVpcEndpoints
 #[derive(Debug, PartialEq)]
 pub enum DeleteVpcEndpointsError {}
@@ -78008,6 +78780,11 @@ impl fmt::Display for DeleteVpcEndpointsError {
     }
 }
 impl Error for DeleteVpcEndpointsError {}
+impl ServiceErrorCode for DeleteVpcEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteVpcPeeringConnection
 #[derive(Debug, PartialEq)]
 pub enum DeleteVpcPeeringConnectionError {}
@@ -78045,6 +78822,11 @@ impl fmt::Display for DeleteVpcPeeringConnectionError {
     }
 }
 impl Error for DeleteVpcPeeringConnectionError {}
+impl ServiceErrorCode for DeleteVpcPeeringConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteVpnConnection
 #[derive(Debug, PartialEq)]
 pub enum DeleteVpnConnectionError {}
@@ -78080,6 +78862,11 @@ impl fmt::Display for DeleteVpnConnectionError {
     }
 }
 impl Error for DeleteVpnConnectionError {}
+impl ServiceErrorCode for DeleteVpnConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteVpnConnectionRoute
 #[derive(Debug, PartialEq)]
 pub enum DeleteVpnConnectionRouteError {}
@@ -78115,6 +78902,11 @@ impl fmt::Display for DeleteVpnConnectionRouteError {
     }
 }
 impl Error for DeleteVpnConnectionRouteError {}
+impl ServiceErrorCode for DeleteVpnConnectionRouteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteVpnGateway
 #[derive(Debug, PartialEq)]
 pub enum DeleteVpnGatewayError {}
@@ -78150,6 +78942,11 @@ impl fmt::Display for DeleteVpnGatewayError {
     }
 }
 impl Error for DeleteVpnGatewayError {}
+impl ServiceErrorCode for DeleteVpnGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeprovisionByoipCidr
 #[derive(Debug, PartialEq)]
 pub enum DeprovisionByoipCidrError {}
@@ -78185,6 +78982,11 @@ impl fmt::Display for DeprovisionByoipCidrError {
     }
 }
 impl Error for DeprovisionByoipCidrError {}
+impl ServiceErrorCode for DeprovisionByoipCidrError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeregisterImage
 #[derive(Debug, PartialEq)]
 pub enum DeregisterImageError {}
@@ -78220,6 +79022,11 @@ impl fmt::Display for DeregisterImageError {
     }
 }
 impl Error for DeregisterImageError {}
+impl ServiceErrorCode for DeregisterImageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeregisterInstanceEventNotificationAttributes
 #[derive(Debug, PartialEq)]
 pub enum DeregisterInstanceEventNotificationAttributesError {}
@@ -78257,6 +79064,11 @@ impl fmt::Display for DeregisterInstanceEventNotificationAttributesError {
     }
 }
 impl Error for DeregisterInstanceEventNotificationAttributesError {}
+impl ServiceErrorCode for DeregisterInstanceEventNotificationAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeregisterTransitGatewayMulticastGroupMembers
 #[derive(Debug, PartialEq)]
 pub enum DeregisterTransitGatewayMulticastGroupMembersError {}
@@ -78294,6 +79106,11 @@ impl fmt::Display for DeregisterTransitGatewayMulticastGroupMembersError {
     }
 }
 impl Error for DeregisterTransitGatewayMulticastGroupMembersError {}
+impl ServiceErrorCode for DeregisterTransitGatewayMulticastGroupMembersError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeregisterTransitGatewayMulticastGroupSources
 #[derive(Debug, PartialEq)]
 pub enum DeregisterTransitGatewayMulticastGroupSourcesError {}
@@ -78331,6 +79148,11 @@ impl fmt::Display for DeregisterTransitGatewayMulticastGroupSourcesError {
     }
 }
 impl Error for DeregisterTransitGatewayMulticastGroupSourcesError {}
+impl ServiceErrorCode for DeregisterTransitGatewayMulticastGroupSourcesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeAccountAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountAttributesError {}
@@ -78366,6 +79188,11 @@ impl fmt::Display for DescribeAccountAttributesError {
     }
 }
 impl Error for DescribeAccountAttributesError {}
+impl ServiceErrorCode for DescribeAccountAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeAddresses
 #[derive(Debug, PartialEq)]
 pub enum DescribeAddressesError {}
@@ -78401,6 +79228,11 @@ impl fmt::Display for DescribeAddressesError {
     }
 }
 impl Error for DescribeAddressesError {}
+impl ServiceErrorCode for DescribeAddressesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeAggregateIdFormat
 #[derive(Debug, PartialEq)]
 pub enum DescribeAggregateIdFormatError {}
@@ -78436,6 +79268,11 @@ impl fmt::Display for DescribeAggregateIdFormatError {
     }
 }
 impl Error for DescribeAggregateIdFormatError {}
+impl ServiceErrorCode for DescribeAggregateIdFormatError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeAvailabilityZones
 #[derive(Debug, PartialEq)]
 pub enum DescribeAvailabilityZonesError {}
@@ -78471,6 +79308,11 @@ impl fmt::Display for DescribeAvailabilityZonesError {
     }
 }
 impl Error for DescribeAvailabilityZonesError {}
+impl ServiceErrorCode for DescribeAvailabilityZonesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeBundleTasks
 #[derive(Debug, PartialEq)]
 pub enum DescribeBundleTasksError {}
@@ -78506,6 +79348,11 @@ impl fmt::Display for DescribeBundleTasksError {
     }
 }
 impl Error for DescribeBundleTasksError {}
+impl ServiceErrorCode for DescribeBundleTasksError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeByoipCidrs
 #[derive(Debug, PartialEq)]
 pub enum DescribeByoipCidrsError {}
@@ -78541,6 +79388,11 @@ impl fmt::Display for DescribeByoipCidrsError {
     }
 }
 impl Error for DescribeByoipCidrsError {}
+impl ServiceErrorCode for DescribeByoipCidrsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeCapacityReservations
 #[derive(Debug, PartialEq)]
 pub enum DescribeCapacityReservationsError {}
@@ -78578,6 +79430,11 @@ impl fmt::Display for DescribeCapacityReservationsError {
     }
 }
 impl Error for DescribeCapacityReservationsError {}
+impl ServiceErrorCode for DescribeCapacityReservationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeCarrierGateways
 #[derive(Debug, PartialEq)]
 pub enum DescribeCarrierGatewaysError {}
@@ -78613,6 +79470,11 @@ impl fmt::Display for DescribeCarrierGatewaysError {
     }
 }
 impl Error for DescribeCarrierGatewaysError {}
+impl ServiceErrorCode for DescribeCarrierGatewaysError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeClassicLinkInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeClassicLinkInstancesError {}
@@ -78650,6 +79512,11 @@ impl fmt::Display for DescribeClassicLinkInstancesError {
     }
 }
 impl Error for DescribeClassicLinkInstancesError {}
+impl ServiceErrorCode for DescribeClassicLinkInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeClientVpnAuthorizationRules
 #[derive(Debug, PartialEq)]
 pub enum DescribeClientVpnAuthorizationRulesError {}
@@ -78687,6 +79554,11 @@ impl fmt::Display for DescribeClientVpnAuthorizationRulesError {
     }
 }
 impl Error for DescribeClientVpnAuthorizationRulesError {}
+impl ServiceErrorCode for DescribeClientVpnAuthorizationRulesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeClientVpnConnections
 #[derive(Debug, PartialEq)]
 pub enum DescribeClientVpnConnectionsError {}
@@ -78724,6 +79596,11 @@ impl fmt::Display for DescribeClientVpnConnectionsError {
     }
 }
 impl Error for DescribeClientVpnConnectionsError {}
+impl ServiceErrorCode for DescribeClientVpnConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeClientVpnEndpoints
 #[derive(Debug, PartialEq)]
 pub enum DescribeClientVpnEndpointsError {}
@@ -78761,6 +79638,11 @@ impl fmt::Display for DescribeClientVpnEndpointsError {
     }
 }
 impl Error for DescribeClientVpnEndpointsError {}
+impl ServiceErrorCode for DescribeClientVpnEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeClientVpnRoutes
 #[derive(Debug, PartialEq)]
 pub enum DescribeClientVpnRoutesError {}
@@ -78796,6 +79678,11 @@ impl fmt::Display for DescribeClientVpnRoutesError {
     }
 }
 impl Error for DescribeClientVpnRoutesError {}
+impl ServiceErrorCode for DescribeClientVpnRoutesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeClientVpnTargetNetworks
 #[derive(Debug, PartialEq)]
 pub enum DescribeClientVpnTargetNetworksError {}
@@ -78833,6 +79720,11 @@ impl fmt::Display for DescribeClientVpnTargetNetworksError {
     }
 }
 impl Error for DescribeClientVpnTargetNetworksError {}
+impl ServiceErrorCode for DescribeClientVpnTargetNetworksError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeCoipPools
 #[derive(Debug, PartialEq)]
 pub enum DescribeCoipPoolsError {}
@@ -78868,6 +79760,11 @@ impl fmt::Display for DescribeCoipPoolsError {
     }
 }
 impl Error for DescribeCoipPoolsError {}
+impl ServiceErrorCode for DescribeCoipPoolsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeConversionTasks
 #[derive(Debug, PartialEq)]
 pub enum DescribeConversionTasksError {}
@@ -78903,6 +79800,11 @@ impl fmt::Display for DescribeConversionTasksError {
     }
 }
 impl Error for DescribeConversionTasksError {}
+impl ServiceErrorCode for DescribeConversionTasksError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeCustomerGateways
 #[derive(Debug, PartialEq)]
 pub enum DescribeCustomerGatewaysError {}
@@ -78938,6 +79840,11 @@ impl fmt::Display for DescribeCustomerGatewaysError {
     }
 }
 impl Error for DescribeCustomerGatewaysError {}
+impl ServiceErrorCode for DescribeCustomerGatewaysError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeDhcpOptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeDhcpOptionsError {}
@@ -78973,6 +79880,11 @@ impl fmt::Display for DescribeDhcpOptionsError {
     }
 }
 impl Error for DescribeDhcpOptionsError {}
+impl ServiceErrorCode for DescribeDhcpOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEgressOnlyInternetGateways
 #[derive(Debug, PartialEq)]
 pub enum DescribeEgressOnlyInternetGatewaysError {}
@@ -79010,6 +79922,11 @@ impl fmt::Display for DescribeEgressOnlyInternetGatewaysError {
     }
 }
 impl Error for DescribeEgressOnlyInternetGatewaysError {}
+impl ServiceErrorCode for DescribeEgressOnlyInternetGatewaysError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeElasticGpus
 #[derive(Debug, PartialEq)]
 pub enum DescribeElasticGpusError {}
@@ -79045,6 +79962,11 @@ impl fmt::Display for DescribeElasticGpusError {
     }
 }
 impl Error for DescribeElasticGpusError {}
+impl ServiceErrorCode for DescribeElasticGpusError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeExportImageTasks
 #[derive(Debug, PartialEq)]
 pub enum DescribeExportImageTasksError {}
@@ -79080,6 +80002,11 @@ impl fmt::Display for DescribeExportImageTasksError {
     }
 }
 impl Error for DescribeExportImageTasksError {}
+impl ServiceErrorCode for DescribeExportImageTasksError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeExportTasks
 #[derive(Debug, PartialEq)]
 pub enum DescribeExportTasksError {}
@@ -79115,6 +80042,11 @@ impl fmt::Display for DescribeExportTasksError {
     }
 }
 impl Error for DescribeExportTasksError {}
+impl ServiceErrorCode for DescribeExportTasksError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeFastSnapshotRestores
 #[derive(Debug, PartialEq)]
 pub enum DescribeFastSnapshotRestoresError {}
@@ -79152,6 +80084,11 @@ impl fmt::Display for DescribeFastSnapshotRestoresError {
     }
 }
 impl Error for DescribeFastSnapshotRestoresError {}
+impl ServiceErrorCode for DescribeFastSnapshotRestoresError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeFleetHistory
 #[derive(Debug, PartialEq)]
 pub enum DescribeFleetHistoryError {}
@@ -79187,6 +80124,11 @@ impl fmt::Display for DescribeFleetHistoryError {
     }
 }
 impl Error for DescribeFleetHistoryError {}
+impl ServiceErrorCode for DescribeFleetHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeFleetInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeFleetInstancesError {}
@@ -79222,6 +80164,11 @@ impl fmt::Display for DescribeFleetInstancesError {
     }
 }
 impl Error for DescribeFleetInstancesError {}
+impl ServiceErrorCode for DescribeFleetInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeFleets
 #[derive(Debug, PartialEq)]
 pub enum DescribeFleetsError {}
@@ -79257,6 +80204,11 @@ impl fmt::Display for DescribeFleetsError {
     }
 }
 impl Error for DescribeFleetsError {}
+impl ServiceErrorCode for DescribeFleetsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeFlowLogs
 #[derive(Debug, PartialEq)]
 pub enum DescribeFlowLogsError {}
@@ -79292,6 +80244,11 @@ impl fmt::Display for DescribeFlowLogsError {
     }
 }
 impl Error for DescribeFlowLogsError {}
+impl ServiceErrorCode for DescribeFlowLogsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeFpgaImageAttribute
 #[derive(Debug, PartialEq)]
 pub enum DescribeFpgaImageAttributeError {}
@@ -79329,6 +80286,11 @@ impl fmt::Display for DescribeFpgaImageAttributeError {
     }
 }
 impl Error for DescribeFpgaImageAttributeError {}
+impl ServiceErrorCode for DescribeFpgaImageAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeFpgaImages
 #[derive(Debug, PartialEq)]
 pub enum DescribeFpgaImagesError {}
@@ -79364,6 +80326,11 @@ impl fmt::Display for DescribeFpgaImagesError {
     }
 }
 impl Error for DescribeFpgaImagesError {}
+impl ServiceErrorCode for DescribeFpgaImagesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeHostReservationOfferings
 #[derive(Debug, PartialEq)]
 pub enum DescribeHostReservationOfferingsError {}
@@ -79401,6 +80368,11 @@ impl fmt::Display for DescribeHostReservationOfferingsError {
     }
 }
 impl Error for DescribeHostReservationOfferingsError {}
+impl ServiceErrorCode for DescribeHostReservationOfferingsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeHostReservations
 #[derive(Debug, PartialEq)]
 pub enum DescribeHostReservationsError {}
@@ -79436,6 +80408,11 @@ impl fmt::Display for DescribeHostReservationsError {
     }
 }
 impl Error for DescribeHostReservationsError {}
+impl ServiceErrorCode for DescribeHostReservationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeHosts
 #[derive(Debug, PartialEq)]
 pub enum DescribeHostsError {}
@@ -79471,6 +80448,11 @@ impl fmt::Display for DescribeHostsError {
     }
 }
 impl Error for DescribeHostsError {}
+impl ServiceErrorCode for DescribeHostsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeIamInstanceProfileAssociations
 #[derive(Debug, PartialEq)]
 pub enum DescribeIamInstanceProfileAssociationsError {}
@@ -79508,6 +80490,11 @@ impl fmt::Display for DescribeIamInstanceProfileAssociationsError {
     }
 }
 impl Error for DescribeIamInstanceProfileAssociationsError {}
+impl ServiceErrorCode for DescribeIamInstanceProfileAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeIdFormat
 #[derive(Debug, PartialEq)]
 pub enum DescribeIdFormatError {}
@@ -79543,6 +80530,11 @@ impl fmt::Display for DescribeIdFormatError {
     }
 }
 impl Error for DescribeIdFormatError {}
+impl ServiceErrorCode for DescribeIdFormatError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeIdentityIdFormat
 #[derive(Debug, PartialEq)]
 pub enum DescribeIdentityIdFormatError {}
@@ -79578,6 +80570,11 @@ impl fmt::Display for DescribeIdentityIdFormatError {
     }
 }
 impl Error for DescribeIdentityIdFormatError {}
+impl ServiceErrorCode for DescribeIdentityIdFormatError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeImageAttribute
 #[derive(Debug, PartialEq)]
 pub enum DescribeImageAttributeError {}
@@ -79613,6 +80610,11 @@ impl fmt::Display for DescribeImageAttributeError {
     }
 }
 impl Error for DescribeImageAttributeError {}
+impl ServiceErrorCode for DescribeImageAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeImages
 #[derive(Debug, PartialEq)]
 pub enum DescribeImagesError {}
@@ -79648,6 +80650,11 @@ impl fmt::Display for DescribeImagesError {
     }
 }
 impl Error for DescribeImagesError {}
+impl ServiceErrorCode for DescribeImagesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeImportImageTasks
 #[derive(Debug, PartialEq)]
 pub enum DescribeImportImageTasksError {}
@@ -79683,6 +80690,11 @@ impl fmt::Display for DescribeImportImageTasksError {
     }
 }
 impl Error for DescribeImportImageTasksError {}
+impl ServiceErrorCode for DescribeImportImageTasksError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeImportSnapshotTasks
 #[derive(Debug, PartialEq)]
 pub enum DescribeImportSnapshotTasksError {}
@@ -79720,6 +80732,11 @@ impl fmt::Display for DescribeImportSnapshotTasksError {
     }
 }
 impl Error for DescribeImportSnapshotTasksError {}
+impl ServiceErrorCode for DescribeImportSnapshotTasksError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeInstanceAttribute
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstanceAttributeError {}
@@ -79755,6 +80772,11 @@ impl fmt::Display for DescribeInstanceAttributeError {
     }
 }
 impl Error for DescribeInstanceAttributeError {}
+impl ServiceErrorCode for DescribeInstanceAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeInstanceCreditSpecifications
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstanceCreditSpecificationsError {}
@@ -79792,6 +80814,11 @@ impl fmt::Display for DescribeInstanceCreditSpecificationsError {
     }
 }
 impl Error for DescribeInstanceCreditSpecificationsError {}
+impl ServiceErrorCode for DescribeInstanceCreditSpecificationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeInstanceEventNotificationAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstanceEventNotificationAttributesError {}
@@ -79829,6 +80856,11 @@ impl fmt::Display for DescribeInstanceEventNotificationAttributesError {
     }
 }
 impl Error for DescribeInstanceEventNotificationAttributesError {}
+impl ServiceErrorCode for DescribeInstanceEventNotificationAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeInstanceStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstanceStatusError {}
@@ -79864,6 +80896,11 @@ impl fmt::Display for DescribeInstanceStatusError {
     }
 }
 impl Error for DescribeInstanceStatusError {}
+impl ServiceErrorCode for DescribeInstanceStatusError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeInstanceTypeOfferings
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstanceTypeOfferingsError {}
@@ -79901,6 +80938,11 @@ impl fmt::Display for DescribeInstanceTypeOfferingsError {
     }
 }
 impl Error for DescribeInstanceTypeOfferingsError {}
+impl ServiceErrorCode for DescribeInstanceTypeOfferingsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeInstanceTypes
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstanceTypesError {}
@@ -79936,6 +80978,11 @@ impl fmt::Display for DescribeInstanceTypesError {
     }
 }
 impl Error for DescribeInstanceTypesError {}
+impl ServiceErrorCode for DescribeInstanceTypesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstancesError {}
@@ -79971,6 +81018,11 @@ impl fmt::Display for DescribeInstancesError {
     }
 }
 impl Error for DescribeInstancesError {}
+impl ServiceErrorCode for DescribeInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeInternetGateways
 #[derive(Debug, PartialEq)]
 pub enum DescribeInternetGatewaysError {}
@@ -80006,6 +81058,11 @@ impl fmt::Display for DescribeInternetGatewaysError {
     }
 }
 impl Error for DescribeInternetGatewaysError {}
+impl ServiceErrorCode for DescribeInternetGatewaysError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeIpv6Pools
 #[derive(Debug, PartialEq)]
 pub enum DescribeIpv6PoolsError {}
@@ -80041,6 +81098,11 @@ impl fmt::Display for DescribeIpv6PoolsError {
     }
 }
 impl Error for DescribeIpv6PoolsError {}
+impl ServiceErrorCode for DescribeIpv6PoolsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeKeyPairs
 #[derive(Debug, PartialEq)]
 pub enum DescribeKeyPairsError {}
@@ -80076,6 +81138,11 @@ impl fmt::Display for DescribeKeyPairsError {
     }
 }
 impl Error for DescribeKeyPairsError {}
+impl ServiceErrorCode for DescribeKeyPairsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeLaunchTemplateVersions
 #[derive(Debug, PartialEq)]
 pub enum DescribeLaunchTemplateVersionsError {}
@@ -80113,6 +81180,11 @@ impl fmt::Display for DescribeLaunchTemplateVersionsError {
     }
 }
 impl Error for DescribeLaunchTemplateVersionsError {}
+impl ServiceErrorCode for DescribeLaunchTemplateVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeLaunchTemplates
 #[derive(Debug, PartialEq)]
 pub enum DescribeLaunchTemplatesError {}
@@ -80148,6 +81220,11 @@ impl fmt::Display for DescribeLaunchTemplatesError {
     }
 }
 impl Error for DescribeLaunchTemplatesError {}
+impl ServiceErrorCode for DescribeLaunchTemplatesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociations
 #[derive(Debug, PartialEq)]
 pub enum DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociationsError {}
@@ -80185,6 +81262,11 @@ impl fmt::Display for DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssocia
     }
 }
 impl Error for DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociationsError {}
+impl ServiceErrorCode for DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeLocalGatewayRouteTableVpcAssociations
 #[derive(Debug, PartialEq)]
 pub enum DescribeLocalGatewayRouteTableVpcAssociationsError {}
@@ -80222,6 +81304,11 @@ impl fmt::Display for DescribeLocalGatewayRouteTableVpcAssociationsError {
     }
 }
 impl Error for DescribeLocalGatewayRouteTableVpcAssociationsError {}
+impl ServiceErrorCode for DescribeLocalGatewayRouteTableVpcAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeLocalGatewayRouteTables
 #[derive(Debug, PartialEq)]
 pub enum DescribeLocalGatewayRouteTablesError {}
@@ -80259,6 +81346,11 @@ impl fmt::Display for DescribeLocalGatewayRouteTablesError {
     }
 }
 impl Error for DescribeLocalGatewayRouteTablesError {}
+impl ServiceErrorCode for DescribeLocalGatewayRouteTablesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeLocalGatewayVirtualInterfaceGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeLocalGatewayVirtualInterfaceGroupsError {}
@@ -80296,6 +81388,11 @@ impl fmt::Display for DescribeLocalGatewayVirtualInterfaceGroupsError {
     }
 }
 impl Error for DescribeLocalGatewayVirtualInterfaceGroupsError {}
+impl ServiceErrorCode for DescribeLocalGatewayVirtualInterfaceGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeLocalGatewayVirtualInterfaces
 #[derive(Debug, PartialEq)]
 pub enum DescribeLocalGatewayVirtualInterfacesError {}
@@ -80333,6 +81430,11 @@ impl fmt::Display for DescribeLocalGatewayVirtualInterfacesError {
     }
 }
 impl Error for DescribeLocalGatewayVirtualInterfacesError {}
+impl ServiceErrorCode for DescribeLocalGatewayVirtualInterfacesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeLocalGateways
 #[derive(Debug, PartialEq)]
 pub enum DescribeLocalGatewaysError {}
@@ -80368,6 +81470,11 @@ impl fmt::Display for DescribeLocalGatewaysError {
     }
 }
 impl Error for DescribeLocalGatewaysError {}
+impl ServiceErrorCode for DescribeLocalGatewaysError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeManagedPrefixLists
 #[derive(Debug, PartialEq)]
 pub enum DescribeManagedPrefixListsError {}
@@ -80405,6 +81512,11 @@ impl fmt::Display for DescribeManagedPrefixListsError {
     }
 }
 impl Error for DescribeManagedPrefixListsError {}
+impl ServiceErrorCode for DescribeManagedPrefixListsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeMovingAddresses
 #[derive(Debug, PartialEq)]
 pub enum DescribeMovingAddressesError {}
@@ -80440,6 +81552,11 @@ impl fmt::Display for DescribeMovingAddressesError {
     }
 }
 impl Error for DescribeMovingAddressesError {}
+impl ServiceErrorCode for DescribeMovingAddressesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeNatGateways
 #[derive(Debug, PartialEq)]
 pub enum DescribeNatGatewaysError {}
@@ -80475,6 +81592,11 @@ impl fmt::Display for DescribeNatGatewaysError {
     }
 }
 impl Error for DescribeNatGatewaysError {}
+impl ServiceErrorCode for DescribeNatGatewaysError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeNetworkAcls
 #[derive(Debug, PartialEq)]
 pub enum DescribeNetworkAclsError {}
@@ -80510,6 +81632,11 @@ impl fmt::Display for DescribeNetworkAclsError {
     }
 }
 impl Error for DescribeNetworkAclsError {}
+impl ServiceErrorCode for DescribeNetworkAclsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeNetworkInsightsAnalyses
 #[derive(Debug, PartialEq)]
 pub enum DescribeNetworkInsightsAnalysesError {}
@@ -80547,6 +81674,11 @@ impl fmt::Display for DescribeNetworkInsightsAnalysesError {
     }
 }
 impl Error for DescribeNetworkInsightsAnalysesError {}
+impl ServiceErrorCode for DescribeNetworkInsightsAnalysesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeNetworkInsightsPaths
 #[derive(Debug, PartialEq)]
 pub enum DescribeNetworkInsightsPathsError {}
@@ -80584,6 +81716,11 @@ impl fmt::Display for DescribeNetworkInsightsPathsError {
     }
 }
 impl Error for DescribeNetworkInsightsPathsError {}
+impl ServiceErrorCode for DescribeNetworkInsightsPathsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeNetworkInterfaceAttribute
 #[derive(Debug, PartialEq)]
 pub enum DescribeNetworkInterfaceAttributeError {}
@@ -80621,6 +81758,11 @@ impl fmt::Display for DescribeNetworkInterfaceAttributeError {
     }
 }
 impl Error for DescribeNetworkInterfaceAttributeError {}
+impl ServiceErrorCode for DescribeNetworkInterfaceAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeNetworkInterfacePermissions
 #[derive(Debug, PartialEq)]
 pub enum DescribeNetworkInterfacePermissionsError {}
@@ -80658,6 +81800,11 @@ impl fmt::Display for DescribeNetworkInterfacePermissionsError {
     }
 }
 impl Error for DescribeNetworkInterfacePermissionsError {}
+impl ServiceErrorCode for DescribeNetworkInterfacePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeNetworkInterfaces
 #[derive(Debug, PartialEq)]
 pub enum DescribeNetworkInterfacesError {}
@@ -80693,6 +81840,11 @@ impl fmt::Display for DescribeNetworkInterfacesError {
     }
 }
 impl Error for DescribeNetworkInterfacesError {}
+impl ServiceErrorCode for DescribeNetworkInterfacesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribePlacementGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribePlacementGroupsError {}
@@ -80728,6 +81880,11 @@ impl fmt::Display for DescribePlacementGroupsError {
     }
 }
 impl Error for DescribePlacementGroupsError {}
+impl ServiceErrorCode for DescribePlacementGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribePrefixLists
 #[derive(Debug, PartialEq)]
 pub enum DescribePrefixListsError {}
@@ -80763,6 +81920,11 @@ impl fmt::Display for DescribePrefixListsError {
     }
 }
 impl Error for DescribePrefixListsError {}
+impl ServiceErrorCode for DescribePrefixListsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribePrincipalIdFormat
 #[derive(Debug, PartialEq)]
 pub enum DescribePrincipalIdFormatError {}
@@ -80798,6 +81960,11 @@ impl fmt::Display for DescribePrincipalIdFormatError {
     }
 }
 impl Error for DescribePrincipalIdFormatError {}
+impl ServiceErrorCode for DescribePrincipalIdFormatError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribePublicIpv4Pools
 #[derive(Debug, PartialEq)]
 pub enum DescribePublicIpv4PoolsError {}
@@ -80833,6 +82000,11 @@ impl fmt::Display for DescribePublicIpv4PoolsError {
     }
 }
 impl Error for DescribePublicIpv4PoolsError {}
+impl ServiceErrorCode for DescribePublicIpv4PoolsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeRegions
 #[derive(Debug, PartialEq)]
 pub enum DescribeRegionsError {}
@@ -80868,6 +82040,11 @@ impl fmt::Display for DescribeRegionsError {
     }
 }
 impl Error for DescribeRegionsError {}
+impl ServiceErrorCode for DescribeRegionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeReservedInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedInstancesError {}
@@ -80903,6 +82080,11 @@ impl fmt::Display for DescribeReservedInstancesError {
     }
 }
 impl Error for DescribeReservedInstancesError {}
+impl ServiceErrorCode for DescribeReservedInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeReservedInstancesListings
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedInstancesListingsError {}
@@ -80940,6 +82122,11 @@ impl fmt::Display for DescribeReservedInstancesListingsError {
     }
 }
 impl Error for DescribeReservedInstancesListingsError {}
+impl ServiceErrorCode for DescribeReservedInstancesListingsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeReservedInstancesModifications
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedInstancesModificationsError {}
@@ -80977,6 +82164,11 @@ impl fmt::Display for DescribeReservedInstancesModificationsError {
     }
 }
 impl Error for DescribeReservedInstancesModificationsError {}
+impl ServiceErrorCode for DescribeReservedInstancesModificationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeReservedInstancesOfferings
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedInstancesOfferingsError {}
@@ -81014,6 +82206,11 @@ impl fmt::Display for DescribeReservedInstancesOfferingsError {
     }
 }
 impl Error for DescribeReservedInstancesOfferingsError {}
+impl ServiceErrorCode for DescribeReservedInstancesOfferingsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeRouteTables
 #[derive(Debug, PartialEq)]
 pub enum DescribeRouteTablesError {}
@@ -81049,6 +82246,11 @@ impl fmt::Display for DescribeRouteTablesError {
     }
 }
 impl Error for DescribeRouteTablesError {}
+impl ServiceErrorCode for DescribeRouteTablesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeScheduledInstanceAvailability
 #[derive(Debug, PartialEq)]
 pub enum DescribeScheduledInstanceAvailabilityError {}
@@ -81086,6 +82288,11 @@ impl fmt::Display for DescribeScheduledInstanceAvailabilityError {
     }
 }
 impl Error for DescribeScheduledInstanceAvailabilityError {}
+impl ServiceErrorCode for DescribeScheduledInstanceAvailabilityError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeScheduledInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeScheduledInstancesError {}
@@ -81123,6 +82330,11 @@ impl fmt::Display for DescribeScheduledInstancesError {
     }
 }
 impl Error for DescribeScheduledInstancesError {}
+impl ServiceErrorCode for DescribeScheduledInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeSecurityGroupReferences
 #[derive(Debug, PartialEq)]
 pub enum DescribeSecurityGroupReferencesError {}
@@ -81160,6 +82372,11 @@ impl fmt::Display for DescribeSecurityGroupReferencesError {
     }
 }
 impl Error for DescribeSecurityGroupReferencesError {}
+impl ServiceErrorCode for DescribeSecurityGroupReferencesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeSecurityGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeSecurityGroupsError {}
@@ -81195,6 +82412,11 @@ impl fmt::Display for DescribeSecurityGroupsError {
     }
 }
 impl Error for DescribeSecurityGroupsError {}
+impl ServiceErrorCode for DescribeSecurityGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeSnapshotAttribute
 #[derive(Debug, PartialEq)]
 pub enum DescribeSnapshotAttributeError {}
@@ -81230,6 +82452,11 @@ impl fmt::Display for DescribeSnapshotAttributeError {
     }
 }
 impl Error for DescribeSnapshotAttributeError {}
+impl ServiceErrorCode for DescribeSnapshotAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeSnapshots
 #[derive(Debug, PartialEq)]
 pub enum DescribeSnapshotsError {}
@@ -81265,6 +82492,11 @@ impl fmt::Display for DescribeSnapshotsError {
     }
 }
 impl Error for DescribeSnapshotsError {}
+impl ServiceErrorCode for DescribeSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeSpotDatafeedSubscription
 #[derive(Debug, PartialEq)]
 pub enum DescribeSpotDatafeedSubscriptionError {}
@@ -81302,6 +82534,11 @@ impl fmt::Display for DescribeSpotDatafeedSubscriptionError {
     }
 }
 impl Error for DescribeSpotDatafeedSubscriptionError {}
+impl ServiceErrorCode for DescribeSpotDatafeedSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeSpotFleetInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeSpotFleetInstancesError {}
@@ -81339,6 +82576,11 @@ impl fmt::Display for DescribeSpotFleetInstancesError {
     }
 }
 impl Error for DescribeSpotFleetInstancesError {}
+impl ServiceErrorCode for DescribeSpotFleetInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeSpotFleetRequestHistory
 #[derive(Debug, PartialEq)]
 pub enum DescribeSpotFleetRequestHistoryError {}
@@ -81376,6 +82618,11 @@ impl fmt::Display for DescribeSpotFleetRequestHistoryError {
     }
 }
 impl Error for DescribeSpotFleetRequestHistoryError {}
+impl ServiceErrorCode for DescribeSpotFleetRequestHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeSpotFleetRequests
 #[derive(Debug, PartialEq)]
 pub enum DescribeSpotFleetRequestsError {}
@@ -81411,6 +82658,11 @@ impl fmt::Display for DescribeSpotFleetRequestsError {
     }
 }
 impl Error for DescribeSpotFleetRequestsError {}
+impl ServiceErrorCode for DescribeSpotFleetRequestsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeSpotInstanceRequests
 #[derive(Debug, PartialEq)]
 pub enum DescribeSpotInstanceRequestsError {}
@@ -81448,6 +82700,11 @@ impl fmt::Display for DescribeSpotInstanceRequestsError {
     }
 }
 impl Error for DescribeSpotInstanceRequestsError {}
+impl ServiceErrorCode for DescribeSpotInstanceRequestsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeSpotPriceHistory
 #[derive(Debug, PartialEq)]
 pub enum DescribeSpotPriceHistoryError {}
@@ -81483,6 +82740,11 @@ impl fmt::Display for DescribeSpotPriceHistoryError {
     }
 }
 impl Error for DescribeSpotPriceHistoryError {}
+impl ServiceErrorCode for DescribeSpotPriceHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeStaleSecurityGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeStaleSecurityGroupsError {}
@@ -81520,6 +82782,11 @@ impl fmt::Display for DescribeStaleSecurityGroupsError {
     }
 }
 impl Error for DescribeStaleSecurityGroupsError {}
+impl ServiceErrorCode for DescribeStaleSecurityGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeSubnets
 #[derive(Debug, PartialEq)]
 pub enum DescribeSubnetsError {}
@@ -81555,6 +82822,11 @@ impl fmt::Display for DescribeSubnetsError {
     }
 }
 impl Error for DescribeSubnetsError {}
+impl ServiceErrorCode for DescribeSubnetsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
 pub enum DescribeTagsError {}
@@ -81590,6 +82862,11 @@ impl fmt::Display for DescribeTagsError {
     }
 }
 impl Error for DescribeTagsError {}
+impl ServiceErrorCode for DescribeTagsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeTrafficMirrorFilters
 #[derive(Debug, PartialEq)]
 pub enum DescribeTrafficMirrorFiltersError {}
@@ -81627,6 +82904,11 @@ impl fmt::Display for DescribeTrafficMirrorFiltersError {
     }
 }
 impl Error for DescribeTrafficMirrorFiltersError {}
+impl ServiceErrorCode for DescribeTrafficMirrorFiltersError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeTrafficMirrorSessions
 #[derive(Debug, PartialEq)]
 pub enum DescribeTrafficMirrorSessionsError {}
@@ -81664,6 +82946,11 @@ impl fmt::Display for DescribeTrafficMirrorSessionsError {
     }
 }
 impl Error for DescribeTrafficMirrorSessionsError {}
+impl ServiceErrorCode for DescribeTrafficMirrorSessionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeTrafficMirrorTargets
 #[derive(Debug, PartialEq)]
 pub enum DescribeTrafficMirrorTargetsError {}
@@ -81701,6 +82988,11 @@ impl fmt::Display for DescribeTrafficMirrorTargetsError {
     }
 }
 impl Error for DescribeTrafficMirrorTargetsError {}
+impl ServiceErrorCode for DescribeTrafficMirrorTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeTransitGatewayAttachments
 #[derive(Debug, PartialEq)]
 pub enum DescribeTransitGatewayAttachmentsError {}
@@ -81738,6 +83030,11 @@ impl fmt::Display for DescribeTransitGatewayAttachmentsError {
     }
 }
 impl Error for DescribeTransitGatewayAttachmentsError {}
+impl ServiceErrorCode for DescribeTransitGatewayAttachmentsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeTransitGatewayConnectPeers
 #[derive(Debug, PartialEq)]
 pub enum DescribeTransitGatewayConnectPeersError {}
@@ -81775,6 +83072,11 @@ impl fmt::Display for DescribeTransitGatewayConnectPeersError {
     }
 }
 impl Error for DescribeTransitGatewayConnectPeersError {}
+impl ServiceErrorCode for DescribeTransitGatewayConnectPeersError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeTransitGatewayConnects
 #[derive(Debug, PartialEq)]
 pub enum DescribeTransitGatewayConnectsError {}
@@ -81812,6 +83114,11 @@ impl fmt::Display for DescribeTransitGatewayConnectsError {
     }
 }
 impl Error for DescribeTransitGatewayConnectsError {}
+impl ServiceErrorCode for DescribeTransitGatewayConnectsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeTransitGatewayMulticastDomains
 #[derive(Debug, PartialEq)]
 pub enum DescribeTransitGatewayMulticastDomainsError {}
@@ -81849,6 +83156,11 @@ impl fmt::Display for DescribeTransitGatewayMulticastDomainsError {
     }
 }
 impl Error for DescribeTransitGatewayMulticastDomainsError {}
+impl ServiceErrorCode for DescribeTransitGatewayMulticastDomainsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeTransitGatewayPeeringAttachments
 #[derive(Debug, PartialEq)]
 pub enum DescribeTransitGatewayPeeringAttachmentsError {}
@@ -81886,6 +83198,11 @@ impl fmt::Display for DescribeTransitGatewayPeeringAttachmentsError {
     }
 }
 impl Error for DescribeTransitGatewayPeeringAttachmentsError {}
+impl ServiceErrorCode for DescribeTransitGatewayPeeringAttachmentsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeTransitGatewayRouteTables
 #[derive(Debug, PartialEq)]
 pub enum DescribeTransitGatewayRouteTablesError {}
@@ -81923,6 +83240,11 @@ impl fmt::Display for DescribeTransitGatewayRouteTablesError {
     }
 }
 impl Error for DescribeTransitGatewayRouteTablesError {}
+impl ServiceErrorCode for DescribeTransitGatewayRouteTablesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeTransitGatewayVpcAttachments
 #[derive(Debug, PartialEq)]
 pub enum DescribeTransitGatewayVpcAttachmentsError {}
@@ -81960,6 +83282,11 @@ impl fmt::Display for DescribeTransitGatewayVpcAttachmentsError {
     }
 }
 impl Error for DescribeTransitGatewayVpcAttachmentsError {}
+impl ServiceErrorCode for DescribeTransitGatewayVpcAttachmentsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeTransitGateways
 #[derive(Debug, PartialEq)]
 pub enum DescribeTransitGatewaysError {}
@@ -81995,6 +83322,11 @@ impl fmt::Display for DescribeTransitGatewaysError {
     }
 }
 impl Error for DescribeTransitGatewaysError {}
+impl ServiceErrorCode for DescribeTransitGatewaysError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVolumeAttribute
 #[derive(Debug, PartialEq)]
 pub enum DescribeVolumeAttributeError {}
@@ -82030,6 +83362,11 @@ impl fmt::Display for DescribeVolumeAttributeError {
     }
 }
 impl Error for DescribeVolumeAttributeError {}
+impl ServiceErrorCode for DescribeVolumeAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVolumeStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeVolumeStatusError {}
@@ -82065,6 +83402,11 @@ impl fmt::Display for DescribeVolumeStatusError {
     }
 }
 impl Error for DescribeVolumeStatusError {}
+impl ServiceErrorCode for DescribeVolumeStatusError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVolumes
 #[derive(Debug, PartialEq)]
 pub enum DescribeVolumesError {}
@@ -82100,6 +83442,11 @@ impl fmt::Display for DescribeVolumesError {
     }
 }
 impl Error for DescribeVolumesError {}
+impl ServiceErrorCode for DescribeVolumesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVolumesModifications
 #[derive(Debug, PartialEq)]
 pub enum DescribeVolumesModificationsError {}
@@ -82137,6 +83484,11 @@ impl fmt::Display for DescribeVolumesModificationsError {
     }
 }
 impl Error for DescribeVolumesModificationsError {}
+impl ServiceErrorCode for DescribeVolumesModificationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVpcAttribute
 #[derive(Debug, PartialEq)]
 pub enum DescribeVpcAttributeError {}
@@ -82172,6 +83524,11 @@ impl fmt::Display for DescribeVpcAttributeError {
     }
 }
 impl Error for DescribeVpcAttributeError {}
+impl ServiceErrorCode for DescribeVpcAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVpcClassicLink
 #[derive(Debug, PartialEq)]
 pub enum DescribeVpcClassicLinkError {}
@@ -82207,6 +83564,11 @@ impl fmt::Display for DescribeVpcClassicLinkError {
     }
 }
 impl Error for DescribeVpcClassicLinkError {}
+impl ServiceErrorCode for DescribeVpcClassicLinkError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVpcClassicLinkDnsSupport
 #[derive(Debug, PartialEq)]
 pub enum DescribeVpcClassicLinkDnsSupportError {}
@@ -82244,6 +83606,11 @@ impl fmt::Display for DescribeVpcClassicLinkDnsSupportError {
     }
 }
 impl Error for DescribeVpcClassicLinkDnsSupportError {}
+impl ServiceErrorCode for DescribeVpcClassicLinkDnsSupportError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVpcEndpointConnectionNotifications
 #[derive(Debug, PartialEq)]
 pub enum DescribeVpcEndpointConnectionNotificationsError {}
@@ -82281,6 +83648,11 @@ impl fmt::Display for DescribeVpcEndpointConnectionNotificationsError {
     }
 }
 impl Error for DescribeVpcEndpointConnectionNotificationsError {}
+impl ServiceErrorCode for DescribeVpcEndpointConnectionNotificationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVpcEndpointConnections
 #[derive(Debug, PartialEq)]
 pub enum DescribeVpcEndpointConnectionsError {}
@@ -82318,6 +83690,11 @@ impl fmt::Display for DescribeVpcEndpointConnectionsError {
     }
 }
 impl Error for DescribeVpcEndpointConnectionsError {}
+impl ServiceErrorCode for DescribeVpcEndpointConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVpcEndpointServiceConfigurations
 #[derive(Debug, PartialEq)]
 pub enum DescribeVpcEndpointServiceConfigurationsError {}
@@ -82355,6 +83732,11 @@ impl fmt::Display for DescribeVpcEndpointServiceConfigurationsError {
     }
 }
 impl Error for DescribeVpcEndpointServiceConfigurationsError {}
+impl ServiceErrorCode for DescribeVpcEndpointServiceConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVpcEndpointServicePermissions
 #[derive(Debug, PartialEq)]
 pub enum DescribeVpcEndpointServicePermissionsError {}
@@ -82392,6 +83774,11 @@ impl fmt::Display for DescribeVpcEndpointServicePermissionsError {
     }
 }
 impl Error for DescribeVpcEndpointServicePermissionsError {}
+impl ServiceErrorCode for DescribeVpcEndpointServicePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVpcEndpointServices
 #[derive(Debug, PartialEq)]
 pub enum DescribeVpcEndpointServicesError {}
@@ -82429,6 +83816,11 @@ impl fmt::Display for DescribeVpcEndpointServicesError {
     }
 }
 impl Error for DescribeVpcEndpointServicesError {}
+impl ServiceErrorCode for DescribeVpcEndpointServicesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVpcEndpoints
 #[derive(Debug, PartialEq)]
 pub enum DescribeVpcEndpointsError {}
@@ -82464,6 +83856,11 @@ impl fmt::Display for DescribeVpcEndpointsError {
     }
 }
 impl Error for DescribeVpcEndpointsError {}
+impl ServiceErrorCode for DescribeVpcEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVpcPeeringConnections
 #[derive(Debug, PartialEq)]
 pub enum DescribeVpcPeeringConnectionsError {}
@@ -82501,6 +83898,11 @@ impl fmt::Display for DescribeVpcPeeringConnectionsError {
     }
 }
 impl Error for DescribeVpcPeeringConnectionsError {}
+impl ServiceErrorCode for DescribeVpcPeeringConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVpcs
 #[derive(Debug, PartialEq)]
 pub enum DescribeVpcsError {}
@@ -82536,6 +83938,11 @@ impl fmt::Display for DescribeVpcsError {
     }
 }
 impl Error for DescribeVpcsError {}
+impl ServiceErrorCode for DescribeVpcsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVpnConnections
 #[derive(Debug, PartialEq)]
 pub enum DescribeVpnConnectionsError {}
@@ -82571,6 +83978,11 @@ impl fmt::Display for DescribeVpnConnectionsError {
     }
 }
 impl Error for DescribeVpnConnectionsError {}
+impl ServiceErrorCode for DescribeVpnConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeVpnGateways
 #[derive(Debug, PartialEq)]
 pub enum DescribeVpnGatewaysError {}
@@ -82606,6 +84018,11 @@ impl fmt::Display for DescribeVpnGatewaysError {
     }
 }
 impl Error for DescribeVpnGatewaysError {}
+impl ServiceErrorCode for DescribeVpnGatewaysError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DetachClassicLinkVpc
 #[derive(Debug, PartialEq)]
 pub enum DetachClassicLinkVpcError {}
@@ -82641,6 +84058,11 @@ impl fmt::Display for DetachClassicLinkVpcError {
     }
 }
 impl Error for DetachClassicLinkVpcError {}
+impl ServiceErrorCode for DetachClassicLinkVpcError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DetachInternetGateway
 #[derive(Debug, PartialEq)]
 pub enum DetachInternetGatewayError {}
@@ -82676,6 +84098,11 @@ impl fmt::Display for DetachInternetGatewayError {
     }
 }
 impl Error for DetachInternetGatewayError {}
+impl ServiceErrorCode for DetachInternetGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DetachNetworkInterface
 #[derive(Debug, PartialEq)]
 pub enum DetachNetworkInterfaceError {}
@@ -82711,6 +84138,11 @@ impl fmt::Display for DetachNetworkInterfaceError {
     }
 }
 impl Error for DetachNetworkInterfaceError {}
+impl ServiceErrorCode for DetachNetworkInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DetachVolume
 #[derive(Debug, PartialEq)]
 pub enum DetachVolumeError {}
@@ -82746,6 +84178,11 @@ impl fmt::Display for DetachVolumeError {
     }
 }
 impl Error for DetachVolumeError {}
+impl ServiceErrorCode for DetachVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DetachVpnGateway
 #[derive(Debug, PartialEq)]
 pub enum DetachVpnGatewayError {}
@@ -82781,6 +84218,11 @@ impl fmt::Display for DetachVpnGatewayError {
     }
 }
 impl Error for DetachVpnGatewayError {}
+impl ServiceErrorCode for DetachVpnGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisableEbsEncryptionByDefault
 #[derive(Debug, PartialEq)]
 pub enum DisableEbsEncryptionByDefaultError {}
@@ -82818,6 +84260,11 @@ impl fmt::Display for DisableEbsEncryptionByDefaultError {
     }
 }
 impl Error for DisableEbsEncryptionByDefaultError {}
+impl ServiceErrorCode for DisableEbsEncryptionByDefaultError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisableFastSnapshotRestores
 #[derive(Debug, PartialEq)]
 pub enum DisableFastSnapshotRestoresError {}
@@ -82855,6 +84302,11 @@ impl fmt::Display for DisableFastSnapshotRestoresError {
     }
 }
 impl Error for DisableFastSnapshotRestoresError {}
+impl ServiceErrorCode for DisableFastSnapshotRestoresError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisableTransitGatewayRouteTablePropagation
 #[derive(Debug, PartialEq)]
 pub enum DisableTransitGatewayRouteTablePropagationError {}
@@ -82892,6 +84344,11 @@ impl fmt::Display for DisableTransitGatewayRouteTablePropagationError {
     }
 }
 impl Error for DisableTransitGatewayRouteTablePropagationError {}
+impl ServiceErrorCode for DisableTransitGatewayRouteTablePropagationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisableVgwRoutePropagation
 #[derive(Debug, PartialEq)]
 pub enum DisableVgwRoutePropagationError {}
@@ -82929,6 +84386,11 @@ impl fmt::Display for DisableVgwRoutePropagationError {
     }
 }
 impl Error for DisableVgwRoutePropagationError {}
+impl ServiceErrorCode for DisableVgwRoutePropagationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisableVpcClassicLink
 #[derive(Debug, PartialEq)]
 pub enum DisableVpcClassicLinkError {}
@@ -82964,6 +84426,11 @@ impl fmt::Display for DisableVpcClassicLinkError {
     }
 }
 impl Error for DisableVpcClassicLinkError {}
+impl ServiceErrorCode for DisableVpcClassicLinkError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisableVpcClassicLinkDnsSupport
 #[derive(Debug, PartialEq)]
 pub enum DisableVpcClassicLinkDnsSupportError {}
@@ -83001,6 +84468,11 @@ impl fmt::Display for DisableVpcClassicLinkDnsSupportError {
     }
 }
 impl Error for DisableVpcClassicLinkDnsSupportError {}
+impl ServiceErrorCode for DisableVpcClassicLinkDnsSupportError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisassociateAddress
 #[derive(Debug, PartialEq)]
 pub enum DisassociateAddressError {}
@@ -83036,6 +84508,11 @@ impl fmt::Display for DisassociateAddressError {
     }
 }
 impl Error for DisassociateAddressError {}
+impl ServiceErrorCode for DisassociateAddressError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisassociateClientVpnTargetNetwork
 #[derive(Debug, PartialEq)]
 pub enum DisassociateClientVpnTargetNetworkError {}
@@ -83073,6 +84550,11 @@ impl fmt::Display for DisassociateClientVpnTargetNetworkError {
     }
 }
 impl Error for DisassociateClientVpnTargetNetworkError {}
+impl ServiceErrorCode for DisassociateClientVpnTargetNetworkError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisassociateEnclaveCertificateIamRole
 #[derive(Debug, PartialEq)]
 pub enum DisassociateEnclaveCertificateIamRoleError {}
@@ -83110,6 +84592,11 @@ impl fmt::Display for DisassociateEnclaveCertificateIamRoleError {
     }
 }
 impl Error for DisassociateEnclaveCertificateIamRoleError {}
+impl ServiceErrorCode for DisassociateEnclaveCertificateIamRoleError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisassociateIamInstanceProfile
 #[derive(Debug, PartialEq)]
 pub enum DisassociateIamInstanceProfileError {}
@@ -83147,6 +84634,11 @@ impl fmt::Display for DisassociateIamInstanceProfileError {
     }
 }
 impl Error for DisassociateIamInstanceProfileError {}
+impl ServiceErrorCode for DisassociateIamInstanceProfileError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisassociateRouteTable
 #[derive(Debug, PartialEq)]
 pub enum DisassociateRouteTableError {}
@@ -83182,6 +84674,11 @@ impl fmt::Display for DisassociateRouteTableError {
     }
 }
 impl Error for DisassociateRouteTableError {}
+impl ServiceErrorCode for DisassociateRouteTableError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisassociateSubnetCidrBlock
 #[derive(Debug, PartialEq)]
 pub enum DisassociateSubnetCidrBlockError {}
@@ -83219,6 +84716,11 @@ impl fmt::Display for DisassociateSubnetCidrBlockError {
     }
 }
 impl Error for DisassociateSubnetCidrBlockError {}
+impl ServiceErrorCode for DisassociateSubnetCidrBlockError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisassociateTransitGatewayMulticastDomain
 #[derive(Debug, PartialEq)]
 pub enum DisassociateTransitGatewayMulticastDomainError {}
@@ -83256,6 +84758,11 @@ impl fmt::Display for DisassociateTransitGatewayMulticastDomainError {
     }
 }
 impl Error for DisassociateTransitGatewayMulticastDomainError {}
+impl ServiceErrorCode for DisassociateTransitGatewayMulticastDomainError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisassociateTransitGatewayRouteTable
 #[derive(Debug, PartialEq)]
 pub enum DisassociateTransitGatewayRouteTableError {}
@@ -83293,6 +84800,11 @@ impl fmt::Display for DisassociateTransitGatewayRouteTableError {
     }
 }
 impl Error for DisassociateTransitGatewayRouteTableError {}
+impl ServiceErrorCode for DisassociateTransitGatewayRouteTableError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisassociateVpcCidrBlock
 #[derive(Debug, PartialEq)]
 pub enum DisassociateVpcCidrBlockError {}
@@ -83328,6 +84840,11 @@ impl fmt::Display for DisassociateVpcCidrBlockError {
     }
 }
 impl Error for DisassociateVpcCidrBlockError {}
+impl ServiceErrorCode for DisassociateVpcCidrBlockError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by EnableEbsEncryptionByDefault
 #[derive(Debug, PartialEq)]
 pub enum EnableEbsEncryptionByDefaultError {}
@@ -83365,6 +84882,11 @@ impl fmt::Display for EnableEbsEncryptionByDefaultError {
     }
 }
 impl Error for EnableEbsEncryptionByDefaultError {}
+impl ServiceErrorCode for EnableEbsEncryptionByDefaultError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by EnableFastSnapshotRestores
 #[derive(Debug, PartialEq)]
 pub enum EnableFastSnapshotRestoresError {}
@@ -83402,6 +84924,11 @@ impl fmt::Display for EnableFastSnapshotRestoresError {
     }
 }
 impl Error for EnableFastSnapshotRestoresError {}
+impl ServiceErrorCode for EnableFastSnapshotRestoresError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by EnableTransitGatewayRouteTablePropagation
 #[derive(Debug, PartialEq)]
 pub enum EnableTransitGatewayRouteTablePropagationError {}
@@ -83439,6 +84966,11 @@ impl fmt::Display for EnableTransitGatewayRouteTablePropagationError {
     }
 }
 impl Error for EnableTransitGatewayRouteTablePropagationError {}
+impl ServiceErrorCode for EnableTransitGatewayRouteTablePropagationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by EnableVgwRoutePropagation
 #[derive(Debug, PartialEq)]
 pub enum EnableVgwRoutePropagationError {}
@@ -83474,6 +85006,11 @@ impl fmt::Display for EnableVgwRoutePropagationError {
     }
 }
 impl Error for EnableVgwRoutePropagationError {}
+impl ServiceErrorCode for EnableVgwRoutePropagationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by EnableVolumeIO
 #[derive(Debug, PartialEq)]
 pub enum EnableVolumeIOError {}
@@ -83509,6 +85046,11 @@ impl fmt::Display for EnableVolumeIOError {
     }
 }
 impl Error for EnableVolumeIOError {}
+impl ServiceErrorCode for EnableVolumeIOError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by EnableVpcClassicLink
 #[derive(Debug, PartialEq)]
 pub enum EnableVpcClassicLinkError {}
@@ -83544,6 +85086,11 @@ impl fmt::Display for EnableVpcClassicLinkError {
     }
 }
 impl Error for EnableVpcClassicLinkError {}
+impl ServiceErrorCode for EnableVpcClassicLinkError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by EnableVpcClassicLinkDnsSupport
 #[derive(Debug, PartialEq)]
 pub enum EnableVpcClassicLinkDnsSupportError {}
@@ -83581,6 +85128,11 @@ impl fmt::Display for EnableVpcClassicLinkDnsSupportError {
     }
 }
 impl Error for EnableVpcClassicLinkDnsSupportError {}
+impl ServiceErrorCode for EnableVpcClassicLinkDnsSupportError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ExportClientVpnClientCertificateRevocationList
 #[derive(Debug, PartialEq)]
 pub enum ExportClientVpnClientCertificateRevocationListError {}
@@ -83618,6 +85170,11 @@ impl fmt::Display for ExportClientVpnClientCertificateRevocationListError {
     }
 }
 impl Error for ExportClientVpnClientCertificateRevocationListError {}
+impl ServiceErrorCode for ExportClientVpnClientCertificateRevocationListError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ExportClientVpnClientConfiguration
 #[derive(Debug, PartialEq)]
 pub enum ExportClientVpnClientConfigurationError {}
@@ -83655,6 +85212,11 @@ impl fmt::Display for ExportClientVpnClientConfigurationError {
     }
 }
 impl Error for ExportClientVpnClientConfigurationError {}
+impl ServiceErrorCode for ExportClientVpnClientConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ExportImage
 #[derive(Debug, PartialEq)]
 pub enum ExportImageError {}
@@ -83690,6 +85252,11 @@ impl fmt::Display for ExportImageError {
     }
 }
 impl Error for ExportImageError {}
+impl ServiceErrorCode for ExportImageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ExportTransitGatewayRoutes
 #[derive(Debug, PartialEq)]
 pub enum ExportTransitGatewayRoutesError {}
@@ -83727,6 +85294,11 @@ impl fmt::Display for ExportTransitGatewayRoutesError {
     }
 }
 impl Error for ExportTransitGatewayRoutesError {}
+impl ServiceErrorCode for ExportTransitGatewayRoutesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetAssociatedEnclaveCertificateIamRoles
 #[derive(Debug, PartialEq)]
 pub enum GetAssociatedEnclaveCertificateIamRolesError {}
@@ -83764,6 +85336,11 @@ impl fmt::Display for GetAssociatedEnclaveCertificateIamRolesError {
     }
 }
 impl Error for GetAssociatedEnclaveCertificateIamRolesError {}
+impl ServiceErrorCode for GetAssociatedEnclaveCertificateIamRolesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetAssociatedIpv6PoolCidrs
 #[derive(Debug, PartialEq)]
 pub enum GetAssociatedIpv6PoolCidrsError {}
@@ -83801,6 +85378,11 @@ impl fmt::Display for GetAssociatedIpv6PoolCidrsError {
     }
 }
 impl Error for GetAssociatedIpv6PoolCidrsError {}
+impl ServiceErrorCode for GetAssociatedIpv6PoolCidrsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetCapacityReservationUsage
 #[derive(Debug, PartialEq)]
 pub enum GetCapacityReservationUsageError {}
@@ -83838,6 +85420,11 @@ impl fmt::Display for GetCapacityReservationUsageError {
     }
 }
 impl Error for GetCapacityReservationUsageError {}
+impl ServiceErrorCode for GetCapacityReservationUsageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetCoipPoolUsage
 #[derive(Debug, PartialEq)]
 pub enum GetCoipPoolUsageError {}
@@ -83873,6 +85460,11 @@ impl fmt::Display for GetCoipPoolUsageError {
     }
 }
 impl Error for GetCoipPoolUsageError {}
+impl ServiceErrorCode for GetCoipPoolUsageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetConsoleOutput
 #[derive(Debug, PartialEq)]
 pub enum GetConsoleOutputError {}
@@ -83908,6 +85500,11 @@ impl fmt::Display for GetConsoleOutputError {
     }
 }
 impl Error for GetConsoleOutputError {}
+impl ServiceErrorCode for GetConsoleOutputError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetConsoleScreenshot
 #[derive(Debug, PartialEq)]
 pub enum GetConsoleScreenshotError {}
@@ -83943,6 +85540,11 @@ impl fmt::Display for GetConsoleScreenshotError {
     }
 }
 impl Error for GetConsoleScreenshotError {}
+impl ServiceErrorCode for GetConsoleScreenshotError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetDefaultCreditSpecification
 #[derive(Debug, PartialEq)]
 pub enum GetDefaultCreditSpecificationError {}
@@ -83980,6 +85582,11 @@ impl fmt::Display for GetDefaultCreditSpecificationError {
     }
 }
 impl Error for GetDefaultCreditSpecificationError {}
+impl ServiceErrorCode for GetDefaultCreditSpecificationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetEbsDefaultKmsKeyId
 #[derive(Debug, PartialEq)]
 pub enum GetEbsDefaultKmsKeyIdError {}
@@ -84015,6 +85622,11 @@ impl fmt::Display for GetEbsDefaultKmsKeyIdError {
     }
 }
 impl Error for GetEbsDefaultKmsKeyIdError {}
+impl ServiceErrorCode for GetEbsDefaultKmsKeyIdError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetEbsEncryptionByDefault
 #[derive(Debug, PartialEq)]
 pub enum GetEbsEncryptionByDefaultError {}
@@ -84050,6 +85662,11 @@ impl fmt::Display for GetEbsEncryptionByDefaultError {
     }
 }
 impl Error for GetEbsEncryptionByDefaultError {}
+impl ServiceErrorCode for GetEbsEncryptionByDefaultError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetGroupsForCapacityReservation
 #[derive(Debug, PartialEq)]
 pub enum GetGroupsForCapacityReservationError {}
@@ -84087,6 +85704,11 @@ impl fmt::Display for GetGroupsForCapacityReservationError {
     }
 }
 impl Error for GetGroupsForCapacityReservationError {}
+impl ServiceErrorCode for GetGroupsForCapacityReservationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetHostReservationPurchasePreview
 #[derive(Debug, PartialEq)]
 pub enum GetHostReservationPurchasePreviewError {}
@@ -84124,6 +85746,11 @@ impl fmt::Display for GetHostReservationPurchasePreviewError {
     }
 }
 impl Error for GetHostReservationPurchasePreviewError {}
+impl ServiceErrorCode for GetHostReservationPurchasePreviewError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetLaunchTemplateData
 #[derive(Debug, PartialEq)]
 pub enum GetLaunchTemplateDataError {}
@@ -84159,6 +85786,11 @@ impl fmt::Display for GetLaunchTemplateDataError {
     }
 }
 impl Error for GetLaunchTemplateDataError {}
+impl ServiceErrorCode for GetLaunchTemplateDataError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetManagedPrefixListAssociations
 #[derive(Debug, PartialEq)]
 pub enum GetManagedPrefixListAssociationsError {}
@@ -84196,6 +85828,11 @@ impl fmt::Display for GetManagedPrefixListAssociationsError {
     }
 }
 impl Error for GetManagedPrefixListAssociationsError {}
+impl ServiceErrorCode for GetManagedPrefixListAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetManagedPrefixListEntries
 #[derive(Debug, PartialEq)]
 pub enum GetManagedPrefixListEntriesError {}
@@ -84233,6 +85870,11 @@ impl fmt::Display for GetManagedPrefixListEntriesError {
     }
 }
 impl Error for GetManagedPrefixListEntriesError {}
+impl ServiceErrorCode for GetManagedPrefixListEntriesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetPasswordData
 #[derive(Debug, PartialEq)]
 pub enum GetPasswordDataError {}
@@ -84268,6 +85910,11 @@ impl fmt::Display for GetPasswordDataError {
     }
 }
 impl Error for GetPasswordDataError {}
+impl ServiceErrorCode for GetPasswordDataError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetReservedInstancesExchangeQuote
 #[derive(Debug, PartialEq)]
 pub enum GetReservedInstancesExchangeQuoteError {}
@@ -84305,6 +85952,11 @@ impl fmt::Display for GetReservedInstancesExchangeQuoteError {
     }
 }
 impl Error for GetReservedInstancesExchangeQuoteError {}
+impl ServiceErrorCode for GetReservedInstancesExchangeQuoteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetTransitGatewayAttachmentPropagations
 #[derive(Debug, PartialEq)]
 pub enum GetTransitGatewayAttachmentPropagationsError {}
@@ -84342,6 +85994,11 @@ impl fmt::Display for GetTransitGatewayAttachmentPropagationsError {
     }
 }
 impl Error for GetTransitGatewayAttachmentPropagationsError {}
+impl ServiceErrorCode for GetTransitGatewayAttachmentPropagationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetTransitGatewayMulticastDomainAssociations
 #[derive(Debug, PartialEq)]
 pub enum GetTransitGatewayMulticastDomainAssociationsError {}
@@ -84379,6 +86036,11 @@ impl fmt::Display for GetTransitGatewayMulticastDomainAssociationsError {
     }
 }
 impl Error for GetTransitGatewayMulticastDomainAssociationsError {}
+impl ServiceErrorCode for GetTransitGatewayMulticastDomainAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetTransitGatewayPrefixListReferences
 #[derive(Debug, PartialEq)]
 pub enum GetTransitGatewayPrefixListReferencesError {}
@@ -84416,6 +86078,11 @@ impl fmt::Display for GetTransitGatewayPrefixListReferencesError {
     }
 }
 impl Error for GetTransitGatewayPrefixListReferencesError {}
+impl ServiceErrorCode for GetTransitGatewayPrefixListReferencesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetTransitGatewayRouteTableAssociations
 #[derive(Debug, PartialEq)]
 pub enum GetTransitGatewayRouteTableAssociationsError {}
@@ -84453,6 +86120,11 @@ impl fmt::Display for GetTransitGatewayRouteTableAssociationsError {
     }
 }
 impl Error for GetTransitGatewayRouteTableAssociationsError {}
+impl ServiceErrorCode for GetTransitGatewayRouteTableAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetTransitGatewayRouteTablePropagations
 #[derive(Debug, PartialEq)]
 pub enum GetTransitGatewayRouteTablePropagationsError {}
@@ -84490,6 +86162,11 @@ impl fmt::Display for GetTransitGatewayRouteTablePropagationsError {
     }
 }
 impl Error for GetTransitGatewayRouteTablePropagationsError {}
+impl ServiceErrorCode for GetTransitGatewayRouteTablePropagationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ImportClientVpnClientCertificateRevocationList
 #[derive(Debug, PartialEq)]
 pub enum ImportClientVpnClientCertificateRevocationListError {}
@@ -84527,6 +86204,11 @@ impl fmt::Display for ImportClientVpnClientCertificateRevocationListError {
     }
 }
 impl Error for ImportClientVpnClientCertificateRevocationListError {}
+impl ServiceErrorCode for ImportClientVpnClientCertificateRevocationListError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ImportImage
 #[derive(Debug, PartialEq)]
 pub enum ImportImageError {}
@@ -84562,6 +86244,11 @@ impl fmt::Display for ImportImageError {
     }
 }
 impl Error for ImportImageError {}
+impl ServiceErrorCode for ImportImageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ImportInstance
 #[derive(Debug, PartialEq)]
 pub enum ImportInstanceError {}
@@ -84597,6 +86284,11 @@ impl fmt::Display for ImportInstanceError {
     }
 }
 impl Error for ImportInstanceError {}
+impl ServiceErrorCode for ImportInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ImportKeyPair
 #[derive(Debug, PartialEq)]
 pub enum ImportKeyPairError {}
@@ -84632,6 +86324,11 @@ impl fmt::Display for ImportKeyPairError {
     }
 }
 impl Error for ImportKeyPairError {}
+impl ServiceErrorCode for ImportKeyPairError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ImportSnapshot
 #[derive(Debug, PartialEq)]
 pub enum ImportSnapshotError {}
@@ -84667,6 +86364,11 @@ impl fmt::Display for ImportSnapshotError {
     }
 }
 impl Error for ImportSnapshotError {}
+impl ServiceErrorCode for ImportSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ImportVolume
 #[derive(Debug, PartialEq)]
 pub enum ImportVolumeError {}
@@ -84702,6 +86404,11 @@ impl fmt::Display for ImportVolumeError {
     }
 }
 impl Error for ImportVolumeError {}
+impl ServiceErrorCode for ImportVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyAvailabilityZoneGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyAvailabilityZoneGroupError {}
@@ -84739,6 +86446,11 @@ impl fmt::Display for ModifyAvailabilityZoneGroupError {
     }
 }
 impl Error for ModifyAvailabilityZoneGroupError {}
+impl ServiceErrorCode for ModifyAvailabilityZoneGroupError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyCapacityReservation
 #[derive(Debug, PartialEq)]
 pub enum ModifyCapacityReservationError {}
@@ -84774,6 +86486,11 @@ impl fmt::Display for ModifyCapacityReservationError {
     }
 }
 impl Error for ModifyCapacityReservationError {}
+impl ServiceErrorCode for ModifyCapacityReservationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyClientVpnEndpoint
 #[derive(Debug, PartialEq)]
 pub enum ModifyClientVpnEndpointError {}
@@ -84809,6 +86526,11 @@ impl fmt::Display for ModifyClientVpnEndpointError {
     }
 }
 impl Error for ModifyClientVpnEndpointError {}
+impl ServiceErrorCode for ModifyClientVpnEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyDefaultCreditSpecification
 #[derive(Debug, PartialEq)]
 pub enum ModifyDefaultCreditSpecificationError {}
@@ -84846,6 +86568,11 @@ impl fmt::Display for ModifyDefaultCreditSpecificationError {
     }
 }
 impl Error for ModifyDefaultCreditSpecificationError {}
+impl ServiceErrorCode for ModifyDefaultCreditSpecificationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyEbsDefaultKmsKeyId
 #[derive(Debug, PartialEq)]
 pub enum ModifyEbsDefaultKmsKeyIdError {}
@@ -84881,6 +86608,11 @@ impl fmt::Display for ModifyEbsDefaultKmsKeyIdError {
     }
 }
 impl Error for ModifyEbsDefaultKmsKeyIdError {}
+impl ServiceErrorCode for ModifyEbsDefaultKmsKeyIdError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyFleet
 #[derive(Debug, PartialEq)]
 pub enum ModifyFleetError {}
@@ -84916,6 +86648,11 @@ impl fmt::Display for ModifyFleetError {
     }
 }
 impl Error for ModifyFleetError {}
+impl ServiceErrorCode for ModifyFleetError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyFpgaImageAttribute
 #[derive(Debug, PartialEq)]
 pub enum ModifyFpgaImageAttributeError {}
@@ -84951,6 +86688,11 @@ impl fmt::Display for ModifyFpgaImageAttributeError {
     }
 }
 impl Error for ModifyFpgaImageAttributeError {}
+impl ServiceErrorCode for ModifyFpgaImageAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyHosts
 #[derive(Debug, PartialEq)]
 pub enum ModifyHostsError {}
@@ -84986,6 +86728,11 @@ impl fmt::Display for ModifyHostsError {
     }
 }
 impl Error for ModifyHostsError {}
+impl ServiceErrorCode for ModifyHostsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyIdFormat
 #[derive(Debug, PartialEq)]
 pub enum ModifyIdFormatError {}
@@ -85021,6 +86768,11 @@ impl fmt::Display for ModifyIdFormatError {
     }
 }
 impl Error for ModifyIdFormatError {}
+impl ServiceErrorCode for ModifyIdFormatError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyIdentityIdFormat
 #[derive(Debug, PartialEq)]
 pub enum ModifyIdentityIdFormatError {}
@@ -85056,6 +86808,11 @@ impl fmt::Display for ModifyIdentityIdFormatError {
     }
 }
 impl Error for ModifyIdentityIdFormatError {}
+impl ServiceErrorCode for ModifyIdentityIdFormatError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyImageAttribute
 #[derive(Debug, PartialEq)]
 pub enum ModifyImageAttributeError {}
@@ -85091,6 +86848,11 @@ impl fmt::Display for ModifyImageAttributeError {
     }
 }
 impl Error for ModifyImageAttributeError {}
+impl ServiceErrorCode for ModifyImageAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyInstanceAttribute
 #[derive(Debug, PartialEq)]
 pub enum ModifyInstanceAttributeError {}
@@ -85126,6 +86888,11 @@ impl fmt::Display for ModifyInstanceAttributeError {
     }
 }
 impl Error for ModifyInstanceAttributeError {}
+impl ServiceErrorCode for ModifyInstanceAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyInstanceCapacityReservationAttributes
 #[derive(Debug, PartialEq)]
 pub enum ModifyInstanceCapacityReservationAttributesError {}
@@ -85163,6 +86930,11 @@ impl fmt::Display for ModifyInstanceCapacityReservationAttributesError {
     }
 }
 impl Error for ModifyInstanceCapacityReservationAttributesError {}
+impl ServiceErrorCode for ModifyInstanceCapacityReservationAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyInstanceCreditSpecification
 #[derive(Debug, PartialEq)]
 pub enum ModifyInstanceCreditSpecificationError {}
@@ -85200,6 +86972,11 @@ impl fmt::Display for ModifyInstanceCreditSpecificationError {
     }
 }
 impl Error for ModifyInstanceCreditSpecificationError {}
+impl ServiceErrorCode for ModifyInstanceCreditSpecificationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyInstanceEventStartTime
 #[derive(Debug, PartialEq)]
 pub enum ModifyInstanceEventStartTimeError {}
@@ -85237,6 +87014,11 @@ impl fmt::Display for ModifyInstanceEventStartTimeError {
     }
 }
 impl Error for ModifyInstanceEventStartTimeError {}
+impl ServiceErrorCode for ModifyInstanceEventStartTimeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyInstanceMetadataOptions
 #[derive(Debug, PartialEq)]
 pub enum ModifyInstanceMetadataOptionsError {}
@@ -85274,6 +87056,11 @@ impl fmt::Display for ModifyInstanceMetadataOptionsError {
     }
 }
 impl Error for ModifyInstanceMetadataOptionsError {}
+impl ServiceErrorCode for ModifyInstanceMetadataOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyInstancePlacement
 #[derive(Debug, PartialEq)]
 pub enum ModifyInstancePlacementError {}
@@ -85309,6 +87096,11 @@ impl fmt::Display for ModifyInstancePlacementError {
     }
 }
 impl Error for ModifyInstancePlacementError {}
+impl ServiceErrorCode for ModifyInstancePlacementError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyLaunchTemplate
 #[derive(Debug, PartialEq)]
 pub enum ModifyLaunchTemplateError {}
@@ -85344,6 +87136,11 @@ impl fmt::Display for ModifyLaunchTemplateError {
     }
 }
 impl Error for ModifyLaunchTemplateError {}
+impl ServiceErrorCode for ModifyLaunchTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyManagedPrefixList
 #[derive(Debug, PartialEq)]
 pub enum ModifyManagedPrefixListError {}
@@ -85379,6 +87176,11 @@ impl fmt::Display for ModifyManagedPrefixListError {
     }
 }
 impl Error for ModifyManagedPrefixListError {}
+impl ServiceErrorCode for ModifyManagedPrefixListError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyNetworkInterfaceAttribute
 #[derive(Debug, PartialEq)]
 pub enum ModifyNetworkInterfaceAttributeError {}
@@ -85416,6 +87218,11 @@ impl fmt::Display for ModifyNetworkInterfaceAttributeError {
     }
 }
 impl Error for ModifyNetworkInterfaceAttributeError {}
+impl ServiceErrorCode for ModifyNetworkInterfaceAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyReservedInstances
 #[derive(Debug, PartialEq)]
 pub enum ModifyReservedInstancesError {}
@@ -85451,6 +87258,11 @@ impl fmt::Display for ModifyReservedInstancesError {
     }
 }
 impl Error for ModifyReservedInstancesError {}
+impl ServiceErrorCode for ModifyReservedInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifySnapshotAttribute
 #[derive(Debug, PartialEq)]
 pub enum ModifySnapshotAttributeError {}
@@ -85486,6 +87298,11 @@ impl fmt::Display for ModifySnapshotAttributeError {
     }
 }
 impl Error for ModifySnapshotAttributeError {}
+impl ServiceErrorCode for ModifySnapshotAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifySpotFleetRequest
 #[derive(Debug, PartialEq)]
 pub enum ModifySpotFleetRequestError {}
@@ -85521,6 +87338,11 @@ impl fmt::Display for ModifySpotFleetRequestError {
     }
 }
 impl Error for ModifySpotFleetRequestError {}
+impl ServiceErrorCode for ModifySpotFleetRequestError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifySubnetAttribute
 #[derive(Debug, PartialEq)]
 pub enum ModifySubnetAttributeError {}
@@ -85556,6 +87378,11 @@ impl fmt::Display for ModifySubnetAttributeError {
     }
 }
 impl Error for ModifySubnetAttributeError {}
+impl ServiceErrorCode for ModifySubnetAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyTrafficMirrorFilterNetworkServices
 #[derive(Debug, PartialEq)]
 pub enum ModifyTrafficMirrorFilterNetworkServicesError {}
@@ -85593,6 +87420,11 @@ impl fmt::Display for ModifyTrafficMirrorFilterNetworkServicesError {
     }
 }
 impl Error for ModifyTrafficMirrorFilterNetworkServicesError {}
+impl ServiceErrorCode for ModifyTrafficMirrorFilterNetworkServicesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyTrafficMirrorFilterRule
 #[derive(Debug, PartialEq)]
 pub enum ModifyTrafficMirrorFilterRuleError {}
@@ -85630,6 +87462,11 @@ impl fmt::Display for ModifyTrafficMirrorFilterRuleError {
     }
 }
 impl Error for ModifyTrafficMirrorFilterRuleError {}
+impl ServiceErrorCode for ModifyTrafficMirrorFilterRuleError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyTrafficMirrorSession
 #[derive(Debug, PartialEq)]
 pub enum ModifyTrafficMirrorSessionError {}
@@ -85667,6 +87504,11 @@ impl fmt::Display for ModifyTrafficMirrorSessionError {
     }
 }
 impl Error for ModifyTrafficMirrorSessionError {}
+impl ServiceErrorCode for ModifyTrafficMirrorSessionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyTransitGateway
 #[derive(Debug, PartialEq)]
 pub enum ModifyTransitGatewayError {}
@@ -85702,6 +87544,11 @@ impl fmt::Display for ModifyTransitGatewayError {
     }
 }
 impl Error for ModifyTransitGatewayError {}
+impl ServiceErrorCode for ModifyTransitGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyTransitGatewayPrefixListReference
 #[derive(Debug, PartialEq)]
 pub enum ModifyTransitGatewayPrefixListReferenceError {}
@@ -85739,6 +87586,11 @@ impl fmt::Display for ModifyTransitGatewayPrefixListReferenceError {
     }
 }
 impl Error for ModifyTransitGatewayPrefixListReferenceError {}
+impl ServiceErrorCode for ModifyTransitGatewayPrefixListReferenceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyTransitGatewayVpcAttachment
 #[derive(Debug, PartialEq)]
 pub enum ModifyTransitGatewayVpcAttachmentError {}
@@ -85776,6 +87628,11 @@ impl fmt::Display for ModifyTransitGatewayVpcAttachmentError {
     }
 }
 impl Error for ModifyTransitGatewayVpcAttachmentError {}
+impl ServiceErrorCode for ModifyTransitGatewayVpcAttachmentError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyVolume
 #[derive(Debug, PartialEq)]
 pub enum ModifyVolumeError {}
@@ -85811,6 +87668,11 @@ impl fmt::Display for ModifyVolumeError {
     }
 }
 impl Error for ModifyVolumeError {}
+impl ServiceErrorCode for ModifyVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyVolumeAttribute
 #[derive(Debug, PartialEq)]
 pub enum ModifyVolumeAttributeError {}
@@ -85846,6 +87708,11 @@ impl fmt::Display for ModifyVolumeAttributeError {
     }
 }
 impl Error for ModifyVolumeAttributeError {}
+impl ServiceErrorCode for ModifyVolumeAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyVpcAttribute
 #[derive(Debug, PartialEq)]
 pub enum ModifyVpcAttributeError {}
@@ -85881,6 +87748,11 @@ impl fmt::Display for ModifyVpcAttributeError {
     }
 }
 impl Error for ModifyVpcAttributeError {}
+impl ServiceErrorCode for ModifyVpcAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyVpcEndpoint
 #[derive(Debug, PartialEq)]
 pub enum ModifyVpcEndpointError {}
@@ -85916,6 +87788,11 @@ impl fmt::Display for ModifyVpcEndpointError {
     }
 }
 impl Error for ModifyVpcEndpointError {}
+impl ServiceErrorCode for ModifyVpcEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyVpcEndpointConnectionNotification
 #[derive(Debug, PartialEq)]
 pub enum ModifyVpcEndpointConnectionNotificationError {}
@@ -85953,6 +87830,11 @@ impl fmt::Display for ModifyVpcEndpointConnectionNotificationError {
     }
 }
 impl Error for ModifyVpcEndpointConnectionNotificationError {}
+impl ServiceErrorCode for ModifyVpcEndpointConnectionNotificationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyVpcEndpointServiceConfiguration
 #[derive(Debug, PartialEq)]
 pub enum ModifyVpcEndpointServiceConfigurationError {}
@@ -85990,6 +87872,11 @@ impl fmt::Display for ModifyVpcEndpointServiceConfigurationError {
     }
 }
 impl Error for ModifyVpcEndpointServiceConfigurationError {}
+impl ServiceErrorCode for ModifyVpcEndpointServiceConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyVpcEndpointServicePermissions
 #[derive(Debug, PartialEq)]
 pub enum ModifyVpcEndpointServicePermissionsError {}
@@ -86027,6 +87914,11 @@ impl fmt::Display for ModifyVpcEndpointServicePermissionsError {
     }
 }
 impl Error for ModifyVpcEndpointServicePermissionsError {}
+impl ServiceErrorCode for ModifyVpcEndpointServicePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyVpcPeeringConnectionOptions
 #[derive(Debug, PartialEq)]
 pub enum ModifyVpcPeeringConnectionOptionsError {}
@@ -86064,6 +87956,11 @@ impl fmt::Display for ModifyVpcPeeringConnectionOptionsError {
     }
 }
 impl Error for ModifyVpcPeeringConnectionOptionsError {}
+impl ServiceErrorCode for ModifyVpcPeeringConnectionOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyVpcTenancy
 #[derive(Debug, PartialEq)]
 pub enum ModifyVpcTenancyError {}
@@ -86099,6 +87996,11 @@ impl fmt::Display for ModifyVpcTenancyError {
     }
 }
 impl Error for ModifyVpcTenancyError {}
+impl ServiceErrorCode for ModifyVpcTenancyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyVpnConnection
 #[derive(Debug, PartialEq)]
 pub enum ModifyVpnConnectionError {}
@@ -86134,6 +88036,11 @@ impl fmt::Display for ModifyVpnConnectionError {
     }
 }
 impl Error for ModifyVpnConnectionError {}
+impl ServiceErrorCode for ModifyVpnConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyVpnConnectionOptions
 #[derive(Debug, PartialEq)]
 pub enum ModifyVpnConnectionOptionsError {}
@@ -86171,6 +88078,11 @@ impl fmt::Display for ModifyVpnConnectionOptionsError {
     }
 }
 impl Error for ModifyVpnConnectionOptionsError {}
+impl ServiceErrorCode for ModifyVpnConnectionOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyVpnTunnelCertificate
 #[derive(Debug, PartialEq)]
 pub enum ModifyVpnTunnelCertificateError {}
@@ -86208,6 +88120,11 @@ impl fmt::Display for ModifyVpnTunnelCertificateError {
     }
 }
 impl Error for ModifyVpnTunnelCertificateError {}
+impl ServiceErrorCode for ModifyVpnTunnelCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ModifyVpnTunnelOptions
 #[derive(Debug, PartialEq)]
 pub enum ModifyVpnTunnelOptionsError {}
@@ -86243,6 +88160,11 @@ impl fmt::Display for ModifyVpnTunnelOptionsError {
     }
 }
 impl Error for ModifyVpnTunnelOptionsError {}
+impl ServiceErrorCode for ModifyVpnTunnelOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by MonitorInstances
 #[derive(Debug, PartialEq)]
 pub enum MonitorInstancesError {}
@@ -86278,6 +88200,11 @@ impl fmt::Display for MonitorInstancesError {
     }
 }
 impl Error for MonitorInstancesError {}
+impl ServiceErrorCode for MonitorInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by MoveAddressToVpc
 #[derive(Debug, PartialEq)]
 pub enum MoveAddressToVpcError {}
@@ -86313,6 +88240,11 @@ impl fmt::Display for MoveAddressToVpcError {
     }
 }
 impl Error for MoveAddressToVpcError {}
+impl ServiceErrorCode for MoveAddressToVpcError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ProvisionByoipCidr
 #[derive(Debug, PartialEq)]
 pub enum ProvisionByoipCidrError {}
@@ -86348,6 +88280,11 @@ impl fmt::Display for ProvisionByoipCidrError {
     }
 }
 impl Error for ProvisionByoipCidrError {}
+impl ServiceErrorCode for ProvisionByoipCidrError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PurchaseHostReservation
 #[derive(Debug, PartialEq)]
 pub enum PurchaseHostReservationError {}
@@ -86383,6 +88320,11 @@ impl fmt::Display for PurchaseHostReservationError {
     }
 }
 impl Error for PurchaseHostReservationError {}
+impl ServiceErrorCode for PurchaseHostReservationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PurchaseReservedInstancesOffering
 #[derive(Debug, PartialEq)]
 pub enum PurchaseReservedInstancesOfferingError {}
@@ -86420,6 +88362,11 @@ impl fmt::Display for PurchaseReservedInstancesOfferingError {
     }
 }
 impl Error for PurchaseReservedInstancesOfferingError {}
+impl ServiceErrorCode for PurchaseReservedInstancesOfferingError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PurchaseScheduledInstances
 #[derive(Debug, PartialEq)]
 pub enum PurchaseScheduledInstancesError {}
@@ -86457,6 +88404,11 @@ impl fmt::Display for PurchaseScheduledInstancesError {
     }
 }
 impl Error for PurchaseScheduledInstancesError {}
+impl ServiceErrorCode for PurchaseScheduledInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RebootInstances
 #[derive(Debug, PartialEq)]
 pub enum RebootInstancesError {}
@@ -86492,6 +88444,11 @@ impl fmt::Display for RebootInstancesError {
     }
 }
 impl Error for RebootInstancesError {}
+impl ServiceErrorCode for RebootInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RegisterImage
 #[derive(Debug, PartialEq)]
 pub enum RegisterImageError {}
@@ -86527,6 +88484,11 @@ impl fmt::Display for RegisterImageError {
     }
 }
 impl Error for RegisterImageError {}
+impl ServiceErrorCode for RegisterImageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RegisterInstanceEventNotificationAttributes
 #[derive(Debug, PartialEq)]
 pub enum RegisterInstanceEventNotificationAttributesError {}
@@ -86564,6 +88526,11 @@ impl fmt::Display for RegisterInstanceEventNotificationAttributesError {
     }
 }
 impl Error for RegisterInstanceEventNotificationAttributesError {}
+impl ServiceErrorCode for RegisterInstanceEventNotificationAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RegisterTransitGatewayMulticastGroupMembers
 #[derive(Debug, PartialEq)]
 pub enum RegisterTransitGatewayMulticastGroupMembersError {}
@@ -86601,6 +88568,11 @@ impl fmt::Display for RegisterTransitGatewayMulticastGroupMembersError {
     }
 }
 impl Error for RegisterTransitGatewayMulticastGroupMembersError {}
+impl ServiceErrorCode for RegisterTransitGatewayMulticastGroupMembersError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RegisterTransitGatewayMulticastGroupSources
 #[derive(Debug, PartialEq)]
 pub enum RegisterTransitGatewayMulticastGroupSourcesError {}
@@ -86638,6 +88610,11 @@ impl fmt::Display for RegisterTransitGatewayMulticastGroupSourcesError {
     }
 }
 impl Error for RegisterTransitGatewayMulticastGroupSourcesError {}
+impl ServiceErrorCode for RegisterTransitGatewayMulticastGroupSourcesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RejectTransitGatewayMulticastDomainAssociations
 #[derive(Debug, PartialEq)]
 pub enum RejectTransitGatewayMulticastDomainAssociationsError {}
@@ -86675,6 +88652,11 @@ impl fmt::Display for RejectTransitGatewayMulticastDomainAssociationsError {
     }
 }
 impl Error for RejectTransitGatewayMulticastDomainAssociationsError {}
+impl ServiceErrorCode for RejectTransitGatewayMulticastDomainAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RejectTransitGatewayPeeringAttachment
 #[derive(Debug, PartialEq)]
 pub enum RejectTransitGatewayPeeringAttachmentError {}
@@ -86712,6 +88694,11 @@ impl fmt::Display for RejectTransitGatewayPeeringAttachmentError {
     }
 }
 impl Error for RejectTransitGatewayPeeringAttachmentError {}
+impl ServiceErrorCode for RejectTransitGatewayPeeringAttachmentError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RejectTransitGatewayVpcAttachment
 #[derive(Debug, PartialEq)]
 pub enum RejectTransitGatewayVpcAttachmentError {}
@@ -86749,6 +88736,11 @@ impl fmt::Display for RejectTransitGatewayVpcAttachmentError {
     }
 }
 impl Error for RejectTransitGatewayVpcAttachmentError {}
+impl ServiceErrorCode for RejectTransitGatewayVpcAttachmentError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RejectVpcEndpointConnections
 #[derive(Debug, PartialEq)]
 pub enum RejectVpcEndpointConnectionsError {}
@@ -86786,6 +88778,11 @@ impl fmt::Display for RejectVpcEndpointConnectionsError {
     }
 }
 impl Error for RejectVpcEndpointConnectionsError {}
+impl ServiceErrorCode for RejectVpcEndpointConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RejectVpcPeeringConnection
 #[derive(Debug, PartialEq)]
 pub enum RejectVpcPeeringConnectionError {}
@@ -86823,6 +88820,11 @@ impl fmt::Display for RejectVpcPeeringConnectionError {
     }
 }
 impl Error for RejectVpcPeeringConnectionError {}
+impl ServiceErrorCode for RejectVpcPeeringConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ReleaseAddress
 #[derive(Debug, PartialEq)]
 pub enum ReleaseAddressError {}
@@ -86858,6 +88860,11 @@ impl fmt::Display for ReleaseAddressError {
     }
 }
 impl Error for ReleaseAddressError {}
+impl ServiceErrorCode for ReleaseAddressError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ReleaseHosts
 #[derive(Debug, PartialEq)]
 pub enum ReleaseHostsError {}
@@ -86893,6 +88900,11 @@ impl fmt::Display for ReleaseHostsError {
     }
 }
 impl Error for ReleaseHostsError {}
+impl ServiceErrorCode for ReleaseHostsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ReplaceIamInstanceProfileAssociation
 #[derive(Debug, PartialEq)]
 pub enum ReplaceIamInstanceProfileAssociationError {}
@@ -86930,6 +88942,11 @@ impl fmt::Display for ReplaceIamInstanceProfileAssociationError {
     }
 }
 impl Error for ReplaceIamInstanceProfileAssociationError {}
+impl ServiceErrorCode for ReplaceIamInstanceProfileAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ReplaceNetworkAclAssociation
 #[derive(Debug, PartialEq)]
 pub enum ReplaceNetworkAclAssociationError {}
@@ -86967,6 +88984,11 @@ impl fmt::Display for ReplaceNetworkAclAssociationError {
     }
 }
 impl Error for ReplaceNetworkAclAssociationError {}
+impl ServiceErrorCode for ReplaceNetworkAclAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ReplaceNetworkAclEntry
 #[derive(Debug, PartialEq)]
 pub enum ReplaceNetworkAclEntryError {}
@@ -87002,6 +89024,11 @@ impl fmt::Display for ReplaceNetworkAclEntryError {
     }
 }
 impl Error for ReplaceNetworkAclEntryError {}
+impl ServiceErrorCode for ReplaceNetworkAclEntryError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ReplaceRoute
 #[derive(Debug, PartialEq)]
 pub enum ReplaceRouteError {}
@@ -87037,6 +89064,11 @@ impl fmt::Display for ReplaceRouteError {
     }
 }
 impl Error for ReplaceRouteError {}
+impl ServiceErrorCode for ReplaceRouteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ReplaceRouteTableAssociation
 #[derive(Debug, PartialEq)]
 pub enum ReplaceRouteTableAssociationError {}
@@ -87074,6 +89106,11 @@ impl fmt::Display for ReplaceRouteTableAssociationError {
     }
 }
 impl Error for ReplaceRouteTableAssociationError {}
+impl ServiceErrorCode for ReplaceRouteTableAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ReplaceTransitGatewayRoute
 #[derive(Debug, PartialEq)]
 pub enum ReplaceTransitGatewayRouteError {}
@@ -87111,6 +89148,11 @@ impl fmt::Display for ReplaceTransitGatewayRouteError {
     }
 }
 impl Error for ReplaceTransitGatewayRouteError {}
+impl ServiceErrorCode for ReplaceTransitGatewayRouteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ReportInstanceStatus
 #[derive(Debug, PartialEq)]
 pub enum ReportInstanceStatusError {}
@@ -87146,6 +89188,11 @@ impl fmt::Display for ReportInstanceStatusError {
     }
 }
 impl Error for ReportInstanceStatusError {}
+impl ServiceErrorCode for ReportInstanceStatusError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RequestSpotFleet
 #[derive(Debug, PartialEq)]
 pub enum RequestSpotFleetError {}
@@ -87181,6 +89228,11 @@ impl fmt::Display for RequestSpotFleetError {
     }
 }
 impl Error for RequestSpotFleetError {}
+impl ServiceErrorCode for RequestSpotFleetError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RequestSpotInstances
 #[derive(Debug, PartialEq)]
 pub enum RequestSpotInstancesError {}
@@ -87216,6 +89268,11 @@ impl fmt::Display for RequestSpotInstancesError {
     }
 }
 impl Error for RequestSpotInstancesError {}
+impl ServiceErrorCode for RequestSpotInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ResetEbsDefaultKmsKeyId
 #[derive(Debug, PartialEq)]
 pub enum ResetEbsDefaultKmsKeyIdError {}
@@ -87251,6 +89308,11 @@ impl fmt::Display for ResetEbsDefaultKmsKeyIdError {
     }
 }
 impl Error for ResetEbsDefaultKmsKeyIdError {}
+impl ServiceErrorCode for ResetEbsDefaultKmsKeyIdError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ResetFpgaImageAttribute
 #[derive(Debug, PartialEq)]
 pub enum ResetFpgaImageAttributeError {}
@@ -87286,6 +89348,11 @@ impl fmt::Display for ResetFpgaImageAttributeError {
     }
 }
 impl Error for ResetFpgaImageAttributeError {}
+impl ServiceErrorCode for ResetFpgaImageAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ResetImageAttribute
 #[derive(Debug, PartialEq)]
 pub enum ResetImageAttributeError {}
@@ -87321,6 +89388,11 @@ impl fmt::Display for ResetImageAttributeError {
     }
 }
 impl Error for ResetImageAttributeError {}
+impl ServiceErrorCode for ResetImageAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ResetInstanceAttribute
 #[derive(Debug, PartialEq)]
 pub enum ResetInstanceAttributeError {}
@@ -87356,6 +89428,11 @@ impl fmt::Display for ResetInstanceAttributeError {
     }
 }
 impl Error for ResetInstanceAttributeError {}
+impl ServiceErrorCode for ResetInstanceAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ResetNetworkInterfaceAttribute
 #[derive(Debug, PartialEq)]
 pub enum ResetNetworkInterfaceAttributeError {}
@@ -87393,6 +89470,11 @@ impl fmt::Display for ResetNetworkInterfaceAttributeError {
     }
 }
 impl Error for ResetNetworkInterfaceAttributeError {}
+impl ServiceErrorCode for ResetNetworkInterfaceAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ResetSnapshotAttribute
 #[derive(Debug, PartialEq)]
 pub enum ResetSnapshotAttributeError {}
@@ -87428,6 +89510,11 @@ impl fmt::Display for ResetSnapshotAttributeError {
     }
 }
 impl Error for ResetSnapshotAttributeError {}
+impl ServiceErrorCode for ResetSnapshotAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RestoreAddressToClassic
 #[derive(Debug, PartialEq)]
 pub enum RestoreAddressToClassicError {}
@@ -87463,6 +89550,11 @@ impl fmt::Display for RestoreAddressToClassicError {
     }
 }
 impl Error for RestoreAddressToClassicError {}
+impl ServiceErrorCode for RestoreAddressToClassicError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RestoreManagedPrefixListVersion
 #[derive(Debug, PartialEq)]
 pub enum RestoreManagedPrefixListVersionError {}
@@ -87500,6 +89592,11 @@ impl fmt::Display for RestoreManagedPrefixListVersionError {
     }
 }
 impl Error for RestoreManagedPrefixListVersionError {}
+impl ServiceErrorCode for RestoreManagedPrefixListVersionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RevokeClientVpnIngress
 #[derive(Debug, PartialEq)]
 pub enum RevokeClientVpnIngressError {}
@@ -87535,6 +89632,11 @@ impl fmt::Display for RevokeClientVpnIngressError {
     }
 }
 impl Error for RevokeClientVpnIngressError {}
+impl ServiceErrorCode for RevokeClientVpnIngressError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RevokeSecurityGroupEgress
 #[derive(Debug, PartialEq)]
 pub enum RevokeSecurityGroupEgressError {}
@@ -87570,6 +89672,11 @@ impl fmt::Display for RevokeSecurityGroupEgressError {
     }
 }
 impl Error for RevokeSecurityGroupEgressError {}
+impl ServiceErrorCode for RevokeSecurityGroupEgressError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RevokeSecurityGroupIngress
 #[derive(Debug, PartialEq)]
 pub enum RevokeSecurityGroupIngressError {}
@@ -87607,6 +89714,11 @@ impl fmt::Display for RevokeSecurityGroupIngressError {
     }
 }
 impl Error for RevokeSecurityGroupIngressError {}
+impl ServiceErrorCode for RevokeSecurityGroupIngressError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RunInstances
 #[derive(Debug, PartialEq)]
 pub enum RunInstancesError {}
@@ -87642,6 +89754,11 @@ impl fmt::Display for RunInstancesError {
     }
 }
 impl Error for RunInstancesError {}
+impl ServiceErrorCode for RunInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RunScheduledInstances
 #[derive(Debug, PartialEq)]
 pub enum RunScheduledInstancesError {}
@@ -87677,6 +89794,11 @@ impl fmt::Display for RunScheduledInstancesError {
     }
 }
 impl Error for RunScheduledInstancesError {}
+impl ServiceErrorCode for RunScheduledInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SearchLocalGatewayRoutes
 #[derive(Debug, PartialEq)]
 pub enum SearchLocalGatewayRoutesError {}
@@ -87712,6 +89834,11 @@ impl fmt::Display for SearchLocalGatewayRoutesError {
     }
 }
 impl Error for SearchLocalGatewayRoutesError {}
+impl ServiceErrorCode for SearchLocalGatewayRoutesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SearchTransitGatewayMulticastGroups
 #[derive(Debug, PartialEq)]
 pub enum SearchTransitGatewayMulticastGroupsError {}
@@ -87749,6 +89876,11 @@ impl fmt::Display for SearchTransitGatewayMulticastGroupsError {
     }
 }
 impl Error for SearchTransitGatewayMulticastGroupsError {}
+impl ServiceErrorCode for SearchTransitGatewayMulticastGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SearchTransitGatewayRoutes
 #[derive(Debug, PartialEq)]
 pub enum SearchTransitGatewayRoutesError {}
@@ -87786,6 +89918,11 @@ impl fmt::Display for SearchTransitGatewayRoutesError {
     }
 }
 impl Error for SearchTransitGatewayRoutesError {}
+impl ServiceErrorCode for SearchTransitGatewayRoutesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SendDiagnosticInterrupt
 #[derive(Debug, PartialEq)]
 pub enum SendDiagnosticInterruptError {}
@@ -87821,6 +89958,11 @@ impl fmt::Display for SendDiagnosticInterruptError {
     }
 }
 impl Error for SendDiagnosticInterruptError {}
+impl ServiceErrorCode for SendDiagnosticInterruptError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by StartInstances
 #[derive(Debug, PartialEq)]
 pub enum StartInstancesError {}
@@ -87856,6 +89998,11 @@ impl fmt::Display for StartInstancesError {
     }
 }
 impl Error for StartInstancesError {}
+impl ServiceErrorCode for StartInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by StartNetworkInsightsAnalysis
 #[derive(Debug, PartialEq)]
 pub enum StartNetworkInsightsAnalysisError {}
@@ -87893,6 +90040,11 @@ impl fmt::Display for StartNetworkInsightsAnalysisError {
     }
 }
 impl Error for StartNetworkInsightsAnalysisError {}
+impl ServiceErrorCode for StartNetworkInsightsAnalysisError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by StartVpcEndpointServicePrivateDnsVerification
 #[derive(Debug, PartialEq)]
 pub enum StartVpcEndpointServicePrivateDnsVerificationError {}
@@ -87930,6 +90082,11 @@ impl fmt::Display for StartVpcEndpointServicePrivateDnsVerificationError {
     }
 }
 impl Error for StartVpcEndpointServicePrivateDnsVerificationError {}
+impl ServiceErrorCode for StartVpcEndpointServicePrivateDnsVerificationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by StopInstances
 #[derive(Debug, PartialEq)]
 pub enum StopInstancesError {}
@@ -87965,6 +90122,11 @@ impl fmt::Display for StopInstancesError {
     }
 }
 impl Error for StopInstancesError {}
+impl ServiceErrorCode for StopInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by TerminateClientVpnConnections
 #[derive(Debug, PartialEq)]
 pub enum TerminateClientVpnConnectionsError {}
@@ -88002,6 +90164,11 @@ impl fmt::Display for TerminateClientVpnConnectionsError {
     }
 }
 impl Error for TerminateClientVpnConnectionsError {}
+impl ServiceErrorCode for TerminateClientVpnConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by TerminateInstances
 #[derive(Debug, PartialEq)]
 pub enum TerminateInstancesError {}
@@ -88037,6 +90204,11 @@ impl fmt::Display for TerminateInstancesError {
     }
 }
 impl Error for TerminateInstancesError {}
+impl ServiceErrorCode for TerminateInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UnassignIpv6Addresses
 #[derive(Debug, PartialEq)]
 pub enum UnassignIpv6AddressesError {}
@@ -88072,6 +90244,11 @@ impl fmt::Display for UnassignIpv6AddressesError {
     }
 }
 impl Error for UnassignIpv6AddressesError {}
+impl ServiceErrorCode for UnassignIpv6AddressesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UnassignPrivateIpAddresses
 #[derive(Debug, PartialEq)]
 pub enum UnassignPrivateIpAddressesError {}
@@ -88109,6 +90286,11 @@ impl fmt::Display for UnassignPrivateIpAddressesError {
     }
 }
 impl Error for UnassignPrivateIpAddressesError {}
+impl ServiceErrorCode for UnassignPrivateIpAddressesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UnmonitorInstances
 #[derive(Debug, PartialEq)]
 pub enum UnmonitorInstancesError {}
@@ -88144,6 +90326,11 @@ impl fmt::Display for UnmonitorInstancesError {
     }
 }
 impl Error for UnmonitorInstancesError {}
+impl ServiceErrorCode for UnmonitorInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UpdateSecurityGroupRuleDescriptionsEgress
 #[derive(Debug, PartialEq)]
 pub enum UpdateSecurityGroupRuleDescriptionsEgressError {}
@@ -88181,6 +90368,11 @@ impl fmt::Display for UpdateSecurityGroupRuleDescriptionsEgressError {
     }
 }
 impl Error for UpdateSecurityGroupRuleDescriptionsEgressError {}
+impl ServiceErrorCode for UpdateSecurityGroupRuleDescriptionsEgressError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UpdateSecurityGroupRuleDescriptionsIngress
 #[derive(Debug, PartialEq)]
 pub enum UpdateSecurityGroupRuleDescriptionsIngressError {}
@@ -88218,6 +90410,11 @@ impl fmt::Display for UpdateSecurityGroupRuleDescriptionsIngressError {
     }
 }
 impl Error for UpdateSecurityGroupRuleDescriptionsIngressError {}
+impl ServiceErrorCode for UpdateSecurityGroupRuleDescriptionsIngressError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by WithdrawByoipCidr
 #[derive(Debug, PartialEq)]
 pub enum WithdrawByoipCidrError {}
@@ -88253,6 +90450,11 @@ impl fmt::Display for WithdrawByoipCidrError {
     }
 }
 impl Error for WithdrawByoipCidrError {}
+impl ServiceErrorCode for WithdrawByoipCidrError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Trait representing the capabilities of the Amazon EC2 API. Amazon EC2 clients implement this trait.
 #[async_trait]
 pub trait Ec2 {
@@ -91664,6 +93866,10 @@ impl Ec2 for Ec2Client {
         AssociateClientVpnTargetNetworkResult,
         RusotoError<AssociateClientVpnTargetNetworkError>,
     > {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("AssociateClientVpnTargetNetwork");
         let mut params = params;
@@ -92041,6 +94247,10 @@ impl Ec2 for Ec2Client {
         &self,
         input: AuthorizeClientVpnIngressRequest,
     ) -> Result<AuthorizeClientVpnIngressResult, RusotoError<AuthorizeClientVpnIngressError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("AuthorizeClientVpnIngress");
         let mut params = params;
@@ -92463,6 +94673,10 @@ impl Ec2 for Ec2Client {
         &self,
         input: CreateCarrierGatewayRequest,
     ) -> Result<CreateCarrierGatewayResult, RusotoError<CreateCarrierGatewayError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("CreateCarrierGateway");
         let mut params = params;
@@ -92489,6 +94703,10 @@ impl Ec2 for Ec2Client {
         &self,
         input: CreateClientVpnEndpointRequest,
     ) -> Result<CreateClientVpnEndpointResult, RusotoError<CreateClientVpnEndpointError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("CreateClientVpnEndpoint");
         let mut params = params;
@@ -92515,6 +94733,10 @@ impl Ec2 for Ec2Client {
         &self,
         input: CreateClientVpnRouteRequest,
     ) -> Result<CreateClientVpnRouteResult, RusotoError<CreateClientVpnRouteError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("CreateClientVpnRoute");
         let mut params = params;
@@ -92974,6 +95196,10 @@ impl Ec2 for Ec2Client {
         &self,
         input: CreateManagedPrefixListRequest,
     ) -> Result<CreateManagedPrefixListResult, RusotoError<CreateManagedPrefixListError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("CreateManagedPrefixList");
         let mut params = params;
@@ -93000,6 +95226,10 @@ impl Ec2 for Ec2Client {
         &self,
         input: CreateNatGatewayRequest,
     ) -> Result<CreateNatGatewayResult, RusotoError<CreateNatGatewayError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("CreateNatGateway");
         let mut params = params;
@@ -93072,6 +95302,12 @@ impl Ec2 for Ec2Client {
         &self,
         input: CreateNetworkInsightsPathRequest,
     ) -> Result<CreateNetworkInsightsPathResult, RusotoError<CreateNetworkInsightsPathError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("CreateNetworkInsightsPath");
         let mut params = params;
@@ -93416,6 +95652,10 @@ impl Ec2 for Ec2Client {
         &self,
         input: CreateTrafficMirrorFilterRequest,
     ) -> Result<CreateTrafficMirrorFilterResult, RusotoError<CreateTrafficMirrorFilterError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("CreateTrafficMirrorFilter");
         let mut params = params;
@@ -93443,6 +95683,10 @@ impl Ec2 for Ec2Client {
         input: CreateTrafficMirrorFilterRuleRequest,
     ) -> Result<CreateTrafficMirrorFilterRuleResult, RusotoError<CreateTrafficMirrorFilterRuleError>>
     {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("CreateTrafficMirrorFilterRule");
         let mut params = params;
@@ -93470,6 +95714,10 @@ impl Ec2 for Ec2Client {
         input: CreateTrafficMirrorSessionRequest,
     ) -> Result<CreateTrafficMirrorSessionResult, RusotoError<CreateTrafficMirrorSessionError>>
     {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("CreateTrafficMirrorSession");
         let mut params = params;
@@ -93496,6 +95744,10 @@ impl Ec2 for Ec2Client {
         &self,
         input: CreateTrafficMirrorTargetRequest,
     ) -> Result<CreateTrafficMirrorTargetResult, RusotoError<CreateTrafficMirrorTargetError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("CreateTrafficMirrorTarget");
         let mut params = params;
@@ -99781,6 +102033,10 @@ impl Ec2 for Ec2Client {
         &self,
         input: ExportImageRequest,
     ) -> Result<ExportImageResult, RusotoError<ExportImageError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("ExportImage");
         let mut params = params;
@@ -101950,6 +104206,10 @@ impl Ec2 for Ec2Client {
         input: PurchaseScheduledInstancesRequest,
     ) -> Result<PurchaseScheduledInstancesResult, RusotoError<PurchaseScheduledInstancesError>>
     {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("PurchaseScheduledInstances");
         let mut params = params;
@@ -102839,6 +105099,10 @@ impl Ec2 for Ec2Client {
         &self,
         input: RunInstancesRequest,
     ) -> Result<Reservation, RusotoError<RunInstancesError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("RunInstances");
         let mut params = params;
@@ -102865,6 +105129,10 @@ impl Ec2 for Ec2Client {
         &self,
         input: RunScheduledInstancesRequest,
     ) -> Result<RunScheduledInstancesResult, RusotoError<RunScheduledInstancesError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("RunScheduledInstances");
         let mut params = params;
@@ -103026,6 +105294,12 @@ impl Ec2 for Ec2Client {
         input: StartNetworkInsightsAnalysisRequest,
     ) -> Result<StartNetworkInsightsAnalysisResult, RusotoError<StartNetworkInsightsAnalysisError>>
     {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
         let params = self.new_params("StartNetworkInsightsAnalysis");
         let mut params = params;
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1553,6 +1553,17 @@ impl fmt::Display for BatchCheckLayerAvailabilityError {
     }
 }
 impl Error for BatchCheckLayerAvailabilityError {}
+impl ServiceErrorCode for BatchCheckLayerAvailabilityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchCheckLayerAvailabilityError::InvalidParameter(_) => "InvalidParameterException",
+            BatchCheckLayerAvailabilityError::RepositoryNotFound(_) => {
+                "RepositoryNotFoundException"
+            }
+            BatchCheckLayerAvailabilityError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by BatchDeleteImage
 #[derive(Debug, PartialEq)]
 pub enum BatchDeleteImageError {
@@ -1595,6 +1606,15 @@ impl fmt::Display for BatchDeleteImageError {
     }
 }
 impl Error for BatchDeleteImageError {}
+impl ServiceErrorCode for BatchDeleteImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDeleteImageError::InvalidParameter(_) => "InvalidParameterException",
+            BatchDeleteImageError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            BatchDeleteImageError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by BatchGetImage
 #[derive(Debug, PartialEq)]
 pub enum BatchGetImageError {
@@ -1637,6 +1657,15 @@ impl fmt::Display for BatchGetImageError {
     }
 }
 impl Error for BatchGetImageError {}
+impl ServiceErrorCode for BatchGetImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetImageError::InvalidParameter(_) => "InvalidParameterException",
+            BatchGetImageError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            BatchGetImageError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by CompleteLayerUpload
 #[derive(Debug, PartialEq)]
 pub enum CompleteLayerUploadError {
@@ -1723,6 +1752,21 @@ impl fmt::Display for CompleteLayerUploadError {
     }
 }
 impl Error for CompleteLayerUploadError {}
+impl ServiceErrorCode for CompleteLayerUploadError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CompleteLayerUploadError::EmptyUpload(_) => "EmptyUploadException",
+            CompleteLayerUploadError::InvalidLayer(_) => "InvalidLayerException",
+            CompleteLayerUploadError::InvalidParameter(_) => "InvalidParameterException",
+            CompleteLayerUploadError::Kms(_) => "KmsException",
+            CompleteLayerUploadError::LayerAlreadyExists(_) => "LayerAlreadyExistsException",
+            CompleteLayerUploadError::LayerPartTooSmall(_) => "LayerPartTooSmallException",
+            CompleteLayerUploadError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            CompleteLayerUploadError::Server(_) => "ServerException",
+            CompleteLayerUploadError::UploadNotFound(_) => "UploadNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateRepository
 #[derive(Debug, PartialEq)]
 pub enum CreateRepositoryError {
@@ -1791,6 +1835,19 @@ impl fmt::Display for CreateRepositoryError {
     }
 }
 impl Error for CreateRepositoryError {}
+impl ServiceErrorCode for CreateRepositoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRepositoryError::InvalidParameter(_) => "InvalidParameterException",
+            CreateRepositoryError::InvalidTagParameter(_) => "InvalidTagParameterException",
+            CreateRepositoryError::Kms(_) => "KmsException",
+            CreateRepositoryError::LimitExceeded(_) => "LimitExceededException",
+            CreateRepositoryError::RepositoryAlreadyExists(_) => "RepositoryAlreadyExistsException",
+            CreateRepositoryError::Server(_) => "ServerException",
+            CreateRepositoryError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by DeleteLifecyclePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteLifecyclePolicyError {
@@ -1847,6 +1904,18 @@ impl fmt::Display for DeleteLifecyclePolicyError {
     }
 }
 impl Error for DeleteLifecyclePolicyError {}
+impl ServiceErrorCode for DeleteLifecyclePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLifecyclePolicyError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteLifecyclePolicyError::LifecyclePolicyNotFound(_) => {
+                "LifecyclePolicyNotFoundException"
+            }
+            DeleteLifecyclePolicyError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            DeleteLifecyclePolicyError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DeleteRegistryPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteRegistryPolicyError {
@@ -1893,6 +1962,17 @@ impl fmt::Display for DeleteRegistryPolicyError {
     }
 }
 impl Error for DeleteRegistryPolicyError {}
+impl ServiceErrorCode for DeleteRegistryPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRegistryPolicyError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteRegistryPolicyError::RegistryPolicyNotFound(_) => {
+                "RegistryPolicyNotFoundException"
+            }
+            DeleteRegistryPolicyError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DeleteRepository
 #[derive(Debug, PartialEq)]
 pub enum DeleteRepositoryError {
@@ -1945,6 +2025,17 @@ impl fmt::Display for DeleteRepositoryError {
     }
 }
 impl Error for DeleteRepositoryError {}
+impl ServiceErrorCode for DeleteRepositoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRepositoryError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteRepositoryError::Kms(_) => "KmsException",
+            DeleteRepositoryError::RepositoryNotEmpty(_) => "RepositoryNotEmptyException",
+            DeleteRepositoryError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            DeleteRepositoryError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DeleteRepositoryPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteRepositoryPolicyError {
@@ -2001,6 +2092,18 @@ impl fmt::Display for DeleteRepositoryPolicyError {
     }
 }
 impl Error for DeleteRepositoryPolicyError {}
+impl ServiceErrorCode for DeleteRepositoryPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRepositoryPolicyError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteRepositoryPolicyError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            DeleteRepositoryPolicyError::RepositoryPolicyNotFound(_) => {
+                "RepositoryPolicyNotFoundException"
+            }
+            DeleteRepositoryPolicyError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeImageScanFindings
 #[derive(Debug, PartialEq)]
 pub enum DescribeImageScanFindingsError {
@@ -2063,6 +2166,17 @@ impl fmt::Display for DescribeImageScanFindingsError {
     }
 }
 impl Error for DescribeImageScanFindingsError {}
+impl ServiceErrorCode for DescribeImageScanFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeImageScanFindingsError::ImageNotFound(_) => "ImageNotFoundException",
+            DescribeImageScanFindingsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeImageScanFindingsError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            DescribeImageScanFindingsError::ScanNotFound(_) => "ScanNotFoundException",
+            DescribeImageScanFindingsError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeImages
 #[derive(Debug, PartialEq)]
 pub enum DescribeImagesError {
@@ -2111,6 +2225,16 @@ impl fmt::Display for DescribeImagesError {
     }
 }
 impl Error for DescribeImagesError {}
+impl ServiceErrorCode for DescribeImagesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeImagesError::ImageNotFound(_) => "ImageNotFoundException",
+            DescribeImagesError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeImagesError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            DescribeImagesError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeRegistry
 #[derive(Debug, PartialEq)]
 pub enum DescribeRegistryError {
@@ -2147,6 +2271,14 @@ impl fmt::Display for DescribeRegistryError {
     }
 }
 impl Error for DescribeRegistryError {}
+impl ServiceErrorCode for DescribeRegistryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRegistryError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeRegistryError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeRepositories
 #[derive(Debug, PartialEq)]
 pub enum DescribeRepositoriesError {
@@ -2193,6 +2325,15 @@ impl fmt::Display for DescribeRepositoriesError {
     }
 }
 impl Error for DescribeRepositoriesError {}
+impl ServiceErrorCode for DescribeRepositoriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRepositoriesError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeRepositoriesError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            DescribeRepositoriesError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by GetAuthorizationToken
 #[derive(Debug, PartialEq)]
 pub enum GetAuthorizationTokenError {
@@ -2231,6 +2372,14 @@ impl fmt::Display for GetAuthorizationTokenError {
     }
 }
 impl Error for GetAuthorizationTokenError {}
+impl ServiceErrorCode for GetAuthorizationTokenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAuthorizationTokenError::InvalidParameter(_) => "InvalidParameterException",
+            GetAuthorizationTokenError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by GetDownloadUrlForLayer
 #[derive(Debug, PartialEq)]
 pub enum GetDownloadUrlForLayerError {
@@ -2293,6 +2442,17 @@ impl fmt::Display for GetDownloadUrlForLayerError {
     }
 }
 impl Error for GetDownloadUrlForLayerError {}
+impl ServiceErrorCode for GetDownloadUrlForLayerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDownloadUrlForLayerError::InvalidParameter(_) => "InvalidParameterException",
+            GetDownloadUrlForLayerError::LayerInaccessible(_) => "LayerInaccessibleException",
+            GetDownloadUrlForLayerError::LayersNotFound(_) => "LayersNotFoundException",
+            GetDownloadUrlForLayerError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            GetDownloadUrlForLayerError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by GetLifecyclePolicy
 #[derive(Debug, PartialEq)]
 pub enum GetLifecyclePolicyError {
@@ -2345,6 +2505,18 @@ impl fmt::Display for GetLifecyclePolicyError {
     }
 }
 impl Error for GetLifecyclePolicyError {}
+impl ServiceErrorCode for GetLifecyclePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLifecyclePolicyError::InvalidParameter(_) => "InvalidParameterException",
+            GetLifecyclePolicyError::LifecyclePolicyNotFound(_) => {
+                "LifecyclePolicyNotFoundException"
+            }
+            GetLifecyclePolicyError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            GetLifecyclePolicyError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by GetLifecyclePolicyPreview
 #[derive(Debug, PartialEq)]
 pub enum GetLifecyclePolicyPreviewError {
@@ -2401,6 +2573,18 @@ impl fmt::Display for GetLifecyclePolicyPreviewError {
     }
 }
 impl Error for GetLifecyclePolicyPreviewError {}
+impl ServiceErrorCode for GetLifecyclePolicyPreviewError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLifecyclePolicyPreviewError::InvalidParameter(_) => "InvalidParameterException",
+            GetLifecyclePolicyPreviewError::LifecyclePolicyPreviewNotFound(_) => {
+                "LifecyclePolicyPreviewNotFoundException"
+            }
+            GetLifecyclePolicyPreviewError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            GetLifecyclePolicyPreviewError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by GetRegistryPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetRegistryPolicyError {
@@ -2445,6 +2629,15 @@ impl fmt::Display for GetRegistryPolicyError {
     }
 }
 impl Error for GetRegistryPolicyError {}
+impl ServiceErrorCode for GetRegistryPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRegistryPolicyError::InvalidParameter(_) => "InvalidParameterException",
+            GetRegistryPolicyError::RegistryPolicyNotFound(_) => "RegistryPolicyNotFoundException",
+            GetRegistryPolicyError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by GetRepositoryPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetRepositoryPolicyError {
@@ -2499,6 +2692,18 @@ impl fmt::Display for GetRepositoryPolicyError {
     }
 }
 impl Error for GetRepositoryPolicyError {}
+impl ServiceErrorCode for GetRepositoryPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRepositoryPolicyError::InvalidParameter(_) => "InvalidParameterException",
+            GetRepositoryPolicyError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            GetRepositoryPolicyError::RepositoryPolicyNotFound(_) => {
+                "RepositoryPolicyNotFoundException"
+            }
+            GetRepositoryPolicyError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by InitiateLayerUpload
 #[derive(Debug, PartialEq)]
 pub enum InitiateLayerUploadError {
@@ -2551,6 +2756,16 @@ impl fmt::Display for InitiateLayerUploadError {
     }
 }
 impl Error for InitiateLayerUploadError {}
+impl ServiceErrorCode for InitiateLayerUploadError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InitiateLayerUploadError::InvalidParameter(_) => "InvalidParameterException",
+            InitiateLayerUploadError::Kms(_) => "KmsException",
+            InitiateLayerUploadError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            InitiateLayerUploadError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by ListImages
 #[derive(Debug, PartialEq)]
 pub enum ListImagesError {
@@ -2591,6 +2806,15 @@ impl fmt::Display for ListImagesError {
     }
 }
 impl Error for ListImagesError {}
+impl ServiceErrorCode for ListImagesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListImagesError::InvalidParameter(_) => "InvalidParameterException",
+            ListImagesError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            ListImagesError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2637,6 +2861,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InvalidParameter(_) => "InvalidParameterException",
+            ListTagsForResourceError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            ListTagsForResourceError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by PutImage
 #[derive(Debug, PartialEq)]
 pub enum PutImageError {
@@ -2717,6 +2950,22 @@ impl fmt::Display for PutImageError {
     }
 }
 impl Error for PutImageError {}
+impl ServiceErrorCode for PutImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutImageError::ImageAlreadyExists(_) => "ImageAlreadyExistsException",
+            PutImageError::ImageDigestDoesNotMatch(_) => "ImageDigestDoesNotMatchException",
+            PutImageError::ImageTagAlreadyExists(_) => "ImageTagAlreadyExistsException",
+            PutImageError::InvalidParameter(_) => "InvalidParameterException",
+            PutImageError::Kms(_) => "KmsException",
+            PutImageError::LayersNotFound(_) => "LayersNotFoundException",
+            PutImageError::LimitExceeded(_) => "LimitExceededException",
+            PutImageError::ReferencedImagesNotFound(_) => "ReferencedImagesNotFoundException",
+            PutImageError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            PutImageError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by PutImageScanningConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutImageScanningConfigurationError {
@@ -2771,6 +3020,17 @@ impl fmt::Display for PutImageScanningConfigurationError {
     }
 }
 impl Error for PutImageScanningConfigurationError {}
+impl ServiceErrorCode for PutImageScanningConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutImageScanningConfigurationError::InvalidParameter(_) => "InvalidParameterException",
+            PutImageScanningConfigurationError::RepositoryNotFound(_) => {
+                "RepositoryNotFoundException"
+            }
+            PutImageScanningConfigurationError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by PutImageTagMutability
 #[derive(Debug, PartialEq)]
 pub enum PutImageTagMutabilityError {
@@ -2817,6 +3077,15 @@ impl fmt::Display for PutImageTagMutabilityError {
     }
 }
 impl Error for PutImageTagMutabilityError {}
+impl ServiceErrorCode for PutImageTagMutabilityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutImageTagMutabilityError::InvalidParameter(_) => "InvalidParameterException",
+            PutImageTagMutabilityError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            PutImageTagMutabilityError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by PutLifecyclePolicy
 #[derive(Debug, PartialEq)]
 pub enum PutLifecyclePolicyError {
@@ -2861,6 +3130,15 @@ impl fmt::Display for PutLifecyclePolicyError {
     }
 }
 impl Error for PutLifecyclePolicyError {}
+impl ServiceErrorCode for PutLifecyclePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutLifecyclePolicyError::InvalidParameter(_) => "InvalidParameterException",
+            PutLifecyclePolicyError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            PutLifecyclePolicyError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by PutRegistryPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutRegistryPolicyError {
@@ -2897,6 +3175,14 @@ impl fmt::Display for PutRegistryPolicyError {
     }
 }
 impl Error for PutRegistryPolicyError {}
+impl ServiceErrorCode for PutRegistryPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRegistryPolicyError::InvalidParameter(_) => "InvalidParameterException",
+            PutRegistryPolicyError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by PutReplicationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutReplicationConfigurationError {
@@ -2937,6 +3223,14 @@ impl fmt::Display for PutReplicationConfigurationError {
     }
 }
 impl Error for PutReplicationConfigurationError {}
+impl ServiceErrorCode for PutReplicationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutReplicationConfigurationError::InvalidParameter(_) => "InvalidParameterException",
+            PutReplicationConfigurationError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by SetRepositoryPolicy
 #[derive(Debug, PartialEq)]
 pub enum SetRepositoryPolicyError {
@@ -2983,6 +3277,15 @@ impl fmt::Display for SetRepositoryPolicyError {
     }
 }
 impl Error for SetRepositoryPolicyError {}
+impl ServiceErrorCode for SetRepositoryPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetRepositoryPolicyError::InvalidParameter(_) => "InvalidParameterException",
+            SetRepositoryPolicyError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            SetRepositoryPolicyError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by StartImageScan
 #[derive(Debug, PartialEq)]
 pub enum StartImageScanError {
@@ -3043,6 +3346,18 @@ impl fmt::Display for StartImageScanError {
     }
 }
 impl Error for StartImageScanError {}
+impl ServiceErrorCode for StartImageScanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartImageScanError::ImageNotFound(_) => "ImageNotFoundException",
+            StartImageScanError::InvalidParameter(_) => "InvalidParameterException",
+            StartImageScanError::LimitExceeded(_) => "LimitExceededException",
+            StartImageScanError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            StartImageScanError::Server(_) => "ServerException",
+            StartImageScanError::UnsupportedImageType(_) => "UnsupportedImageTypeException",
+        }
+    }
+}
 /// Errors returned by StartLifecyclePolicyPreview
 #[derive(Debug, PartialEq)]
 pub enum StartLifecyclePolicyPreviewError {
@@ -3113,6 +3428,23 @@ impl fmt::Display for StartLifecyclePolicyPreviewError {
     }
 }
 impl Error for StartLifecyclePolicyPreviewError {}
+impl ServiceErrorCode for StartLifecyclePolicyPreviewError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartLifecyclePolicyPreviewError::InvalidParameter(_) => "InvalidParameterException",
+            StartLifecyclePolicyPreviewError::LifecyclePolicyNotFound(_) => {
+                "LifecyclePolicyNotFoundException"
+            }
+            StartLifecyclePolicyPreviewError::LifecyclePolicyPreviewInProgress(_) => {
+                "LifecyclePolicyPreviewInProgressException"
+            }
+            StartLifecyclePolicyPreviewError::RepositoryNotFound(_) => {
+                "RepositoryNotFoundException"
+            }
+            StartLifecyclePolicyPreviewError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -3167,6 +3499,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            TagResourceError::InvalidTagParameter(_) => "InvalidTagParameterException",
+            TagResourceError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            TagResourceError::Server(_) => "ServerException",
+            TagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3221,6 +3564,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            UntagResourceError::InvalidTagParameter(_) => "InvalidTagParameterException",
+            UntagResourceError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            UntagResourceError::Server(_) => "ServerException",
+            UntagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UploadLayerPart
 #[derive(Debug, PartialEq)]
 pub enum UploadLayerPartError {
@@ -3285,6 +3639,19 @@ impl fmt::Display for UploadLayerPartError {
     }
 }
 impl Error for UploadLayerPartError {}
+impl ServiceErrorCode for UploadLayerPartError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UploadLayerPartError::InvalidLayerPart(_) => "InvalidLayerPartException",
+            UploadLayerPartError::InvalidParameter(_) => "InvalidParameterException",
+            UploadLayerPartError::Kms(_) => "KmsException",
+            UploadLayerPartError::LimitExceeded(_) => "LimitExceededException",
+            UploadLayerPartError::RepositoryNotFound(_) => "RepositoryNotFoundException",
+            UploadLayerPartError::Server(_) => "ServerException",
+            UploadLayerPartError::UploadNotFound(_) => "UploadNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon ECR API. Amazon ECR clients implement this trait.
 #[async_trait]
 pub trait Ecr {
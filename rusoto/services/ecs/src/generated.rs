@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3676,6 +3676,17 @@ impl fmt::Display for CreateCapacityProviderError {
     }
 }
 impl Error for CreateCapacityProviderError {}
+impl ServiceErrorCode for CreateCapacityProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCapacityProviderError::Client(_) => "ClientException",
+            CreateCapacityProviderError::InvalidParameter(_) => "InvalidParameterException",
+            CreateCapacityProviderError::LimitExceeded(_) => "LimitExceededException",
+            CreateCapacityProviderError::Server(_) => "ServerException",
+            CreateCapacityProviderError::UpdateInProgress(_) => "UpdateInProgressException",
+        }
+    }
+}
 /// Errors returned by CreateCluster
 #[derive(Debug, PartialEq)]
 pub enum CreateClusterError {
@@ -3718,6 +3729,15 @@ impl fmt::Display for CreateClusterError {
     }
 }
 impl Error for CreateClusterError {}
+impl ServiceErrorCode for CreateClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateClusterError::Client(_) => "ClientException",
+            CreateClusterError::InvalidParameter(_) => "InvalidParameterException",
+            CreateClusterError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by CreateService
 #[derive(Debug, PartialEq)]
 pub enum CreateServiceError {
@@ -3794,6 +3814,22 @@ impl fmt::Display for CreateServiceError {
     }
 }
 impl Error for CreateServiceError {}
+impl ServiceErrorCode for CreateServiceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateServiceError::AccessDenied(_) => "AccessDeniedException",
+            CreateServiceError::Client(_) => "ClientException",
+            CreateServiceError::ClusterNotFound(_) => "ClusterNotFoundException",
+            CreateServiceError::InvalidParameter(_) => "InvalidParameterException",
+            CreateServiceError::PlatformTaskDefinitionIncompatibility(_) => {
+                "PlatformTaskDefinitionIncompatibilityException"
+            }
+            CreateServiceError::PlatformUnknown(_) => "PlatformUnknownException",
+            CreateServiceError::Server(_) => "ServerException",
+            CreateServiceError::UnsupportedFeature(_) => "UnsupportedFeatureException",
+        }
+    }
+}
 /// Errors returned by CreateTaskSet
 #[derive(Debug, PartialEq)]
 pub enum CreateTaskSetError {
@@ -3882,6 +3918,24 @@ impl fmt::Display for CreateTaskSetError {
     }
 }
 impl Error for CreateTaskSetError {}
+impl ServiceErrorCode for CreateTaskSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTaskSetError::AccessDenied(_) => "AccessDeniedException",
+            CreateTaskSetError::Client(_) => "ClientException",
+            CreateTaskSetError::ClusterNotFound(_) => "ClusterNotFoundException",
+            CreateTaskSetError::InvalidParameter(_) => "InvalidParameterException",
+            CreateTaskSetError::PlatformTaskDefinitionIncompatibility(_) => {
+                "PlatformTaskDefinitionIncompatibilityException"
+            }
+            CreateTaskSetError::PlatformUnknown(_) => "PlatformUnknownException",
+            CreateTaskSetError::Server(_) => "ServerException",
+            CreateTaskSetError::ServiceNotActive(_) => "ServiceNotActiveException",
+            CreateTaskSetError::ServiceNotFound(_) => "ServiceNotFoundException",
+            CreateTaskSetError::UnsupportedFeature(_) => "UnsupportedFeatureException",
+        }
+    }
+}
 /// Errors returned by DeleteAccountSetting
 #[derive(Debug, PartialEq)]
 pub enum DeleteAccountSettingError {
@@ -3926,6 +3980,15 @@ impl fmt::Display for DeleteAccountSettingError {
     }
 }
 impl Error for DeleteAccountSettingError {}
+impl ServiceErrorCode for DeleteAccountSettingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAccountSettingError::Client(_) => "ClientException",
+            DeleteAccountSettingError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteAccountSettingError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DeleteAttributes
 #[derive(Debug, PartialEq)]
 pub enum DeleteAttributesError {
@@ -3968,6 +4031,15 @@ impl fmt::Display for DeleteAttributesError {
     }
 }
 impl Error for DeleteAttributesError {}
+impl ServiceErrorCode for DeleteAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAttributesError::ClusterNotFound(_) => "ClusterNotFoundException",
+            DeleteAttributesError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteAttributesError::TargetNotFound(_) => "TargetNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteCapacityProvider
 #[derive(Debug, PartialEq)]
 pub enum DeleteCapacityProviderError {
@@ -4012,6 +4084,15 @@ impl fmt::Display for DeleteCapacityProviderError {
     }
 }
 impl Error for DeleteCapacityProviderError {}
+impl ServiceErrorCode for DeleteCapacityProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCapacityProviderError::Client(_) => "ClientException",
+            DeleteCapacityProviderError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteCapacityProviderError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DeleteCluster
 #[derive(Debug, PartialEq)]
 pub enum DeleteClusterError {
@@ -4090,6 +4171,22 @@ impl fmt::Display for DeleteClusterError {
     }
 }
 impl Error for DeleteClusterError {}
+impl ServiceErrorCode for DeleteClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteClusterError::Client(_) => "ClientException",
+            DeleteClusterError::ClusterContainsContainerInstances(_) => {
+                "ClusterContainsContainerInstancesException"
+            }
+            DeleteClusterError::ClusterContainsServices(_) => "ClusterContainsServicesException",
+            DeleteClusterError::ClusterContainsTasks(_) => "ClusterContainsTasksException",
+            DeleteClusterError::ClusterNotFound(_) => "ClusterNotFoundException",
+            DeleteClusterError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteClusterError::Server(_) => "ServerException",
+            DeleteClusterError::UpdateInProgress(_) => "UpdateInProgressException",
+        }
+    }
+}
 /// Errors returned by DeleteService
 #[derive(Debug, PartialEq)]
 pub enum DeleteServiceError {
@@ -4144,6 +4241,17 @@ impl fmt::Display for DeleteServiceError {
     }
 }
 impl Error for DeleteServiceError {}
+impl ServiceErrorCode for DeleteServiceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteServiceError::Client(_) => "ClientException",
+            DeleteServiceError::ClusterNotFound(_) => "ClusterNotFoundException",
+            DeleteServiceError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteServiceError::Server(_) => "ServerException",
+            DeleteServiceError::ServiceNotFound(_) => "ServiceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteTaskSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteTaskSetError {
@@ -4222,6 +4330,21 @@ impl fmt::Display for DeleteTaskSetError {
     }
 }
 impl Error for DeleteTaskSetError {}
+impl ServiceErrorCode for DeleteTaskSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTaskSetError::AccessDenied(_) => "AccessDeniedException",
+            DeleteTaskSetError::Client(_) => "ClientException",
+            DeleteTaskSetError::ClusterNotFound(_) => "ClusterNotFoundException",
+            DeleteTaskSetError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteTaskSetError::Server(_) => "ServerException",
+            DeleteTaskSetError::ServiceNotActive(_) => "ServiceNotActiveException",
+            DeleteTaskSetError::ServiceNotFound(_) => "ServiceNotFoundException",
+            DeleteTaskSetError::TaskSetNotFound(_) => "TaskSetNotFoundException",
+            DeleteTaskSetError::UnsupportedFeature(_) => "UnsupportedFeatureException",
+        }
+    }
+}
 /// Errors returned by DeregisterContainerInstance
 #[derive(Debug, PartialEq)]
 pub enum DeregisterContainerInstanceError {
@@ -4276,6 +4399,16 @@ impl fmt::Display for DeregisterContainerInstanceError {
     }
 }
 impl Error for DeregisterContainerInstanceError {}
+impl ServiceErrorCode for DeregisterContainerInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterContainerInstanceError::Client(_) => "ClientException",
+            DeregisterContainerInstanceError::ClusterNotFound(_) => "ClusterNotFoundException",
+            DeregisterContainerInstanceError::InvalidParameter(_) => "InvalidParameterException",
+            DeregisterContainerInstanceError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DeregisterTaskDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeregisterTaskDefinitionError {
@@ -4320,6 +4453,15 @@ impl fmt::Display for DeregisterTaskDefinitionError {
     }
 }
 impl Error for DeregisterTaskDefinitionError {}
+impl ServiceErrorCode for DeregisterTaskDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterTaskDefinitionError::Client(_) => "ClientException",
+            DeregisterTaskDefinitionError::InvalidParameter(_) => "InvalidParameterException",
+            DeregisterTaskDefinitionError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeCapacityProviders
 #[derive(Debug, PartialEq)]
 pub enum DescribeCapacityProvidersError {
@@ -4364,6 +4506,15 @@ impl fmt::Display for DescribeCapacityProvidersError {
     }
 }
 impl Error for DescribeCapacityProvidersError {}
+impl ServiceErrorCode for DescribeCapacityProvidersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCapacityProvidersError::Client(_) => "ClientException",
+            DescribeCapacityProvidersError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeCapacityProvidersError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeClusters
 #[derive(Debug, PartialEq)]
 pub enum DescribeClustersError {
@@ -4406,6 +4557,15 @@ impl fmt::Display for DescribeClustersError {
     }
 }
 impl Error for DescribeClustersError {}
+impl ServiceErrorCode for DescribeClustersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClustersError::Client(_) => "ClientException",
+            DescribeClustersError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeClustersError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeContainerInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeContainerInstancesError {
@@ -4460,6 +4620,16 @@ impl fmt::Display for DescribeContainerInstancesError {
     }
 }
 impl Error for DescribeContainerInstancesError {}
+impl ServiceErrorCode for DescribeContainerInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeContainerInstancesError::Client(_) => "ClientException",
+            DescribeContainerInstancesError::ClusterNotFound(_) => "ClusterNotFoundException",
+            DescribeContainerInstancesError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeContainerInstancesError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeServices
 #[derive(Debug, PartialEq)]
 pub enum DescribeServicesError {
@@ -4508,6 +4678,16 @@ impl fmt::Display for DescribeServicesError {
     }
 }
 impl Error for DescribeServicesError {}
+impl ServiceErrorCode for DescribeServicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeServicesError::Client(_) => "ClientException",
+            DescribeServicesError::ClusterNotFound(_) => "ClusterNotFoundException",
+            DescribeServicesError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeServicesError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeTaskDefinition
 #[derive(Debug, PartialEq)]
 pub enum DescribeTaskDefinitionError {
@@ -4552,6 +4732,15 @@ impl fmt::Display for DescribeTaskDefinitionError {
     }
 }
 impl Error for DescribeTaskDefinitionError {}
+impl ServiceErrorCode for DescribeTaskDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTaskDefinitionError::Client(_) => "ClientException",
+            DescribeTaskDefinitionError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeTaskDefinitionError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeTaskSets
 #[derive(Debug, PartialEq)]
 pub enum DescribeTaskSetsError {
@@ -4624,6 +4813,20 @@ impl fmt::Display for DescribeTaskSetsError {
     }
 }
 impl Error for DescribeTaskSetsError {}
+impl ServiceErrorCode for DescribeTaskSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTaskSetsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeTaskSetsError::Client(_) => "ClientException",
+            DescribeTaskSetsError::ClusterNotFound(_) => "ClusterNotFoundException",
+            DescribeTaskSetsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeTaskSetsError::Server(_) => "ServerException",
+            DescribeTaskSetsError::ServiceNotActive(_) => "ServiceNotActiveException",
+            DescribeTaskSetsError::ServiceNotFound(_) => "ServiceNotFoundException",
+            DescribeTaskSetsError::UnsupportedFeature(_) => "UnsupportedFeatureException",
+        }
+    }
+}
 /// Errors returned by DescribeTasks
 #[derive(Debug, PartialEq)]
 pub enum DescribeTasksError {
@@ -4672,6 +4875,16 @@ impl fmt::Display for DescribeTasksError {
     }
 }
 impl Error for DescribeTasksError {}
+impl ServiceErrorCode for DescribeTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTasksError::Client(_) => "ClientException",
+            DescribeTasksError::ClusterNotFound(_) => "ClusterNotFoundException",
+            DescribeTasksError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeTasksError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DiscoverPollEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DiscoverPollEndpointError {
@@ -4708,6 +4921,14 @@ impl fmt::Display for DiscoverPollEndpointError {
     }
 }
 impl Error for DiscoverPollEndpointError {}
+impl ServiceErrorCode for DiscoverPollEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DiscoverPollEndpointError::Client(_) => "ClientException",
+            DiscoverPollEndpointError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by ListAccountSettings
 #[derive(Debug, PartialEq)]
 pub enum ListAccountSettingsError {
@@ -4752,6 +4973,15 @@ impl fmt::Display for ListAccountSettingsError {
     }
 }
 impl Error for ListAccountSettingsError {}
+impl ServiceErrorCode for ListAccountSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAccountSettingsError::Client(_) => "ClientException",
+            ListAccountSettingsError::InvalidParameter(_) => "InvalidParameterException",
+            ListAccountSettingsError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by ListAttributes
 #[derive(Debug, PartialEq)]
 pub enum ListAttributesError {
@@ -4788,6 +5018,14 @@ impl fmt::Display for ListAttributesError {
     }
 }
 impl Error for ListAttributesError {}
+impl ServiceErrorCode for ListAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAttributesError::ClusterNotFound(_) => "ClusterNotFoundException",
+            ListAttributesError::InvalidParameter(_) => "InvalidParameterException",
+        }
+    }
+}
 /// Errors returned by ListClusters
 #[derive(Debug, PartialEq)]
 pub enum ListClustersError {
@@ -4830,6 +5068,15 @@ impl fmt::Display for ListClustersError {
     }
 }
 impl Error for ListClustersError {}
+impl ServiceErrorCode for ListClustersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListClustersError::Client(_) => "ClientException",
+            ListClustersError::InvalidParameter(_) => "InvalidParameterException",
+            ListClustersError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by ListContainerInstances
 #[derive(Debug, PartialEq)]
 pub enum ListContainerInstancesError {
@@ -4882,6 +5129,16 @@ impl fmt::Display for ListContainerInstancesError {
     }
 }
 impl Error for ListContainerInstancesError {}
+impl ServiceErrorCode for ListContainerInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListContainerInstancesError::Client(_) => "ClientException",
+            ListContainerInstancesError::ClusterNotFound(_) => "ClusterNotFoundException",
+            ListContainerInstancesError::InvalidParameter(_) => "InvalidParameterException",
+            ListContainerInstancesError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by ListServices
 #[derive(Debug, PartialEq)]
 pub enum ListServicesError {
@@ -4930,6 +5187,16 @@ impl fmt::Display for ListServicesError {
     }
 }
 impl Error for ListServicesError {}
+impl ServiceErrorCode for ListServicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListServicesError::Client(_) => "ClientException",
+            ListServicesError::ClusterNotFound(_) => "ClusterNotFoundException",
+            ListServicesError::InvalidParameter(_) => "InvalidParameterException",
+            ListServicesError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -4980,6 +5247,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::Client(_) => "ClientException",
+            ListTagsForResourceError::ClusterNotFound(_) => "ClusterNotFoundException",
+            ListTagsForResourceError::InvalidParameter(_) => "InvalidParameterException",
+            ListTagsForResourceError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by ListTaskDefinitionFamilies
 #[derive(Debug, PartialEq)]
 pub enum ListTaskDefinitionFamiliesError {
@@ -5026,6 +5303,15 @@ impl fmt::Display for ListTaskDefinitionFamiliesError {
     }
 }
 impl Error for ListTaskDefinitionFamiliesError {}
+impl ServiceErrorCode for ListTaskDefinitionFamiliesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTaskDefinitionFamiliesError::Client(_) => "ClientException",
+            ListTaskDefinitionFamiliesError::InvalidParameter(_) => "InvalidParameterException",
+            ListTaskDefinitionFamiliesError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by ListTaskDefinitions
 #[derive(Debug, PartialEq)]
 pub enum ListTaskDefinitionsError {
@@ -5070,6 +5356,15 @@ impl fmt::Display for ListTaskDefinitionsError {
     }
 }
 impl Error for ListTaskDefinitionsError {}
+impl ServiceErrorCode for ListTaskDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTaskDefinitionsError::Client(_) => "ClientException",
+            ListTaskDefinitionsError::InvalidParameter(_) => "InvalidParameterException",
+            ListTaskDefinitionsError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by ListTasks
 #[derive(Debug, PartialEq)]
 pub enum ListTasksError {
@@ -5120,6 +5415,17 @@ impl fmt::Display for ListTasksError {
     }
 }
 impl Error for ListTasksError {}
+impl ServiceErrorCode for ListTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTasksError::Client(_) => "ClientException",
+            ListTasksError::ClusterNotFound(_) => "ClusterNotFoundException",
+            ListTasksError::InvalidParameter(_) => "InvalidParameterException",
+            ListTasksError::Server(_) => "ServerException",
+            ListTasksError::ServiceNotFound(_) => "ServiceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutAccountSetting
 #[derive(Debug, PartialEq)]
 pub enum PutAccountSettingError {
@@ -5162,6 +5468,15 @@ impl fmt::Display for PutAccountSettingError {
     }
 }
 impl Error for PutAccountSettingError {}
+impl ServiceErrorCode for PutAccountSettingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAccountSettingError::Client(_) => "ClientException",
+            PutAccountSettingError::InvalidParameter(_) => "InvalidParameterException",
+            PutAccountSettingError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by PutAccountSettingDefault
 #[derive(Debug, PartialEq)]
 pub enum PutAccountSettingDefaultError {
@@ -5206,6 +5521,15 @@ impl fmt::Display for PutAccountSettingDefaultError {
     }
 }
 impl Error for PutAccountSettingDefaultError {}
+impl ServiceErrorCode for PutAccountSettingDefaultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAccountSettingDefaultError::Client(_) => "ClientException",
+            PutAccountSettingDefaultError::InvalidParameter(_) => "InvalidParameterException",
+            PutAccountSettingDefaultError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by PutAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutAttributesError {
@@ -5256,6 +5580,16 @@ impl fmt::Display for PutAttributesError {
     }
 }
 impl Error for PutAttributesError {}
+impl ServiceErrorCode for PutAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAttributesError::AttributeLimitExceeded(_) => "AttributeLimitExceededException",
+            PutAttributesError::ClusterNotFound(_) => "ClusterNotFoundException",
+            PutAttributesError::InvalidParameter(_) => "InvalidParameterException",
+            PutAttributesError::TargetNotFound(_) => "TargetNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutClusterCapacityProviders
 #[derive(Debug, PartialEq)]
 pub enum PutClusterCapacityProvidersError {
@@ -5326,6 +5660,18 @@ impl fmt::Display for PutClusterCapacityProvidersError {
     }
 }
 impl Error for PutClusterCapacityProvidersError {}
+impl ServiceErrorCode for PutClusterCapacityProvidersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutClusterCapacityProvidersError::Client(_) => "ClientException",
+            PutClusterCapacityProvidersError::ClusterNotFound(_) => "ClusterNotFoundException",
+            PutClusterCapacityProvidersError::InvalidParameter(_) => "InvalidParameterException",
+            PutClusterCapacityProvidersError::ResourceInUse(_) => "ResourceInUseException",
+            PutClusterCapacityProvidersError::Server(_) => "ServerException",
+            PutClusterCapacityProvidersError::UpdateInProgress(_) => "UpdateInProgressException",
+        }
+    }
+}
 /// Errors returned by RegisterContainerInstance
 #[derive(Debug, PartialEq)]
 pub enum RegisterContainerInstanceError {
@@ -5370,6 +5716,15 @@ impl fmt::Display for RegisterContainerInstanceError {
     }
 }
 impl Error for RegisterContainerInstanceError {}
+impl ServiceErrorCode for RegisterContainerInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterContainerInstanceError::Client(_) => "ClientException",
+            RegisterContainerInstanceError::InvalidParameter(_) => "InvalidParameterException",
+            RegisterContainerInstanceError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by RegisterTaskDefinition
 #[derive(Debug, PartialEq)]
 pub enum RegisterTaskDefinitionError {
@@ -5414,6 +5769,15 @@ impl fmt::Display for RegisterTaskDefinitionError {
     }
 }
 impl Error for RegisterTaskDefinitionError {}
+impl ServiceErrorCode for RegisterTaskDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterTaskDefinitionError::Client(_) => "ClientException",
+            RegisterTaskDefinitionError::InvalidParameter(_) => "InvalidParameterException",
+            RegisterTaskDefinitionError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by RunTask
 #[derive(Debug, PartialEq)]
 pub enum RunTaskError {
@@ -5490,6 +5854,23 @@ impl fmt::Display for RunTaskError {
     }
 }
 impl Error for RunTaskError {}
+impl ServiceErrorCode for RunTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RunTaskError::AccessDenied(_) => "AccessDeniedException",
+            RunTaskError::Blocked(_) => "BlockedException",
+            RunTaskError::Client(_) => "ClientException",
+            RunTaskError::ClusterNotFound(_) => "ClusterNotFoundException",
+            RunTaskError::InvalidParameter(_) => "InvalidParameterException",
+            RunTaskError::PlatformTaskDefinitionIncompatibility(_) => {
+                "PlatformTaskDefinitionIncompatibilityException"
+            }
+            RunTaskError::PlatformUnknown(_) => "PlatformUnknownException",
+            RunTaskError::Server(_) => "ServerException",
+            RunTaskError::UnsupportedFeature(_) => "UnsupportedFeatureException",
+        }
+    }
+}
 /// Errors returned by StartTask
 #[derive(Debug, PartialEq)]
 pub enum StartTaskError {
@@ -5534,6 +5915,16 @@ impl fmt::Display for StartTaskError {
     }
 }
 impl Error for StartTaskError {}
+impl ServiceErrorCode for StartTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartTaskError::Client(_) => "ClientException",
+            StartTaskError::ClusterNotFound(_) => "ClusterNotFoundException",
+            StartTaskError::InvalidParameter(_) => "InvalidParameterException",
+            StartTaskError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by StopTask
 #[derive(Debug, PartialEq)]
 pub enum StopTaskError {
@@ -5578,6 +5969,16 @@ impl fmt::Display for StopTaskError {
     }
 }
 impl Error for StopTaskError {}
+impl ServiceErrorCode for StopTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopTaskError::Client(_) => "ClientException",
+            StopTaskError::ClusterNotFound(_) => "ClusterNotFoundException",
+            StopTaskError::InvalidParameter(_) => "InvalidParameterException",
+            StopTaskError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by SubmitAttachmentStateChanges
 #[derive(Debug, PartialEq)]
 pub enum SubmitAttachmentStateChangesError {
@@ -5634,6 +6035,16 @@ impl fmt::Display for SubmitAttachmentStateChangesError {
     }
 }
 impl Error for SubmitAttachmentStateChangesError {}
+impl ServiceErrorCode for SubmitAttachmentStateChangesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SubmitAttachmentStateChangesError::AccessDenied(_) => "AccessDeniedException",
+            SubmitAttachmentStateChangesError::Client(_) => "ClientException",
+            SubmitAttachmentStateChangesError::InvalidParameter(_) => "InvalidParameterException",
+            SubmitAttachmentStateChangesError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by SubmitContainerStateChange
 #[derive(Debug, PartialEq)]
 pub enum SubmitContainerStateChangeError {
@@ -5680,6 +6091,15 @@ impl fmt::Display for SubmitContainerStateChangeError {
     }
 }
 impl Error for SubmitContainerStateChangeError {}
+impl ServiceErrorCode for SubmitContainerStateChangeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SubmitContainerStateChangeError::AccessDenied(_) => "AccessDeniedException",
+            SubmitContainerStateChangeError::Client(_) => "ClientException",
+            SubmitContainerStateChangeError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by SubmitTaskStateChange
 #[derive(Debug, PartialEq)]
 pub enum SubmitTaskStateChangeError {
@@ -5730,6 +6150,16 @@ impl fmt::Display for SubmitTaskStateChangeError {
     }
 }
 impl Error for SubmitTaskStateChangeError {}
+impl ServiceErrorCode for SubmitTaskStateChangeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SubmitTaskStateChangeError::AccessDenied(_) => "AccessDeniedException",
+            SubmitTaskStateChangeError::Client(_) => "ClientException",
+            SubmitTaskStateChangeError::InvalidParameter(_) => "InvalidParameterException",
+            SubmitTaskStateChangeError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -5784,6 +6214,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::Client(_) => "ClientException",
+            TagResourceError::ClusterNotFound(_) => "ClusterNotFoundException",
+            TagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -5838,6 +6279,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::Client(_) => "ClientException",
+            UntagResourceError::ClusterNotFound(_) => "ClusterNotFoundException",
+            UntagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by UpdateCapacityProvider
 #[derive(Debug, PartialEq)]
 pub enum UpdateCapacityProviderError {
@@ -5882,6 +6334,15 @@ impl fmt::Display for UpdateCapacityProviderError {
     }
 }
 impl Error for UpdateCapacityProviderError {}
+impl ServiceErrorCode for UpdateCapacityProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCapacityProviderError::Client(_) => "ClientException",
+            UpdateCapacityProviderError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateCapacityProviderError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by UpdateClusterSettings
 #[derive(Debug, PartialEq)]
 pub enum UpdateClusterSettingsError {
@@ -5934,6 +6395,16 @@ impl fmt::Display for UpdateClusterSettingsError {
     }
 }
 impl Error for UpdateClusterSettingsError {}
+impl ServiceErrorCode for UpdateClusterSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateClusterSettingsError::Client(_) => "ClientException",
+            UpdateClusterSettingsError::ClusterNotFound(_) => "ClusterNotFoundException",
+            UpdateClusterSettingsError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateClusterSettingsError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by UpdateContainerAgent
 #[derive(Debug, PartialEq)]
 pub enum UpdateContainerAgentError {
@@ -6008,6 +6479,19 @@ impl fmt::Display for UpdateContainerAgentError {
     }
 }
 impl Error for UpdateContainerAgentError {}
+impl ServiceErrorCode for UpdateContainerAgentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateContainerAgentError::Client(_) => "ClientException",
+            UpdateContainerAgentError::ClusterNotFound(_) => "ClusterNotFoundException",
+            UpdateContainerAgentError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateContainerAgentError::MissingVersion(_) => "MissingVersionException",
+            UpdateContainerAgentError::NoUpdateAvailable(_) => "NoUpdateAvailableException",
+            UpdateContainerAgentError::Server(_) => "ServerException",
+            UpdateContainerAgentError::UpdateInProgress(_) => "UpdateInProgressException",
+        }
+    }
+}
 /// Errors returned by UpdateContainerInstancesState
 #[derive(Debug, PartialEq)]
 pub enum UpdateContainerInstancesStateError {
@@ -6070,6 +6554,16 @@ impl fmt::Display for UpdateContainerInstancesStateError {
     }
 }
 impl Error for UpdateContainerInstancesStateError {}
+impl ServiceErrorCode for UpdateContainerInstancesStateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateContainerInstancesStateError::Client(_) => "ClientException",
+            UpdateContainerInstancesStateError::ClusterNotFound(_) => "ClusterNotFoundException",
+            UpdateContainerInstancesStateError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateContainerInstancesStateError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by UpdateService
 #[derive(Debug, PartialEq)]
 pub enum UpdateServiceError {
@@ -6152,6 +6646,23 @@ impl fmt::Display for UpdateServiceError {
     }
 }
 impl Error for UpdateServiceError {}
+impl ServiceErrorCode for UpdateServiceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateServiceError::AccessDenied(_) => "AccessDeniedException",
+            UpdateServiceError::Client(_) => "ClientException",
+            UpdateServiceError::ClusterNotFound(_) => "ClusterNotFoundException",
+            UpdateServiceError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateServiceError::PlatformTaskDefinitionIncompatibility(_) => {
+                "PlatformTaskDefinitionIncompatibilityException"
+            }
+            UpdateServiceError::PlatformUnknown(_) => "PlatformUnknownException",
+            UpdateServiceError::Server(_) => "ServerException",
+            UpdateServiceError::ServiceNotActive(_) => "ServiceNotActiveException",
+            UpdateServiceError::ServiceNotFound(_) => "ServiceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateServicePrimaryTaskSet
 #[derive(Debug, PartialEq)]
 pub enum UpdateServicePrimaryTaskSetError {
@@ -6248,6 +6759,23 @@ impl fmt::Display for UpdateServicePrimaryTaskSetError {
     }
 }
 impl Error for UpdateServicePrimaryTaskSetError {}
+impl ServiceErrorCode for UpdateServicePrimaryTaskSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateServicePrimaryTaskSetError::AccessDenied(_) => "AccessDeniedException",
+            UpdateServicePrimaryTaskSetError::Client(_) => "ClientException",
+            UpdateServicePrimaryTaskSetError::ClusterNotFound(_) => "ClusterNotFoundException",
+            UpdateServicePrimaryTaskSetError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateServicePrimaryTaskSetError::Server(_) => "ServerException",
+            UpdateServicePrimaryTaskSetError::ServiceNotActive(_) => "ServiceNotActiveException",
+            UpdateServicePrimaryTaskSetError::ServiceNotFound(_) => "ServiceNotFoundException",
+            UpdateServicePrimaryTaskSetError::TaskSetNotFound(_) => "TaskSetNotFoundException",
+            UpdateServicePrimaryTaskSetError::UnsupportedFeature(_) => {
+                "UnsupportedFeatureException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateTaskSet
 #[derive(Debug, PartialEq)]
 pub enum UpdateTaskSetError {
@@ -6326,6 +6854,21 @@ impl fmt::Display for UpdateTaskSetError {
     }
 }
 impl Error for UpdateTaskSetError {}
+impl ServiceErrorCode for UpdateTaskSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTaskSetError::AccessDenied(_) => "AccessDeniedException",
+            UpdateTaskSetError::Client(_) => "ClientException",
+            UpdateTaskSetError::ClusterNotFound(_) => "ClusterNotFoundException",
+            UpdateTaskSetError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateTaskSetError::Server(_) => "ServerException",
+            UpdateTaskSetError::ServiceNotActive(_) => "ServiceNotActiveException",
+            UpdateTaskSetError::ServiceNotFound(_) => "ServiceNotFoundException",
+            UpdateTaskSetError::TaskSetNotFound(_) => "TaskSetNotFoundException",
+            UpdateTaskSetError::UnsupportedFeature(_) => "UnsupportedFeatureException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon ECS API. Amazon ECS clients implement this trait.
 #[async_trait]
 pub trait Ecs {
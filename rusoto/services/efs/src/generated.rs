@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -92,6 +92,7 @@ pub struct BackupPolicyDescription {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateAccessPointRequest {
     /// <p>A string of up to 64 ASCII characters that Amazon EFS uses to ensure idempotent creation.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     pub client_token: String,
     /// <p>The ID of the EFS file system that the access point provides access to.</p>
@@ -115,6 +116,7 @@ pub struct CreateAccessPointRequest {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateFileSystemRequest {
     /// <p>A string of up to 64 ASCII characters. Amazon EFS uses this to ensure idempotent creation.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "CreationToken")]
     pub creation_token: String,
     /// <p>A Boolean value that, if true, creates an encrypted file system. When creating an encrypted file system, you have the option of specifying <a>CreateFileSystemRequest$KmsKeyId</a> for an existing AWS Key Management Service (AWS KMS) customer master key (CMK). If you don't specify a CMK, then the default CMK for Amazon EFS, <code>/aws/elasticfilesystem</code>, is used to protect the encrypted file system. </p>
@@ -806,6 +808,20 @@ impl fmt::Display for CreateAccessPointError {
     }
 }
 impl Error for CreateAccessPointError {}
+impl ServiceErrorCode for CreateAccessPointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAccessPointError::AccessPointAlreadyExists(_) => "AccessPointAlreadyExists",
+            CreateAccessPointError::AccessPointLimitExceeded(_) => "AccessPointLimitExceeded",
+            CreateAccessPointError::BadRequest(_) => "BadRequest",
+            CreateAccessPointError::FileSystemNotFound(_) => "FileSystemNotFound",
+            CreateAccessPointError::IncorrectFileSystemLifeCycleState(_) => {
+                "IncorrectFileSystemLifeCycleState"
+            }
+            CreateAccessPointError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by CreateFileSystem
 #[derive(Debug, PartialEq)]
 pub enum CreateFileSystemError {
@@ -878,6 +894,20 @@ impl fmt::Display for CreateFileSystemError {
     }
 }
 impl Error for CreateFileSystemError {}
+impl ServiceErrorCode for CreateFileSystemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFileSystemError::BadRequest(_) => "BadRequest",
+            CreateFileSystemError::FileSystemAlreadyExists(_) => "FileSystemAlreadyExists",
+            CreateFileSystemError::FileSystemLimitExceeded(_) => "FileSystemLimitExceeded",
+            CreateFileSystemError::InsufficientThroughputCapacity(_) => {
+                "InsufficientThroughputCapacity"
+            }
+            CreateFileSystemError::InternalServerError(_) => "InternalServerError",
+            CreateFileSystemError::ThroughputLimitExceeded(_) => "ThroughputLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateMountTarget
 #[derive(Debug, PartialEq)]
 pub enum CreateMountTargetError {
@@ -998,6 +1028,28 @@ impl fmt::Display for CreateMountTargetError {
     }
 }
 impl Error for CreateMountTargetError {}
+impl ServiceErrorCode for CreateMountTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMountTargetError::BadRequest(_) => "BadRequest",
+            CreateMountTargetError::FileSystemNotFound(_) => "FileSystemNotFound",
+            CreateMountTargetError::IncorrectFileSystemLifeCycleState(_) => {
+                "IncorrectFileSystemLifeCycleState"
+            }
+            CreateMountTargetError::InternalServerError(_) => "InternalServerError",
+            CreateMountTargetError::IpAddressInUse(_) => "IpAddressInUse",
+            CreateMountTargetError::MountTargetConflict(_) => "MountTargetConflict",
+            CreateMountTargetError::NetworkInterfaceLimitExceeded(_) => {
+                "NetworkInterfaceLimitExceeded"
+            }
+            CreateMountTargetError::NoFreeAddressesInSubnet(_) => "NoFreeAddressesInSubnet",
+            CreateMountTargetError::SecurityGroupLimitExceeded(_) => "SecurityGroupLimitExceeded",
+            CreateMountTargetError::SecurityGroupNotFound(_) => "SecurityGroupNotFound",
+            CreateMountTargetError::SubnetNotFound(_) => "SubnetNotFound",
+            CreateMountTargetError::UnsupportedAvailabilityZone(_) => "UnsupportedAvailabilityZone",
+        }
+    }
+}
 /// Errors returned by CreateTags
 #[derive(Debug, PartialEq)]
 pub enum CreateTagsError {
@@ -1038,6 +1090,15 @@ impl fmt::Display for CreateTagsError {
     }
 }
 impl Error for CreateTagsError {}
+impl ServiceErrorCode for CreateTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTagsError::BadRequest(_) => "BadRequest",
+            CreateTagsError::FileSystemNotFound(_) => "FileSystemNotFound",
+            CreateTagsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DeleteAccessPoint
 #[derive(Debug, PartialEq)]
 pub enum DeleteAccessPointError {
@@ -1084,6 +1145,15 @@ impl fmt::Display for DeleteAccessPointError {
     }
 }
 impl Error for DeleteAccessPointError {}
+impl ServiceErrorCode for DeleteAccessPointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAccessPointError::AccessPointNotFound(_) => "AccessPointNotFound",
+            DeleteAccessPointError::BadRequest(_) => "BadRequest",
+            DeleteAccessPointError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DeleteFileSystem
 #[derive(Debug, PartialEq)]
 pub enum DeleteFileSystemError {
@@ -1134,6 +1204,16 @@ impl fmt::Display for DeleteFileSystemError {
     }
 }
 impl Error for DeleteFileSystemError {}
+impl ServiceErrorCode for DeleteFileSystemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFileSystemError::BadRequest(_) => "BadRequest",
+            DeleteFileSystemError::FileSystemInUse(_) => "FileSystemInUse",
+            DeleteFileSystemError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DeleteFileSystemError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DeleteFileSystemPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteFileSystemPolicyError {
@@ -1184,6 +1264,17 @@ impl fmt::Display for DeleteFileSystemPolicyError {
     }
 }
 impl Error for DeleteFileSystemPolicyError {}
+impl ServiceErrorCode for DeleteFileSystemPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFileSystemPolicyError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DeleteFileSystemPolicyError::IncorrectFileSystemLifeCycleState(_) => {
+                "IncorrectFileSystemLifeCycleState"
+            }
+            DeleteFileSystemPolicyError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DeleteMountTarget
 #[derive(Debug, PartialEq)]
 pub enum DeleteMountTargetError {
@@ -1236,6 +1327,16 @@ impl fmt::Display for DeleteMountTargetError {
     }
 }
 impl Error for DeleteMountTargetError {}
+impl ServiceErrorCode for DeleteMountTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMountTargetError::BadRequest(_) => "BadRequest",
+            DeleteMountTargetError::DependencyTimeout(_) => "DependencyTimeout",
+            DeleteMountTargetError::InternalServerError(_) => "InternalServerError",
+            DeleteMountTargetError::MountTargetNotFound(_) => "MountTargetNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
 pub enum DeleteTagsError {
@@ -1276,6 +1377,15 @@ impl fmt::Display for DeleteTagsError {
     }
 }
 impl Error for DeleteTagsError {}
+impl ServiceErrorCode for DeleteTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTagsError::BadRequest(_) => "BadRequest",
+            DeleteTagsError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DeleteTagsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeAccessPoints
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccessPointsError {
@@ -1330,6 +1440,16 @@ impl fmt::Display for DescribeAccessPointsError {
     }
 }
 impl Error for DescribeAccessPointsError {}
+impl ServiceErrorCode for DescribeAccessPointsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAccessPointsError::AccessPointNotFound(_) => "AccessPointNotFound",
+            DescribeAccessPointsError::BadRequest(_) => "BadRequest",
+            DescribeAccessPointsError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DescribeAccessPointsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeBackupPolicy
 #[derive(Debug, PartialEq)]
 pub enum DescribeBackupPolicyError {
@@ -1382,6 +1502,16 @@ impl fmt::Display for DescribeBackupPolicyError {
     }
 }
 impl Error for DescribeBackupPolicyError {}
+impl ServiceErrorCode for DescribeBackupPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBackupPolicyError::BadRequest(_) => "BadRequest",
+            DescribeBackupPolicyError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DescribeBackupPolicyError::InternalServerError(_) => "InternalServerError",
+            DescribeBackupPolicyError::PolicyNotFound(_) => "PolicyNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeFileSystemPolicy
 #[derive(Debug, PartialEq)]
 pub enum DescribeFileSystemPolicyError {
@@ -1430,6 +1560,15 @@ impl fmt::Display for DescribeFileSystemPolicyError {
     }
 }
 impl Error for DescribeFileSystemPolicyError {}
+impl ServiceErrorCode for DescribeFileSystemPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFileSystemPolicyError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DescribeFileSystemPolicyError::InternalServerError(_) => "InternalServerError",
+            DescribeFileSystemPolicyError::PolicyNotFound(_) => "PolicyNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeFileSystems
 #[derive(Debug, PartialEq)]
 pub enum DescribeFileSystemsError {
@@ -1476,6 +1615,15 @@ impl fmt::Display for DescribeFileSystemsError {
     }
 }
 impl Error for DescribeFileSystemsError {}
+impl ServiceErrorCode for DescribeFileSystemsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFileSystemsError::BadRequest(_) => "BadRequest",
+            DescribeFileSystemsError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DescribeFileSystemsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeLifecycleConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribeLifecycleConfigurationError {
@@ -1530,6 +1678,15 @@ impl fmt::Display for DescribeLifecycleConfigurationError {
     }
 }
 impl Error for DescribeLifecycleConfigurationError {}
+impl ServiceErrorCode for DescribeLifecycleConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLifecycleConfigurationError::BadRequest(_) => "BadRequest",
+            DescribeLifecycleConfigurationError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DescribeLifecycleConfigurationError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeMountTargetSecurityGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeMountTargetSecurityGroupsError {
@@ -1594,6 +1751,18 @@ impl fmt::Display for DescribeMountTargetSecurityGroupsError {
     }
 }
 impl Error for DescribeMountTargetSecurityGroupsError {}
+impl ServiceErrorCode for DescribeMountTargetSecurityGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMountTargetSecurityGroupsError::BadRequest(_) => "BadRequest",
+            DescribeMountTargetSecurityGroupsError::IncorrectMountTargetState(_) => {
+                "IncorrectMountTargetState"
+            }
+            DescribeMountTargetSecurityGroupsError::InternalServerError(_) => "InternalServerError",
+            DescribeMountTargetSecurityGroupsError::MountTargetNotFound(_) => "MountTargetNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeMountTargets
 #[derive(Debug, PartialEq)]
 pub enum DescribeMountTargetsError {
@@ -1656,6 +1825,17 @@ impl fmt::Display for DescribeMountTargetsError {
     }
 }
 impl Error for DescribeMountTargetsError {}
+impl ServiceErrorCode for DescribeMountTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMountTargetsError::AccessPointNotFound(_) => "AccessPointNotFound",
+            DescribeMountTargetsError::BadRequest(_) => "BadRequest",
+            DescribeMountTargetsError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DescribeMountTargetsError::InternalServerError(_) => "InternalServerError",
+            DescribeMountTargetsError::MountTargetNotFound(_) => "MountTargetNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
 pub enum DescribeTagsError {
@@ -1698,6 +1878,15 @@ impl fmt::Display for DescribeTagsError {
     }
 }
 impl Error for DescribeTagsError {}
+impl ServiceErrorCode for DescribeTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTagsError::BadRequest(_) => "BadRequest",
+            DescribeTagsError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DescribeTagsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1752,6 +1941,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AccessPointNotFound(_) => "AccessPointNotFound",
+            ListTagsForResourceError::BadRequest(_) => "BadRequest",
+            ListTagsForResourceError::FileSystemNotFound(_) => "FileSystemNotFound",
+            ListTagsForResourceError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by ModifyMountTargetSecurityGroups
 #[derive(Debug, PartialEq)]
 pub enum ModifyMountTargetSecurityGroupsError {
@@ -1836,6 +2035,24 @@ impl fmt::Display for ModifyMountTargetSecurityGroupsError {
     }
 }
 impl Error for ModifyMountTargetSecurityGroupsError {}
+impl ServiceErrorCode for ModifyMountTargetSecurityGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyMountTargetSecurityGroupsError::BadRequest(_) => "BadRequest",
+            ModifyMountTargetSecurityGroupsError::IncorrectMountTargetState(_) => {
+                "IncorrectMountTargetState"
+            }
+            ModifyMountTargetSecurityGroupsError::InternalServerError(_) => "InternalServerError",
+            ModifyMountTargetSecurityGroupsError::MountTargetNotFound(_) => "MountTargetNotFound",
+            ModifyMountTargetSecurityGroupsError::SecurityGroupLimitExceeded(_) => {
+                "SecurityGroupLimitExceeded"
+            }
+            ModifyMountTargetSecurityGroupsError::SecurityGroupNotFound(_) => {
+                "SecurityGroupNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by PutBackupPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutBackupPolicyError {
@@ -1888,6 +2105,18 @@ impl fmt::Display for PutBackupPolicyError {
     }
 }
 impl Error for PutBackupPolicyError {}
+impl ServiceErrorCode for PutBackupPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutBackupPolicyError::BadRequest(_) => "BadRequest",
+            PutBackupPolicyError::FileSystemNotFound(_) => "FileSystemNotFound",
+            PutBackupPolicyError::IncorrectFileSystemLifeCycleState(_) => {
+                "IncorrectFileSystemLifeCycleState"
+            }
+            PutBackupPolicyError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by PutFileSystemPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutFileSystemPolicyError {
@@ -1944,6 +2173,18 @@ impl fmt::Display for PutFileSystemPolicyError {
     }
 }
 impl Error for PutFileSystemPolicyError {}
+impl ServiceErrorCode for PutFileSystemPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutFileSystemPolicyError::FileSystemNotFound(_) => "FileSystemNotFound",
+            PutFileSystemPolicyError::IncorrectFileSystemLifeCycleState(_) => {
+                "IncorrectFileSystemLifeCycleState"
+            }
+            PutFileSystemPolicyError::InternalServerError(_) => "InternalServerError",
+            PutFileSystemPolicyError::InvalidPolicy(_) => "InvalidPolicyException",
+        }
+    }
+}
 /// Errors returned by PutLifecycleConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutLifecycleConfigurationError {
@@ -2004,6 +2245,18 @@ impl fmt::Display for PutLifecycleConfigurationError {
     }
 }
 impl Error for PutLifecycleConfigurationError {}
+impl ServiceErrorCode for PutLifecycleConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutLifecycleConfigurationError::BadRequest(_) => "BadRequest",
+            PutLifecycleConfigurationError::FileSystemNotFound(_) => "FileSystemNotFound",
+            PutLifecycleConfigurationError::IncorrectFileSystemLifeCycleState(_) => {
+                "IncorrectFileSystemLifeCycleState"
+            }
+            PutLifecycleConfigurationError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2050,6 +2303,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AccessPointNotFound(_) => "AccessPointNotFound",
+            TagResourceError::BadRequest(_) => "BadRequest",
+            TagResourceError::FileSystemNotFound(_) => "FileSystemNotFound",
+            TagResourceError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2098,6 +2361,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AccessPointNotFound(_) => "AccessPointNotFound",
+            UntagResourceError::BadRequest(_) => "BadRequest",
+            UntagResourceError::FileSystemNotFound(_) => "FileSystemNotFound",
+            UntagResourceError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by UpdateFileSystem
 #[derive(Debug, PartialEq)]
 pub enum UpdateFileSystemError {
@@ -2176,6 +2449,23 @@ impl fmt::Display for UpdateFileSystemError {
     }
 }
 impl Error for UpdateFileSystemError {}
+impl ServiceErrorCode for UpdateFileSystemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFileSystemError::BadRequest(_) => "BadRequest",
+            UpdateFileSystemError::FileSystemNotFound(_) => "FileSystemNotFound",
+            UpdateFileSystemError::IncorrectFileSystemLifeCycleState(_) => {
+                "IncorrectFileSystemLifeCycleState"
+            }
+            UpdateFileSystemError::InsufficientThroughputCapacity(_) => {
+                "InsufficientThroughputCapacity"
+            }
+            UpdateFileSystemError::InternalServerError(_) => "InternalServerError",
+            UpdateFileSystemError::ThroughputLimitExceeded(_) => "ThroughputLimitExceeded",
+            UpdateFileSystemError::TooManyRequests(_) => "TooManyRequests",
+        }
+    }
+}
 /// Trait representing the capabilities of the EFS API. EFS clients implement this trait.
 #[async_trait]
 pub trait Efs {
@@ -2378,6 +2668,12 @@ impl Efs for EfsClient {
         &self,
         input: CreateAccessPointRequest,
     ) -> Result<AccessPointDescription, RusotoError<CreateAccessPointError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/2015-02-01/access-points";
 
         let mut request =
@@ -2410,6 +2706,12 @@ impl Efs for EfsClient {
         &self,
         input: CreateFileSystemRequest,
     ) -> Result<FileSystemDescription, RusotoError<CreateFileSystemError>> {
+        let mut input = input;
+        if input.creation_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.creation_token = token;
+            }
+        }
         let request_uri = "/2015-02-01/file-systems";
 
         let mut request =
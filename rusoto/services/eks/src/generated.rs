@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -254,6 +254,7 @@ pub struct CreateAddonRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub addon_version: Option<String>,
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -286,6 +287,7 @@ pub struct CreateAddonResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateClusterRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -333,6 +335,7 @@ pub struct CreateClusterResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateFargateProfileRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -380,6 +383,7 @@ pub struct CreateNodegroupRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub capacity_type: Option<String>,
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -1230,6 +1234,7 @@ pub struct UpdateAddonRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub addon_version: Option<String>,
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -1258,6 +1263,7 @@ pub struct UpdateAddonResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateClusterConfigRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -1285,6 +1291,7 @@ pub struct UpdateClusterConfigResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateClusterVersionRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -1323,6 +1330,7 @@ pub struct UpdateLabelsPayload {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateNodegroupConfigRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -1354,6 +1362,7 @@ pub struct UpdateNodegroupConfigResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateNodegroupVersionRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -1523,6 +1532,18 @@ impl fmt::Display for CreateAddonError {
     }
 }
 impl Error for CreateAddonError {}
+impl ServiceErrorCode for CreateAddonError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAddonError::Client(_) => "ClientException",
+            CreateAddonError::InvalidParameter(_) => "InvalidParameterException",
+            CreateAddonError::InvalidRequest(_) => "InvalidRequestException",
+            CreateAddonError::ResourceInUse(_) => "ResourceInUseException",
+            CreateAddonError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateAddonError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by CreateCluster
 #[derive(Debug, PartialEq)]
 pub enum CreateClusterError {
@@ -1591,6 +1612,21 @@ impl fmt::Display for CreateClusterError {
     }
 }
 impl Error for CreateClusterError {}
+impl ServiceErrorCode for CreateClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateClusterError::Client(_) => "ClientException",
+            CreateClusterError::InvalidParameter(_) => "InvalidParameterException",
+            CreateClusterError::ResourceInUse(_) => "ResourceInUseException",
+            CreateClusterError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateClusterError::Server(_) => "ServerException",
+            CreateClusterError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateClusterError::UnsupportedAvailabilityZone(_) => {
+                "UnsupportedAvailabilityZoneException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateFargateProfile
 #[derive(Debug, PartialEq)]
 pub enum CreateFargateProfileError {
@@ -1659,6 +1695,20 @@ impl fmt::Display for CreateFargateProfileError {
     }
 }
 impl Error for CreateFargateProfileError {}
+impl ServiceErrorCode for CreateFargateProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFargateProfileError::Client(_) => "ClientException",
+            CreateFargateProfileError::InvalidParameter(_) => "InvalidParameterException",
+            CreateFargateProfileError::InvalidRequest(_) => "InvalidRequestException",
+            CreateFargateProfileError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateFargateProfileError::Server(_) => "ServerException",
+            CreateFargateProfileError::UnsupportedAvailabilityZone(_) => {
+                "UnsupportedAvailabilityZoneException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateNodegroup
 #[derive(Debug, PartialEq)]
 pub enum CreateNodegroupError {
@@ -1727,6 +1777,19 @@ impl fmt::Display for CreateNodegroupError {
     }
 }
 impl Error for CreateNodegroupError {}
+impl ServiceErrorCode for CreateNodegroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateNodegroupError::Client(_) => "ClientException",
+            CreateNodegroupError::InvalidParameter(_) => "InvalidParameterException",
+            CreateNodegroupError::InvalidRequest(_) => "InvalidRequestException",
+            CreateNodegroupError::ResourceInUse(_) => "ResourceInUseException",
+            CreateNodegroupError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateNodegroupError::Server(_) => "ServerException",
+            CreateNodegroupError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteAddon
 #[derive(Debug, PartialEq)]
 pub enum DeleteAddonError {
@@ -1781,6 +1844,17 @@ impl fmt::Display for DeleteAddonError {
     }
 }
 impl Error for DeleteAddonError {}
+impl ServiceErrorCode for DeleteAddonError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAddonError::Client(_) => "ClientException",
+            DeleteAddonError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteAddonError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteAddonError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteAddonError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DeleteCluster
 #[derive(Debug, PartialEq)]
 pub enum DeleteClusterError {
@@ -1835,6 +1909,17 @@ impl fmt::Display for DeleteClusterError {
     }
 }
 impl Error for DeleteClusterError {}
+impl ServiceErrorCode for DeleteClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteClusterError::Client(_) => "ClientException",
+            DeleteClusterError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteClusterError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteClusterError::Server(_) => "ServerException",
+            DeleteClusterError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteFargateProfile
 #[derive(Debug, PartialEq)]
 pub enum DeleteFargateProfileError {
@@ -1887,6 +1972,16 @@ impl fmt::Display for DeleteFargateProfileError {
     }
 }
 impl Error for DeleteFargateProfileError {}
+impl ServiceErrorCode for DeleteFargateProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFargateProfileError::Client(_) => "ClientException",
+            DeleteFargateProfileError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteFargateProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteFargateProfileError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DeleteNodegroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteNodegroupError {
@@ -1947,6 +2042,18 @@ impl fmt::Display for DeleteNodegroupError {
     }
 }
 impl Error for DeleteNodegroupError {}
+impl ServiceErrorCode for DeleteNodegroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteNodegroupError::Client(_) => "ClientException",
+            DeleteNodegroupError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteNodegroupError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteNodegroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteNodegroupError::Server(_) => "ServerException",
+            DeleteNodegroupError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeAddon
 #[derive(Debug, PartialEq)]
 pub enum DescribeAddonError {
@@ -2001,6 +2108,17 @@ impl fmt::Display for DescribeAddonError {
     }
 }
 impl Error for DescribeAddonError {}
+impl ServiceErrorCode for DescribeAddonError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAddonError::Client(_) => "ClientException",
+            DescribeAddonError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeAddonError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeAddonError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeAddonError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeAddonVersions
 #[derive(Debug, PartialEq)]
 pub enum DescribeAddonVersionsError {
@@ -2047,6 +2165,15 @@ impl fmt::Display for DescribeAddonVersionsError {
     }
 }
 impl Error for DescribeAddonVersionsError {}
+impl ServiceErrorCode for DescribeAddonVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAddonVersionsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeAddonVersionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeAddonVersionsError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeCluster
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterError {
@@ -2095,6 +2222,16 @@ impl fmt::Display for DescribeClusterError {
     }
 }
 impl Error for DescribeClusterError {}
+impl ServiceErrorCode for DescribeClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClusterError::Client(_) => "ClientException",
+            DescribeClusterError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeClusterError::Server(_) => "ServerException",
+            DescribeClusterError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeFargateProfile
 #[derive(Debug, PartialEq)]
 pub enum DescribeFargateProfileError {
@@ -2147,6 +2284,16 @@ impl fmt::Display for DescribeFargateProfileError {
     }
 }
 impl Error for DescribeFargateProfileError {}
+impl ServiceErrorCode for DescribeFargateProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFargateProfileError::Client(_) => "ClientException",
+            DescribeFargateProfileError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeFargateProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeFargateProfileError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by DescribeNodegroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeNodegroupError {
@@ -2203,6 +2350,17 @@ impl fmt::Display for DescribeNodegroupError {
     }
 }
 impl Error for DescribeNodegroupError {}
+impl ServiceErrorCode for DescribeNodegroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeNodegroupError::Client(_) => "ClientException",
+            DescribeNodegroupError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeNodegroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeNodegroupError::Server(_) => "ServerException",
+            DescribeNodegroupError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeUpdate
 #[derive(Debug, PartialEq)]
 pub enum DescribeUpdateError {
@@ -2251,6 +2409,16 @@ impl fmt::Display for DescribeUpdateError {
     }
 }
 impl Error for DescribeUpdateError {}
+impl ServiceErrorCode for DescribeUpdateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUpdateError::Client(_) => "ClientException",
+            DescribeUpdateError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeUpdateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeUpdateError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by ListAddons
 #[derive(Debug, PartialEq)]
 pub enum ListAddonsError {
@@ -2301,6 +2469,17 @@ impl fmt::Display for ListAddonsError {
     }
 }
 impl Error for ListAddonsError {}
+impl ServiceErrorCode for ListAddonsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAddonsError::Client(_) => "ClientException",
+            ListAddonsError::InvalidParameter(_) => "InvalidParameterException",
+            ListAddonsError::InvalidRequest(_) => "InvalidRequestException",
+            ListAddonsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListAddonsError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by ListClusters
 #[derive(Debug, PartialEq)]
 pub enum ListClustersError {
@@ -2349,6 +2528,16 @@ impl fmt::Display for ListClustersError {
     }
 }
 impl Error for ListClustersError {}
+impl ServiceErrorCode for ListClustersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListClustersError::Client(_) => "ClientException",
+            ListClustersError::InvalidParameter(_) => "InvalidParameterException",
+            ListClustersError::Server(_) => "ServerException",
+            ListClustersError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListFargateProfiles
 #[derive(Debug, PartialEq)]
 pub enum ListFargateProfilesError {
@@ -2401,6 +2590,16 @@ impl fmt::Display for ListFargateProfilesError {
     }
 }
 impl Error for ListFargateProfilesError {}
+impl ServiceErrorCode for ListFargateProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFargateProfilesError::Client(_) => "ClientException",
+            ListFargateProfilesError::InvalidParameter(_) => "InvalidParameterException",
+            ListFargateProfilesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListFargateProfilesError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by ListNodegroups
 #[derive(Debug, PartialEq)]
 pub enum ListNodegroupsError {
@@ -2455,6 +2654,17 @@ impl fmt::Display for ListNodegroupsError {
     }
 }
 impl Error for ListNodegroupsError {}
+impl ServiceErrorCode for ListNodegroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListNodegroupsError::Client(_) => "ClientException",
+            ListNodegroupsError::InvalidParameter(_) => "InvalidParameterException",
+            ListNodegroupsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListNodegroupsError::Server(_) => "ServerException",
+            ListNodegroupsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2491,6 +2701,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListUpdates
 #[derive(Debug, PartialEq)]
 pub enum ListUpdatesError {
@@ -2539,6 +2757,16 @@ impl fmt::Display for ListUpdatesError {
     }
 }
 impl Error for ListUpdatesError {}
+impl ServiceErrorCode for ListUpdatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUpdatesError::Client(_) => "ClientException",
+            ListUpdatesError::InvalidParameter(_) => "InvalidParameterException",
+            ListUpdatesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListUpdatesError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2575,6 +2803,14 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2611,6 +2847,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateAddon
 #[derive(Debug, PartialEq)]
 pub enum UpdateAddonError {
@@ -2671,6 +2915,18 @@ impl fmt::Display for UpdateAddonError {
     }
 }
 impl Error for UpdateAddonError {}
+impl ServiceErrorCode for UpdateAddonError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAddonError::Client(_) => "ClientException",
+            UpdateAddonError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateAddonError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateAddonError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateAddonError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateAddonError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by UpdateClusterConfig
 #[derive(Debug, PartialEq)]
 pub enum UpdateClusterConfigError {
@@ -2735,6 +2991,18 @@ impl fmt::Display for UpdateClusterConfigError {
     }
 }
 impl Error for UpdateClusterConfigError {}
+impl ServiceErrorCode for UpdateClusterConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateClusterConfigError::Client(_) => "ClientException",
+            UpdateClusterConfigError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateClusterConfigError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateClusterConfigError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateClusterConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateClusterConfigError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by UpdateClusterVersion
 #[derive(Debug, PartialEq)]
 pub enum UpdateClusterVersionError {
@@ -2799,6 +3067,18 @@ impl fmt::Display for UpdateClusterVersionError {
     }
 }
 impl Error for UpdateClusterVersionError {}
+impl ServiceErrorCode for UpdateClusterVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateClusterVersionError::Client(_) => "ClientException",
+            UpdateClusterVersionError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateClusterVersionError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateClusterVersionError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateClusterVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateClusterVersionError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by UpdateNodegroupConfig
 #[derive(Debug, PartialEq)]
 pub enum UpdateNodegroupConfigError {
@@ -2865,6 +3145,18 @@ impl fmt::Display for UpdateNodegroupConfigError {
     }
 }
 impl Error for UpdateNodegroupConfigError {}
+impl ServiceErrorCode for UpdateNodegroupConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateNodegroupConfigError::Client(_) => "ClientException",
+            UpdateNodegroupConfigError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateNodegroupConfigError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateNodegroupConfigError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateNodegroupConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateNodegroupConfigError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Errors returned by UpdateNodegroupVersion
 #[derive(Debug, PartialEq)]
 pub enum UpdateNodegroupVersionError {
@@ -2933,6 +3225,18 @@ impl fmt::Display for UpdateNodegroupVersionError {
     }
 }
 impl Error for UpdateNodegroupVersionError {}
+impl ServiceErrorCode for UpdateNodegroupVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateNodegroupVersionError::Client(_) => "ClientException",
+            UpdateNodegroupVersionError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateNodegroupVersionError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateNodegroupVersionError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateNodegroupVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateNodegroupVersionError::Server(_) => "ServerException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon EKS API. Amazon EKS clients implement this trait.
 #[async_trait]
 pub trait Eks {
@@ -3144,6 +3448,10 @@ impl Eks for EksClient {
         &self,
         input: CreateAddonRequest,
     ) -> Result<CreateAddonResponse, RusotoError<CreateAddonError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = format!("/clusters/{name}/addons", name = input.cluster_name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
@@ -3175,6 +3483,10 @@ impl Eks for EksClient {
         &self,
         input: CreateClusterRequest,
     ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = "/clusters";
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
@@ -3206,6 +3518,10 @@ impl Eks for EksClient {
         &self,
         input: CreateFargateProfileRequest,
     ) -> Result<CreateFargateProfileResponse, RusotoError<CreateFargateProfileError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/clusters/{name}/fargate-profiles",
             name = input.cluster_name
@@ -3240,6 +3556,10 @@ impl Eks for EksClient {
         &self,
         input: CreateNodegroupRequest,
     ) -> Result<CreateNodegroupResponse, RusotoError<CreateNodegroupError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = format!("/clusters/{name}/node-groups", name = input.cluster_name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
@@ -3890,6 +4210,10 @@ impl Eks for EksClient {
         &self,
         input: UpdateAddonRequest,
     ) -> Result<UpdateAddonResponse, RusotoError<UpdateAddonError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/clusters/{name}/addons/{addon_name}/update",
             addon_name = input.addon_name,
@@ -3925,6 +4249,10 @@ impl Eks for EksClient {
         &self,
         input: UpdateClusterConfigRequest,
     ) -> Result<UpdateClusterConfigResponse, RusotoError<UpdateClusterConfigError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = format!("/clusters/{name}/update-config", name = input.name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
@@ -3956,6 +4284,10 @@ impl Eks for EksClient {
         &self,
         input: UpdateClusterVersionRequest,
     ) -> Result<UpdateClusterVersionResponse, RusotoError<UpdateClusterVersionError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = format!("/clusters/{name}/updates", name = input.name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
@@ -3987,6 +4319,10 @@ impl Eks for EksClient {
         &self,
         input: UpdateNodegroupConfigRequest,
     ) -> Result<UpdateNodegroupConfigResponse, RusotoError<UpdateNodegroupConfigError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/clusters/{name}/node-groups/{nodegroup_name}/update-config",
             name = input.cluster_name,
@@ -4022,6 +4358,10 @@ impl Eks for EksClient {
         &self,
         input: UpdateNodegroupVersionRequest,
     ) -> Result<UpdateNodegroupVersionResponse, RusotoError<UpdateNodegroupVersionError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/clusters/{name}/node-groups/{nodegroup_name}/update-version",
             name = input.cluster_name,
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -302,6 +302,15 @@ impl fmt::Display for DescribeAcceleratorOfferingsError {
     }
 }
 impl Error for DescribeAcceleratorOfferingsError {}
+impl ServiceErrorCode for DescribeAcceleratorOfferingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAcceleratorOfferingsError::BadRequest(_) => "BadRequestException",
+            DescribeAcceleratorOfferingsError::InternalServer(_) => "InternalServerException",
+            DescribeAcceleratorOfferingsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeAcceleratorTypes
 #[derive(Debug, PartialEq)]
 pub enum DescribeAcceleratorTypesError {
@@ -334,6 +343,13 @@ impl fmt::Display for DescribeAcceleratorTypesError {
     }
 }
 impl Error for DescribeAcceleratorTypesError {}
+impl ServiceErrorCode for DescribeAcceleratorTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAcceleratorTypesError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by DescribeAccelerators
 #[derive(Debug, PartialEq)]
 pub enum DescribeAcceleratorsError {
@@ -378,6 +394,15 @@ impl fmt::Display for DescribeAcceleratorsError {
     }
 }
 impl Error for DescribeAcceleratorsError {}
+impl ServiceErrorCode for DescribeAcceleratorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAcceleratorsError::BadRequest(_) => "BadRequestException",
+            DescribeAcceleratorsError::InternalServer(_) => "InternalServerException",
+            DescribeAcceleratorsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -422,6 +447,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::InternalServer(_) => "InternalServerException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -464,6 +498,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::InternalServer(_) => "InternalServerException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -506,6 +549,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::InternalServer(_) => "InternalServerException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Elastic Inference API. Amazon Elastic Inference clients implement this trait.
 #[async_trait]
 pub trait ElasticInference {
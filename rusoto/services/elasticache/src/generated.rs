@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -10044,6 +10044,16 @@ impl fmt::Display for AddTagsToResourceError {
     }
 }
 impl Error for AddTagsToResourceError {}
+impl ServiceErrorCode for AddTagsToResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsToResourceError::CacheClusterNotFoundFault(_) => "CacheClusterNotFound",
+            AddTagsToResourceError::InvalidARNFault(_) => "InvalidARN",
+            AddTagsToResourceError::SnapshotNotFoundFault(_) => "SnapshotNotFoundFault",
+            AddTagsToResourceError::TagQuotaPerResourceExceeded(_) => "TagQuotaPerResourceExceeded",
+        }
+    }
+}
 /// Errors returned by AuthorizeCacheSecurityGroupIngress
 #[derive(Debug, PartialEq)]
 pub enum AuthorizeCacheSecurityGroupIngressError {
@@ -10107,6 +10117,27 @@ impl fmt::Display for AuthorizeCacheSecurityGroupIngressError {
     }
 }
 impl Error for AuthorizeCacheSecurityGroupIngressError {}
+impl ServiceErrorCode for AuthorizeCacheSecurityGroupIngressError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AuthorizeCacheSecurityGroupIngressError::AuthorizationAlreadyExistsFault(_) => {
+                "AuthorizationAlreadyExists"
+            }
+            AuthorizeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault(_) => {
+                "CacheSecurityGroupNotFound"
+            }
+            AuthorizeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault(_) => {
+                "InvalidCacheSecurityGroupState"
+            }
+            AuthorizeCacheSecurityGroupIngressError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            AuthorizeCacheSecurityGroupIngressError::InvalidParameterValue(_) => {
+                "InvalidParameterValue"
+            }
+        }
+    }
+}
 /// Errors returned by BatchApplyUpdateAction
 #[derive(Debug, PartialEq)]
 pub enum BatchApplyUpdateActionError {
@@ -10165,6 +10196,16 @@ impl fmt::Display for BatchApplyUpdateActionError {
     }
 }
 impl Error for BatchApplyUpdateActionError {}
+impl ServiceErrorCode for BatchApplyUpdateActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchApplyUpdateActionError::InvalidParameterValue(_) => "InvalidParameterValue",
+            BatchApplyUpdateActionError::ServiceUpdateNotFoundFault(_) => {
+                "ServiceUpdateNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by BatchStopUpdateAction
 #[derive(Debug, PartialEq)]
 pub enum BatchStopUpdateActionError {
@@ -10221,6 +10262,16 @@ impl fmt::Display for BatchStopUpdateActionError {
     }
 }
 impl Error for BatchStopUpdateActionError {}
+impl ServiceErrorCode for BatchStopUpdateActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchStopUpdateActionError::InvalidParameterValue(_) => "InvalidParameterValue",
+            BatchStopUpdateActionError::ServiceUpdateNotFoundFault(_) => {
+                "ServiceUpdateNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by CompleteMigration
 #[derive(Debug, PartialEq)]
 pub enum CompleteMigrationError {
@@ -10293,6 +10344,21 @@ impl fmt::Display for CompleteMigrationError {
     }
 }
 impl Error for CompleteMigrationError {}
+impl ServiceErrorCode for CompleteMigrationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CompleteMigrationError::InvalidReplicationGroupStateFault(_) => {
+                "InvalidReplicationGroupState"
+            }
+            CompleteMigrationError::ReplicationGroupNotFoundFault(_) => {
+                "ReplicationGroupNotFoundFault"
+            }
+            CompleteMigrationError::ReplicationGroupNotUnderMigrationFault(_) => {
+                "ReplicationGroupNotUnderMigrationFault"
+            }
+        }
+    }
+}
 /// Errors returned by CopySnapshot
 #[derive(Debug, PartialEq)]
 pub enum CopySnapshotError {
@@ -10377,6 +10443,18 @@ impl fmt::Display for CopySnapshotError {
     }
 }
 impl Error for CopySnapshotError {}
+impl ServiceErrorCode for CopySnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopySnapshotError::InvalidParameterCombination(_) => "InvalidParameterCombination",
+            CopySnapshotError::InvalidParameterValue(_) => "InvalidParameterValue",
+            CopySnapshotError::InvalidSnapshotStateFault(_) => "InvalidSnapshotState",
+            CopySnapshotError::SnapshotAlreadyExistsFault(_) => "SnapshotAlreadyExistsFault",
+            CopySnapshotError::SnapshotNotFoundFault(_) => "SnapshotNotFoundFault",
+            CopySnapshotError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceededFault",
+        }
+    }
+}
 /// Errors returned by CreateCacheCluster
 #[derive(Debug, PartialEq)]
 pub enum CreateCacheClusterError {
@@ -10577,6 +10655,52 @@ impl fmt::Display for CreateCacheClusterError {
     }
 }
 impl Error for CreateCacheClusterError {}
+impl ServiceErrorCode for CreateCacheClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCacheClusterError::CacheClusterAlreadyExistsFault(_) => {
+                "CacheClusterAlreadyExists"
+            }
+            CreateCacheClusterError::CacheParameterGroupNotFoundFault(_) => {
+                "CacheParameterGroupNotFound"
+            }
+            CreateCacheClusterError::CacheSecurityGroupNotFoundFault(_) => {
+                "CacheSecurityGroupNotFound"
+            }
+            CreateCacheClusterError::CacheSubnetGroupNotFoundFault(_) => {
+                "CacheSubnetGroupNotFoundFault"
+            }
+            CreateCacheClusterError::ClusterQuotaForCustomerExceededFault(_) => {
+                "ClusterQuotaForCustomerExceeded"
+            }
+            CreateCacheClusterError::InsufficientCacheClusterCapacityFault(_) => {
+                "InsufficientCacheClusterCapacity"
+            }
+            CreateCacheClusterError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            CreateCacheClusterError::InvalidParameterValue(_) => "InvalidParameterValue",
+            CreateCacheClusterError::InvalidReplicationGroupStateFault(_) => {
+                "InvalidReplicationGroupState"
+            }
+            CreateCacheClusterError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            CreateCacheClusterError::NodeQuotaForClusterExceededFault(_) => {
+                "NodeQuotaForClusterExceeded"
+            }
+            CreateCacheClusterError::NodeQuotaForCustomerExceededFault(_) => {
+                "NodeQuotaForCustomerExceeded"
+            }
+            CreateCacheClusterError::ReplicationGroupNotFoundFault(_) => {
+                "ReplicationGroupNotFoundFault"
+            }
+            CreateCacheClusterError::TagQuotaPerResourceExceeded(_) => {
+                "TagQuotaPerResourceExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CreateCacheParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateCacheParameterGroupError {
@@ -10673,6 +10797,25 @@ impl fmt::Display for CreateCacheParameterGroupError {
     }
 }
 impl Error for CreateCacheParameterGroupError {}
+impl ServiceErrorCode for CreateCacheParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCacheParameterGroupError::CacheParameterGroupAlreadyExistsFault(_) => {
+                "CacheParameterGroupAlreadyExists"
+            }
+            CreateCacheParameterGroupError::CacheParameterGroupQuotaExceededFault(_) => {
+                "CacheParameterGroupQuotaExceeded"
+            }
+            CreateCacheParameterGroupError::InvalidCacheParameterGroupStateFault(_) => {
+                "InvalidCacheParameterGroupState"
+            }
+            CreateCacheParameterGroupError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            CreateCacheParameterGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by CreateCacheSecurityGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateCacheSecurityGroupError {
@@ -10757,6 +10900,22 @@ impl fmt::Display for CreateCacheSecurityGroupError {
     }
 }
 impl Error for CreateCacheSecurityGroupError {}
+impl ServiceErrorCode for CreateCacheSecurityGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCacheSecurityGroupError::CacheSecurityGroupAlreadyExistsFault(_) => {
+                "CacheSecurityGroupAlreadyExists"
+            }
+            CreateCacheSecurityGroupError::CacheSecurityGroupQuotaExceededFault(_) => {
+                "QuotaExceeded.CacheSecurityGroup"
+            }
+            CreateCacheSecurityGroupError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            CreateCacheSecurityGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by CreateCacheSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateCacheSubnetGroupError {
@@ -10847,6 +11006,23 @@ impl fmt::Display for CreateCacheSubnetGroupError {
     }
 }
 impl Error for CreateCacheSubnetGroupError {}
+impl ServiceErrorCode for CreateCacheSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCacheSubnetGroupError::CacheSubnetGroupAlreadyExistsFault(_) => {
+                "CacheSubnetGroupAlreadyExists"
+            }
+            CreateCacheSubnetGroupError::CacheSubnetGroupQuotaExceededFault(_) => {
+                "CacheSubnetGroupQuotaExceeded"
+            }
+            CreateCacheSubnetGroupError::CacheSubnetQuotaExceededFault(_) => {
+                "CacheSubnetQuotaExceededFault"
+            }
+            CreateCacheSubnetGroupError::InvalidSubnet(_) => "InvalidSubnet",
+            CreateCacheSubnetGroupError::SubnetNotAllowedFault(_) => "SubnetNotAllowedFault",
+        }
+    }
+}
 /// Errors returned by CreateGlobalReplicationGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateGlobalReplicationGroupError {
@@ -10943,6 +11119,25 @@ impl fmt::Display for CreateGlobalReplicationGroupError {
     }
 }
 impl Error for CreateGlobalReplicationGroupError {}
+impl ServiceErrorCode for CreateGlobalReplicationGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGlobalReplicationGroupError::GlobalReplicationGroupAlreadyExistsFault(_) => {
+                "GlobalReplicationGroupAlreadyExistsFault"
+            }
+            CreateGlobalReplicationGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+            CreateGlobalReplicationGroupError::InvalidReplicationGroupStateFault(_) => {
+                "InvalidReplicationGroupState"
+            }
+            CreateGlobalReplicationGroupError::ReplicationGroupNotFoundFault(_) => {
+                "ReplicationGroupNotFoundFault"
+            }
+            CreateGlobalReplicationGroupError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by CreateReplicationGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateReplicationGroupError {
@@ -11072,6 +11267,61 @@ impl fmt::Display for CreateReplicationGroupError {
     }
 }
 impl Error for CreateReplicationGroupError {}
+impl ServiceErrorCode for CreateReplicationGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateReplicationGroupError::CacheClusterNotFoundFault(_) => "CacheClusterNotFound",
+            CreateReplicationGroupError::CacheParameterGroupNotFoundFault(_) => {
+                "CacheParameterGroupNotFound"
+            }
+            CreateReplicationGroupError::CacheSecurityGroupNotFoundFault(_) => {
+                "CacheSecurityGroupNotFound"
+            }
+            CreateReplicationGroupError::CacheSubnetGroupNotFoundFault(_) => {
+                "CacheSubnetGroupNotFoundFault"
+            }
+            CreateReplicationGroupError::ClusterQuotaForCustomerExceededFault(_) => {
+                "ClusterQuotaForCustomerExceeded"
+            }
+            CreateReplicationGroupError::GlobalReplicationGroupNotFoundFault(_) => {
+                "GlobalReplicationGroupNotFoundFault"
+            }
+            CreateReplicationGroupError::InsufficientCacheClusterCapacityFault(_) => {
+                "InsufficientCacheClusterCapacity"
+            }
+            CreateReplicationGroupError::InvalidCacheClusterStateFault(_) => {
+                "InvalidCacheClusterState"
+            }
+            CreateReplicationGroupError::InvalidGlobalReplicationGroupStateFault(_) => {
+                "InvalidGlobalReplicationGroupState"
+            }
+            CreateReplicationGroupError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            CreateReplicationGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+            CreateReplicationGroupError::InvalidUserGroupStateFault(_) => "InvalidUserGroupState",
+            CreateReplicationGroupError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            CreateReplicationGroupError::NodeGroupsPerReplicationGroupQuotaExceededFault(_) => {
+                "NodeGroupsPerReplicationGroupQuotaExceeded"
+            }
+            CreateReplicationGroupError::NodeQuotaForClusterExceededFault(_) => {
+                "NodeQuotaForClusterExceeded"
+            }
+            CreateReplicationGroupError::NodeQuotaForCustomerExceededFault(_) => {
+                "NodeQuotaForCustomerExceeded"
+            }
+            CreateReplicationGroupError::ReplicationGroupAlreadyExistsFault(_) => {
+                "ReplicationGroupAlreadyExists"
+            }
+            CreateReplicationGroupError::TagQuotaPerResourceExceeded(_) => {
+                "TagQuotaPerResourceExceeded"
+            }
+            CreateReplicationGroupError::UserGroupNotFoundFault(_) => "UserGroupNotFound",
+        }
+    }
+}
 /// Errors returned by CreateSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateSnapshotError {
@@ -11192,6 +11442,27 @@ impl fmt::Display for CreateSnapshotError {
     }
 }
 impl Error for CreateSnapshotError {}
+impl ServiceErrorCode for CreateSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSnapshotError::CacheClusterNotFoundFault(_) => "CacheClusterNotFound",
+            CreateSnapshotError::InvalidCacheClusterStateFault(_) => "InvalidCacheClusterState",
+            CreateSnapshotError::InvalidParameterCombination(_) => "InvalidParameterCombination",
+            CreateSnapshotError::InvalidParameterValue(_) => "InvalidParameterValue",
+            CreateSnapshotError::InvalidReplicationGroupStateFault(_) => {
+                "InvalidReplicationGroupState"
+            }
+            CreateSnapshotError::ReplicationGroupNotFoundFault(_) => {
+                "ReplicationGroupNotFoundFault"
+            }
+            CreateSnapshotError::SnapshotAlreadyExistsFault(_) => "SnapshotAlreadyExistsFault",
+            CreateSnapshotError::SnapshotFeatureNotSupportedFault(_) => {
+                "SnapshotFeatureNotSupportedFault"
+            }
+            CreateSnapshotError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceededFault",
+        }
+    }
+}
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
 pub enum CreateUserError {
@@ -11268,6 +11539,17 @@ impl fmt::Display for CreateUserError {
     }
 }
 impl Error for CreateUserError {}
+impl ServiceErrorCode for CreateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserError::DuplicateUserNameFault(_) => "DuplicateUserName",
+            CreateUserError::InvalidParameterCombination(_) => "InvalidParameterCombination",
+            CreateUserError::InvalidParameterValue(_) => "InvalidParameterValue",
+            CreateUserError::UserAlreadyExistsFault(_) => "UserAlreadyExists",
+            CreateUserError::UserQuotaExceededFault(_) => "UserQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by CreateUserGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateUserGroupError {
@@ -11352,6 +11634,18 @@ impl fmt::Display for CreateUserGroupError {
     }
 }
 impl Error for CreateUserGroupError {}
+impl ServiceErrorCode for CreateUserGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserGroupError::DefaultUserRequired(_) => "DefaultUserRequired",
+            CreateUserGroupError::DuplicateUserNameFault(_) => "DuplicateUserName",
+            CreateUserGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+            CreateUserGroupError::UserGroupAlreadyExistsFault(_) => "UserGroupAlreadyExists",
+            CreateUserGroupError::UserGroupQuotaExceededFault(_) => "UserGroupQuotaExceeded",
+            CreateUserGroupError::UserNotFoundFault(_) => "UserNotFound",
+        }
+    }
+}
 /// Errors returned by DecreaseNodeGroupsInGlobalReplicationGroup
 #[derive(Debug, PartialEq)]
 pub enum DecreaseNodeGroupsInGlobalReplicationGroupError {
@@ -11402,6 +11696,16 @@ DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(ref cause
     }
 }
 impl Error for DecreaseNodeGroupsInGlobalReplicationGroupError {}
+impl ServiceErrorCode for DecreaseNodeGroupsInGlobalReplicationGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DecreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(_) => "GlobalReplicationGroupNotFoundFault",
+            DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(_) => "InvalidGlobalReplicationGroupState",
+            DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterCombination(_) => "InvalidParameterCombination",
+            DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by DecreaseReplicaCount
 #[derive(Debug, PartialEq)]
 pub enum DecreaseReplicaCountError {
@@ -11572,6 +11876,44 @@ impl fmt::Display for DecreaseReplicaCountError {
     }
 }
 impl Error for DecreaseReplicaCountError {}
+impl ServiceErrorCode for DecreaseReplicaCountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DecreaseReplicaCountError::ClusterQuotaForCustomerExceededFault(_) => {
+                "ClusterQuotaForCustomerExceeded"
+            }
+            DecreaseReplicaCountError::InsufficientCacheClusterCapacityFault(_) => {
+                "InsufficientCacheClusterCapacity"
+            }
+            DecreaseReplicaCountError::InvalidCacheClusterStateFault(_) => {
+                "InvalidCacheClusterState"
+            }
+            DecreaseReplicaCountError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DecreaseReplicaCountError::InvalidParameterValue(_) => "InvalidParameterValue",
+            DecreaseReplicaCountError::InvalidReplicationGroupStateFault(_) => {
+                "InvalidReplicationGroupState"
+            }
+            DecreaseReplicaCountError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            DecreaseReplicaCountError::NoOperationFault(_) => "NoOperationFault",
+            DecreaseReplicaCountError::NodeGroupsPerReplicationGroupQuotaExceededFault(_) => {
+                "NodeGroupsPerReplicationGroupQuotaExceeded"
+            }
+            DecreaseReplicaCountError::NodeQuotaForCustomerExceededFault(_) => {
+                "NodeQuotaForCustomerExceeded"
+            }
+            DecreaseReplicaCountError::ReplicationGroupNotFoundFault(_) => {
+                "ReplicationGroupNotFoundFault"
+            }
+            DecreaseReplicaCountError::ServiceLinkedRoleNotFoundFault(_) => {
+                "ServiceLinkedRoleNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteCacheCluster
 #[derive(Debug, PartialEq)]
 pub enum DeleteCacheClusterError {
@@ -11686,6 +12028,23 @@ impl fmt::Display for DeleteCacheClusterError {
     }
 }
 impl Error for DeleteCacheClusterError {}
+impl ServiceErrorCode for DeleteCacheClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCacheClusterError::CacheClusterNotFoundFault(_) => "CacheClusterNotFound",
+            DeleteCacheClusterError::InvalidCacheClusterStateFault(_) => "InvalidCacheClusterState",
+            DeleteCacheClusterError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DeleteCacheClusterError::InvalidParameterValue(_) => "InvalidParameterValue",
+            DeleteCacheClusterError::SnapshotAlreadyExistsFault(_) => "SnapshotAlreadyExistsFault",
+            DeleteCacheClusterError::SnapshotFeatureNotSupportedFault(_) => {
+                "SnapshotFeatureNotSupportedFault"
+            }
+            DeleteCacheClusterError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceededFault",
+        }
+    }
+}
 /// Errors returned by DeleteCacheParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteCacheParameterGroupError {
@@ -11770,6 +12129,22 @@ impl fmt::Display for DeleteCacheParameterGroupError {
     }
 }
 impl Error for DeleteCacheParameterGroupError {}
+impl ServiceErrorCode for DeleteCacheParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCacheParameterGroupError::CacheParameterGroupNotFoundFault(_) => {
+                "CacheParameterGroupNotFound"
+            }
+            DeleteCacheParameterGroupError::InvalidCacheParameterGroupStateFault(_) => {
+                "InvalidCacheParameterGroupState"
+            }
+            DeleteCacheParameterGroupError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DeleteCacheParameterGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by DeleteCacheSecurityGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteCacheSecurityGroupError {
@@ -11854,6 +12229,22 @@ impl fmt::Display for DeleteCacheSecurityGroupError {
     }
 }
 impl Error for DeleteCacheSecurityGroupError {}
+impl ServiceErrorCode for DeleteCacheSecurityGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCacheSecurityGroupError::CacheSecurityGroupNotFoundFault(_) => {
+                "CacheSecurityGroupNotFound"
+            }
+            DeleteCacheSecurityGroupError::InvalidCacheSecurityGroupStateFault(_) => {
+                "InvalidCacheSecurityGroupState"
+            }
+            DeleteCacheSecurityGroupError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DeleteCacheSecurityGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by DeleteCacheSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteCacheSubnetGroupError {
@@ -11912,6 +12303,16 @@ impl fmt::Display for DeleteCacheSubnetGroupError {
     }
 }
 impl Error for DeleteCacheSubnetGroupError {}
+impl ServiceErrorCode for DeleteCacheSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCacheSubnetGroupError::CacheSubnetGroupInUse(_) => "CacheSubnetGroupInUse",
+            DeleteCacheSubnetGroupError::CacheSubnetGroupNotFoundFault(_) => {
+                "CacheSubnetGroupNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteGlobalReplicationGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteGlobalReplicationGroupError {
@@ -11984,6 +12385,19 @@ impl fmt::Display for DeleteGlobalReplicationGroupError {
     }
 }
 impl Error for DeleteGlobalReplicationGroupError {}
+impl ServiceErrorCode for DeleteGlobalReplicationGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(_) => {
+                "GlobalReplicationGroupNotFoundFault"
+            }
+            DeleteGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(_) => {
+                "InvalidGlobalReplicationGroupState"
+            }
+            DeleteGlobalReplicationGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by DeleteReplicationGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteReplicationGroupError {
@@ -12102,6 +12516,31 @@ impl fmt::Display for DeleteReplicationGroupError {
     }
 }
 impl Error for DeleteReplicationGroupError {}
+impl ServiceErrorCode for DeleteReplicationGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteReplicationGroupError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DeleteReplicationGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+            DeleteReplicationGroupError::InvalidReplicationGroupStateFault(_) => {
+                "InvalidReplicationGroupState"
+            }
+            DeleteReplicationGroupError::ReplicationGroupNotFoundFault(_) => {
+                "ReplicationGroupNotFoundFault"
+            }
+            DeleteReplicationGroupError::SnapshotAlreadyExistsFault(_) => {
+                "SnapshotAlreadyExistsFault"
+            }
+            DeleteReplicationGroupError::SnapshotFeatureNotSupportedFault(_) => {
+                "SnapshotFeatureNotSupportedFault"
+            }
+            DeleteReplicationGroupError::SnapshotQuotaExceededFault(_) => {
+                "SnapshotQuotaExceededFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteSnapshot
 #[derive(Debug, PartialEq)]
 pub enum DeleteSnapshotError {
@@ -12170,6 +12609,16 @@ impl fmt::Display for DeleteSnapshotError {
     }
 }
 impl Error for DeleteSnapshotError {}
+impl ServiceErrorCode for DeleteSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSnapshotError::InvalidParameterCombination(_) => "InvalidParameterCombination",
+            DeleteSnapshotError::InvalidParameterValue(_) => "InvalidParameterValue",
+            DeleteSnapshotError::InvalidSnapshotStateFault(_) => "InvalidSnapshotState",
+            DeleteSnapshotError::SnapshotNotFoundFault(_) => "SnapshotNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserError {
@@ -12242,6 +12691,18 @@ impl fmt::Display for DeleteUserError {
     }
 }
 impl Error for DeleteUserError {}
+impl ServiceErrorCode for DeleteUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserError::DefaultUserAssociatedToUserGroupFault(_) => {
+                "DefaultUserAssociatedToUserGroup"
+            }
+            DeleteUserError::InvalidParameterValue(_) => "InvalidParameterValue",
+            DeleteUserError::InvalidUserStateFault(_) => "InvalidUserState",
+            DeleteUserError::UserNotFoundFault(_) => "UserNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteUserGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserGroupError {
@@ -12302,6 +12763,15 @@ impl fmt::Display for DeleteUserGroupError {
     }
 }
 impl Error for DeleteUserGroupError {}
+impl ServiceErrorCode for DeleteUserGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+            DeleteUserGroupError::InvalidUserGroupStateFault(_) => "InvalidUserGroupState",
+            DeleteUserGroupError::UserGroupNotFoundFault(_) => "UserGroupNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeCacheClusters
 #[derive(Debug, PartialEq)]
 pub enum DescribeCacheClustersError {
@@ -12370,6 +12840,17 @@ impl fmt::Display for DescribeCacheClustersError {
     }
 }
 impl Error for DescribeCacheClustersError {}
+impl ServiceErrorCode for DescribeCacheClustersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCacheClustersError::CacheClusterNotFoundFault(_) => "CacheClusterNotFound",
+            DescribeCacheClustersError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DescribeCacheClustersError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by DescribeCacheEngineVersions
 #[derive(Debug, PartialEq)]
 pub enum DescribeCacheEngineVersionsError {}
@@ -12406,6 +12887,11 @@ impl fmt::Display for DescribeCacheEngineVersionsError {
     }
 }
 impl Error for DescribeCacheEngineVersionsError {}
+impl ServiceErrorCode for DescribeCacheEngineVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeCacheParameterGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeCacheParameterGroupsError {
@@ -12480,6 +12966,19 @@ impl fmt::Display for DescribeCacheParameterGroupsError {
     }
 }
 impl Error for DescribeCacheParameterGroupsError {}
+impl ServiceErrorCode for DescribeCacheParameterGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCacheParameterGroupsError::CacheParameterGroupNotFoundFault(_) => {
+                "CacheParameterGroupNotFound"
+            }
+            DescribeCacheParameterGroupsError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DescribeCacheParameterGroupsError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by DescribeCacheParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeCacheParametersError {
@@ -12552,6 +13051,19 @@ impl fmt::Display for DescribeCacheParametersError {
     }
 }
 impl Error for DescribeCacheParametersError {}
+impl ServiceErrorCode for DescribeCacheParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCacheParametersError::CacheParameterGroupNotFoundFault(_) => {
+                "CacheParameterGroupNotFound"
+            }
+            DescribeCacheParametersError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DescribeCacheParametersError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by DescribeCacheSecurityGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeCacheSecurityGroupsError {
@@ -12626,6 +13138,19 @@ impl fmt::Display for DescribeCacheSecurityGroupsError {
     }
 }
 impl Error for DescribeCacheSecurityGroupsError {}
+impl ServiceErrorCode for DescribeCacheSecurityGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCacheSecurityGroupsError::CacheSecurityGroupNotFoundFault(_) => {
+                "CacheSecurityGroupNotFound"
+            }
+            DescribeCacheSecurityGroupsError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DescribeCacheSecurityGroupsError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by DescribeCacheSubnetGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeCacheSubnetGroupsError {
@@ -12674,6 +13199,15 @@ impl fmt::Display for DescribeCacheSubnetGroupsError {
     }
 }
 impl Error for DescribeCacheSubnetGroupsError {}
+impl ServiceErrorCode for DescribeCacheSubnetGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCacheSubnetGroupsError::CacheSubnetGroupNotFoundFault(_) => {
+                "CacheSubnetGroupNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeEngineDefaultParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeEngineDefaultParametersError {
@@ -12736,6 +13270,18 @@ impl fmt::Display for DescribeEngineDefaultParametersError {
     }
 }
 impl Error for DescribeEngineDefaultParametersError {}
+impl ServiceErrorCode for DescribeEngineDefaultParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEngineDefaultParametersError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DescribeEngineDefaultParametersError::InvalidParameterValue(_) => {
+                "InvalidParameterValue"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventsError {
@@ -12788,6 +13334,14 @@ impl fmt::Display for DescribeEventsError {
     }
 }
 impl Error for DescribeEventsError {}
+impl ServiceErrorCode for DescribeEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventsError::InvalidParameterCombination(_) => "InvalidParameterCombination",
+            DescribeEventsError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by DescribeGlobalReplicationGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeGlobalReplicationGroupsError {
@@ -12860,6 +13414,21 @@ impl fmt::Display for DescribeGlobalReplicationGroupsError {
     }
 }
 impl Error for DescribeGlobalReplicationGroupsError {}
+impl ServiceErrorCode for DescribeGlobalReplicationGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGlobalReplicationGroupsError::GlobalReplicationGroupNotFoundFault(_) => {
+                "GlobalReplicationGroupNotFoundFault"
+            }
+            DescribeGlobalReplicationGroupsError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DescribeGlobalReplicationGroupsError::InvalidParameterValue(_) => {
+                "InvalidParameterValue"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeReplicationGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeReplicationGroupsError {
@@ -12932,6 +13501,19 @@ impl fmt::Display for DescribeReplicationGroupsError {
     }
 }
 impl Error for DescribeReplicationGroupsError {}
+impl ServiceErrorCode for DescribeReplicationGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReplicationGroupsError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DescribeReplicationGroupsError::InvalidParameterValue(_) => "InvalidParameterValue",
+            DescribeReplicationGroupsError::ReplicationGroupNotFoundFault(_) => {
+                "ReplicationGroupNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeReservedCacheNodes
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedCacheNodesError {
@@ -13006,6 +13588,19 @@ impl fmt::Display for DescribeReservedCacheNodesError {
     }
 }
 impl Error for DescribeReservedCacheNodesError {}
+impl ServiceErrorCode for DescribeReservedCacheNodesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReservedCacheNodesError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DescribeReservedCacheNodesError::InvalidParameterValue(_) => "InvalidParameterValue",
+            DescribeReservedCacheNodesError::ReservedCacheNodeNotFoundFault(_) => {
+                "ReservedCacheNodeNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeReservedCacheNodesOfferings
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedCacheNodesOfferingsError {
@@ -13059,6 +13654,21 @@ impl fmt::Display for DescribeReservedCacheNodesOfferingsError {
     }
 }
 impl Error for DescribeReservedCacheNodesOfferingsError {}
+impl ServiceErrorCode for DescribeReservedCacheNodesOfferingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReservedCacheNodesOfferingsError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DescribeReservedCacheNodesOfferingsError::InvalidParameterValue(_) => {
+                "InvalidParameterValue"
+            }
+            DescribeReservedCacheNodesOfferingsError::ReservedCacheNodesOfferingNotFoundFault(
+                _,
+            ) => "ReservedCacheNodesOfferingNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeServiceUpdates
 #[derive(Debug, PartialEq)]
 pub enum DescribeServiceUpdatesError {
@@ -13129,6 +13739,19 @@ impl fmt::Display for DescribeServiceUpdatesError {
     }
 }
 impl Error for DescribeServiceUpdatesError {}
+impl ServiceErrorCode for DescribeServiceUpdatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeServiceUpdatesError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DescribeServiceUpdatesError::InvalidParameterValue(_) => "InvalidParameterValue",
+            DescribeServiceUpdatesError::ServiceUpdateNotFoundFault(_) => {
+                "ServiceUpdateNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeSnapshots
 #[derive(Debug, PartialEq)]
 pub enum DescribeSnapshotsError {
@@ -13201,6 +13824,16 @@ impl fmt::Display for DescribeSnapshotsError {
     }
 }
 impl Error for DescribeSnapshotsError {}
+impl ServiceErrorCode for DescribeSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSnapshotsError::CacheClusterNotFoundFault(_) => "CacheClusterNotFound",
+            DescribeSnapshotsError::InvalidParameterCombination(_) => "InvalidParameterCombination",
+            DescribeSnapshotsError::InvalidParameterValue(_) => "InvalidParameterValue",
+            DescribeSnapshotsError::SnapshotNotFoundFault(_) => "SnapshotNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeUpdateActions
 #[derive(Debug, PartialEq)]
 pub enum DescribeUpdateActionsError {
@@ -13257,6 +13890,16 @@ impl fmt::Display for DescribeUpdateActionsError {
     }
 }
 impl Error for DescribeUpdateActionsError {}
+impl ServiceErrorCode for DescribeUpdateActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUpdateActionsError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DescribeUpdateActionsError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by DescribeUserGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserGroupsError {
@@ -13313,6 +13956,16 @@ impl fmt::Display for DescribeUserGroupsError {
     }
 }
 impl Error for DescribeUserGroupsError {}
+impl ServiceErrorCode for DescribeUserGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserGroupsError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DescribeUserGroupsError::UserGroupNotFoundFault(_) => "UserGroupNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeUsers
 #[derive(Debug, PartialEq)]
 pub enum DescribeUsersError {
@@ -13365,6 +14018,14 @@ impl fmt::Display for DescribeUsersError {
     }
 }
 impl Error for DescribeUsersError {}
+impl ServiceErrorCode for DescribeUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUsersError::InvalidParameterCombination(_) => "InvalidParameterCombination",
+            DescribeUsersError::UserNotFoundFault(_) => "UserNotFound",
+        }
+    }
+}
 /// Errors returned by DisassociateGlobalReplicationGroup
 #[derive(Debug, PartialEq)]
 pub enum DisassociateGlobalReplicationGroupError {
@@ -13423,6 +14084,24 @@ impl fmt::Display for DisassociateGlobalReplicationGroupError {
     }
 }
 impl Error for DisassociateGlobalReplicationGroupError {}
+impl ServiceErrorCode for DisassociateGlobalReplicationGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(_) => {
+                "GlobalReplicationGroupNotFoundFault"
+            }
+            DisassociateGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(_) => {
+                "InvalidGlobalReplicationGroupState"
+            }
+            DisassociateGlobalReplicationGroupError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            DisassociateGlobalReplicationGroupError::InvalidParameterValue(_) => {
+                "InvalidParameterValue"
+            }
+        }
+    }
+}
 /// Errors returned by FailoverGlobalReplicationGroup
 #[derive(Debug, PartialEq)]
 pub enum FailoverGlobalReplicationGroupError {
@@ -13481,6 +14160,24 @@ impl fmt::Display for FailoverGlobalReplicationGroupError {
     }
 }
 impl Error for FailoverGlobalReplicationGroupError {}
+impl ServiceErrorCode for FailoverGlobalReplicationGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            FailoverGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(_) => {
+                "GlobalReplicationGroupNotFoundFault"
+            }
+            FailoverGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(_) => {
+                "InvalidGlobalReplicationGroupState"
+            }
+            FailoverGlobalReplicationGroupError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            FailoverGlobalReplicationGroupError::InvalidParameterValue(_) => {
+                "InvalidParameterValue"
+            }
+        }
+    }
+}
 /// Errors returned by IncreaseNodeGroupsInGlobalReplicationGroup
 #[derive(Debug, PartialEq)]
 pub enum IncreaseNodeGroupsInGlobalReplicationGroupError {
@@ -13528,6 +14225,15 @@ IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(ref cause
     }
 }
 impl Error for IncreaseNodeGroupsInGlobalReplicationGroupError {}
+impl ServiceErrorCode for IncreaseNodeGroupsInGlobalReplicationGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            IncreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(_) => "GlobalReplicationGroupNotFoundFault",
+            IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(_) => "InvalidGlobalReplicationGroupState",
+            IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by IncreaseReplicaCount
 #[derive(Debug, PartialEq)]
 pub enum IncreaseReplicaCountError {
@@ -13694,6 +14400,42 @@ impl fmt::Display for IncreaseReplicaCountError {
     }
 }
 impl Error for IncreaseReplicaCountError {}
+impl ServiceErrorCode for IncreaseReplicaCountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            IncreaseReplicaCountError::ClusterQuotaForCustomerExceededFault(_) => {
+                "ClusterQuotaForCustomerExceeded"
+            }
+            IncreaseReplicaCountError::InsufficientCacheClusterCapacityFault(_) => {
+                "InsufficientCacheClusterCapacity"
+            }
+            IncreaseReplicaCountError::InvalidCacheClusterStateFault(_) => {
+                "InvalidCacheClusterState"
+            }
+            IncreaseReplicaCountError::InvalidKMSKeyFault(_) => "InvalidKMSKeyFault",
+            IncreaseReplicaCountError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            IncreaseReplicaCountError::InvalidParameterValue(_) => "InvalidParameterValue",
+            IncreaseReplicaCountError::InvalidReplicationGroupStateFault(_) => {
+                "InvalidReplicationGroupState"
+            }
+            IncreaseReplicaCountError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            IncreaseReplicaCountError::NoOperationFault(_) => "NoOperationFault",
+            IncreaseReplicaCountError::NodeGroupsPerReplicationGroupQuotaExceededFault(_) => {
+                "NodeGroupsPerReplicationGroupQuotaExceeded"
+            }
+            IncreaseReplicaCountError::NodeQuotaForCustomerExceededFault(_) => {
+                "NodeQuotaForCustomerExceeded"
+            }
+            IncreaseReplicaCountError::ReplicationGroupNotFoundFault(_) => {
+                "ReplicationGroupNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by ListAllowedNodeTypeModifications
 #[derive(Debug, PartialEq)]
 pub enum ListAllowedNodeTypeModificationsError {
@@ -13780,6 +14522,24 @@ impl fmt::Display for ListAllowedNodeTypeModificationsError {
     }
 }
 impl Error for ListAllowedNodeTypeModificationsError {}
+impl ServiceErrorCode for ListAllowedNodeTypeModificationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAllowedNodeTypeModificationsError::CacheClusterNotFoundFault(_) => {
+                "CacheClusterNotFound"
+            }
+            ListAllowedNodeTypeModificationsError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            ListAllowedNodeTypeModificationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValue"
+            }
+            ListAllowedNodeTypeModificationsError::ReplicationGroupNotFoundFault(_) => {
+                "ReplicationGroupNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -13844,6 +14604,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::CacheClusterNotFoundFault(_) => "CacheClusterNotFound",
+            ListTagsForResourceError::InvalidARNFault(_) => "InvalidARN",
+            ListTagsForResourceError::SnapshotNotFoundFault(_) => "SnapshotNotFoundFault",
+        }
+    }
+}
 /// Errors returned by ModifyCacheCluster
 #[derive(Debug, PartialEq)]
 pub enum ModifyCacheClusterError {
@@ -14006,6 +14775,39 @@ impl fmt::Display for ModifyCacheClusterError {
     }
 }
 impl Error for ModifyCacheClusterError {}
+impl ServiceErrorCode for ModifyCacheClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyCacheClusterError::CacheClusterNotFoundFault(_) => "CacheClusterNotFound",
+            ModifyCacheClusterError::CacheParameterGroupNotFoundFault(_) => {
+                "CacheParameterGroupNotFound"
+            }
+            ModifyCacheClusterError::CacheSecurityGroupNotFoundFault(_) => {
+                "CacheSecurityGroupNotFound"
+            }
+            ModifyCacheClusterError::InsufficientCacheClusterCapacityFault(_) => {
+                "InsufficientCacheClusterCapacity"
+            }
+            ModifyCacheClusterError::InvalidCacheClusterStateFault(_) => "InvalidCacheClusterState",
+            ModifyCacheClusterError::InvalidCacheSecurityGroupStateFault(_) => {
+                "InvalidCacheSecurityGroupState"
+            }
+            ModifyCacheClusterError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            ModifyCacheClusterError::InvalidParameterValue(_) => "InvalidParameterValue",
+            ModifyCacheClusterError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            ModifyCacheClusterError::NodeQuotaForClusterExceededFault(_) => {
+                "NodeQuotaForClusterExceeded"
+            }
+            ModifyCacheClusterError::NodeQuotaForCustomerExceededFault(_) => {
+                "NodeQuotaForCustomerExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyCacheParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyCacheParameterGroupError {
@@ -14102,6 +14904,25 @@ impl fmt::Display for ModifyCacheParameterGroupError {
     }
 }
 impl Error for ModifyCacheParameterGroupError {}
+impl ServiceErrorCode for ModifyCacheParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyCacheParameterGroupError::CacheParameterGroupNotFoundFault(_) => {
+                "CacheParameterGroupNotFound"
+            }
+            ModifyCacheParameterGroupError::InvalidCacheParameterGroupStateFault(_) => {
+                "InvalidCacheParameterGroupState"
+            }
+            ModifyCacheParameterGroupError::InvalidGlobalReplicationGroupStateFault(_) => {
+                "InvalidGlobalReplicationGroupState"
+            }
+            ModifyCacheParameterGroupError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            ModifyCacheParameterGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by ModifyCacheSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyCacheSubnetGroupError {
@@ -14188,6 +15009,21 @@ impl fmt::Display for ModifyCacheSubnetGroupError {
     }
 }
 impl Error for ModifyCacheSubnetGroupError {}
+impl ServiceErrorCode for ModifyCacheSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyCacheSubnetGroupError::CacheSubnetGroupNotFoundFault(_) => {
+                "CacheSubnetGroupNotFoundFault"
+            }
+            ModifyCacheSubnetGroupError::CacheSubnetQuotaExceededFault(_) => {
+                "CacheSubnetQuotaExceededFault"
+            }
+            ModifyCacheSubnetGroupError::InvalidSubnet(_) => "InvalidSubnet",
+            ModifyCacheSubnetGroupError::SubnetInUse(_) => "SubnetInUse",
+            ModifyCacheSubnetGroupError::SubnetNotAllowedFault(_) => "SubnetNotAllowedFault",
+        }
+    }
+}
 /// Errors returned by ModifyGlobalReplicationGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyGlobalReplicationGroupError {
@@ -14260,6 +15096,19 @@ impl fmt::Display for ModifyGlobalReplicationGroupError {
     }
 }
 impl Error for ModifyGlobalReplicationGroupError {}
+impl ServiceErrorCode for ModifyGlobalReplicationGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(_) => {
+                "GlobalReplicationGroupNotFoundFault"
+            }
+            ModifyGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(_) => {
+                "InvalidGlobalReplicationGroupState"
+            }
+            ModifyGlobalReplicationGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by ModifyReplicationGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyReplicationGroupError {
@@ -14482,6 +15331,50 @@ impl fmt::Display for ModifyReplicationGroupError {
     }
 }
 impl Error for ModifyReplicationGroupError {}
+impl ServiceErrorCode for ModifyReplicationGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyReplicationGroupError::CacheClusterNotFoundFault(_) => "CacheClusterNotFound",
+            ModifyReplicationGroupError::CacheParameterGroupNotFoundFault(_) => {
+                "CacheParameterGroupNotFound"
+            }
+            ModifyReplicationGroupError::CacheSecurityGroupNotFoundFault(_) => {
+                "CacheSecurityGroupNotFound"
+            }
+            ModifyReplicationGroupError::InsufficientCacheClusterCapacityFault(_) => {
+                "InsufficientCacheClusterCapacity"
+            }
+            ModifyReplicationGroupError::InvalidCacheClusterStateFault(_) => {
+                "InvalidCacheClusterState"
+            }
+            ModifyReplicationGroupError::InvalidCacheSecurityGroupStateFault(_) => {
+                "InvalidCacheSecurityGroupState"
+            }
+            ModifyReplicationGroupError::InvalidKMSKeyFault(_) => "InvalidKMSKeyFault",
+            ModifyReplicationGroupError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            ModifyReplicationGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+            ModifyReplicationGroupError::InvalidReplicationGroupStateFault(_) => {
+                "InvalidReplicationGroupState"
+            }
+            ModifyReplicationGroupError::InvalidUserGroupStateFault(_) => "InvalidUserGroupState",
+            ModifyReplicationGroupError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            ModifyReplicationGroupError::NodeQuotaForClusterExceededFault(_) => {
+                "NodeQuotaForClusterExceeded"
+            }
+            ModifyReplicationGroupError::NodeQuotaForCustomerExceededFault(_) => {
+                "NodeQuotaForCustomerExceeded"
+            }
+            ModifyReplicationGroupError::ReplicationGroupNotFoundFault(_) => {
+                "ReplicationGroupNotFoundFault"
+            }
+            ModifyReplicationGroupError::UserGroupNotFoundFault(_) => "UserGroupNotFound",
+        }
+    }
+}
 /// Errors returned by ModifyReplicationGroupShardConfiguration
 #[derive(Debug, PartialEq)]
 pub enum ModifyReplicationGroupShardConfigurationError {
@@ -14550,6 +15443,22 @@ ModifyReplicationGroupShardConfigurationError::ReplicationGroupNotFoundFault(ref
     }
 }
 impl Error for ModifyReplicationGroupShardConfigurationError {}
+impl ServiceErrorCode for ModifyReplicationGroupShardConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyReplicationGroupShardConfigurationError::InsufficientCacheClusterCapacityFault(_) => "InsufficientCacheClusterCapacity",
+            ModifyReplicationGroupShardConfigurationError::InvalidCacheClusterStateFault(_) => "InvalidCacheClusterState",
+            ModifyReplicationGroupShardConfigurationError::InvalidKMSKeyFault(_) => "InvalidKMSKeyFault",
+            ModifyReplicationGroupShardConfigurationError::InvalidParameterCombination(_) => "InvalidParameterCombination",
+            ModifyReplicationGroupShardConfigurationError::InvalidParameterValue(_) => "InvalidParameterValue",
+            ModifyReplicationGroupShardConfigurationError::InvalidReplicationGroupStateFault(_) => "InvalidReplicationGroupState",
+            ModifyReplicationGroupShardConfigurationError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            ModifyReplicationGroupShardConfigurationError::NodeGroupsPerReplicationGroupQuotaExceededFault(_) => "NodeGroupsPerReplicationGroupQuotaExceeded",
+            ModifyReplicationGroupShardConfigurationError::NodeQuotaForCustomerExceededFault(_) => "NodeQuotaForCustomerExceeded",
+            ModifyReplicationGroupShardConfigurationError::ReplicationGroupNotFoundFault(_) => "ReplicationGroupNotFoundFault",
+        }
+    }
+}
 /// Errors returned by ModifyUser
 #[derive(Debug, PartialEq)]
 pub enum ModifyUserError {
@@ -14618,6 +15527,16 @@ impl fmt::Display for ModifyUserError {
     }
 }
 impl Error for ModifyUserError {}
+impl ServiceErrorCode for ModifyUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyUserError::InvalidParameterCombination(_) => "InvalidParameterCombination",
+            ModifyUserError::InvalidParameterValue(_) => "InvalidParameterValue",
+            ModifyUserError::InvalidUserStateFault(_) => "InvalidUserState",
+            ModifyUserError::UserNotFoundFault(_) => "UserNotFound",
+        }
+    }
+}
 /// Errors returned by ModifyUserGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyUserGroupError {
@@ -14710,6 +15629,19 @@ impl fmt::Display for ModifyUserGroupError {
     }
 }
 impl Error for ModifyUserGroupError {}
+impl ServiceErrorCode for ModifyUserGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyUserGroupError::DefaultUserRequired(_) => "DefaultUserRequired",
+            ModifyUserGroupError::DuplicateUserNameFault(_) => "DuplicateUserName",
+            ModifyUserGroupError::InvalidParameterCombination(_) => "InvalidParameterCombination",
+            ModifyUserGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+            ModifyUserGroupError::InvalidUserGroupStateFault(_) => "InvalidUserGroupState",
+            ModifyUserGroupError::UserGroupNotFoundFault(_) => "UserGroupNotFound",
+            ModifyUserGroupError::UserNotFoundFault(_) => "UserNotFound",
+        }
+    }
+}
 /// Errors returned by PurchaseReservedCacheNodesOffering
 #[derive(Debug, PartialEq)]
 pub enum PurchaseReservedCacheNodesOfferingError {
@@ -14773,6 +15705,27 @@ impl fmt::Display for PurchaseReservedCacheNodesOfferingError {
     }
 }
 impl Error for PurchaseReservedCacheNodesOfferingError {}
+impl ServiceErrorCode for PurchaseReservedCacheNodesOfferingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PurchaseReservedCacheNodesOfferingError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            PurchaseReservedCacheNodesOfferingError::InvalidParameterValue(_) => {
+                "InvalidParameterValue"
+            }
+            PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeAlreadyExistsFault(_) => {
+                "ReservedCacheNodeAlreadyExists"
+            }
+            PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeQuotaExceededFault(_) => {
+                "ReservedCacheNodeQuotaExceeded"
+            }
+            PurchaseReservedCacheNodesOfferingError::ReservedCacheNodesOfferingNotFoundFault(_) => {
+                "ReservedCacheNodesOfferingNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by RebalanceSlotsInGlobalReplicationGroup
 #[derive(Debug, PartialEq)]
 pub enum RebalanceSlotsInGlobalReplicationGroupError {
@@ -14820,6 +15773,15 @@ RebalanceSlotsInGlobalReplicationGroupError::InvalidParameterValue(ref cause) =>
     }
 }
 impl Error for RebalanceSlotsInGlobalReplicationGroupError {}
+impl ServiceErrorCode for RebalanceSlotsInGlobalReplicationGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RebalanceSlotsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(_) => "GlobalReplicationGroupNotFoundFault",
+            RebalanceSlotsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(_) => "InvalidGlobalReplicationGroupState",
+            RebalanceSlotsInGlobalReplicationGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by RebootCacheCluster
 #[derive(Debug, PartialEq)]
 pub enum RebootCacheClusterError {
@@ -14878,6 +15840,14 @@ impl fmt::Display for RebootCacheClusterError {
     }
 }
 impl Error for RebootCacheClusterError {}
+impl ServiceErrorCode for RebootCacheClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RebootCacheClusterError::CacheClusterNotFoundFault(_) => "CacheClusterNotFound",
+            RebootCacheClusterError::InvalidCacheClusterStateFault(_) => "InvalidCacheClusterState",
+        }
+    }
+}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromResourceError {
@@ -14952,6 +15922,16 @@ impl fmt::Display for RemoveTagsFromResourceError {
     }
 }
 impl Error for RemoveTagsFromResourceError {}
+impl ServiceErrorCode for RemoveTagsFromResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsFromResourceError::CacheClusterNotFoundFault(_) => "CacheClusterNotFound",
+            RemoveTagsFromResourceError::InvalidARNFault(_) => "InvalidARN",
+            RemoveTagsFromResourceError::SnapshotNotFoundFault(_) => "SnapshotNotFoundFault",
+            RemoveTagsFromResourceError::TagNotFoundFault(_) => "TagNotFound",
+        }
+    }
+}
 /// Errors returned by ResetCacheParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum ResetCacheParameterGroupError {
@@ -15048,6 +16028,25 @@ impl fmt::Display for ResetCacheParameterGroupError {
     }
 }
 impl Error for ResetCacheParameterGroupError {}
+impl ServiceErrorCode for ResetCacheParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetCacheParameterGroupError::CacheParameterGroupNotFoundFault(_) => {
+                "CacheParameterGroupNotFound"
+            }
+            ResetCacheParameterGroupError::InvalidCacheParameterGroupStateFault(_) => {
+                "InvalidCacheParameterGroupState"
+            }
+            ResetCacheParameterGroupError::InvalidGlobalReplicationGroupStateFault(_) => {
+                "InvalidGlobalReplicationGroupState"
+            }
+            ResetCacheParameterGroupError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            ResetCacheParameterGroupError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by RevokeCacheSecurityGroupIngress
 #[derive(Debug, PartialEq)]
 pub enum RevokeCacheSecurityGroupIngressError {
@@ -15144,6 +16143,27 @@ impl fmt::Display for RevokeCacheSecurityGroupIngressError {
     }
 }
 impl Error for RevokeCacheSecurityGroupIngressError {}
+impl ServiceErrorCode for RevokeCacheSecurityGroupIngressError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RevokeCacheSecurityGroupIngressError::AuthorizationNotFoundFault(_) => {
+                "AuthorizationNotFound"
+            }
+            RevokeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault(_) => {
+                "CacheSecurityGroupNotFound"
+            }
+            RevokeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault(_) => {
+                "InvalidCacheSecurityGroupState"
+            }
+            RevokeCacheSecurityGroupIngressError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombination"
+            }
+            RevokeCacheSecurityGroupIngressError::InvalidParameterValue(_) => {
+                "InvalidParameterValue"
+            }
+        }
+    }
+}
 /// Errors returned by StartMigration
 #[derive(Debug, PartialEq)]
 pub enum StartMigrationError {
@@ -15222,6 +16242,22 @@ impl fmt::Display for StartMigrationError {
     }
 }
 impl Error for StartMigrationError {}
+impl ServiceErrorCode for StartMigrationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartMigrationError::InvalidParameterValue(_) => "InvalidParameterValue",
+            StartMigrationError::InvalidReplicationGroupStateFault(_) => {
+                "InvalidReplicationGroupState"
+            }
+            StartMigrationError::ReplicationGroupAlreadyUnderMigrationFault(_) => {
+                "ReplicationGroupAlreadyUnderMigrationFault"
+            }
+            StartMigrationError::ReplicationGroupNotFoundFault(_) => {
+                "ReplicationGroupNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by TestFailover
 #[derive(Debug, PartialEq)]
 pub enum TestFailoverError {
@@ -15338,6 +16374,25 @@ impl fmt::Display for TestFailoverError {
     }
 }
 impl Error for TestFailoverError {}
+impl ServiceErrorCode for TestFailoverError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TestFailoverError::APICallRateForCustomerExceededFault(_) => {
+                "APICallRateForCustomerExceeded"
+            }
+            TestFailoverError::InvalidCacheClusterStateFault(_) => "InvalidCacheClusterState",
+            TestFailoverError::InvalidKMSKeyFault(_) => "InvalidKMSKeyFault",
+            TestFailoverError::InvalidParameterCombination(_) => "InvalidParameterCombination",
+            TestFailoverError::InvalidParameterValue(_) => "InvalidParameterValue",
+            TestFailoverError::InvalidReplicationGroupStateFault(_) => {
+                "InvalidReplicationGroupState"
+            }
+            TestFailoverError::NodeGroupNotFoundFault(_) => "NodeGroupNotFoundFault",
+            TestFailoverError::ReplicationGroupNotFoundFault(_) => "ReplicationGroupNotFoundFault",
+            TestFailoverError::TestFailoverNotAvailableFault(_) => "TestFailoverNotAvailableFault",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon ElastiCache API. Amazon ElastiCache clients implement this trait.
 #[async_trait]
 pub trait ElastiCache {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -7764,6 +7764,15 @@ impl fmt::Display for AbortEnvironmentUpdateError {
     }
 }
 impl Error for AbortEnvironmentUpdateError {}
+impl ServiceErrorCode for AbortEnvironmentUpdateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AbortEnvironmentUpdateError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+        }
+    }
+}
 /// Errors returned by ApplyEnvironmentManagedAction
 #[derive(Debug, PartialEq)]
 pub enum ApplyEnvironmentManagedActionError {
@@ -7826,6 +7835,18 @@ impl fmt::Display for ApplyEnvironmentManagedActionError {
     }
 }
 impl Error for ApplyEnvironmentManagedActionError {}
+impl ServiceErrorCode for ApplyEnvironmentManagedActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ApplyEnvironmentManagedActionError::ElasticBeanstalkService(_) => {
+                "ElasticBeanstalkServiceException"
+            }
+            ApplyEnvironmentManagedActionError::ManagedActionInvalidState(_) => {
+                "ManagedActionInvalidStateException"
+            }
+        }
+    }
+}
 /// Errors returned by AssociateEnvironmentOperationsRole
 #[derive(Debug, PartialEq)]
 pub enum AssociateEnvironmentOperationsRoleError {
@@ -7876,6 +7897,15 @@ impl fmt::Display for AssociateEnvironmentOperationsRoleError {
     }
 }
 impl Error for AssociateEnvironmentOperationsRoleError {}
+impl ServiceErrorCode for AssociateEnvironmentOperationsRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateEnvironmentOperationsRoleError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+        }
+    }
+}
 /// Errors returned by CheckDNSAvailability
 #[derive(Debug, PartialEq)]
 pub enum CheckDNSAvailabilityError {}
@@ -7910,6 +7940,11 @@ impl fmt::Display for CheckDNSAvailabilityError {
     }
 }
 impl Error for CheckDNSAvailabilityError {}
+impl ServiceErrorCode for CheckDNSAvailabilityError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ComposeEnvironments
 #[derive(Debug, PartialEq)]
 pub enum ComposeEnvironmentsError {
@@ -7962,6 +7997,16 @@ impl fmt::Display for ComposeEnvironmentsError {
     }
 }
 impl Error for ComposeEnvironmentsError {}
+impl ServiceErrorCode for ComposeEnvironmentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ComposeEnvironmentsError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+            ComposeEnvironmentsError::TooManyEnvironments(_) => "TooManyEnvironmentsException",
+        }
+    }
+}
 /// Errors returned by CreateApplication
 #[derive(Debug, PartialEq)]
 pub enum CreateApplicationError {
@@ -8006,6 +8051,13 @@ impl fmt::Display for CreateApplicationError {
     }
 }
 impl Error for CreateApplicationError {}
+impl ServiceErrorCode for CreateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApplicationError::TooManyApplications(_) => "TooManyApplicationsException",
+        }
+    }
+}
 /// Errors returned by CreateApplicationVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateApplicationVersionError {
@@ -8100,6 +8152,25 @@ impl fmt::Display for CreateApplicationVersionError {
     }
 }
 impl Error for CreateApplicationVersionError {}
+impl ServiceErrorCode for CreateApplicationVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApplicationVersionError::CodeBuildNotInServiceRegion(_) => {
+                "CodeBuildNotInServiceRegionException"
+            }
+            CreateApplicationVersionError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+            CreateApplicationVersionError::S3LocationNotInServiceRegion(_) => {
+                "S3LocationNotInServiceRegionException"
+            }
+            CreateApplicationVersionError::TooManyApplicationVersions(_) => {
+                "TooManyApplicationVersionsException"
+            }
+            CreateApplicationVersionError::TooManyApplications(_) => "TooManyApplicationsException",
+        }
+    }
+}
 /// Errors returned by CreateConfigurationTemplate
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationTemplateError {
@@ -8170,6 +8241,19 @@ impl fmt::Display for CreateConfigurationTemplateError {
     }
 }
 impl Error for CreateConfigurationTemplateError {}
+impl ServiceErrorCode for CreateConfigurationTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConfigurationTemplateError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+            CreateConfigurationTemplateError::TooManyBuckets(_) => "TooManyBucketsException",
+            CreateConfigurationTemplateError::TooManyConfigurationTemplates(_) => {
+                "TooManyConfigurationTemplatesException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateEnvironment
 #[derive(Debug, PartialEq)]
 pub enum CreateEnvironmentError {
@@ -8222,6 +8306,14 @@ impl fmt::Display for CreateEnvironmentError {
     }
 }
 impl Error for CreateEnvironmentError {}
+impl ServiceErrorCode for CreateEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEnvironmentError::InsufficientPrivileges(_) => "InsufficientPrivilegesException",
+            CreateEnvironmentError::TooManyEnvironments(_) => "TooManyEnvironmentsException",
+        }
+    }
+}
 /// Errors returned by CreatePlatformVersion
 #[derive(Debug, PartialEq)]
 pub enum CreatePlatformVersionError {
@@ -8288,6 +8380,19 @@ impl fmt::Display for CreatePlatformVersionError {
     }
 }
 impl Error for CreatePlatformVersionError {}
+impl ServiceErrorCode for CreatePlatformVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePlatformVersionError::ElasticBeanstalkService(_) => {
+                "ElasticBeanstalkServiceException"
+            }
+            CreatePlatformVersionError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+            CreatePlatformVersionError::TooManyPlatforms(_) => "TooManyPlatformsException",
+        }
+    }
+}
 /// Errors returned by CreateStorageLocation
 #[derive(Debug, PartialEq)]
 pub enum CreateStorageLocationError {
@@ -8352,6 +8457,19 @@ impl fmt::Display for CreateStorageLocationError {
     }
 }
 impl Error for CreateStorageLocationError {}
+impl ServiceErrorCode for CreateStorageLocationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStorageLocationError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+            CreateStorageLocationError::S3SubscriptionRequired(_) => {
+                "S3SubscriptionRequiredException"
+            }
+            CreateStorageLocationError::TooManyBuckets(_) => "TooManyBucketsException",
+        }
+    }
+}
 /// Errors returned by DeleteApplication
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationError {
@@ -8396,6 +8514,13 @@ impl fmt::Display for DeleteApplicationError {
     }
 }
 impl Error for DeleteApplicationError {}
+impl ServiceErrorCode for DeleteApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationError::OperationInProgress(_) => "OperationInProgressFailure",
+        }
+    }
+}
 /// Errors returned by DeleteApplicationVersion
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationVersionError {
@@ -8478,6 +8603,20 @@ impl fmt::Display for DeleteApplicationVersionError {
     }
 }
 impl Error for DeleteApplicationVersionError {}
+impl ServiceErrorCode for DeleteApplicationVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationVersionError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+            DeleteApplicationVersionError::OperationInProgress(_) => "OperationInProgressFailure",
+            DeleteApplicationVersionError::S3LocationNotInServiceRegion(_) => {
+                "S3LocationNotInServiceRegionException"
+            }
+            DeleteApplicationVersionError::SourceBundleDeletion(_) => "SourceBundleDeletionFailure",
+        }
+    }
+}
 /// Errors returned by DeleteConfigurationTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationTemplateError {
@@ -8528,6 +8667,15 @@ impl fmt::Display for DeleteConfigurationTemplateError {
     }
 }
 impl Error for DeleteConfigurationTemplateError {}
+impl ServiceErrorCode for DeleteConfigurationTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigurationTemplateError::OperationInProgress(_) => {
+                "OperationInProgressFailure"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteEnvironmentConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteEnvironmentConfigurationError {}
@@ -8564,6 +8712,11 @@ impl fmt::Display for DeleteEnvironmentConfigurationError {
     }
 }
 impl Error for DeleteEnvironmentConfigurationError {}
+impl ServiceErrorCode for DeleteEnvironmentConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeletePlatformVersion
 #[derive(Debug, PartialEq)]
 pub enum DeletePlatformVersionError {
@@ -8642,6 +8795,22 @@ impl fmt::Display for DeletePlatformVersionError {
     }
 }
 impl Error for DeletePlatformVersionError {}
+impl ServiceErrorCode for DeletePlatformVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePlatformVersionError::ElasticBeanstalkService(_) => {
+                "ElasticBeanstalkServiceException"
+            }
+            DeletePlatformVersionError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+            DeletePlatformVersionError::OperationInProgress(_) => "OperationInProgressFailure",
+            DeletePlatformVersionError::PlatformVersionStillReferenced(_) => {
+                "PlatformVersionStillReferencedException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeAccountAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountAttributesError {
@@ -8690,6 +8859,15 @@ impl fmt::Display for DescribeAccountAttributesError {
     }
 }
 impl Error for DescribeAccountAttributesError {}
+impl ServiceErrorCode for DescribeAccountAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAccountAttributesError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeApplicationVersions
 #[derive(Debug, PartialEq)]
 pub enum DescribeApplicationVersionsError {}
@@ -8726,6 +8904,11 @@ impl fmt::Display for DescribeApplicationVersionsError {
     }
 }
 impl Error for DescribeApplicationVersionsError {}
+impl ServiceErrorCode for DescribeApplicationVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeApplications
 #[derive(Debug, PartialEq)]
 pub enum DescribeApplicationsError {}
@@ -8760,6 +8943,11 @@ impl fmt::Display for DescribeApplicationsError {
     }
 }
 impl Error for DescribeApplicationsError {}
+impl ServiceErrorCode for DescribeApplicationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeConfigurationOptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigurationOptionsError {
@@ -8806,6 +8994,13 @@ impl fmt::Display for DescribeConfigurationOptionsError {
     }
 }
 impl Error for DescribeConfigurationOptionsError {}
+impl ServiceErrorCode for DescribeConfigurationOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConfigurationOptionsError::TooManyBuckets(_) => "TooManyBucketsException",
+        }
+    }
+}
 /// Errors returned by DescribeConfigurationSettings
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigurationSettingsError {
@@ -8854,6 +9049,13 @@ impl fmt::Display for DescribeConfigurationSettingsError {
     }
 }
 impl Error for DescribeConfigurationSettingsError {}
+impl ServiceErrorCode for DescribeConfigurationSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConfigurationSettingsError::TooManyBuckets(_) => "TooManyBucketsException",
+        }
+    }
+}
 /// Errors returned by DescribeEnvironmentHealth
 #[derive(Debug, PartialEq)]
 pub enum DescribeEnvironmentHealthError {
@@ -8910,6 +9112,16 @@ impl fmt::Display for DescribeEnvironmentHealthError {
     }
 }
 impl Error for DescribeEnvironmentHealthError {}
+impl ServiceErrorCode for DescribeEnvironmentHealthError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEnvironmentHealthError::ElasticBeanstalkService(_) => {
+                "ElasticBeanstalkServiceException"
+            }
+            DescribeEnvironmentHealthError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeEnvironmentManagedActionHistory
 #[derive(Debug, PartialEq)]
 pub enum DescribeEnvironmentManagedActionHistoryError {
@@ -8960,6 +9172,15 @@ impl fmt::Display for DescribeEnvironmentManagedActionHistoryError {
     }
 }
 impl Error for DescribeEnvironmentManagedActionHistoryError {}
+impl ServiceErrorCode for DescribeEnvironmentManagedActionHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEnvironmentManagedActionHistoryError::ElasticBeanstalkService(_) => {
+                "ElasticBeanstalkServiceException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeEnvironmentManagedActions
 #[derive(Debug, PartialEq)]
 pub enum DescribeEnvironmentManagedActionsError {
@@ -9010,6 +9231,15 @@ impl fmt::Display for DescribeEnvironmentManagedActionsError {
     }
 }
 impl Error for DescribeEnvironmentManagedActionsError {}
+impl ServiceErrorCode for DescribeEnvironmentManagedActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEnvironmentManagedActionsError::ElasticBeanstalkService(_) => {
+                "ElasticBeanstalkServiceException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeEnvironmentResources
 #[derive(Debug, PartialEq)]
 pub enum DescribeEnvironmentResourcesError {
@@ -9060,6 +9290,15 @@ impl fmt::Display for DescribeEnvironmentResourcesError {
     }
 }
 impl Error for DescribeEnvironmentResourcesError {}
+impl ServiceErrorCode for DescribeEnvironmentResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEnvironmentResourcesError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeEnvironments
 #[derive(Debug, PartialEq)]
 pub enum DescribeEnvironmentsError {}
@@ -9094,6 +9333,11 @@ impl fmt::Display for DescribeEnvironmentsError {
     }
 }
 impl Error for DescribeEnvironmentsError {}
+impl ServiceErrorCode for DescribeEnvironmentsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventsError {}
@@ -9128,6 +9372,11 @@ impl fmt::Display for DescribeEventsError {
     }
 }
 impl Error for DescribeEventsError {}
+impl ServiceErrorCode for DescribeEventsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeInstancesHealth
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstancesHealthError {
@@ -9184,6 +9433,16 @@ impl fmt::Display for DescribeInstancesHealthError {
     }
 }
 impl Error for DescribeInstancesHealthError {}
+impl ServiceErrorCode for DescribeInstancesHealthError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInstancesHealthError::ElasticBeanstalkService(_) => {
+                "ElasticBeanstalkServiceException"
+            }
+            DescribeInstancesHealthError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribePlatformVersion
 #[derive(Debug, PartialEq)]
 pub enum DescribePlatformVersionError {
@@ -9244,6 +9503,18 @@ impl fmt::Display for DescribePlatformVersionError {
     }
 }
 impl Error for DescribePlatformVersionError {}
+impl ServiceErrorCode for DescribePlatformVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePlatformVersionError::ElasticBeanstalkService(_) => {
+                "ElasticBeanstalkServiceException"
+            }
+            DescribePlatformVersionError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateEnvironmentOperationsRole
 #[derive(Debug, PartialEq)]
 pub enum DisassociateEnvironmentOperationsRoleError {
@@ -9294,6 +9565,15 @@ impl fmt::Display for DisassociateEnvironmentOperationsRoleError {
     }
 }
 impl Error for DisassociateEnvironmentOperationsRoleError {}
+impl ServiceErrorCode for DisassociateEnvironmentOperationsRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateEnvironmentOperationsRoleError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+        }
+    }
+}
 /// Errors returned by ListAvailableSolutionStacks
 #[derive(Debug, PartialEq)]
 pub enum ListAvailableSolutionStacksError {}
@@ -9330,6 +9610,11 @@ impl fmt::Display for ListAvailableSolutionStacksError {
     }
 }
 impl Error for ListAvailableSolutionStacksError {}
+impl ServiceErrorCode for ListAvailableSolutionStacksError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListPlatformBranches
 #[derive(Debug, PartialEq)]
 pub enum ListPlatformBranchesError {}
@@ -9364,6 +9649,11 @@ impl fmt::Display for ListPlatformBranchesError {
     }
 }
 impl Error for ListPlatformBranchesError {}
+impl ServiceErrorCode for ListPlatformBranchesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListPlatformVersions
 #[derive(Debug, PartialEq)]
 pub enum ListPlatformVersionsError {
@@ -9418,6 +9708,18 @@ impl fmt::Display for ListPlatformVersionsError {
     }
 }
 impl Error for ListPlatformVersionsError {}
+impl ServiceErrorCode for ListPlatformVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPlatformVersionsError::ElasticBeanstalkService(_) => {
+                "ElasticBeanstalkServiceException"
+            }
+            ListPlatformVersionsError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -9480,6 +9782,19 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForResourceError::ResourceTypeNotSupported(_) => {
+                "ResourceTypeNotSupportedException"
+            }
+        }
+    }
+}
 /// Errors returned by RebuildEnvironment
 #[derive(Debug, PartialEq)]
 pub enum RebuildEnvironmentError {
@@ -9524,6 +9839,13 @@ impl fmt::Display for RebuildEnvironmentError {
     }
 }
 impl Error for RebuildEnvironmentError {}
+impl ServiceErrorCode for RebuildEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RebuildEnvironmentError::InsufficientPrivileges(_) => "InsufficientPrivilegesException",
+        }
+    }
+}
 /// Errors returned by RequestEnvironmentInfo
 #[derive(Debug, PartialEq)]
 pub enum RequestEnvironmentInfoError {}
@@ -9558,6 +9880,11 @@ impl fmt::Display for RequestEnvironmentInfoError {
     }
 }
 impl Error for RequestEnvironmentInfoError {}
+impl ServiceErrorCode for RequestEnvironmentInfoError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RestartAppServer
 #[derive(Debug, PartialEq)]
 pub enum RestartAppServerError {}
@@ -9592,6 +9919,11 @@ impl fmt::Display for RestartAppServerError {
     }
 }
 impl Error for RestartAppServerError {}
+impl ServiceErrorCode for RestartAppServerError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RetrieveEnvironmentInfo
 #[derive(Debug, PartialEq)]
 pub enum RetrieveEnvironmentInfoError {}
@@ -9626,6 +9958,11 @@ impl fmt::Display for RetrieveEnvironmentInfoError {
     }
 }
 impl Error for RetrieveEnvironmentInfoError {}
+impl ServiceErrorCode for RetrieveEnvironmentInfoError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SwapEnvironmentCNAMEs
 #[derive(Debug, PartialEq)]
 pub enum SwapEnvironmentCNAMEsError {}
@@ -9660,6 +9997,11 @@ impl fmt::Display for SwapEnvironmentCNAMEsError {
     }
 }
 impl Error for SwapEnvironmentCNAMEsError {}
+impl ServiceErrorCode for SwapEnvironmentCNAMEsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by TerminateEnvironment
 #[derive(Debug, PartialEq)]
 pub enum TerminateEnvironmentError {
@@ -9704,6 +10046,15 @@ impl fmt::Display for TerminateEnvironmentError {
     }
 }
 impl Error for TerminateEnvironmentError {}
+impl ServiceErrorCode for TerminateEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TerminateEnvironmentError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
 pub enum UpdateApplicationError {}
@@ -9738,6 +10089,11 @@ impl fmt::Display for UpdateApplicationError {
     }
 }
 impl Error for UpdateApplicationError {}
+impl ServiceErrorCode for UpdateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UpdateApplicationResourceLifecycle
 #[derive(Debug, PartialEq)]
 pub enum UpdateApplicationResourceLifecycleError {
@@ -9788,6 +10144,15 @@ impl fmt::Display for UpdateApplicationResourceLifecycleError {
     }
 }
 impl Error for UpdateApplicationResourceLifecycleError {}
+impl ServiceErrorCode for UpdateApplicationResourceLifecycleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApplicationResourceLifecycleError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateApplicationVersion
 #[derive(Debug, PartialEq)]
 pub enum UpdateApplicationVersionError {}
@@ -9822,6 +10187,11 @@ impl fmt::Display for UpdateApplicationVersionError {
     }
 }
 impl Error for UpdateApplicationVersionError {}
+impl ServiceErrorCode for UpdateApplicationVersionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UpdateConfigurationTemplate
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationTemplateError {
@@ -9880,6 +10250,16 @@ impl fmt::Display for UpdateConfigurationTemplateError {
     }
 }
 impl Error for UpdateConfigurationTemplateError {}
+impl ServiceErrorCode for UpdateConfigurationTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConfigurationTemplateError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+            UpdateConfigurationTemplateError::TooManyBuckets(_) => "TooManyBucketsException",
+        }
+    }
+}
 /// Errors returned by UpdateEnvironment
 #[derive(Debug, PartialEq)]
 pub enum UpdateEnvironmentError {
@@ -9932,6 +10312,14 @@ impl fmt::Display for UpdateEnvironmentError {
     }
 }
 impl Error for UpdateEnvironmentError {}
+impl ServiceErrorCode for UpdateEnvironmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateEnvironmentError::InsufficientPrivileges(_) => "InsufficientPrivilegesException",
+            UpdateEnvironmentError::TooManyBuckets(_) => "TooManyBucketsException",
+        }
+    }
+}
 /// Errors returned by UpdateTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum UpdateTagsForResourceError {
@@ -10014,6 +10402,21 @@ impl fmt::Display for UpdateTagsForResourceError {
     }
 }
 impl Error for UpdateTagsForResourceError {}
+impl ServiceErrorCode for UpdateTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTagsForResourceError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+            UpdateTagsForResourceError::OperationInProgress(_) => "OperationInProgressFailure",
+            UpdateTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateTagsForResourceError::ResourceTypeNotSupported(_) => {
+                "ResourceTypeNotSupportedException"
+            }
+            UpdateTagsForResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by ValidateConfigurationSettings
 #[derive(Debug, PartialEq)]
 pub enum ValidateConfigurationSettingsError {
@@ -10074,6 +10477,16 @@ impl fmt::Display for ValidateConfigurationSettingsError {
     }
 }
 impl Error for ValidateConfigurationSettingsError {}
+impl ServiceErrorCode for ValidateConfigurationSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ValidateConfigurationSettingsError::InsufficientPrivileges(_) => {
+                "InsufficientPrivilegesException"
+            }
+            ValidateConfigurationSettingsError::TooManyBuckets(_) => "TooManyBucketsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Elastic Beanstalk API. Elastic Beanstalk clients implement this trait.
 #[async_trait]
 pub trait ElasticBeanstalk {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1483,6 +1483,17 @@ impl fmt::Display for CancelJobError {
     }
 }
 impl Error for CancelJobError {}
+impl ServiceErrorCode for CancelJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelJobError::AccessDenied(_) => "AccessDeniedException",
+            CancelJobError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            CancelJobError::InternalService(_) => "InternalServiceException",
+            CancelJobError::ResourceInUse(_) => "ResourceInUseException",
+            CancelJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateJob
 #[derive(Debug, PartialEq)]
 pub enum CreateJobError {
@@ -1537,6 +1548,17 @@ impl fmt::Display for CreateJobError {
     }
 }
 impl Error for CreateJobError {}
+impl ServiceErrorCode for CreateJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateJobError::AccessDenied(_) => "AccessDeniedException",
+            CreateJobError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            CreateJobError::InternalService(_) => "InternalServiceException",
+            CreateJobError::LimitExceeded(_) => "LimitExceededException",
+            CreateJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreatePipeline
 #[derive(Debug, PartialEq)]
 pub enum CreatePipelineError {
@@ -1591,6 +1613,17 @@ impl fmt::Display for CreatePipelineError {
     }
 }
 impl Error for CreatePipelineError {}
+impl ServiceErrorCode for CreatePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePipelineError::AccessDenied(_) => "AccessDeniedException",
+            CreatePipelineError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            CreatePipelineError::InternalService(_) => "InternalServiceException",
+            CreatePipelineError::LimitExceeded(_) => "LimitExceededException",
+            CreatePipelineError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreatePreset
 #[derive(Debug, PartialEq)]
 pub enum CreatePresetError {
@@ -1639,6 +1672,16 @@ impl fmt::Display for CreatePresetError {
     }
 }
 impl Error for CreatePresetError {}
+impl ServiceErrorCode for CreatePresetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePresetError::AccessDenied(_) => "AccessDeniedException",
+            CreatePresetError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            CreatePresetError::InternalService(_) => "InternalServiceException",
+            CreatePresetError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DeletePipeline
 #[derive(Debug, PartialEq)]
 pub enum DeletePipelineError {
@@ -1693,6 +1736,17 @@ impl fmt::Display for DeletePipelineError {
     }
 }
 impl Error for DeletePipelineError {}
+impl ServiceErrorCode for DeletePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePipelineError::AccessDenied(_) => "AccessDeniedException",
+            DeletePipelineError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            DeletePipelineError::InternalService(_) => "InternalServiceException",
+            DeletePipelineError::ResourceInUse(_) => "ResourceInUseException",
+            DeletePipelineError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeletePreset
 #[derive(Debug, PartialEq)]
 pub enum DeletePresetError {
@@ -1741,6 +1795,16 @@ impl fmt::Display for DeletePresetError {
     }
 }
 impl Error for DeletePresetError {}
+impl ServiceErrorCode for DeletePresetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePresetError::AccessDenied(_) => "AccessDeniedException",
+            DeletePresetError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            DeletePresetError::InternalService(_) => "InternalServiceException",
+            DeletePresetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListJobsByPipeline
 #[derive(Debug, PartialEq)]
 pub enum ListJobsByPipelineError {
@@ -1791,6 +1855,16 @@ impl fmt::Display for ListJobsByPipelineError {
     }
 }
 impl Error for ListJobsByPipelineError {}
+impl ServiceErrorCode for ListJobsByPipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJobsByPipelineError::AccessDenied(_) => "AccessDeniedException",
+            ListJobsByPipelineError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            ListJobsByPipelineError::InternalService(_) => "InternalServiceException",
+            ListJobsByPipelineError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListJobsByStatus
 #[derive(Debug, PartialEq)]
 pub enum ListJobsByStatusError {
@@ -1841,6 +1915,16 @@ impl fmt::Display for ListJobsByStatusError {
     }
 }
 impl Error for ListJobsByStatusError {}
+impl ServiceErrorCode for ListJobsByStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJobsByStatusError::AccessDenied(_) => "AccessDeniedException",
+            ListJobsByStatusError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            ListJobsByStatusError::InternalService(_) => "InternalServiceException",
+            ListJobsByStatusError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListPipelines
 #[derive(Debug, PartialEq)]
 pub enum ListPipelinesError {
@@ -1883,6 +1967,15 @@ impl fmt::Display for ListPipelinesError {
     }
 }
 impl Error for ListPipelinesError {}
+impl ServiceErrorCode for ListPipelinesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPipelinesError::AccessDenied(_) => "AccessDeniedException",
+            ListPipelinesError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            ListPipelinesError::InternalService(_) => "InternalServiceException",
+        }
+    }
+}
 /// Errors returned by ListPresets
 #[derive(Debug, PartialEq)]
 pub enum ListPresetsError {
@@ -1925,6 +2018,15 @@ impl fmt::Display for ListPresetsError {
     }
 }
 impl Error for ListPresetsError {}
+impl ServiceErrorCode for ListPresetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPresetsError::AccessDenied(_) => "AccessDeniedException",
+            ListPresetsError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            ListPresetsError::InternalService(_) => "InternalServiceException",
+        }
+    }
+}
 /// Errors returned by ReadJob
 #[derive(Debug, PartialEq)]
 pub enum ReadJobError {
@@ -1973,6 +2075,16 @@ impl fmt::Display for ReadJobError {
     }
 }
 impl Error for ReadJobError {}
+impl ServiceErrorCode for ReadJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ReadJobError::AccessDenied(_) => "AccessDeniedException",
+            ReadJobError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            ReadJobError::InternalService(_) => "InternalServiceException",
+            ReadJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ReadPipeline
 #[derive(Debug, PartialEq)]
 pub enum ReadPipelineError {
@@ -2021,6 +2133,16 @@ impl fmt::Display for ReadPipelineError {
     }
 }
 impl Error for ReadPipelineError {}
+impl ServiceErrorCode for ReadPipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ReadPipelineError::AccessDenied(_) => "AccessDeniedException",
+            ReadPipelineError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            ReadPipelineError::InternalService(_) => "InternalServiceException",
+            ReadPipelineError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ReadPreset
 #[derive(Debug, PartialEq)]
 pub enum ReadPresetError {
@@ -2069,6 +2191,16 @@ impl fmt::Display for ReadPresetError {
     }
 }
 impl Error for ReadPresetError {}
+impl ServiceErrorCode for ReadPresetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ReadPresetError::AccessDenied(_) => "AccessDeniedException",
+            ReadPresetError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            ReadPresetError::InternalService(_) => "InternalServiceException",
+            ReadPresetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TestRole
 #[derive(Debug, PartialEq)]
 pub enum TestRoleError {
@@ -2117,6 +2249,16 @@ impl fmt::Display for TestRoleError {
     }
 }
 impl Error for TestRoleError {}
+impl ServiceErrorCode for TestRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TestRoleError::AccessDenied(_) => "AccessDeniedException",
+            TestRoleError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            TestRoleError::InternalService(_) => "InternalServiceException",
+            TestRoleError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdatePipeline
 #[derive(Debug, PartialEq)]
 pub enum UpdatePipelineError {
@@ -2171,6 +2313,17 @@ impl fmt::Display for UpdatePipelineError {
     }
 }
 impl Error for UpdatePipelineError {}
+impl ServiceErrorCode for UpdatePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePipelineError::AccessDenied(_) => "AccessDeniedException",
+            UpdatePipelineError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            UpdatePipelineError::InternalService(_) => "InternalServiceException",
+            UpdatePipelineError::ResourceInUse(_) => "ResourceInUseException",
+            UpdatePipelineError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdatePipelineNotifications
 #[derive(Debug, PartialEq)]
 pub enum UpdatePipelineNotificationsError {
@@ -2239,6 +2392,19 @@ impl fmt::Display for UpdatePipelineNotificationsError {
     }
 }
 impl Error for UpdatePipelineNotificationsError {}
+impl ServiceErrorCode for UpdatePipelineNotificationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePipelineNotificationsError::AccessDenied(_) => "AccessDeniedException",
+            UpdatePipelineNotificationsError::IncompatibleVersion(_) => {
+                "IncompatibleVersionException"
+            }
+            UpdatePipelineNotificationsError::InternalService(_) => "InternalServiceException",
+            UpdatePipelineNotificationsError::ResourceInUse(_) => "ResourceInUseException",
+            UpdatePipelineNotificationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdatePipelineStatus
 #[derive(Debug, PartialEq)]
 pub enum UpdatePipelineStatusError {
@@ -2299,6 +2465,17 @@ impl fmt::Display for UpdatePipelineStatusError {
     }
 }
 impl Error for UpdatePipelineStatusError {}
+impl ServiceErrorCode for UpdatePipelineStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePipelineStatusError::AccessDenied(_) => "AccessDeniedException",
+            UpdatePipelineStatusError::IncompatibleVersion(_) => "IncompatibleVersionException",
+            UpdatePipelineStatusError::InternalService(_) => "InternalServiceException",
+            UpdatePipelineStatusError::ResourceInUse(_) => "ResourceInUseException",
+            UpdatePipelineStatusError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Elastic Transcoder API. Amazon Elastic Transcoder clients implement this trait.
 #[async_trait]
 pub trait Ets {
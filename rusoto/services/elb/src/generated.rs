@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -4170,6 +4170,15 @@ impl fmt::Display for AddTagsError {
     }
 }
 impl Error for AddTagsError {}
+impl ServiceErrorCode for AddTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+            AddTagsError::DuplicateTagKeys(_) => "DuplicateTagKeys",
+            AddTagsError::TooManyTags(_) => "TooManyTags",
+        }
+    }
+}
 /// Errors returned by ApplySecurityGroupsToLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum ApplySecurityGroupsToLoadBalancerError {
@@ -4244,6 +4253,21 @@ impl fmt::Display for ApplySecurityGroupsToLoadBalancerError {
     }
 }
 impl Error for ApplySecurityGroupsToLoadBalancerError {}
+impl ServiceErrorCode for ApplySecurityGroupsToLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ApplySecurityGroupsToLoadBalancerError::AccessPointNotFound(_) => {
+                "LoadBalancerNotFound"
+            }
+            ApplySecurityGroupsToLoadBalancerError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+            ApplySecurityGroupsToLoadBalancerError::InvalidSecurityGroup(_) => {
+                "InvalidSecurityGroup"
+            }
+        }
+    }
+}
 /// Errors returned by AttachLoadBalancerToSubnets
 #[derive(Debug, PartialEq)]
 pub enum AttachLoadBalancerToSubnetsError {
@@ -4322,6 +4346,18 @@ impl fmt::Display for AttachLoadBalancerToSubnetsError {
     }
 }
 impl Error for AttachLoadBalancerToSubnetsError {}
+impl ServiceErrorCode for AttachLoadBalancerToSubnetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachLoadBalancerToSubnetsError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+            AttachLoadBalancerToSubnetsError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+            AttachLoadBalancerToSubnetsError::InvalidSubnet(_) => "InvalidSubnet",
+            AttachLoadBalancerToSubnetsError::SubnetNotFound(_) => "SubnetNotFound",
+        }
+    }
+}
 /// Errors returned by ConfigureHealthCheck
 #[derive(Debug, PartialEq)]
 pub enum ConfigureHealthCheckError {
@@ -4366,6 +4402,13 @@ impl fmt::Display for ConfigureHealthCheckError {
     }
 }
 impl Error for ConfigureHealthCheckError {}
+impl ServiceErrorCode for ConfigureHealthCheckError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ConfigureHealthCheckError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+        }
+    }
+}
 /// Errors returned by CreateAppCookieStickinessPolicy
 #[derive(Debug, PartialEq)]
 pub enum CreateAppCookieStickinessPolicyError {
@@ -4452,6 +4495,18 @@ impl fmt::Display for CreateAppCookieStickinessPolicyError {
     }
 }
 impl Error for CreateAppCookieStickinessPolicyError {}
+impl ServiceErrorCode for CreateAppCookieStickinessPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAppCookieStickinessPolicyError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+            CreateAppCookieStickinessPolicyError::DuplicatePolicyName(_) => "DuplicatePolicyName",
+            CreateAppCookieStickinessPolicyError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+            CreateAppCookieStickinessPolicyError::TooManyPolicies(_) => "TooManyPolicies",
+        }
+    }
+}
 /// Errors returned by CreateLBCookieStickinessPolicy
 #[derive(Debug, PartialEq)]
 pub enum CreateLBCookieStickinessPolicyError {
@@ -4538,6 +4593,18 @@ impl fmt::Display for CreateLBCookieStickinessPolicyError {
     }
 }
 impl Error for CreateLBCookieStickinessPolicyError {}
+impl ServiceErrorCode for CreateLBCookieStickinessPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLBCookieStickinessPolicyError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+            CreateLBCookieStickinessPolicyError::DuplicatePolicyName(_) => "DuplicatePolicyName",
+            CreateLBCookieStickinessPolicyError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+            CreateLBCookieStickinessPolicyError::TooManyPolicies(_) => "TooManyPolicies",
+        }
+    }
+}
 /// Errors returned by CreateLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum CreateLoadBalancerError {
@@ -4674,6 +4741,26 @@ impl fmt::Display for CreateLoadBalancerError {
     }
 }
 impl Error for CreateLoadBalancerError {}
+impl ServiceErrorCode for CreateLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLoadBalancerError::CertificateNotFound(_) => "CertificateNotFound",
+            CreateLoadBalancerError::DuplicateAccessPointName(_) => "DuplicateLoadBalancerName",
+            CreateLoadBalancerError::DuplicateTagKeys(_) => "DuplicateTagKeys",
+            CreateLoadBalancerError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+            CreateLoadBalancerError::InvalidScheme(_) => "InvalidScheme",
+            CreateLoadBalancerError::InvalidSecurityGroup(_) => "InvalidSecurityGroup",
+            CreateLoadBalancerError::InvalidSubnet(_) => "InvalidSubnet",
+            CreateLoadBalancerError::OperationNotPermitted(_) => "OperationNotPermitted",
+            CreateLoadBalancerError::SubnetNotFound(_) => "SubnetNotFound",
+            CreateLoadBalancerError::TooManyAccessPoints(_) => "TooManyLoadBalancers",
+            CreateLoadBalancerError::TooManyTags(_) => "TooManyTags",
+            CreateLoadBalancerError::UnsupportedProtocol(_) => "UnsupportedProtocol",
+        }
+    }
+}
 /// Errors returned by CreateLoadBalancerListeners
 #[derive(Debug, PartialEq)]
 pub enum CreateLoadBalancerListenersError {
@@ -4772,6 +4859,19 @@ impl fmt::Display for CreateLoadBalancerListenersError {
     }
 }
 impl Error for CreateLoadBalancerListenersError {}
+impl ServiceErrorCode for CreateLoadBalancerListenersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLoadBalancerListenersError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+            CreateLoadBalancerListenersError::CertificateNotFound(_) => "CertificateNotFound",
+            CreateLoadBalancerListenersError::DuplicateListener(_) => "DuplicateListener",
+            CreateLoadBalancerListenersError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+            CreateLoadBalancerListenersError::UnsupportedProtocol(_) => "UnsupportedProtocol",
+        }
+    }
+}
 /// Errors returned by CreateLoadBalancerPolicy
 #[derive(Debug, PartialEq)]
 pub enum CreateLoadBalancerPolicyError {
@@ -4856,6 +4956,19 @@ impl fmt::Display for CreateLoadBalancerPolicyError {
     }
 }
 impl Error for CreateLoadBalancerPolicyError {}
+impl ServiceErrorCode for CreateLoadBalancerPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLoadBalancerPolicyError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+            CreateLoadBalancerPolicyError::DuplicatePolicyName(_) => "DuplicatePolicyName",
+            CreateLoadBalancerPolicyError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+            CreateLoadBalancerPolicyError::PolicyTypeNotFound(_) => "PolicyTypeNotFound",
+            CreateLoadBalancerPolicyError::TooManyPolicies(_) => "TooManyPolicies",
+        }
+    }
+}
 /// Errors returned by DeleteLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum DeleteLoadBalancerError {}
@@ -4890,6 +5003,11 @@ impl fmt::Display for DeleteLoadBalancerError {
     }
 }
 impl Error for DeleteLoadBalancerError {}
+impl ServiceErrorCode for DeleteLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteLoadBalancerListeners
 #[derive(Debug, PartialEq)]
 pub enum DeleteLoadBalancerListenersError {
@@ -4940,6 +5058,13 @@ impl fmt::Display for DeleteLoadBalancerListenersError {
     }
 }
 impl Error for DeleteLoadBalancerListenersError {}
+impl ServiceErrorCode for DeleteLoadBalancerListenersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLoadBalancerListenersError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteLoadBalancerPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteLoadBalancerPolicyError {
@@ -4998,6 +5123,16 @@ impl fmt::Display for DeleteLoadBalancerPolicyError {
     }
 }
 impl Error for DeleteLoadBalancerPolicyError {}
+impl ServiceErrorCode for DeleteLoadBalancerPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLoadBalancerPolicyError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+            DeleteLoadBalancerPolicyError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+        }
+    }
+}
 /// Errors returned by DeregisterInstancesFromLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum DeregisterInstancesFromLoadBalancerError {
@@ -5060,6 +5195,16 @@ impl fmt::Display for DeregisterInstancesFromLoadBalancerError {
     }
 }
 impl Error for DeregisterInstancesFromLoadBalancerError {}
+impl ServiceErrorCode for DeregisterInstancesFromLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterInstancesFromLoadBalancerError::AccessPointNotFound(_) => {
+                "LoadBalancerNotFound"
+            }
+            DeregisterInstancesFromLoadBalancerError::InvalidEndPoint(_) => "InvalidInstance",
+        }
+    }
+}
 /// Errors returned by DescribeAccountLimits
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountLimitsError {}
@@ -5094,6 +5239,11 @@ impl fmt::Display for DescribeAccountLimitsError {
     }
 }
 impl Error for DescribeAccountLimitsError {}
+impl ServiceErrorCode for DescribeAccountLimitsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeInstanceHealth
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstanceHealthError {
@@ -5146,6 +5296,14 @@ impl fmt::Display for DescribeInstanceHealthError {
     }
 }
 impl Error for DescribeInstanceHealthError {}
+impl ServiceErrorCode for DescribeInstanceHealthError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInstanceHealthError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+            DescribeInstanceHealthError::InvalidEndPoint(_) => "InvalidInstance",
+        }
+    }
+}
 /// Errors returned by DescribeLoadBalancerAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancerAttributesError {
@@ -5208,6 +5366,16 @@ impl fmt::Display for DescribeLoadBalancerAttributesError {
     }
 }
 impl Error for DescribeLoadBalancerAttributesError {}
+impl ServiceErrorCode for DescribeLoadBalancerAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLoadBalancerAttributesError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+            DescribeLoadBalancerAttributesError::LoadBalancerAttributeNotFound(_) => {
+                "LoadBalancerAttributeNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeLoadBalancerPolicies
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancerPoliciesError {
@@ -5266,6 +5434,14 @@ impl fmt::Display for DescribeLoadBalancerPoliciesError {
     }
 }
 impl Error for DescribeLoadBalancerPoliciesError {}
+impl ServiceErrorCode for DescribeLoadBalancerPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLoadBalancerPoliciesError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+            DescribeLoadBalancerPoliciesError::PolicyNotFound(_) => "PolicyNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeLoadBalancerPolicyTypes
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancerPolicyTypesError {
@@ -5316,6 +5492,13 @@ impl fmt::Display for DescribeLoadBalancerPolicyTypesError {
     }
 }
 impl Error for DescribeLoadBalancerPolicyTypesError {}
+impl ServiceErrorCode for DescribeLoadBalancerPolicyTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLoadBalancerPolicyTypesError::PolicyTypeNotFound(_) => "PolicyTypeNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeLoadBalancers
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancersError {
@@ -5368,6 +5551,14 @@ impl fmt::Display for DescribeLoadBalancersError {
     }
 }
 impl Error for DescribeLoadBalancersError {}
+impl ServiceErrorCode for DescribeLoadBalancersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLoadBalancersError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+            DescribeLoadBalancersError::DependencyThrottle(_) => "DependencyThrottle",
+        }
+    }
+}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
 pub enum DescribeTagsError {
@@ -5412,6 +5603,13 @@ impl fmt::Display for DescribeTagsError {
     }
 }
 impl Error for DescribeTagsError {}
+impl ServiceErrorCode for DescribeTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTagsError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+        }
+    }
+}
 /// Errors returned by DetachLoadBalancerFromSubnets
 #[derive(Debug, PartialEq)]
 pub enum DetachLoadBalancerFromSubnetsError {
@@ -5474,6 +5672,16 @@ impl fmt::Display for DetachLoadBalancerFromSubnetsError {
     }
 }
 impl Error for DetachLoadBalancerFromSubnetsError {}
+impl ServiceErrorCode for DetachLoadBalancerFromSubnetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachLoadBalancerFromSubnetsError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+            DetachLoadBalancerFromSubnetsError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+        }
+    }
+}
 /// Errors returned by DisableAvailabilityZonesForLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum DisableAvailabilityZonesForLoadBalancerError {
@@ -5534,6 +5742,18 @@ impl fmt::Display for DisableAvailabilityZonesForLoadBalancerError {
     }
 }
 impl Error for DisableAvailabilityZonesForLoadBalancerError {}
+impl ServiceErrorCode for DisableAvailabilityZonesForLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableAvailabilityZonesForLoadBalancerError::AccessPointNotFound(_) => {
+                "LoadBalancerNotFound"
+            }
+            DisableAvailabilityZonesForLoadBalancerError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+        }
+    }
+}
 /// Errors returned by EnableAvailabilityZonesForLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum EnableAvailabilityZonesForLoadBalancerError {
@@ -5584,6 +5804,15 @@ impl fmt::Display for EnableAvailabilityZonesForLoadBalancerError {
     }
 }
 impl Error for EnableAvailabilityZonesForLoadBalancerError {}
+impl ServiceErrorCode for EnableAvailabilityZonesForLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableAvailabilityZonesForLoadBalancerError::AccessPointNotFound(_) => {
+                "LoadBalancerNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyLoadBalancerAttributes
 #[derive(Debug, PartialEq)]
 pub enum ModifyLoadBalancerAttributesError {
@@ -5658,6 +5887,19 @@ impl fmt::Display for ModifyLoadBalancerAttributesError {
     }
 }
 impl Error for ModifyLoadBalancerAttributesError {}
+impl ServiceErrorCode for ModifyLoadBalancerAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyLoadBalancerAttributesError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+            ModifyLoadBalancerAttributesError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+            ModifyLoadBalancerAttributesError::LoadBalancerAttributeNotFound(_) => {
+                "LoadBalancerAttributeNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by RegisterInstancesWithLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum RegisterInstancesWithLoadBalancerError {
@@ -5720,6 +5962,16 @@ impl fmt::Display for RegisterInstancesWithLoadBalancerError {
     }
 }
 impl Error for RegisterInstancesWithLoadBalancerError {}
+impl ServiceErrorCode for RegisterInstancesWithLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterInstancesWithLoadBalancerError::AccessPointNotFound(_) => {
+                "LoadBalancerNotFound"
+            }
+            RegisterInstancesWithLoadBalancerError::InvalidEndPoint(_) => "InvalidInstance",
+        }
+    }
+}
 /// Errors returned by RemoveTags
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsError {
@@ -5764,6 +6016,13 @@ impl fmt::Display for RemoveTagsError {
     }
 }
 impl Error for RemoveTagsError {}
+impl ServiceErrorCode for RemoveTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsError::AccessPointNotFound(_) => "LoadBalancerNotFound",
+        }
+    }
+}
 /// Errors returned by SetLoadBalancerListenerSSLCertificate
 #[derive(Debug, PartialEq)]
 pub enum SetLoadBalancerListenerSSLCertificateError {
@@ -5862,6 +6121,25 @@ impl fmt::Display for SetLoadBalancerListenerSSLCertificateError {
     }
 }
 impl Error for SetLoadBalancerListenerSSLCertificateError {}
+impl ServiceErrorCode for SetLoadBalancerListenerSSLCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetLoadBalancerListenerSSLCertificateError::AccessPointNotFound(_) => {
+                "LoadBalancerNotFound"
+            }
+            SetLoadBalancerListenerSSLCertificateError::CertificateNotFound(_) => {
+                "CertificateNotFound"
+            }
+            SetLoadBalancerListenerSSLCertificateError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+            SetLoadBalancerListenerSSLCertificateError::ListenerNotFound(_) => "ListenerNotFound",
+            SetLoadBalancerListenerSSLCertificateError::UnsupportedProtocol(_) => {
+                "UnsupportedProtocol"
+            }
+        }
+    }
+}
 /// Errors returned by SetLoadBalancerPoliciesForBackendServer
 #[derive(Debug, PartialEq)]
 pub enum SetLoadBalancerPoliciesForBackendServerError {
@@ -5934,6 +6212,19 @@ impl fmt::Display for SetLoadBalancerPoliciesForBackendServerError {
     }
 }
 impl Error for SetLoadBalancerPoliciesForBackendServerError {}
+impl ServiceErrorCode for SetLoadBalancerPoliciesForBackendServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetLoadBalancerPoliciesForBackendServerError::AccessPointNotFound(_) => {
+                "LoadBalancerNotFound"
+            }
+            SetLoadBalancerPoliciesForBackendServerError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+            SetLoadBalancerPoliciesForBackendServerError::PolicyNotFound(_) => "PolicyNotFound",
+        }
+    }
+}
 /// Errors returned by SetLoadBalancerPoliciesOfListener
 #[derive(Debug, PartialEq)]
 pub enum SetLoadBalancerPoliciesOfListenerError {
@@ -6020,6 +6311,20 @@ impl fmt::Display for SetLoadBalancerPoliciesOfListenerError {
     }
 }
 impl Error for SetLoadBalancerPoliciesOfListenerError {}
+impl ServiceErrorCode for SetLoadBalancerPoliciesOfListenerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetLoadBalancerPoliciesOfListenerError::AccessPointNotFound(_) => {
+                "LoadBalancerNotFound"
+            }
+            SetLoadBalancerPoliciesOfListenerError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+            SetLoadBalancerPoliciesOfListenerError::ListenerNotFound(_) => "ListenerNotFound",
+            SetLoadBalancerPoliciesOfListenerError::PolicyNotFound(_) => "PolicyNotFound",
+        }
+    }
+}
 /// Trait representing the capabilities of the Elastic Load Balancing API. Elastic Load Balancing clients implement this trait.
 #[async_trait]
 pub trait Elb {
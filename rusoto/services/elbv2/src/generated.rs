@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -6486,6 +6486,15 @@ impl fmt::Display for AddListenerCertificatesError {
     }
 }
 impl Error for AddListenerCertificatesError {}
+impl ServiceErrorCode for AddListenerCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddListenerCertificatesError::CertificateNotFound(_) => "CertificateNotFound",
+            AddListenerCertificatesError::ListenerNotFound(_) => "ListenerNotFound",
+            AddListenerCertificatesError::TooManyCertificates(_) => "TooManyCertificates",
+        }
+    }
+}
 /// Errors returned by AddTags
 #[derive(Debug, PartialEq)]
 pub enum AddTagsError {
@@ -6554,6 +6563,16 @@ impl fmt::Display for AddTagsError {
     }
 }
 impl Error for AddTagsError {}
+impl ServiceErrorCode for AddTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsError::DuplicateTagKeys(_) => "DuplicateTagKeys",
+            AddTagsError::LoadBalancerNotFound(_) => "LoadBalancerNotFound",
+            AddTagsError::TargetGroupNotFound(_) => "TargetGroupNotFound",
+            AddTagsError::TooManyTags(_) => "TooManyTags",
+        }
+    }
+}
 /// Errors returned by CreateListener
 #[derive(Debug, PartialEq)]
 pub enum CreateListenerError {
@@ -6742,6 +6761,34 @@ impl fmt::Display for CreateListenerError {
     }
 }
 impl Error for CreateListenerError {}
+impl ServiceErrorCode for CreateListenerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateListenerError::ALPNPolicyNotSupported(_) => "ALPNPolicyNotFound",
+            CreateListenerError::CertificateNotFound(_) => "CertificateNotFound",
+            CreateListenerError::DuplicateListener(_) => "DuplicateListener",
+            CreateListenerError::IncompatibleProtocols(_) => "IncompatibleProtocols",
+            CreateListenerError::InvalidConfigurationRequest(_) => "InvalidConfigurationRequest",
+            CreateListenerError::InvalidLoadBalancerAction(_) => "InvalidLoadBalancerAction",
+            CreateListenerError::LoadBalancerNotFound(_) => "LoadBalancerNotFound",
+            CreateListenerError::SSLPolicyNotFound(_) => "SSLPolicyNotFound",
+            CreateListenerError::TargetGroupAssociationLimit(_) => "TargetGroupAssociationLimit",
+            CreateListenerError::TargetGroupNotFound(_) => "TargetGroupNotFound",
+            CreateListenerError::TooManyActions(_) => "TooManyActions",
+            CreateListenerError::TooManyCertificates(_) => "TooManyCertificates",
+            CreateListenerError::TooManyListeners(_) => "TooManyListeners",
+            CreateListenerError::TooManyRegistrationsForTargetId(_) => {
+                "TooManyRegistrationsForTargetId"
+            }
+            CreateListenerError::TooManyTags(_) => "TooManyTags",
+            CreateListenerError::TooManyTargets(_) => "TooManyTargets",
+            CreateListenerError::TooManyUniqueTargetGroupsPerLoadBalancer(_) => {
+                "TooManyUniqueTargetGroupsPerLoadBalancer"
+            }
+            CreateListenerError::UnsupportedProtocol(_) => "UnsupportedProtocol",
+        }
+    }
+}
 /// Errors returned by CreateLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum CreateLoadBalancerError {
@@ -6892,6 +6939,29 @@ impl fmt::Display for CreateLoadBalancerError {
     }
 }
 impl Error for CreateLoadBalancerError {}
+impl ServiceErrorCode for CreateLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLoadBalancerError::AllocationIdNotFound(_) => "AllocationIdNotFound",
+            CreateLoadBalancerError::AvailabilityZoneNotSupported(_) => {
+                "AvailabilityZoneNotSupported"
+            }
+            CreateLoadBalancerError::DuplicateLoadBalancerName(_) => "DuplicateLoadBalancerName",
+            CreateLoadBalancerError::DuplicateTagKeys(_) => "DuplicateTagKeys",
+            CreateLoadBalancerError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+            CreateLoadBalancerError::InvalidScheme(_) => "InvalidScheme",
+            CreateLoadBalancerError::InvalidSecurityGroup(_) => "InvalidSecurityGroup",
+            CreateLoadBalancerError::InvalidSubnet(_) => "InvalidSubnet",
+            CreateLoadBalancerError::OperationNotPermitted(_) => "OperationNotPermitted",
+            CreateLoadBalancerError::ResourceInUse(_) => "ResourceInUse",
+            CreateLoadBalancerError::SubnetNotFound(_) => "SubnetNotFound",
+            CreateLoadBalancerError::TooManyLoadBalancers(_) => "TooManyLoadBalancers",
+            CreateLoadBalancerError::TooManyTags(_) => "TooManyTags",
+        }
+    }
+}
 /// Errors returned by CreateRule
 #[derive(Debug, PartialEq)]
 pub enum CreateRuleError {
@@ -7052,6 +7122,31 @@ impl fmt::Display for CreateRuleError {
     }
 }
 impl Error for CreateRuleError {}
+impl ServiceErrorCode for CreateRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRuleError::IncompatibleProtocols(_) => "IncompatibleProtocols",
+            CreateRuleError::InvalidConfigurationRequest(_) => "InvalidConfigurationRequest",
+            CreateRuleError::InvalidLoadBalancerAction(_) => "InvalidLoadBalancerAction",
+            CreateRuleError::ListenerNotFound(_) => "ListenerNotFound",
+            CreateRuleError::PriorityInUse(_) => "PriorityInUse",
+            CreateRuleError::TargetGroupAssociationLimit(_) => "TargetGroupAssociationLimit",
+            CreateRuleError::TargetGroupNotFound(_) => "TargetGroupNotFound",
+            CreateRuleError::TooManyActions(_) => "TooManyActions",
+            CreateRuleError::TooManyRegistrationsForTargetId(_) => {
+                "TooManyRegistrationsForTargetId"
+            }
+            CreateRuleError::TooManyRules(_) => "TooManyRules",
+            CreateRuleError::TooManyTags(_) => "TooManyTags",
+            CreateRuleError::TooManyTargetGroups(_) => "TooManyTargetGroups",
+            CreateRuleError::TooManyTargets(_) => "TooManyTargets",
+            CreateRuleError::TooManyUniqueTargetGroupsPerLoadBalancer(_) => {
+                "TooManyUniqueTargetGroupsPerLoadBalancer"
+            }
+            CreateRuleError::UnsupportedProtocol(_) => "UnsupportedProtocol",
+        }
+    }
+}
 /// Errors returned by CreateTargetGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateTargetGroupError {
@@ -7124,6 +7219,16 @@ impl fmt::Display for CreateTargetGroupError {
     }
 }
 impl Error for CreateTargetGroupError {}
+impl ServiceErrorCode for CreateTargetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTargetGroupError::DuplicateTargetGroupName(_) => "DuplicateTargetGroupName",
+            CreateTargetGroupError::InvalidConfigurationRequest(_) => "InvalidConfigurationRequest",
+            CreateTargetGroupError::TooManyTags(_) => "TooManyTags",
+            CreateTargetGroupError::TooManyTargetGroups(_) => "TooManyTargetGroups",
+        }
+    }
+}
 /// Errors returned by DeleteListener
 #[derive(Debug, PartialEq)]
 pub enum DeleteListenerError {
@@ -7168,6 +7273,13 @@ impl fmt::Display for DeleteListenerError {
     }
 }
 impl Error for DeleteListenerError {}
+impl ServiceErrorCode for DeleteListenerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteListenerError::ListenerNotFound(_) => "ListenerNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum DeleteLoadBalancerError {
@@ -7228,6 +7340,15 @@ impl fmt::Display for DeleteLoadBalancerError {
     }
 }
 impl Error for DeleteLoadBalancerError {}
+impl ServiceErrorCode for DeleteLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLoadBalancerError::LoadBalancerNotFound(_) => "LoadBalancerNotFound",
+            DeleteLoadBalancerError::OperationNotPermitted(_) => "OperationNotPermitted",
+            DeleteLoadBalancerError::ResourceInUse(_) => "ResourceInUse",
+        }
+    }
+}
 /// Errors returned by DeleteRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteRuleError {
@@ -7280,6 +7401,14 @@ impl fmt::Display for DeleteRuleError {
     }
 }
 impl Error for DeleteRuleError {}
+impl ServiceErrorCode for DeleteRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRuleError::OperationNotPermitted(_) => "OperationNotPermitted",
+            DeleteRuleError::RuleNotFound(_) => "RuleNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteTargetGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteTargetGroupError {
@@ -7324,6 +7453,13 @@ impl fmt::Display for DeleteTargetGroupError {
     }
 }
 impl Error for DeleteTargetGroupError {}
+impl ServiceErrorCode for DeleteTargetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTargetGroupError::ResourceInUse(_) => "ResourceInUse",
+        }
+    }
+}
 /// Errors returned by DeregisterTargets
 #[derive(Debug, PartialEq)]
 pub enum DeregisterTargetsError {
@@ -7376,6 +7512,14 @@ impl fmt::Display for DeregisterTargetsError {
     }
 }
 impl Error for DeregisterTargetsError {}
+impl ServiceErrorCode for DeregisterTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterTargetsError::InvalidTarget(_) => "InvalidTarget",
+            DeregisterTargetsError::TargetGroupNotFound(_) => "TargetGroupNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeAccountLimits
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountLimitsError {}
@@ -7410,6 +7554,11 @@ impl fmt::Display for DescribeAccountLimitsError {
     }
 }
 impl Error for DescribeAccountLimitsError {}
+impl ServiceErrorCode for DescribeAccountLimitsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeListenerCertificates
 #[derive(Debug, PartialEq)]
 pub enum DescribeListenerCertificatesError {
@@ -7460,6 +7609,13 @@ impl fmt::Display for DescribeListenerCertificatesError {
     }
 }
 impl Error for DescribeListenerCertificatesError {}
+impl ServiceErrorCode for DescribeListenerCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeListenerCertificatesError::ListenerNotFound(_) => "ListenerNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeListeners
 #[derive(Debug, PartialEq)]
 pub enum DescribeListenersError {
@@ -7520,6 +7676,15 @@ impl fmt::Display for DescribeListenersError {
     }
 }
 impl Error for DescribeListenersError {}
+impl ServiceErrorCode for DescribeListenersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeListenersError::ListenerNotFound(_) => "ListenerNotFound",
+            DescribeListenersError::LoadBalancerNotFound(_) => "LoadBalancerNotFound",
+            DescribeListenersError::UnsupportedProtocol(_) => "UnsupportedProtocol",
+        }
+    }
+}
 /// Errors returned by DescribeLoadBalancerAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancerAttributesError {
@@ -7570,6 +7735,13 @@ impl fmt::Display for DescribeLoadBalancerAttributesError {
     }
 }
 impl Error for DescribeLoadBalancerAttributesError {}
+impl ServiceErrorCode for DescribeLoadBalancerAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLoadBalancerAttributesError::LoadBalancerNotFound(_) => "LoadBalancerNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeLoadBalancers
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBalancersError {
@@ -7614,6 +7786,13 @@ impl fmt::Display for DescribeLoadBalancersError {
     }
 }
 impl Error for DescribeLoadBalancersError {}
+impl ServiceErrorCode for DescribeLoadBalancersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLoadBalancersError::LoadBalancerNotFound(_) => "LoadBalancerNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeRules
 #[derive(Debug, PartialEq)]
 pub enum DescribeRulesError {
@@ -7674,6 +7853,15 @@ impl fmt::Display for DescribeRulesError {
     }
 }
 impl Error for DescribeRulesError {}
+impl ServiceErrorCode for DescribeRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRulesError::ListenerNotFound(_) => "ListenerNotFound",
+            DescribeRulesError::RuleNotFound(_) => "RuleNotFound",
+            DescribeRulesError::UnsupportedProtocol(_) => "UnsupportedProtocol",
+        }
+    }
+}
 /// Errors returned by DescribeSSLPolicies
 #[derive(Debug, PartialEq)]
 pub enum DescribeSSLPoliciesError {
@@ -7718,6 +7906,13 @@ impl fmt::Display for DescribeSSLPoliciesError {
     }
 }
 impl Error for DescribeSSLPoliciesError {}
+impl ServiceErrorCode for DescribeSSLPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSSLPoliciesError::SSLPolicyNotFound(_) => "SSLPolicyNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
 pub enum DescribeTagsError {
@@ -7786,6 +7981,16 @@ impl fmt::Display for DescribeTagsError {
     }
 }
 impl Error for DescribeTagsError {}
+impl ServiceErrorCode for DescribeTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTagsError::ListenerNotFound(_) => "ListenerNotFound",
+            DescribeTagsError::LoadBalancerNotFound(_) => "LoadBalancerNotFound",
+            DescribeTagsError::RuleNotFound(_) => "RuleNotFound",
+            DescribeTagsError::TargetGroupNotFound(_) => "TargetGroupNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeTargetGroupAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeTargetGroupAttributesError {
@@ -7836,6 +8041,13 @@ impl fmt::Display for DescribeTargetGroupAttributesError {
     }
 }
 impl Error for DescribeTargetGroupAttributesError {}
+impl ServiceErrorCode for DescribeTargetGroupAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTargetGroupAttributesError::TargetGroupNotFound(_) => "TargetGroupNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeTargetGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeTargetGroupsError {
@@ -7888,6 +8100,14 @@ impl fmt::Display for DescribeTargetGroupsError {
     }
 }
 impl Error for DescribeTargetGroupsError {}
+impl ServiceErrorCode for DescribeTargetGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTargetGroupsError::LoadBalancerNotFound(_) => "LoadBalancerNotFound",
+            DescribeTargetGroupsError::TargetGroupNotFound(_) => "TargetGroupNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeTargetHealth
 #[derive(Debug, PartialEq)]
 pub enum DescribeTargetHealthError {
@@ -7948,6 +8168,15 @@ impl fmt::Display for DescribeTargetHealthError {
     }
 }
 impl Error for DescribeTargetHealthError {}
+impl ServiceErrorCode for DescribeTargetHealthError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTargetHealthError::HealthUnavailable(_) => "HealthUnavailable",
+            DescribeTargetHealthError::InvalidTarget(_) => "InvalidTarget",
+            DescribeTargetHealthError::TargetGroupNotFound(_) => "TargetGroupNotFound",
+        }
+    }
+}
 /// Errors returned by ModifyListener
 #[derive(Debug, PartialEq)]
 pub enum ModifyListenerError {
@@ -8128,6 +8357,33 @@ impl fmt::Display for ModifyListenerError {
     }
 }
 impl Error for ModifyListenerError {}
+impl ServiceErrorCode for ModifyListenerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyListenerError::ALPNPolicyNotSupported(_) => "ALPNPolicyNotFound",
+            ModifyListenerError::CertificateNotFound(_) => "CertificateNotFound",
+            ModifyListenerError::DuplicateListener(_) => "DuplicateListener",
+            ModifyListenerError::IncompatibleProtocols(_) => "IncompatibleProtocols",
+            ModifyListenerError::InvalidConfigurationRequest(_) => "InvalidConfigurationRequest",
+            ModifyListenerError::InvalidLoadBalancerAction(_) => "InvalidLoadBalancerAction",
+            ModifyListenerError::ListenerNotFound(_) => "ListenerNotFound",
+            ModifyListenerError::SSLPolicyNotFound(_) => "SSLPolicyNotFound",
+            ModifyListenerError::TargetGroupAssociationLimit(_) => "TargetGroupAssociationLimit",
+            ModifyListenerError::TargetGroupNotFound(_) => "TargetGroupNotFound",
+            ModifyListenerError::TooManyActions(_) => "TooManyActions",
+            ModifyListenerError::TooManyCertificates(_) => "TooManyCertificates",
+            ModifyListenerError::TooManyListeners(_) => "TooManyListeners",
+            ModifyListenerError::TooManyRegistrationsForTargetId(_) => {
+                "TooManyRegistrationsForTargetId"
+            }
+            ModifyListenerError::TooManyTargets(_) => "TooManyTargets",
+            ModifyListenerError::TooManyUniqueTargetGroupsPerLoadBalancer(_) => {
+                "TooManyUniqueTargetGroupsPerLoadBalancer"
+            }
+            ModifyListenerError::UnsupportedProtocol(_) => "UnsupportedProtocol",
+        }
+    }
+}
 /// Errors returned by ModifyLoadBalancerAttributes
 #[derive(Debug, PartialEq)]
 pub enum ModifyLoadBalancerAttributesError {
@@ -8190,6 +8446,16 @@ impl fmt::Display for ModifyLoadBalancerAttributesError {
     }
 }
 impl Error for ModifyLoadBalancerAttributesError {}
+impl ServiceErrorCode for ModifyLoadBalancerAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyLoadBalancerAttributesError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+            ModifyLoadBalancerAttributesError::LoadBalancerNotFound(_) => "LoadBalancerNotFound",
+        }
+    }
+}
 /// Errors returned by ModifyRule
 #[derive(Debug, PartialEq)]
 pub enum ModifyRuleError {
@@ -8318,6 +8584,27 @@ impl fmt::Display for ModifyRuleError {
     }
 }
 impl Error for ModifyRuleError {}
+impl ServiceErrorCode for ModifyRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyRuleError::IncompatibleProtocols(_) => "IncompatibleProtocols",
+            ModifyRuleError::InvalidLoadBalancerAction(_) => "InvalidLoadBalancerAction",
+            ModifyRuleError::OperationNotPermitted(_) => "OperationNotPermitted",
+            ModifyRuleError::RuleNotFound(_) => "RuleNotFound",
+            ModifyRuleError::TargetGroupAssociationLimit(_) => "TargetGroupAssociationLimit",
+            ModifyRuleError::TargetGroupNotFound(_) => "TargetGroupNotFound",
+            ModifyRuleError::TooManyActions(_) => "TooManyActions",
+            ModifyRuleError::TooManyRegistrationsForTargetId(_) => {
+                "TooManyRegistrationsForTargetId"
+            }
+            ModifyRuleError::TooManyTargets(_) => "TooManyTargets",
+            ModifyRuleError::TooManyUniqueTargetGroupsPerLoadBalancer(_) => {
+                "TooManyUniqueTargetGroupsPerLoadBalancer"
+            }
+            ModifyRuleError::UnsupportedProtocol(_) => "UnsupportedProtocol",
+        }
+    }
+}
 /// Errors returned by ModifyTargetGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyTargetGroupError {
@@ -8374,6 +8661,14 @@ impl fmt::Display for ModifyTargetGroupError {
     }
 }
 impl Error for ModifyTargetGroupError {}
+impl ServiceErrorCode for ModifyTargetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyTargetGroupError::InvalidConfigurationRequest(_) => "InvalidConfigurationRequest",
+            ModifyTargetGroupError::TargetGroupNotFound(_) => "TargetGroupNotFound",
+        }
+    }
+}
 /// Errors returned by ModifyTargetGroupAttributes
 #[derive(Debug, PartialEq)]
 pub enum ModifyTargetGroupAttributesError {
@@ -8436,6 +8731,16 @@ impl fmt::Display for ModifyTargetGroupAttributesError {
     }
 }
 impl Error for ModifyTargetGroupAttributesError {}
+impl ServiceErrorCode for ModifyTargetGroupAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyTargetGroupAttributesError::InvalidConfigurationRequest(_) => {
+                "InvalidConfigurationRequest"
+            }
+            ModifyTargetGroupAttributesError::TargetGroupNotFound(_) => "TargetGroupNotFound",
+        }
+    }
+}
 /// Errors returned by RegisterTargets
 #[derive(Debug, PartialEq)]
 pub enum RegisterTargetsError {
@@ -8508,6 +8813,18 @@ impl fmt::Display for RegisterTargetsError {
     }
 }
 impl Error for RegisterTargetsError {}
+impl ServiceErrorCode for RegisterTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterTargetsError::InvalidTarget(_) => "InvalidTarget",
+            RegisterTargetsError::TargetGroupNotFound(_) => "TargetGroupNotFound",
+            RegisterTargetsError::TooManyRegistrationsForTargetId(_) => {
+                "TooManyRegistrationsForTargetId"
+            }
+            RegisterTargetsError::TooManyTargets(_) => "TooManyTargets",
+        }
+    }
+}
 /// Errors returned by RemoveListenerCertificates
 #[derive(Debug, PartialEq)]
 pub enum RemoveListenerCertificatesError {
@@ -8566,6 +8883,14 @@ impl fmt::Display for RemoveListenerCertificatesError {
     }
 }
 impl Error for RemoveListenerCertificatesError {}
+impl ServiceErrorCode for RemoveListenerCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveListenerCertificatesError::ListenerNotFound(_) => "ListenerNotFound",
+            RemoveListenerCertificatesError::OperationNotPermitted(_) => "OperationNotPermitted",
+        }
+    }
+}
 /// Errors returned by RemoveTags
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsError {
@@ -8642,6 +8967,17 @@ impl fmt::Display for RemoveTagsError {
     }
 }
 impl Error for RemoveTagsError {}
+impl ServiceErrorCode for RemoveTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsError::ListenerNotFound(_) => "ListenerNotFound",
+            RemoveTagsError::LoadBalancerNotFound(_) => "LoadBalancerNotFound",
+            RemoveTagsError::RuleNotFound(_) => "RuleNotFound",
+            RemoveTagsError::TargetGroupNotFound(_) => "TargetGroupNotFound",
+            RemoveTagsError::TooManyTags(_) => "TooManyTags",
+        }
+    }
+}
 /// Errors returned by SetIpAddressType
 #[derive(Debug, PartialEq)]
 pub enum SetIpAddressTypeError {
@@ -8704,6 +9040,15 @@ impl fmt::Display for SetIpAddressTypeError {
     }
 }
 impl Error for SetIpAddressTypeError {}
+impl ServiceErrorCode for SetIpAddressTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetIpAddressTypeError::InvalidConfigurationRequest(_) => "InvalidConfigurationRequest",
+            SetIpAddressTypeError::InvalidSubnet(_) => "InvalidSubnet",
+            SetIpAddressTypeError::LoadBalancerNotFound(_) => "LoadBalancerNotFound",
+        }
+    }
+}
 /// Errors returned by SetRulePriorities
 #[derive(Debug, PartialEq)]
 pub enum SetRulePrioritiesError {
@@ -8764,6 +9109,15 @@ impl fmt::Display for SetRulePrioritiesError {
     }
 }
 impl Error for SetRulePrioritiesError {}
+impl ServiceErrorCode for SetRulePrioritiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetRulePrioritiesError::OperationNotPermitted(_) => "OperationNotPermitted",
+            SetRulePrioritiesError::PriorityInUse(_) => "PriorityInUse",
+            SetRulePrioritiesError::RuleNotFound(_) => "RuleNotFound",
+        }
+    }
+}
 /// Errors returned by SetSecurityGroups
 #[derive(Debug, PartialEq)]
 pub enum SetSecurityGroupsError {
@@ -8828,6 +9182,15 @@ impl fmt::Display for SetSecurityGroupsError {
     }
 }
 impl Error for SetSecurityGroupsError {}
+impl ServiceErrorCode for SetSecurityGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetSecurityGroupsError::InvalidConfigurationRequest(_) => "InvalidConfigurationRequest",
+            SetSecurityGroupsError::InvalidSecurityGroup(_) => "InvalidSecurityGroup",
+            SetSecurityGroupsError::LoadBalancerNotFound(_) => "LoadBalancerNotFound",
+        }
+    }
+}
 /// Errors returned by SetSubnets
 #[derive(Debug, PartialEq)]
 pub enum SetSubnetsError {
@@ -8912,6 +9275,18 @@ impl fmt::Display for SetSubnetsError {
     }
 }
 impl Error for SetSubnetsError {}
+impl ServiceErrorCode for SetSubnetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetSubnetsError::AllocationIdNotFound(_) => "AllocationIdNotFound",
+            SetSubnetsError::AvailabilityZoneNotSupported(_) => "AvailabilityZoneNotSupported",
+            SetSubnetsError::InvalidConfigurationRequest(_) => "InvalidConfigurationRequest",
+            SetSubnetsError::InvalidSubnet(_) => "InvalidSubnet",
+            SetSubnetsError::LoadBalancerNotFound(_) => "LoadBalancerNotFound",
+            SetSubnetsError::SubnetNotFound(_) => "SubnetNotFound",
+        }
+    }
+}
 /// Trait representing the capabilities of the Elastic Load Balancing v2 API. Elastic Load Balancing v2 clients implement this trait.
 #[async_trait]
 pub trait Elb {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3328,6 +3328,14 @@ impl fmt::Display for AddInstanceFleetError {
     }
 }
 impl Error for AddInstanceFleetError {}
+impl ServiceErrorCode for AddInstanceFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddInstanceFleetError::InternalServer(_) => "InternalServerException",
+            AddInstanceFleetError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by AddInstanceGroups
 #[derive(Debug, PartialEq)]
 pub enum AddInstanceGroupsError {
@@ -3360,6 +3368,13 @@ impl fmt::Display for AddInstanceGroupsError {
     }
 }
 impl Error for AddInstanceGroupsError {}
+impl ServiceErrorCode for AddInstanceGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddInstanceGroupsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by AddJobFlowSteps
 #[derive(Debug, PartialEq)]
 pub enum AddJobFlowStepsError {
@@ -3390,6 +3405,13 @@ impl fmt::Display for AddJobFlowStepsError {
     }
 }
 impl Error for AddJobFlowStepsError {}
+impl ServiceErrorCode for AddJobFlowStepsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddJobFlowStepsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by AddTags
 #[derive(Debug, PartialEq)]
 pub enum AddTagsError {
@@ -3426,6 +3448,14 @@ impl fmt::Display for AddTagsError {
     }
 }
 impl Error for AddTagsError {}
+impl ServiceErrorCode for AddTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsError::InternalServer(_) => "InternalServerException",
+            AddTagsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CancelSteps
 #[derive(Debug, PartialEq)]
 pub enum CancelStepsError {
@@ -3462,6 +3492,14 @@ impl fmt::Display for CancelStepsError {
     }
 }
 impl Error for CancelStepsError {}
+impl ServiceErrorCode for CancelStepsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelStepsError::InternalServerError(_) => "InternalServerError",
+            CancelStepsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateSecurityConfiguration
 #[derive(Debug, PartialEq)]
 pub enum CreateSecurityConfigurationError {
@@ -3504,6 +3542,14 @@ impl fmt::Display for CreateSecurityConfigurationError {
     }
 }
 impl Error for CreateSecurityConfigurationError {}
+impl ServiceErrorCode for CreateSecurityConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSecurityConfigurationError::InternalServer(_) => "InternalServerException",
+            CreateSecurityConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateStudio
 #[derive(Debug, PartialEq)]
 pub enum CreateStudioError {
@@ -3540,6 +3586,14 @@ impl fmt::Display for CreateStudioError {
     }
 }
 impl Error for CreateStudioError {}
+impl ServiceErrorCode for CreateStudioError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStudioError::InternalServer(_) => "InternalServerException",
+            CreateStudioError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by CreateStudioSessionMapping
 #[derive(Debug, PartialEq)]
 pub enum CreateStudioSessionMappingError {
@@ -3584,6 +3638,14 @@ impl fmt::Display for CreateStudioSessionMappingError {
     }
 }
 impl Error for CreateStudioSessionMappingError {}
+impl ServiceErrorCode for CreateStudioSessionMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStudioSessionMappingError::InternalServerError(_) => "InternalServerError",
+            CreateStudioSessionMappingError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteSecurityConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteSecurityConfigurationError {
@@ -3626,6 +3688,14 @@ impl fmt::Display for DeleteSecurityConfigurationError {
     }
 }
 impl Error for DeleteSecurityConfigurationError {}
+impl ServiceErrorCode for DeleteSecurityConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSecurityConfigurationError::InternalServer(_) => "InternalServerException",
+            DeleteSecurityConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteStudio
 #[derive(Debug, PartialEq)]
 pub enum DeleteStudioError {
@@ -3662,6 +3732,14 @@ impl fmt::Display for DeleteStudioError {
     }
 }
 impl Error for DeleteStudioError {}
+impl ServiceErrorCode for DeleteStudioError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStudioError::InternalServer(_) => "InternalServerException",
+            DeleteStudioError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteStudioSessionMapping
 #[derive(Debug, PartialEq)]
 pub enum DeleteStudioSessionMappingError {
@@ -3706,6 +3784,14 @@ impl fmt::Display for DeleteStudioSessionMappingError {
     }
 }
 impl Error for DeleteStudioSessionMappingError {}
+impl ServiceErrorCode for DeleteStudioSessionMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStudioSessionMappingError::InternalServerError(_) => "InternalServerError",
+            DeleteStudioSessionMappingError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeCluster
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterError {
@@ -3742,6 +3828,14 @@ impl fmt::Display for DescribeClusterError {
     }
 }
 impl Error for DescribeClusterError {}
+impl ServiceErrorCode for DescribeClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClusterError::InternalServer(_) => "InternalServerException",
+            DescribeClusterError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeJobFlows
 #[derive(Debug, PartialEq)]
 pub enum DescribeJobFlowsError {
@@ -3774,6 +3868,13 @@ impl fmt::Display for DescribeJobFlowsError {
     }
 }
 impl Error for DescribeJobFlowsError {}
+impl ServiceErrorCode for DescribeJobFlowsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeJobFlowsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeNotebookExecution
 #[derive(Debug, PartialEq)]
 pub enum DescribeNotebookExecutionError {
@@ -3816,6 +3917,14 @@ impl fmt::Display for DescribeNotebookExecutionError {
     }
 }
 impl Error for DescribeNotebookExecutionError {}
+impl ServiceErrorCode for DescribeNotebookExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeNotebookExecutionError::InternalServerError(_) => "InternalServerError",
+            DescribeNotebookExecutionError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeSecurityConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribeSecurityConfigurationError {
@@ -3858,6 +3967,14 @@ impl fmt::Display for DescribeSecurityConfigurationError {
     }
 }
 impl Error for DescribeSecurityConfigurationError {}
+impl ServiceErrorCode for DescribeSecurityConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSecurityConfigurationError::InternalServer(_) => "InternalServerException",
+            DescribeSecurityConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeStep
 #[derive(Debug, PartialEq)]
 pub enum DescribeStepError {
@@ -3894,6 +4011,14 @@ impl fmt::Display for DescribeStepError {
     }
 }
 impl Error for DescribeStepError {}
+impl ServiceErrorCode for DescribeStepError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStepError::InternalServer(_) => "InternalServerException",
+            DescribeStepError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeStudio
 #[derive(Debug, PartialEq)]
 pub enum DescribeStudioError {
@@ -3930,6 +4055,14 @@ impl fmt::Display for DescribeStudioError {
     }
 }
 impl Error for DescribeStudioError {}
+impl ServiceErrorCode for DescribeStudioError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStudioError::InternalServer(_) => "InternalServerException",
+            DescribeStudioError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by GetBlockPublicAccessConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetBlockPublicAccessConfigurationError {
@@ -3976,6 +4109,14 @@ impl fmt::Display for GetBlockPublicAccessConfigurationError {
     }
 }
 impl Error for GetBlockPublicAccessConfigurationError {}
+impl ServiceErrorCode for GetBlockPublicAccessConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBlockPublicAccessConfigurationError::InternalServer(_) => "InternalServerException",
+            GetBlockPublicAccessConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by GetManagedScalingPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetManagedScalingPolicyError {}
@@ -3998,6 +4139,11 @@ impl fmt::Display for GetManagedScalingPolicyError {
     }
 }
 impl Error for GetManagedScalingPolicyError {}
+impl ServiceErrorCode for GetManagedScalingPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetStudioSessionMapping
 #[derive(Debug, PartialEq)]
 pub enum GetStudioSessionMappingError {
@@ -4038,6 +4184,14 @@ impl fmt::Display for GetStudioSessionMappingError {
     }
 }
 impl Error for GetStudioSessionMappingError {}
+impl ServiceErrorCode for GetStudioSessionMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetStudioSessionMappingError::InternalServerError(_) => "InternalServerError",
+            GetStudioSessionMappingError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListBootstrapActions
 #[derive(Debug, PartialEq)]
 pub enum ListBootstrapActionsError {
@@ -4074,6 +4228,14 @@ impl fmt::Display for ListBootstrapActionsError {
     }
 }
 impl Error for ListBootstrapActionsError {}
+impl ServiceErrorCode for ListBootstrapActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBootstrapActionsError::InternalServer(_) => "InternalServerException",
+            ListBootstrapActionsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListClusters
 #[derive(Debug, PartialEq)]
 pub enum ListClustersError {
@@ -4110,6 +4272,14 @@ impl fmt::Display for ListClustersError {
     }
 }
 impl Error for ListClustersError {}
+impl ServiceErrorCode for ListClustersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListClustersError::InternalServer(_) => "InternalServerException",
+            ListClustersError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListInstanceFleets
 #[derive(Debug, PartialEq)]
 pub enum ListInstanceFleetsError {
@@ -4146,6 +4316,14 @@ impl fmt::Display for ListInstanceFleetsError {
     }
 }
 impl Error for ListInstanceFleetsError {}
+impl ServiceErrorCode for ListInstanceFleetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInstanceFleetsError::InternalServer(_) => "InternalServerException",
+            ListInstanceFleetsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListInstanceGroups
 #[derive(Debug, PartialEq)]
 pub enum ListInstanceGroupsError {
@@ -4182,6 +4360,14 @@ impl fmt::Display for ListInstanceGroupsError {
     }
 }
 impl Error for ListInstanceGroupsError {}
+impl ServiceErrorCode for ListInstanceGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInstanceGroupsError::InternalServer(_) => "InternalServerException",
+            ListInstanceGroupsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListInstances
 #[derive(Debug, PartialEq)]
 pub enum ListInstancesError {
@@ -4218,6 +4404,14 @@ impl fmt::Display for ListInstancesError {
     }
 }
 impl Error for ListInstancesError {}
+impl ServiceErrorCode for ListInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInstancesError::InternalServer(_) => "InternalServerException",
+            ListInstancesError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListNotebookExecutions
 #[derive(Debug, PartialEq)]
 pub enum ListNotebookExecutionsError {
@@ -4258,6 +4452,14 @@ impl fmt::Display for ListNotebookExecutionsError {
     }
 }
 impl Error for ListNotebookExecutionsError {}
+impl ServiceErrorCode for ListNotebookExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListNotebookExecutionsError::InternalServerError(_) => "InternalServerError",
+            ListNotebookExecutionsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListSecurityConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListSecurityConfigurationsError {
@@ -4300,6 +4502,14 @@ impl fmt::Display for ListSecurityConfigurationsError {
     }
 }
 impl Error for ListSecurityConfigurationsError {}
+impl ServiceErrorCode for ListSecurityConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSecurityConfigurationsError::InternalServer(_) => "InternalServerException",
+            ListSecurityConfigurationsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListSteps
 #[derive(Debug, PartialEq)]
 pub enum ListStepsError {
@@ -4336,6 +4546,14 @@ impl fmt::Display for ListStepsError {
     }
 }
 impl Error for ListStepsError {}
+impl ServiceErrorCode for ListStepsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStepsError::InternalServer(_) => "InternalServerException",
+            ListStepsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListStudioSessionMappings
 #[derive(Debug, PartialEq)]
 pub enum ListStudioSessionMappingsError {
@@ -4378,6 +4596,14 @@ impl fmt::Display for ListStudioSessionMappingsError {
     }
 }
 impl Error for ListStudioSessionMappingsError {}
+impl ServiceErrorCode for ListStudioSessionMappingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStudioSessionMappingsError::InternalServerError(_) => "InternalServerError",
+            ListStudioSessionMappingsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListStudios
 #[derive(Debug, PartialEq)]
 pub enum ListStudiosError {
@@ -4414,6 +4640,14 @@ impl fmt::Display for ListStudiosError {
     }
 }
 impl Error for ListStudiosError {}
+impl ServiceErrorCode for ListStudiosError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStudiosError::InternalServer(_) => "InternalServerException",
+            ListStudiosError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ModifyCluster
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterError {
@@ -4450,6 +4684,14 @@ impl fmt::Display for ModifyClusterError {
     }
 }
 impl Error for ModifyClusterError {}
+impl ServiceErrorCode for ModifyClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyClusterError::InternalServerError(_) => "InternalServerError",
+            ModifyClusterError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ModifyInstanceFleet
 #[derive(Debug, PartialEq)]
 pub enum ModifyInstanceFleetError {
@@ -4486,6 +4728,14 @@ impl fmt::Display for ModifyInstanceFleetError {
     }
 }
 impl Error for ModifyInstanceFleetError {}
+impl ServiceErrorCode for ModifyInstanceFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyInstanceFleetError::InternalServer(_) => "InternalServerException",
+            ModifyInstanceFleetError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ModifyInstanceGroups
 #[derive(Debug, PartialEq)]
 pub enum ModifyInstanceGroupsError {
@@ -4518,6 +4768,13 @@ impl fmt::Display for ModifyInstanceGroupsError {
     }
 }
 impl Error for ModifyInstanceGroupsError {}
+impl ServiceErrorCode for ModifyInstanceGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyInstanceGroupsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by PutAutoScalingPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutAutoScalingPolicyError {}
@@ -4540,6 +4797,11 @@ impl fmt::Display for PutAutoScalingPolicyError {
     }
 }
 impl Error for PutAutoScalingPolicyError {}
+impl ServiceErrorCode for PutAutoScalingPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBlockPublicAccessConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutBlockPublicAccessConfigurationError {
@@ -4586,6 +4848,14 @@ impl fmt::Display for PutBlockPublicAccessConfigurationError {
     }
 }
 impl Error for PutBlockPublicAccessConfigurationError {}
+impl ServiceErrorCode for PutBlockPublicAccessConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutBlockPublicAccessConfigurationError::InternalServer(_) => "InternalServerException",
+            PutBlockPublicAccessConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by PutManagedScalingPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutManagedScalingPolicyError {}
@@ -4608,6 +4878,11 @@ impl fmt::Display for PutManagedScalingPolicyError {
     }
 }
 impl Error for PutManagedScalingPolicyError {}
+impl ServiceErrorCode for PutManagedScalingPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RemoveAutoScalingPolicy
 #[derive(Debug, PartialEq)]
 pub enum RemoveAutoScalingPolicyError {}
@@ -4630,6 +4905,11 @@ impl fmt::Display for RemoveAutoScalingPolicyError {
     }
 }
 impl Error for RemoveAutoScalingPolicyError {}
+impl ServiceErrorCode for RemoveAutoScalingPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RemoveManagedScalingPolicy
 #[derive(Debug, PartialEq)]
 pub enum RemoveManagedScalingPolicyError {}
@@ -4654,6 +4934,11 @@ impl fmt::Display for RemoveManagedScalingPolicyError {
     }
 }
 impl Error for RemoveManagedScalingPolicyError {}
+impl ServiceErrorCode for RemoveManagedScalingPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RemoveTags
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsError {
@@ -4690,6 +4975,14 @@ impl fmt::Display for RemoveTagsError {
     }
 }
 impl Error for RemoveTagsError {}
+impl ServiceErrorCode for RemoveTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsError::InternalServer(_) => "InternalServerException",
+            RemoveTagsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by RunJobFlow
 #[derive(Debug, PartialEq)]
 pub enum RunJobFlowError {
@@ -4720,6 +5013,13 @@ impl fmt::Display for RunJobFlowError {
     }
 }
 impl Error for RunJobFlowError {}
+impl ServiceErrorCode for RunJobFlowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RunJobFlowError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by SetTerminationProtection
 #[derive(Debug, PartialEq)]
 pub enum SetTerminationProtectionError {
@@ -4752,6 +5052,13 @@ impl fmt::Display for SetTerminationProtectionError {
     }
 }
 impl Error for SetTerminationProtectionError {}
+impl ServiceErrorCode for SetTerminationProtectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetTerminationProtectionError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by SetVisibleToAllUsers
 #[derive(Debug, PartialEq)]
 pub enum SetVisibleToAllUsersError {
@@ -4784,6 +5091,13 @@ impl fmt::Display for SetVisibleToAllUsersError {
     }
 }
 impl Error for SetVisibleToAllUsersError {}
+impl ServiceErrorCode for SetVisibleToAllUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetVisibleToAllUsersError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by StartNotebookExecution
 #[derive(Debug, PartialEq)]
 pub enum StartNotebookExecutionError {
@@ -4824,6 +5138,14 @@ impl fmt::Display for StartNotebookExecutionError {
     }
 }
 impl Error for StartNotebookExecutionError {}
+impl ServiceErrorCode for StartNotebookExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartNotebookExecutionError::InternalServer(_) => "InternalServerException",
+            StartNotebookExecutionError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by StopNotebookExecution
 #[derive(Debug, PartialEq)]
 pub enum StopNotebookExecutionError {
@@ -4864,6 +5186,14 @@ impl fmt::Display for StopNotebookExecutionError {
     }
 }
 impl Error for StopNotebookExecutionError {}
+impl ServiceErrorCode for StopNotebookExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopNotebookExecutionError::InternalServerError(_) => "InternalServerError",
+            StopNotebookExecutionError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by TerminateJobFlows
 #[derive(Debug, PartialEq)]
 pub enum TerminateJobFlowsError {
@@ -4896,6 +5226,13 @@ impl fmt::Display for TerminateJobFlowsError {
     }
 }
 impl Error for TerminateJobFlowsError {}
+impl ServiceErrorCode for TerminateJobFlowsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TerminateJobFlowsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by UpdateStudioSessionMapping
 #[derive(Debug, PartialEq)]
 pub enum UpdateStudioSessionMappingError {
@@ -4940,6 +5277,14 @@ impl fmt::Display for UpdateStudioSessionMappingError {
     }
 }
 impl Error for UpdateStudioSessionMappingError {}
+impl ServiceErrorCode for UpdateStudioSessionMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateStudioSessionMappingError::InternalServerError(_) => "InternalServerError",
+            UpdateStudioSessionMappingError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon EMR API. Amazon EMR clients implement this trait.
 #[async_trait]
 pub trait Emr {
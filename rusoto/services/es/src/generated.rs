@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2293,6 +2293,21 @@ impl fmt::Display for AcceptInboundCrossClusterSearchConnectionError {
     }
 }
 impl Error for AcceptInboundCrossClusterSearchConnectionError {}
+impl ServiceErrorCode for AcceptInboundCrossClusterSearchConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptInboundCrossClusterSearchConnectionError::DisabledOperation(_) => {
+                "DisabledOperationException"
+            }
+            AcceptInboundCrossClusterSearchConnectionError::LimitExceeded(_) => {
+                "LimitExceededException"
+            }
+            AcceptInboundCrossClusterSearchConnectionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by AddTags
 #[derive(Debug, PartialEq)]
 pub enum AddTagsError {
@@ -2333,6 +2348,15 @@ impl fmt::Display for AddTagsError {
     }
 }
 impl Error for AddTagsError {}
+impl ServiceErrorCode for AddTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsError::Base(_) => "BaseException",
+            AddTagsError::Internal(_) => "InternalException",
+            AddTagsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by AssociatePackage
 #[derive(Debug, PartialEq)]
 pub enum AssociatePackageError {
@@ -2387,6 +2411,17 @@ impl fmt::Display for AssociatePackageError {
     }
 }
 impl Error for AssociatePackageError {}
+impl ServiceErrorCode for AssociatePackageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociatePackageError::AccessDenied(_) => "AccessDeniedException",
+            AssociatePackageError::Base(_) => "BaseException",
+            AssociatePackageError::Conflict(_) => "ConflictException",
+            AssociatePackageError::Internal(_) => "InternalException",
+            AssociatePackageError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CancelElasticsearchServiceSoftwareUpdate
 #[derive(Debug, PartialEq)]
 pub enum CancelElasticsearchServiceSoftwareUpdateError {
@@ -2443,6 +2478,17 @@ impl fmt::Display for CancelElasticsearchServiceSoftwareUpdateError {
     }
 }
 impl Error for CancelElasticsearchServiceSoftwareUpdateError {}
+impl ServiceErrorCode for CancelElasticsearchServiceSoftwareUpdateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelElasticsearchServiceSoftwareUpdateError::Base(_) => "BaseException",
+            CancelElasticsearchServiceSoftwareUpdateError::Internal(_) => "InternalException",
+            CancelElasticsearchServiceSoftwareUpdateError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateElasticsearchDomain
 #[derive(Debug, PartialEq)]
 pub enum CreateElasticsearchDomainError {
@@ -2513,6 +2559,20 @@ impl fmt::Display for CreateElasticsearchDomainError {
     }
 }
 impl Error for CreateElasticsearchDomainError {}
+impl ServiceErrorCode for CreateElasticsearchDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateElasticsearchDomainError::Base(_) => "BaseException",
+            CreateElasticsearchDomainError::DisabledOperation(_) => "DisabledOperationException",
+            CreateElasticsearchDomainError::Internal(_) => "InternalException",
+            CreateElasticsearchDomainError::InvalidType(_) => "InvalidTypeException",
+            CreateElasticsearchDomainError::LimitExceeded(_) => "LimitExceededException",
+            CreateElasticsearchDomainError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateOutboundCrossClusterSearchConnection
 #[derive(Debug, PartialEq)]
 pub enum CreateOutboundCrossClusterSearchConnectionError {
@@ -2581,6 +2641,22 @@ impl fmt::Display for CreateOutboundCrossClusterSearchConnectionError {
     }
 }
 impl Error for CreateOutboundCrossClusterSearchConnectionError {}
+impl ServiceErrorCode for CreateOutboundCrossClusterSearchConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateOutboundCrossClusterSearchConnectionError::DisabledOperation(_) => {
+                "DisabledOperationException"
+            }
+            CreateOutboundCrossClusterSearchConnectionError::Internal(_) => "InternalException",
+            CreateOutboundCrossClusterSearchConnectionError::LimitExceeded(_) => {
+                "LimitExceededException"
+            }
+            CreateOutboundCrossClusterSearchConnectionError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+        }
+    }
+}
 /// Errors returned by CreatePackage
 #[derive(Debug, PartialEq)]
 pub enum CreatePackageError {
@@ -2639,6 +2715,18 @@ impl fmt::Display for CreatePackageError {
     }
 }
 impl Error for CreatePackageError {}
+impl ServiceErrorCode for CreatePackageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePackageError::AccessDenied(_) => "AccessDeniedException",
+            CreatePackageError::Base(_) => "BaseException",
+            CreatePackageError::Internal(_) => "InternalException",
+            CreatePackageError::InvalidType(_) => "InvalidTypeException",
+            CreatePackageError::LimitExceeded(_) => "LimitExceededException",
+            CreatePackageError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by DeleteElasticsearchDomain
 #[derive(Debug, PartialEq)]
 pub enum DeleteElasticsearchDomainError {
@@ -2683,6 +2771,15 @@ impl fmt::Display for DeleteElasticsearchDomainError {
     }
 }
 impl Error for DeleteElasticsearchDomainError {}
+impl ServiceErrorCode for DeleteElasticsearchDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteElasticsearchDomainError::Base(_) => "BaseException",
+            DeleteElasticsearchDomainError::Internal(_) => "InternalException",
+            DeleteElasticsearchDomainError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteElasticsearchServiceRole
 #[derive(Debug, PartialEq)]
 pub enum DeleteElasticsearchServiceRoleError {
@@ -2723,6 +2820,14 @@ impl fmt::Display for DeleteElasticsearchServiceRoleError {
     }
 }
 impl Error for DeleteElasticsearchServiceRoleError {}
+impl ServiceErrorCode for DeleteElasticsearchServiceRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteElasticsearchServiceRoleError::Base(_) => "BaseException",
+            DeleteElasticsearchServiceRoleError::Internal(_) => "InternalException",
+        }
+    }
+}
 /// Errors returned by DeleteInboundCrossClusterSearchConnection
 #[derive(Debug, PartialEq)]
 pub enum DeleteInboundCrossClusterSearchConnectionError {
@@ -2769,6 +2874,18 @@ impl fmt::Display for DeleteInboundCrossClusterSearchConnectionError {
     }
 }
 impl Error for DeleteInboundCrossClusterSearchConnectionError {}
+impl ServiceErrorCode for DeleteInboundCrossClusterSearchConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInboundCrossClusterSearchConnectionError::DisabledOperation(_) => {
+                "DisabledOperationException"
+            }
+            DeleteInboundCrossClusterSearchConnectionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteOutboundCrossClusterSearchConnection
 #[derive(Debug, PartialEq)]
 pub enum DeleteOutboundCrossClusterSearchConnectionError {
@@ -2815,6 +2932,18 @@ impl fmt::Display for DeleteOutboundCrossClusterSearchConnectionError {
     }
 }
 impl Error for DeleteOutboundCrossClusterSearchConnectionError {}
+impl ServiceErrorCode for DeleteOutboundCrossClusterSearchConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteOutboundCrossClusterSearchConnectionError::DisabledOperation(_) => {
+                "DisabledOperationException"
+            }
+            DeleteOutboundCrossClusterSearchConnectionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DeletePackage
 #[derive(Debug, PartialEq)]
 pub enum DeletePackageError {
@@ -2867,6 +2996,17 @@ impl fmt::Display for DeletePackageError {
     }
 }
 impl Error for DeletePackageError {}
+impl ServiceErrorCode for DeletePackageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePackageError::AccessDenied(_) => "AccessDeniedException",
+            DeletePackageError::Base(_) => "BaseException",
+            DeletePackageError::Conflict(_) => "ConflictException",
+            DeletePackageError::Internal(_) => "InternalException",
+            DeletePackageError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeElasticsearchDomain
 #[derive(Debug, PartialEq)]
 pub enum DescribeElasticsearchDomainError {
@@ -2915,6 +3055,15 @@ impl fmt::Display for DescribeElasticsearchDomainError {
     }
 }
 impl Error for DescribeElasticsearchDomainError {}
+impl ServiceErrorCode for DescribeElasticsearchDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeElasticsearchDomainError::Base(_) => "BaseException",
+            DescribeElasticsearchDomainError::Internal(_) => "InternalException",
+            DescribeElasticsearchDomainError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeElasticsearchDomainConfig
 #[derive(Debug, PartialEq)]
 pub enum DescribeElasticsearchDomainConfigError {
@@ -2967,6 +3116,17 @@ impl fmt::Display for DescribeElasticsearchDomainConfigError {
     }
 }
 impl Error for DescribeElasticsearchDomainConfigError {}
+impl ServiceErrorCode for DescribeElasticsearchDomainConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeElasticsearchDomainConfigError::Base(_) => "BaseException",
+            DescribeElasticsearchDomainConfigError::Internal(_) => "InternalException",
+            DescribeElasticsearchDomainConfigError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeElasticsearchDomains
 #[derive(Debug, PartialEq)]
 pub enum DescribeElasticsearchDomainsError {
@@ -3007,6 +3167,14 @@ impl fmt::Display for DescribeElasticsearchDomainsError {
     }
 }
 impl Error for DescribeElasticsearchDomainsError {}
+impl ServiceErrorCode for DescribeElasticsearchDomainsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeElasticsearchDomainsError::Base(_) => "BaseException",
+            DescribeElasticsearchDomainsError::Internal(_) => "InternalException",
+        }
+    }
+}
 /// Errors returned by DescribeElasticsearchInstanceTypeLimits
 #[derive(Debug, PartialEq)]
 pub enum DescribeElasticsearchInstanceTypeLimitsError {
@@ -3081,6 +3249,21 @@ impl fmt::Display for DescribeElasticsearchInstanceTypeLimitsError {
     }
 }
 impl Error for DescribeElasticsearchInstanceTypeLimitsError {}
+impl ServiceErrorCode for DescribeElasticsearchInstanceTypeLimitsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeElasticsearchInstanceTypeLimitsError::Base(_) => "BaseException",
+            DescribeElasticsearchInstanceTypeLimitsError::Internal(_) => "InternalException",
+            DescribeElasticsearchInstanceTypeLimitsError::InvalidType(_) => "InvalidTypeException",
+            DescribeElasticsearchInstanceTypeLimitsError::LimitExceeded(_) => {
+                "LimitExceededException"
+            }
+            DescribeElasticsearchInstanceTypeLimitsError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeInboundCrossClusterSearchConnections
 #[derive(Debug, PartialEq)]
 pub enum DescribeInboundCrossClusterSearchConnectionsError {
@@ -3131,6 +3314,18 @@ impl fmt::Display for DescribeInboundCrossClusterSearchConnectionsError {
     }
 }
 impl Error for DescribeInboundCrossClusterSearchConnectionsError {}
+impl ServiceErrorCode for DescribeInboundCrossClusterSearchConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInboundCrossClusterSearchConnectionsError::DisabledOperation(_) => {
+                "DisabledOperationException"
+            }
+            DescribeInboundCrossClusterSearchConnectionsError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeOutboundCrossClusterSearchConnections
 #[derive(Debug, PartialEq)]
 pub enum DescribeOutboundCrossClusterSearchConnectionsError {
@@ -3181,6 +3376,18 @@ impl fmt::Display for DescribeOutboundCrossClusterSearchConnectionsError {
     }
 }
 impl Error for DescribeOutboundCrossClusterSearchConnectionsError {}
+impl ServiceErrorCode for DescribeOutboundCrossClusterSearchConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeOutboundCrossClusterSearchConnectionsError::DisabledOperation(_) => {
+                "DisabledOperationException"
+            }
+            DescribeOutboundCrossClusterSearchConnectionsError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribePackages
 #[derive(Debug, PartialEq)]
 pub enum DescribePackagesError {
@@ -3229,6 +3436,16 @@ impl fmt::Display for DescribePackagesError {
     }
 }
 impl Error for DescribePackagesError {}
+impl ServiceErrorCode for DescribePackagesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePackagesError::AccessDenied(_) => "AccessDeniedException",
+            DescribePackagesError::Base(_) => "BaseException",
+            DescribePackagesError::Internal(_) => "InternalException",
+            DescribePackagesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeReservedElasticsearchInstanceOfferings
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedElasticsearchInstanceOfferingsError {
@@ -3289,6 +3506,19 @@ impl fmt::Display for DescribeReservedElasticsearchInstanceOfferingsError {
     }
 }
 impl Error for DescribeReservedElasticsearchInstanceOfferingsError {}
+impl ServiceErrorCode for DescribeReservedElasticsearchInstanceOfferingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReservedElasticsearchInstanceOfferingsError::DisabledOperation(_) => {
+                "DisabledOperationException"
+            }
+            DescribeReservedElasticsearchInstanceOfferingsError::Internal(_) => "InternalException",
+            DescribeReservedElasticsearchInstanceOfferingsError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeReservedElasticsearchInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedElasticsearchInstancesError {
@@ -3345,6 +3575,19 @@ impl fmt::Display for DescribeReservedElasticsearchInstancesError {
     }
 }
 impl Error for DescribeReservedElasticsearchInstancesError {}
+impl ServiceErrorCode for DescribeReservedElasticsearchInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReservedElasticsearchInstancesError::DisabledOperation(_) => {
+                "DisabledOperationException"
+            }
+            DescribeReservedElasticsearchInstancesError::Internal(_) => "InternalException",
+            DescribeReservedElasticsearchInstancesError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DissociatePackage
 #[derive(Debug, PartialEq)]
 pub enum DissociatePackageError {
@@ -3399,6 +3642,17 @@ impl fmt::Display for DissociatePackageError {
     }
 }
 impl Error for DissociatePackageError {}
+impl ServiceErrorCode for DissociatePackageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DissociatePackageError::AccessDenied(_) => "AccessDeniedException",
+            DissociatePackageError::Base(_) => "BaseException",
+            DissociatePackageError::Conflict(_) => "ConflictException",
+            DissociatePackageError::Internal(_) => "InternalException",
+            DissociatePackageError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetCompatibleElasticsearchVersions
 #[derive(Debug, PartialEq)]
 pub enum GetCompatibleElasticsearchVersionsError {
@@ -3461,6 +3715,20 @@ impl fmt::Display for GetCompatibleElasticsearchVersionsError {
     }
 }
 impl Error for GetCompatibleElasticsearchVersionsError {}
+impl ServiceErrorCode for GetCompatibleElasticsearchVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCompatibleElasticsearchVersionsError::Base(_) => "BaseException",
+            GetCompatibleElasticsearchVersionsError::DisabledOperation(_) => {
+                "DisabledOperationException"
+            }
+            GetCompatibleElasticsearchVersionsError::Internal(_) => "InternalException",
+            GetCompatibleElasticsearchVersionsError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by GetPackageVersionHistory
 #[derive(Debug, PartialEq)]
 pub enum GetPackageVersionHistoryError {
@@ -3513,6 +3781,16 @@ impl fmt::Display for GetPackageVersionHistoryError {
     }
 }
 impl Error for GetPackageVersionHistoryError {}
+impl ServiceErrorCode for GetPackageVersionHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPackageVersionHistoryError::AccessDenied(_) => "AccessDeniedException",
+            GetPackageVersionHistoryError::Base(_) => "BaseException",
+            GetPackageVersionHistoryError::Internal(_) => "InternalException",
+            GetPackageVersionHistoryError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetUpgradeHistory
 #[derive(Debug, PartialEq)]
 pub enum GetUpgradeHistoryError {
@@ -3561,6 +3839,16 @@ impl fmt::Display for GetUpgradeHistoryError {
     }
 }
 impl Error for GetUpgradeHistoryError {}
+impl ServiceErrorCode for GetUpgradeHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUpgradeHistoryError::Base(_) => "BaseException",
+            GetUpgradeHistoryError::DisabledOperation(_) => "DisabledOperationException",
+            GetUpgradeHistoryError::Internal(_) => "InternalException",
+            GetUpgradeHistoryError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetUpgradeStatus
 #[derive(Debug, PartialEq)]
 pub enum GetUpgradeStatusError {
@@ -3609,6 +3897,16 @@ impl fmt::Display for GetUpgradeStatusError {
     }
 }
 impl Error for GetUpgradeStatusError {}
+impl ServiceErrorCode for GetUpgradeStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUpgradeStatusError::Base(_) => "BaseException",
+            GetUpgradeStatusError::DisabledOperation(_) => "DisabledOperationException",
+            GetUpgradeStatusError::Internal(_) => "InternalException",
+            GetUpgradeStatusError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListDomainNames
 #[derive(Debug, PartialEq)]
 pub enum ListDomainNamesError {
@@ -3639,6 +3937,13 @@ impl fmt::Display for ListDomainNamesError {
     }
 }
 impl Error for ListDomainNamesError {}
+impl ServiceErrorCode for ListDomainNamesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDomainNamesError::Base(_) => "BaseException",
+        }
+    }
+}
 /// Errors returned by ListDomainsForPackage
 #[derive(Debug, PartialEq)]
 pub enum ListDomainsForPackageError {
@@ -3689,6 +3994,16 @@ impl fmt::Display for ListDomainsForPackageError {
     }
 }
 impl Error for ListDomainsForPackageError {}
+impl ServiceErrorCode for ListDomainsForPackageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDomainsForPackageError::AccessDenied(_) => "AccessDeniedException",
+            ListDomainsForPackageError::Base(_) => "BaseException",
+            ListDomainsForPackageError::Internal(_) => "InternalException",
+            ListDomainsForPackageError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListElasticsearchInstanceTypes
 #[derive(Debug, PartialEq)]
 pub enum ListElasticsearchInstanceTypesError {
@@ -3739,6 +4054,15 @@ impl fmt::Display for ListElasticsearchInstanceTypesError {
     }
 }
 impl Error for ListElasticsearchInstanceTypesError {}
+impl ServiceErrorCode for ListElasticsearchInstanceTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListElasticsearchInstanceTypesError::Base(_) => "BaseException",
+            ListElasticsearchInstanceTypesError::Internal(_) => "InternalException",
+            ListElasticsearchInstanceTypesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListElasticsearchVersions
 #[derive(Debug, PartialEq)]
 pub enum ListElasticsearchVersionsError {
@@ -3783,6 +4107,15 @@ impl fmt::Display for ListElasticsearchVersionsError {
     }
 }
 impl Error for ListElasticsearchVersionsError {}
+impl ServiceErrorCode for ListElasticsearchVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListElasticsearchVersionsError::Base(_) => "BaseException",
+            ListElasticsearchVersionsError::Internal(_) => "InternalException",
+            ListElasticsearchVersionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListPackagesForDomain
 #[derive(Debug, PartialEq)]
 pub enum ListPackagesForDomainError {
@@ -3833,6 +4166,16 @@ impl fmt::Display for ListPackagesForDomainError {
     }
 }
 impl Error for ListPackagesForDomainError {}
+impl ServiceErrorCode for ListPackagesForDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPackagesForDomainError::AccessDenied(_) => "AccessDeniedException",
+            ListPackagesForDomainError::Base(_) => "BaseException",
+            ListPackagesForDomainError::Internal(_) => "InternalException",
+            ListPackagesForDomainError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
 pub enum ListTagsError {
@@ -3873,6 +4216,15 @@ impl fmt::Display for ListTagsError {
     }
 }
 impl Error for ListTagsError {}
+impl ServiceErrorCode for ListTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsError::Base(_) => "BaseException",
+            ListTagsError::Internal(_) => "InternalException",
+            ListTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PurchaseReservedElasticsearchInstanceOffering
 #[derive(Debug, PartialEq)]
 pub enum PurchaseReservedElasticsearchInstanceOfferingError {
@@ -3955,6 +4307,25 @@ impl fmt::Display for PurchaseReservedElasticsearchInstanceOfferingError {
     }
 }
 impl Error for PurchaseReservedElasticsearchInstanceOfferingError {}
+impl ServiceErrorCode for PurchaseReservedElasticsearchInstanceOfferingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PurchaseReservedElasticsearchInstanceOfferingError::DisabledOperation(_) => {
+                "DisabledOperationException"
+            }
+            PurchaseReservedElasticsearchInstanceOfferingError::Internal(_) => "InternalException",
+            PurchaseReservedElasticsearchInstanceOfferingError::LimitExceeded(_) => {
+                "LimitExceededException"
+            }
+            PurchaseReservedElasticsearchInstanceOfferingError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            PurchaseReservedElasticsearchInstanceOfferingError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by RejectInboundCrossClusterSearchConnection
 #[derive(Debug, PartialEq)]
 pub enum RejectInboundCrossClusterSearchConnectionError {
@@ -4001,6 +4372,18 @@ impl fmt::Display for RejectInboundCrossClusterSearchConnectionError {
     }
 }
 impl Error for RejectInboundCrossClusterSearchConnectionError {}
+impl ServiceErrorCode for RejectInboundCrossClusterSearchConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RejectInboundCrossClusterSearchConnectionError::DisabledOperation(_) => {
+                "DisabledOperationException"
+            }
+            RejectInboundCrossClusterSearchConnectionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by RemoveTags
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsError {
@@ -4035,6 +4418,14 @@ impl fmt::Display for RemoveTagsError {
     }
 }
 impl Error for RemoveTagsError {}
+impl ServiceErrorCode for RemoveTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsError::Base(_) => "BaseException",
+            RemoveTagsError::Internal(_) => "InternalException",
+        }
+    }
+}
 /// Errors returned by StartElasticsearchServiceSoftwareUpdate
 #[derive(Debug, PartialEq)]
 pub enum StartElasticsearchServiceSoftwareUpdateError {
@@ -4089,6 +4480,17 @@ impl fmt::Display for StartElasticsearchServiceSoftwareUpdateError {
     }
 }
 impl Error for StartElasticsearchServiceSoftwareUpdateError {}
+impl ServiceErrorCode for StartElasticsearchServiceSoftwareUpdateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartElasticsearchServiceSoftwareUpdateError::Base(_) => "BaseException",
+            StartElasticsearchServiceSoftwareUpdateError::Internal(_) => "InternalException",
+            StartElasticsearchServiceSoftwareUpdateError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateElasticsearchDomainConfig
 #[derive(Debug, PartialEq)]
 pub enum UpdateElasticsearchDomainConfigError {
@@ -4159,6 +4561,19 @@ impl fmt::Display for UpdateElasticsearchDomainConfigError {
     }
 }
 impl Error for UpdateElasticsearchDomainConfigError {}
+impl ServiceErrorCode for UpdateElasticsearchDomainConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateElasticsearchDomainConfigError::Base(_) => "BaseException",
+            UpdateElasticsearchDomainConfigError::Internal(_) => "InternalException",
+            UpdateElasticsearchDomainConfigError::InvalidType(_) => "InvalidTypeException",
+            UpdateElasticsearchDomainConfigError::LimitExceeded(_) => "LimitExceededException",
+            UpdateElasticsearchDomainConfigError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdatePackage
 #[derive(Debug, PartialEq)]
 pub enum UpdatePackageError {
@@ -4211,6 +4626,17 @@ impl fmt::Display for UpdatePackageError {
     }
 }
 impl Error for UpdatePackageError {}
+impl ServiceErrorCode for UpdatePackageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePackageError::AccessDenied(_) => "AccessDeniedException",
+            UpdatePackageError::Base(_) => "BaseException",
+            UpdatePackageError::Internal(_) => "InternalException",
+            UpdatePackageError::LimitExceeded(_) => "LimitExceededException",
+            UpdatePackageError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpgradeElasticsearchDomain
 #[derive(Debug, PartialEq)]
 pub enum UpgradeElasticsearchDomainError {
@@ -4275,6 +4701,19 @@ impl fmt::Display for UpgradeElasticsearchDomainError {
     }
 }
 impl Error for UpgradeElasticsearchDomainError {}
+impl ServiceErrorCode for UpgradeElasticsearchDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpgradeElasticsearchDomainError::Base(_) => "BaseException",
+            UpgradeElasticsearchDomainError::DisabledOperation(_) => "DisabledOperationException",
+            UpgradeElasticsearchDomainError::Internal(_) => "InternalException",
+            UpgradeElasticsearchDomainError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            UpgradeElasticsearchDomainError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Elasticsearch Service API. Amazon Elasticsearch Service clients implement this trait.
 #[async_trait]
 pub trait Es {
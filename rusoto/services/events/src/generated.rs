@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1849,6 +1849,19 @@ impl fmt::Display for ActivateEventSourceError {
     }
 }
 impl Error for ActivateEventSourceError {}
+impl ServiceErrorCode for ActivateEventSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ActivateEventSourceError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            ActivateEventSourceError::Internal(_) => "InternalException",
+            ActivateEventSourceError::InvalidState(_) => "InvalidStateException",
+            ActivateEventSourceError::OperationDisabled(_) => "OperationDisabledException",
+            ActivateEventSourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CancelReplay
 #[derive(Debug, PartialEq)]
 pub enum CancelReplayError {
@@ -1897,6 +1910,16 @@ impl fmt::Display for CancelReplayError {
     }
 }
 impl Error for CancelReplayError {}
+impl ServiceErrorCode for CancelReplayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelReplayError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CancelReplayError::IllegalStatus(_) => "IllegalStatusException",
+            CancelReplayError::Internal(_) => "InternalException",
+            CancelReplayError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateArchive
 #[derive(Debug, PartialEq)]
 pub enum CreateArchiveError {
@@ -1959,6 +1982,18 @@ impl fmt::Display for CreateArchiveError {
     }
 }
 impl Error for CreateArchiveError {}
+impl ServiceErrorCode for CreateArchiveError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateArchiveError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateArchiveError::Internal(_) => "InternalException",
+            CreateArchiveError::InvalidEventPattern(_) => "InvalidEventPatternException",
+            CreateArchiveError::LimitExceeded(_) => "LimitExceededException",
+            CreateArchiveError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateArchiveError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateEventBus
 #[derive(Debug, PartialEq)]
 pub enum CreateEventBusError {
@@ -2029,6 +2064,19 @@ impl fmt::Display for CreateEventBusError {
     }
 }
 impl Error for CreateEventBusError {}
+impl ServiceErrorCode for CreateEventBusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEventBusError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateEventBusError::Internal(_) => "InternalException",
+            CreateEventBusError::InvalidState(_) => "InvalidStateException",
+            CreateEventBusError::LimitExceeded(_) => "LimitExceededException",
+            CreateEventBusError::OperationDisabled(_) => "OperationDisabledException",
+            CreateEventBusError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateEventBusError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreatePartnerEventSource
 #[derive(Debug, PartialEq)]
 pub enum CreatePartnerEventSourceError {
@@ -2095,6 +2143,21 @@ impl fmt::Display for CreatePartnerEventSourceError {
     }
 }
 impl Error for CreatePartnerEventSourceError {}
+impl ServiceErrorCode for CreatePartnerEventSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePartnerEventSourceError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            CreatePartnerEventSourceError::Internal(_) => "InternalException",
+            CreatePartnerEventSourceError::LimitExceeded(_) => "LimitExceededException",
+            CreatePartnerEventSourceError::OperationDisabled(_) => "OperationDisabledException",
+            CreatePartnerEventSourceError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+        }
+    }
+}
 /// Errors returned by DeactivateEventSource
 #[derive(Debug, PartialEq)]
 pub enum DeactivateEventSourceError {
@@ -2155,6 +2218,19 @@ impl fmt::Display for DeactivateEventSourceError {
     }
 }
 impl Error for DeactivateEventSourceError {}
+impl ServiceErrorCode for DeactivateEventSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeactivateEventSourceError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeactivateEventSourceError::Internal(_) => "InternalException",
+            DeactivateEventSourceError::InvalidState(_) => "InvalidStateException",
+            DeactivateEventSourceError::OperationDisabled(_) => "OperationDisabledException",
+            DeactivateEventSourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteArchive
 #[derive(Debug, PartialEq)]
 pub enum DeleteArchiveError {
@@ -2199,6 +2275,15 @@ impl fmt::Display for DeleteArchiveError {
     }
 }
 impl Error for DeleteArchiveError {}
+impl ServiceErrorCode for DeleteArchiveError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteArchiveError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteArchiveError::Internal(_) => "InternalException",
+            DeleteArchiveError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteEventBus
 #[derive(Debug, PartialEq)]
 pub enum DeleteEventBusError {
@@ -2237,6 +2322,14 @@ impl fmt::Display for DeleteEventBusError {
     }
 }
 impl Error for DeleteEventBusError {}
+impl ServiceErrorCode for DeleteEventBusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEventBusError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteEventBusError::Internal(_) => "InternalException",
+        }
+    }
+}
 /// Errors returned by DeletePartnerEventSource
 #[derive(Debug, PartialEq)]
 pub enum DeletePartnerEventSourceError {
@@ -2285,6 +2378,17 @@ impl fmt::Display for DeletePartnerEventSourceError {
     }
 }
 impl Error for DeletePartnerEventSourceError {}
+impl ServiceErrorCode for DeletePartnerEventSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePartnerEventSourceError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeletePartnerEventSourceError::Internal(_) => "InternalException",
+            DeletePartnerEventSourceError::OperationDisabled(_) => "OperationDisabledException",
+        }
+    }
+}
 /// Errors returned by DeleteRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteRuleError {
@@ -2333,6 +2437,16 @@ impl fmt::Display for DeleteRuleError {
     }
 }
 impl Error for DeleteRuleError {}
+impl ServiceErrorCode for DeleteRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRuleError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteRuleError::Internal(_) => "InternalException",
+            DeleteRuleError::ManagedRule(_) => "ManagedRuleException",
+            DeleteRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeArchive
 #[derive(Debug, PartialEq)]
 pub enum DescribeArchiveError {
@@ -2377,6 +2491,15 @@ impl fmt::Display for DescribeArchiveError {
     }
 }
 impl Error for DescribeArchiveError {}
+impl ServiceErrorCode for DescribeArchiveError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeArchiveError::Internal(_) => "InternalException",
+            DescribeArchiveError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            DescribeArchiveError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeEventBus
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventBusError {
@@ -2413,6 +2536,14 @@ impl fmt::Display for DescribeEventBusError {
     }
 }
 impl Error for DescribeEventBusError {}
+impl ServiceErrorCode for DescribeEventBusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventBusError::Internal(_) => "InternalException",
+            DescribeEventBusError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeEventSource
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventSourceError {
@@ -2459,6 +2590,15 @@ impl fmt::Display for DescribeEventSourceError {
     }
 }
 impl Error for DescribeEventSourceError {}
+impl ServiceErrorCode for DescribeEventSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventSourceError::Internal(_) => "InternalException",
+            DescribeEventSourceError::OperationDisabled(_) => "OperationDisabledException",
+            DescribeEventSourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribePartnerEventSource
 #[derive(Debug, PartialEq)]
 pub enum DescribePartnerEventSourceError {
@@ -2507,6 +2647,15 @@ impl fmt::Display for DescribePartnerEventSourceError {
     }
 }
 impl Error for DescribePartnerEventSourceError {}
+impl ServiceErrorCode for DescribePartnerEventSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePartnerEventSourceError::Internal(_) => "InternalException",
+            DescribePartnerEventSourceError::OperationDisabled(_) => "OperationDisabledException",
+            DescribePartnerEventSourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeReplay
 #[derive(Debug, PartialEq)]
 pub enum DescribeReplayError {
@@ -2543,6 +2692,14 @@ impl fmt::Display for DescribeReplayError {
     }
 }
 impl Error for DescribeReplayError {}
+impl ServiceErrorCode for DescribeReplayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReplayError::Internal(_) => "InternalException",
+            DescribeReplayError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeRule
 #[derive(Debug, PartialEq)]
 pub enum DescribeRuleError {
@@ -2579,6 +2736,14 @@ impl fmt::Display for DescribeRuleError {
     }
 }
 impl Error for DescribeRuleError {}
+impl ServiceErrorCode for DescribeRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRuleError::Internal(_) => "InternalException",
+            DescribeRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisableRule
 #[derive(Debug, PartialEq)]
 pub enum DisableRuleError {
@@ -2627,6 +2792,16 @@ impl fmt::Display for DisableRuleError {
     }
 }
 impl Error for DisableRuleError {}
+impl ServiceErrorCode for DisableRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableRuleError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DisableRuleError::Internal(_) => "InternalException",
+            DisableRuleError::ManagedRule(_) => "ManagedRuleException",
+            DisableRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by EnableRule
 #[derive(Debug, PartialEq)]
 pub enum EnableRuleError {
@@ -2675,6 +2850,16 @@ impl fmt::Display for EnableRuleError {
     }
 }
 impl Error for EnableRuleError {}
+impl ServiceErrorCode for EnableRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableRuleError::ConcurrentModification(_) => "ConcurrentModificationException",
+            EnableRuleError::Internal(_) => "InternalException",
+            EnableRuleError::ManagedRule(_) => "ManagedRuleException",
+            EnableRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListArchives
 #[derive(Debug, PartialEq)]
 pub enum ListArchivesError {
@@ -2711,6 +2896,14 @@ impl fmt::Display for ListArchivesError {
     }
 }
 impl Error for ListArchivesError {}
+impl ServiceErrorCode for ListArchivesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListArchivesError::Internal(_) => "InternalException",
+            ListArchivesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListEventBuses
 #[derive(Debug, PartialEq)]
 pub enum ListEventBusesError {
@@ -2741,6 +2934,13 @@ impl fmt::Display for ListEventBusesError {
     }
 }
 impl Error for ListEventBusesError {}
+impl ServiceErrorCode for ListEventBusesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEventBusesError::Internal(_) => "InternalException",
+        }
+    }
+}
 /// Errors returned by ListEventSources
 #[derive(Debug, PartialEq)]
 pub enum ListEventSourcesError {
@@ -2777,6 +2977,14 @@ impl fmt::Display for ListEventSourcesError {
     }
 }
 impl Error for ListEventSourcesError {}
+impl ServiceErrorCode for ListEventSourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEventSourcesError::Internal(_) => "InternalException",
+            ListEventSourcesError::OperationDisabled(_) => "OperationDisabledException",
+        }
+    }
+}
 /// Errors returned by ListPartnerEventSourceAccounts
 #[derive(Debug, PartialEq)]
 pub enum ListPartnerEventSourceAccountsError {
@@ -2831,6 +3039,17 @@ impl fmt::Display for ListPartnerEventSourceAccountsError {
     }
 }
 impl Error for ListPartnerEventSourceAccountsError {}
+impl ServiceErrorCode for ListPartnerEventSourceAccountsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPartnerEventSourceAccountsError::Internal(_) => "InternalException",
+            ListPartnerEventSourceAccountsError::OperationDisabled(_) => {
+                "OperationDisabledException"
+            }
+            ListPartnerEventSourceAccountsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListPartnerEventSources
 #[derive(Debug, PartialEq)]
 pub enum ListPartnerEventSourcesError {
@@ -2869,6 +3088,14 @@ impl fmt::Display for ListPartnerEventSourcesError {
     }
 }
 impl Error for ListPartnerEventSourcesError {}
+impl ServiceErrorCode for ListPartnerEventSourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPartnerEventSourcesError::Internal(_) => "InternalException",
+            ListPartnerEventSourcesError::OperationDisabled(_) => "OperationDisabledException",
+        }
+    }
+}
 /// Errors returned by ListReplays
 #[derive(Debug, PartialEq)]
 pub enum ListReplaysError {
@@ -2899,6 +3126,13 @@ impl fmt::Display for ListReplaysError {
     }
 }
 impl Error for ListReplaysError {}
+impl ServiceErrorCode for ListReplaysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListReplaysError::Internal(_) => "InternalException",
+        }
+    }
+}
 /// Errors returned by ListRuleNamesByTarget
 #[derive(Debug, PartialEq)]
 pub enum ListRuleNamesByTargetError {
@@ -2937,6 +3171,14 @@ impl fmt::Display for ListRuleNamesByTargetError {
     }
 }
 impl Error for ListRuleNamesByTargetError {}
+impl ServiceErrorCode for ListRuleNamesByTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRuleNamesByTargetError::Internal(_) => "InternalException",
+            ListRuleNamesByTargetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListRules
 #[derive(Debug, PartialEq)]
 pub enum ListRulesError {
@@ -2973,6 +3215,14 @@ impl fmt::Display for ListRulesError {
     }
 }
 impl Error for ListRulesError {}
+impl ServiceErrorCode for ListRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRulesError::Internal(_) => "InternalException",
+            ListRulesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3011,6 +3261,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::Internal(_) => "InternalException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTargetsByRule
 #[derive(Debug, PartialEq)]
 pub enum ListTargetsByRuleError {
@@ -3047,6 +3305,14 @@ impl fmt::Display for ListTargetsByRuleError {
     }
 }
 impl Error for ListTargetsByRuleError {}
+impl ServiceErrorCode for ListTargetsByRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTargetsByRuleError::Internal(_) => "InternalException",
+            ListTargetsByRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutEvents
 #[derive(Debug, PartialEq)]
 pub enum PutEventsError {
@@ -3077,6 +3343,13 @@ impl fmt::Display for PutEventsError {
     }
 }
 impl Error for PutEventsError {}
+impl ServiceErrorCode for PutEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutEventsError::Internal(_) => "InternalException",
+        }
+    }
+}
 /// Errors returned by PutPartnerEvents
 #[derive(Debug, PartialEq)]
 pub enum PutPartnerEventsError {
@@ -3113,6 +3386,14 @@ impl fmt::Display for PutPartnerEventsError {
     }
 }
 impl Error for PutPartnerEventsError {}
+impl ServiceErrorCode for PutPartnerEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutPartnerEventsError::Internal(_) => "InternalException",
+            PutPartnerEventsError::OperationDisabled(_) => "OperationDisabledException",
+        }
+    }
+}
 /// Errors returned by PutPermission
 #[derive(Debug, PartialEq)]
 pub enum PutPermissionError {
@@ -3169,6 +3450,17 @@ impl fmt::Display for PutPermissionError {
     }
 }
 impl Error for PutPermissionError {}
+impl ServiceErrorCode for PutPermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutPermissionError::ConcurrentModification(_) => "ConcurrentModificationException",
+            PutPermissionError::Internal(_) => "InternalException",
+            PutPermissionError::OperationDisabled(_) => "OperationDisabledException",
+            PutPermissionError::PolicyLengthExceeded(_) => "PolicyLengthExceededException",
+            PutPermissionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutRule
 #[derive(Debug, PartialEq)]
 pub enum PutRuleError {
@@ -3229,6 +3521,18 @@ impl fmt::Display for PutRuleError {
     }
 }
 impl Error for PutRuleError {}
+impl ServiceErrorCode for PutRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRuleError::ConcurrentModification(_) => "ConcurrentModificationException",
+            PutRuleError::Internal(_) => "InternalException",
+            PutRuleError::InvalidEventPattern(_) => "InvalidEventPatternException",
+            PutRuleError::LimitExceeded(_) => "LimitExceededException",
+            PutRuleError::ManagedRule(_) => "ManagedRuleException",
+            PutRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutTargets
 #[derive(Debug, PartialEq)]
 pub enum PutTargetsError {
@@ -3283,6 +3587,17 @@ impl fmt::Display for PutTargetsError {
     }
 }
 impl Error for PutTargetsError {}
+impl ServiceErrorCode for PutTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutTargetsError::ConcurrentModification(_) => "ConcurrentModificationException",
+            PutTargetsError::Internal(_) => "InternalException",
+            PutTargetsError::LimitExceeded(_) => "LimitExceededException",
+            PutTargetsError::ManagedRule(_) => "ManagedRuleException",
+            PutTargetsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RemovePermission
 #[derive(Debug, PartialEq)]
 pub enum RemovePermissionError {
@@ -3333,6 +3648,16 @@ impl fmt::Display for RemovePermissionError {
     }
 }
 impl Error for RemovePermissionError {}
+impl ServiceErrorCode for RemovePermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemovePermissionError::ConcurrentModification(_) => "ConcurrentModificationException",
+            RemovePermissionError::Internal(_) => "InternalException",
+            RemovePermissionError::OperationDisabled(_) => "OperationDisabledException",
+            RemovePermissionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RemoveTargets
 #[derive(Debug, PartialEq)]
 pub enum RemoveTargetsError {
@@ -3383,6 +3708,16 @@ impl fmt::Display for RemoveTargetsError {
     }
 }
 impl Error for RemoveTargetsError {}
+impl ServiceErrorCode for RemoveTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTargetsError::ConcurrentModification(_) => "ConcurrentModificationException",
+            RemoveTargetsError::Internal(_) => "InternalException",
+            RemoveTargetsError::ManagedRule(_) => "ManagedRuleException",
+            RemoveTargetsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartReplay
 #[derive(Debug, PartialEq)]
 pub enum StartReplayError {
@@ -3437,6 +3772,17 @@ impl fmt::Display for StartReplayError {
     }
 }
 impl Error for StartReplayError {}
+impl ServiceErrorCode for StartReplayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartReplayError::Internal(_) => "InternalException",
+            StartReplayError::InvalidEventPattern(_) => "InvalidEventPatternException",
+            StartReplayError::LimitExceeded(_) => "LimitExceededException",
+            StartReplayError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            StartReplayError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -3485,6 +3831,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            TagResourceError::Internal(_) => "InternalException",
+            TagResourceError::ManagedRule(_) => "ManagedRuleException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TestEventPattern
 #[derive(Debug, PartialEq)]
 pub enum TestEventPatternError {
@@ -3523,6 +3879,14 @@ impl fmt::Display for TestEventPatternError {
     }
 }
 impl Error for TestEventPatternError {}
+impl ServiceErrorCode for TestEventPatternError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TestEventPatternError::Internal(_) => "InternalException",
+            TestEventPatternError::InvalidEventPattern(_) => "InvalidEventPatternException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3573,6 +3937,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UntagResourceError::Internal(_) => "InternalException",
+            UntagResourceError::ManagedRule(_) => "ManagedRuleException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateArchive
 #[derive(Debug, PartialEq)]
 pub enum UpdateArchiveError {
@@ -3629,6 +4003,17 @@ impl fmt::Display for UpdateArchiveError {
     }
 }
 impl Error for UpdateArchiveError {}
+impl ServiceErrorCode for UpdateArchiveError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateArchiveError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateArchiveError::Internal(_) => "InternalException",
+            UpdateArchiveError::InvalidEventPattern(_) => "InvalidEventPatternException",
+            UpdateArchiveError::LimitExceeded(_) => "LimitExceededException",
+            UpdateArchiveError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon EventBridge API. Amazon EventBridge clients implement this trait.
 #[async_trait]
 pub trait EventBridge {
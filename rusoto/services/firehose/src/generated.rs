@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1852,6 +1852,16 @@ impl fmt::Display for CreateDeliveryStreamError {
     }
 }
 impl Error for CreateDeliveryStreamError {}
+impl ServiceErrorCode for CreateDeliveryStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeliveryStreamError::InvalidArgument(_) => "InvalidArgumentException",
+            CreateDeliveryStreamError::InvalidKMSResource(_) => "InvalidKMSResourceException",
+            CreateDeliveryStreamError::LimitExceeded(_) => "LimitExceededException",
+            CreateDeliveryStreamError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteDeliveryStream
 #[derive(Debug, PartialEq)]
 pub enum DeleteDeliveryStreamError {
@@ -1890,6 +1900,14 @@ impl fmt::Display for DeleteDeliveryStreamError {
     }
 }
 impl Error for DeleteDeliveryStreamError {}
+impl ServiceErrorCode for DeleteDeliveryStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDeliveryStreamError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteDeliveryStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeDeliveryStream
 #[derive(Debug, PartialEq)]
 pub enum DescribeDeliveryStreamError {
@@ -1922,6 +1940,13 @@ impl fmt::Display for DescribeDeliveryStreamError {
     }
 }
 impl Error for DescribeDeliveryStreamError {}
+impl ServiceErrorCode for DescribeDeliveryStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDeliveryStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListDeliveryStreams
 #[derive(Debug, PartialEq)]
 pub enum ListDeliveryStreamsError {}
@@ -1944,6 +1969,11 @@ impl fmt::Display for ListDeliveryStreamsError {
     }
 }
 impl Error for ListDeliveryStreamsError {}
+impl ServiceErrorCode for ListDeliveryStreamsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListTagsForDeliveryStream
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForDeliveryStreamError {
@@ -1992,6 +2022,15 @@ impl fmt::Display for ListTagsForDeliveryStreamError {
     }
 }
 impl Error for ListTagsForDeliveryStreamError {}
+impl ServiceErrorCode for ListTagsForDeliveryStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForDeliveryStreamError::InvalidArgument(_) => "InvalidArgumentException",
+            ListTagsForDeliveryStreamError::LimitExceeded(_) => "LimitExceededException",
+            ListTagsForDeliveryStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutRecord
 #[derive(Debug, PartialEq)]
 pub enum PutRecordError {
@@ -2040,6 +2079,16 @@ impl fmt::Display for PutRecordError {
     }
 }
 impl Error for PutRecordError {}
+impl ServiceErrorCode for PutRecordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRecordError::InvalidArgument(_) => "InvalidArgumentException",
+            PutRecordError::InvalidKMSResource(_) => "InvalidKMSResourceException",
+            PutRecordError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutRecordError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by PutRecordBatch
 #[derive(Debug, PartialEq)]
 pub enum PutRecordBatchError {
@@ -2088,6 +2137,16 @@ impl fmt::Display for PutRecordBatchError {
     }
 }
 impl Error for PutRecordBatchError {}
+impl ServiceErrorCode for PutRecordBatchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRecordBatchError::InvalidArgument(_) => "InvalidArgumentException",
+            PutRecordBatchError::InvalidKMSResource(_) => "InvalidKMSResourceException",
+            PutRecordBatchError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutRecordBatchError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by StartDeliveryStreamEncryption
 #[derive(Debug, PartialEq)]
 pub enum StartDeliveryStreamEncryptionError {
@@ -2160,6 +2219,19 @@ impl fmt::Display for StartDeliveryStreamEncryptionError {
     }
 }
 impl Error for StartDeliveryStreamEncryptionError {}
+impl ServiceErrorCode for StartDeliveryStreamEncryptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDeliveryStreamEncryptionError::InvalidArgument(_) => "InvalidArgumentException",
+            StartDeliveryStreamEncryptionError::InvalidKMSResource(_) => {
+                "InvalidKMSResourceException"
+            }
+            StartDeliveryStreamEncryptionError::LimitExceeded(_) => "LimitExceededException",
+            StartDeliveryStreamEncryptionError::ResourceInUse(_) => "ResourceInUseException",
+            StartDeliveryStreamEncryptionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopDeliveryStreamEncryption
 #[derive(Debug, PartialEq)]
 pub enum StopDeliveryStreamEncryptionError {
@@ -2220,6 +2292,16 @@ impl fmt::Display for StopDeliveryStreamEncryptionError {
     }
 }
 impl Error for StopDeliveryStreamEncryptionError {}
+impl ServiceErrorCode for StopDeliveryStreamEncryptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopDeliveryStreamEncryptionError::InvalidArgument(_) => "InvalidArgumentException",
+            StopDeliveryStreamEncryptionError::LimitExceeded(_) => "LimitExceededException",
+            StopDeliveryStreamEncryptionError::ResourceInUse(_) => "ResourceInUseException",
+            StopDeliveryStreamEncryptionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagDeliveryStream
 #[derive(Debug, PartialEq)]
 pub enum TagDeliveryStreamError {
@@ -2268,6 +2350,16 @@ impl fmt::Display for TagDeliveryStreamError {
     }
 }
 impl Error for TagDeliveryStreamError {}
+impl ServiceErrorCode for TagDeliveryStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagDeliveryStreamError::InvalidArgument(_) => "InvalidArgumentException",
+            TagDeliveryStreamError::LimitExceeded(_) => "LimitExceededException",
+            TagDeliveryStreamError::ResourceInUse(_) => "ResourceInUseException",
+            TagDeliveryStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagDeliveryStream
 #[derive(Debug, PartialEq)]
 pub enum UntagDeliveryStreamError {
@@ -2318,6 +2410,16 @@ impl fmt::Display for UntagDeliveryStreamError {
     }
 }
 impl Error for UntagDeliveryStreamError {}
+impl ServiceErrorCode for UntagDeliveryStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagDeliveryStreamError::InvalidArgument(_) => "InvalidArgumentException",
+            UntagDeliveryStreamError::LimitExceeded(_) => "LimitExceededException",
+            UntagDeliveryStreamError::ResourceInUse(_) => "ResourceInUseException",
+            UntagDeliveryStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateDestination
 #[derive(Debug, PartialEq)]
 pub enum UpdateDestinationError {
@@ -2368,6 +2470,16 @@ impl fmt::Display for UpdateDestinationError {
     }
 }
 impl Error for UpdateDestinationError {}
+impl ServiceErrorCode for UpdateDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDestinationError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateDestinationError::InvalidArgument(_) => "InvalidArgumentException",
+            UpdateDestinationError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateDestinationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Firehose API. Firehose clients implement this trait.
 #[async_trait]
 pub trait KinesisFirehose {
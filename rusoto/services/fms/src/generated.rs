@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1278,6 +1278,16 @@ impl fmt::Display for AssociateAdminAccountError {
     }
 }
 impl Error for AssociateAdminAccountError {}
+impl ServiceErrorCode for AssociateAdminAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateAdminAccountError::InternalError(_) => "InternalErrorException",
+            AssociateAdminAccountError::InvalidInput(_) => "InvalidInputException",
+            AssociateAdminAccountError::InvalidOperation(_) => "InvalidOperationException",
+            AssociateAdminAccountError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteAppsList
 #[derive(Debug, PartialEq)]
 pub enum DeleteAppsListError {
@@ -1320,6 +1330,15 @@ impl fmt::Display for DeleteAppsListError {
     }
 }
 impl Error for DeleteAppsListError {}
+impl ServiceErrorCode for DeleteAppsListError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAppsListError::InternalError(_) => "InternalErrorException",
+            DeleteAppsListError::InvalidOperation(_) => "InvalidOperationException",
+            DeleteAppsListError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteNotificationChannel
 #[derive(Debug, PartialEq)]
 pub enum DeleteNotificationChannelError {
@@ -1368,6 +1387,15 @@ impl fmt::Display for DeleteNotificationChannelError {
     }
 }
 impl Error for DeleteNotificationChannelError {}
+impl ServiceErrorCode for DeleteNotificationChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteNotificationChannelError::InternalError(_) => "InternalErrorException",
+            DeleteNotificationChannelError::InvalidOperation(_) => "InvalidOperationException",
+            DeleteNotificationChannelError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeletePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeletePolicyError {
@@ -1422,6 +1450,17 @@ impl fmt::Display for DeletePolicyError {
     }
 }
 impl Error for DeletePolicyError {}
+impl ServiceErrorCode for DeletePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePolicyError::InternalError(_) => "InternalErrorException",
+            DeletePolicyError::InvalidInput(_) => "InvalidInputException",
+            DeletePolicyError::InvalidOperation(_) => "InvalidOperationException",
+            DeletePolicyError::LimitExceeded(_) => "LimitExceededException",
+            DeletePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteProtocolsList
 #[derive(Debug, PartialEq)]
 pub enum DeleteProtocolsListError {
@@ -1468,6 +1507,15 @@ impl fmt::Display for DeleteProtocolsListError {
     }
 }
 impl Error for DeleteProtocolsListError {}
+impl ServiceErrorCode for DeleteProtocolsListError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProtocolsListError::InternalError(_) => "InternalErrorException",
+            DeleteProtocolsListError::InvalidOperation(_) => "InvalidOperationException",
+            DeleteProtocolsListError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateAdminAccount
 #[derive(Debug, PartialEq)]
 pub enum DisassociateAdminAccountError {
@@ -1516,6 +1564,15 @@ impl fmt::Display for DisassociateAdminAccountError {
     }
 }
 impl Error for DisassociateAdminAccountError {}
+impl ServiceErrorCode for DisassociateAdminAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateAdminAccountError::InternalError(_) => "InternalErrorException",
+            DisassociateAdminAccountError::InvalidOperation(_) => "InvalidOperationException",
+            DisassociateAdminAccountError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetAdminAccount
 #[derive(Debug, PartialEq)]
 pub enum GetAdminAccountError {
@@ -1558,6 +1615,15 @@ impl fmt::Display for GetAdminAccountError {
     }
 }
 impl Error for GetAdminAccountError {}
+impl ServiceErrorCode for GetAdminAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAdminAccountError::InternalError(_) => "InternalErrorException",
+            GetAdminAccountError::InvalidOperation(_) => "InvalidOperationException",
+            GetAdminAccountError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetAppsList
 #[derive(Debug, PartialEq)]
 pub enum GetAppsListError {
@@ -1600,6 +1666,15 @@ impl fmt::Display for GetAppsListError {
     }
 }
 impl Error for GetAppsListError {}
+impl ServiceErrorCode for GetAppsListError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAppsListError::InternalError(_) => "InternalErrorException",
+            GetAppsListError::InvalidOperation(_) => "InvalidOperationException",
+            GetAppsListError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetComplianceDetail
 #[derive(Debug, PartialEq)]
 pub enum GetComplianceDetailError {
@@ -1652,6 +1727,16 @@ impl fmt::Display for GetComplianceDetailError {
     }
 }
 impl Error for GetComplianceDetailError {}
+impl ServiceErrorCode for GetComplianceDetailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetComplianceDetailError::InternalError(_) => "InternalErrorException",
+            GetComplianceDetailError::InvalidInput(_) => "InvalidInputException",
+            GetComplianceDetailError::InvalidOperation(_) => "InvalidOperationException",
+            GetComplianceDetailError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetNotificationChannel
 #[derive(Debug, PartialEq)]
 pub enum GetNotificationChannelError {
@@ -1700,6 +1785,15 @@ impl fmt::Display for GetNotificationChannelError {
     }
 }
 impl Error for GetNotificationChannelError {}
+impl ServiceErrorCode for GetNotificationChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetNotificationChannelError::InternalError(_) => "InternalErrorException",
+            GetNotificationChannelError::InvalidOperation(_) => "InvalidOperationException",
+            GetNotificationChannelError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetPolicyError {
@@ -1748,6 +1842,16 @@ impl fmt::Display for GetPolicyError {
     }
 }
 impl Error for GetPolicyError {}
+impl ServiceErrorCode for GetPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPolicyError::InternalError(_) => "InternalErrorException",
+            GetPolicyError::InvalidOperation(_) => "InvalidOperationException",
+            GetPolicyError::InvalidType(_) => "InvalidTypeException",
+            GetPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetProtectionStatus
 #[derive(Debug, PartialEq)]
 pub enum GetProtectionStatusError {
@@ -1792,6 +1896,15 @@ impl fmt::Display for GetProtectionStatusError {
     }
 }
 impl Error for GetProtectionStatusError {}
+impl ServiceErrorCode for GetProtectionStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetProtectionStatusError::InternalError(_) => "InternalErrorException",
+            GetProtectionStatusError::InvalidInput(_) => "InvalidInputException",
+            GetProtectionStatusError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetProtocolsList
 #[derive(Debug, PartialEq)]
 pub enum GetProtocolsListError {
@@ -1834,6 +1947,15 @@ impl fmt::Display for GetProtocolsListError {
     }
 }
 impl Error for GetProtocolsListError {}
+impl ServiceErrorCode for GetProtocolsListError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetProtocolsListError::InternalError(_) => "InternalErrorException",
+            GetProtocolsListError::InvalidOperation(_) => "InvalidOperationException",
+            GetProtocolsListError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetViolationDetails
 #[derive(Debug, PartialEq)]
 pub enum GetViolationDetailsError {
@@ -1878,6 +2000,15 @@ impl fmt::Display for GetViolationDetailsError {
     }
 }
 impl Error for GetViolationDetailsError {}
+impl ServiceErrorCode for GetViolationDetailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetViolationDetailsError::InternalError(_) => "InternalErrorException",
+            GetViolationDetailsError::InvalidInput(_) => "InvalidInputException",
+            GetViolationDetailsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListAppsLists
 #[derive(Debug, PartialEq)]
 pub enum ListAppsListsError {
@@ -1926,6 +2057,16 @@ impl fmt::Display for ListAppsListsError {
     }
 }
 impl Error for ListAppsListsError {}
+impl ServiceErrorCode for ListAppsListsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAppsListsError::InternalError(_) => "InternalErrorException",
+            ListAppsListsError::InvalidOperation(_) => "InvalidOperationException",
+            ListAppsListsError::LimitExceeded(_) => "LimitExceededException",
+            ListAppsListsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListComplianceStatus
 #[derive(Debug, PartialEq)]
 pub enum ListComplianceStatusError {
@@ -1964,6 +2105,14 @@ impl fmt::Display for ListComplianceStatusError {
     }
 }
 impl Error for ListComplianceStatusError {}
+impl ServiceErrorCode for ListComplianceStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListComplianceStatusError::InternalError(_) => "InternalErrorException",
+            ListComplianceStatusError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListMemberAccounts
 #[derive(Debug, PartialEq)]
 pub enum ListMemberAccountsError {
@@ -2000,6 +2149,14 @@ impl fmt::Display for ListMemberAccountsError {
     }
 }
 impl Error for ListMemberAccountsError {}
+impl ServiceErrorCode for ListMemberAccountsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMemberAccountsError::InternalError(_) => "InternalErrorException",
+            ListMemberAccountsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListPoliciesError {
@@ -2048,6 +2205,16 @@ impl fmt::Display for ListPoliciesError {
     }
 }
 impl Error for ListPoliciesError {}
+impl ServiceErrorCode for ListPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPoliciesError::InternalError(_) => "InternalErrorException",
+            ListPoliciesError::InvalidOperation(_) => "InvalidOperationException",
+            ListPoliciesError::LimitExceeded(_) => "LimitExceededException",
+            ListPoliciesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListProtocolsLists
 #[derive(Debug, PartialEq)]
 pub enum ListProtocolsListsError {
@@ -2090,6 +2257,15 @@ impl fmt::Display for ListProtocolsListsError {
     }
 }
 impl Error for ListProtocolsListsError {}
+impl ServiceErrorCode for ListProtocolsListsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProtocolsListsError::InternalError(_) => "InternalErrorException",
+            ListProtocolsListsError::InvalidOperation(_) => "InvalidOperationException",
+            ListProtocolsListsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2142,6 +2318,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalError(_) => "InternalErrorException",
+            ListTagsForResourceError::InvalidInput(_) => "InvalidInputException",
+            ListTagsForResourceError::InvalidOperation(_) => "InvalidOperationException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutAppsList
 #[derive(Debug, PartialEq)]
 pub enum PutAppsListError {
@@ -2196,6 +2382,17 @@ impl fmt::Display for PutAppsListError {
     }
 }
 impl Error for PutAppsListError {}
+impl ServiceErrorCode for PutAppsListError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAppsListError::InternalError(_) => "InternalErrorException",
+            PutAppsListError::InvalidInput(_) => "InvalidInputException",
+            PutAppsListError::InvalidOperation(_) => "InvalidOperationException",
+            PutAppsListError::LimitExceeded(_) => "LimitExceededException",
+            PutAppsListError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutNotificationChannel
 #[derive(Debug, PartialEq)]
 pub enum PutNotificationChannelError {
@@ -2244,6 +2441,15 @@ impl fmt::Display for PutNotificationChannelError {
     }
 }
 impl Error for PutNotificationChannelError {}
+impl ServiceErrorCode for PutNotificationChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutNotificationChannelError::InternalError(_) => "InternalErrorException",
+            PutNotificationChannelError::InvalidOperation(_) => "InvalidOperationException",
+            PutNotificationChannelError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutPolicyError {
@@ -2304,6 +2510,18 @@ impl fmt::Display for PutPolicyError {
     }
 }
 impl Error for PutPolicyError {}
+impl ServiceErrorCode for PutPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutPolicyError::InternalError(_) => "InternalErrorException",
+            PutPolicyError::InvalidInput(_) => "InvalidInputException",
+            PutPolicyError::InvalidOperation(_) => "InvalidOperationException",
+            PutPolicyError::InvalidType(_) => "InvalidTypeException",
+            PutPolicyError::LimitExceeded(_) => "LimitExceededException",
+            PutPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutProtocolsList
 #[derive(Debug, PartialEq)]
 pub enum PutProtocolsListError {
@@ -2358,6 +2576,17 @@ impl fmt::Display for PutProtocolsListError {
     }
 }
 impl Error for PutProtocolsListError {}
+impl ServiceErrorCode for PutProtocolsListError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutProtocolsListError::InternalError(_) => "InternalErrorException",
+            PutProtocolsListError::InvalidInput(_) => "InvalidInputException",
+            PutProtocolsListError::InvalidOperation(_) => "InvalidOperationException",
+            PutProtocolsListError::LimitExceeded(_) => "LimitExceededException",
+            PutProtocolsListError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2412,6 +2641,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalError(_) => "InternalErrorException",
+            TagResourceError::InvalidInput(_) => "InvalidInputException",
+            TagResourceError::InvalidOperation(_) => "InvalidOperationException",
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2460,6 +2700,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalError(_) => "InternalErrorException",
+            UntagResourceError::InvalidInput(_) => "InvalidInputException",
+            UntagResourceError::InvalidOperation(_) => "InvalidOperationException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the FMS API. FMS clients implement this trait.
 #[async_trait]
 pub trait Fms {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1709,6 +1709,15 @@ impl fmt::Display for CreateDatasetError {
     }
 }
 impl Error for CreateDatasetError {}
+impl ServiceErrorCode for CreateDatasetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDatasetError::InvalidInput(_) => "InvalidInputException",
+            CreateDatasetError::LimitExceeded(_) => "LimitExceededException",
+            CreateDatasetError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by CreateDatasetGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateDatasetGroupError {
@@ -1765,6 +1774,17 @@ impl fmt::Display for CreateDatasetGroupError {
     }
 }
 impl Error for CreateDatasetGroupError {}
+impl ServiceErrorCode for CreateDatasetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDatasetGroupError::InvalidInput(_) => "InvalidInputException",
+            CreateDatasetGroupError::LimitExceeded(_) => "LimitExceededException",
+            CreateDatasetGroupError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateDatasetGroupError::ResourceInUse(_) => "ResourceInUseException",
+            CreateDatasetGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateDatasetImportJob
 #[derive(Debug, PartialEq)]
 pub enum CreateDatasetImportJobError {
@@ -1827,6 +1847,19 @@ impl fmt::Display for CreateDatasetImportJobError {
     }
 }
 impl Error for CreateDatasetImportJobError {}
+impl ServiceErrorCode for CreateDatasetImportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDatasetImportJobError::InvalidInput(_) => "InvalidInputException",
+            CreateDatasetImportJobError::LimitExceeded(_) => "LimitExceededException",
+            CreateDatasetImportJobError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateDatasetImportJobError::ResourceInUse(_) => "ResourceInUseException",
+            CreateDatasetImportJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateForecast
 #[derive(Debug, PartialEq)]
 pub enum CreateForecastError {
@@ -1883,6 +1916,17 @@ impl fmt::Display for CreateForecastError {
     }
 }
 impl Error for CreateForecastError {}
+impl ServiceErrorCode for CreateForecastError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateForecastError::InvalidInput(_) => "InvalidInputException",
+            CreateForecastError::LimitExceeded(_) => "LimitExceededException",
+            CreateForecastError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateForecastError::ResourceInUse(_) => "ResourceInUseException",
+            CreateForecastError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateForecastExportJob
 #[derive(Debug, PartialEq)]
 pub enum CreateForecastExportJobError {
@@ -1949,6 +1993,19 @@ impl fmt::Display for CreateForecastExportJobError {
     }
 }
 impl Error for CreateForecastExportJobError {}
+impl ServiceErrorCode for CreateForecastExportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateForecastExportJobError::InvalidInput(_) => "InvalidInputException",
+            CreateForecastExportJobError::LimitExceeded(_) => "LimitExceededException",
+            CreateForecastExportJobError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateForecastExportJobError::ResourceInUse(_) => "ResourceInUseException",
+            CreateForecastExportJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreatePredictor
 #[derive(Debug, PartialEq)]
 pub enum CreatePredictorError {
@@ -2005,6 +2062,17 @@ impl fmt::Display for CreatePredictorError {
     }
 }
 impl Error for CreatePredictorError {}
+impl ServiceErrorCode for CreatePredictorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePredictorError::InvalidInput(_) => "InvalidInputException",
+            CreatePredictorError::LimitExceeded(_) => "LimitExceededException",
+            CreatePredictorError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreatePredictorError::ResourceInUse(_) => "ResourceInUseException",
+            CreatePredictorError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreatePredictorBacktestExportJob
 #[derive(Debug, PartialEq)]
 pub enum CreatePredictorBacktestExportJobError {
@@ -2081,6 +2149,21 @@ impl fmt::Display for CreatePredictorBacktestExportJobError {
     }
 }
 impl Error for CreatePredictorBacktestExportJobError {}
+impl ServiceErrorCode for CreatePredictorBacktestExportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePredictorBacktestExportJobError::InvalidInput(_) => "InvalidInputException",
+            CreatePredictorBacktestExportJobError::LimitExceeded(_) => "LimitExceededException",
+            CreatePredictorBacktestExportJobError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreatePredictorBacktestExportJobError::ResourceInUse(_) => "ResourceInUseException",
+            CreatePredictorBacktestExportJobError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDataset
 #[derive(Debug, PartialEq)]
 pub enum DeleteDatasetError {
@@ -2123,6 +2206,15 @@ impl fmt::Display for DeleteDatasetError {
     }
 }
 impl Error for DeleteDatasetError {}
+impl ServiceErrorCode for DeleteDatasetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDatasetError::InvalidInput(_) => "InvalidInputException",
+            DeleteDatasetError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteDatasetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteDatasetGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteDatasetGroupError {
@@ -2165,6 +2257,15 @@ impl fmt::Display for DeleteDatasetGroupError {
     }
 }
 impl Error for DeleteDatasetGroupError {}
+impl ServiceErrorCode for DeleteDatasetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDatasetGroupError::InvalidInput(_) => "InvalidInputException",
+            DeleteDatasetGroupError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteDatasetGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteDatasetImportJob
 #[derive(Debug, PartialEq)]
 pub enum DeleteDatasetImportJobError {
@@ -2211,6 +2312,15 @@ impl fmt::Display for DeleteDatasetImportJobError {
     }
 }
 impl Error for DeleteDatasetImportJobError {}
+impl ServiceErrorCode for DeleteDatasetImportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDatasetImportJobError::InvalidInput(_) => "InvalidInputException",
+            DeleteDatasetImportJobError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteDatasetImportJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteForecast
 #[derive(Debug, PartialEq)]
 pub enum DeleteForecastError {
@@ -2253,6 +2363,15 @@ impl fmt::Display for DeleteForecastError {
     }
 }
 impl Error for DeleteForecastError {}
+impl ServiceErrorCode for DeleteForecastError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteForecastError::InvalidInput(_) => "InvalidInputException",
+            DeleteForecastError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteForecastError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteForecastExportJob
 #[derive(Debug, PartialEq)]
 pub enum DeleteForecastExportJobError {
@@ -2301,6 +2420,15 @@ impl fmt::Display for DeleteForecastExportJobError {
     }
 }
 impl Error for DeleteForecastExportJobError {}
+impl ServiceErrorCode for DeleteForecastExportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteForecastExportJobError::InvalidInput(_) => "InvalidInputException",
+            DeleteForecastExportJobError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteForecastExportJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeletePredictor
 #[derive(Debug, PartialEq)]
 pub enum DeletePredictorError {
@@ -2343,6 +2471,15 @@ impl fmt::Display for DeletePredictorError {
     }
 }
 impl Error for DeletePredictorError {}
+impl ServiceErrorCode for DeletePredictorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePredictorError::InvalidInput(_) => "InvalidInputException",
+            DeletePredictorError::ResourceInUse(_) => "ResourceInUseException",
+            DeletePredictorError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeletePredictorBacktestExportJob
 #[derive(Debug, PartialEq)]
 pub enum DeletePredictorBacktestExportJobError {
@@ -2399,6 +2536,17 @@ impl fmt::Display for DeletePredictorBacktestExportJobError {
     }
 }
 impl Error for DeletePredictorBacktestExportJobError {}
+impl ServiceErrorCode for DeletePredictorBacktestExportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePredictorBacktestExportJobError::InvalidInput(_) => "InvalidInputException",
+            DeletePredictorBacktestExportJobError::ResourceInUse(_) => "ResourceInUseException",
+            DeletePredictorBacktestExportJobError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDataset
 #[derive(Debug, PartialEq)]
 pub enum DescribeDatasetError {
@@ -2435,6 +2583,14 @@ impl fmt::Display for DescribeDatasetError {
     }
 }
 impl Error for DescribeDatasetError {}
+impl ServiceErrorCode for DescribeDatasetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDatasetError::InvalidInput(_) => "InvalidInputException",
+            DescribeDatasetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeDatasetGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeDatasetGroupError {
@@ -2473,6 +2629,14 @@ impl fmt::Display for DescribeDatasetGroupError {
     }
 }
 impl Error for DescribeDatasetGroupError {}
+impl ServiceErrorCode for DescribeDatasetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDatasetGroupError::InvalidInput(_) => "InvalidInputException",
+            DescribeDatasetGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeDatasetImportJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeDatasetImportJobError {
@@ -2513,6 +2677,14 @@ impl fmt::Display for DescribeDatasetImportJobError {
     }
 }
 impl Error for DescribeDatasetImportJobError {}
+impl ServiceErrorCode for DescribeDatasetImportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDatasetImportJobError::InvalidInput(_) => "InvalidInputException",
+            DescribeDatasetImportJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeForecast
 #[derive(Debug, PartialEq)]
 pub enum DescribeForecastError {
@@ -2549,6 +2721,14 @@ impl fmt::Display for DescribeForecastError {
     }
 }
 impl Error for DescribeForecastError {}
+impl ServiceErrorCode for DescribeForecastError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeForecastError::InvalidInput(_) => "InvalidInputException",
+            DescribeForecastError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeForecastExportJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeForecastExportJobError {
@@ -2589,6 +2769,14 @@ impl fmt::Display for DescribeForecastExportJobError {
     }
 }
 impl Error for DescribeForecastExportJobError {}
+impl ServiceErrorCode for DescribeForecastExportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeForecastExportJobError::InvalidInput(_) => "InvalidInputException",
+            DescribeForecastExportJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribePredictor
 #[derive(Debug, PartialEq)]
 pub enum DescribePredictorError {
@@ -2625,6 +2813,14 @@ impl fmt::Display for DescribePredictorError {
     }
 }
 impl Error for DescribePredictorError {}
+impl ServiceErrorCode for DescribePredictorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePredictorError::InvalidInput(_) => "InvalidInputException",
+            DescribePredictorError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribePredictorBacktestExportJob
 #[derive(Debug, PartialEq)]
 pub enum DescribePredictorBacktestExportJobError {
@@ -2671,6 +2867,16 @@ impl fmt::Display for DescribePredictorBacktestExportJobError {
     }
 }
 impl Error for DescribePredictorBacktestExportJobError {}
+impl ServiceErrorCode for DescribePredictorBacktestExportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePredictorBacktestExportJobError::InvalidInput(_) => "InvalidInputException",
+            DescribePredictorBacktestExportJobError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAccuracyMetrics
 #[derive(Debug, PartialEq)]
 pub enum GetAccuracyMetricsError {
@@ -2713,6 +2919,15 @@ impl fmt::Display for GetAccuracyMetricsError {
     }
 }
 impl Error for GetAccuracyMetricsError {}
+impl ServiceErrorCode for GetAccuracyMetricsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAccuracyMetricsError::InvalidInput(_) => "InvalidInputException",
+            GetAccuracyMetricsError::ResourceInUse(_) => "ResourceInUseException",
+            GetAccuracyMetricsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListDatasetGroups
 #[derive(Debug, PartialEq)]
 pub enum ListDatasetGroupsError {
@@ -2743,6 +2958,13 @@ impl fmt::Display for ListDatasetGroupsError {
     }
 }
 impl Error for ListDatasetGroupsError {}
+impl ServiceErrorCode for ListDatasetGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDatasetGroupsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListDatasetImportJobs
 #[derive(Debug, PartialEq)]
 pub enum ListDatasetImportJobsError {
@@ -2781,6 +3003,14 @@ impl fmt::Display for ListDatasetImportJobsError {
     }
 }
 impl Error for ListDatasetImportJobsError {}
+impl ServiceErrorCode for ListDatasetImportJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDatasetImportJobsError::InvalidInput(_) => "InvalidInputException",
+            ListDatasetImportJobsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListDatasets
 #[derive(Debug, PartialEq)]
 pub enum ListDatasetsError {
@@ -2811,6 +3041,13 @@ impl fmt::Display for ListDatasetsError {
     }
 }
 impl Error for ListDatasetsError {}
+impl ServiceErrorCode for ListDatasetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDatasetsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListForecastExportJobs
 #[derive(Debug, PartialEq)]
 pub enum ListForecastExportJobsError {
@@ -2849,6 +3086,14 @@ impl fmt::Display for ListForecastExportJobsError {
     }
 }
 impl Error for ListForecastExportJobsError {}
+impl ServiceErrorCode for ListForecastExportJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListForecastExportJobsError::InvalidInput(_) => "InvalidInputException",
+            ListForecastExportJobsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListForecasts
 #[derive(Debug, PartialEq)]
 pub enum ListForecastsError {
@@ -2885,6 +3130,14 @@ impl fmt::Display for ListForecastsError {
     }
 }
 impl Error for ListForecastsError {}
+impl ServiceErrorCode for ListForecastsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListForecastsError::InvalidInput(_) => "InvalidInputException",
+            ListForecastsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListPredictorBacktestExportJobs
 #[derive(Debug, PartialEq)]
 pub enum ListPredictorBacktestExportJobsError {
@@ -2929,6 +3182,16 @@ impl fmt::Display for ListPredictorBacktestExportJobsError {
     }
 }
 impl Error for ListPredictorBacktestExportJobsError {}
+impl ServiceErrorCode for ListPredictorBacktestExportJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPredictorBacktestExportJobsError::InvalidInput(_) => "InvalidInputException",
+            ListPredictorBacktestExportJobsError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+        }
+    }
+}
 /// Errors returned by ListPredictors
 #[derive(Debug, PartialEq)]
 pub enum ListPredictorsError {
@@ -2965,6 +3228,14 @@ impl fmt::Display for ListPredictorsError {
     }
 }
 impl Error for ListPredictorsError {}
+impl ServiceErrorCode for ListPredictorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPredictorsError::InvalidInput(_) => "InvalidInputException",
+            ListPredictorsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3003,6 +3274,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InvalidInput(_) => "InvalidInputException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -3045,6 +3324,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InvalidInput(_) => "InvalidInputException",
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3081,6 +3369,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InvalidInput(_) => "InvalidInputException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateDatasetGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateDatasetGroupError {
@@ -3123,6 +3419,15 @@ impl fmt::Display for UpdateDatasetGroupError {
     }
 }
 impl Error for UpdateDatasetGroupError {}
+impl ServiceErrorCode for UpdateDatasetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDatasetGroupError::InvalidInput(_) => "InvalidInputException",
+            UpdateDatasetGroupError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateDatasetGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Forecast Service API. Amazon Forecast Service clients implement this trait.
 #[async_trait]
 pub trait Forecast {
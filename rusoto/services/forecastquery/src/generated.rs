@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -161,6 +161,17 @@ impl fmt::Display for QueryForecastError {
     }
 }
 impl Error for QueryForecastError {}
+impl ServiceErrorCode for QueryForecastError {
+    fn error_code(&self) -> &str {
+        match *self {
+            QueryForecastError::InvalidInput(_) => "InvalidInputException",
+            QueryForecastError::InvalidNextToken(_) => "InvalidNextTokenException",
+            QueryForecastError::LimitExceeded(_) => "LimitExceededException",
+            QueryForecastError::ResourceInUse(_) => "ResourceInUseException",
+            QueryForecastError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Forecast Query Service API. Amazon Forecast Query Service clients implement this trait.
 #[async_trait]
 pub trait ForecastQuery {
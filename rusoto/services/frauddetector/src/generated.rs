@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2181,6 +2181,15 @@ impl fmt::Display for BatchCreateVariableError {
     }
 }
 impl Error for BatchCreateVariableError {}
+impl ServiceErrorCode for BatchCreateVariableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchCreateVariableError::AccessDenied(_) => "AccessDeniedException",
+            BatchCreateVariableError::InternalServer(_) => "InternalServerException",
+            BatchCreateVariableError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by BatchGetVariable
 #[derive(Debug, PartialEq)]
 pub enum BatchGetVariableError {
@@ -2223,6 +2232,15 @@ impl fmt::Display for BatchGetVariableError {
     }
 }
 impl Error for BatchGetVariableError {}
+impl ServiceErrorCode for BatchGetVariableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetVariableError::AccessDenied(_) => "AccessDeniedException",
+            BatchGetVariableError::InternalServer(_) => "InternalServerException",
+            BatchGetVariableError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateDetectorVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateDetectorVersionError {
@@ -2275,6 +2293,16 @@ impl fmt::Display for CreateDetectorVersionError {
     }
 }
 impl Error for CreateDetectorVersionError {}
+impl ServiceErrorCode for CreateDetectorVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDetectorVersionError::AccessDenied(_) => "AccessDeniedException",
+            CreateDetectorVersionError::InternalServer(_) => "InternalServerException",
+            CreateDetectorVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateDetectorVersionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateModel
 #[derive(Debug, PartialEq)]
 pub enum CreateModelError {
@@ -2311,6 +2339,14 @@ impl fmt::Display for CreateModelError {
     }
 }
 impl Error for CreateModelError {}
+impl ServiceErrorCode for CreateModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateModelError::AccessDenied(_) => "AccessDeniedException",
+            CreateModelError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by CreateModelVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateModelVersionError {
@@ -2353,6 +2389,15 @@ impl fmt::Display for CreateModelVersionError {
     }
 }
 impl Error for CreateModelVersionError {}
+impl ServiceErrorCode for CreateModelVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateModelVersionError::AccessDenied(_) => "AccessDeniedException",
+            CreateModelVersionError::InternalServer(_) => "InternalServerException",
+            CreateModelVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateRule
 #[derive(Debug, PartialEq)]
 pub enum CreateRuleError {
@@ -2395,6 +2440,15 @@ impl fmt::Display for CreateRuleError {
     }
 }
 impl Error for CreateRuleError {}
+impl ServiceErrorCode for CreateRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRuleError::AccessDenied(_) => "AccessDeniedException",
+            CreateRuleError::InternalServer(_) => "InternalServerException",
+            CreateRuleError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateVariable
 #[derive(Debug, PartialEq)]
 pub enum CreateVariableError {
@@ -2437,6 +2491,15 @@ impl fmt::Display for CreateVariableError {
     }
 }
 impl Error for CreateVariableError {}
+impl ServiceErrorCode for CreateVariableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVariableError::AccessDenied(_) => "AccessDeniedException",
+            CreateVariableError::InternalServer(_) => "InternalServerException",
+            CreateVariableError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteDetector
 #[derive(Debug, PartialEq)]
 pub enum DeleteDetectorError {
@@ -2485,6 +2548,16 @@ impl fmt::Display for DeleteDetectorError {
     }
 }
 impl Error for DeleteDetectorError {}
+impl ServiceErrorCode for DeleteDetectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDetectorError::AccessDenied(_) => "AccessDeniedException",
+            DeleteDetectorError::Conflict(_) => "ConflictException",
+            DeleteDetectorError::InternalServer(_) => "InternalServerException",
+            DeleteDetectorError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteDetectorVersion
 #[derive(Debug, PartialEq)]
 pub enum DeleteDetectorVersionError {
@@ -2543,6 +2616,17 @@ impl fmt::Display for DeleteDetectorVersionError {
     }
 }
 impl Error for DeleteDetectorVersionError {}
+impl ServiceErrorCode for DeleteDetectorVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDetectorVersionError::AccessDenied(_) => "AccessDeniedException",
+            DeleteDetectorVersionError::Conflict(_) => "ConflictException",
+            DeleteDetectorVersionError::InternalServer(_) => "InternalServerException",
+            DeleteDetectorVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDetectorVersionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteEntityType
 #[derive(Debug, PartialEq)]
 pub enum DeleteEntityTypeError {
@@ -2585,6 +2669,15 @@ impl fmt::Display for DeleteEntityTypeError {
     }
 }
 impl Error for DeleteEntityTypeError {}
+impl ServiceErrorCode for DeleteEntityTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEntityTypeError::AccessDenied(_) => "AccessDeniedException",
+            DeleteEntityTypeError::Conflict(_) => "ConflictException",
+            DeleteEntityTypeError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by DeleteEvent
 #[derive(Debug, PartialEq)]
 pub enum DeleteEventError {
@@ -2627,6 +2720,15 @@ impl fmt::Display for DeleteEventError {
     }
 }
 impl Error for DeleteEventError {}
+impl ServiceErrorCode for DeleteEventError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEventError::AccessDenied(_) => "AccessDeniedException",
+            DeleteEventError::InternalServer(_) => "InternalServerException",
+            DeleteEventError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteEventType
 #[derive(Debug, PartialEq)]
 pub enum DeleteEventTypeError {
@@ -2669,6 +2771,15 @@ impl fmt::Display for DeleteEventTypeError {
     }
 }
 impl Error for DeleteEventTypeError {}
+impl ServiceErrorCode for DeleteEventTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEventTypeError::AccessDenied(_) => "AccessDeniedException",
+            DeleteEventTypeError::Conflict(_) => "ConflictException",
+            DeleteEventTypeError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by DeleteExternalModel
 #[derive(Debug, PartialEq)]
 pub enum DeleteExternalModelError {
@@ -2717,6 +2828,16 @@ impl fmt::Display for DeleteExternalModelError {
     }
 }
 impl Error for DeleteExternalModelError {}
+impl ServiceErrorCode for DeleteExternalModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteExternalModelError::AccessDenied(_) => "AccessDeniedException",
+            DeleteExternalModelError::Conflict(_) => "ConflictException",
+            DeleteExternalModelError::InternalServer(_) => "InternalServerException",
+            DeleteExternalModelError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteLabel
 #[derive(Debug, PartialEq)]
 pub enum DeleteLabelError {
@@ -2753,6 +2874,14 @@ impl fmt::Display for DeleteLabelError {
     }
 }
 impl Error for DeleteLabelError {}
+impl ServiceErrorCode for DeleteLabelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLabelError::Conflict(_) => "ConflictException",
+            DeleteLabelError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by DeleteModel
 #[derive(Debug, PartialEq)]
 pub enum DeleteModelError {
@@ -2795,6 +2924,15 @@ impl fmt::Display for DeleteModelError {
     }
 }
 impl Error for DeleteModelError {}
+impl ServiceErrorCode for DeleteModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteModelError::AccessDenied(_) => "AccessDeniedException",
+            DeleteModelError::Conflict(_) => "ConflictException",
+            DeleteModelError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by DeleteModelVersion
 #[derive(Debug, PartialEq)]
 pub enum DeleteModelVersionError {
@@ -2837,6 +2975,15 @@ impl fmt::Display for DeleteModelVersionError {
     }
 }
 impl Error for DeleteModelVersionError {}
+impl ServiceErrorCode for DeleteModelVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteModelVersionError::AccessDenied(_) => "AccessDeniedException",
+            DeleteModelVersionError::Conflict(_) => "ConflictException",
+            DeleteModelVersionError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by DeleteOutcome
 #[derive(Debug, PartialEq)]
 pub enum DeleteOutcomeError {
@@ -2885,6 +3032,16 @@ impl fmt::Display for DeleteOutcomeError {
     }
 }
 impl Error for DeleteOutcomeError {}
+impl ServiceErrorCode for DeleteOutcomeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteOutcomeError::AccessDenied(_) => "AccessDeniedException",
+            DeleteOutcomeError::Conflict(_) => "ConflictException",
+            DeleteOutcomeError::InternalServer(_) => "InternalServerException",
+            DeleteOutcomeError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteRuleError {
@@ -2933,6 +3090,16 @@ impl fmt::Display for DeleteRuleError {
     }
 }
 impl Error for DeleteRuleError {}
+impl ServiceErrorCode for DeleteRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRuleError::AccessDenied(_) => "AccessDeniedException",
+            DeleteRuleError::Conflict(_) => "ConflictException",
+            DeleteRuleError::InternalServer(_) => "InternalServerException",
+            DeleteRuleError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteVariable
 #[derive(Debug, PartialEq)]
 pub enum DeleteVariableError {
@@ -2981,6 +3148,16 @@ impl fmt::Display for DeleteVariableError {
     }
 }
 impl Error for DeleteVariableError {}
+impl ServiceErrorCode for DeleteVariableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVariableError::AccessDenied(_) => "AccessDeniedException",
+            DeleteVariableError::Conflict(_) => "ConflictException",
+            DeleteVariableError::InternalServer(_) => "InternalServerException",
+            DeleteVariableError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeDetector
 #[derive(Debug, PartialEq)]
 pub enum DescribeDetectorError {
@@ -3029,6 +3206,16 @@ impl fmt::Display for DescribeDetectorError {
     }
 }
 impl Error for DescribeDetectorError {}
+impl ServiceErrorCode for DescribeDetectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDetectorError::AccessDenied(_) => "AccessDeniedException",
+            DescribeDetectorError::InternalServer(_) => "InternalServerException",
+            DescribeDetectorError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDetectorError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeModelVersions
 #[derive(Debug, PartialEq)]
 pub enum DescribeModelVersionsError {
@@ -3075,6 +3262,15 @@ impl fmt::Display for DescribeModelVersionsError {
     }
 }
 impl Error for DescribeModelVersionsError {}
+impl ServiceErrorCode for DescribeModelVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeModelVersionsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeModelVersionsError::InternalServer(_) => "InternalServerException",
+            DescribeModelVersionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetDetectorVersion
 #[derive(Debug, PartialEq)]
 pub enum GetDetectorVersionError {
@@ -3123,6 +3319,16 @@ impl fmt::Display for GetDetectorVersionError {
     }
 }
 impl Error for GetDetectorVersionError {}
+impl ServiceErrorCode for GetDetectorVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDetectorVersionError::AccessDenied(_) => "AccessDeniedException",
+            GetDetectorVersionError::InternalServer(_) => "InternalServerException",
+            GetDetectorVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetDetectorVersionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetDetectors
 #[derive(Debug, PartialEq)]
 pub enum GetDetectorsError {
@@ -3171,6 +3377,16 @@ impl fmt::Display for GetDetectorsError {
     }
 }
 impl Error for GetDetectorsError {}
+impl ServiceErrorCode for GetDetectorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDetectorsError::AccessDenied(_) => "AccessDeniedException",
+            GetDetectorsError::InternalServer(_) => "InternalServerException",
+            GetDetectorsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetDetectorsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetEntityTypes
 #[derive(Debug, PartialEq)]
 pub enum GetEntityTypesError {
@@ -3213,6 +3429,15 @@ impl fmt::Display for GetEntityTypesError {
     }
 }
 impl Error for GetEntityTypesError {}
+impl ServiceErrorCode for GetEntityTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEntityTypesError::AccessDenied(_) => "AccessDeniedException",
+            GetEntityTypesError::InternalServer(_) => "InternalServerException",
+            GetEntityTypesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetEventPrediction
 #[derive(Debug, PartialEq)]
 pub enum GetEventPredictionError {
@@ -3267,6 +3492,17 @@ impl fmt::Display for GetEventPredictionError {
     }
 }
 impl Error for GetEventPredictionError {}
+impl ServiceErrorCode for GetEventPredictionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEventPredictionError::AccessDenied(_) => "AccessDeniedException",
+            GetEventPredictionError::Conflict(_) => "ConflictException",
+            GetEventPredictionError::InternalServer(_) => "InternalServerException",
+            GetEventPredictionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetEventPredictionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetEventTypes
 #[derive(Debug, PartialEq)]
 pub enum GetEventTypesError {
@@ -3309,6 +3545,15 @@ impl fmt::Display for GetEventTypesError {
     }
 }
 impl Error for GetEventTypesError {}
+impl ServiceErrorCode for GetEventTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEventTypesError::AccessDenied(_) => "AccessDeniedException",
+            GetEventTypesError::InternalServer(_) => "InternalServerException",
+            GetEventTypesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetExternalModels
 #[derive(Debug, PartialEq)]
 pub enum GetExternalModelsError {
@@ -3357,6 +3602,16 @@ impl fmt::Display for GetExternalModelsError {
     }
 }
 impl Error for GetExternalModelsError {}
+impl ServiceErrorCode for GetExternalModelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetExternalModelsError::AccessDenied(_) => "AccessDeniedException",
+            GetExternalModelsError::InternalServer(_) => "InternalServerException",
+            GetExternalModelsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetExternalModelsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetKMSEncryptionKey
 #[derive(Debug, PartialEq)]
 pub enum GetKMSEncryptionKeyError {
@@ -3401,6 +3656,15 @@ impl fmt::Display for GetKMSEncryptionKeyError {
     }
 }
 impl Error for GetKMSEncryptionKeyError {}
+impl ServiceErrorCode for GetKMSEncryptionKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetKMSEncryptionKeyError::AccessDenied(_) => "AccessDeniedException",
+            GetKMSEncryptionKeyError::InternalServer(_) => "InternalServerException",
+            GetKMSEncryptionKeyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetLabels
 #[derive(Debug, PartialEq)]
 pub enum GetLabelsError {
@@ -3443,6 +3707,15 @@ impl fmt::Display for GetLabelsError {
     }
 }
 impl Error for GetLabelsError {}
+impl ServiceErrorCode for GetLabelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLabelsError::AccessDenied(_) => "AccessDeniedException",
+            GetLabelsError::InternalServer(_) => "InternalServerException",
+            GetLabelsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetModelVersion
 #[derive(Debug, PartialEq)]
 pub enum GetModelVersionError {
@@ -3485,6 +3758,15 @@ impl fmt::Display for GetModelVersionError {
     }
 }
 impl Error for GetModelVersionError {}
+impl ServiceErrorCode for GetModelVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetModelVersionError::AccessDenied(_) => "AccessDeniedException",
+            GetModelVersionError::InternalServer(_) => "InternalServerException",
+            GetModelVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetModels
 #[derive(Debug, PartialEq)]
 pub enum GetModelsError {
@@ -3527,6 +3809,15 @@ impl fmt::Display for GetModelsError {
     }
 }
 impl Error for GetModelsError {}
+impl ServiceErrorCode for GetModelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetModelsError::AccessDenied(_) => "AccessDeniedException",
+            GetModelsError::InternalServer(_) => "InternalServerException",
+            GetModelsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetOutcomes
 #[derive(Debug, PartialEq)]
 pub enum GetOutcomesError {
@@ -3575,6 +3866,16 @@ impl fmt::Display for GetOutcomesError {
     }
 }
 impl Error for GetOutcomesError {}
+impl ServiceErrorCode for GetOutcomesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOutcomesError::AccessDenied(_) => "AccessDeniedException",
+            GetOutcomesError::InternalServer(_) => "InternalServerException",
+            GetOutcomesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetOutcomesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetRules
 #[derive(Debug, PartialEq)]
 pub enum GetRulesError {
@@ -3623,6 +3924,16 @@ impl fmt::Display for GetRulesError {
     }
 }
 impl Error for GetRulesError {}
+impl ServiceErrorCode for GetRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRulesError::AccessDenied(_) => "AccessDeniedException",
+            GetRulesError::InternalServer(_) => "InternalServerException",
+            GetRulesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetRulesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetVariables
 #[derive(Debug, PartialEq)]
 pub enum GetVariablesError {
@@ -3671,6 +3982,16 @@ impl fmt::Display for GetVariablesError {
     }
 }
 impl Error for GetVariablesError {}
+impl ServiceErrorCode for GetVariablesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVariablesError::AccessDenied(_) => "AccessDeniedException",
+            GetVariablesError::InternalServer(_) => "InternalServerException",
+            GetVariablesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetVariablesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3709,6 +4030,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutDetector
 #[derive(Debug, PartialEq)]
 pub enum PutDetectorError {
@@ -3751,6 +4080,15 @@ impl fmt::Display for PutDetectorError {
     }
 }
 impl Error for PutDetectorError {}
+impl ServiceErrorCode for PutDetectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutDetectorError::AccessDenied(_) => "AccessDeniedException",
+            PutDetectorError::InternalServer(_) => "InternalServerException",
+            PutDetectorError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by PutEntityType
 #[derive(Debug, PartialEq)]
 pub enum PutEntityTypeError {
@@ -3787,6 +4125,14 @@ impl fmt::Display for PutEntityTypeError {
     }
 }
 impl Error for PutEntityTypeError {}
+impl ServiceErrorCode for PutEntityTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutEntityTypeError::AccessDenied(_) => "AccessDeniedException",
+            PutEntityTypeError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by PutEventType
 #[derive(Debug, PartialEq)]
 pub enum PutEventTypeError {
@@ -3823,6 +4169,14 @@ impl fmt::Display for PutEventTypeError {
     }
 }
 impl Error for PutEventTypeError {}
+impl ServiceErrorCode for PutEventTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutEventTypeError::AccessDenied(_) => "AccessDeniedException",
+            PutEventTypeError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by PutExternalModel
 #[derive(Debug, PartialEq)]
 pub enum PutExternalModelError {
@@ -3865,6 +4219,15 @@ impl fmt::Display for PutExternalModelError {
     }
 }
 impl Error for PutExternalModelError {}
+impl ServiceErrorCode for PutExternalModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutExternalModelError::AccessDenied(_) => "AccessDeniedException",
+            PutExternalModelError::InternalServer(_) => "InternalServerException",
+            PutExternalModelError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by PutKMSEncryptionKey
 #[derive(Debug, PartialEq)]
 pub enum PutKMSEncryptionKeyError {
@@ -3909,6 +4272,15 @@ impl fmt::Display for PutKMSEncryptionKeyError {
     }
 }
 impl Error for PutKMSEncryptionKeyError {}
+impl ServiceErrorCode for PutKMSEncryptionKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutKMSEncryptionKeyError::AccessDenied(_) => "AccessDeniedException",
+            PutKMSEncryptionKeyError::InternalServer(_) => "InternalServerException",
+            PutKMSEncryptionKeyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutLabel
 #[derive(Debug, PartialEq)]
 pub enum PutLabelError {
@@ -3945,6 +4317,14 @@ impl fmt::Display for PutLabelError {
     }
 }
 impl Error for PutLabelError {}
+impl ServiceErrorCode for PutLabelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutLabelError::AccessDenied(_) => "AccessDeniedException",
+            PutLabelError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by PutOutcome
 #[derive(Debug, PartialEq)]
 pub enum PutOutcomeError {
@@ -3987,6 +4367,15 @@ impl fmt::Display for PutOutcomeError {
     }
 }
 impl Error for PutOutcomeError {}
+impl ServiceErrorCode for PutOutcomeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutOutcomeError::AccessDenied(_) => "AccessDeniedException",
+            PutOutcomeError::InternalServer(_) => "InternalServerException",
+            PutOutcomeError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -4023,6 +4412,14 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AccessDenied(_) => "AccessDeniedException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -4059,6 +4456,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AccessDenied(_) => "AccessDeniedException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateDetectorVersion
 #[derive(Debug, PartialEq)]
 pub enum UpdateDetectorVersionError {
@@ -4111,6 +4516,16 @@ impl fmt::Display for UpdateDetectorVersionError {
     }
 }
 impl Error for UpdateDetectorVersionError {}
+impl ServiceErrorCode for UpdateDetectorVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDetectorVersionError::AccessDenied(_) => "AccessDeniedException",
+            UpdateDetectorVersionError::InternalServer(_) => "InternalServerException",
+            UpdateDetectorVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDetectorVersionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateDetectorVersionMetadata
 #[derive(Debug, PartialEq)]
 pub enum UpdateDetectorVersionMetadataError {
@@ -4161,6 +4576,15 @@ impl fmt::Display for UpdateDetectorVersionMetadataError {
     }
 }
 impl Error for UpdateDetectorVersionMetadataError {}
+impl ServiceErrorCode for UpdateDetectorVersionMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDetectorVersionMetadataError::AccessDenied(_) => "AccessDeniedException",
+            UpdateDetectorVersionMetadataError::InternalServer(_) => "InternalServerException",
+            UpdateDetectorVersionMetadataError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateDetectorVersionStatus
 #[derive(Debug, PartialEq)]
 pub enum UpdateDetectorVersionStatusError {
@@ -4219,6 +4643,16 @@ impl fmt::Display for UpdateDetectorVersionStatusError {
     }
 }
 impl Error for UpdateDetectorVersionStatusError {}
+impl ServiceErrorCode for UpdateDetectorVersionStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDetectorVersionStatusError::AccessDenied(_) => "AccessDeniedException",
+            UpdateDetectorVersionStatusError::InternalServer(_) => "InternalServerException",
+            UpdateDetectorVersionStatusError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDetectorVersionStatusError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateModel
 #[derive(Debug, PartialEq)]
 pub enum UpdateModelError {
@@ -4261,6 +4695,15 @@ impl fmt::Display for UpdateModelError {
     }
 }
 impl Error for UpdateModelError {}
+impl ServiceErrorCode for UpdateModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateModelError::AccessDenied(_) => "AccessDeniedException",
+            UpdateModelError::InternalServer(_) => "InternalServerException",
+            UpdateModelError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateModelVersion
 #[derive(Debug, PartialEq)]
 pub enum UpdateModelVersionError {
@@ -4303,6 +4746,15 @@ impl fmt::Display for UpdateModelVersionError {
     }
 }
 impl Error for UpdateModelVersionError {}
+impl ServiceErrorCode for UpdateModelVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateModelVersionError::AccessDenied(_) => "AccessDeniedException",
+            UpdateModelVersionError::InternalServer(_) => "InternalServerException",
+            UpdateModelVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateModelVersionStatus
 #[derive(Debug, PartialEq)]
 pub enum UpdateModelVersionStatusError {
@@ -4351,6 +4803,15 @@ impl fmt::Display for UpdateModelVersionStatusError {
     }
 }
 impl Error for UpdateModelVersionStatusError {}
+impl ServiceErrorCode for UpdateModelVersionStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateModelVersionStatusError::AccessDenied(_) => "AccessDeniedException",
+            UpdateModelVersionStatusError::InternalServer(_) => "InternalServerException",
+            UpdateModelVersionStatusError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateRuleMetadata
 #[derive(Debug, PartialEq)]
 pub enum UpdateRuleMetadataError {
@@ -4399,6 +4860,16 @@ impl fmt::Display for UpdateRuleMetadataError {
     }
 }
 impl Error for UpdateRuleMetadataError {}
+impl ServiceErrorCode for UpdateRuleMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRuleMetadataError::AccessDenied(_) => "AccessDeniedException",
+            UpdateRuleMetadataError::InternalServer(_) => "InternalServerException",
+            UpdateRuleMetadataError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateRuleMetadataError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateRuleVersion
 #[derive(Debug, PartialEq)]
 pub enum UpdateRuleVersionError {
@@ -4447,6 +4918,16 @@ impl fmt::Display for UpdateRuleVersionError {
     }
 }
 impl Error for UpdateRuleVersionError {}
+impl ServiceErrorCode for UpdateRuleVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRuleVersionError::AccessDenied(_) => "AccessDeniedException",
+            UpdateRuleVersionError::InternalServer(_) => "InternalServerException",
+            UpdateRuleVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateRuleVersionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateVariable
 #[derive(Debug, PartialEq)]
 pub enum UpdateVariableError {
@@ -4495,6 +4976,16 @@ impl fmt::Display for UpdateVariableError {
     }
 }
 impl Error for UpdateVariableError {}
+impl ServiceErrorCode for UpdateVariableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateVariableError::AccessDenied(_) => "AccessDeniedException",
+            UpdateVariableError::InternalServer(_) => "InternalServerException",
+            UpdateVariableError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateVariableError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Fraud Detector API. Amazon Fraud Detector clients implement this trait.
 #[async_trait]
 pub trait FraudDetector {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -123,6 +123,7 @@ pub struct AssociateFileSystemAliasesRequest {
     /// <p>An array of one or more DNS alias names to associate with the file system. The alias name has to comply with the following formatting requirements:</p> <ul> <li> <p>Formatted as a fully-qualified domain name (FQDN), <i> <code>hostname.domain</code> </i>, for example, <code>accounting.corp.example.com</code>.</p> </li> <li> <p>Can contain alphanumeric characters and the hyphen (-).</p> </li> <li> <p>Cannot start or end with a hyphen.</p> </li> <li> <p>Can start with a numeric.</p> </li> </ul> <p>For DNS alias names, Amazon FSx stores alphabetic characters as lowercase letters (a-z), regardless of how you specify them: as uppercase letters, lowercase letters, or the corresponding letters in escape codes.</p>
     #[serde(rename = "Aliases")]
     pub aliases: Vec<String>,
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -242,6 +243,7 @@ pub struct CompletionReport {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateBackupRequest {
     /// <p>(Optional) A string of up to 64 ASCII characters that Amazon FSx uses to ensure idempotent creation. This string is automatically filled on your behalf when you use the AWS Command Line Interface (AWS CLI) or an AWS SDK.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -267,6 +269,7 @@ pub struct CreateBackupResponse {
 #[derive(Clone, Debug, Default, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateDataRepositoryTaskRequest {
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -303,6 +306,7 @@ pub struct CreateFileSystemFromBackupRequest {
     #[serde(rename = "BackupId")]
     pub backup_id: String,
     /// <p>A string of up to 64 ASCII characters that Amazon FSx uses to ensure idempotent creation. This string is automatically filled on your behalf when you use the AWS Command Line Interface (AWS CLI) or an AWS SDK.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -393,6 +397,7 @@ pub struct CreateFileSystemLustreConfiguration {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateFileSystemRequest {
     /// <p>A string of up to 64 ASCII characters that Amazon FSx uses to ensure idempotent creation. This string is automatically filled on your behalf when you use the AWS Command Line Interface (AWS CLI) or an AWS SDK.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -623,6 +628,7 @@ pub struct DeleteBackupRequest {
     #[serde(rename = "BackupId")]
     pub backup_id: String,
     /// <p>A string of up to 64 ASCII characters that Amazon FSx uses to ensure idempotent deletion. This is automatically filled on your behalf when using the AWS CLI or SDK.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -675,6 +681,7 @@ pub struct DeleteFileSystemLustreResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteFileSystemRequest {
     /// <p>A string of up to 64 ASCII characters that Amazon FSx uses to ensure idempotent deletion. This is automatically filled on your behalf when using the AWS CLI or SDK.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -808,6 +815,7 @@ pub struct DescribeDataRepositoryTasksResponse {
 #[derive(Clone, Debug, Default, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DescribeFileSystemAliasesRequest {
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -877,6 +885,7 @@ pub struct DisassociateFileSystemAliasesRequest {
     /// <p>An array of one or more DNS alias names to disassociate, or remove, from the file system.</p>
     #[serde(rename = "Aliases")]
     pub aliases: Vec<String>,
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -1205,6 +1214,7 @@ pub struct UpdateFileSystemLustreConfiguration {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateFileSystemRequest {
     /// <p>A string of up to 64 ASCII characters that Amazon FSx uses to ensure idempotent updates. This string is automatically filled on your behalf when you use the AWS Command Line Interface (AWS CLI) or an AWS SDK.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -1371,6 +1381,15 @@ impl fmt::Display for AssociateFileSystemAliasesError {
     }
 }
 impl Error for AssociateFileSystemAliasesError {}
+impl ServiceErrorCode for AssociateFileSystemAliasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateFileSystemAliasesError::BadRequest(_) => "BadRequest",
+            AssociateFileSystemAliasesError::FileSystemNotFound(_) => "FileSystemNotFound",
+            AssociateFileSystemAliasesError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by CancelDataRepositoryTask
 #[derive(Debug, PartialEq)]
 pub enum CancelDataRepositoryTaskError {
@@ -1439,6 +1458,19 @@ impl fmt::Display for CancelDataRepositoryTaskError {
     }
 }
 impl Error for CancelDataRepositoryTaskError {}
+impl ServiceErrorCode for CancelDataRepositoryTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelDataRepositoryTaskError::BadRequest(_) => "BadRequest",
+            CancelDataRepositoryTaskError::DataRepositoryTaskEnded(_) => "DataRepositoryTaskEnded",
+            CancelDataRepositoryTaskError::DataRepositoryTaskNotFound(_) => {
+                "DataRepositoryTaskNotFound"
+            }
+            CancelDataRepositoryTaskError::InternalServerError(_) => "InternalServerError",
+            CancelDataRepositoryTaskError::UnsupportedOperation(_) => "UnsupportedOperation",
+        }
+    }
+}
 /// Errors returned by CreateBackup
 #[derive(Debug, PartialEq)]
 pub enum CreateBackupError {
@@ -1507,6 +1539,19 @@ impl fmt::Display for CreateBackupError {
     }
 }
 impl Error for CreateBackupError {}
+impl ServiceErrorCode for CreateBackupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBackupError::BackupInProgress(_) => "BackupInProgress",
+            CreateBackupError::BadRequest(_) => "BadRequest",
+            CreateBackupError::FileSystemNotFound(_) => "FileSystemNotFound",
+            CreateBackupError::IncompatibleParameterError(_) => "IncompatibleParameterError",
+            CreateBackupError::InternalServerError(_) => "InternalServerError",
+            CreateBackupError::ServiceLimitExceeded(_) => "ServiceLimitExceeded",
+            CreateBackupError::UnsupportedOperation(_) => "UnsupportedOperation",
+        }
+    }
+}
 /// Errors returned by CreateDataRepositoryTask
 #[derive(Debug, PartialEq)]
 pub enum CreateDataRepositoryTaskError {
@@ -1593,6 +1638,23 @@ impl fmt::Display for CreateDataRepositoryTaskError {
     }
 }
 impl Error for CreateDataRepositoryTaskError {}
+impl ServiceErrorCode for CreateDataRepositoryTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDataRepositoryTaskError::BadRequest(_) => "BadRequest",
+            CreateDataRepositoryTaskError::DataRepositoryTaskExecuting(_) => {
+                "DataRepositoryTaskExecuting"
+            }
+            CreateDataRepositoryTaskError::FileSystemNotFound(_) => "FileSystemNotFound",
+            CreateDataRepositoryTaskError::IncompatibleParameterError(_) => {
+                "IncompatibleParameterError"
+            }
+            CreateDataRepositoryTaskError::InternalServerError(_) => "InternalServerError",
+            CreateDataRepositoryTaskError::ServiceLimitExceeded(_) => "ServiceLimitExceeded",
+            CreateDataRepositoryTaskError::UnsupportedOperation(_) => "UnsupportedOperation",
+        }
+    }
+}
 /// Errors returned by CreateFileSystem
 #[derive(Debug, PartialEq)]
 pub enum CreateFileSystemError {
@@ -1695,6 +1757,26 @@ impl fmt::Display for CreateFileSystemError {
     }
 }
 impl Error for CreateFileSystemError {}
+impl ServiceErrorCode for CreateFileSystemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFileSystemError::ActiveDirectoryError(_) => "ActiveDirectoryError",
+            CreateFileSystemError::BadRequest(_) => "BadRequest",
+            CreateFileSystemError::IncompatibleParameterError(_) => "IncompatibleParameterError",
+            CreateFileSystemError::InternalServerError(_) => "InternalServerError",
+            CreateFileSystemError::InvalidExportPath(_) => "InvalidExportPath",
+            CreateFileSystemError::InvalidImportPath(_) => "InvalidImportPath",
+            CreateFileSystemError::InvalidNetworkSettings(_) => "InvalidNetworkSettings",
+            CreateFileSystemError::InvalidPerUnitStorageThroughput(_) => {
+                "InvalidPerUnitStorageThroughput"
+            }
+            CreateFileSystemError::MissingFileSystemConfiguration(_) => {
+                "MissingFileSystemConfiguration"
+            }
+            CreateFileSystemError::ServiceLimitExceeded(_) => "ServiceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateFileSystemFromBackup
 #[derive(Debug, PartialEq)]
 pub enum CreateFileSystemFromBackupError {
@@ -1807,6 +1889,27 @@ impl fmt::Display for CreateFileSystemFromBackupError {
     }
 }
 impl Error for CreateFileSystemFromBackupError {}
+impl ServiceErrorCode for CreateFileSystemFromBackupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFileSystemFromBackupError::ActiveDirectoryError(_) => "ActiveDirectoryError",
+            CreateFileSystemFromBackupError::BackupNotFound(_) => "BackupNotFound",
+            CreateFileSystemFromBackupError::BadRequest(_) => "BadRequest",
+            CreateFileSystemFromBackupError::IncompatibleParameterError(_) => {
+                "IncompatibleParameterError"
+            }
+            CreateFileSystemFromBackupError::InternalServerError(_) => "InternalServerError",
+            CreateFileSystemFromBackupError::InvalidNetworkSettings(_) => "InvalidNetworkSettings",
+            CreateFileSystemFromBackupError::InvalidPerUnitStorageThroughput(_) => {
+                "InvalidPerUnitStorageThroughput"
+            }
+            CreateFileSystemFromBackupError::MissingFileSystemConfiguration(_) => {
+                "MissingFileSystemConfiguration"
+            }
+            CreateFileSystemFromBackupError::ServiceLimitExceeded(_) => "ServiceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by DeleteBackup
 #[derive(Debug, PartialEq)]
 pub enum DeleteBackupError {
@@ -1869,6 +1972,18 @@ impl fmt::Display for DeleteBackupError {
     }
 }
 impl Error for DeleteBackupError {}
+impl ServiceErrorCode for DeleteBackupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBackupError::BackupInProgress(_) => "BackupInProgress",
+            DeleteBackupError::BackupNotFound(_) => "BackupNotFound",
+            DeleteBackupError::BackupRestoring(_) => "BackupRestoring",
+            DeleteBackupError::BadRequest(_) => "BadRequest",
+            DeleteBackupError::IncompatibleParameterError(_) => "IncompatibleParameterError",
+            DeleteBackupError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DeleteFileSystem
 #[derive(Debug, PartialEq)]
 pub enum DeleteFileSystemError {
@@ -1929,6 +2044,17 @@ impl fmt::Display for DeleteFileSystemError {
     }
 }
 impl Error for DeleteFileSystemError {}
+impl ServiceErrorCode for DeleteFileSystemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFileSystemError::BadRequest(_) => "BadRequest",
+            DeleteFileSystemError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DeleteFileSystemError::IncompatibleParameterError(_) => "IncompatibleParameterError",
+            DeleteFileSystemError::InternalServerError(_) => "InternalServerError",
+            DeleteFileSystemError::ServiceLimitExceeded(_) => "ServiceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by DescribeBackups
 #[derive(Debug, PartialEq)]
 pub enum DescribeBackupsError {
@@ -1977,6 +2103,16 @@ impl fmt::Display for DescribeBackupsError {
     }
 }
 impl Error for DescribeBackupsError {}
+impl ServiceErrorCode for DescribeBackupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBackupsError::BackupNotFound(_) => "BackupNotFound",
+            DescribeBackupsError::BadRequest(_) => "BadRequest",
+            DescribeBackupsError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DescribeBackupsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeDataRepositoryTasks
 #[derive(Debug, PartialEq)]
 pub enum DescribeDataRepositoryTasksError {
@@ -2041,6 +2177,18 @@ impl fmt::Display for DescribeDataRepositoryTasksError {
     }
 }
 impl Error for DescribeDataRepositoryTasksError {}
+impl ServiceErrorCode for DescribeDataRepositoryTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDataRepositoryTasksError::BadRequest(_) => "BadRequest",
+            DescribeDataRepositoryTasksError::DataRepositoryTaskNotFound(_) => {
+                "DataRepositoryTaskNotFound"
+            }
+            DescribeDataRepositoryTasksError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DescribeDataRepositoryTasksError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeFileSystemAliases
 #[derive(Debug, PartialEq)]
 pub enum DescribeFileSystemAliasesError {
@@ -2091,6 +2239,15 @@ impl fmt::Display for DescribeFileSystemAliasesError {
     }
 }
 impl Error for DescribeFileSystemAliasesError {}
+impl ServiceErrorCode for DescribeFileSystemAliasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFileSystemAliasesError::BadRequest(_) => "BadRequest",
+            DescribeFileSystemAliasesError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DescribeFileSystemAliasesError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeFileSystems
 #[derive(Debug, PartialEq)]
 pub enum DescribeFileSystemsError {
@@ -2137,6 +2294,15 @@ impl fmt::Display for DescribeFileSystemsError {
     }
 }
 impl Error for DescribeFileSystemsError {}
+impl ServiceErrorCode for DescribeFileSystemsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFileSystemsError::BadRequest(_) => "BadRequest",
+            DescribeFileSystemsError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DescribeFileSystemsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DisassociateFileSystemAliases
 #[derive(Debug, PartialEq)]
 pub enum DisassociateFileSystemAliasesError {
@@ -2191,6 +2357,15 @@ impl fmt::Display for DisassociateFileSystemAliasesError {
     }
 }
 impl Error for DisassociateFileSystemAliasesError {}
+impl ServiceErrorCode for DisassociateFileSystemAliasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateFileSystemAliasesError::BadRequest(_) => "BadRequest",
+            DisassociateFileSystemAliasesError::FileSystemNotFound(_) => "FileSystemNotFound",
+            DisassociateFileSystemAliasesError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2255,6 +2430,19 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequest",
+            ListTagsForResourceError::InternalServerError(_) => "InternalServerError",
+            ListTagsForResourceError::NotServiceResourceError(_) => "NotServiceResourceError",
+            ListTagsForResourceError::ResourceDoesNotSupportTagging(_) => {
+                "ResourceDoesNotSupportTagging"
+            }
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2309,6 +2497,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequest",
+            TagResourceError::InternalServerError(_) => "InternalServerError",
+            TagResourceError::NotServiceResourceError(_) => "NotServiceResourceError",
+            TagResourceError::ResourceDoesNotSupportTagging(_) => "ResourceDoesNotSupportTagging",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2367,6 +2566,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequest",
+            UntagResourceError::InternalServerError(_) => "InternalServerError",
+            UntagResourceError::NotServiceResourceError(_) => "NotServiceResourceError",
+            UntagResourceError::ResourceDoesNotSupportTagging(_) => "ResourceDoesNotSupportTagging",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateFileSystem
 #[derive(Debug, PartialEq)]
 pub enum UpdateFileSystemError {
@@ -2445,6 +2655,21 @@ impl fmt::Display for UpdateFileSystemError {
     }
 }
 impl Error for UpdateFileSystemError {}
+impl ServiceErrorCode for UpdateFileSystemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFileSystemError::BadRequest(_) => "BadRequest",
+            UpdateFileSystemError::FileSystemNotFound(_) => "FileSystemNotFound",
+            UpdateFileSystemError::IncompatibleParameterError(_) => "IncompatibleParameterError",
+            UpdateFileSystemError::InternalServerError(_) => "InternalServerError",
+            UpdateFileSystemError::MissingFileSystemConfiguration(_) => {
+                "MissingFileSystemConfiguration"
+            }
+            UpdateFileSystemError::ServiceLimitExceeded(_) => "ServiceLimitExceeded",
+            UpdateFileSystemError::UnsupportedOperation(_) => "UnsupportedOperation",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon FSx API. Amazon FSx clients implement this trait.
 #[async_trait]
 pub trait Fsx {
@@ -2599,6 +2824,10 @@ impl Fsx for FsxClient {
         input: AssociateFileSystemAliasesRequest,
     ) -> Result<AssociateFileSystemAliasesResponse, RusotoError<AssociateFileSystemAliasesError>>
     {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -2643,6 +2872,10 @@ impl Fsx for FsxClient {
         &self,
         input: CreateBackupRequest,
     ) -> Result<CreateBackupResponse, RusotoError<CreateBackupError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.CreateBackup");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -2661,6 +2894,10 @@ impl Fsx for FsxClient {
         &self,
         input: CreateDataRepositoryTaskRequest,
     ) -> Result<CreateDataRepositoryTaskResponse, RusotoError<CreateDataRepositoryTaskError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -2683,6 +2920,10 @@ impl Fsx for FsxClient {
         &self,
         input: CreateFileSystemRequest,
     ) -> Result<CreateFileSystemResponse, RusotoError<CreateFileSystemError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -2705,6 +2946,10 @@ impl Fsx for FsxClient {
         input: CreateFileSystemFromBackupRequest,
     ) -> Result<CreateFileSystemFromBackupResponse, RusotoError<CreateFileSystemFromBackupError>>
     {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -2727,6 +2972,10 @@ impl Fsx for FsxClient {
         &self,
         input: DeleteBackupRequest,
     ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.DeleteBackup");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -2745,6 +2994,10 @@ impl Fsx for FsxClient {
         &self,
         input: DeleteFileSystemRequest,
     ) -> Result<DeleteFileSystemResponse, RusotoError<DeleteFileSystemError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -2811,6 +3064,10 @@ impl Fsx for FsxClient {
         input: DescribeFileSystemAliasesRequest,
     ) -> Result<DescribeFileSystemAliasesResponse, RusotoError<DescribeFileSystemAliasesError>>
     {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -2857,6 +3114,10 @@ impl Fsx for FsxClient {
         DisassociateFileSystemAliasesResponse,
         RusotoError<DisassociateFileSystemAliasesError>,
     > {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -2936,6 +3197,10 @@ impl Fsx for FsxClient {
         &self,
         input: UpdateFileSystemRequest,
     ) -> Result<UpdateFileSystemResponse, RusotoError<UpdateFileSystemError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
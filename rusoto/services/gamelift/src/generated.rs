@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -4013,6 +4013,16 @@ impl fmt::Display for AcceptMatchError {
     }
 }
 impl Error for AcceptMatchError {}
+impl ServiceErrorCode for AcceptMatchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptMatchError::InternalService(_) => "InternalServiceException",
+            AcceptMatchError::InvalidRequest(_) => "InvalidRequestException",
+            AcceptMatchError::NotFound(_) => "NotFoundException",
+            AcceptMatchError::UnsupportedRegion(_) => "UnsupportedRegionException",
+        }
+    }
+}
 /// Errors returned by ClaimGameServer
 #[derive(Debug, PartialEq)]
 pub enum ClaimGameServerError {
@@ -4073,6 +4083,18 @@ impl fmt::Display for ClaimGameServerError {
     }
 }
 impl Error for ClaimGameServerError {}
+impl ServiceErrorCode for ClaimGameServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ClaimGameServerError::Conflict(_) => "ConflictException",
+            ClaimGameServerError::InternalService(_) => "InternalServiceException",
+            ClaimGameServerError::InvalidRequest(_) => "InvalidRequestException",
+            ClaimGameServerError::NotFound(_) => "NotFoundException",
+            ClaimGameServerError::OutOfCapacity(_) => "OutOfCapacityException",
+            ClaimGameServerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateAlias
 #[derive(Debug, PartialEq)]
 pub enum CreateAliasError {
@@ -4133,6 +4155,18 @@ impl fmt::Display for CreateAliasError {
     }
 }
 impl Error for CreateAliasError {}
+impl ServiceErrorCode for CreateAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAliasError::Conflict(_) => "ConflictException",
+            CreateAliasError::InternalService(_) => "InternalServiceException",
+            CreateAliasError::InvalidRequest(_) => "InvalidRequestException",
+            CreateAliasError::LimitExceeded(_) => "LimitExceededException",
+            CreateAliasError::TaggingFailed(_) => "TaggingFailedException",
+            CreateAliasError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateBuild
 #[derive(Debug, PartialEq)]
 pub enum CreateBuildError {
@@ -4187,6 +4221,17 @@ impl fmt::Display for CreateBuildError {
     }
 }
 impl Error for CreateBuildError {}
+impl ServiceErrorCode for CreateBuildError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBuildError::Conflict(_) => "ConflictException",
+            CreateBuildError::InternalService(_) => "InternalServiceException",
+            CreateBuildError::InvalidRequest(_) => "InvalidRequestException",
+            CreateBuildError::TaggingFailed(_) => "TaggingFailedException",
+            CreateBuildError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateFleet
 #[derive(Debug, PartialEq)]
 pub enum CreateFleetError {
@@ -4253,6 +4298,19 @@ impl fmt::Display for CreateFleetError {
     }
 }
 impl Error for CreateFleetError {}
+impl ServiceErrorCode for CreateFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFleetError::Conflict(_) => "ConflictException",
+            CreateFleetError::InternalService(_) => "InternalServiceException",
+            CreateFleetError::InvalidRequest(_) => "InvalidRequestException",
+            CreateFleetError::LimitExceeded(_) => "LimitExceededException",
+            CreateFleetError::NotFound(_) => "NotFoundException",
+            CreateFleetError::TaggingFailed(_) => "TaggingFailedException",
+            CreateFleetError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateGameServerGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateGameServerGroupError {
@@ -4311,6 +4369,17 @@ impl fmt::Display for CreateGameServerGroupError {
     }
 }
 impl Error for CreateGameServerGroupError {}
+impl ServiceErrorCode for CreateGameServerGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGameServerGroupError::Conflict(_) => "ConflictException",
+            CreateGameServerGroupError::InternalService(_) => "InternalServiceException",
+            CreateGameServerGroupError::InvalidRequest(_) => "InvalidRequestException",
+            CreateGameServerGroupError::LimitExceeded(_) => "LimitExceededException",
+            CreateGameServerGroupError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateGameSession
 #[derive(Debug, PartialEq)]
 pub enum CreateGameSessionError {
@@ -4405,6 +4474,26 @@ impl fmt::Display for CreateGameSessionError {
     }
 }
 impl Error for CreateGameSessionError {}
+impl ServiceErrorCode for CreateGameSessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGameSessionError::Conflict(_) => "ConflictException",
+            CreateGameSessionError::FleetCapacityExceeded(_) => "FleetCapacityExceededException",
+            CreateGameSessionError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateGameSessionError::InternalService(_) => "InternalServiceException",
+            CreateGameSessionError::InvalidFleetStatus(_) => "InvalidFleetStatusException",
+            CreateGameSessionError::InvalidRequest(_) => "InvalidRequestException",
+            CreateGameSessionError::LimitExceeded(_) => "LimitExceededException",
+            CreateGameSessionError::NotFound(_) => "NotFoundException",
+            CreateGameSessionError::TerminalRoutingStrategy(_) => {
+                "TerminalRoutingStrategyException"
+            }
+            CreateGameSessionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateGameSessionQueue
 #[derive(Debug, PartialEq)]
 pub enum CreateGameSessionQueueError {
@@ -4467,6 +4556,17 @@ impl fmt::Display for CreateGameSessionQueueError {
     }
 }
 impl Error for CreateGameSessionQueueError {}
+impl ServiceErrorCode for CreateGameSessionQueueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGameSessionQueueError::InternalService(_) => "InternalServiceException",
+            CreateGameSessionQueueError::InvalidRequest(_) => "InvalidRequestException",
+            CreateGameSessionQueueError::LimitExceeded(_) => "LimitExceededException",
+            CreateGameSessionQueueError::TaggingFailed(_) => "TaggingFailedException",
+            CreateGameSessionQueueError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateMatchmakingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum CreateMatchmakingConfigurationError {
@@ -4547,6 +4647,20 @@ impl fmt::Display for CreateMatchmakingConfigurationError {
     }
 }
 impl Error for CreateMatchmakingConfigurationError {}
+impl ServiceErrorCode for CreateMatchmakingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMatchmakingConfigurationError::InternalService(_) => "InternalServiceException",
+            CreateMatchmakingConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            CreateMatchmakingConfigurationError::LimitExceeded(_) => "LimitExceededException",
+            CreateMatchmakingConfigurationError::NotFound(_) => "NotFoundException",
+            CreateMatchmakingConfigurationError::TaggingFailed(_) => "TaggingFailedException",
+            CreateMatchmakingConfigurationError::UnsupportedRegion(_) => {
+                "UnsupportedRegionException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateMatchmakingRuleSet
 #[derive(Debug, PartialEq)]
 pub enum CreateMatchmakingRuleSetError {
@@ -4603,6 +4717,16 @@ impl fmt::Display for CreateMatchmakingRuleSetError {
     }
 }
 impl Error for CreateMatchmakingRuleSetError {}
+impl ServiceErrorCode for CreateMatchmakingRuleSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMatchmakingRuleSetError::InternalService(_) => "InternalServiceException",
+            CreateMatchmakingRuleSetError::InvalidRequest(_) => "InvalidRequestException",
+            CreateMatchmakingRuleSetError::TaggingFailed(_) => "TaggingFailedException",
+            CreateMatchmakingRuleSetError::UnsupportedRegion(_) => "UnsupportedRegionException",
+        }
+    }
+}
 /// Errors returned by CreatePlayerSession
 #[derive(Debug, PartialEq)]
 pub enum CreatePlayerSessionError {
@@ -4673,6 +4797,23 @@ impl fmt::Display for CreatePlayerSessionError {
     }
 }
 impl Error for CreatePlayerSessionError {}
+impl ServiceErrorCode for CreatePlayerSessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePlayerSessionError::GameSessionFull(_) => "GameSessionFullException",
+            CreatePlayerSessionError::InternalService(_) => "InternalServiceException",
+            CreatePlayerSessionError::InvalidGameSessionStatus(_) => {
+                "InvalidGameSessionStatusException"
+            }
+            CreatePlayerSessionError::InvalidRequest(_) => "InvalidRequestException",
+            CreatePlayerSessionError::NotFound(_) => "NotFoundException",
+            CreatePlayerSessionError::TerminalRoutingStrategy(_) => {
+                "TerminalRoutingStrategyException"
+            }
+            CreatePlayerSessionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreatePlayerSessions
 #[derive(Debug, PartialEq)]
 pub enum CreatePlayerSessionsError {
@@ -4749,6 +4890,23 @@ impl fmt::Display for CreatePlayerSessionsError {
     }
 }
 impl Error for CreatePlayerSessionsError {}
+impl ServiceErrorCode for CreatePlayerSessionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePlayerSessionsError::GameSessionFull(_) => "GameSessionFullException",
+            CreatePlayerSessionsError::InternalService(_) => "InternalServiceException",
+            CreatePlayerSessionsError::InvalidGameSessionStatus(_) => {
+                "InvalidGameSessionStatusException"
+            }
+            CreatePlayerSessionsError::InvalidRequest(_) => "InvalidRequestException",
+            CreatePlayerSessionsError::NotFound(_) => "NotFoundException",
+            CreatePlayerSessionsError::TerminalRoutingStrategy(_) => {
+                "TerminalRoutingStrategyException"
+            }
+            CreatePlayerSessionsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateScript
 #[derive(Debug, PartialEq)]
 pub enum CreateScriptError {
@@ -4803,6 +4961,17 @@ impl fmt::Display for CreateScriptError {
     }
 }
 impl Error for CreateScriptError {}
+impl ServiceErrorCode for CreateScriptError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateScriptError::Conflict(_) => "ConflictException",
+            CreateScriptError::InternalService(_) => "InternalServiceException",
+            CreateScriptError::InvalidRequest(_) => "InvalidRequestException",
+            CreateScriptError::TaggingFailed(_) => "TaggingFailedException",
+            CreateScriptError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateVpcPeeringAuthorization
 #[derive(Debug, PartialEq)]
 pub enum CreateVpcPeeringAuthorizationError {
@@ -4863,6 +5032,16 @@ impl fmt::Display for CreateVpcPeeringAuthorizationError {
     }
 }
 impl Error for CreateVpcPeeringAuthorizationError {}
+impl ServiceErrorCode for CreateVpcPeeringAuthorizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVpcPeeringAuthorizationError::InternalService(_) => "InternalServiceException",
+            CreateVpcPeeringAuthorizationError::InvalidRequest(_) => "InvalidRequestException",
+            CreateVpcPeeringAuthorizationError::NotFound(_) => "NotFoundException",
+            CreateVpcPeeringAuthorizationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateVpcPeeringConnection
 #[derive(Debug, PartialEq)]
 pub enum CreateVpcPeeringConnectionError {
@@ -4919,6 +5098,16 @@ impl fmt::Display for CreateVpcPeeringConnectionError {
     }
 }
 impl Error for CreateVpcPeeringConnectionError {}
+impl ServiceErrorCode for CreateVpcPeeringConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVpcPeeringConnectionError::InternalService(_) => "InternalServiceException",
+            CreateVpcPeeringConnectionError::InvalidRequest(_) => "InvalidRequestException",
+            CreateVpcPeeringConnectionError::NotFound(_) => "NotFoundException",
+            CreateVpcPeeringConnectionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteAlias
 #[derive(Debug, PartialEq)]
 pub enum DeleteAliasError {
@@ -4973,6 +5162,17 @@ impl fmt::Display for DeleteAliasError {
     }
 }
 impl Error for DeleteAliasError {}
+impl ServiceErrorCode for DeleteAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAliasError::InternalService(_) => "InternalServiceException",
+            DeleteAliasError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteAliasError::NotFound(_) => "NotFoundException",
+            DeleteAliasError::TaggingFailed(_) => "TaggingFailedException",
+            DeleteAliasError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteBuild
 #[derive(Debug, PartialEq)]
 pub enum DeleteBuildError {
@@ -5027,6 +5227,17 @@ impl fmt::Display for DeleteBuildError {
     }
 }
 impl Error for DeleteBuildError {}
+impl ServiceErrorCode for DeleteBuildError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBuildError::InternalService(_) => "InternalServiceException",
+            DeleteBuildError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteBuildError::NotFound(_) => "NotFoundException",
+            DeleteBuildError::TaggingFailed(_) => "TaggingFailedException",
+            DeleteBuildError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteFleet
 #[derive(Debug, PartialEq)]
 pub enum DeleteFleetError {
@@ -5087,6 +5298,18 @@ impl fmt::Display for DeleteFleetError {
     }
 }
 impl Error for DeleteFleetError {}
+impl ServiceErrorCode for DeleteFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFleetError::InternalService(_) => "InternalServiceException",
+            DeleteFleetError::InvalidFleetStatus(_) => "InvalidFleetStatusException",
+            DeleteFleetError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteFleetError::NotFound(_) => "NotFoundException",
+            DeleteFleetError::TaggingFailed(_) => "TaggingFailedException",
+            DeleteFleetError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteGameServerGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteGameServerGroupError {
@@ -5139,6 +5362,16 @@ impl fmt::Display for DeleteGameServerGroupError {
     }
 }
 impl Error for DeleteGameServerGroupError {}
+impl ServiceErrorCode for DeleteGameServerGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGameServerGroupError::InternalService(_) => "InternalServiceException",
+            DeleteGameServerGroupError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteGameServerGroupError::NotFound(_) => "NotFoundException",
+            DeleteGameServerGroupError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteGameSessionQueue
 #[derive(Debug, PartialEq)]
 pub enum DeleteGameSessionQueueError {
@@ -5199,6 +5432,17 @@ impl fmt::Display for DeleteGameSessionQueueError {
     }
 }
 impl Error for DeleteGameSessionQueueError {}
+impl ServiceErrorCode for DeleteGameSessionQueueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGameSessionQueueError::InternalService(_) => "InternalServiceException",
+            DeleteGameSessionQueueError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteGameSessionQueueError::NotFound(_) => "NotFoundException",
+            DeleteGameSessionQueueError::TaggingFailed(_) => "TaggingFailedException",
+            DeleteGameSessionQueueError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteMatchmakingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteMatchmakingConfigurationError {
@@ -5271,6 +5515,19 @@ impl fmt::Display for DeleteMatchmakingConfigurationError {
     }
 }
 impl Error for DeleteMatchmakingConfigurationError {}
+impl ServiceErrorCode for DeleteMatchmakingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMatchmakingConfigurationError::InternalService(_) => "InternalServiceException",
+            DeleteMatchmakingConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteMatchmakingConfigurationError::NotFound(_) => "NotFoundException",
+            DeleteMatchmakingConfigurationError::TaggingFailed(_) => "TaggingFailedException",
+            DeleteMatchmakingConfigurationError::UnsupportedRegion(_) => {
+                "UnsupportedRegionException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteMatchmakingRuleSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteMatchmakingRuleSetError {
@@ -5333,6 +5590,17 @@ impl fmt::Display for DeleteMatchmakingRuleSetError {
     }
 }
 impl Error for DeleteMatchmakingRuleSetError {}
+impl ServiceErrorCode for DeleteMatchmakingRuleSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMatchmakingRuleSetError::InternalService(_) => "InternalServiceException",
+            DeleteMatchmakingRuleSetError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteMatchmakingRuleSetError::NotFound(_) => "NotFoundException",
+            DeleteMatchmakingRuleSetError::TaggingFailed(_) => "TaggingFailedException",
+            DeleteMatchmakingRuleSetError::UnsupportedRegion(_) => "UnsupportedRegionException",
+        }
+    }
+}
 /// Errors returned by DeleteScalingPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteScalingPolicyError {
@@ -5381,6 +5649,16 @@ impl fmt::Display for DeleteScalingPolicyError {
     }
 }
 impl Error for DeleteScalingPolicyError {}
+impl ServiceErrorCode for DeleteScalingPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteScalingPolicyError::InternalService(_) => "InternalServiceException",
+            DeleteScalingPolicyError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteScalingPolicyError::NotFound(_) => "NotFoundException",
+            DeleteScalingPolicyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteScript
 #[derive(Debug, PartialEq)]
 pub enum DeleteScriptError {
@@ -5435,6 +5713,17 @@ impl fmt::Display for DeleteScriptError {
     }
 }
 impl Error for DeleteScriptError {}
+impl ServiceErrorCode for DeleteScriptError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteScriptError::InternalService(_) => "InternalServiceException",
+            DeleteScriptError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteScriptError::NotFound(_) => "NotFoundException",
+            DeleteScriptError::TaggingFailed(_) => "TaggingFailedException",
+            DeleteScriptError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteVpcPeeringAuthorization
 #[derive(Debug, PartialEq)]
 pub enum DeleteVpcPeeringAuthorizationError {
@@ -5495,6 +5784,16 @@ impl fmt::Display for DeleteVpcPeeringAuthorizationError {
     }
 }
 impl Error for DeleteVpcPeeringAuthorizationError {}
+impl ServiceErrorCode for DeleteVpcPeeringAuthorizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVpcPeeringAuthorizationError::InternalService(_) => "InternalServiceException",
+            DeleteVpcPeeringAuthorizationError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteVpcPeeringAuthorizationError::NotFound(_) => "NotFoundException",
+            DeleteVpcPeeringAuthorizationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteVpcPeeringConnection
 #[derive(Debug, PartialEq)]
 pub enum DeleteVpcPeeringConnectionError {
@@ -5551,6 +5850,16 @@ impl fmt::Display for DeleteVpcPeeringConnectionError {
     }
 }
 impl Error for DeleteVpcPeeringConnectionError {}
+impl ServiceErrorCode for DeleteVpcPeeringConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVpcPeeringConnectionError::InternalService(_) => "InternalServiceException",
+            DeleteVpcPeeringConnectionError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteVpcPeeringConnectionError::NotFound(_) => "NotFoundException",
+            DeleteVpcPeeringConnectionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeregisterGameServer
 #[derive(Debug, PartialEq)]
 pub enum DeregisterGameServerError {
@@ -5601,6 +5910,16 @@ impl fmt::Display for DeregisterGameServerError {
     }
 }
 impl Error for DeregisterGameServerError {}
+impl ServiceErrorCode for DeregisterGameServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterGameServerError::InternalService(_) => "InternalServiceException",
+            DeregisterGameServerError::InvalidRequest(_) => "InvalidRequestException",
+            DeregisterGameServerError::NotFound(_) => "NotFoundException",
+            DeregisterGameServerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeAlias
 #[derive(Debug, PartialEq)]
 pub enum DescribeAliasError {
@@ -5649,6 +5968,16 @@ impl fmt::Display for DescribeAliasError {
     }
 }
 impl Error for DescribeAliasError {}
+impl ServiceErrorCode for DescribeAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAliasError::InternalService(_) => "InternalServiceException",
+            DescribeAliasError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeAliasError::NotFound(_) => "NotFoundException",
+            DescribeAliasError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeBuild
 #[derive(Debug, PartialEq)]
 pub enum DescribeBuildError {
@@ -5697,6 +6026,16 @@ impl fmt::Display for DescribeBuildError {
     }
 }
 impl Error for DescribeBuildError {}
+impl ServiceErrorCode for DescribeBuildError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBuildError::InternalService(_) => "InternalServiceException",
+            DescribeBuildError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeBuildError::NotFound(_) => "NotFoundException",
+            DescribeBuildError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeEC2InstanceLimits
 #[derive(Debug, PartialEq)]
 pub enum DescribeEC2InstanceLimitsError {
@@ -5745,6 +6084,15 @@ impl fmt::Display for DescribeEC2InstanceLimitsError {
     }
 }
 impl Error for DescribeEC2InstanceLimitsError {}
+impl ServiceErrorCode for DescribeEC2InstanceLimitsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEC2InstanceLimitsError::InternalService(_) => "InternalServiceException",
+            DescribeEC2InstanceLimitsError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeEC2InstanceLimitsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeFleetAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeFleetAttributesError {
@@ -5799,6 +6147,16 @@ impl fmt::Display for DescribeFleetAttributesError {
     }
 }
 impl Error for DescribeFleetAttributesError {}
+impl ServiceErrorCode for DescribeFleetAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFleetAttributesError::InternalService(_) => "InternalServiceException",
+            DescribeFleetAttributesError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeFleetAttributesError::NotFound(_) => "NotFoundException",
+            DescribeFleetAttributesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeFleetCapacity
 #[derive(Debug, PartialEq)]
 pub enum DescribeFleetCapacityError {
@@ -5851,6 +6209,16 @@ impl fmt::Display for DescribeFleetCapacityError {
     }
 }
 impl Error for DescribeFleetCapacityError {}
+impl ServiceErrorCode for DescribeFleetCapacityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFleetCapacityError::InternalService(_) => "InternalServiceException",
+            DescribeFleetCapacityError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeFleetCapacityError::NotFound(_) => "NotFoundException",
+            DescribeFleetCapacityError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeFleetEvents
 #[derive(Debug, PartialEq)]
 pub enum DescribeFleetEventsError {
@@ -5899,6 +6267,16 @@ impl fmt::Display for DescribeFleetEventsError {
     }
 }
 impl Error for DescribeFleetEventsError {}
+impl ServiceErrorCode for DescribeFleetEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFleetEventsError::InternalService(_) => "InternalServiceException",
+            DescribeFleetEventsError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeFleetEventsError::NotFound(_) => "NotFoundException",
+            DescribeFleetEventsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeFleetPortSettings
 #[derive(Debug, PartialEq)]
 pub enum DescribeFleetPortSettingsError {
@@ -5953,6 +6331,16 @@ impl fmt::Display for DescribeFleetPortSettingsError {
     }
 }
 impl Error for DescribeFleetPortSettingsError {}
+impl ServiceErrorCode for DescribeFleetPortSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFleetPortSettingsError::InternalService(_) => "InternalServiceException",
+            DescribeFleetPortSettingsError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeFleetPortSettingsError::NotFound(_) => "NotFoundException",
+            DescribeFleetPortSettingsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeFleetUtilization
 #[derive(Debug, PartialEq)]
 pub enum DescribeFleetUtilizationError {
@@ -6007,6 +6395,16 @@ impl fmt::Display for DescribeFleetUtilizationError {
     }
 }
 impl Error for DescribeFleetUtilizationError {}
+impl ServiceErrorCode for DescribeFleetUtilizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFleetUtilizationError::InternalService(_) => "InternalServiceException",
+            DescribeFleetUtilizationError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeFleetUtilizationError::NotFound(_) => "NotFoundException",
+            DescribeFleetUtilizationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeGameServer
 #[derive(Debug, PartialEq)]
 pub enum DescribeGameServerError {
@@ -6055,6 +6453,16 @@ impl fmt::Display for DescribeGameServerError {
     }
 }
 impl Error for DescribeGameServerError {}
+impl ServiceErrorCode for DescribeGameServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGameServerError::InternalService(_) => "InternalServiceException",
+            DescribeGameServerError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeGameServerError::NotFound(_) => "NotFoundException",
+            DescribeGameServerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeGameServerGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeGameServerGroupError {
@@ -6109,6 +6517,16 @@ impl fmt::Display for DescribeGameServerGroupError {
     }
 }
 impl Error for DescribeGameServerGroupError {}
+impl ServiceErrorCode for DescribeGameServerGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGameServerGroupError::InternalService(_) => "InternalServiceException",
+            DescribeGameServerGroupError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeGameServerGroupError::NotFound(_) => "NotFoundException",
+            DescribeGameServerGroupError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeGameServerInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeGameServerInstancesError {
@@ -6167,6 +6585,16 @@ impl fmt::Display for DescribeGameServerInstancesError {
     }
 }
 impl Error for DescribeGameServerInstancesError {}
+impl ServiceErrorCode for DescribeGameServerInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGameServerInstancesError::InternalService(_) => "InternalServiceException",
+            DescribeGameServerInstancesError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeGameServerInstancesError::NotFound(_) => "NotFoundException",
+            DescribeGameServerInstancesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeGameSessionDetails
 #[derive(Debug, PartialEq)]
 pub enum DescribeGameSessionDetailsError {
@@ -6233,6 +6661,19 @@ impl fmt::Display for DescribeGameSessionDetailsError {
     }
 }
 impl Error for DescribeGameSessionDetailsError {}
+impl ServiceErrorCode for DescribeGameSessionDetailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGameSessionDetailsError::InternalService(_) => "InternalServiceException",
+            DescribeGameSessionDetailsError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeGameSessionDetailsError::NotFound(_) => "NotFoundException",
+            DescribeGameSessionDetailsError::TerminalRoutingStrategy(_) => {
+                "TerminalRoutingStrategyException"
+            }
+            DescribeGameSessionDetailsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeGameSessionPlacement
 #[derive(Debug, PartialEq)]
 pub enum DescribeGameSessionPlacementError {
@@ -6291,6 +6732,16 @@ impl fmt::Display for DescribeGameSessionPlacementError {
     }
 }
 impl Error for DescribeGameSessionPlacementError {}
+impl ServiceErrorCode for DescribeGameSessionPlacementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGameSessionPlacementError::InternalService(_) => "InternalServiceException",
+            DescribeGameSessionPlacementError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeGameSessionPlacementError::NotFound(_) => "NotFoundException",
+            DescribeGameSessionPlacementError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeGameSessionQueues
 #[derive(Debug, PartialEq)]
 pub enum DescribeGameSessionQueuesError {
@@ -6345,6 +6796,16 @@ impl fmt::Display for DescribeGameSessionQueuesError {
     }
 }
 impl Error for DescribeGameSessionQueuesError {}
+impl ServiceErrorCode for DescribeGameSessionQueuesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGameSessionQueuesError::InternalService(_) => "InternalServiceException",
+            DescribeGameSessionQueuesError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeGameSessionQueuesError::NotFound(_) => "NotFoundException",
+            DescribeGameSessionQueuesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeGameSessions
 #[derive(Debug, PartialEq)]
 pub enum DescribeGameSessionsError {
@@ -6403,6 +6864,19 @@ impl fmt::Display for DescribeGameSessionsError {
     }
 }
 impl Error for DescribeGameSessionsError {}
+impl ServiceErrorCode for DescribeGameSessionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGameSessionsError::InternalService(_) => "InternalServiceException",
+            DescribeGameSessionsError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeGameSessionsError::NotFound(_) => "NotFoundException",
+            DescribeGameSessionsError::TerminalRoutingStrategy(_) => {
+                "TerminalRoutingStrategyException"
+            }
+            DescribeGameSessionsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstancesError {
@@ -6451,6 +6925,16 @@ impl fmt::Display for DescribeInstancesError {
     }
 }
 impl Error for DescribeInstancesError {}
+impl ServiceErrorCode for DescribeInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInstancesError::InternalService(_) => "InternalServiceException",
+            DescribeInstancesError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeInstancesError::NotFound(_) => "NotFoundException",
+            DescribeInstancesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeMatchmaking
 #[derive(Debug, PartialEq)]
 pub enum DescribeMatchmakingError {
@@ -6495,6 +6979,15 @@ impl fmt::Display for DescribeMatchmakingError {
     }
 }
 impl Error for DescribeMatchmakingError {}
+impl ServiceErrorCode for DescribeMatchmakingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMatchmakingError::InternalService(_) => "InternalServiceException",
+            DescribeMatchmakingError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeMatchmakingError::UnsupportedRegion(_) => "UnsupportedRegionException",
+        }
+    }
+}
 /// Errors returned by DescribeMatchmakingConfigurations
 #[derive(Debug, PartialEq)]
 pub enum DescribeMatchmakingConfigurationsError {
@@ -6551,6 +7044,19 @@ impl fmt::Display for DescribeMatchmakingConfigurationsError {
     }
 }
 impl Error for DescribeMatchmakingConfigurationsError {}
+impl ServiceErrorCode for DescribeMatchmakingConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMatchmakingConfigurationsError::InternalService(_) => {
+                "InternalServiceException"
+            }
+            DescribeMatchmakingConfigurationsError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeMatchmakingConfigurationsError::UnsupportedRegion(_) => {
+                "UnsupportedRegionException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeMatchmakingRuleSets
 #[derive(Debug, PartialEq)]
 pub enum DescribeMatchmakingRuleSetsError {
@@ -6611,6 +7117,16 @@ impl fmt::Display for DescribeMatchmakingRuleSetsError {
     }
 }
 impl Error for DescribeMatchmakingRuleSetsError {}
+impl ServiceErrorCode for DescribeMatchmakingRuleSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMatchmakingRuleSetsError::InternalService(_) => "InternalServiceException",
+            DescribeMatchmakingRuleSetsError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeMatchmakingRuleSetsError::NotFound(_) => "NotFoundException",
+            DescribeMatchmakingRuleSetsError::UnsupportedRegion(_) => "UnsupportedRegionException",
+        }
+    }
+}
 /// Errors returned by DescribePlayerSessions
 #[derive(Debug, PartialEq)]
 pub enum DescribePlayerSessionsError {
@@ -6663,6 +7179,16 @@ impl fmt::Display for DescribePlayerSessionsError {
     }
 }
 impl Error for DescribePlayerSessionsError {}
+impl ServiceErrorCode for DescribePlayerSessionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePlayerSessionsError::InternalService(_) => "InternalServiceException",
+            DescribePlayerSessionsError::InvalidRequest(_) => "InvalidRequestException",
+            DescribePlayerSessionsError::NotFound(_) => "NotFoundException",
+            DescribePlayerSessionsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeRuntimeConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribeRuntimeConfigurationError {
@@ -6721,6 +7247,16 @@ impl fmt::Display for DescribeRuntimeConfigurationError {
     }
 }
 impl Error for DescribeRuntimeConfigurationError {}
+impl ServiceErrorCode for DescribeRuntimeConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRuntimeConfigurationError::InternalService(_) => "InternalServiceException",
+            DescribeRuntimeConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeRuntimeConfigurationError::NotFound(_) => "NotFoundException",
+            DescribeRuntimeConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeScalingPolicies
 #[derive(Debug, PartialEq)]
 pub enum DescribeScalingPoliciesError {
@@ -6775,6 +7311,16 @@ impl fmt::Display for DescribeScalingPoliciesError {
     }
 }
 impl Error for DescribeScalingPoliciesError {}
+impl ServiceErrorCode for DescribeScalingPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeScalingPoliciesError::InternalService(_) => "InternalServiceException",
+            DescribeScalingPoliciesError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeScalingPoliciesError::NotFound(_) => "NotFoundException",
+            DescribeScalingPoliciesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeScript
 #[derive(Debug, PartialEq)]
 pub enum DescribeScriptError {
@@ -6823,6 +7369,16 @@ impl fmt::Display for DescribeScriptError {
     }
 }
 impl Error for DescribeScriptError {}
+impl ServiceErrorCode for DescribeScriptError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeScriptError::InternalService(_) => "InternalServiceException",
+            DescribeScriptError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeScriptError::NotFound(_) => "NotFoundException",
+            DescribeScriptError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeVpcPeeringAuthorizations
 #[derive(Debug, PartialEq)]
 pub enum DescribeVpcPeeringAuthorizationsError {
@@ -6879,6 +7435,15 @@ impl fmt::Display for DescribeVpcPeeringAuthorizationsError {
     }
 }
 impl Error for DescribeVpcPeeringAuthorizationsError {}
+impl ServiceErrorCode for DescribeVpcPeeringAuthorizationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeVpcPeeringAuthorizationsError::InternalService(_) => "InternalServiceException",
+            DescribeVpcPeeringAuthorizationsError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeVpcPeeringAuthorizationsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeVpcPeeringConnections
 #[derive(Debug, PartialEq)]
 pub enum DescribeVpcPeeringConnectionsError {
@@ -6939,6 +7504,16 @@ impl fmt::Display for DescribeVpcPeeringConnectionsError {
     }
 }
 impl Error for DescribeVpcPeeringConnectionsError {}
+impl ServiceErrorCode for DescribeVpcPeeringConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeVpcPeeringConnectionsError::InternalService(_) => "InternalServiceException",
+            DescribeVpcPeeringConnectionsError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeVpcPeeringConnectionsError::NotFound(_) => "NotFoundException",
+            DescribeVpcPeeringConnectionsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetGameSessionLogUrl
 #[derive(Debug, PartialEq)]
 pub enum GetGameSessionLogUrlError {
@@ -6989,6 +7564,16 @@ impl fmt::Display for GetGameSessionLogUrlError {
     }
 }
 impl Error for GetGameSessionLogUrlError {}
+impl ServiceErrorCode for GetGameSessionLogUrlError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGameSessionLogUrlError::InternalService(_) => "InternalServiceException",
+            GetGameSessionLogUrlError::InvalidRequest(_) => "InvalidRequestException",
+            GetGameSessionLogUrlError::NotFound(_) => "NotFoundException",
+            GetGameSessionLogUrlError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetInstanceAccess
 #[derive(Debug, PartialEq)]
 pub enum GetInstanceAccessError {
@@ -7037,6 +7622,16 @@ impl fmt::Display for GetInstanceAccessError {
     }
 }
 impl Error for GetInstanceAccessError {}
+impl ServiceErrorCode for GetInstanceAccessError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInstanceAccessError::InternalService(_) => "InternalServiceException",
+            GetInstanceAccessError::InvalidRequest(_) => "InvalidRequestException",
+            GetInstanceAccessError::NotFound(_) => "NotFoundException",
+            GetInstanceAccessError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListAliases
 #[derive(Debug, PartialEq)]
 pub enum ListAliasesError {
@@ -7079,6 +7674,15 @@ impl fmt::Display for ListAliasesError {
     }
 }
 impl Error for ListAliasesError {}
+impl ServiceErrorCode for ListAliasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAliasesError::InternalService(_) => "InternalServiceException",
+            ListAliasesError::InvalidRequest(_) => "InvalidRequestException",
+            ListAliasesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListBuilds
 #[derive(Debug, PartialEq)]
 pub enum ListBuildsError {
@@ -7121,6 +7725,15 @@ impl fmt::Display for ListBuildsError {
     }
 }
 impl Error for ListBuildsError {}
+impl ServiceErrorCode for ListBuildsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBuildsError::InternalService(_) => "InternalServiceException",
+            ListBuildsError::InvalidRequest(_) => "InvalidRequestException",
+            ListBuildsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListFleets
 #[derive(Debug, PartialEq)]
 pub enum ListFleetsError {
@@ -7169,6 +7782,16 @@ impl fmt::Display for ListFleetsError {
     }
 }
 impl Error for ListFleetsError {}
+impl ServiceErrorCode for ListFleetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFleetsError::InternalService(_) => "InternalServiceException",
+            ListFleetsError::InvalidRequest(_) => "InvalidRequestException",
+            ListFleetsError::NotFound(_) => "NotFoundException",
+            ListFleetsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListGameServerGroups
 #[derive(Debug, PartialEq)]
 pub enum ListGameServerGroupsError {
@@ -7213,6 +7836,15 @@ impl fmt::Display for ListGameServerGroupsError {
     }
 }
 impl Error for ListGameServerGroupsError {}
+impl ServiceErrorCode for ListGameServerGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGameServerGroupsError::InternalService(_) => "InternalServiceException",
+            ListGameServerGroupsError::InvalidRequest(_) => "InvalidRequestException",
+            ListGameServerGroupsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListGameServers
 #[derive(Debug, PartialEq)]
 pub enum ListGameServersError {
@@ -7255,6 +7887,15 @@ impl fmt::Display for ListGameServersError {
     }
 }
 impl Error for ListGameServersError {}
+impl ServiceErrorCode for ListGameServersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGameServersError::InternalService(_) => "InternalServiceException",
+            ListGameServersError::InvalidRequest(_) => "InvalidRequestException",
+            ListGameServersError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListScripts
 #[derive(Debug, PartialEq)]
 pub enum ListScriptsError {
@@ -7297,6 +7938,15 @@ impl fmt::Display for ListScriptsError {
     }
 }
 impl Error for ListScriptsError {}
+impl ServiceErrorCode for ListScriptsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListScriptsError::InternalService(_) => "InternalServiceException",
+            ListScriptsError::InvalidRequest(_) => "InvalidRequestException",
+            ListScriptsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -7345,6 +7995,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalService(_) => "InternalServiceException",
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+            ListTagsForResourceError::TaggingFailed(_) => "TaggingFailedException",
+        }
+    }
+}
 /// Errors returned by PutScalingPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutScalingPolicyError {
@@ -7393,6 +8053,16 @@ impl fmt::Display for PutScalingPolicyError {
     }
 }
 impl Error for PutScalingPolicyError {}
+impl ServiceErrorCode for PutScalingPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutScalingPolicyError::InternalService(_) => "InternalServiceException",
+            PutScalingPolicyError::InvalidRequest(_) => "InvalidRequestException",
+            PutScalingPolicyError::NotFound(_) => "NotFoundException",
+            PutScalingPolicyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by RegisterGameServer
 #[derive(Debug, PartialEq)]
 pub enum RegisterGameServerError {
@@ -7447,6 +8117,17 @@ impl fmt::Display for RegisterGameServerError {
     }
 }
 impl Error for RegisterGameServerError {}
+impl ServiceErrorCode for RegisterGameServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterGameServerError::Conflict(_) => "ConflictException",
+            RegisterGameServerError::InternalService(_) => "InternalServiceException",
+            RegisterGameServerError::InvalidRequest(_) => "InvalidRequestException",
+            RegisterGameServerError::LimitExceeded(_) => "LimitExceededException",
+            RegisterGameServerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by RequestUploadCredentials
 #[derive(Debug, PartialEq)]
 pub enum RequestUploadCredentialsError {
@@ -7501,6 +8182,16 @@ impl fmt::Display for RequestUploadCredentialsError {
     }
 }
 impl Error for RequestUploadCredentialsError {}
+impl ServiceErrorCode for RequestUploadCredentialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RequestUploadCredentialsError::InternalService(_) => "InternalServiceException",
+            RequestUploadCredentialsError::InvalidRequest(_) => "InvalidRequestException",
+            RequestUploadCredentialsError::NotFound(_) => "NotFoundException",
+            RequestUploadCredentialsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ResolveAlias
 #[derive(Debug, PartialEq)]
 pub enum ResolveAliasError {
@@ -7557,6 +8248,17 @@ impl fmt::Display for ResolveAliasError {
     }
 }
 impl Error for ResolveAliasError {}
+impl ServiceErrorCode for ResolveAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResolveAliasError::InternalService(_) => "InternalServiceException",
+            ResolveAliasError::InvalidRequest(_) => "InvalidRequestException",
+            ResolveAliasError::NotFound(_) => "NotFoundException",
+            ResolveAliasError::TerminalRoutingStrategy(_) => "TerminalRoutingStrategyException",
+            ResolveAliasError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ResumeGameServerGroup
 #[derive(Debug, PartialEq)]
 pub enum ResumeGameServerGroupError {
@@ -7609,6 +8311,16 @@ impl fmt::Display for ResumeGameServerGroupError {
     }
 }
 impl Error for ResumeGameServerGroupError {}
+impl ServiceErrorCode for ResumeGameServerGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResumeGameServerGroupError::InternalService(_) => "InternalServiceException",
+            ResumeGameServerGroupError::InvalidRequest(_) => "InvalidRequestException",
+            ResumeGameServerGroupError::NotFound(_) => "NotFoundException",
+            ResumeGameServerGroupError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by SearchGameSessions
 #[derive(Debug, PartialEq)]
 pub enum SearchGameSessionsError {
@@ -7665,6 +8377,19 @@ impl fmt::Display for SearchGameSessionsError {
     }
 }
 impl Error for SearchGameSessionsError {}
+impl ServiceErrorCode for SearchGameSessionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchGameSessionsError::InternalService(_) => "InternalServiceException",
+            SearchGameSessionsError::InvalidRequest(_) => "InvalidRequestException",
+            SearchGameSessionsError::NotFound(_) => "NotFoundException",
+            SearchGameSessionsError::TerminalRoutingStrategy(_) => {
+                "TerminalRoutingStrategyException"
+            }
+            SearchGameSessionsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by StartFleetActions
 #[derive(Debug, PartialEq)]
 pub enum StartFleetActionsError {
@@ -7713,6 +8438,16 @@ impl fmt::Display for StartFleetActionsError {
     }
 }
 impl Error for StartFleetActionsError {}
+impl ServiceErrorCode for StartFleetActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartFleetActionsError::InternalService(_) => "InternalServiceException",
+            StartFleetActionsError::InvalidRequest(_) => "InvalidRequestException",
+            StartFleetActionsError::NotFound(_) => "NotFoundException",
+            StartFleetActionsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by StartGameSessionPlacement
 #[derive(Debug, PartialEq)]
 pub enum StartGameSessionPlacementError {
@@ -7767,6 +8502,16 @@ impl fmt::Display for StartGameSessionPlacementError {
     }
 }
 impl Error for StartGameSessionPlacementError {}
+impl ServiceErrorCode for StartGameSessionPlacementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartGameSessionPlacementError::InternalService(_) => "InternalServiceException",
+            StartGameSessionPlacementError::InvalidRequest(_) => "InvalidRequestException",
+            StartGameSessionPlacementError::NotFound(_) => "NotFoundException",
+            StartGameSessionPlacementError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by StartMatchBackfill
 #[derive(Debug, PartialEq)]
 pub enum StartMatchBackfillError {
@@ -7817,6 +8562,16 @@ impl fmt::Display for StartMatchBackfillError {
     }
 }
 impl Error for StartMatchBackfillError {}
+impl ServiceErrorCode for StartMatchBackfillError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartMatchBackfillError::InternalService(_) => "InternalServiceException",
+            StartMatchBackfillError::InvalidRequest(_) => "InvalidRequestException",
+            StartMatchBackfillError::NotFound(_) => "NotFoundException",
+            StartMatchBackfillError::UnsupportedRegion(_) => "UnsupportedRegionException",
+        }
+    }
+}
 /// Errors returned by StartMatchmaking
 #[derive(Debug, PartialEq)]
 pub enum StartMatchmakingError {
@@ -7865,6 +8620,16 @@ impl fmt::Display for StartMatchmakingError {
     }
 }
 impl Error for StartMatchmakingError {}
+impl ServiceErrorCode for StartMatchmakingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartMatchmakingError::InternalService(_) => "InternalServiceException",
+            StartMatchmakingError::InvalidRequest(_) => "InvalidRequestException",
+            StartMatchmakingError::NotFound(_) => "NotFoundException",
+            StartMatchmakingError::UnsupportedRegion(_) => "UnsupportedRegionException",
+        }
+    }
+}
 /// Errors returned by StopFleetActions
 #[derive(Debug, PartialEq)]
 pub enum StopFleetActionsError {
@@ -7913,6 +8678,16 @@ impl fmt::Display for StopFleetActionsError {
     }
 }
 impl Error for StopFleetActionsError {}
+impl ServiceErrorCode for StopFleetActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopFleetActionsError::InternalService(_) => "InternalServiceException",
+            StopFleetActionsError::InvalidRequest(_) => "InvalidRequestException",
+            StopFleetActionsError::NotFound(_) => "NotFoundException",
+            StopFleetActionsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by StopGameSessionPlacement
 #[derive(Debug, PartialEq)]
 pub enum StopGameSessionPlacementError {
@@ -7967,6 +8742,16 @@ impl fmt::Display for StopGameSessionPlacementError {
     }
 }
 impl Error for StopGameSessionPlacementError {}
+impl ServiceErrorCode for StopGameSessionPlacementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopGameSessionPlacementError::InternalService(_) => "InternalServiceException",
+            StopGameSessionPlacementError::InvalidRequest(_) => "InvalidRequestException",
+            StopGameSessionPlacementError::NotFound(_) => "NotFoundException",
+            StopGameSessionPlacementError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by StopMatchmaking
 #[derive(Debug, PartialEq)]
 pub enum StopMatchmakingError {
@@ -8015,6 +8800,16 @@ impl fmt::Display for StopMatchmakingError {
     }
 }
 impl Error for StopMatchmakingError {}
+impl ServiceErrorCode for StopMatchmakingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopMatchmakingError::InternalService(_) => "InternalServiceException",
+            StopMatchmakingError::InvalidRequest(_) => "InvalidRequestException",
+            StopMatchmakingError::NotFound(_) => "NotFoundException",
+            StopMatchmakingError::UnsupportedRegion(_) => "UnsupportedRegionException",
+        }
+    }
+}
 /// Errors returned by SuspendGameServerGroup
 #[derive(Debug, PartialEq)]
 pub enum SuspendGameServerGroupError {
@@ -8067,6 +8862,16 @@ impl fmt::Display for SuspendGameServerGroupError {
     }
 }
 impl Error for SuspendGameServerGroupError {}
+impl ServiceErrorCode for SuspendGameServerGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SuspendGameServerGroupError::InternalService(_) => "InternalServiceException",
+            SuspendGameServerGroupError::InvalidRequest(_) => "InvalidRequestException",
+            SuspendGameServerGroupError::NotFound(_) => "NotFoundException",
+            SuspendGameServerGroupError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -8115,6 +8920,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalService(_) => "InternalServiceException",
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+            TagResourceError::TaggingFailed(_) => "TaggingFailedException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -8163,6 +8978,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalService(_) => "InternalServiceException",
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+            UntagResourceError::TaggingFailed(_) => "TaggingFailedException",
+        }
+    }
+}
 /// Errors returned by UpdateAlias
 #[derive(Debug, PartialEq)]
 pub enum UpdateAliasError {
@@ -8211,6 +9036,16 @@ impl fmt::Display for UpdateAliasError {
     }
 }
 impl Error for UpdateAliasError {}
+impl ServiceErrorCode for UpdateAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAliasError::InternalService(_) => "InternalServiceException",
+            UpdateAliasError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateAliasError::NotFound(_) => "NotFoundException",
+            UpdateAliasError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateBuild
 #[derive(Debug, PartialEq)]
 pub enum UpdateBuildError {
@@ -8259,6 +9094,16 @@ impl fmt::Display for UpdateBuildError {
     }
 }
 impl Error for UpdateBuildError {}
+impl ServiceErrorCode for UpdateBuildError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateBuildError::InternalService(_) => "InternalServiceException",
+            UpdateBuildError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateBuildError::NotFound(_) => "NotFoundException",
+            UpdateBuildError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateFleetAttributes
 #[derive(Debug, PartialEq)]
 pub enum UpdateFleetAttributesError {
@@ -8331,6 +9176,19 @@ impl fmt::Display for UpdateFleetAttributesError {
     }
 }
 impl Error for UpdateFleetAttributesError {}
+impl ServiceErrorCode for UpdateFleetAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFleetAttributesError::Conflict(_) => "ConflictException",
+            UpdateFleetAttributesError::InternalService(_) => "InternalServiceException",
+            UpdateFleetAttributesError::InvalidFleetStatus(_) => "InvalidFleetStatusException",
+            UpdateFleetAttributesError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateFleetAttributesError::LimitExceeded(_) => "LimitExceededException",
+            UpdateFleetAttributesError::NotFound(_) => "NotFoundException",
+            UpdateFleetAttributesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateFleetCapacity
 #[derive(Debug, PartialEq)]
 pub enum UpdateFleetCapacityError {
@@ -8399,6 +9257,19 @@ impl fmt::Display for UpdateFleetCapacityError {
     }
 }
 impl Error for UpdateFleetCapacityError {}
+impl ServiceErrorCode for UpdateFleetCapacityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFleetCapacityError::Conflict(_) => "ConflictException",
+            UpdateFleetCapacityError::InternalService(_) => "InternalServiceException",
+            UpdateFleetCapacityError::InvalidFleetStatus(_) => "InvalidFleetStatusException",
+            UpdateFleetCapacityError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateFleetCapacityError::LimitExceeded(_) => "LimitExceededException",
+            UpdateFleetCapacityError::NotFound(_) => "NotFoundException",
+            UpdateFleetCapacityError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateFleetPortSettings
 #[derive(Debug, PartialEq)]
 pub enum UpdateFleetPortSettingsError {
@@ -8475,6 +9346,19 @@ impl fmt::Display for UpdateFleetPortSettingsError {
     }
 }
 impl Error for UpdateFleetPortSettingsError {}
+impl ServiceErrorCode for UpdateFleetPortSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFleetPortSettingsError::Conflict(_) => "ConflictException",
+            UpdateFleetPortSettingsError::InternalService(_) => "InternalServiceException",
+            UpdateFleetPortSettingsError::InvalidFleetStatus(_) => "InvalidFleetStatusException",
+            UpdateFleetPortSettingsError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateFleetPortSettingsError::LimitExceeded(_) => "LimitExceededException",
+            UpdateFleetPortSettingsError::NotFound(_) => "NotFoundException",
+            UpdateFleetPortSettingsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateGameServer
 #[derive(Debug, PartialEq)]
 pub enum UpdateGameServerError {
@@ -8523,6 +9407,16 @@ impl fmt::Display for UpdateGameServerError {
     }
 }
 impl Error for UpdateGameServerError {}
+impl ServiceErrorCode for UpdateGameServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGameServerError::InternalService(_) => "InternalServiceException",
+            UpdateGameServerError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateGameServerError::NotFound(_) => "NotFoundException",
+            UpdateGameServerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateGameServerGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateGameServerGroupError {
@@ -8575,6 +9469,16 @@ impl fmt::Display for UpdateGameServerGroupError {
     }
 }
 impl Error for UpdateGameServerGroupError {}
+impl ServiceErrorCode for UpdateGameServerGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGameServerGroupError::InternalService(_) => "InternalServiceException",
+            UpdateGameServerGroupError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateGameServerGroupError::NotFound(_) => "NotFoundException",
+            UpdateGameServerGroupError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateGameSession
 #[derive(Debug, PartialEq)]
 pub enum UpdateGameSessionError {
@@ -8637,6 +9541,20 @@ impl fmt::Display for UpdateGameSessionError {
     }
 }
 impl Error for UpdateGameSessionError {}
+impl ServiceErrorCode for UpdateGameSessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGameSessionError::Conflict(_) => "ConflictException",
+            UpdateGameSessionError::InternalService(_) => "InternalServiceException",
+            UpdateGameSessionError::InvalidGameSessionStatus(_) => {
+                "InvalidGameSessionStatusException"
+            }
+            UpdateGameSessionError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateGameSessionError::NotFound(_) => "NotFoundException",
+            UpdateGameSessionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateGameSessionQueue
 #[derive(Debug, PartialEq)]
 pub enum UpdateGameSessionQueueError {
@@ -8689,6 +9607,16 @@ impl fmt::Display for UpdateGameSessionQueueError {
     }
 }
 impl Error for UpdateGameSessionQueueError {}
+impl ServiceErrorCode for UpdateGameSessionQueueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGameSessionQueueError::InternalService(_) => "InternalServiceException",
+            UpdateGameSessionQueueError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateGameSessionQueueError::NotFound(_) => "NotFoundException",
+            UpdateGameSessionQueueError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateMatchmakingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateMatchmakingConfigurationError {
@@ -8753,6 +9681,18 @@ impl fmt::Display for UpdateMatchmakingConfigurationError {
     }
 }
 impl Error for UpdateMatchmakingConfigurationError {}
+impl ServiceErrorCode for UpdateMatchmakingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMatchmakingConfigurationError::InternalService(_) => "InternalServiceException",
+            UpdateMatchmakingConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateMatchmakingConfigurationError::NotFound(_) => "NotFoundException",
+            UpdateMatchmakingConfigurationError::UnsupportedRegion(_) => {
+                "UnsupportedRegionException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateRuntimeConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateRuntimeConfigurationError {
@@ -8819,6 +9759,17 @@ impl fmt::Display for UpdateRuntimeConfigurationError {
     }
 }
 impl Error for UpdateRuntimeConfigurationError {}
+impl ServiceErrorCode for UpdateRuntimeConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRuntimeConfigurationError::InternalService(_) => "InternalServiceException",
+            UpdateRuntimeConfigurationError::InvalidFleetStatus(_) => "InvalidFleetStatusException",
+            UpdateRuntimeConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateRuntimeConfigurationError::NotFound(_) => "NotFoundException",
+            UpdateRuntimeConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateScript
 #[derive(Debug, PartialEq)]
 pub enum UpdateScriptError {
@@ -8867,6 +9818,16 @@ impl fmt::Display for UpdateScriptError {
     }
 }
 impl Error for UpdateScriptError {}
+impl ServiceErrorCode for UpdateScriptError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateScriptError::InternalService(_) => "InternalServiceException",
+            UpdateScriptError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateScriptError::NotFound(_) => "NotFoundException",
+            UpdateScriptError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ValidateMatchmakingRuleSet
 #[derive(Debug, PartialEq)]
 pub enum ValidateMatchmakingRuleSetError {
@@ -8917,6 +9878,15 @@ impl fmt::Display for ValidateMatchmakingRuleSetError {
     }
 }
 impl Error for ValidateMatchmakingRuleSetError {}
+impl ServiceErrorCode for ValidateMatchmakingRuleSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ValidateMatchmakingRuleSetError::InternalService(_) => "InternalServiceException",
+            ValidateMatchmakingRuleSetError::InvalidRequest(_) => "InvalidRequestException",
+            ValidateMatchmakingRuleSetError::UnsupportedRegion(_) => "UnsupportedRegionException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon GameLift API. Amazon GameLift clients implement this trait.
 #[async_trait]
 pub trait GameLift {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1380,6 +1380,16 @@ impl fmt::Display for AbortMultipartUploadError {
     }
 }
 impl Error for AbortMultipartUploadError {}
+impl ServiceErrorCode for AbortMultipartUploadError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AbortMultipartUploadError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            AbortMultipartUploadError::MissingParameterValue(_) => "MissingParameterValueException",
+            AbortMultipartUploadError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AbortMultipartUploadError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by AbortVaultLock
 #[derive(Debug, PartialEq)]
 pub enum AbortVaultLockError {
@@ -1432,6 +1442,16 @@ impl fmt::Display for AbortVaultLockError {
     }
 }
 impl Error for AbortVaultLockError {}
+impl ServiceErrorCode for AbortVaultLockError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AbortVaultLockError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            AbortVaultLockError::MissingParameterValue(_) => "MissingParameterValueException",
+            AbortVaultLockError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AbortVaultLockError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by AddTagsToVault
 #[derive(Debug, PartialEq)]
 pub enum AddTagsToVaultError {
@@ -1490,6 +1510,17 @@ impl fmt::Display for AddTagsToVaultError {
     }
 }
 impl Error for AddTagsToVaultError {}
+impl ServiceErrorCode for AddTagsToVaultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsToVaultError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            AddTagsToVaultError::LimitExceeded(_) => "LimitExceededException",
+            AddTagsToVaultError::MissingParameterValue(_) => "MissingParameterValueException",
+            AddTagsToVaultError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AddTagsToVaultError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CompleteMultipartUpload
 #[derive(Debug, PartialEq)]
 pub enum CompleteMultipartUploadError {
@@ -1550,6 +1581,20 @@ impl fmt::Display for CompleteMultipartUploadError {
     }
 }
 impl Error for CompleteMultipartUploadError {}
+impl ServiceErrorCode for CompleteMultipartUploadError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CompleteMultipartUploadError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            CompleteMultipartUploadError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            CompleteMultipartUploadError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CompleteMultipartUploadError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CompleteVaultLock
 #[derive(Debug, PartialEq)]
 pub enum CompleteVaultLockError {
@@ -1604,6 +1649,16 @@ impl fmt::Display for CompleteVaultLockError {
     }
 }
 impl Error for CompleteVaultLockError {}
+impl ServiceErrorCode for CompleteVaultLockError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CompleteVaultLockError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CompleteVaultLockError::MissingParameterValue(_) => "MissingParameterValueException",
+            CompleteVaultLockError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CompleteVaultLockError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CreateVault
 #[derive(Debug, PartialEq)]
 pub enum CreateVaultError {
@@ -1652,6 +1707,16 @@ impl fmt::Display for CreateVaultError {
     }
 }
 impl Error for CreateVaultError {}
+impl ServiceErrorCode for CreateVaultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVaultError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateVaultError::LimitExceeded(_) => "LimitExceededException",
+            CreateVaultError::MissingParameterValue(_) => "MissingParameterValueException",
+            CreateVaultError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteArchive
 #[derive(Debug, PartialEq)]
 pub enum DeleteArchiveError {
@@ -1700,6 +1765,16 @@ impl fmt::Display for DeleteArchiveError {
     }
 }
 impl Error for DeleteArchiveError {}
+impl ServiceErrorCode for DeleteArchiveError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteArchiveError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteArchiveError::MissingParameterValue(_) => "MissingParameterValueException",
+            DeleteArchiveError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteArchiveError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteVault
 #[derive(Debug, PartialEq)]
 pub enum DeleteVaultError {
@@ -1748,6 +1823,16 @@ impl fmt::Display for DeleteVaultError {
     }
 }
 impl Error for DeleteVaultError {}
+impl ServiceErrorCode for DeleteVaultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVaultError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteVaultError::MissingParameterValue(_) => "MissingParameterValueException",
+            DeleteVaultError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteVaultError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteVaultAccessPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteVaultAccessPolicyError {
@@ -1808,6 +1893,20 @@ impl fmt::Display for DeleteVaultAccessPolicyError {
     }
 }
 impl Error for DeleteVaultAccessPolicyError {}
+impl ServiceErrorCode for DeleteVaultAccessPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVaultAccessPolicyError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteVaultAccessPolicyError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            DeleteVaultAccessPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteVaultAccessPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteVaultNotifications
 #[derive(Debug, PartialEq)]
 pub enum DeleteVaultNotificationsError {
@@ -1868,6 +1967,20 @@ impl fmt::Display for DeleteVaultNotificationsError {
     }
 }
 impl Error for DeleteVaultNotificationsError {}
+impl ServiceErrorCode for DeleteVaultNotificationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVaultNotificationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteVaultNotificationsError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            DeleteVaultNotificationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteVaultNotificationsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeJobError {
@@ -1916,6 +2029,16 @@ impl fmt::Display for DescribeJobError {
     }
 }
 impl Error for DescribeJobError {}
+impl ServiceErrorCode for DescribeJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeJobError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeJobError::MissingParameterValue(_) => "MissingParameterValueException",
+            DescribeJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeVault
 #[derive(Debug, PartialEq)]
 pub enum DescribeVaultError {
@@ -1964,6 +2087,16 @@ impl fmt::Display for DescribeVaultError {
     }
 }
 impl Error for DescribeVaultError {}
+impl ServiceErrorCode for DescribeVaultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeVaultError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeVaultError::MissingParameterValue(_) => "MissingParameterValueException",
+            DescribeVaultError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeVaultError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetDataRetrievalPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetDataRetrievalPolicyError {
@@ -2012,6 +2145,19 @@ impl fmt::Display for GetDataRetrievalPolicyError {
     }
 }
 impl Error for GetDataRetrievalPolicyError {}
+impl ServiceErrorCode for GetDataRetrievalPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDataRetrievalPolicyError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetDataRetrievalPolicyError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            GetDataRetrievalPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetJobOutput
 #[derive(Debug, PartialEq)]
 pub enum GetJobOutputError {
@@ -2060,6 +2206,16 @@ impl fmt::Display for GetJobOutputError {
     }
 }
 impl Error for GetJobOutputError {}
+impl ServiceErrorCode for GetJobOutputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetJobOutputError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetJobOutputError::MissingParameterValue(_) => "MissingParameterValueException",
+            GetJobOutputError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetJobOutputError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetVaultAccessPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetVaultAccessPolicyError {
@@ -2116,6 +2272,16 @@ impl fmt::Display for GetVaultAccessPolicyError {
     }
 }
 impl Error for GetVaultAccessPolicyError {}
+impl ServiceErrorCode for GetVaultAccessPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVaultAccessPolicyError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetVaultAccessPolicyError::MissingParameterValue(_) => "MissingParameterValueException",
+            GetVaultAccessPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetVaultAccessPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetVaultLock
 #[derive(Debug, PartialEq)]
 pub enum GetVaultLockError {
@@ -2164,6 +2330,16 @@ impl fmt::Display for GetVaultLockError {
     }
 }
 impl Error for GetVaultLockError {}
+impl ServiceErrorCode for GetVaultLockError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVaultLockError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetVaultLockError::MissingParameterValue(_) => "MissingParameterValueException",
+            GetVaultLockError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetVaultLockError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetVaultNotifications
 #[derive(Debug, PartialEq)]
 pub enum GetVaultNotificationsError {
@@ -2220,6 +2396,20 @@ impl fmt::Display for GetVaultNotificationsError {
     }
 }
 impl Error for GetVaultNotificationsError {}
+impl ServiceErrorCode for GetVaultNotificationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVaultNotificationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetVaultNotificationsError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            GetVaultNotificationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetVaultNotificationsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by InitiateJob
 #[derive(Debug, PartialEq)]
 pub enum InitiateJobError {
@@ -2280,6 +2470,18 @@ impl fmt::Display for InitiateJobError {
     }
 }
 impl Error for InitiateJobError {}
+impl ServiceErrorCode for InitiateJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InitiateJobError::InsufficientCapacity(_) => "InsufficientCapacityException",
+            InitiateJobError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            InitiateJobError::MissingParameterValue(_) => "MissingParameterValueException",
+            InitiateJobError::PolicyEnforced(_) => "PolicyEnforcedException",
+            InitiateJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            InitiateJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by InitiateMultipartUpload
 #[derive(Debug, PartialEq)]
 pub enum InitiateMultipartUploadError {
@@ -2340,6 +2542,20 @@ impl fmt::Display for InitiateMultipartUploadError {
     }
 }
 impl Error for InitiateMultipartUploadError {}
+impl ServiceErrorCode for InitiateMultipartUploadError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InitiateMultipartUploadError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            InitiateMultipartUploadError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            InitiateMultipartUploadError::ResourceNotFound(_) => "ResourceNotFoundException",
+            InitiateMultipartUploadError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by InitiateVaultLock
 #[derive(Debug, PartialEq)]
 pub enum InitiateVaultLockError {
@@ -2394,6 +2610,16 @@ impl fmt::Display for InitiateVaultLockError {
     }
 }
 impl Error for InitiateVaultLockError {}
+impl ServiceErrorCode for InitiateVaultLockError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InitiateVaultLockError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            InitiateVaultLockError::MissingParameterValue(_) => "MissingParameterValueException",
+            InitiateVaultLockError::ResourceNotFound(_) => "ResourceNotFoundException",
+            InitiateVaultLockError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
 pub enum ListJobsError {
@@ -2442,6 +2668,16 @@ impl fmt::Display for ListJobsError {
     }
 }
 impl Error for ListJobsError {}
+impl ServiceErrorCode for ListJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJobsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListJobsError::MissingParameterValue(_) => "MissingParameterValueException",
+            ListJobsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListJobsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListMultipartUploads
 #[derive(Debug, PartialEq)]
 pub enum ListMultipartUploadsError {
@@ -2498,6 +2734,16 @@ impl fmt::Display for ListMultipartUploadsError {
     }
 }
 impl Error for ListMultipartUploadsError {}
+impl ServiceErrorCode for ListMultipartUploadsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMultipartUploadsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListMultipartUploadsError::MissingParameterValue(_) => "MissingParameterValueException",
+            ListMultipartUploadsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListMultipartUploadsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListParts
 #[derive(Debug, PartialEq)]
 pub enum ListPartsError {
@@ -2546,6 +2792,16 @@ impl fmt::Display for ListPartsError {
     }
 }
 impl Error for ListPartsError {}
+impl ServiceErrorCode for ListPartsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPartsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListPartsError::MissingParameterValue(_) => "MissingParameterValueException",
+            ListPartsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListPartsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListProvisionedCapacity
 #[derive(Debug, PartialEq)]
 pub enum ListProvisionedCapacityError {
@@ -2598,6 +2854,19 @@ impl fmt::Display for ListProvisionedCapacityError {
     }
 }
 impl Error for ListProvisionedCapacityError {}
+impl ServiceErrorCode for ListProvisionedCapacityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProvisionedCapacityError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListProvisionedCapacityError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            ListProvisionedCapacityError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListTagsForVault
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForVaultError {
@@ -2650,6 +2919,16 @@ impl fmt::Display for ListTagsForVaultError {
     }
 }
 impl Error for ListTagsForVaultError {}
+impl ServiceErrorCode for ListTagsForVaultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForVaultError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListTagsForVaultError::MissingParameterValue(_) => "MissingParameterValueException",
+            ListTagsForVaultError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForVaultError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListVaults
 #[derive(Debug, PartialEq)]
 pub enum ListVaultsError {
@@ -2698,6 +2977,16 @@ impl fmt::Display for ListVaultsError {
     }
 }
 impl Error for ListVaultsError {}
+impl ServiceErrorCode for ListVaultsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVaultsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListVaultsError::MissingParameterValue(_) => "MissingParameterValueException",
+            ListVaultsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListVaultsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by PurchaseProvisionedCapacity
 #[derive(Debug, PartialEq)]
 pub enum PurchaseProvisionedCapacityError {
@@ -2762,6 +3051,22 @@ impl fmt::Display for PurchaseProvisionedCapacityError {
     }
 }
 impl Error for PurchaseProvisionedCapacityError {}
+impl ServiceErrorCode for PurchaseProvisionedCapacityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PurchaseProvisionedCapacityError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            PurchaseProvisionedCapacityError::LimitExceeded(_) => "LimitExceededException",
+            PurchaseProvisionedCapacityError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            PurchaseProvisionedCapacityError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by RemoveTagsFromVault
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromVaultError {
@@ -2818,6 +3123,16 @@ impl fmt::Display for RemoveTagsFromVaultError {
     }
 }
 impl Error for RemoveTagsFromVaultError {}
+impl ServiceErrorCode for RemoveTagsFromVaultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsFromVaultError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            RemoveTagsFromVaultError::MissingParameterValue(_) => "MissingParameterValueException",
+            RemoveTagsFromVaultError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RemoveTagsFromVaultError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by SetDataRetrievalPolicy
 #[derive(Debug, PartialEq)]
 pub enum SetDataRetrievalPolicyError {
@@ -2866,6 +3181,19 @@ impl fmt::Display for SetDataRetrievalPolicyError {
     }
 }
 impl Error for SetDataRetrievalPolicyError {}
+impl ServiceErrorCode for SetDataRetrievalPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetDataRetrievalPolicyError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            SetDataRetrievalPolicyError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            SetDataRetrievalPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by SetVaultAccessPolicy
 #[derive(Debug, PartialEq)]
 pub enum SetVaultAccessPolicyError {
@@ -2922,6 +3250,16 @@ impl fmt::Display for SetVaultAccessPolicyError {
     }
 }
 impl Error for SetVaultAccessPolicyError {}
+impl ServiceErrorCode for SetVaultAccessPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetVaultAccessPolicyError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            SetVaultAccessPolicyError::MissingParameterValue(_) => "MissingParameterValueException",
+            SetVaultAccessPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SetVaultAccessPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by SetVaultNotifications
 #[derive(Debug, PartialEq)]
 pub enum SetVaultNotificationsError {
@@ -2978,6 +3316,20 @@ impl fmt::Display for SetVaultNotificationsError {
     }
 }
 impl Error for SetVaultNotificationsError {}
+impl ServiceErrorCode for SetVaultNotificationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetVaultNotificationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            SetVaultNotificationsError::MissingParameterValue(_) => {
+                "MissingParameterValueException"
+            }
+            SetVaultNotificationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SetVaultNotificationsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by UploadArchive
 #[derive(Debug, PartialEq)]
 pub enum UploadArchiveError {
@@ -3032,6 +3384,17 @@ impl fmt::Display for UploadArchiveError {
     }
 }
 impl Error for UploadArchiveError {}
+impl ServiceErrorCode for UploadArchiveError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UploadArchiveError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UploadArchiveError::MissingParameterValue(_) => "MissingParameterValueException",
+            UploadArchiveError::RequestTimeout(_) => "RequestTimeoutException",
+            UploadArchiveError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UploadArchiveError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by UploadMultipartPart
 #[derive(Debug, PartialEq)]
 pub enum UploadMultipartPartError {
@@ -3094,6 +3457,17 @@ impl fmt::Display for UploadMultipartPartError {
     }
 }
 impl Error for UploadMultipartPartError {}
+impl ServiceErrorCode for UploadMultipartPartError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UploadMultipartPartError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UploadMultipartPartError::MissingParameterValue(_) => "MissingParameterValueException",
+            UploadMultipartPartError::RequestTimeout(_) => "RequestTimeoutException",
+            UploadMultipartPartError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UploadMultipartPartError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Glacier API. Amazon Glacier clients implement this trait.
 #[async_trait]
 pub trait Glacier {
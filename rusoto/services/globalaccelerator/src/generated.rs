@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -227,6 +227,7 @@ pub struct CreateAcceleratorRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency—that is, the uniqueness—of an accelerator.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p>The value for the address type must be IPv4.</p>
@@ -263,6 +264,7 @@ pub struct CreateCustomRoutingAcceleratorRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency—that is, the uniqueness—of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p>The value for the address type must be IPv4.</p>
@@ -297,6 +299,7 @@ pub struct CreateCustomRoutingEndpointGroupRequest {
     #[serde(rename = "EndpointGroupRegion")]
     pub endpoint_group_region: String,
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency—that is, the uniqueness—of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p>The Amazon Resource Name (ARN) of the listener for a custom routing endpoint.</p>
@@ -320,6 +323,7 @@ pub struct CreateCustomRoutingListenerRequest {
     #[serde(rename = "AcceleratorArn")]
     pub accelerator_arn: String,
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency—that is, the uniqueness—of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p>The port range to support for connections from clients to your accelerator.</p> <p>Separately, you set port ranges for endpoints. For more information, see <a href="https://docs.aws.amazon.com/global-accelerator/latest/dg/about-custom-routing-endpoints.html">About endpoints for custom routing accelerators</a>.</p>
@@ -363,6 +367,7 @@ pub struct CreateEndpointGroupRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub health_check_protocol: Option<String>,
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency—that is, the uniqueness—of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p>The Amazon Resource Name (ARN) of the listener.</p>
@@ -402,6 +407,7 @@ pub struct CreateListenerRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_affinity: Option<String>,
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency—that is, the uniqueness—of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p>The list of port ranges to support for connections from clients to your accelerator.</p>
@@ -1691,6 +1697,25 @@ impl fmt::Display for AddCustomRoutingEndpointsError {
     }
 }
 impl Error for AddCustomRoutingEndpointsError {}
+impl ServiceErrorCode for AddCustomRoutingEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddCustomRoutingEndpointsError::AccessDenied(_) => "AccessDeniedException",
+            AddCustomRoutingEndpointsError::Conflict(_) => "ConflictException",
+            AddCustomRoutingEndpointsError::EndpointAlreadyExists(_) => {
+                "EndpointAlreadyExistsException"
+            }
+            AddCustomRoutingEndpointsError::EndpointGroupNotFound(_) => {
+                "EndpointGroupNotFoundException"
+            }
+            AddCustomRoutingEndpointsError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            AddCustomRoutingEndpointsError::InvalidArgument(_) => "InvalidArgumentException",
+            AddCustomRoutingEndpointsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by AdvertiseByoipCidr
 #[derive(Debug, PartialEq)]
 pub enum AdvertiseByoipCidrError {
@@ -1751,6 +1776,17 @@ impl fmt::Display for AdvertiseByoipCidrError {
     }
 }
 impl Error for AdvertiseByoipCidrError {}
+impl ServiceErrorCode for AdvertiseByoipCidrError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AdvertiseByoipCidrError::AccessDenied(_) => "AccessDeniedException",
+            AdvertiseByoipCidrError::ByoipCidrNotFound(_) => "ByoipCidrNotFoundException",
+            AdvertiseByoipCidrError::IncorrectCidrState(_) => "IncorrectCidrStateException",
+            AdvertiseByoipCidrError::InternalServiceError(_) => "InternalServiceErrorException",
+            AdvertiseByoipCidrError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by AllowCustomRoutingTraffic
 #[derive(Debug, PartialEq)]
 pub enum AllowCustomRoutingTrafficError {
@@ -1793,6 +1829,16 @@ impl fmt::Display for AllowCustomRoutingTrafficError {
     }
 }
 impl Error for AllowCustomRoutingTrafficError {}
+impl ServiceErrorCode for AllowCustomRoutingTrafficError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AllowCustomRoutingTrafficError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            AllowCustomRoutingTrafficError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by CreateAccelerator
 #[derive(Debug, PartialEq)]
 pub enum CreateAcceleratorError {
@@ -1837,6 +1883,15 @@ impl fmt::Display for CreateAcceleratorError {
     }
 }
 impl Error for CreateAcceleratorError {}
+impl ServiceErrorCode for CreateAcceleratorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAcceleratorError::InternalServiceError(_) => "InternalServiceErrorException",
+            CreateAcceleratorError::InvalidArgument(_) => "InvalidArgumentException",
+            CreateAcceleratorError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateCustomRoutingAccelerator
 #[derive(Debug, PartialEq)]
 pub enum CreateCustomRoutingAcceleratorError {
@@ -1899,6 +1954,18 @@ impl fmt::Display for CreateCustomRoutingAcceleratorError {
     }
 }
 impl Error for CreateCustomRoutingAcceleratorError {}
+impl ServiceErrorCode for CreateCustomRoutingAcceleratorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCustomRoutingAcceleratorError::AccessDenied(_) => "AccessDeniedException",
+            CreateCustomRoutingAcceleratorError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            CreateCustomRoutingAcceleratorError::InvalidArgument(_) => "InvalidArgumentException",
+            CreateCustomRoutingAcceleratorError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateCustomRoutingEndpointGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateCustomRoutingEndpointGroupError {
@@ -2005,6 +2072,30 @@ impl fmt::Display for CreateCustomRoutingEndpointGroupError {
     }
 }
 impl Error for CreateCustomRoutingEndpointGroupError {}
+impl ServiceErrorCode for CreateCustomRoutingEndpointGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCustomRoutingEndpointGroupError::AcceleratorNotFound(_) => {
+                "AcceleratorNotFoundException"
+            }
+            CreateCustomRoutingEndpointGroupError::AccessDenied(_) => "AccessDeniedException",
+            CreateCustomRoutingEndpointGroupError::EndpointGroupAlreadyExists(_) => {
+                "EndpointGroupAlreadyExistsException"
+            }
+            CreateCustomRoutingEndpointGroupError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            CreateCustomRoutingEndpointGroupError::InvalidArgument(_) => "InvalidArgumentException",
+            CreateCustomRoutingEndpointGroupError::InvalidPortRange(_) => {
+                "InvalidPortRangeException"
+            }
+            CreateCustomRoutingEndpointGroupError::LimitExceeded(_) => "LimitExceededException",
+            CreateCustomRoutingEndpointGroupError::ListenerNotFound(_) => {
+                "ListenerNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateCustomRoutingListener
 #[derive(Debug, PartialEq)]
 pub enum CreateCustomRoutingListenerError {
@@ -2075,6 +2166,21 @@ impl fmt::Display for CreateCustomRoutingListenerError {
     }
 }
 impl Error for CreateCustomRoutingListenerError {}
+impl ServiceErrorCode for CreateCustomRoutingListenerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCustomRoutingListenerError::AcceleratorNotFound(_) => {
+                "AcceleratorNotFoundException"
+            }
+            CreateCustomRoutingListenerError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            CreateCustomRoutingListenerError::InvalidArgument(_) => "InvalidArgumentException",
+            CreateCustomRoutingListenerError::InvalidPortRange(_) => "InvalidPortRangeException",
+            CreateCustomRoutingListenerError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateEndpointGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateEndpointGroupError {
@@ -2151,6 +2257,21 @@ impl fmt::Display for CreateEndpointGroupError {
     }
 }
 impl Error for CreateEndpointGroupError {}
+impl ServiceErrorCode for CreateEndpointGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEndpointGroupError::AcceleratorNotFound(_) => "AcceleratorNotFoundException",
+            CreateEndpointGroupError::AccessDenied(_) => "AccessDeniedException",
+            CreateEndpointGroupError::EndpointGroupAlreadyExists(_) => {
+                "EndpointGroupAlreadyExistsException"
+            }
+            CreateEndpointGroupError::InternalServiceError(_) => "InternalServiceErrorException",
+            CreateEndpointGroupError::InvalidArgument(_) => "InvalidArgumentException",
+            CreateEndpointGroupError::LimitExceeded(_) => "LimitExceededException",
+            CreateEndpointGroupError::ListenerNotFound(_) => "ListenerNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateListener
 #[derive(Debug, PartialEq)]
 pub enum CreateListenerError {
@@ -2205,6 +2326,17 @@ impl fmt::Display for CreateListenerError {
     }
 }
 impl Error for CreateListenerError {}
+impl ServiceErrorCode for CreateListenerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateListenerError::AcceleratorNotFound(_) => "AcceleratorNotFoundException",
+            CreateListenerError::InternalServiceError(_) => "InternalServiceErrorException",
+            CreateListenerError::InvalidArgument(_) => "InvalidArgumentException",
+            CreateListenerError::InvalidPortRange(_) => "InvalidPortRangeException",
+            CreateListenerError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DeleteAccelerator
 #[derive(Debug, PartialEq)]
 pub enum DeleteAcceleratorError {
@@ -2267,6 +2399,19 @@ impl fmt::Display for DeleteAcceleratorError {
     }
 }
 impl Error for DeleteAcceleratorError {}
+impl ServiceErrorCode for DeleteAcceleratorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAcceleratorError::AcceleratorNotDisabled(_) => "AcceleratorNotDisabledException",
+            DeleteAcceleratorError::AcceleratorNotFound(_) => "AcceleratorNotFoundException",
+            DeleteAcceleratorError::AssociatedListenerFound(_) => {
+                "AssociatedListenerFoundException"
+            }
+            DeleteAcceleratorError::InternalServiceError(_) => "InternalServiceErrorException",
+            DeleteAcceleratorError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by DeleteCustomRoutingAccelerator
 #[derive(Debug, PartialEq)]
 pub enum DeleteCustomRoutingAcceleratorError {
@@ -2343,6 +2488,25 @@ impl fmt::Display for DeleteCustomRoutingAcceleratorError {
     }
 }
 impl Error for DeleteCustomRoutingAcceleratorError {}
+impl ServiceErrorCode for DeleteCustomRoutingAcceleratorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCustomRoutingAcceleratorError::AcceleratorNotDisabled(_) => {
+                "AcceleratorNotDisabledException"
+            }
+            DeleteCustomRoutingAcceleratorError::AcceleratorNotFound(_) => {
+                "AcceleratorNotFoundException"
+            }
+            DeleteCustomRoutingAcceleratorError::AssociatedListenerFound(_) => {
+                "AssociatedListenerFoundException"
+            }
+            DeleteCustomRoutingAcceleratorError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            DeleteCustomRoutingAcceleratorError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by DeleteCustomRoutingEndpointGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteCustomRoutingEndpointGroupError {
@@ -2399,6 +2563,19 @@ impl fmt::Display for DeleteCustomRoutingEndpointGroupError {
     }
 }
 impl Error for DeleteCustomRoutingEndpointGroupError {}
+impl ServiceErrorCode for DeleteCustomRoutingEndpointGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCustomRoutingEndpointGroupError::EndpointGroupNotFound(_) => {
+                "EndpointGroupNotFoundException"
+            }
+            DeleteCustomRoutingEndpointGroupError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            DeleteCustomRoutingEndpointGroupError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by DeleteCustomRoutingListener
 #[derive(Debug, PartialEq)]
 pub enum DeleteCustomRoutingListenerError {
@@ -2461,6 +2638,20 @@ impl fmt::Display for DeleteCustomRoutingListenerError {
     }
 }
 impl Error for DeleteCustomRoutingListenerError {}
+impl ServiceErrorCode for DeleteCustomRoutingListenerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCustomRoutingListenerError::AssociatedEndpointGroupFound(_) => {
+                "AssociatedEndpointGroupFoundException"
+            }
+            DeleteCustomRoutingListenerError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            DeleteCustomRoutingListenerError::InvalidArgument(_) => "InvalidArgumentException",
+            DeleteCustomRoutingListenerError::ListenerNotFound(_) => "ListenerNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteEndpointGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteEndpointGroupError {
@@ -2507,6 +2698,15 @@ impl fmt::Display for DeleteEndpointGroupError {
     }
 }
 impl Error for DeleteEndpointGroupError {}
+impl ServiceErrorCode for DeleteEndpointGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEndpointGroupError::EndpointGroupNotFound(_) => "EndpointGroupNotFoundException",
+            DeleteEndpointGroupError::InternalServiceError(_) => "InternalServiceErrorException",
+            DeleteEndpointGroupError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by DeleteListener
 #[derive(Debug, PartialEq)]
 pub enum DeleteListenerError {
@@ -2557,6 +2757,18 @@ impl fmt::Display for DeleteListenerError {
     }
 }
 impl Error for DeleteListenerError {}
+impl ServiceErrorCode for DeleteListenerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteListenerError::AssociatedEndpointGroupFound(_) => {
+                "AssociatedEndpointGroupFoundException"
+            }
+            DeleteListenerError::InternalServiceError(_) => "InternalServiceErrorException",
+            DeleteListenerError::InvalidArgument(_) => "InvalidArgumentException",
+            DeleteListenerError::ListenerNotFound(_) => "ListenerNotFoundException",
+        }
+    }
+}
 /// Errors returned by DenyCustomRoutingTraffic
 #[derive(Debug, PartialEq)]
 pub enum DenyCustomRoutingTrafficError {
@@ -2599,6 +2811,16 @@ impl fmt::Display for DenyCustomRoutingTrafficError {
     }
 }
 impl Error for DenyCustomRoutingTrafficError {}
+impl ServiceErrorCode for DenyCustomRoutingTrafficError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DenyCustomRoutingTrafficError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            DenyCustomRoutingTrafficError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by DeprovisionByoipCidr
 #[derive(Debug, PartialEq)]
 pub enum DeprovisionByoipCidrError {
@@ -2661,6 +2883,17 @@ impl fmt::Display for DeprovisionByoipCidrError {
     }
 }
 impl Error for DeprovisionByoipCidrError {}
+impl ServiceErrorCode for DeprovisionByoipCidrError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeprovisionByoipCidrError::AccessDenied(_) => "AccessDeniedException",
+            DeprovisionByoipCidrError::ByoipCidrNotFound(_) => "ByoipCidrNotFoundException",
+            DeprovisionByoipCidrError::IncorrectCidrState(_) => "IncorrectCidrStateException",
+            DeprovisionByoipCidrError::InternalServiceError(_) => "InternalServiceErrorException",
+            DeprovisionByoipCidrError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by DescribeAccelerator
 #[derive(Debug, PartialEq)]
 pub enum DescribeAcceleratorError {
@@ -2707,6 +2940,15 @@ impl fmt::Display for DescribeAcceleratorError {
     }
 }
 impl Error for DescribeAcceleratorError {}
+impl ServiceErrorCode for DescribeAcceleratorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAcceleratorError::AcceleratorNotFound(_) => "AcceleratorNotFoundException",
+            DescribeAcceleratorError::InternalServiceError(_) => "InternalServiceErrorException",
+            DescribeAcceleratorError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by DescribeAcceleratorAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeAcceleratorAttributesError {
@@ -2763,6 +3005,19 @@ impl fmt::Display for DescribeAcceleratorAttributesError {
     }
 }
 impl Error for DescribeAcceleratorAttributesError {}
+impl ServiceErrorCode for DescribeAcceleratorAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAcceleratorAttributesError::AcceleratorNotFound(_) => {
+                "AcceleratorNotFoundException"
+            }
+            DescribeAcceleratorAttributesError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            DescribeAcceleratorAttributesError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by DescribeCustomRoutingAccelerator
 #[derive(Debug, PartialEq)]
 pub enum DescribeCustomRoutingAcceleratorError {
@@ -2819,6 +3074,19 @@ impl fmt::Display for DescribeCustomRoutingAcceleratorError {
     }
 }
 impl Error for DescribeCustomRoutingAcceleratorError {}
+impl ServiceErrorCode for DescribeCustomRoutingAcceleratorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCustomRoutingAcceleratorError::AcceleratorNotFound(_) => {
+                "AcceleratorNotFoundException"
+            }
+            DescribeCustomRoutingAcceleratorError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            DescribeCustomRoutingAcceleratorError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by DescribeCustomRoutingAcceleratorAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeCustomRoutingAcceleratorAttributesError {
@@ -2879,6 +3147,21 @@ impl fmt::Display for DescribeCustomRoutingAcceleratorAttributesError {
     }
 }
 impl Error for DescribeCustomRoutingAcceleratorAttributesError {}
+impl ServiceErrorCode for DescribeCustomRoutingAcceleratorAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCustomRoutingAcceleratorAttributesError::AcceleratorNotFound(_) => {
+                "AcceleratorNotFoundException"
+            }
+            DescribeCustomRoutingAcceleratorAttributesError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            DescribeCustomRoutingAcceleratorAttributesError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeCustomRoutingEndpointGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeCustomRoutingEndpointGroupError {
@@ -2935,6 +3218,21 @@ impl fmt::Display for DescribeCustomRoutingEndpointGroupError {
     }
 }
 impl Error for DescribeCustomRoutingEndpointGroupError {}
+impl ServiceErrorCode for DescribeCustomRoutingEndpointGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCustomRoutingEndpointGroupError::EndpointGroupNotFound(_) => {
+                "EndpointGroupNotFoundException"
+            }
+            DescribeCustomRoutingEndpointGroupError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            DescribeCustomRoutingEndpointGroupError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeCustomRoutingListener
 #[derive(Debug, PartialEq)]
 pub enum DescribeCustomRoutingListenerError {
@@ -2991,6 +3289,17 @@ impl fmt::Display for DescribeCustomRoutingListenerError {
     }
 }
 impl Error for DescribeCustomRoutingListenerError {}
+impl ServiceErrorCode for DescribeCustomRoutingListenerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCustomRoutingListenerError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            DescribeCustomRoutingListenerError::InvalidArgument(_) => "InvalidArgumentException",
+            DescribeCustomRoutingListenerError::ListenerNotFound(_) => "ListenerNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeEndpointGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeEndpointGroupError {
@@ -3039,6 +3348,17 @@ impl fmt::Display for DescribeEndpointGroupError {
     }
 }
 impl Error for DescribeEndpointGroupError {}
+impl ServiceErrorCode for DescribeEndpointGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEndpointGroupError::EndpointGroupNotFound(_) => {
+                "EndpointGroupNotFoundException"
+            }
+            DescribeEndpointGroupError::InternalServiceError(_) => "InternalServiceErrorException",
+            DescribeEndpointGroupError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by DescribeListener
 #[derive(Debug, PartialEq)]
 pub enum DescribeListenerError {
@@ -3083,6 +3403,15 @@ impl fmt::Display for DescribeListenerError {
     }
 }
 impl Error for DescribeListenerError {}
+impl ServiceErrorCode for DescribeListenerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeListenerError::InternalServiceError(_) => "InternalServiceErrorException",
+            DescribeListenerError::InvalidArgument(_) => "InvalidArgumentException",
+            DescribeListenerError::ListenerNotFound(_) => "ListenerNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListAccelerators
 #[derive(Debug, PartialEq)]
 pub enum ListAcceleratorsError {
@@ -3127,6 +3456,15 @@ impl fmt::Display for ListAcceleratorsError {
     }
 }
 impl Error for ListAcceleratorsError {}
+impl ServiceErrorCode for ListAcceleratorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAcceleratorsError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListAcceleratorsError::InvalidArgument(_) => "InvalidArgumentException",
+            ListAcceleratorsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListByoipCidrs
 #[derive(Debug, PartialEq)]
 pub enum ListByoipCidrsError {
@@ -3175,6 +3513,16 @@ impl fmt::Display for ListByoipCidrsError {
     }
 }
 impl Error for ListByoipCidrsError {}
+impl ServiceErrorCode for ListByoipCidrsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListByoipCidrsError::AccessDenied(_) => "AccessDeniedException",
+            ListByoipCidrsError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListByoipCidrsError::InvalidArgument(_) => "InvalidArgumentException",
+            ListByoipCidrsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListCustomRoutingAccelerators
 #[derive(Debug, PartialEq)]
 pub enum ListCustomRoutingAcceleratorsError {
@@ -3231,6 +3579,17 @@ impl fmt::Display for ListCustomRoutingAcceleratorsError {
     }
 }
 impl Error for ListCustomRoutingAcceleratorsError {}
+impl ServiceErrorCode for ListCustomRoutingAcceleratorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCustomRoutingAcceleratorsError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            ListCustomRoutingAcceleratorsError::InvalidArgument(_) => "InvalidArgumentException",
+            ListCustomRoutingAcceleratorsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListCustomRoutingEndpointGroups
 #[derive(Debug, PartialEq)]
 pub enum ListCustomRoutingEndpointGroupsError {
@@ -3297,6 +3656,22 @@ impl fmt::Display for ListCustomRoutingEndpointGroupsError {
     }
 }
 impl Error for ListCustomRoutingEndpointGroupsError {}
+impl ServiceErrorCode for ListCustomRoutingEndpointGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCustomRoutingEndpointGroupsError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            ListCustomRoutingEndpointGroupsError::InvalidArgument(_) => "InvalidArgumentException",
+            ListCustomRoutingEndpointGroupsError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            ListCustomRoutingEndpointGroupsError::ListenerNotFound(_) => {
+                "ListenerNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by ListCustomRoutingListeners
 #[derive(Debug, PartialEq)]
 pub enum ListCustomRoutingListenersError {
@@ -3359,6 +3734,20 @@ impl fmt::Display for ListCustomRoutingListenersError {
     }
 }
 impl Error for ListCustomRoutingListenersError {}
+impl ServiceErrorCode for ListCustomRoutingListenersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCustomRoutingListenersError::AcceleratorNotFound(_) => {
+                "AcceleratorNotFoundException"
+            }
+            ListCustomRoutingListenersError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            ListCustomRoutingListenersError::InvalidArgument(_) => "InvalidArgumentException",
+            ListCustomRoutingListenersError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListCustomRoutingPortMappings
 #[derive(Debug, PartialEq)]
 pub enum ListCustomRoutingPortMappingsError {
@@ -3435,6 +3824,23 @@ impl fmt::Display for ListCustomRoutingPortMappingsError {
     }
 }
 impl Error for ListCustomRoutingPortMappingsError {}
+impl ServiceErrorCode for ListCustomRoutingPortMappingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCustomRoutingPortMappingsError::AcceleratorNotFound(_) => {
+                "AcceleratorNotFoundException"
+            }
+            ListCustomRoutingPortMappingsError::EndpointGroupNotFound(_) => {
+                "EndpointGroupNotFoundException"
+            }
+            ListCustomRoutingPortMappingsError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            ListCustomRoutingPortMappingsError::InvalidArgument(_) => "InvalidArgumentException",
+            ListCustomRoutingPortMappingsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListCustomRoutingPortMappingsByDestination
 #[derive(Debug, PartialEq)]
 pub enum ListCustomRoutingPortMappingsByDestinationError {
@@ -3503,6 +3909,24 @@ impl fmt::Display for ListCustomRoutingPortMappingsByDestinationError {
     }
 }
 impl Error for ListCustomRoutingPortMappingsByDestinationError {}
+impl ServiceErrorCode for ListCustomRoutingPortMappingsByDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCustomRoutingPortMappingsByDestinationError::EndpointNotFound(_) => {
+                "EndpointNotFoundException"
+            }
+            ListCustomRoutingPortMappingsByDestinationError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            ListCustomRoutingPortMappingsByDestinationError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+            ListCustomRoutingPortMappingsByDestinationError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+        }
+    }
+}
 /// Errors returned by ListEndpointGroups
 #[derive(Debug, PartialEq)]
 pub enum ListEndpointGroupsError {
@@ -3553,6 +3977,16 @@ impl fmt::Display for ListEndpointGroupsError {
     }
 }
 impl Error for ListEndpointGroupsError {}
+impl ServiceErrorCode for ListEndpointGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEndpointGroupsError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListEndpointGroupsError::InvalidArgument(_) => "InvalidArgumentException",
+            ListEndpointGroupsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListEndpointGroupsError::ListenerNotFound(_) => "ListenerNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListListeners
 #[derive(Debug, PartialEq)]
 pub enum ListListenersError {
@@ -3601,6 +4035,16 @@ impl fmt::Display for ListListenersError {
     }
 }
 impl Error for ListListenersError {}
+impl ServiceErrorCode for ListListenersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListListenersError::AcceleratorNotFound(_) => "AcceleratorNotFoundException",
+            ListListenersError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListListenersError::InvalidArgument(_) => "InvalidArgumentException",
+            ListListenersError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3647,6 +4091,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AcceleratorNotFound(_) => "AcceleratorNotFoundException",
+            ListTagsForResourceError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListTagsForResourceError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by ProvisionByoipCidr
 #[derive(Debug, PartialEq)]
 pub enum ProvisionByoipCidrError {
@@ -3705,6 +4158,17 @@ impl fmt::Display for ProvisionByoipCidrError {
     }
 }
 impl Error for ProvisionByoipCidrError {}
+impl ServiceErrorCode for ProvisionByoipCidrError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ProvisionByoipCidrError::AccessDenied(_) => "AccessDeniedException",
+            ProvisionByoipCidrError::IncorrectCidrState(_) => "IncorrectCidrStateException",
+            ProvisionByoipCidrError::InternalServiceError(_) => "InternalServiceErrorException",
+            ProvisionByoipCidrError::InvalidArgument(_) => "InvalidArgumentException",
+            ProvisionByoipCidrError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by RemoveCustomRoutingEndpoints
 #[derive(Debug, PartialEq)]
 pub enum RemoveCustomRoutingEndpointsError {
@@ -3785,6 +4249,22 @@ impl fmt::Display for RemoveCustomRoutingEndpointsError {
     }
 }
 impl Error for RemoveCustomRoutingEndpointsError {}
+impl ServiceErrorCode for RemoveCustomRoutingEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveCustomRoutingEndpointsError::AccessDenied(_) => "AccessDeniedException",
+            RemoveCustomRoutingEndpointsError::Conflict(_) => "ConflictException",
+            RemoveCustomRoutingEndpointsError::EndpointGroupNotFound(_) => {
+                "EndpointGroupNotFoundException"
+            }
+            RemoveCustomRoutingEndpointsError::EndpointNotFound(_) => "EndpointNotFoundException",
+            RemoveCustomRoutingEndpointsError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            RemoveCustomRoutingEndpointsError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -3827,6 +4307,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AcceleratorNotFound(_) => "AcceleratorNotFoundException",
+            TagResourceError::InternalServiceError(_) => "InternalServiceErrorException",
+            TagResourceError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3869,6 +4358,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AcceleratorNotFound(_) => "AcceleratorNotFoundException",
+            UntagResourceError::InternalServiceError(_) => "InternalServiceErrorException",
+            UntagResourceError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by UpdateAccelerator
 #[derive(Debug, PartialEq)]
 pub enum UpdateAcceleratorError {
@@ -3915,6 +4413,15 @@ impl fmt::Display for UpdateAcceleratorError {
     }
 }
 impl Error for UpdateAcceleratorError {}
+impl ServiceErrorCode for UpdateAcceleratorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAcceleratorError::AcceleratorNotFound(_) => "AcceleratorNotFoundException",
+            UpdateAcceleratorError::InternalServiceError(_) => "InternalServiceErrorException",
+            UpdateAcceleratorError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by UpdateAcceleratorAttributes
 #[derive(Debug, PartialEq)]
 pub enum UpdateAcceleratorAttributesError {
@@ -3977,6 +4484,20 @@ impl fmt::Display for UpdateAcceleratorAttributesError {
     }
 }
 impl Error for UpdateAcceleratorAttributesError {}
+impl ServiceErrorCode for UpdateAcceleratorAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAcceleratorAttributesError::AcceleratorNotFound(_) => {
+                "AcceleratorNotFoundException"
+            }
+            UpdateAcceleratorAttributesError::AccessDenied(_) => "AccessDeniedException",
+            UpdateAcceleratorAttributesError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            UpdateAcceleratorAttributesError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by UpdateCustomRoutingAccelerator
 #[derive(Debug, PartialEq)]
 pub enum UpdateCustomRoutingAcceleratorError {
@@ -4033,6 +4554,19 @@ impl fmt::Display for UpdateCustomRoutingAcceleratorError {
     }
 }
 impl Error for UpdateCustomRoutingAcceleratorError {}
+impl ServiceErrorCode for UpdateCustomRoutingAcceleratorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCustomRoutingAcceleratorError::AcceleratorNotFound(_) => {
+                "AcceleratorNotFoundException"
+            }
+            UpdateCustomRoutingAcceleratorError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            UpdateCustomRoutingAcceleratorError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by UpdateCustomRoutingAcceleratorAttributes
 #[derive(Debug, PartialEq)]
 pub enum UpdateCustomRoutingAcceleratorAttributesError {
@@ -4101,6 +4635,24 @@ impl fmt::Display for UpdateCustomRoutingAcceleratorAttributesError {
     }
 }
 impl Error for UpdateCustomRoutingAcceleratorAttributesError {}
+impl ServiceErrorCode for UpdateCustomRoutingAcceleratorAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCustomRoutingAcceleratorAttributesError::AcceleratorNotFound(_) => {
+                "AcceleratorNotFoundException"
+            }
+            UpdateCustomRoutingAcceleratorAttributesError::AccessDenied(_) => {
+                "AccessDeniedException"
+            }
+            UpdateCustomRoutingAcceleratorAttributesError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            UpdateCustomRoutingAcceleratorAttributesError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateCustomRoutingListener
 #[derive(Debug, PartialEq)]
 pub enum UpdateCustomRoutingListenerError {
@@ -4169,6 +4721,19 @@ impl fmt::Display for UpdateCustomRoutingListenerError {
     }
 }
 impl Error for UpdateCustomRoutingListenerError {}
+impl ServiceErrorCode for UpdateCustomRoutingListenerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCustomRoutingListenerError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            UpdateCustomRoutingListenerError::InvalidArgument(_) => "InvalidArgumentException",
+            UpdateCustomRoutingListenerError::InvalidPortRange(_) => "InvalidPortRangeException",
+            UpdateCustomRoutingListenerError::LimitExceeded(_) => "LimitExceededException",
+            UpdateCustomRoutingListenerError::ListenerNotFound(_) => "ListenerNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateEndpointGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateEndpointGroupError {
@@ -4227,6 +4792,17 @@ impl fmt::Display for UpdateEndpointGroupError {
     }
 }
 impl Error for UpdateEndpointGroupError {}
+impl ServiceErrorCode for UpdateEndpointGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateEndpointGroupError::AccessDenied(_) => "AccessDeniedException",
+            UpdateEndpointGroupError::EndpointGroupNotFound(_) => "EndpointGroupNotFoundException",
+            UpdateEndpointGroupError::InternalServiceError(_) => "InternalServiceErrorException",
+            UpdateEndpointGroupError::InvalidArgument(_) => "InvalidArgumentException",
+            UpdateEndpointGroupError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by UpdateListener
 #[derive(Debug, PartialEq)]
 pub enum UpdateListenerError {
@@ -4281,6 +4857,17 @@ impl fmt::Display for UpdateListenerError {
     }
 }
 impl Error for UpdateListenerError {}
+impl ServiceErrorCode for UpdateListenerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateListenerError::InternalServiceError(_) => "InternalServiceErrorException",
+            UpdateListenerError::InvalidArgument(_) => "InvalidArgumentException",
+            UpdateListenerError::InvalidPortRange(_) => "InvalidPortRangeException",
+            UpdateListenerError::LimitExceeded(_) => "LimitExceededException",
+            UpdateListenerError::ListenerNotFound(_) => "ListenerNotFoundException",
+        }
+    }
+}
 /// Errors returned by WithdrawByoipCidr
 #[derive(Debug, PartialEq)]
 pub enum WithdrawByoipCidrError {
@@ -4339,6 +4926,17 @@ impl fmt::Display for WithdrawByoipCidrError {
     }
 }
 impl Error for WithdrawByoipCidrError {}
+impl ServiceErrorCode for WithdrawByoipCidrError {
+    fn error_code(&self) -> &str {
+        match *self {
+            WithdrawByoipCidrError::AccessDenied(_) => "AccessDeniedException",
+            WithdrawByoipCidrError::ByoipCidrNotFound(_) => "ByoipCidrNotFoundException",
+            WithdrawByoipCidrError::IncorrectCidrState(_) => "IncorrectCidrStateException",
+            WithdrawByoipCidrError::InternalServiceError(_) => "InternalServiceErrorException",
+            WithdrawByoipCidrError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Global Accelerator API. AWS Global Accelerator clients implement this trait.
 #[async_trait]
 pub trait GlobalAccelerator {
@@ -4772,6 +5370,12 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         &self,
         input: CreateAcceleratorRequest,
     ) -> Result<CreateAcceleratorResponse, RusotoError<CreateAcceleratorError>> {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -4796,6 +5400,12 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         CreateCustomRoutingAcceleratorResponse,
         RusotoError<CreateCustomRoutingAcceleratorError>,
     > {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -4821,6 +5431,12 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         CreateCustomRoutingEndpointGroupResponse,
         RusotoError<CreateCustomRoutingEndpointGroupError>,
     > {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -4847,6 +5463,12 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: CreateCustomRoutingListenerRequest,
     ) -> Result<CreateCustomRoutingListenerResponse, RusotoError<CreateCustomRoutingListenerError>>
     {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -4869,6 +5491,12 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         &self,
         input: CreateEndpointGroupRequest,
     ) -> Result<CreateEndpointGroupResponse, RusotoError<CreateEndpointGroupError>> {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -4890,6 +5518,12 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         &self,
         input: CreateListenerRequest,
     ) -> Result<CreateListenerResponse, RusotoError<CreateListenerError>> {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.CreateListener");
         let encoded = serde_json::to_string(&input).unwrap();
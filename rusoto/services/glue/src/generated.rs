@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -8045,6 +8045,21 @@ impl fmt::Display for BatchCreatePartitionError {
     }
 }
 impl Error for BatchCreatePartitionError {}
+impl ServiceErrorCode for BatchCreatePartitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchCreatePartitionError::AlreadyExists(_) => "AlreadyExistsException",
+            BatchCreatePartitionError::EntityNotFound(_) => "EntityNotFoundException",
+            BatchCreatePartitionError::GlueEncryption(_) => "GlueEncryptionException",
+            BatchCreatePartitionError::InternalService(_) => "InternalServiceException",
+            BatchCreatePartitionError::InvalidInput(_) => "InvalidInputException",
+            BatchCreatePartitionError::OperationTimeout(_) => "OperationTimeoutException",
+            BatchCreatePartitionError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by BatchDeleteConnection
 #[derive(Debug, PartialEq)]
 pub enum BatchDeleteConnectionError {
@@ -8085,6 +8100,14 @@ impl fmt::Display for BatchDeleteConnectionError {
     }
 }
 impl Error for BatchDeleteConnectionError {}
+impl ServiceErrorCode for BatchDeleteConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDeleteConnectionError::InternalService(_) => "InternalServiceException",
+            BatchDeleteConnectionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by BatchDeletePartition
 #[derive(Debug, PartialEq)]
 pub enum BatchDeletePartitionError {
@@ -8137,6 +8160,16 @@ impl fmt::Display for BatchDeletePartitionError {
     }
 }
 impl Error for BatchDeletePartitionError {}
+impl ServiceErrorCode for BatchDeletePartitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDeletePartitionError::EntityNotFound(_) => "EntityNotFoundException",
+            BatchDeletePartitionError::InternalService(_) => "InternalServiceException",
+            BatchDeletePartitionError::InvalidInput(_) => "InvalidInputException",
+            BatchDeletePartitionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by BatchDeleteTable
 #[derive(Debug, PartialEq)]
 pub enum BatchDeleteTableError {
@@ -8185,6 +8218,16 @@ impl fmt::Display for BatchDeleteTableError {
     }
 }
 impl Error for BatchDeleteTableError {}
+impl ServiceErrorCode for BatchDeleteTableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDeleteTableError::EntityNotFound(_) => "EntityNotFoundException",
+            BatchDeleteTableError::InternalService(_) => "InternalServiceException",
+            BatchDeleteTableError::InvalidInput(_) => "InvalidInputException",
+            BatchDeleteTableError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by BatchDeleteTableVersion
 #[derive(Debug, PartialEq)]
 pub enum BatchDeleteTableVersionError {
@@ -8241,6 +8284,16 @@ impl fmt::Display for BatchDeleteTableVersionError {
     }
 }
 impl Error for BatchDeleteTableVersionError {}
+impl ServiceErrorCode for BatchDeleteTableVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDeleteTableVersionError::EntityNotFound(_) => "EntityNotFoundException",
+            BatchDeleteTableVersionError::InternalService(_) => "InternalServiceException",
+            BatchDeleteTableVersionError::InvalidInput(_) => "InvalidInputException",
+            BatchDeleteTableVersionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by BatchGetCrawlers
 #[derive(Debug, PartialEq)]
 pub enum BatchGetCrawlersError {
@@ -8277,6 +8330,14 @@ impl fmt::Display for BatchGetCrawlersError {
     }
 }
 impl Error for BatchGetCrawlersError {}
+impl ServiceErrorCode for BatchGetCrawlersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetCrawlersError::InvalidInput(_) => "InvalidInputException",
+            BatchGetCrawlersError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by BatchGetDevEndpoints
 #[derive(Debug, PartialEq)]
 pub enum BatchGetDevEndpointsError {
@@ -8329,6 +8390,16 @@ impl fmt::Display for BatchGetDevEndpointsError {
     }
 }
 impl Error for BatchGetDevEndpointsError {}
+impl ServiceErrorCode for BatchGetDevEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetDevEndpointsError::AccessDenied(_) => "AccessDeniedException",
+            BatchGetDevEndpointsError::InternalService(_) => "InternalServiceException",
+            BatchGetDevEndpointsError::InvalidInput(_) => "InvalidInputException",
+            BatchGetDevEndpointsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by BatchGetJobs
 #[derive(Debug, PartialEq)]
 pub enum BatchGetJobsError {
@@ -8371,6 +8442,15 @@ impl fmt::Display for BatchGetJobsError {
     }
 }
 impl Error for BatchGetJobsError {}
+impl ServiceErrorCode for BatchGetJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetJobsError::InternalService(_) => "InternalServiceException",
+            BatchGetJobsError::InvalidInput(_) => "InvalidInputException",
+            BatchGetJobsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by BatchGetPartition
 #[derive(Debug, PartialEq)]
 pub enum BatchGetPartitionError {
@@ -8425,6 +8505,17 @@ impl fmt::Display for BatchGetPartitionError {
     }
 }
 impl Error for BatchGetPartitionError {}
+impl ServiceErrorCode for BatchGetPartitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetPartitionError::EntityNotFound(_) => "EntityNotFoundException",
+            BatchGetPartitionError::GlueEncryption(_) => "GlueEncryptionException",
+            BatchGetPartitionError::InternalService(_) => "InternalServiceException",
+            BatchGetPartitionError::InvalidInput(_) => "InvalidInputException",
+            BatchGetPartitionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by BatchGetTriggers
 #[derive(Debug, PartialEq)]
 pub enum BatchGetTriggersError {
@@ -8467,6 +8558,15 @@ impl fmt::Display for BatchGetTriggersError {
     }
 }
 impl Error for BatchGetTriggersError {}
+impl ServiceErrorCode for BatchGetTriggersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetTriggersError::InternalService(_) => "InternalServiceException",
+            BatchGetTriggersError::InvalidInput(_) => "InvalidInputException",
+            BatchGetTriggersError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by BatchGetWorkflows
 #[derive(Debug, PartialEq)]
 pub enum BatchGetWorkflowsError {
@@ -8509,6 +8609,15 @@ impl fmt::Display for BatchGetWorkflowsError {
     }
 }
 impl Error for BatchGetWorkflowsError {}
+impl ServiceErrorCode for BatchGetWorkflowsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetWorkflowsError::InternalService(_) => "InternalServiceException",
+            BatchGetWorkflowsError::InvalidInput(_) => "InvalidInputException",
+            BatchGetWorkflowsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by BatchStopJobRun
 #[derive(Debug, PartialEq)]
 pub enum GlueBatchStopJobRunError {
@@ -8553,6 +8662,15 @@ impl fmt::Display for GlueBatchStopJobRunError {
     }
 }
 impl Error for GlueBatchStopJobRunError {}
+impl ServiceErrorCode for GlueBatchStopJobRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GlueBatchStopJobRunError::InternalService(_) => "InternalServiceException",
+            GlueBatchStopJobRunError::InvalidInput(_) => "InvalidInputException",
+            GlueBatchStopJobRunError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by BatchUpdatePartition
 #[derive(Debug, PartialEq)]
 pub enum BatchUpdatePartitionError {
@@ -8611,6 +8729,17 @@ impl fmt::Display for BatchUpdatePartitionError {
     }
 }
 impl Error for BatchUpdatePartitionError {}
+impl ServiceErrorCode for BatchUpdatePartitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchUpdatePartitionError::EntityNotFound(_) => "EntityNotFoundException",
+            BatchUpdatePartitionError::GlueEncryption(_) => "GlueEncryptionException",
+            BatchUpdatePartitionError::InternalService(_) => "InternalServiceException",
+            BatchUpdatePartitionError::InvalidInput(_) => "InvalidInputException",
+            BatchUpdatePartitionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by CancelMLTaskRun
 #[derive(Debug, PartialEq)]
 pub enum CancelMLTaskRunError {
@@ -8659,6 +8788,16 @@ impl fmt::Display for CancelMLTaskRunError {
     }
 }
 impl Error for CancelMLTaskRunError {}
+impl ServiceErrorCode for CancelMLTaskRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelMLTaskRunError::EntityNotFound(_) => "EntityNotFoundException",
+            CancelMLTaskRunError::InternalService(_) => "InternalServiceException",
+            CancelMLTaskRunError::InvalidInput(_) => "InvalidInputException",
+            CancelMLTaskRunError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by CheckSchemaVersionValidity
 #[derive(Debug, PartialEq)]
 pub enum CheckSchemaVersionValidityError {
@@ -8709,6 +8848,15 @@ impl fmt::Display for CheckSchemaVersionValidityError {
     }
 }
 impl Error for CheckSchemaVersionValidityError {}
+impl ServiceErrorCode for CheckSchemaVersionValidityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CheckSchemaVersionValidityError::AccessDenied(_) => "AccessDeniedException",
+            CheckSchemaVersionValidityError::InternalService(_) => "InternalServiceException",
+            CheckSchemaVersionValidityError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by CreateClassifier
 #[derive(Debug, PartialEq)]
 pub enum CreateClassifierError {
@@ -8751,6 +8899,15 @@ impl fmt::Display for CreateClassifierError {
     }
 }
 impl Error for CreateClassifierError {}
+impl ServiceErrorCode for CreateClassifierError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateClassifierError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateClassifierError::InvalidInput(_) => "InvalidInputException",
+            CreateClassifierError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by CreateConnection
 #[derive(Debug, PartialEq)]
 pub enum CreateConnectionError {
@@ -8807,6 +8964,19 @@ impl fmt::Display for CreateConnectionError {
     }
 }
 impl Error for CreateConnectionError {}
+impl ServiceErrorCode for CreateConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConnectionError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateConnectionError::GlueEncryption(_) => "GlueEncryptionException",
+            CreateConnectionError::InvalidInput(_) => "InvalidInputException",
+            CreateConnectionError::OperationTimeout(_) => "OperationTimeoutException",
+            CreateConnectionError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateCrawler
 #[derive(Debug, PartialEq)]
 pub enum CreateCrawlerError {
@@ -8857,6 +9027,18 @@ impl fmt::Display for CreateCrawlerError {
     }
 }
 impl Error for CreateCrawlerError {}
+impl ServiceErrorCode for CreateCrawlerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCrawlerError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateCrawlerError::InvalidInput(_) => "InvalidInputException",
+            CreateCrawlerError::OperationTimeout(_) => "OperationTimeoutException",
+            CreateCrawlerError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDatabase
 #[derive(Debug, PartialEq)]
 pub enum CreateDatabaseError {
@@ -8919,6 +9101,20 @@ impl fmt::Display for CreateDatabaseError {
     }
 }
 impl Error for CreateDatabaseError {}
+impl ServiceErrorCode for CreateDatabaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDatabaseError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateDatabaseError::GlueEncryption(_) => "GlueEncryptionException",
+            CreateDatabaseError::InternalService(_) => "InternalServiceException",
+            CreateDatabaseError::InvalidInput(_) => "InvalidInputException",
+            CreateDatabaseError::OperationTimeout(_) => "OperationTimeoutException",
+            CreateDatabaseError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDevEndpoint
 #[derive(Debug, PartialEq)]
 pub enum CreateDevEndpointError {
@@ -8993,6 +9189,23 @@ impl fmt::Display for CreateDevEndpointError {
     }
 }
 impl Error for CreateDevEndpointError {}
+impl ServiceErrorCode for CreateDevEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDevEndpointError::AccessDenied(_) => "AccessDeniedException",
+            CreateDevEndpointError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateDevEndpointError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateDevEndpointError::InternalService(_) => "InternalServiceException",
+            CreateDevEndpointError::InvalidInput(_) => "InvalidInputException",
+            CreateDevEndpointError::OperationTimeout(_) => "OperationTimeoutException",
+            CreateDevEndpointError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateJob
 #[derive(Debug, PartialEq)]
 pub enum CreateJobError {
@@ -9063,6 +9276,23 @@ impl fmt::Display for CreateJobError {
     }
 }
 impl Error for CreateJobError {}
+impl ServiceErrorCode for CreateJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateJobError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateJobError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateJobError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateJobError::InternalService(_) => "InternalServiceException",
+            CreateJobError::InvalidInput(_) => "InvalidInputException",
+            CreateJobError::OperationTimeout(_) => "OperationTimeoutException",
+            CreateJobError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateMLTransform
 #[derive(Debug, PartialEq)]
 pub enum CreateMLTransformError {
@@ -9137,6 +9367,23 @@ impl fmt::Display for CreateMLTransformError {
     }
 }
 impl Error for CreateMLTransformError {}
+impl ServiceErrorCode for CreateMLTransformError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMLTransformError::AccessDenied(_) => "AccessDeniedException",
+            CreateMLTransformError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateMLTransformError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateMLTransformError::InternalService(_) => "InternalServiceException",
+            CreateMLTransformError::InvalidInput(_) => "InvalidInputException",
+            CreateMLTransformError::OperationTimeout(_) => "OperationTimeoutException",
+            CreateMLTransformError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreatePartition
 #[derive(Debug, PartialEq)]
 pub enum CreatePartitionError {
@@ -9205,6 +9452,21 @@ impl fmt::Display for CreatePartitionError {
     }
 }
 impl Error for CreatePartitionError {}
+impl ServiceErrorCode for CreatePartitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePartitionError::AlreadyExists(_) => "AlreadyExistsException",
+            CreatePartitionError::EntityNotFound(_) => "EntityNotFoundException",
+            CreatePartitionError::GlueEncryption(_) => "GlueEncryptionException",
+            CreatePartitionError::InternalService(_) => "InternalServiceException",
+            CreatePartitionError::InvalidInput(_) => "InvalidInputException",
+            CreatePartitionError::OperationTimeout(_) => "OperationTimeoutException",
+            CreatePartitionError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreatePartitionIndex
 #[derive(Debug, PartialEq)]
 pub enum CreatePartitionIndexError {
@@ -9279,6 +9541,21 @@ impl fmt::Display for CreatePartitionIndexError {
     }
 }
 impl Error for CreatePartitionIndexError {}
+impl ServiceErrorCode for CreatePartitionIndexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePartitionIndexError::AlreadyExists(_) => "AlreadyExistsException",
+            CreatePartitionIndexError::EntityNotFound(_) => "EntityNotFoundException",
+            CreatePartitionIndexError::GlueEncryption(_) => "GlueEncryptionException",
+            CreatePartitionIndexError::InternalService(_) => "InternalServiceException",
+            CreatePartitionIndexError::InvalidInput(_) => "InvalidInputException",
+            CreatePartitionIndexError::OperationTimeout(_) => "OperationTimeoutException",
+            CreatePartitionIndexError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateRegistry
 #[derive(Debug, PartialEq)]
 pub enum CreateRegistryError {
@@ -9335,6 +9612,19 @@ impl fmt::Display for CreateRegistryError {
     }
 }
 impl Error for CreateRegistryError {}
+impl ServiceErrorCode for CreateRegistryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRegistryError::AccessDenied(_) => "AccessDeniedException",
+            CreateRegistryError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateRegistryError::InternalService(_) => "InternalServiceException",
+            CreateRegistryError::InvalidInput(_) => "InvalidInputException",
+            CreateRegistryError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateSchema
 #[derive(Debug, PartialEq)]
 pub enum CreateSchemaError {
@@ -9397,6 +9687,20 @@ impl fmt::Display for CreateSchemaError {
     }
 }
 impl Error for CreateSchemaError {}
+impl ServiceErrorCode for CreateSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSchemaError::AccessDenied(_) => "AccessDeniedException",
+            CreateSchemaError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateSchemaError::EntityNotFound(_) => "EntityNotFoundException",
+            CreateSchemaError::InternalService(_) => "InternalServiceException",
+            CreateSchemaError::InvalidInput(_) => "InvalidInputException",
+            CreateSchemaError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateScript
 #[derive(Debug, PartialEq)]
 pub enum CreateScriptError {
@@ -9439,6 +9743,15 @@ impl fmt::Display for CreateScriptError {
     }
 }
 impl Error for CreateScriptError {}
+impl ServiceErrorCode for CreateScriptError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateScriptError::InternalService(_) => "InternalServiceException",
+            CreateScriptError::InvalidInput(_) => "InvalidInputException",
+            CreateScriptError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by CreateSecurityConfiguration
 #[derive(Debug, PartialEq)]
 pub enum CreateSecurityConfigurationError {
@@ -9507,6 +9820,19 @@ impl fmt::Display for CreateSecurityConfigurationError {
     }
 }
 impl Error for CreateSecurityConfigurationError {}
+impl ServiceErrorCode for CreateSecurityConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSecurityConfigurationError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateSecurityConfigurationError::InternalService(_) => "InternalServiceException",
+            CreateSecurityConfigurationError::InvalidInput(_) => "InvalidInputException",
+            CreateSecurityConfigurationError::OperationTimeout(_) => "OperationTimeoutException",
+            CreateSecurityConfigurationError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateTable
 #[derive(Debug, PartialEq)]
 pub enum CreateTableError {
@@ -9575,6 +9901,21 @@ impl fmt::Display for CreateTableError {
     }
 }
 impl Error for CreateTableError {}
+impl ServiceErrorCode for CreateTableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTableError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateTableError::EntityNotFound(_) => "EntityNotFoundException",
+            CreateTableError::GlueEncryption(_) => "GlueEncryptionException",
+            CreateTableError::InternalService(_) => "InternalServiceException",
+            CreateTableError::InvalidInput(_) => "InvalidInputException",
+            CreateTableError::OperationTimeout(_) => "OperationTimeoutException",
+            CreateTableError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateTrigger
 #[derive(Debug, PartialEq)]
 pub enum CreateTriggerError {
@@ -9653,6 +9994,24 @@ impl fmt::Display for CreateTriggerError {
     }
 }
 impl Error for CreateTriggerError {}
+impl ServiceErrorCode for CreateTriggerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTriggerError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateTriggerError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateTriggerError::EntityNotFound(_) => "EntityNotFoundException",
+            CreateTriggerError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateTriggerError::InternalService(_) => "InternalServiceException",
+            CreateTriggerError::InvalidInput(_) => "InvalidInputException",
+            CreateTriggerError::OperationTimeout(_) => "OperationTimeoutException",
+            CreateTriggerError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateUserDefinedFunction
 #[derive(Debug, PartialEq)]
 pub enum CreateUserDefinedFunctionError {
@@ -9735,6 +10094,21 @@ impl fmt::Display for CreateUserDefinedFunctionError {
     }
 }
 impl Error for CreateUserDefinedFunctionError {}
+impl ServiceErrorCode for CreateUserDefinedFunctionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserDefinedFunctionError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateUserDefinedFunctionError::EntityNotFound(_) => "EntityNotFoundException",
+            CreateUserDefinedFunctionError::GlueEncryption(_) => "GlueEncryptionException",
+            CreateUserDefinedFunctionError::InternalService(_) => "InternalServiceException",
+            CreateUserDefinedFunctionError::InvalidInput(_) => "InvalidInputException",
+            CreateUserDefinedFunctionError::OperationTimeout(_) => "OperationTimeoutException",
+            CreateUserDefinedFunctionError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateWorkflow
 #[derive(Debug, PartialEq)]
 pub enum CreateWorkflowError {
@@ -9799,6 +10173,20 @@ impl fmt::Display for CreateWorkflowError {
     }
 }
 impl Error for CreateWorkflowError {}
+impl ServiceErrorCode for CreateWorkflowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateWorkflowError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateWorkflowError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateWorkflowError::InternalService(_) => "InternalServiceException",
+            CreateWorkflowError::InvalidInput(_) => "InvalidInputException",
+            CreateWorkflowError::OperationTimeout(_) => "OperationTimeoutException",
+            CreateWorkflowError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteClassifier
 #[derive(Debug, PartialEq)]
 pub enum DeleteClassifierError {
@@ -9835,6 +10223,14 @@ impl fmt::Display for DeleteClassifierError {
     }
 }
 impl Error for DeleteClassifierError {}
+impl ServiceErrorCode for DeleteClassifierError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteClassifierError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteClassifierError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeleteColumnStatisticsForPartition
 #[derive(Debug, PartialEq)]
 pub enum DeleteColumnStatisticsForPartitionError {
@@ -9911,6 +10307,21 @@ impl fmt::Display for DeleteColumnStatisticsForPartitionError {
     }
 }
 impl Error for DeleteColumnStatisticsForPartitionError {}
+impl ServiceErrorCode for DeleteColumnStatisticsForPartitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteColumnStatisticsForPartitionError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteColumnStatisticsForPartitionError::GlueEncryption(_) => "GlueEncryptionException",
+            DeleteColumnStatisticsForPartitionError::InternalService(_) => {
+                "InternalServiceException"
+            }
+            DeleteColumnStatisticsForPartitionError::InvalidInput(_) => "InvalidInputException",
+            DeleteColumnStatisticsForPartitionError::OperationTimeout(_) => {
+                "OperationTimeoutException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteColumnStatisticsForTable
 #[derive(Debug, PartialEq)]
 pub enum DeleteColumnStatisticsForTableError {
@@ -9985,6 +10396,17 @@ impl fmt::Display for DeleteColumnStatisticsForTableError {
     }
 }
 impl Error for DeleteColumnStatisticsForTableError {}
+impl ServiceErrorCode for DeleteColumnStatisticsForTableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteColumnStatisticsForTableError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteColumnStatisticsForTableError::GlueEncryption(_) => "GlueEncryptionException",
+            DeleteColumnStatisticsForTableError::InternalService(_) => "InternalServiceException",
+            DeleteColumnStatisticsForTableError::InvalidInput(_) => "InvalidInputException",
+            DeleteColumnStatisticsForTableError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeleteConnection
 #[derive(Debug, PartialEq)]
 pub enum DeleteConnectionError {
@@ -10021,6 +10443,14 @@ impl fmt::Display for DeleteConnectionError {
     }
 }
 impl Error for DeleteConnectionError {}
+impl ServiceErrorCode for DeleteConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConnectionError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteConnectionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeleteCrawler
 #[derive(Debug, PartialEq)]
 pub enum DeleteCrawlerError {
@@ -10071,6 +10501,16 @@ impl fmt::Display for DeleteCrawlerError {
     }
 }
 impl Error for DeleteCrawlerError {}
+impl ServiceErrorCode for DeleteCrawlerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCrawlerError::CrawlerRunning(_) => "CrawlerRunningException",
+            DeleteCrawlerError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteCrawlerError::OperationTimeout(_) => "OperationTimeoutException",
+            DeleteCrawlerError::SchedulerTransitioning(_) => "SchedulerTransitioningException",
+        }
+    }
+}
 /// Errors returned by DeleteDatabase
 #[derive(Debug, PartialEq)]
 pub enum DeleteDatabaseError {
@@ -10119,6 +10559,16 @@ impl fmt::Display for DeleteDatabaseError {
     }
 }
 impl Error for DeleteDatabaseError {}
+impl ServiceErrorCode for DeleteDatabaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDatabaseError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteDatabaseError::InternalService(_) => "InternalServiceException",
+            DeleteDatabaseError::InvalidInput(_) => "InvalidInputException",
+            DeleteDatabaseError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeleteDevEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DeleteDevEndpointError {
@@ -10167,6 +10617,16 @@ impl fmt::Display for DeleteDevEndpointError {
     }
 }
 impl Error for DeleteDevEndpointError {}
+impl ServiceErrorCode for DeleteDevEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDevEndpointError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteDevEndpointError::InternalService(_) => "InternalServiceException",
+            DeleteDevEndpointError::InvalidInput(_) => "InvalidInputException",
+            DeleteDevEndpointError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeleteJob
 #[derive(Debug, PartialEq)]
 pub enum DeleteJobError {
@@ -10209,6 +10669,15 @@ impl fmt::Display for DeleteJobError {
     }
 }
 impl Error for DeleteJobError {}
+impl ServiceErrorCode for DeleteJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteJobError::InternalService(_) => "InternalServiceException",
+            DeleteJobError::InvalidInput(_) => "InvalidInputException",
+            DeleteJobError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeleteMLTransform
 #[derive(Debug, PartialEq)]
 pub enum DeleteMLTransformError {
@@ -10257,6 +10726,16 @@ impl fmt::Display for DeleteMLTransformError {
     }
 }
 impl Error for DeleteMLTransformError {}
+impl ServiceErrorCode for DeleteMLTransformError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMLTransformError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteMLTransformError::InternalService(_) => "InternalServiceException",
+            DeleteMLTransformError::InvalidInput(_) => "InvalidInputException",
+            DeleteMLTransformError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeletePartition
 #[derive(Debug, PartialEq)]
 pub enum DeletePartitionError {
@@ -10305,6 +10784,16 @@ impl fmt::Display for DeletePartitionError {
     }
 }
 impl Error for DeletePartitionError {}
+impl ServiceErrorCode for DeletePartitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePartitionError::EntityNotFound(_) => "EntityNotFoundException",
+            DeletePartitionError::InternalService(_) => "InternalServiceException",
+            DeletePartitionError::InvalidInput(_) => "InvalidInputException",
+            DeletePartitionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeletePartitionIndex
 #[derive(Debug, PartialEq)]
 pub enum DeletePartitionIndexError {
@@ -10369,6 +10858,18 @@ impl fmt::Display for DeletePartitionIndexError {
     }
 }
 impl Error for DeletePartitionIndexError {}
+impl ServiceErrorCode for DeletePartitionIndexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePartitionIndexError::Conflict(_) => "ConflictException",
+            DeletePartitionIndexError::EntityNotFound(_) => "EntityNotFoundException",
+            DeletePartitionIndexError::GlueEncryption(_) => "GlueEncryptionException",
+            DeletePartitionIndexError::InternalService(_) => "InternalServiceException",
+            DeletePartitionIndexError::InvalidInput(_) => "InvalidInputException",
+            DeletePartitionIndexError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeleteRegistry
 #[derive(Debug, PartialEq)]
 pub enum DeleteRegistryError {
@@ -10419,6 +10920,16 @@ impl fmt::Display for DeleteRegistryError {
     }
 }
 impl Error for DeleteRegistryError {}
+impl ServiceErrorCode for DeleteRegistryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRegistryError::AccessDenied(_) => "AccessDeniedException",
+            DeleteRegistryError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteRegistryError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteRegistryError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DeleteResourcePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteResourcePolicyError {
@@ -10479,6 +10990,17 @@ impl fmt::Display for DeleteResourcePolicyError {
     }
 }
 impl Error for DeleteResourcePolicyError {}
+impl ServiceErrorCode for DeleteResourcePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResourcePolicyError::ConditionCheckFailure(_) => "ConditionCheckFailureException",
+            DeleteResourcePolicyError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteResourcePolicyError::InternalService(_) => "InternalServiceException",
+            DeleteResourcePolicyError::InvalidInput(_) => "InvalidInputException",
+            DeleteResourcePolicyError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeleteSchema
 #[derive(Debug, PartialEq)]
 pub enum DeleteSchemaError {
@@ -10527,6 +11049,16 @@ impl fmt::Display for DeleteSchemaError {
     }
 }
 impl Error for DeleteSchemaError {}
+impl ServiceErrorCode for DeleteSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSchemaError::AccessDenied(_) => "AccessDeniedException",
+            DeleteSchemaError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteSchemaError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteSchemaError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DeleteSchemaVersions
 #[derive(Debug, PartialEq)]
 pub enum DeleteSchemaVersionsError {
@@ -10577,6 +11109,18 @@ impl fmt::Display for DeleteSchemaVersionsError {
     }
 }
 impl Error for DeleteSchemaVersionsError {}
+impl ServiceErrorCode for DeleteSchemaVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSchemaVersionsError::AccessDenied(_) => "AccessDeniedException",
+            DeleteSchemaVersionsError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteSchemaVersionsError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteSchemaVersionsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DeleteSecurityConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteSecurityConfigurationError {
@@ -10635,6 +11179,16 @@ impl fmt::Display for DeleteSecurityConfigurationError {
     }
 }
 impl Error for DeleteSecurityConfigurationError {}
+impl ServiceErrorCode for DeleteSecurityConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSecurityConfigurationError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteSecurityConfigurationError::InternalService(_) => "InternalServiceException",
+            DeleteSecurityConfigurationError::InvalidInput(_) => "InvalidInputException",
+            DeleteSecurityConfigurationError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeleteTable
 #[derive(Debug, PartialEq)]
 pub enum DeleteTableError {
@@ -10683,6 +11237,16 @@ impl fmt::Display for DeleteTableError {
     }
 }
 impl Error for DeleteTableError {}
+impl ServiceErrorCode for DeleteTableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTableError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteTableError::InternalService(_) => "InternalServiceException",
+            DeleteTableError::InvalidInput(_) => "InvalidInputException",
+            DeleteTableError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeleteTableVersion
 #[derive(Debug, PartialEq)]
 pub enum DeleteTableVersionError {
@@ -10731,6 +11295,16 @@ impl fmt::Display for DeleteTableVersionError {
     }
 }
 impl Error for DeleteTableVersionError {}
+impl ServiceErrorCode for DeleteTableVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTableVersionError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteTableVersionError::InternalService(_) => "InternalServiceException",
+            DeleteTableVersionError::InvalidInput(_) => "InvalidInputException",
+            DeleteTableVersionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeleteTrigger
 #[derive(Debug, PartialEq)]
 pub enum DeleteTriggerError {
@@ -10781,6 +11355,16 @@ impl fmt::Display for DeleteTriggerError {
     }
 }
 impl Error for DeleteTriggerError {}
+impl ServiceErrorCode for DeleteTriggerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTriggerError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteTriggerError::InternalService(_) => "InternalServiceException",
+            DeleteTriggerError::InvalidInput(_) => "InvalidInputException",
+            DeleteTriggerError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeleteUserDefinedFunction
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserDefinedFunctionError {
@@ -10837,6 +11421,16 @@ impl fmt::Display for DeleteUserDefinedFunctionError {
     }
 }
 impl Error for DeleteUserDefinedFunctionError {}
+impl ServiceErrorCode for DeleteUserDefinedFunctionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserDefinedFunctionError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteUserDefinedFunctionError::InternalService(_) => "InternalServiceException",
+            DeleteUserDefinedFunctionError::InvalidInput(_) => "InvalidInputException",
+            DeleteUserDefinedFunctionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeleteWorkflow
 #[derive(Debug, PartialEq)]
 pub enum DeleteWorkflowError {
@@ -10887,6 +11481,16 @@ impl fmt::Display for DeleteWorkflowError {
     }
 }
 impl Error for DeleteWorkflowError {}
+impl ServiceErrorCode for DeleteWorkflowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteWorkflowError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteWorkflowError::InternalService(_) => "InternalServiceException",
+            DeleteWorkflowError::InvalidInput(_) => "InvalidInputException",
+            DeleteWorkflowError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetCatalogImportStatus
 #[derive(Debug, PartialEq)]
 pub enum GetCatalogImportStatusError {
@@ -10927,6 +11531,14 @@ impl fmt::Display for GetCatalogImportStatusError {
     }
 }
 impl Error for GetCatalogImportStatusError {}
+impl ServiceErrorCode for GetCatalogImportStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCatalogImportStatusError::InternalService(_) => "InternalServiceException",
+            GetCatalogImportStatusError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetClassifier
 #[derive(Debug, PartialEq)]
 pub enum GetClassifierError {
@@ -10963,6 +11575,14 @@ impl fmt::Display for GetClassifierError {
     }
 }
 impl Error for GetClassifierError {}
+impl ServiceErrorCode for GetClassifierError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetClassifierError::EntityNotFound(_) => "EntityNotFoundException",
+            GetClassifierError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetClassifiers
 #[derive(Debug, PartialEq)]
 pub enum GetClassifiersError {
@@ -10993,6 +11613,13 @@ impl fmt::Display for GetClassifiersError {
     }
 }
 impl Error for GetClassifiersError {}
+impl ServiceErrorCode for GetClassifiersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetClassifiersError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetColumnStatisticsForPartition
 #[derive(Debug, PartialEq)]
 pub enum GetColumnStatisticsForPartitionError {
@@ -11067,6 +11694,19 @@ impl fmt::Display for GetColumnStatisticsForPartitionError {
     }
 }
 impl Error for GetColumnStatisticsForPartitionError {}
+impl ServiceErrorCode for GetColumnStatisticsForPartitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetColumnStatisticsForPartitionError::EntityNotFound(_) => "EntityNotFoundException",
+            GetColumnStatisticsForPartitionError::GlueEncryption(_) => "GlueEncryptionException",
+            GetColumnStatisticsForPartitionError::InternalService(_) => "InternalServiceException",
+            GetColumnStatisticsForPartitionError::InvalidInput(_) => "InvalidInputException",
+            GetColumnStatisticsForPartitionError::OperationTimeout(_) => {
+                "OperationTimeoutException"
+            }
+        }
+    }
+}
 /// Errors returned by GetColumnStatisticsForTable
 #[derive(Debug, PartialEq)]
 pub enum GetColumnStatisticsForTableError {
@@ -11133,6 +11773,17 @@ impl fmt::Display for GetColumnStatisticsForTableError {
     }
 }
 impl Error for GetColumnStatisticsForTableError {}
+impl ServiceErrorCode for GetColumnStatisticsForTableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetColumnStatisticsForTableError::EntityNotFound(_) => "EntityNotFoundException",
+            GetColumnStatisticsForTableError::GlueEncryption(_) => "GlueEncryptionException",
+            GetColumnStatisticsForTableError::InternalService(_) => "InternalServiceException",
+            GetColumnStatisticsForTableError::InvalidInput(_) => "InvalidInputException",
+            GetColumnStatisticsForTableError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetConnection
 #[derive(Debug, PartialEq)]
 pub enum GetConnectionError {
@@ -11181,6 +11832,16 @@ impl fmt::Display for GetConnectionError {
     }
 }
 impl Error for GetConnectionError {}
+impl ServiceErrorCode for GetConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConnectionError::EntityNotFound(_) => "EntityNotFoundException",
+            GetConnectionError::GlueEncryption(_) => "GlueEncryptionException",
+            GetConnectionError::InvalidInput(_) => "InvalidInputException",
+            GetConnectionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetConnections
 #[derive(Debug, PartialEq)]
 pub enum GetConnectionsError {
@@ -11229,6 +11890,16 @@ impl fmt::Display for GetConnectionsError {
     }
 }
 impl Error for GetConnectionsError {}
+impl ServiceErrorCode for GetConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConnectionsError::EntityNotFound(_) => "EntityNotFoundException",
+            GetConnectionsError::GlueEncryption(_) => "GlueEncryptionException",
+            GetConnectionsError::InvalidInput(_) => "InvalidInputException",
+            GetConnectionsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetCrawler
 #[derive(Debug, PartialEq)]
 pub enum GetCrawlerError {
@@ -11265,6 +11936,14 @@ impl fmt::Display for GetCrawlerError {
     }
 }
 impl Error for GetCrawlerError {}
+impl ServiceErrorCode for GetCrawlerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCrawlerError::EntityNotFound(_) => "EntityNotFoundException",
+            GetCrawlerError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetCrawlerMetrics
 #[derive(Debug, PartialEq)]
 pub enum GetCrawlerMetricsError {
@@ -11295,6 +11974,13 @@ impl fmt::Display for GetCrawlerMetricsError {
     }
 }
 impl Error for GetCrawlerMetricsError {}
+impl ServiceErrorCode for GetCrawlerMetricsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCrawlerMetricsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetCrawlers
 #[derive(Debug, PartialEq)]
 pub enum GetCrawlersError {
@@ -11325,6 +12011,13 @@ impl fmt::Display for GetCrawlersError {
     }
 }
 impl Error for GetCrawlersError {}
+impl ServiceErrorCode for GetCrawlersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCrawlersError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetDataCatalogEncryptionSettings
 #[derive(Debug, PartialEq)]
 pub enum GetDataCatalogEncryptionSettingsError {
@@ -11381,6 +12074,17 @@ impl fmt::Display for GetDataCatalogEncryptionSettingsError {
     }
 }
 impl Error for GetDataCatalogEncryptionSettingsError {}
+impl ServiceErrorCode for GetDataCatalogEncryptionSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDataCatalogEncryptionSettingsError::InternalService(_) => "InternalServiceException",
+            GetDataCatalogEncryptionSettingsError::InvalidInput(_) => "InvalidInputException",
+            GetDataCatalogEncryptionSettingsError::OperationTimeout(_) => {
+                "OperationTimeoutException"
+            }
+        }
+    }
+}
 /// Errors returned by GetDatabase
 #[derive(Debug, PartialEq)]
 pub enum GetDatabaseError {
@@ -11435,6 +12139,17 @@ impl fmt::Display for GetDatabaseError {
     }
 }
 impl Error for GetDatabaseError {}
+impl ServiceErrorCode for GetDatabaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDatabaseError::EntityNotFound(_) => "EntityNotFoundException",
+            GetDatabaseError::GlueEncryption(_) => "GlueEncryptionException",
+            GetDatabaseError::InternalService(_) => "InternalServiceException",
+            GetDatabaseError::InvalidInput(_) => "InvalidInputException",
+            GetDatabaseError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetDatabases
 #[derive(Debug, PartialEq)]
 pub enum GetDatabasesError {
@@ -11483,6 +12198,16 @@ impl fmt::Display for GetDatabasesError {
     }
 }
 impl Error for GetDatabasesError {}
+impl ServiceErrorCode for GetDatabasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDatabasesError::GlueEncryption(_) => "GlueEncryptionException",
+            GetDatabasesError::InternalService(_) => "InternalServiceException",
+            GetDatabasesError::InvalidInput(_) => "InvalidInputException",
+            GetDatabasesError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetDataflowGraph
 #[derive(Debug, PartialEq)]
 pub enum GetDataflowGraphError {
@@ -11525,6 +12250,15 @@ impl fmt::Display for GetDataflowGraphError {
     }
 }
 impl Error for GetDataflowGraphError {}
+impl ServiceErrorCode for GetDataflowGraphError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDataflowGraphError::InternalService(_) => "InternalServiceException",
+            GetDataflowGraphError::InvalidInput(_) => "InvalidInputException",
+            GetDataflowGraphError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetDevEndpoint
 #[derive(Debug, PartialEq)]
 pub enum GetDevEndpointError {
@@ -11573,6 +12307,16 @@ impl fmt::Display for GetDevEndpointError {
     }
 }
 impl Error for GetDevEndpointError {}
+impl ServiceErrorCode for GetDevEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDevEndpointError::EntityNotFound(_) => "EntityNotFoundException",
+            GetDevEndpointError::InternalService(_) => "InternalServiceException",
+            GetDevEndpointError::InvalidInput(_) => "InvalidInputException",
+            GetDevEndpointError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetDevEndpoints
 #[derive(Debug, PartialEq)]
 pub enum GetDevEndpointsError {
@@ -11621,6 +12365,16 @@ impl fmt::Display for GetDevEndpointsError {
     }
 }
 impl Error for GetDevEndpointsError {}
+impl ServiceErrorCode for GetDevEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDevEndpointsError::EntityNotFound(_) => "EntityNotFoundException",
+            GetDevEndpointsError::InternalService(_) => "InternalServiceException",
+            GetDevEndpointsError::InvalidInput(_) => "InvalidInputException",
+            GetDevEndpointsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetJob
 #[derive(Debug, PartialEq)]
 pub enum GetJobError {
@@ -11669,6 +12423,16 @@ impl fmt::Display for GetJobError {
     }
 }
 impl Error for GetJobError {}
+impl ServiceErrorCode for GetJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetJobError::EntityNotFound(_) => "EntityNotFoundException",
+            GetJobError::InternalService(_) => "InternalServiceException",
+            GetJobError::InvalidInput(_) => "InvalidInputException",
+            GetJobError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetJobBookmark
 #[derive(Debug, PartialEq)]
 pub enum GetJobBookmarkError {
@@ -11717,6 +12481,16 @@ impl fmt::Display for GetJobBookmarkError {
     }
 }
 impl Error for GetJobBookmarkError {}
+impl ServiceErrorCode for GetJobBookmarkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetJobBookmarkError::EntityNotFound(_) => "EntityNotFoundException",
+            GetJobBookmarkError::InternalService(_) => "InternalServiceException",
+            GetJobBookmarkError::InvalidInput(_) => "InvalidInputException",
+            GetJobBookmarkError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetJobRun
 #[derive(Debug, PartialEq)]
 pub enum GetJobRunError {
@@ -11765,6 +12539,16 @@ impl fmt::Display for GetJobRunError {
     }
 }
 impl Error for GetJobRunError {}
+impl ServiceErrorCode for GetJobRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetJobRunError::EntityNotFound(_) => "EntityNotFoundException",
+            GetJobRunError::InternalService(_) => "InternalServiceException",
+            GetJobRunError::InvalidInput(_) => "InvalidInputException",
+            GetJobRunError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetJobRuns
 #[derive(Debug, PartialEq)]
 pub enum GetJobRunsError {
@@ -11813,6 +12597,16 @@ impl fmt::Display for GetJobRunsError {
     }
 }
 impl Error for GetJobRunsError {}
+impl ServiceErrorCode for GetJobRunsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetJobRunsError::EntityNotFound(_) => "EntityNotFoundException",
+            GetJobRunsError::InternalService(_) => "InternalServiceException",
+            GetJobRunsError::InvalidInput(_) => "InvalidInputException",
+            GetJobRunsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetJobs
 #[derive(Debug, PartialEq)]
 pub enum GetJobsError {
@@ -11861,6 +12655,16 @@ impl fmt::Display for GetJobsError {
     }
 }
 impl Error for GetJobsError {}
+impl ServiceErrorCode for GetJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetJobsError::EntityNotFound(_) => "EntityNotFoundException",
+            GetJobsError::InternalService(_) => "InternalServiceException",
+            GetJobsError::InvalidInput(_) => "InvalidInputException",
+            GetJobsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetMLTaskRun
 #[derive(Debug, PartialEq)]
 pub enum GetMLTaskRunError {
@@ -11909,6 +12713,16 @@ impl fmt::Display for GetMLTaskRunError {
     }
 }
 impl Error for GetMLTaskRunError {}
+impl ServiceErrorCode for GetMLTaskRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMLTaskRunError::EntityNotFound(_) => "EntityNotFoundException",
+            GetMLTaskRunError::InternalService(_) => "InternalServiceException",
+            GetMLTaskRunError::InvalidInput(_) => "InvalidInputException",
+            GetMLTaskRunError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetMLTaskRuns
 #[derive(Debug, PartialEq)]
 pub enum GetMLTaskRunsError {
@@ -11957,6 +12771,16 @@ impl fmt::Display for GetMLTaskRunsError {
     }
 }
 impl Error for GetMLTaskRunsError {}
+impl ServiceErrorCode for GetMLTaskRunsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMLTaskRunsError::EntityNotFound(_) => "EntityNotFoundException",
+            GetMLTaskRunsError::InternalService(_) => "InternalServiceException",
+            GetMLTaskRunsError::InvalidInput(_) => "InvalidInputException",
+            GetMLTaskRunsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetMLTransform
 #[derive(Debug, PartialEq)]
 pub enum GetMLTransformError {
@@ -12005,6 +12829,16 @@ impl fmt::Display for GetMLTransformError {
     }
 }
 impl Error for GetMLTransformError {}
+impl ServiceErrorCode for GetMLTransformError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMLTransformError::EntityNotFound(_) => "EntityNotFoundException",
+            GetMLTransformError::InternalService(_) => "InternalServiceException",
+            GetMLTransformError::InvalidInput(_) => "InvalidInputException",
+            GetMLTransformError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetMLTransforms
 #[derive(Debug, PartialEq)]
 pub enum GetMLTransformsError {
@@ -12053,6 +12887,16 @@ impl fmt::Display for GetMLTransformsError {
     }
 }
 impl Error for GetMLTransformsError {}
+impl ServiceErrorCode for GetMLTransformsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMLTransformsError::EntityNotFound(_) => "EntityNotFoundException",
+            GetMLTransformsError::InternalService(_) => "InternalServiceException",
+            GetMLTransformsError::InvalidInput(_) => "InvalidInputException",
+            GetMLTransformsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetMapping
 #[derive(Debug, PartialEq)]
 pub enum GetMappingError {
@@ -12101,6 +12945,16 @@ impl fmt::Display for GetMappingError {
     }
 }
 impl Error for GetMappingError {}
+impl ServiceErrorCode for GetMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMappingError::EntityNotFound(_) => "EntityNotFoundException",
+            GetMappingError::InternalService(_) => "InternalServiceException",
+            GetMappingError::InvalidInput(_) => "InvalidInputException",
+            GetMappingError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetPartition
 #[derive(Debug, PartialEq)]
 pub enum GetPartitionError {
@@ -12155,6 +13009,17 @@ impl fmt::Display for GetPartitionError {
     }
 }
 impl Error for GetPartitionError {}
+impl ServiceErrorCode for GetPartitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPartitionError::EntityNotFound(_) => "EntityNotFoundException",
+            GetPartitionError::GlueEncryption(_) => "GlueEncryptionException",
+            GetPartitionError::InternalService(_) => "InternalServiceException",
+            GetPartitionError::InvalidInput(_) => "InvalidInputException",
+            GetPartitionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetPartitionIndexes
 #[derive(Debug, PartialEq)]
 pub enum GetPartitionIndexesError {
@@ -12211,6 +13076,17 @@ impl fmt::Display for GetPartitionIndexesError {
     }
 }
 impl Error for GetPartitionIndexesError {}
+impl ServiceErrorCode for GetPartitionIndexesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPartitionIndexesError::Conflict(_) => "ConflictException",
+            GetPartitionIndexesError::EntityNotFound(_) => "EntityNotFoundException",
+            GetPartitionIndexesError::InternalService(_) => "InternalServiceException",
+            GetPartitionIndexesError::InvalidInput(_) => "InvalidInputException",
+            GetPartitionIndexesError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetPartitions
 #[derive(Debug, PartialEq)]
 pub enum GetPartitionsError {
@@ -12265,6 +13141,17 @@ impl fmt::Display for GetPartitionsError {
     }
 }
 impl Error for GetPartitionsError {}
+impl ServiceErrorCode for GetPartitionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPartitionsError::EntityNotFound(_) => "EntityNotFoundException",
+            GetPartitionsError::GlueEncryption(_) => "GlueEncryptionException",
+            GetPartitionsError::InternalService(_) => "InternalServiceException",
+            GetPartitionsError::InvalidInput(_) => "InvalidInputException",
+            GetPartitionsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetPlan
 #[derive(Debug, PartialEq)]
 pub enum GetPlanError {
@@ -12307,6 +13194,15 @@ impl fmt::Display for GetPlanError {
     }
 }
 impl Error for GetPlanError {}
+impl ServiceErrorCode for GetPlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPlanError::InternalService(_) => "InternalServiceException",
+            GetPlanError::InvalidInput(_) => "InvalidInputException",
+            GetPlanError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetRegistry
 #[derive(Debug, PartialEq)]
 pub enum GetRegistryError {
@@ -12355,6 +13251,16 @@ impl fmt::Display for GetRegistryError {
     }
 }
 impl Error for GetRegistryError {}
+impl ServiceErrorCode for GetRegistryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRegistryError::AccessDenied(_) => "AccessDeniedException",
+            GetRegistryError::EntityNotFound(_) => "EntityNotFoundException",
+            GetRegistryError::InternalService(_) => "InternalServiceException",
+            GetRegistryError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by GetResourcePolicies
 #[derive(Debug, PartialEq)]
 pub enum GetResourcePoliciesError {
@@ -12405,6 +13311,16 @@ impl fmt::Display for GetResourcePoliciesError {
     }
 }
 impl Error for GetResourcePoliciesError {}
+impl ServiceErrorCode for GetResourcePoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourcePoliciesError::GlueEncryption(_) => "GlueEncryptionException",
+            GetResourcePoliciesError::InternalService(_) => "InternalServiceException",
+            GetResourcePoliciesError::InvalidInput(_) => "InvalidInputException",
+            GetResourcePoliciesError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetResourcePolicy
 #[derive(Debug, PartialEq)]
 pub enum GetResourcePolicyError {
@@ -12453,6 +13369,16 @@ impl fmt::Display for GetResourcePolicyError {
     }
 }
 impl Error for GetResourcePolicyError {}
+impl ServiceErrorCode for GetResourcePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourcePolicyError::EntityNotFound(_) => "EntityNotFoundException",
+            GetResourcePolicyError::InternalService(_) => "InternalServiceException",
+            GetResourcePolicyError::InvalidInput(_) => "InvalidInputException",
+            GetResourcePolicyError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetSchema
 #[derive(Debug, PartialEq)]
 pub enum GetSchemaError {
@@ -12501,6 +13427,16 @@ impl fmt::Display for GetSchemaError {
     }
 }
 impl Error for GetSchemaError {}
+impl ServiceErrorCode for GetSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSchemaError::AccessDenied(_) => "AccessDeniedException",
+            GetSchemaError::EntityNotFound(_) => "EntityNotFoundException",
+            GetSchemaError::InternalService(_) => "InternalServiceException",
+            GetSchemaError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by GetSchemaByDefinition
 #[derive(Debug, PartialEq)]
 pub enum GetSchemaByDefinitionError {
@@ -12553,6 +13489,16 @@ impl fmt::Display for GetSchemaByDefinitionError {
     }
 }
 impl Error for GetSchemaByDefinitionError {}
+impl ServiceErrorCode for GetSchemaByDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSchemaByDefinitionError::AccessDenied(_) => "AccessDeniedException",
+            GetSchemaByDefinitionError::EntityNotFound(_) => "EntityNotFoundException",
+            GetSchemaByDefinitionError::InternalService(_) => "InternalServiceException",
+            GetSchemaByDefinitionError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by GetSchemaVersion
 #[derive(Debug, PartialEq)]
 pub enum GetSchemaVersionError {
@@ -12601,6 +13547,16 @@ impl fmt::Display for GetSchemaVersionError {
     }
 }
 impl Error for GetSchemaVersionError {}
+impl ServiceErrorCode for GetSchemaVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSchemaVersionError::AccessDenied(_) => "AccessDeniedException",
+            GetSchemaVersionError::EntityNotFound(_) => "EntityNotFoundException",
+            GetSchemaVersionError::InternalService(_) => "InternalServiceException",
+            GetSchemaVersionError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by GetSchemaVersionsDiff
 #[derive(Debug, PartialEq)]
 pub enum GetSchemaVersionsDiffError {
@@ -12653,6 +13609,16 @@ impl fmt::Display for GetSchemaVersionsDiffError {
     }
 }
 impl Error for GetSchemaVersionsDiffError {}
+impl ServiceErrorCode for GetSchemaVersionsDiffError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSchemaVersionsDiffError::AccessDenied(_) => "AccessDeniedException",
+            GetSchemaVersionsDiffError::EntityNotFound(_) => "EntityNotFoundException",
+            GetSchemaVersionsDiffError::InternalService(_) => "InternalServiceException",
+            GetSchemaVersionsDiffError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by GetSecurityConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetSecurityConfigurationError {
@@ -12709,6 +13675,16 @@ impl fmt::Display for GetSecurityConfigurationError {
     }
 }
 impl Error for GetSecurityConfigurationError {}
+impl ServiceErrorCode for GetSecurityConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSecurityConfigurationError::EntityNotFound(_) => "EntityNotFoundException",
+            GetSecurityConfigurationError::InternalService(_) => "InternalServiceException",
+            GetSecurityConfigurationError::InvalidInput(_) => "InvalidInputException",
+            GetSecurityConfigurationError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetSecurityConfigurations
 #[derive(Debug, PartialEq)]
 pub enum GetSecurityConfigurationsError {
@@ -12765,6 +13741,16 @@ impl fmt::Display for GetSecurityConfigurationsError {
     }
 }
 impl Error for GetSecurityConfigurationsError {}
+impl ServiceErrorCode for GetSecurityConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSecurityConfigurationsError::EntityNotFound(_) => "EntityNotFoundException",
+            GetSecurityConfigurationsError::InternalService(_) => "InternalServiceException",
+            GetSecurityConfigurationsError::InvalidInput(_) => "InvalidInputException",
+            GetSecurityConfigurationsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetTable
 #[derive(Debug, PartialEq)]
 pub enum GetTableError {
@@ -12819,6 +13805,17 @@ impl fmt::Display for GetTableError {
     }
 }
 impl Error for GetTableError {}
+impl ServiceErrorCode for GetTableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTableError::EntityNotFound(_) => "EntityNotFoundException",
+            GetTableError::GlueEncryption(_) => "GlueEncryptionException",
+            GetTableError::InternalService(_) => "InternalServiceException",
+            GetTableError::InvalidInput(_) => "InvalidInputException",
+            GetTableError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetTableVersion
 #[derive(Debug, PartialEq)]
 pub enum GetTableVersionError {
@@ -12873,6 +13870,17 @@ impl fmt::Display for GetTableVersionError {
     }
 }
 impl Error for GetTableVersionError {}
+impl ServiceErrorCode for GetTableVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTableVersionError::EntityNotFound(_) => "EntityNotFoundException",
+            GetTableVersionError::GlueEncryption(_) => "GlueEncryptionException",
+            GetTableVersionError::InternalService(_) => "InternalServiceException",
+            GetTableVersionError::InvalidInput(_) => "InvalidInputException",
+            GetTableVersionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetTableVersions
 #[derive(Debug, PartialEq)]
 pub enum GetTableVersionsError {
@@ -12927,6 +13935,17 @@ impl fmt::Display for GetTableVersionsError {
     }
 }
 impl Error for GetTableVersionsError {}
+impl ServiceErrorCode for GetTableVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTableVersionsError::EntityNotFound(_) => "EntityNotFoundException",
+            GetTableVersionsError::GlueEncryption(_) => "GlueEncryptionException",
+            GetTableVersionsError::InternalService(_) => "InternalServiceException",
+            GetTableVersionsError::InvalidInput(_) => "InvalidInputException",
+            GetTableVersionsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetTables
 #[derive(Debug, PartialEq)]
 pub enum GetTablesError {
@@ -12981,6 +14000,17 @@ impl fmt::Display for GetTablesError {
     }
 }
 impl Error for GetTablesError {}
+impl ServiceErrorCode for GetTablesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTablesError::EntityNotFound(_) => "EntityNotFoundException",
+            GetTablesError::GlueEncryption(_) => "GlueEncryptionException",
+            GetTablesError::InternalService(_) => "InternalServiceException",
+            GetTablesError::InvalidInput(_) => "InvalidInputException",
+            GetTablesError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetTags
 #[derive(Debug, PartialEq)]
 pub enum GetTagsError {
@@ -13029,6 +14059,16 @@ impl fmt::Display for GetTagsError {
     }
 }
 impl Error for GetTagsError {}
+impl ServiceErrorCode for GetTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTagsError::EntityNotFound(_) => "EntityNotFoundException",
+            GetTagsError::InternalService(_) => "InternalServiceException",
+            GetTagsError::InvalidInput(_) => "InvalidInputException",
+            GetTagsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetTrigger
 #[derive(Debug, PartialEq)]
 pub enum GetTriggerError {
@@ -13077,6 +14117,16 @@ impl fmt::Display for GetTriggerError {
     }
 }
 impl Error for GetTriggerError {}
+impl ServiceErrorCode for GetTriggerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTriggerError::EntityNotFound(_) => "EntityNotFoundException",
+            GetTriggerError::InternalService(_) => "InternalServiceException",
+            GetTriggerError::InvalidInput(_) => "InvalidInputException",
+            GetTriggerError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetTriggers
 #[derive(Debug, PartialEq)]
 pub enum GetTriggersError {
@@ -13124,7 +14174,17 @@ impl fmt::Display for GetTriggersError {
         }
     }
 }
-impl Error for GetTriggersError {}
+impl Error for GetTriggersError {}
+impl ServiceErrorCode for GetTriggersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTriggersError::EntityNotFound(_) => "EntityNotFoundException",
+            GetTriggersError::InternalService(_) => "InternalServiceException",
+            GetTriggersError::InvalidInput(_) => "InvalidInputException",
+            GetTriggersError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetUserDefinedFunction
 #[derive(Debug, PartialEq)]
 pub enum GetUserDefinedFunctionError {
@@ -13187,6 +14247,17 @@ impl fmt::Display for GetUserDefinedFunctionError {
     }
 }
 impl Error for GetUserDefinedFunctionError {}
+impl ServiceErrorCode for GetUserDefinedFunctionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUserDefinedFunctionError::EntityNotFound(_) => "EntityNotFoundException",
+            GetUserDefinedFunctionError::GlueEncryption(_) => "GlueEncryptionException",
+            GetUserDefinedFunctionError::InternalService(_) => "InternalServiceException",
+            GetUserDefinedFunctionError::InvalidInput(_) => "InvalidInputException",
+            GetUserDefinedFunctionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetUserDefinedFunctions
 #[derive(Debug, PartialEq)]
 pub enum GetUserDefinedFunctionsError {
@@ -13251,6 +14322,17 @@ impl fmt::Display for GetUserDefinedFunctionsError {
     }
 }
 impl Error for GetUserDefinedFunctionsError {}
+impl ServiceErrorCode for GetUserDefinedFunctionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUserDefinedFunctionsError::EntityNotFound(_) => "EntityNotFoundException",
+            GetUserDefinedFunctionsError::GlueEncryption(_) => "GlueEncryptionException",
+            GetUserDefinedFunctionsError::InternalService(_) => "InternalServiceException",
+            GetUserDefinedFunctionsError::InvalidInput(_) => "InvalidInputException",
+            GetUserDefinedFunctionsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetWorkflow
 #[derive(Debug, PartialEq)]
 pub enum GetWorkflowError {
@@ -13299,6 +14381,16 @@ impl fmt::Display for GetWorkflowError {
     }
 }
 impl Error for GetWorkflowError {}
+impl ServiceErrorCode for GetWorkflowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetWorkflowError::EntityNotFound(_) => "EntityNotFoundException",
+            GetWorkflowError::InternalService(_) => "InternalServiceException",
+            GetWorkflowError::InvalidInput(_) => "InvalidInputException",
+            GetWorkflowError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetWorkflowRun
 #[derive(Debug, PartialEq)]
 pub enum GetWorkflowRunError {
@@ -13347,6 +14439,16 @@ impl fmt::Display for GetWorkflowRunError {
     }
 }
 impl Error for GetWorkflowRunError {}
+impl ServiceErrorCode for GetWorkflowRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetWorkflowRunError::EntityNotFound(_) => "EntityNotFoundException",
+            GetWorkflowRunError::InternalService(_) => "InternalServiceException",
+            GetWorkflowRunError::InvalidInput(_) => "InvalidInputException",
+            GetWorkflowRunError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetWorkflowRunProperties
 #[derive(Debug, PartialEq)]
 pub enum GetWorkflowRunPropertiesError {
@@ -13403,6 +14505,16 @@ impl fmt::Display for GetWorkflowRunPropertiesError {
     }
 }
 impl Error for GetWorkflowRunPropertiesError {}
+impl ServiceErrorCode for GetWorkflowRunPropertiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetWorkflowRunPropertiesError::EntityNotFound(_) => "EntityNotFoundException",
+            GetWorkflowRunPropertiesError::InternalService(_) => "InternalServiceException",
+            GetWorkflowRunPropertiesError::InvalidInput(_) => "InvalidInputException",
+            GetWorkflowRunPropertiesError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetWorkflowRuns
 #[derive(Debug, PartialEq)]
 pub enum GetWorkflowRunsError {
@@ -13451,6 +14563,16 @@ impl fmt::Display for GetWorkflowRunsError {
     }
 }
 impl Error for GetWorkflowRunsError {}
+impl ServiceErrorCode for GetWorkflowRunsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetWorkflowRunsError::EntityNotFound(_) => "EntityNotFoundException",
+            GetWorkflowRunsError::InternalService(_) => "InternalServiceException",
+            GetWorkflowRunsError::InvalidInput(_) => "InvalidInputException",
+            GetWorkflowRunsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by ImportCatalogToGlue
 #[derive(Debug, PartialEq)]
 pub enum ImportCatalogToGlueError {
@@ -13489,6 +14611,14 @@ impl fmt::Display for ImportCatalogToGlueError {
     }
 }
 impl Error for ImportCatalogToGlueError {}
+impl ServiceErrorCode for ImportCatalogToGlueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportCatalogToGlueError::InternalService(_) => "InternalServiceException",
+            ImportCatalogToGlueError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by ListCrawlers
 #[derive(Debug, PartialEq)]
 pub enum ListCrawlersError {
@@ -13519,6 +14649,13 @@ impl fmt::Display for ListCrawlersError {
     }
 }
 impl Error for ListCrawlersError {}
+impl ServiceErrorCode for ListCrawlersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCrawlersError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by ListDevEndpoints
 #[derive(Debug, PartialEq)]
 pub enum ListDevEndpointsError {
@@ -13567,6 +14704,16 @@ impl fmt::Display for ListDevEndpointsError {
     }
 }
 impl Error for ListDevEndpointsError {}
+impl ServiceErrorCode for ListDevEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDevEndpointsError::EntityNotFound(_) => "EntityNotFoundException",
+            ListDevEndpointsError::InternalService(_) => "InternalServiceException",
+            ListDevEndpointsError::InvalidInput(_) => "InvalidInputException",
+            ListDevEndpointsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
 pub enum ListJobsError {
@@ -13615,6 +14762,16 @@ impl fmt::Display for ListJobsError {
     }
 }
 impl Error for ListJobsError {}
+impl ServiceErrorCode for ListJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJobsError::EntityNotFound(_) => "EntityNotFoundException",
+            ListJobsError::InternalService(_) => "InternalServiceException",
+            ListJobsError::InvalidInput(_) => "InvalidInputException",
+            ListJobsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by ListMLTransforms
 #[derive(Debug, PartialEq)]
 pub enum ListMLTransformsError {
@@ -13663,6 +14820,16 @@ impl fmt::Display for ListMLTransformsError {
     }
 }
 impl Error for ListMLTransformsError {}
+impl ServiceErrorCode for ListMLTransformsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMLTransformsError::EntityNotFound(_) => "EntityNotFoundException",
+            ListMLTransformsError::InternalService(_) => "InternalServiceException",
+            ListMLTransformsError::InvalidInput(_) => "InvalidInputException",
+            ListMLTransformsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by ListRegistries
 #[derive(Debug, PartialEq)]
 pub enum ListRegistriesError {
@@ -13705,6 +14872,15 @@ impl fmt::Display for ListRegistriesError {
     }
 }
 impl Error for ListRegistriesError {}
+impl ServiceErrorCode for ListRegistriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRegistriesError::AccessDenied(_) => "AccessDeniedException",
+            ListRegistriesError::InternalService(_) => "InternalServiceException",
+            ListRegistriesError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ListSchemaVersions
 #[derive(Debug, PartialEq)]
 pub enum ListSchemaVersionsError {
@@ -13753,6 +14929,16 @@ impl fmt::Display for ListSchemaVersionsError {
     }
 }
 impl Error for ListSchemaVersionsError {}
+impl ServiceErrorCode for ListSchemaVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSchemaVersionsError::AccessDenied(_) => "AccessDeniedException",
+            ListSchemaVersionsError::EntityNotFound(_) => "EntityNotFoundException",
+            ListSchemaVersionsError::InternalService(_) => "InternalServiceException",
+            ListSchemaVersionsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ListSchemas
 #[derive(Debug, PartialEq)]
 pub enum ListSchemasError {
@@ -13801,6 +14987,16 @@ impl fmt::Display for ListSchemasError {
     }
 }
 impl Error for ListSchemasError {}
+impl ServiceErrorCode for ListSchemasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSchemasError::AccessDenied(_) => "AccessDeniedException",
+            ListSchemasError::EntityNotFound(_) => "EntityNotFoundException",
+            ListSchemasError::InternalService(_) => "InternalServiceException",
+            ListSchemasError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ListTriggers
 #[derive(Debug, PartialEq)]
 pub enum ListTriggersError {
@@ -13849,6 +15045,16 @@ impl fmt::Display for ListTriggersError {
     }
 }
 impl Error for ListTriggersError {}
+impl ServiceErrorCode for ListTriggersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTriggersError::EntityNotFound(_) => "EntityNotFoundException",
+            ListTriggersError::InternalService(_) => "InternalServiceException",
+            ListTriggersError::InvalidInput(_) => "InvalidInputException",
+            ListTriggersError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by ListWorkflows
 #[derive(Debug, PartialEq)]
 pub enum ListWorkflowsError {
@@ -13891,6 +15097,15 @@ impl fmt::Display for ListWorkflowsError {
     }
 }
 impl Error for ListWorkflowsError {}
+impl ServiceErrorCode for ListWorkflowsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListWorkflowsError::InternalService(_) => "InternalServiceException",
+            ListWorkflowsError::InvalidInput(_) => "InvalidInputException",
+            ListWorkflowsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by PutDataCatalogEncryptionSettings
 #[derive(Debug, PartialEq)]
 pub enum PutDataCatalogEncryptionSettingsError {
@@ -13947,6 +15162,17 @@ impl fmt::Display for PutDataCatalogEncryptionSettingsError {
     }
 }
 impl Error for PutDataCatalogEncryptionSettingsError {}
+impl ServiceErrorCode for PutDataCatalogEncryptionSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutDataCatalogEncryptionSettingsError::InternalService(_) => "InternalServiceException",
+            PutDataCatalogEncryptionSettingsError::InvalidInput(_) => "InvalidInputException",
+            PutDataCatalogEncryptionSettingsError::OperationTimeout(_) => {
+                "OperationTimeoutException"
+            }
+        }
+    }
+}
 /// Errors returned by PutResourcePolicy
 #[derive(Debug, PartialEq)]
 pub enum PutResourcePolicyError {
@@ -14003,6 +15229,17 @@ impl fmt::Display for PutResourcePolicyError {
     }
 }
 impl Error for PutResourcePolicyError {}
+impl ServiceErrorCode for PutResourcePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutResourcePolicyError::ConditionCheckFailure(_) => "ConditionCheckFailureException",
+            PutResourcePolicyError::EntityNotFound(_) => "EntityNotFoundException",
+            PutResourcePolicyError::InternalService(_) => "InternalServiceException",
+            PutResourcePolicyError::InvalidInput(_) => "InvalidInputException",
+            PutResourcePolicyError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by PutSchemaVersionMetadata
 #[derive(Debug, PartialEq)]
 pub enum PutSchemaVersionMetadataError {
@@ -14069,6 +15306,19 @@ impl fmt::Display for PutSchemaVersionMetadataError {
     }
 }
 impl Error for PutSchemaVersionMetadataError {}
+impl ServiceErrorCode for PutSchemaVersionMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutSchemaVersionMetadataError::AccessDenied(_) => "AccessDeniedException",
+            PutSchemaVersionMetadataError::AlreadyExists(_) => "AlreadyExistsException",
+            PutSchemaVersionMetadataError::EntityNotFound(_) => "EntityNotFoundException",
+            PutSchemaVersionMetadataError::InvalidInput(_) => "InvalidInputException",
+            PutSchemaVersionMetadataError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by PutWorkflowRunProperties
 #[derive(Debug, PartialEq)]
 pub enum PutWorkflowRunPropertiesError {
@@ -14153,6 +15403,23 @@ impl fmt::Display for PutWorkflowRunPropertiesError {
     }
 }
 impl Error for PutWorkflowRunPropertiesError {}
+impl ServiceErrorCode for PutWorkflowRunPropertiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutWorkflowRunPropertiesError::AlreadyExists(_) => "AlreadyExistsException",
+            PutWorkflowRunPropertiesError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            PutWorkflowRunPropertiesError::EntityNotFound(_) => "EntityNotFoundException",
+            PutWorkflowRunPropertiesError::InternalService(_) => "InternalServiceException",
+            PutWorkflowRunPropertiesError::InvalidInput(_) => "InvalidInputException",
+            PutWorkflowRunPropertiesError::OperationTimeout(_) => "OperationTimeoutException",
+            PutWorkflowRunPropertiesError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by QuerySchemaVersionMetadata
 #[derive(Debug, PartialEq)]
 pub enum QuerySchemaVersionMetadataError {
@@ -14203,6 +15470,15 @@ impl fmt::Display for QuerySchemaVersionMetadataError {
     }
 }
 impl Error for QuerySchemaVersionMetadataError {}
+impl ServiceErrorCode for QuerySchemaVersionMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            QuerySchemaVersionMetadataError::AccessDenied(_) => "AccessDeniedException",
+            QuerySchemaVersionMetadataError::EntityNotFound(_) => "EntityNotFoundException",
+            QuerySchemaVersionMetadataError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by RegisterSchemaVersion
 #[derive(Debug, PartialEq)]
 pub enum RegisterSchemaVersionError {
@@ -14273,6 +15549,22 @@ impl fmt::Display for RegisterSchemaVersionError {
     }
 }
 impl Error for RegisterSchemaVersionError {}
+impl ServiceErrorCode for RegisterSchemaVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterSchemaVersionError::AccessDenied(_) => "AccessDeniedException",
+            RegisterSchemaVersionError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            RegisterSchemaVersionError::EntityNotFound(_) => "EntityNotFoundException",
+            RegisterSchemaVersionError::InternalService(_) => "InternalServiceException",
+            RegisterSchemaVersionError::InvalidInput(_) => "InvalidInputException",
+            RegisterSchemaVersionError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by RemoveSchemaVersionMetadata
 #[derive(Debug, PartialEq)]
 pub enum RemoveSchemaVersionMetadataError {
@@ -14323,6 +15615,15 @@ impl fmt::Display for RemoveSchemaVersionMetadataError {
     }
 }
 impl Error for RemoveSchemaVersionMetadataError {}
+impl ServiceErrorCode for RemoveSchemaVersionMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveSchemaVersionMetadataError::AccessDenied(_) => "AccessDeniedException",
+            RemoveSchemaVersionMetadataError::EntityNotFound(_) => "EntityNotFoundException",
+            RemoveSchemaVersionMetadataError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ResetJobBookmark
 #[derive(Debug, PartialEq)]
 pub enum ResetJobBookmarkError {
@@ -14371,6 +15672,16 @@ impl fmt::Display for ResetJobBookmarkError {
     }
 }
 impl Error for ResetJobBookmarkError {}
+impl ServiceErrorCode for ResetJobBookmarkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetJobBookmarkError::EntityNotFound(_) => "EntityNotFoundException",
+            ResetJobBookmarkError::InternalService(_) => "InternalServiceException",
+            ResetJobBookmarkError::InvalidInput(_) => "InvalidInputException",
+            ResetJobBookmarkError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by ResumeWorkflowRun
 #[derive(Debug, PartialEq)]
 pub enum ResumeWorkflowRunError {
@@ -14435,6 +15746,18 @@ impl fmt::Display for ResumeWorkflowRunError {
     }
 }
 impl Error for ResumeWorkflowRunError {}
+impl ServiceErrorCode for ResumeWorkflowRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResumeWorkflowRunError::ConcurrentRunsExceeded(_) => "ConcurrentRunsExceededException",
+            ResumeWorkflowRunError::EntityNotFound(_) => "EntityNotFoundException",
+            ResumeWorkflowRunError::IllegalWorkflowState(_) => "IllegalWorkflowStateException",
+            ResumeWorkflowRunError::InternalService(_) => "InternalServiceException",
+            ResumeWorkflowRunError::InvalidInput(_) => "InvalidInputException",
+            ResumeWorkflowRunError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by SearchTables
 #[derive(Debug, PartialEq)]
 pub enum SearchTablesError {
@@ -14477,6 +15800,15 @@ impl fmt::Display for SearchTablesError {
     }
 }
 impl Error for SearchTablesError {}
+impl ServiceErrorCode for SearchTablesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchTablesError::InternalService(_) => "InternalServiceException",
+            SearchTablesError::InvalidInput(_) => "InvalidInputException",
+            SearchTablesError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by StartCrawler
 #[derive(Debug, PartialEq)]
 pub enum StartCrawlerError {
@@ -14519,6 +15851,15 @@ impl fmt::Display for StartCrawlerError {
     }
 }
 impl Error for StartCrawlerError {}
+impl ServiceErrorCode for StartCrawlerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartCrawlerError::CrawlerRunning(_) => "CrawlerRunningException",
+            StartCrawlerError::EntityNotFound(_) => "EntityNotFoundException",
+            StartCrawlerError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by StartCrawlerSchedule
 #[derive(Debug, PartialEq)]
 pub enum StartCrawlerScheduleError {
@@ -14579,6 +15920,19 @@ impl fmt::Display for StartCrawlerScheduleError {
     }
 }
 impl Error for StartCrawlerScheduleError {}
+impl ServiceErrorCode for StartCrawlerScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartCrawlerScheduleError::EntityNotFound(_) => "EntityNotFoundException",
+            StartCrawlerScheduleError::NoSchedule(_) => "NoScheduleException",
+            StartCrawlerScheduleError::OperationTimeout(_) => "OperationTimeoutException",
+            StartCrawlerScheduleError::SchedulerRunning(_) => "SchedulerRunningException",
+            StartCrawlerScheduleError::SchedulerTransitioning(_) => {
+                "SchedulerTransitioningException"
+            }
+        }
+    }
+}
 /// Errors returned by StartExportLabelsTaskRun
 #[derive(Debug, PartialEq)]
 pub enum StartExportLabelsTaskRunError {
@@ -14635,6 +15989,16 @@ impl fmt::Display for StartExportLabelsTaskRunError {
     }
 }
 impl Error for StartExportLabelsTaskRunError {}
+impl ServiceErrorCode for StartExportLabelsTaskRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartExportLabelsTaskRunError::EntityNotFound(_) => "EntityNotFoundException",
+            StartExportLabelsTaskRunError::InternalService(_) => "InternalServiceException",
+            StartExportLabelsTaskRunError::InvalidInput(_) => "InvalidInputException",
+            StartExportLabelsTaskRunError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by StartImportLabelsTaskRun
 #[derive(Debug, PartialEq)]
 pub enum StartImportLabelsTaskRunError {
@@ -14701,6 +16065,19 @@ impl fmt::Display for StartImportLabelsTaskRunError {
     }
 }
 impl Error for StartImportLabelsTaskRunError {}
+impl ServiceErrorCode for StartImportLabelsTaskRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartImportLabelsTaskRunError::EntityNotFound(_) => "EntityNotFoundException",
+            StartImportLabelsTaskRunError::InternalService(_) => "InternalServiceException",
+            StartImportLabelsTaskRunError::InvalidInput(_) => "InvalidInputException",
+            StartImportLabelsTaskRunError::OperationTimeout(_) => "OperationTimeoutException",
+            StartImportLabelsTaskRunError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by StartJobRun
 #[derive(Debug, PartialEq)]
 pub enum StartJobRunError {
@@ -14763,6 +16140,20 @@ impl fmt::Display for StartJobRunError {
     }
 }
 impl Error for StartJobRunError {}
+impl ServiceErrorCode for StartJobRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartJobRunError::ConcurrentRunsExceeded(_) => "ConcurrentRunsExceededException",
+            StartJobRunError::EntityNotFound(_) => "EntityNotFoundException",
+            StartJobRunError::InternalService(_) => "InternalServiceException",
+            StartJobRunError::InvalidInput(_) => "InvalidInputException",
+            StartJobRunError::OperationTimeout(_) => "OperationTimeoutException",
+            StartJobRunError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by StartMLEvaluationTaskRun
 #[derive(Debug, PartialEq)]
 pub enum StartMLEvaluationTaskRunError {
@@ -14837,6 +16228,20 @@ impl fmt::Display for StartMLEvaluationTaskRunError {
     }
 }
 impl Error for StartMLEvaluationTaskRunError {}
+impl ServiceErrorCode for StartMLEvaluationTaskRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartMLEvaluationTaskRunError::ConcurrentRunsExceeded(_) => {
+                "ConcurrentRunsExceededException"
+            }
+            StartMLEvaluationTaskRunError::EntityNotFound(_) => "EntityNotFoundException",
+            StartMLEvaluationTaskRunError::InternalService(_) => "InternalServiceException",
+            StartMLEvaluationTaskRunError::InvalidInput(_) => "InvalidInputException",
+            StartMLEvaluationTaskRunError::MLTransformNotReady(_) => "MLTransformNotReadyException",
+            StartMLEvaluationTaskRunError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by StartMLLabelingSetGenerationTaskRun
 #[derive(Debug, PartialEq)]
 pub enum StartMLLabelingSetGenerationTaskRunError {
@@ -14913,6 +16318,25 @@ impl fmt::Display for StartMLLabelingSetGenerationTaskRunError {
     }
 }
 impl Error for StartMLLabelingSetGenerationTaskRunError {}
+impl ServiceErrorCode for StartMLLabelingSetGenerationTaskRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartMLLabelingSetGenerationTaskRunError::ConcurrentRunsExceeded(_) => {
+                "ConcurrentRunsExceededException"
+            }
+            StartMLLabelingSetGenerationTaskRunError::EntityNotFound(_) => {
+                "EntityNotFoundException"
+            }
+            StartMLLabelingSetGenerationTaskRunError::InternalService(_) => {
+                "InternalServiceException"
+            }
+            StartMLLabelingSetGenerationTaskRunError::InvalidInput(_) => "InvalidInputException",
+            StartMLLabelingSetGenerationTaskRunError::OperationTimeout(_) => {
+                "OperationTimeoutException"
+            }
+        }
+    }
+}
 /// Errors returned by StartTrigger
 #[derive(Debug, PartialEq)]
 pub enum StartTriggerError {
@@ -14975,6 +16399,20 @@ impl fmt::Display for StartTriggerError {
     }
 }
 impl Error for StartTriggerError {}
+impl ServiceErrorCode for StartTriggerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartTriggerError::ConcurrentRunsExceeded(_) => "ConcurrentRunsExceededException",
+            StartTriggerError::EntityNotFound(_) => "EntityNotFoundException",
+            StartTriggerError::InternalService(_) => "InternalServiceException",
+            StartTriggerError::InvalidInput(_) => "InvalidInputException",
+            StartTriggerError::OperationTimeout(_) => "OperationTimeoutException",
+            StartTriggerError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by StartWorkflowRun
 #[derive(Debug, PartialEq)]
 pub enum StartWorkflowRunError {
@@ -15039,6 +16477,20 @@ impl fmt::Display for StartWorkflowRunError {
     }
 }
 impl Error for StartWorkflowRunError {}
+impl ServiceErrorCode for StartWorkflowRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartWorkflowRunError::ConcurrentRunsExceeded(_) => "ConcurrentRunsExceededException",
+            StartWorkflowRunError::EntityNotFound(_) => "EntityNotFoundException",
+            StartWorkflowRunError::InternalService(_) => "InternalServiceException",
+            StartWorkflowRunError::InvalidInput(_) => "InvalidInputException",
+            StartWorkflowRunError::OperationTimeout(_) => "OperationTimeoutException",
+            StartWorkflowRunError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by StopCrawler
 #[derive(Debug, PartialEq)]
 pub enum StopCrawlerError {
@@ -15087,6 +16539,16 @@ impl fmt::Display for StopCrawlerError {
     }
 }
 impl Error for StopCrawlerError {}
+impl ServiceErrorCode for StopCrawlerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopCrawlerError::CrawlerNotRunning(_) => "CrawlerNotRunningException",
+            StopCrawlerError::CrawlerStopping(_) => "CrawlerStoppingException",
+            StopCrawlerError::EntityNotFound(_) => "EntityNotFoundException",
+            StopCrawlerError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by StopCrawlerSchedule
 #[derive(Debug, PartialEq)]
 pub enum StopCrawlerScheduleError {
@@ -15141,6 +16603,18 @@ impl fmt::Display for StopCrawlerScheduleError {
     }
 }
 impl Error for StopCrawlerScheduleError {}
+impl ServiceErrorCode for StopCrawlerScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopCrawlerScheduleError::EntityNotFound(_) => "EntityNotFoundException",
+            StopCrawlerScheduleError::OperationTimeout(_) => "OperationTimeoutException",
+            StopCrawlerScheduleError::SchedulerNotRunning(_) => "SchedulerNotRunningException",
+            StopCrawlerScheduleError::SchedulerTransitioning(_) => {
+                "SchedulerTransitioningException"
+            }
+        }
+    }
+}
 /// Errors returned by StopTrigger
 #[derive(Debug, PartialEq)]
 pub enum StopTriggerError {
@@ -15195,6 +16669,17 @@ impl fmt::Display for StopTriggerError {
     }
 }
 impl Error for StopTriggerError {}
+impl ServiceErrorCode for StopTriggerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopTriggerError::ConcurrentModification(_) => "ConcurrentModificationException",
+            StopTriggerError::EntityNotFound(_) => "EntityNotFoundException",
+            StopTriggerError::InternalService(_) => "InternalServiceException",
+            StopTriggerError::InvalidInput(_) => "InvalidInputException",
+            StopTriggerError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by StopWorkflowRun
 #[derive(Debug, PartialEq)]
 pub enum StopWorkflowRunError {
@@ -15251,6 +16736,17 @@ impl fmt::Display for StopWorkflowRunError {
     }
 }
 impl Error for StopWorkflowRunError {}
+impl ServiceErrorCode for StopWorkflowRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopWorkflowRunError::EntityNotFound(_) => "EntityNotFoundException",
+            StopWorkflowRunError::IllegalWorkflowState(_) => "IllegalWorkflowStateException",
+            StopWorkflowRunError::InternalService(_) => "InternalServiceException",
+            StopWorkflowRunError::InvalidInput(_) => "InvalidInputException",
+            StopWorkflowRunError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -15299,6 +16795,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::EntityNotFound(_) => "EntityNotFoundException",
+            TagResourceError::InternalService(_) => "InternalServiceException",
+            TagResourceError::InvalidInput(_) => "InvalidInputException",
+            TagResourceError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -15347,6 +16853,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::EntityNotFound(_) => "EntityNotFoundException",
+            UntagResourceError::InternalService(_) => "InternalServiceException",
+            UntagResourceError::InvalidInput(_) => "InvalidInputException",
+            UntagResourceError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by UpdateClassifier
 #[derive(Debug, PartialEq)]
 pub enum UpdateClassifierError {
@@ -15395,6 +16911,16 @@ impl fmt::Display for UpdateClassifierError {
     }
 }
 impl Error for UpdateClassifierError {}
+impl ServiceErrorCode for UpdateClassifierError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateClassifierError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateClassifierError::InvalidInput(_) => "InvalidInputException",
+            UpdateClassifierError::OperationTimeout(_) => "OperationTimeoutException",
+            UpdateClassifierError::VersionMismatch(_) => "VersionMismatchException",
+        }
+    }
+}
 /// Errors returned by UpdateColumnStatisticsForPartition
 #[derive(Debug, PartialEq)]
 pub enum UpdateColumnStatisticsForPartitionError {
@@ -15471,6 +16997,21 @@ impl fmt::Display for UpdateColumnStatisticsForPartitionError {
     }
 }
 impl Error for UpdateColumnStatisticsForPartitionError {}
+impl ServiceErrorCode for UpdateColumnStatisticsForPartitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateColumnStatisticsForPartitionError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateColumnStatisticsForPartitionError::GlueEncryption(_) => "GlueEncryptionException",
+            UpdateColumnStatisticsForPartitionError::InternalService(_) => {
+                "InternalServiceException"
+            }
+            UpdateColumnStatisticsForPartitionError::InvalidInput(_) => "InvalidInputException",
+            UpdateColumnStatisticsForPartitionError::OperationTimeout(_) => {
+                "OperationTimeoutException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateColumnStatisticsForTable
 #[derive(Debug, PartialEq)]
 pub enum UpdateColumnStatisticsForTableError {
@@ -15545,6 +17086,17 @@ impl fmt::Display for UpdateColumnStatisticsForTableError {
     }
 }
 impl Error for UpdateColumnStatisticsForTableError {}
+impl ServiceErrorCode for UpdateColumnStatisticsForTableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateColumnStatisticsForTableError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateColumnStatisticsForTableError::GlueEncryption(_) => "GlueEncryptionException",
+            UpdateColumnStatisticsForTableError::InternalService(_) => "InternalServiceException",
+            UpdateColumnStatisticsForTableError::InvalidInput(_) => "InvalidInputException",
+            UpdateColumnStatisticsForTableError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by UpdateConnection
 #[derive(Debug, PartialEq)]
 pub enum UpdateConnectionError {
@@ -15593,6 +17145,16 @@ impl fmt::Display for UpdateConnectionError {
     }
 }
 impl Error for UpdateConnectionError {}
+impl ServiceErrorCode for UpdateConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConnectionError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateConnectionError::GlueEncryption(_) => "GlueEncryptionException",
+            UpdateConnectionError::InvalidInput(_) => "InvalidInputException",
+            UpdateConnectionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by UpdateCrawler
 #[derive(Debug, PartialEq)]
 pub enum UpdateCrawlerError {
@@ -15647,6 +17209,17 @@ impl fmt::Display for UpdateCrawlerError {
     }
 }
 impl Error for UpdateCrawlerError {}
+impl ServiceErrorCode for UpdateCrawlerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCrawlerError::CrawlerRunning(_) => "CrawlerRunningException",
+            UpdateCrawlerError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateCrawlerError::InvalidInput(_) => "InvalidInputException",
+            UpdateCrawlerError::OperationTimeout(_) => "OperationTimeoutException",
+            UpdateCrawlerError::VersionMismatch(_) => "VersionMismatchException",
+        }
+    }
+}
 /// Errors returned by UpdateCrawlerSchedule
 #[derive(Debug, PartialEq)]
 pub enum UpdateCrawlerScheduleError {
@@ -15709,6 +17282,19 @@ impl fmt::Display for UpdateCrawlerScheduleError {
     }
 }
 impl Error for UpdateCrawlerScheduleError {}
+impl ServiceErrorCode for UpdateCrawlerScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCrawlerScheduleError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateCrawlerScheduleError::InvalidInput(_) => "InvalidInputException",
+            UpdateCrawlerScheduleError::OperationTimeout(_) => "OperationTimeoutException",
+            UpdateCrawlerScheduleError::SchedulerTransitioning(_) => {
+                "SchedulerTransitioningException"
+            }
+            UpdateCrawlerScheduleError::VersionMismatch(_) => "VersionMismatchException",
+        }
+    }
+}
 /// Errors returned by UpdateDatabase
 #[derive(Debug, PartialEq)]
 pub enum UpdateDatabaseError {
@@ -15763,6 +17349,17 @@ impl fmt::Display for UpdateDatabaseError {
     }
 }
 impl Error for UpdateDatabaseError {}
+impl ServiceErrorCode for UpdateDatabaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDatabaseError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateDatabaseError::GlueEncryption(_) => "GlueEncryptionException",
+            UpdateDatabaseError::InternalService(_) => "InternalServiceException",
+            UpdateDatabaseError::InvalidInput(_) => "InvalidInputException",
+            UpdateDatabaseError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by UpdateDevEndpoint
 #[derive(Debug, PartialEq)]
 pub enum UpdateDevEndpointError {
@@ -15811,6 +17408,16 @@ impl fmt::Display for UpdateDevEndpointError {
     }
 }
 impl Error for UpdateDevEndpointError {}
+impl ServiceErrorCode for UpdateDevEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDevEndpointError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateDevEndpointError::InternalService(_) => "InternalServiceException",
+            UpdateDevEndpointError::InvalidInput(_) => "InvalidInputException",
+            UpdateDevEndpointError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by UpdateJob
 #[derive(Debug, PartialEq)]
 pub enum UpdateJobError {
@@ -15865,6 +17472,17 @@ impl fmt::Display for UpdateJobError {
     }
 }
 impl Error for UpdateJobError {}
+impl ServiceErrorCode for UpdateJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateJobError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateJobError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateJobError::InternalService(_) => "InternalServiceException",
+            UpdateJobError::InvalidInput(_) => "InvalidInputException",
+            UpdateJobError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by UpdateMLTransform
 #[derive(Debug, PartialEq)]
 pub enum UpdateMLTransformError {
@@ -15919,6 +17537,17 @@ impl fmt::Display for UpdateMLTransformError {
     }
 }
 impl Error for UpdateMLTransformError {}
+impl ServiceErrorCode for UpdateMLTransformError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMLTransformError::AccessDenied(_) => "AccessDeniedException",
+            UpdateMLTransformError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateMLTransformError::InternalService(_) => "InternalServiceException",
+            UpdateMLTransformError::InvalidInput(_) => "InvalidInputException",
+            UpdateMLTransformError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by UpdatePartition
 #[derive(Debug, PartialEq)]
 pub enum UpdatePartitionError {
@@ -15973,6 +17602,17 @@ impl fmt::Display for UpdatePartitionError {
     }
 }
 impl Error for UpdatePartitionError {}
+impl ServiceErrorCode for UpdatePartitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePartitionError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdatePartitionError::GlueEncryption(_) => "GlueEncryptionException",
+            UpdatePartitionError::InternalService(_) => "InternalServiceException",
+            UpdatePartitionError::InvalidInput(_) => "InvalidInputException",
+            UpdatePartitionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by UpdateRegistry
 #[derive(Debug, PartialEq)]
 pub enum UpdateRegistryError {
@@ -16029,6 +17669,17 @@ impl fmt::Display for UpdateRegistryError {
     }
 }
 impl Error for UpdateRegistryError {}
+impl ServiceErrorCode for UpdateRegistryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRegistryError::AccessDenied(_) => "AccessDeniedException",
+            UpdateRegistryError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateRegistryError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateRegistryError::InternalService(_) => "InternalServiceException",
+            UpdateRegistryError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by UpdateSchema
 #[derive(Debug, PartialEq)]
 pub enum UpdateSchemaError {
@@ -16083,6 +17734,17 @@ impl fmt::Display for UpdateSchemaError {
     }
 }
 impl Error for UpdateSchemaError {}
+impl ServiceErrorCode for UpdateSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSchemaError::AccessDenied(_) => "AccessDeniedException",
+            UpdateSchemaError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateSchemaError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateSchemaError::InternalService(_) => "InternalServiceException",
+            UpdateSchemaError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by UpdateTable
 #[derive(Debug, PartialEq)]
 pub enum UpdateTableError {
@@ -16151,6 +17813,21 @@ impl fmt::Display for UpdateTableError {
     }
 }
 impl Error for UpdateTableError {}
+impl ServiceErrorCode for UpdateTableError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTableError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateTableError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateTableError::GlueEncryption(_) => "GlueEncryptionException",
+            UpdateTableError::InternalService(_) => "InternalServiceException",
+            UpdateTableError::InvalidInput(_) => "InvalidInputException",
+            UpdateTableError::OperationTimeout(_) => "OperationTimeoutException",
+            UpdateTableError::ResourceNumberLimitExceeded(_) => {
+                "ResourceNumberLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateTrigger
 #[derive(Debug, PartialEq)]
 pub enum UpdateTriggerError {
@@ -16207,6 +17884,17 @@ impl fmt::Display for UpdateTriggerError {
     }
 }
 impl Error for UpdateTriggerError {}
+impl ServiceErrorCode for UpdateTriggerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTriggerError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateTriggerError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateTriggerError::InternalService(_) => "InternalServiceException",
+            UpdateTriggerError::InvalidInput(_) => "InvalidInputException",
+            UpdateTriggerError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by UpdateUserDefinedFunction
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserDefinedFunctionError {
@@ -16271,6 +17959,17 @@ impl fmt::Display for UpdateUserDefinedFunctionError {
     }
 }
 impl Error for UpdateUserDefinedFunctionError {}
+impl ServiceErrorCode for UpdateUserDefinedFunctionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserDefinedFunctionError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateUserDefinedFunctionError::GlueEncryption(_) => "GlueEncryptionException",
+            UpdateUserDefinedFunctionError::InternalService(_) => "InternalServiceException",
+            UpdateUserDefinedFunctionError::InvalidInput(_) => "InvalidInputException",
+            UpdateUserDefinedFunctionError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by UpdateWorkflow
 #[derive(Debug, PartialEq)]
 pub enum UpdateWorkflowError {
@@ -16327,6 +18026,17 @@ impl fmt::Display for UpdateWorkflowError {
     }
 }
 impl Error for UpdateWorkflowError {}
+impl ServiceErrorCode for UpdateWorkflowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateWorkflowError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateWorkflowError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateWorkflowError::InternalService(_) => "InternalServiceException",
+            UpdateWorkflowError::InvalidInput(_) => "InvalidInputException",
+            UpdateWorkflowError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Glue API. AWS Glue clients implement this trait.
 #[async_trait]
 pub trait Glue {
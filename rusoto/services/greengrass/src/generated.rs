@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -3551,6 +3551,14 @@ impl fmt::Display for AssociateRoleToGroupError {
     }
 }
 impl Error for AssociateRoleToGroupError {}
+impl ServiceErrorCode for AssociateRoleToGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateRoleToGroupError::BadRequest(_) => "BadRequestException",
+            AssociateRoleToGroupError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by AssociateServiceRoleToAccount
 #[derive(Debug, PartialEq)]
 pub enum AssociateServiceRoleToAccountError {
@@ -3595,6 +3603,16 @@ impl fmt::Display for AssociateServiceRoleToAccountError {
     }
 }
 impl Error for AssociateServiceRoleToAccountError {}
+impl ServiceErrorCode for AssociateServiceRoleToAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateServiceRoleToAccountError::BadRequest(_) => "BadRequestException",
+            AssociateServiceRoleToAccountError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateConnectorDefinition
 #[derive(Debug, PartialEq)]
 pub enum CreateConnectorDefinitionError {
@@ -3627,6 +3645,13 @@ impl fmt::Display for CreateConnectorDefinitionError {
     }
 }
 impl Error for CreateConnectorDefinitionError {}
+impl ServiceErrorCode for CreateConnectorDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConnectorDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateConnectorDefinitionVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateConnectorDefinitionVersionError {
@@ -3661,6 +3686,13 @@ impl fmt::Display for CreateConnectorDefinitionVersionError {
     }
 }
 impl Error for CreateConnectorDefinitionVersionError {}
+impl ServiceErrorCode for CreateConnectorDefinitionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConnectorDefinitionVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateCoreDefinition
 #[derive(Debug, PartialEq)]
 pub enum CreateCoreDefinitionError {
@@ -3691,6 +3723,13 @@ impl fmt::Display for CreateCoreDefinitionError {
     }
 }
 impl Error for CreateCoreDefinitionError {}
+impl ServiceErrorCode for CreateCoreDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCoreDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateCoreDefinitionVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateCoreDefinitionVersionError {
@@ -3725,6 +3764,13 @@ impl fmt::Display for CreateCoreDefinitionVersionError {
     }
 }
 impl Error for CreateCoreDefinitionVersionError {}
+impl ServiceErrorCode for CreateCoreDefinitionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCoreDefinitionVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateDeployment
 #[derive(Debug, PartialEq)]
 pub enum CreateDeploymentError {
@@ -3755,6 +3801,13 @@ impl fmt::Display for CreateDeploymentError {
     }
 }
 impl Error for CreateDeploymentError {}
+impl ServiceErrorCode for CreateDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeploymentError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateDeviceDefinition
 #[derive(Debug, PartialEq)]
 pub enum CreateDeviceDefinitionError {
@@ -3785,6 +3838,13 @@ impl fmt::Display for CreateDeviceDefinitionError {
     }
 }
 impl Error for CreateDeviceDefinitionError {}
+impl ServiceErrorCode for CreateDeviceDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeviceDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateDeviceDefinitionVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateDeviceDefinitionVersionError {
@@ -3819,6 +3879,13 @@ impl fmt::Display for CreateDeviceDefinitionVersionError {
     }
 }
 impl Error for CreateDeviceDefinitionVersionError {}
+impl ServiceErrorCode for CreateDeviceDefinitionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeviceDefinitionVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateFunctionDefinition
 #[derive(Debug, PartialEq)]
 pub enum CreateFunctionDefinitionError {
@@ -3849,6 +3916,13 @@ impl fmt::Display for CreateFunctionDefinitionError {
     }
 }
 impl Error for CreateFunctionDefinitionError {}
+impl ServiceErrorCode for CreateFunctionDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFunctionDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateFunctionDefinitionVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateFunctionDefinitionVersionError {
@@ -3883,6 +3957,13 @@ impl fmt::Display for CreateFunctionDefinitionVersionError {
     }
 }
 impl Error for CreateFunctionDefinitionVersionError {}
+impl ServiceErrorCode for CreateFunctionDefinitionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFunctionDefinitionVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateGroupError {
@@ -3913,6 +3994,13 @@ impl fmt::Display for CreateGroupError {
     }
 }
 impl Error for CreateGroupError {}
+impl ServiceErrorCode for CreateGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGroupError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateGroupCertificateAuthority
 #[derive(Debug, PartialEq)]
 pub enum CreateGroupCertificateAuthorityError {
@@ -3957,6 +4045,16 @@ impl fmt::Display for CreateGroupCertificateAuthorityError {
     }
 }
 impl Error for CreateGroupCertificateAuthorityError {}
+impl ServiceErrorCode for CreateGroupCertificateAuthorityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGroupCertificateAuthorityError::BadRequest(_) => "BadRequestException",
+            CreateGroupCertificateAuthorityError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateGroupVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateGroupVersionError {
@@ -3987,6 +4085,13 @@ impl fmt::Display for CreateGroupVersionError {
     }
 }
 impl Error for CreateGroupVersionError {}
+impl ServiceErrorCode for CreateGroupVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGroupVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateLoggerDefinition
 #[derive(Debug, PartialEq)]
 pub enum CreateLoggerDefinitionError {
@@ -4017,6 +4122,13 @@ impl fmt::Display for CreateLoggerDefinitionError {
     }
 }
 impl Error for CreateLoggerDefinitionError {}
+impl ServiceErrorCode for CreateLoggerDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLoggerDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateLoggerDefinitionVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateLoggerDefinitionVersionError {
@@ -4051,6 +4163,13 @@ impl fmt::Display for CreateLoggerDefinitionVersionError {
     }
 }
 impl Error for CreateLoggerDefinitionVersionError {}
+impl ServiceErrorCode for CreateLoggerDefinitionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLoggerDefinitionVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateResourceDefinition
 #[derive(Debug, PartialEq)]
 pub enum CreateResourceDefinitionError {
@@ -4081,6 +4200,13 @@ impl fmt::Display for CreateResourceDefinitionError {
     }
 }
 impl Error for CreateResourceDefinitionError {}
+impl ServiceErrorCode for CreateResourceDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateResourceDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateResourceDefinitionVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateResourceDefinitionVersionError {
@@ -4115,6 +4241,13 @@ impl fmt::Display for CreateResourceDefinitionVersionError {
     }
 }
 impl Error for CreateResourceDefinitionVersionError {}
+impl ServiceErrorCode for CreateResourceDefinitionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateResourceDefinitionVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateSoftwareUpdateJob
 #[derive(Debug, PartialEq)]
 pub enum CreateSoftwareUpdateJobError {
@@ -4153,6 +4286,14 @@ impl fmt::Display for CreateSoftwareUpdateJobError {
     }
 }
 impl Error for CreateSoftwareUpdateJobError {}
+impl ServiceErrorCode for CreateSoftwareUpdateJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSoftwareUpdateJobError::BadRequest(_) => "BadRequestException",
+            CreateSoftwareUpdateJobError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by CreateSubscriptionDefinition
 #[derive(Debug, PartialEq)]
 pub enum CreateSubscriptionDefinitionError {
@@ -4187,6 +4328,13 @@ impl fmt::Display for CreateSubscriptionDefinitionError {
     }
 }
 impl Error for CreateSubscriptionDefinitionError {}
+impl ServiceErrorCode for CreateSubscriptionDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSubscriptionDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by CreateSubscriptionDefinitionVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateSubscriptionDefinitionVersionError {
@@ -4223,6 +4371,13 @@ impl fmt::Display for CreateSubscriptionDefinitionVersionError {
     }
 }
 impl Error for CreateSubscriptionDefinitionVersionError {}
+impl ServiceErrorCode for CreateSubscriptionDefinitionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSubscriptionDefinitionVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteConnectorDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteConnectorDefinitionError {
@@ -4255,6 +4410,13 @@ impl fmt::Display for DeleteConnectorDefinitionError {
     }
 }
 impl Error for DeleteConnectorDefinitionError {}
+impl ServiceErrorCode for DeleteConnectorDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConnectorDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteCoreDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteCoreDefinitionError {
@@ -4285,6 +4447,13 @@ impl fmt::Display for DeleteCoreDefinitionError {
     }
 }
 impl Error for DeleteCoreDefinitionError {}
+impl ServiceErrorCode for DeleteCoreDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCoreDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteDeviceDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteDeviceDefinitionError {
@@ -4315,6 +4484,13 @@ impl fmt::Display for DeleteDeviceDefinitionError {
     }
 }
 impl Error for DeleteDeviceDefinitionError {}
+impl ServiceErrorCode for DeleteDeviceDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDeviceDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteFunctionDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteFunctionDefinitionError {
@@ -4345,6 +4521,13 @@ impl fmt::Display for DeleteFunctionDefinitionError {
     }
 }
 impl Error for DeleteFunctionDefinitionError {}
+impl ServiceErrorCode for DeleteFunctionDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFunctionDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteGroupError {
@@ -4375,6 +4558,13 @@ impl fmt::Display for DeleteGroupError {
     }
 }
 impl Error for DeleteGroupError {}
+impl ServiceErrorCode for DeleteGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGroupError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteLoggerDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteLoggerDefinitionError {
@@ -4405,6 +4595,13 @@ impl fmt::Display for DeleteLoggerDefinitionError {
     }
 }
 impl Error for DeleteLoggerDefinitionError {}
+impl ServiceErrorCode for DeleteLoggerDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLoggerDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteResourceDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteResourceDefinitionError {
@@ -4435,6 +4632,13 @@ impl fmt::Display for DeleteResourceDefinitionError {
     }
 }
 impl Error for DeleteResourceDefinitionError {}
+impl ServiceErrorCode for DeleteResourceDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResourceDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteSubscriptionDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteSubscriptionDefinitionError {
@@ -4469,6 +4673,13 @@ impl fmt::Display for DeleteSubscriptionDefinitionError {
     }
 }
 impl Error for DeleteSubscriptionDefinitionError {}
+impl ServiceErrorCode for DeleteSubscriptionDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSubscriptionDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by DisassociateRoleFromGroup
 #[derive(Debug, PartialEq)]
 pub enum DisassociateRoleFromGroupError {
@@ -4511,6 +4722,16 @@ impl fmt::Display for DisassociateRoleFromGroupError {
     }
 }
 impl Error for DisassociateRoleFromGroupError {}
+impl ServiceErrorCode for DisassociateRoleFromGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateRoleFromGroupError::BadRequest(_) => "BadRequestException",
+            DisassociateRoleFromGroupError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateServiceRoleFromAccount
 #[derive(Debug, PartialEq)]
 pub enum DisassociateServiceRoleFromAccountError {
@@ -4547,6 +4768,15 @@ impl fmt::Display for DisassociateServiceRoleFromAccountError {
     }
 }
 impl Error for DisassociateServiceRoleFromAccountError {}
+impl ServiceErrorCode for DisassociateServiceRoleFromAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateServiceRoleFromAccountError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAssociatedRole
 #[derive(Debug, PartialEq)]
 pub enum GetAssociatedRoleError {
@@ -4585,6 +4815,14 @@ impl fmt::Display for GetAssociatedRoleError {
     }
 }
 impl Error for GetAssociatedRoleError {}
+impl ServiceErrorCode for GetAssociatedRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAssociatedRoleError::BadRequest(_) => "BadRequestException",
+            GetAssociatedRoleError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by GetBulkDeploymentStatus
 #[derive(Debug, PartialEq)]
 pub enum GetBulkDeploymentStatusError {
@@ -4615,6 +4853,13 @@ impl fmt::Display for GetBulkDeploymentStatusError {
     }
 }
 impl Error for GetBulkDeploymentStatusError {}
+impl ServiceErrorCode for GetBulkDeploymentStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBulkDeploymentStatusError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetConnectivityInfo
 #[derive(Debug, PartialEq)]
 pub enum GetConnectivityInfoError {
@@ -4653,6 +4898,14 @@ impl fmt::Display for GetConnectivityInfoError {
     }
 }
 impl Error for GetConnectivityInfoError {}
+impl ServiceErrorCode for GetConnectivityInfoError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConnectivityInfoError::BadRequest(_) => "BadRequestException",
+            GetConnectivityInfoError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by GetConnectorDefinition
 #[derive(Debug, PartialEq)]
 pub enum GetConnectorDefinitionError {
@@ -4683,6 +4936,13 @@ impl fmt::Display for GetConnectorDefinitionError {
     }
 }
 impl Error for GetConnectorDefinitionError {}
+impl ServiceErrorCode for GetConnectorDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConnectorDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetConnectorDefinitionVersion
 #[derive(Debug, PartialEq)]
 pub enum GetConnectorDefinitionVersionError {
@@ -4717,6 +4977,13 @@ impl fmt::Display for GetConnectorDefinitionVersionError {
     }
 }
 impl Error for GetConnectorDefinitionVersionError {}
+impl ServiceErrorCode for GetConnectorDefinitionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConnectorDefinitionVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetCoreDefinition
 #[derive(Debug, PartialEq)]
 pub enum GetCoreDefinitionError {
@@ -4747,6 +5014,13 @@ impl fmt::Display for GetCoreDefinitionError {
     }
 }
 impl Error for GetCoreDefinitionError {}
+impl ServiceErrorCode for GetCoreDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCoreDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetCoreDefinitionVersion
 #[derive(Debug, PartialEq)]
 pub enum GetCoreDefinitionVersionError {
@@ -4777,6 +5051,13 @@ impl fmt::Display for GetCoreDefinitionVersionError {
     }
 }
 impl Error for GetCoreDefinitionVersionError {}
+impl ServiceErrorCode for GetCoreDefinitionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCoreDefinitionVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetDeploymentStatus
 #[derive(Debug, PartialEq)]
 pub enum GetDeploymentStatusError {
@@ -4807,6 +5088,13 @@ impl fmt::Display for GetDeploymentStatusError {
     }
 }
 impl Error for GetDeploymentStatusError {}
+impl ServiceErrorCode for GetDeploymentStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeploymentStatusError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetDeviceDefinition
 #[derive(Debug, PartialEq)]
 pub enum GetDeviceDefinitionError {
@@ -4837,6 +5125,13 @@ impl fmt::Display for GetDeviceDefinitionError {
     }
 }
 impl Error for GetDeviceDefinitionError {}
+impl ServiceErrorCode for GetDeviceDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeviceDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetDeviceDefinitionVersion
 #[derive(Debug, PartialEq)]
 pub enum GetDeviceDefinitionVersionError {
@@ -4871,6 +5166,13 @@ impl fmt::Display for GetDeviceDefinitionVersionError {
     }
 }
 impl Error for GetDeviceDefinitionVersionError {}
+impl ServiceErrorCode for GetDeviceDefinitionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeviceDefinitionVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetFunctionDefinition
 #[derive(Debug, PartialEq)]
 pub enum GetFunctionDefinitionError {
@@ -4901,6 +5203,13 @@ impl fmt::Display for GetFunctionDefinitionError {
     }
 }
 impl Error for GetFunctionDefinitionError {}
+impl ServiceErrorCode for GetFunctionDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFunctionDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetFunctionDefinitionVersion
 #[derive(Debug, PartialEq)]
 pub enum GetFunctionDefinitionVersionError {
@@ -4935,6 +5244,13 @@ impl fmt::Display for GetFunctionDefinitionVersionError {
     }
 }
 impl Error for GetFunctionDefinitionVersionError {}
+impl ServiceErrorCode for GetFunctionDefinitionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFunctionDefinitionVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetGroup
 #[derive(Debug, PartialEq)]
 pub enum GetGroupError {
@@ -4965,6 +5281,13 @@ impl fmt::Display for GetGroupError {
     }
 }
 impl Error for GetGroupError {}
+impl ServiceErrorCode for GetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGroupError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetGroupCertificateAuthority
 #[derive(Debug, PartialEq)]
 pub enum GetGroupCertificateAuthorityError {
@@ -5009,6 +5332,16 @@ impl fmt::Display for GetGroupCertificateAuthorityError {
     }
 }
 impl Error for GetGroupCertificateAuthorityError {}
+impl ServiceErrorCode for GetGroupCertificateAuthorityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGroupCertificateAuthorityError::BadRequest(_) => "BadRequestException",
+            GetGroupCertificateAuthorityError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by GetGroupCertificateConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetGroupCertificateConfigurationError {
@@ -5053,6 +5386,16 @@ impl fmt::Display for GetGroupCertificateConfigurationError {
     }
 }
 impl Error for GetGroupCertificateConfigurationError {}
+impl ServiceErrorCode for GetGroupCertificateConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGroupCertificateConfigurationError::BadRequest(_) => "BadRequestException",
+            GetGroupCertificateConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by GetGroupVersion
 #[derive(Debug, PartialEq)]
 pub enum GetGroupVersionError {
@@ -5083,6 +5426,13 @@ impl fmt::Display for GetGroupVersionError {
     }
 }
 impl Error for GetGroupVersionError {}
+impl ServiceErrorCode for GetGroupVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGroupVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetLoggerDefinition
 #[derive(Debug, PartialEq)]
 pub enum GetLoggerDefinitionError {
@@ -5113,6 +5463,13 @@ impl fmt::Display for GetLoggerDefinitionError {
     }
 }
 impl Error for GetLoggerDefinitionError {}
+impl ServiceErrorCode for GetLoggerDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLoggerDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetLoggerDefinitionVersion
 #[derive(Debug, PartialEq)]
 pub enum GetLoggerDefinitionVersionError {
@@ -5147,6 +5504,13 @@ impl fmt::Display for GetLoggerDefinitionVersionError {
     }
 }
 impl Error for GetLoggerDefinitionVersionError {}
+impl ServiceErrorCode for GetLoggerDefinitionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLoggerDefinitionVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetResourceDefinition
 #[derive(Debug, PartialEq)]
 pub enum GetResourceDefinitionError {
@@ -5177,6 +5541,13 @@ impl fmt::Display for GetResourceDefinitionError {
     }
 }
 impl Error for GetResourceDefinitionError {}
+impl ServiceErrorCode for GetResourceDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourceDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetResourceDefinitionVersion
 #[derive(Debug, PartialEq)]
 pub enum GetResourceDefinitionVersionError {
@@ -5211,6 +5582,13 @@ impl fmt::Display for GetResourceDefinitionVersionError {
     }
 }
 impl Error for GetResourceDefinitionVersionError {}
+impl ServiceErrorCode for GetResourceDefinitionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourceDefinitionVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetServiceRoleForAccount
 #[derive(Debug, PartialEq)]
 pub enum GetServiceRoleForAccountError {
@@ -5243,6 +5621,13 @@ impl fmt::Display for GetServiceRoleForAccountError {
     }
 }
 impl Error for GetServiceRoleForAccountError {}
+impl ServiceErrorCode for GetServiceRoleForAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetServiceRoleForAccountError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by GetSubscriptionDefinition
 #[derive(Debug, PartialEq)]
 pub enum GetSubscriptionDefinitionError {
@@ -5275,6 +5660,13 @@ impl fmt::Display for GetSubscriptionDefinitionError {
     }
 }
 impl Error for GetSubscriptionDefinitionError {}
+impl ServiceErrorCode for GetSubscriptionDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSubscriptionDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetSubscriptionDefinitionVersion
 #[derive(Debug, PartialEq)]
 pub enum GetSubscriptionDefinitionVersionError {
@@ -5309,6 +5701,13 @@ impl fmt::Display for GetSubscriptionDefinitionVersionError {
     }
 }
 impl Error for GetSubscriptionDefinitionVersionError {}
+impl ServiceErrorCode for GetSubscriptionDefinitionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSubscriptionDefinitionVersionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by GetThingRuntimeConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetThingRuntimeConfigurationError {
@@ -5353,6 +5752,16 @@ impl fmt::Display for GetThingRuntimeConfigurationError {
     }
 }
 impl Error for GetThingRuntimeConfigurationError {}
+impl ServiceErrorCode for GetThingRuntimeConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetThingRuntimeConfigurationError::BadRequest(_) => "BadRequestException",
+            GetThingRuntimeConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by ListBulkDeploymentDetailedReports
 #[derive(Debug, PartialEq)]
 pub enum ListBulkDeploymentDetailedReportsError {
@@ -5387,6 +5796,13 @@ impl fmt::Display for ListBulkDeploymentDetailedReportsError {
     }
 }
 impl Error for ListBulkDeploymentDetailedReportsError {}
+impl ServiceErrorCode for ListBulkDeploymentDetailedReportsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBulkDeploymentDetailedReportsError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by ListBulkDeployments
 #[derive(Debug, PartialEq)]
 pub enum ListBulkDeploymentsError {
@@ -5417,6 +5833,13 @@ impl fmt::Display for ListBulkDeploymentsError {
     }
 }
 impl Error for ListBulkDeploymentsError {}
+impl ServiceErrorCode for ListBulkDeploymentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBulkDeploymentsError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by ListConnectorDefinitionVersions
 #[derive(Debug, PartialEq)]
 pub enum ListConnectorDefinitionVersionsError {
@@ -5451,6 +5874,13 @@ impl fmt::Display for ListConnectorDefinitionVersionsError {
     }
 }
 impl Error for ListConnectorDefinitionVersionsError {}
+impl ServiceErrorCode for ListConnectorDefinitionVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListConnectorDefinitionVersionsError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by ListConnectorDefinitions
 #[derive(Debug, PartialEq)]
 pub enum ListConnectorDefinitionsError {}
@@ -5473,6 +5903,11 @@ impl fmt::Display for ListConnectorDefinitionsError {
     }
 }
 impl Error for ListConnectorDefinitionsError {}
+impl ServiceErrorCode for ListConnectorDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListCoreDefinitionVersions
 #[derive(Debug, PartialEq)]
 pub enum ListCoreDefinitionVersionsError {
@@ -5507,6 +5942,13 @@ impl fmt::Display for ListCoreDefinitionVersionsError {
     }
 }
 impl Error for ListCoreDefinitionVersionsError {}
+impl ServiceErrorCode for ListCoreDefinitionVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCoreDefinitionVersionsError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by ListCoreDefinitions
 #[derive(Debug, PartialEq)]
 pub enum ListCoreDefinitionsError {}
@@ -5529,6 +5971,11 @@ impl fmt::Display for ListCoreDefinitionsError {
     }
 }
 impl Error for ListCoreDefinitionsError {}
+impl ServiceErrorCode for ListCoreDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListDeployments
 #[derive(Debug, PartialEq)]
 pub enum ListDeploymentsError {
@@ -5559,6 +6006,13 @@ impl fmt::Display for ListDeploymentsError {
     }
 }
 impl Error for ListDeploymentsError {}
+impl ServiceErrorCode for ListDeploymentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeploymentsError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by ListDeviceDefinitionVersions
 #[derive(Debug, PartialEq)]
 pub enum ListDeviceDefinitionVersionsError {
@@ -5593,6 +6047,13 @@ impl fmt::Display for ListDeviceDefinitionVersionsError {
     }
 }
 impl Error for ListDeviceDefinitionVersionsError {}
+impl ServiceErrorCode for ListDeviceDefinitionVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeviceDefinitionVersionsError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by ListDeviceDefinitions
 #[derive(Debug, PartialEq)]
 pub enum ListDeviceDefinitionsError {}
@@ -5615,6 +6076,11 @@ impl fmt::Display for ListDeviceDefinitionsError {
     }
 }
 impl Error for ListDeviceDefinitionsError {}
+impl ServiceErrorCode for ListDeviceDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListFunctionDefinitionVersions
 #[derive(Debug, PartialEq)]
 pub enum ListFunctionDefinitionVersionsError {
@@ -5649,6 +6115,13 @@ impl fmt::Display for ListFunctionDefinitionVersionsError {
     }
 }
 impl Error for ListFunctionDefinitionVersionsError {}
+impl ServiceErrorCode for ListFunctionDefinitionVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFunctionDefinitionVersionsError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by ListFunctionDefinitions
 #[derive(Debug, PartialEq)]
 pub enum ListFunctionDefinitionsError {}
@@ -5671,6 +6144,11 @@ impl fmt::Display for ListFunctionDefinitionsError {
     }
 }
 impl Error for ListFunctionDefinitionsError {}
+impl ServiceErrorCode for ListFunctionDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListGroupCertificateAuthorities
 #[derive(Debug, PartialEq)]
 pub enum ListGroupCertificateAuthoritiesError {
@@ -5715,6 +6193,16 @@ impl fmt::Display for ListGroupCertificateAuthoritiesError {
     }
 }
 impl Error for ListGroupCertificateAuthoritiesError {}
+impl ServiceErrorCode for ListGroupCertificateAuthoritiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGroupCertificateAuthoritiesError::BadRequest(_) => "BadRequestException",
+            ListGroupCertificateAuthoritiesError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by ListGroupVersions
 #[derive(Debug, PartialEq)]
 pub enum ListGroupVersionsError {
@@ -5745,6 +6233,13 @@ impl fmt::Display for ListGroupVersionsError {
     }
 }
 impl Error for ListGroupVersionsError {}
+impl ServiceErrorCode for ListGroupVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGroupVersionsError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by ListGroups
 #[derive(Debug, PartialEq)]
 pub enum ListGroupsError {}
@@ -5767,6 +6262,11 @@ impl fmt::Display for ListGroupsError {
     }
 }
 impl Error for ListGroupsError {}
+impl ServiceErrorCode for ListGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListLoggerDefinitionVersions
 #[derive(Debug, PartialEq)]
 pub enum ListLoggerDefinitionVersionsError {
@@ -5801,6 +6301,13 @@ impl fmt::Display for ListLoggerDefinitionVersionsError {
     }
 }
 impl Error for ListLoggerDefinitionVersionsError {}
+impl ServiceErrorCode for ListLoggerDefinitionVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLoggerDefinitionVersionsError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by ListLoggerDefinitions
 #[derive(Debug, PartialEq)]
 pub enum ListLoggerDefinitionsError {}
@@ -5823,6 +6330,11 @@ impl fmt::Display for ListLoggerDefinitionsError {
     }
 }
 impl Error for ListLoggerDefinitionsError {}
+impl ServiceErrorCode for ListLoggerDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListResourceDefinitionVersions
 #[derive(Debug, PartialEq)]
 pub enum ListResourceDefinitionVersionsError {
@@ -5857,6 +6369,13 @@ impl fmt::Display for ListResourceDefinitionVersionsError {
     }
 }
 impl Error for ListResourceDefinitionVersionsError {}
+impl ServiceErrorCode for ListResourceDefinitionVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourceDefinitionVersionsError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by ListResourceDefinitions
 #[derive(Debug, PartialEq)]
 pub enum ListResourceDefinitionsError {}
@@ -5879,6 +6398,11 @@ impl fmt::Display for ListResourceDefinitionsError {
     }
 }
 impl Error for ListResourceDefinitionsError {}
+impl ServiceErrorCode for ListResourceDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListSubscriptionDefinitionVersions
 #[derive(Debug, PartialEq)]
 pub enum ListSubscriptionDefinitionVersionsError {
@@ -5915,6 +6439,13 @@ impl fmt::Display for ListSubscriptionDefinitionVersionsError {
     }
 }
 impl Error for ListSubscriptionDefinitionVersionsError {}
+impl ServiceErrorCode for ListSubscriptionDefinitionVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSubscriptionDefinitionVersionsError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by ListSubscriptionDefinitions
 #[derive(Debug, PartialEq)]
 pub enum ListSubscriptionDefinitionsError {}
@@ -5939,6 +6470,11 @@ impl fmt::Display for ListSubscriptionDefinitionsError {
     }
 }
 impl Error for ListSubscriptionDefinitionsError {}
+impl ServiceErrorCode for ListSubscriptionDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -5969,6 +6505,13 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by ResetDeployments
 #[derive(Debug, PartialEq)]
 pub enum ResetDeploymentsError {
@@ -5999,6 +6542,13 @@ impl fmt::Display for ResetDeploymentsError {
     }
 }
 impl Error for ResetDeploymentsError {}
+impl ServiceErrorCode for ResetDeploymentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetDeploymentsError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by StartBulkDeployment
 #[derive(Debug, PartialEq)]
 pub enum StartBulkDeploymentError {
@@ -6029,6 +6579,13 @@ impl fmt::Display for StartBulkDeploymentError {
     }
 }
 impl Error for StartBulkDeploymentError {}
+impl ServiceErrorCode for StartBulkDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartBulkDeploymentError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by StopBulkDeployment
 #[derive(Debug, PartialEq)]
 pub enum StopBulkDeploymentError {
@@ -6059,6 +6616,13 @@ impl fmt::Display for StopBulkDeploymentError {
     }
 }
 impl Error for StopBulkDeploymentError {}
+impl ServiceErrorCode for StopBulkDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopBulkDeploymentError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -6089,6 +6653,13 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -6119,6 +6690,13 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateConnectivityInfo
 #[derive(Debug, PartialEq)]
 pub enum UpdateConnectivityInfoError {
@@ -6157,6 +6735,14 @@ impl fmt::Display for UpdateConnectivityInfoError {
     }
 }
 impl Error for UpdateConnectivityInfoError {}
+impl ServiceErrorCode for UpdateConnectivityInfoError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConnectivityInfoError::BadRequest(_) => "BadRequestException",
+            UpdateConnectivityInfoError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by UpdateConnectorDefinition
 #[derive(Debug, PartialEq)]
 pub enum UpdateConnectorDefinitionError {
@@ -6189,6 +6775,13 @@ impl fmt::Display for UpdateConnectorDefinitionError {
     }
 }
 impl Error for UpdateConnectorDefinitionError {}
+impl ServiceErrorCode for UpdateConnectorDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConnectorDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateCoreDefinition
 #[derive(Debug, PartialEq)]
 pub enum UpdateCoreDefinitionError {
@@ -6219,6 +6812,13 @@ impl fmt::Display for UpdateCoreDefinitionError {
     }
 }
 impl Error for UpdateCoreDefinitionError {}
+impl ServiceErrorCode for UpdateCoreDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCoreDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateDeviceDefinition
 #[derive(Debug, PartialEq)]
 pub enum UpdateDeviceDefinitionError {
@@ -6249,6 +6849,13 @@ impl fmt::Display for UpdateDeviceDefinitionError {
     }
 }
 impl Error for UpdateDeviceDefinitionError {}
+impl ServiceErrorCode for UpdateDeviceDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDeviceDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateFunctionDefinition
 #[derive(Debug, PartialEq)]
 pub enum UpdateFunctionDefinitionError {
@@ -6279,6 +6886,13 @@ impl fmt::Display for UpdateFunctionDefinitionError {
     }
 }
 impl Error for UpdateFunctionDefinitionError {}
+impl ServiceErrorCode for UpdateFunctionDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFunctionDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateGroupError {
@@ -6309,6 +6923,13 @@ impl fmt::Display for UpdateGroupError {
     }
 }
 impl Error for UpdateGroupError {}
+impl ServiceErrorCode for UpdateGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGroupError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateGroupCertificateConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateGroupCertificateConfigurationError {
@@ -6355,6 +6976,16 @@ impl fmt::Display for UpdateGroupCertificateConfigurationError {
     }
 }
 impl Error for UpdateGroupCertificateConfigurationError {}
+impl ServiceErrorCode for UpdateGroupCertificateConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGroupCertificateConfigurationError::BadRequest(_) => "BadRequestException",
+            UpdateGroupCertificateConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateLoggerDefinition
 #[derive(Debug, PartialEq)]
 pub enum UpdateLoggerDefinitionError {
@@ -6385,6 +7016,13 @@ impl fmt::Display for UpdateLoggerDefinitionError {
     }
 }
 impl Error for UpdateLoggerDefinitionError {}
+impl ServiceErrorCode for UpdateLoggerDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateLoggerDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateResourceDefinition
 #[derive(Debug, PartialEq)]
 pub enum UpdateResourceDefinitionError {
@@ -6415,6 +7053,13 @@ impl fmt::Display for UpdateResourceDefinitionError {
     }
 }
 impl Error for UpdateResourceDefinitionError {}
+impl ServiceErrorCode for UpdateResourceDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateResourceDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateSubscriptionDefinition
 #[derive(Debug, PartialEq)]
 pub enum UpdateSubscriptionDefinitionError {
@@ -6449,6 +7094,13 @@ impl fmt::Display for UpdateSubscriptionDefinitionError {
     }
 }
 impl Error for UpdateSubscriptionDefinitionError {}
+impl ServiceErrorCode for UpdateSubscriptionDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSubscriptionDefinitionError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateThingRuntimeConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateThingRuntimeConfigurationError {
@@ -6493,6 +7145,16 @@ impl fmt::Display for UpdateThingRuntimeConfigurationError {
     }
 }
 impl Error for UpdateThingRuntimeConfigurationError {}
+impl ServiceErrorCode for UpdateThingRuntimeConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateThingRuntimeConfigurationError::BadRequest(_) => "BadRequestException",
+            UpdateThingRuntimeConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Greengrass API. AWS Greengrass clients implement this trait.
 #[async_trait]
 pub trait GreenGrass {
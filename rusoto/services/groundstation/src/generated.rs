@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1282,6 +1282,15 @@ impl fmt::Display for CancelContactError {
     }
 }
 impl Error for CancelContactError {}
+impl ServiceErrorCode for CancelContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelContactError::Dependency(_) => "DependencyException",
+            CancelContactError::InvalidParameter(_) => "InvalidParameterException",
+            CancelContactError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateConfig
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigError {
@@ -1330,6 +1339,16 @@ impl fmt::Display for CreateConfigError {
     }
 }
 impl Error for CreateConfigError {}
+impl ServiceErrorCode for CreateConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConfigError::Dependency(_) => "DependencyException",
+            CreateConfigError::InvalidParameter(_) => "InvalidParameterException",
+            CreateConfigError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateDataflowEndpointGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateDataflowEndpointGroupError {
@@ -1380,6 +1399,15 @@ impl fmt::Display for CreateDataflowEndpointGroupError {
     }
 }
 impl Error for CreateDataflowEndpointGroupError {}
+impl ServiceErrorCode for CreateDataflowEndpointGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDataflowEndpointGroupError::Dependency(_) => "DependencyException",
+            CreateDataflowEndpointGroupError::InvalidParameter(_) => "InvalidParameterException",
+            CreateDataflowEndpointGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateMissionProfile
 #[derive(Debug, PartialEq)]
 pub enum CreateMissionProfileError {
@@ -1426,6 +1454,15 @@ impl fmt::Display for CreateMissionProfileError {
     }
 }
 impl Error for CreateMissionProfileError {}
+impl ServiceErrorCode for CreateMissionProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMissionProfileError::Dependency(_) => "DependencyException",
+            CreateMissionProfileError::InvalidParameter(_) => "InvalidParameterException",
+            CreateMissionProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteConfig
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigError {
@@ -1468,6 +1505,15 @@ impl fmt::Display for DeleteConfigError {
     }
 }
 impl Error for DeleteConfigError {}
+impl ServiceErrorCode for DeleteConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigError::Dependency(_) => "DependencyException",
+            DeleteConfigError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteDataflowEndpointGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteDataflowEndpointGroupError {
@@ -1518,6 +1564,15 @@ impl fmt::Display for DeleteDataflowEndpointGroupError {
     }
 }
 impl Error for DeleteDataflowEndpointGroupError {}
+impl ServiceErrorCode for DeleteDataflowEndpointGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDataflowEndpointGroupError::Dependency(_) => "DependencyException",
+            DeleteDataflowEndpointGroupError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteDataflowEndpointGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteMissionProfile
 #[derive(Debug, PartialEq)]
 pub enum DeleteMissionProfileError {
@@ -1564,6 +1619,15 @@ impl fmt::Display for DeleteMissionProfileError {
     }
 }
 impl Error for DeleteMissionProfileError {}
+impl ServiceErrorCode for DeleteMissionProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMissionProfileError::Dependency(_) => "DependencyException",
+            DeleteMissionProfileError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteMissionProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeContact
 #[derive(Debug, PartialEq)]
 pub enum DescribeContactError {
@@ -1606,6 +1670,15 @@ impl fmt::Display for DescribeContactError {
     }
 }
 impl Error for DescribeContactError {}
+impl ServiceErrorCode for DescribeContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeContactError::Dependency(_) => "DependencyException",
+            DescribeContactError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeContactError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetConfig
 #[derive(Debug, PartialEq)]
 pub enum GetConfigError {
@@ -1648,6 +1721,15 @@ impl fmt::Display for GetConfigError {
     }
 }
 impl Error for GetConfigError {}
+impl ServiceErrorCode for GetConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConfigError::Dependency(_) => "DependencyException",
+            GetConfigError::InvalidParameter(_) => "InvalidParameterException",
+            GetConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetDataflowEndpointGroup
 #[derive(Debug, PartialEq)]
 pub enum GetDataflowEndpointGroupError {
@@ -1694,6 +1776,15 @@ impl fmt::Display for GetDataflowEndpointGroupError {
     }
 }
 impl Error for GetDataflowEndpointGroupError {}
+impl ServiceErrorCode for GetDataflowEndpointGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDataflowEndpointGroupError::Dependency(_) => "DependencyException",
+            GetDataflowEndpointGroupError::InvalidParameter(_) => "InvalidParameterException",
+            GetDataflowEndpointGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetMinuteUsage
 #[derive(Debug, PartialEq)]
 pub enum GetMinuteUsageError {
@@ -1736,6 +1827,15 @@ impl fmt::Display for GetMinuteUsageError {
     }
 }
 impl Error for GetMinuteUsageError {}
+impl ServiceErrorCode for GetMinuteUsageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMinuteUsageError::Dependency(_) => "DependencyException",
+            GetMinuteUsageError::InvalidParameter(_) => "InvalidParameterException",
+            GetMinuteUsageError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetMissionProfile
 #[derive(Debug, PartialEq)]
 pub enum GetMissionProfileError {
@@ -1778,6 +1878,15 @@ impl fmt::Display for GetMissionProfileError {
     }
 }
 impl Error for GetMissionProfileError {}
+impl ServiceErrorCode for GetMissionProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMissionProfileError::Dependency(_) => "DependencyException",
+            GetMissionProfileError::InvalidParameter(_) => "InvalidParameterException",
+            GetMissionProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetSatellite
 #[derive(Debug, PartialEq)]
 pub enum GetSatelliteError {
@@ -1820,6 +1929,15 @@ impl fmt::Display for GetSatelliteError {
     }
 }
 impl Error for GetSatelliteError {}
+impl ServiceErrorCode for GetSatelliteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSatelliteError::Dependency(_) => "DependencyException",
+            GetSatelliteError::InvalidParameter(_) => "InvalidParameterException",
+            GetSatelliteError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListConfigs
 #[derive(Debug, PartialEq)]
 pub enum ListConfigsError {
@@ -1862,6 +1980,15 @@ impl fmt::Display for ListConfigsError {
     }
 }
 impl Error for ListConfigsError {}
+impl ServiceErrorCode for ListConfigsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListConfigsError::Dependency(_) => "DependencyException",
+            ListConfigsError::InvalidParameter(_) => "InvalidParameterException",
+            ListConfigsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListContacts
 #[derive(Debug, PartialEq)]
 pub enum ListContactsError {
@@ -1904,6 +2031,15 @@ impl fmt::Display for ListContactsError {
     }
 }
 impl Error for ListContactsError {}
+impl ServiceErrorCode for ListContactsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListContactsError::Dependency(_) => "DependencyException",
+            ListContactsError::InvalidParameter(_) => "InvalidParameterException",
+            ListContactsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListDataflowEndpointGroups
 #[derive(Debug, PartialEq)]
 pub enum ListDataflowEndpointGroupsError {
@@ -1954,6 +2090,15 @@ impl fmt::Display for ListDataflowEndpointGroupsError {
     }
 }
 impl Error for ListDataflowEndpointGroupsError {}
+impl ServiceErrorCode for ListDataflowEndpointGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDataflowEndpointGroupsError::Dependency(_) => "DependencyException",
+            ListDataflowEndpointGroupsError::InvalidParameter(_) => "InvalidParameterException",
+            ListDataflowEndpointGroupsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListGroundStations
 #[derive(Debug, PartialEq)]
 pub enum ListGroundStationsError {
@@ -1996,6 +2141,15 @@ impl fmt::Display for ListGroundStationsError {
     }
 }
 impl Error for ListGroundStationsError {}
+impl ServiceErrorCode for ListGroundStationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGroundStationsError::Dependency(_) => "DependencyException",
+            ListGroundStationsError::InvalidParameter(_) => "InvalidParameterException",
+            ListGroundStationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListMissionProfiles
 #[derive(Debug, PartialEq)]
 pub enum ListMissionProfilesError {
@@ -2042,6 +2196,15 @@ impl fmt::Display for ListMissionProfilesError {
     }
 }
 impl Error for ListMissionProfilesError {}
+impl ServiceErrorCode for ListMissionProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMissionProfilesError::Dependency(_) => "DependencyException",
+            ListMissionProfilesError::InvalidParameter(_) => "InvalidParameterException",
+            ListMissionProfilesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListSatellites
 #[derive(Debug, PartialEq)]
 pub enum ListSatellitesError {
@@ -2084,6 +2247,15 @@ impl fmt::Display for ListSatellitesError {
     }
 }
 impl Error for ListSatellitesError {}
+impl ServiceErrorCode for ListSatellitesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSatellitesError::Dependency(_) => "DependencyException",
+            ListSatellitesError::InvalidParameter(_) => "InvalidParameterException",
+            ListSatellitesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2130,6 +2302,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::Dependency(_) => "DependencyException",
+            ListTagsForResourceError::InvalidParameter(_) => "InvalidParameterException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ReserveContact
 #[derive(Debug, PartialEq)]
 pub enum ReserveContactError {
@@ -2172,6 +2353,15 @@ impl fmt::Display for ReserveContactError {
     }
 }
 impl Error for ReserveContactError {}
+impl ServiceErrorCode for ReserveContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ReserveContactError::Dependency(_) => "DependencyException",
+            ReserveContactError::InvalidParameter(_) => "InvalidParameterException",
+            ReserveContactError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2214,6 +2404,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::Dependency(_) => "DependencyException",
+            TagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2256,6 +2455,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::Dependency(_) => "DependencyException",
+            UntagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateConfig
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigError {
@@ -2298,6 +2506,15 @@ impl fmt::Display for UpdateConfigError {
     }
 }
 impl Error for UpdateConfigError {}
+impl ServiceErrorCode for UpdateConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConfigError::Dependency(_) => "DependencyException",
+            UpdateConfigError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateMissionProfile
 #[derive(Debug, PartialEq)]
 pub enum UpdateMissionProfileError {
@@ -2344,6 +2561,15 @@ impl fmt::Display for UpdateMissionProfileError {
     }
 }
 impl Error for UpdateMissionProfileError {}
+impl ServiceErrorCode for UpdateMissionProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMissionProfileError::Dependency(_) => "DependencyException",
+            UpdateMissionProfileError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateMissionProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Ground Station API. AWS Ground Station clients implement this trait.
 #[async_trait]
 pub trait GroundStation {
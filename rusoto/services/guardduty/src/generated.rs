@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -306,6 +306,7 @@ pub struct Country {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateDetectorRequest {
     /// <p>The idempotency token for the create request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -343,6 +344,7 @@ pub struct CreateFilterRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub action: Option<String>,
     /// <p>The idempotency token for the create request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -384,6 +386,7 @@ pub struct CreateIPSetRequest {
     #[serde(rename = "Activate")]
     pub activate: bool,
     /// <p>The idempotency token for the create request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -436,6 +439,7 @@ pub struct CreateMembersResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreatePublishingDestinationRequest {
     /// <p>The idempotency token for the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -481,6 +485,7 @@ pub struct CreateThreatIntelSetRequest {
     #[serde(rename = "Activate")]
     pub activate: bool,
     /// <p>The idempotency token for the create request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -2609,6 +2614,14 @@ impl fmt::Display for AcceptInvitationError {
     }
 }
 impl Error for AcceptInvitationError {}
+impl ServiceErrorCode for AcceptInvitationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptInvitationError::BadRequest(_) => "BadRequestException",
+            AcceptInvitationError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by ArchiveFindings
 #[derive(Debug, PartialEq)]
 pub enum ArchiveFindingsError {
@@ -2645,6 +2658,14 @@ impl fmt::Display for ArchiveFindingsError {
     }
 }
 impl Error for ArchiveFindingsError {}
+impl ServiceErrorCode for ArchiveFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ArchiveFindingsError::BadRequest(_) => "BadRequestException",
+            ArchiveFindingsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by CreateDetector
 #[derive(Debug, PartialEq)]
 pub enum CreateDetectorError {
@@ -2681,6 +2702,14 @@ impl fmt::Display for CreateDetectorError {
     }
 }
 impl Error for CreateDetectorError {}
+impl ServiceErrorCode for CreateDetectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDetectorError::BadRequest(_) => "BadRequestException",
+            CreateDetectorError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by CreateFilter
 #[derive(Debug, PartialEq)]
 pub enum CreateFilterError {
@@ -2717,6 +2746,14 @@ impl fmt::Display for CreateFilterError {
     }
 }
 impl Error for CreateFilterError {}
+impl ServiceErrorCode for CreateFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFilterError::BadRequest(_) => "BadRequestException",
+            CreateFilterError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by CreateIPSet
 #[derive(Debug, PartialEq)]
 pub enum CreateIPSetError {
@@ -2753,6 +2790,14 @@ impl fmt::Display for CreateIPSetError {
     }
 }
 impl Error for CreateIPSetError {}
+impl ServiceErrorCode for CreateIPSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateIPSetError::BadRequest(_) => "BadRequestException",
+            CreateIPSetError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by CreateMembers
 #[derive(Debug, PartialEq)]
 pub enum CreateMembersError {
@@ -2789,6 +2834,14 @@ impl fmt::Display for CreateMembersError {
     }
 }
 impl Error for CreateMembersError {}
+impl ServiceErrorCode for CreateMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMembersError::BadRequest(_) => "BadRequestException",
+            CreateMembersError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by CreatePublishingDestination
 #[derive(Debug, PartialEq)]
 pub enum CreatePublishingDestinationError {
@@ -2833,6 +2886,16 @@ impl fmt::Display for CreatePublishingDestinationError {
     }
 }
 impl Error for CreatePublishingDestinationError {}
+impl ServiceErrorCode for CreatePublishingDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePublishingDestinationError::BadRequest(_) => "BadRequestException",
+            CreatePublishingDestinationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateSampleFindings
 #[derive(Debug, PartialEq)]
 pub enum CreateSampleFindingsError {
@@ -2871,6 +2934,14 @@ impl fmt::Display for CreateSampleFindingsError {
     }
 }
 impl Error for CreateSampleFindingsError {}
+impl ServiceErrorCode for CreateSampleFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSampleFindingsError::BadRequest(_) => "BadRequestException",
+            CreateSampleFindingsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by CreateThreatIntelSet
 #[derive(Debug, PartialEq)]
 pub enum CreateThreatIntelSetError {
@@ -2909,6 +2980,14 @@ impl fmt::Display for CreateThreatIntelSetError {
     }
 }
 impl Error for CreateThreatIntelSetError {}
+impl ServiceErrorCode for CreateThreatIntelSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateThreatIntelSetError::BadRequest(_) => "BadRequestException",
+            CreateThreatIntelSetError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by DeclineInvitations
 #[derive(Debug, PartialEq)]
 pub enum DeclineInvitationsError {
@@ -2947,6 +3026,14 @@ impl fmt::Display for DeclineInvitationsError {
     }
 }
 impl Error for DeclineInvitationsError {}
+impl ServiceErrorCode for DeclineInvitationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeclineInvitationsError::BadRequest(_) => "BadRequestException",
+            DeclineInvitationsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by DeleteDetector
 #[derive(Debug, PartialEq)]
 pub enum DeleteDetectorError {
@@ -2983,6 +3070,14 @@ impl fmt::Display for DeleteDetectorError {
     }
 }
 impl Error for DeleteDetectorError {}
+impl ServiceErrorCode for DeleteDetectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDetectorError::BadRequest(_) => "BadRequestException",
+            DeleteDetectorError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by DeleteFilter
 #[derive(Debug, PartialEq)]
 pub enum DeleteFilterError {
@@ -3019,6 +3114,14 @@ impl fmt::Display for DeleteFilterError {
     }
 }
 impl Error for DeleteFilterError {}
+impl ServiceErrorCode for DeleteFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFilterError::BadRequest(_) => "BadRequestException",
+            DeleteFilterError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by DeleteIPSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteIPSetError {
@@ -3055,6 +3158,14 @@ impl fmt::Display for DeleteIPSetError {
     }
 }
 impl Error for DeleteIPSetError {}
+impl ServiceErrorCode for DeleteIPSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIPSetError::BadRequest(_) => "BadRequestException",
+            DeleteIPSetError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by DeleteInvitations
 #[derive(Debug, PartialEq)]
 pub enum DeleteInvitationsError {
@@ -3093,6 +3204,14 @@ impl fmt::Display for DeleteInvitationsError {
     }
 }
 impl Error for DeleteInvitationsError {}
+impl ServiceErrorCode for DeleteInvitationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInvitationsError::BadRequest(_) => "BadRequestException",
+            DeleteInvitationsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by DeleteMembers
 #[derive(Debug, PartialEq)]
 pub enum DeleteMembersError {
@@ -3129,6 +3248,14 @@ impl fmt::Display for DeleteMembersError {
     }
 }
 impl Error for DeleteMembersError {}
+impl ServiceErrorCode for DeleteMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMembersError::BadRequest(_) => "BadRequestException",
+            DeleteMembersError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by DeletePublishingDestination
 #[derive(Debug, PartialEq)]
 pub enum DeletePublishingDestinationError {
@@ -3173,6 +3300,16 @@ impl fmt::Display for DeletePublishingDestinationError {
     }
 }
 impl Error for DeletePublishingDestinationError {}
+impl ServiceErrorCode for DeletePublishingDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePublishingDestinationError::BadRequest(_) => "BadRequestException",
+            DeletePublishingDestinationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteThreatIntelSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteThreatIntelSetError {
@@ -3211,6 +3348,14 @@ impl fmt::Display for DeleteThreatIntelSetError {
     }
 }
 impl Error for DeleteThreatIntelSetError {}
+impl ServiceErrorCode for DeleteThreatIntelSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteThreatIntelSetError::BadRequest(_) => "BadRequestException",
+            DeleteThreatIntelSetError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by DescribeOrganizationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribeOrganizationConfigurationError {
@@ -3255,6 +3400,16 @@ impl fmt::Display for DescribeOrganizationConfigurationError {
     }
 }
 impl Error for DescribeOrganizationConfigurationError {}
+impl ServiceErrorCode for DescribeOrganizationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeOrganizationConfigurationError::BadRequest(_) => "BadRequestException",
+            DescribeOrganizationConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribePublishingDestination
 #[derive(Debug, PartialEq)]
 pub enum DescribePublishingDestinationError {
@@ -3299,6 +3454,16 @@ impl fmt::Display for DescribePublishingDestinationError {
     }
 }
 impl Error for DescribePublishingDestinationError {}
+impl ServiceErrorCode for DescribePublishingDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePublishingDestinationError::BadRequest(_) => "BadRequestException",
+            DescribePublishingDestinationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by DisableOrganizationAdminAccount
 #[derive(Debug, PartialEq)]
 pub enum DisableOrganizationAdminAccountError {
@@ -3343,6 +3508,16 @@ impl fmt::Display for DisableOrganizationAdminAccountError {
     }
 }
 impl Error for DisableOrganizationAdminAccountError {}
+impl ServiceErrorCode for DisableOrganizationAdminAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableOrganizationAdminAccountError::BadRequest(_) => "BadRequestException",
+            DisableOrganizationAdminAccountError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateFromMasterAccount
 #[derive(Debug, PartialEq)]
 pub enum DisassociateFromMasterAccountError {
@@ -3387,6 +3562,16 @@ impl fmt::Display for DisassociateFromMasterAccountError {
     }
 }
 impl Error for DisassociateFromMasterAccountError {}
+impl ServiceErrorCode for DisassociateFromMasterAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateFromMasterAccountError::BadRequest(_) => "BadRequestException",
+            DisassociateFromMasterAccountError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateMembers
 #[derive(Debug, PartialEq)]
 pub enum DisassociateMembersError {
@@ -3425,6 +3610,14 @@ impl fmt::Display for DisassociateMembersError {
     }
 }
 impl Error for DisassociateMembersError {}
+impl ServiceErrorCode for DisassociateMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateMembersError::BadRequest(_) => "BadRequestException",
+            DisassociateMembersError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by EnableOrganizationAdminAccount
 #[derive(Debug, PartialEq)]
 pub enum EnableOrganizationAdminAccountError {
@@ -3469,6 +3662,16 @@ impl fmt::Display for EnableOrganizationAdminAccountError {
     }
 }
 impl Error for EnableOrganizationAdminAccountError {}
+impl ServiceErrorCode for EnableOrganizationAdminAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableOrganizationAdminAccountError::BadRequest(_) => "BadRequestException",
+            EnableOrganizationAdminAccountError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by GetDetector
 #[derive(Debug, PartialEq)]
 pub enum GetDetectorError {
@@ -3505,6 +3708,14 @@ impl fmt::Display for GetDetectorError {
     }
 }
 impl Error for GetDetectorError {}
+impl ServiceErrorCode for GetDetectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDetectorError::BadRequest(_) => "BadRequestException",
+            GetDetectorError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by GetFilter
 #[derive(Debug, PartialEq)]
 pub enum GetFilterError {
@@ -3541,6 +3752,14 @@ impl fmt::Display for GetFilterError {
     }
 }
 impl Error for GetFilterError {}
+impl ServiceErrorCode for GetFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFilterError::BadRequest(_) => "BadRequestException",
+            GetFilterError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by GetFindings
 #[derive(Debug, PartialEq)]
 pub enum GetFindingsError {
@@ -3577,6 +3796,14 @@ impl fmt::Display for GetFindingsError {
     }
 }
 impl Error for GetFindingsError {}
+impl ServiceErrorCode for GetFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFindingsError::BadRequest(_) => "BadRequestException",
+            GetFindingsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by GetFindingsStatistics
 #[derive(Debug, PartialEq)]
 pub enum GetFindingsStatisticsError {
@@ -3615,6 +3842,14 @@ impl fmt::Display for GetFindingsStatisticsError {
     }
 }
 impl Error for GetFindingsStatisticsError {}
+impl ServiceErrorCode for GetFindingsStatisticsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFindingsStatisticsError::BadRequest(_) => "BadRequestException",
+            GetFindingsStatisticsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by GetIPSet
 #[derive(Debug, PartialEq)]
 pub enum GetIPSetError {
@@ -3651,6 +3886,14 @@ impl fmt::Display for GetIPSetError {
     }
 }
 impl Error for GetIPSetError {}
+impl ServiceErrorCode for GetIPSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIPSetError::BadRequest(_) => "BadRequestException",
+            GetIPSetError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by GetInvitationsCount
 #[derive(Debug, PartialEq)]
 pub enum GetInvitationsCountError {
@@ -3689,6 +3932,14 @@ impl fmt::Display for GetInvitationsCountError {
     }
 }
 impl Error for GetInvitationsCountError {}
+impl ServiceErrorCode for GetInvitationsCountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInvitationsCountError::BadRequest(_) => "BadRequestException",
+            GetInvitationsCountError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by GetMasterAccount
 #[derive(Debug, PartialEq)]
 pub enum GetMasterAccountError {
@@ -3727,6 +3978,14 @@ impl fmt::Display for GetMasterAccountError {
     }
 }
 impl Error for GetMasterAccountError {}
+impl ServiceErrorCode for GetMasterAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMasterAccountError::BadRequest(_) => "BadRequestException",
+            GetMasterAccountError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by GetMemberDetectors
 #[derive(Debug, PartialEq)]
 pub enum GetMemberDetectorsError {
@@ -3765,6 +4024,14 @@ impl fmt::Display for GetMemberDetectorsError {
     }
 }
 impl Error for GetMemberDetectorsError {}
+impl ServiceErrorCode for GetMemberDetectorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMemberDetectorsError::BadRequest(_) => "BadRequestException",
+            GetMemberDetectorsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by GetMembers
 #[derive(Debug, PartialEq)]
 pub enum GetMembersError {
@@ -3801,6 +4068,14 @@ impl fmt::Display for GetMembersError {
     }
 }
 impl Error for GetMembersError {}
+impl ServiceErrorCode for GetMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMembersError::BadRequest(_) => "BadRequestException",
+            GetMembersError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by GetThreatIntelSet
 #[derive(Debug, PartialEq)]
 pub enum GetThreatIntelSetError {
@@ -3839,6 +4114,14 @@ impl fmt::Display for GetThreatIntelSetError {
     }
 }
 impl Error for GetThreatIntelSetError {}
+impl ServiceErrorCode for GetThreatIntelSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetThreatIntelSetError::BadRequest(_) => "BadRequestException",
+            GetThreatIntelSetError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by GetUsageStatistics
 #[derive(Debug, PartialEq)]
 pub enum GetUsageStatisticsError {
@@ -3877,6 +4160,14 @@ impl fmt::Display for GetUsageStatisticsError {
     }
 }
 impl Error for GetUsageStatisticsError {}
+impl ServiceErrorCode for GetUsageStatisticsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUsageStatisticsError::BadRequest(_) => "BadRequestException",
+            GetUsageStatisticsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by InviteMembers
 #[derive(Debug, PartialEq)]
 pub enum InviteMembersError {
@@ -3913,6 +4204,14 @@ impl fmt::Display for InviteMembersError {
     }
 }
 impl Error for InviteMembersError {}
+impl ServiceErrorCode for InviteMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InviteMembersError::BadRequest(_) => "BadRequestException",
+            InviteMembersError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by ListDetectors
 #[derive(Debug, PartialEq)]
 pub enum ListDetectorsError {
@@ -3949,6 +4248,14 @@ impl fmt::Display for ListDetectorsError {
     }
 }
 impl Error for ListDetectorsError {}
+impl ServiceErrorCode for ListDetectorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDetectorsError::BadRequest(_) => "BadRequestException",
+            ListDetectorsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by ListFilters
 #[derive(Debug, PartialEq)]
 pub enum ListFiltersError {
@@ -3985,6 +4292,14 @@ impl fmt::Display for ListFiltersError {
     }
 }
 impl Error for ListFiltersError {}
+impl ServiceErrorCode for ListFiltersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFiltersError::BadRequest(_) => "BadRequestException",
+            ListFiltersError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by ListFindings
 #[derive(Debug, PartialEq)]
 pub enum ListFindingsError {
@@ -4021,6 +4336,14 @@ impl fmt::Display for ListFindingsError {
     }
 }
 impl Error for ListFindingsError {}
+impl ServiceErrorCode for ListFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFindingsError::BadRequest(_) => "BadRequestException",
+            ListFindingsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by ListIPSets
 #[derive(Debug, PartialEq)]
 pub enum ListIPSetsError {
@@ -4057,6 +4380,14 @@ impl fmt::Display for ListIPSetsError {
     }
 }
 impl Error for ListIPSetsError {}
+impl ServiceErrorCode for ListIPSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListIPSetsError::BadRequest(_) => "BadRequestException",
+            ListIPSetsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by ListInvitations
 #[derive(Debug, PartialEq)]
 pub enum ListInvitationsError {
@@ -4093,6 +4424,14 @@ impl fmt::Display for ListInvitationsError {
     }
 }
 impl Error for ListInvitationsError {}
+impl ServiceErrorCode for ListInvitationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInvitationsError::BadRequest(_) => "BadRequestException",
+            ListInvitationsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by ListMembers
 #[derive(Debug, PartialEq)]
 pub enum ListMembersError {
@@ -4129,6 +4468,14 @@ impl fmt::Display for ListMembersError {
     }
 }
 impl Error for ListMembersError {}
+impl ServiceErrorCode for ListMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMembersError::BadRequest(_) => "BadRequestException",
+            ListMembersError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by ListOrganizationAdminAccounts
 #[derive(Debug, PartialEq)]
 pub enum ListOrganizationAdminAccountsError {
@@ -4173,6 +4520,16 @@ impl fmt::Display for ListOrganizationAdminAccountsError {
     }
 }
 impl Error for ListOrganizationAdminAccountsError {}
+impl ServiceErrorCode for ListOrganizationAdminAccountsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOrganizationAdminAccountsError::BadRequest(_) => "BadRequestException",
+            ListOrganizationAdminAccountsError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by ListPublishingDestinations
 #[derive(Debug, PartialEq)]
 pub enum ListPublishingDestinationsError {
@@ -4217,6 +4574,16 @@ impl fmt::Display for ListPublishingDestinationsError {
     }
 }
 impl Error for ListPublishingDestinationsError {}
+impl ServiceErrorCode for ListPublishingDestinationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPublishingDestinationsError::BadRequest(_) => "BadRequestException",
+            ListPublishingDestinationsError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -4255,6 +4622,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by ListThreatIntelSets
 #[derive(Debug, PartialEq)]
 pub enum ListThreatIntelSetsError {
@@ -4293,6 +4668,14 @@ impl fmt::Display for ListThreatIntelSetsError {
     }
 }
 impl Error for ListThreatIntelSetsError {}
+impl ServiceErrorCode for ListThreatIntelSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListThreatIntelSetsError::BadRequest(_) => "BadRequestException",
+            ListThreatIntelSetsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by StartMonitoringMembers
 #[derive(Debug, PartialEq)]
 pub enum StartMonitoringMembersError {
@@ -4331,6 +4714,14 @@ impl fmt::Display for StartMonitoringMembersError {
     }
 }
 impl Error for StartMonitoringMembersError {}
+impl ServiceErrorCode for StartMonitoringMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartMonitoringMembersError::BadRequest(_) => "BadRequestException",
+            StartMonitoringMembersError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by StopMonitoringMembers
 #[derive(Debug, PartialEq)]
 pub enum StopMonitoringMembersError {
@@ -4369,6 +4760,14 @@ impl fmt::Display for StopMonitoringMembersError {
     }
 }
 impl Error for StopMonitoringMembersError {}
+impl ServiceErrorCode for StopMonitoringMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopMonitoringMembersError::BadRequest(_) => "BadRequestException",
+            StopMonitoringMembersError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -4405,6 +4804,14 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by UnarchiveFindings
 #[derive(Debug, PartialEq)]
 pub enum UnarchiveFindingsError {
@@ -4443,6 +4850,14 @@ impl fmt::Display for UnarchiveFindingsError {
     }
 }
 impl Error for UnarchiveFindingsError {}
+impl ServiceErrorCode for UnarchiveFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UnarchiveFindingsError::BadRequest(_) => "BadRequestException",
+            UnarchiveFindingsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -4479,6 +4894,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by UpdateDetector
 #[derive(Debug, PartialEq)]
 pub enum UpdateDetectorError {
@@ -4515,6 +4938,14 @@ impl fmt::Display for UpdateDetectorError {
     }
 }
 impl Error for UpdateDetectorError {}
+impl ServiceErrorCode for UpdateDetectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDetectorError::BadRequest(_) => "BadRequestException",
+            UpdateDetectorError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by UpdateFilter
 #[derive(Debug, PartialEq)]
 pub enum UpdateFilterError {
@@ -4551,6 +4982,14 @@ impl fmt::Display for UpdateFilterError {
     }
 }
 impl Error for UpdateFilterError {}
+impl ServiceErrorCode for UpdateFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFilterError::BadRequest(_) => "BadRequestException",
+            UpdateFilterError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by UpdateFindingsFeedback
 #[derive(Debug, PartialEq)]
 pub enum UpdateFindingsFeedbackError {
@@ -4589,6 +5028,14 @@ impl fmt::Display for UpdateFindingsFeedbackError {
     }
 }
 impl Error for UpdateFindingsFeedbackError {}
+impl ServiceErrorCode for UpdateFindingsFeedbackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFindingsFeedbackError::BadRequest(_) => "BadRequestException",
+            UpdateFindingsFeedbackError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by UpdateIPSet
 #[derive(Debug, PartialEq)]
 pub enum UpdateIPSetError {
@@ -4625,6 +5072,14 @@ impl fmt::Display for UpdateIPSetError {
     }
 }
 impl Error for UpdateIPSetError {}
+impl ServiceErrorCode for UpdateIPSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateIPSetError::BadRequest(_) => "BadRequestException",
+            UpdateIPSetError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by UpdateMemberDetectors
 #[derive(Debug, PartialEq)]
 pub enum UpdateMemberDetectorsError {
@@ -4663,6 +5118,14 @@ impl fmt::Display for UpdateMemberDetectorsError {
     }
 }
 impl Error for UpdateMemberDetectorsError {}
+impl ServiceErrorCode for UpdateMemberDetectorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMemberDetectorsError::BadRequest(_) => "BadRequestException",
+            UpdateMemberDetectorsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by UpdateOrganizationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateOrganizationConfigurationError {
@@ -4707,6 +5170,16 @@ impl fmt::Display for UpdateOrganizationConfigurationError {
     }
 }
 impl Error for UpdateOrganizationConfigurationError {}
+impl ServiceErrorCode for UpdateOrganizationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateOrganizationConfigurationError::BadRequest(_) => "BadRequestException",
+            UpdateOrganizationConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdatePublishingDestination
 #[derive(Debug, PartialEq)]
 pub enum UpdatePublishingDestinationError {
@@ -4751,6 +5224,16 @@ impl fmt::Display for UpdatePublishingDestinationError {
     }
 }
 impl Error for UpdatePublishingDestinationError {}
+impl ServiceErrorCode for UpdatePublishingDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePublishingDestinationError::BadRequest(_) => "BadRequestException",
+            UpdatePublishingDestinationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateThreatIntelSet
 #[derive(Debug, PartialEq)]
 pub enum UpdateThreatIntelSetError {
@@ -4789,6 +5272,14 @@ impl fmt::Display for UpdateThreatIntelSetError {
     }
 }
 impl Error for UpdateThreatIntelSetError {}
+impl ServiceErrorCode for UpdateThreatIntelSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateThreatIntelSetError::BadRequest(_) => "BadRequestException",
+            UpdateThreatIntelSetError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon GuardDuty API. Amazon GuardDuty clients implement this trait.
 #[async_trait]
 pub trait GuardDuty {
@@ -5274,6 +5765,10 @@ impl GuardDuty for GuardDutyClient {
         &self,
         input: CreateDetectorRequest,
     ) -> Result<CreateDetectorResponse, RusotoError<CreateDetectorError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = "/detector";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
@@ -5305,6 +5800,10 @@ impl GuardDuty for GuardDutyClient {
         &self,
         input: CreateFilterRequest,
     ) -> Result<CreateFilterResponse, RusotoError<CreateFilterError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/detector/{detector_id}/filter",
             detector_id = input.detector_id
@@ -5339,6 +5838,10 @@ impl GuardDuty for GuardDutyClient {
         &self,
         input: CreateIPSetRequest,
     ) -> Result<CreateIPSetResponse, RusotoError<CreateIPSetError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/detector/{detector_id}/ipset",
             detector_id = input.detector_id
@@ -5408,6 +5911,10 @@ impl GuardDuty for GuardDutyClient {
         input: CreatePublishingDestinationRequest,
     ) -> Result<CreatePublishingDestinationResponse, RusotoError<CreatePublishingDestinationError>>
     {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/detector/{detector_id}/publishingDestination",
             detector_id = input.detector_id
@@ -5476,6 +5983,10 @@ impl GuardDuty for GuardDutyClient {
         &self,
         input: CreateThreatIntelSetRequest,
     ) -> Result<CreateThreatIntelSetResponse, RusotoError<CreateThreatIntelSetError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/detector/{detector_id}/threatintelset",
             detector_id = input.detector_id
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -844,6 +844,15 @@ impl fmt::Display for DescribeAffectedAccountsForOrganizationError {
     }
 }
 impl Error for DescribeAffectedAccountsForOrganizationError {}
+impl ServiceErrorCode for DescribeAffectedAccountsForOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAffectedAccountsForOrganizationError::InvalidPaginationToken(_) => {
+                "InvalidPaginationToken"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeAffectedEntities
 #[derive(Debug, PartialEq)]
 pub enum DescribeAffectedEntitiesError {
@@ -886,6 +895,14 @@ impl fmt::Display for DescribeAffectedEntitiesError {
     }
 }
 impl Error for DescribeAffectedEntitiesError {}
+impl ServiceErrorCode for DescribeAffectedEntitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAffectedEntitiesError::InvalidPaginationToken(_) => "InvalidPaginationToken",
+            DescribeAffectedEntitiesError::UnsupportedLocale(_) => "UnsupportedLocale",
+        }
+    }
+}
 /// Errors returned by DescribeAffectedEntitiesForOrganization
 #[derive(Debug, PartialEq)]
 pub enum DescribeAffectedEntitiesForOrganizationError {
@@ -934,6 +951,18 @@ impl fmt::Display for DescribeAffectedEntitiesForOrganizationError {
     }
 }
 impl Error for DescribeAffectedEntitiesForOrganizationError {}
+impl ServiceErrorCode for DescribeAffectedEntitiesForOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAffectedEntitiesForOrganizationError::InvalidPaginationToken(_) => {
+                "InvalidPaginationToken"
+            }
+            DescribeAffectedEntitiesForOrganizationError::UnsupportedLocale(_) => {
+                "UnsupportedLocale"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeEntityAggregates
 #[derive(Debug, PartialEq)]
 pub enum DescribeEntityAggregatesError {}
@@ -956,6 +985,11 @@ impl fmt::Display for DescribeEntityAggregatesError {
     }
 }
 impl Error for DescribeEntityAggregatesError {}
+impl ServiceErrorCode for DescribeEntityAggregatesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEventAggregates
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventAggregatesError {
@@ -990,6 +1024,13 @@ impl fmt::Display for DescribeEventAggregatesError {
     }
 }
 impl Error for DescribeEventAggregatesError {}
+impl ServiceErrorCode for DescribeEventAggregatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventAggregatesError::InvalidPaginationToken(_) => "InvalidPaginationToken",
+        }
+    }
+}
 /// Errors returned by DescribeEventDetails
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventDetailsError {
@@ -1022,6 +1063,13 @@ impl fmt::Display for DescribeEventDetailsError {
     }
 }
 impl Error for DescribeEventDetailsError {}
+impl ServiceErrorCode for DescribeEventDetailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventDetailsError::UnsupportedLocale(_) => "UnsupportedLocale",
+        }
+    }
+}
 /// Errors returned by DescribeEventDetailsForOrganization
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventDetailsForOrganizationError {
@@ -1058,6 +1106,13 @@ impl fmt::Display for DescribeEventDetailsForOrganizationError {
     }
 }
 impl Error for DescribeEventDetailsForOrganizationError {}
+impl ServiceErrorCode for DescribeEventDetailsForOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventDetailsForOrganizationError::UnsupportedLocale(_) => "UnsupportedLocale",
+        }
+    }
+}
 /// Errors returned by DescribeEventTypes
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventTypesError {
@@ -1098,6 +1153,14 @@ impl fmt::Display for DescribeEventTypesError {
     }
 }
 impl Error for DescribeEventTypesError {}
+impl ServiceErrorCode for DescribeEventTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventTypesError::InvalidPaginationToken(_) => "InvalidPaginationToken",
+            DescribeEventTypesError::UnsupportedLocale(_) => "UnsupportedLocale",
+        }
+    }
+}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventsError {
@@ -1136,6 +1199,14 @@ impl fmt::Display for DescribeEventsError {
     }
 }
 impl Error for DescribeEventsError {}
+impl ServiceErrorCode for DescribeEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventsError::InvalidPaginationToken(_) => "InvalidPaginationToken",
+            DescribeEventsError::UnsupportedLocale(_) => "UnsupportedLocale",
+        }
+    }
+}
 /// Errors returned by DescribeEventsForOrganization
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventsForOrganizationError {
@@ -1182,6 +1253,16 @@ impl fmt::Display for DescribeEventsForOrganizationError {
     }
 }
 impl Error for DescribeEventsForOrganizationError {}
+impl ServiceErrorCode for DescribeEventsForOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventsForOrganizationError::InvalidPaginationToken(_) => {
+                "InvalidPaginationToken"
+            }
+            DescribeEventsForOrganizationError::UnsupportedLocale(_) => "UnsupportedLocale",
+        }
+    }
+}
 /// Errors returned by DescribeHealthServiceStatusForOrganization
 #[derive(Debug, PartialEq)]
 pub enum DescribeHealthServiceStatusForOrganizationError {}
@@ -1206,6 +1287,11 @@ impl fmt::Display for DescribeHealthServiceStatusForOrganizationError {
     }
 }
 impl Error for DescribeHealthServiceStatusForOrganizationError {}
+impl ServiceErrorCode for DescribeHealthServiceStatusForOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisableHealthServiceAccessForOrganization
 #[derive(Debug, PartialEq)]
 pub enum DisableHealthServiceAccessForOrganizationError {
@@ -1244,6 +1330,15 @@ impl fmt::Display for DisableHealthServiceAccessForOrganizationError {
     }
 }
 impl Error for DisableHealthServiceAccessForOrganizationError {}
+impl ServiceErrorCode for DisableHealthServiceAccessForOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableHealthServiceAccessForOrganizationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+        }
+    }
+}
 /// Errors returned by EnableHealthServiceAccessForOrganization
 #[derive(Debug, PartialEq)]
 pub enum EnableHealthServiceAccessForOrganizationError {
@@ -1282,6 +1377,15 @@ impl fmt::Display for EnableHealthServiceAccessForOrganizationError {
     }
 }
 impl Error for EnableHealthServiceAccessForOrganizationError {}
+impl ServiceErrorCode for EnableHealthServiceAccessForOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableHealthServiceAccessForOrganizationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the AWSHealth API. AWSHealth clients implement this trait.
 #[async_trait]
 pub trait AWSHealth {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -11432,6 +11432,16 @@ impl fmt::Display for AddClientIDToOpenIDConnectProviderError {
     }
 }
 impl Error for AddClientIDToOpenIDConnectProviderError {}
+impl ServiceErrorCode for AddClientIDToOpenIDConnectProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddClientIDToOpenIDConnectProviderError::InvalidInput(_) => "InvalidInput",
+            AddClientIDToOpenIDConnectProviderError::LimitExceeded(_) => "LimitExceeded",
+            AddClientIDToOpenIDConnectProviderError::NoSuchEntity(_) => "NoSuchEntity",
+            AddClientIDToOpenIDConnectProviderError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by AddRoleToInstanceProfile
 #[derive(Debug, PartialEq)]
 pub enum AddRoleToInstanceProfileError {
@@ -11510,6 +11520,17 @@ impl fmt::Display for AddRoleToInstanceProfileError {
     }
 }
 impl Error for AddRoleToInstanceProfileError {}
+impl ServiceErrorCode for AddRoleToInstanceProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddRoleToInstanceProfileError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            AddRoleToInstanceProfileError::LimitExceeded(_) => "LimitExceeded",
+            AddRoleToInstanceProfileError::NoSuchEntity(_) => "NoSuchEntity",
+            AddRoleToInstanceProfileError::ServiceFailure(_) => "ServiceFailure",
+            AddRoleToInstanceProfileError::UnmodifiableEntity(_) => "UnmodifiableEntity",
+        }
+    }
+}
 /// Errors returned by AddUserToGroup
 #[derive(Debug, PartialEq)]
 pub enum AddUserToGroupError {
@@ -11570,6 +11591,15 @@ impl fmt::Display for AddUserToGroupError {
     }
 }
 impl Error for AddUserToGroupError {}
+impl ServiceErrorCode for AddUserToGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddUserToGroupError::LimitExceeded(_) => "LimitExceeded",
+            AddUserToGroupError::NoSuchEntity(_) => "NoSuchEntity",
+            AddUserToGroupError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by AttachGroupPolicy
 #[derive(Debug, PartialEq)]
 pub enum AttachGroupPolicyError {
@@ -11646,6 +11676,17 @@ impl fmt::Display for AttachGroupPolicyError {
     }
 }
 impl Error for AttachGroupPolicyError {}
+impl ServiceErrorCode for AttachGroupPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachGroupPolicyError::InvalidInput(_) => "InvalidInput",
+            AttachGroupPolicyError::LimitExceeded(_) => "LimitExceeded",
+            AttachGroupPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            AttachGroupPolicyError::PolicyNotAttachable(_) => "PolicyNotAttachable",
+            AttachGroupPolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by AttachRolePolicy
 #[derive(Debug, PartialEq)]
 pub enum AttachRolePolicyError {
@@ -11730,6 +11771,18 @@ impl fmt::Display for AttachRolePolicyError {
     }
 }
 impl Error for AttachRolePolicyError {}
+impl ServiceErrorCode for AttachRolePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachRolePolicyError::InvalidInput(_) => "InvalidInput",
+            AttachRolePolicyError::LimitExceeded(_) => "LimitExceeded",
+            AttachRolePolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            AttachRolePolicyError::PolicyNotAttachable(_) => "PolicyNotAttachable",
+            AttachRolePolicyError::ServiceFailure(_) => "ServiceFailure",
+            AttachRolePolicyError::UnmodifiableEntity(_) => "UnmodifiableEntity",
+        }
+    }
+}
 /// Errors returned by AttachUserPolicy
 #[derive(Debug, PartialEq)]
 pub enum AttachUserPolicyError {
@@ -11806,6 +11859,17 @@ impl fmt::Display for AttachUserPolicyError {
     }
 }
 impl Error for AttachUserPolicyError {}
+impl ServiceErrorCode for AttachUserPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachUserPolicyError::InvalidInput(_) => "InvalidInput",
+            AttachUserPolicyError::LimitExceeded(_) => "LimitExceeded",
+            AttachUserPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            AttachUserPolicyError::PolicyNotAttachable(_) => "PolicyNotAttachable",
+            AttachUserPolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ChangePassword
 #[derive(Debug, PartialEq)]
 pub enum ChangePasswordError {
@@ -11892,6 +11956,20 @@ impl fmt::Display for ChangePasswordError {
     }
 }
 impl Error for ChangePasswordError {}
+impl ServiceErrorCode for ChangePasswordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ChangePasswordError::EntityTemporarilyUnmodifiable(_) => {
+                "EntityTemporarilyUnmodifiable"
+            }
+            ChangePasswordError::InvalidUserType(_) => "InvalidUserType",
+            ChangePasswordError::LimitExceeded(_) => "LimitExceeded",
+            ChangePasswordError::NoSuchEntity(_) => "NoSuchEntity",
+            ChangePasswordError::PasswordPolicyViolation(_) => "PasswordPolicyViolation",
+            ChangePasswordError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by CreateAccessKey
 #[derive(Debug, PartialEq)]
 pub enum CreateAccessKeyError {
@@ -11952,6 +12030,15 @@ impl fmt::Display for CreateAccessKeyError {
     }
 }
 impl Error for CreateAccessKeyError {}
+impl ServiceErrorCode for CreateAccessKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAccessKeyError::LimitExceeded(_) => "LimitExceeded",
+            CreateAccessKeyError::NoSuchEntity(_) => "NoSuchEntity",
+            CreateAccessKeyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by CreateAccountAlias
 #[derive(Debug, PartialEq)]
 pub enum CreateAccountAliasError {
@@ -12012,6 +12099,15 @@ impl fmt::Display for CreateAccountAliasError {
     }
 }
 impl Error for CreateAccountAliasError {}
+impl ServiceErrorCode for CreateAccountAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAccountAliasError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            CreateAccountAliasError::LimitExceeded(_) => "LimitExceeded",
+            CreateAccountAliasError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by CreateGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateGroupError {
@@ -12080,6 +12176,16 @@ impl fmt::Display for CreateGroupError {
     }
 }
 impl Error for CreateGroupError {}
+impl ServiceErrorCode for CreateGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGroupError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            CreateGroupError::LimitExceeded(_) => "LimitExceeded",
+            CreateGroupError::NoSuchEntity(_) => "NoSuchEntity",
+            CreateGroupError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by CreateInstanceProfile
 #[derive(Debug, PartialEq)]
 pub enum CreateInstanceProfileError {
@@ -12140,6 +12246,15 @@ impl fmt::Display for CreateInstanceProfileError {
     }
 }
 impl Error for CreateInstanceProfileError {}
+impl ServiceErrorCode for CreateInstanceProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateInstanceProfileError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            CreateInstanceProfileError::LimitExceeded(_) => "LimitExceeded",
+            CreateInstanceProfileError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by CreateLoginProfile
 #[derive(Debug, PartialEq)]
 pub enum CreateLoginProfileError {
@@ -12216,6 +12331,17 @@ impl fmt::Display for CreateLoginProfileError {
     }
 }
 impl Error for CreateLoginProfileError {}
+impl ServiceErrorCode for CreateLoginProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLoginProfileError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            CreateLoginProfileError::LimitExceeded(_) => "LimitExceeded",
+            CreateLoginProfileError::NoSuchEntity(_) => "NoSuchEntity",
+            CreateLoginProfileError::PasswordPolicyViolation(_) => "PasswordPolicyViolation",
+            CreateLoginProfileError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by CreateOpenIDConnectProvider
 #[derive(Debug, PartialEq)]
 pub enum CreateOpenIDConnectProviderError {
@@ -12290,6 +12416,16 @@ impl fmt::Display for CreateOpenIDConnectProviderError {
     }
 }
 impl Error for CreateOpenIDConnectProviderError {}
+impl ServiceErrorCode for CreateOpenIDConnectProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateOpenIDConnectProviderError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            CreateOpenIDConnectProviderError::InvalidInput(_) => "InvalidInput",
+            CreateOpenIDConnectProviderError::LimitExceeded(_) => "LimitExceeded",
+            CreateOpenIDConnectProviderError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by CreatePolicy
 #[derive(Debug, PartialEq)]
 pub enum CreatePolicyError {
@@ -12366,6 +12502,17 @@ impl fmt::Display for CreatePolicyError {
     }
 }
 impl Error for CreatePolicyError {}
+impl ServiceErrorCode for CreatePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePolicyError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            CreatePolicyError::InvalidInput(_) => "InvalidInput",
+            CreatePolicyError::LimitExceeded(_) => "LimitExceeded",
+            CreatePolicyError::MalformedPolicyDocument(_) => "MalformedPolicyDocument",
+            CreatePolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by CreatePolicyVersion
 #[derive(Debug, PartialEq)]
 pub enum CreatePolicyVersionError {
@@ -12442,6 +12589,17 @@ impl fmt::Display for CreatePolicyVersionError {
     }
 }
 impl Error for CreatePolicyVersionError {}
+impl ServiceErrorCode for CreatePolicyVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePolicyVersionError::InvalidInput(_) => "InvalidInput",
+            CreatePolicyVersionError::LimitExceeded(_) => "LimitExceeded",
+            CreatePolicyVersionError::MalformedPolicyDocument(_) => "MalformedPolicyDocument",
+            CreatePolicyVersionError::NoSuchEntity(_) => "NoSuchEntity",
+            CreatePolicyVersionError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by CreateRole
 #[derive(Debug, PartialEq)]
 pub enum CreateRoleError {
@@ -12526,6 +12684,18 @@ impl fmt::Display for CreateRoleError {
     }
 }
 impl Error for CreateRoleError {}
+impl ServiceErrorCode for CreateRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRoleError::ConcurrentModification(_) => "ConcurrentModification",
+            CreateRoleError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            CreateRoleError::InvalidInput(_) => "InvalidInput",
+            CreateRoleError::LimitExceeded(_) => "LimitExceeded",
+            CreateRoleError::MalformedPolicyDocument(_) => "MalformedPolicyDocument",
+            CreateRoleError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by CreateSAMLProvider
 #[derive(Debug, PartialEq)]
 pub enum CreateSAMLProviderError {
@@ -12594,6 +12764,16 @@ impl fmt::Display for CreateSAMLProviderError {
     }
 }
 impl Error for CreateSAMLProviderError {}
+impl ServiceErrorCode for CreateSAMLProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSAMLProviderError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            CreateSAMLProviderError::InvalidInput(_) => "InvalidInput",
+            CreateSAMLProviderError::LimitExceeded(_) => "LimitExceeded",
+            CreateSAMLProviderError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by CreateServiceLinkedRole
 #[derive(Debug, PartialEq)]
 pub enum CreateServiceLinkedRoleError {
@@ -12662,6 +12842,16 @@ impl fmt::Display for CreateServiceLinkedRoleError {
     }
 }
 impl Error for CreateServiceLinkedRoleError {}
+impl ServiceErrorCode for CreateServiceLinkedRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateServiceLinkedRoleError::InvalidInput(_) => "InvalidInput",
+            CreateServiceLinkedRoleError::LimitExceeded(_) => "LimitExceeded",
+            CreateServiceLinkedRoleError::NoSuchEntity(_) => "NoSuchEntity",
+            CreateServiceLinkedRoleError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by CreateServiceSpecificCredential
 #[derive(Debug, PartialEq)]
 pub enum CreateServiceSpecificCredentialError {
@@ -12734,6 +12924,15 @@ impl fmt::Display for CreateServiceSpecificCredentialError {
     }
 }
 impl Error for CreateServiceSpecificCredentialError {}
+impl ServiceErrorCode for CreateServiceSpecificCredentialError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateServiceSpecificCredentialError::LimitExceeded(_) => "LimitExceeded",
+            CreateServiceSpecificCredentialError::NoSuchEntity(_) => "NoSuchEntity",
+            CreateServiceSpecificCredentialError::ServiceNotSupported(_) => "NotSupportedService",
+        }
+    }
+}
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
 pub enum CreateUserError {
@@ -12818,6 +13017,18 @@ impl fmt::Display for CreateUserError {
     }
 }
 impl Error for CreateUserError {}
+impl ServiceErrorCode for CreateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserError::ConcurrentModification(_) => "ConcurrentModification",
+            CreateUserError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            CreateUserError::InvalidInput(_) => "InvalidInput",
+            CreateUserError::LimitExceeded(_) => "LimitExceeded",
+            CreateUserError::NoSuchEntity(_) => "NoSuchEntity",
+            CreateUserError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by CreateVirtualMFADevice
 #[derive(Debug, PartialEq)]
 pub enum CreateVirtualMFADeviceError {
@@ -12878,6 +13089,15 @@ impl fmt::Display for CreateVirtualMFADeviceError {
     }
 }
 impl Error for CreateVirtualMFADeviceError {}
+impl ServiceErrorCode for CreateVirtualMFADeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVirtualMFADeviceError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            CreateVirtualMFADeviceError::LimitExceeded(_) => "LimitExceeded",
+            CreateVirtualMFADeviceError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeactivateMFADevice
 #[derive(Debug, PartialEq)]
 pub enum DeactivateMFADeviceError {
@@ -12950,6 +13170,18 @@ impl fmt::Display for DeactivateMFADeviceError {
     }
 }
 impl Error for DeactivateMFADeviceError {}
+impl ServiceErrorCode for DeactivateMFADeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeactivateMFADeviceError::EntityTemporarilyUnmodifiable(_) => {
+                "EntityTemporarilyUnmodifiable"
+            }
+            DeactivateMFADeviceError::LimitExceeded(_) => "LimitExceeded",
+            DeactivateMFADeviceError::NoSuchEntity(_) => "NoSuchEntity",
+            DeactivateMFADeviceError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteAccessKey
 #[derive(Debug, PartialEq)]
 pub enum DeleteAccessKeyError {
@@ -13010,6 +13242,15 @@ impl fmt::Display for DeleteAccessKeyError {
     }
 }
 impl Error for DeleteAccessKeyError {}
+impl ServiceErrorCode for DeleteAccessKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAccessKeyError::LimitExceeded(_) => "LimitExceeded",
+            DeleteAccessKeyError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteAccessKeyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteAccountAlias
 #[derive(Debug, PartialEq)]
 pub enum DeleteAccountAliasError {
@@ -13070,6 +13311,15 @@ impl fmt::Display for DeleteAccountAliasError {
     }
 }
 impl Error for DeleteAccountAliasError {}
+impl ServiceErrorCode for DeleteAccountAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAccountAliasError::LimitExceeded(_) => "LimitExceeded",
+            DeleteAccountAliasError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteAccountAliasError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteAccountPasswordPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteAccountPasswordPolicyError {
@@ -13132,6 +13382,15 @@ impl fmt::Display for DeleteAccountPasswordPolicyError {
     }
 }
 impl Error for DeleteAccountPasswordPolicyError {}
+impl ServiceErrorCode for DeleteAccountPasswordPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAccountPasswordPolicyError::LimitExceeded(_) => "LimitExceeded",
+            DeleteAccountPasswordPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteAccountPasswordPolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteGroupError {
@@ -13200,6 +13459,16 @@ impl fmt::Display for DeleteGroupError {
     }
 }
 impl Error for DeleteGroupError {}
+impl ServiceErrorCode for DeleteGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGroupError::DeleteConflict(_) => "DeleteConflict",
+            DeleteGroupError::LimitExceeded(_) => "LimitExceeded",
+            DeleteGroupError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteGroupError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteGroupPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteGroupPolicyError {
@@ -13260,6 +13529,15 @@ impl fmt::Display for DeleteGroupPolicyError {
     }
 }
 impl Error for DeleteGroupPolicyError {}
+impl ServiceErrorCode for DeleteGroupPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGroupPolicyError::LimitExceeded(_) => "LimitExceeded",
+            DeleteGroupPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteGroupPolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteInstanceProfile
 #[derive(Debug, PartialEq)]
 pub enum DeleteInstanceProfileError {
@@ -13328,6 +13606,16 @@ impl fmt::Display for DeleteInstanceProfileError {
     }
 }
 impl Error for DeleteInstanceProfileError {}
+impl ServiceErrorCode for DeleteInstanceProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInstanceProfileError::DeleteConflict(_) => "DeleteConflict",
+            DeleteInstanceProfileError::LimitExceeded(_) => "LimitExceeded",
+            DeleteInstanceProfileError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteInstanceProfileError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteLoginProfile
 #[derive(Debug, PartialEq)]
 pub enum DeleteLoginProfileError {
@@ -13400,6 +13688,18 @@ impl fmt::Display for DeleteLoginProfileError {
     }
 }
 impl Error for DeleteLoginProfileError {}
+impl ServiceErrorCode for DeleteLoginProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLoginProfileError::EntityTemporarilyUnmodifiable(_) => {
+                "EntityTemporarilyUnmodifiable"
+            }
+            DeleteLoginProfileError::LimitExceeded(_) => "LimitExceeded",
+            DeleteLoginProfileError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteLoginProfileError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteOpenIDConnectProvider
 #[derive(Debug, PartialEq)]
 pub enum DeleteOpenIDConnectProviderError {
@@ -13462,6 +13762,15 @@ impl fmt::Display for DeleteOpenIDConnectProviderError {
     }
 }
 impl Error for DeleteOpenIDConnectProviderError {}
+impl ServiceErrorCode for DeleteOpenIDConnectProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteOpenIDConnectProviderError::InvalidInput(_) => "InvalidInput",
+            DeleteOpenIDConnectProviderError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteOpenIDConnectProviderError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeletePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeletePolicyError {
@@ -13538,6 +13847,17 @@ impl fmt::Display for DeletePolicyError {
     }
 }
 impl Error for DeletePolicyError {}
+impl ServiceErrorCode for DeletePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePolicyError::DeleteConflict(_) => "DeleteConflict",
+            DeletePolicyError::InvalidInput(_) => "InvalidInput",
+            DeletePolicyError::LimitExceeded(_) => "LimitExceeded",
+            DeletePolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            DeletePolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeletePolicyVersion
 #[derive(Debug, PartialEq)]
 pub enum DeletePolicyVersionError {
@@ -13614,6 +13934,17 @@ impl fmt::Display for DeletePolicyVersionError {
     }
 }
 impl Error for DeletePolicyVersionError {}
+impl ServiceErrorCode for DeletePolicyVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePolicyVersionError::DeleteConflict(_) => "DeleteConflict",
+            DeletePolicyVersionError::InvalidInput(_) => "InvalidInput",
+            DeletePolicyVersionError::LimitExceeded(_) => "LimitExceeded",
+            DeletePolicyVersionError::NoSuchEntity(_) => "NoSuchEntity",
+            DeletePolicyVersionError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteRole
 #[derive(Debug, PartialEq)]
 pub enum DeleteRoleError {
@@ -13698,6 +14029,18 @@ impl fmt::Display for DeleteRoleError {
     }
 }
 impl Error for DeleteRoleError {}
+impl ServiceErrorCode for DeleteRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRoleError::ConcurrentModification(_) => "ConcurrentModification",
+            DeleteRoleError::DeleteConflict(_) => "DeleteConflict",
+            DeleteRoleError::LimitExceeded(_) => "LimitExceeded",
+            DeleteRoleError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteRoleError::ServiceFailure(_) => "ServiceFailure",
+            DeleteRoleError::UnmodifiableEntity(_) => "UnmodifiableEntity",
+        }
+    }
+}
 /// Errors returned by DeleteRolePermissionsBoundary
 #[derive(Debug, PartialEq)]
 pub enum DeleteRolePermissionsBoundaryError {
@@ -13766,6 +14109,15 @@ impl fmt::Display for DeleteRolePermissionsBoundaryError {
     }
 }
 impl Error for DeleteRolePermissionsBoundaryError {}
+impl ServiceErrorCode for DeleteRolePermissionsBoundaryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRolePermissionsBoundaryError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteRolePermissionsBoundaryError::ServiceFailure(_) => "ServiceFailure",
+            DeleteRolePermissionsBoundaryError::UnmodifiableEntity(_) => "UnmodifiableEntity",
+        }
+    }
+}
 /// Errors returned by DeleteRolePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteRolePolicyError {
@@ -13834,6 +14186,16 @@ impl fmt::Display for DeleteRolePolicyError {
     }
 }
 impl Error for DeleteRolePolicyError {}
+impl ServiceErrorCode for DeleteRolePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRolePolicyError::LimitExceeded(_) => "LimitExceeded",
+            DeleteRolePolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteRolePolicyError::ServiceFailure(_) => "ServiceFailure",
+            DeleteRolePolicyError::UnmodifiableEntity(_) => "UnmodifiableEntity",
+        }
+    }
+}
 /// Errors returned by DeleteSAMLProvider
 #[derive(Debug, PartialEq)]
 pub enum DeleteSAMLProviderError {
@@ -13902,6 +14264,16 @@ impl fmt::Display for DeleteSAMLProviderError {
     }
 }
 impl Error for DeleteSAMLProviderError {}
+impl ServiceErrorCode for DeleteSAMLProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSAMLProviderError::InvalidInput(_) => "InvalidInput",
+            DeleteSAMLProviderError::LimitExceeded(_) => "LimitExceeded",
+            DeleteSAMLProviderError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteSAMLProviderError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteSSHPublicKey
 #[derive(Debug, PartialEq)]
 pub enum DeleteSSHPublicKeyError {
@@ -13946,6 +14318,13 @@ impl fmt::Display for DeleteSSHPublicKeyError {
     }
 }
 impl Error for DeleteSSHPublicKeyError {}
+impl ServiceErrorCode for DeleteSSHPublicKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSSHPublicKeyError::NoSuchEntity(_) => "NoSuchEntity",
+        }
+    }
+}
 /// Errors returned by DeleteServerCertificate
 #[derive(Debug, PartialEq)]
 pub enum DeleteServerCertificateError {
@@ -14014,6 +14393,16 @@ impl fmt::Display for DeleteServerCertificateError {
     }
 }
 impl Error for DeleteServerCertificateError {}
+impl ServiceErrorCode for DeleteServerCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteServerCertificateError::DeleteConflict(_) => "DeleteConflict",
+            DeleteServerCertificateError::LimitExceeded(_) => "LimitExceeded",
+            DeleteServerCertificateError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteServerCertificateError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteServiceLinkedRole
 #[derive(Debug, PartialEq)]
 pub enum DeleteServiceLinkedRoleError {
@@ -14074,6 +14463,15 @@ impl fmt::Display for DeleteServiceLinkedRoleError {
     }
 }
 impl Error for DeleteServiceLinkedRoleError {}
+impl ServiceErrorCode for DeleteServiceLinkedRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteServiceLinkedRoleError::LimitExceeded(_) => "LimitExceeded",
+            DeleteServiceLinkedRoleError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteServiceLinkedRoleError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteServiceSpecificCredential
 #[derive(Debug, PartialEq)]
 pub enum DeleteServiceSpecificCredentialError {
@@ -14122,6 +14520,13 @@ impl fmt::Display for DeleteServiceSpecificCredentialError {
     }
 }
 impl Error for DeleteServiceSpecificCredentialError {}
+impl ServiceErrorCode for DeleteServiceSpecificCredentialError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteServiceSpecificCredentialError::NoSuchEntity(_) => "NoSuchEntity",
+        }
+    }
+}
 /// Errors returned by DeleteSigningCertificate
 #[derive(Debug, PartialEq)]
 pub enum DeleteSigningCertificateError {
@@ -14182,6 +14587,15 @@ impl fmt::Display for DeleteSigningCertificateError {
     }
 }
 impl Error for DeleteSigningCertificateError {}
+impl ServiceErrorCode for DeleteSigningCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSigningCertificateError::LimitExceeded(_) => "LimitExceeded",
+            DeleteSigningCertificateError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteSigningCertificateError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserError {
@@ -14258,6 +14672,17 @@ impl fmt::Display for DeleteUserError {
     }
 }
 impl Error for DeleteUserError {}
+impl ServiceErrorCode for DeleteUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserError::ConcurrentModification(_) => "ConcurrentModification",
+            DeleteUserError::DeleteConflict(_) => "DeleteConflict",
+            DeleteUserError::LimitExceeded(_) => "LimitExceeded",
+            DeleteUserError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteUserError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteUserPermissionsBoundary
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserPermissionsBoundaryError {
@@ -14314,6 +14739,14 @@ impl fmt::Display for DeleteUserPermissionsBoundaryError {
     }
 }
 impl Error for DeleteUserPermissionsBoundaryError {}
+impl ServiceErrorCode for DeleteUserPermissionsBoundaryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserPermissionsBoundaryError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteUserPermissionsBoundaryError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteUserPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserPolicyError {
@@ -14374,6 +14807,15 @@ impl fmt::Display for DeleteUserPolicyError {
     }
 }
 impl Error for DeleteUserPolicyError {}
+impl ServiceErrorCode for DeleteUserPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserPolicyError::LimitExceeded(_) => "LimitExceeded",
+            DeleteUserPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteUserPolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DeleteVirtualMFADevice
 #[derive(Debug, PartialEq)]
 pub enum DeleteVirtualMFADeviceError {
@@ -14442,6 +14884,16 @@ impl fmt::Display for DeleteVirtualMFADeviceError {
     }
 }
 impl Error for DeleteVirtualMFADeviceError {}
+impl ServiceErrorCode for DeleteVirtualMFADeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVirtualMFADeviceError::DeleteConflict(_) => "DeleteConflict",
+            DeleteVirtualMFADeviceError::LimitExceeded(_) => "LimitExceeded",
+            DeleteVirtualMFADeviceError::NoSuchEntity(_) => "NoSuchEntity",
+            DeleteVirtualMFADeviceError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DetachGroupPolicy
 #[derive(Debug, PartialEq)]
 pub enum DetachGroupPolicyError {
@@ -14510,6 +14962,16 @@ impl fmt::Display for DetachGroupPolicyError {
     }
 }
 impl Error for DetachGroupPolicyError {}
+impl ServiceErrorCode for DetachGroupPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachGroupPolicyError::InvalidInput(_) => "InvalidInput",
+            DetachGroupPolicyError::LimitExceeded(_) => "LimitExceeded",
+            DetachGroupPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            DetachGroupPolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by DetachRolePolicy
 #[derive(Debug, PartialEq)]
 pub enum DetachRolePolicyError {
@@ -14586,6 +15048,17 @@ impl fmt::Display for DetachRolePolicyError {
     }
 }
 impl Error for DetachRolePolicyError {}
+impl ServiceErrorCode for DetachRolePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachRolePolicyError::InvalidInput(_) => "InvalidInput",
+            DetachRolePolicyError::LimitExceeded(_) => "LimitExceeded",
+            DetachRolePolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            DetachRolePolicyError::ServiceFailure(_) => "ServiceFailure",
+            DetachRolePolicyError::UnmodifiableEntity(_) => "UnmodifiableEntity",
+        }
+    }
+}
 /// Errors returned by DetachUserPolicy
 #[derive(Debug, PartialEq)]
 pub enum DetachUserPolicyError {
@@ -14654,6 +15127,16 @@ impl fmt::Display for DetachUserPolicyError {
     }
 }
 impl Error for DetachUserPolicyError {}
+impl ServiceErrorCode for DetachUserPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachUserPolicyError::InvalidInput(_) => "InvalidInput",
+            DetachUserPolicyError::LimitExceeded(_) => "LimitExceeded",
+            DetachUserPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            DetachUserPolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by EnableMFADevice
 #[derive(Debug, PartialEq)]
 pub enum EnableMFADeviceError {
@@ -14742,6 +15225,20 @@ impl fmt::Display for EnableMFADeviceError {
     }
 }
 impl Error for EnableMFADeviceError {}
+impl ServiceErrorCode for EnableMFADeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableMFADeviceError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            EnableMFADeviceError::EntityTemporarilyUnmodifiable(_) => {
+                "EntityTemporarilyUnmodifiable"
+            }
+            EnableMFADeviceError::InvalidAuthenticationCode(_) => "InvalidAuthenticationCode",
+            EnableMFADeviceError::LimitExceeded(_) => "LimitExceeded",
+            EnableMFADeviceError::NoSuchEntity(_) => "NoSuchEntity",
+            EnableMFADeviceError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GenerateCredentialReport
 #[derive(Debug, PartialEq)]
 pub enum GenerateCredentialReportError {
@@ -14794,6 +15291,14 @@ impl fmt::Display for GenerateCredentialReportError {
     }
 }
 impl Error for GenerateCredentialReportError {}
+impl ServiceErrorCode for GenerateCredentialReportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GenerateCredentialReportError::LimitExceeded(_) => "LimitExceeded",
+            GenerateCredentialReportError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GenerateOrganizationsAccessReport
 #[derive(Debug, PartialEq)]
 pub enum GenerateOrganizationsAccessReportError {
@@ -14844,6 +15349,15 @@ impl fmt::Display for GenerateOrganizationsAccessReportError {
     }
 }
 impl Error for GenerateOrganizationsAccessReportError {}
+impl ServiceErrorCode for GenerateOrganizationsAccessReportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GenerateOrganizationsAccessReportError::ReportGenerationLimitExceeded(_) => {
+                "ReportGenerationLimitExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by GenerateServiceLastAccessedDetails
 #[derive(Debug, PartialEq)]
 pub enum GenerateServiceLastAccessedDetailsError {
@@ -14906,6 +15420,14 @@ impl fmt::Display for GenerateServiceLastAccessedDetailsError {
     }
 }
 impl Error for GenerateServiceLastAccessedDetailsError {}
+impl ServiceErrorCode for GenerateServiceLastAccessedDetailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GenerateServiceLastAccessedDetailsError::InvalidInput(_) => "InvalidInput",
+            GenerateServiceLastAccessedDetailsError::NoSuchEntity(_) => "NoSuchEntity",
+        }
+    }
+}
 /// Errors returned by GetAccessKeyLastUsed
 #[derive(Debug, PartialEq)]
 pub enum GetAccessKeyLastUsedError {}
@@ -14940,6 +15462,11 @@ impl fmt::Display for GetAccessKeyLastUsedError {
     }
 }
 impl Error for GetAccessKeyLastUsedError {}
+impl ServiceErrorCode for GetAccessKeyLastUsedError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetAccountAuthorizationDetails
 #[derive(Debug, PartialEq)]
 pub enum GetAccountAuthorizationDetailsError {
@@ -14990,6 +15517,13 @@ impl fmt::Display for GetAccountAuthorizationDetailsError {
     }
 }
 impl Error for GetAccountAuthorizationDetailsError {}
+impl ServiceErrorCode for GetAccountAuthorizationDetailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAccountAuthorizationDetailsError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetAccountPasswordPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetAccountPasswordPolicyError {
@@ -15042,6 +15576,14 @@ impl fmt::Display for GetAccountPasswordPolicyError {
     }
 }
 impl Error for GetAccountPasswordPolicyError {}
+impl ServiceErrorCode for GetAccountPasswordPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAccountPasswordPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            GetAccountPasswordPolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetAccountSummary
 #[derive(Debug, PartialEq)]
 pub enum GetAccountSummaryError {
@@ -15086,6 +15628,13 @@ impl fmt::Display for GetAccountSummaryError {
     }
 }
 impl Error for GetAccountSummaryError {}
+impl ServiceErrorCode for GetAccountSummaryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAccountSummaryError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetContextKeysForCustomPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetContextKeysForCustomPolicyError {
@@ -15132,6 +15681,13 @@ impl fmt::Display for GetContextKeysForCustomPolicyError {
     }
 }
 impl Error for GetContextKeysForCustomPolicyError {}
+impl ServiceErrorCode for GetContextKeysForCustomPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContextKeysForCustomPolicyError::InvalidInput(_) => "InvalidInput",
+        }
+    }
+}
 /// Errors returned by GetContextKeysForPrincipalPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetContextKeysForPrincipalPolicyError {
@@ -15194,6 +15750,14 @@ impl fmt::Display for GetContextKeysForPrincipalPolicyError {
     }
 }
 impl Error for GetContextKeysForPrincipalPolicyError {}
+impl ServiceErrorCode for GetContextKeysForPrincipalPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContextKeysForPrincipalPolicyError::InvalidInput(_) => "InvalidInput",
+            GetContextKeysForPrincipalPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+        }
+    }
+}
 /// Errors returned by GetCredentialReport
 #[derive(Debug, PartialEq)]
 pub enum GetCredentialReportError {
@@ -15268,6 +15832,16 @@ impl fmt::Display for GetCredentialReportError {
     }
 }
 impl Error for GetCredentialReportError {}
+impl ServiceErrorCode for GetCredentialReportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCredentialReportError::CredentialReportExpired(_) => "ReportExpired",
+            GetCredentialReportError::CredentialReportNotPresent(_) => "ReportNotPresent",
+            GetCredentialReportError::CredentialReportNotReady(_) => "ReportInProgress",
+            GetCredentialReportError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetGroup
 #[derive(Debug, PartialEq)]
 pub enum GetGroupError {
@@ -15320,6 +15894,14 @@ impl fmt::Display for GetGroupError {
     }
 }
 impl Error for GetGroupError {}
+impl ServiceErrorCode for GetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGroupError::NoSuchEntity(_) => "NoSuchEntity",
+            GetGroupError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetGroupPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetGroupPolicyError {
@@ -15372,6 +15954,14 @@ impl fmt::Display for GetGroupPolicyError {
     }
 }
 impl Error for GetGroupPolicyError {}
+impl ServiceErrorCode for GetGroupPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGroupPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            GetGroupPolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetInstanceProfile
 #[derive(Debug, PartialEq)]
 pub enum GetInstanceProfileError {
@@ -15424,6 +16014,14 @@ impl fmt::Display for GetInstanceProfileError {
     }
 }
 impl Error for GetInstanceProfileError {}
+impl ServiceErrorCode for GetInstanceProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInstanceProfileError::NoSuchEntity(_) => "NoSuchEntity",
+            GetInstanceProfileError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetLoginProfile
 #[derive(Debug, PartialEq)]
 pub enum GetLoginProfileError {
@@ -15476,6 +16074,14 @@ impl fmt::Display for GetLoginProfileError {
     }
 }
 impl Error for GetLoginProfileError {}
+impl ServiceErrorCode for GetLoginProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLoginProfileError::NoSuchEntity(_) => "NoSuchEntity",
+            GetLoginProfileError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetOpenIDConnectProvider
 #[derive(Debug, PartialEq)]
 pub enum GetOpenIDConnectProviderError {
@@ -15536,6 +16142,15 @@ impl fmt::Display for GetOpenIDConnectProviderError {
     }
 }
 impl Error for GetOpenIDConnectProviderError {}
+impl ServiceErrorCode for GetOpenIDConnectProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOpenIDConnectProviderError::InvalidInput(_) => "InvalidInput",
+            GetOpenIDConnectProviderError::NoSuchEntity(_) => "NoSuchEntity",
+            GetOpenIDConnectProviderError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetOrganizationsAccessReport
 #[derive(Debug, PartialEq)]
 pub enum GetOrganizationsAccessReportError {
@@ -15582,6 +16197,13 @@ impl fmt::Display for GetOrganizationsAccessReportError {
     }
 }
 impl Error for GetOrganizationsAccessReportError {}
+impl ServiceErrorCode for GetOrganizationsAccessReportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOrganizationsAccessReportError::NoSuchEntity(_) => "NoSuchEntity",
+        }
+    }
+}
 /// Errors returned by GetPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetPolicyError {
@@ -15642,6 +16264,15 @@ impl fmt::Display for GetPolicyError {
     }
 }
 impl Error for GetPolicyError {}
+impl ServiceErrorCode for GetPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPolicyError::InvalidInput(_) => "InvalidInput",
+            GetPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            GetPolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetPolicyVersion
 #[derive(Debug, PartialEq)]
 pub enum GetPolicyVersionError {
@@ -15702,6 +16333,15 @@ impl fmt::Display for GetPolicyVersionError {
     }
 }
 impl Error for GetPolicyVersionError {}
+impl ServiceErrorCode for GetPolicyVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPolicyVersionError::InvalidInput(_) => "InvalidInput",
+            GetPolicyVersionError::NoSuchEntity(_) => "NoSuchEntity",
+            GetPolicyVersionError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetRole
 #[derive(Debug, PartialEq)]
 pub enum GetRoleError {
@@ -15754,6 +16394,14 @@ impl fmt::Display for GetRoleError {
     }
 }
 impl Error for GetRoleError {}
+impl ServiceErrorCode for GetRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRoleError::NoSuchEntity(_) => "NoSuchEntity",
+            GetRoleError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetRolePolicy
 #[derive(Debug, PartialEq)]
 pub enum GetRolePolicyError {
@@ -15806,6 +16454,14 @@ impl fmt::Display for GetRolePolicyError {
     }
 }
 impl Error for GetRolePolicyError {}
+impl ServiceErrorCode for GetRolePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRolePolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            GetRolePolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetSAMLProvider
 #[derive(Debug, PartialEq)]
 pub enum GetSAMLProviderError {
@@ -15866,6 +16522,15 @@ impl fmt::Display for GetSAMLProviderError {
     }
 }
 impl Error for GetSAMLProviderError {}
+impl ServiceErrorCode for GetSAMLProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSAMLProviderError::InvalidInput(_) => "InvalidInput",
+            GetSAMLProviderError::NoSuchEntity(_) => "NoSuchEntity",
+            GetSAMLProviderError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetSSHPublicKey
 #[derive(Debug, PartialEq)]
 pub enum GetSSHPublicKeyError {
@@ -15922,6 +16587,16 @@ impl fmt::Display for GetSSHPublicKeyError {
     }
 }
 impl Error for GetSSHPublicKeyError {}
+impl ServiceErrorCode for GetSSHPublicKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSSHPublicKeyError::NoSuchEntity(_) => "NoSuchEntity",
+            GetSSHPublicKeyError::UnrecognizedPublicKeyEncoding(_) => {
+                "UnrecognizedPublicKeyEncoding"
+            }
+        }
+    }
+}
 /// Errors returned by GetServerCertificate
 #[derive(Debug, PartialEq)]
 pub enum GetServerCertificateError {
@@ -15974,6 +16649,14 @@ impl fmt::Display for GetServerCertificateError {
     }
 }
 impl Error for GetServerCertificateError {}
+impl ServiceErrorCode for GetServerCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetServerCertificateError::NoSuchEntity(_) => "NoSuchEntity",
+            GetServerCertificateError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetServiceLastAccessedDetails
 #[derive(Debug, PartialEq)]
 pub enum GetServiceLastAccessedDetailsError {
@@ -16028,6 +16711,14 @@ impl fmt::Display for GetServiceLastAccessedDetailsError {
     }
 }
 impl Error for GetServiceLastAccessedDetailsError {}
+impl ServiceErrorCode for GetServiceLastAccessedDetailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetServiceLastAccessedDetailsError::InvalidInput(_) => "InvalidInput",
+            GetServiceLastAccessedDetailsError::NoSuchEntity(_) => "NoSuchEntity",
+        }
+    }
+}
 /// Errors returned by GetServiceLastAccessedDetailsWithEntities
 #[derive(Debug, PartialEq)]
 pub enum GetServiceLastAccessedDetailsWithEntitiesError {
@@ -16090,6 +16781,14 @@ impl fmt::Display for GetServiceLastAccessedDetailsWithEntitiesError {
     }
 }
 impl Error for GetServiceLastAccessedDetailsWithEntitiesError {}
+impl ServiceErrorCode for GetServiceLastAccessedDetailsWithEntitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetServiceLastAccessedDetailsWithEntitiesError::InvalidInput(_) => "InvalidInput",
+            GetServiceLastAccessedDetailsWithEntitiesError::NoSuchEntity(_) => "NoSuchEntity",
+        }
+    }
+}
 /// Errors returned by GetServiceLinkedRoleDeletionStatus
 #[derive(Debug, PartialEq)]
 pub enum GetServiceLinkedRoleDeletionStatusError {
@@ -16164,6 +16863,15 @@ impl fmt::Display for GetServiceLinkedRoleDeletionStatusError {
     }
 }
 impl Error for GetServiceLinkedRoleDeletionStatusError {}
+impl ServiceErrorCode for GetServiceLinkedRoleDeletionStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetServiceLinkedRoleDeletionStatusError::InvalidInput(_) => "InvalidInput",
+            GetServiceLinkedRoleDeletionStatusError::NoSuchEntity(_) => "NoSuchEntity",
+            GetServiceLinkedRoleDeletionStatusError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetUser
 #[derive(Debug, PartialEq)]
 pub enum GetUserError {
@@ -16216,6 +16924,14 @@ impl fmt::Display for GetUserError {
     }
 }
 impl Error for GetUserError {}
+impl ServiceErrorCode for GetUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUserError::NoSuchEntity(_) => "NoSuchEntity",
+            GetUserError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by GetUserPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetUserPolicyError {
@@ -16268,6 +16984,14 @@ impl fmt::Display for GetUserPolicyError {
     }
 }
 impl Error for GetUserPolicyError {}
+impl ServiceErrorCode for GetUserPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUserPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            GetUserPolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListAccessKeys
 #[derive(Debug, PartialEq)]
 pub enum ListAccessKeysError {
@@ -16320,6 +17044,14 @@ impl fmt::Display for ListAccessKeysError {
     }
 }
 impl Error for ListAccessKeysError {}
+impl ServiceErrorCode for ListAccessKeysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAccessKeysError::NoSuchEntity(_) => "NoSuchEntity",
+            ListAccessKeysError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListAccountAliases
 #[derive(Debug, PartialEq)]
 pub enum ListAccountAliasesError {
@@ -16364,6 +17096,13 @@ impl fmt::Display for ListAccountAliasesError {
     }
 }
 impl Error for ListAccountAliasesError {}
+impl ServiceErrorCode for ListAccountAliasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAccountAliasesError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListAttachedGroupPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListAttachedGroupPoliciesError {
@@ -16424,6 +17163,15 @@ impl fmt::Display for ListAttachedGroupPoliciesError {
     }
 }
 impl Error for ListAttachedGroupPoliciesError {}
+impl ServiceErrorCode for ListAttachedGroupPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAttachedGroupPoliciesError::InvalidInput(_) => "InvalidInput",
+            ListAttachedGroupPoliciesError::NoSuchEntity(_) => "NoSuchEntity",
+            ListAttachedGroupPoliciesError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListAttachedRolePolicies
 #[derive(Debug, PartialEq)]
 pub enum ListAttachedRolePoliciesError {
@@ -16484,6 +17232,15 @@ impl fmt::Display for ListAttachedRolePoliciesError {
     }
 }
 impl Error for ListAttachedRolePoliciesError {}
+impl ServiceErrorCode for ListAttachedRolePoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAttachedRolePoliciesError::InvalidInput(_) => "InvalidInput",
+            ListAttachedRolePoliciesError::NoSuchEntity(_) => "NoSuchEntity",
+            ListAttachedRolePoliciesError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListAttachedUserPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListAttachedUserPoliciesError {
@@ -16544,6 +17301,15 @@ impl fmt::Display for ListAttachedUserPoliciesError {
     }
 }
 impl Error for ListAttachedUserPoliciesError {}
+impl ServiceErrorCode for ListAttachedUserPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAttachedUserPoliciesError::InvalidInput(_) => "InvalidInput",
+            ListAttachedUserPoliciesError::NoSuchEntity(_) => "NoSuchEntity",
+            ListAttachedUserPoliciesError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListEntitiesForPolicy
 #[derive(Debug, PartialEq)]
 pub enum ListEntitiesForPolicyError {
@@ -16604,6 +17370,15 @@ impl fmt::Display for ListEntitiesForPolicyError {
     }
 }
 impl Error for ListEntitiesForPolicyError {}
+impl ServiceErrorCode for ListEntitiesForPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEntitiesForPolicyError::InvalidInput(_) => "InvalidInput",
+            ListEntitiesForPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            ListEntitiesForPolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListGroupPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListGroupPoliciesError {
@@ -16656,6 +17431,14 @@ impl fmt::Display for ListGroupPoliciesError {
     }
 }
 impl Error for ListGroupPoliciesError {}
+impl ServiceErrorCode for ListGroupPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGroupPoliciesError::NoSuchEntity(_) => "NoSuchEntity",
+            ListGroupPoliciesError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListGroups
 #[derive(Debug, PartialEq)]
 pub enum ListGroupsError {
@@ -16700,6 +17483,13 @@ impl fmt::Display for ListGroupsError {
     }
 }
 impl Error for ListGroupsError {}
+impl ServiceErrorCode for ListGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGroupsError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListGroupsForUser
 #[derive(Debug, PartialEq)]
 pub enum ListGroupsForUserError {
@@ -16752,6 +17542,14 @@ impl fmt::Display for ListGroupsForUserError {
     }
 }
 impl Error for ListGroupsForUserError {}
+impl ServiceErrorCode for ListGroupsForUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGroupsForUserError::NoSuchEntity(_) => "NoSuchEntity",
+            ListGroupsForUserError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListInstanceProfiles
 #[derive(Debug, PartialEq)]
 pub enum ListInstanceProfilesError {
@@ -16796,6 +17594,13 @@ impl fmt::Display for ListInstanceProfilesError {
     }
 }
 impl Error for ListInstanceProfilesError {}
+impl ServiceErrorCode for ListInstanceProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInstanceProfilesError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListInstanceProfilesForRole
 #[derive(Debug, PartialEq)]
 pub enum ListInstanceProfilesForRoleError {
@@ -16850,6 +17655,14 @@ impl fmt::Display for ListInstanceProfilesForRoleError {
     }
 }
 impl Error for ListInstanceProfilesForRoleError {}
+impl ServiceErrorCode for ListInstanceProfilesForRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInstanceProfilesForRoleError::NoSuchEntity(_) => "NoSuchEntity",
+            ListInstanceProfilesForRoleError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListMFADevices
 #[derive(Debug, PartialEq)]
 pub enum ListMFADevicesError {
@@ -16902,6 +17715,14 @@ impl fmt::Display for ListMFADevicesError {
     }
 }
 impl Error for ListMFADevicesError {}
+impl ServiceErrorCode for ListMFADevicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMFADevicesError::NoSuchEntity(_) => "NoSuchEntity",
+            ListMFADevicesError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListOpenIDConnectProviders
 #[derive(Debug, PartialEq)]
 pub enum ListOpenIDConnectProvidersError {
@@ -16948,6 +17769,13 @@ impl fmt::Display for ListOpenIDConnectProvidersError {
     }
 }
 impl Error for ListOpenIDConnectProvidersError {}
+impl ServiceErrorCode for ListOpenIDConnectProvidersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOpenIDConnectProvidersError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListPoliciesError {
@@ -16992,6 +17820,13 @@ impl fmt::Display for ListPoliciesError {
     }
 }
 impl Error for ListPoliciesError {}
+impl ServiceErrorCode for ListPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPoliciesError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListPoliciesGrantingServiceAccess
 #[derive(Debug, PartialEq)]
 pub enum ListPoliciesGrantingServiceAccessError {
@@ -17054,6 +17889,14 @@ impl fmt::Display for ListPoliciesGrantingServiceAccessError {
     }
 }
 impl Error for ListPoliciesGrantingServiceAccessError {}
+impl ServiceErrorCode for ListPoliciesGrantingServiceAccessError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPoliciesGrantingServiceAccessError::InvalidInput(_) => "InvalidInput",
+            ListPoliciesGrantingServiceAccessError::NoSuchEntity(_) => "NoSuchEntity",
+        }
+    }
+}
 /// Errors returned by ListPolicyVersions
 #[derive(Debug, PartialEq)]
 pub enum ListPolicyVersionsError {
@@ -17114,6 +17957,15 @@ impl fmt::Display for ListPolicyVersionsError {
     }
 }
 impl Error for ListPolicyVersionsError {}
+impl ServiceErrorCode for ListPolicyVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPolicyVersionsError::InvalidInput(_) => "InvalidInput",
+            ListPolicyVersionsError::NoSuchEntity(_) => "NoSuchEntity",
+            ListPolicyVersionsError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListRolePolicies
 #[derive(Debug, PartialEq)]
 pub enum ListRolePoliciesError {
@@ -17166,6 +18018,14 @@ impl fmt::Display for ListRolePoliciesError {
     }
 }
 impl Error for ListRolePoliciesError {}
+impl ServiceErrorCode for ListRolePoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRolePoliciesError::NoSuchEntity(_) => "NoSuchEntity",
+            ListRolePoliciesError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListRoleTags
 #[derive(Debug, PartialEq)]
 pub enum ListRoleTagsError {
@@ -17218,6 +18078,14 @@ impl fmt::Display for ListRoleTagsError {
     }
 }
 impl Error for ListRoleTagsError {}
+impl ServiceErrorCode for ListRoleTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRoleTagsError::NoSuchEntity(_) => "NoSuchEntity",
+            ListRoleTagsError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListRoles
 #[derive(Debug, PartialEq)]
 pub enum ListRolesError {
@@ -17262,6 +18130,13 @@ impl fmt::Display for ListRolesError {
     }
 }
 impl Error for ListRolesError {}
+impl ServiceErrorCode for ListRolesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRolesError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListSAMLProviders
 #[derive(Debug, PartialEq)]
 pub enum ListSAMLProvidersError {
@@ -17306,6 +18181,13 @@ impl fmt::Display for ListSAMLProvidersError {
     }
 }
 impl Error for ListSAMLProvidersError {}
+impl ServiceErrorCode for ListSAMLProvidersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSAMLProvidersError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListSSHPublicKeys
 #[derive(Debug, PartialEq)]
 pub enum ListSSHPublicKeysError {
@@ -17350,6 +18232,13 @@ impl fmt::Display for ListSSHPublicKeysError {
     }
 }
 impl Error for ListSSHPublicKeysError {}
+impl ServiceErrorCode for ListSSHPublicKeysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSSHPublicKeysError::NoSuchEntity(_) => "NoSuchEntity",
+        }
+    }
+}
 /// Errors returned by ListServerCertificates
 #[derive(Debug, PartialEq)]
 pub enum ListServerCertificatesError {
@@ -17394,6 +18283,13 @@ impl fmt::Display for ListServerCertificatesError {
     }
 }
 impl Error for ListServerCertificatesError {}
+impl ServiceErrorCode for ListServerCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListServerCertificatesError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListServiceSpecificCredentials
 #[derive(Debug, PartialEq)]
 pub enum ListServiceSpecificCredentialsError {
@@ -17452,6 +18348,14 @@ impl fmt::Display for ListServiceSpecificCredentialsError {
     }
 }
 impl Error for ListServiceSpecificCredentialsError {}
+impl ServiceErrorCode for ListServiceSpecificCredentialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListServiceSpecificCredentialsError::NoSuchEntity(_) => "NoSuchEntity",
+            ListServiceSpecificCredentialsError::ServiceNotSupported(_) => "NotSupportedService",
+        }
+    }
+}
 /// Errors returned by ListSigningCertificates
 #[derive(Debug, PartialEq)]
 pub enum ListSigningCertificatesError {
@@ -17504,6 +18408,14 @@ impl fmt::Display for ListSigningCertificatesError {
     }
 }
 impl Error for ListSigningCertificatesError {}
+impl ServiceErrorCode for ListSigningCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSigningCertificatesError::NoSuchEntity(_) => "NoSuchEntity",
+            ListSigningCertificatesError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListUserPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListUserPoliciesError {
@@ -17556,6 +18468,14 @@ impl fmt::Display for ListUserPoliciesError {
     }
 }
 impl Error for ListUserPoliciesError {}
+impl ServiceErrorCode for ListUserPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUserPoliciesError::NoSuchEntity(_) => "NoSuchEntity",
+            ListUserPoliciesError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListUserTags
 #[derive(Debug, PartialEq)]
 pub enum ListUserTagsError {
@@ -17608,6 +18528,14 @@ impl fmt::Display for ListUserTagsError {
     }
 }
 impl Error for ListUserTagsError {}
+impl ServiceErrorCode for ListUserTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUserTagsError::NoSuchEntity(_) => "NoSuchEntity",
+            ListUserTagsError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListUsers
 #[derive(Debug, PartialEq)]
 pub enum ListUsersError {
@@ -17652,6 +18580,13 @@ impl fmt::Display for ListUsersError {
     }
 }
 impl Error for ListUsersError {}
+impl ServiceErrorCode for ListUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUsersError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ListVirtualMFADevices
 #[derive(Debug, PartialEq)]
 pub enum ListVirtualMFADevicesError {}
@@ -17686,6 +18621,11 @@ impl fmt::Display for ListVirtualMFADevicesError {
     }
 }
 impl Error for ListVirtualMFADevicesError {}
+impl ServiceErrorCode for ListVirtualMFADevicesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutGroupPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutGroupPolicyError {
@@ -17754,6 +18694,16 @@ impl fmt::Display for PutGroupPolicyError {
     }
 }
 impl Error for PutGroupPolicyError {}
+impl ServiceErrorCode for PutGroupPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutGroupPolicyError::LimitExceeded(_) => "LimitExceeded",
+            PutGroupPolicyError::MalformedPolicyDocument(_) => "MalformedPolicyDocument",
+            PutGroupPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            PutGroupPolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by PutRolePermissionsBoundary
 #[derive(Debug, PartialEq)]
 pub enum PutRolePermissionsBoundaryError {
@@ -17840,6 +18790,17 @@ impl fmt::Display for PutRolePermissionsBoundaryError {
     }
 }
 impl Error for PutRolePermissionsBoundaryError {}
+impl ServiceErrorCode for PutRolePermissionsBoundaryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRolePermissionsBoundaryError::InvalidInput(_) => "InvalidInput",
+            PutRolePermissionsBoundaryError::NoSuchEntity(_) => "NoSuchEntity",
+            PutRolePermissionsBoundaryError::PolicyNotAttachable(_) => "PolicyNotAttachable",
+            PutRolePermissionsBoundaryError::ServiceFailure(_) => "ServiceFailure",
+            PutRolePermissionsBoundaryError::UnmodifiableEntity(_) => "UnmodifiableEntity",
+        }
+    }
+}
 /// Errors returned by PutRolePolicy
 #[derive(Debug, PartialEq)]
 pub enum PutRolePolicyError {
@@ -17916,6 +18877,17 @@ impl fmt::Display for PutRolePolicyError {
     }
 }
 impl Error for PutRolePolicyError {}
+impl ServiceErrorCode for PutRolePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRolePolicyError::LimitExceeded(_) => "LimitExceeded",
+            PutRolePolicyError::MalformedPolicyDocument(_) => "MalformedPolicyDocument",
+            PutRolePolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            PutRolePolicyError::ServiceFailure(_) => "ServiceFailure",
+            PutRolePolicyError::UnmodifiableEntity(_) => "UnmodifiableEntity",
+        }
+    }
+}
 /// Errors returned by PutUserPermissionsBoundary
 #[derive(Debug, PartialEq)]
 pub enum PutUserPermissionsBoundaryError {
@@ -17990,6 +18962,16 @@ impl fmt::Display for PutUserPermissionsBoundaryError {
     }
 }
 impl Error for PutUserPermissionsBoundaryError {}
+impl ServiceErrorCode for PutUserPermissionsBoundaryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutUserPermissionsBoundaryError::InvalidInput(_) => "InvalidInput",
+            PutUserPermissionsBoundaryError::NoSuchEntity(_) => "NoSuchEntity",
+            PutUserPermissionsBoundaryError::PolicyNotAttachable(_) => "PolicyNotAttachable",
+            PutUserPermissionsBoundaryError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by PutUserPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutUserPolicyError {
@@ -18058,6 +19040,16 @@ impl fmt::Display for PutUserPolicyError {
     }
 }
 impl Error for PutUserPolicyError {}
+impl ServiceErrorCode for PutUserPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutUserPolicyError::LimitExceeded(_) => "LimitExceeded",
+            PutUserPolicyError::MalformedPolicyDocument(_) => "MalformedPolicyDocument",
+            PutUserPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            PutUserPolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by RemoveClientIDFromOpenIDConnectProvider
 #[derive(Debug, PartialEq)]
 pub enum RemoveClientIDFromOpenIDConnectProviderError {
@@ -18132,6 +19124,15 @@ impl fmt::Display for RemoveClientIDFromOpenIDConnectProviderError {
     }
 }
 impl Error for RemoveClientIDFromOpenIDConnectProviderError {}
+impl ServiceErrorCode for RemoveClientIDFromOpenIDConnectProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveClientIDFromOpenIDConnectProviderError::InvalidInput(_) => "InvalidInput",
+            RemoveClientIDFromOpenIDConnectProviderError::NoSuchEntity(_) => "NoSuchEntity",
+            RemoveClientIDFromOpenIDConnectProviderError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by RemoveRoleFromInstanceProfile
 #[derive(Debug, PartialEq)]
 pub enum RemoveRoleFromInstanceProfileError {
@@ -18208,6 +19209,16 @@ impl fmt::Display for RemoveRoleFromInstanceProfileError {
     }
 }
 impl Error for RemoveRoleFromInstanceProfileError {}
+impl ServiceErrorCode for RemoveRoleFromInstanceProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveRoleFromInstanceProfileError::LimitExceeded(_) => "LimitExceeded",
+            RemoveRoleFromInstanceProfileError::NoSuchEntity(_) => "NoSuchEntity",
+            RemoveRoleFromInstanceProfileError::ServiceFailure(_) => "ServiceFailure",
+            RemoveRoleFromInstanceProfileError::UnmodifiableEntity(_) => "UnmodifiableEntity",
+        }
+    }
+}
 /// Errors returned by RemoveUserFromGroup
 #[derive(Debug, PartialEq)]
 pub enum RemoveUserFromGroupError {
@@ -18268,6 +19279,15 @@ impl fmt::Display for RemoveUserFromGroupError {
     }
 }
 impl Error for RemoveUserFromGroupError {}
+impl ServiceErrorCode for RemoveUserFromGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveUserFromGroupError::LimitExceeded(_) => "LimitExceeded",
+            RemoveUserFromGroupError::NoSuchEntity(_) => "NoSuchEntity",
+            RemoveUserFromGroupError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by ResetServiceSpecificCredential
 #[derive(Debug, PartialEq)]
 pub enum ResetServiceSpecificCredentialError {
@@ -18314,6 +19334,13 @@ impl fmt::Display for ResetServiceSpecificCredentialError {
     }
 }
 impl Error for ResetServiceSpecificCredentialError {}
+impl ServiceErrorCode for ResetServiceSpecificCredentialError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetServiceSpecificCredentialError::NoSuchEntity(_) => "NoSuchEntity",
+        }
+    }
+}
 /// Errors returned by ResyncMFADevice
 #[derive(Debug, PartialEq)]
 pub enum ResyncMFADeviceError {
@@ -18382,6 +19409,16 @@ impl fmt::Display for ResyncMFADeviceError {
     }
 }
 impl Error for ResyncMFADeviceError {}
+impl ServiceErrorCode for ResyncMFADeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResyncMFADeviceError::InvalidAuthenticationCode(_) => "InvalidAuthenticationCode",
+            ResyncMFADeviceError::LimitExceeded(_) => "LimitExceeded",
+            ResyncMFADeviceError::NoSuchEntity(_) => "NoSuchEntity",
+            ResyncMFADeviceError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by SetDefaultPolicyVersion
 #[derive(Debug, PartialEq)]
 pub enum SetDefaultPolicyVersionError {
@@ -18450,6 +19487,16 @@ impl fmt::Display for SetDefaultPolicyVersionError {
     }
 }
 impl Error for SetDefaultPolicyVersionError {}
+impl ServiceErrorCode for SetDefaultPolicyVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetDefaultPolicyVersionError::InvalidInput(_) => "InvalidInput",
+            SetDefaultPolicyVersionError::LimitExceeded(_) => "LimitExceeded",
+            SetDefaultPolicyVersionError::NoSuchEntity(_) => "NoSuchEntity",
+            SetDefaultPolicyVersionError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by SetSecurityTokenServicePreferences
 #[derive(Debug, PartialEq)]
 pub enum SetSecurityTokenServicePreferencesError {
@@ -18500,6 +19547,13 @@ impl fmt::Display for SetSecurityTokenServicePreferencesError {
     }
 }
 impl Error for SetSecurityTokenServicePreferencesError {}
+impl ServiceErrorCode for SetSecurityTokenServicePreferencesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetSecurityTokenServicePreferencesError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by SimulateCustomPolicy
 #[derive(Debug, PartialEq)]
 pub enum SimulateCustomPolicyError {
@@ -18552,6 +19606,14 @@ impl fmt::Display for SimulateCustomPolicyError {
     }
 }
 impl Error for SimulateCustomPolicyError {}
+impl ServiceErrorCode for SimulateCustomPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SimulateCustomPolicyError::InvalidInput(_) => "InvalidInput",
+            SimulateCustomPolicyError::PolicyEvaluation(_) => "PolicyEvaluation",
+        }
+    }
+}
 /// Errors returned by SimulatePrincipalPolicy
 #[derive(Debug, PartialEq)]
 pub enum SimulatePrincipalPolicyError {
@@ -18612,6 +19674,15 @@ impl fmt::Display for SimulatePrincipalPolicyError {
     }
 }
 impl Error for SimulatePrincipalPolicyError {}
+impl ServiceErrorCode for SimulatePrincipalPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SimulatePrincipalPolicyError::InvalidInput(_) => "InvalidInput",
+            SimulatePrincipalPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            SimulatePrincipalPolicyError::PolicyEvaluation(_) => "PolicyEvaluation",
+        }
+    }
+}
 /// Errors returned by TagRole
 #[derive(Debug, PartialEq)]
 pub enum TagRoleError {
@@ -18688,6 +19759,17 @@ impl fmt::Display for TagRoleError {
     }
 }
 impl Error for TagRoleError {}
+impl ServiceErrorCode for TagRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagRoleError::ConcurrentModification(_) => "ConcurrentModification",
+            TagRoleError::InvalidInput(_) => "InvalidInput",
+            TagRoleError::LimitExceeded(_) => "LimitExceeded",
+            TagRoleError::NoSuchEntity(_) => "NoSuchEntity",
+            TagRoleError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by TagUser
 #[derive(Debug, PartialEq)]
 pub enum TagUserError {
@@ -18764,6 +19846,17 @@ impl fmt::Display for TagUserError {
     }
 }
 impl Error for TagUserError {}
+impl ServiceErrorCode for TagUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagUserError::ConcurrentModification(_) => "ConcurrentModification",
+            TagUserError::InvalidInput(_) => "InvalidInput",
+            TagUserError::LimitExceeded(_) => "LimitExceeded",
+            TagUserError::NoSuchEntity(_) => "NoSuchEntity",
+            TagUserError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by UntagRole
 #[derive(Debug, PartialEq)]
 pub enum UntagRoleError {
@@ -18824,6 +19917,15 @@ impl fmt::Display for UntagRoleError {
     }
 }
 impl Error for UntagRoleError {}
+impl ServiceErrorCode for UntagRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagRoleError::ConcurrentModification(_) => "ConcurrentModification",
+            UntagRoleError::NoSuchEntity(_) => "NoSuchEntity",
+            UntagRoleError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by UntagUser
 #[derive(Debug, PartialEq)]
 pub enum UntagUserError {
@@ -18884,6 +19986,15 @@ impl fmt::Display for UntagUserError {
     }
 }
 impl Error for UntagUserError {}
+impl ServiceErrorCode for UntagUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagUserError::ConcurrentModification(_) => "ConcurrentModification",
+            UntagUserError::NoSuchEntity(_) => "NoSuchEntity",
+            UntagUserError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by UpdateAccessKey
 #[derive(Debug, PartialEq)]
 pub enum UpdateAccessKeyError {
@@ -18944,6 +20055,15 @@ impl fmt::Display for UpdateAccessKeyError {
     }
 }
 impl Error for UpdateAccessKeyError {}
+impl ServiceErrorCode for UpdateAccessKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAccessKeyError::LimitExceeded(_) => "LimitExceeded",
+            UpdateAccessKeyError::NoSuchEntity(_) => "NoSuchEntity",
+            UpdateAccessKeyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by UpdateAccountPasswordPolicy
 #[derive(Debug, PartialEq)]
 pub enum UpdateAccountPasswordPolicyError {
@@ -19018,6 +20138,18 @@ impl fmt::Display for UpdateAccountPasswordPolicyError {
     }
 }
 impl Error for UpdateAccountPasswordPolicyError {}
+impl ServiceErrorCode for UpdateAccountPasswordPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAccountPasswordPolicyError::LimitExceeded(_) => "LimitExceeded",
+            UpdateAccountPasswordPolicyError::MalformedPolicyDocument(_) => {
+                "MalformedPolicyDocument"
+            }
+            UpdateAccountPasswordPolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            UpdateAccountPasswordPolicyError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by UpdateAssumeRolePolicy
 #[derive(Debug, PartialEq)]
 pub enum UpdateAssumeRolePolicyError {
@@ -19098,6 +20230,17 @@ impl fmt::Display for UpdateAssumeRolePolicyError {
     }
 }
 impl Error for UpdateAssumeRolePolicyError {}
+impl ServiceErrorCode for UpdateAssumeRolePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAssumeRolePolicyError::LimitExceeded(_) => "LimitExceeded",
+            UpdateAssumeRolePolicyError::MalformedPolicyDocument(_) => "MalformedPolicyDocument",
+            UpdateAssumeRolePolicyError::NoSuchEntity(_) => "NoSuchEntity",
+            UpdateAssumeRolePolicyError::ServiceFailure(_) => "ServiceFailure",
+            UpdateAssumeRolePolicyError::UnmodifiableEntity(_) => "UnmodifiableEntity",
+        }
+    }
+}
 /// Errors returned by UpdateGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateGroupError {
@@ -19166,6 +20309,16 @@ impl fmt::Display for UpdateGroupError {
     }
 }
 impl Error for UpdateGroupError {}
+impl ServiceErrorCode for UpdateGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGroupError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            UpdateGroupError::LimitExceeded(_) => "LimitExceeded",
+            UpdateGroupError::NoSuchEntity(_) => "NoSuchEntity",
+            UpdateGroupError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by UpdateLoginProfile
 #[derive(Debug, PartialEq)]
 pub enum UpdateLoginProfileError {
@@ -19246,6 +20399,19 @@ impl fmt::Display for UpdateLoginProfileError {
     }
 }
 impl Error for UpdateLoginProfileError {}
+impl ServiceErrorCode for UpdateLoginProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateLoginProfileError::EntityTemporarilyUnmodifiable(_) => {
+                "EntityTemporarilyUnmodifiable"
+            }
+            UpdateLoginProfileError::LimitExceeded(_) => "LimitExceeded",
+            UpdateLoginProfileError::NoSuchEntity(_) => "NoSuchEntity",
+            UpdateLoginProfileError::PasswordPolicyViolation(_) => "PasswordPolicyViolation",
+            UpdateLoginProfileError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by UpdateOpenIDConnectProviderThumbprint
 #[derive(Debug, PartialEq)]
 pub enum UpdateOpenIDConnectProviderThumbprintError {
@@ -19320,6 +20486,15 @@ impl fmt::Display for UpdateOpenIDConnectProviderThumbprintError {
     }
 }
 impl Error for UpdateOpenIDConnectProviderThumbprintError {}
+impl ServiceErrorCode for UpdateOpenIDConnectProviderThumbprintError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateOpenIDConnectProviderThumbprintError::InvalidInput(_) => "InvalidInput",
+            UpdateOpenIDConnectProviderThumbprintError::NoSuchEntity(_) => "NoSuchEntity",
+            UpdateOpenIDConnectProviderThumbprintError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by UpdateRole
 #[derive(Debug, PartialEq)]
 pub enum UpdateRoleError {
@@ -19380,6 +20555,15 @@ impl fmt::Display for UpdateRoleError {
     }
 }
 impl Error for UpdateRoleError {}
+impl ServiceErrorCode for UpdateRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRoleError::NoSuchEntity(_) => "NoSuchEntity",
+            UpdateRoleError::ServiceFailure(_) => "ServiceFailure",
+            UpdateRoleError::UnmodifiableEntity(_) => "UnmodifiableEntity",
+        }
+    }
+}
 /// Errors returned by UpdateRoleDescription
 #[derive(Debug, PartialEq)]
 pub enum UpdateRoleDescriptionError {
@@ -19440,6 +20624,15 @@ impl fmt::Display for UpdateRoleDescriptionError {
     }
 }
 impl Error for UpdateRoleDescriptionError {}
+impl ServiceErrorCode for UpdateRoleDescriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRoleDescriptionError::NoSuchEntity(_) => "NoSuchEntity",
+            UpdateRoleDescriptionError::ServiceFailure(_) => "ServiceFailure",
+            UpdateRoleDescriptionError::UnmodifiableEntity(_) => "UnmodifiableEntity",
+        }
+    }
+}
 /// Errors returned by UpdateSAMLProvider
 #[derive(Debug, PartialEq)]
 pub enum UpdateSAMLProviderError {
@@ -19508,6 +20701,16 @@ impl fmt::Display for UpdateSAMLProviderError {
     }
 }
 impl Error for UpdateSAMLProviderError {}
+impl ServiceErrorCode for UpdateSAMLProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSAMLProviderError::InvalidInput(_) => "InvalidInput",
+            UpdateSAMLProviderError::LimitExceeded(_) => "LimitExceeded",
+            UpdateSAMLProviderError::NoSuchEntity(_) => "NoSuchEntity",
+            UpdateSAMLProviderError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by UpdateSSHPublicKey
 #[derive(Debug, PartialEq)]
 pub enum UpdateSSHPublicKeyError {
@@ -19552,6 +20755,13 @@ impl fmt::Display for UpdateSSHPublicKeyError {
     }
 }
 impl Error for UpdateSSHPublicKeyError {}
+impl ServiceErrorCode for UpdateSSHPublicKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSSHPublicKeyError::NoSuchEntity(_) => "NoSuchEntity",
+        }
+    }
+}
 /// Errors returned by UpdateServerCertificate
 #[derive(Debug, PartialEq)]
 pub enum UpdateServerCertificateError {
@@ -19620,6 +20830,16 @@ impl fmt::Display for UpdateServerCertificateError {
     }
 }
 impl Error for UpdateServerCertificateError {}
+impl ServiceErrorCode for UpdateServerCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateServerCertificateError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            UpdateServerCertificateError::LimitExceeded(_) => "LimitExceeded",
+            UpdateServerCertificateError::NoSuchEntity(_) => "NoSuchEntity",
+            UpdateServerCertificateError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by UpdateServiceSpecificCredential
 #[derive(Debug, PartialEq)]
 pub enum UpdateServiceSpecificCredentialError {
@@ -19668,6 +20888,13 @@ impl fmt::Display for UpdateServiceSpecificCredentialError {
     }
 }
 impl Error for UpdateServiceSpecificCredentialError {}
+impl ServiceErrorCode for UpdateServiceSpecificCredentialError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateServiceSpecificCredentialError::NoSuchEntity(_) => "NoSuchEntity",
+        }
+    }
+}
 /// Errors returned by UpdateSigningCertificate
 #[derive(Debug, PartialEq)]
 pub enum UpdateSigningCertificateError {
@@ -19728,6 +20955,15 @@ impl fmt::Display for UpdateSigningCertificateError {
     }
 }
 impl Error for UpdateSigningCertificateError {}
+impl ServiceErrorCode for UpdateSigningCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSigningCertificateError::LimitExceeded(_) => "LimitExceeded",
+            UpdateSigningCertificateError::NoSuchEntity(_) => "NoSuchEntity",
+            UpdateSigningCertificateError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by UpdateUser
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserError {
@@ -19812,6 +21048,18 @@ impl fmt::Display for UpdateUserError {
     }
 }
 impl Error for UpdateUserError {}
+impl ServiceErrorCode for UpdateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserError::ConcurrentModification(_) => "ConcurrentModification",
+            UpdateUserError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            UpdateUserError::EntityTemporarilyUnmodifiable(_) => "EntityTemporarilyUnmodifiable",
+            UpdateUserError::LimitExceeded(_) => "LimitExceeded",
+            UpdateUserError::NoSuchEntity(_) => "NoSuchEntity",
+            UpdateUserError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by UploadSSHPublicKey
 #[derive(Debug, PartialEq)]
 pub enum UploadSSHPublicKeyError {
@@ -19892,6 +21140,19 @@ impl fmt::Display for UploadSSHPublicKeyError {
     }
 }
 impl Error for UploadSSHPublicKeyError {}
+impl ServiceErrorCode for UploadSSHPublicKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UploadSSHPublicKeyError::DuplicateSSHPublicKey(_) => "DuplicateSSHPublicKey",
+            UploadSSHPublicKeyError::InvalidPublicKey(_) => "InvalidPublicKey",
+            UploadSSHPublicKeyError::LimitExceeded(_) => "LimitExceeded",
+            UploadSSHPublicKeyError::NoSuchEntity(_) => "NoSuchEntity",
+            UploadSSHPublicKeyError::UnrecognizedPublicKeyEncoding(_) => {
+                "UnrecognizedPublicKeyEncoding"
+            }
+        }
+    }
+}
 /// Errors returned by UploadServerCertificate
 #[derive(Debug, PartialEq)]
 pub enum UploadServerCertificateError {
@@ -19970,6 +21231,17 @@ impl fmt::Display for UploadServerCertificateError {
     }
 }
 impl Error for UploadServerCertificateError {}
+impl ServiceErrorCode for UploadServerCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UploadServerCertificateError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            UploadServerCertificateError::KeyPairMismatch(_) => "KeyPairMismatch",
+            UploadServerCertificateError::LimitExceeded(_) => "LimitExceeded",
+            UploadServerCertificateError::MalformedCertificate(_) => "MalformedCertificate",
+            UploadServerCertificateError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Errors returned by UploadSigningCertificate
 #[derive(Debug, PartialEq)]
 pub enum UploadSigningCertificateError {
@@ -20072,6 +21344,19 @@ impl fmt::Display for UploadSigningCertificateError {
     }
 }
 impl Error for UploadSigningCertificateError {}
+impl ServiceErrorCode for UploadSigningCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UploadSigningCertificateError::DuplicateCertificate(_) => "DuplicateCertificate",
+            UploadSigningCertificateError::EntityAlreadyExists(_) => "EntityAlreadyExists",
+            UploadSigningCertificateError::InvalidCertificate(_) => "InvalidCertificate",
+            UploadSigningCertificateError::LimitExceeded(_) => "LimitExceeded",
+            UploadSigningCertificateError::MalformedCertificate(_) => "MalformedCertificate",
+            UploadSigningCertificateError::NoSuchEntity(_) => "NoSuchEntity",
+            UploadSigningCertificateError::ServiceFailure(_) => "ServiceFailure",
+        }
+    }
+}
 /// Trait representing the capabilities of the IAM API. IAM clients implement this trait.
 #[async_trait]
 pub trait Iam {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -87,6 +87,7 @@ pub struct AmiDistributionConfiguration {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CancelImageCreationRequest {
     /// <p>The idempotency token used to make this request idempotent.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     pub client_token: String,
     /// <p>The Amazon Resource Name (ARN) of the image whose creation you want to cancel.</p>
@@ -419,6 +420,7 @@ pub struct CreateComponentRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub change_description: Option<String>,
     /// <p>The idempotency token of the component.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     pub client_token: String,
     /// <p>The data of the component. Used to specify the data inline. Either <code>data</code> or <code>uri</code> can be used to specify the data within the component.</p>
@@ -477,6 +479,7 @@ pub struct CreateComponentResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateContainerRecipeRequest {
     /// <p>The client token used to make this request idempotent.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     pub client_token: String,
     /// <p>Components for build and test that are included in the container recipe.</p>
@@ -551,6 +554,7 @@ pub struct CreateContainerRecipeResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateDistributionConfigurationRequest {
     /// <p> The idempotency token of the distribution configuration. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     pub client_token: String,
     /// <p> The description of the distribution configuration. </p>
@@ -590,6 +594,7 @@ pub struct CreateDistributionConfigurationResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateImagePipelineRequest {
     /// <p> The idempotency token used to make this request idempotent. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     pub client_token: String,
     /// <p>The Amazon Resource Name (ARN) of the container recipe that is used to configure images created by this container pipeline.</p>
@@ -661,6 +666,7 @@ pub struct CreateImageRecipeRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub block_device_mappings: Option<Vec<InstanceBlockDeviceMapping>>,
     /// <p>The idempotency token used to make this request idempotent. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     pub client_token: String,
     /// <p>The components of the image recipe. </p>
@@ -710,6 +716,7 @@ pub struct CreateImageRecipeResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateImageRequest {
     /// <p> The idempotency token used to make this request idempotent. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     pub client_token: String,
     /// <p>The Amazon Resource Name (ARN) of the container recipe that defines how images are configured and tested.</p>
@@ -762,6 +769,7 @@ pub struct CreateImageResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateInfrastructureConfigurationRequest {
     /// <p>The idempotency token used to make this request idempotent. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     pub client_token: String,
     /// <p>The description of the infrastructure configuration. </p>
@@ -1714,6 +1722,7 @@ pub struct ImportComponentRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub change_description: Option<String>,
     /// <p>The idempotency token of the component. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     pub client_token: String,
     /// <p>The data of the component. Used to specify the data inline. Either <code>data</code> or <code>uri</code> can be used to specify the data within the component.</p>
@@ -2443,6 +2452,7 @@ pub struct Schedule {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartImagePipelineExecutionRequest {
     /// <p>The idempotency token used to make this request idempotent. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     pub client_token: String,
     /// <p>The Amazon Resource Name (ARN) of the image pipeline that you want to manually invoke. </p>
@@ -2512,6 +2522,7 @@ pub struct UntagResourceResponse {}
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateDistributionConfigurationRequest {
     /// <p>The idempotency token of the distribution configuration. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     pub client_token: String,
     /// <p>The description of the distribution configuration. </p>
@@ -2547,6 +2558,7 @@ pub struct UpdateDistributionConfigurationResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateImagePipelineRequest {
     /// <p>The idempotency token used to make this request idempotent. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     pub client_token: String,
     /// <p>The Amazon Resource Name (ARN) of the container pipeline to update.</p>
@@ -2610,6 +2622,7 @@ pub struct UpdateImagePipelineResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateInfrastructureConfigurationRequest {
     /// <p>The idempotency token used to make this request idempotent. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     pub client_token: String,
     /// <p>The description of the infrastructure configuration. </p>
@@ -2753,6 +2766,22 @@ impl fmt::Display for CancelImageCreationError {
     }
 }
 impl Error for CancelImageCreationError {}
+impl ServiceErrorCode for CancelImageCreationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelImageCreationError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            CancelImageCreationError::Client(_) => "ClientException",
+            CancelImageCreationError::Forbidden(_) => "ForbiddenException",
+            CancelImageCreationError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CancelImageCreationError::InvalidRequest(_) => "InvalidRequestException",
+            CancelImageCreationError::ResourceInUse(_) => "ResourceInUseException",
+            CancelImageCreationError::Service(_) => "ServiceException",
+            CancelImageCreationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CreateComponent
 #[derive(Debug, PartialEq)]
 pub enum CreateComponentError {
@@ -2853,6 +2882,27 @@ impl fmt::Display for CreateComponentError {
     }
 }
 impl Error for CreateComponentError {}
+impl ServiceErrorCode for CreateComponentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateComponentError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            CreateComponentError::Client(_) => "ClientException",
+            CreateComponentError::Forbidden(_) => "ForbiddenException",
+            CreateComponentError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateComponentError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            CreateComponentError::InvalidRequest(_) => "InvalidRequestException",
+            CreateComponentError::InvalidVersionNumber(_) => "InvalidVersionNumberException",
+            CreateComponentError::ResourceInUse(_) => "ResourceInUseException",
+            CreateComponentError::Service(_) => "ServiceException",
+            CreateComponentError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateComponentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CreateContainerRecipe
 #[derive(Debug, PartialEq)]
 pub enum CreateContainerRecipeError {
@@ -2959,6 +3009,29 @@ impl fmt::Display for CreateContainerRecipeError {
     }
 }
 impl Error for CreateContainerRecipeError {}
+impl ServiceErrorCode for CreateContainerRecipeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateContainerRecipeError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            CreateContainerRecipeError::Client(_) => "ClientException",
+            CreateContainerRecipeError::Forbidden(_) => "ForbiddenException",
+            CreateContainerRecipeError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateContainerRecipeError::InvalidRequest(_) => "InvalidRequestException",
+            CreateContainerRecipeError::InvalidVersionNumber(_) => "InvalidVersionNumberException",
+            CreateContainerRecipeError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateContainerRecipeError::ResourceInUse(_) => "ResourceInUseException",
+            CreateContainerRecipeError::Service(_) => "ServiceException",
+            CreateContainerRecipeError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateContainerRecipeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CreateDistributionConfiguration
 #[derive(Debug, PartialEq)]
 pub enum CreateDistributionConfigurationError {
@@ -3089,6 +3162,35 @@ impl fmt::Display for CreateDistributionConfigurationError {
     }
 }
 impl Error for CreateDistributionConfigurationError {}
+impl ServiceErrorCode for CreateDistributionConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDistributionConfigurationError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            CreateDistributionConfigurationError::Client(_) => "ClientException",
+            CreateDistributionConfigurationError::Forbidden(_) => "ForbiddenException",
+            CreateDistributionConfigurationError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateDistributionConfigurationError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            CreateDistributionConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            CreateDistributionConfigurationError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateDistributionConfigurationError::ResourceInUse(_) => "ResourceInUseException",
+            CreateDistributionConfigurationError::Service(_) => "ServiceException",
+            CreateDistributionConfigurationError::ServiceQuotaExceeded(_) => {
+                "ServiceQuotaExceededException"
+            }
+            CreateDistributionConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateImage
 #[derive(Debug, PartialEq)]
 pub enum CreateImageError {
@@ -3169,6 +3271,23 @@ impl fmt::Display for CreateImageError {
     }
 }
 impl Error for CreateImageError {}
+impl ServiceErrorCode for CreateImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateImageError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            CreateImageError::Client(_) => "ClientException",
+            CreateImageError::Forbidden(_) => "ForbiddenException",
+            CreateImageError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateImageError::InvalidRequest(_) => "InvalidRequestException",
+            CreateImageError::ResourceInUse(_) => "ResourceInUseException",
+            CreateImageError::Service(_) => "ServiceException",
+            CreateImageError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateImageError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CreateImagePipeline
 #[derive(Debug, PartialEq)]
 pub enum CreateImagePipelineError {
@@ -3265,6 +3384,24 @@ impl fmt::Display for CreateImagePipelineError {
     }
 }
 impl Error for CreateImagePipelineError {}
+impl ServiceErrorCode for CreateImagePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateImagePipelineError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            CreateImagePipelineError::Client(_) => "ClientException",
+            CreateImagePipelineError::Forbidden(_) => "ForbiddenException",
+            CreateImagePipelineError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateImagePipelineError::InvalidRequest(_) => "InvalidRequestException",
+            CreateImagePipelineError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateImagePipelineError::ResourceInUse(_) => "ResourceInUseException",
+            CreateImagePipelineError::Service(_) => "ServiceException",
+            CreateImagePipelineError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateImagePipelineError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CreateImageRecipe
 #[derive(Debug, PartialEq)]
 pub enum CreateImageRecipeError {
@@ -3369,6 +3506,25 @@ impl fmt::Display for CreateImageRecipeError {
     }
 }
 impl Error for CreateImageRecipeError {}
+impl ServiceErrorCode for CreateImageRecipeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateImageRecipeError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            CreateImageRecipeError::Client(_) => "ClientException",
+            CreateImageRecipeError::Forbidden(_) => "ForbiddenException",
+            CreateImageRecipeError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateImageRecipeError::InvalidRequest(_) => "InvalidRequestException",
+            CreateImageRecipeError::InvalidVersionNumber(_) => "InvalidVersionNumberException",
+            CreateImageRecipeError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateImageRecipeError::ResourceInUse(_) => "ResourceInUseException",
+            CreateImageRecipeError::Service(_) => "ServiceException",
+            CreateImageRecipeError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateImageRecipeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CreateInfrastructureConfiguration
 #[derive(Debug, PartialEq)]
 pub enum CreateInfrastructureConfigurationError {
@@ -3491,6 +3647,32 @@ impl fmt::Display for CreateInfrastructureConfigurationError {
     }
 }
 impl Error for CreateInfrastructureConfigurationError {}
+impl ServiceErrorCode for CreateInfrastructureConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateInfrastructureConfigurationError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            CreateInfrastructureConfigurationError::Client(_) => "ClientException",
+            CreateInfrastructureConfigurationError::Forbidden(_) => "ForbiddenException",
+            CreateInfrastructureConfigurationError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateInfrastructureConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            CreateInfrastructureConfigurationError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateInfrastructureConfigurationError::ResourceInUse(_) => "ResourceInUseException",
+            CreateInfrastructureConfigurationError::Service(_) => "ServiceException",
+            CreateInfrastructureConfigurationError::ServiceQuotaExceeded(_) => {
+                "ServiceQuotaExceededException"
+            }
+            CreateInfrastructureConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteComponent
 #[derive(Debug, PartialEq)]
 pub enum DeleteComponentError {
@@ -3559,6 +3741,19 @@ impl fmt::Display for DeleteComponentError {
     }
 }
 impl Error for DeleteComponentError {}
+impl ServiceErrorCode for DeleteComponentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteComponentError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            DeleteComponentError::Client(_) => "ClientException",
+            DeleteComponentError::Forbidden(_) => "ForbiddenException",
+            DeleteComponentError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteComponentError::ResourceDependency(_) => "ResourceDependencyException",
+            DeleteComponentError::Service(_) => "ServiceException",
+            DeleteComponentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteContainerRecipe
 #[derive(Debug, PartialEq)]
 pub enum DeleteContainerRecipeError {
@@ -3633,6 +3828,21 @@ impl fmt::Display for DeleteContainerRecipeError {
     }
 }
 impl Error for DeleteContainerRecipeError {}
+impl ServiceErrorCode for DeleteContainerRecipeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteContainerRecipeError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            DeleteContainerRecipeError::Client(_) => "ClientException",
+            DeleteContainerRecipeError::Forbidden(_) => "ForbiddenException",
+            DeleteContainerRecipeError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteContainerRecipeError::ResourceDependency(_) => "ResourceDependencyException",
+            DeleteContainerRecipeError::Service(_) => "ServiceException",
+            DeleteContainerRecipeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteDistributionConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteDistributionConfigurationError {
@@ -3723,6 +3933,25 @@ impl fmt::Display for DeleteDistributionConfigurationError {
     }
 }
 impl Error for DeleteDistributionConfigurationError {}
+impl ServiceErrorCode for DeleteDistributionConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDistributionConfigurationError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            DeleteDistributionConfigurationError::Client(_) => "ClientException",
+            DeleteDistributionConfigurationError::Forbidden(_) => "ForbiddenException",
+            DeleteDistributionConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteDistributionConfigurationError::ResourceDependency(_) => {
+                "ResourceDependencyException"
+            }
+            DeleteDistributionConfigurationError::Service(_) => "ServiceException",
+            DeleteDistributionConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteImage
 #[derive(Debug, PartialEq)]
 pub enum DeleteImageError {
@@ -3789,6 +4018,19 @@ impl fmt::Display for DeleteImageError {
     }
 }
 impl Error for DeleteImageError {}
+impl ServiceErrorCode for DeleteImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteImageError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            DeleteImageError::Client(_) => "ClientException",
+            DeleteImageError::Forbidden(_) => "ForbiddenException",
+            DeleteImageError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteImageError::ResourceDependency(_) => "ResourceDependencyException",
+            DeleteImageError::Service(_) => "ServiceException",
+            DeleteImageError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteImagePipeline
 #[derive(Debug, PartialEq)]
 pub enum DeleteImagePipelineError {
@@ -3861,6 +4103,19 @@ impl fmt::Display for DeleteImagePipelineError {
     }
 }
 impl Error for DeleteImagePipelineError {}
+impl ServiceErrorCode for DeleteImagePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteImagePipelineError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            DeleteImagePipelineError::Client(_) => "ClientException",
+            DeleteImagePipelineError::Forbidden(_) => "ForbiddenException",
+            DeleteImagePipelineError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteImagePipelineError::ResourceDependency(_) => "ResourceDependencyException",
+            DeleteImagePipelineError::Service(_) => "ServiceException",
+            DeleteImagePipelineError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteImageRecipe
 #[derive(Debug, PartialEq)]
 pub enum DeleteImageRecipeError {
@@ -3933,6 +4188,19 @@ impl fmt::Display for DeleteImageRecipeError {
     }
 }
 impl Error for DeleteImageRecipeError {}
+impl ServiceErrorCode for DeleteImageRecipeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteImageRecipeError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            DeleteImageRecipeError::Client(_) => "ClientException",
+            DeleteImageRecipeError::Forbidden(_) => "ForbiddenException",
+            DeleteImageRecipeError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteImageRecipeError::ResourceDependency(_) => "ResourceDependencyException",
+            DeleteImageRecipeError::Service(_) => "ServiceException",
+            DeleteImageRecipeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteInfrastructureConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteInfrastructureConfigurationError {
@@ -4023,6 +4291,25 @@ impl fmt::Display for DeleteInfrastructureConfigurationError {
     }
 }
 impl Error for DeleteInfrastructureConfigurationError {}
+impl ServiceErrorCode for DeleteInfrastructureConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInfrastructureConfigurationError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            DeleteInfrastructureConfigurationError::Client(_) => "ClientException",
+            DeleteInfrastructureConfigurationError::Forbidden(_) => "ForbiddenException",
+            DeleteInfrastructureConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteInfrastructureConfigurationError::ResourceDependency(_) => {
+                "ResourceDependencyException"
+            }
+            DeleteInfrastructureConfigurationError::Service(_) => "ServiceException",
+            DeleteInfrastructureConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by GetComponent
 #[derive(Debug, PartialEq)]
 pub enum GetComponentError {
@@ -4083,6 +4370,18 @@ impl fmt::Display for GetComponentError {
     }
 }
 impl Error for GetComponentError {}
+impl ServiceErrorCode for GetComponentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetComponentError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            GetComponentError::Client(_) => "ClientException",
+            GetComponentError::Forbidden(_) => "ForbiddenException",
+            GetComponentError::InvalidRequest(_) => "InvalidRequestException",
+            GetComponentError::Service(_) => "ServiceException",
+            GetComponentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetComponentPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetComponentPolicyError {
@@ -4147,6 +4446,18 @@ impl fmt::Display for GetComponentPolicyError {
     }
 }
 impl Error for GetComponentPolicyError {}
+impl ServiceErrorCode for GetComponentPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetComponentPolicyError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            GetComponentPolicyError::Forbidden(_) => "ForbiddenException",
+            GetComponentPolicyError::InvalidRequest(_) => "InvalidRequestException",
+            GetComponentPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetComponentPolicyError::Service(_) => "ServiceException",
+            GetComponentPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetContainerRecipe
 #[derive(Debug, PartialEq)]
 pub enum GetContainerRecipeError {
@@ -4211,6 +4522,18 @@ impl fmt::Display for GetContainerRecipeError {
     }
 }
 impl Error for GetContainerRecipeError {}
+impl ServiceErrorCode for GetContainerRecipeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContainerRecipeError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            GetContainerRecipeError::Client(_) => "ClientException",
+            GetContainerRecipeError::Forbidden(_) => "ForbiddenException",
+            GetContainerRecipeError::InvalidRequest(_) => "InvalidRequestException",
+            GetContainerRecipeError::Service(_) => "ServiceException",
+            GetContainerRecipeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetContainerRecipePolicy
 #[derive(Debug, PartialEq)]
 pub enum GetContainerRecipePolicyError {
@@ -4281,6 +4604,20 @@ impl fmt::Display for GetContainerRecipePolicyError {
     }
 }
 impl Error for GetContainerRecipePolicyError {}
+impl ServiceErrorCode for GetContainerRecipePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContainerRecipePolicyError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            GetContainerRecipePolicyError::Forbidden(_) => "ForbiddenException",
+            GetContainerRecipePolicyError::InvalidRequest(_) => "InvalidRequestException",
+            GetContainerRecipePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetContainerRecipePolicyError::Service(_) => "ServiceException",
+            GetContainerRecipePolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetDistributionConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetDistributionConfigurationError {
@@ -4357,6 +4694,22 @@ impl fmt::Display for GetDistributionConfigurationError {
     }
 }
 impl Error for GetDistributionConfigurationError {}
+impl ServiceErrorCode for GetDistributionConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDistributionConfigurationError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            GetDistributionConfigurationError::Client(_) => "ClientException",
+            GetDistributionConfigurationError::Forbidden(_) => "ForbiddenException",
+            GetDistributionConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            GetDistributionConfigurationError::Service(_) => "ServiceException",
+            GetDistributionConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by GetImage
 #[derive(Debug, PartialEq)]
 pub enum GetImageError {
@@ -4413,6 +4766,18 @@ impl fmt::Display for GetImageError {
     }
 }
 impl Error for GetImageError {}
+impl ServiceErrorCode for GetImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetImageError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            GetImageError::Client(_) => "ClientException",
+            GetImageError::Forbidden(_) => "ForbiddenException",
+            GetImageError::InvalidRequest(_) => "InvalidRequestException",
+            GetImageError::Service(_) => "ServiceException",
+            GetImageError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetImagePipeline
 #[derive(Debug, PartialEq)]
 pub enum GetImagePipelineError {
@@ -4475,6 +4840,18 @@ impl fmt::Display for GetImagePipelineError {
     }
 }
 impl Error for GetImagePipelineError {}
+impl ServiceErrorCode for GetImagePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetImagePipelineError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            GetImagePipelineError::Client(_) => "ClientException",
+            GetImagePipelineError::Forbidden(_) => "ForbiddenException",
+            GetImagePipelineError::InvalidRequest(_) => "InvalidRequestException",
+            GetImagePipelineError::Service(_) => "ServiceException",
+            GetImagePipelineError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetImagePolicy
 #[derive(Debug, PartialEq)]
 pub enum GetImagePolicyError {
@@ -4537,6 +4914,18 @@ impl fmt::Display for GetImagePolicyError {
     }
 }
 impl Error for GetImagePolicyError {}
+impl ServiceErrorCode for GetImagePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetImagePolicyError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            GetImagePolicyError::Forbidden(_) => "ForbiddenException",
+            GetImagePolicyError::InvalidRequest(_) => "InvalidRequestException",
+            GetImagePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetImagePolicyError::Service(_) => "ServiceException",
+            GetImagePolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetImageRecipe
 #[derive(Debug, PartialEq)]
 pub enum GetImageRecipeError {
@@ -4599,6 +4988,18 @@ impl fmt::Display for GetImageRecipeError {
     }
 }
 impl Error for GetImageRecipeError {}
+impl ServiceErrorCode for GetImageRecipeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetImageRecipeError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            GetImageRecipeError::Client(_) => "ClientException",
+            GetImageRecipeError::Forbidden(_) => "ForbiddenException",
+            GetImageRecipeError::InvalidRequest(_) => "InvalidRequestException",
+            GetImageRecipeError::Service(_) => "ServiceException",
+            GetImageRecipeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetImageRecipePolicy
 #[derive(Debug, PartialEq)]
 pub enum GetImageRecipePolicyError {
@@ -4665,6 +5066,18 @@ impl fmt::Display for GetImageRecipePolicyError {
     }
 }
 impl Error for GetImageRecipePolicyError {}
+impl ServiceErrorCode for GetImageRecipePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetImageRecipePolicyError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            GetImageRecipePolicyError::Forbidden(_) => "ForbiddenException",
+            GetImageRecipePolicyError::InvalidRequest(_) => "InvalidRequestException",
+            GetImageRecipePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetImageRecipePolicyError::Service(_) => "ServiceException",
+            GetImageRecipePolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetInfrastructureConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetInfrastructureConfigurationError {
@@ -4745,6 +5158,22 @@ impl fmt::Display for GetInfrastructureConfigurationError {
     }
 }
 impl Error for GetInfrastructureConfigurationError {}
+impl ServiceErrorCode for GetInfrastructureConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInfrastructureConfigurationError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            GetInfrastructureConfigurationError::Client(_) => "ClientException",
+            GetInfrastructureConfigurationError::Forbidden(_) => "ForbiddenException",
+            GetInfrastructureConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            GetInfrastructureConfigurationError::Service(_) => "ServiceException",
+            GetInfrastructureConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by ImportComponent
 #[derive(Debug, PartialEq)]
 pub enum ImportComponentError {
@@ -4837,6 +5266,26 @@ impl fmt::Display for ImportComponentError {
     }
 }
 impl Error for ImportComponentError {}
+impl ServiceErrorCode for ImportComponentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportComponentError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            ImportComponentError::Client(_) => "ClientException",
+            ImportComponentError::Forbidden(_) => "ForbiddenException",
+            ImportComponentError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            ImportComponentError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            ImportComponentError::InvalidRequest(_) => "InvalidRequestException",
+            ImportComponentError::InvalidVersionNumber(_) => "InvalidVersionNumberException",
+            ImportComponentError::ResourceInUse(_) => "ResourceInUseException",
+            ImportComponentError::Service(_) => "ServiceException",
+            ImportComponentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListComponentBuildVersions
 #[derive(Debug, PartialEq)]
 pub enum ListComponentBuildVersionsError {
@@ -4921,6 +5370,23 @@ impl fmt::Display for ListComponentBuildVersionsError {
     }
 }
 impl Error for ListComponentBuildVersionsError {}
+impl ServiceErrorCode for ListComponentBuildVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListComponentBuildVersionsError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            ListComponentBuildVersionsError::Client(_) => "ClientException",
+            ListComponentBuildVersionsError::Forbidden(_) => "ForbiddenException",
+            ListComponentBuildVersionsError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            ListComponentBuildVersionsError::InvalidRequest(_) => "InvalidRequestException",
+            ListComponentBuildVersionsError::Service(_) => "ServiceException",
+            ListComponentBuildVersionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListComponents
 #[derive(Debug, PartialEq)]
 pub enum ListComponentsError {
@@ -4991,6 +5457,19 @@ impl fmt::Display for ListComponentsError {
     }
 }
 impl Error for ListComponentsError {}
+impl ServiceErrorCode for ListComponentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListComponentsError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            ListComponentsError::Client(_) => "ClientException",
+            ListComponentsError::Forbidden(_) => "ForbiddenException",
+            ListComponentsError::InvalidPaginationToken(_) => "InvalidPaginationTokenException",
+            ListComponentsError::InvalidRequest(_) => "InvalidRequestException",
+            ListComponentsError::Service(_) => "ServiceException",
+            ListComponentsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListContainerRecipes
 #[derive(Debug, PartialEq)]
 pub enum ListContainerRecipesError {
@@ -5063,6 +5542,21 @@ impl fmt::Display for ListContainerRecipesError {
     }
 }
 impl Error for ListContainerRecipesError {}
+impl ServiceErrorCode for ListContainerRecipesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListContainerRecipesError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            ListContainerRecipesError::Client(_) => "ClientException",
+            ListContainerRecipesError::Forbidden(_) => "ForbiddenException",
+            ListContainerRecipesError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            ListContainerRecipesError::InvalidRequest(_) => "InvalidRequestException",
+            ListContainerRecipesError::Service(_) => "ServiceException",
+            ListContainerRecipesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListDistributionConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListDistributionConfigurationsError {
@@ -5153,6 +5647,25 @@ impl fmt::Display for ListDistributionConfigurationsError {
     }
 }
 impl Error for ListDistributionConfigurationsError {}
+impl ServiceErrorCode for ListDistributionConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDistributionConfigurationsError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            ListDistributionConfigurationsError::Client(_) => "ClientException",
+            ListDistributionConfigurationsError::Forbidden(_) => "ForbiddenException",
+            ListDistributionConfigurationsError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            ListDistributionConfigurationsError::InvalidRequest(_) => "InvalidRequestException",
+            ListDistributionConfigurationsError::Service(_) => "ServiceException",
+            ListDistributionConfigurationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by ListImageBuildVersions
 #[derive(Debug, PartialEq)]
 pub enum ListImageBuildVersionsError {
@@ -5229,6 +5742,23 @@ impl fmt::Display for ListImageBuildVersionsError {
     }
 }
 impl Error for ListImageBuildVersionsError {}
+impl ServiceErrorCode for ListImageBuildVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListImageBuildVersionsError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            ListImageBuildVersionsError::Client(_) => "ClientException",
+            ListImageBuildVersionsError::Forbidden(_) => "ForbiddenException",
+            ListImageBuildVersionsError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            ListImageBuildVersionsError::InvalidRequest(_) => "InvalidRequestException",
+            ListImageBuildVersionsError::Service(_) => "ServiceException",
+            ListImageBuildVersionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListImagePipelineImages
 #[derive(Debug, PartialEq)]
 pub enum ListImagePipelineImagesError {
@@ -5315,6 +5845,24 @@ impl fmt::Display for ListImagePipelineImagesError {
     }
 }
 impl Error for ListImagePipelineImagesError {}
+impl ServiceErrorCode for ListImagePipelineImagesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListImagePipelineImagesError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            ListImagePipelineImagesError::Client(_) => "ClientException",
+            ListImagePipelineImagesError::Forbidden(_) => "ForbiddenException",
+            ListImagePipelineImagesError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            ListImagePipelineImagesError::InvalidRequest(_) => "InvalidRequestException",
+            ListImagePipelineImagesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListImagePipelineImagesError::Service(_) => "ServiceException",
+            ListImagePipelineImagesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListImagePipelines
 #[derive(Debug, PartialEq)]
 pub enum ListImagePipelinesError {
@@ -5387,6 +5935,19 @@ impl fmt::Display for ListImagePipelinesError {
     }
 }
 impl Error for ListImagePipelinesError {}
+impl ServiceErrorCode for ListImagePipelinesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListImagePipelinesError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            ListImagePipelinesError::Client(_) => "ClientException",
+            ListImagePipelinesError::Forbidden(_) => "ForbiddenException",
+            ListImagePipelinesError::InvalidPaginationToken(_) => "InvalidPaginationTokenException",
+            ListImagePipelinesError::InvalidRequest(_) => "InvalidRequestException",
+            ListImagePipelinesError::Service(_) => "ServiceException",
+            ListImagePipelinesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListImageRecipes
 #[derive(Debug, PartialEq)]
 pub enum ListImageRecipesError {
@@ -5457,6 +6018,19 @@ impl fmt::Display for ListImageRecipesError {
     }
 }
 impl Error for ListImageRecipesError {}
+impl ServiceErrorCode for ListImageRecipesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListImageRecipesError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            ListImageRecipesError::Client(_) => "ClientException",
+            ListImageRecipesError::Forbidden(_) => "ForbiddenException",
+            ListImageRecipesError::InvalidPaginationToken(_) => "InvalidPaginationTokenException",
+            ListImageRecipesError::InvalidRequest(_) => "InvalidRequestException",
+            ListImageRecipesError::Service(_) => "ServiceException",
+            ListImageRecipesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListImages
 #[derive(Debug, PartialEq)]
 pub enum ListImagesError {
@@ -5521,6 +6095,19 @@ impl fmt::Display for ListImagesError {
     }
 }
 impl Error for ListImagesError {}
+impl ServiceErrorCode for ListImagesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListImagesError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            ListImagesError::Client(_) => "ClientException",
+            ListImagesError::Forbidden(_) => "ForbiddenException",
+            ListImagesError::InvalidPaginationToken(_) => "InvalidPaginationTokenException",
+            ListImagesError::InvalidRequest(_) => "InvalidRequestException",
+            ListImagesError::Service(_) => "ServiceException",
+            ListImagesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListInfrastructureConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListInfrastructureConfigurationsError {
@@ -5611,6 +6198,25 @@ impl fmt::Display for ListInfrastructureConfigurationsError {
     }
 }
 impl Error for ListInfrastructureConfigurationsError {}
+impl ServiceErrorCode for ListInfrastructureConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInfrastructureConfigurationsError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            ListInfrastructureConfigurationsError::Client(_) => "ClientException",
+            ListInfrastructureConfigurationsError::Forbidden(_) => "ForbiddenException",
+            ListInfrastructureConfigurationsError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            ListInfrastructureConfigurationsError::InvalidRequest(_) => "InvalidRequestException",
+            ListInfrastructureConfigurationsError::Service(_) => "ServiceException",
+            ListInfrastructureConfigurationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -5657,6 +6263,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InvalidParameter(_) => "InvalidParameterException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForResourceError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by PutComponentPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutComponentPolicyError {
@@ -5735,6 +6350,20 @@ impl fmt::Display for PutComponentPolicyError {
     }
 }
 impl Error for PutComponentPolicyError {}
+impl ServiceErrorCode for PutComponentPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutComponentPolicyError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            PutComponentPolicyError::Client(_) => "ClientException",
+            PutComponentPolicyError::Forbidden(_) => "ForbiddenException",
+            PutComponentPolicyError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            PutComponentPolicyError::InvalidRequest(_) => "InvalidRequestException",
+            PutComponentPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutComponentPolicyError::Service(_) => "ServiceException",
+            PutComponentPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by PutContainerRecipePolicy
 #[derive(Debug, PartialEq)]
 pub enum PutContainerRecipePolicyError {
@@ -5821,6 +6450,24 @@ impl fmt::Display for PutContainerRecipePolicyError {
     }
 }
 impl Error for PutContainerRecipePolicyError {}
+impl ServiceErrorCode for PutContainerRecipePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutContainerRecipePolicyError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            PutContainerRecipePolicyError::Client(_) => "ClientException",
+            PutContainerRecipePolicyError::Forbidden(_) => "ForbiddenException",
+            PutContainerRecipePolicyError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            PutContainerRecipePolicyError::InvalidRequest(_) => "InvalidRequestException",
+            PutContainerRecipePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutContainerRecipePolicyError::Service(_) => "ServiceException",
+            PutContainerRecipePolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by PutImagePolicy
 #[derive(Debug, PartialEq)]
 pub enum PutImagePolicyError {
@@ -5897,6 +6544,20 @@ impl fmt::Display for PutImagePolicyError {
     }
 }
 impl Error for PutImagePolicyError {}
+impl ServiceErrorCode for PutImagePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutImagePolicyError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            PutImagePolicyError::Client(_) => "ClientException",
+            PutImagePolicyError::Forbidden(_) => "ForbiddenException",
+            PutImagePolicyError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            PutImagePolicyError::InvalidRequest(_) => "InvalidRequestException",
+            PutImagePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutImagePolicyError::Service(_) => "ServiceException",
+            PutImagePolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by PutImageRecipePolicy
 #[derive(Debug, PartialEq)]
 pub enum PutImageRecipePolicyError {
@@ -5977,6 +6638,20 @@ impl fmt::Display for PutImageRecipePolicyError {
     }
 }
 impl Error for PutImageRecipePolicyError {}
+impl ServiceErrorCode for PutImageRecipePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutImageRecipePolicyError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            PutImageRecipePolicyError::Client(_) => "ClientException",
+            PutImageRecipePolicyError::Forbidden(_) => "ForbiddenException",
+            PutImageRecipePolicyError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            PutImageRecipePolicyError::InvalidRequest(_) => "InvalidRequestException",
+            PutImageRecipePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutImageRecipePolicyError::Service(_) => "ServiceException",
+            PutImageRecipePolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by StartImagePipelineExecution
 #[derive(Debug, PartialEq)]
 pub enum StartImagePipelineExecutionError {
@@ -6077,6 +6752,27 @@ impl fmt::Display for StartImagePipelineExecutionError {
     }
 }
 impl Error for StartImagePipelineExecutionError {}
+impl ServiceErrorCode for StartImagePipelineExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartImagePipelineExecutionError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            StartImagePipelineExecutionError::Client(_) => "ClientException",
+            StartImagePipelineExecutionError::Forbidden(_) => "ForbiddenException",
+            StartImagePipelineExecutionError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            StartImagePipelineExecutionError::InvalidRequest(_) => "InvalidRequestException",
+            StartImagePipelineExecutionError::ResourceInUse(_) => "ResourceInUseException",
+            StartImagePipelineExecutionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartImagePipelineExecutionError::Service(_) => "ServiceException",
+            StartImagePipelineExecutionError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -6119,6 +6815,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -6161,6 +6866,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by UpdateDistributionConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateDistributionConfigurationError {
@@ -6271,6 +6985,29 @@ impl fmt::Display for UpdateDistributionConfigurationError {
     }
 }
 impl Error for UpdateDistributionConfigurationError {}
+impl ServiceErrorCode for UpdateDistributionConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDistributionConfigurationError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            UpdateDistributionConfigurationError::Client(_) => "ClientException",
+            UpdateDistributionConfigurationError::Forbidden(_) => "ForbiddenException",
+            UpdateDistributionConfigurationError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            UpdateDistributionConfigurationError::InvalidParameterCombination(_) => {
+                "InvalidParameterCombinationException"
+            }
+            UpdateDistributionConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateDistributionConfigurationError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateDistributionConfigurationError::Service(_) => "ServiceException",
+            UpdateDistributionConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateImagePipeline
 #[derive(Debug, PartialEq)]
 pub enum UpdateImagePipelineError {
@@ -6351,6 +7088,22 @@ impl fmt::Display for UpdateImagePipelineError {
     }
 }
 impl Error for UpdateImagePipelineError {}
+impl ServiceErrorCode for UpdateImagePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateImagePipelineError::CallRateLimitExceeded(_) => "CallRateLimitExceededException",
+            UpdateImagePipelineError::Client(_) => "ClientException",
+            UpdateImagePipelineError::Forbidden(_) => "ForbiddenException",
+            UpdateImagePipelineError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            UpdateImagePipelineError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateImagePipelineError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateImagePipelineError::Service(_) => "ServiceException",
+            UpdateImagePipelineError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by UpdateInfrastructureConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateInfrastructureConfigurationError {
@@ -6453,6 +7206,26 @@ impl fmt::Display for UpdateInfrastructureConfigurationError {
     }
 }
 impl Error for UpdateInfrastructureConfigurationError {}
+impl ServiceErrorCode for UpdateInfrastructureConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateInfrastructureConfigurationError::CallRateLimitExceeded(_) => {
+                "CallRateLimitExceededException"
+            }
+            UpdateInfrastructureConfigurationError::Client(_) => "ClientException",
+            UpdateInfrastructureConfigurationError::Forbidden(_) => "ForbiddenException",
+            UpdateInfrastructureConfigurationError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            UpdateInfrastructureConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateInfrastructureConfigurationError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateInfrastructureConfigurationError::Service(_) => "ServiceException",
+            UpdateInfrastructureConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the imagebuilder API. imagebuilder clients implement this trait.
 #[async_trait]
 pub trait ImageBuilder {
@@ -6817,6 +7590,12 @@ impl ImageBuilder for ImageBuilderClient {
         &self,
         input: CancelImageCreationRequest,
     ) -> Result<CancelImageCreationResponse, RusotoError<CancelImageCreationError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/CancelImageCreation";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
@@ -6848,6 +7627,12 @@ impl ImageBuilder for ImageBuilderClient {
         &self,
         input: CreateComponentRequest,
     ) -> Result<CreateComponentResponse, RusotoError<CreateComponentError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/CreateComponent";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
@@ -6879,6 +7664,12 @@ impl ImageBuilder for ImageBuilderClient {
         &self,
         input: CreateContainerRecipeRequest,
     ) -> Result<CreateContainerRecipeResponse, RusotoError<CreateContainerRecipeError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/CreateContainerRecipe";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
@@ -6913,6 +7704,12 @@ impl ImageBuilder for ImageBuilderClient {
         CreateDistributionConfigurationResponse,
         RusotoError<CreateDistributionConfigurationError>,
     > {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/CreateDistributionConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
@@ -6946,6 +7743,12 @@ impl ImageBuilder for ImageBuilderClient {
         &self,
         input: CreateImageRequest,
     ) -> Result<CreateImageResponse, RusotoError<CreateImageError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/CreateImage";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
@@ -6977,6 +7780,12 @@ impl ImageBuilder for ImageBuilderClient {
         &self,
         input: CreateImagePipelineRequest,
     ) -> Result<CreateImagePipelineResponse, RusotoError<CreateImagePipelineError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/CreateImagePipeline";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
@@ -7008,6 +7817,12 @@ impl ImageBuilder for ImageBuilderClient {
         &self,
         input: CreateImageRecipeRequest,
     ) -> Result<CreateImageRecipeResponse, RusotoError<CreateImageRecipeError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/CreateImageRecipe";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
@@ -7042,6 +7857,12 @@ impl ImageBuilder for ImageBuilderClient {
         CreateInfrastructureConfigurationResponse,
         RusotoError<CreateInfrastructureConfigurationError>,
     > {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/CreateInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
@@ -7683,6 +8504,12 @@ impl ImageBuilder for ImageBuilderClient {
         &self,
         input: ImportComponentRequest,
     ) -> Result<ImportComponentResponse, RusotoError<ImportComponentError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/ImportComponent";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
@@ -8186,6 +9013,12 @@ impl ImageBuilder for ImageBuilderClient {
         input: StartImagePipelineExecutionRequest,
     ) -> Result<StartImagePipelineExecutionResponse, RusotoError<StartImagePipelineExecutionError>>
     {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/StartImagePipelineExecution";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
@@ -8285,6 +9118,12 @@ impl ImageBuilder for ImageBuilderClient {
         UpdateDistributionConfigurationResponse,
         RusotoError<UpdateDistributionConfigurationError>,
     > {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/UpdateDistributionConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
@@ -8318,6 +9157,12 @@ impl ImageBuilder for ImageBuilderClient {
         &self,
         input: UpdateImagePipelineRequest,
     ) -> Result<UpdateImagePipelineResponse, RusotoError<UpdateImagePipelineError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/UpdateImagePipeline";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
@@ -8352,6 +9197,12 @@ impl ImageBuilder for ImageBuilderClient {
         UpdateInfrastructureConfigurationResponse,
         RusotoError<UpdateInfrastructureConfigurationError>,
     > {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let request_uri = "/UpdateInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -939,6 +939,18 @@ impl fmt::Display for CancelJobError {
     }
 }
 impl Error for CancelJobError {}
+impl ServiceErrorCode for CancelJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelJobError::CanceledJobId(_) => "CanceledJobIdException",
+            CancelJobError::ExpiredJobId(_) => "ExpiredJobIdException",
+            CancelJobError::InvalidAccessKeyId(_) => "InvalidAccessKeyIdException",
+            CancelJobError::InvalidJobId(_) => "InvalidJobIdException",
+            CancelJobError::InvalidVersion(_) => "InvalidVersionException",
+            CancelJobError::UnableToCancelJobId(_) => "UnableToCancelJobIdException",
+        }
+    }
+}
 /// Errors returned by CreateJob
 #[derive(Debug, PartialEq)]
 pub enum CreateJobError {
@@ -1103,6 +1115,28 @@ impl fmt::Display for CreateJobError {
     }
 }
 impl Error for CreateJobError {}
+impl ServiceErrorCode for CreateJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateJobError::BucketPermission(_) => "BucketPermissionException",
+            CreateJobError::CreateJobQuotaExceeded(_) => "CreateJobQuotaExceededException",
+            CreateJobError::InvalidAccessKeyId(_) => "InvalidAccessKeyIdException",
+            CreateJobError::InvalidAddress(_) => "InvalidAddressException",
+            CreateJobError::InvalidCustoms(_) => "InvalidCustomsException",
+            CreateJobError::InvalidFileSystem(_) => "InvalidFileSystemException",
+            CreateJobError::InvalidJobId(_) => "InvalidJobIdException",
+            CreateJobError::InvalidManifestField(_) => "InvalidManifestFieldException",
+            CreateJobError::InvalidParameter(_) => "InvalidParameterException",
+            CreateJobError::InvalidVersion(_) => "InvalidVersionException",
+            CreateJobError::MalformedManifest(_) => "MalformedManifestException",
+            CreateJobError::MissingCustoms(_) => "MissingCustomsException",
+            CreateJobError::MissingManifestField(_) => "MissingManifestFieldException",
+            CreateJobError::MissingParameter(_) => "MissingParameterException",
+            CreateJobError::MultipleRegions(_) => "MultipleRegionsException",
+            CreateJobError::NoSuchBucket(_) => "NoSuchBucketException",
+        }
+    }
+}
 /// Errors returned by GetShippingLabel
 #[derive(Debug, PartialEq)]
 pub enum GetShippingLabelError {
@@ -1195,6 +1229,19 @@ impl fmt::Display for GetShippingLabelError {
     }
 }
 impl Error for GetShippingLabelError {}
+impl ServiceErrorCode for GetShippingLabelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetShippingLabelError::CanceledJobId(_) => "CanceledJobIdException",
+            GetShippingLabelError::ExpiredJobId(_) => "ExpiredJobIdException",
+            GetShippingLabelError::InvalidAccessKeyId(_) => "InvalidAccessKeyIdException",
+            GetShippingLabelError::InvalidAddress(_) => "InvalidAddressException",
+            GetShippingLabelError::InvalidJobId(_) => "InvalidJobIdException",
+            GetShippingLabelError::InvalidParameter(_) => "InvalidParameterException",
+            GetShippingLabelError::InvalidVersion(_) => "InvalidVersionException",
+        }
+    }
+}
 /// Errors returned by GetStatus
 #[derive(Debug, PartialEq)]
 pub enum GetStatusError {
@@ -1271,6 +1318,17 @@ impl fmt::Display for GetStatusError {
     }
 }
 impl Error for GetStatusError {}
+impl ServiceErrorCode for GetStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetStatusError::CanceledJobId(_) => "CanceledJobIdException",
+            GetStatusError::ExpiredJobId(_) => "ExpiredJobIdException",
+            GetStatusError::InvalidAccessKeyId(_) => "InvalidAccessKeyIdException",
+            GetStatusError::InvalidJobId(_) => "InvalidJobIdException",
+            GetStatusError::InvalidVersion(_) => "InvalidVersionException",
+        }
+    }
+}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
 pub enum ListJobsError {
@@ -1331,6 +1389,15 @@ impl fmt::Display for ListJobsError {
     }
 }
 impl Error for ListJobsError {}
+impl ServiceErrorCode for ListJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJobsError::InvalidAccessKeyId(_) => "InvalidAccessKeyIdException",
+            ListJobsError::InvalidParameter(_) => "InvalidParameterException",
+            ListJobsError::InvalidVersion(_) => "InvalidVersionException",
+        }
+    }
+}
 /// Errors returned by UpdateJob
 #[derive(Debug, PartialEq)]
 pub enum UpdateJobError {
@@ -1511,6 +1578,30 @@ impl fmt::Display for UpdateJobError {
     }
 }
 impl Error for UpdateJobError {}
+impl ServiceErrorCode for UpdateJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateJobError::BucketPermission(_) => "BucketPermissionException",
+            UpdateJobError::CanceledJobId(_) => "CanceledJobIdException",
+            UpdateJobError::ExpiredJobId(_) => "ExpiredJobIdException",
+            UpdateJobError::InvalidAccessKeyId(_) => "InvalidAccessKeyIdException",
+            UpdateJobError::InvalidAddress(_) => "InvalidAddressException",
+            UpdateJobError::InvalidCustoms(_) => "InvalidCustomsException",
+            UpdateJobError::InvalidFileSystem(_) => "InvalidFileSystemException",
+            UpdateJobError::InvalidJobId(_) => "InvalidJobIdException",
+            UpdateJobError::InvalidManifestField(_) => "InvalidManifestFieldException",
+            UpdateJobError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateJobError::InvalidVersion(_) => "InvalidVersionException",
+            UpdateJobError::MalformedManifest(_) => "MalformedManifestException",
+            UpdateJobError::MissingCustoms(_) => "MissingCustomsException",
+            UpdateJobError::MissingManifestField(_) => "MissingManifestFieldException",
+            UpdateJobError::MissingParameter(_) => "MissingParameterException",
+            UpdateJobError::MultipleRegions(_) => "MultipleRegionsException",
+            UpdateJobError::NoSuchBucket(_) => "NoSuchBucketException",
+            UpdateJobError::UnableToUpdateJobId(_) => "UnableToUpdateJobIdException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Import/Export API. AWS Import/Export clients implement this trait.
 #[async_trait]
 pub trait ImportExport {
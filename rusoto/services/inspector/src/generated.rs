@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1630,6 +1630,19 @@ impl fmt::Display for AddAttributesToFindingsError {
     }
 }
 impl Error for AddAttributesToFindingsError {}
+impl ServiceErrorCode for AddAttributesToFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddAttributesToFindingsError::AccessDenied(_) => "AccessDeniedException",
+            AddAttributesToFindingsError::Internal(_) => "InternalException",
+            AddAttributesToFindingsError::InvalidInput(_) => "InvalidInputException",
+            AddAttributesToFindingsError::NoSuchEntity(_) => "NoSuchEntityException",
+            AddAttributesToFindingsError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateAssessmentTarget
 #[derive(Debug, PartialEq)]
 pub enum CreateAssessmentTargetError {
@@ -1706,6 +1719,23 @@ impl fmt::Display for CreateAssessmentTargetError {
     }
 }
 impl Error for CreateAssessmentTargetError {}
+impl ServiceErrorCode for CreateAssessmentTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAssessmentTargetError::AccessDenied(_) => "AccessDeniedException",
+            CreateAssessmentTargetError::Internal(_) => "InternalException",
+            CreateAssessmentTargetError::InvalidCrossAccountRole(_) => {
+                "InvalidCrossAccountRoleException"
+            }
+            CreateAssessmentTargetError::InvalidInput(_) => "InvalidInputException",
+            CreateAssessmentTargetError::LimitExceeded(_) => "LimitExceededException",
+            CreateAssessmentTargetError::NoSuchEntity(_) => "NoSuchEntityException",
+            CreateAssessmentTargetError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateAssessmentTemplate
 #[derive(Debug, PartialEq)]
 pub enum CreateAssessmentTemplateError {
@@ -1778,6 +1808,20 @@ impl fmt::Display for CreateAssessmentTemplateError {
     }
 }
 impl Error for CreateAssessmentTemplateError {}
+impl ServiceErrorCode for CreateAssessmentTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAssessmentTemplateError::AccessDenied(_) => "AccessDeniedException",
+            CreateAssessmentTemplateError::Internal(_) => "InternalException",
+            CreateAssessmentTemplateError::InvalidInput(_) => "InvalidInputException",
+            CreateAssessmentTemplateError::LimitExceeded(_) => "LimitExceededException",
+            CreateAssessmentTemplateError::NoSuchEntity(_) => "NoSuchEntityException",
+            CreateAssessmentTemplateError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateExclusionsPreview
 #[derive(Debug, PartialEq)]
 pub enum CreateExclusionsPreviewError {
@@ -1852,6 +1896,22 @@ impl fmt::Display for CreateExclusionsPreviewError {
     }
 }
 impl Error for CreateExclusionsPreviewError {}
+impl ServiceErrorCode for CreateExclusionsPreviewError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateExclusionsPreviewError::AccessDenied(_) => "AccessDeniedException",
+            CreateExclusionsPreviewError::Internal(_) => "InternalException",
+            CreateExclusionsPreviewError::InvalidInput(_) => "InvalidInputException",
+            CreateExclusionsPreviewError::NoSuchEntity(_) => "NoSuchEntityException",
+            CreateExclusionsPreviewError::PreviewGenerationInProgress(_) => {
+                "PreviewGenerationInProgressException"
+            }
+            CreateExclusionsPreviewError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateResourceGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateResourceGroupError {
@@ -1910,6 +1970,19 @@ impl fmt::Display for CreateResourceGroupError {
     }
 }
 impl Error for CreateResourceGroupError {}
+impl ServiceErrorCode for CreateResourceGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateResourceGroupError::AccessDenied(_) => "AccessDeniedException",
+            CreateResourceGroupError::Internal(_) => "InternalException",
+            CreateResourceGroupError::InvalidInput(_) => "InvalidInputException",
+            CreateResourceGroupError::LimitExceeded(_) => "LimitExceededException",
+            CreateResourceGroupError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteAssessmentRun
 #[derive(Debug, PartialEq)]
 pub enum DeleteAssessmentRunError {
@@ -1976,6 +2049,22 @@ impl fmt::Display for DeleteAssessmentRunError {
     }
 }
 impl Error for DeleteAssessmentRunError {}
+impl ServiceErrorCode for DeleteAssessmentRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAssessmentRunError::AccessDenied(_) => "AccessDeniedException",
+            DeleteAssessmentRunError::AssessmentRunInProgress(_) => {
+                "AssessmentRunInProgressException"
+            }
+            DeleteAssessmentRunError::Internal(_) => "InternalException",
+            DeleteAssessmentRunError::InvalidInput(_) => "InvalidInputException",
+            DeleteAssessmentRunError::NoSuchEntity(_) => "NoSuchEntityException",
+            DeleteAssessmentRunError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteAssessmentTarget
 #[derive(Debug, PartialEq)]
 pub enum DeleteAssessmentTargetError {
@@ -2044,6 +2133,22 @@ impl fmt::Display for DeleteAssessmentTargetError {
     }
 }
 impl Error for DeleteAssessmentTargetError {}
+impl ServiceErrorCode for DeleteAssessmentTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAssessmentTargetError::AccessDenied(_) => "AccessDeniedException",
+            DeleteAssessmentTargetError::AssessmentRunInProgress(_) => {
+                "AssessmentRunInProgressException"
+            }
+            DeleteAssessmentTargetError::Internal(_) => "InternalException",
+            DeleteAssessmentTargetError::InvalidInput(_) => "InvalidInputException",
+            DeleteAssessmentTargetError::NoSuchEntity(_) => "NoSuchEntityException",
+            DeleteAssessmentTargetError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteAssessmentTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeleteAssessmentTemplateError {
@@ -2118,6 +2223,22 @@ impl fmt::Display for DeleteAssessmentTemplateError {
     }
 }
 impl Error for DeleteAssessmentTemplateError {}
+impl ServiceErrorCode for DeleteAssessmentTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAssessmentTemplateError::AccessDenied(_) => "AccessDeniedException",
+            DeleteAssessmentTemplateError::AssessmentRunInProgress(_) => {
+                "AssessmentRunInProgressException"
+            }
+            DeleteAssessmentTemplateError::Internal(_) => "InternalException",
+            DeleteAssessmentTemplateError::InvalidInput(_) => "InvalidInputException",
+            DeleteAssessmentTemplateError::NoSuchEntity(_) => "NoSuchEntityException",
+            DeleteAssessmentTemplateError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeAssessmentRuns
 #[derive(Debug, PartialEq)]
 pub enum DescribeAssessmentRunsError {
@@ -2154,6 +2275,14 @@ impl fmt::Display for DescribeAssessmentRunsError {
     }
 }
 impl Error for DescribeAssessmentRunsError {}
+impl ServiceErrorCode for DescribeAssessmentRunsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAssessmentRunsError::Internal(_) => "InternalException",
+            DescribeAssessmentRunsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DescribeAssessmentTargets
 #[derive(Debug, PartialEq)]
 pub enum DescribeAssessmentTargetsError {
@@ -2192,6 +2321,14 @@ impl fmt::Display for DescribeAssessmentTargetsError {
     }
 }
 impl Error for DescribeAssessmentTargetsError {}
+impl ServiceErrorCode for DescribeAssessmentTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAssessmentTargetsError::Internal(_) => "InternalException",
+            DescribeAssessmentTargetsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DescribeAssessmentTemplates
 #[derive(Debug, PartialEq)]
 pub enum DescribeAssessmentTemplatesError {
@@ -2234,6 +2371,14 @@ impl fmt::Display for DescribeAssessmentTemplatesError {
     }
 }
 impl Error for DescribeAssessmentTemplatesError {}
+impl ServiceErrorCode for DescribeAssessmentTemplatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAssessmentTemplatesError::Internal(_) => "InternalException",
+            DescribeAssessmentTemplatesError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DescribeCrossAccountAccessRole
 #[derive(Debug, PartialEq)]
 pub enum DescribeCrossAccountAccessRoleError {
@@ -2268,6 +2413,13 @@ impl fmt::Display for DescribeCrossAccountAccessRoleError {
     }
 }
 impl Error for DescribeCrossAccountAccessRoleError {}
+impl ServiceErrorCode for DescribeCrossAccountAccessRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCrossAccountAccessRoleError::Internal(_) => "InternalException",
+        }
+    }
+}
 /// Errors returned by DescribeExclusions
 #[derive(Debug, PartialEq)]
 pub enum DescribeExclusionsError {
@@ -2304,6 +2456,14 @@ impl fmt::Display for DescribeExclusionsError {
     }
 }
 impl Error for DescribeExclusionsError {}
+impl ServiceErrorCode for DescribeExclusionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeExclusionsError::Internal(_) => "InternalException",
+            DescribeExclusionsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DescribeFindings
 #[derive(Debug, PartialEq)]
 pub enum DescribeFindingsError {
@@ -2340,6 +2500,14 @@ impl fmt::Display for DescribeFindingsError {
     }
 }
 impl Error for DescribeFindingsError {}
+impl ServiceErrorCode for DescribeFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFindingsError::Internal(_) => "InternalException",
+            DescribeFindingsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DescribeResourceGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeResourceGroupsError {
@@ -2376,6 +2544,14 @@ impl fmt::Display for DescribeResourceGroupsError {
     }
 }
 impl Error for DescribeResourceGroupsError {}
+impl ServiceErrorCode for DescribeResourceGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeResourceGroupsError::Internal(_) => "InternalException",
+            DescribeResourceGroupsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DescribeRulesPackages
 #[derive(Debug, PartialEq)]
 pub enum DescribeRulesPackagesError {
@@ -2412,6 +2588,14 @@ impl fmt::Display for DescribeRulesPackagesError {
     }
 }
 impl Error for DescribeRulesPackagesError {}
+impl ServiceErrorCode for DescribeRulesPackagesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRulesPackagesError::Internal(_) => "InternalException",
+            DescribeRulesPackagesError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by GetAssessmentReport
 #[derive(Debug, PartialEq)]
 pub enum GetAssessmentReportError {
@@ -2486,6 +2670,23 @@ impl fmt::Display for GetAssessmentReportError {
     }
 }
 impl Error for GetAssessmentReportError {}
+impl ServiceErrorCode for GetAssessmentReportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAssessmentReportError::AccessDenied(_) => "AccessDeniedException",
+            GetAssessmentReportError::AssessmentRunInProgress(_) => {
+                "AssessmentRunInProgressException"
+            }
+            GetAssessmentReportError::Internal(_) => "InternalException",
+            GetAssessmentReportError::InvalidInput(_) => "InvalidInputException",
+            GetAssessmentReportError::NoSuchEntity(_) => "NoSuchEntityException",
+            GetAssessmentReportError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+            GetAssessmentReportError::UnsupportedFeature(_) => "UnsupportedFeatureException",
+        }
+    }
+}
 /// Errors returned by GetExclusionsPreview
 #[derive(Debug, PartialEq)]
 pub enum GetExclusionsPreviewError {
@@ -2534,6 +2735,16 @@ impl fmt::Display for GetExclusionsPreviewError {
     }
 }
 impl Error for GetExclusionsPreviewError {}
+impl ServiceErrorCode for GetExclusionsPreviewError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetExclusionsPreviewError::AccessDenied(_) => "AccessDeniedException",
+            GetExclusionsPreviewError::Internal(_) => "InternalException",
+            GetExclusionsPreviewError::InvalidInput(_) => "InvalidInputException",
+            GetExclusionsPreviewError::NoSuchEntity(_) => "NoSuchEntityException",
+        }
+    }
+}
 /// Errors returned by GetTelemetryMetadata
 #[derive(Debug, PartialEq)]
 pub enum GetTelemetryMetadataError {
@@ -2582,6 +2793,16 @@ impl fmt::Display for GetTelemetryMetadataError {
     }
 }
 impl Error for GetTelemetryMetadataError {}
+impl ServiceErrorCode for GetTelemetryMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTelemetryMetadataError::AccessDenied(_) => "AccessDeniedException",
+            GetTelemetryMetadataError::Internal(_) => "InternalException",
+            GetTelemetryMetadataError::InvalidInput(_) => "InvalidInputException",
+            GetTelemetryMetadataError::NoSuchEntity(_) => "NoSuchEntityException",
+        }
+    }
+}
 /// Errors returned by ListAssessmentRunAgents
 #[derive(Debug, PartialEq)]
 pub enum ListAssessmentRunAgentsError {
@@ -2636,6 +2857,16 @@ impl fmt::Display for ListAssessmentRunAgentsError {
     }
 }
 impl Error for ListAssessmentRunAgentsError {}
+impl ServiceErrorCode for ListAssessmentRunAgentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAssessmentRunAgentsError::AccessDenied(_) => "AccessDeniedException",
+            ListAssessmentRunAgentsError::Internal(_) => "InternalException",
+            ListAssessmentRunAgentsError::InvalidInput(_) => "InvalidInputException",
+            ListAssessmentRunAgentsError::NoSuchEntity(_) => "NoSuchEntityException",
+        }
+    }
+}
 /// Errors returned by ListAssessmentRuns
 #[derive(Debug, PartialEq)]
 pub enum ListAssessmentRunsError {
@@ -2684,6 +2915,16 @@ impl fmt::Display for ListAssessmentRunsError {
     }
 }
 impl Error for ListAssessmentRunsError {}
+impl ServiceErrorCode for ListAssessmentRunsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAssessmentRunsError::AccessDenied(_) => "AccessDeniedException",
+            ListAssessmentRunsError::Internal(_) => "InternalException",
+            ListAssessmentRunsError::InvalidInput(_) => "InvalidInputException",
+            ListAssessmentRunsError::NoSuchEntity(_) => "NoSuchEntityException",
+        }
+    }
+}
 /// Errors returned by ListAssessmentTargets
 #[derive(Debug, PartialEq)]
 pub enum ListAssessmentTargetsError {
@@ -2726,6 +2967,15 @@ impl fmt::Display for ListAssessmentTargetsError {
     }
 }
 impl Error for ListAssessmentTargetsError {}
+impl ServiceErrorCode for ListAssessmentTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAssessmentTargetsError::AccessDenied(_) => "AccessDeniedException",
+            ListAssessmentTargetsError::Internal(_) => "InternalException",
+            ListAssessmentTargetsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ListAssessmentTemplates
 #[derive(Debug, PartialEq)]
 pub enum ListAssessmentTemplatesError {
@@ -2780,6 +3030,16 @@ impl fmt::Display for ListAssessmentTemplatesError {
     }
 }
 impl Error for ListAssessmentTemplatesError {}
+impl ServiceErrorCode for ListAssessmentTemplatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAssessmentTemplatesError::AccessDenied(_) => "AccessDeniedException",
+            ListAssessmentTemplatesError::Internal(_) => "InternalException",
+            ListAssessmentTemplatesError::InvalidInput(_) => "InvalidInputException",
+            ListAssessmentTemplatesError::NoSuchEntity(_) => "NoSuchEntityException",
+        }
+    }
+}
 /// Errors returned by ListEventSubscriptions
 #[derive(Debug, PartialEq)]
 pub enum ListEventSubscriptionsError {
@@ -2828,6 +3088,16 @@ impl fmt::Display for ListEventSubscriptionsError {
     }
 }
 impl Error for ListEventSubscriptionsError {}
+impl ServiceErrorCode for ListEventSubscriptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEventSubscriptionsError::AccessDenied(_) => "AccessDeniedException",
+            ListEventSubscriptionsError::Internal(_) => "InternalException",
+            ListEventSubscriptionsError::InvalidInput(_) => "InvalidInputException",
+            ListEventSubscriptionsError::NoSuchEntity(_) => "NoSuchEntityException",
+        }
+    }
+}
 /// Errors returned by ListExclusions
 #[derive(Debug, PartialEq)]
 pub enum ListExclusionsError {
@@ -2876,6 +3146,16 @@ impl fmt::Display for ListExclusionsError {
     }
 }
 impl Error for ListExclusionsError {}
+impl ServiceErrorCode for ListExclusionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListExclusionsError::AccessDenied(_) => "AccessDeniedException",
+            ListExclusionsError::Internal(_) => "InternalException",
+            ListExclusionsError::InvalidInput(_) => "InvalidInputException",
+            ListExclusionsError::NoSuchEntity(_) => "NoSuchEntityException",
+        }
+    }
+}
 /// Errors returned by ListFindings
 #[derive(Debug, PartialEq)]
 pub enum ListFindingsError {
@@ -2924,6 +3204,16 @@ impl fmt::Display for ListFindingsError {
     }
 }
 impl Error for ListFindingsError {}
+impl ServiceErrorCode for ListFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFindingsError::AccessDenied(_) => "AccessDeniedException",
+            ListFindingsError::Internal(_) => "InternalException",
+            ListFindingsError::InvalidInput(_) => "InvalidInputException",
+            ListFindingsError::NoSuchEntity(_) => "NoSuchEntityException",
+        }
+    }
+}
 /// Errors returned by ListRulesPackages
 #[derive(Debug, PartialEq)]
 pub enum ListRulesPackagesError {
@@ -2966,6 +3256,15 @@ impl fmt::Display for ListRulesPackagesError {
     }
 }
 impl Error for ListRulesPackagesError {}
+impl ServiceErrorCode for ListRulesPackagesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRulesPackagesError::AccessDenied(_) => "AccessDeniedException",
+            ListRulesPackagesError::Internal(_) => "InternalException",
+            ListRulesPackagesError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3014,6 +3313,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            ListTagsForResourceError::Internal(_) => "InternalException",
+            ListTagsForResourceError::InvalidInput(_) => "InvalidInputException",
+            ListTagsForResourceError::NoSuchEntity(_) => "NoSuchEntityException",
+        }
+    }
+}
 /// Errors returned by PreviewAgents
 #[derive(Debug, PartialEq)]
 pub enum PreviewAgentsError {
@@ -3070,6 +3379,17 @@ impl fmt::Display for PreviewAgentsError {
     }
 }
 impl Error for PreviewAgentsError {}
+impl ServiceErrorCode for PreviewAgentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PreviewAgentsError::AccessDenied(_) => "AccessDeniedException",
+            PreviewAgentsError::Internal(_) => "InternalException",
+            PreviewAgentsError::InvalidCrossAccountRole(_) => "InvalidCrossAccountRoleException",
+            PreviewAgentsError::InvalidInput(_) => "InvalidInputException",
+            PreviewAgentsError::NoSuchEntity(_) => "NoSuchEntityException",
+        }
+    }
+}
 /// Errors returned by RegisterCrossAccountAccessRole
 #[derive(Debug, PartialEq)]
 pub enum RegisterCrossAccountAccessRoleError {
@@ -3140,6 +3460,21 @@ impl fmt::Display for RegisterCrossAccountAccessRoleError {
     }
 }
 impl Error for RegisterCrossAccountAccessRoleError {}
+impl ServiceErrorCode for RegisterCrossAccountAccessRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterCrossAccountAccessRoleError::AccessDenied(_) => "AccessDeniedException",
+            RegisterCrossAccountAccessRoleError::Internal(_) => "InternalException",
+            RegisterCrossAccountAccessRoleError::InvalidCrossAccountRole(_) => {
+                "InvalidCrossAccountRoleException"
+            }
+            RegisterCrossAccountAccessRoleError::InvalidInput(_) => "InvalidInputException",
+            RegisterCrossAccountAccessRoleError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by RemoveAttributesFromFindings
 #[derive(Debug, PartialEq)]
 pub enum RemoveAttributesFromFindingsError {
@@ -3208,6 +3543,19 @@ impl fmt::Display for RemoveAttributesFromFindingsError {
     }
 }
 impl Error for RemoveAttributesFromFindingsError {}
+impl ServiceErrorCode for RemoveAttributesFromFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveAttributesFromFindingsError::AccessDenied(_) => "AccessDeniedException",
+            RemoveAttributesFromFindingsError::Internal(_) => "InternalException",
+            RemoveAttributesFromFindingsError::InvalidInput(_) => "InvalidInputException",
+            RemoveAttributesFromFindingsError::NoSuchEntity(_) => "NoSuchEntityException",
+            RemoveAttributesFromFindingsError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by SetTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum SetTagsForResourceError {
@@ -3266,6 +3614,19 @@ impl fmt::Display for SetTagsForResourceError {
     }
 }
 impl Error for SetTagsForResourceError {}
+impl ServiceErrorCode for SetTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetTagsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            SetTagsForResourceError::Internal(_) => "InternalException",
+            SetTagsForResourceError::InvalidInput(_) => "InvalidInputException",
+            SetTagsForResourceError::NoSuchEntity(_) => "NoSuchEntityException",
+            SetTagsForResourceError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by StartAssessmentRun
 #[derive(Debug, PartialEq)]
 pub enum StartAssessmentRunError {
@@ -3348,6 +3709,26 @@ impl fmt::Display for StartAssessmentRunError {
     }
 }
 impl Error for StartAssessmentRunError {}
+impl ServiceErrorCode for StartAssessmentRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartAssessmentRunError::AccessDenied(_) => "AccessDeniedException",
+            StartAssessmentRunError::AgentsAlreadyRunningAssessment(_) => {
+                "AgentsAlreadyRunningAssessmentException"
+            }
+            StartAssessmentRunError::Internal(_) => "InternalException",
+            StartAssessmentRunError::InvalidCrossAccountRole(_) => {
+                "InvalidCrossAccountRoleException"
+            }
+            StartAssessmentRunError::InvalidInput(_) => "InvalidInputException",
+            StartAssessmentRunError::LimitExceeded(_) => "LimitExceededException",
+            StartAssessmentRunError::NoSuchEntity(_) => "NoSuchEntityException",
+            StartAssessmentRunError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by StopAssessmentRun
 #[derive(Debug, PartialEq)]
 pub enum StopAssessmentRunError {
@@ -3406,6 +3787,19 @@ impl fmt::Display for StopAssessmentRunError {
     }
 }
 impl Error for StopAssessmentRunError {}
+impl ServiceErrorCode for StopAssessmentRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopAssessmentRunError::AccessDenied(_) => "AccessDeniedException",
+            StopAssessmentRunError::Internal(_) => "InternalException",
+            StopAssessmentRunError::InvalidInput(_) => "InvalidInputException",
+            StopAssessmentRunError::NoSuchEntity(_) => "NoSuchEntityException",
+            StopAssessmentRunError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by SubscribeToEvent
 #[derive(Debug, PartialEq)]
 pub enum SubscribeToEventError {
@@ -3470,6 +3864,20 @@ impl fmt::Display for SubscribeToEventError {
     }
 }
 impl Error for SubscribeToEventError {}
+impl ServiceErrorCode for SubscribeToEventError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SubscribeToEventError::AccessDenied(_) => "AccessDeniedException",
+            SubscribeToEventError::Internal(_) => "InternalException",
+            SubscribeToEventError::InvalidInput(_) => "InvalidInputException",
+            SubscribeToEventError::LimitExceeded(_) => "LimitExceededException",
+            SubscribeToEventError::NoSuchEntity(_) => "NoSuchEntityException",
+            SubscribeToEventError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by UnsubscribeFromEvent
 #[derive(Debug, PartialEq)]
 pub enum UnsubscribeFromEventError {
@@ -3528,6 +3936,19 @@ impl fmt::Display for UnsubscribeFromEventError {
     }
 }
 impl Error for UnsubscribeFromEventError {}
+impl ServiceErrorCode for UnsubscribeFromEventError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UnsubscribeFromEventError::AccessDenied(_) => "AccessDeniedException",
+            UnsubscribeFromEventError::Internal(_) => "InternalException",
+            UnsubscribeFromEventError::InvalidInput(_) => "InvalidInputException",
+            UnsubscribeFromEventError::NoSuchEntity(_) => "NoSuchEntityException",
+            UnsubscribeFromEventError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateAssessmentTarget
 #[derive(Debug, PartialEq)]
 pub enum UpdateAssessmentTargetError {
@@ -3586,6 +4007,19 @@ impl fmt::Display for UpdateAssessmentTargetError {
     }
 }
 impl Error for UpdateAssessmentTargetError {}
+impl ServiceErrorCode for UpdateAssessmentTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAssessmentTargetError::AccessDenied(_) => "AccessDeniedException",
+            UpdateAssessmentTargetError::Internal(_) => "InternalException",
+            UpdateAssessmentTargetError::InvalidInput(_) => "InvalidInputException",
+            UpdateAssessmentTargetError::NoSuchEntity(_) => "NoSuchEntityException",
+            UpdateAssessmentTargetError::ServiceTemporarilyUnavailable(_) => {
+                "ServiceTemporarilyUnavailableException"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Inspector API. Amazon Inspector clients implement this trait.
 #[async_trait]
 pub trait Inspector {
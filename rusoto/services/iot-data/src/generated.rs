@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -225,6 +225,22 @@ impl fmt::Display for DeleteThingShadowError {
     }
 }
 impl Error for DeleteThingShadowError {}
+impl ServiceErrorCode for DeleteThingShadowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteThingShadowError::InternalFailure(_) => "InternalFailureException",
+            DeleteThingShadowError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteThingShadowError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            DeleteThingShadowError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteThingShadowError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteThingShadowError::Throttling(_) => "ThrottlingException",
+            DeleteThingShadowError::Unauthorized(_) => "UnauthorizedException",
+            DeleteThingShadowError::UnsupportedDocumentEncoding(_) => {
+                "UnsupportedDocumentEncodingException"
+            }
+        }
+    }
+}
 /// Errors returned by GetThingShadow
 #[derive(Debug, PartialEq)]
 pub enum GetThingShadowError {
@@ -299,6 +315,22 @@ impl fmt::Display for GetThingShadowError {
     }
 }
 impl Error for GetThingShadowError {}
+impl ServiceErrorCode for GetThingShadowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetThingShadowError::InternalFailure(_) => "InternalFailureException",
+            GetThingShadowError::InvalidRequest(_) => "InvalidRequestException",
+            GetThingShadowError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            GetThingShadowError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetThingShadowError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetThingShadowError::Throttling(_) => "ThrottlingException",
+            GetThingShadowError::Unauthorized(_) => "UnauthorizedException",
+            GetThingShadowError::UnsupportedDocumentEncoding(_) => {
+                "UnsupportedDocumentEncodingException"
+            }
+        }
+    }
+}
 /// Errors returned by ListNamedShadowsForThing
 #[derive(Debug, PartialEq)]
 pub enum ListNamedShadowsForThingError {
@@ -377,6 +409,19 @@ impl fmt::Display for ListNamedShadowsForThingError {
     }
 }
 impl Error for ListNamedShadowsForThingError {}
+impl ServiceErrorCode for ListNamedShadowsForThingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListNamedShadowsForThingError::InternalFailure(_) => "InternalFailureException",
+            ListNamedShadowsForThingError::InvalidRequest(_) => "InvalidRequestException",
+            ListNamedShadowsForThingError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            ListNamedShadowsForThingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListNamedShadowsForThingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListNamedShadowsForThingError::Throttling(_) => "ThrottlingException",
+            ListNamedShadowsForThingError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by Publish
 #[derive(Debug, PartialEq)]
 pub enum PublishError {
@@ -425,6 +470,16 @@ impl fmt::Display for PublishError {
     }
 }
 impl Error for PublishError {}
+impl ServiceErrorCode for PublishError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PublishError::InternalFailure(_) => "InternalFailureException",
+            PublishError::InvalidRequest(_) => "InvalidRequestException",
+            PublishError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            PublishError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateThingShadow
 #[derive(Debug, PartialEq)]
 pub enum UpdateThingShadowError {
@@ -511,6 +566,23 @@ impl fmt::Display for UpdateThingShadowError {
     }
 }
 impl Error for UpdateThingShadowError {}
+impl ServiceErrorCode for UpdateThingShadowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateThingShadowError::Conflict(_) => "ConflictException",
+            UpdateThingShadowError::InternalFailure(_) => "InternalFailureException",
+            UpdateThingShadowError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateThingShadowError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            UpdateThingShadowError::RequestEntityTooLarge(_) => "RequestEntityTooLargeException",
+            UpdateThingShadowError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateThingShadowError::Throttling(_) => "ThrottlingException",
+            UpdateThingShadowError::Unauthorized(_) => "UnauthorizedException",
+            UpdateThingShadowError::UnsupportedDocumentEncoding(_) => {
+                "UnsupportedDocumentEncodingException"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS IoT Data Plane API. AWS IoT Data Plane clients implement this trait.
 #[async_trait]
 pub trait IotData {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -314,6 +314,18 @@ impl fmt::Display for DescribeJobExecutionError {
     }
 }
 impl Error for DescribeJobExecutionError {}
+impl ServiceErrorCode for DescribeJobExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeJobExecutionError::CertificateValidation(_) => "CertificateValidationException",
+            DescribeJobExecutionError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeJobExecutionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeJobExecutionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeJobExecutionError::TerminalState(_) => "TerminalStateException",
+            DescribeJobExecutionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetPendingJobExecutions
 #[derive(Debug, PartialEq)]
 pub enum GetPendingJobExecutionsError {
@@ -378,6 +390,19 @@ impl fmt::Display for GetPendingJobExecutionsError {
     }
 }
 impl Error for GetPendingJobExecutionsError {}
+impl ServiceErrorCode for GetPendingJobExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPendingJobExecutionsError::CertificateValidation(_) => {
+                "CertificateValidationException"
+            }
+            GetPendingJobExecutionsError::InvalidRequest(_) => "InvalidRequestException",
+            GetPendingJobExecutionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetPendingJobExecutionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetPendingJobExecutionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StartNextPendingJobExecution
 #[derive(Debug, PartialEq)]
 pub enum StartNextPendingJobExecutionError {
@@ -450,6 +475,21 @@ impl fmt::Display for StartNextPendingJobExecutionError {
     }
 }
 impl Error for StartNextPendingJobExecutionError {}
+impl ServiceErrorCode for StartNextPendingJobExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartNextPendingJobExecutionError::CertificateValidation(_) => {
+                "CertificateValidationException"
+            }
+            StartNextPendingJobExecutionError::InvalidRequest(_) => "InvalidRequestException",
+            StartNextPendingJobExecutionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartNextPendingJobExecutionError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            StartNextPendingJobExecutionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateJobExecution
 #[derive(Debug, PartialEq)]
 pub enum UpdateJobExecutionError {
@@ -516,6 +556,18 @@ impl fmt::Display for UpdateJobExecutionError {
     }
 }
 impl Error for UpdateJobExecutionError {}
+impl ServiceErrorCode for UpdateJobExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateJobExecutionError::CertificateValidation(_) => "CertificateValidationException",
+            UpdateJobExecutionError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateJobExecutionError::InvalidStateTransition(_) => "InvalidStateTransitionException",
+            UpdateJobExecutionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateJobExecutionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateJobExecutionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS IoT Jobs Data Plane API. AWS IoT Jobs Data Plane clients implement this trait.
 #[async_trait]
 pub trait IotJobsData {
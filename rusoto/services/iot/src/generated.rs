@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1365,6 +1365,7 @@ pub struct CreateAuditSuppressionRequest {
     #[serde(rename = "checkName")]
     pub check_name: String,
     /// <p> The epoch timestamp in seconds at which this suppression expires. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     pub client_request_token: String,
     /// <p> The description of the audit suppression. </p>
@@ -1499,6 +1500,7 @@ pub struct CreateCertificateFromCsrResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateCustomMetricRequest {
     /// <p>Each custom metric must have a unique client request token. If you try to create a new custom metric that already exists with a different token, an exception occurs. If you omit this value, AWS SDKs will automatically generate a unique client request. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     pub client_request_token: String,
     /// <p> Field represents a friendly name in the console for the custom metric; it doesn't have to be unique. Don't use this name as the metric identifier in the device metric report. Can be updated once defined.</p>
@@ -1534,6 +1536,7 @@ pub struct CreateCustomMetricResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateDimensionRequest {
     /// <p>Each dimension must have a unique client request token. If you try to create a new dimension with the same token as a dimension that already exists, an exception occurs. If you omit this value, AWS SDKs will automatically generate a unique client request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     pub client_request_token: String,
     /// <p>A unique identifier for the dimension. Choose something that describes the type and value to make it easy to remember what it does.</p>
@@ -7954,6 +7957,7 @@ pub struct StartAuditMitigationActionsTaskRequest {
     #[serde(rename = "auditCheckToActionsMapping")]
     pub audit_check_to_actions_mapping: ::std::collections::HashMap<String, Vec<String>>,
     /// <p>Each audit mitigation task must have a unique client request token. If you try to start a new task with the same token as a task that already exists, an exception occurs. If you omit this value, a unique client request token is generated automatically.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     pub client_request_token: String,
     /// <p>Specifies the audit findings to which the mitigation actions are applied. You can apply them to a type of audit check, to all findings from an audit, or to a specific set of findings.</p>
@@ -7980,6 +7984,7 @@ pub struct StartDetectMitigationActionsTaskRequest {
     #[serde(rename = "actions")]
     pub actions: Vec<String>,
     /// <p> Each mitigation action task must have a unique client request token. If you try to create a new task with the same token as a task that already exists, an exception occurs. If you omit this value, AWS SDKs will automatically generate a unique client request. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     pub client_request_token: String,
     /// <p> Specifies to list only active violations. </p>
@@ -9882,6 +9887,21 @@ impl fmt::Display for AcceptCertificateTransferError {
     }
 }
 impl Error for AcceptCertificateTransferError {}
+impl ServiceErrorCode for AcceptCertificateTransferError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptCertificateTransferError::InternalFailure(_) => "InternalFailureException",
+            AcceptCertificateTransferError::InvalidRequest(_) => "InvalidRequestException",
+            AcceptCertificateTransferError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AcceptCertificateTransferError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            AcceptCertificateTransferError::Throttling(_) => "ThrottlingException",
+            AcceptCertificateTransferError::TransferAlreadyCompleted(_) => {
+                "TransferAlreadyCompletedException"
+            }
+            AcceptCertificateTransferError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by AddThingToBillingGroup
 #[derive(Debug, PartialEq)]
 pub enum AddThingToBillingGroupError {
@@ -9936,6 +9956,16 @@ impl fmt::Display for AddThingToBillingGroupError {
     }
 }
 impl Error for AddThingToBillingGroupError {}
+impl ServiceErrorCode for AddThingToBillingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddThingToBillingGroupError::InternalFailure(_) => "InternalFailureException",
+            AddThingToBillingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            AddThingToBillingGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AddThingToBillingGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by AddThingToThingGroup
 #[derive(Debug, PartialEq)]
 pub enum AddThingToThingGroupError {
@@ -9988,6 +10018,16 @@ impl fmt::Display for AddThingToThingGroupError {
     }
 }
 impl Error for AddThingToThingGroupError {}
+impl ServiceErrorCode for AddThingToThingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddThingToThingGroupError::InternalFailure(_) => "InternalFailureException",
+            AddThingToThingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            AddThingToThingGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AddThingToThingGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by AssociateTargetsWithJob
 #[derive(Debug, PartialEq)]
 pub enum AssociateTargetsWithJobError {
@@ -10050,6 +10090,17 @@ impl fmt::Display for AssociateTargetsWithJobError {
     }
 }
 impl Error for AssociateTargetsWithJobError {}
+impl ServiceErrorCode for AssociateTargetsWithJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateTargetsWithJobError::InvalidRequest(_) => "InvalidRequestException",
+            AssociateTargetsWithJobError::LimitExceeded(_) => "LimitExceededException",
+            AssociateTargetsWithJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateTargetsWithJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            AssociateTargetsWithJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by AttachPolicy
 #[derive(Debug, PartialEq)]
 pub enum AttachPolicyError {
@@ -10116,6 +10167,19 @@ impl fmt::Display for AttachPolicyError {
     }
 }
 impl Error for AttachPolicyError {}
+impl ServiceErrorCode for AttachPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachPolicyError::InternalFailure(_) => "InternalFailureException",
+            AttachPolicyError::InvalidRequest(_) => "InvalidRequestException",
+            AttachPolicyError::LimitExceeded(_) => "LimitExceededException",
+            AttachPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AttachPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            AttachPolicyError::Throttling(_) => "ThrottlingException",
+            AttachPolicyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by AttachPrincipalPolicy
 #[derive(Debug, PartialEq)]
 pub enum AttachPrincipalPolicyError {
@@ -10190,6 +10254,19 @@ impl fmt::Display for AttachPrincipalPolicyError {
     }
 }
 impl Error for AttachPrincipalPolicyError {}
+impl ServiceErrorCode for AttachPrincipalPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachPrincipalPolicyError::InternalFailure(_) => "InternalFailureException",
+            AttachPrincipalPolicyError::InvalidRequest(_) => "InvalidRequestException",
+            AttachPrincipalPolicyError::LimitExceeded(_) => "LimitExceededException",
+            AttachPrincipalPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AttachPrincipalPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            AttachPrincipalPolicyError::Throttling(_) => "ThrottlingException",
+            AttachPrincipalPolicyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by AttachSecurityProfile
 #[derive(Debug, PartialEq)]
 pub enum AttachSecurityProfileError {
@@ -10258,6 +10335,18 @@ impl fmt::Display for AttachSecurityProfileError {
     }
 }
 impl Error for AttachSecurityProfileError {}
+impl ServiceErrorCode for AttachSecurityProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachSecurityProfileError::InternalFailure(_) => "InternalFailureException",
+            AttachSecurityProfileError::InvalidRequest(_) => "InvalidRequestException",
+            AttachSecurityProfileError::LimitExceeded(_) => "LimitExceededException",
+            AttachSecurityProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AttachSecurityProfileError::Throttling(_) => "ThrottlingException",
+            AttachSecurityProfileError::VersionConflict(_) => "VersionConflictException",
+        }
+    }
+}
 /// Errors returned by AttachThingPrincipal
 #[derive(Debug, PartialEq)]
 pub enum AttachThingPrincipalError {
@@ -10324,6 +10413,18 @@ impl fmt::Display for AttachThingPrincipalError {
     }
 }
 impl Error for AttachThingPrincipalError {}
+impl ServiceErrorCode for AttachThingPrincipalError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachThingPrincipalError::InternalFailure(_) => "InternalFailureException",
+            AttachThingPrincipalError::InvalidRequest(_) => "InvalidRequestException",
+            AttachThingPrincipalError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AttachThingPrincipalError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            AttachThingPrincipalError::Throttling(_) => "ThrottlingException",
+            AttachThingPrincipalError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CancelAuditMitigationActionsTask
 #[derive(Debug, PartialEq)]
 pub enum CancelAuditMitigationActionsTaskError {
@@ -10388,6 +10489,18 @@ impl fmt::Display for CancelAuditMitigationActionsTaskError {
     }
 }
 impl Error for CancelAuditMitigationActionsTaskError {}
+impl ServiceErrorCode for CancelAuditMitigationActionsTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelAuditMitigationActionsTaskError::InternalFailure(_) => "InternalFailureException",
+            CancelAuditMitigationActionsTaskError::InvalidRequest(_) => "InvalidRequestException",
+            CancelAuditMitigationActionsTaskError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            CancelAuditMitigationActionsTaskError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CancelAuditTask
 #[derive(Debug, PartialEq)]
 pub enum CancelAuditTaskError {
@@ -10436,6 +10549,16 @@ impl fmt::Display for CancelAuditTaskError {
     }
 }
 impl Error for CancelAuditTaskError {}
+impl ServiceErrorCode for CancelAuditTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelAuditTaskError::InternalFailure(_) => "InternalFailureException",
+            CancelAuditTaskError::InvalidRequest(_) => "InvalidRequestException",
+            CancelAuditTaskError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelAuditTaskError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CancelCertificateTransfer
 #[derive(Debug, PartialEq)]
 pub enum CancelCertificateTransferError {
@@ -10518,6 +10641,21 @@ impl fmt::Display for CancelCertificateTransferError {
     }
 }
 impl Error for CancelCertificateTransferError {}
+impl ServiceErrorCode for CancelCertificateTransferError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelCertificateTransferError::InternalFailure(_) => "InternalFailureException",
+            CancelCertificateTransferError::InvalidRequest(_) => "InvalidRequestException",
+            CancelCertificateTransferError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelCertificateTransferError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CancelCertificateTransferError::Throttling(_) => "ThrottlingException",
+            CancelCertificateTransferError::TransferAlreadyCompleted(_) => {
+                "TransferAlreadyCompletedException"
+            }
+            CancelCertificateTransferError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CancelDetectMitigationActionsTask
 #[derive(Debug, PartialEq)]
 pub enum CancelDetectMitigationActionsTaskError {
@@ -10582,6 +10720,20 @@ impl fmt::Display for CancelDetectMitigationActionsTaskError {
     }
 }
 impl Error for CancelDetectMitigationActionsTaskError {}
+impl ServiceErrorCode for CancelDetectMitigationActionsTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelDetectMitigationActionsTaskError::InternalFailure(_) => {
+                "InternalFailureException"
+            }
+            CancelDetectMitigationActionsTaskError::InvalidRequest(_) => "InvalidRequestException",
+            CancelDetectMitigationActionsTaskError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            CancelDetectMitigationActionsTaskError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CancelJob
 #[derive(Debug, PartialEq)]
 pub enum CancelJobError {
@@ -10630,6 +10782,16 @@ impl fmt::Display for CancelJobError {
     }
 }
 impl Error for CancelJobError {}
+impl ServiceErrorCode for CancelJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelJobError::InvalidRequest(_) => "InvalidRequestException",
+            CancelJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CancelJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CancelJobExecution
 #[derive(Debug, PartialEq)]
 pub enum CancelJobExecutionError {
@@ -10694,6 +10856,18 @@ impl fmt::Display for CancelJobExecutionError {
     }
 }
 impl Error for CancelJobExecutionError {}
+impl ServiceErrorCode for CancelJobExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelJobExecutionError::InvalidRequest(_) => "InvalidRequestException",
+            CancelJobExecutionError::InvalidStateTransition(_) => "InvalidStateTransitionException",
+            CancelJobExecutionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelJobExecutionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CancelJobExecutionError::Throttling(_) => "ThrottlingException",
+            CancelJobExecutionError::VersionConflict(_) => "VersionConflictException",
+        }
+    }
+}
 /// Errors returned by ClearDefaultAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum ClearDefaultAuthorizerError {
@@ -10762,6 +10936,18 @@ impl fmt::Display for ClearDefaultAuthorizerError {
     }
 }
 impl Error for ClearDefaultAuthorizerError {}
+impl ServiceErrorCode for ClearDefaultAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ClearDefaultAuthorizerError::InternalFailure(_) => "InternalFailureException",
+            ClearDefaultAuthorizerError::InvalidRequest(_) => "InvalidRequestException",
+            ClearDefaultAuthorizerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ClearDefaultAuthorizerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ClearDefaultAuthorizerError::Throttling(_) => "ThrottlingException",
+            ClearDefaultAuthorizerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ConfirmTopicRuleDestination
 #[derive(Debug, PartialEq)]
 pub enum ConfirmTopicRuleDestinationError {
@@ -10832,6 +11018,21 @@ impl fmt::Display for ConfirmTopicRuleDestinationError {
     }
 }
 impl Error for ConfirmTopicRuleDestinationError {}
+impl ServiceErrorCode for ConfirmTopicRuleDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ConfirmTopicRuleDestinationError::ConflictingResourceUpdate(_) => {
+                "ConflictingResourceUpdateException"
+            }
+            ConfirmTopicRuleDestinationError::Internal(_) => "InternalException",
+            ConfirmTopicRuleDestinationError::InvalidRequest(_) => "InvalidRequestException",
+            ConfirmTopicRuleDestinationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            ConfirmTopicRuleDestinationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateAuditSuppression
 #[derive(Debug, PartialEq)]
 pub enum CreateAuditSuppressionError {
@@ -10894,6 +11095,19 @@ impl fmt::Display for CreateAuditSuppressionError {
     }
 }
 impl Error for CreateAuditSuppressionError {}
+impl ServiceErrorCode for CreateAuditSuppressionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAuditSuppressionError::InternalFailure(_) => "InternalFailureException",
+            CreateAuditSuppressionError::InvalidRequest(_) => "InvalidRequestException",
+            CreateAuditSuppressionError::LimitExceeded(_) => "LimitExceededException",
+            CreateAuditSuppressionError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateAuditSuppressionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum CreateAuthorizerError {
@@ -10962,6 +11176,19 @@ impl fmt::Display for CreateAuthorizerError {
     }
 }
 impl Error for CreateAuthorizerError {}
+impl ServiceErrorCode for CreateAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAuthorizerError::InternalFailure(_) => "InternalFailureException",
+            CreateAuthorizerError::InvalidRequest(_) => "InvalidRequestException",
+            CreateAuthorizerError::LimitExceeded(_) => "LimitExceededException",
+            CreateAuthorizerError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateAuthorizerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateAuthorizerError::Throttling(_) => "ThrottlingException",
+            CreateAuthorizerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateBillingGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateBillingGroupError {
@@ -11012,6 +11239,16 @@ impl fmt::Display for CreateBillingGroupError {
     }
 }
 impl Error for CreateBillingGroupError {}
+impl ServiceErrorCode for CreateBillingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBillingGroupError::InternalFailure(_) => "InternalFailureException",
+            CreateBillingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            CreateBillingGroupError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateBillingGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateCertificateFromCsr
 #[derive(Debug, PartialEq)]
 pub enum CreateCertificateFromCsrError {
@@ -11074,6 +11311,17 @@ impl fmt::Display for CreateCertificateFromCsrError {
     }
 }
 impl Error for CreateCertificateFromCsrError {}
+impl ServiceErrorCode for CreateCertificateFromCsrError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCertificateFromCsrError::InternalFailure(_) => "InternalFailureException",
+            CreateCertificateFromCsrError::InvalidRequest(_) => "InvalidRequestException",
+            CreateCertificateFromCsrError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateCertificateFromCsrError::Throttling(_) => "ThrottlingException",
+            CreateCertificateFromCsrError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateCustomMetric
 #[derive(Debug, PartialEq)]
 pub enum CreateCustomMetricError {
@@ -11130,6 +11378,17 @@ impl fmt::Display for CreateCustomMetricError {
     }
 }
 impl Error for CreateCustomMetricError {}
+impl ServiceErrorCode for CreateCustomMetricError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCustomMetricError::InternalFailure(_) => "InternalFailureException",
+            CreateCustomMetricError::InvalidRequest(_) => "InvalidRequestException",
+            CreateCustomMetricError::LimitExceeded(_) => "LimitExceededException",
+            CreateCustomMetricError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateCustomMetricError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateDimension
 #[derive(Debug, PartialEq)]
 pub enum CreateDimensionError {
@@ -11186,6 +11445,17 @@ impl fmt::Display for CreateDimensionError {
     }
 }
 impl Error for CreateDimensionError {}
+impl ServiceErrorCode for CreateDimensionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDimensionError::InternalFailure(_) => "InternalFailureException",
+            CreateDimensionError::InvalidRequest(_) => "InvalidRequestException",
+            CreateDimensionError::LimitExceeded(_) => "LimitExceededException",
+            CreateDimensionError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateDimensionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateDomainConfiguration
 #[derive(Debug, PartialEq)]
 pub enum CreateDomainConfigurationError {
@@ -11278,6 +11548,24 @@ impl fmt::Display for CreateDomainConfigurationError {
     }
 }
 impl Error for CreateDomainConfigurationError {}
+impl ServiceErrorCode for CreateDomainConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDomainConfigurationError::CertificateValidation(_) => {
+                "CertificateValidationException"
+            }
+            CreateDomainConfigurationError::InternalFailure(_) => "InternalFailureException",
+            CreateDomainConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            CreateDomainConfigurationError::LimitExceeded(_) => "LimitExceededException",
+            CreateDomainConfigurationError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateDomainConfigurationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateDomainConfigurationError::Throttling(_) => "ThrottlingException",
+            CreateDomainConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateDynamicThingGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateDynamicThingGroupError {
@@ -11358,6 +11646,21 @@ impl fmt::Display for CreateDynamicThingGroupError {
     }
 }
 impl Error for CreateDynamicThingGroupError {}
+impl ServiceErrorCode for CreateDynamicThingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDynamicThingGroupError::InternalFailure(_) => "InternalFailureException",
+            CreateDynamicThingGroupError::InvalidQuery(_) => "InvalidQueryException",
+            CreateDynamicThingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            CreateDynamicThingGroupError::LimitExceeded(_) => "LimitExceededException",
+            CreateDynamicThingGroupError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateDynamicThingGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateDynamicThingGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateJob
 #[derive(Debug, PartialEq)]
 pub enum CreateJobError {
@@ -11418,6 +11721,18 @@ impl fmt::Display for CreateJobError {
     }
 }
 impl Error for CreateJobError {}
+impl ServiceErrorCode for CreateJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateJobError::InvalidRequest(_) => "InvalidRequestException",
+            CreateJobError::LimitExceeded(_) => "LimitExceededException",
+            CreateJobError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateKeysAndCertificate
 #[derive(Debug, PartialEq)]
 pub enum CreateKeysAndCertificateError {
@@ -11480,6 +11795,17 @@ impl fmt::Display for CreateKeysAndCertificateError {
     }
 }
 impl Error for CreateKeysAndCertificateError {}
+impl ServiceErrorCode for CreateKeysAndCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateKeysAndCertificateError::InternalFailure(_) => "InternalFailureException",
+            CreateKeysAndCertificateError::InvalidRequest(_) => "InvalidRequestException",
+            CreateKeysAndCertificateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateKeysAndCertificateError::Throttling(_) => "ThrottlingException",
+            CreateKeysAndCertificateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateMitigationAction
 #[derive(Debug, PartialEq)]
 pub enum CreateMitigationActionError {
@@ -11542,6 +11868,19 @@ impl fmt::Display for CreateMitigationActionError {
     }
 }
 impl Error for CreateMitigationActionError {}
+impl ServiceErrorCode for CreateMitigationActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMitigationActionError::InternalFailure(_) => "InternalFailureException",
+            CreateMitigationActionError::InvalidRequest(_) => "InvalidRequestException",
+            CreateMitigationActionError::LimitExceeded(_) => "LimitExceededException",
+            CreateMitigationActionError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateMitigationActionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateOTAUpdate
 #[derive(Debug, PartialEq)]
 pub enum CreateOTAUpdateError {
@@ -11616,6 +11955,20 @@ impl fmt::Display for CreateOTAUpdateError {
     }
 }
 impl Error for CreateOTAUpdateError {}
+impl ServiceErrorCode for CreateOTAUpdateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateOTAUpdateError::InternalFailure(_) => "InternalFailureException",
+            CreateOTAUpdateError::InvalidRequest(_) => "InvalidRequestException",
+            CreateOTAUpdateError::LimitExceeded(_) => "LimitExceededException",
+            CreateOTAUpdateError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateOTAUpdateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateOTAUpdateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateOTAUpdateError::Throttling(_) => "ThrottlingException",
+            CreateOTAUpdateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreatePolicy
 #[derive(Debug, PartialEq)]
 pub enum CreatePolicyError {
@@ -11682,6 +12035,19 @@ impl fmt::Display for CreatePolicyError {
     }
 }
 impl Error for CreatePolicyError {}
+impl ServiceErrorCode for CreatePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePolicyError::InternalFailure(_) => "InternalFailureException",
+            CreatePolicyError::InvalidRequest(_) => "InvalidRequestException",
+            CreatePolicyError::MalformedPolicy(_) => "MalformedPolicyException",
+            CreatePolicyError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreatePolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreatePolicyError::Throttling(_) => "ThrottlingException",
+            CreatePolicyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreatePolicyVersion
 #[derive(Debug, PartialEq)]
 pub enum CreatePolicyVersionError {
@@ -11760,6 +12126,20 @@ impl fmt::Display for CreatePolicyVersionError {
     }
 }
 impl Error for CreatePolicyVersionError {}
+impl ServiceErrorCode for CreatePolicyVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePolicyVersionError::InternalFailure(_) => "InternalFailureException",
+            CreatePolicyVersionError::InvalidRequest(_) => "InvalidRequestException",
+            CreatePolicyVersionError::MalformedPolicy(_) => "MalformedPolicyException",
+            CreatePolicyVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreatePolicyVersionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreatePolicyVersionError::Throttling(_) => "ThrottlingException",
+            CreatePolicyVersionError::Unauthorized(_) => "UnauthorizedException",
+            CreatePolicyVersionError::VersionsLimitExceeded(_) => "VersionsLimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateProvisioningClaim
 #[derive(Debug, PartialEq)]
 pub enum CreateProvisioningClaimError {
@@ -11830,6 +12210,18 @@ impl fmt::Display for CreateProvisioningClaimError {
     }
 }
 impl Error for CreateProvisioningClaimError {}
+impl ServiceErrorCode for CreateProvisioningClaimError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProvisioningClaimError::InternalFailure(_) => "InternalFailureException",
+            CreateProvisioningClaimError::InvalidRequest(_) => "InvalidRequestException",
+            CreateProvisioningClaimError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateProvisioningClaimError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateProvisioningClaimError::Throttling(_) => "ThrottlingException",
+            CreateProvisioningClaimError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateProvisioningTemplate
 #[derive(Debug, PartialEq)]
 pub enum CreateProvisioningTemplateError {
@@ -11906,6 +12298,20 @@ impl fmt::Display for CreateProvisioningTemplateError {
     }
 }
 impl Error for CreateProvisioningTemplateError {}
+impl ServiceErrorCode for CreateProvisioningTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProvisioningTemplateError::InternalFailure(_) => "InternalFailureException",
+            CreateProvisioningTemplateError::InvalidRequest(_) => "InvalidRequestException",
+            CreateProvisioningTemplateError::LimitExceeded(_) => "LimitExceededException",
+            CreateProvisioningTemplateError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateProvisioningTemplateError::Throttling(_) => "ThrottlingException",
+            CreateProvisioningTemplateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateProvisioningTemplateVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateProvisioningTemplateVersionError {
@@ -12000,6 +12406,27 @@ impl fmt::Display for CreateProvisioningTemplateVersionError {
     }
 }
 impl Error for CreateProvisioningTemplateVersionError {}
+impl ServiceErrorCode for CreateProvisioningTemplateVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProvisioningTemplateVersionError::ConflictingResourceUpdate(_) => {
+                "ConflictingResourceUpdateException"
+            }
+            CreateProvisioningTemplateVersionError::InternalFailure(_) => {
+                "InternalFailureException"
+            }
+            CreateProvisioningTemplateVersionError::InvalidRequest(_) => "InvalidRequestException",
+            CreateProvisioningTemplateVersionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            CreateProvisioningTemplateVersionError::Throttling(_) => "ThrottlingException",
+            CreateProvisioningTemplateVersionError::Unauthorized(_) => "UnauthorizedException",
+            CreateProvisioningTemplateVersionError::VersionsLimitExceeded(_) => {
+                "VersionsLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateRoleAlias
 #[derive(Debug, PartialEq)]
 pub enum CreateRoleAliasError {
@@ -12068,6 +12495,19 @@ impl fmt::Display for CreateRoleAliasError {
     }
 }
 impl Error for CreateRoleAliasError {}
+impl ServiceErrorCode for CreateRoleAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRoleAliasError::InternalFailure(_) => "InternalFailureException",
+            CreateRoleAliasError::InvalidRequest(_) => "InvalidRequestException",
+            CreateRoleAliasError::LimitExceeded(_) => "LimitExceededException",
+            CreateRoleAliasError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateRoleAliasError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateRoleAliasError::Throttling(_) => "ThrottlingException",
+            CreateRoleAliasError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateScheduledAudit
 #[derive(Debug, PartialEq)]
 pub enum CreateScheduledAuditError {
@@ -12126,6 +12566,17 @@ impl fmt::Display for CreateScheduledAuditError {
     }
 }
 impl Error for CreateScheduledAuditError {}
+impl ServiceErrorCode for CreateScheduledAuditError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateScheduledAuditError::InternalFailure(_) => "InternalFailureException",
+            CreateScheduledAuditError::InvalidRequest(_) => "InvalidRequestException",
+            CreateScheduledAuditError::LimitExceeded(_) => "LimitExceededException",
+            CreateScheduledAuditError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateScheduledAuditError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateSecurityProfile
 #[derive(Debug, PartialEq)]
 pub enum CreateSecurityProfileError {
@@ -12180,6 +12631,18 @@ impl fmt::Display for CreateSecurityProfileError {
     }
 }
 impl Error for CreateSecurityProfileError {}
+impl ServiceErrorCode for CreateSecurityProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSecurityProfileError::InternalFailure(_) => "InternalFailureException",
+            CreateSecurityProfileError::InvalidRequest(_) => "InvalidRequestException",
+            CreateSecurityProfileError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateSecurityProfileError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateStream
 #[derive(Debug, PartialEq)]
 pub enum CreateStreamError {
@@ -12252,6 +12715,20 @@ impl fmt::Display for CreateStreamError {
     }
 }
 impl Error for CreateStreamError {}
+impl ServiceErrorCode for CreateStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStreamError::InternalFailure(_) => "InternalFailureException",
+            CreateStreamError::InvalidRequest(_) => "InvalidRequestException",
+            CreateStreamError::LimitExceeded(_) => "LimitExceededException",
+            CreateStreamError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateStreamError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateStreamError::Throttling(_) => "ThrottlingException",
+            CreateStreamError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateThing
 #[derive(Debug, PartialEq)]
 pub enum CreateThingError {
@@ -12318,6 +12795,19 @@ impl fmt::Display for CreateThingError {
     }
 }
 impl Error for CreateThingError {}
+impl ServiceErrorCode for CreateThingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateThingError::InternalFailure(_) => "InternalFailureException",
+            CreateThingError::InvalidRequest(_) => "InvalidRequestException",
+            CreateThingError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateThingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateThingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateThingError::Throttling(_) => "ThrottlingException",
+            CreateThingError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateThingGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateThingGroupError {
@@ -12368,6 +12858,16 @@ impl fmt::Display for CreateThingGroupError {
     }
 }
 impl Error for CreateThingGroupError {}
+impl ServiceErrorCode for CreateThingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateThingGroupError::InternalFailure(_) => "InternalFailureException",
+            CreateThingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            CreateThingGroupError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateThingGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateThingType
 #[derive(Debug, PartialEq)]
 pub enum CreateThingTypeError {
@@ -12430,6 +12930,18 @@ impl fmt::Display for CreateThingTypeError {
     }
 }
 impl Error for CreateThingTypeError {}
+impl ServiceErrorCode for CreateThingTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateThingTypeError::InternalFailure(_) => "InternalFailureException",
+            CreateThingTypeError::InvalidRequest(_) => "InvalidRequestException",
+            CreateThingTypeError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateThingTypeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateThingTypeError::Throttling(_) => "ThrottlingException",
+            CreateThingTypeError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateTopicRule
 #[derive(Debug, PartialEq)]
 pub enum CreateTopicRuleError {
@@ -12494,6 +13006,20 @@ impl fmt::Display for CreateTopicRuleError {
     }
 }
 impl Error for CreateTopicRuleError {}
+impl ServiceErrorCode for CreateTopicRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTopicRuleError::ConflictingResourceUpdate(_) => {
+                "ConflictingResourceUpdateException"
+            }
+            CreateTopicRuleError::Internal(_) => "InternalException",
+            CreateTopicRuleError::InvalidRequest(_) => "InvalidRequestException",
+            CreateTopicRuleError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateTopicRuleError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateTopicRuleError::SqlParse(_) => "SqlParseException",
+        }
+    }
+}
 /// Errors returned by CreateTopicRuleDestination
 #[derive(Debug, PartialEq)]
 pub enum CreateTopicRuleDestinationError {
@@ -12564,6 +13090,21 @@ impl fmt::Display for CreateTopicRuleDestinationError {
     }
 }
 impl Error for CreateTopicRuleDestinationError {}
+impl ServiceErrorCode for CreateTopicRuleDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTopicRuleDestinationError::ConflictingResourceUpdate(_) => {
+                "ConflictingResourceUpdateException"
+            }
+            CreateTopicRuleDestinationError::Internal(_) => "InternalException",
+            CreateTopicRuleDestinationError::InvalidRequest(_) => "InvalidRequestException",
+            CreateTopicRuleDestinationError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateTopicRuleDestinationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteAccountAuditConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteAccountAuditConfigurationError {
@@ -12628,6 +13169,18 @@ impl fmt::Display for DeleteAccountAuditConfigurationError {
     }
 }
 impl Error for DeleteAccountAuditConfigurationError {}
+impl ServiceErrorCode for DeleteAccountAuditConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAccountAuditConfigurationError::InternalFailure(_) => "InternalFailureException",
+            DeleteAccountAuditConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteAccountAuditConfigurationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DeleteAccountAuditConfigurationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteAuditSuppression
 #[derive(Debug, PartialEq)]
 pub enum DeleteAuditSuppressionError {
@@ -12674,6 +13227,15 @@ impl fmt::Display for DeleteAuditSuppressionError {
     }
 }
 impl Error for DeleteAuditSuppressionError {}
+impl ServiceErrorCode for DeleteAuditSuppressionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAuditSuppressionError::InternalFailure(_) => "InternalFailureException",
+            DeleteAuditSuppressionError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteAuditSuppressionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum DeleteAuthorizerError {
@@ -12740,6 +13302,19 @@ impl fmt::Display for DeleteAuthorizerError {
     }
 }
 impl Error for DeleteAuthorizerError {}
+impl ServiceErrorCode for DeleteAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAuthorizerError::DeleteConflict(_) => "DeleteConflictException",
+            DeleteAuthorizerError::InternalFailure(_) => "InternalFailureException",
+            DeleteAuthorizerError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteAuthorizerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteAuthorizerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteAuthorizerError::Throttling(_) => "ThrottlingException",
+            DeleteAuthorizerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteBillingGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteBillingGroupError {
@@ -12788,6 +13363,16 @@ impl fmt::Display for DeleteBillingGroupError {
     }
 }
 impl Error for DeleteBillingGroupError {}
+impl ServiceErrorCode for DeleteBillingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBillingGroupError::InternalFailure(_) => "InternalFailureException",
+            DeleteBillingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteBillingGroupError::Throttling(_) => "ThrottlingException",
+            DeleteBillingGroupError::VersionConflict(_) => "VersionConflictException",
+        }
+    }
+}
 /// Errors returned by DeleteCACertificate
 #[derive(Debug, PartialEq)]
 pub enum DeleteCACertificateError {
@@ -12860,6 +13445,19 @@ impl fmt::Display for DeleteCACertificateError {
     }
 }
 impl Error for DeleteCACertificateError {}
+impl ServiceErrorCode for DeleteCACertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCACertificateError::CertificateState(_) => "CertificateStateException",
+            DeleteCACertificateError::InternalFailure(_) => "InternalFailureException",
+            DeleteCACertificateError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteCACertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteCACertificateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteCACertificateError::Throttling(_) => "ThrottlingException",
+            DeleteCACertificateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteCertificate
 #[derive(Debug, PartialEq)]
 pub enum DeleteCertificateError {
@@ -12934,6 +13532,20 @@ impl fmt::Display for DeleteCertificateError {
     }
 }
 impl Error for DeleteCertificateError {}
+impl ServiceErrorCode for DeleteCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCertificateError::CertificateState(_) => "CertificateStateException",
+            DeleteCertificateError::DeleteConflict(_) => "DeleteConflictException",
+            DeleteCertificateError::InternalFailure(_) => "InternalFailureException",
+            DeleteCertificateError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteCertificateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteCertificateError::Throttling(_) => "ThrottlingException",
+            DeleteCertificateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteCustomMetric
 #[derive(Debug, PartialEq)]
 pub enum DeleteCustomMetricError {
@@ -12976,6 +13588,15 @@ impl fmt::Display for DeleteCustomMetricError {
     }
 }
 impl Error for DeleteCustomMetricError {}
+impl ServiceErrorCode for DeleteCustomMetricError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCustomMetricError::InternalFailure(_) => "InternalFailureException",
+            DeleteCustomMetricError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteCustomMetricError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteDimension
 #[derive(Debug, PartialEq)]
 pub enum DeleteDimensionError {
@@ -13018,6 +13639,15 @@ impl fmt::Display for DeleteDimensionError {
     }
 }
 impl Error for DeleteDimensionError {}
+impl ServiceErrorCode for DeleteDimensionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDimensionError::InternalFailure(_) => "InternalFailureException",
+            DeleteDimensionError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteDimensionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteDomainConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteDomainConfigurationError {
@@ -13090,6 +13720,18 @@ impl fmt::Display for DeleteDomainConfigurationError {
     }
 }
 impl Error for DeleteDomainConfigurationError {}
+impl ServiceErrorCode for DeleteDomainConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDomainConfigurationError::InternalFailure(_) => "InternalFailureException",
+            DeleteDomainConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteDomainConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDomainConfigurationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteDomainConfigurationError::Throttling(_) => "ThrottlingException",
+            DeleteDomainConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteDynamicThingGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteDynamicThingGroupError {
@@ -13144,6 +13786,16 @@ impl fmt::Display for DeleteDynamicThingGroupError {
     }
 }
 impl Error for DeleteDynamicThingGroupError {}
+impl ServiceErrorCode for DeleteDynamicThingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDynamicThingGroupError::InternalFailure(_) => "InternalFailureException",
+            DeleteDynamicThingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteDynamicThingGroupError::Throttling(_) => "ThrottlingException",
+            DeleteDynamicThingGroupError::VersionConflict(_) => "VersionConflictException",
+        }
+    }
+}
 /// Errors returned by DeleteJob
 #[derive(Debug, PartialEq)]
 pub enum DeleteJobError {
@@ -13204,6 +13856,18 @@ impl fmt::Display for DeleteJobError {
     }
 }
 impl Error for DeleteJobError {}
+impl ServiceErrorCode for DeleteJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteJobError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteJobError::InvalidStateTransition(_) => "InvalidStateTransitionException",
+            DeleteJobError::LimitExceeded(_) => "LimitExceededException",
+            DeleteJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteJobExecution
 #[derive(Debug, PartialEq)]
 pub enum DeleteJobExecutionError {
@@ -13262,6 +13926,17 @@ impl fmt::Display for DeleteJobExecutionError {
     }
 }
 impl Error for DeleteJobExecutionError {}
+impl ServiceErrorCode for DeleteJobExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteJobExecutionError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteJobExecutionError::InvalidStateTransition(_) => "InvalidStateTransitionException",
+            DeleteJobExecutionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteJobExecutionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteJobExecutionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteMitigationAction
 #[derive(Debug, PartialEq)]
 pub enum DeleteMitigationActionError {
@@ -13308,6 +13983,15 @@ impl fmt::Display for DeleteMitigationActionError {
     }
 }
 impl Error for DeleteMitigationActionError {}
+impl ServiceErrorCode for DeleteMitigationActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMitigationActionError::InternalFailure(_) => "InternalFailureException",
+            DeleteMitigationActionError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteMitigationActionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteOTAUpdate
 #[derive(Debug, PartialEq)]
 pub enum DeleteOTAUpdateError {
@@ -13374,6 +14058,19 @@ impl fmt::Display for DeleteOTAUpdateError {
     }
 }
 impl Error for DeleteOTAUpdateError {}
+impl ServiceErrorCode for DeleteOTAUpdateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteOTAUpdateError::InternalFailure(_) => "InternalFailureException",
+            DeleteOTAUpdateError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteOTAUpdateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteOTAUpdateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteOTAUpdateError::Throttling(_) => "ThrottlingException",
+            DeleteOTAUpdateError::Unauthorized(_) => "UnauthorizedException",
+            DeleteOTAUpdateError::VersionConflict(_) => "VersionConflictException",
+        }
+    }
+}
 /// Errors returned by DeletePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeletePolicyError {
@@ -13440,6 +14137,19 @@ impl fmt::Display for DeletePolicyError {
     }
 }
 impl Error for DeletePolicyError {}
+impl ServiceErrorCode for DeletePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePolicyError::DeleteConflict(_) => "DeleteConflictException",
+            DeletePolicyError::InternalFailure(_) => "InternalFailureException",
+            DeletePolicyError::InvalidRequest(_) => "InvalidRequestException",
+            DeletePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeletePolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeletePolicyError::Throttling(_) => "ThrottlingException",
+            DeletePolicyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeletePolicyVersion
 #[derive(Debug, PartialEq)]
 pub enum DeletePolicyVersionError {
@@ -13510,6 +14220,19 @@ impl fmt::Display for DeletePolicyVersionError {
     }
 }
 impl Error for DeletePolicyVersionError {}
+impl ServiceErrorCode for DeletePolicyVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePolicyVersionError::DeleteConflict(_) => "DeleteConflictException",
+            DeletePolicyVersionError::InternalFailure(_) => "InternalFailureException",
+            DeletePolicyVersionError::InvalidRequest(_) => "InvalidRequestException",
+            DeletePolicyVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeletePolicyVersionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeletePolicyVersionError::Throttling(_) => "ThrottlingException",
+            DeletePolicyVersionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteProvisioningTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeleteProvisioningTemplateError {
@@ -13594,8 +14317,23 @@ impl fmt::Display for DeleteProvisioningTemplateError {
     }
 }
 impl Error for DeleteProvisioningTemplateError {}
-/// Errors returned by DeleteProvisioningTemplateVersion
-#[derive(Debug, PartialEq)]
+impl ServiceErrorCode for DeleteProvisioningTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProvisioningTemplateError::ConflictingResourceUpdate(_) => {
+                "ConflictingResourceUpdateException"
+            }
+            DeleteProvisioningTemplateError::DeleteConflict(_) => "DeleteConflictException",
+            DeleteProvisioningTemplateError::InternalFailure(_) => "InternalFailureException",
+            DeleteProvisioningTemplateError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteProvisioningTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteProvisioningTemplateError::Throttling(_) => "ThrottlingException",
+            DeleteProvisioningTemplateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
+/// Errors returned by DeleteProvisioningTemplateVersion
+#[derive(Debug, PartialEq)]
 pub enum DeleteProvisioningTemplateVersionError {
     /// <p>A conflicting resource update exception. This exception is thrown when two pending updates cause a conflict.</p>
     ConflictingResourceUpdate(String),
@@ -13688,6 +14426,25 @@ impl fmt::Display for DeleteProvisioningTemplateVersionError {
     }
 }
 impl Error for DeleteProvisioningTemplateVersionError {}
+impl ServiceErrorCode for DeleteProvisioningTemplateVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProvisioningTemplateVersionError::ConflictingResourceUpdate(_) => {
+                "ConflictingResourceUpdateException"
+            }
+            DeleteProvisioningTemplateVersionError::DeleteConflict(_) => "DeleteConflictException",
+            DeleteProvisioningTemplateVersionError::InternalFailure(_) => {
+                "InternalFailureException"
+            }
+            DeleteProvisioningTemplateVersionError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteProvisioningTemplateVersionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DeleteProvisioningTemplateVersionError::Throttling(_) => "ThrottlingException",
+            DeleteProvisioningTemplateVersionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteRegistrationCode
 #[derive(Debug, PartialEq)]
 pub enum DeleteRegistrationCodeError {
@@ -13748,6 +14505,17 @@ impl fmt::Display for DeleteRegistrationCodeError {
     }
 }
 impl Error for DeleteRegistrationCodeError {}
+impl ServiceErrorCode for DeleteRegistrationCodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRegistrationCodeError::InternalFailure(_) => "InternalFailureException",
+            DeleteRegistrationCodeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteRegistrationCodeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteRegistrationCodeError::Throttling(_) => "ThrottlingException",
+            DeleteRegistrationCodeError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteRoleAlias
 #[derive(Debug, PartialEq)]
 pub enum DeleteRoleAliasError {
@@ -13814,6 +14582,19 @@ impl fmt::Display for DeleteRoleAliasError {
     }
 }
 impl Error for DeleteRoleAliasError {}
+impl ServiceErrorCode for DeleteRoleAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRoleAliasError::DeleteConflict(_) => "DeleteConflictException",
+            DeleteRoleAliasError::InternalFailure(_) => "InternalFailureException",
+            DeleteRoleAliasError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteRoleAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteRoleAliasError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteRoleAliasError::Throttling(_) => "ThrottlingException",
+            DeleteRoleAliasError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteScheduledAudit
 #[derive(Debug, PartialEq)]
 pub enum DeleteScheduledAuditError {
@@ -13866,6 +14647,16 @@ impl fmt::Display for DeleteScheduledAuditError {
     }
 }
 impl Error for DeleteScheduledAuditError {}
+impl ServiceErrorCode for DeleteScheduledAuditError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteScheduledAuditError::InternalFailure(_) => "InternalFailureException",
+            DeleteScheduledAuditError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteScheduledAuditError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteScheduledAuditError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteSecurityProfile
 #[derive(Debug, PartialEq)]
 pub enum DeleteSecurityProfileError {
@@ -13920,6 +14711,16 @@ impl fmt::Display for DeleteSecurityProfileError {
     }
 }
 impl Error for DeleteSecurityProfileError {}
+impl ServiceErrorCode for DeleteSecurityProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSecurityProfileError::InternalFailure(_) => "InternalFailureException",
+            DeleteSecurityProfileError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteSecurityProfileError::Throttling(_) => "ThrottlingException",
+            DeleteSecurityProfileError::VersionConflict(_) => "VersionConflictException",
+        }
+    }
+}
 /// Errors returned by DeleteStream
 #[derive(Debug, PartialEq)]
 pub enum DeleteStreamError {
@@ -13986,6 +14787,19 @@ impl fmt::Display for DeleteStreamError {
     }
 }
 impl Error for DeleteStreamError {}
+impl ServiceErrorCode for DeleteStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStreamError::DeleteConflict(_) => "DeleteConflictException",
+            DeleteStreamError::InternalFailure(_) => "InternalFailureException",
+            DeleteStreamError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteStreamError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteStreamError::Throttling(_) => "ThrottlingException",
+            DeleteStreamError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteThing
 #[derive(Debug, PartialEq)]
 pub enum DeleteThingError {
@@ -14052,6 +14866,19 @@ impl fmt::Display for DeleteThingError {
     }
 }
 impl Error for DeleteThingError {}
+impl ServiceErrorCode for DeleteThingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteThingError::InternalFailure(_) => "InternalFailureException",
+            DeleteThingError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteThingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteThingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteThingError::Throttling(_) => "ThrottlingException",
+            DeleteThingError::Unauthorized(_) => "UnauthorizedException",
+            DeleteThingError::VersionConflict(_) => "VersionConflictException",
+        }
+    }
+}
 /// Errors returned by DeleteThingGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteThingGroupError {
@@ -14100,6 +14927,16 @@ impl fmt::Display for DeleteThingGroupError {
     }
 }
 impl Error for DeleteThingGroupError {}
+impl ServiceErrorCode for DeleteThingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteThingGroupError::InternalFailure(_) => "InternalFailureException",
+            DeleteThingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteThingGroupError::Throttling(_) => "ThrottlingException",
+            DeleteThingGroupError::VersionConflict(_) => "VersionConflictException",
+        }
+    }
+}
 /// Errors returned by DeleteThingType
 #[derive(Debug, PartialEq)]
 pub enum DeleteThingTypeError {
@@ -14160,6 +14997,18 @@ impl fmt::Display for DeleteThingTypeError {
     }
 }
 impl Error for DeleteThingTypeError {}
+impl ServiceErrorCode for DeleteThingTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteThingTypeError::InternalFailure(_) => "InternalFailureException",
+            DeleteThingTypeError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteThingTypeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteThingTypeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteThingTypeError::Throttling(_) => "ThrottlingException",
+            DeleteThingTypeError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteTopicRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteTopicRuleError {
@@ -14216,6 +15065,19 @@ impl fmt::Display for DeleteTopicRuleError {
     }
 }
 impl Error for DeleteTopicRuleError {}
+impl ServiceErrorCode for DeleteTopicRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTopicRuleError::ConflictingResourceUpdate(_) => {
+                "ConflictingResourceUpdateException"
+            }
+            DeleteTopicRuleError::Internal(_) => "InternalException",
+            DeleteTopicRuleError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteTopicRuleError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteTopicRuleError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteTopicRuleDestination
 #[derive(Debug, PartialEq)]
 pub enum DeleteTopicRuleDestinationError {
@@ -14284,6 +15146,19 @@ impl fmt::Display for DeleteTopicRuleDestinationError {
     }
 }
 impl Error for DeleteTopicRuleDestinationError {}
+impl ServiceErrorCode for DeleteTopicRuleDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTopicRuleDestinationError::ConflictingResourceUpdate(_) => {
+                "ConflictingResourceUpdateException"
+            }
+            DeleteTopicRuleDestinationError::Internal(_) => "InternalException",
+            DeleteTopicRuleDestinationError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteTopicRuleDestinationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteTopicRuleDestinationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteV2LoggingLevel
 #[derive(Debug, PartialEq)]
 pub enum DeleteV2LoggingLevelError {
@@ -14328,6 +15203,15 @@ impl fmt::Display for DeleteV2LoggingLevelError {
     }
 }
 impl Error for DeleteV2LoggingLevelError {}
+impl ServiceErrorCode for DeleteV2LoggingLevelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteV2LoggingLevelError::Internal(_) => "InternalException",
+            DeleteV2LoggingLevelError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteV2LoggingLevelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeprecateThingType
 #[derive(Debug, PartialEq)]
 pub enum DeprecateThingTypeError {
@@ -14390,6 +15274,18 @@ impl fmt::Display for DeprecateThingTypeError {
     }
 }
 impl Error for DeprecateThingTypeError {}
+impl ServiceErrorCode for DeprecateThingTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeprecateThingTypeError::InternalFailure(_) => "InternalFailureException",
+            DeprecateThingTypeError::InvalidRequest(_) => "InvalidRequestException",
+            DeprecateThingTypeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeprecateThingTypeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeprecateThingTypeError::Throttling(_) => "ThrottlingException",
+            DeprecateThingTypeError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeAccountAuditConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountAuditConfigurationError {
@@ -14434,6 +15330,16 @@ impl fmt::Display for DescribeAccountAuditConfigurationError {
     }
 }
 impl Error for DescribeAccountAuditConfigurationError {}
+impl ServiceErrorCode for DescribeAccountAuditConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAccountAuditConfigurationError::InternalFailure(_) => {
+                "InternalFailureException"
+            }
+            DescribeAccountAuditConfigurationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeAuditFinding
 #[derive(Debug, PartialEq)]
 pub enum DescribeAuditFindingError {
@@ -14486,6 +15392,16 @@ impl fmt::Display for DescribeAuditFindingError {
     }
 }
 impl Error for DescribeAuditFindingError {}
+impl ServiceErrorCode for DescribeAuditFindingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAuditFindingError::InternalFailure(_) => "InternalFailureException",
+            DescribeAuditFindingError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeAuditFindingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeAuditFindingError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeAuditMitigationActionsTask
 #[derive(Debug, PartialEq)]
 pub enum DescribeAuditMitigationActionsTaskError {
@@ -14552,6 +15468,20 @@ impl fmt::Display for DescribeAuditMitigationActionsTaskError {
     }
 }
 impl Error for DescribeAuditMitigationActionsTaskError {}
+impl ServiceErrorCode for DescribeAuditMitigationActionsTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAuditMitigationActionsTaskError::InternalFailure(_) => {
+                "InternalFailureException"
+            }
+            DescribeAuditMitigationActionsTaskError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeAuditMitigationActionsTaskError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DescribeAuditMitigationActionsTaskError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeAuditSuppression
 #[derive(Debug, PartialEq)]
 pub enum DescribeAuditSuppressionError {
@@ -14606,6 +15536,16 @@ impl fmt::Display for DescribeAuditSuppressionError {
     }
 }
 impl Error for DescribeAuditSuppressionError {}
+impl ServiceErrorCode for DescribeAuditSuppressionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAuditSuppressionError::InternalFailure(_) => "InternalFailureException",
+            DescribeAuditSuppressionError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeAuditSuppressionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeAuditSuppressionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeAuditTask
 #[derive(Debug, PartialEq)]
 pub enum DescribeAuditTaskError {
@@ -14654,6 +15594,16 @@ impl fmt::Display for DescribeAuditTaskError {
     }
 }
 impl Error for DescribeAuditTaskError {}
+impl ServiceErrorCode for DescribeAuditTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAuditTaskError::InternalFailure(_) => "InternalFailureException",
+            DescribeAuditTaskError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeAuditTaskError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeAuditTaskError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum DescribeAuthorizerError {
@@ -14716,6 +15666,18 @@ impl fmt::Display for DescribeAuthorizerError {
     }
 }
 impl Error for DescribeAuthorizerError {}
+impl ServiceErrorCode for DescribeAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAuthorizerError::InternalFailure(_) => "InternalFailureException",
+            DescribeAuthorizerError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeAuthorizerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeAuthorizerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeAuthorizerError::Throttling(_) => "ThrottlingException",
+            DescribeAuthorizerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeBillingGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeBillingGroupError {
@@ -14768,6 +15730,16 @@ impl fmt::Display for DescribeBillingGroupError {
     }
 }
 impl Error for DescribeBillingGroupError {}
+impl ServiceErrorCode for DescribeBillingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBillingGroupError::InternalFailure(_) => "InternalFailureException",
+            DescribeBillingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeBillingGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeBillingGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeCACertificate
 #[derive(Debug, PartialEq)]
 pub enum DescribeCACertificateError {
@@ -14836,6 +15808,18 @@ impl fmt::Display for DescribeCACertificateError {
     }
 }
 impl Error for DescribeCACertificateError {}
+impl ServiceErrorCode for DescribeCACertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCACertificateError::InternalFailure(_) => "InternalFailureException",
+            DescribeCACertificateError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeCACertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeCACertificateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeCACertificateError::Throttling(_) => "ThrottlingException",
+            DescribeCACertificateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeCertificate
 #[derive(Debug, PartialEq)]
 pub enum DescribeCertificateError {
@@ -14900,6 +15884,18 @@ impl fmt::Display for DescribeCertificateError {
     }
 }
 impl Error for DescribeCertificateError {}
+impl ServiceErrorCode for DescribeCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCertificateError::InternalFailure(_) => "InternalFailureException",
+            DescribeCertificateError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeCertificateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeCertificateError::Throttling(_) => "ThrottlingException",
+            DescribeCertificateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeCustomMetric
 #[derive(Debug, PartialEq)]
 pub enum DescribeCustomMetricError {
@@ -14952,6 +15948,16 @@ impl fmt::Display for DescribeCustomMetricError {
     }
 }
 impl Error for DescribeCustomMetricError {}
+impl ServiceErrorCode for DescribeCustomMetricError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCustomMetricError::InternalFailure(_) => "InternalFailureException",
+            DescribeCustomMetricError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeCustomMetricError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeCustomMetricError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeDefaultAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum DescribeDefaultAuthorizerError {
@@ -15024,6 +16030,18 @@ impl fmt::Display for DescribeDefaultAuthorizerError {
     }
 }
 impl Error for DescribeDefaultAuthorizerError {}
+impl ServiceErrorCode for DescribeDefaultAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDefaultAuthorizerError::InternalFailure(_) => "InternalFailureException",
+            DescribeDefaultAuthorizerError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeDefaultAuthorizerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDefaultAuthorizerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeDefaultAuthorizerError::Throttling(_) => "ThrottlingException",
+            DescribeDefaultAuthorizerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeDetectMitigationActionsTask
 #[derive(Debug, PartialEq)]
 pub enum DescribeDetectMitigationActionsTaskError {
@@ -15090,6 +16108,22 @@ impl fmt::Display for DescribeDetectMitigationActionsTaskError {
     }
 }
 impl Error for DescribeDetectMitigationActionsTaskError {}
+impl ServiceErrorCode for DescribeDetectMitigationActionsTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDetectMitigationActionsTaskError::InternalFailure(_) => {
+                "InternalFailureException"
+            }
+            DescribeDetectMitigationActionsTaskError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            DescribeDetectMitigationActionsTaskError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DescribeDetectMitigationActionsTaskError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeDimension
 #[derive(Debug, PartialEq)]
 pub enum DescribeDimensionError {
@@ -15138,6 +16172,16 @@ impl fmt::Display for DescribeDimensionError {
     }
 }
 impl Error for DescribeDimensionError {}
+impl ServiceErrorCode for DescribeDimensionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDimensionError::InternalFailure(_) => "InternalFailureException",
+            DescribeDimensionError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeDimensionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDimensionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeDomainConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribeDomainConfigurationError {
@@ -15214,6 +16258,20 @@ impl fmt::Display for DescribeDomainConfigurationError {
     }
 }
 impl Error for DescribeDomainConfigurationError {}
+impl ServiceErrorCode for DescribeDomainConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDomainConfigurationError::InternalFailure(_) => "InternalFailureException",
+            DescribeDomainConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeDomainConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDomainConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DescribeDomainConfigurationError::Throttling(_) => "ThrottlingException",
+            DescribeDomainConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DescribeEndpointError {
@@ -15262,6 +16320,16 @@ impl fmt::Display for DescribeEndpointError {
     }
 }
 impl Error for DescribeEndpointError {}
+impl ServiceErrorCode for DescribeEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEndpointError::InternalFailure(_) => "InternalFailureException",
+            DescribeEndpointError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeEndpointError::Throttling(_) => "ThrottlingException",
+            DescribeEndpointError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeEventConfigurations
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventConfigurationsError {
@@ -15304,6 +16372,14 @@ impl fmt::Display for DescribeEventConfigurationsError {
     }
 }
 impl Error for DescribeEventConfigurationsError {}
+impl ServiceErrorCode for DescribeEventConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventConfigurationsError::InternalFailure(_) => "InternalFailureException",
+            DescribeEventConfigurationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeIndex
 #[derive(Debug, PartialEq)]
 pub enum DescribeIndexError {
@@ -15364,6 +16440,18 @@ impl fmt::Display for DescribeIndexError {
     }
 }
 impl Error for DescribeIndexError {}
+impl ServiceErrorCode for DescribeIndexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeIndexError::InternalFailure(_) => "InternalFailureException",
+            DescribeIndexError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeIndexError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeIndexError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeIndexError::Throttling(_) => "ThrottlingException",
+            DescribeIndexError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeJobError {
@@ -15412,6 +16500,16 @@ impl fmt::Display for DescribeJobError {
     }
 }
 impl Error for DescribeJobError {}
+impl ServiceErrorCode for DescribeJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeJobError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeJobExecution
 #[derive(Debug, PartialEq)]
 pub enum DescribeJobExecutionError {
@@ -15464,6 +16562,16 @@ impl fmt::Display for DescribeJobExecutionError {
     }
 }
 impl Error for DescribeJobExecutionError {}
+impl ServiceErrorCode for DescribeJobExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeJobExecutionError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeJobExecutionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeJobExecutionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeJobExecutionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeMitigationAction
 #[derive(Debug, PartialEq)]
 pub enum DescribeMitigationActionError {
@@ -15518,6 +16626,16 @@ impl fmt::Display for DescribeMitigationActionError {
     }
 }
 impl Error for DescribeMitigationActionError {}
+impl ServiceErrorCode for DescribeMitigationActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMitigationActionError::InternalFailure(_) => "InternalFailureException",
+            DescribeMitigationActionError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeMitigationActionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeMitigationActionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeProvisioningTemplate
 #[derive(Debug, PartialEq)]
 pub enum DescribeProvisioningTemplateError {
@@ -15586,6 +16704,17 @@ impl fmt::Display for DescribeProvisioningTemplateError {
     }
 }
 impl Error for DescribeProvisioningTemplateError {}
+impl ServiceErrorCode for DescribeProvisioningTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProvisioningTemplateError::InternalFailure(_) => "InternalFailureException",
+            DescribeProvisioningTemplateError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeProvisioningTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeProvisioningTemplateError::Throttling(_) => "ThrottlingException",
+            DescribeProvisioningTemplateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeProvisioningTemplateVersion
 #[derive(Debug, PartialEq)]
 pub enum DescribeProvisioningTemplateVersionError {
@@ -15662,6 +16791,23 @@ impl fmt::Display for DescribeProvisioningTemplateVersionError {
     }
 }
 impl Error for DescribeProvisioningTemplateVersionError {}
+impl ServiceErrorCode for DescribeProvisioningTemplateVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProvisioningTemplateVersionError::InternalFailure(_) => {
+                "InternalFailureException"
+            }
+            DescribeProvisioningTemplateVersionError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            DescribeProvisioningTemplateVersionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DescribeProvisioningTemplateVersionError::Throttling(_) => "ThrottlingException",
+            DescribeProvisioningTemplateVersionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeRoleAlias
 #[derive(Debug, PartialEq)]
 pub enum DescribeRoleAliasError {
@@ -15724,6 +16870,18 @@ impl fmt::Display for DescribeRoleAliasError {
     }
 }
 impl Error for DescribeRoleAliasError {}
+impl ServiceErrorCode for DescribeRoleAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRoleAliasError::InternalFailure(_) => "InternalFailureException",
+            DescribeRoleAliasError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeRoleAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeRoleAliasError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeRoleAliasError::Throttling(_) => "ThrottlingException",
+            DescribeRoleAliasError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeScheduledAudit
 #[derive(Debug, PartialEq)]
 pub enum DescribeScheduledAuditError {
@@ -15778,6 +16936,16 @@ impl fmt::Display for DescribeScheduledAuditError {
     }
 }
 impl Error for DescribeScheduledAuditError {}
+impl ServiceErrorCode for DescribeScheduledAuditError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeScheduledAuditError::InternalFailure(_) => "InternalFailureException",
+            DescribeScheduledAuditError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeScheduledAuditError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeScheduledAuditError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeSecurityProfile
 #[derive(Debug, PartialEq)]
 pub enum DescribeSecurityProfileError {
@@ -15832,6 +17000,16 @@ impl fmt::Display for DescribeSecurityProfileError {
     }
 }
 impl Error for DescribeSecurityProfileError {}
+impl ServiceErrorCode for DescribeSecurityProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSecurityProfileError::InternalFailure(_) => "InternalFailureException",
+            DescribeSecurityProfileError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeSecurityProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeSecurityProfileError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeStream
 #[derive(Debug, PartialEq)]
 pub enum DescribeStreamError {
@@ -15892,6 +17070,18 @@ impl fmt::Display for DescribeStreamError {
     }
 }
 impl Error for DescribeStreamError {}
+impl ServiceErrorCode for DescribeStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStreamError::InternalFailure(_) => "InternalFailureException",
+            DescribeStreamError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeStreamError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeStreamError::Throttling(_) => "ThrottlingException",
+            DescribeStreamError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeThing
 #[derive(Debug, PartialEq)]
 pub enum DescribeThingError {
@@ -15952,6 +17142,18 @@ impl fmt::Display for DescribeThingError {
     }
 }
 impl Error for DescribeThingError {}
+impl ServiceErrorCode for DescribeThingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeThingError::InternalFailure(_) => "InternalFailureException",
+            DescribeThingError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeThingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeThingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeThingError::Throttling(_) => "ThrottlingException",
+            DescribeThingError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeThingGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeThingGroupError {
@@ -16000,6 +17202,16 @@ impl fmt::Display for DescribeThingGroupError {
     }
 }
 impl Error for DescribeThingGroupError {}
+impl ServiceErrorCode for DescribeThingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeThingGroupError::InternalFailure(_) => "InternalFailureException",
+            DescribeThingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeThingGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeThingGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeThingRegistrationTask
 #[derive(Debug, PartialEq)]
 pub enum DescribeThingRegistrationTaskError {
@@ -16070,6 +17282,17 @@ impl fmt::Display for DescribeThingRegistrationTaskError {
     }
 }
 impl Error for DescribeThingRegistrationTaskError {}
+impl ServiceErrorCode for DescribeThingRegistrationTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeThingRegistrationTaskError::InternalFailure(_) => "InternalFailureException",
+            DescribeThingRegistrationTaskError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeThingRegistrationTaskError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeThingRegistrationTaskError::Throttling(_) => "ThrottlingException",
+            DescribeThingRegistrationTaskError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeThingType
 #[derive(Debug, PartialEq)]
 pub enum DescribeThingTypeError {
@@ -16132,6 +17355,18 @@ impl fmt::Display for DescribeThingTypeError {
     }
 }
 impl Error for DescribeThingTypeError {}
+impl ServiceErrorCode for DescribeThingTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeThingTypeError::InternalFailure(_) => "InternalFailureException",
+            DescribeThingTypeError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeThingTypeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeThingTypeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeThingTypeError::Throttling(_) => "ThrottlingException",
+            DescribeThingTypeError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DetachPolicy
 #[derive(Debug, PartialEq)]
 pub enum DetachPolicyError {
@@ -16192,6 +17427,18 @@ impl fmt::Display for DetachPolicyError {
     }
 }
 impl Error for DetachPolicyError {}
+impl ServiceErrorCode for DetachPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachPolicyError::InternalFailure(_) => "InternalFailureException",
+            DetachPolicyError::InvalidRequest(_) => "InvalidRequestException",
+            DetachPolicyError::LimitExceeded(_) => "LimitExceededException",
+            DetachPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DetachPolicyError::Throttling(_) => "ThrottlingException",
+            DetachPolicyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DetachPrincipalPolicy
 #[derive(Debug, PartialEq)]
 pub enum DetachPrincipalPolicyError {
@@ -16260,6 +17507,18 @@ impl fmt::Display for DetachPrincipalPolicyError {
     }
 }
 impl Error for DetachPrincipalPolicyError {}
+impl ServiceErrorCode for DetachPrincipalPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachPrincipalPolicyError::InternalFailure(_) => "InternalFailureException",
+            DetachPrincipalPolicyError::InvalidRequest(_) => "InvalidRequestException",
+            DetachPrincipalPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DetachPrincipalPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DetachPrincipalPolicyError::Throttling(_) => "ThrottlingException",
+            DetachPrincipalPolicyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DetachSecurityProfile
 #[derive(Debug, PartialEq)]
 pub enum DetachSecurityProfileError {
@@ -16314,6 +17573,16 @@ impl fmt::Display for DetachSecurityProfileError {
     }
 }
 impl Error for DetachSecurityProfileError {}
+impl ServiceErrorCode for DetachSecurityProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachSecurityProfileError::InternalFailure(_) => "InternalFailureException",
+            DetachSecurityProfileError::InvalidRequest(_) => "InvalidRequestException",
+            DetachSecurityProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DetachSecurityProfileError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DetachThingPrincipal
 #[derive(Debug, PartialEq)]
 pub enum DetachThingPrincipalError {
@@ -16380,6 +17649,18 @@ impl fmt::Display for DetachThingPrincipalError {
     }
 }
 impl Error for DetachThingPrincipalError {}
+impl ServiceErrorCode for DetachThingPrincipalError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachThingPrincipalError::InternalFailure(_) => "InternalFailureException",
+            DetachThingPrincipalError::InvalidRequest(_) => "InvalidRequestException",
+            DetachThingPrincipalError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DetachThingPrincipalError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DetachThingPrincipalError::Throttling(_) => "ThrottlingException",
+            DetachThingPrincipalError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DisableTopicRule
 #[derive(Debug, PartialEq)]
 pub enum DisableTopicRuleError {
@@ -16436,6 +17717,19 @@ impl fmt::Display for DisableTopicRuleError {
     }
 }
 impl Error for DisableTopicRuleError {}
+impl ServiceErrorCode for DisableTopicRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableTopicRuleError::ConflictingResourceUpdate(_) => {
+                "ConflictingResourceUpdateException"
+            }
+            DisableTopicRuleError::Internal(_) => "InternalException",
+            DisableTopicRuleError::InvalidRequest(_) => "InvalidRequestException",
+            DisableTopicRuleError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DisableTopicRuleError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by EnableTopicRule
 #[derive(Debug, PartialEq)]
 pub enum EnableTopicRuleError {
@@ -16492,6 +17786,19 @@ impl fmt::Display for EnableTopicRuleError {
     }
 }
 impl Error for EnableTopicRuleError {}
+impl ServiceErrorCode for EnableTopicRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableTopicRuleError::ConflictingResourceUpdate(_) => {
+                "ConflictingResourceUpdateException"
+            }
+            EnableTopicRuleError::Internal(_) => "InternalException",
+            EnableTopicRuleError::InvalidRequest(_) => "InvalidRequestException",
+            EnableTopicRuleError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            EnableTopicRuleError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetBehaviorModelTrainingSummaries
 #[derive(Debug, PartialEq)]
 pub enum GetBehaviorModelTrainingSummariesError {
@@ -16556,6 +17863,20 @@ impl fmt::Display for GetBehaviorModelTrainingSummariesError {
     }
 }
 impl Error for GetBehaviorModelTrainingSummariesError {}
+impl ServiceErrorCode for GetBehaviorModelTrainingSummariesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBehaviorModelTrainingSummariesError::InternalFailure(_) => {
+                "InternalFailureException"
+            }
+            GetBehaviorModelTrainingSummariesError::InvalidRequest(_) => "InvalidRequestException",
+            GetBehaviorModelTrainingSummariesError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            GetBehaviorModelTrainingSummariesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetCardinality
 #[derive(Debug, PartialEq)]
 pub enum GetCardinalityError {
@@ -16634,6 +17955,21 @@ impl fmt::Display for GetCardinalityError {
     }
 }
 impl Error for GetCardinalityError {}
+impl ServiceErrorCode for GetCardinalityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCardinalityError::IndexNotReady(_) => "IndexNotReadyException",
+            GetCardinalityError::InternalFailure(_) => "InternalFailureException",
+            GetCardinalityError::InvalidAggregation(_) => "InvalidAggregationException",
+            GetCardinalityError::InvalidQuery(_) => "InvalidQueryException",
+            GetCardinalityError::InvalidRequest(_) => "InvalidRequestException",
+            GetCardinalityError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetCardinalityError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetCardinalityError::Throttling(_) => "ThrottlingException",
+            GetCardinalityError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetEffectivePolicies
 #[derive(Debug, PartialEq)]
 pub enum GetEffectivePoliciesError {
@@ -16706,6 +18042,19 @@ impl fmt::Display for GetEffectivePoliciesError {
     }
 }
 impl Error for GetEffectivePoliciesError {}
+impl ServiceErrorCode for GetEffectivePoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEffectivePoliciesError::InternalFailure(_) => "InternalFailureException",
+            GetEffectivePoliciesError::InvalidRequest(_) => "InvalidRequestException",
+            GetEffectivePoliciesError::LimitExceeded(_) => "LimitExceededException",
+            GetEffectivePoliciesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetEffectivePoliciesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetEffectivePoliciesError::Throttling(_) => "ThrottlingException",
+            GetEffectivePoliciesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetIndexingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetIndexingConfigurationError {
@@ -16768,6 +18117,17 @@ impl fmt::Display for GetIndexingConfigurationError {
     }
 }
 impl Error for GetIndexingConfigurationError {}
+impl ServiceErrorCode for GetIndexingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIndexingConfigurationError::InternalFailure(_) => "InternalFailureException",
+            GetIndexingConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            GetIndexingConfigurationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetIndexingConfigurationError::Throttling(_) => "ThrottlingException",
+            GetIndexingConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetJobDocument
 #[derive(Debug, PartialEq)]
 pub enum GetJobDocumentError {
@@ -16816,6 +18176,16 @@ impl fmt::Display for GetJobDocumentError {
     }
 }
 impl Error for GetJobDocumentError {}
+impl ServiceErrorCode for GetJobDocumentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetJobDocumentError::InvalidRequest(_) => "InvalidRequestException",
+            GetJobDocumentError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetJobDocumentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetJobDocumentError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetLoggingOptions
 #[derive(Debug, PartialEq)]
 pub enum GetLoggingOptionsError {
@@ -16860,6 +18230,15 @@ impl fmt::Display for GetLoggingOptionsError {
     }
 }
 impl Error for GetLoggingOptionsError {}
+impl ServiceErrorCode for GetLoggingOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLoggingOptionsError::Internal(_) => "InternalException",
+            GetLoggingOptionsError::InvalidRequest(_) => "InvalidRequestException",
+            GetLoggingOptionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetOTAUpdate
 #[derive(Debug, PartialEq)]
 pub enum GetOTAUpdateError {
@@ -16920,6 +18299,18 @@ impl fmt::Display for GetOTAUpdateError {
     }
 }
 impl Error for GetOTAUpdateError {}
+impl ServiceErrorCode for GetOTAUpdateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOTAUpdateError::InternalFailure(_) => "InternalFailureException",
+            GetOTAUpdateError::InvalidRequest(_) => "InvalidRequestException",
+            GetOTAUpdateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetOTAUpdateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetOTAUpdateError::Throttling(_) => "ThrottlingException",
+            GetOTAUpdateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetPercentiles
 #[derive(Debug, PartialEq)]
 pub enum GetPercentilesError {
@@ -16998,6 +18389,21 @@ impl fmt::Display for GetPercentilesError {
     }
 }
 impl Error for GetPercentilesError {}
+impl ServiceErrorCode for GetPercentilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPercentilesError::IndexNotReady(_) => "IndexNotReadyException",
+            GetPercentilesError::InternalFailure(_) => "InternalFailureException",
+            GetPercentilesError::InvalidAggregation(_) => "InvalidAggregationException",
+            GetPercentilesError::InvalidQuery(_) => "InvalidQueryException",
+            GetPercentilesError::InvalidRequest(_) => "InvalidRequestException",
+            GetPercentilesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetPercentilesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetPercentilesError::Throttling(_) => "ThrottlingException",
+            GetPercentilesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetPolicyError {
@@ -17058,6 +18464,18 @@ impl fmt::Display for GetPolicyError {
     }
 }
 impl Error for GetPolicyError {}
+impl ServiceErrorCode for GetPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPolicyError::InternalFailure(_) => "InternalFailureException",
+            GetPolicyError::InvalidRequest(_) => "InvalidRequestException",
+            GetPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetPolicyError::Throttling(_) => "ThrottlingException",
+            GetPolicyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetPolicyVersion
 #[derive(Debug, PartialEq)]
 pub enum GetPolicyVersionError {
@@ -17118,6 +18536,18 @@ impl fmt::Display for GetPolicyVersionError {
     }
 }
 impl Error for GetPolicyVersionError {}
+impl ServiceErrorCode for GetPolicyVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPolicyVersionError::InternalFailure(_) => "InternalFailureException",
+            GetPolicyVersionError::InvalidRequest(_) => "InvalidRequestException",
+            GetPolicyVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetPolicyVersionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetPolicyVersionError::Throttling(_) => "ThrottlingException",
+            GetPolicyVersionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetRegistrationCode
 #[derive(Debug, PartialEq)]
 pub enum GetRegistrationCodeError {
@@ -17174,6 +18604,17 @@ impl fmt::Display for GetRegistrationCodeError {
     }
 }
 impl Error for GetRegistrationCodeError {}
+impl ServiceErrorCode for GetRegistrationCodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRegistrationCodeError::InternalFailure(_) => "InternalFailureException",
+            GetRegistrationCodeError::InvalidRequest(_) => "InvalidRequestException",
+            GetRegistrationCodeError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetRegistrationCodeError::Throttling(_) => "ThrottlingException",
+            GetRegistrationCodeError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetStatistics
 #[derive(Debug, PartialEq)]
 pub enum GetStatisticsError {
@@ -17252,6 +18693,21 @@ impl fmt::Display for GetStatisticsError {
     }
 }
 impl Error for GetStatisticsError {}
+impl ServiceErrorCode for GetStatisticsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetStatisticsError::IndexNotReady(_) => "IndexNotReadyException",
+            GetStatisticsError::InternalFailure(_) => "InternalFailureException",
+            GetStatisticsError::InvalidAggregation(_) => "InvalidAggregationException",
+            GetStatisticsError::InvalidQuery(_) => "InvalidQueryException",
+            GetStatisticsError::InvalidRequest(_) => "InvalidRequestException",
+            GetStatisticsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetStatisticsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetStatisticsError::Throttling(_) => "ThrottlingException",
+            GetStatisticsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetTopicRule
 #[derive(Debug, PartialEq)]
 pub enum GetTopicRuleError {
@@ -17300,6 +18756,16 @@ impl fmt::Display for GetTopicRuleError {
     }
 }
 impl Error for GetTopicRuleError {}
+impl ServiceErrorCode for GetTopicRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTopicRuleError::Internal(_) => "InternalException",
+            GetTopicRuleError::InvalidRequest(_) => "InvalidRequestException",
+            GetTopicRuleError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetTopicRuleError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetTopicRuleDestination
 #[derive(Debug, PartialEq)]
 pub enum GetTopicRuleDestinationError {
@@ -17354,6 +18820,16 @@ impl fmt::Display for GetTopicRuleDestinationError {
     }
 }
 impl Error for GetTopicRuleDestinationError {}
+impl ServiceErrorCode for GetTopicRuleDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTopicRuleDestinationError::Internal(_) => "InternalException",
+            GetTopicRuleDestinationError::InvalidRequest(_) => "InvalidRequestException",
+            GetTopicRuleDestinationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetTopicRuleDestinationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetV2LoggingOptions
 #[derive(Debug, PartialEq)]
 pub enum GetV2LoggingOptionsError {
@@ -17398,6 +18874,15 @@ impl fmt::Display for GetV2LoggingOptionsError {
     }
 }
 impl Error for GetV2LoggingOptionsError {}
+impl ServiceErrorCode for GetV2LoggingOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetV2LoggingOptionsError::Internal(_) => "InternalException",
+            GetV2LoggingOptionsError::NotConfigured(_) => "NotConfiguredException",
+            GetV2LoggingOptionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListActiveViolations
 #[derive(Debug, PartialEq)]
 pub enum ListActiveViolationsError {
@@ -17450,6 +18935,16 @@ impl fmt::Display for ListActiveViolationsError {
     }
 }
 impl Error for ListActiveViolationsError {}
+impl ServiceErrorCode for ListActiveViolationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListActiveViolationsError::InternalFailure(_) => "InternalFailureException",
+            ListActiveViolationsError::InvalidRequest(_) => "InvalidRequestException",
+            ListActiveViolationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListActiveViolationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListAttachedPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListAttachedPoliciesError {
@@ -17522,6 +19017,19 @@ impl fmt::Display for ListAttachedPoliciesError {
     }
 }
 impl Error for ListAttachedPoliciesError {}
+impl ServiceErrorCode for ListAttachedPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAttachedPoliciesError::InternalFailure(_) => "InternalFailureException",
+            ListAttachedPoliciesError::InvalidRequest(_) => "InvalidRequestException",
+            ListAttachedPoliciesError::LimitExceeded(_) => "LimitExceededException",
+            ListAttachedPoliciesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListAttachedPoliciesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListAttachedPoliciesError::Throttling(_) => "ThrottlingException",
+            ListAttachedPoliciesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListAuditFindings
 #[derive(Debug, PartialEq)]
 pub enum ListAuditFindingsError {
@@ -17564,6 +19072,15 @@ impl fmt::Display for ListAuditFindingsError {
     }
 }
 impl Error for ListAuditFindingsError {}
+impl ServiceErrorCode for ListAuditFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAuditFindingsError::InternalFailure(_) => "InternalFailureException",
+            ListAuditFindingsError::InvalidRequest(_) => "InvalidRequestException",
+            ListAuditFindingsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListAuditMitigationActionsExecutions
 #[derive(Debug, PartialEq)]
 pub enum ListAuditMitigationActionsExecutionsError {
@@ -17620,6 +19137,19 @@ impl fmt::Display for ListAuditMitigationActionsExecutionsError {
     }
 }
 impl Error for ListAuditMitigationActionsExecutionsError {}
+impl ServiceErrorCode for ListAuditMitigationActionsExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAuditMitigationActionsExecutionsError::InternalFailure(_) => {
+                "InternalFailureException"
+            }
+            ListAuditMitigationActionsExecutionsError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            ListAuditMitigationActionsExecutionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListAuditMitigationActionsTasks
 #[derive(Debug, PartialEq)]
 pub enum ListAuditMitigationActionsTasksError {
@@ -17674,6 +19204,15 @@ impl fmt::Display for ListAuditMitigationActionsTasksError {
     }
 }
 impl Error for ListAuditMitigationActionsTasksError {}
+impl ServiceErrorCode for ListAuditMitigationActionsTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAuditMitigationActionsTasksError::InternalFailure(_) => "InternalFailureException",
+            ListAuditMitigationActionsTasksError::InvalidRequest(_) => "InvalidRequestException",
+            ListAuditMitigationActionsTasksError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListAuditSuppressions
 #[derive(Debug, PartialEq)]
 pub enum ListAuditSuppressionsError {
@@ -17720,6 +19259,15 @@ impl fmt::Display for ListAuditSuppressionsError {
     }
 }
 impl Error for ListAuditSuppressionsError {}
+impl ServiceErrorCode for ListAuditSuppressionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAuditSuppressionsError::InternalFailure(_) => "InternalFailureException",
+            ListAuditSuppressionsError::InvalidRequest(_) => "InvalidRequestException",
+            ListAuditSuppressionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListAuditTasks
 #[derive(Debug, PartialEq)]
 pub enum ListAuditTasksError {
@@ -17762,6 +19310,15 @@ impl fmt::Display for ListAuditTasksError {
     }
 }
 impl Error for ListAuditTasksError {}
+impl ServiceErrorCode for ListAuditTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAuditTasksError::InternalFailure(_) => "InternalFailureException",
+            ListAuditTasksError::InvalidRequest(_) => "InvalidRequestException",
+            ListAuditTasksError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListAuthorizers
 #[derive(Debug, PartialEq)]
 pub enum ListAuthorizersError {
@@ -17816,6 +19373,17 @@ impl fmt::Display for ListAuthorizersError {
     }
 }
 impl Error for ListAuthorizersError {}
+impl ServiceErrorCode for ListAuthorizersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAuthorizersError::InternalFailure(_) => "InternalFailureException",
+            ListAuthorizersError::InvalidRequest(_) => "InvalidRequestException",
+            ListAuthorizersError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListAuthorizersError::Throttling(_) => "ThrottlingException",
+            ListAuthorizersError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListBillingGroups
 #[derive(Debug, PartialEq)]
 pub enum ListBillingGroupsError {
@@ -17864,6 +19432,16 @@ impl fmt::Display for ListBillingGroupsError {
     }
 }
 impl Error for ListBillingGroupsError {}
+impl ServiceErrorCode for ListBillingGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBillingGroupsError::InternalFailure(_) => "InternalFailureException",
+            ListBillingGroupsError::InvalidRequest(_) => "InvalidRequestException",
+            ListBillingGroupsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListBillingGroupsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListCACertificates
 #[derive(Debug, PartialEq)]
 pub enum ListCACertificatesError {
@@ -17920,6 +19498,17 @@ impl fmt::Display for ListCACertificatesError {
     }
 }
 impl Error for ListCACertificatesError {}
+impl ServiceErrorCode for ListCACertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCACertificatesError::InternalFailure(_) => "InternalFailureException",
+            ListCACertificatesError::InvalidRequest(_) => "InvalidRequestException",
+            ListCACertificatesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListCACertificatesError::Throttling(_) => "ThrottlingException",
+            ListCACertificatesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListCertificates
 #[derive(Debug, PartialEq)]
 pub enum ListCertificatesError {
@@ -17974,6 +19563,17 @@ impl fmt::Display for ListCertificatesError {
     }
 }
 impl Error for ListCertificatesError {}
+impl ServiceErrorCode for ListCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCertificatesError::InternalFailure(_) => "InternalFailureException",
+            ListCertificatesError::InvalidRequest(_) => "InvalidRequestException",
+            ListCertificatesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListCertificatesError::Throttling(_) => "ThrottlingException",
+            ListCertificatesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListCertificatesByCA
 #[derive(Debug, PartialEq)]
 pub enum ListCertificatesByCAError {
@@ -18032,6 +19632,17 @@ impl fmt::Display for ListCertificatesByCAError {
     }
 }
 impl Error for ListCertificatesByCAError {}
+impl ServiceErrorCode for ListCertificatesByCAError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCertificatesByCAError::InternalFailure(_) => "InternalFailureException",
+            ListCertificatesByCAError::InvalidRequest(_) => "InvalidRequestException",
+            ListCertificatesByCAError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListCertificatesByCAError::Throttling(_) => "ThrottlingException",
+            ListCertificatesByCAError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListCustomMetrics
 #[derive(Debug, PartialEq)]
 pub enum ListCustomMetricsError {
@@ -18074,6 +19685,15 @@ impl fmt::Display for ListCustomMetricsError {
     }
 }
 impl Error for ListCustomMetricsError {}
+impl ServiceErrorCode for ListCustomMetricsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCustomMetricsError::InternalFailure(_) => "InternalFailureException",
+            ListCustomMetricsError::InvalidRequest(_) => "InvalidRequestException",
+            ListCustomMetricsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDetectMitigationActionsExecutions
 #[derive(Debug, PartialEq)]
 pub enum ListDetectMitigationActionsExecutionsError {
@@ -18130,6 +19750,19 @@ impl fmt::Display for ListDetectMitigationActionsExecutionsError {
     }
 }
 impl Error for ListDetectMitigationActionsExecutionsError {}
+impl ServiceErrorCode for ListDetectMitigationActionsExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDetectMitigationActionsExecutionsError::InternalFailure(_) => {
+                "InternalFailureException"
+            }
+            ListDetectMitigationActionsExecutionsError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            ListDetectMitigationActionsExecutionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDetectMitigationActionsTasks
 #[derive(Debug, PartialEq)]
 pub enum ListDetectMitigationActionsTasksError {
@@ -18184,6 +19817,15 @@ impl fmt::Display for ListDetectMitigationActionsTasksError {
     }
 }
 impl Error for ListDetectMitigationActionsTasksError {}
+impl ServiceErrorCode for ListDetectMitigationActionsTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDetectMitigationActionsTasksError::InternalFailure(_) => "InternalFailureException",
+            ListDetectMitigationActionsTasksError::InvalidRequest(_) => "InvalidRequestException",
+            ListDetectMitigationActionsTasksError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDimensions
 #[derive(Debug, PartialEq)]
 pub enum ListDimensionsError {
@@ -18226,6 +19868,15 @@ impl fmt::Display for ListDimensionsError {
     }
 }
 impl Error for ListDimensionsError {}
+impl ServiceErrorCode for ListDimensionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDimensionsError::InternalFailure(_) => "InternalFailureException",
+            ListDimensionsError::InvalidRequest(_) => "InvalidRequestException",
+            ListDimensionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDomainConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListDomainConfigurationsError {
@@ -18288,6 +19939,17 @@ impl fmt::Display for ListDomainConfigurationsError {
     }
 }
 impl Error for ListDomainConfigurationsError {}
+impl ServiceErrorCode for ListDomainConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDomainConfigurationsError::InternalFailure(_) => "InternalFailureException",
+            ListDomainConfigurationsError::InvalidRequest(_) => "InvalidRequestException",
+            ListDomainConfigurationsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListDomainConfigurationsError::Throttling(_) => "ThrottlingException",
+            ListDomainConfigurationsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListIndices
 #[derive(Debug, PartialEq)]
 pub enum ListIndicesError {
@@ -18342,6 +20004,17 @@ impl fmt::Display for ListIndicesError {
     }
 }
 impl Error for ListIndicesError {}
+impl ServiceErrorCode for ListIndicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListIndicesError::InternalFailure(_) => "InternalFailureException",
+            ListIndicesError::InvalidRequest(_) => "InvalidRequestException",
+            ListIndicesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListIndicesError::Throttling(_) => "ThrottlingException",
+            ListIndicesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListJobExecutionsForJob
 #[derive(Debug, PartialEq)]
 pub enum ListJobExecutionsForJobError {
@@ -18396,6 +20069,16 @@ impl fmt::Display for ListJobExecutionsForJobError {
     }
 }
 impl Error for ListJobExecutionsForJobError {}
+impl ServiceErrorCode for ListJobExecutionsForJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJobExecutionsForJobError::InvalidRequest(_) => "InvalidRequestException",
+            ListJobExecutionsForJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListJobExecutionsForJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListJobExecutionsForJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListJobExecutionsForThing
 #[derive(Debug, PartialEq)]
 pub enum ListJobExecutionsForThingError {
@@ -18452,6 +20135,16 @@ impl fmt::Display for ListJobExecutionsForThingError {
     }
 }
 impl Error for ListJobExecutionsForThingError {}
+impl ServiceErrorCode for ListJobExecutionsForThingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJobExecutionsForThingError::InvalidRequest(_) => "InvalidRequestException",
+            ListJobExecutionsForThingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListJobExecutionsForThingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListJobExecutionsForThingError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
 pub enum ListJobsError {
@@ -18500,6 +20193,16 @@ impl fmt::Display for ListJobsError {
     }
 }
 impl Error for ListJobsError {}
+impl ServiceErrorCode for ListJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJobsError::InvalidRequest(_) => "InvalidRequestException",
+            ListJobsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListJobsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListJobsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListMitigationActions
 #[derive(Debug, PartialEq)]
 pub enum ListMitigationActionsError {
@@ -18546,6 +20249,15 @@ impl fmt::Display for ListMitigationActionsError {
     }
 }
 impl Error for ListMitigationActionsError {}
+impl ServiceErrorCode for ListMitigationActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMitigationActionsError::InternalFailure(_) => "InternalFailureException",
+            ListMitigationActionsError::InvalidRequest(_) => "InvalidRequestException",
+            ListMitigationActionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListOTAUpdates
 #[derive(Debug, PartialEq)]
 pub enum ListOTAUpdatesError {
@@ -18600,6 +20312,17 @@ impl fmt::Display for ListOTAUpdatesError {
     }
 }
 impl Error for ListOTAUpdatesError {}
+impl ServiceErrorCode for ListOTAUpdatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOTAUpdatesError::InternalFailure(_) => "InternalFailureException",
+            ListOTAUpdatesError::InvalidRequest(_) => "InvalidRequestException",
+            ListOTAUpdatesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListOTAUpdatesError::Throttling(_) => "ThrottlingException",
+            ListOTAUpdatesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListOutgoingCertificates
 #[derive(Debug, PartialEq)]
 pub enum ListOutgoingCertificatesError {
@@ -18662,6 +20385,17 @@ impl fmt::Display for ListOutgoingCertificatesError {
     }
 }
 impl Error for ListOutgoingCertificatesError {}
+impl ServiceErrorCode for ListOutgoingCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOutgoingCertificatesError::InternalFailure(_) => "InternalFailureException",
+            ListOutgoingCertificatesError::InvalidRequest(_) => "InvalidRequestException",
+            ListOutgoingCertificatesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListOutgoingCertificatesError::Throttling(_) => "ThrottlingException",
+            ListOutgoingCertificatesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListPoliciesError {
@@ -18716,6 +20450,17 @@ impl fmt::Display for ListPoliciesError {
     }
 }
 impl Error for ListPoliciesError {}
+impl ServiceErrorCode for ListPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPoliciesError::InternalFailure(_) => "InternalFailureException",
+            ListPoliciesError::InvalidRequest(_) => "InvalidRequestException",
+            ListPoliciesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListPoliciesError::Throttling(_) => "ThrottlingException",
+            ListPoliciesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListPolicyPrincipals
 #[derive(Debug, PartialEq)]
 pub enum ListPolicyPrincipalsError {
@@ -18782,6 +20527,18 @@ impl fmt::Display for ListPolicyPrincipalsError {
     }
 }
 impl Error for ListPolicyPrincipalsError {}
+impl ServiceErrorCode for ListPolicyPrincipalsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPolicyPrincipalsError::InternalFailure(_) => "InternalFailureException",
+            ListPolicyPrincipalsError::InvalidRequest(_) => "InvalidRequestException",
+            ListPolicyPrincipalsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListPolicyPrincipalsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListPolicyPrincipalsError::Throttling(_) => "ThrottlingException",
+            ListPolicyPrincipalsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListPolicyVersions
 #[derive(Debug, PartialEq)]
 pub enum ListPolicyVersionsError {
@@ -18844,6 +20601,18 @@ impl fmt::Display for ListPolicyVersionsError {
     }
 }
 impl Error for ListPolicyVersionsError {}
+impl ServiceErrorCode for ListPolicyVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPolicyVersionsError::InternalFailure(_) => "InternalFailureException",
+            ListPolicyVersionsError::InvalidRequest(_) => "InvalidRequestException",
+            ListPolicyVersionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListPolicyVersionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListPolicyVersionsError::Throttling(_) => "ThrottlingException",
+            ListPolicyVersionsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListPrincipalPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListPrincipalPoliciesError {
@@ -18912,6 +20681,18 @@ impl fmt::Display for ListPrincipalPoliciesError {
     }
 }
 impl Error for ListPrincipalPoliciesError {}
+impl ServiceErrorCode for ListPrincipalPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPrincipalPoliciesError::InternalFailure(_) => "InternalFailureException",
+            ListPrincipalPoliciesError::InvalidRequest(_) => "InvalidRequestException",
+            ListPrincipalPoliciesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListPrincipalPoliciesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListPrincipalPoliciesError::Throttling(_) => "ThrottlingException",
+            ListPrincipalPoliciesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListPrincipalThings
 #[derive(Debug, PartialEq)]
 pub enum ListPrincipalThingsError {
@@ -18976,6 +20757,18 @@ impl fmt::Display for ListPrincipalThingsError {
     }
 }
 impl Error for ListPrincipalThingsError {}
+impl ServiceErrorCode for ListPrincipalThingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPrincipalThingsError::InternalFailure(_) => "InternalFailureException",
+            ListPrincipalThingsError::InvalidRequest(_) => "InvalidRequestException",
+            ListPrincipalThingsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListPrincipalThingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListPrincipalThingsError::Throttling(_) => "ThrottlingException",
+            ListPrincipalThingsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListProvisioningTemplateVersions
 #[derive(Debug, PartialEq)]
 pub enum ListProvisioningTemplateVersionsError {
@@ -19050,6 +20843,19 @@ impl fmt::Display for ListProvisioningTemplateVersionsError {
     }
 }
 impl Error for ListProvisioningTemplateVersionsError {}
+impl ServiceErrorCode for ListProvisioningTemplateVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProvisioningTemplateVersionsError::InternalFailure(_) => "InternalFailureException",
+            ListProvisioningTemplateVersionsError::InvalidRequest(_) => "InvalidRequestException",
+            ListProvisioningTemplateVersionsError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            ListProvisioningTemplateVersionsError::Throttling(_) => "ThrottlingException",
+            ListProvisioningTemplateVersionsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListProvisioningTemplates
 #[derive(Debug, PartialEq)]
 pub enum ListProvisioningTemplatesError {
@@ -19106,6 +20912,16 @@ impl fmt::Display for ListProvisioningTemplatesError {
     }
 }
 impl Error for ListProvisioningTemplatesError {}
+impl ServiceErrorCode for ListProvisioningTemplatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProvisioningTemplatesError::InternalFailure(_) => "InternalFailureException",
+            ListProvisioningTemplatesError::InvalidRequest(_) => "InvalidRequestException",
+            ListProvisioningTemplatesError::Throttling(_) => "ThrottlingException",
+            ListProvisioningTemplatesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListRoleAliases
 #[derive(Debug, PartialEq)]
 pub enum ListRoleAliasesError {
@@ -19160,6 +20976,17 @@ impl fmt::Display for ListRoleAliasesError {
     }
 }
 impl Error for ListRoleAliasesError {}
+impl ServiceErrorCode for ListRoleAliasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRoleAliasesError::InternalFailure(_) => "InternalFailureException",
+            ListRoleAliasesError::InvalidRequest(_) => "InvalidRequestException",
+            ListRoleAliasesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListRoleAliasesError::Throttling(_) => "ThrottlingException",
+            ListRoleAliasesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListScheduledAudits
 #[derive(Debug, PartialEq)]
 pub enum ListScheduledAuditsError {
@@ -19202,6 +21029,15 @@ impl fmt::Display for ListScheduledAuditsError {
     }
 }
 impl Error for ListScheduledAuditsError {}
+impl ServiceErrorCode for ListScheduledAuditsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListScheduledAuditsError::InternalFailure(_) => "InternalFailureException",
+            ListScheduledAuditsError::InvalidRequest(_) => "InvalidRequestException",
+            ListScheduledAuditsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListSecurityProfiles
 #[derive(Debug, PartialEq)]
 pub enum ListSecurityProfilesError {
@@ -19254,6 +21090,16 @@ impl fmt::Display for ListSecurityProfilesError {
     }
 }
 impl Error for ListSecurityProfilesError {}
+impl ServiceErrorCode for ListSecurityProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSecurityProfilesError::InternalFailure(_) => "InternalFailureException",
+            ListSecurityProfilesError::InvalidRequest(_) => "InvalidRequestException",
+            ListSecurityProfilesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListSecurityProfilesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListSecurityProfilesForTarget
 #[derive(Debug, PartialEq)]
 pub enum ListSecurityProfilesForTargetError {
@@ -19316,6 +21162,16 @@ impl fmt::Display for ListSecurityProfilesForTargetError {
     }
 }
 impl Error for ListSecurityProfilesForTargetError {}
+impl ServiceErrorCode for ListSecurityProfilesForTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSecurityProfilesForTargetError::InternalFailure(_) => "InternalFailureException",
+            ListSecurityProfilesForTargetError::InvalidRequest(_) => "InvalidRequestException",
+            ListSecurityProfilesForTargetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListSecurityProfilesForTargetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListStreams
 #[derive(Debug, PartialEq)]
 pub enum ListStreamsError {
@@ -19370,6 +21226,17 @@ impl fmt::Display for ListStreamsError {
     }
 }
 impl Error for ListStreamsError {}
+impl ServiceErrorCode for ListStreamsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStreamsError::InternalFailure(_) => "InternalFailureException",
+            ListStreamsError::InvalidRequest(_) => "InvalidRequestException",
+            ListStreamsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListStreamsError::Throttling(_) => "ThrottlingException",
+            ListStreamsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -19420,6 +21287,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalFailure(_) => "InternalFailureException",
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTargetsForPolicy
 #[derive(Debug, PartialEq)]
 pub enum ListTargetsForPolicyError {
@@ -19492,6 +21369,19 @@ impl fmt::Display for ListTargetsForPolicyError {
     }
 }
 impl Error for ListTargetsForPolicyError {}
+impl ServiceErrorCode for ListTargetsForPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTargetsForPolicyError::InternalFailure(_) => "InternalFailureException",
+            ListTargetsForPolicyError::InvalidRequest(_) => "InvalidRequestException",
+            ListTargetsForPolicyError::LimitExceeded(_) => "LimitExceededException",
+            ListTargetsForPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTargetsForPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListTargetsForPolicyError::Throttling(_) => "ThrottlingException",
+            ListTargetsForPolicyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListTargetsForSecurityProfile
 #[derive(Debug, PartialEq)]
 pub enum ListTargetsForSecurityProfileError {
@@ -19554,6 +21444,16 @@ impl fmt::Display for ListTargetsForSecurityProfileError {
     }
 }
 impl Error for ListTargetsForSecurityProfileError {}
+impl ServiceErrorCode for ListTargetsForSecurityProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTargetsForSecurityProfileError::InternalFailure(_) => "InternalFailureException",
+            ListTargetsForSecurityProfileError::InvalidRequest(_) => "InvalidRequestException",
+            ListTargetsForSecurityProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTargetsForSecurityProfileError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListThingGroups
 #[derive(Debug, PartialEq)]
 pub enum ListThingGroupsError {
@@ -19602,6 +21502,16 @@ impl fmt::Display for ListThingGroupsError {
     }
 }
 impl Error for ListThingGroupsError {}
+impl ServiceErrorCode for ListThingGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListThingGroupsError::InternalFailure(_) => "InternalFailureException",
+            ListThingGroupsError::InvalidRequest(_) => "InvalidRequestException",
+            ListThingGroupsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListThingGroupsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListThingGroupsForThing
 #[derive(Debug, PartialEq)]
 pub enum ListThingGroupsForThingError {
@@ -19656,6 +21566,16 @@ impl fmt::Display for ListThingGroupsForThingError {
     }
 }
 impl Error for ListThingGroupsForThingError {}
+impl ServiceErrorCode for ListThingGroupsForThingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListThingGroupsForThingError::InternalFailure(_) => "InternalFailureException",
+            ListThingGroupsForThingError::InvalidRequest(_) => "InvalidRequestException",
+            ListThingGroupsForThingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListThingGroupsForThingError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListThingPrincipals
 #[derive(Debug, PartialEq)]
 pub enum ListThingPrincipalsError {
@@ -19720,6 +21640,18 @@ impl fmt::Display for ListThingPrincipalsError {
     }
 }
 impl Error for ListThingPrincipalsError {}
+impl ServiceErrorCode for ListThingPrincipalsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListThingPrincipalsError::InternalFailure(_) => "InternalFailureException",
+            ListThingPrincipalsError::InvalidRequest(_) => "InvalidRequestException",
+            ListThingPrincipalsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListThingPrincipalsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListThingPrincipalsError::Throttling(_) => "ThrottlingException",
+            ListThingPrincipalsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListThingRegistrationTaskReports
 #[derive(Debug, PartialEq)]
 pub enum ListThingRegistrationTaskReportsError {
@@ -19783,7 +21715,17 @@ impl fmt::Display for ListThingRegistrationTaskReportsError {
         }
     }
 }
-impl Error for ListThingRegistrationTaskReportsError {}
+impl Error for ListThingRegistrationTaskReportsError {}
+impl ServiceErrorCode for ListThingRegistrationTaskReportsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListThingRegistrationTaskReportsError::InternalFailure(_) => "InternalFailureException",
+            ListThingRegistrationTaskReportsError::InvalidRequest(_) => "InvalidRequestException",
+            ListThingRegistrationTaskReportsError::Throttling(_) => "ThrottlingException",
+            ListThingRegistrationTaskReportsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListThingRegistrationTasks
 #[derive(Debug, PartialEq)]
 pub enum ListThingRegistrationTasksError {
@@ -19842,6 +21784,16 @@ impl fmt::Display for ListThingRegistrationTasksError {
     }
 }
 impl Error for ListThingRegistrationTasksError {}
+impl ServiceErrorCode for ListThingRegistrationTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListThingRegistrationTasksError::InternalFailure(_) => "InternalFailureException",
+            ListThingRegistrationTasksError::InvalidRequest(_) => "InvalidRequestException",
+            ListThingRegistrationTasksError::Throttling(_) => "ThrottlingException",
+            ListThingRegistrationTasksError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListThingTypes
 #[derive(Debug, PartialEq)]
 pub enum ListThingTypesError {
@@ -19896,6 +21848,17 @@ impl fmt::Display for ListThingTypesError {
     }
 }
 impl Error for ListThingTypesError {}
+impl ServiceErrorCode for ListThingTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListThingTypesError::InternalFailure(_) => "InternalFailureException",
+            ListThingTypesError::InvalidRequest(_) => "InvalidRequestException",
+            ListThingTypesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListThingTypesError::Throttling(_) => "ThrottlingException",
+            ListThingTypesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListThings
 #[derive(Debug, PartialEq)]
 pub enum ListThingsError {
@@ -19950,6 +21913,17 @@ impl fmt::Display for ListThingsError {
     }
 }
 impl Error for ListThingsError {}
+impl ServiceErrorCode for ListThingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListThingsError::InternalFailure(_) => "InternalFailureException",
+            ListThingsError::InvalidRequest(_) => "InvalidRequestException",
+            ListThingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListThingsError::Throttling(_) => "ThrottlingException",
+            ListThingsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListThingsInBillingGroup
 #[derive(Debug, PartialEq)]
 pub enum ListThingsInBillingGroupError {
@@ -20004,6 +21978,16 @@ impl fmt::Display for ListThingsInBillingGroupError {
     }
 }
 impl Error for ListThingsInBillingGroupError {}
+impl ServiceErrorCode for ListThingsInBillingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListThingsInBillingGroupError::InternalFailure(_) => "InternalFailureException",
+            ListThingsInBillingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            ListThingsInBillingGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListThingsInBillingGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListThingsInThingGroup
 #[derive(Debug, PartialEq)]
 pub enum ListThingsInThingGroupError {
@@ -20058,6 +22042,16 @@ impl fmt::Display for ListThingsInThingGroupError {
     }
 }
 impl Error for ListThingsInThingGroupError {}
+impl ServiceErrorCode for ListThingsInThingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListThingsInThingGroupError::InternalFailure(_) => "InternalFailureException",
+            ListThingsInThingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            ListThingsInThingGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListThingsInThingGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTopicRuleDestinations
 #[derive(Debug, PartialEq)]
 pub enum ListTopicRuleDestinationsError {
@@ -20112,6 +22106,16 @@ impl fmt::Display for ListTopicRuleDestinationsError {
     }
 }
 impl Error for ListTopicRuleDestinationsError {}
+impl ServiceErrorCode for ListTopicRuleDestinationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTopicRuleDestinationsError::Internal(_) => "InternalException",
+            ListTopicRuleDestinationsError::InvalidRequest(_) => "InvalidRequestException",
+            ListTopicRuleDestinationsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListTopicRuleDestinationsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListTopicRules
 #[derive(Debug, PartialEq)]
 pub enum ListTopicRulesError {
@@ -20154,6 +22158,15 @@ impl fmt::Display for ListTopicRulesError {
     }
 }
 impl Error for ListTopicRulesError {}
+impl ServiceErrorCode for ListTopicRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTopicRulesError::Internal(_) => "InternalException",
+            ListTopicRulesError::InvalidRequest(_) => "InvalidRequestException",
+            ListTopicRulesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListV2LoggingLevels
 #[derive(Debug, PartialEq)]
 pub enum ListV2LoggingLevelsError {
@@ -20204,6 +22217,16 @@ impl fmt::Display for ListV2LoggingLevelsError {
     }
 }
 impl Error for ListV2LoggingLevelsError {}
+impl ServiceErrorCode for ListV2LoggingLevelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListV2LoggingLevelsError::Internal(_) => "InternalException",
+            ListV2LoggingLevelsError::InvalidRequest(_) => "InvalidRequestException",
+            ListV2LoggingLevelsError::NotConfigured(_) => "NotConfiguredException",
+            ListV2LoggingLevelsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListViolationEvents
 #[derive(Debug, PartialEq)]
 pub enum ListViolationEventsError {
@@ -20246,6 +22269,15 @@ impl fmt::Display for ListViolationEventsError {
     }
 }
 impl Error for ListViolationEventsError {}
+impl ServiceErrorCode for ListViolationEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListViolationEventsError::InternalFailure(_) => "InternalFailureException",
+            ListViolationEventsError::InvalidRequest(_) => "InvalidRequestException",
+            ListViolationEventsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by RegisterCACertificate
 #[derive(Debug, PartialEq)]
 pub enum RegisterCACertificateError {
@@ -20338,6 +22370,27 @@ impl fmt::Display for RegisterCACertificateError {
     }
 }
 impl Error for RegisterCACertificateError {}
+impl ServiceErrorCode for RegisterCACertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterCACertificateError::CertificateValidation(_) => {
+                "CertificateValidationException"
+            }
+            RegisterCACertificateError::InternalFailure(_) => "InternalFailureException",
+            RegisterCACertificateError::InvalidRequest(_) => "InvalidRequestException",
+            RegisterCACertificateError::LimitExceeded(_) => "LimitExceededException",
+            RegisterCACertificateError::RegistrationCodeValidation(_) => {
+                "RegistrationCodeValidationException"
+            }
+            RegisterCACertificateError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            RegisterCACertificateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RegisterCACertificateError::Throttling(_) => "ThrottlingException",
+            RegisterCACertificateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by RegisterCertificate
 #[derive(Debug, PartialEq)]
 pub enum RegisterCertificateError {
@@ -20426,6 +22479,21 @@ impl fmt::Display for RegisterCertificateError {
     }
 }
 impl Error for RegisterCertificateError {}
+impl ServiceErrorCode for RegisterCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterCertificateError::CertificateConflict(_) => "CertificateConflictException",
+            RegisterCertificateError::CertificateState(_) => "CertificateStateException",
+            RegisterCertificateError::CertificateValidation(_) => "CertificateValidationException",
+            RegisterCertificateError::InternalFailure(_) => "InternalFailureException",
+            RegisterCertificateError::InvalidRequest(_) => "InvalidRequestException",
+            RegisterCertificateError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            RegisterCertificateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RegisterCertificateError::Throttling(_) => "ThrottlingException",
+            RegisterCertificateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by RegisterCertificateWithoutCA
 #[derive(Debug, PartialEq)]
 pub enum RegisterCertificateWithoutCAError {
@@ -20524,6 +22592,26 @@ impl fmt::Display for RegisterCertificateWithoutCAError {
     }
 }
 impl Error for RegisterCertificateWithoutCAError {}
+impl ServiceErrorCode for RegisterCertificateWithoutCAError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterCertificateWithoutCAError::CertificateState(_) => "CertificateStateException",
+            RegisterCertificateWithoutCAError::CertificateValidation(_) => {
+                "CertificateValidationException"
+            }
+            RegisterCertificateWithoutCAError::InternalFailure(_) => "InternalFailureException",
+            RegisterCertificateWithoutCAError::InvalidRequest(_) => "InvalidRequestException",
+            RegisterCertificateWithoutCAError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            RegisterCertificateWithoutCAError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            RegisterCertificateWithoutCAError::Throttling(_) => "ThrottlingException",
+            RegisterCertificateWithoutCAError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by RegisterThing
 #[derive(Debug, PartialEq)]
 pub enum RegisterThingError {
@@ -20594,6 +22682,23 @@ impl fmt::Display for RegisterThingError {
     }
 }
 impl Error for RegisterThingError {}
+impl ServiceErrorCode for RegisterThingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterThingError::ConflictingResourceUpdate(_) => {
+                "ConflictingResourceUpdateException"
+            }
+            RegisterThingError::InternalFailure(_) => "InternalFailureException",
+            RegisterThingError::InvalidRequest(_) => "InvalidRequestException",
+            RegisterThingError::ResourceRegistrationFailure(_) => {
+                "ResourceRegistrationFailureException"
+            }
+            RegisterThingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RegisterThingError::Throttling(_) => "ThrottlingException",
+            RegisterThingError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by RejectCertificateTransfer
 #[derive(Debug, PartialEq)]
 pub enum RejectCertificateTransferError {
@@ -20676,6 +22781,21 @@ impl fmt::Display for RejectCertificateTransferError {
     }
 }
 impl Error for RejectCertificateTransferError {}
+impl ServiceErrorCode for RejectCertificateTransferError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RejectCertificateTransferError::InternalFailure(_) => "InternalFailureException",
+            RejectCertificateTransferError::InvalidRequest(_) => "InvalidRequestException",
+            RejectCertificateTransferError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RejectCertificateTransferError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RejectCertificateTransferError::Throttling(_) => "ThrottlingException",
+            RejectCertificateTransferError::TransferAlreadyCompleted(_) => {
+                "TransferAlreadyCompletedException"
+            }
+            RejectCertificateTransferError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by RemoveThingFromBillingGroup
 #[derive(Debug, PartialEq)]
 pub enum RemoveThingFromBillingGroupError {
@@ -20734,6 +22854,16 @@ impl fmt::Display for RemoveThingFromBillingGroupError {
     }
 }
 impl Error for RemoveThingFromBillingGroupError {}
+impl ServiceErrorCode for RemoveThingFromBillingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveThingFromBillingGroupError::InternalFailure(_) => "InternalFailureException",
+            RemoveThingFromBillingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            RemoveThingFromBillingGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RemoveThingFromBillingGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by RemoveThingFromThingGroup
 #[derive(Debug, PartialEq)]
 pub enum RemoveThingFromThingGroupError {
@@ -20790,6 +22920,16 @@ impl fmt::Display for RemoveThingFromThingGroupError {
     }
 }
 impl Error for RemoveThingFromThingGroupError {}
+impl ServiceErrorCode for RemoveThingFromThingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveThingFromThingGroupError::InternalFailure(_) => "InternalFailureException",
+            RemoveThingFromThingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            RemoveThingFromThingGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RemoveThingFromThingGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ReplaceTopicRule
 #[derive(Debug, PartialEq)]
 pub enum ReplaceTopicRuleError {
@@ -20852,6 +22992,20 @@ impl fmt::Display for ReplaceTopicRuleError {
     }
 }
 impl Error for ReplaceTopicRuleError {}
+impl ServiceErrorCode for ReplaceTopicRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ReplaceTopicRuleError::ConflictingResourceUpdate(_) => {
+                "ConflictingResourceUpdateException"
+            }
+            ReplaceTopicRuleError::Internal(_) => "InternalException",
+            ReplaceTopicRuleError::InvalidRequest(_) => "InvalidRequestException",
+            ReplaceTopicRuleError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ReplaceTopicRuleError::SqlParse(_) => "SqlParseException",
+            ReplaceTopicRuleError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by SearchIndex
 #[derive(Debug, PartialEq)]
 pub enum SearchIndexError {
@@ -20924,6 +23078,20 @@ impl fmt::Display for SearchIndexError {
     }
 }
 impl Error for SearchIndexError {}
+impl ServiceErrorCode for SearchIndexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchIndexError::IndexNotReady(_) => "IndexNotReadyException",
+            SearchIndexError::InternalFailure(_) => "InternalFailureException",
+            SearchIndexError::InvalidQuery(_) => "InvalidQueryException",
+            SearchIndexError::InvalidRequest(_) => "InvalidRequestException",
+            SearchIndexError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SearchIndexError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            SearchIndexError::Throttling(_) => "ThrottlingException",
+            SearchIndexError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by SetDefaultAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum SetDefaultAuthorizerError {
@@ -20998,6 +23166,19 @@ impl fmt::Display for SetDefaultAuthorizerError {
     }
 }
 impl Error for SetDefaultAuthorizerError {}
+impl ServiceErrorCode for SetDefaultAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetDefaultAuthorizerError::InternalFailure(_) => "InternalFailureException",
+            SetDefaultAuthorizerError::InvalidRequest(_) => "InvalidRequestException",
+            SetDefaultAuthorizerError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            SetDefaultAuthorizerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SetDefaultAuthorizerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            SetDefaultAuthorizerError::Throttling(_) => "ThrottlingException",
+            SetDefaultAuthorizerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by SetDefaultPolicyVersion
 #[derive(Debug, PartialEq)]
 pub enum SetDefaultPolicyVersionError {
@@ -21068,6 +23249,18 @@ impl fmt::Display for SetDefaultPolicyVersionError {
     }
 }
 impl Error for SetDefaultPolicyVersionError {}
+impl ServiceErrorCode for SetDefaultPolicyVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetDefaultPolicyVersionError::InternalFailure(_) => "InternalFailureException",
+            SetDefaultPolicyVersionError::InvalidRequest(_) => "InvalidRequestException",
+            SetDefaultPolicyVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SetDefaultPolicyVersionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            SetDefaultPolicyVersionError::Throttling(_) => "ThrottlingException",
+            SetDefaultPolicyVersionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by SetLoggingOptions
 #[derive(Debug, PartialEq)]
 pub enum SetLoggingOptionsError {
@@ -21112,6 +23305,15 @@ impl fmt::Display for SetLoggingOptionsError {
     }
 }
 impl Error for SetLoggingOptionsError {}
+impl ServiceErrorCode for SetLoggingOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetLoggingOptionsError::Internal(_) => "InternalException",
+            SetLoggingOptionsError::InvalidRequest(_) => "InvalidRequestException",
+            SetLoggingOptionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by SetV2LoggingLevel
 #[derive(Debug, PartialEq)]
 pub enum SetV2LoggingLevelError {
@@ -21168,6 +23370,17 @@ impl fmt::Display for SetV2LoggingLevelError {
     }
 }
 impl Error for SetV2LoggingLevelError {}
+impl ServiceErrorCode for SetV2LoggingLevelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetV2LoggingLevelError::Internal(_) => "InternalException",
+            SetV2LoggingLevelError::InvalidRequest(_) => "InvalidRequestException",
+            SetV2LoggingLevelError::LimitExceeded(_) => "LimitExceededException",
+            SetV2LoggingLevelError::NotConfigured(_) => "NotConfiguredException",
+            SetV2LoggingLevelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by SetV2LoggingOptions
 #[derive(Debug, PartialEq)]
 pub enum SetV2LoggingOptionsError {
@@ -21212,6 +23425,15 @@ impl fmt::Display for SetV2LoggingOptionsError {
     }
 }
 impl Error for SetV2LoggingOptionsError {}
+impl ServiceErrorCode for SetV2LoggingOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetV2LoggingOptionsError::Internal(_) => "InternalException",
+            SetV2LoggingOptionsError::InvalidRequest(_) => "InvalidRequestException",
+            SetV2LoggingOptionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by StartAuditMitigationActionsTask
 #[derive(Debug, PartialEq)]
 pub enum StartAuditMitigationActionsTaskError {
@@ -21286,6 +23508,19 @@ impl fmt::Display for StartAuditMitigationActionsTaskError {
     }
 }
 impl Error for StartAuditMitigationActionsTaskError {}
+impl ServiceErrorCode for StartAuditMitigationActionsTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartAuditMitigationActionsTaskError::InternalFailure(_) => "InternalFailureException",
+            StartAuditMitigationActionsTaskError::InvalidRequest(_) => "InvalidRequestException",
+            StartAuditMitigationActionsTaskError::LimitExceeded(_) => "LimitExceededException",
+            StartAuditMitigationActionsTaskError::TaskAlreadyExists(_) => {
+                "TaskAlreadyExistsException"
+            }
+            StartAuditMitigationActionsTaskError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StartDetectMitigationActionsTask
 #[derive(Debug, PartialEq)]
 pub enum StartDetectMitigationActionsTaskError {
@@ -21360,6 +23595,19 @@ impl fmt::Display for StartDetectMitigationActionsTaskError {
     }
 }
 impl Error for StartDetectMitigationActionsTaskError {}
+impl ServiceErrorCode for StartDetectMitigationActionsTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDetectMitigationActionsTaskError::InternalFailure(_) => "InternalFailureException",
+            StartDetectMitigationActionsTaskError::InvalidRequest(_) => "InvalidRequestException",
+            StartDetectMitigationActionsTaskError::LimitExceeded(_) => "LimitExceededException",
+            StartDetectMitigationActionsTaskError::TaskAlreadyExists(_) => {
+                "TaskAlreadyExistsException"
+            }
+            StartDetectMitigationActionsTaskError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StartOnDemandAuditTask
 #[derive(Debug, PartialEq)]
 pub enum StartOnDemandAuditTaskError {
@@ -21414,6 +23662,16 @@ impl fmt::Display for StartOnDemandAuditTaskError {
     }
 }
 impl Error for StartOnDemandAuditTaskError {}
+impl ServiceErrorCode for StartOnDemandAuditTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartOnDemandAuditTaskError::InternalFailure(_) => "InternalFailureException",
+            StartOnDemandAuditTaskError::InvalidRequest(_) => "InvalidRequestException",
+            StartOnDemandAuditTaskError::LimitExceeded(_) => "LimitExceededException",
+            StartOnDemandAuditTaskError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StartThingRegistrationTask
 #[derive(Debug, PartialEq)]
 pub enum StartThingRegistrationTaskError {
@@ -21472,6 +23730,16 @@ impl fmt::Display for StartThingRegistrationTaskError {
     }
 }
 impl Error for StartThingRegistrationTaskError {}
+impl ServiceErrorCode for StartThingRegistrationTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartThingRegistrationTaskError::InternalFailure(_) => "InternalFailureException",
+            StartThingRegistrationTaskError::InvalidRequest(_) => "InvalidRequestException",
+            StartThingRegistrationTaskError::Throttling(_) => "ThrottlingException",
+            StartThingRegistrationTaskError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by StopThingRegistrationTask
 #[derive(Debug, PartialEq)]
 pub enum StopThingRegistrationTaskError {
@@ -21536,6 +23804,17 @@ impl fmt::Display for StopThingRegistrationTaskError {
     }
 }
 impl Error for StopThingRegistrationTaskError {}
+impl ServiceErrorCode for StopThingRegistrationTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopThingRegistrationTaskError::InternalFailure(_) => "InternalFailureException",
+            StopThingRegistrationTaskError::InvalidRequest(_) => "InvalidRequestException",
+            StopThingRegistrationTaskError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StopThingRegistrationTaskError::Throttling(_) => "ThrottlingException",
+            StopThingRegistrationTaskError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -21590,6 +23869,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalFailure(_) => "InternalFailureException",
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by TestAuthorization
 #[derive(Debug, PartialEq)]
 pub enum TestAuthorizationError {
@@ -21658,6 +23948,19 @@ impl fmt::Display for TestAuthorizationError {
     }
 }
 impl Error for TestAuthorizationError {}
+impl ServiceErrorCode for TestAuthorizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TestAuthorizationError::InternalFailure(_) => "InternalFailureException",
+            TestAuthorizationError::InvalidRequest(_) => "InvalidRequestException",
+            TestAuthorizationError::LimitExceeded(_) => "LimitExceededException",
+            TestAuthorizationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TestAuthorizationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            TestAuthorizationError::Throttling(_) => "ThrottlingException",
+            TestAuthorizationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by TestInvokeAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum TestInvokeAuthorizerError {
@@ -21732,6 +24035,19 @@ impl fmt::Display for TestInvokeAuthorizerError {
     }
 }
 impl Error for TestInvokeAuthorizerError {}
+impl ServiceErrorCode for TestInvokeAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TestInvokeAuthorizerError::InternalFailure(_) => "InternalFailureException",
+            TestInvokeAuthorizerError::InvalidRequest(_) => "InvalidRequestException",
+            TestInvokeAuthorizerError::InvalidResponse(_) => "InvalidResponseException",
+            TestInvokeAuthorizerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TestInvokeAuthorizerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            TestInvokeAuthorizerError::Throttling(_) => "ThrottlingException",
+            TestInvokeAuthorizerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by TransferCertificate
 #[derive(Debug, PartialEq)]
 pub enum TransferCertificateError {
@@ -21812,6 +24128,20 @@ impl fmt::Display for TransferCertificateError {
     }
 }
 impl Error for TransferCertificateError {}
+impl ServiceErrorCode for TransferCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TransferCertificateError::CertificateState(_) => "CertificateStateException",
+            TransferCertificateError::InternalFailure(_) => "InternalFailureException",
+            TransferCertificateError::InvalidRequest(_) => "InvalidRequestException",
+            TransferCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TransferCertificateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            TransferCertificateError::Throttling(_) => "ThrottlingException",
+            TransferCertificateError::TransferConflict(_) => "TransferConflictException",
+            TransferCertificateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -21860,6 +24190,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalFailure(_) => "InternalFailureException",
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateAccountAuditConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateAccountAuditConfigurationError {
@@ -21914,6 +24254,15 @@ impl fmt::Display for UpdateAccountAuditConfigurationError {
     }
 }
 impl Error for UpdateAccountAuditConfigurationError {}
+impl ServiceErrorCode for UpdateAccountAuditConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAccountAuditConfigurationError::InternalFailure(_) => "InternalFailureException",
+            UpdateAccountAuditConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateAccountAuditConfigurationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateAuditSuppression
 #[derive(Debug, PartialEq)]
 pub enum UpdateAuditSuppressionError {
@@ -21968,6 +24317,16 @@ impl fmt::Display for UpdateAuditSuppressionError {
     }
 }
 impl Error for UpdateAuditSuppressionError {}
+impl ServiceErrorCode for UpdateAuditSuppressionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAuditSuppressionError::InternalFailure(_) => "InternalFailureException",
+            UpdateAuditSuppressionError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateAuditSuppressionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateAuditSuppressionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateAuthorizer
 #[derive(Debug, PartialEq)]
 pub enum UpdateAuthorizerError {
@@ -22034,6 +24393,19 @@ impl fmt::Display for UpdateAuthorizerError {
     }
 }
 impl Error for UpdateAuthorizerError {}
+impl ServiceErrorCode for UpdateAuthorizerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAuthorizerError::InternalFailure(_) => "InternalFailureException",
+            UpdateAuthorizerError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateAuthorizerError::LimitExceeded(_) => "LimitExceededException",
+            UpdateAuthorizerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateAuthorizerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateAuthorizerError::Throttling(_) => "ThrottlingException",
+            UpdateAuthorizerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateBillingGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateBillingGroupError {
@@ -22088,6 +24460,17 @@ impl fmt::Display for UpdateBillingGroupError {
     }
 }
 impl Error for UpdateBillingGroupError {}
+impl ServiceErrorCode for UpdateBillingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateBillingGroupError::InternalFailure(_) => "InternalFailureException",
+            UpdateBillingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateBillingGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateBillingGroupError::Throttling(_) => "ThrottlingException",
+            UpdateBillingGroupError::VersionConflict(_) => "VersionConflictException",
+        }
+    }
+}
 /// Errors returned by UpdateCACertificate
 #[derive(Debug, PartialEq)]
 pub enum UpdateCACertificateError {
@@ -22152,6 +24535,18 @@ impl fmt::Display for UpdateCACertificateError {
     }
 }
 impl Error for UpdateCACertificateError {}
+impl ServiceErrorCode for UpdateCACertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCACertificateError::InternalFailure(_) => "InternalFailureException",
+            UpdateCACertificateError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateCACertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateCACertificateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateCACertificateError::Throttling(_) => "ThrottlingException",
+            UpdateCACertificateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateCertificate
 #[derive(Debug, PartialEq)]
 pub enum UpdateCertificateError {
@@ -22220,6 +24615,19 @@ impl fmt::Display for UpdateCertificateError {
     }
 }
 impl Error for UpdateCertificateError {}
+impl ServiceErrorCode for UpdateCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCertificateError::CertificateState(_) => "CertificateStateException",
+            UpdateCertificateError::InternalFailure(_) => "InternalFailureException",
+            UpdateCertificateError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateCertificateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateCertificateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateCertificateError::Throttling(_) => "ThrottlingException",
+            UpdateCertificateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateCustomMetric
 #[derive(Debug, PartialEq)]
 pub enum UpdateCustomMetricError {
@@ -22268,6 +24676,16 @@ impl fmt::Display for UpdateCustomMetricError {
     }
 }
 impl Error for UpdateCustomMetricError {}
+impl ServiceErrorCode for UpdateCustomMetricError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCustomMetricError::InternalFailure(_) => "InternalFailureException",
+            UpdateCustomMetricError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateCustomMetricError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateCustomMetricError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateDimension
 #[derive(Debug, PartialEq)]
 pub enum UpdateDimensionError {
@@ -22316,6 +24734,16 @@ impl fmt::Display for UpdateDimensionError {
     }
 }
 impl Error for UpdateDimensionError {}
+impl ServiceErrorCode for UpdateDimensionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDimensionError::InternalFailure(_) => "InternalFailureException",
+            UpdateDimensionError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateDimensionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDimensionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateDomainConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateDomainConfigurationError {
@@ -22398,6 +24826,21 @@ impl fmt::Display for UpdateDomainConfigurationError {
     }
 }
 impl Error for UpdateDomainConfigurationError {}
+impl ServiceErrorCode for UpdateDomainConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDomainConfigurationError::CertificateValidation(_) => {
+                "CertificateValidationException"
+            }
+            UpdateDomainConfigurationError::InternalFailure(_) => "InternalFailureException",
+            UpdateDomainConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateDomainConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDomainConfigurationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateDomainConfigurationError::Throttling(_) => "ThrottlingException",
+            UpdateDomainConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateDynamicThingGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateDynamicThingGroupError {
@@ -22468,6 +24911,18 @@ impl fmt::Display for UpdateDynamicThingGroupError {
     }
 }
 impl Error for UpdateDynamicThingGroupError {}
+impl ServiceErrorCode for UpdateDynamicThingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDynamicThingGroupError::InternalFailure(_) => "InternalFailureException",
+            UpdateDynamicThingGroupError::InvalidQuery(_) => "InvalidQueryException",
+            UpdateDynamicThingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateDynamicThingGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDynamicThingGroupError::Throttling(_) => "ThrottlingException",
+            UpdateDynamicThingGroupError::VersionConflict(_) => "VersionConflictException",
+        }
+    }
+}
 /// Errors returned by UpdateEventConfigurations
 #[derive(Debug, PartialEq)]
 pub enum UpdateEventConfigurationsError {
@@ -22516,6 +24971,15 @@ impl fmt::Display for UpdateEventConfigurationsError {
     }
 }
 impl Error for UpdateEventConfigurationsError {}
+impl ServiceErrorCode for UpdateEventConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateEventConfigurationsError::InternalFailure(_) => "InternalFailureException",
+            UpdateEventConfigurationsError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateEventConfigurationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateIndexingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateIndexingConfigurationError {
@@ -22584,6 +25048,19 @@ impl fmt::Display for UpdateIndexingConfigurationError {
     }
 }
 impl Error for UpdateIndexingConfigurationError {}
+impl ServiceErrorCode for UpdateIndexingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateIndexingConfigurationError::InternalFailure(_) => "InternalFailureException",
+            UpdateIndexingConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateIndexingConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            UpdateIndexingConfigurationError::Throttling(_) => "ThrottlingException",
+            UpdateIndexingConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateJob
 #[derive(Debug, PartialEq)]
 pub enum UpdateJobError {
@@ -22632,6 +25109,16 @@ impl fmt::Display for UpdateJobError {
     }
 }
 impl Error for UpdateJobError {}
+impl ServiceErrorCode for UpdateJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateJobError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateMitigationAction
 #[derive(Debug, PartialEq)]
 pub enum UpdateMitigationActionError {
@@ -22686,6 +25173,16 @@ impl fmt::Display for UpdateMitigationActionError {
     }
 }
 impl Error for UpdateMitigationActionError {}
+impl ServiceErrorCode for UpdateMitigationActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMitigationActionError::InternalFailure(_) => "InternalFailureException",
+            UpdateMitigationActionError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateMitigationActionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateMitigationActionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateProvisioningTemplate
 #[derive(Debug, PartialEq)]
 pub enum UpdateProvisioningTemplateError {
@@ -22754,6 +25251,19 @@ impl fmt::Display for UpdateProvisioningTemplateError {
     }
 }
 impl Error for UpdateProvisioningTemplateError {}
+impl ServiceErrorCode for UpdateProvisioningTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateProvisioningTemplateError::ConflictingResourceUpdate(_) => {
+                "ConflictingResourceUpdateException"
+            }
+            UpdateProvisioningTemplateError::InternalFailure(_) => "InternalFailureException",
+            UpdateProvisioningTemplateError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateProvisioningTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateProvisioningTemplateError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateRoleAlias
 #[derive(Debug, PartialEq)]
 pub enum UpdateRoleAliasError {
@@ -22814,6 +25324,18 @@ impl fmt::Display for UpdateRoleAliasError {
     }
 }
 impl Error for UpdateRoleAliasError {}
+impl ServiceErrorCode for UpdateRoleAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRoleAliasError::InternalFailure(_) => "InternalFailureException",
+            UpdateRoleAliasError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateRoleAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateRoleAliasError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateRoleAliasError::Throttling(_) => "ThrottlingException",
+            UpdateRoleAliasError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateScheduledAudit
 #[derive(Debug, PartialEq)]
 pub enum UpdateScheduledAuditError {
@@ -22866,6 +25388,16 @@ impl fmt::Display for UpdateScheduledAuditError {
     }
 }
 impl Error for UpdateScheduledAuditError {}
+impl ServiceErrorCode for UpdateScheduledAuditError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateScheduledAuditError::InternalFailure(_) => "InternalFailureException",
+            UpdateScheduledAuditError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateScheduledAuditError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateScheduledAuditError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateSecurityProfile
 #[derive(Debug, PartialEq)]
 pub enum UpdateSecurityProfileError {
@@ -22928,6 +25460,17 @@ impl fmt::Display for UpdateSecurityProfileError {
     }
 }
 impl Error for UpdateSecurityProfileError {}
+impl ServiceErrorCode for UpdateSecurityProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSecurityProfileError::InternalFailure(_) => "InternalFailureException",
+            UpdateSecurityProfileError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateSecurityProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateSecurityProfileError::Throttling(_) => "ThrottlingException",
+            UpdateSecurityProfileError::VersionConflict(_) => "VersionConflictException",
+        }
+    }
+}
 /// Errors returned by UpdateStream
 #[derive(Debug, PartialEq)]
 pub enum UpdateStreamError {
@@ -22988,6 +25531,18 @@ impl fmt::Display for UpdateStreamError {
     }
 }
 impl Error for UpdateStreamError {}
+impl ServiceErrorCode for UpdateStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateStreamError::InternalFailure(_) => "InternalFailureException",
+            UpdateStreamError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateStreamError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateStreamError::Throttling(_) => "ThrottlingException",
+            UpdateStreamError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateThing
 #[derive(Debug, PartialEq)]
 pub enum UpdateThingError {
@@ -23054,6 +25609,19 @@ impl fmt::Display for UpdateThingError {
     }
 }
 impl Error for UpdateThingError {}
+impl ServiceErrorCode for UpdateThingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateThingError::InternalFailure(_) => "InternalFailureException",
+            UpdateThingError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateThingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateThingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateThingError::Throttling(_) => "ThrottlingException",
+            UpdateThingError::Unauthorized(_) => "UnauthorizedException",
+            UpdateThingError::VersionConflict(_) => "VersionConflictException",
+        }
+    }
+}
 /// Errors returned by UpdateThingGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateThingGroupError {
@@ -23108,6 +25676,17 @@ impl fmt::Display for UpdateThingGroupError {
     }
 }
 impl Error for UpdateThingGroupError {}
+impl ServiceErrorCode for UpdateThingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateThingGroupError::InternalFailure(_) => "InternalFailureException",
+            UpdateThingGroupError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateThingGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateThingGroupError::Throttling(_) => "ThrottlingException",
+            UpdateThingGroupError::VersionConflict(_) => "VersionConflictException",
+        }
+    }
+}
 /// Errors returned by UpdateThingGroupsForThing
 #[derive(Debug, PartialEq)]
 pub enum UpdateThingGroupsForThingError {
@@ -23164,6 +25743,16 @@ impl fmt::Display for UpdateThingGroupsForThingError {
     }
 }
 impl Error for UpdateThingGroupsForThingError {}
+impl ServiceErrorCode for UpdateThingGroupsForThingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateThingGroupsForThingError::InternalFailure(_) => "InternalFailureException",
+            UpdateThingGroupsForThingError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateThingGroupsForThingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateThingGroupsForThingError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateTopicRuleDestination
 #[derive(Debug, PartialEq)]
 pub enum UpdateTopicRuleDestinationError {
@@ -23232,6 +25821,19 @@ impl fmt::Display for UpdateTopicRuleDestinationError {
     }
 }
 impl Error for UpdateTopicRuleDestinationError {}
+impl ServiceErrorCode for UpdateTopicRuleDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTopicRuleDestinationError::ConflictingResourceUpdate(_) => {
+                "ConflictingResourceUpdateException"
+            }
+            UpdateTopicRuleDestinationError::Internal(_) => "InternalException",
+            UpdateTopicRuleDestinationError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateTopicRuleDestinationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateTopicRuleDestinationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ValidateSecurityProfileBehaviors
 #[derive(Debug, PartialEq)]
 pub enum ValidateSecurityProfileBehaviorsError {
@@ -23286,6 +25888,15 @@ impl fmt::Display for ValidateSecurityProfileBehaviorsError {
     }
 }
 impl Error for ValidateSecurityProfileBehaviorsError {}
+impl ServiceErrorCode for ValidateSecurityProfileBehaviorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ValidateSecurityProfileBehaviorsError::InternalFailure(_) => "InternalFailureException",
+            ValidateSecurityProfileBehaviorsError::InvalidRequest(_) => "InvalidRequestException",
+            ValidateSecurityProfileBehaviorsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS IoT API. AWS IoT clients implement this trait.
 #[async_trait]
 pub trait Iot {
@@ -25290,6 +27901,12 @@ impl Iot for IotClient {
         &self,
         input: CreateAuditSuppressionRequest,
     ) -> Result<CreateAuditSuppressionResponse, RusotoError<CreateAuditSuppressionError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = "/audit/suppressions/create";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
@@ -25430,6 +28047,12 @@ impl Iot for IotClient {
         &self,
         input: CreateCustomMetricRequest,
     ) -> Result<CreateCustomMetricResponse, RusotoError<CreateCustomMetricError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = format!(
             "/custom-metric/{metric_name}",
             metric_name = input.metric_name
@@ -25465,6 +28088,12 @@ impl Iot for IotClient {
         &self,
         input: CreateDimensionRequest,
     ) -> Result<CreateDimensionResponse, RusotoError<CreateDimensionError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = format!("/dimensions/{name}", name = input.name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
@@ -31636,6 +34265,12 @@ impl Iot for IotClient {
         StartAuditMitigationActionsTaskResponse,
         RusotoError<StartAuditMitigationActionsTaskError>,
     > {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = format!(
             "/audit/mitigationactions/tasks/{task_id}",
             task_id = input.task_id
@@ -31676,6 +34311,12 @@ impl Iot for IotClient {
         StartDetectMitigationActionsTaskResponse,
         RusotoError<StartDetectMitigationActionsTaskError>,
     > {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = format!(
             "/detect/mitigationactions/tasks/{task_id}",
             task_id = input.task_id
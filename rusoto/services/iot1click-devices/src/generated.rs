@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -427,6 +427,15 @@ impl fmt::Display for ClaimDevicesByClaimCodeError {
     }
 }
 impl Error for ClaimDevicesByClaimCodeError {}
+impl ServiceErrorCode for ClaimDevicesByClaimCodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ClaimDevicesByClaimCodeError::Forbidden(_) => "ForbiddenException",
+            ClaimDevicesByClaimCodeError::InternalFailure(_) => "InternalFailureException",
+            ClaimDevicesByClaimCodeError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeDevice
 #[derive(Debug, PartialEq)]
 pub enum DescribeDeviceError {
@@ -468,6 +477,15 @@ impl fmt::Display for DescribeDeviceError {
     }
 }
 impl Error for DescribeDeviceError {}
+impl ServiceErrorCode for DescribeDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDeviceError::InternalFailure(_) => "InternalFailureException",
+            DescribeDeviceError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeDeviceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by FinalizeDeviceClaim
 #[derive(Debug, PartialEq)]
 pub enum FinalizeDeviceClaimError {
@@ -527,6 +545,17 @@ impl fmt::Display for FinalizeDeviceClaimError {
     }
 }
 impl Error for FinalizeDeviceClaimError {}
+impl ServiceErrorCode for FinalizeDeviceClaimError {
+    fn error_code(&self) -> &str {
+        match *self {
+            FinalizeDeviceClaimError::InternalFailure(_) => "InternalFailureException",
+            FinalizeDeviceClaimError::InvalidRequest(_) => "InvalidRequestException",
+            FinalizeDeviceClaimError::PreconditionFailed(_) => "PreconditionFailedException",
+            FinalizeDeviceClaimError::ResourceConflict(_) => "ResourceConflictException",
+            FinalizeDeviceClaimError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetDeviceMethods
 #[derive(Debug, PartialEq)]
 pub enum GetDeviceMethodsError {
@@ -568,6 +597,15 @@ impl fmt::Display for GetDeviceMethodsError {
     }
 }
 impl Error for GetDeviceMethodsError {}
+impl ServiceErrorCode for GetDeviceMethodsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeviceMethodsError::InternalFailure(_) => "InternalFailureException",
+            GetDeviceMethodsError::InvalidRequest(_) => "InvalidRequestException",
+            GetDeviceMethodsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by InitiateDeviceClaim
 #[derive(Debug, PartialEq)]
 pub enum InitiateDeviceClaimError {
@@ -619,6 +657,16 @@ impl fmt::Display for InitiateDeviceClaimError {
     }
 }
 impl Error for InitiateDeviceClaimError {}
+impl ServiceErrorCode for InitiateDeviceClaimError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InitiateDeviceClaimError::InternalFailure(_) => "InternalFailureException",
+            InitiateDeviceClaimError::InvalidRequest(_) => "InvalidRequestException",
+            InitiateDeviceClaimError::ResourceConflict(_) => "ResourceConflictException",
+            InitiateDeviceClaimError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by InvokeDeviceMethod
 #[derive(Debug, PartialEq)]
 pub enum InvokeDeviceMethodError {
@@ -682,6 +730,18 @@ impl fmt::Display for InvokeDeviceMethodError {
     }
 }
 impl Error for InvokeDeviceMethodError {}
+impl ServiceErrorCode for InvokeDeviceMethodError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InvokeDeviceMethodError::InternalFailure(_) => "InternalFailureException",
+            InvokeDeviceMethodError::InvalidRequest(_) => "InvalidRequestException",
+            InvokeDeviceMethodError::PreconditionFailed(_) => "PreconditionFailedException",
+            InvokeDeviceMethodError::RangeNotSatisfiable(_) => "RangeNotSatisfiableException",
+            InvokeDeviceMethodError::ResourceConflict(_) => "ResourceConflictException",
+            InvokeDeviceMethodError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListDeviceEvents
 #[derive(Debug, PartialEq)]
 pub enum ListDeviceEventsError {
@@ -731,6 +791,16 @@ impl fmt::Display for ListDeviceEventsError {
     }
 }
 impl Error for ListDeviceEventsError {}
+impl ServiceErrorCode for ListDeviceEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeviceEventsError::InternalFailure(_) => "InternalFailureException",
+            ListDeviceEventsError::InvalidRequest(_) => "InvalidRequestException",
+            ListDeviceEventsError::RangeNotSatisfiable(_) => "RangeNotSatisfiableException",
+            ListDeviceEventsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListDevices
 #[derive(Debug, PartialEq)]
 pub enum ListDevicesError {
@@ -772,6 +842,15 @@ impl fmt::Display for ListDevicesError {
     }
 }
 impl Error for ListDevicesError {}
+impl ServiceErrorCode for ListDevicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDevicesError::InternalFailure(_) => "InternalFailureException",
+            ListDevicesError::InvalidRequest(_) => "InvalidRequestException",
+            ListDevicesError::RangeNotSatisfiable(_) => "RangeNotSatisfiableException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -809,6 +888,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalFailure(_) => "InternalFailureException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -850,6 +937,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalFailure(_) => "InternalFailureException",
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UnclaimDevice
 #[derive(Debug, PartialEq)]
 pub enum UnclaimDeviceError {
@@ -891,6 +987,15 @@ impl fmt::Display for UnclaimDeviceError {
     }
 }
 impl Error for UnclaimDeviceError {}
+impl ServiceErrorCode for UnclaimDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UnclaimDeviceError::InternalFailure(_) => "InternalFailureException",
+            UnclaimDeviceError::InvalidRequest(_) => "InvalidRequestException",
+            UnclaimDeviceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -932,6 +1037,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalFailure(_) => "InternalFailureException",
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateDeviceState
 #[derive(Debug, PartialEq)]
 pub enum UpdateDeviceStateError {
@@ -973,6 +1087,15 @@ impl fmt::Display for UpdateDeviceStateError {
     }
 }
 impl Error for UpdateDeviceStateError {}
+impl ServiceErrorCode for UpdateDeviceStateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDeviceStateError::InternalFailure(_) => "InternalFailureException",
+            UpdateDeviceStateError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateDeviceStateError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS IoT 1-Click Devices Service API. AWS IoT 1-Click Devices Service clients implement this trait.
 #[async_trait]
 pub trait Iot1ClickDevices {
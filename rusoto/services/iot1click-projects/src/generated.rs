@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -508,6 +508,16 @@ impl fmt::Display for AssociateDeviceWithPlacementError {
     }
 }
 impl Error for AssociateDeviceWithPlacementError {}
+impl ServiceErrorCode for AssociateDeviceWithPlacementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateDeviceWithPlacementError::InternalFailure(_) => "InternalFailureException",
+            AssociateDeviceWithPlacementError::InvalidRequest(_) => "InvalidRequestException",
+            AssociateDeviceWithPlacementError::ResourceConflict(_) => "ResourceConflictException",
+            AssociateDeviceWithPlacementError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreatePlacement
 #[derive(Debug, PartialEq)]
 pub enum CreatePlacementError {
@@ -556,6 +566,16 @@ impl fmt::Display for CreatePlacementError {
     }
 }
 impl Error for CreatePlacementError {}
+impl ServiceErrorCode for CreatePlacementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePlacementError::InternalFailure(_) => "InternalFailureException",
+            CreatePlacementError::InvalidRequest(_) => "InvalidRequestException",
+            CreatePlacementError::ResourceConflict(_) => "ResourceConflictException",
+            CreatePlacementError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateProject
 #[derive(Debug, PartialEq)]
 pub enum CreateProjectError {
@@ -598,6 +618,15 @@ impl fmt::Display for CreateProjectError {
     }
 }
 impl Error for CreateProjectError {}
+impl ServiceErrorCode for CreateProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProjectError::InternalFailure(_) => "InternalFailureException",
+            CreateProjectError::InvalidRequest(_) => "InvalidRequestException",
+            CreateProjectError::ResourceConflict(_) => "ResourceConflictException",
+        }
+    }
+}
 /// Errors returned by DeletePlacement
 #[derive(Debug, PartialEq)]
 pub enum DeletePlacementError {
@@ -646,6 +675,16 @@ impl fmt::Display for DeletePlacementError {
     }
 }
 impl Error for DeletePlacementError {}
+impl ServiceErrorCode for DeletePlacementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePlacementError::InternalFailure(_) => "InternalFailureException",
+            DeletePlacementError::InvalidRequest(_) => "InvalidRequestException",
+            DeletePlacementError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeletePlacementError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteProject
 #[derive(Debug, PartialEq)]
 pub enum DeleteProjectError {
@@ -694,6 +733,16 @@ impl fmt::Display for DeleteProjectError {
     }
 }
 impl Error for DeleteProjectError {}
+impl ServiceErrorCode for DeleteProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProjectError::InternalFailure(_) => "InternalFailureException",
+            DeleteProjectError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteProjectError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteProjectError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribePlacement
 #[derive(Debug, PartialEq)]
 pub enum DescribePlacementError {
@@ -736,6 +785,15 @@ impl fmt::Display for DescribePlacementError {
     }
 }
 impl Error for DescribePlacementError {}
+impl ServiceErrorCode for DescribePlacementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePlacementError::InternalFailure(_) => "InternalFailureException",
+            DescribePlacementError::InvalidRequest(_) => "InvalidRequestException",
+            DescribePlacementError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeProject
 #[derive(Debug, PartialEq)]
 pub enum DescribeProjectError {
@@ -778,6 +836,15 @@ impl fmt::Display for DescribeProjectError {
     }
 }
 impl Error for DescribeProjectError {}
+impl ServiceErrorCode for DescribeProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProjectError::InternalFailure(_) => "InternalFailureException",
+            DescribeProjectError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeProjectError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateDeviceFromPlacement
 #[derive(Debug, PartialEq)]
 pub enum DisassociateDeviceFromPlacementError {
@@ -844,6 +911,18 @@ impl fmt::Display for DisassociateDeviceFromPlacementError {
     }
 }
 impl Error for DisassociateDeviceFromPlacementError {}
+impl ServiceErrorCode for DisassociateDeviceFromPlacementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateDeviceFromPlacementError::InternalFailure(_) => "InternalFailureException",
+            DisassociateDeviceFromPlacementError::InvalidRequest(_) => "InvalidRequestException",
+            DisassociateDeviceFromPlacementError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DisassociateDeviceFromPlacementError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetDevicesInPlacement
 #[derive(Debug, PartialEq)]
 pub enum GetDevicesInPlacementError {
@@ -892,6 +971,15 @@ impl fmt::Display for GetDevicesInPlacementError {
     }
 }
 impl Error for GetDevicesInPlacementError {}
+impl ServiceErrorCode for GetDevicesInPlacementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDevicesInPlacementError::InternalFailure(_) => "InternalFailureException",
+            GetDevicesInPlacementError::InvalidRequest(_) => "InvalidRequestException",
+            GetDevicesInPlacementError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListPlacements
 #[derive(Debug, PartialEq)]
 pub enum ListPlacementsError {
@@ -934,6 +1022,15 @@ impl fmt::Display for ListPlacementsError {
     }
 }
 impl Error for ListPlacementsError {}
+impl ServiceErrorCode for ListPlacementsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPlacementsError::InternalFailure(_) => "InternalFailureException",
+            ListPlacementsError::InvalidRequest(_) => "InvalidRequestException",
+            ListPlacementsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListProjects
 #[derive(Debug, PartialEq)]
 pub enum ListProjectsError {
@@ -970,6 +1067,14 @@ impl fmt::Display for ListProjectsError {
     }
 }
 impl Error for ListProjectsError {}
+impl ServiceErrorCode for ListProjectsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProjectsError::InternalFailure(_) => "InternalFailureException",
+            ListProjectsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1014,6 +1119,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalFailure(_) => "InternalFailureException",
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1056,6 +1170,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalFailure(_) => "InternalFailureException",
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1098,6 +1221,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalFailure(_) => "InternalFailureException",
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdatePlacement
 #[derive(Debug, PartialEq)]
 pub enum UpdatePlacementError {
@@ -1146,6 +1278,16 @@ impl fmt::Display for UpdatePlacementError {
     }
 }
 impl Error for UpdatePlacementError {}
+impl ServiceErrorCode for UpdatePlacementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePlacementError::InternalFailure(_) => "InternalFailureException",
+            UpdatePlacementError::InvalidRequest(_) => "InvalidRequestException",
+            UpdatePlacementError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdatePlacementError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateProject
 #[derive(Debug, PartialEq)]
 pub enum UpdateProjectError {
@@ -1194,6 +1336,16 @@ impl fmt::Display for UpdateProjectError {
     }
 }
 impl Error for UpdateProjectError {}
+impl ServiceErrorCode for UpdateProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateProjectError::InternalFailure(_) => "InternalFailureException",
+            UpdateProjectError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateProjectError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateProjectError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS IoT 1-Click Projects API. AWS IoT 1-Click Projects clients implement this trait.
 #[async_trait]
 pub trait Iot1ClickProjects {
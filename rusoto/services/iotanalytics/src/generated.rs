@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1961,6 +1961,17 @@ impl fmt::Display for BatchPutMessageError {
     }
 }
 impl Error for BatchPutMessageError {}
+impl ServiceErrorCode for BatchPutMessageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchPutMessageError::InternalFailure(_) => "InternalFailureException",
+            BatchPutMessageError::InvalidRequest(_) => "InvalidRequestException",
+            BatchPutMessageError::ResourceNotFound(_) => "ResourceNotFoundException",
+            BatchPutMessageError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            BatchPutMessageError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CancelPipelineReprocessing
 #[derive(Debug, PartialEq)]
 pub enum CancelPipelineReprocessingError {
@@ -2029,6 +2040,17 @@ impl fmt::Display for CancelPipelineReprocessingError {
     }
 }
 impl Error for CancelPipelineReprocessingError {}
+impl ServiceErrorCode for CancelPipelineReprocessingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelPipelineReprocessingError::InternalFailure(_) => "InternalFailureException",
+            CancelPipelineReprocessingError::InvalidRequest(_) => "InvalidRequestException",
+            CancelPipelineReprocessingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelPipelineReprocessingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CancelPipelineReprocessingError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateChannel
 #[derive(Debug, PartialEq)]
 pub enum CreateChannelError {
@@ -2089,6 +2111,18 @@ impl fmt::Display for CreateChannelError {
     }
 }
 impl Error for CreateChannelError {}
+impl ServiceErrorCode for CreateChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateChannelError::InternalFailure(_) => "InternalFailureException",
+            CreateChannelError::InvalidRequest(_) => "InvalidRequestException",
+            CreateChannelError::LimitExceeded(_) => "LimitExceededException",
+            CreateChannelError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateChannelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateChannelError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateDataset
 #[derive(Debug, PartialEq)]
 pub enum CreateDatasetError {
@@ -2149,6 +2183,18 @@ impl fmt::Display for CreateDatasetError {
     }
 }
 impl Error for CreateDatasetError {}
+impl ServiceErrorCode for CreateDatasetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDatasetError::InternalFailure(_) => "InternalFailureException",
+            CreateDatasetError::InvalidRequest(_) => "InvalidRequestException",
+            CreateDatasetError::LimitExceeded(_) => "LimitExceededException",
+            CreateDatasetError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateDatasetError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateDatasetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateDatasetContent
 #[derive(Debug, PartialEq)]
 pub enum CreateDatasetContentError {
@@ -2209,6 +2255,17 @@ impl fmt::Display for CreateDatasetContentError {
     }
 }
 impl Error for CreateDatasetContentError {}
+impl ServiceErrorCode for CreateDatasetContentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDatasetContentError::InternalFailure(_) => "InternalFailureException",
+            CreateDatasetContentError::InvalidRequest(_) => "InvalidRequestException",
+            CreateDatasetContentError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateDatasetContentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateDatasetContentError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateDatastore
 #[derive(Debug, PartialEq)]
 pub enum CreateDatastoreError {
@@ -2271,6 +2328,18 @@ impl fmt::Display for CreateDatastoreError {
     }
 }
 impl Error for CreateDatastoreError {}
+impl ServiceErrorCode for CreateDatastoreError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDatastoreError::InternalFailure(_) => "InternalFailureException",
+            CreateDatastoreError::InvalidRequest(_) => "InvalidRequestException",
+            CreateDatastoreError::LimitExceeded(_) => "LimitExceededException",
+            CreateDatastoreError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateDatastoreError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateDatastoreError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreatePipeline
 #[derive(Debug, PartialEq)]
 pub enum CreatePipelineError {
@@ -2333,6 +2402,18 @@ impl fmt::Display for CreatePipelineError {
     }
 }
 impl Error for CreatePipelineError {}
+impl ServiceErrorCode for CreatePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePipelineError::InternalFailure(_) => "InternalFailureException",
+            CreatePipelineError::InvalidRequest(_) => "InvalidRequestException",
+            CreatePipelineError::LimitExceeded(_) => "LimitExceededException",
+            CreatePipelineError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreatePipelineError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreatePipelineError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteChannel
 #[derive(Debug, PartialEq)]
 pub enum DeleteChannelError {
@@ -2387,6 +2468,17 @@ impl fmt::Display for DeleteChannelError {
     }
 }
 impl Error for DeleteChannelError {}
+impl ServiceErrorCode for DeleteChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteChannelError::InternalFailure(_) => "InternalFailureException",
+            DeleteChannelError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteChannelError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteChannelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteChannelError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteDataset
 #[derive(Debug, PartialEq)]
 pub enum DeleteDatasetError {
@@ -2441,6 +2533,17 @@ impl fmt::Display for DeleteDatasetError {
     }
 }
 impl Error for DeleteDatasetError {}
+impl ServiceErrorCode for DeleteDatasetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDatasetError::InternalFailure(_) => "InternalFailureException",
+            DeleteDatasetError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteDatasetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDatasetError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteDatasetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteDatasetContent
 #[derive(Debug, PartialEq)]
 pub enum DeleteDatasetContentError {
@@ -2501,6 +2604,17 @@ impl fmt::Display for DeleteDatasetContentError {
     }
 }
 impl Error for DeleteDatasetContentError {}
+impl ServiceErrorCode for DeleteDatasetContentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDatasetContentError::InternalFailure(_) => "InternalFailureException",
+            DeleteDatasetContentError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteDatasetContentError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDatasetContentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteDatasetContentError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteDatastore
 #[derive(Debug, PartialEq)]
 pub enum DeleteDatastoreError {
@@ -2555,6 +2669,17 @@ impl fmt::Display for DeleteDatastoreError {
     }
 }
 impl Error for DeleteDatastoreError {}
+impl ServiceErrorCode for DeleteDatastoreError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDatastoreError::InternalFailure(_) => "InternalFailureException",
+            DeleteDatastoreError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteDatastoreError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDatastoreError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteDatastoreError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeletePipeline
 #[derive(Debug, PartialEq)]
 pub enum DeletePipelineError {
@@ -2609,6 +2734,17 @@ impl fmt::Display for DeletePipelineError {
     }
 }
 impl Error for DeletePipelineError {}
+impl ServiceErrorCode for DeletePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePipelineError::InternalFailure(_) => "InternalFailureException",
+            DeletePipelineError::InvalidRequest(_) => "InvalidRequestException",
+            DeletePipelineError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeletePipelineError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeletePipelineError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeChannel
 #[derive(Debug, PartialEq)]
 pub enum DescribeChannelError {
@@ -2663,6 +2799,17 @@ impl fmt::Display for DescribeChannelError {
     }
 }
 impl Error for DescribeChannelError {}
+impl ServiceErrorCode for DescribeChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeChannelError::InternalFailure(_) => "InternalFailureException",
+            DescribeChannelError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeChannelError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeChannelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeChannelError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeDataset
 #[derive(Debug, PartialEq)]
 pub enum DescribeDatasetError {
@@ -2717,6 +2864,17 @@ impl fmt::Display for DescribeDatasetError {
     }
 }
 impl Error for DescribeDatasetError {}
+impl ServiceErrorCode for DescribeDatasetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDatasetError::InternalFailure(_) => "InternalFailureException",
+            DescribeDatasetError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeDatasetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDatasetError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeDatasetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeDatastore
 #[derive(Debug, PartialEq)]
 pub enum DescribeDatastoreError {
@@ -2773,6 +2931,17 @@ impl fmt::Display for DescribeDatastoreError {
     }
 }
 impl Error for DescribeDatastoreError {}
+impl ServiceErrorCode for DescribeDatastoreError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDatastoreError::InternalFailure(_) => "InternalFailureException",
+            DescribeDatastoreError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeDatastoreError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDatastoreError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeDatastoreError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeLoggingOptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoggingOptionsError {
@@ -2835,6 +3004,17 @@ impl fmt::Display for DescribeLoggingOptionsError {
     }
 }
 impl Error for DescribeLoggingOptionsError {}
+impl ServiceErrorCode for DescribeLoggingOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLoggingOptionsError::InternalFailure(_) => "InternalFailureException",
+            DescribeLoggingOptionsError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeLoggingOptionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeLoggingOptionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeLoggingOptionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribePipeline
 #[derive(Debug, PartialEq)]
 pub enum DescribePipelineError {
@@ -2889,6 +3069,17 @@ impl fmt::Display for DescribePipelineError {
     }
 }
 impl Error for DescribePipelineError {}
+impl ServiceErrorCode for DescribePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePipelineError::InternalFailure(_) => "InternalFailureException",
+            DescribePipelineError::InvalidRequest(_) => "InvalidRequestException",
+            DescribePipelineError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribePipelineError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribePipelineError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetDatasetContent
 #[derive(Debug, PartialEq)]
 pub enum GetDatasetContentError {
@@ -2945,6 +3136,17 @@ impl fmt::Display for GetDatasetContentError {
     }
 }
 impl Error for GetDatasetContentError {}
+impl ServiceErrorCode for GetDatasetContentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDatasetContentError::InternalFailure(_) => "InternalFailureException",
+            GetDatasetContentError::InvalidRequest(_) => "InvalidRequestException",
+            GetDatasetContentError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetDatasetContentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetDatasetContentError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListChannels
 #[derive(Debug, PartialEq)]
 pub enum ListChannelsError {
@@ -2993,6 +3195,16 @@ impl fmt::Display for ListChannelsError {
     }
 }
 impl Error for ListChannelsError {}
+impl ServiceErrorCode for ListChannelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListChannelsError::InternalFailure(_) => "InternalFailureException",
+            ListChannelsError::InvalidRequest(_) => "InvalidRequestException",
+            ListChannelsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListChannelsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDatasetContents
 #[derive(Debug, PartialEq)]
 pub enum ListDatasetContentsError {
@@ -3051,6 +3263,17 @@ impl fmt::Display for ListDatasetContentsError {
     }
 }
 impl Error for ListDatasetContentsError {}
+impl ServiceErrorCode for ListDatasetContentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDatasetContentsError::InternalFailure(_) => "InternalFailureException",
+            ListDatasetContentsError::InvalidRequest(_) => "InvalidRequestException",
+            ListDatasetContentsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListDatasetContentsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListDatasetContentsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDatasets
 #[derive(Debug, PartialEq)]
 pub enum ListDatasetsError {
@@ -3099,6 +3322,16 @@ impl fmt::Display for ListDatasetsError {
     }
 }
 impl Error for ListDatasetsError {}
+impl ServiceErrorCode for ListDatasetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDatasetsError::InternalFailure(_) => "InternalFailureException",
+            ListDatasetsError::InvalidRequest(_) => "InvalidRequestException",
+            ListDatasetsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListDatasetsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDatastores
 #[derive(Debug, PartialEq)]
 pub enum ListDatastoresError {
@@ -3147,6 +3380,16 @@ impl fmt::Display for ListDatastoresError {
     }
 }
 impl Error for ListDatastoresError {}
+impl ServiceErrorCode for ListDatastoresError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDatastoresError::InternalFailure(_) => "InternalFailureException",
+            ListDatastoresError::InvalidRequest(_) => "InvalidRequestException",
+            ListDatastoresError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListDatastoresError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListPipelines
 #[derive(Debug, PartialEq)]
 pub enum ListPipelinesError {
@@ -3195,6 +3438,16 @@ impl fmt::Display for ListPipelinesError {
     }
 }
 impl Error for ListPipelinesError {}
+impl ServiceErrorCode for ListPipelinesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPipelinesError::InternalFailure(_) => "InternalFailureException",
+            ListPipelinesError::InvalidRequest(_) => "InvalidRequestException",
+            ListPipelinesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListPipelinesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3259,6 +3512,18 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalFailure(_) => "InternalFailureException",
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+            ListTagsForResourceError::LimitExceeded(_) => "LimitExceededException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListTagsForResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by PutLoggingOptions
 #[derive(Debug, PartialEq)]
 pub enum PutLoggingOptionsError {
@@ -3309,6 +3574,16 @@ impl fmt::Display for PutLoggingOptionsError {
     }
 }
 impl Error for PutLoggingOptionsError {}
+impl ServiceErrorCode for PutLoggingOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutLoggingOptionsError::InternalFailure(_) => "InternalFailureException",
+            PutLoggingOptionsError::InvalidRequest(_) => "InvalidRequestException",
+            PutLoggingOptionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            PutLoggingOptionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by RunPipelineActivity
 #[derive(Debug, PartialEq)]
 pub enum RunPipelineActivityError {
@@ -3359,6 +3634,16 @@ impl fmt::Display for RunPipelineActivityError {
     }
 }
 impl Error for RunPipelineActivityError {}
+impl ServiceErrorCode for RunPipelineActivityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RunPipelineActivityError::InternalFailure(_) => "InternalFailureException",
+            RunPipelineActivityError::InvalidRequest(_) => "InvalidRequestException",
+            RunPipelineActivityError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RunPipelineActivityError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by SampleChannelData
 #[derive(Debug, PartialEq)]
 pub enum SampleChannelDataError {
@@ -3415,6 +3700,17 @@ impl fmt::Display for SampleChannelDataError {
     }
 }
 impl Error for SampleChannelDataError {}
+impl ServiceErrorCode for SampleChannelDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SampleChannelDataError::InternalFailure(_) => "InternalFailureException",
+            SampleChannelDataError::InvalidRequest(_) => "InvalidRequestException",
+            SampleChannelDataError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SampleChannelDataError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            SampleChannelDataError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StartPipelineReprocessing
 #[derive(Debug, PartialEq)]
 pub enum StartPipelineReprocessingError {
@@ -3489,6 +3785,20 @@ impl fmt::Display for StartPipelineReprocessingError {
     }
 }
 impl Error for StartPipelineReprocessingError {}
+impl ServiceErrorCode for StartPipelineReprocessingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartPipelineReprocessingError::InternalFailure(_) => "InternalFailureException",
+            StartPipelineReprocessingError::InvalidRequest(_) => "InvalidRequestException",
+            StartPipelineReprocessingError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            StartPipelineReprocessingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartPipelineReprocessingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            StartPipelineReprocessingError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -3549,6 +3859,18 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalFailure(_) => "InternalFailureException",
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            TagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3609,6 +3931,18 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalFailure(_) => "InternalFailureException",
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            UntagResourceError::LimitExceeded(_) => "LimitExceededException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UntagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateChannel
 #[derive(Debug, PartialEq)]
 pub enum UpdateChannelError {
@@ -3663,6 +3997,17 @@ impl fmt::Display for UpdateChannelError {
     }
 }
 impl Error for UpdateChannelError {}
+impl ServiceErrorCode for UpdateChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateChannelError::InternalFailure(_) => "InternalFailureException",
+            UpdateChannelError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateChannelError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateChannelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateChannelError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateDataset
 #[derive(Debug, PartialEq)]
 pub enum UpdateDatasetError {
@@ -3717,6 +4062,17 @@ impl fmt::Display for UpdateDatasetError {
     }
 }
 impl Error for UpdateDatasetError {}
+impl ServiceErrorCode for UpdateDatasetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDatasetError::InternalFailure(_) => "InternalFailureException",
+            UpdateDatasetError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateDatasetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDatasetError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateDatasetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateDatastore
 #[derive(Debug, PartialEq)]
 pub enum UpdateDatastoreError {
@@ -3771,6 +4127,17 @@ impl fmt::Display for UpdateDatastoreError {
     }
 }
 impl Error for UpdateDatastoreError {}
+impl ServiceErrorCode for UpdateDatastoreError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDatastoreError::InternalFailure(_) => "InternalFailureException",
+            UpdateDatastoreError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateDatastoreError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDatastoreError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateDatastoreError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdatePipeline
 #[derive(Debug, PartialEq)]
 pub enum UpdatePipelineError {
@@ -3831,6 +4198,18 @@ impl fmt::Display for UpdatePipelineError {
     }
 }
 impl Error for UpdatePipelineError {}
+impl ServiceErrorCode for UpdatePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePipelineError::InternalFailure(_) => "InternalFailureException",
+            UpdatePipelineError::InvalidRequest(_) => "InvalidRequestException",
+            UpdatePipelineError::LimitExceeded(_) => "LimitExceededException",
+            UpdatePipelineError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdatePipelineError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdatePipelineError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS IoT Analytics API. AWS IoT Analytics clients implement this trait.
 #[async_trait]
 pub trait IotAnalytics {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -384,6 +384,16 @@ impl fmt::Display for BatchPutMessageError {
     }
 }
 impl Error for BatchPutMessageError {}
+impl ServiceErrorCode for BatchPutMessageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchPutMessageError::InternalFailure(_) => "InternalFailureException",
+            BatchPutMessageError::InvalidRequest(_) => "InvalidRequestException",
+            BatchPutMessageError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            BatchPutMessageError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by BatchUpdateDetector
 #[derive(Debug, PartialEq)]
 pub enum BatchUpdateDetectorError {
@@ -434,6 +444,16 @@ impl fmt::Display for BatchUpdateDetectorError {
     }
 }
 impl Error for BatchUpdateDetectorError {}
+impl ServiceErrorCode for BatchUpdateDetectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchUpdateDetectorError::InternalFailure(_) => "InternalFailureException",
+            BatchUpdateDetectorError::InvalidRequest(_) => "InvalidRequestException",
+            BatchUpdateDetectorError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            BatchUpdateDetectorError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeDetector
 #[derive(Debug, PartialEq)]
 pub enum DescribeDetectorError {
@@ -488,6 +508,17 @@ impl fmt::Display for DescribeDetectorError {
     }
 }
 impl Error for DescribeDetectorError {}
+impl ServiceErrorCode for DescribeDetectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDetectorError::InternalFailure(_) => "InternalFailureException",
+            DescribeDetectorError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeDetectorError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDetectorError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeDetectorError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDetectors
 #[derive(Debug, PartialEq)]
 pub enum ListDetectorsError {
@@ -542,6 +573,17 @@ impl fmt::Display for ListDetectorsError {
     }
 }
 impl Error for ListDetectorsError {}
+impl ServiceErrorCode for ListDetectorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDetectorsError::InternalFailure(_) => "InternalFailureException",
+            ListDetectorsError::InvalidRequest(_) => "InvalidRequestException",
+            ListDetectorsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListDetectorsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListDetectorsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS IoT Events Data API. AWS IoT Events Data clients implement this trait.
 #[async_trait]
 pub trait IotEventsData {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1078,6 +1078,19 @@ impl fmt::Display for CreateDetectorModelError {
     }
 }
 impl Error for CreateDetectorModelError {}
+impl ServiceErrorCode for CreateDetectorModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDetectorModelError::InternalFailure(_) => "InternalFailureException",
+            CreateDetectorModelError::InvalidRequest(_) => "InvalidRequestException",
+            CreateDetectorModelError::LimitExceeded(_) => "LimitExceededException",
+            CreateDetectorModelError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateDetectorModelError::ResourceInUse(_) => "ResourceInUseException",
+            CreateDetectorModelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateDetectorModelError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateInput
 #[derive(Debug, PartialEq)]
 pub enum CreateInputError {
@@ -1132,6 +1145,17 @@ impl fmt::Display for CreateInputError {
     }
 }
 impl Error for CreateInputError {}
+impl ServiceErrorCode for CreateInputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateInputError::InternalFailure(_) => "InternalFailureException",
+            CreateInputError::InvalidRequest(_) => "InvalidRequestException",
+            CreateInputError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateInputError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateInputError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteDetectorModel
 #[derive(Debug, PartialEq)]
 pub enum DeleteDetectorModelError {
@@ -1196,6 +1220,18 @@ impl fmt::Display for DeleteDetectorModelError {
     }
 }
 impl Error for DeleteDetectorModelError {}
+impl ServiceErrorCode for DeleteDetectorModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDetectorModelError::InternalFailure(_) => "InternalFailureException",
+            DeleteDetectorModelError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteDetectorModelError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteDetectorModelError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDetectorModelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteDetectorModelError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteInput
 #[derive(Debug, PartialEq)]
 pub enum DeleteInputError {
@@ -1256,6 +1292,18 @@ impl fmt::Display for DeleteInputError {
     }
 }
 impl Error for DeleteInputError {}
+impl ServiceErrorCode for DeleteInputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInputError::InternalFailure(_) => "InternalFailureException",
+            DeleteInputError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteInputError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteInputError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteInputError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteInputError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeDetectorModel
 #[derive(Debug, PartialEq)]
 pub enum DescribeDetectorModelError {
@@ -1318,6 +1366,17 @@ impl fmt::Display for DescribeDetectorModelError {
     }
 }
 impl Error for DescribeDetectorModelError {}
+impl ServiceErrorCode for DescribeDetectorModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDetectorModelError::InternalFailure(_) => "InternalFailureException",
+            DescribeDetectorModelError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeDetectorModelError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDetectorModelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeDetectorModelError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeInput
 #[derive(Debug, PartialEq)]
 pub enum DescribeInputError {
@@ -1372,6 +1431,17 @@ impl fmt::Display for DescribeInputError {
     }
 }
 impl Error for DescribeInputError {}
+impl ServiceErrorCode for DescribeInputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInputError::InternalFailure(_) => "InternalFailureException",
+            DescribeInputError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeInputError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeInputError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeInputError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeLoggingOptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoggingOptionsError {
@@ -1442,6 +1512,18 @@ impl fmt::Display for DescribeLoggingOptionsError {
     }
 }
 impl Error for DescribeLoggingOptionsError {}
+impl ServiceErrorCode for DescribeLoggingOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLoggingOptionsError::InternalFailure(_) => "InternalFailureException",
+            DescribeLoggingOptionsError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeLoggingOptionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeLoggingOptionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeLoggingOptionsError::Throttling(_) => "ThrottlingException",
+            DescribeLoggingOptionsError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by ListDetectorModelVersions
 #[derive(Debug, PartialEq)]
 pub enum ListDetectorModelVersionsError {
@@ -1506,6 +1588,17 @@ impl fmt::Display for ListDetectorModelVersionsError {
     }
 }
 impl Error for ListDetectorModelVersionsError {}
+impl ServiceErrorCode for ListDetectorModelVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDetectorModelVersionsError::InternalFailure(_) => "InternalFailureException",
+            ListDetectorModelVersionsError::InvalidRequest(_) => "InvalidRequestException",
+            ListDetectorModelVersionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListDetectorModelVersionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListDetectorModelVersionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDetectorModels
 #[derive(Debug, PartialEq)]
 pub enum ListDetectorModelsError {
@@ -1556,6 +1649,16 @@ impl fmt::Display for ListDetectorModelsError {
     }
 }
 impl Error for ListDetectorModelsError {}
+impl ServiceErrorCode for ListDetectorModelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDetectorModelsError::InternalFailure(_) => "InternalFailureException",
+            ListDetectorModelsError::InvalidRequest(_) => "InvalidRequestException",
+            ListDetectorModelsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListDetectorModelsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListInputs
 #[derive(Debug, PartialEq)]
 pub enum ListInputsError {
@@ -1604,6 +1707,16 @@ impl fmt::Display for ListInputsError {
     }
 }
 impl Error for ListInputsError {}
+impl ServiceErrorCode for ListInputsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInputsError::InternalFailure(_) => "InternalFailureException",
+            ListInputsError::InvalidRequest(_) => "InvalidRequestException",
+            ListInputsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListInputsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1660,6 +1773,17 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalFailure(_) => "InternalFailureException",
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+            ListTagsForResourceError::ResourceInUse(_) => "ResourceInUseException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by PutLoggingOptions
 #[derive(Debug, PartialEq)]
 pub enum PutLoggingOptionsError {
@@ -1724,6 +1848,18 @@ impl fmt::Display for PutLoggingOptionsError {
     }
 }
 impl Error for PutLoggingOptionsError {}
+impl ServiceErrorCode for PutLoggingOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutLoggingOptionsError::InternalFailure(_) => "InternalFailureException",
+            PutLoggingOptionsError::InvalidRequest(_) => "InvalidRequestException",
+            PutLoggingOptionsError::ResourceInUse(_) => "ResourceInUseException",
+            PutLoggingOptionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            PutLoggingOptionsError::Throttling(_) => "ThrottlingException",
+            PutLoggingOptionsError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1784,6 +1920,18 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalFailure(_) => "InternalFailureException",
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::ResourceInUse(_) => "ResourceInUseException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1838,6 +1986,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalFailure(_) => "InternalFailureException",
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            UntagResourceError::ResourceInUse(_) => "ResourceInUseException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateDetectorModel
 #[derive(Debug, PartialEq)]
 pub enum UpdateDetectorModelError {
@@ -1902,6 +2061,18 @@ impl fmt::Display for UpdateDetectorModelError {
     }
 }
 impl Error for UpdateDetectorModelError {}
+impl ServiceErrorCode for UpdateDetectorModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDetectorModelError::InternalFailure(_) => "InternalFailureException",
+            UpdateDetectorModelError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateDetectorModelError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateDetectorModelError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDetectorModelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateDetectorModelError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateInput
 #[derive(Debug, PartialEq)]
 pub enum UpdateInputError {
@@ -1962,6 +2133,18 @@ impl fmt::Display for UpdateInputError {
     }
 }
 impl Error for UpdateInputError {}
+impl ServiceErrorCode for UpdateInputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateInputError::InternalFailure(_) => "InternalFailureException",
+            UpdateInputError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateInputError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateInputError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateInputError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateInputError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS IoT Events API. AWS IoT Events clients implement this trait.
 #[async_trait]
 pub trait IotEvents {
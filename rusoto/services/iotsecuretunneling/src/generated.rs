@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -364,6 +364,13 @@ impl fmt::Display for CloseTunnelError {
     }
 }
 impl Error for CloseTunnelError {}
+impl ServiceErrorCode for CloseTunnelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CloseTunnelError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeTunnel
 #[derive(Debug, PartialEq)]
 pub enum DescribeTunnelError {
@@ -394,6 +401,13 @@ impl fmt::Display for DescribeTunnelError {
     }
 }
 impl Error for DescribeTunnelError {}
+impl ServiceErrorCode for DescribeTunnelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTunnelError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -426,6 +440,13 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTunnels
 #[derive(Debug, PartialEq)]
 pub enum ListTunnelsError {}
@@ -448,6 +469,11 @@ impl fmt::Display for ListTunnelsError {
     }
 }
 impl Error for ListTunnelsError {}
+impl ServiceErrorCode for ListTunnelsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by OpenTunnel
 #[derive(Debug, PartialEq)]
 pub enum OpenTunnelError {
@@ -478,6 +504,13 @@ impl fmt::Display for OpenTunnelError {
     }
 }
 impl Error for OpenTunnelError {}
+impl ServiceErrorCode for OpenTunnelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            OpenTunnelError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -508,6 +541,13 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -538,6 +578,13 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS IoT Secure Tunneling API. AWS IoT Secure Tunneling clients implement this trait.
 #[async_trait]
 pub trait IoTSecureTunneling {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1284,6 +1284,16 @@ impl fmt::Display for AssociateEntityToThingError {
     }
 }
 impl Error for AssociateEntityToThingError {}
+impl ServiceErrorCode for AssociateEntityToThingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateEntityToThingError::InternalFailure(_) => "InternalFailureException",
+            AssociateEntityToThingError::InvalidRequest(_) => "InvalidRequestException",
+            AssociateEntityToThingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateEntityToThingError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateFlowTemplate
 #[derive(Debug, PartialEq)]
 pub enum CreateFlowTemplateError {
@@ -1340,6 +1350,17 @@ impl fmt::Display for CreateFlowTemplateError {
     }
 }
 impl Error for CreateFlowTemplateError {}
+impl ServiceErrorCode for CreateFlowTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFlowTemplateError::InternalFailure(_) => "InternalFailureException",
+            CreateFlowTemplateError::InvalidRequest(_) => "InvalidRequestException",
+            CreateFlowTemplateError::LimitExceeded(_) => "LimitExceededException",
+            CreateFlowTemplateError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateFlowTemplateError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateSystemInstance
 #[derive(Debug, PartialEq)]
 pub enum CreateSystemInstanceError {
@@ -1398,6 +1419,17 @@ impl fmt::Display for CreateSystemInstanceError {
     }
 }
 impl Error for CreateSystemInstanceError {}
+impl ServiceErrorCode for CreateSystemInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSystemInstanceError::InternalFailure(_) => "InternalFailureException",
+            CreateSystemInstanceError::InvalidRequest(_) => "InvalidRequestException",
+            CreateSystemInstanceError::LimitExceeded(_) => "LimitExceededException",
+            CreateSystemInstanceError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateSystemInstanceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateSystemTemplate
 #[derive(Debug, PartialEq)]
 pub enum CreateSystemTemplateError {
@@ -1450,6 +1482,16 @@ impl fmt::Display for CreateSystemTemplateError {
     }
 }
 impl Error for CreateSystemTemplateError {}
+impl ServiceErrorCode for CreateSystemTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSystemTemplateError::InternalFailure(_) => "InternalFailureException",
+            CreateSystemTemplateError::InvalidRequest(_) => "InvalidRequestException",
+            CreateSystemTemplateError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateSystemTemplateError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteFlowTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeleteFlowTemplateError {
@@ -1498,6 +1540,16 @@ impl fmt::Display for DeleteFlowTemplateError {
     }
 }
 impl Error for DeleteFlowTemplateError {}
+impl ServiceErrorCode for DeleteFlowTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFlowTemplateError::InternalFailure(_) => "InternalFailureException",
+            DeleteFlowTemplateError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteFlowTemplateError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteFlowTemplateError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteNamespace
 #[derive(Debug, PartialEq)]
 pub enum DeleteNamespaceError {
@@ -1534,6 +1586,14 @@ impl fmt::Display for DeleteNamespaceError {
     }
 }
 impl Error for DeleteNamespaceError {}
+impl ServiceErrorCode for DeleteNamespaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteNamespaceError::InternalFailure(_) => "InternalFailureException",
+            DeleteNamespaceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteSystemInstance
 #[derive(Debug, PartialEq)]
 pub enum DeleteSystemInstanceError {
@@ -1584,6 +1644,16 @@ impl fmt::Display for DeleteSystemInstanceError {
     }
 }
 impl Error for DeleteSystemInstanceError {}
+impl ServiceErrorCode for DeleteSystemInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSystemInstanceError::InternalFailure(_) => "InternalFailureException",
+            DeleteSystemInstanceError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteSystemInstanceError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteSystemInstanceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteSystemTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeleteSystemTemplateError {
@@ -1634,6 +1704,16 @@ impl fmt::Display for DeleteSystemTemplateError {
     }
 }
 impl Error for DeleteSystemTemplateError {}
+impl ServiceErrorCode for DeleteSystemTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSystemTemplateError::InternalFailure(_) => "InternalFailureException",
+            DeleteSystemTemplateError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteSystemTemplateError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteSystemTemplateError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeploySystemInstance
 #[derive(Debug, PartialEq)]
 pub enum DeploySystemInstanceError {
@@ -1692,6 +1772,17 @@ impl fmt::Display for DeploySystemInstanceError {
     }
 }
 impl Error for DeploySystemInstanceError {}
+impl ServiceErrorCode for DeploySystemInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeploySystemInstanceError::InternalFailure(_) => "InternalFailureException",
+            DeploySystemInstanceError::InvalidRequest(_) => "InvalidRequestException",
+            DeploySystemInstanceError::ResourceInUse(_) => "ResourceInUseException",
+            DeploySystemInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeploySystemInstanceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeprecateFlowTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeprecateFlowTemplateError {
@@ -1746,6 +1837,16 @@ impl fmt::Display for DeprecateFlowTemplateError {
     }
 }
 impl Error for DeprecateFlowTemplateError {}
+impl ServiceErrorCode for DeprecateFlowTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeprecateFlowTemplateError::InternalFailure(_) => "InternalFailureException",
+            DeprecateFlowTemplateError::InvalidRequest(_) => "InvalidRequestException",
+            DeprecateFlowTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeprecateFlowTemplateError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeprecateSystemTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeprecateSystemTemplateError {
@@ -1800,6 +1901,16 @@ impl fmt::Display for DeprecateSystemTemplateError {
     }
 }
 impl Error for DeprecateSystemTemplateError {}
+impl ServiceErrorCode for DeprecateSystemTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeprecateSystemTemplateError::InternalFailure(_) => "InternalFailureException",
+            DeprecateSystemTemplateError::InvalidRequest(_) => "InvalidRequestException",
+            DeprecateSystemTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeprecateSystemTemplateError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeNamespace
 #[derive(Debug, PartialEq)]
 pub enum DescribeNamespaceError {
@@ -1848,6 +1959,16 @@ impl fmt::Display for DescribeNamespaceError {
     }
 }
 impl Error for DescribeNamespaceError {}
+impl ServiceErrorCode for DescribeNamespaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeNamespaceError::InternalFailure(_) => "InternalFailureException",
+            DescribeNamespaceError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeNamespaceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeNamespaceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DissociateEntityFromThing
 #[derive(Debug, PartialEq)]
 pub enum DissociateEntityFromThingError {
@@ -1904,6 +2025,16 @@ impl fmt::Display for DissociateEntityFromThingError {
     }
 }
 impl Error for DissociateEntityFromThingError {}
+impl ServiceErrorCode for DissociateEntityFromThingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DissociateEntityFromThingError::InternalFailure(_) => "InternalFailureException",
+            DissociateEntityFromThingError::InvalidRequest(_) => "InvalidRequestException",
+            DissociateEntityFromThingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DissociateEntityFromThingError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetEntities
 #[derive(Debug, PartialEq)]
 pub enum GetEntitiesError {
@@ -1952,6 +2083,16 @@ impl fmt::Display for GetEntitiesError {
     }
 }
 impl Error for GetEntitiesError {}
+impl ServiceErrorCode for GetEntitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEntitiesError::InternalFailure(_) => "InternalFailureException",
+            GetEntitiesError::InvalidRequest(_) => "InvalidRequestException",
+            GetEntitiesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetEntitiesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetFlowTemplate
 #[derive(Debug, PartialEq)]
 pub enum GetFlowTemplateError {
@@ -2000,6 +2141,16 @@ impl fmt::Display for GetFlowTemplateError {
     }
 }
 impl Error for GetFlowTemplateError {}
+impl ServiceErrorCode for GetFlowTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFlowTemplateError::InternalFailure(_) => "InternalFailureException",
+            GetFlowTemplateError::InvalidRequest(_) => "InvalidRequestException",
+            GetFlowTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetFlowTemplateError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetFlowTemplateRevisions
 #[derive(Debug, PartialEq)]
 pub enum GetFlowTemplateRevisionsError {
@@ -2054,6 +2205,16 @@ impl fmt::Display for GetFlowTemplateRevisionsError {
     }
 }
 impl Error for GetFlowTemplateRevisionsError {}
+impl ServiceErrorCode for GetFlowTemplateRevisionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFlowTemplateRevisionsError::InternalFailure(_) => "InternalFailureException",
+            GetFlowTemplateRevisionsError::InvalidRequest(_) => "InvalidRequestException",
+            GetFlowTemplateRevisionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetFlowTemplateRevisionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetNamespaceDeletionStatus
 #[derive(Debug, PartialEq)]
 pub enum GetNamespaceDeletionStatusError {
@@ -2104,6 +2265,15 @@ impl fmt::Display for GetNamespaceDeletionStatusError {
     }
 }
 impl Error for GetNamespaceDeletionStatusError {}
+impl ServiceErrorCode for GetNamespaceDeletionStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetNamespaceDeletionStatusError::InternalFailure(_) => "InternalFailureException",
+            GetNamespaceDeletionStatusError::InvalidRequest(_) => "InvalidRequestException",
+            GetNamespaceDeletionStatusError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetSystemInstance
 #[derive(Debug, PartialEq)]
 pub enum GetSystemInstanceError {
@@ -2152,6 +2322,16 @@ impl fmt::Display for GetSystemInstanceError {
     }
 }
 impl Error for GetSystemInstanceError {}
+impl ServiceErrorCode for GetSystemInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSystemInstanceError::InternalFailure(_) => "InternalFailureException",
+            GetSystemInstanceError::InvalidRequest(_) => "InvalidRequestException",
+            GetSystemInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetSystemInstanceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetSystemTemplate
 #[derive(Debug, PartialEq)]
 pub enum GetSystemTemplateError {
@@ -2200,6 +2380,16 @@ impl fmt::Display for GetSystemTemplateError {
     }
 }
 impl Error for GetSystemTemplateError {}
+impl ServiceErrorCode for GetSystemTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSystemTemplateError::InternalFailure(_) => "InternalFailureException",
+            GetSystemTemplateError::InvalidRequest(_) => "InvalidRequestException",
+            GetSystemTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetSystemTemplateError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetSystemTemplateRevisions
 #[derive(Debug, PartialEq)]
 pub enum GetSystemTemplateRevisionsError {
@@ -2258,6 +2448,16 @@ impl fmt::Display for GetSystemTemplateRevisionsError {
     }
 }
 impl Error for GetSystemTemplateRevisionsError {}
+impl ServiceErrorCode for GetSystemTemplateRevisionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSystemTemplateRevisionsError::InternalFailure(_) => "InternalFailureException",
+            GetSystemTemplateRevisionsError::InvalidRequest(_) => "InvalidRequestException",
+            GetSystemTemplateRevisionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetSystemTemplateRevisionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetUploadStatus
 #[derive(Debug, PartialEq)]
 pub enum GetUploadStatusError {
@@ -2306,6 +2506,16 @@ impl fmt::Display for GetUploadStatusError {
     }
 }
 impl Error for GetUploadStatusError {}
+impl ServiceErrorCode for GetUploadStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUploadStatusError::InternalFailure(_) => "InternalFailureException",
+            GetUploadStatusError::InvalidRequest(_) => "InvalidRequestException",
+            GetUploadStatusError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetUploadStatusError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListFlowExecutionMessages
 #[derive(Debug, PartialEq)]
 pub enum ListFlowExecutionMessagesError {
@@ -2362,6 +2572,16 @@ impl fmt::Display for ListFlowExecutionMessagesError {
     }
 }
 impl Error for ListFlowExecutionMessagesError {}
+impl ServiceErrorCode for ListFlowExecutionMessagesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFlowExecutionMessagesError::InternalFailure(_) => "InternalFailureException",
+            ListFlowExecutionMessagesError::InvalidRequest(_) => "InvalidRequestException",
+            ListFlowExecutionMessagesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListFlowExecutionMessagesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2412,6 +2632,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalFailure(_) => "InternalFailureException",
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+            ListTagsForResourceError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            ListTagsForResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by SearchEntities
 #[derive(Debug, PartialEq)]
 pub enum SearchEntitiesError {
@@ -2454,6 +2684,15 @@ impl fmt::Display for SearchEntitiesError {
     }
 }
 impl Error for SearchEntitiesError {}
+impl ServiceErrorCode for SearchEntitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchEntitiesError::InternalFailure(_) => "InternalFailureException",
+            SearchEntitiesError::InvalidRequest(_) => "InvalidRequestException",
+            SearchEntitiesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by SearchFlowExecutions
 #[derive(Debug, PartialEq)]
 pub enum SearchFlowExecutionsError {
@@ -2506,6 +2745,16 @@ impl fmt::Display for SearchFlowExecutionsError {
     }
 }
 impl Error for SearchFlowExecutionsError {}
+impl ServiceErrorCode for SearchFlowExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchFlowExecutionsError::InternalFailure(_) => "InternalFailureException",
+            SearchFlowExecutionsError::InvalidRequest(_) => "InvalidRequestException",
+            SearchFlowExecutionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SearchFlowExecutionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by SearchFlowTemplates
 #[derive(Debug, PartialEq)]
 pub enum SearchFlowTemplatesError {
@@ -2548,6 +2797,15 @@ impl fmt::Display for SearchFlowTemplatesError {
     }
 }
 impl Error for SearchFlowTemplatesError {}
+impl ServiceErrorCode for SearchFlowTemplatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchFlowTemplatesError::InternalFailure(_) => "InternalFailureException",
+            SearchFlowTemplatesError::InvalidRequest(_) => "InvalidRequestException",
+            SearchFlowTemplatesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by SearchSystemInstances
 #[derive(Debug, PartialEq)]
 pub enum SearchSystemInstancesError {
@@ -2594,6 +2852,15 @@ impl fmt::Display for SearchSystemInstancesError {
     }
 }
 impl Error for SearchSystemInstancesError {}
+impl ServiceErrorCode for SearchSystemInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchSystemInstancesError::InternalFailure(_) => "InternalFailureException",
+            SearchSystemInstancesError::InvalidRequest(_) => "InvalidRequestException",
+            SearchSystemInstancesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by SearchSystemTemplates
 #[derive(Debug, PartialEq)]
 pub enum SearchSystemTemplatesError {
@@ -2640,6 +2907,15 @@ impl fmt::Display for SearchSystemTemplatesError {
     }
 }
 impl Error for SearchSystemTemplatesError {}
+impl ServiceErrorCode for SearchSystemTemplatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchSystemTemplatesError::InternalFailure(_) => "InternalFailureException",
+            SearchSystemTemplatesError::InvalidRequest(_) => "InvalidRequestException",
+            SearchSystemTemplatesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by SearchThings
 #[derive(Debug, PartialEq)]
 pub enum SearchThingsError {
@@ -2688,6 +2964,16 @@ impl fmt::Display for SearchThingsError {
     }
 }
 impl Error for SearchThingsError {}
+impl ServiceErrorCode for SearchThingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchThingsError::InternalFailure(_) => "InternalFailureException",
+            SearchThingsError::InvalidRequest(_) => "InvalidRequestException",
+            SearchThingsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SearchThingsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2736,6 +3022,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalFailure(_) => "InternalFailureException",
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            TagResourceError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            TagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UndeploySystemInstance
 #[derive(Debug, PartialEq)]
 pub enum UndeploySystemInstanceError {
@@ -2798,6 +3094,17 @@ impl fmt::Display for UndeploySystemInstanceError {
     }
 }
 impl Error for UndeploySystemInstanceError {}
+impl ServiceErrorCode for UndeploySystemInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UndeploySystemInstanceError::InternalFailure(_) => "InternalFailureException",
+            UndeploySystemInstanceError::InvalidRequest(_) => "InvalidRequestException",
+            UndeploySystemInstanceError::ResourceInUse(_) => "ResourceInUseException",
+            UndeploySystemInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UndeploySystemInstanceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2846,6 +3153,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalFailure(_) => "InternalFailureException",
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            UntagResourceError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            UntagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateFlowTemplate
 #[derive(Debug, PartialEq)]
 pub enum UpdateFlowTemplateError {
@@ -2894,6 +3211,16 @@ impl fmt::Display for UpdateFlowTemplateError {
     }
 }
 impl Error for UpdateFlowTemplateError {}
+impl ServiceErrorCode for UpdateFlowTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFlowTemplateError::InternalFailure(_) => "InternalFailureException",
+            UpdateFlowTemplateError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateFlowTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateFlowTemplateError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateSystemTemplate
 #[derive(Debug, PartialEq)]
 pub enum UpdateSystemTemplateError {
@@ -2946,6 +3273,16 @@ impl fmt::Display for UpdateSystemTemplateError {
     }
 }
 impl Error for UpdateSystemTemplateError {}
+impl ServiceErrorCode for UpdateSystemTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSystemTemplateError::InternalFailure(_) => "InternalFailureException",
+            UpdateSystemTemplateError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateSystemTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateSystemTemplateError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UploadEntityDefinitions
 #[derive(Debug, PartialEq)]
 pub enum UploadEntityDefinitionsError {
@@ -2992,6 +3329,15 @@ impl fmt::Display for UploadEntityDefinitionsError {
     }
 }
 impl Error for UploadEntityDefinitionsError {}
+impl ServiceErrorCode for UploadEntityDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UploadEntityDefinitionsError::InternalFailure(_) => "InternalFailureException",
+            UploadEntityDefinitionsError::InvalidRequest(_) => "InvalidRequestException",
+            UploadEntityDefinitionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS IoT Things Graph API. AWS IoT Things Graph clients implement this trait.
 #[async_trait]
 pub trait IotThingsGraph {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1898,6 +1898,21 @@ impl fmt::Display for BatchAssociateScramSecretError {
     }
 }
 impl Error for BatchAssociateScramSecretError {}
+impl ServiceErrorCode for BatchAssociateScramSecretError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchAssociateScramSecretError::BadRequest(_) => "BadRequestException",
+            BatchAssociateScramSecretError::Forbidden(_) => "ForbiddenException",
+            BatchAssociateScramSecretError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            BatchAssociateScramSecretError::NotFound(_) => "NotFoundException",
+            BatchAssociateScramSecretError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            BatchAssociateScramSecretError::TooManyRequests(_) => "TooManyRequestsException",
+            BatchAssociateScramSecretError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by BatchDisassociateScramSecret
 #[derive(Debug, PartialEq)]
 pub enum BatchDisassociateScramSecretError {
@@ -1991,6 +2006,23 @@ impl fmt::Display for BatchDisassociateScramSecretError {
     }
 }
 impl Error for BatchDisassociateScramSecretError {}
+impl ServiceErrorCode for BatchDisassociateScramSecretError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDisassociateScramSecretError::BadRequest(_) => "BadRequestException",
+            BatchDisassociateScramSecretError::Forbidden(_) => "ForbiddenException",
+            BatchDisassociateScramSecretError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            BatchDisassociateScramSecretError::NotFound(_) => "NotFoundException",
+            BatchDisassociateScramSecretError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            BatchDisassociateScramSecretError::TooManyRequests(_) => "TooManyRequestsException",
+            BatchDisassociateScramSecretError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateCluster
 #[derive(Debug, PartialEq)]
 pub enum CreateClusterError {
@@ -2064,6 +2096,19 @@ impl fmt::Display for CreateClusterError {
     }
 }
 impl Error for CreateClusterError {}
+impl ServiceErrorCode for CreateClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateClusterError::BadRequest(_) => "BadRequestException",
+            CreateClusterError::Conflict(_) => "ConflictException",
+            CreateClusterError::Forbidden(_) => "ForbiddenException",
+            CreateClusterError::InternalServerError(_) => "InternalServerErrorException",
+            CreateClusterError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateClusterError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateClusterError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateConfiguration
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationError {
@@ -2141,6 +2186,19 @@ impl fmt::Display for CreateConfigurationError {
     }
 }
 impl Error for CreateConfigurationError {}
+impl ServiceErrorCode for CreateConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConfigurationError::BadRequest(_) => "BadRequestException",
+            CreateConfigurationError::Conflict(_) => "ConflictException",
+            CreateConfigurationError::Forbidden(_) => "ForbiddenException",
+            CreateConfigurationError::InternalServerError(_) => "InternalServerErrorException",
+            CreateConfigurationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateConfigurationError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteCluster
 #[derive(Debug, PartialEq)]
 pub enum DeleteClusterError {
@@ -2193,6 +2251,16 @@ impl fmt::Display for DeleteClusterError {
     }
 }
 impl Error for DeleteClusterError {}
+impl ServiceErrorCode for DeleteClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteClusterError::BadRequest(_) => "BadRequestException",
+            DeleteClusterError::Forbidden(_) => "ForbiddenException",
+            DeleteClusterError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteClusterError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationError {
@@ -2247,6 +2315,16 @@ impl fmt::Display for DeleteConfigurationError {
     }
 }
 impl Error for DeleteConfigurationError {}
+impl ServiceErrorCode for DeleteConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigurationError::BadRequest(_) => "BadRequestException",
+            DeleteConfigurationError::Forbidden(_) => "ForbiddenException",
+            DeleteConfigurationError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteConfigurationError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeCluster
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterError {
@@ -2306,6 +2384,17 @@ impl fmt::Display for DescribeClusterError {
     }
 }
 impl Error for DescribeClusterError {}
+impl ServiceErrorCode for DescribeClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClusterError::BadRequest(_) => "BadRequestException",
+            DescribeClusterError::Forbidden(_) => "ForbiddenException",
+            DescribeClusterError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeClusterError::NotFound(_) => "NotFoundException",
+            DescribeClusterError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeClusterOperation
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterOperationError {
@@ -2369,6 +2458,17 @@ impl fmt::Display for DescribeClusterOperationError {
     }
 }
 impl Error for DescribeClusterOperationError {}
+impl ServiceErrorCode for DescribeClusterOperationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClusterOperationError::BadRequest(_) => "BadRequestException",
+            DescribeClusterOperationError::Forbidden(_) => "ForbiddenException",
+            DescribeClusterOperationError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeClusterOperationError::NotFound(_) => "NotFoundException",
+            DescribeClusterOperationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigurationError {
@@ -2439,6 +2539,18 @@ impl fmt::Display for DescribeConfigurationError {
     }
 }
 impl Error for DescribeConfigurationError {}
+impl ServiceErrorCode for DescribeConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConfigurationError::BadRequest(_) => "BadRequestException",
+            DescribeConfigurationError::Forbidden(_) => "ForbiddenException",
+            DescribeConfigurationError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeConfigurationError::NotFound(_) => "NotFoundException",
+            DescribeConfigurationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeConfigurationRevision
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigurationRevisionError {
@@ -2523,6 +2635,22 @@ impl fmt::Display for DescribeConfigurationRevisionError {
     }
 }
 impl Error for DescribeConfigurationRevisionError {}
+impl ServiceErrorCode for DescribeConfigurationRevisionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConfigurationRevisionError::BadRequest(_) => "BadRequestException",
+            DescribeConfigurationRevisionError::Forbidden(_) => "ForbiddenException",
+            DescribeConfigurationRevisionError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DescribeConfigurationRevisionError::NotFound(_) => "NotFoundException",
+            DescribeConfigurationRevisionError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DescribeConfigurationRevisionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetBootstrapBrokers
 #[derive(Debug, PartialEq)]
 pub enum GetBootstrapBrokersError {
@@ -2584,6 +2712,17 @@ impl fmt::Display for GetBootstrapBrokersError {
     }
 }
 impl Error for GetBootstrapBrokersError {}
+impl ServiceErrorCode for GetBootstrapBrokersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBootstrapBrokersError::BadRequest(_) => "BadRequestException",
+            GetBootstrapBrokersError::Conflict(_) => "ConflictException",
+            GetBootstrapBrokersError::Forbidden(_) => "ForbiddenException",
+            GetBootstrapBrokersError::InternalServerError(_) => "InternalServerErrorException",
+            GetBootstrapBrokersError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetCompatibleKafkaVersions
 #[derive(Debug, PartialEq)]
 pub enum GetCompatibleKafkaVersionsError {
@@ -2675,6 +2814,21 @@ impl fmt::Display for GetCompatibleKafkaVersionsError {
     }
 }
 impl Error for GetCompatibleKafkaVersionsError {}
+impl ServiceErrorCode for GetCompatibleKafkaVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCompatibleKafkaVersionsError::BadRequest(_) => "BadRequestException",
+            GetCompatibleKafkaVersionsError::Forbidden(_) => "ForbiddenException",
+            GetCompatibleKafkaVersionsError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            GetCompatibleKafkaVersionsError::NotFound(_) => "NotFoundException",
+            GetCompatibleKafkaVersionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetCompatibleKafkaVersionsError::TooManyRequests(_) => "TooManyRequestsException",
+            GetCompatibleKafkaVersionsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListClusterOperations
 #[derive(Debug, PartialEq)]
 pub enum ListClusterOperationsError {
@@ -2729,6 +2883,16 @@ impl fmt::Display for ListClusterOperationsError {
     }
 }
 impl Error for ListClusterOperationsError {}
+impl ServiceErrorCode for ListClusterOperationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListClusterOperationsError::BadRequest(_) => "BadRequestException",
+            ListClusterOperationsError::Forbidden(_) => "ForbiddenException",
+            ListClusterOperationsError::InternalServerError(_) => "InternalServerErrorException",
+            ListClusterOperationsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListClusters
 #[derive(Debug, PartialEq)]
 pub enum ListClustersError {
@@ -2781,6 +2945,16 @@ impl fmt::Display for ListClustersError {
     }
 }
 impl Error for ListClustersError {}
+impl ServiceErrorCode for ListClustersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListClustersError::BadRequest(_) => "BadRequestException",
+            ListClustersError::Forbidden(_) => "ForbiddenException",
+            ListClustersError::InternalServerError(_) => "InternalServerErrorException",
+            ListClustersError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListConfigurationRevisions
 #[derive(Debug, PartialEq)]
 pub enum ListConfigurationRevisionsError {
@@ -2863,6 +3037,20 @@ impl fmt::Display for ListConfigurationRevisionsError {
     }
 }
 impl Error for ListConfigurationRevisionsError {}
+impl ServiceErrorCode for ListConfigurationRevisionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListConfigurationRevisionsError::BadRequest(_) => "BadRequestException",
+            ListConfigurationRevisionsError::Forbidden(_) => "ForbiddenException",
+            ListConfigurationRevisionsError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            ListConfigurationRevisionsError::NotFound(_) => "NotFoundException",
+            ListConfigurationRevisionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListConfigurationRevisionsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListConfigurationsError {
@@ -2926,6 +3114,17 @@ impl fmt::Display for ListConfigurationsError {
     }
 }
 impl Error for ListConfigurationsError {}
+impl ServiceErrorCode for ListConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListConfigurationsError::BadRequest(_) => "BadRequestException",
+            ListConfigurationsError::Forbidden(_) => "ForbiddenException",
+            ListConfigurationsError::InternalServerError(_) => "InternalServerErrorException",
+            ListConfigurationsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListConfigurationsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListKafkaVersions
 #[derive(Debug, PartialEq)]
 pub enum ListKafkaVersionsError {
@@ -2980,6 +3179,16 @@ impl fmt::Display for ListKafkaVersionsError {
     }
 }
 impl Error for ListKafkaVersionsError {}
+impl ServiceErrorCode for ListKafkaVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListKafkaVersionsError::BadRequest(_) => "BadRequestException",
+            ListKafkaVersionsError::Forbidden(_) => "ForbiddenException",
+            ListKafkaVersionsError::InternalServerError(_) => "InternalServerErrorException",
+            ListKafkaVersionsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListNodes
 #[derive(Debug, PartialEq)]
 pub enum ListNodesError {
@@ -3032,6 +3241,16 @@ impl fmt::Display for ListNodesError {
     }
 }
 impl Error for ListNodesError {}
+impl ServiceErrorCode for ListNodesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListNodesError::BadRequest(_) => "BadRequestException",
+            ListNodesError::Forbidden(_) => "ForbiddenException",
+            ListNodesError::InternalServerError(_) => "InternalServerErrorException",
+            ListNodesError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListScramSecrets
 #[derive(Debug, PartialEq)]
 pub enum ListScramSecretsError {
@@ -3107,6 +3326,19 @@ impl fmt::Display for ListScramSecretsError {
     }
 }
 impl Error for ListScramSecretsError {}
+impl ServiceErrorCode for ListScramSecretsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListScramSecretsError::BadRequest(_) => "BadRequestException",
+            ListScramSecretsError::Forbidden(_) => "ForbiddenException",
+            ListScramSecretsError::InternalServerError(_) => "InternalServerErrorException",
+            ListScramSecretsError::NotFound(_) => "NotFoundException",
+            ListScramSecretsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListScramSecretsError::TooManyRequests(_) => "TooManyRequestsException",
+            ListScramSecretsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3154,6 +3386,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::InternalServerError(_) => "InternalServerErrorException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by RebootBroker
 #[derive(Debug, PartialEq)]
 pub enum RebootBrokerError {
@@ -3227,6 +3468,19 @@ impl fmt::Display for RebootBrokerError {
     }
 }
 impl Error for RebootBrokerError {}
+impl ServiceErrorCode for RebootBrokerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RebootBrokerError::BadRequest(_) => "BadRequestException",
+            RebootBrokerError::Forbidden(_) => "ForbiddenException",
+            RebootBrokerError::InternalServerError(_) => "InternalServerErrorException",
+            RebootBrokerError::NotFound(_) => "NotFoundException",
+            RebootBrokerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RebootBrokerError::TooManyRequests(_) => "TooManyRequestsException",
+            RebootBrokerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -3272,6 +3526,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::InternalServerError(_) => "InternalServerErrorException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3317,6 +3580,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::InternalServerError(_) => "InternalServerErrorException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateBrokerCount
 #[derive(Debug, PartialEq)]
 pub enum UpdateBrokerCountError {
@@ -3380,6 +3652,17 @@ impl fmt::Display for UpdateBrokerCountError {
     }
 }
 impl Error for UpdateBrokerCountError {}
+impl ServiceErrorCode for UpdateBrokerCountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateBrokerCountError::BadRequest(_) => "BadRequestException",
+            UpdateBrokerCountError::Forbidden(_) => "ForbiddenException",
+            UpdateBrokerCountError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateBrokerCountError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateBrokerCountError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateBrokerStorage
 #[derive(Debug, PartialEq)]
 pub enum UpdateBrokerStorageError {
@@ -3443,6 +3726,17 @@ impl fmt::Display for UpdateBrokerStorageError {
     }
 }
 impl Error for UpdateBrokerStorageError {}
+impl ServiceErrorCode for UpdateBrokerStorageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateBrokerStorageError::BadRequest(_) => "BadRequestException",
+            UpdateBrokerStorageError::Forbidden(_) => "ForbiddenException",
+            UpdateBrokerStorageError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateBrokerStorageError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateBrokerStorageError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateClusterConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateClusterConfigurationError {
@@ -3525,6 +3819,20 @@ impl fmt::Display for UpdateClusterConfigurationError {
     }
 }
 impl Error for UpdateClusterConfigurationError {}
+impl ServiceErrorCode for UpdateClusterConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateClusterConfigurationError::BadRequest(_) => "BadRequestException",
+            UpdateClusterConfigurationError::Forbidden(_) => "ForbiddenException",
+            UpdateClusterConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            UpdateClusterConfigurationError::NotFound(_) => "NotFoundException",
+            UpdateClusterConfigurationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateClusterConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateClusterKafkaVersion
 #[derive(Debug, PartialEq)]
 pub enum UpdateClusterKafkaVersionError {
@@ -3610,6 +3918,21 @@ impl fmt::Display for UpdateClusterKafkaVersionError {
     }
 }
 impl Error for UpdateClusterKafkaVersionError {}
+impl ServiceErrorCode for UpdateClusterKafkaVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateClusterKafkaVersionError::BadRequest(_) => "BadRequestException",
+            UpdateClusterKafkaVersionError::Forbidden(_) => "ForbiddenException",
+            UpdateClusterKafkaVersionError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            UpdateClusterKafkaVersionError::NotFound(_) => "NotFoundException",
+            UpdateClusterKafkaVersionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateClusterKafkaVersionError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateClusterKafkaVersionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationError {
@@ -3680,6 +4003,18 @@ impl fmt::Display for UpdateConfigurationError {
     }
 }
 impl Error for UpdateConfigurationError {}
+impl ServiceErrorCode for UpdateConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConfigurationError::BadRequest(_) => "BadRequestException",
+            UpdateConfigurationError::Forbidden(_) => "ForbiddenException",
+            UpdateConfigurationError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateConfigurationError::NotFound(_) => "NotFoundException",
+            UpdateConfigurationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateMonitoring
 #[derive(Debug, PartialEq)]
 pub enum UpdateMonitoringError {
@@ -3741,6 +4076,17 @@ impl fmt::Display for UpdateMonitoringError {
     }
 }
 impl Error for UpdateMonitoringError {}
+impl ServiceErrorCode for UpdateMonitoringError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMonitoringError::BadRequest(_) => "BadRequestException",
+            UpdateMonitoringError::Forbidden(_) => "ForbiddenException",
+            UpdateMonitoringError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateMonitoringError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateMonitoringError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Kafka API. Kafka clients implement this trait.
 #[async_trait]
 pub trait Kafka {
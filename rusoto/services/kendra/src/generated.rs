@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -456,6 +456,7 @@ pub struct ConnectionConfiguration {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateDataSourceRequest {
     /// <p>A token that you provide to identify the request to create a data source. Multiple calls to the <code>CreateDataSource</code> operation with the same client token will create only one data source.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -502,6 +503,7 @@ pub struct CreateDataSourceResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateFaqRequest {
     /// <p>A token that you provide to identify the request to create a FAQ. Multiple calls to the <code>CreateFaqRequest</code> operation with the same client token will create only one FAQ. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -544,6 +546,7 @@ pub struct CreateFaqResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateIndexRequest {
     /// <p>A token that you provide to identify the request to create an index. Multiple calls to the <code>CreateIndex</code> operation with the same client token will create only one index.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -592,6 +595,7 @@ pub struct CreateIndexResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateThesaurusRequest {
     /// <p>A token that you provide to identify the request to create a thesaurus. Multiple calls to the <code>CreateThesaurus</code> operation with the same client token will create only one index. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -2510,6 +2514,17 @@ impl fmt::Display for BatchDeleteDocumentError {
     }
 }
 impl Error for BatchDeleteDocumentError {}
+impl ServiceErrorCode for BatchDeleteDocumentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDeleteDocumentError::AccessDenied(_) => "AccessDeniedException",
+            BatchDeleteDocumentError::Conflict(_) => "ConflictException",
+            BatchDeleteDocumentError::InternalServer(_) => "InternalServerException",
+            BatchDeleteDocumentError::ResourceNotFound(_) => "ResourceNotFoundException",
+            BatchDeleteDocumentError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by BatchPutDocument
 #[derive(Debug, PartialEq)]
 pub enum BatchPutDocumentError {
@@ -2572,6 +2587,18 @@ impl fmt::Display for BatchPutDocumentError {
     }
 }
 impl Error for BatchPutDocumentError {}
+impl ServiceErrorCode for BatchPutDocumentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchPutDocumentError::AccessDenied(_) => "AccessDeniedException",
+            BatchPutDocumentError::Conflict(_) => "ConflictException",
+            BatchPutDocumentError::InternalServer(_) => "InternalServerException",
+            BatchPutDocumentError::ResourceNotFound(_) => "ResourceNotFoundException",
+            BatchPutDocumentError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            BatchPutDocumentError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateDataSource
 #[derive(Debug, PartialEq)]
 pub enum CreateDataSourceError {
@@ -2642,6 +2669,19 @@ impl fmt::Display for CreateDataSourceError {
     }
 }
 impl Error for CreateDataSourceError {}
+impl ServiceErrorCode for CreateDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDataSourceError::AccessDenied(_) => "AccessDeniedException",
+            CreateDataSourceError::Conflict(_) => "ConflictException",
+            CreateDataSourceError::InternalServer(_) => "InternalServerException",
+            CreateDataSourceError::ResourceAlreadyExist(_) => "ResourceAlreadyExistException",
+            CreateDataSourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateDataSourceError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateDataSourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateFaq
 #[derive(Debug, PartialEq)]
 pub enum CreateFaqError {
@@ -2702,6 +2742,18 @@ impl fmt::Display for CreateFaqError {
     }
 }
 impl Error for CreateFaqError {}
+impl ServiceErrorCode for CreateFaqError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFaqError::AccessDenied(_) => "AccessDeniedException",
+            CreateFaqError::Conflict(_) => "ConflictException",
+            CreateFaqError::InternalServer(_) => "InternalServerException",
+            CreateFaqError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateFaqError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateFaqError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateIndex
 #[derive(Debug, PartialEq)]
 pub enum CreateIndexError {
@@ -2762,6 +2814,18 @@ impl fmt::Display for CreateIndexError {
     }
 }
 impl Error for CreateIndexError {}
+impl ServiceErrorCode for CreateIndexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateIndexError::AccessDenied(_) => "AccessDeniedException",
+            CreateIndexError::Conflict(_) => "ConflictException",
+            CreateIndexError::InternalServer(_) => "InternalServerException",
+            CreateIndexError::ResourceAlreadyExist(_) => "ResourceAlreadyExistException",
+            CreateIndexError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateIndexError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateThesaurus
 #[derive(Debug, PartialEq)]
 pub enum CreateThesaurusError {
@@ -2824,6 +2888,18 @@ impl fmt::Display for CreateThesaurusError {
     }
 }
 impl Error for CreateThesaurusError {}
+impl ServiceErrorCode for CreateThesaurusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateThesaurusError::AccessDenied(_) => "AccessDeniedException",
+            CreateThesaurusError::Conflict(_) => "ConflictException",
+            CreateThesaurusError::InternalServer(_) => "InternalServerException",
+            CreateThesaurusError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateThesaurusError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateThesaurusError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteDataSource
 #[derive(Debug, PartialEq)]
 pub enum DeleteDataSourceError {
@@ -2878,6 +2954,17 @@ impl fmt::Display for DeleteDataSourceError {
     }
 }
 impl Error for DeleteDataSourceError {}
+impl ServiceErrorCode for DeleteDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDataSourceError::AccessDenied(_) => "AccessDeniedException",
+            DeleteDataSourceError::Conflict(_) => "ConflictException",
+            DeleteDataSourceError::InternalServer(_) => "InternalServerException",
+            DeleteDataSourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDataSourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteFaq
 #[derive(Debug, PartialEq)]
 pub enum DeleteFaqError {
@@ -2932,6 +3019,17 @@ impl fmt::Display for DeleteFaqError {
     }
 }
 impl Error for DeleteFaqError {}
+impl ServiceErrorCode for DeleteFaqError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFaqError::AccessDenied(_) => "AccessDeniedException",
+            DeleteFaqError::Conflict(_) => "ConflictException",
+            DeleteFaqError::InternalServer(_) => "InternalServerException",
+            DeleteFaqError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteFaqError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteIndex
 #[derive(Debug, PartialEq)]
 pub enum DeleteIndexError {
@@ -2986,6 +3084,17 @@ impl fmt::Display for DeleteIndexError {
     }
 }
 impl Error for DeleteIndexError {}
+impl ServiceErrorCode for DeleteIndexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIndexError::AccessDenied(_) => "AccessDeniedException",
+            DeleteIndexError::Conflict(_) => "ConflictException",
+            DeleteIndexError::InternalServer(_) => "InternalServerException",
+            DeleteIndexError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteIndexError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteThesaurus
 #[derive(Debug, PartialEq)]
 pub enum DeleteThesaurusError {
@@ -3040,6 +3149,17 @@ impl fmt::Display for DeleteThesaurusError {
     }
 }
 impl Error for DeleteThesaurusError {}
+impl ServiceErrorCode for DeleteThesaurusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteThesaurusError::AccessDenied(_) => "AccessDeniedException",
+            DeleteThesaurusError::Conflict(_) => "ConflictException",
+            DeleteThesaurusError::InternalServer(_) => "InternalServerException",
+            DeleteThesaurusError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteThesaurusError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeDataSource
 #[derive(Debug, PartialEq)]
 pub enum DescribeDataSourceError {
@@ -3088,6 +3208,16 @@ impl fmt::Display for DescribeDataSourceError {
     }
 }
 impl Error for DescribeDataSourceError {}
+impl ServiceErrorCode for DescribeDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDataSourceError::AccessDenied(_) => "AccessDeniedException",
+            DescribeDataSourceError::InternalServer(_) => "InternalServerException",
+            DescribeDataSourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDataSourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeFaq
 #[derive(Debug, PartialEq)]
 pub enum DescribeFaqError {
@@ -3136,6 +3266,16 @@ impl fmt::Display for DescribeFaqError {
     }
 }
 impl Error for DescribeFaqError {}
+impl ServiceErrorCode for DescribeFaqError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFaqError::AccessDenied(_) => "AccessDeniedException",
+            DescribeFaqError::InternalServer(_) => "InternalServerException",
+            DescribeFaqError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeFaqError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeIndex
 #[derive(Debug, PartialEq)]
 pub enum DescribeIndexError {
@@ -3184,6 +3324,16 @@ impl fmt::Display for DescribeIndexError {
     }
 }
 impl Error for DescribeIndexError {}
+impl ServiceErrorCode for DescribeIndexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeIndexError::AccessDenied(_) => "AccessDeniedException",
+            DescribeIndexError::InternalServer(_) => "InternalServerException",
+            DescribeIndexError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeIndexError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeThesaurus
 #[derive(Debug, PartialEq)]
 pub enum DescribeThesaurusError {
@@ -3232,6 +3382,16 @@ impl fmt::Display for DescribeThesaurusError {
     }
 }
 impl Error for DescribeThesaurusError {}
+impl ServiceErrorCode for DescribeThesaurusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeThesaurusError::AccessDenied(_) => "AccessDeniedException",
+            DescribeThesaurusError::InternalServer(_) => "InternalServerException",
+            DescribeThesaurusError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeThesaurusError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDataSourceSyncJobs
 #[derive(Debug, PartialEq)]
 pub enum ListDataSourceSyncJobsError {
@@ -3290,6 +3450,17 @@ impl fmt::Display for ListDataSourceSyncJobsError {
     }
 }
 impl Error for ListDataSourceSyncJobsError {}
+impl ServiceErrorCode for ListDataSourceSyncJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDataSourceSyncJobsError::AccessDenied(_) => "AccessDeniedException",
+            ListDataSourceSyncJobsError::Conflict(_) => "ConflictException",
+            ListDataSourceSyncJobsError::InternalServer(_) => "InternalServerException",
+            ListDataSourceSyncJobsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListDataSourceSyncJobsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDataSources
 #[derive(Debug, PartialEq)]
 pub enum ListDataSourcesError {
@@ -3338,6 +3509,16 @@ impl fmt::Display for ListDataSourcesError {
     }
 }
 impl Error for ListDataSourcesError {}
+impl ServiceErrorCode for ListDataSourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDataSourcesError::AccessDenied(_) => "AccessDeniedException",
+            ListDataSourcesError::InternalServer(_) => "InternalServerException",
+            ListDataSourcesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListDataSourcesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListFaqs
 #[derive(Debug, PartialEq)]
 pub enum ListFaqsError {
@@ -3386,6 +3567,16 @@ impl fmt::Display for ListFaqsError {
     }
 }
 impl Error for ListFaqsError {}
+impl ServiceErrorCode for ListFaqsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFaqsError::AccessDenied(_) => "AccessDeniedException",
+            ListFaqsError::InternalServer(_) => "InternalServerException",
+            ListFaqsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListFaqsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListIndices
 #[derive(Debug, PartialEq)]
 pub enum ListIndicesError {
@@ -3428,6 +3619,15 @@ impl fmt::Display for ListIndicesError {
     }
 }
 impl Error for ListIndicesError {}
+impl ServiceErrorCode for ListIndicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListIndicesError::AccessDenied(_) => "AccessDeniedException",
+            ListIndicesError::InternalServer(_) => "InternalServerException",
+            ListIndicesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3478,6 +3678,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            ListTagsForResourceError::InternalServer(_) => "InternalServerException",
+            ListTagsForResourceError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            ListTagsForResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListThesauri
 #[derive(Debug, PartialEq)]
 pub enum ListThesauriError {
@@ -3526,6 +3736,16 @@ impl fmt::Display for ListThesauriError {
     }
 }
 impl Error for ListThesauriError {}
+impl ServiceErrorCode for ListThesauriError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListThesauriError::AccessDenied(_) => "AccessDeniedException",
+            ListThesauriError::InternalServer(_) => "InternalServerException",
+            ListThesauriError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListThesauriError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by Query
 #[derive(Debug, PartialEq)]
 pub enum QueryError {
@@ -3584,6 +3804,18 @@ impl fmt::Display for QueryError {
     }
 }
 impl Error for QueryError {}
+impl ServiceErrorCode for QueryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            QueryError::AccessDenied(_) => "AccessDeniedException",
+            QueryError::Conflict(_) => "ConflictException",
+            QueryError::InternalServer(_) => "InternalServerException",
+            QueryError::ResourceNotFound(_) => "ResourceNotFoundException",
+            QueryError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            QueryError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StartDataSourceSyncJob
 #[derive(Debug, PartialEq)]
 pub enum StartDataSourceSyncJobError {
@@ -3650,6 +3882,18 @@ impl fmt::Display for StartDataSourceSyncJobError {
     }
 }
 impl Error for StartDataSourceSyncJobError {}
+impl ServiceErrorCode for StartDataSourceSyncJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDataSourceSyncJobError::AccessDenied(_) => "AccessDeniedException",
+            StartDataSourceSyncJobError::Conflict(_) => "ConflictException",
+            StartDataSourceSyncJobError::InternalServer(_) => "InternalServerException",
+            StartDataSourceSyncJobError::ResourceInUse(_) => "ResourceInUseException",
+            StartDataSourceSyncJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartDataSourceSyncJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StopDataSourceSyncJob
 #[derive(Debug, PartialEq)]
 pub enum StopDataSourceSyncJobError {
@@ -3702,6 +3946,16 @@ impl fmt::Display for StopDataSourceSyncJobError {
     }
 }
 impl Error for StopDataSourceSyncJobError {}
+impl ServiceErrorCode for StopDataSourceSyncJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopDataSourceSyncJobError::AccessDenied(_) => "AccessDeniedException",
+            StopDataSourceSyncJobError::InternalServer(_) => "InternalServerException",
+            StopDataSourceSyncJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StopDataSourceSyncJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by SubmitFeedback
 #[derive(Debug, PartialEq)]
 pub enum SubmitFeedbackError {
@@ -3756,6 +4010,17 @@ impl fmt::Display for SubmitFeedbackError {
     }
 }
 impl Error for SubmitFeedbackError {}
+impl ServiceErrorCode for SubmitFeedbackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SubmitFeedbackError::AccessDenied(_) => "AccessDeniedException",
+            SubmitFeedbackError::InternalServer(_) => "InternalServerException",
+            SubmitFeedbackError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SubmitFeedbackError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            SubmitFeedbackError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -3804,6 +4069,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AccessDenied(_) => "AccessDeniedException",
+            TagResourceError::InternalServer(_) => "InternalServerException",
+            TagResourceError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            TagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3852,6 +4127,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AccessDenied(_) => "AccessDeniedException",
+            UntagResourceError::InternalServer(_) => "InternalServerException",
+            UntagResourceError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            UntagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateDataSource
 #[derive(Debug, PartialEq)]
 pub enum UpdateDataSourceError {
@@ -3906,6 +4191,17 @@ impl fmt::Display for UpdateDataSourceError {
     }
 }
 impl Error for UpdateDataSourceError {}
+impl ServiceErrorCode for UpdateDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDataSourceError::AccessDenied(_) => "AccessDeniedException",
+            UpdateDataSourceError::Conflict(_) => "ConflictException",
+            UpdateDataSourceError::InternalServer(_) => "InternalServerException",
+            UpdateDataSourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDataSourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateIndex
 #[derive(Debug, PartialEq)]
 pub enum UpdateIndexError {
@@ -3966,6 +4262,18 @@ impl fmt::Display for UpdateIndexError {
     }
 }
 impl Error for UpdateIndexError {}
+impl ServiceErrorCode for UpdateIndexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateIndexError::AccessDenied(_) => "AccessDeniedException",
+            UpdateIndexError::Conflict(_) => "ConflictException",
+            UpdateIndexError::InternalServer(_) => "InternalServerException",
+            UpdateIndexError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateIndexError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            UpdateIndexError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateThesaurus
 #[derive(Debug, PartialEq)]
 pub enum UpdateThesaurusError {
@@ -4020,6 +4328,17 @@ impl fmt::Display for UpdateThesaurusError {
     }
 }
 impl Error for UpdateThesaurusError {}
+impl ServiceErrorCode for UpdateThesaurusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateThesaurusError::AccessDenied(_) => "AccessDeniedException",
+            UpdateThesaurusError::Conflict(_) => "ConflictException",
+            UpdateThesaurusError::InternalServer(_) => "InternalServerException",
+            UpdateThesaurusError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateThesaurusError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the kendra API. kendra clients implement this trait.
 #[async_trait]
 pub trait Kendra {
@@ -4275,6 +4594,10 @@ impl Kendra for KendraClient {
         &self,
         input: CreateDataSourceRequest,
     ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -4293,6 +4616,10 @@ impl Kendra for KendraClient {
         &self,
         input: CreateFaqRequest,
     ) -> Result<CreateFaqResponse, RusotoError<CreateFaqError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateFaq");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -4311,6 +4638,10 @@ impl Kendra for KendraClient {
         &self,
         input: CreateIndexRequest,
     ) -> Result<CreateIndexResponse, RusotoError<CreateIndexError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateIndex");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -4329,6 +4660,10 @@ impl Kendra for KendraClient {
         &self,
         input: CreateThesaurusRequest,
     ) -> Result<CreateThesaurusResponse, RusotoError<CreateThesaurusError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateThesaurus");
         let encoded = serde_json::to_string(&input).unwrap();
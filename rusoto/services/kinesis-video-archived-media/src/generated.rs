@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -404,6 +404,21 @@ impl fmt::Display for GetClipError {
     }
 }
 impl Error for GetClipError {}
+impl ServiceErrorCode for GetClipError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetClipError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            GetClipError::InvalidArgument(_) => "InvalidArgumentException",
+            GetClipError::InvalidCodecPrivateData(_) => "InvalidCodecPrivateDataException",
+            GetClipError::InvalidMediaFrame(_) => "InvalidMediaFrameException",
+            GetClipError::MissingCodecPrivateData(_) => "MissingCodecPrivateDataException",
+            GetClipError::NoDataRetention(_) => "NoDataRetentionException",
+            GetClipError::NotAuthorized(_) => "NotAuthorizedException",
+            GetClipError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetClipError::UnsupportedStreamMediaType(_) => "UnsupportedStreamMediaTypeException",
+        }
+    }
+}
 /// Errors returned by GetDASHStreamingSessionURL
 #[derive(Debug, PartialEq)]
 pub enum GetDASHStreamingSessionURLError {
@@ -502,6 +517,28 @@ impl fmt::Display for GetDASHStreamingSessionURLError {
     }
 }
 impl Error for GetDASHStreamingSessionURLError {}
+impl ServiceErrorCode for GetDASHStreamingSessionURLError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDASHStreamingSessionURLError::ClientLimitExceeded(_) => {
+                "ClientLimitExceededException"
+            }
+            GetDASHStreamingSessionURLError::InvalidArgument(_) => "InvalidArgumentException",
+            GetDASHStreamingSessionURLError::InvalidCodecPrivateData(_) => {
+                "InvalidCodecPrivateDataException"
+            }
+            GetDASHStreamingSessionURLError::MissingCodecPrivateData(_) => {
+                "MissingCodecPrivateDataException"
+            }
+            GetDASHStreamingSessionURLError::NoDataRetention(_) => "NoDataRetentionException",
+            GetDASHStreamingSessionURLError::NotAuthorized(_) => "NotAuthorizedException",
+            GetDASHStreamingSessionURLError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetDASHStreamingSessionURLError::UnsupportedStreamMediaType(_) => {
+                "UnsupportedStreamMediaTypeException"
+            }
+        }
+    }
+}
 /// Errors returned by GetHLSStreamingSessionURL
 #[derive(Debug, PartialEq)]
 pub enum GetHLSStreamingSessionURLError {
@@ -598,6 +635,28 @@ impl fmt::Display for GetHLSStreamingSessionURLError {
     }
 }
 impl Error for GetHLSStreamingSessionURLError {}
+impl ServiceErrorCode for GetHLSStreamingSessionURLError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetHLSStreamingSessionURLError::ClientLimitExceeded(_) => {
+                "ClientLimitExceededException"
+            }
+            GetHLSStreamingSessionURLError::InvalidArgument(_) => "InvalidArgumentException",
+            GetHLSStreamingSessionURLError::InvalidCodecPrivateData(_) => {
+                "InvalidCodecPrivateDataException"
+            }
+            GetHLSStreamingSessionURLError::MissingCodecPrivateData(_) => {
+                "MissingCodecPrivateDataException"
+            }
+            GetHLSStreamingSessionURLError::NoDataRetention(_) => "NoDataRetentionException",
+            GetHLSStreamingSessionURLError::NotAuthorized(_) => "NotAuthorizedException",
+            GetHLSStreamingSessionURLError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetHLSStreamingSessionURLError::UnsupportedStreamMediaType(_) => {
+                "UnsupportedStreamMediaTypeException"
+            }
+        }
+    }
+}
 /// Errors returned by GetMediaForFragmentList
 #[derive(Debug, PartialEq)]
 pub enum GetMediaForFragmentListError {
@@ -654,6 +713,16 @@ impl fmt::Display for GetMediaForFragmentListError {
     }
 }
 impl Error for GetMediaForFragmentListError {}
+impl ServiceErrorCode for GetMediaForFragmentListError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMediaForFragmentListError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            GetMediaForFragmentListError::InvalidArgument(_) => "InvalidArgumentException",
+            GetMediaForFragmentListError::NotAuthorized(_) => "NotAuthorizedException",
+            GetMediaForFragmentListError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListFragments
 #[derive(Debug, PartialEq)]
 pub enum ListFragmentsError {
@@ -702,6 +771,16 @@ impl fmt::Display for ListFragmentsError {
     }
 }
 impl Error for ListFragmentsError {}
+impl ServiceErrorCode for ListFragmentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFragmentsError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            ListFragmentsError::InvalidArgument(_) => "InvalidArgumentException",
+            ListFragmentsError::NotAuthorized(_) => "NotAuthorizedException",
+            ListFragmentsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Kinesis Video Archived Media API. Kinesis Video Archived Media clients implement this trait.
 #[async_trait]
 pub trait KinesisVideoArchivedMedia {
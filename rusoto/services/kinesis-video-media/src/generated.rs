@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -129,6 +129,18 @@ impl fmt::Display for GetMediaError {
     }
 }
 impl Error for GetMediaError {}
+impl ServiceErrorCode for GetMediaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMediaError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            GetMediaError::ConnectionLimitExceeded(_) => "ConnectionLimitExceededException",
+            GetMediaError::InvalidArgument(_) => "InvalidArgumentException",
+            GetMediaError::InvalidEndpoint(_) => "InvalidEndpointException",
+            GetMediaError::NotAuthorized(_) => "NotAuthorizedException",
+            GetMediaError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Kinesis Video Media API. Kinesis Video Media clients implement this trait.
 #[async_trait]
 pub trait KinesisVideoMedia {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -160,6 +160,18 @@ impl fmt::Display for GetIceServerConfigError {
     }
 }
 impl Error for GetIceServerConfigError {}
+impl ServiceErrorCode for GetIceServerConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIceServerConfigError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            GetIceServerConfigError::InvalidArgument(_) => "InvalidArgumentException",
+            GetIceServerConfigError::InvalidClient(_) => "InvalidClientException",
+            GetIceServerConfigError::NotAuthorized(_) => "NotAuthorizedException",
+            GetIceServerConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetIceServerConfigError::SessionExpired(_) => "SessionExpiredException",
+        }
+    }
+}
 /// Errors returned by SendAlexaOfferToMaster
 #[derive(Debug, PartialEq)]
 pub enum SendAlexaOfferToMasterError {
@@ -216,6 +228,16 @@ impl fmt::Display for SendAlexaOfferToMasterError {
     }
 }
 impl Error for SendAlexaOfferToMasterError {}
+impl ServiceErrorCode for SendAlexaOfferToMasterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendAlexaOfferToMasterError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            SendAlexaOfferToMasterError::InvalidArgument(_) => "InvalidArgumentException",
+            SendAlexaOfferToMasterError::NotAuthorized(_) => "NotAuthorizedException",
+            SendAlexaOfferToMasterError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Kinesis Video Signaling Channels API. Amazon Kinesis Video Signaling Channels clients implement this trait.
 #[async_trait]
 pub trait KinesisVideoSignaling {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1218,6 +1218,16 @@ impl fmt::Display for AddTagsToStreamError {
     }
 }
 impl Error for AddTagsToStreamError {}
+impl ServiceErrorCode for AddTagsToStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsToStreamError::InvalidArgument(_) => "InvalidArgumentException",
+            AddTagsToStreamError::LimitExceeded(_) => "LimitExceededException",
+            AddTagsToStreamError::ResourceInUse(_) => "ResourceInUseException",
+            AddTagsToStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateStream
 #[derive(Debug, PartialEq)]
 pub enum CreateStreamError {
@@ -1260,6 +1270,15 @@ impl fmt::Display for CreateStreamError {
     }
 }
 impl Error for CreateStreamError {}
+impl ServiceErrorCode for CreateStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStreamError::InvalidArgument(_) => "InvalidArgumentException",
+            CreateStreamError::LimitExceeded(_) => "LimitExceededException",
+            CreateStreamError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DecreaseStreamRetentionPeriod
 #[derive(Debug, PartialEq)]
 pub enum DecreaseStreamRetentionPeriodError {
@@ -1322,6 +1341,16 @@ impl fmt::Display for DecreaseStreamRetentionPeriodError {
     }
 }
 impl Error for DecreaseStreamRetentionPeriodError {}
+impl ServiceErrorCode for DecreaseStreamRetentionPeriodError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DecreaseStreamRetentionPeriodError::InvalidArgument(_) => "InvalidArgumentException",
+            DecreaseStreamRetentionPeriodError::LimitExceeded(_) => "LimitExceededException",
+            DecreaseStreamRetentionPeriodError::ResourceInUse(_) => "ResourceInUseException",
+            DecreaseStreamRetentionPeriodError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteStream
 #[derive(Debug, PartialEq)]
 pub enum DeleteStreamError {
@@ -1364,6 +1393,15 @@ impl fmt::Display for DeleteStreamError {
     }
 }
 impl Error for DeleteStreamError {}
+impl ServiceErrorCode for DeleteStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStreamError::LimitExceeded(_) => "LimitExceededException",
+            DeleteStreamError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeregisterStreamConsumer
 #[derive(Debug, PartialEq)]
 pub enum DeregisterStreamConsumerError {
@@ -1412,6 +1450,15 @@ impl fmt::Display for DeregisterStreamConsumerError {
     }
 }
 impl Error for DeregisterStreamConsumerError {}
+impl ServiceErrorCode for DeregisterStreamConsumerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterStreamConsumerError::InvalidArgument(_) => "InvalidArgumentException",
+            DeregisterStreamConsumerError::LimitExceeded(_) => "LimitExceededException",
+            DeregisterStreamConsumerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeLimits
 #[derive(Debug, PartialEq)]
 pub enum DescribeLimitsError {
@@ -1442,6 +1489,13 @@ impl fmt::Display for DescribeLimitsError {
     }
 }
 impl Error for DescribeLimitsError {}
+impl ServiceErrorCode for DescribeLimitsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLimitsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DescribeStream
 #[derive(Debug, PartialEq)]
 pub enum DescribeStreamError {
@@ -1478,6 +1532,14 @@ impl fmt::Display for DescribeStreamError {
     }
 }
 impl Error for DescribeStreamError {}
+impl ServiceErrorCode for DescribeStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStreamError::LimitExceeded(_) => "LimitExceededException",
+            DescribeStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeStreamConsumer
 #[derive(Debug, PartialEq)]
 pub enum DescribeStreamConsumerError {
@@ -1526,6 +1588,15 @@ impl fmt::Display for DescribeStreamConsumerError {
     }
 }
 impl Error for DescribeStreamConsumerError {}
+impl ServiceErrorCode for DescribeStreamConsumerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStreamConsumerError::InvalidArgument(_) => "InvalidArgumentException",
+            DescribeStreamConsumerError::LimitExceeded(_) => "LimitExceededException",
+            DescribeStreamConsumerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeStreamSummary
 #[derive(Debug, PartialEq)]
 pub enum DescribeStreamSummaryError {
@@ -1564,6 +1635,14 @@ impl fmt::Display for DescribeStreamSummaryError {
     }
 }
 impl Error for DescribeStreamSummaryError {}
+impl ServiceErrorCode for DescribeStreamSummaryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStreamSummaryError::LimitExceeded(_) => "LimitExceededException",
+            DescribeStreamSummaryError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisableEnhancedMonitoring
 #[derive(Debug, PartialEq)]
 pub enum DisableEnhancedMonitoringError {
@@ -1620,6 +1699,16 @@ impl fmt::Display for DisableEnhancedMonitoringError {
     }
 }
 impl Error for DisableEnhancedMonitoringError {}
+impl ServiceErrorCode for DisableEnhancedMonitoringError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableEnhancedMonitoringError::InvalidArgument(_) => "InvalidArgumentException",
+            DisableEnhancedMonitoringError::LimitExceeded(_) => "LimitExceededException",
+            DisableEnhancedMonitoringError::ResourceInUse(_) => "ResourceInUseException",
+            DisableEnhancedMonitoringError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by EnableEnhancedMonitoring
 #[derive(Debug, PartialEq)]
 pub enum EnableEnhancedMonitoringError {
@@ -1676,6 +1765,16 @@ impl fmt::Display for EnableEnhancedMonitoringError {
     }
 }
 impl Error for EnableEnhancedMonitoringError {}
+impl ServiceErrorCode for EnableEnhancedMonitoringError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableEnhancedMonitoringError::InvalidArgument(_) => "InvalidArgumentException",
+            EnableEnhancedMonitoringError::LimitExceeded(_) => "LimitExceededException",
+            EnableEnhancedMonitoringError::ResourceInUse(_) => "ResourceInUseException",
+            EnableEnhancedMonitoringError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetRecords
 #[derive(Debug, PartialEq)]
 pub enum GetRecordsError {
@@ -1762,6 +1861,24 @@ impl fmt::Display for GetRecordsError {
     }
 }
 impl Error for GetRecordsError {}
+impl ServiceErrorCode for GetRecordsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRecordsError::ExpiredIterator(_) => "ExpiredIteratorException",
+            GetRecordsError::InvalidArgument(_) => "InvalidArgumentException",
+            GetRecordsError::KMSAccessDenied(_) => "KMSAccessDeniedException",
+            GetRecordsError::KMSDisabled(_) => "KMSDisabledException",
+            GetRecordsError::KMSInvalidState(_) => "KMSInvalidStateException",
+            GetRecordsError::KMSNotFound(_) => "KMSNotFoundException",
+            GetRecordsError::KMSOptInRequired(_) => "KMSOptInRequired",
+            GetRecordsError::KMSThrottling(_) => "KMSThrottlingException",
+            GetRecordsError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            GetRecordsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetShardIterator
 #[derive(Debug, PartialEq)]
 pub enum GetShardIteratorError {
@@ -1808,6 +1925,17 @@ impl fmt::Display for GetShardIteratorError {
     }
 }
 impl Error for GetShardIteratorError {}
+impl ServiceErrorCode for GetShardIteratorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetShardIteratorError::InvalidArgument(_) => "InvalidArgumentException",
+            GetShardIteratorError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            GetShardIteratorError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by IncreaseStreamRetentionPeriod
 #[derive(Debug, PartialEq)]
 pub enum IncreaseStreamRetentionPeriodError {
@@ -1870,6 +1998,16 @@ impl fmt::Display for IncreaseStreamRetentionPeriodError {
     }
 }
 impl Error for IncreaseStreamRetentionPeriodError {}
+impl ServiceErrorCode for IncreaseStreamRetentionPeriodError {
+    fn error_code(&self) -> &str {
+        match *self {
+            IncreaseStreamRetentionPeriodError::InvalidArgument(_) => "InvalidArgumentException",
+            IncreaseStreamRetentionPeriodError::LimitExceeded(_) => "LimitExceededException",
+            IncreaseStreamRetentionPeriodError::ResourceInUse(_) => "ResourceInUseException",
+            IncreaseStreamRetentionPeriodError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListShards
 #[derive(Debug, PartialEq)]
 pub enum ListShardsError {
@@ -1924,6 +2062,17 @@ impl fmt::Display for ListShardsError {
     }
 }
 impl Error for ListShardsError {}
+impl ServiceErrorCode for ListShardsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListShardsError::ExpiredNextToken(_) => "ExpiredNextTokenException",
+            ListShardsError::InvalidArgument(_) => "InvalidArgumentException",
+            ListShardsError::LimitExceeded(_) => "LimitExceededException",
+            ListShardsError::ResourceInUse(_) => "ResourceInUseException",
+            ListShardsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListStreamConsumers
 #[derive(Debug, PartialEq)]
 pub enum ListStreamConsumersError {
@@ -1982,6 +2131,17 @@ impl fmt::Display for ListStreamConsumersError {
     }
 }
 impl Error for ListStreamConsumersError {}
+impl ServiceErrorCode for ListStreamConsumersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStreamConsumersError::ExpiredNextToken(_) => "ExpiredNextTokenException",
+            ListStreamConsumersError::InvalidArgument(_) => "InvalidArgumentException",
+            ListStreamConsumersError::LimitExceeded(_) => "LimitExceededException",
+            ListStreamConsumersError::ResourceInUse(_) => "ResourceInUseException",
+            ListStreamConsumersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListStreams
 #[derive(Debug, PartialEq)]
 pub enum ListStreamsError {
@@ -2012,6 +2172,13 @@ impl fmt::Display for ListStreamsError {
     }
 }
 impl Error for ListStreamsError {}
+impl ServiceErrorCode for ListStreamsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStreamsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by ListTagsForStream
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForStreamError {
@@ -2054,6 +2221,15 @@ impl fmt::Display for ListTagsForStreamError {
     }
 }
 impl Error for ListTagsForStreamError {}
+impl ServiceErrorCode for ListTagsForStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForStreamError::InvalidArgument(_) => "InvalidArgumentException",
+            ListTagsForStreamError::LimitExceeded(_) => "LimitExceededException",
+            ListTagsForStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by MergeShards
 #[derive(Debug, PartialEq)]
 pub enum MergeShardsError {
@@ -2102,6 +2278,16 @@ impl fmt::Display for MergeShardsError {
     }
 }
 impl Error for MergeShardsError {}
+impl ServiceErrorCode for MergeShardsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            MergeShardsError::InvalidArgument(_) => "InvalidArgumentException",
+            MergeShardsError::LimitExceeded(_) => "LimitExceededException",
+            MergeShardsError::ResourceInUse(_) => "ResourceInUseException",
+            MergeShardsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutRecord
 #[derive(Debug, PartialEq)]
 pub enum PutRecordError {
@@ -2182,6 +2368,23 @@ impl fmt::Display for PutRecordError {
     }
 }
 impl Error for PutRecordError {}
+impl ServiceErrorCode for PutRecordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRecordError::InvalidArgument(_) => "InvalidArgumentException",
+            PutRecordError::KMSAccessDenied(_) => "KMSAccessDeniedException",
+            PutRecordError::KMSDisabled(_) => "KMSDisabledException",
+            PutRecordError::KMSInvalidState(_) => "KMSInvalidStateException",
+            PutRecordError::KMSNotFound(_) => "KMSNotFoundException",
+            PutRecordError::KMSOptInRequired(_) => "KMSOptInRequired",
+            PutRecordError::KMSThrottling(_) => "KMSThrottlingException",
+            PutRecordError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            PutRecordError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutRecords
 #[derive(Debug, PartialEq)]
 pub enum PutRecordsError {
@@ -2262,6 +2465,23 @@ impl fmt::Display for PutRecordsError {
     }
 }
 impl Error for PutRecordsError {}
+impl ServiceErrorCode for PutRecordsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRecordsError::InvalidArgument(_) => "InvalidArgumentException",
+            PutRecordsError::KMSAccessDenied(_) => "KMSAccessDeniedException",
+            PutRecordsError::KMSDisabled(_) => "KMSDisabledException",
+            PutRecordsError::KMSInvalidState(_) => "KMSInvalidStateException",
+            PutRecordsError::KMSNotFound(_) => "KMSNotFoundException",
+            PutRecordsError::KMSOptInRequired(_) => "KMSOptInRequired",
+            PutRecordsError::KMSThrottling(_) => "KMSThrottlingException",
+            PutRecordsError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            PutRecordsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RegisterStreamConsumer
 #[derive(Debug, PartialEq)]
 pub enum RegisterStreamConsumerError {
@@ -2318,6 +2538,16 @@ impl fmt::Display for RegisterStreamConsumerError {
     }
 }
 impl Error for RegisterStreamConsumerError {}
+impl ServiceErrorCode for RegisterStreamConsumerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterStreamConsumerError::InvalidArgument(_) => "InvalidArgumentException",
+            RegisterStreamConsumerError::LimitExceeded(_) => "LimitExceededException",
+            RegisterStreamConsumerError::ResourceInUse(_) => "ResourceInUseException",
+            RegisterStreamConsumerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RemoveTagsFromStream
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromStreamError {
@@ -2370,6 +2600,16 @@ impl fmt::Display for RemoveTagsFromStreamError {
     }
 }
 impl Error for RemoveTagsFromStreamError {}
+impl ServiceErrorCode for RemoveTagsFromStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsFromStreamError::InvalidArgument(_) => "InvalidArgumentException",
+            RemoveTagsFromStreamError::LimitExceeded(_) => "LimitExceededException",
+            RemoveTagsFromStreamError::ResourceInUse(_) => "ResourceInUseException",
+            RemoveTagsFromStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by SplitShard
 #[derive(Debug, PartialEq)]
 pub enum SplitShardError {
@@ -2418,6 +2658,16 @@ impl fmt::Display for SplitShardError {
     }
 }
 impl Error for SplitShardError {}
+impl ServiceErrorCode for SplitShardError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SplitShardError::InvalidArgument(_) => "InvalidArgumentException",
+            SplitShardError::LimitExceeded(_) => "LimitExceededException",
+            SplitShardError::ResourceInUse(_) => "ResourceInUseException",
+            SplitShardError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartStreamEncryption
 #[derive(Debug, PartialEq)]
 pub enum StartStreamEncryptionError {
@@ -2512,6 +2762,22 @@ impl fmt::Display for StartStreamEncryptionError {
     }
 }
 impl Error for StartStreamEncryptionError {}
+impl ServiceErrorCode for StartStreamEncryptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartStreamEncryptionError::InvalidArgument(_) => "InvalidArgumentException",
+            StartStreamEncryptionError::KMSAccessDenied(_) => "KMSAccessDeniedException",
+            StartStreamEncryptionError::KMSDisabled(_) => "KMSDisabledException",
+            StartStreamEncryptionError::KMSInvalidState(_) => "KMSInvalidStateException",
+            StartStreamEncryptionError::KMSNotFound(_) => "KMSNotFoundException",
+            StartStreamEncryptionError::KMSOptInRequired(_) => "KMSOptInRequired",
+            StartStreamEncryptionError::KMSThrottling(_) => "KMSThrottlingException",
+            StartStreamEncryptionError::LimitExceeded(_) => "LimitExceededException",
+            StartStreamEncryptionError::ResourceInUse(_) => "ResourceInUseException",
+            StartStreamEncryptionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopStreamEncryption
 #[derive(Debug, PartialEq)]
 pub enum StopStreamEncryptionError {
@@ -2564,6 +2830,16 @@ impl fmt::Display for StopStreamEncryptionError {
     }
 }
 impl Error for StopStreamEncryptionError {}
+impl ServiceErrorCode for StopStreamEncryptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopStreamEncryptionError::InvalidArgument(_) => "InvalidArgumentException",
+            StopStreamEncryptionError::LimitExceeded(_) => "LimitExceededException",
+            StopStreamEncryptionError::ResourceInUse(_) => "ResourceInUseException",
+            StopStreamEncryptionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by SubscribeToShard
 #[derive(Debug, PartialEq)]
 pub enum SubscribeToShardError {
@@ -2612,6 +2888,16 @@ impl fmt::Display for SubscribeToShardError {
     }
 }
 impl Error for SubscribeToShardError {}
+impl ServiceErrorCode for SubscribeToShardError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SubscribeToShardError::InvalidArgument(_) => "InvalidArgumentException",
+            SubscribeToShardError::LimitExceeded(_) => "LimitExceededException",
+            SubscribeToShardError::ResourceInUse(_) => "ResourceInUseException",
+            SubscribeToShardError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateShardCount
 #[derive(Debug, PartialEq)]
 pub enum UpdateShardCountError {
@@ -2660,6 +2946,16 @@ impl fmt::Display for UpdateShardCountError {
     }
 }
 impl Error for UpdateShardCountError {}
+impl ServiceErrorCode for UpdateShardCountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateShardCountError::InvalidArgument(_) => "InvalidArgumentException",
+            UpdateShardCountError::LimitExceeded(_) => "LimitExceededException",
+            UpdateShardCountError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateShardCountError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Kinesis API. Kinesis clients implement this trait.
 #[async_trait]
 pub trait Kinesis {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1407,6 +1407,27 @@ impl fmt::Display for AddApplicationCloudWatchLoggingOptionError {
     }
 }
 impl Error for AddApplicationCloudWatchLoggingOptionError {}
+impl ServiceErrorCode for AddApplicationCloudWatchLoggingOptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddApplicationCloudWatchLoggingOptionError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AddApplicationCloudWatchLoggingOptionError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+            AddApplicationCloudWatchLoggingOptionError::ResourceInUse(_) => {
+                "ResourceInUseException"
+            }
+            AddApplicationCloudWatchLoggingOptionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            AddApplicationCloudWatchLoggingOptionError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by AddApplicationInput
 #[derive(Debug, PartialEq)]
 pub enum AddApplicationInputError {
@@ -1473,6 +1494,20 @@ impl fmt::Display for AddApplicationInputError {
     }
 }
 impl Error for AddApplicationInputError {}
+impl ServiceErrorCode for AddApplicationInputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddApplicationInputError::CodeValidation(_) => "CodeValidationException",
+            AddApplicationInputError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AddApplicationInputError::InvalidArgument(_) => "InvalidArgumentException",
+            AddApplicationInputError::ResourceInUse(_) => "ResourceInUseException",
+            AddApplicationInputError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AddApplicationInputError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by AddApplicationInputProcessingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum AddApplicationInputProcessingConfigurationError {
@@ -1553,6 +1588,27 @@ impl fmt::Display for AddApplicationInputProcessingConfigurationError {
     }
 }
 impl Error for AddApplicationInputProcessingConfigurationError {}
+impl ServiceErrorCode for AddApplicationInputProcessingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddApplicationInputProcessingConfigurationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AddApplicationInputProcessingConfigurationError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+            AddApplicationInputProcessingConfigurationError::ResourceInUse(_) => {
+                "ResourceInUseException"
+            }
+            AddApplicationInputProcessingConfigurationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            AddApplicationInputProcessingConfigurationError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by AddApplicationOutput
 #[derive(Debug, PartialEq)]
 pub enum AddApplicationOutputError {
@@ -1615,6 +1671,19 @@ impl fmt::Display for AddApplicationOutputError {
     }
 }
 impl Error for AddApplicationOutputError {}
+impl ServiceErrorCode for AddApplicationOutputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddApplicationOutputError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AddApplicationOutputError::InvalidArgument(_) => "InvalidArgumentException",
+            AddApplicationOutputError::ResourceInUse(_) => "ResourceInUseException",
+            AddApplicationOutputError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AddApplicationOutputError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by AddApplicationReferenceDataSource
 #[derive(Debug, PartialEq)]
 pub enum AddApplicationReferenceDataSourceError {
@@ -1691,6 +1760,25 @@ impl fmt::Display for AddApplicationReferenceDataSourceError {
     }
 }
 impl Error for AddApplicationReferenceDataSourceError {}
+impl ServiceErrorCode for AddApplicationReferenceDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddApplicationReferenceDataSourceError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AddApplicationReferenceDataSourceError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+            AddApplicationReferenceDataSourceError::ResourceInUse(_) => "ResourceInUseException",
+            AddApplicationReferenceDataSourceError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            AddApplicationReferenceDataSourceError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateApplication
 #[derive(Debug, PartialEq)]
 pub enum CreateApplicationError {
@@ -1753,6 +1841,18 @@ impl fmt::Display for CreateApplicationError {
     }
 }
 impl Error for CreateApplicationError {}
+impl ServiceErrorCode for CreateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApplicationError::CodeValidation(_) => "CodeValidationException",
+            CreateApplicationError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateApplicationError::InvalidArgument(_) => "InvalidArgumentException",
+            CreateApplicationError::LimitExceeded(_) => "LimitExceededException",
+            CreateApplicationError::ResourceInUse(_) => "ResourceInUseException",
+            CreateApplicationError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by DeleteApplication
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationError {
@@ -1805,6 +1905,16 @@ impl fmt::Display for DeleteApplicationError {
     }
 }
 impl Error for DeleteApplicationError {}
+impl ServiceErrorCode for DeleteApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteApplicationError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteApplicationError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DeleteApplicationCloudWatchLoggingOption
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationCloudWatchLoggingOptionError {
@@ -1885,6 +1995,27 @@ impl fmt::Display for DeleteApplicationCloudWatchLoggingOptionError {
     }
 }
 impl Error for DeleteApplicationCloudWatchLoggingOptionError {}
+impl ServiceErrorCode for DeleteApplicationCloudWatchLoggingOptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationCloudWatchLoggingOptionError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteApplicationCloudWatchLoggingOptionError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+            DeleteApplicationCloudWatchLoggingOptionError::ResourceInUse(_) => {
+                "ResourceInUseException"
+            }
+            DeleteApplicationCloudWatchLoggingOptionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DeleteApplicationCloudWatchLoggingOptionError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteApplicationInputProcessingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationInputProcessingConfigurationError {
@@ -1969,6 +2100,27 @@ impl fmt::Display for DeleteApplicationInputProcessingConfigurationError {
     }
 }
 impl Error for DeleteApplicationInputProcessingConfigurationError {}
+impl ServiceErrorCode for DeleteApplicationInputProcessingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationInputProcessingConfigurationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteApplicationInputProcessingConfigurationError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+            DeleteApplicationInputProcessingConfigurationError::ResourceInUse(_) => {
+                "ResourceInUseException"
+            }
+            DeleteApplicationInputProcessingConfigurationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DeleteApplicationInputProcessingConfigurationError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteApplicationOutput
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationOutputError {
@@ -2035,6 +2187,21 @@ impl fmt::Display for DeleteApplicationOutputError {
     }
 }
 impl Error for DeleteApplicationOutputError {}
+impl ServiceErrorCode for DeleteApplicationOutputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationOutputError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteApplicationOutputError::InvalidArgument(_) => "InvalidArgumentException",
+            DeleteApplicationOutputError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteApplicationOutputError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteApplicationOutputError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteApplicationReferenceDataSource
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationReferenceDataSourceError {
@@ -2111,6 +2278,25 @@ impl fmt::Display for DeleteApplicationReferenceDataSourceError {
     }
 }
 impl Error for DeleteApplicationReferenceDataSourceError {}
+impl ServiceErrorCode for DeleteApplicationReferenceDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationReferenceDataSourceError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteApplicationReferenceDataSourceError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+            DeleteApplicationReferenceDataSourceError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteApplicationReferenceDataSourceError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DeleteApplicationReferenceDataSourceError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeApplication
 #[derive(Debug, PartialEq)]
 pub enum DescribeApplicationError {
@@ -2151,6 +2337,14 @@ impl fmt::Display for DescribeApplicationError {
     }
 }
 impl Error for DescribeApplicationError {}
+impl ServiceErrorCode for DescribeApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeApplicationError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DiscoverInputSchema
 #[derive(Debug, PartialEq)]
 pub enum DiscoverInputSchemaError {
@@ -2207,6 +2401,18 @@ impl fmt::Display for DiscoverInputSchemaError {
     }
 }
 impl Error for DiscoverInputSchemaError {}
+impl ServiceErrorCode for DiscoverInputSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DiscoverInputSchemaError::InvalidArgument(_) => "InvalidArgumentException",
+            DiscoverInputSchemaError::ResourceProvisionedThroughputExceeded(_) => {
+                "ResourceProvisionedThroughputExceededException"
+            }
+            DiscoverInputSchemaError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DiscoverInputSchemaError::UnableToDetectSchema(_) => "UnableToDetectSchemaException",
+        }
+    }
+}
 /// Errors returned by ListApplications
 #[derive(Debug, PartialEq)]
 pub enum ListApplicationsError {}
@@ -2229,6 +2435,11 @@ impl fmt::Display for ListApplicationsError {
     }
 }
 impl Error for ListApplicationsError {}
+impl ServiceErrorCode for ListApplicationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2275,6 +2486,17 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            ListTagsForResourceError::InvalidArgument(_) => "InvalidArgumentException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartApplication
 #[derive(Debug, PartialEq)]
 pub enum StartApplicationError {
@@ -2335,6 +2557,19 @@ impl fmt::Display for StartApplicationError {
     }
 }
 impl Error for StartApplicationError {}
+impl ServiceErrorCode for StartApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartApplicationError::InvalidApplicationConfiguration(_) => {
+                "InvalidApplicationConfigurationException"
+            }
+            StartApplicationError::InvalidArgument(_) => "InvalidArgumentException",
+            StartApplicationError::ResourceInUse(_) => "ResourceInUseException",
+            StartApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartApplicationError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by StopApplication
 #[derive(Debug, PartialEq)]
 pub enum StopApplicationError {
@@ -2379,6 +2614,15 @@ impl fmt::Display for StopApplicationError {
     }
 }
 impl Error for StopApplicationError {}
+impl ServiceErrorCode for StopApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopApplicationError::ResourceInUse(_) => "ResourceInUseException",
+            StopApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StopApplicationError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2433,6 +2677,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            TagResourceError::InvalidArgument(_) => "InvalidArgumentException",
+            TagResourceError::ResourceInUse(_) => "ResourceInUseException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2489,6 +2744,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UntagResourceError::InvalidArgument(_) => "InvalidArgumentException",
+            UntagResourceError::ResourceInUse(_) => "ResourceInUseException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
 pub enum UpdateApplicationError {
@@ -2553,6 +2819,18 @@ impl fmt::Display for UpdateApplicationError {
     }
 }
 impl Error for UpdateApplicationError {}
+impl ServiceErrorCode for UpdateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApplicationError::CodeValidation(_) => "CodeValidationException",
+            UpdateApplicationError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateApplicationError::InvalidArgument(_) => "InvalidArgumentException",
+            UpdateApplicationError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateApplicationError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Kinesis Analytics API. Kinesis Analytics clients implement this trait.
 #[async_trait]
 pub trait KinesisAnalytics {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2350,6 +2350,30 @@ impl fmt::Display for AddApplicationCloudWatchLoggingOptionError {
     }
 }
 impl Error for AddApplicationCloudWatchLoggingOptionError {}
+impl ServiceErrorCode for AddApplicationCloudWatchLoggingOptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddApplicationCloudWatchLoggingOptionError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AddApplicationCloudWatchLoggingOptionError::InvalidApplicationConfiguration(_) => {
+                "InvalidApplicationConfigurationException"
+            }
+            AddApplicationCloudWatchLoggingOptionError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+            AddApplicationCloudWatchLoggingOptionError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            AddApplicationCloudWatchLoggingOptionError::ResourceInUse(_) => {
+                "ResourceInUseException"
+            }
+            AddApplicationCloudWatchLoggingOptionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by AddApplicationInput
 #[derive(Debug, PartialEq)]
 pub enum AddApplicationInputError {
@@ -2414,6 +2438,20 @@ impl fmt::Display for AddApplicationInputError {
     }
 }
 impl Error for AddApplicationInputError {}
+impl ServiceErrorCode for AddApplicationInputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddApplicationInputError::CodeValidation(_) => "CodeValidationException",
+            AddApplicationInputError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AddApplicationInputError::InvalidArgument(_) => "InvalidArgumentException",
+            AddApplicationInputError::InvalidRequest(_) => "InvalidRequestException",
+            AddApplicationInputError::ResourceInUse(_) => "ResourceInUseException",
+            AddApplicationInputError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by AddApplicationInputProcessingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum AddApplicationInputProcessingConfigurationError {
@@ -2492,6 +2530,27 @@ impl fmt::Display for AddApplicationInputProcessingConfigurationError {
     }
 }
 impl Error for AddApplicationInputProcessingConfigurationError {}
+impl ServiceErrorCode for AddApplicationInputProcessingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddApplicationInputProcessingConfigurationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AddApplicationInputProcessingConfigurationError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+            AddApplicationInputProcessingConfigurationError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            AddApplicationInputProcessingConfigurationError::ResourceInUse(_) => {
+                "ResourceInUseException"
+            }
+            AddApplicationInputProcessingConfigurationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by AddApplicationOutput
 #[derive(Debug, PartialEq)]
 pub enum AddApplicationOutputError {
@@ -2552,6 +2611,19 @@ impl fmt::Display for AddApplicationOutputError {
     }
 }
 impl Error for AddApplicationOutputError {}
+impl ServiceErrorCode for AddApplicationOutputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddApplicationOutputError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AddApplicationOutputError::InvalidArgument(_) => "InvalidArgumentException",
+            AddApplicationOutputError::InvalidRequest(_) => "InvalidRequestException",
+            AddApplicationOutputError::ResourceInUse(_) => "ResourceInUseException",
+            AddApplicationOutputError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by AddApplicationReferenceDataSource
 #[derive(Debug, PartialEq)]
 pub enum AddApplicationReferenceDataSourceError {
@@ -2628,6 +2700,23 @@ impl fmt::Display for AddApplicationReferenceDataSourceError {
     }
 }
 impl Error for AddApplicationReferenceDataSourceError {}
+impl ServiceErrorCode for AddApplicationReferenceDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddApplicationReferenceDataSourceError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AddApplicationReferenceDataSourceError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+            AddApplicationReferenceDataSourceError::InvalidRequest(_) => "InvalidRequestException",
+            AddApplicationReferenceDataSourceError::ResourceInUse(_) => "ResourceInUseException",
+            AddApplicationReferenceDataSourceError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by AddApplicationVpcConfiguration
 #[derive(Debug, PartialEq)]
 pub enum AddApplicationVpcConfigurationError {
@@ -2704,6 +2793,21 @@ impl fmt::Display for AddApplicationVpcConfigurationError {
     }
 }
 impl Error for AddApplicationVpcConfigurationError {}
+impl ServiceErrorCode for AddApplicationVpcConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddApplicationVpcConfigurationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            AddApplicationVpcConfigurationError::InvalidApplicationConfiguration(_) => {
+                "InvalidApplicationConfigurationException"
+            }
+            AddApplicationVpcConfigurationError::InvalidArgument(_) => "InvalidArgumentException",
+            AddApplicationVpcConfigurationError::ResourceInUse(_) => "ResourceInUseException",
+            AddApplicationVpcConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateApplication
 #[derive(Debug, PartialEq)]
 pub enum CreateApplicationError {
@@ -2772,6 +2876,19 @@ impl fmt::Display for CreateApplicationError {
     }
 }
 impl Error for CreateApplicationError {}
+impl ServiceErrorCode for CreateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApplicationError::CodeValidation(_) => "CodeValidationException",
+            CreateApplicationError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateApplicationError::InvalidArgument(_) => "InvalidArgumentException",
+            CreateApplicationError::InvalidRequest(_) => "InvalidRequestException",
+            CreateApplicationError::LimitExceeded(_) => "LimitExceededException",
+            CreateApplicationError::ResourceInUse(_) => "ResourceInUseException",
+            CreateApplicationError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by CreateApplicationPresignedUrl
 #[derive(Debug, PartialEq)]
 pub enum CreateApplicationPresignedUrlError {
@@ -2826,6 +2943,15 @@ impl fmt::Display for CreateApplicationPresignedUrlError {
     }
 }
 impl Error for CreateApplicationPresignedUrlError {}
+impl ServiceErrorCode for CreateApplicationPresignedUrlError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApplicationPresignedUrlError::InvalidArgument(_) => "InvalidArgumentException",
+            CreateApplicationPresignedUrlError::ResourceInUse(_) => "ResourceInUseException",
+            CreateApplicationPresignedUrlError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateApplicationSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateApplicationSnapshotError {
@@ -2910,6 +3036,23 @@ impl fmt::Display for CreateApplicationSnapshotError {
     }
 }
 impl Error for CreateApplicationSnapshotError {}
+impl ServiceErrorCode for CreateApplicationSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApplicationSnapshotError::InvalidApplicationConfiguration(_) => {
+                "InvalidApplicationConfigurationException"
+            }
+            CreateApplicationSnapshotError::InvalidArgument(_) => "InvalidArgumentException",
+            CreateApplicationSnapshotError::InvalidRequest(_) => "InvalidRequestException",
+            CreateApplicationSnapshotError::LimitExceeded(_) => "LimitExceededException",
+            CreateApplicationSnapshotError::ResourceInUse(_) => "ResourceInUseException",
+            CreateApplicationSnapshotError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateApplicationSnapshotError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteApplication
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationError {
@@ -2976,6 +3119,20 @@ impl fmt::Display for DeleteApplicationError {
     }
 }
 impl Error for DeleteApplicationError {}
+impl ServiceErrorCode for DeleteApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteApplicationError::InvalidApplicationConfiguration(_) => {
+                "InvalidApplicationConfigurationException"
+            }
+            DeleteApplicationError::InvalidArgument(_) => "InvalidArgumentException",
+            DeleteApplicationError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteApplicationError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteApplicationCloudWatchLoggingOption
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationCloudWatchLoggingOptionError {
@@ -3064,6 +3221,30 @@ impl fmt::Display for DeleteApplicationCloudWatchLoggingOptionError {
     }
 }
 impl Error for DeleteApplicationCloudWatchLoggingOptionError {}
+impl ServiceErrorCode for DeleteApplicationCloudWatchLoggingOptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationCloudWatchLoggingOptionError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteApplicationCloudWatchLoggingOptionError::InvalidApplicationConfiguration(_) => {
+                "InvalidApplicationConfigurationException"
+            }
+            DeleteApplicationCloudWatchLoggingOptionError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+            DeleteApplicationCloudWatchLoggingOptionError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            DeleteApplicationCloudWatchLoggingOptionError::ResourceInUse(_) => {
+                "ResourceInUseException"
+            }
+            DeleteApplicationCloudWatchLoggingOptionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteApplicationInputProcessingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationInputProcessingConfigurationError {
@@ -3146,6 +3327,27 @@ impl fmt::Display for DeleteApplicationInputProcessingConfigurationError {
     }
 }
 impl Error for DeleteApplicationInputProcessingConfigurationError {}
+impl ServiceErrorCode for DeleteApplicationInputProcessingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationInputProcessingConfigurationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteApplicationInputProcessingConfigurationError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+            DeleteApplicationInputProcessingConfigurationError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            DeleteApplicationInputProcessingConfigurationError::ResourceInUse(_) => {
+                "ResourceInUseException"
+            }
+            DeleteApplicationInputProcessingConfigurationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteApplicationOutput
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationOutputError {
@@ -3212,6 +3414,19 @@ impl fmt::Display for DeleteApplicationOutputError {
     }
 }
 impl Error for DeleteApplicationOutputError {}
+impl ServiceErrorCode for DeleteApplicationOutputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationOutputError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteApplicationOutputError::InvalidArgument(_) => "InvalidArgumentException",
+            DeleteApplicationOutputError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteApplicationOutputError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteApplicationOutputError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteApplicationReferenceDataSource
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationReferenceDataSourceError {
@@ -3288,6 +3503,25 @@ impl fmt::Display for DeleteApplicationReferenceDataSourceError {
     }
 }
 impl Error for DeleteApplicationReferenceDataSourceError {}
+impl ServiceErrorCode for DeleteApplicationReferenceDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationReferenceDataSourceError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteApplicationReferenceDataSourceError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+            DeleteApplicationReferenceDataSourceError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            DeleteApplicationReferenceDataSourceError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteApplicationReferenceDataSourceError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteApplicationSnapshot
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationSnapshotError {
@@ -3354,6 +3588,19 @@ impl fmt::Display for DeleteApplicationSnapshotError {
     }
 }
 impl Error for DeleteApplicationSnapshotError {}
+impl ServiceErrorCode for DeleteApplicationSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationSnapshotError::InvalidArgument(_) => "InvalidArgumentException",
+            DeleteApplicationSnapshotError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteApplicationSnapshotError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteApplicationSnapshotError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteApplicationSnapshotError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteApplicationVpcConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationVpcConfigurationError {
@@ -3432,6 +3679,25 @@ impl fmt::Display for DeleteApplicationVpcConfigurationError {
     }
 }
 impl Error for DeleteApplicationVpcConfigurationError {}
+impl ServiceErrorCode for DeleteApplicationVpcConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationVpcConfigurationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteApplicationVpcConfigurationError::InvalidApplicationConfiguration(_) => {
+                "InvalidApplicationConfigurationException"
+            }
+            DeleteApplicationVpcConfigurationError::InvalidArgument(_) => {
+                "InvalidArgumentException"
+            }
+            DeleteApplicationVpcConfigurationError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteApplicationVpcConfigurationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeApplication
 #[derive(Debug, PartialEq)]
 pub enum DescribeApplicationError {
@@ -3476,6 +3742,15 @@ impl fmt::Display for DescribeApplicationError {
     }
 }
 impl Error for DescribeApplicationError {}
+impl ServiceErrorCode for DescribeApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeApplicationError::InvalidArgument(_) => "InvalidArgumentException",
+            DescribeApplicationError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeApplicationSnapshot
 #[derive(Debug, PartialEq)]
 pub enum DescribeApplicationSnapshotError {
@@ -3528,6 +3803,17 @@ impl fmt::Display for DescribeApplicationSnapshotError {
     }
 }
 impl Error for DescribeApplicationSnapshotError {}
+impl ServiceErrorCode for DescribeApplicationSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeApplicationSnapshotError::InvalidArgument(_) => "InvalidArgumentException",
+            DescribeApplicationSnapshotError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeApplicationSnapshotError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DiscoverInputSchema
 #[derive(Debug, PartialEq)]
 pub enum DiscoverInputSchemaError {
@@ -3590,6 +3876,19 @@ impl fmt::Display for DiscoverInputSchemaError {
     }
 }
 impl Error for DiscoverInputSchemaError {}
+impl ServiceErrorCode for DiscoverInputSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DiscoverInputSchemaError::InvalidArgument(_) => "InvalidArgumentException",
+            DiscoverInputSchemaError::InvalidRequest(_) => "InvalidRequestException",
+            DiscoverInputSchemaError::ResourceProvisionedThroughputExceeded(_) => {
+                "ResourceProvisionedThroughputExceededException"
+            }
+            DiscoverInputSchemaError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DiscoverInputSchemaError::UnableToDetectSchema(_) => "UnableToDetectSchemaException",
+        }
+    }
+}
 /// Errors returned by ListApplicationSnapshots
 #[derive(Debug, PartialEq)]
 pub enum ListApplicationSnapshotsError {
@@ -3632,6 +3931,16 @@ impl fmt::Display for ListApplicationSnapshotsError {
     }
 }
 impl Error for ListApplicationSnapshotsError {}
+impl ServiceErrorCode for ListApplicationSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListApplicationSnapshotsError::InvalidArgument(_) => "InvalidArgumentException",
+            ListApplicationSnapshotsError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by ListApplications
 #[derive(Debug, PartialEq)]
 pub enum ListApplicationsError {
@@ -3662,6 +3971,13 @@ impl fmt::Display for ListApplicationsError {
     }
 }
 impl Error for ListApplicationsError {}
+impl ServiceErrorCode for ListApplicationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListApplicationsError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3708,6 +4024,17 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            ListTagsForResourceError::InvalidArgument(_) => "InvalidArgumentException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartApplication
 #[derive(Debug, PartialEq)]
 pub enum StartApplicationError {
@@ -3766,6 +4093,19 @@ impl fmt::Display for StartApplicationError {
     }
 }
 impl Error for StartApplicationError {}
+impl ServiceErrorCode for StartApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartApplicationError::InvalidApplicationConfiguration(_) => {
+                "InvalidApplicationConfigurationException"
+            }
+            StartApplicationError::InvalidArgument(_) => "InvalidArgumentException",
+            StartApplicationError::InvalidRequest(_) => "InvalidRequestException",
+            StartApplicationError::ResourceInUse(_) => "ResourceInUseException",
+            StartApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopApplication
 #[derive(Debug, PartialEq)]
 pub enum StopApplicationError {
@@ -3832,6 +4172,20 @@ impl fmt::Display for StopApplicationError {
     }
 }
 impl Error for StopApplicationError {}
+impl ServiceErrorCode for StopApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopApplicationError::ConcurrentModification(_) => "ConcurrentModificationException",
+            StopApplicationError::InvalidApplicationConfiguration(_) => {
+                "InvalidApplicationConfigurationException"
+            }
+            StopApplicationError::InvalidArgument(_) => "InvalidArgumentException",
+            StopApplicationError::InvalidRequest(_) => "InvalidRequestException",
+            StopApplicationError::ResourceInUse(_) => "ResourceInUseException",
+            StopApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -3886,6 +4240,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            TagResourceError::InvalidArgument(_) => "InvalidArgumentException",
+            TagResourceError::ResourceInUse(_) => "ResourceInUseException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3942,6 +4307,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UntagResourceError::InvalidArgument(_) => "InvalidArgumentException",
+            UntagResourceError::ResourceInUse(_) => "ResourceInUseException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
 pub enum UpdateApplicationError {
@@ -4014,6 +4390,21 @@ impl fmt::Display for UpdateApplicationError {
     }
 }
 impl Error for UpdateApplicationError {}
+impl ServiceErrorCode for UpdateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApplicationError::CodeValidation(_) => "CodeValidationException",
+            UpdateApplicationError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateApplicationError::InvalidApplicationConfiguration(_) => {
+                "InvalidApplicationConfigurationException"
+            }
+            UpdateApplicationError::InvalidArgument(_) => "InvalidArgumentException",
+            UpdateApplicationError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateApplicationError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Kinesis Analytics V2 API. Kinesis Analytics V2 clients implement this trait.
 #[async_trait]
 pub trait KinesisAnalyticsV2 {
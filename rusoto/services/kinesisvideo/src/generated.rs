@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -698,6 +698,22 @@ impl fmt::Display for CreateSignalingChannelError {
     }
 }
 impl Error for CreateSignalingChannelError {}
+impl ServiceErrorCode for CreateSignalingChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSignalingChannelError::AccessDenied(_) => "AccessDeniedException",
+            CreateSignalingChannelError::AccountChannelLimitExceeded(_) => {
+                "AccountChannelLimitExceededException"
+            }
+            CreateSignalingChannelError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            CreateSignalingChannelError::InvalidArgument(_) => "InvalidArgumentException",
+            CreateSignalingChannelError::ResourceInUse(_) => "ResourceInUseException",
+            CreateSignalingChannelError::TagsPerResourceExceededLimit(_) => {
+                "TagsPerResourceExceededLimitException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateStream
 #[derive(Debug, PartialEq)]
 pub enum CreateStreamError {
@@ -770,6 +786,23 @@ impl fmt::Display for CreateStreamError {
     }
 }
 impl Error for CreateStreamError {}
+impl ServiceErrorCode for CreateStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStreamError::AccountStreamLimitExceeded(_) => {
+                "AccountStreamLimitExceededException"
+            }
+            CreateStreamError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            CreateStreamError::DeviceStreamLimitExceeded(_) => "DeviceStreamLimitExceededException",
+            CreateStreamError::InvalidArgument(_) => "InvalidArgumentException",
+            CreateStreamError::InvalidDevice(_) => "InvalidDeviceException",
+            CreateStreamError::ResourceInUse(_) => "ResourceInUseException",
+            CreateStreamError::TagsPerResourceExceededLimit(_) => {
+                "TagsPerResourceExceededLimitException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteSignalingChannel
 #[derive(Debug, PartialEq)]
 pub enum DeleteSignalingChannelError {
@@ -840,6 +873,18 @@ impl fmt::Display for DeleteSignalingChannelError {
     }
 }
 impl Error for DeleteSignalingChannelError {}
+impl ServiceErrorCode for DeleteSignalingChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSignalingChannelError::AccessDenied(_) => "AccessDeniedException",
+            DeleteSignalingChannelError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            DeleteSignalingChannelError::InvalidArgument(_) => "InvalidArgumentException",
+            DeleteSignalingChannelError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteSignalingChannelError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteSignalingChannelError::VersionMismatch(_) => "VersionMismatchException",
+        }
+    }
+}
 /// Errors returned by DeleteStream
 #[derive(Debug, PartialEq)]
 pub enum DeleteStreamError {
@@ -900,6 +945,18 @@ impl fmt::Display for DeleteStreamError {
     }
 }
 impl Error for DeleteStreamError {}
+impl ServiceErrorCode for DeleteStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStreamError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            DeleteStreamError::InvalidArgument(_) => "InvalidArgumentException",
+            DeleteStreamError::NotAuthorized(_) => "NotAuthorizedException",
+            DeleteStreamError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteStreamError::VersionMismatch(_) => "VersionMismatchException",
+        }
+    }
+}
 /// Errors returned by DescribeSignalingChannel
 #[derive(Debug, PartialEq)]
 pub enum DescribeSignalingChannelError {
@@ -956,6 +1013,16 @@ impl fmt::Display for DescribeSignalingChannelError {
     }
 }
 impl Error for DescribeSignalingChannelError {}
+impl ServiceErrorCode for DescribeSignalingChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSignalingChannelError::AccessDenied(_) => "AccessDeniedException",
+            DescribeSignalingChannelError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            DescribeSignalingChannelError::InvalidArgument(_) => "InvalidArgumentException",
+            DescribeSignalingChannelError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeStream
 #[derive(Debug, PartialEq)]
 pub enum DescribeStreamError {
@@ -1004,6 +1071,16 @@ impl fmt::Display for DescribeStreamError {
     }
 }
 impl Error for DescribeStreamError {}
+impl ServiceErrorCode for DescribeStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStreamError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            DescribeStreamError::InvalidArgument(_) => "InvalidArgumentException",
+            DescribeStreamError::NotAuthorized(_) => "NotAuthorizedException",
+            DescribeStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetDataEndpoint
 #[derive(Debug, PartialEq)]
 pub enum GetDataEndpointError {
@@ -1052,6 +1129,16 @@ impl fmt::Display for GetDataEndpointError {
     }
 }
 impl Error for GetDataEndpointError {}
+impl ServiceErrorCode for GetDataEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDataEndpointError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            GetDataEndpointError::InvalidArgument(_) => "InvalidArgumentException",
+            GetDataEndpointError::NotAuthorized(_) => "NotAuthorizedException",
+            GetDataEndpointError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetSignalingChannelEndpoint
 #[derive(Debug, PartialEq)]
 pub enum GetSignalingChannelEndpointError {
@@ -1120,6 +1207,19 @@ impl fmt::Display for GetSignalingChannelEndpointError {
     }
 }
 impl Error for GetSignalingChannelEndpointError {}
+impl ServiceErrorCode for GetSignalingChannelEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSignalingChannelEndpointError::AccessDenied(_) => "AccessDeniedException",
+            GetSignalingChannelEndpointError::ClientLimitExceeded(_) => {
+                "ClientLimitExceededException"
+            }
+            GetSignalingChannelEndpointError::InvalidArgument(_) => "InvalidArgumentException",
+            GetSignalingChannelEndpointError::ResourceInUse(_) => "ResourceInUseException",
+            GetSignalingChannelEndpointError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListSignalingChannels
 #[derive(Debug, PartialEq)]
 pub enum ListSignalingChannelsError {
@@ -1166,6 +1266,15 @@ impl fmt::Display for ListSignalingChannelsError {
     }
 }
 impl Error for ListSignalingChannelsError {}
+impl ServiceErrorCode for ListSignalingChannelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSignalingChannelsError::AccessDenied(_) => "AccessDeniedException",
+            ListSignalingChannelsError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            ListSignalingChannelsError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by ListStreams
 #[derive(Debug, PartialEq)]
 pub enum ListStreamsError {
@@ -1202,6 +1311,14 @@ impl fmt::Display for ListStreamsError {
     }
 }
 impl Error for ListStreamsError {}
+impl ServiceErrorCode for ListStreamsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStreamsError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            ListStreamsError::InvalidArgument(_) => "InvalidArgumentException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1254,6 +1371,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            ListTagsForResourceError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            ListTagsForResourceError::InvalidArgument(_) => "InvalidArgumentException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTagsForStream
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForStreamError {
@@ -1312,6 +1439,17 @@ impl fmt::Display for ListTagsForStreamError {
     }
 }
 impl Error for ListTagsForStreamError {}
+impl ServiceErrorCode for ListTagsForStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForStreamError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            ListTagsForStreamError::InvalidArgument(_) => "InvalidArgumentException",
+            ListTagsForStreamError::InvalidResourceFormat(_) => "InvalidResourceFormatException",
+            ListTagsForStreamError::NotAuthorized(_) => "NotAuthorizedException",
+            ListTagsForStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1368,6 +1506,19 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AccessDenied(_) => "AccessDeniedException",
+            TagResourceError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            TagResourceError::InvalidArgument(_) => "InvalidArgumentException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::TagsPerResourceExceededLimit(_) => {
+                "TagsPerResourceExceededLimitException"
+            }
+        }
+    }
+}
 /// Errors returned by TagStream
 #[derive(Debug, PartialEq)]
 pub enum TagStreamError {
@@ -1430,6 +1581,20 @@ impl fmt::Display for TagStreamError {
     }
 }
 impl Error for TagStreamError {}
+impl ServiceErrorCode for TagStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagStreamError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            TagStreamError::InvalidArgument(_) => "InvalidArgumentException",
+            TagStreamError::InvalidResourceFormat(_) => "InvalidResourceFormatException",
+            TagStreamError::NotAuthorized(_) => "NotAuthorizedException",
+            TagStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagStreamError::TagsPerResourceExceededLimit(_) => {
+                "TagsPerResourceExceededLimitException"
+            }
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1478,6 +1643,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AccessDenied(_) => "AccessDeniedException",
+            UntagResourceError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            UntagResourceError::InvalidArgument(_) => "InvalidArgumentException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagStream
 #[derive(Debug, PartialEq)]
 pub enum UntagStreamError {
@@ -1532,6 +1707,17 @@ impl fmt::Display for UntagStreamError {
     }
 }
 impl Error for UntagStreamError {}
+impl ServiceErrorCode for UntagStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagStreamError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            UntagStreamError::InvalidArgument(_) => "InvalidArgumentException",
+            UntagStreamError::InvalidResourceFormat(_) => "InvalidResourceFormatException",
+            UntagStreamError::NotAuthorized(_) => "NotAuthorizedException",
+            UntagStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateDataRetention
 #[derive(Debug, PartialEq)]
 pub enum UpdateDataRetentionError {
@@ -1596,6 +1782,18 @@ impl fmt::Display for UpdateDataRetentionError {
     }
 }
 impl Error for UpdateDataRetentionError {}
+impl ServiceErrorCode for UpdateDataRetentionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDataRetentionError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            UpdateDataRetentionError::InvalidArgument(_) => "InvalidArgumentException",
+            UpdateDataRetentionError::NotAuthorized(_) => "NotAuthorizedException",
+            UpdateDataRetentionError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateDataRetentionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDataRetentionError::VersionMismatch(_) => "VersionMismatchException",
+        }
+    }
+}
 /// Errors returned by UpdateSignalingChannel
 #[derive(Debug, PartialEq)]
 pub enum UpdateSignalingChannelError {
@@ -1666,6 +1864,18 @@ impl fmt::Display for UpdateSignalingChannelError {
     }
 }
 impl Error for UpdateSignalingChannelError {}
+impl ServiceErrorCode for UpdateSignalingChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSignalingChannelError::AccessDenied(_) => "AccessDeniedException",
+            UpdateSignalingChannelError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            UpdateSignalingChannelError::InvalidArgument(_) => "InvalidArgumentException",
+            UpdateSignalingChannelError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateSignalingChannelError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateSignalingChannelError::VersionMismatch(_) => "VersionMismatchException",
+        }
+    }
+}
 /// Errors returned by UpdateStream
 #[derive(Debug, PartialEq)]
 pub enum UpdateStreamError {
@@ -1726,6 +1936,18 @@ impl fmt::Display for UpdateStreamError {
     }
 }
 impl Error for UpdateStreamError {}
+impl ServiceErrorCode for UpdateStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateStreamError::ClientLimitExceeded(_) => "ClientLimitExceededException",
+            UpdateStreamError::InvalidArgument(_) => "InvalidArgumentException",
+            UpdateStreamError::NotAuthorized(_) => "NotAuthorizedException",
+            UpdateStreamError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateStreamError::VersionMismatch(_) => "VersionMismatchException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Kinesis Video API. Kinesis Video clients implement this trait.
 #[async_trait]
 pub trait KinesisVideo {
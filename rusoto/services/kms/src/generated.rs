@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1590,6 +1590,17 @@ impl fmt::Display for CancelKeyDeletionError {
     }
 }
 impl Error for CancelKeyDeletionError {}
+impl ServiceErrorCode for CancelKeyDeletionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelKeyDeletionError::DependencyTimeout(_) => "DependencyTimeoutException",
+            CancelKeyDeletionError::InvalidArn(_) => "InvalidArnException",
+            CancelKeyDeletionError::KMSInternal(_) => "KMSInternalException",
+            CancelKeyDeletionError::KMSInvalidState(_) => "KMSInvalidStateException",
+            CancelKeyDeletionError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ConnectCustomKeyStore
 #[derive(Debug, PartialEq)]
 pub enum ConnectCustomKeyStoreError {
@@ -1658,6 +1669,25 @@ impl fmt::Display for ConnectCustomKeyStoreError {
     }
 }
 impl Error for ConnectCustomKeyStoreError {}
+impl ServiceErrorCode for ConnectCustomKeyStoreError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ConnectCustomKeyStoreError::CloudHsmClusterInvalidConfiguration(_) => {
+                "CloudHsmClusterInvalidConfigurationException"
+            }
+            ConnectCustomKeyStoreError::CloudHsmClusterNotActive(_) => {
+                "CloudHsmClusterNotActiveException"
+            }
+            ConnectCustomKeyStoreError::CustomKeyStoreInvalidState(_) => {
+                "CustomKeyStoreInvalidStateException"
+            }
+            ConnectCustomKeyStoreError::CustomKeyStoreNotFound(_) => {
+                "CustomKeyStoreNotFoundException"
+            }
+            ConnectCustomKeyStoreError::KMSInternal(_) => "KMSInternalException",
+        }
+    }
+}
 /// Errors returned by CreateAlias
 #[derive(Debug, PartialEq)]
 pub enum CreateAliasError {
@@ -1724,6 +1754,19 @@ impl fmt::Display for CreateAliasError {
     }
 }
 impl Error for CreateAliasError {}
+impl ServiceErrorCode for CreateAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAliasError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateAliasError::DependencyTimeout(_) => "DependencyTimeoutException",
+            CreateAliasError::InvalidAliasName(_) => "InvalidAliasNameException",
+            CreateAliasError::KMSInternal(_) => "KMSInternalException",
+            CreateAliasError::KMSInvalidState(_) => "KMSInvalidStateException",
+            CreateAliasError::LimitExceeded(_) => "LimitExceededException",
+            CreateAliasError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateCustomKeyStore
 #[derive(Debug, PartialEq)]
 pub enum CreateCustomKeyStoreError {
@@ -1806,6 +1849,27 @@ impl fmt::Display for CreateCustomKeyStoreError {
     }
 }
 impl Error for CreateCustomKeyStoreError {}
+impl ServiceErrorCode for CreateCustomKeyStoreError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCustomKeyStoreError::CloudHsmClusterInUse(_) => "CloudHsmClusterInUseException",
+            CreateCustomKeyStoreError::CloudHsmClusterInvalidConfiguration(_) => {
+                "CloudHsmClusterInvalidConfigurationException"
+            }
+            CreateCustomKeyStoreError::CloudHsmClusterNotActive(_) => {
+                "CloudHsmClusterNotActiveException"
+            }
+            CreateCustomKeyStoreError::CloudHsmClusterNotFound(_) => {
+                "CloudHsmClusterNotFoundException"
+            }
+            CreateCustomKeyStoreError::CustomKeyStoreNameInUse(_) => {
+                "CustomKeyStoreNameInUseException"
+            }
+            CreateCustomKeyStoreError::IncorrectTrustAnchor(_) => "IncorrectTrustAnchorException",
+            CreateCustomKeyStoreError::KMSInternal(_) => "KMSInternalException",
+        }
+    }
+}
 /// Errors returned by CreateGrant
 #[derive(Debug, PartialEq)]
 pub enum CreateGrantError {
@@ -1878,6 +1942,20 @@ impl fmt::Display for CreateGrantError {
     }
 }
 impl Error for CreateGrantError {}
+impl ServiceErrorCode for CreateGrantError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGrantError::DependencyTimeout(_) => "DependencyTimeoutException",
+            CreateGrantError::Disabled(_) => "DisabledException",
+            CreateGrantError::InvalidArn(_) => "InvalidArnException",
+            CreateGrantError::InvalidGrantToken(_) => "InvalidGrantTokenException",
+            CreateGrantError::KMSInternal(_) => "KMSInternalException",
+            CreateGrantError::KMSInvalidState(_) => "KMSInvalidStateException",
+            CreateGrantError::LimitExceeded(_) => "LimitExceededException",
+            CreateGrantError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateKey
 #[derive(Debug, PartialEq)]
 pub enum CreateKeyError {
@@ -1966,6 +2044,24 @@ impl fmt::Display for CreateKeyError {
     }
 }
 impl Error for CreateKeyError {}
+impl ServiceErrorCode for CreateKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateKeyError::CloudHsmClusterInvalidConfiguration(_) => {
+                "CloudHsmClusterInvalidConfigurationException"
+            }
+            CreateKeyError::CustomKeyStoreInvalidState(_) => "CustomKeyStoreInvalidStateException",
+            CreateKeyError::CustomKeyStoreNotFound(_) => "CustomKeyStoreNotFoundException",
+            CreateKeyError::DependencyTimeout(_) => "DependencyTimeoutException",
+            CreateKeyError::InvalidArn(_) => "InvalidArnException",
+            CreateKeyError::KMSInternal(_) => "KMSInternalException",
+            CreateKeyError::LimitExceeded(_) => "LimitExceededException",
+            CreateKeyError::MalformedPolicyDocument(_) => "MalformedPolicyDocumentException",
+            CreateKeyError::Tag(_) => "TagException",
+            CreateKeyError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by Decrypt
 #[derive(Debug, PartialEq)]
 pub enum DecryptError {
@@ -2050,6 +2146,22 @@ impl fmt::Display for DecryptError {
     }
 }
 impl Error for DecryptError {}
+impl ServiceErrorCode for DecryptError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DecryptError::DependencyTimeout(_) => "DependencyTimeoutException",
+            DecryptError::Disabled(_) => "DisabledException",
+            DecryptError::IncorrectKey(_) => "IncorrectKeyException",
+            DecryptError::InvalidCiphertext(_) => "InvalidCiphertextException",
+            DecryptError::InvalidGrantToken(_) => "InvalidGrantTokenException",
+            DecryptError::InvalidKeyUsage(_) => "InvalidKeyUsageException",
+            DecryptError::KMSInternal(_) => "KMSInternalException",
+            DecryptError::KMSInvalidState(_) => "KMSInvalidStateException",
+            DecryptError::KeyUnavailable(_) => "KeyUnavailableException",
+            DecryptError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteAlias
 #[derive(Debug, PartialEq)]
 pub enum DeleteAliasError {
@@ -2098,6 +2210,16 @@ impl fmt::Display for DeleteAliasError {
     }
 }
 impl Error for DeleteAliasError {}
+impl ServiceErrorCode for DeleteAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAliasError::DependencyTimeout(_) => "DependencyTimeoutException",
+            DeleteAliasError::KMSInternal(_) => "KMSInternalException",
+            DeleteAliasError::KMSInvalidState(_) => "KMSInvalidStateException",
+            DeleteAliasError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteCustomKeyStore
 #[derive(Debug, PartialEq)]
 pub enum DeleteCustomKeyStoreError {
@@ -2154,6 +2276,20 @@ impl fmt::Display for DeleteCustomKeyStoreError {
     }
 }
 impl Error for DeleteCustomKeyStoreError {}
+impl ServiceErrorCode for DeleteCustomKeyStoreError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCustomKeyStoreError::CustomKeyStoreHasCMKs(_) => "CustomKeyStoreHasCMKsException",
+            DeleteCustomKeyStoreError::CustomKeyStoreInvalidState(_) => {
+                "CustomKeyStoreInvalidStateException"
+            }
+            DeleteCustomKeyStoreError::CustomKeyStoreNotFound(_) => {
+                "CustomKeyStoreNotFoundException"
+            }
+            DeleteCustomKeyStoreError::KMSInternal(_) => "KMSInternalException",
+        }
+    }
+}
 /// Errors returned by DeleteImportedKeyMaterial
 #[derive(Debug, PartialEq)]
 pub enum DeleteImportedKeyMaterialError {
@@ -2226,6 +2362,20 @@ impl fmt::Display for DeleteImportedKeyMaterialError {
     }
 }
 impl Error for DeleteImportedKeyMaterialError {}
+impl ServiceErrorCode for DeleteImportedKeyMaterialError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteImportedKeyMaterialError::DependencyTimeout(_) => "DependencyTimeoutException",
+            DeleteImportedKeyMaterialError::InvalidArn(_) => "InvalidArnException",
+            DeleteImportedKeyMaterialError::KMSInternal(_) => "KMSInternalException",
+            DeleteImportedKeyMaterialError::KMSInvalidState(_) => "KMSInvalidStateException",
+            DeleteImportedKeyMaterialError::NotFound(_) => "NotFoundException",
+            DeleteImportedKeyMaterialError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeCustomKeyStores
 #[derive(Debug, PartialEq)]
 pub enum DescribeCustomKeyStoresError {
@@ -2266,6 +2416,16 @@ impl fmt::Display for DescribeCustomKeyStoresError {
     }
 }
 impl Error for DescribeCustomKeyStoresError {}
+impl ServiceErrorCode for DescribeCustomKeyStoresError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCustomKeyStoresError::CustomKeyStoreNotFound(_) => {
+                "CustomKeyStoreNotFoundException"
+            }
+            DescribeCustomKeyStoresError::KMSInternal(_) => "KMSInternalException",
+        }
+    }
+}
 /// Errors returned by DescribeKey
 #[derive(Debug, PartialEq)]
 pub enum DescribeKeyError {
@@ -2314,6 +2474,16 @@ impl fmt::Display for DescribeKeyError {
     }
 }
 impl Error for DescribeKeyError {}
+impl ServiceErrorCode for DescribeKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeKeyError::DependencyTimeout(_) => "DependencyTimeoutException",
+            DescribeKeyError::InvalidArn(_) => "InvalidArnException",
+            DescribeKeyError::KMSInternal(_) => "KMSInternalException",
+            DescribeKeyError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DisableKey
 #[derive(Debug, PartialEq)]
 pub enum DisableKeyError {
@@ -2368,6 +2538,17 @@ impl fmt::Display for DisableKeyError {
     }
 }
 impl Error for DisableKeyError {}
+impl ServiceErrorCode for DisableKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableKeyError::DependencyTimeout(_) => "DependencyTimeoutException",
+            DisableKeyError::InvalidArn(_) => "InvalidArnException",
+            DisableKeyError::KMSInternal(_) => "KMSInternalException",
+            DisableKeyError::KMSInvalidState(_) => "KMSInvalidStateException",
+            DisableKeyError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DisableKeyRotation
 #[derive(Debug, PartialEq)]
 pub enum DisableKeyRotationError {
@@ -2438,6 +2619,19 @@ impl fmt::Display for DisableKeyRotationError {
     }
 }
 impl Error for DisableKeyRotationError {}
+impl ServiceErrorCode for DisableKeyRotationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableKeyRotationError::DependencyTimeout(_) => "DependencyTimeoutException",
+            DisableKeyRotationError::Disabled(_) => "DisabledException",
+            DisableKeyRotationError::InvalidArn(_) => "InvalidArnException",
+            DisableKeyRotationError::KMSInternal(_) => "KMSInternalException",
+            DisableKeyRotationError::KMSInvalidState(_) => "KMSInvalidStateException",
+            DisableKeyRotationError::NotFound(_) => "NotFoundException",
+            DisableKeyRotationError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DisconnectCustomKeyStore
 #[derive(Debug, PartialEq)]
 pub enum DisconnectCustomKeyStoreError {
@@ -2490,6 +2684,19 @@ impl fmt::Display for DisconnectCustomKeyStoreError {
     }
 }
 impl Error for DisconnectCustomKeyStoreError {}
+impl ServiceErrorCode for DisconnectCustomKeyStoreError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisconnectCustomKeyStoreError::CustomKeyStoreInvalidState(_) => {
+                "CustomKeyStoreInvalidStateException"
+            }
+            DisconnectCustomKeyStoreError::CustomKeyStoreNotFound(_) => {
+                "CustomKeyStoreNotFoundException"
+            }
+            DisconnectCustomKeyStoreError::KMSInternal(_) => "KMSInternalException",
+        }
+    }
+}
 /// Errors returned by EnableKey
 #[derive(Debug, PartialEq)]
 pub enum EnableKeyError {
@@ -2550,6 +2757,18 @@ impl fmt::Display for EnableKeyError {
     }
 }
 impl Error for EnableKeyError {}
+impl ServiceErrorCode for EnableKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableKeyError::DependencyTimeout(_) => "DependencyTimeoutException",
+            EnableKeyError::InvalidArn(_) => "InvalidArnException",
+            EnableKeyError::KMSInternal(_) => "KMSInternalException",
+            EnableKeyError::KMSInvalidState(_) => "KMSInvalidStateException",
+            EnableKeyError::LimitExceeded(_) => "LimitExceededException",
+            EnableKeyError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by EnableKeyRotation
 #[derive(Debug, PartialEq)]
 pub enum EnableKeyRotationError {
@@ -2618,6 +2837,19 @@ impl fmt::Display for EnableKeyRotationError {
     }
 }
 impl Error for EnableKeyRotationError {}
+impl ServiceErrorCode for EnableKeyRotationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableKeyRotationError::DependencyTimeout(_) => "DependencyTimeoutException",
+            EnableKeyRotationError::Disabled(_) => "DisabledException",
+            EnableKeyRotationError::InvalidArn(_) => "InvalidArnException",
+            EnableKeyRotationError::KMSInternal(_) => "KMSInternalException",
+            EnableKeyRotationError::KMSInvalidState(_) => "KMSInvalidStateException",
+            EnableKeyRotationError::NotFound(_) => "NotFoundException",
+            EnableKeyRotationError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by Encrypt
 #[derive(Debug, PartialEq)]
 pub enum EncryptError {
@@ -2690,6 +2922,20 @@ impl fmt::Display for EncryptError {
     }
 }
 impl Error for EncryptError {}
+impl ServiceErrorCode for EncryptError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EncryptError::DependencyTimeout(_) => "DependencyTimeoutException",
+            EncryptError::Disabled(_) => "DisabledException",
+            EncryptError::InvalidGrantToken(_) => "InvalidGrantTokenException",
+            EncryptError::InvalidKeyUsage(_) => "InvalidKeyUsageException",
+            EncryptError::KMSInternal(_) => "KMSInternalException",
+            EncryptError::KMSInvalidState(_) => "KMSInvalidStateException",
+            EncryptError::KeyUnavailable(_) => "KeyUnavailableException",
+            EncryptError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GenerateDataKey
 #[derive(Debug, PartialEq)]
 pub enum GenerateDataKeyError {
@@ -2762,6 +3008,20 @@ impl fmt::Display for GenerateDataKeyError {
     }
 }
 impl Error for GenerateDataKeyError {}
+impl ServiceErrorCode for GenerateDataKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GenerateDataKeyError::DependencyTimeout(_) => "DependencyTimeoutException",
+            GenerateDataKeyError::Disabled(_) => "DisabledException",
+            GenerateDataKeyError::InvalidGrantToken(_) => "InvalidGrantTokenException",
+            GenerateDataKeyError::InvalidKeyUsage(_) => "InvalidKeyUsageException",
+            GenerateDataKeyError::KMSInternal(_) => "KMSInternalException",
+            GenerateDataKeyError::KMSInvalidState(_) => "KMSInvalidStateException",
+            GenerateDataKeyError::KeyUnavailable(_) => "KeyUnavailableException",
+            GenerateDataKeyError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GenerateDataKeyPair
 #[derive(Debug, PartialEq)]
 pub enum GenerateDataKeyPairError {
@@ -2846,6 +3106,21 @@ impl fmt::Display for GenerateDataKeyPairError {
     }
 }
 impl Error for GenerateDataKeyPairError {}
+impl ServiceErrorCode for GenerateDataKeyPairError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GenerateDataKeyPairError::DependencyTimeout(_) => "DependencyTimeoutException",
+            GenerateDataKeyPairError::Disabled(_) => "DisabledException",
+            GenerateDataKeyPairError::InvalidGrantToken(_) => "InvalidGrantTokenException",
+            GenerateDataKeyPairError::InvalidKeyUsage(_) => "InvalidKeyUsageException",
+            GenerateDataKeyPairError::KMSInternal(_) => "KMSInternalException",
+            GenerateDataKeyPairError::KMSInvalidState(_) => "KMSInvalidStateException",
+            GenerateDataKeyPairError::KeyUnavailable(_) => "KeyUnavailableException",
+            GenerateDataKeyPairError::NotFound(_) => "NotFoundException",
+            GenerateDataKeyPairError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by GenerateDataKeyPairWithoutPlaintext
 #[derive(Debug, PartialEq)]
 pub enum GenerateDataKeyPairWithoutPlaintextError {
@@ -2958,6 +3233,33 @@ impl fmt::Display for GenerateDataKeyPairWithoutPlaintextError {
     }
 }
 impl Error for GenerateDataKeyPairWithoutPlaintextError {}
+impl ServiceErrorCode for GenerateDataKeyPairWithoutPlaintextError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GenerateDataKeyPairWithoutPlaintextError::DependencyTimeout(_) => {
+                "DependencyTimeoutException"
+            }
+            GenerateDataKeyPairWithoutPlaintextError::Disabled(_) => "DisabledException",
+            GenerateDataKeyPairWithoutPlaintextError::InvalidGrantToken(_) => {
+                "InvalidGrantTokenException"
+            }
+            GenerateDataKeyPairWithoutPlaintextError::InvalidKeyUsage(_) => {
+                "InvalidKeyUsageException"
+            }
+            GenerateDataKeyPairWithoutPlaintextError::KMSInternal(_) => "KMSInternalException",
+            GenerateDataKeyPairWithoutPlaintextError::KMSInvalidState(_) => {
+                "KMSInvalidStateException"
+            }
+            GenerateDataKeyPairWithoutPlaintextError::KeyUnavailable(_) => {
+                "KeyUnavailableException"
+            }
+            GenerateDataKeyPairWithoutPlaintextError::NotFound(_) => "NotFoundException",
+            GenerateDataKeyPairWithoutPlaintextError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by GenerateDataKeyWithoutPlaintext
 #[derive(Debug, PartialEq)]
 pub enum GenerateDataKeyWithoutPlaintextError {
@@ -3058,6 +3360,24 @@ impl fmt::Display for GenerateDataKeyWithoutPlaintextError {
     }
 }
 impl Error for GenerateDataKeyWithoutPlaintextError {}
+impl ServiceErrorCode for GenerateDataKeyWithoutPlaintextError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GenerateDataKeyWithoutPlaintextError::DependencyTimeout(_) => {
+                "DependencyTimeoutException"
+            }
+            GenerateDataKeyWithoutPlaintextError::Disabled(_) => "DisabledException",
+            GenerateDataKeyWithoutPlaintextError::InvalidGrantToken(_) => {
+                "InvalidGrantTokenException"
+            }
+            GenerateDataKeyWithoutPlaintextError::InvalidKeyUsage(_) => "InvalidKeyUsageException",
+            GenerateDataKeyWithoutPlaintextError::KMSInternal(_) => "KMSInternalException",
+            GenerateDataKeyWithoutPlaintextError::KMSInvalidState(_) => "KMSInvalidStateException",
+            GenerateDataKeyWithoutPlaintextError::KeyUnavailable(_) => "KeyUnavailableException",
+            GenerateDataKeyWithoutPlaintextError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GenerateRandom
 #[derive(Debug, PartialEq)]
 pub enum GenerateRandomError {
@@ -3110,6 +3430,18 @@ impl fmt::Display for GenerateRandomError {
     }
 }
 impl Error for GenerateRandomError {}
+impl ServiceErrorCode for GenerateRandomError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GenerateRandomError::CustomKeyStoreInvalidState(_) => {
+                "CustomKeyStoreInvalidStateException"
+            }
+            GenerateRandomError::CustomKeyStoreNotFound(_) => "CustomKeyStoreNotFoundException",
+            GenerateRandomError::DependencyTimeout(_) => "DependencyTimeoutException",
+            GenerateRandomError::KMSInternal(_) => "KMSInternalException",
+        }
+    }
+}
 /// Errors returned by GetKeyPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetKeyPolicyError {
@@ -3164,6 +3496,17 @@ impl fmt::Display for GetKeyPolicyError {
     }
 }
 impl Error for GetKeyPolicyError {}
+impl ServiceErrorCode for GetKeyPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetKeyPolicyError::DependencyTimeout(_) => "DependencyTimeoutException",
+            GetKeyPolicyError::InvalidArn(_) => "InvalidArnException",
+            GetKeyPolicyError::KMSInternal(_) => "KMSInternalException",
+            GetKeyPolicyError::KMSInvalidState(_) => "KMSInvalidStateException",
+            GetKeyPolicyError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetKeyRotationStatus
 #[derive(Debug, PartialEq)]
 pub enum GetKeyRotationStatusError {
@@ -3230,6 +3573,18 @@ impl fmt::Display for GetKeyRotationStatusError {
     }
 }
 impl Error for GetKeyRotationStatusError {}
+impl ServiceErrorCode for GetKeyRotationStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetKeyRotationStatusError::DependencyTimeout(_) => "DependencyTimeoutException",
+            GetKeyRotationStatusError::InvalidArn(_) => "InvalidArnException",
+            GetKeyRotationStatusError::KMSInternal(_) => "KMSInternalException",
+            GetKeyRotationStatusError::KMSInvalidState(_) => "KMSInvalidStateException",
+            GetKeyRotationStatusError::NotFound(_) => "NotFoundException",
+            GetKeyRotationStatusError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by GetParametersForImport
 #[derive(Debug, PartialEq)]
 pub enum GetParametersForImportError {
@@ -3296,6 +3651,18 @@ impl fmt::Display for GetParametersForImportError {
     }
 }
 impl Error for GetParametersForImportError {}
+impl ServiceErrorCode for GetParametersForImportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetParametersForImportError::DependencyTimeout(_) => "DependencyTimeoutException",
+            GetParametersForImportError::InvalidArn(_) => "InvalidArnException",
+            GetParametersForImportError::KMSInternal(_) => "KMSInternalException",
+            GetParametersForImportError::KMSInvalidState(_) => "KMSInvalidStateException",
+            GetParametersForImportError::NotFound(_) => "NotFoundException",
+            GetParametersForImportError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by GetPublicKey
 #[derive(Debug, PartialEq)]
 pub enum GetPublicKeyError {
@@ -3380,6 +3747,22 @@ impl fmt::Display for GetPublicKeyError {
     }
 }
 impl Error for GetPublicKeyError {}
+impl ServiceErrorCode for GetPublicKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPublicKeyError::DependencyTimeout(_) => "DependencyTimeoutException",
+            GetPublicKeyError::Disabled(_) => "DisabledException",
+            GetPublicKeyError::InvalidArn(_) => "InvalidArnException",
+            GetPublicKeyError::InvalidGrantToken(_) => "InvalidGrantTokenException",
+            GetPublicKeyError::InvalidKeyUsage(_) => "InvalidKeyUsageException",
+            GetPublicKeyError::KMSInternal(_) => "KMSInternalException",
+            GetPublicKeyError::KMSInvalidState(_) => "KMSInvalidStateException",
+            GetPublicKeyError::KeyUnavailable(_) => "KeyUnavailableException",
+            GetPublicKeyError::NotFound(_) => "NotFoundException",
+            GetPublicKeyError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by ImportKeyMaterial
 #[derive(Debug, PartialEq)]
 pub enum ImportKeyMaterialError {
@@ -3472,6 +3855,22 @@ impl fmt::Display for ImportKeyMaterialError {
     }
 }
 impl Error for ImportKeyMaterialError {}
+impl ServiceErrorCode for ImportKeyMaterialError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportKeyMaterialError::DependencyTimeout(_) => "DependencyTimeoutException",
+            ImportKeyMaterialError::ExpiredImportToken(_) => "ExpiredImportTokenException",
+            ImportKeyMaterialError::IncorrectKeyMaterial(_) => "IncorrectKeyMaterialException",
+            ImportKeyMaterialError::InvalidArn(_) => "InvalidArnException",
+            ImportKeyMaterialError::InvalidCiphertext(_) => "InvalidCiphertextException",
+            ImportKeyMaterialError::InvalidImportToken(_) => "InvalidImportTokenException",
+            ImportKeyMaterialError::KMSInternal(_) => "KMSInternalException",
+            ImportKeyMaterialError::KMSInvalidState(_) => "KMSInvalidStateException",
+            ImportKeyMaterialError::NotFound(_) => "NotFoundException",
+            ImportKeyMaterialError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by ListAliases
 #[derive(Debug, PartialEq)]
 pub enum ListAliasesError {
@@ -3526,6 +3925,17 @@ impl fmt::Display for ListAliasesError {
     }
 }
 impl Error for ListAliasesError {}
+impl ServiceErrorCode for ListAliasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAliasesError::DependencyTimeout(_) => "DependencyTimeoutException",
+            ListAliasesError::InvalidArn(_) => "InvalidArnException",
+            ListAliasesError::InvalidMarker(_) => "InvalidMarkerException",
+            ListAliasesError::KMSInternal(_) => "KMSInternalException",
+            ListAliasesError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListGrants
 #[derive(Debug, PartialEq)]
 pub enum ListGrantsError {
@@ -3586,6 +3996,18 @@ impl fmt::Display for ListGrantsError {
     }
 }
 impl Error for ListGrantsError {}
+impl ServiceErrorCode for ListGrantsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGrantsError::DependencyTimeout(_) => "DependencyTimeoutException",
+            ListGrantsError::InvalidArn(_) => "InvalidArnException",
+            ListGrantsError::InvalidMarker(_) => "InvalidMarkerException",
+            ListGrantsError::KMSInternal(_) => "KMSInternalException",
+            ListGrantsError::KMSInvalidState(_) => "KMSInvalidStateException",
+            ListGrantsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListKeyPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListKeyPoliciesError {
@@ -3640,6 +4062,17 @@ impl fmt::Display for ListKeyPoliciesError {
     }
 }
 impl Error for ListKeyPoliciesError {}
+impl ServiceErrorCode for ListKeyPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListKeyPoliciesError::DependencyTimeout(_) => "DependencyTimeoutException",
+            ListKeyPoliciesError::InvalidArn(_) => "InvalidArnException",
+            ListKeyPoliciesError::KMSInternal(_) => "KMSInternalException",
+            ListKeyPoliciesError::KMSInvalidState(_) => "KMSInvalidStateException",
+            ListKeyPoliciesError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListKeys
 #[derive(Debug, PartialEq)]
 pub enum ListKeysError {
@@ -3682,6 +4115,15 @@ impl fmt::Display for ListKeysError {
     }
 }
 impl Error for ListKeysError {}
+impl ServiceErrorCode for ListKeysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListKeysError::DependencyTimeout(_) => "DependencyTimeoutException",
+            ListKeysError::InvalidMarker(_) => "InvalidMarkerException",
+            ListKeysError::KMSInternal(_) => "KMSInternalException",
+        }
+    }
+}
 /// Errors returned by ListResourceTags
 #[derive(Debug, PartialEq)]
 pub enum ListResourceTagsError {
@@ -3730,6 +4172,16 @@ impl fmt::Display for ListResourceTagsError {
     }
 }
 impl Error for ListResourceTagsError {}
+impl ServiceErrorCode for ListResourceTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourceTagsError::InvalidArn(_) => "InvalidArnException",
+            ListResourceTagsError::InvalidMarker(_) => "InvalidMarkerException",
+            ListResourceTagsError::KMSInternal(_) => "KMSInternalException",
+            ListResourceTagsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListRetirableGrants
 #[derive(Debug, PartialEq)]
 pub enum ListRetirableGrantsError {
@@ -3786,6 +4238,17 @@ impl fmt::Display for ListRetirableGrantsError {
     }
 }
 impl Error for ListRetirableGrantsError {}
+impl ServiceErrorCode for ListRetirableGrantsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRetirableGrantsError::DependencyTimeout(_) => "DependencyTimeoutException",
+            ListRetirableGrantsError::InvalidArn(_) => "InvalidArnException",
+            ListRetirableGrantsError::InvalidMarker(_) => "InvalidMarkerException",
+            ListRetirableGrantsError::KMSInternal(_) => "KMSInternalException",
+            ListRetirableGrantsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by PutKeyPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutKeyPolicyError {
@@ -3860,6 +4323,20 @@ impl fmt::Display for PutKeyPolicyError {
     }
 }
 impl Error for PutKeyPolicyError {}
+impl ServiceErrorCode for PutKeyPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutKeyPolicyError::DependencyTimeout(_) => "DependencyTimeoutException",
+            PutKeyPolicyError::InvalidArn(_) => "InvalidArnException",
+            PutKeyPolicyError::KMSInternal(_) => "KMSInternalException",
+            PutKeyPolicyError::KMSInvalidState(_) => "KMSInvalidStateException",
+            PutKeyPolicyError::LimitExceeded(_) => "LimitExceededException",
+            PutKeyPolicyError::MalformedPolicyDocument(_) => "MalformedPolicyDocumentException",
+            PutKeyPolicyError::NotFound(_) => "NotFoundException",
+            PutKeyPolicyError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by ReEncrypt
 #[derive(Debug, PartialEq)]
 pub enum ReEncryptError {
@@ -3944,6 +4421,22 @@ impl fmt::Display for ReEncryptError {
     }
 }
 impl Error for ReEncryptError {}
+impl ServiceErrorCode for ReEncryptError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ReEncryptError::DependencyTimeout(_) => "DependencyTimeoutException",
+            ReEncryptError::Disabled(_) => "DisabledException",
+            ReEncryptError::IncorrectKey(_) => "IncorrectKeyException",
+            ReEncryptError::InvalidCiphertext(_) => "InvalidCiphertextException",
+            ReEncryptError::InvalidGrantToken(_) => "InvalidGrantTokenException",
+            ReEncryptError::InvalidKeyUsage(_) => "InvalidKeyUsageException",
+            ReEncryptError::KMSInternal(_) => "KMSInternalException",
+            ReEncryptError::KMSInvalidState(_) => "KMSInvalidStateException",
+            ReEncryptError::KeyUnavailable(_) => "KeyUnavailableException",
+            ReEncryptError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by RetireGrant
 #[derive(Debug, PartialEq)]
 pub enum RetireGrantError {
@@ -4010,6 +4503,19 @@ impl fmt::Display for RetireGrantError {
     }
 }
 impl Error for RetireGrantError {}
+impl ServiceErrorCode for RetireGrantError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RetireGrantError::DependencyTimeout(_) => "DependencyTimeoutException",
+            RetireGrantError::InvalidArn(_) => "InvalidArnException",
+            RetireGrantError::InvalidGrantId(_) => "InvalidGrantIdException",
+            RetireGrantError::InvalidGrantToken(_) => "InvalidGrantTokenException",
+            RetireGrantError::KMSInternal(_) => "KMSInternalException",
+            RetireGrantError::KMSInvalidState(_) => "KMSInvalidStateException",
+            RetireGrantError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by RevokeGrant
 #[derive(Debug, PartialEq)]
 pub enum RevokeGrantError {
@@ -4070,6 +4576,18 @@ impl fmt::Display for RevokeGrantError {
     }
 }
 impl Error for RevokeGrantError {}
+impl ServiceErrorCode for RevokeGrantError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RevokeGrantError::DependencyTimeout(_) => "DependencyTimeoutException",
+            RevokeGrantError::InvalidArn(_) => "InvalidArnException",
+            RevokeGrantError::InvalidGrantId(_) => "InvalidGrantIdException",
+            RevokeGrantError::KMSInternal(_) => "KMSInternalException",
+            RevokeGrantError::KMSInvalidState(_) => "KMSInvalidStateException",
+            RevokeGrantError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ScheduleKeyDeletion
 #[derive(Debug, PartialEq)]
 pub enum ScheduleKeyDeletionError {
@@ -4126,6 +4644,17 @@ impl fmt::Display for ScheduleKeyDeletionError {
     }
 }
 impl Error for ScheduleKeyDeletionError {}
+impl ServiceErrorCode for ScheduleKeyDeletionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ScheduleKeyDeletionError::DependencyTimeout(_) => "DependencyTimeoutException",
+            ScheduleKeyDeletionError::InvalidArn(_) => "InvalidArnException",
+            ScheduleKeyDeletionError::KMSInternal(_) => "KMSInternalException",
+            ScheduleKeyDeletionError::KMSInvalidState(_) => "KMSInvalidStateException",
+            ScheduleKeyDeletionError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by Sign
 #[derive(Debug, PartialEq)]
 pub enum SignError {
@@ -4194,6 +4723,20 @@ impl fmt::Display for SignError {
     }
 }
 impl Error for SignError {}
+impl ServiceErrorCode for SignError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SignError::DependencyTimeout(_) => "DependencyTimeoutException",
+            SignError::Disabled(_) => "DisabledException",
+            SignError::InvalidGrantToken(_) => "InvalidGrantTokenException",
+            SignError::InvalidKeyUsage(_) => "InvalidKeyUsageException",
+            SignError::KMSInternal(_) => "KMSInternalException",
+            SignError::KMSInvalidState(_) => "KMSInvalidStateException",
+            SignError::KeyUnavailable(_) => "KeyUnavailableException",
+            SignError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -4252,6 +4795,18 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InvalidArn(_) => "InvalidArnException",
+            TagResourceError::KMSInternal(_) => "KMSInternalException",
+            TagResourceError::KMSInvalidState(_) => "KMSInvalidStateException",
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+            TagResourceError::Tag(_) => "TagException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -4304,6 +4859,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InvalidArn(_) => "InvalidArnException",
+            UntagResourceError::KMSInternal(_) => "KMSInternalException",
+            UntagResourceError::KMSInvalidState(_) => "KMSInvalidStateException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+            UntagResourceError::Tag(_) => "TagException",
+        }
+    }
+}
 /// Errors returned by UpdateAlias
 #[derive(Debug, PartialEq)]
 pub enum UpdateAliasError {
@@ -4358,6 +4924,17 @@ impl fmt::Display for UpdateAliasError {
     }
 }
 impl Error for UpdateAliasError {}
+impl ServiceErrorCode for UpdateAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAliasError::DependencyTimeout(_) => "DependencyTimeoutException",
+            UpdateAliasError::KMSInternal(_) => "KMSInternalException",
+            UpdateAliasError::KMSInvalidState(_) => "KMSInvalidStateException",
+            UpdateAliasError::LimitExceeded(_) => "LimitExceededException",
+            UpdateAliasError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateCustomKeyStore
 #[derive(Debug, PartialEq)]
 pub enum UpdateCustomKeyStoreError {
@@ -4452,6 +5029,34 @@ impl fmt::Display for UpdateCustomKeyStoreError {
     }
 }
 impl Error for UpdateCustomKeyStoreError {}
+impl ServiceErrorCode for UpdateCustomKeyStoreError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCustomKeyStoreError::CloudHsmClusterInvalidConfiguration(_) => {
+                "CloudHsmClusterInvalidConfigurationException"
+            }
+            UpdateCustomKeyStoreError::CloudHsmClusterNotActive(_) => {
+                "CloudHsmClusterNotActiveException"
+            }
+            UpdateCustomKeyStoreError::CloudHsmClusterNotFound(_) => {
+                "CloudHsmClusterNotFoundException"
+            }
+            UpdateCustomKeyStoreError::CloudHsmClusterNotRelated(_) => {
+                "CloudHsmClusterNotRelatedException"
+            }
+            UpdateCustomKeyStoreError::CustomKeyStoreInvalidState(_) => {
+                "CustomKeyStoreInvalidStateException"
+            }
+            UpdateCustomKeyStoreError::CustomKeyStoreNameInUse(_) => {
+                "CustomKeyStoreNameInUseException"
+            }
+            UpdateCustomKeyStoreError::CustomKeyStoreNotFound(_) => {
+                "CustomKeyStoreNotFoundException"
+            }
+            UpdateCustomKeyStoreError::KMSInternal(_) => "KMSInternalException",
+        }
+    }
+}
 /// Errors returned by UpdateKeyDescription
 #[derive(Debug, PartialEq)]
 pub enum UpdateKeyDescriptionError {
@@ -4510,6 +5115,17 @@ impl fmt::Display for UpdateKeyDescriptionError {
     }
 }
 impl Error for UpdateKeyDescriptionError {}
+impl ServiceErrorCode for UpdateKeyDescriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateKeyDescriptionError::DependencyTimeout(_) => "DependencyTimeoutException",
+            UpdateKeyDescriptionError::InvalidArn(_) => "InvalidArnException",
+            UpdateKeyDescriptionError::KMSInternal(_) => "KMSInternalException",
+            UpdateKeyDescriptionError::KMSInvalidState(_) => "KMSInvalidStateException",
+            UpdateKeyDescriptionError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by Verify
 #[derive(Debug, PartialEq)]
 pub enum VerifyError {
@@ -4584,6 +5200,21 @@ impl fmt::Display for VerifyError {
     }
 }
 impl Error for VerifyError {}
+impl ServiceErrorCode for VerifyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            VerifyError::DependencyTimeout(_) => "DependencyTimeoutException",
+            VerifyError::Disabled(_) => "DisabledException",
+            VerifyError::InvalidGrantToken(_) => "InvalidGrantTokenException",
+            VerifyError::InvalidKeyUsage(_) => "InvalidKeyUsageException",
+            VerifyError::KMSInternal(_) => "KMSInternalException",
+            VerifyError::KMSInvalidSignature(_) => "KMSInvalidSignatureException",
+            VerifyError::KMSInvalidState(_) => "KMSInvalidStateException",
+            VerifyError::KeyUnavailable(_) => "KeyUnavailableException",
+            VerifyError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the KMS API. KMS clients implement this trait.
 #[async_trait]
 pub trait Kms {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -662,6 +662,14 @@ impl fmt::Display for BatchGrantPermissionsError {
     }
 }
 impl Error for BatchGrantPermissionsError {}
+impl ServiceErrorCode for BatchGrantPermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGrantPermissionsError::InvalidInput(_) => "InvalidInputException",
+            BatchGrantPermissionsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by BatchRevokePermissions
 #[derive(Debug, PartialEq)]
 pub enum BatchRevokePermissionsError {
@@ -700,6 +708,14 @@ impl fmt::Display for BatchRevokePermissionsError {
     }
 }
 impl Error for BatchRevokePermissionsError {}
+impl ServiceErrorCode for BatchRevokePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchRevokePermissionsError::InvalidInput(_) => "InvalidInputException",
+            BatchRevokePermissionsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DeregisterResource
 #[derive(Debug, PartialEq)]
 pub enum DeregisterResourceError {
@@ -748,6 +764,16 @@ impl fmt::Display for DeregisterResourceError {
     }
 }
 impl Error for DeregisterResourceError {}
+impl ServiceErrorCode for DeregisterResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterResourceError::EntityNotFound(_) => "EntityNotFoundException",
+            DeregisterResourceError::InternalService(_) => "InternalServiceException",
+            DeregisterResourceError::InvalidInput(_) => "InvalidInputException",
+            DeregisterResourceError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by DescribeResource
 #[derive(Debug, PartialEq)]
 pub enum DescribeResourceError {
@@ -796,6 +822,16 @@ impl fmt::Display for DescribeResourceError {
     }
 }
 impl Error for DescribeResourceError {}
+impl ServiceErrorCode for DescribeResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeResourceError::EntityNotFound(_) => "EntityNotFoundException",
+            DescribeResourceError::InternalService(_) => "InternalServiceException",
+            DescribeResourceError::InvalidInput(_) => "InvalidInputException",
+            DescribeResourceError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GetDataLakeSettings
 #[derive(Debug, PartialEq)]
 pub enum GetDataLakeSettingsError {
@@ -838,6 +874,15 @@ impl fmt::Display for GetDataLakeSettingsError {
     }
 }
 impl Error for GetDataLakeSettingsError {}
+impl ServiceErrorCode for GetDataLakeSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDataLakeSettingsError::EntityNotFound(_) => "EntityNotFoundException",
+            GetDataLakeSettingsError::InternalService(_) => "InternalServiceException",
+            GetDataLakeSettingsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by GetEffectivePermissionsForPath
 #[derive(Debug, PartialEq)]
 pub enum GetEffectivePermissionsForPathError {
@@ -902,6 +947,16 @@ impl fmt::Display for GetEffectivePermissionsForPathError {
     }
 }
 impl Error for GetEffectivePermissionsForPathError {}
+impl ServiceErrorCode for GetEffectivePermissionsForPathError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEffectivePermissionsForPathError::EntityNotFound(_) => "EntityNotFoundException",
+            GetEffectivePermissionsForPathError::InternalService(_) => "InternalServiceException",
+            GetEffectivePermissionsForPathError::InvalidInput(_) => "InvalidInputException",
+            GetEffectivePermissionsForPathError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by GrantPermissions
 #[derive(Debug, PartialEq)]
 pub enum GrantPermissionsError {
@@ -946,6 +1001,15 @@ impl fmt::Display for GrantPermissionsError {
     }
 }
 impl Error for GrantPermissionsError {}
+impl ServiceErrorCode for GrantPermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GrantPermissionsError::ConcurrentModification(_) => "ConcurrentModificationException",
+            GrantPermissionsError::EntityNotFound(_) => "EntityNotFoundException",
+            GrantPermissionsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ListPermissions
 #[derive(Debug, PartialEq)]
 pub enum ListPermissionsError {
@@ -988,6 +1052,15 @@ impl fmt::Display for ListPermissionsError {
     }
 }
 impl Error for ListPermissionsError {}
+impl ServiceErrorCode for ListPermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPermissionsError::InternalService(_) => "InternalServiceException",
+            ListPermissionsError::InvalidInput(_) => "InvalidInputException",
+            ListPermissionsError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by ListResources
 #[derive(Debug, PartialEq)]
 pub enum ListResourcesError {
@@ -1030,6 +1103,15 @@ impl fmt::Display for ListResourcesError {
     }
 }
 impl Error for ListResourcesError {}
+impl ServiceErrorCode for ListResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourcesError::InternalService(_) => "InternalServiceException",
+            ListResourcesError::InvalidInput(_) => "InvalidInputException",
+            ListResourcesError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by PutDataLakeSettings
 #[derive(Debug, PartialEq)]
 pub enum PutDataLakeSettingsError {
@@ -1066,6 +1148,14 @@ impl fmt::Display for PutDataLakeSettingsError {
     }
 }
 impl Error for PutDataLakeSettingsError {}
+impl ServiceErrorCode for PutDataLakeSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutDataLakeSettingsError::InternalService(_) => "InternalServiceException",
+            PutDataLakeSettingsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by RegisterResource
 #[derive(Debug, PartialEq)]
 pub enum RegisterResourceError {
@@ -1114,6 +1204,16 @@ impl fmt::Display for RegisterResourceError {
     }
 }
 impl Error for RegisterResourceError {}
+impl ServiceErrorCode for RegisterResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterResourceError::AlreadyExists(_) => "AlreadyExistsException",
+            RegisterResourceError::InternalService(_) => "InternalServiceException",
+            RegisterResourceError::InvalidInput(_) => "InvalidInputException",
+            RegisterResourceError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Errors returned by RevokePermissions
 #[derive(Debug, PartialEq)]
 pub enum RevokePermissionsError {
@@ -1158,6 +1258,15 @@ impl fmt::Display for RevokePermissionsError {
     }
 }
 impl Error for RevokePermissionsError {}
+impl ServiceErrorCode for RevokePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RevokePermissionsError::ConcurrentModification(_) => "ConcurrentModificationException",
+            RevokePermissionsError::EntityNotFound(_) => "EntityNotFoundException",
+            RevokePermissionsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by UpdateResource
 #[derive(Debug, PartialEq)]
 pub enum UpdateResourceError {
@@ -1206,6 +1315,16 @@ impl fmt::Display for UpdateResourceError {
     }
 }
 impl Error for UpdateResourceError {}
+impl ServiceErrorCode for UpdateResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateResourceError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateResourceError::InternalService(_) => "InternalServiceException",
+            UpdateResourceError::InvalidInput(_) => "InvalidInputException",
+            UpdateResourceError::OperationTimeout(_) => "OperationTimeoutException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Lake Formation API. AWS Lake Formation clients implement this trait.
 #[async_trait]
 pub trait LakeFormation {
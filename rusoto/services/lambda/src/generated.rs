@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2462,6 +2462,23 @@ impl fmt::Display for AddLayerVersionPermissionError {
     }
 }
 impl Error for AddLayerVersionPermissionError {}
+impl ServiceErrorCode for AddLayerVersionPermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddLayerVersionPermissionError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            AddLayerVersionPermissionError::PolicyLengthExceeded(_) => {
+                "PolicyLengthExceededException"
+            }
+            AddLayerVersionPermissionError::PreconditionFailed(_) => "PreconditionFailedException",
+            AddLayerVersionPermissionError::ResourceConflict(_) => "ResourceConflictException",
+            AddLayerVersionPermissionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AddLayerVersionPermissionError::Service(_) => "ServiceException",
+            AddLayerVersionPermissionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by AddPermission
 #[derive(Debug, PartialEq)]
 pub enum AddPermissionError {
@@ -2528,6 +2545,19 @@ impl fmt::Display for AddPermissionError {
     }
 }
 impl Error for AddPermissionError {}
+impl ServiceErrorCode for AddPermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddPermissionError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            AddPermissionError::PolicyLengthExceeded(_) => "PolicyLengthExceededException",
+            AddPermissionError::PreconditionFailed(_) => "PreconditionFailedException",
+            AddPermissionError::ResourceConflict(_) => "ResourceConflictException",
+            AddPermissionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AddPermissionError::Service(_) => "ServiceException",
+            AddPermissionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateAlias
 #[derive(Debug, PartialEq)]
 pub enum CreateAliasError {
@@ -2582,6 +2612,17 @@ impl fmt::Display for CreateAliasError {
     }
 }
 impl Error for CreateAliasError {}
+impl ServiceErrorCode for CreateAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAliasError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateAliasError::ResourceConflict(_) => "ResourceConflictException",
+            CreateAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateAliasError::Service(_) => "ServiceException",
+            CreateAliasError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateCodeSigningConfig
 #[derive(Debug, PartialEq)]
 pub enum CreateCodeSigningConfigError {
@@ -2622,6 +2663,16 @@ impl fmt::Display for CreateCodeSigningConfigError {
     }
 }
 impl Error for CreateCodeSigningConfigError {}
+impl ServiceErrorCode for CreateCodeSigningConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCodeSigningConfigError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            CreateCodeSigningConfigError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by CreateEventSourceMapping
 #[derive(Debug, PartialEq)]
 pub enum CreateEventSourceMappingError {
@@ -2686,6 +2737,19 @@ impl fmt::Display for CreateEventSourceMappingError {
     }
 }
 impl Error for CreateEventSourceMappingError {}
+impl ServiceErrorCode for CreateEventSourceMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEventSourceMappingError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            CreateEventSourceMappingError::ResourceConflict(_) => "ResourceConflictException",
+            CreateEventSourceMappingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateEventSourceMappingError::Service(_) => "ServiceException",
+            CreateEventSourceMappingError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateFunction
 #[derive(Debug, PartialEq)]
 pub enum CreateFunctionError {
@@ -2770,6 +2834,23 @@ impl fmt::Display for CreateFunctionError {
     }
 }
 impl Error for CreateFunctionError {}
+impl ServiceErrorCode for CreateFunctionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFunctionError::CodeSigningConfigNotFound(_) => {
+                "CodeSigningConfigNotFoundException"
+            }
+            CreateFunctionError::CodeStorageExceeded(_) => "CodeStorageExceededException",
+            CreateFunctionError::CodeVerificationFailed(_) => "CodeVerificationFailedException",
+            CreateFunctionError::InvalidCodeSignature(_) => "InvalidCodeSignatureException",
+            CreateFunctionError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateFunctionError::ResourceConflict(_) => "ResourceConflictException",
+            CreateFunctionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateFunctionError::Service(_) => "ServiceException",
+            CreateFunctionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteAlias
 #[derive(Debug, PartialEq)]
 pub enum DeleteAliasError {
@@ -2818,6 +2899,16 @@ impl fmt::Display for DeleteAliasError {
     }
 }
 impl Error for DeleteAliasError {}
+impl ServiceErrorCode for DeleteAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAliasError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteAliasError::ResourceConflict(_) => "ResourceConflictException",
+            DeleteAliasError::Service(_) => "ServiceException",
+            DeleteAliasError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteCodeSigningConfig
 #[derive(Debug, PartialEq)]
 pub enum DeleteCodeSigningConfigError {
@@ -2874,6 +2965,18 @@ impl fmt::Display for DeleteCodeSigningConfigError {
     }
 }
 impl Error for DeleteCodeSigningConfigError {}
+impl ServiceErrorCode for DeleteCodeSigningConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCodeSigningConfigError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteCodeSigningConfigError::ResourceConflict(_) => "ResourceConflictException",
+            DeleteCodeSigningConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteCodeSigningConfigError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by DeleteEventSourceMapping
 #[derive(Debug, PartialEq)]
 pub enum DeleteEventSourceMappingError {
@@ -2938,6 +3041,19 @@ impl fmt::Display for DeleteEventSourceMappingError {
     }
 }
 impl Error for DeleteEventSourceMappingError {}
+impl ServiceErrorCode for DeleteEventSourceMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEventSourceMappingError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteEventSourceMappingError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteEventSourceMappingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteEventSourceMappingError::Service(_) => "ServiceException",
+            DeleteEventSourceMappingError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteFunction
 #[derive(Debug, PartialEq)]
 pub enum DeleteFunctionError {
@@ -2994,6 +3110,17 @@ impl fmt::Display for DeleteFunctionError {
     }
 }
 impl Error for DeleteFunctionError {}
+impl ServiceErrorCode for DeleteFunctionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFunctionError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteFunctionError::ResourceConflict(_) => "ResourceConflictException",
+            DeleteFunctionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteFunctionError::Service(_) => "ServiceException",
+            DeleteFunctionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteFunctionCodeSigningConfig
 #[derive(Debug, PartialEq)]
 pub enum DeleteFunctionCodeSigningConfigError {
@@ -3078,6 +3205,26 @@ impl fmt::Display for DeleteFunctionCodeSigningConfigError {
     }
 }
 impl Error for DeleteFunctionCodeSigningConfigError {}
+impl ServiceErrorCode for DeleteFunctionCodeSigningConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFunctionCodeSigningConfigError::CodeSigningConfigNotFound(_) => {
+                "CodeSigningConfigNotFoundException"
+            }
+            DeleteFunctionCodeSigningConfigError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteFunctionCodeSigningConfigError::ResourceConflict(_) => {
+                "ResourceConflictException"
+            }
+            DeleteFunctionCodeSigningConfigError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DeleteFunctionCodeSigningConfigError::Service(_) => "ServiceException",
+            DeleteFunctionCodeSigningConfigError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteFunctionConcurrency
 #[derive(Debug, PartialEq)]
 pub enum DeleteFunctionConcurrencyError {
@@ -3142,6 +3289,19 @@ impl fmt::Display for DeleteFunctionConcurrencyError {
     }
 }
 impl Error for DeleteFunctionConcurrencyError {}
+impl ServiceErrorCode for DeleteFunctionConcurrencyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFunctionConcurrencyError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteFunctionConcurrencyError::ResourceConflict(_) => "ResourceConflictException",
+            DeleteFunctionConcurrencyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteFunctionConcurrencyError::Service(_) => "ServiceException",
+            DeleteFunctionConcurrencyError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteFunctionEventInvokeConfig
 #[derive(Debug, PartialEq)]
 pub enum DeleteFunctionEventInvokeConfigError {
@@ -3206,6 +3366,20 @@ impl fmt::Display for DeleteFunctionEventInvokeConfigError {
     }
 }
 impl Error for DeleteFunctionEventInvokeConfigError {}
+impl ServiceErrorCode for DeleteFunctionEventInvokeConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFunctionEventInvokeConfigError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteFunctionEventInvokeConfigError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DeleteFunctionEventInvokeConfigError::Service(_) => "ServiceException",
+            DeleteFunctionEventInvokeConfigError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteLayerVersion
 #[derive(Debug, PartialEq)]
 pub enum DeleteLayerVersionError {
@@ -3242,6 +3416,14 @@ impl fmt::Display for DeleteLayerVersionError {
     }
 }
 impl Error for DeleteLayerVersionError {}
+impl ServiceErrorCode for DeleteLayerVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLayerVersionError::Service(_) => "ServiceException",
+            DeleteLayerVersionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteProvisionedConcurrencyConfig
 #[derive(Debug, PartialEq)]
 pub enum DeleteProvisionedConcurrencyConfigError {
@@ -3316,6 +3498,25 @@ impl fmt::Display for DeleteProvisionedConcurrencyConfigError {
     }
 }
 impl Error for DeleteProvisionedConcurrencyConfigError {}
+impl ServiceErrorCode for DeleteProvisionedConcurrencyConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProvisionedConcurrencyConfigError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteProvisionedConcurrencyConfigError::ResourceConflict(_) => {
+                "ResourceConflictException"
+            }
+            DeleteProvisionedConcurrencyConfigError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DeleteProvisionedConcurrencyConfigError::Service(_) => "ServiceException",
+            DeleteProvisionedConcurrencyConfigError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAccountSettings
 #[derive(Debug, PartialEq)]
 pub enum GetAccountSettingsError {
@@ -3352,6 +3553,14 @@ impl fmt::Display for GetAccountSettingsError {
     }
 }
 impl Error for GetAccountSettingsError {}
+impl ServiceErrorCode for GetAccountSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAccountSettingsError::Service(_) => "ServiceException",
+            GetAccountSettingsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetAlias
 #[derive(Debug, PartialEq)]
 pub enum GetAliasError {
@@ -3398,6 +3607,16 @@ impl fmt::Display for GetAliasError {
     }
 }
 impl Error for GetAliasError {}
+impl ServiceErrorCode for GetAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAliasError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetAliasError::Service(_) => "ServiceException",
+            GetAliasError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetCodeSigningConfig
 #[derive(Debug, PartialEq)]
 pub enum GetCodeSigningConfigError {
@@ -3444,6 +3663,15 @@ impl fmt::Display for GetCodeSigningConfigError {
     }
 }
 impl Error for GetCodeSigningConfigError {}
+impl ServiceErrorCode for GetCodeSigningConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCodeSigningConfigError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetCodeSigningConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetCodeSigningConfigError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by GetEventSourceMapping
 #[derive(Debug, PartialEq)]
 pub enum GetEventSourceMappingError {
@@ -3498,6 +3726,18 @@ impl fmt::Display for GetEventSourceMappingError {
     }
 }
 impl Error for GetEventSourceMappingError {}
+impl ServiceErrorCode for GetEventSourceMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEventSourceMappingError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetEventSourceMappingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetEventSourceMappingError::Service(_) => "ServiceException",
+            GetEventSourceMappingError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetFunction
 #[derive(Debug, PartialEq)]
 pub enum GetFunctionError {
@@ -3546,6 +3786,16 @@ impl fmt::Display for GetFunctionError {
     }
 }
 impl Error for GetFunctionError {}
+impl ServiceErrorCode for GetFunctionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFunctionError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetFunctionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetFunctionError::Service(_) => "ServiceException",
+            GetFunctionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetFunctionCodeSigningConfig
 #[derive(Debug, PartialEq)]
 pub enum GetFunctionCodeSigningConfigError {
@@ -3608,6 +3858,18 @@ impl fmt::Display for GetFunctionCodeSigningConfigError {
     }
 }
 impl Error for GetFunctionCodeSigningConfigError {}
+impl ServiceErrorCode for GetFunctionCodeSigningConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFunctionCodeSigningConfigError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetFunctionCodeSigningConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetFunctionCodeSigningConfigError::Service(_) => "ServiceException",
+            GetFunctionCodeSigningConfigError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetFunctionConcurrency
 #[derive(Debug, PartialEq)]
 pub enum GetFunctionConcurrencyError {
@@ -3662,6 +3924,18 @@ impl fmt::Display for GetFunctionConcurrencyError {
     }
 }
 impl Error for GetFunctionConcurrencyError {}
+impl ServiceErrorCode for GetFunctionConcurrencyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFunctionConcurrencyError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetFunctionConcurrencyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetFunctionConcurrencyError::Service(_) => "ServiceException",
+            GetFunctionConcurrencyError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetFunctionConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetFunctionConfigurationError {
@@ -3718,6 +3992,18 @@ impl fmt::Display for GetFunctionConfigurationError {
     }
 }
 impl Error for GetFunctionConfigurationError {}
+impl ServiceErrorCode for GetFunctionConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFunctionConfigurationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetFunctionConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetFunctionConfigurationError::Service(_) => "ServiceException",
+            GetFunctionConfigurationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetFunctionEventInvokeConfig
 #[derive(Debug, PartialEq)]
 pub enum GetFunctionEventInvokeConfigError {
@@ -3780,6 +4066,18 @@ impl fmt::Display for GetFunctionEventInvokeConfigError {
     }
 }
 impl Error for GetFunctionEventInvokeConfigError {}
+impl ServiceErrorCode for GetFunctionEventInvokeConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFunctionEventInvokeConfigError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetFunctionEventInvokeConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetFunctionEventInvokeConfigError::Service(_) => "ServiceException",
+            GetFunctionEventInvokeConfigError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetLayerVersion
 #[derive(Debug, PartialEq)]
 pub enum GetLayerVersionError {
@@ -3830,6 +4128,16 @@ impl fmt::Display for GetLayerVersionError {
     }
 }
 impl Error for GetLayerVersionError {}
+impl ServiceErrorCode for GetLayerVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLayerVersionError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetLayerVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetLayerVersionError::Service(_) => "ServiceException",
+            GetLayerVersionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetLayerVersionByArn
 #[derive(Debug, PartialEq)]
 pub enum GetLayerVersionByArnError {
@@ -3884,6 +4192,16 @@ impl fmt::Display for GetLayerVersionByArnError {
     }
 }
 impl Error for GetLayerVersionByArnError {}
+impl ServiceErrorCode for GetLayerVersionByArnError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLayerVersionByArnError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetLayerVersionByArnError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetLayerVersionByArnError::Service(_) => "ServiceException",
+            GetLayerVersionByArnError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetLayerVersionPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetLayerVersionPolicyError {
@@ -3938,6 +4256,18 @@ impl fmt::Display for GetLayerVersionPolicyError {
     }
 }
 impl Error for GetLayerVersionPolicyError {}
+impl ServiceErrorCode for GetLayerVersionPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLayerVersionPolicyError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetLayerVersionPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetLayerVersionPolicyError::Service(_) => "ServiceException",
+            GetLayerVersionPolicyError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetPolicyError {
@@ -3986,6 +4316,16 @@ impl fmt::Display for GetPolicyError {
     }
 }
 impl Error for GetPolicyError {}
+impl ServiceErrorCode for GetPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPolicyError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetPolicyError::Service(_) => "ServiceException",
+            GetPolicyError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetProvisionedConcurrencyConfig
 #[derive(Debug, PartialEq)]
 pub enum GetProvisionedConcurrencyConfigError {
@@ -4062,6 +4402,23 @@ impl fmt::Display for GetProvisionedConcurrencyConfigError {
     }
 }
 impl Error for GetProvisionedConcurrencyConfigError {}
+impl ServiceErrorCode for GetProvisionedConcurrencyConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetProvisionedConcurrencyConfigError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetProvisionedConcurrencyConfigError::ProvisionedConcurrencyConfigNotFound(_) => {
+                "ProvisionedConcurrencyConfigNotFoundException"
+            }
+            GetProvisionedConcurrencyConfigError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            GetProvisionedConcurrencyConfigError::Service(_) => "ServiceException",
+            GetProvisionedConcurrencyConfigError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by Invoke
 #[derive(Debug, PartialEq)]
 pub enum InvokeError {
@@ -4238,6 +4595,38 @@ impl fmt::Display for InvokeError {
     }
 }
 impl Error for InvokeError {}
+impl ServiceErrorCode for InvokeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InvokeError::EC2AccessDenied(_) => "EC2AccessDeniedException",
+            InvokeError::EC2Throttled(_) => "EC2ThrottledException",
+            InvokeError::EC2Unexpected(_) => "EC2UnexpectedException",
+            InvokeError::EFSIO(_) => "EFSIOException",
+            InvokeError::EFSMountConnectivity(_) => "EFSMountConnectivityException",
+            InvokeError::EFSMountFailure(_) => "EFSMountFailureException",
+            InvokeError::EFSMountTimeout(_) => "EFSMountTimeoutException",
+            InvokeError::ENILimitReached(_) => "ENILimitReachedException",
+            InvokeError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            InvokeError::InvalidRequestContent(_) => "InvalidRequestContentException",
+            InvokeError::InvalidRuntime(_) => "InvalidRuntimeException",
+            InvokeError::InvalidSecurityGroupID(_) => "InvalidSecurityGroupIDException",
+            InvokeError::InvalidSubnetID(_) => "InvalidSubnetIDException",
+            InvokeError::InvalidZipFile(_) => "InvalidZipFileException",
+            InvokeError::KMSAccessDenied(_) => "KMSAccessDeniedException",
+            InvokeError::KMSDisabled(_) => "KMSDisabledException",
+            InvokeError::KMSInvalidState(_) => "KMSInvalidStateException",
+            InvokeError::KMSNotFound(_) => "KMSNotFoundException",
+            InvokeError::RequestTooLarge(_) => "RequestTooLargeException",
+            InvokeError::ResourceConflict(_) => "ResourceConflictException",
+            InvokeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            InvokeError::ResourceNotReady(_) => "ResourceNotReadyException",
+            InvokeError::Service(_) => "ServiceException",
+            InvokeError::SubnetIPAddressLimitReached(_) => "SubnetIPAddressLimitReachedException",
+            InvokeError::TooManyRequests(_) => "TooManyRequestsException",
+            InvokeError::UnsupportedMediaType(_) => "UnsupportedMediaTypeException",
+        }
+    }
+}
 /// Errors returned by InvokeAsync
 #[derive(Debug, PartialEq)]
 pub enum InvokeAsyncError {
@@ -4292,6 +4681,17 @@ impl fmt::Display for InvokeAsyncError {
     }
 }
 impl Error for InvokeAsyncError {}
+impl ServiceErrorCode for InvokeAsyncError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InvokeAsyncError::InvalidRequestContent(_) => "InvalidRequestContentException",
+            InvokeAsyncError::InvalidRuntime(_) => "InvalidRuntimeException",
+            InvokeAsyncError::ResourceConflict(_) => "ResourceConflictException",
+            InvokeAsyncError::ResourceNotFound(_) => "ResourceNotFoundException",
+            InvokeAsyncError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by ListAliases
 #[derive(Debug, PartialEq)]
 pub enum ListAliasesError {
@@ -4340,6 +4740,16 @@ impl fmt::Display for ListAliasesError {
     }
 }
 impl Error for ListAliasesError {}
+impl ServiceErrorCode for ListAliasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAliasesError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListAliasesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListAliasesError::Service(_) => "ServiceException",
+            ListAliasesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListCodeSigningConfigs
 #[derive(Debug, PartialEq)]
 pub enum ListCodeSigningConfigsError {
@@ -4378,6 +4788,16 @@ impl fmt::Display for ListCodeSigningConfigsError {
     }
 }
 impl Error for ListCodeSigningConfigsError {}
+impl ServiceErrorCode for ListCodeSigningConfigsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCodeSigningConfigsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListCodeSigningConfigsError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by ListEventSourceMappings
 #[derive(Debug, PartialEq)]
 pub enum ListEventSourceMappingsError {
@@ -4434,6 +4854,18 @@ impl fmt::Display for ListEventSourceMappingsError {
     }
 }
 impl Error for ListEventSourceMappingsError {}
+impl ServiceErrorCode for ListEventSourceMappingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEventSourceMappingsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListEventSourceMappingsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListEventSourceMappingsError::Service(_) => "ServiceException",
+            ListEventSourceMappingsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListFunctionEventInvokeConfigs
 #[derive(Debug, PartialEq)]
 pub enum ListFunctionEventInvokeConfigsError {
@@ -4498,6 +4930,18 @@ impl fmt::Display for ListFunctionEventInvokeConfigsError {
     }
 }
 impl Error for ListFunctionEventInvokeConfigsError {}
+impl ServiceErrorCode for ListFunctionEventInvokeConfigsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFunctionEventInvokeConfigsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListFunctionEventInvokeConfigsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListFunctionEventInvokeConfigsError::Service(_) => "ServiceException",
+            ListFunctionEventInvokeConfigsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListFunctions
 #[derive(Debug, PartialEq)]
 pub enum ListFunctionsError {
@@ -4540,6 +4984,15 @@ impl fmt::Display for ListFunctionsError {
     }
 }
 impl Error for ListFunctionsError {}
+impl ServiceErrorCode for ListFunctionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFunctionsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListFunctionsError::Service(_) => "ServiceException",
+            ListFunctionsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListFunctionsByCodeSigningConfig
 #[derive(Debug, PartialEq)]
 pub enum ListFunctionsByCodeSigningConfigError {
@@ -4594,6 +5047,19 @@ impl fmt::Display for ListFunctionsByCodeSigningConfigError {
     }
 }
 impl Error for ListFunctionsByCodeSigningConfigError {}
+impl ServiceErrorCode for ListFunctionsByCodeSigningConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFunctionsByCodeSigningConfigError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListFunctionsByCodeSigningConfigError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            ListFunctionsByCodeSigningConfigError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by ListLayerVersions
 #[derive(Debug, PartialEq)]
 pub enum ListLayerVersionsError {
@@ -4644,6 +5110,16 @@ impl fmt::Display for ListLayerVersionsError {
     }
 }
 impl Error for ListLayerVersionsError {}
+impl ServiceErrorCode for ListLayerVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLayerVersionsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListLayerVersionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListLayerVersionsError::Service(_) => "ServiceException",
+            ListLayerVersionsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListLayers
 #[derive(Debug, PartialEq)]
 pub enum ListLayersError {
@@ -4686,6 +5162,15 @@ impl fmt::Display for ListLayersError {
     }
 }
 impl Error for ListLayersError {}
+impl ServiceErrorCode for ListLayersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLayersError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListLayersError::Service(_) => "ServiceException",
+            ListLayersError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListProvisionedConcurrencyConfigs
 #[derive(Debug, PartialEq)]
 pub enum ListProvisionedConcurrencyConfigsError {
@@ -4750,6 +5235,22 @@ impl fmt::Display for ListProvisionedConcurrencyConfigsError {
     }
 }
 impl Error for ListProvisionedConcurrencyConfigsError {}
+impl ServiceErrorCode for ListProvisionedConcurrencyConfigsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProvisionedConcurrencyConfigsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListProvisionedConcurrencyConfigsError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            ListProvisionedConcurrencyConfigsError::Service(_) => "ServiceException",
+            ListProvisionedConcurrencyConfigsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
 pub enum ListTagsError {
@@ -4796,6 +5297,16 @@ impl fmt::Display for ListTagsError {
     }
 }
 impl Error for ListTagsError {}
+impl ServiceErrorCode for ListTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsError::Service(_) => "ServiceException",
+            ListTagsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListVersionsByFunction
 #[derive(Debug, PartialEq)]
 pub enum ListVersionsByFunctionError {
@@ -4850,6 +5361,18 @@ impl fmt::Display for ListVersionsByFunctionError {
     }
 }
 impl Error for ListVersionsByFunctionError {}
+impl ServiceErrorCode for ListVersionsByFunctionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVersionsByFunctionError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListVersionsByFunctionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListVersionsByFunctionError::Service(_) => "ServiceException",
+            ListVersionsByFunctionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PublishLayerVersion
 #[derive(Debug, PartialEq)]
 pub enum PublishLayerVersionError {
@@ -4910,6 +5433,17 @@ impl fmt::Display for PublishLayerVersionError {
     }
 }
 impl Error for PublishLayerVersionError {}
+impl ServiceErrorCode for PublishLayerVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PublishLayerVersionError::CodeStorageExceeded(_) => "CodeStorageExceededException",
+            PublishLayerVersionError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            PublishLayerVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PublishLayerVersionError::Service(_) => "ServiceException",
+            PublishLayerVersionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PublishVersion
 #[derive(Debug, PartialEq)]
 pub enum PublishVersionError {
@@ -4978,6 +5512,19 @@ impl fmt::Display for PublishVersionError {
     }
 }
 impl Error for PublishVersionError {}
+impl ServiceErrorCode for PublishVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PublishVersionError::CodeStorageExceeded(_) => "CodeStorageExceededException",
+            PublishVersionError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            PublishVersionError::PreconditionFailed(_) => "PreconditionFailedException",
+            PublishVersionError::ResourceConflict(_) => "ResourceConflictException",
+            PublishVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PublishVersionError::Service(_) => "ServiceException",
+            PublishVersionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutFunctionCodeSigningConfig
 #[derive(Debug, PartialEq)]
 pub enum PutFunctionCodeSigningConfigError {
@@ -5060,6 +5607,22 @@ impl fmt::Display for PutFunctionCodeSigningConfigError {
     }
 }
 impl Error for PutFunctionCodeSigningConfigError {}
+impl ServiceErrorCode for PutFunctionCodeSigningConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutFunctionCodeSigningConfigError::CodeSigningConfigNotFound(_) => {
+                "CodeSigningConfigNotFoundException"
+            }
+            PutFunctionCodeSigningConfigError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            PutFunctionCodeSigningConfigError::ResourceConflict(_) => "ResourceConflictException",
+            PutFunctionCodeSigningConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutFunctionCodeSigningConfigError::Service(_) => "ServiceException",
+            PutFunctionCodeSigningConfigError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutFunctionConcurrency
 #[derive(Debug, PartialEq)]
 pub enum PutFunctionConcurrencyError {
@@ -5122,6 +5685,19 @@ impl fmt::Display for PutFunctionConcurrencyError {
     }
 }
 impl Error for PutFunctionConcurrencyError {}
+impl ServiceErrorCode for PutFunctionConcurrencyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutFunctionConcurrencyError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            PutFunctionConcurrencyError::ResourceConflict(_) => "ResourceConflictException",
+            PutFunctionConcurrencyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutFunctionConcurrencyError::Service(_) => "ServiceException",
+            PutFunctionConcurrencyError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutFunctionEventInvokeConfig
 #[derive(Debug, PartialEq)]
 pub enum PutFunctionEventInvokeConfigError {
@@ -5184,6 +5760,18 @@ impl fmt::Display for PutFunctionEventInvokeConfigError {
     }
 }
 impl Error for PutFunctionEventInvokeConfigError {}
+impl ServiceErrorCode for PutFunctionEventInvokeConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutFunctionEventInvokeConfigError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            PutFunctionEventInvokeConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutFunctionEventInvokeConfigError::Service(_) => "ServiceException",
+            PutFunctionEventInvokeConfigError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutProvisionedConcurrencyConfig
 #[derive(Debug, PartialEq)]
 pub enum PutProvisionedConcurrencyConfigError {
@@ -5258,6 +5846,23 @@ impl fmt::Display for PutProvisionedConcurrencyConfigError {
     }
 }
 impl Error for PutProvisionedConcurrencyConfigError {}
+impl ServiceErrorCode for PutProvisionedConcurrencyConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutProvisionedConcurrencyConfigError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            PutProvisionedConcurrencyConfigError::ResourceConflict(_) => {
+                "ResourceConflictException"
+            }
+            PutProvisionedConcurrencyConfigError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            PutProvisionedConcurrencyConfigError::Service(_) => "ServiceException",
+            PutProvisionedConcurrencyConfigError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by RemoveLayerVersionPermission
 #[derive(Debug, PartialEq)]
 pub enum RemoveLayerVersionPermissionError {
@@ -5330,6 +5935,21 @@ impl fmt::Display for RemoveLayerVersionPermissionError {
     }
 }
 impl Error for RemoveLayerVersionPermissionError {}
+impl ServiceErrorCode for RemoveLayerVersionPermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveLayerVersionPermissionError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            RemoveLayerVersionPermissionError::PreconditionFailed(_) => {
+                "PreconditionFailedException"
+            }
+            RemoveLayerVersionPermissionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RemoveLayerVersionPermissionError::Service(_) => "ServiceException",
+            RemoveLayerVersionPermissionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by RemovePermission
 #[derive(Debug, PartialEq)]
 pub enum RemovePermissionError {
@@ -5386,6 +6006,17 @@ impl fmt::Display for RemovePermissionError {
     }
 }
 impl Error for RemovePermissionError {}
+impl ServiceErrorCode for RemovePermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemovePermissionError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            RemovePermissionError::PreconditionFailed(_) => "PreconditionFailedException",
+            RemovePermissionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RemovePermissionError::Service(_) => "ServiceException",
+            RemovePermissionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -5440,6 +6071,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            TagResourceError::ResourceConflict(_) => "ResourceConflictException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::Service(_) => "ServiceException",
+            TagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -5494,6 +6136,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UntagResourceError::ResourceConflict(_) => "ResourceConflictException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::Service(_) => "ServiceException",
+            UntagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateAlias
 #[derive(Debug, PartialEq)]
 pub enum UpdateAliasError {
@@ -5554,6 +6207,18 @@ impl fmt::Display for UpdateAliasError {
     }
 }
 impl Error for UpdateAliasError {}
+impl ServiceErrorCode for UpdateAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAliasError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateAliasError::PreconditionFailed(_) => "PreconditionFailedException",
+            UpdateAliasError::ResourceConflict(_) => "ResourceConflictException",
+            UpdateAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateAliasError::Service(_) => "ServiceException",
+            UpdateAliasError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateCodeSigningConfig
 #[derive(Debug, PartialEq)]
 pub enum UpdateCodeSigningConfigError {
@@ -5602,6 +6267,17 @@ impl fmt::Display for UpdateCodeSigningConfigError {
     }
 }
 impl Error for UpdateCodeSigningConfigError {}
+impl ServiceErrorCode for UpdateCodeSigningConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCodeSigningConfigError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateCodeSigningConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateCodeSigningConfigError::Service(_) => "ServiceException",
+        }
+    }
+}
 /// Errors returned by UpdateEventSourceMapping
 #[derive(Debug, PartialEq)]
 pub enum UpdateEventSourceMappingError {
@@ -5674,6 +6350,20 @@ impl fmt::Display for UpdateEventSourceMappingError {
     }
 }
 impl Error for UpdateEventSourceMappingError {}
+impl ServiceErrorCode for UpdateEventSourceMappingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateEventSourceMappingError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateEventSourceMappingError::ResourceConflict(_) => "ResourceConflictException",
+            UpdateEventSourceMappingError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateEventSourceMappingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateEventSourceMappingError::Service(_) => "ServiceException",
+            UpdateEventSourceMappingError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateFunctionCode
 #[derive(Debug, PartialEq)]
 pub enum UpdateFunctionCodeError {
@@ -5770,6 +6460,24 @@ impl fmt::Display for UpdateFunctionCodeError {
     }
 }
 impl Error for UpdateFunctionCodeError {}
+impl ServiceErrorCode for UpdateFunctionCodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFunctionCodeError::CodeSigningConfigNotFound(_) => {
+                "CodeSigningConfigNotFoundException"
+            }
+            UpdateFunctionCodeError::CodeStorageExceeded(_) => "CodeStorageExceededException",
+            UpdateFunctionCodeError::CodeVerificationFailed(_) => "CodeVerificationFailedException",
+            UpdateFunctionCodeError::InvalidCodeSignature(_) => "InvalidCodeSignatureException",
+            UpdateFunctionCodeError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateFunctionCodeError::PreconditionFailed(_) => "PreconditionFailedException",
+            UpdateFunctionCodeError::ResourceConflict(_) => "ResourceConflictException",
+            UpdateFunctionCodeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateFunctionCodeError::Service(_) => "ServiceException",
+            UpdateFunctionCodeError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateFunctionConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateFunctionConfigurationError {
@@ -5876,6 +6584,31 @@ impl fmt::Display for UpdateFunctionConfigurationError {
     }
 }
 impl Error for UpdateFunctionConfigurationError {}
+impl ServiceErrorCode for UpdateFunctionConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFunctionConfigurationError::CodeSigningConfigNotFound(_) => {
+                "CodeSigningConfigNotFoundException"
+            }
+            UpdateFunctionConfigurationError::CodeVerificationFailed(_) => {
+                "CodeVerificationFailedException"
+            }
+            UpdateFunctionConfigurationError::InvalidCodeSignature(_) => {
+                "InvalidCodeSignatureException"
+            }
+            UpdateFunctionConfigurationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateFunctionConfigurationError::PreconditionFailed(_) => {
+                "PreconditionFailedException"
+            }
+            UpdateFunctionConfigurationError::ResourceConflict(_) => "ResourceConflictException",
+            UpdateFunctionConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateFunctionConfigurationError::Service(_) => "ServiceException",
+            UpdateFunctionConfigurationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateFunctionEventInvokeConfig
 #[derive(Debug, PartialEq)]
 pub enum UpdateFunctionEventInvokeConfigError {
@@ -5940,6 +6673,20 @@ impl fmt::Display for UpdateFunctionEventInvokeConfigError {
     }
 }
 impl Error for UpdateFunctionEventInvokeConfigError {}
+impl ServiceErrorCode for UpdateFunctionEventInvokeConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFunctionEventInvokeConfigError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateFunctionEventInvokeConfigError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            UpdateFunctionEventInvokeConfigError::Service(_) => "ServiceException",
+            UpdateFunctionEventInvokeConfigError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Lambda API. AWS Lambda clients implement this trait.
 #[async_trait]
 pub trait Lambda {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2307,6 +2307,18 @@ impl fmt::Display for CreateBotVersionError {
     }
 }
 impl Error for CreateBotVersionError {}
+impl ServiceErrorCode for CreateBotVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBotVersionError::BadRequest(_) => "BadRequestException",
+            CreateBotVersionError::Conflict(_) => "ConflictException",
+            CreateBotVersionError::InternalFailure(_) => "InternalFailureException",
+            CreateBotVersionError::LimitExceeded(_) => "LimitExceededException",
+            CreateBotVersionError::NotFound(_) => "NotFoundException",
+            CreateBotVersionError::PreconditionFailed(_) => "PreconditionFailedException",
+        }
+    }
+}
 /// Errors returned by CreateIntentVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateIntentVersionError {
@@ -2369,6 +2381,18 @@ impl fmt::Display for CreateIntentVersionError {
     }
 }
 impl Error for CreateIntentVersionError {}
+impl ServiceErrorCode for CreateIntentVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateIntentVersionError::BadRequest(_) => "BadRequestException",
+            CreateIntentVersionError::Conflict(_) => "ConflictException",
+            CreateIntentVersionError::InternalFailure(_) => "InternalFailureException",
+            CreateIntentVersionError::LimitExceeded(_) => "LimitExceededException",
+            CreateIntentVersionError::NotFound(_) => "NotFoundException",
+            CreateIntentVersionError::PreconditionFailed(_) => "PreconditionFailedException",
+        }
+    }
+}
 /// Errors returned by CreateSlotTypeVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateSlotTypeVersionError {
@@ -2433,6 +2457,18 @@ impl fmt::Display for CreateSlotTypeVersionError {
     }
 }
 impl Error for CreateSlotTypeVersionError {}
+impl ServiceErrorCode for CreateSlotTypeVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSlotTypeVersionError::BadRequest(_) => "BadRequestException",
+            CreateSlotTypeVersionError::Conflict(_) => "ConflictException",
+            CreateSlotTypeVersionError::InternalFailure(_) => "InternalFailureException",
+            CreateSlotTypeVersionError::LimitExceeded(_) => "LimitExceededException",
+            CreateSlotTypeVersionError::NotFound(_) => "NotFoundException",
+            CreateSlotTypeVersionError::PreconditionFailed(_) => "PreconditionFailedException",
+        }
+    }
+}
 /// Errors returned by DeleteBot
 #[derive(Debug, PartialEq)]
 pub enum DeleteBotError {
@@ -2493,6 +2529,18 @@ impl fmt::Display for DeleteBotError {
     }
 }
 impl Error for DeleteBotError {}
+impl ServiceErrorCode for DeleteBotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBotError::BadRequest(_) => "BadRequestException",
+            DeleteBotError::Conflict(_) => "ConflictException",
+            DeleteBotError::InternalFailure(_) => "InternalFailureException",
+            DeleteBotError::LimitExceeded(_) => "LimitExceededException",
+            DeleteBotError::NotFound(_) => "NotFoundException",
+            DeleteBotError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteBotAlias
 #[derive(Debug, PartialEq)]
 pub enum DeleteBotAliasError {
@@ -2553,6 +2601,18 @@ impl fmt::Display for DeleteBotAliasError {
     }
 }
 impl Error for DeleteBotAliasError {}
+impl ServiceErrorCode for DeleteBotAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBotAliasError::BadRequest(_) => "BadRequestException",
+            DeleteBotAliasError::Conflict(_) => "ConflictException",
+            DeleteBotAliasError::InternalFailure(_) => "InternalFailureException",
+            DeleteBotAliasError::LimitExceeded(_) => "LimitExceededException",
+            DeleteBotAliasError::NotFound(_) => "NotFoundException",
+            DeleteBotAliasError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteBotChannelAssociation
 #[derive(Debug, PartialEq)]
 pub enum DeleteBotChannelAssociationError {
@@ -2619,6 +2679,17 @@ impl fmt::Display for DeleteBotChannelAssociationError {
     }
 }
 impl Error for DeleteBotChannelAssociationError {}
+impl ServiceErrorCode for DeleteBotChannelAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBotChannelAssociationError::BadRequest(_) => "BadRequestException",
+            DeleteBotChannelAssociationError::Conflict(_) => "ConflictException",
+            DeleteBotChannelAssociationError::InternalFailure(_) => "InternalFailureException",
+            DeleteBotChannelAssociationError::LimitExceeded(_) => "LimitExceededException",
+            DeleteBotChannelAssociationError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteBotVersion
 #[derive(Debug, PartialEq)]
 pub enum DeleteBotVersionError {
@@ -2679,6 +2750,18 @@ impl fmt::Display for DeleteBotVersionError {
     }
 }
 impl Error for DeleteBotVersionError {}
+impl ServiceErrorCode for DeleteBotVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBotVersionError::BadRequest(_) => "BadRequestException",
+            DeleteBotVersionError::Conflict(_) => "ConflictException",
+            DeleteBotVersionError::InternalFailure(_) => "InternalFailureException",
+            DeleteBotVersionError::LimitExceeded(_) => "LimitExceededException",
+            DeleteBotVersionError::NotFound(_) => "NotFoundException",
+            DeleteBotVersionError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteIntent
 #[derive(Debug, PartialEq)]
 pub enum DeleteIntentError {
@@ -2739,6 +2822,18 @@ impl fmt::Display for DeleteIntentError {
     }
 }
 impl Error for DeleteIntentError {}
+impl ServiceErrorCode for DeleteIntentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIntentError::BadRequest(_) => "BadRequestException",
+            DeleteIntentError::Conflict(_) => "ConflictException",
+            DeleteIntentError::InternalFailure(_) => "InternalFailureException",
+            DeleteIntentError::LimitExceeded(_) => "LimitExceededException",
+            DeleteIntentError::NotFound(_) => "NotFoundException",
+            DeleteIntentError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteIntentVersion
 #[derive(Debug, PartialEq)]
 pub enum DeleteIntentVersionError {
@@ -2799,6 +2894,18 @@ impl fmt::Display for DeleteIntentVersionError {
     }
 }
 impl Error for DeleteIntentVersionError {}
+impl ServiceErrorCode for DeleteIntentVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIntentVersionError::BadRequest(_) => "BadRequestException",
+            DeleteIntentVersionError::Conflict(_) => "ConflictException",
+            DeleteIntentVersionError::InternalFailure(_) => "InternalFailureException",
+            DeleteIntentVersionError::LimitExceeded(_) => "LimitExceededException",
+            DeleteIntentVersionError::NotFound(_) => "NotFoundException",
+            DeleteIntentVersionError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteSlotType
 #[derive(Debug, PartialEq)]
 pub enum DeleteSlotTypeError {
@@ -2859,6 +2966,18 @@ impl fmt::Display for DeleteSlotTypeError {
     }
 }
 impl Error for DeleteSlotTypeError {}
+impl ServiceErrorCode for DeleteSlotTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSlotTypeError::BadRequest(_) => "BadRequestException",
+            DeleteSlotTypeError::Conflict(_) => "ConflictException",
+            DeleteSlotTypeError::InternalFailure(_) => "InternalFailureException",
+            DeleteSlotTypeError::LimitExceeded(_) => "LimitExceededException",
+            DeleteSlotTypeError::NotFound(_) => "NotFoundException",
+            DeleteSlotTypeError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteSlotTypeVersion
 #[derive(Debug, PartialEq)]
 pub enum DeleteSlotTypeVersionError {
@@ -2921,6 +3040,18 @@ impl fmt::Display for DeleteSlotTypeVersionError {
     }
 }
 impl Error for DeleteSlotTypeVersionError {}
+impl ServiceErrorCode for DeleteSlotTypeVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSlotTypeVersionError::BadRequest(_) => "BadRequestException",
+            DeleteSlotTypeVersionError::Conflict(_) => "ConflictException",
+            DeleteSlotTypeVersionError::InternalFailure(_) => "InternalFailureException",
+            DeleteSlotTypeVersionError::LimitExceeded(_) => "LimitExceededException",
+            DeleteSlotTypeVersionError::NotFound(_) => "NotFoundException",
+            DeleteSlotTypeVersionError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteUtterances
 #[derive(Debug, PartialEq)]
 pub enum DeleteUtterancesError {
@@ -2969,6 +3100,16 @@ impl fmt::Display for DeleteUtterancesError {
     }
 }
 impl Error for DeleteUtterancesError {}
+impl ServiceErrorCode for DeleteUtterancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUtterancesError::BadRequest(_) => "BadRequestException",
+            DeleteUtterancesError::InternalFailure(_) => "InternalFailureException",
+            DeleteUtterancesError::LimitExceeded(_) => "LimitExceededException",
+            DeleteUtterancesError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetBot
 #[derive(Debug, PartialEq)]
 pub enum GetBotError {
@@ -3015,6 +3156,16 @@ impl fmt::Display for GetBotError {
     }
 }
 impl Error for GetBotError {}
+impl ServiceErrorCode for GetBotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBotError::BadRequest(_) => "BadRequestException",
+            GetBotError::InternalFailure(_) => "InternalFailureException",
+            GetBotError::LimitExceeded(_) => "LimitExceededException",
+            GetBotError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetBotAlias
 #[derive(Debug, PartialEq)]
 pub enum GetBotAliasError {
@@ -3063,6 +3214,16 @@ impl fmt::Display for GetBotAliasError {
     }
 }
 impl Error for GetBotAliasError {}
+impl ServiceErrorCode for GetBotAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBotAliasError::BadRequest(_) => "BadRequestException",
+            GetBotAliasError::InternalFailure(_) => "InternalFailureException",
+            GetBotAliasError::LimitExceeded(_) => "LimitExceededException",
+            GetBotAliasError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetBotAliases
 #[derive(Debug, PartialEq)]
 pub enum GetBotAliasesError {
@@ -3105,6 +3266,15 @@ impl fmt::Display for GetBotAliasesError {
     }
 }
 impl Error for GetBotAliasesError {}
+impl ServiceErrorCode for GetBotAliasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBotAliasesError::BadRequest(_) => "BadRequestException",
+            GetBotAliasesError::InternalFailure(_) => "InternalFailureException",
+            GetBotAliasesError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetBotChannelAssociation
 #[derive(Debug, PartialEq)]
 pub enum GetBotChannelAssociationError {
@@ -3157,6 +3327,16 @@ impl fmt::Display for GetBotChannelAssociationError {
     }
 }
 impl Error for GetBotChannelAssociationError {}
+impl ServiceErrorCode for GetBotChannelAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBotChannelAssociationError::BadRequest(_) => "BadRequestException",
+            GetBotChannelAssociationError::InternalFailure(_) => "InternalFailureException",
+            GetBotChannelAssociationError::LimitExceeded(_) => "LimitExceededException",
+            GetBotChannelAssociationError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetBotChannelAssociations
 #[derive(Debug, PartialEq)]
 pub enum GetBotChannelAssociationsError {
@@ -3205,6 +3385,15 @@ impl fmt::Display for GetBotChannelAssociationsError {
     }
 }
 impl Error for GetBotChannelAssociationsError {}
+impl ServiceErrorCode for GetBotChannelAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBotChannelAssociationsError::BadRequest(_) => "BadRequestException",
+            GetBotChannelAssociationsError::InternalFailure(_) => "InternalFailureException",
+            GetBotChannelAssociationsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetBotVersions
 #[derive(Debug, PartialEq)]
 pub enum GetBotVersionsError {
@@ -3253,6 +3442,16 @@ impl fmt::Display for GetBotVersionsError {
     }
 }
 impl Error for GetBotVersionsError {}
+impl ServiceErrorCode for GetBotVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBotVersionsError::BadRequest(_) => "BadRequestException",
+            GetBotVersionsError::InternalFailure(_) => "InternalFailureException",
+            GetBotVersionsError::LimitExceeded(_) => "LimitExceededException",
+            GetBotVersionsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetBots
 #[derive(Debug, PartialEq)]
 pub enum GetBotsError {
@@ -3301,6 +3500,16 @@ impl fmt::Display for GetBotsError {
     }
 }
 impl Error for GetBotsError {}
+impl ServiceErrorCode for GetBotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBotsError::BadRequest(_) => "BadRequestException",
+            GetBotsError::InternalFailure(_) => "InternalFailureException",
+            GetBotsError::LimitExceeded(_) => "LimitExceededException",
+            GetBotsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetBuiltinIntent
 #[derive(Debug, PartialEq)]
 pub enum GetBuiltinIntentError {
@@ -3349,6 +3558,16 @@ impl fmt::Display for GetBuiltinIntentError {
     }
 }
 impl Error for GetBuiltinIntentError {}
+impl ServiceErrorCode for GetBuiltinIntentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBuiltinIntentError::BadRequest(_) => "BadRequestException",
+            GetBuiltinIntentError::InternalFailure(_) => "InternalFailureException",
+            GetBuiltinIntentError::LimitExceeded(_) => "LimitExceededException",
+            GetBuiltinIntentError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetBuiltinIntents
 #[derive(Debug, PartialEq)]
 pub enum GetBuiltinIntentsError {
@@ -3391,6 +3610,15 @@ impl fmt::Display for GetBuiltinIntentsError {
     }
 }
 impl Error for GetBuiltinIntentsError {}
+impl ServiceErrorCode for GetBuiltinIntentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBuiltinIntentsError::BadRequest(_) => "BadRequestException",
+            GetBuiltinIntentsError::InternalFailure(_) => "InternalFailureException",
+            GetBuiltinIntentsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetBuiltinSlotTypes
 #[derive(Debug, PartialEq)]
 pub enum GetBuiltinSlotTypesError {
@@ -3433,6 +3661,15 @@ impl fmt::Display for GetBuiltinSlotTypesError {
     }
 }
 impl Error for GetBuiltinSlotTypesError {}
+impl ServiceErrorCode for GetBuiltinSlotTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBuiltinSlotTypesError::BadRequest(_) => "BadRequestException",
+            GetBuiltinSlotTypesError::InternalFailure(_) => "InternalFailureException",
+            GetBuiltinSlotTypesError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetExport
 #[derive(Debug, PartialEq)]
 pub enum GetExportError {
@@ -3481,6 +3718,16 @@ impl fmt::Display for GetExportError {
     }
 }
 impl Error for GetExportError {}
+impl ServiceErrorCode for GetExportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetExportError::BadRequest(_) => "BadRequestException",
+            GetExportError::InternalFailure(_) => "InternalFailureException",
+            GetExportError::LimitExceeded(_) => "LimitExceededException",
+            GetExportError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetImport
 #[derive(Debug, PartialEq)]
 pub enum GetImportError {
@@ -3529,6 +3776,16 @@ impl fmt::Display for GetImportError {
     }
 }
 impl Error for GetImportError {}
+impl ServiceErrorCode for GetImportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetImportError::BadRequest(_) => "BadRequestException",
+            GetImportError::InternalFailure(_) => "InternalFailureException",
+            GetImportError::LimitExceeded(_) => "LimitExceededException",
+            GetImportError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetIntent
 #[derive(Debug, PartialEq)]
 pub enum GetIntentError {
@@ -3577,6 +3834,16 @@ impl fmt::Display for GetIntentError {
     }
 }
 impl Error for GetIntentError {}
+impl ServiceErrorCode for GetIntentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIntentError::BadRequest(_) => "BadRequestException",
+            GetIntentError::InternalFailure(_) => "InternalFailureException",
+            GetIntentError::LimitExceeded(_) => "LimitExceededException",
+            GetIntentError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetIntentVersions
 #[derive(Debug, PartialEq)]
 pub enum GetIntentVersionsError {
@@ -3625,6 +3892,16 @@ impl fmt::Display for GetIntentVersionsError {
     }
 }
 impl Error for GetIntentVersionsError {}
+impl ServiceErrorCode for GetIntentVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIntentVersionsError::BadRequest(_) => "BadRequestException",
+            GetIntentVersionsError::InternalFailure(_) => "InternalFailureException",
+            GetIntentVersionsError::LimitExceeded(_) => "LimitExceededException",
+            GetIntentVersionsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetIntents
 #[derive(Debug, PartialEq)]
 pub enum GetIntentsError {
@@ -3673,6 +3950,16 @@ impl fmt::Display for GetIntentsError {
     }
 }
 impl Error for GetIntentsError {}
+impl ServiceErrorCode for GetIntentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIntentsError::BadRequest(_) => "BadRequestException",
+            GetIntentsError::InternalFailure(_) => "InternalFailureException",
+            GetIntentsError::LimitExceeded(_) => "LimitExceededException",
+            GetIntentsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetSlotType
 #[derive(Debug, PartialEq)]
 pub enum GetSlotTypeError {
@@ -3721,6 +4008,16 @@ impl fmt::Display for GetSlotTypeError {
     }
 }
 impl Error for GetSlotTypeError {}
+impl ServiceErrorCode for GetSlotTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSlotTypeError::BadRequest(_) => "BadRequestException",
+            GetSlotTypeError::InternalFailure(_) => "InternalFailureException",
+            GetSlotTypeError::LimitExceeded(_) => "LimitExceededException",
+            GetSlotTypeError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetSlotTypeVersions
 #[derive(Debug, PartialEq)]
 pub enum GetSlotTypeVersionsError {
@@ -3769,6 +4066,16 @@ impl fmt::Display for GetSlotTypeVersionsError {
     }
 }
 impl Error for GetSlotTypeVersionsError {}
+impl ServiceErrorCode for GetSlotTypeVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSlotTypeVersionsError::BadRequest(_) => "BadRequestException",
+            GetSlotTypeVersionsError::InternalFailure(_) => "InternalFailureException",
+            GetSlotTypeVersionsError::LimitExceeded(_) => "LimitExceededException",
+            GetSlotTypeVersionsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetSlotTypes
 #[derive(Debug, PartialEq)]
 pub enum GetSlotTypesError {
@@ -3817,6 +4124,16 @@ impl fmt::Display for GetSlotTypesError {
     }
 }
 impl Error for GetSlotTypesError {}
+impl ServiceErrorCode for GetSlotTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSlotTypesError::BadRequest(_) => "BadRequestException",
+            GetSlotTypesError::InternalFailure(_) => "InternalFailureException",
+            GetSlotTypesError::LimitExceeded(_) => "LimitExceededException",
+            GetSlotTypesError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetUtterancesView
 #[derive(Debug, PartialEq)]
 pub enum GetUtterancesViewError {
@@ -3859,6 +4176,15 @@ impl fmt::Display for GetUtterancesViewError {
     }
 }
 impl Error for GetUtterancesViewError {}
+impl ServiceErrorCode for GetUtterancesViewError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetUtterancesViewError::BadRequest(_) => "BadRequestException",
+            GetUtterancesViewError::InternalFailure(_) => "InternalFailureException",
+            GetUtterancesViewError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3907,6 +4233,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::InternalFailure(_) => "InternalFailureException",
+            ListTagsForResourceError::LimitExceeded(_) => "LimitExceededException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by PutBot
 #[derive(Debug, PartialEq)]
 pub enum PutBotError {
@@ -3959,6 +4295,17 @@ impl fmt::Display for PutBotError {
     }
 }
 impl Error for PutBotError {}
+impl ServiceErrorCode for PutBotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutBotError::BadRequest(_) => "BadRequestException",
+            PutBotError::Conflict(_) => "ConflictException",
+            PutBotError::InternalFailure(_) => "InternalFailureException",
+            PutBotError::LimitExceeded(_) => "LimitExceededException",
+            PutBotError::PreconditionFailed(_) => "PreconditionFailedException",
+        }
+    }
+}
 /// Errors returned by PutBotAlias
 #[derive(Debug, PartialEq)]
 pub enum PutBotAliasError {
@@ -4013,6 +4360,17 @@ impl fmt::Display for PutBotAliasError {
     }
 }
 impl Error for PutBotAliasError {}
+impl ServiceErrorCode for PutBotAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutBotAliasError::BadRequest(_) => "BadRequestException",
+            PutBotAliasError::Conflict(_) => "ConflictException",
+            PutBotAliasError::InternalFailure(_) => "InternalFailureException",
+            PutBotAliasError::LimitExceeded(_) => "LimitExceededException",
+            PutBotAliasError::PreconditionFailed(_) => "PreconditionFailedException",
+        }
+    }
+}
 /// Errors returned by PutIntent
 #[derive(Debug, PartialEq)]
 pub enum PutIntentError {
@@ -4067,6 +4425,17 @@ impl fmt::Display for PutIntentError {
     }
 }
 impl Error for PutIntentError {}
+impl ServiceErrorCode for PutIntentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutIntentError::BadRequest(_) => "BadRequestException",
+            PutIntentError::Conflict(_) => "ConflictException",
+            PutIntentError::InternalFailure(_) => "InternalFailureException",
+            PutIntentError::LimitExceeded(_) => "LimitExceededException",
+            PutIntentError::PreconditionFailed(_) => "PreconditionFailedException",
+        }
+    }
+}
 /// Errors returned by PutSlotType
 #[derive(Debug, PartialEq)]
 pub enum PutSlotTypeError {
@@ -4121,6 +4490,17 @@ impl fmt::Display for PutSlotTypeError {
     }
 }
 impl Error for PutSlotTypeError {}
+impl ServiceErrorCode for PutSlotTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutSlotTypeError::BadRequest(_) => "BadRequestException",
+            PutSlotTypeError::Conflict(_) => "ConflictException",
+            PutSlotTypeError::InternalFailure(_) => "InternalFailureException",
+            PutSlotTypeError::LimitExceeded(_) => "LimitExceededException",
+            PutSlotTypeError::PreconditionFailed(_) => "PreconditionFailedException",
+        }
+    }
+}
 /// Errors returned by StartImport
 #[derive(Debug, PartialEq)]
 pub enum StartImportError {
@@ -4163,6 +4543,15 @@ impl fmt::Display for StartImportError {
     }
 }
 impl Error for StartImportError {}
+impl ServiceErrorCode for StartImportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartImportError::BadRequest(_) => "BadRequestException",
+            StartImportError::InternalFailure(_) => "InternalFailureException",
+            StartImportError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -4217,6 +4606,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::Conflict(_) => "ConflictException",
+            TagResourceError::InternalFailure(_) => "InternalFailureException",
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -4271,6 +4671,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::Conflict(_) => "ConflictException",
+            UntagResourceError::InternalFailure(_) => "InternalFailureException",
+            UntagResourceError::LimitExceeded(_) => "LimitExceededException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Lex Model Building Service API. Amazon Lex Model Building Service clients implement this trait.
 #[async_trait]
 pub trait LexModels {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -573,6 +573,17 @@ impl fmt::Display for DeleteSessionError {
     }
 }
 impl Error for DeleteSessionError {}
+impl ServiceErrorCode for DeleteSessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSessionError::BadRequest(_) => "BadRequestException",
+            DeleteSessionError::Conflict(_) => "ConflictException",
+            DeleteSessionError::InternalFailure(_) => "InternalFailureException",
+            DeleteSessionError::LimitExceeded(_) => "LimitExceededException",
+            DeleteSessionError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetSession
 #[derive(Debug, PartialEq)]
 pub enum GetSessionError {
@@ -621,6 +632,16 @@ impl fmt::Display for GetSessionError {
     }
 }
 impl Error for GetSessionError {}
+impl ServiceErrorCode for GetSessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSessionError::BadRequest(_) => "BadRequestException",
+            GetSessionError::InternalFailure(_) => "InternalFailureException",
+            GetSessionError::LimitExceeded(_) => "LimitExceededException",
+            GetSessionError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by PostContent
 #[derive(Debug, PartialEq)]
 pub enum PostContentError {
@@ -711,6 +732,23 @@ impl fmt::Display for PostContentError {
     }
 }
 impl Error for PostContentError {}
+impl ServiceErrorCode for PostContentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PostContentError::BadGateway(_) => "BadGatewayException",
+            PostContentError::BadRequest(_) => "BadRequestException",
+            PostContentError::Conflict(_) => "ConflictException",
+            PostContentError::DependencyFailed(_) => "DependencyFailedException",
+            PostContentError::InternalFailure(_) => "InternalFailureException",
+            PostContentError::LimitExceeded(_) => "LimitExceededException",
+            PostContentError::LoopDetected(_) => "LoopDetectedException",
+            PostContentError::NotAcceptable(_) => "NotAcceptableException",
+            PostContentError::NotFound(_) => "NotFoundException",
+            PostContentError::RequestTimeout(_) => "RequestTimeoutException",
+            PostContentError::UnsupportedMediaType(_) => "UnsupportedMediaTypeException",
+        }
+    }
+}
 /// Errors returned by PostText
 #[derive(Debug, PartialEq)]
 pub enum PostTextError {
@@ -783,6 +821,20 @@ impl fmt::Display for PostTextError {
     }
 }
 impl Error for PostTextError {}
+impl ServiceErrorCode for PostTextError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PostTextError::BadGateway(_) => "BadGatewayException",
+            PostTextError::BadRequest(_) => "BadRequestException",
+            PostTextError::Conflict(_) => "ConflictException",
+            PostTextError::DependencyFailed(_) => "DependencyFailedException",
+            PostTextError::InternalFailure(_) => "InternalFailureException",
+            PostTextError::LimitExceeded(_) => "LimitExceededException",
+            PostTextError::LoopDetected(_) => "LoopDetectedException",
+            PostTextError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by PutSession
 #[derive(Debug, PartialEq)]
 pub enum PutSessionError {
@@ -855,6 +907,20 @@ impl fmt::Display for PutSessionError {
     }
 }
 impl Error for PutSessionError {}
+impl ServiceErrorCode for PutSessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutSessionError::BadGateway(_) => "BadGatewayException",
+            PutSessionError::BadRequest(_) => "BadRequestException",
+            PutSessionError::Conflict(_) => "ConflictException",
+            PutSessionError::DependencyFailed(_) => "DependencyFailedException",
+            PutSessionError::InternalFailure(_) => "InternalFailureException",
+            PutSessionError::LimitExceeded(_) => "LimitExceededException",
+            PutSessionError::NotAcceptable(_) => "NotAcceptableException",
+            PutSessionError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Lex Runtime Service API. Amazon Lex Runtime Service clients implement this trait.
 #[async_trait]
 pub trait LexRuntime {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2094,6 +2094,18 @@ impl fmt::Display for AcceptGrantError {
     }
 }
 impl Error for AcceptGrantError {}
+impl ServiceErrorCode for AcceptGrantError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptGrantError::AccessDenied(_) => "AccessDeniedException",
+            AcceptGrantError::Authorization(_) => "AuthorizationException",
+            AcceptGrantError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            AcceptGrantError::RateLimitExceeded(_) => "RateLimitExceededException",
+            AcceptGrantError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            AcceptGrantError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by CheckInLicense
 #[derive(Debug, PartialEq)]
 pub enum CheckInLicenseError {
@@ -2154,6 +2166,18 @@ impl fmt::Display for CheckInLicenseError {
     }
 }
 impl Error for CheckInLicenseError {}
+impl ServiceErrorCode for CheckInLicenseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CheckInLicenseError::AccessDenied(_) => "AccessDeniedException",
+            CheckInLicenseError::Authorization(_) => "AuthorizationException",
+            CheckInLicenseError::Conflict(_) => "ConflictException",
+            CheckInLicenseError::RateLimitExceeded(_) => "RateLimitExceededException",
+            CheckInLicenseError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CheckInLicenseError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by CheckoutBorrowLicense
 #[derive(Debug, PartialEq)]
 pub enum CheckoutBorrowLicenseError {
@@ -2246,6 +2270,27 @@ impl fmt::Display for CheckoutBorrowLicenseError {
     }
 }
 impl Error for CheckoutBorrowLicenseError {}
+impl ServiceErrorCode for CheckoutBorrowLicenseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CheckoutBorrowLicenseError::AccessDenied(_) => "AccessDeniedException",
+            CheckoutBorrowLicenseError::Authorization(_) => "AuthorizationException",
+            CheckoutBorrowLicenseError::EntitlementNotAllowed(_) => {
+                "EntitlementNotAllowedException"
+            }
+            CheckoutBorrowLicenseError::NoEntitlementsAllowed(_) => {
+                "NoEntitlementsAllowedException"
+            }
+            CheckoutBorrowLicenseError::RateLimitExceeded(_) => "RateLimitExceededException",
+            CheckoutBorrowLicenseError::Redirect(_) => "RedirectException",
+            CheckoutBorrowLicenseError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CheckoutBorrowLicenseError::ServerInternal(_) => "ServerInternalException",
+            CheckoutBorrowLicenseError::UnsupportedDigitalSignatureMethod(_) => {
+                "UnsupportedDigitalSignatureMethodException"
+            }
+        }
+    }
+}
 /// Errors returned by CheckoutLicense
 #[derive(Debug, PartialEq)]
 pub enum CheckoutLicenseError {
@@ -2324,6 +2369,22 @@ impl fmt::Display for CheckoutLicenseError {
     }
 }
 impl Error for CheckoutLicenseError {}
+impl ServiceErrorCode for CheckoutLicenseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CheckoutLicenseError::AccessDenied(_) => "AccessDeniedException",
+            CheckoutLicenseError::Authorization(_) => "AuthorizationException",
+            CheckoutLicenseError::NoEntitlementsAllowed(_) => "NoEntitlementsAllowedException",
+            CheckoutLicenseError::RateLimitExceeded(_) => "RateLimitExceededException",
+            CheckoutLicenseError::Redirect(_) => "RedirectException",
+            CheckoutLicenseError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CheckoutLicenseError::ServerInternal(_) => "ServerInternalException",
+            CheckoutLicenseError::UnsupportedDigitalSignatureMethod(_) => {
+                "UnsupportedDigitalSignatureMethodException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateGrant
 #[derive(Debug, PartialEq)]
 pub enum CreateGrantError {
@@ -2384,6 +2445,18 @@ impl fmt::Display for CreateGrantError {
     }
 }
 impl Error for CreateGrantError {}
+impl ServiceErrorCode for CreateGrantError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGrantError::AccessDenied(_) => "AccessDeniedException",
+            CreateGrantError::Authorization(_) => "AuthorizationException",
+            CreateGrantError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateGrantError::RateLimitExceeded(_) => "RateLimitExceededException",
+            CreateGrantError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateGrantError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by CreateGrantVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateGrantVersionError {
@@ -2450,6 +2523,18 @@ impl fmt::Display for CreateGrantVersionError {
     }
 }
 impl Error for CreateGrantVersionError {}
+impl ServiceErrorCode for CreateGrantVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGrantVersionError::AccessDenied(_) => "AccessDeniedException",
+            CreateGrantVersionError::Authorization(_) => "AuthorizationException",
+            CreateGrantVersionError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateGrantVersionError::RateLimitExceeded(_) => "RateLimitExceededException",
+            CreateGrantVersionError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateGrantVersionError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by CreateLicense
 #[derive(Debug, PartialEq)]
 pub enum CreateLicenseError {
@@ -2510,6 +2595,18 @@ impl fmt::Display for CreateLicenseError {
     }
 }
 impl Error for CreateLicenseError {}
+impl ServiceErrorCode for CreateLicenseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLicenseError::AccessDenied(_) => "AccessDeniedException",
+            CreateLicenseError::Authorization(_) => "AuthorizationException",
+            CreateLicenseError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateLicenseError::RateLimitExceeded(_) => "RateLimitExceededException",
+            CreateLicenseError::Redirect(_) => "RedirectException",
+            CreateLicenseError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by CreateLicenseConfiguration
 #[derive(Debug, PartialEq)]
 pub enum CreateLicenseConfigurationError {
@@ -2588,6 +2685,22 @@ impl fmt::Display for CreateLicenseConfigurationError {
     }
 }
 impl Error for CreateLicenseConfigurationError {}
+impl ServiceErrorCode for CreateLicenseConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLicenseConfigurationError::AccessDenied(_) => "AccessDeniedException",
+            CreateLicenseConfigurationError::Authorization(_) => "AuthorizationException",
+            CreateLicenseConfigurationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            CreateLicenseConfigurationError::RateLimitExceeded(_) => "RateLimitExceededException",
+            CreateLicenseConfigurationError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            CreateLicenseConfigurationError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by CreateLicenseVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateLicenseVersionError {
@@ -2658,6 +2771,19 @@ impl fmt::Display for CreateLicenseVersionError {
     }
 }
 impl Error for CreateLicenseVersionError {}
+impl ServiceErrorCode for CreateLicenseVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLicenseVersionError::AccessDenied(_) => "AccessDeniedException",
+            CreateLicenseVersionError::Authorization(_) => "AuthorizationException",
+            CreateLicenseVersionError::Conflict(_) => "ConflictException",
+            CreateLicenseVersionError::RateLimitExceeded(_) => "RateLimitExceededException",
+            CreateLicenseVersionError::Redirect(_) => "RedirectException",
+            CreateLicenseVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateLicenseVersionError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by CreateToken
 #[derive(Debug, PartialEq)]
 pub enum CreateTokenError {
@@ -2724,6 +2850,19 @@ impl fmt::Display for CreateTokenError {
     }
 }
 impl Error for CreateTokenError {}
+impl ServiceErrorCode for CreateTokenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTokenError::AccessDenied(_) => "AccessDeniedException",
+            CreateTokenError::Authorization(_) => "AuthorizationException",
+            CreateTokenError::RateLimitExceeded(_) => "RateLimitExceededException",
+            CreateTokenError::Redirect(_) => "RedirectException",
+            CreateTokenError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateTokenError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateTokenError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by DeleteGrant
 #[derive(Debug, PartialEq)]
 pub enum DeleteGrantError {
@@ -2784,6 +2923,18 @@ impl fmt::Display for DeleteGrantError {
     }
 }
 impl Error for DeleteGrantError {}
+impl ServiceErrorCode for DeleteGrantError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGrantError::AccessDenied(_) => "AccessDeniedException",
+            DeleteGrantError::Authorization(_) => "AuthorizationException",
+            DeleteGrantError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteGrantError::RateLimitExceeded(_) => "RateLimitExceededException",
+            DeleteGrantError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            DeleteGrantError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by DeleteLicense
 #[derive(Debug, PartialEq)]
 pub enum DeleteLicenseError {
@@ -2850,6 +3001,19 @@ impl fmt::Display for DeleteLicenseError {
     }
 }
 impl Error for DeleteLicenseError {}
+impl ServiceErrorCode for DeleteLicenseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLicenseError::AccessDenied(_) => "AccessDeniedException",
+            DeleteLicenseError::Authorization(_) => "AuthorizationException",
+            DeleteLicenseError::Conflict(_) => "ConflictException",
+            DeleteLicenseError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteLicenseError::RateLimitExceeded(_) => "RateLimitExceededException",
+            DeleteLicenseError::Redirect(_) => "RedirectException",
+            DeleteLicenseError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by DeleteLicenseConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteLicenseConfigurationError {
@@ -2918,6 +3082,19 @@ impl fmt::Display for DeleteLicenseConfigurationError {
     }
 }
 impl Error for DeleteLicenseConfigurationError {}
+impl ServiceErrorCode for DeleteLicenseConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLicenseConfigurationError::AccessDenied(_) => "AccessDeniedException",
+            DeleteLicenseConfigurationError::Authorization(_) => "AuthorizationException",
+            DeleteLicenseConfigurationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteLicenseConfigurationError::RateLimitExceeded(_) => "RateLimitExceededException",
+            DeleteLicenseConfigurationError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by DeleteToken
 #[derive(Debug, PartialEq)]
 pub enum DeleteTokenError {
@@ -2978,6 +3155,18 @@ impl fmt::Display for DeleteTokenError {
     }
 }
 impl Error for DeleteTokenError {}
+impl ServiceErrorCode for DeleteTokenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTokenError::AccessDenied(_) => "AccessDeniedException",
+            DeleteTokenError::Authorization(_) => "AuthorizationException",
+            DeleteTokenError::RateLimitExceeded(_) => "RateLimitExceededException",
+            DeleteTokenError::Redirect(_) => "RedirectException",
+            DeleteTokenError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteTokenError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by ExtendLicenseConsumption
 #[derive(Debug, PartialEq)]
 pub enum ExtendLicenseConsumptionError {
@@ -3052,6 +3241,20 @@ impl fmt::Display for ExtendLicenseConsumptionError {
     }
 }
 impl Error for ExtendLicenseConsumptionError {}
+impl ServiceErrorCode for ExtendLicenseConsumptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExtendLicenseConsumptionError::AccessDenied(_) => "AccessDeniedException",
+            ExtendLicenseConsumptionError::Authorization(_) => "AuthorizationException",
+            ExtendLicenseConsumptionError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ExtendLicenseConsumptionError::RateLimitExceeded(_) => "RateLimitExceededException",
+            ExtendLicenseConsumptionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ExtendLicenseConsumptionError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by GetAccessToken
 #[derive(Debug, PartialEq)]
 pub enum GetAccessTokenError {
@@ -3100,6 +3303,16 @@ impl fmt::Display for GetAccessTokenError {
     }
 }
 impl Error for GetAccessTokenError {}
+impl ServiceErrorCode for GetAccessTokenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAccessTokenError::AccessDenied(_) => "AccessDeniedException",
+            GetAccessTokenError::Authorization(_) => "AuthorizationException",
+            GetAccessTokenError::RateLimitExceeded(_) => "RateLimitExceededException",
+            GetAccessTokenError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by GetGrant
 #[derive(Debug, PartialEq)]
 pub enum GetGrantError {
@@ -3160,6 +3373,18 @@ impl fmt::Display for GetGrantError {
     }
 }
 impl Error for GetGrantError {}
+impl ServiceErrorCode for GetGrantError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGrantError::AccessDenied(_) => "AccessDeniedException",
+            GetGrantError::Authorization(_) => "AuthorizationException",
+            GetGrantError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetGrantError::RateLimitExceeded(_) => "RateLimitExceededException",
+            GetGrantError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            GetGrantError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by GetLicense
 #[derive(Debug, PartialEq)]
 pub enum GetLicenseError {
@@ -3214,6 +3439,17 @@ impl fmt::Display for GetLicenseError {
     }
 }
 impl Error for GetLicenseError {}
+impl ServiceErrorCode for GetLicenseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLicenseError::AccessDenied(_) => "AccessDeniedException",
+            GetLicenseError::Authorization(_) => "AuthorizationException",
+            GetLicenseError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetLicenseError::RateLimitExceeded(_) => "RateLimitExceededException",
+            GetLicenseError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by GetLicenseConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetLicenseConfigurationError {
@@ -3280,6 +3516,19 @@ impl fmt::Display for GetLicenseConfigurationError {
     }
 }
 impl Error for GetLicenseConfigurationError {}
+impl ServiceErrorCode for GetLicenseConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLicenseConfigurationError::AccessDenied(_) => "AccessDeniedException",
+            GetLicenseConfigurationError::Authorization(_) => "AuthorizationException",
+            GetLicenseConfigurationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            GetLicenseConfigurationError::RateLimitExceeded(_) => "RateLimitExceededException",
+            GetLicenseConfigurationError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by GetLicenseUsage
 #[derive(Debug, PartialEq)]
 pub enum GetLicenseUsageError {
@@ -3336,6 +3585,17 @@ impl fmt::Display for GetLicenseUsageError {
     }
 }
 impl Error for GetLicenseUsageError {}
+impl ServiceErrorCode for GetLicenseUsageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLicenseUsageError::AccessDenied(_) => "AccessDeniedException",
+            GetLicenseUsageError::Authorization(_) => "AuthorizationException",
+            GetLicenseUsageError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetLicenseUsageError::RateLimitExceeded(_) => "RateLimitExceededException",
+            GetLicenseUsageError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by GetServiceSettings
 #[derive(Debug, PartialEq)]
 pub enum GetServiceSettingsError {
@@ -3386,6 +3646,16 @@ impl fmt::Display for GetServiceSettingsError {
     }
 }
 impl Error for GetServiceSettingsError {}
+impl ServiceErrorCode for GetServiceSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetServiceSettingsError::AccessDenied(_) => "AccessDeniedException",
+            GetServiceSettingsError::Authorization(_) => "AuthorizationException",
+            GetServiceSettingsError::RateLimitExceeded(_) => "RateLimitExceededException",
+            GetServiceSettingsError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by ListAssociationsForLicenseConfiguration
 #[derive(Debug, PartialEq)]
 pub enum ListAssociationsForLicenseConfigurationError {
@@ -3474,6 +3744,30 @@ impl fmt::Display for ListAssociationsForLicenseConfigurationError {
     }
 }
 impl Error for ListAssociationsForLicenseConfigurationError {}
+impl ServiceErrorCode for ListAssociationsForLicenseConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAssociationsForLicenseConfigurationError::AccessDenied(_) => {
+                "AccessDeniedException"
+            }
+            ListAssociationsForLicenseConfigurationError::Authorization(_) => {
+                "AuthorizationException"
+            }
+            ListAssociationsForLicenseConfigurationError::FilterLimitExceeded(_) => {
+                "FilterLimitExceededException"
+            }
+            ListAssociationsForLicenseConfigurationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListAssociationsForLicenseConfigurationError::RateLimitExceeded(_) => {
+                "RateLimitExceededException"
+            }
+            ListAssociationsForLicenseConfigurationError::ServerInternal(_) => {
+                "ServerInternalException"
+            }
+        }
+    }
+}
 /// Errors returned by ListDistributedGrants
 #[derive(Debug, PartialEq)]
 pub enum ListDistributedGrantsError {
@@ -3542,6 +3836,22 @@ impl fmt::Display for ListDistributedGrantsError {
     }
 }
 impl Error for ListDistributedGrantsError {}
+impl ServiceErrorCode for ListDistributedGrantsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDistributedGrantsError::AccessDenied(_) => "AccessDeniedException",
+            ListDistributedGrantsError::Authorization(_) => "AuthorizationException",
+            ListDistributedGrantsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListDistributedGrantsError::RateLimitExceeded(_) => "RateLimitExceededException",
+            ListDistributedGrantsError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            ListDistributedGrantsError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by ListFailuresForLicenseConfigurationOperations
 #[derive(Debug, PartialEq)]
 pub enum ListFailuresForLicenseConfigurationOperationsError {
@@ -3622,6 +3932,27 @@ impl fmt::Display for ListFailuresForLicenseConfigurationOperationsError {
     }
 }
 impl Error for ListFailuresForLicenseConfigurationOperationsError {}
+impl ServiceErrorCode for ListFailuresForLicenseConfigurationOperationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFailuresForLicenseConfigurationOperationsError::AccessDenied(_) => {
+                "AccessDeniedException"
+            }
+            ListFailuresForLicenseConfigurationOperationsError::Authorization(_) => {
+                "AuthorizationException"
+            }
+            ListFailuresForLicenseConfigurationOperationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListFailuresForLicenseConfigurationOperationsError::RateLimitExceeded(_) => {
+                "RateLimitExceededException"
+            }
+            ListFailuresForLicenseConfigurationOperationsError::ServerInternal(_) => {
+                "ServerInternalException"
+            }
+        }
+    }
+}
 /// Errors returned by ListLicenseConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListLicenseConfigurationsError {
@@ -3698,6 +4029,22 @@ impl fmt::Display for ListLicenseConfigurationsError {
     }
 }
 impl Error for ListLicenseConfigurationsError {}
+impl ServiceErrorCode for ListLicenseConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLicenseConfigurationsError::AccessDenied(_) => "AccessDeniedException",
+            ListLicenseConfigurationsError::Authorization(_) => "AuthorizationException",
+            ListLicenseConfigurationsError::FilterLimitExceeded(_) => {
+                "FilterLimitExceededException"
+            }
+            ListLicenseConfigurationsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListLicenseConfigurationsError::RateLimitExceeded(_) => "RateLimitExceededException",
+            ListLicenseConfigurationsError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by ListLicenseSpecificationsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListLicenseSpecificationsForResourceError {
@@ -3774,6 +4121,23 @@ impl fmt::Display for ListLicenseSpecificationsForResourceError {
     }
 }
 impl Error for ListLicenseSpecificationsForResourceError {}
+impl ServiceErrorCode for ListLicenseSpecificationsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLicenseSpecificationsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            ListLicenseSpecificationsForResourceError::Authorization(_) => "AuthorizationException",
+            ListLicenseSpecificationsForResourceError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListLicenseSpecificationsForResourceError::RateLimitExceeded(_) => {
+                "RateLimitExceededException"
+            }
+            ListLicenseSpecificationsForResourceError::ServerInternal(_) => {
+                "ServerInternalException"
+            }
+        }
+    }
+}
 /// Errors returned by ListLicenseVersions
 #[derive(Debug, PartialEq)]
 pub enum ListLicenseVersionsError {
@@ -3832,6 +4196,17 @@ impl fmt::Display for ListLicenseVersionsError {
     }
 }
 impl Error for ListLicenseVersionsError {}
+impl ServiceErrorCode for ListLicenseVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLicenseVersionsError::AccessDenied(_) => "AccessDeniedException",
+            ListLicenseVersionsError::Authorization(_) => "AuthorizationException",
+            ListLicenseVersionsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListLicenseVersionsError::RateLimitExceeded(_) => "RateLimitExceededException",
+            ListLicenseVersionsError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by ListLicenses
 #[derive(Debug, PartialEq)]
 pub enum ListLicensesError {
@@ -3886,6 +4261,17 @@ impl fmt::Display for ListLicensesError {
     }
 }
 impl Error for ListLicensesError {}
+impl ServiceErrorCode for ListLicensesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLicensesError::AccessDenied(_) => "AccessDeniedException",
+            ListLicensesError::Authorization(_) => "AuthorizationException",
+            ListLicensesError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListLicensesError::RateLimitExceeded(_) => "RateLimitExceededException",
+            ListLicensesError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by ListReceivedGrants
 #[derive(Debug, PartialEq)]
 pub enum ListReceivedGrantsError {
@@ -3952,6 +4338,18 @@ impl fmt::Display for ListReceivedGrantsError {
     }
 }
 impl Error for ListReceivedGrantsError {}
+impl ServiceErrorCode for ListReceivedGrantsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListReceivedGrantsError::AccessDenied(_) => "AccessDeniedException",
+            ListReceivedGrantsError::Authorization(_) => "AuthorizationException",
+            ListReceivedGrantsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListReceivedGrantsError::RateLimitExceeded(_) => "RateLimitExceededException",
+            ListReceivedGrantsError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            ListReceivedGrantsError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by ListReceivedLicenses
 #[derive(Debug, PartialEq)]
 pub enum ListReceivedLicensesError {
@@ -4018,6 +4416,18 @@ impl fmt::Display for ListReceivedLicensesError {
     }
 }
 impl Error for ListReceivedLicensesError {}
+impl ServiceErrorCode for ListReceivedLicensesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListReceivedLicensesError::AccessDenied(_) => "AccessDeniedException",
+            ListReceivedLicensesError::Authorization(_) => "AuthorizationException",
+            ListReceivedLicensesError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListReceivedLicensesError::RateLimitExceeded(_) => "RateLimitExceededException",
+            ListReceivedLicensesError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            ListReceivedLicensesError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by ListResourceInventory
 #[derive(Debug, PartialEq)]
 pub enum ListResourceInventoryError {
@@ -4094,6 +4504,21 @@ impl fmt::Display for ListResourceInventoryError {
     }
 }
 impl Error for ListResourceInventoryError {}
+impl ServiceErrorCode for ListResourceInventoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourceInventoryError::AccessDenied(_) => "AccessDeniedException",
+            ListResourceInventoryError::Authorization(_) => "AuthorizationException",
+            ListResourceInventoryError::FailedDependency(_) => "FailedDependencyException",
+            ListResourceInventoryError::FilterLimitExceeded(_) => "FilterLimitExceededException",
+            ListResourceInventoryError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListResourceInventoryError::RateLimitExceeded(_) => "RateLimitExceededException",
+            ListResourceInventoryError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -4152,6 +4577,17 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            ListTagsForResourceError::Authorization(_) => "AuthorizationException",
+            ListTagsForResourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListTagsForResourceError::RateLimitExceeded(_) => "RateLimitExceededException",
+            ListTagsForResourceError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by ListTokens
 #[derive(Debug, PartialEq)]
 pub enum ListTokensError {
@@ -4200,6 +4636,16 @@ impl fmt::Display for ListTokensError {
     }
 }
 impl Error for ListTokensError {}
+impl ServiceErrorCode for ListTokensError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTokensError::AccessDenied(_) => "AccessDeniedException",
+            ListTokensError::Authorization(_) => "AuthorizationException",
+            ListTokensError::RateLimitExceeded(_) => "RateLimitExceededException",
+            ListTokensError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by ListUsageForLicenseConfiguration
 #[derive(Debug, PartialEq)]
 pub enum ListUsageForLicenseConfigurationError {
@@ -4286,6 +4732,24 @@ impl fmt::Display for ListUsageForLicenseConfigurationError {
     }
 }
 impl Error for ListUsageForLicenseConfigurationError {}
+impl ServiceErrorCode for ListUsageForLicenseConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUsageForLicenseConfigurationError::AccessDenied(_) => "AccessDeniedException",
+            ListUsageForLicenseConfigurationError::Authorization(_) => "AuthorizationException",
+            ListUsageForLicenseConfigurationError::FilterLimitExceeded(_) => {
+                "FilterLimitExceededException"
+            }
+            ListUsageForLicenseConfigurationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListUsageForLicenseConfigurationError::RateLimitExceeded(_) => {
+                "RateLimitExceededException"
+            }
+            ListUsageForLicenseConfigurationError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by RejectGrant
 #[derive(Debug, PartialEq)]
 pub enum RejectGrantError {
@@ -4346,6 +4810,18 @@ impl fmt::Display for RejectGrantError {
     }
 }
 impl Error for RejectGrantError {}
+impl ServiceErrorCode for RejectGrantError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RejectGrantError::AccessDenied(_) => "AccessDeniedException",
+            RejectGrantError::Authorization(_) => "AuthorizationException",
+            RejectGrantError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            RejectGrantError::RateLimitExceeded(_) => "RateLimitExceededException",
+            RejectGrantError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            RejectGrantError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -4400,6 +4876,17 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AccessDenied(_) => "AccessDeniedException",
+            TagResourceError::Authorization(_) => "AuthorizationException",
+            TagResourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            TagResourceError::RateLimitExceeded(_) => "RateLimitExceededException",
+            TagResourceError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -4454,6 +4941,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AccessDenied(_) => "AccessDeniedException",
+            UntagResourceError::Authorization(_) => "AuthorizationException",
+            UntagResourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UntagResourceError::RateLimitExceeded(_) => "RateLimitExceededException",
+            UntagResourceError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by UpdateLicenseConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateLicenseConfigurationError {
@@ -4532,6 +5030,22 @@ impl fmt::Display for UpdateLicenseConfigurationError {
     }
 }
 impl Error for UpdateLicenseConfigurationError {}
+impl ServiceErrorCode for UpdateLicenseConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateLicenseConfigurationError::AccessDenied(_) => "AccessDeniedException",
+            UpdateLicenseConfigurationError::Authorization(_) => "AuthorizationException",
+            UpdateLicenseConfigurationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateLicenseConfigurationError::RateLimitExceeded(_) => "RateLimitExceededException",
+            UpdateLicenseConfigurationError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            UpdateLicenseConfigurationError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Errors returned by UpdateLicenseSpecificationsForResource
 #[derive(Debug, PartialEq)]
 pub enum UpdateLicenseSpecificationsForResourceError {
@@ -4628,6 +5142,29 @@ impl fmt::Display for UpdateLicenseSpecificationsForResourceError {
     }
 }
 impl Error for UpdateLicenseSpecificationsForResourceError {}
+impl ServiceErrorCode for UpdateLicenseSpecificationsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateLicenseSpecificationsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            UpdateLicenseSpecificationsForResourceError::Authorization(_) => {
+                "AuthorizationException"
+            }
+            UpdateLicenseSpecificationsForResourceError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateLicenseSpecificationsForResourceError::InvalidResourceState(_) => {
+                "InvalidResourceStateException"
+            }
+            UpdateLicenseSpecificationsForResourceError::LicenseUsage(_) => "LicenseUsageException",
+            UpdateLicenseSpecificationsForResourceError::RateLimitExceeded(_) => {
+                "RateLimitExceededException"
+            }
+            UpdateLicenseSpecificationsForResourceError::ServerInternal(_) => {
+                "ServerInternalException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateServiceSettings
 #[derive(Debug, PartialEq)]
 pub enum UpdateServiceSettingsError {
@@ -4688,6 +5225,19 @@ impl fmt::Display for UpdateServiceSettingsError {
     }
 }
 impl Error for UpdateServiceSettingsError {}
+impl ServiceErrorCode for UpdateServiceSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateServiceSettingsError::AccessDenied(_) => "AccessDeniedException",
+            UpdateServiceSettingsError::Authorization(_) => "AuthorizationException",
+            UpdateServiceSettingsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateServiceSettingsError::RateLimitExceeded(_) => "RateLimitExceededException",
+            UpdateServiceSettingsError::ServerInternal(_) => "ServerInternalException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS License Manager API. AWS License Manager clients implement this trait.
 #[async_trait]
 pub trait LicenseManager {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -6159,6 +6159,19 @@ impl fmt::Display for AllocateStaticIpError {
     }
 }
 impl Error for AllocateStaticIpError {}
+impl ServiceErrorCode for AllocateStaticIpError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AllocateStaticIpError::AccessDenied(_) => "AccessDeniedException",
+            AllocateStaticIpError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            AllocateStaticIpError::InvalidInput(_) => "InvalidInputException",
+            AllocateStaticIpError::NotFound(_) => "NotFoundException",
+            AllocateStaticIpError::OperationFailure(_) => "OperationFailureException",
+            AllocateStaticIpError::Service(_) => "ServiceException",
+            AllocateStaticIpError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by AttachCertificateToDistribution
 #[derive(Debug, PartialEq)]
 pub enum AttachCertificateToDistributionError {
@@ -6237,6 +6250,20 @@ impl fmt::Display for AttachCertificateToDistributionError {
     }
 }
 impl Error for AttachCertificateToDistributionError {}
+impl ServiceErrorCode for AttachCertificateToDistributionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachCertificateToDistributionError::AccessDenied(_) => "AccessDeniedException",
+            AttachCertificateToDistributionError::InvalidInput(_) => "InvalidInputException",
+            AttachCertificateToDistributionError::NotFound(_) => "NotFoundException",
+            AttachCertificateToDistributionError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            AttachCertificateToDistributionError::Service(_) => "ServiceException",
+            AttachCertificateToDistributionError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by AttachDisk
 #[derive(Debug, PartialEq)]
 pub enum AttachDiskError {
@@ -6303,6 +6330,19 @@ impl fmt::Display for AttachDiskError {
     }
 }
 impl Error for AttachDiskError {}
+impl ServiceErrorCode for AttachDiskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachDiskError::AccessDenied(_) => "AccessDeniedException",
+            AttachDiskError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            AttachDiskError::InvalidInput(_) => "InvalidInputException",
+            AttachDiskError::NotFound(_) => "NotFoundException",
+            AttachDiskError::OperationFailure(_) => "OperationFailureException",
+            AttachDiskError::Service(_) => "ServiceException",
+            AttachDiskError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by AttachInstancesToLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum AttachInstancesToLoadBalancerError {
@@ -6391,6 +6431,21 @@ impl fmt::Display for AttachInstancesToLoadBalancerError {
     }
 }
 impl Error for AttachInstancesToLoadBalancerError {}
+impl ServiceErrorCode for AttachInstancesToLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachInstancesToLoadBalancerError::AccessDenied(_) => "AccessDeniedException",
+            AttachInstancesToLoadBalancerError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            AttachInstancesToLoadBalancerError::InvalidInput(_) => "InvalidInputException",
+            AttachInstancesToLoadBalancerError::NotFound(_) => "NotFoundException",
+            AttachInstancesToLoadBalancerError::OperationFailure(_) => "OperationFailureException",
+            AttachInstancesToLoadBalancerError::Service(_) => "ServiceException",
+            AttachInstancesToLoadBalancerError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by AttachLoadBalancerTlsCertificate
 #[derive(Debug, PartialEq)]
 pub enum AttachLoadBalancerTlsCertificateError {
@@ -6483,6 +6538,23 @@ impl fmt::Display for AttachLoadBalancerTlsCertificateError {
     }
 }
 impl Error for AttachLoadBalancerTlsCertificateError {}
+impl ServiceErrorCode for AttachLoadBalancerTlsCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachLoadBalancerTlsCertificateError::AccessDenied(_) => "AccessDeniedException",
+            AttachLoadBalancerTlsCertificateError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            AttachLoadBalancerTlsCertificateError::InvalidInput(_) => "InvalidInputException",
+            AttachLoadBalancerTlsCertificateError::NotFound(_) => "NotFoundException",
+            AttachLoadBalancerTlsCertificateError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            AttachLoadBalancerTlsCertificateError::Service(_) => "ServiceException",
+            AttachLoadBalancerTlsCertificateError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by AttachStaticIp
 #[derive(Debug, PartialEq)]
 pub enum AttachStaticIpError {
@@ -6551,6 +6623,19 @@ impl fmt::Display for AttachStaticIpError {
     }
 }
 impl Error for AttachStaticIpError {}
+impl ServiceErrorCode for AttachStaticIpError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachStaticIpError::AccessDenied(_) => "AccessDeniedException",
+            AttachStaticIpError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            AttachStaticIpError::InvalidInput(_) => "InvalidInputException",
+            AttachStaticIpError::NotFound(_) => "NotFoundException",
+            AttachStaticIpError::OperationFailure(_) => "OperationFailureException",
+            AttachStaticIpError::Service(_) => "ServiceException",
+            AttachStaticIpError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CloseInstancePublicPorts
 #[derive(Debug, PartialEq)]
 pub enum CloseInstancePublicPortsError {
@@ -6629,6 +6714,21 @@ impl fmt::Display for CloseInstancePublicPortsError {
     }
 }
 impl Error for CloseInstancePublicPortsError {}
+impl ServiceErrorCode for CloseInstancePublicPortsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CloseInstancePublicPortsError::AccessDenied(_) => "AccessDeniedException",
+            CloseInstancePublicPortsError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            CloseInstancePublicPortsError::InvalidInput(_) => "InvalidInputException",
+            CloseInstancePublicPortsError::NotFound(_) => "NotFoundException",
+            CloseInstancePublicPortsError::OperationFailure(_) => "OperationFailureException",
+            CloseInstancePublicPortsError::Service(_) => "ServiceException",
+            CloseInstancePublicPortsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CopySnapshot
 #[derive(Debug, PartialEq)]
 pub enum CopySnapshotError {
@@ -6695,6 +6795,19 @@ impl fmt::Display for CopySnapshotError {
     }
 }
 impl Error for CopySnapshotError {}
+impl ServiceErrorCode for CopySnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopySnapshotError::AccessDenied(_) => "AccessDeniedException",
+            CopySnapshotError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            CopySnapshotError::InvalidInput(_) => "InvalidInputException",
+            CopySnapshotError::NotFound(_) => "NotFoundException",
+            CopySnapshotError::OperationFailure(_) => "OperationFailureException",
+            CopySnapshotError::Service(_) => "ServiceException",
+            CopySnapshotError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateCertificate
 #[derive(Debug, PartialEq)]
 pub enum CreateCertificateError {
@@ -6749,6 +6862,17 @@ impl fmt::Display for CreateCertificateError {
     }
 }
 impl Error for CreateCertificateError {}
+impl ServiceErrorCode for CreateCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCertificateError::AccessDenied(_) => "AccessDeniedException",
+            CreateCertificateError::InvalidInput(_) => "InvalidInputException",
+            CreateCertificateError::NotFound(_) => "NotFoundException",
+            CreateCertificateError::Service(_) => "ServiceException",
+            CreateCertificateError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateCloudFormationStack
 #[derive(Debug, PartialEq)]
 pub enum CreateCloudFormationStackError {
@@ -6827,6 +6951,21 @@ impl fmt::Display for CreateCloudFormationStackError {
     }
 }
 impl Error for CreateCloudFormationStackError {}
+impl ServiceErrorCode for CreateCloudFormationStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCloudFormationStackError::AccessDenied(_) => "AccessDeniedException",
+            CreateCloudFormationStackError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            CreateCloudFormationStackError::InvalidInput(_) => "InvalidInputException",
+            CreateCloudFormationStackError::NotFound(_) => "NotFoundException",
+            CreateCloudFormationStackError::OperationFailure(_) => "OperationFailureException",
+            CreateCloudFormationStackError::Service(_) => "ServiceException",
+            CreateCloudFormationStackError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateContactMethod
 #[derive(Debug, PartialEq)]
 pub enum CreateContactMethodError {
@@ -6889,6 +7028,18 @@ impl fmt::Display for CreateContactMethodError {
     }
 }
 impl Error for CreateContactMethodError {}
+impl ServiceErrorCode for CreateContactMethodError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateContactMethodError::AccessDenied(_) => "AccessDeniedException",
+            CreateContactMethodError::InvalidInput(_) => "InvalidInputException",
+            CreateContactMethodError::NotFound(_) => "NotFoundException",
+            CreateContactMethodError::OperationFailure(_) => "OperationFailureException",
+            CreateContactMethodError::Service(_) => "ServiceException",
+            CreateContactMethodError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateContainerService
 #[derive(Debug, PartialEq)]
 pub enum CreateContainerServiceError {
@@ -6945,6 +7096,17 @@ impl fmt::Display for CreateContainerServiceError {
     }
 }
 impl Error for CreateContainerServiceError {}
+impl ServiceErrorCode for CreateContainerServiceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateContainerServiceError::AccessDenied(_) => "AccessDeniedException",
+            CreateContainerServiceError::InvalidInput(_) => "InvalidInputException",
+            CreateContainerServiceError::NotFound(_) => "NotFoundException",
+            CreateContainerServiceError::Service(_) => "ServiceException",
+            CreateContainerServiceError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateContainerServiceDeployment
 #[derive(Debug, PartialEq)]
 pub enum CreateContainerServiceDeploymentError {
@@ -7017,6 +7179,17 @@ impl fmt::Display for CreateContainerServiceDeploymentError {
     }
 }
 impl Error for CreateContainerServiceDeploymentError {}
+impl ServiceErrorCode for CreateContainerServiceDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateContainerServiceDeploymentError::AccessDenied(_) => "AccessDeniedException",
+            CreateContainerServiceDeploymentError::InvalidInput(_) => "InvalidInputException",
+            CreateContainerServiceDeploymentError::NotFound(_) => "NotFoundException",
+            CreateContainerServiceDeploymentError::Service(_) => "ServiceException",
+            CreateContainerServiceDeploymentError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateContainerServiceRegistryLogin
 #[derive(Debug, PartialEq)]
 pub enum CreateContainerServiceRegistryLoginError {
@@ -7089,6 +7262,19 @@ impl fmt::Display for CreateContainerServiceRegistryLoginError {
     }
 }
 impl Error for CreateContainerServiceRegistryLoginError {}
+impl ServiceErrorCode for CreateContainerServiceRegistryLoginError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateContainerServiceRegistryLoginError::AccessDenied(_) => "AccessDeniedException",
+            CreateContainerServiceRegistryLoginError::InvalidInput(_) => "InvalidInputException",
+            CreateContainerServiceRegistryLoginError::NotFound(_) => "NotFoundException",
+            CreateContainerServiceRegistryLoginError::Service(_) => "ServiceException",
+            CreateContainerServiceRegistryLoginError::Unauthenticated(_) => {
+                "UnauthenticatedException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDisk
 #[derive(Debug, PartialEq)]
 pub enum CreateDiskError {
@@ -7155,6 +7341,19 @@ impl fmt::Display for CreateDiskError {
     }
 }
 impl Error for CreateDiskError {}
+impl ServiceErrorCode for CreateDiskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDiskError::AccessDenied(_) => "AccessDeniedException",
+            CreateDiskError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            CreateDiskError::InvalidInput(_) => "InvalidInputException",
+            CreateDiskError::NotFound(_) => "NotFoundException",
+            CreateDiskError::OperationFailure(_) => "OperationFailureException",
+            CreateDiskError::Service(_) => "ServiceException",
+            CreateDiskError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateDiskFromSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateDiskFromSnapshotError {
@@ -7229,6 +7428,21 @@ impl fmt::Display for CreateDiskFromSnapshotError {
     }
 }
 impl Error for CreateDiskFromSnapshotError {}
+impl ServiceErrorCode for CreateDiskFromSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDiskFromSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            CreateDiskFromSnapshotError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            CreateDiskFromSnapshotError::InvalidInput(_) => "InvalidInputException",
+            CreateDiskFromSnapshotError::NotFound(_) => "NotFoundException",
+            CreateDiskFromSnapshotError::OperationFailure(_) => "OperationFailureException",
+            CreateDiskFromSnapshotError::Service(_) => "ServiceException",
+            CreateDiskFromSnapshotError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateDiskSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateDiskSnapshotError {
@@ -7297,6 +7511,19 @@ impl fmt::Display for CreateDiskSnapshotError {
     }
 }
 impl Error for CreateDiskSnapshotError {}
+impl ServiceErrorCode for CreateDiskSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDiskSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            CreateDiskSnapshotError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            CreateDiskSnapshotError::InvalidInput(_) => "InvalidInputException",
+            CreateDiskSnapshotError::NotFound(_) => "NotFoundException",
+            CreateDiskSnapshotError::OperationFailure(_) => "OperationFailureException",
+            CreateDiskSnapshotError::Service(_) => "ServiceException",
+            CreateDiskSnapshotError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateDistribution
 #[derive(Debug, PartialEq)]
 pub enum CreateDistributionError {
@@ -7357,6 +7584,18 @@ impl fmt::Display for CreateDistributionError {
     }
 }
 impl Error for CreateDistributionError {}
+impl ServiceErrorCode for CreateDistributionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDistributionError::AccessDenied(_) => "AccessDeniedException",
+            CreateDistributionError::InvalidInput(_) => "InvalidInputException",
+            CreateDistributionError::NotFound(_) => "NotFoundException",
+            CreateDistributionError::OperationFailure(_) => "OperationFailureException",
+            CreateDistributionError::Service(_) => "ServiceException",
+            CreateDistributionError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateDomain
 #[derive(Debug, PartialEq)]
 pub enum CreateDomainError {
@@ -7423,6 +7662,19 @@ impl fmt::Display for CreateDomainError {
     }
 }
 impl Error for CreateDomainError {}
+impl ServiceErrorCode for CreateDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDomainError::AccessDenied(_) => "AccessDeniedException",
+            CreateDomainError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            CreateDomainError::InvalidInput(_) => "InvalidInputException",
+            CreateDomainError::NotFound(_) => "NotFoundException",
+            CreateDomainError::OperationFailure(_) => "OperationFailureException",
+            CreateDomainError::Service(_) => "ServiceException",
+            CreateDomainError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateDomainEntry
 #[derive(Debug, PartialEq)]
 pub enum CreateDomainEntryError {
@@ -7491,6 +7743,19 @@ impl fmt::Display for CreateDomainEntryError {
     }
 }
 impl Error for CreateDomainEntryError {}
+impl ServiceErrorCode for CreateDomainEntryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDomainEntryError::AccessDenied(_) => "AccessDeniedException",
+            CreateDomainEntryError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            CreateDomainEntryError::InvalidInput(_) => "InvalidInputException",
+            CreateDomainEntryError::NotFound(_) => "NotFoundException",
+            CreateDomainEntryError::OperationFailure(_) => "OperationFailureException",
+            CreateDomainEntryError::Service(_) => "ServiceException",
+            CreateDomainEntryError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateInstanceSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateInstanceSnapshotError {
@@ -7565,6 +7830,21 @@ impl fmt::Display for CreateInstanceSnapshotError {
     }
 }
 impl Error for CreateInstanceSnapshotError {}
+impl ServiceErrorCode for CreateInstanceSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateInstanceSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            CreateInstanceSnapshotError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            CreateInstanceSnapshotError::InvalidInput(_) => "InvalidInputException",
+            CreateInstanceSnapshotError::NotFound(_) => "NotFoundException",
+            CreateInstanceSnapshotError::OperationFailure(_) => "OperationFailureException",
+            CreateInstanceSnapshotError::Service(_) => "ServiceException",
+            CreateInstanceSnapshotError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateInstances
 #[derive(Debug, PartialEq)]
 pub enum CreateInstancesError {
@@ -7633,6 +7913,19 @@ impl fmt::Display for CreateInstancesError {
     }
 }
 impl Error for CreateInstancesError {}
+impl ServiceErrorCode for CreateInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateInstancesError::AccessDenied(_) => "AccessDeniedException",
+            CreateInstancesError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            CreateInstancesError::InvalidInput(_) => "InvalidInputException",
+            CreateInstancesError::NotFound(_) => "NotFoundException",
+            CreateInstancesError::OperationFailure(_) => "OperationFailureException",
+            CreateInstancesError::Service(_) => "ServiceException",
+            CreateInstancesError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateInstancesFromSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateInstancesFromSnapshotError {
@@ -7715,6 +8008,21 @@ impl fmt::Display for CreateInstancesFromSnapshotError {
     }
 }
 impl Error for CreateInstancesFromSnapshotError {}
+impl ServiceErrorCode for CreateInstancesFromSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateInstancesFromSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            CreateInstancesFromSnapshotError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            CreateInstancesFromSnapshotError::InvalidInput(_) => "InvalidInputException",
+            CreateInstancesFromSnapshotError::NotFound(_) => "NotFoundException",
+            CreateInstancesFromSnapshotError::OperationFailure(_) => "OperationFailureException",
+            CreateInstancesFromSnapshotError::Service(_) => "ServiceException",
+            CreateInstancesFromSnapshotError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateKeyPair
 #[derive(Debug, PartialEq)]
 pub enum CreateKeyPairError {
@@ -7783,6 +8091,19 @@ impl fmt::Display for CreateKeyPairError {
     }
 }
 impl Error for CreateKeyPairError {}
+impl ServiceErrorCode for CreateKeyPairError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateKeyPairError::AccessDenied(_) => "AccessDeniedException",
+            CreateKeyPairError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            CreateKeyPairError::InvalidInput(_) => "InvalidInputException",
+            CreateKeyPairError::NotFound(_) => "NotFoundException",
+            CreateKeyPairError::OperationFailure(_) => "OperationFailureException",
+            CreateKeyPairError::Service(_) => "ServiceException",
+            CreateKeyPairError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum CreateLoadBalancerError {
@@ -7851,6 +8172,19 @@ impl fmt::Display for CreateLoadBalancerError {
     }
 }
 impl Error for CreateLoadBalancerError {}
+impl ServiceErrorCode for CreateLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLoadBalancerError::AccessDenied(_) => "AccessDeniedException",
+            CreateLoadBalancerError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            CreateLoadBalancerError::InvalidInput(_) => "InvalidInputException",
+            CreateLoadBalancerError::NotFound(_) => "NotFoundException",
+            CreateLoadBalancerError::OperationFailure(_) => "OperationFailureException",
+            CreateLoadBalancerError::Service(_) => "ServiceException",
+            CreateLoadBalancerError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateLoadBalancerTlsCertificate
 #[derive(Debug, PartialEq)]
 pub enum CreateLoadBalancerTlsCertificateError {
@@ -7943,6 +8277,23 @@ impl fmt::Display for CreateLoadBalancerTlsCertificateError {
     }
 }
 impl Error for CreateLoadBalancerTlsCertificateError {}
+impl ServiceErrorCode for CreateLoadBalancerTlsCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLoadBalancerTlsCertificateError::AccessDenied(_) => "AccessDeniedException",
+            CreateLoadBalancerTlsCertificateError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            CreateLoadBalancerTlsCertificateError::InvalidInput(_) => "InvalidInputException",
+            CreateLoadBalancerTlsCertificateError::NotFound(_) => "NotFoundException",
+            CreateLoadBalancerTlsCertificateError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            CreateLoadBalancerTlsCertificateError::Service(_) => "ServiceException",
+            CreateLoadBalancerTlsCertificateError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateRelationalDatabase
 #[derive(Debug, PartialEq)]
 pub enum CreateRelationalDatabaseError {
@@ -8021,6 +8372,21 @@ impl fmt::Display for CreateRelationalDatabaseError {
     }
 }
 impl Error for CreateRelationalDatabaseError {}
+impl ServiceErrorCode for CreateRelationalDatabaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRelationalDatabaseError::AccessDenied(_) => "AccessDeniedException",
+            CreateRelationalDatabaseError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            CreateRelationalDatabaseError::InvalidInput(_) => "InvalidInputException",
+            CreateRelationalDatabaseError::NotFound(_) => "NotFoundException",
+            CreateRelationalDatabaseError::OperationFailure(_) => "OperationFailureException",
+            CreateRelationalDatabaseError::Service(_) => "ServiceException",
+            CreateRelationalDatabaseError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by CreateRelationalDatabaseFromSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateRelationalDatabaseFromSnapshotError {
@@ -8115,6 +8481,25 @@ impl fmt::Display for CreateRelationalDatabaseFromSnapshotError {
     }
 }
 impl Error for CreateRelationalDatabaseFromSnapshotError {}
+impl ServiceErrorCode for CreateRelationalDatabaseFromSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRelationalDatabaseFromSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            CreateRelationalDatabaseFromSnapshotError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            CreateRelationalDatabaseFromSnapshotError::InvalidInput(_) => "InvalidInputException",
+            CreateRelationalDatabaseFromSnapshotError::NotFound(_) => "NotFoundException",
+            CreateRelationalDatabaseFromSnapshotError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            CreateRelationalDatabaseFromSnapshotError::Service(_) => "ServiceException",
+            CreateRelationalDatabaseFromSnapshotError::Unauthenticated(_) => {
+                "UnauthenticatedException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateRelationalDatabaseSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateRelationalDatabaseSnapshotError {
@@ -8207,6 +8592,23 @@ impl fmt::Display for CreateRelationalDatabaseSnapshotError {
     }
 }
 impl Error for CreateRelationalDatabaseSnapshotError {}
+impl ServiceErrorCode for CreateRelationalDatabaseSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRelationalDatabaseSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            CreateRelationalDatabaseSnapshotError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            CreateRelationalDatabaseSnapshotError::InvalidInput(_) => "InvalidInputException",
+            CreateRelationalDatabaseSnapshotError::NotFound(_) => "NotFoundException",
+            CreateRelationalDatabaseSnapshotError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            CreateRelationalDatabaseSnapshotError::Service(_) => "ServiceException",
+            CreateRelationalDatabaseSnapshotError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteAlarm
 #[derive(Debug, PartialEq)]
 pub enum DeleteAlarmError {
@@ -8267,6 +8669,18 @@ impl fmt::Display for DeleteAlarmError {
     }
 }
 impl Error for DeleteAlarmError {}
+impl ServiceErrorCode for DeleteAlarmError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAlarmError::AccessDenied(_) => "AccessDeniedException",
+            DeleteAlarmError::InvalidInput(_) => "InvalidInputException",
+            DeleteAlarmError::NotFound(_) => "NotFoundException",
+            DeleteAlarmError::OperationFailure(_) => "OperationFailureException",
+            DeleteAlarmError::Service(_) => "ServiceException",
+            DeleteAlarmError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteAutoSnapshot
 #[derive(Debug, PartialEq)]
 pub enum DeleteAutoSnapshotError {
@@ -8327,6 +8741,18 @@ impl fmt::Display for DeleteAutoSnapshotError {
     }
 }
 impl Error for DeleteAutoSnapshotError {}
+impl ServiceErrorCode for DeleteAutoSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAutoSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            DeleteAutoSnapshotError::InvalidInput(_) => "InvalidInputException",
+            DeleteAutoSnapshotError::NotFound(_) => "NotFoundException",
+            DeleteAutoSnapshotError::OperationFailure(_) => "OperationFailureException",
+            DeleteAutoSnapshotError::Service(_) => "ServiceException",
+            DeleteAutoSnapshotError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteCertificate
 #[derive(Debug, PartialEq)]
 pub enum DeleteCertificateError {
@@ -8381,6 +8807,17 @@ impl fmt::Display for DeleteCertificateError {
     }
 }
 impl Error for DeleteCertificateError {}
+impl ServiceErrorCode for DeleteCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCertificateError::AccessDenied(_) => "AccessDeniedException",
+            DeleteCertificateError::InvalidInput(_) => "InvalidInputException",
+            DeleteCertificateError::NotFound(_) => "NotFoundException",
+            DeleteCertificateError::Service(_) => "ServiceException",
+            DeleteCertificateError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteContactMethod
 #[derive(Debug, PartialEq)]
 pub enum DeleteContactMethodError {
@@ -8443,6 +8880,18 @@ impl fmt::Display for DeleteContactMethodError {
     }
 }
 impl Error for DeleteContactMethodError {}
+impl ServiceErrorCode for DeleteContactMethodError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteContactMethodError::AccessDenied(_) => "AccessDeniedException",
+            DeleteContactMethodError::InvalidInput(_) => "InvalidInputException",
+            DeleteContactMethodError::NotFound(_) => "NotFoundException",
+            DeleteContactMethodError::OperationFailure(_) => "OperationFailureException",
+            DeleteContactMethodError::Service(_) => "ServiceException",
+            DeleteContactMethodError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteContainerImage
 #[derive(Debug, PartialEq)]
 pub enum DeleteContainerImageError {
@@ -8499,6 +8948,17 @@ impl fmt::Display for DeleteContainerImageError {
     }
 }
 impl Error for DeleteContainerImageError {}
+impl ServiceErrorCode for DeleteContainerImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteContainerImageError::AccessDenied(_) => "AccessDeniedException",
+            DeleteContainerImageError::InvalidInput(_) => "InvalidInputException",
+            DeleteContainerImageError::NotFound(_) => "NotFoundException",
+            DeleteContainerImageError::Service(_) => "ServiceException",
+            DeleteContainerImageError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteContainerService
 #[derive(Debug, PartialEq)]
 pub enum DeleteContainerServiceError {
@@ -8555,6 +9015,17 @@ impl fmt::Display for DeleteContainerServiceError {
     }
 }
 impl Error for DeleteContainerServiceError {}
+impl ServiceErrorCode for DeleteContainerServiceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteContainerServiceError::AccessDenied(_) => "AccessDeniedException",
+            DeleteContainerServiceError::InvalidInput(_) => "InvalidInputException",
+            DeleteContainerServiceError::NotFound(_) => "NotFoundException",
+            DeleteContainerServiceError::Service(_) => "ServiceException",
+            DeleteContainerServiceError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteDisk
 #[derive(Debug, PartialEq)]
 pub enum DeleteDiskError {
@@ -8621,6 +9092,19 @@ impl fmt::Display for DeleteDiskError {
     }
 }
 impl Error for DeleteDiskError {}
+impl ServiceErrorCode for DeleteDiskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDiskError::AccessDenied(_) => "AccessDeniedException",
+            DeleteDiskError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            DeleteDiskError::InvalidInput(_) => "InvalidInputException",
+            DeleteDiskError::NotFound(_) => "NotFoundException",
+            DeleteDiskError::OperationFailure(_) => "OperationFailureException",
+            DeleteDiskError::Service(_) => "ServiceException",
+            DeleteDiskError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteDiskSnapshot
 #[derive(Debug, PartialEq)]
 pub enum DeleteDiskSnapshotError {
@@ -8689,6 +9173,19 @@ impl fmt::Display for DeleteDiskSnapshotError {
     }
 }
 impl Error for DeleteDiskSnapshotError {}
+impl ServiceErrorCode for DeleteDiskSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDiskSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            DeleteDiskSnapshotError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            DeleteDiskSnapshotError::InvalidInput(_) => "InvalidInputException",
+            DeleteDiskSnapshotError::NotFound(_) => "NotFoundException",
+            DeleteDiskSnapshotError::OperationFailure(_) => "OperationFailureException",
+            DeleteDiskSnapshotError::Service(_) => "ServiceException",
+            DeleteDiskSnapshotError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteDistribution
 #[derive(Debug, PartialEq)]
 pub enum DeleteDistributionError {
@@ -8749,6 +9246,18 @@ impl fmt::Display for DeleteDistributionError {
     }
 }
 impl Error for DeleteDistributionError {}
+impl ServiceErrorCode for DeleteDistributionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDistributionError::AccessDenied(_) => "AccessDeniedException",
+            DeleteDistributionError::InvalidInput(_) => "InvalidInputException",
+            DeleteDistributionError::NotFound(_) => "NotFoundException",
+            DeleteDistributionError::OperationFailure(_) => "OperationFailureException",
+            DeleteDistributionError::Service(_) => "ServiceException",
+            DeleteDistributionError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteDomain
 #[derive(Debug, PartialEq)]
 pub enum DeleteDomainError {
@@ -8815,6 +9324,19 @@ impl fmt::Display for DeleteDomainError {
     }
 }
 impl Error for DeleteDomainError {}
+impl ServiceErrorCode for DeleteDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDomainError::AccessDenied(_) => "AccessDeniedException",
+            DeleteDomainError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            DeleteDomainError::InvalidInput(_) => "InvalidInputException",
+            DeleteDomainError::NotFound(_) => "NotFoundException",
+            DeleteDomainError::OperationFailure(_) => "OperationFailureException",
+            DeleteDomainError::Service(_) => "ServiceException",
+            DeleteDomainError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteDomainEntry
 #[derive(Debug, PartialEq)]
 pub enum DeleteDomainEntryError {
@@ -8883,6 +9405,19 @@ impl fmt::Display for DeleteDomainEntryError {
     }
 }
 impl Error for DeleteDomainEntryError {}
+impl ServiceErrorCode for DeleteDomainEntryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDomainEntryError::AccessDenied(_) => "AccessDeniedException",
+            DeleteDomainEntryError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            DeleteDomainEntryError::InvalidInput(_) => "InvalidInputException",
+            DeleteDomainEntryError::NotFound(_) => "NotFoundException",
+            DeleteDomainEntryError::OperationFailure(_) => "OperationFailureException",
+            DeleteDomainEntryError::Service(_) => "ServiceException",
+            DeleteDomainEntryError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteInstance
 #[derive(Debug, PartialEq)]
 pub enum DeleteInstanceError {
@@ -8951,6 +9486,19 @@ impl fmt::Display for DeleteInstanceError {
     }
 }
 impl Error for DeleteInstanceError {}
+impl ServiceErrorCode for DeleteInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInstanceError::AccessDenied(_) => "AccessDeniedException",
+            DeleteInstanceError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            DeleteInstanceError::InvalidInput(_) => "InvalidInputException",
+            DeleteInstanceError::NotFound(_) => "NotFoundException",
+            DeleteInstanceError::OperationFailure(_) => "OperationFailureException",
+            DeleteInstanceError::Service(_) => "ServiceException",
+            DeleteInstanceError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteInstanceSnapshot
 #[derive(Debug, PartialEq)]
 pub enum DeleteInstanceSnapshotError {
@@ -9025,6 +9573,21 @@ impl fmt::Display for DeleteInstanceSnapshotError {
     }
 }
 impl Error for DeleteInstanceSnapshotError {}
+impl ServiceErrorCode for DeleteInstanceSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInstanceSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            DeleteInstanceSnapshotError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            DeleteInstanceSnapshotError::InvalidInput(_) => "InvalidInputException",
+            DeleteInstanceSnapshotError::NotFound(_) => "NotFoundException",
+            DeleteInstanceSnapshotError::OperationFailure(_) => "OperationFailureException",
+            DeleteInstanceSnapshotError::Service(_) => "ServiceException",
+            DeleteInstanceSnapshotError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteKeyPair
 #[derive(Debug, PartialEq)]
 pub enum DeleteKeyPairError {
@@ -9093,6 +9656,19 @@ impl fmt::Display for DeleteKeyPairError {
     }
 }
 impl Error for DeleteKeyPairError {}
+impl ServiceErrorCode for DeleteKeyPairError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteKeyPairError::AccessDenied(_) => "AccessDeniedException",
+            DeleteKeyPairError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            DeleteKeyPairError::InvalidInput(_) => "InvalidInputException",
+            DeleteKeyPairError::NotFound(_) => "NotFoundException",
+            DeleteKeyPairError::OperationFailure(_) => "OperationFailureException",
+            DeleteKeyPairError::Service(_) => "ServiceException",
+            DeleteKeyPairError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteKnownHostKeys
 #[derive(Debug, PartialEq)]
 pub enum DeleteKnownHostKeysError {
@@ -9163,6 +9739,21 @@ impl fmt::Display for DeleteKnownHostKeysError {
     }
 }
 impl Error for DeleteKnownHostKeysError {}
+impl ServiceErrorCode for DeleteKnownHostKeysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteKnownHostKeysError::AccessDenied(_) => "AccessDeniedException",
+            DeleteKnownHostKeysError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            DeleteKnownHostKeysError::InvalidInput(_) => "InvalidInputException",
+            DeleteKnownHostKeysError::NotFound(_) => "NotFoundException",
+            DeleteKnownHostKeysError::OperationFailure(_) => "OperationFailureException",
+            DeleteKnownHostKeysError::Service(_) => "ServiceException",
+            DeleteKnownHostKeysError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum DeleteLoadBalancerError {
@@ -9231,6 +9822,19 @@ impl fmt::Display for DeleteLoadBalancerError {
     }
 }
 impl Error for DeleteLoadBalancerError {}
+impl ServiceErrorCode for DeleteLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLoadBalancerError::AccessDenied(_) => "AccessDeniedException",
+            DeleteLoadBalancerError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            DeleteLoadBalancerError::InvalidInput(_) => "InvalidInputException",
+            DeleteLoadBalancerError::NotFound(_) => "NotFoundException",
+            DeleteLoadBalancerError::OperationFailure(_) => "OperationFailureException",
+            DeleteLoadBalancerError::Service(_) => "ServiceException",
+            DeleteLoadBalancerError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteLoadBalancerTlsCertificate
 #[derive(Debug, PartialEq)]
 pub enum DeleteLoadBalancerTlsCertificateError {
@@ -9323,6 +9927,23 @@ impl fmt::Display for DeleteLoadBalancerTlsCertificateError {
     }
 }
 impl Error for DeleteLoadBalancerTlsCertificateError {}
+impl ServiceErrorCode for DeleteLoadBalancerTlsCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLoadBalancerTlsCertificateError::AccessDenied(_) => "AccessDeniedException",
+            DeleteLoadBalancerTlsCertificateError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            DeleteLoadBalancerTlsCertificateError::InvalidInput(_) => "InvalidInputException",
+            DeleteLoadBalancerTlsCertificateError::NotFound(_) => "NotFoundException",
+            DeleteLoadBalancerTlsCertificateError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            DeleteLoadBalancerTlsCertificateError::Service(_) => "ServiceException",
+            DeleteLoadBalancerTlsCertificateError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteRelationalDatabase
 #[derive(Debug, PartialEq)]
 pub enum DeleteRelationalDatabaseError {
@@ -9401,6 +10022,21 @@ impl fmt::Display for DeleteRelationalDatabaseError {
     }
 }
 impl Error for DeleteRelationalDatabaseError {}
+impl ServiceErrorCode for DeleteRelationalDatabaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRelationalDatabaseError::AccessDenied(_) => "AccessDeniedException",
+            DeleteRelationalDatabaseError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            DeleteRelationalDatabaseError::InvalidInput(_) => "InvalidInputException",
+            DeleteRelationalDatabaseError::NotFound(_) => "NotFoundException",
+            DeleteRelationalDatabaseError::OperationFailure(_) => "OperationFailureException",
+            DeleteRelationalDatabaseError::Service(_) => "ServiceException",
+            DeleteRelationalDatabaseError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DeleteRelationalDatabaseSnapshot
 #[derive(Debug, PartialEq)]
 pub enum DeleteRelationalDatabaseSnapshotError {
@@ -9493,6 +10129,23 @@ impl fmt::Display for DeleteRelationalDatabaseSnapshotError {
     }
 }
 impl Error for DeleteRelationalDatabaseSnapshotError {}
+impl ServiceErrorCode for DeleteRelationalDatabaseSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRelationalDatabaseSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            DeleteRelationalDatabaseSnapshotError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            DeleteRelationalDatabaseSnapshotError::InvalidInput(_) => "InvalidInputException",
+            DeleteRelationalDatabaseSnapshotError::NotFound(_) => "NotFoundException",
+            DeleteRelationalDatabaseSnapshotError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            DeleteRelationalDatabaseSnapshotError::Service(_) => "ServiceException",
+            DeleteRelationalDatabaseSnapshotError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DetachCertificateFromDistribution
 #[derive(Debug, PartialEq)]
 pub enum DetachCertificateFromDistributionError {
@@ -9575,6 +10228,22 @@ impl fmt::Display for DetachCertificateFromDistributionError {
     }
 }
 impl Error for DetachCertificateFromDistributionError {}
+impl ServiceErrorCode for DetachCertificateFromDistributionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachCertificateFromDistributionError::AccessDenied(_) => "AccessDeniedException",
+            DetachCertificateFromDistributionError::InvalidInput(_) => "InvalidInputException",
+            DetachCertificateFromDistributionError::NotFound(_) => "NotFoundException",
+            DetachCertificateFromDistributionError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            DetachCertificateFromDistributionError::Service(_) => "ServiceException",
+            DetachCertificateFromDistributionError::Unauthenticated(_) => {
+                "UnauthenticatedException"
+            }
+        }
+    }
+}
 /// Errors returned by DetachDisk
 #[derive(Debug, PartialEq)]
 pub enum DetachDiskError {
@@ -9641,6 +10310,19 @@ impl fmt::Display for DetachDiskError {
     }
 }
 impl Error for DetachDiskError {}
+impl ServiceErrorCode for DetachDiskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachDiskError::AccessDenied(_) => "AccessDeniedException",
+            DetachDiskError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            DetachDiskError::InvalidInput(_) => "InvalidInputException",
+            DetachDiskError::NotFound(_) => "NotFoundException",
+            DetachDiskError::OperationFailure(_) => "OperationFailureException",
+            DetachDiskError::Service(_) => "ServiceException",
+            DetachDiskError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DetachInstancesFromLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum DetachInstancesFromLoadBalancerError {
@@ -9729,6 +10411,23 @@ impl fmt::Display for DetachInstancesFromLoadBalancerError {
     }
 }
 impl Error for DetachInstancesFromLoadBalancerError {}
+impl ServiceErrorCode for DetachInstancesFromLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachInstancesFromLoadBalancerError::AccessDenied(_) => "AccessDeniedException",
+            DetachInstancesFromLoadBalancerError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            DetachInstancesFromLoadBalancerError::InvalidInput(_) => "InvalidInputException",
+            DetachInstancesFromLoadBalancerError::NotFound(_) => "NotFoundException",
+            DetachInstancesFromLoadBalancerError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            DetachInstancesFromLoadBalancerError::Service(_) => "ServiceException",
+            DetachInstancesFromLoadBalancerError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DetachStaticIp
 #[derive(Debug, PartialEq)]
 pub enum DetachStaticIpError {
@@ -9797,6 +10496,19 @@ impl fmt::Display for DetachStaticIpError {
     }
 }
 impl Error for DetachStaticIpError {}
+impl ServiceErrorCode for DetachStaticIpError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachStaticIpError::AccessDenied(_) => "AccessDeniedException",
+            DetachStaticIpError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            DetachStaticIpError::InvalidInput(_) => "InvalidInputException",
+            DetachStaticIpError::NotFound(_) => "NotFoundException",
+            DetachStaticIpError::OperationFailure(_) => "OperationFailureException",
+            DetachStaticIpError::Service(_) => "ServiceException",
+            DetachStaticIpError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DisableAddOn
 #[derive(Debug, PartialEq)]
 pub enum DisableAddOnError {
@@ -9857,6 +10569,18 @@ impl fmt::Display for DisableAddOnError {
     }
 }
 impl Error for DisableAddOnError {}
+impl ServiceErrorCode for DisableAddOnError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableAddOnError::AccessDenied(_) => "AccessDeniedException",
+            DisableAddOnError::InvalidInput(_) => "InvalidInputException",
+            DisableAddOnError::NotFound(_) => "NotFoundException",
+            DisableAddOnError::OperationFailure(_) => "OperationFailureException",
+            DisableAddOnError::Service(_) => "ServiceException",
+            DisableAddOnError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by DownloadDefaultKeyPair
 #[derive(Debug, PartialEq)]
 pub enum DownloadDefaultKeyPairError {
@@ -9931,6 +10655,21 @@ impl fmt::Display for DownloadDefaultKeyPairError {
     }
 }
 impl Error for DownloadDefaultKeyPairError {}
+impl ServiceErrorCode for DownloadDefaultKeyPairError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DownloadDefaultKeyPairError::AccessDenied(_) => "AccessDeniedException",
+            DownloadDefaultKeyPairError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            DownloadDefaultKeyPairError::InvalidInput(_) => "InvalidInputException",
+            DownloadDefaultKeyPairError::NotFound(_) => "NotFoundException",
+            DownloadDefaultKeyPairError::OperationFailure(_) => "OperationFailureException",
+            DownloadDefaultKeyPairError::Service(_) => "ServiceException",
+            DownloadDefaultKeyPairError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by EnableAddOn
 #[derive(Debug, PartialEq)]
 pub enum EnableAddOnError {
@@ -9991,6 +10730,18 @@ impl fmt::Display for EnableAddOnError {
     }
 }
 impl Error for EnableAddOnError {}
+impl ServiceErrorCode for EnableAddOnError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableAddOnError::AccessDenied(_) => "AccessDeniedException",
+            EnableAddOnError::InvalidInput(_) => "InvalidInputException",
+            EnableAddOnError::NotFound(_) => "NotFoundException",
+            EnableAddOnError::OperationFailure(_) => "OperationFailureException",
+            EnableAddOnError::Service(_) => "ServiceException",
+            EnableAddOnError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by ExportSnapshot
 #[derive(Debug, PartialEq)]
 pub enum ExportSnapshotError {
@@ -10059,6 +10810,19 @@ impl fmt::Display for ExportSnapshotError {
     }
 }
 impl Error for ExportSnapshotError {}
+impl ServiceErrorCode for ExportSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExportSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            ExportSnapshotError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            ExportSnapshotError::InvalidInput(_) => "InvalidInputException",
+            ExportSnapshotError::NotFound(_) => "NotFoundException",
+            ExportSnapshotError::OperationFailure(_) => "OperationFailureException",
+            ExportSnapshotError::Service(_) => "ServiceException",
+            ExportSnapshotError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetActiveNames
 #[derive(Debug, PartialEq)]
 pub enum GetActiveNamesError {
@@ -10127,6 +10891,19 @@ impl fmt::Display for GetActiveNamesError {
     }
 }
 impl Error for GetActiveNamesError {}
+impl ServiceErrorCode for GetActiveNamesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetActiveNamesError::AccessDenied(_) => "AccessDeniedException",
+            GetActiveNamesError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetActiveNamesError::InvalidInput(_) => "InvalidInputException",
+            GetActiveNamesError::NotFound(_) => "NotFoundException",
+            GetActiveNamesError::OperationFailure(_) => "OperationFailureException",
+            GetActiveNamesError::Service(_) => "ServiceException",
+            GetActiveNamesError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetAlarms
 #[derive(Debug, PartialEq)]
 pub enum GetAlarmsError {
@@ -10187,6 +10964,18 @@ impl fmt::Display for GetAlarmsError {
     }
 }
 impl Error for GetAlarmsError {}
+impl ServiceErrorCode for GetAlarmsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAlarmsError::AccessDenied(_) => "AccessDeniedException",
+            GetAlarmsError::InvalidInput(_) => "InvalidInputException",
+            GetAlarmsError::NotFound(_) => "NotFoundException",
+            GetAlarmsError::OperationFailure(_) => "OperationFailureException",
+            GetAlarmsError::Service(_) => "ServiceException",
+            GetAlarmsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetAutoSnapshots
 #[derive(Debug, PartialEq)]
 pub enum GetAutoSnapshotsError {
@@ -10247,6 +11036,18 @@ impl fmt::Display for GetAutoSnapshotsError {
     }
 }
 impl Error for GetAutoSnapshotsError {}
+impl ServiceErrorCode for GetAutoSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAutoSnapshotsError::AccessDenied(_) => "AccessDeniedException",
+            GetAutoSnapshotsError::InvalidInput(_) => "InvalidInputException",
+            GetAutoSnapshotsError::NotFound(_) => "NotFoundException",
+            GetAutoSnapshotsError::OperationFailure(_) => "OperationFailureException",
+            GetAutoSnapshotsError::Service(_) => "ServiceException",
+            GetAutoSnapshotsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetBlueprints
 #[derive(Debug, PartialEq)]
 pub enum GetBlueprintsError {
@@ -10315,6 +11116,19 @@ impl fmt::Display for GetBlueprintsError {
     }
 }
 impl Error for GetBlueprintsError {}
+impl ServiceErrorCode for GetBlueprintsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBlueprintsError::AccessDenied(_) => "AccessDeniedException",
+            GetBlueprintsError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetBlueprintsError::InvalidInput(_) => "InvalidInputException",
+            GetBlueprintsError::NotFound(_) => "NotFoundException",
+            GetBlueprintsError::OperationFailure(_) => "OperationFailureException",
+            GetBlueprintsError::Service(_) => "ServiceException",
+            GetBlueprintsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetBundles
 #[derive(Debug, PartialEq)]
 pub enum GetBundlesError {
@@ -10381,6 +11195,19 @@ impl fmt::Display for GetBundlesError {
     }
 }
 impl Error for GetBundlesError {}
+impl ServiceErrorCode for GetBundlesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBundlesError::AccessDenied(_) => "AccessDeniedException",
+            GetBundlesError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetBundlesError::InvalidInput(_) => "InvalidInputException",
+            GetBundlesError::NotFound(_) => "NotFoundException",
+            GetBundlesError::OperationFailure(_) => "OperationFailureException",
+            GetBundlesError::Service(_) => "ServiceException",
+            GetBundlesError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetCertificates
 #[derive(Debug, PartialEq)]
 pub enum GetCertificatesError {
@@ -10435,6 +11262,17 @@ impl fmt::Display for GetCertificatesError {
     }
 }
 impl Error for GetCertificatesError {}
+impl ServiceErrorCode for GetCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCertificatesError::AccessDenied(_) => "AccessDeniedException",
+            GetCertificatesError::InvalidInput(_) => "InvalidInputException",
+            GetCertificatesError::NotFound(_) => "NotFoundException",
+            GetCertificatesError::Service(_) => "ServiceException",
+            GetCertificatesError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetCloudFormationStackRecords
 #[derive(Debug, PartialEq)]
 pub enum GetCloudFormationStackRecordsError {
@@ -10523,6 +11361,21 @@ impl fmt::Display for GetCloudFormationStackRecordsError {
     }
 }
 impl Error for GetCloudFormationStackRecordsError {}
+impl ServiceErrorCode for GetCloudFormationStackRecordsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCloudFormationStackRecordsError::AccessDenied(_) => "AccessDeniedException",
+            GetCloudFormationStackRecordsError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetCloudFormationStackRecordsError::InvalidInput(_) => "InvalidInputException",
+            GetCloudFormationStackRecordsError::NotFound(_) => "NotFoundException",
+            GetCloudFormationStackRecordsError::OperationFailure(_) => "OperationFailureException",
+            GetCloudFormationStackRecordsError::Service(_) => "ServiceException",
+            GetCloudFormationStackRecordsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetContactMethods
 #[derive(Debug, PartialEq)]
 pub enum GetContactMethodsError {
@@ -10583,6 +11436,18 @@ impl fmt::Display for GetContactMethodsError {
     }
 }
 impl Error for GetContactMethodsError {}
+impl ServiceErrorCode for GetContactMethodsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContactMethodsError::AccessDenied(_) => "AccessDeniedException",
+            GetContactMethodsError::InvalidInput(_) => "InvalidInputException",
+            GetContactMethodsError::NotFound(_) => "NotFoundException",
+            GetContactMethodsError::OperationFailure(_) => "OperationFailureException",
+            GetContactMethodsError::Service(_) => "ServiceException",
+            GetContactMethodsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetContainerAPIMetadata
 #[derive(Debug, PartialEq)]
 pub enum GetContainerAPIMetadataError {
@@ -10629,6 +11494,15 @@ impl fmt::Display for GetContainerAPIMetadataError {
     }
 }
 impl Error for GetContainerAPIMetadataError {}
+impl ServiceErrorCode for GetContainerAPIMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContainerAPIMetadataError::AccessDenied(_) => "AccessDeniedException",
+            GetContainerAPIMetadataError::Service(_) => "ServiceException",
+            GetContainerAPIMetadataError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetContainerImages
 #[derive(Debug, PartialEq)]
 pub enum GetContainerImagesError {
@@ -10683,6 +11557,17 @@ impl fmt::Display for GetContainerImagesError {
     }
 }
 impl Error for GetContainerImagesError {}
+impl ServiceErrorCode for GetContainerImagesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContainerImagesError::AccessDenied(_) => "AccessDeniedException",
+            GetContainerImagesError::InvalidInput(_) => "InvalidInputException",
+            GetContainerImagesError::NotFound(_) => "NotFoundException",
+            GetContainerImagesError::Service(_) => "ServiceException",
+            GetContainerImagesError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetContainerLog
 #[derive(Debug, PartialEq)]
 pub enum GetContainerLogError {
@@ -10737,6 +11622,17 @@ impl fmt::Display for GetContainerLogError {
     }
 }
 impl Error for GetContainerLogError {}
+impl ServiceErrorCode for GetContainerLogError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContainerLogError::AccessDenied(_) => "AccessDeniedException",
+            GetContainerLogError::InvalidInput(_) => "InvalidInputException",
+            GetContainerLogError::NotFound(_) => "NotFoundException",
+            GetContainerLogError::Service(_) => "ServiceException",
+            GetContainerLogError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetContainerServiceDeployments
 #[derive(Debug, PartialEq)]
 pub enum GetContainerServiceDeploymentsError {
@@ -10805,6 +11701,17 @@ impl fmt::Display for GetContainerServiceDeploymentsError {
     }
 }
 impl Error for GetContainerServiceDeploymentsError {}
+impl ServiceErrorCode for GetContainerServiceDeploymentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContainerServiceDeploymentsError::AccessDenied(_) => "AccessDeniedException",
+            GetContainerServiceDeploymentsError::InvalidInput(_) => "InvalidInputException",
+            GetContainerServiceDeploymentsError::NotFound(_) => "NotFoundException",
+            GetContainerServiceDeploymentsError::Service(_) => "ServiceException",
+            GetContainerServiceDeploymentsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetContainerServiceMetricData
 #[derive(Debug, PartialEq)]
 pub enum GetContainerServiceMetricDataError {
@@ -10873,6 +11780,17 @@ impl fmt::Display for GetContainerServiceMetricDataError {
     }
 }
 impl Error for GetContainerServiceMetricDataError {}
+impl ServiceErrorCode for GetContainerServiceMetricDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContainerServiceMetricDataError::AccessDenied(_) => "AccessDeniedException",
+            GetContainerServiceMetricDataError::InvalidInput(_) => "InvalidInputException",
+            GetContainerServiceMetricDataError::NotFound(_) => "NotFoundException",
+            GetContainerServiceMetricDataError::Service(_) => "ServiceException",
+            GetContainerServiceMetricDataError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetContainerServicePowers
 #[derive(Debug, PartialEq)]
 pub enum GetContainerServicePowersError {
@@ -10933,6 +11851,17 @@ impl fmt::Display for GetContainerServicePowersError {
     }
 }
 impl Error for GetContainerServicePowersError {}
+impl ServiceErrorCode for GetContainerServicePowersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContainerServicePowersError::AccessDenied(_) => "AccessDeniedException",
+            GetContainerServicePowersError::InvalidInput(_) => "InvalidInputException",
+            GetContainerServicePowersError::NotFound(_) => "NotFoundException",
+            GetContainerServicePowersError::Service(_) => "ServiceException",
+            GetContainerServicePowersError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetContainerServices
 #[derive(Debug, PartialEq)]
 pub enum GetContainerServicesError {
@@ -10989,6 +11918,17 @@ impl fmt::Display for GetContainerServicesError {
     }
 }
 impl Error for GetContainerServicesError {}
+impl ServiceErrorCode for GetContainerServicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContainerServicesError::AccessDenied(_) => "AccessDeniedException",
+            GetContainerServicesError::InvalidInput(_) => "InvalidInputException",
+            GetContainerServicesError::NotFound(_) => "NotFoundException",
+            GetContainerServicesError::Service(_) => "ServiceException",
+            GetContainerServicesError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetDisk
 #[derive(Debug, PartialEq)]
 pub enum GetDiskError {
@@ -11053,6 +11993,19 @@ impl fmt::Display for GetDiskError {
     }
 }
 impl Error for GetDiskError {}
+impl ServiceErrorCode for GetDiskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDiskError::AccessDenied(_) => "AccessDeniedException",
+            GetDiskError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetDiskError::InvalidInput(_) => "InvalidInputException",
+            GetDiskError::NotFound(_) => "NotFoundException",
+            GetDiskError::OperationFailure(_) => "OperationFailureException",
+            GetDiskError::Service(_) => "ServiceException",
+            GetDiskError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetDiskSnapshot
 #[derive(Debug, PartialEq)]
 pub enum GetDiskSnapshotError {
@@ -11121,6 +12074,19 @@ impl fmt::Display for GetDiskSnapshotError {
     }
 }
 impl Error for GetDiskSnapshotError {}
+impl ServiceErrorCode for GetDiskSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDiskSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            GetDiskSnapshotError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetDiskSnapshotError::InvalidInput(_) => "InvalidInputException",
+            GetDiskSnapshotError::NotFound(_) => "NotFoundException",
+            GetDiskSnapshotError::OperationFailure(_) => "OperationFailureException",
+            GetDiskSnapshotError::Service(_) => "ServiceException",
+            GetDiskSnapshotError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetDiskSnapshots
 #[derive(Debug, PartialEq)]
 pub enum GetDiskSnapshotsError {
@@ -11189,6 +12155,19 @@ impl fmt::Display for GetDiskSnapshotsError {
     }
 }
 impl Error for GetDiskSnapshotsError {}
+impl ServiceErrorCode for GetDiskSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDiskSnapshotsError::AccessDenied(_) => "AccessDeniedException",
+            GetDiskSnapshotsError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetDiskSnapshotsError::InvalidInput(_) => "InvalidInputException",
+            GetDiskSnapshotsError::NotFound(_) => "NotFoundException",
+            GetDiskSnapshotsError::OperationFailure(_) => "OperationFailureException",
+            GetDiskSnapshotsError::Service(_) => "ServiceException",
+            GetDiskSnapshotsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetDisks
 #[derive(Debug, PartialEq)]
 pub enum GetDisksError {
@@ -11253,6 +12232,19 @@ impl fmt::Display for GetDisksError {
     }
 }
 impl Error for GetDisksError {}
+impl ServiceErrorCode for GetDisksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDisksError::AccessDenied(_) => "AccessDeniedException",
+            GetDisksError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetDisksError::InvalidInput(_) => "InvalidInputException",
+            GetDisksError::NotFound(_) => "NotFoundException",
+            GetDisksError::OperationFailure(_) => "OperationFailureException",
+            GetDisksError::Service(_) => "ServiceException",
+            GetDisksError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetDistributionBundles
 #[derive(Debug, PartialEq)]
 pub enum GetDistributionBundlesError {
@@ -11317,6 +12309,18 @@ impl fmt::Display for GetDistributionBundlesError {
     }
 }
 impl Error for GetDistributionBundlesError {}
+impl ServiceErrorCode for GetDistributionBundlesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDistributionBundlesError::AccessDenied(_) => "AccessDeniedException",
+            GetDistributionBundlesError::InvalidInput(_) => "InvalidInputException",
+            GetDistributionBundlesError::NotFound(_) => "NotFoundException",
+            GetDistributionBundlesError::OperationFailure(_) => "OperationFailureException",
+            GetDistributionBundlesError::Service(_) => "ServiceException",
+            GetDistributionBundlesError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetDistributionLatestCacheReset
 #[derive(Debug, PartialEq)]
 pub enum GetDistributionLatestCacheResetError {
@@ -11395,6 +12399,20 @@ impl fmt::Display for GetDistributionLatestCacheResetError {
     }
 }
 impl Error for GetDistributionLatestCacheResetError {}
+impl ServiceErrorCode for GetDistributionLatestCacheResetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDistributionLatestCacheResetError::AccessDenied(_) => "AccessDeniedException",
+            GetDistributionLatestCacheResetError::InvalidInput(_) => "InvalidInputException",
+            GetDistributionLatestCacheResetError::NotFound(_) => "NotFoundException",
+            GetDistributionLatestCacheResetError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            GetDistributionLatestCacheResetError::Service(_) => "ServiceException",
+            GetDistributionLatestCacheResetError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetDistributionMetricData
 #[derive(Debug, PartialEq)]
 pub enum GetDistributionMetricDataError {
@@ -11463,6 +12481,18 @@ impl fmt::Display for GetDistributionMetricDataError {
     }
 }
 impl Error for GetDistributionMetricDataError {}
+impl ServiceErrorCode for GetDistributionMetricDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDistributionMetricDataError::AccessDenied(_) => "AccessDeniedException",
+            GetDistributionMetricDataError::InvalidInput(_) => "InvalidInputException",
+            GetDistributionMetricDataError::NotFound(_) => "NotFoundException",
+            GetDistributionMetricDataError::OperationFailure(_) => "OperationFailureException",
+            GetDistributionMetricDataError::Service(_) => "ServiceException",
+            GetDistributionMetricDataError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetDistributions
 #[derive(Debug, PartialEq)]
 pub enum GetDistributionsError {
@@ -11523,6 +12553,18 @@ impl fmt::Display for GetDistributionsError {
     }
 }
 impl Error for GetDistributionsError {}
+impl ServiceErrorCode for GetDistributionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDistributionsError::AccessDenied(_) => "AccessDeniedException",
+            GetDistributionsError::InvalidInput(_) => "InvalidInputException",
+            GetDistributionsError::NotFound(_) => "NotFoundException",
+            GetDistributionsError::OperationFailure(_) => "OperationFailureException",
+            GetDistributionsError::Service(_) => "ServiceException",
+            GetDistributionsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetDomain
 #[derive(Debug, PartialEq)]
 pub enum GetDomainError {
@@ -11589,6 +12631,19 @@ impl fmt::Display for GetDomainError {
     }
 }
 impl Error for GetDomainError {}
+impl ServiceErrorCode for GetDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDomainError::AccessDenied(_) => "AccessDeniedException",
+            GetDomainError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetDomainError::InvalidInput(_) => "InvalidInputException",
+            GetDomainError::NotFound(_) => "NotFoundException",
+            GetDomainError::OperationFailure(_) => "OperationFailureException",
+            GetDomainError::Service(_) => "ServiceException",
+            GetDomainError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetDomains
 #[derive(Debug, PartialEq)]
 pub enum GetDomainsError {
@@ -11655,6 +12710,19 @@ impl fmt::Display for GetDomainsError {
     }
 }
 impl Error for GetDomainsError {}
+impl ServiceErrorCode for GetDomainsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDomainsError::AccessDenied(_) => "AccessDeniedException",
+            GetDomainsError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetDomainsError::InvalidInput(_) => "InvalidInputException",
+            GetDomainsError::NotFound(_) => "NotFoundException",
+            GetDomainsError::OperationFailure(_) => "OperationFailureException",
+            GetDomainsError::Service(_) => "ServiceException",
+            GetDomainsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetExportSnapshotRecords
 #[derive(Debug, PartialEq)]
 pub enum GetExportSnapshotRecordsError {
@@ -11733,6 +12801,21 @@ impl fmt::Display for GetExportSnapshotRecordsError {
     }
 }
 impl Error for GetExportSnapshotRecordsError {}
+impl ServiceErrorCode for GetExportSnapshotRecordsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetExportSnapshotRecordsError::AccessDenied(_) => "AccessDeniedException",
+            GetExportSnapshotRecordsError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetExportSnapshotRecordsError::InvalidInput(_) => "InvalidInputException",
+            GetExportSnapshotRecordsError::NotFound(_) => "NotFoundException",
+            GetExportSnapshotRecordsError::OperationFailure(_) => "OperationFailureException",
+            GetExportSnapshotRecordsError::Service(_) => "ServiceException",
+            GetExportSnapshotRecordsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetInstance
 #[derive(Debug, PartialEq)]
 pub enum GetInstanceError {
@@ -11799,6 +12882,19 @@ impl fmt::Display for GetInstanceError {
     }
 }
 impl Error for GetInstanceError {}
+impl ServiceErrorCode for GetInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInstanceError::AccessDenied(_) => "AccessDeniedException",
+            GetInstanceError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetInstanceError::InvalidInput(_) => "InvalidInputException",
+            GetInstanceError::NotFound(_) => "NotFoundException",
+            GetInstanceError::OperationFailure(_) => "OperationFailureException",
+            GetInstanceError::Service(_) => "ServiceException",
+            GetInstanceError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetInstanceAccessDetails
 #[derive(Debug, PartialEq)]
 pub enum GetInstanceAccessDetailsError {
@@ -11877,6 +12973,21 @@ impl fmt::Display for GetInstanceAccessDetailsError {
     }
 }
 impl Error for GetInstanceAccessDetailsError {}
+impl ServiceErrorCode for GetInstanceAccessDetailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInstanceAccessDetailsError::AccessDenied(_) => "AccessDeniedException",
+            GetInstanceAccessDetailsError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetInstanceAccessDetailsError::InvalidInput(_) => "InvalidInputException",
+            GetInstanceAccessDetailsError::NotFound(_) => "NotFoundException",
+            GetInstanceAccessDetailsError::OperationFailure(_) => "OperationFailureException",
+            GetInstanceAccessDetailsError::Service(_) => "ServiceException",
+            GetInstanceAccessDetailsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetInstanceMetricData
 #[derive(Debug, PartialEq)]
 pub enum GetInstanceMetricDataError {
@@ -11949,6 +13060,21 @@ impl fmt::Display for GetInstanceMetricDataError {
     }
 }
 impl Error for GetInstanceMetricDataError {}
+impl ServiceErrorCode for GetInstanceMetricDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInstanceMetricDataError::AccessDenied(_) => "AccessDeniedException",
+            GetInstanceMetricDataError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetInstanceMetricDataError::InvalidInput(_) => "InvalidInputException",
+            GetInstanceMetricDataError::NotFound(_) => "NotFoundException",
+            GetInstanceMetricDataError::OperationFailure(_) => "OperationFailureException",
+            GetInstanceMetricDataError::Service(_) => "ServiceException",
+            GetInstanceMetricDataError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetInstancePortStates
 #[derive(Debug, PartialEq)]
 pub enum GetInstancePortStatesError {
@@ -12020,7 +13146,22 @@ impl fmt::Display for GetInstancePortStatesError {
         }
     }
 }
-impl Error for GetInstancePortStatesError {}
+impl Error for GetInstancePortStatesError {}
+impl ServiceErrorCode for GetInstancePortStatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInstancePortStatesError::AccessDenied(_) => "AccessDeniedException",
+            GetInstancePortStatesError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetInstancePortStatesError::InvalidInput(_) => "InvalidInputException",
+            GetInstancePortStatesError::NotFound(_) => "NotFoundException",
+            GetInstancePortStatesError::OperationFailure(_) => "OperationFailureException",
+            GetInstancePortStatesError::Service(_) => "ServiceException",
+            GetInstancePortStatesError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetInstanceSnapshot
 #[derive(Debug, PartialEq)]
 pub enum GetInstanceSnapshotError {
@@ -12091,6 +13232,21 @@ impl fmt::Display for GetInstanceSnapshotError {
     }
 }
 impl Error for GetInstanceSnapshotError {}
+impl ServiceErrorCode for GetInstanceSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInstanceSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            GetInstanceSnapshotError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetInstanceSnapshotError::InvalidInput(_) => "InvalidInputException",
+            GetInstanceSnapshotError::NotFound(_) => "NotFoundException",
+            GetInstanceSnapshotError::OperationFailure(_) => "OperationFailureException",
+            GetInstanceSnapshotError::Service(_) => "ServiceException",
+            GetInstanceSnapshotError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetInstanceSnapshots
 #[derive(Debug, PartialEq)]
 pub enum GetInstanceSnapshotsError {
@@ -12163,6 +13319,21 @@ impl fmt::Display for GetInstanceSnapshotsError {
     }
 }
 impl Error for GetInstanceSnapshotsError {}
+impl ServiceErrorCode for GetInstanceSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInstanceSnapshotsError::AccessDenied(_) => "AccessDeniedException",
+            GetInstanceSnapshotsError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetInstanceSnapshotsError::InvalidInput(_) => "InvalidInputException",
+            GetInstanceSnapshotsError::NotFound(_) => "NotFoundException",
+            GetInstanceSnapshotsError::OperationFailure(_) => "OperationFailureException",
+            GetInstanceSnapshotsError::Service(_) => "ServiceException",
+            GetInstanceSnapshotsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetInstanceState
 #[derive(Debug, PartialEq)]
 pub enum GetInstanceStateError {
@@ -12231,6 +13402,19 @@ impl fmt::Display for GetInstanceStateError {
     }
 }
 impl Error for GetInstanceStateError {}
+impl ServiceErrorCode for GetInstanceStateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInstanceStateError::AccessDenied(_) => "AccessDeniedException",
+            GetInstanceStateError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetInstanceStateError::InvalidInput(_) => "InvalidInputException",
+            GetInstanceStateError::NotFound(_) => "NotFoundException",
+            GetInstanceStateError::OperationFailure(_) => "OperationFailureException",
+            GetInstanceStateError::Service(_) => "ServiceException",
+            GetInstanceStateError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetInstances
 #[derive(Debug, PartialEq)]
 pub enum GetInstancesError {
@@ -12297,6 +13481,19 @@ impl fmt::Display for GetInstancesError {
     }
 }
 impl Error for GetInstancesError {}
+impl ServiceErrorCode for GetInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInstancesError::AccessDenied(_) => "AccessDeniedException",
+            GetInstancesError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetInstancesError::InvalidInput(_) => "InvalidInputException",
+            GetInstancesError::NotFound(_) => "NotFoundException",
+            GetInstancesError::OperationFailure(_) => "OperationFailureException",
+            GetInstancesError::Service(_) => "ServiceException",
+            GetInstancesError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetKeyPair
 #[derive(Debug, PartialEq)]
 pub enum GetKeyPairError {
@@ -12363,6 +13560,19 @@ impl fmt::Display for GetKeyPairError {
     }
 }
 impl Error for GetKeyPairError {}
+impl ServiceErrorCode for GetKeyPairError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetKeyPairError::AccessDenied(_) => "AccessDeniedException",
+            GetKeyPairError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetKeyPairError::InvalidInput(_) => "InvalidInputException",
+            GetKeyPairError::NotFound(_) => "NotFoundException",
+            GetKeyPairError::OperationFailure(_) => "OperationFailureException",
+            GetKeyPairError::Service(_) => "ServiceException",
+            GetKeyPairError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetKeyPairs
 #[derive(Debug, PartialEq)]
 pub enum GetKeyPairsError {
@@ -12429,6 +13639,19 @@ impl fmt::Display for GetKeyPairsError {
     }
 }
 impl Error for GetKeyPairsError {}
+impl ServiceErrorCode for GetKeyPairsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetKeyPairsError::AccessDenied(_) => "AccessDeniedException",
+            GetKeyPairsError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetKeyPairsError::InvalidInput(_) => "InvalidInputException",
+            GetKeyPairsError::NotFound(_) => "NotFoundException",
+            GetKeyPairsError::OperationFailure(_) => "OperationFailureException",
+            GetKeyPairsError::Service(_) => "ServiceException",
+            GetKeyPairsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum GetLoadBalancerError {
@@ -12497,6 +13720,19 @@ impl fmt::Display for GetLoadBalancerError {
     }
 }
 impl Error for GetLoadBalancerError {}
+impl ServiceErrorCode for GetLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLoadBalancerError::AccessDenied(_) => "AccessDeniedException",
+            GetLoadBalancerError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetLoadBalancerError::InvalidInput(_) => "InvalidInputException",
+            GetLoadBalancerError::NotFound(_) => "NotFoundException",
+            GetLoadBalancerError::OperationFailure(_) => "OperationFailureException",
+            GetLoadBalancerError::Service(_) => "ServiceException",
+            GetLoadBalancerError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetLoadBalancerMetricData
 #[derive(Debug, PartialEq)]
 pub enum GetLoadBalancerMetricDataError {
@@ -12575,6 +13811,21 @@ impl fmt::Display for GetLoadBalancerMetricDataError {
     }
 }
 impl Error for GetLoadBalancerMetricDataError {}
+impl ServiceErrorCode for GetLoadBalancerMetricDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLoadBalancerMetricDataError::AccessDenied(_) => "AccessDeniedException",
+            GetLoadBalancerMetricDataError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetLoadBalancerMetricDataError::InvalidInput(_) => "InvalidInputException",
+            GetLoadBalancerMetricDataError::NotFound(_) => "NotFoundException",
+            GetLoadBalancerMetricDataError::OperationFailure(_) => "OperationFailureException",
+            GetLoadBalancerMetricDataError::Service(_) => "ServiceException",
+            GetLoadBalancerMetricDataError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetLoadBalancerTlsCertificates
 #[derive(Debug, PartialEq)]
 pub enum GetLoadBalancerTlsCertificatesError {
@@ -12663,6 +13914,21 @@ impl fmt::Display for GetLoadBalancerTlsCertificatesError {
     }
 }
 impl Error for GetLoadBalancerTlsCertificatesError {}
+impl ServiceErrorCode for GetLoadBalancerTlsCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLoadBalancerTlsCertificatesError::AccessDenied(_) => "AccessDeniedException",
+            GetLoadBalancerTlsCertificatesError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetLoadBalancerTlsCertificatesError::InvalidInput(_) => "InvalidInputException",
+            GetLoadBalancerTlsCertificatesError::NotFound(_) => "NotFoundException",
+            GetLoadBalancerTlsCertificatesError::OperationFailure(_) => "OperationFailureException",
+            GetLoadBalancerTlsCertificatesError::Service(_) => "ServiceException",
+            GetLoadBalancerTlsCertificatesError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetLoadBalancers
 #[derive(Debug, PartialEq)]
 pub enum GetLoadBalancersError {
@@ -12731,6 +13997,19 @@ impl fmt::Display for GetLoadBalancersError {
     }
 }
 impl Error for GetLoadBalancersError {}
+impl ServiceErrorCode for GetLoadBalancersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLoadBalancersError::AccessDenied(_) => "AccessDeniedException",
+            GetLoadBalancersError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetLoadBalancersError::InvalidInput(_) => "InvalidInputException",
+            GetLoadBalancersError::NotFound(_) => "NotFoundException",
+            GetLoadBalancersError::OperationFailure(_) => "OperationFailureException",
+            GetLoadBalancersError::Service(_) => "ServiceException",
+            GetLoadBalancersError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetOperation
 #[derive(Debug, PartialEq)]
 pub enum GetOperationError {
@@ -12797,6 +14076,19 @@ impl fmt::Display for GetOperationError {
     }
 }
 impl Error for GetOperationError {}
+impl ServiceErrorCode for GetOperationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOperationError::AccessDenied(_) => "AccessDeniedException",
+            GetOperationError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetOperationError::InvalidInput(_) => "InvalidInputException",
+            GetOperationError::NotFound(_) => "NotFoundException",
+            GetOperationError::OperationFailure(_) => "OperationFailureException",
+            GetOperationError::Service(_) => "ServiceException",
+            GetOperationError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetOperations
 #[derive(Debug, PartialEq)]
 pub enum GetOperationsError {
@@ -12865,6 +14157,19 @@ impl fmt::Display for GetOperationsError {
     }
 }
 impl Error for GetOperationsError {}
+impl ServiceErrorCode for GetOperationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOperationsError::AccessDenied(_) => "AccessDeniedException",
+            GetOperationsError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetOperationsError::InvalidInput(_) => "InvalidInputException",
+            GetOperationsError::NotFound(_) => "NotFoundException",
+            GetOperationsError::OperationFailure(_) => "OperationFailureException",
+            GetOperationsError::Service(_) => "ServiceException",
+            GetOperationsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetOperationsForResource
 #[derive(Debug, PartialEq)]
 pub enum GetOperationsForResourceError {
@@ -12943,6 +14248,21 @@ impl fmt::Display for GetOperationsForResourceError {
     }
 }
 impl Error for GetOperationsForResourceError {}
+impl ServiceErrorCode for GetOperationsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOperationsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            GetOperationsForResourceError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetOperationsForResourceError::InvalidInput(_) => "InvalidInputException",
+            GetOperationsForResourceError::NotFound(_) => "NotFoundException",
+            GetOperationsForResourceError::OperationFailure(_) => "OperationFailureException",
+            GetOperationsForResourceError::Service(_) => "ServiceException",
+            GetOperationsForResourceError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetRegions
 #[derive(Debug, PartialEq)]
 pub enum GetRegionsError {
@@ -13009,6 +14329,19 @@ impl fmt::Display for GetRegionsError {
     }
 }
 impl Error for GetRegionsError {}
+impl ServiceErrorCode for GetRegionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRegionsError::AccessDenied(_) => "AccessDeniedException",
+            GetRegionsError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetRegionsError::InvalidInput(_) => "InvalidInputException",
+            GetRegionsError::NotFound(_) => "NotFoundException",
+            GetRegionsError::OperationFailure(_) => "OperationFailureException",
+            GetRegionsError::Service(_) => "ServiceException",
+            GetRegionsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetRelationalDatabase
 #[derive(Debug, PartialEq)]
 pub enum GetRelationalDatabaseError {
@@ -13081,6 +14414,21 @@ impl fmt::Display for GetRelationalDatabaseError {
     }
 }
 impl Error for GetRelationalDatabaseError {}
+impl ServiceErrorCode for GetRelationalDatabaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRelationalDatabaseError::AccessDenied(_) => "AccessDeniedException",
+            GetRelationalDatabaseError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetRelationalDatabaseError::InvalidInput(_) => "InvalidInputException",
+            GetRelationalDatabaseError::NotFound(_) => "NotFoundException",
+            GetRelationalDatabaseError::OperationFailure(_) => "OperationFailureException",
+            GetRelationalDatabaseError::Service(_) => "ServiceException",
+            GetRelationalDatabaseError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetRelationalDatabaseBlueprints
 #[derive(Debug, PartialEq)]
 pub enum GetRelationalDatabaseBlueprintsError {
@@ -13169,6 +14517,23 @@ impl fmt::Display for GetRelationalDatabaseBlueprintsError {
     }
 }
 impl Error for GetRelationalDatabaseBlueprintsError {}
+impl ServiceErrorCode for GetRelationalDatabaseBlueprintsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRelationalDatabaseBlueprintsError::AccessDenied(_) => "AccessDeniedException",
+            GetRelationalDatabaseBlueprintsError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetRelationalDatabaseBlueprintsError::InvalidInput(_) => "InvalidInputException",
+            GetRelationalDatabaseBlueprintsError::NotFound(_) => "NotFoundException",
+            GetRelationalDatabaseBlueprintsError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            GetRelationalDatabaseBlueprintsError::Service(_) => "ServiceException",
+            GetRelationalDatabaseBlueprintsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetRelationalDatabaseBundles
 #[derive(Debug, PartialEq)]
 pub enum GetRelationalDatabaseBundlesError {
@@ -13255,6 +14620,21 @@ impl fmt::Display for GetRelationalDatabaseBundlesError {
     }
 }
 impl Error for GetRelationalDatabaseBundlesError {}
+impl ServiceErrorCode for GetRelationalDatabaseBundlesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRelationalDatabaseBundlesError::AccessDenied(_) => "AccessDeniedException",
+            GetRelationalDatabaseBundlesError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetRelationalDatabaseBundlesError::InvalidInput(_) => "InvalidInputException",
+            GetRelationalDatabaseBundlesError::NotFound(_) => "NotFoundException",
+            GetRelationalDatabaseBundlesError::OperationFailure(_) => "OperationFailureException",
+            GetRelationalDatabaseBundlesError::Service(_) => "ServiceException",
+            GetRelationalDatabaseBundlesError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetRelationalDatabaseEvents
 #[derive(Debug, PartialEq)]
 pub enum GetRelationalDatabaseEventsError {
@@ -13337,6 +14717,21 @@ impl fmt::Display for GetRelationalDatabaseEventsError {
     }
 }
 impl Error for GetRelationalDatabaseEventsError {}
+impl ServiceErrorCode for GetRelationalDatabaseEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRelationalDatabaseEventsError::AccessDenied(_) => "AccessDeniedException",
+            GetRelationalDatabaseEventsError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetRelationalDatabaseEventsError::InvalidInput(_) => "InvalidInputException",
+            GetRelationalDatabaseEventsError::NotFound(_) => "NotFoundException",
+            GetRelationalDatabaseEventsError::OperationFailure(_) => "OperationFailureException",
+            GetRelationalDatabaseEventsError::Service(_) => "ServiceException",
+            GetRelationalDatabaseEventsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetRelationalDatabaseLogEvents
 #[derive(Debug, PartialEq)]
 pub enum GetRelationalDatabaseLogEventsError {
@@ -13425,6 +14820,21 @@ impl fmt::Display for GetRelationalDatabaseLogEventsError {
     }
 }
 impl Error for GetRelationalDatabaseLogEventsError {}
+impl ServiceErrorCode for GetRelationalDatabaseLogEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRelationalDatabaseLogEventsError::AccessDenied(_) => "AccessDeniedException",
+            GetRelationalDatabaseLogEventsError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetRelationalDatabaseLogEventsError::InvalidInput(_) => "InvalidInputException",
+            GetRelationalDatabaseLogEventsError::NotFound(_) => "NotFoundException",
+            GetRelationalDatabaseLogEventsError::OperationFailure(_) => "OperationFailureException",
+            GetRelationalDatabaseLogEventsError::Service(_) => "ServiceException",
+            GetRelationalDatabaseLogEventsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetRelationalDatabaseLogStreams
 #[derive(Debug, PartialEq)]
 pub enum GetRelationalDatabaseLogStreamsError {
@@ -13513,6 +14923,23 @@ impl fmt::Display for GetRelationalDatabaseLogStreamsError {
     }
 }
 impl Error for GetRelationalDatabaseLogStreamsError {}
+impl ServiceErrorCode for GetRelationalDatabaseLogStreamsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRelationalDatabaseLogStreamsError::AccessDenied(_) => "AccessDeniedException",
+            GetRelationalDatabaseLogStreamsError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetRelationalDatabaseLogStreamsError::InvalidInput(_) => "InvalidInputException",
+            GetRelationalDatabaseLogStreamsError::NotFound(_) => "NotFoundException",
+            GetRelationalDatabaseLogStreamsError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            GetRelationalDatabaseLogStreamsError::Service(_) => "ServiceException",
+            GetRelationalDatabaseLogStreamsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetRelationalDatabaseMasterUserPassword
 #[derive(Debug, PartialEq)]
 pub enum GetRelationalDatabaseMasterUserPasswordError {
@@ -13611,6 +15038,29 @@ impl fmt::Display for GetRelationalDatabaseMasterUserPasswordError {
     }
 }
 impl Error for GetRelationalDatabaseMasterUserPasswordError {}
+impl ServiceErrorCode for GetRelationalDatabaseMasterUserPasswordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRelationalDatabaseMasterUserPasswordError::AccessDenied(_) => {
+                "AccessDeniedException"
+            }
+            GetRelationalDatabaseMasterUserPasswordError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetRelationalDatabaseMasterUserPasswordError::InvalidInput(_) => {
+                "InvalidInputException"
+            }
+            GetRelationalDatabaseMasterUserPasswordError::NotFound(_) => "NotFoundException",
+            GetRelationalDatabaseMasterUserPasswordError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            GetRelationalDatabaseMasterUserPasswordError::Service(_) => "ServiceException",
+            GetRelationalDatabaseMasterUserPasswordError::Unauthenticated(_) => {
+                "UnauthenticatedException"
+            }
+        }
+    }
+}
 /// Errors returned by GetRelationalDatabaseMetricData
 #[derive(Debug, PartialEq)]
 pub enum GetRelationalDatabaseMetricDataError {
@@ -13699,6 +15149,23 @@ impl fmt::Display for GetRelationalDatabaseMetricDataError {
     }
 }
 impl Error for GetRelationalDatabaseMetricDataError {}
+impl ServiceErrorCode for GetRelationalDatabaseMetricDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRelationalDatabaseMetricDataError::AccessDenied(_) => "AccessDeniedException",
+            GetRelationalDatabaseMetricDataError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetRelationalDatabaseMetricDataError::InvalidInput(_) => "InvalidInputException",
+            GetRelationalDatabaseMetricDataError::NotFound(_) => "NotFoundException",
+            GetRelationalDatabaseMetricDataError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            GetRelationalDatabaseMetricDataError::Service(_) => "ServiceException",
+            GetRelationalDatabaseMetricDataError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetRelationalDatabaseParameters
 #[derive(Debug, PartialEq)]
 pub enum GetRelationalDatabaseParametersError {
@@ -13787,6 +15254,23 @@ impl fmt::Display for GetRelationalDatabaseParametersError {
     }
 }
 impl Error for GetRelationalDatabaseParametersError {}
+impl ServiceErrorCode for GetRelationalDatabaseParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRelationalDatabaseParametersError::AccessDenied(_) => "AccessDeniedException",
+            GetRelationalDatabaseParametersError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetRelationalDatabaseParametersError::InvalidInput(_) => "InvalidInputException",
+            GetRelationalDatabaseParametersError::NotFound(_) => "NotFoundException",
+            GetRelationalDatabaseParametersError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            GetRelationalDatabaseParametersError::Service(_) => "ServiceException",
+            GetRelationalDatabaseParametersError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetRelationalDatabaseSnapshot
 #[derive(Debug, PartialEq)]
 pub enum GetRelationalDatabaseSnapshotError {
@@ -13875,6 +15359,21 @@ impl fmt::Display for GetRelationalDatabaseSnapshotError {
     }
 }
 impl Error for GetRelationalDatabaseSnapshotError {}
+impl ServiceErrorCode for GetRelationalDatabaseSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRelationalDatabaseSnapshotError::AccessDenied(_) => "AccessDeniedException",
+            GetRelationalDatabaseSnapshotError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetRelationalDatabaseSnapshotError::InvalidInput(_) => "InvalidInputException",
+            GetRelationalDatabaseSnapshotError::NotFound(_) => "NotFoundException",
+            GetRelationalDatabaseSnapshotError::OperationFailure(_) => "OperationFailureException",
+            GetRelationalDatabaseSnapshotError::Service(_) => "ServiceException",
+            GetRelationalDatabaseSnapshotError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetRelationalDatabaseSnapshots
 #[derive(Debug, PartialEq)]
 pub enum GetRelationalDatabaseSnapshotsError {
@@ -13963,6 +15462,21 @@ impl fmt::Display for GetRelationalDatabaseSnapshotsError {
     }
 }
 impl Error for GetRelationalDatabaseSnapshotsError {}
+impl ServiceErrorCode for GetRelationalDatabaseSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRelationalDatabaseSnapshotsError::AccessDenied(_) => "AccessDeniedException",
+            GetRelationalDatabaseSnapshotsError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetRelationalDatabaseSnapshotsError::InvalidInput(_) => "InvalidInputException",
+            GetRelationalDatabaseSnapshotsError::NotFound(_) => "NotFoundException",
+            GetRelationalDatabaseSnapshotsError::OperationFailure(_) => "OperationFailureException",
+            GetRelationalDatabaseSnapshotsError::Service(_) => "ServiceException",
+            GetRelationalDatabaseSnapshotsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetRelationalDatabases
 #[derive(Debug, PartialEq)]
 pub enum GetRelationalDatabasesError {
@@ -14037,6 +15551,21 @@ impl fmt::Display for GetRelationalDatabasesError {
     }
 }
 impl Error for GetRelationalDatabasesError {}
+impl ServiceErrorCode for GetRelationalDatabasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRelationalDatabasesError::AccessDenied(_) => "AccessDeniedException",
+            GetRelationalDatabasesError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            GetRelationalDatabasesError::InvalidInput(_) => "InvalidInputException",
+            GetRelationalDatabasesError::NotFound(_) => "NotFoundException",
+            GetRelationalDatabasesError::OperationFailure(_) => "OperationFailureException",
+            GetRelationalDatabasesError::Service(_) => "ServiceException",
+            GetRelationalDatabasesError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetStaticIp
 #[derive(Debug, PartialEq)]
 pub enum GetStaticIpError {
@@ -14103,6 +15632,19 @@ impl fmt::Display for GetStaticIpError {
     }
 }
 impl Error for GetStaticIpError {}
+impl ServiceErrorCode for GetStaticIpError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetStaticIpError::AccessDenied(_) => "AccessDeniedException",
+            GetStaticIpError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetStaticIpError::InvalidInput(_) => "InvalidInputException",
+            GetStaticIpError::NotFound(_) => "NotFoundException",
+            GetStaticIpError::OperationFailure(_) => "OperationFailureException",
+            GetStaticIpError::Service(_) => "ServiceException",
+            GetStaticIpError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by GetStaticIps
 #[derive(Debug, PartialEq)]
 pub enum GetStaticIpsError {
@@ -14169,6 +15711,19 @@ impl fmt::Display for GetStaticIpsError {
     }
 }
 impl Error for GetStaticIpsError {}
+impl ServiceErrorCode for GetStaticIpsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetStaticIpsError::AccessDenied(_) => "AccessDeniedException",
+            GetStaticIpsError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            GetStaticIpsError::InvalidInput(_) => "InvalidInputException",
+            GetStaticIpsError::NotFound(_) => "NotFoundException",
+            GetStaticIpsError::OperationFailure(_) => "OperationFailureException",
+            GetStaticIpsError::Service(_) => "ServiceException",
+            GetStaticIpsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by ImportKeyPair
 #[derive(Debug, PartialEq)]
 pub enum ImportKeyPairError {
@@ -14237,6 +15792,19 @@ impl fmt::Display for ImportKeyPairError {
     }
 }
 impl Error for ImportKeyPairError {}
+impl ServiceErrorCode for ImportKeyPairError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportKeyPairError::AccessDenied(_) => "AccessDeniedException",
+            ImportKeyPairError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            ImportKeyPairError::InvalidInput(_) => "InvalidInputException",
+            ImportKeyPairError::NotFound(_) => "NotFoundException",
+            ImportKeyPairError::OperationFailure(_) => "OperationFailureException",
+            ImportKeyPairError::Service(_) => "ServiceException",
+            ImportKeyPairError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by IsVpcPeered
 #[derive(Debug, PartialEq)]
 pub enum IsVpcPeeredError {
@@ -14303,6 +15871,19 @@ impl fmt::Display for IsVpcPeeredError {
     }
 }
 impl Error for IsVpcPeeredError {}
+impl ServiceErrorCode for IsVpcPeeredError {
+    fn error_code(&self) -> &str {
+        match *self {
+            IsVpcPeeredError::AccessDenied(_) => "AccessDeniedException",
+            IsVpcPeeredError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            IsVpcPeeredError::InvalidInput(_) => "InvalidInputException",
+            IsVpcPeeredError::NotFound(_) => "NotFoundException",
+            IsVpcPeeredError::OperationFailure(_) => "OperationFailureException",
+            IsVpcPeeredError::Service(_) => "ServiceException",
+            IsVpcPeeredError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by OpenInstancePublicPorts
 #[derive(Debug, PartialEq)]
 pub enum OpenInstancePublicPortsError {
@@ -14381,6 +15962,21 @@ impl fmt::Display for OpenInstancePublicPortsError {
     }
 }
 impl Error for OpenInstancePublicPortsError {}
+impl ServiceErrorCode for OpenInstancePublicPortsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            OpenInstancePublicPortsError::AccessDenied(_) => "AccessDeniedException",
+            OpenInstancePublicPortsError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            OpenInstancePublicPortsError::InvalidInput(_) => "InvalidInputException",
+            OpenInstancePublicPortsError::NotFound(_) => "NotFoundException",
+            OpenInstancePublicPortsError::OperationFailure(_) => "OperationFailureException",
+            OpenInstancePublicPortsError::Service(_) => "ServiceException",
+            OpenInstancePublicPortsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by PeerVpc
 #[derive(Debug, PartialEq)]
 pub enum PeerVpcError {
@@ -14445,6 +16041,19 @@ impl fmt::Display for PeerVpcError {
     }
 }
 impl Error for PeerVpcError {}
+impl ServiceErrorCode for PeerVpcError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PeerVpcError::AccessDenied(_) => "AccessDeniedException",
+            PeerVpcError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            PeerVpcError::InvalidInput(_) => "InvalidInputException",
+            PeerVpcError::NotFound(_) => "NotFoundException",
+            PeerVpcError::OperationFailure(_) => "OperationFailureException",
+            PeerVpcError::Service(_) => "ServiceException",
+            PeerVpcError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by PutAlarm
 #[derive(Debug, PartialEq)]
 pub enum PutAlarmError {
@@ -14503,6 +16112,18 @@ impl fmt::Display for PutAlarmError {
     }
 }
 impl Error for PutAlarmError {}
+impl ServiceErrorCode for PutAlarmError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAlarmError::AccessDenied(_) => "AccessDeniedException",
+            PutAlarmError::InvalidInput(_) => "InvalidInputException",
+            PutAlarmError::NotFound(_) => "NotFoundException",
+            PutAlarmError::OperationFailure(_) => "OperationFailureException",
+            PutAlarmError::Service(_) => "ServiceException",
+            PutAlarmError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by PutInstancePublicPorts
 #[derive(Debug, PartialEq)]
 pub enum PutInstancePublicPortsError {
@@ -14577,6 +16198,21 @@ impl fmt::Display for PutInstancePublicPortsError {
     }
 }
 impl Error for PutInstancePublicPortsError {}
+impl ServiceErrorCode for PutInstancePublicPortsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutInstancePublicPortsError::AccessDenied(_) => "AccessDeniedException",
+            PutInstancePublicPortsError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            PutInstancePublicPortsError::InvalidInput(_) => "InvalidInputException",
+            PutInstancePublicPortsError::NotFound(_) => "NotFoundException",
+            PutInstancePublicPortsError::OperationFailure(_) => "OperationFailureException",
+            PutInstancePublicPortsError::Service(_) => "ServiceException",
+            PutInstancePublicPortsError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by RebootInstance
 #[derive(Debug, PartialEq)]
 pub enum RebootInstanceError {
@@ -14645,6 +16281,19 @@ impl fmt::Display for RebootInstanceError {
     }
 }
 impl Error for RebootInstanceError {}
+impl ServiceErrorCode for RebootInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RebootInstanceError::AccessDenied(_) => "AccessDeniedException",
+            RebootInstanceError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            RebootInstanceError::InvalidInput(_) => "InvalidInputException",
+            RebootInstanceError::NotFound(_) => "NotFoundException",
+            RebootInstanceError::OperationFailure(_) => "OperationFailureException",
+            RebootInstanceError::Service(_) => "ServiceException",
+            RebootInstanceError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by RebootRelationalDatabase
 #[derive(Debug, PartialEq)]
 pub enum RebootRelationalDatabaseError {
@@ -14723,6 +16372,21 @@ impl fmt::Display for RebootRelationalDatabaseError {
     }
 }
 impl Error for RebootRelationalDatabaseError {}
+impl ServiceErrorCode for RebootRelationalDatabaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RebootRelationalDatabaseError::AccessDenied(_) => "AccessDeniedException",
+            RebootRelationalDatabaseError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            RebootRelationalDatabaseError::InvalidInput(_) => "InvalidInputException",
+            RebootRelationalDatabaseError::NotFound(_) => "NotFoundException",
+            RebootRelationalDatabaseError::OperationFailure(_) => "OperationFailureException",
+            RebootRelationalDatabaseError::Service(_) => "ServiceException",
+            RebootRelationalDatabaseError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by RegisterContainerImage
 #[derive(Debug, PartialEq)]
 pub enum RegisterContainerImageError {
@@ -14779,6 +16443,17 @@ impl fmt::Display for RegisterContainerImageError {
     }
 }
 impl Error for RegisterContainerImageError {}
+impl ServiceErrorCode for RegisterContainerImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterContainerImageError::AccessDenied(_) => "AccessDeniedException",
+            RegisterContainerImageError::InvalidInput(_) => "InvalidInputException",
+            RegisterContainerImageError::NotFound(_) => "NotFoundException",
+            RegisterContainerImageError::Service(_) => "ServiceException",
+            RegisterContainerImageError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by ReleaseStaticIp
 #[derive(Debug, PartialEq)]
 pub enum ReleaseStaticIpError {
@@ -14847,6 +16522,19 @@ impl fmt::Display for ReleaseStaticIpError {
     }
 }
 impl Error for ReleaseStaticIpError {}
+impl ServiceErrorCode for ReleaseStaticIpError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ReleaseStaticIpError::AccessDenied(_) => "AccessDeniedException",
+            ReleaseStaticIpError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            ReleaseStaticIpError::InvalidInput(_) => "InvalidInputException",
+            ReleaseStaticIpError::NotFound(_) => "NotFoundException",
+            ReleaseStaticIpError::OperationFailure(_) => "OperationFailureException",
+            ReleaseStaticIpError::Service(_) => "ServiceException",
+            ReleaseStaticIpError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by ResetDistributionCache
 #[derive(Debug, PartialEq)]
 pub enum ResetDistributionCacheError {
@@ -14911,6 +16599,18 @@ impl fmt::Display for ResetDistributionCacheError {
     }
 }
 impl Error for ResetDistributionCacheError {}
+impl ServiceErrorCode for ResetDistributionCacheError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetDistributionCacheError::AccessDenied(_) => "AccessDeniedException",
+            ResetDistributionCacheError::InvalidInput(_) => "InvalidInputException",
+            ResetDistributionCacheError::NotFound(_) => "NotFoundException",
+            ResetDistributionCacheError::OperationFailure(_) => "OperationFailureException",
+            ResetDistributionCacheError::Service(_) => "ServiceException",
+            ResetDistributionCacheError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by SendContactMethodVerification
 #[derive(Debug, PartialEq)]
 pub enum SendContactMethodVerificationError {
@@ -14989,6 +16689,18 @@ impl fmt::Display for SendContactMethodVerificationError {
     }
 }
 impl Error for SendContactMethodVerificationError {}
+impl ServiceErrorCode for SendContactMethodVerificationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendContactMethodVerificationError::AccessDenied(_) => "AccessDeniedException",
+            SendContactMethodVerificationError::InvalidInput(_) => "InvalidInputException",
+            SendContactMethodVerificationError::NotFound(_) => "NotFoundException",
+            SendContactMethodVerificationError::OperationFailure(_) => "OperationFailureException",
+            SendContactMethodVerificationError::Service(_) => "ServiceException",
+            SendContactMethodVerificationError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by StartInstance
 #[derive(Debug, PartialEq)]
 pub enum StartInstanceError {
@@ -15057,6 +16769,19 @@ impl fmt::Display for StartInstanceError {
     }
 }
 impl Error for StartInstanceError {}
+impl ServiceErrorCode for StartInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartInstanceError::AccessDenied(_) => "AccessDeniedException",
+            StartInstanceError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            StartInstanceError::InvalidInput(_) => "InvalidInputException",
+            StartInstanceError::NotFound(_) => "NotFoundException",
+            StartInstanceError::OperationFailure(_) => "OperationFailureException",
+            StartInstanceError::Service(_) => "ServiceException",
+            StartInstanceError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by StartRelationalDatabase
 #[derive(Debug, PartialEq)]
 pub enum StartRelationalDatabaseError {
@@ -15135,6 +16860,21 @@ impl fmt::Display for StartRelationalDatabaseError {
     }
 }
 impl Error for StartRelationalDatabaseError {}
+impl ServiceErrorCode for StartRelationalDatabaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartRelationalDatabaseError::AccessDenied(_) => "AccessDeniedException",
+            StartRelationalDatabaseError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            StartRelationalDatabaseError::InvalidInput(_) => "InvalidInputException",
+            StartRelationalDatabaseError::NotFound(_) => "NotFoundException",
+            StartRelationalDatabaseError::OperationFailure(_) => "OperationFailureException",
+            StartRelationalDatabaseError::Service(_) => "ServiceException",
+            StartRelationalDatabaseError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by StopInstance
 #[derive(Debug, PartialEq)]
 pub enum StopInstanceError {
@@ -15201,6 +16941,19 @@ impl fmt::Display for StopInstanceError {
     }
 }
 impl Error for StopInstanceError {}
+impl ServiceErrorCode for StopInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopInstanceError::AccessDenied(_) => "AccessDeniedException",
+            StopInstanceError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            StopInstanceError::InvalidInput(_) => "InvalidInputException",
+            StopInstanceError::NotFound(_) => "NotFoundException",
+            StopInstanceError::OperationFailure(_) => "OperationFailureException",
+            StopInstanceError::Service(_) => "ServiceException",
+            StopInstanceError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by StopRelationalDatabase
 #[derive(Debug, PartialEq)]
 pub enum StopRelationalDatabaseError {
@@ -15275,6 +17028,21 @@ impl fmt::Display for StopRelationalDatabaseError {
     }
 }
 impl Error for StopRelationalDatabaseError {}
+impl ServiceErrorCode for StopRelationalDatabaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopRelationalDatabaseError::AccessDenied(_) => "AccessDeniedException",
+            StopRelationalDatabaseError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            StopRelationalDatabaseError::InvalidInput(_) => "InvalidInputException",
+            StopRelationalDatabaseError::NotFound(_) => "NotFoundException",
+            StopRelationalDatabaseError::OperationFailure(_) => "OperationFailureException",
+            StopRelationalDatabaseError::Service(_) => "ServiceException",
+            StopRelationalDatabaseError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -15341,6 +17109,19 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AccessDenied(_) => "AccessDeniedException",
+            TagResourceError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            TagResourceError::InvalidInput(_) => "InvalidInputException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+            TagResourceError::OperationFailure(_) => "OperationFailureException",
+            TagResourceError::Service(_) => "ServiceException",
+            TagResourceError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by TestAlarm
 #[derive(Debug, PartialEq)]
 pub enum TestAlarmError {
@@ -15401,6 +17182,18 @@ impl fmt::Display for TestAlarmError {
     }
 }
 impl Error for TestAlarmError {}
+impl ServiceErrorCode for TestAlarmError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TestAlarmError::AccessDenied(_) => "AccessDeniedException",
+            TestAlarmError::InvalidInput(_) => "InvalidInputException",
+            TestAlarmError::NotFound(_) => "NotFoundException",
+            TestAlarmError::OperationFailure(_) => "OperationFailureException",
+            TestAlarmError::Service(_) => "ServiceException",
+            TestAlarmError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by UnpeerVpc
 #[derive(Debug, PartialEq)]
 pub enum UnpeerVpcError {
@@ -15467,6 +17260,19 @@ impl fmt::Display for UnpeerVpcError {
     }
 }
 impl Error for UnpeerVpcError {}
+impl ServiceErrorCode for UnpeerVpcError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UnpeerVpcError::AccessDenied(_) => "AccessDeniedException",
+            UnpeerVpcError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            UnpeerVpcError::InvalidInput(_) => "InvalidInputException",
+            UnpeerVpcError::NotFound(_) => "NotFoundException",
+            UnpeerVpcError::OperationFailure(_) => "OperationFailureException",
+            UnpeerVpcError::Service(_) => "ServiceException",
+            UnpeerVpcError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -15535,6 +17341,19 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AccessDenied(_) => "AccessDeniedException",
+            UntagResourceError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            UntagResourceError::InvalidInput(_) => "InvalidInputException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+            UntagResourceError::OperationFailure(_) => "OperationFailureException",
+            UntagResourceError::Service(_) => "ServiceException",
+            UntagResourceError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by UpdateContainerService
 #[derive(Debug, PartialEq)]
 pub enum UpdateContainerServiceError {
@@ -15591,6 +17410,17 @@ impl fmt::Display for UpdateContainerServiceError {
     }
 }
 impl Error for UpdateContainerServiceError {}
+impl ServiceErrorCode for UpdateContainerServiceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateContainerServiceError::AccessDenied(_) => "AccessDeniedException",
+            UpdateContainerServiceError::InvalidInput(_) => "InvalidInputException",
+            UpdateContainerServiceError::NotFound(_) => "NotFoundException",
+            UpdateContainerServiceError::Service(_) => "ServiceException",
+            UpdateContainerServiceError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by UpdateDistribution
 #[derive(Debug, PartialEq)]
 pub enum UpdateDistributionError {
@@ -15651,6 +17481,18 @@ impl fmt::Display for UpdateDistributionError {
     }
 }
 impl Error for UpdateDistributionError {}
+impl ServiceErrorCode for UpdateDistributionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDistributionError::AccessDenied(_) => "AccessDeniedException",
+            UpdateDistributionError::InvalidInput(_) => "InvalidInputException",
+            UpdateDistributionError::NotFound(_) => "NotFoundException",
+            UpdateDistributionError::OperationFailure(_) => "OperationFailureException",
+            UpdateDistributionError::Service(_) => "ServiceException",
+            UpdateDistributionError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by UpdateDistributionBundle
 #[derive(Debug, PartialEq)]
 pub enum UpdateDistributionBundleError {
@@ -15719,6 +17561,18 @@ impl fmt::Display for UpdateDistributionBundleError {
     }
 }
 impl Error for UpdateDistributionBundleError {}
+impl ServiceErrorCode for UpdateDistributionBundleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDistributionBundleError::AccessDenied(_) => "AccessDeniedException",
+            UpdateDistributionBundleError::InvalidInput(_) => "InvalidInputException",
+            UpdateDistributionBundleError::NotFound(_) => "NotFoundException",
+            UpdateDistributionBundleError::OperationFailure(_) => "OperationFailureException",
+            UpdateDistributionBundleError::Service(_) => "ServiceException",
+            UpdateDistributionBundleError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by UpdateDomainEntry
 #[derive(Debug, PartialEq)]
 pub enum UpdateDomainEntryError {
@@ -15787,6 +17641,19 @@ impl fmt::Display for UpdateDomainEntryError {
     }
 }
 impl Error for UpdateDomainEntryError {}
+impl ServiceErrorCode for UpdateDomainEntryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDomainEntryError::AccessDenied(_) => "AccessDeniedException",
+            UpdateDomainEntryError::AccountSetupInProgress(_) => "AccountSetupInProgressException",
+            UpdateDomainEntryError::InvalidInput(_) => "InvalidInputException",
+            UpdateDomainEntryError::NotFound(_) => "NotFoundException",
+            UpdateDomainEntryError::OperationFailure(_) => "OperationFailureException",
+            UpdateDomainEntryError::Service(_) => "ServiceException",
+            UpdateDomainEntryError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by UpdateLoadBalancerAttribute
 #[derive(Debug, PartialEq)]
 pub enum UpdateLoadBalancerAttributeError {
@@ -15869,6 +17736,21 @@ impl fmt::Display for UpdateLoadBalancerAttributeError {
     }
 }
 impl Error for UpdateLoadBalancerAttributeError {}
+impl ServiceErrorCode for UpdateLoadBalancerAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateLoadBalancerAttributeError::AccessDenied(_) => "AccessDeniedException",
+            UpdateLoadBalancerAttributeError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            UpdateLoadBalancerAttributeError::InvalidInput(_) => "InvalidInputException",
+            UpdateLoadBalancerAttributeError::NotFound(_) => "NotFoundException",
+            UpdateLoadBalancerAttributeError::OperationFailure(_) => "OperationFailureException",
+            UpdateLoadBalancerAttributeError::Service(_) => "ServiceException",
+            UpdateLoadBalancerAttributeError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by UpdateRelationalDatabase
 #[derive(Debug, PartialEq)]
 pub enum UpdateRelationalDatabaseError {
@@ -15947,6 +17829,21 @@ impl fmt::Display for UpdateRelationalDatabaseError {
     }
 }
 impl Error for UpdateRelationalDatabaseError {}
+impl ServiceErrorCode for UpdateRelationalDatabaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRelationalDatabaseError::AccessDenied(_) => "AccessDeniedException",
+            UpdateRelationalDatabaseError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            UpdateRelationalDatabaseError::InvalidInput(_) => "InvalidInputException",
+            UpdateRelationalDatabaseError::NotFound(_) => "NotFoundException",
+            UpdateRelationalDatabaseError::OperationFailure(_) => "OperationFailureException",
+            UpdateRelationalDatabaseError::Service(_) => "ServiceException",
+            UpdateRelationalDatabaseError::Unauthenticated(_) => "UnauthenticatedException",
+        }
+    }
+}
 /// Errors returned by UpdateRelationalDatabaseParameters
 #[derive(Debug, PartialEq)]
 pub enum UpdateRelationalDatabaseParametersError {
@@ -16039,6 +17936,25 @@ impl fmt::Display for UpdateRelationalDatabaseParametersError {
     }
 }
 impl Error for UpdateRelationalDatabaseParametersError {}
+impl ServiceErrorCode for UpdateRelationalDatabaseParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRelationalDatabaseParametersError::AccessDenied(_) => "AccessDeniedException",
+            UpdateRelationalDatabaseParametersError::AccountSetupInProgress(_) => {
+                "AccountSetupInProgressException"
+            }
+            UpdateRelationalDatabaseParametersError::InvalidInput(_) => "InvalidInputException",
+            UpdateRelationalDatabaseParametersError::NotFound(_) => "NotFoundException",
+            UpdateRelationalDatabaseParametersError::OperationFailure(_) => {
+                "OperationFailureException"
+            }
+            UpdateRelationalDatabaseParametersError::Service(_) => "ServiceException",
+            UpdateRelationalDatabaseParametersError::Unauthenticated(_) => {
+                "UnauthenticatedException"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Lightsail API. Amazon Lightsail clients implement this trait.
 #[async_trait]
 pub trait Lightsail {
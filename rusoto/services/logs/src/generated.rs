@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1484,6 +1484,16 @@ impl fmt::Display for AssociateKmsKeyError {
     }
 }
 impl Error for AssociateKmsKeyError {}
+impl ServiceErrorCode for AssociateKmsKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateKmsKeyError::InvalidParameter(_) => "InvalidParameterException",
+            AssociateKmsKeyError::OperationAborted(_) => "OperationAbortedException",
+            AssociateKmsKeyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateKmsKeyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CancelExportTask
 #[derive(Debug, PartialEq)]
 pub enum CancelExportTaskError {
@@ -1532,6 +1542,16 @@ impl fmt::Display for CancelExportTaskError {
     }
 }
 impl Error for CancelExportTaskError {}
+impl ServiceErrorCode for CancelExportTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelExportTaskError::InvalidOperation(_) => "InvalidOperationException",
+            CancelExportTaskError::InvalidParameter(_) => "InvalidParameterException",
+            CancelExportTaskError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelExportTaskError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CreateExportTask
 #[derive(Debug, PartialEq)]
 pub enum CreateExportTaskError {
@@ -1594,6 +1614,18 @@ impl fmt::Display for CreateExportTaskError {
     }
 }
 impl Error for CreateExportTaskError {}
+impl ServiceErrorCode for CreateExportTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateExportTaskError::InvalidParameter(_) => "InvalidParameterException",
+            CreateExportTaskError::LimitExceeded(_) => "LimitExceededException",
+            CreateExportTaskError::OperationAborted(_) => "OperationAbortedException",
+            CreateExportTaskError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateExportTaskError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateExportTaskError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CreateLogGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateLogGroupError {
@@ -1650,6 +1682,17 @@ impl fmt::Display for CreateLogGroupError {
     }
 }
 impl Error for CreateLogGroupError {}
+impl ServiceErrorCode for CreateLogGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLogGroupError::InvalidParameter(_) => "InvalidParameterException",
+            CreateLogGroupError::LimitExceeded(_) => "LimitExceededException",
+            CreateLogGroupError::OperationAborted(_) => "OperationAbortedException",
+            CreateLogGroupError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateLogGroupError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CreateLogStream
 #[derive(Debug, PartialEq)]
 pub enum CreateLogStreamError {
@@ -1700,6 +1743,16 @@ impl fmt::Display for CreateLogStreamError {
     }
 }
 impl Error for CreateLogStreamError {}
+impl ServiceErrorCode for CreateLogStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLogStreamError::InvalidParameter(_) => "InvalidParameterException",
+            CreateLogStreamError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateLogStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateLogStreamError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteDestination
 #[derive(Debug, PartialEq)]
 pub enum DeleteDestinationError {
@@ -1750,6 +1803,16 @@ impl fmt::Display for DeleteDestinationError {
     }
 }
 impl Error for DeleteDestinationError {}
+impl ServiceErrorCode for DeleteDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDestinationError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteDestinationError::OperationAborted(_) => "OperationAbortedException",
+            DeleteDestinationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDestinationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteLogGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteLogGroupError {
@@ -1798,6 +1861,16 @@ impl fmt::Display for DeleteLogGroupError {
     }
 }
 impl Error for DeleteLogGroupError {}
+impl ServiceErrorCode for DeleteLogGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLogGroupError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteLogGroupError::OperationAborted(_) => "OperationAbortedException",
+            DeleteLogGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteLogGroupError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteLogStream
 #[derive(Debug, PartialEq)]
 pub enum DeleteLogStreamError {
@@ -1846,6 +1919,16 @@ impl fmt::Display for DeleteLogStreamError {
     }
 }
 impl Error for DeleteLogStreamError {}
+impl ServiceErrorCode for DeleteLogStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLogStreamError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteLogStreamError::OperationAborted(_) => "OperationAbortedException",
+            DeleteLogStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteLogStreamError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteMetricFilter
 #[derive(Debug, PartialEq)]
 pub enum DeleteMetricFilterError {
@@ -1896,6 +1979,16 @@ impl fmt::Display for DeleteMetricFilterError {
     }
 }
 impl Error for DeleteMetricFilterError {}
+impl ServiceErrorCode for DeleteMetricFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMetricFilterError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteMetricFilterError::OperationAborted(_) => "OperationAbortedException",
+            DeleteMetricFilterError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteMetricFilterError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteQueryDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteQueryDefinitionError {
@@ -1944,6 +2037,15 @@ impl fmt::Display for DeleteQueryDefinitionError {
     }
 }
 impl Error for DeleteQueryDefinitionError {}
+impl ServiceErrorCode for DeleteQueryDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteQueryDefinitionError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteQueryDefinitionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteQueryDefinitionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteResourcePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteResourcePolicyError {
@@ -1992,6 +2094,15 @@ impl fmt::Display for DeleteResourcePolicyError {
     }
 }
 impl Error for DeleteResourcePolicyError {}
+impl ServiceErrorCode for DeleteResourcePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResourcePolicyError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteResourcePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteResourcePolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteRetentionPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteRetentionPolicyError {
@@ -2048,6 +2159,16 @@ impl fmt::Display for DeleteRetentionPolicyError {
     }
 }
 impl Error for DeleteRetentionPolicyError {}
+impl ServiceErrorCode for DeleteRetentionPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRetentionPolicyError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteRetentionPolicyError::OperationAborted(_) => "OperationAbortedException",
+            DeleteRetentionPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteRetentionPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteSubscriptionFilter
 #[derive(Debug, PartialEq)]
 pub enum DeleteSubscriptionFilterError {
@@ -2104,6 +2225,16 @@ impl fmt::Display for DeleteSubscriptionFilterError {
     }
 }
 impl Error for DeleteSubscriptionFilterError {}
+impl ServiceErrorCode for DeleteSubscriptionFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSubscriptionFilterError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteSubscriptionFilterError::OperationAborted(_) => "OperationAbortedException",
+            DeleteSubscriptionFilterError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteSubscriptionFilterError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeDestinations
 #[derive(Debug, PartialEq)]
 pub enum DescribeDestinationsError {
@@ -2144,6 +2275,14 @@ impl fmt::Display for DescribeDestinationsError {
     }
 }
 impl Error for DescribeDestinationsError {}
+impl ServiceErrorCode for DescribeDestinationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDestinationsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeDestinationsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeExportTasks
 #[derive(Debug, PartialEq)]
 pub enum DescribeExportTasksError {
@@ -2184,6 +2323,14 @@ impl fmt::Display for DescribeExportTasksError {
     }
 }
 impl Error for DescribeExportTasksError {}
+impl ServiceErrorCode for DescribeExportTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeExportTasksError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeExportTasksError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeLogGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeLogGroupsError {
@@ -2222,6 +2369,14 @@ impl fmt::Display for DescribeLogGroupsError {
     }
 }
 impl Error for DescribeLogGroupsError {}
+impl ServiceErrorCode for DescribeLogGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLogGroupsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeLogGroupsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeLogStreams
 #[derive(Debug, PartialEq)]
 pub enum DescribeLogStreamsError {
@@ -2266,6 +2421,15 @@ impl fmt::Display for DescribeLogStreamsError {
     }
 }
 impl Error for DescribeLogStreamsError {}
+impl ServiceErrorCode for DescribeLogStreamsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLogStreamsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeLogStreamsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeLogStreamsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeMetricFilters
 #[derive(Debug, PartialEq)]
 pub enum DescribeMetricFiltersError {
@@ -2314,6 +2478,15 @@ impl fmt::Display for DescribeMetricFiltersError {
     }
 }
 impl Error for DescribeMetricFiltersError {}
+impl ServiceErrorCode for DescribeMetricFiltersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMetricFiltersError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeMetricFiltersError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeMetricFiltersError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeQueries
 #[derive(Debug, PartialEq)]
 pub enum DescribeQueriesError {
@@ -2356,6 +2529,15 @@ impl fmt::Display for DescribeQueriesError {
     }
 }
 impl Error for DescribeQueriesError {}
+impl ServiceErrorCode for DescribeQueriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeQueriesError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeQueriesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeQueriesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeQueryDefinitions
 #[derive(Debug, PartialEq)]
 pub enum DescribeQueryDefinitionsError {
@@ -2396,6 +2578,14 @@ impl fmt::Display for DescribeQueryDefinitionsError {
     }
 }
 impl Error for DescribeQueryDefinitionsError {}
+impl ServiceErrorCode for DescribeQueryDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeQueryDefinitionsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeQueryDefinitionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeResourcePolicies
 #[derive(Debug, PartialEq)]
 pub enum DescribeResourcePoliciesError {
@@ -2436,6 +2626,14 @@ impl fmt::Display for DescribeResourcePoliciesError {
     }
 }
 impl Error for DescribeResourcePoliciesError {}
+impl ServiceErrorCode for DescribeResourcePoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeResourcePoliciesError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeResourcePoliciesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeSubscriptionFilters
 #[derive(Debug, PartialEq)]
 pub enum DescribeSubscriptionFiltersError {
@@ -2488,6 +2686,17 @@ impl fmt::Display for DescribeSubscriptionFiltersError {
     }
 }
 impl Error for DescribeSubscriptionFiltersError {}
+impl ServiceErrorCode for DescribeSubscriptionFiltersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSubscriptionFiltersError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeSubscriptionFiltersError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeSubscriptionFiltersError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateKmsKey
 #[derive(Debug, PartialEq)]
 pub enum DisassociateKmsKeyError {
@@ -2538,6 +2747,16 @@ impl fmt::Display for DisassociateKmsKeyError {
     }
 }
 impl Error for DisassociateKmsKeyError {}
+impl ServiceErrorCode for DisassociateKmsKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateKmsKeyError::InvalidParameter(_) => "InvalidParameterException",
+            DisassociateKmsKeyError::OperationAborted(_) => "OperationAbortedException",
+            DisassociateKmsKeyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DisassociateKmsKeyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by FilterLogEvents
 #[derive(Debug, PartialEq)]
 pub enum FilterLogEventsError {
@@ -2580,6 +2799,15 @@ impl fmt::Display for FilterLogEventsError {
     }
 }
 impl Error for FilterLogEventsError {}
+impl ServiceErrorCode for FilterLogEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            FilterLogEventsError::InvalidParameter(_) => "InvalidParameterException",
+            FilterLogEventsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            FilterLogEventsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetLogEvents
 #[derive(Debug, PartialEq)]
 pub enum GetLogEventsError {
@@ -2622,6 +2850,15 @@ impl fmt::Display for GetLogEventsError {
     }
 }
 impl Error for GetLogEventsError {}
+impl ServiceErrorCode for GetLogEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLogEventsError::InvalidParameter(_) => "InvalidParameterException",
+            GetLogEventsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetLogEventsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetLogGroupFields
 #[derive(Debug, PartialEq)]
 pub enum GetLogGroupFieldsError {
@@ -2672,6 +2909,16 @@ impl fmt::Display for GetLogGroupFieldsError {
     }
 }
 impl Error for GetLogGroupFieldsError {}
+impl ServiceErrorCode for GetLogGroupFieldsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLogGroupFieldsError::InvalidParameter(_) => "InvalidParameterException",
+            GetLogGroupFieldsError::LimitExceeded(_) => "LimitExceededException",
+            GetLogGroupFieldsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetLogGroupFieldsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetLogRecord
 #[derive(Debug, PartialEq)]
 pub enum GetLogRecordError {
@@ -2720,6 +2967,16 @@ impl fmt::Display for GetLogRecordError {
     }
 }
 impl Error for GetLogRecordError {}
+impl ServiceErrorCode for GetLogRecordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLogRecordError::InvalidParameter(_) => "InvalidParameterException",
+            GetLogRecordError::LimitExceeded(_) => "LimitExceededException",
+            GetLogRecordError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetLogRecordError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetQueryResults
 #[derive(Debug, PartialEq)]
 pub enum GetQueryResultsError {
@@ -2762,6 +3019,15 @@ impl fmt::Display for GetQueryResultsError {
     }
 }
 impl Error for GetQueryResultsError {}
+impl ServiceErrorCode for GetQueryResultsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetQueryResultsError::InvalidParameter(_) => "InvalidParameterException",
+            GetQueryResultsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetQueryResultsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListTagsLogGroup
 #[derive(Debug, PartialEq)]
 pub enum ListTagsLogGroupError {
@@ -2798,6 +3064,14 @@ impl fmt::Display for ListTagsLogGroupError {
     }
 }
 impl Error for ListTagsLogGroupError {}
+impl ServiceErrorCode for ListTagsLogGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsLogGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsLogGroupError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by PutDestination
 #[derive(Debug, PartialEq)]
 pub enum PutDestinationError {
@@ -2840,6 +3114,15 @@ impl fmt::Display for PutDestinationError {
     }
 }
 impl Error for PutDestinationError {}
+impl ServiceErrorCode for PutDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutDestinationError::InvalidParameter(_) => "InvalidParameterException",
+            PutDestinationError::OperationAborted(_) => "OperationAbortedException",
+            PutDestinationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by PutDestinationPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutDestinationPolicyError {
@@ -2888,6 +3171,15 @@ impl fmt::Display for PutDestinationPolicyError {
     }
 }
 impl Error for PutDestinationPolicyError {}
+impl ServiceErrorCode for PutDestinationPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutDestinationPolicyError::InvalidParameter(_) => "InvalidParameterException",
+            PutDestinationPolicyError::OperationAborted(_) => "OperationAbortedException",
+            PutDestinationPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by PutLogEvents
 #[derive(Debug, PartialEq)]
 pub enum PutLogEventsError {
@@ -2948,6 +3240,18 @@ impl fmt::Display for PutLogEventsError {
     }
 }
 impl Error for PutLogEventsError {}
+impl ServiceErrorCode for PutLogEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutLogEventsError::DataAlreadyAccepted(_) => "DataAlreadyAcceptedException",
+            PutLogEventsError::InvalidParameter(_) => "InvalidParameterException",
+            PutLogEventsError::InvalidSequenceToken(_) => "InvalidSequenceTokenException",
+            PutLogEventsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutLogEventsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            PutLogEventsError::UnrecognizedClient(_) => "UnrecognizedClientException",
+        }
+    }
+}
 /// Errors returned by PutMetricFilter
 #[derive(Debug, PartialEq)]
 pub enum PutMetricFilterError {
@@ -3002,6 +3306,17 @@ impl fmt::Display for PutMetricFilterError {
     }
 }
 impl Error for PutMetricFilterError {}
+impl ServiceErrorCode for PutMetricFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutMetricFilterError::InvalidParameter(_) => "InvalidParameterException",
+            PutMetricFilterError::LimitExceeded(_) => "LimitExceededException",
+            PutMetricFilterError::OperationAborted(_) => "OperationAbortedException",
+            PutMetricFilterError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutMetricFilterError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by PutQueryDefinition
 #[derive(Debug, PartialEq)]
 pub enum PutQueryDefinitionError {
@@ -3046,6 +3361,15 @@ impl fmt::Display for PutQueryDefinitionError {
     }
 }
 impl Error for PutQueryDefinitionError {}
+impl ServiceErrorCode for PutQueryDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutQueryDefinitionError::InvalidParameter(_) => "InvalidParameterException",
+            PutQueryDefinitionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutQueryDefinitionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by PutResourcePolicy
 #[derive(Debug, PartialEq)]
 pub enum PutResourcePolicyError {
@@ -3090,6 +3414,15 @@ impl fmt::Display for PutResourcePolicyError {
     }
 }
 impl Error for PutResourcePolicyError {}
+impl ServiceErrorCode for PutResourcePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutResourcePolicyError::InvalidParameter(_) => "InvalidParameterException",
+            PutResourcePolicyError::LimitExceeded(_) => "LimitExceededException",
+            PutResourcePolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by PutRetentionPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutRetentionPolicyError {
@@ -3140,6 +3473,16 @@ impl fmt::Display for PutRetentionPolicyError {
     }
 }
 impl Error for PutRetentionPolicyError {}
+impl ServiceErrorCode for PutRetentionPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRetentionPolicyError::InvalidParameter(_) => "InvalidParameterException",
+            PutRetentionPolicyError::OperationAborted(_) => "OperationAbortedException",
+            PutRetentionPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutRetentionPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by PutSubscriptionFilter
 #[derive(Debug, PartialEq)]
 pub enum PutSubscriptionFilterError {
@@ -3202,6 +3545,17 @@ impl fmt::Display for PutSubscriptionFilterError {
     }
 }
 impl Error for PutSubscriptionFilterError {}
+impl ServiceErrorCode for PutSubscriptionFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutSubscriptionFilterError::InvalidParameter(_) => "InvalidParameterException",
+            PutSubscriptionFilterError::LimitExceeded(_) => "LimitExceededException",
+            PutSubscriptionFilterError::OperationAborted(_) => "OperationAbortedException",
+            PutSubscriptionFilterError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutSubscriptionFilterError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by StartQuery
 #[derive(Debug, PartialEq)]
 pub enum StartQueryError {
@@ -3256,6 +3610,17 @@ impl fmt::Display for StartQueryError {
     }
 }
 impl Error for StartQueryError {}
+impl ServiceErrorCode for StartQueryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartQueryError::InvalidParameter(_) => "InvalidParameterException",
+            StartQueryError::LimitExceeded(_) => "LimitExceededException",
+            StartQueryError::MalformedQuery(_) => "MalformedQueryException",
+            StartQueryError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartQueryError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by StopQuery
 #[derive(Debug, PartialEq)]
 pub enum StopQueryError {
@@ -3298,6 +3663,15 @@ impl fmt::Display for StopQueryError {
     }
 }
 impl Error for StopQueryError {}
+impl ServiceErrorCode for StopQueryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopQueryError::InvalidParameter(_) => "InvalidParameterException",
+            StopQueryError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StopQueryError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by TagLogGroup
 #[derive(Debug, PartialEq)]
 pub enum TagLogGroupError {
@@ -3334,6 +3708,14 @@ impl fmt::Display for TagLogGroupError {
     }
 }
 impl Error for TagLogGroupError {}
+impl ServiceErrorCode for TagLogGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagLogGroupError::InvalidParameter(_) => "InvalidParameterException",
+            TagLogGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TestMetricFilter
 #[derive(Debug, PartialEq)]
 pub enum TestMetricFilterError {
@@ -3370,6 +3752,14 @@ impl fmt::Display for TestMetricFilterError {
     }
 }
 impl Error for TestMetricFilterError {}
+impl ServiceErrorCode for TestMetricFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TestMetricFilterError::InvalidParameter(_) => "InvalidParameterException",
+            TestMetricFilterError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by UntagLogGroup
 #[derive(Debug, PartialEq)]
 pub enum UntagLogGroupError {
@@ -3400,6 +3790,13 @@ impl fmt::Display for UntagLogGroupError {
     }
 }
 impl Error for UntagLogGroupError {}
+impl ServiceErrorCode for UntagLogGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagLogGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon CloudWatch Logs API. Amazon CloudWatch Logs clients implement this trait.
 #[async_trait]
 pub trait CloudWatchLogs {
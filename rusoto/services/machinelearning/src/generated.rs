@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1725,6 +1725,17 @@ impl fmt::Display for AddTagsError {
     }
 }
 impl Error for AddTagsError {}
+impl ServiceErrorCode for AddTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsError::InternalServer(_) => "InternalServerException",
+            AddTagsError::InvalidInput(_) => "InvalidInputException",
+            AddTagsError::InvalidTag(_) => "InvalidTagException",
+            AddTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AddTagsError::TagLimitExceeded(_) => "TagLimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateBatchPrediction
 #[derive(Debug, PartialEq)]
 pub enum CreateBatchPredictionError {
@@ -1773,6 +1784,17 @@ impl fmt::Display for CreateBatchPredictionError {
     }
 }
 impl Error for CreateBatchPredictionError {}
+impl ServiceErrorCode for CreateBatchPredictionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBatchPredictionError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateBatchPredictionError::InternalServer(_) => "InternalServerException",
+            CreateBatchPredictionError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by CreateDataSourceFromRDS
 #[derive(Debug, PartialEq)]
 pub enum CreateDataSourceFromRDSError {
@@ -1823,6 +1845,17 @@ impl fmt::Display for CreateDataSourceFromRDSError {
     }
 }
 impl Error for CreateDataSourceFromRDSError {}
+impl ServiceErrorCode for CreateDataSourceFromRDSError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDataSourceFromRDSError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateDataSourceFromRDSError::InternalServer(_) => "InternalServerException",
+            CreateDataSourceFromRDSError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by CreateDataSourceFromRedshift
 #[derive(Debug, PartialEq)]
 pub enum CreateDataSourceFromRedshiftError {
@@ -1875,6 +1908,17 @@ impl fmt::Display for CreateDataSourceFromRedshiftError {
     }
 }
 impl Error for CreateDataSourceFromRedshiftError {}
+impl ServiceErrorCode for CreateDataSourceFromRedshiftError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDataSourceFromRedshiftError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateDataSourceFromRedshiftError::InternalServer(_) => "InternalServerException",
+            CreateDataSourceFromRedshiftError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by CreateDataSourceFromS3
 #[derive(Debug, PartialEq)]
 pub enum CreateDataSourceFromS3Error {
@@ -1923,6 +1967,17 @@ impl fmt::Display for CreateDataSourceFromS3Error {
     }
 }
 impl Error for CreateDataSourceFromS3Error {}
+impl ServiceErrorCode for CreateDataSourceFromS3Error {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDataSourceFromS3Error::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateDataSourceFromS3Error::InternalServer(_) => "InternalServerException",
+            CreateDataSourceFromS3Error::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by CreateEvaluation
 #[derive(Debug, PartialEq)]
 pub enum CreateEvaluationError {
@@ -1967,6 +2022,17 @@ impl fmt::Display for CreateEvaluationError {
     }
 }
 impl Error for CreateEvaluationError {}
+impl ServiceErrorCode for CreateEvaluationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEvaluationError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateEvaluationError::InternalServer(_) => "InternalServerException",
+            CreateEvaluationError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by CreateMLModel
 #[derive(Debug, PartialEq)]
 pub enum CreateMLModelError {
@@ -2011,6 +2077,17 @@ impl fmt::Display for CreateMLModelError {
     }
 }
 impl Error for CreateMLModelError {}
+impl ServiceErrorCode for CreateMLModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMLModelError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateMLModelError::InternalServer(_) => "InternalServerException",
+            CreateMLModelError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by CreateRealtimeEndpoint
 #[derive(Debug, PartialEq)]
 pub enum CreateRealtimeEndpointError {
@@ -2057,6 +2134,15 @@ impl fmt::Display for CreateRealtimeEndpointError {
     }
 }
 impl Error for CreateRealtimeEndpointError {}
+impl ServiceErrorCode for CreateRealtimeEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRealtimeEndpointError::InternalServer(_) => "InternalServerException",
+            CreateRealtimeEndpointError::InvalidInput(_) => "InvalidInputException",
+            CreateRealtimeEndpointError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteBatchPrediction
 #[derive(Debug, PartialEq)]
 pub enum DeleteBatchPredictionError {
@@ -2103,6 +2189,15 @@ impl fmt::Display for DeleteBatchPredictionError {
     }
 }
 impl Error for DeleteBatchPredictionError {}
+impl ServiceErrorCode for DeleteBatchPredictionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBatchPredictionError::InternalServer(_) => "InternalServerException",
+            DeleteBatchPredictionError::InvalidInput(_) => "InvalidInputException",
+            DeleteBatchPredictionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteDataSource
 #[derive(Debug, PartialEq)]
 pub enum DeleteDataSourceError {
@@ -2145,6 +2240,15 @@ impl fmt::Display for DeleteDataSourceError {
     }
 }
 impl Error for DeleteDataSourceError {}
+impl ServiceErrorCode for DeleteDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDataSourceError::InternalServer(_) => "InternalServerException",
+            DeleteDataSourceError::InvalidInput(_) => "InvalidInputException",
+            DeleteDataSourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteEvaluation
 #[derive(Debug, PartialEq)]
 pub enum DeleteEvaluationError {
@@ -2187,6 +2291,15 @@ impl fmt::Display for DeleteEvaluationError {
     }
 }
 impl Error for DeleteEvaluationError {}
+impl ServiceErrorCode for DeleteEvaluationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEvaluationError::InternalServer(_) => "InternalServerException",
+            DeleteEvaluationError::InvalidInput(_) => "InvalidInputException",
+            DeleteEvaluationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteMLModel
 #[derive(Debug, PartialEq)]
 pub enum DeleteMLModelError {
@@ -2229,6 +2342,15 @@ impl fmt::Display for DeleteMLModelError {
     }
 }
 impl Error for DeleteMLModelError {}
+impl ServiceErrorCode for DeleteMLModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMLModelError::InternalServer(_) => "InternalServerException",
+            DeleteMLModelError::InvalidInput(_) => "InvalidInputException",
+            DeleteMLModelError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteRealtimeEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DeleteRealtimeEndpointError {
@@ -2275,6 +2397,15 @@ impl fmt::Display for DeleteRealtimeEndpointError {
     }
 }
 impl Error for DeleteRealtimeEndpointError {}
+impl ServiceErrorCode for DeleteRealtimeEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRealtimeEndpointError::InternalServer(_) => "InternalServerException",
+            DeleteRealtimeEndpointError::InvalidInput(_) => "InvalidInputException",
+            DeleteRealtimeEndpointError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
 pub enum DeleteTagsError {
@@ -2323,6 +2454,16 @@ impl fmt::Display for DeleteTagsError {
     }
 }
 impl Error for DeleteTagsError {}
+impl ServiceErrorCode for DeleteTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTagsError::InternalServer(_) => "InternalServerException",
+            DeleteTagsError::InvalidInput(_) => "InvalidInputException",
+            DeleteTagsError::InvalidTag(_) => "InvalidTagException",
+            DeleteTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeBatchPredictions
 #[derive(Debug, PartialEq)]
 pub enum DescribeBatchPredictionsError {
@@ -2363,6 +2504,14 @@ impl fmt::Display for DescribeBatchPredictionsError {
     }
 }
 impl Error for DescribeBatchPredictionsError {}
+impl ServiceErrorCode for DescribeBatchPredictionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBatchPredictionsError::InternalServer(_) => "InternalServerException",
+            DescribeBatchPredictionsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DescribeDataSources
 #[derive(Debug, PartialEq)]
 pub enum DescribeDataSourcesError {
@@ -2399,6 +2548,14 @@ impl fmt::Display for DescribeDataSourcesError {
     }
 }
 impl Error for DescribeDataSourcesError {}
+impl ServiceErrorCode for DescribeDataSourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDataSourcesError::InternalServer(_) => "InternalServerException",
+            DescribeDataSourcesError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DescribeEvaluations
 #[derive(Debug, PartialEq)]
 pub enum DescribeEvaluationsError {
@@ -2435,6 +2592,14 @@ impl fmt::Display for DescribeEvaluationsError {
     }
 }
 impl Error for DescribeEvaluationsError {}
+impl ServiceErrorCode for DescribeEvaluationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEvaluationsError::InternalServer(_) => "InternalServerException",
+            DescribeEvaluationsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DescribeMLModels
 #[derive(Debug, PartialEq)]
 pub enum DescribeMLModelsError {
@@ -2471,6 +2636,14 @@ impl fmt::Display for DescribeMLModelsError {
     }
 }
 impl Error for DescribeMLModelsError {}
+impl ServiceErrorCode for DescribeMLModelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMLModelsError::InternalServer(_) => "InternalServerException",
+            DescribeMLModelsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
 pub enum DescribeTagsError {
@@ -2513,6 +2686,15 @@ impl fmt::Display for DescribeTagsError {
     }
 }
 impl Error for DescribeTagsError {}
+impl ServiceErrorCode for DescribeTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTagsError::InternalServer(_) => "InternalServerException",
+            DescribeTagsError::InvalidInput(_) => "InvalidInputException",
+            DescribeTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetBatchPrediction
 #[derive(Debug, PartialEq)]
 pub enum GetBatchPredictionError {
@@ -2555,6 +2737,15 @@ impl fmt::Display for GetBatchPredictionError {
     }
 }
 impl Error for GetBatchPredictionError {}
+impl ServiceErrorCode for GetBatchPredictionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBatchPredictionError::InternalServer(_) => "InternalServerException",
+            GetBatchPredictionError::InvalidInput(_) => "InvalidInputException",
+            GetBatchPredictionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetDataSource
 #[derive(Debug, PartialEq)]
 pub enum GetDataSourceError {
@@ -2597,6 +2788,15 @@ impl fmt::Display for GetDataSourceError {
     }
 }
 impl Error for GetDataSourceError {}
+impl ServiceErrorCode for GetDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDataSourceError::InternalServer(_) => "InternalServerException",
+            GetDataSourceError::InvalidInput(_) => "InvalidInputException",
+            GetDataSourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetEvaluation
 #[derive(Debug, PartialEq)]
 pub enum GetEvaluationError {
@@ -2639,6 +2839,15 @@ impl fmt::Display for GetEvaluationError {
     }
 }
 impl Error for GetEvaluationError {}
+impl ServiceErrorCode for GetEvaluationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEvaluationError::InternalServer(_) => "InternalServerException",
+            GetEvaluationError::InvalidInput(_) => "InvalidInputException",
+            GetEvaluationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetMLModel
 #[derive(Debug, PartialEq)]
 pub enum GetMLModelError {
@@ -2681,6 +2890,15 @@ impl fmt::Display for GetMLModelError {
     }
 }
 impl Error for GetMLModelError {}
+impl ServiceErrorCode for GetMLModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMLModelError::InternalServer(_) => "InternalServerException",
+            GetMLModelError::InvalidInput(_) => "InvalidInputException",
+            GetMLModelError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by Predict
 #[derive(Debug, PartialEq)]
 pub enum PredictError {
@@ -2735,6 +2953,17 @@ impl fmt::Display for PredictError {
     }
 }
 impl Error for PredictError {}
+impl ServiceErrorCode for PredictError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PredictError::InternalServer(_) => "InternalServerException",
+            PredictError::InvalidInput(_) => "InvalidInputException",
+            PredictError::LimitExceeded(_) => "LimitExceededException",
+            PredictError::PredictorNotMounted(_) => "PredictorNotMountedException",
+            PredictError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateBatchPrediction
 #[derive(Debug, PartialEq)]
 pub enum UpdateBatchPredictionError {
@@ -2781,6 +3010,15 @@ impl fmt::Display for UpdateBatchPredictionError {
     }
 }
 impl Error for UpdateBatchPredictionError {}
+impl ServiceErrorCode for UpdateBatchPredictionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateBatchPredictionError::InternalServer(_) => "InternalServerException",
+            UpdateBatchPredictionError::InvalidInput(_) => "InvalidInputException",
+            UpdateBatchPredictionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateDataSource
 #[derive(Debug, PartialEq)]
 pub enum UpdateDataSourceError {
@@ -2823,6 +3061,15 @@ impl fmt::Display for UpdateDataSourceError {
     }
 }
 impl Error for UpdateDataSourceError {}
+impl ServiceErrorCode for UpdateDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDataSourceError::InternalServer(_) => "InternalServerException",
+            UpdateDataSourceError::InvalidInput(_) => "InvalidInputException",
+            UpdateDataSourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateEvaluation
 #[derive(Debug, PartialEq)]
 pub enum UpdateEvaluationError {
@@ -2865,6 +3112,15 @@ impl fmt::Display for UpdateEvaluationError {
     }
 }
 impl Error for UpdateEvaluationError {}
+impl ServiceErrorCode for UpdateEvaluationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateEvaluationError::InternalServer(_) => "InternalServerException",
+            UpdateEvaluationError::InvalidInput(_) => "InvalidInputException",
+            UpdateEvaluationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateMLModel
 #[derive(Debug, PartialEq)]
 pub enum UpdateMLModelError {
@@ -2907,6 +3163,15 @@ impl fmt::Display for UpdateMLModelError {
     }
 }
 impl Error for UpdateMLModelError {}
+impl ServiceErrorCode for UpdateMLModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMLModelError::InternalServer(_) => "InternalServerException",
+            UpdateMLModelError::InvalidInput(_) => "InvalidInputException",
+            UpdateMLModelError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Machine Learning API. Amazon Machine Learning clients implement this trait.
 #[async_trait]
 pub trait MachineLearning {
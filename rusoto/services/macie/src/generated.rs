@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -325,6 +325,15 @@ impl fmt::Display for AssociateMemberAccountError {
     }
 }
 impl Error for AssociateMemberAccountError {}
+impl ServiceErrorCode for AssociateMemberAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateMemberAccountError::Internal(_) => "InternalException",
+            AssociateMemberAccountError::InvalidInput(_) => "InvalidInputException",
+            AssociateMemberAccountError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by AssociateS3Resources
 #[derive(Debug, PartialEq)]
 pub enum AssociateS3ResourcesError {
@@ -373,6 +382,16 @@ impl fmt::Display for AssociateS3ResourcesError {
     }
 }
 impl Error for AssociateS3ResourcesError {}
+impl ServiceErrorCode for AssociateS3ResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateS3ResourcesError::AccessDenied(_) => "AccessDeniedException",
+            AssociateS3ResourcesError::Internal(_) => "InternalException",
+            AssociateS3ResourcesError::InvalidInput(_) => "InvalidInputException",
+            AssociateS3ResourcesError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DisassociateMemberAccount
 #[derive(Debug, PartialEq)]
 pub enum DisassociateMemberAccountError {
@@ -411,6 +430,14 @@ impl fmt::Display for DisassociateMemberAccountError {
     }
 }
 impl Error for DisassociateMemberAccountError {}
+impl ServiceErrorCode for DisassociateMemberAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateMemberAccountError::Internal(_) => "InternalException",
+            DisassociateMemberAccountError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DisassociateS3Resources
 #[derive(Debug, PartialEq)]
 pub enum DisassociateS3ResourcesError {
@@ -457,6 +484,15 @@ impl fmt::Display for DisassociateS3ResourcesError {
     }
 }
 impl Error for DisassociateS3ResourcesError {}
+impl ServiceErrorCode for DisassociateS3ResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateS3ResourcesError::AccessDenied(_) => "AccessDeniedException",
+            DisassociateS3ResourcesError::Internal(_) => "InternalException",
+            DisassociateS3ResourcesError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ListMemberAccounts
 #[derive(Debug, PartialEq)]
 pub enum ListMemberAccountsError {
@@ -493,6 +529,14 @@ impl fmt::Display for ListMemberAccountsError {
     }
 }
 impl Error for ListMemberAccountsError {}
+impl ServiceErrorCode for ListMemberAccountsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMemberAccountsError::Internal(_) => "InternalException",
+            ListMemberAccountsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by ListS3Resources
 #[derive(Debug, PartialEq)]
 pub enum ListS3ResourcesError {
@@ -535,6 +579,15 @@ impl fmt::Display for ListS3ResourcesError {
     }
 }
 impl Error for ListS3ResourcesError {}
+impl ServiceErrorCode for ListS3ResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListS3ResourcesError::AccessDenied(_) => "AccessDeniedException",
+            ListS3ResourcesError::Internal(_) => "InternalException",
+            ListS3ResourcesError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by UpdateS3Resources
 #[derive(Debug, PartialEq)]
 pub enum UpdateS3ResourcesError {
@@ -577,6 +630,15 @@ impl fmt::Display for UpdateS3ResourcesError {
     }
 }
 impl Error for UpdateS3ResourcesError {}
+impl ServiceErrorCode for UpdateS3ResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateS3ResourcesError::AccessDenied(_) => "AccessDeniedException",
+            UpdateS3ResourcesError::Internal(_) => "InternalException",
+            UpdateS3ResourcesError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Macie API. Amazon Macie clients implement this trait.
 #[async_trait]
 pub trait Macie {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -46,6 +46,7 @@ pub struct ApprovalThresholdPolicy {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateMemberInput {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the operation. An idempotent operation completes no more than one time. This identifier is required only if you make a service request directly using an HTTP client. It is generated automatically if you use an AWS SDK or the AWS CLI.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>The unique identifier of the invitation that is sent to the member to join the network.</p>
@@ -72,6 +73,7 @@ pub struct CreateMemberOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateNetworkInput {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the operation. An idempotent operation completes no more than one time. This identifier is required only if you make a service request directly using an HTTP client. It is generated automatically if you use an AWS SDK or the AWS CLI.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>An optional description for the network.</p>
@@ -116,6 +118,7 @@ pub struct CreateNetworkOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateNodeInput {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the operation. An idempotent operation completes no more than one time. This identifier is required only if you make a service request directly using an HTTP client. It is generated automatically if you use an AWS SDK or the AWS CLI.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>The unique identifier of the member that owns this node.</p> <p>Applies only to Hyperledger Fabric.</p>
@@ -146,6 +149,7 @@ pub struct CreateProposalInput {
     #[serde(rename = "Actions")]
     pub actions: ProposalActions,
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the operation. An idempotent operation completes no more than one time. This identifier is required only if you make a service request directly using an HTTP client. It is generated automatically if you use an AWS SDK or the AWS CLI.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>A description for the proposal that is visible to voting members, for example, "Proposal to add Example Corp. as member."</p>
@@ -1267,6 +1271,20 @@ impl fmt::Display for CreateMemberError {
     }
 }
 impl Error for CreateMemberError {}
+impl ServiceErrorCode for CreateMemberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMemberError::AccessDenied(_) => "AccessDeniedException",
+            CreateMemberError::InternalServiceError(_) => "InternalServiceErrorException",
+            CreateMemberError::InvalidRequest(_) => "InvalidRequestException",
+            CreateMemberError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateMemberError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateMemberError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateMemberError::ResourceNotReady(_) => "ResourceNotReadyException",
+            CreateMemberError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateNetwork
 #[derive(Debug, PartialEq)]
 pub enum CreateNetworkError {
@@ -1327,6 +1345,18 @@ impl fmt::Display for CreateNetworkError {
     }
 }
 impl Error for CreateNetworkError {}
+impl ServiceErrorCode for CreateNetworkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateNetworkError::AccessDenied(_) => "AccessDeniedException",
+            CreateNetworkError::InternalServiceError(_) => "InternalServiceErrorException",
+            CreateNetworkError::InvalidRequest(_) => "InvalidRequestException",
+            CreateNetworkError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateNetworkError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateNetworkError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateNode
 #[derive(Debug, PartialEq)]
 pub enum CreateNodeError {
@@ -1399,6 +1429,20 @@ impl fmt::Display for CreateNodeError {
     }
 }
 impl Error for CreateNodeError {}
+impl ServiceErrorCode for CreateNodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateNodeError::AccessDenied(_) => "AccessDeniedException",
+            CreateNodeError::InternalServiceError(_) => "InternalServiceErrorException",
+            CreateNodeError::InvalidRequest(_) => "InvalidRequestException",
+            CreateNodeError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateNodeError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateNodeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateNodeError::ResourceNotReady(_) => "ResourceNotReadyException",
+            CreateNodeError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateProposal
 #[derive(Debug, PartialEq)]
 pub enum CreateProposalError {
@@ -1459,6 +1503,18 @@ impl fmt::Display for CreateProposalError {
     }
 }
 impl Error for CreateProposalError {}
+impl ServiceErrorCode for CreateProposalError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProposalError::AccessDenied(_) => "AccessDeniedException",
+            CreateProposalError::InternalServiceError(_) => "InternalServiceErrorException",
+            CreateProposalError::InvalidRequest(_) => "InvalidRequestException",
+            CreateProposalError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateProposalError::ResourceNotReady(_) => "ResourceNotReadyException",
+            CreateProposalError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteMember
 #[derive(Debug, PartialEq)]
 pub enum DeleteMemberError {
@@ -1519,6 +1575,18 @@ impl fmt::Display for DeleteMemberError {
     }
 }
 impl Error for DeleteMemberError {}
+impl ServiceErrorCode for DeleteMemberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMemberError::AccessDenied(_) => "AccessDeniedException",
+            DeleteMemberError::InternalServiceError(_) => "InternalServiceErrorException",
+            DeleteMemberError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteMemberError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteMemberError::ResourceNotReady(_) => "ResourceNotReadyException",
+            DeleteMemberError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteNode
 #[derive(Debug, PartialEq)]
 pub enum DeleteNodeError {
@@ -1579,6 +1647,18 @@ impl fmt::Display for DeleteNodeError {
     }
 }
 impl Error for DeleteNodeError {}
+impl ServiceErrorCode for DeleteNodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteNodeError::AccessDenied(_) => "AccessDeniedException",
+            DeleteNodeError::InternalServiceError(_) => "InternalServiceErrorException",
+            DeleteNodeError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteNodeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteNodeError::ResourceNotReady(_) => "ResourceNotReadyException",
+            DeleteNodeError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetMember
 #[derive(Debug, PartialEq)]
 pub enum GetMemberError {
@@ -1633,6 +1713,17 @@ impl fmt::Display for GetMemberError {
     }
 }
 impl Error for GetMemberError {}
+impl ServiceErrorCode for GetMemberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMemberError::AccessDenied(_) => "AccessDeniedException",
+            GetMemberError::InternalServiceError(_) => "InternalServiceErrorException",
+            GetMemberError::InvalidRequest(_) => "InvalidRequestException",
+            GetMemberError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetMemberError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetNetwork
 #[derive(Debug, PartialEq)]
 pub enum GetNetworkError {
@@ -1687,6 +1778,17 @@ impl fmt::Display for GetNetworkError {
     }
 }
 impl Error for GetNetworkError {}
+impl ServiceErrorCode for GetNetworkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetNetworkError::AccessDenied(_) => "AccessDeniedException",
+            GetNetworkError::InternalServiceError(_) => "InternalServiceErrorException",
+            GetNetworkError::InvalidRequest(_) => "InvalidRequestException",
+            GetNetworkError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetNetworkError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetNode
 #[derive(Debug, PartialEq)]
 pub enum GetNodeError {
@@ -1741,6 +1843,17 @@ impl fmt::Display for GetNodeError {
     }
 }
 impl Error for GetNodeError {}
+impl ServiceErrorCode for GetNodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetNodeError::AccessDenied(_) => "AccessDeniedException",
+            GetNodeError::InternalServiceError(_) => "InternalServiceErrorException",
+            GetNodeError::InvalidRequest(_) => "InvalidRequestException",
+            GetNodeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetNodeError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetProposal
 #[derive(Debug, PartialEq)]
 pub enum GetProposalError {
@@ -1795,6 +1908,17 @@ impl fmt::Display for GetProposalError {
     }
 }
 impl Error for GetProposalError {}
+impl ServiceErrorCode for GetProposalError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetProposalError::AccessDenied(_) => "AccessDeniedException",
+            GetProposalError::InternalServiceError(_) => "InternalServiceErrorException",
+            GetProposalError::InvalidRequest(_) => "InvalidRequestException",
+            GetProposalError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetProposalError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListInvitations
 #[derive(Debug, PartialEq)]
 pub enum ListInvitationsError {
@@ -1859,6 +1983,18 @@ impl fmt::Display for ListInvitationsError {
     }
 }
 impl Error for ListInvitationsError {}
+impl ServiceErrorCode for ListInvitationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInvitationsError::AccessDenied(_) => "AccessDeniedException",
+            ListInvitationsError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListInvitationsError::InvalidRequest(_) => "InvalidRequestException",
+            ListInvitationsError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            ListInvitationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListInvitationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListMembers
 #[derive(Debug, PartialEq)]
 pub enum ListMembersError {
@@ -1907,6 +2043,16 @@ impl fmt::Display for ListMembersError {
     }
 }
 impl Error for ListMembersError {}
+impl ServiceErrorCode for ListMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMembersError::AccessDenied(_) => "AccessDeniedException",
+            ListMembersError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListMembersError::InvalidRequest(_) => "InvalidRequestException",
+            ListMembersError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListNetworks
 #[derive(Debug, PartialEq)]
 pub enum ListNetworksError {
@@ -1955,6 +2101,16 @@ impl fmt::Display for ListNetworksError {
     }
 }
 impl Error for ListNetworksError {}
+impl ServiceErrorCode for ListNetworksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListNetworksError::AccessDenied(_) => "AccessDeniedException",
+            ListNetworksError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListNetworksError::InvalidRequest(_) => "InvalidRequestException",
+            ListNetworksError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListNodes
 #[derive(Debug, PartialEq)]
 pub enum ListNodesError {
@@ -2003,6 +2159,16 @@ impl fmt::Display for ListNodesError {
     }
 }
 impl Error for ListNodesError {}
+impl ServiceErrorCode for ListNodesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListNodesError::AccessDenied(_) => "AccessDeniedException",
+            ListNodesError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListNodesError::InvalidRequest(_) => "InvalidRequestException",
+            ListNodesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListProposalVotes
 #[derive(Debug, PartialEq)]
 pub enum ListProposalVotesError {
@@ -2053,6 +2219,16 @@ impl fmt::Display for ListProposalVotesError {
     }
 }
 impl Error for ListProposalVotesError {}
+impl ServiceErrorCode for ListProposalVotesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProposalVotesError::AccessDenied(_) => "AccessDeniedException",
+            ListProposalVotesError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListProposalVotesError::InvalidRequest(_) => "InvalidRequestException",
+            ListProposalVotesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListProposals
 #[derive(Debug, PartialEq)]
 pub enum ListProposalsError {
@@ -2107,6 +2283,17 @@ impl fmt::Display for ListProposalsError {
     }
 }
 impl Error for ListProposalsError {}
+impl ServiceErrorCode for ListProposalsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProposalsError::AccessDenied(_) => "AccessDeniedException",
+            ListProposalsError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListProposalsError::InvalidRequest(_) => "InvalidRequestException",
+            ListProposalsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListProposalsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by RejectInvitation
 #[derive(Debug, PartialEq)]
 pub enum RejectInvitationError {
@@ -2169,6 +2356,18 @@ impl fmt::Display for RejectInvitationError {
     }
 }
 impl Error for RejectInvitationError {}
+impl ServiceErrorCode for RejectInvitationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RejectInvitationError::AccessDenied(_) => "AccessDeniedException",
+            RejectInvitationError::IllegalAction(_) => "IllegalActionException",
+            RejectInvitationError::InternalServiceError(_) => "InternalServiceErrorException",
+            RejectInvitationError::InvalidRequest(_) => "InvalidRequestException",
+            RejectInvitationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RejectInvitationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateMember
 #[derive(Debug, PartialEq)]
 pub enum UpdateMemberError {
@@ -2223,6 +2422,17 @@ impl fmt::Display for UpdateMemberError {
     }
 }
 impl Error for UpdateMemberError {}
+impl ServiceErrorCode for UpdateMemberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMemberError::AccessDenied(_) => "AccessDeniedException",
+            UpdateMemberError::InternalServiceError(_) => "InternalServiceErrorException",
+            UpdateMemberError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateMemberError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateMemberError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateNode
 #[derive(Debug, PartialEq)]
 pub enum UpdateNodeError {
@@ -2277,6 +2487,17 @@ impl fmt::Display for UpdateNodeError {
     }
 }
 impl Error for UpdateNodeError {}
+impl ServiceErrorCode for UpdateNodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateNodeError::AccessDenied(_) => "AccessDeniedException",
+            UpdateNodeError::InternalServiceError(_) => "InternalServiceErrorException",
+            UpdateNodeError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateNodeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateNodeError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by VoteOnProposal
 #[derive(Debug, PartialEq)]
 pub enum VoteOnProposalError {
@@ -2337,6 +2558,18 @@ impl fmt::Display for VoteOnProposalError {
     }
 }
 impl Error for VoteOnProposalError {}
+impl ServiceErrorCode for VoteOnProposalError {
+    fn error_code(&self) -> &str {
+        match *self {
+            VoteOnProposalError::AccessDenied(_) => "AccessDeniedException",
+            VoteOnProposalError::IllegalAction(_) => "IllegalActionException",
+            VoteOnProposalError::InternalServiceError(_) => "InternalServiceErrorException",
+            VoteOnProposalError::InvalidRequest(_) => "InvalidRequestException",
+            VoteOnProposalError::ResourceNotFound(_) => "ResourceNotFoundException",
+            VoteOnProposalError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the ManagedBlockchain API. ManagedBlockchain clients implement this trait.
 #[async_trait]
 pub trait ManagedBlockchain {
@@ -2506,6 +2739,12 @@ impl ManagedBlockchain for ManagedBlockchainClient {
         &self,
         input: CreateMemberInput,
     ) -> Result<CreateMemberOutput, RusotoError<CreateMemberError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = format!(
             "/networks/{network_id}/members",
             network_id = input.network_id
@@ -2541,6 +2780,12 @@ impl ManagedBlockchain for ManagedBlockchainClient {
         &self,
         input: CreateNetworkInput,
     ) -> Result<CreateNetworkOutput, RusotoError<CreateNetworkError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = "/networks";
 
         let mut request =
@@ -2573,6 +2818,12 @@ impl ManagedBlockchain for ManagedBlockchainClient {
         &self,
         input: CreateNodeInput,
     ) -> Result<CreateNodeOutput, RusotoError<CreateNodeError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = format!(
             "/networks/{network_id}/nodes",
             network_id = input.network_id
@@ -2608,6 +2859,12 @@ impl ManagedBlockchain for ManagedBlockchainClient {
         &self,
         input: CreateProposalInput,
     ) -> Result<CreateProposalOutput, RusotoError<CreateProposalError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = format!(
             "/networks/{network_id}/proposals",
             network_id = input.network_id
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -387,6 +387,7 @@ pub struct StartChangeSetRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub change_set_name: Option<String>,
     /// <p>A unique token to identify the request to ensure idempotency.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -459,6 +460,17 @@ impl fmt::Display for CancelChangeSetError {
     }
 }
 impl Error for CancelChangeSetError {}
+impl ServiceErrorCode for CancelChangeSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelChangeSetError::AccessDenied(_) => "AccessDeniedException",
+            CancelChangeSetError::InternalService(_) => "InternalServiceException",
+            CancelChangeSetError::ResourceInUse(_) => "ResourceInUseException",
+            CancelChangeSetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelChangeSetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeChangeSet
 #[derive(Debug, PartialEq)]
 pub enum DescribeChangeSetError {
@@ -507,6 +519,16 @@ impl fmt::Display for DescribeChangeSetError {
     }
 }
 impl Error for DescribeChangeSetError {}
+impl ServiceErrorCode for DescribeChangeSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeChangeSetError::AccessDenied(_) => "AccessDeniedException",
+            DescribeChangeSetError::InternalService(_) => "InternalServiceException",
+            DescribeChangeSetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeChangeSetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeEntity
 #[derive(Debug, PartialEq)]
 pub enum DescribeEntityError {
@@ -561,6 +583,17 @@ impl fmt::Display for DescribeEntityError {
     }
 }
 impl Error for DescribeEntityError {}
+impl ServiceErrorCode for DescribeEntityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEntityError::AccessDenied(_) => "AccessDeniedException",
+            DescribeEntityError::InternalService(_) => "InternalServiceException",
+            DescribeEntityError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeEntityError::ResourceNotSupported(_) => "ResourceNotSupportedException",
+            DescribeEntityError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListChangeSets
 #[derive(Debug, PartialEq)]
 pub enum ListChangeSetsError {
@@ -603,6 +636,15 @@ impl fmt::Display for ListChangeSetsError {
     }
 }
 impl Error for ListChangeSetsError {}
+impl ServiceErrorCode for ListChangeSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListChangeSetsError::AccessDenied(_) => "AccessDeniedException",
+            ListChangeSetsError::InternalService(_) => "InternalServiceException",
+            ListChangeSetsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListEntities
 #[derive(Debug, PartialEq)]
 pub enum ListEntitiesError {
@@ -651,6 +693,16 @@ impl fmt::Display for ListEntitiesError {
     }
 }
 impl Error for ListEntitiesError {}
+impl ServiceErrorCode for ListEntitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEntitiesError::AccessDenied(_) => "AccessDeniedException",
+            ListEntitiesError::InternalService(_) => "InternalServiceException",
+            ListEntitiesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListEntitiesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StartChangeSet
 #[derive(Debug, PartialEq)]
 pub enum StartChangeSetError {
@@ -711,6 +763,18 @@ impl fmt::Display for StartChangeSetError {
     }
 }
 impl Error for StartChangeSetError {}
+impl ServiceErrorCode for StartChangeSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartChangeSetError::AccessDenied(_) => "AccessDeniedException",
+            StartChangeSetError::InternalService(_) => "InternalServiceException",
+            StartChangeSetError::ResourceInUse(_) => "ResourceInUseException",
+            StartChangeSetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartChangeSetError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            StartChangeSetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Marketplace Catalog API. AWS Marketplace Catalog clients implement this trait.
 #[async_trait]
 pub trait MarketplaceCatalog {
@@ -966,6 +1030,10 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         &self,
         input: StartChangeSetRequest,
     ) -> Result<StartChangeSetResponse, RusotoError<StartChangeSetError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = "/StartChangeSet";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -179,6 +179,15 @@ impl fmt::Display for GetEntitlementsError {
     }
 }
 impl Error for GetEntitlementsError {}
+impl ServiceErrorCode for GetEntitlementsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEntitlementsError::InternalServiceError(_) => "InternalServiceErrorException",
+            GetEntitlementsError::InvalidParameter(_) => "InvalidParameterException",
+            GetEntitlementsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Marketplace Entitlement Service API. AWS Marketplace Entitlement Service clients implement this trait.
 #[async_trait]
 pub trait MarketplaceEntitlement {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -165,6 +165,15 @@ impl fmt::Display for GenerateDataSetError {
     }
 }
 impl Error for GenerateDataSetError {}
+impl ServiceErrorCode for GenerateDataSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GenerateDataSetError::MarketplaceCommerceAnalytics(_) => {
+                "MarketplaceCommerceAnalyticsException"
+            }
+        }
+    }
+}
 /// Errors returned by StartSupportDataExport
 #[derive(Debug, PartialEq)]
 pub enum StartSupportDataExportError {
@@ -199,6 +208,15 @@ impl fmt::Display for StartSupportDataExportError {
     }
 }
 impl Error for StartSupportDataExportError {}
+impl ServiceErrorCode for StartSupportDataExportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartSupportDataExportError::MarketplaceCommerceAnalytics(_) => {
+                "MarketplaceCommerceAnalyticsException"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Marketplace Commerce Analytics API. AWS Marketplace Commerce Analytics clients implement this trait.
 #[async_trait]
 pub trait MarketplaceCommerceAnalytics {
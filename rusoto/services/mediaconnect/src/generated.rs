@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1436,6 +1436,19 @@ impl fmt::Display for AddFlowOutputsError {
     }
 }
 impl Error for AddFlowOutputsError {}
+impl ServiceErrorCode for AddFlowOutputsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddFlowOutputsError::AddFlowOutputs420(_) => "AddFlowOutputs420Exception",
+            AddFlowOutputsError::BadRequest(_) => "BadRequestException",
+            AddFlowOutputsError::Forbidden(_) => "ForbiddenException",
+            AddFlowOutputsError::InternalServerError(_) => "InternalServerErrorException",
+            AddFlowOutputsError::NotFound(_) => "NotFoundException",
+            AddFlowOutputsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            AddFlowOutputsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by AddFlowSources
 #[derive(Debug, PartialEq)]
 pub enum AddFlowSourcesError {
@@ -1496,6 +1509,18 @@ impl fmt::Display for AddFlowSourcesError {
     }
 }
 impl Error for AddFlowSourcesError {}
+impl ServiceErrorCode for AddFlowSourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddFlowSourcesError::BadRequest(_) => "BadRequestException",
+            AddFlowSourcesError::Forbidden(_) => "ForbiddenException",
+            AddFlowSourcesError::InternalServerError(_) => "InternalServerErrorException",
+            AddFlowSourcesError::NotFound(_) => "NotFoundException",
+            AddFlowSourcesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            AddFlowSourcesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by AddFlowVpcInterfaces
 #[derive(Debug, PartialEq)]
 pub enum AddFlowVpcInterfacesError {
@@ -1562,6 +1587,18 @@ impl fmt::Display for AddFlowVpcInterfacesError {
     }
 }
 impl Error for AddFlowVpcInterfacesError {}
+impl ServiceErrorCode for AddFlowVpcInterfacesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddFlowVpcInterfacesError::BadRequest(_) => "BadRequestException",
+            AddFlowVpcInterfacesError::Forbidden(_) => "ForbiddenException",
+            AddFlowVpcInterfacesError::InternalServerError(_) => "InternalServerErrorException",
+            AddFlowVpcInterfacesError::NotFound(_) => "NotFoundException",
+            AddFlowVpcInterfacesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            AddFlowVpcInterfacesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateFlow
 #[derive(Debug, PartialEq)]
 pub enum CreateFlowError {
@@ -1622,6 +1659,18 @@ impl fmt::Display for CreateFlowError {
     }
 }
 impl Error for CreateFlowError {}
+impl ServiceErrorCode for CreateFlowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFlowError::BadRequest(_) => "BadRequestException",
+            CreateFlowError::CreateFlow420(_) => "CreateFlow420Exception",
+            CreateFlowError::Forbidden(_) => "ForbiddenException",
+            CreateFlowError::InternalServerError(_) => "InternalServerErrorException",
+            CreateFlowError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateFlowError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteFlow
 #[derive(Debug, PartialEq)]
 pub enum DeleteFlowError {
@@ -1682,6 +1731,18 @@ impl fmt::Display for DeleteFlowError {
     }
 }
 impl Error for DeleteFlowError {}
+impl ServiceErrorCode for DeleteFlowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFlowError::BadRequest(_) => "BadRequestException",
+            DeleteFlowError::Forbidden(_) => "ForbiddenException",
+            DeleteFlowError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteFlowError::NotFound(_) => "NotFoundException",
+            DeleteFlowError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteFlowError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeFlow
 #[derive(Debug, PartialEq)]
 pub enum DescribeFlowError {
@@ -1742,6 +1803,18 @@ impl fmt::Display for DescribeFlowError {
     }
 }
 impl Error for DescribeFlowError {}
+impl ServiceErrorCode for DescribeFlowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFlowError::BadRequest(_) => "BadRequestException",
+            DescribeFlowError::Forbidden(_) => "ForbiddenException",
+            DescribeFlowError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeFlowError::NotFound(_) => "NotFoundException",
+            DescribeFlowError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeFlowError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeOffering
 #[derive(Debug, PartialEq)]
 pub enum DescribeOfferingError {
@@ -1798,6 +1871,17 @@ impl fmt::Display for DescribeOfferingError {
     }
 }
 impl Error for DescribeOfferingError {}
+impl ServiceErrorCode for DescribeOfferingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeOfferingError::BadRequest(_) => "BadRequestException",
+            DescribeOfferingError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeOfferingError::NotFound(_) => "NotFoundException",
+            DescribeOfferingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeOfferingError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeReservation
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservationError {
@@ -1856,6 +1940,17 @@ impl fmt::Display for DescribeReservationError {
     }
 }
 impl Error for DescribeReservationError {}
+impl ServiceErrorCode for DescribeReservationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReservationError::BadRequest(_) => "BadRequestException",
+            DescribeReservationError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeReservationError::NotFound(_) => "NotFoundException",
+            DescribeReservationError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeReservationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GrantFlowEntitlements
 #[derive(Debug, PartialEq)]
 pub enum GrantFlowEntitlementsError {
@@ -1932,6 +2027,21 @@ impl fmt::Display for GrantFlowEntitlementsError {
     }
 }
 impl Error for GrantFlowEntitlementsError {}
+impl ServiceErrorCode for GrantFlowEntitlementsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GrantFlowEntitlementsError::BadRequest(_) => "BadRequestException",
+            GrantFlowEntitlementsError::Forbidden(_) => "ForbiddenException",
+            GrantFlowEntitlementsError::GrantFlowEntitlements420(_) => {
+                "GrantFlowEntitlements420Exception"
+            }
+            GrantFlowEntitlementsError::InternalServerError(_) => "InternalServerErrorException",
+            GrantFlowEntitlementsError::NotFound(_) => "NotFoundException",
+            GrantFlowEntitlementsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GrantFlowEntitlementsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListEntitlements
 #[derive(Debug, PartialEq)]
 pub enum ListEntitlementsError {
@@ -1982,6 +2092,16 @@ impl fmt::Display for ListEntitlementsError {
     }
 }
 impl Error for ListEntitlementsError {}
+impl ServiceErrorCode for ListEntitlementsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEntitlementsError::BadRequest(_) => "BadRequestException",
+            ListEntitlementsError::InternalServerError(_) => "InternalServerErrorException",
+            ListEntitlementsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListEntitlementsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListFlows
 #[derive(Debug, PartialEq)]
 pub enum ListFlowsError {
@@ -2030,6 +2150,16 @@ impl fmt::Display for ListFlowsError {
     }
 }
 impl Error for ListFlowsError {}
+impl ServiceErrorCode for ListFlowsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFlowsError::BadRequest(_) => "BadRequestException",
+            ListFlowsError::InternalServerError(_) => "InternalServerErrorException",
+            ListFlowsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListFlowsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListOfferings
 #[derive(Debug, PartialEq)]
 pub enum ListOfferingsError {
@@ -2078,6 +2208,16 @@ impl fmt::Display for ListOfferingsError {
     }
 }
 impl Error for ListOfferingsError {}
+impl ServiceErrorCode for ListOfferingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOfferingsError::BadRequest(_) => "BadRequestException",
+            ListOfferingsError::InternalServerError(_) => "InternalServerErrorException",
+            ListOfferingsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListOfferingsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListReservations
 #[derive(Debug, PartialEq)]
 pub enum ListReservationsError {
@@ -2128,6 +2268,16 @@ impl fmt::Display for ListReservationsError {
     }
 }
 impl Error for ListReservationsError {}
+impl ServiceErrorCode for ListReservationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListReservationsError::BadRequest(_) => "BadRequestException",
+            ListReservationsError::InternalServerError(_) => "InternalServerErrorException",
+            ListReservationsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListReservationsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2172,6 +2322,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::InternalServerError(_) => "InternalServerErrorException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by PurchaseOffering
 #[derive(Debug, PartialEq)]
 pub enum PurchaseOfferingError {
@@ -2234,6 +2393,18 @@ impl fmt::Display for PurchaseOfferingError {
     }
 }
 impl Error for PurchaseOfferingError {}
+impl ServiceErrorCode for PurchaseOfferingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PurchaseOfferingError::BadRequest(_) => "BadRequestException",
+            PurchaseOfferingError::Forbidden(_) => "ForbiddenException",
+            PurchaseOfferingError::InternalServerError(_) => "InternalServerErrorException",
+            PurchaseOfferingError::NotFound(_) => "NotFoundException",
+            PurchaseOfferingError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            PurchaseOfferingError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by RemoveFlowOutput
 #[derive(Debug, PartialEq)]
 pub enum RemoveFlowOutputError {
@@ -2296,6 +2467,18 @@ impl fmt::Display for RemoveFlowOutputError {
     }
 }
 impl Error for RemoveFlowOutputError {}
+impl ServiceErrorCode for RemoveFlowOutputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveFlowOutputError::BadRequest(_) => "BadRequestException",
+            RemoveFlowOutputError::Forbidden(_) => "ForbiddenException",
+            RemoveFlowOutputError::InternalServerError(_) => "InternalServerErrorException",
+            RemoveFlowOutputError::NotFound(_) => "NotFoundException",
+            RemoveFlowOutputError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RemoveFlowOutputError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by RemoveFlowSource
 #[derive(Debug, PartialEq)]
 pub enum RemoveFlowSourceError {
@@ -2358,6 +2541,18 @@ impl fmt::Display for RemoveFlowSourceError {
     }
 }
 impl Error for RemoveFlowSourceError {}
+impl ServiceErrorCode for RemoveFlowSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveFlowSourceError::BadRequest(_) => "BadRequestException",
+            RemoveFlowSourceError::Forbidden(_) => "ForbiddenException",
+            RemoveFlowSourceError::InternalServerError(_) => "InternalServerErrorException",
+            RemoveFlowSourceError::NotFound(_) => "NotFoundException",
+            RemoveFlowSourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RemoveFlowSourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by RemoveFlowVpcInterface
 #[derive(Debug, PartialEq)]
 pub enum RemoveFlowVpcInterfaceError {
@@ -2424,6 +2619,18 @@ impl fmt::Display for RemoveFlowVpcInterfaceError {
     }
 }
 impl Error for RemoveFlowVpcInterfaceError {}
+impl ServiceErrorCode for RemoveFlowVpcInterfaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveFlowVpcInterfaceError::BadRequest(_) => "BadRequestException",
+            RemoveFlowVpcInterfaceError::Forbidden(_) => "ForbiddenException",
+            RemoveFlowVpcInterfaceError::InternalServerError(_) => "InternalServerErrorException",
+            RemoveFlowVpcInterfaceError::NotFound(_) => "NotFoundException",
+            RemoveFlowVpcInterfaceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RemoveFlowVpcInterfaceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by RevokeFlowEntitlement
 #[derive(Debug, PartialEq)]
 pub enum RevokeFlowEntitlementError {
@@ -2490,6 +2697,18 @@ impl fmt::Display for RevokeFlowEntitlementError {
     }
 }
 impl Error for RevokeFlowEntitlementError {}
+impl ServiceErrorCode for RevokeFlowEntitlementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RevokeFlowEntitlementError::BadRequest(_) => "BadRequestException",
+            RevokeFlowEntitlementError::Forbidden(_) => "ForbiddenException",
+            RevokeFlowEntitlementError::InternalServerError(_) => "InternalServerErrorException",
+            RevokeFlowEntitlementError::NotFound(_) => "NotFoundException",
+            RevokeFlowEntitlementError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RevokeFlowEntitlementError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StartFlow
 #[derive(Debug, PartialEq)]
 pub enum StartFlowError {
@@ -2550,6 +2769,18 @@ impl fmt::Display for StartFlowError {
     }
 }
 impl Error for StartFlowError {}
+impl ServiceErrorCode for StartFlowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartFlowError::BadRequest(_) => "BadRequestException",
+            StartFlowError::Forbidden(_) => "ForbiddenException",
+            StartFlowError::InternalServerError(_) => "InternalServerErrorException",
+            StartFlowError::NotFound(_) => "NotFoundException",
+            StartFlowError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            StartFlowError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StopFlow
 #[derive(Debug, PartialEq)]
 pub enum StopFlowError {
@@ -2610,6 +2841,18 @@ impl fmt::Display for StopFlowError {
     }
 }
 impl Error for StopFlowError {}
+impl ServiceErrorCode for StopFlowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopFlowError::BadRequest(_) => "BadRequestException",
+            StopFlowError::Forbidden(_) => "ForbiddenException",
+            StopFlowError::InternalServerError(_) => "InternalServerErrorException",
+            StopFlowError::NotFound(_) => "NotFoundException",
+            StopFlowError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            StopFlowError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2652,6 +2895,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::InternalServerError(_) => "InternalServerErrorException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2694,6 +2946,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::InternalServerError(_) => "InternalServerErrorException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateFlow
 #[derive(Debug, PartialEq)]
 pub enum UpdateFlowError {
@@ -2754,6 +3015,18 @@ impl fmt::Display for UpdateFlowError {
     }
 }
 impl Error for UpdateFlowError {}
+impl ServiceErrorCode for UpdateFlowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFlowError::BadRequest(_) => "BadRequestException",
+            UpdateFlowError::Forbidden(_) => "ForbiddenException",
+            UpdateFlowError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateFlowError::NotFound(_) => "NotFoundException",
+            UpdateFlowError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateFlowError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateFlowEntitlement
 #[derive(Debug, PartialEq)]
 pub enum UpdateFlowEntitlementError {
@@ -2820,6 +3093,18 @@ impl fmt::Display for UpdateFlowEntitlementError {
     }
 }
 impl Error for UpdateFlowEntitlementError {}
+impl ServiceErrorCode for UpdateFlowEntitlementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFlowEntitlementError::BadRequest(_) => "BadRequestException",
+            UpdateFlowEntitlementError::Forbidden(_) => "ForbiddenException",
+            UpdateFlowEntitlementError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateFlowEntitlementError::NotFound(_) => "NotFoundException",
+            UpdateFlowEntitlementError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateFlowEntitlementError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateFlowOutput
 #[derive(Debug, PartialEq)]
 pub enum UpdateFlowOutputError {
@@ -2882,6 +3167,18 @@ impl fmt::Display for UpdateFlowOutputError {
     }
 }
 impl Error for UpdateFlowOutputError {}
+impl ServiceErrorCode for UpdateFlowOutputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFlowOutputError::BadRequest(_) => "BadRequestException",
+            UpdateFlowOutputError::Forbidden(_) => "ForbiddenException",
+            UpdateFlowOutputError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateFlowOutputError::NotFound(_) => "NotFoundException",
+            UpdateFlowOutputError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateFlowOutputError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateFlowSource
 #[derive(Debug, PartialEq)]
 pub enum UpdateFlowSourceError {
@@ -2944,6 +3241,18 @@ impl fmt::Display for UpdateFlowSourceError {
     }
 }
 impl Error for UpdateFlowSourceError {}
+impl ServiceErrorCode for UpdateFlowSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFlowSourceError::BadRequest(_) => "BadRequestException",
+            UpdateFlowSourceError::Forbidden(_) => "ForbiddenException",
+            UpdateFlowSourceError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateFlowSourceError::NotFound(_) => "NotFoundException",
+            UpdateFlowSourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateFlowSourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS MediaConnect API. AWS MediaConnect clients implement this trait.
 #[async_trait]
 pub trait MediaConnect {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -951,6 +951,7 @@ pub struct CreateJobRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub billing_tags_source: Option<String>,
     /// <p>Optional. Idempotency token for CreateJob operation.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -5172,6 +5173,18 @@ impl fmt::Display for AssociateCertificateError {
     }
 }
 impl Error for AssociateCertificateError {}
+impl ServiceErrorCode for AssociateCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateCertificateError::BadRequest(_) => "BadRequestException",
+            AssociateCertificateError::Conflict(_) => "ConflictException",
+            AssociateCertificateError::Forbidden(_) => "ForbiddenException",
+            AssociateCertificateError::InternalServerError(_) => "InternalServerErrorException",
+            AssociateCertificateError::NotFound(_) => "NotFoundException",
+            AssociateCertificateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CancelJob
 #[derive(Debug, PartialEq)]
 pub enum CancelJobError {
@@ -5232,6 +5245,18 @@ impl fmt::Display for CancelJobError {
     }
 }
 impl Error for CancelJobError {}
+impl ServiceErrorCode for CancelJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelJobError::BadRequest(_) => "BadRequestException",
+            CancelJobError::Conflict(_) => "ConflictException",
+            CancelJobError::Forbidden(_) => "ForbiddenException",
+            CancelJobError::InternalServerError(_) => "InternalServerErrorException",
+            CancelJobError::NotFound(_) => "NotFoundException",
+            CancelJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateJob
 #[derive(Debug, PartialEq)]
 pub enum CreateJobError {
@@ -5292,6 +5317,18 @@ impl fmt::Display for CreateJobError {
     }
 }
 impl Error for CreateJobError {}
+impl ServiceErrorCode for CreateJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateJobError::BadRequest(_) => "BadRequestException",
+            CreateJobError::Conflict(_) => "ConflictException",
+            CreateJobError::Forbidden(_) => "ForbiddenException",
+            CreateJobError::InternalServerError(_) => "InternalServerErrorException",
+            CreateJobError::NotFound(_) => "NotFoundException",
+            CreateJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateJobTemplate
 #[derive(Debug, PartialEq)]
 pub enum CreateJobTemplateError {
@@ -5354,6 +5391,18 @@ impl fmt::Display for CreateJobTemplateError {
     }
 }
 impl Error for CreateJobTemplateError {}
+impl ServiceErrorCode for CreateJobTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateJobTemplateError::BadRequest(_) => "BadRequestException",
+            CreateJobTemplateError::Conflict(_) => "ConflictException",
+            CreateJobTemplateError::Forbidden(_) => "ForbiddenException",
+            CreateJobTemplateError::InternalServerError(_) => "InternalServerErrorException",
+            CreateJobTemplateError::NotFound(_) => "NotFoundException",
+            CreateJobTemplateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreatePreset
 #[derive(Debug, PartialEq)]
 pub enum CreatePresetError {
@@ -5414,6 +5463,18 @@ impl fmt::Display for CreatePresetError {
     }
 }
 impl Error for CreatePresetError {}
+impl ServiceErrorCode for CreatePresetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePresetError::BadRequest(_) => "BadRequestException",
+            CreatePresetError::Conflict(_) => "ConflictException",
+            CreatePresetError::Forbidden(_) => "ForbiddenException",
+            CreatePresetError::InternalServerError(_) => "InternalServerErrorException",
+            CreatePresetError::NotFound(_) => "NotFoundException",
+            CreatePresetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateQueue
 #[derive(Debug, PartialEq)]
 pub enum CreateQueueError {
@@ -5474,6 +5535,18 @@ impl fmt::Display for CreateQueueError {
     }
 }
 impl Error for CreateQueueError {}
+impl ServiceErrorCode for CreateQueueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateQueueError::BadRequest(_) => "BadRequestException",
+            CreateQueueError::Conflict(_) => "ConflictException",
+            CreateQueueError::Forbidden(_) => "ForbiddenException",
+            CreateQueueError::InternalServerError(_) => "InternalServerErrorException",
+            CreateQueueError::NotFound(_) => "NotFoundException",
+            CreateQueueError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteJobTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeleteJobTemplateError {
@@ -5536,6 +5609,18 @@ impl fmt::Display for DeleteJobTemplateError {
     }
 }
 impl Error for DeleteJobTemplateError {}
+impl ServiceErrorCode for DeleteJobTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteJobTemplateError::BadRequest(_) => "BadRequestException",
+            DeleteJobTemplateError::Conflict(_) => "ConflictException",
+            DeleteJobTemplateError::Forbidden(_) => "ForbiddenException",
+            DeleteJobTemplateError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteJobTemplateError::NotFound(_) => "NotFoundException",
+            DeleteJobTemplateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeletePreset
 #[derive(Debug, PartialEq)]
 pub enum DeletePresetError {
@@ -5596,6 +5681,18 @@ impl fmt::Display for DeletePresetError {
     }
 }
 impl Error for DeletePresetError {}
+impl ServiceErrorCode for DeletePresetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePresetError::BadRequest(_) => "BadRequestException",
+            DeletePresetError::Conflict(_) => "ConflictException",
+            DeletePresetError::Forbidden(_) => "ForbiddenException",
+            DeletePresetError::InternalServerError(_) => "InternalServerErrorException",
+            DeletePresetError::NotFound(_) => "NotFoundException",
+            DeletePresetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteQueue
 #[derive(Debug, PartialEq)]
 pub enum DeleteQueueError {
@@ -5656,6 +5753,18 @@ impl fmt::Display for DeleteQueueError {
     }
 }
 impl Error for DeleteQueueError {}
+impl ServiceErrorCode for DeleteQueueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteQueueError::BadRequest(_) => "BadRequestException",
+            DeleteQueueError::Conflict(_) => "ConflictException",
+            DeleteQueueError::Forbidden(_) => "ForbiddenException",
+            DeleteQueueError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteQueueError::NotFound(_) => "NotFoundException",
+            DeleteQueueError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeEndpoints
 #[derive(Debug, PartialEq)]
 pub enum DescribeEndpointsError {
@@ -5718,6 +5827,18 @@ impl fmt::Display for DescribeEndpointsError {
     }
 }
 impl Error for DescribeEndpointsError {}
+impl ServiceErrorCode for DescribeEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEndpointsError::BadRequest(_) => "BadRequestException",
+            DescribeEndpointsError::Conflict(_) => "ConflictException",
+            DescribeEndpointsError::Forbidden(_) => "ForbiddenException",
+            DescribeEndpointsError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeEndpointsError::NotFound(_) => "NotFoundException",
+            DescribeEndpointsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DisassociateCertificate
 #[derive(Debug, PartialEq)]
 pub enum DisassociateCertificateError {
@@ -5782,6 +5903,18 @@ impl fmt::Display for DisassociateCertificateError {
     }
 }
 impl Error for DisassociateCertificateError {}
+impl ServiceErrorCode for DisassociateCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateCertificateError::BadRequest(_) => "BadRequestException",
+            DisassociateCertificateError::Conflict(_) => "ConflictException",
+            DisassociateCertificateError::Forbidden(_) => "ForbiddenException",
+            DisassociateCertificateError::InternalServerError(_) => "InternalServerErrorException",
+            DisassociateCertificateError::NotFound(_) => "NotFoundException",
+            DisassociateCertificateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetJob
 #[derive(Debug, PartialEq)]
 pub enum GetJobError {
@@ -5838,6 +5971,18 @@ impl fmt::Display for GetJobError {
     }
 }
 impl Error for GetJobError {}
+impl ServiceErrorCode for GetJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetJobError::BadRequest(_) => "BadRequestException",
+            GetJobError::Conflict(_) => "ConflictException",
+            GetJobError::Forbidden(_) => "ForbiddenException",
+            GetJobError::InternalServerError(_) => "InternalServerErrorException",
+            GetJobError::NotFound(_) => "NotFoundException",
+            GetJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetJobTemplate
 #[derive(Debug, PartialEq)]
 pub enum GetJobTemplateError {
@@ -5898,6 +6043,18 @@ impl fmt::Display for GetJobTemplateError {
     }
 }
 impl Error for GetJobTemplateError {}
+impl ServiceErrorCode for GetJobTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetJobTemplateError::BadRequest(_) => "BadRequestException",
+            GetJobTemplateError::Conflict(_) => "ConflictException",
+            GetJobTemplateError::Forbidden(_) => "ForbiddenException",
+            GetJobTemplateError::InternalServerError(_) => "InternalServerErrorException",
+            GetJobTemplateError::NotFound(_) => "NotFoundException",
+            GetJobTemplateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetPreset
 #[derive(Debug, PartialEq)]
 pub enum GetPresetError {
@@ -5958,6 +6115,18 @@ impl fmt::Display for GetPresetError {
     }
 }
 impl Error for GetPresetError {}
+impl ServiceErrorCode for GetPresetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPresetError::BadRequest(_) => "BadRequestException",
+            GetPresetError::Conflict(_) => "ConflictException",
+            GetPresetError::Forbidden(_) => "ForbiddenException",
+            GetPresetError::InternalServerError(_) => "InternalServerErrorException",
+            GetPresetError::NotFound(_) => "NotFoundException",
+            GetPresetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetQueue
 #[derive(Debug, PartialEq)]
 pub enum GetQueueError {
@@ -6018,6 +6187,18 @@ impl fmt::Display for GetQueueError {
     }
 }
 impl Error for GetQueueError {}
+impl ServiceErrorCode for GetQueueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetQueueError::BadRequest(_) => "BadRequestException",
+            GetQueueError::Conflict(_) => "ConflictException",
+            GetQueueError::Forbidden(_) => "ForbiddenException",
+            GetQueueError::InternalServerError(_) => "InternalServerErrorException",
+            GetQueueError::NotFound(_) => "NotFoundException",
+            GetQueueError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListJobTemplates
 #[derive(Debug, PartialEq)]
 pub enum ListJobTemplatesError {
@@ -6080,6 +6261,18 @@ impl fmt::Display for ListJobTemplatesError {
     }
 }
 impl Error for ListJobTemplatesError {}
+impl ServiceErrorCode for ListJobTemplatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJobTemplatesError::BadRequest(_) => "BadRequestException",
+            ListJobTemplatesError::Conflict(_) => "ConflictException",
+            ListJobTemplatesError::Forbidden(_) => "ForbiddenException",
+            ListJobTemplatesError::InternalServerError(_) => "InternalServerErrorException",
+            ListJobTemplatesError::NotFound(_) => "NotFoundException",
+            ListJobTemplatesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
 pub enum ListJobsError {
@@ -6140,6 +6333,18 @@ impl fmt::Display for ListJobsError {
     }
 }
 impl Error for ListJobsError {}
+impl ServiceErrorCode for ListJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJobsError::BadRequest(_) => "BadRequestException",
+            ListJobsError::Conflict(_) => "ConflictException",
+            ListJobsError::Forbidden(_) => "ForbiddenException",
+            ListJobsError::InternalServerError(_) => "InternalServerErrorException",
+            ListJobsError::NotFound(_) => "NotFoundException",
+            ListJobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListPresets
 #[derive(Debug, PartialEq)]
 pub enum ListPresetsError {
@@ -6200,6 +6405,18 @@ impl fmt::Display for ListPresetsError {
     }
 }
 impl Error for ListPresetsError {}
+impl ServiceErrorCode for ListPresetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPresetsError::BadRequest(_) => "BadRequestException",
+            ListPresetsError::Conflict(_) => "ConflictException",
+            ListPresetsError::Forbidden(_) => "ForbiddenException",
+            ListPresetsError::InternalServerError(_) => "InternalServerErrorException",
+            ListPresetsError::NotFound(_) => "NotFoundException",
+            ListPresetsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListQueues
 #[derive(Debug, PartialEq)]
 pub enum ListQueuesError {
@@ -6260,6 +6477,18 @@ impl fmt::Display for ListQueuesError {
     }
 }
 impl Error for ListQueuesError {}
+impl ServiceErrorCode for ListQueuesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListQueuesError::BadRequest(_) => "BadRequestException",
+            ListQueuesError::Conflict(_) => "ConflictException",
+            ListQueuesError::Forbidden(_) => "ForbiddenException",
+            ListQueuesError::InternalServerError(_) => "InternalServerErrorException",
+            ListQueuesError::NotFound(_) => "NotFoundException",
+            ListQueuesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -6322,6 +6551,18 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::Conflict(_) => "ConflictException",
+            ListTagsForResourceError::Forbidden(_) => "ForbiddenException",
+            ListTagsForResourceError::InternalServerError(_) => "InternalServerErrorException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+            ListTagsForResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -6382,6 +6623,18 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::Conflict(_) => "ConflictException",
+            TagResourceError::Forbidden(_) => "ForbiddenException",
+            TagResourceError::InternalServerError(_) => "InternalServerErrorException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+            TagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -6442,6 +6695,18 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::Conflict(_) => "ConflictException",
+            UntagResourceError::Forbidden(_) => "ForbiddenException",
+            UntagResourceError::InternalServerError(_) => "InternalServerErrorException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+            UntagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateJobTemplate
 #[derive(Debug, PartialEq)]
 pub enum UpdateJobTemplateError {
@@ -6504,6 +6769,18 @@ impl fmt::Display for UpdateJobTemplateError {
     }
 }
 impl Error for UpdateJobTemplateError {}
+impl ServiceErrorCode for UpdateJobTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateJobTemplateError::BadRequest(_) => "BadRequestException",
+            UpdateJobTemplateError::Conflict(_) => "ConflictException",
+            UpdateJobTemplateError::Forbidden(_) => "ForbiddenException",
+            UpdateJobTemplateError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateJobTemplateError::NotFound(_) => "NotFoundException",
+            UpdateJobTemplateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdatePreset
 #[derive(Debug, PartialEq)]
 pub enum UpdatePresetError {
@@ -6564,6 +6841,18 @@ impl fmt::Display for UpdatePresetError {
     }
 }
 impl Error for UpdatePresetError {}
+impl ServiceErrorCode for UpdatePresetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePresetError::BadRequest(_) => "BadRequestException",
+            UpdatePresetError::Conflict(_) => "ConflictException",
+            UpdatePresetError::Forbidden(_) => "ForbiddenException",
+            UpdatePresetError::InternalServerError(_) => "InternalServerErrorException",
+            UpdatePresetError::NotFound(_) => "NotFoundException",
+            UpdatePresetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateQueue
 #[derive(Debug, PartialEq)]
 pub enum UpdateQueueError {
@@ -6624,6 +6913,18 @@ impl fmt::Display for UpdateQueueError {
     }
 }
 impl Error for UpdateQueueError {}
+impl ServiceErrorCode for UpdateQueueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateQueueError::BadRequest(_) => "BadRequestException",
+            UpdateQueueError::Conflict(_) => "ConflictException",
+            UpdateQueueError::Forbidden(_) => "ForbiddenException",
+            UpdateQueueError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateQueueError::NotFound(_) => "NotFoundException",
+            UpdateQueueError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the MediaConvert API. MediaConvert clients implement this trait.
 #[async_trait]
 pub trait MediaConvert {
@@ -6882,6 +7183,10 @@ impl MediaConvert for MediaConvertClient {
         &self,
         input: CreateJobRequest,
     ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = "/2017-08-29/jobs";
 
         let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1056,6 +1056,7 @@ pub struct CreateChannelRequest {
     pub name: Option<String>,
     /// <p>Unique request ID to be specified. This is needed to prevent retries from
     /// creating multiple resources.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "RequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_id: Option<String>,
@@ -1106,6 +1107,7 @@ pub struct CreateInputRequest {
     pub name: Option<String>,
     /// <p>Unique identifier of the request to ensure the request is handled
     /// exactly once in case of retries.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "RequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_id: Option<String>,
@@ -1178,6 +1180,7 @@ pub struct CreateMultiplexProgramRequest {
     pub program_name: String,
     /// <p>Unique request ID. This prevents retries from creating multiple
     /// resources.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "RequestId")]
     pub request_id: String,
 }
@@ -1207,6 +1210,7 @@ pub struct CreateMultiplexRequest {
     pub name: String,
     /// <p>Unique request ID. This prevents retries from creating multiple
     /// resources.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "RequestId")]
     pub request_id: String,
     /// <p>A collection of key-value pairs.</p>
@@ -5432,6 +5436,7 @@ pub struct PurchaseOfferingRequest {
     #[serde(rename = "OfferingId")]
     pub offering_id: String,
     /// <p>Unique request ID to be specified. This is needed to prevent retries from creating multiple resources.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "RequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_id: Option<String>,
@@ -6976,6 +6981,25 @@ impl fmt::Display for AcceptInputDeviceTransferError {
     }
 }
 impl Error for AcceptInputDeviceTransferError {}
+impl ServiceErrorCode for AcceptInputDeviceTransferError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptInputDeviceTransferError::BadGateway(_) => "BadGatewayException",
+            AcceptInputDeviceTransferError::BadRequest(_) => "BadRequestException",
+            AcceptInputDeviceTransferError::Conflict(_) => "ConflictException",
+            AcceptInputDeviceTransferError::Forbidden(_) => "ForbiddenException",
+            AcceptInputDeviceTransferError::GatewayTimeout(_) => "GatewayTimeoutException",
+            AcceptInputDeviceTransferError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            AcceptInputDeviceTransferError::NotFound(_) => "NotFoundException",
+            AcceptInputDeviceTransferError::TooManyRequests(_) => "TooManyRequestsException",
+            AcceptInputDeviceTransferError::UnprocessableEntity(_) => {
+                "UnprocessableEntityException"
+            }
+        }
+    }
+}
 /// Errors returned by BatchDelete
 #[derive(Debug, PartialEq)]
 pub enum BatchDeleteError {
@@ -7048,6 +7072,20 @@ impl fmt::Display for BatchDeleteError {
     }
 }
 impl Error for BatchDeleteError {}
+impl ServiceErrorCode for BatchDeleteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDeleteError::BadGateway(_) => "BadGatewayException",
+            BatchDeleteError::BadRequest(_) => "BadRequestException",
+            BatchDeleteError::Conflict(_) => "ConflictException",
+            BatchDeleteError::Forbidden(_) => "ForbiddenException",
+            BatchDeleteError::GatewayTimeout(_) => "GatewayTimeoutException",
+            BatchDeleteError::InternalServerError(_) => "InternalServerErrorException",
+            BatchDeleteError::NotFound(_) => "NotFoundException",
+            BatchDeleteError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by BatchStart
 #[derive(Debug, PartialEq)]
 pub enum BatchStartError {
@@ -7120,6 +7158,20 @@ impl fmt::Display for BatchStartError {
     }
 }
 impl Error for BatchStartError {}
+impl ServiceErrorCode for BatchStartError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchStartError::BadGateway(_) => "BadGatewayException",
+            BatchStartError::BadRequest(_) => "BadRequestException",
+            BatchStartError::Conflict(_) => "ConflictException",
+            BatchStartError::Forbidden(_) => "ForbiddenException",
+            BatchStartError::GatewayTimeout(_) => "GatewayTimeoutException",
+            BatchStartError::InternalServerError(_) => "InternalServerErrorException",
+            BatchStartError::NotFound(_) => "NotFoundException",
+            BatchStartError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by BatchStop
 #[derive(Debug, PartialEq)]
 pub enum BatchStopError {
@@ -7192,6 +7244,20 @@ impl fmt::Display for BatchStopError {
     }
 }
 impl Error for BatchStopError {}
+impl ServiceErrorCode for BatchStopError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchStopError::BadGateway(_) => "BadGatewayException",
+            BatchStopError::BadRequest(_) => "BadRequestException",
+            BatchStopError::Conflict(_) => "ConflictException",
+            BatchStopError::Forbidden(_) => "ForbiddenException",
+            BatchStopError::GatewayTimeout(_) => "GatewayTimeoutException",
+            BatchStopError::InternalServerError(_) => "InternalServerErrorException",
+            BatchStopError::NotFound(_) => "NotFoundException",
+            BatchStopError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by BatchUpdateSchedule
 #[derive(Debug, PartialEq)]
 pub enum BatchUpdateScheduleError {
@@ -7268,6 +7334,20 @@ impl fmt::Display for BatchUpdateScheduleError {
     }
 }
 impl Error for BatchUpdateScheduleError {}
+impl ServiceErrorCode for BatchUpdateScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchUpdateScheduleError::BadGateway(_) => "BadGatewayException",
+            BatchUpdateScheduleError::BadRequest(_) => "BadRequestException",
+            BatchUpdateScheduleError::Forbidden(_) => "ForbiddenException",
+            BatchUpdateScheduleError::GatewayTimeout(_) => "GatewayTimeoutException",
+            BatchUpdateScheduleError::InternalServerError(_) => "InternalServerErrorException",
+            BatchUpdateScheduleError::NotFound(_) => "NotFoundException",
+            BatchUpdateScheduleError::TooManyRequests(_) => "TooManyRequestsException",
+            BatchUpdateScheduleError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by CancelInputDeviceTransfer
 #[derive(Debug, PartialEq)]
 pub enum CancelInputDeviceTransferError {
@@ -7362,6 +7442,25 @@ impl fmt::Display for CancelInputDeviceTransferError {
     }
 }
 impl Error for CancelInputDeviceTransferError {}
+impl ServiceErrorCode for CancelInputDeviceTransferError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelInputDeviceTransferError::BadGateway(_) => "BadGatewayException",
+            CancelInputDeviceTransferError::BadRequest(_) => "BadRequestException",
+            CancelInputDeviceTransferError::Conflict(_) => "ConflictException",
+            CancelInputDeviceTransferError::Forbidden(_) => "ForbiddenException",
+            CancelInputDeviceTransferError::GatewayTimeout(_) => "GatewayTimeoutException",
+            CancelInputDeviceTransferError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            CancelInputDeviceTransferError::NotFound(_) => "NotFoundException",
+            CancelInputDeviceTransferError::TooManyRequests(_) => "TooManyRequestsException",
+            CancelInputDeviceTransferError::UnprocessableEntity(_) => {
+                "UnprocessableEntityException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateChannel
 #[derive(Debug, PartialEq)]
 pub enum CreateChannelError {
@@ -7434,6 +7533,20 @@ impl fmt::Display for CreateChannelError {
     }
 }
 impl Error for CreateChannelError {}
+impl ServiceErrorCode for CreateChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateChannelError::BadGateway(_) => "BadGatewayException",
+            CreateChannelError::BadRequest(_) => "BadRequestException",
+            CreateChannelError::Conflict(_) => "ConflictException",
+            CreateChannelError::Forbidden(_) => "ForbiddenException",
+            CreateChannelError::GatewayTimeout(_) => "GatewayTimeoutException",
+            CreateChannelError::InternalServerError(_) => "InternalServerErrorException",
+            CreateChannelError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateChannelError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by CreateInput
 #[derive(Debug, PartialEq)]
 pub enum CreateInputError {
@@ -7494,6 +7607,18 @@ impl fmt::Display for CreateInputError {
     }
 }
 impl Error for CreateInputError {}
+impl ServiceErrorCode for CreateInputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateInputError::BadGateway(_) => "BadGatewayException",
+            CreateInputError::BadRequest(_) => "BadRequestException",
+            CreateInputError::Forbidden(_) => "ForbiddenException",
+            CreateInputError::GatewayTimeout(_) => "GatewayTimeoutException",
+            CreateInputError::InternalServerError(_) => "InternalServerErrorException",
+            CreateInputError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateInputSecurityGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateInputSecurityGroupError {
@@ -7560,6 +7685,18 @@ impl fmt::Display for CreateInputSecurityGroupError {
     }
 }
 impl Error for CreateInputSecurityGroupError {}
+impl ServiceErrorCode for CreateInputSecurityGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateInputSecurityGroupError::BadGateway(_) => "BadGatewayException",
+            CreateInputSecurityGroupError::BadRequest(_) => "BadRequestException",
+            CreateInputSecurityGroupError::Forbidden(_) => "ForbiddenException",
+            CreateInputSecurityGroupError::GatewayTimeout(_) => "GatewayTimeoutException",
+            CreateInputSecurityGroupError::InternalServerError(_) => "InternalServerErrorException",
+            CreateInputSecurityGroupError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateMultiplex
 #[derive(Debug, PartialEq)]
 pub enum CreateMultiplexError {
@@ -7632,6 +7769,20 @@ impl fmt::Display for CreateMultiplexError {
     }
 }
 impl Error for CreateMultiplexError {}
+impl ServiceErrorCode for CreateMultiplexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMultiplexError::BadGateway(_) => "BadGatewayException",
+            CreateMultiplexError::BadRequest(_) => "BadRequestException",
+            CreateMultiplexError::Conflict(_) => "ConflictException",
+            CreateMultiplexError::Forbidden(_) => "ForbiddenException",
+            CreateMultiplexError::GatewayTimeout(_) => "GatewayTimeoutException",
+            CreateMultiplexError::InternalServerError(_) => "InternalServerErrorException",
+            CreateMultiplexError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateMultiplexError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by CreateMultiplexProgram
 #[derive(Debug, PartialEq)]
 pub enum CreateMultiplexProgramError {
@@ -7712,6 +7863,20 @@ impl fmt::Display for CreateMultiplexProgramError {
     }
 }
 impl Error for CreateMultiplexProgramError {}
+impl ServiceErrorCode for CreateMultiplexProgramError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMultiplexProgramError::BadGateway(_) => "BadGatewayException",
+            CreateMultiplexProgramError::BadRequest(_) => "BadRequestException",
+            CreateMultiplexProgramError::Conflict(_) => "ConflictException",
+            CreateMultiplexProgramError::Forbidden(_) => "ForbiddenException",
+            CreateMultiplexProgramError::GatewayTimeout(_) => "GatewayTimeoutException",
+            CreateMultiplexProgramError::InternalServerError(_) => "InternalServerErrorException",
+            CreateMultiplexProgramError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateMultiplexProgramError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by CreateTags
 #[derive(Debug, PartialEq)]
 pub enum CreateTagsError {
@@ -7760,6 +7925,16 @@ impl fmt::Display for CreateTagsError {
     }
 }
 impl Error for CreateTagsError {}
+impl ServiceErrorCode for CreateTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTagsError::BadRequest(_) => "BadRequestException",
+            CreateTagsError::Forbidden(_) => "ForbiddenException",
+            CreateTagsError::InternalServerError(_) => "InternalServerErrorException",
+            CreateTagsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteChannel
 #[derive(Debug, PartialEq)]
 pub enum DeleteChannelError {
@@ -7832,6 +8007,20 @@ impl fmt::Display for DeleteChannelError {
     }
 }
 impl Error for DeleteChannelError {}
+impl ServiceErrorCode for DeleteChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteChannelError::BadGateway(_) => "BadGatewayException",
+            DeleteChannelError::BadRequest(_) => "BadRequestException",
+            DeleteChannelError::Conflict(_) => "ConflictException",
+            DeleteChannelError::Forbidden(_) => "ForbiddenException",
+            DeleteChannelError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DeleteChannelError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteChannelError::NotFound(_) => "NotFoundException",
+            DeleteChannelError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteInput
 #[derive(Debug, PartialEq)]
 pub enum DeleteInputError {
@@ -7904,6 +8093,20 @@ impl fmt::Display for DeleteInputError {
     }
 }
 impl Error for DeleteInputError {}
+impl ServiceErrorCode for DeleteInputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInputError::BadGateway(_) => "BadGatewayException",
+            DeleteInputError::BadRequest(_) => "BadRequestException",
+            DeleteInputError::Conflict(_) => "ConflictException",
+            DeleteInputError::Forbidden(_) => "ForbiddenException",
+            DeleteInputError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DeleteInputError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteInputError::NotFound(_) => "NotFoundException",
+            DeleteInputError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteInputSecurityGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteInputSecurityGroupError {
@@ -7976,6 +8179,19 @@ impl fmt::Display for DeleteInputSecurityGroupError {
     }
 }
 impl Error for DeleteInputSecurityGroupError {}
+impl ServiceErrorCode for DeleteInputSecurityGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInputSecurityGroupError::BadGateway(_) => "BadGatewayException",
+            DeleteInputSecurityGroupError::BadRequest(_) => "BadRequestException",
+            DeleteInputSecurityGroupError::Forbidden(_) => "ForbiddenException",
+            DeleteInputSecurityGroupError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DeleteInputSecurityGroupError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteInputSecurityGroupError::NotFound(_) => "NotFoundException",
+            DeleteInputSecurityGroupError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteMultiplex
 #[derive(Debug, PartialEq)]
 pub enum DeleteMultiplexError {
@@ -8048,6 +8264,20 @@ impl fmt::Display for DeleteMultiplexError {
     }
 }
 impl Error for DeleteMultiplexError {}
+impl ServiceErrorCode for DeleteMultiplexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMultiplexError::BadGateway(_) => "BadGatewayException",
+            DeleteMultiplexError::BadRequest(_) => "BadRequestException",
+            DeleteMultiplexError::Conflict(_) => "ConflictException",
+            DeleteMultiplexError::Forbidden(_) => "ForbiddenException",
+            DeleteMultiplexError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DeleteMultiplexError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteMultiplexError::NotFound(_) => "NotFoundException",
+            DeleteMultiplexError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteMultiplexProgram
 #[derive(Debug, PartialEq)]
 pub enum DeleteMultiplexProgramError {
@@ -8126,6 +8356,20 @@ impl fmt::Display for DeleteMultiplexProgramError {
     }
 }
 impl Error for DeleteMultiplexProgramError {}
+impl ServiceErrorCode for DeleteMultiplexProgramError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMultiplexProgramError::BadGateway(_) => "BadGatewayException",
+            DeleteMultiplexProgramError::BadRequest(_) => "BadRequestException",
+            DeleteMultiplexProgramError::Conflict(_) => "ConflictException",
+            DeleteMultiplexProgramError::Forbidden(_) => "ForbiddenException",
+            DeleteMultiplexProgramError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DeleteMultiplexProgramError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteMultiplexProgramError::NotFound(_) => "NotFoundException",
+            DeleteMultiplexProgramError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteReservation
 #[derive(Debug, PartialEq)]
 pub enum DeleteReservationError {
@@ -8200,6 +8444,20 @@ impl fmt::Display for DeleteReservationError {
     }
 }
 impl Error for DeleteReservationError {}
+impl ServiceErrorCode for DeleteReservationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteReservationError::BadGateway(_) => "BadGatewayException",
+            DeleteReservationError::BadRequest(_) => "BadRequestException",
+            DeleteReservationError::Conflict(_) => "ConflictException",
+            DeleteReservationError::Forbidden(_) => "ForbiddenException",
+            DeleteReservationError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DeleteReservationError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteReservationError::NotFound(_) => "NotFoundException",
+            DeleteReservationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteSchedule
 #[derive(Debug, PartialEq)]
 pub enum DeleteScheduleError {
@@ -8266,6 +8524,19 @@ impl fmt::Display for DeleteScheduleError {
     }
 }
 impl Error for DeleteScheduleError {}
+impl ServiceErrorCode for DeleteScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteScheduleError::BadGateway(_) => "BadGatewayException",
+            DeleteScheduleError::BadRequest(_) => "BadRequestException",
+            DeleteScheduleError::Forbidden(_) => "ForbiddenException",
+            DeleteScheduleError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DeleteScheduleError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteScheduleError::NotFound(_) => "NotFoundException",
+            DeleteScheduleError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
 pub enum DeleteTagsError {
@@ -8314,6 +8585,16 @@ impl fmt::Display for DeleteTagsError {
     }
 }
 impl Error for DeleteTagsError {}
+impl ServiceErrorCode for DeleteTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTagsError::BadRequest(_) => "BadRequestException",
+            DeleteTagsError::Forbidden(_) => "ForbiddenException",
+            DeleteTagsError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteTagsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeChannel
 #[derive(Debug, PartialEq)]
 pub enum DescribeChannelError {
@@ -8380,6 +8661,19 @@ impl fmt::Display for DescribeChannelError {
     }
 }
 impl Error for DescribeChannelError {}
+impl ServiceErrorCode for DescribeChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeChannelError::BadGateway(_) => "BadGatewayException",
+            DescribeChannelError::BadRequest(_) => "BadRequestException",
+            DescribeChannelError::Forbidden(_) => "ForbiddenException",
+            DescribeChannelError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DescribeChannelError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeChannelError::NotFound(_) => "NotFoundException",
+            DescribeChannelError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeInput
 #[derive(Debug, PartialEq)]
 pub enum DescribeInputError {
@@ -8446,6 +8740,19 @@ impl fmt::Display for DescribeInputError {
     }
 }
 impl Error for DescribeInputError {}
+impl ServiceErrorCode for DescribeInputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInputError::BadGateway(_) => "BadGatewayException",
+            DescribeInputError::BadRequest(_) => "BadRequestException",
+            DescribeInputError::Forbidden(_) => "ForbiddenException",
+            DescribeInputError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DescribeInputError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeInputError::NotFound(_) => "NotFoundException",
+            DescribeInputError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeInputDevice
 #[derive(Debug, PartialEq)]
 pub enum DescribeInputDeviceError {
@@ -8514,6 +8821,19 @@ impl fmt::Display for DescribeInputDeviceError {
     }
 }
 impl Error for DescribeInputDeviceError {}
+impl ServiceErrorCode for DescribeInputDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInputDeviceError::BadGateway(_) => "BadGatewayException",
+            DescribeInputDeviceError::BadRequest(_) => "BadRequestException",
+            DescribeInputDeviceError::Forbidden(_) => "ForbiddenException",
+            DescribeInputDeviceError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DescribeInputDeviceError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeInputDeviceError::NotFound(_) => "NotFoundException",
+            DescribeInputDeviceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeInputDeviceThumbnail
 #[derive(Debug, PartialEq)]
 pub enum DescribeInputDeviceThumbnailError {
@@ -8598,6 +8918,21 @@ impl fmt::Display for DescribeInputDeviceThumbnailError {
     }
 }
 impl Error for DescribeInputDeviceThumbnailError {}
+impl ServiceErrorCode for DescribeInputDeviceThumbnailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInputDeviceThumbnailError::BadGateway(_) => "BadGatewayException",
+            DescribeInputDeviceThumbnailError::BadRequest(_) => "BadRequestException",
+            DescribeInputDeviceThumbnailError::Forbidden(_) => "ForbiddenException",
+            DescribeInputDeviceThumbnailError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DescribeInputDeviceThumbnailError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DescribeInputDeviceThumbnailError::NotFound(_) => "NotFoundException",
+            DescribeInputDeviceThumbnailError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeInputSecurityGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeInputSecurityGroupError {
@@ -8680,6 +9015,21 @@ impl fmt::Display for DescribeInputSecurityGroupError {
     }
 }
 impl Error for DescribeInputSecurityGroupError {}
+impl ServiceErrorCode for DescribeInputSecurityGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInputSecurityGroupError::BadGateway(_) => "BadGatewayException",
+            DescribeInputSecurityGroupError::BadRequest(_) => "BadRequestException",
+            DescribeInputSecurityGroupError::Forbidden(_) => "ForbiddenException",
+            DescribeInputSecurityGroupError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DescribeInputSecurityGroupError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DescribeInputSecurityGroupError::NotFound(_) => "NotFoundException",
+            DescribeInputSecurityGroupError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeMultiplex
 #[derive(Debug, PartialEq)]
 pub enum DescribeMultiplexError {
@@ -8748,6 +9098,19 @@ impl fmt::Display for DescribeMultiplexError {
     }
 }
 impl Error for DescribeMultiplexError {}
+impl ServiceErrorCode for DescribeMultiplexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMultiplexError::BadGateway(_) => "BadGatewayException",
+            DescribeMultiplexError::BadRequest(_) => "BadRequestException",
+            DescribeMultiplexError::Forbidden(_) => "ForbiddenException",
+            DescribeMultiplexError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DescribeMultiplexError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeMultiplexError::NotFound(_) => "NotFoundException",
+            DescribeMultiplexError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeMultiplexProgram
 #[derive(Debug, PartialEq)]
 pub enum DescribeMultiplexProgramError {
@@ -8820,6 +9183,19 @@ impl fmt::Display for DescribeMultiplexProgramError {
     }
 }
 impl Error for DescribeMultiplexProgramError {}
+impl ServiceErrorCode for DescribeMultiplexProgramError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMultiplexProgramError::BadGateway(_) => "BadGatewayException",
+            DescribeMultiplexProgramError::BadRequest(_) => "BadRequestException",
+            DescribeMultiplexProgramError::Forbidden(_) => "ForbiddenException",
+            DescribeMultiplexProgramError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DescribeMultiplexProgramError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeMultiplexProgramError::NotFound(_) => "NotFoundException",
+            DescribeMultiplexProgramError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeOffering
 #[derive(Debug, PartialEq)]
 pub enum DescribeOfferingError {
@@ -8888,6 +9264,19 @@ impl fmt::Display for DescribeOfferingError {
     }
 }
 impl Error for DescribeOfferingError {}
+impl ServiceErrorCode for DescribeOfferingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeOfferingError::BadGateway(_) => "BadGatewayException",
+            DescribeOfferingError::BadRequest(_) => "BadRequestException",
+            DescribeOfferingError::Forbidden(_) => "ForbiddenException",
+            DescribeOfferingError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DescribeOfferingError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeOfferingError::NotFound(_) => "NotFoundException",
+            DescribeOfferingError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeReservation
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservationError {
@@ -8956,6 +9345,19 @@ impl fmt::Display for DescribeReservationError {
     }
 }
 impl Error for DescribeReservationError {}
+impl ServiceErrorCode for DescribeReservationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReservationError::BadGateway(_) => "BadGatewayException",
+            DescribeReservationError::BadRequest(_) => "BadRequestException",
+            DescribeReservationError::Forbidden(_) => "ForbiddenException",
+            DescribeReservationError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DescribeReservationError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeReservationError::NotFound(_) => "NotFoundException",
+            DescribeReservationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeSchedule
 #[derive(Debug, PartialEq)]
 pub enum DescribeScheduleError {
@@ -9024,6 +9426,19 @@ impl fmt::Display for DescribeScheduleError {
     }
 }
 impl Error for DescribeScheduleError {}
+impl ServiceErrorCode for DescribeScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeScheduleError::BadGateway(_) => "BadGatewayException",
+            DescribeScheduleError::BadRequest(_) => "BadRequestException",
+            DescribeScheduleError::Forbidden(_) => "ForbiddenException",
+            DescribeScheduleError::GatewayTimeout(_) => "GatewayTimeoutException",
+            DescribeScheduleError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeScheduleError::NotFound(_) => "NotFoundException",
+            DescribeScheduleError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListChannels
 #[derive(Debug, PartialEq)]
 pub enum ListChannelsError {
@@ -9084,6 +9499,18 @@ impl fmt::Display for ListChannelsError {
     }
 }
 impl Error for ListChannelsError {}
+impl ServiceErrorCode for ListChannelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListChannelsError::BadGateway(_) => "BadGatewayException",
+            ListChannelsError::BadRequest(_) => "BadRequestException",
+            ListChannelsError::Forbidden(_) => "ForbiddenException",
+            ListChannelsError::GatewayTimeout(_) => "GatewayTimeoutException",
+            ListChannelsError::InternalServerError(_) => "InternalServerErrorException",
+            ListChannelsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListInputDeviceTransfers
 #[derive(Debug, PartialEq)]
 pub enum ListInputDeviceTransfersError {
@@ -9158,6 +9585,19 @@ impl fmt::Display for ListInputDeviceTransfersError {
     }
 }
 impl Error for ListInputDeviceTransfersError {}
+impl ServiceErrorCode for ListInputDeviceTransfersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInputDeviceTransfersError::BadGateway(_) => "BadGatewayException",
+            ListInputDeviceTransfersError::BadRequest(_) => "BadRequestException",
+            ListInputDeviceTransfersError::Forbidden(_) => "ForbiddenException",
+            ListInputDeviceTransfersError::GatewayTimeout(_) => "GatewayTimeoutException",
+            ListInputDeviceTransfersError::InternalServerError(_) => "InternalServerErrorException",
+            ListInputDeviceTransfersError::TooManyRequests(_) => "TooManyRequestsException",
+            ListInputDeviceTransfersError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by ListInputDevices
 #[derive(Debug, PartialEq)]
 pub enum ListInputDevicesError {
@@ -9220,6 +9660,18 @@ impl fmt::Display for ListInputDevicesError {
     }
 }
 impl Error for ListInputDevicesError {}
+impl ServiceErrorCode for ListInputDevicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInputDevicesError::BadGateway(_) => "BadGatewayException",
+            ListInputDevicesError::BadRequest(_) => "BadRequestException",
+            ListInputDevicesError::Forbidden(_) => "ForbiddenException",
+            ListInputDevicesError::GatewayTimeout(_) => "GatewayTimeoutException",
+            ListInputDevicesError::InternalServerError(_) => "InternalServerErrorException",
+            ListInputDevicesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListInputSecurityGroups
 #[derive(Debug, PartialEq)]
 pub enum ListInputSecurityGroupsError {
@@ -9286,6 +9738,18 @@ impl fmt::Display for ListInputSecurityGroupsError {
     }
 }
 impl Error for ListInputSecurityGroupsError {}
+impl ServiceErrorCode for ListInputSecurityGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInputSecurityGroupsError::BadGateway(_) => "BadGatewayException",
+            ListInputSecurityGroupsError::BadRequest(_) => "BadRequestException",
+            ListInputSecurityGroupsError::Forbidden(_) => "ForbiddenException",
+            ListInputSecurityGroupsError::GatewayTimeout(_) => "GatewayTimeoutException",
+            ListInputSecurityGroupsError::InternalServerError(_) => "InternalServerErrorException",
+            ListInputSecurityGroupsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListInputs
 #[derive(Debug, PartialEq)]
 pub enum ListInputsError {
@@ -9346,6 +9810,18 @@ impl fmt::Display for ListInputsError {
     }
 }
 impl Error for ListInputsError {}
+impl ServiceErrorCode for ListInputsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInputsError::BadGateway(_) => "BadGatewayException",
+            ListInputsError::BadRequest(_) => "BadRequestException",
+            ListInputsError::Forbidden(_) => "ForbiddenException",
+            ListInputsError::GatewayTimeout(_) => "GatewayTimeoutException",
+            ListInputsError::InternalServerError(_) => "InternalServerErrorException",
+            ListInputsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListMultiplexPrograms
 #[derive(Debug, PartialEq)]
 pub enum ListMultiplexProgramsError {
@@ -9418,6 +9894,19 @@ impl fmt::Display for ListMultiplexProgramsError {
     }
 }
 impl Error for ListMultiplexProgramsError {}
+impl ServiceErrorCode for ListMultiplexProgramsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMultiplexProgramsError::BadGateway(_) => "BadGatewayException",
+            ListMultiplexProgramsError::BadRequest(_) => "BadRequestException",
+            ListMultiplexProgramsError::Forbidden(_) => "ForbiddenException",
+            ListMultiplexProgramsError::GatewayTimeout(_) => "GatewayTimeoutException",
+            ListMultiplexProgramsError::InternalServerError(_) => "InternalServerErrorException",
+            ListMultiplexProgramsError::NotFound(_) => "NotFoundException",
+            ListMultiplexProgramsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListMultiplexes
 #[derive(Debug, PartialEq)]
 pub enum ListMultiplexesError {
@@ -9478,6 +9967,18 @@ impl fmt::Display for ListMultiplexesError {
     }
 }
 impl Error for ListMultiplexesError {}
+impl ServiceErrorCode for ListMultiplexesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMultiplexesError::BadGateway(_) => "BadGatewayException",
+            ListMultiplexesError::BadRequest(_) => "BadRequestException",
+            ListMultiplexesError::Forbidden(_) => "ForbiddenException",
+            ListMultiplexesError::GatewayTimeout(_) => "GatewayTimeoutException",
+            ListMultiplexesError::InternalServerError(_) => "InternalServerErrorException",
+            ListMultiplexesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListOfferings
 #[derive(Debug, PartialEq)]
 pub enum ListOfferingsError {
@@ -9538,6 +10039,18 @@ impl fmt::Display for ListOfferingsError {
     }
 }
 impl Error for ListOfferingsError {}
+impl ServiceErrorCode for ListOfferingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOfferingsError::BadGateway(_) => "BadGatewayException",
+            ListOfferingsError::BadRequest(_) => "BadRequestException",
+            ListOfferingsError::Forbidden(_) => "ForbiddenException",
+            ListOfferingsError::GatewayTimeout(_) => "GatewayTimeoutException",
+            ListOfferingsError::InternalServerError(_) => "InternalServerErrorException",
+            ListOfferingsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListReservations
 #[derive(Debug, PartialEq)]
 pub enum ListReservationsError {
@@ -9600,6 +10113,18 @@ impl fmt::Display for ListReservationsError {
     }
 }
 impl Error for ListReservationsError {}
+impl ServiceErrorCode for ListReservationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListReservationsError::BadGateway(_) => "BadGatewayException",
+            ListReservationsError::BadRequest(_) => "BadRequestException",
+            ListReservationsError::Forbidden(_) => "ForbiddenException",
+            ListReservationsError::GatewayTimeout(_) => "GatewayTimeoutException",
+            ListReservationsError::InternalServerError(_) => "InternalServerErrorException",
+            ListReservationsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -9650,6 +10175,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::Forbidden(_) => "ForbiddenException",
+            ListTagsForResourceError::InternalServerError(_) => "InternalServerErrorException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by PurchaseOffering
 #[derive(Debug, PartialEq)]
 pub enum PurchaseOfferingError {
@@ -9724,6 +10259,20 @@ impl fmt::Display for PurchaseOfferingError {
     }
 }
 impl Error for PurchaseOfferingError {}
+impl ServiceErrorCode for PurchaseOfferingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PurchaseOfferingError::BadGateway(_) => "BadGatewayException",
+            PurchaseOfferingError::BadRequest(_) => "BadRequestException",
+            PurchaseOfferingError::Conflict(_) => "ConflictException",
+            PurchaseOfferingError::Forbidden(_) => "ForbiddenException",
+            PurchaseOfferingError::GatewayTimeout(_) => "GatewayTimeoutException",
+            PurchaseOfferingError::InternalServerError(_) => "InternalServerErrorException",
+            PurchaseOfferingError::NotFound(_) => "NotFoundException",
+            PurchaseOfferingError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by RejectInputDeviceTransfer
 #[derive(Debug, PartialEq)]
 pub enum RejectInputDeviceTransferError {
@@ -9818,6 +10367,25 @@ impl fmt::Display for RejectInputDeviceTransferError {
     }
 }
 impl Error for RejectInputDeviceTransferError {}
+impl ServiceErrorCode for RejectInputDeviceTransferError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RejectInputDeviceTransferError::BadGateway(_) => "BadGatewayException",
+            RejectInputDeviceTransferError::BadRequest(_) => "BadRequestException",
+            RejectInputDeviceTransferError::Conflict(_) => "ConflictException",
+            RejectInputDeviceTransferError::Forbidden(_) => "ForbiddenException",
+            RejectInputDeviceTransferError::GatewayTimeout(_) => "GatewayTimeoutException",
+            RejectInputDeviceTransferError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            RejectInputDeviceTransferError::NotFound(_) => "NotFoundException",
+            RejectInputDeviceTransferError::TooManyRequests(_) => "TooManyRequestsException",
+            RejectInputDeviceTransferError::UnprocessableEntity(_) => {
+                "UnprocessableEntityException"
+            }
+        }
+    }
+}
 /// Errors returned by StartChannel
 #[derive(Debug, PartialEq)]
 pub enum StartChannelError {
@@ -9890,6 +10458,20 @@ impl fmt::Display for StartChannelError {
     }
 }
 impl Error for StartChannelError {}
+impl ServiceErrorCode for StartChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartChannelError::BadGateway(_) => "BadGatewayException",
+            StartChannelError::BadRequest(_) => "BadRequestException",
+            StartChannelError::Conflict(_) => "ConflictException",
+            StartChannelError::Forbidden(_) => "ForbiddenException",
+            StartChannelError::GatewayTimeout(_) => "GatewayTimeoutException",
+            StartChannelError::InternalServerError(_) => "InternalServerErrorException",
+            StartChannelError::NotFound(_) => "NotFoundException",
+            StartChannelError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StartMultiplex
 #[derive(Debug, PartialEq)]
 pub enum StartMultiplexError {
@@ -9962,6 +10544,20 @@ impl fmt::Display for StartMultiplexError {
     }
 }
 impl Error for StartMultiplexError {}
+impl ServiceErrorCode for StartMultiplexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartMultiplexError::BadGateway(_) => "BadGatewayException",
+            StartMultiplexError::BadRequest(_) => "BadRequestException",
+            StartMultiplexError::Conflict(_) => "ConflictException",
+            StartMultiplexError::Forbidden(_) => "ForbiddenException",
+            StartMultiplexError::GatewayTimeout(_) => "GatewayTimeoutException",
+            StartMultiplexError::InternalServerError(_) => "InternalServerErrorException",
+            StartMultiplexError::NotFound(_) => "NotFoundException",
+            StartMultiplexError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StopChannel
 #[derive(Debug, PartialEq)]
 pub enum StopChannelError {
@@ -10034,6 +10630,20 @@ impl fmt::Display for StopChannelError {
     }
 }
 impl Error for StopChannelError {}
+impl ServiceErrorCode for StopChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopChannelError::BadGateway(_) => "BadGatewayException",
+            StopChannelError::BadRequest(_) => "BadRequestException",
+            StopChannelError::Conflict(_) => "ConflictException",
+            StopChannelError::Forbidden(_) => "ForbiddenException",
+            StopChannelError::GatewayTimeout(_) => "GatewayTimeoutException",
+            StopChannelError::InternalServerError(_) => "InternalServerErrorException",
+            StopChannelError::NotFound(_) => "NotFoundException",
+            StopChannelError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StopMultiplex
 #[derive(Debug, PartialEq)]
 pub enum StopMultiplexError {
@@ -10106,6 +10716,20 @@ impl fmt::Display for StopMultiplexError {
     }
 }
 impl Error for StopMultiplexError {}
+impl ServiceErrorCode for StopMultiplexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopMultiplexError::BadGateway(_) => "BadGatewayException",
+            StopMultiplexError::BadRequest(_) => "BadRequestException",
+            StopMultiplexError::Conflict(_) => "ConflictException",
+            StopMultiplexError::Forbidden(_) => "ForbiddenException",
+            StopMultiplexError::GatewayTimeout(_) => "GatewayTimeoutException",
+            StopMultiplexError::InternalServerError(_) => "InternalServerErrorException",
+            StopMultiplexError::NotFound(_) => "NotFoundException",
+            StopMultiplexError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TransferInputDevice
 #[derive(Debug, PartialEq)]
 pub enum TransferInputDeviceError {
@@ -10188,6 +10812,21 @@ impl fmt::Display for TransferInputDeviceError {
     }
 }
 impl Error for TransferInputDeviceError {}
+impl ServiceErrorCode for TransferInputDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TransferInputDeviceError::BadGateway(_) => "BadGatewayException",
+            TransferInputDeviceError::BadRequest(_) => "BadRequestException",
+            TransferInputDeviceError::Conflict(_) => "ConflictException",
+            TransferInputDeviceError::Forbidden(_) => "ForbiddenException",
+            TransferInputDeviceError::GatewayTimeout(_) => "GatewayTimeoutException",
+            TransferInputDeviceError::InternalServerError(_) => "InternalServerErrorException",
+            TransferInputDeviceError::NotFound(_) => "NotFoundException",
+            TransferInputDeviceError::TooManyRequests(_) => "TooManyRequestsException",
+            TransferInputDeviceError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by UpdateChannel
 #[derive(Debug, PartialEq)]
 pub enum UpdateChannelError {
@@ -10254,6 +10893,19 @@ impl fmt::Display for UpdateChannelError {
     }
 }
 impl Error for UpdateChannelError {}
+impl ServiceErrorCode for UpdateChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateChannelError::BadGateway(_) => "BadGatewayException",
+            UpdateChannelError::BadRequest(_) => "BadRequestException",
+            UpdateChannelError::Conflict(_) => "ConflictException",
+            UpdateChannelError::Forbidden(_) => "ForbiddenException",
+            UpdateChannelError::GatewayTimeout(_) => "GatewayTimeoutException",
+            UpdateChannelError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateChannelError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by UpdateChannelClass
 #[derive(Debug, PartialEq)]
 pub enum UpdateChannelClassError {
@@ -10336,6 +10988,21 @@ impl fmt::Display for UpdateChannelClassError {
     }
 }
 impl Error for UpdateChannelClassError {}
+impl ServiceErrorCode for UpdateChannelClassError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateChannelClassError::BadGateway(_) => "BadGatewayException",
+            UpdateChannelClassError::BadRequest(_) => "BadRequestException",
+            UpdateChannelClassError::Conflict(_) => "ConflictException",
+            UpdateChannelClassError::Forbidden(_) => "ForbiddenException",
+            UpdateChannelClassError::GatewayTimeout(_) => "GatewayTimeoutException",
+            UpdateChannelClassError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateChannelClassError::NotFound(_) => "NotFoundException",
+            UpdateChannelClassError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateChannelClassError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by UpdateInput
 #[derive(Debug, PartialEq)]
 pub enum UpdateInputError {
@@ -10402,6 +11069,19 @@ impl fmt::Display for UpdateInputError {
     }
 }
 impl Error for UpdateInputError {}
+impl ServiceErrorCode for UpdateInputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateInputError::BadGateway(_) => "BadGatewayException",
+            UpdateInputError::BadRequest(_) => "BadRequestException",
+            UpdateInputError::Conflict(_) => "ConflictException",
+            UpdateInputError::Forbidden(_) => "ForbiddenException",
+            UpdateInputError::GatewayTimeout(_) => "GatewayTimeoutException",
+            UpdateInputError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateInputError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateInputDevice
 #[derive(Debug, PartialEq)]
 pub enum UpdateInputDeviceError {
@@ -10478,6 +11158,20 @@ impl fmt::Display for UpdateInputDeviceError {
     }
 }
 impl Error for UpdateInputDeviceError {}
+impl ServiceErrorCode for UpdateInputDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateInputDeviceError::BadGateway(_) => "BadGatewayException",
+            UpdateInputDeviceError::BadRequest(_) => "BadRequestException",
+            UpdateInputDeviceError::Forbidden(_) => "ForbiddenException",
+            UpdateInputDeviceError::GatewayTimeout(_) => "GatewayTimeoutException",
+            UpdateInputDeviceError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateInputDeviceError::NotFound(_) => "NotFoundException",
+            UpdateInputDeviceError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateInputDeviceError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by UpdateInputSecurityGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateInputSecurityGroupError {
@@ -10548,6 +11242,19 @@ impl fmt::Display for UpdateInputSecurityGroupError {
     }
 }
 impl Error for UpdateInputSecurityGroupError {}
+impl ServiceErrorCode for UpdateInputSecurityGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateInputSecurityGroupError::BadGateway(_) => "BadGatewayException",
+            UpdateInputSecurityGroupError::BadRequest(_) => "BadRequestException",
+            UpdateInputSecurityGroupError::Conflict(_) => "ConflictException",
+            UpdateInputSecurityGroupError::Forbidden(_) => "ForbiddenException",
+            UpdateInputSecurityGroupError::GatewayTimeout(_) => "GatewayTimeoutException",
+            UpdateInputSecurityGroupError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateInputSecurityGroupError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateMultiplex
 #[derive(Debug, PartialEq)]
 pub enum UpdateMultiplexError {
@@ -10620,6 +11327,20 @@ impl fmt::Display for UpdateMultiplexError {
     }
 }
 impl Error for UpdateMultiplexError {}
+impl ServiceErrorCode for UpdateMultiplexError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMultiplexError::BadGateway(_) => "BadGatewayException",
+            UpdateMultiplexError::BadRequest(_) => "BadRequestException",
+            UpdateMultiplexError::Conflict(_) => "ConflictException",
+            UpdateMultiplexError::Forbidden(_) => "ForbiddenException",
+            UpdateMultiplexError::GatewayTimeout(_) => "GatewayTimeoutException",
+            UpdateMultiplexError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateMultiplexError::NotFound(_) => "NotFoundException",
+            UpdateMultiplexError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by UpdateMultiplexProgram
 #[derive(Debug, PartialEq)]
 pub enum UpdateMultiplexProgramError {
@@ -10698,6 +11419,20 @@ impl fmt::Display for UpdateMultiplexProgramError {
     }
 }
 impl Error for UpdateMultiplexProgramError {}
+impl ServiceErrorCode for UpdateMultiplexProgramError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMultiplexProgramError::BadGateway(_) => "BadGatewayException",
+            UpdateMultiplexProgramError::BadRequest(_) => "BadRequestException",
+            UpdateMultiplexProgramError::Conflict(_) => "ConflictException",
+            UpdateMultiplexProgramError::Forbidden(_) => "ForbiddenException",
+            UpdateMultiplexProgramError::GatewayTimeout(_) => "GatewayTimeoutException",
+            UpdateMultiplexProgramError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateMultiplexProgramError::NotFound(_) => "NotFoundException",
+            UpdateMultiplexProgramError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by UpdateReservation
 #[derive(Debug, PartialEq)]
 pub enum UpdateReservationError {
@@ -10772,6 +11507,20 @@ impl fmt::Display for UpdateReservationError {
     }
 }
 impl Error for UpdateReservationError {}
+impl ServiceErrorCode for UpdateReservationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateReservationError::BadGateway(_) => "BadGatewayException",
+            UpdateReservationError::BadRequest(_) => "BadRequestException",
+            UpdateReservationError::Conflict(_) => "ConflictException",
+            UpdateReservationError::Forbidden(_) => "ForbiddenException",
+            UpdateReservationError::GatewayTimeout(_) => "GatewayTimeoutException",
+            UpdateReservationError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateReservationError::NotFound(_) => "NotFoundException",
+            UpdateReservationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the MediaLive API. MediaLive clients implement this trait.
 #[async_trait]
 pub trait MediaLive {
@@ -11342,6 +12091,10 @@ impl MediaLive for MediaLiveClient {
         &self,
         input: CreateChannelRequest,
     ) -> Result<CreateChannelResponse, RusotoError<CreateChannelError>> {
+        let mut input = input;
+        if input.request_id.is_none() {
+            input.request_id = self.client.idempotency_token();
+        }
         let request_uri = "/prod/channels";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
@@ -11373,6 +12126,10 @@ impl MediaLive for MediaLiveClient {
         &self,
         input: CreateInputRequest,
     ) -> Result<CreateInputResponse, RusotoError<CreateInputError>> {
+        let mut input = input;
+        if input.request_id.is_none() {
+            input.request_id = self.client.idempotency_token();
+        }
         let request_uri = "/prod/inputs";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
@@ -11435,6 +12192,12 @@ impl MediaLive for MediaLiveClient {
         &self,
         input: CreateMultiplexRequest,
     ) -> Result<CreateMultiplexResponse, RusotoError<CreateMultiplexError>> {
+        let mut input = input;
+        if input.request_id.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.request_id = token;
+            }
+        }
         let request_uri = "/prod/multiplexes";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
@@ -11466,6 +12229,12 @@ impl MediaLive for MediaLiveClient {
         &self,
         input: CreateMultiplexProgramRequest,
     ) -> Result<CreateMultiplexProgramResponse, RusotoError<CreateMultiplexProgramError>> {
+        let mut input = input;
+        if input.request_id.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.request_id = token;
+            }
+        }
         let request_uri = format!(
             "/prod/multiplexes/{multiplex_id}/programs",
             multiplex_id = input.multiplex_id
@@ -12533,6 +13302,10 @@ impl MediaLive for MediaLiveClient {
         &self,
         input: PurchaseOfferingRequest,
     ) -> Result<PurchaseOfferingResponse, RusotoError<PurchaseOfferingError>> {
+        let mut input = input;
+        if input.request_id.is_none() {
+            input.request_id = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/prod/offerings/{offering_id}/purchase",
             offering_id = input.offering_id
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -905,6 +905,18 @@ impl fmt::Display for CreateAssetError {
     }
 }
 impl Error for CreateAssetError {}
+impl ServiceErrorCode for CreateAssetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAssetError::Forbidden(_) => "ForbiddenException",
+            CreateAssetError::InternalServerError(_) => "InternalServerErrorException",
+            CreateAssetError::NotFound(_) => "NotFoundException",
+            CreateAssetError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateAssetError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateAssetError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by CreatePackagingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum CreatePackagingConfigurationError {
@@ -985,6 +997,24 @@ impl fmt::Display for CreatePackagingConfigurationError {
     }
 }
 impl Error for CreatePackagingConfigurationError {}
+impl ServiceErrorCode for CreatePackagingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePackagingConfigurationError::Forbidden(_) => "ForbiddenException",
+            CreatePackagingConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            CreatePackagingConfigurationError::NotFound(_) => "NotFoundException",
+            CreatePackagingConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            CreatePackagingConfigurationError::TooManyRequests(_) => "TooManyRequestsException",
+            CreatePackagingConfigurationError::UnprocessableEntity(_) => {
+                "UnprocessableEntityException"
+            }
+        }
+    }
+}
 /// Errors returned by CreatePackagingGroup
 #[derive(Debug, PartialEq)]
 pub enum CreatePackagingGroupError {
@@ -1053,6 +1083,18 @@ impl fmt::Display for CreatePackagingGroupError {
     }
 }
 impl Error for CreatePackagingGroupError {}
+impl ServiceErrorCode for CreatePackagingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePackagingGroupError::Forbidden(_) => "ForbiddenException",
+            CreatePackagingGroupError::InternalServerError(_) => "InternalServerErrorException",
+            CreatePackagingGroupError::NotFound(_) => "NotFoundException",
+            CreatePackagingGroupError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreatePackagingGroupError::TooManyRequests(_) => "TooManyRequestsException",
+            CreatePackagingGroupError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by DeleteAsset
 #[derive(Debug, PartialEq)]
 pub enum DeleteAssetError {
@@ -1113,6 +1155,18 @@ impl fmt::Display for DeleteAssetError {
     }
 }
 impl Error for DeleteAssetError {}
+impl ServiceErrorCode for DeleteAssetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAssetError::Forbidden(_) => "ForbiddenException",
+            DeleteAssetError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteAssetError::NotFound(_) => "NotFoundException",
+            DeleteAssetError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteAssetError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteAssetError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by DeletePackagingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeletePackagingConfigurationError {
@@ -1193,6 +1247,24 @@ impl fmt::Display for DeletePackagingConfigurationError {
     }
 }
 impl Error for DeletePackagingConfigurationError {}
+impl ServiceErrorCode for DeletePackagingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePackagingConfigurationError::Forbidden(_) => "ForbiddenException",
+            DeletePackagingConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DeletePackagingConfigurationError::NotFound(_) => "NotFoundException",
+            DeletePackagingConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DeletePackagingConfigurationError::TooManyRequests(_) => "TooManyRequestsException",
+            DeletePackagingConfigurationError::UnprocessableEntity(_) => {
+                "UnprocessableEntityException"
+            }
+        }
+    }
+}
 /// Errors returned by DeletePackagingGroup
 #[derive(Debug, PartialEq)]
 pub enum DeletePackagingGroupError {
@@ -1261,6 +1333,18 @@ impl fmt::Display for DeletePackagingGroupError {
     }
 }
 impl Error for DeletePackagingGroupError {}
+impl ServiceErrorCode for DeletePackagingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePackagingGroupError::Forbidden(_) => "ForbiddenException",
+            DeletePackagingGroupError::InternalServerError(_) => "InternalServerErrorException",
+            DeletePackagingGroupError::NotFound(_) => "NotFoundException",
+            DeletePackagingGroupError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeletePackagingGroupError::TooManyRequests(_) => "TooManyRequestsException",
+            DeletePackagingGroupError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by DescribeAsset
 #[derive(Debug, PartialEq)]
 pub enum DescribeAssetError {
@@ -1321,6 +1405,18 @@ impl fmt::Display for DescribeAssetError {
     }
 }
 impl Error for DescribeAssetError {}
+impl ServiceErrorCode for DescribeAssetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAssetError::Forbidden(_) => "ForbiddenException",
+            DescribeAssetError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeAssetError::NotFound(_) => "NotFoundException",
+            DescribeAssetError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeAssetError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribeAssetError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by DescribePackagingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribePackagingConfigurationError {
@@ -1403,6 +1499,24 @@ impl fmt::Display for DescribePackagingConfigurationError {
     }
 }
 impl Error for DescribePackagingConfigurationError {}
+impl ServiceErrorCode for DescribePackagingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePackagingConfigurationError::Forbidden(_) => "ForbiddenException",
+            DescribePackagingConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DescribePackagingConfigurationError::NotFound(_) => "NotFoundException",
+            DescribePackagingConfigurationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DescribePackagingConfigurationError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribePackagingConfigurationError::UnprocessableEntity(_) => {
+                "UnprocessableEntityException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribePackagingGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribePackagingGroupError {
@@ -1471,6 +1585,18 @@ impl fmt::Display for DescribePackagingGroupError {
     }
 }
 impl Error for DescribePackagingGroupError {}
+impl ServiceErrorCode for DescribePackagingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePackagingGroupError::Forbidden(_) => "ForbiddenException",
+            DescribePackagingGroupError::InternalServerError(_) => "InternalServerErrorException",
+            DescribePackagingGroupError::NotFound(_) => "NotFoundException",
+            DescribePackagingGroupError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribePackagingGroupError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribePackagingGroupError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by ListAssets
 #[derive(Debug, PartialEq)]
 pub enum ListAssetsError {
@@ -1531,6 +1657,18 @@ impl fmt::Display for ListAssetsError {
     }
 }
 impl Error for ListAssetsError {}
+impl ServiceErrorCode for ListAssetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAssetsError::Forbidden(_) => "ForbiddenException",
+            ListAssetsError::InternalServerError(_) => "InternalServerErrorException",
+            ListAssetsError::NotFound(_) => "NotFoundException",
+            ListAssetsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListAssetsError::TooManyRequests(_) => "TooManyRequestsException",
+            ListAssetsError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by ListPackagingConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListPackagingConfigurationsError {
@@ -1611,6 +1749,24 @@ impl fmt::Display for ListPackagingConfigurationsError {
     }
 }
 impl Error for ListPackagingConfigurationsError {}
+impl ServiceErrorCode for ListPackagingConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPackagingConfigurationsError::Forbidden(_) => "ForbiddenException",
+            ListPackagingConfigurationsError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            ListPackagingConfigurationsError::NotFound(_) => "NotFoundException",
+            ListPackagingConfigurationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            ListPackagingConfigurationsError::TooManyRequests(_) => "TooManyRequestsException",
+            ListPackagingConfigurationsError::UnprocessableEntity(_) => {
+                "UnprocessableEntityException"
+            }
+        }
+    }
+}
 /// Errors returned by ListPackagingGroups
 #[derive(Debug, PartialEq)]
 pub enum ListPackagingGroupsError {
@@ -1677,6 +1833,18 @@ impl fmt::Display for ListPackagingGroupsError {
     }
 }
 impl Error for ListPackagingGroupsError {}
+impl ServiceErrorCode for ListPackagingGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPackagingGroupsError::Forbidden(_) => "ForbiddenException",
+            ListPackagingGroupsError::InternalServerError(_) => "InternalServerErrorException",
+            ListPackagingGroupsError::NotFound(_) => "NotFoundException",
+            ListPackagingGroupsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListPackagingGroupsError::TooManyRequests(_) => "TooManyRequestsException",
+            ListPackagingGroupsError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {}
@@ -1699,6 +1867,11 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {}
@@ -1721,6 +1894,11 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {}
@@ -1743,6 +1921,11 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UpdatePackagingGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdatePackagingGroupError {
@@ -1811,6 +1994,18 @@ impl fmt::Display for UpdatePackagingGroupError {
     }
 }
 impl Error for UpdatePackagingGroupError {}
+impl ServiceErrorCode for UpdatePackagingGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePackagingGroupError::Forbidden(_) => "ForbiddenException",
+            UpdatePackagingGroupError::InternalServerError(_) => "InternalServerErrorException",
+            UpdatePackagingGroupError::NotFound(_) => "NotFoundException",
+            UpdatePackagingGroupError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdatePackagingGroupError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdatePackagingGroupError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Trait representing the capabilities of the MediaPackage Vod API. MediaPackage Vod clients implement this trait.
 #[async_trait]
 pub trait MediaPackageVod {
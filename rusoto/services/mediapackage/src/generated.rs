@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1540,6 +1540,18 @@ impl fmt::Display for ConfigureLogsError {
     }
 }
 impl Error for ConfigureLogsError {}
+impl ServiceErrorCode for ConfigureLogsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ConfigureLogsError::Forbidden(_) => "ForbiddenException",
+            ConfigureLogsError::InternalServerError(_) => "InternalServerErrorException",
+            ConfigureLogsError::NotFound(_) => "NotFoundException",
+            ConfigureLogsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ConfigureLogsError::TooManyRequests(_) => "TooManyRequestsException",
+            ConfigureLogsError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by CreateChannel
 #[derive(Debug, PartialEq)]
 pub enum CreateChannelError {
@@ -1600,6 +1612,18 @@ impl fmt::Display for CreateChannelError {
     }
 }
 impl Error for CreateChannelError {}
+impl ServiceErrorCode for CreateChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateChannelError::Forbidden(_) => "ForbiddenException",
+            CreateChannelError::InternalServerError(_) => "InternalServerErrorException",
+            CreateChannelError::NotFound(_) => "NotFoundException",
+            CreateChannelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateChannelError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateChannelError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by CreateHarvestJob
 #[derive(Debug, PartialEq)]
 pub enum CreateHarvestJobError {
@@ -1664,6 +1688,18 @@ impl fmt::Display for CreateHarvestJobError {
     }
 }
 impl Error for CreateHarvestJobError {}
+impl ServiceErrorCode for CreateHarvestJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHarvestJobError::Forbidden(_) => "ForbiddenException",
+            CreateHarvestJobError::InternalServerError(_) => "InternalServerErrorException",
+            CreateHarvestJobError::NotFound(_) => "NotFoundException",
+            CreateHarvestJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateHarvestJobError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateHarvestJobError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by CreateOriginEndpoint
 #[derive(Debug, PartialEq)]
 pub enum CreateOriginEndpointError {
@@ -1732,6 +1768,18 @@ impl fmt::Display for CreateOriginEndpointError {
     }
 }
 impl Error for CreateOriginEndpointError {}
+impl ServiceErrorCode for CreateOriginEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateOriginEndpointError::Forbidden(_) => "ForbiddenException",
+            CreateOriginEndpointError::InternalServerError(_) => "InternalServerErrorException",
+            CreateOriginEndpointError::NotFound(_) => "NotFoundException",
+            CreateOriginEndpointError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateOriginEndpointError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateOriginEndpointError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by DeleteChannel
 #[derive(Debug, PartialEq)]
 pub enum DeleteChannelError {
@@ -1792,6 +1840,18 @@ impl fmt::Display for DeleteChannelError {
     }
 }
 impl Error for DeleteChannelError {}
+impl ServiceErrorCode for DeleteChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteChannelError::Forbidden(_) => "ForbiddenException",
+            DeleteChannelError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteChannelError::NotFound(_) => "NotFoundException",
+            DeleteChannelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteChannelError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteChannelError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by DeleteOriginEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DeleteOriginEndpointError {
@@ -1860,6 +1920,18 @@ impl fmt::Display for DeleteOriginEndpointError {
     }
 }
 impl Error for DeleteOriginEndpointError {}
+impl ServiceErrorCode for DeleteOriginEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteOriginEndpointError::Forbidden(_) => "ForbiddenException",
+            DeleteOriginEndpointError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteOriginEndpointError::NotFound(_) => "NotFoundException",
+            DeleteOriginEndpointError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteOriginEndpointError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteOriginEndpointError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by DescribeChannel
 #[derive(Debug, PartialEq)]
 pub enum DescribeChannelError {
@@ -1920,6 +1992,18 @@ impl fmt::Display for DescribeChannelError {
     }
 }
 impl Error for DescribeChannelError {}
+impl ServiceErrorCode for DescribeChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeChannelError::Forbidden(_) => "ForbiddenException",
+            DescribeChannelError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeChannelError::NotFound(_) => "NotFoundException",
+            DescribeChannelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeChannelError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribeChannelError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by DescribeHarvestJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeHarvestJobError {
@@ -1986,6 +2070,18 @@ impl fmt::Display for DescribeHarvestJobError {
     }
 }
 impl Error for DescribeHarvestJobError {}
+impl ServiceErrorCode for DescribeHarvestJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeHarvestJobError::Forbidden(_) => "ForbiddenException",
+            DescribeHarvestJobError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeHarvestJobError::NotFound(_) => "NotFoundException",
+            DescribeHarvestJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeHarvestJobError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribeHarvestJobError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by DescribeOriginEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DescribeOriginEndpointError {
@@ -2054,6 +2150,18 @@ impl fmt::Display for DescribeOriginEndpointError {
     }
 }
 impl Error for DescribeOriginEndpointError {}
+impl ServiceErrorCode for DescribeOriginEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeOriginEndpointError::Forbidden(_) => "ForbiddenException",
+            DescribeOriginEndpointError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeOriginEndpointError::NotFound(_) => "NotFoundException",
+            DescribeOriginEndpointError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeOriginEndpointError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribeOriginEndpointError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by ListChannels
 #[derive(Debug, PartialEq)]
 pub enum ListChannelsError {
@@ -2114,6 +2222,18 @@ impl fmt::Display for ListChannelsError {
     }
 }
 impl Error for ListChannelsError {}
+impl ServiceErrorCode for ListChannelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListChannelsError::Forbidden(_) => "ForbiddenException",
+            ListChannelsError::InternalServerError(_) => "InternalServerErrorException",
+            ListChannelsError::NotFound(_) => "NotFoundException",
+            ListChannelsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListChannelsError::TooManyRequests(_) => "TooManyRequestsException",
+            ListChannelsError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by ListHarvestJobs
 #[derive(Debug, PartialEq)]
 pub enum ListHarvestJobsError {
@@ -2174,6 +2294,18 @@ impl fmt::Display for ListHarvestJobsError {
     }
 }
 impl Error for ListHarvestJobsError {}
+impl ServiceErrorCode for ListHarvestJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListHarvestJobsError::Forbidden(_) => "ForbiddenException",
+            ListHarvestJobsError::InternalServerError(_) => "InternalServerErrorException",
+            ListHarvestJobsError::NotFound(_) => "NotFoundException",
+            ListHarvestJobsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListHarvestJobsError::TooManyRequests(_) => "TooManyRequestsException",
+            ListHarvestJobsError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by ListOriginEndpoints
 #[derive(Debug, PartialEq)]
 pub enum ListOriginEndpointsError {
@@ -2240,6 +2372,18 @@ impl fmt::Display for ListOriginEndpointsError {
     }
 }
 impl Error for ListOriginEndpointsError {}
+impl ServiceErrorCode for ListOriginEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOriginEndpointsError::Forbidden(_) => "ForbiddenException",
+            ListOriginEndpointsError::InternalServerError(_) => "InternalServerErrorException",
+            ListOriginEndpointsError::NotFound(_) => "NotFoundException",
+            ListOriginEndpointsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListOriginEndpointsError::TooManyRequests(_) => "TooManyRequestsException",
+            ListOriginEndpointsError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {}
@@ -2262,6 +2406,11 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RotateChannelCredentials
 #[derive(Debug, PartialEq)]
 pub enum RotateChannelCredentialsError {
@@ -2330,6 +2479,18 @@ impl fmt::Display for RotateChannelCredentialsError {
     }
 }
 impl Error for RotateChannelCredentialsError {}
+impl ServiceErrorCode for RotateChannelCredentialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RotateChannelCredentialsError::Forbidden(_) => "ForbiddenException",
+            RotateChannelCredentialsError::InternalServerError(_) => "InternalServerErrorException",
+            RotateChannelCredentialsError::NotFound(_) => "NotFoundException",
+            RotateChannelCredentialsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RotateChannelCredentialsError::TooManyRequests(_) => "TooManyRequestsException",
+            RotateChannelCredentialsError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by RotateIngestEndpointCredentials
 #[derive(Debug, PartialEq)]
 pub enum RotateIngestEndpointCredentialsError {
@@ -2412,6 +2573,24 @@ impl fmt::Display for RotateIngestEndpointCredentialsError {
     }
 }
 impl Error for RotateIngestEndpointCredentialsError {}
+impl ServiceErrorCode for RotateIngestEndpointCredentialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RotateIngestEndpointCredentialsError::Forbidden(_) => "ForbiddenException",
+            RotateIngestEndpointCredentialsError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            RotateIngestEndpointCredentialsError::NotFound(_) => "NotFoundException",
+            RotateIngestEndpointCredentialsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            RotateIngestEndpointCredentialsError::TooManyRequests(_) => "TooManyRequestsException",
+            RotateIngestEndpointCredentialsError::UnprocessableEntity(_) => {
+                "UnprocessableEntityException"
+            }
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {}
@@ -2434,6 +2613,11 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {}
@@ -2456,6 +2640,11 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UpdateChannel
 #[derive(Debug, PartialEq)]
 pub enum UpdateChannelError {
@@ -2516,6 +2705,18 @@ impl fmt::Display for UpdateChannelError {
     }
 }
 impl Error for UpdateChannelError {}
+impl ServiceErrorCode for UpdateChannelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateChannelError::Forbidden(_) => "ForbiddenException",
+            UpdateChannelError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateChannelError::NotFound(_) => "NotFoundException",
+            UpdateChannelError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateChannelError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateChannelError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Errors returned by UpdateOriginEndpoint
 #[derive(Debug, PartialEq)]
 pub enum UpdateOriginEndpointError {
@@ -2584,6 +2785,18 @@ impl fmt::Display for UpdateOriginEndpointError {
     }
 }
 impl Error for UpdateOriginEndpointError {}
+impl ServiceErrorCode for UpdateOriginEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateOriginEndpointError::Forbidden(_) => "ForbiddenException",
+            UpdateOriginEndpointError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateOriginEndpointError::NotFound(_) => "NotFoundException",
+            UpdateOriginEndpointError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateOriginEndpointError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateOriginEndpointError::UnprocessableEntity(_) => "UnprocessableEntityException",
+        }
+    }
+}
 /// Trait representing the capabilities of the MediaPackage API. MediaPackage clients implement this trait.
 #[async_trait]
 pub trait MediaPackage {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -498,6 +498,15 @@ impl fmt::Display for CreateContainerError {
     }
 }
 impl Error for CreateContainerError {}
+impl ServiceErrorCode for CreateContainerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateContainerError::ContainerInUse(_) => "ContainerInUseException",
+            CreateContainerError::InternalServerError(_) => "InternalServerError",
+            CreateContainerError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DeleteContainer
 #[derive(Debug, PartialEq)]
 pub enum DeleteContainerError {
@@ -540,6 +549,15 @@ impl fmt::Display for DeleteContainerError {
     }
 }
 impl Error for DeleteContainerError {}
+impl ServiceErrorCode for DeleteContainerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteContainerError::ContainerInUse(_) => "ContainerInUseException",
+            DeleteContainerError::ContainerNotFound(_) => "ContainerNotFoundException",
+            DeleteContainerError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DeleteContainerPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteContainerPolicyError {
@@ -596,6 +614,16 @@ impl fmt::Display for DeleteContainerPolicyError {
     }
 }
 impl Error for DeleteContainerPolicyError {}
+impl ServiceErrorCode for DeleteContainerPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteContainerPolicyError::ContainerInUse(_) => "ContainerInUseException",
+            DeleteContainerPolicyError::ContainerNotFound(_) => "ContainerNotFoundException",
+            DeleteContainerPolicyError::InternalServerError(_) => "InternalServerError",
+            DeleteContainerPolicyError::PolicyNotFound(_) => "PolicyNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteCorsPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteCorsPolicyError {
@@ -646,6 +674,16 @@ impl fmt::Display for DeleteCorsPolicyError {
     }
 }
 impl Error for DeleteCorsPolicyError {}
+impl ServiceErrorCode for DeleteCorsPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCorsPolicyError::ContainerInUse(_) => "ContainerInUseException",
+            DeleteCorsPolicyError::ContainerNotFound(_) => "ContainerNotFoundException",
+            DeleteCorsPolicyError::CorsPolicyNotFound(_) => "CorsPolicyNotFoundException",
+            DeleteCorsPolicyError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DeleteLifecyclePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteLifecyclePolicyError {
@@ -702,6 +740,16 @@ impl fmt::Display for DeleteLifecyclePolicyError {
     }
 }
 impl Error for DeleteLifecyclePolicyError {}
+impl ServiceErrorCode for DeleteLifecyclePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLifecyclePolicyError::ContainerInUse(_) => "ContainerInUseException",
+            DeleteLifecyclePolicyError::ContainerNotFound(_) => "ContainerNotFoundException",
+            DeleteLifecyclePolicyError::InternalServerError(_) => "InternalServerError",
+            DeleteLifecyclePolicyError::PolicyNotFound(_) => "PolicyNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteMetricPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteMetricPolicyError {
@@ -754,6 +802,16 @@ impl fmt::Display for DeleteMetricPolicyError {
     }
 }
 impl Error for DeleteMetricPolicyError {}
+impl ServiceErrorCode for DeleteMetricPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMetricPolicyError::ContainerInUse(_) => "ContainerInUseException",
+            DeleteMetricPolicyError::ContainerNotFound(_) => "ContainerNotFoundException",
+            DeleteMetricPolicyError::InternalServerError(_) => "InternalServerError",
+            DeleteMetricPolicyError::PolicyNotFound(_) => "PolicyNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeContainer
 #[derive(Debug, PartialEq)]
 pub enum DescribeContainerError {
@@ -792,6 +850,14 @@ impl fmt::Display for DescribeContainerError {
     }
 }
 impl Error for DescribeContainerError {}
+impl ServiceErrorCode for DescribeContainerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeContainerError::ContainerNotFound(_) => "ContainerNotFoundException",
+            DescribeContainerError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by GetContainerPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetContainerPolicyError {
@@ -844,6 +910,16 @@ impl fmt::Display for GetContainerPolicyError {
     }
 }
 impl Error for GetContainerPolicyError {}
+impl ServiceErrorCode for GetContainerPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContainerPolicyError::ContainerInUse(_) => "ContainerInUseException",
+            GetContainerPolicyError::ContainerNotFound(_) => "ContainerNotFoundException",
+            GetContainerPolicyError::InternalServerError(_) => "InternalServerError",
+            GetContainerPolicyError::PolicyNotFound(_) => "PolicyNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetCorsPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetCorsPolicyError {
@@ -892,6 +968,16 @@ impl fmt::Display for GetCorsPolicyError {
     }
 }
 impl Error for GetCorsPolicyError {}
+impl ServiceErrorCode for GetCorsPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCorsPolicyError::ContainerInUse(_) => "ContainerInUseException",
+            GetCorsPolicyError::ContainerNotFound(_) => "ContainerNotFoundException",
+            GetCorsPolicyError::CorsPolicyNotFound(_) => "CorsPolicyNotFoundException",
+            GetCorsPolicyError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by GetLifecyclePolicy
 #[derive(Debug, PartialEq)]
 pub enum GetLifecyclePolicyError {
@@ -944,6 +1030,16 @@ impl fmt::Display for GetLifecyclePolicyError {
     }
 }
 impl Error for GetLifecyclePolicyError {}
+impl ServiceErrorCode for GetLifecyclePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLifecyclePolicyError::ContainerInUse(_) => "ContainerInUseException",
+            GetLifecyclePolicyError::ContainerNotFound(_) => "ContainerNotFoundException",
+            GetLifecyclePolicyError::InternalServerError(_) => "InternalServerError",
+            GetLifecyclePolicyError::PolicyNotFound(_) => "PolicyNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetMetricPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetMetricPolicyError {
@@ -992,6 +1088,16 @@ impl fmt::Display for GetMetricPolicyError {
     }
 }
 impl Error for GetMetricPolicyError {}
+impl ServiceErrorCode for GetMetricPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMetricPolicyError::ContainerInUse(_) => "ContainerInUseException",
+            GetMetricPolicyError::ContainerNotFound(_) => "ContainerNotFoundException",
+            GetMetricPolicyError::InternalServerError(_) => "InternalServerError",
+            GetMetricPolicyError::PolicyNotFound(_) => "PolicyNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListContainers
 #[derive(Debug, PartialEq)]
 pub enum ListContainersError {
@@ -1022,6 +1128,13 @@ impl fmt::Display for ListContainersError {
     }
 }
 impl Error for ListContainersError {}
+impl ServiceErrorCode for ListContainersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListContainersError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1068,6 +1181,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::ContainerInUse(_) => "ContainerInUseException",
+            ListTagsForResourceError::ContainerNotFound(_) => "ContainerNotFoundException",
+            ListTagsForResourceError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by PutContainerPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutContainerPolicyError {
@@ -1114,6 +1236,15 @@ impl fmt::Display for PutContainerPolicyError {
     }
 }
 impl Error for PutContainerPolicyError {}
+impl ServiceErrorCode for PutContainerPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutContainerPolicyError::ContainerInUse(_) => "ContainerInUseException",
+            PutContainerPolicyError::ContainerNotFound(_) => "ContainerNotFoundException",
+            PutContainerPolicyError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by PutCorsPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutCorsPolicyError {
@@ -1156,6 +1287,15 @@ impl fmt::Display for PutCorsPolicyError {
     }
 }
 impl Error for PutCorsPolicyError {}
+impl ServiceErrorCode for PutCorsPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutCorsPolicyError::ContainerInUse(_) => "ContainerInUseException",
+            PutCorsPolicyError::ContainerNotFound(_) => "ContainerNotFoundException",
+            PutCorsPolicyError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by PutLifecyclePolicy
 #[derive(Debug, PartialEq)]
 pub enum PutLifecyclePolicyError {
@@ -1202,6 +1342,15 @@ impl fmt::Display for PutLifecyclePolicyError {
     }
 }
 impl Error for PutLifecyclePolicyError {}
+impl ServiceErrorCode for PutLifecyclePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutLifecyclePolicyError::ContainerInUse(_) => "ContainerInUseException",
+            PutLifecyclePolicyError::ContainerNotFound(_) => "ContainerNotFoundException",
+            PutLifecyclePolicyError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by PutMetricPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutMetricPolicyError {
@@ -1244,6 +1393,15 @@ impl fmt::Display for PutMetricPolicyError {
     }
 }
 impl Error for PutMetricPolicyError {}
+impl ServiceErrorCode for PutMetricPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutMetricPolicyError::ContainerInUse(_) => "ContainerInUseException",
+            PutMetricPolicyError::ContainerNotFound(_) => "ContainerNotFoundException",
+            PutMetricPolicyError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by StartAccessLogging
 #[derive(Debug, PartialEq)]
 pub enum StartAccessLoggingError {
@@ -1290,6 +1448,15 @@ impl fmt::Display for StartAccessLoggingError {
     }
 }
 impl Error for StartAccessLoggingError {}
+impl ServiceErrorCode for StartAccessLoggingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartAccessLoggingError::ContainerInUse(_) => "ContainerInUseException",
+            StartAccessLoggingError::ContainerNotFound(_) => "ContainerNotFoundException",
+            StartAccessLoggingError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by StopAccessLogging
 #[derive(Debug, PartialEq)]
 pub enum StopAccessLoggingError {
@@ -1334,6 +1501,15 @@ impl fmt::Display for StopAccessLoggingError {
     }
 }
 impl Error for StopAccessLoggingError {}
+impl ServiceErrorCode for StopAccessLoggingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopAccessLoggingError::ContainerInUse(_) => "ContainerInUseException",
+            StopAccessLoggingError::ContainerNotFound(_) => "ContainerNotFoundException",
+            StopAccessLoggingError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1376,6 +1552,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::ContainerInUse(_) => "ContainerInUseException",
+            TagResourceError::ContainerNotFound(_) => "ContainerNotFoundException",
+            TagResourceError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1418,6 +1603,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ContainerInUse(_) => "ContainerInUseException",
+            UntagResourceError::ContainerNotFound(_) => "ContainerNotFoundException",
+            UntagResourceError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Trait representing the capabilities of the MediaStore API. MediaStore clients implement this trait.
 #[async_trait]
 pub trait MediaStore {
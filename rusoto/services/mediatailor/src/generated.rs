@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -528,6 +528,11 @@ impl fmt::Display for DeletePlaybackConfigurationError {
     }
 }
 impl Error for DeletePlaybackConfigurationError {}
+impl ServiceErrorCode for DeletePlaybackConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetPlaybackConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetPlaybackConfigurationError {}
@@ -550,6 +555,11 @@ impl fmt::Display for GetPlaybackConfigurationError {
     }
 }
 impl Error for GetPlaybackConfigurationError {}
+impl ServiceErrorCode for GetPlaybackConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListPlaybackConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListPlaybackConfigurationsError {}
@@ -574,6 +584,11 @@ impl fmt::Display for ListPlaybackConfigurationsError {
     }
 }
 impl Error for ListPlaybackConfigurationsError {}
+impl ServiceErrorCode for ListPlaybackConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -604,6 +619,13 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by PutPlaybackConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutPlaybackConfigurationError {}
@@ -626,6 +648,11 @@ impl fmt::Display for PutPlaybackConfigurationError {
     }
 }
 impl Error for PutPlaybackConfigurationError {}
+impl ServiceErrorCode for PutPlaybackConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -656,6 +683,13 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -686,6 +720,13 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+        }
+    }
+}
 /// Trait representing the capabilities of the MediaTailor API. MediaTailor clients implement this trait.
 #[async_trait]
 pub trait MediaTailor {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -315,6 +315,23 @@ impl fmt::Display for BatchMeterUsageError {
     }
 }
 impl Error for BatchMeterUsageError {}
+impl ServiceErrorCode for BatchMeterUsageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchMeterUsageError::DisabledApi(_) => "DisabledApiException",
+            BatchMeterUsageError::InternalServiceError(_) => "InternalServiceErrorException",
+            BatchMeterUsageError::InvalidCustomerIdentifier(_) => {
+                "InvalidCustomerIdentifierException"
+            }
+            BatchMeterUsageError::InvalidProductCode(_) => "InvalidProductCodeException",
+            BatchMeterUsageError::InvalidTag(_) => "InvalidTagException",
+            BatchMeterUsageError::InvalidUsageAllocations(_) => "InvalidUsageAllocationsException",
+            BatchMeterUsageError::InvalidUsageDimension(_) => "InvalidUsageDimensionException",
+            BatchMeterUsageError::Throttling(_) => "ThrottlingException",
+            BatchMeterUsageError::TimestampOutOfBounds(_) => "TimestampOutOfBoundsException",
+        }
+    }
+}
 /// Errors returned by MeterUsage
 #[derive(Debug, PartialEq)]
 pub enum MeterUsageError {
@@ -399,6 +416,22 @@ impl fmt::Display for MeterUsageError {
     }
 }
 impl Error for MeterUsageError {}
+impl ServiceErrorCode for MeterUsageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            MeterUsageError::CustomerNotEntitled(_) => "CustomerNotEntitledException",
+            MeterUsageError::DuplicateRequest(_) => "DuplicateRequestException",
+            MeterUsageError::InternalServiceError(_) => "InternalServiceErrorException",
+            MeterUsageError::InvalidEndpointRegion(_) => "InvalidEndpointRegionException",
+            MeterUsageError::InvalidProductCode(_) => "InvalidProductCodeException",
+            MeterUsageError::InvalidTag(_) => "InvalidTagException",
+            MeterUsageError::InvalidUsageAllocations(_) => "InvalidUsageAllocationsException",
+            MeterUsageError::InvalidUsageDimension(_) => "InvalidUsageDimensionException",
+            MeterUsageError::Throttling(_) => "ThrottlingException",
+            MeterUsageError::TimestampOutOfBounds(_) => "TimestampOutOfBoundsException",
+        }
+    }
+}
 /// Errors returned by RegisterUsage
 #[derive(Debug, PartialEq)]
 pub enum RegisterUsageError {
@@ -473,6 +506,20 @@ impl fmt::Display for RegisterUsageError {
     }
 }
 impl Error for RegisterUsageError {}
+impl ServiceErrorCode for RegisterUsageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterUsageError::CustomerNotEntitled(_) => "CustomerNotEntitledException",
+            RegisterUsageError::DisabledApi(_) => "DisabledApiException",
+            RegisterUsageError::InternalServiceError(_) => "InternalServiceErrorException",
+            RegisterUsageError::InvalidProductCode(_) => "InvalidProductCodeException",
+            RegisterUsageError::InvalidPublicKeyVersion(_) => "InvalidPublicKeyVersionException",
+            RegisterUsageError::InvalidRegion(_) => "InvalidRegionException",
+            RegisterUsageError::PlatformNotSupported(_) => "PlatformNotSupportedException",
+            RegisterUsageError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ResolveCustomer
 #[derive(Debug, PartialEq)]
 pub enum ResolveCustomerError {
@@ -529,6 +576,17 @@ impl fmt::Display for ResolveCustomerError {
     }
 }
 impl Error for ResolveCustomerError {}
+impl ServiceErrorCode for ResolveCustomerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResolveCustomerError::DisabledApi(_) => "DisabledApiException",
+            ResolveCustomerError::ExpiredToken(_) => "ExpiredTokenException",
+            ResolveCustomerError::InternalServiceError(_) => "InternalServiceErrorException",
+            ResolveCustomerError::InvalidToken(_) => "InvalidTokenException",
+            ResolveCustomerError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWSMarketplace Metering API. AWSMarketplace Metering clients implement this trait.
 #[async_trait]
 pub trait MarketplaceMetering {
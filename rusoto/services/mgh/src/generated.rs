@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -684,6 +684,21 @@ impl fmt::Display for AssociateCreatedArtifactError {
     }
 }
 impl Error for AssociateCreatedArtifactError {}
+impl ServiceErrorCode for AssociateCreatedArtifactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateCreatedArtifactError::AccessDenied(_) => "AccessDeniedException",
+            AssociateCreatedArtifactError::DryRunOperation(_) => "DryRunOperation",
+            AssociateCreatedArtifactError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            AssociateCreatedArtifactError::InternalServerError(_) => "InternalServerError",
+            AssociateCreatedArtifactError::InvalidInput(_) => "InvalidInputException",
+            AssociateCreatedArtifactError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateCreatedArtifactError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            AssociateCreatedArtifactError::Throttling(_) => "ThrottlingException",
+            AssociateCreatedArtifactError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by AssociateDiscoveredResource
 #[derive(Debug, PartialEq)]
 pub enum AssociateDiscoveredResourceError {
@@ -796,6 +811,24 @@ impl fmt::Display for AssociateDiscoveredResourceError {
     }
 }
 impl Error for AssociateDiscoveredResourceError {}
+impl ServiceErrorCode for AssociateDiscoveredResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateDiscoveredResourceError::AccessDenied(_) => "AccessDeniedException",
+            AssociateDiscoveredResourceError::DryRunOperation(_) => "DryRunOperation",
+            AssociateDiscoveredResourceError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            AssociateDiscoveredResourceError::InternalServerError(_) => "InternalServerError",
+            AssociateDiscoveredResourceError::InvalidInput(_) => "InvalidInputException",
+            AssociateDiscoveredResourceError::PolicyError(_) => "PolicyErrorException",
+            AssociateDiscoveredResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateDiscoveredResourceError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            AssociateDiscoveredResourceError::Throttling(_) => "ThrottlingException",
+            AssociateDiscoveredResourceError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by CreateProgressUpdateStream
 #[derive(Debug, PartialEq)]
 pub enum CreateProgressUpdateStreamError {
@@ -892,6 +925,20 @@ impl fmt::Display for CreateProgressUpdateStreamError {
     }
 }
 impl Error for CreateProgressUpdateStreamError {}
+impl ServiceErrorCode for CreateProgressUpdateStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProgressUpdateStreamError::AccessDenied(_) => "AccessDeniedException",
+            CreateProgressUpdateStreamError::DryRunOperation(_) => "DryRunOperation",
+            CreateProgressUpdateStreamError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            CreateProgressUpdateStreamError::InternalServerError(_) => "InternalServerError",
+            CreateProgressUpdateStreamError::InvalidInput(_) => "InvalidInputException",
+            CreateProgressUpdateStreamError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateProgressUpdateStreamError::Throttling(_) => "ThrottlingException",
+            CreateProgressUpdateStreamError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by DeleteProgressUpdateStream
 #[derive(Debug, PartialEq)]
 pub enum DeleteProgressUpdateStreamError {
@@ -996,6 +1043,21 @@ impl fmt::Display for DeleteProgressUpdateStreamError {
     }
 }
 impl Error for DeleteProgressUpdateStreamError {}
+impl ServiceErrorCode for DeleteProgressUpdateStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProgressUpdateStreamError::AccessDenied(_) => "AccessDeniedException",
+            DeleteProgressUpdateStreamError::DryRunOperation(_) => "DryRunOperation",
+            DeleteProgressUpdateStreamError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            DeleteProgressUpdateStreamError::InternalServerError(_) => "InternalServerError",
+            DeleteProgressUpdateStreamError::InvalidInput(_) => "InvalidInputException",
+            DeleteProgressUpdateStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteProgressUpdateStreamError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteProgressUpdateStreamError::Throttling(_) => "ThrottlingException",
+            DeleteProgressUpdateStreamError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by DescribeApplicationState
 #[derive(Debug, PartialEq)]
 pub enum DescribeApplicationStateError {
@@ -1082,6 +1144,20 @@ impl fmt::Display for DescribeApplicationStateError {
     }
 }
 impl Error for DescribeApplicationStateError {}
+impl ServiceErrorCode for DescribeApplicationStateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeApplicationStateError::AccessDenied(_) => "AccessDeniedException",
+            DescribeApplicationStateError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            DescribeApplicationStateError::InternalServerError(_) => "InternalServerError",
+            DescribeApplicationStateError::InvalidInput(_) => "InvalidInputException",
+            DescribeApplicationStateError::PolicyError(_) => "PolicyErrorException",
+            DescribeApplicationStateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeApplicationStateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeApplicationStateError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeMigrationTask
 #[derive(Debug, PartialEq)]
 pub enum DescribeMigrationTaskError {
@@ -1156,6 +1232,19 @@ impl fmt::Display for DescribeMigrationTaskError {
     }
 }
 impl Error for DescribeMigrationTaskError {}
+impl ServiceErrorCode for DescribeMigrationTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMigrationTaskError::AccessDenied(_) => "AccessDeniedException",
+            DescribeMigrationTaskError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            DescribeMigrationTaskError::InternalServerError(_) => "InternalServerError",
+            DescribeMigrationTaskError::InvalidInput(_) => "InvalidInputException",
+            DescribeMigrationTaskError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeMigrationTaskError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeMigrationTaskError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DisassociateCreatedArtifact
 #[derive(Debug, PartialEq)]
 pub enum DisassociateCreatedArtifactError {
@@ -1260,6 +1349,23 @@ impl fmt::Display for DisassociateCreatedArtifactError {
     }
 }
 impl Error for DisassociateCreatedArtifactError {}
+impl ServiceErrorCode for DisassociateCreatedArtifactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateCreatedArtifactError::AccessDenied(_) => "AccessDeniedException",
+            DisassociateCreatedArtifactError::DryRunOperation(_) => "DryRunOperation",
+            DisassociateCreatedArtifactError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            DisassociateCreatedArtifactError::InternalServerError(_) => "InternalServerError",
+            DisassociateCreatedArtifactError::InvalidInput(_) => "InvalidInputException",
+            DisassociateCreatedArtifactError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DisassociateCreatedArtifactError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DisassociateCreatedArtifactError::Throttling(_) => "ThrottlingException",
+            DisassociateCreatedArtifactError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by DisassociateDiscoveredResource
 #[derive(Debug, PartialEq)]
 pub enum DisassociateDiscoveredResourceError {
@@ -1370,6 +1476,25 @@ impl fmt::Display for DisassociateDiscoveredResourceError {
     }
 }
 impl Error for DisassociateDiscoveredResourceError {}
+impl ServiceErrorCode for DisassociateDiscoveredResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateDiscoveredResourceError::AccessDenied(_) => "AccessDeniedException",
+            DisassociateDiscoveredResourceError::DryRunOperation(_) => "DryRunOperation",
+            DisassociateDiscoveredResourceError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            DisassociateDiscoveredResourceError::InternalServerError(_) => "InternalServerError",
+            DisassociateDiscoveredResourceError::InvalidInput(_) => "InvalidInputException",
+            DisassociateDiscoveredResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DisassociateDiscoveredResourceError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DisassociateDiscoveredResourceError::Throttling(_) => "ThrottlingException",
+            DisassociateDiscoveredResourceError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperation"
+            }
+        }
+    }
+}
 /// Errors returned by ImportMigrationTask
 #[derive(Debug, PartialEq)]
 pub enum ImportMigrationTaskError {
@@ -1458,6 +1583,21 @@ impl fmt::Display for ImportMigrationTaskError {
     }
 }
 impl Error for ImportMigrationTaskError {}
+impl ServiceErrorCode for ImportMigrationTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportMigrationTaskError::AccessDenied(_) => "AccessDeniedException",
+            ImportMigrationTaskError::DryRunOperation(_) => "DryRunOperation",
+            ImportMigrationTaskError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            ImportMigrationTaskError::InternalServerError(_) => "InternalServerError",
+            ImportMigrationTaskError::InvalidInput(_) => "InvalidInputException",
+            ImportMigrationTaskError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ImportMigrationTaskError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ImportMigrationTaskError::Throttling(_) => "ThrottlingException",
+            ImportMigrationTaskError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by ListApplicationStates
 #[derive(Debug, PartialEq)]
 pub enum ListApplicationStatesError {
@@ -1524,6 +1664,18 @@ impl fmt::Display for ListApplicationStatesError {
     }
 }
 impl Error for ListApplicationStatesError {}
+impl ServiceErrorCode for ListApplicationStatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListApplicationStatesError::AccessDenied(_) => "AccessDeniedException",
+            ListApplicationStatesError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            ListApplicationStatesError::InternalServerError(_) => "InternalServerError",
+            ListApplicationStatesError::InvalidInput(_) => "InvalidInputException",
+            ListApplicationStatesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListApplicationStatesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListCreatedArtifacts
 #[derive(Debug, PartialEq)]
 pub enum ListCreatedArtifactsError {
@@ -1598,6 +1750,19 @@ impl fmt::Display for ListCreatedArtifactsError {
     }
 }
 impl Error for ListCreatedArtifactsError {}
+impl ServiceErrorCode for ListCreatedArtifactsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCreatedArtifactsError::AccessDenied(_) => "AccessDeniedException",
+            ListCreatedArtifactsError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            ListCreatedArtifactsError::InternalServerError(_) => "InternalServerError",
+            ListCreatedArtifactsError::InvalidInput(_) => "InvalidInputException",
+            ListCreatedArtifactsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListCreatedArtifactsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListCreatedArtifactsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDiscoveredResources
 #[derive(Debug, PartialEq)]
 pub enum ListDiscoveredResourcesError {
@@ -1676,6 +1841,19 @@ impl fmt::Display for ListDiscoveredResourcesError {
     }
 }
 impl Error for ListDiscoveredResourcesError {}
+impl ServiceErrorCode for ListDiscoveredResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDiscoveredResourcesError::AccessDenied(_) => "AccessDeniedException",
+            ListDiscoveredResourcesError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            ListDiscoveredResourcesError::InternalServerError(_) => "InternalServerError",
+            ListDiscoveredResourcesError::InvalidInput(_) => "InvalidInputException",
+            ListDiscoveredResourcesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListDiscoveredResourcesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListDiscoveredResourcesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListMigrationTasks
 #[derive(Debug, PartialEq)]
 pub enum ListMigrationTasksError {
@@ -1752,6 +1930,20 @@ impl fmt::Display for ListMigrationTasksError {
     }
 }
 impl Error for ListMigrationTasksError {}
+impl ServiceErrorCode for ListMigrationTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMigrationTasksError::AccessDenied(_) => "AccessDeniedException",
+            ListMigrationTasksError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            ListMigrationTasksError::InternalServerError(_) => "InternalServerError",
+            ListMigrationTasksError::InvalidInput(_) => "InvalidInputException",
+            ListMigrationTasksError::PolicyError(_) => "PolicyErrorException",
+            ListMigrationTasksError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListMigrationTasksError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListMigrationTasksError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListProgressUpdateStreams
 #[derive(Debug, PartialEq)]
 pub enum ListProgressUpdateStreamsError {
@@ -1826,6 +2018,18 @@ impl fmt::Display for ListProgressUpdateStreamsError {
     }
 }
 impl Error for ListProgressUpdateStreamsError {}
+impl ServiceErrorCode for ListProgressUpdateStreamsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProgressUpdateStreamsError::AccessDenied(_) => "AccessDeniedException",
+            ListProgressUpdateStreamsError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            ListProgressUpdateStreamsError::InternalServerError(_) => "InternalServerError",
+            ListProgressUpdateStreamsError::InvalidInput(_) => "InvalidInputException",
+            ListProgressUpdateStreamsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListProgressUpdateStreamsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by NotifyApplicationState
 #[derive(Debug, PartialEq)]
 pub enum NotifyApplicationStateError {
@@ -1922,6 +2126,22 @@ impl fmt::Display for NotifyApplicationStateError {
     }
 }
 impl Error for NotifyApplicationStateError {}
+impl ServiceErrorCode for NotifyApplicationStateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            NotifyApplicationStateError::AccessDenied(_) => "AccessDeniedException",
+            NotifyApplicationStateError::DryRunOperation(_) => "DryRunOperation",
+            NotifyApplicationStateError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            NotifyApplicationStateError::InternalServerError(_) => "InternalServerError",
+            NotifyApplicationStateError::InvalidInput(_) => "InvalidInputException",
+            NotifyApplicationStateError::PolicyError(_) => "PolicyErrorException",
+            NotifyApplicationStateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            NotifyApplicationStateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            NotifyApplicationStateError::Throttling(_) => "ThrottlingException",
+            NotifyApplicationStateError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by NotifyMigrationTaskState
 #[derive(Debug, PartialEq)]
 pub enum NotifyMigrationTaskStateError {
@@ -2018,6 +2238,21 @@ impl fmt::Display for NotifyMigrationTaskStateError {
     }
 }
 impl Error for NotifyMigrationTaskStateError {}
+impl ServiceErrorCode for NotifyMigrationTaskStateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            NotifyMigrationTaskStateError::AccessDenied(_) => "AccessDeniedException",
+            NotifyMigrationTaskStateError::DryRunOperation(_) => "DryRunOperation",
+            NotifyMigrationTaskStateError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            NotifyMigrationTaskStateError::InternalServerError(_) => "InternalServerError",
+            NotifyMigrationTaskStateError::InvalidInput(_) => "InvalidInputException",
+            NotifyMigrationTaskStateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            NotifyMigrationTaskStateError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            NotifyMigrationTaskStateError::Throttling(_) => "ThrottlingException",
+            NotifyMigrationTaskStateError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by PutResourceAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutResourceAttributesError {
@@ -2108,6 +2343,21 @@ impl fmt::Display for PutResourceAttributesError {
     }
 }
 impl Error for PutResourceAttributesError {}
+impl ServiceErrorCode for PutResourceAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutResourceAttributesError::AccessDenied(_) => "AccessDeniedException",
+            PutResourceAttributesError::DryRunOperation(_) => "DryRunOperation",
+            PutResourceAttributesError::HomeRegionNotSet(_) => "HomeRegionNotSetException",
+            PutResourceAttributesError::InternalServerError(_) => "InternalServerError",
+            PutResourceAttributesError::InvalidInput(_) => "InvalidInputException",
+            PutResourceAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutResourceAttributesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            PutResourceAttributesError::Throttling(_) => "ThrottlingException",
+            PutResourceAttributesError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Migration Hub API. AWS Migration Hub clients implement this trait.
 #[async_trait]
 pub trait MigrationHub {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -230,6 +230,18 @@ impl fmt::Display for CreateHomeRegionControlError {
     }
 }
 impl Error for CreateHomeRegionControlError {}
+impl ServiceErrorCode for CreateHomeRegionControlError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHomeRegionControlError::AccessDenied(_) => "AccessDeniedException",
+            CreateHomeRegionControlError::DryRunOperation(_) => "DryRunOperation",
+            CreateHomeRegionControlError::InternalServerError(_) => "InternalServerError",
+            CreateHomeRegionControlError::InvalidInput(_) => "InvalidInputException",
+            CreateHomeRegionControlError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateHomeRegionControlError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeHomeRegionControls
 #[derive(Debug, PartialEq)]
 pub enum DescribeHomeRegionControlsError {
@@ -300,6 +312,17 @@ impl fmt::Display for DescribeHomeRegionControlsError {
     }
 }
 impl Error for DescribeHomeRegionControlsError {}
+impl ServiceErrorCode for DescribeHomeRegionControlsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeHomeRegionControlsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeHomeRegionControlsError::InternalServerError(_) => "InternalServerError",
+            DescribeHomeRegionControlsError::InvalidInput(_) => "InvalidInputException",
+            DescribeHomeRegionControlsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeHomeRegionControlsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetHomeRegion
 #[derive(Debug, PartialEq)]
 pub enum GetHomeRegionError {
@@ -354,6 +377,17 @@ impl fmt::Display for GetHomeRegionError {
     }
 }
 impl Error for GetHomeRegionError {}
+impl ServiceErrorCode for GetHomeRegionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetHomeRegionError::AccessDenied(_) => "AccessDeniedException",
+            GetHomeRegionError::InternalServerError(_) => "InternalServerError",
+            GetHomeRegionError::InvalidInput(_) => "InvalidInputException",
+            GetHomeRegionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetHomeRegionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Migration Hub Config API. AWS Migration Hub Config clients implement this trait.
 #[async_trait]
 pub trait MigrationHubConfig {
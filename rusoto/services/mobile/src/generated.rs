@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -419,6 +419,19 @@ impl fmt::Display for CreateProjectError {
     }
 }
 impl Error for CreateProjectError {}
+impl ServiceErrorCode for CreateProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProjectError::BadRequest(_) => "BadRequestException",
+            CreateProjectError::InternalFailure(_) => "InternalFailureException",
+            CreateProjectError::LimitExceeded(_) => "LimitExceededException",
+            CreateProjectError::NotFound(_) => "NotFoundException",
+            CreateProjectError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateProjectError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateProjectError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteProject
 #[derive(Debug, PartialEq)]
 pub enum DeleteProjectError {
@@ -473,6 +486,17 @@ impl fmt::Display for DeleteProjectError {
     }
 }
 impl Error for DeleteProjectError {}
+impl ServiceErrorCode for DeleteProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProjectError::InternalFailure(_) => "InternalFailureException",
+            DeleteProjectError::NotFound(_) => "NotFoundException",
+            DeleteProjectError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteProjectError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteProjectError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeBundle
 #[derive(Debug, PartialEq)]
 pub enum DescribeBundleError {
@@ -533,6 +557,18 @@ impl fmt::Display for DescribeBundleError {
     }
 }
 impl Error for DescribeBundleError {}
+impl ServiceErrorCode for DescribeBundleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBundleError::BadRequest(_) => "BadRequestException",
+            DescribeBundleError::InternalFailure(_) => "InternalFailureException",
+            DescribeBundleError::NotFound(_) => "NotFoundException",
+            DescribeBundleError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeBundleError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribeBundleError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeProject
 #[derive(Debug, PartialEq)]
 pub enum DescribeProjectError {
@@ -593,6 +629,18 @@ impl fmt::Display for DescribeProjectError {
     }
 }
 impl Error for DescribeProjectError {}
+impl ServiceErrorCode for DescribeProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProjectError::BadRequest(_) => "BadRequestException",
+            DescribeProjectError::InternalFailure(_) => "InternalFailureException",
+            DescribeProjectError::NotFound(_) => "NotFoundException",
+            DescribeProjectError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeProjectError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribeProjectError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ExportBundle
 #[derive(Debug, PartialEq)]
 pub enum ExportBundleError {
@@ -653,6 +701,18 @@ impl fmt::Display for ExportBundleError {
     }
 }
 impl Error for ExportBundleError {}
+impl ServiceErrorCode for ExportBundleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExportBundleError::BadRequest(_) => "BadRequestException",
+            ExportBundleError::InternalFailure(_) => "InternalFailureException",
+            ExportBundleError::NotFound(_) => "NotFoundException",
+            ExportBundleError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ExportBundleError::TooManyRequests(_) => "TooManyRequestsException",
+            ExportBundleError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ExportProject
 #[derive(Debug, PartialEq)]
 pub enum ExportProjectError {
@@ -713,6 +773,18 @@ impl fmt::Display for ExportProjectError {
     }
 }
 impl Error for ExportProjectError {}
+impl ServiceErrorCode for ExportProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExportProjectError::BadRequest(_) => "BadRequestException",
+            ExportProjectError::InternalFailure(_) => "InternalFailureException",
+            ExportProjectError::NotFound(_) => "NotFoundException",
+            ExportProjectError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ExportProjectError::TooManyRequests(_) => "TooManyRequestsException",
+            ExportProjectError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListBundles
 #[derive(Debug, PartialEq)]
 pub enum ListBundlesError {
@@ -767,6 +839,17 @@ impl fmt::Display for ListBundlesError {
     }
 }
 impl Error for ListBundlesError {}
+impl ServiceErrorCode for ListBundlesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBundlesError::BadRequest(_) => "BadRequestException",
+            ListBundlesError::InternalFailure(_) => "InternalFailureException",
+            ListBundlesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListBundlesError::TooManyRequests(_) => "TooManyRequestsException",
+            ListBundlesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListProjects
 #[derive(Debug, PartialEq)]
 pub enum ListProjectsError {
@@ -821,6 +904,17 @@ impl fmt::Display for ListProjectsError {
     }
 }
 impl Error for ListProjectsError {}
+impl ServiceErrorCode for ListProjectsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProjectsError::BadRequest(_) => "BadRequestException",
+            ListProjectsError::InternalFailure(_) => "InternalFailureException",
+            ListProjectsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListProjectsError::TooManyRequests(_) => "TooManyRequestsException",
+            ListProjectsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateProject
 #[derive(Debug, PartialEq)]
 pub enum UpdateProjectError {
@@ -893,6 +987,20 @@ impl fmt::Display for UpdateProjectError {
     }
 }
 impl Error for UpdateProjectError {}
+impl ServiceErrorCode for UpdateProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateProjectError::AccountActionRequired(_) => "AccountActionRequiredException",
+            UpdateProjectError::BadRequest(_) => "BadRequestException",
+            UpdateProjectError::InternalFailure(_) => "InternalFailureException",
+            UpdateProjectError::LimitExceeded(_) => "LimitExceededException",
+            UpdateProjectError::NotFound(_) => "NotFoundException",
+            UpdateProjectError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateProjectError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateProjectError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Mobile API. AWS Mobile clients implement this trait.
 #[async_trait]
 pub trait Mobile {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -251,6 +251,7 @@ pub struct CreateBrokerRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub configuration: Option<ConfigurationId>,
     /// <p>The unique ID that the requester receives for the created broker. Amazon MQ passes your ID with the API action. Note: We recommend using a Universally Unique Identifier (UUID) for the creatorRequestId. You may omit the creatorRequestId if your application doesn&#39;t require idempotency.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub creator_request_id: Option<String>,
@@ -1415,6 +1416,17 @@ impl fmt::Display for CreateBrokerError {
     }
 }
 impl Error for CreateBrokerError {}
+impl ServiceErrorCode for CreateBrokerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBrokerError::BadRequest(_) => "BadRequestException",
+            CreateBrokerError::Conflict(_) => "ConflictException",
+            CreateBrokerError::Forbidden(_) => "ForbiddenException",
+            CreateBrokerError::InternalServerError(_) => "InternalServerErrorException",
+            CreateBrokerError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateConfiguration
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationError {
@@ -1465,6 +1477,16 @@ impl fmt::Display for CreateConfigurationError {
     }
 }
 impl Error for CreateConfigurationError {}
+impl ServiceErrorCode for CreateConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConfigurationError::BadRequest(_) => "BadRequestException",
+            CreateConfigurationError::Conflict(_) => "ConflictException",
+            CreateConfigurationError::Forbidden(_) => "ForbiddenException",
+            CreateConfigurationError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by CreateTags
 #[derive(Debug, PartialEq)]
 pub enum CreateTagsError {
@@ -1513,6 +1535,16 @@ impl fmt::Display for CreateTagsError {
     }
 }
 impl Error for CreateTagsError {}
+impl ServiceErrorCode for CreateTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTagsError::BadRequest(_) => "BadRequestException",
+            CreateTagsError::Forbidden(_) => "ForbiddenException",
+            CreateTagsError::InternalServerError(_) => "InternalServerErrorException",
+            CreateTagsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
 pub enum CreateUserError {
@@ -1567,6 +1599,17 @@ impl fmt::Display for CreateUserError {
     }
 }
 impl Error for CreateUserError {}
+impl ServiceErrorCode for CreateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserError::BadRequest(_) => "BadRequestException",
+            CreateUserError::Conflict(_) => "ConflictException",
+            CreateUserError::Forbidden(_) => "ForbiddenException",
+            CreateUserError::InternalServerError(_) => "InternalServerErrorException",
+            CreateUserError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteBroker
 #[derive(Debug, PartialEq)]
 pub enum DeleteBrokerError {
@@ -1615,6 +1658,16 @@ impl fmt::Display for DeleteBrokerError {
     }
 }
 impl Error for DeleteBrokerError {}
+impl ServiceErrorCode for DeleteBrokerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBrokerError::BadRequest(_) => "BadRequestException",
+            DeleteBrokerError::Forbidden(_) => "ForbiddenException",
+            DeleteBrokerError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteBrokerError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
 pub enum DeleteTagsError {
@@ -1663,6 +1716,16 @@ impl fmt::Display for DeleteTagsError {
     }
 }
 impl Error for DeleteTagsError {}
+impl ServiceErrorCode for DeleteTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTagsError::BadRequest(_) => "BadRequestException",
+            DeleteTagsError::Forbidden(_) => "ForbiddenException",
+            DeleteTagsError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteTagsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserError {
@@ -1711,6 +1774,16 @@ impl fmt::Display for DeleteUserError {
     }
 }
 impl Error for DeleteUserError {}
+impl ServiceErrorCode for DeleteUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserError::BadRequest(_) => "BadRequestException",
+            DeleteUserError::Forbidden(_) => "ForbiddenException",
+            DeleteUserError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteUserError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeBroker
 #[derive(Debug, PartialEq)]
 pub enum DescribeBrokerError {
@@ -1759,6 +1832,16 @@ impl fmt::Display for DescribeBrokerError {
     }
 }
 impl Error for DescribeBrokerError {}
+impl ServiceErrorCode for DescribeBrokerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBrokerError::BadRequest(_) => "BadRequestException",
+            DescribeBrokerError::Forbidden(_) => "ForbiddenException",
+            DescribeBrokerError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeBrokerError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeBrokerEngineTypes
 #[derive(Debug, PartialEq)]
 pub enum DescribeBrokerEngineTypesError {
@@ -1807,6 +1890,17 @@ impl fmt::Display for DescribeBrokerEngineTypesError {
     }
 }
 impl Error for DescribeBrokerEngineTypesError {}
+impl ServiceErrorCode for DescribeBrokerEngineTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBrokerEngineTypesError::BadRequest(_) => "BadRequestException",
+            DescribeBrokerEngineTypesError::Forbidden(_) => "ForbiddenException",
+            DescribeBrokerEngineTypesError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeBrokerInstanceOptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeBrokerInstanceOptionsError {
@@ -1859,6 +1953,17 @@ impl fmt::Display for DescribeBrokerInstanceOptionsError {
     }
 }
 impl Error for DescribeBrokerInstanceOptionsError {}
+impl ServiceErrorCode for DescribeBrokerInstanceOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBrokerInstanceOptionsError::BadRequest(_) => "BadRequestException",
+            DescribeBrokerInstanceOptionsError::Forbidden(_) => "ForbiddenException",
+            DescribeBrokerInstanceOptionsError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigurationError {
@@ -1909,6 +2014,16 @@ impl fmt::Display for DescribeConfigurationError {
     }
 }
 impl Error for DescribeConfigurationError {}
+impl ServiceErrorCode for DescribeConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConfigurationError::BadRequest(_) => "BadRequestException",
+            DescribeConfigurationError::Forbidden(_) => "ForbiddenException",
+            DescribeConfigurationError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeConfigurationError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeConfigurationRevision
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigurationRevisionError {
@@ -1969,6 +2084,18 @@ impl fmt::Display for DescribeConfigurationRevisionError {
     }
 }
 impl Error for DescribeConfigurationRevisionError {}
+impl ServiceErrorCode for DescribeConfigurationRevisionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConfigurationRevisionError::BadRequest(_) => "BadRequestException",
+            DescribeConfigurationRevisionError::Forbidden(_) => "ForbiddenException",
+            DescribeConfigurationRevisionError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DescribeConfigurationRevisionError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeUser
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserError {
@@ -2017,6 +2144,16 @@ impl fmt::Display for DescribeUserError {
     }
 }
 impl Error for DescribeUserError {}
+impl ServiceErrorCode for DescribeUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserError::BadRequest(_) => "BadRequestException",
+            DescribeUserError::Forbidden(_) => "ForbiddenException",
+            DescribeUserError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeUserError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListBrokers
 #[derive(Debug, PartialEq)]
 pub enum ListBrokersError {
@@ -2059,6 +2196,15 @@ impl fmt::Display for ListBrokersError {
     }
 }
 impl Error for ListBrokersError {}
+impl ServiceErrorCode for ListBrokersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBrokersError::BadRequest(_) => "BadRequestException",
+            ListBrokersError::Forbidden(_) => "ForbiddenException",
+            ListBrokersError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by ListConfigurationRevisions
 #[derive(Debug, PartialEq)]
 pub enum ListConfigurationRevisionsError {
@@ -2117,6 +2263,18 @@ impl fmt::Display for ListConfigurationRevisionsError {
     }
 }
 impl Error for ListConfigurationRevisionsError {}
+impl ServiceErrorCode for ListConfigurationRevisionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListConfigurationRevisionsError::BadRequest(_) => "BadRequestException",
+            ListConfigurationRevisionsError::Forbidden(_) => "ForbiddenException",
+            ListConfigurationRevisionsError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            ListConfigurationRevisionsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListConfigurationsError {
@@ -2161,6 +2319,15 @@ impl fmt::Display for ListConfigurationsError {
     }
 }
 impl Error for ListConfigurationsError {}
+impl ServiceErrorCode for ListConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListConfigurationsError::BadRequest(_) => "BadRequestException",
+            ListConfigurationsError::Forbidden(_) => "ForbiddenException",
+            ListConfigurationsError::InternalServerError(_) => "InternalServerErrorException",
+        }
+    }
+}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
 pub enum ListTagsError {
@@ -2209,6 +2376,16 @@ impl fmt::Display for ListTagsError {
     }
 }
 impl Error for ListTagsError {}
+impl ServiceErrorCode for ListTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsError::BadRequest(_) => "BadRequestException",
+            ListTagsError::Forbidden(_) => "ForbiddenException",
+            ListTagsError::InternalServerError(_) => "InternalServerErrorException",
+            ListTagsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListUsers
 #[derive(Debug, PartialEq)]
 pub enum ListUsersError {
@@ -2257,6 +2434,16 @@ impl fmt::Display for ListUsersError {
     }
 }
 impl Error for ListUsersError {}
+impl ServiceErrorCode for ListUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUsersError::BadRequest(_) => "BadRequestException",
+            ListUsersError::Forbidden(_) => "ForbiddenException",
+            ListUsersError::InternalServerError(_) => "InternalServerErrorException",
+            ListUsersError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by RebootBroker
 #[derive(Debug, PartialEq)]
 pub enum RebootBrokerError {
@@ -2305,6 +2492,16 @@ impl fmt::Display for RebootBrokerError {
     }
 }
 impl Error for RebootBrokerError {}
+impl ServiceErrorCode for RebootBrokerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RebootBrokerError::BadRequest(_) => "BadRequestException",
+            RebootBrokerError::Forbidden(_) => "ForbiddenException",
+            RebootBrokerError::InternalServerError(_) => "InternalServerErrorException",
+            RebootBrokerError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateBroker
 #[derive(Debug, PartialEq)]
 pub enum UpdateBrokerError {
@@ -2359,6 +2556,17 @@ impl fmt::Display for UpdateBrokerError {
     }
 }
 impl Error for UpdateBrokerError {}
+impl ServiceErrorCode for UpdateBrokerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateBrokerError::BadRequest(_) => "BadRequestException",
+            UpdateBrokerError::Conflict(_) => "ConflictException",
+            UpdateBrokerError::Forbidden(_) => "ForbiddenException",
+            UpdateBrokerError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateBrokerError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationError {
@@ -2415,6 +2623,17 @@ impl fmt::Display for UpdateConfigurationError {
     }
 }
 impl Error for UpdateConfigurationError {}
+impl ServiceErrorCode for UpdateConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConfigurationError::BadRequest(_) => "BadRequestException",
+            UpdateConfigurationError::Conflict(_) => "ConflictException",
+            UpdateConfigurationError::Forbidden(_) => "ForbiddenException",
+            UpdateConfigurationError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateConfigurationError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateUser
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserError {
@@ -2469,6 +2688,17 @@ impl fmt::Display for UpdateUserError {
     }
 }
 impl Error for UpdateUserError {}
+impl ServiceErrorCode for UpdateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserError::BadRequest(_) => "BadRequestException",
+            UpdateUserError::Conflict(_) => "ConflictException",
+            UpdateUserError::Forbidden(_) => "ForbiddenException",
+            UpdateUserError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateUserError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AmazonMQ API. AmazonMQ clients implement this trait.
 #[async_trait]
 pub trait MQ {
@@ -2656,6 +2886,10 @@ impl MQ for MQClient {
         &self,
         input: CreateBrokerRequest,
     ) -> Result<CreateBrokerResponse, RusotoError<CreateBrokerError>> {
+        let mut input = input;
+        if input.creator_request_id.is_none() {
+            input.creator_request_id = self.client.idempotency_token();
+        }
         let request_uri = "/v1/brokers";
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
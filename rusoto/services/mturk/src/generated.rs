@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1643,6 +1643,14 @@ impl fmt::Display for AcceptQualificationRequestError {
     }
 }
 impl Error for AcceptQualificationRequestError {}
+impl ServiceErrorCode for AcceptQualificationRequestError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptQualificationRequestError::RequestError(_) => "RequestError",
+            AcceptQualificationRequestError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by ApproveAssignment
 #[derive(Debug, PartialEq)]
 pub enum ApproveAssignmentError {
@@ -1679,6 +1687,14 @@ impl fmt::Display for ApproveAssignmentError {
     }
 }
 impl Error for ApproveAssignmentError {}
+impl ServiceErrorCode for ApproveAssignmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ApproveAssignmentError::RequestError(_) => "RequestError",
+            ApproveAssignmentError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by AssociateQualificationWithWorker
 #[derive(Debug, PartialEq)]
 pub enum AssociateQualificationWithWorkerError {
@@ -1725,6 +1741,14 @@ impl fmt::Display for AssociateQualificationWithWorkerError {
     }
 }
 impl Error for AssociateQualificationWithWorkerError {}
+impl ServiceErrorCode for AssociateQualificationWithWorkerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateQualificationWithWorkerError::RequestError(_) => "RequestError",
+            AssociateQualificationWithWorkerError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by CreateAdditionalAssignmentsForHIT
 #[derive(Debug, PartialEq)]
 pub enum CreateAdditionalAssignmentsForHITError {
@@ -1771,6 +1795,14 @@ impl fmt::Display for CreateAdditionalAssignmentsForHITError {
     }
 }
 impl Error for CreateAdditionalAssignmentsForHITError {}
+impl ServiceErrorCode for CreateAdditionalAssignmentsForHITError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAdditionalAssignmentsForHITError::RequestError(_) => "RequestError",
+            CreateAdditionalAssignmentsForHITError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by CreateHIT
 #[derive(Debug, PartialEq)]
 pub enum CreateHITError {
@@ -1807,6 +1839,14 @@ impl fmt::Display for CreateHITError {
     }
 }
 impl Error for CreateHITError {}
+impl ServiceErrorCode for CreateHITError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHITError::RequestError(_) => "RequestError",
+            CreateHITError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by CreateHITType
 #[derive(Debug, PartialEq)]
 pub enum CreateHITTypeError {
@@ -1843,6 +1883,14 @@ impl fmt::Display for CreateHITTypeError {
     }
 }
 impl Error for CreateHITTypeError {}
+impl ServiceErrorCode for CreateHITTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHITTypeError::RequestError(_) => "RequestError",
+            CreateHITTypeError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by CreateHITWithHITType
 #[derive(Debug, PartialEq)]
 pub enum CreateHITWithHITTypeError {
@@ -1879,6 +1927,14 @@ impl fmt::Display for CreateHITWithHITTypeError {
     }
 }
 impl Error for CreateHITWithHITTypeError {}
+impl ServiceErrorCode for CreateHITWithHITTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHITWithHITTypeError::RequestError(_) => "RequestError",
+            CreateHITWithHITTypeError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by CreateQualificationType
 #[derive(Debug, PartialEq)]
 pub enum CreateQualificationTypeError {
@@ -1919,6 +1975,14 @@ impl fmt::Display for CreateQualificationTypeError {
     }
 }
 impl Error for CreateQualificationTypeError {}
+impl ServiceErrorCode for CreateQualificationTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateQualificationTypeError::RequestError(_) => "RequestError",
+            CreateQualificationTypeError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by CreateWorkerBlock
 #[derive(Debug, PartialEq)]
 pub enum CreateWorkerBlockError {
@@ -1955,6 +2019,14 @@ impl fmt::Display for CreateWorkerBlockError {
     }
 }
 impl Error for CreateWorkerBlockError {}
+impl ServiceErrorCode for CreateWorkerBlockError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateWorkerBlockError::RequestError(_) => "RequestError",
+            CreateWorkerBlockError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by DeleteHIT
 #[derive(Debug, PartialEq)]
 pub enum DeleteHITError {
@@ -1991,6 +2063,14 @@ impl fmt::Display for DeleteHITError {
     }
 }
 impl Error for DeleteHITError {}
+impl ServiceErrorCode for DeleteHITError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteHITError::RequestError(_) => "RequestError",
+            DeleteHITError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by DeleteQualificationType
 #[derive(Debug, PartialEq)]
 pub enum DeleteQualificationTypeError {
@@ -2031,6 +2111,14 @@ impl fmt::Display for DeleteQualificationTypeError {
     }
 }
 impl Error for DeleteQualificationTypeError {}
+impl ServiceErrorCode for DeleteQualificationTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteQualificationTypeError::RequestError(_) => "RequestError",
+            DeleteQualificationTypeError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by DeleteWorkerBlock
 #[derive(Debug, PartialEq)]
 pub enum DeleteWorkerBlockError {
@@ -2067,6 +2155,14 @@ impl fmt::Display for DeleteWorkerBlockError {
     }
 }
 impl Error for DeleteWorkerBlockError {}
+impl ServiceErrorCode for DeleteWorkerBlockError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteWorkerBlockError::RequestError(_) => "RequestError",
+            DeleteWorkerBlockError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by DisassociateQualificationFromWorker
 #[derive(Debug, PartialEq)]
 pub enum DisassociateQualificationFromWorkerError {
@@ -2113,6 +2209,14 @@ impl fmt::Display for DisassociateQualificationFromWorkerError {
     }
 }
 impl Error for DisassociateQualificationFromWorkerError {}
+impl ServiceErrorCode for DisassociateQualificationFromWorkerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateQualificationFromWorkerError::RequestError(_) => "RequestError",
+            DisassociateQualificationFromWorkerError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by GetAccountBalance
 #[derive(Debug, PartialEq)]
 pub enum GetAccountBalanceError {
@@ -2149,6 +2253,14 @@ impl fmt::Display for GetAccountBalanceError {
     }
 }
 impl Error for GetAccountBalanceError {}
+impl ServiceErrorCode for GetAccountBalanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAccountBalanceError::RequestError(_) => "RequestError",
+            GetAccountBalanceError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by GetAssignment
 #[derive(Debug, PartialEq)]
 pub enum GetAssignmentError {
@@ -2185,6 +2297,14 @@ impl fmt::Display for GetAssignmentError {
     }
 }
 impl Error for GetAssignmentError {}
+impl ServiceErrorCode for GetAssignmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAssignmentError::RequestError(_) => "RequestError",
+            GetAssignmentError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by GetFileUploadURL
 #[derive(Debug, PartialEq)]
 pub enum GetFileUploadURLError {
@@ -2221,6 +2341,14 @@ impl fmt::Display for GetFileUploadURLError {
     }
 }
 impl Error for GetFileUploadURLError {}
+impl ServiceErrorCode for GetFileUploadURLError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFileUploadURLError::RequestError(_) => "RequestError",
+            GetFileUploadURLError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by GetHIT
 #[derive(Debug, PartialEq)]
 pub enum GetHITError {
@@ -2253,6 +2381,14 @@ impl fmt::Display for GetHITError {
     }
 }
 impl Error for GetHITError {}
+impl ServiceErrorCode for GetHITError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetHITError::RequestError(_) => "RequestError",
+            GetHITError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by GetQualificationScore
 #[derive(Debug, PartialEq)]
 pub enum GetQualificationScoreError {
@@ -2289,6 +2425,14 @@ impl fmt::Display for GetQualificationScoreError {
     }
 }
 impl Error for GetQualificationScoreError {}
+impl ServiceErrorCode for GetQualificationScoreError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetQualificationScoreError::RequestError(_) => "RequestError",
+            GetQualificationScoreError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by GetQualificationType
 #[derive(Debug, PartialEq)]
 pub enum GetQualificationTypeError {
@@ -2325,6 +2469,14 @@ impl fmt::Display for GetQualificationTypeError {
     }
 }
 impl Error for GetQualificationTypeError {}
+impl ServiceErrorCode for GetQualificationTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetQualificationTypeError::RequestError(_) => "RequestError",
+            GetQualificationTypeError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by ListAssignmentsForHIT
 #[derive(Debug, PartialEq)]
 pub enum ListAssignmentsForHITError {
@@ -2361,6 +2513,14 @@ impl fmt::Display for ListAssignmentsForHITError {
     }
 }
 impl Error for ListAssignmentsForHITError {}
+impl ServiceErrorCode for ListAssignmentsForHITError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAssignmentsForHITError::RequestError(_) => "RequestError",
+            ListAssignmentsForHITError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by ListBonusPayments
 #[derive(Debug, PartialEq)]
 pub enum ListBonusPaymentsError {
@@ -2397,6 +2557,14 @@ impl fmt::Display for ListBonusPaymentsError {
     }
 }
 impl Error for ListBonusPaymentsError {}
+impl ServiceErrorCode for ListBonusPaymentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBonusPaymentsError::RequestError(_) => "RequestError",
+            ListBonusPaymentsError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by ListHITs
 #[derive(Debug, PartialEq)]
 pub enum ListHITsError {
@@ -2433,6 +2601,14 @@ impl fmt::Display for ListHITsError {
     }
 }
 impl Error for ListHITsError {}
+impl ServiceErrorCode for ListHITsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListHITsError::RequestError(_) => "RequestError",
+            ListHITsError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by ListHITsForQualificationType
 #[derive(Debug, PartialEq)]
 pub enum ListHITsForQualificationTypeError {
@@ -2475,6 +2651,14 @@ impl fmt::Display for ListHITsForQualificationTypeError {
     }
 }
 impl Error for ListHITsForQualificationTypeError {}
+impl ServiceErrorCode for ListHITsForQualificationTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListHITsForQualificationTypeError::RequestError(_) => "RequestError",
+            ListHITsForQualificationTypeError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by ListQualificationRequests
 #[derive(Debug, PartialEq)]
 pub enum ListQualificationRequestsError {
@@ -2515,6 +2699,14 @@ impl fmt::Display for ListQualificationRequestsError {
     }
 }
 impl Error for ListQualificationRequestsError {}
+impl ServiceErrorCode for ListQualificationRequestsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListQualificationRequestsError::RequestError(_) => "RequestError",
+            ListQualificationRequestsError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by ListQualificationTypes
 #[derive(Debug, PartialEq)]
 pub enum ListQualificationTypesError {
@@ -2551,6 +2743,14 @@ impl fmt::Display for ListQualificationTypesError {
     }
 }
 impl Error for ListQualificationTypesError {}
+impl ServiceErrorCode for ListQualificationTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListQualificationTypesError::RequestError(_) => "RequestError",
+            ListQualificationTypesError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by ListReviewPolicyResultsForHIT
 #[derive(Debug, PartialEq)]
 pub enum ListReviewPolicyResultsForHITError {
@@ -2593,6 +2793,14 @@ impl fmt::Display for ListReviewPolicyResultsForHITError {
     }
 }
 impl Error for ListReviewPolicyResultsForHITError {}
+impl ServiceErrorCode for ListReviewPolicyResultsForHITError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListReviewPolicyResultsForHITError::RequestError(_) => "RequestError",
+            ListReviewPolicyResultsForHITError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by ListReviewableHITs
 #[derive(Debug, PartialEq)]
 pub enum ListReviewableHITsError {
@@ -2629,6 +2837,14 @@ impl fmt::Display for ListReviewableHITsError {
     }
 }
 impl Error for ListReviewableHITsError {}
+impl ServiceErrorCode for ListReviewableHITsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListReviewableHITsError::RequestError(_) => "RequestError",
+            ListReviewableHITsError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by ListWorkerBlocks
 #[derive(Debug, PartialEq)]
 pub enum ListWorkerBlocksError {
@@ -2665,6 +2881,14 @@ impl fmt::Display for ListWorkerBlocksError {
     }
 }
 impl Error for ListWorkerBlocksError {}
+impl ServiceErrorCode for ListWorkerBlocksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListWorkerBlocksError::RequestError(_) => "RequestError",
+            ListWorkerBlocksError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by ListWorkersWithQualificationType
 #[derive(Debug, PartialEq)]
 pub enum ListWorkersWithQualificationTypeError {
@@ -2711,6 +2935,14 @@ impl fmt::Display for ListWorkersWithQualificationTypeError {
     }
 }
 impl Error for ListWorkersWithQualificationTypeError {}
+impl ServiceErrorCode for ListWorkersWithQualificationTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListWorkersWithQualificationTypeError::RequestError(_) => "RequestError",
+            ListWorkersWithQualificationTypeError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by NotifyWorkers
 #[derive(Debug, PartialEq)]
 pub enum NotifyWorkersError {
@@ -2747,6 +2979,14 @@ impl fmt::Display for NotifyWorkersError {
     }
 }
 impl Error for NotifyWorkersError {}
+impl ServiceErrorCode for NotifyWorkersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            NotifyWorkersError::RequestError(_) => "RequestError",
+            NotifyWorkersError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by RejectAssignment
 #[derive(Debug, PartialEq)]
 pub enum RejectAssignmentError {
@@ -2783,6 +3023,14 @@ impl fmt::Display for RejectAssignmentError {
     }
 }
 impl Error for RejectAssignmentError {}
+impl ServiceErrorCode for RejectAssignmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RejectAssignmentError::RequestError(_) => "RequestError",
+            RejectAssignmentError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by RejectQualificationRequest
 #[derive(Debug, PartialEq)]
 pub enum RejectQualificationRequestError {
@@ -2825,6 +3073,14 @@ impl fmt::Display for RejectQualificationRequestError {
     }
 }
 impl Error for RejectQualificationRequestError {}
+impl ServiceErrorCode for RejectQualificationRequestError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RejectQualificationRequestError::RequestError(_) => "RequestError",
+            RejectQualificationRequestError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by SendBonus
 #[derive(Debug, PartialEq)]
 pub enum SendBonusError {
@@ -2861,6 +3117,14 @@ impl fmt::Display for SendBonusError {
     }
 }
 impl Error for SendBonusError {}
+impl ServiceErrorCode for SendBonusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendBonusError::RequestError(_) => "RequestError",
+            SendBonusError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by SendTestEventNotification
 #[derive(Debug, PartialEq)]
 pub enum SendTestEventNotificationError {
@@ -2901,6 +3165,14 @@ impl fmt::Display for SendTestEventNotificationError {
     }
 }
 impl Error for SendTestEventNotificationError {}
+impl ServiceErrorCode for SendTestEventNotificationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendTestEventNotificationError::RequestError(_) => "RequestError",
+            SendTestEventNotificationError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by UpdateExpirationForHIT
 #[derive(Debug, PartialEq)]
 pub enum UpdateExpirationForHITError {
@@ -2937,6 +3209,14 @@ impl fmt::Display for UpdateExpirationForHITError {
     }
 }
 impl Error for UpdateExpirationForHITError {}
+impl ServiceErrorCode for UpdateExpirationForHITError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateExpirationForHITError::RequestError(_) => "RequestError",
+            UpdateExpirationForHITError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by UpdateHITReviewStatus
 #[derive(Debug, PartialEq)]
 pub enum UpdateHITReviewStatusError {
@@ -2973,6 +3253,14 @@ impl fmt::Display for UpdateHITReviewStatusError {
     }
 }
 impl Error for UpdateHITReviewStatusError {}
+impl ServiceErrorCode for UpdateHITReviewStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateHITReviewStatusError::RequestError(_) => "RequestError",
+            UpdateHITReviewStatusError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by UpdateHITTypeOfHIT
 #[derive(Debug, PartialEq)]
 pub enum UpdateHITTypeOfHITError {
@@ -3009,6 +3297,14 @@ impl fmt::Display for UpdateHITTypeOfHITError {
     }
 }
 impl Error for UpdateHITTypeOfHITError {}
+impl ServiceErrorCode for UpdateHITTypeOfHITError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateHITTypeOfHITError::RequestError(_) => "RequestError",
+            UpdateHITTypeOfHITError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by UpdateNotificationSettings
 #[derive(Debug, PartialEq)]
 pub enum UpdateNotificationSettingsError {
@@ -3051,6 +3347,14 @@ impl fmt::Display for UpdateNotificationSettingsError {
     }
 }
 impl Error for UpdateNotificationSettingsError {}
+impl ServiceErrorCode for UpdateNotificationSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateNotificationSettingsError::RequestError(_) => "RequestError",
+            UpdateNotificationSettingsError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Errors returned by UpdateQualificationType
 #[derive(Debug, PartialEq)]
 pub enum UpdateQualificationTypeError {
@@ -3091,6 +3395,14 @@ impl fmt::Display for UpdateQualificationTypeError {
     }
 }
 impl Error for UpdateQualificationTypeError {}
+impl ServiceErrorCode for UpdateQualificationTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateQualificationTypeError::RequestError(_) => "RequestError",
+            UpdateQualificationTypeError::ServiceFault(_) => "ServiceFault",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon MTurk API. Amazon MTurk clients implement this trait.
 #[async_trait]
 pub trait MechanicalTurk {
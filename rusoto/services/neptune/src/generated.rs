@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -9282,6 +9282,20 @@ impl fmt::Display for AddRoleToDBClusterError {
     }
 }
 impl Error for AddRoleToDBClusterError {}
+impl ServiceErrorCode for AddRoleToDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddRoleToDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            AddRoleToDBClusterError::DBClusterRoleAlreadyExistsFault(_) => {
+                "DBClusterRoleAlreadyExists"
+            }
+            AddRoleToDBClusterError::DBClusterRoleQuotaExceededFault(_) => {
+                "DBClusterRoleQuotaExceeded"
+            }
+            AddRoleToDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+        }
+    }
+}
 /// Errors returned by AddSourceIdentifierToSubscription
 #[derive(Debug, PartialEq)]
 pub enum AddSourceIdentifierToSubscriptionError {
@@ -9344,6 +9358,16 @@ impl fmt::Display for AddSourceIdentifierToSubscriptionError {
     }
 }
 impl Error for AddSourceIdentifierToSubscriptionError {}
+impl ServiceErrorCode for AddSourceIdentifierToSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddSourceIdentifierToSubscriptionError::SourceNotFoundFault(_) => "SourceNotFound",
+            AddSourceIdentifierToSubscriptionError::SubscriptionNotFoundFault(_) => {
+                "SubscriptionNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by AddTagsToResource
 #[derive(Debug, PartialEq)]
 pub enum AddTagsToResourceError {
@@ -9404,6 +9428,15 @@ impl fmt::Display for AddTagsToResourceError {
     }
 }
 impl Error for AddTagsToResourceError {}
+impl ServiceErrorCode for AddTagsToResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsToResourceError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            AddTagsToResourceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            AddTagsToResourceError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+        }
+    }
+}
 /// Errors returned by ApplyPendingMaintenanceAction
 #[derive(Debug, PartialEq)]
 pub enum ApplyPendingMaintenanceActionError {
@@ -9454,6 +9487,13 @@ impl fmt::Display for ApplyPendingMaintenanceActionError {
     }
 }
 impl Error for ApplyPendingMaintenanceActionError {}
+impl ServiceErrorCode for ApplyPendingMaintenanceActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ApplyPendingMaintenanceActionError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by CopyDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum CopyDBClusterParameterGroupError {
@@ -9528,6 +9568,21 @@ impl fmt::Display for CopyDBClusterParameterGroupError {
     }
 }
 impl Error for CopyDBClusterParameterGroupError {}
+impl ServiceErrorCode for CopyDBClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(_) => {
+                "DBParameterGroupAlreadyExists"
+            }
+            CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(_) => {
+                "DBParameterGroupQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CopyDBClusterSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CopyDBClusterSnapshotError {
@@ -9636,6 +9691,26 @@ impl fmt::Display for CopyDBClusterSnapshotError {
     }
 }
 impl Error for CopyDBClusterSnapshotError {}
+impl ServiceErrorCode for CopyDBClusterSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(_) => {
+                "DBClusterSnapshotAlreadyExistsFault"
+            }
+            CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            CopyDBClusterSnapshotError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by CopyDBParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum CopyDBParameterGroupError {
@@ -9708,6 +9783,21 @@ impl fmt::Display for CopyDBParameterGroupError {
     }
 }
 impl Error for CopyDBParameterGroupError {}
+impl ServiceErrorCode for CopyDBParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyDBParameterGroupError::DBParameterGroupAlreadyExistsFault(_) => {
+                "DBParameterGroupAlreadyExists"
+            }
+            CopyDBParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            CopyDBParameterGroupError::DBParameterGroupQuotaExceededFault(_) => {
+                "DBParameterGroupQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDBCluster
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterError {
@@ -9880,6 +9970,35 @@ impl fmt::Display for CreateDBClusterError {
     }
 }
 impl Error for CreateDBClusterError {}
+impl ServiceErrorCode for CreateDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBClusterError::DBClusterAlreadyExistsFault(_) => "DBClusterAlreadyExistsFault",
+            CreateDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            CreateDBClusterError::DBClusterParameterGroupNotFoundFault(_) => {
+                "DBClusterParameterGroupNotFound"
+            }
+            CreateDBClusterError::DBClusterQuotaExceededFault(_) => "DBClusterQuotaExceededFault",
+            CreateDBClusterError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            CreateDBClusterError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            CreateDBClusterError::InsufficientStorageClusterCapacityFault(_) => {
+                "InsufficientStorageClusterCapacity"
+            }
+            CreateDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            CreateDBClusterError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            CreateDBClusterError::InvalidDBSubnetGroupStateFault(_) => {
+                "InvalidDBSubnetGroupStateFault"
+            }
+            CreateDBClusterError::InvalidSubnet(_) => "InvalidSubnet",
+            CreateDBClusterError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            CreateDBClusterError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            CreateDBClusterError::StorageQuotaExceededFault(_) => "StorageQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by CreateDBClusterEndpoint
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterEndpointError {
@@ -9988,6 +10107,26 @@ impl fmt::Display for CreateDBClusterEndpointError {
     }
 }
 impl Error for CreateDBClusterEndpointError {}
+impl ServiceErrorCode for CreateDBClusterEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBClusterEndpointError::DBClusterEndpointAlreadyExistsFault(_) => {
+                "DBClusterEndpointAlreadyExistsFault"
+            }
+            CreateDBClusterEndpointError::DBClusterEndpointQuotaExceededFault(_) => {
+                "DBClusterEndpointQuotaExceededFault"
+            }
+            CreateDBClusterEndpointError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            CreateDBClusterEndpointError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            CreateDBClusterEndpointError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            CreateDBClusterEndpointError::InvalidDBInstanceStateFault(_) => {
+                "InvalidDBInstanceState"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterParameterGroupError {
@@ -10050,6 +10189,18 @@ impl fmt::Display for CreateDBClusterParameterGroupError {
     }
 }
 impl Error for CreateDBClusterParameterGroupError {}
+impl ServiceErrorCode for CreateDBClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(_) => {
+                "DBParameterGroupAlreadyExists"
+            }
+            CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(_) => {
+                "DBParameterGroupQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDBClusterSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterSnapshotError {
@@ -10146,6 +10297,23 @@ impl fmt::Display for CreateDBClusterSnapshotError {
     }
 }
 impl Error for CreateDBClusterSnapshotError {}
+impl ServiceErrorCode for CreateDBClusterSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBClusterSnapshotError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(_) => {
+                "DBClusterSnapshotAlreadyExistsFault"
+            }
+            CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            CreateDBClusterSnapshotError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by CreateDBInstance
 #[derive(Debug, PartialEq)]
 pub enum CreateDBInstanceError {
@@ -10356,6 +10524,36 @@ impl fmt::Display for CreateDBInstanceError {
     }
 }
 impl Error for CreateDBInstanceError {}
+impl ServiceErrorCode for CreateDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBInstanceError::AuthorizationNotFoundFault(_) => "AuthorizationNotFound",
+            CreateDBInstanceError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            CreateDBInstanceError::DBInstanceAlreadyExistsFault(_) => "DBInstanceAlreadyExists",
+            CreateDBInstanceError::DBParameterGroupNotFoundFault(_) => "DBParameterGroupNotFound",
+            CreateDBInstanceError::DBSecurityGroupNotFoundFault(_) => "DBSecurityGroupNotFound",
+            CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            CreateDBInstanceError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            CreateDBInstanceError::DomainNotFoundFault(_) => "DomainNotFoundFault",
+            CreateDBInstanceError::InstanceQuotaExceededFault(_) => "InstanceQuotaExceeded",
+            CreateDBInstanceError::InsufficientDBInstanceCapacityFault(_) => {
+                "InsufficientDBInstanceCapacity"
+            }
+            CreateDBInstanceError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            CreateDBInstanceError::InvalidSubnet(_) => "InvalidSubnet",
+            CreateDBInstanceError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            CreateDBInstanceError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            CreateDBInstanceError::OptionGroupNotFoundFault(_) => "OptionGroupNotFoundFault",
+            CreateDBInstanceError::ProvisionedIopsNotAvailableInAZFault(_) => {
+                "ProvisionedIopsNotAvailableInAZFault"
+            }
+            CreateDBInstanceError::StorageQuotaExceededFault(_) => "StorageQuotaExceeded",
+            CreateDBInstanceError::StorageTypeNotSupportedFault(_) => "StorageTypeNotSupported",
+        }
+    }
+}
 /// Errors returned by CreateDBParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateDBParameterGroupError {
@@ -10416,6 +10614,18 @@ impl fmt::Display for CreateDBParameterGroupError {
     }
 }
 impl Error for CreateDBParameterGroupError {}
+impl ServiceErrorCode for CreateDBParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBParameterGroupError::DBParameterGroupAlreadyExistsFault(_) => {
+                "DBParameterGroupAlreadyExists"
+            }
+            CreateDBParameterGroupError::DBParameterGroupQuotaExceededFault(_) => {
+                "DBParameterGroupQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDBSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateDBSubnetGroupError {
@@ -10508,6 +10718,23 @@ impl fmt::Display for CreateDBSubnetGroupError {
     }
 }
 impl Error for CreateDBSubnetGroupError {}
+impl ServiceErrorCode for CreateDBSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(_) => {
+                "DBSubnetGroupAlreadyExists"
+            }
+            CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(_) => {
+                "DBSubnetGroupQuotaExceeded"
+            }
+            CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(_) => "DBSubnetQuotaExceededFault",
+            CreateDBSubnetGroupError::InvalidSubnet(_) => "InvalidSubnet",
+        }
+    }
+}
 /// Errors returned by CreateEventSubscription
 #[derive(Debug, PartialEq)]
 pub enum CreateEventSubscriptionError {
@@ -10622,6 +10849,25 @@ impl fmt::Display for CreateEventSubscriptionError {
     }
 }
 impl Error for CreateEventSubscriptionError {}
+impl ServiceErrorCode for CreateEventSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(_) => {
+                "EventSubscriptionQuotaExceeded"
+            }
+            CreateEventSubscriptionError::SNSInvalidTopicFault(_) => "SNSInvalidTopic",
+            CreateEventSubscriptionError::SNSNoAuthorizationFault(_) => "SNSNoAuthorization",
+            CreateEventSubscriptionError::SNSTopicArnNotFoundFault(_) => "SNSTopicArnNotFound",
+            CreateEventSubscriptionError::SourceNotFoundFault(_) => "SourceNotFound",
+            CreateEventSubscriptionError::SubscriptionAlreadyExistFault(_) => {
+                "SubscriptionAlreadyExist"
+            }
+            CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(_) => {
+                "SubscriptionCategoryNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDBCluster
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterError {
@@ -10706,6 +10952,21 @@ impl fmt::Display for DeleteDBClusterError {
     }
 }
 impl Error for DeleteDBClusterError {}
+impl ServiceErrorCode for DeleteDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(_) => {
+                "DBClusterSnapshotAlreadyExistsFault"
+            }
+            DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            DeleteDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            DeleteDBClusterError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by DeleteDBClusterEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterEndpointError {
@@ -10778,6 +11039,21 @@ impl fmt::Display for DeleteDBClusterEndpointError {
     }
 }
 impl Error for DeleteDBClusterEndpointError {}
+impl ServiceErrorCode for DeleteDBClusterEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBClusterEndpointError::DBClusterEndpointNotFoundFault(_) => {
+                "DBClusterEndpointNotFoundFault"
+            }
+            DeleteDBClusterEndpointError::InvalidDBClusterEndpointStateFault(_) => {
+                "InvalidDBClusterEndpointStateFault"
+            }
+            DeleteDBClusterEndpointError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterParameterGroupError {
@@ -10840,6 +11116,18 @@ impl fmt::Display for DeleteDBClusterParameterGroupError {
     }
 }
 impl Error for DeleteDBClusterParameterGroupError {}
+impl ServiceErrorCode for DeleteDBClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(_) => {
+                "InvalidDBParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDBClusterSnapshot
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterSnapshotError {
@@ -10900,6 +11188,18 @@ impl fmt::Display for DeleteDBClusterSnapshotError {
     }
 }
 impl Error for DeleteDBClusterSnapshotError {}
+impl ServiceErrorCode for DeleteDBClusterSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDBInstance
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBInstanceError {
@@ -10982,6 +11282,17 @@ impl fmt::Display for DeleteDBInstanceError {
     }
 }
 impl Error for DeleteDBInstanceError {}
+impl ServiceErrorCode for DeleteDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBInstanceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(_) => "DBSnapshotAlreadyExists",
+            DeleteDBInstanceError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            DeleteDBInstanceError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            DeleteDBInstanceError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by DeleteDBParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBParameterGroupError {
@@ -11042,6 +11353,18 @@ impl fmt::Display for DeleteDBParameterGroupError {
     }
 }
 impl Error for DeleteDBParameterGroupError {}
+impl ServiceErrorCode for DeleteDBParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            DeleteDBParameterGroupError::InvalidDBParameterGroupStateFault(_) => {
+                "InvalidDBParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDBSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBSubnetGroupError {
@@ -11114,6 +11437,17 @@ impl fmt::Display for DeleteDBSubnetGroupError {
     }
 }
 impl Error for DeleteDBSubnetGroupError {}
+impl ServiceErrorCode for DeleteDBSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(_) => {
+                "InvalidDBSubnetGroupStateFault"
+            }
+            DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(_) => "InvalidDBSubnetStateFault",
+        }
+    }
+}
 /// Errors returned by DeleteEventSubscription
 #[derive(Debug, PartialEq)]
 pub enum DeleteEventSubscriptionError {
@@ -11174,6 +11508,16 @@ impl fmt::Display for DeleteEventSubscriptionError {
     }
 }
 impl Error for DeleteEventSubscriptionError {}
+impl ServiceErrorCode for DeleteEventSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEventSubscriptionError::InvalidEventSubscriptionStateFault(_) => {
+                "InvalidEventSubscriptionState"
+            }
+            DeleteEventSubscriptionError::SubscriptionNotFoundFault(_) => "SubscriptionNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeDBClusterEndpoints
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterEndpointsError {
@@ -11224,6 +11568,13 @@ impl fmt::Display for DescribeDBClusterEndpointsError {
     }
 }
 impl Error for DescribeDBClusterEndpointsError {}
+impl ServiceErrorCode for DescribeDBClusterEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClusterEndpointsError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeDBClusterParameterGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterParameterGroupsError {
@@ -11274,6 +11625,15 @@ impl fmt::Display for DescribeDBClusterParameterGroupsError {
     }
 }
 impl Error for DescribeDBClusterParameterGroupsError {}
+impl ServiceErrorCode for DescribeDBClusterParameterGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBClusterParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterParametersError {
@@ -11324,6 +11684,15 @@ impl fmt::Display for DescribeDBClusterParametersError {
     }
 }
 impl Error for DescribeDBClusterParametersError {}
+impl ServiceErrorCode for DescribeDBClusterParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBClusterSnapshotAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterSnapshotAttributesError {
@@ -11372,6 +11741,15 @@ impl fmt::Display for DescribeDBClusterSnapshotAttributesError {
     }
 }
 impl Error for DescribeDBClusterSnapshotAttributesError {}
+impl ServiceErrorCode for DescribeDBClusterSnapshotAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBClusterSnapshots
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterSnapshotsError {
@@ -11422,6 +11800,15 @@ impl fmt::Display for DescribeDBClusterSnapshotsError {
     }
 }
 impl Error for DescribeDBClusterSnapshotsError {}
+impl ServiceErrorCode for DescribeDBClusterSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBClusters
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClustersError {
@@ -11466,6 +11853,13 @@ impl fmt::Display for DescribeDBClustersError {
     }
 }
 impl Error for DescribeDBClustersError {}
+impl ServiceErrorCode for DescribeDBClustersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClustersError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeDBEngineVersions
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBEngineVersionsError {}
@@ -11500,6 +11894,11 @@ impl fmt::Display for DescribeDBEngineVersionsError {
     }
 }
 impl Error for DescribeDBEngineVersionsError {}
+impl ServiceErrorCode for DescribeDBEngineVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeDBInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBInstancesError {
@@ -11544,6 +11943,13 @@ impl fmt::Display for DescribeDBInstancesError {
     }
 }
 impl Error for DescribeDBInstancesError {}
+impl ServiceErrorCode for DescribeDBInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBInstancesError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeDBParameterGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBParameterGroupsError {
@@ -11592,6 +11998,15 @@ impl fmt::Display for DescribeDBParameterGroupsError {
     }
 }
 impl Error for DescribeDBParameterGroupsError {}
+impl ServiceErrorCode for DescribeDBParameterGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBParameterGroupsError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBParametersError {
@@ -11640,6 +12055,15 @@ impl fmt::Display for DescribeDBParametersError {
     }
 }
 impl Error for DescribeDBParametersError {}
+impl ServiceErrorCode for DescribeDBParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBParametersError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBSubnetGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBSubnetGroupsError {
@@ -11688,6 +12112,15 @@ impl fmt::Display for DescribeDBSubnetGroupsError {
     }
 }
 impl Error for DescribeDBSubnetGroupsError {}
+impl ServiceErrorCode for DescribeDBSubnetGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(_) => {
+                "DBSubnetGroupNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeEngineDefaultClusterParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeEngineDefaultClusterParametersError {}
@@ -11724,6 +12157,11 @@ impl fmt::Display for DescribeEngineDefaultClusterParametersError {
     }
 }
 impl Error for DescribeEngineDefaultClusterParametersError {}
+impl ServiceErrorCode for DescribeEngineDefaultClusterParametersError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEngineDefaultParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeEngineDefaultParametersError {}
@@ -11760,6 +12198,11 @@ impl fmt::Display for DescribeEngineDefaultParametersError {
     }
 }
 impl Error for DescribeEngineDefaultParametersError {}
+impl ServiceErrorCode for DescribeEngineDefaultParametersError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEventCategories
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventCategoriesError {}
@@ -11794,6 +12237,11 @@ impl fmt::Display for DescribeEventCategoriesError {
     }
 }
 impl Error for DescribeEventCategoriesError {}
+impl ServiceErrorCode for DescribeEventCategoriesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEventSubscriptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventSubscriptionsError {
@@ -11844,6 +12292,13 @@ impl fmt::Display for DescribeEventSubscriptionsError {
     }
 }
 impl Error for DescribeEventSubscriptionsError {}
+impl ServiceErrorCode for DescribeEventSubscriptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventSubscriptionsError::SubscriptionNotFoundFault(_) => "SubscriptionNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventsError {}
@@ -11878,6 +12333,11 @@ impl fmt::Display for DescribeEventsError {
     }
 }
 impl Error for DescribeEventsError {}
+impl ServiceErrorCode for DescribeEventsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeOrderableDBInstanceOptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeOrderableDBInstanceOptionsError {}
@@ -11914,6 +12374,11 @@ impl fmt::Display for DescribeOrderableDBInstanceOptionsError {
     }
 }
 impl Error for DescribeOrderableDBInstanceOptionsError {}
+impl ServiceErrorCode for DescribeOrderableDBInstanceOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribePendingMaintenanceActions
 #[derive(Debug, PartialEq)]
 pub enum DescribePendingMaintenanceActionsError {
@@ -11964,6 +12429,15 @@ impl fmt::Display for DescribePendingMaintenanceActionsError {
     }
 }
 impl Error for DescribePendingMaintenanceActionsError {}
+impl ServiceErrorCode for DescribePendingMaintenanceActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePendingMaintenanceActionsError::ResourceNotFoundFault(_) => {
+                "ResourceNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeValidDBInstanceModifications
 #[derive(Debug, PartialEq)]
 pub enum DescribeValidDBInstanceModificationsError {
@@ -12026,6 +12500,18 @@ impl fmt::Display for DescribeValidDBInstanceModificationsError {
     }
 }
 impl Error for DescribeValidDBInstanceModificationsError {}
+impl ServiceErrorCode for DescribeValidDBInstanceModificationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeValidDBInstanceModificationsError::DBInstanceNotFoundFault(_) => {
+                "DBInstanceNotFound"
+            }
+            DescribeValidDBInstanceModificationsError::InvalidDBInstanceStateFault(_) => {
+                "InvalidDBInstanceState"
+            }
+        }
+    }
+}
 /// Errors returned by FailoverDBCluster
 #[derive(Debug, PartialEq)]
 pub enum FailoverDBClusterError {
@@ -12092,6 +12578,15 @@ impl fmt::Display for FailoverDBClusterError {
     }
 }
 impl Error for FailoverDBClusterError {}
+impl ServiceErrorCode for FailoverDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            FailoverDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            FailoverDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            FailoverDBClusterError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -12152,6 +12647,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            ListTagsForResourceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            ListTagsForResourceError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+        }
+    }
+}
 /// Errors returned by ModifyDBCluster
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterError {
@@ -12288,6 +12792,29 @@ impl fmt::Display for ModifyDBClusterError {
     }
 }
 impl Error for ModifyDBClusterError {}
+impl ServiceErrorCode for ModifyDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBClusterError::DBClusterAlreadyExistsFault(_) => "DBClusterAlreadyExistsFault",
+            ModifyDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(_) => {
+                "DBClusterParameterGroupNotFound"
+            }
+            ModifyDBClusterError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            ModifyDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            ModifyDBClusterError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            ModifyDBClusterError::InvalidDBSecurityGroupStateFault(_) => {
+                "InvalidDBSecurityGroupState"
+            }
+            ModifyDBClusterError::InvalidDBSubnetGroupStateFault(_) => {
+                "InvalidDBSubnetGroupStateFault"
+            }
+            ModifyDBClusterError::InvalidSubnet(_) => "InvalidSubnet",
+            ModifyDBClusterError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            ModifyDBClusterError::StorageQuotaExceededFault(_) => "StorageQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by ModifyDBClusterEndpoint
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterEndpointError {
@@ -12384,6 +12911,25 @@ impl fmt::Display for ModifyDBClusterEndpointError {
     }
 }
 impl Error for ModifyDBClusterEndpointError {}
+impl ServiceErrorCode for ModifyDBClusterEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBClusterEndpointError::DBClusterEndpointNotFoundFault(_) => {
+                "DBClusterEndpointNotFoundFault"
+            }
+            ModifyDBClusterEndpointError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            ModifyDBClusterEndpointError::InvalidDBClusterEndpointStateFault(_) => {
+                "InvalidDBClusterEndpointStateFault"
+            }
+            ModifyDBClusterEndpointError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            ModifyDBClusterEndpointError::InvalidDBInstanceStateFault(_) => {
+                "InvalidDBInstanceState"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterParameterGroupError {
@@ -12446,6 +12992,18 @@ impl fmt::Display for ModifyDBClusterParameterGroupError {
     }
 }
 impl Error for ModifyDBClusterParameterGroupError {}
+impl ServiceErrorCode for ModifyDBClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(_) => {
+                "InvalidDBParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyDBClusterSnapshotAttribute
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterSnapshotAttributeError {
@@ -12518,6 +13076,21 @@ impl fmt::Display for ModifyDBClusterSnapshotAttributeError {
     }
 }
 impl Error for ModifyDBClusterSnapshotAttributeError {}
+impl ServiceErrorCode for ModifyDBClusterSnapshotAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(_) => {
+                "SharedSnapshotQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyDBInstance
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBInstanceError {
@@ -12718,6 +13291,36 @@ impl fmt::Display for ModifyDBInstanceError {
     }
 }
 impl Error for ModifyDBInstanceError {}
+impl ServiceErrorCode for ModifyDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBInstanceError::AuthorizationNotFoundFault(_) => "AuthorizationNotFound",
+            ModifyDBInstanceError::CertificateNotFoundFault(_) => "CertificateNotFound",
+            ModifyDBInstanceError::DBInstanceAlreadyExistsFault(_) => "DBInstanceAlreadyExists",
+            ModifyDBInstanceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            ModifyDBInstanceError::DBParameterGroupNotFoundFault(_) => "DBParameterGroupNotFound",
+            ModifyDBInstanceError::DBSecurityGroupNotFoundFault(_) => "DBSecurityGroupNotFound",
+            ModifyDBInstanceError::DBUpgradeDependencyFailureFault(_) => {
+                "DBUpgradeDependencyFailure"
+            }
+            ModifyDBInstanceError::DomainNotFoundFault(_) => "DomainNotFoundFault",
+            ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(_) => {
+                "InsufficientDBInstanceCapacity"
+            }
+            ModifyDBInstanceError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(_) => {
+                "InvalidDBSecurityGroupState"
+            }
+            ModifyDBInstanceError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            ModifyDBInstanceError::OptionGroupNotFoundFault(_) => "OptionGroupNotFoundFault",
+            ModifyDBInstanceError::ProvisionedIopsNotAvailableInAZFault(_) => {
+                "ProvisionedIopsNotAvailableInAZFault"
+            }
+            ModifyDBInstanceError::StorageQuotaExceededFault(_) => "StorageQuotaExceeded",
+            ModifyDBInstanceError::StorageTypeNotSupportedFault(_) => "StorageTypeNotSupported",
+        }
+    }
+}
 /// Errors returned by ModifyDBParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBParameterGroupError {
@@ -12778,6 +13381,18 @@ impl fmt::Display for ModifyDBParameterGroupError {
     }
 }
 impl Error for ModifyDBParameterGroupError {}
+impl ServiceErrorCode for ModifyDBParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            ModifyDBParameterGroupError::InvalidDBParameterGroupStateFault(_) => {
+                "InvalidDBParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyDBSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBSubnetGroupError {
@@ -12866,6 +13481,19 @@ impl fmt::Display for ModifyDBSubnetGroupError {
     }
 }
 impl Error for ModifyDBSubnetGroupError {}
+impl ServiceErrorCode for ModifyDBSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(_) => "DBSubnetQuotaExceededFault",
+            ModifyDBSubnetGroupError::InvalidSubnet(_) => "InvalidSubnet",
+            ModifyDBSubnetGroupError::SubnetAlreadyInUse(_) => "SubnetAlreadyInUse",
+        }
+    }
+}
 /// Errors returned by ModifyEventSubscription
 #[derive(Debug, PartialEq)]
 pub enum ModifyEventSubscriptionError {
@@ -12972,6 +13600,22 @@ impl fmt::Display for ModifyEventSubscriptionError {
     }
 }
 impl Error for ModifyEventSubscriptionError {}
+impl ServiceErrorCode for ModifyEventSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyEventSubscriptionError::EventSubscriptionQuotaExceededFault(_) => {
+                "EventSubscriptionQuotaExceeded"
+            }
+            ModifyEventSubscriptionError::SNSInvalidTopicFault(_) => "SNSInvalidTopic",
+            ModifyEventSubscriptionError::SNSNoAuthorizationFault(_) => "SNSNoAuthorization",
+            ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(_) => "SNSTopicArnNotFound",
+            ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(_) => {
+                "SubscriptionCategoryNotFound"
+            }
+            ModifyEventSubscriptionError::SubscriptionNotFoundFault(_) => "SubscriptionNotFound",
+        }
+    }
+}
 /// Errors returned by PromoteReadReplicaDBCluster
 #[derive(Debug, PartialEq)]
 pub enum PromoteReadReplicaDBClusterError {
@@ -13034,6 +13678,16 @@ impl fmt::Display for PromoteReadReplicaDBClusterError {
     }
 }
 impl Error for PromoteReadReplicaDBClusterError {}
+impl ServiceErrorCode for PromoteReadReplicaDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PromoteReadReplicaDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            PromoteReadReplicaDBClusterError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by RebootDBInstance
 #[derive(Debug, PartialEq)]
 pub enum RebootDBInstanceError {
@@ -13088,6 +13742,14 @@ impl fmt::Display for RebootDBInstanceError {
     }
 }
 impl Error for RebootDBInstanceError {}
+impl ServiceErrorCode for RebootDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RebootDBInstanceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            RebootDBInstanceError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+        }
+    }
+}
 /// Errors returned by RemoveRoleFromDBCluster
 #[derive(Debug, PartialEq)]
 pub enum RemoveRoleFromDBClusterError {
@@ -13160,6 +13822,17 @@ impl fmt::Display for RemoveRoleFromDBClusterError {
     }
 }
 impl Error for RemoveRoleFromDBClusterError {}
+impl ServiceErrorCode for RemoveRoleFromDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveRoleFromDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            RemoveRoleFromDBClusterError::DBClusterRoleNotFoundFault(_) => "DBClusterRoleNotFound",
+            RemoveRoleFromDBClusterError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by RemoveSourceIdentifierFromSubscription
 #[derive(Debug, PartialEq)]
 pub enum RemoveSourceIdentifierFromSubscriptionError {
@@ -13222,6 +13895,16 @@ impl fmt::Display for RemoveSourceIdentifierFromSubscriptionError {
     }
 }
 impl Error for RemoveSourceIdentifierFromSubscriptionError {}
+impl ServiceErrorCode for RemoveSourceIdentifierFromSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveSourceIdentifierFromSubscriptionError::SourceNotFoundFault(_) => "SourceNotFound",
+            RemoveSourceIdentifierFromSubscriptionError::SubscriptionNotFoundFault(_) => {
+                "SubscriptionNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromResourceError {
@@ -13294,6 +13977,15 @@ impl fmt::Display for RemoveTagsFromResourceError {
     }
 }
 impl Error for RemoveTagsFromResourceError {}
+impl ServiceErrorCode for RemoveTagsFromResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsFromResourceError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            RemoveTagsFromResourceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            RemoveTagsFromResourceError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+        }
+    }
+}
 /// Errors returned by ResetDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum ResetDBClusterParameterGroupError {
@@ -13356,6 +14048,18 @@ impl fmt::Display for ResetDBClusterParameterGroupError {
     }
 }
 impl Error for ResetDBClusterParameterGroupError {}
+impl ServiceErrorCode for ResetDBClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(_) => {
+                "InvalidDBParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by ResetDBParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum ResetDBParameterGroupError {
@@ -13416,6 +14120,18 @@ impl fmt::Display for ResetDBParameterGroupError {
     }
 }
 impl Error for ResetDBParameterGroupError {}
+impl ServiceErrorCode for ResetDBParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetDBParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            ResetDBParameterGroupError::InvalidDBParameterGroupStateFault(_) => {
+                "InvalidDBParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by RestoreDBClusterFromSnapshot
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBClusterFromSnapshotError {
@@ -13640,6 +14356,54 @@ impl fmt::Display for RestoreDBClusterFromSnapshotError {
     }
 }
 impl Error for RestoreDBClusterFromSnapshotError {}
+impl ServiceErrorCode for RestoreDBClusterFromSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(_) => {
+                "DBClusterAlreadyExistsFault"
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterParameterGroupNotFoundFault(_) => {
+                "DBClusterParameterGroupNotFound"
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(_) => {
+                "DBClusterQuotaExceededFault"
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+            RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(_) => {
+                "DBSubnetGroupNotFoundFault"
+            }
+            RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(_) => {
+                "InsufficientDBClusterCapacityFault"
+            }
+            RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(_) => {
+                "InsufficientStorageClusterCapacity"
+            }
+            RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(_) => {
+                "InvalidDBSnapshotState"
+            }
+            RestoreDBClusterFromSnapshotError::InvalidRestoreFault(_) => "InvalidRestoreFault",
+            RestoreDBClusterFromSnapshotError::InvalidSubnet(_) => "InvalidSubnet",
+            RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(_) => {
+                "KMSKeyNotAccessibleFault"
+            }
+            RestoreDBClusterFromSnapshotError::OptionGroupNotFoundFault(_) => {
+                "OptionGroupNotFoundFault"
+            }
+            RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(_) => {
+                "StorageQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by RestoreDBClusterToPointInTime
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBClusterToPointInTimeError {
@@ -13874,6 +14638,59 @@ impl fmt::Display for RestoreDBClusterToPointInTimeError {
     }
 }
 impl Error for RestoreDBClusterToPointInTimeError {}
+impl ServiceErrorCode for RestoreDBClusterToPointInTimeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(_) => {
+                "DBClusterAlreadyExistsFault"
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(_) => {
+                "DBClusterNotFoundFault"
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterParameterGroupNotFoundFault(_) => {
+                "DBClusterParameterGroupNotFound"
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(_) => {
+                "DBClusterQuotaExceededFault"
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(_) => {
+                "DBSubnetGroupNotFoundFault"
+            }
+            RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(_) => {
+                "InsufficientDBClusterCapacityFault"
+            }
+            RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(_) => {
+                "InsufficientStorageClusterCapacity"
+            }
+            RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(_) => {
+                "InvalidDBSnapshotState"
+            }
+            RestoreDBClusterToPointInTimeError::InvalidRestoreFault(_) => "InvalidRestoreFault",
+            RestoreDBClusterToPointInTimeError::InvalidSubnet(_) => "InvalidSubnet",
+            RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(_) => {
+                "KMSKeyNotAccessibleFault"
+            }
+            RestoreDBClusterToPointInTimeError::OptionGroupNotFoundFault(_) => {
+                "OptionGroupNotFoundFault"
+            }
+            RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(_) => {
+                "StorageQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by StartDBCluster
 #[derive(Debug, PartialEq)]
 pub enum StartDBClusterError {
@@ -13934,6 +14751,15 @@ impl fmt::Display for StartDBClusterError {
     }
 }
 impl Error for StartDBClusterError {}
+impl ServiceErrorCode for StartDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            StartDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            StartDBClusterError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+        }
+    }
+}
 /// Errors returned by StopDBCluster
 #[derive(Debug, PartialEq)]
 pub enum StopDBClusterError {
@@ -13994,6 +14820,15 @@ impl fmt::Display for StopDBClusterError {
     }
 }
 impl Error for StopDBClusterError {}
+impl ServiceErrorCode for StopDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            StopDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            StopDBClusterError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Neptune API. Amazon Neptune clients implement this trait.
 #[async_trait]
 pub trait Neptune {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1489,6 +1489,20 @@ impl fmt::Display for AssociateCustomerGatewayError {
     }
 }
 impl Error for AssociateCustomerGatewayError {}
+impl ServiceErrorCode for AssociateCustomerGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateCustomerGatewayError::AccessDenied(_) => "AccessDeniedException",
+            AssociateCustomerGatewayError::Conflict(_) => "ConflictException",
+            AssociateCustomerGatewayError::InternalServer(_) => "InternalServerException",
+            AssociateCustomerGatewayError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateCustomerGatewayError::ServiceQuotaExceeded(_) => {
+                "ServiceQuotaExceededException"
+            }
+            AssociateCustomerGatewayError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by AssociateLink
 #[derive(Debug, PartialEq)]
 pub enum AssociateLinkError {
@@ -1549,6 +1563,18 @@ impl fmt::Display for AssociateLinkError {
     }
 }
 impl Error for AssociateLinkError {}
+impl ServiceErrorCode for AssociateLinkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateLinkError::AccessDenied(_) => "AccessDeniedException",
+            AssociateLinkError::Conflict(_) => "ConflictException",
+            AssociateLinkError::InternalServer(_) => "InternalServerException",
+            AssociateLinkError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateLinkError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            AssociateLinkError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by AssociateTransitGatewayConnectPeer
 #[derive(Debug, PartialEq)]
 pub enum AssociateTransitGatewayConnectPeerError {
@@ -1633,6 +1659,22 @@ impl fmt::Display for AssociateTransitGatewayConnectPeerError {
     }
 }
 impl Error for AssociateTransitGatewayConnectPeerError {}
+impl ServiceErrorCode for AssociateTransitGatewayConnectPeerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateTransitGatewayConnectPeerError::AccessDenied(_) => "AccessDeniedException",
+            AssociateTransitGatewayConnectPeerError::Conflict(_) => "ConflictException",
+            AssociateTransitGatewayConnectPeerError::InternalServer(_) => "InternalServerException",
+            AssociateTransitGatewayConnectPeerError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            AssociateTransitGatewayConnectPeerError::ServiceQuotaExceeded(_) => {
+                "ServiceQuotaExceededException"
+            }
+            AssociateTransitGatewayConnectPeerError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateConnection
 #[derive(Debug, PartialEq)]
 pub enum CreateConnectionError {
@@ -1689,6 +1731,17 @@ impl fmt::Display for CreateConnectionError {
     }
 }
 impl Error for CreateConnectionError {}
+impl ServiceErrorCode for CreateConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConnectionError::AccessDenied(_) => "AccessDeniedException",
+            CreateConnectionError::Conflict(_) => "ConflictException",
+            CreateConnectionError::InternalServer(_) => "InternalServerException",
+            CreateConnectionError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateConnectionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateDevice
 #[derive(Debug, PartialEq)]
 pub enum CreateDeviceError {
@@ -1749,6 +1802,18 @@ impl fmt::Display for CreateDeviceError {
     }
 }
 impl Error for CreateDeviceError {}
+impl ServiceErrorCode for CreateDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeviceError::AccessDenied(_) => "AccessDeniedException",
+            CreateDeviceError::Conflict(_) => "ConflictException",
+            CreateDeviceError::InternalServer(_) => "InternalServerException",
+            CreateDeviceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateDeviceError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateDeviceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateGlobalNetwork
 #[derive(Debug, PartialEq)]
 pub enum CreateGlobalNetworkError {
@@ -1805,6 +1870,17 @@ impl fmt::Display for CreateGlobalNetworkError {
     }
 }
 impl Error for CreateGlobalNetworkError {}
+impl ServiceErrorCode for CreateGlobalNetworkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGlobalNetworkError::AccessDenied(_) => "AccessDeniedException",
+            CreateGlobalNetworkError::Conflict(_) => "ConflictException",
+            CreateGlobalNetworkError::InternalServer(_) => "InternalServerException",
+            CreateGlobalNetworkError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateGlobalNetworkError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateLink
 #[derive(Debug, PartialEq)]
 pub enum CreateLinkError {
@@ -1865,6 +1941,18 @@ impl fmt::Display for CreateLinkError {
     }
 }
 impl Error for CreateLinkError {}
+impl ServiceErrorCode for CreateLinkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLinkError::AccessDenied(_) => "AccessDeniedException",
+            CreateLinkError::Conflict(_) => "ConflictException",
+            CreateLinkError::InternalServer(_) => "InternalServerException",
+            CreateLinkError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateLinkError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateLinkError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateSite
 #[derive(Debug, PartialEq)]
 pub enum CreateSiteError {
@@ -1925,6 +2013,18 @@ impl fmt::Display for CreateSiteError {
     }
 }
 impl Error for CreateSiteError {}
+impl ServiceErrorCode for CreateSiteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSiteError::AccessDenied(_) => "AccessDeniedException",
+            CreateSiteError::Conflict(_) => "ConflictException",
+            CreateSiteError::InternalServer(_) => "InternalServerException",
+            CreateSiteError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateSiteError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            CreateSiteError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteConnection
 #[derive(Debug, PartialEq)]
 pub enum DeleteConnectionError {
@@ -1979,6 +2079,17 @@ impl fmt::Display for DeleteConnectionError {
     }
 }
 impl Error for DeleteConnectionError {}
+impl ServiceErrorCode for DeleteConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConnectionError::AccessDenied(_) => "AccessDeniedException",
+            DeleteConnectionError::Conflict(_) => "ConflictException",
+            DeleteConnectionError::InternalServer(_) => "InternalServerException",
+            DeleteConnectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteConnectionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteDevice
 #[derive(Debug, PartialEq)]
 pub enum DeleteDeviceError {
@@ -2033,6 +2144,17 @@ impl fmt::Display for DeleteDeviceError {
     }
 }
 impl Error for DeleteDeviceError {}
+impl ServiceErrorCode for DeleteDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDeviceError::AccessDenied(_) => "AccessDeniedException",
+            DeleteDeviceError::Conflict(_) => "ConflictException",
+            DeleteDeviceError::InternalServer(_) => "InternalServerException",
+            DeleteDeviceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDeviceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteGlobalNetwork
 #[derive(Debug, PartialEq)]
 pub enum DeleteGlobalNetworkError {
@@ -2089,6 +2211,17 @@ impl fmt::Display for DeleteGlobalNetworkError {
     }
 }
 impl Error for DeleteGlobalNetworkError {}
+impl ServiceErrorCode for DeleteGlobalNetworkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGlobalNetworkError::AccessDenied(_) => "AccessDeniedException",
+            DeleteGlobalNetworkError::Conflict(_) => "ConflictException",
+            DeleteGlobalNetworkError::InternalServer(_) => "InternalServerException",
+            DeleteGlobalNetworkError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteGlobalNetworkError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteLink
 #[derive(Debug, PartialEq)]
 pub enum DeleteLinkError {
@@ -2143,6 +2276,17 @@ impl fmt::Display for DeleteLinkError {
     }
 }
 impl Error for DeleteLinkError {}
+impl ServiceErrorCode for DeleteLinkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLinkError::AccessDenied(_) => "AccessDeniedException",
+            DeleteLinkError::Conflict(_) => "ConflictException",
+            DeleteLinkError::InternalServer(_) => "InternalServerException",
+            DeleteLinkError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteLinkError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteSite
 #[derive(Debug, PartialEq)]
 pub enum DeleteSiteError {
@@ -2197,6 +2341,17 @@ impl fmt::Display for DeleteSiteError {
     }
 }
 impl Error for DeleteSiteError {}
+impl ServiceErrorCode for DeleteSiteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSiteError::AccessDenied(_) => "AccessDeniedException",
+            DeleteSiteError::Conflict(_) => "ConflictException",
+            DeleteSiteError::InternalServer(_) => "InternalServerException",
+            DeleteSiteError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteSiteError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeregisterTransitGateway
 #[derive(Debug, PartialEq)]
 pub enum DeregisterTransitGatewayError {
@@ -2257,6 +2412,17 @@ impl fmt::Display for DeregisterTransitGatewayError {
     }
 }
 impl Error for DeregisterTransitGatewayError {}
+impl ServiceErrorCode for DeregisterTransitGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterTransitGatewayError::AccessDenied(_) => "AccessDeniedException",
+            DeregisterTransitGatewayError::Conflict(_) => "ConflictException",
+            DeregisterTransitGatewayError::InternalServer(_) => "InternalServerException",
+            DeregisterTransitGatewayError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeregisterTransitGatewayError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeGlobalNetworks
 #[derive(Debug, PartialEq)]
 pub enum DescribeGlobalNetworksError {
@@ -2309,6 +2475,16 @@ impl fmt::Display for DescribeGlobalNetworksError {
     }
 }
 impl Error for DescribeGlobalNetworksError {}
+impl ServiceErrorCode for DescribeGlobalNetworksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGlobalNetworksError::AccessDenied(_) => "AccessDeniedException",
+            DescribeGlobalNetworksError::InternalServer(_) => "InternalServerException",
+            DescribeGlobalNetworksError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeGlobalNetworksError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DisassociateCustomerGateway
 #[derive(Debug, PartialEq)]
 pub enum DisassociateCustomerGatewayError {
@@ -2375,6 +2551,17 @@ impl fmt::Display for DisassociateCustomerGatewayError {
     }
 }
 impl Error for DisassociateCustomerGatewayError {}
+impl ServiceErrorCode for DisassociateCustomerGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateCustomerGatewayError::AccessDenied(_) => "AccessDeniedException",
+            DisassociateCustomerGatewayError::Conflict(_) => "ConflictException",
+            DisassociateCustomerGatewayError::InternalServer(_) => "InternalServerException",
+            DisassociateCustomerGatewayError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DisassociateCustomerGatewayError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DisassociateLink
 #[derive(Debug, PartialEq)]
 pub enum DisassociateLinkError {
@@ -2429,6 +2616,17 @@ impl fmt::Display for DisassociateLinkError {
     }
 }
 impl Error for DisassociateLinkError {}
+impl ServiceErrorCode for DisassociateLinkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateLinkError::AccessDenied(_) => "AccessDeniedException",
+            DisassociateLinkError::Conflict(_) => "ConflictException",
+            DisassociateLinkError::InternalServer(_) => "InternalServerException",
+            DisassociateLinkError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DisassociateLinkError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DisassociateTransitGatewayConnectPeer
 #[derive(Debug, PartialEq)]
 pub enum DisassociateTransitGatewayConnectPeerError {
@@ -2505,6 +2703,21 @@ impl fmt::Display for DisassociateTransitGatewayConnectPeerError {
     }
 }
 impl Error for DisassociateTransitGatewayConnectPeerError {}
+impl ServiceErrorCode for DisassociateTransitGatewayConnectPeerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateTransitGatewayConnectPeerError::AccessDenied(_) => "AccessDeniedException",
+            DisassociateTransitGatewayConnectPeerError::Conflict(_) => "ConflictException",
+            DisassociateTransitGatewayConnectPeerError::InternalServer(_) => {
+                "InternalServerException"
+            }
+            DisassociateTransitGatewayConnectPeerError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DisassociateTransitGatewayConnectPeerError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetConnections
 #[derive(Debug, PartialEq)]
 pub enum GetConnectionsError {
@@ -2553,6 +2766,16 @@ impl fmt::Display for GetConnectionsError {
     }
 }
 impl Error for GetConnectionsError {}
+impl ServiceErrorCode for GetConnectionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConnectionsError::AccessDenied(_) => "AccessDeniedException",
+            GetConnectionsError::InternalServer(_) => "InternalServerException",
+            GetConnectionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetConnectionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetCustomerGatewayAssociations
 #[derive(Debug, PartialEq)]
 pub enum GetCustomerGatewayAssociationsError {
@@ -2623,6 +2846,17 @@ impl fmt::Display for GetCustomerGatewayAssociationsError {
     }
 }
 impl Error for GetCustomerGatewayAssociationsError {}
+impl ServiceErrorCode for GetCustomerGatewayAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCustomerGatewayAssociationsError::AccessDenied(_) => "AccessDeniedException",
+            GetCustomerGatewayAssociationsError::Conflict(_) => "ConflictException",
+            GetCustomerGatewayAssociationsError::InternalServer(_) => "InternalServerException",
+            GetCustomerGatewayAssociationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetCustomerGatewayAssociationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetDevices
 #[derive(Debug, PartialEq)]
 pub enum GetDevicesError {
@@ -2671,6 +2905,16 @@ impl fmt::Display for GetDevicesError {
     }
 }
 impl Error for GetDevicesError {}
+impl ServiceErrorCode for GetDevicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDevicesError::AccessDenied(_) => "AccessDeniedException",
+            GetDevicesError::InternalServer(_) => "InternalServerException",
+            GetDevicesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetDevicesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetLinkAssociations
 #[derive(Debug, PartialEq)]
 pub enum GetLinkAssociationsError {
@@ -2721,6 +2965,16 @@ impl fmt::Display for GetLinkAssociationsError {
     }
 }
 impl Error for GetLinkAssociationsError {}
+impl ServiceErrorCode for GetLinkAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLinkAssociationsError::AccessDenied(_) => "AccessDeniedException",
+            GetLinkAssociationsError::InternalServer(_) => "InternalServerException",
+            GetLinkAssociationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetLinkAssociationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetLinks
 #[derive(Debug, PartialEq)]
 pub enum GetLinksError {
@@ -2769,6 +3023,16 @@ impl fmt::Display for GetLinksError {
     }
 }
 impl Error for GetLinksError {}
+impl ServiceErrorCode for GetLinksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLinksError::AccessDenied(_) => "AccessDeniedException",
+            GetLinksError::InternalServer(_) => "InternalServerException",
+            GetLinksError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetLinksError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetSites
 #[derive(Debug, PartialEq)]
 pub enum GetSitesError {
@@ -2817,6 +3081,16 @@ impl fmt::Display for GetSitesError {
     }
 }
 impl Error for GetSitesError {}
+impl ServiceErrorCode for GetSitesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSitesError::AccessDenied(_) => "AccessDeniedException",
+            GetSitesError::InternalServer(_) => "InternalServerException",
+            GetSitesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetSitesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetTransitGatewayConnectPeerAssociations
 #[derive(Debug, PartialEq)]
 pub enum GetTransitGatewayConnectPeerAssociationsError {
@@ -2893,6 +3167,23 @@ impl fmt::Display for GetTransitGatewayConnectPeerAssociationsError {
     }
 }
 impl Error for GetTransitGatewayConnectPeerAssociationsError {}
+impl ServiceErrorCode for GetTransitGatewayConnectPeerAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTransitGatewayConnectPeerAssociationsError::AccessDenied(_) => {
+                "AccessDeniedException"
+            }
+            GetTransitGatewayConnectPeerAssociationsError::Conflict(_) => "ConflictException",
+            GetTransitGatewayConnectPeerAssociationsError::InternalServer(_) => {
+                "InternalServerException"
+            }
+            GetTransitGatewayConnectPeerAssociationsError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            GetTransitGatewayConnectPeerAssociationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetTransitGatewayRegistrations
 #[derive(Debug, PartialEq)]
 pub enum GetTransitGatewayRegistrationsError {
@@ -2955,6 +3246,16 @@ impl fmt::Display for GetTransitGatewayRegistrationsError {
     }
 }
 impl Error for GetTransitGatewayRegistrationsError {}
+impl ServiceErrorCode for GetTransitGatewayRegistrationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTransitGatewayRegistrationsError::AccessDenied(_) => "AccessDeniedException",
+            GetTransitGatewayRegistrationsError::InternalServer(_) => "InternalServerException",
+            GetTransitGatewayRegistrationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetTransitGatewayRegistrationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3005,6 +3306,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            ListTagsForResourceError::InternalServer(_) => "InternalServerException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by RegisterTransitGateway
 #[derive(Debug, PartialEq)]
 pub enum RegisterTransitGatewayError {
@@ -3063,6 +3374,17 @@ impl fmt::Display for RegisterTransitGatewayError {
     }
 }
 impl Error for RegisterTransitGatewayError {}
+impl ServiceErrorCode for RegisterTransitGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterTransitGatewayError::AccessDenied(_) => "AccessDeniedException",
+            RegisterTransitGatewayError::Conflict(_) => "ConflictException",
+            RegisterTransitGatewayError::InternalServer(_) => "InternalServerException",
+            RegisterTransitGatewayError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RegisterTransitGatewayError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -3123,6 +3445,18 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AccessDenied(_) => "AccessDeniedException",
+            TagResourceError::Conflict(_) => "ConflictException",
+            TagResourceError::InternalServer(_) => "InternalServerException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            TagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3177,6 +3511,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AccessDenied(_) => "AccessDeniedException",
+            UntagResourceError::Conflict(_) => "ConflictException",
+            UntagResourceError::InternalServer(_) => "InternalServerException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateConnection
 #[derive(Debug, PartialEq)]
 pub enum UpdateConnectionError {
@@ -3231,6 +3576,17 @@ impl fmt::Display for UpdateConnectionError {
     }
 }
 impl Error for UpdateConnectionError {}
+impl ServiceErrorCode for UpdateConnectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConnectionError::AccessDenied(_) => "AccessDeniedException",
+            UpdateConnectionError::Conflict(_) => "ConflictException",
+            UpdateConnectionError::InternalServer(_) => "InternalServerException",
+            UpdateConnectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateConnectionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateDevice
 #[derive(Debug, PartialEq)]
 pub enum UpdateDeviceError {
@@ -3285,6 +3641,17 @@ impl fmt::Display for UpdateDeviceError {
     }
 }
 impl Error for UpdateDeviceError {}
+impl ServiceErrorCode for UpdateDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDeviceError::AccessDenied(_) => "AccessDeniedException",
+            UpdateDeviceError::Conflict(_) => "ConflictException",
+            UpdateDeviceError::InternalServer(_) => "InternalServerException",
+            UpdateDeviceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDeviceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateGlobalNetwork
 #[derive(Debug, PartialEq)]
 pub enum UpdateGlobalNetworkError {
@@ -3341,6 +3708,17 @@ impl fmt::Display for UpdateGlobalNetworkError {
     }
 }
 impl Error for UpdateGlobalNetworkError {}
+impl ServiceErrorCode for UpdateGlobalNetworkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGlobalNetworkError::AccessDenied(_) => "AccessDeniedException",
+            UpdateGlobalNetworkError::Conflict(_) => "ConflictException",
+            UpdateGlobalNetworkError::InternalServer(_) => "InternalServerException",
+            UpdateGlobalNetworkError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateGlobalNetworkError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateLink
 #[derive(Debug, PartialEq)]
 pub enum UpdateLinkError {
@@ -3401,6 +3779,18 @@ impl fmt::Display for UpdateLinkError {
     }
 }
 impl Error for UpdateLinkError {}
+impl ServiceErrorCode for UpdateLinkError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateLinkError::AccessDenied(_) => "AccessDeniedException",
+            UpdateLinkError::Conflict(_) => "ConflictException",
+            UpdateLinkError::InternalServer(_) => "InternalServerException",
+            UpdateLinkError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateLinkError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            UpdateLinkError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateSite
 #[derive(Debug, PartialEq)]
 pub enum UpdateSiteError {
@@ -3455,6 +3845,17 @@ impl fmt::Display for UpdateSiteError {
     }
 }
 impl Error for UpdateSiteError {}
+impl ServiceErrorCode for UpdateSiteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSiteError::AccessDenied(_) => "AccessDeniedException",
+            UpdateSiteError::Conflict(_) => "ConflictException",
+            UpdateSiteError::InternalServer(_) => "InternalServerException",
+            UpdateSiteError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateSiteError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the NetworkManager API. NetworkManager clients implement this trait.
 #[async_trait]
 pub trait NetworkManager {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3386,6 +3386,13 @@ impl fmt::Display for AssignInstanceError {
     }
 }
 impl Error for AssignInstanceError {}
+impl ServiceErrorCode for AssignInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssignInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by AssignVolume
 #[derive(Debug, PartialEq)]
 pub enum AssignVolumeError {
@@ -3416,6 +3423,13 @@ impl fmt::Display for AssignVolumeError {
     }
 }
 impl Error for AssignVolumeError {}
+impl ServiceErrorCode for AssignVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssignVolumeError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by AssociateElasticIp
 #[derive(Debug, PartialEq)]
 pub enum AssociateElasticIpError {
@@ -3446,6 +3460,13 @@ impl fmt::Display for AssociateElasticIpError {
     }
 }
 impl Error for AssociateElasticIpError {}
+impl ServiceErrorCode for AssociateElasticIpError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateElasticIpError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by AttachElasticLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum AttachElasticLoadBalancerError {
@@ -3478,6 +3499,13 @@ impl fmt::Display for AttachElasticLoadBalancerError {
     }
 }
 impl Error for AttachElasticLoadBalancerError {}
+impl ServiceErrorCode for AttachElasticLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachElasticLoadBalancerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CloneStack
 #[derive(Debug, PartialEq)]
 pub enum CloneStackError {
@@ -3508,6 +3536,13 @@ impl fmt::Display for CloneStackError {
     }
 }
 impl Error for CloneStackError {}
+impl ServiceErrorCode for CloneStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CloneStackError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateApp
 #[derive(Debug, PartialEq)]
 pub enum CreateAppError {
@@ -3538,6 +3573,13 @@ impl fmt::Display for CreateAppError {
     }
 }
 impl Error for CreateAppError {}
+impl ServiceErrorCode for CreateAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAppError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateDeployment
 #[derive(Debug, PartialEq)]
 pub enum CreateDeploymentError {
@@ -3568,6 +3610,13 @@ impl fmt::Display for CreateDeploymentError {
     }
 }
 impl Error for CreateDeploymentError {}
+impl ServiceErrorCode for CreateDeploymentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeploymentError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateInstance
 #[derive(Debug, PartialEq)]
 pub enum CreateInstanceError {
@@ -3598,6 +3647,13 @@ impl fmt::Display for CreateInstanceError {
     }
 }
 impl Error for CreateInstanceError {}
+impl ServiceErrorCode for CreateInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateLayer
 #[derive(Debug, PartialEq)]
 pub enum CreateLayerError {
@@ -3628,6 +3684,13 @@ impl fmt::Display for CreateLayerError {
     }
 }
 impl Error for CreateLayerError {}
+impl ServiceErrorCode for CreateLayerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLayerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateStack
 #[derive(Debug, PartialEq)]
 pub enum CreateStackError {}
@@ -3650,6 +3713,11 @@ impl fmt::Display for CreateStackError {
     }
 }
 impl Error for CreateStackError {}
+impl ServiceErrorCode for CreateStackError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateUserProfile
 #[derive(Debug, PartialEq)]
 pub enum CreateUserProfileError {}
@@ -3672,6 +3740,11 @@ impl fmt::Display for CreateUserProfileError {
     }
 }
 impl Error for CreateUserProfileError {}
+impl ServiceErrorCode for CreateUserProfileError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteApp
 #[derive(Debug, PartialEq)]
 pub enum DeleteAppError {
@@ -3702,6 +3775,13 @@ impl fmt::Display for DeleteAppError {
     }
 }
 impl Error for DeleteAppError {}
+impl ServiceErrorCode for DeleteAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAppError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteInstance
 #[derive(Debug, PartialEq)]
 pub enum DeleteInstanceError {
@@ -3732,6 +3812,13 @@ impl fmt::Display for DeleteInstanceError {
     }
 }
 impl Error for DeleteInstanceError {}
+impl ServiceErrorCode for DeleteInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteLayer
 #[derive(Debug, PartialEq)]
 pub enum DeleteLayerError {
@@ -3762,6 +3849,13 @@ impl fmt::Display for DeleteLayerError {
     }
 }
 impl Error for DeleteLayerError {}
+impl ServiceErrorCode for DeleteLayerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLayerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteStack
 #[derive(Debug, PartialEq)]
 pub enum DeleteStackError {
@@ -3792,6 +3886,13 @@ impl fmt::Display for DeleteStackError {
     }
 }
 impl Error for DeleteStackError {}
+impl ServiceErrorCode for DeleteStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStackError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteUserProfile
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserProfileError {
@@ -3822,6 +3923,13 @@ impl fmt::Display for DeleteUserProfileError {
     }
 }
 impl Error for DeleteUserProfileError {}
+impl ServiceErrorCode for DeleteUserProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeregisterEcsCluster
 #[derive(Debug, PartialEq)]
 pub enum DeregisterEcsClusterError {
@@ -3854,6 +3962,13 @@ impl fmt::Display for DeregisterEcsClusterError {
     }
 }
 impl Error for DeregisterEcsClusterError {}
+impl ServiceErrorCode for DeregisterEcsClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterEcsClusterError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeregisterElasticIp
 #[derive(Debug, PartialEq)]
 pub enum DeregisterElasticIpError {
@@ -3886,6 +4001,13 @@ impl fmt::Display for DeregisterElasticIpError {
     }
 }
 impl Error for DeregisterElasticIpError {}
+impl ServiceErrorCode for DeregisterElasticIpError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterElasticIpError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeregisterInstance
 #[derive(Debug, PartialEq)]
 pub enum DeregisterInstanceError {
@@ -3916,6 +4038,13 @@ impl fmt::Display for DeregisterInstanceError {
     }
 }
 impl Error for DeregisterInstanceError {}
+impl ServiceErrorCode for DeregisterInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeregisterRdsDbInstance
 #[derive(Debug, PartialEq)]
 pub enum DeregisterRdsDbInstanceError {
@@ -3948,6 +4077,13 @@ impl fmt::Display for DeregisterRdsDbInstanceError {
     }
 }
 impl Error for DeregisterRdsDbInstanceError {}
+impl ServiceErrorCode for DeregisterRdsDbInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterRdsDbInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeregisterVolume
 #[derive(Debug, PartialEq)]
 pub enum DeregisterVolumeError {
@@ -3978,6 +4114,13 @@ impl fmt::Display for DeregisterVolumeError {
     }
 }
 impl Error for DeregisterVolumeError {}
+impl ServiceErrorCode for DeregisterVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterVolumeError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeAgentVersions
 #[derive(Debug, PartialEq)]
 pub enum DescribeAgentVersionsError {
@@ -4010,6 +4153,13 @@ impl fmt::Display for DescribeAgentVersionsError {
     }
 }
 impl Error for DescribeAgentVersionsError {}
+impl ServiceErrorCode for DescribeAgentVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAgentVersionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeApps
 #[derive(Debug, PartialEq)]
 pub enum DescribeAppsError {
@@ -4040,6 +4190,13 @@ impl fmt::Display for DescribeAppsError {
     }
 }
 impl Error for DescribeAppsError {}
+impl ServiceErrorCode for DescribeAppsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAppsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeCommands
 #[derive(Debug, PartialEq)]
 pub enum DescribeCommandsError {
@@ -4070,6 +4227,13 @@ impl fmt::Display for DescribeCommandsError {
     }
 }
 impl Error for DescribeCommandsError {}
+impl ServiceErrorCode for DescribeCommandsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCommandsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeDeployments
 #[derive(Debug, PartialEq)]
 pub enum DescribeDeploymentsError {
@@ -4102,6 +4266,13 @@ impl fmt::Display for DescribeDeploymentsError {
     }
 }
 impl Error for DescribeDeploymentsError {}
+impl ServiceErrorCode for DescribeDeploymentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDeploymentsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeEcsClusters
 #[derive(Debug, PartialEq)]
 pub enum DescribeEcsClustersError {
@@ -4134,6 +4305,13 @@ impl fmt::Display for DescribeEcsClustersError {
     }
 }
 impl Error for DescribeEcsClustersError {}
+impl ServiceErrorCode for DescribeEcsClustersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEcsClustersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeElasticIps
 #[derive(Debug, PartialEq)]
 pub enum DescribeElasticIpsError {
@@ -4164,6 +4342,13 @@ impl fmt::Display for DescribeElasticIpsError {
     }
 }
 impl Error for DescribeElasticIpsError {}
+impl ServiceErrorCode for DescribeElasticIpsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeElasticIpsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeElasticLoadBalancers
 #[derive(Debug, PartialEq)]
 pub enum DescribeElasticLoadBalancersError {
@@ -4200,6 +4385,13 @@ impl fmt::Display for DescribeElasticLoadBalancersError {
     }
 }
 impl Error for DescribeElasticLoadBalancersError {}
+impl ServiceErrorCode for DescribeElasticLoadBalancersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeElasticLoadBalancersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstancesError {
@@ -4230,6 +4422,13 @@ impl fmt::Display for DescribeInstancesError {
     }
 }
 impl Error for DescribeInstancesError {}
+impl ServiceErrorCode for DescribeInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInstancesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeLayers
 #[derive(Debug, PartialEq)]
 pub enum DescribeLayersError {
@@ -4260,6 +4459,13 @@ impl fmt::Display for DescribeLayersError {
     }
 }
 impl Error for DescribeLayersError {}
+impl ServiceErrorCode for DescribeLayersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLayersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeLoadBasedAutoScaling
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoadBasedAutoScalingError {
@@ -4296,6 +4502,13 @@ impl fmt::Display for DescribeLoadBasedAutoScalingError {
     }
 }
 impl Error for DescribeLoadBasedAutoScalingError {}
+impl ServiceErrorCode for DescribeLoadBasedAutoScalingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLoadBasedAutoScalingError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeMyUserProfile
 #[derive(Debug, PartialEq)]
 pub enum DescribeMyUserProfileError {}
@@ -4318,6 +4531,11 @@ impl fmt::Display for DescribeMyUserProfileError {
     }
 }
 impl Error for DescribeMyUserProfileError {}
+impl ServiceErrorCode for DescribeMyUserProfileError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeOperatingSystems
 #[derive(Debug, PartialEq)]
 pub enum DescribeOperatingSystemsError {}
@@ -4340,6 +4558,11 @@ impl fmt::Display for DescribeOperatingSystemsError {
     }
 }
 impl Error for DescribeOperatingSystemsError {}
+impl ServiceErrorCode for DescribeOperatingSystemsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribePermissions
 #[derive(Debug, PartialEq)]
 pub enum DescribePermissionsError {
@@ -4372,6 +4595,13 @@ impl fmt::Display for DescribePermissionsError {
     }
 }
 impl Error for DescribePermissionsError {}
+impl ServiceErrorCode for DescribePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeRaidArrays
 #[derive(Debug, PartialEq)]
 pub enum DescribeRaidArraysError {
@@ -4402,6 +4632,13 @@ impl fmt::Display for DescribeRaidArraysError {
     }
 }
 impl Error for DescribeRaidArraysError {}
+impl ServiceErrorCode for DescribeRaidArraysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRaidArraysError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeRdsDbInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeRdsDbInstancesError {
@@ -4434,6 +4671,13 @@ impl fmt::Display for DescribeRdsDbInstancesError {
     }
 }
 impl Error for DescribeRdsDbInstancesError {}
+impl ServiceErrorCode for DescribeRdsDbInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRdsDbInstancesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeServiceErrors
 #[derive(Debug, PartialEq)]
 pub enum DescribeServiceErrorsError {
@@ -4466,6 +4710,13 @@ impl fmt::Display for DescribeServiceErrorsError {
     }
 }
 impl Error for DescribeServiceErrorsError {}
+impl ServiceErrorCode for DescribeServiceErrorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeServiceErrorsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeStackProvisioningParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeStackProvisioningParametersError {
@@ -4502,6 +4753,15 @@ impl fmt::Display for DescribeStackProvisioningParametersError {
     }
 }
 impl Error for DescribeStackProvisioningParametersError {}
+impl ServiceErrorCode for DescribeStackProvisioningParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStackProvisioningParametersError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeStackSummary
 #[derive(Debug, PartialEq)]
 pub enum DescribeStackSummaryError {
@@ -4534,6 +4794,13 @@ impl fmt::Display for DescribeStackSummaryError {
     }
 }
 impl Error for DescribeStackSummaryError {}
+impl ServiceErrorCode for DescribeStackSummaryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStackSummaryError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeStacks
 #[derive(Debug, PartialEq)]
 pub enum DescribeStacksError {
@@ -4564,6 +4831,13 @@ impl fmt::Display for DescribeStacksError {
     }
 }
 impl Error for DescribeStacksError {}
+impl ServiceErrorCode for DescribeStacksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStacksError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeTimeBasedAutoScaling
 #[derive(Debug, PartialEq)]
 pub enum DescribeTimeBasedAutoScalingError {
@@ -4600,6 +4874,13 @@ impl fmt::Display for DescribeTimeBasedAutoScalingError {
     }
 }
 impl Error for DescribeTimeBasedAutoScalingError {}
+impl ServiceErrorCode for DescribeTimeBasedAutoScalingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTimeBasedAutoScalingError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeUserProfiles
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserProfilesError {
@@ -4632,6 +4913,13 @@ impl fmt::Display for DescribeUserProfilesError {
     }
 }
 impl Error for DescribeUserProfilesError {}
+impl ServiceErrorCode for DescribeUserProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserProfilesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeVolumes
 #[derive(Debug, PartialEq)]
 pub enum DescribeVolumesError {
@@ -4662,6 +4950,13 @@ impl fmt::Display for DescribeVolumesError {
     }
 }
 impl Error for DescribeVolumesError {}
+impl ServiceErrorCode for DescribeVolumesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeVolumesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DetachElasticLoadBalancer
 #[derive(Debug, PartialEq)]
 pub enum DetachElasticLoadBalancerError {
@@ -4694,6 +4989,13 @@ impl fmt::Display for DetachElasticLoadBalancerError {
     }
 }
 impl Error for DetachElasticLoadBalancerError {}
+impl ServiceErrorCode for DetachElasticLoadBalancerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachElasticLoadBalancerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateElasticIp
 #[derive(Debug, PartialEq)]
 pub enum DisassociateElasticIpError {
@@ -4726,6 +5028,13 @@ impl fmt::Display for DisassociateElasticIpError {
     }
 }
 impl Error for DisassociateElasticIpError {}
+impl ServiceErrorCode for DisassociateElasticIpError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateElasticIpError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetHostnameSuggestion
 #[derive(Debug, PartialEq)]
 pub enum GetHostnameSuggestionError {
@@ -4758,6 +5067,13 @@ impl fmt::Display for GetHostnameSuggestionError {
     }
 }
 impl Error for GetHostnameSuggestionError {}
+impl ServiceErrorCode for GetHostnameSuggestionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetHostnameSuggestionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GrantAccess
 #[derive(Debug, PartialEq)]
 pub enum GrantAccessError {
@@ -4788,6 +5104,13 @@ impl fmt::Display for GrantAccessError {
     }
 }
 impl Error for GrantAccessError {}
+impl ServiceErrorCode for GrantAccessError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GrantAccessError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
 pub enum ListTagsError {
@@ -4818,6 +5141,13 @@ impl fmt::Display for ListTagsError {
     }
 }
 impl Error for ListTagsError {}
+impl ServiceErrorCode for ListTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RebootInstance
 #[derive(Debug, PartialEq)]
 pub enum RebootInstanceError {
@@ -4848,6 +5178,13 @@ impl fmt::Display for RebootInstanceError {
     }
 }
 impl Error for RebootInstanceError {}
+impl ServiceErrorCode for RebootInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RebootInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RegisterEcsCluster
 #[derive(Debug, PartialEq)]
 pub enum RegisterEcsClusterError {
@@ -4878,6 +5215,13 @@ impl fmt::Display for RegisterEcsClusterError {
     }
 }
 impl Error for RegisterEcsClusterError {}
+impl ServiceErrorCode for RegisterEcsClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterEcsClusterError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RegisterElasticIp
 #[derive(Debug, PartialEq)]
 pub enum RegisterElasticIpError {
@@ -4908,6 +5252,13 @@ impl fmt::Display for RegisterElasticIpError {
     }
 }
 impl Error for RegisterElasticIpError {}
+impl ServiceErrorCode for RegisterElasticIpError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterElasticIpError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RegisterInstance
 #[derive(Debug, PartialEq)]
 pub enum RegisterInstanceError {
@@ -4938,6 +5289,13 @@ impl fmt::Display for RegisterInstanceError {
     }
 }
 impl Error for RegisterInstanceError {}
+impl ServiceErrorCode for RegisterInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RegisterRdsDbInstance
 #[derive(Debug, PartialEq)]
 pub enum RegisterRdsDbInstanceError {
@@ -4970,6 +5328,13 @@ impl fmt::Display for RegisterRdsDbInstanceError {
     }
 }
 impl Error for RegisterRdsDbInstanceError {}
+impl ServiceErrorCode for RegisterRdsDbInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterRdsDbInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RegisterVolume
 #[derive(Debug, PartialEq)]
 pub enum RegisterVolumeError {
@@ -5000,6 +5365,13 @@ impl fmt::Display for RegisterVolumeError {
     }
 }
 impl Error for RegisterVolumeError {}
+impl ServiceErrorCode for RegisterVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterVolumeError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by SetLoadBasedAutoScaling
 #[derive(Debug, PartialEq)]
 pub enum SetLoadBasedAutoScalingError {
@@ -5032,6 +5404,13 @@ impl fmt::Display for SetLoadBasedAutoScalingError {
     }
 }
 impl Error for SetLoadBasedAutoScalingError {}
+impl ServiceErrorCode for SetLoadBasedAutoScalingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetLoadBasedAutoScalingError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by SetPermission
 #[derive(Debug, PartialEq)]
 pub enum SetPermissionError {
@@ -5062,6 +5441,13 @@ impl fmt::Display for SetPermissionError {
     }
 }
 impl Error for SetPermissionError {}
+impl ServiceErrorCode for SetPermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetPermissionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by SetTimeBasedAutoScaling
 #[derive(Debug, PartialEq)]
 pub enum SetTimeBasedAutoScalingError {
@@ -5094,6 +5480,13 @@ impl fmt::Display for SetTimeBasedAutoScalingError {
     }
 }
 impl Error for SetTimeBasedAutoScalingError {}
+impl ServiceErrorCode for SetTimeBasedAutoScalingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetTimeBasedAutoScalingError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartInstance
 #[derive(Debug, PartialEq)]
 pub enum StartInstanceError {
@@ -5124,6 +5517,13 @@ impl fmt::Display for StartInstanceError {
     }
 }
 impl Error for StartInstanceError {}
+impl ServiceErrorCode for StartInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartStack
 #[derive(Debug, PartialEq)]
 pub enum StartStackError {
@@ -5154,6 +5554,13 @@ impl fmt::Display for StartStackError {
     }
 }
 impl Error for StartStackError {}
+impl ServiceErrorCode for StartStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartStackError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopInstance
 #[derive(Debug, PartialEq)]
 pub enum StopInstanceError {
@@ -5184,6 +5591,13 @@ impl fmt::Display for StopInstanceError {
     }
 }
 impl Error for StopInstanceError {}
+impl ServiceErrorCode for StopInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StopStack
 #[derive(Debug, PartialEq)]
 pub enum StopStackError {
@@ -5214,6 +5628,13 @@ impl fmt::Display for StopStackError {
     }
 }
 impl Error for StopStackError {}
+impl ServiceErrorCode for StopStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopStackError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -5244,6 +5665,13 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UnassignInstance
 #[derive(Debug, PartialEq)]
 pub enum UnassignInstanceError {
@@ -5274,6 +5702,13 @@ impl fmt::Display for UnassignInstanceError {
     }
 }
 impl Error for UnassignInstanceError {}
+impl ServiceErrorCode for UnassignInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UnassignInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UnassignVolume
 #[derive(Debug, PartialEq)]
 pub enum UnassignVolumeError {
@@ -5304,6 +5739,13 @@ impl fmt::Display for UnassignVolumeError {
     }
 }
 impl Error for UnassignVolumeError {}
+impl ServiceErrorCode for UnassignVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UnassignVolumeError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -5334,6 +5776,13 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateApp
 #[derive(Debug, PartialEq)]
 pub enum UpdateAppError {
@@ -5364,6 +5813,13 @@ impl fmt::Display for UpdateAppError {
     }
 }
 impl Error for UpdateAppError {}
+impl ServiceErrorCode for UpdateAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAppError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateElasticIp
 #[derive(Debug, PartialEq)]
 pub enum UpdateElasticIpError {
@@ -5394,6 +5850,13 @@ impl fmt::Display for UpdateElasticIpError {
     }
 }
 impl Error for UpdateElasticIpError {}
+impl ServiceErrorCode for UpdateElasticIpError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateElasticIpError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateInstance
 #[derive(Debug, PartialEq)]
 pub enum UpdateInstanceError {
@@ -5424,6 +5887,13 @@ impl fmt::Display for UpdateInstanceError {
     }
 }
 impl Error for UpdateInstanceError {}
+impl ServiceErrorCode for UpdateInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateLayer
 #[derive(Debug, PartialEq)]
 pub enum UpdateLayerError {
@@ -5454,6 +5924,13 @@ impl fmt::Display for UpdateLayerError {
     }
 }
 impl Error for UpdateLayerError {}
+impl ServiceErrorCode for UpdateLayerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateLayerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateMyUserProfile
 #[derive(Debug, PartialEq)]
 pub enum UpdateMyUserProfileError {}
@@ -5476,6 +5953,11 @@ impl fmt::Display for UpdateMyUserProfileError {
     }
 }
 impl Error for UpdateMyUserProfileError {}
+impl ServiceErrorCode for UpdateMyUserProfileError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UpdateRdsDbInstance
 #[derive(Debug, PartialEq)]
 pub enum UpdateRdsDbInstanceError {
@@ -5508,6 +5990,13 @@ impl fmt::Display for UpdateRdsDbInstanceError {
     }
 }
 impl Error for UpdateRdsDbInstanceError {}
+impl ServiceErrorCode for UpdateRdsDbInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRdsDbInstanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateStack
 #[derive(Debug, PartialEq)]
 pub enum UpdateStackError {
@@ -5538,6 +6027,13 @@ impl fmt::Display for UpdateStackError {
     }
 }
 impl Error for UpdateStackError {}
+impl ServiceErrorCode for UpdateStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateStackError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateUserProfile
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserProfileError {
@@ -5568,6 +6064,13 @@ impl fmt::Display for UpdateUserProfileError {
     }
 }
 impl Error for UpdateUserProfileError {}
+impl ServiceErrorCode for UpdateUserProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateVolume
 #[derive(Debug, PartialEq)]
 pub enum UpdateVolumeError {
@@ -5598,6 +6101,13 @@ impl fmt::Display for UpdateVolumeError {
     }
 }
 impl Error for UpdateVolumeError {}
+impl ServiceErrorCode for UpdateVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateVolumeError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS OpsWorks API. AWS OpsWorks clients implement this trait.
 #[async_trait]
 pub trait OpsWorks {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -851,6 +851,14 @@ impl fmt::Display for AssociateNodeError {
     }
 }
 impl Error for AssociateNodeError {}
+impl ServiceErrorCode for AssociateNodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateNodeError::InvalidState(_) => "InvalidStateException",
+            AssociateNodeError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateBackup
 #[derive(Debug, PartialEq)]
 pub enum CreateBackupError {
@@ -893,6 +901,15 @@ impl fmt::Display for CreateBackupError {
     }
 }
 impl Error for CreateBackupError {}
+impl ServiceErrorCode for CreateBackupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBackupError::InvalidState(_) => "InvalidStateException",
+            CreateBackupError::LimitExceeded(_) => "LimitExceededException",
+            CreateBackupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateServer
 #[derive(Debug, PartialEq)]
 pub enum CreateServerError {
@@ -935,6 +952,15 @@ impl fmt::Display for CreateServerError {
     }
 }
 impl Error for CreateServerError {}
+impl ServiceErrorCode for CreateServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateServerError::LimitExceeded(_) => "LimitExceededException",
+            CreateServerError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateServerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteBackup
 #[derive(Debug, PartialEq)]
 pub enum DeleteBackupError {
@@ -971,6 +997,14 @@ impl fmt::Display for DeleteBackupError {
     }
 }
 impl Error for DeleteBackupError {}
+impl ServiceErrorCode for DeleteBackupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBackupError::InvalidState(_) => "InvalidStateException",
+            DeleteBackupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteServer
 #[derive(Debug, PartialEq)]
 pub enum DeleteServerError {
@@ -1007,6 +1041,14 @@ impl fmt::Display for DeleteServerError {
     }
 }
 impl Error for DeleteServerError {}
+impl ServiceErrorCode for DeleteServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteServerError::InvalidState(_) => "InvalidStateException",
+            DeleteServerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeAccountAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountAttributesError {}
@@ -1029,6 +1071,11 @@ impl fmt::Display for DescribeAccountAttributesError {
     }
 }
 impl Error for DescribeAccountAttributesError {}
+impl ServiceErrorCode for DescribeAccountAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeBackups
 #[derive(Debug, PartialEq)]
 pub enum DescribeBackupsError {
@@ -1065,6 +1112,14 @@ impl fmt::Display for DescribeBackupsError {
     }
 }
 impl Error for DescribeBackupsError {}
+impl ServiceErrorCode for DescribeBackupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBackupsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeBackupsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventsError {
@@ -1101,6 +1156,14 @@ impl fmt::Display for DescribeEventsError {
     }
 }
 impl Error for DescribeEventsError {}
+impl ServiceErrorCode for DescribeEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeEventsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeNodeAssociationStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeNodeAssociationStatusError {
@@ -1137,6 +1200,13 @@ impl fmt::Display for DescribeNodeAssociationStatusError {
     }
 }
 impl Error for DescribeNodeAssociationStatusError {}
+impl ServiceErrorCode for DescribeNodeAssociationStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeNodeAssociationStatusError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeServers
 #[derive(Debug, PartialEq)]
 pub enum DescribeServersError {
@@ -1173,6 +1243,14 @@ impl fmt::Display for DescribeServersError {
     }
 }
 impl Error for DescribeServersError {}
+impl ServiceErrorCode for DescribeServersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeServersError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeServersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateNode
 #[derive(Debug, PartialEq)]
 pub enum DisassociateNodeError {
@@ -1209,6 +1287,14 @@ impl fmt::Display for DisassociateNodeError {
     }
 }
 impl Error for DisassociateNodeError {}
+impl ServiceErrorCode for DisassociateNodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateNodeError::InvalidState(_) => "InvalidStateException",
+            DisassociateNodeError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ExportServerEngineAttribute
 #[derive(Debug, PartialEq)]
 pub enum ExportServerEngineAttributeError {
@@ -1251,6 +1337,14 @@ impl fmt::Display for ExportServerEngineAttributeError {
     }
 }
 impl Error for ExportServerEngineAttributeError {}
+impl ServiceErrorCode for ExportServerEngineAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExportServerEngineAttributeError::InvalidState(_) => "InvalidStateException",
+            ExportServerEngineAttributeError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1283,6 +1377,13 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RestoreServer
 #[derive(Debug, PartialEq)]
 pub enum RestoreServerError {
@@ -1319,6 +1420,14 @@ impl fmt::Display for RestoreServerError {
     }
 }
 impl Error for RestoreServerError {}
+impl ServiceErrorCode for RestoreServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreServerError::InvalidState(_) => "InvalidStateException",
+            RestoreServerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartMaintenance
 #[derive(Debug, PartialEq)]
 pub enum StartMaintenanceError {
@@ -1355,6 +1464,14 @@ impl fmt::Display for StartMaintenanceError {
     }
 }
 impl Error for StartMaintenanceError {}
+impl ServiceErrorCode for StartMaintenanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartMaintenanceError::InvalidState(_) => "InvalidStateException",
+            StartMaintenanceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1391,6 +1508,14 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InvalidState(_) => "InvalidStateException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1427,6 +1552,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InvalidState(_) => "InvalidStateException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateServer
 #[derive(Debug, PartialEq)]
 pub enum UpdateServerError {
@@ -1463,6 +1596,14 @@ impl fmt::Display for UpdateServerError {
     }
 }
 impl Error for UpdateServerError {}
+impl ServiceErrorCode for UpdateServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateServerError::InvalidState(_) => "InvalidStateException",
+            UpdateServerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateServerEngineAttributes
 #[derive(Debug, PartialEq)]
 pub enum UpdateServerEngineAttributesError {
@@ -1507,6 +1648,14 @@ impl fmt::Display for UpdateServerEngineAttributesError {
     }
 }
 impl Error for UpdateServerEngineAttributesError {}
+impl ServiceErrorCode for UpdateServerEngineAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateServerEngineAttributesError::InvalidState(_) => "InvalidStateException",
+            UpdateServerEngineAttributesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the OpsWorksCM API. OpsWorksCM clients implement this trait.
 #[async_trait]
 pub trait OpsWorksCM {
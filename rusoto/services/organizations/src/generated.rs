@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1597,6 +1597,31 @@ impl fmt::Display for AcceptHandshakeError {
     }
 }
 impl Error for AcceptHandshakeError {}
+impl ServiceErrorCode for AcceptHandshakeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptHandshakeError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            AcceptHandshakeError::AccessDenied(_) => "AccessDeniedException",
+            AcceptHandshakeError::AccessDeniedForDependency(_) => {
+                "AccessDeniedForDependencyException"
+            }
+            AcceptHandshakeError::ConcurrentModification(_) => "ConcurrentModificationException",
+            AcceptHandshakeError::HandshakeAlreadyInState(_) => "HandshakeAlreadyInStateException",
+            AcceptHandshakeError::HandshakeConstraintViolation(_) => {
+                "HandshakeConstraintViolationException"
+            }
+            AcceptHandshakeError::HandshakeNotFound(_) => "HandshakeNotFoundException",
+            AcceptHandshakeError::InvalidHandshakeTransition(_) => {
+                "InvalidHandshakeTransitionException"
+            }
+            AcceptHandshakeError::InvalidInput(_) => "InvalidInputException",
+            AcceptHandshakeError::Service(_) => "ServiceException",
+            AcceptHandshakeError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by AttachPolicy
 #[derive(Debug, PartialEq)]
 pub enum AttachPolicyError {
@@ -1705,6 +1730,25 @@ impl fmt::Display for AttachPolicyError {
     }
 }
 impl Error for AttachPolicyError {}
+impl ServiceErrorCode for AttachPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachPolicyError::AWSOrganizationsNotInUse(_) => "AWSOrganizationsNotInUseException",
+            AttachPolicyError::AccessDenied(_) => "AccessDeniedException",
+            AttachPolicyError::ConcurrentModification(_) => "ConcurrentModificationException",
+            AttachPolicyError::ConstraintViolation(_) => "ConstraintViolationException",
+            AttachPolicyError::DuplicatePolicyAttachment(_) => "DuplicatePolicyAttachmentException",
+            AttachPolicyError::InvalidInput(_) => "InvalidInputException",
+            AttachPolicyError::PolicyChangesInProgress(_) => "PolicyChangesInProgressException",
+            AttachPolicyError::PolicyNotFound(_) => "PolicyNotFoundException",
+            AttachPolicyError::PolicyTypeNotEnabled(_) => "PolicyTypeNotEnabledException",
+            AttachPolicyError::Service(_) => "ServiceException",
+            AttachPolicyError::TargetNotFound(_) => "TargetNotFoundException",
+            AttachPolicyError::TooManyRequests(_) => "TooManyRequestsException",
+            AttachPolicyError::UnsupportedAPIEndpoint(_) => "UnsupportedAPIEndpointException",
+        }
+    }
+}
 /// Errors returned by CancelHandshake
 #[derive(Debug, PartialEq)]
 pub enum CancelHandshakeError {
@@ -1783,6 +1827,22 @@ impl fmt::Display for CancelHandshakeError {
     }
 }
 impl Error for CancelHandshakeError {}
+impl ServiceErrorCode for CancelHandshakeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelHandshakeError::AccessDenied(_) => "AccessDeniedException",
+            CancelHandshakeError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CancelHandshakeError::HandshakeAlreadyInState(_) => "HandshakeAlreadyInStateException",
+            CancelHandshakeError::HandshakeNotFound(_) => "HandshakeNotFoundException",
+            CancelHandshakeError::InvalidHandshakeTransition(_) => {
+                "InvalidHandshakeTransitionException"
+            }
+            CancelHandshakeError::InvalidInput(_) => "InvalidInputException",
+            CancelHandshakeError::Service(_) => "ServiceException",
+            CancelHandshakeError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateAccount
 #[derive(Debug, PartialEq)]
 pub enum CreateAccountError {
@@ -1869,6 +1929,21 @@ impl fmt::Display for CreateAccountError {
     }
 }
 impl Error for CreateAccountError {}
+impl ServiceErrorCode for CreateAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAccountError::AWSOrganizationsNotInUse(_) => "AWSOrganizationsNotInUseException",
+            CreateAccountError::AccessDenied(_) => "AccessDeniedException",
+            CreateAccountError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateAccountError::ConstraintViolation(_) => "ConstraintViolationException",
+            CreateAccountError::FinalizingOrganization(_) => "FinalizingOrganizationException",
+            CreateAccountError::InvalidInput(_) => "InvalidInputException",
+            CreateAccountError::Service(_) => "ServiceException",
+            CreateAccountError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateAccountError::UnsupportedAPIEndpoint(_) => "UnsupportedAPIEndpointException",
+        }
+    }
+}
 /// Errors returned by CreateGovCloudAccount
 #[derive(Debug, PartialEq)]
 pub enum CreateGovCloudAccountError {
@@ -1961,6 +2036,29 @@ impl fmt::Display for CreateGovCloudAccountError {
     }
 }
 impl Error for CreateGovCloudAccountError {}
+impl ServiceErrorCode for CreateGovCloudAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGovCloudAccountError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            CreateGovCloudAccountError::AccessDenied(_) => "AccessDeniedException",
+            CreateGovCloudAccountError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            CreateGovCloudAccountError::ConstraintViolation(_) => "ConstraintViolationException",
+            CreateGovCloudAccountError::FinalizingOrganization(_) => {
+                "FinalizingOrganizationException"
+            }
+            CreateGovCloudAccountError::InvalidInput(_) => "InvalidInputException",
+            CreateGovCloudAccountError::Service(_) => "ServiceException",
+            CreateGovCloudAccountError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateGovCloudAccountError::UnsupportedAPIEndpoint(_) => {
+                "UnsupportedAPIEndpointException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateOrganization
 #[derive(Debug, PartialEq)]
 pub enum CreateOrganizationError {
@@ -2041,6 +2139,22 @@ impl fmt::Display for CreateOrganizationError {
     }
 }
 impl Error for CreateOrganizationError {}
+impl ServiceErrorCode for CreateOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateOrganizationError::AccessDenied(_) => "AccessDeniedException",
+            CreateOrganizationError::AccessDeniedForDependency(_) => {
+                "AccessDeniedForDependencyException"
+            }
+            CreateOrganizationError::AlreadyInOrganization(_) => "AlreadyInOrganizationException",
+            CreateOrganizationError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreateOrganizationError::ConstraintViolation(_) => "ConstraintViolationException",
+            CreateOrganizationError::InvalidInput(_) => "InvalidInputException",
+            CreateOrganizationError::Service(_) => "ServiceException",
+            CreateOrganizationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateOrganizationalUnit
 #[derive(Debug, PartialEq)]
 pub enum CreateOrganizationalUnitError {
@@ -2141,6 +2255,27 @@ impl fmt::Display for CreateOrganizationalUnitError {
     }
 }
 impl Error for CreateOrganizationalUnitError {}
+impl ServiceErrorCode for CreateOrganizationalUnitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateOrganizationalUnitError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            CreateOrganizationalUnitError::AccessDenied(_) => "AccessDeniedException",
+            CreateOrganizationalUnitError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            CreateOrganizationalUnitError::ConstraintViolation(_) => "ConstraintViolationException",
+            CreateOrganizationalUnitError::DuplicateOrganizationalUnit(_) => {
+                "DuplicateOrganizationalUnitException"
+            }
+            CreateOrganizationalUnitError::InvalidInput(_) => "InvalidInputException",
+            CreateOrganizationalUnitError::ParentNotFound(_) => "ParentNotFoundException",
+            CreateOrganizationalUnitError::Service(_) => "ServiceException",
+            CreateOrganizationalUnitError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreatePolicy
 #[derive(Debug, PartialEq)]
 pub enum CreatePolicyError {
@@ -2239,6 +2374,25 @@ impl fmt::Display for CreatePolicyError {
     }
 }
 impl Error for CreatePolicyError {}
+impl ServiceErrorCode for CreatePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePolicyError::AWSOrganizationsNotInUse(_) => "AWSOrganizationsNotInUseException",
+            CreatePolicyError::AccessDenied(_) => "AccessDeniedException",
+            CreatePolicyError::ConcurrentModification(_) => "ConcurrentModificationException",
+            CreatePolicyError::ConstraintViolation(_) => "ConstraintViolationException",
+            CreatePolicyError::DuplicatePolicy(_) => "DuplicatePolicyException",
+            CreatePolicyError::InvalidInput(_) => "InvalidInputException",
+            CreatePolicyError::MalformedPolicyDocument(_) => "MalformedPolicyDocumentException",
+            CreatePolicyError::PolicyTypeNotAvailableForOrganization(_) => {
+                "PolicyTypeNotAvailableForOrganizationException"
+            }
+            CreatePolicyError::Service(_) => "ServiceException",
+            CreatePolicyError::TooManyRequests(_) => "TooManyRequestsException",
+            CreatePolicyError::UnsupportedAPIEndpoint(_) => "UnsupportedAPIEndpointException",
+        }
+    }
+}
 /// Errors returned by DeclineHandshake
 #[derive(Debug, PartialEq)]
 pub enum DeclineHandshakeError {
@@ -2317,6 +2471,22 @@ impl fmt::Display for DeclineHandshakeError {
     }
 }
 impl Error for DeclineHandshakeError {}
+impl ServiceErrorCode for DeclineHandshakeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeclineHandshakeError::AccessDenied(_) => "AccessDeniedException",
+            DeclineHandshakeError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeclineHandshakeError::HandshakeAlreadyInState(_) => "HandshakeAlreadyInStateException",
+            DeclineHandshakeError::HandshakeNotFound(_) => "HandshakeNotFoundException",
+            DeclineHandshakeError::InvalidHandshakeTransition(_) => {
+                "InvalidHandshakeTransitionException"
+            }
+            DeclineHandshakeError::InvalidInput(_) => "InvalidInputException",
+            DeclineHandshakeError::Service(_) => "ServiceException",
+            DeclineHandshakeError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteOrganization
 #[derive(Debug, PartialEq)]
 pub enum DeleteOrganizationError {
@@ -2389,6 +2559,21 @@ impl fmt::Display for DeleteOrganizationError {
     }
 }
 impl Error for DeleteOrganizationError {}
+impl ServiceErrorCode for DeleteOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteOrganizationError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            DeleteOrganizationError::AccessDenied(_) => "AccessDeniedException",
+            DeleteOrganizationError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteOrganizationError::InvalidInput(_) => "InvalidInputException",
+            DeleteOrganizationError::OrganizationNotEmpty(_) => "OrganizationNotEmptyException",
+            DeleteOrganizationError::Service(_) => "ServiceException",
+            DeleteOrganizationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteOrganizationalUnit
 #[derive(Debug, PartialEq)]
 pub enum DeleteOrganizationalUnitError {
@@ -2483,6 +2668,28 @@ impl fmt::Display for DeleteOrganizationalUnitError {
     }
 }
 impl Error for DeleteOrganizationalUnitError {}
+impl ServiceErrorCode for DeleteOrganizationalUnitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteOrganizationalUnitError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            DeleteOrganizationalUnitError::AccessDenied(_) => "AccessDeniedException",
+            DeleteOrganizationalUnitError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteOrganizationalUnitError::InvalidInput(_) => "InvalidInputException",
+            DeleteOrganizationalUnitError::OrganizationalUnitNotEmpty(_) => {
+                "OrganizationalUnitNotEmptyException"
+            }
+            DeleteOrganizationalUnitError::OrganizationalUnitNotFound(_) => {
+                "OrganizationalUnitNotFoundException"
+            }
+            DeleteOrganizationalUnitError::Service(_) => "ServiceException",
+            DeleteOrganizationalUnitError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeletePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeletePolicyError {
@@ -2563,6 +2770,21 @@ impl fmt::Display for DeletePolicyError {
     }
 }
 impl Error for DeletePolicyError {}
+impl ServiceErrorCode for DeletePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePolicyError::AWSOrganizationsNotInUse(_) => "AWSOrganizationsNotInUseException",
+            DeletePolicyError::AccessDenied(_) => "AccessDeniedException",
+            DeletePolicyError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeletePolicyError::InvalidInput(_) => "InvalidInputException",
+            DeletePolicyError::PolicyInUse(_) => "PolicyInUseException",
+            DeletePolicyError::PolicyNotFound(_) => "PolicyNotFoundException",
+            DeletePolicyError::Service(_) => "ServiceException",
+            DeletePolicyError::TooManyRequests(_) => "TooManyRequestsException",
+            DeletePolicyError::UnsupportedAPIEndpoint(_) => "UnsupportedAPIEndpointException",
+        }
+    }
+}
 /// Errors returned by DeregisterDelegatedAdministrator
 #[derive(Debug, PartialEq)]
 pub enum DeregisterDelegatedAdministratorError {
@@ -2687,6 +2909,32 @@ impl fmt::Display for DeregisterDelegatedAdministratorError {
     }
 }
 impl Error for DeregisterDelegatedAdministratorError {}
+impl ServiceErrorCode for DeregisterDelegatedAdministratorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterDelegatedAdministratorError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            DeregisterDelegatedAdministratorError::AccessDenied(_) => "AccessDeniedException",
+            DeregisterDelegatedAdministratorError::AccountNotFound(_) => "AccountNotFoundException",
+            DeregisterDelegatedAdministratorError::AccountNotRegistered(_) => {
+                "AccountNotRegisteredException"
+            }
+            DeregisterDelegatedAdministratorError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeregisterDelegatedAdministratorError::ConstraintViolation(_) => {
+                "ConstraintViolationException"
+            }
+            DeregisterDelegatedAdministratorError::InvalidInput(_) => "InvalidInputException",
+            DeregisterDelegatedAdministratorError::Service(_) => "ServiceException",
+            DeregisterDelegatedAdministratorError::TooManyRequests(_) => "TooManyRequestsException",
+            DeregisterDelegatedAdministratorError::UnsupportedAPIEndpoint(_) => {
+                "UnsupportedAPIEndpointException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeAccount
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountError {
@@ -2749,6 +2997,20 @@ impl fmt::Display for DescribeAccountError {
     }
 }
 impl Error for DescribeAccountError {}
+impl ServiceErrorCode for DescribeAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAccountError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            DescribeAccountError::AccessDenied(_) => "AccessDeniedException",
+            DescribeAccountError::AccountNotFound(_) => "AccountNotFoundException",
+            DescribeAccountError::InvalidInput(_) => "InvalidInputException",
+            DescribeAccountError::Service(_) => "ServiceException",
+            DescribeAccountError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeCreateAccountStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeCreateAccountStatusError {
@@ -2835,6 +3097,25 @@ impl fmt::Display for DescribeCreateAccountStatusError {
     }
 }
 impl Error for DescribeCreateAccountStatusError {}
+impl ServiceErrorCode for DescribeCreateAccountStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCreateAccountStatusError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            DescribeCreateAccountStatusError::AccessDenied(_) => "AccessDeniedException",
+            DescribeCreateAccountStatusError::CreateAccountStatusNotFound(_) => {
+                "CreateAccountStatusNotFoundException"
+            }
+            DescribeCreateAccountStatusError::InvalidInput(_) => "InvalidInputException",
+            DescribeCreateAccountStatusError::Service(_) => "ServiceException",
+            DescribeCreateAccountStatusError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribeCreateAccountStatusError::UnsupportedAPIEndpoint(_) => {
+                "UnsupportedAPIEndpointException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeEffectivePolicy
 #[derive(Debug, PartialEq)]
 pub enum DescribeEffectivePolicyError {
@@ -2935,6 +3216,27 @@ impl fmt::Display for DescribeEffectivePolicyError {
     }
 }
 impl Error for DescribeEffectivePolicyError {}
+impl ServiceErrorCode for DescribeEffectivePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEffectivePolicyError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            DescribeEffectivePolicyError::AccessDenied(_) => "AccessDeniedException",
+            DescribeEffectivePolicyError::ConstraintViolation(_) => "ConstraintViolationException",
+            DescribeEffectivePolicyError::EffectivePolicyNotFound(_) => {
+                "EffectivePolicyNotFoundException"
+            }
+            DescribeEffectivePolicyError::InvalidInput(_) => "InvalidInputException",
+            DescribeEffectivePolicyError::Service(_) => "ServiceException",
+            DescribeEffectivePolicyError::TargetNotFound(_) => "TargetNotFoundException",
+            DescribeEffectivePolicyError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribeEffectivePolicyError::UnsupportedAPIEndpoint(_) => {
+                "UnsupportedAPIEndpointException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeHandshake
 #[derive(Debug, PartialEq)]
 pub enum DescribeHandshakeError {
@@ -2997,6 +3299,18 @@ impl fmt::Display for DescribeHandshakeError {
     }
 }
 impl Error for DescribeHandshakeError {}
+impl ServiceErrorCode for DescribeHandshakeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeHandshakeError::AccessDenied(_) => "AccessDeniedException",
+            DescribeHandshakeError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DescribeHandshakeError::HandshakeNotFound(_) => "HandshakeNotFoundException",
+            DescribeHandshakeError::InvalidInput(_) => "InvalidInputException",
+            DescribeHandshakeError::Service(_) => "ServiceException",
+            DescribeHandshakeError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeOrganization
 #[derive(Debug, PartialEq)]
 pub enum DescribeOrganizationError {
@@ -3059,6 +3373,21 @@ impl fmt::Display for DescribeOrganizationError {
     }
 }
 impl Error for DescribeOrganizationError {}
+impl ServiceErrorCode for DescribeOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeOrganizationError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            DescribeOrganizationError::AccessDenied(_) => "AccessDeniedException",
+            DescribeOrganizationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DescribeOrganizationError::Service(_) => "ServiceException",
+            DescribeOrganizationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeOrganizationalUnit
 #[derive(Debug, PartialEq)]
 pub enum DescribeOrganizationalUnitError {
@@ -3135,6 +3464,22 @@ impl fmt::Display for DescribeOrganizationalUnitError {
     }
 }
 impl Error for DescribeOrganizationalUnitError {}
+impl ServiceErrorCode for DescribeOrganizationalUnitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeOrganizationalUnitError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            DescribeOrganizationalUnitError::AccessDenied(_) => "AccessDeniedException",
+            DescribeOrganizationalUnitError::InvalidInput(_) => "InvalidInputException",
+            DescribeOrganizationalUnitError::OrganizationalUnitNotFound(_) => {
+                "OrganizationalUnitNotFoundException"
+            }
+            DescribeOrganizationalUnitError::Service(_) => "ServiceException",
+            DescribeOrganizationalUnitError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribePolicy
 #[derive(Debug, PartialEq)]
 pub enum DescribePolicyError {
@@ -3205,6 +3550,19 @@ impl fmt::Display for DescribePolicyError {
     }
 }
 impl Error for DescribePolicyError {}
+impl ServiceErrorCode for DescribePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePolicyError::AWSOrganizationsNotInUse(_) => "AWSOrganizationsNotInUseException",
+            DescribePolicyError::AccessDenied(_) => "AccessDeniedException",
+            DescribePolicyError::InvalidInput(_) => "InvalidInputException",
+            DescribePolicyError::PolicyNotFound(_) => "PolicyNotFoundException",
+            DescribePolicyError::Service(_) => "ServiceException",
+            DescribePolicyError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribePolicyError::UnsupportedAPIEndpoint(_) => "UnsupportedAPIEndpointException",
+        }
+    }
+}
 /// Errors returned by DetachPolicy
 #[derive(Debug, PartialEq)]
 pub enum DetachPolicyError {
@@ -3305,6 +3663,24 @@ impl fmt::Display for DetachPolicyError {
     }
 }
 impl Error for DetachPolicyError {}
+impl ServiceErrorCode for DetachPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachPolicyError::AWSOrganizationsNotInUse(_) => "AWSOrganizationsNotInUseException",
+            DetachPolicyError::AccessDenied(_) => "AccessDeniedException",
+            DetachPolicyError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DetachPolicyError::ConstraintViolation(_) => "ConstraintViolationException",
+            DetachPolicyError::InvalidInput(_) => "InvalidInputException",
+            DetachPolicyError::PolicyChangesInProgress(_) => "PolicyChangesInProgressException",
+            DetachPolicyError::PolicyNotAttached(_) => "PolicyNotAttachedException",
+            DetachPolicyError::PolicyNotFound(_) => "PolicyNotFoundException",
+            DetachPolicyError::Service(_) => "ServiceException",
+            DetachPolicyError::TargetNotFound(_) => "TargetNotFoundException",
+            DetachPolicyError::TooManyRequests(_) => "TooManyRequestsException",
+            DetachPolicyError::UnsupportedAPIEndpoint(_) => "UnsupportedAPIEndpointException",
+        }
+    }
+}
 /// Errors returned by DisableAWSServiceAccess
 #[derive(Debug, PartialEq)]
 pub enum DisableAWSServiceAccessError {
@@ -3397,6 +3773,26 @@ impl fmt::Display for DisableAWSServiceAccessError {
     }
 }
 impl Error for DisableAWSServiceAccessError {}
+impl ServiceErrorCode for DisableAWSServiceAccessError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableAWSServiceAccessError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            DisableAWSServiceAccessError::AccessDenied(_) => "AccessDeniedException",
+            DisableAWSServiceAccessError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DisableAWSServiceAccessError::ConstraintViolation(_) => "ConstraintViolationException",
+            DisableAWSServiceAccessError::InvalidInput(_) => "InvalidInputException",
+            DisableAWSServiceAccessError::Service(_) => "ServiceException",
+            DisableAWSServiceAccessError::TooManyRequests(_) => "TooManyRequestsException",
+            DisableAWSServiceAccessError::UnsupportedAPIEndpoint(_) => {
+                "UnsupportedAPIEndpointException"
+            }
+        }
+    }
+}
 /// Errors returned by DisablePolicyType
 #[derive(Debug, PartialEq)]
 pub enum DisablePolicyTypeError {
@@ -3499,6 +3895,27 @@ impl fmt::Display for DisablePolicyTypeError {
     }
 }
 impl Error for DisablePolicyTypeError {}
+impl ServiceErrorCode for DisablePolicyTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisablePolicyTypeError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            DisablePolicyTypeError::AccessDenied(_) => "AccessDeniedException",
+            DisablePolicyTypeError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DisablePolicyTypeError::ConstraintViolation(_) => "ConstraintViolationException",
+            DisablePolicyTypeError::InvalidInput(_) => "InvalidInputException",
+            DisablePolicyTypeError::PolicyChangesInProgress(_) => {
+                "PolicyChangesInProgressException"
+            }
+            DisablePolicyTypeError::PolicyTypeNotEnabled(_) => "PolicyTypeNotEnabledException",
+            DisablePolicyTypeError::RootNotFound(_) => "RootNotFoundException",
+            DisablePolicyTypeError::Service(_) => "ServiceException",
+            DisablePolicyTypeError::TooManyRequests(_) => "TooManyRequestsException",
+            DisablePolicyTypeError::UnsupportedAPIEndpoint(_) => "UnsupportedAPIEndpointException",
+        }
+    }
+}
 /// Errors returned by EnableAWSServiceAccess
 #[derive(Debug, PartialEq)]
 pub enum EnableAWSServiceAccessError {
@@ -3587,6 +4004,26 @@ impl fmt::Display for EnableAWSServiceAccessError {
     }
 }
 impl Error for EnableAWSServiceAccessError {}
+impl ServiceErrorCode for EnableAWSServiceAccessError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableAWSServiceAccessError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            EnableAWSServiceAccessError::AccessDenied(_) => "AccessDeniedException",
+            EnableAWSServiceAccessError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            EnableAWSServiceAccessError::ConstraintViolation(_) => "ConstraintViolationException",
+            EnableAWSServiceAccessError::InvalidInput(_) => "InvalidInputException",
+            EnableAWSServiceAccessError::Service(_) => "ServiceException",
+            EnableAWSServiceAccessError::TooManyRequests(_) => "TooManyRequestsException",
+            EnableAWSServiceAccessError::UnsupportedAPIEndpoint(_) => {
+                "UnsupportedAPIEndpointException"
+            }
+        }
+    }
+}
 /// Errors returned by EnableAllFeatures
 #[derive(Debug, PartialEq)]
 pub enum EnableAllFeaturesError {
@@ -3661,6 +4098,23 @@ impl fmt::Display for EnableAllFeaturesError {
     }
 }
 impl Error for EnableAllFeaturesError {}
+impl ServiceErrorCode for EnableAllFeaturesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableAllFeaturesError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            EnableAllFeaturesError::AccessDenied(_) => "AccessDeniedException",
+            EnableAllFeaturesError::ConcurrentModification(_) => "ConcurrentModificationException",
+            EnableAllFeaturesError::HandshakeConstraintViolation(_) => {
+                "HandshakeConstraintViolationException"
+            }
+            EnableAllFeaturesError::InvalidInput(_) => "InvalidInputException",
+            EnableAllFeaturesError::Service(_) => "ServiceException",
+            EnableAllFeaturesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by EnablePolicyType
 #[derive(Debug, PartialEq)]
 pub enum EnablePolicyTypeError {
@@ -3773,6 +4227,30 @@ impl fmt::Display for EnablePolicyTypeError {
     }
 }
 impl Error for EnablePolicyTypeError {}
+impl ServiceErrorCode for EnablePolicyTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnablePolicyTypeError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            EnablePolicyTypeError::AccessDenied(_) => "AccessDeniedException",
+            EnablePolicyTypeError::ConcurrentModification(_) => "ConcurrentModificationException",
+            EnablePolicyTypeError::ConstraintViolation(_) => "ConstraintViolationException",
+            EnablePolicyTypeError::InvalidInput(_) => "InvalidInputException",
+            EnablePolicyTypeError::PolicyChangesInProgress(_) => "PolicyChangesInProgressException",
+            EnablePolicyTypeError::PolicyTypeAlreadyEnabled(_) => {
+                "PolicyTypeAlreadyEnabledException"
+            }
+            EnablePolicyTypeError::PolicyTypeNotAvailableForOrganization(_) => {
+                "PolicyTypeNotAvailableForOrganizationException"
+            }
+            EnablePolicyTypeError::RootNotFound(_) => "RootNotFoundException",
+            EnablePolicyTypeError::Service(_) => "ServiceException",
+            EnablePolicyTypeError::TooManyRequests(_) => "TooManyRequestsException",
+            EnablePolicyTypeError::UnsupportedAPIEndpoint(_) => "UnsupportedAPIEndpointException",
+        }
+    }
+}
 /// Errors returned by InviteAccountToOrganization
 #[derive(Debug, PartialEq)]
 pub enum InviteAccountToOrganizationError {
@@ -3899,6 +4377,37 @@ impl fmt::Display for InviteAccountToOrganizationError {
     }
 }
 impl Error for InviteAccountToOrganizationError {}
+impl ServiceErrorCode for InviteAccountToOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InviteAccountToOrganizationError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            InviteAccountToOrganizationError::AccessDenied(_) => "AccessDeniedException",
+            InviteAccountToOrganizationError::AccountOwnerNotVerified(_) => {
+                "AccountOwnerNotVerifiedException"
+            }
+            InviteAccountToOrganizationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            InviteAccountToOrganizationError::ConstraintViolation(_) => {
+                "ConstraintViolationException"
+            }
+            InviteAccountToOrganizationError::DuplicateHandshake(_) => {
+                "DuplicateHandshakeException"
+            }
+            InviteAccountToOrganizationError::FinalizingOrganization(_) => {
+                "FinalizingOrganizationException"
+            }
+            InviteAccountToOrganizationError::HandshakeConstraintViolation(_) => {
+                "HandshakeConstraintViolationException"
+            }
+            InviteAccountToOrganizationError::InvalidInput(_) => "InvalidInputException",
+            InviteAccountToOrganizationError::Service(_) => "ServiceException",
+            InviteAccountToOrganizationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by LeaveOrganization
 #[derive(Debug, PartialEq)]
 pub enum LeaveOrganizationError {
@@ -3987,6 +4496,25 @@ impl fmt::Display for LeaveOrganizationError {
     }
 }
 impl Error for LeaveOrganizationError {}
+impl ServiceErrorCode for LeaveOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            LeaveOrganizationError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            LeaveOrganizationError::AccessDenied(_) => "AccessDeniedException",
+            LeaveOrganizationError::AccountNotFound(_) => "AccountNotFoundException",
+            LeaveOrganizationError::ConcurrentModification(_) => "ConcurrentModificationException",
+            LeaveOrganizationError::ConstraintViolation(_) => "ConstraintViolationException",
+            LeaveOrganizationError::InvalidInput(_) => "InvalidInputException",
+            LeaveOrganizationError::MasterCannotLeaveOrganization(_) => {
+                "MasterCannotLeaveOrganizationException"
+            }
+            LeaveOrganizationError::Service(_) => "ServiceException",
+            LeaveOrganizationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListAWSServiceAccessForOrganization
 #[derive(Debug, PartialEq)]
 pub enum ListAWSServiceAccessForOrganizationError {
@@ -4081,6 +4609,27 @@ impl fmt::Display for ListAWSServiceAccessForOrganizationError {
     }
 }
 impl Error for ListAWSServiceAccessForOrganizationError {}
+impl ServiceErrorCode for ListAWSServiceAccessForOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAWSServiceAccessForOrganizationError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            ListAWSServiceAccessForOrganizationError::AccessDenied(_) => "AccessDeniedException",
+            ListAWSServiceAccessForOrganizationError::ConstraintViolation(_) => {
+                "ConstraintViolationException"
+            }
+            ListAWSServiceAccessForOrganizationError::InvalidInput(_) => "InvalidInputException",
+            ListAWSServiceAccessForOrganizationError::Service(_) => "ServiceException",
+            ListAWSServiceAccessForOrganizationError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+            ListAWSServiceAccessForOrganizationError::UnsupportedAPIEndpoint(_) => {
+                "UnsupportedAPIEndpointException"
+            }
+        }
+    }
+}
 /// Errors returned by ListAccounts
 #[derive(Debug, PartialEq)]
 pub enum ListAccountsError {
@@ -4137,6 +4686,17 @@ impl fmt::Display for ListAccountsError {
     }
 }
 impl Error for ListAccountsError {}
+impl ServiceErrorCode for ListAccountsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAccountsError::AWSOrganizationsNotInUse(_) => "AWSOrganizationsNotInUseException",
+            ListAccountsError::AccessDenied(_) => "AccessDeniedException",
+            ListAccountsError::InvalidInput(_) => "InvalidInputException",
+            ListAccountsError::Service(_) => "ServiceException",
+            ListAccountsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListAccountsForParent
 #[derive(Debug, PartialEq)]
 pub enum ListAccountsForParentError {
@@ -4205,6 +4765,20 @@ impl fmt::Display for ListAccountsForParentError {
     }
 }
 impl Error for ListAccountsForParentError {}
+impl ServiceErrorCode for ListAccountsForParentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAccountsForParentError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            ListAccountsForParentError::AccessDenied(_) => "AccessDeniedException",
+            ListAccountsForParentError::InvalidInput(_) => "InvalidInputException",
+            ListAccountsForParentError::ParentNotFound(_) => "ParentNotFoundException",
+            ListAccountsForParentError::Service(_) => "ServiceException",
+            ListAccountsForParentError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListChildren
 #[derive(Debug, PartialEq)]
 pub enum ListChildrenError {
@@ -4267,6 +4841,18 @@ impl fmt::Display for ListChildrenError {
     }
 }
 impl Error for ListChildrenError {}
+impl ServiceErrorCode for ListChildrenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListChildrenError::AWSOrganizationsNotInUse(_) => "AWSOrganizationsNotInUseException",
+            ListChildrenError::AccessDenied(_) => "AccessDeniedException",
+            ListChildrenError::InvalidInput(_) => "InvalidInputException",
+            ListChildrenError::ParentNotFound(_) => "ParentNotFoundException",
+            ListChildrenError::Service(_) => "ServiceException",
+            ListChildrenError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListCreateAccountStatus
 #[derive(Debug, PartialEq)]
 pub enum ListCreateAccountStatusError {
@@ -4341,6 +4927,22 @@ impl fmt::Display for ListCreateAccountStatusError {
     }
 }
 impl Error for ListCreateAccountStatusError {}
+impl ServiceErrorCode for ListCreateAccountStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCreateAccountStatusError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            ListCreateAccountStatusError::AccessDenied(_) => "AccessDeniedException",
+            ListCreateAccountStatusError::InvalidInput(_) => "InvalidInputException",
+            ListCreateAccountStatusError::Service(_) => "ServiceException",
+            ListCreateAccountStatusError::TooManyRequests(_) => "TooManyRequestsException",
+            ListCreateAccountStatusError::UnsupportedAPIEndpoint(_) => {
+                "UnsupportedAPIEndpointException"
+            }
+        }
+    }
+}
 /// Errors returned by ListDelegatedAdministrators
 #[derive(Debug, PartialEq)]
 pub enum ListDelegatedAdministratorsError {
@@ -4427,6 +5029,25 @@ impl fmt::Display for ListDelegatedAdministratorsError {
     }
 }
 impl Error for ListDelegatedAdministratorsError {}
+impl ServiceErrorCode for ListDelegatedAdministratorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDelegatedAdministratorsError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            ListDelegatedAdministratorsError::AccessDenied(_) => "AccessDeniedException",
+            ListDelegatedAdministratorsError::ConstraintViolation(_) => {
+                "ConstraintViolationException"
+            }
+            ListDelegatedAdministratorsError::InvalidInput(_) => "InvalidInputException",
+            ListDelegatedAdministratorsError::Service(_) => "ServiceException",
+            ListDelegatedAdministratorsError::TooManyRequests(_) => "TooManyRequestsException",
+            ListDelegatedAdministratorsError::UnsupportedAPIEndpoint(_) => {
+                "UnsupportedAPIEndpointException"
+            }
+        }
+    }
+}
 /// Errors returned by ListDelegatedServicesForAccount
 #[derive(Debug, PartialEq)]
 pub enum ListDelegatedServicesForAccountError {
@@ -4537,6 +5158,29 @@ impl fmt::Display for ListDelegatedServicesForAccountError {
     }
 }
 impl Error for ListDelegatedServicesForAccountError {}
+impl ServiceErrorCode for ListDelegatedServicesForAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDelegatedServicesForAccountError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            ListDelegatedServicesForAccountError::AccessDenied(_) => "AccessDeniedException",
+            ListDelegatedServicesForAccountError::AccountNotFound(_) => "AccountNotFoundException",
+            ListDelegatedServicesForAccountError::AccountNotRegistered(_) => {
+                "AccountNotRegisteredException"
+            }
+            ListDelegatedServicesForAccountError::ConstraintViolation(_) => {
+                "ConstraintViolationException"
+            }
+            ListDelegatedServicesForAccountError::InvalidInput(_) => "InvalidInputException",
+            ListDelegatedServicesForAccountError::Service(_) => "ServiceException",
+            ListDelegatedServicesForAccountError::TooManyRequests(_) => "TooManyRequestsException",
+            ListDelegatedServicesForAccountError::UnsupportedAPIEndpoint(_) => {
+                "UnsupportedAPIEndpointException"
+            }
+        }
+    }
+}
 /// Errors returned by ListHandshakesForAccount
 #[derive(Debug, PartialEq)]
 pub enum ListHandshakesForAccountError {
@@ -4601,6 +5245,19 @@ impl fmt::Display for ListHandshakesForAccountError {
     }
 }
 impl Error for ListHandshakesForAccountError {}
+impl ServiceErrorCode for ListHandshakesForAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListHandshakesForAccountError::AccessDenied(_) => "AccessDeniedException",
+            ListHandshakesForAccountError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            ListHandshakesForAccountError::InvalidInput(_) => "InvalidInputException",
+            ListHandshakesForAccountError::Service(_) => "ServiceException",
+            ListHandshakesForAccountError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListHandshakesForOrganization
 #[derive(Debug, PartialEq)]
 pub enum ListHandshakesForOrganizationError {
@@ -4681,6 +5338,22 @@ impl fmt::Display for ListHandshakesForOrganizationError {
     }
 }
 impl Error for ListHandshakesForOrganizationError {}
+impl ServiceErrorCode for ListHandshakesForOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListHandshakesForOrganizationError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            ListHandshakesForOrganizationError::AccessDenied(_) => "AccessDeniedException",
+            ListHandshakesForOrganizationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            ListHandshakesForOrganizationError::InvalidInput(_) => "InvalidInputException",
+            ListHandshakesForOrganizationError::Service(_) => "ServiceException",
+            ListHandshakesForOrganizationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListOrganizationalUnitsForParent
 #[derive(Debug, PartialEq)]
 pub enum ListOrganizationalUnitsForParentError {
@@ -4765,6 +5438,20 @@ impl fmt::Display for ListOrganizationalUnitsForParentError {
     }
 }
 impl Error for ListOrganizationalUnitsForParentError {}
+impl ServiceErrorCode for ListOrganizationalUnitsForParentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOrganizationalUnitsForParentError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            ListOrganizationalUnitsForParentError::AccessDenied(_) => "AccessDeniedException",
+            ListOrganizationalUnitsForParentError::InvalidInput(_) => "InvalidInputException",
+            ListOrganizationalUnitsForParentError::ParentNotFound(_) => "ParentNotFoundException",
+            ListOrganizationalUnitsForParentError::Service(_) => "ServiceException",
+            ListOrganizationalUnitsForParentError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListParents
 #[derive(Debug, PartialEq)]
 pub enum ListParentsError {
@@ -4827,6 +5514,18 @@ impl fmt::Display for ListParentsError {
     }
 }
 impl Error for ListParentsError {}
+impl ServiceErrorCode for ListParentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListParentsError::AWSOrganizationsNotInUse(_) => "AWSOrganizationsNotInUseException",
+            ListParentsError::AccessDenied(_) => "AccessDeniedException",
+            ListParentsError::ChildNotFound(_) => "ChildNotFoundException",
+            ListParentsError::InvalidInput(_) => "InvalidInputException",
+            ListParentsError::Service(_) => "ServiceException",
+            ListParentsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListPoliciesError {
@@ -4889,6 +5588,18 @@ impl fmt::Display for ListPoliciesError {
     }
 }
 impl Error for ListPoliciesError {}
+impl ServiceErrorCode for ListPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPoliciesError::AWSOrganizationsNotInUse(_) => "AWSOrganizationsNotInUseException",
+            ListPoliciesError::AccessDenied(_) => "AccessDeniedException",
+            ListPoliciesError::InvalidInput(_) => "InvalidInputException",
+            ListPoliciesError::Service(_) => "ServiceException",
+            ListPoliciesError::TooManyRequests(_) => "TooManyRequestsException",
+            ListPoliciesError::UnsupportedAPIEndpoint(_) => "UnsupportedAPIEndpointException",
+        }
+    }
+}
 /// Errors returned by ListPoliciesForTarget
 #[derive(Debug, PartialEq)]
 pub enum ListPoliciesForTargetError {
@@ -4965,6 +5676,23 @@ impl fmt::Display for ListPoliciesForTargetError {
     }
 }
 impl Error for ListPoliciesForTargetError {}
+impl ServiceErrorCode for ListPoliciesForTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPoliciesForTargetError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            ListPoliciesForTargetError::AccessDenied(_) => "AccessDeniedException",
+            ListPoliciesForTargetError::InvalidInput(_) => "InvalidInputException",
+            ListPoliciesForTargetError::Service(_) => "ServiceException",
+            ListPoliciesForTargetError::TargetNotFound(_) => "TargetNotFoundException",
+            ListPoliciesForTargetError::TooManyRequests(_) => "TooManyRequestsException",
+            ListPoliciesForTargetError::UnsupportedAPIEndpoint(_) => {
+                "UnsupportedAPIEndpointException"
+            }
+        }
+    }
+}
 /// Errors returned by ListRoots
 #[derive(Debug, PartialEq)]
 pub enum ListRootsError {
@@ -5019,6 +5747,17 @@ impl fmt::Display for ListRootsError {
     }
 }
 impl Error for ListRootsError {}
+impl ServiceErrorCode for ListRootsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRootsError::AWSOrganizationsNotInUse(_) => "AWSOrganizationsNotInUseException",
+            ListRootsError::AccessDenied(_) => "AccessDeniedException",
+            ListRootsError::InvalidInput(_) => "InvalidInputException",
+            ListRootsError::Service(_) => "ServiceException",
+            ListRootsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -5081,6 +5820,20 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            ListTagsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            ListTagsForResourceError::InvalidInput(_) => "InvalidInputException",
+            ListTagsForResourceError::Service(_) => "ServiceException",
+            ListTagsForResourceError::TargetNotFound(_) => "TargetNotFoundException",
+            ListTagsForResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTargetsForPolicy
 #[derive(Debug, PartialEq)]
 pub enum ListTargetsForPolicyError {
@@ -5155,6 +5908,23 @@ impl fmt::Display for ListTargetsForPolicyError {
     }
 }
 impl Error for ListTargetsForPolicyError {}
+impl ServiceErrorCode for ListTargetsForPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTargetsForPolicyError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            ListTargetsForPolicyError::AccessDenied(_) => "AccessDeniedException",
+            ListTargetsForPolicyError::InvalidInput(_) => "InvalidInputException",
+            ListTargetsForPolicyError::PolicyNotFound(_) => "PolicyNotFoundException",
+            ListTargetsForPolicyError::Service(_) => "ServiceException",
+            ListTargetsForPolicyError::TooManyRequests(_) => "TooManyRequestsException",
+            ListTargetsForPolicyError::UnsupportedAPIEndpoint(_) => {
+                "UnsupportedAPIEndpointException"
+            }
+        }
+    }
+}
 /// Errors returned by MoveAccount
 #[derive(Debug, PartialEq)]
 pub enum MoveAccountError {
@@ -5243,6 +6013,22 @@ impl fmt::Display for MoveAccountError {
     }
 }
 impl Error for MoveAccountError {}
+impl ServiceErrorCode for MoveAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            MoveAccountError::AWSOrganizationsNotInUse(_) => "AWSOrganizationsNotInUseException",
+            MoveAccountError::AccessDenied(_) => "AccessDeniedException",
+            MoveAccountError::AccountNotFound(_) => "AccountNotFoundException",
+            MoveAccountError::ConcurrentModification(_) => "ConcurrentModificationException",
+            MoveAccountError::DestinationParentNotFound(_) => "DestinationParentNotFoundException",
+            MoveAccountError::DuplicateAccount(_) => "DuplicateAccountException",
+            MoveAccountError::InvalidInput(_) => "InvalidInputException",
+            MoveAccountError::Service(_) => "ServiceException",
+            MoveAccountError::SourceParentNotFound(_) => "SourceParentNotFoundException",
+            MoveAccountError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by RegisterDelegatedAdministrator
 #[derive(Debug, PartialEq)]
 pub enum RegisterDelegatedAdministratorError {
@@ -5363,6 +6149,32 @@ impl fmt::Display for RegisterDelegatedAdministratorError {
     }
 }
 impl Error for RegisterDelegatedAdministratorError {}
+impl ServiceErrorCode for RegisterDelegatedAdministratorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterDelegatedAdministratorError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            RegisterDelegatedAdministratorError::AccessDenied(_) => "AccessDeniedException",
+            RegisterDelegatedAdministratorError::AccountAlreadyRegistered(_) => {
+                "AccountAlreadyRegisteredException"
+            }
+            RegisterDelegatedAdministratorError::AccountNotFound(_) => "AccountNotFoundException",
+            RegisterDelegatedAdministratorError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            RegisterDelegatedAdministratorError::ConstraintViolation(_) => {
+                "ConstraintViolationException"
+            }
+            RegisterDelegatedAdministratorError::InvalidInput(_) => "InvalidInputException",
+            RegisterDelegatedAdministratorError::Service(_) => "ServiceException",
+            RegisterDelegatedAdministratorError::TooManyRequests(_) => "TooManyRequestsException",
+            RegisterDelegatedAdministratorError::UnsupportedAPIEndpoint(_) => {
+                "UnsupportedAPIEndpointException"
+            }
+        }
+    }
+}
 /// Errors returned by RemoveAccountFromOrganization
 #[derive(Debug, PartialEq)]
 pub enum RemoveAccountFromOrganizationError {
@@ -5473,6 +6285,29 @@ impl fmt::Display for RemoveAccountFromOrganizationError {
     }
 }
 impl Error for RemoveAccountFromOrganizationError {}
+impl ServiceErrorCode for RemoveAccountFromOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveAccountFromOrganizationError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            RemoveAccountFromOrganizationError::AccessDenied(_) => "AccessDeniedException",
+            RemoveAccountFromOrganizationError::AccountNotFound(_) => "AccountNotFoundException",
+            RemoveAccountFromOrganizationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            RemoveAccountFromOrganizationError::ConstraintViolation(_) => {
+                "ConstraintViolationException"
+            }
+            RemoveAccountFromOrganizationError::InvalidInput(_) => "InvalidInputException",
+            RemoveAccountFromOrganizationError::MasterCannotLeaveOrganization(_) => {
+                "MasterCannotLeaveOrganizationException"
+            }
+            RemoveAccountFromOrganizationError::Service(_) => "ServiceException",
+            RemoveAccountFromOrganizationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -5547,6 +6382,20 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AWSOrganizationsNotInUse(_) => "AWSOrganizationsNotInUseException",
+            TagResourceError::AccessDenied(_) => "AccessDeniedException",
+            TagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            TagResourceError::ConstraintViolation(_) => "ConstraintViolationException",
+            TagResourceError::InvalidInput(_) => "InvalidInputException",
+            TagResourceError::Service(_) => "ServiceException",
+            TagResourceError::TargetNotFound(_) => "TargetNotFoundException",
+            TagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -5623,6 +6472,20 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AWSOrganizationsNotInUse(_) => "AWSOrganizationsNotInUseException",
+            UntagResourceError::AccessDenied(_) => "AccessDeniedException",
+            UntagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UntagResourceError::ConstraintViolation(_) => "ConstraintViolationException",
+            UntagResourceError::InvalidInput(_) => "InvalidInputException",
+            UntagResourceError::Service(_) => "ServiceException",
+            UntagResourceError::TargetNotFound(_) => "TargetNotFoundException",
+            UntagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateOrganizationalUnit
 #[derive(Debug, PartialEq)]
 pub enum UpdateOrganizationalUnitError {
@@ -5717,6 +6580,28 @@ impl fmt::Display for UpdateOrganizationalUnitError {
     }
 }
 impl Error for UpdateOrganizationalUnitError {}
+impl ServiceErrorCode for UpdateOrganizationalUnitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateOrganizationalUnitError::AWSOrganizationsNotInUse(_) => {
+                "AWSOrganizationsNotInUseException"
+            }
+            UpdateOrganizationalUnitError::AccessDenied(_) => "AccessDeniedException",
+            UpdateOrganizationalUnitError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            UpdateOrganizationalUnitError::DuplicateOrganizationalUnit(_) => {
+                "DuplicateOrganizationalUnitException"
+            }
+            UpdateOrganizationalUnitError::InvalidInput(_) => "InvalidInputException",
+            UpdateOrganizationalUnitError::OrganizationalUnitNotFound(_) => {
+                "OrganizationalUnitNotFoundException"
+            }
+            UpdateOrganizationalUnitError::Service(_) => "ServiceException",
+            UpdateOrganizationalUnitError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdatePolicy
 #[derive(Debug, PartialEq)]
 pub enum UpdatePolicyError {
@@ -5819,6 +6704,24 @@ impl fmt::Display for UpdatePolicyError {
     }
 }
 impl Error for UpdatePolicyError {}
+impl ServiceErrorCode for UpdatePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePolicyError::AWSOrganizationsNotInUse(_) => "AWSOrganizationsNotInUseException",
+            UpdatePolicyError::AccessDenied(_) => "AccessDeniedException",
+            UpdatePolicyError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdatePolicyError::ConstraintViolation(_) => "ConstraintViolationException",
+            UpdatePolicyError::DuplicatePolicy(_) => "DuplicatePolicyException",
+            UpdatePolicyError::InvalidInput(_) => "InvalidInputException",
+            UpdatePolicyError::MalformedPolicyDocument(_) => "MalformedPolicyDocumentException",
+            UpdatePolicyError::PolicyChangesInProgress(_) => "PolicyChangesInProgressException",
+            UpdatePolicyError::PolicyNotFound(_) => "PolicyNotFoundException",
+            UpdatePolicyError::Service(_) => "ServiceException",
+            UpdatePolicyError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdatePolicyError::UnsupportedAPIEndpoint(_) => "UnsupportedAPIEndpointException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Organizations API. Organizations clients implement this trait.
 #[async_trait]
 pub trait Organizations {
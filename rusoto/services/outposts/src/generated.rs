@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -329,6 +329,16 @@ impl fmt::Display for CreateOutpostError {
     }
 }
 impl Error for CreateOutpostError {}
+impl ServiceErrorCode for CreateOutpostError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateOutpostError::AccessDenied(_) => "AccessDeniedException",
+            CreateOutpostError::InternalServer(_) => "InternalServerException",
+            CreateOutpostError::NotFound(_) => "NotFoundException",
+            CreateOutpostError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+        }
+    }
+}
 /// Errors returned by DeleteOutpost
 #[derive(Debug, PartialEq)]
 pub enum DeleteOutpostError {
@@ -371,6 +381,15 @@ impl fmt::Display for DeleteOutpostError {
     }
 }
 impl Error for DeleteOutpostError {}
+impl ServiceErrorCode for DeleteOutpostError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteOutpostError::AccessDenied(_) => "AccessDeniedException",
+            DeleteOutpostError::InternalServer(_) => "InternalServerException",
+            DeleteOutpostError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteSite
 #[derive(Debug, PartialEq)]
 pub enum DeleteSiteError {
@@ -413,6 +432,15 @@ impl fmt::Display for DeleteSiteError {
     }
 }
 impl Error for DeleteSiteError {}
+impl ServiceErrorCode for DeleteSiteError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSiteError::AccessDenied(_) => "AccessDeniedException",
+            DeleteSiteError::InternalServer(_) => "InternalServerException",
+            DeleteSiteError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetOutpost
 #[derive(Debug, PartialEq)]
 pub enum GetOutpostError {
@@ -455,6 +483,15 @@ impl fmt::Display for GetOutpostError {
     }
 }
 impl Error for GetOutpostError {}
+impl ServiceErrorCode for GetOutpostError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOutpostError::AccessDenied(_) => "AccessDeniedException",
+            GetOutpostError::InternalServer(_) => "InternalServerException",
+            GetOutpostError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetOutpostInstanceTypes
 #[derive(Debug, PartialEq)]
 pub enum GetOutpostInstanceTypesError {
@@ -501,6 +538,15 @@ impl fmt::Display for GetOutpostInstanceTypesError {
     }
 }
 impl Error for GetOutpostInstanceTypesError {}
+impl ServiceErrorCode for GetOutpostInstanceTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOutpostInstanceTypesError::AccessDenied(_) => "AccessDeniedException",
+            GetOutpostInstanceTypesError::InternalServer(_) => "InternalServerException",
+            GetOutpostInstanceTypesError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListOutposts
 #[derive(Debug, PartialEq)]
 pub enum ListOutpostsError {
@@ -537,6 +583,14 @@ impl fmt::Display for ListOutpostsError {
     }
 }
 impl Error for ListOutpostsError {}
+impl ServiceErrorCode for ListOutpostsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOutpostsError::AccessDenied(_) => "AccessDeniedException",
+            ListOutpostsError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by ListSites
 #[derive(Debug, PartialEq)]
 pub enum ListSitesError {
@@ -573,6 +627,14 @@ impl fmt::Display for ListSitesError {
     }
 }
 impl Error for ListSitesError {}
+impl ServiceErrorCode for ListSitesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSitesError::AccessDenied(_) => "AccessDeniedException",
+            ListSitesError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -609,6 +671,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalServer(_) => "InternalServerException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -645,6 +715,14 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalServer(_) => "InternalServerException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -681,6 +759,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalServer(_) => "InternalServerException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Outposts API. Outposts clients implement this trait.
 #[async_trait]
 pub trait Outposts {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -156,6 +156,13 @@ impl fmt::Display for PutEventsError {
     }
 }
 impl Error for PutEventsError {}
+impl ServiceErrorCode for PutEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutEventsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by PutItems
 #[derive(Debug, PartialEq)]
 pub enum PutItemsError {
@@ -192,6 +199,14 @@ impl fmt::Display for PutItemsError {
     }
 }
 impl Error for PutItemsError {}
+impl ServiceErrorCode for PutItemsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutItemsError::InvalidInput(_) => "InvalidInputException",
+            PutItemsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutUsers
 #[derive(Debug, PartialEq)]
 pub enum PutUsersError {
@@ -228,6 +243,14 @@ impl fmt::Display for PutUsersError {
     }
 }
 impl Error for PutUsersError {}
+impl ServiceErrorCode for PutUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutUsersError::InvalidInput(_) => "InvalidInputException",
+            PutUsersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Personalize Events API. Amazon Personalize Events clients implement this trait.
 #[async_trait]
 pub trait PersonalizeEvents {
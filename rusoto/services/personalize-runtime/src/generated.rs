@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -160,6 +160,14 @@ impl fmt::Display for GetPersonalizedRankingError {
     }
 }
 impl Error for GetPersonalizedRankingError {}
+impl ServiceErrorCode for GetPersonalizedRankingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPersonalizedRankingError::InvalidInput(_) => "InvalidInputException",
+            GetPersonalizedRankingError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetRecommendations
 #[derive(Debug, PartialEq)]
 pub enum GetRecommendationsError {
@@ -196,6 +204,14 @@ impl fmt::Display for GetRecommendationsError {
     }
 }
 impl Error for GetRecommendationsError {}
+impl ServiceErrorCode for GetRecommendationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRecommendationsError::InvalidInput(_) => "InvalidInputException",
+            GetRecommendationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Personalize Runtime API. Amazon Personalize Runtime clients implement this trait.
 #[async_trait]
 pub trait PersonalizeRuntime {
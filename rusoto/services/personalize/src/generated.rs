@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2267,6 +2267,19 @@ impl fmt::Display for CreateBatchInferenceJobError {
     }
 }
 impl Error for CreateBatchInferenceJobError {}
+impl ServiceErrorCode for CreateBatchInferenceJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBatchInferenceJobError::InvalidInput(_) => "InvalidInputException",
+            CreateBatchInferenceJobError::LimitExceeded(_) => "LimitExceededException",
+            CreateBatchInferenceJobError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateBatchInferenceJobError::ResourceInUse(_) => "ResourceInUseException",
+            CreateBatchInferenceJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateCampaign
 #[derive(Debug, PartialEq)]
 pub enum CreateCampaignError {
@@ -2323,6 +2336,17 @@ impl fmt::Display for CreateCampaignError {
     }
 }
 impl Error for CreateCampaignError {}
+impl ServiceErrorCode for CreateCampaignError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCampaignError::InvalidInput(_) => "InvalidInputException",
+            CreateCampaignError::LimitExceeded(_) => "LimitExceededException",
+            CreateCampaignError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateCampaignError::ResourceInUse(_) => "ResourceInUseException",
+            CreateCampaignError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateDataset
 #[derive(Debug, PartialEq)]
 pub enum CreateDatasetError {
@@ -2377,6 +2401,17 @@ impl fmt::Display for CreateDatasetError {
     }
 }
 impl Error for CreateDatasetError {}
+impl ServiceErrorCode for CreateDatasetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDatasetError::InvalidInput(_) => "InvalidInputException",
+            CreateDatasetError::LimitExceeded(_) => "LimitExceededException",
+            CreateDatasetError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateDatasetError::ResourceInUse(_) => "ResourceInUseException",
+            CreateDatasetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateDatasetGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateDatasetGroupError {
@@ -2421,6 +2456,15 @@ impl fmt::Display for CreateDatasetGroupError {
     }
 }
 impl Error for CreateDatasetGroupError {}
+impl ServiceErrorCode for CreateDatasetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDatasetGroupError::InvalidInput(_) => "InvalidInputException",
+            CreateDatasetGroupError::LimitExceeded(_) => "LimitExceededException",
+            CreateDatasetGroupError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by CreateDatasetImportJob
 #[derive(Debug, PartialEq)]
 pub enum CreateDatasetImportJobError {
@@ -2483,6 +2527,19 @@ impl fmt::Display for CreateDatasetImportJobError {
     }
 }
 impl Error for CreateDatasetImportJobError {}
+impl ServiceErrorCode for CreateDatasetImportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDatasetImportJobError::InvalidInput(_) => "InvalidInputException",
+            CreateDatasetImportJobError::LimitExceeded(_) => "LimitExceededException",
+            CreateDatasetImportJobError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateDatasetImportJobError::ResourceInUse(_) => "ResourceInUseException",
+            CreateDatasetImportJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateEventTracker
 #[derive(Debug, PartialEq)]
 pub enum CreateEventTrackerError {
@@ -2539,6 +2596,17 @@ impl fmt::Display for CreateEventTrackerError {
     }
 }
 impl Error for CreateEventTrackerError {}
+impl ServiceErrorCode for CreateEventTrackerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEventTrackerError::InvalidInput(_) => "InvalidInputException",
+            CreateEventTrackerError::LimitExceeded(_) => "LimitExceededException",
+            CreateEventTrackerError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateEventTrackerError::ResourceInUse(_) => "ResourceInUseException",
+            CreateEventTrackerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateFilter
 #[derive(Debug, PartialEq)]
 pub enum CreateFilterError {
@@ -2587,6 +2655,16 @@ impl fmt::Display for CreateFilterError {
     }
 }
 impl Error for CreateFilterError {}
+impl ServiceErrorCode for CreateFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFilterError::InvalidInput(_) => "InvalidInputException",
+            CreateFilterError::LimitExceeded(_) => "LimitExceededException",
+            CreateFilterError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateFilterError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateSchema
 #[derive(Debug, PartialEq)]
 pub enum CreateSchemaError {
@@ -2629,6 +2707,15 @@ impl fmt::Display for CreateSchemaError {
     }
 }
 impl Error for CreateSchemaError {}
+impl ServiceErrorCode for CreateSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSchemaError::InvalidInput(_) => "InvalidInputException",
+            CreateSchemaError::LimitExceeded(_) => "LimitExceededException",
+            CreateSchemaError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by CreateSolution
 #[derive(Debug, PartialEq)]
 pub enum CreateSolutionError {
@@ -2685,6 +2772,17 @@ impl fmt::Display for CreateSolutionError {
     }
 }
 impl Error for CreateSolutionError {}
+impl ServiceErrorCode for CreateSolutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSolutionError::InvalidInput(_) => "InvalidInputException",
+            CreateSolutionError::LimitExceeded(_) => "LimitExceededException",
+            CreateSolutionError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateSolutionError::ResourceInUse(_) => "ResourceInUseException",
+            CreateSolutionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateSolutionVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateSolutionVersionError {
@@ -2735,6 +2833,16 @@ impl fmt::Display for CreateSolutionVersionError {
     }
 }
 impl Error for CreateSolutionVersionError {}
+impl ServiceErrorCode for CreateSolutionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSolutionVersionError::InvalidInput(_) => "InvalidInputException",
+            CreateSolutionVersionError::LimitExceeded(_) => "LimitExceededException",
+            CreateSolutionVersionError::ResourceInUse(_) => "ResourceInUseException",
+            CreateSolutionVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteCampaign
 #[derive(Debug, PartialEq)]
 pub enum DeleteCampaignError {
@@ -2777,6 +2885,15 @@ impl fmt::Display for DeleteCampaignError {
     }
 }
 impl Error for DeleteCampaignError {}
+impl ServiceErrorCode for DeleteCampaignError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCampaignError::InvalidInput(_) => "InvalidInputException",
+            DeleteCampaignError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteCampaignError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteDataset
 #[derive(Debug, PartialEq)]
 pub enum DeleteDatasetError {
@@ -2819,6 +2936,15 @@ impl fmt::Display for DeleteDatasetError {
     }
 }
 impl Error for DeleteDatasetError {}
+impl ServiceErrorCode for DeleteDatasetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDatasetError::InvalidInput(_) => "InvalidInputException",
+            DeleteDatasetError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteDatasetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteDatasetGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteDatasetGroupError {
@@ -2861,6 +2987,15 @@ impl fmt::Display for DeleteDatasetGroupError {
     }
 }
 impl Error for DeleteDatasetGroupError {}
+impl ServiceErrorCode for DeleteDatasetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDatasetGroupError::InvalidInput(_) => "InvalidInputException",
+            DeleteDatasetGroupError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteDatasetGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteEventTracker
 #[derive(Debug, PartialEq)]
 pub enum DeleteEventTrackerError {
@@ -2903,6 +3038,15 @@ impl fmt::Display for DeleteEventTrackerError {
     }
 }
 impl Error for DeleteEventTrackerError {}
+impl ServiceErrorCode for DeleteEventTrackerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEventTrackerError::InvalidInput(_) => "InvalidInputException",
+            DeleteEventTrackerError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteEventTrackerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteFilter
 #[derive(Debug, PartialEq)]
 pub enum DeleteFilterError {
@@ -2945,6 +3089,15 @@ impl fmt::Display for DeleteFilterError {
     }
 }
 impl Error for DeleteFilterError {}
+impl ServiceErrorCode for DeleteFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFilterError::InvalidInput(_) => "InvalidInputException",
+            DeleteFilterError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteFilterError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteSchema
 #[derive(Debug, PartialEq)]
 pub enum DeleteSchemaError {
@@ -2987,6 +3140,15 @@ impl fmt::Display for DeleteSchemaError {
     }
 }
 impl Error for DeleteSchemaError {}
+impl ServiceErrorCode for DeleteSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSchemaError::InvalidInput(_) => "InvalidInputException",
+            DeleteSchemaError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteSchemaError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteSolution
 #[derive(Debug, PartialEq)]
 pub enum DeleteSolutionError {
@@ -3029,6 +3191,15 @@ impl fmt::Display for DeleteSolutionError {
     }
 }
 impl Error for DeleteSolutionError {}
+impl ServiceErrorCode for DeleteSolutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSolutionError::InvalidInput(_) => "InvalidInputException",
+            DeleteSolutionError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteSolutionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeAlgorithm
 #[derive(Debug, PartialEq)]
 pub enum DescribeAlgorithmError {
@@ -3065,6 +3236,14 @@ impl fmt::Display for DescribeAlgorithmError {
     }
 }
 impl Error for DescribeAlgorithmError {}
+impl ServiceErrorCode for DescribeAlgorithmError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAlgorithmError::InvalidInput(_) => "InvalidInputException",
+            DescribeAlgorithmError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeBatchInferenceJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeBatchInferenceJobError {
@@ -3105,6 +3284,14 @@ impl fmt::Display for DescribeBatchInferenceJobError {
     }
 }
 impl Error for DescribeBatchInferenceJobError {}
+impl ServiceErrorCode for DescribeBatchInferenceJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBatchInferenceJobError::InvalidInput(_) => "InvalidInputException",
+            DescribeBatchInferenceJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeCampaign
 #[derive(Debug, PartialEq)]
 pub enum DescribeCampaignError {
@@ -3141,6 +3328,14 @@ impl fmt::Display for DescribeCampaignError {
     }
 }
 impl Error for DescribeCampaignError {}
+impl ServiceErrorCode for DescribeCampaignError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCampaignError::InvalidInput(_) => "InvalidInputException",
+            DescribeCampaignError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeDataset
 #[derive(Debug, PartialEq)]
 pub enum DescribeDatasetError {
@@ -3177,6 +3372,14 @@ impl fmt::Display for DescribeDatasetError {
     }
 }
 impl Error for DescribeDatasetError {}
+impl ServiceErrorCode for DescribeDatasetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDatasetError::InvalidInput(_) => "InvalidInputException",
+            DescribeDatasetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeDatasetGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeDatasetGroupError {
@@ -3215,6 +3418,14 @@ impl fmt::Display for DescribeDatasetGroupError {
     }
 }
 impl Error for DescribeDatasetGroupError {}
+impl ServiceErrorCode for DescribeDatasetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDatasetGroupError::InvalidInput(_) => "InvalidInputException",
+            DescribeDatasetGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeDatasetImportJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeDatasetImportJobError {
@@ -3255,6 +3466,14 @@ impl fmt::Display for DescribeDatasetImportJobError {
     }
 }
 impl Error for DescribeDatasetImportJobError {}
+impl ServiceErrorCode for DescribeDatasetImportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDatasetImportJobError::InvalidInput(_) => "InvalidInputException",
+            DescribeDatasetImportJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeEventTracker
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventTrackerError {
@@ -3293,6 +3512,14 @@ impl fmt::Display for DescribeEventTrackerError {
     }
 }
 impl Error for DescribeEventTrackerError {}
+impl ServiceErrorCode for DescribeEventTrackerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventTrackerError::InvalidInput(_) => "InvalidInputException",
+            DescribeEventTrackerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeFeatureTransformation
 #[derive(Debug, PartialEq)]
 pub enum DescribeFeatureTransformationError {
@@ -3337,6 +3564,14 @@ impl fmt::Display for DescribeFeatureTransformationError {
     }
 }
 impl Error for DescribeFeatureTransformationError {}
+impl ServiceErrorCode for DescribeFeatureTransformationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFeatureTransformationError::InvalidInput(_) => "InvalidInputException",
+            DescribeFeatureTransformationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeFilter
 #[derive(Debug, PartialEq)]
 pub enum DescribeFilterError {
@@ -3373,6 +3608,14 @@ impl fmt::Display for DescribeFilterError {
     }
 }
 impl Error for DescribeFilterError {}
+impl ServiceErrorCode for DescribeFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFilterError::InvalidInput(_) => "InvalidInputException",
+            DescribeFilterError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeRecipe
 #[derive(Debug, PartialEq)]
 pub enum DescribeRecipeError {
@@ -3409,6 +3652,14 @@ impl fmt::Display for DescribeRecipeError {
     }
 }
 impl Error for DescribeRecipeError {}
+impl ServiceErrorCode for DescribeRecipeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRecipeError::InvalidInput(_) => "InvalidInputException",
+            DescribeRecipeError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeSchema
 #[derive(Debug, PartialEq)]
 pub enum DescribeSchemaError {
@@ -3445,6 +3696,14 @@ impl fmt::Display for DescribeSchemaError {
     }
 }
 impl Error for DescribeSchemaError {}
+impl ServiceErrorCode for DescribeSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSchemaError::InvalidInput(_) => "InvalidInputException",
+            DescribeSchemaError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeSolution
 #[derive(Debug, PartialEq)]
 pub enum DescribeSolutionError {
@@ -3481,6 +3740,14 @@ impl fmt::Display for DescribeSolutionError {
     }
 }
 impl Error for DescribeSolutionError {}
+impl ServiceErrorCode for DescribeSolutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSolutionError::InvalidInput(_) => "InvalidInputException",
+            DescribeSolutionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeSolutionVersion
 #[derive(Debug, PartialEq)]
 pub enum DescribeSolutionVersionError {
@@ -3521,6 +3788,14 @@ impl fmt::Display for DescribeSolutionVersionError {
     }
 }
 impl Error for DescribeSolutionVersionError {}
+impl ServiceErrorCode for DescribeSolutionVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSolutionVersionError::InvalidInput(_) => "InvalidInputException",
+            DescribeSolutionVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetSolutionMetrics
 #[derive(Debug, PartialEq)]
 pub enum GetSolutionMetricsError {
@@ -3563,6 +3838,15 @@ impl fmt::Display for GetSolutionMetricsError {
     }
 }
 impl Error for GetSolutionMetricsError {}
+impl ServiceErrorCode for GetSolutionMetricsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSolutionMetricsError::InvalidInput(_) => "InvalidInputException",
+            GetSolutionMetricsError::ResourceInUse(_) => "ResourceInUseException",
+            GetSolutionMetricsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListBatchInferenceJobs
 #[derive(Debug, PartialEq)]
 pub enum ListBatchInferenceJobsError {
@@ -3601,6 +3885,14 @@ impl fmt::Display for ListBatchInferenceJobsError {
     }
 }
 impl Error for ListBatchInferenceJobsError {}
+impl ServiceErrorCode for ListBatchInferenceJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBatchInferenceJobsError::InvalidInput(_) => "InvalidInputException",
+            ListBatchInferenceJobsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListCampaigns
 #[derive(Debug, PartialEq)]
 pub enum ListCampaignsError {
@@ -3637,6 +3929,14 @@ impl fmt::Display for ListCampaignsError {
     }
 }
 impl Error for ListCampaignsError {}
+impl ServiceErrorCode for ListCampaignsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCampaignsError::InvalidInput(_) => "InvalidInputException",
+            ListCampaignsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListDatasetGroups
 #[derive(Debug, PartialEq)]
 pub enum ListDatasetGroupsError {
@@ -3667,6 +3967,13 @@ impl fmt::Display for ListDatasetGroupsError {
     }
 }
 impl Error for ListDatasetGroupsError {}
+impl ServiceErrorCode for ListDatasetGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDatasetGroupsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListDatasetImportJobs
 #[derive(Debug, PartialEq)]
 pub enum ListDatasetImportJobsError {
@@ -3705,6 +4012,14 @@ impl fmt::Display for ListDatasetImportJobsError {
     }
 }
 impl Error for ListDatasetImportJobsError {}
+impl ServiceErrorCode for ListDatasetImportJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDatasetImportJobsError::InvalidInput(_) => "InvalidInputException",
+            ListDatasetImportJobsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListDatasets
 #[derive(Debug, PartialEq)]
 pub enum ListDatasetsError {
@@ -3741,6 +4056,14 @@ impl fmt::Display for ListDatasetsError {
     }
 }
 impl Error for ListDatasetsError {}
+impl ServiceErrorCode for ListDatasetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDatasetsError::InvalidInput(_) => "InvalidInputException",
+            ListDatasetsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListEventTrackers
 #[derive(Debug, PartialEq)]
 pub enum ListEventTrackersError {
@@ -3777,6 +4100,14 @@ impl fmt::Display for ListEventTrackersError {
     }
 }
 impl Error for ListEventTrackersError {}
+impl ServiceErrorCode for ListEventTrackersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEventTrackersError::InvalidInput(_) => "InvalidInputException",
+            ListEventTrackersError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListFilters
 #[derive(Debug, PartialEq)]
 pub enum ListFiltersError {
@@ -3813,6 +4144,14 @@ impl fmt::Display for ListFiltersError {
     }
 }
 impl Error for ListFiltersError {}
+impl ServiceErrorCode for ListFiltersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFiltersError::InvalidInput(_) => "InvalidInputException",
+            ListFiltersError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListRecipes
 #[derive(Debug, PartialEq)]
 pub enum ListRecipesError {
@@ -3843,6 +4182,13 @@ impl fmt::Display for ListRecipesError {
     }
 }
 impl Error for ListRecipesError {}
+impl ServiceErrorCode for ListRecipesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRecipesError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListSchemas
 #[derive(Debug, PartialEq)]
 pub enum ListSchemasError {
@@ -3873,6 +4219,13 @@ impl fmt::Display for ListSchemasError {
     }
 }
 impl Error for ListSchemasError {}
+impl ServiceErrorCode for ListSchemasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSchemasError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListSolutionVersions
 #[derive(Debug, PartialEq)]
 pub enum ListSolutionVersionsError {
@@ -3919,6 +4272,15 @@ impl fmt::Display for ListSolutionVersionsError {
     }
 }
 impl Error for ListSolutionVersionsError {}
+impl ServiceErrorCode for ListSolutionVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSolutionVersionsError::InvalidInput(_) => "InvalidInputException",
+            ListSolutionVersionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListSolutionVersionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListSolutions
 #[derive(Debug, PartialEq)]
 pub enum ListSolutionsError {
@@ -3955,6 +4317,14 @@ impl fmt::Display for ListSolutionsError {
     }
 }
 impl Error for ListSolutionsError {}
+impl ServiceErrorCode for ListSolutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSolutionsError::InvalidInput(_) => "InvalidInputException",
+            ListSolutionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by UpdateCampaign
 #[derive(Debug, PartialEq)]
 pub enum UpdateCampaignError {
@@ -3997,6 +4367,15 @@ impl fmt::Display for UpdateCampaignError {
     }
 }
 impl Error for UpdateCampaignError {}
+impl ServiceErrorCode for UpdateCampaignError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCampaignError::InvalidInput(_) => "InvalidInputException",
+            UpdateCampaignError::ResourceInUse(_) => "ResourceInUseException",
+            UpdateCampaignError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Personalize API. Amazon Personalize clients implement this trait.
 #[async_trait]
 pub trait Personalize {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -321,6 +321,15 @@ impl fmt::Display for DescribeDimensionKeysError {
     }
 }
 impl Error for DescribeDimensionKeysError {}
+impl ServiceErrorCode for DescribeDimensionKeysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDimensionKeysError::InternalServiceError(_) => "InternalServiceError",
+            DescribeDimensionKeysError::InvalidArgument(_) => "InvalidArgumentException",
+            DescribeDimensionKeysError::NotAuthorized(_) => "NotAuthorizedException",
+        }
+    }
+}
 /// Errors returned by GetResourceMetrics
 #[derive(Debug, PartialEq)]
 pub enum GetResourceMetricsError {
@@ -365,6 +374,15 @@ impl fmt::Display for GetResourceMetricsError {
     }
 }
 impl Error for GetResourceMetricsError {}
+impl ServiceErrorCode for GetResourceMetricsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourceMetricsError::InternalServiceError(_) => "InternalServiceError",
+            GetResourceMetricsError::InvalidArgument(_) => "InvalidArgumentException",
+            GetResourceMetricsError::NotAuthorized(_) => "NotAuthorizedException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS PI API. AWS PI clients implement this trait.
 #[async_trait]
 pub trait PerformanceInsights {
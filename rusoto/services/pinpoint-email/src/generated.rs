@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1696,6 +1696,20 @@ impl fmt::Display for CreateConfigurationSetError {
     }
 }
 impl Error for CreateConfigurationSetError {}
+impl ServiceErrorCode for CreateConfigurationSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConfigurationSetError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateConfigurationSetError::BadRequest(_) => "BadRequestException",
+            CreateConfigurationSetError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            CreateConfigurationSetError::LimitExceeded(_) => "LimitExceededException",
+            CreateConfigurationSetError::NotFound(_) => "NotFoundException",
+            CreateConfigurationSetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationSetEventDestinationError {
@@ -1772,6 +1786,23 @@ impl fmt::Display for CreateConfigurationSetEventDestinationError {
     }
 }
 impl Error for CreateConfigurationSetEventDestinationError {}
+impl ServiceErrorCode for CreateConfigurationSetEventDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConfigurationSetEventDestinationError::AlreadyExists(_) => {
+                "AlreadyExistsException"
+            }
+            CreateConfigurationSetEventDestinationError::BadRequest(_) => "BadRequestException",
+            CreateConfigurationSetEventDestinationError::LimitExceeded(_) => {
+                "LimitExceededException"
+            }
+            CreateConfigurationSetEventDestinationError::NotFound(_) => "NotFoundException",
+            CreateConfigurationSetEventDestinationError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDedicatedIpPool
 #[derive(Debug, PartialEq)]
 pub enum CreateDedicatedIpPoolError {
@@ -1830,6 +1861,19 @@ impl fmt::Display for CreateDedicatedIpPoolError {
     }
 }
 impl Error for CreateDedicatedIpPoolError {}
+impl ServiceErrorCode for CreateDedicatedIpPoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDedicatedIpPoolError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateDedicatedIpPoolError::BadRequest(_) => "BadRequestException",
+            CreateDedicatedIpPoolError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            CreateDedicatedIpPoolError::LimitExceeded(_) => "LimitExceededException",
+            CreateDedicatedIpPoolError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateDeliverabilityTestReport
 #[derive(Debug, PartialEq)]
 pub enum CreateDeliverabilityTestReportError {
@@ -1938,6 +1982,25 @@ impl fmt::Display for CreateDeliverabilityTestReportError {
     }
 }
 impl Error for CreateDeliverabilityTestReportError {}
+impl ServiceErrorCode for CreateDeliverabilityTestReportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeliverabilityTestReportError::AccountSuspended(_) => "AccountSuspendedException",
+            CreateDeliverabilityTestReportError::BadRequest(_) => "BadRequestException",
+            CreateDeliverabilityTestReportError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            CreateDeliverabilityTestReportError::LimitExceeded(_) => "LimitExceededException",
+            CreateDeliverabilityTestReportError::MailFromDomainNotVerified(_) => {
+                "MailFromDomainNotVerifiedException"
+            }
+            CreateDeliverabilityTestReportError::MessageRejected(_) => "MessageRejected",
+            CreateDeliverabilityTestReportError::NotFound(_) => "NotFoundException",
+            CreateDeliverabilityTestReportError::SendingPaused(_) => "SendingPausedException",
+            CreateDeliverabilityTestReportError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateEmailIdentity
 #[derive(Debug, PartialEq)]
 pub enum CreateEmailIdentityError {
@@ -1988,6 +2051,18 @@ impl fmt::Display for CreateEmailIdentityError {
     }
 }
 impl Error for CreateEmailIdentityError {}
+impl ServiceErrorCode for CreateEmailIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEmailIdentityError::BadRequest(_) => "BadRequestException",
+            CreateEmailIdentityError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            CreateEmailIdentityError::LimitExceeded(_) => "LimitExceededException",
+            CreateEmailIdentityError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteConfigurationSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationSetError {
@@ -2042,6 +2117,18 @@ impl fmt::Display for DeleteConfigurationSetError {
     }
 }
 impl Error for DeleteConfigurationSetError {}
+impl ServiceErrorCode for DeleteConfigurationSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigurationSetError::BadRequest(_) => "BadRequestException",
+            DeleteConfigurationSetError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteConfigurationSetError::NotFound(_) => "NotFoundException",
+            DeleteConfigurationSetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationSetEventDestinationError {
@@ -2098,6 +2185,17 @@ impl fmt::Display for DeleteConfigurationSetEventDestinationError {
     }
 }
 impl Error for DeleteConfigurationSetEventDestinationError {}
+impl ServiceErrorCode for DeleteConfigurationSetEventDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigurationSetEventDestinationError::BadRequest(_) => "BadRequestException",
+            DeleteConfigurationSetEventDestinationError::NotFound(_) => "NotFoundException",
+            DeleteConfigurationSetEventDestinationError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDedicatedIpPool
 #[derive(Debug, PartialEq)]
 pub enum DeleteDedicatedIpPoolError {
@@ -2150,6 +2248,18 @@ impl fmt::Display for DeleteDedicatedIpPoolError {
     }
 }
 impl Error for DeleteDedicatedIpPoolError {}
+impl ServiceErrorCode for DeleteDedicatedIpPoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDedicatedIpPoolError::BadRequest(_) => "BadRequestException",
+            DeleteDedicatedIpPoolError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteDedicatedIpPoolError::NotFound(_) => "NotFoundException",
+            DeleteDedicatedIpPoolError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteEmailIdentity
 #[derive(Debug, PartialEq)]
 pub enum DeleteEmailIdentityError {
@@ -2200,6 +2310,18 @@ impl fmt::Display for DeleteEmailIdentityError {
     }
 }
 impl Error for DeleteEmailIdentityError {}
+impl ServiceErrorCode for DeleteEmailIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEmailIdentityError::BadRequest(_) => "BadRequestException",
+            DeleteEmailIdentityError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteEmailIdentityError::NotFound(_) => "NotFoundException",
+            DeleteEmailIdentityError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetAccount
 #[derive(Debug, PartialEq)]
 pub enum GetAccountError {
@@ -2236,6 +2358,14 @@ impl fmt::Display for GetAccountError {
     }
 }
 impl Error for GetAccountError {}
+impl ServiceErrorCode for GetAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAccountError::BadRequest(_) => "BadRequestException",
+            GetAccountError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetBlacklistReports
 #[derive(Debug, PartialEq)]
 pub enum GetBlacklistReportsError {
@@ -2278,6 +2408,15 @@ impl fmt::Display for GetBlacklistReportsError {
     }
 }
 impl Error for GetBlacklistReportsError {}
+impl ServiceErrorCode for GetBlacklistReportsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBlacklistReportsError::BadRequest(_) => "BadRequestException",
+            GetBlacklistReportsError::NotFound(_) => "NotFoundException",
+            GetBlacklistReportsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetConfigurationSet
 #[derive(Debug, PartialEq)]
 pub enum GetConfigurationSetError {
@@ -2320,6 +2459,15 @@ impl fmt::Display for GetConfigurationSetError {
     }
 }
 impl Error for GetConfigurationSetError {}
+impl ServiceErrorCode for GetConfigurationSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConfigurationSetError::BadRequest(_) => "BadRequestException",
+            GetConfigurationSetError::NotFound(_) => "NotFoundException",
+            GetConfigurationSetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetConfigurationSetEventDestinations
 #[derive(Debug, PartialEq)]
 pub enum GetConfigurationSetEventDestinationsError {
@@ -2376,6 +2524,17 @@ impl fmt::Display for GetConfigurationSetEventDestinationsError {
     }
 }
 impl Error for GetConfigurationSetEventDestinationsError {}
+impl ServiceErrorCode for GetConfigurationSetEventDestinationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConfigurationSetEventDestinationsError::BadRequest(_) => "BadRequestException",
+            GetConfigurationSetEventDestinationsError::NotFound(_) => "NotFoundException",
+            GetConfigurationSetEventDestinationsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by GetDedicatedIp
 #[derive(Debug, PartialEq)]
 pub enum GetDedicatedIpError {
@@ -2418,6 +2577,15 @@ impl fmt::Display for GetDedicatedIpError {
     }
 }
 impl Error for GetDedicatedIpError {}
+impl ServiceErrorCode for GetDedicatedIpError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDedicatedIpError::BadRequest(_) => "BadRequestException",
+            GetDedicatedIpError::NotFound(_) => "NotFoundException",
+            GetDedicatedIpError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetDedicatedIps
 #[derive(Debug, PartialEq)]
 pub enum GetDedicatedIpsError {
@@ -2460,6 +2628,15 @@ impl fmt::Display for GetDedicatedIpsError {
     }
 }
 impl Error for GetDedicatedIpsError {}
+impl ServiceErrorCode for GetDedicatedIpsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDedicatedIpsError::BadRequest(_) => "BadRequestException",
+            GetDedicatedIpsError::NotFound(_) => "NotFoundException",
+            GetDedicatedIpsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetDeliverabilityDashboardOptions
 #[derive(Debug, PartialEq)]
 pub enum GetDeliverabilityDashboardOptionsError {
@@ -2514,6 +2691,17 @@ impl fmt::Display for GetDeliverabilityDashboardOptionsError {
     }
 }
 impl Error for GetDeliverabilityDashboardOptionsError {}
+impl ServiceErrorCode for GetDeliverabilityDashboardOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeliverabilityDashboardOptionsError::BadRequest(_) => "BadRequestException",
+            GetDeliverabilityDashboardOptionsError::LimitExceeded(_) => "LimitExceededException",
+            GetDeliverabilityDashboardOptionsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by GetDeliverabilityTestReport
 #[derive(Debug, PartialEq)]
 pub enum GetDeliverabilityTestReportError {
@@ -2564,6 +2752,15 @@ impl fmt::Display for GetDeliverabilityTestReportError {
     }
 }
 impl Error for GetDeliverabilityTestReportError {}
+impl ServiceErrorCode for GetDeliverabilityTestReportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeliverabilityTestReportError::BadRequest(_) => "BadRequestException",
+            GetDeliverabilityTestReportError::NotFound(_) => "NotFoundException",
+            GetDeliverabilityTestReportError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetDomainDeliverabilityCampaign
 #[derive(Debug, PartialEq)]
 pub enum GetDomainDeliverabilityCampaignError {
@@ -2616,6 +2813,15 @@ impl fmt::Display for GetDomainDeliverabilityCampaignError {
     }
 }
 impl Error for GetDomainDeliverabilityCampaignError {}
+impl ServiceErrorCode for GetDomainDeliverabilityCampaignError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDomainDeliverabilityCampaignError::BadRequest(_) => "BadRequestException",
+            GetDomainDeliverabilityCampaignError::NotFound(_) => "NotFoundException",
+            GetDomainDeliverabilityCampaignError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetDomainStatisticsReport
 #[derive(Debug, PartialEq)]
 pub enum GetDomainStatisticsReportError {
@@ -2662,6 +2868,15 @@ impl fmt::Display for GetDomainStatisticsReportError {
     }
 }
 impl Error for GetDomainStatisticsReportError {}
+impl ServiceErrorCode for GetDomainStatisticsReportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDomainStatisticsReportError::BadRequest(_) => "BadRequestException",
+            GetDomainStatisticsReportError::NotFound(_) => "NotFoundException",
+            GetDomainStatisticsReportError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetEmailIdentity
 #[derive(Debug, PartialEq)]
 pub enum GetEmailIdentityError {
@@ -2704,6 +2919,15 @@ impl fmt::Display for GetEmailIdentityError {
     }
 }
 impl Error for GetEmailIdentityError {}
+impl ServiceErrorCode for GetEmailIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEmailIdentityError::BadRequest(_) => "BadRequestException",
+            GetEmailIdentityError::NotFound(_) => "NotFoundException",
+            GetEmailIdentityError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListConfigurationSets
 #[derive(Debug, PartialEq)]
 pub enum ListConfigurationSetsError {
@@ -2742,6 +2966,14 @@ impl fmt::Display for ListConfigurationSetsError {
     }
 }
 impl Error for ListConfigurationSetsError {}
+impl ServiceErrorCode for ListConfigurationSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListConfigurationSetsError::BadRequest(_) => "BadRequestException",
+            ListConfigurationSetsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListDedicatedIpPools
 #[derive(Debug, PartialEq)]
 pub enum ListDedicatedIpPoolsError {
@@ -2780,6 +3012,14 @@ impl fmt::Display for ListDedicatedIpPoolsError {
     }
 }
 impl Error for ListDedicatedIpPoolsError {}
+impl ServiceErrorCode for ListDedicatedIpPoolsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDedicatedIpPoolsError::BadRequest(_) => "BadRequestException",
+            ListDedicatedIpPoolsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListDeliverabilityTestReports
 #[derive(Debug, PartialEq)]
 pub enum ListDeliverabilityTestReportsError {
@@ -2832,6 +3072,15 @@ impl fmt::Display for ListDeliverabilityTestReportsError {
     }
 }
 impl Error for ListDeliverabilityTestReportsError {}
+impl ServiceErrorCode for ListDeliverabilityTestReportsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeliverabilityTestReportsError::BadRequest(_) => "BadRequestException",
+            ListDeliverabilityTestReportsError::NotFound(_) => "NotFoundException",
+            ListDeliverabilityTestReportsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListDomainDeliverabilityCampaigns
 #[derive(Debug, PartialEq)]
 pub enum ListDomainDeliverabilityCampaignsError {
@@ -2884,6 +3133,17 @@ impl fmt::Display for ListDomainDeliverabilityCampaignsError {
     }
 }
 impl Error for ListDomainDeliverabilityCampaignsError {}
+impl ServiceErrorCode for ListDomainDeliverabilityCampaignsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDomainDeliverabilityCampaignsError::BadRequest(_) => "BadRequestException",
+            ListDomainDeliverabilityCampaignsError::NotFound(_) => "NotFoundException",
+            ListDomainDeliverabilityCampaignsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by ListEmailIdentities
 #[derive(Debug, PartialEq)]
 pub enum ListEmailIdentitiesError {
@@ -2920,6 +3180,14 @@ impl fmt::Display for ListEmailIdentitiesError {
     }
 }
 impl Error for ListEmailIdentitiesError {}
+impl ServiceErrorCode for ListEmailIdentitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEmailIdentitiesError::BadRequest(_) => "BadRequestException",
+            ListEmailIdentitiesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2962,6 +3230,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+            ListTagsForResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutAccountDedicatedIpWarmupAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutAccountDedicatedIpWarmupAttributesError {
@@ -3008,6 +3285,16 @@ impl fmt::Display for PutAccountDedicatedIpWarmupAttributesError {
     }
 }
 impl Error for PutAccountDedicatedIpWarmupAttributesError {}
+impl ServiceErrorCode for PutAccountDedicatedIpWarmupAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAccountDedicatedIpWarmupAttributesError::BadRequest(_) => "BadRequestException",
+            PutAccountDedicatedIpWarmupAttributesError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by PutAccountSendingAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutAccountSendingAttributesError {
@@ -3050,6 +3337,14 @@ impl fmt::Display for PutAccountSendingAttributesError {
     }
 }
 impl Error for PutAccountSendingAttributesError {}
+impl ServiceErrorCode for PutAccountSendingAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAccountSendingAttributesError::BadRequest(_) => "BadRequestException",
+            PutAccountSendingAttributesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutConfigurationSetDeliveryOptions
 #[derive(Debug, PartialEq)]
 pub enum PutConfigurationSetDeliveryOptionsError {
@@ -3104,6 +3399,17 @@ impl fmt::Display for PutConfigurationSetDeliveryOptionsError {
     }
 }
 impl Error for PutConfigurationSetDeliveryOptionsError {}
+impl ServiceErrorCode for PutConfigurationSetDeliveryOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutConfigurationSetDeliveryOptionsError::BadRequest(_) => "BadRequestException",
+            PutConfigurationSetDeliveryOptionsError::NotFound(_) => "NotFoundException",
+            PutConfigurationSetDeliveryOptionsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by PutConfigurationSetReputationOptions
 #[derive(Debug, PartialEq)]
 pub enum PutConfigurationSetReputationOptionsError {
@@ -3160,6 +3466,17 @@ impl fmt::Display for PutConfigurationSetReputationOptionsError {
     }
 }
 impl Error for PutConfigurationSetReputationOptionsError {}
+impl ServiceErrorCode for PutConfigurationSetReputationOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutConfigurationSetReputationOptionsError::BadRequest(_) => "BadRequestException",
+            PutConfigurationSetReputationOptionsError::NotFound(_) => "NotFoundException",
+            PutConfigurationSetReputationOptionsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by PutConfigurationSetSendingOptions
 #[derive(Debug, PartialEq)]
 pub enum PutConfigurationSetSendingOptionsError {
@@ -3212,6 +3529,17 @@ impl fmt::Display for PutConfigurationSetSendingOptionsError {
     }
 }
 impl Error for PutConfigurationSetSendingOptionsError {}
+impl ServiceErrorCode for PutConfigurationSetSendingOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutConfigurationSetSendingOptionsError::BadRequest(_) => "BadRequestException",
+            PutConfigurationSetSendingOptionsError::NotFound(_) => "NotFoundException",
+            PutConfigurationSetSendingOptionsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by PutConfigurationSetTrackingOptions
 #[derive(Debug, PartialEq)]
 pub enum PutConfigurationSetTrackingOptionsError {
@@ -3266,6 +3594,17 @@ impl fmt::Display for PutConfigurationSetTrackingOptionsError {
     }
 }
 impl Error for PutConfigurationSetTrackingOptionsError {}
+impl ServiceErrorCode for PutConfigurationSetTrackingOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutConfigurationSetTrackingOptionsError::BadRequest(_) => "BadRequestException",
+            PutConfigurationSetTrackingOptionsError::NotFound(_) => "NotFoundException",
+            PutConfigurationSetTrackingOptionsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by PutDedicatedIpInPool
 #[derive(Debug, PartialEq)]
 pub enum PutDedicatedIpInPoolError {
@@ -3310,6 +3649,15 @@ impl fmt::Display for PutDedicatedIpInPoolError {
     }
 }
 impl Error for PutDedicatedIpInPoolError {}
+impl ServiceErrorCode for PutDedicatedIpInPoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutDedicatedIpInPoolError::BadRequest(_) => "BadRequestException",
+            PutDedicatedIpInPoolError::NotFound(_) => "NotFoundException",
+            PutDedicatedIpInPoolError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutDedicatedIpWarmupAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutDedicatedIpWarmupAttributesError {
@@ -3362,6 +3710,15 @@ impl fmt::Display for PutDedicatedIpWarmupAttributesError {
     }
 }
 impl Error for PutDedicatedIpWarmupAttributesError {}
+impl ServiceErrorCode for PutDedicatedIpWarmupAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutDedicatedIpWarmupAttributesError::BadRequest(_) => "BadRequestException",
+            PutDedicatedIpWarmupAttributesError::NotFound(_) => "NotFoundException",
+            PutDedicatedIpWarmupAttributesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutDeliverabilityDashboardOption
 #[derive(Debug, PartialEq)]
 pub enum PutDeliverabilityDashboardOptionError {
@@ -3434,6 +3791,17 @@ impl fmt::Display for PutDeliverabilityDashboardOptionError {
     }
 }
 impl Error for PutDeliverabilityDashboardOptionError {}
+impl ServiceErrorCode for PutDeliverabilityDashboardOptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutDeliverabilityDashboardOptionError::AlreadyExists(_) => "AlreadyExistsException",
+            PutDeliverabilityDashboardOptionError::BadRequest(_) => "BadRequestException",
+            PutDeliverabilityDashboardOptionError::LimitExceeded(_) => "LimitExceededException",
+            PutDeliverabilityDashboardOptionError::NotFound(_) => "NotFoundException",
+            PutDeliverabilityDashboardOptionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutEmailIdentityDkimAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutEmailIdentityDkimAttributesError {
@@ -3486,6 +3854,15 @@ impl fmt::Display for PutEmailIdentityDkimAttributesError {
     }
 }
 impl Error for PutEmailIdentityDkimAttributesError {}
+impl ServiceErrorCode for PutEmailIdentityDkimAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutEmailIdentityDkimAttributesError::BadRequest(_) => "BadRequestException",
+            PutEmailIdentityDkimAttributesError::NotFound(_) => "NotFoundException",
+            PutEmailIdentityDkimAttributesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutEmailIdentityFeedbackAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutEmailIdentityFeedbackAttributesError {
@@ -3540,6 +3917,17 @@ impl fmt::Display for PutEmailIdentityFeedbackAttributesError {
     }
 }
 impl Error for PutEmailIdentityFeedbackAttributesError {}
+impl ServiceErrorCode for PutEmailIdentityFeedbackAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutEmailIdentityFeedbackAttributesError::BadRequest(_) => "BadRequestException",
+            PutEmailIdentityFeedbackAttributesError::NotFound(_) => "NotFoundException",
+            PutEmailIdentityFeedbackAttributesError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by PutEmailIdentityMailFromAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutEmailIdentityMailFromAttributesError {
@@ -3594,6 +3982,17 @@ impl fmt::Display for PutEmailIdentityMailFromAttributesError {
     }
 }
 impl Error for PutEmailIdentityMailFromAttributesError {}
+impl ServiceErrorCode for PutEmailIdentityMailFromAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutEmailIdentityMailFromAttributesError::BadRequest(_) => "BadRequestException",
+            PutEmailIdentityMailFromAttributesError::NotFound(_) => "NotFoundException",
+            PutEmailIdentityMailFromAttributesError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by SendEmail
 #[derive(Debug, PartialEq)]
 pub enum SendEmailError {
@@ -3666,6 +4065,20 @@ impl fmt::Display for SendEmailError {
     }
 }
 impl Error for SendEmailError {}
+impl ServiceErrorCode for SendEmailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendEmailError::AccountSuspended(_) => "AccountSuspendedException",
+            SendEmailError::BadRequest(_) => "BadRequestException",
+            SendEmailError::LimitExceeded(_) => "LimitExceededException",
+            SendEmailError::MailFromDomainNotVerified(_) => "MailFromDomainNotVerifiedException",
+            SendEmailError::MessageRejected(_) => "MessageRejected",
+            SendEmailError::NotFound(_) => "NotFoundException",
+            SendEmailError::SendingPaused(_) => "SendingPausedException",
+            SendEmailError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -3714,6 +4127,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+            TagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3764,6 +4187,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+            UntagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationSetEventDestinationError {
@@ -3820,6 +4253,17 @@ impl fmt::Display for UpdateConfigurationSetEventDestinationError {
     }
 }
 impl Error for UpdateConfigurationSetEventDestinationError {}
+impl ServiceErrorCode for UpdateConfigurationSetEventDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConfigurationSetEventDestinationError::BadRequest(_) => "BadRequestException",
+            UpdateConfigurationSetEventDestinationError::NotFound(_) => "NotFoundException",
+            UpdateConfigurationSetEventDestinationError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the Pinpoint Email API. Pinpoint Email clients implement this trait.
 #[async_trait]
 pub trait PinpointEmail {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -476,6 +476,14 @@ impl fmt::Display for DeleteLexiconError {
     }
 }
 impl Error for DeleteLexiconError {}
+impl ServiceErrorCode for DeleteLexiconError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLexiconError::LexiconNotFound(_) => "LexiconNotFoundException",
+            DeleteLexiconError::ServiceFailure(_) => "ServiceFailureException",
+        }
+    }
+}
 /// Errors returned by DescribeVoices
 #[derive(Debug, PartialEq)]
 pub enum DescribeVoicesError {
@@ -512,6 +520,14 @@ impl fmt::Display for DescribeVoicesError {
     }
 }
 impl Error for DescribeVoicesError {}
+impl ServiceErrorCode for DescribeVoicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeVoicesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeVoicesError::ServiceFailure(_) => "ServiceFailureException",
+        }
+    }
+}
 /// Errors returned by GetLexicon
 #[derive(Debug, PartialEq)]
 pub enum GetLexiconError {
@@ -548,6 +564,14 @@ impl fmt::Display for GetLexiconError {
     }
 }
 impl Error for GetLexiconError {}
+impl ServiceErrorCode for GetLexiconError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLexiconError::LexiconNotFound(_) => "LexiconNotFoundException",
+            GetLexiconError::ServiceFailure(_) => "ServiceFailureException",
+        }
+    }
+}
 /// Errors returned by GetSpeechSynthesisTask
 #[derive(Debug, PartialEq)]
 pub enum GetSpeechSynthesisTaskError {
@@ -596,6 +620,17 @@ impl fmt::Display for GetSpeechSynthesisTaskError {
     }
 }
 impl Error for GetSpeechSynthesisTaskError {}
+impl ServiceErrorCode for GetSpeechSynthesisTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSpeechSynthesisTaskError::InvalidTaskId(_) => "InvalidTaskIdException",
+            GetSpeechSynthesisTaskError::ServiceFailure(_) => "ServiceFailureException",
+            GetSpeechSynthesisTaskError::SynthesisTaskNotFound(_) => {
+                "SynthesisTaskNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by ListLexicons
 #[derive(Debug, PartialEq)]
 pub enum ListLexiconsError {
@@ -632,6 +667,14 @@ impl fmt::Display for ListLexiconsError {
     }
 }
 impl Error for ListLexiconsError {}
+impl ServiceErrorCode for ListLexiconsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLexiconsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListLexiconsError::ServiceFailure(_) => "ServiceFailureException",
+        }
+    }
+}
 /// Errors returned by ListSpeechSynthesisTasks
 #[derive(Debug, PartialEq)]
 pub enum ListSpeechSynthesisTasksError {
@@ -672,6 +715,14 @@ impl fmt::Display for ListSpeechSynthesisTasksError {
     }
 }
 impl Error for ListSpeechSynthesisTasksError {}
+impl ServiceErrorCode for ListSpeechSynthesisTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSpeechSynthesisTasksError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListSpeechSynthesisTasksError::ServiceFailure(_) => "ServiceFailureException",
+        }
+    }
+}
 /// Errors returned by PutLexicon
 #[derive(Debug, PartialEq)]
 pub enum PutLexiconError {
@@ -740,6 +791,19 @@ impl fmt::Display for PutLexiconError {
     }
 }
 impl Error for PutLexiconError {}
+impl ServiceErrorCode for PutLexiconError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutLexiconError::InvalidLexicon(_) => "InvalidLexiconException",
+            PutLexiconError::LexiconSizeExceeded(_) => "LexiconSizeExceededException",
+            PutLexiconError::MaxLexemeLengthExceeded(_) => "MaxLexemeLengthExceededException",
+            PutLexiconError::MaxLexiconsNumberExceeded(_) => "MaxLexiconsNumberExceededException",
+            PutLexiconError::ServiceFailure(_) => "ServiceFailureException",
+            PutLexiconError::UnsupportedPlsAlphabet(_) => "UnsupportedPlsAlphabetException",
+            PutLexiconError::UnsupportedPlsLanguage(_) => "UnsupportedPlsLanguageException",
+        }
+    }
+}
 /// Errors returned by StartSpeechSynthesisTask
 #[derive(Debug, PartialEq)]
 pub enum StartSpeechSynthesisTaskError {
@@ -866,6 +930,30 @@ impl fmt::Display for StartSpeechSynthesisTaskError {
     }
 }
 impl Error for StartSpeechSynthesisTaskError {}
+impl ServiceErrorCode for StartSpeechSynthesisTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartSpeechSynthesisTaskError::EngineNotSupported(_) => "EngineNotSupportedException",
+            StartSpeechSynthesisTaskError::InvalidS3Bucket(_) => "InvalidS3BucketException",
+            StartSpeechSynthesisTaskError::InvalidS3Key(_) => "InvalidS3KeyException",
+            StartSpeechSynthesisTaskError::InvalidSampleRate(_) => "InvalidSampleRateException",
+            StartSpeechSynthesisTaskError::InvalidSnsTopicArn(_) => "InvalidSnsTopicArnException",
+            StartSpeechSynthesisTaskError::InvalidSsml(_) => "InvalidSsmlException",
+            StartSpeechSynthesisTaskError::LanguageNotSupported(_) => {
+                "LanguageNotSupportedException"
+            }
+            StartSpeechSynthesisTaskError::LexiconNotFound(_) => "LexiconNotFoundException",
+            StartSpeechSynthesisTaskError::MarksNotSupportedForFormat(_) => {
+                "MarksNotSupportedForFormatException"
+            }
+            StartSpeechSynthesisTaskError::ServiceFailure(_) => "ServiceFailureException",
+            StartSpeechSynthesisTaskError::SsmlMarksNotSupportedForTextType(_) => {
+                "SsmlMarksNotSupportedForTextTypeException"
+            }
+            StartSpeechSynthesisTaskError::TextLengthExceeded(_) => "TextLengthExceededException",
+        }
+    }
+}
 /// Errors returned by SynthesizeSpeech
 #[derive(Debug, PartialEq)]
 pub enum SynthesizeSpeechError {
@@ -952,6 +1040,25 @@ impl fmt::Display for SynthesizeSpeechError {
     }
 }
 impl Error for SynthesizeSpeechError {}
+impl ServiceErrorCode for SynthesizeSpeechError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SynthesizeSpeechError::EngineNotSupported(_) => "EngineNotSupportedException",
+            SynthesizeSpeechError::InvalidSampleRate(_) => "InvalidSampleRateException",
+            SynthesizeSpeechError::InvalidSsml(_) => "InvalidSsmlException",
+            SynthesizeSpeechError::LanguageNotSupported(_) => "LanguageNotSupportedException",
+            SynthesizeSpeechError::LexiconNotFound(_) => "LexiconNotFoundException",
+            SynthesizeSpeechError::MarksNotSupportedForFormat(_) => {
+                "MarksNotSupportedForFormatException"
+            }
+            SynthesizeSpeechError::ServiceFailure(_) => "ServiceFailureException",
+            SynthesizeSpeechError::SsmlMarksNotSupportedForTextType(_) => {
+                "SsmlMarksNotSupportedForTextTypeException"
+            }
+            SynthesizeSpeechError::TextLengthExceeded(_) => "TextLengthExceededException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Polly API. Amazon Polly clients implement this trait.
 #[async_trait]
 pub trait Polly {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -256,6 +256,17 @@ impl fmt::Display for DescribeServicesError {
     }
 }
 impl Error for DescribeServicesError {}
+impl ServiceErrorCode for DescribeServicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeServicesError::ExpiredNextToken(_) => "ExpiredNextTokenException",
+            DescribeServicesError::InternalError(_) => "InternalErrorException",
+            DescribeServicesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeServicesError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeServicesError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetAttributeValues
 #[derive(Debug, PartialEq)]
 pub enum GetAttributeValuesError {
@@ -310,6 +321,17 @@ impl fmt::Display for GetAttributeValuesError {
     }
 }
 impl Error for GetAttributeValuesError {}
+impl ServiceErrorCode for GetAttributeValuesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAttributeValuesError::ExpiredNextToken(_) => "ExpiredNextTokenException",
+            GetAttributeValuesError::InternalError(_) => "InternalErrorException",
+            GetAttributeValuesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetAttributeValuesError::InvalidParameter(_) => "InvalidParameterException",
+            GetAttributeValuesError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetProducts
 #[derive(Debug, PartialEq)]
 pub enum GetProductsError {
@@ -364,6 +386,17 @@ impl fmt::Display for GetProductsError {
     }
 }
 impl Error for GetProductsError {}
+impl ServiceErrorCode for GetProductsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetProductsError::ExpiredNextToken(_) => "ExpiredNextTokenException",
+            GetProductsError::InternalError(_) => "InternalErrorException",
+            GetProductsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetProductsError::InvalidParameter(_) => "InvalidParameterException",
+            GetProductsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Pricing API. AWS Pricing clients implement this trait.
 #[async_trait]
 pub trait Pricing {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -411,6 +411,17 @@ impl fmt::Display for SendCommandError {
     }
 }
 impl Error for SendCommandError {}
+impl ServiceErrorCode for SendCommandError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendCommandError::BadRequest(_) => "BadRequestException",
+            SendCommandError::InvalidSession(_) => "InvalidSessionException",
+            SendCommandError::LimitExceeded(_) => "LimitExceededException",
+            SendCommandError::OccConflict(_) => "OccConflictException",
+            SendCommandError::RateExceeded(_) => "RateExceededException",
+        }
+    }
+}
 /// Trait representing the capabilities of the QLDB Session API. QLDB Session clients implement this trait.
 #[async_trait]
 pub trait QldbSession {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -699,6 +699,17 @@ impl fmt::Display for CancelJournalKinesisStreamError {
     }
 }
 impl Error for CancelJournalKinesisStreamError {}
+impl ServiceErrorCode for CancelJournalKinesisStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelJournalKinesisStreamError::InvalidParameter(_) => "InvalidParameterException",
+            CancelJournalKinesisStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelJournalKinesisStreamError::ResourcePreconditionNotMet(_) => {
+                "ResourcePreconditionNotMetException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateLedger
 #[derive(Debug, PartialEq)]
 pub enum CreateLedgerError {
@@ -747,6 +758,16 @@ impl fmt::Display for CreateLedgerError {
     }
 }
 impl Error for CreateLedgerError {}
+impl ServiceErrorCode for CreateLedgerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLedgerError::InvalidParameter(_) => "InvalidParameterException",
+            CreateLedgerError::LimitExceeded(_) => "LimitExceededException",
+            CreateLedgerError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateLedgerError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteLedger
 #[derive(Debug, PartialEq)]
 pub enum DeleteLedgerError {
@@ -797,6 +818,18 @@ impl fmt::Display for DeleteLedgerError {
     }
 }
 impl Error for DeleteLedgerError {}
+impl ServiceErrorCode for DeleteLedgerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLedgerError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteLedgerError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteLedgerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteLedgerError::ResourcePreconditionNotMet(_) => {
+                "ResourcePreconditionNotMetException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeJournalKinesisStream
 #[derive(Debug, PartialEq)]
 pub enum DescribeJournalKinesisStreamError {
@@ -853,6 +886,17 @@ impl fmt::Display for DescribeJournalKinesisStreamError {
     }
 }
 impl Error for DescribeJournalKinesisStreamError {}
+impl ServiceErrorCode for DescribeJournalKinesisStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeJournalKinesisStreamError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeJournalKinesisStreamError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeJournalKinesisStreamError::ResourcePreconditionNotMet(_) => {
+                "ResourcePreconditionNotMetException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeJournalS3Export
 #[derive(Debug, PartialEq)]
 pub enum DescribeJournalS3ExportError {
@@ -885,6 +929,13 @@ impl fmt::Display for DescribeJournalS3ExportError {
     }
 }
 impl Error for DescribeJournalS3ExportError {}
+impl ServiceErrorCode for DescribeJournalS3ExportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeJournalS3ExportError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeLedger
 #[derive(Debug, PartialEq)]
 pub enum DescribeLedgerError {
@@ -921,6 +972,14 @@ impl fmt::Display for DescribeLedgerError {
     }
 }
 impl Error for DescribeLedgerError {}
+impl ServiceErrorCode for DescribeLedgerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLedgerError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeLedgerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ExportJournalToS3
 #[derive(Debug, PartialEq)]
 pub enum ExportJournalToS3Error {
@@ -959,6 +1018,16 @@ impl fmt::Display for ExportJournalToS3Error {
     }
 }
 impl Error for ExportJournalToS3Error {}
+impl ServiceErrorCode for ExportJournalToS3Error {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExportJournalToS3Error::ResourceNotFound(_) => "ResourceNotFoundException",
+            ExportJournalToS3Error::ResourcePreconditionNotMet(_) => {
+                "ResourcePreconditionNotMetException"
+            }
+        }
+    }
+}
 /// Errors returned by GetBlock
 #[derive(Debug, PartialEq)]
 pub enum GetBlockError {
@@ -1001,6 +1070,15 @@ impl fmt::Display for GetBlockError {
     }
 }
 impl Error for GetBlockError {}
+impl ServiceErrorCode for GetBlockError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBlockError::InvalidParameter(_) => "InvalidParameterException",
+            GetBlockError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetBlockError::ResourcePreconditionNotMet(_) => "ResourcePreconditionNotMetException",
+        }
+    }
+}
 /// Errors returned by GetDigest
 #[derive(Debug, PartialEq)]
 pub enum GetDigestError {
@@ -1045,6 +1123,15 @@ impl fmt::Display for GetDigestError {
     }
 }
 impl Error for GetDigestError {}
+impl ServiceErrorCode for GetDigestError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDigestError::InvalidParameter(_) => "InvalidParameterException",
+            GetDigestError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetDigestError::ResourcePreconditionNotMet(_) => "ResourcePreconditionNotMetException",
+        }
+    }
+}
 /// Errors returned by GetRevision
 #[derive(Debug, PartialEq)]
 pub enum GetRevisionError {
@@ -1089,6 +1176,17 @@ impl fmt::Display for GetRevisionError {
     }
 }
 impl Error for GetRevisionError {}
+impl ServiceErrorCode for GetRevisionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRevisionError::InvalidParameter(_) => "InvalidParameterException",
+            GetRevisionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetRevisionError::ResourcePreconditionNotMet(_) => {
+                "ResourcePreconditionNotMetException"
+            }
+        }
+    }
+}
 /// Errors returned by ListJournalKinesisStreamsForLedger
 #[derive(Debug, PartialEq)]
 pub enum ListJournalKinesisStreamsForLedgerError {
@@ -1147,6 +1245,21 @@ impl fmt::Display for ListJournalKinesisStreamsForLedgerError {
     }
 }
 impl Error for ListJournalKinesisStreamsForLedgerError {}
+impl ServiceErrorCode for ListJournalKinesisStreamsForLedgerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJournalKinesisStreamsForLedgerError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            ListJournalKinesisStreamsForLedgerError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            ListJournalKinesisStreamsForLedgerError::ResourcePreconditionNotMet(_) => {
+                "ResourcePreconditionNotMetException"
+            }
+        }
+    }
+}
 /// Errors returned by ListJournalS3Exports
 #[derive(Debug, PartialEq)]
 pub enum ListJournalS3ExportsError {}
@@ -1169,6 +1282,11 @@ impl fmt::Display for ListJournalS3ExportsError {
     }
 }
 impl Error for ListJournalS3ExportsError {}
+impl ServiceErrorCode for ListJournalS3ExportsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListJournalS3ExportsForLedger
 #[derive(Debug, PartialEq)]
 pub enum ListJournalS3ExportsForLedgerError {}
@@ -1193,6 +1311,11 @@ impl fmt::Display for ListJournalS3ExportsForLedgerError {
     }
 }
 impl Error for ListJournalS3ExportsForLedgerError {}
+impl ServiceErrorCode for ListJournalS3ExportsForLedgerError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListLedgers
 #[derive(Debug, PartialEq)]
 pub enum ListLedgersError {}
@@ -1215,6 +1338,11 @@ impl fmt::Display for ListLedgersError {
     }
 }
 impl Error for ListLedgersError {}
+impl ServiceErrorCode for ListLedgersError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1255,6 +1383,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InvalidParameter(_) => "InvalidParameterException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StreamJournalToKinesis
 #[derive(Debug, PartialEq)]
 pub enum StreamJournalToKinesisError {
@@ -1305,6 +1441,17 @@ impl fmt::Display for StreamJournalToKinesisError {
     }
 }
 impl Error for StreamJournalToKinesisError {}
+impl ServiceErrorCode for StreamJournalToKinesisError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StreamJournalToKinesisError::InvalidParameter(_) => "InvalidParameterException",
+            StreamJournalToKinesisError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StreamJournalToKinesisError::ResourcePreconditionNotMet(_) => {
+                "ResourcePreconditionNotMetException"
+            }
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1341,6 +1488,14 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1377,6 +1532,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateLedger
 #[derive(Debug, PartialEq)]
 pub enum UpdateLedgerError {
@@ -1413,6 +1576,14 @@ impl fmt::Display for UpdateLedgerError {
     }
 }
 impl Error for UpdateLedgerError {}
+impl ServiceErrorCode for UpdateLedgerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateLedgerError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateLedgerError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the QLDB API. QLDB clients implement this trait.
 #[async_trait]
 pub trait Qldb {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -6313,6 +6313,18 @@ impl fmt::Display for CancelIngestionError {
     }
 }
 impl Error for CancelIngestionError {}
+impl ServiceErrorCode for CancelIngestionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelIngestionError::AccessDenied(_) => "AccessDeniedException",
+            CancelIngestionError::InternalFailure(_) => "InternalFailureException",
+            CancelIngestionError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CancelIngestionError::ResourceExists(_) => "ResourceExistsException",
+            CancelIngestionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelIngestionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateAccountCustomization
 #[derive(Debug, PartialEq)]
 pub enum CreateAccountCustomizationError {
@@ -6399,6 +6411,23 @@ impl fmt::Display for CreateAccountCustomizationError {
     }
 }
 impl Error for CreateAccountCustomizationError {}
+impl ServiceErrorCode for CreateAccountCustomizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAccountCustomizationError::AccessDenied(_) => "AccessDeniedException",
+            CreateAccountCustomizationError::InternalFailure(_) => "InternalFailureException",
+            CreateAccountCustomizationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            CreateAccountCustomizationError::ResourceExists(_) => "ResourceExistsException",
+            CreateAccountCustomizationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateAccountCustomizationError::ResourceUnavailable(_) => {
+                "ResourceUnavailableException"
+            }
+            CreateAccountCustomizationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateAnalysis
 #[derive(Debug, PartialEq)]
 pub enum CreateAnalysisError {
@@ -6469,6 +6498,19 @@ impl fmt::Display for CreateAnalysisError {
     }
 }
 impl Error for CreateAnalysisError {}
+impl ServiceErrorCode for CreateAnalysisError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAnalysisError::Conflict(_) => "ConflictException",
+            CreateAnalysisError::InternalFailure(_) => "InternalFailureException",
+            CreateAnalysisError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateAnalysisError::ResourceExists(_) => "ResourceExistsException",
+            CreateAnalysisError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateAnalysisError::Throttling(_) => "ThrottlingException",
+            CreateAnalysisError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by CreateDashboard
 #[derive(Debug, PartialEq)]
 pub enum CreateDashboardError {
@@ -6539,6 +6581,19 @@ impl fmt::Display for CreateDashboardError {
     }
 }
 impl Error for CreateDashboardError {}
+impl ServiceErrorCode for CreateDashboardError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDashboardError::Conflict(_) => "ConflictException",
+            CreateDashboardError::InternalFailure(_) => "InternalFailureException",
+            CreateDashboardError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateDashboardError::ResourceExists(_) => "ResourceExistsException",
+            CreateDashboardError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateDashboardError::Throttling(_) => "ThrottlingException",
+            CreateDashboardError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by CreateDataSet
 #[derive(Debug, PartialEq)]
 pub enum CreateDataSetError {
@@ -6619,6 +6674,21 @@ impl fmt::Display for CreateDataSetError {
     }
 }
 impl Error for CreateDataSetError {}
+impl ServiceErrorCode for CreateDataSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDataSetError::AccessDenied(_) => "AccessDeniedException",
+            CreateDataSetError::Conflict(_) => "ConflictException",
+            CreateDataSetError::InternalFailure(_) => "InternalFailureException",
+            CreateDataSetError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateDataSetError::LimitExceeded(_) => "LimitExceededException",
+            CreateDataSetError::ResourceExists(_) => "ResourceExistsException",
+            CreateDataSetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateDataSetError::Throttling(_) => "ThrottlingException",
+            CreateDataSetError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by CreateDataSource
 #[derive(Debug, PartialEq)]
 pub enum CreateDataSourceError {
@@ -6693,6 +6763,20 @@ impl fmt::Display for CreateDataSourceError {
     }
 }
 impl Error for CreateDataSourceError {}
+impl ServiceErrorCode for CreateDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDataSourceError::AccessDenied(_) => "AccessDeniedException",
+            CreateDataSourceError::Conflict(_) => "ConflictException",
+            CreateDataSourceError::InternalFailure(_) => "InternalFailureException",
+            CreateDataSourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateDataSourceError::LimitExceeded(_) => "LimitExceededException",
+            CreateDataSourceError::ResourceExists(_) => "ResourceExistsException",
+            CreateDataSourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateDataSourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateGroupError {
@@ -6771,6 +6855,21 @@ impl fmt::Display for CreateGroupError {
     }
 }
 impl Error for CreateGroupError {}
+impl ServiceErrorCode for CreateGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGroupError::AccessDenied(_) => "AccessDeniedException",
+            CreateGroupError::InternalFailure(_) => "InternalFailureException",
+            CreateGroupError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateGroupError::LimitExceeded(_) => "LimitExceededException",
+            CreateGroupError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            CreateGroupError::ResourceExists(_) => "ResourceExistsException",
+            CreateGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateGroupError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            CreateGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateGroupMembership
 #[derive(Debug, PartialEq)]
 pub enum CreateGroupMembershipError {
@@ -6847,6 +6946,21 @@ impl fmt::Display for CreateGroupMembershipError {
     }
 }
 impl Error for CreateGroupMembershipError {}
+impl ServiceErrorCode for CreateGroupMembershipError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGroupMembershipError::AccessDenied(_) => "AccessDeniedException",
+            CreateGroupMembershipError::InternalFailure(_) => "InternalFailureException",
+            CreateGroupMembershipError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            CreateGroupMembershipError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            CreateGroupMembershipError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateGroupMembershipError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            CreateGroupMembershipError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateIAMPolicyAssignment
 #[derive(Debug, PartialEq)]
 pub enum CreateIAMPolicyAssignmentError {
@@ -6929,6 +7043,21 @@ impl fmt::Display for CreateIAMPolicyAssignmentError {
     }
 }
 impl Error for CreateIAMPolicyAssignmentError {}
+impl ServiceErrorCode for CreateIAMPolicyAssignmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateIAMPolicyAssignmentError::AccessDenied(_) => "AccessDeniedException",
+            CreateIAMPolicyAssignmentError::ConcurrentUpdating(_) => "ConcurrentUpdatingException",
+            CreateIAMPolicyAssignmentError::InternalFailure(_) => "InternalFailureException",
+            CreateIAMPolicyAssignmentError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            CreateIAMPolicyAssignmentError::ResourceExists(_) => "ResourceExistsException",
+            CreateIAMPolicyAssignmentError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateIAMPolicyAssignmentError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateIngestion
 #[derive(Debug, PartialEq)]
 pub enum CreateIngestionError {
@@ -6997,6 +7126,19 @@ impl fmt::Display for CreateIngestionError {
     }
 }
 impl Error for CreateIngestionError {}
+impl ServiceErrorCode for CreateIngestionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateIngestionError::AccessDenied(_) => "AccessDeniedException",
+            CreateIngestionError::InternalFailure(_) => "InternalFailureException",
+            CreateIngestionError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateIngestionError::LimitExceeded(_) => "LimitExceededException",
+            CreateIngestionError::ResourceExists(_) => "ResourceExistsException",
+            CreateIngestionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateIngestionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateNamespace
 #[derive(Debug, PartialEq)]
 pub enum CreateNamespaceError {
@@ -7083,6 +7225,22 @@ impl fmt::Display for CreateNamespaceError {
     }
 }
 impl Error for CreateNamespaceError {}
+impl ServiceErrorCode for CreateNamespaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateNamespaceError::AccessDenied(_) => "AccessDeniedException",
+            CreateNamespaceError::Conflict(_) => "ConflictException",
+            CreateNamespaceError::InternalFailure(_) => "InternalFailureException",
+            CreateNamespaceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateNamespaceError::LimitExceeded(_) => "LimitExceededException",
+            CreateNamespaceError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            CreateNamespaceError::ResourceExists(_) => "ResourceExistsException",
+            CreateNamespaceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateNamespaceError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            CreateNamespaceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateTemplate
 #[derive(Debug, PartialEq)]
 pub enum CreateTemplateError {
@@ -7165,6 +7323,21 @@ impl fmt::Display for CreateTemplateError {
     }
 }
 impl Error for CreateTemplateError {}
+impl ServiceErrorCode for CreateTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTemplateError::AccessDenied(_) => "AccessDeniedException",
+            CreateTemplateError::Conflict(_) => "ConflictException",
+            CreateTemplateError::InternalFailure(_) => "InternalFailureException",
+            CreateTemplateError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateTemplateError::LimitExceeded(_) => "LimitExceededException",
+            CreateTemplateError::ResourceExists(_) => "ResourceExistsException",
+            CreateTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateTemplateError::Throttling(_) => "ThrottlingException",
+            CreateTemplateError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by CreateTemplateAlias
 #[derive(Debug, PartialEq)]
 pub enum CreateTemplateAliasError {
@@ -7235,6 +7408,21 @@ impl fmt::Display for CreateTemplateAliasError {
     }
 }
 impl Error for CreateTemplateAliasError {}
+impl ServiceErrorCode for CreateTemplateAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTemplateAliasError::Conflict(_) => "ConflictException",
+            CreateTemplateAliasError::InternalFailure(_) => "InternalFailureException",
+            CreateTemplateAliasError::LimitExceeded(_) => "LimitExceededException",
+            CreateTemplateAliasError::ResourceExists(_) => "ResourceExistsException",
+            CreateTemplateAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateTemplateAliasError::Throttling(_) => "ThrottlingException",
+            CreateTemplateAliasError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateTheme
 #[derive(Debug, PartialEq)]
 pub enum CreateThemeError {
@@ -7307,6 +7495,20 @@ impl fmt::Display for CreateThemeError {
     }
 }
 impl Error for CreateThemeError {}
+impl ServiceErrorCode for CreateThemeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateThemeError::AccessDenied(_) => "AccessDeniedException",
+            CreateThemeError::InternalFailure(_) => "InternalFailureException",
+            CreateThemeError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateThemeError::LimitExceeded(_) => "LimitExceededException",
+            CreateThemeError::ResourceExists(_) => "ResourceExistsException",
+            CreateThemeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateThemeError::Throttling(_) => "ThrottlingException",
+            CreateThemeError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by CreateThemeAlias
 #[derive(Debug, PartialEq)]
 pub enum CreateThemeAliasError {
@@ -7383,6 +7585,20 @@ impl fmt::Display for CreateThemeAliasError {
     }
 }
 impl Error for CreateThemeAliasError {}
+impl ServiceErrorCode for CreateThemeAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateThemeAliasError::Conflict(_) => "ConflictException",
+            CreateThemeAliasError::InternalFailure(_) => "InternalFailureException",
+            CreateThemeAliasError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateThemeAliasError::LimitExceeded(_) => "LimitExceededException",
+            CreateThemeAliasError::ResourceExists(_) => "ResourceExistsException",
+            CreateThemeAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateThemeAliasError::Throttling(_) => "ThrottlingException",
+            CreateThemeAliasError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by DeleteAccountCustomization
 #[derive(Debug, PartialEq)]
 pub enum DeleteAccountCustomizationError {
@@ -7461,6 +7677,22 @@ impl fmt::Display for DeleteAccountCustomizationError {
     }
 }
 impl Error for DeleteAccountCustomizationError {}
+impl ServiceErrorCode for DeleteAccountCustomizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAccountCustomizationError::AccessDenied(_) => "AccessDeniedException",
+            DeleteAccountCustomizationError::InternalFailure(_) => "InternalFailureException",
+            DeleteAccountCustomizationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteAccountCustomizationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteAccountCustomizationError::ResourceUnavailable(_) => {
+                "ResourceUnavailableException"
+            }
+            DeleteAccountCustomizationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteAnalysis
 #[derive(Debug, PartialEq)]
 pub enum DeleteAnalysisError {
@@ -7525,6 +7757,18 @@ impl fmt::Display for DeleteAnalysisError {
     }
 }
 impl Error for DeleteAnalysisError {}
+impl ServiceErrorCode for DeleteAnalysisError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAnalysisError::Conflict(_) => "ConflictException",
+            DeleteAnalysisError::InternalFailure(_) => "InternalFailureException",
+            DeleteAnalysisError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteAnalysisError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteAnalysisError::Throttling(_) => "ThrottlingException",
+            DeleteAnalysisError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by DeleteDashboard
 #[derive(Debug, PartialEq)]
 pub enum DeleteDashboardError {
@@ -7589,6 +7833,18 @@ impl fmt::Display for DeleteDashboardError {
     }
 }
 impl Error for DeleteDashboardError {}
+impl ServiceErrorCode for DeleteDashboardError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDashboardError::Conflict(_) => "ConflictException",
+            DeleteDashboardError::InternalFailure(_) => "InternalFailureException",
+            DeleteDashboardError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteDashboardError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDashboardError::Throttling(_) => "ThrottlingException",
+            DeleteDashboardError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by DeleteDataSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteDataSetError {
@@ -7643,6 +7899,17 @@ impl fmt::Display for DeleteDataSetError {
     }
 }
 impl Error for DeleteDataSetError {}
+impl ServiceErrorCode for DeleteDataSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDataSetError::AccessDenied(_) => "AccessDeniedException",
+            DeleteDataSetError::InternalFailure(_) => "InternalFailureException",
+            DeleteDataSetError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteDataSetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDataSetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteDataSource
 #[derive(Debug, PartialEq)]
 pub enum DeleteDataSourceError {
@@ -7699,6 +7966,17 @@ impl fmt::Display for DeleteDataSourceError {
     }
 }
 impl Error for DeleteDataSourceError {}
+impl ServiceErrorCode for DeleteDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDataSourceError::AccessDenied(_) => "AccessDeniedException",
+            DeleteDataSourceError::InternalFailure(_) => "InternalFailureException",
+            DeleteDataSourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteDataSourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteDataSourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteGroupError {
@@ -7765,6 +8043,19 @@ impl fmt::Display for DeleteGroupError {
     }
 }
 impl Error for DeleteGroupError {}
+impl ServiceErrorCode for DeleteGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGroupError::AccessDenied(_) => "AccessDeniedException",
+            DeleteGroupError::InternalFailure(_) => "InternalFailureException",
+            DeleteGroupError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteGroupError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            DeleteGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteGroupError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            DeleteGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteGroupMembership
 #[derive(Debug, PartialEq)]
 pub enum DeleteGroupMembershipError {
@@ -7841,6 +8132,21 @@ impl fmt::Display for DeleteGroupMembershipError {
     }
 }
 impl Error for DeleteGroupMembershipError {}
+impl ServiceErrorCode for DeleteGroupMembershipError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGroupMembershipError::AccessDenied(_) => "AccessDeniedException",
+            DeleteGroupMembershipError::InternalFailure(_) => "InternalFailureException",
+            DeleteGroupMembershipError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteGroupMembershipError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            DeleteGroupMembershipError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteGroupMembershipError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            DeleteGroupMembershipError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteIAMPolicyAssignment
 #[derive(Debug, PartialEq)]
 pub enum DeleteIAMPolicyAssignmentError {
@@ -7923,6 +8229,21 @@ impl fmt::Display for DeleteIAMPolicyAssignmentError {
     }
 }
 impl Error for DeleteIAMPolicyAssignmentError {}
+impl ServiceErrorCode for DeleteIAMPolicyAssignmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIAMPolicyAssignmentError::AccessDenied(_) => "AccessDeniedException",
+            DeleteIAMPolicyAssignmentError::ConcurrentUpdating(_) => "ConcurrentUpdatingException",
+            DeleteIAMPolicyAssignmentError::InternalFailure(_) => "InternalFailureException",
+            DeleteIAMPolicyAssignmentError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteIAMPolicyAssignmentError::ResourceExists(_) => "ResourceExistsException",
+            DeleteIAMPolicyAssignmentError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteIAMPolicyAssignmentError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteNamespace
 #[derive(Debug, PartialEq)]
 pub enum DeleteNamespaceError {
@@ -7991,6 +8312,19 @@ impl fmt::Display for DeleteNamespaceError {
     }
 }
 impl Error for DeleteNamespaceError {}
+impl ServiceErrorCode for DeleteNamespaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteNamespaceError::AccessDenied(_) => "AccessDeniedException",
+            DeleteNamespaceError::InternalFailure(_) => "InternalFailureException",
+            DeleteNamespaceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteNamespaceError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            DeleteNamespaceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteNamespaceError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            DeleteNamespaceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeleteTemplateError {
@@ -8061,6 +8395,19 @@ impl fmt::Display for DeleteTemplateError {
     }
 }
 impl Error for DeleteTemplateError {}
+impl ServiceErrorCode for DeleteTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTemplateError::Conflict(_) => "ConflictException",
+            DeleteTemplateError::InternalFailure(_) => "InternalFailureException",
+            DeleteTemplateError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteTemplateError::LimitExceeded(_) => "LimitExceededException",
+            DeleteTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteTemplateError::Throttling(_) => "ThrottlingException",
+            DeleteTemplateError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by DeleteTemplateAlias
 #[derive(Debug, PartialEq)]
 pub enum DeleteTemplateAliasError {
@@ -8119,6 +8466,19 @@ impl fmt::Display for DeleteTemplateAliasError {
     }
 }
 impl Error for DeleteTemplateAliasError {}
+impl ServiceErrorCode for DeleteTemplateAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTemplateAliasError::Conflict(_) => "ConflictException",
+            DeleteTemplateAliasError::InternalFailure(_) => "InternalFailureException",
+            DeleteTemplateAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteTemplateAliasError::Throttling(_) => "ThrottlingException",
+            DeleteTemplateAliasError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteTheme
 #[derive(Debug, PartialEq)]
 pub enum DeleteThemeError {
@@ -8185,6 +8545,19 @@ impl fmt::Display for DeleteThemeError {
     }
 }
 impl Error for DeleteThemeError {}
+impl ServiceErrorCode for DeleteThemeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteThemeError::AccessDenied(_) => "AccessDeniedException",
+            DeleteThemeError::Conflict(_) => "ConflictException",
+            DeleteThemeError::InternalFailure(_) => "InternalFailureException",
+            DeleteThemeError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteThemeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteThemeError::Throttling(_) => "ThrottlingException",
+            DeleteThemeError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by DeleteThemeAlias
 #[derive(Debug, PartialEq)]
 pub enum DeleteThemeAliasError {
@@ -8249,6 +8622,18 @@ impl fmt::Display for DeleteThemeAliasError {
     }
 }
 impl Error for DeleteThemeAliasError {}
+impl ServiceErrorCode for DeleteThemeAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteThemeAliasError::Conflict(_) => "ConflictException",
+            DeleteThemeAliasError::InternalFailure(_) => "InternalFailureException",
+            DeleteThemeAliasError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteThemeAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteThemeAliasError::Throttling(_) => "ThrottlingException",
+            DeleteThemeAliasError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserError {
@@ -8315,6 +8700,19 @@ impl fmt::Display for DeleteUserError {
     }
 }
 impl Error for DeleteUserError {}
+impl ServiceErrorCode for DeleteUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserError::AccessDenied(_) => "AccessDeniedException",
+            DeleteUserError::InternalFailure(_) => "InternalFailureException",
+            DeleteUserError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteUserError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            DeleteUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteUserError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            DeleteUserError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteUserByPrincipalId
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserByPrincipalIdError {
@@ -8395,6 +8793,21 @@ impl fmt::Display for DeleteUserByPrincipalIdError {
     }
 }
 impl Error for DeleteUserByPrincipalIdError {}
+impl ServiceErrorCode for DeleteUserByPrincipalIdError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserByPrincipalIdError::AccessDenied(_) => "AccessDeniedException",
+            DeleteUserByPrincipalIdError::InternalFailure(_) => "InternalFailureException",
+            DeleteUserByPrincipalIdError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DeleteUserByPrincipalIdError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            DeleteUserByPrincipalIdError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteUserByPrincipalIdError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            DeleteUserByPrincipalIdError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeAccountCustomization
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountCustomizationError {
@@ -8475,6 +8888,22 @@ impl fmt::Display for DescribeAccountCustomizationError {
     }
 }
 impl Error for DescribeAccountCustomizationError {}
+impl ServiceErrorCode for DescribeAccountCustomizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAccountCustomizationError::AccessDenied(_) => "AccessDeniedException",
+            DescribeAccountCustomizationError::InternalFailure(_) => "InternalFailureException",
+            DescribeAccountCustomizationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeAccountCustomizationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeAccountCustomizationError::ResourceUnavailable(_) => {
+                "ResourceUnavailableException"
+            }
+            DescribeAccountCustomizationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeAccountSettings
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountSettingsError {
@@ -8547,6 +8976,20 @@ impl fmt::Display for DescribeAccountSettingsError {
     }
 }
 impl Error for DescribeAccountSettingsError {}
+impl ServiceErrorCode for DescribeAccountSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAccountSettingsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeAccountSettingsError::InternalFailure(_) => "InternalFailureException",
+            DescribeAccountSettingsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeAccountSettingsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeAccountSettingsError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            DescribeAccountSettingsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeAnalysis
 #[derive(Debug, PartialEq)]
 pub enum DescribeAnalysisError {
@@ -8611,6 +9054,18 @@ impl fmt::Display for DescribeAnalysisError {
     }
 }
 impl Error for DescribeAnalysisError {}
+impl ServiceErrorCode for DescribeAnalysisError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAnalysisError::AccessDenied(_) => "AccessDeniedException",
+            DescribeAnalysisError::InternalFailure(_) => "InternalFailureException",
+            DescribeAnalysisError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeAnalysisError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeAnalysisError::Throttling(_) => "ThrottlingException",
+            DescribeAnalysisError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by DescribeAnalysisPermissions
 #[derive(Debug, PartialEq)]
 pub enum DescribeAnalysisPermissionsError {
@@ -8681,6 +9136,21 @@ impl fmt::Display for DescribeAnalysisPermissionsError {
     }
 }
 impl Error for DescribeAnalysisPermissionsError {}
+impl ServiceErrorCode for DescribeAnalysisPermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAnalysisPermissionsError::InternalFailure(_) => "InternalFailureException",
+            DescribeAnalysisPermissionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeAnalysisPermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeAnalysisPermissionsError::Throttling(_) => "ThrottlingException",
+            DescribeAnalysisPermissionsError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDashboard
 #[derive(Debug, PartialEq)]
 pub enum DescribeDashboardError {
@@ -8745,6 +9215,18 @@ impl fmt::Display for DescribeDashboardError {
     }
 }
 impl Error for DescribeDashboardError {}
+impl ServiceErrorCode for DescribeDashboardError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDashboardError::AccessDenied(_) => "AccessDeniedException",
+            DescribeDashboardError::InternalFailure(_) => "InternalFailureException",
+            DescribeDashboardError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeDashboardError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDashboardError::Throttling(_) => "ThrottlingException",
+            DescribeDashboardError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by DescribeDashboardPermissions
 #[derive(Debug, PartialEq)]
 pub enum DescribeDashboardPermissionsError {
@@ -8817,6 +9299,21 @@ impl fmt::Display for DescribeDashboardPermissionsError {
     }
 }
 impl Error for DescribeDashboardPermissionsError {}
+impl ServiceErrorCode for DescribeDashboardPermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDashboardPermissionsError::InternalFailure(_) => "InternalFailureException",
+            DescribeDashboardPermissionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeDashboardPermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDashboardPermissionsError::Throttling(_) => "ThrottlingException",
+            DescribeDashboardPermissionsError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDataSet
 #[derive(Debug, PartialEq)]
 pub enum DescribeDataSetError {
@@ -8873,6 +9370,17 @@ impl fmt::Display for DescribeDataSetError {
     }
 }
 impl Error for DescribeDataSetError {}
+impl ServiceErrorCode for DescribeDataSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDataSetError::AccessDenied(_) => "AccessDeniedException",
+            DescribeDataSetError::InternalFailure(_) => "InternalFailureException",
+            DescribeDataSetError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeDataSetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDataSetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeDataSetPermissions
 #[derive(Debug, PartialEq)]
 pub enum DescribeDataSetPermissionsError {
@@ -8941,6 +9449,19 @@ impl fmt::Display for DescribeDataSetPermissionsError {
     }
 }
 impl Error for DescribeDataSetPermissionsError {}
+impl ServiceErrorCode for DescribeDataSetPermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDataSetPermissionsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeDataSetPermissionsError::InternalFailure(_) => "InternalFailureException",
+            DescribeDataSetPermissionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeDataSetPermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDataSetPermissionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeDataSource
 #[derive(Debug, PartialEq)]
 pub enum DescribeDataSourceError {
@@ -8997,6 +9518,17 @@ impl fmt::Display for DescribeDataSourceError {
     }
 }
 impl Error for DescribeDataSourceError {}
+impl ServiceErrorCode for DescribeDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDataSourceError::AccessDenied(_) => "AccessDeniedException",
+            DescribeDataSourceError::InternalFailure(_) => "InternalFailureException",
+            DescribeDataSourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeDataSourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDataSourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeDataSourcePermissions
 #[derive(Debug, PartialEq)]
 pub enum DescribeDataSourcePermissionsError {
@@ -9069,6 +9601,19 @@ impl fmt::Display for DescribeDataSourcePermissionsError {
     }
 }
 impl Error for DescribeDataSourcePermissionsError {}
+impl ServiceErrorCode for DescribeDataSourcePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDataSourcePermissionsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeDataSourcePermissionsError::InternalFailure(_) => "InternalFailureException",
+            DescribeDataSourcePermissionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeDataSourcePermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDataSourcePermissionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeGroupError {
@@ -9135,6 +9680,19 @@ impl fmt::Display for DescribeGroupError {
     }
 }
 impl Error for DescribeGroupError {}
+impl ServiceErrorCode for DescribeGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGroupError::AccessDenied(_) => "AccessDeniedException",
+            DescribeGroupError::InternalFailure(_) => "InternalFailureException",
+            DescribeGroupError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeGroupError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            DescribeGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeGroupError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            DescribeGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeIAMPolicyAssignment
 #[derive(Debug, PartialEq)]
 pub enum DescribeIAMPolicyAssignmentError {
@@ -9211,6 +9769,20 @@ impl fmt::Display for DescribeIAMPolicyAssignmentError {
     }
 }
 impl Error for DescribeIAMPolicyAssignmentError {}
+impl ServiceErrorCode for DescribeIAMPolicyAssignmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeIAMPolicyAssignmentError::AccessDenied(_) => "AccessDeniedException",
+            DescribeIAMPolicyAssignmentError::InternalFailure(_) => "InternalFailureException",
+            DescribeIAMPolicyAssignmentError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeIAMPolicyAssignmentError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeIAMPolicyAssignmentError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeIAMPolicyAssignmentError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeIngestion
 #[derive(Debug, PartialEq)]
 pub enum DescribeIngestionError {
@@ -9273,6 +9845,18 @@ impl fmt::Display for DescribeIngestionError {
     }
 }
 impl Error for DescribeIngestionError {}
+impl ServiceErrorCode for DescribeIngestionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeIngestionError::AccessDenied(_) => "AccessDeniedException",
+            DescribeIngestionError::InternalFailure(_) => "InternalFailureException",
+            DescribeIngestionError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeIngestionError::ResourceExists(_) => "ResourceExistsException",
+            DescribeIngestionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeIngestionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeNamespace
 #[derive(Debug, PartialEq)]
 pub enum DescribeNamespaceError {
@@ -9337,6 +9921,18 @@ impl fmt::Display for DescribeNamespaceError {
     }
 }
 impl Error for DescribeNamespaceError {}
+impl ServiceErrorCode for DescribeNamespaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeNamespaceError::AccessDenied(_) => "AccessDeniedException",
+            DescribeNamespaceError::InternalFailure(_) => "InternalFailureException",
+            DescribeNamespaceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeNamespaceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeNamespaceError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            DescribeNamespaceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeTemplate
 #[derive(Debug, PartialEq)]
 pub enum DescribeTemplateError {
@@ -9413,6 +10009,20 @@ impl fmt::Display for DescribeTemplateError {
     }
 }
 impl Error for DescribeTemplateError {}
+impl ServiceErrorCode for DescribeTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTemplateError::AccessDenied(_) => "AccessDeniedException",
+            DescribeTemplateError::Conflict(_) => "ConflictException",
+            DescribeTemplateError::InternalFailure(_) => "InternalFailureException",
+            DescribeTemplateError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeTemplateError::ResourceExists(_) => "ResourceExistsException",
+            DescribeTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeTemplateError::Throttling(_) => "ThrottlingException",
+            DescribeTemplateError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by DescribeTemplateAlias
 #[derive(Debug, PartialEq)]
 pub enum DescribeTemplateAliasError {
@@ -9467,6 +10077,18 @@ impl fmt::Display for DescribeTemplateAliasError {
     }
 }
 impl Error for DescribeTemplateAliasError {}
+impl ServiceErrorCode for DescribeTemplateAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTemplateAliasError::InternalFailure(_) => "InternalFailureException",
+            DescribeTemplateAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeTemplateAliasError::Throttling(_) => "ThrottlingException",
+            DescribeTemplateAliasError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeTemplatePermissions
 #[derive(Debug, PartialEq)]
 pub enum DescribeTemplatePermissionsError {
@@ -9545,6 +10167,22 @@ impl fmt::Display for DescribeTemplatePermissionsError {
     }
 }
 impl Error for DescribeTemplatePermissionsError {}
+impl ServiceErrorCode for DescribeTemplatePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTemplatePermissionsError::Conflict(_) => "ConflictException",
+            DescribeTemplatePermissionsError::InternalFailure(_) => "InternalFailureException",
+            DescribeTemplatePermissionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeTemplatePermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeTemplatePermissionsError::Throttling(_) => "ThrottlingException",
+            DescribeTemplatePermissionsError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeTheme
 #[derive(Debug, PartialEq)]
 pub enum DescribeThemeError {
@@ -9613,6 +10251,19 @@ impl fmt::Display for DescribeThemeError {
     }
 }
 impl Error for DescribeThemeError {}
+impl ServiceErrorCode for DescribeThemeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeThemeError::AccessDenied(_) => "AccessDeniedException",
+            DescribeThemeError::InternalFailure(_) => "InternalFailureException",
+            DescribeThemeError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeThemeError::ResourceExists(_) => "ResourceExistsException",
+            DescribeThemeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeThemeError::Throttling(_) => "ThrottlingException",
+            DescribeThemeError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by DescribeThemeAlias
 #[derive(Debug, PartialEq)]
 pub enum DescribeThemeAliasError {
@@ -9677,6 +10328,18 @@ impl fmt::Display for DescribeThemeAliasError {
     }
 }
 impl Error for DescribeThemeAliasError {}
+impl ServiceErrorCode for DescribeThemeAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeThemeAliasError::Conflict(_) => "ConflictException",
+            DescribeThemeAliasError::InternalFailure(_) => "InternalFailureException",
+            DescribeThemeAliasError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeThemeAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeThemeAliasError::Throttling(_) => "ThrottlingException",
+            DescribeThemeAliasError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by DescribeThemePermissions
 #[derive(Debug, PartialEq)]
 pub enum DescribeThemePermissionsError {
@@ -9751,6 +10414,22 @@ impl fmt::Display for DescribeThemePermissionsError {
     }
 }
 impl Error for DescribeThemePermissionsError {}
+impl ServiceErrorCode for DescribeThemePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeThemePermissionsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeThemePermissionsError::InternalFailure(_) => "InternalFailureException",
+            DescribeThemePermissionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            DescribeThemePermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeThemePermissionsError::Throttling(_) => "ThrottlingException",
+            DescribeThemePermissionsError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeUser
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserError {
@@ -9817,6 +10496,19 @@ impl fmt::Display for DescribeUserError {
     }
 }
 impl Error for DescribeUserError {}
+impl ServiceErrorCode for DescribeUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserError::AccessDenied(_) => "AccessDeniedException",
+            DescribeUserError::InternalFailure(_) => "InternalFailureException",
+            DescribeUserError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DescribeUserError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            DescribeUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeUserError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            DescribeUserError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetDashboardEmbedUrl
 #[derive(Debug, PartialEq)]
 pub enum GetDashboardEmbedUrlError {
@@ -9935,6 +10627,34 @@ impl fmt::Display for GetDashboardEmbedUrlError {
     }
 }
 impl Error for GetDashboardEmbedUrlError {}
+impl ServiceErrorCode for GetDashboardEmbedUrlError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDashboardEmbedUrlError::AccessDenied(_) => "AccessDeniedException",
+            GetDashboardEmbedUrlError::DomainNotWhitelisted(_) => "DomainNotWhitelistedException",
+            GetDashboardEmbedUrlError::IdentityTypeNotSupported(_) => {
+                "IdentityTypeNotSupportedException"
+            }
+            GetDashboardEmbedUrlError::InternalFailure(_) => "InternalFailureException",
+            GetDashboardEmbedUrlError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetDashboardEmbedUrlError::QuickSightUserNotFound(_) => {
+                "QuickSightUserNotFoundException"
+            }
+            GetDashboardEmbedUrlError::ResourceExists(_) => "ResourceExistsException",
+            GetDashboardEmbedUrlError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetDashboardEmbedUrlError::SessionLifetimeInMinutesInvalid(_) => {
+                "SessionLifetimeInMinutesInvalidException"
+            }
+            GetDashboardEmbedUrlError::Throttling(_) => "ThrottlingException",
+            GetDashboardEmbedUrlError::UnsupportedPricingPlan(_) => {
+                "UnsupportedPricingPlanException"
+            }
+            GetDashboardEmbedUrlError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by GetSessionEmbedUrl
 #[derive(Debug, PartialEq)]
 pub enum GetSessionEmbedUrlError {
@@ -10023,6 +10743,23 @@ impl fmt::Display for GetSessionEmbedUrlError {
     }
 }
 impl Error for GetSessionEmbedUrlError {}
+impl ServiceErrorCode for GetSessionEmbedUrlError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSessionEmbedUrlError::AccessDenied(_) => "AccessDeniedException",
+            GetSessionEmbedUrlError::InternalFailure(_) => "InternalFailureException",
+            GetSessionEmbedUrlError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetSessionEmbedUrlError::QuickSightUserNotFound(_) => "QuickSightUserNotFoundException",
+            GetSessionEmbedUrlError::ResourceExists(_) => "ResourceExistsException",
+            GetSessionEmbedUrlError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetSessionEmbedUrlError::SessionLifetimeInMinutesInvalid(_) => {
+                "SessionLifetimeInMinutesInvalidException"
+            }
+            GetSessionEmbedUrlError::Throttling(_) => "ThrottlingException",
+            GetSessionEmbedUrlError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by ListAnalyses
 #[derive(Debug, PartialEq)]
 pub enum ListAnalysesError {
@@ -10071,6 +10808,16 @@ impl fmt::Display for ListAnalysesError {
     }
 }
 impl Error for ListAnalysesError {}
+impl ServiceErrorCode for ListAnalysesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAnalysesError::InternalFailure(_) => "InternalFailureException",
+            ListAnalysesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListAnalysesError::Throttling(_) => "ThrottlingException",
+            ListAnalysesError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by ListDashboardVersions
 #[derive(Debug, PartialEq)]
 pub enum ListDashboardVersionsError {
@@ -10141,6 +10888,22 @@ impl fmt::Display for ListDashboardVersionsError {
     }
 }
 impl Error for ListDashboardVersionsError {}
+impl ServiceErrorCode for ListDashboardVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDashboardVersionsError::InternalFailure(_) => "InternalFailureException",
+            ListDashboardVersionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListDashboardVersionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListDashboardVersionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListDashboardVersionsError::Throttling(_) => "ThrottlingException",
+            ListDashboardVersionsError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by ListDashboards
 #[derive(Debug, PartialEq)]
 pub enum ListDashboardsError {
@@ -10191,6 +10954,16 @@ impl fmt::Display for ListDashboardsError {
     }
 }
 impl Error for ListDashboardsError {}
+impl ServiceErrorCode for ListDashboardsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDashboardsError::InternalFailure(_) => "InternalFailureException",
+            ListDashboardsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListDashboardsError::Throttling(_) => "ThrottlingException",
+            ListDashboardsError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by ListDataSets
 #[derive(Debug, PartialEq)]
 pub enum ListDataSetsError {
@@ -10245,6 +11018,17 @@ impl fmt::Display for ListDataSetsError {
     }
 }
 impl Error for ListDataSetsError {}
+impl ServiceErrorCode for ListDataSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDataSetsError::AccessDenied(_) => "AccessDeniedException",
+            ListDataSetsError::InternalFailure(_) => "InternalFailureException",
+            ListDataSetsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListDataSetsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListDataSetsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDataSources
 #[derive(Debug, PartialEq)]
 pub enum ListDataSourcesError {
@@ -10301,6 +11085,17 @@ impl fmt::Display for ListDataSourcesError {
     }
 }
 impl Error for ListDataSourcesError {}
+impl ServiceErrorCode for ListDataSourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDataSourcesError::AccessDenied(_) => "AccessDeniedException",
+            ListDataSourcesError::InternalFailure(_) => "InternalFailureException",
+            ListDataSourcesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListDataSourcesError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListDataSourcesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListGroupMemberships
 #[derive(Debug, PartialEq)]
 pub enum ListGroupMembershipsError {
@@ -10385,6 +11180,20 @@ impl fmt::Display for ListGroupMembershipsError {
     }
 }
 impl Error for ListGroupMembershipsError {}
+impl ServiceErrorCode for ListGroupMembershipsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGroupMembershipsError::AccessDenied(_) => "AccessDeniedException",
+            ListGroupMembershipsError::InternalFailure(_) => "InternalFailureException",
+            ListGroupMembershipsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListGroupMembershipsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListGroupMembershipsError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            ListGroupMembershipsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListGroupMembershipsError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            ListGroupMembershipsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListGroups
 #[derive(Debug, PartialEq)]
 pub enum ListGroupsError {
@@ -10457,6 +11266,20 @@ impl fmt::Display for ListGroupsError {
     }
 }
 impl Error for ListGroupsError {}
+impl ServiceErrorCode for ListGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGroupsError::AccessDenied(_) => "AccessDeniedException",
+            ListGroupsError::InternalFailure(_) => "InternalFailureException",
+            ListGroupsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListGroupsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListGroupsError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            ListGroupsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListGroupsError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            ListGroupsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListIAMPolicyAssignments
 #[derive(Debug, PartialEq)]
 pub enum ListIAMPolicyAssignmentsError {
@@ -10529,6 +11352,20 @@ impl fmt::Display for ListIAMPolicyAssignmentsError {
     }
 }
 impl Error for ListIAMPolicyAssignmentsError {}
+impl ServiceErrorCode for ListIAMPolicyAssignmentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListIAMPolicyAssignmentsError::AccessDenied(_) => "AccessDeniedException",
+            ListIAMPolicyAssignmentsError::InternalFailure(_) => "InternalFailureException",
+            ListIAMPolicyAssignmentsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListIAMPolicyAssignmentsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListIAMPolicyAssignmentsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListIAMPolicyAssignmentsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListIAMPolicyAssignmentsForUser
 #[derive(Debug, PartialEq)]
 pub enum ListIAMPolicyAssignmentsForUserError {
@@ -10621,6 +11458,25 @@ impl fmt::Display for ListIAMPolicyAssignmentsForUserError {
     }
 }
 impl Error for ListIAMPolicyAssignmentsForUserError {}
+impl ServiceErrorCode for ListIAMPolicyAssignmentsForUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListIAMPolicyAssignmentsForUserError::AccessDenied(_) => "AccessDeniedException",
+            ListIAMPolicyAssignmentsForUserError::ConcurrentUpdating(_) => {
+                "ConcurrentUpdatingException"
+            }
+            ListIAMPolicyAssignmentsForUserError::InternalFailure(_) => "InternalFailureException",
+            ListIAMPolicyAssignmentsForUserError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            ListIAMPolicyAssignmentsForUserError::ResourceExists(_) => "ResourceExistsException",
+            ListIAMPolicyAssignmentsForUserError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            ListIAMPolicyAssignmentsForUserError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListIngestions
 #[derive(Debug, PartialEq)]
 pub enum ListIngestionsError {
@@ -10689,6 +11545,19 @@ impl fmt::Display for ListIngestionsError {
     }
 }
 impl Error for ListIngestionsError {}
+impl ServiceErrorCode for ListIngestionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListIngestionsError::AccessDenied(_) => "AccessDeniedException",
+            ListIngestionsError::InternalFailure(_) => "InternalFailureException",
+            ListIngestionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListIngestionsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListIngestionsError::ResourceExists(_) => "ResourceExistsException",
+            ListIngestionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListIngestionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListNamespaces
 #[derive(Debug, PartialEq)]
 pub enum ListNamespacesError {
@@ -10763,6 +11632,20 @@ impl fmt::Display for ListNamespacesError {
     }
 }
 impl Error for ListNamespacesError {}
+impl ServiceErrorCode for ListNamespacesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListNamespacesError::AccessDenied(_) => "AccessDeniedException",
+            ListNamespacesError::InternalFailure(_) => "InternalFailureException",
+            ListNamespacesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListNamespacesError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListNamespacesError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            ListNamespacesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListNamespacesError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            ListNamespacesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -10821,6 +11704,17 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            ListTagsForResourceError::InternalFailure(_) => "InternalFailureException",
+            ListTagsForResourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTemplateAliases
 #[derive(Debug, PartialEq)]
 pub enum ListTemplateAliasesError {
@@ -10881,6 +11775,19 @@ impl fmt::Display for ListTemplateAliasesError {
     }
 }
 impl Error for ListTemplateAliasesError {}
+impl ServiceErrorCode for ListTemplateAliasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTemplateAliasesError::InternalFailure(_) => "InternalFailureException",
+            ListTemplateAliasesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListTemplateAliasesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTemplateAliasesError::Throttling(_) => "ThrottlingException",
+            ListTemplateAliasesError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by ListTemplateVersions
 #[derive(Debug, PartialEq)]
 pub enum ListTemplateVersionsError {
@@ -10951,6 +11858,20 @@ impl fmt::Display for ListTemplateVersionsError {
     }
 }
 impl Error for ListTemplateVersionsError {}
+impl ServiceErrorCode for ListTemplateVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTemplateVersionsError::InternalFailure(_) => "InternalFailureException",
+            ListTemplateVersionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListTemplateVersionsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListTemplateVersionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTemplateVersionsError::Throttling(_) => "ThrottlingException",
+            ListTemplateVersionsError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by ListTemplates
 #[derive(Debug, PartialEq)]
 pub enum ListTemplatesError {
@@ -11013,6 +11934,18 @@ impl fmt::Display for ListTemplatesError {
     }
 }
 impl Error for ListTemplatesError {}
+impl ServiceErrorCode for ListTemplatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTemplatesError::InternalFailure(_) => "InternalFailureException",
+            ListTemplatesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListTemplatesError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListTemplatesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTemplatesError::Throttling(_) => "ThrottlingException",
+            ListTemplatesError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by ListThemeAliases
 #[derive(Debug, PartialEq)]
 pub enum ListThemeAliasesError {
@@ -11083,6 +12016,19 @@ impl fmt::Display for ListThemeAliasesError {
     }
 }
 impl Error for ListThemeAliasesError {}
+impl ServiceErrorCode for ListThemeAliasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListThemeAliasesError::Conflict(_) => "ConflictException",
+            ListThemeAliasesError::InternalFailure(_) => "InternalFailureException",
+            ListThemeAliasesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListThemeAliasesError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListThemeAliasesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListThemeAliasesError::Throttling(_) => "ThrottlingException",
+            ListThemeAliasesError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by ListThemeVersions
 #[derive(Debug, PartialEq)]
 pub enum ListThemeVersionsError {
@@ -11153,6 +12099,19 @@ impl fmt::Display for ListThemeVersionsError {
     }
 }
 impl Error for ListThemeVersionsError {}
+impl ServiceErrorCode for ListThemeVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListThemeVersionsError::AccessDenied(_) => "AccessDeniedException",
+            ListThemeVersionsError::InternalFailure(_) => "InternalFailureException",
+            ListThemeVersionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListThemeVersionsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListThemeVersionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListThemeVersionsError::Throttling(_) => "ThrottlingException",
+            ListThemeVersionsError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by ListThemes
 #[derive(Debug, PartialEq)]
 pub enum ListThemesError {
@@ -11219,6 +12178,19 @@ impl fmt::Display for ListThemesError {
     }
 }
 impl Error for ListThemesError {}
+impl ServiceErrorCode for ListThemesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListThemesError::AccessDenied(_) => "AccessDeniedException",
+            ListThemesError::InternalFailure(_) => "InternalFailureException",
+            ListThemesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListThemesError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListThemesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListThemesError::Throttling(_) => "ThrottlingException",
+            ListThemesError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by ListUserGroups
 #[derive(Debug, PartialEq)]
 pub enum ListUserGroupsError {
@@ -11287,6 +12259,19 @@ impl fmt::Display for ListUserGroupsError {
     }
 }
 impl Error for ListUserGroupsError {}
+impl ServiceErrorCode for ListUserGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUserGroupsError::AccessDenied(_) => "AccessDeniedException",
+            ListUserGroupsError::InternalFailure(_) => "InternalFailureException",
+            ListUserGroupsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListUserGroupsError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            ListUserGroupsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListUserGroupsError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            ListUserGroupsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListUsers
 #[derive(Debug, PartialEq)]
 pub enum ListUsersError {
@@ -11359,6 +12344,20 @@ impl fmt::Display for ListUsersError {
     }
 }
 impl Error for ListUsersError {}
+impl ServiceErrorCode for ListUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUsersError::AccessDenied(_) => "AccessDeniedException",
+            ListUsersError::InternalFailure(_) => "InternalFailureException",
+            ListUsersError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListUsersError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListUsersError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            ListUsersError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListUsersError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            ListUsersError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by RegisterUser
 #[derive(Debug, PartialEq)]
 pub enum RegisterUserError {
@@ -11437,6 +12436,21 @@ impl fmt::Display for RegisterUserError {
     }
 }
 impl Error for RegisterUserError {}
+impl ServiceErrorCode for RegisterUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterUserError::AccessDenied(_) => "AccessDeniedException",
+            RegisterUserError::InternalFailure(_) => "InternalFailureException",
+            RegisterUserError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            RegisterUserError::LimitExceeded(_) => "LimitExceededException",
+            RegisterUserError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            RegisterUserError::ResourceExists(_) => "ResourceExistsException",
+            RegisterUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RegisterUserError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            RegisterUserError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by RestoreAnalysis
 #[derive(Debug, PartialEq)]
 pub enum RestoreAnalysisError {
@@ -11501,6 +12515,18 @@ impl fmt::Display for RestoreAnalysisError {
     }
 }
 impl Error for RestoreAnalysisError {}
+impl ServiceErrorCode for RestoreAnalysisError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreAnalysisError::Conflict(_) => "ConflictException",
+            RestoreAnalysisError::InternalFailure(_) => "InternalFailureException",
+            RestoreAnalysisError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            RestoreAnalysisError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RestoreAnalysisError::Throttling(_) => "ThrottlingException",
+            RestoreAnalysisError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by SearchAnalyses
 #[derive(Debug, PartialEq)]
 pub enum SearchAnalysesError {
@@ -11565,6 +12591,18 @@ impl fmt::Display for SearchAnalysesError {
     }
 }
 impl Error for SearchAnalysesError {}
+impl ServiceErrorCode for SearchAnalysesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchAnalysesError::InternalFailure(_) => "InternalFailureException",
+            SearchAnalysesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            SearchAnalysesError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            SearchAnalysesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SearchAnalysesError::Throttling(_) => "ThrottlingException",
+            SearchAnalysesError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by SearchDashboards
 #[derive(Debug, PartialEq)]
 pub enum SearchDashboardsError {
@@ -11629,6 +12667,18 @@ impl fmt::Display for SearchDashboardsError {
     }
 }
 impl Error for SearchDashboardsError {}
+impl ServiceErrorCode for SearchDashboardsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchDashboardsError::InternalFailure(_) => "InternalFailureException",
+            SearchDashboardsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            SearchDashboardsError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            SearchDashboardsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SearchDashboardsError::Throttling(_) => "ThrottlingException",
+            SearchDashboardsError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -11689,6 +12739,18 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AccessDenied(_) => "AccessDeniedException",
+            TagResourceError::InternalFailure(_) => "InternalFailureException",
+            TagResourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -11743,6 +12805,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AccessDenied(_) => "AccessDeniedException",
+            UntagResourceError::InternalFailure(_) => "InternalFailureException",
+            UntagResourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateAccountCustomization
 #[derive(Debug, PartialEq)]
 pub enum UpdateAccountCustomizationError {
@@ -11821,6 +12894,22 @@ impl fmt::Display for UpdateAccountCustomizationError {
     }
 }
 impl Error for UpdateAccountCustomizationError {}
+impl ServiceErrorCode for UpdateAccountCustomizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAccountCustomizationError::AccessDenied(_) => "AccessDeniedException",
+            UpdateAccountCustomizationError::InternalFailure(_) => "InternalFailureException",
+            UpdateAccountCustomizationError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateAccountCustomizationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateAccountCustomizationError::ResourceUnavailable(_) => {
+                "ResourceUnavailableException"
+            }
+            UpdateAccountCustomizationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateAccountSettings
 #[derive(Debug, PartialEq)]
 pub enum UpdateAccountSettingsError {
@@ -11889,6 +12978,20 @@ impl fmt::Display for UpdateAccountSettingsError {
     }
 }
 impl Error for UpdateAccountSettingsError {}
+impl ServiceErrorCode for UpdateAccountSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAccountSettingsError::AccessDenied(_) => "AccessDeniedException",
+            UpdateAccountSettingsError::InternalFailure(_) => "InternalFailureException",
+            UpdateAccountSettingsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateAccountSettingsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateAccountSettingsError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            UpdateAccountSettingsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateAnalysis
 #[derive(Debug, PartialEq)]
 pub enum UpdateAnalysisError {
@@ -11959,6 +13062,19 @@ impl fmt::Display for UpdateAnalysisError {
     }
 }
 impl Error for UpdateAnalysisError {}
+impl ServiceErrorCode for UpdateAnalysisError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAnalysisError::Conflict(_) => "ConflictException",
+            UpdateAnalysisError::InternalFailure(_) => "InternalFailureException",
+            UpdateAnalysisError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateAnalysisError::ResourceExists(_) => "ResourceExistsException",
+            UpdateAnalysisError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateAnalysisError::Throttling(_) => "ThrottlingException",
+            UpdateAnalysisError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by UpdateAnalysisPermissions
 #[derive(Debug, PartialEq)]
 pub enum UpdateAnalysisPermissionsError {
@@ -12033,6 +13149,22 @@ impl fmt::Display for UpdateAnalysisPermissionsError {
     }
 }
 impl Error for UpdateAnalysisPermissionsError {}
+impl ServiceErrorCode for UpdateAnalysisPermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAnalysisPermissionsError::Conflict(_) => "ConflictException",
+            UpdateAnalysisPermissionsError::InternalFailure(_) => "InternalFailureException",
+            UpdateAnalysisPermissionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateAnalysisPermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateAnalysisPermissionsError::Throttling(_) => "ThrottlingException",
+            UpdateAnalysisPermissionsError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateDashboard
 #[derive(Debug, PartialEq)]
 pub enum UpdateDashboardError {
@@ -12103,6 +13235,19 @@ impl fmt::Display for UpdateDashboardError {
     }
 }
 impl Error for UpdateDashboardError {}
+impl ServiceErrorCode for UpdateDashboardError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDashboardError::Conflict(_) => "ConflictException",
+            UpdateDashboardError::InternalFailure(_) => "InternalFailureException",
+            UpdateDashboardError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateDashboardError::LimitExceeded(_) => "LimitExceededException",
+            UpdateDashboardError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDashboardError::Throttling(_) => "ThrottlingException",
+            UpdateDashboardError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by UpdateDashboardPermissions
 #[derive(Debug, PartialEq)]
 pub enum UpdateDashboardPermissionsError {
@@ -12179,6 +13324,22 @@ impl fmt::Display for UpdateDashboardPermissionsError {
     }
 }
 impl Error for UpdateDashboardPermissionsError {}
+impl ServiceErrorCode for UpdateDashboardPermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDashboardPermissionsError::Conflict(_) => "ConflictException",
+            UpdateDashboardPermissionsError::InternalFailure(_) => "InternalFailureException",
+            UpdateDashboardPermissionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateDashboardPermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDashboardPermissionsError::Throttling(_) => "ThrottlingException",
+            UpdateDashboardPermissionsError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateDashboardPublishedVersion
 #[derive(Debug, PartialEq)]
 pub enum UpdateDashboardPublishedVersionError {
@@ -12261,6 +13422,24 @@ impl fmt::Display for UpdateDashboardPublishedVersionError {
     }
 }
 impl Error for UpdateDashboardPublishedVersionError {}
+impl ServiceErrorCode for UpdateDashboardPublishedVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDashboardPublishedVersionError::Conflict(_) => "ConflictException",
+            UpdateDashboardPublishedVersionError::InternalFailure(_) => "InternalFailureException",
+            UpdateDashboardPublishedVersionError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateDashboardPublishedVersionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            UpdateDashboardPublishedVersionError::Throttling(_) => "ThrottlingException",
+            UpdateDashboardPublishedVersionError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateDataSet
 #[derive(Debug, PartialEq)]
 pub enum UpdateDataSetError {
@@ -12335,6 +13514,20 @@ impl fmt::Display for UpdateDataSetError {
     }
 }
 impl Error for UpdateDataSetError {}
+impl ServiceErrorCode for UpdateDataSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDataSetError::AccessDenied(_) => "AccessDeniedException",
+            UpdateDataSetError::Conflict(_) => "ConflictException",
+            UpdateDataSetError::InternalFailure(_) => "InternalFailureException",
+            UpdateDataSetError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateDataSetError::LimitExceeded(_) => "LimitExceededException",
+            UpdateDataSetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDataSetError::Throttling(_) => "ThrottlingException",
+            UpdateDataSetError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by UpdateDataSetPermissions
 #[derive(Debug, PartialEq)]
 pub enum UpdateDataSetPermissionsError {
@@ -12405,6 +13598,20 @@ impl fmt::Display for UpdateDataSetPermissionsError {
     }
 }
 impl Error for UpdateDataSetPermissionsError {}
+impl ServiceErrorCode for UpdateDataSetPermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDataSetPermissionsError::AccessDenied(_) => "AccessDeniedException",
+            UpdateDataSetPermissionsError::Conflict(_) => "ConflictException",
+            UpdateDataSetPermissionsError::InternalFailure(_) => "InternalFailureException",
+            UpdateDataSetPermissionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateDataSetPermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDataSetPermissionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateDataSource
 #[derive(Debug, PartialEq)]
 pub enum UpdateDataSourceError {
@@ -12467,6 +13674,18 @@ impl fmt::Display for UpdateDataSourceError {
     }
 }
 impl Error for UpdateDataSourceError {}
+impl ServiceErrorCode for UpdateDataSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDataSourceError::AccessDenied(_) => "AccessDeniedException",
+            UpdateDataSourceError::Conflict(_) => "ConflictException",
+            UpdateDataSourceError::InternalFailure(_) => "InternalFailureException",
+            UpdateDataSourceError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateDataSourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDataSourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateDataSourcePermissions
 #[derive(Debug, PartialEq)]
 pub enum UpdateDataSourcePermissionsError {
@@ -12543,6 +13762,20 @@ impl fmt::Display for UpdateDataSourcePermissionsError {
     }
 }
 impl Error for UpdateDataSourcePermissionsError {}
+impl ServiceErrorCode for UpdateDataSourcePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDataSourcePermissionsError::AccessDenied(_) => "AccessDeniedException",
+            UpdateDataSourcePermissionsError::Conflict(_) => "ConflictException",
+            UpdateDataSourcePermissionsError::InternalFailure(_) => "InternalFailureException",
+            UpdateDataSourcePermissionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateDataSourcePermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDataSourcePermissionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateGroupError {
@@ -12609,6 +13842,19 @@ impl fmt::Display for UpdateGroupError {
     }
 }
 impl Error for UpdateGroupError {}
+impl ServiceErrorCode for UpdateGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGroupError::AccessDenied(_) => "AccessDeniedException",
+            UpdateGroupError::InternalFailure(_) => "InternalFailureException",
+            UpdateGroupError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateGroupError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            UpdateGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateGroupError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            UpdateGroupError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateIAMPolicyAssignment
 #[derive(Debug, PartialEq)]
 pub enum UpdateIAMPolicyAssignmentError {
@@ -12691,6 +13937,21 @@ impl fmt::Display for UpdateIAMPolicyAssignmentError {
     }
 }
 impl Error for UpdateIAMPolicyAssignmentError {}
+impl ServiceErrorCode for UpdateIAMPolicyAssignmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateIAMPolicyAssignmentError::AccessDenied(_) => "AccessDeniedException",
+            UpdateIAMPolicyAssignmentError::ConcurrentUpdating(_) => "ConcurrentUpdatingException",
+            UpdateIAMPolicyAssignmentError::InternalFailure(_) => "InternalFailureException",
+            UpdateIAMPolicyAssignmentError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateIAMPolicyAssignmentError::ResourceExists(_) => "ResourceExistsException",
+            UpdateIAMPolicyAssignmentError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateIAMPolicyAssignmentError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateTemplate
 #[derive(Debug, PartialEq)]
 pub enum UpdateTemplateError {
@@ -12767,6 +14028,20 @@ impl fmt::Display for UpdateTemplateError {
     }
 }
 impl Error for UpdateTemplateError {}
+impl ServiceErrorCode for UpdateTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTemplateError::Conflict(_) => "ConflictException",
+            UpdateTemplateError::InternalFailure(_) => "InternalFailureException",
+            UpdateTemplateError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateTemplateError::LimitExceeded(_) => "LimitExceededException",
+            UpdateTemplateError::ResourceExists(_) => "ResourceExistsException",
+            UpdateTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateTemplateError::Throttling(_) => "ThrottlingException",
+            UpdateTemplateError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by UpdateTemplateAlias
 #[derive(Debug, PartialEq)]
 pub enum UpdateTemplateAliasError {
@@ -12825,6 +14100,19 @@ impl fmt::Display for UpdateTemplateAliasError {
     }
 }
 impl Error for UpdateTemplateAliasError {}
+impl ServiceErrorCode for UpdateTemplateAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTemplateAliasError::Conflict(_) => "ConflictException",
+            UpdateTemplateAliasError::InternalFailure(_) => "InternalFailureException",
+            UpdateTemplateAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateTemplateAliasError::Throttling(_) => "ThrottlingException",
+            UpdateTemplateAliasError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateTemplatePermissions
 #[derive(Debug, PartialEq)]
 pub enum UpdateTemplatePermissionsError {
@@ -12899,6 +14187,22 @@ impl fmt::Display for UpdateTemplatePermissionsError {
     }
 }
 impl Error for UpdateTemplatePermissionsError {}
+impl ServiceErrorCode for UpdateTemplatePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTemplatePermissionsError::Conflict(_) => "ConflictException",
+            UpdateTemplatePermissionsError::InternalFailure(_) => "InternalFailureException",
+            UpdateTemplatePermissionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateTemplatePermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateTemplatePermissionsError::Throttling(_) => "ThrottlingException",
+            UpdateTemplatePermissionsError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateTheme
 #[derive(Debug, PartialEq)]
 pub enum UpdateThemeError {
@@ -12971,6 +14275,20 @@ impl fmt::Display for UpdateThemeError {
     }
 }
 impl Error for UpdateThemeError {}
+impl ServiceErrorCode for UpdateThemeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateThemeError::AccessDenied(_) => "AccessDeniedException",
+            UpdateThemeError::InternalFailure(_) => "InternalFailureException",
+            UpdateThemeError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateThemeError::LimitExceeded(_) => "LimitExceededException",
+            UpdateThemeError::ResourceExists(_) => "ResourceExistsException",
+            UpdateThemeError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateThemeError::Throttling(_) => "ThrottlingException",
+            UpdateThemeError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by UpdateThemeAlias
 #[derive(Debug, PartialEq)]
 pub enum UpdateThemeAliasError {
@@ -13041,6 +14359,19 @@ impl fmt::Display for UpdateThemeAliasError {
     }
 }
 impl Error for UpdateThemeAliasError {}
+impl ServiceErrorCode for UpdateThemeAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateThemeAliasError::Conflict(_) => "ConflictException",
+            UpdateThemeAliasError::InternalFailure(_) => "InternalFailureException",
+            UpdateThemeAliasError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateThemeAliasError::ResourceExists(_) => "ResourceExistsException",
+            UpdateThemeAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateThemeAliasError::Throttling(_) => "ThrottlingException",
+            UpdateThemeAliasError::UnsupportedUserEdition(_) => "UnsupportedUserEditionException",
+        }
+    }
+}
 /// Errors returned by UpdateThemePermissions
 #[derive(Debug, PartialEq)]
 pub enum UpdateThemePermissionsError {
@@ -13111,6 +14442,22 @@ impl fmt::Display for UpdateThemePermissionsError {
     }
 }
 impl Error for UpdateThemePermissionsError {}
+impl ServiceErrorCode for UpdateThemePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateThemePermissionsError::AccessDenied(_) => "AccessDeniedException",
+            UpdateThemePermissionsError::InternalFailure(_) => "InternalFailureException",
+            UpdateThemePermissionsError::InvalidParameterValue(_) => {
+                "InvalidParameterValueException"
+            }
+            UpdateThemePermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateThemePermissionsError::Throttling(_) => "ThrottlingException",
+            UpdateThemePermissionsError::UnsupportedUserEdition(_) => {
+                "UnsupportedUserEditionException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateUser
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserError {
@@ -13177,6 +14524,19 @@ impl fmt::Display for UpdateUserError {
     }
 }
 impl Error for UpdateUserError {}
+impl ServiceErrorCode for UpdateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserError::AccessDenied(_) => "AccessDeniedException",
+            UpdateUserError::InternalFailure(_) => "InternalFailureException",
+            UpdateUserError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateUserError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            UpdateUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateUserError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            UpdateUserError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon QuickSight API. Amazon QuickSight clients implement this trait.
 #[async_trait]
 pub trait Quicksight {
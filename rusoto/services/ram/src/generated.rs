@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1177,6 +1177,38 @@ impl fmt::Display for AcceptResourceShareInvitationError {
     }
 }
 impl Error for AcceptResourceShareInvitationError {}
+impl ServiceErrorCode for AcceptResourceShareInvitationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptResourceShareInvitationError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            AcceptResourceShareInvitationError::InvalidClientToken(_) => {
+                "InvalidClientTokenException"
+            }
+            AcceptResourceShareInvitationError::MalformedArn(_) => "MalformedArnException",
+            AcceptResourceShareInvitationError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            AcceptResourceShareInvitationError::ResourceShareInvitationAlreadyAccepted(_) => {
+                "ResourceShareInvitationAlreadyAcceptedException"
+            }
+            AcceptResourceShareInvitationError::ResourceShareInvitationAlreadyRejected(_) => {
+                "ResourceShareInvitationAlreadyRejectedException"
+            }
+            AcceptResourceShareInvitationError::ResourceShareInvitationArnNotFound(_) => {
+                "ResourceShareInvitationArnNotFoundException"
+            }
+            AcceptResourceShareInvitationError::ResourceShareInvitationExpired(_) => {
+                "ResourceShareInvitationExpiredException"
+            }
+            AcceptResourceShareInvitationError::ServerInternal(_) => "ServerInternalException",
+            AcceptResourceShareInvitationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by AssociateResourceShare
 #[derive(Debug, PartialEq)]
 pub enum AssociateResourceShareError {
@@ -1285,6 +1317,30 @@ impl fmt::Display for AssociateResourceShareError {
     }
 }
 impl Error for AssociateResourceShareError {}
+impl ServiceErrorCode for AssociateResourceShareError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateResourceShareError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            AssociateResourceShareError::InvalidClientToken(_) => "InvalidClientTokenException",
+            AssociateResourceShareError::InvalidParameter(_) => "InvalidParameterException",
+            AssociateResourceShareError::InvalidStateTransition(_) => {
+                "InvalidStateTransitionException"
+            }
+            AssociateResourceShareError::MalformedArn(_) => "MalformedArnException",
+            AssociateResourceShareError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            AssociateResourceShareError::ResourceShareLimitExceeded(_) => {
+                "ResourceShareLimitExceededException"
+            }
+            AssociateResourceShareError::ServerInternal(_) => "ServerInternalException",
+            AssociateResourceShareError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            AssociateResourceShareError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by AssociateResourceSharePermission
 #[derive(Debug, PartialEq)]
 pub enum AssociateResourceSharePermissionError {
@@ -1381,6 +1437,27 @@ impl fmt::Display for AssociateResourceSharePermissionError {
     }
 }
 impl Error for AssociateResourceSharePermissionError {}
+impl ServiceErrorCode for AssociateResourceSharePermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateResourceSharePermissionError::InvalidClientToken(_) => {
+                "InvalidClientTokenException"
+            }
+            AssociateResourceSharePermissionError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            AssociateResourceSharePermissionError::MalformedArn(_) => "MalformedArnException",
+            AssociateResourceSharePermissionError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            AssociateResourceSharePermissionError::ServerInternal(_) => "ServerInternalException",
+            AssociateResourceSharePermissionError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            AssociateResourceSharePermissionError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by CreateResourceShare
 #[derive(Debug, PartialEq)]
 pub enum CreateResourceShareError {
@@ -1491,6 +1568,29 @@ impl fmt::Display for CreateResourceShareError {
     }
 }
 impl Error for CreateResourceShareError {}
+impl ServiceErrorCode for CreateResourceShareError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateResourceShareError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateResourceShareError::InvalidClientToken(_) => "InvalidClientTokenException",
+            CreateResourceShareError::InvalidParameter(_) => "InvalidParameterException",
+            CreateResourceShareError::InvalidStateTransition(_) => {
+                "InvalidStateTransitionException"
+            }
+            CreateResourceShareError::MalformedArn(_) => "MalformedArnException",
+            CreateResourceShareError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            CreateResourceShareError::ResourceShareLimitExceeded(_) => {
+                "ResourceShareLimitExceededException"
+            }
+            CreateResourceShareError::ServerInternal(_) => "ServerInternalException",
+            CreateResourceShareError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateResourceShareError::TagPolicyViolation(_) => "TagPolicyViolationException",
+            CreateResourceShareError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by DeleteResourceShare
 #[derive(Debug, PartialEq)]
 pub enum DeleteResourceShareError {
@@ -1583,6 +1683,25 @@ impl fmt::Display for DeleteResourceShareError {
     }
 }
 impl Error for DeleteResourceShareError {}
+impl ServiceErrorCode for DeleteResourceShareError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResourceShareError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            DeleteResourceShareError::InvalidClientToken(_) => "InvalidClientTokenException",
+            DeleteResourceShareError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteResourceShareError::InvalidStateTransition(_) => {
+                "InvalidStateTransitionException"
+            }
+            DeleteResourceShareError::MalformedArn(_) => "MalformedArnException",
+            DeleteResourceShareError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            DeleteResourceShareError::ServerInternal(_) => "ServerInternalException",
+            DeleteResourceShareError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteResourceShareError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by DisassociateResourceShare
 #[derive(Debug, PartialEq)]
 pub enum DisassociateResourceShareError {
@@ -1695,6 +1814,30 @@ impl fmt::Display for DisassociateResourceShareError {
     }
 }
 impl Error for DisassociateResourceShareError {}
+impl ServiceErrorCode for DisassociateResourceShareError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateResourceShareError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            DisassociateResourceShareError::InvalidClientToken(_) => "InvalidClientTokenException",
+            DisassociateResourceShareError::InvalidParameter(_) => "InvalidParameterException",
+            DisassociateResourceShareError::InvalidStateTransition(_) => {
+                "InvalidStateTransitionException"
+            }
+            DisassociateResourceShareError::MalformedArn(_) => "MalformedArnException",
+            DisassociateResourceShareError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            DisassociateResourceShareError::ResourceShareLimitExceeded(_) => {
+                "ResourceShareLimitExceededException"
+            }
+            DisassociateResourceShareError::ServerInternal(_) => "ServerInternalException",
+            DisassociateResourceShareError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DisassociateResourceShareError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by DisassociateResourceSharePermission
 #[derive(Debug, PartialEq)]
 pub enum DisassociateResourceSharePermissionError {
@@ -1791,6 +1934,31 @@ impl fmt::Display for DisassociateResourceSharePermissionError {
     }
 }
 impl Error for DisassociateResourceSharePermissionError {}
+impl ServiceErrorCode for DisassociateResourceSharePermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateResourceSharePermissionError::InvalidClientToken(_) => {
+                "InvalidClientTokenException"
+            }
+            DisassociateResourceSharePermissionError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            DisassociateResourceSharePermissionError::MalformedArn(_) => "MalformedArnException",
+            DisassociateResourceSharePermissionError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            DisassociateResourceSharePermissionError::ServerInternal(_) => {
+                "ServerInternalException"
+            }
+            DisassociateResourceSharePermissionError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DisassociateResourceSharePermissionError::UnknownResource(_) => {
+                "UnknownResourceException"
+            }
+        }
+    }
+}
 /// Errors returned by EnableSharingWithAwsOrganization
 #[derive(Debug, PartialEq)]
 pub enum EnableSharingWithAwsOrganizationError {
@@ -1847,6 +2015,19 @@ impl fmt::Display for EnableSharingWithAwsOrganizationError {
     }
 }
 impl Error for EnableSharingWithAwsOrganizationError {}
+impl ServiceErrorCode for EnableSharingWithAwsOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableSharingWithAwsOrganizationError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            EnableSharingWithAwsOrganizationError::ServerInternal(_) => "ServerInternalException",
+            EnableSharingWithAwsOrganizationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by GetPermission
 #[derive(Debug, PartialEq)]
 pub enum GetPermissionError {
@@ -1907,6 +2088,18 @@ impl fmt::Display for GetPermissionError {
     }
 }
 impl Error for GetPermissionError {}
+impl ServiceErrorCode for GetPermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPermissionError::InvalidParameter(_) => "InvalidParameterException",
+            GetPermissionError::MalformedArn(_) => "MalformedArnException",
+            GetPermissionError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            GetPermissionError::ServerInternal(_) => "ServerInternalException",
+            GetPermissionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetPermissionError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by GetResourcePolicies
 #[derive(Debug, PartialEq)]
 pub enum GetResourcePoliciesError {
@@ -1975,6 +2168,18 @@ impl fmt::Display for GetResourcePoliciesError {
     }
 }
 impl Error for GetResourcePoliciesError {}
+impl ServiceErrorCode for GetResourcePoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourcePoliciesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetResourcePoliciesError::InvalidParameter(_) => "InvalidParameterException",
+            GetResourcePoliciesError::MalformedArn(_) => "MalformedArnException",
+            GetResourcePoliciesError::ResourceArnNotFound(_) => "ResourceArnNotFoundException",
+            GetResourcePoliciesError::ServerInternal(_) => "ServerInternalException",
+            GetResourcePoliciesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by GetResourceShareAssociations
 #[derive(Debug, PartialEq)]
 pub enum GetResourceShareAssociationsError {
@@ -2065,6 +2270,23 @@ impl fmt::Display for GetResourceShareAssociationsError {
     }
 }
 impl Error for GetResourceShareAssociationsError {}
+impl ServiceErrorCode for GetResourceShareAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourceShareAssociationsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetResourceShareAssociationsError::InvalidParameter(_) => "InvalidParameterException",
+            GetResourceShareAssociationsError::MalformedArn(_) => "MalformedArnException",
+            GetResourceShareAssociationsError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            GetResourceShareAssociationsError::ServerInternal(_) => "ServerInternalException",
+            GetResourceShareAssociationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetResourceShareAssociationsError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by GetResourceShareInvitations
 #[derive(Debug, PartialEq)]
 pub enum GetResourceShareInvitationsError {
@@ -2163,6 +2385,24 @@ impl fmt::Display for GetResourceShareInvitationsError {
     }
 }
 impl Error for GetResourceShareInvitationsError {}
+impl ServiceErrorCode for GetResourceShareInvitationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourceShareInvitationsError::InvalidMaxResults(_) => "InvalidMaxResultsException",
+            GetResourceShareInvitationsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetResourceShareInvitationsError::InvalidParameter(_) => "InvalidParameterException",
+            GetResourceShareInvitationsError::MalformedArn(_) => "MalformedArnException",
+            GetResourceShareInvitationsError::ResourceShareInvitationArnNotFound(_) => {
+                "ResourceShareInvitationArnNotFoundException"
+            }
+            GetResourceShareInvitationsError::ServerInternal(_) => "ServerInternalException",
+            GetResourceShareInvitationsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            GetResourceShareInvitationsError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by GetResourceShares
 #[derive(Debug, PartialEq)]
 pub enum GetResourceSharesError {
@@ -2225,6 +2465,18 @@ impl fmt::Display for GetResourceSharesError {
     }
 }
 impl Error for GetResourceSharesError {}
+impl ServiceErrorCode for GetResourceSharesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourceSharesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            GetResourceSharesError::InvalidParameter(_) => "InvalidParameterException",
+            GetResourceSharesError::MalformedArn(_) => "MalformedArnException",
+            GetResourceSharesError::ServerInternal(_) => "ServerInternalException",
+            GetResourceSharesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetResourceSharesError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by ListPendingInvitationResources
 #[derive(Debug, PartialEq)]
 pub enum ListPendingInvitationResourcesError {
@@ -2345,6 +2597,31 @@ impl fmt::Display for ListPendingInvitationResourcesError {
     }
 }
 impl Error for ListPendingInvitationResourcesError {}
+impl ServiceErrorCode for ListPendingInvitationResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPendingInvitationResourcesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListPendingInvitationResourcesError::InvalidParameter(_) => "InvalidParameterException",
+            ListPendingInvitationResourcesError::MalformedArn(_) => "MalformedArnException",
+            ListPendingInvitationResourcesError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            ListPendingInvitationResourcesError::ResourceShareInvitationAlreadyRejected(_) => {
+                "ResourceShareInvitationAlreadyRejectedException"
+            }
+            ListPendingInvitationResourcesError::ResourceShareInvitationArnNotFound(_) => {
+                "ResourceShareInvitationArnNotFoundException"
+            }
+            ListPendingInvitationResourcesError::ResourceShareInvitationExpired(_) => {
+                "ResourceShareInvitationExpiredException"
+            }
+            ListPendingInvitationResourcesError::ServerInternal(_) => "ServerInternalException",
+            ListPendingInvitationResourcesError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by ListPermissions
 #[derive(Debug, PartialEq)]
 pub enum ListPermissionsError {
@@ -2401,6 +2678,17 @@ impl fmt::Display for ListPermissionsError {
     }
 }
 impl Error for ListPermissionsError {}
+impl ServiceErrorCode for ListPermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPermissionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListPermissionsError::InvalidParameter(_) => "InvalidParameterException",
+            ListPermissionsError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            ListPermissionsError::ServerInternal(_) => "ServerInternalException",
+            ListPermissionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListPrincipals
 #[derive(Debug, PartialEq)]
 pub enum ListPrincipalsError {
@@ -2461,6 +2749,18 @@ impl fmt::Display for ListPrincipalsError {
     }
 }
 impl Error for ListPrincipalsError {}
+impl ServiceErrorCode for ListPrincipalsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPrincipalsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListPrincipalsError::InvalidParameter(_) => "InvalidParameterException",
+            ListPrincipalsError::MalformedArn(_) => "MalformedArnException",
+            ListPrincipalsError::ServerInternal(_) => "ServerInternalException",
+            ListPrincipalsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListPrincipalsError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by ListResourceSharePermissions
 #[derive(Debug, PartialEq)]
 pub enum ListResourceSharePermissionsError {
@@ -2551,6 +2851,23 @@ impl fmt::Display for ListResourceSharePermissionsError {
     }
 }
 impl Error for ListResourceSharePermissionsError {}
+impl ServiceErrorCode for ListResourceSharePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourceSharePermissionsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListResourceSharePermissionsError::InvalidParameter(_) => "InvalidParameterException",
+            ListResourceSharePermissionsError::MalformedArn(_) => "MalformedArnException",
+            ListResourceSharePermissionsError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            ListResourceSharePermissionsError::ServerInternal(_) => "ServerInternalException",
+            ListResourceSharePermissionsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            ListResourceSharePermissionsError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by ListResourceTypes
 #[derive(Debug, PartialEq)]
 pub enum ListResourceTypesError {
@@ -2601,6 +2918,16 @@ impl fmt::Display for ListResourceTypesError {
     }
 }
 impl Error for ListResourceTypesError {}
+impl ServiceErrorCode for ListResourceTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourceTypesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListResourceTypesError::InvalidParameter(_) => "InvalidParameterException",
+            ListResourceTypesError::ServerInternal(_) => "ServerInternalException",
+            ListResourceTypesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListResources
 #[derive(Debug, PartialEq)]
 pub enum ListResourcesError {
@@ -2667,6 +2994,19 @@ impl fmt::Display for ListResourcesError {
     }
 }
 impl Error for ListResourcesError {}
+impl ServiceErrorCode for ListResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourcesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListResourcesError::InvalidParameter(_) => "InvalidParameterException",
+            ListResourcesError::InvalidResourceType(_) => "InvalidResourceTypeException",
+            ListResourcesError::MalformedArn(_) => "MalformedArnException",
+            ListResourcesError::ServerInternal(_) => "ServerInternalException",
+            ListResourcesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListResourcesError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by PromoteResourceShareCreatedFromPolicy
 #[derive(Debug, PartialEq)]
 pub enum PromoteResourceShareCreatedFromPolicyError {
@@ -2765,6 +3105,31 @@ impl fmt::Display for PromoteResourceShareCreatedFromPolicyError {
     }
 }
 impl Error for PromoteResourceShareCreatedFromPolicyError {}
+impl ServiceErrorCode for PromoteResourceShareCreatedFromPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PromoteResourceShareCreatedFromPolicyError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            PromoteResourceShareCreatedFromPolicyError::MalformedArn(_) => "MalformedArnException",
+            PromoteResourceShareCreatedFromPolicyError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            PromoteResourceShareCreatedFromPolicyError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            PromoteResourceShareCreatedFromPolicyError::ServerInternal(_) => {
+                "ServerInternalException"
+            }
+            PromoteResourceShareCreatedFromPolicyError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            PromoteResourceShareCreatedFromPolicyError::UnknownResource(_) => {
+                "UnknownResourceException"
+            }
+        }
+    }
+}
 /// Errors returned by RejectResourceShareInvitation
 #[derive(Debug, PartialEq)]
 pub enum RejectResourceShareInvitationError {
@@ -2893,6 +3258,38 @@ impl fmt::Display for RejectResourceShareInvitationError {
     }
 }
 impl Error for RejectResourceShareInvitationError {}
+impl ServiceErrorCode for RejectResourceShareInvitationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RejectResourceShareInvitationError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            RejectResourceShareInvitationError::InvalidClientToken(_) => {
+                "InvalidClientTokenException"
+            }
+            RejectResourceShareInvitationError::MalformedArn(_) => "MalformedArnException",
+            RejectResourceShareInvitationError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            RejectResourceShareInvitationError::ResourceShareInvitationAlreadyAccepted(_) => {
+                "ResourceShareInvitationAlreadyAcceptedException"
+            }
+            RejectResourceShareInvitationError::ResourceShareInvitationAlreadyRejected(_) => {
+                "ResourceShareInvitationAlreadyRejectedException"
+            }
+            RejectResourceShareInvitationError::ResourceShareInvitationArnNotFound(_) => {
+                "ResourceShareInvitationArnNotFoundException"
+            }
+            RejectResourceShareInvitationError::ResourceShareInvitationExpired(_) => {
+                "ResourceShareInvitationExpiredException"
+            }
+            RejectResourceShareInvitationError::ServerInternal(_) => "ServerInternalException",
+            RejectResourceShareInvitationError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2959,6 +3356,19 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            TagResourceError::MalformedArn(_) => "MalformedArnException",
+            TagResourceError::ResourceArnNotFound(_) => "ResourceArnNotFoundException",
+            TagResourceError::ServerInternal(_) => "ServerInternalException",
+            TagResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            TagResourceError::TagLimitExceeded(_) => "TagLimitExceededException",
+            TagResourceError::TagPolicyViolation(_) => "TagPolicyViolationException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3001,6 +3411,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            UntagResourceError::ServerInternal(_) => "ServerInternalException",
+            UntagResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by UpdateResourceShare
 #[derive(Debug, PartialEq)]
 pub enum UpdateResourceShareError {
@@ -3093,6 +3512,25 @@ impl fmt::Display for UpdateResourceShareError {
     }
 }
 impl Error for UpdateResourceShareError {}
+impl ServiceErrorCode for UpdateResourceShareError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateResourceShareError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            UpdateResourceShareError::InvalidClientToken(_) => "InvalidClientTokenException",
+            UpdateResourceShareError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateResourceShareError::MalformedArn(_) => "MalformedArnException",
+            UpdateResourceShareError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            UpdateResourceShareError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            UpdateResourceShareError::ServerInternal(_) => "ServerInternalException",
+            UpdateResourceShareError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateResourceShareError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Trait representing the capabilities of the RAM API. RAM clients implement this trait.
 #[async_trait]
 pub trait Ram {
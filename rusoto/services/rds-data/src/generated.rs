@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -581,6 +581,17 @@ impl fmt::Display for BatchExecuteStatementError {
     }
 }
 impl Error for BatchExecuteStatementError {}
+impl ServiceErrorCode for BatchExecuteStatementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchExecuteStatementError::BadRequest(_) => "BadRequestException",
+            BatchExecuteStatementError::Forbidden(_) => "ForbiddenException",
+            BatchExecuteStatementError::InternalServerError(_) => "InternalServerErrorException",
+            BatchExecuteStatementError::ServiceUnavailableError(_) => "ServiceUnavailableError",
+            BatchExecuteStatementError::StatementTimeout(_) => "StatementTimeoutException",
+        }
+    }
+}
 /// Errors returned by BeginTransaction
 #[derive(Debug, PartialEq)]
 pub enum BeginTransactionError {
@@ -639,6 +650,17 @@ impl fmt::Display for BeginTransactionError {
     }
 }
 impl Error for BeginTransactionError {}
+impl ServiceErrorCode for BeginTransactionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BeginTransactionError::BadRequest(_) => "BadRequestException",
+            BeginTransactionError::Forbidden(_) => "ForbiddenException",
+            BeginTransactionError::InternalServerError(_) => "InternalServerErrorException",
+            BeginTransactionError::ServiceUnavailableError(_) => "ServiceUnavailableError",
+            BeginTransactionError::StatementTimeout(_) => "StatementTimeoutException",
+        }
+    }
+}
 /// Errors returned by CommitTransaction
 #[derive(Debug, PartialEq)]
 pub enum CommitTransactionError {
@@ -703,6 +725,18 @@ impl fmt::Display for CommitTransactionError {
     }
 }
 impl Error for CommitTransactionError {}
+impl ServiceErrorCode for CommitTransactionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CommitTransactionError::BadRequest(_) => "BadRequestException",
+            CommitTransactionError::Forbidden(_) => "ForbiddenException",
+            CommitTransactionError::InternalServerError(_) => "InternalServerErrorException",
+            CommitTransactionError::NotFound(_) => "NotFoundException",
+            CommitTransactionError::ServiceUnavailableError(_) => "ServiceUnavailableError",
+            CommitTransactionError::StatementTimeout(_) => "StatementTimeoutException",
+        }
+    }
+}
 /// Errors returned by ExecuteSql
 #[derive(Debug, PartialEq)]
 pub enum ExecuteSqlError {
@@ -751,6 +785,16 @@ impl fmt::Display for ExecuteSqlError {
     }
 }
 impl Error for ExecuteSqlError {}
+impl ServiceErrorCode for ExecuteSqlError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExecuteSqlError::BadRequest(_) => "BadRequestException",
+            ExecuteSqlError::Forbidden(_) => "ForbiddenException",
+            ExecuteSqlError::InternalServerError(_) => "InternalServerErrorException",
+            ExecuteSqlError::ServiceUnavailableError(_) => "ServiceUnavailableError",
+        }
+    }
+}
 /// Errors returned by ExecuteStatement
 #[derive(Debug, PartialEq)]
 pub enum ExecuteStatementError {
@@ -809,6 +853,17 @@ impl fmt::Display for ExecuteStatementError {
     }
 }
 impl Error for ExecuteStatementError {}
+impl ServiceErrorCode for ExecuteStatementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExecuteStatementError::BadRequest(_) => "BadRequestException",
+            ExecuteStatementError::Forbidden(_) => "ForbiddenException",
+            ExecuteStatementError::InternalServerError(_) => "InternalServerErrorException",
+            ExecuteStatementError::ServiceUnavailableError(_) => "ServiceUnavailableError",
+            ExecuteStatementError::StatementTimeout(_) => "StatementTimeoutException",
+        }
+    }
+}
 /// Errors returned by RollbackTransaction
 #[derive(Debug, PartialEq)]
 pub enum RollbackTransactionError {
@@ -875,6 +930,18 @@ impl fmt::Display for RollbackTransactionError {
     }
 }
 impl Error for RollbackTransactionError {}
+impl ServiceErrorCode for RollbackTransactionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RollbackTransactionError::BadRequest(_) => "BadRequestException",
+            RollbackTransactionError::Forbidden(_) => "ForbiddenException",
+            RollbackTransactionError::InternalServerError(_) => "InternalServerErrorException",
+            RollbackTransactionError::NotFound(_) => "NotFoundException",
+            RollbackTransactionError::ServiceUnavailableError(_) => "ServiceUnavailableError",
+            RollbackTransactionError::StatementTimeout(_) => "StatementTimeoutException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS RDS DataService API. AWS RDS DataService clients implement this trait.
 #[async_trait]
 pub trait RdsData {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -19598,6 +19598,20 @@ impl fmt::Display for AddRoleToDBClusterError {
     }
 }
 impl Error for AddRoleToDBClusterError {}
+impl ServiceErrorCode for AddRoleToDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddRoleToDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            AddRoleToDBClusterError::DBClusterRoleAlreadyExistsFault(_) => {
+                "DBClusterRoleAlreadyExists"
+            }
+            AddRoleToDBClusterError::DBClusterRoleQuotaExceededFault(_) => {
+                "DBClusterRoleQuotaExceeded"
+            }
+            AddRoleToDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+        }
+    }
+}
 /// Errors returned by AddRoleToDBInstance
 #[derive(Debug, PartialEq)]
 pub enum AddRoleToDBInstanceError {
@@ -19678,6 +19692,20 @@ impl fmt::Display for AddRoleToDBInstanceError {
     }
 }
 impl Error for AddRoleToDBInstanceError {}
+impl ServiceErrorCode for AddRoleToDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddRoleToDBInstanceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            AddRoleToDBInstanceError::DBInstanceRoleAlreadyExistsFault(_) => {
+                "DBInstanceRoleAlreadyExists"
+            }
+            AddRoleToDBInstanceError::DBInstanceRoleQuotaExceededFault(_) => {
+                "DBInstanceRoleQuotaExceeded"
+            }
+            AddRoleToDBInstanceError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+        }
+    }
+}
 /// Errors returned by AddSourceIdentifierToSubscription
 #[derive(Debug, PartialEq)]
 pub enum AddSourceIdentifierToSubscriptionError {
@@ -19740,6 +19768,16 @@ impl fmt::Display for AddSourceIdentifierToSubscriptionError {
     }
 }
 impl Error for AddSourceIdentifierToSubscriptionError {}
+impl ServiceErrorCode for AddSourceIdentifierToSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddSourceIdentifierToSubscriptionError::SourceNotFoundFault(_) => "SourceNotFound",
+            AddSourceIdentifierToSubscriptionError::SubscriptionNotFoundFault(_) => {
+                "SubscriptionNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by AddTagsToResource
 #[derive(Debug, PartialEq)]
 pub enum AddTagsToResourceError {
@@ -19820,6 +19858,19 @@ impl fmt::Display for AddTagsToResourceError {
     }
 }
 impl Error for AddTagsToResourceError {}
+impl ServiceErrorCode for AddTagsToResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsToResourceError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            AddTagsToResourceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            AddTagsToResourceError::DBProxyNotFoundFault(_) => "DBProxyNotFoundFault",
+            AddTagsToResourceError::DBProxyTargetGroupNotFoundFault(_) => {
+                "DBProxyTargetGroupNotFoundFault"
+            }
+            AddTagsToResourceError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+        }
+    }
+}
 /// Errors returned by ApplyPendingMaintenanceAction
 #[derive(Debug, PartialEq)]
 pub enum ApplyPendingMaintenanceActionError {
@@ -19894,6 +19945,19 @@ impl fmt::Display for ApplyPendingMaintenanceActionError {
     }
 }
 impl Error for ApplyPendingMaintenanceActionError {}
+impl ServiceErrorCode for ApplyPendingMaintenanceActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ApplyPendingMaintenanceActionError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            ApplyPendingMaintenanceActionError::InvalidDBInstanceStateFault(_) => {
+                "InvalidDBInstanceState"
+            }
+            ApplyPendingMaintenanceActionError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by AuthorizeDBSecurityGroupIngress
 #[derive(Debug, PartialEq)]
 pub enum AuthorizeDBSecurityGroupIngressError {
@@ -19980,6 +20044,24 @@ impl fmt::Display for AuthorizeDBSecurityGroupIngressError {
     }
 }
 impl Error for AuthorizeDBSecurityGroupIngressError {}
+impl ServiceErrorCode for AuthorizeDBSecurityGroupIngressError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AuthorizeDBSecurityGroupIngressError::AuthorizationAlreadyExistsFault(_) => {
+                "AuthorizationAlreadyExists"
+            }
+            AuthorizeDBSecurityGroupIngressError::AuthorizationQuotaExceededFault(_) => {
+                "AuthorizationQuotaExceeded"
+            }
+            AuthorizeDBSecurityGroupIngressError::DBSecurityGroupNotFoundFault(_) => {
+                "DBSecurityGroupNotFound"
+            }
+            AuthorizeDBSecurityGroupIngressError::InvalidDBSecurityGroupStateFault(_) => {
+                "InvalidDBSecurityGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by BacktrackDBCluster
 #[derive(Debug, PartialEq)]
 pub enum BacktrackDBClusterError {
@@ -20036,6 +20118,14 @@ impl fmt::Display for BacktrackDBClusterError {
     }
 }
 impl Error for BacktrackDBClusterError {}
+impl ServiceErrorCode for BacktrackDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BacktrackDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            BacktrackDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+        }
+    }
+}
 /// Errors returned by CancelExportTask
 #[derive(Debug, PartialEq)]
 pub enum CancelExportTaskError {
@@ -20090,6 +20180,14 @@ impl fmt::Display for CancelExportTaskError {
     }
 }
 impl Error for CancelExportTaskError {}
+impl ServiceErrorCode for CancelExportTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelExportTaskError::ExportTaskNotFoundFault(_) => "ExportTaskNotFound",
+            CancelExportTaskError::InvalidExportTaskStateFault(_) => "InvalidExportTaskStateFault",
+        }
+    }
+}
 /// Errors returned by CopyDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum CopyDBClusterParameterGroupError {
@@ -20164,6 +20262,21 @@ impl fmt::Display for CopyDBClusterParameterGroupError {
     }
 }
 impl Error for CopyDBClusterParameterGroupError {}
+impl ServiceErrorCode for CopyDBClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(_) => {
+                "DBParameterGroupAlreadyExists"
+            }
+            CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(_) => {
+                "DBParameterGroupQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CopyDBClusterSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CopyDBClusterSnapshotError {
@@ -20272,6 +20385,26 @@ impl fmt::Display for CopyDBClusterSnapshotError {
     }
 }
 impl Error for CopyDBClusterSnapshotError {}
+impl ServiceErrorCode for CopyDBClusterSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(_) => {
+                "DBClusterSnapshotAlreadyExistsFault"
+            }
+            CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            CopyDBClusterSnapshotError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by CopyDBParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum CopyDBParameterGroupError {
@@ -20344,6 +20477,21 @@ impl fmt::Display for CopyDBParameterGroupError {
     }
 }
 impl Error for CopyDBParameterGroupError {}
+impl ServiceErrorCode for CopyDBParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyDBParameterGroupError::DBParameterGroupAlreadyExistsFault(_) => {
+                "DBParameterGroupAlreadyExists"
+            }
+            CopyDBParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            CopyDBParameterGroupError::DBParameterGroupQuotaExceededFault(_) => {
+                "DBParameterGroupQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CopyDBSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CopyDBSnapshotError {
@@ -20432,6 +20580,20 @@ impl fmt::Display for CopyDBSnapshotError {
     }
 }
 impl Error for CopyDBSnapshotError {}
+impl ServiceErrorCode for CopyDBSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyDBSnapshotError::CustomAvailabilityZoneNotFoundFault(_) => {
+                "CustomAvailabilityZoneNotFound"
+            }
+            CopyDBSnapshotError::DBSnapshotAlreadyExistsFault(_) => "DBSnapshotAlreadyExists",
+            CopyDBSnapshotError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+            CopyDBSnapshotError::InvalidDBSnapshotStateFault(_) => "InvalidDBSnapshotState",
+            CopyDBSnapshotError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            CopyDBSnapshotError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by CopyOptionGroup
 #[derive(Debug, PartialEq)]
 pub enum CopyOptionGroupError {
@@ -20500,6 +20662,19 @@ impl fmt::Display for CopyOptionGroupError {
     }
 }
 impl Error for CopyOptionGroupError {}
+impl ServiceErrorCode for CopyOptionGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyOptionGroupError::OptionGroupAlreadyExistsFault(_) => {
+                "OptionGroupAlreadyExistsFault"
+            }
+            CopyOptionGroupError::OptionGroupNotFoundFault(_) => "OptionGroupNotFoundFault",
+            CopyOptionGroupError::OptionGroupQuotaExceededFault(_) => {
+                "OptionGroupQuotaExceededFault"
+            }
+        }
+    }
+}
 /// Errors returned by CreateCustomAvailabilityZone
 #[derive(Debug, PartialEq)]
 pub enum CreateCustomAvailabilityZoneError {
@@ -20570,6 +20745,21 @@ impl fmt::Display for CreateCustomAvailabilityZoneError {
     }
 }
 impl Error for CreateCustomAvailabilityZoneError {}
+impl ServiceErrorCode for CreateCustomAvailabilityZoneError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCustomAvailabilityZoneError::CustomAvailabilityZoneAlreadyExistsFault(_) => {
+                "CustomAvailabilityZoneAlreadyExists"
+            }
+            CreateCustomAvailabilityZoneError::CustomAvailabilityZoneQuotaExceededFault(_) => {
+                "CustomAvailabilityZoneQuotaExceeded"
+            }
+            CreateCustomAvailabilityZoneError::KMSKeyNotAccessibleFault(_) => {
+                "KMSKeyNotAccessibleFault"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDBCluster
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterError {
@@ -20770,6 +20960,40 @@ impl fmt::Display for CreateDBClusterError {
     }
 }
 impl Error for CreateDBClusterError {}
+impl ServiceErrorCode for CreateDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBClusterError::DBClusterAlreadyExistsFault(_) => "DBClusterAlreadyExistsFault",
+            CreateDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            CreateDBClusterError::DBClusterParameterGroupNotFoundFault(_) => {
+                "DBClusterParameterGroupNotFound"
+            }
+            CreateDBClusterError::DBClusterQuotaExceededFault(_) => "DBClusterQuotaExceededFault",
+            CreateDBClusterError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            CreateDBClusterError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            CreateDBClusterError::DomainNotFoundFault(_) => "DomainNotFoundFault",
+            CreateDBClusterError::GlobalClusterNotFoundFault(_) => "GlobalClusterNotFoundFault",
+            CreateDBClusterError::InsufficientStorageClusterCapacityFault(_) => {
+                "InsufficientStorageClusterCapacity"
+            }
+            CreateDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            CreateDBClusterError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            CreateDBClusterError::InvalidDBSubnetGroupStateFault(_) => {
+                "InvalidDBSubnetGroupStateFault"
+            }
+            CreateDBClusterError::InvalidGlobalClusterStateFault(_) => {
+                "InvalidGlobalClusterStateFault"
+            }
+            CreateDBClusterError::InvalidSubnet(_) => "InvalidSubnet",
+            CreateDBClusterError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            CreateDBClusterError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            CreateDBClusterError::StorageQuotaExceededFault(_) => "StorageQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by CreateDBClusterEndpoint
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterEndpointError {
@@ -20878,6 +21102,26 @@ impl fmt::Display for CreateDBClusterEndpointError {
     }
 }
 impl Error for CreateDBClusterEndpointError {}
+impl ServiceErrorCode for CreateDBClusterEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBClusterEndpointError::DBClusterEndpointAlreadyExistsFault(_) => {
+                "DBClusterEndpointAlreadyExistsFault"
+            }
+            CreateDBClusterEndpointError::DBClusterEndpointQuotaExceededFault(_) => {
+                "DBClusterEndpointQuotaExceededFault"
+            }
+            CreateDBClusterEndpointError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            CreateDBClusterEndpointError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            CreateDBClusterEndpointError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            CreateDBClusterEndpointError::InvalidDBInstanceStateFault(_) => {
+                "InvalidDBInstanceState"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterParameterGroupError {
@@ -20940,6 +21184,18 @@ impl fmt::Display for CreateDBClusterParameterGroupError {
     }
 }
 impl Error for CreateDBClusterParameterGroupError {}
+impl ServiceErrorCode for CreateDBClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(_) => {
+                "DBParameterGroupAlreadyExists"
+            }
+            CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(_) => {
+                "DBParameterGroupQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDBClusterSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateDBClusterSnapshotError {
@@ -21036,6 +21292,23 @@ impl fmt::Display for CreateDBClusterSnapshotError {
     }
 }
 impl Error for CreateDBClusterSnapshotError {}
+impl ServiceErrorCode for CreateDBClusterSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBClusterSnapshotError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(_) => {
+                "DBClusterSnapshotAlreadyExistsFault"
+            }
+            CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            CreateDBClusterSnapshotError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by CreateDBInstance
 #[derive(Debug, PartialEq)]
 pub enum CreateDBInstanceError {
@@ -21254,6 +21527,37 @@ impl fmt::Display for CreateDBInstanceError {
     }
 }
 impl Error for CreateDBInstanceError {}
+impl ServiceErrorCode for CreateDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBInstanceError::AuthorizationNotFoundFault(_) => "AuthorizationNotFound",
+            CreateDBInstanceError::BackupPolicyNotFoundFault(_) => "BackupPolicyNotFoundFault",
+            CreateDBInstanceError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            CreateDBInstanceError::DBInstanceAlreadyExistsFault(_) => "DBInstanceAlreadyExists",
+            CreateDBInstanceError::DBParameterGroupNotFoundFault(_) => "DBParameterGroupNotFound",
+            CreateDBInstanceError::DBSecurityGroupNotFoundFault(_) => "DBSecurityGroupNotFound",
+            CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            CreateDBInstanceError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            CreateDBInstanceError::DomainNotFoundFault(_) => "DomainNotFoundFault",
+            CreateDBInstanceError::InstanceQuotaExceededFault(_) => "InstanceQuotaExceeded",
+            CreateDBInstanceError::InsufficientDBInstanceCapacityFault(_) => {
+                "InsufficientDBInstanceCapacity"
+            }
+            CreateDBInstanceError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            CreateDBInstanceError::InvalidSubnet(_) => "InvalidSubnet",
+            CreateDBInstanceError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            CreateDBInstanceError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            CreateDBInstanceError::OptionGroupNotFoundFault(_) => "OptionGroupNotFoundFault",
+            CreateDBInstanceError::ProvisionedIopsNotAvailableInAZFault(_) => {
+                "ProvisionedIopsNotAvailableInAZFault"
+            }
+            CreateDBInstanceError::StorageQuotaExceededFault(_) => "StorageQuotaExceeded",
+            CreateDBInstanceError::StorageTypeNotSupportedFault(_) => "StorageTypeNotSupported",
+        }
+    }
+}
 /// Errors returned by CreateDBInstanceReadReplica
 #[derive(Debug, PartialEq)]
 pub enum CreateDBInstanceReadReplicaError {
@@ -21516,6 +21820,63 @@ impl fmt::Display for CreateDBInstanceReadReplicaError {
     }
 }
 impl Error for CreateDBInstanceReadReplicaError {}
+impl ServiceErrorCode for CreateDBInstanceReadReplicaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBInstanceReadReplicaError::DBInstanceAlreadyExistsFault(_) => {
+                "DBInstanceAlreadyExists"
+            }
+            CreateDBInstanceReadReplicaError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            CreateDBInstanceReadReplicaError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            CreateDBInstanceReadReplicaError::DBSecurityGroupNotFoundFault(_) => {
+                "DBSecurityGroupNotFound"
+            }
+            CreateDBInstanceReadReplicaError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            CreateDBInstanceReadReplicaError::DBSubnetGroupNotAllowedFault(_) => {
+                "DBSubnetGroupNotAllowedFault"
+            }
+            CreateDBInstanceReadReplicaError::DBSubnetGroupNotFoundFault(_) => {
+                "DBSubnetGroupNotFoundFault"
+            }
+            CreateDBInstanceReadReplicaError::DomainNotFoundFault(_) => "DomainNotFoundFault",
+            CreateDBInstanceReadReplicaError::InstanceQuotaExceededFault(_) => {
+                "InstanceQuotaExceeded"
+            }
+            CreateDBInstanceReadReplicaError::InsufficientDBInstanceCapacityFault(_) => {
+                "InsufficientDBInstanceCapacity"
+            }
+            CreateDBInstanceReadReplicaError::InvalidDBInstanceStateFault(_) => {
+                "InvalidDBInstanceState"
+            }
+            CreateDBInstanceReadReplicaError::InvalidDBSubnetGroupFault(_) => {
+                "InvalidDBSubnetGroupFault"
+            }
+            CreateDBInstanceReadReplicaError::InvalidSubnet(_) => "InvalidSubnet",
+            CreateDBInstanceReadReplicaError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            CreateDBInstanceReadReplicaError::KMSKeyNotAccessibleFault(_) => {
+                "KMSKeyNotAccessibleFault"
+            }
+            CreateDBInstanceReadReplicaError::OptionGroupNotFoundFault(_) => {
+                "OptionGroupNotFoundFault"
+            }
+            CreateDBInstanceReadReplicaError::ProvisionedIopsNotAvailableInAZFault(_) => {
+                "ProvisionedIopsNotAvailableInAZFault"
+            }
+            CreateDBInstanceReadReplicaError::StorageQuotaExceededFault(_) => {
+                "StorageQuotaExceeded"
+            }
+            CreateDBInstanceReadReplicaError::StorageTypeNotSupportedFault(_) => {
+                "StorageTypeNotSupported"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDBParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateDBParameterGroupError {
@@ -21576,6 +21937,18 @@ impl fmt::Display for CreateDBParameterGroupError {
     }
 }
 impl Error for CreateDBParameterGroupError {}
+impl ServiceErrorCode for CreateDBParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBParameterGroupError::DBParameterGroupAlreadyExistsFault(_) => {
+                "DBParameterGroupAlreadyExists"
+            }
+            CreateDBParameterGroupError::DBParameterGroupQuotaExceededFault(_) => {
+                "DBParameterGroupQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDBProxy
 #[derive(Debug, PartialEq)]
 pub enum CreateDBProxyError {
@@ -21636,6 +22009,15 @@ impl fmt::Display for CreateDBProxyError {
     }
 }
 impl Error for CreateDBProxyError {}
+impl ServiceErrorCode for CreateDBProxyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBProxyError::DBProxyAlreadyExistsFault(_) => "DBProxyTargetExistsFault",
+            CreateDBProxyError::DBProxyQuotaExceededFault(_) => "DBProxyQuotaExceededFault",
+            CreateDBProxyError::InvalidSubnet(_) => "InvalidSubnet",
+        }
+    }
+}
 /// Errors returned by CreateDBSecurityGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateDBSecurityGroupError {
@@ -21708,6 +22090,21 @@ impl fmt::Display for CreateDBSecurityGroupError {
     }
 }
 impl Error for CreateDBSecurityGroupError {}
+impl ServiceErrorCode for CreateDBSecurityGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBSecurityGroupError::DBSecurityGroupAlreadyExistsFault(_) => {
+                "DBSecurityGroupAlreadyExists"
+            }
+            CreateDBSecurityGroupError::DBSecurityGroupNotSupportedFault(_) => {
+                "DBSecurityGroupNotSupported"
+            }
+            CreateDBSecurityGroupError::DBSecurityGroupQuotaExceededFault(_) => {
+                "QuotaExceeded.DBSecurityGroup"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDBSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateDBSnapshotError {
@@ -21782,6 +22179,16 @@ impl fmt::Display for CreateDBSnapshotError {
     }
 }
 impl Error for CreateDBSnapshotError {}
+impl ServiceErrorCode for CreateDBSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBSnapshotError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            CreateDBSnapshotError::DBSnapshotAlreadyExistsFault(_) => "DBSnapshotAlreadyExists",
+            CreateDBSnapshotError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            CreateDBSnapshotError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by CreateDBSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateDBSubnetGroupError {
@@ -21874,6 +22281,23 @@ impl fmt::Display for CreateDBSubnetGroupError {
     }
 }
 impl Error for CreateDBSubnetGroupError {}
+impl ServiceErrorCode for CreateDBSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(_) => {
+                "DBSubnetGroupAlreadyExists"
+            }
+            CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(_) => {
+                "DBSubnetGroupQuotaExceeded"
+            }
+            CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(_) => "DBSubnetQuotaExceededFault",
+            CreateDBSubnetGroupError::InvalidSubnet(_) => "InvalidSubnet",
+        }
+    }
+}
 /// Errors returned by CreateEventSubscription
 #[derive(Debug, PartialEq)]
 pub enum CreateEventSubscriptionError {
@@ -21988,6 +22412,25 @@ impl fmt::Display for CreateEventSubscriptionError {
     }
 }
 impl Error for CreateEventSubscriptionError {}
+impl ServiceErrorCode for CreateEventSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(_) => {
+                "EventSubscriptionQuotaExceeded"
+            }
+            CreateEventSubscriptionError::SNSInvalidTopicFault(_) => "SNSInvalidTopic",
+            CreateEventSubscriptionError::SNSNoAuthorizationFault(_) => "SNSNoAuthorization",
+            CreateEventSubscriptionError::SNSTopicArnNotFoundFault(_) => "SNSTopicArnNotFound",
+            CreateEventSubscriptionError::SourceNotFoundFault(_) => "SourceNotFound",
+            CreateEventSubscriptionError::SubscriptionAlreadyExistFault(_) => {
+                "SubscriptionAlreadyExist"
+            }
+            CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(_) => {
+                "SubscriptionCategoryNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by CreateGlobalCluster
 #[derive(Debug, PartialEq)]
 pub enum CreateGlobalClusterError {
@@ -22068,6 +22511,20 @@ impl fmt::Display for CreateGlobalClusterError {
     }
 }
 impl Error for CreateGlobalClusterError {}
+impl ServiceErrorCode for CreateGlobalClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGlobalClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            CreateGlobalClusterError::GlobalClusterAlreadyExistsFault(_) => {
+                "GlobalClusterAlreadyExistsFault"
+            }
+            CreateGlobalClusterError::GlobalClusterQuotaExceededFault(_) => {
+                "GlobalClusterQuotaExceededFault"
+            }
+            CreateGlobalClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+        }
+    }
+}
 /// Errors returned by CreateOptionGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateOptionGroupError {
@@ -22128,6 +22585,18 @@ impl fmt::Display for CreateOptionGroupError {
     }
 }
 impl Error for CreateOptionGroupError {}
+impl ServiceErrorCode for CreateOptionGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateOptionGroupError::OptionGroupAlreadyExistsFault(_) => {
+                "OptionGroupAlreadyExistsFault"
+            }
+            CreateOptionGroupError::OptionGroupQuotaExceededFault(_) => {
+                "OptionGroupQuotaExceededFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteCustomAvailabilityZone
 #[derive(Debug, PartialEq)]
 pub enum DeleteCustomAvailabilityZoneError {
@@ -22190,6 +22659,18 @@ impl fmt::Display for DeleteCustomAvailabilityZoneError {
     }
 }
 impl Error for DeleteCustomAvailabilityZoneError {}
+impl ServiceErrorCode for DeleteCustomAvailabilityZoneError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCustomAvailabilityZoneError::CustomAvailabilityZoneNotFoundFault(_) => {
+                "CustomAvailabilityZoneNotFound"
+            }
+            DeleteCustomAvailabilityZoneError::KMSKeyNotAccessibleFault(_) => {
+                "KMSKeyNotAccessibleFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDBCluster
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterError {
@@ -22274,6 +22755,21 @@ impl fmt::Display for DeleteDBClusterError {
     }
 }
 impl Error for DeleteDBClusterError {}
+impl ServiceErrorCode for DeleteDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(_) => {
+                "DBClusterSnapshotAlreadyExistsFault"
+            }
+            DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            DeleteDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            DeleteDBClusterError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by DeleteDBClusterEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterEndpointError {
@@ -22346,6 +22842,21 @@ impl fmt::Display for DeleteDBClusterEndpointError {
     }
 }
 impl Error for DeleteDBClusterEndpointError {}
+impl ServiceErrorCode for DeleteDBClusterEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBClusterEndpointError::DBClusterEndpointNotFoundFault(_) => {
+                "DBClusterEndpointNotFoundFault"
+            }
+            DeleteDBClusterEndpointError::InvalidDBClusterEndpointStateFault(_) => {
+                "InvalidDBClusterEndpointStateFault"
+            }
+            DeleteDBClusterEndpointError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterParameterGroupError {
@@ -22408,6 +22919,18 @@ impl fmt::Display for DeleteDBClusterParameterGroupError {
     }
 }
 impl Error for DeleteDBClusterParameterGroupError {}
+impl ServiceErrorCode for DeleteDBClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(_) => {
+                "InvalidDBParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDBClusterSnapshot
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBClusterSnapshotError {
@@ -22468,6 +22991,18 @@ impl fmt::Display for DeleteDBClusterSnapshotError {
     }
 }
 impl Error for DeleteDBClusterSnapshotError {}
+impl ServiceErrorCode for DeleteDBClusterSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDBInstance
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBInstanceError {
@@ -22562,6 +23097,20 @@ impl fmt::Display for DeleteDBInstanceError {
     }
 }
 impl Error for DeleteDBInstanceError {}
+impl ServiceErrorCode for DeleteDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBInstanceError::DBInstanceAutomatedBackupQuotaExceededFault(_) => {
+                "DBInstanceAutomatedBackupQuotaExceeded"
+            }
+            DeleteDBInstanceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(_) => "DBSnapshotAlreadyExists",
+            DeleteDBInstanceError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            DeleteDBInstanceError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            DeleteDBInstanceError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by DeleteDBInstanceAutomatedBackup
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBInstanceAutomatedBackupError {
@@ -22610,6 +23159,18 @@ impl fmt::Display for DeleteDBInstanceAutomatedBackupError {
     }
 }
 impl Error for DeleteDBInstanceAutomatedBackupError {}
+impl ServiceErrorCode for DeleteDBInstanceAutomatedBackupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBInstanceAutomatedBackupError::DBInstanceAutomatedBackupNotFoundFault(_) => {
+                "DBInstanceAutomatedBackupNotFound"
+            }
+            DeleteDBInstanceAutomatedBackupError::InvalidDBInstanceAutomatedBackupStateFault(_) => {
+                "InvalidDBInstanceAutomatedBackupState"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDBParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBParameterGroupError {
@@ -22670,6 +23231,18 @@ impl fmt::Display for DeleteDBParameterGroupError {
     }
 }
 impl Error for DeleteDBParameterGroupError {}
+impl ServiceErrorCode for DeleteDBParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            DeleteDBParameterGroupError::InvalidDBParameterGroupStateFault(_) => {
+                "InvalidDBParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDBProxy
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBProxyError {
@@ -22722,6 +23295,14 @@ impl fmt::Display for DeleteDBProxyError {
     }
 }
 impl Error for DeleteDBProxyError {}
+impl ServiceErrorCode for DeleteDBProxyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBProxyError::DBProxyNotFoundFault(_) => "DBProxyNotFoundFault",
+            DeleteDBProxyError::InvalidDBProxyStateFault(_) => "InvalidDBProxyStateFault",
+        }
+    }
+}
 /// Errors returned by DeleteDBSecurityGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBSecurityGroupError {
@@ -22782,6 +23363,18 @@ impl fmt::Display for DeleteDBSecurityGroupError {
     }
 }
 impl Error for DeleteDBSecurityGroupError {}
+impl ServiceErrorCode for DeleteDBSecurityGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBSecurityGroupError::DBSecurityGroupNotFoundFault(_) => {
+                "DBSecurityGroupNotFound"
+            }
+            DeleteDBSecurityGroupError::InvalidDBSecurityGroupStateFault(_) => {
+                "InvalidDBSecurityGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDBSnapshot
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBSnapshotError {
@@ -22836,6 +23429,14 @@ impl fmt::Display for DeleteDBSnapshotError {
     }
 }
 impl Error for DeleteDBSnapshotError {}
+impl ServiceErrorCode for DeleteDBSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBSnapshotError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+            DeleteDBSnapshotError::InvalidDBSnapshotStateFault(_) => "InvalidDBSnapshotState",
+        }
+    }
+}
 /// Errors returned by DeleteDBSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteDBSubnetGroupError {
@@ -22908,6 +23509,17 @@ impl fmt::Display for DeleteDBSubnetGroupError {
     }
 }
 impl Error for DeleteDBSubnetGroupError {}
+impl ServiceErrorCode for DeleteDBSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(_) => {
+                "InvalidDBSubnetGroupStateFault"
+            }
+            DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(_) => "InvalidDBSubnetStateFault",
+        }
+    }
+}
 /// Errors returned by DeleteEventSubscription
 #[derive(Debug, PartialEq)]
 pub enum DeleteEventSubscriptionError {
@@ -22968,10 +23580,20 @@ impl fmt::Display for DeleteEventSubscriptionError {
     }
 }
 impl Error for DeleteEventSubscriptionError {}
-/// Errors returned by DeleteGlobalCluster
-#[derive(Debug, PartialEq)]
-pub enum DeleteGlobalClusterError {
-    /// <p>The <code>GlobalClusterIdentifier</code> doesn't refer to an existing global database cluster. </p>
+impl ServiceErrorCode for DeleteEventSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEventSubscriptionError::InvalidEventSubscriptionStateFault(_) => {
+                "InvalidEventSubscriptionState"
+            }
+            DeleteEventSubscriptionError::SubscriptionNotFoundFault(_) => "SubscriptionNotFound",
+        }
+    }
+}
+/// Errors returned by DeleteGlobalCluster
+#[derive(Debug, PartialEq)]
+pub enum DeleteGlobalClusterError {
+    /// <p>The <code>GlobalClusterIdentifier</code> doesn't refer to an existing global database cluster. </p>
     GlobalClusterNotFoundFault(String),
     /// <p>The global cluster is in an invalid state and can't perform the requested operation. </p>
     InvalidGlobalClusterStateFault(String),
@@ -23028,6 +23650,16 @@ impl fmt::Display for DeleteGlobalClusterError {
     }
 }
 impl Error for DeleteGlobalClusterError {}
+impl ServiceErrorCode for DeleteGlobalClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGlobalClusterError::GlobalClusterNotFoundFault(_) => "GlobalClusterNotFoundFault",
+            DeleteGlobalClusterError::InvalidGlobalClusterStateFault(_) => {
+                "InvalidGlobalClusterStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteInstallationMedia
 #[derive(Debug, PartialEq)]
 pub enum DeleteInstallationMediaError {
@@ -23076,6 +23708,15 @@ impl fmt::Display for DeleteInstallationMediaError {
     }
 }
 impl Error for DeleteInstallationMediaError {}
+impl ServiceErrorCode for DeleteInstallationMediaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInstallationMediaError::InstallationMediaNotFoundFault(_) => {
+                "InstallationMediaNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteOptionGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteOptionGroupError {
@@ -23132,6 +23773,16 @@ impl fmt::Display for DeleteOptionGroupError {
     }
 }
 impl Error for DeleteOptionGroupError {}
+impl ServiceErrorCode for DeleteOptionGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteOptionGroupError::InvalidOptionGroupStateFault(_) => {
+                "InvalidOptionGroupStateFault"
+            }
+            DeleteOptionGroupError::OptionGroupNotFoundFault(_) => "OptionGroupNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DeregisterDBProxyTargets
 #[derive(Debug, PartialEq)]
 pub enum DeregisterDBProxyTargetsError {
@@ -23216,6 +23867,22 @@ impl fmt::Display for DeregisterDBProxyTargetsError {
     }
 }
 impl Error for DeregisterDBProxyTargetsError {}
+impl ServiceErrorCode for DeregisterDBProxyTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterDBProxyTargetsError::DBProxyNotFoundFault(_) => "DBProxyNotFoundFault",
+            DeregisterDBProxyTargetsError::DBProxyTargetGroupNotFoundFault(_) => {
+                "DBProxyTargetGroupNotFoundFault"
+            }
+            DeregisterDBProxyTargetsError::DBProxyTargetNotFoundFault(_) => {
+                "DBProxyTargetNotFoundFault"
+            }
+            DeregisterDBProxyTargetsError::InvalidDBProxyStateFault(_) => {
+                "InvalidDBProxyStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeAccountAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountAttributesError {}
@@ -23250,6 +23917,11 @@ impl fmt::Display for DescribeAccountAttributesError {
     }
 }
 impl Error for DescribeAccountAttributesError {}
+impl ServiceErrorCode for DescribeAccountAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeCertificates
 #[derive(Debug, PartialEq)]
 pub enum DescribeCertificatesError {
@@ -23298,6 +23970,13 @@ impl fmt::Display for DescribeCertificatesError {
     }
 }
 impl Error for DescribeCertificatesError {}
+impl ServiceErrorCode for DescribeCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCertificatesError::CertificateNotFoundFault(_) => "CertificateNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeCustomAvailabilityZones
 #[derive(Debug, PartialEq)]
 pub enum DescribeCustomAvailabilityZonesError {
@@ -23346,6 +24025,15 @@ impl fmt::Display for DescribeCustomAvailabilityZonesError {
     }
 }
 impl Error for DescribeCustomAvailabilityZonesError {}
+impl ServiceErrorCode for DescribeCustomAvailabilityZonesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCustomAvailabilityZonesError::CustomAvailabilityZoneNotFoundFault(_) => {
+                "CustomAvailabilityZoneNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBClusterBacktracks
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterBacktracksError {
@@ -23408,6 +24096,16 @@ impl fmt::Display for DescribeDBClusterBacktracksError {
     }
 }
 impl Error for DescribeDBClusterBacktracksError {}
+impl ServiceErrorCode for DescribeDBClusterBacktracksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClusterBacktracksError::DBClusterBacktrackNotFoundFault(_) => {
+                "DBClusterBacktrackNotFoundFault"
+            }
+            DescribeDBClusterBacktracksError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeDBClusterEndpoints
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterEndpointsError {
@@ -23458,6 +24156,13 @@ impl fmt::Display for DescribeDBClusterEndpointsError {
     }
 }
 impl Error for DescribeDBClusterEndpointsError {}
+impl ServiceErrorCode for DescribeDBClusterEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClusterEndpointsError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeDBClusterParameterGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterParameterGroupsError {
@@ -23508,6 +24213,15 @@ impl fmt::Display for DescribeDBClusterParameterGroupsError {
     }
 }
 impl Error for DescribeDBClusterParameterGroupsError {}
+impl ServiceErrorCode for DescribeDBClusterParameterGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBClusterParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterParametersError {
@@ -23558,6 +24272,15 @@ impl fmt::Display for DescribeDBClusterParametersError {
     }
 }
 impl Error for DescribeDBClusterParametersError {}
+impl ServiceErrorCode for DescribeDBClusterParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBClusterSnapshotAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterSnapshotAttributesError {
@@ -23606,6 +24329,15 @@ impl fmt::Display for DescribeDBClusterSnapshotAttributesError {
     }
 }
 impl Error for DescribeDBClusterSnapshotAttributesError {}
+impl ServiceErrorCode for DescribeDBClusterSnapshotAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBClusterSnapshots
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClusterSnapshotsError {
@@ -23656,6 +24388,15 @@ impl fmt::Display for DescribeDBClusterSnapshotsError {
     }
 }
 impl Error for DescribeDBClusterSnapshotsError {}
+impl ServiceErrorCode for DescribeDBClusterSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBClusters
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBClustersError {
@@ -23700,6 +24441,13 @@ impl fmt::Display for DescribeDBClustersError {
     }
 }
 impl Error for DescribeDBClustersError {}
+impl ServiceErrorCode for DescribeDBClustersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBClustersError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeDBEngineVersions
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBEngineVersionsError {}
@@ -23734,6 +24482,11 @@ impl fmt::Display for DescribeDBEngineVersionsError {
     }
 }
 impl Error for DescribeDBEngineVersionsError {}
+impl ServiceErrorCode for DescribeDBEngineVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeDBInstanceAutomatedBackups
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBInstanceAutomatedBackupsError {
@@ -23777,6 +24530,15 @@ impl fmt::Display for DescribeDBInstanceAutomatedBackupsError {
     }
 }
 impl Error for DescribeDBInstanceAutomatedBackupsError {}
+impl ServiceErrorCode for DescribeDBInstanceAutomatedBackupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBInstanceAutomatedBackupsError::DBInstanceAutomatedBackupNotFoundFault(_) => {
+                "DBInstanceAutomatedBackupNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBInstancesError {
@@ -23821,6 +24583,13 @@ impl fmt::Display for DescribeDBInstancesError {
     }
 }
 impl Error for DescribeDBInstancesError {}
+impl ServiceErrorCode for DescribeDBInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBInstancesError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeDBLogFiles
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBLogFilesError {
@@ -23865,6 +24634,13 @@ impl fmt::Display for DescribeDBLogFilesError {
     }
 }
 impl Error for DescribeDBLogFilesError {}
+impl ServiceErrorCode for DescribeDBLogFilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBLogFilesError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeDBParameterGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBParameterGroupsError {
@@ -23913,6 +24689,15 @@ impl fmt::Display for DescribeDBParameterGroupsError {
     }
 }
 impl Error for DescribeDBParameterGroupsError {}
+impl ServiceErrorCode for DescribeDBParameterGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBParameterGroupsError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBParametersError {
@@ -23961,6 +24746,15 @@ impl fmt::Display for DescribeDBParametersError {
     }
 }
 impl Error for DescribeDBParametersError {}
+impl ServiceErrorCode for DescribeDBParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBParametersError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBProxies
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBProxiesError {
@@ -24005,6 +24799,13 @@ impl fmt::Display for DescribeDBProxiesError {
     }
 }
 impl Error for DescribeDBProxiesError {}
+impl ServiceErrorCode for DescribeDBProxiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBProxiesError::DBProxyNotFoundFault(_) => "DBProxyNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeDBProxyTargetGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBProxyTargetGroupsError {
@@ -24079,6 +24880,19 @@ impl fmt::Display for DescribeDBProxyTargetGroupsError {
     }
 }
 impl Error for DescribeDBProxyTargetGroupsError {}
+impl ServiceErrorCode for DescribeDBProxyTargetGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBProxyTargetGroupsError::DBProxyNotFoundFault(_) => "DBProxyNotFoundFault",
+            DescribeDBProxyTargetGroupsError::DBProxyTargetGroupNotFoundFault(_) => {
+                "DBProxyTargetGroupNotFoundFault"
+            }
+            DescribeDBProxyTargetGroupsError::InvalidDBProxyStateFault(_) => {
+                "InvalidDBProxyStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBProxyTargets
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBProxyTargetsError {
@@ -24159,6 +24973,20 @@ impl fmt::Display for DescribeDBProxyTargetsError {
     }
 }
 impl Error for DescribeDBProxyTargetsError {}
+impl ServiceErrorCode for DescribeDBProxyTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBProxyTargetsError::DBProxyNotFoundFault(_) => "DBProxyNotFoundFault",
+            DescribeDBProxyTargetsError::DBProxyTargetGroupNotFoundFault(_) => {
+                "DBProxyTargetGroupNotFoundFault"
+            }
+            DescribeDBProxyTargetsError::DBProxyTargetNotFoundFault(_) => {
+                "DBProxyTargetNotFoundFault"
+            }
+            DescribeDBProxyTargetsError::InvalidDBProxyStateFault(_) => "InvalidDBProxyStateFault",
+        }
+    }
+}
 /// Errors returned by DescribeDBSecurityGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBSecurityGroupsError {
@@ -24207,6 +25035,15 @@ impl fmt::Display for DescribeDBSecurityGroupsError {
     }
 }
 impl Error for DescribeDBSecurityGroupsError {}
+impl ServiceErrorCode for DescribeDBSecurityGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBSecurityGroupsError::DBSecurityGroupNotFoundFault(_) => {
+                "DBSecurityGroupNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDBSnapshotAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBSnapshotAttributesError {
@@ -24257,6 +25094,13 @@ impl fmt::Display for DescribeDBSnapshotAttributesError {
     }
 }
 impl Error for DescribeDBSnapshotAttributesError {}
+impl ServiceErrorCode for DescribeDBSnapshotAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBSnapshotAttributesError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeDBSnapshots
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBSnapshotsError {
@@ -24301,6 +25145,13 @@ impl fmt::Display for DescribeDBSnapshotsError {
     }
 }
 impl Error for DescribeDBSnapshotsError {}
+impl ServiceErrorCode for DescribeDBSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBSnapshotsError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeDBSubnetGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeDBSubnetGroupsError {
@@ -24349,6 +25200,15 @@ impl fmt::Display for DescribeDBSubnetGroupsError {
     }
 }
 impl Error for DescribeDBSubnetGroupsError {}
+impl ServiceErrorCode for DescribeDBSubnetGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(_) => {
+                "DBSubnetGroupNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeEngineDefaultClusterParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeEngineDefaultClusterParametersError {}
@@ -24385,6 +25245,11 @@ impl fmt::Display for DescribeEngineDefaultClusterParametersError {
     }
 }
 impl Error for DescribeEngineDefaultClusterParametersError {}
+impl ServiceErrorCode for DescribeEngineDefaultClusterParametersError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEngineDefaultParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeEngineDefaultParametersError {}
@@ -24421,6 +25286,11 @@ impl fmt::Display for DescribeEngineDefaultParametersError {
     }
 }
 impl Error for DescribeEngineDefaultParametersError {}
+impl ServiceErrorCode for DescribeEngineDefaultParametersError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEventCategories
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventCategoriesError {}
@@ -24455,6 +25325,11 @@ impl fmt::Display for DescribeEventCategoriesError {
     }
 }
 impl Error for DescribeEventCategoriesError {}
+impl ServiceErrorCode for DescribeEventCategoriesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEventSubscriptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventSubscriptionsError {
@@ -24505,6 +25380,13 @@ impl fmt::Display for DescribeEventSubscriptionsError {
     }
 }
 impl Error for DescribeEventSubscriptionsError {}
+impl ServiceErrorCode for DescribeEventSubscriptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventSubscriptionsError::SubscriptionNotFoundFault(_) => "SubscriptionNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventsError {}
@@ -24539,6 +25421,11 @@ impl fmt::Display for DescribeEventsError {
     }
 }
 impl Error for DescribeEventsError {}
+impl ServiceErrorCode for DescribeEventsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeExportTasks
 #[derive(Debug, PartialEq)]
 pub enum DescribeExportTasksError {
@@ -24583,6 +25470,13 @@ impl fmt::Display for DescribeExportTasksError {
     }
 }
 impl Error for DescribeExportTasksError {}
+impl ServiceErrorCode for DescribeExportTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeExportTasksError::ExportTaskNotFoundFault(_) => "ExportTaskNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeGlobalClusters
 #[derive(Debug, PartialEq)]
 pub enum DescribeGlobalClustersError {
@@ -24631,6 +25525,15 @@ impl fmt::Display for DescribeGlobalClustersError {
     }
 }
 impl Error for DescribeGlobalClustersError {}
+impl ServiceErrorCode for DescribeGlobalClustersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGlobalClustersError::GlobalClusterNotFoundFault(_) => {
+                "GlobalClusterNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeInstallationMedia
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstallationMediaError {
@@ -24679,6 +25582,15 @@ impl fmt::Display for DescribeInstallationMediaError {
     }
 }
 impl Error for DescribeInstallationMediaError {}
+impl ServiceErrorCode for DescribeInstallationMediaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInstallationMediaError::InstallationMediaNotFoundFault(_) => {
+                "InstallationMediaNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeOptionGroupOptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeOptionGroupOptionsError {}
@@ -24715,6 +25627,11 @@ impl fmt::Display for DescribeOptionGroupOptionsError {
     }
 }
 impl Error for DescribeOptionGroupOptionsError {}
+impl ServiceErrorCode for DescribeOptionGroupOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeOptionGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeOptionGroupsError {
@@ -24763,6 +25680,13 @@ impl fmt::Display for DescribeOptionGroupsError {
     }
 }
 impl Error for DescribeOptionGroupsError {}
+impl ServiceErrorCode for DescribeOptionGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeOptionGroupsError::OptionGroupNotFoundFault(_) => "OptionGroupNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeOrderableDBInstanceOptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeOrderableDBInstanceOptionsError {}
@@ -24799,6 +25723,11 @@ impl fmt::Display for DescribeOrderableDBInstanceOptionsError {
     }
 }
 impl Error for DescribeOrderableDBInstanceOptionsError {}
+impl ServiceErrorCode for DescribeOrderableDBInstanceOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribePendingMaintenanceActions
 #[derive(Debug, PartialEq)]
 pub enum DescribePendingMaintenanceActionsError {
@@ -24849,6 +25778,15 @@ impl fmt::Display for DescribePendingMaintenanceActionsError {
     }
 }
 impl Error for DescribePendingMaintenanceActionsError {}
+impl ServiceErrorCode for DescribePendingMaintenanceActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePendingMaintenanceActionsError::ResourceNotFoundFault(_) => {
+                "ResourceNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeReservedDBInstances
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedDBInstancesError {
@@ -24899,6 +25837,15 @@ impl fmt::Display for DescribeReservedDBInstancesError {
     }
 }
 impl Error for DescribeReservedDBInstancesError {}
+impl ServiceErrorCode for DescribeReservedDBInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReservedDBInstancesError::ReservedDBInstanceNotFoundFault(_) => {
+                "ReservedDBInstanceNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeReservedDBInstancesOfferings
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedDBInstancesOfferingsError {
@@ -24942,6 +25889,15 @@ impl fmt::Display for DescribeReservedDBInstancesOfferingsError {
     }
 }
 impl Error for DescribeReservedDBInstancesOfferingsError {}
+impl ServiceErrorCode for DescribeReservedDBInstancesOfferingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReservedDBInstancesOfferingsError::ReservedDBInstancesOfferingNotFoundFault(
+                _,
+            ) => "ReservedDBInstancesOfferingNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeSourceRegions
 #[derive(Debug, PartialEq)]
 pub enum DescribeSourceRegionsError {}
@@ -24976,6 +25932,11 @@ impl fmt::Display for DescribeSourceRegionsError {
     }
 }
 impl Error for DescribeSourceRegionsError {}
+impl ServiceErrorCode for DescribeSourceRegionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeValidDBInstanceModifications
 #[derive(Debug, PartialEq)]
 pub enum DescribeValidDBInstanceModificationsError {
@@ -25038,6 +25999,18 @@ impl fmt::Display for DescribeValidDBInstanceModificationsError {
     }
 }
 impl Error for DescribeValidDBInstanceModificationsError {}
+impl ServiceErrorCode for DescribeValidDBInstanceModificationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeValidDBInstanceModificationsError::DBInstanceNotFoundFault(_) => {
+                "DBInstanceNotFound"
+            }
+            DescribeValidDBInstanceModificationsError::InvalidDBInstanceStateFault(_) => {
+                "InvalidDBInstanceState"
+            }
+        }
+    }
+}
 /// Errors returned by DownloadDBLogFilePortion
 #[derive(Debug, PartialEq)]
 pub enum DownloadDBLogFilePortionError {
@@ -25098,6 +26071,14 @@ impl fmt::Display for DownloadDBLogFilePortionError {
     }
 }
 impl Error for DownloadDBLogFilePortionError {}
+impl ServiceErrorCode for DownloadDBLogFilePortionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DownloadDBLogFilePortionError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            DownloadDBLogFilePortionError::DBLogFileNotFoundFault(_) => "DBLogFileNotFoundFault",
+        }
+    }
+}
 /// Errors returned by FailoverDBCluster
 #[derive(Debug, PartialEq)]
 pub enum FailoverDBClusterError {
@@ -25164,6 +26145,15 @@ impl fmt::Display for FailoverDBClusterError {
     }
 }
 impl Error for FailoverDBClusterError {}
+impl ServiceErrorCode for FailoverDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            FailoverDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            FailoverDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            FailoverDBClusterError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+        }
+    }
+}
 /// Errors returned by ImportInstallationMedia
 #[derive(Debug, PartialEq)]
 pub enum ImportInstallationMediaError {
@@ -25224,6 +26214,18 @@ impl fmt::Display for ImportInstallationMediaError {
     }
 }
 impl Error for ImportInstallationMediaError {}
+impl ServiceErrorCode for ImportInstallationMediaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportInstallationMediaError::CustomAvailabilityZoneNotFoundFault(_) => {
+                "CustomAvailabilityZoneNotFound"
+            }
+            ImportInstallationMediaError::InstallationMediaAlreadyExistsFault(_) => {
+                "InstallationMediaAlreadyExists"
+            }
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -25304,6 +26306,19 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            ListTagsForResourceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            ListTagsForResourceError::DBProxyNotFoundFault(_) => "DBProxyNotFoundFault",
+            ListTagsForResourceError::DBProxyTargetGroupNotFoundFault(_) => {
+                "DBProxyTargetGroupNotFoundFault"
+            }
+            ListTagsForResourceError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+        }
+    }
+}
 /// Errors returned by ModifyCertificates
 #[derive(Debug, PartialEq)]
 pub enum ModifyCertificatesError {
@@ -25348,6 +26363,13 @@ impl fmt::Display for ModifyCertificatesError {
     }
 }
 impl Error for ModifyCertificatesError {}
+impl ServiceErrorCode for ModifyCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyCertificatesError::CertificateNotFoundFault(_) => "CertificateNotFound",
+        }
+    }
+}
 /// Errors returned by ModifyCurrentDBClusterCapacity
 #[derive(Debug, PartialEq)]
 pub enum ModifyCurrentDBClusterCapacityError {
@@ -25422,6 +26444,21 @@ impl fmt::Display for ModifyCurrentDBClusterCapacityError {
     }
 }
 impl Error for ModifyCurrentDBClusterCapacityError {}
+impl ServiceErrorCode for ModifyCurrentDBClusterCapacityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyCurrentDBClusterCapacityError::DBClusterNotFoundFault(_) => {
+                "DBClusterNotFoundFault"
+            }
+            ModifyCurrentDBClusterCapacityError::InvalidDBClusterCapacityFault(_) => {
+                "InvalidDBClusterCapacityFault"
+            }
+            ModifyCurrentDBClusterCapacityError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyDBCluster
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterError {
@@ -25566,6 +26603,30 @@ impl fmt::Display for ModifyDBClusterError {
     }
 }
 impl Error for ModifyDBClusterError {}
+impl ServiceErrorCode for ModifyDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBClusterError::DBClusterAlreadyExistsFault(_) => "DBClusterAlreadyExistsFault",
+            ModifyDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(_) => {
+                "DBClusterParameterGroupNotFound"
+            }
+            ModifyDBClusterError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            ModifyDBClusterError::DomainNotFoundFault(_) => "DomainNotFoundFault",
+            ModifyDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            ModifyDBClusterError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            ModifyDBClusterError::InvalidDBSecurityGroupStateFault(_) => {
+                "InvalidDBSecurityGroupState"
+            }
+            ModifyDBClusterError::InvalidDBSubnetGroupStateFault(_) => {
+                "InvalidDBSubnetGroupStateFault"
+            }
+            ModifyDBClusterError::InvalidSubnet(_) => "InvalidSubnet",
+            ModifyDBClusterError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            ModifyDBClusterError::StorageQuotaExceededFault(_) => "StorageQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by ModifyDBClusterEndpoint
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterEndpointError {
@@ -25662,6 +26723,25 @@ impl fmt::Display for ModifyDBClusterEndpointError {
     }
 }
 impl Error for ModifyDBClusterEndpointError {}
+impl ServiceErrorCode for ModifyDBClusterEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBClusterEndpointError::DBClusterEndpointNotFoundFault(_) => {
+                "DBClusterEndpointNotFoundFault"
+            }
+            ModifyDBClusterEndpointError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            ModifyDBClusterEndpointError::InvalidDBClusterEndpointStateFault(_) => {
+                "InvalidDBClusterEndpointStateFault"
+            }
+            ModifyDBClusterEndpointError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            ModifyDBClusterEndpointError::InvalidDBInstanceStateFault(_) => {
+                "InvalidDBInstanceState"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterParameterGroupError {
@@ -25724,6 +26804,18 @@ impl fmt::Display for ModifyDBClusterParameterGroupError {
     }
 }
 impl Error for ModifyDBClusterParameterGroupError {}
+impl ServiceErrorCode for ModifyDBClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(_) => {
+                "InvalidDBParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyDBClusterSnapshotAttribute
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBClusterSnapshotAttributeError {
@@ -25796,6 +26888,21 @@ impl fmt::Display for ModifyDBClusterSnapshotAttributeError {
     }
 }
 impl Error for ModifyDBClusterSnapshotAttributeError {}
+impl ServiceErrorCode for ModifyDBClusterSnapshotAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(_) => {
+                "SharedSnapshotQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyDBInstance
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBInstanceError {
@@ -26020,6 +27127,39 @@ impl fmt::Display for ModifyDBInstanceError {
     }
 }
 impl Error for ModifyDBInstanceError {}
+impl ServiceErrorCode for ModifyDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBInstanceError::AuthorizationNotFoundFault(_) => "AuthorizationNotFound",
+            ModifyDBInstanceError::BackupPolicyNotFoundFault(_) => "BackupPolicyNotFoundFault",
+            ModifyDBInstanceError::CertificateNotFoundFault(_) => "CertificateNotFound",
+            ModifyDBInstanceError::DBInstanceAlreadyExistsFault(_) => "DBInstanceAlreadyExists",
+            ModifyDBInstanceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            ModifyDBInstanceError::DBParameterGroupNotFoundFault(_) => "DBParameterGroupNotFound",
+            ModifyDBInstanceError::DBSecurityGroupNotFoundFault(_) => "DBSecurityGroupNotFound",
+            ModifyDBInstanceError::DBUpgradeDependencyFailureFault(_) => {
+                "DBUpgradeDependencyFailure"
+            }
+            ModifyDBInstanceError::DomainNotFoundFault(_) => "DomainNotFoundFault",
+            ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(_) => {
+                "InsufficientDBInstanceCapacity"
+            }
+            ModifyDBInstanceError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            ModifyDBInstanceError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(_) => {
+                "InvalidDBSecurityGroupState"
+            }
+            ModifyDBInstanceError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            ModifyDBInstanceError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            ModifyDBInstanceError::OptionGroupNotFoundFault(_) => "OptionGroupNotFoundFault",
+            ModifyDBInstanceError::ProvisionedIopsNotAvailableInAZFault(_) => {
+                "ProvisionedIopsNotAvailableInAZFault"
+            }
+            ModifyDBInstanceError::StorageQuotaExceededFault(_) => "StorageQuotaExceeded",
+            ModifyDBInstanceError::StorageTypeNotSupportedFault(_) => "StorageTypeNotSupported",
+        }
+    }
+}
 /// Errors returned by ModifyDBParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBParameterGroupError {
@@ -26080,6 +27220,18 @@ impl fmt::Display for ModifyDBParameterGroupError {
     }
 }
 impl Error for ModifyDBParameterGroupError {}
+impl ServiceErrorCode for ModifyDBParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            ModifyDBParameterGroupError::InvalidDBParameterGroupStateFault(_) => {
+                "InvalidDBParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyDBProxy
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBProxyError {
@@ -26140,6 +27292,15 @@ impl fmt::Display for ModifyDBProxyError {
     }
 }
 impl Error for ModifyDBProxyError {}
+impl ServiceErrorCode for ModifyDBProxyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBProxyError::DBProxyAlreadyExistsFault(_) => "DBProxyTargetExistsFault",
+            ModifyDBProxyError::DBProxyNotFoundFault(_) => "DBProxyNotFoundFault",
+            ModifyDBProxyError::InvalidDBProxyStateFault(_) => "InvalidDBProxyStateFault",
+        }
+    }
+}
 /// Errors returned by ModifyDBProxyTargetGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBProxyTargetGroupError {
@@ -26205,13 +27366,26 @@ impl fmt::Display for ModifyDBProxyTargetGroupError {
             ModifyDBProxyTargetGroupError::DBProxyTargetGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
-            ModifyDBProxyTargetGroupError::InvalidDBProxyStateFault(ref cause) => {
-                write!(f, "{}", cause)
+            ModifyDBProxyTargetGroupError::InvalidDBProxyStateFault(ref cause) => {
+                write!(f, "{}", cause)
+            }
+        }
+    }
+}
+impl Error for ModifyDBProxyTargetGroupError {}
+impl ServiceErrorCode for ModifyDBProxyTargetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBProxyTargetGroupError::DBProxyNotFoundFault(_) => "DBProxyNotFoundFault",
+            ModifyDBProxyTargetGroupError::DBProxyTargetGroupNotFoundFault(_) => {
+                "DBProxyTargetGroupNotFoundFault"
+            }
+            ModifyDBProxyTargetGroupError::InvalidDBProxyStateFault(_) => {
+                "InvalidDBProxyStateFault"
             }
         }
     }
 }
-impl Error for ModifyDBProxyTargetGroupError {}
 /// Errors returned by ModifyDBSnapshot
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBSnapshotError {
@@ -26256,6 +27430,13 @@ impl fmt::Display for ModifyDBSnapshotError {
     }
 }
 impl Error for ModifyDBSnapshotError {}
+impl ServiceErrorCode for ModifyDBSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBSnapshotError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+        }
+    }
+}
 /// Errors returned by ModifyDBSnapshotAttribute
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBSnapshotAttributeError {
@@ -26328,6 +27509,19 @@ impl fmt::Display for ModifyDBSnapshotAttributeError {
     }
 }
 impl Error for ModifyDBSnapshotAttributeError {}
+impl ServiceErrorCode for ModifyDBSnapshotAttributeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBSnapshotAttributeError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+            ModifyDBSnapshotAttributeError::InvalidDBSnapshotStateFault(_) => {
+                "InvalidDBSnapshotState"
+            }
+            ModifyDBSnapshotAttributeError::SharedSnapshotQuotaExceededFault(_) => {
+                "SharedSnapshotQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyDBSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyDBSubnetGroupError {
@@ -26416,6 +27610,19 @@ impl fmt::Display for ModifyDBSubnetGroupError {
     }
 }
 impl Error for ModifyDBSubnetGroupError {}
+impl ServiceErrorCode for ModifyDBSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(_) => "DBSubnetQuotaExceededFault",
+            ModifyDBSubnetGroupError::InvalidSubnet(_) => "InvalidSubnet",
+            ModifyDBSubnetGroupError::SubnetAlreadyInUse(_) => "SubnetAlreadyInUse",
+        }
+    }
+}
 /// Errors returned by ModifyEventSubscription
 #[derive(Debug, PartialEq)]
 pub enum ModifyEventSubscriptionError {
@@ -26522,6 +27729,22 @@ impl fmt::Display for ModifyEventSubscriptionError {
     }
 }
 impl Error for ModifyEventSubscriptionError {}
+impl ServiceErrorCode for ModifyEventSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyEventSubscriptionError::EventSubscriptionQuotaExceededFault(_) => {
+                "EventSubscriptionQuotaExceeded"
+            }
+            ModifyEventSubscriptionError::SNSInvalidTopicFault(_) => "SNSInvalidTopic",
+            ModifyEventSubscriptionError::SNSNoAuthorizationFault(_) => "SNSNoAuthorization",
+            ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(_) => "SNSTopicArnNotFound",
+            ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(_) => {
+                "SubscriptionCategoryNotFound"
+            }
+            ModifyEventSubscriptionError::SubscriptionNotFoundFault(_) => "SubscriptionNotFound",
+        }
+    }
+}
 /// Errors returned by ModifyGlobalCluster
 #[derive(Debug, PartialEq)]
 pub enum ModifyGlobalClusterError {
@@ -26582,6 +27805,16 @@ impl fmt::Display for ModifyGlobalClusterError {
     }
 }
 impl Error for ModifyGlobalClusterError {}
+impl ServiceErrorCode for ModifyGlobalClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyGlobalClusterError::GlobalClusterNotFoundFault(_) => "GlobalClusterNotFoundFault",
+            ModifyGlobalClusterError::InvalidGlobalClusterStateFault(_) => {
+                "InvalidGlobalClusterStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyOptionGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyOptionGroupError {
@@ -26638,6 +27871,16 @@ impl fmt::Display for ModifyOptionGroupError {
     }
 }
 impl Error for ModifyOptionGroupError {}
+impl ServiceErrorCode for ModifyOptionGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyOptionGroupError::InvalidOptionGroupStateFault(_) => {
+                "InvalidOptionGroupStateFault"
+            }
+            ModifyOptionGroupError::OptionGroupNotFoundFault(_) => "OptionGroupNotFoundFault",
+        }
+    }
+}
 /// Errors returned by PromoteReadReplica
 #[derive(Debug, PartialEq)]
 pub enum PromoteReadReplicaError {
@@ -26694,6 +27937,14 @@ impl fmt::Display for PromoteReadReplicaError {
     }
 }
 impl Error for PromoteReadReplicaError {}
+impl ServiceErrorCode for PromoteReadReplicaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PromoteReadReplicaError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            PromoteReadReplicaError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+        }
+    }
+}
 /// Errors returned by PromoteReadReplicaDBCluster
 #[derive(Debug, PartialEq)]
 pub enum PromoteReadReplicaDBClusterError {
@@ -26756,6 +28007,16 @@ impl fmt::Display for PromoteReadReplicaDBClusterError {
     }
 }
 impl Error for PromoteReadReplicaDBClusterError {}
+impl ServiceErrorCode for PromoteReadReplicaDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PromoteReadReplicaDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            PromoteReadReplicaDBClusterError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by PurchaseReservedDBInstancesOffering
 #[derive(Debug, PartialEq)]
 pub enum PurchaseReservedDBInstancesOfferingError {
@@ -26809,6 +28070,21 @@ impl fmt::Display for PurchaseReservedDBInstancesOfferingError {
     }
 }
 impl Error for PurchaseReservedDBInstancesOfferingError {}
+impl ServiceErrorCode for PurchaseReservedDBInstancesOfferingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceAlreadyExistsFault(_) => {
+                "ReservedDBInstanceAlreadyExists"
+            }
+            PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceQuotaExceededFault(_) => {
+                "ReservedDBInstanceQuotaExceeded"
+            }
+            PurchaseReservedDBInstancesOfferingError::ReservedDBInstancesOfferingNotFoundFault(
+                _,
+            ) => "ReservedDBInstancesOfferingNotFound",
+        }
+    }
+}
 /// Errors returned by RebootDBInstance
 #[derive(Debug, PartialEq)]
 pub enum RebootDBInstanceError {
@@ -26863,6 +28139,14 @@ impl fmt::Display for RebootDBInstanceError {
     }
 }
 impl Error for RebootDBInstanceError {}
+impl ServiceErrorCode for RebootDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RebootDBInstanceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            RebootDBInstanceError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+        }
+    }
+}
 /// Errors returned by RegisterDBProxyTargets
 #[derive(Debug, PartialEq)]
 pub enum RegisterDBProxyTargetsError {
@@ -27003,6 +28287,29 @@ impl fmt::Display for RegisterDBProxyTargetsError {
     }
 }
 impl Error for RegisterDBProxyTargetsError {}
+impl ServiceErrorCode for RegisterDBProxyTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterDBProxyTargetsError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            RegisterDBProxyTargetsError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            RegisterDBProxyTargetsError::DBProxyNotFoundFault(_) => "DBProxyNotFoundFault",
+            RegisterDBProxyTargetsError::DBProxyTargetAlreadyRegisteredFault(_) => {
+                "DBProxyTargetAlreadyRegisteredFault"
+            }
+            RegisterDBProxyTargetsError::DBProxyTargetGroupNotFoundFault(_) => {
+                "DBProxyTargetGroupNotFoundFault"
+            }
+            RegisterDBProxyTargetsError::InsufficientAvailableIPsInSubnetFault(_) => {
+                "InsufficientAvailableIPsInSubnetFault"
+            }
+            RegisterDBProxyTargetsError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            RegisterDBProxyTargetsError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            RegisterDBProxyTargetsError::InvalidDBProxyStateFault(_) => "InvalidDBProxyStateFault",
+        }
+    }
+}
 /// Errors returned by RemoveFromGlobalCluster
 #[derive(Debug, PartialEq)]
 pub enum RemoveFromGlobalClusterError {
@@ -27075,6 +28382,19 @@ impl fmt::Display for RemoveFromGlobalClusterError {
     }
 }
 impl Error for RemoveFromGlobalClusterError {}
+impl ServiceErrorCode for RemoveFromGlobalClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveFromGlobalClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            RemoveFromGlobalClusterError::GlobalClusterNotFoundFault(_) => {
+                "GlobalClusterNotFoundFault"
+            }
+            RemoveFromGlobalClusterError::InvalidGlobalClusterStateFault(_) => {
+                "InvalidGlobalClusterStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by RemoveRoleFromDBCluster
 #[derive(Debug, PartialEq)]
 pub enum RemoveRoleFromDBClusterError {
@@ -27147,6 +28467,17 @@ impl fmt::Display for RemoveRoleFromDBClusterError {
     }
 }
 impl Error for RemoveRoleFromDBClusterError {}
+impl ServiceErrorCode for RemoveRoleFromDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveRoleFromDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            RemoveRoleFromDBClusterError::DBClusterRoleNotFoundFault(_) => "DBClusterRoleNotFound",
+            RemoveRoleFromDBClusterError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by RemoveRoleFromDBInstance
 #[derive(Debug, PartialEq)]
 pub enum RemoveRoleFromDBInstanceError {
@@ -27219,6 +28550,19 @@ impl fmt::Display for RemoveRoleFromDBInstanceError {
     }
 }
 impl Error for RemoveRoleFromDBInstanceError {}
+impl ServiceErrorCode for RemoveRoleFromDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveRoleFromDBInstanceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            RemoveRoleFromDBInstanceError::DBInstanceRoleNotFoundFault(_) => {
+                "DBInstanceRoleNotFound"
+            }
+            RemoveRoleFromDBInstanceError::InvalidDBInstanceStateFault(_) => {
+                "InvalidDBInstanceState"
+            }
+        }
+    }
+}
 /// Errors returned by RemoveSourceIdentifierFromSubscription
 #[derive(Debug, PartialEq)]
 pub enum RemoveSourceIdentifierFromSubscriptionError {
@@ -27281,6 +28625,16 @@ impl fmt::Display for RemoveSourceIdentifierFromSubscriptionError {
     }
 }
 impl Error for RemoveSourceIdentifierFromSubscriptionError {}
+impl ServiceErrorCode for RemoveSourceIdentifierFromSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveSourceIdentifierFromSubscriptionError::SourceNotFoundFault(_) => "SourceNotFound",
+            RemoveSourceIdentifierFromSubscriptionError::SubscriptionNotFoundFault(_) => {
+                "SubscriptionNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromResourceError {
@@ -27373,6 +28727,19 @@ impl fmt::Display for RemoveTagsFromResourceError {
     }
 }
 impl Error for RemoveTagsFromResourceError {}
+impl ServiceErrorCode for RemoveTagsFromResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsFromResourceError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            RemoveTagsFromResourceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            RemoveTagsFromResourceError::DBProxyNotFoundFault(_) => "DBProxyNotFoundFault",
+            RemoveTagsFromResourceError::DBProxyTargetGroupNotFoundFault(_) => {
+                "DBProxyTargetGroupNotFoundFault"
+            }
+            RemoveTagsFromResourceError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+        }
+    }
+}
 /// Errors returned by ResetDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum ResetDBClusterParameterGroupError {
@@ -27435,6 +28802,18 @@ impl fmt::Display for ResetDBClusterParameterGroupError {
     }
 }
 impl Error for ResetDBClusterParameterGroupError {}
+impl ServiceErrorCode for ResetDBClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(_) => {
+                "InvalidDBParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by ResetDBParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum ResetDBParameterGroupError {
@@ -27495,6 +28874,18 @@ impl fmt::Display for ResetDBParameterGroupError {
     }
 }
 impl Error for ResetDBParameterGroupError {}
+impl ServiceErrorCode for ResetDBParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetDBParameterGroupError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            ResetDBParameterGroupError::InvalidDBParameterGroupStateFault(_) => {
+                "InvalidDBParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by RestoreDBClusterFromS3
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBClusterFromS3Error {
@@ -27687,6 +29078,42 @@ impl fmt::Display for RestoreDBClusterFromS3Error {
     }
 }
 impl Error for RestoreDBClusterFromS3Error {}
+impl ServiceErrorCode for RestoreDBClusterFromS3Error {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreDBClusterFromS3Error::DBClusterAlreadyExistsFault(_) => {
+                "DBClusterAlreadyExistsFault"
+            }
+            RestoreDBClusterFromS3Error::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            RestoreDBClusterFromS3Error::DBClusterParameterGroupNotFoundFault(_) => {
+                "DBClusterParameterGroupNotFound"
+            }
+            RestoreDBClusterFromS3Error::DBClusterQuotaExceededFault(_) => {
+                "DBClusterQuotaExceededFault"
+            }
+            RestoreDBClusterFromS3Error::DBSubnetGroupNotFoundFault(_) => {
+                "DBSubnetGroupNotFoundFault"
+            }
+            RestoreDBClusterFromS3Error::DomainNotFoundFault(_) => "DomainNotFoundFault",
+            RestoreDBClusterFromS3Error::InsufficientStorageClusterCapacityFault(_) => {
+                "InsufficientStorageClusterCapacity"
+            }
+            RestoreDBClusterFromS3Error::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            RestoreDBClusterFromS3Error::InvalidDBSubnetGroupStateFault(_) => {
+                "InvalidDBSubnetGroupStateFault"
+            }
+            RestoreDBClusterFromS3Error::InvalidS3BucketFault(_) => "InvalidS3BucketFault",
+            RestoreDBClusterFromS3Error::InvalidSubnet(_) => "InvalidSubnet",
+            RestoreDBClusterFromS3Error::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            RestoreDBClusterFromS3Error::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            RestoreDBClusterFromS3Error::StorageQuotaExceededFault(_) => "StorageQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by RestoreDBClusterFromSnapshot
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBClusterFromSnapshotError {
@@ -27923,6 +29350,55 @@ impl fmt::Display for RestoreDBClusterFromSnapshotError {
     }
 }
 impl Error for RestoreDBClusterFromSnapshotError {}
+impl ServiceErrorCode for RestoreDBClusterFromSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(_) => {
+                "DBClusterAlreadyExistsFault"
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterParameterGroupNotFoundFault(_) => {
+                "DBClusterParameterGroupNotFound"
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(_) => {
+                "DBClusterQuotaExceededFault"
+            }
+            RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+            RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(_) => {
+                "DBSubnetGroupNotFoundFault"
+            }
+            RestoreDBClusterFromSnapshotError::DomainNotFoundFault(_) => "DomainNotFoundFault",
+            RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(_) => {
+                "InsufficientDBClusterCapacityFault"
+            }
+            RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(_) => {
+                "InsufficientStorageClusterCapacity"
+            }
+            RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(_) => {
+                "InvalidDBSnapshotState"
+            }
+            RestoreDBClusterFromSnapshotError::InvalidRestoreFault(_) => "InvalidRestoreFault",
+            RestoreDBClusterFromSnapshotError::InvalidSubnet(_) => "InvalidSubnet",
+            RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(_) => {
+                "KMSKeyNotAccessibleFault"
+            }
+            RestoreDBClusterFromSnapshotError::OptionGroupNotFoundFault(_) => {
+                "OptionGroupNotFoundFault"
+            }
+            RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(_) => {
+                "StorageQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by RestoreDBClusterToPointInTime
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBClusterToPointInTimeError {
@@ -28169,6 +29645,60 @@ impl fmt::Display for RestoreDBClusterToPointInTimeError {
     }
 }
 impl Error for RestoreDBClusterToPointInTimeError {}
+impl ServiceErrorCode for RestoreDBClusterToPointInTimeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(_) => {
+                "DBClusterAlreadyExistsFault"
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(_) => {
+                "DBClusterNotFoundFault"
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterParameterGroupNotFoundFault(_) => {
+                "DBClusterParameterGroupNotFound"
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(_) => {
+                "DBClusterQuotaExceededFault"
+            }
+            RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(_) => {
+                "DBSubnetGroupNotFoundFault"
+            }
+            RestoreDBClusterToPointInTimeError::DomainNotFoundFault(_) => "DomainNotFoundFault",
+            RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(_) => {
+                "InsufficientDBClusterCapacityFault"
+            }
+            RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(_) => {
+                "InsufficientStorageClusterCapacity"
+            }
+            RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(_) => {
+                "InvalidDBClusterSnapshotStateFault"
+            }
+            RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(_) => {
+                "InvalidDBClusterStateFault"
+            }
+            RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(_) => {
+                "InvalidDBSnapshotState"
+            }
+            RestoreDBClusterToPointInTimeError::InvalidRestoreFault(_) => "InvalidRestoreFault",
+            RestoreDBClusterToPointInTimeError::InvalidSubnet(_) => "InvalidSubnet",
+            RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(_) => {
+                "KMSKeyNotAccessibleFault"
+            }
+            RestoreDBClusterToPointInTimeError::OptionGroupNotFoundFault(_) => {
+                "OptionGroupNotFoundFault"
+            }
+            RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(_) => {
+                "StorageQuotaExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by RestoreDBInstanceFromDBSnapshot
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBInstanceFromDBSnapshotError {
@@ -28441,6 +29971,66 @@ impl fmt::Display for RestoreDBInstanceFromDBSnapshotError {
     }
 }
 impl Error for RestoreDBInstanceFromDBSnapshotError {}
+impl ServiceErrorCode for RestoreDBInstanceFromDBSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreDBInstanceFromDBSnapshotError::AuthorizationNotFoundFault(_) => {
+                "AuthorizationNotFound"
+            }
+            RestoreDBInstanceFromDBSnapshotError::BackupPolicyNotFoundFault(_) => {
+                "BackupPolicyNotFoundFault"
+            }
+            RestoreDBInstanceFromDBSnapshotError::DBInstanceAlreadyExistsFault(_) => {
+                "DBInstanceAlreadyExists"
+            }
+            RestoreDBInstanceFromDBSnapshotError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            RestoreDBInstanceFromDBSnapshotError::DBSecurityGroupNotFoundFault(_) => {
+                "DBSecurityGroupNotFound"
+            }
+            RestoreDBInstanceFromDBSnapshotError::DBSnapshotNotFoundFault(_) => {
+                "DBSnapshotNotFound"
+            }
+            RestoreDBInstanceFromDBSnapshotError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            RestoreDBInstanceFromDBSnapshotError::DBSubnetGroupNotFoundFault(_) => {
+                "DBSubnetGroupNotFoundFault"
+            }
+            RestoreDBInstanceFromDBSnapshotError::DomainNotFoundFault(_) => "DomainNotFoundFault",
+            RestoreDBInstanceFromDBSnapshotError::InstanceQuotaExceededFault(_) => {
+                "InstanceQuotaExceeded"
+            }
+            RestoreDBInstanceFromDBSnapshotError::InsufficientDBInstanceCapacityFault(_) => {
+                "InsufficientDBInstanceCapacity"
+            }
+            RestoreDBInstanceFromDBSnapshotError::InvalidDBSnapshotStateFault(_) => {
+                "InvalidDBSnapshotState"
+            }
+            RestoreDBInstanceFromDBSnapshotError::InvalidRestoreFault(_) => "InvalidRestoreFault",
+            RestoreDBInstanceFromDBSnapshotError::InvalidSubnet(_) => "InvalidSubnet",
+            RestoreDBInstanceFromDBSnapshotError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            RestoreDBInstanceFromDBSnapshotError::KMSKeyNotAccessibleFault(_) => {
+                "KMSKeyNotAccessibleFault"
+            }
+            RestoreDBInstanceFromDBSnapshotError::OptionGroupNotFoundFault(_) => {
+                "OptionGroupNotFoundFault"
+            }
+            RestoreDBInstanceFromDBSnapshotError::ProvisionedIopsNotAvailableInAZFault(_) => {
+                "ProvisionedIopsNotAvailableInAZFault"
+            }
+            RestoreDBInstanceFromDBSnapshotError::StorageQuotaExceededFault(_) => {
+                "StorageQuotaExceeded"
+            }
+            RestoreDBInstanceFromDBSnapshotError::StorageTypeNotSupportedFault(_) => {
+                "StorageTypeNotSupported"
+            }
+        }
+    }
+}
 /// Errors returned by RestoreDBInstanceFromS3
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBInstanceFromS3Error {
@@ -28675,6 +30265,49 @@ impl fmt::Display for RestoreDBInstanceFromS3Error {
     }
 }
 impl Error for RestoreDBInstanceFromS3Error {}
+impl ServiceErrorCode for RestoreDBInstanceFromS3Error {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreDBInstanceFromS3Error::AuthorizationNotFoundFault(_) => "AuthorizationNotFound",
+            RestoreDBInstanceFromS3Error::BackupPolicyNotFoundFault(_) => {
+                "BackupPolicyNotFoundFault"
+            }
+            RestoreDBInstanceFromS3Error::DBInstanceAlreadyExistsFault(_) => {
+                "DBInstanceAlreadyExists"
+            }
+            RestoreDBInstanceFromS3Error::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            RestoreDBInstanceFromS3Error::DBSecurityGroupNotFoundFault(_) => {
+                "DBSecurityGroupNotFound"
+            }
+            RestoreDBInstanceFromS3Error::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            RestoreDBInstanceFromS3Error::DBSubnetGroupNotFoundFault(_) => {
+                "DBSubnetGroupNotFoundFault"
+            }
+            RestoreDBInstanceFromS3Error::InstanceQuotaExceededFault(_) => "InstanceQuotaExceeded",
+            RestoreDBInstanceFromS3Error::InsufficientDBInstanceCapacityFault(_) => {
+                "InsufficientDBInstanceCapacity"
+            }
+            RestoreDBInstanceFromS3Error::InvalidS3BucketFault(_) => "InvalidS3BucketFault",
+            RestoreDBInstanceFromS3Error::InvalidSubnet(_) => "InvalidSubnet",
+            RestoreDBInstanceFromS3Error::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            RestoreDBInstanceFromS3Error::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            RestoreDBInstanceFromS3Error::OptionGroupNotFoundFault(_) => "OptionGroupNotFoundFault",
+            RestoreDBInstanceFromS3Error::ProvisionedIopsNotAvailableInAZFault(_) => {
+                "ProvisionedIopsNotAvailableInAZFault"
+            }
+            RestoreDBInstanceFromS3Error::StorageQuotaExceededFault(_) => "StorageQuotaExceeded",
+            RestoreDBInstanceFromS3Error::StorageTypeNotSupportedFault(_) => {
+                "StorageTypeNotSupported"
+            }
+        }
+    }
+}
 /// Errors returned by RestoreDBInstanceToPointInTime
 #[derive(Debug, PartialEq)]
 pub enum RestoreDBInstanceToPointInTimeError {
@@ -28969,6 +30602,70 @@ impl fmt::Display for RestoreDBInstanceToPointInTimeError {
     }
 }
 impl Error for RestoreDBInstanceToPointInTimeError {}
+impl ServiceErrorCode for RestoreDBInstanceToPointInTimeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreDBInstanceToPointInTimeError::AuthorizationNotFoundFault(_) => {
+                "AuthorizationNotFound"
+            }
+            RestoreDBInstanceToPointInTimeError::BackupPolicyNotFoundFault(_) => {
+                "BackupPolicyNotFoundFault"
+            }
+            RestoreDBInstanceToPointInTimeError::DBInstanceAlreadyExistsFault(_) => {
+                "DBInstanceAlreadyExists"
+            }
+            RestoreDBInstanceToPointInTimeError::DBInstanceAutomatedBackupNotFoundFault(_) => {
+                "DBInstanceAutomatedBackupNotFound"
+            }
+            RestoreDBInstanceToPointInTimeError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            RestoreDBInstanceToPointInTimeError::DBParameterGroupNotFoundFault(_) => {
+                "DBParameterGroupNotFound"
+            }
+            RestoreDBInstanceToPointInTimeError::DBSecurityGroupNotFoundFault(_) => {
+                "DBSecurityGroupNotFound"
+            }
+            RestoreDBInstanceToPointInTimeError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            RestoreDBInstanceToPointInTimeError::DBSubnetGroupNotFoundFault(_) => {
+                "DBSubnetGroupNotFoundFault"
+            }
+            RestoreDBInstanceToPointInTimeError::DomainNotFoundFault(_) => "DomainNotFoundFault",
+            RestoreDBInstanceToPointInTimeError::InstanceQuotaExceededFault(_) => {
+                "InstanceQuotaExceeded"
+            }
+            RestoreDBInstanceToPointInTimeError::InsufficientDBInstanceCapacityFault(_) => {
+                "InsufficientDBInstanceCapacity"
+            }
+            RestoreDBInstanceToPointInTimeError::InvalidDBInstanceStateFault(_) => {
+                "InvalidDBInstanceState"
+            }
+            RestoreDBInstanceToPointInTimeError::InvalidRestoreFault(_) => "InvalidRestoreFault",
+            RestoreDBInstanceToPointInTimeError::InvalidSubnet(_) => "InvalidSubnet",
+            RestoreDBInstanceToPointInTimeError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            RestoreDBInstanceToPointInTimeError::KMSKeyNotAccessibleFault(_) => {
+                "KMSKeyNotAccessibleFault"
+            }
+            RestoreDBInstanceToPointInTimeError::OptionGroupNotFoundFault(_) => {
+                "OptionGroupNotFoundFault"
+            }
+            RestoreDBInstanceToPointInTimeError::PointInTimeRestoreNotEnabledFault(_) => {
+                "PointInTimeRestoreNotEnabled"
+            }
+            RestoreDBInstanceToPointInTimeError::ProvisionedIopsNotAvailableInAZFault(_) => {
+                "ProvisionedIopsNotAvailableInAZFault"
+            }
+            RestoreDBInstanceToPointInTimeError::StorageQuotaExceededFault(_) => {
+                "StorageQuotaExceeded"
+            }
+            RestoreDBInstanceToPointInTimeError::StorageTypeNotSupportedFault(_) => {
+                "StorageTypeNotSupported"
+            }
+        }
+    }
+}
 /// Errors returned by RevokeDBSecurityGroupIngress
 #[derive(Debug, PartialEq)]
 pub enum RevokeDBSecurityGroupIngressError {
@@ -29043,6 +30740,21 @@ impl fmt::Display for RevokeDBSecurityGroupIngressError {
     }
 }
 impl Error for RevokeDBSecurityGroupIngressError {}
+impl ServiceErrorCode for RevokeDBSecurityGroupIngressError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RevokeDBSecurityGroupIngressError::AuthorizationNotFoundFault(_) => {
+                "AuthorizationNotFound"
+            }
+            RevokeDBSecurityGroupIngressError::DBSecurityGroupNotFoundFault(_) => {
+                "DBSecurityGroupNotFound"
+            }
+            RevokeDBSecurityGroupIngressError::InvalidDBSecurityGroupStateFault(_) => {
+                "InvalidDBSecurityGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by StartActivityStream
 #[derive(Debug, PartialEq)]
 pub enum StartActivityStreamError {
@@ -29137,6 +30849,18 @@ impl fmt::Display for StartActivityStreamError {
     }
 }
 impl Error for StartActivityStreamError {}
+impl ServiceErrorCode for StartActivityStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartActivityStreamError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            StartActivityStreamError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            StartActivityStreamError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            StartActivityStreamError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            StartActivityStreamError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            StartActivityStreamError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by StartDBCluster
 #[derive(Debug, PartialEq)]
 pub enum StartDBClusterError {
@@ -29197,6 +30921,15 @@ impl fmt::Display for StartDBClusterError {
     }
 }
 impl Error for StartDBClusterError {}
+impl ServiceErrorCode for StartDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            StartDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            StartDBClusterError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+        }
+    }
+}
 /// Errors returned by StartDBInstance
 #[derive(Debug, PartialEq)]
 pub enum StartDBInstanceError {
@@ -29329,6 +31062,27 @@ impl fmt::Display for StartDBInstanceError {
     }
 }
 impl Error for StartDBInstanceError {}
+impl ServiceErrorCode for StartDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDBInstanceError::AuthorizationNotFoundFault(_) => "AuthorizationNotFound",
+            StartDBInstanceError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            StartDBInstanceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            StartDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(_) => {
+                "DBSubnetGroupDoesNotCoverEnoughAZs"
+            }
+            StartDBInstanceError::DBSubnetGroupNotFoundFault(_) => "DBSubnetGroupNotFoundFault",
+            StartDBInstanceError::InsufficientDBInstanceCapacityFault(_) => {
+                "InsufficientDBInstanceCapacity"
+            }
+            StartDBInstanceError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            StartDBInstanceError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            StartDBInstanceError::InvalidSubnet(_) => "InvalidSubnet",
+            StartDBInstanceError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            StartDBInstanceError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+        }
+    }
+}
 /// Errors returned by StartDBInstanceAutomatedBackupsReplication
 #[derive(Debug, PartialEq)]
 pub enum StartDBInstanceAutomatedBackupsReplicationError {
@@ -29382,6 +31136,17 @@ StartDBInstanceAutomatedBackupsReplicationError::StorageTypeNotSupportedFault(re
     }
 }
 impl Error for StartDBInstanceAutomatedBackupsReplicationError {}
+impl ServiceErrorCode for StartDBInstanceAutomatedBackupsReplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDBInstanceAutomatedBackupsReplicationError::DBInstanceAutomatedBackupQuotaExceededFault(_) => "DBInstanceAutomatedBackupQuotaExceeded",
+            StartDBInstanceAutomatedBackupsReplicationError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            StartDBInstanceAutomatedBackupsReplicationError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            StartDBInstanceAutomatedBackupsReplicationError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+            StartDBInstanceAutomatedBackupsReplicationError::StorageTypeNotSupportedFault(_) => "StorageTypeNotSupported",
+        }
+    }
+}
 /// Errors returned by StartExportTask
 #[derive(Debug, PartialEq)]
 pub enum StartExportTaskError {
@@ -29504,6 +31269,23 @@ impl fmt::Display for StartExportTaskError {
     }
 }
 impl Error for StartExportTaskError {}
+impl ServiceErrorCode for StartExportTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartExportTaskError::DBClusterSnapshotNotFoundFault(_) => {
+                "DBClusterSnapshotNotFoundFault"
+            }
+            StartExportTaskError::DBSnapshotNotFoundFault(_) => "DBSnapshotNotFound",
+            StartExportTaskError::ExportTaskAlreadyExistsFault(_) => "ExportTaskAlreadyExists",
+            StartExportTaskError::IamRoleMissingPermissionsFault(_) => "IamRoleMissingPermissions",
+            StartExportTaskError::IamRoleNotFoundFault(_) => "IamRoleNotFound",
+            StartExportTaskError::InvalidExportOnlyFault(_) => "InvalidExportOnly",
+            StartExportTaskError::InvalidExportSourceStateFault(_) => "InvalidExportSourceState",
+            StartExportTaskError::InvalidS3BucketFault(_) => "InvalidS3BucketFault",
+            StartExportTaskError::KMSKeyNotAccessibleFault(_) => "KMSKeyNotAccessibleFault",
+        }
+    }
+}
 /// Errors returned by StopActivityStream
 #[derive(Debug, PartialEq)]
 pub enum StopActivityStreamError {
@@ -29588,6 +31370,17 @@ impl fmt::Display for StopActivityStreamError {
     }
 }
 impl Error for StopActivityStreamError {}
+impl ServiceErrorCode for StopActivityStreamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopActivityStreamError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            StopActivityStreamError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            StopActivityStreamError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            StopActivityStreamError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            StopActivityStreamError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by StopDBCluster
 #[derive(Debug, PartialEq)]
 pub enum StopDBClusterError {
@@ -29648,6 +31441,15 @@ impl fmt::Display for StopDBClusterError {
     }
 }
 impl Error for StopDBClusterError {}
+impl ServiceErrorCode for StopDBClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopDBClusterError::DBClusterNotFoundFault(_) => "DBClusterNotFoundFault",
+            StopDBClusterError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            StopDBClusterError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+        }
+    }
+}
 /// Errors returned by StopDBInstance
 #[derive(Debug, PartialEq)]
 pub enum StopDBInstanceError {
@@ -29724,6 +31526,17 @@ impl fmt::Display for StopDBInstanceError {
     }
 }
 impl Error for StopDBInstanceError {}
+impl ServiceErrorCode for StopDBInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopDBInstanceError::DBInstanceNotFoundFault(_) => "DBInstanceNotFound",
+            StopDBInstanceError::DBSnapshotAlreadyExistsFault(_) => "DBSnapshotAlreadyExists",
+            StopDBInstanceError::InvalidDBClusterStateFault(_) => "InvalidDBClusterStateFault",
+            StopDBInstanceError::InvalidDBInstanceStateFault(_) => "InvalidDBInstanceState",
+            StopDBInstanceError::SnapshotQuotaExceededFault(_) => "SnapshotQuotaExceeded",
+        }
+    }
+}
 /// Errors returned by StopDBInstanceAutomatedBackupsReplication
 #[derive(Debug, PartialEq)]
 pub enum StopDBInstanceAutomatedBackupsReplicationError {
@@ -29784,6 +31597,18 @@ impl fmt::Display for StopDBInstanceAutomatedBackupsReplicationError {
     }
 }
 impl Error for StopDBInstanceAutomatedBackupsReplicationError {}
+impl ServiceErrorCode for StopDBInstanceAutomatedBackupsReplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopDBInstanceAutomatedBackupsReplicationError::DBInstanceNotFoundFault(_) => {
+                "DBInstanceNotFound"
+            }
+            StopDBInstanceAutomatedBackupsReplicationError::InvalidDBInstanceStateFault(_) => {
+                "InvalidDBInstanceState"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon RDS API. Amazon RDS clients implement this trait.
 #[async_trait]
 pub trait Rds {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -12029,6 +12029,29 @@ impl fmt::Display for AcceptReservedNodeExchangeError {
     }
 }
 impl Error for AcceptReservedNodeExchangeError {}
+impl ServiceErrorCode for AcceptReservedNodeExchangeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptReservedNodeExchangeError::DependentServiceUnavailableFault(_) => {
+                "DependentServiceUnavailableFault"
+            }
+            AcceptReservedNodeExchangeError::InvalidReservedNodeStateFault(_) => {
+                "InvalidReservedNodeState"
+            }
+            AcceptReservedNodeExchangeError::ReservedNodeAlreadyExistsFault(_) => {
+                "ReservedNodeAlreadyExists"
+            }
+            AcceptReservedNodeExchangeError::ReservedNodeAlreadyMigratedFault(_) => {
+                "ReservedNodeAlreadyMigrated"
+            }
+            AcceptReservedNodeExchangeError::ReservedNodeNotFoundFault(_) => "ReservedNodeNotFound",
+            AcceptReservedNodeExchangeError::ReservedNodeOfferingNotFoundFault(_) => {
+                "ReservedNodeOfferingNotFound"
+            }
+            AcceptReservedNodeExchangeError::UnsupportedOperationFault(_) => "UnsupportedOperation",
+        }
+    }
+}
 /// Errors returned by AuthorizeClusterSecurityGroupIngress
 #[derive(Debug, PartialEq)]
 pub enum AuthorizeClusterSecurityGroupIngressError {
@@ -12087,6 +12110,24 @@ impl fmt::Display for AuthorizeClusterSecurityGroupIngressError {
     }
 }
 impl Error for AuthorizeClusterSecurityGroupIngressError {}
+impl ServiceErrorCode for AuthorizeClusterSecurityGroupIngressError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AuthorizeClusterSecurityGroupIngressError::AuthorizationAlreadyExistsFault(_) => {
+                "AuthorizationAlreadyExists"
+            }
+            AuthorizeClusterSecurityGroupIngressError::AuthorizationQuotaExceededFault(_) => {
+                "AuthorizationQuotaExceeded"
+            }
+            AuthorizeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(_) => {
+                "ClusterSecurityGroupNotFound"
+            }
+            AuthorizeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(_) => {
+                "InvalidClusterSecurityGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by AuthorizeSnapshotAccess
 #[derive(Debug, PartialEq)]
 pub enum AuthorizeSnapshotAccessError {
@@ -12191,6 +12232,28 @@ impl fmt::Display for AuthorizeSnapshotAccessError {
     }
 }
 impl Error for AuthorizeSnapshotAccessError {}
+impl ServiceErrorCode for AuthorizeSnapshotAccessError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AuthorizeSnapshotAccessError::AuthorizationAlreadyExistsFault(_) => {
+                "AuthorizationAlreadyExists"
+            }
+            AuthorizeSnapshotAccessError::AuthorizationQuotaExceededFault(_) => {
+                "AuthorizationQuotaExceeded"
+            }
+            AuthorizeSnapshotAccessError::ClusterSnapshotNotFoundFault(_) => {
+                "ClusterSnapshotNotFound"
+            }
+            AuthorizeSnapshotAccessError::DependentServiceRequestThrottlingFault(_) => {
+                "DependentServiceRequestThrottlingFault"
+            }
+            AuthorizeSnapshotAccessError::InvalidClusterSnapshotStateFault(_) => {
+                "InvalidClusterSnapshotState"
+            }
+            AuthorizeSnapshotAccessError::LimitExceededFault(_) => "LimitExceededFault",
+        }
+    }
+}
 /// Errors returned by BatchDeleteClusterSnapshots
 #[derive(Debug, PartialEq)]
 pub enum BatchDeleteClusterSnapshotsError {
@@ -12241,6 +12304,15 @@ impl fmt::Display for BatchDeleteClusterSnapshotsError {
     }
 }
 impl Error for BatchDeleteClusterSnapshotsError {}
+impl ServiceErrorCode for BatchDeleteClusterSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDeleteClusterSnapshotsError::BatchDeleteRequestSizeExceededFault(_) => {
+                "BatchDeleteRequestSizeExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by BatchModifyClusterSnapshots
 #[derive(Debug, PartialEq)]
 pub enum BatchModifyClusterSnapshotsError {
@@ -12289,6 +12361,18 @@ impl fmt::Display for BatchModifyClusterSnapshotsError {
     }
 }
 impl Error for BatchModifyClusterSnapshotsError {}
+impl ServiceErrorCode for BatchModifyClusterSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchModifyClusterSnapshotsError::BatchModifyClusterSnapshotsLimitExceededFault(_) => {
+                "BatchModifyClusterSnapshotsLimitExceededFault"
+            }
+            BatchModifyClusterSnapshotsError::InvalidRetentionPeriodFault(_) => {
+                "InvalidRetentionPeriodFault"
+            }
+        }
+    }
+}
 /// Errors returned by CancelResize
 #[derive(Debug, PartialEq)]
 pub enum CancelResizeError {
@@ -12357,6 +12441,16 @@ impl fmt::Display for CancelResizeError {
     }
 }
 impl Error for CancelResizeError {}
+impl ServiceErrorCode for CancelResizeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelResizeError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            CancelResizeError::InvalidClusterStateFault(_) => "InvalidClusterState",
+            CancelResizeError::ResizeNotFoundFault(_) => "ResizeNotFound",
+            CancelResizeError::UnsupportedOperationFault(_) => "UnsupportedOperation",
+        }
+    }
+}
 /// Errors returned by CopyClusterSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CopyClusterSnapshotError {
@@ -12453,6 +12547,25 @@ impl fmt::Display for CopyClusterSnapshotError {
     }
 }
 impl Error for CopyClusterSnapshotError {}
+impl ServiceErrorCode for CopyClusterSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyClusterSnapshotError::ClusterSnapshotAlreadyExistsFault(_) => {
+                "ClusterSnapshotAlreadyExists"
+            }
+            CopyClusterSnapshotError::ClusterSnapshotNotFoundFault(_) => "ClusterSnapshotNotFound",
+            CopyClusterSnapshotError::ClusterSnapshotQuotaExceededFault(_) => {
+                "ClusterSnapshotQuotaExceeded"
+            }
+            CopyClusterSnapshotError::InvalidClusterSnapshotStateFault(_) => {
+                "InvalidClusterSnapshotState"
+            }
+            CopyClusterSnapshotError::InvalidRetentionPeriodFault(_) => {
+                "InvalidRetentionPeriodFault"
+            }
+        }
+    }
+}
 /// Errors returned by CreateCluster
 #[derive(Debug, PartialEq)]
 pub enum CreateClusterError {
@@ -12701,6 +12814,50 @@ impl fmt::Display for CreateClusterError {
     }
 }
 impl Error for CreateClusterError {}
+impl ServiceErrorCode for CreateClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateClusterError::ClusterAlreadyExistsFault(_) => "ClusterAlreadyExists",
+            CreateClusterError::ClusterParameterGroupNotFoundFault(_) => {
+                "ClusterParameterGroupNotFound"
+            }
+            CreateClusterError::ClusterQuotaExceededFault(_) => "ClusterQuotaExceeded",
+            CreateClusterError::ClusterSecurityGroupNotFoundFault(_) => {
+                "ClusterSecurityGroupNotFound"
+            }
+            CreateClusterError::ClusterSubnetGroupNotFoundFault(_) => {
+                "ClusterSubnetGroupNotFoundFault"
+            }
+            CreateClusterError::DependentServiceRequestThrottlingFault(_) => {
+                "DependentServiceRequestThrottlingFault"
+            }
+            CreateClusterError::HsmClientCertificateNotFoundFault(_) => {
+                "HsmClientCertificateNotFoundFault"
+            }
+            CreateClusterError::HsmConfigurationNotFoundFault(_) => "HsmConfigurationNotFoundFault",
+            CreateClusterError::InsufficientClusterCapacityFault(_) => {
+                "InsufficientClusterCapacity"
+            }
+            CreateClusterError::InvalidClusterSubnetGroupStateFault(_) => {
+                "InvalidClusterSubnetGroupStateFault"
+            }
+            CreateClusterError::InvalidClusterTrackFault(_) => "InvalidClusterTrack",
+            CreateClusterError::InvalidElasticIpFault(_) => "InvalidElasticIpFault",
+            CreateClusterError::InvalidRetentionPeriodFault(_) => "InvalidRetentionPeriodFault",
+            CreateClusterError::InvalidSubnet(_) => "InvalidSubnet",
+            CreateClusterError::InvalidTagFault(_) => "InvalidTagFault",
+            CreateClusterError::InvalidVPCNetworkStateFault(_) => "InvalidVPCNetworkStateFault",
+            CreateClusterError::LimitExceededFault(_) => "LimitExceededFault",
+            CreateClusterError::NumberOfNodesPerClusterLimitExceededFault(_) => {
+                "NumberOfNodesPerClusterLimitExceeded"
+            }
+            CreateClusterError::NumberOfNodesQuotaExceededFault(_) => "NumberOfNodesQuotaExceeded",
+            CreateClusterError::SnapshotScheduleNotFoundFault(_) => "SnapshotScheduleNotFound",
+            CreateClusterError::TagLimitExceededFault(_) => "TagLimitExceededFault",
+            CreateClusterError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by CreateClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateClusterParameterGroupError {
@@ -12779,6 +12936,20 @@ impl fmt::Display for CreateClusterParameterGroupError {
     }
 }
 impl Error for CreateClusterParameterGroupError {}
+impl ServiceErrorCode for CreateClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateClusterParameterGroupError::ClusterParameterGroupAlreadyExistsFault(_) => {
+                "ClusterParameterGroupAlreadyExists"
+            }
+            CreateClusterParameterGroupError::ClusterParameterGroupQuotaExceededFault(_) => {
+                "ClusterParameterGroupQuotaExceeded"
+            }
+            CreateClusterParameterGroupError::InvalidTagFault(_) => "InvalidTagFault",
+            CreateClusterParameterGroupError::TagLimitExceededFault(_) => "TagLimitExceededFault",
+        }
+    }
+}
 /// Errors returned by CreateClusterSecurityGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateClusterSecurityGroupError {
@@ -12861,6 +13032,20 @@ impl fmt::Display for CreateClusterSecurityGroupError {
     }
 }
 impl Error for CreateClusterSecurityGroupError {}
+impl ServiceErrorCode for CreateClusterSecurityGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateClusterSecurityGroupError::ClusterSecurityGroupAlreadyExistsFault(_) => {
+                "ClusterSecurityGroupAlreadyExists"
+            }
+            CreateClusterSecurityGroupError::ClusterSecurityGroupQuotaExceededFault(_) => {
+                "QuotaExceeded.ClusterSecurityGroup"
+            }
+            CreateClusterSecurityGroupError::InvalidTagFault(_) => "InvalidTagFault",
+            CreateClusterSecurityGroupError::TagLimitExceededFault(_) => "TagLimitExceededFault",
+        }
+    }
+}
 /// Errors returned by CreateClusterSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateClusterSnapshotError {
@@ -12969,6 +13154,25 @@ impl fmt::Display for CreateClusterSnapshotError {
     }
 }
 impl Error for CreateClusterSnapshotError {}
+impl ServiceErrorCode for CreateClusterSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateClusterSnapshotError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            CreateClusterSnapshotError::ClusterSnapshotAlreadyExistsFault(_) => {
+                "ClusterSnapshotAlreadyExists"
+            }
+            CreateClusterSnapshotError::ClusterSnapshotQuotaExceededFault(_) => {
+                "ClusterSnapshotQuotaExceeded"
+            }
+            CreateClusterSnapshotError::InvalidClusterStateFault(_) => "InvalidClusterState",
+            CreateClusterSnapshotError::InvalidRetentionPeriodFault(_) => {
+                "InvalidRetentionPeriodFault"
+            }
+            CreateClusterSnapshotError::InvalidTagFault(_) => "InvalidTagFault",
+            CreateClusterSnapshotError::TagLimitExceededFault(_) => "TagLimitExceededFault",
+        }
+    }
+}
 /// Errors returned by CreateClusterSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateClusterSubnetGroupError {
@@ -13093,6 +13297,28 @@ impl fmt::Display for CreateClusterSubnetGroupError {
     }
 }
 impl Error for CreateClusterSubnetGroupError {}
+impl ServiceErrorCode for CreateClusterSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateClusterSubnetGroupError::ClusterSubnetGroupAlreadyExistsFault(_) => {
+                "ClusterSubnetGroupAlreadyExists"
+            }
+            CreateClusterSubnetGroupError::ClusterSubnetGroupQuotaExceededFault(_) => {
+                "ClusterSubnetGroupQuotaExceeded"
+            }
+            CreateClusterSubnetGroupError::ClusterSubnetQuotaExceededFault(_) => {
+                "ClusterSubnetQuotaExceededFault"
+            }
+            CreateClusterSubnetGroupError::DependentServiceRequestThrottlingFault(_) => {
+                "DependentServiceRequestThrottlingFault"
+            }
+            CreateClusterSubnetGroupError::InvalidSubnet(_) => "InvalidSubnet",
+            CreateClusterSubnetGroupError::InvalidTagFault(_) => "InvalidTagFault",
+            CreateClusterSubnetGroupError::TagLimitExceededFault(_) => "TagLimitExceededFault",
+            CreateClusterSubnetGroupError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by CreateEventSubscription
 #[derive(Debug, PartialEq)]
 pub enum CreateEventSubscriptionError {
@@ -13251,6 +13477,33 @@ impl fmt::Display for CreateEventSubscriptionError {
     }
 }
 impl Error for CreateEventSubscriptionError {}
+impl ServiceErrorCode for CreateEventSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(_) => {
+                "EventSubscriptionQuotaExceeded"
+            }
+            CreateEventSubscriptionError::InvalidTagFault(_) => "InvalidTagFault",
+            CreateEventSubscriptionError::SNSInvalidTopicFault(_) => "SNSInvalidTopic",
+            CreateEventSubscriptionError::SNSNoAuthorizationFault(_) => "SNSNoAuthorization",
+            CreateEventSubscriptionError::SNSTopicArnNotFoundFault(_) => "SNSTopicArnNotFound",
+            CreateEventSubscriptionError::SourceNotFoundFault(_) => "SourceNotFound",
+            CreateEventSubscriptionError::SubscriptionAlreadyExistFault(_) => {
+                "SubscriptionAlreadyExist"
+            }
+            CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(_) => {
+                "SubscriptionCategoryNotFound"
+            }
+            CreateEventSubscriptionError::SubscriptionEventIdNotFoundFault(_) => {
+                "SubscriptionEventIdNotFound"
+            }
+            CreateEventSubscriptionError::SubscriptionSeverityNotFoundFault(_) => {
+                "SubscriptionSeverityNotFound"
+            }
+            CreateEventSubscriptionError::TagLimitExceededFault(_) => "TagLimitExceededFault",
+        }
+    }
+}
 /// Errors returned by CreateHsmClientCertificate
 #[derive(Debug, PartialEq)]
 pub enum CreateHsmClientCertificateError {
@@ -13333,6 +13586,20 @@ impl fmt::Display for CreateHsmClientCertificateError {
     }
 }
 impl Error for CreateHsmClientCertificateError {}
+impl ServiceErrorCode for CreateHsmClientCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHsmClientCertificateError::HsmClientCertificateAlreadyExistsFault(_) => {
+                "HsmClientCertificateAlreadyExistsFault"
+            }
+            CreateHsmClientCertificateError::HsmClientCertificateQuotaExceededFault(_) => {
+                "HsmClientCertificateQuotaExceededFault"
+            }
+            CreateHsmClientCertificateError::InvalidTagFault(_) => "InvalidTagFault",
+            CreateHsmClientCertificateError::TagLimitExceededFault(_) => "TagLimitExceededFault",
+        }
+    }
+}
 /// Errors returned by CreateHsmConfiguration
 #[derive(Debug, PartialEq)]
 pub enum CreateHsmConfigurationError {
@@ -13411,6 +13678,20 @@ impl fmt::Display for CreateHsmConfigurationError {
     }
 }
 impl Error for CreateHsmConfigurationError {}
+impl ServiceErrorCode for CreateHsmConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHsmConfigurationError::HsmConfigurationAlreadyExistsFault(_) => {
+                "HsmConfigurationAlreadyExistsFault"
+            }
+            CreateHsmConfigurationError::HsmConfigurationQuotaExceededFault(_) => {
+                "HsmConfigurationQuotaExceededFault"
+            }
+            CreateHsmConfigurationError::InvalidTagFault(_) => "InvalidTagFault",
+            CreateHsmConfigurationError::TagLimitExceededFault(_) => "TagLimitExceededFault",
+        }
+    }
+}
 /// Errors returned by CreateScheduledAction
 #[derive(Debug, PartialEq)]
 pub enum CreateScheduledActionError {
@@ -13511,6 +13792,24 @@ impl fmt::Display for CreateScheduledActionError {
     }
 }
 impl Error for CreateScheduledActionError {}
+impl ServiceErrorCode for CreateScheduledActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateScheduledActionError::InvalidScheduleFault(_) => "InvalidSchedule",
+            CreateScheduledActionError::InvalidScheduledActionFault(_) => "InvalidScheduledAction",
+            CreateScheduledActionError::ScheduledActionAlreadyExistsFault(_) => {
+                "ScheduledActionAlreadyExists"
+            }
+            CreateScheduledActionError::ScheduledActionQuotaExceededFault(_) => {
+                "ScheduledActionQuotaExceeded"
+            }
+            CreateScheduledActionError::ScheduledActionTypeUnsupportedFault(_) => {
+                "ScheduledActionTypeUnsupported"
+            }
+            CreateScheduledActionError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by CreateSnapshotCopyGrant
 #[derive(Debug, PartialEq)]
 pub enum CreateSnapshotCopyGrantError {
@@ -13611,6 +13910,24 @@ impl fmt::Display for CreateSnapshotCopyGrantError {
     }
 }
 impl Error for CreateSnapshotCopyGrantError {}
+impl ServiceErrorCode for CreateSnapshotCopyGrantError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSnapshotCopyGrantError::DependentServiceRequestThrottlingFault(_) => {
+                "DependentServiceRequestThrottlingFault"
+            }
+            CreateSnapshotCopyGrantError::InvalidTagFault(_) => "InvalidTagFault",
+            CreateSnapshotCopyGrantError::LimitExceededFault(_) => "LimitExceededFault",
+            CreateSnapshotCopyGrantError::SnapshotCopyGrantAlreadyExistsFault(_) => {
+                "SnapshotCopyGrantAlreadyExistsFault"
+            }
+            CreateSnapshotCopyGrantError::SnapshotCopyGrantQuotaExceededFault(_) => {
+                "SnapshotCopyGrantQuotaExceededFault"
+            }
+            CreateSnapshotCopyGrantError::TagLimitExceededFault(_) => "TagLimitExceededFault",
+        }
+    }
+}
 /// Errors returned by CreateSnapshotSchedule
 #[derive(Debug, PartialEq)]
 pub enum CreateSnapshotScheduleError {
@@ -13709,6 +14026,24 @@ impl fmt::Display for CreateSnapshotScheduleError {
     }
 }
 impl Error for CreateSnapshotScheduleError {}
+impl ServiceErrorCode for CreateSnapshotScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSnapshotScheduleError::InvalidScheduleFault(_) => "InvalidSchedule",
+            CreateSnapshotScheduleError::InvalidTagFault(_) => "InvalidTagFault",
+            CreateSnapshotScheduleError::ScheduleDefinitionTypeUnsupportedFault(_) => {
+                "ScheduleDefinitionTypeUnsupported"
+            }
+            CreateSnapshotScheduleError::SnapshotScheduleAlreadyExistsFault(_) => {
+                "SnapshotScheduleAlreadyExists"
+            }
+            CreateSnapshotScheduleError::SnapshotScheduleQuotaExceededFault(_) => {
+                "SnapshotScheduleQuotaExceeded"
+            }
+            CreateSnapshotScheduleError::TagLimitExceededFault(_) => "TagLimitExceededFault",
+        }
+    }
+}
 /// Errors returned by CreateTags
 #[derive(Debug, PartialEq)]
 pub enum CreateTagsError {
@@ -13777,6 +14112,16 @@ impl fmt::Display for CreateTagsError {
     }
 }
 impl Error for CreateTagsError {}
+impl ServiceErrorCode for CreateTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTagsError::InvalidClusterStateFault(_) => "InvalidClusterState",
+            CreateTagsError::InvalidTagFault(_) => "InvalidTagFault",
+            CreateTagsError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+            CreateTagsError::TagLimitExceededFault(_) => "TagLimitExceededFault",
+        }
+    }
+}
 /// Errors returned by CreateUsageLimit
 #[derive(Debug, PartialEq)]
 pub enum CreateUsageLimitError {
@@ -13873,6 +14218,19 @@ impl fmt::Display for CreateUsageLimitError {
     }
 }
 impl Error for CreateUsageLimitError {}
+impl ServiceErrorCode for CreateUsageLimitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUsageLimitError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            CreateUsageLimitError::InvalidClusterStateFault(_) => "InvalidClusterState",
+            CreateUsageLimitError::InvalidUsageLimitFault(_) => "InvalidUsageLimit",
+            CreateUsageLimitError::LimitExceededFault(_) => "LimitExceededFault",
+            CreateUsageLimitError::TagLimitExceededFault(_) => "TagLimitExceededFault",
+            CreateUsageLimitError::UnsupportedOperationFault(_) => "UnsupportedOperation",
+            CreateUsageLimitError::UsageLimitAlreadyExistsFault(_) => "UsageLimitAlreadyExists",
+        }
+    }
+}
 /// Errors returned by DeleteCluster
 #[derive(Debug, PartialEq)]
 pub enum DeleteClusterError {
@@ -13957,6 +14315,21 @@ impl fmt::Display for DeleteClusterError {
     }
 }
 impl Error for DeleteClusterError {}
+impl ServiceErrorCode for DeleteClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteClusterError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            DeleteClusterError::ClusterSnapshotAlreadyExistsFault(_) => {
+                "ClusterSnapshotAlreadyExists"
+            }
+            DeleteClusterError::ClusterSnapshotQuotaExceededFault(_) => {
+                "ClusterSnapshotQuotaExceeded"
+            }
+            DeleteClusterError::InvalidClusterStateFault(_) => "InvalidClusterState",
+            DeleteClusterError::InvalidRetentionPeriodFault(_) => "InvalidRetentionPeriodFault",
+        }
+    }
+}
 /// Errors returned by DeleteClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteClusterParameterGroupError {
@@ -14017,6 +14390,18 @@ impl fmt::Display for DeleteClusterParameterGroupError {
     }
 }
 impl Error for DeleteClusterParameterGroupError {}
+impl ServiceErrorCode for DeleteClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteClusterParameterGroupError::ClusterParameterGroupNotFoundFault(_) => {
+                "ClusterParameterGroupNotFound"
+            }
+            DeleteClusterParameterGroupError::InvalidClusterParameterGroupStateFault(_) => {
+                "InvalidClusterParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteClusterSecurityGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteClusterSecurityGroupError {
@@ -14079,6 +14464,18 @@ impl fmt::Display for DeleteClusterSecurityGroupError {
     }
 }
 impl Error for DeleteClusterSecurityGroupError {}
+impl ServiceErrorCode for DeleteClusterSecurityGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteClusterSecurityGroupError::ClusterSecurityGroupNotFoundFault(_) => {
+                "ClusterSecurityGroupNotFound"
+            }
+            DeleteClusterSecurityGroupError::InvalidClusterSecurityGroupStateFault(_) => {
+                "InvalidClusterSecurityGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteClusterSnapshot
 #[derive(Debug, PartialEq)]
 pub enum DeleteClusterSnapshotError {
@@ -14139,6 +14536,18 @@ impl fmt::Display for DeleteClusterSnapshotError {
     }
 }
 impl Error for DeleteClusterSnapshotError {}
+impl ServiceErrorCode for DeleteClusterSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteClusterSnapshotError::ClusterSnapshotNotFoundFault(_) => {
+                "ClusterSnapshotNotFound"
+            }
+            DeleteClusterSnapshotError::InvalidClusterSnapshotStateFault(_) => {
+                "InvalidClusterSnapshotState"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteClusterSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteClusterSubnetGroupError {
@@ -14211,6 +14620,21 @@ impl fmt::Display for DeleteClusterSubnetGroupError {
     }
 }
 impl Error for DeleteClusterSubnetGroupError {}
+impl ServiceErrorCode for DeleteClusterSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteClusterSubnetGroupError::ClusterSubnetGroupNotFoundFault(_) => {
+                "ClusterSubnetGroupNotFoundFault"
+            }
+            DeleteClusterSubnetGroupError::InvalidClusterSubnetGroupStateFault(_) => {
+                "InvalidClusterSubnetGroupStateFault"
+            }
+            DeleteClusterSubnetGroupError::InvalidClusterSubnetStateFault(_) => {
+                "InvalidClusterSubnetStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteEventSubscription
 #[derive(Debug, PartialEq)]
 pub enum DeleteEventSubscriptionError {
@@ -14271,6 +14695,16 @@ impl fmt::Display for DeleteEventSubscriptionError {
     }
 }
 impl Error for DeleteEventSubscriptionError {}
+impl ServiceErrorCode for DeleteEventSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEventSubscriptionError::InvalidSubscriptionStateFault(_) => {
+                "InvalidSubscriptionStateFault"
+            }
+            DeleteEventSubscriptionError::SubscriptionNotFoundFault(_) => "SubscriptionNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteHsmClientCertificate
 #[derive(Debug, PartialEq)]
 pub enum DeleteHsmClientCertificateError {
@@ -14333,6 +14767,18 @@ impl fmt::Display for DeleteHsmClientCertificateError {
     }
 }
 impl Error for DeleteHsmClientCertificateError {}
+impl ServiceErrorCode for DeleteHsmClientCertificateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteHsmClientCertificateError::HsmClientCertificateNotFoundFault(_) => {
+                "HsmClientCertificateNotFoundFault"
+            }
+            DeleteHsmClientCertificateError::InvalidHsmClientCertificateStateFault(_) => {
+                "InvalidHsmClientCertificateStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteHsmConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteHsmConfigurationError {
@@ -14393,6 +14839,18 @@ impl fmt::Display for DeleteHsmConfigurationError {
     }
 }
 impl Error for DeleteHsmConfigurationError {}
+impl ServiceErrorCode for DeleteHsmConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteHsmConfigurationError::HsmConfigurationNotFoundFault(_) => {
+                "HsmConfigurationNotFoundFault"
+            }
+            DeleteHsmConfigurationError::InvalidHsmConfigurationStateFault(_) => {
+                "InvalidHsmConfigurationStateFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteScheduledAction
 #[derive(Debug, PartialEq)]
 pub enum DeleteScheduledActionError {
@@ -14449,6 +14907,16 @@ impl fmt::Display for DeleteScheduledActionError {
     }
 }
 impl Error for DeleteScheduledActionError {}
+impl ServiceErrorCode for DeleteScheduledActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteScheduledActionError::ScheduledActionNotFoundFault(_) => {
+                "ScheduledActionNotFound"
+            }
+            DeleteScheduledActionError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by DeleteSnapshotCopyGrant
 #[derive(Debug, PartialEq)]
 pub enum DeleteSnapshotCopyGrantError {
@@ -14509,6 +14977,18 @@ impl fmt::Display for DeleteSnapshotCopyGrantError {
     }
 }
 impl Error for DeleteSnapshotCopyGrantError {}
+impl ServiceErrorCode for DeleteSnapshotCopyGrantError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSnapshotCopyGrantError::InvalidSnapshotCopyGrantStateFault(_) => {
+                "InvalidSnapshotCopyGrantStateFault"
+            }
+            DeleteSnapshotCopyGrantError::SnapshotCopyGrantNotFoundFault(_) => {
+                "SnapshotCopyGrantNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteSnapshotSchedule
 #[derive(Debug, PartialEq)]
 pub enum DeleteSnapshotScheduleError {
@@ -14569,6 +15049,18 @@ impl fmt::Display for DeleteSnapshotScheduleError {
     }
 }
 impl Error for DeleteSnapshotScheduleError {}
+impl ServiceErrorCode for DeleteSnapshotScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault(_) => {
+                "InvalidClusterSnapshotScheduleState"
+            }
+            DeleteSnapshotScheduleError::SnapshotScheduleNotFoundFault(_) => {
+                "SnapshotScheduleNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
 pub enum DeleteTagsError {
@@ -14621,6 +15113,14 @@ impl fmt::Display for DeleteTagsError {
     }
 }
 impl Error for DeleteTagsError {}
+impl ServiceErrorCode for DeleteTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTagsError::InvalidTagFault(_) => "InvalidTagFault",
+            DeleteTagsError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DeleteUsageLimit
 #[derive(Debug, PartialEq)]
 pub enum DeleteUsageLimitError {
@@ -14673,6 +15173,14 @@ impl fmt::Display for DeleteUsageLimitError {
     }
 }
 impl Error for DeleteUsageLimitError {}
+impl ServiceErrorCode for DeleteUsageLimitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUsageLimitError::UnsupportedOperationFault(_) => "UnsupportedOperation",
+            DeleteUsageLimitError::UsageLimitNotFoundFault(_) => "UsageLimitNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeAccountAttributes
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountAttributesError {}
@@ -14707,6 +15215,11 @@ impl fmt::Display for DescribeAccountAttributesError {
     }
 }
 impl Error for DescribeAccountAttributesError {}
+impl ServiceErrorCode for DescribeAccountAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeClusterDbRevisions
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterDbRevisionsError {
@@ -14769,6 +15282,14 @@ impl fmt::Display for DescribeClusterDbRevisionsError {
     }
 }
 impl Error for DescribeClusterDbRevisionsError {}
+impl ServiceErrorCode for DescribeClusterDbRevisionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClusterDbRevisionsError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            DescribeClusterDbRevisionsError::InvalidClusterStateFault(_) => "InvalidClusterState",
+        }
+    }
+}
 /// Errors returned by DescribeClusterParameterGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterParameterGroupsError {
@@ -14831,6 +15352,16 @@ impl fmt::Display for DescribeClusterParameterGroupsError {
     }
 }
 impl Error for DescribeClusterParameterGroupsError {}
+impl ServiceErrorCode for DescribeClusterParameterGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClusterParameterGroupsError::ClusterParameterGroupNotFoundFault(_) => {
+                "ClusterParameterGroupNotFound"
+            }
+            DescribeClusterParameterGroupsError::InvalidTagFault(_) => "InvalidTagFault",
+        }
+    }
+}
 /// Errors returned by DescribeClusterParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterParametersError {
@@ -14879,6 +15410,15 @@ impl fmt::Display for DescribeClusterParametersError {
     }
 }
 impl Error for DescribeClusterParametersError {}
+impl ServiceErrorCode for DescribeClusterParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClusterParametersError::ClusterParameterGroupNotFoundFault(_) => {
+                "ClusterParameterGroupNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeClusterSecurityGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterSecurityGroupsError {
@@ -14941,6 +15481,16 @@ impl fmt::Display for DescribeClusterSecurityGroupsError {
     }
 }
 impl Error for DescribeClusterSecurityGroupsError {}
+impl ServiceErrorCode for DescribeClusterSecurityGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClusterSecurityGroupsError::ClusterSecurityGroupNotFoundFault(_) => {
+                "ClusterSecurityGroupNotFound"
+            }
+            DescribeClusterSecurityGroupsError::InvalidTagFault(_) => "InvalidTagFault",
+        }
+    }
+}
 /// Errors returned by DescribeClusterSnapshots
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterSnapshotsError {
@@ -15009,6 +15559,17 @@ impl fmt::Display for DescribeClusterSnapshotsError {
     }
 }
 impl Error for DescribeClusterSnapshotsError {}
+impl ServiceErrorCode for DescribeClusterSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClusterSnapshotsError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            DescribeClusterSnapshotsError::ClusterSnapshotNotFoundFault(_) => {
+                "ClusterSnapshotNotFound"
+            }
+            DescribeClusterSnapshotsError::InvalidTagFault(_) => "InvalidTagFault",
+        }
+    }
+}
 /// Errors returned by DescribeClusterSubnetGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterSubnetGroupsError {
@@ -15067,6 +15628,16 @@ impl fmt::Display for DescribeClusterSubnetGroupsError {
     }
 }
 impl Error for DescribeClusterSubnetGroupsError {}
+impl ServiceErrorCode for DescribeClusterSubnetGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClusterSubnetGroupsError::ClusterSubnetGroupNotFoundFault(_) => {
+                "ClusterSubnetGroupNotFoundFault"
+            }
+            DescribeClusterSubnetGroupsError::InvalidTagFault(_) => "InvalidTagFault",
+        }
+    }
+}
 /// Errors returned by DescribeClusterTracks
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterTracksError {
@@ -15123,6 +15694,14 @@ impl fmt::Display for DescribeClusterTracksError {
     }
 }
 impl Error for DescribeClusterTracksError {}
+impl ServiceErrorCode for DescribeClusterTracksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClusterTracksError::InvalidClusterTrackFault(_) => "InvalidClusterTrack",
+            DescribeClusterTracksError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by DescribeClusterVersions
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterVersionsError {}
@@ -15157,6 +15736,11 @@ impl fmt::Display for DescribeClusterVersionsError {
     }
 }
 impl Error for DescribeClusterVersionsError {}
+impl ServiceErrorCode for DescribeClusterVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeClusters
 #[derive(Debug, PartialEq)]
 pub enum DescribeClustersError {
@@ -15209,6 +15793,14 @@ impl fmt::Display for DescribeClustersError {
     }
 }
 impl Error for DescribeClustersError {}
+impl ServiceErrorCode for DescribeClustersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClustersError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            DescribeClustersError::InvalidTagFault(_) => "InvalidTagFault",
+        }
+    }
+}
 /// Errors returned by DescribeDefaultClusterParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeDefaultClusterParametersError {}
@@ -15245,6 +15837,11 @@ impl fmt::Display for DescribeDefaultClusterParametersError {
     }
 }
 impl Error for DescribeDefaultClusterParametersError {}
+impl ServiceErrorCode for DescribeDefaultClusterParametersError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEventCategories
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventCategoriesError {}
@@ -15279,6 +15876,11 @@ impl fmt::Display for DescribeEventCategoriesError {
     }
 }
 impl Error for DescribeEventCategoriesError {}
+impl ServiceErrorCode for DescribeEventCategoriesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEventSubscriptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventSubscriptionsError {
@@ -15337,6 +15939,14 @@ impl fmt::Display for DescribeEventSubscriptionsError {
     }
 }
 impl Error for DescribeEventSubscriptionsError {}
+impl ServiceErrorCode for DescribeEventSubscriptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEventSubscriptionsError::InvalidTagFault(_) => "InvalidTagFault",
+            DescribeEventSubscriptionsError::SubscriptionNotFoundFault(_) => "SubscriptionNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
 pub enum DescribeEventsError {}
@@ -15371,6 +15981,11 @@ impl fmt::Display for DescribeEventsError {
     }
 }
 impl Error for DescribeEventsError {}
+impl ServiceErrorCode for DescribeEventsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeHsmClientCertificates
 #[derive(Debug, PartialEq)]
 pub enum DescribeHsmClientCertificatesError {
@@ -15433,6 +16048,16 @@ impl fmt::Display for DescribeHsmClientCertificatesError {
     }
 }
 impl Error for DescribeHsmClientCertificatesError {}
+impl ServiceErrorCode for DescribeHsmClientCertificatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeHsmClientCertificatesError::HsmClientCertificateNotFoundFault(_) => {
+                "HsmClientCertificateNotFoundFault"
+            }
+            DescribeHsmClientCertificatesError::InvalidTagFault(_) => "InvalidTagFault",
+        }
+    }
+}
 /// Errors returned by DescribeHsmConfigurations
 #[derive(Debug, PartialEq)]
 pub enum DescribeHsmConfigurationsError {
@@ -15489,6 +16114,16 @@ impl fmt::Display for DescribeHsmConfigurationsError {
     }
 }
 impl Error for DescribeHsmConfigurationsError {}
+impl ServiceErrorCode for DescribeHsmConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeHsmConfigurationsError::HsmConfigurationNotFoundFault(_) => {
+                "HsmConfigurationNotFoundFault"
+            }
+            DescribeHsmConfigurationsError::InvalidTagFault(_) => "InvalidTagFault",
+        }
+    }
+}
 /// Errors returned by DescribeLoggingStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeLoggingStatusError {
@@ -15528,11 +16163,18 @@ impl fmt::Display for DescribeLoggingStatusError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            DescribeLoggingStatusError::ClusterNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DescribeLoggingStatusError::ClusterNotFoundFault(ref cause) => write!(f, "{}", cause),
+        }
+    }
+}
+impl Error for DescribeLoggingStatusError {}
+impl ServiceErrorCode for DescribeLoggingStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLoggingStatusError::ClusterNotFoundFault(_) => "ClusterNotFound",
         }
     }
 }
-impl Error for DescribeLoggingStatusError {}
 /// Errors returned by DescribeNodeConfigurationOptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeNodeConfigurationOptionsError {
@@ -15619,6 +16261,22 @@ impl fmt::Display for DescribeNodeConfigurationOptionsError {
     }
 }
 impl Error for DescribeNodeConfigurationOptionsError {}
+impl ServiceErrorCode for DescribeNodeConfigurationOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeNodeConfigurationOptionsError::AccessToSnapshotDeniedFault(_) => {
+                "AccessToSnapshotDenied"
+            }
+            DescribeNodeConfigurationOptionsError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            DescribeNodeConfigurationOptionsError::ClusterSnapshotNotFoundFault(_) => {
+                "ClusterSnapshotNotFound"
+            }
+            DescribeNodeConfigurationOptionsError::InvalidClusterSnapshotStateFault(_) => {
+                "InvalidClusterSnapshotState"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeOrderableClusterOptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeOrderableClusterOptionsError {}
@@ -15655,6 +16313,11 @@ impl fmt::Display for DescribeOrderableClusterOptionsError {
     }
 }
 impl Error for DescribeOrderableClusterOptionsError {}
+impl ServiceErrorCode for DescribeOrderableClusterOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeReservedNodeOfferings
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedNodeOfferingsError {
@@ -15729,6 +16392,21 @@ impl fmt::Display for DescribeReservedNodeOfferingsError {
     }
 }
 impl Error for DescribeReservedNodeOfferingsError {}
+impl ServiceErrorCode for DescribeReservedNodeOfferingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReservedNodeOfferingsError::DependentServiceUnavailableFault(_) => {
+                "DependentServiceUnavailableFault"
+            }
+            DescribeReservedNodeOfferingsError::ReservedNodeOfferingNotFoundFault(_) => {
+                "ReservedNodeOfferingNotFound"
+            }
+            DescribeReservedNodeOfferingsError::UnsupportedOperationFault(_) => {
+                "UnsupportedOperation"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeReservedNodes
 #[derive(Debug, PartialEq)]
 pub enum DescribeReservedNodesError {
@@ -15789,6 +16467,16 @@ impl fmt::Display for DescribeReservedNodesError {
     }
 }
 impl Error for DescribeReservedNodesError {}
+impl ServiceErrorCode for DescribeReservedNodesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReservedNodesError::DependentServiceUnavailableFault(_) => {
+                "DependentServiceUnavailableFault"
+            }
+            DescribeReservedNodesError::ReservedNodeNotFoundFault(_) => "ReservedNodeNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeResize
 #[derive(Debug, PartialEq)]
 pub enum DescribeResizeError {
@@ -15841,6 +16529,14 @@ impl fmt::Display for DescribeResizeError {
     }
 }
 impl Error for DescribeResizeError {}
+impl ServiceErrorCode for DescribeResizeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeResizeError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            DescribeResizeError::ResizeNotFoundFault(_) => "ResizeNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeScheduledActions
 #[derive(Debug, PartialEq)]
 pub enum DescribeScheduledActionsError {
@@ -15901,6 +16597,16 @@ impl fmt::Display for DescribeScheduledActionsError {
     }
 }
 impl Error for DescribeScheduledActionsError {}
+impl ServiceErrorCode for DescribeScheduledActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeScheduledActionsError::ScheduledActionNotFoundFault(_) => {
+                "ScheduledActionNotFound"
+            }
+            DescribeScheduledActionsError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by DescribeSnapshotCopyGrants
 #[derive(Debug, PartialEq)]
 pub enum DescribeSnapshotCopyGrantsError {
@@ -15959,6 +16665,16 @@ impl fmt::Display for DescribeSnapshotCopyGrantsError {
     }
 }
 impl Error for DescribeSnapshotCopyGrantsError {}
+impl ServiceErrorCode for DescribeSnapshotCopyGrantsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSnapshotCopyGrantsError::InvalidTagFault(_) => "InvalidTagFault",
+            DescribeSnapshotCopyGrantsError::SnapshotCopyGrantNotFoundFault(_) => {
+                "SnapshotCopyGrantNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeSnapshotSchedules
 #[derive(Debug, PartialEq)]
 pub enum DescribeSnapshotSchedulesError {}
@@ -15993,6 +16709,11 @@ impl fmt::Display for DescribeSnapshotSchedulesError {
     }
 }
 impl Error for DescribeSnapshotSchedulesError {}
+impl ServiceErrorCode for DescribeSnapshotSchedulesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeStorage
 #[derive(Debug, PartialEq)]
 pub enum DescribeStorageError {}
@@ -16027,6 +16748,11 @@ impl fmt::Display for DescribeStorageError {
     }
 }
 impl Error for DescribeStorageError {}
+impl ServiceErrorCode for DescribeStorageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeTableRestoreStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeTableRestoreStatusError {
@@ -16089,6 +16815,16 @@ impl fmt::Display for DescribeTableRestoreStatusError {
     }
 }
 impl Error for DescribeTableRestoreStatusError {}
+impl ServiceErrorCode for DescribeTableRestoreStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTableRestoreStatusError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            DescribeTableRestoreStatusError::TableRestoreNotFoundFault(_) => {
+                "TableRestoreNotFoundFault"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
 pub enum DescribeTagsError {
@@ -16141,6 +16877,14 @@ impl fmt::Display for DescribeTagsError {
     }
 }
 impl Error for DescribeTagsError {}
+impl ServiceErrorCode for DescribeTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTagsError::InvalidTagFault(_) => "InvalidTagFault",
+            DescribeTagsError::ResourceNotFoundFault(_) => "ResourceNotFoundFault",
+        }
+    }
+}
 /// Errors returned by DescribeUsageLimits
 #[derive(Debug, PartialEq)]
 pub enum DescribeUsageLimitsError {
@@ -16197,6 +16941,14 @@ impl fmt::Display for DescribeUsageLimitsError {
     }
 }
 impl Error for DescribeUsageLimitsError {}
+impl ServiceErrorCode for DescribeUsageLimitsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUsageLimitsError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            DescribeUsageLimitsError::UnsupportedOperationFault(_) => "UnsupportedOperation",
+        }
+    }
+}
 /// Errors returned by DisableLogging
 #[derive(Debug, PartialEq)]
 pub enum DisableLoggingError {
@@ -16241,6 +16993,13 @@ impl fmt::Display for DisableLoggingError {
     }
 }
 impl Error for DisableLoggingError {}
+impl ServiceErrorCode for DisableLoggingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableLoggingError::ClusterNotFoundFault(_) => "ClusterNotFound",
+        }
+    }
+}
 /// Errors returned by DisableSnapshotCopy
 #[derive(Debug, PartialEq)]
 pub enum DisableSnapshotCopyError {
@@ -16315,6 +17074,18 @@ impl fmt::Display for DisableSnapshotCopyError {
     }
 }
 impl Error for DisableSnapshotCopyError {}
+impl ServiceErrorCode for DisableSnapshotCopyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableSnapshotCopyError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            DisableSnapshotCopyError::InvalidClusterStateFault(_) => "InvalidClusterState",
+            DisableSnapshotCopyError::SnapshotCopyAlreadyDisabledFault(_) => {
+                "SnapshotCopyAlreadyDisabledFault"
+            }
+            DisableSnapshotCopyError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by EnableLogging
 #[derive(Debug, PartialEq)]
 pub enum EnableLoggingError {
@@ -16403,6 +17174,20 @@ impl fmt::Display for EnableLoggingError {
     }
 }
 impl Error for EnableLoggingError {}
+impl ServiceErrorCode for EnableLoggingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableLoggingError::BucketNotFoundFault(_) => "BucketNotFoundFault",
+            EnableLoggingError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            EnableLoggingError::InsufficientS3BucketPolicyFault(_) => {
+                "InsufficientS3BucketPolicyFault"
+            }
+            EnableLoggingError::InvalidClusterStateFault(_) => "InvalidClusterState",
+            EnableLoggingError::InvalidS3BucketNameFault(_) => "InvalidS3BucketNameFault",
+            EnableLoggingError::InvalidS3KeyPrefixFault(_) => "InvalidS3KeyPrefixFault",
+        }
+    }
+}
 /// Errors returned by EnableSnapshotCopy
 #[derive(Debug, PartialEq)]
 pub enum EnableSnapshotCopyError {
@@ -16553,6 +17338,35 @@ impl fmt::Display for EnableSnapshotCopyError {
     }
 }
 impl Error for EnableSnapshotCopyError {}
+impl ServiceErrorCode for EnableSnapshotCopyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableSnapshotCopyError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            EnableSnapshotCopyError::CopyToRegionDisabledFault(_) => "CopyToRegionDisabledFault",
+            EnableSnapshotCopyError::DependentServiceRequestThrottlingFault(_) => {
+                "DependentServiceRequestThrottlingFault"
+            }
+            EnableSnapshotCopyError::IncompatibleOrderableOptions(_) => {
+                "IncompatibleOrderableOptions"
+            }
+            EnableSnapshotCopyError::InvalidClusterStateFault(_) => "InvalidClusterState",
+            EnableSnapshotCopyError::InvalidRetentionPeriodFault(_) => {
+                "InvalidRetentionPeriodFault"
+            }
+            EnableSnapshotCopyError::LimitExceededFault(_) => "LimitExceededFault",
+            EnableSnapshotCopyError::SnapshotCopyAlreadyEnabledFault(_) => {
+                "SnapshotCopyAlreadyEnabledFault"
+            }
+            EnableSnapshotCopyError::SnapshotCopyGrantNotFoundFault(_) => {
+                "SnapshotCopyGrantNotFoundFault"
+            }
+            EnableSnapshotCopyError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+            EnableSnapshotCopyError::UnknownSnapshotCopyRegionFault(_) => {
+                "UnknownSnapshotCopyRegionFault"
+            }
+        }
+    }
+}
 /// Errors returned by GetClusterCredentials
 #[derive(Debug, PartialEq)]
 pub enum GetClusterCredentialsError {
@@ -16609,6 +17423,14 @@ impl fmt::Display for GetClusterCredentialsError {
     }
 }
 impl Error for GetClusterCredentialsError {}
+impl ServiceErrorCode for GetClusterCredentialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetClusterCredentialsError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            GetClusterCredentialsError::UnsupportedOperationFault(_) => "UnsupportedOperation",
+        }
+    }
+}
 /// Errors returned by GetReservedNodeExchangeOfferings
 #[derive(Debug, PartialEq)]
 pub enum GetReservedNodeExchangeOfferingsError {
@@ -16717,6 +17539,30 @@ impl fmt::Display for GetReservedNodeExchangeOfferingsError {
     }
 }
 impl Error for GetReservedNodeExchangeOfferingsError {}
+impl ServiceErrorCode for GetReservedNodeExchangeOfferingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetReservedNodeExchangeOfferingsError::DependentServiceUnavailableFault(_) => {
+                "DependentServiceUnavailableFault"
+            }
+            GetReservedNodeExchangeOfferingsError::InvalidReservedNodeStateFault(_) => {
+                "InvalidReservedNodeState"
+            }
+            GetReservedNodeExchangeOfferingsError::ReservedNodeAlreadyMigratedFault(_) => {
+                "ReservedNodeAlreadyMigrated"
+            }
+            GetReservedNodeExchangeOfferingsError::ReservedNodeNotFoundFault(_) => {
+                "ReservedNodeNotFound"
+            }
+            GetReservedNodeExchangeOfferingsError::ReservedNodeOfferingNotFoundFault(_) => {
+                "ReservedNodeOfferingNotFound"
+            }
+            GetReservedNodeExchangeOfferingsError::UnsupportedOperationFault(_) => {
+                "UnsupportedOperation"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyCluster
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterError {
@@ -16937,6 +17783,45 @@ impl fmt::Display for ModifyClusterError {
     }
 }
 impl Error for ModifyClusterError {}
+impl ServiceErrorCode for ModifyClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyClusterError::ClusterAlreadyExistsFault(_) => "ClusterAlreadyExists",
+            ModifyClusterError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            ModifyClusterError::ClusterParameterGroupNotFoundFault(_) => {
+                "ClusterParameterGroupNotFound"
+            }
+            ModifyClusterError::ClusterSecurityGroupNotFoundFault(_) => {
+                "ClusterSecurityGroupNotFound"
+            }
+            ModifyClusterError::DependentServiceRequestThrottlingFault(_) => {
+                "DependentServiceRequestThrottlingFault"
+            }
+            ModifyClusterError::HsmClientCertificateNotFoundFault(_) => {
+                "HsmClientCertificateNotFoundFault"
+            }
+            ModifyClusterError::HsmConfigurationNotFoundFault(_) => "HsmConfigurationNotFoundFault",
+            ModifyClusterError::InsufficientClusterCapacityFault(_) => {
+                "InsufficientClusterCapacity"
+            }
+            ModifyClusterError::InvalidClusterSecurityGroupStateFault(_) => {
+                "InvalidClusterSecurityGroupState"
+            }
+            ModifyClusterError::InvalidClusterStateFault(_) => "InvalidClusterState",
+            ModifyClusterError::InvalidClusterTrackFault(_) => "InvalidClusterTrack",
+            ModifyClusterError::InvalidElasticIpFault(_) => "InvalidElasticIpFault",
+            ModifyClusterError::InvalidRetentionPeriodFault(_) => "InvalidRetentionPeriodFault",
+            ModifyClusterError::LimitExceededFault(_) => "LimitExceededFault",
+            ModifyClusterError::NumberOfNodesPerClusterLimitExceededFault(_) => {
+                "NumberOfNodesPerClusterLimitExceeded"
+            }
+            ModifyClusterError::NumberOfNodesQuotaExceededFault(_) => "NumberOfNodesQuotaExceeded",
+            ModifyClusterError::TableLimitExceededFault(_) => "TableLimitExceeded",
+            ModifyClusterError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+            ModifyClusterError::UnsupportedOptionFault(_) => "UnsupportedOptionFault",
+        }
+    }
+}
 /// Errors returned by ModifyClusterDbRevision
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterDbRevisionError {
@@ -17007,6 +17892,17 @@ impl fmt::Display for ModifyClusterDbRevisionError {
     }
 }
 impl Error for ModifyClusterDbRevisionError {}
+impl ServiceErrorCode for ModifyClusterDbRevisionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyClusterDbRevisionError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            ModifyClusterDbRevisionError::ClusterOnLatestRevisionFault(_) => {
+                "ClusterOnLatestRevision"
+            }
+            ModifyClusterDbRevisionError::InvalidClusterStateFault(_) => "InvalidClusterState",
+        }
+    }
+}
 /// Errors returned by ModifyClusterIamRoles
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterIamRolesError {
@@ -17063,6 +17959,14 @@ impl fmt::Display for ModifyClusterIamRolesError {
     }
 }
 impl Error for ModifyClusterIamRolesError {}
+impl ServiceErrorCode for ModifyClusterIamRolesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyClusterIamRolesError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            ModifyClusterIamRolesError::InvalidClusterStateFault(_) => "InvalidClusterState",
+        }
+    }
+}
 /// Errors returned by ModifyClusterMaintenance
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterMaintenanceError {
@@ -17123,6 +18027,14 @@ impl fmt::Display for ModifyClusterMaintenanceError {
     }
 }
 impl Error for ModifyClusterMaintenanceError {}
+impl ServiceErrorCode for ModifyClusterMaintenanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyClusterMaintenanceError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            ModifyClusterMaintenanceError::InvalidClusterStateFault(_) => "InvalidClusterState",
+        }
+    }
+}
 /// Errors returned by ModifyClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterParameterGroupError {
@@ -17183,6 +18095,18 @@ impl fmt::Display for ModifyClusterParameterGroupError {
     }
 }
 impl Error for ModifyClusterParameterGroupError {}
+impl ServiceErrorCode for ModifyClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyClusterParameterGroupError::ClusterParameterGroupNotFoundFault(_) => {
+                "ClusterParameterGroupNotFound"
+            }
+            ModifyClusterParameterGroupError::InvalidClusterParameterGroupStateFault(_) => {
+                "InvalidClusterParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyClusterSnapshot
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterSnapshotError {
@@ -17255,6 +18179,21 @@ impl fmt::Display for ModifyClusterSnapshotError {
     }
 }
 impl Error for ModifyClusterSnapshotError {}
+impl ServiceErrorCode for ModifyClusterSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyClusterSnapshotError::ClusterSnapshotNotFoundFault(_) => {
+                "ClusterSnapshotNotFound"
+            }
+            ModifyClusterSnapshotError::InvalidClusterSnapshotStateFault(_) => {
+                "InvalidClusterSnapshotState"
+            }
+            ModifyClusterSnapshotError::InvalidRetentionPeriodFault(_) => {
+                "InvalidRetentionPeriodFault"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyClusterSnapshotSchedule
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterSnapshotScheduleError {
@@ -17308,6 +18247,19 @@ impl fmt::Display for ModifyClusterSnapshotScheduleError {
     }
 }
 impl Error for ModifyClusterSnapshotScheduleError {}
+impl ServiceErrorCode for ModifyClusterSnapshotScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyClusterSnapshotScheduleError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            ModifyClusterSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault(_) => {
+                "InvalidClusterSnapshotScheduleState"
+            }
+            ModifyClusterSnapshotScheduleError::SnapshotScheduleNotFoundFault(_) => {
+                "SnapshotScheduleNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyClusterSubnetGroup
 #[derive(Debug, PartialEq)]
 pub enum ModifyClusterSubnetGroupError {
@@ -17408,6 +18360,24 @@ impl fmt::Display for ModifyClusterSubnetGroupError {
     }
 }
 impl Error for ModifyClusterSubnetGroupError {}
+impl ServiceErrorCode for ModifyClusterSubnetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyClusterSubnetGroupError::ClusterSubnetGroupNotFoundFault(_) => {
+                "ClusterSubnetGroupNotFoundFault"
+            }
+            ModifyClusterSubnetGroupError::ClusterSubnetQuotaExceededFault(_) => {
+                "ClusterSubnetQuotaExceededFault"
+            }
+            ModifyClusterSubnetGroupError::DependentServiceRequestThrottlingFault(_) => {
+                "DependentServiceRequestThrottlingFault"
+            }
+            ModifyClusterSubnetGroupError::InvalidSubnet(_) => "InvalidSubnet",
+            ModifyClusterSubnetGroupError::SubnetAlreadyInUse(_) => "SubnetAlreadyInUse",
+            ModifyClusterSubnetGroupError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by ModifyEventSubscription
 #[derive(Debug, PartialEq)]
 pub enum ModifyEventSubscriptionError {
@@ -17546,6 +18516,29 @@ impl fmt::Display for ModifyEventSubscriptionError {
     }
 }
 impl Error for ModifyEventSubscriptionError {}
+impl ServiceErrorCode for ModifyEventSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyEventSubscriptionError::InvalidSubscriptionStateFault(_) => {
+                "InvalidSubscriptionStateFault"
+            }
+            ModifyEventSubscriptionError::SNSInvalidTopicFault(_) => "SNSInvalidTopic",
+            ModifyEventSubscriptionError::SNSNoAuthorizationFault(_) => "SNSNoAuthorization",
+            ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(_) => "SNSTopicArnNotFound",
+            ModifyEventSubscriptionError::SourceNotFoundFault(_) => "SourceNotFound",
+            ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(_) => {
+                "SubscriptionCategoryNotFound"
+            }
+            ModifyEventSubscriptionError::SubscriptionEventIdNotFoundFault(_) => {
+                "SubscriptionEventIdNotFound"
+            }
+            ModifyEventSubscriptionError::SubscriptionNotFoundFault(_) => "SubscriptionNotFound",
+            ModifyEventSubscriptionError::SubscriptionSeverityNotFoundFault(_) => {
+                "SubscriptionSeverityNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyScheduledAction
 #[derive(Debug, PartialEq)]
 pub enum ModifyScheduledActionError {
@@ -17634,6 +18627,21 @@ impl fmt::Display for ModifyScheduledActionError {
     }
 }
 impl Error for ModifyScheduledActionError {}
+impl ServiceErrorCode for ModifyScheduledActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyScheduledActionError::InvalidScheduleFault(_) => "InvalidSchedule",
+            ModifyScheduledActionError::InvalidScheduledActionFault(_) => "InvalidScheduledAction",
+            ModifyScheduledActionError::ScheduledActionNotFoundFault(_) => {
+                "ScheduledActionNotFound"
+            }
+            ModifyScheduledActionError::ScheduledActionTypeUnsupportedFault(_) => {
+                "ScheduledActionTypeUnsupported"
+            }
+            ModifyScheduledActionError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by ModifySnapshotCopyRetentionPeriod
 #[derive(Debug, PartialEq)]
 pub enum ModifySnapshotCopyRetentionPeriodError {
@@ -17732,6 +18740,25 @@ impl fmt::Display for ModifySnapshotCopyRetentionPeriodError {
     }
 }
 impl Error for ModifySnapshotCopyRetentionPeriodError {}
+impl ServiceErrorCode for ModifySnapshotCopyRetentionPeriodError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifySnapshotCopyRetentionPeriodError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            ModifySnapshotCopyRetentionPeriodError::InvalidClusterStateFault(_) => {
+                "InvalidClusterState"
+            }
+            ModifySnapshotCopyRetentionPeriodError::InvalidRetentionPeriodFault(_) => {
+                "InvalidRetentionPeriodFault"
+            }
+            ModifySnapshotCopyRetentionPeriodError::SnapshotCopyDisabledFault(_) => {
+                "SnapshotCopyDisabledFault"
+            }
+            ModifySnapshotCopyRetentionPeriodError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperation"
+            }
+        }
+    }
+}
 /// Errors returned by ModifySnapshotSchedule
 #[derive(Debug, PartialEq)]
 pub enum ModifySnapshotScheduleError {
@@ -17800,6 +18827,19 @@ impl fmt::Display for ModifySnapshotScheduleError {
     }
 }
 impl Error for ModifySnapshotScheduleError {}
+impl ServiceErrorCode for ModifySnapshotScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifySnapshotScheduleError::InvalidScheduleFault(_) => "InvalidSchedule",
+            ModifySnapshotScheduleError::SnapshotScheduleNotFoundFault(_) => {
+                "SnapshotScheduleNotFound"
+            }
+            ModifySnapshotScheduleError::SnapshotScheduleUpdateInProgressFault(_) => {
+                "SnapshotScheduleUpdateInProgress"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyUsageLimit
 #[derive(Debug, PartialEq)]
 pub enum ModifyUsageLimitError {
@@ -17860,6 +18900,15 @@ impl fmt::Display for ModifyUsageLimitError {
     }
 }
 impl Error for ModifyUsageLimitError {}
+impl ServiceErrorCode for ModifyUsageLimitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyUsageLimitError::InvalidUsageLimitFault(_) => "InvalidUsageLimit",
+            ModifyUsageLimitError::UnsupportedOperationFault(_) => "UnsupportedOperation",
+            ModifyUsageLimitError::UsageLimitNotFoundFault(_) => "UsageLimitNotFound",
+        }
+    }
+}
 /// Errors returned by PauseCluster
 #[derive(Debug, PartialEq)]
 pub enum PauseClusterError {
@@ -17912,6 +18961,14 @@ impl fmt::Display for PauseClusterError {
     }
 }
 impl Error for PauseClusterError {}
+impl ServiceErrorCode for PauseClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PauseClusterError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            PauseClusterError::InvalidClusterStateFault(_) => "InvalidClusterState",
+        }
+    }
+}
 /// Errors returned by PurchaseReservedNodeOffering
 #[derive(Debug, PartialEq)]
 pub enum PurchaseReservedNodeOfferingError {
@@ -17998,6 +19055,24 @@ impl fmt::Display for PurchaseReservedNodeOfferingError {
     }
 }
 impl Error for PurchaseReservedNodeOfferingError {}
+impl ServiceErrorCode for PurchaseReservedNodeOfferingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PurchaseReservedNodeOfferingError::ReservedNodeAlreadyExistsFault(_) => {
+                "ReservedNodeAlreadyExists"
+            }
+            PurchaseReservedNodeOfferingError::ReservedNodeOfferingNotFoundFault(_) => {
+                "ReservedNodeOfferingNotFound"
+            }
+            PurchaseReservedNodeOfferingError::ReservedNodeQuotaExceededFault(_) => {
+                "ReservedNodeQuotaExceeded"
+            }
+            PurchaseReservedNodeOfferingError::UnsupportedOperationFault(_) => {
+                "UnsupportedOperation"
+            }
+        }
+    }
+}
 /// Errors returned by RebootCluster
 #[derive(Debug, PartialEq)]
 pub enum RebootClusterError {
@@ -18050,6 +19125,14 @@ impl fmt::Display for RebootClusterError {
     }
 }
 impl Error for RebootClusterError {}
+impl ServiceErrorCode for RebootClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RebootClusterError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            RebootClusterError::InvalidClusterStateFault(_) => "InvalidClusterState",
+        }
+    }
+}
 /// Errors returned by ResetClusterParameterGroup
 #[derive(Debug, PartialEq)]
 pub enum ResetClusterParameterGroupError {
@@ -18112,6 +19195,18 @@ impl fmt::Display for ResetClusterParameterGroupError {
     }
 }
 impl Error for ResetClusterParameterGroupError {}
+impl ServiceErrorCode for ResetClusterParameterGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetClusterParameterGroupError::ClusterParameterGroupNotFoundFault(_) => {
+                "ClusterParameterGroupNotFound"
+            }
+            ResetClusterParameterGroupError::InvalidClusterParameterGroupStateFault(_) => {
+                "InvalidClusterParameterGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by ResizeCluster
 #[derive(Debug, PartialEq)]
 pub enum ResizeClusterError {
@@ -18232,6 +19327,25 @@ impl fmt::Display for ResizeClusterError {
     }
 }
 impl Error for ResizeClusterError {}
+impl ServiceErrorCode for ResizeClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResizeClusterError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            ResizeClusterError::InsufficientClusterCapacityFault(_) => {
+                "InsufficientClusterCapacity"
+            }
+            ResizeClusterError::InvalidClusterStateFault(_) => "InvalidClusterState",
+            ResizeClusterError::LimitExceededFault(_) => "LimitExceededFault",
+            ResizeClusterError::NumberOfNodesPerClusterLimitExceededFault(_) => {
+                "NumberOfNodesPerClusterLimitExceeded"
+            }
+            ResizeClusterError::NumberOfNodesQuotaExceededFault(_) => "NumberOfNodesQuotaExceeded",
+            ResizeClusterError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+            ResizeClusterError::UnsupportedOperationFault(_) => "UnsupportedOperation",
+            ResizeClusterError::UnsupportedOptionFault(_) => "UnsupportedOptionFault",
+        }
+    }
+}
 /// Errors returned by RestoreFromClusterSnapshot
 #[derive(Debug, PartialEq)]
 pub enum RestoreFromClusterSnapshotError {
@@ -18560,6 +19674,67 @@ impl fmt::Display for RestoreFromClusterSnapshotError {
     }
 }
 impl Error for RestoreFromClusterSnapshotError {}
+impl ServiceErrorCode for RestoreFromClusterSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreFromClusterSnapshotError::AccessToSnapshotDeniedFault(_) => {
+                "AccessToSnapshotDenied"
+            }
+            RestoreFromClusterSnapshotError::ClusterAlreadyExistsFault(_) => "ClusterAlreadyExists",
+            RestoreFromClusterSnapshotError::ClusterParameterGroupNotFoundFault(_) => {
+                "ClusterParameterGroupNotFound"
+            }
+            RestoreFromClusterSnapshotError::ClusterQuotaExceededFault(_) => "ClusterQuotaExceeded",
+            RestoreFromClusterSnapshotError::ClusterSecurityGroupNotFoundFault(_) => {
+                "ClusterSecurityGroupNotFound"
+            }
+            RestoreFromClusterSnapshotError::ClusterSnapshotNotFoundFault(_) => {
+                "ClusterSnapshotNotFound"
+            }
+            RestoreFromClusterSnapshotError::ClusterSubnetGroupNotFoundFault(_) => {
+                "ClusterSubnetGroupNotFoundFault"
+            }
+            RestoreFromClusterSnapshotError::DependentServiceRequestThrottlingFault(_) => {
+                "DependentServiceRequestThrottlingFault"
+            }
+            RestoreFromClusterSnapshotError::HsmClientCertificateNotFoundFault(_) => {
+                "HsmClientCertificateNotFoundFault"
+            }
+            RestoreFromClusterSnapshotError::HsmConfigurationNotFoundFault(_) => {
+                "HsmConfigurationNotFoundFault"
+            }
+            RestoreFromClusterSnapshotError::InsufficientClusterCapacityFault(_) => {
+                "InsufficientClusterCapacity"
+            }
+            RestoreFromClusterSnapshotError::InvalidClusterSnapshotStateFault(_) => {
+                "InvalidClusterSnapshotState"
+            }
+            RestoreFromClusterSnapshotError::InvalidClusterSubnetGroupStateFault(_) => {
+                "InvalidClusterSubnetGroupStateFault"
+            }
+            RestoreFromClusterSnapshotError::InvalidClusterTrackFault(_) => "InvalidClusterTrack",
+            RestoreFromClusterSnapshotError::InvalidElasticIpFault(_) => "InvalidElasticIpFault",
+            RestoreFromClusterSnapshotError::InvalidRestoreFault(_) => "InvalidRestore",
+            RestoreFromClusterSnapshotError::InvalidSubnet(_) => "InvalidSubnet",
+            RestoreFromClusterSnapshotError::InvalidTagFault(_) => "InvalidTagFault",
+            RestoreFromClusterSnapshotError::InvalidVPCNetworkStateFault(_) => {
+                "InvalidVPCNetworkStateFault"
+            }
+            RestoreFromClusterSnapshotError::LimitExceededFault(_) => "LimitExceededFault",
+            RestoreFromClusterSnapshotError::NumberOfNodesPerClusterLimitExceededFault(_) => {
+                "NumberOfNodesPerClusterLimitExceeded"
+            }
+            RestoreFromClusterSnapshotError::NumberOfNodesQuotaExceededFault(_) => {
+                "NumberOfNodesQuotaExceeded"
+            }
+            RestoreFromClusterSnapshotError::SnapshotScheduleNotFoundFault(_) => {
+                "SnapshotScheduleNotFound"
+            }
+            RestoreFromClusterSnapshotError::TagLimitExceededFault(_) => "TagLimitExceededFault",
+            RestoreFromClusterSnapshotError::UnauthorizedOperation(_) => "UnauthorizedOperation",
+        }
+    }
+}
 /// Errors returned by RestoreTableFromClusterSnapshot
 #[derive(Debug, PartialEq)]
 pub enum RestoreTableFromClusterSnapshotError {
@@ -18633,6 +19808,31 @@ impl fmt::Display for RestoreTableFromClusterSnapshotError {
     }
 }
 impl Error for RestoreTableFromClusterSnapshotError {}
+impl ServiceErrorCode for RestoreTableFromClusterSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreTableFromClusterSnapshotError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            RestoreTableFromClusterSnapshotError::ClusterSnapshotNotFoundFault(_) => {
+                "ClusterSnapshotNotFound"
+            }
+            RestoreTableFromClusterSnapshotError::InProgressTableRestoreQuotaExceededFault(_) => {
+                "InProgressTableRestoreQuotaExceededFault"
+            }
+            RestoreTableFromClusterSnapshotError::InvalidClusterSnapshotStateFault(_) => {
+                "InvalidClusterSnapshotState"
+            }
+            RestoreTableFromClusterSnapshotError::InvalidClusterStateFault(_) => {
+                "InvalidClusterState"
+            }
+            RestoreTableFromClusterSnapshotError::InvalidTableRestoreArgumentFault(_) => {
+                "InvalidTableRestoreArgument"
+            }
+            RestoreTableFromClusterSnapshotError::UnsupportedOperationFault(_) => {
+                "UnsupportedOperation"
+            }
+        }
+    }
+}
 /// Errors returned by ResumeCluster
 #[derive(Debug, PartialEq)]
 pub enum ResumeClusterError {
@@ -18697,6 +19897,17 @@ impl fmt::Display for ResumeClusterError {
     }
 }
 impl Error for ResumeClusterError {}
+impl ServiceErrorCode for ResumeClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResumeClusterError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            ResumeClusterError::InsufficientClusterCapacityFault(_) => {
+                "InsufficientClusterCapacity"
+            }
+            ResumeClusterError::InvalidClusterStateFault(_) => "InvalidClusterState",
+        }
+    }
+}
 /// Errors returned by RevokeClusterSecurityGroupIngress
 #[derive(Debug, PartialEq)]
 pub enum RevokeClusterSecurityGroupIngressError {
@@ -18750,6 +19961,21 @@ impl fmt::Display for RevokeClusterSecurityGroupIngressError {
     }
 }
 impl Error for RevokeClusterSecurityGroupIngressError {}
+impl ServiceErrorCode for RevokeClusterSecurityGroupIngressError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RevokeClusterSecurityGroupIngressError::AuthorizationNotFoundFault(_) => {
+                "AuthorizationNotFound"
+            }
+            RevokeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(_) => {
+                "ClusterSecurityGroupNotFound"
+            }
+            RevokeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(_) => {
+                "InvalidClusterSecurityGroupState"
+            }
+        }
+    }
+}
 /// Errors returned by RevokeSnapshotAccess
 #[derive(Debug, PartialEq)]
 pub enum RevokeSnapshotAccessError {
@@ -18822,6 +20048,15 @@ impl fmt::Display for RevokeSnapshotAccessError {
     }
 }
 impl Error for RevokeSnapshotAccessError {}
+impl ServiceErrorCode for RevokeSnapshotAccessError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RevokeSnapshotAccessError::AccessToSnapshotDeniedFault(_) => "AccessToSnapshotDenied",
+            RevokeSnapshotAccessError::AuthorizationNotFoundFault(_) => "AuthorizationNotFound",
+            RevokeSnapshotAccessError::ClusterSnapshotNotFoundFault(_) => "ClusterSnapshotNotFound",
+        }
+    }
+}
 /// Errors returned by RotateEncryptionKey
 #[derive(Debug, PartialEq)]
 pub enum RotateEncryptionKeyError {
@@ -18888,6 +20123,17 @@ impl fmt::Display for RotateEncryptionKeyError {
     }
 }
 impl Error for RotateEncryptionKeyError {}
+impl ServiceErrorCode for RotateEncryptionKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RotateEncryptionKeyError::ClusterNotFoundFault(_) => "ClusterNotFound",
+            RotateEncryptionKeyError::DependentServiceRequestThrottlingFault(_) => {
+                "DependentServiceRequestThrottlingFault"
+            }
+            RotateEncryptionKeyError::InvalidClusterStateFault(_) => "InvalidClusterState",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Redshift API. Amazon Redshift clients implement this trait.
 #[async_trait]
 pub trait Redshift {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3026,6 +3026,22 @@ impl fmt::Display for CompareFacesError {
     }
 }
 impl Error for CompareFacesError {}
+impl ServiceErrorCode for CompareFacesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CompareFacesError::AccessDenied(_) => "AccessDeniedException",
+            CompareFacesError::ImageTooLarge(_) => "ImageTooLargeException",
+            CompareFacesError::InternalServerError(_) => "InternalServerError",
+            CompareFacesError::InvalidImageFormat(_) => "InvalidImageFormatException",
+            CompareFacesError::InvalidParameter(_) => "InvalidParameterException",
+            CompareFacesError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            CompareFacesError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            CompareFacesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateCollection
 #[derive(Debug, PartialEq)]
 pub enum CreateCollectionError {
@@ -3094,6 +3110,20 @@ impl fmt::Display for CreateCollectionError {
     }
 }
 impl Error for CreateCollectionError {}
+impl ServiceErrorCode for CreateCollectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCollectionError::AccessDenied(_) => "AccessDeniedException",
+            CreateCollectionError::InternalServerError(_) => "InternalServerError",
+            CreateCollectionError::InvalidParameter(_) => "InvalidParameterException",
+            CreateCollectionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            CreateCollectionError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateCollectionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateProject
 #[derive(Debug, PartialEq)]
 pub enum CreateProjectError {
@@ -3162,6 +3192,21 @@ impl fmt::Display for CreateProjectError {
     }
 }
 impl Error for CreateProjectError {}
+impl ServiceErrorCode for CreateProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProjectError::AccessDenied(_) => "AccessDeniedException",
+            CreateProjectError::InternalServerError(_) => "InternalServerError",
+            CreateProjectError::InvalidParameter(_) => "InvalidParameterException",
+            CreateProjectError::LimitExceeded(_) => "LimitExceededException",
+            CreateProjectError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            CreateProjectError::ResourceInUse(_) => "ResourceInUseException",
+            CreateProjectError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateProjectVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateProjectVersionError {
@@ -3244,6 +3289,22 @@ impl fmt::Display for CreateProjectVersionError {
     }
 }
 impl Error for CreateProjectVersionError {}
+impl ServiceErrorCode for CreateProjectVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProjectVersionError::AccessDenied(_) => "AccessDeniedException",
+            CreateProjectVersionError::InternalServerError(_) => "InternalServerError",
+            CreateProjectVersionError::InvalidParameter(_) => "InvalidParameterException",
+            CreateProjectVersionError::LimitExceeded(_) => "LimitExceededException",
+            CreateProjectVersionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            CreateProjectVersionError::ResourceInUse(_) => "ResourceInUseException",
+            CreateProjectVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateProjectVersionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateStreamProcessor
 #[derive(Debug, PartialEq)]
 pub enum CreateStreamProcessorError {
@@ -3318,6 +3379,21 @@ impl fmt::Display for CreateStreamProcessorError {
     }
 }
 impl Error for CreateStreamProcessorError {}
+impl ServiceErrorCode for CreateStreamProcessorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStreamProcessorError::AccessDenied(_) => "AccessDeniedException",
+            CreateStreamProcessorError::InternalServerError(_) => "InternalServerError",
+            CreateStreamProcessorError::InvalidParameter(_) => "InvalidParameterException",
+            CreateStreamProcessorError::LimitExceeded(_) => "LimitExceededException",
+            CreateStreamProcessorError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            CreateStreamProcessorError::ResourceInUse(_) => "ResourceInUseException",
+            CreateStreamProcessorError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteCollection
 #[derive(Debug, PartialEq)]
 pub enum DeleteCollectionError {
@@ -3384,6 +3460,20 @@ impl fmt::Display for DeleteCollectionError {
     }
 }
 impl Error for DeleteCollectionError {}
+impl ServiceErrorCode for DeleteCollectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCollectionError::AccessDenied(_) => "AccessDeniedException",
+            DeleteCollectionError::InternalServerError(_) => "InternalServerError",
+            DeleteCollectionError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteCollectionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DeleteCollectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteCollectionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteFaces
 #[derive(Debug, PartialEq)]
 pub enum DeleteFacesError {
@@ -3446,6 +3536,20 @@ impl fmt::Display for DeleteFacesError {
     }
 }
 impl Error for DeleteFacesError {}
+impl ServiceErrorCode for DeleteFacesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFacesError::AccessDenied(_) => "AccessDeniedException",
+            DeleteFacesError::InternalServerError(_) => "InternalServerError",
+            DeleteFacesError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteFacesError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DeleteFacesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteFacesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteProject
 #[derive(Debug, PartialEq)]
 pub enum DeleteProjectError {
@@ -3514,6 +3618,21 @@ impl fmt::Display for DeleteProjectError {
     }
 }
 impl Error for DeleteProjectError {}
+impl ServiceErrorCode for DeleteProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProjectError::AccessDenied(_) => "AccessDeniedException",
+            DeleteProjectError::InternalServerError(_) => "InternalServerError",
+            DeleteProjectError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteProjectError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DeleteProjectError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteProjectError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteProjectError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteProjectVersion
 #[derive(Debug, PartialEq)]
 pub enum DeleteProjectVersionError {
@@ -3590,6 +3709,21 @@ impl fmt::Display for DeleteProjectVersionError {
     }
 }
 impl Error for DeleteProjectVersionError {}
+impl ServiceErrorCode for DeleteProjectVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProjectVersionError::AccessDenied(_) => "AccessDeniedException",
+            DeleteProjectVersionError::InternalServerError(_) => "InternalServerError",
+            DeleteProjectVersionError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteProjectVersionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DeleteProjectVersionError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteProjectVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteProjectVersionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteStreamProcessor
 #[derive(Debug, PartialEq)]
 pub enum DeleteStreamProcessorError {
@@ -3666,6 +3800,21 @@ impl fmt::Display for DeleteStreamProcessorError {
     }
 }
 impl Error for DeleteStreamProcessorError {}
+impl ServiceErrorCode for DeleteStreamProcessorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStreamProcessorError::AccessDenied(_) => "AccessDeniedException",
+            DeleteStreamProcessorError::InternalServerError(_) => "InternalServerError",
+            DeleteStreamProcessorError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteStreamProcessorError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DeleteStreamProcessorError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteStreamProcessorError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteStreamProcessorError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeCollection
 #[derive(Debug, PartialEq)]
 pub enum DescribeCollectionError {
@@ -3732,6 +3881,20 @@ impl fmt::Display for DescribeCollectionError {
     }
 }
 impl Error for DescribeCollectionError {}
+impl ServiceErrorCode for DescribeCollectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCollectionError::AccessDenied(_) => "AccessDeniedException",
+            DescribeCollectionError::InternalServerError(_) => "InternalServerError",
+            DescribeCollectionError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeCollectionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DescribeCollectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeCollectionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeProjectVersions
 #[derive(Debug, PartialEq)]
 pub enum DescribeProjectVersionsError {
@@ -3814,6 +3977,23 @@ impl fmt::Display for DescribeProjectVersionsError {
     }
 }
 impl Error for DescribeProjectVersionsError {}
+impl ServiceErrorCode for DescribeProjectVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProjectVersionsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeProjectVersionsError::InternalServerError(_) => "InternalServerError",
+            DescribeProjectVersionsError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            DescribeProjectVersionsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeProjectVersionsError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DescribeProjectVersionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeProjectVersionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeProjects
 #[derive(Debug, PartialEq)]
 pub enum DescribeProjectsError {
@@ -3882,6 +4062,20 @@ impl fmt::Display for DescribeProjectsError {
     }
 }
 impl Error for DescribeProjectsError {}
+impl ServiceErrorCode for DescribeProjectsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProjectsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeProjectsError::InternalServerError(_) => "InternalServerError",
+            DescribeProjectsError::InvalidPaginationToken(_) => "InvalidPaginationTokenException",
+            DescribeProjectsError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeProjectsError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DescribeProjectsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeStreamProcessor
 #[derive(Debug, PartialEq)]
 pub enum DescribeStreamProcessorError {
@@ -3954,6 +4148,20 @@ impl fmt::Display for DescribeStreamProcessorError {
     }
 }
 impl Error for DescribeStreamProcessorError {}
+impl ServiceErrorCode for DescribeStreamProcessorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStreamProcessorError::AccessDenied(_) => "AccessDeniedException",
+            DescribeStreamProcessorError::InternalServerError(_) => "InternalServerError",
+            DescribeStreamProcessorError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeStreamProcessorError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DescribeStreamProcessorError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeStreamProcessorError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DetectCustomLabels
 #[derive(Debug, PartialEq)]
 pub enum DetectCustomLabelsError {
@@ -4052,6 +4260,25 @@ impl fmt::Display for DetectCustomLabelsError {
     }
 }
 impl Error for DetectCustomLabelsError {}
+impl ServiceErrorCode for DetectCustomLabelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectCustomLabelsError::AccessDenied(_) => "AccessDeniedException",
+            DetectCustomLabelsError::ImageTooLarge(_) => "ImageTooLargeException",
+            DetectCustomLabelsError::InternalServerError(_) => "InternalServerError",
+            DetectCustomLabelsError::InvalidImageFormat(_) => "InvalidImageFormatException",
+            DetectCustomLabelsError::InvalidParameter(_) => "InvalidParameterException",
+            DetectCustomLabelsError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            DetectCustomLabelsError::LimitExceeded(_) => "LimitExceededException",
+            DetectCustomLabelsError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DetectCustomLabelsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DetectCustomLabelsError::ResourceNotReady(_) => "ResourceNotReadyException",
+            DetectCustomLabelsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DetectFaces
 #[derive(Debug, PartialEq)]
 pub enum DetectFacesError {
@@ -4126,6 +4353,22 @@ impl fmt::Display for DetectFacesError {
     }
 }
 impl Error for DetectFacesError {}
+impl ServiceErrorCode for DetectFacesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectFacesError::AccessDenied(_) => "AccessDeniedException",
+            DetectFacesError::ImageTooLarge(_) => "ImageTooLargeException",
+            DetectFacesError::InternalServerError(_) => "InternalServerError",
+            DetectFacesError::InvalidImageFormat(_) => "InvalidImageFormatException",
+            DetectFacesError::InvalidParameter(_) => "InvalidParameterException",
+            DetectFacesError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            DetectFacesError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DetectFacesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DetectLabels
 #[derive(Debug, PartialEq)]
 pub enum DetectLabelsError {
@@ -4200,6 +4443,22 @@ impl fmt::Display for DetectLabelsError {
     }
 }
 impl Error for DetectLabelsError {}
+impl ServiceErrorCode for DetectLabelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectLabelsError::AccessDenied(_) => "AccessDeniedException",
+            DetectLabelsError::ImageTooLarge(_) => "ImageTooLargeException",
+            DetectLabelsError::InternalServerError(_) => "InternalServerError",
+            DetectLabelsError::InvalidImageFormat(_) => "InvalidImageFormatException",
+            DetectLabelsError::InvalidParameter(_) => "InvalidParameterException",
+            DetectLabelsError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            DetectLabelsError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DetectLabelsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DetectModerationLabels
 #[derive(Debug, PartialEq)]
 pub enum DetectModerationLabelsError {
@@ -4296,6 +4555,25 @@ impl fmt::Display for DetectModerationLabelsError {
     }
 }
 impl Error for DetectModerationLabelsError {}
+impl ServiceErrorCode for DetectModerationLabelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectModerationLabelsError::AccessDenied(_) => "AccessDeniedException",
+            DetectModerationLabelsError::HumanLoopQuotaExceeded(_) => {
+                "HumanLoopQuotaExceededException"
+            }
+            DetectModerationLabelsError::ImageTooLarge(_) => "ImageTooLargeException",
+            DetectModerationLabelsError::InternalServerError(_) => "InternalServerError",
+            DetectModerationLabelsError::InvalidImageFormat(_) => "InvalidImageFormatException",
+            DetectModerationLabelsError::InvalidParameter(_) => "InvalidParameterException",
+            DetectModerationLabelsError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            DetectModerationLabelsError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DetectModerationLabelsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DetectProtectiveEquipment
 #[derive(Debug, PartialEq)]
 pub enum DetectProtectiveEquipmentError {
@@ -4388,6 +4666,22 @@ impl fmt::Display for DetectProtectiveEquipmentError {
     }
 }
 impl Error for DetectProtectiveEquipmentError {}
+impl ServiceErrorCode for DetectProtectiveEquipmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectProtectiveEquipmentError::AccessDenied(_) => "AccessDeniedException",
+            DetectProtectiveEquipmentError::ImageTooLarge(_) => "ImageTooLargeException",
+            DetectProtectiveEquipmentError::InternalServerError(_) => "InternalServerError",
+            DetectProtectiveEquipmentError::InvalidImageFormat(_) => "InvalidImageFormatException",
+            DetectProtectiveEquipmentError::InvalidParameter(_) => "InvalidParameterException",
+            DetectProtectiveEquipmentError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            DetectProtectiveEquipmentError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DetectProtectiveEquipmentError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DetectText
 #[derive(Debug, PartialEq)]
 pub enum DetectTextError {
@@ -4462,6 +4756,22 @@ impl fmt::Display for DetectTextError {
     }
 }
 impl Error for DetectTextError {}
+impl ServiceErrorCode for DetectTextError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectTextError::AccessDenied(_) => "AccessDeniedException",
+            DetectTextError::ImageTooLarge(_) => "ImageTooLargeException",
+            DetectTextError::InternalServerError(_) => "InternalServerError",
+            DetectTextError::InvalidImageFormat(_) => "InvalidImageFormatException",
+            DetectTextError::InvalidParameter(_) => "InvalidParameterException",
+            DetectTextError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            DetectTextError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DetectTextError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetCelebrityInfo
 #[derive(Debug, PartialEq)]
 pub enum GetCelebrityInfoError {
@@ -4528,6 +4838,20 @@ impl fmt::Display for GetCelebrityInfoError {
     }
 }
 impl Error for GetCelebrityInfoError {}
+impl ServiceErrorCode for GetCelebrityInfoError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCelebrityInfoError::AccessDenied(_) => "AccessDeniedException",
+            GetCelebrityInfoError::InternalServerError(_) => "InternalServerError",
+            GetCelebrityInfoError::InvalidParameter(_) => "InvalidParameterException",
+            GetCelebrityInfoError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            GetCelebrityInfoError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetCelebrityInfoError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetCelebrityRecognition
 #[derive(Debug, PartialEq)]
 pub enum GetCelebrityRecognitionError {
@@ -4610,6 +4934,23 @@ impl fmt::Display for GetCelebrityRecognitionError {
     }
 }
 impl Error for GetCelebrityRecognitionError {}
+impl ServiceErrorCode for GetCelebrityRecognitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCelebrityRecognitionError::AccessDenied(_) => "AccessDeniedException",
+            GetCelebrityRecognitionError::InternalServerError(_) => "InternalServerError",
+            GetCelebrityRecognitionError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            GetCelebrityRecognitionError::InvalidParameter(_) => "InvalidParameterException",
+            GetCelebrityRecognitionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            GetCelebrityRecognitionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetCelebrityRecognitionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetContentModeration
 #[derive(Debug, PartialEq)]
 pub enum GetContentModerationError {
@@ -4688,6 +5029,23 @@ impl fmt::Display for GetContentModerationError {
     }
 }
 impl Error for GetContentModerationError {}
+impl ServiceErrorCode for GetContentModerationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContentModerationError::AccessDenied(_) => "AccessDeniedException",
+            GetContentModerationError::InternalServerError(_) => "InternalServerError",
+            GetContentModerationError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            GetContentModerationError::InvalidParameter(_) => "InvalidParameterException",
+            GetContentModerationError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            GetContentModerationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetContentModerationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetFaceDetection
 #[derive(Debug, PartialEq)]
 pub enum GetFaceDetectionError {
@@ -4762,6 +5120,21 @@ impl fmt::Display for GetFaceDetectionError {
     }
 }
 impl Error for GetFaceDetectionError {}
+impl ServiceErrorCode for GetFaceDetectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFaceDetectionError::AccessDenied(_) => "AccessDeniedException",
+            GetFaceDetectionError::InternalServerError(_) => "InternalServerError",
+            GetFaceDetectionError::InvalidPaginationToken(_) => "InvalidPaginationTokenException",
+            GetFaceDetectionError::InvalidParameter(_) => "InvalidParameterException",
+            GetFaceDetectionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            GetFaceDetectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetFaceDetectionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetFaceSearch
 #[derive(Debug, PartialEq)]
 pub enum GetFaceSearchError {
@@ -4832,6 +5205,21 @@ impl fmt::Display for GetFaceSearchError {
     }
 }
 impl Error for GetFaceSearchError {}
+impl ServiceErrorCode for GetFaceSearchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFaceSearchError::AccessDenied(_) => "AccessDeniedException",
+            GetFaceSearchError::InternalServerError(_) => "InternalServerError",
+            GetFaceSearchError::InvalidPaginationToken(_) => "InvalidPaginationTokenException",
+            GetFaceSearchError::InvalidParameter(_) => "InvalidParameterException",
+            GetFaceSearchError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            GetFaceSearchError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetFaceSearchError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetLabelDetection
 #[derive(Debug, PartialEq)]
 pub enum GetLabelDetectionError {
@@ -4906,6 +5294,21 @@ impl fmt::Display for GetLabelDetectionError {
     }
 }
 impl Error for GetLabelDetectionError {}
+impl ServiceErrorCode for GetLabelDetectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLabelDetectionError::AccessDenied(_) => "AccessDeniedException",
+            GetLabelDetectionError::InternalServerError(_) => "InternalServerError",
+            GetLabelDetectionError::InvalidPaginationToken(_) => "InvalidPaginationTokenException",
+            GetLabelDetectionError::InvalidParameter(_) => "InvalidParameterException",
+            GetLabelDetectionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            GetLabelDetectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetLabelDetectionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetPersonTracking
 #[derive(Debug, PartialEq)]
 pub enum GetPersonTrackingError {
@@ -4980,6 +5383,21 @@ impl fmt::Display for GetPersonTrackingError {
     }
 }
 impl Error for GetPersonTrackingError {}
+impl ServiceErrorCode for GetPersonTrackingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPersonTrackingError::AccessDenied(_) => "AccessDeniedException",
+            GetPersonTrackingError::InternalServerError(_) => "InternalServerError",
+            GetPersonTrackingError::InvalidPaginationToken(_) => "InvalidPaginationTokenException",
+            GetPersonTrackingError::InvalidParameter(_) => "InvalidParameterException",
+            GetPersonTrackingError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            GetPersonTrackingError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetPersonTrackingError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetSegmentDetection
 #[derive(Debug, PartialEq)]
 pub enum GetSegmentDetectionError {
@@ -5058,6 +5476,23 @@ impl fmt::Display for GetSegmentDetectionError {
     }
 }
 impl Error for GetSegmentDetectionError {}
+impl ServiceErrorCode for GetSegmentDetectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSegmentDetectionError::AccessDenied(_) => "AccessDeniedException",
+            GetSegmentDetectionError::InternalServerError(_) => "InternalServerError",
+            GetSegmentDetectionError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            GetSegmentDetectionError::InvalidParameter(_) => "InvalidParameterException",
+            GetSegmentDetectionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            GetSegmentDetectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetSegmentDetectionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetTextDetection
 #[derive(Debug, PartialEq)]
 pub enum GetTextDetectionError {
@@ -5132,6 +5567,21 @@ impl fmt::Display for GetTextDetectionError {
     }
 }
 impl Error for GetTextDetectionError {}
+impl ServiceErrorCode for GetTextDetectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTextDetectionError::AccessDenied(_) => "AccessDeniedException",
+            GetTextDetectionError::InternalServerError(_) => "InternalServerError",
+            GetTextDetectionError::InvalidPaginationToken(_) => "InvalidPaginationTokenException",
+            GetTextDetectionError::InvalidParameter(_) => "InvalidParameterException",
+            GetTextDetectionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            GetTextDetectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetTextDetectionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by IndexFaces
 #[derive(Debug, PartialEq)]
 pub enum IndexFacesError {
@@ -5218,6 +5668,24 @@ impl fmt::Display for IndexFacesError {
     }
 }
 impl Error for IndexFacesError {}
+impl ServiceErrorCode for IndexFacesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            IndexFacesError::AccessDenied(_) => "AccessDeniedException",
+            IndexFacesError::ImageTooLarge(_) => "ImageTooLargeException",
+            IndexFacesError::InternalServerError(_) => "InternalServerError",
+            IndexFacesError::InvalidImageFormat(_) => "InvalidImageFormatException",
+            IndexFacesError::InvalidParameter(_) => "InvalidParameterException",
+            IndexFacesError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            IndexFacesError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            IndexFacesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            IndexFacesError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            IndexFacesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListCollections
 #[derive(Debug, PartialEq)]
 pub enum ListCollectionsError {
@@ -5290,6 +5758,21 @@ impl fmt::Display for ListCollectionsError {
     }
 }
 impl Error for ListCollectionsError {}
+impl ServiceErrorCode for ListCollectionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCollectionsError::AccessDenied(_) => "AccessDeniedException",
+            ListCollectionsError::InternalServerError(_) => "InternalServerError",
+            ListCollectionsError::InvalidPaginationToken(_) => "InvalidPaginationTokenException",
+            ListCollectionsError::InvalidParameter(_) => "InvalidParameterException",
+            ListCollectionsError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            ListCollectionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListCollectionsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListFaces
 #[derive(Debug, PartialEq)]
 pub enum ListFacesError {
@@ -5358,6 +5841,21 @@ impl fmt::Display for ListFacesError {
     }
 }
 impl Error for ListFacesError {}
+impl ServiceErrorCode for ListFacesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFacesError::AccessDenied(_) => "AccessDeniedException",
+            ListFacesError::InternalServerError(_) => "InternalServerError",
+            ListFacesError::InvalidPaginationToken(_) => "InvalidPaginationTokenException",
+            ListFacesError::InvalidParameter(_) => "InvalidParameterException",
+            ListFacesError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            ListFacesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListFacesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListStreamProcessors
 #[derive(Debug, PartialEq)]
 pub enum ListStreamProcessorsError {
@@ -5428,6 +5926,22 @@ impl fmt::Display for ListStreamProcessorsError {
     }
 }
 impl Error for ListStreamProcessorsError {}
+impl ServiceErrorCode for ListStreamProcessorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStreamProcessorsError::AccessDenied(_) => "AccessDeniedException",
+            ListStreamProcessorsError::InternalServerError(_) => "InternalServerError",
+            ListStreamProcessorsError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            ListStreamProcessorsError::InvalidParameter(_) => "InvalidParameterException",
+            ListStreamProcessorsError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            ListStreamProcessorsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by RecognizeCelebrities
 #[derive(Debug, PartialEq)]
 pub enum RecognizeCelebritiesError {
@@ -5512,6 +6026,22 @@ impl fmt::Display for RecognizeCelebritiesError {
     }
 }
 impl Error for RecognizeCelebritiesError {}
+impl ServiceErrorCode for RecognizeCelebritiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RecognizeCelebritiesError::AccessDenied(_) => "AccessDeniedException",
+            RecognizeCelebritiesError::ImageTooLarge(_) => "ImageTooLargeException",
+            RecognizeCelebritiesError::InternalServerError(_) => "InternalServerError",
+            RecognizeCelebritiesError::InvalidImageFormat(_) => "InvalidImageFormatException",
+            RecognizeCelebritiesError::InvalidParameter(_) => "InvalidParameterException",
+            RecognizeCelebritiesError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            RecognizeCelebritiesError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            RecognizeCelebritiesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by SearchFaces
 #[derive(Debug, PartialEq)]
 pub enum SearchFacesError {
@@ -5574,6 +6104,20 @@ impl fmt::Display for SearchFacesError {
     }
 }
 impl Error for SearchFacesError {}
+impl ServiceErrorCode for SearchFacesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchFacesError::AccessDenied(_) => "AccessDeniedException",
+            SearchFacesError::InternalServerError(_) => "InternalServerError",
+            SearchFacesError::InvalidParameter(_) => "InvalidParameterException",
+            SearchFacesError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            SearchFacesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SearchFacesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by SearchFacesByImage
 #[derive(Debug, PartialEq)]
 pub enum SearchFacesByImageError {
@@ -5660,6 +6204,23 @@ impl fmt::Display for SearchFacesByImageError {
     }
 }
 impl Error for SearchFacesByImageError {}
+impl ServiceErrorCode for SearchFacesByImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchFacesByImageError::AccessDenied(_) => "AccessDeniedException",
+            SearchFacesByImageError::ImageTooLarge(_) => "ImageTooLargeException",
+            SearchFacesByImageError::InternalServerError(_) => "InternalServerError",
+            SearchFacesByImageError::InvalidImageFormat(_) => "InvalidImageFormatException",
+            SearchFacesByImageError::InvalidParameter(_) => "InvalidParameterException",
+            SearchFacesByImageError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            SearchFacesByImageError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            SearchFacesByImageError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SearchFacesByImageError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StartCelebrityRecognition
 #[derive(Debug, PartialEq)]
 pub enum StartCelebrityRecognitionError {
@@ -5762,6 +6323,25 @@ impl fmt::Display for StartCelebrityRecognitionError {
     }
 }
 impl Error for StartCelebrityRecognitionError {}
+impl ServiceErrorCode for StartCelebrityRecognitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartCelebrityRecognitionError::AccessDenied(_) => "AccessDeniedException",
+            StartCelebrityRecognitionError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            StartCelebrityRecognitionError::InternalServerError(_) => "InternalServerError",
+            StartCelebrityRecognitionError::InvalidParameter(_) => "InvalidParameterException",
+            StartCelebrityRecognitionError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            StartCelebrityRecognitionError::LimitExceeded(_) => "LimitExceededException",
+            StartCelebrityRecognitionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            StartCelebrityRecognitionError::Throttling(_) => "ThrottlingException",
+            StartCelebrityRecognitionError::VideoTooLarge(_) => "VideoTooLargeException",
+        }
+    }
+}
 /// Errors returned by StartContentModeration
 #[derive(Debug, PartialEq)]
 pub enum StartContentModerationError {
@@ -5858,6 +6438,25 @@ impl fmt::Display for StartContentModerationError {
     }
 }
 impl Error for StartContentModerationError {}
+impl ServiceErrorCode for StartContentModerationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartContentModerationError::AccessDenied(_) => "AccessDeniedException",
+            StartContentModerationError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            StartContentModerationError::InternalServerError(_) => "InternalServerError",
+            StartContentModerationError::InvalidParameter(_) => "InvalidParameterException",
+            StartContentModerationError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            StartContentModerationError::LimitExceeded(_) => "LimitExceededException",
+            StartContentModerationError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            StartContentModerationError::Throttling(_) => "ThrottlingException",
+            StartContentModerationError::VideoTooLarge(_) => "VideoTooLargeException",
+        }
+    }
+}
 /// Errors returned by StartFaceDetection
 #[derive(Debug, PartialEq)]
 pub enum StartFaceDetectionError {
@@ -5946,6 +6545,25 @@ impl fmt::Display for StartFaceDetectionError {
     }
 }
 impl Error for StartFaceDetectionError {}
+impl ServiceErrorCode for StartFaceDetectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartFaceDetectionError::AccessDenied(_) => "AccessDeniedException",
+            StartFaceDetectionError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            StartFaceDetectionError::InternalServerError(_) => "InternalServerError",
+            StartFaceDetectionError::InvalidParameter(_) => "InvalidParameterException",
+            StartFaceDetectionError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            StartFaceDetectionError::LimitExceeded(_) => "LimitExceededException",
+            StartFaceDetectionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            StartFaceDetectionError::Throttling(_) => "ThrottlingException",
+            StartFaceDetectionError::VideoTooLarge(_) => "VideoTooLargeException",
+        }
+    }
+}
 /// Errors returned by StartFaceSearch
 #[derive(Debug, PartialEq)]
 pub enum StartFaceSearchError {
@@ -6036,6 +6654,26 @@ impl fmt::Display for StartFaceSearchError {
     }
 }
 impl Error for StartFaceSearchError {}
+impl ServiceErrorCode for StartFaceSearchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartFaceSearchError::AccessDenied(_) => "AccessDeniedException",
+            StartFaceSearchError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            StartFaceSearchError::InternalServerError(_) => "InternalServerError",
+            StartFaceSearchError::InvalidParameter(_) => "InvalidParameterException",
+            StartFaceSearchError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            StartFaceSearchError::LimitExceeded(_) => "LimitExceededException",
+            StartFaceSearchError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            StartFaceSearchError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartFaceSearchError::Throttling(_) => "ThrottlingException",
+            StartFaceSearchError::VideoTooLarge(_) => "VideoTooLargeException",
+        }
+    }
+}
 /// Errors returned by StartLabelDetection
 #[derive(Debug, PartialEq)]
 pub enum StartLabelDetectionError {
@@ -6126,6 +6764,25 @@ impl fmt::Display for StartLabelDetectionError {
     }
 }
 impl Error for StartLabelDetectionError {}
+impl ServiceErrorCode for StartLabelDetectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartLabelDetectionError::AccessDenied(_) => "AccessDeniedException",
+            StartLabelDetectionError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            StartLabelDetectionError::InternalServerError(_) => "InternalServerError",
+            StartLabelDetectionError::InvalidParameter(_) => "InvalidParameterException",
+            StartLabelDetectionError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            StartLabelDetectionError::LimitExceeded(_) => "LimitExceededException",
+            StartLabelDetectionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            StartLabelDetectionError::Throttling(_) => "ThrottlingException",
+            StartLabelDetectionError::VideoTooLarge(_) => "VideoTooLargeException",
+        }
+    }
+}
 /// Errors returned by StartPersonTracking
 #[derive(Debug, PartialEq)]
 pub enum StartPersonTrackingError {
@@ -6216,6 +6873,25 @@ impl fmt::Display for StartPersonTrackingError {
     }
 }
 impl Error for StartPersonTrackingError {}
+impl ServiceErrorCode for StartPersonTrackingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartPersonTrackingError::AccessDenied(_) => "AccessDeniedException",
+            StartPersonTrackingError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            StartPersonTrackingError::InternalServerError(_) => "InternalServerError",
+            StartPersonTrackingError::InvalidParameter(_) => "InvalidParameterException",
+            StartPersonTrackingError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            StartPersonTrackingError::LimitExceeded(_) => "LimitExceededException",
+            StartPersonTrackingError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            StartPersonTrackingError::Throttling(_) => "ThrottlingException",
+            StartPersonTrackingError::VideoTooLarge(_) => "VideoTooLargeException",
+        }
+    }
+}
 /// Errors returned by StartProjectVersion
 #[derive(Debug, PartialEq)]
 pub enum StartProjectVersionError {
@@ -6298,6 +6974,22 @@ impl fmt::Display for StartProjectVersionError {
     }
 }
 impl Error for StartProjectVersionError {}
+impl ServiceErrorCode for StartProjectVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartProjectVersionError::AccessDenied(_) => "AccessDeniedException",
+            StartProjectVersionError::InternalServerError(_) => "InternalServerError",
+            StartProjectVersionError::InvalidParameter(_) => "InvalidParameterException",
+            StartProjectVersionError::LimitExceeded(_) => "LimitExceededException",
+            StartProjectVersionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            StartProjectVersionError::ResourceInUse(_) => "ResourceInUseException",
+            StartProjectVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartProjectVersionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StartSegmentDetection
 #[derive(Debug, PartialEq)]
 pub enum StartSegmentDetectionError {
@@ -6390,6 +7082,25 @@ impl fmt::Display for StartSegmentDetectionError {
     }
 }
 impl Error for StartSegmentDetectionError {}
+impl ServiceErrorCode for StartSegmentDetectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartSegmentDetectionError::AccessDenied(_) => "AccessDeniedException",
+            StartSegmentDetectionError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            StartSegmentDetectionError::InternalServerError(_) => "InternalServerError",
+            StartSegmentDetectionError::InvalidParameter(_) => "InvalidParameterException",
+            StartSegmentDetectionError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            StartSegmentDetectionError::LimitExceeded(_) => "LimitExceededException",
+            StartSegmentDetectionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            StartSegmentDetectionError::Throttling(_) => "ThrottlingException",
+            StartSegmentDetectionError::VideoTooLarge(_) => "VideoTooLargeException",
+        }
+    }
+}
 /// Errors returned by StartStreamProcessor
 #[derive(Debug, PartialEq)]
 pub enum StartStreamProcessorError {
@@ -6466,6 +7177,21 @@ impl fmt::Display for StartStreamProcessorError {
     }
 }
 impl Error for StartStreamProcessorError {}
+impl ServiceErrorCode for StartStreamProcessorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartStreamProcessorError::AccessDenied(_) => "AccessDeniedException",
+            StartStreamProcessorError::InternalServerError(_) => "InternalServerError",
+            StartStreamProcessorError::InvalidParameter(_) => "InvalidParameterException",
+            StartStreamProcessorError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            StartStreamProcessorError::ResourceInUse(_) => "ResourceInUseException",
+            StartStreamProcessorError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartStreamProcessorError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StartTextDetection
 #[derive(Debug, PartialEq)]
 pub enum StartTextDetectionError {
@@ -6554,6 +7280,25 @@ impl fmt::Display for StartTextDetectionError {
     }
 }
 impl Error for StartTextDetectionError {}
+impl ServiceErrorCode for StartTextDetectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartTextDetectionError::AccessDenied(_) => "AccessDeniedException",
+            StartTextDetectionError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            StartTextDetectionError::InternalServerError(_) => "InternalServerError",
+            StartTextDetectionError::InvalidParameter(_) => "InvalidParameterException",
+            StartTextDetectionError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            StartTextDetectionError::LimitExceeded(_) => "LimitExceededException",
+            StartTextDetectionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            StartTextDetectionError::Throttling(_) => "ThrottlingException",
+            StartTextDetectionError::VideoTooLarge(_) => "VideoTooLargeException",
+        }
+    }
+}
 /// Errors returned by StopProjectVersion
 #[derive(Debug, PartialEq)]
 pub enum StopProjectVersionError {
@@ -6626,6 +7371,21 @@ impl fmt::Display for StopProjectVersionError {
     }
 }
 impl Error for StopProjectVersionError {}
+impl ServiceErrorCode for StopProjectVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopProjectVersionError::AccessDenied(_) => "AccessDeniedException",
+            StopProjectVersionError::InternalServerError(_) => "InternalServerError",
+            StopProjectVersionError::InvalidParameter(_) => "InvalidParameterException",
+            StopProjectVersionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            StopProjectVersionError::ResourceInUse(_) => "ResourceInUseException",
+            StopProjectVersionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StopProjectVersionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StopStreamProcessor
 #[derive(Debug, PartialEq)]
 pub enum StopStreamProcessorError {
@@ -6702,6 +7462,21 @@ impl fmt::Display for StopStreamProcessorError {
     }
 }
 impl Error for StopStreamProcessorError {}
+impl ServiceErrorCode for StopStreamProcessorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopStreamProcessorError::AccessDenied(_) => "AccessDeniedException",
+            StopStreamProcessorError::InternalServerError(_) => "InternalServerError",
+            StopStreamProcessorError::InvalidParameter(_) => "InvalidParameterException",
+            StopStreamProcessorError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            StopStreamProcessorError::ResourceInUse(_) => "ResourceInUseException",
+            StopStreamProcessorError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StopStreamProcessorError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Rekognition API. Amazon Rekognition clients implement this trait.
 #[async_trait]
 pub trait Rekognition {
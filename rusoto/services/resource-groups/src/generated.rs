@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -626,6 +626,17 @@ impl fmt::Display for CreateGroupError {
     }
 }
 impl Error for CreateGroupError {}
+impl ServiceErrorCode for CreateGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGroupError::BadRequest(_) => "BadRequestException",
+            CreateGroupError::Forbidden(_) => "ForbiddenException",
+            CreateGroupError::InternalServerError(_) => "InternalServerErrorException",
+            CreateGroupError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            CreateGroupError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteGroupError {
@@ -686,6 +697,18 @@ impl fmt::Display for DeleteGroupError {
     }
 }
 impl Error for DeleteGroupError {}
+impl ServiceErrorCode for DeleteGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGroupError::BadRequest(_) => "BadRequestException",
+            DeleteGroupError::Forbidden(_) => "ForbiddenException",
+            DeleteGroupError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteGroupError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            DeleteGroupError::NotFound(_) => "NotFoundException",
+            DeleteGroupError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetGroup
 #[derive(Debug, PartialEq)]
 pub enum GetGroupError {
@@ -746,6 +769,18 @@ impl fmt::Display for GetGroupError {
     }
 }
 impl Error for GetGroupError {}
+impl ServiceErrorCode for GetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGroupError::BadRequest(_) => "BadRequestException",
+            GetGroupError::Forbidden(_) => "ForbiddenException",
+            GetGroupError::InternalServerError(_) => "InternalServerErrorException",
+            GetGroupError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            GetGroupError::NotFound(_) => "NotFoundException",
+            GetGroupError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetGroupConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetGroupConfigurationError {
@@ -812,6 +847,18 @@ impl fmt::Display for GetGroupConfigurationError {
     }
 }
 impl Error for GetGroupConfigurationError {}
+impl ServiceErrorCode for GetGroupConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGroupConfigurationError::BadRequest(_) => "BadRequestException",
+            GetGroupConfigurationError::Forbidden(_) => "ForbiddenException",
+            GetGroupConfigurationError::InternalServerError(_) => "InternalServerErrorException",
+            GetGroupConfigurationError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            GetGroupConfigurationError::NotFound(_) => "NotFoundException",
+            GetGroupConfigurationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetGroupQuery
 #[derive(Debug, PartialEq)]
 pub enum GetGroupQueryError {
@@ -872,6 +919,18 @@ impl fmt::Display for GetGroupQueryError {
     }
 }
 impl Error for GetGroupQueryError {}
+impl ServiceErrorCode for GetGroupQueryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGroupQueryError::BadRequest(_) => "BadRequestException",
+            GetGroupQueryError::Forbidden(_) => "ForbiddenException",
+            GetGroupQueryError::InternalServerError(_) => "InternalServerErrorException",
+            GetGroupQueryError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            GetGroupQueryError::NotFound(_) => "NotFoundException",
+            GetGroupQueryError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetTags
 #[derive(Debug, PartialEq)]
 pub enum GetTagsError {
@@ -932,6 +991,18 @@ impl fmt::Display for GetTagsError {
     }
 }
 impl Error for GetTagsError {}
+impl ServiceErrorCode for GetTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTagsError::BadRequest(_) => "BadRequestException",
+            GetTagsError::Forbidden(_) => "ForbiddenException",
+            GetTagsError::InternalServerError(_) => "InternalServerErrorException",
+            GetTagsError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            GetTagsError::NotFound(_) => "NotFoundException",
+            GetTagsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GroupResources
 #[derive(Debug, PartialEq)]
 pub enum GroupResourcesError {
@@ -992,6 +1063,18 @@ impl fmt::Display for GroupResourcesError {
     }
 }
 impl Error for GroupResourcesError {}
+impl ServiceErrorCode for GroupResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GroupResourcesError::BadRequest(_) => "BadRequestException",
+            GroupResourcesError::Forbidden(_) => "ForbiddenException",
+            GroupResourcesError::InternalServerError(_) => "InternalServerErrorException",
+            GroupResourcesError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            GroupResourcesError::NotFound(_) => "NotFoundException",
+            GroupResourcesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListGroupResources
 #[derive(Debug, PartialEq)]
 pub enum ListGroupResourcesError {
@@ -1060,6 +1143,19 @@ impl fmt::Display for ListGroupResourcesError {
     }
 }
 impl Error for ListGroupResourcesError {}
+impl ServiceErrorCode for ListGroupResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGroupResourcesError::BadRequest(_) => "BadRequestException",
+            ListGroupResourcesError::Forbidden(_) => "ForbiddenException",
+            ListGroupResourcesError::InternalServerError(_) => "InternalServerErrorException",
+            ListGroupResourcesError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            ListGroupResourcesError::NotFound(_) => "NotFoundException",
+            ListGroupResourcesError::TooManyRequests(_) => "TooManyRequestsException",
+            ListGroupResourcesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListGroups
 #[derive(Debug, PartialEq)]
 pub enum ListGroupsError {
@@ -1114,6 +1210,17 @@ impl fmt::Display for ListGroupsError {
     }
 }
 impl Error for ListGroupsError {}
+impl ServiceErrorCode for ListGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGroupsError::BadRequest(_) => "BadRequestException",
+            ListGroupsError::Forbidden(_) => "ForbiddenException",
+            ListGroupsError::InternalServerError(_) => "InternalServerErrorException",
+            ListGroupsError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            ListGroupsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by SearchResources
 #[derive(Debug, PartialEq)]
 pub enum SearchResourcesError {
@@ -1174,6 +1281,18 @@ impl fmt::Display for SearchResourcesError {
     }
 }
 impl Error for SearchResourcesError {}
+impl ServiceErrorCode for SearchResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchResourcesError::BadRequest(_) => "BadRequestException",
+            SearchResourcesError::Forbidden(_) => "ForbiddenException",
+            SearchResourcesError::InternalServerError(_) => "InternalServerErrorException",
+            SearchResourcesError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            SearchResourcesError::TooManyRequests(_) => "TooManyRequestsException",
+            SearchResourcesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by Tag
 #[derive(Debug, PartialEq)]
 pub enum TagError {
@@ -1230,6 +1349,18 @@ impl fmt::Display for TagError {
     }
 }
 impl Error for TagError {}
+impl ServiceErrorCode for TagError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagError::BadRequest(_) => "BadRequestException",
+            TagError::Forbidden(_) => "ForbiddenException",
+            TagError::InternalServerError(_) => "InternalServerErrorException",
+            TagError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            TagError::NotFound(_) => "NotFoundException",
+            TagError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UngroupResources
 #[derive(Debug, PartialEq)]
 pub enum UngroupResourcesError {
@@ -1292,6 +1423,18 @@ impl fmt::Display for UngroupResourcesError {
     }
 }
 impl Error for UngroupResourcesError {}
+impl ServiceErrorCode for UngroupResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UngroupResourcesError::BadRequest(_) => "BadRequestException",
+            UngroupResourcesError::Forbidden(_) => "ForbiddenException",
+            UngroupResourcesError::InternalServerError(_) => "InternalServerErrorException",
+            UngroupResourcesError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            UngroupResourcesError::NotFound(_) => "NotFoundException",
+            UngroupResourcesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by Untag
 #[derive(Debug, PartialEq)]
 pub enum UntagError {
@@ -1350,6 +1493,18 @@ impl fmt::Display for UntagError {
     }
 }
 impl Error for UntagError {}
+impl ServiceErrorCode for UntagError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagError::BadRequest(_) => "BadRequestException",
+            UntagError::Forbidden(_) => "ForbiddenException",
+            UntagError::InternalServerError(_) => "InternalServerErrorException",
+            UntagError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            UntagError::NotFound(_) => "NotFoundException",
+            UntagError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateGroupError {
@@ -1410,6 +1565,18 @@ impl fmt::Display for UpdateGroupError {
     }
 }
 impl Error for UpdateGroupError {}
+impl ServiceErrorCode for UpdateGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGroupError::BadRequest(_) => "BadRequestException",
+            UpdateGroupError::Forbidden(_) => "ForbiddenException",
+            UpdateGroupError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateGroupError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            UpdateGroupError::NotFound(_) => "NotFoundException",
+            UpdateGroupError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateGroupQuery
 #[derive(Debug, PartialEq)]
 pub enum UpdateGroupQueryError {
@@ -1472,6 +1639,18 @@ impl fmt::Display for UpdateGroupQueryError {
     }
 }
 impl Error for UpdateGroupQueryError {}
+impl ServiceErrorCode for UpdateGroupQueryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGroupQueryError::BadRequest(_) => "BadRequestException",
+            UpdateGroupQueryError::Forbidden(_) => "ForbiddenException",
+            UpdateGroupQueryError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateGroupQueryError::MethodNotAllowed(_) => "MethodNotAllowedException",
+            UpdateGroupQueryError::NotFound(_) => "NotFoundException",
+            UpdateGroupQueryError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Resource Groups API. Resource Groups clients implement this trait.
 #[async_trait]
 pub trait ResourceGroups {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -426,6 +426,16 @@ impl fmt::Display for DescribeReportCreationError {
     }
 }
 impl Error for DescribeReportCreationError {}
+impl ServiceErrorCode for DescribeReportCreationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReportCreationError::ConstraintViolation(_) => "ConstraintViolationException",
+            DescribeReportCreationError::InternalService(_) => "InternalServiceException",
+            DescribeReportCreationError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeReportCreationError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetComplianceSummary
 #[derive(Debug, PartialEq)]
 pub enum GetComplianceSummaryError {
@@ -480,6 +490,16 @@ impl fmt::Display for GetComplianceSummaryError {
     }
 }
 impl Error for GetComplianceSummaryError {}
+impl ServiceErrorCode for GetComplianceSummaryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetComplianceSummaryError::ConstraintViolation(_) => "ConstraintViolationException",
+            GetComplianceSummaryError::InternalService(_) => "InternalServiceException",
+            GetComplianceSummaryError::InvalidParameter(_) => "InvalidParameterException",
+            GetComplianceSummaryError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetResources
 #[derive(Debug, PartialEq)]
 pub enum GetResourcesError {
@@ -528,6 +548,16 @@ impl fmt::Display for GetResourcesError {
     }
 }
 impl Error for GetResourcesError {}
+impl ServiceErrorCode for GetResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourcesError::InternalService(_) => "InternalServiceException",
+            GetResourcesError::InvalidParameter(_) => "InvalidParameterException",
+            GetResourcesError::PaginationTokenExpired(_) => "PaginationTokenExpiredException",
+            GetResourcesError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetTagKeys
 #[derive(Debug, PartialEq)]
 pub enum GetTagKeysError {
@@ -576,6 +606,16 @@ impl fmt::Display for GetTagKeysError {
     }
 }
 impl Error for GetTagKeysError {}
+impl ServiceErrorCode for GetTagKeysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTagKeysError::InternalService(_) => "InternalServiceException",
+            GetTagKeysError::InvalidParameter(_) => "InvalidParameterException",
+            GetTagKeysError::PaginationTokenExpired(_) => "PaginationTokenExpiredException",
+            GetTagKeysError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetTagValues
 #[derive(Debug, PartialEq)]
 pub enum GetTagValuesError {
@@ -624,6 +664,16 @@ impl fmt::Display for GetTagValuesError {
     }
 }
 impl Error for GetTagValuesError {}
+impl ServiceErrorCode for GetTagValuesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTagValuesError::InternalService(_) => "InternalServiceException",
+            GetTagValuesError::InvalidParameter(_) => "InvalidParameterException",
+            GetTagValuesError::PaginationTokenExpired(_) => "PaginationTokenExpiredException",
+            GetTagValuesError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by StartReportCreation
 #[derive(Debug, PartialEq)]
 pub enum StartReportCreationError {
@@ -684,6 +734,19 @@ impl fmt::Display for StartReportCreationError {
     }
 }
 impl Error for StartReportCreationError {}
+impl ServiceErrorCode for StartReportCreationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartReportCreationError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            StartReportCreationError::ConstraintViolation(_) => "ConstraintViolationException",
+            StartReportCreationError::InternalService(_) => "InternalServiceException",
+            StartReportCreationError::InvalidParameter(_) => "InvalidParameterException",
+            StartReportCreationError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by TagResources
 #[derive(Debug, PartialEq)]
 pub enum TagResourcesError {
@@ -726,6 +789,15 @@ impl fmt::Display for TagResourcesError {
     }
 }
 impl Error for TagResourcesError {}
+impl ServiceErrorCode for TagResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourcesError::InternalService(_) => "InternalServiceException",
+            TagResourcesError::InvalidParameter(_) => "InvalidParameterException",
+            TagResourcesError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by UntagResources
 #[derive(Debug, PartialEq)]
 pub enum UntagResourcesError {
@@ -768,6 +840,15 @@ impl fmt::Display for UntagResourcesError {
     }
 }
 impl Error for UntagResourcesError {}
+impl ServiceErrorCode for UntagResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourcesError::InternalService(_) => "InternalServiceException",
+            UntagResourcesError::InvalidParameter(_) => "InvalidParameterException",
+            UntagResourcesError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Resource Groups Tagging API API. AWS Resource Groups Tagging API clients implement this trait.
 #[async_trait]
 pub trait ResourceGroupsTaggingApi {
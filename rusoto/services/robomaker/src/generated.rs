@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -159,6 +159,7 @@ pub struct ComputeResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateDeploymentJobRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     pub client_request_token: String,
     /// <p>The deployment application configuration.</p>
@@ -525,6 +526,7 @@ pub struct CreateSimulationApplicationVersionResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateSimulationJobRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -653,6 +655,7 @@ pub struct CreateSimulationJobResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateWorldExportJobRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -710,6 +713,7 @@ pub struct CreateWorldExportJobResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateWorldGenerationJobRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -2735,6 +2739,7 @@ pub struct StartSimulationJobBatchRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub batch_policy: Option<BatchPolicy>,
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -2800,6 +2805,7 @@ pub struct StartSimulationJobBatchResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct SyncDeploymentJobRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     pub client_request_token: String,
     /// <p>The target fleet for the synchronization.</p>
@@ -3264,6 +3270,15 @@ impl fmt::Display for BatchDeleteWorldsError {
     }
 }
 impl Error for BatchDeleteWorldsError {}
+impl ServiceErrorCode for BatchDeleteWorldsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDeleteWorldsError::InternalServer(_) => "InternalServerException",
+            BatchDeleteWorldsError::InvalidParameter(_) => "InvalidParameterException",
+            BatchDeleteWorldsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by BatchDescribeSimulationJob
 #[derive(Debug, PartialEq)]
 pub enum BatchDescribeSimulationJobError {
@@ -3322,6 +3337,16 @@ impl fmt::Display for BatchDescribeSimulationJobError {
     }
 }
 impl Error for BatchDescribeSimulationJobError {}
+impl ServiceErrorCode for BatchDescribeSimulationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDescribeSimulationJobError::InternalServer(_) => "InternalServerException",
+            BatchDescribeSimulationJobError::InvalidParameter(_) => "InvalidParameterException",
+            BatchDescribeSimulationJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            BatchDescribeSimulationJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CancelDeploymentJob
 #[derive(Debug, PartialEq)]
 pub enum CancelDeploymentJobError {
@@ -3374,6 +3399,16 @@ impl fmt::Display for CancelDeploymentJobError {
     }
 }
 impl Error for CancelDeploymentJobError {}
+impl ServiceErrorCode for CancelDeploymentJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelDeploymentJobError::InternalServer(_) => "InternalServerException",
+            CancelDeploymentJobError::InvalidParameter(_) => "InvalidParameterException",
+            CancelDeploymentJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelDeploymentJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CancelSimulationJob
 #[derive(Debug, PartialEq)]
 pub enum CancelSimulationJobError {
@@ -3426,6 +3461,16 @@ impl fmt::Display for CancelSimulationJobError {
     }
 }
 impl Error for CancelSimulationJobError {}
+impl ServiceErrorCode for CancelSimulationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelSimulationJobError::InternalServer(_) => "InternalServerException",
+            CancelSimulationJobError::InvalidParameter(_) => "InvalidParameterException",
+            CancelSimulationJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelSimulationJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CancelSimulationJobBatch
 #[derive(Debug, PartialEq)]
 pub enum CancelSimulationJobBatchError {
@@ -3480,6 +3525,16 @@ impl fmt::Display for CancelSimulationJobBatchError {
     }
 }
 impl Error for CancelSimulationJobBatchError {}
+impl ServiceErrorCode for CancelSimulationJobBatchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelSimulationJobBatchError::InternalServer(_) => "InternalServerException",
+            CancelSimulationJobBatchError::InvalidParameter(_) => "InvalidParameterException",
+            CancelSimulationJobBatchError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelSimulationJobBatchError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CancelWorldExportJob
 #[derive(Debug, PartialEq)]
 pub enum CancelWorldExportJobError {
@@ -3532,6 +3587,16 @@ impl fmt::Display for CancelWorldExportJobError {
     }
 }
 impl Error for CancelWorldExportJobError {}
+impl ServiceErrorCode for CancelWorldExportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelWorldExportJobError::InternalServer(_) => "InternalServerException",
+            CancelWorldExportJobError::InvalidParameter(_) => "InvalidParameterException",
+            CancelWorldExportJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelWorldExportJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CancelWorldGenerationJob
 #[derive(Debug, PartialEq)]
 pub enum CancelWorldGenerationJobError {
@@ -3586,6 +3651,16 @@ impl fmt::Display for CancelWorldGenerationJobError {
     }
 }
 impl Error for CancelWorldGenerationJobError {}
+impl ServiceErrorCode for CancelWorldGenerationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelWorldGenerationJobError::InternalServer(_) => "InternalServerException",
+            CancelWorldGenerationJobError::InvalidParameter(_) => "InvalidParameterException",
+            CancelWorldGenerationJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelWorldGenerationJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateDeploymentJob
 #[derive(Debug, PartialEq)]
 pub enum CreateDeploymentJobError {
@@ -3662,6 +3737,21 @@ impl fmt::Display for CreateDeploymentJobError {
     }
 }
 impl Error for CreateDeploymentJobError {}
+impl ServiceErrorCode for CreateDeploymentJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeploymentJobError::ConcurrentDeployment(_) => "ConcurrentDeploymentException",
+            CreateDeploymentJobError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateDeploymentJobError::InternalServer(_) => "InternalServerException",
+            CreateDeploymentJobError::InvalidParameter(_) => "InvalidParameterException",
+            CreateDeploymentJobError::LimitExceeded(_) => "LimitExceededException",
+            CreateDeploymentJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateDeploymentJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateFleet
 #[derive(Debug, PartialEq)]
 pub enum CreateFleetError {
@@ -3710,6 +3800,16 @@ impl fmt::Display for CreateFleetError {
     }
 }
 impl Error for CreateFleetError {}
+impl ServiceErrorCode for CreateFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFleetError::InternalServer(_) => "InternalServerException",
+            CreateFleetError::InvalidParameter(_) => "InvalidParameterException",
+            CreateFleetError::LimitExceeded(_) => "LimitExceededException",
+            CreateFleetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateRobot
 #[derive(Debug, PartialEq)]
 pub enum CreateRobotError {
@@ -3764,6 +3864,17 @@ impl fmt::Display for CreateRobotError {
     }
 }
 impl Error for CreateRobotError {}
+impl ServiceErrorCode for CreateRobotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRobotError::InternalServer(_) => "InternalServerException",
+            CreateRobotError::InvalidParameter(_) => "InvalidParameterException",
+            CreateRobotError::LimitExceeded(_) => "LimitExceededException",
+            CreateRobotError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateRobotError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateRobotApplication
 #[derive(Debug, PartialEq)]
 pub enum CreateRobotApplicationError {
@@ -3836,6 +3947,22 @@ impl fmt::Display for CreateRobotApplicationError {
     }
 }
 impl Error for CreateRobotApplicationError {}
+impl ServiceErrorCode for CreateRobotApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRobotApplicationError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateRobotApplicationError::InternalServer(_) => "InternalServerException",
+            CreateRobotApplicationError::InvalidParameter(_) => "InvalidParameterException",
+            CreateRobotApplicationError::LimitExceeded(_) => "LimitExceededException",
+            CreateRobotApplicationError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateRobotApplicationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateRobotApplicationVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateRobotApplicationVersionError {
@@ -3906,6 +4033,19 @@ impl fmt::Display for CreateRobotApplicationVersionError {
     }
 }
 impl Error for CreateRobotApplicationVersionError {}
+impl ServiceErrorCode for CreateRobotApplicationVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRobotApplicationVersionError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateRobotApplicationVersionError::InternalServer(_) => "InternalServerException",
+            CreateRobotApplicationVersionError::InvalidParameter(_) => "InvalidParameterException",
+            CreateRobotApplicationVersionError::LimitExceeded(_) => "LimitExceededException",
+            CreateRobotApplicationVersionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateSimulationApplication
 #[derive(Debug, PartialEq)]
 pub enum CreateSimulationApplicationError {
@@ -3984,6 +4124,22 @@ impl fmt::Display for CreateSimulationApplicationError {
     }
 }
 impl Error for CreateSimulationApplicationError {}
+impl ServiceErrorCode for CreateSimulationApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSimulationApplicationError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateSimulationApplicationError::InternalServer(_) => "InternalServerException",
+            CreateSimulationApplicationError::InvalidParameter(_) => "InvalidParameterException",
+            CreateSimulationApplicationError::LimitExceeded(_) => "LimitExceededException",
+            CreateSimulationApplicationError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateSimulationApplicationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateSimulationApplicationVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateSimulationApplicationVersionError {
@@ -4062,6 +4218,21 @@ impl fmt::Display for CreateSimulationApplicationVersionError {
     }
 }
 impl Error for CreateSimulationApplicationVersionError {}
+impl ServiceErrorCode for CreateSimulationApplicationVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSimulationApplicationVersionError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateSimulationApplicationVersionError::InternalServer(_) => "InternalServerException",
+            CreateSimulationApplicationVersionError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            CreateSimulationApplicationVersionError::LimitExceeded(_) => "LimitExceededException",
+            CreateSimulationApplicationVersionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateSimulationJob
 #[derive(Debug, PartialEq)]
 pub enum CreateSimulationJobError {
@@ -4138,6 +4309,21 @@ impl fmt::Display for CreateSimulationJobError {
     }
 }
 impl Error for CreateSimulationJobError {}
+impl ServiceErrorCode for CreateSimulationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSimulationJobError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateSimulationJobError::InternalServer(_) => "InternalServerException",
+            CreateSimulationJobError::InvalidParameter(_) => "InvalidParameterException",
+            CreateSimulationJobError::LimitExceeded(_) => "LimitExceededException",
+            CreateSimulationJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateSimulationJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateSimulationJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateWorldExportJob
 #[derive(Debug, PartialEq)]
 pub enum CreateWorldExportJobError {
@@ -4208,6 +4394,20 @@ impl fmt::Display for CreateWorldExportJobError {
     }
 }
 impl Error for CreateWorldExportJobError {}
+impl ServiceErrorCode for CreateWorldExportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateWorldExportJobError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateWorldExportJobError::InternalServer(_) => "InternalServerException",
+            CreateWorldExportJobError::InvalidParameter(_) => "InvalidParameterException",
+            CreateWorldExportJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateWorldExportJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateWorldExportJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateWorldGenerationJob
 #[derive(Debug, PartialEq)]
 pub enum CreateWorldGenerationJobError {
@@ -4288,6 +4488,21 @@ impl fmt::Display for CreateWorldGenerationJobError {
     }
 }
 impl Error for CreateWorldGenerationJobError {}
+impl ServiceErrorCode for CreateWorldGenerationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateWorldGenerationJobError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            CreateWorldGenerationJobError::InternalServer(_) => "InternalServerException",
+            CreateWorldGenerationJobError::InvalidParameter(_) => "InvalidParameterException",
+            CreateWorldGenerationJobError::LimitExceeded(_) => "LimitExceededException",
+            CreateWorldGenerationJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateWorldGenerationJobError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateWorldGenerationJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateWorldTemplate
 #[derive(Debug, PartialEq)]
 pub enum CreateWorldTemplateError {
@@ -4354,6 +4569,18 @@ impl fmt::Display for CreateWorldTemplateError {
     }
 }
 impl Error for CreateWorldTemplateError {}
+impl ServiceErrorCode for CreateWorldTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateWorldTemplateError::InternalServer(_) => "InternalServerException",
+            CreateWorldTemplateError::InvalidParameter(_) => "InvalidParameterException",
+            CreateWorldTemplateError::LimitExceeded(_) => "LimitExceededException",
+            CreateWorldTemplateError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateWorldTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateWorldTemplateError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteFleet
 #[derive(Debug, PartialEq)]
 pub enum DeleteFleetError {
@@ -4396,6 +4623,15 @@ impl fmt::Display for DeleteFleetError {
     }
 }
 impl Error for DeleteFleetError {}
+impl ServiceErrorCode for DeleteFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFleetError::InternalServer(_) => "InternalServerException",
+            DeleteFleetError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteFleetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteRobot
 #[derive(Debug, PartialEq)]
 pub enum DeleteRobotError {
@@ -4438,6 +4674,15 @@ impl fmt::Display for DeleteRobotError {
     }
 }
 impl Error for DeleteRobotError {}
+impl ServiceErrorCode for DeleteRobotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRobotError::InternalServer(_) => "InternalServerException",
+            DeleteRobotError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteRobotError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteRobotApplication
 #[derive(Debug, PartialEq)]
 pub enum DeleteRobotApplicationError {
@@ -4484,6 +4729,15 @@ impl fmt::Display for DeleteRobotApplicationError {
     }
 }
 impl Error for DeleteRobotApplicationError {}
+impl ServiceErrorCode for DeleteRobotApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRobotApplicationError::InternalServer(_) => "InternalServerException",
+            DeleteRobotApplicationError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteRobotApplicationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteSimulationApplication
 #[derive(Debug, PartialEq)]
 pub enum DeleteSimulationApplicationError {
@@ -4534,6 +4788,15 @@ impl fmt::Display for DeleteSimulationApplicationError {
     }
 }
 impl Error for DeleteSimulationApplicationError {}
+impl ServiceErrorCode for DeleteSimulationApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSimulationApplicationError::InternalServer(_) => "InternalServerException",
+            DeleteSimulationApplicationError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteSimulationApplicationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteWorldTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeleteWorldTemplateError {
@@ -4586,6 +4849,16 @@ impl fmt::Display for DeleteWorldTemplateError {
     }
 }
 impl Error for DeleteWorldTemplateError {}
+impl ServiceErrorCode for DeleteWorldTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteWorldTemplateError::InternalServer(_) => "InternalServerException",
+            DeleteWorldTemplateError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteWorldTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteWorldTemplateError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeregisterRobot
 #[derive(Debug, PartialEq)]
 pub enum DeregisterRobotError {
@@ -4634,6 +4907,16 @@ impl fmt::Display for DeregisterRobotError {
     }
 }
 impl Error for DeregisterRobotError {}
+impl ServiceErrorCode for DeregisterRobotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterRobotError::InternalServer(_) => "InternalServerException",
+            DeregisterRobotError::InvalidParameter(_) => "InvalidParameterException",
+            DeregisterRobotError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeregisterRobotError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeDeploymentJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeDeploymentJobError {
@@ -4688,6 +4971,16 @@ impl fmt::Display for DescribeDeploymentJobError {
     }
 }
 impl Error for DescribeDeploymentJobError {}
+impl ServiceErrorCode for DescribeDeploymentJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDeploymentJobError::InternalServer(_) => "InternalServerException",
+            DescribeDeploymentJobError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeDeploymentJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDeploymentJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeFleet
 #[derive(Debug, PartialEq)]
 pub enum DescribeFleetError {
@@ -4736,6 +5029,16 @@ impl fmt::Display for DescribeFleetError {
     }
 }
 impl Error for DescribeFleetError {}
+impl ServiceErrorCode for DescribeFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFleetError::InternalServer(_) => "InternalServerException",
+            DescribeFleetError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeFleetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeFleetError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeRobot
 #[derive(Debug, PartialEq)]
 pub enum DescribeRobotError {
@@ -4784,6 +5087,16 @@ impl fmt::Display for DescribeRobotError {
     }
 }
 impl Error for DescribeRobotError {}
+impl ServiceErrorCode for DescribeRobotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRobotError::InternalServer(_) => "InternalServerException",
+            DescribeRobotError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeRobotError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeRobotError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeRobotApplication
 #[derive(Debug, PartialEq)]
 pub enum DescribeRobotApplicationError {
@@ -4838,6 +5151,16 @@ impl fmt::Display for DescribeRobotApplicationError {
     }
 }
 impl Error for DescribeRobotApplicationError {}
+impl ServiceErrorCode for DescribeRobotApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRobotApplicationError::InternalServer(_) => "InternalServerException",
+            DescribeRobotApplicationError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeRobotApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeRobotApplicationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeSimulationApplication
 #[derive(Debug, PartialEq)]
 pub enum DescribeSimulationApplicationError {
@@ -4900,6 +5223,16 @@ impl fmt::Display for DescribeSimulationApplicationError {
     }
 }
 impl Error for DescribeSimulationApplicationError {}
+impl ServiceErrorCode for DescribeSimulationApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSimulationApplicationError::InternalServer(_) => "InternalServerException",
+            DescribeSimulationApplicationError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeSimulationApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeSimulationApplicationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeSimulationJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeSimulationJobError {
@@ -4954,6 +5287,16 @@ impl fmt::Display for DescribeSimulationJobError {
     }
 }
 impl Error for DescribeSimulationJobError {}
+impl ServiceErrorCode for DescribeSimulationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSimulationJobError::InternalServer(_) => "InternalServerException",
+            DescribeSimulationJobError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeSimulationJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeSimulationJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeSimulationJobBatch
 #[derive(Debug, PartialEq)]
 pub enum DescribeSimulationJobBatchError {
@@ -5004,6 +5347,15 @@ impl fmt::Display for DescribeSimulationJobBatchError {
     }
 }
 impl Error for DescribeSimulationJobBatchError {}
+impl ServiceErrorCode for DescribeSimulationJobBatchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSimulationJobBatchError::InternalServer(_) => "InternalServerException",
+            DescribeSimulationJobBatchError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeSimulationJobBatchError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeWorld
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorldError {
@@ -5052,6 +5404,16 @@ impl fmt::Display for DescribeWorldError {
     }
 }
 impl Error for DescribeWorldError {}
+impl ServiceErrorCode for DescribeWorldError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeWorldError::InternalServer(_) => "InternalServerException",
+            DescribeWorldError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeWorldError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeWorldError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeWorldExportJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorldExportJobError {
@@ -5106,6 +5468,16 @@ impl fmt::Display for DescribeWorldExportJobError {
     }
 }
 impl Error for DescribeWorldExportJobError {}
+impl ServiceErrorCode for DescribeWorldExportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeWorldExportJobError::InternalServer(_) => "InternalServerException",
+            DescribeWorldExportJobError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeWorldExportJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeWorldExportJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeWorldGenerationJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorldGenerationJobError {
@@ -5164,6 +5536,16 @@ impl fmt::Display for DescribeWorldGenerationJobError {
     }
 }
 impl Error for DescribeWorldGenerationJobError {}
+impl ServiceErrorCode for DescribeWorldGenerationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeWorldGenerationJobError::InternalServer(_) => "InternalServerException",
+            DescribeWorldGenerationJobError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeWorldGenerationJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeWorldGenerationJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeWorldTemplate
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorldTemplateError {
@@ -5218,6 +5600,16 @@ impl fmt::Display for DescribeWorldTemplateError {
     }
 }
 impl Error for DescribeWorldTemplateError {}
+impl ServiceErrorCode for DescribeWorldTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeWorldTemplateError::InternalServer(_) => "InternalServerException",
+            DescribeWorldTemplateError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeWorldTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeWorldTemplateError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetWorldTemplateBody
 #[derive(Debug, PartialEq)]
 pub enum GetWorldTemplateBodyError {
@@ -5270,6 +5662,16 @@ impl fmt::Display for GetWorldTemplateBodyError {
     }
 }
 impl Error for GetWorldTemplateBodyError {}
+impl ServiceErrorCode for GetWorldTemplateBodyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetWorldTemplateBodyError::InternalServer(_) => "InternalServerException",
+            GetWorldTemplateBodyError::InvalidParameter(_) => "InvalidParameterException",
+            GetWorldTemplateBodyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetWorldTemplateBodyError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListDeploymentJobs
 #[derive(Debug, PartialEq)]
 pub enum ListDeploymentJobsError {
@@ -5318,6 +5720,16 @@ impl fmt::Display for ListDeploymentJobsError {
     }
 }
 impl Error for ListDeploymentJobsError {}
+impl ServiceErrorCode for ListDeploymentJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeploymentJobsError::InternalServer(_) => "InternalServerException",
+            ListDeploymentJobsError::InvalidParameter(_) => "InvalidParameterException",
+            ListDeploymentJobsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListDeploymentJobsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListFleets
 #[derive(Debug, PartialEq)]
 pub enum ListFleetsError {
@@ -5366,6 +5778,16 @@ impl fmt::Display for ListFleetsError {
     }
 }
 impl Error for ListFleetsError {}
+impl ServiceErrorCode for ListFleetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFleetsError::InternalServer(_) => "InternalServerException",
+            ListFleetsError::InvalidParameter(_) => "InvalidParameterException",
+            ListFleetsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListFleetsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListRobotApplications
 #[derive(Debug, PartialEq)]
 pub enum ListRobotApplicationsError {
@@ -5412,6 +5834,15 @@ impl fmt::Display for ListRobotApplicationsError {
     }
 }
 impl Error for ListRobotApplicationsError {}
+impl ServiceErrorCode for ListRobotApplicationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRobotApplicationsError::InternalServer(_) => "InternalServerException",
+            ListRobotApplicationsError::InvalidParameter(_) => "InvalidParameterException",
+            ListRobotApplicationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListRobots
 #[derive(Debug, PartialEq)]
 pub enum ListRobotsError {
@@ -5460,6 +5891,16 @@ impl fmt::Display for ListRobotsError {
     }
 }
 impl Error for ListRobotsError {}
+impl ServiceErrorCode for ListRobotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRobotsError::InternalServer(_) => "InternalServerException",
+            ListRobotsError::InvalidParameter(_) => "InvalidParameterException",
+            ListRobotsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListRobotsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListSimulationApplications
 #[derive(Debug, PartialEq)]
 pub enum ListSimulationApplicationsError {
@@ -5510,6 +5951,15 @@ impl fmt::Display for ListSimulationApplicationsError {
     }
 }
 impl Error for ListSimulationApplicationsError {}
+impl ServiceErrorCode for ListSimulationApplicationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSimulationApplicationsError::InternalServer(_) => "InternalServerException",
+            ListSimulationApplicationsError::InvalidParameter(_) => "InvalidParameterException",
+            ListSimulationApplicationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListSimulationJobBatches
 #[derive(Debug, PartialEq)]
 pub enum ListSimulationJobBatchesError {
@@ -5550,6 +6000,14 @@ impl fmt::Display for ListSimulationJobBatchesError {
     }
 }
 impl Error for ListSimulationJobBatchesError {}
+impl ServiceErrorCode for ListSimulationJobBatchesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSimulationJobBatchesError::InternalServer(_) => "InternalServerException",
+            ListSimulationJobBatchesError::InvalidParameter(_) => "InvalidParameterException",
+        }
+    }
+}
 /// Errors returned by ListSimulationJobs
 #[derive(Debug, PartialEq)]
 pub enum ListSimulationJobsError {
@@ -5592,6 +6050,15 @@ impl fmt::Display for ListSimulationJobsError {
     }
 }
 impl Error for ListSimulationJobsError {}
+impl ServiceErrorCode for ListSimulationJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSimulationJobsError::InternalServer(_) => "InternalServerException",
+            ListSimulationJobsError::InvalidParameter(_) => "InvalidParameterException",
+            ListSimulationJobsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -5644,6 +6111,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalServer(_) => "InternalServerException",
+            ListTagsForResourceError::InvalidParameter(_) => "InvalidParameterException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListWorldExportJobs
 #[derive(Debug, PartialEq)]
 pub enum ListWorldExportJobsError {
@@ -5688,6 +6165,15 @@ impl fmt::Display for ListWorldExportJobsError {
     }
 }
 impl Error for ListWorldExportJobsError {}
+impl ServiceErrorCode for ListWorldExportJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListWorldExportJobsError::InternalServer(_) => "InternalServerException",
+            ListWorldExportJobsError::InvalidParameter(_) => "InvalidParameterException",
+            ListWorldExportJobsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListWorldGenerationJobs
 #[derive(Debug, PartialEq)]
 pub enum ListWorldGenerationJobsError {
@@ -5734,6 +6220,15 @@ impl fmt::Display for ListWorldGenerationJobsError {
     }
 }
 impl Error for ListWorldGenerationJobsError {}
+impl ServiceErrorCode for ListWorldGenerationJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListWorldGenerationJobsError::InternalServer(_) => "InternalServerException",
+            ListWorldGenerationJobsError::InvalidParameter(_) => "InvalidParameterException",
+            ListWorldGenerationJobsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListWorldTemplates
 #[derive(Debug, PartialEq)]
 pub enum ListWorldTemplatesError {
@@ -5776,6 +6271,15 @@ impl fmt::Display for ListWorldTemplatesError {
     }
 }
 impl Error for ListWorldTemplatesError {}
+impl ServiceErrorCode for ListWorldTemplatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListWorldTemplatesError::InternalServer(_) => "InternalServerException",
+            ListWorldTemplatesError::InvalidParameter(_) => "InvalidParameterException",
+            ListWorldTemplatesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListWorlds
 #[derive(Debug, PartialEq)]
 pub enum ListWorldsError {
@@ -5818,6 +6322,15 @@ impl fmt::Display for ListWorldsError {
     }
 }
 impl Error for ListWorldsError {}
+impl ServiceErrorCode for ListWorldsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListWorldsError::InternalServer(_) => "InternalServerException",
+            ListWorldsError::InvalidParameter(_) => "InvalidParameterException",
+            ListWorldsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by RegisterRobot
 #[derive(Debug, PartialEq)]
 pub enum RegisterRobotError {
@@ -5872,6 +6385,17 @@ impl fmt::Display for RegisterRobotError {
     }
 }
 impl Error for RegisterRobotError {}
+impl ServiceErrorCode for RegisterRobotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterRobotError::InternalServer(_) => "InternalServerException",
+            RegisterRobotError::InvalidParameter(_) => "InvalidParameterException",
+            RegisterRobotError::LimitExceeded(_) => "LimitExceededException",
+            RegisterRobotError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RegisterRobotError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by RestartSimulationJob
 #[derive(Debug, PartialEq)]
 pub enum RestartSimulationJobError {
@@ -5930,6 +6454,17 @@ impl fmt::Display for RestartSimulationJobError {
     }
 }
 impl Error for RestartSimulationJobError {}
+impl ServiceErrorCode for RestartSimulationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestartSimulationJobError::InternalServer(_) => "InternalServerException",
+            RestartSimulationJobError::InvalidParameter(_) => "InvalidParameterException",
+            RestartSimulationJobError::LimitExceeded(_) => "LimitExceededException",
+            RestartSimulationJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RestartSimulationJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StartSimulationJobBatch
 #[derive(Debug, PartialEq)]
 pub enum StartSimulationJobBatchError {
@@ -5994,6 +6529,19 @@ impl fmt::Display for StartSimulationJobBatchError {
     }
 }
 impl Error for StartSimulationJobBatchError {}
+impl ServiceErrorCode for StartSimulationJobBatchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartSimulationJobBatchError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            StartSimulationJobBatchError::InternalServer(_) => "InternalServerException",
+            StartSimulationJobBatchError::InvalidParameter(_) => "InvalidParameterException",
+            StartSimulationJobBatchError::LimitExceeded(_) => "LimitExceededException",
+            StartSimulationJobBatchError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by SyncDeploymentJob
 #[derive(Debug, PartialEq)]
 pub enum SyncDeploymentJobError {
@@ -6066,6 +6614,21 @@ impl fmt::Display for SyncDeploymentJobError {
     }
 }
 impl Error for SyncDeploymentJobError {}
+impl ServiceErrorCode for SyncDeploymentJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SyncDeploymentJobError::ConcurrentDeployment(_) => "ConcurrentDeploymentException",
+            SyncDeploymentJobError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            SyncDeploymentJobError::InternalServer(_) => "InternalServerException",
+            SyncDeploymentJobError::InvalidParameter(_) => "InvalidParameterException",
+            SyncDeploymentJobError::LimitExceeded(_) => "LimitExceededException",
+            SyncDeploymentJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SyncDeploymentJobError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -6114,6 +6677,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalServer(_) => "InternalServerException",
+            TagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -6162,6 +6735,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalServer(_) => "InternalServerException",
+            UntagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateRobotApplication
 #[derive(Debug, PartialEq)]
 pub enum UpdateRobotApplicationError {
@@ -6224,6 +6807,17 @@ impl fmt::Display for UpdateRobotApplicationError {
     }
 }
 impl Error for UpdateRobotApplicationError {}
+impl ServiceErrorCode for UpdateRobotApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRobotApplicationError::InternalServer(_) => "InternalServerException",
+            UpdateRobotApplicationError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateRobotApplicationError::LimitExceeded(_) => "LimitExceededException",
+            UpdateRobotApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateRobotApplicationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateSimulationApplication
 #[derive(Debug, PartialEq)]
 pub enum UpdateSimulationApplicationError {
@@ -6290,6 +6884,17 @@ impl fmt::Display for UpdateSimulationApplicationError {
     }
 }
 impl Error for UpdateSimulationApplicationError {}
+impl ServiceErrorCode for UpdateSimulationApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSimulationApplicationError::InternalServer(_) => "InternalServerException",
+            UpdateSimulationApplicationError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateSimulationApplicationError::LimitExceeded(_) => "LimitExceededException",
+            UpdateSimulationApplicationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateSimulationApplicationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateWorldTemplate
 #[derive(Debug, PartialEq)]
 pub enum UpdateWorldTemplateError {
@@ -6342,6 +6947,16 @@ impl fmt::Display for UpdateWorldTemplateError {
     }
 }
 impl Error for UpdateWorldTemplateError {}
+impl ServiceErrorCode for UpdateWorldTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateWorldTemplateError::InternalServer(_) => "InternalServerException",
+            UpdateWorldTemplateError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateWorldTemplateError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateWorldTemplateError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the RoboMaker API. RoboMaker clients implement this trait.
 #[async_trait]
 pub trait Robomaker {
@@ -6960,6 +7575,12 @@ impl Robomaker for RobomakerClient {
         &self,
         input: CreateDeploymentJobRequest,
     ) -> Result<CreateDeploymentJobResponse, RusotoError<CreateDeploymentJobError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = "/createDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
@@ -7186,6 +7807,10 @@ impl Robomaker for RobomakerClient {
         &self,
         input: CreateSimulationJobRequest,
     ) -> Result<CreateSimulationJobResponse, RusotoError<CreateSimulationJobError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = "/createSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
@@ -7217,6 +7842,10 @@ impl Robomaker for RobomakerClient {
         &self,
         input: CreateWorldExportJobRequest,
     ) -> Result<CreateWorldExportJobResponse, RusotoError<CreateWorldExportJobError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = "/createWorldExportJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
@@ -7248,6 +7877,10 @@ impl Robomaker for RobomakerClient {
         &self,
         input: CreateWorldGenerationJobRequest,
     ) -> Result<CreateWorldGenerationJobResponse, RusotoError<CreateWorldGenerationJobError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = "/createWorldGenerationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
@@ -8306,6 +8939,10 @@ impl Robomaker for RobomakerClient {
         &self,
         input: StartSimulationJobBatchRequest,
     ) -> Result<StartSimulationJobBatchResponse, RusotoError<StartSimulationJobBatchError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let request_uri = "/startSimulationJobBatch";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
@@ -8337,6 +8974,12 @@ impl Robomaker for RobomakerClient {
         &self,
         input: SyncDeploymentJobRequest,
     ) -> Result<SyncDeploymentJobResponse, RusotoError<SyncDeploymentJobError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = "/syncDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -8101,6 +8101,19 @@ impl fmt::Display for ActivateKeySigningKeyError {
     }
 }
 impl Error for ActivateKeySigningKeyError {}
+impl ServiceErrorCode for ActivateKeySigningKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ActivateKeySigningKeyError::ConcurrentModification(_) => "ConcurrentModification",
+            ActivateKeySigningKeyError::InvalidKMSArn(_) => "InvalidKMSArn",
+            ActivateKeySigningKeyError::InvalidKeySigningKeyStatus(_) => {
+                "InvalidKeySigningKeyStatus"
+            }
+            ActivateKeySigningKeyError::InvalidSigningStatus(_) => "InvalidSigningStatus",
+            ActivateKeySigningKeyError::NoSuchKeySigningKey(_) => "NoSuchKeySigningKey",
+        }
+    }
+}
 /// Errors returned by AssociateVPCWithHostedZone
 #[derive(Debug, PartialEq)]
 pub enum AssociateVPCWithHostedZoneError {
@@ -8215,6 +8228,26 @@ impl fmt::Display for AssociateVPCWithHostedZoneError {
     }
 }
 impl Error for AssociateVPCWithHostedZoneError {}
+impl ServiceErrorCode for AssociateVPCWithHostedZoneError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateVPCWithHostedZoneError::ConflictingDomainExists(_) => {
+                "ConflictingDomainExists"
+            }
+            AssociateVPCWithHostedZoneError::InvalidInput(_) => "InvalidInput",
+            AssociateVPCWithHostedZoneError::InvalidVPCId(_) => "InvalidVPCId",
+            AssociateVPCWithHostedZoneError::LimitsExceeded(_) => "LimitsExceeded",
+            AssociateVPCWithHostedZoneError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+            AssociateVPCWithHostedZoneError::NotAuthorized(_) => "NotAuthorizedException",
+            AssociateVPCWithHostedZoneError::PriorRequestNotComplete(_) => {
+                "PriorRequestNotComplete"
+            }
+            AssociateVPCWithHostedZoneError::PublicZoneVPCAssociation(_) => {
+                "PublicZoneVPCAssociation"
+            }
+        }
+    }
+}
 /// Errors returned by ChangeResourceRecordSets
 #[derive(Debug, PartialEq)]
 pub enum ChangeResourceRecordSetsError {
@@ -8295,6 +8328,17 @@ impl fmt::Display for ChangeResourceRecordSetsError {
     }
 }
 impl Error for ChangeResourceRecordSetsError {}
+impl ServiceErrorCode for ChangeResourceRecordSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ChangeResourceRecordSetsError::InvalidChangeBatch(_) => "InvalidChangeBatch",
+            ChangeResourceRecordSetsError::InvalidInput(_) => "InvalidInput",
+            ChangeResourceRecordSetsError::NoSuchHealthCheck(_) => "NoSuchHealthCheck",
+            ChangeResourceRecordSetsError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+            ChangeResourceRecordSetsError::PriorRequestNotComplete(_) => "PriorRequestNotComplete",
+        }
+    }
+}
 /// Errors returned by ChangeTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ChangeTagsForResourceError {
@@ -8375,6 +8419,17 @@ impl fmt::Display for ChangeTagsForResourceError {
     }
 }
 impl Error for ChangeTagsForResourceError {}
+impl ServiceErrorCode for ChangeTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ChangeTagsForResourceError::InvalidInput(_) => "InvalidInput",
+            ChangeTagsForResourceError::NoSuchHealthCheck(_) => "NoSuchHealthCheck",
+            ChangeTagsForResourceError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+            ChangeTagsForResourceError::PriorRequestNotComplete(_) => "PriorRequestNotComplete",
+            ChangeTagsForResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateHealthCheck
 #[derive(Debug, PartialEq)]
 pub enum CreateHealthCheckError {
@@ -8435,6 +8490,15 @@ impl fmt::Display for CreateHealthCheckError {
     }
 }
 impl Error for CreateHealthCheckError {}
+impl ServiceErrorCode for CreateHealthCheckError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHealthCheckError::HealthCheckAlreadyExists(_) => "HealthCheckAlreadyExists",
+            CreateHealthCheckError::InvalidInput(_) => "InvalidInput",
+            CreateHealthCheckError::TooManyHealthChecks(_) => "TooManyHealthChecks",
+        }
+    }
+}
 /// Errors returned by CreateHostedZone
 #[derive(Debug, PartialEq)]
 pub enum CreateHostedZoneError {
@@ -8543,6 +8607,21 @@ impl fmt::Display for CreateHostedZoneError {
     }
 }
 impl Error for CreateHostedZoneError {}
+impl ServiceErrorCode for CreateHostedZoneError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHostedZoneError::ConflictingDomainExists(_) => "ConflictingDomainExists",
+            CreateHostedZoneError::DelegationSetNotAvailable(_) => "DelegationSetNotAvailable",
+            CreateHostedZoneError::DelegationSetNotReusable(_) => "DelegationSetNotReusable",
+            CreateHostedZoneError::HostedZoneAlreadyExists(_) => "HostedZoneAlreadyExists",
+            CreateHostedZoneError::InvalidDomainName(_) => "InvalidDomainName",
+            CreateHostedZoneError::InvalidInput(_) => "InvalidInput",
+            CreateHostedZoneError::InvalidVPCId(_) => "InvalidVPCId",
+            CreateHostedZoneError::NoSuchDelegationSet(_) => "NoSuchDelegationSet",
+            CreateHostedZoneError::TooManyHostedZones(_) => "TooManyHostedZones",
+        }
+    }
+}
 /// Errors returned by CreateKeySigningKey
 #[derive(Debug, PartialEq)]
 pub enum CreateKeySigningKeyError {
@@ -8669,6 +8748,22 @@ impl fmt::Display for CreateKeySigningKeyError {
     }
 }
 impl Error for CreateKeySigningKeyError {}
+impl ServiceErrorCode for CreateKeySigningKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateKeySigningKeyError::ConcurrentModification(_) => "ConcurrentModification",
+            CreateKeySigningKeyError::InvalidArgument(_) => "InvalidArgument",
+            CreateKeySigningKeyError::InvalidInput(_) => "InvalidInput",
+            CreateKeySigningKeyError::InvalidKMSArn(_) => "InvalidKMSArn",
+            CreateKeySigningKeyError::InvalidKeySigningKeyName(_) => "InvalidKeySigningKeyName",
+            CreateKeySigningKeyError::InvalidKeySigningKeyStatus(_) => "InvalidKeySigningKeyStatus",
+            CreateKeySigningKeyError::InvalidSigningStatus(_) => "InvalidSigningStatus",
+            CreateKeySigningKeyError::KeySigningKeyAlreadyExists(_) => "KeySigningKeyAlreadyExists",
+            CreateKeySigningKeyError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+            CreateKeySigningKeyError::TooManyKeySigningKeys(_) => "TooManyKeySigningKeys",
+        }
+    }
+}
 /// Errors returned by CreateQueryLoggingConfig
 #[derive(Debug, PartialEq)]
 pub enum CreateQueryLoggingConfigError {
@@ -8769,6 +8864,24 @@ impl fmt::Display for CreateQueryLoggingConfigError {
     }
 }
 impl Error for CreateQueryLoggingConfigError {}
+impl ServiceErrorCode for CreateQueryLoggingConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateQueryLoggingConfigError::ConcurrentModification(_) => "ConcurrentModification",
+            CreateQueryLoggingConfigError::InsufficientCloudWatchLogsResourcePolicy(_) => {
+                "InsufficientCloudWatchLogsResourcePolicy"
+            }
+            CreateQueryLoggingConfigError::InvalidInput(_) => "InvalidInput",
+            CreateQueryLoggingConfigError::NoSuchCloudWatchLogsLogGroup(_) => {
+                "NoSuchCloudWatchLogsLogGroup"
+            }
+            CreateQueryLoggingConfigError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+            CreateQueryLoggingConfigError::QueryLoggingConfigAlreadyExists(_) => {
+                "QueryLoggingConfigAlreadyExists"
+            }
+        }
+    }
+}
 /// Errors returned by CreateReusableDelegationSet
 #[derive(Debug, PartialEq)]
 pub enum CreateReusableDelegationSetError {
@@ -8879,6 +8992,25 @@ impl fmt::Display for CreateReusableDelegationSetError {
     }
 }
 impl Error for CreateReusableDelegationSetError {}
+impl ServiceErrorCode for CreateReusableDelegationSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateReusableDelegationSetError::DelegationSetAlreadyCreated(_) => {
+                "DelegationSetAlreadyCreated"
+            }
+            CreateReusableDelegationSetError::DelegationSetAlreadyReusable(_) => {
+                "DelegationSetAlreadyReusable"
+            }
+            CreateReusableDelegationSetError::DelegationSetNotAvailable(_) => {
+                "DelegationSetNotAvailable"
+            }
+            CreateReusableDelegationSetError::HostedZoneNotFound(_) => "HostedZoneNotFound",
+            CreateReusableDelegationSetError::InvalidArgument(_) => "InvalidArgument",
+            CreateReusableDelegationSetError::InvalidInput(_) => "InvalidInput",
+            CreateReusableDelegationSetError::LimitsExceeded(_) => "LimitsExceeded",
+        }
+    }
+}
 /// Errors returned by CreateTrafficPolicy
 #[derive(Debug, PartialEq)]
 pub enum CreateTrafficPolicyError {
@@ -8955,6 +9087,18 @@ impl fmt::Display for CreateTrafficPolicyError {
     }
 }
 impl Error for CreateTrafficPolicyError {}
+impl ServiceErrorCode for CreateTrafficPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTrafficPolicyError::InvalidInput(_) => "InvalidInput",
+            CreateTrafficPolicyError::InvalidTrafficPolicyDocument(_) => {
+                "InvalidTrafficPolicyDocument"
+            }
+            CreateTrafficPolicyError::TooManyTrafficPolicies(_) => "TooManyTrafficPolicies",
+            CreateTrafficPolicyError::TrafficPolicyAlreadyExists(_) => "TrafficPolicyAlreadyExists",
+        }
+    }
+}
 /// Errors returned by CreateTrafficPolicyInstance
 #[derive(Debug, PartialEq)]
 pub enum CreateTrafficPolicyInstanceError {
@@ -9047,6 +9191,21 @@ impl fmt::Display for CreateTrafficPolicyInstanceError {
     }
 }
 impl Error for CreateTrafficPolicyInstanceError {}
+impl ServiceErrorCode for CreateTrafficPolicyInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTrafficPolicyInstanceError::InvalidInput(_) => "InvalidInput",
+            CreateTrafficPolicyInstanceError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+            CreateTrafficPolicyInstanceError::NoSuchTrafficPolicy(_) => "NoSuchTrafficPolicy",
+            CreateTrafficPolicyInstanceError::TooManyTrafficPolicyInstances(_) => {
+                "TooManyTrafficPolicyInstances"
+            }
+            CreateTrafficPolicyInstanceError::TrafficPolicyInstanceAlreadyExists(_) => {
+                "TrafficPolicyInstanceAlreadyExists"
+            }
+        }
+    }
+}
 /// Errors returned by CreateTrafficPolicyVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateTrafficPolicyVersionError {
@@ -9108,6 +9267,21 @@ impl fmt::Display for CreateTrafficPolicyVersionError {
     }
 }
 impl Error for CreateTrafficPolicyVersionError {}
+impl ServiceErrorCode for CreateTrafficPolicyVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTrafficPolicyVersionError::ConcurrentModification(_) => "ConcurrentModification",
+            CreateTrafficPolicyVersionError::InvalidInput(_) => "InvalidInput",
+            CreateTrafficPolicyVersionError::InvalidTrafficPolicyDocument(_) => {
+                "InvalidTrafficPolicyDocument"
+            }
+            CreateTrafficPolicyVersionError::NoSuchTrafficPolicy(_) => "NoSuchTrafficPolicy",
+            CreateTrafficPolicyVersionError::TooManyTrafficPolicyVersionsForCurrentPolicy(_) => {
+                "TooManyTrafficPolicyVersionsForCurrentPolicy"
+            }
+        }
+    }
+}
 /// Errors returned by CreateVPCAssociationAuthorization
 #[derive(Debug, PartialEq)]
 pub enum CreateVPCAssociationAuthorizationError {
@@ -9204,6 +9378,21 @@ impl fmt::Display for CreateVPCAssociationAuthorizationError {
     }
 }
 impl Error for CreateVPCAssociationAuthorizationError {}
+impl ServiceErrorCode for CreateVPCAssociationAuthorizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVPCAssociationAuthorizationError::ConcurrentModification(_) => {
+                "ConcurrentModification"
+            }
+            CreateVPCAssociationAuthorizationError::InvalidInput(_) => "InvalidInput",
+            CreateVPCAssociationAuthorizationError::InvalidVPCId(_) => "InvalidVPCId",
+            CreateVPCAssociationAuthorizationError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+            CreateVPCAssociationAuthorizationError::TooManyVPCAssociationAuthorizations(_) => {
+                "TooManyVPCAssociationAuthorizations"
+            }
+        }
+    }
+}
 /// Errors returned by DeactivateKeySigningKey
 #[derive(Debug, PartialEq)]
 pub enum DeactivateKeySigningKeyError {
@@ -9302,6 +9491,22 @@ impl fmt::Display for DeactivateKeySigningKeyError {
     }
 }
 impl Error for DeactivateKeySigningKeyError {}
+impl ServiceErrorCode for DeactivateKeySigningKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeactivateKeySigningKeyError::ConcurrentModification(_) => "ConcurrentModification",
+            DeactivateKeySigningKeyError::InvalidKeySigningKeyStatus(_) => {
+                "InvalidKeySigningKeyStatus"
+            }
+            DeactivateKeySigningKeyError::InvalidSigningStatus(_) => "InvalidSigningStatus",
+            DeactivateKeySigningKeyError::KeySigningKeyInParentDSRecord(_) => {
+                "KeySigningKeyInParentDSRecord"
+            }
+            DeactivateKeySigningKeyError::KeySigningKeyInUse(_) => "KeySigningKeyInUse",
+            DeactivateKeySigningKeyError::NoSuchKeySigningKey(_) => "NoSuchKeySigningKey",
+        }
+    }
+}
 /// Errors returned by DeleteHealthCheck
 #[derive(Debug, PartialEq)]
 pub enum DeleteHealthCheckError {
@@ -9362,6 +9567,15 @@ impl fmt::Display for DeleteHealthCheckError {
     }
 }
 impl Error for DeleteHealthCheckError {}
+impl ServiceErrorCode for DeleteHealthCheckError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteHealthCheckError::HealthCheckInUse(_) => "HealthCheckInUse",
+            DeleteHealthCheckError::InvalidInput(_) => "InvalidInput",
+            DeleteHealthCheckError::NoSuchHealthCheck(_) => "NoSuchHealthCheck",
+        }
+    }
+}
 /// Errors returned by DeleteHostedZone
 #[derive(Debug, PartialEq)]
 pub enum DeleteHostedZoneError {
@@ -9438,6 +9652,17 @@ impl fmt::Display for DeleteHostedZoneError {
     }
 }
 impl Error for DeleteHostedZoneError {}
+impl ServiceErrorCode for DeleteHostedZoneError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteHostedZoneError::HostedZoneNotEmpty(_) => "HostedZoneNotEmpty",
+            DeleteHostedZoneError::InvalidDomainName(_) => "InvalidDomainName",
+            DeleteHostedZoneError::InvalidInput(_) => "InvalidInput",
+            DeleteHostedZoneError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+            DeleteHostedZoneError::PriorRequestNotComplete(_) => "PriorRequestNotComplete",
+        }
+    }
+}
 /// Errors returned by DeleteKeySigningKey
 #[derive(Debug, PartialEq)]
 pub enum DeleteKeySigningKeyError {
@@ -9518,6 +9743,17 @@ impl fmt::Display for DeleteKeySigningKeyError {
     }
 }
 impl Error for DeleteKeySigningKeyError {}
+impl ServiceErrorCode for DeleteKeySigningKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteKeySigningKeyError::ConcurrentModification(_) => "ConcurrentModification",
+            DeleteKeySigningKeyError::InvalidKMSArn(_) => "InvalidKMSArn",
+            DeleteKeySigningKeyError::InvalidKeySigningKeyStatus(_) => "InvalidKeySigningKeyStatus",
+            DeleteKeySigningKeyError::InvalidSigningStatus(_) => "InvalidSigningStatus",
+            DeleteKeySigningKeyError::NoSuchKeySigningKey(_) => "NoSuchKeySigningKey",
+        }
+    }
+}
 /// Errors returned by DeleteQueryLoggingConfig
 #[derive(Debug, PartialEq)]
 pub enum DeleteQueryLoggingConfigError {
@@ -9586,6 +9822,17 @@ impl fmt::Display for DeleteQueryLoggingConfigError {
     }
 }
 impl Error for DeleteQueryLoggingConfigError {}
+impl ServiceErrorCode for DeleteQueryLoggingConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteQueryLoggingConfigError::ConcurrentModification(_) => "ConcurrentModification",
+            DeleteQueryLoggingConfigError::InvalidInput(_) => "InvalidInput",
+            DeleteQueryLoggingConfigError::NoSuchQueryLoggingConfig(_) => {
+                "NoSuchQueryLoggingConfig"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteReusableDelegationSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteReusableDelegationSetError {
@@ -9668,6 +9915,18 @@ impl fmt::Display for DeleteReusableDelegationSetError {
     }
 }
 impl Error for DeleteReusableDelegationSetError {}
+impl ServiceErrorCode for DeleteReusableDelegationSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteReusableDelegationSetError::DelegationSetInUse(_) => "DelegationSetInUse",
+            DeleteReusableDelegationSetError::DelegationSetNotReusable(_) => {
+                "DelegationSetNotReusable"
+            }
+            DeleteReusableDelegationSetError::InvalidInput(_) => "InvalidInput",
+            DeleteReusableDelegationSetError::NoSuchDelegationSet(_) => "NoSuchDelegationSet",
+        }
+    }
+}
 /// Errors returned by DeleteTrafficPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteTrafficPolicyError {
@@ -9736,6 +9995,16 @@ impl fmt::Display for DeleteTrafficPolicyError {
     }
 }
 impl Error for DeleteTrafficPolicyError {}
+impl ServiceErrorCode for DeleteTrafficPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTrafficPolicyError::ConcurrentModification(_) => "ConcurrentModification",
+            DeleteTrafficPolicyError::InvalidInput(_) => "InvalidInput",
+            DeleteTrafficPolicyError::NoSuchTrafficPolicy(_) => "NoSuchTrafficPolicy",
+            DeleteTrafficPolicyError::TrafficPolicyInUse(_) => "TrafficPolicyInUse",
+        }
+    }
+}
 /// Errors returned by DeleteTrafficPolicyInstance
 #[derive(Debug, PartialEq)]
 pub enum DeleteTrafficPolicyInstanceError {
@@ -9806,6 +10075,19 @@ impl fmt::Display for DeleteTrafficPolicyInstanceError {
     }
 }
 impl Error for DeleteTrafficPolicyInstanceError {}
+impl ServiceErrorCode for DeleteTrafficPolicyInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTrafficPolicyInstanceError::InvalidInput(_) => "InvalidInput",
+            DeleteTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(_) => {
+                "NoSuchTrafficPolicyInstance"
+            }
+            DeleteTrafficPolicyInstanceError::PriorRequestNotComplete(_) => {
+                "PriorRequestNotComplete"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteVPCAssociationAuthorization
 #[derive(Debug, PartialEq)]
 pub enum DeleteVPCAssociationAuthorizationError {
@@ -9902,6 +10184,21 @@ impl fmt::Display for DeleteVPCAssociationAuthorizationError {
     }
 }
 impl Error for DeleteVPCAssociationAuthorizationError {}
+impl ServiceErrorCode for DeleteVPCAssociationAuthorizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVPCAssociationAuthorizationError::ConcurrentModification(_) => {
+                "ConcurrentModification"
+            }
+            DeleteVPCAssociationAuthorizationError::InvalidInput(_) => "InvalidInput",
+            DeleteVPCAssociationAuthorizationError::InvalidVPCId(_) => "InvalidVPCId",
+            DeleteVPCAssociationAuthorizationError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+            DeleteVPCAssociationAuthorizationError::VPCAssociationAuthorizationNotFound(_) => {
+                "VPCAssociationAuthorizationNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DisableHostedZoneDNSSEC
 #[derive(Debug, PartialEq)]
 pub enum DisableHostedZoneDNSSECError {
@@ -10006,6 +10303,23 @@ impl fmt::Display for DisableHostedZoneDNSSECError {
     }
 }
 impl Error for DisableHostedZoneDNSSECError {}
+impl ServiceErrorCode for DisableHostedZoneDNSSECError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableHostedZoneDNSSECError::ConcurrentModification(_) => "ConcurrentModification",
+            DisableHostedZoneDNSSECError::DNSSECNotFound(_) => "DNSSECNotFound",
+            DisableHostedZoneDNSSECError::InvalidArgument(_) => "InvalidArgument",
+            DisableHostedZoneDNSSECError::InvalidKMSArn(_) => "InvalidKMSArn",
+            DisableHostedZoneDNSSECError::InvalidKeySigningKeyStatus(_) => {
+                "InvalidKeySigningKeyStatus"
+            }
+            DisableHostedZoneDNSSECError::KeySigningKeyInParentDSRecord(_) => {
+                "KeySigningKeyInParentDSRecord"
+            }
+            DisableHostedZoneDNSSECError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+        }
+    }
+}
 /// Errors returned by DisassociateVPCFromHostedZone
 #[derive(Debug, PartialEq)]
 pub enum DisassociateVPCFromHostedZoneError {
@@ -10096,6 +10410,19 @@ impl fmt::Display for DisassociateVPCFromHostedZoneError {
     }
 }
 impl Error for DisassociateVPCFromHostedZoneError {}
+impl ServiceErrorCode for DisassociateVPCFromHostedZoneError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateVPCFromHostedZoneError::InvalidInput(_) => "InvalidInput",
+            DisassociateVPCFromHostedZoneError::InvalidVPCId(_) => "InvalidVPCId",
+            DisassociateVPCFromHostedZoneError::LastVPCAssociation(_) => "LastVPCAssociation",
+            DisassociateVPCFromHostedZoneError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+            DisassociateVPCFromHostedZoneError::VPCAssociationNotFound(_) => {
+                "VPCAssociationNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by EnableHostedZoneDNSSEC
 #[derive(Debug, PartialEq)]
 pub enum EnableHostedZoneDNSSECError {
@@ -10212,6 +10539,26 @@ impl fmt::Display for EnableHostedZoneDNSSECError {
     }
 }
 impl Error for EnableHostedZoneDNSSECError {}
+impl ServiceErrorCode for EnableHostedZoneDNSSECError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableHostedZoneDNSSECError::ConcurrentModification(_) => "ConcurrentModification",
+            EnableHostedZoneDNSSECError::DNSSECNotFound(_) => "DNSSECNotFound",
+            EnableHostedZoneDNSSECError::HostedZonePartiallyDelegated(_) => {
+                "HostedZonePartiallyDelegated"
+            }
+            EnableHostedZoneDNSSECError::InvalidArgument(_) => "InvalidArgument",
+            EnableHostedZoneDNSSECError::InvalidKMSArn(_) => "InvalidKMSArn",
+            EnableHostedZoneDNSSECError::InvalidKeySigningKeyStatus(_) => {
+                "InvalidKeySigningKeyStatus"
+            }
+            EnableHostedZoneDNSSECError::KeySigningKeyWithActiveStatusNotFound(_) => {
+                "KeySigningKeyWithActiveStatusNotFound"
+            }
+            EnableHostedZoneDNSSECError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+        }
+    }
+}
 /// Errors returned by GetAccountLimit
 #[derive(Debug, PartialEq)]
 pub enum GetAccountLimitError {
@@ -10256,6 +10603,13 @@ impl fmt::Display for GetAccountLimitError {
     }
 }
 impl Error for GetAccountLimitError {}
+impl ServiceErrorCode for GetAccountLimitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAccountLimitError::InvalidInput(_) => "InvalidInput",
+        }
+    }
+}
 /// Errors returned by GetChange
 #[derive(Debug, PartialEq)]
 pub enum GetChangeError {
@@ -10308,6 +10662,14 @@ impl fmt::Display for GetChangeError {
     }
 }
 impl Error for GetChangeError {}
+impl ServiceErrorCode for GetChangeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetChangeError::InvalidInput(_) => "InvalidInput",
+            GetChangeError::NoSuchChange(_) => "NoSuchChange",
+        }
+    }
+}
 /// Errors returned by GetCheckerIpRanges
 #[derive(Debug, PartialEq)]
 pub enum GetCheckerIpRangesError {}
@@ -10342,6 +10704,11 @@ impl fmt::Display for GetCheckerIpRangesError {
     }
 }
 impl Error for GetCheckerIpRangesError {}
+impl ServiceErrorCode for GetCheckerIpRangesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetDNSSEC
 #[derive(Debug, PartialEq)]
 pub enum GetDNSSECError {
@@ -10394,6 +10761,14 @@ impl fmt::Display for GetDNSSECError {
     }
 }
 impl Error for GetDNSSECError {}
+impl ServiceErrorCode for GetDNSSECError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDNSSECError::InvalidArgument(_) => "InvalidArgument",
+            GetDNSSECError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+        }
+    }
+}
 /// Errors returned by GetGeoLocation
 #[derive(Debug, PartialEq)]
 pub enum GetGeoLocationError {
@@ -10446,6 +10821,14 @@ impl fmt::Display for GetGeoLocationError {
     }
 }
 impl Error for GetGeoLocationError {}
+impl ServiceErrorCode for GetGeoLocationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGeoLocationError::InvalidInput(_) => "InvalidInput",
+            GetGeoLocationError::NoSuchGeoLocation(_) => "NoSuchGeoLocation",
+        }
+    }
+}
 /// Errors returned by GetHealthCheck
 #[derive(Debug, PartialEq)]
 pub enum GetHealthCheckError {
@@ -10506,6 +10889,15 @@ impl fmt::Display for GetHealthCheckError {
     }
 }
 impl Error for GetHealthCheckError {}
+impl ServiceErrorCode for GetHealthCheckError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetHealthCheckError::IncompatibleVersion(_) => "IncompatibleVersion",
+            GetHealthCheckError::InvalidInput(_) => "InvalidInput",
+            GetHealthCheckError::NoSuchHealthCheck(_) => "NoSuchHealthCheck",
+        }
+    }
+}
 /// Errors returned by GetHealthCheckCount
 #[derive(Debug, PartialEq)]
 pub enum GetHealthCheckCountError {}
@@ -10540,6 +10932,11 @@ impl fmt::Display for GetHealthCheckCountError {
     }
 }
 impl Error for GetHealthCheckCountError {}
+impl ServiceErrorCode for GetHealthCheckCountError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetHealthCheckLastFailureReason
 #[derive(Debug, PartialEq)]
 pub enum GetHealthCheckLastFailureReasonError {
@@ -10600,6 +10997,14 @@ impl fmt::Display for GetHealthCheckLastFailureReasonError {
     }
 }
 impl Error for GetHealthCheckLastFailureReasonError {}
+impl ServiceErrorCode for GetHealthCheckLastFailureReasonError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetHealthCheckLastFailureReasonError::InvalidInput(_) => "InvalidInput",
+            GetHealthCheckLastFailureReasonError::NoSuchHealthCheck(_) => "NoSuchHealthCheck",
+        }
+    }
+}
 /// Errors returned by GetHealthCheckStatus
 #[derive(Debug, PartialEq)]
 pub enum GetHealthCheckStatusError {
@@ -10652,6 +11057,14 @@ impl fmt::Display for GetHealthCheckStatusError {
     }
 }
 impl Error for GetHealthCheckStatusError {}
+impl ServiceErrorCode for GetHealthCheckStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetHealthCheckStatusError::InvalidInput(_) => "InvalidInput",
+            GetHealthCheckStatusError::NoSuchHealthCheck(_) => "NoSuchHealthCheck",
+        }
+    }
+}
 /// Errors returned by GetHostedZone
 #[derive(Debug, PartialEq)]
 pub enum GetHostedZoneError {
@@ -10704,6 +11117,14 @@ impl fmt::Display for GetHostedZoneError {
     }
 }
 impl Error for GetHostedZoneError {}
+impl ServiceErrorCode for GetHostedZoneError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetHostedZoneError::InvalidInput(_) => "InvalidInput",
+            GetHostedZoneError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+        }
+    }
+}
 /// Errors returned by GetHostedZoneCount
 #[derive(Debug, PartialEq)]
 pub enum GetHostedZoneCountError {
@@ -10748,6 +11169,13 @@ impl fmt::Display for GetHostedZoneCountError {
     }
 }
 impl Error for GetHostedZoneCountError {}
+impl ServiceErrorCode for GetHostedZoneCountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetHostedZoneCountError::InvalidInput(_) => "InvalidInput",
+        }
+    }
+}
 /// Errors returned by GetHostedZoneLimit
 #[derive(Debug, PartialEq)]
 pub enum GetHostedZoneLimitError {
@@ -10808,6 +11236,15 @@ impl fmt::Display for GetHostedZoneLimitError {
     }
 }
 impl Error for GetHostedZoneLimitError {}
+impl ServiceErrorCode for GetHostedZoneLimitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetHostedZoneLimitError::HostedZoneNotPrivate(_) => "HostedZoneNotPrivate",
+            GetHostedZoneLimitError::InvalidInput(_) => "InvalidInput",
+            GetHostedZoneLimitError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+        }
+    }
+}
 /// Errors returned by GetQueryLoggingConfig
 #[derive(Debug, PartialEq)]
 pub enum GetQueryLoggingConfigError {
@@ -10864,6 +11301,14 @@ impl fmt::Display for GetQueryLoggingConfigError {
     }
 }
 impl Error for GetQueryLoggingConfigError {}
+impl ServiceErrorCode for GetQueryLoggingConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetQueryLoggingConfigError::InvalidInput(_) => "InvalidInput",
+            GetQueryLoggingConfigError::NoSuchQueryLoggingConfig(_) => "NoSuchQueryLoggingConfig",
+        }
+    }
+}
 /// Errors returned by GetReusableDelegationSet
 #[derive(Debug, PartialEq)]
 pub enum GetReusableDelegationSetError {
@@ -10930,6 +11375,17 @@ impl fmt::Display for GetReusableDelegationSetError {
     }
 }
 impl Error for GetReusableDelegationSetError {}
+impl ServiceErrorCode for GetReusableDelegationSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetReusableDelegationSetError::DelegationSetNotReusable(_) => {
+                "DelegationSetNotReusable"
+            }
+            GetReusableDelegationSetError::InvalidInput(_) => "InvalidInput",
+            GetReusableDelegationSetError::NoSuchDelegationSet(_) => "NoSuchDelegationSet",
+        }
+    }
+}
 /// Errors returned by GetReusableDelegationSetLimit
 #[derive(Debug, PartialEq)]
 pub enum GetReusableDelegationSetLimitError {
@@ -10988,6 +11444,14 @@ impl fmt::Display for GetReusableDelegationSetLimitError {
     }
 }
 impl Error for GetReusableDelegationSetLimitError {}
+impl ServiceErrorCode for GetReusableDelegationSetLimitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetReusableDelegationSetLimitError::InvalidInput(_) => "InvalidInput",
+            GetReusableDelegationSetLimitError::NoSuchDelegationSet(_) => "NoSuchDelegationSet",
+        }
+    }
+}
 /// Errors returned by GetTrafficPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetTrafficPolicyError {
@@ -11040,6 +11504,14 @@ impl fmt::Display for GetTrafficPolicyError {
     }
 }
 impl Error for GetTrafficPolicyError {}
+impl ServiceErrorCode for GetTrafficPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTrafficPolicyError::InvalidInput(_) => "InvalidInput",
+            GetTrafficPolicyError::NoSuchTrafficPolicy(_) => "NoSuchTrafficPolicy",
+        }
+    }
+}
 /// Errors returned by GetTrafficPolicyInstance
 #[derive(Debug, PartialEq)]
 pub enum GetTrafficPolicyInstanceError {
@@ -11096,6 +11568,16 @@ impl fmt::Display for GetTrafficPolicyInstanceError {
     }
 }
 impl Error for GetTrafficPolicyInstanceError {}
+impl ServiceErrorCode for GetTrafficPolicyInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTrafficPolicyInstanceError::InvalidInput(_) => "InvalidInput",
+            GetTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(_) => {
+                "NoSuchTrafficPolicyInstance"
+            }
+        }
+    }
+}
 /// Errors returned by GetTrafficPolicyInstanceCount
 #[derive(Debug, PartialEq)]
 pub enum GetTrafficPolicyInstanceCountError {}
@@ -11132,6 +11614,11 @@ impl fmt::Display for GetTrafficPolicyInstanceCountError {
     }
 }
 impl Error for GetTrafficPolicyInstanceCountError {}
+impl ServiceErrorCode for GetTrafficPolicyInstanceCountError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListGeoLocations
 #[derive(Debug, PartialEq)]
 pub enum ListGeoLocationsError {
@@ -11176,6 +11663,13 @@ impl fmt::Display for ListGeoLocationsError {
     }
 }
 impl Error for ListGeoLocationsError {}
+impl ServiceErrorCode for ListGeoLocationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGeoLocationsError::InvalidInput(_) => "InvalidInput",
+        }
+    }
+}
 /// Errors returned by ListHealthChecks
 #[derive(Debug, PartialEq)]
 pub enum ListHealthChecksError {
@@ -11228,6 +11722,14 @@ impl fmt::Display for ListHealthChecksError {
     }
 }
 impl Error for ListHealthChecksError {}
+impl ServiceErrorCode for ListHealthChecksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListHealthChecksError::IncompatibleVersion(_) => "IncompatibleVersion",
+            ListHealthChecksError::InvalidInput(_) => "InvalidInput",
+        }
+    }
+}
 /// Errors returned by ListHostedZones
 #[derive(Debug, PartialEq)]
 pub enum ListHostedZonesError {
@@ -11288,6 +11790,15 @@ impl fmt::Display for ListHostedZonesError {
     }
 }
 impl Error for ListHostedZonesError {}
+impl ServiceErrorCode for ListHostedZonesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListHostedZonesError::DelegationSetNotReusable(_) => "DelegationSetNotReusable",
+            ListHostedZonesError::InvalidInput(_) => "InvalidInput",
+            ListHostedZonesError::NoSuchDelegationSet(_) => "NoSuchDelegationSet",
+        }
+    }
+}
 /// Errors returned by ListHostedZonesByName
 #[derive(Debug, PartialEq)]
 pub enum ListHostedZonesByNameError {
@@ -11340,6 +11851,14 @@ impl fmt::Display for ListHostedZonesByNameError {
     }
 }
 impl Error for ListHostedZonesByNameError {}
+impl ServiceErrorCode for ListHostedZonesByNameError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListHostedZonesByNameError::InvalidDomainName(_) => "InvalidDomainName",
+            ListHostedZonesByNameError::InvalidInput(_) => "InvalidInput",
+        }
+    }
+}
 /// Errors returned by ListHostedZonesByVPC
 #[derive(Debug, PartialEq)]
 pub enum ListHostedZonesByVPCError {
@@ -11392,6 +11911,14 @@ impl fmt::Display for ListHostedZonesByVPCError {
     }
 }
 impl Error for ListHostedZonesByVPCError {}
+impl ServiceErrorCode for ListHostedZonesByVPCError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListHostedZonesByVPCError::InvalidInput(_) => "InvalidInput",
+            ListHostedZonesByVPCError::InvalidPaginationToken(_) => "InvalidPaginationToken",
+        }
+    }
+}
 /// Errors returned by ListQueryLoggingConfigs
 #[derive(Debug, PartialEq)]
 pub enum ListQueryLoggingConfigsError {
@@ -11456,6 +11983,15 @@ impl fmt::Display for ListQueryLoggingConfigsError {
     }
 }
 impl Error for ListQueryLoggingConfigsError {}
+impl ServiceErrorCode for ListQueryLoggingConfigsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListQueryLoggingConfigsError::InvalidInput(_) => "InvalidInput",
+            ListQueryLoggingConfigsError::InvalidPaginationToken(_) => "InvalidPaginationToken",
+            ListQueryLoggingConfigsError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+        }
+    }
+}
 /// Errors returned by ListResourceRecordSets
 #[derive(Debug, PartialEq)]
 pub enum ListResourceRecordSetsError {
@@ -11508,6 +12044,14 @@ impl fmt::Display for ListResourceRecordSetsError {
     }
 }
 impl Error for ListResourceRecordSetsError {}
+impl ServiceErrorCode for ListResourceRecordSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourceRecordSetsError::InvalidInput(_) => "InvalidInput",
+            ListResourceRecordSetsError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+        }
+    }
+}
 /// Errors returned by ListReusableDelegationSets
 #[derive(Debug, PartialEq)]
 pub enum ListReusableDelegationSetsError {
@@ -11554,6 +12098,13 @@ impl fmt::Display for ListReusableDelegationSetsError {
     }
 }
 impl Error for ListReusableDelegationSetsError {}
+impl ServiceErrorCode for ListReusableDelegationSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListReusableDelegationSetsError::InvalidInput(_) => "InvalidInput",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -11630,6 +12181,17 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InvalidInput(_) => "InvalidInput",
+            ListTagsForResourceError::NoSuchHealthCheck(_) => "NoSuchHealthCheck",
+            ListTagsForResourceError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+            ListTagsForResourceError::PriorRequestNotComplete(_) => "PriorRequestNotComplete",
+            ListTagsForResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResources
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourcesError {
@@ -11708,6 +12270,17 @@ impl fmt::Display for ListTagsForResourcesError {
     }
 }
 impl Error for ListTagsForResourcesError {}
+impl ServiceErrorCode for ListTagsForResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourcesError::InvalidInput(_) => "InvalidInput",
+            ListTagsForResourcesError::NoSuchHealthCheck(_) => "NoSuchHealthCheck",
+            ListTagsForResourcesError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+            ListTagsForResourcesError::PriorRequestNotComplete(_) => "PriorRequestNotComplete",
+            ListTagsForResourcesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTrafficPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListTrafficPoliciesError {
@@ -11752,6 +12325,13 @@ impl fmt::Display for ListTrafficPoliciesError {
     }
 }
 impl Error for ListTrafficPoliciesError {}
+impl ServiceErrorCode for ListTrafficPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTrafficPoliciesError::InvalidInput(_) => "InvalidInput",
+        }
+    }
+}
 /// Errors returned by ListTrafficPolicyInstances
 #[derive(Debug, PartialEq)]
 pub enum ListTrafficPolicyInstancesError {
@@ -11810,6 +12390,16 @@ impl fmt::Display for ListTrafficPolicyInstancesError {
     }
 }
 impl Error for ListTrafficPolicyInstancesError {}
+impl ServiceErrorCode for ListTrafficPolicyInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTrafficPolicyInstancesError::InvalidInput(_) => "InvalidInput",
+            ListTrafficPolicyInstancesError::NoSuchTrafficPolicyInstance(_) => {
+                "NoSuchTrafficPolicyInstance"
+            }
+        }
+    }
+}
 /// Errors returned by ListTrafficPolicyInstancesByHostedZone
 #[derive(Debug, PartialEq)]
 pub enum ListTrafficPolicyInstancesByHostedZoneError {
@@ -11882,6 +12472,17 @@ impl fmt::Display for ListTrafficPolicyInstancesByHostedZoneError {
     }
 }
 impl Error for ListTrafficPolicyInstancesByHostedZoneError {}
+impl ServiceErrorCode for ListTrafficPolicyInstancesByHostedZoneError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTrafficPolicyInstancesByHostedZoneError::InvalidInput(_) => "InvalidInput",
+            ListTrafficPolicyInstancesByHostedZoneError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+            ListTrafficPolicyInstancesByHostedZoneError::NoSuchTrafficPolicyInstance(_) => {
+                "NoSuchTrafficPolicyInstance"
+            }
+        }
+    }
+}
 /// Errors returned by ListTrafficPolicyInstancesByPolicy
 #[derive(Debug, PartialEq)]
 pub enum ListTrafficPolicyInstancesByPolicyError {
@@ -11956,6 +12557,19 @@ impl fmt::Display for ListTrafficPolicyInstancesByPolicyError {
     }
 }
 impl Error for ListTrafficPolicyInstancesByPolicyError {}
+impl ServiceErrorCode for ListTrafficPolicyInstancesByPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTrafficPolicyInstancesByPolicyError::InvalidInput(_) => "InvalidInput",
+            ListTrafficPolicyInstancesByPolicyError::NoSuchTrafficPolicy(_) => {
+                "NoSuchTrafficPolicy"
+            }
+            ListTrafficPolicyInstancesByPolicyError::NoSuchTrafficPolicyInstance(_) => {
+                "NoSuchTrafficPolicyInstance"
+            }
+        }
+    }
+}
 /// Errors returned by ListTrafficPolicyVersions
 #[derive(Debug, PartialEq)]
 pub enum ListTrafficPolicyVersionsError {
@@ -12012,6 +12626,14 @@ impl fmt::Display for ListTrafficPolicyVersionsError {
     }
 }
 impl Error for ListTrafficPolicyVersionsError {}
+impl ServiceErrorCode for ListTrafficPolicyVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTrafficPolicyVersionsError::InvalidInput(_) => "InvalidInput",
+            ListTrafficPolicyVersionsError::NoSuchTrafficPolicy(_) => "NoSuchTrafficPolicy",
+        }
+    }
+}
 /// Errors returned by ListVPCAssociationAuthorizations
 #[derive(Debug, PartialEq)]
 pub enum ListVPCAssociationAuthorizationsError {
@@ -12086,6 +12708,17 @@ impl fmt::Display for ListVPCAssociationAuthorizationsError {
     }
 }
 impl Error for ListVPCAssociationAuthorizationsError {}
+impl ServiceErrorCode for ListVPCAssociationAuthorizationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVPCAssociationAuthorizationsError::InvalidInput(_) => "InvalidInput",
+            ListVPCAssociationAuthorizationsError::InvalidPaginationToken(_) => {
+                "InvalidPaginationToken"
+            }
+            ListVPCAssociationAuthorizationsError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+        }
+    }
+}
 /// Errors returned by TestDNSAnswer
 #[derive(Debug, PartialEq)]
 pub enum TestDNSAnswerError {
@@ -12138,6 +12771,14 @@ impl fmt::Display for TestDNSAnswerError {
     }
 }
 impl Error for TestDNSAnswerError {}
+impl ServiceErrorCode for TestDNSAnswerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TestDNSAnswerError::InvalidInput(_) => "InvalidInput",
+            TestDNSAnswerError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+        }
+    }
+}
 /// Errors returned by UpdateHealthCheck
 #[derive(Debug, PartialEq)]
 pub enum UpdateHealthCheckError {
@@ -12200,6 +12841,15 @@ impl fmt::Display for UpdateHealthCheckError {
     }
 }
 impl Error for UpdateHealthCheckError {}
+impl ServiceErrorCode for UpdateHealthCheckError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateHealthCheckError::HealthCheckVersionMismatch(_) => "HealthCheckVersionMismatch",
+            UpdateHealthCheckError::InvalidInput(_) => "InvalidInput",
+            UpdateHealthCheckError::NoSuchHealthCheck(_) => "NoSuchHealthCheck",
+        }
+    }
+}
 /// Errors returned by UpdateHostedZoneComment
 #[derive(Debug, PartialEq)]
 pub enum UpdateHostedZoneCommentError {
@@ -12252,6 +12902,14 @@ impl fmt::Display for UpdateHostedZoneCommentError {
     }
 }
 impl Error for UpdateHostedZoneCommentError {}
+impl ServiceErrorCode for UpdateHostedZoneCommentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateHostedZoneCommentError::InvalidInput(_) => "InvalidInput",
+            UpdateHostedZoneCommentError::NoSuchHostedZone(_) => "NoSuchHostedZone",
+        }
+    }
+}
 /// Errors returned by UpdateTrafficPolicyComment
 #[derive(Debug, PartialEq)]
 pub enum UpdateTrafficPolicyCommentError {
@@ -12322,6 +12980,15 @@ impl fmt::Display for UpdateTrafficPolicyCommentError {
     }
 }
 impl Error for UpdateTrafficPolicyCommentError {}
+impl ServiceErrorCode for UpdateTrafficPolicyCommentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTrafficPolicyCommentError::ConcurrentModification(_) => "ConcurrentModification",
+            UpdateTrafficPolicyCommentError::InvalidInput(_) => "InvalidInput",
+            UpdateTrafficPolicyCommentError::NoSuchTrafficPolicy(_) => "NoSuchTrafficPolicy",
+        }
+    }
+}
 /// Errors returned by UpdateTrafficPolicyInstance
 #[derive(Debug, PartialEq)]
 pub enum UpdateTrafficPolicyInstanceError {
@@ -12414,6 +13081,21 @@ impl fmt::Display for UpdateTrafficPolicyInstanceError {
     }
 }
 impl Error for UpdateTrafficPolicyInstanceError {}
+impl ServiceErrorCode for UpdateTrafficPolicyInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTrafficPolicyInstanceError::ConflictingTypes(_) => "ConflictingTypes",
+            UpdateTrafficPolicyInstanceError::InvalidInput(_) => "InvalidInput",
+            UpdateTrafficPolicyInstanceError::NoSuchTrafficPolicy(_) => "NoSuchTrafficPolicy",
+            UpdateTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(_) => {
+                "NoSuchTrafficPolicyInstance"
+            }
+            UpdateTrafficPolicyInstanceError::PriorRequestNotComplete(_) => {
+                "PriorRequestNotComplete"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the Route 53 API. Route 53 clients implement this trait.
 #[async_trait]
 pub trait Route53 {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1066,6 +1066,19 @@ impl fmt::Display for AcceptDomainTransferFromAnotherAwsAccountError {
     }
 }
 impl Error for AcceptDomainTransferFromAnotherAwsAccountError {}
+impl ServiceErrorCode for AcceptDomainTransferFromAnotherAwsAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptDomainTransferFromAnotherAwsAccountError::DomainLimitExceeded(_) => {
+                "DomainLimitExceeded"
+            }
+            AcceptDomainTransferFromAnotherAwsAccountError::InvalidInput(_) => "InvalidInput",
+            AcceptDomainTransferFromAnotherAwsAccountError::OperationLimitExceeded(_) => {
+                "OperationLimitExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CancelDomainTransferToAnotherAwsAccount
 #[derive(Debug, PartialEq)]
 pub enum CancelDomainTransferToAnotherAwsAccountError {
@@ -1114,6 +1127,16 @@ impl fmt::Display for CancelDomainTransferToAnotherAwsAccountError {
     }
 }
 impl Error for CancelDomainTransferToAnotherAwsAccountError {}
+impl ServiceErrorCode for CancelDomainTransferToAnotherAwsAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelDomainTransferToAnotherAwsAccountError::InvalidInput(_) => "InvalidInput",
+            CancelDomainTransferToAnotherAwsAccountError::OperationLimitExceeded(_) => {
+                "OperationLimitExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CheckDomainAvailability
 #[derive(Debug, PartialEq)]
 pub enum CheckDomainAvailabilityError {
@@ -1154,6 +1177,14 @@ impl fmt::Display for CheckDomainAvailabilityError {
     }
 }
 impl Error for CheckDomainAvailabilityError {}
+impl ServiceErrorCode for CheckDomainAvailabilityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CheckDomainAvailabilityError::InvalidInput(_) => "InvalidInput",
+            CheckDomainAvailabilityError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by CheckDomainTransferability
 #[derive(Debug, PartialEq)]
 pub enum CheckDomainTransferabilityError {
@@ -1196,6 +1227,14 @@ impl fmt::Display for CheckDomainTransferabilityError {
     }
 }
 impl Error for CheckDomainTransferabilityError {}
+impl ServiceErrorCode for CheckDomainTransferabilityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CheckDomainTransferabilityError::InvalidInput(_) => "InvalidInput",
+            CheckDomainTransferabilityError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by DeleteTagsForDomain
 #[derive(Debug, PartialEq)]
 pub enum DeleteTagsForDomainError {
@@ -1240,6 +1279,15 @@ impl fmt::Display for DeleteTagsForDomainError {
     }
 }
 impl Error for DeleteTagsForDomainError {}
+impl ServiceErrorCode for DeleteTagsForDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTagsForDomainError::InvalidInput(_) => "InvalidInput",
+            DeleteTagsForDomainError::OperationLimitExceeded(_) => "OperationLimitExceeded",
+            DeleteTagsForDomainError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by DisableDomainAutoRenew
 #[derive(Debug, PartialEq)]
 pub enum DisableDomainAutoRenewError {
@@ -1278,6 +1326,14 @@ impl fmt::Display for DisableDomainAutoRenewError {
     }
 }
 impl Error for DisableDomainAutoRenewError {}
+impl ServiceErrorCode for DisableDomainAutoRenewError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableDomainAutoRenewError::InvalidInput(_) => "InvalidInput",
+            DisableDomainAutoRenewError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by DisableDomainTransferLock
 #[derive(Debug, PartialEq)]
 pub enum DisableDomainTransferLockError {
@@ -1344,6 +1400,17 @@ impl fmt::Display for DisableDomainTransferLockError {
     }
 }
 impl Error for DisableDomainTransferLockError {}
+impl ServiceErrorCode for DisableDomainTransferLockError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableDomainTransferLockError::DuplicateRequest(_) => "DuplicateRequest",
+            DisableDomainTransferLockError::InvalidInput(_) => "InvalidInput",
+            DisableDomainTransferLockError::OperationLimitExceeded(_) => "OperationLimitExceeded",
+            DisableDomainTransferLockError::TLDRulesViolation(_) => "TLDRulesViolation",
+            DisableDomainTransferLockError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by EnableDomainAutoRenew
 #[derive(Debug, PartialEq)]
 pub enum EnableDomainAutoRenewError {
@@ -1390,6 +1457,15 @@ impl fmt::Display for EnableDomainAutoRenewError {
     }
 }
 impl Error for EnableDomainAutoRenewError {}
+impl ServiceErrorCode for EnableDomainAutoRenewError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableDomainAutoRenewError::InvalidInput(_) => "InvalidInput",
+            EnableDomainAutoRenewError::TLDRulesViolation(_) => "TLDRulesViolation",
+            EnableDomainAutoRenewError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by EnableDomainTransferLock
 #[derive(Debug, PartialEq)]
 pub enum EnableDomainTransferLockError {
@@ -1456,6 +1532,17 @@ impl fmt::Display for EnableDomainTransferLockError {
     }
 }
 impl Error for EnableDomainTransferLockError {}
+impl ServiceErrorCode for EnableDomainTransferLockError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableDomainTransferLockError::DuplicateRequest(_) => "DuplicateRequest",
+            EnableDomainTransferLockError::InvalidInput(_) => "InvalidInput",
+            EnableDomainTransferLockError::OperationLimitExceeded(_) => "OperationLimitExceeded",
+            EnableDomainTransferLockError::TLDRulesViolation(_) => "TLDRulesViolation",
+            EnableDomainTransferLockError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by GetContactReachabilityStatus
 #[derive(Debug, PartialEq)]
 pub enum GetContactReachabilityStatusError {
@@ -1508,6 +1595,17 @@ impl fmt::Display for GetContactReachabilityStatusError {
     }
 }
 impl Error for GetContactReachabilityStatusError {}
+impl ServiceErrorCode for GetContactReachabilityStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContactReachabilityStatusError::InvalidInput(_) => "InvalidInput",
+            GetContactReachabilityStatusError::OperationLimitExceeded(_) => {
+                "OperationLimitExceeded"
+            }
+            GetContactReachabilityStatusError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by GetDomainDetail
 #[derive(Debug, PartialEq)]
 pub enum GetDomainDetailError {
@@ -1544,6 +1642,14 @@ impl fmt::Display for GetDomainDetailError {
     }
 }
 impl Error for GetDomainDetailError {}
+impl ServiceErrorCode for GetDomainDetailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDomainDetailError::InvalidInput(_) => "InvalidInput",
+            GetDomainDetailError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by GetDomainSuggestions
 #[derive(Debug, PartialEq)]
 pub enum GetDomainSuggestionsError {
@@ -1580,6 +1686,14 @@ impl fmt::Display for GetDomainSuggestionsError {
     }
 }
 impl Error for GetDomainSuggestionsError {}
+impl ServiceErrorCode for GetDomainSuggestionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDomainSuggestionsError::InvalidInput(_) => "InvalidInput",
+            GetDomainSuggestionsError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by GetOperationDetail
 #[derive(Debug, PartialEq)]
 pub enum GetOperationDetailError {
@@ -1610,6 +1724,13 @@ impl fmt::Display for GetOperationDetailError {
     }
 }
 impl Error for GetOperationDetailError {}
+impl ServiceErrorCode for GetOperationDetailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOperationDetailError::InvalidInput(_) => "InvalidInput",
+        }
+    }
+}
 /// Errors returned by ListDomains
 #[derive(Debug, PartialEq)]
 pub enum ListDomainsError {
@@ -1640,6 +1761,13 @@ impl fmt::Display for ListDomainsError {
     }
 }
 impl Error for ListDomainsError {}
+impl ServiceErrorCode for ListDomainsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDomainsError::InvalidInput(_) => "InvalidInput",
+        }
+    }
+}
 /// Errors returned by ListOperations
 #[derive(Debug, PartialEq)]
 pub enum ListOperationsError {
@@ -1670,6 +1798,13 @@ impl fmt::Display for ListOperationsError {
     }
 }
 impl Error for ListOperationsError {}
+impl ServiceErrorCode for ListOperationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOperationsError::InvalidInput(_) => "InvalidInput",
+        }
+    }
+}
 /// Errors returned by ListTagsForDomain
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForDomainError {
@@ -1714,6 +1849,15 @@ impl fmt::Display for ListTagsForDomainError {
     }
 }
 impl Error for ListTagsForDomainError {}
+impl ServiceErrorCode for ListTagsForDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForDomainError::InvalidInput(_) => "InvalidInput",
+            ListTagsForDomainError::OperationLimitExceeded(_) => "OperationLimitExceeded",
+            ListTagsForDomainError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by RegisterDomain
 #[derive(Debug, PartialEq)]
 pub enum RegisterDomainError {
@@ -1776,6 +1920,18 @@ impl fmt::Display for RegisterDomainError {
     }
 }
 impl Error for RegisterDomainError {}
+impl ServiceErrorCode for RegisterDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterDomainError::DomainLimitExceeded(_) => "DomainLimitExceeded",
+            RegisterDomainError::DuplicateRequest(_) => "DuplicateRequest",
+            RegisterDomainError::InvalidInput(_) => "InvalidInput",
+            RegisterDomainError::OperationLimitExceeded(_) => "OperationLimitExceeded",
+            RegisterDomainError::TLDRulesViolation(_) => "TLDRulesViolation",
+            RegisterDomainError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by RejectDomainTransferFromAnotherAwsAccount
 #[derive(Debug, PartialEq)]
 pub enum RejectDomainTransferFromAnotherAwsAccountError {
@@ -1824,6 +1980,16 @@ impl fmt::Display for RejectDomainTransferFromAnotherAwsAccountError {
     }
 }
 impl Error for RejectDomainTransferFromAnotherAwsAccountError {}
+impl ServiceErrorCode for RejectDomainTransferFromAnotherAwsAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RejectDomainTransferFromAnotherAwsAccountError::InvalidInput(_) => "InvalidInput",
+            RejectDomainTransferFromAnotherAwsAccountError::OperationLimitExceeded(_) => {
+                "OperationLimitExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by RenewDomain
 #[derive(Debug, PartialEq)]
 pub enum RenewDomainError {
@@ -1878,6 +2044,17 @@ impl fmt::Display for RenewDomainError {
     }
 }
 impl Error for RenewDomainError {}
+impl ServiceErrorCode for RenewDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RenewDomainError::DuplicateRequest(_) => "DuplicateRequest",
+            RenewDomainError::InvalidInput(_) => "InvalidInput",
+            RenewDomainError::OperationLimitExceeded(_) => "OperationLimitExceeded",
+            RenewDomainError::TLDRulesViolation(_) => "TLDRulesViolation",
+            RenewDomainError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by ResendContactReachabilityEmail
 #[derive(Debug, PartialEq)]
 pub enum ResendContactReachabilityEmailError {
@@ -1932,6 +2109,17 @@ impl fmt::Display for ResendContactReachabilityEmailError {
     }
 }
 impl Error for ResendContactReachabilityEmailError {}
+impl ServiceErrorCode for ResendContactReachabilityEmailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResendContactReachabilityEmailError::InvalidInput(_) => "InvalidInput",
+            ResendContactReachabilityEmailError::OperationLimitExceeded(_) => {
+                "OperationLimitExceeded"
+            }
+            ResendContactReachabilityEmailError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by RetrieveDomainAuthCode
 #[derive(Debug, PartialEq)]
 pub enum RetrieveDomainAuthCodeError {
@@ -1970,6 +2158,14 @@ impl fmt::Display for RetrieveDomainAuthCodeError {
     }
 }
 impl Error for RetrieveDomainAuthCodeError {}
+impl ServiceErrorCode for RetrieveDomainAuthCodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RetrieveDomainAuthCodeError::InvalidInput(_) => "InvalidInput",
+            RetrieveDomainAuthCodeError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by TransferDomain
 #[derive(Debug, PartialEq)]
 pub enum TransferDomainError {
@@ -2032,6 +2228,18 @@ impl fmt::Display for TransferDomainError {
     }
 }
 impl Error for TransferDomainError {}
+impl ServiceErrorCode for TransferDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TransferDomainError::DomainLimitExceeded(_) => "DomainLimitExceeded",
+            TransferDomainError::DuplicateRequest(_) => "DuplicateRequest",
+            TransferDomainError::InvalidInput(_) => "InvalidInput",
+            TransferDomainError::OperationLimitExceeded(_) => "OperationLimitExceeded",
+            TransferDomainError::TLDRulesViolation(_) => "TLDRulesViolation",
+            TransferDomainError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by TransferDomainToAnotherAwsAccount
 #[derive(Debug, PartialEq)]
 pub enum TransferDomainToAnotherAwsAccountError {
@@ -2088,6 +2296,17 @@ impl fmt::Display for TransferDomainToAnotherAwsAccountError {
     }
 }
 impl Error for TransferDomainToAnotherAwsAccountError {}
+impl ServiceErrorCode for TransferDomainToAnotherAwsAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TransferDomainToAnotherAwsAccountError::DuplicateRequest(_) => "DuplicateRequest",
+            TransferDomainToAnotherAwsAccountError::InvalidInput(_) => "InvalidInput",
+            TransferDomainToAnotherAwsAccountError::OperationLimitExceeded(_) => {
+                "OperationLimitExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateDomainContact
 #[derive(Debug, PartialEq)]
 pub enum UpdateDomainContactError {
@@ -2148,6 +2367,17 @@ impl fmt::Display for UpdateDomainContactError {
     }
 }
 impl Error for UpdateDomainContactError {}
+impl ServiceErrorCode for UpdateDomainContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDomainContactError::DuplicateRequest(_) => "DuplicateRequest",
+            UpdateDomainContactError::InvalidInput(_) => "InvalidInput",
+            UpdateDomainContactError::OperationLimitExceeded(_) => "OperationLimitExceeded",
+            UpdateDomainContactError::TLDRulesViolation(_) => "TLDRulesViolation",
+            UpdateDomainContactError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by UpdateDomainContactPrivacy
 #[derive(Debug, PartialEq)]
 pub enum UpdateDomainContactPrivacyError {
@@ -2216,6 +2446,17 @@ impl fmt::Display for UpdateDomainContactPrivacyError {
     }
 }
 impl Error for UpdateDomainContactPrivacyError {}
+impl ServiceErrorCode for UpdateDomainContactPrivacyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDomainContactPrivacyError::DuplicateRequest(_) => "DuplicateRequest",
+            UpdateDomainContactPrivacyError::InvalidInput(_) => "InvalidInput",
+            UpdateDomainContactPrivacyError::OperationLimitExceeded(_) => "OperationLimitExceeded",
+            UpdateDomainContactPrivacyError::TLDRulesViolation(_) => "TLDRulesViolation",
+            UpdateDomainContactPrivacyError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by UpdateDomainNameservers
 #[derive(Debug, PartialEq)]
 pub enum UpdateDomainNameserversError {
@@ -2282,6 +2523,17 @@ impl fmt::Display for UpdateDomainNameserversError {
     }
 }
 impl Error for UpdateDomainNameserversError {}
+impl ServiceErrorCode for UpdateDomainNameserversError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDomainNameserversError::DuplicateRequest(_) => "DuplicateRequest",
+            UpdateDomainNameserversError::InvalidInput(_) => "InvalidInput",
+            UpdateDomainNameserversError::OperationLimitExceeded(_) => "OperationLimitExceeded",
+            UpdateDomainNameserversError::TLDRulesViolation(_) => "TLDRulesViolation",
+            UpdateDomainNameserversError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by UpdateTagsForDomain
 #[derive(Debug, PartialEq)]
 pub enum UpdateTagsForDomainError {
@@ -2326,6 +2578,15 @@ impl fmt::Display for UpdateTagsForDomainError {
     }
 }
 impl Error for UpdateTagsForDomainError {}
+impl ServiceErrorCode for UpdateTagsForDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTagsForDomainError::InvalidInput(_) => "InvalidInput",
+            UpdateTagsForDomainError::OperationLimitExceeded(_) => "OperationLimitExceeded",
+            UpdateTagsForDomainError::UnsupportedTLD(_) => "UnsupportedTLD",
+        }
+    }
+}
 /// Errors returned by ViewBilling
 #[derive(Debug, PartialEq)]
 pub enum ViewBillingError {
@@ -2356,6 +2617,13 @@ impl fmt::Display for ViewBillingError {
     }
 }
 impl Error for ViewBillingError {}
+impl ServiceErrorCode for ViewBillingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ViewBillingError::InvalidInput(_) => "InvalidInput",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Route 53 Domains API. Amazon Route 53 Domains clients implement this trait.
 #[async_trait]
 pub trait Route53Domains {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -153,6 +153,7 @@ pub struct CreateResolverEndpointResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateResolverQueryLogConfigRequest {
     /// <p>A unique string that identifies the request and that allows failed requests to be retried without the risk of executing the operation twice. <code>CreatorRequestId</code> can be any unique string, for example, a date/time stamp. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "CreatorRequestId")]
     pub creator_request_id: String,
     /// <p><p>The ARN of the resource that you want Resolver to send query logs. You can send query logs to an S3 bucket, a CloudWatch Logs log group, or a Kinesis Data Firehose delivery stream. Examples of valid values include the following:</p> <ul> <li> <p> <b>S3 bucket</b>: </p> <p> <code>arn:aws:s3:::examplebucket</code> </p> <p>You can optionally append a file prefix to the end of the ARN.</p> <p> <code>arn:aws:s3:::examplebucket/development/</code> </p> </li> <li> <p> <b>CloudWatch Logs log group</b>: </p> <p> <code>arn:aws:logs:us-west-1:123456789012:log-group:/mystack-testgroup-12ABC1AB12A1:*</code> </p> </li> <li> <p> <b>Kinesis Data Firehose delivery stream</b>:</p> <p> <code>arn:aws:kinesis:us-east-2:0123456789:stream/my<em>stream</em>name</code> </p> </li> </ul></p>
@@ -1345,6 +1346,25 @@ impl fmt::Display for AssociateResolverEndpointIpAddressError {
     }
 }
 impl Error for AssociateResolverEndpointIpAddressError {}
+impl ServiceErrorCode for AssociateResolverEndpointIpAddressError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateResolverEndpointIpAddressError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            AssociateResolverEndpointIpAddressError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            AssociateResolverEndpointIpAddressError::InvalidRequest(_) => "InvalidRequestException",
+            AssociateResolverEndpointIpAddressError::LimitExceeded(_) => "LimitExceededException",
+            AssociateResolverEndpointIpAddressError::ResourceExists(_) => "ResourceExistsException",
+            AssociateResolverEndpointIpAddressError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            AssociateResolverEndpointIpAddressError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by AssociateResolverQueryLogConfig
 #[derive(Debug, PartialEq)]
 pub enum AssociateResolverQueryLogConfigError {
@@ -1447,6 +1467,26 @@ impl fmt::Display for AssociateResolverQueryLogConfigError {
     }
 }
 impl Error for AssociateResolverQueryLogConfigError {}
+impl ServiceErrorCode for AssociateResolverQueryLogConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateResolverQueryLogConfigError::AccessDenied(_) => "AccessDeniedException",
+            AssociateResolverQueryLogConfigError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            AssociateResolverQueryLogConfigError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            AssociateResolverQueryLogConfigError::InvalidRequest(_) => "InvalidRequestException",
+            AssociateResolverQueryLogConfigError::LimitExceeded(_) => "LimitExceededException",
+            AssociateResolverQueryLogConfigError::ResourceExists(_) => "ResourceExistsException",
+            AssociateResolverQueryLogConfigError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            AssociateResolverQueryLogConfigError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by AssociateResolverRule
 #[derive(Debug, PartialEq)]
 pub enum AssociateResolverRuleError {
@@ -1531,6 +1571,20 @@ impl fmt::Display for AssociateResolverRuleError {
     }
 }
 impl Error for AssociateResolverRuleError {}
+impl ServiceErrorCode for AssociateResolverRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateResolverRuleError::InternalServiceError(_) => "InternalServiceErrorException",
+            AssociateResolverRuleError::InvalidParameter(_) => "InvalidParameterException",
+            AssociateResolverRuleError::InvalidRequest(_) => "InvalidRequestException",
+            AssociateResolverRuleError::LimitExceeded(_) => "LimitExceededException",
+            AssociateResolverRuleError::ResourceExists(_) => "ResourceExistsException",
+            AssociateResolverRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateResolverRuleError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            AssociateResolverRuleError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateResolverEndpoint
 #[derive(Debug, PartialEq)]
 pub enum CreateResolverEndpointError {
@@ -1609,6 +1663,19 @@ impl fmt::Display for CreateResolverEndpointError {
     }
 }
 impl Error for CreateResolverEndpointError {}
+impl ServiceErrorCode for CreateResolverEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateResolverEndpointError::InternalServiceError(_) => "InternalServiceErrorException",
+            CreateResolverEndpointError::InvalidParameter(_) => "InvalidParameterException",
+            CreateResolverEndpointError::InvalidRequest(_) => "InvalidRequestException",
+            CreateResolverEndpointError::LimitExceeded(_) => "LimitExceededException",
+            CreateResolverEndpointError::ResourceExists(_) => "ResourceExistsException",
+            CreateResolverEndpointError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateResolverEndpointError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateResolverQueryLogConfig
 #[derive(Debug, PartialEq)]
 pub enum CreateResolverQueryLogConfigError {
@@ -1705,6 +1772,22 @@ impl fmt::Display for CreateResolverQueryLogConfigError {
     }
 }
 impl Error for CreateResolverQueryLogConfigError {}
+impl ServiceErrorCode for CreateResolverQueryLogConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateResolverQueryLogConfigError::AccessDenied(_) => "AccessDeniedException",
+            CreateResolverQueryLogConfigError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            CreateResolverQueryLogConfigError::InvalidParameter(_) => "InvalidParameterException",
+            CreateResolverQueryLogConfigError::InvalidRequest(_) => "InvalidRequestException",
+            CreateResolverQueryLogConfigError::LimitExceeded(_) => "LimitExceededException",
+            CreateResolverQueryLogConfigError::ResourceExists(_) => "ResourceExistsException",
+            CreateResolverQueryLogConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateResolverQueryLogConfigError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateResolverRule
 #[derive(Debug, PartialEq)]
 pub enum CreateResolverRuleError {
@@ -1781,6 +1864,20 @@ impl fmt::Display for CreateResolverRuleError {
     }
 }
 impl Error for CreateResolverRuleError {}
+impl ServiceErrorCode for CreateResolverRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateResolverRuleError::InternalServiceError(_) => "InternalServiceErrorException",
+            CreateResolverRuleError::InvalidParameter(_) => "InvalidParameterException",
+            CreateResolverRuleError::InvalidRequest(_) => "InvalidRequestException",
+            CreateResolverRuleError::LimitExceeded(_) => "LimitExceededException",
+            CreateResolverRuleError::ResourceExists(_) => "ResourceExistsException",
+            CreateResolverRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateResolverRuleError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            CreateResolverRuleError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteResolverEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DeleteResolverEndpointError {
@@ -1843,6 +1940,17 @@ impl fmt::Display for DeleteResolverEndpointError {
     }
 }
 impl Error for DeleteResolverEndpointError {}
+impl ServiceErrorCode for DeleteResolverEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResolverEndpointError::InternalServiceError(_) => "InternalServiceErrorException",
+            DeleteResolverEndpointError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteResolverEndpointError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteResolverEndpointError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteResolverEndpointError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteResolverQueryLogConfig
 #[derive(Debug, PartialEq)]
 pub enum DeleteResolverQueryLogConfigError {
@@ -1923,6 +2031,20 @@ impl fmt::Display for DeleteResolverQueryLogConfigError {
     }
 }
 impl Error for DeleteResolverQueryLogConfigError {}
+impl ServiceErrorCode for DeleteResolverQueryLogConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResolverQueryLogConfigError::AccessDenied(_) => "AccessDeniedException",
+            DeleteResolverQueryLogConfigError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            DeleteResolverQueryLogConfigError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteResolverQueryLogConfigError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteResolverQueryLogConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteResolverQueryLogConfigError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteResolverRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteResolverRuleError {
@@ -1979,6 +2101,17 @@ impl fmt::Display for DeleteResolverRuleError {
     }
 }
 impl Error for DeleteResolverRuleError {}
+impl ServiceErrorCode for DeleteResolverRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResolverRuleError::InternalServiceError(_) => "InternalServiceErrorException",
+            DeleteResolverRuleError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteResolverRuleError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteResolverRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteResolverRuleError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DisassociateResolverEndpointIpAddress
 #[derive(Debug, PartialEq)]
 pub enum DisassociateResolverEndpointIpAddressError {
@@ -2065,6 +2198,28 @@ impl fmt::Display for DisassociateResolverEndpointIpAddressError {
     }
 }
 impl Error for DisassociateResolverEndpointIpAddressError {}
+impl ServiceErrorCode for DisassociateResolverEndpointIpAddressError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateResolverEndpointIpAddressError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            DisassociateResolverEndpointIpAddressError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            DisassociateResolverEndpointIpAddressError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            DisassociateResolverEndpointIpAddressError::ResourceExists(_) => {
+                "ResourceExistsException"
+            }
+            DisassociateResolverEndpointIpAddressError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DisassociateResolverEndpointIpAddressError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DisassociateResolverQueryLogConfig
 #[derive(Debug, PartialEq)]
 pub enum DisassociateResolverQueryLogConfigError {
@@ -2151,6 +2306,24 @@ impl fmt::Display for DisassociateResolverQueryLogConfigError {
     }
 }
 impl Error for DisassociateResolverQueryLogConfigError {}
+impl ServiceErrorCode for DisassociateResolverQueryLogConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateResolverQueryLogConfigError::AccessDenied(_) => "AccessDeniedException",
+            DisassociateResolverQueryLogConfigError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            DisassociateResolverQueryLogConfigError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            DisassociateResolverQueryLogConfigError::InvalidRequest(_) => "InvalidRequestException",
+            DisassociateResolverQueryLogConfigError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DisassociateResolverQueryLogConfigError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DisassociateResolverRule
 #[derive(Debug, PartialEq)]
 pub enum DisassociateResolverRuleError {
@@ -2207,6 +2380,18 @@ impl fmt::Display for DisassociateResolverRuleError {
     }
 }
 impl Error for DisassociateResolverRuleError {}
+impl ServiceErrorCode for DisassociateResolverRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateResolverRuleError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            DisassociateResolverRuleError::InvalidParameter(_) => "InvalidParameterException",
+            DisassociateResolverRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DisassociateResolverRuleError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetResolverDnssecConfig
 #[derive(Debug, PartialEq)]
 pub enum GetResolverDnssecConfigError {
@@ -2277,6 +2462,20 @@ impl fmt::Display for GetResolverDnssecConfigError {
     }
 }
 impl Error for GetResolverDnssecConfigError {}
+impl ServiceErrorCode for GetResolverDnssecConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResolverDnssecConfigError::AccessDenied(_) => "AccessDeniedException",
+            GetResolverDnssecConfigError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            GetResolverDnssecConfigError::InvalidParameter(_) => "InvalidParameterException",
+            GetResolverDnssecConfigError::InvalidRequest(_) => "InvalidRequestException",
+            GetResolverDnssecConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetResolverDnssecConfigError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetResolverEndpoint
 #[derive(Debug, PartialEq)]
 pub enum GetResolverEndpointError {
@@ -2331,6 +2530,16 @@ impl fmt::Display for GetResolverEndpointError {
     }
 }
 impl Error for GetResolverEndpointError {}
+impl ServiceErrorCode for GetResolverEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResolverEndpointError::InternalServiceError(_) => "InternalServiceErrorException",
+            GetResolverEndpointError::InvalidParameter(_) => "InvalidParameterException",
+            GetResolverEndpointError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetResolverEndpointError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetResolverQueryLogConfig
 #[derive(Debug, PartialEq)]
 pub enum GetResolverQueryLogConfigError {
@@ -2405,6 +2614,20 @@ impl fmt::Display for GetResolverQueryLogConfigError {
     }
 }
 impl Error for GetResolverQueryLogConfigError {}
+impl ServiceErrorCode for GetResolverQueryLogConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResolverQueryLogConfigError::AccessDenied(_) => "AccessDeniedException",
+            GetResolverQueryLogConfigError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            GetResolverQueryLogConfigError::InvalidParameter(_) => "InvalidParameterException",
+            GetResolverQueryLogConfigError::InvalidRequest(_) => "InvalidRequestException",
+            GetResolverQueryLogConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetResolverQueryLogConfigError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetResolverQueryLogConfigAssociation
 #[derive(Debug, PartialEq)]
 pub enum GetResolverQueryLogConfigAssociationError {
@@ -2491,6 +2714,26 @@ impl fmt::Display for GetResolverQueryLogConfigAssociationError {
     }
 }
 impl Error for GetResolverQueryLogConfigAssociationError {}
+impl ServiceErrorCode for GetResolverQueryLogConfigAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResolverQueryLogConfigAssociationError::AccessDenied(_) => "AccessDeniedException",
+            GetResolverQueryLogConfigAssociationError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            GetResolverQueryLogConfigAssociationError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            GetResolverQueryLogConfigAssociationError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            GetResolverQueryLogConfigAssociationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            GetResolverQueryLogConfigAssociationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetResolverQueryLogConfigPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetResolverQueryLogConfigPolicyError {
@@ -2565,6 +2808,21 @@ impl fmt::Display for GetResolverQueryLogConfigPolicyError {
     }
 }
 impl Error for GetResolverQueryLogConfigPolicyError {}
+impl ServiceErrorCode for GetResolverQueryLogConfigPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResolverQueryLogConfigPolicyError::AccessDenied(_) => "AccessDeniedException",
+            GetResolverQueryLogConfigPolicyError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            GetResolverQueryLogConfigPolicyError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            GetResolverQueryLogConfigPolicyError::InvalidRequest(_) => "InvalidRequestException",
+            GetResolverQueryLogConfigPolicyError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by GetResolverRule
 #[derive(Debug, PartialEq)]
 pub enum GetResolverRuleError {
@@ -2615,6 +2873,16 @@ impl fmt::Display for GetResolverRuleError {
     }
 }
 impl Error for GetResolverRuleError {}
+impl ServiceErrorCode for GetResolverRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResolverRuleError::InternalServiceError(_) => "InternalServiceErrorException",
+            GetResolverRuleError::InvalidParameter(_) => "InvalidParameterException",
+            GetResolverRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetResolverRuleError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetResolverRuleAssociation
 #[derive(Debug, PartialEq)]
 pub enum GetResolverRuleAssociationError {
@@ -2675,6 +2943,18 @@ impl fmt::Display for GetResolverRuleAssociationError {
     }
 }
 impl Error for GetResolverRuleAssociationError {}
+impl ServiceErrorCode for GetResolverRuleAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResolverRuleAssociationError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            GetResolverRuleAssociationError::InvalidParameter(_) => "InvalidParameterException",
+            GetResolverRuleAssociationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetResolverRuleAssociationError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetResolverRulePolicy
 #[derive(Debug, PartialEq)]
 pub enum GetResolverRulePolicyError {
@@ -2723,6 +3003,15 @@ impl fmt::Display for GetResolverRulePolicyError {
     }
 }
 impl Error for GetResolverRulePolicyError {}
+impl ServiceErrorCode for GetResolverRulePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResolverRulePolicyError::InternalServiceError(_) => "InternalServiceErrorException",
+            GetResolverRulePolicyError::InvalidParameter(_) => "InvalidParameterException",
+            GetResolverRulePolicyError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by ListResolverDnssecConfigs
 #[derive(Debug, PartialEq)]
 pub enum ListResolverDnssecConfigsError {
@@ -2797,6 +3086,20 @@ impl fmt::Display for ListResolverDnssecConfigsError {
     }
 }
 impl Error for ListResolverDnssecConfigsError {}
+impl ServiceErrorCode for ListResolverDnssecConfigsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResolverDnssecConfigsError::AccessDenied(_) => "AccessDeniedException",
+            ListResolverDnssecConfigsError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            ListResolverDnssecConfigsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListResolverDnssecConfigsError::InvalidParameter(_) => "InvalidParameterException",
+            ListResolverDnssecConfigsError::InvalidRequest(_) => "InvalidRequestException",
+            ListResolverDnssecConfigsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListResolverEndpointIpAddresses
 #[derive(Debug, PartialEq)]
 pub enum ListResolverEndpointIpAddressesError {
@@ -2871,6 +3174,25 @@ impl fmt::Display for ListResolverEndpointIpAddressesError {
     }
 }
 impl Error for ListResolverEndpointIpAddressesError {}
+impl ServiceErrorCode for ListResolverEndpointIpAddressesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResolverEndpointIpAddressesError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            ListResolverEndpointIpAddressesError::InvalidNextToken(_) => {
+                "InvalidNextTokenException"
+            }
+            ListResolverEndpointIpAddressesError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            ListResolverEndpointIpAddressesError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            ListResolverEndpointIpAddressesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListResolverEndpoints
 #[derive(Debug, PartialEq)]
 pub enum ListResolverEndpointsError {
@@ -2933,6 +3255,17 @@ impl fmt::Display for ListResolverEndpointsError {
     }
 }
 impl Error for ListResolverEndpointsError {}
+impl ServiceErrorCode for ListResolverEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResolverEndpointsError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListResolverEndpointsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListResolverEndpointsError::InvalidParameter(_) => "InvalidParameterException",
+            ListResolverEndpointsError::InvalidRequest(_) => "InvalidRequestException",
+            ListResolverEndpointsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListResolverQueryLogConfigAssociations
 #[derive(Debug, PartialEq)]
 pub enum ListResolverQueryLogConfigAssociationsError {
@@ -3019,6 +3352,26 @@ impl fmt::Display for ListResolverQueryLogConfigAssociationsError {
     }
 }
 impl Error for ListResolverQueryLogConfigAssociationsError {}
+impl ServiceErrorCode for ListResolverQueryLogConfigAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResolverQueryLogConfigAssociationsError::AccessDenied(_) => "AccessDeniedException",
+            ListResolverQueryLogConfigAssociationsError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            ListResolverQueryLogConfigAssociationsError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            ListResolverQueryLogConfigAssociationsError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            ListResolverQueryLogConfigAssociationsError::LimitExceeded(_) => {
+                "LimitExceededException"
+            }
+            ListResolverQueryLogConfigAssociationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListResolverQueryLogConfigs
 #[derive(Debug, PartialEq)]
 pub enum ListResolverQueryLogConfigsError {
@@ -3095,6 +3448,20 @@ impl fmt::Display for ListResolverQueryLogConfigsError {
     }
 }
 impl Error for ListResolverQueryLogConfigsError {}
+impl ServiceErrorCode for ListResolverQueryLogConfigsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResolverQueryLogConfigsError::AccessDenied(_) => "AccessDeniedException",
+            ListResolverQueryLogConfigsError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            ListResolverQueryLogConfigsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListResolverQueryLogConfigsError::InvalidParameter(_) => "InvalidParameterException",
+            ListResolverQueryLogConfigsError::InvalidRequest(_) => "InvalidRequestException",
+            ListResolverQueryLogConfigsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListResolverRuleAssociations
 #[derive(Debug, PartialEq)]
 pub enum ListResolverRuleAssociationsError {
@@ -3167,6 +3534,19 @@ impl fmt::Display for ListResolverRuleAssociationsError {
     }
 }
 impl Error for ListResolverRuleAssociationsError {}
+impl ServiceErrorCode for ListResolverRuleAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResolverRuleAssociationsError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            ListResolverRuleAssociationsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListResolverRuleAssociationsError::InvalidParameter(_) => "InvalidParameterException",
+            ListResolverRuleAssociationsError::InvalidRequest(_) => "InvalidRequestException",
+            ListResolverRuleAssociationsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListResolverRules
 #[derive(Debug, PartialEq)]
 pub enum ListResolverRulesError {
@@ -3223,6 +3603,17 @@ impl fmt::Display for ListResolverRulesError {
     }
 }
 impl Error for ListResolverRulesError {}
+impl ServiceErrorCode for ListResolverRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResolverRulesError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListResolverRulesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListResolverRulesError::InvalidParameter(_) => "InvalidParameterException",
+            ListResolverRulesError::InvalidRequest(_) => "InvalidRequestException",
+            ListResolverRulesError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3291,6 +3682,18 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListTagsForResourceError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListTagsForResourceError::InvalidParameter(_) => "InvalidParameterException",
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by PutResolverQueryLogConfigPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutResolverQueryLogConfigPolicyError {
@@ -3375,6 +3778,24 @@ impl fmt::Display for PutResolverQueryLogConfigPolicyError {
     }
 }
 impl Error for PutResolverQueryLogConfigPolicyError {}
+impl ServiceErrorCode for PutResolverQueryLogConfigPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutResolverQueryLogConfigPolicyError::AccessDenied(_) => "AccessDeniedException",
+            PutResolverQueryLogConfigPolicyError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            PutResolverQueryLogConfigPolicyError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            PutResolverQueryLogConfigPolicyError::InvalidPolicyDocument(_) => {
+                "InvalidPolicyDocument"
+            }
+            PutResolverQueryLogConfigPolicyError::InvalidRequest(_) => "InvalidRequestException",
+            PutResolverQueryLogConfigPolicyError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by PutResolverRulePolicy
 #[derive(Debug, PartialEq)]
 pub enum PutResolverRulePolicyError {
@@ -3431,6 +3852,16 @@ impl fmt::Display for PutResolverRulePolicyError {
     }
 }
 impl Error for PutResolverRulePolicyError {}
+impl ServiceErrorCode for PutResolverRulePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutResolverRulePolicyError::InternalServiceError(_) => "InternalServiceErrorException",
+            PutResolverRulePolicyError::InvalidParameter(_) => "InvalidParameterException",
+            PutResolverRulePolicyError::InvalidPolicyDocument(_) => "InvalidPolicyDocument",
+            PutResolverRulePolicyError::UnknownResource(_) => "UnknownResourceException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -3497,6 +3928,19 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalServiceError(_) => "InternalServiceErrorException",
+            TagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            TagResourceError::InvalidTag(_) => "InvalidTagException",
+            TagResourceError::LimitExceeded(_) => "LimitExceededException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -3551,6 +3995,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalServiceError(_) => "InternalServiceErrorException",
+            UntagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateResolverDnssecConfig
 #[derive(Debug, PartialEq)]
 pub enum UpdateResolverDnssecConfigError {
@@ -3627,6 +4082,20 @@ impl fmt::Display for UpdateResolverDnssecConfigError {
     }
 }
 impl Error for UpdateResolverDnssecConfigError {}
+impl ServiceErrorCode for UpdateResolverDnssecConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateResolverDnssecConfigError::AccessDenied(_) => "AccessDeniedException",
+            UpdateResolverDnssecConfigError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            UpdateResolverDnssecConfigError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateResolverDnssecConfigError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateResolverDnssecConfigError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateResolverDnssecConfigError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateResolverEndpoint
 #[derive(Debug, PartialEq)]
 pub enum UpdateResolverEndpointError {
@@ -3689,6 +4158,17 @@ impl fmt::Display for UpdateResolverEndpointError {
     }
 }
 impl Error for UpdateResolverEndpointError {}
+impl ServiceErrorCode for UpdateResolverEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateResolverEndpointError::InternalServiceError(_) => "InternalServiceErrorException",
+            UpdateResolverEndpointError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateResolverEndpointError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateResolverEndpointError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateResolverEndpointError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateResolverRule
 #[derive(Debug, PartialEq)]
 pub enum UpdateResolverRuleError {
@@ -3759,6 +4239,19 @@ impl fmt::Display for UpdateResolverRuleError {
     }
 }
 impl Error for UpdateResolverRuleError {}
+impl ServiceErrorCode for UpdateResolverRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateResolverRuleError::InternalServiceError(_) => "InternalServiceErrorException",
+            UpdateResolverRuleError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateResolverRuleError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateResolverRuleError::LimitExceeded(_) => "LimitExceededException",
+            UpdateResolverRuleError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateResolverRuleError::ResourceUnavailable(_) => "ResourceUnavailableException",
+            UpdateResolverRuleError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Route53Resolver API. Route53Resolver clients implement this trait.
 #[async_trait]
 pub trait Route53Resolver {
@@ -4136,6 +4629,12 @@ impl Route53Resolver for Route53ResolverClient {
         input: CreateResolverQueryLogConfigRequest,
     ) -> Result<CreateResolverQueryLogConfigResponse, RusotoError<CreateResolverQueryLogConfigError>>
     {
+        let mut input = input;
+        if input.creator_request_id.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.creator_request_id = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
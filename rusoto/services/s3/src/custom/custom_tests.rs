@@ -1,12 +1,22 @@
 extern crate rusoto_mock;
 
-use crate::{custom::util::AddressingStyle, generated::*};
+use crate::custom::range::{ByteRange, ContentRange};
+use crate::custom::storage_class::StorageClass;
+use crate::custom::transform::BodyTransform;
+use crate::custom::util::{
+    compute_multipart_etag, AddressingStyle, EndpointRequest, EndpointResolver, PreSignedRequest,
+    PreSignedRequestOption, S3Endpoint,
+};
+use crate::generated::*;
 
 use self::rusoto_mock::*;
 use bytes::BytesMut;
 use futures::TryStreamExt;
-use rusoto_core::signature::SignedRequest;
-use rusoto_core::{Region, RusotoError};
+use rusoto_core::credential::AwsCredentials;
+use rusoto_core::credential::Clock;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::{Client, InvalidDnsNameError, Region, RusotoError};
+use std::sync::Arc;
 
 #[tokio::test]
 async fn test_multipart_upload_copy_response() {
@@ -58,7 +68,7 @@ async fn test_list_object_versions_with_multiple_versions() {
     assert_eq!(client.config().addressing_style, AddressingStyle::Auto);
     let result = client
         .list_object_versions(ListObjectVersionsRequest {
-            bucket: "test_bucket".to_string(),
+            bucket: "test-bucket".to_string(),
             ..Default::default()
         })
         .await
@@ -398,13 +408,189 @@ async fn should_parse_headers() {
 
     let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
     assert_eq!(client.config().addressing_style, AddressingStyle::Auto);
-    let request = HeadObjectRequest::default();
+    let request = HeadObjectRequest {
+        bucket: "bucket".to_owned(),
+        ..Default::default()
+    };
     let result = client.head_object(request).await.unwrap();
 
     assert_eq!(result.expiration, Some("foo".to_string()));
     assert_eq!(result.restore, Some("bar".to_string()));
 }
 
+/// Mocks the response in a fixture: a status line, headers and an optional body after a
+/// blank line, as on the wire.
+fn mock_response(raw: &str) -> MockRequestDispatcher {
+    let (head, body) = match raw.find("\n\n") {
+        Some(end) => (&raw[..end], &raw[end + 2..]),
+        None => (raw, ""),
+    };
+    let mut lines = head.lines();
+    let status = lines.next().unwrap().split(' ').nth(1).unwrap();
+    lines
+        .map(|line| line.split_at(line.find(':').unwrap()))
+        .fold(
+            MockRequestDispatcher::with_status(status.parse().unwrap()).with_body(body),
+            |mock, (name, value)| mock.with_header(name, value[1..].trim()),
+        )
+}
+
+#[tokio::test]
+async fn should_parse_repeated_metadata_headers() {
+    let expected: ::std::collections::HashMap<String, String> = vec![
+        ("author", "Jane Doe"),
+        ("project", "apollo"),
+        ("tag", "red,green,blue"),
+        ("empty", ""),
+    ]
+    .into_iter()
+    .map(|(name, value)| (name.to_owned(), value.to_owned()))
+    .collect();
+
+    let response = include_str!("../../test_resources/custom/s3_head_object_metadata.txt");
+    let mock = || mock_response(response);
+
+    let client = S3Client::new_with(mock(), MockCredentialsProvider, Region::UsEast1);
+    let request = HeadObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    };
+    let result = client.head_object(request).await.unwrap();
+    assert_eq!(result.metadata, Some(expected.clone()));
+    assert_eq!(result.content_length, Some(11));
+
+    let client = S3Client::new_with(mock(), MockCredentialsProvider, Region::UsEast1);
+    let request = GetObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    };
+    let result = client.get_object(request).await.unwrap();
+    assert_eq!(result.metadata, Some(expected));
+}
+
+#[tokio::test]
+async fn should_parse_request_charged() {
+    let mock = mock_response(include_str!(
+        "../../test_resources/custom/s3_get_object_requester_pays.txt"
+    ));
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = GetObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        request_payer: sstr("requester"),
+        ..Default::default()
+    };
+    let result = client.get_object(request).await.unwrap();
+    assert_eq!(result.request_charged, sstr("requester"));
+
+    let mock = mock_response(include_str!(
+        "../../test_resources/custom/s3_put_object_requester_pays.txt"
+    ));
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = PutObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        request_payer: sstr("requester"),
+        ..Default::default()
+    };
+    let result = client.put_object(request).await.unwrap();
+    assert_eq!(result.request_charged, sstr("requester"));
+    assert_eq!(result.e_tag, sstr("\"9b2cf535f27731c974343645a3985328\""));
+}
+
+#[tokio::test]
+async fn should_parse_storage_class() {
+    let mock = mock_response(include_str!(
+        "../../test_resources/custom/s3_head_object_glacier_ir.txt"
+    ));
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = HeadObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "archive.tar".to_owned(),
+        ..Default::default()
+    };
+    let result = client.head_object(request).await.unwrap();
+    assert_eq!(result.storage_class, sstr("GLACIER_IR"));
+    assert_eq!(result.parsed_storage_class(), Ok(StorageClass::GlacierIr));
+
+    let mock = MockRequestDispatcher::with_status(200).with_body(
+        r#"<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Name>bucket</Name>
+            <IsTruncated>false</IsTruncated>
+            <Contents><Key>a</Key><StorageClass>DEEP_ARCHIVE</StorageClass></Contents>
+            <Contents><Key>b</Key><StorageClass>EXPRESS_ONEZONE</StorageClass></Contents>
+            <Contents><Key>c</Key><StorageClass>INTELLIGENT_TIERING</StorageClass></Contents>
+        </ListBucketResult>"#,
+    );
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = ListObjectsV2Request {
+        bucket: "bucket".to_owned(),
+        ..Default::default()
+    };
+    let result = client.list_objects_v2(request).await.unwrap();
+    let classes: Vec<StorageClass> = result
+        .contents
+        .unwrap()
+        .iter()
+        .map(|object| object.parsed_storage_class().unwrap())
+        .collect();
+    assert_eq!(
+        classes,
+        vec![
+            StorageClass::DeepArchive,
+            StorageClass::ExpressOnezone,
+            StorageClass::IntelligentTiering
+        ]
+    );
+}
+
+#[tokio::test]
+async fn should_parse_content_range_of_partial_content() {
+    let mock = mock_response(include_str!(
+        "../../test_resources/custom/s3_get_object_partial_content.txt"
+    ));
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = GetObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    }
+    .with_range(ByteRange::Inclusive(1024, 1039));
+    let result = client.get_object(request).await.unwrap();
+
+    assert_eq!(
+        result.content_range.as_deref(),
+        Some("bytes 1024-1039/1048576")
+    );
+    assert_eq!(
+        result.parsed_content_range(),
+        Some(Ok(ContentRange {
+            range_start: 1024,
+            range_end: 1039,
+            total_size: Some(1048576),
+        }))
+    );
+    assert_eq!(result.content_length, Some(16));
+    let body = result
+        .body
+        .unwrap()
+        .map_ok(|b| BytesMut::from(&b[..]))
+        .try_concat();
+    assert_eq!(&body.await.unwrap()[..], b"0123456789abcdef");
+
+    let result = GetObjectOutput {
+        content_range: Some("bytes 0-15/*".to_owned()),
+        ..Default::default()
+    };
+    assert_eq!(
+        result.parsed_content_range().unwrap().unwrap().total_size,
+        None
+    );
+    assert_eq!(GetObjectOutput::default().parsed_content_range(), None);
+}
+
 #[tokio::test]
 async fn should_serialize_complicated_request() {
     let request = GetObjectRequest {
@@ -493,74 +679,2152 @@ async fn can_construct_streaming_body() {
     assert_eq!("Simple Body Test", read_string);
 }
 
-#[test]
-fn structs_should_impl_clone() {
-    fn assert_clone<T: Clone>() {}
+#[tokio::test]
+async fn put_empty_object_sends_zero_content_length() {
+    let checker = |request: &SignedRequest| {
+        assert_eq!(request.method, "PUT");
+        assert_eq!(
+            request.headers.get("content-length"),
+            Some(&vec![b"0".to_vec()])
+        );
+    };
 
-    // GetObjectRequest is common but simple. It must impl Clone.
-    // (But note GetObjectOutput cannot impl Clone because it uses streaming.)
-    assert_clone::<GetObjectRequest>();
-    // RestoreObjectRequest has several layers of structs, all of which must impl Clone.
-    assert_clone::<RestoreObjectRequest>();
-}
+    // Without a body at all.
+    let mock = MockRequestDispatcher::with_status(200).with_request_checker(checker);
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = PutObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "empty".to_owned(),
+        ..Default::default()
+    };
+    client.put_object(request).await.unwrap();
 
-/// returns Some(String)
-fn sstr(value: &'static str) -> Option<String> {
-    Some(value.to_string())
+    // With an empty body.
+    let mock = MockRequestDispatcher::with_status(200).with_request_checker(checker);
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = PutObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "empty".to_owned(),
+        body: Some(Vec::new().into()),
+        ..Default::default()
+    };
+    client.put_object(request).await.unwrap();
 }
 
 #[tokio::test]
-async fn test_parse_no_such_bucket_error() {
-    let mock = MockRequestDispatcher::with_status(404).with_body(
-        r#"<?xml version="1.0" encoding="UTF-8"?>
-        <Error>
-            <Code>NoSuchBucket</Code>
-            <Message>The specified bucket does not exist</Message>
-            <RequestId>4442587FB7D0A2F9</RequestId>
-        </Error>"#,
-    );
+async fn should_validate_requests_before_sending() {
+    let mock = MockRequestDispatcher::with_status(200).with_request_checker(|_| {
+        panic!("an invalid request should not be sent");
+    });
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
 
     let request = ListObjectsV2Request {
-        bucket: "no-such-bucket".to_owned(),
+        bucket: "My_Bucket".to_owned(),
+        ..Default::default()
+    };
+    match client.list_objects_v2(request).await {
+        Err(RusotoError::Validation(message)) => assert!(message.contains("My_Bucket")),
+        result => panic!("expected a validation error, got {:?}", result),
+    }
+
+    let request = ListObjectsV2Request {
+        bucket: "bucket".to_owned(),
+        max_keys: Some(-1),
+        ..Default::default()
+    };
+    match client.list_objects_v2(request).await {
+        Err(RusotoError::Validation(message)) => assert!(message.contains("max-keys")),
+        result => panic!("expected a validation error, got {:?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_skip_validation_when_server_side_only() {
+    let mock =
+        MockRequestDispatcher::with_status(200).with_request_checker(|request: &SignedRequest| {
+            assert_eq!(request.path, "/My_Bucket");
+            assert_eq!(request.params.get("max-keys"), Some(&Some("-1".to_owned())));
+        });
+    let mut client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    client.config_mut().server_side_validation_only = true;
+    let request = ListObjectsV2Request {
+        bucket: "My_Bucket".to_owned(),
+        max_keys: Some(-1),
+        ..Default::default()
+    };
+    client.list_objects_v2(request).await.unwrap();
+}
+
+#[tokio::test]
+async fn put_unsized_stream_requires_content_length() {
+    fn unsized_body() -> Option<StreamingBody> {
+        let chunks = futures::stream::once(async { Ok(bytes::Bytes::from_static(b"body")) });
+        Some(StreamingBody::new(chunks))
+    }
+
+    let mock = MockRequestDispatcher::with_status(200).with_request_checker(|_| {
+        panic!("a body of unknown length should not be sent");
+    });
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = PutObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        body: unsized_body(),
         ..Default::default()
     };
+    match client.put_object(request).await {
+        Err(RusotoError::Validation(message)) => {
+            assert!(
+                message.contains("Transfer-Encoding: chunked"),
+                "{}",
+                message
+            )
+        }
+        result => panic!("expected a validation error, got {:?}", result),
+    }
 
+    // An explicit Content-Length is enough.
+    let mock =
+        MockRequestDispatcher::with_status(200).with_request_checker(|request: &SignedRequest| {
+            assert_eq!(
+                request.headers.get("content-length"),
+                Some(&vec![b"4".to_vec()])
+            );
+        });
     let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
-    assert_eq!(client.config().addressing_style, AddressingStyle::Auto);
-    let result = client.list_objects_v2(request).await;
-    assert!(result.is_err());
-    let err = result.err().unwrap();
+    let request = PutObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        body: unsized_body(),
+        content_length: Some(4),
+        ..Default::default()
+    };
+    client.put_object(request).await.unwrap();
+
+    // Endpoints that accept chunked bodies can opt in.
+    let mock =
+        MockRequestDispatcher::with_status(200).with_request_checker(|request: &SignedRequest| {
+            assert!(!request.headers.contains_key("content-length"));
+        });
+    let mut client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    client.config_mut().allow_chunked_encoding = true;
+    let request = PutObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        body: unsized_body(),
+        ..Default::default()
+    };
+    client.put_object(request).await.unwrap();
+}
+
+#[tokio::test]
+async fn should_parse_delete_marker_and_version_headers() {
+    let client = S3Client::new_with(
+        MockRequestDispatcher::with_status(204)
+            .with_header("x-amz-delete-marker", "true")
+            .with_header("x-amz-version-id", "3HL4kqtJlcpXroDTDmJ-rmSpXd3dIbrHY"),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    let result = client
+        .delete_object(DeleteObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(result.delete_marker, Some(true));
+    assert_eq!(result.version_id, sstr("3HL4kqtJlcpXroDTDmJ-rmSpXd3dIbrHY"));
+
+    let client = S3Client::new_with(
+        MockRequestDispatcher::with_status(200)
+            .with_body("body")
+            .with_header("x-amz-delete-marker", "false")
+            .with_header(
+                "x-amz-version-id",
+                "UIORUnfndfiufdisojhr398493jfdkjFJjkndnqUifhnw89493jJFJ",
+            ),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    let result = client
+        .get_object(GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(result.delete_marker, Some(false));
     assert_eq!(
-        RusotoError::Service(ListObjectsV2Error::NoSuchBucket(
-            "The specified bucket does not exist".to_owned()
-        )),
-        err
+        result.version_id,
+        sstr("UIORUnfndfiufdisojhr398493jfdkjFJjkndnqUifhnw89493jJFJ")
+    );
+
+    let client = S3Client::new_with(
+        MockRequestDispatcher::with_status(200).with_header(
+            "x-amz-version-id",
+            "43jfkodU8493jnFJD9fjj3HHNVfdsQUIFDNsidf038jfdsjGFDSIRp",
+        ),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    let result = client
+        .put_object(PutObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        result.version_id,
+        sstr("43jfkodU8493jnFJD9fjj3HHNVfdsQUIFDNsidf038jfdsjGFDSIRp")
     );
 }
 
 #[tokio::test]
-async fn test_multiple_mock() {
-    let mock = MultipleMockRequestDispatcher::new(vec![
-        MockRequestDispatcher::with_status(200)
-            .with_body("")
-            .with_header("x-amz-expiration", "foo1")
-            .with_header("x-amz-restore", "bar1"),
-        MockRequestDispatcher::with_status(200)
-            .with_body("")
-            .with_header("x-amz-expiration", "foo2")
-            .with_header("x-amz-restore", "bar2"),
-    ]);
+async fn should_parse_responses_without_version_headers() {
+    let client = S3Client::new_with(
+        MockRequestDispatcher::with_status(204),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    let result = client
+        .delete_object(DeleteObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(result.delete_marker, None);
+    assert_eq!(result.version_id, None);
 
-    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
-    assert_eq!(client.config().addressing_style, AddressingStyle::Auto);
+    let client = S3Client::new_with(
+        MockRequestDispatcher::with_status(200).with_body("body"),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    let result = client
+        .get_object(GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(result.delete_marker, None);
+    assert_eq!(result.version_id, None);
 
-    let mut request = HeadObjectRequest::default();
-    let mut result = client.head_object(request).await.unwrap();
-    assert_eq!(result.expiration, Some("foo1".to_string()));
-    assert_eq!(result.restore, Some("bar1".to_string()));
+    let client = S3Client::new_with(
+        MockRequestDispatcher::with_status(200),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    let result = client
+        .put_object(PutObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(result.version_id, None);
+}
 
-    request = HeadObjectRequest::default();
-    result = client.head_object(request).await.unwrap();
-    assert_eq!(result.expiration, Some("foo2".to_string()));
-    assert_eq!(result.restore, Some("bar2".to_string()));
+#[tokio::test]
+async fn should_sign_with_the_client_clock() {
+    let clock = MockClock::default();
+    for _ in 0..2 {
+        let expected = clock.now().format("%Y%m%dT%H%M%SZ").to_string();
+        let mock = MockRequestDispatcher::with_status(200).with_request_checker(
+            move |request: &SignedRequest| {
+                assert_eq!(
+                    request.headers.get("x-amz-date"),
+                    Some(&vec![expected.as_bytes().to_vec()])
+                );
+            },
+        );
+        let client = Client::new_with(MockCredentialsProvider, mock).with_clock(clock.clone());
+        let client = S3Client::new_with_client(client, Region::UsEast1);
+        let request = HeadObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        };
+        client.head_object(request).await.unwrap();
+
+        clock.advance(std::time::Duration::from_secs(3600));
+    }
+}
+
+#[test]
+fn should_presign_with_the_option_clock() {
+    use crate::custom::util::{verify_presigned_url_at, PreSignError, PresignedUrlPolicy};
+    use chrono::{TimeZone, Utc};
+    use std::time::{Duration, SystemTime};
+
+    let clock = MockClock::new(Utc.timestamp_opt(1_600_000_000, 0).unwrap());
+    let option = PreSignedRequestOption::builder()
+        .expires_in(Duration::from_secs(300))
+        .clock(clock.clone())
+        .build();
+    let credentials = AwsCredentials::new("foo_access_key", "foo_secret_key", None, None);
+    let request = GetObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    };
+    let (url, expires_at) = request
+        .get_presigned_url_with_expiry(&Region::UsEast1, &credentials, &option)
+        .unwrap();
+    assert!(url.contains("X-Amz-Date=20200913T122640Z"), "{}", url);
+    assert_eq!(
+        expires_at,
+        SystemTime::from(clock.now()) + Duration::from_secs(300)
+    );
+
+    let policy = PresignedUrlPolicy::default();
+    clock.advance(Duration::from_secs(299));
+    assert_eq!(
+        verify_presigned_url_at("GET", &url, &credentials, &policy, clock.now().into()),
+        Ok(())
+    );
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(
+        verify_presigned_url_at("GET", &url, &credentials, &policy, clock.now().into()),
+        Err(PreSignError::Rejected(
+            "the URL expired at Sun, 13 Sep 2020 12:31:40 GMT".to_owned()
+        ))
+    );
+}
+
+#[tokio::test]
+async fn should_use_vpc_endpoint() {
+    let mock =
+        MockRequestDispatcher::with_status(200).with_request_checker(|request: &SignedRequest| {
+            assert_eq!(
+                request.hostname(),
+                "bucket.vpce-0abc-1def.s3.us-east-1.vpce.amazonaws.com"
+            );
+            assert_eq!(request.region, Region::UsEast1);
+            assert_eq!(request.path, "/key");
+        });
+    let mut client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    client.config_mut().vpc_endpoint =
+        Some("vpce-0abc-1def.s3.us-east-1.vpce.amazonaws.com".to_owned());
+    let request = HeadObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    };
+    client.head_object(request).await.unwrap();
+}
+
+#[tokio::test]
+async fn should_send_requests_to_object_lambda_access_points() {
+    let mock =
+        MockRequestDispatcher::with_status(200).with_request_checker(|request: &SignedRequest| {
+            assert_eq!(
+                request.hostname(),
+                "my-transform-123456789012.s3-object-lambda.us-west-2.amazonaws.com"
+            );
+            assert_eq!(request.service, "s3-object-lambda");
+            assert_eq!(request.region, Region::UsWest2);
+            assert_eq!(request.path, "/key");
+        });
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsWest2);
+    let request = GetObjectRequest {
+        bucket: "arn:aws:s3-object-lambda:us-west-2:123456789012:accesspoint/my-transform"
+            .to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    };
+    client.get_object(request).await.unwrap();
+}
+
+#[derive(Debug, Default)]
+struct InternalEndpoint {
+    operations: std::sync::Mutex<Vec<String>>,
+}
+
+impl EndpointResolver for InternalEndpoint {
+    fn resolve(&self, request: &EndpointRequest) -> Result<S3Endpoint, InvalidDnsNameError> {
+        self.operations
+            .lock()
+            .unwrap()
+            .push(request.operation.unwrap_or_default().to_owned());
+        Ok(S3Endpoint {
+            host: "storage.internal:9000".to_owned(),
+            scheme: Some("http".to_owned()),
+            virtual_hosted: false,
+            signing_service: None,
+        })
+    }
+}
+
+#[tokio::test]
+async fn should_use_endpoint_resolver() {
+    let mock =
+        MockRequestDispatcher::with_status(200).with_request_checker(|request: &SignedRequest| {
+            assert_eq!(request.hostname(), "storage.internal:9000");
+            assert_eq!(request.scheme(), "http");
+            assert_eq!(request.region, Region::UsEast1);
+            assert_eq!(request.path, "/bucket/key");
+        });
+    let resolver = Arc::new(InternalEndpoint::default());
+    let mut client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    client.config_mut().endpoint_resolver = Some(resolver.clone());
+    let request = HeadObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    };
+    client.head_object(request).await.unwrap();
+
+    let option = PreSignedRequestOption::builder()
+        .endpoint_resolver(resolver.clone())
+        .build();
+    let credentials = AwsCredentials::new("foo_access_key", "foo_secret_key", None, None);
+    let request = GetObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    };
+    let url = request
+        .get_presigned_url(&Region::UsEast1, &credentials, &option)
+        .unwrap();
+    assert!(url.starts_with("http://storage.internal:9000/bucket/key?"));
+
+    assert_eq!(
+        *resolver.operations.lock().unwrap(),
+        ["HeadObject", "GetObject"]
+    );
+}
+
+/// Swaps the case of ASCII letters in bodies, marking uploads in their metadata.
+#[derive(Debug)]
+struct SwapCase;
+
+fn swap_case(body: StreamingBody) -> StreamingBody {
+    body.map_chunks(|chunk| {
+        chunk
+            .iter()
+            .map(|&byte| match byte {
+                b'a'..=b'z' | b'A'..=b'Z' => byte ^ 0x20,
+                _ => byte,
+            })
+            .collect()
+    })
+}
+
+impl BodyTransform for SwapCase {
+    fn wrap_upload(&self, body: StreamingBody, request: &mut PutObjectRequest) -> StreamingBody {
+        request
+            .metadata
+            .get_or_insert_with(Default::default)
+            .insert("transform".to_owned(), "swap-case".to_owned());
+        request.content_md5 = None;
+        swap_case(body)
+    }
+
+    fn wrap_download(&self, body: StreamingBody, output: &GetObjectOutput) -> StreamingBody {
+        match output.metadata.as_ref().and_then(|m| m.get("transform")) {
+            Some(transform) if transform == "swap-case" => swap_case(body),
+            _ => body,
+        }
+    }
+}
+
+#[tokio::test]
+async fn should_transform_uploaded_bodies() {
+    let mock =
+        MockRequestDispatcher::with_status(200).with_request_checker(|request: &SignedRequest| {
+            assert_eq!(
+                request.headers.get("x-amz-meta-transform"),
+                Some(&vec![b"swap-case".to_vec()])
+            );
+            assert!(!request.headers.contains_key("content-md5"));
+            assert_eq!(
+                request.headers.get("content-length"),
+                Some(&vec![b"4".to_vec()])
+            );
+        });
+    let mut client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    client.config_mut().body_transform = Some(Arc::new(SwapCase));
+    let request = PutObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        body: Some(b"body".to_vec().into()),
+        content_md5: sstr("hBotaJrYa9FhFEdFPCLG/A=="),
+        ..Default::default()
+    };
+    client.put_object(request).await.unwrap();
+
+    // Requests without a body get an empty one.
+    let mock =
+        MockRequestDispatcher::with_status(200).with_request_checker(|request: &SignedRequest| {
+            assert!(request.headers.contains_key("x-amz-meta-transform"));
+            assert_eq!(
+                request.headers.get("content-length"),
+                Some(&vec![b"0".to_vec()])
+            );
+        });
+    let mut client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    client.config_mut().body_transform = Some(Arc::new(SwapCase));
+    let request = PutObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    };
+    client.put_object(request).await.unwrap();
+}
+
+#[tokio::test]
+async fn should_transform_downloaded_bodies() {
+    async fn get_body(mock: MockRequestDispatcher) -> Vec<u8> {
+        let mut client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        client.config_mut().body_transform = Some(Arc::new(SwapCase));
+        let request = GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        };
+        let output = client.get_object(request).await.unwrap();
+        let body: BytesMut = output
+            .body
+            .unwrap()
+            .map_ok(|b| BytesMut::from(&b[..]))
+            .try_concat()
+            .await
+            .unwrap();
+        body.to_vec()
+    }
+
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body("BoDy 42")
+        .with_header("x-amz-meta-transform", "swap-case");
+    assert_eq!(get_body(mock).await, b"bOdY 42");
+
+    // Objects uploaded without the transform are left alone.
+    let mock = MockRequestDispatcher::with_status(200).with_body("BoDy 42");
+    assert_eq!(get_body(mock).await, b"BoDy 42");
+}
+
+#[test]
+fn structs_should_impl_clone() {
+    fn assert_clone<T: Clone>() {}
+
+    // GetObjectRequest is common but simple. It must impl Clone.
+    // (But note GetObjectOutput cannot impl Clone because it uses streaming.)
+    assert_clone::<GetObjectRequest>();
+    // RestoreObjectRequest has several layers of structs, all of which must impl Clone.
+    assert_clone::<RestoreObjectRequest>();
+}
+
+/// returns Some(String)
+fn sstr(value: &'static str) -> Option<String> {
+    Some(value.to_string())
+}
+
+#[tokio::test]
+async fn test_parse_no_such_bucket_error() {
+    let mock = MockRequestDispatcher::with_status(404).with_body(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+        <Error>
+            <Code>NoSuchBucket</Code>
+            <Message>The specified bucket does not exist</Message>
+            <RequestId>4442587FB7D0A2F9</RequestId>
+        </Error>"#,
+    );
+
+    let request = ListObjectsV2Request {
+        bucket: "no-such-bucket".to_owned(),
+        ..Default::default()
+    };
+
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    assert_eq!(client.config().addressing_style, AddressingStyle::Auto);
+    let result = client.list_objects_v2(request).await;
+    assert!(result.is_err());
+    let err = result.err().unwrap();
+    assert_eq!(
+        RusotoError::Service(ListObjectsV2Error::NoSuchBucket(
+            "The specified bucket does not exist".to_owned()
+        )),
+        err
+    );
+    assert_eq!(err.error_code().as_deref(), Some("NoSuchBucket"));
+
+    // Errors the operation doesn't model still have their code.
+    let mock = MockRequestDispatcher::with_status(403)
+        .with_body(r#"<Error><Code>AccessDenied</Code><Message>Access Denied</Message></Error>"#);
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let err = client
+        .list_objects_v2(ListObjectsV2Request {
+            bucket: "bucket".to_owned(),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RusotoError::Unknown(_)));
+    assert_eq!(err.error_code().as_deref(), Some("AccessDenied"));
+}
+
+#[tokio::test]
+async fn test_parse_json_error() {
+    let mock = MockRequestDispatcher::with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"Code":"NoSuchKey","Message":"The specified key does not exist."}"#);
+
+    let request = GetObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "no-such-key".to_owned(),
+        ..Default::default()
+    };
+
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let err = client.get_object(request).await.err().unwrap();
+    assert_eq!(
+        RusotoError::Service(GetObjectError::NoSuchKey(
+            "The specified key does not exist.".to_owned()
+        )),
+        err
+    );
+    assert_eq!(err.error_code().as_deref(), Some("NoSuchKey"));
+}
+
+#[tokio::test]
+async fn test_multiple_mock() {
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        MockRequestDispatcher::with_status(200)
+            .with_body("")
+            .with_header("x-amz-expiration", "foo1")
+            .with_header("x-amz-restore", "bar1"),
+        MockRequestDispatcher::with_status(200)
+            .with_body("")
+            .with_header("x-amz-expiration", "foo2")
+            .with_header("x-amz-restore", "bar2"),
+    ]);
+
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    assert_eq!(client.config().addressing_style, AddressingStyle::Auto);
+
+    let mut request = HeadObjectRequest {
+        bucket: "bucket".to_owned(),
+        ..Default::default()
+    };
+    let mut result = client.head_object(request).await.unwrap();
+    assert_eq!(result.expiration, Some("foo1".to_string()));
+    assert_eq!(result.restore, Some("bar1".to_string()));
+
+    request = HeadObjectRequest {
+        bucket: "bucket".to_owned(),
+        ..Default::default()
+    };
+    result = client.head_object(request).await.unwrap();
+    assert_eq!(result.expiration, Some("foo2".to_string()));
+    assert_eq!(result.restore, Some("bar2".to_string()));
+}
+
+fn list_objects_v2_page(
+    prefix: &'static str,
+    continuation_token: Option<&'static str>,
+    body: &str,
+) -> MockRequestDispatcher {
+    MockRequestDispatcher::with_status(200)
+        .with_body(body)
+        .with_request_checker(move |request: &SignedRequest| {
+            assert_eq!(request.params.get("prefix"), Some(&sstr(prefix)));
+            assert_eq!(request.params.get("delimiter"), Some(&sstr("/")));
+            assert_eq!(
+                request.params.get("continuation-token"),
+                continuation_token.map(sstr).as_ref()
+            );
+        })
+}
+
+#[tokio::test]
+async fn walk_descends_into_common_prefixes() {
+    use crate::walk::{walk, WalkEntry};
+
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        list_objects_v2_page(
+            "",
+            None,
+            r#"<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                <Name>bucket</Name>
+                <Prefix></Prefix>
+                <IsTruncated>true</IsTruncated>
+                <NextContinuationToken>page2</NextContinuationToken>
+                <Contents><Key>a.txt</Key></Contents>
+                <CommonPrefixes><Prefix>photos/</Prefix></CommonPrefixes>
+            </ListBucketResult>"#,
+        ),
+        list_objects_v2_page(
+            "photos/",
+            None,
+            r#"<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                <Name>bucket</Name>
+                <Prefix>photos/</Prefix>
+                <IsTruncated>false</IsTruncated>
+                <CommonPrefixes><Prefix>photos/2020/</Prefix></CommonPrefixes>
+                <Contents><Key>photos/1.jpg</Key></Contents>
+            </ListBucketResult>"#,
+        ),
+        list_objects_v2_page(
+            "photos/2020/",
+            None,
+            r#"<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                <Name>bucket</Name>
+                <Prefix>photos/2020/</Prefix>
+                <IsTruncated>false</IsTruncated>
+            </ListBucketResult>"#,
+        ),
+        list_objects_v2_page(
+            "",
+            Some("page2"),
+            r#"<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                <Name>bucket</Name>
+                <Prefix></Prefix>
+                <IsTruncated>false</IsTruncated>
+                <Contents><Key>z.txt</Key></Contents>
+            </ListBucketResult>"#,
+        ),
+    ]);
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let entries: Vec<String> = walk(&client, "bucket", "", "/", true)
+        .map_ok(|entry| match entry {
+            WalkEntry::Object(object) => object.key.unwrap(),
+            WalkEntry::CommonPrefix(prefix) => prefix,
+        })
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        entries,
+        vec!["a.txt", "photos/", "photos/1.jpg", "photos/2020/", "z.txt"]
+    );
+}
+
+#[tokio::test]
+async fn walk_empty_bucket() {
+    use crate::walk::walk;
+
+    let mock = list_objects_v2_page(
+        "",
+        None,
+        r#"<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Name>bucket</Name>
+            <Prefix></Prefix>
+            <KeyCount>0</KeyCount>
+            <IsTruncated>false</IsTruncated>
+        </ListBucketResult>"#,
+    );
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let entries: Vec<_> = walk(&client, "bucket", "", "/", true)
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(entries.is_empty());
+}
+
+#[tokio::test]
+async fn list_objects_v2_pager_follows_continuation_tokens() {
+    use crate::paginate::ListObjectsV2Pager;
+
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        list_objects_v2_page(
+            "photos/",
+            None,
+            r#"<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                <Name>bucket</Name>
+                <Prefix>photos/</Prefix>
+                <IsTruncated>true</IsTruncated>
+                <NextContinuationToken>page2</NextContinuationToken>
+                <Contents><Key>photos/1.jpg</Key></Contents>
+            </ListBucketResult>"#,
+        ),
+        list_objects_v2_page(
+            "photos/",
+            Some("page2"),
+            r#"<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                <Name>bucket</Name>
+                <Prefix>photos/</Prefix>
+                <IsTruncated>false</IsTruncated>
+                <Contents><Key>photos/2.jpg</Key></Contents>
+            </ListBucketResult>"#,
+        ),
+    ]);
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let mut pager = ListObjectsV2Pager::new(ListObjectsV2Request {
+        bucket: "bucket".to_owned(),
+        delimiter: sstr("/"),
+        prefix: sstr("photos/"),
+        ..Default::default()
+    });
+    let keys = |page: ListObjectsV2Output| -> Vec<String> {
+        page.contents
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|object| object.key)
+            .collect()
+    };
+
+    let first = pager.next_page(&client).await.unwrap().unwrap();
+    assert_eq!(keys(first), vec!["photos/1.jpg"]);
+    assert_eq!(pager.continuation_token(), Some("page2"));
+    assert!(!pager.is_exhausted());
+
+    let second = pager.next_page(&client).await.unwrap().unwrap();
+    assert_eq!(keys(second), vec!["photos/2.jpg"]);
+    assert!(pager.is_exhausted());
+
+    // No further request is sent once the listing is exhausted.
+    assert_eq!(pager.next_page(&client).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn list_objects_v2_pager_retries_failed_pages() {
+    use crate::paginate::ListObjectsV2Pager;
+    use rusoto_core::{RetryMode, RetryPolicy};
+
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        list_objects_v2_page(
+            "photos/",
+            None,
+            r#"<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                <Name>bucket</Name>
+                <IsTruncated>true</IsTruncated>
+                <NextContinuationToken>page2</NextContinuationToken>
+                <Prefix>photos/</Prefix>
+                <Contents><Key>photos/1.jpg</Key></Contents>
+            </ListBucketResult>"#,
+        ),
+        MockRequestDispatcher::with_status(503).with_request_checker(|request: &SignedRequest| {
+            assert_eq!(
+                request.params.get("continuation-token"),
+                Some(&sstr("page2"))
+            );
+        }),
+        list_objects_v2_page(
+            "photos/",
+            Some("page2"),
+            r#"<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                <Name>bucket</Name>
+                <IsTruncated>false</IsTruncated>
+                <Prefix>photos/</Prefix>
+                <Contents><Key>photos/2.jpg</Key></Contents>
+            </ListBucketResult>"#,
+        ),
+    ]);
+    // Only the pager retries, not the client.
+    let client =
+        Client::new_with(MockCredentialsProvider, mock).with_retry_policy(RetryPolicy::never());
+    let client = S3Client::new_with_client(client, Region::UsEast1);
+
+    let mut pager = ListObjectsV2Pager::new(ListObjectsV2Request {
+        bucket: "bucket".to_owned(),
+        delimiter: sstr("/"),
+        prefix: sstr("photos/"),
+        ..Default::default()
+    })
+    .with_retry_policy(RetryPolicy::new(RetryMode::Standard));
+
+    let mut keys = Vec::new();
+    while let Some(page) = pager.next_page(&client).await.unwrap() {
+        keys.extend(
+            page.contents
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|object| object.key),
+        );
+    }
+    assert_eq!(keys, vec!["photos/1.jpg", "photos/2.jpg"]);
+}
+
+#[tokio::test]
+async fn list_object_versions_paginator_advances_both_markers() {
+    use crate::paginate::{list_object_versions_paginator, VersionEntry};
+
+    let page =
+        |file: &str, key_marker: Option<&'static str>, version_id_marker: Option<&'static str>| {
+            MockRequestDispatcher::with_status(200)
+                .with_body(&MockResponseReader::read_response(
+                    "test_resources/custom",
+                    file,
+                ))
+                .with_request_checker(move |request: &SignedRequest| {
+                    assert!(request.params.contains_key("versions"));
+                    assert_eq!(request.params.get("prefix"), Some(&sstr("photos/")));
+                    assert_eq!(
+                        request.params.get("key-marker"),
+                        key_marker.map(sstr).as_ref()
+                    );
+                    assert_eq!(
+                        request.params.get("version-id-marker"),
+                        version_id_marker.map(sstr).as_ref()
+                    );
+                })
+        };
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        page("s3_list_object_versions_page1.xml", None, None),
+        page(
+            "s3_list_object_versions_page2.xml",
+            Some("photos/b.jpg"),
+            Some("3sL4kqtJlcpXroDTDmJ.rmSpXd3dIbrHY"),
+        ),
+    ]);
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let request = ListObjectVersionsRequest {
+        bucket: "bucket".to_owned(),
+        prefix: sstr("photos/"),
+        ..Default::default()
+    };
+    let entries: Vec<VersionEntry> = list_object_versions_paginator(&client, request)
+        .try_collect()
+        .await
+        .unwrap();
+    let listed: Vec<(&str, &str, bool)> = entries
+        .iter()
+        .map(|entry| {
+            let is_marker = matches!(entry, VersionEntry::DeleteMarker(_));
+            (entry.key().unwrap(), entry.version_id().unwrap(), is_marker)
+        })
+        .collect();
+    assert_eq!(
+        listed,
+        vec![
+            ("photos/a.jpg", "Ev3aJH8kqtFSv7PlUOH2dJg2yXyrRbMu", true),
+            ("photos/a.jpg", "null", false),
+            ("photos/b.jpg", "3sL4kqtJlcpXroDTDmJ.rmSpXd3dIbrHY", false),
+            ("photos/b.jpg", "QUpfdndhfd8438MNFDN93jdnJFkdmqnh", false),
+            ("photos/c.jpg", "Wh0aJH8kqtFSv7PlUOH2dJg2yXyrRbMx", true),
+            ("photos/c.jpg", "UIORUnfndfiufdisojhr398493jfdkjF", false),
+        ]
+    );
+    match entries[5] {
+        VersionEntry::Version(ref version) => {
+            assert_eq!(version.storage_class.as_deref(), Some("GLACIER"));
+            assert_eq!(version.size, Some(2048));
+        }
+        ref entry => panic!("expected a version, got {:?}", entry),
+    }
+}
+
+#[tokio::test]
+async fn should_sign_with_rotated_credentials() {
+    use rusoto_core::credential::SharedCredentialsProvider;
+
+    let signed_with = |access_key: &'static str| {
+        MockRequestDispatcher::with_status(200).with_request_checker(
+            move |request: &SignedRequest| {
+                let authorization =
+                    String::from_utf8(request.headers()["authorization"][0].clone()).unwrap();
+                assert!(
+                    authorization.contains(&format!("Credential={}/", access_key)),
+                    "{}",
+                    authorization
+                );
+            },
+        )
+    };
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        signed_with("old_access_key"),
+        signed_with("new_access_key"),
+    ]);
+    let provider = SharedCredentialsProvider::new(AwsCredentials::new(
+        "old_access_key",
+        "old_secret_key",
+        None,
+        None,
+    ));
+    let client = S3Client::new_with(mock, provider.clone(), Region::UsEast1);
+    let request = HeadBucketRequest {
+        bucket: "bucket".to_owned(),
+        ..Default::default()
+    };
+
+    client.head_bucket(request.clone()).await.unwrap();
+    provider.set(AwsCredentials::new(
+        "new_access_key",
+        "new_secret_key",
+        None,
+        None,
+    ));
+    client.head_bucket(request).await.unwrap();
+}
+
+fn delete_objects_request(quiet: Option<bool>) -> DeleteObjectsRequest {
+    DeleteObjectsRequest {
+        bucket: "bucket".to_owned(),
+        delete: Delete {
+            objects: vec![
+                ObjectIdentifier {
+                    key: "a.txt".to_owned(),
+                    version_id: None,
+                },
+                ObjectIdentifier {
+                    key: "b.txt".to_owned(),
+                    version_id: None,
+                },
+            ],
+            quiet,
+        },
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn should_parse_quiet_delete_objects_response() {
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <DeleteResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/"/>"#,
+        )
+        .with_request_checker(|request: &SignedRequest| {
+            let body = match request.payload {
+                Some(SignedRequestPayload::Buffer(ref body)) => body.clone(),
+                _ => panic!("expected a buffered body"),
+            };
+            let body = String::from_utf8(body.to_vec()).unwrap();
+            assert!(body.contains("<Quiet>true</Quiet>"), "{}", body);
+        });
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let result = client
+        .delete_objects(delete_objects_request(Some(true)))
+        .await
+        .unwrap();
+    assert_eq!(result.deleted, None);
+    assert_eq!(result.errors, None);
+}
+
+#[tokio::test]
+async fn should_parse_quiet_delete_objects_response_with_errors() {
+    let mock = MockRequestDispatcher::with_status(200).with_body(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+        <DeleteResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Error>
+                <Key>b.txt</Key>
+                <Code>AccessDenied</Code>
+                <Message>Access Denied</Message>
+            </Error>
+        </DeleteResult>"#,
+    );
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let result = client
+        .delete_objects(delete_objects_request(Some(true)))
+        .await
+        .unwrap();
+    assert_eq!(result.deleted, None);
+    assert_eq!(
+        result.errors,
+        Some(vec![S3Error {
+            key: sstr("b.txt"),
+            code: sstr("AccessDenied"),
+            message: sstr("Access Denied"),
+            version_id: None,
+        }])
+    );
+}
+
+#[tokio::test]
+async fn should_parse_verbose_delete_objects_response() {
+    let mock = MockRequestDispatcher::with_status(200).with_body(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+        <DeleteResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Deleted>
+                <Key>a.txt</Key>
+            </Deleted>
+            <Error>
+                <Key>b.txt</Key>
+                <Code>AccessDenied</Code>
+                <Message>Access Denied</Message>
+            </Error>
+        </DeleteResult>"#,
+    );
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let result = client
+        .delete_objects(delete_objects_request(None))
+        .await
+        .unwrap();
+    let deleted: Vec<_> = result
+        .deleted
+        .unwrap()
+        .into_iter()
+        .map(|deleted| deleted.key.unwrap())
+        .collect();
+    assert_eq!(deleted, vec!["a.txt"]);
+    assert_eq!(result.errors.unwrap().len(), 1);
+}
+
+fn tag(key: &str, value: &str) -> Tag {
+    Tag {
+        key: key.to_owned(),
+        value: value.to_owned(),
+    }
+}
+
+#[tokio::test]
+async fn set_tags_for_keys_reports_each_key() {
+    use crate::tagging::set_tags_for_keys;
+
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        MockRequestDispatcher::with_status(200).with_request_checker(|request: &SignedRequest| {
+            assert_eq!(request.method, "PUT");
+            assert_eq!(request.path, "/a");
+            assert!(request.params.contains_key("tagging"));
+        }),
+        MockRequestDispatcher::with_status(404).with_body(
+            r#"<Error><Code>NoSuchKey</Code><Message>The specified key does not exist.</Message></Error>"#,
+        ),
+        MockRequestDispatcher::with_status(200)
+            .with_header("x-amz-version-id", "v3")
+            .with_request_checker(|request: &SignedRequest| {
+                assert_eq!(request.path, "/c");
+            }),
+    ]);
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let tags = vec![tag("lifecycle", "archive")];
+    let results = set_tags_for_keys(&client, "bucket", vec!["a", "b", "c"], &tags, 2)
+        .await
+        .unwrap();
+    let keys: Vec<&str> = results.iter().map(|(key, _)| key.as_str()).collect();
+    assert_eq!(keys, vec!["a", "b", "c"]);
+    assert!(results[0].1.is_ok());
+    match results[1].1 {
+        Err(RusotoError::Unknown(ref response)) => assert_eq!(response.status, 404),
+        ref other => panic!("unexpected result {:?}", other),
+    }
+    assert_eq!(results[2].1.as_ref().unwrap().version_id, sstr("v3"));
+}
+
+#[tokio::test]
+async fn set_tags_for_keys_validates_tags() {
+    use crate::tagging::set_tags_for_keys;
+
+    let client = S3Client::new_with(
+        MockRequestDispatcher::with_status(200)
+            .with_request_checker(|_: &SignedRequest| panic!("no request should be sent")),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+
+    let too_many: Vec<Tag> = (0..11).map(|i| tag(&i.to_string(), "")).collect();
+    let long_key = vec![tag(&"k".repeat(129), "v")];
+    let long_value = vec![tag("k", &"v".repeat(257))];
+    let duplicate = vec![tag("k", "1"), tag("k", "2")];
+    for tags in &[too_many, long_key, long_value, duplicate] {
+        match set_tags_for_keys(&client, "bucket", vec!["a"], tags, 1).await {
+            Err(RusotoError::Validation(_)) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+}
+
+#[tokio::test]
+async fn get_tags_for_keys_reports_each_key() {
+    use crate::tagging::get_tags_for_keys;
+
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        MockRequestDispatcher::with_status(200).with_body(
+            r#"<Tagging><TagSet><Tag><Key>lifecycle</Key><Value>archive</Value></Tag></TagSet></Tagging>"#,
+        ),
+        MockRequestDispatcher::with_status(403).with_body(
+            r#"<Error><Code>AccessDenied</Code><Message>Access Denied</Message></Error>"#,
+        ),
+    ]);
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let results = get_tags_for_keys(&client, "bucket", vec!["a", "b"], 4).await;
+    assert_eq!(results[0].0, "a");
+    assert_eq!(
+        results[0].1.as_ref().unwrap(),
+        &vec![tag("lifecycle", "archive")]
+    );
+    assert_eq!(results[1].0, "b");
+    assert!(results[1].1.is_err());
+}
+
+fn upload_part_mock(part_number: &'static str, e_tag: &'static str) -> MockRequestDispatcher {
+    MockRequestDispatcher::with_status(200)
+        .with_header("ETag", e_tag)
+        .with_request_checker(move |request: &SignedRequest| {
+            assert_eq!(request.method, "PUT");
+            assert_eq!(
+                request.params.get("partNumber"),
+                Some(&Some(part_number.to_owned()))
+            );
+            assert_eq!(
+                request.params.get("uploadId"),
+                Some(&Some("upload-1".to_owned()))
+            );
+        })
+}
+
+fn complete_multipart_upload_mock() -> MockRequestDispatcher {
+    MockRequestDispatcher::with_status(200).with_body(
+        r#"<CompleteMultipartUploadResult>
+            <Bucket>bucket</Bucket><Key>key</Key><ETag>"abc-3"</ETag>
+        </CompleteMultipartUploadResult>"#,
+    )
+}
+
+#[tokio::test]
+async fn multipart_uploader_reports_uploaded_parts() {
+    use crate::multipart::{MultipartUploader, UploadedPart};
+
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        MockRequestDispatcher::with_status(200).with_body(
+            r#"<InitiateMultipartUploadResult>
+                <Bucket>bucket</Bucket><Key>key</Key><UploadId>upload-1</UploadId>
+            </InitiateMultipartUploadResult>"#,
+        ),
+        upload_part_mock("1", "\"e1\""),
+        upload_part_mock("2", "\"e2\""),
+        upload_part_mock("3", "\"e3\""),
+        complete_multipart_upload_mock(),
+    ]);
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let mut uploader = MultipartUploader::new(CreateMultipartUploadRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    })
+    .with_part_size(5);
+    let output = uploader
+        .upload(&client, "hello world!".to_owned().into_bytes().into())
+        .await
+        .unwrap();
+    assert_eq!(output.e_tag, Some("\"abc-3\"".to_owned()));
+    assert_eq!(uploader.upload_id(), Some("upload-1"));
+    let part = |part_number, e_tag: &str, size| UploadedPart {
+        part_number,
+        e_tag: e_tag.to_owned(),
+        size,
+    };
+    assert_eq!(
+        uploader.parts(),
+        &[
+            part(1, "\"e1\"", 5),
+            part(2, "\"e2\"", 5),
+            part(3, "\"e3\"", 2)
+        ][..]
+    );
+}
+
+#[tokio::test]
+async fn multipart_uploader_resumes_with_listed_parts() {
+    use crate::multipart::MultipartUploader;
+
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        MockRequestDispatcher::with_status(200)
+            .with_body(
+                r#"<ListPartsResult>
+                    <Bucket>bucket</Bucket><Key>key</Key><UploadId>upload-1</UploadId>
+                    <IsTruncated>false</IsTruncated>
+                    <Part><PartNumber>1</PartNumber><ETag>"5d41402abc4b2a76b9719d911017c592"</ETag><Size>5</Size></Part>
+                    <Part><PartNumber>2</PartNumber><ETag>"stale"</ETag><Size>5</Size></Part>
+                </ListPartsResult>"#,
+            )
+            .with_request_checker(|request: &SignedRequest| {
+                assert_eq!(request.method, "GET");
+                assert_eq!(
+                    request.params.get("uploadId"),
+                    Some(&Some("upload-1".to_owned()))
+                );
+            }),
+        upload_part_mock("2", "\"e2\""),
+        upload_part_mock("3", "\"e3\""),
+        complete_multipart_upload_mock(),
+    ]);
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let mut uploader = MultipartUploader::new(CreateMultipartUploadRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    })
+    .with_part_size(5)
+    .with_upload_id("upload-1");
+    uploader
+        .upload(&client, "hello world!".to_owned().into_bytes().into())
+        .await
+        .unwrap();
+    let e_tags: Vec<&str> = uploader
+        .parts()
+        .iter()
+        .map(|part| part.e_tag.as_str())
+        .collect();
+    assert_eq!(
+        e_tags,
+        vec!["\"5d41402abc4b2a76b9719d911017c592\"", "\"e2\"", "\"e3\""]
+    );
+}
+
+#[tokio::test]
+async fn put_object_uploads_large_bodies_in_parts() {
+    use rusoto_core::ByteStream;
+
+    let create_multipart_upload_mock = || {
+        MockRequestDispatcher::with_status(200)
+            .with_body(
+                r#"<InitiateMultipartUploadResult>
+                    <Bucket>bucket</Bucket><Key>key</Key><UploadId>upload-1</UploadId>
+                </InitiateMultipartUploadResult>"#,
+            )
+            .with_request_checker(|request: &SignedRequest| {
+                assert_eq!(request.method, "POST");
+                assert!(request.params.contains_key("uploads"));
+                assert_eq!(
+                    request.headers.get("content-type"),
+                    Some(&vec![b"text/plain".to_vec()])
+                );
+                assert!(!request.headers.contains_key("content-md5"));
+            })
+    };
+    let single_put_mock = |content_length: &'static str| {
+        MockRequestDispatcher::with_status(200)
+            .with_header("ETag", "\"5eb63bbbe01eeed093cb22bb8f5acdc3\"")
+            .with_request_checker(move |request: &SignedRequest| {
+                assert_eq!(request.method, "PUT");
+                assert!(!request.params.contains_key("uploadId"));
+                assert_eq!(
+                    request.headers.get("content-length"),
+                    Some(&vec![content_length.as_bytes().to_vec()])
+                );
+            })
+    };
+    let request = |body: ByteStream| PutObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        content_type: sstr("text/plain"),
+        content_md5: sstr("/D/5joxqDTCH1RXARz+Gdw=="),
+        body: Some(body),
+        ..Default::default()
+    };
+    let unsized_body = |chunks: Vec<&'static str>| {
+        ByteStream::new(futures::stream::iter(
+            chunks
+                .into_iter()
+                .map(|chunk| Ok(bytes::Bytes::from_static(chunk.as_bytes()))),
+        ))
+    };
+
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        // A body of known size above the threshold
+        create_multipart_upload_mock(),
+        upload_part_mock("1", "\"e1\""),
+        complete_multipart_upload_mock(),
+        // A body of unknown size that turns out to be above the threshold
+        create_multipart_upload_mock(),
+        upload_part_mock("1", "\"e1\"").with_request_checker(|request: &SignedRequest| {
+            assert_eq!(
+                request.headers.get("content-length"),
+                Some(&vec![b"12".to_vec()])
+            );
+        }),
+        complete_multipart_upload_mock(),
+        // Bodies of known and unknown size up to the threshold
+        single_put_mock("5"),
+        single_put_mock("5"),
+    ]);
+    let mut client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    assert_eq!(client.config().multipart_threshold, None);
+    client.config_mut().multipart_threshold = Some(5);
+
+    let output = client
+        .put_object(request(b"hello world!".to_vec().into()))
+        .await
+        .unwrap();
+    assert_eq!(output.e_tag, sstr("\"abc-3\""));
+
+    let output = client
+        .put_object(request(unsized_body(vec!["hel", "lo wor", "ld!"])))
+        .await
+        .unwrap();
+    assert_eq!(output.e_tag, sstr("\"abc-3\""));
+
+    let output = client
+        .put_object(request(b"hello".to_vec().into()))
+        .await
+        .unwrap();
+    assert_eq!(output.e_tag, sstr("\"5eb63bbbe01eeed093cb22bb8f5acdc3\""));
+    client
+        .put_object(request(unsized_body(vec!["hel", "lo"])))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn put_object_signs_content_encoding() {
+    let mock =
+        MockRequestDispatcher::with_status(200).with_request_checker(|request: &SignedRequest| {
+            assert_eq!(
+                request.headers()["content-encoding"],
+                vec![b"gzip".to_vec()]
+            );
+            let authorization =
+                String::from_utf8(request.headers()["authorization"][0].clone()).unwrap();
+            assert!(authorization.contains("SignedHeaders=content-encoding;"));
+        });
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    client
+        .put_object(PutObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key.json.gz".to_owned(),
+            body: Some(b"\x1f\x8b".to_vec().into()),
+            content_encoding: Some("gzip".to_owned()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn slow_down_is_reported_as_throttling() {
+    use crate::error::slow_down;
+    use rusoto_core::{Client, RetryPolicy};
+
+    let mock = MockRequestDispatcher::with_status(503)
+        .with_header("Retry-After", "2")
+        .with_body(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error><Code>SlowDown</Code><Message>Please reduce your request rate.</Message><RequestId>4442587FB7D0A2F9</RequestId></Error>"#,
+        );
+    let client =
+        Client::new_with(MockCredentialsProvider, mock).with_retry_policy(RetryPolicy::never());
+    let client = S3Client::new_with_client(client, Region::UsEast1);
+
+    let err = client
+        .put_object(PutObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    let throttling = slow_down(&err).expect("SlowDown should be reported as throttling");
+    assert_eq!(
+        throttling.message.as_deref(),
+        Some("Please reduce your request rate.")
+    );
+    assert_eq!(
+        throttling.retry_after,
+        Some(std::time::Duration::from_secs(2))
+    );
+
+    let not_found = MockRequestDispatcher::with_status(404).with_body(
+        r#"<Error><Code>NoSuchKey</Code><Message>The specified key does not exist.</Message></Error>"#,
+    );
+    let client = S3Client::new_with(not_found, MockCredentialsProvider, Region::UsEast1);
+    let err = client
+        .get_object(GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(slow_down(&err), None);
+}
+
+/// Answers the requests of a multipart upload, except that parts are never uploaded,
+/// recording the method of each request.
+#[derive(Default)]
+struct StalledUpload {
+    methods: Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+impl rusoto_core::DispatchSignedRequest for StalledUpload {
+    fn dispatch(
+        &self,
+        request: SignedRequest,
+        timeout: Option<std::time::Duration>,
+    ) -> rusoto_core::request::DispatchSignedRequestFuture {
+        use futures::FutureExt;
+
+        self.methods.lock().unwrap().push(request.method.clone());
+        if request.params.contains_key("partNumber") {
+            return futures::future::pending().boxed();
+        }
+        let mock = if request.params.contains_key("uploads") {
+            MockRequestDispatcher::with_status(200).with_body(
+                r#"<InitiateMultipartUploadResult><UploadId>upload-1</UploadId></InitiateMultipartUploadResult>"#,
+            )
+        } else {
+            MockRequestDispatcher::with_status(204).with_request_checker(
+                |request: &SignedRequest| {
+                    assert_eq!(
+                        request.params.get("uploadId"),
+                        Some(&Some("upload-1".to_owned()))
+                    );
+                },
+            )
+        };
+        mock.dispatch(request, timeout)
+    }
+}
+
+#[tokio::test]
+async fn put_object_aborts_upload_when_dropped() {
+    use std::time::Duration;
+
+    let server = StalledUpload::default();
+    let methods = server.methods.clone();
+    let mut client = S3Client::new_with(server, MockCredentialsProvider, Region::UsEast1);
+    client.config_mut().multipart_threshold = Some(5);
+    let put = client.put_object(PutObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        body: Some(b"hello world!".to_vec().into()),
+        ..Default::default()
+    });
+    assert!(tokio::time::timeout(Duration::from_millis(50), put)
+        .await
+        .is_err());
+
+    for _ in 0..100 {
+        if methods.lock().unwrap().contains(&"DELETE".to_owned()) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(*methods.lock().unwrap(), ["POST", "PUT", "DELETE"]);
+}
+
+#[tokio::test]
+async fn multipart_uploader_aborts_cancelled_uploads() {
+    use crate::multipart::{CancellationToken, MultipartUploadError, MultipartUploader};
+
+    let server = StalledUpload::default();
+    let methods = server.methods.clone();
+    let client = S3Client::new_with(server, MockCredentialsProvider, Region::UsEast1);
+    let cancellation = CancellationToken::new();
+    let mut uploader = MultipartUploader::new(CreateMultipartUploadRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    })
+    .with_part_size(5)
+    .with_cancellation(cancellation.clone());
+    let cancel = cancellation.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        cancel.cancel();
+    });
+    let result = uploader
+        .upload(&client, "hello world!".to_owned().into_bytes().into())
+        .await;
+    assert!(matches!(result, Err(MultipartUploadError::Cancelled)));
+    assert_eq!(uploader.upload_id(), None);
+    let sent = std::mem::take(&mut *methods.lock().unwrap());
+    assert_eq!(sent.first().map(String::as_str), Some("POST"));
+    assert_eq!(sent.last().map(String::as_str), Some("DELETE"));
+
+    // Nothing is sent once the token is cancelled.
+    let result = uploader
+        .upload(&client, "hello world!".to_owned().into_bytes().into())
+        .await;
+    assert!(matches!(result, Err(MultipartUploadError::Cancelled)));
+    assert!(methods.lock().unwrap().is_empty());
+}
+
+/// Answers the requests of a multipart upload, keeping track of the most parts, and
+/// bytes of parts, being uploaded at the same time.
+#[derive(Default)]
+struct PartUploadTracker {
+    in_flight: std::sync::Arc<std::sync::Mutex<(usize, usize)>>,
+    max_in_flight: std::sync::Arc<std::sync::Mutex<(usize, usize)>>,
+}
+
+impl rusoto_core::DispatchSignedRequest for PartUploadTracker {
+    fn dispatch(
+        &self,
+        request: SignedRequest,
+        timeout: Option<std::time::Duration>,
+    ) -> rusoto_core::request::DispatchSignedRequestFuture {
+        use futures::FutureExt;
+
+        let part_size = match request.params.get("partNumber") {
+            Some(_) => request.content_length(),
+            None => None,
+        };
+        let mock = if request.params.contains_key("uploads") {
+            MockRequestDispatcher::with_status(200).with_body(
+                r#"<InitiateMultipartUploadResult><UploadId>upload-1</UploadId></InitiateMultipartUploadResult>"#,
+            )
+        } else if part_size.is_some() {
+            MockRequestDispatcher::with_status(200).with_header("ETag", "\"etag\"")
+        } else {
+            complete_multipart_upload_mock()
+        };
+        let response = mock.dispatch(request, timeout);
+        let in_flight = self.in_flight.clone();
+        let max_in_flight = self.max_in_flight.clone();
+        async move {
+            if let Some(size) = part_size {
+                {
+                    let mut in_flight = in_flight.lock().unwrap();
+                    in_flight.0 += 1;
+                    in_flight.1 += size;
+                    let mut max = max_in_flight.lock().unwrap();
+                    max.0 = max.0.max(in_flight.0);
+                    max.1 = max.1.max(in_flight.1);
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                let mut in_flight = in_flight.lock().unwrap();
+                in_flight.0 -= 1;
+                in_flight.1 -= size;
+            }
+            response.await
+        }
+        .boxed()
+    }
+}
+
+#[tokio::test]
+async fn multipart_uploader_limits_parts_in_flight() {
+    use crate::multipart::MultipartUploader;
+
+    let upload = |concurrency, max_buffered_bytes: Option<usize>| async move {
+        let tracker = PartUploadTracker::default();
+        let max_in_flight = tracker.max_in_flight.clone();
+        let client = S3Client::new_with(tracker, MockCredentialsProvider, Region::UsEast1);
+        let mut uploader = MultipartUploader::new(CreateMultipartUploadRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        })
+        .with_part_size(10)
+        .with_concurrency(concurrency);
+        if let Some(max_buffered_bytes) = max_buffered_bytes {
+            uploader = uploader.with_max_buffered_bytes(max_buffered_bytes);
+        }
+        uploader
+            .upload(&client, vec![b'a'; 95].into())
+            .await
+            .unwrap();
+        assert_eq!(uploader.parts().len(), 10);
+        let part_numbers: Vec<i64> = uploader
+            .parts()
+            .iter()
+            .map(|part| part.part_number)
+            .collect();
+        assert_eq!(part_numbers, (1..=10).collect::<Vec<_>>());
+        let max = *max_in_flight.lock().unwrap();
+        max
+    };
+
+    assert_eq!(upload(1, None).await, (1, 10));
+    assert_eq!(upload(4, None).await, (4, 40));
+    // The budget allows fewer parts than the concurrency.
+    assert_eq!(upload(4, Some(25)).await, (2, 20));
+    // At least one part is always uploaded.
+    assert_eq!(upload(4, Some(5)).await, (1, 10));
+}
+
+/// Answers the requests of `copy_large_object` for a source of `size` bytes, failing the
+/// copy of part `failing_part`, and records them.
+struct CopyTracker {
+    size: i64,
+    failing_part: Option<i64>,
+    /// Never answers the copies of parts.
+    stall_parts: bool,
+    requests: Arc<std::sync::Mutex<Vec<RecordedRequest>>>,
+}
+
+/// The parts of a `SignedRequest` the `copy_large_object` tests look at.
+struct RecordedRequest {
+    method: String,
+    path: String,
+    params: rusoto_core::param::Params,
+    headers: std::collections::BTreeMap<String, Vec<Vec<u8>>>,
+    body: Option<String>,
+}
+
+impl CopyTracker {
+    fn new(size: i64, failing_part: Option<i64>) -> Self {
+        CopyTracker {
+            size,
+            failing_part,
+            stall_parts: false,
+            requests: Default::default(),
+        }
+    }
+}
+
+impl rusoto_core::DispatchSignedRequest for CopyTracker {
+    fn dispatch(
+        &self,
+        request: SignedRequest,
+        timeout: Option<std::time::Duration>,
+    ) -> rusoto_core::request::DispatchSignedRequestFuture {
+        use futures::FutureExt;
+
+        let part_number = request
+            .params
+            .get("partNumber")
+            .and_then(|part_number| part_number.as_ref())
+            .map(|part_number| part_number.parse::<i64>().unwrap());
+        let stalled = self.stall_parts && part_number.is_some();
+        let mock = match (request.method.as_str(), part_number) {
+            ("HEAD", _) => MockRequestDispatcher::with_status(200)
+                .with_header("Content-Length", &self.size.to_string())
+                .with_header("Content-Type", "application/x-tar")
+                .with_header("ETag", "\"source-etag\"")
+                .with_header("x-amz-meta-origin", "backup"),
+            ("PUT", Some(part_number)) if Some(part_number) == self.failing_part => {
+                MockRequestDispatcher::with_status(412)
+            }
+            ("PUT", Some(part_number)) => MockRequestDispatcher::with_status(200).with_body(
+                &format!(
+                    "<CopyPartResult><ETag>\"etag-{}\"</ETag></CopyPartResult>",
+                    part_number
+                ),
+            ),
+            ("PUT", None) => MockRequestDispatcher::with_status(200)
+                .with_header("x-amz-request-charged", "requester")
+                .with_body(r#"<CopyObjectResult><ETag>"copy-etag"</ETag></CopyObjectResult>"#),
+            ("POST", _) if request.params.contains_key("uploads") => {
+                MockRequestDispatcher::with_status(200).with_body(
+                    r#"<InitiateMultipartUploadResult><UploadId>upload-1</UploadId></InitiateMultipartUploadResult>"#,
+                )
+            }
+            ("POST", _) => complete_multipart_upload_mock(),
+            _ => MockRequestDispatcher::with_status(204),
+        };
+        let body = match request.payload {
+            Some(SignedRequestPayload::Buffer(ref body)) => {
+                Some(String::from_utf8(body.to_vec()).unwrap())
+            }
+            _ => None,
+        };
+        self.requests.lock().unwrap().push(RecordedRequest {
+            method: request.method.clone(),
+            path: request.path.clone(),
+            params: request.params.clone(),
+            headers: request.headers.clone(),
+            body,
+        });
+        if stalled {
+            return futures::future::pending().boxed();
+        }
+        mock.dispatch(request, timeout)
+    }
+}
+
+fn header<'a>(request: &'a RecordedRequest, name: &str) -> Option<&'a str> {
+    request
+        .headers
+        .get(name)
+        .map(|values| std::str::from_utf8(&values[0]).unwrap())
+}
+
+#[tokio::test]
+async fn copy_large_object_uses_copy_object_up_to_5_gib() {
+    use crate::multipart::{copy_large_object, CopySource, MAX_COPY_OBJECT_SIZE};
+
+    let tracker = CopyTracker::new(MAX_COPY_OBJECT_SIZE, None);
+    let requests = tracker.requests.clone();
+    let client = S3Client::new_with(tracker, MockCredentialsProvider, Region::UsEast1);
+    let source = CopySource {
+        version_id: Some("v1".to_owned()),
+        ..CopySource::new("source-bucket", "dir/kéy.tar")
+    };
+    let output = copy_large_object(
+        &client,
+        &source,
+        CopyObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        },
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(output.e_tag.as_deref(), Some("\"copy-etag\""));
+    assert_eq!(output.parts, None);
+    assert_eq!(output.request_charged.as_deref(), Some("requester"));
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method, "HEAD");
+    assert_eq!(requests[0].path, "/dir/kéy.tar");
+    assert_eq!(
+        requests[0].params.get("versionId"),
+        Some(&Some("v1".to_owned()))
+    );
+    assert_eq!(requests[1].method, "PUT");
+    assert_eq!(
+        header(&requests[1], "x-amz-copy-source"),
+        Some("source-bucket/dir/k%C3%A9y.tar?versionId=v1")
+    );
+}
+
+#[tokio::test]
+async fn copy_large_object_copies_larger_objects_in_parts() {
+    use crate::multipart::{copy_large_object, CopySource, MAX_COPY_OBJECT_SIZE};
+
+    let tracker = CopyTracker::new(MAX_COPY_OBJECT_SIZE + 1, None);
+    let requests = tracker.requests.clone();
+    let client = S3Client::new_with(tracker, MockCredentialsProvider, Region::UsEast1);
+    let output = copy_large_object(
+        &client,
+        &CopySource::new("source-bucket", "backup.tar"),
+        CopyObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            storage_class: Some("GLACIER".to_owned()),
+            ..Default::default()
+        },
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(output.e_tag.as_deref(), Some("\"abc-3\""));
+    // 10 parts of 512 MiB and one of a single byte.
+    assert_eq!(output.parts, Some(11));
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 14);
+    let create = &requests[1];
+    assert!(create.params.contains_key("uploads"));
+    assert_eq!(header(create, "content-type"), Some("application/x-tar"));
+    assert_eq!(header(create, "x-amz-meta-origin"), Some("backup"));
+    assert_eq!(header(create, "x-amz-storage-class"), Some("GLACIER"));
+
+    let mut ranges: Vec<(String, String)> = requests[2..13]
+        .iter()
+        .map(|request| {
+            assert_eq!(
+                header(request, "x-amz-copy-source"),
+                Some("source-bucket/backup.tar")
+            );
+            assert_eq!(
+                header(request, "x-amz-copy-source-if-match"),
+                Some("\"source-etag\"")
+            );
+            (
+                request.params["partNumber"].clone().unwrap(),
+                header(request, "x-amz-copy-source-range")
+                    .unwrap()
+                    .to_owned(),
+            )
+        })
+        .collect();
+    ranges.sort_by_key(|(part_number, _)| part_number.parse::<i64>().unwrap());
+    assert_eq!(ranges[0], ("1".to_owned(), "bytes=0-536870911".to_owned()));
+    assert_eq!(
+        ranges[9],
+        ("10".to_owned(), "bytes=4831838208-5368709119".to_owned())
+    );
+    assert_eq!(
+        ranges[10],
+        ("11".to_owned(), "bytes=5368709120-5368709120".to_owned())
+    );
+
+    let complete = &requests[13];
+    assert_eq!(complete.method, "POST");
+    let part_numbers: Vec<&str> = complete
+        .body
+        .as_ref()
+        .unwrap()
+        .split("<PartNumber>")
+        .skip(1)
+        .map(|part| part.split('<').next().unwrap())
+        .collect();
+    assert_eq!(
+        part_numbers,
+        (1..=11).map(|n| n.to_string()).collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn copy_large_object_aborts_failed_multipart_copies() {
+    use crate::multipart::{copy_large_object, CopyLargeObjectError, CopySource};
+
+    let tracker = CopyTracker::new(5 * 1024 * 1024 * 1024 * 1024, Some(2));
+    let requests = tracker.requests.clone();
+    let client = S3Client::new_with(tracker, MockCredentialsProvider, Region::UsEast1);
+    let result = copy_large_object(
+        &client,
+        &CopySource::new("source-bucket", "backup.tar"),
+        CopyObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        },
+        None,
+    )
+    .await;
+    assert!(matches!(
+        result,
+        Err(CopyLargeObjectError::UploadPartCopy(_))
+    ));
+
+    let requests = requests.lock().unwrap();
+    let abort = requests.last().unwrap();
+    assert_eq!(abort.method, "DELETE");
+    assert_eq!(
+        abort.params.get("uploadId"),
+        Some(&Some("upload-1".to_owned()))
+    );
+    // 5 TiB is more than 10,000 parts of 512 MiB, so the parts are larger.
+    let first_range = requests
+        .iter()
+        .find(|request| request.params.get("partNumber") == Some(&Some("1".to_owned())))
+        .and_then(|request| header(request, "x-amz-copy-source-range"));
+    assert_eq!(first_range, Some("bytes=0-549755813"));
+}
+
+#[tokio::test]
+async fn copy_large_object_aborts_cancelled_multipart_copies() {
+    use crate::multipart::{
+        copy_large_object, CancellationToken, CopyLargeObjectError, CopySource,
+        MAX_COPY_OBJECT_SIZE,
+    };
+
+    let tracker = CopyTracker {
+        stall_parts: true,
+        ..CopyTracker::new(MAX_COPY_OBJECT_SIZE + 1, None)
+    };
+    let requests = tracker.requests.clone();
+    let client = S3Client::new_with(tracker, MockCredentialsProvider, Region::UsEast1);
+    let cancellation = CancellationToken::new();
+    let cancel = cancellation.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        cancel.cancel();
+    });
+    let result = copy_large_object(
+        &client,
+        &CopySource::new("source-bucket", "backup.tar"),
+        CopyObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        },
+        Some(&cancellation),
+    )
+    .await;
+    assert!(matches!(result, Err(CopyLargeObjectError::Cancelled)));
+
+    let requests = requests.lock().unwrap();
+    let abort = requests.last().unwrap();
+    assert_eq!(abort.method, "DELETE");
+    assert_eq!(
+        abort.params.get("uploadId"),
+        Some(&Some("upload-1".to_owned()))
+    );
+}
+
+/// Serves an object uploaded in `parts`, or as a single part if there's only one, by
+/// part number, recording the requests `download_parts` sends.
+struct PartsServer {
+    parts: Vec<&'static str>,
+    /// The part number whose body is served uppercased, i.e. corrupted.
+    corrupt_part: Option<usize>,
+    requests: Arc<std::sync::Mutex<Vec<RecordedRequest>>>,
+}
+
+impl PartsServer {
+    fn new(parts: Vec<&'static str>) -> PartsServer {
+        PartsServer {
+            parts,
+            corrupt_part: None,
+            requests: Default::default(),
+        }
+    }
+
+    /// The ETag of the object, computed from its parts as S3 does.
+    fn e_tag(&self) -> String {
+        use md5::{Digest, Md5};
+
+        let part_md5s: Vec<[u8; 16]> = self
+            .parts
+            .iter()
+            .map(|part| Md5::digest(part.as_bytes()).into())
+            .collect();
+        format!("\"{}\"", compute_multipart_etag(&part_md5s))
+    }
+}
+
+impl rusoto_core::DispatchSignedRequest for PartsServer {
+    fn dispatch(
+        &self,
+        request: SignedRequest,
+        timeout: Option<std::time::Duration>,
+    ) -> rusoto_core::request::DispatchSignedRequestFuture {
+        let part_number = request
+            .params
+            .get("partNumber")
+            .and_then(|part_number| part_number.as_ref())
+            .map(|part_number| part_number.parse::<usize>().unwrap())
+            .unwrap();
+        let part = self.parts[part_number - 1];
+        let start: usize = self.parts[..part_number - 1].iter().map(|p| p.len()).sum();
+        let size: usize = self.parts.iter().map(|p| p.len()).sum();
+        let mut mock = MockRequestDispatcher::with_status(200)
+            .with_header("ETag", &self.e_tag())
+            .with_header("x-amz-version-id", "v1");
+        if self.parts.len() > 1 {
+            mock = mock
+                .with_header("x-amz-mp-parts-count", &self.parts.len().to_string())
+                .with_header(
+                    "Content-Range",
+                    &format!("bytes {}-{}/{}", start, start + part.len() - 1, size),
+                );
+        }
+        mock = match request.method.as_str() {
+            "HEAD" => mock.with_header("Content-Length", &part.len().to_string()),
+            _ if self.corrupt_part == Some(part_number) => mock
+                .with_header("x-amz-request-charged", "requester")
+                .with_body(&part.to_uppercase()),
+            _ => mock
+                .with_header("x-amz-request-charged", "requester")
+                .with_body(part),
+        };
+        self.requests.lock().unwrap().push(RecordedRequest {
+            method: request.method.clone(),
+            path: request.path.clone(),
+            params: request.params.clone(),
+            headers: request.headers.clone(),
+            body: None,
+        });
+        mock.dispatch(request, timeout)
+    }
+}
+
+async fn download_all(parts: Vec<&'static str>) -> (Vec<u8>, Vec<RecordedRequest>) {
+    use crate::multipart::download_parts;
+
+    let server = PartsServer::new(parts.clone());
+    let e_tag = server.e_tag();
+    let requests = server.requests.clone();
+    let client = S3Client::new_with(server, MockCredentialsProvider, Region::UsEast1);
+    let request = GetObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        range: sstr("bytes=0-1"),
+        ..Default::default()
+    };
+    let mut contents = Vec::new();
+    let output = download_parts(&client, request, None, |offset, part| {
+        let start = offset as usize;
+        if contents.len() < start + part.len() {
+            contents.resize(start + part.len(), 0);
+        }
+        contents[start..start + part.len()].copy_from_slice(&part);
+        Ok(())
+    })
+    .await
+    .unwrap();
+    assert_eq!(output.parts, parts.len() as i64);
+    assert_eq!(output.size, contents.len() as u64);
+    assert_eq!(output.e_tag, Some(e_tag));
+    assert_eq!(output.version_id, sstr("v1"));
+    assert_eq!(output.request_charged, sstr("requester"));
+
+    let requests = std::mem::take(&mut *requests.lock().unwrap());
+    (contents, requests)
+}
+
+#[tokio::test]
+async fn download_parts_fetches_every_part() {
+    let (contents, requests) = download_all(vec!["first part, ", "second part, ", "last"]).await;
+    assert_eq!(contents, b"first part, second part, last");
+
+    assert_eq!(requests.len(), 4);
+    assert_eq!(requests[0].method, "HEAD");
+    assert_eq!(
+        requests[0].params.get("partNumber"),
+        Some(&Some("1".to_owned()))
+    );
+    let mut part_numbers: Vec<String> = requests[1..]
+        .iter()
+        .map(|request| {
+            assert_eq!(request.method, "GET");
+            assert_eq!(
+                header(request, "if-match"),
+                Some("\"cf61fe220f6416c2c9d45438811fa7a8-3\"")
+            );
+            assert_eq!(header(request, "range"), None);
+            assert_eq!(
+                request.params.get("versionId"),
+                Some(&Some("v1".to_owned()))
+            );
+            request.params["partNumber"].clone().unwrap()
+        })
+        .collect();
+    part_numbers.sort();
+    assert_eq!(part_numbers, ["1", "2", "3"]);
+}
+
+#[tokio::test]
+async fn download_parts_fetches_single_part_objects_whole() {
+    let (contents, requests) = download_all(vec!["uploaded in one go"]).await;
+    assert_eq!(contents, b"uploaded in one go");
+    assert_eq!(requests.len(), 2);
+}
+
+#[tokio::test]
+async fn download_parts_stops_when_cancelled() {
+    use crate::multipart::{download_parts, CancellationToken, DownloadPartsError};
+
+    let server = PartsServer::new(vec!["first part, ", "last"]);
+    let requests = server.requests.clone();
+    let client = S3Client::new_with(server, MockCredentialsProvider, Region::UsEast1);
+    let cancellation = CancellationToken::new();
+    cancellation.cancel();
+    let request = GetObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    };
+    let result = download_parts(&client, request, Some(&cancellation), |_, _| {
+        panic!("no part should be downloaded")
+    })
+    .await;
+    assert!(matches!(result, Err(DownloadPartsError::Cancelled)));
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn download_parts_rejects_corrupted_parts() {
+    use crate::multipart::{download_parts, DownloadPartsError};
+
+    let mut server = PartsServer::new(vec!["first part, ", "second part, ", "last"]);
+    server.corrupt_part = Some(2);
+    let e_tag = server.e_tag();
+    let client = S3Client::new_with(server, MockCredentialsProvider, Region::UsEast1);
+    let request = GetObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    };
+    match download_parts(&client, request, None, |_, _| Ok(())).await {
+        Err(DownloadPartsError::ETagMismatch {
+            e_tag: expected,
+            computed,
+        }) => {
+            assert_eq!(expected, e_tag);
+            assert_ne!(format!("\"{}\"", computed), e_tag);
+            assert!(computed.ends_with("-3"), "{}", computed);
+        }
+        result => panic!("expected an ETag mismatch, got {:?}", result.map(|_| ())),
+    }
 }
@@ -0,0 +1,88 @@
+use rusoto_core::proto::json::Error as JsonError;
+use rusoto_core::proto::xml::error::{XmlError, XmlErrorDeserializer};
+use rusoto_core::proto::xml::util::{find_start_element, XmlResponse};
+use rusoto_core::request::BufferedHttpResponse;
+use rusoto_core::RusotoError;
+use std::time::Duration;
+use xml::EventReader;
+
+/// Parses an S3 error response body into an `XmlError`.
+///
+/// S3 errors are almost always XML, but some newer surfaces (e.g. S3 Object Lambda)
+/// return JSON bodies instead. The `Content-Type` header decides which parser is used.
+pub(crate) fn parse_error_response(res: &BufferedHttpResponse) -> Option<XmlError> {
+    let is_json = res
+        .headers
+        .get("content-type")
+        .map(|content_type| {
+            content_type.starts_with("application/json")
+                || content_type.starts_with("application/x-amz-json")
+        })
+        .unwrap_or(false);
+
+    if is_json {
+        return JsonError::parse_rest(res).map(|err| XmlError {
+            code: err.typ,
+            message: err.msg,
+            ..Default::default()
+        });
+    }
+
+    let reader = EventReader::new(res.body.as_ref());
+    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+    find_start_element(&mut stack);
+    XmlErrorDeserializer::deserialize("Error", &mut stack).ok()
+}
+
+/// S3 asking to reduce the request rate, usually because too many requests are sent to
+/// the same prefix. See `slow_down`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlowDown {
+    /// The message S3 sent along, if the response had a body.
+    pub message: Option<String>,
+    /// How long to wait before sending more requests, if S3 sent a `Retry-After` header
+    /// in seconds.
+    pub retry_after: Option<Duration>,
+}
+
+/// Returns the details of `err` if it is S3 throttling requests: a `SlowDown` error, or
+/// any response with status 503, e.g. to a `HEAD` request, which has no body to tell.
+///
+/// S3 reports these as `RusotoError::Unknown`. Clients already retry them with backoff,
+/// as throttling errors, according to their `RetryPolicy`, so this only sees them once
+/// the last attempt failed too.
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_s3::error::slow_down;
+/// use rusoto_s3::{PutObjectRequest, S3Client, S3};
+///
+/// # async fn example(request: PutObjectRequest) {
+/// let client = S3Client::new(Region::UsEast1);
+/// if let Err(err) = client.put_object(request).await {
+///     if let Some(throttling) = slow_down(&err) {
+///         println!("slowing down for {:?}", throttling.retry_after);
+///     }
+/// }
+/// # }
+/// ```
+pub fn slow_down<E>(err: &RusotoError<E>) -> Option<SlowDown> {
+    let response = match err {
+        RusotoError::Unknown(response) => response,
+        _ => return None,
+    };
+    let parsed = parse_error_response(response);
+    let is_slow_down = matches!(parsed, Some(ref error) if error.code == "SlowDown");
+    if !is_slow_down && response.status.as_u16() != 503 {
+        return None;
+    }
+    let retry_after = response
+        .headers
+        .get("retry-after")
+        .and_then(|value| value.trim().parse().ok())
+        .map(Duration::from_secs);
+    Some(SlowDown {
+        message: parsed.map(|error| error.message),
+        retry_after,
+    })
+}
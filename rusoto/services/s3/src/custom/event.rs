@@ -0,0 +1,189 @@
+//! Requires the `event` feature.
+//!
+//! S3 sends event notifications to SQS, SNS and Lambda as JSON documents with a list of
+//! records, one per event. The test event S3 sends when notifications are configured
+//! has no records, so it parses as an empty `S3Event`.
+//!
+//! ```rust
+//! use rusoto_s3::event::parse_event;
+//!
+//! let json = r#"{"Records": [{
+//!     "eventVersion": "2.1",
+//!     "eventSource": "aws:s3",
+//!     "awsRegion": "us-east-1",
+//!     "eventTime": "2021-03-01T12:34:56.789Z",
+//!     "eventName": "ObjectCreated:Put",
+//!     "s3": {
+//!         "bucket": {"name": "my-bucket", "arn": "arn:aws:s3:::my-bucket"},
+//!         "object": {"key": "summer+photos/beach.jpg", "size": 1024, "eTag": "abc"}
+//!     }
+//! }]}"#;
+//! let event = parse_event(json).unwrap();
+//! let record = &event.records[0];
+//! assert_eq!(record.event_name, "ObjectCreated:Put");
+//! assert_eq!(record.s3.bucket.name, "my-bucket");
+//! assert_eq!(record.s3.object.key, "summer photos/beach.jpg");
+//! ```
+
+use rusoto_core::signature;
+use serde::de::{Deserialize, Deserializer};
+use serde_derive::Deserialize;
+
+/// An S3 event notification, as delivered in the body of an SQS message or to a Lambda
+/// function.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct S3Event {
+    #[serde(rename = "Records", default)]
+    pub records: Vec<EventRecord>,
+}
+
+/// A single event, e.g. an object being created or removed.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventRecord {
+    #[serde(default)]
+    pub event_version: String,
+    #[serde(default)]
+    pub event_source: String,
+    #[serde(default)]
+    pub aws_region: String,
+    /// When the request that caused the event finished, in ISO 8601 format.
+    pub event_time: String,
+    /// The type of the event without the `s3:` prefix, e.g. `ObjectCreated:Put` or
+    /// `ObjectRemoved:Delete`.
+    pub event_name: String,
+    /// Who made the request that caused the event.
+    pub user_identity: Option<UserIdentity>,
+    pub s3: EventEntity,
+}
+
+/// The bucket and object an event is about.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventEntity {
+    #[serde(rename = "s3SchemaVersion", default)]
+    pub schema_version: String,
+    /// The ID of the notification configuration that matched the event.
+    #[serde(default)]
+    pub configuration_id: String,
+    pub bucket: EventBucket,
+    pub object: EventObject,
+}
+
+/// The bucket of an event.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventBucket {
+    pub name: String,
+    #[serde(default)]
+    pub arn: String,
+    pub owner_identity: Option<UserIdentity>,
+}
+
+/// The object of an event.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventObject {
+    /// The key of the object, decoded from the URL encoding S3 uses in notifications, so
+    /// it can be used as is in requests.
+    #[serde(deserialize_with = "deserialize_key")]
+    pub key: String,
+    /// The size of the object in bytes, not included for removals.
+    pub size: Option<i64>,
+    /// The ETag of the object, unquoted. Not included for removals.
+    pub e_tag: Option<String>,
+    /// The version of the object, in versioned buckets.
+    pub version_id: Option<String>,
+    /// Orders the events of the same key: compared as strings after padding the shorter
+    /// with leading zeros, a greater sequencer is a later event.
+    pub sequencer: Option<String>,
+}
+
+/// The identity of an AWS account or user.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserIdentity {
+    pub principal_id: String,
+}
+
+/// Parses the JSON of an S3 event notification.
+pub fn parse_event(json: &str) -> Result<S3Event, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+/// Keys are form-encoded in notifications: spaces become `+`, and `+` itself `%2B`.
+fn deserialize_key<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let key = String::deserialize(deserializer)?;
+    Ok(signature::decode_uri(&key.replace('+', " ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_object_created_put() {
+        let event = parse_event(include_str!(
+            "../../test_resources/custom/s3_event_object_created_put.json"
+        ))
+        .unwrap();
+        assert_eq!(event.records.len(), 1);
+
+        let record = &event.records[0];
+        assert_eq!(record.event_source, "aws:s3");
+        assert_eq!(record.aws_region, "us-west-2");
+        assert_eq!(record.event_name, "ObjectCreated:Put");
+        assert_eq!(record.event_time, "2021-03-01T12:34:56.789Z");
+        assert_eq!(
+            record.user_identity.as_ref().unwrap().principal_id,
+            "AWS:AIDAJDPLRKLG7UEXAMPLE"
+        );
+        assert_eq!(record.s3.configuration_id, "uploads");
+        assert_eq!(record.s3.bucket.name, "my-bucket");
+        assert_eq!(record.s3.bucket.arn, "arn:aws:s3:::my-bucket");
+
+        let object = &record.s3.object;
+        assert_eq!(object.key, "photos/summer 2020/beach+suné.jpg");
+        assert_eq!(object.size, Some(1024));
+        assert_eq!(
+            object.e_tag.as_deref(),
+            Some("d41d8cd98f00b204e9800998ecf8427e")
+        );
+        assert_eq!(
+            object.version_id.as_deref(),
+            Some("096fKKXTRTtl3on89fVO.nfljtsv6qko")
+        );
+        assert_eq!(object.sequencer.as_deref(), Some("0055AED6DCD90281E5"));
+    }
+
+    #[test]
+    fn parse_object_removed_delete() {
+        let event = parse_event(include_str!(
+            "../../test_resources/custom/s3_event_object_removed_delete.json"
+        ))
+        .unwrap();
+
+        let record = &event.records[0];
+        assert_eq!(record.event_name, "ObjectRemoved:Delete");
+        assert_eq!(record.s3.object.key, "reports/2021-03-01.csv");
+        assert_eq!(record.s3.object.size, None);
+        assert_eq!(record.s3.object.e_tag, None);
+    }
+
+    #[test]
+    fn parse_test_event() {
+        let json = r#"{
+            "Service": "Amazon S3",
+            "Event": "s3:TestEvent",
+            "Time": "2021-03-01T12:00:00.000Z",
+            "Bucket": "my-bucket",
+            "RequestId": "5582815E1AEA5ADF",
+            "HostId": "8cLeGAmw098X5cv4Zkwcmo8vvZa3eH3eKxsPzbB9wrR+YstdA6Knx4Ip8EXAMPLE"
+        }"#;
+        assert_eq!(parse_event(json).unwrap(), S3Event::default());
+        assert!(parse_event("{\"Records\": [{}]}").is_err());
+    }
+}
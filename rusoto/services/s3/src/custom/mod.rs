@@ -1,5 +1,36 @@
 /// Utility helpers for working with S3
 pub mod util;
 
+/// Uploading and copying large objects in parts
+pub mod multipart;
+
+/// Fetching list results one page at a time
+pub mod paginate;
+
+/// Byte ranges of objects
+pub mod range;
+
+/// Storage classes of objects
+pub mod storage_class;
+
+/// Reading and writing the tags of many objects at once
+pub mod tagging;
+
+/// Client-side checks of request parameters
+pub mod validation;
+
+/// Walking the keys of a bucket like a directory tree
+pub mod walk;
+
+/// Inspecting errors returned by S3
+pub mod error;
+
+/// Transforming object bodies on upload and download
+pub mod transform;
+
+/// Parsing S3 event notifications
+#[cfg(feature = "event")]
+pub mod event;
+
 #[cfg(test)]
 mod custom_tests;
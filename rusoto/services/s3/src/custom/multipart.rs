@@ -0,0 +1,1320 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::io;
+
+use bytes::{Bytes, BytesMut};
+use futures::stream::{self, FuturesUnordered};
+use futures::{StreamExt, TryStreamExt};
+use log::warn;
+use md5::{Digest, Md5};
+use rusoto_core::request::HttpDispatchError;
+use rusoto_core::signature::md5_digest;
+use rusoto_core::{ByteStream, RusotoError};
+pub use tokio_util::sync::CancellationToken;
+
+use crate::generated::{
+    AbortMultipartUploadError, AbortMultipartUploadRequest, CompleteMultipartUploadError,
+    CompleteMultipartUploadOutput, CompleteMultipartUploadRequest, CompletedMultipartUpload,
+    CompletedPart, CopyObjectError, CopyObjectRequest, CreateMultipartUploadError,
+    CreateMultipartUploadRequest, GetObjectError, GetObjectRequest, HeadObjectError,
+    HeadObjectOutput, HeadObjectRequest, ListPartsError, ListPartsRequest, Part, PutObjectError,
+    PutObjectOutput, PutObjectRequest, S3Client, UploadPartCopyError, UploadPartCopyRequest,
+    UploadPartError, UploadPartRequest, S3,
+};
+use crate::range::ByteRange;
+use crate::util::{compute_multipart_etag, encode_key};
+
+/// The size of the parts `MultipartUploader` splits bodies into by default, 8 MiB.
+pub const DEFAULT_PART_SIZE: usize = 8 * 1024 * 1024;
+
+/// The number of parts `MultipartUploader` uploads at the same time by default.
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// The largest object `CopyObject` can copy, 5 GiB. `copy_large_object` copies larger
+/// objects in parts.
+pub const MAX_COPY_OBJECT_SIZE: i64 = 5 * 1024 * 1024 * 1024;
+
+/// The size of the parts `copy_large_object` copies in, 512 MiB, unless the object is
+/// too large to be copied in 10,000 parts of that size.
+pub const DEFAULT_COPY_PART_SIZE: i64 = 512 * 1024 * 1024;
+
+/// The most parts a multipart upload can have.
+const MAX_PARTS: i64 = 10_000;
+
+/// A part of a multipart upload that S3 has stored.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UploadedPart {
+    /// The number of the part, starting at 1.
+    pub part_number: i64,
+    /// The ETag S3 returned for the part, including its quotes.
+    pub e_tag: String,
+    /// The size of the part in bytes.
+    pub size: i64,
+}
+
+/// Uploads a body in parts: creates a multipart upload, uploads the parts of the body,
+/// several at a time, and completes the upload.
+///
+/// The upload ID and the parts uploaded so far stay available through `upload_id` and
+/// `parts`, also after an error, so they can be persisted. An interrupted upload is
+/// resumed by passing its ID to `with_upload_id` and the same body to `upload` again:
+/// the parts S3 already has are listed with `ListParts`, and those with the same size
+/// and MD5 digest as the corresponding part of the body are not uploaded again.
+///
+/// The upload is not aborted on errors, so that it can be resumed. Call `abort` to
+/// discard it, or S3 keeps charging for the stored parts. To stop an upload early,
+/// cancel the token passed to `with_cancellation`, which aborts it.
+///
+/// ```rust,no_run
+/// use rusoto_core::{ByteStream, Region};
+/// use rusoto_s3::multipart::MultipartUploader;
+/// use rusoto_s3::{CreateMultipartUploadRequest, S3Client};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = S3Client::new(Region::UsEast1);
+/// let mut uploader = MultipartUploader::new(CreateMultipartUploadRequest {
+///     bucket: "my-bucket".to_owned(),
+///     key: "backup.tar".to_owned(),
+///     ..Default::default()
+/// });
+/// let body = ByteStream::from(vec![0; 20 * 1024 * 1024]);
+/// if let Err(err) = uploader.upload(&client, body).await {
+///     // persist `uploader.upload_id()` to resume later
+///     return Err(err.into());
+/// }
+/// for part in uploader.parts() {
+///     println!("part {}: {} bytes, ETag {}", part.part_number, part.size, part.e_tag);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct MultipartUploader {
+    request: CreateMultipartUploadRequest,
+    part_size: usize,
+    concurrency: usize,
+    max_buffered_bytes: Option<usize>,
+    cancellation: Option<CancellationToken>,
+    upload_id: Option<String>,
+    parts: Vec<UploadedPart>,
+}
+
+impl MultipartUploader {
+    /// Creates an uploader for the object described by `request`, which is sent as is
+    /// to create the upload. Its SSE-C key, request payer and expected bucket owner are
+    /// also sent with every part.
+    pub fn new(request: CreateMultipartUploadRequest) -> Self {
+        MultipartUploader {
+            request,
+            part_size: DEFAULT_PART_SIZE,
+            concurrency: DEFAULT_CONCURRENCY,
+            max_buffered_bytes: None,
+            cancellation: None,
+            upload_id: None,
+            parts: Vec::new(),
+        }
+    }
+
+    /// Sets the size of the parts the body is split into, `DEFAULT_PART_SIZE` by default.
+    ///
+    /// S3 rejects parts smaller than 5 MiB, except for the last one, and uploads of more
+    /// than 10,000 parts, so the part size limits the size of the object: the default of
+    /// 8 MiB allows objects of up to 78 GiB, and objects of 5 TiB, the most S3 stores,
+    /// need parts of at least 525 MiB. Larger parts also take more memory, see
+    /// `with_max_buffered_bytes`.
+    pub fn with_part_size(mut self, part_size: usize) -> Self {
+        self.part_size = part_size.max(1);
+        self
+    }
+
+    /// Sets how many parts are uploaded at the same time, `DEFAULT_CONCURRENCY` by
+    /// default. Values below 1 are treated as 1.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Limits the memory taken by parts read from the body but not uploaded yet, which
+    /// is `concurrency` times the part size by default.
+    ///
+    /// Fewer parts are uploaded at the same time if `concurrency` of them don't fit. One
+    /// part is always read, even if it is larger than the budget. Chunks of the body that
+    /// straddle parts may take up to one chunk more.
+    pub fn with_max_buffered_bytes(mut self, max_buffered_bytes: usize) -> Self {
+        self.max_buffered_bytes = Some(max_buffered_bytes);
+        self
+    }
+
+    /// Stops the upload when `cancellation` is cancelled: `upload` stops uploading parts,
+    /// aborts the upload, also if it was resumed, and returns
+    /// `MultipartUploadError::Cancelled`. If the abort fails, `upload_id` still returns
+    /// the ID of the upload, so that it can be aborted later.
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = Some(cancellation);
+        self
+    }
+
+    /// Continues the existing upload `upload_id` instead of creating a new one.
+    pub fn with_upload_id<S: Into<String>>(mut self, upload_id: S) -> Self {
+        self.upload_id = Some(upload_id.into());
+        self
+    }
+
+    /// The ID of the upload, once it has been created.
+    pub fn upload_id(&self) -> Option<&str> {
+        self.upload_id.as_deref()
+    }
+
+    /// The parts of the body S3 has stored so far, in order.
+    pub fn parts(&self) -> &[UploadedPart] {
+        &self.parts
+    }
+
+    /// Uploads `body` and completes the upload.
+    pub async fn upload<C>(
+        &mut self,
+        client: &C,
+        body: ByteStream,
+    ) -> Result<CompleteMultipartUploadOutput, MultipartUploadError>
+    where
+        C: S3 + ?Sized,
+    {
+        let cancellation = self.cancellation.clone();
+        if cancellation
+            .as_ref()
+            .is_some_and(|token| token.is_cancelled())
+        {
+            return Err(MultipartUploadError::Cancelled);
+        }
+        self.parts.clear();
+        let stored = match self.upload_id {
+            Some(ref upload_id) => self.list_parts(client, upload_id).await?,
+            None => {
+                let output = client
+                    .create_multipart_upload(self.request.clone())
+                    .await
+                    .map_err(MultipartUploadError::CreateMultipartUpload)?;
+                let upload_id = output.upload_id.ok_or_else(|| {
+                    MultipartUploadError::CreateMultipartUpload(RusotoError::ParseError(
+                        "CreateMultipartUpload response has no UploadId".to_owned(),
+                    ))
+                })?;
+                self.upload_id = Some(upload_id);
+                BTreeMap::new()
+            }
+        };
+
+        let upload = self.upload_parts_and_complete(client, body, stored);
+        match unless_cancelled(cancellation.as_ref(), upload).await {
+            Some(result) => result,
+            None => {
+                // The upload ID is kept if this fails, for the caller to try again.
+                let _ = self.abort(client).await;
+                Err(MultipartUploadError::Cancelled)
+            }
+        }
+    }
+
+    async fn upload_parts_and_complete<C>(
+        &mut self,
+        client: &C,
+        body: ByteStream,
+        stored: BTreeMap<i64, Part>,
+    ) -> Result<CompleteMultipartUploadOutput, MultipartUploadError>
+    where
+        C: S3 + ?Sized,
+    {
+        let mut uploaded = Vec::new();
+        let result = self.upload_parts(client, body, stored, &mut uploaded).await;
+        uploaded.sort_by_key(|part| part.part_number);
+        self.parts = uploaded;
+        result?;
+
+        self.complete(client).await
+    }
+
+    /// Aborts the upload, deleting the parts S3 has stored. Does nothing if the upload
+    /// hasn't been created yet.
+    pub async fn abort<C>(
+        &mut self,
+        client: &C,
+    ) -> Result<(), RusotoError<AbortMultipartUploadError>>
+    where
+        C: S3 + ?Sized,
+    {
+        let upload_id = match self.upload_id.take() {
+            Some(upload_id) => upload_id,
+            None => return Ok(()),
+        };
+        let request = AbortMultipartUploadRequest {
+            bucket: self.request.bucket.clone(),
+            key: self.request.key.clone(),
+            expected_bucket_owner: self.request.expected_bucket_owner.clone(),
+            request_payer: self.request.request_payer.clone(),
+            upload_id: upload_id.clone(),
+        };
+        if let Err(err) = client.abort_multipart_upload(request).await {
+            self.upload_id = Some(upload_id);
+            return Err(err);
+        }
+        self.parts.clear();
+        Ok(())
+    }
+
+    /// Splits `body` into parts and uploads them, adding them to `uploaded` as they
+    /// complete. Parts are only read from the body while fewer than
+    /// `max_parts_in_flight` are being uploaded.
+    async fn upload_parts<C>(
+        &self,
+        client: &C,
+        mut body: ByteStream,
+        mut stored: BTreeMap<i64, Part>,
+        uploaded: &mut Vec<UploadedPart>,
+    ) -> Result<(), MultipartUploadError>
+    where
+        C: S3 + ?Sized,
+    {
+        let max_in_flight = self.max_parts_in_flight();
+        let mut in_flight = FuturesUnordered::new();
+        let mut buffer = BytesMut::new();
+        let mut body_done = false;
+        let mut parts_done = false;
+        let mut part_number = 1;
+        loop {
+            while !parts_done && in_flight.len() < max_in_flight {
+                while buffer.len() < self.part_size && !body_done {
+                    match body.next().await {
+                        Some(chunk) => {
+                            buffer.extend_from_slice(&chunk.map_err(MultipartUploadError::Io)?)
+                        }
+                        None => body_done = true,
+                    }
+                }
+                // An empty body is uploaded as a single empty part.
+                if buffer.is_empty() && body_done && part_number > 1 {
+                    parts_done = true;
+                    break;
+                }
+                let part = buffer.split_to(buffer.len().min(self.part_size)).freeze();
+                in_flight.push(self.upload_part(
+                    client,
+                    part_number,
+                    part,
+                    stored.remove(&part_number),
+                ));
+                part_number += 1;
+            }
+            match in_flight.next().await {
+                Some(part) => uploaded.push(part?),
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// The number of parts held in memory at once: at most `concurrency`, and as many
+    /// as fit into `max_buffered_bytes`, but at least one.
+    fn max_parts_in_flight(&self) -> usize {
+        let within_budget = match self.max_buffered_bytes {
+            Some(max_buffered_bytes) => (max_buffered_bytes / self.part_size).max(1),
+            None => usize::MAX,
+        };
+        self.concurrency.min(within_budget)
+    }
+
+    /// Lists the parts S3 has stored for the upload, by part number.
+    async fn list_parts<C>(
+        &self,
+        client: &C,
+        upload_id: &str,
+    ) -> Result<BTreeMap<i64, Part>, MultipartUploadError>
+    where
+        C: S3 + ?Sized,
+    {
+        let mut parts = BTreeMap::new();
+        let mut request = ListPartsRequest {
+            bucket: self.request.bucket.clone(),
+            key: self.request.key.clone(),
+            expected_bucket_owner: self.request.expected_bucket_owner.clone(),
+            request_payer: self.request.request_payer.clone(),
+            upload_id: upload_id.to_owned(),
+            ..Default::default()
+        };
+        loop {
+            let output = client
+                .list_parts(request.clone())
+                .await
+                .map_err(MultipartUploadError::ListParts)?;
+            for part in output.parts.unwrap_or_default() {
+                if let Some(part_number) = part.part_number {
+                    parts.insert(part_number, part);
+                }
+            }
+            match output.next_part_number_marker {
+                Some(marker) if output.is_truncated.unwrap_or(false) => {
+                    request.part_number_marker = Some(marker);
+                }
+                _ => return Ok(parts),
+            }
+        }
+    }
+
+    /// Uploads `part`, unless `stored` is the same part already.
+    async fn upload_part<C>(
+        &self,
+        client: &C,
+        part_number: i64,
+        part: Bytes,
+        stored: Option<Part>,
+    ) -> Result<UploadedPart, MultipartUploadError>
+    where
+        C: S3 + ?Sized,
+    {
+        let size = part.len() as i64;
+        if let Some(Part {
+            e_tag: Some(e_tag),
+            size: Some(stored_size),
+            ..
+        }) = stored
+        {
+            // The ETag of a part is its MD5 digest, unless it's encrypted with SSE-KMS,
+            // in which case the part is uploaded again.
+            let md5 = md5_digest(&part).map_err(MultipartUploadError::Io)?;
+            let md5: String = md5.iter().map(|byte| format!("{:02x}", byte)).collect();
+            if stored_size == size && e_tag.trim_matches('"') == md5 {
+                return Ok(UploadedPart {
+                    part_number,
+                    e_tag,
+                    size,
+                });
+            }
+        }
+
+        let request = UploadPartRequest {
+            bucket: self.request.bucket.clone(),
+            key: self.request.key.clone(),
+            expected_bucket_owner: self.request.expected_bucket_owner.clone(),
+            request_payer: self.request.request_payer.clone(),
+            sse_customer_algorithm: self.request.sse_customer_algorithm.clone(),
+            sse_customer_key: self.request.sse_customer_key.clone(),
+            sse_customer_key_md5: self.request.sse_customer_key_md5.clone(),
+            upload_id: self.upload_id.clone().unwrap_or_default(),
+            part_number,
+            content_length: Some(size),
+            body: Some(ByteStream::new_with_size(
+                stream::once(async move { Ok(part) }),
+                size as usize,
+            )),
+            ..Default::default()
+        };
+        let output = client
+            .upload_part(request)
+            .await
+            .map_err(MultipartUploadError::UploadPart)?;
+        let e_tag = output.e_tag.ok_or_else(|| {
+            MultipartUploadError::UploadPart(RusotoError::ParseError(
+                "UploadPart response has no ETag".to_owned(),
+            ))
+        })?;
+        Ok(UploadedPart {
+            part_number,
+            e_tag,
+            size,
+        })
+    }
+
+    async fn complete<C>(
+        &self,
+        client: &C,
+    ) -> Result<CompleteMultipartUploadOutput, MultipartUploadError>
+    where
+        C: S3 + ?Sized,
+    {
+        let parts = self
+            .parts
+            .iter()
+            .map(|part| CompletedPart {
+                e_tag: Some(part.e_tag.clone()),
+                part_number: Some(part.part_number),
+            })
+            .collect();
+        let request = CompleteMultipartUploadRequest {
+            bucket: self.request.bucket.clone(),
+            key: self.request.key.clone(),
+            expected_bucket_owner: self.request.expected_bucket_owner.clone(),
+            request_payer: self.request.request_payer.clone(),
+            upload_id: self.upload_id.clone().unwrap_or_default(),
+            multipart_upload: Some(CompletedMultipartUpload { parts: Some(parts) }),
+        };
+        client
+            .complete_multipart_upload(request)
+            .await
+            .map_err(MultipartUploadError::CompleteMultipartUpload)
+    }
+}
+
+/// An error that occurred during a multipart upload, by the step that failed.
+#[derive(Debug)]
+pub enum MultipartUploadError {
+    /// The upload could not be created.
+    CreateMultipartUpload(RusotoError<CreateMultipartUploadError>),
+    /// The parts of a resumed upload could not be listed.
+    ListParts(RusotoError<ListPartsError>),
+    /// A part could not be uploaded.
+    UploadPart(RusotoError<UploadPartError>),
+    /// The upload could not be completed.
+    CompleteMultipartUpload(RusotoError<CompleteMultipartUploadError>),
+    /// The body could not be read, or a part could not be hashed.
+    Io(io::Error),
+    /// The upload was cancelled through the token passed to `with_cancellation`.
+    Cancelled,
+}
+
+impl Error for MultipartUploadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MultipartUploadError::CreateMultipartUpload(err) => Some(err),
+            MultipartUploadError::ListParts(err) => Some(err),
+            MultipartUploadError::UploadPart(err) => Some(err),
+            MultipartUploadError::CompleteMultipartUpload(err) => Some(err),
+            MultipartUploadError::Io(err) => Some(err),
+            MultipartUploadError::Cancelled => None,
+        }
+    }
+}
+
+impl fmt::Display for MultipartUploadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MultipartUploadError::CreateMultipartUpload(err) => {
+                write!(f, "Failed to create the multipart upload: {}", err)
+            }
+            MultipartUploadError::ListParts(err) => {
+                write!(f, "Failed to list the uploaded parts: {}", err)
+            }
+            MultipartUploadError::UploadPart(err) => write!(f, "Failed to upload a part: {}", err),
+            MultipartUploadError::CompleteMultipartUpload(err) => {
+                write!(f, "Failed to complete the multipart upload: {}", err)
+            }
+            MultipartUploadError::Io(err) => write!(f, "Failed to read the body: {}", err),
+            MultipartUploadError::Cancelled => write!(f, "The multipart upload was cancelled"),
+        }
+    }
+}
+
+/// The object `copy_large_object` copies.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CopySource {
+    pub bucket: String,
+    pub key: String,
+    /// The version to copy, instead of the current one.
+    pub version_id: Option<String>,
+}
+
+impl CopySource {
+    /// The current version of `key` in `bucket`.
+    pub fn new<B, K>(bucket: B, key: K) -> Self
+    where
+        B: Into<String>,
+        K: Into<String>,
+    {
+        CopySource {
+            bucket: bucket.into(),
+            key: key.into(),
+            version_id: None,
+        }
+    }
+
+    /// The value of `copy_source` in `CopyObject` and `UploadPartCopy` requests, with the
+    /// key URL encoded.
+    pub fn to_copy_source(&self) -> String {
+        let copy_source = encode_key(format!("{}/{}", self.bucket, self.key));
+        match self.version_id {
+            Some(ref version_id) => format!("{}?versionId={}", copy_source, encode_key(version_id)),
+            None => copy_source,
+        }
+    }
+}
+
+/// The object `copy_large_object` created.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CopyLargeObjectOutput {
+    /// The ETag of the copy, including its quotes.
+    pub e_tag: Option<String>,
+    /// The version of the copy, in versioned buckets.
+    pub version_id: Option<String>,
+    /// The number of parts the object was copied in, `None` if it was copied with a
+    /// single `CopyObject`.
+    pub parts: Option<i64>,
+    /// `requester` if the requester was charged for the copy, for requester pays buckets.
+    pub request_charged: Option<String>,
+}
+
+/// Copies an object of any size within S3, without downloading it.
+///
+/// The source is looked up with `HeadObject` first. Objects of up to
+/// `MAX_COPY_OBJECT_SIZE` are copied with `destination` as a single `CopyObject`, larger
+/// ones with a multipart upload whose parts are copied with ranged `UploadPartCopy`
+/// requests, several at a time. The `copy_source` of `destination` is ignored; it is
+/// set from `source`.
+///
+/// For multipart copies, the system metadata and user metadata of the source are
+/// carried over unless `metadata_directive` is `REPLACE`, like `CopyObject` does, but
+/// tags are not: set `tagging` to tag the copy. The parts are copied on the condition
+/// that the source still has the ETag it had when it was looked up, unless
+/// `copy_source_if_match` is set, and the upload is aborted if any step fails, or when
+/// `cancellation` is cancelled.
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_s3::multipart::{copy_large_object, CopySource};
+/// use rusoto_s3::{CopyObjectRequest, S3Client};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = S3Client::new(Region::UsEast1);
+/// let source = CopySource::new("my-bucket", "backups/2021-03-01.tar");
+/// let output = copy_large_object(
+///     &client,
+///     &source,
+///     CopyObjectRequest {
+///         bucket: "archive-bucket".to_owned(),
+///         key: "2021-03-01.tar".to_owned(),
+///         ..Default::default()
+///     },
+///     None,
+/// )
+/// .await?;
+/// println!("copied, ETag {:?}", output.e_tag);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn copy_large_object<C>(
+    client: &C,
+    source: &CopySource,
+    mut destination: CopyObjectRequest,
+    cancellation: Option<&CancellationToken>,
+) -> Result<CopyLargeObjectOutput, CopyLargeObjectError>
+where
+    C: S3 + ?Sized,
+{
+    let head = client.head_object(HeadObjectRequest {
+        bucket: source.bucket.clone(),
+        key: source.key.clone(),
+        version_id: source.version_id.clone(),
+        expected_bucket_owner: destination.expected_source_bucket_owner.clone(),
+        if_match: destination.copy_source_if_match.clone(),
+        if_modified_since: destination.copy_source_if_modified_since.clone(),
+        if_none_match: destination.copy_source_if_none_match.clone(),
+        if_unmodified_since: destination.copy_source_if_unmodified_since.clone(),
+        request_payer: destination.request_payer.clone(),
+        sse_customer_algorithm: destination.copy_source_sse_customer_algorithm.clone(),
+        sse_customer_key: destination.copy_source_sse_customer_key.clone(),
+        sse_customer_key_md5: destination.copy_source_sse_customer_key_md5.clone(),
+        ..Default::default()
+    });
+    let head = unless_cancelled(cancellation, head)
+        .await
+        .ok_or(CopyLargeObjectError::Cancelled)?
+        .map_err(CopyLargeObjectError::HeadObject)?;
+    let size = head.content_length.ok_or_else(|| {
+        CopyLargeObjectError::HeadObject(RusotoError::ParseError(
+            "HeadObject response has no Content-Length".to_owned(),
+        ))
+    })?;
+    destination.copy_source = source.to_copy_source();
+
+    if size <= MAX_COPY_OBJECT_SIZE {
+        let output = unless_cancelled(cancellation, client.copy_object(destination))
+            .await
+            .ok_or(CopyLargeObjectError::Cancelled)?
+            .map_err(CopyLargeObjectError::CopyObject)?;
+        return Ok(CopyLargeObjectOutput {
+            e_tag: output.copy_object_result.and_then(|result| result.e_tag),
+            version_id: output.version_id,
+            parts: None,
+            request_charged: output.request_charged,
+        });
+    }
+
+    // Not cancelled midway, as the upload couldn't be aborted without its ID.
+    let request = create_copy_upload_request(&destination, &head);
+    let output = client
+        .create_multipart_upload(request)
+        .await
+        .map_err(CopyLargeObjectError::CreateMultipartUpload)?;
+    let upload_id = output.upload_id.ok_or_else(|| {
+        CopyLargeObjectError::CreateMultipartUpload(RusotoError::ParseError(
+            "CreateMultipartUpload response has no UploadId".to_owned(),
+        ))
+    })?;
+
+    let copy = copy_parts(client, &destination, &head, &upload_id, size);
+    let result = unless_cancelled(cancellation, copy)
+        .await
+        .unwrap_or(Err(CopyLargeObjectError::Cancelled));
+    if result.is_err() {
+        // The error that made the copy fail matters more than one aborting it.
+        let _ = client
+            .abort_multipart_upload(AbortMultipartUploadRequest {
+                bucket: destination.bucket.clone(),
+                key: destination.key.clone(),
+                expected_bucket_owner: destination.expected_bucket_owner.clone(),
+                request_payer: destination.request_payer.clone(),
+                upload_id,
+            })
+            .await;
+    }
+    result
+}
+
+/// The size of the parts to copy an object of `size` bytes in, so that there are no
+/// more than `MAX_PARTS`.
+fn copy_part_size(size: i64) -> i64 {
+    DEFAULT_COPY_PART_SIZE.max((size + MAX_PARTS - 1) / MAX_PARTS)
+}
+
+fn create_copy_upload_request(
+    destination: &CopyObjectRequest,
+    head: &HeadObjectOutput,
+) -> CreateMultipartUploadRequest {
+    let mut request = CreateMultipartUploadRequest {
+        acl: destination.acl.clone(),
+        bucket: destination.bucket.clone(),
+        bucket_key_enabled: destination.bucket_key_enabled,
+        cache_control: destination.cache_control.clone(),
+        content_disposition: destination.content_disposition.clone(),
+        content_encoding: destination.content_encoding.clone(),
+        content_language: destination.content_language.clone(),
+        content_type: destination.content_type.clone(),
+        expected_bucket_owner: destination.expected_bucket_owner.clone(),
+        expires: destination.expires.clone(),
+        grant_full_control: destination.grant_full_control.clone(),
+        grant_read: destination.grant_read.clone(),
+        grant_read_acp: destination.grant_read_acp.clone(),
+        grant_write_acp: destination.grant_write_acp.clone(),
+        key: destination.key.clone(),
+        metadata: destination.metadata.clone(),
+        object_lock_legal_hold_status: destination.object_lock_legal_hold_status.clone(),
+        object_lock_mode: destination.object_lock_mode.clone(),
+        object_lock_retain_until_date: destination.object_lock_retain_until_date.clone(),
+        request_payer: destination.request_payer.clone(),
+        sse_customer_algorithm: destination.sse_customer_algorithm.clone(),
+        sse_customer_key: destination.sse_customer_key.clone(),
+        sse_customer_key_md5: destination.sse_customer_key_md5.clone(),
+        ssekms_encryption_context: destination.ssekms_encryption_context.clone(),
+        ssekms_key_id: destination.ssekms_key_id.clone(),
+        server_side_encryption: destination.server_side_encryption.clone(),
+        storage_class: destination.storage_class.clone(),
+        tagging: destination.tagging.clone(),
+        website_redirect_location: destination.website_redirect_location.clone(),
+    };
+    if destination.metadata_directive.as_deref() != Some("REPLACE") {
+        request.cache_control = head.cache_control.clone();
+        request.content_disposition = head.content_disposition.clone();
+        request.content_encoding = head.content_encoding.clone();
+        request.content_language = head.content_language.clone();
+        request.content_type = head.content_type.clone();
+        request.expires = head.expires.clone();
+        request.metadata = head.metadata.clone();
+    }
+    request
+}
+
+async fn copy_parts<C>(
+    client: &C,
+    destination: &CopyObjectRequest,
+    head: &HeadObjectOutput,
+    upload_id: &str,
+    size: i64,
+) -> Result<CopyLargeObjectOutput, CopyLargeObjectError>
+where
+    C: S3 + ?Sized,
+{
+    let part_size = copy_part_size(size);
+    let if_match = destination
+        .copy_source_if_match
+        .clone()
+        .or_else(|| head.e_tag.clone());
+    let requests = (0..)
+        .map(|index| index * part_size)
+        .take_while(|start| *start < size)
+        .enumerate()
+        .map(|(index, start)| {
+            let end = (start + part_size).min(size) - 1;
+            UploadPartCopyRequest {
+                bucket: destination.bucket.clone(),
+                copy_source: destination.copy_source.clone(),
+                copy_source_if_match: if_match.clone(),
+                copy_source_if_modified_since: destination.copy_source_if_modified_since.clone(),
+                copy_source_if_none_match: destination.copy_source_if_none_match.clone(),
+                copy_source_if_unmodified_since: destination
+                    .copy_source_if_unmodified_since
+                    .clone(),
+                copy_source_range: Some(ByteRange::Inclusive(start as u64, end as u64).to_string()),
+                copy_source_sse_customer_algorithm: destination
+                    .copy_source_sse_customer_algorithm
+                    .clone(),
+                copy_source_sse_customer_key: destination.copy_source_sse_customer_key.clone(),
+                copy_source_sse_customer_key_md5: destination
+                    .copy_source_sse_customer_key_md5
+                    .clone(),
+                expected_bucket_owner: destination.expected_bucket_owner.clone(),
+                expected_source_bucket_owner: destination.expected_source_bucket_owner.clone(),
+                key: destination.key.clone(),
+                part_number: index as i64 + 1,
+                request_payer: destination.request_payer.clone(),
+                sse_customer_algorithm: destination.sse_customer_algorithm.clone(),
+                sse_customer_key: destination.sse_customer_key.clone(),
+                sse_customer_key_md5: destination.sse_customer_key_md5.clone(),
+                upload_id: upload_id.to_owned(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut parts = stream::iter(requests)
+        .map(|request| async move {
+            let part_number = request.part_number;
+            let output = client.upload_part_copy(request).await?;
+            let e_tag = output.copy_part_result.and_then(|result| result.e_tag);
+            match e_tag {
+                Some(e_tag) => Ok(CompletedPart {
+                    e_tag: Some(e_tag),
+                    part_number: Some(part_number),
+                }),
+                None => Err(RusotoError::ParseError(
+                    "UploadPartCopy response has no ETag".to_owned(),
+                )),
+            }
+        })
+        .buffer_unordered(DEFAULT_CONCURRENCY)
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(CopyLargeObjectError::UploadPartCopy)?;
+    parts.sort_by_key(|part| part.part_number);
+    let part_count = parts.len() as i64;
+
+    let output = client
+        .complete_multipart_upload(CompleteMultipartUploadRequest {
+            bucket: destination.bucket.clone(),
+            key: destination.key.clone(),
+            expected_bucket_owner: destination.expected_bucket_owner.clone(),
+            request_payer: destination.request_payer.clone(),
+            upload_id: upload_id.to_owned(),
+            multipart_upload: Some(CompletedMultipartUpload { parts: Some(parts) }),
+        })
+        .await
+        .map_err(CopyLargeObjectError::CompleteMultipartUpload)?;
+    Ok(CopyLargeObjectOutput {
+        e_tag: output.e_tag,
+        version_id: output.version_id,
+        parts: Some(part_count),
+        request_charged: output.request_charged,
+    })
+}
+
+/// An error that occurred while copying an object with `copy_large_object`, by the step
+/// that failed.
+#[derive(Debug)]
+pub enum CopyLargeObjectError {
+    /// The source could not be looked up.
+    HeadObject(RusotoError<HeadObjectError>),
+    /// The object could not be copied in one request.
+    CopyObject(RusotoError<CopyObjectError>),
+    /// The upload of a multipart copy could not be created.
+    CreateMultipartUpload(RusotoError<CreateMultipartUploadError>),
+    /// A part could not be copied.
+    UploadPartCopy(RusotoError<UploadPartCopyError>),
+    /// The upload of a multipart copy could not be completed.
+    CompleteMultipartUpload(RusotoError<CompleteMultipartUploadError>),
+    /// The copy was cancelled through the token passed to `copy_large_object`.
+    Cancelled,
+}
+
+impl Error for CopyLargeObjectError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CopyLargeObjectError::HeadObject(err) => Some(err),
+            CopyLargeObjectError::CopyObject(err) => Some(err),
+            CopyLargeObjectError::CreateMultipartUpload(err) => Some(err),
+            CopyLargeObjectError::UploadPartCopy(err) => Some(err),
+            CopyLargeObjectError::CompleteMultipartUpload(err) => Some(err),
+            CopyLargeObjectError::Cancelled => None,
+        }
+    }
+}
+
+impl fmt::Display for CopyLargeObjectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CopyLargeObjectError::HeadObject(err) => {
+                write!(f, "Failed to look up the source object: {}", err)
+            }
+            CopyLargeObjectError::CopyObject(err) => {
+                write!(f, "Failed to copy the object: {}", err)
+            }
+            CopyLargeObjectError::CreateMultipartUpload(err) => {
+                write!(f, "Failed to create the multipart upload: {}", err)
+            }
+            CopyLargeObjectError::UploadPartCopy(err) => {
+                write!(f, "Failed to copy a part: {}", err)
+            }
+            CopyLargeObjectError::CompleteMultipartUpload(err) => {
+                write!(f, "Failed to complete the multipart upload: {}", err)
+            }
+            CopyLargeObjectError::Cancelled => write!(f, "The copy was cancelled"),
+        }
+    }
+}
+
+/// The object `download_parts` downloaded.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DownloadPartsOutput {
+    /// The ETag of the object, including its quotes.
+    pub e_tag: Option<String>,
+    /// The version of the object, in versioned buckets.
+    pub version_id: Option<String>,
+    /// The number of parts the object was downloaded in, 1 if it wasn't uploaded in parts.
+    pub parts: i64,
+    /// The size of the object in bytes.
+    pub size: u64,
+    /// `requester` if the requester was charged for the download, for requester pays
+    /// buckets.
+    pub request_charged: Option<String>,
+}
+
+/// Downloads an object by the parts it was uploaded in, several at a time, passing each
+/// part to `write_at` with its offset in the object.
+///
+/// The number of parts is looked up with a `HeadObject` of the first part, which
+/// reports `parts_count` for objects uploaded in parts. Each part is then fetched with a
+/// `GetObject` of its `part_number`, on the condition that the object still has the
+/// ETag and version it had when it was looked up, unless `request` sets `if_match` or
+/// `version_id`. Objects that weren't uploaded in parts are downloaded whole. The
+/// `range` and `part_number` of `request` are ignored.
+///
+/// The parts are passed to `write_at` as they complete, so not in order, and each is
+/// buffered in memory until then: up to `DEFAULT_CONCURRENCY` parts at a time. To
+/// download to a file, write them with e.g. `std::os::unix::fs::FileExt::write_all_at`.
+///
+/// Once all parts are downloaded, the ETag of objects uploaded in parts is checked
+/// against the MD5 digests of the parts, see `util::compute_multipart_etag`, unless they
+/// are encrypted with SSE-KMS or SSE-C, whose ETags aren't MD5 digests. On a mismatch,
+/// `DownloadPartsError::ETagMismatch` is returned, and what was written should be
+/// discarded.
+///
+/// The download stops with `DownloadPartsError::Cancelled` when `cancellation` is
+/// cancelled, after the parts passed to `write_at` so far.
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_s3::multipart::download_parts;
+/// use rusoto_s3::{GetObjectRequest, S3Client};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = S3Client::new(Region::UsEast1);
+/// let request = GetObjectRequest {
+///     bucket: "my-bucket".to_owned(),
+///     key: "backup.tar".to_owned(),
+///     ..Default::default()
+/// };
+/// let mut contents = Vec::new();
+/// let output = download_parts(&client, request, None, |offset, part| {
+///     let start = offset as usize;
+///     if contents.len() < start + part.len() {
+///         contents.resize(start + part.len(), 0);
+///     }
+///     contents[start..start + part.len()].copy_from_slice(&part);
+///     Ok(())
+/// })
+/// .await?;
+/// println!("downloaded {} bytes in {} parts", output.size, output.parts);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn download_parts<C, W>(
+    client: &C,
+    request: GetObjectRequest,
+    cancellation: Option<&CancellationToken>,
+    mut write_at: W,
+) -> Result<DownloadPartsOutput, DownloadPartsError>
+where
+    C: S3 + ?Sized,
+    W: FnMut(u64, Bytes) -> io::Result<()>,
+{
+    let head = client.head_object(HeadObjectRequest {
+        bucket: request.bucket.clone(),
+        key: request.key.clone(),
+        expected_bucket_owner: request.expected_bucket_owner.clone(),
+        if_match: request.if_match.clone(),
+        if_modified_since: request.if_modified_since.clone(),
+        if_none_match: request.if_none_match.clone(),
+        if_unmodified_since: request.if_unmodified_since.clone(),
+        part_number: Some(1),
+        request_payer: request.request_payer.clone(),
+        sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+        sse_customer_key: request.sse_customer_key.clone(),
+        sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+        version_id: request.version_id.clone(),
+        ..Default::default()
+    });
+    let head = unless_cancelled(cancellation, head)
+        .await
+        .ok_or(DownloadPartsError::Cancelled)?
+        .map_err(DownloadPartsError::HeadObject)?;
+    let parts = head.parts_count.unwrap_or(1);
+
+    let template = GetObjectRequest {
+        if_match: request.if_match.clone().or_else(|| head.e_tag.clone()),
+        version_id: request
+            .version_id
+            .clone()
+            .or_else(|| head.version_id.clone()),
+        range: None,
+        ..request
+    };
+    let mut downloads = stream::iter(1..=parts)
+        .map(|part_number| {
+            let request = GetObjectRequest {
+                part_number: Some(part_number),
+                ..template.clone()
+            };
+            async move {
+                let output = client
+                    .get_object(request)
+                    .await
+                    .map_err(DownloadPartsError::GetObject)?;
+                let offset = match output.parsed_content_range() {
+                    Some(content_range) => content_range.ok().map(|range| range.range_start),
+                    // Objects that weren't uploaded in parts are returned whole.
+                    None if parts == 1 => Some(0),
+                    None => None,
+                };
+                let offset = offset.ok_or_else(|| {
+                    DownloadPartsError::GetObject(RusotoError::ParseError(format!(
+                        "GetObject response for part {} has no valid Content-Range: {:?}",
+                        part_number, output.content_range
+                    )))
+                })?;
+                let body = match output.body {
+                    Some(body) => body
+                        .map_ok(|chunk| BytesMut::from(&chunk[..]))
+                        .try_concat()
+                        .await
+                        .map_err(DownloadPartsError::Io)?
+                        .freeze(),
+                    None => Bytes::new(),
+                };
+                let md5: [u8; 16] = Md5::digest(&body).into();
+                Ok((part_number, offset, body, md5, output.request_charged))
+            }
+        })
+        .buffer_unordered(DEFAULT_CONCURRENCY);
+
+    let mut size = 0;
+    let mut request_charged = head.request_charged;
+    let mut part_md5s = BTreeMap::new();
+    while let Some((part_number, offset, body, md5, charged)) =
+        unless_cancelled(cancellation, downloads.try_next())
+            .await
+            .ok_or(DownloadPartsError::Cancelled)??
+    {
+        size += body.len() as u64;
+        request_charged = request_charged.or(charged);
+        part_md5s.insert(part_number, md5);
+        write_at(offset, body).map_err(DownloadPartsError::Io)?;
+    }
+
+    let encrypted = head.sse_customer_algorithm.is_some()
+        || head
+            .server_side_encryption
+            .as_deref()
+            .is_some_and(|encryption| encryption.starts_with("aws:kms"));
+    if let (Some(_), Some(e_tag), false) = (head.parts_count, &head.e_tag, encrypted) {
+        let part_md5s: Vec<[u8; 16]> = part_md5s.into_values().collect();
+        let computed = compute_multipart_etag(&part_md5s);
+        if e_tag.trim_matches('"') != computed {
+            return Err(DownloadPartsError::ETagMismatch {
+                e_tag: e_tag.clone(),
+                computed,
+            });
+        }
+    }
+    Ok(DownloadPartsOutput {
+        e_tag: head.e_tag,
+        version_id: head.version_id,
+        parts,
+        size,
+        request_charged,
+    })
+}
+
+/// An error that occurred while downloading an object with `download_parts`, by the step
+/// that failed.
+#[derive(Debug)]
+pub enum DownloadPartsError {
+    /// The object could not be looked up.
+    HeadObject(RusotoError<HeadObjectError>),
+    /// A part could not be requested.
+    GetObject(RusotoError<GetObjectError>),
+    /// The body of a part could not be read, or written.
+    Io(io::Error),
+    /// The download was cancelled through the token passed to `download_parts`.
+    Cancelled,
+    /// The parts downloaded don't match the ETag of the object, i.e. they were corrupted
+    /// on the way.
+    ETagMismatch {
+        /// The ETag of the object, including its quotes.
+        e_tag: String,
+        /// The ETag computed from the MD5 digests of the parts downloaded.
+        computed: String,
+    },
+}
+
+impl Error for DownloadPartsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DownloadPartsError::HeadObject(err) => Some(err),
+            DownloadPartsError::GetObject(err) => Some(err),
+            DownloadPartsError::Io(err) => Some(err),
+            DownloadPartsError::Cancelled => None,
+            DownloadPartsError::ETagMismatch { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for DownloadPartsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DownloadPartsError::HeadObject(err) => {
+                write!(f, "Failed to look up the object: {}", err)
+            }
+            DownloadPartsError::GetObject(err) => write!(f, "Failed to download a part: {}", err),
+            DownloadPartsError::Io(err) => write!(f, "Failed to transfer a part: {}", err),
+            DownloadPartsError::Cancelled => write!(f, "The download was cancelled"),
+            DownloadPartsError::ETagMismatch { e_tag, computed } => write!(
+                f,
+                "The parts downloaded have the ETag \"{}\" instead of {}",
+                computed, e_tag
+            ),
+        }
+    }
+}
+
+/// Runs `future` to completion, or until `cancellation` is cancelled, in which case it
+/// is dropped and `None` returned.
+async fn unless_cancelled<F>(
+    cancellation: Option<&CancellationToken>,
+    future: F,
+) -> Option<F::Output>
+where
+    F: Future,
+{
+    match cancellation {
+        Some(cancellation) => cancellation.run_until_cancelled(future).await,
+        None => Some(future.await),
+    }
+}
+
+/// The `multipart_threshold` of an `S3Config` that most callers want, 100 MiB.
+pub const DEFAULT_MULTIPART_THRESHOLD: usize = 100 * 1024 * 1024;
+
+/// What `put_object` does with a request when the config sets a `multipart_threshold`.
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum AutoMultipart {
+    /// The body is small enough to be sent with a single `PutObject`.
+    PutObject(PutObjectRequest),
+    /// The body was larger, and has been uploaded in parts.
+    Uploaded(PutObjectOutput),
+}
+
+impl S3Client {
+    /// Uploads the body of `request` in parts if it is larger than `threshold` bytes.
+    ///
+    /// Bodies of unknown size are read until they turn out to be larger: if they end
+    /// before, they are buffered and sent with a single `PutObject`.
+    pub(crate) async fn put_object_in_parts_if_large(
+        &self,
+        mut request: PutObjectRequest,
+        threshold: usize,
+    ) -> Result<AutoMultipart, RusotoError<PutObjectError>> {
+        let mut body = match request.body.take() {
+            Some(body) => body,
+            None => return Ok(AutoMultipart::PutObject(request)),
+        };
+        let size = request
+            .content_length
+            .map(|size| size as usize)
+            .or_else(|| body.size_hint());
+        match size {
+            Some(size) if size <= threshold => {
+                request.body = Some(body);
+                return Ok(AutoMultipart::PutObject(request));
+            }
+            Some(_) => {}
+            None => {
+                let mut head = BytesMut::new();
+                let mut done = false;
+                while head.len() <= threshold {
+                    match body.next().await {
+                        Some(chunk) => head.extend_from_slice(&chunk.map_err(read_error)?),
+                        None => {
+                            done = true;
+                            break;
+                        }
+                    }
+                }
+                let head = head.freeze();
+                if done {
+                    request.content_length = Some(head.len() as i64);
+                    request.body = Some(head.to_vec().into());
+                    return Ok(AutoMultipart::PutObject(request));
+                }
+                body = ByteStream::new(stream::once(async move { Ok(head) }).chain(body));
+            }
+        }
+
+        let part_size = match size {
+            Some(size) => multipart_part_size(size as i64) as usize,
+            None => DEFAULT_PART_SIZE,
+        };
+        let mut upload = AbortOnDrop {
+            client: self,
+            uploader: MultipartUploader::new(create_put_upload_request(&request))
+                .with_part_size(part_size),
+            finished: false,
+        };
+        let result = upload.uploader.upload(self, body).await;
+        if result.is_err() {
+            // The upload can't be resumed without its ID, which the caller never sees.
+            let _ = upload.uploader.abort(self).await;
+        }
+        upload.finished = true;
+        let output = result.map_err(put_object_error)?;
+        Ok(AutoMultipart::Uploaded(PutObjectOutput {
+            bucket_key_enabled: output.bucket_key_enabled,
+            e_tag: output.e_tag,
+            expiration: output.expiration,
+            request_charged: output.request_charged,
+            sse_customer_algorithm: request.sse_customer_algorithm,
+            sse_customer_key_md5: request.sse_customer_key_md5,
+            ssekms_encryption_context: request.ssekms_encryption_context,
+            ssekms_key_id: output.ssekms_key_id,
+            server_side_encryption: output.server_side_encryption,
+            version_id: output.version_id,
+        }))
+    }
+}
+
+/// Aborts the upload of `uploader` in the background if it's dropped before `finished`
+/// is set, i.e. when the `put_object` future is dropped mid-upload, e.g. on a timeout.
+/// Nobody else could abort it, as the caller never sees the upload ID.
+struct AbortOnDrop<'a> {
+    client: &'a S3Client,
+    uploader: MultipartUploader,
+    finished: bool,
+}
+
+impl Drop for AbortOnDrop<'_> {
+    fn drop(&mut self) {
+        let upload_id = match self.uploader.upload_id() {
+            Some(upload_id) if !self.finished => upload_id.to_owned(),
+            _ => return,
+        };
+        let client = self.client.clone();
+        let mut uploader = self.uploader.clone();
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => {
+                runtime.spawn(async move {
+                    if let Err(err) = uploader.abort(&client).await {
+                        warn!("Failed to abort interrupted upload {}: {}", upload_id, err);
+                    }
+                });
+            }
+            Err(_) => warn!(
+                "Interrupted upload {} can't be aborted outside of a Tokio runtime",
+                upload_id
+            ),
+        }
+    }
+}
+
+/// The size of the parts to upload a body of `size` bytes in, so that there are no
+/// more than `MAX_PARTS`.
+fn multipart_part_size(size: i64) -> i64 {
+    (DEFAULT_PART_SIZE as i64).max((size + MAX_PARTS - 1) / MAX_PARTS)
+}
+
+/// The `Content-MD5` of the whole body can't be checked by a multipart upload, whose
+/// parts are each checked against their own digest instead.
+fn create_put_upload_request(request: &PutObjectRequest) -> CreateMultipartUploadRequest {
+    CreateMultipartUploadRequest {
+        acl: request.acl.clone(),
+        bucket: request.bucket.clone(),
+        bucket_key_enabled: request.bucket_key_enabled,
+        cache_control: request.cache_control.clone(),
+        content_disposition: request.content_disposition.clone(),
+        content_encoding: request.content_encoding.clone(),
+        content_language: request.content_language.clone(),
+        content_type: request.content_type.clone(),
+        expected_bucket_owner: request.expected_bucket_owner.clone(),
+        expires: request.expires.clone(),
+        grant_full_control: request.grant_full_control.clone(),
+        grant_read: request.grant_read.clone(),
+        grant_read_acp: request.grant_read_acp.clone(),
+        grant_write_acp: request.grant_write_acp.clone(),
+        key: request.key.clone(),
+        metadata: request.metadata.clone(),
+        object_lock_legal_hold_status: request.object_lock_legal_hold_status.clone(),
+        object_lock_mode: request.object_lock_mode.clone(),
+        object_lock_retain_until_date: request.object_lock_retain_until_date.clone(),
+        request_payer: request.request_payer.clone(),
+        sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+        sse_customer_key: request.sse_customer_key.clone(),
+        sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+        ssekms_encryption_context: request.ssekms_encryption_context.clone(),
+        ssekms_key_id: request.ssekms_key_id.clone(),
+        server_side_encryption: request.server_side_encryption.clone(),
+        storage_class: request.storage_class.clone(),
+        tagging: request.tagging.clone(),
+        website_redirect_location: request.website_redirect_location.clone(),
+    }
+}
+
+fn read_error(err: io::Error) -> RusotoError<PutObjectError> {
+    RusotoError::HttpDispatch(HttpDispatchError::new(format!(
+        "Failed to read the body: {}",
+        err
+    )))
+}
+
+/// Passes on the error of a multipart upload as that of the `PutObject` it replaces.
+/// None of the operations of an upload have service errors of their own.
+fn put_object_error(err: MultipartUploadError) -> RusotoError<PutObjectError> {
+    fn convert<E>(
+        err: RusotoError<E>,
+        service: fn(E) -> RusotoError<PutObjectError>,
+    ) -> RusotoError<PutObjectError> {
+        match err {
+            RusotoError::Service(err) => service(err),
+            RusotoError::HttpDispatch(err) => RusotoError::HttpDispatch(err),
+            RusotoError::InvalidDnsName(err) => RusotoError::InvalidDnsName(err),
+            RusotoError::Credentials(err) => RusotoError::Credentials(err),
+            RusotoError::Validation(message) => RusotoError::Validation(message),
+            RusotoError::ParseError(message) => RusotoError::ParseError(message),
+            RusotoError::Unknown(response) => RusotoError::Unknown(response),
+            RusotoError::Blocking => RusotoError::Blocking,
+        }
+    }
+
+    match err {
+        MultipartUploadError::CreateMultipartUpload(err) => convert(err, |err| match err {}),
+        MultipartUploadError::ListParts(err) => convert(err, |err| match err {}),
+        MultipartUploadError::UploadPart(err) => convert(err, |err| match err {}),
+        MultipartUploadError::CompleteMultipartUpload(err) => convert(err, |err| match err {}),
+        MultipartUploadError::Io(err) => read_error(err),
+        MultipartUploadError::Cancelled => {
+            RusotoError::HttpDispatch(HttpDispatchError::new(err.to_string()))
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::VecDeque;
+
+use futures::stream::{self, Stream};
+use rusoto_core::{RetryPolicy, RusotoError, RusotoResult};
+
+use crate::generated::{
+    DeleteMarkerEntry, ListObjectVersionsError, ListObjectVersionsRequest, ListObjectsV2Error,
+    ListObjectsV2Output, ListObjectsV2Request, ObjectVersion, S3,
+};
+
+/// Fetches the pages of a `ListObjectsV2Request` one call at a time, threading each
+/// `next_continuation_token` into the following request.
+///
+/// A page that fails with a transient error, e.g. a throttling response or a dropped
+/// connection, is requested again with the same continuation token according to the
+/// pager's retry policy, which is `RetryPolicy::from_env()` by default. The pager only
+/// advances once a page has been fetched successfully, so after an error `next_page`
+/// can also be called again to retry the same page.
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_s3::paginate::ListObjectsV2Pager;
+/// use rusoto_s3::{ListObjectsV2Request, S3Client};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = S3Client::new(Region::UsEast1);
+/// let mut pager = ListObjectsV2Pager::new(ListObjectsV2Request {
+///     bucket: "my-bucket".to_owned(),
+///     ..Default::default()
+/// });
+/// while let Some(page) = pager.next_page(&client).await? {
+///     for object in page.contents.unwrap_or_default() {
+///         println!("{:?}", object.key);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListObjectsV2Pager {
+    request: ListObjectsV2Request,
+    exhausted: bool,
+    retry_policy: RetryPolicy,
+}
+
+impl ListObjectsV2Pager {
+    /// Creates a pager starting at `request.continuation_token`, or at the first page
+    /// if it is unset.
+    pub fn new(request: ListObjectsV2Request) -> Self {
+        ListObjectsV2Pager {
+            request,
+            exhausted: false,
+            retry_policy: RetryPolicy::from_env(),
+        }
+    }
+
+    /// Sets how pages that fail with a transient error are retried.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Fetches the next page, or returns `None` once the last page has been returned.
+    pub async fn next_page<C>(
+        &mut self,
+        client: &C,
+    ) -> RusotoResult<Option<ListObjectsV2Output>, ListObjectsV2Error>
+    where
+        C: S3 + ?Sized,
+    {
+        if self.exhausted {
+            return Ok(None);
+        }
+        let request = &self.request;
+        let output = self
+            .retry_policy
+            .retry(|| client.list_objects_v2(request.clone()))
+            .await?;
+        match output.next_continuation_token {
+            Some(ref token) if output.is_truncated.unwrap_or(false) => {
+                self.request.continuation_token = Some(token.clone());
+            }
+            _ => self.exhausted = true,
+        }
+        Ok(Some(output))
+    }
+
+    /// The token the next page will be requested with, e.g. to resume the listing
+    /// later with a new pager.
+    pub fn continuation_token(&self) -> Option<&str> {
+        self.request.continuation_token.as_deref()
+    }
+
+    /// Returns true once the last page has been returned.
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+}
+
+/// An entry of a versioned bucket listed by `list_object_versions_paginator`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VersionEntry {
+    /// A version of an object.
+    Version(ObjectVersion),
+    /// A delete marker, i.e. the version a `DeleteObject` without a version ID created.
+    DeleteMarker(DeleteMarkerEntry),
+}
+
+impl VersionEntry {
+    /// The key the version or delete marker belongs to.
+    pub fn key(&self) -> Option<&str> {
+        match *self {
+            VersionEntry::Version(ref version) => version.key.as_deref(),
+            VersionEntry::DeleteMarker(ref marker) => marker.key.as_deref(),
+        }
+    }
+
+    /// The ID of the version or delete marker.
+    pub fn version_id(&self) -> Option<&str> {
+        match *self {
+            VersionEntry::Version(ref version) => version.version_id.as_deref(),
+            VersionEntry::DeleteMarker(ref marker) => marker.version_id.as_deref(),
+        }
+    }
+
+    fn last_modified(&self) -> Option<&str> {
+        match *self {
+            VersionEntry::Version(ref version) => version.last_modified.as_deref(),
+            VersionEntry::DeleteMarker(ref marker) => marker.last_modified.as_deref(),
+        }
+    }
+}
+
+/// How far a `list_object_versions_paginator` got.
+struct VersionListing {
+    request: ListObjectVersionsRequest,
+    pending: VecDeque<VersionEntry>,
+    exhausted: bool,
+    retry_policy: RetryPolicy,
+}
+
+/// Lists the versions and delete markers of the objects matching `request`, in key
+/// order and newest first for each key, fetching pages as the stream is polled.
+///
+/// Each page is requested from the `next_key_marker` and `next_version_id_marker` of
+/// the previous one, starting at `request.key_marker` and `request.version_id_marker`,
+/// so a listing can be resumed from the last entry it returned. S3 returns the versions
+/// and delete markers of a page as separate lists, which are merged back into one.
+///
+/// A page that fails with a transient error is requested again with the same markers
+/// according to `RetryPolicy::from_env()`, see
+/// `list_object_versions_paginator_with_retry_policy`.
+///
+/// ```rust,no_run
+/// use futures::TryStreamExt;
+/// use rusoto_core::Region;
+/// use rusoto_s3::paginate::{list_object_versions_paginator, VersionEntry};
+/// use rusoto_s3::{ListObjectVersionsRequest, S3Client};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = S3Client::new(Region::UsEast1);
+/// let request = ListObjectVersionsRequest {
+///     bucket: "my-bucket".to_owned(),
+///     ..Default::default()
+/// };
+/// let mut entries = Box::pin(list_object_versions_paginator(&client, request));
+/// while let Some(entry) = entries.try_next().await? {
+///     match entry {
+///         VersionEntry::Version(version) => println!("{:?}", version.version_id),
+///         VersionEntry::DeleteMarker(marker) => println!("deleted {:?}", marker.key),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn list_object_versions_paginator<'a, C>(
+    client: &'a C,
+    request: ListObjectVersionsRequest,
+) -> impl Stream<Item = Result<VersionEntry, RusotoError<ListObjectVersionsError>>> + 'a
+where
+    C: S3 + ?Sized,
+{
+    list_object_versions_paginator_with_retry_policy(client, request, RetryPolicy::from_env())
+}
+
+/// Like `list_object_versions_paginator`, retrying pages that fail with a transient
+/// error according to `retry_policy`.
+pub fn list_object_versions_paginator_with_retry_policy<'a, C>(
+    client: &'a C,
+    request: ListObjectVersionsRequest,
+    retry_policy: RetryPolicy,
+) -> impl Stream<Item = Result<VersionEntry, RusotoError<ListObjectVersionsError>>> + 'a
+where
+    C: S3 + ?Sized,
+{
+    let listing = VersionListing {
+        request,
+        pending: VecDeque::new(),
+        exhausted: false,
+        retry_policy,
+    };
+
+    stream::try_unfold(listing, move |mut listing| async move {
+        loop {
+            if let Some(entry) = listing.pending.pop_front() {
+                return Ok(Some((entry, listing)));
+            }
+            if listing.exhausted {
+                return Ok(None);
+            }
+
+            let request = &listing.request;
+            let output = listing
+                .retry_policy
+                .retry(|| client.list_object_versions(request.clone()))
+                .await?;
+
+            let mut entries: Vec<VersionEntry> = output
+                .versions
+                .unwrap_or_default()
+                .into_iter()
+                .map(VersionEntry::Version)
+                .chain(
+                    output
+                        .delete_markers
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(VersionEntry::DeleteMarker),
+                )
+                .collect();
+            // Timestamps are all in the same ISO 8601 format, so they sort as strings.
+            entries.sort_by(|a, b| {
+                a.key()
+                    .cmp(&b.key())
+                    .then_with(|| b.last_modified().cmp(&a.last_modified()))
+            });
+            listing.pending = entries.into();
+
+            // Both markers move together: the version ID marker is only meaningful for
+            // the key marker it came with.
+            match output.next_key_marker {
+                Some(key_marker) if output.is_truncated.unwrap_or(false) => {
+                    listing.request.key_marker = Some(key_marker);
+                    listing.request.version_id_marker = output.next_version_id_marker;
+                }
+                _ => listing.exhausted = true,
+            }
+        }
+    })
+}
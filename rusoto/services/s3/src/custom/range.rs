@@ -0,0 +1,205 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::generated::{GetObjectOutput, GetObjectRequest};
+
+/// A range of bytes of an object, formatted as the value of a `Range` header.
+///
+/// ```rust
+/// use rusoto_s3::range::ByteRange;
+/// use rusoto_s3::GetObjectRequest;
+///
+/// let request = GetObjectRequest {
+///     bucket: "my-bucket".to_owned(),
+///     key: "video.mp4".to_owned(),
+///     ..Default::default()
+/// }
+/// .with_range(ByteRange::Inclusive(0, 1023));
+/// assert_eq!(request.range.as_deref(), Some("bytes=0-1023"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ByteRange {
+    /// The bytes from the first offset up to and including the second, `bytes=start-end`.
+    ///
+    /// S3 ignores ranges that end before they start and returns the whole object.
+    Inclusive(u64, u64),
+    /// The bytes from the offset to the end of the object, `bytes=start-`.
+    From(u64),
+    /// The last bytes of the object, `bytes=-length`.
+    Last(u64),
+}
+
+impl fmt::Display for ByteRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ByteRange::Inclusive(start, end) => write!(f, "bytes={}-{}", start, end),
+            ByteRange::From(start) => write!(f, "bytes={}-", start),
+            ByteRange::Last(length) => write!(f, "bytes=-{}", length),
+        }
+    }
+}
+
+impl GetObjectRequest {
+    /// Sets `range` to fetch only the given bytes of the object.
+    ///
+    /// The range is a signed header of presigned URLs, so whoever uses a URL presigned
+    /// from the request must send exactly this `Range` header.
+    pub fn with_range(mut self, range: ByteRange) -> Self {
+        self.range = Some(range.to_string());
+        self
+    }
+}
+
+/// The bytes of an object a ranged `GetObject` response holds, parsed from its
+/// `Content-Range` header, e.g. `bytes 0-1023/4096`.
+///
+/// ```rust
+/// use rusoto_s3::range::ContentRange;
+///
+/// let range: ContentRange = "bytes 0-1023/4096".parse().unwrap();
+/// assert_eq!(range.range_start, 0);
+/// assert_eq!(range.range_end, 1023);
+/// assert_eq!(range.total_size, Some(4096));
+/// assert_eq!(range.len(), 1024);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ContentRange {
+    /// The offset of the first byte.
+    pub range_start: u64,
+    /// The offset of the last byte, inclusive.
+    pub range_end: u64,
+    /// The size of the whole object, `None` if the response gives it as `*`.
+    pub total_size: Option<u64>,
+}
+
+impl ContentRange {
+    /// The number of bytes in the range.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u64 {
+        self.range_end - self.range_start + 1
+    }
+}
+
+impl fmt::Display for ContentRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bytes {}-{}/", self.range_start, self.range_end)?;
+        match self.total_size {
+            Some(total_size) => write!(f, "{}", total_size),
+            None => f.write_str("*"),
+        }
+    }
+}
+
+/// The error returned when a `Content-Range` header can't be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseContentRangeError(String);
+
+impl fmt::Display for ParseContentRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid Content-Range: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseContentRangeError {}
+
+impl FromStr for ContentRange {
+    type Err = ParseContentRangeError;
+
+    /// Parses `bytes start-end/total`, where the total may be `*`. The `bytes */total`
+    /// form of unsatisfiable ranges holds no bytes, so it's an error.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseContentRangeError(s.to_owned());
+        let (range, total) = s
+            .trim()
+            .strip_prefix("bytes ")
+            .and_then(|rest| rest.split_once('/'))
+            .ok_or_else(invalid)?;
+        let (start, end) = range.trim().split_once('-').ok_or_else(invalid)?;
+        let range_start = start.parse().map_err(|_| invalid())?;
+        let range_end = end.parse().map_err(|_| invalid())?;
+        let total_size = match total.trim() {
+            "*" => None,
+            total => Some(total.parse().map_err(|_| invalid())?),
+        };
+        match total_size {
+            _ if range_end < range_start => Err(invalid()),
+            Some(total_size) if range_end >= total_size => Err(invalid()),
+            _ => Ok(ContentRange {
+                range_start,
+                range_end,
+                total_size,
+            }),
+        }
+    }
+}
+
+impl GetObjectOutput {
+    /// The bytes of the object the response holds, if it answers a request with a `range`
+    /// or `part_number`, parsed from `content_range`.
+    ///
+    /// The `total_size` of the first range tells a ranged download how much is left to
+    /// fetch, without a separate `HeadObject`.
+    pub fn parsed_content_range(&self) -> Option<Result<ContentRange, ParseContentRangeError>> {
+        self.content_range.as_deref().map(str::parse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_range_forms() {
+        assert_eq!(ByteRange::Inclusive(0, 1023).to_string(), "bytes=0-1023");
+        assert_eq!(ByteRange::From(500).to_string(), "bytes=500-");
+        assert_eq!(ByteRange::Last(100).to_string(), "bytes=-100");
+
+        let request = GetObjectRequest::default().with_range(ByteRange::Last(100));
+        assert_eq!(request.range.as_deref(), Some("bytes=-100"));
+    }
+
+    #[test]
+    fn parse_content_range() {
+        let parse = |s: &str| s.parse::<ContentRange>();
+        assert_eq!(
+            parse("bytes 0-1023/4096"),
+            Ok(ContentRange {
+                range_start: 0,
+                range_end: 1023,
+                total_size: Some(4096),
+            })
+        );
+        assert_eq!(
+            parse("bytes 4000-4095/*"),
+            Ok(ContentRange {
+                range_start: 4000,
+                range_end: 4095,
+                total_size: None,
+            })
+        );
+        assert_eq!(parse("bytes 7-7/8").unwrap().len(), 1);
+        for range in &["bytes 0-1023/4096", "bytes 4000-4095/*"] {
+            assert_eq!(parse(range).unwrap().to_string(), *range);
+        }
+
+        for invalid in &[
+            "",
+            "0-1023/4096",
+            "items 0-1023/4096",
+            "bytes */4096",
+            "bytes 0-1023",
+            "bytes 1023-0/4096",
+            "bytes 0-4096/4096",
+            "bytes -1-10/20",
+        ] {
+            assert_eq!(
+                parse(invalid),
+                Err(ParseContentRangeError(invalid.to_string()))
+            );
+        }
+        assert_eq!(
+            parse("bytes */4096").unwrap_err().to_string(),
+            "Invalid Content-Range: \"bytes */4096\""
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::generated::{
+    CopyObjectRequest, CreateMultipartUploadRequest, GetObjectOutput, HeadObjectOutput, Object,
+    ObjectVersion, PutObjectRequest,
+};
+
+/// The storage class of an object, formatted as the value of `x-amz-storage-class` and
+/// of the `StorageClass` element of listings.
+///
+/// ```rust
+/// use rusoto_s3::storage_class::StorageClass;
+/// use rusoto_s3::PutObjectRequest;
+///
+/// let request = PutObjectRequest {
+///     bucket: "my-bucket".to_owned(),
+///     key: "archive.tar".to_owned(),
+///     ..Default::default()
+/// }
+/// .with_storage_class(StorageClass::GlacierIr);
+/// assert_eq!(request.storage_class.as_deref(), Some("GLACIER_IR"));
+/// assert_eq!("DEEP_ARCHIVE".parse(), Ok(StorageClass::DeepArchive));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StorageClass {
+    /// `STANDARD`, which S3 uses for objects stored without a storage class.
+    Standard,
+    /// `REDUCED_REDUNDANCY`
+    ReducedRedundancy,
+    /// `STANDARD_IA`
+    StandardIa,
+    /// `ONEZONE_IA`
+    OnezoneIa,
+    /// `INTELLIGENT_TIERING`
+    IntelligentTiering,
+    /// `GLACIER`, i.e. S3 Glacier Flexible Retrieval. Objects have to be restored before
+    /// they can be read.
+    Glacier,
+    /// `GLACIER_IR`, i.e. S3 Glacier Instant Retrieval.
+    GlacierIr,
+    /// `DEEP_ARCHIVE`. Objects have to be restored before they can be read.
+    DeepArchive,
+    /// `OUTPOSTS`, for objects on S3 on Outposts.
+    Outposts,
+    /// `SNOW`, for objects on Snow Family devices.
+    Snow,
+    /// `EXPRESS_ONEZONE`, for objects in directory buckets.
+    ExpressOnezone,
+}
+
+impl StorageClass {
+    /// The name S3 uses for the storage class, e.g. `GLACIER_IR`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StorageClass::Standard => "STANDARD",
+            StorageClass::ReducedRedundancy => "REDUCED_REDUNDANCY",
+            StorageClass::StandardIa => "STANDARD_IA",
+            StorageClass::OnezoneIa => "ONEZONE_IA",
+            StorageClass::IntelligentTiering => "INTELLIGENT_TIERING",
+            StorageClass::Glacier => "GLACIER",
+            StorageClass::GlacierIr => "GLACIER_IR",
+            StorageClass::DeepArchive => "DEEP_ARCHIVE",
+            StorageClass::Outposts => "OUTPOSTS",
+            StorageClass::Snow => "SNOW",
+            StorageClass::ExpressOnezone => "EXPRESS_ONEZONE",
+        }
+    }
+}
+
+impl fmt::Display for StorageClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The error returned when a storage class isn't one of those `StorageClass` knows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseStorageClassError(String);
+
+impl fmt::Display for ParseStorageClassError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unknown storage class: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseStorageClassError {}
+
+impl FromStr for StorageClass {
+    type Err = ParseStorageClassError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "STANDARD" => Ok(StorageClass::Standard),
+            "REDUCED_REDUNDANCY" => Ok(StorageClass::ReducedRedundancy),
+            "STANDARD_IA" => Ok(StorageClass::StandardIa),
+            "ONEZONE_IA" => Ok(StorageClass::OnezoneIa),
+            "INTELLIGENT_TIERING" => Ok(StorageClass::IntelligentTiering),
+            "GLACIER" => Ok(StorageClass::Glacier),
+            "GLACIER_IR" => Ok(StorageClass::GlacierIr),
+            "DEEP_ARCHIVE" => Ok(StorageClass::DeepArchive),
+            "OUTPOSTS" => Ok(StorageClass::Outposts),
+            "SNOW" => Ok(StorageClass::Snow),
+            "EXPRESS_ONEZONE" => Ok(StorageClass::ExpressOnezone),
+            _ => Err(ParseStorageClassError(s.to_owned())),
+        }
+    }
+}
+
+/// S3 leaves the storage class out of responses about `STANDARD` objects.
+fn parse_or_standard(storage_class: Option<&str>) -> Result<StorageClass, ParseStorageClassError> {
+    storage_class.map_or(Ok(StorageClass::Standard), str::parse)
+}
+
+macro_rules! with_storage_class {
+    ($($request:ty),*) => {
+        $(
+            impl $request {
+                /// Sets `storage_class` to store the object in the given class.
+                pub fn with_storage_class(mut self, storage_class: StorageClass) -> Self {
+                    self.storage_class = Some(storage_class.to_string());
+                    self
+                }
+            }
+        )*
+    };
+}
+
+with_storage_class!(
+    PutObjectRequest,
+    CopyObjectRequest,
+    CreateMultipartUploadRequest
+);
+
+macro_rules! parsed_storage_class {
+    ($($output:ty),*) => {
+        $(
+            impl $output {
+                /// The storage class of the object, parsed from `storage_class`, which is
+                /// `Standard` when S3 left it out.
+                pub fn parsed_storage_class(
+                    &self,
+                ) -> Result<StorageClass, ParseStorageClassError> {
+                    parse_or_standard(self.storage_class.as_deref())
+                }
+            }
+        )*
+    };
+}
+
+parsed_storage_class!(HeadObjectOutput, GetObjectOutput, Object, ObjectVersion);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_storage_classes() {
+        let classes = [
+            ("STANDARD", StorageClass::Standard),
+            ("REDUCED_REDUNDANCY", StorageClass::ReducedRedundancy),
+            ("STANDARD_IA", StorageClass::StandardIa),
+            ("ONEZONE_IA", StorageClass::OnezoneIa),
+            ("INTELLIGENT_TIERING", StorageClass::IntelligentTiering),
+            ("GLACIER", StorageClass::Glacier),
+            ("GLACIER_IR", StorageClass::GlacierIr),
+            ("DEEP_ARCHIVE", StorageClass::DeepArchive),
+            ("OUTPOSTS", StorageClass::Outposts),
+            ("SNOW", StorageClass::Snow),
+            ("EXPRESS_ONEZONE", StorageClass::ExpressOnezone),
+        ];
+        for (name, class) in classes.iter() {
+            assert_eq!(name.parse(), Ok(*class));
+            assert_eq!(class.to_string(), *name);
+        }
+
+        let err = "glacier".parse::<StorageClass>().unwrap_err();
+        assert_eq!(err.to_string(), "Unknown storage class: \"glacier\"");
+        assert!("".parse::<StorageClass>().is_err());
+    }
+
+    #[test]
+    fn missing_storage_class_is_standard() {
+        let output = HeadObjectOutput::default();
+        assert_eq!(output.parsed_storage_class(), Ok(StorageClass::Standard));
+
+        let object = Object {
+            storage_class: Some("SNOW".to_owned()),
+            ..Default::default()
+        };
+        assert_eq!(object.parsed_storage_class(), Ok(StorageClass::Snow));
+
+        let request = CreateMultipartUploadRequest::default()
+            .with_storage_class(StorageClass::IntelligentTiering);
+        assert_eq!(
+            request.storage_class.as_deref(),
+            Some("INTELLIGENT_TIERING")
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use futures::stream::{self, StreamExt};
+use rusoto_core::RusotoError;
+
+use crate::generated::{
+    GetObjectTaggingError, GetObjectTaggingRequest, PutObjectTaggingError, PutObjectTaggingOutput,
+    PutObjectTaggingRequest, Tag, Tagging, S3,
+};
+
+/// The maximum number of tags S3 allows on an object.
+pub const MAX_TAGS_PER_OBJECT: usize = 10;
+/// The maximum length of a tag key, in Unicode characters.
+pub const MAX_TAG_KEY_LENGTH: usize = 128;
+/// The maximum length of a tag value, in Unicode characters.
+pub const MAX_TAG_VALUE_LENGTH: usize = 256;
+
+/// Checks a tag set against the limits S3 enforces on object tags.
+pub fn validate_tags(tags: &[Tag]) -> Result<(), String> {
+    if tags.len() > MAX_TAGS_PER_OBJECT {
+        return Err(format!(
+            "Objects can have at most {} tags, got {}",
+            MAX_TAGS_PER_OBJECT,
+            tags.len()
+        ));
+    }
+    for tag in tags {
+        let key_length = tag.key.chars().count();
+        if key_length == 0 || key_length > MAX_TAG_KEY_LENGTH {
+            return Err(format!(
+                "Tag keys must be 1 to {} characters long, got {:?}",
+                MAX_TAG_KEY_LENGTH, tag.key
+            ));
+        }
+        if tag.value.chars().count() > MAX_TAG_VALUE_LENGTH {
+            return Err(format!(
+                "Tag values must be at most {} characters long, got {:?} for key {:?}",
+                MAX_TAG_VALUE_LENGTH, tag.value, tag.key
+            ));
+        }
+    }
+    if let Some(duplicate) = tags
+        .iter()
+        .enumerate()
+        .find(|(i, tag)| tags[..*i].iter().any(|other| other.key == tag.key))
+    {
+        return Err(format!("Duplicate tag key {:?}", (duplicate.1).key));
+    }
+    Ok(())
+}
+
+/// Replaces the tag set of every key in `keys` with `tags`, sending up to `concurrency`
+/// requests at a time.
+///
+/// The tag set is validated once up front, and a `RusotoError::Validation` is returned
+/// without sending any request if it breaks S3's limits. Otherwise every key is tagged,
+/// even if some fail, and the result of each is returned in the order of `keys`.
+pub async fn set_tags_for_keys<C, I, K>(
+    client: &C,
+    bucket: &str,
+    keys: I,
+    tags: &[Tag],
+    concurrency: usize,
+) -> Result<
+    Vec<(
+        String,
+        Result<PutObjectTaggingOutput, RusotoError<PutObjectTaggingError>>,
+    )>,
+    RusotoError<PutObjectTaggingError>,
+>
+where
+    C: S3 + ?Sized,
+    I: IntoIterator<Item = K>,
+    K: Into<String>,
+{
+    validate_tags(tags).map_err(RusotoError::Validation)?;
+
+    let results = stream::iter(keys)
+        .map(|key| {
+            let key = key.into();
+            let request = PutObjectTaggingRequest {
+                bucket: bucket.to_owned(),
+                key: key.clone(),
+                tagging: Tagging {
+                    tag_set: tags.to_vec(),
+                },
+                ..Default::default()
+            };
+            async move { (key, client.put_object_tagging(request).await) }
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+    Ok(results)
+}
+
+/// Fetches the tag set of every key in `keys`, sending up to `concurrency` requests at
+/// a time. Failures don't stop the other keys from being fetched, and the result of
+/// each is returned in the order of `keys`.
+pub async fn get_tags_for_keys<C, I, K>(
+    client: &C,
+    bucket: &str,
+    keys: I,
+    concurrency: usize,
+) -> Vec<(String, Result<Vec<Tag>, RusotoError<GetObjectTaggingError>>)>
+where
+    C: S3 + ?Sized,
+    I: IntoIterator<Item = K>,
+    K: Into<String>,
+{
+    stream::iter(keys)
+        .map(|key| {
+            let key = key.into();
+            let request = GetObjectTaggingRequest {
+                bucket: bucket.to_owned(),
+                key: key.clone(),
+                ..Default::default()
+            };
+            async move {
+                let result = client.get_object_tagging(request).await;
+                (key, result.map(|output| output.tag_set))
+            }
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
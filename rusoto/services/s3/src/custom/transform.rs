@@ -0,0 +1,85 @@
+//! Transforming the bodies of objects on their way to and from S3, e.g. for client-side
+//! encryption or compression, with a `BodyTransform` set as the `body_transform` of the
+//! `S3Config` of a client.
+//!
+//! The bodies of `PutObject` requests and `GetObject` responses are passed through the
+//! transform. Other ways of reading or writing objects are not: `UploadPart`,
+//! `CopyObject`, `SelectObjectContent` and presigned URLs see the bodies as stored.
+//!
+//! A transform usually has to record how it changed a body for the download to undo
+//! it, e.g. which key encrypted it. User metadata is the place for that: set it in
+//! `PutObjectRequest::metadata` on upload and read it from `GetObjectOutput::metadata`
+//! on download. The S3 encryption clients of the AWS SDKs, for example, store the
+//! encrypted data key as `x-amz-key-v2`, the IV as `x-amz-iv` and the algorithm as
+//! `x-amz-cek-alg`, sent as the `x-amz-meta-x-amz-key-v2` etc. headers. Objects
+//! without the metadata can then be passed through unchanged.
+//!
+//! ```rust
+//! use rusoto_core::{ByteStream, Region};
+//! use rusoto_s3::transform::BodyTransform;
+//! use rusoto_s3::{GetObjectOutput, PutObjectRequest, S3Client};
+//! use std::sync::Arc;
+//!
+//! /// Flips the bits of every byte, standing in for a real cipher.
+//! #[derive(Debug)]
+//! struct Invert;
+//!
+//! fn invert(body: ByteStream) -> ByteStream {
+//!     body.map_chunks(|chunk| chunk.iter().map(|byte| !byte).collect())
+//! }
+//!
+//! impl BodyTransform for Invert {
+//!     fn wrap_upload(&self, body: ByteStream, request: &mut PutObjectRequest) -> ByteStream {
+//!         request
+//!             .metadata
+//!             .get_or_insert_with(Default::default)
+//!             .insert("transform".to_owned(), "invert".to_owned());
+//!         // The digest of the original body doesn't match the stored one.
+//!         request.content_md5 = None;
+//!         invert(body)
+//!     }
+//!
+//!     fn wrap_download(&self, body: ByteStream, output: &GetObjectOutput) -> ByteStream {
+//!         let metadata = output.metadata.as_ref();
+//!         match metadata.and_then(|metadata| metadata.get("transform")) {
+//!             Some(transform) if transform == "invert" => invert(body),
+//!             _ => body,
+//!         }
+//!     }
+//! }
+//!
+//! let mut client = S3Client::new(Region::UsEast1);
+//! client.config_mut().body_transform = Some(Arc::new(Invert));
+//! ```
+
+use std::fmt;
+
+use rusoto_core::ByteStream;
+
+use crate::generated::{GetObjectOutput, PutObjectRequest};
+
+/// Transforms the bodies of objects uploaded with `PutObject` and downloaded with
+/// `GetObject`. Both methods pass bodies through unchanged by default.
+///
+/// The transforms are called when a request is sent or a response received, and
+/// return a stream that transforms the body lazily, as it is read.
+pub trait BodyTransform: fmt::Debug + Send + Sync {
+    /// Transforms the body of a `GetObject` response. `output` is the rest of the
+    /// response, including the user metadata the upload stored.
+    ///
+    /// Downloads of a `range` get a part of the stored body, which the transform must be
+    /// able to handle.
+    fn wrap_download(&self, body: ByteStream, _output: &GetObjectOutput) -> ByteStream {
+        body
+    }
+
+    /// Transforms the body of a `PutObject` request, before its headers are set.
+    /// Requests without a body are passed an empty one.
+    ///
+    /// `request` can be updated along with the body, e.g. to add user metadata. A
+    /// `content_length` or `content_md5` set on it must be updated, or cleared, when the
+    /// transform changes the length or the content of the body, or S3 rejects the upload.
+    fn wrap_upload(&self, body: ByteStream, _request: &mut PutObjectRequest) -> ByteStream {
+        body
+    }
+}
@@ -0,0 +1,269 @@
+use std::net::Ipv4Addr;
+
+use rusoto_core::signature::SignedRequest;
+
+/// Query parameters S3 takes a number for, with the smallest and largest values it
+/// accepts.
+const NUMERIC_PARAMS: &[(&str, i64, i64)] = &[
+    ("max-keys", 0, i32::MAX as i64),
+    ("max-parts", 0, i32::MAX as i64),
+    ("max-uploads", 1, 1000),
+    ("part-number-marker", 0, i32::MAX as i64),
+    ("partNumber", 1, 10_000),
+];
+
+/// The longest key S3 accepts, in bytes of UTF-8.
+const MAX_KEY_LENGTH: usize = 1024;
+
+/// Characters AWS recommends avoiding in keys, as many tools and applications
+/// mishandle them.
+const AVOIDED_KEY_CHARS: &[char] = &[
+    '\\', '{', '}', '^', '%', '`', '[', ']', '"', '<', '>', '~', '#', '|',
+];
+
+/// Checks `bucket` against the rules S3 applies to the names of new buckets.
+///
+/// Names must be 3 to 63 characters long and consist of lowercase letters, digits,
+/// dots and hyphens. They must begin and end with a letter or digit, must not contain
+/// two adjacent dots, must not look like an IPv4 address, and must not start with
+/// `xn--` or end with `-s3alias`.
+///
+/// Buckets created in us-east-1 before March 2018 may have names that break these
+/// rules. Set `S3Config::server_side_validation_only` to use them.
+pub fn validate_bucket_name(bucket: &str) -> Result<(), String> {
+    let invalid = |reason: &str| Err(format!("Invalid bucket name {:?}: {}", bucket, reason));
+
+    if bucket.len() < 3 || bucket.len() > 63 {
+        return invalid("must be 3 to 63 characters long");
+    }
+    if let Some(c) = bucket
+        .chars()
+        .find(|c| !matches!(c, 'a'..='z' | '0'..='9' | '.' | '-'))
+    {
+        return invalid(&format!(
+            "{:?} is not allowed, only lowercase letters, digits, dots and hyphens are",
+            c
+        ));
+    }
+    let is_alphanumeric = |c: Option<char>| c.is_some_and(|c| c.is_ascii_alphanumeric());
+    if !is_alphanumeric(bucket.chars().next()) || !is_alphanumeric(bucket.chars().last()) {
+        return invalid("must begin and end with a letter or digit");
+    }
+    if bucket.contains("..") {
+        return invalid("must not contain two adjacent dots");
+    }
+    if bucket.parse::<Ipv4Addr>().is_ok() {
+        return invalid("must not be formatted as an IP address");
+    }
+    if bucket.starts_with("xn--") {
+        return invalid("must not start with \"xn--\"");
+    }
+    if bucket.ends_with("-s3alias") {
+        return invalid("must not end with \"-s3alias\"");
+    }
+    Ok(())
+}
+
+/// Checks that `key` is safe to use with S3 and the tools around it.
+///
+/// S3 itself accepts any UTF-8 key of up to 1024 bytes, but this also rejects keys
+/// with control characters, leading or trailing whitespace, `.` or `..` path
+/// segments or empty ones (`a//b`), and the characters AWS recommends avoiding:
+/// ``\ { } ^ % ` [ ] " < > ~ # |``. Use `sanitize_key` to turn a user-supplied
+/// name into a key that passes.
+pub fn validate_key(key: &str) -> Result<(), String> {
+    let invalid = |reason: &str| Err(format!("Invalid key {:?}: {}", key, reason));
+
+    if key.is_empty() || key.len() > MAX_KEY_LENGTH {
+        return invalid("must be 1 to 1024 bytes long");
+    }
+    if let Some(c) = key.chars().find(|c| c.is_control()) {
+        return invalid(&format!("contains the control character {:?}", c));
+    }
+    if key.trim() != key {
+        return invalid("must not begin or end with whitespace");
+    }
+    if let Some(c) = key.chars().find(|c| AVOIDED_KEY_CHARS.contains(c)) {
+        return invalid(&format!("contains {:?}, which should be avoided", c));
+    }
+    // The last segment may be empty, e.g. in `photos/`, which is a common way to
+    // represent a folder.
+    let segments: Vec<&str> = key.split('/').collect();
+    let (_, rest) = segments.split_last().expect("split yields a segment");
+    if rest.iter().any(|segment| segment.is_empty()) {
+        return invalid("must not contain empty path segments");
+    }
+    if segments
+        .iter()
+        .any(|segment| *segment == "." || *segment == "..")
+    {
+        return invalid("must not contain \".\" or \"..\" path segments");
+    }
+    Ok(())
+}
+
+/// Turns `key` into one that passes `validate_key`, or explains why it can't.
+///
+/// Control characters are removed, leading and trailing whitespace is trimmed,
+/// characters to avoid are replaced with `_`, and empty, `.` and `..` path segments
+/// are dropped. A trailing `/` is kept. Fails if nothing is left, or if the result is
+/// longer than 1024 bytes.
+pub fn sanitize_key(key: &str) -> Result<String, String> {
+    let cleaned: String = key
+        .chars()
+        .filter(|c| !c.is_control())
+        .map(|c| {
+            if AVOIDED_KEY_CHARS.contains(&c) {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    let cleaned = cleaned.trim();
+    let mut sanitized = cleaned
+        .split('/')
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty() && *segment != "." && *segment != "..")
+        .collect::<Vec<_>>()
+        .join("/");
+    if sanitized.is_empty() {
+        return Err(format!("Invalid key {:?}: no valid characters left", key));
+    }
+    if cleaned.ends_with('/') {
+        sanitized.push('/');
+    }
+    validate_key(&sanitized).map(|_| sanitized)
+}
+
+/// Checks the numeric query parameters of `request`, such as `max-keys`, against the
+/// range S3 accepts.
+pub(crate) fn validate_params(request: &SignedRequest) -> Result<(), String> {
+    for &(name, min, max) in NUMERIC_PARAMS {
+        let value = match request.params.get(name) {
+            Some(Some(value)) => value,
+            _ => continue,
+        };
+        match value.parse::<i64>() {
+            Ok(number) if number >= min && number <= max => {}
+            _ => {
+                return Err(format!(
+                    "Invalid {} {:?}: must be between {} and {}",
+                    name, value, min, max
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::param::{Params, ServiceParams};
+    use rusoto_core::Region;
+
+    #[test]
+    fn valid_bucket_names() {
+        for bucket in &["abc", "my-bucket", "my.bucket.2020", "0bucket9"] {
+            assert_eq!(validate_bucket_name(bucket), Ok(()), "{}", bucket);
+        }
+        assert_eq!(validate_bucket_name(&"a".repeat(63)), Ok(()));
+    }
+
+    #[test]
+    fn invalid_bucket_names() {
+        for bucket in &[
+            "ab",
+            "MyBucket",
+            "my_bucket",
+            "-bucket",
+            "bucket.",
+            "my..bucket",
+            "192.168.5.4",
+            "xn--bucket",
+            "bucket-s3alias",
+        ] {
+            assert!(validate_bucket_name(bucket).is_err(), "{}", bucket);
+        }
+        assert!(validate_bucket_name(&"a".repeat(64)).is_err());
+    }
+
+    #[test]
+    fn valid_keys() {
+        for key in &[
+            "a",
+            "photos/2020/beach.jpg",
+            "photos/",
+            "résumé (final).pdf",
+            "a.b..c",
+        ] {
+            assert_eq!(validate_key(key), Ok(()), "{}", key);
+        }
+        assert_eq!(validate_key(&"a".repeat(1024)), Ok(()));
+    }
+
+    #[test]
+    fn invalid_keys() {
+        for key in &[
+            "",
+            "/",
+            "tab\there",
+            " leading",
+            "trailing ",
+            "50%",
+            "a\\b",
+            "a//b",
+            "/absolute",
+            "../escape",
+            "a/./b",
+        ] {
+            assert!(validate_key(key).is_err(), "{}", key);
+        }
+        assert!(validate_key(&"a".repeat(1025)).is_err());
+        assert_eq!(
+            validate_key("a#b"),
+            Err("Invalid key \"a#b\": contains '#', which should be avoided".to_owned())
+        );
+    }
+
+    #[test]
+    fn sanitized_keys() {
+        assert_eq!(
+            sanitize_key("  /photos//./2020/../beach{1}.jpg\n"),
+            Ok("photos/2020/beach_1_.jpg".to_owned())
+        );
+        assert_eq!(
+            sanitize_key("photos / 2020/"),
+            Ok("photos/2020/".to_owned())
+        );
+        assert_eq!(sanitize_key("report.pdf"), Ok("report.pdf".to_owned()));
+        assert!(sanitize_key(" /../ ").is_err());
+        assert!(sanitize_key(&"a".repeat(1025)).is_err());
+    }
+
+    #[test]
+    fn numeric_params() {
+        let request_with = |name: &str, value: &str| {
+            let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket");
+            let mut params = Params::new();
+            params.put(name, value);
+            request.set_params(params);
+            request
+        };
+
+        assert_eq!(validate_params(&request_with("max-keys", "0")), Ok(()));
+        assert_eq!(
+            validate_params(&request_with("max-uploads", "1000")),
+            Ok(())
+        );
+        assert_eq!(validate_params(&request_with("prefix", "-1")), Ok(()));
+        assert_eq!(
+            validate_params(&request_with("max-keys", "-1")),
+            Err("Invalid max-keys \"-1\": must be between 0 and 2147483647".to_owned())
+        );
+        assert!(validate_params(&request_with("max-uploads", "1001")).is_err());
+        assert!(validate_params(&request_with("partNumber", "0")).is_err());
+        assert!(validate_params(&request_with("partNumber", "10001")).is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::VecDeque;
+
+use futures::stream::{self, Stream};
+use rusoto_core::RusotoError;
+
+use crate::generated::{ListObjectsV2Error, ListObjectsV2Request, Object, S3};
+
+/// An entry found by `walk`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WalkEntry {
+    /// An object stored under the walked prefix.
+    Object(Object),
+    /// A common prefix, i.e. a "folder" of keys sharing the walked prefix up to the
+    /// next delimiter. It includes the delimiter, e.g. `photos/2020/`.
+    CommonPrefix(String),
+}
+
+/// A prefix being listed, and how far the listing got.
+struct Listing {
+    prefix: String,
+    pending: VecDeque<WalkEntry>,
+    continuation_token: Option<String>,
+    exhausted: bool,
+}
+
+impl Listing {
+    fn new(prefix: String) -> Self {
+        Listing {
+            prefix,
+            pending: VecDeque::new(),
+            continuation_token: None,
+            exhausted: false,
+        }
+    }
+}
+
+/// Lists the objects and common prefixes under `prefix` in `bucket`, in key order,
+/// following continuation tokens as the stream is polled.
+///
+/// Keys are grouped into common prefixes at the first `delimiter` after `prefix`,
+/// like a directory listing. With `recursive`, the stream also descends depth-first
+/// into each common prefix right after yielding it, so `photos/` is followed by
+/// everything under it before `videos/`. An empty delimiter lists every key under
+/// `prefix` without grouping.
+///
+/// ```rust,no_run
+/// use futures::TryStreamExt;
+/// use rusoto_core::Region;
+/// use rusoto_s3::walk::{walk, WalkEntry};
+/// use rusoto_s3::S3Client;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = S3Client::new(Region::UsEast1);
+/// let mut entries = Box::pin(walk(&client, "my-bucket", "photos/", "/", true));
+/// while let Some(entry) = entries.try_next().await? {
+///     match entry {
+///         WalkEntry::CommonPrefix(prefix) => println!("{}", prefix),
+///         WalkEntry::Object(object) => println!("{:?}", object.key),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn walk<'a, C>(
+    client: &'a C,
+    bucket: &str,
+    prefix: &str,
+    delimiter: &str,
+    recursive: bool,
+) -> impl Stream<Item = Result<WalkEntry, RusotoError<ListObjectsV2Error>>> + 'a
+where
+    C: S3 + ?Sized,
+{
+    let bucket = bucket.to_owned();
+    let delimiter = if delimiter.is_empty() {
+        None
+    } else {
+        Some(delimiter.to_owned())
+    };
+    let listings = vec![Listing::new(prefix.to_owned())];
+
+    stream::try_unfold(listings, move |mut listings| {
+        let bucket = bucket.clone();
+        let delimiter = delimiter.clone();
+        async move {
+            loop {
+                let listing = match listings.last_mut() {
+                    Some(listing) => listing,
+                    None => return Ok(None),
+                };
+
+                if let Some(entry) = listing.pending.pop_front() {
+                    if let WalkEntry::CommonPrefix(ref prefix) = entry {
+                        // S3 never returns the listed prefix itself, but guard against
+                        // walking the same prefix forever.
+                        if recursive && *prefix != listing.prefix {
+                            listings.push(Listing::new(prefix.clone()));
+                        }
+                    }
+                    return Ok(Some((entry, listings)));
+                }
+
+                if listing.exhausted {
+                    listings.pop();
+                    continue;
+                }
+
+                let output = client
+                    .list_objects_v2(ListObjectsV2Request {
+                        bucket: bucket.clone(),
+                        continuation_token: listing.continuation_token.take(),
+                        delimiter: delimiter.clone(),
+                        prefix: Some(listing.prefix.clone()),
+                        ..Default::default()
+                    })
+                    .await?;
+
+                let mut entries: Vec<(String, WalkEntry)> = output
+                    .common_prefixes
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|common| common.prefix)
+                    .map(|prefix| (prefix.clone(), WalkEntry::CommonPrefix(prefix)))
+                    .chain(
+                        output
+                            .contents
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(|object| {
+                                (
+                                    object.key.clone().unwrap_or_default(),
+                                    WalkEntry::Object(object),
+                                )
+                            }),
+                    )
+                    .collect();
+                entries.sort_by(|a, b| a.0.cmp(&b.0));
+                listing.pending = entries.into_iter().map(|(_, entry)| entry).collect();
+
+                listing.continuation_token = output.next_continuation_token;
+                listing.exhausted =
+                    !output.is_truncated.unwrap_or(false) || listing.continuation_token.is_none();
+            }
+        }
+    })
+}
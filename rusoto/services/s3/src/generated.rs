@@ -17,14 +17,13 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
-use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    self as xml_util, deserialize_elements, find_start_element, skip_tree, write_characters_element,
+    self as xml_util, deserialize_elements, skip_tree, write_characters_element,
 };
-use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
+use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError};
 use rusoto_core::request::HttpResponse;
 use rusoto_core::signature::SignedRequest;
 #[cfg(feature = "deserialize_structs")]
@@ -34,15 +33,20 @@ use serde::Serialize;
 use std::io::Write;
 use std::str::FromStr;
 use xml;
-use xml::EventReader;
 use xml::EventWriter;
 
+use crate::custom::error::parse_error_response;
+use crate::custom::multipart::AutoMultipart;
+
 impl S3Client {
     async fn sign_and_dispatch<E>(
         &self,
         request: SignedRequest,
         from_response: fn(BufferedHttpResponse) -> RusotoError<E>,
     ) -> Result<HttpResponse, RusotoError<E>> {
+        self.config
+            .validate_request(&request)
+            .map_err(RusotoError::Validation)?;
         let mut response = self.client.sign_and_dispatch(request).await?;
         if !response.status.is_success() {
             let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
@@ -2838,6 +2842,8 @@ pub struct DeleteObjectRequest {
     pub bypass_governance_retention: Option<bool>,
     /// <p>The account id of the expected bucket owner. If the bucket is owned by a different account, the request will fail with an HTTP <code>403 (Access Denied)</code> error.</p>
     pub expected_bucket_owner: Option<String>,
+    /// <p>Deletes the object only if its entity tag (ETag) is the same as the one specified, otherwise returns a 412 (precondition failed).</p>
+    pub if_match: Option<String>,
     /// <p>Key name of the object to delete.</p>
     pub key: String,
     /// <p>The concatenation of the authentication device's serial number, a space, and the value that is displayed on your authentication device. Required to permanently delete a versioned object if versioning is configured with MFA delete enabled.</p>
@@ -15300,30 +15306,18 @@ pub enum AbortMultipartUploadError {
 
 impl AbortMultipartUploadError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<AbortMultipartUploadError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    "NoSuchUpload" => {
-                        return RusotoError::Service(AbortMultipartUploadError::NoSuchUpload(
-                            parsed_error.message,
-                        ))
-                    }
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                "NoSuchUpload" => {
+                    return RusotoError::Service(AbortMultipartUploadError::NoSuchUpload(
+                        parsed_error.message,
+                    ))
+                }
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for AbortMultipartUploadError {
     #[allow(unused_variables)]
@@ -15334,31 +15328,26 @@ impl fmt::Display for AbortMultipartUploadError {
     }
 }
 impl Error for AbortMultipartUploadError {}
+impl ServiceErrorCode for AbortMultipartUploadError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AbortMultipartUploadError::NoSuchUpload(_) => "NoSuchUpload",
+        }
+    }
+}
 /// Errors returned by CompleteMultipartUpload
 #[derive(Debug, PartialEq)]
 pub enum CompleteMultipartUploadError {}
 
 impl CompleteMultipartUploadError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CompleteMultipartUploadError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for CompleteMultipartUploadError {
     #[allow(unused_variables)]
@@ -15367,6 +15356,11 @@ impl fmt::Display for CompleteMultipartUploadError {
     }
 }
 impl Error for CompleteMultipartUploadError {}
+impl ServiceErrorCode for CompleteMultipartUploadError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CopyObject
 #[derive(Debug, PartialEq)]
 pub enum CopyObjectError {
@@ -15376,30 +15370,18 @@ pub enum CopyObjectError {
 
 impl CopyObjectError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CopyObjectError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    "ObjectNotInActiveTierError" => {
-                        return RusotoError::Service(CopyObjectError::ObjectNotInActiveTierError(
-                            parsed_error.message,
-                        ))
-                    }
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                "ObjectNotInActiveTierError" => {
+                    return RusotoError::Service(CopyObjectError::ObjectNotInActiveTierError(
+                        parsed_error.message,
+                    ))
+                }
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for CopyObjectError {
     #[allow(unused_variables)]
@@ -15410,6 +15392,13 @@ impl fmt::Display for CopyObjectError {
     }
 }
 impl Error for CopyObjectError {}
+impl ServiceErrorCode for CopyObjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyObjectError::ObjectNotInActiveTierError(_) => "ObjectNotInActiveTierError",
+        }
+    }
+}
 /// Errors returned by CreateBucket
 #[derive(Debug, PartialEq)]
 pub enum CreateBucketError {
@@ -15421,35 +15410,23 @@ pub enum CreateBucketError {
 
 impl CreateBucketError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateBucketError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    "BucketAlreadyExists" => {
-                        return RusotoError::Service(CreateBucketError::BucketAlreadyExists(
-                            parsed_error.message,
-                        ))
-                    }
-                    "BucketAlreadyOwnedByYou" => {
-                        return RusotoError::Service(CreateBucketError::BucketAlreadyOwnedByYou(
-                            parsed_error.message,
-                        ))
-                    }
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                "BucketAlreadyExists" => {
+                    return RusotoError::Service(CreateBucketError::BucketAlreadyExists(
+                        parsed_error.message,
+                    ))
+                }
+                "BucketAlreadyOwnedByYou" => {
+                    return RusotoError::Service(CreateBucketError::BucketAlreadyOwnedByYou(
+                        parsed_error.message,
+                    ))
+                }
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for CreateBucketError {
     #[allow(unused_variables)]
@@ -15461,31 +15438,27 @@ impl fmt::Display for CreateBucketError {
     }
 }
 impl Error for CreateBucketError {}
+impl ServiceErrorCode for CreateBucketError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateBucketError::BucketAlreadyExists(_) => "BucketAlreadyExists",
+            CreateBucketError::BucketAlreadyOwnedByYou(_) => "BucketAlreadyOwnedByYou",
+        }
+    }
+}
 /// Errors returned by CreateMultipartUpload
 #[derive(Debug, PartialEq)]
 pub enum CreateMultipartUploadError {}
 
 impl CreateMultipartUploadError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateMultipartUploadError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for CreateMultipartUploadError {
     #[allow(unused_variables)]
@@ -15494,31 +15467,24 @@ impl fmt::Display for CreateMultipartUploadError {
     }
 }
 impl Error for CreateMultipartUploadError {}
+impl ServiceErrorCode for CreateMultipartUploadError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteBucket
 #[derive(Debug, PartialEq)]
 pub enum DeleteBucketError {}
 
 impl DeleteBucketError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteBucketError {
     #[allow(unused_variables)]
@@ -15527,6 +15493,11 @@ impl fmt::Display for DeleteBucketError {
     }
 }
 impl Error for DeleteBucketError {}
+impl ServiceErrorCode for DeleteBucketError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteBucketAnalyticsConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteBucketAnalyticsConfigurationError {}
@@ -15535,25 +15506,13 @@ impl DeleteBucketAnalyticsConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<DeleteBucketAnalyticsConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteBucketAnalyticsConfigurationError {
     #[allow(unused_variables)]
@@ -15562,31 +15521,24 @@ impl fmt::Display for DeleteBucketAnalyticsConfigurationError {
     }
 }
 impl Error for DeleteBucketAnalyticsConfigurationError {}
+impl ServiceErrorCode for DeleteBucketAnalyticsConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteBucketCors
 #[derive(Debug, PartialEq)]
 pub enum DeleteBucketCorsError {}
 
 impl DeleteBucketCorsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketCorsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteBucketCorsError {
     #[allow(unused_variables)]
@@ -15595,31 +15547,24 @@ impl fmt::Display for DeleteBucketCorsError {
     }
 }
 impl Error for DeleteBucketCorsError {}
+impl ServiceErrorCode for DeleteBucketCorsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteBucketEncryption
 #[derive(Debug, PartialEq)]
 pub enum DeleteBucketEncryptionError {}
 
 impl DeleteBucketEncryptionError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketEncryptionError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteBucketEncryptionError {
     #[allow(unused_variables)]
@@ -15628,6 +15573,11 @@ impl fmt::Display for DeleteBucketEncryptionError {
     }
 }
 impl Error for DeleteBucketEncryptionError {}
+impl ServiceErrorCode for DeleteBucketEncryptionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteBucketIntelligentTieringConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteBucketIntelligentTieringConfigurationError {}
@@ -15636,25 +15586,13 @@ impl DeleteBucketIntelligentTieringConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<DeleteBucketIntelligentTieringConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteBucketIntelligentTieringConfigurationError {
     #[allow(unused_variables)]
@@ -15663,6 +15601,11 @@ impl fmt::Display for DeleteBucketIntelligentTieringConfigurationError {
     }
 }
 impl Error for DeleteBucketIntelligentTieringConfigurationError {}
+impl ServiceErrorCode for DeleteBucketIntelligentTieringConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteBucketInventoryConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteBucketInventoryConfigurationError {}
@@ -15671,25 +15614,13 @@ impl DeleteBucketInventoryConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<DeleteBucketInventoryConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteBucketInventoryConfigurationError {
     #[allow(unused_variables)]
@@ -15698,31 +15629,24 @@ impl fmt::Display for DeleteBucketInventoryConfigurationError {
     }
 }
 impl Error for DeleteBucketInventoryConfigurationError {}
+impl ServiceErrorCode for DeleteBucketInventoryConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteBucketLifecycle
 #[derive(Debug, PartialEq)]
 pub enum DeleteBucketLifecycleError {}
 
 impl DeleteBucketLifecycleError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketLifecycleError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteBucketLifecycleError {
     #[allow(unused_variables)]
@@ -15731,6 +15655,11 @@ impl fmt::Display for DeleteBucketLifecycleError {
     }
 }
 impl Error for DeleteBucketLifecycleError {}
+impl ServiceErrorCode for DeleteBucketLifecycleError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteBucketMetricsConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteBucketMetricsConfigurationError {}
@@ -15739,25 +15668,13 @@ impl DeleteBucketMetricsConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<DeleteBucketMetricsConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteBucketMetricsConfigurationError {
     #[allow(unused_variables)]
@@ -15766,6 +15683,11 @@ impl fmt::Display for DeleteBucketMetricsConfigurationError {
     }
 }
 impl Error for DeleteBucketMetricsConfigurationError {}
+impl ServiceErrorCode for DeleteBucketMetricsConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteBucketOwnershipControls
 #[derive(Debug, PartialEq)]
 pub enum DeleteBucketOwnershipControlsError {}
@@ -15774,25 +15696,13 @@ impl DeleteBucketOwnershipControlsError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<DeleteBucketOwnershipControlsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteBucketOwnershipControlsError {
     #[allow(unused_variables)]
@@ -15801,31 +15711,24 @@ impl fmt::Display for DeleteBucketOwnershipControlsError {
     }
 }
 impl Error for DeleteBucketOwnershipControlsError {}
+impl ServiceErrorCode for DeleteBucketOwnershipControlsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteBucketPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteBucketPolicyError {}
 
 impl DeleteBucketPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketPolicyError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteBucketPolicyError {
     #[allow(unused_variables)]
@@ -15834,31 +15737,24 @@ impl fmt::Display for DeleteBucketPolicyError {
     }
 }
 impl Error for DeleteBucketPolicyError {}
+impl ServiceErrorCode for DeleteBucketPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteBucketReplication
 #[derive(Debug, PartialEq)]
 pub enum DeleteBucketReplicationError {}
 
 impl DeleteBucketReplicationError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketReplicationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteBucketReplicationError {
     #[allow(unused_variables)]
@@ -15867,31 +15763,24 @@ impl fmt::Display for DeleteBucketReplicationError {
     }
 }
 impl Error for DeleteBucketReplicationError {}
+impl ServiceErrorCode for DeleteBucketReplicationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteBucketTagging
 #[derive(Debug, PartialEq)]
 pub enum DeleteBucketTaggingError {}
 
 impl DeleteBucketTaggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketTaggingError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteBucketTaggingError {
     #[allow(unused_variables)]
@@ -15900,31 +15789,24 @@ impl fmt::Display for DeleteBucketTaggingError {
     }
 }
 impl Error for DeleteBucketTaggingError {}
+impl ServiceErrorCode for DeleteBucketTaggingError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteBucketWebsite
 #[derive(Debug, PartialEq)]
 pub enum DeleteBucketWebsiteError {}
 
 impl DeleteBucketWebsiteError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteBucketWebsiteError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteBucketWebsiteError {
     #[allow(unused_variables)]
@@ -15933,31 +15815,24 @@ impl fmt::Display for DeleteBucketWebsiteError {
     }
 }
 impl Error for DeleteBucketWebsiteError {}
+impl ServiceErrorCode for DeleteBucketWebsiteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteObject
 #[derive(Debug, PartialEq)]
 pub enum DeleteObjectError {}
 
 impl DeleteObjectError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteObjectError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteObjectError {
     #[allow(unused_variables)]
@@ -15966,31 +15841,24 @@ impl fmt::Display for DeleteObjectError {
     }
 }
 impl Error for DeleteObjectError {}
+impl ServiceErrorCode for DeleteObjectError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteObjectTagging
 #[derive(Debug, PartialEq)]
 pub enum DeleteObjectTaggingError {}
 
 impl DeleteObjectTaggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteObjectTaggingError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteObjectTaggingError {
     #[allow(unused_variables)]
@@ -15999,31 +15867,24 @@ impl fmt::Display for DeleteObjectTaggingError {
     }
 }
 impl Error for DeleteObjectTaggingError {}
+impl ServiceErrorCode for DeleteObjectTaggingError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteObjects
 #[derive(Debug, PartialEq)]
 pub enum DeleteObjectsError {}
 
 impl DeleteObjectsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteObjectsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeleteObjectsError {
     #[allow(unused_variables)]
@@ -16032,31 +15893,24 @@ impl fmt::Display for DeleteObjectsError {
     }
 }
 impl Error for DeleteObjectsError {}
+impl ServiceErrorCode for DeleteObjectsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeletePublicAccessBlock
 #[derive(Debug, PartialEq)]
 pub enum DeletePublicAccessBlockError {}
 
 impl DeletePublicAccessBlockError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeletePublicAccessBlockError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for DeletePublicAccessBlockError {
     #[allow(unused_variables)]
@@ -16065,6 +15919,11 @@ impl fmt::Display for DeletePublicAccessBlockError {
     }
 }
 impl Error for DeletePublicAccessBlockError {}
+impl ServiceErrorCode for DeletePublicAccessBlockError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketAccelerateConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetBucketAccelerateConfigurationError {}
@@ -16073,25 +15932,13 @@ impl GetBucketAccelerateConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<GetBucketAccelerateConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketAccelerateConfigurationError {
     #[allow(unused_variables)]
@@ -16100,31 +15947,24 @@ impl fmt::Display for GetBucketAccelerateConfigurationError {
     }
 }
 impl Error for GetBucketAccelerateConfigurationError {}
+impl ServiceErrorCode for GetBucketAccelerateConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketAcl
 #[derive(Debug, PartialEq)]
 pub enum GetBucketAclError {}
 
 impl GetBucketAclError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketAclError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketAclError {
     #[allow(unused_variables)]
@@ -16133,6 +15973,11 @@ impl fmt::Display for GetBucketAclError {
     }
 }
 impl Error for GetBucketAclError {}
+impl ServiceErrorCode for GetBucketAclError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketAnalyticsConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetBucketAnalyticsConfigurationError {}
@@ -16141,25 +15986,13 @@ impl GetBucketAnalyticsConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<GetBucketAnalyticsConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketAnalyticsConfigurationError {
     #[allow(unused_variables)]
@@ -16168,31 +16001,24 @@ impl fmt::Display for GetBucketAnalyticsConfigurationError {
     }
 }
 impl Error for GetBucketAnalyticsConfigurationError {}
+impl ServiceErrorCode for GetBucketAnalyticsConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketCors
 #[derive(Debug, PartialEq)]
 pub enum GetBucketCorsError {}
 
 impl GetBucketCorsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketCorsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketCorsError {
     #[allow(unused_variables)]
@@ -16201,31 +16027,24 @@ impl fmt::Display for GetBucketCorsError {
     }
 }
 impl Error for GetBucketCorsError {}
+impl ServiceErrorCode for GetBucketCorsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketEncryption
 #[derive(Debug, PartialEq)]
 pub enum GetBucketEncryptionError {}
 
 impl GetBucketEncryptionError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketEncryptionError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketEncryptionError {
     #[allow(unused_variables)]
@@ -16234,6 +16053,11 @@ impl fmt::Display for GetBucketEncryptionError {
     }
 }
 impl Error for GetBucketEncryptionError {}
+impl ServiceErrorCode for GetBucketEncryptionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketIntelligentTieringConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetBucketIntelligentTieringConfigurationError {}
@@ -16242,25 +16066,13 @@ impl GetBucketIntelligentTieringConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<GetBucketIntelligentTieringConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketIntelligentTieringConfigurationError {
     #[allow(unused_variables)]
@@ -16269,6 +16081,11 @@ impl fmt::Display for GetBucketIntelligentTieringConfigurationError {
     }
 }
 impl Error for GetBucketIntelligentTieringConfigurationError {}
+impl ServiceErrorCode for GetBucketIntelligentTieringConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketInventoryConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetBucketInventoryConfigurationError {}
@@ -16277,25 +16094,13 @@ impl GetBucketInventoryConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<GetBucketInventoryConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketInventoryConfigurationError {
     #[allow(unused_variables)]
@@ -16304,31 +16109,24 @@ impl fmt::Display for GetBucketInventoryConfigurationError {
     }
 }
 impl Error for GetBucketInventoryConfigurationError {}
+impl ServiceErrorCode for GetBucketInventoryConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketLifecycle
 #[derive(Debug, PartialEq)]
 pub enum GetBucketLifecycleError {}
 
 impl GetBucketLifecycleError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketLifecycleError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketLifecycleError {
     #[allow(unused_variables)]
@@ -16337,6 +16135,11 @@ impl fmt::Display for GetBucketLifecycleError {
     }
 }
 impl Error for GetBucketLifecycleError {}
+impl ServiceErrorCode for GetBucketLifecycleError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketLifecycleConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetBucketLifecycleConfigurationError {}
@@ -16345,25 +16148,13 @@ impl GetBucketLifecycleConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<GetBucketLifecycleConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketLifecycleConfigurationError {
     #[allow(unused_variables)]
@@ -16372,31 +16163,24 @@ impl fmt::Display for GetBucketLifecycleConfigurationError {
     }
 }
 impl Error for GetBucketLifecycleConfigurationError {}
-/// Errors returned by GetBucketLocation
+impl ServiceErrorCode for GetBucketLifecycleConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
+/// Errors returned by GetBucketLocation
 #[derive(Debug, PartialEq)]
 pub enum GetBucketLocationError {}
 
 impl GetBucketLocationError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketLocationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketLocationError {
     #[allow(unused_variables)]
@@ -16405,31 +16189,24 @@ impl fmt::Display for GetBucketLocationError {
     }
 }
 impl Error for GetBucketLocationError {}
+impl ServiceErrorCode for GetBucketLocationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketLogging
 #[derive(Debug, PartialEq)]
 pub enum GetBucketLoggingError {}
 
 impl GetBucketLoggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketLoggingError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketLoggingError {
     #[allow(unused_variables)]
@@ -16438,6 +16215,11 @@ impl fmt::Display for GetBucketLoggingError {
     }
 }
 impl Error for GetBucketLoggingError {}
+impl ServiceErrorCode for GetBucketLoggingError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketMetricsConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetBucketMetricsConfigurationError {}
@@ -16446,25 +16228,13 @@ impl GetBucketMetricsConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<GetBucketMetricsConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketMetricsConfigurationError {
     #[allow(unused_variables)]
@@ -16473,31 +16243,24 @@ impl fmt::Display for GetBucketMetricsConfigurationError {
     }
 }
 impl Error for GetBucketMetricsConfigurationError {}
+impl ServiceErrorCode for GetBucketMetricsConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketNotification
 #[derive(Debug, PartialEq)]
 pub enum GetBucketNotificationError {}
 
 impl GetBucketNotificationError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketNotificationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketNotificationError {
     #[allow(unused_variables)]
@@ -16506,6 +16269,11 @@ impl fmt::Display for GetBucketNotificationError {
     }
 }
 impl Error for GetBucketNotificationError {}
+impl ServiceErrorCode for GetBucketNotificationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketNotificationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetBucketNotificationConfigurationError {}
@@ -16514,25 +16282,13 @@ impl GetBucketNotificationConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<GetBucketNotificationConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketNotificationConfigurationError {
     #[allow(unused_variables)]
@@ -16541,6 +16297,11 @@ impl fmt::Display for GetBucketNotificationConfigurationError {
     }
 }
 impl Error for GetBucketNotificationConfigurationError {}
+impl ServiceErrorCode for GetBucketNotificationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketOwnershipControls
 #[derive(Debug, PartialEq)]
 pub enum GetBucketOwnershipControlsError {}
@@ -16549,25 +16310,13 @@ impl GetBucketOwnershipControlsError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<GetBucketOwnershipControlsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketOwnershipControlsError {
     #[allow(unused_variables)]
@@ -16576,31 +16325,24 @@ impl fmt::Display for GetBucketOwnershipControlsError {
     }
 }
 impl Error for GetBucketOwnershipControlsError {}
+impl ServiceErrorCode for GetBucketOwnershipControlsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetBucketPolicyError {}
 
 impl GetBucketPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketPolicyError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketPolicyError {
     #[allow(unused_variables)]
@@ -16609,31 +16351,24 @@ impl fmt::Display for GetBucketPolicyError {
     }
 }
 impl Error for GetBucketPolicyError {}
+impl ServiceErrorCode for GetBucketPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketPolicyStatus
 #[derive(Debug, PartialEq)]
 pub enum GetBucketPolicyStatusError {}
 
 impl GetBucketPolicyStatusError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketPolicyStatusError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketPolicyStatusError {
     #[allow(unused_variables)]
@@ -16642,31 +16377,24 @@ impl fmt::Display for GetBucketPolicyStatusError {
     }
 }
 impl Error for GetBucketPolicyStatusError {}
+impl ServiceErrorCode for GetBucketPolicyStatusError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketReplication
 #[derive(Debug, PartialEq)]
 pub enum GetBucketReplicationError {}
 
 impl GetBucketReplicationError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketReplicationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketReplicationError {
     #[allow(unused_variables)]
@@ -16675,31 +16403,24 @@ impl fmt::Display for GetBucketReplicationError {
     }
 }
 impl Error for GetBucketReplicationError {}
+impl ServiceErrorCode for GetBucketReplicationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketRequestPayment
 #[derive(Debug, PartialEq)]
 pub enum GetBucketRequestPaymentError {}
 
 impl GetBucketRequestPaymentError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketRequestPaymentError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketRequestPaymentError {
     #[allow(unused_variables)]
@@ -16708,31 +16429,24 @@ impl fmt::Display for GetBucketRequestPaymentError {
     }
 }
 impl Error for GetBucketRequestPaymentError {}
+impl ServiceErrorCode for GetBucketRequestPaymentError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketTagging
 #[derive(Debug, PartialEq)]
 pub enum GetBucketTaggingError {}
 
 impl GetBucketTaggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketTaggingError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketTaggingError {
     #[allow(unused_variables)]
@@ -16741,31 +16455,24 @@ impl fmt::Display for GetBucketTaggingError {
     }
 }
 impl Error for GetBucketTaggingError {}
+impl ServiceErrorCode for GetBucketTaggingError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketVersioning
 #[derive(Debug, PartialEq)]
 pub enum GetBucketVersioningError {}
 
 impl GetBucketVersioningError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketVersioningError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketVersioningError {
     #[allow(unused_variables)]
@@ -16774,31 +16481,24 @@ impl fmt::Display for GetBucketVersioningError {
     }
 }
 impl Error for GetBucketVersioningError {}
+impl ServiceErrorCode for GetBucketVersioningError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetBucketWebsite
 #[derive(Debug, PartialEq)]
 pub enum GetBucketWebsiteError {}
 
 impl GetBucketWebsiteError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBucketWebsiteError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetBucketWebsiteError {
     #[allow(unused_variables)]
@@ -16807,6 +16507,11 @@ impl fmt::Display for GetBucketWebsiteError {
     }
 }
 impl Error for GetBucketWebsiteError {}
+impl ServiceErrorCode for GetBucketWebsiteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetObject
 #[derive(Debug, PartialEq)]
 pub enum GetObjectError {
@@ -16818,35 +16523,21 @@ pub enum GetObjectError {
 
 impl GetObjectError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetObjectError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    "InvalidObjectState" => {
-                        return RusotoError::Service(GetObjectError::InvalidObjectState(
-                            parsed_error.message,
-                        ))
-                    }
-                    "NoSuchKey" => {
-                        return RusotoError::Service(GetObjectError::NoSuchKey(
-                            parsed_error.message,
-                        ))
-                    }
-                    _ => {}
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                "InvalidObjectState" => {
+                    return RusotoError::Service(GetObjectError::InvalidObjectState(
+                        parsed_error.message,
+                    ))
+                }
+                "NoSuchKey" => {
+                    return RusotoError::Service(GetObjectError::NoSuchKey(parsed_error.message))
                 }
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetObjectError {
     #[allow(unused_variables)]
@@ -16858,6 +16549,14 @@ impl fmt::Display for GetObjectError {
     }
 }
 impl Error for GetObjectError {}
+impl ServiceErrorCode for GetObjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetObjectError::InvalidObjectState(_) => "InvalidObjectState",
+            GetObjectError::NoSuchKey(_) => "NoSuchKey",
+        }
+    }
+}
 /// Errors returned by GetObjectAcl
 #[derive(Debug, PartialEq)]
 pub enum GetObjectAclError {
@@ -16867,30 +16566,16 @@ pub enum GetObjectAclError {
 
 impl GetObjectAclError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetObjectAclError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    "NoSuchKey" => {
-                        return RusotoError::Service(GetObjectAclError::NoSuchKey(
-                            parsed_error.message,
-                        ))
-                    }
-                    _ => {}
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                "NoSuchKey" => {
+                    return RusotoError::Service(GetObjectAclError::NoSuchKey(parsed_error.message))
                 }
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetObjectAclError {
     #[allow(unused_variables)]
@@ -16901,31 +16586,26 @@ impl fmt::Display for GetObjectAclError {
     }
 }
 impl Error for GetObjectAclError {}
+impl ServiceErrorCode for GetObjectAclError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetObjectAclError::NoSuchKey(_) => "NoSuchKey",
+        }
+    }
+}
 /// Errors returned by GetObjectLegalHold
 #[derive(Debug, PartialEq)]
 pub enum GetObjectLegalHoldError {}
 
 impl GetObjectLegalHoldError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetObjectLegalHoldError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetObjectLegalHoldError {
     #[allow(unused_variables)]
@@ -16934,6 +16614,11 @@ impl fmt::Display for GetObjectLegalHoldError {
     }
 }
 impl Error for GetObjectLegalHoldError {}
+impl ServiceErrorCode for GetObjectLegalHoldError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetObjectLockConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetObjectLockConfigurationError {}
@@ -16942,25 +16627,13 @@ impl GetObjectLockConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<GetObjectLockConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetObjectLockConfigurationError {
     #[allow(unused_variables)]
@@ -16969,31 +16642,24 @@ impl fmt::Display for GetObjectLockConfigurationError {
     }
 }
 impl Error for GetObjectLockConfigurationError {}
+impl ServiceErrorCode for GetObjectLockConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetObjectRetention
 #[derive(Debug, PartialEq)]
 pub enum GetObjectRetentionError {}
 
 impl GetObjectRetentionError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetObjectRetentionError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetObjectRetentionError {
     #[allow(unused_variables)]
@@ -17002,31 +16668,24 @@ impl fmt::Display for GetObjectRetentionError {
     }
 }
 impl Error for GetObjectRetentionError {}
+impl ServiceErrorCode for GetObjectRetentionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetObjectTagging
 #[derive(Debug, PartialEq)]
 pub enum GetObjectTaggingError {}
 
 impl GetObjectTaggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetObjectTaggingError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetObjectTaggingError {
     #[allow(unused_variables)]
@@ -17035,31 +16694,24 @@ impl fmt::Display for GetObjectTaggingError {
     }
 }
 impl Error for GetObjectTaggingError {}
+impl ServiceErrorCode for GetObjectTaggingError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetObjectTorrent
 #[derive(Debug, PartialEq)]
 pub enum GetObjectTorrentError {}
 
 impl GetObjectTorrentError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetObjectTorrentError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetObjectTorrentError {
     #[allow(unused_variables)]
@@ -17068,31 +16720,24 @@ impl fmt::Display for GetObjectTorrentError {
     }
 }
 impl Error for GetObjectTorrentError {}
+impl ServiceErrorCode for GetObjectTorrentError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetPublicAccessBlock
 #[derive(Debug, PartialEq)]
 pub enum GetPublicAccessBlockError {}
 
 impl GetPublicAccessBlockError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetPublicAccessBlockError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for GetPublicAccessBlockError {
     #[allow(unused_variables)]
@@ -17101,6 +16746,11 @@ impl fmt::Display for GetPublicAccessBlockError {
     }
 }
 impl Error for GetPublicAccessBlockError {}
+impl ServiceErrorCode for GetPublicAccessBlockError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by HeadBucket
 #[derive(Debug, PartialEq)]
 pub enum HeadBucketError {
@@ -17110,30 +16760,18 @@ pub enum HeadBucketError {
 
 impl HeadBucketError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<HeadBucketError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    "NoSuchBucket" => {
-                        return RusotoError::Service(HeadBucketError::NoSuchBucket(
-                            parsed_error.message,
-                        ))
-                    }
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                "NoSuchBucket" => {
+                    return RusotoError::Service(HeadBucketError::NoSuchBucket(
+                        parsed_error.message,
+                    ))
+                }
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for HeadBucketError {
     #[allow(unused_variables)]
@@ -17144,6 +16782,13 @@ impl fmt::Display for HeadBucketError {
     }
 }
 impl Error for HeadBucketError {}
+impl ServiceErrorCode for HeadBucketError {
+    fn error_code(&self) -> &str {
+        match *self {
+            HeadBucketError::NoSuchBucket(_) => "NoSuchBucket",
+        }
+    }
+}
 /// Errors returned by HeadObject
 #[derive(Debug, PartialEq)]
 pub enum HeadObjectError {
@@ -17153,30 +16798,16 @@ pub enum HeadObjectError {
 
 impl HeadObjectError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<HeadObjectError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    "NoSuchKey" => {
-                        return RusotoError::Service(HeadObjectError::NoSuchKey(
-                            parsed_error.message,
-                        ))
-                    }
-                    _ => {}
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                "NoSuchKey" => {
+                    return RusotoError::Service(HeadObjectError::NoSuchKey(parsed_error.message))
                 }
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for HeadObjectError {
     #[allow(unused_variables)]
@@ -17187,6 +16818,13 @@ impl fmt::Display for HeadObjectError {
     }
 }
 impl Error for HeadObjectError {}
+impl ServiceErrorCode for HeadObjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            HeadObjectError::NoSuchKey(_) => "NoSuchKey",
+        }
+    }
+}
 /// Errors returned by ListBucketAnalyticsConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListBucketAnalyticsConfigurationsError {}
@@ -17195,25 +16833,13 @@ impl ListBucketAnalyticsConfigurationsError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<ListBucketAnalyticsConfigurationsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for ListBucketAnalyticsConfigurationsError {
     #[allow(unused_variables)]
@@ -17222,6 +16848,11 @@ impl fmt::Display for ListBucketAnalyticsConfigurationsError {
     }
 }
 impl Error for ListBucketAnalyticsConfigurationsError {}
+impl ServiceErrorCode for ListBucketAnalyticsConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListBucketIntelligentTieringConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListBucketIntelligentTieringConfigurationsError {}
@@ -17230,25 +16861,13 @@ impl ListBucketIntelligentTieringConfigurationsError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<ListBucketIntelligentTieringConfigurationsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for ListBucketIntelligentTieringConfigurationsError {
     #[allow(unused_variables)]
@@ -17257,6 +16876,11 @@ impl fmt::Display for ListBucketIntelligentTieringConfigurationsError {
     }
 }
 impl Error for ListBucketIntelligentTieringConfigurationsError {}
+impl ServiceErrorCode for ListBucketIntelligentTieringConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListBucketInventoryConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListBucketInventoryConfigurationsError {}
@@ -17265,25 +16889,13 @@ impl ListBucketInventoryConfigurationsError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<ListBucketInventoryConfigurationsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for ListBucketInventoryConfigurationsError {
     #[allow(unused_variables)]
@@ -17292,6 +16904,11 @@ impl fmt::Display for ListBucketInventoryConfigurationsError {
     }
 }
 impl Error for ListBucketInventoryConfigurationsError {}
+impl ServiceErrorCode for ListBucketInventoryConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListBucketMetricsConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListBucketMetricsConfigurationsError {}
@@ -17300,25 +16917,13 @@ impl ListBucketMetricsConfigurationsError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<ListBucketMetricsConfigurationsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for ListBucketMetricsConfigurationsError {
     #[allow(unused_variables)]
@@ -17327,31 +16932,24 @@ impl fmt::Display for ListBucketMetricsConfigurationsError {
     }
 }
 impl Error for ListBucketMetricsConfigurationsError {}
+impl ServiceErrorCode for ListBucketMetricsConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListBuckets
 #[derive(Debug, PartialEq)]
 pub enum ListBucketsError {}
 
 impl ListBucketsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListBucketsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for ListBucketsError {
     #[allow(unused_variables)]
@@ -17360,31 +16958,24 @@ impl fmt::Display for ListBucketsError {
     }
 }
 impl Error for ListBucketsError {}
+impl ServiceErrorCode for ListBucketsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListMultipartUploads
 #[derive(Debug, PartialEq)]
 pub enum ListMultipartUploadsError {}
 
 impl ListMultipartUploadsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListMultipartUploadsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for ListMultipartUploadsError {
     #[allow(unused_variables)]
@@ -17393,31 +16984,24 @@ impl fmt::Display for ListMultipartUploadsError {
     }
 }
 impl Error for ListMultipartUploadsError {}
+impl ServiceErrorCode for ListMultipartUploadsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListObjectVersions
 #[derive(Debug, PartialEq)]
 pub enum ListObjectVersionsError {}
 
 impl ListObjectVersionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListObjectVersionsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for ListObjectVersionsError {
     #[allow(unused_variables)]
@@ -17426,6 +17010,11 @@ impl fmt::Display for ListObjectVersionsError {
     }
 }
 impl Error for ListObjectVersionsError {}
+impl ServiceErrorCode for ListObjectVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListObjects
 #[derive(Debug, PartialEq)]
 pub enum ListObjectsError {
@@ -17435,30 +17024,18 @@ pub enum ListObjectsError {
 
 impl ListObjectsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListObjectsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    "NoSuchBucket" => {
-                        return RusotoError::Service(ListObjectsError::NoSuchBucket(
-                            parsed_error.message,
-                        ))
-                    }
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                "NoSuchBucket" => {
+                    return RusotoError::Service(ListObjectsError::NoSuchBucket(
+                        parsed_error.message,
+                    ))
+                }
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for ListObjectsError {
     #[allow(unused_variables)]
@@ -17469,6 +17046,13 @@ impl fmt::Display for ListObjectsError {
     }
 }
 impl Error for ListObjectsError {}
+impl ServiceErrorCode for ListObjectsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListObjectsError::NoSuchBucket(_) => "NoSuchBucket",
+        }
+    }
+}
 /// Errors returned by ListObjectsV2
 #[derive(Debug, PartialEq)]
 pub enum ListObjectsV2Error {
@@ -17478,30 +17062,18 @@ pub enum ListObjectsV2Error {
 
 impl ListObjectsV2Error {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListObjectsV2Error> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    "NoSuchBucket" => {
-                        return RusotoError::Service(ListObjectsV2Error::NoSuchBucket(
-                            parsed_error.message,
-                        ))
-                    }
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                "NoSuchBucket" => {
+                    return RusotoError::Service(ListObjectsV2Error::NoSuchBucket(
+                        parsed_error.message,
+                    ))
+                }
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for ListObjectsV2Error {
     #[allow(unused_variables)]
@@ -17512,31 +17084,26 @@ impl fmt::Display for ListObjectsV2Error {
     }
 }
 impl Error for ListObjectsV2Error {}
+impl ServiceErrorCode for ListObjectsV2Error {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListObjectsV2Error::NoSuchBucket(_) => "NoSuchBucket",
+        }
+    }
+}
 /// Errors returned by ListParts
 #[derive(Debug, PartialEq)]
 pub enum ListPartsError {}
 
 impl ListPartsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListPartsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for ListPartsError {
     #[allow(unused_variables)]
@@ -17545,6 +17112,11 @@ impl fmt::Display for ListPartsError {
     }
 }
 impl Error for ListPartsError {}
+impl ServiceErrorCode for ListPartsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketAccelerateConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutBucketAccelerateConfigurationError {}
@@ -17553,25 +17125,13 @@ impl PutBucketAccelerateConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<PutBucketAccelerateConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketAccelerateConfigurationError {
     #[allow(unused_variables)]
@@ -17580,31 +17140,24 @@ impl fmt::Display for PutBucketAccelerateConfigurationError {
     }
 }
 impl Error for PutBucketAccelerateConfigurationError {}
+impl ServiceErrorCode for PutBucketAccelerateConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketAcl
 #[derive(Debug, PartialEq)]
 pub enum PutBucketAclError {}
 
 impl PutBucketAclError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketAclError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketAclError {
     #[allow(unused_variables)]
@@ -17612,7 +17165,12 @@ impl fmt::Display for PutBucketAclError {
         match *self {}
     }
 }
-impl Error for PutBucketAclError {}
+impl Error for PutBucketAclError {}
+impl ServiceErrorCode for PutBucketAclError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketAnalyticsConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutBucketAnalyticsConfigurationError {}
@@ -17621,25 +17179,13 @@ impl PutBucketAnalyticsConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<PutBucketAnalyticsConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketAnalyticsConfigurationError {
     #[allow(unused_variables)]
@@ -17648,31 +17194,24 @@ impl fmt::Display for PutBucketAnalyticsConfigurationError {
     }
 }
 impl Error for PutBucketAnalyticsConfigurationError {}
+impl ServiceErrorCode for PutBucketAnalyticsConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketCors
 #[derive(Debug, PartialEq)]
 pub enum PutBucketCorsError {}
 
 impl PutBucketCorsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketCorsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketCorsError {
     #[allow(unused_variables)]
@@ -17681,31 +17220,24 @@ impl fmt::Display for PutBucketCorsError {
     }
 }
 impl Error for PutBucketCorsError {}
+impl ServiceErrorCode for PutBucketCorsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketEncryption
 #[derive(Debug, PartialEq)]
 pub enum PutBucketEncryptionError {}
 
 impl PutBucketEncryptionError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketEncryptionError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketEncryptionError {
     #[allow(unused_variables)]
@@ -17714,6 +17246,11 @@ impl fmt::Display for PutBucketEncryptionError {
     }
 }
 impl Error for PutBucketEncryptionError {}
+impl ServiceErrorCode for PutBucketEncryptionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketIntelligentTieringConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutBucketIntelligentTieringConfigurationError {}
@@ -17722,25 +17259,13 @@ impl PutBucketIntelligentTieringConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<PutBucketIntelligentTieringConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketIntelligentTieringConfigurationError {
     #[allow(unused_variables)]
@@ -17749,6 +17274,11 @@ impl fmt::Display for PutBucketIntelligentTieringConfigurationError {
     }
 }
 impl Error for PutBucketIntelligentTieringConfigurationError {}
+impl ServiceErrorCode for PutBucketIntelligentTieringConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketInventoryConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutBucketInventoryConfigurationError {}
@@ -17757,25 +17287,13 @@ impl PutBucketInventoryConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<PutBucketInventoryConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketInventoryConfigurationError {
     #[allow(unused_variables)]
@@ -17784,31 +17302,24 @@ impl fmt::Display for PutBucketInventoryConfigurationError {
     }
 }
 impl Error for PutBucketInventoryConfigurationError {}
+impl ServiceErrorCode for PutBucketInventoryConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketLifecycle
 #[derive(Debug, PartialEq)]
 pub enum PutBucketLifecycleError {}
 
 impl PutBucketLifecycleError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketLifecycleError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketLifecycleError {
     #[allow(unused_variables)]
@@ -17817,6 +17328,11 @@ impl fmt::Display for PutBucketLifecycleError {
     }
 }
 impl Error for PutBucketLifecycleError {}
+impl ServiceErrorCode for PutBucketLifecycleError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketLifecycleConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutBucketLifecycleConfigurationError {}
@@ -17825,25 +17341,13 @@ impl PutBucketLifecycleConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<PutBucketLifecycleConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketLifecycleConfigurationError {
     #[allow(unused_variables)]
@@ -17852,31 +17356,24 @@ impl fmt::Display for PutBucketLifecycleConfigurationError {
     }
 }
 impl Error for PutBucketLifecycleConfigurationError {}
+impl ServiceErrorCode for PutBucketLifecycleConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketLogging
 #[derive(Debug, PartialEq)]
 pub enum PutBucketLoggingError {}
 
 impl PutBucketLoggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketLoggingError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketLoggingError {
     #[allow(unused_variables)]
@@ -17885,6 +17382,11 @@ impl fmt::Display for PutBucketLoggingError {
     }
 }
 impl Error for PutBucketLoggingError {}
+impl ServiceErrorCode for PutBucketLoggingError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketMetricsConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutBucketMetricsConfigurationError {}
@@ -17893,25 +17395,13 @@ impl PutBucketMetricsConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<PutBucketMetricsConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketMetricsConfigurationError {
     #[allow(unused_variables)]
@@ -17920,31 +17410,24 @@ impl fmt::Display for PutBucketMetricsConfigurationError {
     }
 }
 impl Error for PutBucketMetricsConfigurationError {}
+impl ServiceErrorCode for PutBucketMetricsConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketNotification
 #[derive(Debug, PartialEq)]
 pub enum PutBucketNotificationError {}
 
 impl PutBucketNotificationError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketNotificationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketNotificationError {
     #[allow(unused_variables)]
@@ -17953,6 +17436,11 @@ impl fmt::Display for PutBucketNotificationError {
     }
 }
 impl Error for PutBucketNotificationError {}
+impl ServiceErrorCode for PutBucketNotificationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketNotificationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutBucketNotificationConfigurationError {}
@@ -17961,25 +17449,13 @@ impl PutBucketNotificationConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<PutBucketNotificationConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketNotificationConfigurationError {
     #[allow(unused_variables)]
@@ -17988,6 +17464,11 @@ impl fmt::Display for PutBucketNotificationConfigurationError {
     }
 }
 impl Error for PutBucketNotificationConfigurationError {}
+impl ServiceErrorCode for PutBucketNotificationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketOwnershipControls
 #[derive(Debug, PartialEq)]
 pub enum PutBucketOwnershipControlsError {}
@@ -17996,25 +17477,13 @@ impl PutBucketOwnershipControlsError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<PutBucketOwnershipControlsError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketOwnershipControlsError {
     #[allow(unused_variables)]
@@ -18023,31 +17492,24 @@ impl fmt::Display for PutBucketOwnershipControlsError {
     }
 }
 impl Error for PutBucketOwnershipControlsError {}
+impl ServiceErrorCode for PutBucketOwnershipControlsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutBucketPolicyError {}
 
 impl PutBucketPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketPolicyError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketPolicyError {
     #[allow(unused_variables)]
@@ -18056,31 +17518,24 @@ impl fmt::Display for PutBucketPolicyError {
     }
 }
 impl Error for PutBucketPolicyError {}
+impl ServiceErrorCode for PutBucketPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketReplication
 #[derive(Debug, PartialEq)]
 pub enum PutBucketReplicationError {}
 
 impl PutBucketReplicationError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketReplicationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketReplicationError {
     #[allow(unused_variables)]
@@ -18089,31 +17544,24 @@ impl fmt::Display for PutBucketReplicationError {
     }
 }
 impl Error for PutBucketReplicationError {}
+impl ServiceErrorCode for PutBucketReplicationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketRequestPayment
 #[derive(Debug, PartialEq)]
 pub enum PutBucketRequestPaymentError {}
 
 impl PutBucketRequestPaymentError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketRequestPaymentError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketRequestPaymentError {
     #[allow(unused_variables)]
@@ -18122,31 +17570,24 @@ impl fmt::Display for PutBucketRequestPaymentError {
     }
 }
 impl Error for PutBucketRequestPaymentError {}
+impl ServiceErrorCode for PutBucketRequestPaymentError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketTagging
 #[derive(Debug, PartialEq)]
 pub enum PutBucketTaggingError {}
 
 impl PutBucketTaggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketTaggingError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketTaggingError {
     #[allow(unused_variables)]
@@ -18155,31 +17596,24 @@ impl fmt::Display for PutBucketTaggingError {
     }
 }
 impl Error for PutBucketTaggingError {}
+impl ServiceErrorCode for PutBucketTaggingError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketVersioning
 #[derive(Debug, PartialEq)]
 pub enum PutBucketVersioningError {}
 
 impl PutBucketVersioningError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketVersioningError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketVersioningError {
     #[allow(unused_variables)]
@@ -18188,31 +17622,24 @@ impl fmt::Display for PutBucketVersioningError {
     }
 }
 impl Error for PutBucketVersioningError {}
+impl ServiceErrorCode for PutBucketVersioningError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutBucketWebsite
 #[derive(Debug, PartialEq)]
 pub enum PutBucketWebsiteError {}
 
 impl PutBucketWebsiteError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutBucketWebsiteError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutBucketWebsiteError {
     #[allow(unused_variables)]
@@ -18221,31 +17648,24 @@ impl fmt::Display for PutBucketWebsiteError {
     }
 }
 impl Error for PutBucketWebsiteError {}
+impl ServiceErrorCode for PutBucketWebsiteError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutObject
 #[derive(Debug, PartialEq)]
 pub enum PutObjectError {}
 
 impl PutObjectError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutObjectError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutObjectError {
     #[allow(unused_variables)]
@@ -18254,6 +17674,11 @@ impl fmt::Display for PutObjectError {
     }
 }
 impl Error for PutObjectError {}
+impl ServiceErrorCode for PutObjectError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutObjectAcl
 #[derive(Debug, PartialEq)]
 pub enum PutObjectAclError {
@@ -18263,30 +17688,16 @@ pub enum PutObjectAclError {
 
 impl PutObjectAclError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutObjectAclError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    "NoSuchKey" => {
-                        return RusotoError::Service(PutObjectAclError::NoSuchKey(
-                            parsed_error.message,
-                        ))
-                    }
-                    _ => {}
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                "NoSuchKey" => {
+                    return RusotoError::Service(PutObjectAclError::NoSuchKey(parsed_error.message))
                 }
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutObjectAclError {
     #[allow(unused_variables)]
@@ -18297,31 +17708,26 @@ impl fmt::Display for PutObjectAclError {
     }
 }
 impl Error for PutObjectAclError {}
+impl ServiceErrorCode for PutObjectAclError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutObjectAclError::NoSuchKey(_) => "NoSuchKey",
+        }
+    }
+}
 /// Errors returned by PutObjectLegalHold
 #[derive(Debug, PartialEq)]
 pub enum PutObjectLegalHoldError {}
 
 impl PutObjectLegalHoldError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutObjectLegalHoldError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutObjectLegalHoldError {
     #[allow(unused_variables)]
@@ -18330,6 +17736,11 @@ impl fmt::Display for PutObjectLegalHoldError {
     }
 }
 impl Error for PutObjectLegalHoldError {}
+impl ServiceErrorCode for PutObjectLegalHoldError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutObjectLockConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutObjectLockConfigurationError {}
@@ -18338,25 +17749,13 @@ impl PutObjectLockConfigurationError {
     pub fn from_response(
         res: BufferedHttpResponse,
     ) -> RusotoError<PutObjectLockConfigurationError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutObjectLockConfigurationError {
     #[allow(unused_variables)]
@@ -18365,31 +17764,24 @@ impl fmt::Display for PutObjectLockConfigurationError {
     }
 }
 impl Error for PutObjectLockConfigurationError {}
+impl ServiceErrorCode for PutObjectLockConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutObjectRetention
 #[derive(Debug, PartialEq)]
 pub enum PutObjectRetentionError {}
 
 impl PutObjectRetentionError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutObjectRetentionError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutObjectRetentionError {
     #[allow(unused_variables)]
@@ -18398,31 +17790,24 @@ impl fmt::Display for PutObjectRetentionError {
     }
 }
 impl Error for PutObjectRetentionError {}
+impl ServiceErrorCode for PutObjectRetentionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutObjectTagging
 #[derive(Debug, PartialEq)]
 pub enum PutObjectTaggingError {}
 
 impl PutObjectTaggingError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutObjectTaggingError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutObjectTaggingError {
     #[allow(unused_variables)]
@@ -18431,31 +17816,24 @@ impl fmt::Display for PutObjectTaggingError {
     }
 }
 impl Error for PutObjectTaggingError {}
+impl ServiceErrorCode for PutObjectTaggingError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutPublicAccessBlock
 #[derive(Debug, PartialEq)]
 pub enum PutPublicAccessBlockError {}
 
 impl PutPublicAccessBlockError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutPublicAccessBlockError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for PutPublicAccessBlockError {
     #[allow(unused_variables)]
@@ -18464,6 +17842,11 @@ impl fmt::Display for PutPublicAccessBlockError {
     }
 }
 impl Error for PutPublicAccessBlockError {}
+impl ServiceErrorCode for PutPublicAccessBlockError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RestoreObject
 #[derive(Debug, PartialEq)]
 pub enum RestoreObjectError {
@@ -18473,32 +17856,18 @@ pub enum RestoreObjectError {
 
 impl RestoreObjectError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RestoreObjectError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    "ObjectAlreadyInActiveTierError" => {
-                        return RusotoError::Service(
-                            RestoreObjectError::ObjectAlreadyInActiveTierError(
-                                parsed_error.message,
-                            ),
-                        )
-                    }
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                "ObjectAlreadyInActiveTierError" => {
+                    return RusotoError::Service(
+                        RestoreObjectError::ObjectAlreadyInActiveTierError(parsed_error.message),
+                    )
+                }
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for RestoreObjectError {
     #[allow(unused_variables)]
@@ -18509,31 +17878,28 @@ impl fmt::Display for RestoreObjectError {
     }
 }
 impl Error for RestoreObjectError {}
+impl ServiceErrorCode for RestoreObjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreObjectError::ObjectAlreadyInActiveTierError(_) => {
+                "ObjectAlreadyInActiveTierError"
+            }
+        }
+    }
+}
 /// Errors returned by SelectObjectContent
 #[derive(Debug, PartialEq)]
 pub enum SelectObjectContentError {}
 
 impl SelectObjectContentError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SelectObjectContentError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for SelectObjectContentError {
     #[allow(unused_variables)]
@@ -18542,31 +17908,24 @@ impl fmt::Display for SelectObjectContentError {
     }
 }
 impl Error for SelectObjectContentError {}
+impl ServiceErrorCode for SelectObjectContentError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UploadPart
 #[derive(Debug, PartialEq)]
 pub enum UploadPartError {}
 
 impl UploadPartError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UploadPartError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for UploadPartError {
     #[allow(unused_variables)]
@@ -18575,31 +17934,24 @@ impl fmt::Display for UploadPartError {
     }
 }
 impl Error for UploadPartError {}
+impl ServiceErrorCode for UploadPartError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UploadPartCopy
 #[derive(Debug, PartialEq)]
 pub enum UploadPartCopyError {}
 
 impl UploadPartCopyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UploadPartCopyError> {
-        {
-            let reader = EventReader::new(res.body.as_ref());
-            let mut stack = XmlResponse::new(reader.into_iter().peekable());
-            find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+        if let Some(parsed_error) = parse_error_response(&res) {
+            match &parsed_error.code[..] {
+                _ => {}
             }
         }
         RusotoError::Unknown(res)
     }
-
-    fn deserialize<T>(stack: &mut T) -> Result<XmlError, XmlParseError>
-    where
-        T: Peek + Next,
-    {
-        XmlErrorDeserializer::deserialize("Error", stack)
-    }
 }
 impl fmt::Display for UploadPartCopyError {
     #[allow(unused_variables)]
@@ -18608,6 +17960,11 @@ impl fmt::Display for UploadPartCopyError {
     }
 }
 impl Error for UploadPartCopyError {}
+impl ServiceErrorCode for UploadPartCopyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Trait representing the capabilities of the Amazon S3 API. Amazon S3 clients implement this trait.
 #[async_trait]
 pub trait S3 {
@@ -19206,7 +18563,7 @@ pub trait S3 {
     ) -> Result<UploadPartCopyOutput, RusotoError<UploadPartCopyError>>;
 }
 
-use crate::util::S3Config;
+use crate::util::{S3Config, S3Endpoint};
 
 /// A client for the Amazon S3 API.
 #[derive(Clone)]
@@ -19266,10 +18623,27 @@ impl S3Client {
 
     pub fn build_s3_hostname<T>(&self, bucket: &str) -> Result<(bool, String), RusotoError<T>> {
         self.config
-            .addressing_style
+            .validate_bucket_name(bucket)
+            .map_err(RusotoError::Validation)?;
+        self.config
             .build_s3_hostname(&self.region, bucket)
             .map_err(|e| RusotoError::InvalidDnsName(e))
     }
+
+    /// Resolves where to send the given operation on `bucket`, with the
+    /// `endpoint_resolver` of the config if set.
+    pub fn resolve_s3_endpoint<T>(
+        &self,
+        operation: &str,
+        bucket: &str,
+    ) -> Result<S3Endpoint, RusotoError<T>> {
+        self.config
+            .validate_bucket_name(bucket)
+            .map_err(RusotoError::Validation)?;
+        self.config
+            .resolve_endpoint(&self.region, bucket, Some(operation))
+            .map_err(RusotoError::InvalidDnsName)
+    }
 }
 
 #[async_trait]
@@ -19280,9 +18654,9 @@ impl S3 for S3Client {
         &self,
         input: AbortMultipartUploadRequest,
     ) -> Result<AbortMultipartUploadOutput, RusotoError<AbortMultipartUploadError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("AbortMultipartUpload", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -19299,7 +18673,7 @@ impl S3 for S3Client {
         params.put("uploadId", &input.upload_id);
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, AbortMultipartUploadError::from_response)
@@ -19317,9 +18691,9 @@ impl S3 for S3Client {
         &self,
         input: CompleteMultipartUploadRequest,
     ) -> Result<CompleteMultipartUploadOutput, RusotoError<CompleteMultipartUploadError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("CompleteMultipartUpload", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -19346,7 +18720,7 @@ impl S3 for S3Client {
         } else {
             request.set_payload(Some(Vec::new()));
         }
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, CompleteMultipartUploadError::from_response)
@@ -19378,9 +18752,9 @@ impl S3 for S3Client {
         &self,
         input: CopyObjectRequest,
     ) -> Result<CopyObjectOutput, RusotoError<CopyObjectError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("CopyObject", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -19496,7 +18870,7 @@ impl S3 for S3Client {
             input.website_redirect_location.as_ref(),
         );
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, CopyObjectError::from_response)
@@ -19538,9 +18912,9 @@ impl S3 for S3Client {
         &self,
         input: CreateBucketRequest,
     ) -> Result<CreateBucketOutput, RusotoError<CreateBucketError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("CreateBucket", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -19573,7 +18947,7 @@ impl S3 for S3Client {
         } else {
             request.set_payload(Some(Vec::new()));
         }
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, CreateBucketError::from_response)
@@ -19591,9 +18965,9 @@ impl S3 for S3Client {
         &self,
         input: CreateMultipartUploadRequest,
     ) -> Result<CreateMultipartUploadOutput, RusotoError<CreateMultipartUploadError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("CreateMultipartUpload", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -19674,7 +19048,7 @@ impl S3 for S3Client {
         params.put_key("uploads");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, CreateMultipartUploadError::from_response)
@@ -19715,9 +19089,9 @@ impl S3 for S3Client {
         &self,
         input: DeleteBucketRequest,
     ) -> Result<(), RusotoError<DeleteBucketError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("DeleteBucket", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -19730,7 +19104,7 @@ impl S3 for S3Client {
             input.expected_bucket_owner.as_ref(),
         );
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteBucketError::from_response)
@@ -19746,9 +19120,10 @@ impl S3 for S3Client {
         &self,
         input: DeleteBucketAnalyticsConfigurationRequest,
     ) -> Result<(), RusotoError<DeleteBucketAnalyticsConfigurationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("DeleteBucketAnalyticsConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -19765,7 +19140,7 @@ impl S3 for S3Client {
         params.put_key("analytics");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(
@@ -19784,9 +19159,9 @@ impl S3 for S3Client {
         &self,
         input: DeleteBucketCorsRequest,
     ) -> Result<(), RusotoError<DeleteBucketCorsError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("DeleteBucketCors", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -19802,7 +19177,7 @@ impl S3 for S3Client {
         params.put_key("cors");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteBucketCorsError::from_response)
@@ -19818,9 +19193,9 @@ impl S3 for S3Client {
         &self,
         input: DeleteBucketEncryptionRequest,
     ) -> Result<(), RusotoError<DeleteBucketEncryptionError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("DeleteBucketEncryption", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -19836,7 +19211,7 @@ impl S3 for S3Client {
         params.put_key("encryption");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteBucketEncryptionError::from_response)
@@ -19852,9 +19227,10 @@ impl S3 for S3Client {
         &self,
         input: DeleteBucketIntelligentTieringConfigurationRequest,
     ) -> Result<(), RusotoError<DeleteBucketIntelligentTieringConfigurationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("DeleteBucketIntelligentTieringConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -19867,7 +19243,7 @@ impl S3 for S3Client {
         params.put_key("intelligent-tiering");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(
@@ -19886,9 +19262,10 @@ impl S3 for S3Client {
         &self,
         input: DeleteBucketInventoryConfigurationRequest,
     ) -> Result<(), RusotoError<DeleteBucketInventoryConfigurationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("DeleteBucketInventoryConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -19905,7 +19282,7 @@ impl S3 for S3Client {
         params.put_key("inventory");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(
@@ -19924,9 +19301,9 @@ impl S3 for S3Client {
         &self,
         input: DeleteBucketLifecycleRequest,
     ) -> Result<(), RusotoError<DeleteBucketLifecycleError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("DeleteBucketLifecycle", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -19942,7 +19319,7 @@ impl S3 for S3Client {
         params.put_key("lifecycle");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteBucketLifecycleError::from_response)
@@ -19958,9 +19335,10 @@ impl S3 for S3Client {
         &self,
         input: DeleteBucketMetricsConfigurationRequest,
     ) -> Result<(), RusotoError<DeleteBucketMetricsConfigurationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("DeleteBucketMetricsConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -19977,7 +19355,7 @@ impl S3 for S3Client {
         params.put_key("metrics");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(
@@ -19996,9 +19374,9 @@ impl S3 for S3Client {
         &self,
         input: DeleteBucketOwnershipControlsRequest,
     ) -> Result<(), RusotoError<DeleteBucketOwnershipControlsError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("DeleteBucketOwnershipControls", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20014,7 +19392,7 @@ impl S3 for S3Client {
         params.put_key("ownershipControls");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteBucketOwnershipControlsError::from_response)
@@ -20030,9 +19408,9 @@ impl S3 for S3Client {
         &self,
         input: DeleteBucketPolicyRequest,
     ) -> Result<(), RusotoError<DeleteBucketPolicyError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("DeleteBucketPolicy", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20048,7 +19426,7 @@ impl S3 for S3Client {
         params.put_key("policy");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteBucketPolicyError::from_response)
@@ -20064,9 +19442,9 @@ impl S3 for S3Client {
         &self,
         input: DeleteBucketReplicationRequest,
     ) -> Result<(), RusotoError<DeleteBucketReplicationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("DeleteBucketReplication", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20082,7 +19460,7 @@ impl S3 for S3Client {
         params.put_key("replication");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteBucketReplicationError::from_response)
@@ -20098,9 +19476,9 @@ impl S3 for S3Client {
         &self,
         input: DeleteBucketTaggingRequest,
     ) -> Result<(), RusotoError<DeleteBucketTaggingError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("DeleteBucketTagging", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20116,7 +19494,7 @@ impl S3 for S3Client {
         params.put_key("tagging");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteBucketTaggingError::from_response)
@@ -20132,9 +19510,9 @@ impl S3 for S3Client {
         &self,
         input: DeleteBucketWebsiteRequest,
     ) -> Result<(), RusotoError<DeleteBucketWebsiteError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("DeleteBucketWebsite", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20150,7 +19528,7 @@ impl S3 for S3Client {
         params.put_key("website");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteBucketWebsiteError::from_response)
@@ -20166,9 +19544,9 @@ impl S3 for S3Client {
         &self,
         input: DeleteObjectRequest,
     ) -> Result<DeleteObjectOutput, RusotoError<DeleteObjectError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("DeleteObject", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -20184,6 +19562,7 @@ impl S3 for S3Client {
             "x-amz-expected-bucket-owner",
             input.expected_bucket_owner.as_ref(),
         );
+        request.add_optional_header("If-Match", input.if_match.as_ref());
         request.add_optional_header("x-amz-mfa", input.mfa.as_ref());
         request.add_optional_header("x-amz-request-payer", input.request_payer.as_ref());
         let mut params = Params::new();
@@ -20192,7 +19571,7 @@ impl S3 for S3Client {
         }
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteObjectError::from_response)
@@ -20215,9 +19594,9 @@ impl S3 for S3Client {
         &self,
         input: DeleteObjectTaggingRequest,
     ) -> Result<DeleteObjectTaggingOutput, RusotoError<DeleteObjectTaggingError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("DeleteObjectTagging", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -20236,7 +19615,7 @@ impl S3 for S3Client {
         params.put_key("tagging");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteObjectTaggingError::from_response)
@@ -20254,9 +19633,9 @@ impl S3 for S3Client {
         &self,
         input: DeleteObjectsRequest,
     ) -> Result<DeleteObjectsOutput, RusotoError<DeleteObjectsError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("DeleteObjects", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20281,7 +19660,7 @@ impl S3 for S3Client {
         DeleteSerializer::serialize(&mut writer, "Delete", &input.delete);
         request.set_payload(Some(writer.into_inner()));
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteObjectsError::from_response)
@@ -20303,9 +19682,9 @@ impl S3 for S3Client {
         &self,
         input: DeletePublicAccessBlockRequest,
     ) -> Result<(), RusotoError<DeletePublicAccessBlockError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("DeletePublicAccessBlock", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20321,7 +19700,7 @@ impl S3 for S3Client {
         params.put_key("publicAccessBlock");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, DeletePublicAccessBlockError::from_response)
@@ -20340,9 +19719,10 @@ impl S3 for S3Client {
         GetBucketAccelerateConfigurationOutput,
         RusotoError<GetBucketAccelerateConfigurationError>,
     > {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("GetBucketAccelerateConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20358,7 +19738,7 @@ impl S3 for S3Client {
         params.put_key("accelerate");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(
@@ -20383,9 +19763,9 @@ impl S3 for S3Client {
         &self,
         input: GetBucketAclRequest,
     ) -> Result<GetBucketAclOutput, RusotoError<GetBucketAclError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetBucketAcl", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20401,7 +19781,7 @@ impl S3 for S3Client {
         params.put_key("acl");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketAclError::from_response)
@@ -20426,9 +19806,10 @@ impl S3 for S3Client {
         GetBucketAnalyticsConfigurationOutput,
         RusotoError<GetBucketAnalyticsConfigurationError>,
     > {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("GetBucketAnalyticsConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20445,7 +19826,7 @@ impl S3 for S3Client {
         params.put_key("analytics");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketAnalyticsConfigurationError::from_response)
@@ -20467,9 +19848,9 @@ impl S3 for S3Client {
         &self,
         input: GetBucketCorsRequest,
     ) -> Result<GetBucketCorsOutput, RusotoError<GetBucketCorsError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetBucketCors", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20485,7 +19866,7 @@ impl S3 for S3Client {
         params.put_key("cors");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketCorsError::from_response)
@@ -20507,9 +19888,9 @@ impl S3 for S3Client {
         &self,
         input: GetBucketEncryptionRequest,
     ) -> Result<GetBucketEncryptionOutput, RusotoError<GetBucketEncryptionError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetBucketEncryption", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20525,7 +19906,7 @@ impl S3 for S3Client {
         params.put_key("encryption");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketEncryptionError::from_response)
@@ -20550,9 +19931,10 @@ impl S3 for S3Client {
         GetBucketIntelligentTieringConfigurationOutput,
         RusotoError<GetBucketIntelligentTieringConfigurationError>,
     > {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("GetBucketIntelligentTieringConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20565,7 +19947,7 @@ impl S3 for S3Client {
         params.put_key("intelligent-tiering");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(
@@ -20596,9 +19978,10 @@ impl S3 for S3Client {
         GetBucketInventoryConfigurationOutput,
         RusotoError<GetBucketInventoryConfigurationError>,
     > {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("GetBucketInventoryConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20615,7 +19998,7 @@ impl S3 for S3Client {
         params.put_key("inventory");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketInventoryConfigurationError::from_response)
@@ -20637,9 +20020,9 @@ impl S3 for S3Client {
         &self,
         input: GetBucketLifecycleRequest,
     ) -> Result<GetBucketLifecycleOutput, RusotoError<GetBucketLifecycleError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetBucketLifecycle", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20655,7 +20038,7 @@ impl S3 for S3Client {
         params.put_key("lifecycle");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketLifecycleError::from_response)
@@ -20680,9 +20063,10 @@ impl S3 for S3Client {
         GetBucketLifecycleConfigurationOutput,
         RusotoError<GetBucketLifecycleConfigurationError>,
     > {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("GetBucketLifecycleConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20698,7 +20082,7 @@ impl S3 for S3Client {
         params.put_key("lifecycle");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketLifecycleConfigurationError::from_response)
@@ -20752,9 +20136,9 @@ impl S3 for S3Client {
         &self,
         input: GetBucketLoggingRequest,
     ) -> Result<GetBucketLoggingOutput, RusotoError<GetBucketLoggingError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetBucketLogging", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20770,7 +20154,7 @@ impl S3 for S3Client {
         params.put_key("logging");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketLoggingError::from_response)
@@ -20793,9 +20177,9 @@ impl S3 for S3Client {
         input: GetBucketMetricsConfigurationRequest,
     ) -> Result<GetBucketMetricsConfigurationOutput, RusotoError<GetBucketMetricsConfigurationError>>
     {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetBucketMetricsConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20812,7 +20196,7 @@ impl S3 for S3Client {
         params.put_key("metrics");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketMetricsConfigurationError::from_response)
@@ -20834,9 +20218,9 @@ impl S3 for S3Client {
         &self,
         input: GetBucketNotificationConfigurationRequest,
     ) -> Result<NotificationConfigurationDeprecated, RusotoError<GetBucketNotificationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetBucketNotification", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20852,7 +20236,7 @@ impl S3 for S3Client {
         params.put_key("notification");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketNotificationError::from_response)
@@ -20875,9 +20259,10 @@ impl S3 for S3Client {
         input: GetBucketNotificationConfigurationRequest,
     ) -> Result<NotificationConfiguration, RusotoError<GetBucketNotificationConfigurationError>>
     {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("GetBucketNotificationConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20893,7 +20278,7 @@ impl S3 for S3Client {
         params.put_key("notification");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(
@@ -20919,9 +20304,9 @@ impl S3 for S3Client {
         input: GetBucketOwnershipControlsRequest,
     ) -> Result<GetBucketOwnershipControlsOutput, RusotoError<GetBucketOwnershipControlsError>>
     {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetBucketOwnershipControls", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20937,7 +20322,7 @@ impl S3 for S3Client {
         params.put_key("ownershipControls");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketOwnershipControlsError::from_response)
@@ -20959,9 +20344,9 @@ impl S3 for S3Client {
         &self,
         input: GetBucketPolicyRequest,
     ) -> Result<GetBucketPolicyOutput, RusotoError<GetBucketPolicyError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetBucketPolicy", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -20977,7 +20362,7 @@ impl S3 for S3Client {
         params.put_key("policy");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketPolicyError::from_response)
@@ -20997,9 +20382,9 @@ impl S3 for S3Client {
         &self,
         input: GetBucketPolicyStatusRequest,
     ) -> Result<GetBucketPolicyStatusOutput, RusotoError<GetBucketPolicyStatusError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetBucketPolicyStatus", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -21015,7 +20400,7 @@ impl S3 for S3Client {
         params.put_key("policyStatus");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketPolicyStatusError::from_response)
@@ -21037,9 +20422,9 @@ impl S3 for S3Client {
         &self,
         input: GetBucketReplicationRequest,
     ) -> Result<GetBucketReplicationOutput, RusotoError<GetBucketReplicationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetBucketReplication", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -21055,7 +20440,7 @@ impl S3 for S3Client {
         params.put_key("replication");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketReplicationError::from_response)
@@ -21077,9 +20462,9 @@ impl S3 for S3Client {
         &self,
         input: GetBucketRequestPaymentRequest,
     ) -> Result<GetBucketRequestPaymentOutput, RusotoError<GetBucketRequestPaymentError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetBucketRequestPayment", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -21095,7 +20480,7 @@ impl S3 for S3Client {
         params.put_key("requestPayment");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketRequestPaymentError::from_response)
@@ -21117,9 +20502,9 @@ impl S3 for S3Client {
         &self,
         input: GetBucketTaggingRequest,
     ) -> Result<GetBucketTaggingOutput, RusotoError<GetBucketTaggingError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetBucketTagging", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -21135,7 +20520,7 @@ impl S3 for S3Client {
         params.put_key("tagging");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketTaggingError::from_response)
@@ -21157,9 +20542,9 @@ impl S3 for S3Client {
         &self,
         input: GetBucketVersioningRequest,
     ) -> Result<GetBucketVersioningOutput, RusotoError<GetBucketVersioningError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetBucketVersioning", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -21175,7 +20560,7 @@ impl S3 for S3Client {
         params.put_key("versioning");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketVersioningError::from_response)
@@ -21197,9 +20582,9 @@ impl S3 for S3Client {
         &self,
         input: GetBucketWebsiteRequest,
     ) -> Result<GetBucketWebsiteOutput, RusotoError<GetBucketWebsiteError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetBucketWebsite", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -21215,7 +20600,7 @@ impl S3 for S3Client {
         params.put_key("website");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetBucketWebsiteError::from_response)
@@ -21237,9 +20622,9 @@ impl S3 for S3Client {
         &self,
         input: GetObjectRequest,
     ) -> Result<GetObjectOutput, RusotoError<GetObjectError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetObject", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -21296,7 +20681,7 @@ impl S3 for S3Client {
         }
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetObjectError::from_response)
@@ -21329,11 +20714,14 @@ impl S3 for S3Client {
         result.last_modified = response.headers.remove("Last-Modified");
         let mut values = ::std::collections::HashMap::new();
         for (key, value) in response.headers.iter() {
-            if key.as_str().starts_with("x-amz-meta-") {
-                values.insert(
-                    key.as_str()["x-amz-meta-".len()..].to_owned(),
-                    value.to_owned(),
-                );
+            if let Some(name) = key.as_str().strip_prefix("x-amz-meta-") {
+                values
+                    .entry(name.to_owned())
+                    .and_modify(|values: &mut String| {
+                        values.push(',');
+                        values.push_str(value);
+                    })
+                    .or_insert_with(|| value.to_owned());
             }
         }
         result.metadata = Some(values);
@@ -21372,7 +20760,7 @@ impl S3 for S3Client {
         result.version_id = response.headers.remove("x-amz-version-id");
         result.website_redirect_location =
             response.headers.remove("x-amz-website-redirect-location");
-        Ok(result)
+        Ok(self.config.transform_download(result))
     }
 
     /// <p><p>Returns the access control list (ACL) of an object. To use this operation, you must have <code>READ<em>ACP</code> access to the object.</p> <p>This action is not supported by Amazon S3 on Outposts.</p> <p> <b>Versioning</b> </p> <p>By default, GET returns ACL information about the current version of an object. To return ACL information about a different version, use the versionId subresource.</p> <p>The following operations are related to <code>GetObjectAcl</code>:</p> <ul> <li> <p> &lt;a href=&quot;https://docs.aws.amazon.com/AmazonS3/latest/API/API</em>GetObject.html&quot;&gt;GetObject</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteObject.html">DeleteObject</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/API_PutObject.html">PutObject</a> </p> </li> </ul></p>
@@ -21381,9 +20769,9 @@ impl S3 for S3Client {
         &self,
         input: GetObjectAclRequest,
     ) -> Result<GetObjectAclOutput, RusotoError<GetObjectAclError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetObjectAcl", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -21403,7 +20791,7 @@ impl S3 for S3Client {
         params.put_key("acl");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetObjectAclError::from_response)
@@ -21425,9 +20813,9 @@ impl S3 for S3Client {
         &self,
         input: GetObjectLegalHoldRequest,
     ) -> Result<GetObjectLegalHoldOutput, RusotoError<GetObjectLegalHoldError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetObjectLegalHold", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -21447,7 +20835,7 @@ impl S3 for S3Client {
         params.put_key("legal-hold");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetObjectLegalHoldError::from_response)
@@ -21470,9 +20858,9 @@ impl S3 for S3Client {
         input: GetObjectLockConfigurationRequest,
     ) -> Result<GetObjectLockConfigurationOutput, RusotoError<GetObjectLockConfigurationError>>
     {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetObjectLockConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -21488,7 +20876,7 @@ impl S3 for S3Client {
         params.put_key("object-lock");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetObjectLockConfigurationError::from_response)
@@ -21510,9 +20898,9 @@ impl S3 for S3Client {
         &self,
         input: GetObjectRetentionRequest,
     ) -> Result<GetObjectRetentionOutput, RusotoError<GetObjectRetentionError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetObjectRetention", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -21532,7 +20920,7 @@ impl S3 for S3Client {
         params.put_key("retention");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetObjectRetentionError::from_response)
@@ -21554,9 +20942,9 @@ impl S3 for S3Client {
         &self,
         input: GetObjectTaggingRequest,
     ) -> Result<GetObjectTaggingOutput, RusotoError<GetObjectTaggingError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetObjectTagging", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -21575,7 +20963,7 @@ impl S3 for S3Client {
         params.put_key("tagging");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetObjectTaggingError::from_response)
@@ -21597,9 +20985,9 @@ impl S3 for S3Client {
         &self,
         input: GetObjectTorrentRequest,
     ) -> Result<GetObjectTorrentOutput, RusotoError<GetObjectTorrentError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetObjectTorrent", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -21616,7 +21004,7 @@ impl S3 for S3Client {
         params.put_key("torrent");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetObjectTorrentError::from_response)
@@ -21634,9 +21022,9 @@ impl S3 for S3Client {
         &self,
         input: GetPublicAccessBlockRequest,
     ) -> Result<GetPublicAccessBlockOutput, RusotoError<GetPublicAccessBlockError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("GetPublicAccessBlock", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -21652,7 +21040,7 @@ impl S3 for S3Client {
         params.put_key("publicAccessBlock");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, GetPublicAccessBlockError::from_response)
@@ -21674,9 +21062,9 @@ impl S3 for S3Client {
         &self,
         input: HeadBucketRequest,
     ) -> Result<(), RusotoError<HeadBucketError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("HeadBucket", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -21689,7 +21077,7 @@ impl S3 for S3Client {
             input.expected_bucket_owner.as_ref(),
         );
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, HeadBucketError::from_response)
@@ -21705,9 +21093,9 @@ impl S3 for S3Client {
         &self,
         input: HeadObjectRequest,
     ) -> Result<HeadObjectOutput, RusotoError<HeadObjectError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("HeadObject", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -21746,7 +21134,7 @@ impl S3 for S3Client {
         }
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, HeadObjectError::from_response)
@@ -21779,11 +21167,14 @@ impl S3 for S3Client {
         result.last_modified = response.headers.remove("Last-Modified");
         let mut values = ::std::collections::HashMap::new();
         for (key, value) in response.headers.iter() {
-            if key.as_str().starts_with("x-amz-meta-") {
-                values.insert(
-                    key.as_str()["x-amz-meta-".len()..].to_owned(),
-                    value.to_owned(),
-                );
+            if let Some(name) = key.as_str().strip_prefix("x-amz-meta-") {
+                values
+                    .entry(name.to_owned())
+                    .and_modify(|values: &mut String| {
+                        values.push(',');
+                        values.push_str(value);
+                    })
+                    .or_insert_with(|| value.to_owned());
             }
         }
         result.metadata = Some(values);
@@ -21830,9 +21221,10 @@ impl S3 for S3Client {
         ListBucketAnalyticsConfigurationsOutput,
         RusotoError<ListBucketAnalyticsConfigurationsError>,
     > {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("ListBucketAnalyticsConfigurations", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -21851,7 +21243,7 @@ impl S3 for S3Client {
         params.put_key("analytics");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(
@@ -21879,9 +21271,10 @@ impl S3 for S3Client {
         ListBucketIntelligentTieringConfigurationsOutput,
         RusotoError<ListBucketIntelligentTieringConfigurationsError>,
     > {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("ListBucketIntelligentTieringConfigurations", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -21896,7 +21289,7 @@ impl S3 for S3Client {
         params.put_key("intelligent-tiering");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(
@@ -21927,9 +21320,10 @@ impl S3 for S3Client {
         ListBucketInventoryConfigurationsOutput,
         RusotoError<ListBucketInventoryConfigurationsError>,
     > {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("ListBucketInventoryConfigurations", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -21948,7 +21342,7 @@ impl S3 for S3Client {
         params.put_key("inventory");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(
@@ -21976,9 +21370,10 @@ impl S3 for S3Client {
         ListBucketMetricsConfigurationsOutput,
         RusotoError<ListBucketMetricsConfigurationsError>,
     > {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("ListBucketMetricsConfigurations", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -21997,7 +21392,7 @@ impl S3 for S3Client {
         params.put_key("metrics");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, ListBucketMetricsConfigurationsError::from_response)
@@ -22040,9 +21435,9 @@ impl S3 for S3Client {
         &self,
         input: ListMultipartUploadsRequest,
     ) -> Result<ListMultipartUploadsOutput, RusotoError<ListMultipartUploadsError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("ListMultipartUploads", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22076,7 +21471,7 @@ impl S3 for S3Client {
         params.put_key("uploads");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, ListMultipartUploadsError::from_response)
@@ -22098,9 +21493,9 @@ impl S3 for S3Client {
         &self,
         input: ListObjectVersionsRequest,
     ) -> Result<ListObjectVersionsOutput, RusotoError<ListObjectVersionsError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("ListObjectVersions", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22134,7 +21529,7 @@ impl S3 for S3Client {
         params.put_key("versions");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, ListObjectVersionsError::from_response)
@@ -22156,9 +21551,9 @@ impl S3 for S3Client {
         &self,
         input: ListObjectsRequest,
     ) -> Result<ListObjectsOutput, RusotoError<ListObjectsError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("ListObjects", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22189,7 +21584,7 @@ impl S3 for S3Client {
         }
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, ListObjectsError::from_response)
@@ -22211,9 +21606,9 @@ impl S3 for S3Client {
         &self,
         input: ListObjectsV2Request,
     ) -> Result<ListObjectsV2Output, RusotoError<ListObjectsV2Error>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("ListObjectsV2", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22251,7 +21646,7 @@ impl S3 for S3Client {
         params.put("list-type", "2");
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, ListObjectsV2Error::from_response)
@@ -22273,9 +21668,9 @@ impl S3 for S3Client {
         &self,
         input: ListPartsRequest,
     ) -> Result<ListPartsOutput, RusotoError<ListPartsError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("ListParts", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -22298,7 +21693,7 @@ impl S3 for S3Client {
         params.put("uploadId", &input.upload_id);
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, ListPartsError::from_response)
@@ -22322,9 +21717,10 @@ impl S3 for S3Client {
         &self,
         input: PutBucketAccelerateConfigurationRequest,
     ) -> Result<(), RusotoError<PutBucketAccelerateConfigurationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("PutBucketAccelerateConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22346,7 +21742,7 @@ impl S3 for S3Client {
             &input.accelerate_configuration,
         );
         request.set_payload(Some(writer.into_inner()));
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(
@@ -22365,9 +21761,9 @@ impl S3 for S3Client {
         &self,
         input: PutBucketAclRequest,
     ) -> Result<(), RusotoError<PutBucketAclError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutBucketAcl", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22404,7 +21800,7 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketAclError::from_response)
@@ -22420,9 +21816,10 @@ impl S3 for S3Client {
         &self,
         input: PutBucketAnalyticsConfigurationRequest,
     ) -> Result<(), RusotoError<PutBucketAnalyticsConfigurationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("PutBucketAnalyticsConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22445,7 +21842,7 @@ impl S3 for S3Client {
             &input.analytics_configuration,
         );
         request.set_payload(Some(writer.into_inner()));
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketAnalyticsConfigurationError::from_response)
@@ -22461,9 +21858,9 @@ impl S3 for S3Client {
         &self,
         input: PutBucketCorsRequest,
     ) -> Result<(), RusotoError<PutBucketCorsError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutBucketCors", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22487,7 +21884,7 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketCorsError::from_response)
@@ -22503,9 +21900,9 @@ impl S3 for S3Client {
         &self,
         input: PutBucketEncryptionRequest,
     ) -> Result<(), RusotoError<PutBucketEncryptionError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutBucketEncryption", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22529,7 +21926,7 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketEncryptionError::from_response)
@@ -22545,9 +21942,10 @@ impl S3 for S3Client {
         &self,
         input: PutBucketIntelligentTieringConfigurationRequest,
     ) -> Result<(), RusotoError<PutBucketIntelligentTieringConfigurationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("PutBucketIntelligentTieringConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22566,7 +21964,7 @@ impl S3 for S3Client {
             &input.intelligent_tiering_configuration,
         );
         request.set_payload(Some(writer.into_inner()));
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(
@@ -22585,9 +21983,10 @@ impl S3 for S3Client {
         &self,
         input: PutBucketInventoryConfigurationRequest,
     ) -> Result<(), RusotoError<PutBucketInventoryConfigurationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("PutBucketInventoryConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22610,7 +22009,7 @@ impl S3 for S3Client {
             &input.inventory_configuration,
         );
         request.set_payload(Some(writer.into_inner()));
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketInventoryConfigurationError::from_response)
@@ -22626,9 +22025,9 @@ impl S3 for S3Client {
         &self,
         input: PutBucketLifecycleRequest,
     ) -> Result<(), RusotoError<PutBucketLifecycleError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutBucketLifecycle", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22656,7 +22055,7 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketLifecycleError::from_response)
@@ -22672,9 +22071,10 @@ impl S3 for S3Client {
         &self,
         input: PutBucketLifecycleConfigurationRequest,
     ) -> Result<(), RusotoError<PutBucketLifecycleConfigurationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("PutBucketLifecycleConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22701,7 +22101,7 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketLifecycleConfigurationError::from_response)
@@ -22717,9 +22117,9 @@ impl S3 for S3Client {
         &self,
         input: PutBucketLoggingRequest,
     ) -> Result<(), RusotoError<PutBucketLoggingError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutBucketLogging", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22743,7 +22143,7 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketLoggingError::from_response)
@@ -22759,9 +22159,9 @@ impl S3 for S3Client {
         &self,
         input: PutBucketMetricsConfigurationRequest,
     ) -> Result<(), RusotoError<PutBucketMetricsConfigurationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutBucketMetricsConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22784,7 +22184,7 @@ impl S3 for S3Client {
             &input.metrics_configuration,
         );
         request.set_payload(Some(writer.into_inner()));
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketMetricsConfigurationError::from_response)
@@ -22800,9 +22200,9 @@ impl S3 for S3Client {
         &self,
         input: PutBucketNotificationRequest,
     ) -> Result<(), RusotoError<PutBucketNotificationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutBucketNotification", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22826,7 +22226,7 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketNotificationError::from_response)
@@ -22842,9 +22242,10 @@ impl S3 for S3Client {
         &self,
         input: PutBucketNotificationConfigurationRequest,
     ) -> Result<(), RusotoError<PutBucketNotificationConfigurationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint =
+            self.resolve_s3_endpoint("PutBucketNotificationConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22866,7 +22267,7 @@ impl S3 for S3Client {
             &input.notification_configuration,
         );
         request.set_payload(Some(writer.into_inner()));
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(
@@ -22885,9 +22286,9 @@ impl S3 for S3Client {
         &self,
         input: PutBucketOwnershipControlsRequest,
     ) -> Result<(), RusotoError<PutBucketOwnershipControlsError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutBucketOwnershipControls", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22911,7 +22312,7 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketOwnershipControlsError::from_response)
@@ -22927,9 +22328,9 @@ impl S3 for S3Client {
         &self,
         input: PutBucketPolicyRequest,
     ) -> Result<(), RusotoError<PutBucketPolicyError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutBucketPolicy", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22951,7 +22352,7 @@ impl S3 for S3Client {
         request.set_params(params);
         request.set_payload(Some(input.policy.into_bytes()));
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketPolicyError::from_response)
@@ -22967,9 +22368,9 @@ impl S3 for S3Client {
         &self,
         input: PutBucketReplicationRequest,
     ) -> Result<(), RusotoError<PutBucketReplicationError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutBucketReplication", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -22994,7 +22395,7 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketReplicationError::from_response)
@@ -23010,9 +22411,9 @@ impl S3 for S3Client {
         &self,
         input: PutBucketRequestPaymentRequest,
     ) -> Result<(), RusotoError<PutBucketRequestPaymentError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutBucketRequestPayment", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -23036,7 +22437,7 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketRequestPaymentError::from_response)
@@ -23052,9 +22453,9 @@ impl S3 for S3Client {
         &self,
         input: PutBucketTaggingRequest,
     ) -> Result<(), RusotoError<PutBucketTaggingError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutBucketTagging", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -23074,7 +22475,7 @@ impl S3 for S3Client {
         TaggingSerializer::serialize(&mut writer, "Tagging", &input.tagging);
         request.set_payload(Some(writer.into_inner()));
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketTaggingError::from_response)
@@ -23090,9 +22491,9 @@ impl S3 for S3Client {
         &self,
         input: PutBucketVersioningRequest,
     ) -> Result<(), RusotoError<PutBucketVersioningError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutBucketVersioning", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -23117,7 +22518,7 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketVersioningError::from_response)
@@ -23133,9 +22534,9 @@ impl S3 for S3Client {
         &self,
         input: PutBucketWebsiteRequest,
     ) -> Result<(), RusotoError<PutBucketWebsiteError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutBucketWebsite", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -23159,7 +22560,7 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutBucketWebsiteError::from_response)
@@ -23175,9 +22576,17 @@ impl S3 for S3Client {
         &self,
         input: PutObjectRequest,
     ) -> Result<PutObjectOutput, RusotoError<PutObjectError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let input = self.config.transform_upload(input);
+        let input = match self.config.multipart_threshold {
+            Some(threshold) => match self.put_object_in_parts_if_large(input, threshold).await? {
+                AutoMultipart::PutObject(input) => input,
+                AutoMultipart::Uploaded(output) => return Ok(output),
+            },
+            None => input,
+        };
+        let endpoint = self.resolve_s3_endpoint("PutObject", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -23260,7 +22669,7 @@ impl S3 for S3Client {
         if let Some(__body) = input.body {
             request.set_payload_stream(__body);
         }
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutObjectError::from_response)
@@ -23298,9 +22707,9 @@ impl S3 for S3Client {
         &self,
         input: PutObjectAclRequest,
     ) -> Result<PutObjectAclOutput, RusotoError<PutObjectAclError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutObjectAcl", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -23341,7 +22750,7 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutObjectAclError::from_response)
@@ -23359,9 +22768,9 @@ impl S3 for S3Client {
         &self,
         input: PutObjectLegalHoldRequest,
     ) -> Result<PutObjectLegalHoldOutput, RusotoError<PutObjectLegalHoldError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutObjectLegalHold", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -23393,7 +22802,7 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutObjectLegalHoldError::from_response)
@@ -23412,9 +22821,9 @@ impl S3 for S3Client {
         input: PutObjectLockConfigurationRequest,
     ) -> Result<PutObjectLockConfigurationOutput, RusotoError<PutObjectLockConfigurationError>>
     {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutObjectLockConfiguration", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -23444,7 +22853,7 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutObjectLockConfigurationError::from_response)
@@ -23462,9 +22871,9 @@ impl S3 for S3Client {
         &self,
         input: PutObjectRetentionRequest,
     ) -> Result<PutObjectRetentionOutput, RusotoError<PutObjectRetentionError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutObjectRetention", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -23500,7 +22909,7 @@ impl S3 for S3Client {
             request.set_payload(Some(Vec::new()));
         }
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutObjectRetentionError::from_response)
@@ -23518,9 +22927,9 @@ impl S3 for S3Client {
         &self,
         input: PutObjectTaggingRequest,
     ) -> Result<PutObjectTaggingOutput, RusotoError<PutObjectTaggingError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutObjectTagging", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -23543,7 +22952,7 @@ impl S3 for S3Client {
         TaggingSerializer::serialize(&mut writer, "Tagging", &input.tagging);
         request.set_payload(Some(writer.into_inner()));
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutObjectTaggingError::from_response)
@@ -23561,9 +22970,9 @@ impl S3 for S3Client {
         &self,
         input: PutPublicAccessBlockRequest,
     ) -> Result<(), RusotoError<PutPublicAccessBlockError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("PutPublicAccessBlock", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             "/".into()
         } else {
             format!("/{bucket}", bucket = input.bucket).into()
@@ -23587,7 +22996,7 @@ impl S3 for S3Client {
         );
         request.set_payload(Some(writer.into_inner()));
         request.maybe_set_content_md5_header()?;
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, PutPublicAccessBlockError::from_response)
@@ -23603,9 +23012,9 @@ impl S3 for S3Client {
         &self,
         input: RestoreObjectRequest,
     ) -> Result<RestoreObjectOutput, RusotoError<RestoreObjectError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("RestoreObject", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -23635,7 +23044,7 @@ impl S3 for S3Client {
         } else {
             request.set_payload(Some(Vec::new()));
         }
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, RestoreObjectError::from_response)
@@ -23654,9 +23063,9 @@ impl S3 for S3Client {
         &self,
         input: SelectObjectContentRequest,
     ) -> Result<SelectObjectContentOutput, RusotoError<SelectObjectContentError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("SelectObjectContent", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -23692,7 +23101,7 @@ impl S3 for S3Client {
             "http://s3.amazonaws.com/doc/2006-03-01/",
         );
         request.set_payload(Some(writer.into_inner()));
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, SelectObjectContentError::from_response)
@@ -23707,9 +23116,9 @@ impl S3 for S3Client {
         &self,
         input: UploadPartRequest,
     ) -> Result<UploadPartOutput, RusotoError<UploadPartError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("UploadPart", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -23743,7 +23152,7 @@ impl S3 for S3Client {
         if let Some(__body) = input.body {
             request.set_payload_stream(__body);
         }
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, UploadPartError::from_response)
@@ -23776,9 +23185,9 @@ impl S3 for S3Client {
         &self,
         input: UploadPartCopyRequest,
     ) -> Result<UploadPartCopyOutput, RusotoError<UploadPartCopyError>> {
-        let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+        let endpoint = self.resolve_s3_endpoint("UploadPartCopy", &input.bucket)?;
 
-        let request_uri: std::borrow::Cow<'_, str> = if is_virtual {
+        let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {
             format!("/{key}", key = input.key).into()
         } else {
             format!("/{bucket}/{key}", bucket = input.bucket, key = input.key).into()
@@ -23842,7 +23251,7 @@ impl S3 for S3Client {
         params.put("uploadId", &input.upload_id);
         request.set_params(params);
 
-        request.set_hostname(Some(hostname));
+        endpoint.apply_to(&mut request);
 
         let mut response = self
             .sign_and_dispatch(request, UploadPartCopyError::from_response)
@@ -23891,7 +23300,10 @@ mod protocol_tests {
         );
         let mock = MockRequestDispatcher::with_status(400).with_body(&mock_response);
         let client = S3Client::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = CreateBucketRequest::default();
+        let request = CreateBucketRequest {
+            bucket: "bucket".to_owned(),
+            ..Default::default()
+        };
         let result = client.create_bucket(request).await;
         assert!(!result.is_ok(), "parse error: {:?}", result);
     }
@@ -23904,7 +23316,10 @@ mod protocol_tests {
         );
         let mock = MockRequestDispatcher::with_status(400).with_body(&mock_response);
         let client = S3Client::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = ListObjectsRequest::default();
+        let request = ListObjectsRequest {
+            bucket: "bucket".to_owned(),
+            ..Default::default()
+        };
         let result = client.list_objects(request).await;
         assert!(!result.is_ok(), "parse error: {:?}", result);
     }
@@ -23917,7 +23332,10 @@ mod protocol_tests {
         );
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client = S3Client::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = GetBucketAclRequest::default();
+        let request = GetBucketAclRequest {
+            bucket: "bucket".to_owned(),
+            ..Default::default()
+        };
         let result = client.get_bucket_acl(request).await;
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
@@ -23930,7 +23348,10 @@ mod protocol_tests {
         );
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client = S3Client::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = GetBucketLocationRequest::default();
+        let request = GetBucketLocationRequest {
+            bucket: "bucket".to_owned(),
+            ..Default::default()
+        };
         let result = client.get_bucket_location(request).await;
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
@@ -23943,7 +23364,10 @@ mod protocol_tests {
         );
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client = S3Client::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = GetBucketLoggingRequest::default();
+        let request = GetBucketLoggingRequest {
+            bucket: "bucket".to_owned(),
+            ..Default::default()
+        };
         let result = client.get_bucket_logging(request).await;
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
@@ -23956,7 +23380,10 @@ mod protocol_tests {
         );
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client = S3Client::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = GetBucketPolicyRequest::default();
+        let request = GetBucketPolicyRequest {
+            bucket: "bucket".to_owned(),
+            ..Default::default()
+        };
         let result = client.get_bucket_policy(request).await;
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
@@ -23982,7 +23409,10 @@ mod protocol_tests {
         );
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client = S3Client::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = ListMultipartUploadsRequest::default();
+        let request = ListMultipartUploadsRequest {
+            bucket: "bucket".to_owned(),
+            ..Default::default()
+        };
         let result = client.list_multipart_uploads(request).await;
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
@@ -23995,7 +23425,10 @@ mod protocol_tests {
         );
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client = S3Client::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = ListObjectVersionsRequest::default();
+        let request = ListObjectVersionsRequest {
+            bucket: "bucket".to_owned(),
+            ..Default::default()
+        };
         let result = client.list_object_versions(request).await;
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
@@ -24008,7 +23441,10 @@ mod protocol_tests {
         );
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client = S3Client::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = ListObjectsRequest::default();
+        let request = ListObjectsRequest {
+            bucket: "bucket".to_owned(),
+            ..Default::default()
+        };
         let result = client.list_objects(request).await;
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -251,6 +251,15 @@ impl fmt::Display for DeleteHumanLoopError {
     }
 }
 impl Error for DeleteHumanLoopError {}
+impl ServiceErrorCode for DeleteHumanLoopError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteHumanLoopError::InternalServer(_) => "InternalServerException",
+            DeleteHumanLoopError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteHumanLoopError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DescribeHumanLoop
 #[derive(Debug, PartialEq)]
 pub enum DescribeHumanLoopError {
@@ -293,6 +302,15 @@ impl fmt::Display for DescribeHumanLoopError {
     }
 }
 impl Error for DescribeHumanLoopError {}
+impl ServiceErrorCode for DescribeHumanLoopError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeHumanLoopError::InternalServer(_) => "InternalServerException",
+            DescribeHumanLoopError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeHumanLoopError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListHumanLoops
 #[derive(Debug, PartialEq)]
 pub enum ListHumanLoopsError {
@@ -335,6 +353,15 @@ impl fmt::Display for ListHumanLoopsError {
     }
 }
 impl Error for ListHumanLoopsError {}
+impl ServiceErrorCode for ListHumanLoopsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListHumanLoopsError::InternalServer(_) => "InternalServerException",
+            ListHumanLoopsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListHumanLoopsError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StartHumanLoop
 #[derive(Debug, PartialEq)]
 pub enum StartHumanLoopError {
@@ -383,6 +410,16 @@ impl fmt::Display for StartHumanLoopError {
     }
 }
 impl Error for StartHumanLoopError {}
+impl ServiceErrorCode for StartHumanLoopError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartHumanLoopError::Conflict(_) => "ConflictException",
+            StartHumanLoopError::InternalServer(_) => "InternalServerException",
+            StartHumanLoopError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+            StartHumanLoopError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StopHumanLoop
 #[derive(Debug, PartialEq)]
 pub enum StopHumanLoopError {
@@ -425,6 +462,15 @@ impl fmt::Display for StopHumanLoopError {
     }
 }
 impl Error for StopHumanLoopError {}
+impl ServiceErrorCode for StopHumanLoopError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopHumanLoopError::InternalServer(_) => "InternalServerException",
+            StopHumanLoopError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StopHumanLoopError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Augmented AI Runtime API. Amazon Augmented AI Runtime clients implement this trait.
 #[async_trait]
 pub trait SagemakerA2iRuntime {
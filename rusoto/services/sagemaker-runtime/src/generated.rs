@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -123,6 +123,16 @@ impl fmt::Display for InvokeEndpointError {
     }
 }
 impl Error for InvokeEndpointError {}
+impl ServiceErrorCode for InvokeEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InvokeEndpointError::InternalFailure(_) => "InternalFailure",
+            InvokeEndpointError::ModelError(_) => "ModelError",
+            InvokeEndpointError::ServiceUnavailable(_) => "ServiceUnavailable",
+            InvokeEndpointError::ValidationError(_) => "ValidationError",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon SageMaker Runtime API. Amazon SageMaker Runtime clients implement this trait.
 #[async_trait]
 pub trait SageMakerRuntime {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1814,6 +1814,7 @@ pub struct CreateImageVersionRequest {
     #[serde(rename = "BaseImage")]
     pub base_image: String,
     /// <p>A unique ID. If not specified, the AWS CLI and AWS SDKs, such as the SDK for Python (Boto3), add a unique value to the call.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     pub client_token: String,
     /// <p>The <code>ImageName</code> of the <code>Image</code> to create a version of.</p>
@@ -2036,6 +2037,7 @@ pub struct CreateModelPackageInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub certify_for_marketplace: Option<bool>,
     /// <p>A unique token that guarantees that the call to this API is idempotent.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -2251,6 +2253,7 @@ pub struct CreateNotebookInstanceOutput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreatePipelineRequest {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the operation. An idempotent operation completes no more than one time.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>The JSON pipeline definition of the pipeline.</p>
@@ -3320,6 +3323,7 @@ pub struct DeleteNotebookInstanceLifecycleConfigInput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeletePipelineRequest {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the operation. An idempotent operation completes no more than one time.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>The name of the pipeline to delete.</p>
@@ -12576,6 +12580,7 @@ pub struct StartNotebookInstanceInput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartPipelineExecutionRequest {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the operation. An idempotent operation completes no more than one time.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>The description of the pipeline execution.</p>
@@ -12664,6 +12669,7 @@ pub struct StopNotebookInstanceInput {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StopPipelineExecutionRequest {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the operation. An idempotent operation completes no more than one time.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     pub client_request_token: String,
     /// <p>The Amazon Resource Name (ARN) of the pipeline execution.</p>
@@ -14581,6 +14587,14 @@ impl fmt::Display for AddAssociationError {
     }
 }
 impl Error for AddAssociationError {}
+impl ServiceErrorCode for AddAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddAssociationError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            AddAssociationError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by AddTags
 #[derive(Debug, PartialEq)]
 pub enum AddTagsError {}
@@ -14603,6 +14617,11 @@ impl fmt::Display for AddTagsError {
     }
 }
 impl Error for AddTagsError {}
+impl ServiceErrorCode for AddTagsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by AssociateTrialComponent
 #[derive(Debug, PartialEq)]
 pub enum AssociateTrialComponentError {
@@ -14645,6 +14664,14 @@ impl fmt::Display for AssociateTrialComponentError {
     }
 }
 impl Error for AssociateTrialComponentError {}
+impl ServiceErrorCode for AssociateTrialComponentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateTrialComponentError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            AssociateTrialComponentError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by CreateAction
 #[derive(Debug, PartialEq)]
 pub enum CreateActionError {
@@ -14675,6 +14702,13 @@ impl fmt::Display for CreateActionError {
     }
 }
 impl Error for CreateActionError {}
+impl ServiceErrorCode for CreateActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateActionError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateAlgorithm
 #[derive(Debug, PartialEq)]
 pub enum CreateAlgorithmError {}
@@ -14697,6 +14731,11 @@ impl fmt::Display for CreateAlgorithmError {
     }
 }
 impl Error for CreateAlgorithmError {}
+impl ServiceErrorCode for CreateAlgorithmError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateApp
 #[derive(Debug, PartialEq)]
 pub enum CreateAppError {
@@ -14733,6 +14772,14 @@ impl fmt::Display for CreateAppError {
     }
 }
 impl Error for CreateAppError {}
+impl ServiceErrorCode for CreateAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAppError::ResourceInUse(_) => "ResourceInUse",
+            CreateAppError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateAppImageConfig
 #[derive(Debug, PartialEq)]
 pub enum CreateAppImageConfigError {
@@ -14763,6 +14810,13 @@ impl fmt::Display for CreateAppImageConfigError {
     }
 }
 impl Error for CreateAppImageConfigError {}
+impl ServiceErrorCode for CreateAppImageConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAppImageConfigError::ResourceInUse(_) => "ResourceInUse",
+        }
+    }
+}
 /// Errors returned by CreateArtifact
 #[derive(Debug, PartialEq)]
 pub enum CreateArtifactError {
@@ -14795,6 +14849,13 @@ impl fmt::Display for CreateArtifactError {
     }
 }
 impl Error for CreateArtifactError {}
+impl ServiceErrorCode for CreateArtifactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateArtifactError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateAutoMLJob
 #[derive(Debug, PartialEq)]
 pub enum CreateAutoMLJobError {
@@ -14833,6 +14894,14 @@ impl fmt::Display for CreateAutoMLJobError {
     }
 }
 impl Error for CreateAutoMLJobError {}
+impl ServiceErrorCode for CreateAutoMLJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAutoMLJobError::ResourceInUse(_) => "ResourceInUse",
+            CreateAutoMLJobError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateCodeRepository
 #[derive(Debug, PartialEq)]
 pub enum CreateCodeRepositoryError {}
@@ -14855,6 +14924,11 @@ impl fmt::Display for CreateCodeRepositoryError {
     }
 }
 impl Error for CreateCodeRepositoryError {}
+impl ServiceErrorCode for CreateCodeRepositoryError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateCompilationJob
 #[derive(Debug, PartialEq)]
 pub enum CreateCompilationJobError {
@@ -14893,6 +14967,14 @@ impl fmt::Display for CreateCompilationJobError {
     }
 }
 impl Error for CreateCompilationJobError {}
+impl ServiceErrorCode for CreateCompilationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCompilationJobError::ResourceInUse(_) => "ResourceInUse",
+            CreateCompilationJobError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateContext
 #[derive(Debug, PartialEq)]
 pub enum CreateContextError {
@@ -14923,6 +15005,13 @@ impl fmt::Display for CreateContextError {
     }
 }
 impl Error for CreateContextError {}
+impl ServiceErrorCode for CreateContextError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateContextError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateDataQualityJobDefinition
 #[derive(Debug, PartialEq)]
 pub enum CreateDataQualityJobDefinitionError {
@@ -14967,6 +15056,16 @@ impl fmt::Display for CreateDataQualityJobDefinitionError {
     }
 }
 impl Error for CreateDataQualityJobDefinitionError {}
+impl ServiceErrorCode for CreateDataQualityJobDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDataQualityJobDefinitionError::ResourceInUse(_) => "ResourceInUse",
+            CreateDataQualityJobDefinitionError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDeviceFleet
 #[derive(Debug, PartialEq)]
 pub enum CreateDeviceFleetError {
@@ -15005,6 +15104,14 @@ impl fmt::Display for CreateDeviceFleetError {
     }
 }
 impl Error for CreateDeviceFleetError {}
+impl ServiceErrorCode for CreateDeviceFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeviceFleetError::ResourceInUse(_) => "ResourceInUse",
+            CreateDeviceFleetError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateDomain
 #[derive(Debug, PartialEq)]
 pub enum CreateDomainError {
@@ -15041,6 +15148,14 @@ impl fmt::Display for CreateDomainError {
     }
 }
 impl Error for CreateDomainError {}
+impl ServiceErrorCode for CreateDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDomainError::ResourceInUse(_) => "ResourceInUse",
+            CreateDomainError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateEdgePackagingJob
 #[derive(Debug, PartialEq)]
 pub enum CreateEdgePackagingJobError {
@@ -15073,6 +15188,13 @@ impl fmt::Display for CreateEdgePackagingJobError {
     }
 }
 impl Error for CreateEdgePackagingJobError {}
+impl ServiceErrorCode for CreateEdgePackagingJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEdgePackagingJobError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateEndpoint
 #[derive(Debug, PartialEq)]
 pub enum CreateEndpointError {
@@ -15105,6 +15227,13 @@ impl fmt::Display for CreateEndpointError {
     }
 }
 impl Error for CreateEndpointError {}
+impl ServiceErrorCode for CreateEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEndpointError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateEndpointConfig
 #[derive(Debug, PartialEq)]
 pub enum CreateEndpointConfigError {
@@ -15137,6 +15266,13 @@ impl fmt::Display for CreateEndpointConfigError {
     }
 }
 impl Error for CreateEndpointConfigError {}
+impl ServiceErrorCode for CreateEndpointConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEndpointConfigError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateExperiment
 #[derive(Debug, PartialEq)]
 pub enum CreateExperimentError {
@@ -15169,6 +15305,13 @@ impl fmt::Display for CreateExperimentError {
     }
 }
 impl Error for CreateExperimentError {}
+impl ServiceErrorCode for CreateExperimentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateExperimentError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateFeatureGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateFeatureGroupError {
@@ -15207,6 +15350,14 @@ impl fmt::Display for CreateFeatureGroupError {
     }
 }
 impl Error for CreateFeatureGroupError {}
+impl ServiceErrorCode for CreateFeatureGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFeatureGroupError::ResourceInUse(_) => "ResourceInUse",
+            CreateFeatureGroupError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateFlowDefinition
 #[derive(Debug, PartialEq)]
 pub enum CreateFlowDefinitionError {
@@ -15245,6 +15396,14 @@ impl fmt::Display for CreateFlowDefinitionError {
     }
 }
 impl Error for CreateFlowDefinitionError {}
+impl ServiceErrorCode for CreateFlowDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFlowDefinitionError::ResourceInUse(_) => "ResourceInUse",
+            CreateFlowDefinitionError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateHumanTaskUi
 #[derive(Debug, PartialEq)]
 pub enum CreateHumanTaskUiError {
@@ -15283,6 +15442,14 @@ impl fmt::Display for CreateHumanTaskUiError {
     }
 }
 impl Error for CreateHumanTaskUiError {}
+impl ServiceErrorCode for CreateHumanTaskUiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHumanTaskUiError::ResourceInUse(_) => "ResourceInUse",
+            CreateHumanTaskUiError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateHyperParameterTuningJob
 #[derive(Debug, PartialEq)]
 pub enum CreateHyperParameterTuningJobError {
@@ -15327,6 +15494,14 @@ impl fmt::Display for CreateHyperParameterTuningJobError {
     }
 }
 impl Error for CreateHyperParameterTuningJobError {}
+impl ServiceErrorCode for CreateHyperParameterTuningJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHyperParameterTuningJobError::ResourceInUse(_) => "ResourceInUse",
+            CreateHyperParameterTuningJobError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateImage
 #[derive(Debug, PartialEq)]
 pub enum CreateImageError {
@@ -15363,6 +15538,14 @@ impl fmt::Display for CreateImageError {
     }
 }
 impl Error for CreateImageError {}
+impl ServiceErrorCode for CreateImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateImageError::ResourceInUse(_) => "ResourceInUse",
+            CreateImageError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateImageVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateImageVersionError {
@@ -15407,6 +15590,15 @@ impl fmt::Display for CreateImageVersionError {
     }
 }
 impl Error for CreateImageVersionError {}
+impl ServiceErrorCode for CreateImageVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateImageVersionError::ResourceInUse(_) => "ResourceInUse",
+            CreateImageVersionError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            CreateImageVersionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by CreateLabelingJob
 #[derive(Debug, PartialEq)]
 pub enum CreateLabelingJobError {
@@ -15445,6 +15637,14 @@ impl fmt::Display for CreateLabelingJobError {
     }
 }
 impl Error for CreateLabelingJobError {}
+impl ServiceErrorCode for CreateLabelingJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLabelingJobError::ResourceInUse(_) => "ResourceInUse",
+            CreateLabelingJobError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateModel
 #[derive(Debug, PartialEq)]
 pub enum CreateModelError {
@@ -15475,6 +15675,13 @@ impl fmt::Display for CreateModelError {
     }
 }
 impl Error for CreateModelError {}
+impl ServiceErrorCode for CreateModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateModelError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateModelBiasJobDefinition
 #[derive(Debug, PartialEq)]
 pub enum CreateModelBiasJobDefinitionError {
@@ -15519,6 +15726,14 @@ impl fmt::Display for CreateModelBiasJobDefinitionError {
     }
 }
 impl Error for CreateModelBiasJobDefinitionError {}
+impl ServiceErrorCode for CreateModelBiasJobDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateModelBiasJobDefinitionError::ResourceInUse(_) => "ResourceInUse",
+            CreateModelBiasJobDefinitionError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateModelExplainabilityJobDefinition
 #[derive(Debug, PartialEq)]
 pub enum CreateModelExplainabilityJobDefinitionError {
@@ -15565,6 +15780,16 @@ impl fmt::Display for CreateModelExplainabilityJobDefinitionError {
     }
 }
 impl Error for CreateModelExplainabilityJobDefinitionError {}
+impl ServiceErrorCode for CreateModelExplainabilityJobDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateModelExplainabilityJobDefinitionError::ResourceInUse(_) => "ResourceInUse",
+            CreateModelExplainabilityJobDefinitionError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CreateModelPackage
 #[derive(Debug, PartialEq)]
 pub enum CreateModelPackageError {
@@ -15603,6 +15828,14 @@ impl fmt::Display for CreateModelPackageError {
     }
 }
 impl Error for CreateModelPackageError {}
+impl ServiceErrorCode for CreateModelPackageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateModelPackageError::Conflict(_) => "ConflictException",
+            CreateModelPackageError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateModelPackageGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateModelPackageGroupError {
@@ -15637,6 +15870,13 @@ impl fmt::Display for CreateModelPackageGroupError {
     }
 }
 impl Error for CreateModelPackageGroupError {}
+impl ServiceErrorCode for CreateModelPackageGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateModelPackageGroupError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateModelQualityJobDefinition
 #[derive(Debug, PartialEq)]
 pub enum CreateModelQualityJobDefinitionError {
@@ -15683,6 +15923,16 @@ impl fmt::Display for CreateModelQualityJobDefinitionError {
     }
 }
 impl Error for CreateModelQualityJobDefinitionError {}
+impl ServiceErrorCode for CreateModelQualityJobDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateModelQualityJobDefinitionError::ResourceInUse(_) => "ResourceInUse",
+            CreateModelQualityJobDefinitionError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CreateMonitoringSchedule
 #[derive(Debug, PartialEq)]
 pub enum CreateMonitoringScheduleError {
@@ -15725,6 +15975,14 @@ impl fmt::Display for CreateMonitoringScheduleError {
     }
 }
 impl Error for CreateMonitoringScheduleError {}
+impl ServiceErrorCode for CreateMonitoringScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMonitoringScheduleError::ResourceInUse(_) => "ResourceInUse",
+            CreateMonitoringScheduleError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateNotebookInstance
 #[derive(Debug, PartialEq)]
 pub enum CreateNotebookInstanceError {
@@ -15757,6 +16015,13 @@ impl fmt::Display for CreateNotebookInstanceError {
     }
 }
 impl Error for CreateNotebookInstanceError {}
+impl ServiceErrorCode for CreateNotebookInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateNotebookInstanceError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateNotebookInstanceLifecycleConfig
 #[derive(Debug, PartialEq)]
 pub enum CreateNotebookInstanceLifecycleConfigError {
@@ -15793,6 +16058,15 @@ impl fmt::Display for CreateNotebookInstanceLifecycleConfigError {
     }
 }
 impl Error for CreateNotebookInstanceLifecycleConfigError {}
+impl ServiceErrorCode for CreateNotebookInstanceLifecycleConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateNotebookInstanceLifecycleConfigError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CreatePipeline
 #[derive(Debug, PartialEq)]
 pub enum CreatePipelineError {
@@ -15831,6 +16105,14 @@ impl fmt::Display for CreatePipelineError {
     }
 }
 impl Error for CreatePipelineError {}
+impl ServiceErrorCode for CreatePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePipelineError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            CreatePipelineError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by CreatePresignedDomainUrl
 #[derive(Debug, PartialEq)]
 pub enum CreatePresignedDomainUrlError {
@@ -15863,6 +16145,13 @@ impl fmt::Display for CreatePresignedDomainUrlError {
     }
 }
 impl Error for CreatePresignedDomainUrlError {}
+impl ServiceErrorCode for CreatePresignedDomainUrlError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePresignedDomainUrlError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by CreatePresignedNotebookInstanceUrl
 #[derive(Debug, PartialEq)]
 pub enum CreatePresignedNotebookInstanceUrlError {}
@@ -15887,6 +16176,11 @@ impl fmt::Display for CreatePresignedNotebookInstanceUrlError {
     }
 }
 impl Error for CreatePresignedNotebookInstanceUrlError {}
+impl ServiceErrorCode for CreatePresignedNotebookInstanceUrlError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateProcessingJob
 #[derive(Debug, PartialEq)]
 pub enum CreateProcessingJobError {
@@ -15933,6 +16227,15 @@ impl fmt::Display for CreateProcessingJobError {
     }
 }
 impl Error for CreateProcessingJobError {}
+impl ServiceErrorCode for CreateProcessingJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProcessingJobError::ResourceInUse(_) => "ResourceInUse",
+            CreateProcessingJobError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            CreateProcessingJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by CreateProject
 #[derive(Debug, PartialEq)]
 pub enum CreateProjectError {
@@ -15963,6 +16266,13 @@ impl fmt::Display for CreateProjectError {
     }
 }
 impl Error for CreateProjectError {}
+impl ServiceErrorCode for CreateProjectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProjectError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateTrainingJob
 #[derive(Debug, PartialEq)]
 pub enum CreateTrainingJobError {
@@ -16007,6 +16317,15 @@ impl fmt::Display for CreateTrainingJobError {
     }
 }
 impl Error for CreateTrainingJobError {}
+impl ServiceErrorCode for CreateTrainingJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTrainingJobError::ResourceInUse(_) => "ResourceInUse",
+            CreateTrainingJobError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            CreateTrainingJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by CreateTransformJob
 #[derive(Debug, PartialEq)]
 pub enum CreateTransformJobError {
@@ -16051,6 +16370,15 @@ impl fmt::Display for CreateTransformJobError {
     }
 }
 impl Error for CreateTransformJobError {}
+impl ServiceErrorCode for CreateTransformJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTransformJobError::ResourceInUse(_) => "ResourceInUse",
+            CreateTransformJobError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            CreateTransformJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by CreateTrial
 #[derive(Debug, PartialEq)]
 pub enum CreateTrialError {
@@ -16087,6 +16415,14 @@ impl fmt::Display for CreateTrialError {
     }
 }
 impl Error for CreateTrialError {}
+impl ServiceErrorCode for CreateTrialError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTrialError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            CreateTrialError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by CreateTrialComponent
 #[derive(Debug, PartialEq)]
 pub enum CreateTrialComponentError {
@@ -16119,6 +16455,13 @@ impl fmt::Display for CreateTrialComponentError {
     }
 }
 impl Error for CreateTrialComponentError {}
+impl ServiceErrorCode for CreateTrialComponentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTrialComponentError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateUserProfile
 #[derive(Debug, PartialEq)]
 pub enum CreateUserProfileError {
@@ -16157,6 +16500,14 @@ impl fmt::Display for CreateUserProfileError {
     }
 }
 impl Error for CreateUserProfileError {}
+impl ServiceErrorCode for CreateUserProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserProfileError::ResourceInUse(_) => "ResourceInUse",
+            CreateUserProfileError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateWorkforce
 #[derive(Debug, PartialEq)]
 pub enum CreateWorkforceError {}
@@ -16179,6 +16530,11 @@ impl fmt::Display for CreateWorkforceError {
     }
 }
 impl Error for CreateWorkforceError {}
+impl ServiceErrorCode for CreateWorkforceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by CreateWorkteam
 #[derive(Debug, PartialEq)]
 pub enum CreateWorkteamError {
@@ -16217,6 +16573,14 @@ impl fmt::Display for CreateWorkteamError {
     }
 }
 impl Error for CreateWorkteamError {}
+impl ServiceErrorCode for CreateWorkteamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateWorkteamError::ResourceInUse(_) => "ResourceInUse",
+            CreateWorkteamError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by DeleteAction
 #[derive(Debug, PartialEq)]
 pub enum DeleteActionError {
@@ -16247,6 +16611,13 @@ impl fmt::Display for DeleteActionError {
     }
 }
 impl Error for DeleteActionError {}
+impl ServiceErrorCode for DeleteActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteActionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteAlgorithm
 #[derive(Debug, PartialEq)]
 pub enum DeleteAlgorithmError {}
@@ -16269,6 +16640,11 @@ impl fmt::Display for DeleteAlgorithmError {
     }
 }
 impl Error for DeleteAlgorithmError {}
+impl ServiceErrorCode for DeleteAlgorithmError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteApp
 #[derive(Debug, PartialEq)]
 pub enum DeleteAppError {
@@ -16305,6 +16681,14 @@ impl fmt::Display for DeleteAppError {
     }
 }
 impl Error for DeleteAppError {}
+impl ServiceErrorCode for DeleteAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAppError::ResourceInUse(_) => "ResourceInUse",
+            DeleteAppError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteAppImageConfig
 #[derive(Debug, PartialEq)]
 pub enum DeleteAppImageConfigError {
@@ -16337,6 +16721,13 @@ impl fmt::Display for DeleteAppImageConfigError {
     }
 }
 impl Error for DeleteAppImageConfigError {}
+impl ServiceErrorCode for DeleteAppImageConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAppImageConfigError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteArtifact
 #[derive(Debug, PartialEq)]
 pub enum DeleteArtifactError {
@@ -16367,6 +16758,13 @@ impl fmt::Display for DeleteArtifactError {
     }
 }
 impl Error for DeleteArtifactError {}
+impl ServiceErrorCode for DeleteArtifactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteArtifactError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteAssociation
 #[derive(Debug, PartialEq)]
 pub enum DeleteAssociationError {
@@ -16397,6 +16795,13 @@ impl fmt::Display for DeleteAssociationError {
     }
 }
 impl Error for DeleteAssociationError {}
+impl ServiceErrorCode for DeleteAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAssociationError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteCodeRepository
 #[derive(Debug, PartialEq)]
 pub enum DeleteCodeRepositoryError {}
@@ -16419,6 +16824,11 @@ impl fmt::Display for DeleteCodeRepositoryError {
     }
 }
 impl Error for DeleteCodeRepositoryError {}
+impl ServiceErrorCode for DeleteCodeRepositoryError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteContext
 #[derive(Debug, PartialEq)]
 pub enum DeleteContextError {
@@ -16449,6 +16859,13 @@ impl fmt::Display for DeleteContextError {
     }
 }
 impl Error for DeleteContextError {}
+impl ServiceErrorCode for DeleteContextError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteContextError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteDataQualityJobDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteDataQualityJobDefinitionError {
@@ -16485,6 +16902,13 @@ impl fmt::Display for DeleteDataQualityJobDefinitionError {
     }
 }
 impl Error for DeleteDataQualityJobDefinitionError {}
+impl ServiceErrorCode for DeleteDataQualityJobDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDataQualityJobDefinitionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteDeviceFleet
 #[derive(Debug, PartialEq)]
 pub enum DeleteDeviceFleetError {
@@ -16515,6 +16939,13 @@ impl fmt::Display for DeleteDeviceFleetError {
     }
 }
 impl Error for DeleteDeviceFleetError {}
+impl ServiceErrorCode for DeleteDeviceFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDeviceFleetError::ResourceInUse(_) => "ResourceInUse",
+        }
+    }
+}
 /// Errors returned by DeleteDomain
 #[derive(Debug, PartialEq)]
 pub enum DeleteDomainError {
@@ -16551,6 +16982,14 @@ impl fmt::Display for DeleteDomainError {
     }
 }
 impl Error for DeleteDomainError {}
+impl ServiceErrorCode for DeleteDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDomainError::ResourceInUse(_) => "ResourceInUse",
+            DeleteDomainError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DeleteEndpointError {}
@@ -16573,6 +17012,11 @@ impl fmt::Display for DeleteEndpointError {
     }
 }
 impl Error for DeleteEndpointError {}
+impl ServiceErrorCode for DeleteEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteEndpointConfig
 #[derive(Debug, PartialEq)]
 pub enum DeleteEndpointConfigError {}
@@ -16595,6 +17039,11 @@ impl fmt::Display for DeleteEndpointConfigError {
     }
 }
 impl Error for DeleteEndpointConfigError {}
+impl ServiceErrorCode for DeleteEndpointConfigError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteExperiment
 #[derive(Debug, PartialEq)]
 pub enum DeleteExperimentError {
@@ -16625,6 +17074,13 @@ impl fmt::Display for DeleteExperimentError {
     }
 }
 impl Error for DeleteExperimentError {}
+impl ServiceErrorCode for DeleteExperimentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteExperimentError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteFeatureGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteFeatureGroupError {
@@ -16655,6 +17111,13 @@ impl fmt::Display for DeleteFeatureGroupError {
     }
 }
 impl Error for DeleteFeatureGroupError {}
+impl ServiceErrorCode for DeleteFeatureGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFeatureGroupError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteFlowDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteFlowDefinitionError {
@@ -16693,6 +17156,14 @@ impl fmt::Display for DeleteFlowDefinitionError {
     }
 }
 impl Error for DeleteFlowDefinitionError {}
+impl ServiceErrorCode for DeleteFlowDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFlowDefinitionError::ResourceInUse(_) => "ResourceInUse",
+            DeleteFlowDefinitionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteHumanTaskUi
 #[derive(Debug, PartialEq)]
 pub enum DeleteHumanTaskUiError {
@@ -16723,6 +17194,13 @@ impl fmt::Display for DeleteHumanTaskUiError {
     }
 }
 impl Error for DeleteHumanTaskUiError {}
+impl ServiceErrorCode for DeleteHumanTaskUiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteHumanTaskUiError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteImage
 #[derive(Debug, PartialEq)]
 pub enum DeleteImageError {
@@ -16759,6 +17237,14 @@ impl fmt::Display for DeleteImageError {
     }
 }
 impl Error for DeleteImageError {}
+impl ServiceErrorCode for DeleteImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteImageError::ResourceInUse(_) => "ResourceInUse",
+            DeleteImageError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteImageVersion
 #[derive(Debug, PartialEq)]
 pub enum DeleteImageVersionError {
@@ -16795,7 +17281,15 @@ impl fmt::Display for DeleteImageVersionError {
     }
 }
 impl Error for DeleteImageVersionError {}
-/// Errors returned by DeleteModel
+impl ServiceErrorCode for DeleteImageVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteImageVersionError::ResourceInUse(_) => "ResourceInUse",
+            DeleteImageVersionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
+/// Errors returned by DeleteModel
 #[derive(Debug, PartialEq)]
 pub enum DeleteModelError {}
 
@@ -16817,6 +17311,11 @@ impl fmt::Display for DeleteModelError {
     }
 }
 impl Error for DeleteModelError {}
+impl ServiceErrorCode for DeleteModelError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteModelBiasJobDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteModelBiasJobDefinitionError {
@@ -16853,6 +17352,13 @@ impl fmt::Display for DeleteModelBiasJobDefinitionError {
     }
 }
 impl Error for DeleteModelBiasJobDefinitionError {}
+impl ServiceErrorCode for DeleteModelBiasJobDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteModelBiasJobDefinitionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteModelExplainabilityJobDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteModelExplainabilityJobDefinitionError {
@@ -16889,6 +17395,13 @@ impl fmt::Display for DeleteModelExplainabilityJobDefinitionError {
     }
 }
 impl Error for DeleteModelExplainabilityJobDefinitionError {}
+impl ServiceErrorCode for DeleteModelExplainabilityJobDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteModelExplainabilityJobDefinitionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteModelPackage
 #[derive(Debug, PartialEq)]
 pub enum DeleteModelPackageError {
@@ -16919,6 +17432,13 @@ impl fmt::Display for DeleteModelPackageError {
     }
 }
 impl Error for DeleteModelPackageError {}
+impl ServiceErrorCode for DeleteModelPackageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteModelPackageError::Conflict(_) => "ConflictException",
+        }
+    }
+}
 /// Errors returned by DeleteModelPackageGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteModelPackageGroupError {}
@@ -16941,6 +17461,11 @@ impl fmt::Display for DeleteModelPackageGroupError {
     }
 }
 impl Error for DeleteModelPackageGroupError {}
+impl ServiceErrorCode for DeleteModelPackageGroupError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteModelPackageGroupPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteModelPackageGroupPolicyError {}
@@ -16965,6 +17490,11 @@ impl fmt::Display for DeleteModelPackageGroupPolicyError {
     }
 }
 impl Error for DeleteModelPackageGroupPolicyError {}
+impl ServiceErrorCode for DeleteModelPackageGroupPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteModelQualityJobDefinition
 #[derive(Debug, PartialEq)]
 pub enum DeleteModelQualityJobDefinitionError {
@@ -17001,6 +17531,13 @@ impl fmt::Display for DeleteModelQualityJobDefinitionError {
     }
 }
 impl Error for DeleteModelQualityJobDefinitionError {}
+impl ServiceErrorCode for DeleteModelQualityJobDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteModelQualityJobDefinitionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteMonitoringSchedule
 #[derive(Debug, PartialEq)]
 pub enum DeleteMonitoringScheduleError {
@@ -17033,6 +17570,13 @@ impl fmt::Display for DeleteMonitoringScheduleError {
     }
 }
 impl Error for DeleteMonitoringScheduleError {}
+impl ServiceErrorCode for DeleteMonitoringScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMonitoringScheduleError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteNotebookInstance
 #[derive(Debug, PartialEq)]
 pub enum DeleteNotebookInstanceError {}
@@ -17055,6 +17599,11 @@ impl fmt::Display for DeleteNotebookInstanceError {
     }
 }
 impl Error for DeleteNotebookInstanceError {}
+impl ServiceErrorCode for DeleteNotebookInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteNotebookInstanceLifecycleConfig
 #[derive(Debug, PartialEq)]
 pub enum DeleteNotebookInstanceLifecycleConfigError {}
@@ -17079,6 +17628,11 @@ impl fmt::Display for DeleteNotebookInstanceLifecycleConfigError {
     }
 }
 impl Error for DeleteNotebookInstanceLifecycleConfigError {}
+impl ServiceErrorCode for DeleteNotebookInstanceLifecycleConfigError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeletePipeline
 #[derive(Debug, PartialEq)]
 pub enum DeletePipelineError {
@@ -17109,6 +17663,13 @@ impl fmt::Display for DeletePipelineError {
     }
 }
 impl Error for DeletePipelineError {}
+impl ServiceErrorCode for DeletePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePipelineError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteProject
 #[derive(Debug, PartialEq)]
 pub enum DeleteProjectError {}
@@ -17131,6 +17692,11 @@ impl fmt::Display for DeleteProjectError {
     }
 }
 impl Error for DeleteProjectError {}
+impl ServiceErrorCode for DeleteProjectError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
 pub enum DeleteTagsError {}
@@ -17153,6 +17719,11 @@ impl fmt::Display for DeleteTagsError {
     }
 }
 impl Error for DeleteTagsError {}
+impl ServiceErrorCode for DeleteTagsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteTrial
 #[derive(Debug, PartialEq)]
 pub enum DeleteTrialError {
@@ -17183,6 +17754,13 @@ impl fmt::Display for DeleteTrialError {
     }
 }
 impl Error for DeleteTrialError {}
+impl ServiceErrorCode for DeleteTrialError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTrialError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteTrialComponent
 #[derive(Debug, PartialEq)]
 pub enum DeleteTrialComponentError {
@@ -17215,6 +17793,13 @@ impl fmt::Display for DeleteTrialComponentError {
     }
 }
 impl Error for DeleteTrialComponentError {}
+impl ServiceErrorCode for DeleteTrialComponentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTrialComponentError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteUserProfile
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserProfileError {
@@ -17251,6 +17836,14 @@ impl fmt::Display for DeleteUserProfileError {
     }
 }
 impl Error for DeleteUserProfileError {}
+impl ServiceErrorCode for DeleteUserProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserProfileError::ResourceInUse(_) => "ResourceInUse",
+            DeleteUserProfileError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteWorkforce
 #[derive(Debug, PartialEq)]
 pub enum DeleteWorkforceError {}
@@ -17273,6 +17866,11 @@ impl fmt::Display for DeleteWorkforceError {
     }
 }
 impl Error for DeleteWorkforceError {}
+impl ServiceErrorCode for DeleteWorkforceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteWorkteam
 #[derive(Debug, PartialEq)]
 pub enum DeleteWorkteamError {
@@ -17305,6 +17903,13 @@ impl fmt::Display for DeleteWorkteamError {
     }
 }
 impl Error for DeleteWorkteamError {}
+impl ServiceErrorCode for DeleteWorkteamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteWorkteamError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by DeregisterDevices
 #[derive(Debug, PartialEq)]
 pub enum DeregisterDevicesError {}
@@ -17327,6 +17932,11 @@ impl fmt::Display for DeregisterDevicesError {
     }
 }
 impl Error for DeregisterDevicesError {}
+impl ServiceErrorCode for DeregisterDevicesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeAction
 #[derive(Debug, PartialEq)]
 pub enum DescribeActionError {
@@ -17357,6 +17967,13 @@ impl fmt::Display for DescribeActionError {
     }
 }
 impl Error for DescribeActionError {}
+impl ServiceErrorCode for DescribeActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeActionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeAlgorithm
 #[derive(Debug, PartialEq)]
 pub enum DescribeAlgorithmError {}
@@ -17379,6 +17996,11 @@ impl fmt::Display for DescribeAlgorithmError {
     }
 }
 impl Error for DescribeAlgorithmError {}
+impl ServiceErrorCode for DescribeAlgorithmError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeApp
 #[derive(Debug, PartialEq)]
 pub enum DescribeAppError {
@@ -17409,6 +18031,13 @@ impl fmt::Display for DescribeAppError {
     }
 }
 impl Error for DescribeAppError {}
+impl ServiceErrorCode for DescribeAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAppError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeAppImageConfig
 #[derive(Debug, PartialEq)]
 pub enum DescribeAppImageConfigError {
@@ -17441,6 +18070,13 @@ impl fmt::Display for DescribeAppImageConfigError {
     }
 }
 impl Error for DescribeAppImageConfigError {}
+impl ServiceErrorCode for DescribeAppImageConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAppImageConfigError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeArtifact
 #[derive(Debug, PartialEq)]
 pub enum DescribeArtifactError {
@@ -17471,6 +18107,13 @@ impl fmt::Display for DescribeArtifactError {
     }
 }
 impl Error for DescribeArtifactError {}
+impl ServiceErrorCode for DescribeArtifactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeArtifactError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeAutoMLJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeAutoMLJobError {
@@ -17501,6 +18144,13 @@ impl fmt::Display for DescribeAutoMLJobError {
     }
 }
 impl Error for DescribeAutoMLJobError {}
+impl ServiceErrorCode for DescribeAutoMLJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAutoMLJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeCodeRepository
 #[derive(Debug, PartialEq)]
 pub enum DescribeCodeRepositoryError {}
@@ -17523,6 +18173,11 @@ impl fmt::Display for DescribeCodeRepositoryError {
     }
 }
 impl Error for DescribeCodeRepositoryError {}
+impl ServiceErrorCode for DescribeCodeRepositoryError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeCompilationJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeCompilationJobError {
@@ -17555,6 +18210,13 @@ impl fmt::Display for DescribeCompilationJobError {
     }
 }
 impl Error for DescribeCompilationJobError {}
+impl ServiceErrorCode for DescribeCompilationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCompilationJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeContext
 #[derive(Debug, PartialEq)]
 pub enum DescribeContextError {
@@ -17585,6 +18247,13 @@ impl fmt::Display for DescribeContextError {
     }
 }
 impl Error for DescribeContextError {}
+impl ServiceErrorCode for DescribeContextError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeContextError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeDataQualityJobDefinition
 #[derive(Debug, PartialEq)]
 pub enum DescribeDataQualityJobDefinitionError {
@@ -17621,6 +18290,13 @@ impl fmt::Display for DescribeDataQualityJobDefinitionError {
     }
 }
 impl Error for DescribeDataQualityJobDefinitionError {}
+impl ServiceErrorCode for DescribeDataQualityJobDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDataQualityJobDefinitionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeDevice
 #[derive(Debug, PartialEq)]
 pub enum DescribeDeviceError {
@@ -17651,6 +18327,13 @@ impl fmt::Display for DescribeDeviceError {
     }
 }
 impl Error for DescribeDeviceError {}
+impl ServiceErrorCode for DescribeDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDeviceError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeDeviceFleet
 #[derive(Debug, PartialEq)]
 pub enum DescribeDeviceFleetError {
@@ -17683,6 +18366,13 @@ impl fmt::Display for DescribeDeviceFleetError {
     }
 }
 impl Error for DescribeDeviceFleetError {}
+impl ServiceErrorCode for DescribeDeviceFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDeviceFleetError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeDomain
 #[derive(Debug, PartialEq)]
 pub enum DescribeDomainError {
@@ -17713,6 +18403,13 @@ impl fmt::Display for DescribeDomainError {
     }
 }
 impl Error for DescribeDomainError {}
+impl ServiceErrorCode for DescribeDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDomainError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeEdgePackagingJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeEdgePackagingJobError {
@@ -17745,6 +18442,13 @@ impl fmt::Display for DescribeEdgePackagingJobError {
     }
 }
 impl Error for DescribeEdgePackagingJobError {}
+impl ServiceErrorCode for DescribeEdgePackagingJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEdgePackagingJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DescribeEndpointError {}
@@ -17767,6 +18471,11 @@ impl fmt::Display for DescribeEndpointError {
     }
 }
 impl Error for DescribeEndpointError {}
+impl ServiceErrorCode for DescribeEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeEndpointConfig
 #[derive(Debug, PartialEq)]
 pub enum DescribeEndpointConfigError {}
@@ -17789,6 +18498,11 @@ impl fmt::Display for DescribeEndpointConfigError {
     }
 }
 impl Error for DescribeEndpointConfigError {}
+impl ServiceErrorCode for DescribeEndpointConfigError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeExperiment
 #[derive(Debug, PartialEq)]
 pub enum DescribeExperimentError {
@@ -17819,6 +18533,13 @@ impl fmt::Display for DescribeExperimentError {
     }
 }
 impl Error for DescribeExperimentError {}
+impl ServiceErrorCode for DescribeExperimentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeExperimentError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeFeatureGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeFeatureGroupError {
@@ -17851,6 +18572,13 @@ impl fmt::Display for DescribeFeatureGroupError {
     }
 }
 impl Error for DescribeFeatureGroupError {}
+impl ServiceErrorCode for DescribeFeatureGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFeatureGroupError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeFlowDefinition
 #[derive(Debug, PartialEq)]
 pub enum DescribeFlowDefinitionError {
@@ -17883,6 +18611,13 @@ impl fmt::Display for DescribeFlowDefinitionError {
     }
 }
 impl Error for DescribeFlowDefinitionError {}
+impl ServiceErrorCode for DescribeFlowDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFlowDefinitionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeHumanTaskUi
 #[derive(Debug, PartialEq)]
 pub enum DescribeHumanTaskUiError {
@@ -17915,6 +18650,13 @@ impl fmt::Display for DescribeHumanTaskUiError {
     }
 }
 impl Error for DescribeHumanTaskUiError {}
+impl ServiceErrorCode for DescribeHumanTaskUiError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeHumanTaskUiError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeHyperParameterTuningJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeHyperParameterTuningJobError {
@@ -17951,6 +18693,13 @@ impl fmt::Display for DescribeHyperParameterTuningJobError {
     }
 }
 impl Error for DescribeHyperParameterTuningJobError {}
+impl ServiceErrorCode for DescribeHyperParameterTuningJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeHyperParameterTuningJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeImage
 #[derive(Debug, PartialEq)]
 pub enum DescribeImageError {
@@ -17981,6 +18730,13 @@ impl fmt::Display for DescribeImageError {
     }
 }
 impl Error for DescribeImageError {}
+impl ServiceErrorCode for DescribeImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeImageError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeImageVersion
 #[derive(Debug, PartialEq)]
 pub enum DescribeImageVersionError {
@@ -18013,6 +18769,13 @@ impl fmt::Display for DescribeImageVersionError {
     }
 }
 impl Error for DescribeImageVersionError {}
+impl ServiceErrorCode for DescribeImageVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeImageVersionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeLabelingJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeLabelingJobError {
@@ -18045,6 +18808,13 @@ impl fmt::Display for DescribeLabelingJobError {
     }
 }
 impl Error for DescribeLabelingJobError {}
+impl ServiceErrorCode for DescribeLabelingJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLabelingJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeModel
 #[derive(Debug, PartialEq)]
 pub enum DescribeModelError {}
@@ -18067,6 +18837,11 @@ impl fmt::Display for DescribeModelError {
     }
 }
 impl Error for DescribeModelError {}
+impl ServiceErrorCode for DescribeModelError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeModelBiasJobDefinition
 #[derive(Debug, PartialEq)]
 pub enum DescribeModelBiasJobDefinitionError {
@@ -18103,6 +18878,13 @@ impl fmt::Display for DescribeModelBiasJobDefinitionError {
     }
 }
 impl Error for DescribeModelBiasJobDefinitionError {}
+impl ServiceErrorCode for DescribeModelBiasJobDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeModelBiasJobDefinitionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeModelExplainabilityJobDefinition
 #[derive(Debug, PartialEq)]
 pub enum DescribeModelExplainabilityJobDefinitionError {
@@ -18139,6 +18921,15 @@ impl fmt::Display for DescribeModelExplainabilityJobDefinitionError {
     }
 }
 impl Error for DescribeModelExplainabilityJobDefinitionError {}
+impl ServiceErrorCode for DescribeModelExplainabilityJobDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeModelExplainabilityJobDefinitionError::ResourceNotFound(_) => {
+                "ResourceNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeModelPackage
 #[derive(Debug, PartialEq)]
 pub enum DescribeModelPackageError {}
@@ -18161,6 +18952,11 @@ impl fmt::Display for DescribeModelPackageError {
     }
 }
 impl Error for DescribeModelPackageError {}
+impl ServiceErrorCode for DescribeModelPackageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeModelPackageGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeModelPackageGroupError {}
@@ -18183,6 +18979,11 @@ impl fmt::Display for DescribeModelPackageGroupError {
     }
 }
 impl Error for DescribeModelPackageGroupError {}
+impl ServiceErrorCode for DescribeModelPackageGroupError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeModelQualityJobDefinition
 #[derive(Debug, PartialEq)]
 pub enum DescribeModelQualityJobDefinitionError {
@@ -18219,6 +19020,13 @@ impl fmt::Display for DescribeModelQualityJobDefinitionError {
     }
 }
 impl Error for DescribeModelQualityJobDefinitionError {}
+impl ServiceErrorCode for DescribeModelQualityJobDefinitionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeModelQualityJobDefinitionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeMonitoringSchedule
 #[derive(Debug, PartialEq)]
 pub enum DescribeMonitoringScheduleError {
@@ -18253,6 +19061,13 @@ impl fmt::Display for DescribeMonitoringScheduleError {
     }
 }
 impl Error for DescribeMonitoringScheduleError {}
+impl ServiceErrorCode for DescribeMonitoringScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMonitoringScheduleError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeNotebookInstance
 #[derive(Debug, PartialEq)]
 pub enum DescribeNotebookInstanceError {}
@@ -18275,6 +19090,11 @@ impl fmt::Display for DescribeNotebookInstanceError {
     }
 }
 impl Error for DescribeNotebookInstanceError {}
+impl ServiceErrorCode for DescribeNotebookInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeNotebookInstanceLifecycleConfig
 #[derive(Debug, PartialEq)]
 pub enum DescribeNotebookInstanceLifecycleConfigError {}
@@ -18299,6 +19119,11 @@ impl fmt::Display for DescribeNotebookInstanceLifecycleConfigError {
     }
 }
 impl Error for DescribeNotebookInstanceLifecycleConfigError {}
+impl ServiceErrorCode for DescribeNotebookInstanceLifecycleConfigError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribePipeline
 #[derive(Debug, PartialEq)]
 pub enum DescribePipelineError {
@@ -18329,6 +19154,13 @@ impl fmt::Display for DescribePipelineError {
     }
 }
 impl Error for DescribePipelineError {}
+impl ServiceErrorCode for DescribePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePipelineError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribePipelineDefinitionForExecution
 #[derive(Debug, PartialEq)]
 pub enum DescribePipelineDefinitionForExecutionError {
@@ -18365,6 +19197,13 @@ impl fmt::Display for DescribePipelineDefinitionForExecutionError {
     }
 }
 impl Error for DescribePipelineDefinitionForExecutionError {}
+impl ServiceErrorCode for DescribePipelineDefinitionForExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePipelineDefinitionForExecutionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribePipelineExecution
 #[derive(Debug, PartialEq)]
 pub enum DescribePipelineExecutionError {
@@ -18397,6 +19236,13 @@ impl fmt::Display for DescribePipelineExecutionError {
     }
 }
 impl Error for DescribePipelineExecutionError {}
+impl ServiceErrorCode for DescribePipelineExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePipelineExecutionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeProcessingJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeProcessingJobError {
@@ -18429,6 +19275,13 @@ impl fmt::Display for DescribeProcessingJobError {
     }
 }
 impl Error for DescribeProcessingJobError {}
+impl ServiceErrorCode for DescribeProcessingJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProcessingJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeProject
 #[derive(Debug, PartialEq)]
 pub enum DescribeProjectError {}
@@ -18451,6 +19304,11 @@ impl fmt::Display for DescribeProjectError {
     }
 }
 impl Error for DescribeProjectError {}
+impl ServiceErrorCode for DescribeProjectError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeSubscribedWorkteam
 #[derive(Debug, PartialEq)]
 pub enum DescribeSubscribedWorkteamError {}
@@ -18475,6 +19333,11 @@ impl fmt::Display for DescribeSubscribedWorkteamError {
     }
 }
 impl Error for DescribeSubscribedWorkteamError {}
+impl ServiceErrorCode for DescribeSubscribedWorkteamError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeTrainingJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeTrainingJobError {
@@ -18507,6 +19370,13 @@ impl fmt::Display for DescribeTrainingJobError {
     }
 }
 impl Error for DescribeTrainingJobError {}
+impl ServiceErrorCode for DescribeTrainingJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTrainingJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeTransformJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeTransformJobError {
@@ -18539,6 +19409,13 @@ impl fmt::Display for DescribeTransformJobError {
     }
 }
 impl Error for DescribeTransformJobError {}
+impl ServiceErrorCode for DescribeTransformJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTransformJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeTrial
 #[derive(Debug, PartialEq)]
 pub enum DescribeTrialError {
@@ -18569,6 +19446,13 @@ impl fmt::Display for DescribeTrialError {
     }
 }
 impl Error for DescribeTrialError {}
+impl ServiceErrorCode for DescribeTrialError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTrialError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeTrialComponent
 #[derive(Debug, PartialEq)]
 pub enum DescribeTrialComponentError {
@@ -18601,6 +19485,13 @@ impl fmt::Display for DescribeTrialComponentError {
     }
 }
 impl Error for DescribeTrialComponentError {}
+impl ServiceErrorCode for DescribeTrialComponentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTrialComponentError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeUserProfile
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserProfileError {
@@ -18633,6 +19524,13 @@ impl fmt::Display for DescribeUserProfileError {
     }
 }
 impl Error for DescribeUserProfileError {}
+impl ServiceErrorCode for DescribeUserProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserProfileError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by DescribeWorkforce
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorkforceError {}
@@ -18655,6 +19553,11 @@ impl fmt::Display for DescribeWorkforceError {
     }
 }
 impl Error for DescribeWorkforceError {}
+impl ServiceErrorCode for DescribeWorkforceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeWorkteam
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorkteamError {}
@@ -18677,6 +19580,11 @@ impl fmt::Display for DescribeWorkteamError {
     }
 }
 impl Error for DescribeWorkteamError {}
+impl ServiceErrorCode for DescribeWorkteamError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisableSagemakerServicecatalogPortfolio
 #[derive(Debug, PartialEq)]
 pub enum DisableSagemakerServicecatalogPortfolioError {}
@@ -18701,6 +19609,11 @@ impl fmt::Display for DisableSagemakerServicecatalogPortfolioError {
     }
 }
 impl Error for DisableSagemakerServicecatalogPortfolioError {}
+impl ServiceErrorCode for DisableSagemakerServicecatalogPortfolioError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DisassociateTrialComponent
 #[derive(Debug, PartialEq)]
 pub enum DisassociateTrialComponentError {
@@ -18735,6 +19648,13 @@ impl fmt::Display for DisassociateTrialComponentError {
     }
 }
 impl Error for DisassociateTrialComponentError {}
+impl ServiceErrorCode for DisassociateTrialComponentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateTrialComponentError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by EnableSagemakerServicecatalogPortfolio
 #[derive(Debug, PartialEq)]
 pub enum EnableSagemakerServicecatalogPortfolioError {}
@@ -18759,6 +19679,11 @@ impl fmt::Display for EnableSagemakerServicecatalogPortfolioError {
     }
 }
 impl Error for EnableSagemakerServicecatalogPortfolioError {}
+impl ServiceErrorCode for EnableSagemakerServicecatalogPortfolioError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetDeviceFleetReport
 #[derive(Debug, PartialEq)]
 pub enum GetDeviceFleetReportError {}
@@ -18781,6 +19706,11 @@ impl fmt::Display for GetDeviceFleetReportError {
     }
 }
 impl Error for GetDeviceFleetReportError {}
+impl ServiceErrorCode for GetDeviceFleetReportError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetModelPackageGroupPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetModelPackageGroupPolicyError {}
@@ -18805,6 +19735,11 @@ impl fmt::Display for GetModelPackageGroupPolicyError {
     }
 }
 impl Error for GetModelPackageGroupPolicyError {}
+impl ServiceErrorCode for GetModelPackageGroupPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetSagemakerServicecatalogPortfolioStatus
 #[derive(Debug, PartialEq)]
 pub enum GetSagemakerServicecatalogPortfolioStatusError {}
@@ -18829,6 +19764,11 @@ impl fmt::Display for GetSagemakerServicecatalogPortfolioStatusError {
     }
 }
 impl Error for GetSagemakerServicecatalogPortfolioStatusError {}
+impl ServiceErrorCode for GetSagemakerServicecatalogPortfolioStatusError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetSearchSuggestions
 #[derive(Debug, PartialEq)]
 pub enum GetSearchSuggestionsError {}
@@ -18851,6 +19791,11 @@ impl fmt::Display for GetSearchSuggestionsError {
     }
 }
 impl Error for GetSearchSuggestionsError {}
+impl ServiceErrorCode for GetSearchSuggestionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListActions
 #[derive(Debug, PartialEq)]
 pub enum ListActionsError {
@@ -18881,6 +19826,13 @@ impl fmt::Display for ListActionsError {
     }
 }
 impl Error for ListActionsError {}
+impl ServiceErrorCode for ListActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListActionsError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by ListAlgorithms
 #[derive(Debug, PartialEq)]
 pub enum ListAlgorithmsError {}
@@ -18903,6 +19855,11 @@ impl fmt::Display for ListAlgorithmsError {
     }
 }
 impl Error for ListAlgorithmsError {}
+impl ServiceErrorCode for ListAlgorithmsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListAppImageConfigs
 #[derive(Debug, PartialEq)]
 pub enum ListAppImageConfigsError {}
@@ -18925,6 +19882,11 @@ impl fmt::Display for ListAppImageConfigsError {
     }
 }
 impl Error for ListAppImageConfigsError {}
+impl ServiceErrorCode for ListAppImageConfigsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListApps
 #[derive(Debug, PartialEq)]
 pub enum ListAppsError {}
@@ -18947,6 +19909,11 @@ impl fmt::Display for ListAppsError {
     }
 }
 impl Error for ListAppsError {}
+impl ServiceErrorCode for ListAppsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListArtifacts
 #[derive(Debug, PartialEq)]
 pub enum ListArtifactsError {
@@ -18977,6 +19944,13 @@ impl fmt::Display for ListArtifactsError {
     }
 }
 impl Error for ListArtifactsError {}
+impl ServiceErrorCode for ListArtifactsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListArtifactsError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by ListAssociations
 #[derive(Debug, PartialEq)]
 pub enum ListAssociationsError {
@@ -19007,6 +19981,13 @@ impl fmt::Display for ListAssociationsError {
     }
 }
 impl Error for ListAssociationsError {}
+impl ServiceErrorCode for ListAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAssociationsError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by ListAutoMLJobs
 #[derive(Debug, PartialEq)]
 pub enum ListAutoMLJobsError {}
@@ -19029,6 +20010,11 @@ impl fmt::Display for ListAutoMLJobsError {
     }
 }
 impl Error for ListAutoMLJobsError {}
+impl ServiceErrorCode for ListAutoMLJobsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListCandidatesForAutoMLJob
 #[derive(Debug, PartialEq)]
 pub enum ListCandidatesForAutoMLJobError {
@@ -19063,6 +20049,13 @@ impl fmt::Display for ListCandidatesForAutoMLJobError {
     }
 }
 impl Error for ListCandidatesForAutoMLJobError {}
+impl ServiceErrorCode for ListCandidatesForAutoMLJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCandidatesForAutoMLJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by ListCodeRepositories
 #[derive(Debug, PartialEq)]
 pub enum ListCodeRepositoriesError {}
@@ -19085,6 +20078,11 @@ impl fmt::Display for ListCodeRepositoriesError {
     }
 }
 impl Error for ListCodeRepositoriesError {}
+impl ServiceErrorCode for ListCodeRepositoriesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListCompilationJobs
 #[derive(Debug, PartialEq)]
 pub enum ListCompilationJobsError {}
@@ -19107,6 +20105,11 @@ impl fmt::Display for ListCompilationJobsError {
     }
 }
 impl Error for ListCompilationJobsError {}
+impl ServiceErrorCode for ListCompilationJobsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListContexts
 #[derive(Debug, PartialEq)]
 pub enum ListContextsError {
@@ -19137,6 +20140,13 @@ impl fmt::Display for ListContextsError {
     }
 }
 impl Error for ListContextsError {}
+impl ServiceErrorCode for ListContextsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListContextsError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by ListDataQualityJobDefinitions
 #[derive(Debug, PartialEq)]
 pub enum ListDataQualityJobDefinitionsError {}
@@ -19161,6 +20171,11 @@ impl fmt::Display for ListDataQualityJobDefinitionsError {
     }
 }
 impl Error for ListDataQualityJobDefinitionsError {}
+impl ServiceErrorCode for ListDataQualityJobDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListDeviceFleets
 #[derive(Debug, PartialEq)]
 pub enum ListDeviceFleetsError {}
@@ -19183,6 +20198,11 @@ impl fmt::Display for ListDeviceFleetsError {
     }
 }
 impl Error for ListDeviceFleetsError {}
+impl ServiceErrorCode for ListDeviceFleetsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListDevices
 #[derive(Debug, PartialEq)]
 pub enum ListDevicesError {}
@@ -19205,6 +20225,11 @@ impl fmt::Display for ListDevicesError {
     }
 }
 impl Error for ListDevicesError {}
+impl ServiceErrorCode for ListDevicesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListDomains
 #[derive(Debug, PartialEq)]
 pub enum ListDomainsError {}
@@ -19227,6 +20252,11 @@ impl fmt::Display for ListDomainsError {
     }
 }
 impl Error for ListDomainsError {}
+impl ServiceErrorCode for ListDomainsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListEdgePackagingJobs
 #[derive(Debug, PartialEq)]
 pub enum ListEdgePackagingJobsError {}
@@ -19249,6 +20279,11 @@ impl fmt::Display for ListEdgePackagingJobsError {
     }
 }
 impl Error for ListEdgePackagingJobsError {}
+impl ServiceErrorCode for ListEdgePackagingJobsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListEndpointConfigs
 #[derive(Debug, PartialEq)]
 pub enum ListEndpointConfigsError {}
@@ -19271,6 +20306,11 @@ impl fmt::Display for ListEndpointConfigsError {
     }
 }
 impl Error for ListEndpointConfigsError {}
+impl ServiceErrorCode for ListEndpointConfigsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListEndpoints
 #[derive(Debug, PartialEq)]
 pub enum ListEndpointsError {}
@@ -19293,6 +20333,11 @@ impl fmt::Display for ListEndpointsError {
     }
 }
 impl Error for ListEndpointsError {}
+impl ServiceErrorCode for ListEndpointsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListExperiments
 #[derive(Debug, PartialEq)]
 pub enum ListExperimentsError {}
@@ -19315,6 +20360,11 @@ impl fmt::Display for ListExperimentsError {
     }
 }
 impl Error for ListExperimentsError {}
+impl ServiceErrorCode for ListExperimentsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListFeatureGroups
 #[derive(Debug, PartialEq)]
 pub enum ListFeatureGroupsError {}
@@ -19337,6 +20387,11 @@ impl fmt::Display for ListFeatureGroupsError {
     }
 }
 impl Error for ListFeatureGroupsError {}
+impl ServiceErrorCode for ListFeatureGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListFlowDefinitions
 #[derive(Debug, PartialEq)]
 pub enum ListFlowDefinitionsError {}
@@ -19359,6 +20414,11 @@ impl fmt::Display for ListFlowDefinitionsError {
     }
 }
 impl Error for ListFlowDefinitionsError {}
+impl ServiceErrorCode for ListFlowDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListHumanTaskUis
 #[derive(Debug, PartialEq)]
 pub enum ListHumanTaskUisError {}
@@ -19381,6 +20441,11 @@ impl fmt::Display for ListHumanTaskUisError {
     }
 }
 impl Error for ListHumanTaskUisError {}
+impl ServiceErrorCode for ListHumanTaskUisError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListHyperParameterTuningJobs
 #[derive(Debug, PartialEq)]
 pub enum ListHyperParameterTuningJobsError {}
@@ -19405,6 +20470,11 @@ impl fmt::Display for ListHyperParameterTuningJobsError {
     }
 }
 impl Error for ListHyperParameterTuningJobsError {}
+impl ServiceErrorCode for ListHyperParameterTuningJobsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListImageVersions
 #[derive(Debug, PartialEq)]
 pub enum ListImageVersionsError {
@@ -19435,6 +20505,13 @@ impl fmt::Display for ListImageVersionsError {
     }
 }
 impl Error for ListImageVersionsError {}
+impl ServiceErrorCode for ListImageVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListImageVersionsError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by ListImages
 #[derive(Debug, PartialEq)]
 pub enum ListImagesError {}
@@ -19457,6 +20534,11 @@ impl fmt::Display for ListImagesError {
     }
 }
 impl Error for ListImagesError {}
+impl ServiceErrorCode for ListImagesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListLabelingJobs
 #[derive(Debug, PartialEq)]
 pub enum ListLabelingJobsError {}
@@ -19479,6 +20561,11 @@ impl fmt::Display for ListLabelingJobsError {
     }
 }
 impl Error for ListLabelingJobsError {}
+impl ServiceErrorCode for ListLabelingJobsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListLabelingJobsForWorkteam
 #[derive(Debug, PartialEq)]
 pub enum ListLabelingJobsForWorkteamError {
@@ -19513,6 +20600,13 @@ impl fmt::Display for ListLabelingJobsForWorkteamError {
     }
 }
 impl Error for ListLabelingJobsForWorkteamError {}
+impl ServiceErrorCode for ListLabelingJobsForWorkteamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLabelingJobsForWorkteamError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by ListModelBiasJobDefinitions
 #[derive(Debug, PartialEq)]
 pub enum ListModelBiasJobDefinitionsError {}
@@ -19536,7 +20630,12 @@ impl fmt::Display for ListModelBiasJobDefinitionsError {
         match *self {}
     }
 }
-impl Error for ListModelBiasJobDefinitionsError {}
+impl Error for ListModelBiasJobDefinitionsError {}
+impl ServiceErrorCode for ListModelBiasJobDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListModelExplainabilityJobDefinitions
 #[derive(Debug, PartialEq)]
 pub enum ListModelExplainabilityJobDefinitionsError {}
@@ -19561,6 +20660,11 @@ impl fmt::Display for ListModelExplainabilityJobDefinitionsError {
     }
 }
 impl Error for ListModelExplainabilityJobDefinitionsError {}
+impl ServiceErrorCode for ListModelExplainabilityJobDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListModelPackageGroups
 #[derive(Debug, PartialEq)]
 pub enum ListModelPackageGroupsError {}
@@ -19583,6 +20687,11 @@ impl fmt::Display for ListModelPackageGroupsError {
     }
 }
 impl Error for ListModelPackageGroupsError {}
+impl ServiceErrorCode for ListModelPackageGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListModelPackages
 #[derive(Debug, PartialEq)]
 pub enum ListModelPackagesError {}
@@ -19605,6 +20714,11 @@ impl fmt::Display for ListModelPackagesError {
     }
 }
 impl Error for ListModelPackagesError {}
+impl ServiceErrorCode for ListModelPackagesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListModelQualityJobDefinitions
 #[derive(Debug, PartialEq)]
 pub enum ListModelQualityJobDefinitionsError {}
@@ -19629,6 +20743,11 @@ impl fmt::Display for ListModelQualityJobDefinitionsError {
     }
 }
 impl Error for ListModelQualityJobDefinitionsError {}
+impl ServiceErrorCode for ListModelQualityJobDefinitionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListModels
 #[derive(Debug, PartialEq)]
 pub enum ListModelsError {}
@@ -19651,6 +20770,11 @@ impl fmt::Display for ListModelsError {
     }
 }
 impl Error for ListModelsError {}
+impl ServiceErrorCode for ListModelsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListMonitoringExecutions
 #[derive(Debug, PartialEq)]
 pub enum ListMonitoringExecutionsError {}
@@ -19673,6 +20797,11 @@ impl fmt::Display for ListMonitoringExecutionsError {
     }
 }
 impl Error for ListMonitoringExecutionsError {}
+impl ServiceErrorCode for ListMonitoringExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListMonitoringSchedules
 #[derive(Debug, PartialEq)]
 pub enum ListMonitoringSchedulesError {}
@@ -19695,6 +20824,11 @@ impl fmt::Display for ListMonitoringSchedulesError {
     }
 }
 impl Error for ListMonitoringSchedulesError {}
+impl ServiceErrorCode for ListMonitoringSchedulesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListNotebookInstanceLifecycleConfigs
 #[derive(Debug, PartialEq)]
 pub enum ListNotebookInstanceLifecycleConfigsError {}
@@ -19719,6 +20853,11 @@ impl fmt::Display for ListNotebookInstanceLifecycleConfigsError {
     }
 }
 impl Error for ListNotebookInstanceLifecycleConfigsError {}
+impl ServiceErrorCode for ListNotebookInstanceLifecycleConfigsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListNotebookInstances
 #[derive(Debug, PartialEq)]
 pub enum ListNotebookInstancesError {}
@@ -19741,6 +20880,11 @@ impl fmt::Display for ListNotebookInstancesError {
     }
 }
 impl Error for ListNotebookInstancesError {}
+impl ServiceErrorCode for ListNotebookInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListPipelineExecutionSteps
 #[derive(Debug, PartialEq)]
 pub enum ListPipelineExecutionStepsError {
@@ -19775,6 +20919,13 @@ impl fmt::Display for ListPipelineExecutionStepsError {
     }
 }
 impl Error for ListPipelineExecutionStepsError {}
+impl ServiceErrorCode for ListPipelineExecutionStepsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPipelineExecutionStepsError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by ListPipelineExecutions
 #[derive(Debug, PartialEq)]
 pub enum ListPipelineExecutionsError {
@@ -19807,6 +20958,13 @@ impl fmt::Display for ListPipelineExecutionsError {
     }
 }
 impl Error for ListPipelineExecutionsError {}
+impl ServiceErrorCode for ListPipelineExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPipelineExecutionsError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by ListPipelineParametersForExecution
 #[derive(Debug, PartialEq)]
 pub enum ListPipelineParametersForExecutionError {
@@ -19843,6 +21001,13 @@ impl fmt::Display for ListPipelineParametersForExecutionError {
     }
 }
 impl Error for ListPipelineParametersForExecutionError {}
+impl ServiceErrorCode for ListPipelineParametersForExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPipelineParametersForExecutionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by ListPipelines
 #[derive(Debug, PartialEq)]
 pub enum ListPipelinesError {}
@@ -19865,6 +21030,11 @@ impl fmt::Display for ListPipelinesError {
     }
 }
 impl Error for ListPipelinesError {}
+impl ServiceErrorCode for ListPipelinesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListProcessingJobs
 #[derive(Debug, PartialEq)]
 pub enum ListProcessingJobsError {}
@@ -19887,6 +21057,11 @@ impl fmt::Display for ListProcessingJobsError {
     }
 }
 impl Error for ListProcessingJobsError {}
+impl ServiceErrorCode for ListProcessingJobsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListProjects
 #[derive(Debug, PartialEq)]
 pub enum ListProjectsError {}
@@ -19909,6 +21084,11 @@ impl fmt::Display for ListProjectsError {
     }
 }
 impl Error for ListProjectsError {}
+impl ServiceErrorCode for ListProjectsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListSubscribedWorkteams
 #[derive(Debug, PartialEq)]
 pub enum ListSubscribedWorkteamsError {}
@@ -19931,6 +21111,11 @@ impl fmt::Display for ListSubscribedWorkteamsError {
     }
 }
 impl Error for ListSubscribedWorkteamsError {}
+impl ServiceErrorCode for ListSubscribedWorkteamsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
 pub enum ListTagsError {}
@@ -19953,6 +21138,11 @@ impl fmt::Display for ListTagsError {
     }
 }
 impl Error for ListTagsError {}
+impl ServiceErrorCode for ListTagsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListTrainingJobs
 #[derive(Debug, PartialEq)]
 pub enum ListTrainingJobsError {}
@@ -19975,6 +21165,11 @@ impl fmt::Display for ListTrainingJobsError {
     }
 }
 impl Error for ListTrainingJobsError {}
+impl ServiceErrorCode for ListTrainingJobsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListTrainingJobsForHyperParameterTuningJob
 #[derive(Debug, PartialEq)]
 pub enum ListTrainingJobsForHyperParameterTuningJobError {
@@ -20011,6 +21206,15 @@ impl fmt::Display for ListTrainingJobsForHyperParameterTuningJobError {
     }
 }
 impl Error for ListTrainingJobsForHyperParameterTuningJobError {}
+impl ServiceErrorCode for ListTrainingJobsForHyperParameterTuningJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTrainingJobsForHyperParameterTuningJobError::ResourceNotFound(_) => {
+                "ResourceNotFound"
+            }
+        }
+    }
+}
 /// Errors returned by ListTransformJobs
 #[derive(Debug, PartialEq)]
 pub enum ListTransformJobsError {}
@@ -20033,6 +21237,11 @@ impl fmt::Display for ListTransformJobsError {
     }
 }
 impl Error for ListTransformJobsError {}
+impl ServiceErrorCode for ListTransformJobsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListTrialComponents
 #[derive(Debug, PartialEq)]
 pub enum ListTrialComponentsError {
@@ -20065,6 +21274,13 @@ impl fmt::Display for ListTrialComponentsError {
     }
 }
 impl Error for ListTrialComponentsError {}
+impl ServiceErrorCode for ListTrialComponentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTrialComponentsError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by ListTrials
 #[derive(Debug, PartialEq)]
 pub enum ListTrialsError {
@@ -20095,6 +21311,13 @@ impl fmt::Display for ListTrialsError {
     }
 }
 impl Error for ListTrialsError {}
+impl ServiceErrorCode for ListTrialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTrialsError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by ListUserProfiles
 #[derive(Debug, PartialEq)]
 pub enum ListUserProfilesError {}
@@ -20117,6 +21340,11 @@ impl fmt::Display for ListUserProfilesError {
     }
 }
 impl Error for ListUserProfilesError {}
+impl ServiceErrorCode for ListUserProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListWorkforces
 #[derive(Debug, PartialEq)]
 pub enum ListWorkforcesError {}
@@ -20139,6 +21367,11 @@ impl fmt::Display for ListWorkforcesError {
     }
 }
 impl Error for ListWorkforcesError {}
+impl ServiceErrorCode for ListWorkforcesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListWorkteams
 #[derive(Debug, PartialEq)]
 pub enum ListWorkteamsError {}
@@ -20161,6 +21394,11 @@ impl fmt::Display for ListWorkteamsError {
     }
 }
 impl Error for ListWorkteamsError {}
+impl ServiceErrorCode for ListWorkteamsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutModelPackageGroupPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutModelPackageGroupPolicyError {}
@@ -20185,6 +21423,11 @@ impl fmt::Display for PutModelPackageGroupPolicyError {
     }
 }
 impl Error for PutModelPackageGroupPolicyError {}
+impl ServiceErrorCode for PutModelPackageGroupPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RegisterDevices
 #[derive(Debug, PartialEq)]
 pub enum RegisterDevicesError {
@@ -20217,6 +21460,13 @@ impl fmt::Display for RegisterDevicesError {
     }
 }
 impl Error for RegisterDevicesError {}
+impl ServiceErrorCode for RegisterDevicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterDevicesError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by RenderUiTemplate
 #[derive(Debug, PartialEq)]
 pub enum RenderUiTemplateError {
@@ -20247,6 +21497,13 @@ impl fmt::Display for RenderUiTemplateError {
     }
 }
 impl Error for RenderUiTemplateError {}
+impl ServiceErrorCode for RenderUiTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RenderUiTemplateError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by Search
 #[derive(Debug, PartialEq)]
 pub enum SearchError {}
@@ -20269,6 +21526,11 @@ impl fmt::Display for SearchError {
     }
 }
 impl Error for SearchError {}
+impl ServiceErrorCode for SearchError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by StartMonitoringSchedule
 #[derive(Debug, PartialEq)]
 pub enum StartMonitoringScheduleError {
@@ -20301,6 +21563,13 @@ impl fmt::Display for StartMonitoringScheduleError {
     }
 }
 impl Error for StartMonitoringScheduleError {}
+impl ServiceErrorCode for StartMonitoringScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartMonitoringScheduleError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by StartNotebookInstance
 #[derive(Debug, PartialEq)]
 pub enum StartNotebookInstanceError {
@@ -20333,6 +21602,13 @@ impl fmt::Display for StartNotebookInstanceError {
     }
 }
 impl Error for StartNotebookInstanceError {}
+impl ServiceErrorCode for StartNotebookInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartNotebookInstanceError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by StartPipelineExecution
 #[derive(Debug, PartialEq)]
 pub enum StartPipelineExecutionError {
@@ -20373,6 +21649,14 @@ impl fmt::Display for StartPipelineExecutionError {
     }
 }
 impl Error for StartPipelineExecutionError {}
+impl ServiceErrorCode for StartPipelineExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartPipelineExecutionError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            StartPipelineExecutionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by StopAutoMLJob
 #[derive(Debug, PartialEq)]
 pub enum StopAutoMLJobError {
@@ -20403,6 +21687,13 @@ impl fmt::Display for StopAutoMLJobError {
     }
 }
 impl Error for StopAutoMLJobError {}
+impl ServiceErrorCode for StopAutoMLJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopAutoMLJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by StopCompilationJob
 #[derive(Debug, PartialEq)]
 pub enum StopCompilationJobError {
@@ -20433,6 +21724,13 @@ impl fmt::Display for StopCompilationJobError {
     }
 }
 impl Error for StopCompilationJobError {}
+impl ServiceErrorCode for StopCompilationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopCompilationJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by StopEdgePackagingJob
 #[derive(Debug, PartialEq)]
 pub enum StopEdgePackagingJobError {}
@@ -20455,6 +21753,11 @@ impl fmt::Display for StopEdgePackagingJobError {
     }
 }
 impl Error for StopEdgePackagingJobError {}
+impl ServiceErrorCode for StopEdgePackagingJobError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by StopHyperParameterTuningJob
 #[derive(Debug, PartialEq)]
 pub enum StopHyperParameterTuningJobError {
@@ -20489,6 +21792,13 @@ impl fmt::Display for StopHyperParameterTuningJobError {
     }
 }
 impl Error for StopHyperParameterTuningJobError {}
+impl ServiceErrorCode for StopHyperParameterTuningJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopHyperParameterTuningJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by StopLabelingJob
 #[derive(Debug, PartialEq)]
 pub enum StopLabelingJobError {
@@ -20519,6 +21829,13 @@ impl fmt::Display for StopLabelingJobError {
     }
 }
 impl Error for StopLabelingJobError {}
+impl ServiceErrorCode for StopLabelingJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopLabelingJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by StopMonitoringSchedule
 #[derive(Debug, PartialEq)]
 pub enum StopMonitoringScheduleError {
@@ -20551,6 +21868,13 @@ impl fmt::Display for StopMonitoringScheduleError {
     }
 }
 impl Error for StopMonitoringScheduleError {}
+impl ServiceErrorCode for StopMonitoringScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopMonitoringScheduleError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by StopNotebookInstance
 #[derive(Debug, PartialEq)]
 pub enum StopNotebookInstanceError {}
@@ -20573,6 +21897,11 @@ impl fmt::Display for StopNotebookInstanceError {
     }
 }
 impl Error for StopNotebookInstanceError {}
+impl ServiceErrorCode for StopNotebookInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by StopPipelineExecution
 #[derive(Debug, PartialEq)]
 pub enum StopPipelineExecutionError {
@@ -20605,6 +21934,13 @@ impl fmt::Display for StopPipelineExecutionError {
     }
 }
 impl Error for StopPipelineExecutionError {}
+impl ServiceErrorCode for StopPipelineExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopPipelineExecutionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by StopProcessingJob
 #[derive(Debug, PartialEq)]
 pub enum StopProcessingJobError {
@@ -20635,6 +21971,13 @@ impl fmt::Display for StopProcessingJobError {
     }
 }
 impl Error for StopProcessingJobError {}
+impl ServiceErrorCode for StopProcessingJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopProcessingJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by StopTrainingJob
 #[derive(Debug, PartialEq)]
 pub enum StopTrainingJobError {
@@ -20665,6 +22008,13 @@ impl fmt::Display for StopTrainingJobError {
     }
 }
 impl Error for StopTrainingJobError {}
+impl ServiceErrorCode for StopTrainingJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopTrainingJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by StopTransformJob
 #[derive(Debug, PartialEq)]
 pub enum StopTransformJobError {
@@ -20695,6 +22045,13 @@ impl fmt::Display for StopTransformJobError {
     }
 }
 impl Error for StopTransformJobError {}
+impl ServiceErrorCode for StopTransformJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopTransformJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateAction
 #[derive(Debug, PartialEq)]
 pub enum UpdateActionError {
@@ -20731,6 +22088,14 @@ impl fmt::Display for UpdateActionError {
     }
 }
 impl Error for UpdateActionError {}
+impl ServiceErrorCode for UpdateActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateActionError::Conflict(_) => "ConflictException",
+            UpdateActionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateAppImageConfig
 #[derive(Debug, PartialEq)]
 pub enum UpdateAppImageConfigError {
@@ -20763,6 +22128,13 @@ impl fmt::Display for UpdateAppImageConfigError {
     }
 }
 impl Error for UpdateAppImageConfigError {}
+impl ServiceErrorCode for UpdateAppImageConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAppImageConfigError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateArtifact
 #[derive(Debug, PartialEq)]
 pub enum UpdateArtifactError {
@@ -20799,6 +22171,14 @@ impl fmt::Display for UpdateArtifactError {
     }
 }
 impl Error for UpdateArtifactError {}
+impl ServiceErrorCode for UpdateArtifactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateArtifactError::Conflict(_) => "ConflictException",
+            UpdateArtifactError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateCodeRepository
 #[derive(Debug, PartialEq)]
 pub enum UpdateCodeRepositoryError {}
@@ -20821,6 +22201,11 @@ impl fmt::Display for UpdateCodeRepositoryError {
     }
 }
 impl Error for UpdateCodeRepositoryError {}
+impl ServiceErrorCode for UpdateCodeRepositoryError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UpdateContext
 #[derive(Debug, PartialEq)]
 pub enum UpdateContextError {
@@ -20857,6 +22242,14 @@ impl fmt::Display for UpdateContextError {
     }
 }
 impl Error for UpdateContextError {}
+impl ServiceErrorCode for UpdateContextError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateContextError::Conflict(_) => "ConflictException",
+            UpdateContextError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateDeviceFleet
 #[derive(Debug, PartialEq)]
 pub enum UpdateDeviceFleetError {
@@ -20887,6 +22280,13 @@ impl fmt::Display for UpdateDeviceFleetError {
     }
 }
 impl Error for UpdateDeviceFleetError {}
+impl ServiceErrorCode for UpdateDeviceFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDeviceFleetError::ResourceInUse(_) => "ResourceInUse",
+        }
+    }
+}
 /// Errors returned by UpdateDevices
 #[derive(Debug, PartialEq)]
 pub enum UpdateDevicesError {}
@@ -20909,6 +22309,11 @@ impl fmt::Display for UpdateDevicesError {
     }
 }
 impl Error for UpdateDevicesError {}
+impl ServiceErrorCode for UpdateDevicesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UpdateDomain
 #[derive(Debug, PartialEq)]
 pub enum UpdateDomainError {
@@ -20951,6 +22356,15 @@ impl fmt::Display for UpdateDomainError {
     }
 }
 impl Error for UpdateDomainError {}
+impl ServiceErrorCode for UpdateDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDomainError::ResourceInUse(_) => "ResourceInUse",
+            UpdateDomainError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            UpdateDomainError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateEndpoint
 #[derive(Debug, PartialEq)]
 pub enum UpdateEndpointError {
@@ -20983,6 +22397,13 @@ impl fmt::Display for UpdateEndpointError {
     }
 }
 impl Error for UpdateEndpointError {}
+impl ServiceErrorCode for UpdateEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateEndpointError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by UpdateEndpointWeightsAndCapacities
 #[derive(Debug, PartialEq)]
 pub enum UpdateEndpointWeightsAndCapacitiesError {
@@ -21019,6 +22440,15 @@ impl fmt::Display for UpdateEndpointWeightsAndCapacitiesError {
     }
 }
 impl Error for UpdateEndpointWeightsAndCapacitiesError {}
+impl ServiceErrorCode for UpdateEndpointWeightsAndCapacitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateEndpointWeightsAndCapacitiesError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateExperiment
 #[derive(Debug, PartialEq)]
 pub enum UpdateExperimentError {
@@ -21055,6 +22485,14 @@ impl fmt::Display for UpdateExperimentError {
     }
 }
 impl Error for UpdateExperimentError {}
+impl ServiceErrorCode for UpdateExperimentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateExperimentError::Conflict(_) => "ConflictException",
+            UpdateExperimentError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateImage
 #[derive(Debug, PartialEq)]
 pub enum UpdateImageError {
@@ -21091,6 +22529,14 @@ impl fmt::Display for UpdateImageError {
     }
 }
 impl Error for UpdateImageError {}
+impl ServiceErrorCode for UpdateImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateImageError::ResourceInUse(_) => "ResourceInUse",
+            UpdateImageError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateModelPackage
 #[derive(Debug, PartialEq)]
 pub enum UpdateModelPackageError {}
@@ -21113,6 +22559,11 @@ impl fmt::Display for UpdateModelPackageError {
     }
 }
 impl Error for UpdateModelPackageError {}
+impl ServiceErrorCode for UpdateModelPackageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UpdateMonitoringSchedule
 #[derive(Debug, PartialEq)]
 pub enum UpdateMonitoringScheduleError {
@@ -21155,6 +22606,14 @@ impl fmt::Display for UpdateMonitoringScheduleError {
     }
 }
 impl Error for UpdateMonitoringScheduleError {}
+impl ServiceErrorCode for UpdateMonitoringScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMonitoringScheduleError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            UpdateMonitoringScheduleError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateNotebookInstance
 #[derive(Debug, PartialEq)]
 pub enum UpdateNotebookInstanceError {
@@ -21187,6 +22646,13 @@ impl fmt::Display for UpdateNotebookInstanceError {
     }
 }
 impl Error for UpdateNotebookInstanceError {}
+impl ServiceErrorCode for UpdateNotebookInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateNotebookInstanceError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Errors returned by UpdateNotebookInstanceLifecycleConfig
 #[derive(Debug, PartialEq)]
 pub enum UpdateNotebookInstanceLifecycleConfigError {
@@ -21223,6 +22689,15 @@ impl fmt::Display for UpdateNotebookInstanceLifecycleConfigError {
     }
 }
 impl Error for UpdateNotebookInstanceLifecycleConfigError {}
+impl ServiceErrorCode for UpdateNotebookInstanceLifecycleConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateNotebookInstanceLifecycleConfigError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by UpdatePipeline
 #[derive(Debug, PartialEq)]
 pub enum UpdatePipelineError {
@@ -21253,6 +22728,13 @@ impl fmt::Display for UpdatePipelineError {
     }
 }
 impl Error for UpdatePipelineError {}
+impl ServiceErrorCode for UpdatePipelineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePipelineError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdatePipelineExecution
 #[derive(Debug, PartialEq)]
 pub enum UpdatePipelineExecutionError {
@@ -21285,6 +22767,13 @@ impl fmt::Display for UpdatePipelineExecutionError {
     }
 }
 impl Error for UpdatePipelineExecutionError {}
+impl ServiceErrorCode for UpdatePipelineExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePipelineExecutionError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateTrainingJob
 #[derive(Debug, PartialEq)]
 pub enum UpdateTrainingJobError {
@@ -21315,6 +22804,13 @@ impl fmt::Display for UpdateTrainingJobError {
     }
 }
 impl Error for UpdateTrainingJobError {}
+impl ServiceErrorCode for UpdateTrainingJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTrainingJobError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateTrial
 #[derive(Debug, PartialEq)]
 pub enum UpdateTrialError {
@@ -21351,6 +22847,14 @@ impl fmt::Display for UpdateTrialError {
     }
 }
 impl Error for UpdateTrialError {}
+impl ServiceErrorCode for UpdateTrialError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTrialError::Conflict(_) => "ConflictException",
+            UpdateTrialError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateTrialComponent
 #[derive(Debug, PartialEq)]
 pub enum UpdateTrialComponentError {
@@ -21389,6 +22893,14 @@ impl fmt::Display for UpdateTrialComponentError {
     }
 }
 impl Error for UpdateTrialComponentError {}
+impl ServiceErrorCode for UpdateTrialComponentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTrialComponentError::Conflict(_) => "ConflictException",
+            UpdateTrialComponentError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateUserProfile
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserProfileError {
@@ -21433,6 +22945,15 @@ impl fmt::Display for UpdateUserProfileError {
     }
 }
 impl Error for UpdateUserProfileError {}
+impl ServiceErrorCode for UpdateUserProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserProfileError::ResourceInUse(_) => "ResourceInUse",
+            UpdateUserProfileError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            UpdateUserProfileError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateWorkforce
 #[derive(Debug, PartialEq)]
 pub enum UpdateWorkforceError {}
@@ -21455,6 +22976,11 @@ impl fmt::Display for UpdateWorkforceError {
     }
 }
 impl Error for UpdateWorkforceError {}
+impl ServiceErrorCode for UpdateWorkforceError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UpdateWorkteam
 #[derive(Debug, PartialEq)]
 pub enum UpdateWorkteamError {
@@ -21487,6 +23013,13 @@ impl fmt::Display for UpdateWorkteamError {
     }
 }
 impl Error for UpdateWorkteamError {}
+impl ServiceErrorCode for UpdateWorkteamError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateWorkteamError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+        }
+    }
+}
 /// Trait representing the capabilities of the SageMaker API. SageMaker clients implement this trait.
 #[async_trait]
 pub trait SageMaker {
@@ -23441,6 +24974,12 @@ impl SageMaker for SageMakerClient {
         &self,
         input: CreateImageVersionRequest,
     ) -> Result<CreateImageVersionResponse, RusotoError<CreateImageVersionError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "SageMaker.CreateImageVersion");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -23543,6 +25082,10 @@ impl SageMaker for SageMakerClient {
         &self,
         input: CreateModelPackageInput,
     ) -> Result<CreateModelPackageOutput, RusotoError<CreateModelPackageError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "SageMaker.CreateModelPackage");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -23668,6 +25211,12 @@ impl SageMaker for SageMakerClient {
         &self,
         input: CreatePipelineRequest,
     ) -> Result<CreatePipelineResponse, RusotoError<CreatePipelineError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "SageMaker.CreatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -24408,6 +25957,12 @@ impl SageMaker for SageMakerClient {
         &self,
         input: DeletePipelineRequest,
     ) -> Result<DeletePipelineResponse, RusotoError<DeletePipelineError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "SageMaker.DeletePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -26751,6 +28306,12 @@ impl SageMaker for SageMakerClient {
         &self,
         input: StartPipelineExecutionRequest,
     ) -> Result<StartPipelineExecutionResponse, RusotoError<StartPipelineExecutionError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "SageMaker.StartPipelineExecution");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -26889,6 +28450,12 @@ impl SageMaker for SageMakerClient {
         &self,
         input: StopPipelineExecutionRequest,
     ) -> Result<StopPipelineExecutionResponse, RusotoError<StopPipelineExecutionError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "SageMaker.StopPipelineExecution");
         let encoded = serde_json::to_string(&input).unwrap();
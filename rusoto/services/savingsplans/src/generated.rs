@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -28,6 +28,7 @@ use serde_json;
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateSavingsPlanRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -702,6 +703,15 @@ impl fmt::Display for CreateSavingsPlanError {
     }
 }
 impl Error for CreateSavingsPlanError {}
+impl ServiceErrorCode for CreateSavingsPlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSavingsPlanError::InternalServer(_) => "InternalServerException",
+            CreateSavingsPlanError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateSavingsPlanError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+        }
+    }
+}
 /// Errors returned by DeleteQueuedSavingsPlan
 #[derive(Debug, PartialEq)]
 pub enum DeleteQueuedSavingsPlanError {
@@ -750,6 +760,17 @@ impl fmt::Display for DeleteQueuedSavingsPlanError {
     }
 }
 impl Error for DeleteQueuedSavingsPlanError {}
+impl ServiceErrorCode for DeleteQueuedSavingsPlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteQueuedSavingsPlanError::InternalServer(_) => "InternalServerException",
+            DeleteQueuedSavingsPlanError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteQueuedSavingsPlanError::ServiceQuotaExceeded(_) => {
+                "ServiceQuotaExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeSavingsPlanRates
 #[derive(Debug, PartialEq)]
 pub enum DescribeSavingsPlanRatesError {
@@ -782,6 +803,13 @@ impl fmt::Display for DescribeSavingsPlanRatesError {
     }
 }
 impl Error for DescribeSavingsPlanRatesError {}
+impl ServiceErrorCode for DescribeSavingsPlanRatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSavingsPlanRatesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeSavingsPlans
 #[derive(Debug, PartialEq)]
 pub enum DescribeSavingsPlansError {
@@ -812,6 +840,13 @@ impl fmt::Display for DescribeSavingsPlansError {
     }
 }
 impl Error for DescribeSavingsPlansError {}
+impl ServiceErrorCode for DescribeSavingsPlansError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSavingsPlansError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by DescribeSavingsPlansOfferingRates
 #[derive(Debug, PartialEq)]
 pub enum DescribeSavingsPlansOfferingRatesError {
@@ -848,6 +883,13 @@ impl fmt::Display for DescribeSavingsPlansOfferingRatesError {
     }
 }
 impl Error for DescribeSavingsPlansOfferingRatesError {}
+impl ServiceErrorCode for DescribeSavingsPlansOfferingRatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSavingsPlansOfferingRatesError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by DescribeSavingsPlansOfferings
 #[derive(Debug, PartialEq)]
 pub enum DescribeSavingsPlansOfferingsError {
@@ -882,6 +924,13 @@ impl fmt::Display for DescribeSavingsPlansOfferingsError {
     }
 }
 impl Error for DescribeSavingsPlansOfferingsError {}
+impl ServiceErrorCode for DescribeSavingsPlansOfferingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSavingsPlansOfferingsError::InternalServer(_) => "InternalServerException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -920,6 +969,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalServer(_) => "InternalServerException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -962,6 +1019,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalServer(_) => "InternalServerException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::ServiceQuotaExceeded(_) => "ServiceQuotaExceededException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -998,6 +1064,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalServer(_) => "InternalServerException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWSSavingsPlans API. AWSSavingsPlans clients implement this trait.
 #[async_trait]
 pub trait SavingsPlans {
@@ -1107,6 +1181,10 @@ impl SavingsPlans for SavingsPlansClient {
         &self,
         input: CreateSavingsPlanRequest,
     ) -> Result<CreateSavingsPlanResponse, RusotoError<CreateSavingsPlanError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let request_uri = "/CreateSavingsPlan";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -998,6 +998,7 @@ pub struct UpdateRegistryResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateSchemaRequest {
     /// <p>The ID of the client token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientTokenId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token_id: Option<String>,
@@ -1118,6 +1119,18 @@ impl fmt::Display for CreateDiscovererError {
     }
 }
 impl Error for CreateDiscovererError {}
+impl ServiceErrorCode for CreateDiscovererError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDiscovererError::BadRequest(_) => "BadRequestException",
+            CreateDiscovererError::Conflict(_) => "ConflictException",
+            CreateDiscovererError::Forbidden(_) => "ForbiddenException",
+            CreateDiscovererError::InternalServerError(_) => "InternalServerErrorException",
+            CreateDiscovererError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateDiscovererError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateRegistry
 #[derive(Debug, PartialEq)]
 pub enum CreateRegistryError {
@@ -1177,6 +1190,18 @@ impl fmt::Display for CreateRegistryError {
     }
 }
 impl Error for CreateRegistryError {}
+impl ServiceErrorCode for CreateRegistryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRegistryError::BadRequest(_) => "BadRequestException",
+            CreateRegistryError::Conflict(_) => "ConflictException",
+            CreateRegistryError::Forbidden(_) => "ForbiddenException",
+            CreateRegistryError::InternalServerError(_) => "InternalServerErrorException",
+            CreateRegistryError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateRegistryError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateSchema
 #[derive(Debug, PartialEq)]
 pub enum CreateSchemaError {
@@ -1224,6 +1249,16 @@ impl fmt::Display for CreateSchemaError {
     }
 }
 impl Error for CreateSchemaError {}
+impl ServiceErrorCode for CreateSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSchemaError::BadRequest(_) => "BadRequestException",
+            CreateSchemaError::Forbidden(_) => "ForbiddenException",
+            CreateSchemaError::InternalServerError(_) => "InternalServerErrorException",
+            CreateSchemaError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteDiscoverer
 #[derive(Debug, PartialEq)]
 pub enum DeleteDiscovererError {
@@ -1285,6 +1320,18 @@ impl fmt::Display for DeleteDiscovererError {
     }
 }
 impl Error for DeleteDiscovererError {}
+impl ServiceErrorCode for DeleteDiscovererError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDiscovererError::BadRequest(_) => "BadRequestException",
+            DeleteDiscovererError::Forbidden(_) => "ForbiddenException",
+            DeleteDiscovererError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteDiscovererError::NotFound(_) => "NotFoundException",
+            DeleteDiscovererError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteDiscovererError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteRegistry
 #[derive(Debug, PartialEq)]
 pub enum DeleteRegistryError {
@@ -1344,6 +1391,18 @@ impl fmt::Display for DeleteRegistryError {
     }
 }
 impl Error for DeleteRegistryError {}
+impl ServiceErrorCode for DeleteRegistryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRegistryError::BadRequest(_) => "BadRequestException",
+            DeleteRegistryError::Forbidden(_) => "ForbiddenException",
+            DeleteRegistryError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteRegistryError::NotFound(_) => "NotFoundException",
+            DeleteRegistryError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteRegistryError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteResourcePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteResourcePolicyError {
@@ -1407,6 +1466,18 @@ impl fmt::Display for DeleteResourcePolicyError {
     }
 }
 impl Error for DeleteResourcePolicyError {}
+impl ServiceErrorCode for DeleteResourcePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResourcePolicyError::BadRequest(_) => "BadRequestException",
+            DeleteResourcePolicyError::Forbidden(_) => "ForbiddenException",
+            DeleteResourcePolicyError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteResourcePolicyError::NotFound(_) => "NotFoundException",
+            DeleteResourcePolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteResourcePolicyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteSchema
 #[derive(Debug, PartialEq)]
 pub enum DeleteSchemaError {
@@ -1466,6 +1537,18 @@ impl fmt::Display for DeleteSchemaError {
     }
 }
 impl Error for DeleteSchemaError {}
+impl ServiceErrorCode for DeleteSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSchemaError::BadRequest(_) => "BadRequestException",
+            DeleteSchemaError::Forbidden(_) => "ForbiddenException",
+            DeleteSchemaError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteSchemaError::NotFound(_) => "NotFoundException",
+            DeleteSchemaError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteSchemaError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteSchemaVersion
 #[derive(Debug, PartialEq)]
 pub enum DeleteSchemaVersionError {
@@ -1529,6 +1612,18 @@ impl fmt::Display for DeleteSchemaVersionError {
     }
 }
 impl Error for DeleteSchemaVersionError {}
+impl ServiceErrorCode for DeleteSchemaVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSchemaVersionError::BadRequest(_) => "BadRequestException",
+            DeleteSchemaVersionError::Forbidden(_) => "ForbiddenException",
+            DeleteSchemaVersionError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteSchemaVersionError::NotFound(_) => "NotFoundException",
+            DeleteSchemaVersionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteSchemaVersionError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeCodeBinding
 #[derive(Debug, PartialEq)]
 pub enum DescribeCodeBindingError {
@@ -1590,6 +1685,18 @@ impl fmt::Display for DescribeCodeBindingError {
     }
 }
 impl Error for DescribeCodeBindingError {}
+impl ServiceErrorCode for DescribeCodeBindingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCodeBindingError::BadRequest(_) => "BadRequestException",
+            DescribeCodeBindingError::Forbidden(_) => "ForbiddenException",
+            DescribeCodeBindingError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeCodeBindingError::NotFound(_) => "NotFoundException",
+            DescribeCodeBindingError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribeCodeBindingError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeDiscoverer
 #[derive(Debug, PartialEq)]
 pub enum DescribeDiscovererError {
@@ -1653,6 +1760,18 @@ impl fmt::Display for DescribeDiscovererError {
     }
 }
 impl Error for DescribeDiscovererError {}
+impl ServiceErrorCode for DescribeDiscovererError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDiscovererError::BadRequest(_) => "BadRequestException",
+            DescribeDiscovererError::Forbidden(_) => "ForbiddenException",
+            DescribeDiscovererError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeDiscovererError::NotFound(_) => "NotFoundException",
+            DescribeDiscovererError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeDiscovererError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeRegistry
 #[derive(Debug, PartialEq)]
 pub enum DescribeRegistryError {
@@ -1714,6 +1833,18 @@ impl fmt::Display for DescribeRegistryError {
     }
 }
 impl Error for DescribeRegistryError {}
+impl ServiceErrorCode for DescribeRegistryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRegistryError::BadRequest(_) => "BadRequestException",
+            DescribeRegistryError::Forbidden(_) => "ForbiddenException",
+            DescribeRegistryError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeRegistryError::NotFound(_) => "NotFoundException",
+            DescribeRegistryError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeRegistryError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeSchema
 #[derive(Debug, PartialEq)]
 pub enum DescribeSchemaError {
@@ -1773,6 +1904,18 @@ impl fmt::Display for DescribeSchemaError {
     }
 }
 impl Error for DescribeSchemaError {}
+impl ServiceErrorCode for DescribeSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSchemaError::BadRequest(_) => "BadRequestException",
+            DescribeSchemaError::Forbidden(_) => "ForbiddenException",
+            DescribeSchemaError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeSchemaError::NotFound(_) => "NotFoundException",
+            DescribeSchemaError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeSchemaError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ExportSchema
 #[derive(Debug, PartialEq)]
 pub enum ExportSchemaError {
@@ -1838,6 +1981,19 @@ impl fmt::Display for ExportSchemaError {
     }
 }
 impl Error for ExportSchemaError {}
+impl ServiceErrorCode for ExportSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExportSchemaError::BadRequest(_) => "BadRequestException",
+            ExportSchemaError::Forbidden(_) => "ForbiddenException",
+            ExportSchemaError::InternalServerError(_) => "InternalServerErrorException",
+            ExportSchemaError::NotFound(_) => "NotFoundException",
+            ExportSchemaError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ExportSchemaError::TooManyRequests(_) => "TooManyRequestsException",
+            ExportSchemaError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetCodeBindingSource
 #[derive(Debug, PartialEq)]
 pub enum GetCodeBindingSourceError {
@@ -1901,6 +2057,18 @@ impl fmt::Display for GetCodeBindingSourceError {
     }
 }
 impl Error for GetCodeBindingSourceError {}
+impl ServiceErrorCode for GetCodeBindingSourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCodeBindingSourceError::BadRequest(_) => "BadRequestException",
+            GetCodeBindingSourceError::Forbidden(_) => "ForbiddenException",
+            GetCodeBindingSourceError::InternalServerError(_) => "InternalServerErrorException",
+            GetCodeBindingSourceError::NotFound(_) => "NotFoundException",
+            GetCodeBindingSourceError::TooManyRequests(_) => "TooManyRequestsException",
+            GetCodeBindingSourceError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetDiscoveredSchema
 #[derive(Debug, PartialEq)]
 pub enum GetDiscoveredSchemaError {
@@ -1958,6 +2126,17 @@ impl fmt::Display for GetDiscoveredSchemaError {
     }
 }
 impl Error for GetDiscoveredSchemaError {}
+impl ServiceErrorCode for GetDiscoveredSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDiscoveredSchemaError::BadRequest(_) => "BadRequestException",
+            GetDiscoveredSchemaError::Forbidden(_) => "ForbiddenException",
+            GetDiscoveredSchemaError::InternalServerError(_) => "InternalServerErrorException",
+            GetDiscoveredSchemaError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetDiscoveredSchemaError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by GetResourcePolicy
 #[derive(Debug, PartialEq)]
 pub enum GetResourcePolicyError {
@@ -2021,6 +2200,18 @@ impl fmt::Display for GetResourcePolicyError {
     }
 }
 impl Error for GetResourcePolicyError {}
+impl ServiceErrorCode for GetResourcePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourcePolicyError::BadRequest(_) => "BadRequestException",
+            GetResourcePolicyError::Forbidden(_) => "ForbiddenException",
+            GetResourcePolicyError::InternalServerError(_) => "InternalServerErrorException",
+            GetResourcePolicyError::NotFound(_) => "NotFoundException",
+            GetResourcePolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetResourcePolicyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListDiscoverers
 #[derive(Debug, PartialEq)]
 pub enum ListDiscoverersError {
@@ -2074,6 +2265,17 @@ impl fmt::Display for ListDiscoverersError {
     }
 }
 impl Error for ListDiscoverersError {}
+impl ServiceErrorCode for ListDiscoverersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDiscoverersError::BadRequest(_) => "BadRequestException",
+            ListDiscoverersError::Forbidden(_) => "ForbiddenException",
+            ListDiscoverersError::InternalServerError(_) => "InternalServerErrorException",
+            ListDiscoverersError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListDiscoverersError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListRegistries
 #[derive(Debug, PartialEq)]
 pub enum ListRegistriesError {
@@ -2127,6 +2329,17 @@ impl fmt::Display for ListRegistriesError {
     }
 }
 impl Error for ListRegistriesError {}
+impl ServiceErrorCode for ListRegistriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRegistriesError::BadRequest(_) => "BadRequestException",
+            ListRegistriesError::Forbidden(_) => "ForbiddenException",
+            ListRegistriesError::InternalServerError(_) => "InternalServerErrorException",
+            ListRegistriesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListRegistriesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListSchemaVersions
 #[derive(Debug, PartialEq)]
 pub enum ListSchemaVersionsError {
@@ -2190,6 +2403,18 @@ impl fmt::Display for ListSchemaVersionsError {
     }
 }
 impl Error for ListSchemaVersionsError {}
+impl ServiceErrorCode for ListSchemaVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSchemaVersionsError::BadRequest(_) => "BadRequestException",
+            ListSchemaVersionsError::Forbidden(_) => "ForbiddenException",
+            ListSchemaVersionsError::InternalServerError(_) => "InternalServerErrorException",
+            ListSchemaVersionsError::NotFound(_) => "NotFoundException",
+            ListSchemaVersionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListSchemaVersionsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListSchemas
 #[derive(Debug, PartialEq)]
 pub enum ListSchemasError {
@@ -2243,6 +2468,17 @@ impl fmt::Display for ListSchemasError {
     }
 }
 impl Error for ListSchemasError {}
+impl ServiceErrorCode for ListSchemasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSchemasError::BadRequest(_) => "BadRequestException",
+            ListSchemasError::Forbidden(_) => "ForbiddenException",
+            ListSchemasError::InternalServerError(_) => "InternalServerErrorException",
+            ListSchemasError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ListSchemasError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2292,6 +2528,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::Forbidden(_) => "ForbiddenException",
+            ListTagsForResourceError::InternalServerError(_) => "InternalServerErrorException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by PutCodeBinding
 #[derive(Debug, PartialEq)]
 pub enum PutCodeBindingError {
@@ -2355,6 +2601,19 @@ impl fmt::Display for PutCodeBindingError {
     }
 }
 impl Error for PutCodeBindingError {}
+impl ServiceErrorCode for PutCodeBindingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutCodeBindingError::BadRequest(_) => "BadRequestException",
+            PutCodeBindingError::Forbidden(_) => "ForbiddenException",
+            PutCodeBindingError::Gone(_) => "GoneException",
+            PutCodeBindingError::InternalServerError(_) => "InternalServerErrorException",
+            PutCodeBindingError::NotFound(_) => "NotFoundException",
+            PutCodeBindingError::TooManyRequests(_) => "TooManyRequestsException",
+            PutCodeBindingError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by PutResourcePolicy
 #[derive(Debug, PartialEq)]
 pub enum PutResourcePolicyError {
@@ -2426,6 +2685,19 @@ impl fmt::Display for PutResourcePolicyError {
     }
 }
 impl Error for PutResourcePolicyError {}
+impl ServiceErrorCode for PutResourcePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutResourcePolicyError::BadRequest(_) => "BadRequestException",
+            PutResourcePolicyError::Forbidden(_) => "ForbiddenException",
+            PutResourcePolicyError::InternalServerError(_) => "InternalServerErrorException",
+            PutResourcePolicyError::NotFound(_) => "NotFoundException",
+            PutResourcePolicyError::PreconditionFailed(_) => "PreconditionFailedException",
+            PutResourcePolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            PutResourcePolicyError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by SearchSchemas
 #[derive(Debug, PartialEq)]
 pub enum SearchSchemasError {
@@ -2479,6 +2751,17 @@ impl fmt::Display for SearchSchemasError {
     }
 }
 impl Error for SearchSchemasError {}
+impl ServiceErrorCode for SearchSchemasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchSchemasError::BadRequest(_) => "BadRequestException",
+            SearchSchemasError::Forbidden(_) => "ForbiddenException",
+            SearchSchemasError::InternalServerError(_) => "InternalServerErrorException",
+            SearchSchemasError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            SearchSchemasError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by StartDiscoverer
 #[derive(Debug, PartialEq)]
 pub enum StartDiscovererError {
@@ -2538,6 +2821,18 @@ impl fmt::Display for StartDiscovererError {
     }
 }
 impl Error for StartDiscovererError {}
+impl ServiceErrorCode for StartDiscovererError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDiscovererError::BadRequest(_) => "BadRequestException",
+            StartDiscovererError::Forbidden(_) => "ForbiddenException",
+            StartDiscovererError::InternalServerError(_) => "InternalServerErrorException",
+            StartDiscovererError::NotFound(_) => "NotFoundException",
+            StartDiscovererError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            StartDiscovererError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by StopDiscoverer
 #[derive(Debug, PartialEq)]
 pub enum StopDiscovererError {
@@ -2597,6 +2892,18 @@ impl fmt::Display for StopDiscovererError {
     }
 }
 impl Error for StopDiscovererError {}
+impl ServiceErrorCode for StopDiscovererError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopDiscovererError::BadRequest(_) => "BadRequestException",
+            StopDiscovererError::Forbidden(_) => "ForbiddenException",
+            StopDiscovererError::InternalServerError(_) => "InternalServerErrorException",
+            StopDiscovererError::NotFound(_) => "NotFoundException",
+            StopDiscovererError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            StopDiscovererError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2644,6 +2951,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::Forbidden(_) => "ForbiddenException",
+            TagResourceError::InternalServerError(_) => "InternalServerErrorException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2691,6 +3008,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::Forbidden(_) => "ForbiddenException",
+            UntagResourceError::InternalServerError(_) => "InternalServerErrorException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateDiscoverer
 #[derive(Debug, PartialEq)]
 pub enum UpdateDiscovererError {
@@ -2752,6 +3079,18 @@ impl fmt::Display for UpdateDiscovererError {
     }
 }
 impl Error for UpdateDiscovererError {}
+impl ServiceErrorCode for UpdateDiscovererError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDiscovererError::BadRequest(_) => "BadRequestException",
+            UpdateDiscovererError::Forbidden(_) => "ForbiddenException",
+            UpdateDiscovererError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateDiscovererError::NotFound(_) => "NotFoundException",
+            UpdateDiscovererError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateDiscovererError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateRegistry
 #[derive(Debug, PartialEq)]
 pub enum UpdateRegistryError {
@@ -2811,6 +3150,18 @@ impl fmt::Display for UpdateRegistryError {
     }
 }
 impl Error for UpdateRegistryError {}
+impl ServiceErrorCode for UpdateRegistryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRegistryError::BadRequest(_) => "BadRequestException",
+            UpdateRegistryError::Forbidden(_) => "ForbiddenException",
+            UpdateRegistryError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateRegistryError::NotFound(_) => "NotFoundException",
+            UpdateRegistryError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateRegistryError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateSchema
 #[derive(Debug, PartialEq)]
 pub enum UpdateSchemaError {
@@ -2864,6 +3215,17 @@ impl fmt::Display for UpdateSchemaError {
     }
 }
 impl Error for UpdateSchemaError {}
+impl ServiceErrorCode for UpdateSchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSchemaError::BadRequest(_) => "BadRequestException",
+            UpdateSchemaError::Forbidden(_) => "ForbiddenException",
+            UpdateSchemaError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateSchemaError::NotFound(_) => "NotFoundException",
+            UpdateSchemaError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Schemas API. Schemas clients implement this trait.
 #[async_trait]
 pub trait Schemas {
@@ -4134,6 +4496,10 @@ impl Schemas for SchemasClient {
         &self,
         input: UpdateSchemaRequest,
     ) -> Result<UpdateSchemaResponse, RusotoError<UpdateSchemaError>> {
+        let mut input = input;
+        if input.client_token_id.is_none() {
+            input.client_token_id = self.client.idempotency_token();
+        }
         let request_uri = format!(
             "/v1/registries/name/{registry_name}/schemas/name/{schema_name}",
             registry_name = input.registry_name,
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -943,6 +943,11 @@ impl fmt::Display for BatchDeleteAttributesError {
     }
 }
 impl Error for BatchDeleteAttributesError {}
+impl ServiceErrorCode for BatchDeleteAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by BatchPutAttributes
 #[derive(Debug, PartialEq)]
 pub enum BatchPutAttributesError {
@@ -1069,6 +1074,29 @@ impl fmt::Display for BatchPutAttributesError {
     }
 }
 impl Error for BatchPutAttributesError {}
+impl ServiceErrorCode for BatchPutAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchPutAttributesError::DuplicateItemName(_) => "DuplicateItemName",
+            BatchPutAttributesError::InvalidParameterValue(_) => "InvalidParameterValue",
+            BatchPutAttributesError::MissingParameter(_) => "MissingParameter",
+            BatchPutAttributesError::NoSuchDomain(_) => "NoSuchDomain",
+            BatchPutAttributesError::NumberDomainAttributesExceeded(_) => {
+                "NumberDomainAttributesExceeded"
+            }
+            BatchPutAttributesError::NumberDomainBytesExceeded(_) => "NumberDomainBytesExceeded",
+            BatchPutAttributesError::NumberItemAttributesExceeded(_) => {
+                "NumberItemAttributesExceeded"
+            }
+            BatchPutAttributesError::NumberSubmittedAttributesExceeded(_) => {
+                "NumberSubmittedAttributesExceeded"
+            }
+            BatchPutAttributesError::NumberSubmittedItemsExceeded(_) => {
+                "NumberSubmittedItemsExceeded"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDomain
 #[derive(Debug, PartialEq)]
 pub enum CreateDomainError {
@@ -1129,6 +1157,15 @@ impl fmt::Display for CreateDomainError {
     }
 }
 impl Error for CreateDomainError {}
+impl ServiceErrorCode for CreateDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDomainError::InvalidParameterValue(_) => "InvalidParameterValue",
+            CreateDomainError::MissingParameter(_) => "MissingParameter",
+            CreateDomainError::NumberDomainsExceeded(_) => "NumberDomainsExceeded",
+        }
+    }
+}
 /// Errors returned by DeleteAttributes
 #[derive(Debug, PartialEq)]
 pub enum DeleteAttributesError {
@@ -1197,6 +1234,16 @@ impl fmt::Display for DeleteAttributesError {
     }
 }
 impl Error for DeleteAttributesError {}
+impl ServiceErrorCode for DeleteAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAttributesError::AttributeDoesNotExist(_) => "AttributeDoesNotExist",
+            DeleteAttributesError::InvalidParameterValue(_) => "InvalidParameterValue",
+            DeleteAttributesError::MissingParameter(_) => "MissingParameter",
+            DeleteAttributesError::NoSuchDomain(_) => "NoSuchDomain",
+        }
+    }
+}
 /// Errors returned by DeleteDomain
 #[derive(Debug, PartialEq)]
 pub enum DeleteDomainError {
@@ -1241,6 +1288,13 @@ impl fmt::Display for DeleteDomainError {
     }
 }
 impl Error for DeleteDomainError {}
+impl ServiceErrorCode for DeleteDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDomainError::MissingParameter(_) => "MissingParameter",
+        }
+    }
+}
 /// Errors returned by DomainMetadata
 #[derive(Debug, PartialEq)]
 pub enum DomainMetadataError {
@@ -1293,6 +1347,14 @@ impl fmt::Display for DomainMetadataError {
     }
 }
 impl Error for DomainMetadataError {}
+impl ServiceErrorCode for DomainMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DomainMetadataError::MissingParameter(_) => "MissingParameter",
+            DomainMetadataError::NoSuchDomain(_) => "NoSuchDomain",
+        }
+    }
+}
 /// Errors returned by GetAttributes
 #[derive(Debug, PartialEq)]
 pub enum GetAttributesError {
@@ -1353,6 +1415,15 @@ impl fmt::Display for GetAttributesError {
     }
 }
 impl Error for GetAttributesError {}
+impl ServiceErrorCode for GetAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAttributesError::InvalidParameterValue(_) => "InvalidParameterValue",
+            GetAttributesError::MissingParameter(_) => "MissingParameter",
+            GetAttributesError::NoSuchDomain(_) => "NoSuchDomain",
+        }
+    }
+}
 /// Errors returned by ListDomains
 #[derive(Debug, PartialEq)]
 pub enum ListDomainsError {
@@ -1405,6 +1476,14 @@ impl fmt::Display for ListDomainsError {
     }
 }
 impl Error for ListDomainsError {}
+impl ServiceErrorCode for ListDomainsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDomainsError::InvalidNextToken(_) => "InvalidNextToken",
+            ListDomainsError::InvalidParameterValue(_) => "InvalidParameterValue",
+        }
+    }
+}
 /// Errors returned by PutAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutAttributesError {
@@ -1499,6 +1578,21 @@ impl fmt::Display for PutAttributesError {
     }
 }
 impl Error for PutAttributesError {}
+impl ServiceErrorCode for PutAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAttributesError::AttributeDoesNotExist(_) => "AttributeDoesNotExist",
+            PutAttributesError::InvalidParameterValue(_) => "InvalidParameterValue",
+            PutAttributesError::MissingParameter(_) => "MissingParameter",
+            PutAttributesError::NoSuchDomain(_) => "NoSuchDomain",
+            PutAttributesError::NumberDomainAttributesExceeded(_) => {
+                "NumberDomainAttributesExceeded"
+            }
+            PutAttributesError::NumberDomainBytesExceeded(_) => "NumberDomainBytesExceeded",
+            PutAttributesError::NumberItemAttributesExceeded(_) => "NumberItemAttributesExceeded",
+        }
+    }
+}
 /// Errors returned by Select
 #[derive(Debug, PartialEq)]
 pub enum SelectError {
@@ -1607,6 +1701,21 @@ impl fmt::Display for SelectError {
     }
 }
 impl Error for SelectError {}
+impl ServiceErrorCode for SelectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SelectError::InvalidNextToken(_) => "InvalidNextToken",
+            SelectError::InvalidNumberPredicates(_) => "InvalidNumberPredicates",
+            SelectError::InvalidNumberValueTests(_) => "InvalidNumberValueTests",
+            SelectError::InvalidParameterValue(_) => "InvalidParameterValue",
+            SelectError::InvalidQueryExpression(_) => "InvalidQueryExpression",
+            SelectError::MissingParameter(_) => "MissingParameter",
+            SelectError::NoSuchDomain(_) => "NoSuchDomain",
+            SelectError::RequestTimeout(_) => "RequestTimeout",
+            SelectError::TooManyRequestedAttributes(_) => "TooManyRequestedAttributes",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon SimpleDB API. Amazon SimpleDB clients implement this trait.
 #[async_trait]
 pub trait SimpleDb {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -80,6 +80,7 @@ pub struct CancelRotateSecretResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateSecretRequest {
     /// <p>(Optional) If you include <code>SecretString</code> or <code>SecretBinary</code>, then an initial version is created as part of the secret, and this parameter specifies a unique identifier for the new version. </p> <note> <p>If you use the AWS CLI or one of the AWS SDK to call this operation, then you can leave this parameter empty. The CLI or SDK generates a random UUID for you and includes it as the value for this parameter in the request. If you don't use the SDK and instead generate a raw HTTP request to the Secrets Manager service endpoint, then you must generate a <code>ClientRequestToken</code> yourself for the new version and include the value in the request.</p> </note> <p>This value helps ensure idempotency. Secrets Manager uses this value to prevent the accidental creation of duplicate versions if there are failures and retries during a rotation. We recommend that you generate a <a href="https://wikipedia.org/wiki/Universally_unique_identifier">UUID-type</a> value to ensure uniqueness of your versions within the specified secret. </p> <ul> <li> <p>If the <code>ClientRequestToken</code> value isn't already associated with a version of the secret then a new version of the secret is created. </p> </li> <li> <p>If a version with this value already exists and the version <code>SecretString</code> and <code>SecretBinary</code> values are the same as those in the request, then the request is ignored.</p> </li> <li> <p>If a version with this value already exists and that version's <code>SecretString</code> and <code>SecretBinary</code> values are different from those in the request then the request fails because you cannot modify an existing version. Instead, use <a>PutSecretValue</a> to create a new version.</p> </li> </ul> <p>This value becomes the <code>VersionId</code> of the new version.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -503,6 +504,7 @@ pub struct PutResourcePolicyResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct PutSecretValueRequest {
     /// <p>(Optional) Specifies a unique identifier for the new version of the secret. </p> <note> <p>If you use the AWS CLI or one of the AWS SDK to call this operation, then you can leave this parameter empty. The CLI or SDK generates a random UUID for you and includes that in the request. If you don't use the SDK and instead generate a raw HTTP request to the Secrets Manager service endpoint, then you must generate a <code>ClientRequestToken</code> yourself for new versions and include that value in the request. </p> </note> <p>This value helps ensure idempotency. Secrets Manager uses this value to prevent the accidental creation of duplicate versions if there are failures and retries during the Lambda rotation function's processing. We recommend that you generate a <a href="https://wikipedia.org/wiki/Universally_unique_identifier">UUID-type</a> value to ensure uniqueness within the specified secret. </p> <ul> <li> <p>If the <code>ClientRequestToken</code> value isn't already associated with a version of the secret then a new version of the secret is created. </p> </li> <li> <p>If a version with this value already exists and that version's <code>SecretString</code> or <code>SecretBinary</code> values are the same as those in the request then the request is ignored (the operation is idempotent). </p> </li> <li> <p>If a version with this value already exists and the version of the <code>SecretString</code> and <code>SecretBinary</code> values are different from those in the request then the request fails because you cannot modify an existing secret version. You can only create new versions to store new secret values.</p> </li> </ul> <p>This value becomes the <code>VersionId</code> of the new version.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -574,6 +576,7 @@ pub struct RestoreSecretResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RotateSecretRequest {
     /// <p>(Optional) Specifies a unique identifier for the new version of the secret that helps ensure idempotency. </p> <p>If you use the AWS CLI or one of the AWS SDK to call this operation, then you can leave this parameter empty. The CLI or SDK generates a random UUID for you and includes that in the request for this parameter. If you don't use the SDK and instead generate a raw HTTP request to the Secrets Manager service endpoint, then you must generate a <code>ClientRequestToken</code> yourself for new versions and include that value in the request.</p> <p>You only need to specify your own value if you implement your own retry logic and want to ensure that a given secret is not created twice. We recommend that you generate a <a href="https://wikipedia.org/wiki/Universally_unique_identifier">UUID-type</a> value to ensure uniqueness within the specified secret. </p> <p>Secrets Manager uses this value to prevent the accidental creation of duplicate versions if there are failures and retries during the function's processing. This value becomes the <code>VersionId</code> of the new version.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -743,6 +746,7 @@ pub struct UntagResourceRequest {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateSecretRequest {
     /// <p>(Optional) If you want to add a new version to the secret, this parameter specifies a unique identifier for the new version that helps ensure idempotency. </p> <p>If you use the AWS CLI or one of the AWS SDK to call this operation, then you can leave this parameter empty. The CLI or SDK generates a random UUID for you and includes that in the request. If you don't use the SDK and instead generate a raw HTTP request to the Secrets Manager service endpoint, then you must generate a <code>ClientRequestToken</code> yourself for new versions and include that value in the request.</p> <p>You typically only need to interact with this value if you implement your own retry logic and want to ensure that a given secret is not created twice. We recommend that you generate a <a href="https://wikipedia.org/wiki/Universally_unique_identifier">UUID-type</a> value to ensure uniqueness within the specified secret. </p> <p>Secrets Manager uses this value to prevent the accidental creation of duplicate versions if there are failures and retries during the Lambda rotation function's processing.</p> <ul> <li> <p>If the <code>ClientRequestToken</code> value isn't already associated with a version of the secret then a new version of the secret is created. </p> </li> <li> <p>If a version with this value already exists and that version's <code>SecretString</code> and <code>SecretBinary</code> values are the same as those in the request then the request is ignored (the operation is idempotent). </p> </li> <li> <p>If a version with this value already exists and that version's <code>SecretString</code> and <code>SecretBinary</code> values are different from the request then an error occurs because you cannot modify an existing secret value.</p> </li> </ul> <p>This value becomes the <code>VersionId</code> of the new version.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientRequestToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_request_token: Option<String>,
@@ -910,6 +914,16 @@ impl fmt::Display for CancelRotateSecretError {
     }
 }
 impl Error for CancelRotateSecretError {}
+impl ServiceErrorCode for CancelRotateSecretError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelRotateSecretError::InternalServiceError(_) => "InternalServiceError",
+            CancelRotateSecretError::InvalidParameter(_) => "InvalidParameterException",
+            CancelRotateSecretError::InvalidRequest(_) => "InvalidRequestException",
+            CancelRotateSecretError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateSecret
 #[derive(Debug, PartialEq)]
 pub enum CreateSecretError {
@@ -990,6 +1004,21 @@ impl fmt::Display for CreateSecretError {
     }
 }
 impl Error for CreateSecretError {}
+impl ServiceErrorCode for CreateSecretError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSecretError::EncryptionFailure(_) => "EncryptionFailure",
+            CreateSecretError::InternalServiceError(_) => "InternalServiceError",
+            CreateSecretError::InvalidParameter(_) => "InvalidParameterException",
+            CreateSecretError::InvalidRequest(_) => "InvalidRequestException",
+            CreateSecretError::LimitExceeded(_) => "LimitExceededException",
+            CreateSecretError::MalformedPolicyDocument(_) => "MalformedPolicyDocumentException",
+            CreateSecretError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            CreateSecretError::ResourceExists(_) => "ResourceExistsException",
+            CreateSecretError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteResourcePolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteResourcePolicyError {
@@ -1036,6 +1065,15 @@ impl fmt::Display for DeleteResourcePolicyError {
     }
 }
 impl Error for DeleteResourcePolicyError {}
+impl ServiceErrorCode for DeleteResourcePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResourcePolicyError::InternalServiceError(_) => "InternalServiceError",
+            DeleteResourcePolicyError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteResourcePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteSecret
 #[derive(Debug, PartialEq)]
 pub enum DeleteSecretError {
@@ -1084,6 +1122,16 @@ impl fmt::Display for DeleteSecretError {
     }
 }
 impl Error for DeleteSecretError {}
+impl ServiceErrorCode for DeleteSecretError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSecretError::InternalServiceError(_) => "InternalServiceError",
+            DeleteSecretError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteSecretError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteSecretError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeSecret
 #[derive(Debug, PartialEq)]
 pub enum DescribeSecretError {
@@ -1120,6 +1168,14 @@ impl fmt::Display for DescribeSecretError {
     }
 }
 impl Error for DescribeSecretError {}
+impl ServiceErrorCode for DescribeSecretError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSecretError::InternalServiceError(_) => "InternalServiceError",
+            DescribeSecretError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetRandomPassword
 #[derive(Debug, PartialEq)]
 pub enum GetRandomPasswordError {
@@ -1164,6 +1220,15 @@ impl fmt::Display for GetRandomPasswordError {
     }
 }
 impl Error for GetRandomPasswordError {}
+impl ServiceErrorCode for GetRandomPasswordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRandomPasswordError::InternalServiceError(_) => "InternalServiceError",
+            GetRandomPasswordError::InvalidParameter(_) => "InvalidParameterException",
+            GetRandomPasswordError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by GetResourcePolicy
 #[derive(Debug, PartialEq)]
 pub enum GetResourcePolicyError {
@@ -1208,6 +1273,15 @@ impl fmt::Display for GetResourcePolicyError {
     }
 }
 impl Error for GetResourcePolicyError {}
+impl ServiceErrorCode for GetResourcePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourcePolicyError::InternalServiceError(_) => "InternalServiceError",
+            GetResourcePolicyError::InvalidRequest(_) => "InvalidRequestException",
+            GetResourcePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetSecretValue
 #[derive(Debug, PartialEq)]
 pub enum GetSecretValueError {
@@ -1262,6 +1336,17 @@ impl fmt::Display for GetSecretValueError {
     }
 }
 impl Error for GetSecretValueError {}
+impl ServiceErrorCode for GetSecretValueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSecretValueError::DecryptionFailure(_) => "DecryptionFailure",
+            GetSecretValueError::InternalServiceError(_) => "InternalServiceError",
+            GetSecretValueError::InvalidParameter(_) => "InvalidParameterException",
+            GetSecretValueError::InvalidRequest(_) => "InvalidRequestException",
+            GetSecretValueError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListSecretVersionIds
 #[derive(Debug, PartialEq)]
 pub enum ListSecretVersionIdsError {
@@ -1310,6 +1395,15 @@ impl fmt::Display for ListSecretVersionIdsError {
     }
 }
 impl Error for ListSecretVersionIdsError {}
+impl ServiceErrorCode for ListSecretVersionIdsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSecretVersionIdsError::InternalServiceError(_) => "InternalServiceError",
+            ListSecretVersionIdsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListSecretVersionIdsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListSecrets
 #[derive(Debug, PartialEq)]
 pub enum ListSecretsError {
@@ -1352,6 +1446,15 @@ impl fmt::Display for ListSecretsError {
     }
 }
 impl Error for ListSecretsError {}
+impl ServiceErrorCode for ListSecretsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSecretsError::InternalServiceError(_) => "InternalServiceError",
+            ListSecretsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListSecretsError::InvalidParameter(_) => "InvalidParameterException",
+        }
+    }
+}
 /// Errors returned by PutResourcePolicy
 #[derive(Debug, PartialEq)]
 pub enum PutResourcePolicyError {
@@ -1416,6 +1519,20 @@ impl fmt::Display for PutResourcePolicyError {
     }
 }
 impl Error for PutResourcePolicyError {}
+impl ServiceErrorCode for PutResourcePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutResourcePolicyError::InternalServiceError(_) => "InternalServiceError",
+            PutResourcePolicyError::InvalidParameter(_) => "InvalidParameterException",
+            PutResourcePolicyError::InvalidRequest(_) => "InvalidRequestException",
+            PutResourcePolicyError::MalformedPolicyDocument(_) => {
+                "MalformedPolicyDocumentException"
+            }
+            PutResourcePolicyError::PublicPolicy(_) => "PublicPolicyException",
+            PutResourcePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by PutSecretValue
 #[derive(Debug, PartialEq)]
 pub enum PutSecretValueError {
@@ -1482,6 +1599,19 @@ impl fmt::Display for PutSecretValueError {
     }
 }
 impl Error for PutSecretValueError {}
+impl ServiceErrorCode for PutSecretValueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutSecretValueError::EncryptionFailure(_) => "EncryptionFailure",
+            PutSecretValueError::InternalServiceError(_) => "InternalServiceError",
+            PutSecretValueError::InvalidParameter(_) => "InvalidParameterException",
+            PutSecretValueError::InvalidRequest(_) => "InvalidRequestException",
+            PutSecretValueError::LimitExceeded(_) => "LimitExceededException",
+            PutSecretValueError::ResourceExists(_) => "ResourceExistsException",
+            PutSecretValueError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RestoreSecret
 #[derive(Debug, PartialEq)]
 pub enum RestoreSecretError {
@@ -1530,6 +1660,16 @@ impl fmt::Display for RestoreSecretError {
     }
 }
 impl Error for RestoreSecretError {}
+impl ServiceErrorCode for RestoreSecretError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreSecretError::InternalServiceError(_) => "InternalServiceError",
+            RestoreSecretError::InvalidParameter(_) => "InvalidParameterException",
+            RestoreSecretError::InvalidRequest(_) => "InvalidRequestException",
+            RestoreSecretError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RotateSecret
 #[derive(Debug, PartialEq)]
 pub enum RotateSecretError {
@@ -1578,6 +1718,16 @@ impl fmt::Display for RotateSecretError {
     }
 }
 impl Error for RotateSecretError {}
+impl ServiceErrorCode for RotateSecretError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RotateSecretError::InternalServiceError(_) => "InternalServiceError",
+            RotateSecretError::InvalidParameter(_) => "InvalidParameterException",
+            RotateSecretError::InvalidRequest(_) => "InvalidRequestException",
+            RotateSecretError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1626,6 +1776,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalServiceError(_) => "InternalServiceError",
+            TagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1674,6 +1834,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalServiceError(_) => "InternalServiceError",
+            UntagResourceError::InvalidParameter(_) => "InvalidParameterException",
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateSecret
 #[derive(Debug, PartialEq)]
 pub enum UpdateSecretError {
@@ -1754,6 +1924,21 @@ impl fmt::Display for UpdateSecretError {
     }
 }
 impl Error for UpdateSecretError {}
+impl ServiceErrorCode for UpdateSecretError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSecretError::EncryptionFailure(_) => "EncryptionFailure",
+            UpdateSecretError::InternalServiceError(_) => "InternalServiceError",
+            UpdateSecretError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateSecretError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateSecretError::LimitExceeded(_) => "LimitExceededException",
+            UpdateSecretError::MalformedPolicyDocument(_) => "MalformedPolicyDocumentException",
+            UpdateSecretError::PreconditionNotMet(_) => "PreconditionNotMetException",
+            UpdateSecretError::ResourceExists(_) => "ResourceExistsException",
+            UpdateSecretError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateSecretVersionStage
 #[derive(Debug, PartialEq)]
 pub enum UpdateSecretVersionStageError {
@@ -1820,6 +2005,17 @@ impl fmt::Display for UpdateSecretVersionStageError {
     }
 }
 impl Error for UpdateSecretVersionStageError {}
+impl ServiceErrorCode for UpdateSecretVersionStageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSecretVersionStageError::InternalServiceError(_) => "InternalServiceError",
+            UpdateSecretVersionStageError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateSecretVersionStageError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateSecretVersionStageError::LimitExceeded(_) => "LimitExceededException",
+            UpdateSecretVersionStageError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ValidateResourcePolicy
 #[derive(Debug, PartialEq)]
 pub enum ValidateResourcePolicyError {
@@ -1886,6 +2082,19 @@ impl fmt::Display for ValidateResourcePolicyError {
     }
 }
 impl Error for ValidateResourcePolicyError {}
+impl ServiceErrorCode for ValidateResourcePolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ValidateResourcePolicyError::InternalServiceError(_) => "InternalServiceError",
+            ValidateResourcePolicyError::InvalidParameter(_) => "InvalidParameterException",
+            ValidateResourcePolicyError::InvalidRequest(_) => "InvalidRequestException",
+            ValidateResourcePolicyError::MalformedPolicyDocument(_) => {
+                "MalformedPolicyDocumentException"
+            }
+            ValidateResourcePolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Secrets Manager API. AWS Secrets Manager clients implement this trait.
 #[async_trait]
 pub trait SecretsManager {
@@ -2066,6 +2275,10 @@ impl SecretsManager for SecretsManagerClient {
         &self,
         input: CreateSecretRequest,
     ) -> Result<CreateSecretResponse, RusotoError<CreateSecretError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "secretsmanager.CreateSecret");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -2248,6 +2461,10 @@ impl SecretsManager for SecretsManagerClient {
         &self,
         input: PutSecretValueRequest,
     ) -> Result<PutSecretValueResponse, RusotoError<PutSecretValueError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "secretsmanager.PutSecretValue");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -2284,6 +2501,10 @@ impl SecretsManager for SecretsManagerClient {
         &self,
         input: RotateSecretRequest,
     ) -> Result<RotateSecretResponse, RusotoError<RotateSecretError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "secretsmanager.RotateSecret");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -2336,6 +2557,10 @@ impl SecretsManager for SecretsManagerClient {
         &self,
         input: UpdateSecretRequest,
     ) -> Result<UpdateSecretResponse, RusotoError<UpdateSecretError>> {
+        let mut input = input;
+        if input.client_request_token.is_none() {
+            input.client_request_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "secretsmanager.UpdateSecret");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -6913,6 +6913,17 @@ impl fmt::Display for AcceptInvitationError {
     }
 }
 impl Error for AcceptInvitationError {}
+impl ServiceErrorCode for AcceptInvitationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptInvitationError::Internal(_) => "InternalException",
+            AcceptInvitationError::InvalidAccess(_) => "InvalidAccessException",
+            AcceptInvitationError::InvalidInput(_) => "InvalidInputException",
+            AcceptInvitationError::LimitExceeded(_) => "LimitExceededException",
+            AcceptInvitationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by BatchDisableStandards
 #[derive(Debug, PartialEq)]
 pub enum BatchDisableStandardsError {
@@ -6961,6 +6972,16 @@ impl fmt::Display for BatchDisableStandardsError {
     }
 }
 impl Error for BatchDisableStandardsError {}
+impl ServiceErrorCode for BatchDisableStandardsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDisableStandardsError::Internal(_) => "InternalException",
+            BatchDisableStandardsError::InvalidAccess(_) => "InvalidAccessException",
+            BatchDisableStandardsError::InvalidInput(_) => "InvalidInputException",
+            BatchDisableStandardsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by BatchEnableStandards
 #[derive(Debug, PartialEq)]
 pub enum BatchEnableStandardsError {
@@ -7009,6 +7030,16 @@ impl fmt::Display for BatchEnableStandardsError {
     }
 }
 impl Error for BatchEnableStandardsError {}
+impl ServiceErrorCode for BatchEnableStandardsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchEnableStandardsError::Internal(_) => "InternalException",
+            BatchEnableStandardsError::InvalidAccess(_) => "InvalidAccessException",
+            BatchEnableStandardsError::InvalidInput(_) => "InvalidInputException",
+            BatchEnableStandardsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by BatchImportFindings
 #[derive(Debug, PartialEq)]
 pub enum BatchImportFindingsError {
@@ -7057,6 +7088,16 @@ impl fmt::Display for BatchImportFindingsError {
     }
 }
 impl Error for BatchImportFindingsError {}
+impl ServiceErrorCode for BatchImportFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchImportFindingsError::Internal(_) => "InternalException",
+            BatchImportFindingsError::InvalidAccess(_) => "InvalidAccessException",
+            BatchImportFindingsError::InvalidInput(_) => "InvalidInputException",
+            BatchImportFindingsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by BatchUpdateFindings
 #[derive(Debug, PartialEq)]
 pub enum BatchUpdateFindingsError {
@@ -7105,6 +7146,16 @@ impl fmt::Display for BatchUpdateFindingsError {
     }
 }
 impl Error for BatchUpdateFindingsError {}
+impl ServiceErrorCode for BatchUpdateFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchUpdateFindingsError::Internal(_) => "InternalException",
+            BatchUpdateFindingsError::InvalidAccess(_) => "InvalidAccessException",
+            BatchUpdateFindingsError::InvalidInput(_) => "InvalidInputException",
+            BatchUpdateFindingsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateActionTarget
 #[derive(Debug, PartialEq)]
 pub enum CreateActionTargetError {
@@ -7159,6 +7210,17 @@ impl fmt::Display for CreateActionTargetError {
     }
 }
 impl Error for CreateActionTargetError {}
+impl ServiceErrorCode for CreateActionTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateActionTargetError::Internal(_) => "InternalException",
+            CreateActionTargetError::InvalidAccess(_) => "InvalidAccessException",
+            CreateActionTargetError::InvalidInput(_) => "InvalidInputException",
+            CreateActionTargetError::LimitExceeded(_) => "LimitExceededException",
+            CreateActionTargetError::ResourceConflict(_) => "ResourceConflictException",
+        }
+    }
+}
 /// Errors returned by CreateInsight
 #[derive(Debug, PartialEq)]
 pub enum CreateInsightError {
@@ -7213,6 +7275,17 @@ impl fmt::Display for CreateInsightError {
     }
 }
 impl Error for CreateInsightError {}
+impl ServiceErrorCode for CreateInsightError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateInsightError::Internal(_) => "InternalException",
+            CreateInsightError::InvalidAccess(_) => "InvalidAccessException",
+            CreateInsightError::InvalidInput(_) => "InvalidInputException",
+            CreateInsightError::LimitExceeded(_) => "LimitExceededException",
+            CreateInsightError::ResourceConflict(_) => "ResourceConflictException",
+        }
+    }
+}
 /// Errors returned by CreateMembers
 #[derive(Debug, PartialEq)]
 pub enum CreateMembersError {
@@ -7267,6 +7340,17 @@ impl fmt::Display for CreateMembersError {
     }
 }
 impl Error for CreateMembersError {}
+impl ServiceErrorCode for CreateMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMembersError::Internal(_) => "InternalException",
+            CreateMembersError::InvalidAccess(_) => "InvalidAccessException",
+            CreateMembersError::InvalidInput(_) => "InvalidInputException",
+            CreateMembersError::LimitExceeded(_) => "LimitExceededException",
+            CreateMembersError::ResourceConflict(_) => "ResourceConflictException",
+        }
+    }
+}
 /// Errors returned by DeclineInvitations
 #[derive(Debug, PartialEq)]
 pub enum DeclineInvitationsError {
@@ -7315,6 +7399,16 @@ impl fmt::Display for DeclineInvitationsError {
     }
 }
 impl Error for DeclineInvitationsError {}
+impl ServiceErrorCode for DeclineInvitationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeclineInvitationsError::Internal(_) => "InternalException",
+            DeclineInvitationsError::InvalidAccess(_) => "InvalidAccessException",
+            DeclineInvitationsError::InvalidInput(_) => "InvalidInputException",
+            DeclineInvitationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteActionTarget
 #[derive(Debug, PartialEq)]
 pub enum DeleteActionTargetError {
@@ -7363,6 +7457,16 @@ impl fmt::Display for DeleteActionTargetError {
     }
 }
 impl Error for DeleteActionTargetError {}
+impl ServiceErrorCode for DeleteActionTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteActionTargetError::Internal(_) => "InternalException",
+            DeleteActionTargetError::InvalidAccess(_) => "InvalidAccessException",
+            DeleteActionTargetError::InvalidInput(_) => "InvalidInputException",
+            DeleteActionTargetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteInsight
 #[derive(Debug, PartialEq)]
 pub enum DeleteInsightError {
@@ -7417,6 +7521,17 @@ impl fmt::Display for DeleteInsightError {
     }
 }
 impl Error for DeleteInsightError {}
+impl ServiceErrorCode for DeleteInsightError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInsightError::Internal(_) => "InternalException",
+            DeleteInsightError::InvalidAccess(_) => "InvalidAccessException",
+            DeleteInsightError::InvalidInput(_) => "InvalidInputException",
+            DeleteInsightError::LimitExceeded(_) => "LimitExceededException",
+            DeleteInsightError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteInvitations
 #[derive(Debug, PartialEq)]
 pub enum DeleteInvitationsError {
@@ -7471,6 +7586,17 @@ impl fmt::Display for DeleteInvitationsError {
     }
 }
 impl Error for DeleteInvitationsError {}
+impl ServiceErrorCode for DeleteInvitationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInvitationsError::Internal(_) => "InternalException",
+            DeleteInvitationsError::InvalidAccess(_) => "InvalidAccessException",
+            DeleteInvitationsError::InvalidInput(_) => "InvalidInputException",
+            DeleteInvitationsError::LimitExceeded(_) => "LimitExceededException",
+            DeleteInvitationsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteMembers
 #[derive(Debug, PartialEq)]
 pub enum DeleteMembersError {
@@ -7525,6 +7651,17 @@ impl fmt::Display for DeleteMembersError {
     }
 }
 impl Error for DeleteMembersError {}
+impl ServiceErrorCode for DeleteMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMembersError::Internal(_) => "InternalException",
+            DeleteMembersError::InvalidAccess(_) => "InvalidAccessException",
+            DeleteMembersError::InvalidInput(_) => "InvalidInputException",
+            DeleteMembersError::LimitExceeded(_) => "LimitExceededException",
+            DeleteMembersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeActionTargets
 #[derive(Debug, PartialEq)]
 pub enum DescribeActionTargetsError {
@@ -7575,6 +7712,16 @@ impl fmt::Display for DescribeActionTargetsError {
     }
 }
 impl Error for DescribeActionTargetsError {}
+impl ServiceErrorCode for DescribeActionTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeActionTargetsError::Internal(_) => "InternalException",
+            DescribeActionTargetsError::InvalidAccess(_) => "InvalidAccessException",
+            DescribeActionTargetsError::InvalidInput(_) => "InvalidInputException",
+            DescribeActionTargetsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeHub
 #[derive(Debug, PartialEq)]
 pub enum DescribeHubError {
@@ -7629,6 +7776,17 @@ impl fmt::Display for DescribeHubError {
     }
 }
 impl Error for DescribeHubError {}
+impl ServiceErrorCode for DescribeHubError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeHubError::Internal(_) => "InternalException",
+            DescribeHubError::InvalidAccess(_) => "InvalidAccessException",
+            DescribeHubError::InvalidInput(_) => "InvalidInputException",
+            DescribeHubError::LimitExceeded(_) => "LimitExceededException",
+            DescribeHubError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeOrganizationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribeOrganizationConfigurationError {
@@ -7693,6 +7851,16 @@ impl fmt::Display for DescribeOrganizationConfigurationError {
     }
 }
 impl Error for DescribeOrganizationConfigurationError {}
+impl ServiceErrorCode for DescribeOrganizationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeOrganizationConfigurationError::Internal(_) => "InternalException",
+            DescribeOrganizationConfigurationError::InvalidAccess(_) => "InvalidAccessException",
+            DescribeOrganizationConfigurationError::InvalidInput(_) => "InvalidInputException",
+            DescribeOrganizationConfigurationError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DescribeProducts
 #[derive(Debug, PartialEq)]
 pub enum DescribeProductsError {
@@ -7741,6 +7909,16 @@ impl fmt::Display for DescribeProductsError {
     }
 }
 impl Error for DescribeProductsError {}
+impl ServiceErrorCode for DescribeProductsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProductsError::Internal(_) => "InternalException",
+            DescribeProductsError::InvalidAccess(_) => "InvalidAccessException",
+            DescribeProductsError::InvalidInput(_) => "InvalidInputException",
+            DescribeProductsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DescribeStandards
 #[derive(Debug, PartialEq)]
 pub enum DescribeStandardsError {
@@ -7783,6 +7961,15 @@ impl fmt::Display for DescribeStandardsError {
     }
 }
 impl Error for DescribeStandardsError {}
+impl ServiceErrorCode for DescribeStandardsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStandardsError::Internal(_) => "InternalException",
+            DescribeStandardsError::InvalidAccess(_) => "InvalidAccessException",
+            DescribeStandardsError::InvalidInput(_) => "InvalidInputException",
+        }
+    }
+}
 /// Errors returned by DescribeStandardsControls
 #[derive(Debug, PartialEq)]
 pub enum DescribeStandardsControlsError {
@@ -7837,6 +8024,16 @@ impl fmt::Display for DescribeStandardsControlsError {
     }
 }
 impl Error for DescribeStandardsControlsError {}
+impl ServiceErrorCode for DescribeStandardsControlsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStandardsControlsError::Internal(_) => "InternalException",
+            DescribeStandardsControlsError::InvalidAccess(_) => "InvalidAccessException",
+            DescribeStandardsControlsError::InvalidInput(_) => "InvalidInputException",
+            DescribeStandardsControlsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisableImportFindingsForProduct
 #[derive(Debug, PartialEq)]
 pub enum DisableImportFindingsForProductError {
@@ -7909,6 +8106,19 @@ impl fmt::Display for DisableImportFindingsForProductError {
     }
 }
 impl Error for DisableImportFindingsForProductError {}
+impl ServiceErrorCode for DisableImportFindingsForProductError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableImportFindingsForProductError::Internal(_) => "InternalException",
+            DisableImportFindingsForProductError::InvalidAccess(_) => "InvalidAccessException",
+            DisableImportFindingsForProductError::InvalidInput(_) => "InvalidInputException",
+            DisableImportFindingsForProductError::LimitExceeded(_) => "LimitExceededException",
+            DisableImportFindingsForProductError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DisableOrganizationAdminAccount
 #[derive(Debug, PartialEq)]
 pub enum DisableOrganizationAdminAccountError {
@@ -7971,6 +8181,16 @@ impl fmt::Display for DisableOrganizationAdminAccountError {
     }
 }
 impl Error for DisableOrganizationAdminAccountError {}
+impl ServiceErrorCode for DisableOrganizationAdminAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableOrganizationAdminAccountError::Internal(_) => "InternalException",
+            DisableOrganizationAdminAccountError::InvalidAccess(_) => "InvalidAccessException",
+            DisableOrganizationAdminAccountError::InvalidInput(_) => "InvalidInputException",
+            DisableOrganizationAdminAccountError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DisableSecurityHub
 #[derive(Debug, PartialEq)]
 pub enum DisableSecurityHubError {
@@ -8019,6 +8239,16 @@ impl fmt::Display for DisableSecurityHubError {
     }
 }
 impl Error for DisableSecurityHubError {}
+impl ServiceErrorCode for DisableSecurityHubError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableSecurityHubError::Internal(_) => "InternalException",
+            DisableSecurityHubError::InvalidAccess(_) => "InvalidAccessException",
+            DisableSecurityHubError::LimitExceeded(_) => "LimitExceededException",
+            DisableSecurityHubError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateFromMasterAccount
 #[derive(Debug, PartialEq)]
 pub enum DisassociateFromMasterAccountError {
@@ -8087,6 +8317,17 @@ impl fmt::Display for DisassociateFromMasterAccountError {
     }
 }
 impl Error for DisassociateFromMasterAccountError {}
+impl ServiceErrorCode for DisassociateFromMasterAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateFromMasterAccountError::Internal(_) => "InternalException",
+            DisassociateFromMasterAccountError::InvalidAccess(_) => "InvalidAccessException",
+            DisassociateFromMasterAccountError::InvalidInput(_) => "InvalidInputException",
+            DisassociateFromMasterAccountError::LimitExceeded(_) => "LimitExceededException",
+            DisassociateFromMasterAccountError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateMembers
 #[derive(Debug, PartialEq)]
 pub enum DisassociateMembersError {
@@ -8143,6 +8384,17 @@ impl fmt::Display for DisassociateMembersError {
     }
 }
 impl Error for DisassociateMembersError {}
+impl ServiceErrorCode for DisassociateMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateMembersError::Internal(_) => "InternalException",
+            DisassociateMembersError::InvalidAccess(_) => "InvalidAccessException",
+            DisassociateMembersError::InvalidInput(_) => "InvalidInputException",
+            DisassociateMembersError::LimitExceeded(_) => "LimitExceededException",
+            DisassociateMembersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by EnableImportFindingsForProduct
 #[derive(Debug, PartialEq)]
 pub enum EnableImportFindingsForProductError {
@@ -8211,6 +8463,17 @@ impl fmt::Display for EnableImportFindingsForProductError {
     }
 }
 impl Error for EnableImportFindingsForProductError {}
+impl ServiceErrorCode for EnableImportFindingsForProductError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableImportFindingsForProductError::Internal(_) => "InternalException",
+            EnableImportFindingsForProductError::InvalidAccess(_) => "InvalidAccessException",
+            EnableImportFindingsForProductError::InvalidInput(_) => "InvalidInputException",
+            EnableImportFindingsForProductError::LimitExceeded(_) => "LimitExceededException",
+            EnableImportFindingsForProductError::ResourceConflict(_) => "ResourceConflictException",
+        }
+    }
+}
 /// Errors returned by EnableOrganizationAdminAccount
 #[derive(Debug, PartialEq)]
 pub enum EnableOrganizationAdminAccountError {
@@ -8269,6 +8532,16 @@ impl fmt::Display for EnableOrganizationAdminAccountError {
     }
 }
 impl Error for EnableOrganizationAdminAccountError {}
+impl ServiceErrorCode for EnableOrganizationAdminAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableOrganizationAdminAccountError::Internal(_) => "InternalException",
+            EnableOrganizationAdminAccountError::InvalidAccess(_) => "InvalidAccessException",
+            EnableOrganizationAdminAccountError::InvalidInput(_) => "InvalidInputException",
+            EnableOrganizationAdminAccountError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by EnableSecurityHub
 #[derive(Debug, PartialEq)]
 pub enum EnableSecurityHubError {
@@ -8323,6 +8596,17 @@ impl fmt::Display for EnableSecurityHubError {
     }
 }
 impl Error for EnableSecurityHubError {}
+impl ServiceErrorCode for EnableSecurityHubError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableSecurityHubError::AccessDenied(_) => "AccessDeniedException",
+            EnableSecurityHubError::Internal(_) => "InternalException",
+            EnableSecurityHubError::InvalidAccess(_) => "InvalidAccessException",
+            EnableSecurityHubError::LimitExceeded(_) => "LimitExceededException",
+            EnableSecurityHubError::ResourceConflict(_) => "ResourceConflictException",
+        }
+    }
+}
 /// Errors returned by GetEnabledStandards
 #[derive(Debug, PartialEq)]
 pub enum GetEnabledStandardsError {
@@ -8371,6 +8655,16 @@ impl fmt::Display for GetEnabledStandardsError {
     }
 }
 impl Error for GetEnabledStandardsError {}
+impl ServiceErrorCode for GetEnabledStandardsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEnabledStandardsError::Internal(_) => "InternalException",
+            GetEnabledStandardsError::InvalidAccess(_) => "InvalidAccessException",
+            GetEnabledStandardsError::InvalidInput(_) => "InvalidInputException",
+            GetEnabledStandardsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetFindings
 #[derive(Debug, PartialEq)]
 pub enum GetFindingsError {
@@ -8419,6 +8713,16 @@ impl fmt::Display for GetFindingsError {
     }
 }
 impl Error for GetFindingsError {}
+impl ServiceErrorCode for GetFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFindingsError::Internal(_) => "InternalException",
+            GetFindingsError::InvalidAccess(_) => "InvalidAccessException",
+            GetFindingsError::InvalidInput(_) => "InvalidInputException",
+            GetFindingsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetInsightResults
 #[derive(Debug, PartialEq)]
 pub enum GetInsightResultsError {
@@ -8473,6 +8777,17 @@ impl fmt::Display for GetInsightResultsError {
     }
 }
 impl Error for GetInsightResultsError {}
+impl ServiceErrorCode for GetInsightResultsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInsightResultsError::Internal(_) => "InternalException",
+            GetInsightResultsError::InvalidAccess(_) => "InvalidAccessException",
+            GetInsightResultsError::InvalidInput(_) => "InvalidInputException",
+            GetInsightResultsError::LimitExceeded(_) => "LimitExceededException",
+            GetInsightResultsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetInsights
 #[derive(Debug, PartialEq)]
 pub enum GetInsightsError {
@@ -8527,6 +8842,17 @@ impl fmt::Display for GetInsightsError {
     }
 }
 impl Error for GetInsightsError {}
+impl ServiceErrorCode for GetInsightsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInsightsError::Internal(_) => "InternalException",
+            GetInsightsError::InvalidAccess(_) => "InvalidAccessException",
+            GetInsightsError::InvalidInput(_) => "InvalidInputException",
+            GetInsightsError::LimitExceeded(_) => "LimitExceededException",
+            GetInsightsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetInvitationsCount
 #[derive(Debug, PartialEq)]
 pub enum GetInvitationsCountError {
@@ -8575,6 +8901,16 @@ impl fmt::Display for GetInvitationsCountError {
     }
 }
 impl Error for GetInvitationsCountError {}
+impl ServiceErrorCode for GetInvitationsCountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInvitationsCountError::Internal(_) => "InternalException",
+            GetInvitationsCountError::InvalidAccess(_) => "InvalidAccessException",
+            GetInvitationsCountError::InvalidInput(_) => "InvalidInputException",
+            GetInvitationsCountError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by GetMasterAccount
 #[derive(Debug, PartialEq)]
 pub enum GetMasterAccountError {
@@ -8629,6 +8965,17 @@ impl fmt::Display for GetMasterAccountError {
     }
 }
 impl Error for GetMasterAccountError {}
+impl ServiceErrorCode for GetMasterAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMasterAccountError::Internal(_) => "InternalException",
+            GetMasterAccountError::InvalidAccess(_) => "InvalidAccessException",
+            GetMasterAccountError::InvalidInput(_) => "InvalidInputException",
+            GetMasterAccountError::LimitExceeded(_) => "LimitExceededException",
+            GetMasterAccountError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetMembers
 #[derive(Debug, PartialEq)]
 pub enum GetMembersError {
@@ -8683,6 +9030,17 @@ impl fmt::Display for GetMembersError {
     }
 }
 impl Error for GetMembersError {}
+impl ServiceErrorCode for GetMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMembersError::Internal(_) => "InternalException",
+            GetMembersError::InvalidAccess(_) => "InvalidAccessException",
+            GetMembersError::InvalidInput(_) => "InvalidInputException",
+            GetMembersError::LimitExceeded(_) => "LimitExceededException",
+            GetMembersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by InviteMembers
 #[derive(Debug, PartialEq)]
 pub enum InviteMembersError {
@@ -8737,6 +9095,17 @@ impl fmt::Display for InviteMembersError {
     }
 }
 impl Error for InviteMembersError {}
+impl ServiceErrorCode for InviteMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InviteMembersError::Internal(_) => "InternalException",
+            InviteMembersError::InvalidAccess(_) => "InvalidAccessException",
+            InviteMembersError::InvalidInput(_) => "InvalidInputException",
+            InviteMembersError::LimitExceeded(_) => "LimitExceededException",
+            InviteMembersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListEnabledProductsForImport
 #[derive(Debug, PartialEq)]
 pub enum ListEnabledProductsForImportError {
@@ -8787,6 +9156,15 @@ impl fmt::Display for ListEnabledProductsForImportError {
     }
 }
 impl Error for ListEnabledProductsForImportError {}
+impl ServiceErrorCode for ListEnabledProductsForImportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEnabledProductsForImportError::Internal(_) => "InternalException",
+            ListEnabledProductsForImportError::InvalidAccess(_) => "InvalidAccessException",
+            ListEnabledProductsForImportError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by ListInvitations
 #[derive(Debug, PartialEq)]
 pub enum ListInvitationsError {
@@ -8835,6 +9213,16 @@ impl fmt::Display for ListInvitationsError {
     }
 }
 impl Error for ListInvitationsError {}
+impl ServiceErrorCode for ListInvitationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInvitationsError::Internal(_) => "InternalException",
+            ListInvitationsError::InvalidAccess(_) => "InvalidAccessException",
+            ListInvitationsError::InvalidInput(_) => "InvalidInputException",
+            ListInvitationsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by ListMembers
 #[derive(Debug, PartialEq)]
 pub enum ListMembersError {
@@ -8883,6 +9271,16 @@ impl fmt::Display for ListMembersError {
     }
 }
 impl Error for ListMembersError {}
+impl ServiceErrorCode for ListMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMembersError::Internal(_) => "InternalException",
+            ListMembersError::InvalidAccess(_) => "InvalidAccessException",
+            ListMembersError::InvalidInput(_) => "InvalidInputException",
+            ListMembersError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by ListOrganizationAdminAccounts
 #[derive(Debug, PartialEq)]
 pub enum ListOrganizationAdminAccountsError {
@@ -8941,6 +9339,16 @@ impl fmt::Display for ListOrganizationAdminAccountsError {
     }
 }
 impl Error for ListOrganizationAdminAccountsError {}
+impl ServiceErrorCode for ListOrganizationAdminAccountsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOrganizationAdminAccountsError::Internal(_) => "InternalException",
+            ListOrganizationAdminAccountsError::InvalidAccess(_) => "InvalidAccessException",
+            ListOrganizationAdminAccountsError::InvalidInput(_) => "InvalidInputException",
+            ListOrganizationAdminAccountsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -8985,6 +9393,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::Internal(_) => "InternalException",
+            ListTagsForResourceError::InvalidInput(_) => "InvalidInputException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -9027,6 +9444,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::Internal(_) => "InternalException",
+            TagResourceError::InvalidInput(_) => "InvalidInputException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -9069,6 +9495,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::Internal(_) => "InternalException",
+            UntagResourceError::InvalidInput(_) => "InvalidInputException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateActionTarget
 #[derive(Debug, PartialEq)]
 pub enum UpdateActionTargetError {
@@ -9117,6 +9552,16 @@ impl fmt::Display for UpdateActionTargetError {
     }
 }
 impl Error for UpdateActionTargetError {}
+impl ServiceErrorCode for UpdateActionTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateActionTargetError::Internal(_) => "InternalException",
+            UpdateActionTargetError::InvalidAccess(_) => "InvalidAccessException",
+            UpdateActionTargetError::InvalidInput(_) => "InvalidInputException",
+            UpdateActionTargetError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateFindings
 #[derive(Debug, PartialEq)]
 pub enum UpdateFindingsError {
@@ -9171,6 +9616,17 @@ impl fmt::Display for UpdateFindingsError {
     }
 }
 impl Error for UpdateFindingsError {}
+impl ServiceErrorCode for UpdateFindingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFindingsError::Internal(_) => "InternalException",
+            UpdateFindingsError::InvalidAccess(_) => "InvalidAccessException",
+            UpdateFindingsError::InvalidInput(_) => "InvalidInputException",
+            UpdateFindingsError::LimitExceeded(_) => "LimitExceededException",
+            UpdateFindingsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateInsight
 #[derive(Debug, PartialEq)]
 pub enum UpdateInsightError {
@@ -9225,6 +9681,17 @@ impl fmt::Display for UpdateInsightError {
     }
 }
 impl Error for UpdateInsightError {}
+impl ServiceErrorCode for UpdateInsightError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateInsightError::Internal(_) => "InternalException",
+            UpdateInsightError::InvalidAccess(_) => "InvalidAccessException",
+            UpdateInsightError::InvalidInput(_) => "InvalidInputException",
+            UpdateInsightError::LimitExceeded(_) => "LimitExceededException",
+            UpdateInsightError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateOrganizationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateOrganizationConfigurationError {
@@ -9287,6 +9754,16 @@ impl fmt::Display for UpdateOrganizationConfigurationError {
     }
 }
 impl Error for UpdateOrganizationConfigurationError {}
+impl ServiceErrorCode for UpdateOrganizationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateOrganizationConfigurationError::Internal(_) => "InternalException",
+            UpdateOrganizationConfigurationError::InvalidAccess(_) => "InvalidAccessException",
+            UpdateOrganizationConfigurationError::InvalidInput(_) => "InvalidInputException",
+            UpdateOrganizationConfigurationError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by UpdateSecurityHubConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateSecurityHubConfigurationError {
@@ -9355,6 +9832,17 @@ impl fmt::Display for UpdateSecurityHubConfigurationError {
     }
 }
 impl Error for UpdateSecurityHubConfigurationError {}
+impl ServiceErrorCode for UpdateSecurityHubConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSecurityHubConfigurationError::Internal(_) => "InternalException",
+            UpdateSecurityHubConfigurationError::InvalidAccess(_) => "InvalidAccessException",
+            UpdateSecurityHubConfigurationError::InvalidInput(_) => "InvalidInputException",
+            UpdateSecurityHubConfigurationError::LimitExceeded(_) => "LimitExceededException",
+            UpdateSecurityHubConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateStandardsControl
 #[derive(Debug, PartialEq)]
 pub enum UpdateStandardsControlError {
@@ -9407,6 +9895,16 @@ impl fmt::Display for UpdateStandardsControlError {
     }
 }
 impl Error for UpdateStandardsControlError {}
+impl ServiceErrorCode for UpdateStandardsControlError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateStandardsControlError::Internal(_) => "InternalException",
+            UpdateStandardsControlError::InvalidAccess(_) => "InvalidAccessException",
+            UpdateStandardsControlError::InvalidInput(_) => "InvalidInputException",
+            UpdateStandardsControlError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS SecurityHub API. AWS SecurityHub clients implement this trait.
 #[async_trait]
 pub trait SecurityHub {
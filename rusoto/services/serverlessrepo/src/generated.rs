@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1093,6 +1093,17 @@ impl fmt::Display for CreateApplicationError {
     }
 }
 impl Error for CreateApplicationError {}
+impl ServiceErrorCode for CreateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApplicationError::BadRequest(_) => "BadRequestException",
+            CreateApplicationError::Conflict(_) => "ConflictException",
+            CreateApplicationError::Forbidden(_) => "ForbiddenException",
+            CreateApplicationError::InternalServerError(_) => "InternalServerErrorException",
+            CreateApplicationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateApplicationVersion
 #[derive(Debug, PartialEq)]
 pub enum CreateApplicationVersionError {
@@ -1151,6 +1162,17 @@ impl fmt::Display for CreateApplicationVersionError {
     }
 }
 impl Error for CreateApplicationVersionError {}
+impl ServiceErrorCode for CreateApplicationVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateApplicationVersionError::BadRequest(_) => "BadRequestException",
+            CreateApplicationVersionError::Conflict(_) => "ConflictException",
+            CreateApplicationVersionError::Forbidden(_) => "ForbiddenException",
+            CreateApplicationVersionError::InternalServerError(_) => "InternalServerErrorException",
+            CreateApplicationVersionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateCloudFormationChangeSet
 #[derive(Debug, PartialEq)]
 pub enum CreateCloudFormationChangeSetError {
@@ -1213,6 +1235,18 @@ impl fmt::Display for CreateCloudFormationChangeSetError {
     }
 }
 impl Error for CreateCloudFormationChangeSetError {}
+impl ServiceErrorCode for CreateCloudFormationChangeSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCloudFormationChangeSetError::BadRequest(_) => "BadRequestException",
+            CreateCloudFormationChangeSetError::Forbidden(_) => "ForbiddenException",
+            CreateCloudFormationChangeSetError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            CreateCloudFormationChangeSetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateCloudFormationTemplate
 #[derive(Debug, PartialEq)]
 pub enum CreateCloudFormationTemplateError {
@@ -1281,6 +1315,19 @@ impl fmt::Display for CreateCloudFormationTemplateError {
     }
 }
 impl Error for CreateCloudFormationTemplateError {}
+impl ServiceErrorCode for CreateCloudFormationTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCloudFormationTemplateError::BadRequest(_) => "BadRequestException",
+            CreateCloudFormationTemplateError::Forbidden(_) => "ForbiddenException",
+            CreateCloudFormationTemplateError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            CreateCloudFormationTemplateError::NotFound(_) => "NotFoundException",
+            CreateCloudFormationTemplateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteApplication
 #[derive(Debug, PartialEq)]
 pub enum DeleteApplicationError {
@@ -1343,6 +1390,18 @@ impl fmt::Display for DeleteApplicationError {
     }
 }
 impl Error for DeleteApplicationError {}
+impl ServiceErrorCode for DeleteApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteApplicationError::BadRequest(_) => "BadRequestException",
+            DeleteApplicationError::Conflict(_) => "ConflictException",
+            DeleteApplicationError::Forbidden(_) => "ForbiddenException",
+            DeleteApplicationError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteApplicationError::NotFound(_) => "NotFoundException",
+            DeleteApplicationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetApplication
 #[derive(Debug, PartialEq)]
 pub enum GetApplicationError {
@@ -1397,6 +1456,17 @@ impl fmt::Display for GetApplicationError {
     }
 }
 impl Error for GetApplicationError {}
+impl ServiceErrorCode for GetApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetApplicationError::BadRequest(_) => "BadRequestException",
+            GetApplicationError::Forbidden(_) => "ForbiddenException",
+            GetApplicationError::InternalServerError(_) => "InternalServerErrorException",
+            GetApplicationError::NotFound(_) => "NotFoundException",
+            GetApplicationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetApplicationPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetApplicationPolicyError {
@@ -1455,6 +1525,17 @@ impl fmt::Display for GetApplicationPolicyError {
     }
 }
 impl Error for GetApplicationPolicyError {}
+impl ServiceErrorCode for GetApplicationPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetApplicationPolicyError::BadRequest(_) => "BadRequestException",
+            GetApplicationPolicyError::Forbidden(_) => "ForbiddenException",
+            GetApplicationPolicyError::InternalServerError(_) => "InternalServerErrorException",
+            GetApplicationPolicyError::NotFound(_) => "NotFoundException",
+            GetApplicationPolicyError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetCloudFormationTemplate
 #[derive(Debug, PartialEq)]
 pub enum GetCloudFormationTemplateError {
@@ -1517,6 +1598,19 @@ impl fmt::Display for GetCloudFormationTemplateError {
     }
 }
 impl Error for GetCloudFormationTemplateError {}
+impl ServiceErrorCode for GetCloudFormationTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCloudFormationTemplateError::BadRequest(_) => "BadRequestException",
+            GetCloudFormationTemplateError::Forbidden(_) => "ForbiddenException",
+            GetCloudFormationTemplateError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            GetCloudFormationTemplateError::NotFound(_) => "NotFoundException",
+            GetCloudFormationTemplateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListApplicationDependencies
 #[derive(Debug, PartialEq)]
 pub enum ListApplicationDependenciesError {
@@ -1585,6 +1679,19 @@ impl fmt::Display for ListApplicationDependenciesError {
     }
 }
 impl Error for ListApplicationDependenciesError {}
+impl ServiceErrorCode for ListApplicationDependenciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListApplicationDependenciesError::BadRequest(_) => "BadRequestException",
+            ListApplicationDependenciesError::Forbidden(_) => "ForbiddenException",
+            ListApplicationDependenciesError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            ListApplicationDependenciesError::NotFound(_) => "NotFoundException",
+            ListApplicationDependenciesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListApplicationVersions
 #[derive(Debug, PartialEq)]
 pub enum ListApplicationVersionsError {
@@ -1643,6 +1750,17 @@ impl fmt::Display for ListApplicationVersionsError {
     }
 }
 impl Error for ListApplicationVersionsError {}
+impl ServiceErrorCode for ListApplicationVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListApplicationVersionsError::BadRequest(_) => "BadRequestException",
+            ListApplicationVersionsError::Forbidden(_) => "ForbiddenException",
+            ListApplicationVersionsError::InternalServerError(_) => "InternalServerErrorException",
+            ListApplicationVersionsError::NotFound(_) => "NotFoundException",
+            ListApplicationVersionsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListApplications
 #[derive(Debug, PartialEq)]
 pub enum ListApplicationsError {
@@ -1693,6 +1811,16 @@ impl fmt::Display for ListApplicationsError {
     }
 }
 impl Error for ListApplicationsError {}
+impl ServiceErrorCode for ListApplicationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListApplicationsError::BadRequest(_) => "BadRequestException",
+            ListApplicationsError::Forbidden(_) => "ForbiddenException",
+            ListApplicationsError::InternalServerError(_) => "InternalServerErrorException",
+            ListApplicationsError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by PutApplicationPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutApplicationPolicyError {
@@ -1751,6 +1879,17 @@ impl fmt::Display for PutApplicationPolicyError {
     }
 }
 impl Error for PutApplicationPolicyError {}
+impl ServiceErrorCode for PutApplicationPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutApplicationPolicyError::BadRequest(_) => "BadRequestException",
+            PutApplicationPolicyError::Forbidden(_) => "ForbiddenException",
+            PutApplicationPolicyError::InternalServerError(_) => "InternalServerErrorException",
+            PutApplicationPolicyError::NotFound(_) => "NotFoundException",
+            PutApplicationPolicyError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UnshareApplication
 #[derive(Debug, PartialEq)]
 pub enum UnshareApplicationError {
@@ -1807,6 +1946,17 @@ impl fmt::Display for UnshareApplicationError {
     }
 }
 impl Error for UnshareApplicationError {}
+impl ServiceErrorCode for UnshareApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UnshareApplicationError::BadRequest(_) => "BadRequestException",
+            UnshareApplicationError::Forbidden(_) => "ForbiddenException",
+            UnshareApplicationError::InternalServerError(_) => "InternalServerErrorException",
+            UnshareApplicationError::NotFound(_) => "NotFoundException",
+            UnshareApplicationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
 pub enum UpdateApplicationError {
@@ -1869,6 +2019,18 @@ impl fmt::Display for UpdateApplicationError {
     }
 }
 impl Error for UpdateApplicationError {}
+impl ServiceErrorCode for UpdateApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateApplicationError::BadRequest(_) => "BadRequestException",
+            UpdateApplicationError::Conflict(_) => "ConflictException",
+            UpdateApplicationError::Forbidden(_) => "ForbiddenException",
+            UpdateApplicationError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateApplicationError::NotFound(_) => "NotFoundException",
+            UpdateApplicationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWSServerlessApplicationRepository API. AWSServerlessApplicationRepository clients implement this trait.
 #[async_trait]
 pub trait ServerlessRepo {
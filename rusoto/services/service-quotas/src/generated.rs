@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -796,6 +796,30 @@ impl fmt::Display for AssociateServiceQuotaTemplateError {
     }
 }
 impl Error for AssociateServiceQuotaTemplateError {}
+impl ServiceErrorCode for AssociateServiceQuotaTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateServiceQuotaTemplateError::AWSServiceAccessNotEnabled(_) => {
+                "AWSServiceAccessNotEnabledException"
+            }
+            AssociateServiceQuotaTemplateError::AccessDenied(_) => "AccessDeniedException",
+            AssociateServiceQuotaTemplateError::DependencyAccessDenied(_) => {
+                "DependencyAccessDeniedException"
+            }
+            AssociateServiceQuotaTemplateError::NoAvailableOrganization(_) => {
+                "NoAvailableOrganizationException"
+            }
+            AssociateServiceQuotaTemplateError::OrganizationNotInAllFeaturesMode(_) => {
+                "OrganizationNotInAllFeaturesModeException"
+            }
+            AssociateServiceQuotaTemplateError::Service(_) => "ServiceException",
+            AssociateServiceQuotaTemplateError::TemplatesNotAvailableInRegion(_) => {
+                "TemplatesNotAvailableInRegionException"
+            }
+            AssociateServiceQuotaTemplateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteServiceQuotaIncreaseRequestFromTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeleteServiceQuotaIncreaseRequestFromTemplateError {
@@ -876,6 +900,37 @@ impl fmt::Display for DeleteServiceQuotaIncreaseRequestFromTemplateError {
     }
 }
 impl Error for DeleteServiceQuotaIncreaseRequestFromTemplateError {}
+impl ServiceErrorCode for DeleteServiceQuotaIncreaseRequestFromTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteServiceQuotaIncreaseRequestFromTemplateError::AWSServiceAccessNotEnabled(_) => {
+                "AWSServiceAccessNotEnabledException"
+            }
+            DeleteServiceQuotaIncreaseRequestFromTemplateError::AccessDenied(_) => {
+                "AccessDeniedException"
+            }
+            DeleteServiceQuotaIncreaseRequestFromTemplateError::DependencyAccessDenied(_) => {
+                "DependencyAccessDeniedException"
+            }
+            DeleteServiceQuotaIncreaseRequestFromTemplateError::IllegalArgument(_) => {
+                "IllegalArgumentException"
+            }
+            DeleteServiceQuotaIncreaseRequestFromTemplateError::NoAvailableOrganization(_) => {
+                "NoAvailableOrganizationException"
+            }
+            DeleteServiceQuotaIncreaseRequestFromTemplateError::NoSuchResource(_) => {
+                "NoSuchResourceException"
+            }
+            DeleteServiceQuotaIncreaseRequestFromTemplateError::Service(_) => "ServiceException",
+            DeleteServiceQuotaIncreaseRequestFromTemplateError::TemplatesNotAvailableInRegion(
+                _,
+            ) => "TemplatesNotAvailableInRegionException",
+            DeleteServiceQuotaIncreaseRequestFromTemplateError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateServiceQuotaTemplate
 #[derive(Debug, PartialEq)]
 pub enum DisassociateServiceQuotaTemplateError {
@@ -984,6 +1039,30 @@ impl fmt::Display for DisassociateServiceQuotaTemplateError {
     }
 }
 impl Error for DisassociateServiceQuotaTemplateError {}
+impl ServiceErrorCode for DisassociateServiceQuotaTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateServiceQuotaTemplateError::AWSServiceAccessNotEnabled(_) => {
+                "AWSServiceAccessNotEnabledException"
+            }
+            DisassociateServiceQuotaTemplateError::AccessDenied(_) => "AccessDeniedException",
+            DisassociateServiceQuotaTemplateError::DependencyAccessDenied(_) => {
+                "DependencyAccessDeniedException"
+            }
+            DisassociateServiceQuotaTemplateError::NoAvailableOrganization(_) => {
+                "NoAvailableOrganizationException"
+            }
+            DisassociateServiceQuotaTemplateError::Service(_) => "ServiceException",
+            DisassociateServiceQuotaTemplateError::ServiceQuotaTemplateNotInUse(_) => {
+                "ServiceQuotaTemplateNotInUseException"
+            }
+            DisassociateServiceQuotaTemplateError::TemplatesNotAvailableInRegion(_) => {
+                "TemplatesNotAvailableInRegionException"
+            }
+            DisassociateServiceQuotaTemplateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetAWSDefaultServiceQuota
 #[derive(Debug, PartialEq)]
 pub enum GetAWSDefaultServiceQuotaError {
@@ -1046,6 +1125,17 @@ impl fmt::Display for GetAWSDefaultServiceQuotaError {
     }
 }
 impl Error for GetAWSDefaultServiceQuotaError {}
+impl ServiceErrorCode for GetAWSDefaultServiceQuotaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAWSDefaultServiceQuotaError::AccessDenied(_) => "AccessDeniedException",
+            GetAWSDefaultServiceQuotaError::IllegalArgument(_) => "IllegalArgumentException",
+            GetAWSDefaultServiceQuotaError::NoSuchResource(_) => "NoSuchResourceException",
+            GetAWSDefaultServiceQuotaError::Service(_) => "ServiceException",
+            GetAWSDefaultServiceQuotaError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetAssociationForServiceQuotaTemplate
 #[derive(Debug, PartialEq)]
 pub enum GetAssociationForServiceQuotaTemplateError {
@@ -1160,6 +1250,32 @@ impl fmt::Display for GetAssociationForServiceQuotaTemplateError {
     }
 }
 impl Error for GetAssociationForServiceQuotaTemplateError {}
+impl ServiceErrorCode for GetAssociationForServiceQuotaTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAssociationForServiceQuotaTemplateError::AWSServiceAccessNotEnabled(_) => {
+                "AWSServiceAccessNotEnabledException"
+            }
+            GetAssociationForServiceQuotaTemplateError::AccessDenied(_) => "AccessDeniedException",
+            GetAssociationForServiceQuotaTemplateError::DependencyAccessDenied(_) => {
+                "DependencyAccessDeniedException"
+            }
+            GetAssociationForServiceQuotaTemplateError::NoAvailableOrganization(_) => {
+                "NoAvailableOrganizationException"
+            }
+            GetAssociationForServiceQuotaTemplateError::Service(_) => "ServiceException",
+            GetAssociationForServiceQuotaTemplateError::ServiceQuotaTemplateNotInUse(_) => {
+                "ServiceQuotaTemplateNotInUseException"
+            }
+            GetAssociationForServiceQuotaTemplateError::TemplatesNotAvailableInRegion(_) => {
+                "TemplatesNotAvailableInRegionException"
+            }
+            GetAssociationForServiceQuotaTemplateError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by GetRequestedServiceQuotaChange
 #[derive(Debug, PartialEq)]
 pub enum GetRequestedServiceQuotaChangeError {
@@ -1232,6 +1348,17 @@ impl fmt::Display for GetRequestedServiceQuotaChangeError {
     }
 }
 impl Error for GetRequestedServiceQuotaChangeError {}
+impl ServiceErrorCode for GetRequestedServiceQuotaChangeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRequestedServiceQuotaChangeError::AccessDenied(_) => "AccessDeniedException",
+            GetRequestedServiceQuotaChangeError::IllegalArgument(_) => "IllegalArgumentException",
+            GetRequestedServiceQuotaChangeError::NoSuchResource(_) => "NoSuchResourceException",
+            GetRequestedServiceQuotaChangeError::Service(_) => "ServiceException",
+            GetRequestedServiceQuotaChangeError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetServiceQuota
 #[derive(Debug, PartialEq)]
 pub enum GetServiceQuotaError {
@@ -1286,6 +1413,17 @@ impl fmt::Display for GetServiceQuotaError {
     }
 }
 impl Error for GetServiceQuotaError {}
+impl ServiceErrorCode for GetServiceQuotaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetServiceQuotaError::AccessDenied(_) => "AccessDeniedException",
+            GetServiceQuotaError::IllegalArgument(_) => "IllegalArgumentException",
+            GetServiceQuotaError::NoSuchResource(_) => "NoSuchResourceException",
+            GetServiceQuotaError::Service(_) => "ServiceException",
+            GetServiceQuotaError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetServiceQuotaIncreaseRequestFromTemplate
 #[derive(Debug, PartialEq)]
 pub enum GetServiceQuotaIncreaseRequestFromTemplateError {
@@ -1408,6 +1546,37 @@ impl fmt::Display for GetServiceQuotaIncreaseRequestFromTemplateError {
     }
 }
 impl Error for GetServiceQuotaIncreaseRequestFromTemplateError {}
+impl ServiceErrorCode for GetServiceQuotaIncreaseRequestFromTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetServiceQuotaIncreaseRequestFromTemplateError::AWSServiceAccessNotEnabled(_) => {
+                "AWSServiceAccessNotEnabledException"
+            }
+            GetServiceQuotaIncreaseRequestFromTemplateError::AccessDenied(_) => {
+                "AccessDeniedException"
+            }
+            GetServiceQuotaIncreaseRequestFromTemplateError::DependencyAccessDenied(_) => {
+                "DependencyAccessDeniedException"
+            }
+            GetServiceQuotaIncreaseRequestFromTemplateError::IllegalArgument(_) => {
+                "IllegalArgumentException"
+            }
+            GetServiceQuotaIncreaseRequestFromTemplateError::NoAvailableOrganization(_) => {
+                "NoAvailableOrganizationException"
+            }
+            GetServiceQuotaIncreaseRequestFromTemplateError::NoSuchResource(_) => {
+                "NoSuchResourceException"
+            }
+            GetServiceQuotaIncreaseRequestFromTemplateError::Service(_) => "ServiceException",
+            GetServiceQuotaIncreaseRequestFromTemplateError::TemplatesNotAvailableInRegion(_) => {
+                "TemplatesNotAvailableInRegionException"
+            }
+            GetServiceQuotaIncreaseRequestFromTemplateError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by ListAWSDefaultServiceQuotas
 #[derive(Debug, PartialEq)]
 pub enum ListAWSDefaultServiceQuotasError {
@@ -1482,6 +1651,20 @@ impl fmt::Display for ListAWSDefaultServiceQuotasError {
     }
 }
 impl Error for ListAWSDefaultServiceQuotasError {}
+impl ServiceErrorCode for ListAWSDefaultServiceQuotasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAWSDefaultServiceQuotasError::AccessDenied(_) => "AccessDeniedException",
+            ListAWSDefaultServiceQuotasError::IllegalArgument(_) => "IllegalArgumentException",
+            ListAWSDefaultServiceQuotasError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            ListAWSDefaultServiceQuotasError::NoSuchResource(_) => "NoSuchResourceException",
+            ListAWSDefaultServiceQuotasError::Service(_) => "ServiceException",
+            ListAWSDefaultServiceQuotasError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListRequestedServiceQuotaChangeHistory
 #[derive(Debug, PartialEq)]
 pub enum ListRequestedServiceQuotaChangeHistoryError {
@@ -1570,6 +1753,26 @@ impl fmt::Display for ListRequestedServiceQuotaChangeHistoryError {
     }
 }
 impl Error for ListRequestedServiceQuotaChangeHistoryError {}
+impl ServiceErrorCode for ListRequestedServiceQuotaChangeHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRequestedServiceQuotaChangeHistoryError::AccessDenied(_) => "AccessDeniedException",
+            ListRequestedServiceQuotaChangeHistoryError::IllegalArgument(_) => {
+                "IllegalArgumentException"
+            }
+            ListRequestedServiceQuotaChangeHistoryError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            ListRequestedServiceQuotaChangeHistoryError::NoSuchResource(_) => {
+                "NoSuchResourceException"
+            }
+            ListRequestedServiceQuotaChangeHistoryError::Service(_) => "ServiceException",
+            ListRequestedServiceQuotaChangeHistoryError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by ListRequestedServiceQuotaChangeHistoryByQuota
 #[derive(Debug, PartialEq)]
 pub enum ListRequestedServiceQuotaChangeHistoryByQuotaError {
@@ -1662,6 +1865,28 @@ impl fmt::Display for ListRequestedServiceQuotaChangeHistoryByQuotaError {
     }
 }
 impl Error for ListRequestedServiceQuotaChangeHistoryByQuotaError {}
+impl ServiceErrorCode for ListRequestedServiceQuotaChangeHistoryByQuotaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRequestedServiceQuotaChangeHistoryByQuotaError::AccessDenied(_) => {
+                "AccessDeniedException"
+            }
+            ListRequestedServiceQuotaChangeHistoryByQuotaError::IllegalArgument(_) => {
+                "IllegalArgumentException"
+            }
+            ListRequestedServiceQuotaChangeHistoryByQuotaError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            ListRequestedServiceQuotaChangeHistoryByQuotaError::NoSuchResource(_) => {
+                "NoSuchResourceException"
+            }
+            ListRequestedServiceQuotaChangeHistoryByQuotaError::Service(_) => "ServiceException",
+            ListRequestedServiceQuotaChangeHistoryByQuotaError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by ListServiceQuotaIncreaseRequestsInTemplate
 #[derive(Debug, PartialEq)]
 pub enum ListServiceQuotaIncreaseRequestsInTemplateError {
@@ -1774,6 +1999,34 @@ impl fmt::Display for ListServiceQuotaIncreaseRequestsInTemplateError {
     }
 }
 impl Error for ListServiceQuotaIncreaseRequestsInTemplateError {}
+impl ServiceErrorCode for ListServiceQuotaIncreaseRequestsInTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListServiceQuotaIncreaseRequestsInTemplateError::AWSServiceAccessNotEnabled(_) => {
+                "AWSServiceAccessNotEnabledException"
+            }
+            ListServiceQuotaIncreaseRequestsInTemplateError::AccessDenied(_) => {
+                "AccessDeniedException"
+            }
+            ListServiceQuotaIncreaseRequestsInTemplateError::DependencyAccessDenied(_) => {
+                "DependencyAccessDeniedException"
+            }
+            ListServiceQuotaIncreaseRequestsInTemplateError::IllegalArgument(_) => {
+                "IllegalArgumentException"
+            }
+            ListServiceQuotaIncreaseRequestsInTemplateError::NoAvailableOrganization(_) => {
+                "NoAvailableOrganizationException"
+            }
+            ListServiceQuotaIncreaseRequestsInTemplateError::Service(_) => "ServiceException",
+            ListServiceQuotaIncreaseRequestsInTemplateError::TemplatesNotAvailableInRegion(_) => {
+                "TemplatesNotAvailableInRegionException"
+            }
+            ListServiceQuotaIncreaseRequestsInTemplateError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by ListServiceQuotas
 #[derive(Debug, PartialEq)]
 pub enum ListServiceQuotasError {
@@ -1836,6 +2089,18 @@ impl fmt::Display for ListServiceQuotasError {
     }
 }
 impl Error for ListServiceQuotasError {}
+impl ServiceErrorCode for ListServiceQuotasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListServiceQuotasError::AccessDenied(_) => "AccessDeniedException",
+            ListServiceQuotasError::IllegalArgument(_) => "IllegalArgumentException",
+            ListServiceQuotasError::InvalidPaginationToken(_) => "InvalidPaginationTokenException",
+            ListServiceQuotasError::NoSuchResource(_) => "NoSuchResourceException",
+            ListServiceQuotasError::Service(_) => "ServiceException",
+            ListServiceQuotasError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListServices
 #[derive(Debug, PartialEq)]
 pub enum ListServicesError {
@@ -1890,6 +2155,17 @@ impl fmt::Display for ListServicesError {
     }
 }
 impl Error for ListServicesError {}
+impl ServiceErrorCode for ListServicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListServicesError::AccessDenied(_) => "AccessDeniedException",
+            ListServicesError::IllegalArgument(_) => "IllegalArgumentException",
+            ListServicesError::InvalidPaginationToken(_) => "InvalidPaginationTokenException",
+            ListServicesError::Service(_) => "ServiceException",
+            ListServicesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1944,6 +2220,17 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AccessDenied(_) => "AccessDeniedException",
+            ListTagsForResourceError::IllegalArgument(_) => "IllegalArgumentException",
+            ListTagsForResourceError::NoSuchResource(_) => "NoSuchResourceException",
+            ListTagsForResourceError::Service(_) => "ServiceException",
+            ListTagsForResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutServiceQuotaIncreaseRequestIntoTemplate
 #[derive(Debug, PartialEq)]
 pub enum PutServiceQuotaIncreaseRequestIntoTemplateError {
@@ -2076,6 +2363,40 @@ impl fmt::Display for PutServiceQuotaIncreaseRequestIntoTemplateError {
     }
 }
 impl Error for PutServiceQuotaIncreaseRequestIntoTemplateError {}
+impl ServiceErrorCode for PutServiceQuotaIncreaseRequestIntoTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutServiceQuotaIncreaseRequestIntoTemplateError::AWSServiceAccessNotEnabled(_) => {
+                "AWSServiceAccessNotEnabledException"
+            }
+            PutServiceQuotaIncreaseRequestIntoTemplateError::AccessDenied(_) => {
+                "AccessDeniedException"
+            }
+            PutServiceQuotaIncreaseRequestIntoTemplateError::DependencyAccessDenied(_) => {
+                "DependencyAccessDeniedException"
+            }
+            PutServiceQuotaIncreaseRequestIntoTemplateError::IllegalArgument(_) => {
+                "IllegalArgumentException"
+            }
+            PutServiceQuotaIncreaseRequestIntoTemplateError::NoAvailableOrganization(_) => {
+                "NoAvailableOrganizationException"
+            }
+            PutServiceQuotaIncreaseRequestIntoTemplateError::NoSuchResource(_) => {
+                "NoSuchResourceException"
+            }
+            PutServiceQuotaIncreaseRequestIntoTemplateError::QuotaExceeded(_) => {
+                "QuotaExceededException"
+            }
+            PutServiceQuotaIncreaseRequestIntoTemplateError::Service(_) => "ServiceException",
+            PutServiceQuotaIncreaseRequestIntoTemplateError::TemplatesNotAvailableInRegion(_) => {
+                "TemplatesNotAvailableInRegionException"
+            }
+            PutServiceQuotaIncreaseRequestIntoTemplateError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by RequestServiceQuotaIncrease
 #[derive(Debug, PartialEq)]
 pub enum RequestServiceQuotaIncreaseError {
@@ -2178,6 +2499,27 @@ impl fmt::Display for RequestServiceQuotaIncreaseError {
     }
 }
 impl Error for RequestServiceQuotaIncreaseError {}
+impl ServiceErrorCode for RequestServiceQuotaIncreaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RequestServiceQuotaIncreaseError::AccessDenied(_) => "AccessDeniedException",
+            RequestServiceQuotaIncreaseError::DependencyAccessDenied(_) => {
+                "DependencyAccessDeniedException"
+            }
+            RequestServiceQuotaIncreaseError::IllegalArgument(_) => "IllegalArgumentException",
+            RequestServiceQuotaIncreaseError::InvalidResourceState(_) => {
+                "InvalidResourceStateException"
+            }
+            RequestServiceQuotaIncreaseError::NoSuchResource(_) => "NoSuchResourceException",
+            RequestServiceQuotaIncreaseError::QuotaExceeded(_) => "QuotaExceededException",
+            RequestServiceQuotaIncreaseError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            RequestServiceQuotaIncreaseError::Service(_) => "ServiceException",
+            RequestServiceQuotaIncreaseError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2244,6 +2586,19 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AccessDenied(_) => "AccessDeniedException",
+            TagResourceError::IllegalArgument(_) => "IllegalArgumentException",
+            TagResourceError::NoSuchResource(_) => "NoSuchResourceException",
+            TagResourceError::Service(_) => "ServiceException",
+            TagResourceError::TagPolicyViolation(_) => "TagPolicyViolationException",
+            TagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+            TagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2298,6 +2653,17 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AccessDenied(_) => "AccessDeniedException",
+            UntagResourceError::IllegalArgument(_) => "IllegalArgumentException",
+            UntagResourceError::NoSuchResource(_) => "NoSuchResourceException",
+            UntagResourceError::Service(_) => "ServiceException",
+            UntagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Service Quotas API. Service Quotas clients implement this trait.
 #[async_trait]
 pub trait ServiceQuotas {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -300,6 +300,7 @@ pub struct CopyProductInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub copy_options: Option<Vec<String>>,
     /// <p> A unique identifier that you provide to ensure idempotency. If multiple requests differ only by the idempotency token, the same response is returned for each repeated request. </p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p>The Amazon Resource Name (ARN) of the source product.</p>
@@ -341,6 +342,7 @@ pub struct CreateConstraintInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>A unique identifier that you provide to ensure idempotency. If multiple requests differ only by the idempotency token, the same response is returned for each repeated request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p><p>The constraint parameters, in JSON format. The syntax depends on the constraint type as follows:</p> <dl> <dt>LAUNCH</dt> <dd> <p>You are required to specify either the <code>RoleArn</code> or the <code>LocalRoleName</code> but can&#39;t use both.</p> <p>Specify the <code>RoleArn</code> property as follows:</p> <p> <code>{&quot;RoleArn&quot; : &quot;arn:aws:iam::123456789012:role/LaunchRole&quot;}</code> </p> <p>Specify the <code>LocalRoleName</code> property as follows:</p> <p> <code>{&quot;LocalRoleName&quot;: &quot;SCBasicLaunchRole&quot;}</code> </p> <p>If you specify the <code>LocalRoleName</code> property, when an account uses the launch constraint, the IAM role with that name in the account will be used. This allows launch-role constraints to be account-agnostic so the administrator can create fewer resources per shared account.</p> <note> <p>The given role name must exist in the account used to create the launch constraint and the account of the user who launches a product with this launch constraint.</p> </note> <p>You cannot have both a <code>LAUNCH</code> and a <code>STACKSET</code> constraint.</p> <p>You also cannot have more than one <code>LAUNCH</code> constraint on a product and portfolio.</p> </dd> <dt>NOTIFICATION</dt> <dd> <p>Specify the <code>NotificationArns</code> property as follows:</p> <p> <code>{&quot;NotificationArns&quot; : [&quot;arn:aws:sns:us-east-1:123456789012:Topic&quot;]}</code> </p> </dd> <dt>RESOURCE<em>UPDATE</dt> <dd> <p>Specify the <code>TagUpdatesOnProvisionedProduct</code> property as follows:</p> <p> <code>{&quot;Version&quot;:&quot;2.0&quot;,&quot;Properties&quot;:{&quot;TagUpdateOnProvisionedProduct&quot;:&quot;String&quot;}}</code> </p> <p>The <code>TagUpdatesOnProvisionedProduct</code> property accepts a string value of <code>ALLOWED</code> or <code>NOT</em>ALLOWED</code>.</p> </dd> <dt>STACKSET</dt> <dd> <p>Specify the <code>Parameters</code> property as follows:</p> <p> <code>{&quot;Version&quot;: &quot;String&quot;, &quot;Properties&quot;: {&quot;AccountList&quot;: [ &quot;String&quot; ], &quot;RegionList&quot;: [ &quot;String&quot; ], &quot;AdminRole&quot;: &quot;String&quot;, &quot;ExecutionRole&quot;: &quot;String&quot;}}</code> </p> <p>You cannot have both a <code>LAUNCH</code> and a <code>STACKSET</code> constraint.</p> <p>You also cannot have more than one <code>STACKSET</code> constraint on a product and portfolio.</p> <p>Products with a <code>STACKSET</code> constraint will launch an AWS CloudFormation stack set.</p> </dd> <dt>TEMPLATE</dt> <dd> <p>Specify the <code>Rules</code> property. For more information, see <a href="http://docs.aws.amazon.com/servicecatalog/latest/adminguide/reference-template_constraint_rules.html">Template Constraint Rules</a>.</p> </dd> </dl></p>
@@ -389,6 +391,7 @@ pub struct CreatePortfolioInput {
     #[serde(rename = "DisplayName")]
     pub display_name: String,
     /// <p>A unique identifier that you provide to ensure idempotency. If multiple requests differ only by the idempotency token, the same response is returned for each repeated request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p>The name of the portfolio provider.</p>
@@ -462,6 +465,7 @@ pub struct CreateProductInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub distributor: Option<String>,
     /// <p>A unique identifier that you provide to ensure idempotency. If multiple requests differ only by the idempotency token, the same response is returned for each repeated request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p>The name of the product.</p>
@@ -519,6 +523,7 @@ pub struct CreateProvisionedProductPlanInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub accept_language: Option<String>,
     /// <p>A unique identifier that you provide to ensure idempotency. If multiple requests differ only by the idempotency token, the same response is returned for each repeated request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p>Passed to CloudFormation. The SNS topic ARNs to which to publish stack-related events.</p>
@@ -587,6 +592,7 @@ pub struct CreateProvisioningArtifactInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub accept_language: Option<String>,
     /// <p>A unique identifier that you provide to ensure idempotency. If multiple requests differ only by the idempotency token, the same response is returned for each repeated request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p>The configuration for the provisioning artifact.</p>
@@ -632,6 +638,7 @@ pub struct CreateServiceActionInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>A unique identifier that you provide to ensure idempotency. If multiple requests differ only by the idempotency token, the same response is returned for each repeated request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p>The self-service action name.</p>
@@ -1466,6 +1473,7 @@ pub struct ExecuteProvisionedProductPlanInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub accept_language: Option<String>,
     /// <p>A unique identifier that you provide to ensure idempotency. If multiple requests differ only by the idempotency token, the same response is returned for each repeated request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p>The plan identifier.</p>
@@ -1490,6 +1498,7 @@ pub struct ExecuteProvisionedProductServiceActionInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub accept_language: Option<String>,
     /// <p>An idempotency token that uniquely identifies the execute request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ExecuteToken")]
     pub execute_token: String,
     /// <p>A map of all self-service action parameters and their values. If a provided parameter is of a special type, such as <code>TARGET</code>, the provided value will override the default value generated by AWS Service Catalog. If the parameters field is not provided, no additional parameters are passed and default values will be used for any special parameters such as <code>TARGET</code>.</p>
@@ -1620,6 +1629,7 @@ pub struct ImportAsProvisionedProductInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub accept_language: Option<String>,
     /// <p>A unique identifier that you provide to ensure idempotency. If multiple requests differ only by the idempotency token, the same response is returned for each repeated request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p>The unique identifier of the resource to be imported. It only currently supports CloudFormation stack IDs.</p>
@@ -2517,6 +2527,7 @@ pub struct ProvisionProductInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub product_name: Option<String>,
     /// <p>An idempotency token that uniquely identifies the provisioning request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ProvisionToken")]
     pub provision_token: String,
     /// <p>A user-friendly name for the provisioned product. This value must be unique for the AWS account and cannot be updated after the product is provisioned.</p>
@@ -3601,6 +3612,7 @@ pub struct TerminateProvisionedProductInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retain_physical_resources: Option<bool>,
     /// <p>An idempotency token that uniquely identifies the termination request. This token is only valid during the termination process. After the provisioned product is terminated, subsequent requests to terminate the same provisioned product always return <b>ResourceNotFound</b>.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "TerminateToken")]
     pub terminate_token: String,
 }
@@ -3845,6 +3857,7 @@ pub struct UpdateProvisionedProductInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<Tag>>,
     /// <p>The idempotency token that uniquely identifies the provisioning update request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "UpdateToken")]
     pub update_token: String,
 }
@@ -3866,6 +3879,7 @@ pub struct UpdateProvisionedProductPropertiesInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub accept_language: Option<String>,
     /// <p>The idempotency token that uniquely identifies the provisioning product update request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "IdempotencyToken")]
     pub idempotency_token: String,
     /// <p>The identifier of the provisioned product.</p>
@@ -4114,6 +4128,15 @@ impl fmt::Display for AcceptPortfolioShareError {
     }
 }
 impl Error for AcceptPortfolioShareError {}
+impl ServiceErrorCode for AcceptPortfolioShareError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AcceptPortfolioShareError::InvalidParameters(_) => "InvalidParametersException",
+            AcceptPortfolioShareError::LimitExceeded(_) => "LimitExceededException",
+            AcceptPortfolioShareError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by AssociateBudgetWithResource
 #[derive(Debug, PartialEq)]
 pub enum AssociateBudgetWithResourceError {
@@ -4176,6 +4199,16 @@ impl fmt::Display for AssociateBudgetWithResourceError {
     }
 }
 impl Error for AssociateBudgetWithResourceError {}
+impl ServiceErrorCode for AssociateBudgetWithResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateBudgetWithResourceError::DuplicateResource(_) => "DuplicateResourceException",
+            AssociateBudgetWithResourceError::InvalidParameters(_) => "InvalidParametersException",
+            AssociateBudgetWithResourceError::LimitExceeded(_) => "LimitExceededException",
+            AssociateBudgetWithResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by AssociatePrincipalWithPortfolio
 #[derive(Debug, PartialEq)]
 pub enum AssociatePrincipalWithPortfolioError {
@@ -4232,6 +4265,19 @@ impl fmt::Display for AssociatePrincipalWithPortfolioError {
     }
 }
 impl Error for AssociatePrincipalWithPortfolioError {}
+impl ServiceErrorCode for AssociatePrincipalWithPortfolioError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociatePrincipalWithPortfolioError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+            AssociatePrincipalWithPortfolioError::LimitExceeded(_) => "LimitExceededException",
+            AssociatePrincipalWithPortfolioError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by AssociateProductWithPortfolio
 #[derive(Debug, PartialEq)]
 pub enum AssociateProductWithPortfolioError {
@@ -4286,6 +4332,17 @@ impl fmt::Display for AssociateProductWithPortfolioError {
     }
 }
 impl Error for AssociateProductWithPortfolioError {}
+impl ServiceErrorCode for AssociateProductWithPortfolioError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateProductWithPortfolioError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+            AssociateProductWithPortfolioError::LimitExceeded(_) => "LimitExceededException",
+            AssociateProductWithPortfolioError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by AssociateServiceActionWithProvisioningArtifact
 #[derive(Debug, PartialEq)]
 pub enum AssociateServiceActionWithProvisioningArtifactError {
@@ -4346,6 +4403,21 @@ impl fmt::Display for AssociateServiceActionWithProvisioningArtifactError {
     }
 }
 impl Error for AssociateServiceActionWithProvisioningArtifactError {}
+impl ServiceErrorCode for AssociateServiceActionWithProvisioningArtifactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateServiceActionWithProvisioningArtifactError::DuplicateResource(_) => {
+                "DuplicateResourceException"
+            }
+            AssociateServiceActionWithProvisioningArtifactError::LimitExceeded(_) => {
+                "LimitExceededException"
+            }
+            AssociateServiceActionWithProvisioningArtifactError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by AssociateTagOptionWithResource
 #[derive(Debug, PartialEq)]
 pub enum AssociateTagOptionWithResourceError {
@@ -4428,6 +4500,24 @@ impl fmt::Display for AssociateTagOptionWithResourceError {
     }
 }
 impl Error for AssociateTagOptionWithResourceError {}
+impl ServiceErrorCode for AssociateTagOptionWithResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateTagOptionWithResourceError::DuplicateResource(_) => {
+                "DuplicateResourceException"
+            }
+            AssociateTagOptionWithResourceError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+            AssociateTagOptionWithResourceError::InvalidState(_) => "InvalidStateException",
+            AssociateTagOptionWithResourceError::LimitExceeded(_) => "LimitExceededException",
+            AssociateTagOptionWithResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateTagOptionWithResourceError::TagOptionNotMigrated(_) => {
+                "TagOptionNotMigratedException"
+            }
+        }
+    }
+}
 /// Errors returned by BatchAssociateServiceActionWithProvisioningArtifact
 #[derive(Debug, PartialEq)]
 pub enum BatchAssociateServiceActionWithProvisioningArtifactError {
@@ -4466,6 +4556,15 @@ impl fmt::Display for BatchAssociateServiceActionWithProvisioningArtifactError {
     }
 }
 impl Error for BatchAssociateServiceActionWithProvisioningArtifactError {}
+impl ServiceErrorCode for BatchAssociateServiceActionWithProvisioningArtifactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchAssociateServiceActionWithProvisioningArtifactError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+        }
+    }
+}
 /// Errors returned by BatchDisassociateServiceActionFromProvisioningArtifact
 #[derive(Debug, PartialEq)]
 pub enum BatchDisassociateServiceActionFromProvisioningArtifactError {
@@ -4502,6 +4601,15 @@ impl fmt::Display for BatchDisassociateServiceActionFromProvisioningArtifactErro
     }
 }
 impl Error for BatchDisassociateServiceActionFromProvisioningArtifactError {}
+impl ServiceErrorCode for BatchDisassociateServiceActionFromProvisioningArtifactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchDisassociateServiceActionFromProvisioningArtifactError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+        }
+    }
+}
 /// Errors returned by CopyProduct
 #[derive(Debug, PartialEq)]
 pub enum CopyProductError {
@@ -4538,6 +4646,14 @@ impl fmt::Display for CopyProductError {
     }
 }
 impl Error for CopyProductError {}
+impl ServiceErrorCode for CopyProductError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyProductError::InvalidParameters(_) => "InvalidParametersException",
+            CopyProductError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateConstraint
 #[derive(Debug, PartialEq)]
 pub enum CreateConstraintError {
@@ -4586,6 +4702,16 @@ impl fmt::Display for CreateConstraintError {
     }
 }
 impl Error for CreateConstraintError {}
+impl ServiceErrorCode for CreateConstraintError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConstraintError::DuplicateResource(_) => "DuplicateResourceException",
+            CreateConstraintError::InvalidParameters(_) => "InvalidParametersException",
+            CreateConstraintError::LimitExceeded(_) => "LimitExceededException",
+            CreateConstraintError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreatePortfolio
 #[derive(Debug, PartialEq)]
 pub enum CreatePortfolioError {
@@ -4630,6 +4756,15 @@ impl fmt::Display for CreatePortfolioError {
     }
 }
 impl Error for CreatePortfolioError {}
+impl ServiceErrorCode for CreatePortfolioError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePortfolioError::InvalidParameters(_) => "InvalidParametersException",
+            CreatePortfolioError::LimitExceeded(_) => "LimitExceededException",
+            CreatePortfolioError::TagOptionNotMigrated(_) => "TagOptionNotMigratedException",
+        }
+    }
+}
 /// Errors returned by CreatePortfolioShare
 #[derive(Debug, PartialEq)]
 pub enum CreatePortfolioShareError {
@@ -4690,6 +4825,17 @@ impl fmt::Display for CreatePortfolioShareError {
     }
 }
 impl Error for CreatePortfolioShareError {}
+impl ServiceErrorCode for CreatePortfolioShareError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePortfolioShareError::InvalidParameters(_) => "InvalidParametersException",
+            CreatePortfolioShareError::InvalidState(_) => "InvalidStateException",
+            CreatePortfolioShareError::LimitExceeded(_) => "LimitExceededException",
+            CreatePortfolioShareError::OperationNotSupported(_) => "OperationNotSupportedException",
+            CreatePortfolioShareError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateProduct
 #[derive(Debug, PartialEq)]
 pub enum CreateProductError {
@@ -4732,6 +4878,15 @@ impl fmt::Display for CreateProductError {
     }
 }
 impl Error for CreateProductError {}
+impl ServiceErrorCode for CreateProductError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProductError::InvalidParameters(_) => "InvalidParametersException",
+            CreateProductError::LimitExceeded(_) => "LimitExceededException",
+            CreateProductError::TagOptionNotMigrated(_) => "TagOptionNotMigratedException",
+        }
+    }
+}
 /// Errors returned by CreateProvisionedProductPlan
 #[derive(Debug, PartialEq)]
 pub enum CreateProvisionedProductPlanError {
@@ -4786,6 +4941,15 @@ impl fmt::Display for CreateProvisionedProductPlanError {
     }
 }
 impl Error for CreateProvisionedProductPlanError {}
+impl ServiceErrorCode for CreateProvisionedProductPlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProvisionedProductPlanError::InvalidParameters(_) => "InvalidParametersException",
+            CreateProvisionedProductPlanError::InvalidState(_) => "InvalidStateException",
+            CreateProvisionedProductPlanError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateProvisioningArtifact
 #[derive(Debug, PartialEq)]
 pub enum CreateProvisioningArtifactError {
@@ -4836,6 +5000,15 @@ impl fmt::Display for CreateProvisioningArtifactError {
     }
 }
 impl Error for CreateProvisioningArtifactError {}
+impl ServiceErrorCode for CreateProvisioningArtifactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProvisioningArtifactError::InvalidParameters(_) => "InvalidParametersException",
+            CreateProvisioningArtifactError::LimitExceeded(_) => "LimitExceededException",
+            CreateProvisioningArtifactError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateServiceAction
 #[derive(Debug, PartialEq)]
 pub enum CreateServiceActionError {
@@ -4874,6 +5047,14 @@ impl fmt::Display for CreateServiceActionError {
     }
 }
 impl Error for CreateServiceActionError {}
+impl ServiceErrorCode for CreateServiceActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateServiceActionError::InvalidParameters(_) => "InvalidParametersException",
+            CreateServiceActionError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateTagOption
 #[derive(Debug, PartialEq)]
 pub enum CreateTagOptionError {
@@ -4918,6 +5099,15 @@ impl fmt::Display for CreateTagOptionError {
     }
 }
 impl Error for CreateTagOptionError {}
+impl ServiceErrorCode for CreateTagOptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTagOptionError::DuplicateResource(_) => "DuplicateResourceException",
+            CreateTagOptionError::LimitExceeded(_) => "LimitExceededException",
+            CreateTagOptionError::TagOptionNotMigrated(_) => "TagOptionNotMigratedException",
+        }
+    }
+}
 /// Errors returned by DeleteConstraint
 #[derive(Debug, PartialEq)]
 pub enum DeleteConstraintError {
@@ -4954,6 +5144,14 @@ impl fmt::Display for DeleteConstraintError {
     }
 }
 impl Error for DeleteConstraintError {}
+impl ServiceErrorCode for DeleteConstraintError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConstraintError::InvalidParameters(_) => "InvalidParametersException",
+            DeleteConstraintError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeletePortfolio
 #[derive(Debug, PartialEq)]
 pub enum DeletePortfolioError {
@@ -5004,6 +5202,16 @@ impl fmt::Display for DeletePortfolioError {
     }
 }
 impl Error for DeletePortfolioError {}
+impl ServiceErrorCode for DeletePortfolioError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePortfolioError::InvalidParameters(_) => "InvalidParametersException",
+            DeletePortfolioError::ResourceInUse(_) => "ResourceInUseException",
+            DeletePortfolioError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeletePortfolioError::TagOptionNotMigrated(_) => "TagOptionNotMigratedException",
+        }
+    }
+}
 /// Errors returned by DeletePortfolioShare
 #[derive(Debug, PartialEq)]
 pub enum DeletePortfolioShareError {
@@ -5058,6 +5266,16 @@ impl fmt::Display for DeletePortfolioShareError {
     }
 }
 impl Error for DeletePortfolioShareError {}
+impl ServiceErrorCode for DeletePortfolioShareError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePortfolioShareError::InvalidParameters(_) => "InvalidParametersException",
+            DeletePortfolioShareError::InvalidState(_) => "InvalidStateException",
+            DeletePortfolioShareError::OperationNotSupported(_) => "OperationNotSupportedException",
+            DeletePortfolioShareError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteProduct
 #[derive(Debug, PartialEq)]
 pub enum DeleteProductError {
@@ -5106,6 +5324,16 @@ impl fmt::Display for DeleteProductError {
     }
 }
 impl Error for DeleteProductError {}
+impl ServiceErrorCode for DeleteProductError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProductError::InvalidParameters(_) => "InvalidParametersException",
+            DeleteProductError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteProductError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteProductError::TagOptionNotMigrated(_) => "TagOptionNotMigratedException",
+        }
+    }
+}
 /// Errors returned by DeleteProvisionedProductPlan
 #[derive(Debug, PartialEq)]
 pub enum DeleteProvisionedProductPlanError {
@@ -5152,6 +5380,14 @@ impl fmt::Display for DeleteProvisionedProductPlanError {
     }
 }
 impl Error for DeleteProvisionedProductPlanError {}
+impl ServiceErrorCode for DeleteProvisionedProductPlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProvisionedProductPlanError::InvalidParameters(_) => "InvalidParametersException",
+            DeleteProvisionedProductPlanError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteProvisioningArtifact
 #[derive(Debug, PartialEq)]
 pub enum DeleteProvisioningArtifactError {
@@ -5202,6 +5438,15 @@ impl fmt::Display for DeleteProvisioningArtifactError {
     }
 }
 impl Error for DeleteProvisioningArtifactError {}
+impl ServiceErrorCode for DeleteProvisioningArtifactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProvisioningArtifactError::InvalidParameters(_) => "InvalidParametersException",
+            DeleteProvisioningArtifactError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteProvisioningArtifactError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteServiceAction
 #[derive(Debug, PartialEq)]
 pub enum DeleteServiceActionError {
@@ -5240,6 +5485,14 @@ impl fmt::Display for DeleteServiceActionError {
     }
 }
 impl Error for DeleteServiceActionError {}
+impl ServiceErrorCode for DeleteServiceActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteServiceActionError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteServiceActionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteTagOption
 #[derive(Debug, PartialEq)]
 pub enum DeleteTagOptionError {
@@ -5284,6 +5537,15 @@ impl fmt::Display for DeleteTagOptionError {
     }
 }
 impl Error for DeleteTagOptionError {}
+impl ServiceErrorCode for DeleteTagOptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTagOptionError::ResourceInUse(_) => "ResourceInUseException",
+            DeleteTagOptionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteTagOptionError::TagOptionNotMigrated(_) => "TagOptionNotMigratedException",
+        }
+    }
+}
 /// Errors returned by DescribeConstraint
 #[derive(Debug, PartialEq)]
 pub enum DescribeConstraintError {
@@ -5314,6 +5576,13 @@ impl fmt::Display for DescribeConstraintError {
     }
 }
 impl Error for DescribeConstraintError {}
+impl ServiceErrorCode for DescribeConstraintError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConstraintError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeCopyProductStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeCopyProductStatusError {
@@ -5346,6 +5615,13 @@ impl fmt::Display for DescribeCopyProductStatusError {
     }
 }
 impl Error for DescribeCopyProductStatusError {}
+impl ServiceErrorCode for DescribeCopyProductStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCopyProductStatusError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribePortfolio
 #[derive(Debug, PartialEq)]
 pub enum DescribePortfolioError {
@@ -5376,6 +5652,13 @@ impl fmt::Display for DescribePortfolioError {
     }
 }
 impl Error for DescribePortfolioError {}
+impl ServiceErrorCode for DescribePortfolioError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePortfolioError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribePortfolioShareStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribePortfolioShareStatusError {
@@ -5432,6 +5715,17 @@ impl fmt::Display for DescribePortfolioShareStatusError {
     }
 }
 impl Error for DescribePortfolioShareStatusError {}
+impl ServiceErrorCode for DescribePortfolioShareStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePortfolioShareStatusError::InvalidParameters(_) => "InvalidParametersException",
+            DescribePortfolioShareStatusError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            DescribePortfolioShareStatusError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribePortfolioShares
 #[derive(Debug, PartialEq)]
 pub enum DescribePortfolioSharesError {
@@ -5472,6 +5766,14 @@ impl fmt::Display for DescribePortfolioSharesError {
     }
 }
 impl Error for DescribePortfolioSharesError {}
+impl ServiceErrorCode for DescribePortfolioSharesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePortfolioSharesError::InvalidParameters(_) => "InvalidParametersException",
+            DescribePortfolioSharesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeProduct
 #[derive(Debug, PartialEq)]
 pub enum DescribeProductError {
@@ -5508,6 +5810,14 @@ impl fmt::Display for DescribeProductError {
     }
 }
 impl Error for DescribeProductError {}
+impl ServiceErrorCode for DescribeProductError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProductError::InvalidParameters(_) => "InvalidParametersException",
+            DescribeProductError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeProductAsAdmin
 #[derive(Debug, PartialEq)]
 pub enum DescribeProductAsAdminError {
@@ -5548,6 +5858,14 @@ impl fmt::Display for DescribeProductAsAdminError {
     }
 }
 impl Error for DescribeProductAsAdminError {}
+impl ServiceErrorCode for DescribeProductAsAdminError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProductAsAdminError::InvalidParameters(_) => "InvalidParametersException",
+            DescribeProductAsAdminError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeProductView
 #[derive(Debug, PartialEq)]
 pub enum DescribeProductViewError {
@@ -5588,6 +5906,14 @@ impl fmt::Display for DescribeProductViewError {
     }
 }
 impl Error for DescribeProductViewError {}
+impl ServiceErrorCode for DescribeProductViewError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProductViewError::InvalidParameters(_) => "InvalidParametersException",
+            DescribeProductViewError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeProvisionedProduct
 #[derive(Debug, PartialEq)]
 pub enum DescribeProvisionedProductError {
@@ -5630,6 +5956,14 @@ impl fmt::Display for DescribeProvisionedProductError {
     }
 }
 impl Error for DescribeProvisionedProductError {}
+impl ServiceErrorCode for DescribeProvisionedProductError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProvisionedProductError::InvalidParameters(_) => "InvalidParametersException",
+            DescribeProvisionedProductError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeProvisionedProductPlan
 #[derive(Debug, PartialEq)]
 pub enum DescribeProvisionedProductPlanError {
@@ -5676,6 +6010,16 @@ impl fmt::Display for DescribeProvisionedProductPlanError {
     }
 }
 impl Error for DescribeProvisionedProductPlanError {}
+impl ServiceErrorCode for DescribeProvisionedProductPlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProvisionedProductPlanError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+            DescribeProvisionedProductPlanError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeProvisioningArtifact
 #[derive(Debug, PartialEq)]
 pub enum DescribeProvisioningArtifactError {
@@ -5722,6 +6066,14 @@ impl fmt::Display for DescribeProvisioningArtifactError {
     }
 }
 impl Error for DescribeProvisioningArtifactError {}
+impl ServiceErrorCode for DescribeProvisioningArtifactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProvisioningArtifactError::InvalidParameters(_) => "InvalidParametersException",
+            DescribeProvisioningArtifactError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeProvisioningParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeProvisioningParametersError {
@@ -5768,6 +6120,16 @@ impl fmt::Display for DescribeProvisioningParametersError {
     }
 }
 impl Error for DescribeProvisioningParametersError {}
+impl ServiceErrorCode for DescribeProvisioningParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProvisioningParametersError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+            DescribeProvisioningParametersError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeRecord
 #[derive(Debug, PartialEq)]
 pub enum DescribeRecordError {
@@ -5798,6 +6160,13 @@ impl fmt::Display for DescribeRecordError {
     }
 }
 impl Error for DescribeRecordError {}
+impl ServiceErrorCode for DescribeRecordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRecordError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeServiceAction
 #[derive(Debug, PartialEq)]
 pub enum DescribeServiceActionError {
@@ -5830,6 +6199,13 @@ impl fmt::Display for DescribeServiceActionError {
     }
 }
 impl Error for DescribeServiceActionError {}
+impl ServiceErrorCode for DescribeServiceActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeServiceActionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeServiceActionExecutionParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeServiceActionExecutionParametersError {
@@ -5876,6 +6252,18 @@ impl fmt::Display for DescribeServiceActionExecutionParametersError {
     }
 }
 impl Error for DescribeServiceActionExecutionParametersError {}
+impl ServiceErrorCode for DescribeServiceActionExecutionParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeServiceActionExecutionParametersError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+            DescribeServiceActionExecutionParametersError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeTagOption
 #[derive(Debug, PartialEq)]
 pub enum DescribeTagOptionError {
@@ -5914,6 +6302,14 @@ impl fmt::Display for DescribeTagOptionError {
     }
 }
 impl Error for DescribeTagOptionError {}
+impl ServiceErrorCode for DescribeTagOptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTagOptionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeTagOptionError::TagOptionNotMigrated(_) => "TagOptionNotMigratedException",
+        }
+    }
+}
 /// Errors returned by DisableAWSOrganizationsAccess
 #[derive(Debug, PartialEq)]
 pub enum DisableAWSOrganizationsAccessError {
@@ -5968,6 +6364,17 @@ impl fmt::Display for DisableAWSOrganizationsAccessError {
     }
 }
 impl Error for DisableAWSOrganizationsAccessError {}
+impl ServiceErrorCode for DisableAWSOrganizationsAccessError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableAWSOrganizationsAccessError::InvalidState(_) => "InvalidStateException",
+            DisableAWSOrganizationsAccessError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            DisableAWSOrganizationsAccessError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateBudgetFromResource
 #[derive(Debug, PartialEq)]
 pub enum DisassociateBudgetFromResourceError {
@@ -6004,6 +6411,13 @@ impl fmt::Display for DisassociateBudgetFromResourceError {
     }
 }
 impl Error for DisassociateBudgetFromResourceError {}
+impl ServiceErrorCode for DisassociateBudgetFromResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateBudgetFromResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociatePrincipalFromPortfolio
 #[derive(Debug, PartialEq)]
 pub enum DisassociatePrincipalFromPortfolioError {
@@ -6050,6 +6464,18 @@ impl fmt::Display for DisassociatePrincipalFromPortfolioError {
     }
 }
 impl Error for DisassociatePrincipalFromPortfolioError {}
+impl ServiceErrorCode for DisassociatePrincipalFromPortfolioError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociatePrincipalFromPortfolioError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+            DisassociatePrincipalFromPortfolioError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateProductFromPortfolio
 #[derive(Debug, PartialEq)]
 pub enum DisassociateProductFromPortfolioError {
@@ -6106,6 +6532,19 @@ impl fmt::Display for DisassociateProductFromPortfolioError {
     }
 }
 impl Error for DisassociateProductFromPortfolioError {}
+impl ServiceErrorCode for DisassociateProductFromPortfolioError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateProductFromPortfolioError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+            DisassociateProductFromPortfolioError::ResourceInUse(_) => "ResourceInUseException",
+            DisassociateProductFromPortfolioError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateServiceActionFromProvisioningArtifact
 #[derive(Debug, PartialEq)]
 pub enum DisassociateServiceActionFromProvisioningArtifactError {
@@ -6144,6 +6583,15 @@ impl fmt::Display for DisassociateServiceActionFromProvisioningArtifactError {
     }
 }
 impl Error for DisassociateServiceActionFromProvisioningArtifactError {}
+impl ServiceErrorCode for DisassociateServiceActionFromProvisioningArtifactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateServiceActionFromProvisioningArtifactError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateTagOptionFromResource
 #[derive(Debug, PartialEq)]
 pub enum DisassociateTagOptionFromResourceError {
@@ -6190,6 +6638,18 @@ impl fmt::Display for DisassociateTagOptionFromResourceError {
     }
 }
 impl Error for DisassociateTagOptionFromResourceError {}
+impl ServiceErrorCode for DisassociateTagOptionFromResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateTagOptionFromResourceError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DisassociateTagOptionFromResourceError::TagOptionNotMigrated(_) => {
+                "TagOptionNotMigratedException"
+            }
+        }
+    }
+}
 /// Errors returned by EnableAWSOrganizationsAccess
 #[derive(Debug, PartialEq)]
 pub enum EnableAWSOrganizationsAccessError {
@@ -6244,6 +6704,17 @@ impl fmt::Display for EnableAWSOrganizationsAccessError {
     }
 }
 impl Error for EnableAWSOrganizationsAccessError {}
+impl ServiceErrorCode for EnableAWSOrganizationsAccessError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableAWSOrganizationsAccessError::InvalidState(_) => "InvalidStateException",
+            EnableAWSOrganizationsAccessError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            EnableAWSOrganizationsAccessError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ExecuteProvisionedProductPlan
 #[derive(Debug, PartialEq)]
 pub enum ExecuteProvisionedProductPlanError {
@@ -6298,6 +6769,17 @@ impl fmt::Display for ExecuteProvisionedProductPlanError {
     }
 }
 impl Error for ExecuteProvisionedProductPlanError {}
+impl ServiceErrorCode for ExecuteProvisionedProductPlanError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExecuteProvisionedProductPlanError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+            ExecuteProvisionedProductPlanError::InvalidState(_) => "InvalidStateException",
+            ExecuteProvisionedProductPlanError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ExecuteProvisionedProductServiceAction
 #[derive(Debug, PartialEq)]
 pub enum ExecuteProvisionedProductServiceActionError {
@@ -6354,6 +6836,19 @@ impl fmt::Display for ExecuteProvisionedProductServiceActionError {
     }
 }
 impl Error for ExecuteProvisionedProductServiceActionError {}
+impl ServiceErrorCode for ExecuteProvisionedProductServiceActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ExecuteProvisionedProductServiceActionError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+            ExecuteProvisionedProductServiceActionError::InvalidState(_) => "InvalidStateException",
+            ExecuteProvisionedProductServiceActionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAWSOrganizationsAccessStatus
 #[derive(Debug, PartialEq)]
 pub enum GetAWSOrganizationsAccessStatusError {
@@ -6400,6 +6895,18 @@ impl fmt::Display for GetAWSOrganizationsAccessStatusError {
     }
 }
 impl Error for GetAWSOrganizationsAccessStatusError {}
+impl ServiceErrorCode for GetAWSOrganizationsAccessStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAWSOrganizationsAccessStatusError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            GetAWSOrganizationsAccessStatusError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by GetProvisionedProductOutputs
 #[derive(Debug, PartialEq)]
 pub enum GetProvisionedProductOutputsError {
@@ -6446,6 +6953,14 @@ impl fmt::Display for GetProvisionedProductOutputsError {
     }
 }
 impl Error for GetProvisionedProductOutputsError {}
+impl ServiceErrorCode for GetProvisionedProductOutputsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetProvisionedProductOutputsError::InvalidParameters(_) => "InvalidParametersException",
+            GetProvisionedProductOutputsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ImportAsProvisionedProduct
 #[derive(Debug, PartialEq)]
 pub enum ImportAsProvisionedProductError {
@@ -6504,6 +7019,16 @@ impl fmt::Display for ImportAsProvisionedProductError {
     }
 }
 impl Error for ImportAsProvisionedProductError {}
+impl ServiceErrorCode for ImportAsProvisionedProductError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportAsProvisionedProductError::DuplicateResource(_) => "DuplicateResourceException",
+            ImportAsProvisionedProductError::InvalidParameters(_) => "InvalidParametersException",
+            ImportAsProvisionedProductError::InvalidState(_) => "InvalidStateException",
+            ImportAsProvisionedProductError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListAcceptedPortfolioShares
 #[derive(Debug, PartialEq)]
 pub enum ListAcceptedPortfolioSharesError {
@@ -6550,6 +7075,16 @@ impl fmt::Display for ListAcceptedPortfolioSharesError {
     }
 }
 impl Error for ListAcceptedPortfolioSharesError {}
+impl ServiceErrorCode for ListAcceptedPortfolioSharesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAcceptedPortfolioSharesError::InvalidParameters(_) => "InvalidParametersException",
+            ListAcceptedPortfolioSharesError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+        }
+    }
+}
 /// Errors returned by ListBudgetsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListBudgetsForResourceError {
@@ -6590,6 +7125,14 @@ impl fmt::Display for ListBudgetsForResourceError {
     }
 }
 impl Error for ListBudgetsForResourceError {}
+impl ServiceErrorCode for ListBudgetsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListBudgetsForResourceError::InvalidParameters(_) => "InvalidParametersException",
+            ListBudgetsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListConstraintsForPortfolio
 #[derive(Debug, PartialEq)]
 pub enum ListConstraintsForPortfolioError {
@@ -6634,6 +7177,14 @@ impl fmt::Display for ListConstraintsForPortfolioError {
     }
 }
 impl Error for ListConstraintsForPortfolioError {}
+impl ServiceErrorCode for ListConstraintsForPortfolioError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListConstraintsForPortfolioError::InvalidParameters(_) => "InvalidParametersException",
+            ListConstraintsForPortfolioError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListLaunchPaths
 #[derive(Debug, PartialEq)]
 pub enum ListLaunchPathsError {
@@ -6670,6 +7221,14 @@ impl fmt::Display for ListLaunchPathsError {
     }
 }
 impl Error for ListLaunchPathsError {}
+impl ServiceErrorCode for ListLaunchPathsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLaunchPathsError::InvalidParameters(_) => "InvalidParametersException",
+            ListLaunchPathsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListOrganizationPortfolioAccess
 #[derive(Debug, PartialEq)]
 pub enum ListOrganizationPortfolioAccessError {
@@ -6726,6 +7285,21 @@ impl fmt::Display for ListOrganizationPortfolioAccessError {
     }
 }
 impl Error for ListOrganizationPortfolioAccessError {}
+impl ServiceErrorCode for ListOrganizationPortfolioAccessError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOrganizationPortfolioAccessError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+            ListOrganizationPortfolioAccessError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            ListOrganizationPortfolioAccessError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by ListPortfolioAccess
 #[derive(Debug, PartialEq)]
 pub enum ListPortfolioAccessError {
@@ -6766,6 +7340,14 @@ impl fmt::Display for ListPortfolioAccessError {
     }
 }
 impl Error for ListPortfolioAccessError {}
+impl ServiceErrorCode for ListPortfolioAccessError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPortfolioAccessError::InvalidParameters(_) => "InvalidParametersException",
+            ListPortfolioAccessError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListPortfolios
 #[derive(Debug, PartialEq)]
 pub enum ListPortfoliosError {
@@ -6796,6 +7378,13 @@ impl fmt::Display for ListPortfoliosError {
     }
 }
 impl Error for ListPortfoliosError {}
+impl ServiceErrorCode for ListPortfoliosError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPortfoliosError::InvalidParameters(_) => "InvalidParametersException",
+        }
+    }
+}
 /// Errors returned by ListPortfoliosForProduct
 #[derive(Debug, PartialEq)]
 pub enum ListPortfoliosForProductError {
@@ -6836,6 +7425,14 @@ impl fmt::Display for ListPortfoliosForProductError {
     }
 }
 impl Error for ListPortfoliosForProductError {}
+impl ServiceErrorCode for ListPortfoliosForProductError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPortfoliosForProductError::InvalidParameters(_) => "InvalidParametersException",
+            ListPortfoliosForProductError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListPrincipalsForPortfolio
 #[derive(Debug, PartialEq)]
 pub enum ListPrincipalsForPortfolioError {
@@ -6878,6 +7475,14 @@ impl fmt::Display for ListPrincipalsForPortfolioError {
     }
 }
 impl Error for ListPrincipalsForPortfolioError {}
+impl ServiceErrorCode for ListPrincipalsForPortfolioError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPrincipalsForPortfolioError::InvalidParameters(_) => "InvalidParametersException",
+            ListPrincipalsForPortfolioError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListProvisionedProductPlans
 #[derive(Debug, PartialEq)]
 pub enum ListProvisionedProductPlansError {
@@ -6922,6 +7527,14 @@ impl fmt::Display for ListProvisionedProductPlansError {
     }
 }
 impl Error for ListProvisionedProductPlansError {}
+impl ServiceErrorCode for ListProvisionedProductPlansError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProvisionedProductPlansError::InvalidParameters(_) => "InvalidParametersException",
+            ListProvisionedProductPlansError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListProvisioningArtifacts
 #[derive(Debug, PartialEq)]
 pub enum ListProvisioningArtifactsError {
@@ -6962,6 +7575,14 @@ impl fmt::Display for ListProvisioningArtifactsError {
     }
 }
 impl Error for ListProvisioningArtifactsError {}
+impl ServiceErrorCode for ListProvisioningArtifactsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProvisioningArtifactsError::InvalidParameters(_) => "InvalidParametersException",
+            ListProvisioningArtifactsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListProvisioningArtifactsForServiceAction
 #[derive(Debug, PartialEq)]
 pub enum ListProvisioningArtifactsForServiceActionError {
@@ -7008,6 +7629,18 @@ impl fmt::Display for ListProvisioningArtifactsForServiceActionError {
     }
 }
 impl Error for ListProvisioningArtifactsForServiceActionError {}
+impl ServiceErrorCode for ListProvisioningArtifactsForServiceActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProvisioningArtifactsForServiceActionError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+            ListProvisioningArtifactsForServiceActionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by ListRecordHistory
 #[derive(Debug, PartialEq)]
 pub enum ListRecordHistoryError {
@@ -7038,6 +7671,13 @@ impl fmt::Display for ListRecordHistoryError {
     }
 }
 impl Error for ListRecordHistoryError {}
+impl ServiceErrorCode for ListRecordHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRecordHistoryError::InvalidParameters(_) => "InvalidParametersException",
+        }
+    }
+}
 /// Errors returned by ListResourcesForTagOption
 #[derive(Debug, PartialEq)]
 pub enum ListResourcesForTagOptionError {
@@ -7088,6 +7728,17 @@ impl fmt::Display for ListResourcesForTagOptionError {
     }
 }
 impl Error for ListResourcesForTagOptionError {}
+impl ServiceErrorCode for ListResourcesForTagOptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourcesForTagOptionError::InvalidParameters(_) => "InvalidParametersException",
+            ListResourcesForTagOptionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListResourcesForTagOptionError::TagOptionNotMigrated(_) => {
+                "TagOptionNotMigratedException"
+            }
+        }
+    }
+}
 /// Errors returned by ListServiceActions
 #[derive(Debug, PartialEq)]
 pub enum ListServiceActionsError {
@@ -7120,6 +7771,13 @@ impl fmt::Display for ListServiceActionsError {
     }
 }
 impl Error for ListServiceActionsError {}
+impl ServiceErrorCode for ListServiceActionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListServiceActionsError::InvalidParameters(_) => "InvalidParametersException",
+        }
+    }
+}
 /// Errors returned by ListServiceActionsForProvisioningArtifact
 #[derive(Debug, PartialEq)]
 pub enum ListServiceActionsForProvisioningArtifactError {
@@ -7166,6 +7824,18 @@ impl fmt::Display for ListServiceActionsForProvisioningArtifactError {
     }
 }
 impl Error for ListServiceActionsForProvisioningArtifactError {}
+impl ServiceErrorCode for ListServiceActionsForProvisioningArtifactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListServiceActionsForProvisioningArtifactError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+            ListServiceActionsForProvisioningArtifactError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by ListStackInstancesForProvisionedProduct
 #[derive(Debug, PartialEq)]
 pub enum ListStackInstancesForProvisionedProductError {
@@ -7212,6 +7882,18 @@ impl fmt::Display for ListStackInstancesForProvisionedProductError {
     }
 }
 impl Error for ListStackInstancesForProvisionedProductError {}
+impl ServiceErrorCode for ListStackInstancesForProvisionedProductError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStackInstancesForProvisionedProductError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+            ListStackInstancesForProvisionedProductError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by ListTagOptions
 #[derive(Debug, PartialEq)]
 pub enum ListTagOptionsError {
@@ -7248,6 +7930,14 @@ impl fmt::Display for ListTagOptionsError {
     }
 }
 impl Error for ListTagOptionsError {}
+impl ServiceErrorCode for ListTagOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagOptionsError::InvalidParameters(_) => "InvalidParametersException",
+            ListTagOptionsError::TagOptionNotMigrated(_) => "TagOptionNotMigratedException",
+        }
+    }
+}
 /// Errors returned by ProvisionProduct
 #[derive(Debug, PartialEq)]
 pub enum ProvisionProductError {
@@ -7290,6 +7980,15 @@ impl fmt::Display for ProvisionProductError {
     }
 }
 impl Error for ProvisionProductError {}
+impl ServiceErrorCode for ProvisionProductError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ProvisionProductError::DuplicateResource(_) => "DuplicateResourceException",
+            ProvisionProductError::InvalidParameters(_) => "InvalidParametersException",
+            ProvisionProductError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RejectPortfolioShare
 #[derive(Debug, PartialEq)]
 pub enum RejectPortfolioShareError {
@@ -7322,6 +8021,13 @@ impl fmt::Display for RejectPortfolioShareError {
     }
 }
 impl Error for RejectPortfolioShareError {}
+impl ServiceErrorCode for RejectPortfolioShareError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RejectPortfolioShareError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ScanProvisionedProducts
 #[derive(Debug, PartialEq)]
 pub enum ScanProvisionedProductsError {
@@ -7354,6 +8060,13 @@ impl fmt::Display for ScanProvisionedProductsError {
     }
 }
 impl Error for ScanProvisionedProductsError {}
+impl ServiceErrorCode for ScanProvisionedProductsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ScanProvisionedProductsError::InvalidParameters(_) => "InvalidParametersException",
+        }
+    }
+}
 /// Errors returned by SearchProducts
 #[derive(Debug, PartialEq)]
 pub enum SearchProductsError {
@@ -7384,6 +8097,13 @@ impl fmt::Display for SearchProductsError {
     }
 }
 impl Error for SearchProductsError {}
+impl ServiceErrorCode for SearchProductsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchProductsError::InvalidParameters(_) => "InvalidParametersException",
+        }
+    }
+}
 /// Errors returned by SearchProductsAsAdmin
 #[derive(Debug, PartialEq)]
 pub enum SearchProductsAsAdminError {
@@ -7424,6 +8144,14 @@ impl fmt::Display for SearchProductsAsAdminError {
     }
 }
 impl Error for SearchProductsAsAdminError {}
+impl ServiceErrorCode for SearchProductsAsAdminError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchProductsAsAdminError::InvalidParameters(_) => "InvalidParametersException",
+            SearchProductsAsAdminError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by SearchProvisionedProducts
 #[derive(Debug, PartialEq)]
 pub enum SearchProvisionedProductsError {
@@ -7456,6 +8184,13 @@ impl fmt::Display for SearchProvisionedProductsError {
     }
 }
 impl Error for SearchProvisionedProductsError {}
+impl ServiceErrorCode for SearchProvisionedProductsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SearchProvisionedProductsError::InvalidParameters(_) => "InvalidParametersException",
+        }
+    }
+}
 /// Errors returned by TerminateProvisionedProduct
 #[derive(Debug, PartialEq)]
 pub enum TerminateProvisionedProductError {
@@ -7490,6 +8225,13 @@ impl fmt::Display for TerminateProvisionedProductError {
     }
 }
 impl Error for TerminateProvisionedProductError {}
+impl ServiceErrorCode for TerminateProvisionedProductError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TerminateProvisionedProductError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateConstraint
 #[derive(Debug, PartialEq)]
 pub enum UpdateConstraintError {
@@ -7526,6 +8268,14 @@ impl fmt::Display for UpdateConstraintError {
     }
 }
 impl Error for UpdateConstraintError {}
+impl ServiceErrorCode for UpdateConstraintError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConstraintError::InvalidParameters(_) => "InvalidParametersException",
+            UpdateConstraintError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdatePortfolio
 #[derive(Debug, PartialEq)]
 pub enum UpdatePortfolioError {
@@ -7576,6 +8326,16 @@ impl fmt::Display for UpdatePortfolioError {
     }
 }
 impl Error for UpdatePortfolioError {}
+impl ServiceErrorCode for UpdatePortfolioError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePortfolioError::InvalidParameters(_) => "InvalidParametersException",
+            UpdatePortfolioError::LimitExceeded(_) => "LimitExceededException",
+            UpdatePortfolioError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdatePortfolioError::TagOptionNotMigrated(_) => "TagOptionNotMigratedException",
+        }
+    }
+}
 /// Errors returned by UpdatePortfolioShare
 #[derive(Debug, PartialEq)]
 pub enum UpdatePortfolioShareError {
@@ -7630,6 +8390,16 @@ impl fmt::Display for UpdatePortfolioShareError {
     }
 }
 impl Error for UpdatePortfolioShareError {}
+impl ServiceErrorCode for UpdatePortfolioShareError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePortfolioShareError::InvalidParameters(_) => "InvalidParametersException",
+            UpdatePortfolioShareError::InvalidState(_) => "InvalidStateException",
+            UpdatePortfolioShareError::OperationNotSupported(_) => "OperationNotSupportedException",
+            UpdatePortfolioShareError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateProduct
 #[derive(Debug, PartialEq)]
 pub enum UpdateProductError {
@@ -7672,6 +8442,15 @@ impl fmt::Display for UpdateProductError {
     }
 }
 impl Error for UpdateProductError {}
+impl ServiceErrorCode for UpdateProductError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateProductError::InvalidParameters(_) => "InvalidParametersException",
+            UpdateProductError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateProductError::TagOptionNotMigrated(_) => "TagOptionNotMigratedException",
+        }
+    }
+}
 /// Errors returned by UpdateProvisionedProduct
 #[derive(Debug, PartialEq)]
 pub enum UpdateProvisionedProductError {
@@ -7712,6 +8491,14 @@ impl fmt::Display for UpdateProvisionedProductError {
     }
 }
 impl Error for UpdateProvisionedProductError {}
+impl ServiceErrorCode for UpdateProvisionedProductError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateProvisionedProductError::InvalidParameters(_) => "InvalidParametersException",
+            UpdateProvisionedProductError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateProvisionedProductProperties
 #[derive(Debug, PartialEq)]
 pub enum UpdateProvisionedProductPropertiesError {
@@ -7768,6 +8555,19 @@ impl fmt::Display for UpdateProvisionedProductPropertiesError {
     }
 }
 impl Error for UpdateProvisionedProductPropertiesError {}
+impl ServiceErrorCode for UpdateProvisionedProductPropertiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateProvisionedProductPropertiesError::InvalidParameters(_) => {
+                "InvalidParametersException"
+            }
+            UpdateProvisionedProductPropertiesError::InvalidState(_) => "InvalidStateException",
+            UpdateProvisionedProductPropertiesError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateProvisioningArtifact
 #[derive(Debug, PartialEq)]
 pub enum UpdateProvisioningArtifactError {
@@ -7810,6 +8610,14 @@ impl fmt::Display for UpdateProvisioningArtifactError {
     }
 }
 impl Error for UpdateProvisioningArtifactError {}
+impl ServiceErrorCode for UpdateProvisioningArtifactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateProvisioningArtifactError::InvalidParameters(_) => "InvalidParametersException",
+            UpdateProvisioningArtifactError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateServiceAction
 #[derive(Debug, PartialEq)]
 pub enum UpdateServiceActionError {
@@ -7850,6 +8658,14 @@ impl fmt::Display for UpdateServiceActionError {
     }
 }
 impl Error for UpdateServiceActionError {}
+impl ServiceErrorCode for UpdateServiceActionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateServiceActionError::InvalidParameters(_) => "InvalidParametersException",
+            UpdateServiceActionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateTagOption
 #[derive(Debug, PartialEq)]
 pub enum UpdateTagOptionError {
@@ -7900,6 +8716,16 @@ impl fmt::Display for UpdateTagOptionError {
     }
 }
 impl Error for UpdateTagOptionError {}
+impl ServiceErrorCode for UpdateTagOptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTagOptionError::DuplicateResource(_) => "DuplicateResourceException",
+            UpdateTagOptionError::InvalidParameters(_) => "InvalidParametersException",
+            UpdateTagOptionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateTagOptionError::TagOptionNotMigrated(_) => "TagOptionNotMigratedException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Service Catalog API. AWS Service Catalog clients implement this trait.
 #[async_trait]
 pub trait ServiceCatalog {
@@ -8728,6 +9554,12 @@ impl ServiceCatalog for ServiceCatalogClient {
         &self,
         input: CopyProductInput,
     ) -> Result<CopyProductOutput, RusotoError<CopyProductError>> {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.CopyProduct");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -8746,6 +9578,12 @@ impl ServiceCatalog for ServiceCatalogClient {
         &self,
         input: CreateConstraintInput,
     ) -> Result<CreateConstraintOutput, RusotoError<CreateConstraintError>> {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -8767,6 +9605,12 @@ impl ServiceCatalog for ServiceCatalogClient {
         &self,
         input: CreatePortfolioInput,
     ) -> Result<CreatePortfolioOutput, RusotoError<CreatePortfolioError>> {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -8809,6 +9653,12 @@ impl ServiceCatalog for ServiceCatalogClient {
         &self,
         input: CreateProductInput,
     ) -> Result<CreateProductOutput, RusotoError<CreateProductError>> {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.CreateProduct");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -8828,6 +9678,12 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreateProvisionedProductPlanInput,
     ) -> Result<CreateProvisionedProductPlanOutput, RusotoError<CreateProvisionedProductPlanError>>
     {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -8851,6 +9707,12 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreateProvisioningArtifactInput,
     ) -> Result<CreateProvisioningArtifactOutput, RusotoError<CreateProvisioningArtifactError>>
     {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -8873,6 +9735,12 @@ impl ServiceCatalog for ServiceCatalogClient {
         &self,
         input: CreateServiceActionInput,
     ) -> Result<CreateServiceActionOutput, RusotoError<CreateServiceActionError>> {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -9621,6 +10489,12 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ExecuteProvisionedProductPlanInput,
     ) -> Result<ExecuteProvisionedProductPlanOutput, RusotoError<ExecuteProvisionedProductPlanError>>
     {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -9646,6 +10520,12 @@ impl ServiceCatalog for ServiceCatalogClient {
         ExecuteProvisionedProductServiceActionOutput,
         RusotoError<ExecuteProvisionedProductServiceActionError>,
     > {
+        let mut input = input;
+        if input.execute_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.execute_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -9718,6 +10598,12 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ImportAsProvisionedProductInput,
     ) -> Result<ImportAsProvisionedProductOutput, RusotoError<ImportAsProvisionedProductError>>
     {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -10149,6 +11035,12 @@ impl ServiceCatalog for ServiceCatalogClient {
         &self,
         input: ProvisionProductInput,
     ) -> Result<ProvisionProductOutput, RusotoError<ProvisionProductError>> {
+        let mut input = input;
+        if input.provision_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.provision_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -10275,6 +11167,12 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: TerminateProvisionedProductInput,
     ) -> Result<TerminateProvisionedProductOutput, RusotoError<TerminateProvisionedProductError>>
     {
+        let mut input = input;
+        if input.terminate_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.terminate_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -10378,6 +11276,12 @@ impl ServiceCatalog for ServiceCatalogClient {
         &self,
         input: UpdateProvisionedProductInput,
     ) -> Result<UpdateProvisionedProductOutput, RusotoError<UpdateProvisionedProductError>> {
+        let mut input = input;
+        if input.update_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.update_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -10403,6 +11307,12 @@ impl ServiceCatalog for ServiceCatalogClient {
         UpdateProvisionedProductPropertiesOutput,
         RusotoError<UpdateProvisionedProductPropertiesError>,
     > {
+        let mut input = input;
+        if input.idempotency_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.idempotency_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
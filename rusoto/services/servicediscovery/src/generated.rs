@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -55,6 +55,7 @@ use serde_json;
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateHttpNamespaceRequest {
     /// <p>A unique string that identifies the request and that allows failed <code>CreateHttpNamespace</code> requests to be retried without the risk of executing the operation twice. <code>CreatorRequestId</code> can be any unique string, for example, a date/time stamp.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub creator_request_id: Option<String>,
@@ -84,6 +85,7 @@ pub struct CreateHttpNamespaceResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreatePrivateDnsNamespaceRequest {
     /// <p>A unique string that identifies the request and that allows failed <code>CreatePrivateDnsNamespace</code> requests to be retried without the risk of executing the operation twice. <code>CreatorRequestId</code> can be any unique string, for example, a date/time stamp.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub creator_request_id: Option<String>,
@@ -116,6 +118,7 @@ pub struct CreatePrivateDnsNamespaceResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreatePublicDnsNamespaceRequest {
     /// <p>A unique string that identifies the request and that allows failed <code>CreatePublicDnsNamespace</code> requests to be retried without the risk of executing the operation twice. <code>CreatorRequestId</code> can be any unique string, for example, a date/time stamp.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub creator_request_id: Option<String>,
@@ -145,6 +148,7 @@ pub struct CreatePublicDnsNamespaceResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateServiceRequest {
     /// <p>A unique string that identifies the request and that allows failed <code>CreateService</code> requests to be retried without the risk of executing the operation twice. <code>CreatorRequestId</code> can be any unique string, for example, a date/time stamp.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub creator_request_id: Option<String>,
@@ -829,6 +833,7 @@ pub struct RegisterInstanceRequest {
     #[serde(rename = "Attributes")]
     pub attributes: ::std::collections::HashMap<String, String>,
     /// <p>A unique string that identifies the request and that allows failed <code>RegisterInstance</code> requests to be retried without the risk of executing the operation twice. You must use a unique <code>CreatorRequestId</code> string every time you submit a <code>RegisterInstance</code> request if you're registering additional instances for the same namespace and service. <code>CreatorRequestId</code> can be any unique string, for example, a date/time stamp.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "CreatorRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub creator_request_id: Option<String>,
@@ -1106,6 +1111,17 @@ impl fmt::Display for CreateHttpNamespaceError {
     }
 }
 impl Error for CreateHttpNamespaceError {}
+impl ServiceErrorCode for CreateHttpNamespaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateHttpNamespaceError::DuplicateRequest(_) => "DuplicateRequest",
+            CreateHttpNamespaceError::InvalidInput(_) => "InvalidInput",
+            CreateHttpNamespaceError::NamespaceAlreadyExists(_) => "NamespaceAlreadyExists",
+            CreateHttpNamespaceError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            CreateHttpNamespaceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by CreatePrivateDnsNamespace
 #[derive(Debug, PartialEq)]
 pub enum CreatePrivateDnsNamespaceError {
@@ -1174,6 +1190,17 @@ impl fmt::Display for CreatePrivateDnsNamespaceError {
     }
 }
 impl Error for CreatePrivateDnsNamespaceError {}
+impl ServiceErrorCode for CreatePrivateDnsNamespaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePrivateDnsNamespaceError::DuplicateRequest(_) => "DuplicateRequest",
+            CreatePrivateDnsNamespaceError::InvalidInput(_) => "InvalidInput",
+            CreatePrivateDnsNamespaceError::NamespaceAlreadyExists(_) => "NamespaceAlreadyExists",
+            CreatePrivateDnsNamespaceError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            CreatePrivateDnsNamespaceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by CreatePublicDnsNamespace
 #[derive(Debug, PartialEq)]
 pub enum CreatePublicDnsNamespaceError {
@@ -1242,6 +1269,17 @@ impl fmt::Display for CreatePublicDnsNamespaceError {
     }
 }
 impl Error for CreatePublicDnsNamespaceError {}
+impl ServiceErrorCode for CreatePublicDnsNamespaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePublicDnsNamespaceError::DuplicateRequest(_) => "DuplicateRequest",
+            CreatePublicDnsNamespaceError::InvalidInput(_) => "InvalidInput",
+            CreatePublicDnsNamespaceError::NamespaceAlreadyExists(_) => "NamespaceAlreadyExists",
+            CreatePublicDnsNamespaceError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            CreatePublicDnsNamespaceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by CreateService
 #[derive(Debug, PartialEq)]
 pub enum CreateServiceError {
@@ -1296,6 +1334,17 @@ impl fmt::Display for CreateServiceError {
     }
 }
 impl Error for CreateServiceError {}
+impl ServiceErrorCode for CreateServiceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateServiceError::InvalidInput(_) => "InvalidInput",
+            CreateServiceError::NamespaceNotFound(_) => "NamespaceNotFound",
+            CreateServiceError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            CreateServiceError::ServiceAlreadyExists(_) => "ServiceAlreadyExists",
+            CreateServiceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by DeleteNamespace
 #[derive(Debug, PartialEq)]
 pub enum DeleteNamespaceError {
@@ -1344,6 +1393,16 @@ impl fmt::Display for DeleteNamespaceError {
     }
 }
 impl Error for DeleteNamespaceError {}
+impl ServiceErrorCode for DeleteNamespaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteNamespaceError::DuplicateRequest(_) => "DuplicateRequest",
+            DeleteNamespaceError::InvalidInput(_) => "InvalidInput",
+            DeleteNamespaceError::NamespaceNotFound(_) => "NamespaceNotFound",
+            DeleteNamespaceError::ResourceInUse(_) => "ResourceInUse",
+        }
+    }
+}
 /// Errors returned by DeleteService
 #[derive(Debug, PartialEq)]
 pub enum DeleteServiceError {
@@ -1386,6 +1445,15 @@ impl fmt::Display for DeleteServiceError {
     }
 }
 impl Error for DeleteServiceError {}
+impl ServiceErrorCode for DeleteServiceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteServiceError::InvalidInput(_) => "InvalidInput",
+            DeleteServiceError::ResourceInUse(_) => "ResourceInUse",
+            DeleteServiceError::ServiceNotFound(_) => "ServiceNotFound",
+        }
+    }
+}
 /// Errors returned by DeregisterInstance
 #[derive(Debug, PartialEq)]
 pub enum DeregisterInstanceError {
@@ -1440,6 +1508,17 @@ impl fmt::Display for DeregisterInstanceError {
     }
 }
 impl Error for DeregisterInstanceError {}
+impl ServiceErrorCode for DeregisterInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterInstanceError::DuplicateRequest(_) => "DuplicateRequest",
+            DeregisterInstanceError::InstanceNotFound(_) => "InstanceNotFound",
+            DeregisterInstanceError::InvalidInput(_) => "InvalidInput",
+            DeregisterInstanceError::ResourceInUse(_) => "ResourceInUse",
+            DeregisterInstanceError::ServiceNotFound(_) => "ServiceNotFound",
+        }
+    }
+}
 /// Errors returned by DiscoverInstances
 #[derive(Debug, PartialEq)]
 pub enum DiscoverInstancesError {
@@ -1490,6 +1569,16 @@ impl fmt::Display for DiscoverInstancesError {
     }
 }
 impl Error for DiscoverInstancesError {}
+impl ServiceErrorCode for DiscoverInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DiscoverInstancesError::InvalidInput(_) => "InvalidInput",
+            DiscoverInstancesError::NamespaceNotFound(_) => "NamespaceNotFound",
+            DiscoverInstancesError::RequestLimitExceeded(_) => "RequestLimitExceeded",
+            DiscoverInstancesError::ServiceNotFound(_) => "ServiceNotFound",
+        }
+    }
+}
 /// Errors returned by GetInstance
 #[derive(Debug, PartialEq)]
 pub enum GetInstanceError {
@@ -1532,6 +1621,15 @@ impl fmt::Display for GetInstanceError {
     }
 }
 impl Error for GetInstanceError {}
+impl ServiceErrorCode for GetInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInstanceError::InstanceNotFound(_) => "InstanceNotFound",
+            GetInstanceError::InvalidInput(_) => "InvalidInput",
+            GetInstanceError::ServiceNotFound(_) => "ServiceNotFound",
+        }
+    }
+}
 /// Errors returned by GetInstancesHealthStatus
 #[derive(Debug, PartialEq)]
 pub enum GetInstancesHealthStatusError {
@@ -1580,6 +1678,15 @@ impl fmt::Display for GetInstancesHealthStatusError {
     }
 }
 impl Error for GetInstancesHealthStatusError {}
+impl ServiceErrorCode for GetInstancesHealthStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInstancesHealthStatusError::InstanceNotFound(_) => "InstanceNotFound",
+            GetInstancesHealthStatusError::InvalidInput(_) => "InvalidInput",
+            GetInstancesHealthStatusError::ServiceNotFound(_) => "ServiceNotFound",
+        }
+    }
+}
 /// Errors returned by GetNamespace
 #[derive(Debug, PartialEq)]
 pub enum GetNamespaceError {
@@ -1616,6 +1723,14 @@ impl fmt::Display for GetNamespaceError {
     }
 }
 impl Error for GetNamespaceError {}
+impl ServiceErrorCode for GetNamespaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetNamespaceError::InvalidInput(_) => "InvalidInput",
+            GetNamespaceError::NamespaceNotFound(_) => "NamespaceNotFound",
+        }
+    }
+}
 /// Errors returned by GetOperation
 #[derive(Debug, PartialEq)]
 pub enum GetOperationError {
@@ -1652,6 +1767,14 @@ impl fmt::Display for GetOperationError {
     }
 }
 impl Error for GetOperationError {}
+impl ServiceErrorCode for GetOperationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOperationError::InvalidInput(_) => "InvalidInput",
+            GetOperationError::OperationNotFound(_) => "OperationNotFound",
+        }
+    }
+}
 /// Errors returned by GetService
 #[derive(Debug, PartialEq)]
 pub enum GetServiceError {
@@ -1688,6 +1811,14 @@ impl fmt::Display for GetServiceError {
     }
 }
 impl Error for GetServiceError {}
+impl ServiceErrorCode for GetServiceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetServiceError::InvalidInput(_) => "InvalidInput",
+            GetServiceError::ServiceNotFound(_) => "ServiceNotFound",
+        }
+    }
+}
 /// Errors returned by ListInstances
 #[derive(Debug, PartialEq)]
 pub enum ListInstancesError {
@@ -1724,6 +1855,14 @@ impl fmt::Display for ListInstancesError {
     }
 }
 impl Error for ListInstancesError {}
+impl ServiceErrorCode for ListInstancesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInstancesError::InvalidInput(_) => "InvalidInput",
+            ListInstancesError::ServiceNotFound(_) => "ServiceNotFound",
+        }
+    }
+}
 /// Errors returned by ListNamespaces
 #[derive(Debug, PartialEq)]
 pub enum ListNamespacesError {
@@ -1754,6 +1893,13 @@ impl fmt::Display for ListNamespacesError {
     }
 }
 impl Error for ListNamespacesError {}
+impl ServiceErrorCode for ListNamespacesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListNamespacesError::InvalidInput(_) => "InvalidInput",
+        }
+    }
+}
 /// Errors returned by ListOperations
 #[derive(Debug, PartialEq)]
 pub enum ListOperationsError {
@@ -1784,6 +1930,13 @@ impl fmt::Display for ListOperationsError {
     }
 }
 impl Error for ListOperationsError {}
+impl ServiceErrorCode for ListOperationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOperationsError::InvalidInput(_) => "InvalidInput",
+        }
+    }
+}
 /// Errors returned by ListServices
 #[derive(Debug, PartialEq)]
 pub enum ListServicesError {
@@ -1814,6 +1967,13 @@ impl fmt::Display for ListServicesError {
     }
 }
 impl Error for ListServicesError {}
+impl ServiceErrorCode for ListServicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListServicesError::InvalidInput(_) => "InvalidInput",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1852,6 +2012,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InvalidInput(_) => "InvalidInput",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RegisterInstance
 #[derive(Debug, PartialEq)]
 pub enum RegisterInstanceError {
@@ -1908,6 +2076,17 @@ impl fmt::Display for RegisterInstanceError {
     }
 }
 impl Error for RegisterInstanceError {}
+impl ServiceErrorCode for RegisterInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterInstanceError::DuplicateRequest(_) => "DuplicateRequest",
+            RegisterInstanceError::InvalidInput(_) => "InvalidInput",
+            RegisterInstanceError::ResourceInUse(_) => "ResourceInUse",
+            RegisterInstanceError::ResourceLimitExceeded(_) => "ResourceLimitExceeded",
+            RegisterInstanceError::ServiceNotFound(_) => "ServiceNotFound",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1950,6 +2129,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InvalidInput(_) => "InvalidInput",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1986,6 +2174,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InvalidInput(_) => "InvalidInput",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateInstanceCustomHealthStatus
 #[derive(Debug, PartialEq)]
 pub enum UpdateInstanceCustomHealthStatusError {
@@ -2052,6 +2248,18 @@ impl fmt::Display for UpdateInstanceCustomHealthStatusError {
     }
 }
 impl Error for UpdateInstanceCustomHealthStatusError {}
+impl ServiceErrorCode for UpdateInstanceCustomHealthStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateInstanceCustomHealthStatusError::CustomHealthNotFound(_) => {
+                "CustomHealthNotFound"
+            }
+            UpdateInstanceCustomHealthStatusError::InstanceNotFound(_) => "InstanceNotFound",
+            UpdateInstanceCustomHealthStatusError::InvalidInput(_) => "InvalidInput",
+            UpdateInstanceCustomHealthStatusError::ServiceNotFound(_) => "ServiceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateService
 #[derive(Debug, PartialEq)]
 pub enum UpdateServiceError {
@@ -2094,6 +2302,15 @@ impl fmt::Display for UpdateServiceError {
     }
 }
 impl Error for UpdateServiceError {}
+impl ServiceErrorCode for UpdateServiceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateServiceError::DuplicateRequest(_) => "DuplicateRequest",
+            UpdateServiceError::InvalidInput(_) => "InvalidInput",
+            UpdateServiceError::ServiceNotFound(_) => "ServiceNotFound",
+        }
+    }
+}
 /// Trait representing the capabilities of the ServiceDiscovery API. ServiceDiscovery clients implement this trait.
 #[async_trait]
 pub trait ServiceDiscovery {
@@ -2280,6 +2497,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: CreateHttpNamespaceRequest,
     ) -> Result<CreateHttpNamespaceResponse, RusotoError<CreateHttpNamespaceError>> {
+        let mut input = input;
+        if input.creator_request_id.is_none() {
+            input.creator_request_id = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -2302,6 +2523,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: CreatePrivateDnsNamespaceRequest,
     ) -> Result<CreatePrivateDnsNamespaceResponse, RusotoError<CreatePrivateDnsNamespaceError>>
     {
+        let mut input = input;
+        if input.creator_request_id.is_none() {
+            input.creator_request_id = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -2324,6 +2549,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: CreatePublicDnsNamespaceRequest,
     ) -> Result<CreatePublicDnsNamespaceResponse, RusotoError<CreatePublicDnsNamespaceError>> {
+        let mut input = input;
+        if input.creator_request_id.is_none() {
+            input.creator_request_id = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -2346,6 +2575,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: CreateServiceRequest,
     ) -> Result<CreateServiceResponse, RusotoError<CreateServiceError>> {
+        let mut input = input;
+        if input.creator_request_id.is_none() {
+            input.creator_request_id = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.CreateService");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -2632,6 +2865,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: RegisterInstanceRequest,
     ) -> Result<RegisterInstanceResponse, RusotoError<RegisterInstanceError>> {
+        let mut input = input;
+        if input.creator_request_id.is_none() {
+            input.creator_request_id = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
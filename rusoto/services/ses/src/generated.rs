@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -7141,6 +7141,15 @@ impl fmt::Display for CloneReceiptRuleSetError {
     }
 }
 impl Error for CloneReceiptRuleSetError {}
+impl ServiceErrorCode for CloneReceiptRuleSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CloneReceiptRuleSetError::AlreadyExists(_) => "AlreadyExists",
+            CloneReceiptRuleSetError::LimitExceeded(_) => "LimitExceeded",
+            CloneReceiptRuleSetError::RuleSetDoesNotExist(_) => "RuleSetDoesNotExist",
+        }
+    }
+}
 /// Errors returned by CreateConfigurationSet
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationSetError {
@@ -7209,6 +7218,17 @@ impl fmt::Display for CreateConfigurationSetError {
     }
 }
 impl Error for CreateConfigurationSetError {}
+impl ServiceErrorCode for CreateConfigurationSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConfigurationSetError::ConfigurationSetAlreadyExists(_) => {
+                "ConfigurationSetAlreadyExists"
+            }
+            CreateConfigurationSetError::InvalidConfigurationSet(_) => "InvalidConfigurationSet",
+            CreateConfigurationSetError::LimitExceeded(_) => "LimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationSetEventDestinationError {
@@ -7313,6 +7333,28 @@ impl fmt::Display for CreateConfigurationSetEventDestinationError {
     }
 }
 impl Error for CreateConfigurationSetEventDestinationError {}
+impl ServiceErrorCode for CreateConfigurationSetEventDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(_) => {
+                "ConfigurationSetDoesNotExist"
+            }
+            CreateConfigurationSetEventDestinationError::EventDestinationAlreadyExists(_) => {
+                "EventDestinationAlreadyExists"
+            }
+            CreateConfigurationSetEventDestinationError::InvalidCloudWatchDestination(_) => {
+                "InvalidCloudWatchDestination"
+            }
+            CreateConfigurationSetEventDestinationError::InvalidFirehoseDestination(_) => {
+                "InvalidFirehoseDestination"
+            }
+            CreateConfigurationSetEventDestinationError::InvalidSNSDestination(_) => {
+                "InvalidSNSDestination"
+            }
+            CreateConfigurationSetEventDestinationError::LimitExceeded(_) => "LimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateConfigurationSetTrackingOptions
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationSetTrackingOptionsError {
@@ -7383,6 +7425,21 @@ impl fmt::Display for CreateConfigurationSetTrackingOptionsError {
     }
 }
 impl Error for CreateConfigurationSetTrackingOptionsError {}
+impl ServiceErrorCode for CreateConfigurationSetTrackingOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(_) => {
+                "ConfigurationSetDoesNotExist"
+            }
+            CreateConfigurationSetTrackingOptionsError::InvalidTrackingOptions(_) => {
+                "InvalidTrackingOptions"
+            }
+            CreateConfigurationSetTrackingOptionsError::TrackingOptionsAlreadyExists(_) => {
+                "TrackingOptionsAlreadyExistsException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateCustomVerificationEmailTemplate
 #[derive(Debug, PartialEq)]
 pub enum CreateCustomVerificationEmailTemplateError {
@@ -7433,6 +7490,16 @@ CreateCustomVerificationEmailTemplateError::LimitExceeded(ref cause) => write!(f
     }
 }
 impl Error for CreateCustomVerificationEmailTemplateError {}
+impl ServiceErrorCode for CreateCustomVerificationEmailTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(_) => "CustomVerificationEmailInvalidContent",
+            CreateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateAlreadyExists(_) => "CustomVerificationEmailTemplateAlreadyExists",
+            CreateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(_) => "FromEmailAddressNotVerified",
+            CreateCustomVerificationEmailTemplateError::LimitExceeded(_) => "LimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateReceiptFilter
 #[derive(Debug, PartialEq)]
 pub enum CreateReceiptFilterError {
@@ -7485,6 +7552,14 @@ impl fmt::Display for CreateReceiptFilterError {
     }
 }
 impl Error for CreateReceiptFilterError {}
+impl ServiceErrorCode for CreateReceiptFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateReceiptFilterError::AlreadyExists(_) => "AlreadyExists",
+            CreateReceiptFilterError::LimitExceeded(_) => "LimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateReceiptRule
 #[derive(Debug, PartialEq)]
 pub enum CreateReceiptRuleError {
@@ -7577,6 +7652,19 @@ impl fmt::Display for CreateReceiptRuleError {
     }
 }
 impl Error for CreateReceiptRuleError {}
+impl ServiceErrorCode for CreateReceiptRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateReceiptRuleError::AlreadyExists(_) => "AlreadyExists",
+            CreateReceiptRuleError::InvalidLambdaFunction(_) => "InvalidLambdaFunction",
+            CreateReceiptRuleError::InvalidS3Configuration(_) => "InvalidS3Configuration",
+            CreateReceiptRuleError::InvalidSnsTopic(_) => "InvalidSnsTopic",
+            CreateReceiptRuleError::LimitExceeded(_) => "LimitExceeded",
+            CreateReceiptRuleError::RuleDoesNotExist(_) => "RuleDoesNotExist",
+            CreateReceiptRuleError::RuleSetDoesNotExist(_) => "RuleSetDoesNotExist",
+        }
+    }
+}
 /// Errors returned by CreateReceiptRuleSet
 #[derive(Debug, PartialEq)]
 pub enum CreateReceiptRuleSetError {
@@ -7629,6 +7717,14 @@ impl fmt::Display for CreateReceiptRuleSetError {
     }
 }
 impl Error for CreateReceiptRuleSetError {}
+impl ServiceErrorCode for CreateReceiptRuleSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateReceiptRuleSetError::AlreadyExists(_) => "AlreadyExists",
+            CreateReceiptRuleSetError::LimitExceeded(_) => "LimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreateTemplate
 #[derive(Debug, PartialEq)]
 pub enum CreateTemplateError {
@@ -7689,6 +7785,15 @@ impl fmt::Display for CreateTemplateError {
     }
 }
 impl Error for CreateTemplateError {}
+impl ServiceErrorCode for CreateTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTemplateError::AlreadyExists(_) => "AlreadyExists",
+            CreateTemplateError::InvalidTemplate(_) => "InvalidTemplate",
+            CreateTemplateError::LimitExceeded(_) => "LimitExceeded",
+        }
+    }
+}
 /// Errors returned by DeleteConfigurationSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationSetError {
@@ -7737,6 +7842,15 @@ impl fmt::Display for DeleteConfigurationSetError {
     }
 }
 impl Error for DeleteConfigurationSetError {}
+impl ServiceErrorCode for DeleteConfigurationSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigurationSetError::ConfigurationSetDoesNotExist(_) => {
+                "ConfigurationSetDoesNotExist"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationSetEventDestinationError {
@@ -7795,6 +7909,18 @@ impl fmt::Display for DeleteConfigurationSetEventDestinationError {
     }
 }
 impl Error for DeleteConfigurationSetEventDestinationError {}
+impl ServiceErrorCode for DeleteConfigurationSetEventDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(_) => {
+                "ConfigurationSetDoesNotExist"
+            }
+            DeleteConfigurationSetEventDestinationError::EventDestinationDoesNotExist(_) => {
+                "EventDestinationDoesNotExist"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteConfigurationSetTrackingOptions
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationSetTrackingOptionsError {
@@ -7855,6 +7981,18 @@ impl fmt::Display for DeleteConfigurationSetTrackingOptionsError {
     }
 }
 impl Error for DeleteConfigurationSetTrackingOptionsError {}
+impl ServiceErrorCode for DeleteConfigurationSetTrackingOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(_) => {
+                "ConfigurationSetDoesNotExist"
+            }
+            DeleteConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist(_) => {
+                "TrackingOptionsDoesNotExistException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteCustomVerificationEmailTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeleteCustomVerificationEmailTemplateError {}
@@ -7891,6 +8029,11 @@ impl fmt::Display for DeleteCustomVerificationEmailTemplateError {
     }
 }
 impl Error for DeleteCustomVerificationEmailTemplateError {}
+impl ServiceErrorCode for DeleteCustomVerificationEmailTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteIdentity
 #[derive(Debug, PartialEq)]
 pub enum DeleteIdentityError {}
@@ -7925,6 +8068,11 @@ impl fmt::Display for DeleteIdentityError {
     }
 }
 impl Error for DeleteIdentityError {}
+impl ServiceErrorCode for DeleteIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteIdentityPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteIdentityPolicyError {}
@@ -7959,6 +8107,11 @@ impl fmt::Display for DeleteIdentityPolicyError {
     }
 }
 impl Error for DeleteIdentityPolicyError {}
+impl ServiceErrorCode for DeleteIdentityPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteReceiptFilter
 #[derive(Debug, PartialEq)]
 pub enum DeleteReceiptFilterError {}
@@ -7993,6 +8146,11 @@ impl fmt::Display for DeleteReceiptFilterError {
     }
 }
 impl Error for DeleteReceiptFilterError {}
+impl ServiceErrorCode for DeleteReceiptFilterError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteReceiptRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteReceiptRuleError {
@@ -8037,6 +8195,13 @@ impl fmt::Display for DeleteReceiptRuleError {
     }
 }
 impl Error for DeleteReceiptRuleError {}
+impl ServiceErrorCode for DeleteReceiptRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteReceiptRuleError::RuleSetDoesNotExist(_) => "RuleSetDoesNotExist",
+        }
+    }
+}
 /// Errors returned by DeleteReceiptRuleSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteReceiptRuleSetError {
@@ -8081,6 +8246,13 @@ impl fmt::Display for DeleteReceiptRuleSetError {
     }
 }
 impl Error for DeleteReceiptRuleSetError {}
+impl ServiceErrorCode for DeleteReceiptRuleSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteReceiptRuleSetError::CannotDelete(_) => "CannotDelete",
+        }
+    }
+}
 /// Errors returned by DeleteTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeleteTemplateError {}
@@ -8115,6 +8287,11 @@ impl fmt::Display for DeleteTemplateError {
     }
 }
 impl Error for DeleteTemplateError {}
+impl ServiceErrorCode for DeleteTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DeleteVerifiedEmailAddress
 #[derive(Debug, PartialEq)]
 pub enum DeleteVerifiedEmailAddressError {}
@@ -8151,6 +8328,11 @@ impl fmt::Display for DeleteVerifiedEmailAddressError {
     }
 }
 impl Error for DeleteVerifiedEmailAddressError {}
+impl ServiceErrorCode for DeleteVerifiedEmailAddressError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeActiveReceiptRuleSet
 #[derive(Debug, PartialEq)]
 pub enum DescribeActiveReceiptRuleSetError {}
@@ -8187,6 +8369,11 @@ impl fmt::Display for DescribeActiveReceiptRuleSetError {
     }
 }
 impl Error for DescribeActiveReceiptRuleSetError {}
+impl ServiceErrorCode for DescribeActiveReceiptRuleSetError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by DescribeConfigurationSet
 #[derive(Debug, PartialEq)]
 pub enum DescribeConfigurationSetError {
@@ -8235,6 +8422,15 @@ impl fmt::Display for DescribeConfigurationSetError {
     }
 }
 impl Error for DescribeConfigurationSetError {}
+impl ServiceErrorCode for DescribeConfigurationSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConfigurationSetError::ConfigurationSetDoesNotExist(_) => {
+                "ConfigurationSetDoesNotExist"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeReceiptRule
 #[derive(Debug, PartialEq)]
 pub enum DescribeReceiptRuleError {
@@ -8287,6 +8483,14 @@ impl fmt::Display for DescribeReceiptRuleError {
     }
 }
 impl Error for DescribeReceiptRuleError {}
+impl ServiceErrorCode for DescribeReceiptRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReceiptRuleError::RuleDoesNotExist(_) => "RuleDoesNotExist",
+            DescribeReceiptRuleError::RuleSetDoesNotExist(_) => "RuleSetDoesNotExist",
+        }
+    }
+}
 /// Errors returned by DescribeReceiptRuleSet
 #[derive(Debug, PartialEq)]
 pub enum DescribeReceiptRuleSetError {
@@ -8331,6 +8535,13 @@ impl fmt::Display for DescribeReceiptRuleSetError {
     }
 }
 impl Error for DescribeReceiptRuleSetError {}
+impl ServiceErrorCode for DescribeReceiptRuleSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReceiptRuleSetError::RuleSetDoesNotExist(_) => "RuleSetDoesNotExist",
+        }
+    }
+}
 /// Errors returned by GetAccountSendingEnabled
 #[derive(Debug, PartialEq)]
 pub enum GetAccountSendingEnabledError {}
@@ -8365,6 +8576,11 @@ impl fmt::Display for GetAccountSendingEnabledError {
     }
 }
 impl Error for GetAccountSendingEnabledError {}
+impl ServiceErrorCode for GetAccountSendingEnabledError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetCustomVerificationEmailTemplate
 #[derive(Debug, PartialEq)]
 pub enum GetCustomVerificationEmailTemplateError {
@@ -8406,6 +8622,13 @@ impl fmt::Display for GetCustomVerificationEmailTemplateError {
     }
 }
 impl Error for GetCustomVerificationEmailTemplateError {}
+impl ServiceErrorCode for GetCustomVerificationEmailTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(_) => "CustomVerificationEmailTemplateDoesNotExist",
+        }
+    }
+}
 /// Errors returned by GetIdentityDkimAttributes
 #[derive(Debug, PartialEq)]
 pub enum GetIdentityDkimAttributesError {}
@@ -8440,6 +8663,11 @@ impl fmt::Display for GetIdentityDkimAttributesError {
     }
 }
 impl Error for GetIdentityDkimAttributesError {}
+impl ServiceErrorCode for GetIdentityDkimAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetIdentityMailFromDomainAttributes
 #[derive(Debug, PartialEq)]
 pub enum GetIdentityMailFromDomainAttributesError {}
@@ -8476,6 +8704,11 @@ impl fmt::Display for GetIdentityMailFromDomainAttributesError {
     }
 }
 impl Error for GetIdentityMailFromDomainAttributesError {}
+impl ServiceErrorCode for GetIdentityMailFromDomainAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetIdentityNotificationAttributes
 #[derive(Debug, PartialEq)]
 pub enum GetIdentityNotificationAttributesError {}
@@ -8512,6 +8745,11 @@ impl fmt::Display for GetIdentityNotificationAttributesError {
     }
 }
 impl Error for GetIdentityNotificationAttributesError {}
+impl ServiceErrorCode for GetIdentityNotificationAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetIdentityPolicies
 #[derive(Debug, PartialEq)]
 pub enum GetIdentityPoliciesError {}
@@ -8546,6 +8784,11 @@ impl fmt::Display for GetIdentityPoliciesError {
     }
 }
 impl Error for GetIdentityPoliciesError {}
+impl ServiceErrorCode for GetIdentityPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetIdentityVerificationAttributes
 #[derive(Debug, PartialEq)]
 pub enum GetIdentityVerificationAttributesError {}
@@ -8582,6 +8825,11 @@ impl fmt::Display for GetIdentityVerificationAttributesError {
     }
 }
 impl Error for GetIdentityVerificationAttributesError {}
+impl ServiceErrorCode for GetIdentityVerificationAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetSendQuota
 #[derive(Debug, PartialEq)]
 pub enum GetSendQuotaError {}
@@ -8616,6 +8864,11 @@ impl fmt::Display for GetSendQuotaError {
     }
 }
 impl Error for GetSendQuotaError {}
+impl ServiceErrorCode for GetSendQuotaError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetSendStatistics
 #[derive(Debug, PartialEq)]
 pub enum GetSendStatisticsError {}
@@ -8650,6 +8903,11 @@ impl fmt::Display for GetSendStatisticsError {
     }
 }
 impl Error for GetSendStatisticsError {}
+impl ServiceErrorCode for GetSendStatisticsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetTemplate
 #[derive(Debug, PartialEq)]
 pub enum GetTemplateError {
@@ -8694,6 +8952,13 @@ impl fmt::Display for GetTemplateError {
     }
 }
 impl Error for GetTemplateError {}
+impl ServiceErrorCode for GetTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTemplateError::TemplateDoesNotExist(_) => "TemplateDoesNotExist",
+        }
+    }
+}
 /// Errors returned by ListConfigurationSets
 #[derive(Debug, PartialEq)]
 pub enum ListConfigurationSetsError {}
@@ -8728,6 +8993,11 @@ impl fmt::Display for ListConfigurationSetsError {
     }
 }
 impl Error for ListConfigurationSetsError {}
+impl ServiceErrorCode for ListConfigurationSetsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListCustomVerificationEmailTemplates
 #[derive(Debug, PartialEq)]
 pub enum ListCustomVerificationEmailTemplatesError {}
@@ -8764,6 +9034,11 @@ impl fmt::Display for ListCustomVerificationEmailTemplatesError {
     }
 }
 impl Error for ListCustomVerificationEmailTemplatesError {}
+impl ServiceErrorCode for ListCustomVerificationEmailTemplatesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListIdentities
 #[derive(Debug, PartialEq)]
 pub enum ListIdentitiesError {}
@@ -8798,6 +9073,11 @@ impl fmt::Display for ListIdentitiesError {
     }
 }
 impl Error for ListIdentitiesError {}
+impl ServiceErrorCode for ListIdentitiesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListIdentityPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListIdentityPoliciesError {}
@@ -8832,6 +9112,11 @@ impl fmt::Display for ListIdentityPoliciesError {
     }
 }
 impl Error for ListIdentityPoliciesError {}
+impl ServiceErrorCode for ListIdentityPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListReceiptFilters
 #[derive(Debug, PartialEq)]
 pub enum ListReceiptFiltersError {}
@@ -8866,6 +9151,11 @@ impl fmt::Display for ListReceiptFiltersError {
     }
 }
 impl Error for ListReceiptFiltersError {}
+impl ServiceErrorCode for ListReceiptFiltersError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListReceiptRuleSets
 #[derive(Debug, PartialEq)]
 pub enum ListReceiptRuleSetsError {}
@@ -8900,6 +9190,11 @@ impl fmt::Display for ListReceiptRuleSetsError {
     }
 }
 impl Error for ListReceiptRuleSetsError {}
+impl ServiceErrorCode for ListReceiptRuleSetsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListTemplates
 #[derive(Debug, PartialEq)]
 pub enum ListTemplatesError {}
@@ -8934,6 +9229,11 @@ impl fmt::Display for ListTemplatesError {
     }
 }
 impl Error for ListTemplatesError {}
+impl ServiceErrorCode for ListTemplatesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListVerifiedEmailAddresses
 #[derive(Debug, PartialEq)]
 pub enum ListVerifiedEmailAddressesError {}
@@ -8970,6 +9270,11 @@ impl fmt::Display for ListVerifiedEmailAddressesError {
     }
 }
 impl Error for ListVerifiedEmailAddressesError {}
+impl ServiceErrorCode for ListVerifiedEmailAddressesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PutConfigurationSetDeliveryOptions
 #[derive(Debug, PartialEq)]
 pub enum PutConfigurationSetDeliveryOptionsError {
@@ -9032,6 +9337,18 @@ impl fmt::Display for PutConfigurationSetDeliveryOptionsError {
     }
 }
 impl Error for PutConfigurationSetDeliveryOptionsError {}
+impl ServiceErrorCode for PutConfigurationSetDeliveryOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutConfigurationSetDeliveryOptionsError::ConfigurationSetDoesNotExist(_) => {
+                "ConfigurationSetDoesNotExist"
+            }
+            PutConfigurationSetDeliveryOptionsError::InvalidDeliveryOptions(_) => {
+                "InvalidDeliveryOptions"
+            }
+        }
+    }
+}
 /// Errors returned by PutIdentityPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutIdentityPolicyError {
@@ -9076,6 +9393,13 @@ impl fmt::Display for PutIdentityPolicyError {
     }
 }
 impl Error for PutIdentityPolicyError {}
+impl ServiceErrorCode for PutIdentityPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutIdentityPolicyError::InvalidPolicy(_) => "InvalidPolicy",
+        }
+    }
+}
 /// Errors returned by ReorderReceiptRuleSet
 #[derive(Debug, PartialEq)]
 pub enum ReorderReceiptRuleSetError {
@@ -9128,6 +9452,14 @@ impl fmt::Display for ReorderReceiptRuleSetError {
     }
 }
 impl Error for ReorderReceiptRuleSetError {}
+impl ServiceErrorCode for ReorderReceiptRuleSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ReorderReceiptRuleSetError::RuleDoesNotExist(_) => "RuleDoesNotExist",
+            ReorderReceiptRuleSetError::RuleSetDoesNotExist(_) => "RuleSetDoesNotExist",
+        }
+    }
+}
 /// Errors returned by SendBounce
 #[derive(Debug, PartialEq)]
 pub enum SendBounceError {
@@ -9172,6 +9504,13 @@ impl fmt::Display for SendBounceError {
     }
 }
 impl Error for SendBounceError {}
+impl ServiceErrorCode for SendBounceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendBounceError::MessageRejected(_) => "MessageRejected",
+        }
+    }
+}
 /// Errors returned by SendBulkTemplatedEmail
 #[derive(Debug, PartialEq)]
 pub enum SendBulkTemplatedEmailError {
@@ -9268,6 +9607,24 @@ impl fmt::Display for SendBulkTemplatedEmailError {
     }
 }
 impl Error for SendBulkTemplatedEmailError {}
+impl ServiceErrorCode for SendBulkTemplatedEmailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendBulkTemplatedEmailError::AccountSendingPaused(_) => "AccountSendingPausedException",
+            SendBulkTemplatedEmailError::ConfigurationSetDoesNotExist(_) => {
+                "ConfigurationSetDoesNotExist"
+            }
+            SendBulkTemplatedEmailError::ConfigurationSetSendingPaused(_) => {
+                "ConfigurationSetSendingPausedException"
+            }
+            SendBulkTemplatedEmailError::MailFromDomainNotVerified(_) => {
+                "MailFromDomainNotVerifiedException"
+            }
+            SendBulkTemplatedEmailError::MessageRejected(_) => "MessageRejected",
+            SendBulkTemplatedEmailError::TemplateDoesNotExist(_) => "TemplateDoesNotExist",
+        }
+    }
+}
 /// Errors returned by SendCustomVerificationEmail
 #[derive(Debug, PartialEq)]
 pub enum SendCustomVerificationEmailError {
@@ -9329,6 +9686,25 @@ impl fmt::Display for SendCustomVerificationEmailError {
     }
 }
 impl Error for SendCustomVerificationEmailError {}
+impl ServiceErrorCode for SendCustomVerificationEmailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendCustomVerificationEmailError::ConfigurationSetDoesNotExist(_) => {
+                "ConfigurationSetDoesNotExist"
+            }
+            SendCustomVerificationEmailError::CustomVerificationEmailTemplateDoesNotExist(_) => {
+                "CustomVerificationEmailTemplateDoesNotExist"
+            }
+            SendCustomVerificationEmailError::FromEmailAddressNotVerified(_) => {
+                "FromEmailAddressNotVerified"
+            }
+            SendCustomVerificationEmailError::MessageRejected(_) => "MessageRejected",
+            SendCustomVerificationEmailError::ProductionAccessNotGranted(_) => {
+                "ProductionAccessNotGranted"
+            }
+        }
+    }
+}
 /// Errors returned by SendEmail
 #[derive(Debug, PartialEq)]
 pub enum SendEmailError {
@@ -9405,6 +9781,19 @@ impl fmt::Display for SendEmailError {
     }
 }
 impl Error for SendEmailError {}
+impl ServiceErrorCode for SendEmailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendEmailError::AccountSendingPaused(_) => "AccountSendingPausedException",
+            SendEmailError::ConfigurationSetDoesNotExist(_) => "ConfigurationSetDoesNotExist",
+            SendEmailError::ConfigurationSetSendingPaused(_) => {
+                "ConfigurationSetSendingPausedException"
+            }
+            SendEmailError::MailFromDomainNotVerified(_) => "MailFromDomainNotVerifiedException",
+            SendEmailError::MessageRejected(_) => "MessageRejected",
+        }
+    }
+}
 /// Errors returned by SendRawEmail
 #[derive(Debug, PartialEq)]
 pub enum SendRawEmailError {
@@ -9481,6 +9870,19 @@ impl fmt::Display for SendRawEmailError {
     }
 }
 impl Error for SendRawEmailError {}
+impl ServiceErrorCode for SendRawEmailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendRawEmailError::AccountSendingPaused(_) => "AccountSendingPausedException",
+            SendRawEmailError::ConfigurationSetDoesNotExist(_) => "ConfigurationSetDoesNotExist",
+            SendRawEmailError::ConfigurationSetSendingPaused(_) => {
+                "ConfigurationSetSendingPausedException"
+            }
+            SendRawEmailError::MailFromDomainNotVerified(_) => "MailFromDomainNotVerifiedException",
+            SendRawEmailError::MessageRejected(_) => "MessageRejected",
+        }
+    }
+}
 /// Errors returned by SendTemplatedEmail
 #[derive(Debug, PartialEq)]
 pub enum SendTemplatedEmailError {
@@ -9575,6 +9977,24 @@ impl fmt::Display for SendTemplatedEmailError {
     }
 }
 impl Error for SendTemplatedEmailError {}
+impl ServiceErrorCode for SendTemplatedEmailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendTemplatedEmailError::AccountSendingPaused(_) => "AccountSendingPausedException",
+            SendTemplatedEmailError::ConfigurationSetDoesNotExist(_) => {
+                "ConfigurationSetDoesNotExist"
+            }
+            SendTemplatedEmailError::ConfigurationSetSendingPaused(_) => {
+                "ConfigurationSetSendingPausedException"
+            }
+            SendTemplatedEmailError::MailFromDomainNotVerified(_) => {
+                "MailFromDomainNotVerifiedException"
+            }
+            SendTemplatedEmailError::MessageRejected(_) => "MessageRejected",
+            SendTemplatedEmailError::TemplateDoesNotExist(_) => "TemplateDoesNotExist",
+        }
+    }
+}
 /// Errors returned by SetActiveReceiptRuleSet
 #[derive(Debug, PartialEq)]
 pub enum SetActiveReceiptRuleSetError {
@@ -9619,6 +10039,13 @@ impl fmt::Display for SetActiveReceiptRuleSetError {
     }
 }
 impl Error for SetActiveReceiptRuleSetError {}
+impl ServiceErrorCode for SetActiveReceiptRuleSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetActiveReceiptRuleSetError::RuleSetDoesNotExist(_) => "RuleSetDoesNotExist",
+        }
+    }
+}
 /// Errors returned by SetIdentityDkimEnabled
 #[derive(Debug, PartialEq)]
 pub enum SetIdentityDkimEnabledError {}
@@ -9653,6 +10080,11 @@ impl fmt::Display for SetIdentityDkimEnabledError {
     }
 }
 impl Error for SetIdentityDkimEnabledError {}
+impl ServiceErrorCode for SetIdentityDkimEnabledError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SetIdentityFeedbackForwardingEnabled
 #[derive(Debug, PartialEq)]
 pub enum SetIdentityFeedbackForwardingEnabledError {}
@@ -9689,6 +10121,11 @@ impl fmt::Display for SetIdentityFeedbackForwardingEnabledError {
     }
 }
 impl Error for SetIdentityFeedbackForwardingEnabledError {}
+impl ServiceErrorCode for SetIdentityFeedbackForwardingEnabledError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SetIdentityHeadersInNotificationsEnabled
 #[derive(Debug, PartialEq)]
 pub enum SetIdentityHeadersInNotificationsEnabledError {}
@@ -9725,6 +10162,11 @@ impl fmt::Display for SetIdentityHeadersInNotificationsEnabledError {
     }
 }
 impl Error for SetIdentityHeadersInNotificationsEnabledError {}
+impl ServiceErrorCode for SetIdentityHeadersInNotificationsEnabledError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SetIdentityMailFromDomain
 #[derive(Debug, PartialEq)]
 pub enum SetIdentityMailFromDomainError {}
@@ -9759,6 +10201,11 @@ impl fmt::Display for SetIdentityMailFromDomainError {
     }
 }
 impl Error for SetIdentityMailFromDomainError {}
+impl ServiceErrorCode for SetIdentityMailFromDomainError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SetIdentityNotificationTopic
 #[derive(Debug, PartialEq)]
 pub enum SetIdentityNotificationTopicError {}
@@ -9795,6 +10242,11 @@ impl fmt::Display for SetIdentityNotificationTopicError {
     }
 }
 impl Error for SetIdentityNotificationTopicError {}
+impl ServiceErrorCode for SetIdentityNotificationTopicError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SetReceiptRulePosition
 #[derive(Debug, PartialEq)]
 pub enum SetReceiptRulePositionError {
@@ -9847,6 +10299,14 @@ impl fmt::Display for SetReceiptRulePositionError {
     }
 }
 impl Error for SetReceiptRulePositionError {}
+impl ServiceErrorCode for SetReceiptRulePositionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetReceiptRulePositionError::RuleDoesNotExist(_) => "RuleDoesNotExist",
+            SetReceiptRulePositionError::RuleSetDoesNotExist(_) => "RuleSetDoesNotExist",
+        }
+    }
+}
 /// Errors returned by TestRenderTemplate
 #[derive(Debug, PartialEq)]
 pub enum TestRenderTemplateError {
@@ -9911,6 +10371,15 @@ impl fmt::Display for TestRenderTemplateError {
     }
 }
 impl Error for TestRenderTemplateError {}
+impl ServiceErrorCode for TestRenderTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TestRenderTemplateError::InvalidRenderingParameter(_) => "InvalidRenderingParameter",
+            TestRenderTemplateError::MissingRenderingAttribute(_) => "MissingRenderingAttribute",
+            TestRenderTemplateError::TemplateDoesNotExist(_) => "TemplateDoesNotExist",
+        }
+    }
+}
 /// Errors returned by UpdateAccountSendingEnabled
 #[derive(Debug, PartialEq)]
 pub enum UpdateAccountSendingEnabledError {}
@@ -9947,6 +10416,11 @@ impl fmt::Display for UpdateAccountSendingEnabledError {
     }
 }
 impl Error for UpdateAccountSendingEnabledError {}
+impl ServiceErrorCode for UpdateAccountSendingEnabledError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UpdateConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationSetEventDestinationError {
@@ -10039,6 +10513,27 @@ impl fmt::Display for UpdateConfigurationSetEventDestinationError {
     }
 }
 impl Error for UpdateConfigurationSetEventDestinationError {}
+impl ServiceErrorCode for UpdateConfigurationSetEventDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(_) => {
+                "ConfigurationSetDoesNotExist"
+            }
+            UpdateConfigurationSetEventDestinationError::EventDestinationDoesNotExist(_) => {
+                "EventDestinationDoesNotExist"
+            }
+            UpdateConfigurationSetEventDestinationError::InvalidCloudWatchDestination(_) => {
+                "InvalidCloudWatchDestination"
+            }
+            UpdateConfigurationSetEventDestinationError::InvalidFirehoseDestination(_) => {
+                "InvalidFirehoseDestination"
+            }
+            UpdateConfigurationSetEventDestinationError::InvalidSNSDestination(_) => {
+                "InvalidSNSDestination"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateConfigurationSetReputationMetricsEnabled
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationSetReputationMetricsEnabledError {
@@ -10082,6 +10577,15 @@ impl fmt::Display for UpdateConfigurationSetReputationMetricsEnabledError {
     }
 }
 impl Error for UpdateConfigurationSetReputationMetricsEnabledError {}
+impl ServiceErrorCode for UpdateConfigurationSetReputationMetricsEnabledError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConfigurationSetReputationMetricsEnabledError::ConfigurationSetDoesNotExist(
+                _,
+            ) => "ConfigurationSetDoesNotExist",
+        }
+    }
+}
 /// Errors returned by UpdateConfigurationSetSendingEnabled
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationSetSendingEnabledError {
@@ -10132,6 +10636,15 @@ impl fmt::Display for UpdateConfigurationSetSendingEnabledError {
     }
 }
 impl Error for UpdateConfigurationSetSendingEnabledError {}
+impl ServiceErrorCode for UpdateConfigurationSetSendingEnabledError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConfigurationSetSendingEnabledError::ConfigurationSetDoesNotExist(_) => {
+                "ConfigurationSetDoesNotExist"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateConfigurationSetTrackingOptions
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationSetTrackingOptionsError {
@@ -10204,6 +10717,21 @@ impl fmt::Display for UpdateConfigurationSetTrackingOptionsError {
     }
 }
 impl Error for UpdateConfigurationSetTrackingOptionsError {}
+impl ServiceErrorCode for UpdateConfigurationSetTrackingOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(_) => {
+                "ConfigurationSetDoesNotExist"
+            }
+            UpdateConfigurationSetTrackingOptionsError::InvalidTrackingOptions(_) => {
+                "InvalidTrackingOptions"
+            }
+            UpdateConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist(_) => {
+                "TrackingOptionsDoesNotExistException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateCustomVerificationEmailTemplate
 #[derive(Debug, PartialEq)]
 pub enum UpdateCustomVerificationEmailTemplateError {
@@ -10251,6 +10779,15 @@ UpdateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(ref caus
     }
 }
 impl Error for UpdateCustomVerificationEmailTemplateError {}
+impl ServiceErrorCode for UpdateCustomVerificationEmailTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(_) => "CustomVerificationEmailInvalidContent",
+            UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(_) => "CustomVerificationEmailTemplateDoesNotExist",
+            UpdateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(_) => "FromEmailAddressNotVerified",
+        }
+    }
+}
 /// Errors returned by UpdateReceiptRule
 #[derive(Debug, PartialEq)]
 pub enum UpdateReceiptRuleError {
@@ -10335,6 +10872,18 @@ impl fmt::Display for UpdateReceiptRuleError {
     }
 }
 impl Error for UpdateReceiptRuleError {}
+impl ServiceErrorCode for UpdateReceiptRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateReceiptRuleError::InvalidLambdaFunction(_) => "InvalidLambdaFunction",
+            UpdateReceiptRuleError::InvalidS3Configuration(_) => "InvalidS3Configuration",
+            UpdateReceiptRuleError::InvalidSnsTopic(_) => "InvalidSnsTopic",
+            UpdateReceiptRuleError::LimitExceeded(_) => "LimitExceeded",
+            UpdateReceiptRuleError::RuleDoesNotExist(_) => "RuleDoesNotExist",
+            UpdateReceiptRuleError::RuleSetDoesNotExist(_) => "RuleSetDoesNotExist",
+        }
+    }
+}
 /// Errors returned by UpdateTemplate
 #[derive(Debug, PartialEq)]
 pub enum UpdateTemplateError {
@@ -10387,6 +10936,14 @@ impl fmt::Display for UpdateTemplateError {
     }
 }
 impl Error for UpdateTemplateError {}
+impl ServiceErrorCode for UpdateTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateTemplateError::InvalidTemplate(_) => "InvalidTemplate",
+            UpdateTemplateError::TemplateDoesNotExist(_) => "TemplateDoesNotExist",
+        }
+    }
+}
 /// Errors returned by VerifyDomainDkim
 #[derive(Debug, PartialEq)]
 pub enum VerifyDomainDkimError {}
@@ -10421,6 +10978,11 @@ impl fmt::Display for VerifyDomainDkimError {
     }
 }
 impl Error for VerifyDomainDkimError {}
+impl ServiceErrorCode for VerifyDomainDkimError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by VerifyDomainIdentity
 #[derive(Debug, PartialEq)]
 pub enum VerifyDomainIdentityError {}
@@ -10455,6 +11017,11 @@ impl fmt::Display for VerifyDomainIdentityError {
     }
 }
 impl Error for VerifyDomainIdentityError {}
+impl ServiceErrorCode for VerifyDomainIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by VerifyEmailAddress
 #[derive(Debug, PartialEq)]
 pub enum VerifyEmailAddressError {}
@@ -10489,6 +11056,11 @@ impl fmt::Display for VerifyEmailAddressError {
     }
 }
 impl Error for VerifyEmailAddressError {}
+impl ServiceErrorCode for VerifyEmailAddressError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by VerifyEmailIdentity
 #[derive(Debug, PartialEq)]
 pub enum VerifyEmailIdentityError {}
@@ -10523,6 +11095,11 @@ impl fmt::Display for VerifyEmailIdentityError {
     }
 }
 impl Error for VerifyEmailIdentityError {}
+impl ServiceErrorCode for VerifyEmailIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Trait representing the capabilities of the Amazon SES API. Amazon SES clients implement this trait.
 #[async_trait]
 pub trait Ses {
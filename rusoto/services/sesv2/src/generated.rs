@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -3136,6 +3136,20 @@ impl fmt::Display for CreateConfigurationSetError {
     }
 }
 impl Error for CreateConfigurationSetError {}
+impl ServiceErrorCode for CreateConfigurationSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConfigurationSetError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateConfigurationSetError::BadRequest(_) => "BadRequestException",
+            CreateConfigurationSetError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            CreateConfigurationSetError::LimitExceeded(_) => "LimitExceededException",
+            CreateConfigurationSetError::NotFound(_) => "NotFoundException",
+            CreateConfigurationSetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationSetEventDestinationError {
@@ -3212,6 +3226,23 @@ impl fmt::Display for CreateConfigurationSetEventDestinationError {
     }
 }
 impl Error for CreateConfigurationSetEventDestinationError {}
+impl ServiceErrorCode for CreateConfigurationSetEventDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConfigurationSetEventDestinationError::AlreadyExists(_) => {
+                "AlreadyExistsException"
+            }
+            CreateConfigurationSetEventDestinationError::BadRequest(_) => "BadRequestException",
+            CreateConfigurationSetEventDestinationError::LimitExceeded(_) => {
+                "LimitExceededException"
+            }
+            CreateConfigurationSetEventDestinationError::NotFound(_) => "NotFoundException",
+            CreateConfigurationSetEventDestinationError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateContact
 #[derive(Debug, PartialEq)]
 pub enum CreateContactError {
@@ -3260,6 +3291,16 @@ impl fmt::Display for CreateContactError {
     }
 }
 impl Error for CreateContactError {}
+impl ServiceErrorCode for CreateContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateContactError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateContactError::BadRequest(_) => "BadRequestException",
+            CreateContactError::NotFound(_) => "NotFoundException",
+            CreateContactError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateContactList
 #[derive(Debug, PartialEq)]
 pub enum CreateContactListError {
@@ -3308,6 +3349,16 @@ impl fmt::Display for CreateContactListError {
     }
 }
 impl Error for CreateContactListError {}
+impl ServiceErrorCode for CreateContactListError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateContactListError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateContactListError::BadRequest(_) => "BadRequestException",
+            CreateContactListError::LimitExceeded(_) => "LimitExceededException",
+            CreateContactListError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateCustomVerificationEmailTemplate
 #[derive(Debug, PartialEq)]
 pub enum CreateCustomVerificationEmailTemplateError {
@@ -3384,6 +3435,23 @@ impl fmt::Display for CreateCustomVerificationEmailTemplateError {
     }
 }
 impl Error for CreateCustomVerificationEmailTemplateError {}
+impl ServiceErrorCode for CreateCustomVerificationEmailTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCustomVerificationEmailTemplateError::AlreadyExists(_) => {
+                "AlreadyExistsException"
+            }
+            CreateCustomVerificationEmailTemplateError::BadRequest(_) => "BadRequestException",
+            CreateCustomVerificationEmailTemplateError::LimitExceeded(_) => {
+                "LimitExceededException"
+            }
+            CreateCustomVerificationEmailTemplateError::NotFound(_) => "NotFoundException",
+            CreateCustomVerificationEmailTemplateError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateDedicatedIpPool
 #[derive(Debug, PartialEq)]
 pub enum CreateDedicatedIpPoolError {
@@ -3442,6 +3510,19 @@ impl fmt::Display for CreateDedicatedIpPoolError {
     }
 }
 impl Error for CreateDedicatedIpPoolError {}
+impl ServiceErrorCode for CreateDedicatedIpPoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDedicatedIpPoolError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateDedicatedIpPoolError::BadRequest(_) => "BadRequestException",
+            CreateDedicatedIpPoolError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            CreateDedicatedIpPoolError::LimitExceeded(_) => "LimitExceededException",
+            CreateDedicatedIpPoolError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateDeliverabilityTestReport
 #[derive(Debug, PartialEq)]
 pub enum CreateDeliverabilityTestReportError {
@@ -3550,6 +3631,25 @@ impl fmt::Display for CreateDeliverabilityTestReportError {
     }
 }
 impl Error for CreateDeliverabilityTestReportError {}
+impl ServiceErrorCode for CreateDeliverabilityTestReportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDeliverabilityTestReportError::AccountSuspended(_) => "AccountSuspendedException",
+            CreateDeliverabilityTestReportError::BadRequest(_) => "BadRequestException",
+            CreateDeliverabilityTestReportError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            CreateDeliverabilityTestReportError::LimitExceeded(_) => "LimitExceededException",
+            CreateDeliverabilityTestReportError::MailFromDomainNotVerified(_) => {
+                "MailFromDomainNotVerifiedException"
+            }
+            CreateDeliverabilityTestReportError::MessageRejected(_) => "MessageRejected",
+            CreateDeliverabilityTestReportError::NotFound(_) => "NotFoundException",
+            CreateDeliverabilityTestReportError::SendingPaused(_) => "SendingPausedException",
+            CreateDeliverabilityTestReportError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateEmailIdentity
 #[derive(Debug, PartialEq)]
 pub enum CreateEmailIdentityError {
@@ -3606,6 +3706,19 @@ impl fmt::Display for CreateEmailIdentityError {
     }
 }
 impl Error for CreateEmailIdentityError {}
+impl ServiceErrorCode for CreateEmailIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEmailIdentityError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateEmailIdentityError::BadRequest(_) => "BadRequestException",
+            CreateEmailIdentityError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            CreateEmailIdentityError::LimitExceeded(_) => "LimitExceededException",
+            CreateEmailIdentityError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateEmailIdentityPolicy
 #[derive(Debug, PartialEq)]
 pub enum CreateEmailIdentityPolicyError {
@@ -3668,6 +3781,17 @@ impl fmt::Display for CreateEmailIdentityPolicyError {
     }
 }
 impl Error for CreateEmailIdentityPolicyError {}
+impl ServiceErrorCode for CreateEmailIdentityPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEmailIdentityPolicyError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateEmailIdentityPolicyError::BadRequest(_) => "BadRequestException",
+            CreateEmailIdentityPolicyError::LimitExceeded(_) => "LimitExceededException",
+            CreateEmailIdentityPolicyError::NotFound(_) => "NotFoundException",
+            CreateEmailIdentityPolicyError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateEmailTemplate
 #[derive(Debug, PartialEq)]
 pub enum CreateEmailTemplateError {
@@ -3716,6 +3840,16 @@ impl fmt::Display for CreateEmailTemplateError {
     }
 }
 impl Error for CreateEmailTemplateError {}
+impl ServiceErrorCode for CreateEmailTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateEmailTemplateError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateEmailTemplateError::BadRequest(_) => "BadRequestException",
+            CreateEmailTemplateError::LimitExceeded(_) => "LimitExceededException",
+            CreateEmailTemplateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateImportJob
 #[derive(Debug, PartialEq)]
 pub enum CreateImportJobError {
@@ -3758,6 +3892,15 @@ impl fmt::Display for CreateImportJobError {
     }
 }
 impl Error for CreateImportJobError {}
+impl ServiceErrorCode for CreateImportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateImportJobError::BadRequest(_) => "BadRequestException",
+            CreateImportJobError::LimitExceeded(_) => "LimitExceededException",
+            CreateImportJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteConfigurationSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationSetError {
@@ -3812,6 +3955,18 @@ impl fmt::Display for DeleteConfigurationSetError {
     }
 }
 impl Error for DeleteConfigurationSetError {}
+impl ServiceErrorCode for DeleteConfigurationSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigurationSetError::BadRequest(_) => "BadRequestException",
+            DeleteConfigurationSetError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteConfigurationSetError::NotFound(_) => "NotFoundException",
+            DeleteConfigurationSetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationSetEventDestinationError {
@@ -3868,6 +4023,17 @@ impl fmt::Display for DeleteConfigurationSetEventDestinationError {
     }
 }
 impl Error for DeleteConfigurationSetEventDestinationError {}
+impl ServiceErrorCode for DeleteConfigurationSetEventDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigurationSetEventDestinationError::BadRequest(_) => "BadRequestException",
+            DeleteConfigurationSetEventDestinationError::NotFound(_) => "NotFoundException",
+            DeleteConfigurationSetEventDestinationError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteContact
 #[derive(Debug, PartialEq)]
 pub enum DeleteContactError {
@@ -3910,6 +4076,15 @@ impl fmt::Display for DeleteContactError {
     }
 }
 impl Error for DeleteContactError {}
+impl ServiceErrorCode for DeleteContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteContactError::BadRequest(_) => "BadRequestException",
+            DeleteContactError::NotFound(_) => "NotFoundException",
+            DeleteContactError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteContactList
 #[derive(Debug, PartialEq)]
 pub enum DeleteContactListError {
@@ -3960,6 +4135,16 @@ impl fmt::Display for DeleteContactListError {
     }
 }
 impl Error for DeleteContactListError {}
+impl ServiceErrorCode for DeleteContactListError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteContactListError::BadRequest(_) => "BadRequestException",
+            DeleteContactListError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteContactListError::NotFound(_) => "NotFoundException",
+            DeleteContactListError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteCustomVerificationEmailTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeleteCustomVerificationEmailTemplateError {
@@ -4016,6 +4201,17 @@ impl fmt::Display for DeleteCustomVerificationEmailTemplateError {
     }
 }
 impl Error for DeleteCustomVerificationEmailTemplateError {}
+impl ServiceErrorCode for DeleteCustomVerificationEmailTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCustomVerificationEmailTemplateError::BadRequest(_) => "BadRequestException",
+            DeleteCustomVerificationEmailTemplateError::NotFound(_) => "NotFoundException",
+            DeleteCustomVerificationEmailTemplateError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDedicatedIpPool
 #[derive(Debug, PartialEq)]
 pub enum DeleteDedicatedIpPoolError {
@@ -4068,6 +4264,18 @@ impl fmt::Display for DeleteDedicatedIpPoolError {
     }
 }
 impl Error for DeleteDedicatedIpPoolError {}
+impl ServiceErrorCode for DeleteDedicatedIpPoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDedicatedIpPoolError::BadRequest(_) => "BadRequestException",
+            DeleteDedicatedIpPoolError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteDedicatedIpPoolError::NotFound(_) => "NotFoundException",
+            DeleteDedicatedIpPoolError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteEmailIdentity
 #[derive(Debug, PartialEq)]
 pub enum DeleteEmailIdentityError {
@@ -4118,6 +4326,18 @@ impl fmt::Display for DeleteEmailIdentityError {
     }
 }
 impl Error for DeleteEmailIdentityError {}
+impl ServiceErrorCode for DeleteEmailIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEmailIdentityError::BadRequest(_) => "BadRequestException",
+            DeleteEmailIdentityError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            DeleteEmailIdentityError::NotFound(_) => "NotFoundException",
+            DeleteEmailIdentityError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteEmailIdentityPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteEmailIdentityPolicyError {
@@ -4164,6 +4384,15 @@ impl fmt::Display for DeleteEmailIdentityPolicyError {
     }
 }
 impl Error for DeleteEmailIdentityPolicyError {}
+impl ServiceErrorCode for DeleteEmailIdentityPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEmailIdentityPolicyError::BadRequest(_) => "BadRequestException",
+            DeleteEmailIdentityPolicyError::NotFound(_) => "NotFoundException",
+            DeleteEmailIdentityPolicyError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteEmailTemplate
 #[derive(Debug, PartialEq)]
 pub enum DeleteEmailTemplateError {
@@ -4206,6 +4435,15 @@ impl fmt::Display for DeleteEmailTemplateError {
     }
 }
 impl Error for DeleteEmailTemplateError {}
+impl ServiceErrorCode for DeleteEmailTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEmailTemplateError::BadRequest(_) => "BadRequestException",
+            DeleteEmailTemplateError::NotFound(_) => "NotFoundException",
+            DeleteEmailTemplateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteSuppressedDestination
 #[derive(Debug, PartialEq)]
 pub enum DeleteSuppressedDestinationError {
@@ -4256,6 +4494,15 @@ impl fmt::Display for DeleteSuppressedDestinationError {
     }
 }
 impl Error for DeleteSuppressedDestinationError {}
+impl ServiceErrorCode for DeleteSuppressedDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSuppressedDestinationError::BadRequest(_) => "BadRequestException",
+            DeleteSuppressedDestinationError::NotFound(_) => "NotFoundException",
+            DeleteSuppressedDestinationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetAccount
 #[derive(Debug, PartialEq)]
 pub enum GetAccountError {
@@ -4292,6 +4539,14 @@ impl fmt::Display for GetAccountError {
     }
 }
 impl Error for GetAccountError {}
+impl ServiceErrorCode for GetAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAccountError::BadRequest(_) => "BadRequestException",
+            GetAccountError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetBlacklistReports
 #[derive(Debug, PartialEq)]
 pub enum GetBlacklistReportsError {
@@ -4334,6 +4589,15 @@ impl fmt::Display for GetBlacklistReportsError {
     }
 }
 impl Error for GetBlacklistReportsError {}
+impl ServiceErrorCode for GetBlacklistReportsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetBlacklistReportsError::BadRequest(_) => "BadRequestException",
+            GetBlacklistReportsError::NotFound(_) => "NotFoundException",
+            GetBlacklistReportsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetConfigurationSet
 #[derive(Debug, PartialEq)]
 pub enum GetConfigurationSetError {
@@ -4376,6 +4640,15 @@ impl fmt::Display for GetConfigurationSetError {
     }
 }
 impl Error for GetConfigurationSetError {}
+impl ServiceErrorCode for GetConfigurationSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConfigurationSetError::BadRequest(_) => "BadRequestException",
+            GetConfigurationSetError::NotFound(_) => "NotFoundException",
+            GetConfigurationSetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetConfigurationSetEventDestinations
 #[derive(Debug, PartialEq)]
 pub enum GetConfigurationSetEventDestinationsError {
@@ -4432,6 +4705,17 @@ impl fmt::Display for GetConfigurationSetEventDestinationsError {
     }
 }
 impl Error for GetConfigurationSetEventDestinationsError {}
+impl ServiceErrorCode for GetConfigurationSetEventDestinationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConfigurationSetEventDestinationsError::BadRequest(_) => "BadRequestException",
+            GetConfigurationSetEventDestinationsError::NotFound(_) => "NotFoundException",
+            GetConfigurationSetEventDestinationsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by GetContact
 #[derive(Debug, PartialEq)]
 pub enum GetContactError {
@@ -4474,6 +4758,15 @@ impl fmt::Display for GetContactError {
     }
 }
 impl Error for GetContactError {}
+impl ServiceErrorCode for GetContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContactError::BadRequest(_) => "BadRequestException",
+            GetContactError::NotFound(_) => "NotFoundException",
+            GetContactError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetContactList
 #[derive(Debug, PartialEq)]
 pub enum GetContactListError {
@@ -4516,6 +4809,15 @@ impl fmt::Display for GetContactListError {
     }
 }
 impl Error for GetContactListError {}
+impl ServiceErrorCode for GetContactListError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetContactListError::BadRequest(_) => "BadRequestException",
+            GetContactListError::NotFound(_) => "NotFoundException",
+            GetContactListError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetCustomVerificationEmailTemplate
 #[derive(Debug, PartialEq)]
 pub enum GetCustomVerificationEmailTemplateError {
@@ -4570,6 +4872,17 @@ impl fmt::Display for GetCustomVerificationEmailTemplateError {
     }
 }
 impl Error for GetCustomVerificationEmailTemplateError {}
+impl ServiceErrorCode for GetCustomVerificationEmailTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCustomVerificationEmailTemplateError::BadRequest(_) => "BadRequestException",
+            GetCustomVerificationEmailTemplateError::NotFound(_) => "NotFoundException",
+            GetCustomVerificationEmailTemplateError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by GetDedicatedIp
 #[derive(Debug, PartialEq)]
 pub enum GetDedicatedIpError {
@@ -4612,6 +4925,15 @@ impl fmt::Display for GetDedicatedIpError {
     }
 }
 impl Error for GetDedicatedIpError {}
+impl ServiceErrorCode for GetDedicatedIpError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDedicatedIpError::BadRequest(_) => "BadRequestException",
+            GetDedicatedIpError::NotFound(_) => "NotFoundException",
+            GetDedicatedIpError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetDedicatedIps
 #[derive(Debug, PartialEq)]
 pub enum GetDedicatedIpsError {
@@ -4654,6 +4976,15 @@ impl fmt::Display for GetDedicatedIpsError {
     }
 }
 impl Error for GetDedicatedIpsError {}
+impl ServiceErrorCode for GetDedicatedIpsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDedicatedIpsError::BadRequest(_) => "BadRequestException",
+            GetDedicatedIpsError::NotFound(_) => "NotFoundException",
+            GetDedicatedIpsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetDeliverabilityDashboardOptions
 #[derive(Debug, PartialEq)]
 pub enum GetDeliverabilityDashboardOptionsError {
@@ -4708,6 +5039,17 @@ impl fmt::Display for GetDeliverabilityDashboardOptionsError {
     }
 }
 impl Error for GetDeliverabilityDashboardOptionsError {}
+impl ServiceErrorCode for GetDeliverabilityDashboardOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeliverabilityDashboardOptionsError::BadRequest(_) => "BadRequestException",
+            GetDeliverabilityDashboardOptionsError::LimitExceeded(_) => "LimitExceededException",
+            GetDeliverabilityDashboardOptionsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by GetDeliverabilityTestReport
 #[derive(Debug, PartialEq)]
 pub enum GetDeliverabilityTestReportError {
@@ -4758,6 +5100,15 @@ impl fmt::Display for GetDeliverabilityTestReportError {
     }
 }
 impl Error for GetDeliverabilityTestReportError {}
+impl ServiceErrorCode for GetDeliverabilityTestReportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeliverabilityTestReportError::BadRequest(_) => "BadRequestException",
+            GetDeliverabilityTestReportError::NotFound(_) => "NotFoundException",
+            GetDeliverabilityTestReportError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetDomainDeliverabilityCampaign
 #[derive(Debug, PartialEq)]
 pub enum GetDomainDeliverabilityCampaignError {
@@ -4810,6 +5161,15 @@ impl fmt::Display for GetDomainDeliverabilityCampaignError {
     }
 }
 impl Error for GetDomainDeliverabilityCampaignError {}
+impl ServiceErrorCode for GetDomainDeliverabilityCampaignError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDomainDeliverabilityCampaignError::BadRequest(_) => "BadRequestException",
+            GetDomainDeliverabilityCampaignError::NotFound(_) => "NotFoundException",
+            GetDomainDeliverabilityCampaignError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetDomainStatisticsReport
 #[derive(Debug, PartialEq)]
 pub enum GetDomainStatisticsReportError {
@@ -4856,6 +5216,15 @@ impl fmt::Display for GetDomainStatisticsReportError {
     }
 }
 impl Error for GetDomainStatisticsReportError {}
+impl ServiceErrorCode for GetDomainStatisticsReportError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDomainStatisticsReportError::BadRequest(_) => "BadRequestException",
+            GetDomainStatisticsReportError::NotFound(_) => "NotFoundException",
+            GetDomainStatisticsReportError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetEmailIdentity
 #[derive(Debug, PartialEq)]
 pub enum GetEmailIdentityError {
@@ -4898,6 +5267,15 @@ impl fmt::Display for GetEmailIdentityError {
     }
 }
 impl Error for GetEmailIdentityError {}
+impl ServiceErrorCode for GetEmailIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEmailIdentityError::BadRequest(_) => "BadRequestException",
+            GetEmailIdentityError::NotFound(_) => "NotFoundException",
+            GetEmailIdentityError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetEmailIdentityPolicies
 #[derive(Debug, PartialEq)]
 pub enum GetEmailIdentityPoliciesError {
@@ -4942,6 +5320,15 @@ impl fmt::Display for GetEmailIdentityPoliciesError {
     }
 }
 impl Error for GetEmailIdentityPoliciesError {}
+impl ServiceErrorCode for GetEmailIdentityPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEmailIdentityPoliciesError::BadRequest(_) => "BadRequestException",
+            GetEmailIdentityPoliciesError::NotFound(_) => "NotFoundException",
+            GetEmailIdentityPoliciesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetEmailTemplate
 #[derive(Debug, PartialEq)]
 pub enum GetEmailTemplateError {
@@ -4984,6 +5371,15 @@ impl fmt::Display for GetEmailTemplateError {
     }
 }
 impl Error for GetEmailTemplateError {}
+impl ServiceErrorCode for GetEmailTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEmailTemplateError::BadRequest(_) => "BadRequestException",
+            GetEmailTemplateError::NotFound(_) => "NotFoundException",
+            GetEmailTemplateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetImportJob
 #[derive(Debug, PartialEq)]
 pub enum GetImportJobError {
@@ -5026,6 +5422,15 @@ impl fmt::Display for GetImportJobError {
     }
 }
 impl Error for GetImportJobError {}
+impl ServiceErrorCode for GetImportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetImportJobError::BadRequest(_) => "BadRequestException",
+            GetImportJobError::NotFound(_) => "NotFoundException",
+            GetImportJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetSuppressedDestination
 #[derive(Debug, PartialEq)]
 pub enum GetSuppressedDestinationError {
@@ -5070,6 +5475,15 @@ impl fmt::Display for GetSuppressedDestinationError {
     }
 }
 impl Error for GetSuppressedDestinationError {}
+impl ServiceErrorCode for GetSuppressedDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSuppressedDestinationError::BadRequest(_) => "BadRequestException",
+            GetSuppressedDestinationError::NotFound(_) => "NotFoundException",
+            GetSuppressedDestinationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListConfigurationSets
 #[derive(Debug, PartialEq)]
 pub enum ListConfigurationSetsError {
@@ -5108,6 +5522,14 @@ impl fmt::Display for ListConfigurationSetsError {
     }
 }
 impl Error for ListConfigurationSetsError {}
+impl ServiceErrorCode for ListConfigurationSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListConfigurationSetsError::BadRequest(_) => "BadRequestException",
+            ListConfigurationSetsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListContactLists
 #[derive(Debug, PartialEq)]
 pub enum ListContactListsError {
@@ -5144,6 +5566,14 @@ impl fmt::Display for ListContactListsError {
     }
 }
 impl Error for ListContactListsError {}
+impl ServiceErrorCode for ListContactListsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListContactListsError::BadRequest(_) => "BadRequestException",
+            ListContactListsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListContacts
 #[derive(Debug, PartialEq)]
 pub enum ListContactsError {
@@ -5186,6 +5616,15 @@ impl fmt::Display for ListContactsError {
     }
 }
 impl Error for ListContactsError {}
+impl ServiceErrorCode for ListContactsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListContactsError::BadRequest(_) => "BadRequestException",
+            ListContactsError::NotFound(_) => "NotFoundException",
+            ListContactsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListCustomVerificationEmailTemplates
 #[derive(Debug, PartialEq)]
 pub enum ListCustomVerificationEmailTemplatesError {
@@ -5232,6 +5671,16 @@ impl fmt::Display for ListCustomVerificationEmailTemplatesError {
     }
 }
 impl Error for ListCustomVerificationEmailTemplatesError {}
+impl ServiceErrorCode for ListCustomVerificationEmailTemplatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCustomVerificationEmailTemplatesError::BadRequest(_) => "BadRequestException",
+            ListCustomVerificationEmailTemplatesError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by ListDedicatedIpPools
 #[derive(Debug, PartialEq)]
 pub enum ListDedicatedIpPoolsError {
@@ -5270,6 +5719,14 @@ impl fmt::Display for ListDedicatedIpPoolsError {
     }
 }
 impl Error for ListDedicatedIpPoolsError {}
+impl ServiceErrorCode for ListDedicatedIpPoolsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDedicatedIpPoolsError::BadRequest(_) => "BadRequestException",
+            ListDedicatedIpPoolsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListDeliverabilityTestReports
 #[derive(Debug, PartialEq)]
 pub enum ListDeliverabilityTestReportsError {
@@ -5322,6 +5779,15 @@ impl fmt::Display for ListDeliverabilityTestReportsError {
     }
 }
 impl Error for ListDeliverabilityTestReportsError {}
+impl ServiceErrorCode for ListDeliverabilityTestReportsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeliverabilityTestReportsError::BadRequest(_) => "BadRequestException",
+            ListDeliverabilityTestReportsError::NotFound(_) => "NotFoundException",
+            ListDeliverabilityTestReportsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListDomainDeliverabilityCampaigns
 #[derive(Debug, PartialEq)]
 pub enum ListDomainDeliverabilityCampaignsError {
@@ -5374,6 +5840,17 @@ impl fmt::Display for ListDomainDeliverabilityCampaignsError {
     }
 }
 impl Error for ListDomainDeliverabilityCampaignsError {}
+impl ServiceErrorCode for ListDomainDeliverabilityCampaignsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDomainDeliverabilityCampaignsError::BadRequest(_) => "BadRequestException",
+            ListDomainDeliverabilityCampaignsError::NotFound(_) => "NotFoundException",
+            ListDomainDeliverabilityCampaignsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by ListEmailIdentities
 #[derive(Debug, PartialEq)]
 pub enum ListEmailIdentitiesError {
@@ -5410,6 +5887,14 @@ impl fmt::Display for ListEmailIdentitiesError {
     }
 }
 impl Error for ListEmailIdentitiesError {}
+impl ServiceErrorCode for ListEmailIdentitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEmailIdentitiesError::BadRequest(_) => "BadRequestException",
+            ListEmailIdentitiesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListEmailTemplates
 #[derive(Debug, PartialEq)]
 pub enum ListEmailTemplatesError {
@@ -5446,6 +5931,14 @@ impl fmt::Display for ListEmailTemplatesError {
     }
 }
 impl Error for ListEmailTemplatesError {}
+impl ServiceErrorCode for ListEmailTemplatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEmailTemplatesError::BadRequest(_) => "BadRequestException",
+            ListEmailTemplatesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListImportJobs
 #[derive(Debug, PartialEq)]
 pub enum ListImportJobsError {
@@ -5482,6 +5975,14 @@ impl fmt::Display for ListImportJobsError {
     }
 }
 impl Error for ListImportJobsError {}
+impl ServiceErrorCode for ListImportJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListImportJobsError::BadRequest(_) => "BadRequestException",
+            ListImportJobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListSuppressedDestinations
 #[derive(Debug, PartialEq)]
 pub enum ListSuppressedDestinationsError {
@@ -5532,6 +6033,15 @@ impl fmt::Display for ListSuppressedDestinationsError {
     }
 }
 impl Error for ListSuppressedDestinationsError {}
+impl ServiceErrorCode for ListSuppressedDestinationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSuppressedDestinationsError::BadRequest(_) => "BadRequestException",
+            ListSuppressedDestinationsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListSuppressedDestinationsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -5574,6 +6084,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+            ListTagsForResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutAccountDedicatedIpWarmupAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutAccountDedicatedIpWarmupAttributesError {
@@ -5620,6 +6139,16 @@ impl fmt::Display for PutAccountDedicatedIpWarmupAttributesError {
     }
 }
 impl Error for PutAccountDedicatedIpWarmupAttributesError {}
+impl ServiceErrorCode for PutAccountDedicatedIpWarmupAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAccountDedicatedIpWarmupAttributesError::BadRequest(_) => "BadRequestException",
+            PutAccountDedicatedIpWarmupAttributesError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by PutAccountDetails
 #[derive(Debug, PartialEq)]
 pub enum PutAccountDetailsError {
@@ -5662,6 +6191,15 @@ impl fmt::Display for PutAccountDetailsError {
     }
 }
 impl Error for PutAccountDetailsError {}
+impl ServiceErrorCode for PutAccountDetailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAccountDetailsError::BadRequest(_) => "BadRequestException",
+            PutAccountDetailsError::Conflict(_) => "ConflictException",
+            PutAccountDetailsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutAccountSendingAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutAccountSendingAttributesError {
@@ -5704,6 +6242,14 @@ impl fmt::Display for PutAccountSendingAttributesError {
     }
 }
 impl Error for PutAccountSendingAttributesError {}
+impl ServiceErrorCode for PutAccountSendingAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAccountSendingAttributesError::BadRequest(_) => "BadRequestException",
+            PutAccountSendingAttributesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutAccountSuppressionAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutAccountSuppressionAttributesError {
@@ -5748,6 +6294,14 @@ impl fmt::Display for PutAccountSuppressionAttributesError {
     }
 }
 impl Error for PutAccountSuppressionAttributesError {}
+impl ServiceErrorCode for PutAccountSuppressionAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAccountSuppressionAttributesError::BadRequest(_) => "BadRequestException",
+            PutAccountSuppressionAttributesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutConfigurationSetDeliveryOptions
 #[derive(Debug, PartialEq)]
 pub enum PutConfigurationSetDeliveryOptionsError {
@@ -5802,6 +6356,17 @@ impl fmt::Display for PutConfigurationSetDeliveryOptionsError {
     }
 }
 impl Error for PutConfigurationSetDeliveryOptionsError {}
+impl ServiceErrorCode for PutConfigurationSetDeliveryOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutConfigurationSetDeliveryOptionsError::BadRequest(_) => "BadRequestException",
+            PutConfigurationSetDeliveryOptionsError::NotFound(_) => "NotFoundException",
+            PutConfigurationSetDeliveryOptionsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by PutConfigurationSetReputationOptions
 #[derive(Debug, PartialEq)]
 pub enum PutConfigurationSetReputationOptionsError {
@@ -5858,6 +6423,17 @@ impl fmt::Display for PutConfigurationSetReputationOptionsError {
     }
 }
 impl Error for PutConfigurationSetReputationOptionsError {}
+impl ServiceErrorCode for PutConfigurationSetReputationOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutConfigurationSetReputationOptionsError::BadRequest(_) => "BadRequestException",
+            PutConfigurationSetReputationOptionsError::NotFound(_) => "NotFoundException",
+            PutConfigurationSetReputationOptionsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by PutConfigurationSetSendingOptions
 #[derive(Debug, PartialEq)]
 pub enum PutConfigurationSetSendingOptionsError {
@@ -5910,6 +6486,17 @@ impl fmt::Display for PutConfigurationSetSendingOptionsError {
     }
 }
 impl Error for PutConfigurationSetSendingOptionsError {}
+impl ServiceErrorCode for PutConfigurationSetSendingOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutConfigurationSetSendingOptionsError::BadRequest(_) => "BadRequestException",
+            PutConfigurationSetSendingOptionsError::NotFound(_) => "NotFoundException",
+            PutConfigurationSetSendingOptionsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by PutConfigurationSetSuppressionOptions
 #[derive(Debug, PartialEq)]
 pub enum PutConfigurationSetSuppressionOptionsError {
@@ -5966,6 +6553,17 @@ impl fmt::Display for PutConfigurationSetSuppressionOptionsError {
     }
 }
 impl Error for PutConfigurationSetSuppressionOptionsError {}
+impl ServiceErrorCode for PutConfigurationSetSuppressionOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutConfigurationSetSuppressionOptionsError::BadRequest(_) => "BadRequestException",
+            PutConfigurationSetSuppressionOptionsError::NotFound(_) => "NotFoundException",
+            PutConfigurationSetSuppressionOptionsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by PutConfigurationSetTrackingOptions
 #[derive(Debug, PartialEq)]
 pub enum PutConfigurationSetTrackingOptionsError {
@@ -6020,6 +6618,17 @@ impl fmt::Display for PutConfigurationSetTrackingOptionsError {
     }
 }
 impl Error for PutConfigurationSetTrackingOptionsError {}
+impl ServiceErrorCode for PutConfigurationSetTrackingOptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutConfigurationSetTrackingOptionsError::BadRequest(_) => "BadRequestException",
+            PutConfigurationSetTrackingOptionsError::NotFound(_) => "NotFoundException",
+            PutConfigurationSetTrackingOptionsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by PutDedicatedIpInPool
 #[derive(Debug, PartialEq)]
 pub enum PutDedicatedIpInPoolError {
@@ -6064,6 +6673,15 @@ impl fmt::Display for PutDedicatedIpInPoolError {
     }
 }
 impl Error for PutDedicatedIpInPoolError {}
+impl ServiceErrorCode for PutDedicatedIpInPoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutDedicatedIpInPoolError::BadRequest(_) => "BadRequestException",
+            PutDedicatedIpInPoolError::NotFound(_) => "NotFoundException",
+            PutDedicatedIpInPoolError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutDedicatedIpWarmupAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutDedicatedIpWarmupAttributesError {
@@ -6116,6 +6734,15 @@ impl fmt::Display for PutDedicatedIpWarmupAttributesError {
     }
 }
 impl Error for PutDedicatedIpWarmupAttributesError {}
+impl ServiceErrorCode for PutDedicatedIpWarmupAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutDedicatedIpWarmupAttributesError::BadRequest(_) => "BadRequestException",
+            PutDedicatedIpWarmupAttributesError::NotFound(_) => "NotFoundException",
+            PutDedicatedIpWarmupAttributesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutDeliverabilityDashboardOption
 #[derive(Debug, PartialEq)]
 pub enum PutDeliverabilityDashboardOptionError {
@@ -6188,6 +6815,17 @@ impl fmt::Display for PutDeliverabilityDashboardOptionError {
     }
 }
 impl Error for PutDeliverabilityDashboardOptionError {}
+impl ServiceErrorCode for PutDeliverabilityDashboardOptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutDeliverabilityDashboardOptionError::AlreadyExists(_) => "AlreadyExistsException",
+            PutDeliverabilityDashboardOptionError::BadRequest(_) => "BadRequestException",
+            PutDeliverabilityDashboardOptionError::LimitExceeded(_) => "LimitExceededException",
+            PutDeliverabilityDashboardOptionError::NotFound(_) => "NotFoundException",
+            PutDeliverabilityDashboardOptionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutEmailIdentityDkimAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutEmailIdentityDkimAttributesError {
@@ -6240,6 +6878,15 @@ impl fmt::Display for PutEmailIdentityDkimAttributesError {
     }
 }
 impl Error for PutEmailIdentityDkimAttributesError {}
+impl ServiceErrorCode for PutEmailIdentityDkimAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutEmailIdentityDkimAttributesError::BadRequest(_) => "BadRequestException",
+            PutEmailIdentityDkimAttributesError::NotFound(_) => "NotFoundException",
+            PutEmailIdentityDkimAttributesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutEmailIdentityDkimSigningAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutEmailIdentityDkimSigningAttributesError {
@@ -6296,6 +6943,17 @@ impl fmt::Display for PutEmailIdentityDkimSigningAttributesError {
     }
 }
 impl Error for PutEmailIdentityDkimSigningAttributesError {}
+impl ServiceErrorCode for PutEmailIdentityDkimSigningAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutEmailIdentityDkimSigningAttributesError::BadRequest(_) => "BadRequestException",
+            PutEmailIdentityDkimSigningAttributesError::NotFound(_) => "NotFoundException",
+            PutEmailIdentityDkimSigningAttributesError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by PutEmailIdentityFeedbackAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutEmailIdentityFeedbackAttributesError {
@@ -6350,6 +7008,17 @@ impl fmt::Display for PutEmailIdentityFeedbackAttributesError {
     }
 }
 impl Error for PutEmailIdentityFeedbackAttributesError {}
+impl ServiceErrorCode for PutEmailIdentityFeedbackAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutEmailIdentityFeedbackAttributesError::BadRequest(_) => "BadRequestException",
+            PutEmailIdentityFeedbackAttributesError::NotFound(_) => "NotFoundException",
+            PutEmailIdentityFeedbackAttributesError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by PutEmailIdentityMailFromAttributes
 #[derive(Debug, PartialEq)]
 pub enum PutEmailIdentityMailFromAttributesError {
@@ -6404,6 +7073,17 @@ impl fmt::Display for PutEmailIdentityMailFromAttributesError {
     }
 }
 impl Error for PutEmailIdentityMailFromAttributesError {}
+impl ServiceErrorCode for PutEmailIdentityMailFromAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutEmailIdentityMailFromAttributesError::BadRequest(_) => "BadRequestException",
+            PutEmailIdentityMailFromAttributesError::NotFound(_) => "NotFoundException",
+            PutEmailIdentityMailFromAttributesError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by PutSuppressedDestination
 #[derive(Debug, PartialEq)]
 pub enum PutSuppressedDestinationError {
@@ -6442,6 +7122,14 @@ impl fmt::Display for PutSuppressedDestinationError {
     }
 }
 impl Error for PutSuppressedDestinationError {}
+impl ServiceErrorCode for PutSuppressedDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutSuppressedDestinationError::BadRequest(_) => "BadRequestException",
+            PutSuppressedDestinationError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by SendBulkEmail
 #[derive(Debug, PartialEq)]
 pub enum SendBulkEmailError {
@@ -6516,6 +7204,22 @@ impl fmt::Display for SendBulkEmailError {
     }
 }
 impl Error for SendBulkEmailError {}
+impl ServiceErrorCode for SendBulkEmailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendBulkEmailError::AccountSuspended(_) => "AccountSuspendedException",
+            SendBulkEmailError::BadRequest(_) => "BadRequestException",
+            SendBulkEmailError::LimitExceeded(_) => "LimitExceededException",
+            SendBulkEmailError::MailFromDomainNotVerified(_) => {
+                "MailFromDomainNotVerifiedException"
+            }
+            SendBulkEmailError::MessageRejected(_) => "MessageRejected",
+            SendBulkEmailError::NotFound(_) => "NotFoundException",
+            SendBulkEmailError::SendingPaused(_) => "SendingPausedException",
+            SendBulkEmailError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by SendCustomVerificationEmail
 #[derive(Debug, PartialEq)]
 pub enum SendCustomVerificationEmailError {
@@ -6600,6 +7304,21 @@ impl fmt::Display for SendCustomVerificationEmailError {
     }
 }
 impl Error for SendCustomVerificationEmailError {}
+impl ServiceErrorCode for SendCustomVerificationEmailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendCustomVerificationEmailError::BadRequest(_) => "BadRequestException",
+            SendCustomVerificationEmailError::LimitExceeded(_) => "LimitExceededException",
+            SendCustomVerificationEmailError::MailFromDomainNotVerified(_) => {
+                "MailFromDomainNotVerifiedException"
+            }
+            SendCustomVerificationEmailError::MessageRejected(_) => "MessageRejected",
+            SendCustomVerificationEmailError::NotFound(_) => "NotFoundException",
+            SendCustomVerificationEmailError::SendingPaused(_) => "SendingPausedException",
+            SendCustomVerificationEmailError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by SendEmail
 #[derive(Debug, PartialEq)]
 pub enum SendEmailError {
@@ -6672,6 +7391,20 @@ impl fmt::Display for SendEmailError {
     }
 }
 impl Error for SendEmailError {}
+impl ServiceErrorCode for SendEmailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendEmailError::AccountSuspended(_) => "AccountSuspendedException",
+            SendEmailError::BadRequest(_) => "BadRequestException",
+            SendEmailError::LimitExceeded(_) => "LimitExceededException",
+            SendEmailError::MailFromDomainNotVerified(_) => "MailFromDomainNotVerifiedException",
+            SendEmailError::MessageRejected(_) => "MessageRejected",
+            SendEmailError::NotFound(_) => "NotFoundException",
+            SendEmailError::SendingPaused(_) => "SendingPausedException",
+            SendEmailError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -6720,6 +7453,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+            TagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TestRenderEmailTemplate
 #[derive(Debug, PartialEq)]
 pub enum TestRenderEmailTemplateError {
@@ -6764,6 +7507,15 @@ impl fmt::Display for TestRenderEmailTemplateError {
     }
 }
 impl Error for TestRenderEmailTemplateError {}
+impl ServiceErrorCode for TestRenderEmailTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TestRenderEmailTemplateError::BadRequest(_) => "BadRequestException",
+            TestRenderEmailTemplateError::NotFound(_) => "NotFoundException",
+            TestRenderEmailTemplateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -6814,6 +7566,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+            UntagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationSetEventDestinationError {
@@ -6870,6 +7632,17 @@ impl fmt::Display for UpdateConfigurationSetEventDestinationError {
     }
 }
 impl Error for UpdateConfigurationSetEventDestinationError {}
+impl ServiceErrorCode for UpdateConfigurationSetEventDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConfigurationSetEventDestinationError::BadRequest(_) => "BadRequestException",
+            UpdateConfigurationSetEventDestinationError::NotFound(_) => "NotFoundException",
+            UpdateConfigurationSetEventDestinationError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateContact
 #[derive(Debug, PartialEq)]
 pub enum UpdateContactError {
@@ -6920,6 +7693,16 @@ impl fmt::Display for UpdateContactError {
     }
 }
 impl Error for UpdateContactError {}
+impl ServiceErrorCode for UpdateContactError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateContactError::BadRequest(_) => "BadRequestException",
+            UpdateContactError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateContactError::NotFound(_) => "NotFoundException",
+            UpdateContactError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateContactList
 #[derive(Debug, PartialEq)]
 pub enum UpdateContactListError {
@@ -6970,6 +7753,16 @@ impl fmt::Display for UpdateContactListError {
     }
 }
 impl Error for UpdateContactListError {}
+impl ServiceErrorCode for UpdateContactListError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateContactListError::BadRequest(_) => "BadRequestException",
+            UpdateContactListError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateContactListError::NotFound(_) => "NotFoundException",
+            UpdateContactListError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateCustomVerificationEmailTemplate
 #[derive(Debug, PartialEq)]
 pub enum UpdateCustomVerificationEmailTemplateError {
@@ -7026,6 +7819,17 @@ impl fmt::Display for UpdateCustomVerificationEmailTemplateError {
     }
 }
 impl Error for UpdateCustomVerificationEmailTemplateError {}
+impl ServiceErrorCode for UpdateCustomVerificationEmailTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCustomVerificationEmailTemplateError::BadRequest(_) => "BadRequestException",
+            UpdateCustomVerificationEmailTemplateError::NotFound(_) => "NotFoundException",
+            UpdateCustomVerificationEmailTemplateError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateEmailIdentityPolicy
 #[derive(Debug, PartialEq)]
 pub enum UpdateEmailIdentityPolicyError {
@@ -7072,6 +7876,15 @@ impl fmt::Display for UpdateEmailIdentityPolicyError {
     }
 }
 impl Error for UpdateEmailIdentityPolicyError {}
+impl ServiceErrorCode for UpdateEmailIdentityPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateEmailIdentityPolicyError::BadRequest(_) => "BadRequestException",
+            UpdateEmailIdentityPolicyError::NotFound(_) => "NotFoundException",
+            UpdateEmailIdentityPolicyError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateEmailTemplate
 #[derive(Debug, PartialEq)]
 pub enum UpdateEmailTemplateError {
@@ -7114,6 +7927,15 @@ impl fmt::Display for UpdateEmailTemplateError {
     }
 }
 impl Error for UpdateEmailTemplateError {}
+impl ServiceErrorCode for UpdateEmailTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateEmailTemplateError::BadRequest(_) => "BadRequestException",
+            UpdateEmailTemplateError::NotFound(_) => "NotFoundException",
+            UpdateEmailTemplateError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon SES V2 API. Amazon SES V2 clients implement this trait.
 #[async_trait]
 pub trait SesV2 {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1027,6 +1027,22 @@ impl fmt::Display for AssociateDRTLogBucketError {
     }
 }
 impl Error for AssociateDRTLogBucketError {}
+impl ServiceErrorCode for AssociateDRTLogBucketError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateDRTLogBucketError::AccessDeniedForDependency(_) => {
+                "AccessDeniedForDependencyException"
+            }
+            AssociateDRTLogBucketError::InternalError(_) => "InternalErrorException",
+            AssociateDRTLogBucketError::InvalidOperation(_) => "InvalidOperationException",
+            AssociateDRTLogBucketError::InvalidParameter(_) => "InvalidParameterException",
+            AssociateDRTLogBucketError::LimitsExceeded(_) => "LimitsExceededException",
+            AssociateDRTLogBucketError::NoAssociatedRole(_) => "NoAssociatedRoleException",
+            AssociateDRTLogBucketError::OptimisticLock(_) => "OptimisticLockException",
+            AssociateDRTLogBucketError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by AssociateDRTRole
 #[derive(Debug, PartialEq)]
 pub enum AssociateDRTRoleError {
@@ -1089,6 +1105,20 @@ impl fmt::Display for AssociateDRTRoleError {
     }
 }
 impl Error for AssociateDRTRoleError {}
+impl ServiceErrorCode for AssociateDRTRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateDRTRoleError::AccessDeniedForDependency(_) => {
+                "AccessDeniedForDependencyException"
+            }
+            AssociateDRTRoleError::InternalError(_) => "InternalErrorException",
+            AssociateDRTRoleError::InvalidOperation(_) => "InvalidOperationException",
+            AssociateDRTRoleError::InvalidParameter(_) => "InvalidParameterException",
+            AssociateDRTRoleError::OptimisticLock(_) => "OptimisticLockException",
+            AssociateDRTRoleError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by AssociateHealthCheck
 #[derive(Debug, PartialEq)]
 pub enum AssociateHealthCheckError {
@@ -1147,6 +1177,17 @@ impl fmt::Display for AssociateHealthCheckError {
     }
 }
 impl Error for AssociateHealthCheckError {}
+impl ServiceErrorCode for AssociateHealthCheckError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateHealthCheckError::InternalError(_) => "InternalErrorException",
+            AssociateHealthCheckError::InvalidParameter(_) => "InvalidParameterException",
+            AssociateHealthCheckError::LimitsExceeded(_) => "LimitsExceededException",
+            AssociateHealthCheckError::OptimisticLock(_) => "OptimisticLockException",
+            AssociateHealthCheckError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by AssociateProactiveEngagementDetails
 #[derive(Debug, PartialEq)]
 pub enum AssociateProactiveEngagementDetailsError {
@@ -1223,6 +1264,25 @@ impl fmt::Display for AssociateProactiveEngagementDetailsError {
     }
 }
 impl Error for AssociateProactiveEngagementDetailsError {}
+impl ServiceErrorCode for AssociateProactiveEngagementDetailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateProactiveEngagementDetailsError::InternalError(_) => "InternalErrorException",
+            AssociateProactiveEngagementDetailsError::InvalidOperation(_) => {
+                "InvalidOperationException"
+            }
+            AssociateProactiveEngagementDetailsError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            AssociateProactiveEngagementDetailsError::OptimisticLock(_) => {
+                "OptimisticLockException"
+            }
+            AssociateProactiveEngagementDetailsError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateProtection
 #[derive(Debug, PartialEq)]
 pub enum CreateProtectionError {
@@ -1291,6 +1351,19 @@ impl fmt::Display for CreateProtectionError {
     }
 }
 impl Error for CreateProtectionError {}
+impl ServiceErrorCode for CreateProtectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProtectionError::InternalError(_) => "InternalErrorException",
+            CreateProtectionError::InvalidOperation(_) => "InvalidOperationException",
+            CreateProtectionError::InvalidResource(_) => "InvalidResourceException",
+            CreateProtectionError::LimitsExceeded(_) => "LimitsExceededException",
+            CreateProtectionError::OptimisticLock(_) => "OptimisticLockException",
+            CreateProtectionError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateProtectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateProtectionGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateProtectionGroupError {
@@ -1361,6 +1434,20 @@ impl fmt::Display for CreateProtectionGroupError {
     }
 }
 impl Error for CreateProtectionGroupError {}
+impl ServiceErrorCode for CreateProtectionGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateProtectionGroupError::InternalError(_) => "InternalErrorException",
+            CreateProtectionGroupError::InvalidParameter(_) => "InvalidParameterException",
+            CreateProtectionGroupError::LimitsExceeded(_) => "LimitsExceededException",
+            CreateProtectionGroupError::OptimisticLock(_) => "OptimisticLockException",
+            CreateProtectionGroupError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateProtectionGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateSubscription
 #[derive(Debug, PartialEq)]
 pub enum CreateSubscriptionError {
@@ -1399,6 +1486,14 @@ impl fmt::Display for CreateSubscriptionError {
     }
 }
 impl Error for CreateSubscriptionError {}
+impl ServiceErrorCode for CreateSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSubscriptionError::InternalError(_) => "InternalErrorException",
+            CreateSubscriptionError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+        }
+    }
+}
 /// Errors returned by DeleteProtection
 #[derive(Debug, PartialEq)]
 pub enum DeleteProtectionError {
@@ -1441,6 +1536,15 @@ impl fmt::Display for DeleteProtectionError {
     }
 }
 impl Error for DeleteProtectionError {}
+impl ServiceErrorCode for DeleteProtectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProtectionError::InternalError(_) => "InternalErrorException",
+            DeleteProtectionError::OptimisticLock(_) => "OptimisticLockException",
+            DeleteProtectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteProtectionGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteProtectionGroupError {
@@ -1487,6 +1591,15 @@ impl fmt::Display for DeleteProtectionGroupError {
     }
 }
 impl Error for DeleteProtectionGroupError {}
+impl ServiceErrorCode for DeleteProtectionGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteProtectionGroupError::InternalError(_) => "InternalErrorException",
+            DeleteProtectionGroupError::OptimisticLock(_) => "OptimisticLockException",
+            DeleteProtectionGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteSubscription
 #[derive(Debug, PartialEq)]
 pub enum DeleteSubscriptionError {
@@ -1531,6 +1644,15 @@ impl fmt::Display for DeleteSubscriptionError {
     }
 }
 impl Error for DeleteSubscriptionError {}
+impl ServiceErrorCode for DeleteSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSubscriptionError::InternalError(_) => "InternalErrorException",
+            DeleteSubscriptionError::LockedSubscription(_) => "LockedSubscriptionException",
+            DeleteSubscriptionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeAttack
 #[derive(Debug, PartialEq)]
 pub enum DescribeAttackError {
@@ -1567,6 +1689,14 @@ impl fmt::Display for DescribeAttackError {
     }
 }
 impl Error for DescribeAttackError {}
+impl ServiceErrorCode for DescribeAttackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAttackError::AccessDenied(_) => "AccessDeniedException",
+            DescribeAttackError::InternalError(_) => "InternalErrorException",
+        }
+    }
+}
 /// Errors returned by DescribeAttackStatistics
 #[derive(Debug, PartialEq)]
 pub enum DescribeAttackStatisticsError {
@@ -1599,6 +1729,13 @@ impl fmt::Display for DescribeAttackStatisticsError {
     }
 }
 impl Error for DescribeAttackStatisticsError {}
+impl ServiceErrorCode for DescribeAttackStatisticsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAttackStatisticsError::InternalError(_) => "InternalErrorException",
+        }
+    }
+}
 /// Errors returned by DescribeDRTAccess
 #[derive(Debug, PartialEq)]
 pub enum DescribeDRTAccessError {
@@ -1635,6 +1772,14 @@ impl fmt::Display for DescribeDRTAccessError {
     }
 }
 impl Error for DescribeDRTAccessError {}
+impl ServiceErrorCode for DescribeDRTAccessError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDRTAccessError::InternalError(_) => "InternalErrorException",
+            DescribeDRTAccessError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeEmergencyContactSettings
 #[derive(Debug, PartialEq)]
 pub enum DescribeEmergencyContactSettingsError {
@@ -1681,6 +1826,16 @@ impl fmt::Display for DescribeEmergencyContactSettingsError {
     }
 }
 impl Error for DescribeEmergencyContactSettingsError {}
+impl ServiceErrorCode for DescribeEmergencyContactSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEmergencyContactSettingsError::InternalError(_) => "InternalErrorException",
+            DescribeEmergencyContactSettingsError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeProtection
 #[derive(Debug, PartialEq)]
 pub enum DescribeProtectionError {
@@ -1723,6 +1878,15 @@ impl fmt::Display for DescribeProtectionError {
     }
 }
 impl Error for DescribeProtectionError {}
+impl ServiceErrorCode for DescribeProtectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProtectionError::InternalError(_) => "InternalErrorException",
+            DescribeProtectionError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeProtectionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeProtectionGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeProtectionGroupError {
@@ -1763,6 +1927,14 @@ impl fmt::Display for DescribeProtectionGroupError {
     }
 }
 impl Error for DescribeProtectionGroupError {}
+impl ServiceErrorCode for DescribeProtectionGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeProtectionGroupError::InternalError(_) => "InternalErrorException",
+            DescribeProtectionGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeSubscription
 #[derive(Debug, PartialEq)]
 pub enum DescribeSubscriptionError {
@@ -1801,6 +1973,14 @@ impl fmt::Display for DescribeSubscriptionError {
     }
 }
 impl Error for DescribeSubscriptionError {}
+impl ServiceErrorCode for DescribeSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSubscriptionError::InternalError(_) => "InternalErrorException",
+            DescribeSubscriptionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisableProactiveEngagement
 #[derive(Debug, PartialEq)]
 pub enum DisableProactiveEngagementError {
@@ -1867,6 +2047,17 @@ impl fmt::Display for DisableProactiveEngagementError {
     }
 }
 impl Error for DisableProactiveEngagementError {}
+impl ServiceErrorCode for DisableProactiveEngagementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableProactiveEngagementError::InternalError(_) => "InternalErrorException",
+            DisableProactiveEngagementError::InvalidOperation(_) => "InvalidOperationException",
+            DisableProactiveEngagementError::InvalidParameter(_) => "InvalidParameterException",
+            DisableProactiveEngagementError::OptimisticLock(_) => "OptimisticLockException",
+            DisableProactiveEngagementError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateDRTLogBucket
 #[derive(Debug, PartialEq)]
 pub enum DisassociateDRTLogBucketError {
@@ -1941,6 +2132,20 @@ impl fmt::Display for DisassociateDRTLogBucketError {
     }
 }
 impl Error for DisassociateDRTLogBucketError {}
+impl ServiceErrorCode for DisassociateDRTLogBucketError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateDRTLogBucketError::AccessDeniedForDependency(_) => {
+                "AccessDeniedForDependencyException"
+            }
+            DisassociateDRTLogBucketError::InternalError(_) => "InternalErrorException",
+            DisassociateDRTLogBucketError::InvalidOperation(_) => "InvalidOperationException",
+            DisassociateDRTLogBucketError::NoAssociatedRole(_) => "NoAssociatedRoleException",
+            DisassociateDRTLogBucketError::OptimisticLock(_) => "OptimisticLockException",
+            DisassociateDRTLogBucketError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateDRTRole
 #[derive(Debug, PartialEq)]
 pub enum DisassociateDRTRoleError {
@@ -1993,6 +2198,16 @@ impl fmt::Display for DisassociateDRTRoleError {
     }
 }
 impl Error for DisassociateDRTRoleError {}
+impl ServiceErrorCode for DisassociateDRTRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateDRTRoleError::InternalError(_) => "InternalErrorException",
+            DisassociateDRTRoleError::InvalidOperation(_) => "InvalidOperationException",
+            DisassociateDRTRoleError::OptimisticLock(_) => "OptimisticLockException",
+            DisassociateDRTRoleError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateHealthCheck
 #[derive(Debug, PartialEq)]
 pub enum DisassociateHealthCheckError {
@@ -2049,6 +2264,16 @@ impl fmt::Display for DisassociateHealthCheckError {
     }
 }
 impl Error for DisassociateHealthCheckError {}
+impl ServiceErrorCode for DisassociateHealthCheckError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateHealthCheckError::InternalError(_) => "InternalErrorException",
+            DisassociateHealthCheckError::InvalidParameter(_) => "InvalidParameterException",
+            DisassociateHealthCheckError::OptimisticLock(_) => "OptimisticLockException",
+            DisassociateHealthCheckError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by EnableProactiveEngagement
 #[derive(Debug, PartialEq)]
 pub enum EnableProactiveEngagementError {
@@ -2113,6 +2338,17 @@ impl fmt::Display for EnableProactiveEngagementError {
     }
 }
 impl Error for EnableProactiveEngagementError {}
+impl ServiceErrorCode for EnableProactiveEngagementError {
+    fn error_code(&self) -> &str {
+        match *self {
+            EnableProactiveEngagementError::InternalError(_) => "InternalErrorException",
+            EnableProactiveEngagementError::InvalidOperation(_) => "InvalidOperationException",
+            EnableProactiveEngagementError::InvalidParameter(_) => "InvalidParameterException",
+            EnableProactiveEngagementError::OptimisticLock(_) => "OptimisticLockException",
+            EnableProactiveEngagementError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetSubscriptionState
 #[derive(Debug, PartialEq)]
 pub enum GetSubscriptionStateError {
@@ -2143,6 +2379,13 @@ impl fmt::Display for GetSubscriptionStateError {
     }
 }
 impl Error for GetSubscriptionStateError {}
+impl ServiceErrorCode for GetSubscriptionStateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSubscriptionStateError::InternalError(_) => "InternalErrorException",
+        }
+    }
+}
 /// Errors returned by ListAttacks
 #[derive(Debug, PartialEq)]
 pub enum ListAttacksError {
@@ -2185,6 +2428,15 @@ impl fmt::Display for ListAttacksError {
     }
 }
 impl Error for ListAttacksError {}
+impl ServiceErrorCode for ListAttacksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAttacksError::InternalError(_) => "InternalErrorException",
+            ListAttacksError::InvalidOperation(_) => "InvalidOperationException",
+            ListAttacksError::InvalidParameter(_) => "InvalidParameterException",
+        }
+    }
+}
 /// Errors returned by ListProtectionGroups
 #[derive(Debug, PartialEq)]
 pub enum ListProtectionGroupsError {
@@ -2231,6 +2483,17 @@ impl fmt::Display for ListProtectionGroupsError {
     }
 }
 impl Error for ListProtectionGroupsError {}
+impl ServiceErrorCode for ListProtectionGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProtectionGroupsError::InternalError(_) => "InternalErrorException",
+            ListProtectionGroupsError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            ListProtectionGroupsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListProtections
 #[derive(Debug, PartialEq)]
 pub enum ListProtectionsError {
@@ -2275,6 +2538,15 @@ impl fmt::Display for ListProtectionsError {
     }
 }
 impl Error for ListProtectionsError {}
+impl ServiceErrorCode for ListProtectionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProtectionsError::InternalError(_) => "InternalErrorException",
+            ListProtectionsError::InvalidPaginationToken(_) => "InvalidPaginationTokenException",
+            ListProtectionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListResourcesInProtectionGroup
 #[derive(Debug, PartialEq)]
 pub enum ListResourcesInProtectionGroupError {
@@ -2329,6 +2601,17 @@ impl fmt::Display for ListResourcesInProtectionGroupError {
     }
 }
 impl Error for ListResourcesInProtectionGroupError {}
+impl ServiceErrorCode for ListResourcesInProtectionGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourcesInProtectionGroupError::InternalError(_) => "InternalErrorException",
+            ListResourcesInProtectionGroupError::InvalidPaginationToken(_) => {
+                "InvalidPaginationTokenException"
+            }
+            ListResourcesInProtectionGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateEmergencyContactSettings
 #[derive(Debug, PartialEq)]
 pub enum UpdateEmergencyContactSettingsError {
@@ -2393,6 +2676,16 @@ impl fmt::Display for UpdateEmergencyContactSettingsError {
     }
 }
 impl Error for UpdateEmergencyContactSettingsError {}
+impl ServiceErrorCode for UpdateEmergencyContactSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateEmergencyContactSettingsError::InternalError(_) => "InternalErrorException",
+            UpdateEmergencyContactSettingsError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateEmergencyContactSettingsError::OptimisticLock(_) => "OptimisticLockException",
+            UpdateEmergencyContactSettingsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateProtectionGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateProtectionGroupError {
@@ -2447,6 +2740,16 @@ impl fmt::Display for UpdateProtectionGroupError {
     }
 }
 impl Error for UpdateProtectionGroupError {}
+impl ServiceErrorCode for UpdateProtectionGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateProtectionGroupError::InternalError(_) => "InternalErrorException",
+            UpdateProtectionGroupError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateProtectionGroupError::OptimisticLock(_) => "OptimisticLockException",
+            UpdateProtectionGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateSubscription
 #[derive(Debug, PartialEq)]
 pub enum UpdateSubscriptionError {
@@ -2503,6 +2806,17 @@ impl fmt::Display for UpdateSubscriptionError {
     }
 }
 impl Error for UpdateSubscriptionError {}
+impl ServiceErrorCode for UpdateSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSubscriptionError::InternalError(_) => "InternalErrorException",
+            UpdateSubscriptionError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateSubscriptionError::LockedSubscription(_) => "LockedSubscriptionException",
+            UpdateSubscriptionError::OptimisticLock(_) => "OptimisticLockException",
+            UpdateSubscriptionError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Shield API. AWS Shield clients implement this trait.
 #[async_trait]
 pub trait Shield {
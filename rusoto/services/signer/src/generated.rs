@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -940,6 +940,7 @@ pub struct Source {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartSigningJobRequest {
     /// <p>String that identifies the signing request. All calls after the first that use this token return the same response as the first call.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "clientRequestToken")]
     pub client_request_token: String,
     /// <p>The S3 bucket in which to save your signed object. The destination contains the name of your bucket and an optional prefix.</p>
@@ -1068,6 +1069,18 @@ impl fmt::Display for AddProfilePermissionError {
     }
 }
 impl Error for AddProfilePermissionError {}
+impl ServiceErrorCode for AddProfilePermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddProfilePermissionError::AccessDenied(_) => "AccessDeniedException",
+            AddProfilePermissionError::Conflict(_) => "ConflictException",
+            AddProfilePermissionError::InternalServiceError(_) => "InternalServiceErrorException",
+            AddProfilePermissionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AddProfilePermissionError::ServiceLimitExceeded(_) => "ServiceLimitExceededException",
+            AddProfilePermissionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CancelSigningProfile
 #[derive(Debug, PartialEq)]
 pub enum CancelSigningProfileError {
@@ -1122,6 +1135,16 @@ impl fmt::Display for CancelSigningProfileError {
     }
 }
 impl Error for CancelSigningProfileError {}
+impl ServiceErrorCode for CancelSigningProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelSigningProfileError::AccessDenied(_) => "AccessDeniedException",
+            CancelSigningProfileError::InternalServiceError(_) => "InternalServiceErrorException",
+            CancelSigningProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CancelSigningProfileError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeSigningJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeSigningJobError {
@@ -1172,6 +1195,16 @@ impl fmt::Display for DescribeSigningJobError {
     }
 }
 impl Error for DescribeSigningJobError {}
+impl ServiceErrorCode for DescribeSigningJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSigningJobError::AccessDenied(_) => "AccessDeniedException",
+            DescribeSigningJobError::InternalServiceError(_) => "InternalServiceErrorException",
+            DescribeSigningJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeSigningJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetSigningPlatform
 #[derive(Debug, PartialEq)]
 pub enum GetSigningPlatformError {
@@ -1222,6 +1255,16 @@ impl fmt::Display for GetSigningPlatformError {
     }
 }
 impl Error for GetSigningPlatformError {}
+impl ServiceErrorCode for GetSigningPlatformError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSigningPlatformError::AccessDenied(_) => "AccessDeniedException",
+            GetSigningPlatformError::InternalServiceError(_) => "InternalServiceErrorException",
+            GetSigningPlatformError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetSigningPlatformError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetSigningProfile
 #[derive(Debug, PartialEq)]
 pub enum GetSigningProfileError {
@@ -1272,6 +1315,16 @@ impl fmt::Display for GetSigningProfileError {
     }
 }
 impl Error for GetSigningProfileError {}
+impl ServiceErrorCode for GetSigningProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSigningProfileError::AccessDenied(_) => "AccessDeniedException",
+            GetSigningProfileError::InternalServiceError(_) => "InternalServiceErrorException",
+            GetSigningProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetSigningProfileError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListProfilePermissions
 #[derive(Debug, PartialEq)]
 pub enum ListProfilePermissionsError {
@@ -1326,6 +1379,16 @@ impl fmt::Display for ListProfilePermissionsError {
     }
 }
 impl Error for ListProfilePermissionsError {}
+impl ServiceErrorCode for ListProfilePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListProfilePermissionsError::AccessDenied(_) => "AccessDeniedException",
+            ListProfilePermissionsError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListProfilePermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListProfilePermissionsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListSigningJobs
 #[derive(Debug, PartialEq)]
 pub enum ListSigningJobsError {
@@ -1370,6 +1433,15 @@ impl fmt::Display for ListSigningJobsError {
     }
 }
 impl Error for ListSigningJobsError {}
+impl ServiceErrorCode for ListSigningJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSigningJobsError::AccessDenied(_) => "AccessDeniedException",
+            ListSigningJobsError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListSigningJobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListSigningPlatforms
 #[derive(Debug, PartialEq)]
 pub enum ListSigningPlatformsError {
@@ -1416,6 +1488,15 @@ impl fmt::Display for ListSigningPlatformsError {
     }
 }
 impl Error for ListSigningPlatformsError {}
+impl ServiceErrorCode for ListSigningPlatformsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSigningPlatformsError::AccessDenied(_) => "AccessDeniedException",
+            ListSigningPlatformsError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListSigningPlatformsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListSigningProfiles
 #[derive(Debug, PartialEq)]
 pub enum ListSigningProfilesError {
@@ -1460,6 +1541,15 @@ impl fmt::Display for ListSigningProfilesError {
     }
 }
 impl Error for ListSigningProfilesError {}
+impl ServiceErrorCode for ListSigningProfilesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSigningProfilesError::AccessDenied(_) => "AccessDeniedException",
+            ListSigningProfilesError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListSigningProfilesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1510,6 +1600,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::BadRequest(_) => "BadRequestException",
+            ListTagsForResourceError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListTagsForResourceError::NotFound(_) => "NotFoundException",
+            ListTagsForResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by PutSigningProfile
 #[derive(Debug, PartialEq)]
 pub enum PutSigningProfileError {
@@ -1560,6 +1660,16 @@ impl fmt::Display for PutSigningProfileError {
     }
 }
 impl Error for PutSigningProfileError {}
+impl ServiceErrorCode for PutSigningProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutSigningProfileError::AccessDenied(_) => "AccessDeniedException",
+            PutSigningProfileError::InternalServiceError(_) => "InternalServiceErrorException",
+            PutSigningProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            PutSigningProfileError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by RemoveProfilePermission
 #[derive(Debug, PartialEq)]
 pub enum RemoveProfilePermissionError {
@@ -1622,6 +1732,19 @@ impl fmt::Display for RemoveProfilePermissionError {
     }
 }
 impl Error for RemoveProfilePermissionError {}
+impl ServiceErrorCode for RemoveProfilePermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveProfilePermissionError::AccessDenied(_) => "AccessDeniedException",
+            RemoveProfilePermissionError::Conflict(_) => "ConflictException",
+            RemoveProfilePermissionError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            RemoveProfilePermissionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RemoveProfilePermissionError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by RevokeSignature
 #[derive(Debug, PartialEq)]
 pub enum RevokeSignatureError {
@@ -1672,6 +1795,16 @@ impl fmt::Display for RevokeSignatureError {
     }
 }
 impl Error for RevokeSignatureError {}
+impl ServiceErrorCode for RevokeSignatureError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RevokeSignatureError::AccessDenied(_) => "AccessDeniedException",
+            RevokeSignatureError::InternalServiceError(_) => "InternalServiceErrorException",
+            RevokeSignatureError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RevokeSignatureError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by RevokeSigningProfile
 #[derive(Debug, PartialEq)]
 pub enum RevokeSigningProfileError {
@@ -1726,6 +1859,16 @@ impl fmt::Display for RevokeSigningProfileError {
     }
 }
 impl Error for RevokeSigningProfileError {}
+impl ServiceErrorCode for RevokeSigningProfileError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RevokeSigningProfileError::AccessDenied(_) => "AccessDeniedException",
+            RevokeSigningProfileError::InternalServiceError(_) => "InternalServiceErrorException",
+            RevokeSigningProfileError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RevokeSigningProfileError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StartSigningJob
 #[derive(Debug, PartialEq)]
 pub enum StartSigningJobError {
@@ -1782,6 +1925,17 @@ impl fmt::Display for StartSigningJobError {
     }
 }
 impl Error for StartSigningJobError {}
+impl ServiceErrorCode for StartSigningJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartSigningJobError::AccessDenied(_) => "AccessDeniedException",
+            StartSigningJobError::InternalServiceError(_) => "InternalServiceErrorException",
+            StartSigningJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartSigningJobError::Throttling(_) => "ThrottlingException",
+            StartSigningJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1830,6 +1984,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::BadRequest(_) => "BadRequestException",
+            TagResourceError::InternalServiceError(_) => "InternalServiceErrorException",
+            TagResourceError::NotFound(_) => "NotFoundException",
+            TagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1878,6 +2042,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::BadRequest(_) => "BadRequestException",
+            UntagResourceError::InternalServiceError(_) => "InternalServiceErrorException",
+            UntagResourceError::NotFound(_) => "NotFoundException",
+            UntagResourceError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the signer API. signer clients implement this trait.
 #[async_trait]
 pub trait Signer {
@@ -2539,6 +2713,12 @@ impl Signer for SignerClient {
         &self,
         input: StartSigningJobRequest,
     ) -> Result<StartSigningJobResponse, RusotoError<StartSigningJobError>> {
+        let mut input = input;
+        if input.client_request_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_request_token = token;
+            }
+        }
         let request_uri = "/signing-jobs";
 
         let mut request = SignedRequest::new("POST", "signer", &self.region, &request_uri);
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -395,6 +395,17 @@ impl fmt::Display for CreateConfigurationSetError {
     }
 }
 impl Error for CreateConfigurationSetError {}
+impl ServiceErrorCode for CreateConfigurationSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConfigurationSetError::AlreadyExists(_) => "AlreadyExistsException",
+            CreateConfigurationSetError::BadRequest(_) => "BadRequestException",
+            CreateConfigurationSetError::InternalServiceError(_) => "InternalServiceErrorException",
+            CreateConfigurationSetError::LimitExceeded(_) => "LimitExceededException",
+            CreateConfigurationSetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by CreateConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
 pub enum CreateConfigurationSetEventDestinationError {
@@ -481,6 +492,26 @@ impl fmt::Display for CreateConfigurationSetEventDestinationError {
     }
 }
 impl Error for CreateConfigurationSetEventDestinationError {}
+impl ServiceErrorCode for CreateConfigurationSetEventDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConfigurationSetEventDestinationError::AlreadyExists(_) => {
+                "AlreadyExistsException"
+            }
+            CreateConfigurationSetEventDestinationError::BadRequest(_) => "BadRequestException",
+            CreateConfigurationSetEventDestinationError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            CreateConfigurationSetEventDestinationError::LimitExceeded(_) => {
+                "LimitExceededException"
+            }
+            CreateConfigurationSetEventDestinationError::NotFound(_) => "NotFoundException",
+            CreateConfigurationSetEventDestinationError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteConfigurationSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationSetError {
@@ -533,6 +564,16 @@ impl fmt::Display for DeleteConfigurationSetError {
     }
 }
 impl Error for DeleteConfigurationSetError {}
+impl ServiceErrorCode for DeleteConfigurationSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigurationSetError::BadRequest(_) => "BadRequestException",
+            DeleteConfigurationSetError::InternalServiceError(_) => "InternalServiceErrorException",
+            DeleteConfigurationSetError::NotFound(_) => "NotFoundException",
+            DeleteConfigurationSetError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
 pub enum DeleteConfigurationSetEventDestinationError {
@@ -599,6 +640,20 @@ impl fmt::Display for DeleteConfigurationSetEventDestinationError {
     }
 }
 impl Error for DeleteConfigurationSetEventDestinationError {}
+impl ServiceErrorCode for DeleteConfigurationSetEventDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConfigurationSetEventDestinationError::BadRequest(_) => "BadRequestException",
+            DeleteConfigurationSetEventDestinationError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            DeleteConfigurationSetEventDestinationError::NotFound(_) => "NotFoundException",
+            DeleteConfigurationSetEventDestinationError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by GetConfigurationSetEventDestinations
 #[derive(Debug, PartialEq)]
 pub enum GetConfigurationSetEventDestinationsError {
@@ -665,6 +720,20 @@ impl fmt::Display for GetConfigurationSetEventDestinationsError {
     }
 }
 impl Error for GetConfigurationSetEventDestinationsError {}
+impl ServiceErrorCode for GetConfigurationSetEventDestinationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConfigurationSetEventDestinationsError::BadRequest(_) => "BadRequestException",
+            GetConfigurationSetEventDestinationsError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            GetConfigurationSetEventDestinationsError::NotFound(_) => "NotFoundException",
+            GetConfigurationSetEventDestinationsError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Errors returned by ListConfigurationSets
 #[derive(Debug, PartialEq)]
 pub enum ListConfigurationSetsError {
@@ -711,6 +780,15 @@ impl fmt::Display for ListConfigurationSetsError {
     }
 }
 impl Error for ListConfigurationSetsError {}
+impl ServiceErrorCode for ListConfigurationSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListConfigurationSetsError::BadRequest(_) => "BadRequestException",
+            ListConfigurationSetsError::InternalServiceError(_) => "InternalServiceErrorException",
+            ListConfigurationSetsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by SendVoiceMessage
 #[derive(Debug, PartialEq)]
 pub enum SendVoiceMessageError {
@@ -755,6 +833,15 @@ impl fmt::Display for SendVoiceMessageError {
     }
 }
 impl Error for SendVoiceMessageError {}
+impl ServiceErrorCode for SendVoiceMessageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendVoiceMessageError::BadRequest(_) => "BadRequestException",
+            SendVoiceMessageError::InternalServiceError(_) => "InternalServiceErrorException",
+            SendVoiceMessageError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by UpdateConfigurationSetEventDestination
 #[derive(Debug, PartialEq)]
 pub enum UpdateConfigurationSetEventDestinationError {
@@ -821,6 +908,20 @@ impl fmt::Display for UpdateConfigurationSetEventDestinationError {
     }
 }
 impl Error for UpdateConfigurationSetEventDestinationError {}
+impl ServiceErrorCode for UpdateConfigurationSetEventDestinationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConfigurationSetEventDestinationError::BadRequest(_) => "BadRequestException",
+            UpdateConfigurationSetEventDestinationError::InternalServiceError(_) => {
+                "InternalServiceErrorException"
+            }
+            UpdateConfigurationSetEventDestinationError::NotFound(_) => "NotFoundException",
+            UpdateConfigurationSetEventDestinationError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the Pinpoint SMS Voice API. Pinpoint SMS Voice clients implement this trait.
 #[async_trait]
 pub trait SmsVoice {
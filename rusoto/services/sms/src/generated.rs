@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1603,6 +1603,17 @@ impl fmt::Display for CreateAppError {
     }
 }
 impl Error for CreateAppError {}
+impl ServiceErrorCode for CreateAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAppError::InternalError(_) => "InternalError",
+            CreateAppError::InvalidParameter(_) => "InvalidParameterException",
+            CreateAppError::MissingRequiredParameter(_) => "MissingRequiredParameterException",
+            CreateAppError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            CreateAppError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by CreateReplicationJob
 #[derive(Debug, PartialEq)]
 pub enum CreateReplicationJobError {
@@ -1703,6 +1714,29 @@ impl fmt::Display for CreateReplicationJobError {
     }
 }
 impl Error for CreateReplicationJobError {}
+impl ServiceErrorCode for CreateReplicationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateReplicationJobError::InternalError(_) => "InternalError",
+            CreateReplicationJobError::InvalidParameter(_) => "InvalidParameterException",
+            CreateReplicationJobError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            CreateReplicationJobError::NoConnectorsAvailable(_) => "NoConnectorsAvailableException",
+            CreateReplicationJobError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            CreateReplicationJobError::ReplicationJobAlreadyExists(_) => {
+                "ReplicationJobAlreadyExistsException"
+            }
+            CreateReplicationJobError::ServerCannotBeReplicated(_) => {
+                "ServerCannotBeReplicatedException"
+            }
+            CreateReplicationJobError::TemporarilyUnavailable(_) => {
+                "TemporarilyUnavailableException"
+            }
+            CreateReplicationJobError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by DeleteApp
 #[derive(Debug, PartialEq)]
 pub enum DeleteAppError {
@@ -1757,6 +1791,17 @@ impl fmt::Display for DeleteAppError {
     }
 }
 impl Error for DeleteAppError {}
+impl ServiceErrorCode for DeleteAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAppError::InternalError(_) => "InternalError",
+            DeleteAppError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteAppError::MissingRequiredParameter(_) => "MissingRequiredParameterException",
+            DeleteAppError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            DeleteAppError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by DeleteAppLaunchConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteAppLaunchConfigurationError {
@@ -1831,6 +1876,23 @@ impl fmt::Display for DeleteAppLaunchConfigurationError {
     }
 }
 impl Error for DeleteAppLaunchConfigurationError {}
+impl ServiceErrorCode for DeleteAppLaunchConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAppLaunchConfigurationError::InternalError(_) => "InternalError",
+            DeleteAppLaunchConfigurationError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteAppLaunchConfigurationError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            DeleteAppLaunchConfigurationError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            DeleteAppLaunchConfigurationError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteAppReplicationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteAppReplicationConfigurationError {
@@ -1907,6 +1969,25 @@ impl fmt::Display for DeleteAppReplicationConfigurationError {
     }
 }
 impl Error for DeleteAppReplicationConfigurationError {}
+impl ServiceErrorCode for DeleteAppReplicationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAppReplicationConfigurationError::InternalError(_) => "InternalError",
+            DeleteAppReplicationConfigurationError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            DeleteAppReplicationConfigurationError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            DeleteAppReplicationConfigurationError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            DeleteAppReplicationConfigurationError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteAppValidationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteAppValidationConfigurationError {
@@ -1983,6 +2064,25 @@ impl fmt::Display for DeleteAppValidationConfigurationError {
     }
 }
 impl Error for DeleteAppValidationConfigurationError {}
+impl ServiceErrorCode for DeleteAppValidationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAppValidationConfigurationError::InternalError(_) => "InternalError",
+            DeleteAppValidationConfigurationError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            DeleteAppValidationConfigurationError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            DeleteAppValidationConfigurationError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            DeleteAppValidationConfigurationError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteReplicationJob
 #[derive(Debug, PartialEq)]
 pub enum DeleteReplicationJobError {
@@ -2049,6 +2149,21 @@ impl fmt::Display for DeleteReplicationJobError {
     }
 }
 impl Error for DeleteReplicationJobError {}
+impl ServiceErrorCode for DeleteReplicationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteReplicationJobError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteReplicationJobError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            DeleteReplicationJobError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            DeleteReplicationJobError::ReplicationJobNotFound(_) => {
+                "ReplicationJobNotFoundException"
+            }
+            DeleteReplicationJobError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by DeleteServerCatalog
 #[derive(Debug, PartialEq)]
 pub enum DeleteServerCatalogError {
@@ -2105,6 +2220,18 @@ impl fmt::Display for DeleteServerCatalogError {
     }
 }
 impl Error for DeleteServerCatalogError {}
+impl ServiceErrorCode for DeleteServerCatalogError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteServerCatalogError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteServerCatalogError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            DeleteServerCatalogError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            DeleteServerCatalogError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by DisassociateConnector
 #[derive(Debug, PartialEq)]
 pub enum DisassociateConnectorError {
@@ -2163,6 +2290,22 @@ impl fmt::Display for DisassociateConnectorError {
     }
 }
 impl Error for DisassociateConnectorError {}
+impl ServiceErrorCode for DisassociateConnectorError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateConnectorError::InvalidParameter(_) => "InvalidParameterException",
+            DisassociateConnectorError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            DisassociateConnectorError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            DisassociateConnectorError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by GenerateChangeSet
 #[derive(Debug, PartialEq)]
 pub enum GenerateChangeSetError {
@@ -2223,6 +2366,19 @@ impl fmt::Display for GenerateChangeSetError {
     }
 }
 impl Error for GenerateChangeSetError {}
+impl ServiceErrorCode for GenerateChangeSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GenerateChangeSetError::InternalError(_) => "InternalError",
+            GenerateChangeSetError::InvalidParameter(_) => "InvalidParameterException",
+            GenerateChangeSetError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            GenerateChangeSetError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            GenerateChangeSetError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by GenerateTemplate
 #[derive(Debug, PartialEq)]
 pub enum GenerateTemplateError {
@@ -2283,6 +2439,19 @@ impl fmt::Display for GenerateTemplateError {
     }
 }
 impl Error for GenerateTemplateError {}
+impl ServiceErrorCode for GenerateTemplateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GenerateTemplateError::InternalError(_) => "InternalError",
+            GenerateTemplateError::InvalidParameter(_) => "InvalidParameterException",
+            GenerateTemplateError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            GenerateTemplateError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            GenerateTemplateError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by GetApp
 #[derive(Debug, PartialEq)]
 pub enum GetAppError {
@@ -2337,6 +2506,17 @@ impl fmt::Display for GetAppError {
     }
 }
 impl Error for GetAppError {}
+impl ServiceErrorCode for GetAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAppError::InternalError(_) => "InternalError",
+            GetAppError::InvalidParameter(_) => "InvalidParameterException",
+            GetAppError::MissingRequiredParameter(_) => "MissingRequiredParameterException",
+            GetAppError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            GetAppError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by GetAppLaunchConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetAppLaunchConfigurationError {
@@ -2407,6 +2587,23 @@ impl fmt::Display for GetAppLaunchConfigurationError {
     }
 }
 impl Error for GetAppLaunchConfigurationError {}
+impl ServiceErrorCode for GetAppLaunchConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAppLaunchConfigurationError::InternalError(_) => "InternalError",
+            GetAppLaunchConfigurationError::InvalidParameter(_) => "InvalidParameterException",
+            GetAppLaunchConfigurationError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            GetAppLaunchConfigurationError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            GetAppLaunchConfigurationError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAppReplicationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetAppReplicationConfigurationError {
@@ -2481,6 +2678,23 @@ impl fmt::Display for GetAppReplicationConfigurationError {
     }
 }
 impl Error for GetAppReplicationConfigurationError {}
+impl ServiceErrorCode for GetAppReplicationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAppReplicationConfigurationError::InternalError(_) => "InternalError",
+            GetAppReplicationConfigurationError::InvalidParameter(_) => "InvalidParameterException",
+            GetAppReplicationConfigurationError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            GetAppReplicationConfigurationError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            GetAppReplicationConfigurationError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAppValidationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetAppValidationConfigurationError {
@@ -2555,6 +2769,23 @@ impl fmt::Display for GetAppValidationConfigurationError {
     }
 }
 impl Error for GetAppValidationConfigurationError {}
+impl ServiceErrorCode for GetAppValidationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAppValidationConfigurationError::InternalError(_) => "InternalError",
+            GetAppValidationConfigurationError::InvalidParameter(_) => "InvalidParameterException",
+            GetAppValidationConfigurationError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            GetAppValidationConfigurationError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            GetAppValidationConfigurationError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAppValidationOutput
 #[derive(Debug, PartialEq)]
 pub enum GetAppValidationOutputError {
@@ -2621,6 +2852,23 @@ impl fmt::Display for GetAppValidationOutputError {
     }
 }
 impl Error for GetAppValidationOutputError {}
+impl ServiceErrorCode for GetAppValidationOutputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAppValidationOutputError::InternalError(_) => "InternalError",
+            GetAppValidationOutputError::InvalidParameter(_) => "InvalidParameterException",
+            GetAppValidationOutputError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            GetAppValidationOutputError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            GetAppValidationOutputError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by GetConnectors
 #[derive(Debug, PartialEq)]
 pub enum GetConnectorsError {
@@ -2651,6 +2899,13 @@ impl fmt::Display for GetConnectorsError {
     }
 }
 impl Error for GetConnectorsError {}
+impl ServiceErrorCode for GetConnectorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConnectorsError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by GetReplicationJobs
 #[derive(Debug, PartialEq)]
 pub enum GetReplicationJobsError {
@@ -2697,6 +2952,17 @@ impl fmt::Display for GetReplicationJobsError {
     }
 }
 impl Error for GetReplicationJobsError {}
+impl ServiceErrorCode for GetReplicationJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetReplicationJobsError::InvalidParameter(_) => "InvalidParameterException",
+            GetReplicationJobsError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            GetReplicationJobsError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by GetReplicationRuns
 #[derive(Debug, PartialEq)]
 pub enum GetReplicationRunsError {
@@ -2743,6 +3009,17 @@ impl fmt::Display for GetReplicationRunsError {
     }
 }
 impl Error for GetReplicationRunsError {}
+impl ServiceErrorCode for GetReplicationRunsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetReplicationRunsError::InvalidParameter(_) => "InvalidParameterException",
+            GetReplicationRunsError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            GetReplicationRunsError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by GetServers
 #[derive(Debug, PartialEq)]
 pub enum GetServersError {
@@ -2791,6 +3068,16 @@ impl fmt::Display for GetServersError {
     }
 }
 impl Error for GetServersError {}
+impl ServiceErrorCode for GetServersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetServersError::InternalError(_) => "InternalError",
+            GetServersError::InvalidParameter(_) => "InvalidParameterException",
+            GetServersError::MissingRequiredParameter(_) => "MissingRequiredParameterException",
+            GetServersError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by ImportAppCatalog
 #[derive(Debug, PartialEq)]
 pub enum ImportAppCatalogError {
@@ -2851,6 +3138,19 @@ impl fmt::Display for ImportAppCatalogError {
     }
 }
 impl Error for ImportAppCatalogError {}
+impl ServiceErrorCode for ImportAppCatalogError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportAppCatalogError::InternalError(_) => "InternalError",
+            ImportAppCatalogError::InvalidParameter(_) => "InvalidParameterException",
+            ImportAppCatalogError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            ImportAppCatalogError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            ImportAppCatalogError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by ImportServerCatalog
 #[derive(Debug, PartialEq)]
 pub enum ImportServerCatalogError {
@@ -2915,6 +3215,19 @@ impl fmt::Display for ImportServerCatalogError {
     }
 }
 impl Error for ImportServerCatalogError {}
+impl ServiceErrorCode for ImportServerCatalogError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportServerCatalogError::InvalidParameter(_) => "InvalidParameterException",
+            ImportServerCatalogError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            ImportServerCatalogError::NoConnectorsAvailable(_) => "NoConnectorsAvailableException",
+            ImportServerCatalogError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            ImportServerCatalogError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by LaunchApp
 #[derive(Debug, PartialEq)]
 pub enum LaunchAppError {
@@ -2969,6 +3282,17 @@ impl fmt::Display for LaunchAppError {
     }
 }
 impl Error for LaunchAppError {}
+impl ServiceErrorCode for LaunchAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            LaunchAppError::InternalError(_) => "InternalError",
+            LaunchAppError::InvalidParameter(_) => "InvalidParameterException",
+            LaunchAppError::MissingRequiredParameter(_) => "MissingRequiredParameterException",
+            LaunchAppError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            LaunchAppError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by ListApps
 #[derive(Debug, PartialEq)]
 pub enum ListAppsError {
@@ -3023,6 +3347,17 @@ impl fmt::Display for ListAppsError {
     }
 }
 impl Error for ListAppsError {}
+impl ServiceErrorCode for ListAppsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAppsError::InternalError(_) => "InternalError",
+            ListAppsError::InvalidParameter(_) => "InvalidParameterException",
+            ListAppsError::MissingRequiredParameter(_) => "MissingRequiredParameterException",
+            ListAppsError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            ListAppsError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by NotifyAppValidationOutput
 #[derive(Debug, PartialEq)]
 pub enum NotifyAppValidationOutputError {
@@ -3093,6 +3428,23 @@ impl fmt::Display for NotifyAppValidationOutputError {
     }
 }
 impl Error for NotifyAppValidationOutputError {}
+impl ServiceErrorCode for NotifyAppValidationOutputError {
+    fn error_code(&self) -> &str {
+        match *self {
+            NotifyAppValidationOutputError::InternalError(_) => "InternalError",
+            NotifyAppValidationOutputError::InvalidParameter(_) => "InvalidParameterException",
+            NotifyAppValidationOutputError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            NotifyAppValidationOutputError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            NotifyAppValidationOutputError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by PutAppLaunchConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutAppLaunchConfigurationError {
@@ -3163,6 +3515,23 @@ impl fmt::Display for PutAppLaunchConfigurationError {
     }
 }
 impl Error for PutAppLaunchConfigurationError {}
+impl ServiceErrorCode for PutAppLaunchConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAppLaunchConfigurationError::InternalError(_) => "InternalError",
+            PutAppLaunchConfigurationError::InvalidParameter(_) => "InvalidParameterException",
+            PutAppLaunchConfigurationError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            PutAppLaunchConfigurationError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            PutAppLaunchConfigurationError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by PutAppReplicationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutAppReplicationConfigurationError {
@@ -3237,6 +3606,23 @@ impl fmt::Display for PutAppReplicationConfigurationError {
     }
 }
 impl Error for PutAppReplicationConfigurationError {}
+impl ServiceErrorCode for PutAppReplicationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAppReplicationConfigurationError::InternalError(_) => "InternalError",
+            PutAppReplicationConfigurationError::InvalidParameter(_) => "InvalidParameterException",
+            PutAppReplicationConfigurationError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            PutAppReplicationConfigurationError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            PutAppReplicationConfigurationError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by PutAppValidationConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutAppValidationConfigurationError {
@@ -3311,6 +3697,23 @@ impl fmt::Display for PutAppValidationConfigurationError {
     }
 }
 impl Error for PutAppValidationConfigurationError {}
+impl ServiceErrorCode for PutAppValidationConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAppValidationConfigurationError::InternalError(_) => "InternalError",
+            PutAppValidationConfigurationError::InvalidParameter(_) => "InvalidParameterException",
+            PutAppValidationConfigurationError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            PutAppValidationConfigurationError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            PutAppValidationConfigurationError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by StartAppReplication
 #[derive(Debug, PartialEq)]
 pub enum StartAppReplicationError {
@@ -3373,6 +3776,19 @@ impl fmt::Display for StartAppReplicationError {
     }
 }
 impl Error for StartAppReplicationError {}
+impl ServiceErrorCode for StartAppReplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartAppReplicationError::InternalError(_) => "InternalError",
+            StartAppReplicationError::InvalidParameter(_) => "InvalidParameterException",
+            StartAppReplicationError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            StartAppReplicationError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            StartAppReplicationError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by StartOnDemandAppReplication
 #[derive(Debug, PartialEq)]
 pub enum StartOnDemandAppReplicationError {
@@ -3445,6 +3861,23 @@ impl fmt::Display for StartOnDemandAppReplicationError {
     }
 }
 impl Error for StartOnDemandAppReplicationError {}
+impl ServiceErrorCode for StartOnDemandAppReplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartOnDemandAppReplicationError::InternalError(_) => "InternalError",
+            StartOnDemandAppReplicationError::InvalidParameter(_) => "InvalidParameterException",
+            StartOnDemandAppReplicationError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            StartOnDemandAppReplicationError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            StartOnDemandAppReplicationError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by StartOnDemandReplicationRun
 #[derive(Debug, PartialEq)]
 pub enum StartOnDemandReplicationRunError {
@@ -3527,6 +3960,26 @@ impl fmt::Display for StartOnDemandReplicationRunError {
     }
 }
 impl Error for StartOnDemandReplicationRunError {}
+impl ServiceErrorCode for StartOnDemandReplicationRunError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartOnDemandReplicationRunError::DryRunOperation(_) => "DryRunOperationException",
+            StartOnDemandReplicationRunError::InvalidParameter(_) => "InvalidParameterException",
+            StartOnDemandReplicationRunError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            StartOnDemandReplicationRunError::OperationNotPermitted(_) => {
+                "OperationNotPermittedException"
+            }
+            StartOnDemandReplicationRunError::ReplicationRunLimitExceeded(_) => {
+                "ReplicationRunLimitExceededException"
+            }
+            StartOnDemandReplicationRunError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by StopAppReplication
 #[derive(Debug, PartialEq)]
 pub enum StopAppReplicationError {
@@ -3587,6 +4040,19 @@ impl fmt::Display for StopAppReplicationError {
     }
 }
 impl Error for StopAppReplicationError {}
+impl ServiceErrorCode for StopAppReplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopAppReplicationError::InternalError(_) => "InternalError",
+            StopAppReplicationError::InvalidParameter(_) => "InvalidParameterException",
+            StopAppReplicationError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            StopAppReplicationError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            StopAppReplicationError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by TerminateApp
 #[derive(Debug, PartialEq)]
 pub enum TerminateAppError {
@@ -3643,6 +4109,17 @@ impl fmt::Display for TerminateAppError {
     }
 }
 impl Error for TerminateAppError {}
+impl ServiceErrorCode for TerminateAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TerminateAppError::InternalError(_) => "InternalError",
+            TerminateAppError::InvalidParameter(_) => "InvalidParameterException",
+            TerminateAppError::MissingRequiredParameter(_) => "MissingRequiredParameterException",
+            TerminateAppError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            TerminateAppError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by UpdateApp
 #[derive(Debug, PartialEq)]
 pub enum UpdateAppError {
@@ -3697,6 +4174,17 @@ impl fmt::Display for UpdateAppError {
     }
 }
 impl Error for UpdateAppError {}
+impl ServiceErrorCode for UpdateAppError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAppError::InternalError(_) => "InternalError",
+            UpdateAppError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateAppError::MissingRequiredParameter(_) => "MissingRequiredParameterException",
+            UpdateAppError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            UpdateAppError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Errors returned by UpdateReplicationJob
 #[derive(Debug, PartialEq)]
 pub enum UpdateReplicationJobError {
@@ -3787,6 +4275,28 @@ impl fmt::Display for UpdateReplicationJobError {
     }
 }
 impl Error for UpdateReplicationJobError {}
+impl ServiceErrorCode for UpdateReplicationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateReplicationJobError::InternalError(_) => "InternalError",
+            UpdateReplicationJobError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateReplicationJobError::MissingRequiredParameter(_) => {
+                "MissingRequiredParameterException"
+            }
+            UpdateReplicationJobError::OperationNotPermitted(_) => "OperationNotPermittedException",
+            UpdateReplicationJobError::ReplicationJobNotFound(_) => {
+                "ReplicationJobNotFoundException"
+            }
+            UpdateReplicationJobError::ServerCannotBeReplicated(_) => {
+                "ServerCannotBeReplicatedException"
+            }
+            UpdateReplicationJobError::TemporarilyUnavailable(_) => {
+                "TemporarilyUnavailableException"
+            }
+            UpdateReplicationJobError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+        }
+    }
+}
 /// Trait representing the capabilities of the SMS API. SMS clients implement this trait.
 #[async_trait]
 pub trait ServerMigrationService {
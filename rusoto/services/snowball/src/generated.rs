@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1138,6 +1138,15 @@ impl fmt::Display for CancelClusterError {
     }
 }
 impl Error for CancelClusterError {}
+impl ServiceErrorCode for CancelClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelClusterError::InvalidJobState(_) => "InvalidJobStateException",
+            CancelClusterError::InvalidResource(_) => "InvalidResourceException",
+            CancelClusterError::KMSRequestFailed(_) => "KMSRequestFailedException",
+        }
+    }
+}
 /// Errors returned by CancelJob
 #[derive(Debug, PartialEq)]
 pub enum CancelJobError {
@@ -1180,6 +1189,15 @@ impl fmt::Display for CancelJobError {
     }
 }
 impl Error for CancelJobError {}
+impl ServiceErrorCode for CancelJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelJobError::InvalidJobState(_) => "InvalidJobStateException",
+            CancelJobError::InvalidResource(_) => "InvalidResourceException",
+            CancelJobError::KMSRequestFailed(_) => "KMSRequestFailedException",
+        }
+    }
+}
 /// Errors returned by CreateAddress
 #[derive(Debug, PartialEq)]
 pub enum CreateAddressError {
@@ -1216,6 +1234,14 @@ impl fmt::Display for CreateAddressError {
     }
 }
 impl Error for CreateAddressError {}
+impl ServiceErrorCode for CreateAddressError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAddressError::InvalidAddress(_) => "InvalidAddressException",
+            CreateAddressError::UnsupportedAddress(_) => "UnsupportedAddressException",
+        }
+    }
+}
 /// Errors returned by CreateCluster
 #[derive(Debug, PartialEq)]
 pub enum CreateClusterError {
@@ -1266,6 +1292,16 @@ impl fmt::Display for CreateClusterError {
     }
 }
 impl Error for CreateClusterError {}
+impl ServiceErrorCode for CreateClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateClusterError::Ec2RequestFailed(_) => "Ec2RequestFailedException",
+            CreateClusterError::InvalidInputCombination(_) => "InvalidInputCombinationException",
+            CreateClusterError::InvalidResource(_) => "InvalidResourceException",
+            CreateClusterError::KMSRequestFailed(_) => "KMSRequestFailedException",
+        }
+    }
+}
 /// Errors returned by CreateJob
 #[derive(Debug, PartialEq)]
 pub enum CreateJobError {
@@ -1320,6 +1356,17 @@ impl fmt::Display for CreateJobError {
     }
 }
 impl Error for CreateJobError {}
+impl ServiceErrorCode for CreateJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateJobError::ClusterLimitExceeded(_) => "ClusterLimitExceededException",
+            CreateJobError::Ec2RequestFailed(_) => "Ec2RequestFailedException",
+            CreateJobError::InvalidInputCombination(_) => "InvalidInputCombinationException",
+            CreateJobError::InvalidResource(_) => "InvalidResourceException",
+            CreateJobError::KMSRequestFailed(_) => "KMSRequestFailedException",
+        }
+    }
+}
 /// Errors returned by CreateReturnShippingLabel
 #[derive(Debug, PartialEq)]
 pub enum CreateReturnShippingLabelError {
@@ -1386,6 +1433,21 @@ impl fmt::Display for CreateReturnShippingLabelError {
     }
 }
 impl Error for CreateReturnShippingLabelError {}
+impl ServiceErrorCode for CreateReturnShippingLabelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateReturnShippingLabelError::Conflict(_) => "ConflictException",
+            CreateReturnShippingLabelError::InvalidInputCombination(_) => {
+                "InvalidInputCombinationException"
+            }
+            CreateReturnShippingLabelError::InvalidJobState(_) => "InvalidJobStateException",
+            CreateReturnShippingLabelError::InvalidResource(_) => "InvalidResourceException",
+            CreateReturnShippingLabelError::ReturnShippingLabelAlreadyExists(_) => {
+                "ReturnShippingLabelAlreadyExistsException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeAddress
 #[derive(Debug, PartialEq)]
 pub enum DescribeAddressError {
@@ -1416,6 +1478,13 @@ impl fmt::Display for DescribeAddressError {
     }
 }
 impl Error for DescribeAddressError {}
+impl ServiceErrorCode for DescribeAddressError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAddressError::InvalidResource(_) => "InvalidResourceException",
+        }
+    }
+}
 /// Errors returned by DescribeAddresses
 #[derive(Debug, PartialEq)]
 pub enum DescribeAddressesError {
@@ -1452,6 +1521,14 @@ impl fmt::Display for DescribeAddressesError {
     }
 }
 impl Error for DescribeAddressesError {}
+impl ServiceErrorCode for DescribeAddressesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAddressesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            DescribeAddressesError::InvalidResource(_) => "InvalidResourceException",
+        }
+    }
+}
 /// Errors returned by DescribeCluster
 #[derive(Debug, PartialEq)]
 pub enum DescribeClusterError {
@@ -1482,6 +1559,13 @@ impl fmt::Display for DescribeClusterError {
     }
 }
 impl Error for DescribeClusterError {}
+impl ServiceErrorCode for DescribeClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClusterError::InvalidResource(_) => "InvalidResourceException",
+        }
+    }
+}
 /// Errors returned by DescribeJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeJobError {
@@ -1512,6 +1596,13 @@ impl fmt::Display for DescribeJobError {
     }
 }
 impl Error for DescribeJobError {}
+impl ServiceErrorCode for DescribeJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeJobError::InvalidResource(_) => "InvalidResourceException",
+        }
+    }
+}
 /// Errors returned by DescribeReturnShippingLabel
 #[derive(Debug, PartialEq)]
 pub enum DescribeReturnShippingLabelError {
@@ -1562,6 +1653,15 @@ impl fmt::Display for DescribeReturnShippingLabelError {
     }
 }
 impl Error for DescribeReturnShippingLabelError {}
+impl ServiceErrorCode for DescribeReturnShippingLabelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeReturnShippingLabelError::Conflict(_) => "ConflictException",
+            DescribeReturnShippingLabelError::InvalidJobState(_) => "InvalidJobStateException",
+            DescribeReturnShippingLabelError::InvalidResource(_) => "InvalidResourceException",
+        }
+    }
+}
 /// Errors returned by GetJobManifest
 #[derive(Debug, PartialEq)]
 pub enum GetJobManifestError {
@@ -1598,6 +1698,14 @@ impl fmt::Display for GetJobManifestError {
     }
 }
 impl Error for GetJobManifestError {}
+impl ServiceErrorCode for GetJobManifestError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetJobManifestError::InvalidJobState(_) => "InvalidJobStateException",
+            GetJobManifestError::InvalidResource(_) => "InvalidResourceException",
+        }
+    }
+}
 /// Errors returned by GetJobUnlockCode
 #[derive(Debug, PartialEq)]
 pub enum GetJobUnlockCodeError {
@@ -1634,6 +1742,14 @@ impl fmt::Display for GetJobUnlockCodeError {
     }
 }
 impl Error for GetJobUnlockCodeError {}
+impl ServiceErrorCode for GetJobUnlockCodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetJobUnlockCodeError::InvalidJobState(_) => "InvalidJobStateException",
+            GetJobUnlockCodeError::InvalidResource(_) => "InvalidResourceException",
+        }
+    }
+}
 /// Errors returned by GetSnowballUsage
 #[derive(Debug, PartialEq)]
 pub enum GetSnowballUsageError {}
@@ -1656,6 +1772,11 @@ impl fmt::Display for GetSnowballUsageError {
     }
 }
 impl Error for GetSnowballUsageError {}
+impl ServiceErrorCode for GetSnowballUsageError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetSoftwareUpdates
 #[derive(Debug, PartialEq)]
 pub enum GetSoftwareUpdatesError {
@@ -1692,6 +1813,14 @@ impl fmt::Display for GetSoftwareUpdatesError {
     }
 }
 impl Error for GetSoftwareUpdatesError {}
+impl ServiceErrorCode for GetSoftwareUpdatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSoftwareUpdatesError::InvalidJobState(_) => "InvalidJobStateException",
+            GetSoftwareUpdatesError::InvalidResource(_) => "InvalidResourceException",
+        }
+    }
+}
 /// Errors returned by ListClusterJobs
 #[derive(Debug, PartialEq)]
 pub enum ListClusterJobsError {
@@ -1728,6 +1857,14 @@ impl fmt::Display for ListClusterJobsError {
     }
 }
 impl Error for ListClusterJobsError {}
+impl ServiceErrorCode for ListClusterJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListClusterJobsError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListClusterJobsError::InvalidResource(_) => "InvalidResourceException",
+        }
+    }
+}
 /// Errors returned by ListClusters
 #[derive(Debug, PartialEq)]
 pub enum ListClustersError {
@@ -1758,6 +1895,13 @@ impl fmt::Display for ListClustersError {
     }
 }
 impl Error for ListClustersError {}
+impl ServiceErrorCode for ListClustersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListClustersError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListCompatibleImages
 #[derive(Debug, PartialEq)]
 pub enum ListCompatibleImagesError {
@@ -1798,6 +1942,14 @@ impl fmt::Display for ListCompatibleImagesError {
     }
 }
 impl Error for ListCompatibleImagesError {}
+impl ServiceErrorCode for ListCompatibleImagesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCompatibleImagesError::Ec2RequestFailed(_) => "Ec2RequestFailedException",
+            ListCompatibleImagesError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
 pub enum ListJobsError {
@@ -1828,6 +1980,13 @@ impl fmt::Display for ListJobsError {
     }
 }
 impl Error for ListJobsError {}
+impl ServiceErrorCode for ListJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListJobsError::InvalidNextToken(_) => "InvalidNextTokenException",
+        }
+    }
+}
 /// Errors returned by UpdateCluster
 #[derive(Debug, PartialEq)]
 pub enum UpdateClusterError {
@@ -1884,6 +2043,17 @@ impl fmt::Display for UpdateClusterError {
     }
 }
 impl Error for UpdateClusterError {}
+impl ServiceErrorCode for UpdateClusterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateClusterError::Ec2RequestFailed(_) => "Ec2RequestFailedException",
+            UpdateClusterError::InvalidInputCombination(_) => "InvalidInputCombinationException",
+            UpdateClusterError::InvalidJobState(_) => "InvalidJobStateException",
+            UpdateClusterError::InvalidResource(_) => "InvalidResourceException",
+            UpdateClusterError::KMSRequestFailed(_) => "KMSRequestFailedException",
+        }
+    }
+}
 /// Errors returned by UpdateJob
 #[derive(Debug, PartialEq)]
 pub enum UpdateJobError {
@@ -1944,6 +2114,18 @@ impl fmt::Display for UpdateJobError {
     }
 }
 impl Error for UpdateJobError {}
+impl ServiceErrorCode for UpdateJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateJobError::ClusterLimitExceeded(_) => "ClusterLimitExceededException",
+            UpdateJobError::Ec2RequestFailed(_) => "Ec2RequestFailedException",
+            UpdateJobError::InvalidInputCombination(_) => "InvalidInputCombinationException",
+            UpdateJobError::InvalidJobState(_) => "InvalidJobStateException",
+            UpdateJobError::InvalidResource(_) => "InvalidResourceException",
+            UpdateJobError::KMSRequestFailed(_) => "KMSRequestFailedException",
+        }
+    }
+}
 /// Errors returned by UpdateJobShipmentState
 #[derive(Debug, PartialEq)]
 pub enum UpdateJobShipmentStateError {
@@ -1984,6 +2166,14 @@ impl fmt::Display for UpdateJobShipmentStateError {
     }
 }
 impl Error for UpdateJobShipmentStateError {}
+impl ServiceErrorCode for UpdateJobShipmentStateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateJobShipmentStateError::InvalidJobState(_) => "InvalidJobStateException",
+            UpdateJobShipmentStateError::InvalidResource(_) => "InvalidResourceException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Snowball API. Amazon Snowball clients implement this trait.
 #[async_trait]
 pub trait Snowball {
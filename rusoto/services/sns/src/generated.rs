@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -2403,6 +2403,16 @@ impl fmt::Display for AddPermissionError {
     }
 }
 impl Error for AddPermissionError {}
+impl ServiceErrorCode for AddPermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddPermissionError::AuthorizationError(_) => "AuthorizationError",
+            AddPermissionError::InternalError(_) => "InternalError",
+            AddPermissionError::InvalidParameter(_) => "InvalidParameter",
+            AddPermissionError::NotFound(_) => "NotFound",
+        }
+    }
+}
 /// Errors returned by CheckIfPhoneNumberIsOptedOut
 #[derive(Debug, PartialEq)]
 pub enum CheckIfPhoneNumberIsOptedOutError {
@@ -2481,6 +2491,16 @@ impl fmt::Display for CheckIfPhoneNumberIsOptedOutError {
     }
 }
 impl Error for CheckIfPhoneNumberIsOptedOutError {}
+impl ServiceErrorCode for CheckIfPhoneNumberIsOptedOutError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CheckIfPhoneNumberIsOptedOutError::AuthorizationError(_) => "AuthorizationError",
+            CheckIfPhoneNumberIsOptedOutError::InternalError(_) => "InternalError",
+            CheckIfPhoneNumberIsOptedOutError::InvalidParameter(_) => "InvalidParameter",
+            CheckIfPhoneNumberIsOptedOutError::Throttled(_) => "Throttled",
+        }
+    }
+}
 /// Errors returned by ConfirmSubscription
 #[derive(Debug, PartialEq)]
 pub enum ConfirmSubscriptionError {
@@ -2573,6 +2593,18 @@ impl fmt::Display for ConfirmSubscriptionError {
     }
 }
 impl Error for ConfirmSubscriptionError {}
+impl ServiceErrorCode for ConfirmSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ConfirmSubscriptionError::AuthorizationError(_) => "AuthorizationError",
+            ConfirmSubscriptionError::FilterPolicyLimitExceeded(_) => "FilterPolicyLimitExceeded",
+            ConfirmSubscriptionError::InternalError(_) => "InternalError",
+            ConfirmSubscriptionError::InvalidParameter(_) => "InvalidParameter",
+            ConfirmSubscriptionError::NotFound(_) => "NotFound",
+            ConfirmSubscriptionError::SubscriptionLimitExceeded(_) => "SubscriptionLimitExceeded",
+        }
+    }
+}
 /// Errors returned by CreatePlatformApplication
 #[derive(Debug, PartialEq)]
 pub enum CreatePlatformApplicationError {
@@ -2635,6 +2667,15 @@ impl fmt::Display for CreatePlatformApplicationError {
     }
 }
 impl Error for CreatePlatformApplicationError {}
+impl ServiceErrorCode for CreatePlatformApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePlatformApplicationError::AuthorizationError(_) => "AuthorizationError",
+            CreatePlatformApplicationError::InternalError(_) => "InternalError",
+            CreatePlatformApplicationError::InvalidParameter(_) => "InvalidParameter",
+        }
+    }
+}
 /// Errors returned by CreatePlatformEndpoint
 #[derive(Debug, PartialEq)]
 pub enum CreatePlatformEndpointError {
@@ -2703,6 +2744,16 @@ impl fmt::Display for CreatePlatformEndpointError {
     }
 }
 impl Error for CreatePlatformEndpointError {}
+impl ServiceErrorCode for CreatePlatformEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePlatformEndpointError::AuthorizationError(_) => "AuthorizationError",
+            CreatePlatformEndpointError::InternalError(_) => "InternalError",
+            CreatePlatformEndpointError::InvalidParameter(_) => "InvalidParameter",
+            CreatePlatformEndpointError::NotFound(_) => "NotFound",
+        }
+    }
+}
 /// Errors returned by CreateTopic
 #[derive(Debug, PartialEq)]
 pub enum CreateTopicError {
@@ -2811,6 +2862,21 @@ impl fmt::Display for CreateTopicError {
     }
 }
 impl Error for CreateTopicError {}
+impl ServiceErrorCode for CreateTopicError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTopicError::AuthorizationError(_) => "AuthorizationError",
+            CreateTopicError::ConcurrentAccess(_) => "ConcurrentAccess",
+            CreateTopicError::InternalError(_) => "InternalError",
+            CreateTopicError::InvalidParameter(_) => "InvalidParameter",
+            CreateTopicError::InvalidSecurity(_) => "InvalidSecurity",
+            CreateTopicError::StaleTag(_) => "StaleTag",
+            CreateTopicError::TagLimitExceeded(_) => "TagLimitExceeded",
+            CreateTopicError::TagPolicy(_) => "TagPolicy",
+            CreateTopicError::TopicLimitExceeded(_) => "TopicLimitExceeded",
+        }
+    }
+}
 /// Errors returned by DeleteEndpoint
 #[derive(Debug, PartialEq)]
 pub enum DeleteEndpointError {
@@ -2871,6 +2937,15 @@ impl fmt::Display for DeleteEndpointError {
     }
 }
 impl Error for DeleteEndpointError {}
+impl ServiceErrorCode for DeleteEndpointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteEndpointError::AuthorizationError(_) => "AuthorizationError",
+            DeleteEndpointError::InternalError(_) => "InternalError",
+            DeleteEndpointError::InvalidParameter(_) => "InvalidParameter",
+        }
+    }
+}
 /// Errors returned by DeletePlatformApplication
 #[derive(Debug, PartialEq)]
 pub enum DeletePlatformApplicationError {
@@ -2933,6 +3008,15 @@ impl fmt::Display for DeletePlatformApplicationError {
     }
 }
 impl Error for DeletePlatformApplicationError {}
+impl ServiceErrorCode for DeletePlatformApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePlatformApplicationError::AuthorizationError(_) => "AuthorizationError",
+            DeletePlatformApplicationError::InternalError(_) => "InternalError",
+            DeletePlatformApplicationError::InvalidParameter(_) => "InvalidParameter",
+        }
+    }
+}
 /// Errors returned by DeleteTopic
 #[derive(Debug, PartialEq)]
 pub enum DeleteTopicError {
@@ -3025,6 +3109,19 @@ impl fmt::Display for DeleteTopicError {
     }
 }
 impl Error for DeleteTopicError {}
+impl ServiceErrorCode for DeleteTopicError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTopicError::AuthorizationError(_) => "AuthorizationError",
+            DeleteTopicError::ConcurrentAccess(_) => "ConcurrentAccess",
+            DeleteTopicError::InternalError(_) => "InternalError",
+            DeleteTopicError::InvalidParameter(_) => "InvalidParameter",
+            DeleteTopicError::NotFound(_) => "NotFound",
+            DeleteTopicError::StaleTag(_) => "StaleTag",
+            DeleteTopicError::TagPolicy(_) => "TagPolicy",
+        }
+    }
+}
 /// Errors returned by GetEndpointAttributes
 #[derive(Debug, PartialEq)]
 pub enum GetEndpointAttributesError {
@@ -3093,6 +3190,16 @@ impl fmt::Display for GetEndpointAttributesError {
     }
 }
 impl Error for GetEndpointAttributesError {}
+impl ServiceErrorCode for GetEndpointAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEndpointAttributesError::AuthorizationError(_) => "AuthorizationError",
+            GetEndpointAttributesError::InternalError(_) => "InternalError",
+            GetEndpointAttributesError::InvalidParameter(_) => "InvalidParameter",
+            GetEndpointAttributesError::NotFound(_) => "NotFound",
+        }
+    }
+}
 /// Errors returned by GetPlatformApplicationAttributes
 #[derive(Debug, PartialEq)]
 pub enum GetPlatformApplicationAttributesError {
@@ -3175,6 +3282,16 @@ impl fmt::Display for GetPlatformApplicationAttributesError {
     }
 }
 impl Error for GetPlatformApplicationAttributesError {}
+impl ServiceErrorCode for GetPlatformApplicationAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPlatformApplicationAttributesError::AuthorizationError(_) => "AuthorizationError",
+            GetPlatformApplicationAttributesError::InternalError(_) => "InternalError",
+            GetPlatformApplicationAttributesError::InvalidParameter(_) => "InvalidParameter",
+            GetPlatformApplicationAttributesError::NotFound(_) => "NotFound",
+        }
+    }
+}
 /// Errors returned by GetSMSAttributes
 #[derive(Debug, PartialEq)]
 pub enum GetSMSAttributesError {
@@ -3243,6 +3360,16 @@ impl fmt::Display for GetSMSAttributesError {
     }
 }
 impl Error for GetSMSAttributesError {}
+impl ServiceErrorCode for GetSMSAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSMSAttributesError::AuthorizationError(_) => "AuthorizationError",
+            GetSMSAttributesError::InternalError(_) => "InternalError",
+            GetSMSAttributesError::InvalidParameter(_) => "InvalidParameter",
+            GetSMSAttributesError::Throttled(_) => "Throttled",
+        }
+    }
+}
 /// Errors returned by GetSubscriptionAttributes
 #[derive(Debug, PartialEq)]
 pub enum GetSubscriptionAttributesError {
@@ -3313,6 +3440,16 @@ impl fmt::Display for GetSubscriptionAttributesError {
     }
 }
 impl Error for GetSubscriptionAttributesError {}
+impl ServiceErrorCode for GetSubscriptionAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSubscriptionAttributesError::AuthorizationError(_) => "AuthorizationError",
+            GetSubscriptionAttributesError::InternalError(_) => "InternalError",
+            GetSubscriptionAttributesError::InvalidParameter(_) => "InvalidParameter",
+            GetSubscriptionAttributesError::NotFound(_) => "NotFound",
+        }
+    }
+}
 /// Errors returned by GetTopicAttributes
 #[derive(Debug, PartialEq)]
 pub enum GetTopicAttributesError {
@@ -3389,6 +3526,17 @@ impl fmt::Display for GetTopicAttributesError {
     }
 }
 impl Error for GetTopicAttributesError {}
+impl ServiceErrorCode for GetTopicAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTopicAttributesError::AuthorizationError(_) => "AuthorizationError",
+            GetTopicAttributesError::InternalError(_) => "InternalError",
+            GetTopicAttributesError::InvalidParameter(_) => "InvalidParameter",
+            GetTopicAttributesError::InvalidSecurity(_) => "InvalidSecurity",
+            GetTopicAttributesError::NotFound(_) => "NotFound",
+        }
+    }
+}
 /// Errors returned by ListEndpointsByPlatformApplication
 #[derive(Debug, PartialEq)]
 pub enum ListEndpointsByPlatformApplicationError {
@@ -3471,6 +3619,16 @@ impl fmt::Display for ListEndpointsByPlatformApplicationError {
     }
 }
 impl Error for ListEndpointsByPlatformApplicationError {}
+impl ServiceErrorCode for ListEndpointsByPlatformApplicationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListEndpointsByPlatformApplicationError::AuthorizationError(_) => "AuthorizationError",
+            ListEndpointsByPlatformApplicationError::InternalError(_) => "InternalError",
+            ListEndpointsByPlatformApplicationError::InvalidParameter(_) => "InvalidParameter",
+            ListEndpointsByPlatformApplicationError::NotFound(_) => "NotFound",
+        }
+    }
+}
 /// Errors returned by ListPhoneNumbersOptedOut
 #[derive(Debug, PartialEq)]
 pub enum ListPhoneNumbersOptedOutError {
@@ -3539,6 +3697,16 @@ impl fmt::Display for ListPhoneNumbersOptedOutError {
     }
 }
 impl Error for ListPhoneNumbersOptedOutError {}
+impl ServiceErrorCode for ListPhoneNumbersOptedOutError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPhoneNumbersOptedOutError::AuthorizationError(_) => "AuthorizationError",
+            ListPhoneNumbersOptedOutError::InternalError(_) => "InternalError",
+            ListPhoneNumbersOptedOutError::InvalidParameter(_) => "InvalidParameter",
+            ListPhoneNumbersOptedOutError::Throttled(_) => "Throttled",
+        }
+    }
+}
 /// Errors returned by ListPlatformApplications
 #[derive(Debug, PartialEq)]
 pub enum ListPlatformApplicationsError {
@@ -3599,6 +3767,15 @@ impl fmt::Display for ListPlatformApplicationsError {
     }
 }
 impl Error for ListPlatformApplicationsError {}
+impl ServiceErrorCode for ListPlatformApplicationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListPlatformApplicationsError::AuthorizationError(_) => "AuthorizationError",
+            ListPlatformApplicationsError::InternalError(_) => "InternalError",
+            ListPlatformApplicationsError::InvalidParameter(_) => "InvalidParameter",
+        }
+    }
+}
 /// Errors returned by ListSubscriptions
 #[derive(Debug, PartialEq)]
 pub enum ListSubscriptionsError {
@@ -3659,6 +3836,15 @@ impl fmt::Display for ListSubscriptionsError {
     }
 }
 impl Error for ListSubscriptionsError {}
+impl ServiceErrorCode for ListSubscriptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSubscriptionsError::AuthorizationError(_) => "AuthorizationError",
+            ListSubscriptionsError::InternalError(_) => "InternalError",
+            ListSubscriptionsError::InvalidParameter(_) => "InvalidParameter",
+        }
+    }
+}
 /// Errors returned by ListSubscriptionsByTopic
 #[derive(Debug, PartialEq)]
 pub enum ListSubscriptionsByTopicError {
@@ -3727,6 +3913,16 @@ impl fmt::Display for ListSubscriptionsByTopicError {
     }
 }
 impl Error for ListSubscriptionsByTopicError {}
+impl ServiceErrorCode for ListSubscriptionsByTopicError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSubscriptionsByTopicError::AuthorizationError(_) => "AuthorizationError",
+            ListSubscriptionsByTopicError::InternalError(_) => "InternalError",
+            ListSubscriptionsByTopicError::InvalidParameter(_) => "InvalidParameter",
+            ListSubscriptionsByTopicError::NotFound(_) => "NotFound",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3803,6 +3999,17 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::AuthorizationError(_) => "AuthorizationError",
+            ListTagsForResourceError::ConcurrentAccess(_) => "ConcurrentAccess",
+            ListTagsForResourceError::InvalidParameter(_) => "InvalidParameter",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFound",
+            ListTagsForResourceError::TagPolicy(_) => "TagPolicy",
+        }
+    }
+}
 /// Errors returned by ListTopics
 #[derive(Debug, PartialEq)]
 pub enum ListTopicsError {
@@ -3863,6 +4070,15 @@ impl fmt::Display for ListTopicsError {
     }
 }
 impl Error for ListTopicsError {}
+impl ServiceErrorCode for ListTopicsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTopicsError::AuthorizationError(_) => "AuthorizationError",
+            ListTopicsError::InternalError(_) => "InternalError",
+            ListTopicsError::InvalidParameter(_) => "InvalidParameter",
+        }
+    }
+}
 /// Errors returned by OptInPhoneNumber
 #[derive(Debug, PartialEq)]
 pub enum OptInPhoneNumberError {
@@ -3931,6 +4147,16 @@ impl fmt::Display for OptInPhoneNumberError {
     }
 }
 impl Error for OptInPhoneNumberError {}
+impl ServiceErrorCode for OptInPhoneNumberError {
+    fn error_code(&self) -> &str {
+        match *self {
+            OptInPhoneNumberError::AuthorizationError(_) => "AuthorizationError",
+            OptInPhoneNumberError::InternalError(_) => "InternalError",
+            OptInPhoneNumberError::InvalidParameter(_) => "InvalidParameter",
+            OptInPhoneNumberError::Throttled(_) => "Throttled",
+        }
+    }
+}
 /// Errors returned by Publish
 #[derive(Debug, PartialEq)]
 pub enum PublishError {
@@ -4077,6 +4303,26 @@ impl fmt::Display for PublishError {
     }
 }
 impl Error for PublishError {}
+impl ServiceErrorCode for PublishError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PublishError::AuthorizationError(_) => "AuthorizationError",
+            PublishError::EndpointDisabled(_) => "EndpointDisabled",
+            PublishError::InternalError(_) => "InternalError",
+            PublishError::InvalidParameter(_) => "InvalidParameter",
+            PublishError::InvalidParameterValue(_) => "ParameterValueInvalid",
+            PublishError::InvalidSecurity(_) => "InvalidSecurity",
+            PublishError::KMSAccessDenied(_) => "KMSAccessDenied",
+            PublishError::KMSDisabled(_) => "KMSDisabled",
+            PublishError::KMSInvalidState(_) => "KMSInvalidState",
+            PublishError::KMSNotFound(_) => "KMSNotFound",
+            PublishError::KMSOptInRequired(_) => "KMSOptInRequired",
+            PublishError::KMSThrottling(_) => "KMSThrottling",
+            PublishError::NotFound(_) => "NotFound",
+            PublishError::PlatformApplicationDisabled(_) => "PlatformApplicationDisabled",
+        }
+    }
+}
 /// Errors returned by RemovePermission
 #[derive(Debug, PartialEq)]
 pub enum RemovePermissionError {
@@ -4145,6 +4391,16 @@ impl fmt::Display for RemovePermissionError {
     }
 }
 impl Error for RemovePermissionError {}
+impl ServiceErrorCode for RemovePermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemovePermissionError::AuthorizationError(_) => "AuthorizationError",
+            RemovePermissionError::InternalError(_) => "InternalError",
+            RemovePermissionError::InvalidParameter(_) => "InvalidParameter",
+            RemovePermissionError::NotFound(_) => "NotFound",
+        }
+    }
+}
 /// Errors returned by SetEndpointAttributes
 #[derive(Debug, PartialEq)]
 pub enum SetEndpointAttributesError {
@@ -4213,6 +4469,16 @@ impl fmt::Display for SetEndpointAttributesError {
     }
 }
 impl Error for SetEndpointAttributesError {}
+impl ServiceErrorCode for SetEndpointAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetEndpointAttributesError::AuthorizationError(_) => "AuthorizationError",
+            SetEndpointAttributesError::InternalError(_) => "InternalError",
+            SetEndpointAttributesError::InvalidParameter(_) => "InvalidParameter",
+            SetEndpointAttributesError::NotFound(_) => "NotFound",
+        }
+    }
+}
 /// Errors returned by SetPlatformApplicationAttributes
 #[derive(Debug, PartialEq)]
 pub enum SetPlatformApplicationAttributesError {
@@ -4295,6 +4561,16 @@ impl fmt::Display for SetPlatformApplicationAttributesError {
     }
 }
 impl Error for SetPlatformApplicationAttributesError {}
+impl ServiceErrorCode for SetPlatformApplicationAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetPlatformApplicationAttributesError::AuthorizationError(_) => "AuthorizationError",
+            SetPlatformApplicationAttributesError::InternalError(_) => "InternalError",
+            SetPlatformApplicationAttributesError::InvalidParameter(_) => "InvalidParameter",
+            SetPlatformApplicationAttributesError::NotFound(_) => "NotFound",
+        }
+    }
+}
 /// Errors returned by SetSMSAttributes
 #[derive(Debug, PartialEq)]
 pub enum SetSMSAttributesError {
@@ -4363,6 +4639,16 @@ impl fmt::Display for SetSMSAttributesError {
     }
 }
 impl Error for SetSMSAttributesError {}
+impl ServiceErrorCode for SetSMSAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetSMSAttributesError::AuthorizationError(_) => "AuthorizationError",
+            SetSMSAttributesError::InternalError(_) => "InternalError",
+            SetSMSAttributesError::InvalidParameter(_) => "InvalidParameter",
+            SetSMSAttributesError::Throttled(_) => "Throttled",
+        }
+    }
+}
 /// Errors returned by SetSubscriptionAttributes
 #[derive(Debug, PartialEq)]
 pub enum SetSubscriptionAttributesError {
@@ -4445,6 +4731,19 @@ impl fmt::Display for SetSubscriptionAttributesError {
     }
 }
 impl Error for SetSubscriptionAttributesError {}
+impl ServiceErrorCode for SetSubscriptionAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetSubscriptionAttributesError::AuthorizationError(_) => "AuthorizationError",
+            SetSubscriptionAttributesError::FilterPolicyLimitExceeded(_) => {
+                "FilterPolicyLimitExceeded"
+            }
+            SetSubscriptionAttributesError::InternalError(_) => "InternalError",
+            SetSubscriptionAttributesError::InvalidParameter(_) => "InvalidParameter",
+            SetSubscriptionAttributesError::NotFound(_) => "NotFound",
+        }
+    }
+}
 /// Errors returned by SetTopicAttributes
 #[derive(Debug, PartialEq)]
 pub enum SetTopicAttributesError {
@@ -4521,6 +4820,17 @@ impl fmt::Display for SetTopicAttributesError {
     }
 }
 impl Error for SetTopicAttributesError {}
+impl ServiceErrorCode for SetTopicAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetTopicAttributesError::AuthorizationError(_) => "AuthorizationError",
+            SetTopicAttributesError::InternalError(_) => "InternalError",
+            SetTopicAttributesError::InvalidParameter(_) => "InvalidParameter",
+            SetTopicAttributesError::InvalidSecurity(_) => "InvalidSecurity",
+            SetTopicAttributesError::NotFound(_) => "NotFound",
+        }
+    }
+}
 /// Errors returned by Subscribe
 #[derive(Debug, PartialEq)]
 pub enum SubscribeError {
@@ -4611,6 +4921,19 @@ impl fmt::Display for SubscribeError {
     }
 }
 impl Error for SubscribeError {}
+impl ServiceErrorCode for SubscribeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SubscribeError::AuthorizationError(_) => "AuthorizationError",
+            SubscribeError::FilterPolicyLimitExceeded(_) => "FilterPolicyLimitExceeded",
+            SubscribeError::InternalError(_) => "InternalError",
+            SubscribeError::InvalidParameter(_) => "InvalidParameter",
+            SubscribeError::InvalidSecurity(_) => "InvalidSecurity",
+            SubscribeError::NotFound(_) => "NotFound",
+            SubscribeError::SubscriptionLimitExceeded(_) => "SubscriptionLimitExceeded",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -4703,6 +5026,19 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::AuthorizationError(_) => "AuthorizationError",
+            TagResourceError::ConcurrentAccess(_) => "ConcurrentAccess",
+            TagResourceError::InvalidParameter(_) => "InvalidParameter",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFound",
+            TagResourceError::StaleTag(_) => "StaleTag",
+            TagResourceError::TagLimitExceeded(_) => "TagLimitExceeded",
+            TagResourceError::TagPolicy(_) => "TagPolicy",
+        }
+    }
+}
 /// Errors returned by Unsubscribe
 #[derive(Debug, PartialEq)]
 pub enum UnsubscribeError {
@@ -4779,6 +5115,17 @@ impl fmt::Display for UnsubscribeError {
     }
 }
 impl Error for UnsubscribeError {}
+impl ServiceErrorCode for UnsubscribeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UnsubscribeError::AuthorizationError(_) => "AuthorizationError",
+            UnsubscribeError::InternalError(_) => "InternalError",
+            UnsubscribeError::InvalidParameter(_) => "InvalidParameter",
+            UnsubscribeError::InvalidSecurity(_) => "InvalidSecurity",
+            UnsubscribeError::NotFound(_) => "NotFound",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -4871,6 +5218,19 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::AuthorizationError(_) => "AuthorizationError",
+            UntagResourceError::ConcurrentAccess(_) => "ConcurrentAccess",
+            UntagResourceError::InvalidParameter(_) => "InvalidParameter",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFound",
+            UntagResourceError::StaleTag(_) => "StaleTag",
+            UntagResourceError::TagLimitExceeded(_) => "TagLimitExceeded",
+            UntagResourceError::TagPolicy(_) => "TagPolicy",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon SNS API. Amazon SNS clients implement this trait.
 #[async_trait]
 pub trait Sns {
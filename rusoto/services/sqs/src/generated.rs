@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -2183,6 +2183,13 @@ impl fmt::Display for AddPermissionError {
     }
 }
 impl Error for AddPermissionError {}
+impl ServiceErrorCode for AddPermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddPermissionError::OverLimit(_) => "OverLimit",
+        }
+    }
+}
 /// Errors returned by ChangeMessageVisibility
 #[derive(Debug, PartialEq)]
 pub enum ChangeMessageVisibilityError {
@@ -2239,6 +2246,16 @@ impl fmt::Display for ChangeMessageVisibilityError {
     }
 }
 impl Error for ChangeMessageVisibilityError {}
+impl ServiceErrorCode for ChangeMessageVisibilityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ChangeMessageVisibilityError::MessageNotInflight(_) => {
+                "AWS.SimpleQueueService.MessageNotInflight"
+            }
+            ChangeMessageVisibilityError::ReceiptHandleIsInvalid(_) => "ReceiptHandleIsInvalid",
+        }
+    }
+}
 /// Errors returned by ChangeMessageVisibilityBatch
 #[derive(Debug, PartialEq)]
 pub enum ChangeMessageVisibilityBatchError {
@@ -2325,6 +2342,24 @@ impl fmt::Display for ChangeMessageVisibilityBatchError {
     }
 }
 impl Error for ChangeMessageVisibilityBatchError {}
+impl ServiceErrorCode for ChangeMessageVisibilityBatchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ChangeMessageVisibilityBatchError::BatchEntryIdsNotDistinct(_) => {
+                "AWS.SimpleQueueService.BatchEntryIdsNotDistinct"
+            }
+            ChangeMessageVisibilityBatchError::EmptyBatchRequest(_) => {
+                "AWS.SimpleQueueService.EmptyBatchRequest"
+            }
+            ChangeMessageVisibilityBatchError::InvalidBatchEntryId(_) => {
+                "AWS.SimpleQueueService.InvalidBatchEntryId"
+            }
+            ChangeMessageVisibilityBatchError::TooManyEntriesInBatchRequest(_) => {
+                "AWS.SimpleQueueService.TooManyEntriesInBatchRequest"
+            }
+        }
+    }
+}
 /// Errors returned by CreateQueue
 #[derive(Debug, PartialEq)]
 pub enum CreateQueueError {
@@ -2377,6 +2412,16 @@ impl fmt::Display for CreateQueueError {
     }
 }
 impl Error for CreateQueueError {}
+impl ServiceErrorCode for CreateQueueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateQueueError::QueueDeletedRecently(_) => {
+                "AWS.SimpleQueueService.QueueDeletedRecently"
+            }
+            CreateQueueError::QueueNameExists(_) => "QueueAlreadyExists",
+        }
+    }
+}
 /// Errors returned by DeleteMessage
 #[derive(Debug, PartialEq)]
 pub enum DeleteMessageError {
@@ -2429,6 +2474,14 @@ impl fmt::Display for DeleteMessageError {
     }
 }
 impl Error for DeleteMessageError {}
+impl ServiceErrorCode for DeleteMessageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMessageError::InvalidIdFormat(_) => "InvalidIdFormat",
+            DeleteMessageError::ReceiptHandleIsInvalid(_) => "ReceiptHandleIsInvalid",
+        }
+    }
+}
 /// Errors returned by DeleteMessageBatch
 #[derive(Debug, PartialEq)]
 pub enum DeleteMessageBatchError {
@@ -2501,6 +2554,24 @@ impl fmt::Display for DeleteMessageBatchError {
     }
 }
 impl Error for DeleteMessageBatchError {}
+impl ServiceErrorCode for DeleteMessageBatchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMessageBatchError::BatchEntryIdsNotDistinct(_) => {
+                "AWS.SimpleQueueService.BatchEntryIdsNotDistinct"
+            }
+            DeleteMessageBatchError::EmptyBatchRequest(_) => {
+                "AWS.SimpleQueueService.EmptyBatchRequest"
+            }
+            DeleteMessageBatchError::InvalidBatchEntryId(_) => {
+                "AWS.SimpleQueueService.InvalidBatchEntryId"
+            }
+            DeleteMessageBatchError::TooManyEntriesInBatchRequest(_) => {
+                "AWS.SimpleQueueService.TooManyEntriesInBatchRequest"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteQueue
 #[derive(Debug, PartialEq)]
 pub enum DeleteQueueError {}
@@ -2535,6 +2606,11 @@ impl fmt::Display for DeleteQueueError {
     }
 }
 impl Error for DeleteQueueError {}
+impl ServiceErrorCode for DeleteQueueError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetQueueAttributes
 #[derive(Debug, PartialEq)]
 pub enum GetQueueAttributesError {
@@ -2579,6 +2655,13 @@ impl fmt::Display for GetQueueAttributesError {
     }
 }
 impl Error for GetQueueAttributesError {}
+impl ServiceErrorCode for GetQueueAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetQueueAttributesError::InvalidAttributeName(_) => "InvalidAttributeName",
+        }
+    }
+}
 /// Errors returned by GetQueueUrl
 #[derive(Debug, PartialEq)]
 pub enum GetQueueUrlError {
@@ -2623,6 +2706,13 @@ impl fmt::Display for GetQueueUrlError {
     }
 }
 impl Error for GetQueueUrlError {}
+impl ServiceErrorCode for GetQueueUrlError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetQueueUrlError::QueueDoesNotExist(_) => "AWS.SimpleQueueService.NonExistentQueue",
+        }
+    }
+}
 /// Errors returned by ListDeadLetterSourceQueues
 #[derive(Debug, PartialEq)]
 pub enum ListDeadLetterSourceQueuesError {
@@ -2671,6 +2761,15 @@ impl fmt::Display for ListDeadLetterSourceQueuesError {
     }
 }
 impl Error for ListDeadLetterSourceQueuesError {}
+impl ServiceErrorCode for ListDeadLetterSourceQueuesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDeadLetterSourceQueuesError::QueueDoesNotExist(_) => {
+                "AWS.SimpleQueueService.NonExistentQueue"
+            }
+        }
+    }
+}
 /// Errors returned by ListQueueTags
 #[derive(Debug, PartialEq)]
 pub enum ListQueueTagsError {}
@@ -2705,6 +2804,11 @@ impl fmt::Display for ListQueueTagsError {
     }
 }
 impl Error for ListQueueTagsError {}
+impl ServiceErrorCode for ListQueueTagsError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by ListQueues
 #[derive(Debug, PartialEq)]
 pub enum ListQueuesError {}
@@ -2739,6 +2843,11 @@ impl fmt::Display for ListQueuesError {
     }
 }
 impl Error for ListQueuesError {}
+impl ServiceErrorCode for ListQueuesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by PurgeQueue
 #[derive(Debug, PartialEq)]
 pub enum PurgeQueueError {
@@ -2791,6 +2900,16 @@ impl fmt::Display for PurgeQueueError {
     }
 }
 impl Error for PurgeQueueError {}
+impl ServiceErrorCode for PurgeQueueError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PurgeQueueError::PurgeQueueInProgress(_) => {
+                "AWS.SimpleQueueService.PurgeQueueInProgress"
+            }
+            PurgeQueueError::QueueDoesNotExist(_) => "AWS.SimpleQueueService.NonExistentQueue",
+        }
+    }
+}
 /// Errors returned by ReceiveMessage
 #[derive(Debug, PartialEq)]
 pub enum ReceiveMessageError {
@@ -2835,6 +2954,13 @@ impl fmt::Display for ReceiveMessageError {
     }
 }
 impl Error for ReceiveMessageError {}
+impl ServiceErrorCode for ReceiveMessageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ReceiveMessageError::OverLimit(_) => "OverLimit",
+        }
+    }
+}
 /// Errors returned by RemovePermission
 #[derive(Debug, PartialEq)]
 pub enum RemovePermissionError {}
@@ -2869,6 +2995,11 @@ impl fmt::Display for RemovePermissionError {
     }
 }
 impl Error for RemovePermissionError {}
+impl ServiceErrorCode for RemovePermissionError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by SendMessage
 #[derive(Debug, PartialEq)]
 pub enum SendMessageError {
@@ -2921,6 +3052,16 @@ impl fmt::Display for SendMessageError {
     }
 }
 impl Error for SendMessageError {}
+impl ServiceErrorCode for SendMessageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendMessageError::InvalidMessageContents(_) => "InvalidMessageContents",
+            SendMessageError::UnsupportedOperation(_) => {
+                "AWS.SimpleQueueService.UnsupportedOperation"
+            }
+        }
+    }
+}
 /// Errors returned by SendMessageBatch
 #[derive(Debug, PartialEq)]
 pub enum SendMessageBatchError {
@@ -3009,6 +3150,30 @@ impl fmt::Display for SendMessageBatchError {
     }
 }
 impl Error for SendMessageBatchError {}
+impl ServiceErrorCode for SendMessageBatchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendMessageBatchError::BatchEntryIdsNotDistinct(_) => {
+                "AWS.SimpleQueueService.BatchEntryIdsNotDistinct"
+            }
+            SendMessageBatchError::BatchRequestTooLong(_) => {
+                "AWS.SimpleQueueService.BatchRequestTooLong"
+            }
+            SendMessageBatchError::EmptyBatchRequest(_) => {
+                "AWS.SimpleQueueService.EmptyBatchRequest"
+            }
+            SendMessageBatchError::InvalidBatchEntryId(_) => {
+                "AWS.SimpleQueueService.InvalidBatchEntryId"
+            }
+            SendMessageBatchError::TooManyEntriesInBatchRequest(_) => {
+                "AWS.SimpleQueueService.TooManyEntriesInBatchRequest"
+            }
+            SendMessageBatchError::UnsupportedOperation(_) => {
+                "AWS.SimpleQueueService.UnsupportedOperation"
+            }
+        }
+    }
+}
 /// Errors returned by SetQueueAttributes
 #[derive(Debug, PartialEq)]
 pub enum SetQueueAttributesError {
@@ -3053,6 +3218,13 @@ impl fmt::Display for SetQueueAttributesError {
     }
 }
 impl Error for SetQueueAttributesError {}
+impl ServiceErrorCode for SetQueueAttributesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetQueueAttributesError::InvalidAttributeName(_) => "InvalidAttributeName",
+        }
+    }
+}
 /// Errors returned by TagQueue
 #[derive(Debug, PartialEq)]
 pub enum TagQueueError {}
@@ -3087,6 +3259,11 @@ impl fmt::Display for TagQueueError {
     }
 }
 impl Error for TagQueueError {}
+impl ServiceErrorCode for TagQueueError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UntagQueue
 #[derive(Debug, PartialEq)]
 pub enum UntagQueueError {}
@@ -3121,6 +3298,11 @@ impl fmt::Display for UntagQueueError {
     }
 }
 impl Error for UntagQueueError {}
+impl ServiceErrorCode for UntagQueueError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Trait representing the capabilities of the Amazon SQS API. Amazon SQS clients implement this trait.
 #[async_trait]
 pub trait Sqs {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1482,6 +1482,7 @@ pub struct CreateMaintenanceWindowRequest {
     #[serde(rename = "AllowUnassociatedTargets")]
     pub allow_unassociated_targets: bool,
     /// <p>User-provided idempotency token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -1644,6 +1645,7 @@ pub struct CreatePatchBaselineRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub approved_patches_enable_non_security: Option<bool>,
     /// <p>User-provided idempotency token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -1774,6 +1776,7 @@ pub struct DeleteDocumentResult {}
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteInventoryRequest {
     /// <p>User-provided idempotency token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -7051,6 +7054,7 @@ pub struct RegisterPatchBaselineForPatchGroupResult {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RegisterTargetWithMaintenanceWindowRequest {
     /// <p>User-provided idempotency token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -7090,6 +7094,7 @@ pub struct RegisterTargetWithMaintenanceWindowResult {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct RegisterTaskWithMaintenanceWindowRequest {
     /// <p>User-provided idempotency token.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -8878,6 +8883,17 @@ impl fmt::Display for AddTagsToResourceError {
     }
 }
 impl Error for AddTagsToResourceError {}
+impl ServiceErrorCode for AddTagsToResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsToResourceError::InternalServerError(_) => "InternalServerError",
+            AddTagsToResourceError::InvalidResourceId(_) => "InvalidResourceId",
+            AddTagsToResourceError::InvalidResourceType(_) => "InvalidResourceType",
+            AddTagsToResourceError::TooManyTagsError(_) => "TooManyTagsError",
+            AddTagsToResourceError::TooManyUpdates(_) => "TooManyUpdates",
+        }
+    }
+}
 /// Errors returned by CancelCommand
 #[derive(Debug, PartialEq)]
 pub enum CancelCommandError {
@@ -8926,6 +8942,16 @@ impl fmt::Display for CancelCommandError {
     }
 }
 impl Error for CancelCommandError {}
+impl ServiceErrorCode for CancelCommandError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelCommandError::DuplicateInstanceId(_) => "DuplicateInstanceId",
+            CancelCommandError::InternalServerError(_) => "InternalServerError",
+            CancelCommandError::InvalidCommandId(_) => "InvalidCommandId",
+            CancelCommandError::InvalidInstanceId(_) => "InvalidInstanceId",
+        }
+    }
+}
 /// Errors returned by CancelMaintenanceWindowExecution
 #[derive(Debug, PartialEq)]
 pub enum CancelMaintenanceWindowExecutionError {
@@ -8972,6 +8998,14 @@ impl fmt::Display for CancelMaintenanceWindowExecutionError {
     }
 }
 impl Error for CancelMaintenanceWindowExecutionError {}
+impl ServiceErrorCode for CancelMaintenanceWindowExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelMaintenanceWindowExecutionError::DoesNotExist(_) => "DoesNotExistException",
+            CancelMaintenanceWindowExecutionError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by CreateActivation
 #[derive(Debug, PartialEq)]
 pub enum CreateActivationError {
@@ -9004,6 +9038,13 @@ impl fmt::Display for CreateActivationError {
     }
 }
 impl Error for CreateActivationError {}
+impl ServiceErrorCode for CreateActivationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateActivationError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by CreateAssociation
 #[derive(Debug, PartialEq)]
 pub enum CreateAssociationError {
@@ -9106,6 +9147,23 @@ impl fmt::Display for CreateAssociationError {
     }
 }
 impl Error for CreateAssociationError {}
+impl ServiceErrorCode for CreateAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAssociationError::AssociationAlreadyExists(_) => "AssociationAlreadyExists",
+            CreateAssociationError::AssociationLimitExceeded(_) => "AssociationLimitExceeded",
+            CreateAssociationError::InternalServerError(_) => "InternalServerError",
+            CreateAssociationError::InvalidDocument(_) => "InvalidDocument",
+            CreateAssociationError::InvalidDocumentVersion(_) => "InvalidDocumentVersion",
+            CreateAssociationError::InvalidInstanceId(_) => "InvalidInstanceId",
+            CreateAssociationError::InvalidOutputLocation(_) => "InvalidOutputLocation",
+            CreateAssociationError::InvalidParameters(_) => "InvalidParameters",
+            CreateAssociationError::InvalidSchedule(_) => "InvalidSchedule",
+            CreateAssociationError::InvalidTarget(_) => "InvalidTarget",
+            CreateAssociationError::UnsupportedPlatformType(_) => "UnsupportedPlatformType",
+        }
+    }
+}
 /// Errors returned by CreateAssociationBatch
 #[derive(Debug, PartialEq)]
 pub enum CreateAssociationBatchError {
@@ -9224,6 +9282,23 @@ impl fmt::Display for CreateAssociationBatchError {
     }
 }
 impl Error for CreateAssociationBatchError {}
+impl ServiceErrorCode for CreateAssociationBatchError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAssociationBatchError::AssociationLimitExceeded(_) => "AssociationLimitExceeded",
+            CreateAssociationBatchError::DuplicateInstanceId(_) => "DuplicateInstanceId",
+            CreateAssociationBatchError::InternalServerError(_) => "InternalServerError",
+            CreateAssociationBatchError::InvalidDocument(_) => "InvalidDocument",
+            CreateAssociationBatchError::InvalidDocumentVersion(_) => "InvalidDocumentVersion",
+            CreateAssociationBatchError::InvalidInstanceId(_) => "InvalidInstanceId",
+            CreateAssociationBatchError::InvalidOutputLocation(_) => "InvalidOutputLocation",
+            CreateAssociationBatchError::InvalidParameters(_) => "InvalidParameters",
+            CreateAssociationBatchError::InvalidSchedule(_) => "InvalidSchedule",
+            CreateAssociationBatchError::InvalidTarget(_) => "InvalidTarget",
+            CreateAssociationBatchError::UnsupportedPlatformType(_) => "UnsupportedPlatformType",
+        }
+    }
+}
 /// Errors returned by CreateDocument
 #[derive(Debug, PartialEq)]
 pub enum CreateDocumentError {
@@ -9294,6 +9369,18 @@ impl fmt::Display for CreateDocumentError {
     }
 }
 impl Error for CreateDocumentError {}
+impl ServiceErrorCode for CreateDocumentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateDocumentError::DocumentAlreadyExists(_) => "DocumentAlreadyExists",
+            CreateDocumentError::DocumentLimitExceeded(_) => "DocumentLimitExceeded",
+            CreateDocumentError::InternalServerError(_) => "InternalServerError",
+            CreateDocumentError::InvalidDocumentContent(_) => "InvalidDocumentContent",
+            CreateDocumentError::InvalidDocumentSchemaVersion(_) => "InvalidDocumentSchemaVersion",
+            CreateDocumentError::MaxDocumentSizeExceeded(_) => "MaxDocumentSizeExceeded",
+        }
+    }
+}
 /// Errors returned by CreateMaintenanceWindow
 #[derive(Debug, PartialEq)]
 pub enum CreateMaintenanceWindowError {
@@ -9346,6 +9433,19 @@ impl fmt::Display for CreateMaintenanceWindowError {
     }
 }
 impl Error for CreateMaintenanceWindowError {}
+impl ServiceErrorCode for CreateMaintenanceWindowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMaintenanceWindowError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatch"
+            }
+            CreateMaintenanceWindowError::InternalServerError(_) => "InternalServerError",
+            CreateMaintenanceWindowError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateOpsItem
 #[derive(Debug, PartialEq)]
 pub enum CreateOpsItemError {
@@ -9396,6 +9496,16 @@ impl fmt::Display for CreateOpsItemError {
     }
 }
 impl Error for CreateOpsItemError {}
+impl ServiceErrorCode for CreateOpsItemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateOpsItemError::InternalServerError(_) => "InternalServerError",
+            CreateOpsItemError::OpsItemAlreadyExists(_) => "OpsItemAlreadyExistsException",
+            CreateOpsItemError::OpsItemInvalidParameter(_) => "OpsItemInvalidParameterException",
+            CreateOpsItemError::OpsItemLimitExceeded(_) => "OpsItemLimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateOpsMetadata
 #[derive(Debug, PartialEq)]
 pub enum CreateOpsMetadataError {
@@ -9460,6 +9570,25 @@ impl fmt::Display for CreateOpsMetadataError {
     }
 }
 impl Error for CreateOpsMetadataError {}
+impl ServiceErrorCode for CreateOpsMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateOpsMetadataError::InternalServerError(_) => "InternalServerError",
+            CreateOpsMetadataError::OpsMetadataAlreadyExists(_) => {
+                "OpsMetadataAlreadyExistsException"
+            }
+            CreateOpsMetadataError::OpsMetadataInvalidArgument(_) => {
+                "OpsMetadataInvalidArgumentException"
+            }
+            CreateOpsMetadataError::OpsMetadataLimitExceeded(_) => {
+                "OpsMetadataLimitExceededException"
+            }
+            CreateOpsMetadataError::OpsMetadataTooManyUpdates(_) => {
+                "OpsMetadataTooManyUpdatesException"
+            }
+        }
+    }
+}
 /// Errors returned by CreatePatchBaseline
 #[derive(Debug, PartialEq)]
 pub enum CreatePatchBaselineError {
@@ -9510,6 +9639,17 @@ impl fmt::Display for CreatePatchBaselineError {
     }
 }
 impl Error for CreatePatchBaselineError {}
+impl ServiceErrorCode for CreatePatchBaselineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreatePatchBaselineError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatch"
+            }
+            CreatePatchBaselineError::InternalServerError(_) => "InternalServerError",
+            CreatePatchBaselineError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateResourceDataSync
 #[derive(Debug, PartialEq)]
 pub enum CreateResourceDataSyncError {
@@ -9572,6 +9712,22 @@ impl fmt::Display for CreateResourceDataSyncError {
     }
 }
 impl Error for CreateResourceDataSyncError {}
+impl ServiceErrorCode for CreateResourceDataSyncError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateResourceDataSyncError::InternalServerError(_) => "InternalServerError",
+            CreateResourceDataSyncError::ResourceDataSyncAlreadyExists(_) => {
+                "ResourceDataSyncAlreadyExistsException"
+            }
+            CreateResourceDataSyncError::ResourceDataSyncCountExceeded(_) => {
+                "ResourceDataSyncCountExceededException"
+            }
+            CreateResourceDataSyncError::ResourceDataSyncInvalidConfiguration(_) => {
+                "ResourceDataSyncInvalidConfigurationException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteActivation
 #[derive(Debug, PartialEq)]
 pub enum DeleteActivationError {
@@ -9624,6 +9780,16 @@ impl fmt::Display for DeleteActivationError {
     }
 }
 impl Error for DeleteActivationError {}
+impl ServiceErrorCode for DeleteActivationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteActivationError::InternalServerError(_) => "InternalServerError",
+            DeleteActivationError::InvalidActivation(_) => "InvalidActivation",
+            DeleteActivationError::InvalidActivationId(_) => "InvalidActivationId",
+            DeleteActivationError::TooManyUpdates(_) => "TooManyUpdates",
+        }
+    }
+}
 /// Errors returned by DeleteAssociation
 #[derive(Debug, PartialEq)]
 pub enum DeleteAssociationError {
@@ -9682,6 +9848,17 @@ impl fmt::Display for DeleteAssociationError {
     }
 }
 impl Error for DeleteAssociationError {}
+impl ServiceErrorCode for DeleteAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAssociationError::AssociationDoesNotExist(_) => "AssociationDoesNotExist",
+            DeleteAssociationError::InternalServerError(_) => "InternalServerError",
+            DeleteAssociationError::InvalidDocument(_) => "InvalidDocument",
+            DeleteAssociationError::InvalidInstanceId(_) => "InvalidInstanceId",
+            DeleteAssociationError::TooManyUpdates(_) => "TooManyUpdates",
+        }
+    }
+}
 /// Errors returned by DeleteDocument
 #[derive(Debug, PartialEq)]
 pub enum DeleteDocumentError {
@@ -9732,6 +9909,16 @@ impl fmt::Display for DeleteDocumentError {
     }
 }
 impl Error for DeleteDocumentError {}
+impl ServiceErrorCode for DeleteDocumentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDocumentError::AssociatedInstances(_) => "AssociatedInstances",
+            DeleteDocumentError::InternalServerError(_) => "InternalServerError",
+            DeleteDocumentError::InvalidDocument(_) => "InvalidDocument",
+            DeleteDocumentError::InvalidDocumentOperation(_) => "InvalidDocumentOperation",
+        }
+    }
+}
 /// Errors returned by DeleteInventory
 #[derive(Debug, PartialEq)]
 pub enum DeleteInventoryError {
@@ -9792,6 +9979,19 @@ impl fmt::Display for DeleteInventoryError {
     }
 }
 impl Error for DeleteInventoryError {}
+impl ServiceErrorCode for DeleteInventoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteInventoryError::InternalServerError(_) => "InternalServerError",
+            DeleteInventoryError::InvalidDeleteInventoryParameters(_) => {
+                "InvalidDeleteInventoryParametersException"
+            }
+            DeleteInventoryError::InvalidInventoryRequest(_) => "InvalidInventoryRequestException",
+            DeleteInventoryError::InvalidOption(_) => "InvalidOptionException",
+            DeleteInventoryError::InvalidTypeName(_) => "InvalidTypeNameException",
+        }
+    }
+}
 /// Errors returned by DeleteMaintenanceWindow
 #[derive(Debug, PartialEq)]
 pub enum DeleteMaintenanceWindowError {
@@ -9824,6 +10024,13 @@ impl fmt::Display for DeleteMaintenanceWindowError {
     }
 }
 impl Error for DeleteMaintenanceWindowError {}
+impl ServiceErrorCode for DeleteMaintenanceWindowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMaintenanceWindowError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DeleteOpsMetadata
 #[derive(Debug, PartialEq)]
 pub enum DeleteOpsMetadataError {
@@ -9872,6 +10079,17 @@ impl fmt::Display for DeleteOpsMetadataError {
     }
 }
 impl Error for DeleteOpsMetadataError {}
+impl ServiceErrorCode for DeleteOpsMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteOpsMetadataError::InternalServerError(_) => "InternalServerError",
+            DeleteOpsMetadataError::OpsMetadataInvalidArgument(_) => {
+                "OpsMetadataInvalidArgumentException"
+            }
+            DeleteOpsMetadataError::OpsMetadataNotFound(_) => "OpsMetadataNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteParameter
 #[derive(Debug, PartialEq)]
 pub enum DeleteParameterError {
@@ -9908,6 +10126,14 @@ impl fmt::Display for DeleteParameterError {
     }
 }
 impl Error for DeleteParameterError {}
+impl ServiceErrorCode for DeleteParameterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteParameterError::InternalServerError(_) => "InternalServerError",
+            DeleteParameterError::ParameterNotFound(_) => "ParameterNotFound",
+        }
+    }
+}
 /// Errors returned by DeleteParameters
 #[derive(Debug, PartialEq)]
 pub enum DeleteParametersError {
@@ -9940,6 +10166,13 @@ impl fmt::Display for DeleteParametersError {
     }
 }
 impl Error for DeleteParametersError {}
+impl ServiceErrorCode for DeleteParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteParametersError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DeletePatchBaseline
 #[derive(Debug, PartialEq)]
 pub enum DeletePatchBaselineError {
@@ -9978,6 +10211,14 @@ impl fmt::Display for DeletePatchBaselineError {
     }
 }
 impl Error for DeletePatchBaselineError {}
+impl ServiceErrorCode for DeletePatchBaselineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePatchBaselineError::InternalServerError(_) => "InternalServerError",
+            DeletePatchBaselineError::ResourceInUse(_) => "ResourceInUseException",
+        }
+    }
+}
 /// Errors returned by DeleteResourceDataSync
 #[derive(Debug, PartialEq)]
 pub enum DeleteResourceDataSyncError {
@@ -10030,6 +10271,19 @@ impl fmt::Display for DeleteResourceDataSyncError {
     }
 }
 impl Error for DeleteResourceDataSyncError {}
+impl ServiceErrorCode for DeleteResourceDataSyncError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResourceDataSyncError::InternalServerError(_) => "InternalServerError",
+            DeleteResourceDataSyncError::ResourceDataSyncInvalidConfiguration(_) => {
+                "ResourceDataSyncInvalidConfigurationException"
+            }
+            DeleteResourceDataSyncError::ResourceDataSyncNotFound(_) => {
+                "ResourceDataSyncNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DeregisterManagedInstance
 #[derive(Debug, PartialEq)]
 pub enum DeregisterManagedInstanceError {
@@ -10072,6 +10326,14 @@ impl fmt::Display for DeregisterManagedInstanceError {
     }
 }
 impl Error for DeregisterManagedInstanceError {}
+impl ServiceErrorCode for DeregisterManagedInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterManagedInstanceError::InternalServerError(_) => "InternalServerError",
+            DeregisterManagedInstanceError::InvalidInstanceId(_) => "InvalidInstanceId",
+        }
+    }
+}
 /// Errors returned by DeregisterPatchBaselineForPatchGroup
 #[derive(Debug, PartialEq)]
 pub enum DeregisterPatchBaselineForPatchGroupError {
@@ -10118,6 +10380,16 @@ impl fmt::Display for DeregisterPatchBaselineForPatchGroupError {
     }
 }
 impl Error for DeregisterPatchBaselineForPatchGroupError {}
+impl ServiceErrorCode for DeregisterPatchBaselineForPatchGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterPatchBaselineForPatchGroupError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+            DeregisterPatchBaselineForPatchGroupError::InvalidResourceId(_) => "InvalidResourceId",
+        }
+    }
+}
 /// Errors returned by DeregisterTargetFromMaintenanceWindow
 #[derive(Debug, PartialEq)]
 pub enum DeregisterTargetFromMaintenanceWindowError {
@@ -10174,6 +10446,17 @@ impl fmt::Display for DeregisterTargetFromMaintenanceWindowError {
     }
 }
 impl Error for DeregisterTargetFromMaintenanceWindowError {}
+impl ServiceErrorCode for DeregisterTargetFromMaintenanceWindowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterTargetFromMaintenanceWindowError::DoesNotExist(_) => "DoesNotExistException",
+            DeregisterTargetFromMaintenanceWindowError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+            DeregisterTargetFromMaintenanceWindowError::TargetInUse(_) => "TargetInUseException",
+        }
+    }
+}
 /// Errors returned by DeregisterTaskFromMaintenanceWindow
 #[derive(Debug, PartialEq)]
 pub enum DeregisterTaskFromMaintenanceWindowError {
@@ -10220,6 +10503,16 @@ impl fmt::Display for DeregisterTaskFromMaintenanceWindowError {
     }
 }
 impl Error for DeregisterTaskFromMaintenanceWindowError {}
+impl ServiceErrorCode for DeregisterTaskFromMaintenanceWindowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterTaskFromMaintenanceWindowError::DoesNotExist(_) => "DoesNotExistException",
+            DeregisterTaskFromMaintenanceWindowError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeActivations
 #[derive(Debug, PartialEq)]
 pub enum DescribeActivationsError {
@@ -10266,6 +10559,15 @@ impl fmt::Display for DescribeActivationsError {
     }
 }
 impl Error for DescribeActivationsError {}
+impl ServiceErrorCode for DescribeActivationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeActivationsError::InternalServerError(_) => "InternalServerError",
+            DescribeActivationsError::InvalidFilter(_) => "InvalidFilter",
+            DescribeActivationsError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DescribeAssociation
 #[derive(Debug, PartialEq)]
 pub enum DescribeAssociationError {
@@ -10330,6 +10632,17 @@ impl fmt::Display for DescribeAssociationError {
     }
 }
 impl Error for DescribeAssociationError {}
+impl ServiceErrorCode for DescribeAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAssociationError::AssociationDoesNotExist(_) => "AssociationDoesNotExist",
+            DescribeAssociationError::InternalServerError(_) => "InternalServerError",
+            DescribeAssociationError::InvalidAssociationVersion(_) => "InvalidAssociationVersion",
+            DescribeAssociationError::InvalidDocument(_) => "InvalidDocument",
+            DescribeAssociationError::InvalidInstanceId(_) => "InvalidInstanceId",
+        }
+    }
+}
 /// Errors returned by DescribeAssociationExecutionTargets
 #[derive(Debug, PartialEq)]
 pub enum DescribeAssociationExecutionTargetsError {
@@ -10398,6 +10711,22 @@ impl fmt::Display for DescribeAssociationExecutionTargetsError {
     }
 }
 impl Error for DescribeAssociationExecutionTargetsError {}
+impl ServiceErrorCode for DescribeAssociationExecutionTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAssociationExecutionTargetsError::AssociationDoesNotExist(_) => {
+                "AssociationDoesNotExist"
+            }
+            DescribeAssociationExecutionTargetsError::AssociationExecutionDoesNotExist(_) => {
+                "AssociationExecutionDoesNotExist"
+            }
+            DescribeAssociationExecutionTargetsError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+            DescribeAssociationExecutionTargetsError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DescribeAssociationExecutions
 #[derive(Debug, PartialEq)]
 pub enum DescribeAssociationExecutionsError {
@@ -10454,6 +10783,17 @@ impl fmt::Display for DescribeAssociationExecutionsError {
     }
 }
 impl Error for DescribeAssociationExecutionsError {}
+impl ServiceErrorCode for DescribeAssociationExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAssociationExecutionsError::AssociationDoesNotExist(_) => {
+                "AssociationDoesNotExist"
+            }
+            DescribeAssociationExecutionsError::InternalServerError(_) => "InternalServerError",
+            DescribeAssociationExecutionsError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DescribeAutomationExecutions
 #[derive(Debug, PartialEq)]
 pub enum DescribeAutomationExecutionsError {
@@ -10520,6 +10860,16 @@ impl fmt::Display for DescribeAutomationExecutionsError {
     }
 }
 impl Error for DescribeAutomationExecutionsError {}
+impl ServiceErrorCode for DescribeAutomationExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAutomationExecutionsError::InternalServerError(_) => "InternalServerError",
+            DescribeAutomationExecutionsError::InvalidFilterKey(_) => "InvalidFilterKey",
+            DescribeAutomationExecutionsError::InvalidFilterValue(_) => "InvalidFilterValue",
+            DescribeAutomationExecutionsError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DescribeAutomationStepExecutions
 #[derive(Debug, PartialEq)]
 pub enum DescribeAutomationStepExecutionsError {
@@ -10596,6 +10946,19 @@ impl fmt::Display for DescribeAutomationStepExecutionsError {
     }
 }
 impl Error for DescribeAutomationStepExecutionsError {}
+impl ServiceErrorCode for DescribeAutomationStepExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAutomationStepExecutionsError::AutomationExecutionNotFound(_) => {
+                "AutomationExecutionNotFoundException"
+            }
+            DescribeAutomationStepExecutionsError::InternalServerError(_) => "InternalServerError",
+            DescribeAutomationStepExecutionsError::InvalidFilterKey(_) => "InvalidFilterKey",
+            DescribeAutomationStepExecutionsError::InvalidFilterValue(_) => "InvalidFilterValue",
+            DescribeAutomationStepExecutionsError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DescribeAvailablePatches
 #[derive(Debug, PartialEq)]
 pub enum DescribeAvailablePatchesError {
@@ -10628,6 +10991,13 @@ impl fmt::Display for DescribeAvailablePatchesError {
     }
 }
 impl Error for DescribeAvailablePatchesError {}
+impl ServiceErrorCode for DescribeAvailablePatchesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAvailablePatchesError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeDocument
 #[derive(Debug, PartialEq)]
 pub enum DescribeDocumentError {
@@ -10674,6 +11044,15 @@ impl fmt::Display for DescribeDocumentError {
     }
 }
 impl Error for DescribeDocumentError {}
+impl ServiceErrorCode for DescribeDocumentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDocumentError::InternalServerError(_) => "InternalServerError",
+            DescribeDocumentError::InvalidDocument(_) => "InvalidDocument",
+            DescribeDocumentError::InvalidDocumentVersion(_) => "InvalidDocumentVersion",
+        }
+    }
+}
 /// Errors returned by DescribeDocumentPermission
 #[derive(Debug, PartialEq)]
 pub enum DescribeDocumentPermissionError {
@@ -10728,6 +11107,15 @@ impl fmt::Display for DescribeDocumentPermissionError {
     }
 }
 impl Error for DescribeDocumentPermissionError {}
+impl ServiceErrorCode for DescribeDocumentPermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDocumentPermissionError::InternalServerError(_) => "InternalServerError",
+            DescribeDocumentPermissionError::InvalidDocument(_) => "InvalidDocument",
+            DescribeDocumentPermissionError::InvalidPermissionType(_) => "InvalidPermissionType",
+        }
+    }
+}
 /// Errors returned by DescribeEffectiveInstanceAssociations
 #[derive(Debug, PartialEq)]
 pub enum DescribeEffectiveInstanceAssociationsError {
@@ -10784,6 +11172,17 @@ impl fmt::Display for DescribeEffectiveInstanceAssociationsError {
     }
 }
 impl Error for DescribeEffectiveInstanceAssociationsError {}
+impl ServiceErrorCode for DescribeEffectiveInstanceAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEffectiveInstanceAssociationsError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+            DescribeEffectiveInstanceAssociationsError::InvalidInstanceId(_) => "InvalidInstanceId",
+            DescribeEffectiveInstanceAssociationsError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DescribeEffectivePatchesForPatchBaseline
 #[derive(Debug, PartialEq)]
 pub enum DescribeEffectivePatchesForPatchBaselineError {
@@ -10852,6 +11251,24 @@ impl fmt::Display for DescribeEffectivePatchesForPatchBaselineError {
     }
 }
 impl Error for DescribeEffectivePatchesForPatchBaselineError {}
+impl ServiceErrorCode for DescribeEffectivePatchesForPatchBaselineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeEffectivePatchesForPatchBaselineError::DoesNotExist(_) => {
+                "DoesNotExistException"
+            }
+            DescribeEffectivePatchesForPatchBaselineError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+            DescribeEffectivePatchesForPatchBaselineError::InvalidResourceId(_) => {
+                "InvalidResourceId"
+            }
+            DescribeEffectivePatchesForPatchBaselineError::UnsupportedOperatingSystem(_) => {
+                "UnsupportedOperatingSystem"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeInstanceAssociationsStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstanceAssociationsStatusError {
@@ -10908,6 +11325,17 @@ impl fmt::Display for DescribeInstanceAssociationsStatusError {
     }
 }
 impl Error for DescribeInstanceAssociationsStatusError {}
+impl ServiceErrorCode for DescribeInstanceAssociationsStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInstanceAssociationsStatusError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+            DescribeInstanceAssociationsStatusError::InvalidInstanceId(_) => "InvalidInstanceId",
+            DescribeInstanceAssociationsStatusError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DescribeInstanceInformation
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstanceInformationError {
@@ -10982,6 +11410,19 @@ impl fmt::Display for DescribeInstanceInformationError {
     }
 }
 impl Error for DescribeInstanceInformationError {}
+impl ServiceErrorCode for DescribeInstanceInformationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInstanceInformationError::InternalServerError(_) => "InternalServerError",
+            DescribeInstanceInformationError::InvalidFilterKey(_) => "InvalidFilterKey",
+            DescribeInstanceInformationError::InvalidInstanceId(_) => "InvalidInstanceId",
+            DescribeInstanceInformationError::InvalidInstanceInformationFilterValue(_) => {
+                "InvalidInstanceInformationFilterValue"
+            }
+            DescribeInstanceInformationError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DescribeInstancePatchStates
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstancePatchStatesError {
@@ -11026,6 +11467,14 @@ impl fmt::Display for DescribeInstancePatchStatesError {
     }
 }
 impl Error for DescribeInstancePatchStatesError {}
+impl ServiceErrorCode for DescribeInstancePatchStatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInstancePatchStatesError::InternalServerError(_) => "InternalServerError",
+            DescribeInstancePatchStatesError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DescribeInstancePatchStatesForPatchGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstancePatchStatesForPatchGroupError {
@@ -11082,6 +11531,19 @@ impl fmt::Display for DescribeInstancePatchStatesForPatchGroupError {
     }
 }
 impl Error for DescribeInstancePatchStatesForPatchGroupError {}
+impl ServiceErrorCode for DescribeInstancePatchStatesForPatchGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInstancePatchStatesForPatchGroupError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+            DescribeInstancePatchStatesForPatchGroupError::InvalidFilter(_) => "InvalidFilter",
+            DescribeInstancePatchStatesForPatchGroupError::InvalidNextToken(_) => {
+                "InvalidNextToken"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeInstancePatches
 #[derive(Debug, PartialEq)]
 pub enum DescribeInstancePatchesError {
@@ -11138,6 +11600,16 @@ impl fmt::Display for DescribeInstancePatchesError {
     }
 }
 impl Error for DescribeInstancePatchesError {}
+impl ServiceErrorCode for DescribeInstancePatchesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInstancePatchesError::InternalServerError(_) => "InternalServerError",
+            DescribeInstancePatchesError::InvalidFilter(_) => "InvalidFilter",
+            DescribeInstancePatchesError::InvalidInstanceId(_) => "InvalidInstanceId",
+            DescribeInstancePatchesError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DescribeInventoryDeletions
 #[derive(Debug, PartialEq)]
 pub enum DescribeInventoryDeletionsError {
@@ -11190,6 +11662,15 @@ impl fmt::Display for DescribeInventoryDeletionsError {
     }
 }
 impl Error for DescribeInventoryDeletionsError {}
+impl ServiceErrorCode for DescribeInventoryDeletionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeInventoryDeletionsError::InternalServerError(_) => "InternalServerError",
+            DescribeInventoryDeletionsError::InvalidDeletionId(_) => "InvalidDeletionIdException",
+            DescribeInventoryDeletionsError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DescribeMaintenanceWindowExecutionTaskInvocations
 #[derive(Debug, PartialEq)]
 pub enum DescribeMaintenanceWindowExecutionTaskInvocationsError {
@@ -11240,6 +11721,18 @@ impl fmt::Display for DescribeMaintenanceWindowExecutionTaskInvocationsError {
     }
 }
 impl Error for DescribeMaintenanceWindowExecutionTaskInvocationsError {}
+impl ServiceErrorCode for DescribeMaintenanceWindowExecutionTaskInvocationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMaintenanceWindowExecutionTaskInvocationsError::DoesNotExist(_) => {
+                "DoesNotExistException"
+            }
+            DescribeMaintenanceWindowExecutionTaskInvocationsError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeMaintenanceWindowExecutionTasks
 #[derive(Debug, PartialEq)]
 pub enum DescribeMaintenanceWindowExecutionTasksError {
@@ -11286,6 +11779,18 @@ impl fmt::Display for DescribeMaintenanceWindowExecutionTasksError {
     }
 }
 impl Error for DescribeMaintenanceWindowExecutionTasksError {}
+impl ServiceErrorCode for DescribeMaintenanceWindowExecutionTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMaintenanceWindowExecutionTasksError::DoesNotExist(_) => {
+                "DoesNotExistException"
+            }
+            DescribeMaintenanceWindowExecutionTasksError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeMaintenanceWindowExecutions
 #[derive(Debug, PartialEq)]
 pub enum DescribeMaintenanceWindowExecutionsError {
@@ -11322,6 +11827,15 @@ impl fmt::Display for DescribeMaintenanceWindowExecutionsError {
     }
 }
 impl Error for DescribeMaintenanceWindowExecutionsError {}
+impl ServiceErrorCode for DescribeMaintenanceWindowExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMaintenanceWindowExecutionsError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeMaintenanceWindowSchedule
 #[derive(Debug, PartialEq)]
 pub enum DescribeMaintenanceWindowScheduleError {
@@ -11368,6 +11882,14 @@ impl fmt::Display for DescribeMaintenanceWindowScheduleError {
     }
 }
 impl Error for DescribeMaintenanceWindowScheduleError {}
+impl ServiceErrorCode for DescribeMaintenanceWindowScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMaintenanceWindowScheduleError::DoesNotExist(_) => "DoesNotExistException",
+            DescribeMaintenanceWindowScheduleError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeMaintenanceWindowTargets
 #[derive(Debug, PartialEq)]
 pub enum DescribeMaintenanceWindowTargetsError {
@@ -11414,6 +11936,14 @@ impl fmt::Display for DescribeMaintenanceWindowTargetsError {
     }
 }
 impl Error for DescribeMaintenanceWindowTargetsError {}
+impl ServiceErrorCode for DescribeMaintenanceWindowTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMaintenanceWindowTargetsError::DoesNotExist(_) => "DoesNotExistException",
+            DescribeMaintenanceWindowTargetsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeMaintenanceWindowTasks
 #[derive(Debug, PartialEq)]
 pub enum DescribeMaintenanceWindowTasksError {
@@ -11458,6 +11988,14 @@ impl fmt::Display for DescribeMaintenanceWindowTasksError {
     }
 }
 impl Error for DescribeMaintenanceWindowTasksError {}
+impl ServiceErrorCode for DescribeMaintenanceWindowTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMaintenanceWindowTasksError::DoesNotExist(_) => "DoesNotExistException",
+            DescribeMaintenanceWindowTasksError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeMaintenanceWindows
 #[derive(Debug, PartialEq)]
 pub enum DescribeMaintenanceWindowsError {
@@ -11494,6 +12032,13 @@ impl fmt::Display for DescribeMaintenanceWindowsError {
     }
 }
 impl Error for DescribeMaintenanceWindowsError {}
+impl ServiceErrorCode for DescribeMaintenanceWindowsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMaintenanceWindowsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeMaintenanceWindowsForTarget
 #[derive(Debug, PartialEq)]
 pub enum DescribeMaintenanceWindowsForTargetError {
@@ -11530,6 +12075,15 @@ impl fmt::Display for DescribeMaintenanceWindowsForTargetError {
     }
 }
 impl Error for DescribeMaintenanceWindowsForTargetError {}
+impl ServiceErrorCode for DescribeMaintenanceWindowsForTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMaintenanceWindowsForTargetError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeOpsItems
 #[derive(Debug, PartialEq)]
 pub enum DescribeOpsItemsError {
@@ -11562,6 +12116,13 @@ impl fmt::Display for DescribeOpsItemsError {
     }
 }
 impl Error for DescribeOpsItemsError {}
+impl ServiceErrorCode for DescribeOpsItemsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeOpsItemsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeParameters
 #[derive(Debug, PartialEq)]
 pub enum DescribeParametersError {
@@ -11622,6 +12183,17 @@ impl fmt::Display for DescribeParametersError {
     }
 }
 impl Error for DescribeParametersError {}
+impl ServiceErrorCode for DescribeParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeParametersError::InternalServerError(_) => "InternalServerError",
+            DescribeParametersError::InvalidFilterKey(_) => "InvalidFilterKey",
+            DescribeParametersError::InvalidFilterOption(_) => "InvalidFilterOption",
+            DescribeParametersError::InvalidFilterValue(_) => "InvalidFilterValue",
+            DescribeParametersError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DescribePatchBaselines
 #[derive(Debug, PartialEq)]
 pub enum DescribePatchBaselinesError {
@@ -11654,6 +12226,13 @@ impl fmt::Display for DescribePatchBaselinesError {
     }
 }
 impl Error for DescribePatchBaselinesError {}
+impl ServiceErrorCode for DescribePatchBaselinesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePatchBaselinesError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribePatchGroupState
 #[derive(Debug, PartialEq)]
 pub enum DescribePatchGroupStateError {
@@ -11694,6 +12273,14 @@ impl fmt::Display for DescribePatchGroupStateError {
     }
 }
 impl Error for DescribePatchGroupStateError {}
+impl ServiceErrorCode for DescribePatchGroupStateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePatchGroupStateError::InternalServerError(_) => "InternalServerError",
+            DescribePatchGroupStateError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by DescribePatchGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribePatchGroupsError {
@@ -11726,6 +12313,13 @@ impl fmt::Display for DescribePatchGroupsError {
     }
 }
 impl Error for DescribePatchGroupsError {}
+impl ServiceErrorCode for DescribePatchGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePatchGroupsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribePatchProperties
 #[derive(Debug, PartialEq)]
 pub enum DescribePatchPropertiesError {
@@ -11758,6 +12352,13 @@ impl fmt::Display for DescribePatchPropertiesError {
     }
 }
 impl Error for DescribePatchPropertiesError {}
+impl ServiceErrorCode for DescribePatchPropertiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribePatchPropertiesError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeSessions
 #[derive(Debug, PartialEq)]
 pub enum DescribeSessionsError {
@@ -11802,6 +12403,15 @@ impl fmt::Display for DescribeSessionsError {
     }
 }
 impl Error for DescribeSessionsError {}
+impl ServiceErrorCode for DescribeSessionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSessionsError::InternalServerError(_) => "InternalServerError",
+            DescribeSessionsError::InvalidFilterKey(_) => "InvalidFilterKey",
+            DescribeSessionsError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by GetAutomationExecution
 #[derive(Debug, PartialEq)]
 pub enum GetAutomationExecutionError {
@@ -11844,6 +12454,16 @@ impl fmt::Display for GetAutomationExecutionError {
     }
 }
 impl Error for GetAutomationExecutionError {}
+impl ServiceErrorCode for GetAutomationExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAutomationExecutionError::AutomationExecutionNotFound(_) => {
+                "AutomationExecutionNotFoundException"
+            }
+            GetAutomationExecutionError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by GetCalendarState
 #[derive(Debug, PartialEq)]
 pub enum GetCalendarStateError {
@@ -11898,6 +12518,16 @@ impl fmt::Display for GetCalendarStateError {
     }
 }
 impl Error for GetCalendarStateError {}
+impl ServiceErrorCode for GetCalendarStateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCalendarStateError::InternalServerError(_) => "InternalServerError",
+            GetCalendarStateError::InvalidDocument(_) => "InvalidDocument",
+            GetCalendarStateError::InvalidDocumentType(_) => "InvalidDocumentType",
+            GetCalendarStateError::UnsupportedCalendar(_) => "UnsupportedCalendarException",
+        }
+    }
+}
 /// Errors returned by GetCommandInvocation
 #[derive(Debug, PartialEq)]
 pub enum GetCommandInvocationError {
@@ -11962,6 +12592,17 @@ impl fmt::Display for GetCommandInvocationError {
     }
 }
 impl Error for GetCommandInvocationError {}
+impl ServiceErrorCode for GetCommandInvocationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCommandInvocationError::InternalServerError(_) => "InternalServerError",
+            GetCommandInvocationError::InvalidCommandId(_) => "InvalidCommandId",
+            GetCommandInvocationError::InvalidInstanceId(_) => "InvalidInstanceId",
+            GetCommandInvocationError::InvalidPluginName(_) => "InvalidPluginName",
+            GetCommandInvocationError::InvocationDoesNotExist(_) => "InvocationDoesNotExist",
+        }
+    }
+}
 /// Errors returned by GetConnectionStatus
 #[derive(Debug, PartialEq)]
 pub enum GetConnectionStatusError {
@@ -11994,6 +12635,13 @@ impl fmt::Display for GetConnectionStatusError {
     }
 }
 impl Error for GetConnectionStatusError {}
+impl ServiceErrorCode for GetConnectionStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetConnectionStatusError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by GetDefaultPatchBaseline
 #[derive(Debug, PartialEq)]
 pub enum GetDefaultPatchBaselineError {
@@ -12026,6 +12674,13 @@ impl fmt::Display for GetDefaultPatchBaselineError {
     }
 }
 impl Error for GetDefaultPatchBaselineError {}
+impl ServiceErrorCode for GetDefaultPatchBaselineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDefaultPatchBaselineError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by GetDeployablePatchSnapshotForInstance
 #[derive(Debug, PartialEq)]
 pub enum GetDeployablePatchSnapshotForInstanceError {
@@ -12086,6 +12741,21 @@ impl fmt::Display for GetDeployablePatchSnapshotForInstanceError {
     }
 }
 impl Error for GetDeployablePatchSnapshotForInstanceError {}
+impl ServiceErrorCode for GetDeployablePatchSnapshotForInstanceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDeployablePatchSnapshotForInstanceError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+            GetDeployablePatchSnapshotForInstanceError::UnsupportedFeatureRequired(_) => {
+                "UnsupportedFeatureRequiredException"
+            }
+            GetDeployablePatchSnapshotForInstanceError::UnsupportedOperatingSystem(_) => {
+                "UnsupportedOperatingSystem"
+            }
+        }
+    }
+}
 /// Errors returned by GetDocument
 #[derive(Debug, PartialEq)]
 pub enum GetDocumentError {
@@ -12128,6 +12798,15 @@ impl fmt::Display for GetDocumentError {
     }
 }
 impl Error for GetDocumentError {}
+impl ServiceErrorCode for GetDocumentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDocumentError::InternalServerError(_) => "InternalServerError",
+            GetDocumentError::InvalidDocument(_) => "InvalidDocument",
+            GetDocumentError::InvalidDocumentVersion(_) => "InvalidDocumentVersion",
+        }
+    }
+}
 /// Errors returned by GetInventory
 #[derive(Debug, PartialEq)]
 pub enum GetInventoryError {
@@ -12194,6 +12873,19 @@ impl fmt::Display for GetInventoryError {
     }
 }
 impl Error for GetInventoryError {}
+impl ServiceErrorCode for GetInventoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInventoryError::InternalServerError(_) => "InternalServerError",
+            GetInventoryError::InvalidAggregator(_) => "InvalidAggregatorException",
+            GetInventoryError::InvalidFilter(_) => "InvalidFilter",
+            GetInventoryError::InvalidInventoryGroup(_) => "InvalidInventoryGroupException",
+            GetInventoryError::InvalidNextToken(_) => "InvalidNextToken",
+            GetInventoryError::InvalidResultAttribute(_) => "InvalidResultAttributeException",
+            GetInventoryError::InvalidTypeName(_) => "InvalidTypeNameException",
+        }
+    }
+}
 /// Errors returned by GetInventorySchema
 #[derive(Debug, PartialEq)]
 pub enum GetInventorySchemaError {
@@ -12238,6 +12930,15 @@ impl fmt::Display for GetInventorySchemaError {
     }
 }
 impl Error for GetInventorySchemaError {}
+impl ServiceErrorCode for GetInventorySchemaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInventorySchemaError::InternalServerError(_) => "InternalServerError",
+            GetInventorySchemaError::InvalidNextToken(_) => "InvalidNextToken",
+            GetInventorySchemaError::InvalidTypeName(_) => "InvalidTypeNameException",
+        }
+    }
+}
 /// Errors returned by GetMaintenanceWindow
 #[derive(Debug, PartialEq)]
 pub enum GetMaintenanceWindowError {
@@ -12276,6 +12977,14 @@ impl fmt::Display for GetMaintenanceWindowError {
     }
 }
 impl Error for GetMaintenanceWindowError {}
+impl ServiceErrorCode for GetMaintenanceWindowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMaintenanceWindowError::DoesNotExist(_) => "DoesNotExistException",
+            GetMaintenanceWindowError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by GetMaintenanceWindowExecution
 #[derive(Debug, PartialEq)]
 pub enum GetMaintenanceWindowExecutionError {
@@ -12320,6 +13029,14 @@ impl fmt::Display for GetMaintenanceWindowExecutionError {
     }
 }
 impl Error for GetMaintenanceWindowExecutionError {}
+impl ServiceErrorCode for GetMaintenanceWindowExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMaintenanceWindowExecutionError::DoesNotExist(_) => "DoesNotExistException",
+            GetMaintenanceWindowExecutionError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by GetMaintenanceWindowExecutionTask
 #[derive(Debug, PartialEq)]
 pub enum GetMaintenanceWindowExecutionTaskError {
@@ -12366,6 +13083,14 @@ impl fmt::Display for GetMaintenanceWindowExecutionTaskError {
     }
 }
 impl Error for GetMaintenanceWindowExecutionTaskError {}
+impl ServiceErrorCode for GetMaintenanceWindowExecutionTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMaintenanceWindowExecutionTaskError::DoesNotExist(_) => "DoesNotExistException",
+            GetMaintenanceWindowExecutionTaskError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by GetMaintenanceWindowExecutionTaskInvocation
 #[derive(Debug, PartialEq)]
 pub enum GetMaintenanceWindowExecutionTaskInvocationError {
@@ -12414,6 +13139,18 @@ impl fmt::Display for GetMaintenanceWindowExecutionTaskInvocationError {
     }
 }
 impl Error for GetMaintenanceWindowExecutionTaskInvocationError {}
+impl ServiceErrorCode for GetMaintenanceWindowExecutionTaskInvocationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMaintenanceWindowExecutionTaskInvocationError::DoesNotExist(_) => {
+                "DoesNotExistException"
+            }
+            GetMaintenanceWindowExecutionTaskInvocationError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+        }
+    }
+}
 /// Errors returned by GetMaintenanceWindowTask
 #[derive(Debug, PartialEq)]
 pub enum GetMaintenanceWindowTaskError {
@@ -12454,6 +13191,14 @@ impl fmt::Display for GetMaintenanceWindowTaskError {
     }
 }
 impl Error for GetMaintenanceWindowTaskError {}
+impl ServiceErrorCode for GetMaintenanceWindowTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMaintenanceWindowTaskError::DoesNotExist(_) => "DoesNotExistException",
+            GetMaintenanceWindowTaskError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by GetOpsItem
 #[derive(Debug, PartialEq)]
 pub enum GetOpsItemError {
@@ -12490,6 +13235,14 @@ impl fmt::Display for GetOpsItemError {
     }
 }
 impl Error for GetOpsItemError {}
+impl ServiceErrorCode for GetOpsItemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOpsItemError::InternalServerError(_) => "InternalServerError",
+            GetOpsItemError::OpsItemNotFound(_) => "OpsItemNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetOpsMetadata
 #[derive(Debug, PartialEq)]
 pub enum GetOpsMetadataError {
@@ -12534,6 +13287,17 @@ impl fmt::Display for GetOpsMetadataError {
     }
 }
 impl Error for GetOpsMetadataError {}
+impl ServiceErrorCode for GetOpsMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOpsMetadataError::InternalServerError(_) => "InternalServerError",
+            GetOpsMetadataError::OpsMetadataInvalidArgument(_) => {
+                "OpsMetadataInvalidArgumentException"
+            }
+            GetOpsMetadataError::OpsMetadataNotFound(_) => "OpsMetadataNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetOpsSummary
 #[derive(Debug, PartialEq)]
 pub enum GetOpsSummaryError {
@@ -12596,6 +13360,18 @@ impl fmt::Display for GetOpsSummaryError {
     }
 }
 impl Error for GetOpsSummaryError {}
+impl ServiceErrorCode for GetOpsSummaryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetOpsSummaryError::InternalServerError(_) => "InternalServerError",
+            GetOpsSummaryError::InvalidAggregator(_) => "InvalidAggregatorException",
+            GetOpsSummaryError::InvalidFilter(_) => "InvalidFilter",
+            GetOpsSummaryError::InvalidNextToken(_) => "InvalidNextToken",
+            GetOpsSummaryError::InvalidTypeName(_) => "InvalidTypeNameException",
+            GetOpsSummaryError::ResourceDataSyncNotFound(_) => "ResourceDataSyncNotFoundException",
+        }
+    }
+}
 /// Errors returned by GetParameter
 #[derive(Debug, PartialEq)]
 pub enum GetParameterError {
@@ -12646,6 +13422,16 @@ impl fmt::Display for GetParameterError {
     }
 }
 impl Error for GetParameterError {}
+impl ServiceErrorCode for GetParameterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetParameterError::InternalServerError(_) => "InternalServerError",
+            GetParameterError::InvalidKeyId(_) => "InvalidKeyId",
+            GetParameterError::ParameterNotFound(_) => "ParameterNotFound",
+            GetParameterError::ParameterVersionNotFound(_) => "ParameterVersionNotFound",
+        }
+    }
+}
 /// Errors returned by GetParameterHistory
 #[derive(Debug, PartialEq)]
 pub enum GetParameterHistoryError {
@@ -12700,6 +13486,16 @@ impl fmt::Display for GetParameterHistoryError {
     }
 }
 impl Error for GetParameterHistoryError {}
+impl ServiceErrorCode for GetParameterHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetParameterHistoryError::InternalServerError(_) => "InternalServerError",
+            GetParameterHistoryError::InvalidKeyId(_) => "InvalidKeyId",
+            GetParameterHistoryError::InvalidNextToken(_) => "InvalidNextToken",
+            GetParameterHistoryError::ParameterNotFound(_) => "ParameterNotFound",
+        }
+    }
+}
 /// Errors returned by GetParameters
 #[derive(Debug, PartialEq)]
 pub enum GetParametersError {
@@ -12736,6 +13532,14 @@ impl fmt::Display for GetParametersError {
     }
 }
 impl Error for GetParametersError {}
+impl ServiceErrorCode for GetParametersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetParametersError::InternalServerError(_) => "InternalServerError",
+            GetParametersError::InvalidKeyId(_) => "InvalidKeyId",
+        }
+    }
+}
 /// Errors returned by GetParametersByPath
 #[derive(Debug, PartialEq)]
 pub enum GetParametersByPathError {
@@ -12806,6 +13610,18 @@ impl fmt::Display for GetParametersByPathError {
     }
 }
 impl Error for GetParametersByPathError {}
+impl ServiceErrorCode for GetParametersByPathError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetParametersByPathError::InternalServerError(_) => "InternalServerError",
+            GetParametersByPathError::InvalidFilterKey(_) => "InvalidFilterKey",
+            GetParametersByPathError::InvalidFilterOption(_) => "InvalidFilterOption",
+            GetParametersByPathError::InvalidFilterValue(_) => "InvalidFilterValue",
+            GetParametersByPathError::InvalidKeyId(_) => "InvalidKeyId",
+            GetParametersByPathError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by GetPatchBaseline
 #[derive(Debug, PartialEq)]
 pub enum GetPatchBaselineError {
@@ -12849,7 +13665,16 @@ impl fmt::Display for GetPatchBaselineError {
         }
     }
 }
-impl Error for GetPatchBaselineError {}
+impl Error for GetPatchBaselineError {}
+impl ServiceErrorCode for GetPatchBaselineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPatchBaselineError::DoesNotExist(_) => "DoesNotExistException",
+            GetPatchBaselineError::InternalServerError(_) => "InternalServerError",
+            GetPatchBaselineError::InvalidResourceId(_) => "InvalidResourceId",
+        }
+    }
+}
 /// Errors returned by GetPatchBaselineForPatchGroup
 #[derive(Debug, PartialEq)]
 pub enum GetPatchBaselineForPatchGroupError {
@@ -12886,6 +13711,13 @@ impl fmt::Display for GetPatchBaselineForPatchGroupError {
     }
 }
 impl Error for GetPatchBaselineForPatchGroupError {}
+impl ServiceErrorCode for GetPatchBaselineForPatchGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPatchBaselineForPatchGroupError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by GetServiceSetting
 #[derive(Debug, PartialEq)]
 pub enum GetServiceSettingError {
@@ -12926,6 +13758,14 @@ impl fmt::Display for GetServiceSettingError {
     }
 }
 impl Error for GetServiceSettingError {}
+impl ServiceErrorCode for GetServiceSettingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetServiceSettingError::InternalServerError(_) => "InternalServerError",
+            GetServiceSettingError::ServiceSettingNotFound(_) => "ServiceSettingNotFound",
+        }
+    }
+}
 /// Errors returned by LabelParameterVersion
 #[derive(Debug, PartialEq)]
 pub enum LabelParameterVersionError {
@@ -12994,6 +13834,19 @@ impl fmt::Display for LabelParameterVersionError {
     }
 }
 impl Error for LabelParameterVersionError {}
+impl ServiceErrorCode for LabelParameterVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            LabelParameterVersionError::InternalServerError(_) => "InternalServerError",
+            LabelParameterVersionError::ParameterNotFound(_) => "ParameterNotFound",
+            LabelParameterVersionError::ParameterVersionLabelLimitExceeded(_) => {
+                "ParameterVersionLabelLimitExceeded"
+            }
+            LabelParameterVersionError::ParameterVersionNotFound(_) => "ParameterVersionNotFound",
+            LabelParameterVersionError::TooManyUpdates(_) => "TooManyUpdates",
+        }
+    }
+}
 /// Errors returned by ListAssociationVersions
 #[derive(Debug, PartialEq)]
 pub enum ListAssociationVersionsError {
@@ -13044,6 +13897,15 @@ impl fmt::Display for ListAssociationVersionsError {
     }
 }
 impl Error for ListAssociationVersionsError {}
+impl ServiceErrorCode for ListAssociationVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAssociationVersionsError::AssociationDoesNotExist(_) => "AssociationDoesNotExist",
+            ListAssociationVersionsError::InternalServerError(_) => "InternalServerError",
+            ListAssociationVersionsError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by ListAssociations
 #[derive(Debug, PartialEq)]
 pub enum ListAssociationsError {
@@ -13082,6 +13944,14 @@ impl fmt::Display for ListAssociationsError {
     }
 }
 impl Error for ListAssociationsError {}
+impl ServiceErrorCode for ListAssociationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAssociationsError::InternalServerError(_) => "InternalServerError",
+            ListAssociationsError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by ListCommandInvocations
 #[derive(Debug, PartialEq)]
 pub enum ListCommandInvocationsError {
@@ -13146,6 +14016,17 @@ impl fmt::Display for ListCommandInvocationsError {
     }
 }
 impl Error for ListCommandInvocationsError {}
+impl ServiceErrorCode for ListCommandInvocationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCommandInvocationsError::InternalServerError(_) => "InternalServerError",
+            ListCommandInvocationsError::InvalidCommandId(_) => "InvalidCommandId",
+            ListCommandInvocationsError::InvalidFilterKey(_) => "InvalidFilterKey",
+            ListCommandInvocationsError::InvalidInstanceId(_) => "InvalidInstanceId",
+            ListCommandInvocationsError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by ListCommands
 #[derive(Debug, PartialEq)]
 pub enum ListCommandsError {
@@ -13200,6 +14081,17 @@ impl fmt::Display for ListCommandsError {
     }
 }
 impl Error for ListCommandsError {}
+impl ServiceErrorCode for ListCommandsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListCommandsError::InternalServerError(_) => "InternalServerError",
+            ListCommandsError::InvalidCommandId(_) => "InvalidCommandId",
+            ListCommandsError::InvalidFilterKey(_) => "InvalidFilterKey",
+            ListCommandsError::InvalidInstanceId(_) => "InvalidInstanceId",
+            ListCommandsError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by ListComplianceItems
 #[derive(Debug, PartialEq)]
 pub enum ListComplianceItemsError {
@@ -13262,6 +14154,17 @@ impl fmt::Display for ListComplianceItemsError {
     }
 }
 impl Error for ListComplianceItemsError {}
+impl ServiceErrorCode for ListComplianceItemsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListComplianceItemsError::InternalServerError(_) => "InternalServerError",
+            ListComplianceItemsError::InvalidFilter(_) => "InvalidFilter",
+            ListComplianceItemsError::InvalidNextToken(_) => "InvalidNextToken",
+            ListComplianceItemsError::InvalidResourceId(_) => "InvalidResourceId",
+            ListComplianceItemsError::InvalidResourceType(_) => "InvalidResourceType",
+        }
+    }
+}
 /// Errors returned by ListComplianceSummaries
 #[derive(Debug, PartialEq)]
 pub enum ListComplianceSummariesError {
@@ -13310,6 +14213,15 @@ impl fmt::Display for ListComplianceSummariesError {
     }
 }
 impl Error for ListComplianceSummariesError {}
+impl ServiceErrorCode for ListComplianceSummariesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListComplianceSummariesError::InternalServerError(_) => "InternalServerError",
+            ListComplianceSummariesError::InvalidFilter(_) => "InvalidFilter",
+            ListComplianceSummariesError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by ListDocumentMetadataHistory
 #[derive(Debug, PartialEq)]
 pub enum ListDocumentMetadataHistoryError {
@@ -13372,6 +14284,16 @@ impl fmt::Display for ListDocumentMetadataHistoryError {
     }
 }
 impl Error for ListDocumentMetadataHistoryError {}
+impl ServiceErrorCode for ListDocumentMetadataHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDocumentMetadataHistoryError::InternalServerError(_) => "InternalServerError",
+            ListDocumentMetadataHistoryError::InvalidDocument(_) => "InvalidDocument",
+            ListDocumentMetadataHistoryError::InvalidDocumentVersion(_) => "InvalidDocumentVersion",
+            ListDocumentMetadataHistoryError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by ListDocumentVersions
 #[derive(Debug, PartialEq)]
 pub enum ListDocumentVersionsError {
@@ -13420,6 +14342,15 @@ impl fmt::Display for ListDocumentVersionsError {
     }
 }
 impl Error for ListDocumentVersionsError {}
+impl ServiceErrorCode for ListDocumentVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDocumentVersionsError::InternalServerError(_) => "InternalServerError",
+            ListDocumentVersionsError::InvalidDocument(_) => "InvalidDocument",
+            ListDocumentVersionsError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by ListDocuments
 #[derive(Debug, PartialEq)]
 pub enum ListDocumentsError {
@@ -13462,6 +14393,15 @@ impl fmt::Display for ListDocumentsError {
     }
 }
 impl Error for ListDocumentsError {}
+impl ServiceErrorCode for ListDocumentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDocumentsError::InternalServerError(_) => "InternalServerError",
+            ListDocumentsError::InvalidFilterKey(_) => "InvalidFilterKey",
+            ListDocumentsError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by ListInventoryEntries
 #[derive(Debug, PartialEq)]
 pub enum ListInventoryEntriesError {
@@ -13524,6 +14464,17 @@ impl fmt::Display for ListInventoryEntriesError {
     }
 }
 impl Error for ListInventoryEntriesError {}
+impl ServiceErrorCode for ListInventoryEntriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListInventoryEntriesError::InternalServerError(_) => "InternalServerError",
+            ListInventoryEntriesError::InvalidFilter(_) => "InvalidFilter",
+            ListInventoryEntriesError::InvalidInstanceId(_) => "InvalidInstanceId",
+            ListInventoryEntriesError::InvalidNextToken(_) => "InvalidNextToken",
+            ListInventoryEntriesError::InvalidTypeName(_) => "InvalidTypeNameException",
+        }
+    }
+}
 /// Errors returned by ListOpsItemEvents
 #[derive(Debug, PartialEq)]
 pub enum ListOpsItemEventsError {
@@ -13578,6 +14529,18 @@ impl fmt::Display for ListOpsItemEventsError {
     }
 }
 impl Error for ListOpsItemEventsError {}
+impl ServiceErrorCode for ListOpsItemEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOpsItemEventsError::InternalServerError(_) => "InternalServerError",
+            ListOpsItemEventsError::OpsItemInvalidParameter(_) => {
+                "OpsItemInvalidParameterException"
+            }
+            ListOpsItemEventsError::OpsItemLimitExceeded(_) => "OpsItemLimitExceededException",
+            ListOpsItemEventsError::OpsItemNotFound(_) => "OpsItemNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListOpsMetadata
 #[derive(Debug, PartialEq)]
 pub enum ListOpsMetadataError {
@@ -13616,6 +14579,16 @@ impl fmt::Display for ListOpsMetadataError {
     }
 }
 impl Error for ListOpsMetadataError {}
+impl ServiceErrorCode for ListOpsMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOpsMetadataError::InternalServerError(_) => "InternalServerError",
+            ListOpsMetadataError::OpsMetadataInvalidArgument(_) => {
+                "OpsMetadataInvalidArgumentException"
+            }
+        }
+    }
+}
 /// Errors returned by ListResourceComplianceSummaries
 #[derive(Debug, PartialEq)]
 pub enum ListResourceComplianceSummariesError {
@@ -13672,6 +14645,15 @@ impl fmt::Display for ListResourceComplianceSummariesError {
     }
 }
 impl Error for ListResourceComplianceSummariesError {}
+impl ServiceErrorCode for ListResourceComplianceSummariesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourceComplianceSummariesError::InternalServerError(_) => "InternalServerError",
+            ListResourceComplianceSummariesError::InvalidFilter(_) => "InvalidFilter",
+            ListResourceComplianceSummariesError::InvalidNextToken(_) => "InvalidNextToken",
+        }
+    }
+}
 /// Errors returned by ListResourceDataSync
 #[derive(Debug, PartialEq)]
 pub enum ListResourceDataSyncError {
@@ -13722,6 +14704,17 @@ impl fmt::Display for ListResourceDataSyncError {
     }
 }
 impl Error for ListResourceDataSyncError {}
+impl ServiceErrorCode for ListResourceDataSyncError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourceDataSyncError::InternalServerError(_) => "InternalServerError",
+            ListResourceDataSyncError::InvalidNextToken(_) => "InvalidNextToken",
+            ListResourceDataSyncError::ResourceDataSyncInvalidConfiguration(_) => {
+                "ResourceDataSyncInvalidConfigurationException"
+            }
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -13770,6 +14763,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalServerError(_) => "InternalServerError",
+            ListTagsForResourceError::InvalidResourceId(_) => "InvalidResourceId",
+            ListTagsForResourceError::InvalidResourceType(_) => "InvalidResourceType",
+        }
+    }
+}
 /// Errors returned by ModifyDocumentPermission
 #[derive(Debug, PartialEq)]
 pub enum ModifyDocumentPermissionError {
@@ -13840,6 +14842,17 @@ impl fmt::Display for ModifyDocumentPermissionError {
     }
 }
 impl Error for ModifyDocumentPermissionError {}
+impl ServiceErrorCode for ModifyDocumentPermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyDocumentPermissionError::DocumentLimitExceeded(_) => "DocumentLimitExceeded",
+            ModifyDocumentPermissionError::DocumentPermissionLimit(_) => "DocumentPermissionLimit",
+            ModifyDocumentPermissionError::InternalServerError(_) => "InternalServerError",
+            ModifyDocumentPermissionError::InvalidDocument(_) => "InvalidDocument",
+            ModifyDocumentPermissionError::InvalidPermissionType(_) => "InvalidPermissionType",
+        }
+    }
+}
 /// Errors returned by PutComplianceItems
 #[derive(Debug, PartialEq)]
 pub enum PutComplianceItemsError {
@@ -13922,6 +14935,21 @@ impl fmt::Display for PutComplianceItemsError {
     }
 }
 impl Error for PutComplianceItemsError {}
+impl ServiceErrorCode for PutComplianceItemsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutComplianceItemsError::ComplianceTypeCountLimitExceeded(_) => {
+                "ComplianceTypeCountLimitExceededException"
+            }
+            PutComplianceItemsError::InternalServerError(_) => "InternalServerError",
+            PutComplianceItemsError::InvalidItemContent(_) => "InvalidItemContentException",
+            PutComplianceItemsError::InvalidResourceId(_) => "InvalidResourceId",
+            PutComplianceItemsError::InvalidResourceType(_) => "InvalidResourceType",
+            PutComplianceItemsError::ItemSizeLimitExceeded(_) => "ItemSizeLimitExceededException",
+            PutComplianceItemsError::TotalSizeLimitExceeded(_) => "TotalSizeLimitExceededException",
+        }
+    }
+}
 /// Errors returned by PutInventory
 #[derive(Debug, PartialEq)]
 pub enum PutInventoryError {
@@ -14030,6 +15058,32 @@ impl fmt::Display for PutInventoryError {
     }
 }
 impl Error for PutInventoryError {}
+impl ServiceErrorCode for PutInventoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutInventoryError::CustomSchemaCountLimitExceeded(_) => {
+                "CustomSchemaCountLimitExceededException"
+            }
+            PutInventoryError::InternalServerError(_) => "InternalServerError",
+            PutInventoryError::InvalidInstanceId(_) => "InvalidInstanceId",
+            PutInventoryError::InvalidInventoryItemContext(_) => {
+                "InvalidInventoryItemContextException"
+            }
+            PutInventoryError::InvalidItemContent(_) => "InvalidItemContentException",
+            PutInventoryError::InvalidTypeName(_) => "InvalidTypeNameException",
+            PutInventoryError::ItemContentMismatch(_) => "ItemContentMismatchException",
+            PutInventoryError::ItemSizeLimitExceeded(_) => "ItemSizeLimitExceededException",
+            PutInventoryError::SubTypeCountLimitExceeded(_) => "SubTypeCountLimitExceededException",
+            PutInventoryError::TotalSizeLimitExceeded(_) => "TotalSizeLimitExceededException",
+            PutInventoryError::UnsupportedInventoryItemContext(_) => {
+                "UnsupportedInventoryItemContextException"
+            }
+            PutInventoryError::UnsupportedInventorySchemaVersion(_) => {
+                "UnsupportedInventorySchemaVersionException"
+            }
+        }
+    }
+}
 /// Errors returned by PutParameter
 #[derive(Debug, PartialEq)]
 pub enum PutParameterError {
@@ -14154,6 +15208,31 @@ impl fmt::Display for PutParameterError {
     }
 }
 impl Error for PutParameterError {}
+impl ServiceErrorCode for PutParameterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutParameterError::HierarchyLevelLimitExceeded(_) => {
+                "HierarchyLevelLimitExceededException"
+            }
+            PutParameterError::HierarchyTypeMismatch(_) => "HierarchyTypeMismatchException",
+            PutParameterError::IncompatiblePolicy(_) => "IncompatiblePolicyException",
+            PutParameterError::InternalServerError(_) => "InternalServerError",
+            PutParameterError::InvalidAllowedPattern(_) => "InvalidAllowedPatternException",
+            PutParameterError::InvalidKeyId(_) => "InvalidKeyId",
+            PutParameterError::InvalidPolicyAttribute(_) => "InvalidPolicyAttributeException",
+            PutParameterError::InvalidPolicyType(_) => "InvalidPolicyTypeException",
+            PutParameterError::ParameterAlreadyExists(_) => "ParameterAlreadyExists",
+            PutParameterError::ParameterLimitExceeded(_) => "ParameterLimitExceeded",
+            PutParameterError::ParameterMaxVersionLimitExceeded(_) => {
+                "ParameterMaxVersionLimitExceeded"
+            }
+            PutParameterError::ParameterPatternMismatch(_) => "ParameterPatternMismatchException",
+            PutParameterError::PoliciesLimitExceeded(_) => "PoliciesLimitExceededException",
+            PutParameterError::TooManyUpdates(_) => "TooManyUpdates",
+            PutParameterError::UnsupportedParameterType(_) => "UnsupportedParameterType",
+        }
+    }
+}
 /// Errors returned by RegisterDefaultPatchBaseline
 #[derive(Debug, PartialEq)]
 pub enum RegisterDefaultPatchBaselineError {
@@ -14208,6 +15287,15 @@ impl fmt::Display for RegisterDefaultPatchBaselineError {
     }
 }
 impl Error for RegisterDefaultPatchBaselineError {}
+impl ServiceErrorCode for RegisterDefaultPatchBaselineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterDefaultPatchBaselineError::DoesNotExist(_) => "DoesNotExistException",
+            RegisterDefaultPatchBaselineError::InternalServerError(_) => "InternalServerError",
+            RegisterDefaultPatchBaselineError::InvalidResourceId(_) => "InvalidResourceId",
+        }
+    }
+}
 /// Errors returned by RegisterPatchBaselineForPatchGroup
 #[derive(Debug, PartialEq)]
 pub enum RegisterPatchBaselineForPatchGroupError {
@@ -14284,6 +15372,21 @@ impl fmt::Display for RegisterPatchBaselineForPatchGroupError {
     }
 }
 impl Error for RegisterPatchBaselineForPatchGroupError {}
+impl ServiceErrorCode for RegisterPatchBaselineForPatchGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterPatchBaselineForPatchGroupError::AlreadyExists(_) => "AlreadyExistsException",
+            RegisterPatchBaselineForPatchGroupError::DoesNotExist(_) => "DoesNotExistException",
+            RegisterPatchBaselineForPatchGroupError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+            RegisterPatchBaselineForPatchGroupError::InvalidResourceId(_) => "InvalidResourceId",
+            RegisterPatchBaselineForPatchGroupError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by RegisterTargetWithMaintenanceWindow
 #[derive(Debug, PartialEq)]
 pub enum RegisterTargetWithMaintenanceWindowError {
@@ -14352,6 +15455,22 @@ impl fmt::Display for RegisterTargetWithMaintenanceWindowError {
     }
 }
 impl Error for RegisterTargetWithMaintenanceWindowError {}
+impl ServiceErrorCode for RegisterTargetWithMaintenanceWindowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterTargetWithMaintenanceWindowError::DoesNotExist(_) => "DoesNotExistException",
+            RegisterTargetWithMaintenanceWindowError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatch"
+            }
+            RegisterTargetWithMaintenanceWindowError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+            RegisterTargetWithMaintenanceWindowError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by RegisterTaskWithMaintenanceWindow
 #[derive(Debug, PartialEq)]
 pub enum RegisterTaskWithMaintenanceWindowError {
@@ -14430,6 +15549,23 @@ impl fmt::Display for RegisterTaskWithMaintenanceWindowError {
     }
 }
 impl Error for RegisterTaskWithMaintenanceWindowError {}
+impl ServiceErrorCode for RegisterTaskWithMaintenanceWindowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterTaskWithMaintenanceWindowError::DoesNotExist(_) => "DoesNotExistException",
+            RegisterTaskWithMaintenanceWindowError::FeatureNotAvailable(_) => {
+                "FeatureNotAvailableException"
+            }
+            RegisterTaskWithMaintenanceWindowError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatch"
+            }
+            RegisterTaskWithMaintenanceWindowError::InternalServerError(_) => "InternalServerError",
+            RegisterTaskWithMaintenanceWindowError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromResourceError {
@@ -14486,6 +15622,16 @@ impl fmt::Display for RemoveTagsFromResourceError {
     }
 }
 impl Error for RemoveTagsFromResourceError {}
+impl ServiceErrorCode for RemoveTagsFromResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsFromResourceError::InternalServerError(_) => "InternalServerError",
+            RemoveTagsFromResourceError::InvalidResourceId(_) => "InvalidResourceId",
+            RemoveTagsFromResourceError::InvalidResourceType(_) => "InvalidResourceType",
+            RemoveTagsFromResourceError::TooManyUpdates(_) => "TooManyUpdates",
+        }
+    }
+}
 /// Errors returned by ResetServiceSetting
 #[derive(Debug, PartialEq)]
 pub enum ResetServiceSettingError {
@@ -14532,6 +15678,15 @@ impl fmt::Display for ResetServiceSettingError {
     }
 }
 impl Error for ResetServiceSettingError {}
+impl ServiceErrorCode for ResetServiceSettingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetServiceSettingError::InternalServerError(_) => "InternalServerError",
+            ResetServiceSettingError::ServiceSettingNotFound(_) => "ServiceSettingNotFound",
+            ResetServiceSettingError::TooManyUpdates(_) => "TooManyUpdates",
+        }
+    }
+}
 /// Errors returned by ResumeSession
 #[derive(Debug, PartialEq)]
 pub enum ResumeSessionError {
@@ -14568,6 +15723,14 @@ impl fmt::Display for ResumeSessionError {
     }
 }
 impl Error for ResumeSessionError {}
+impl ServiceErrorCode for ResumeSessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResumeSessionError::DoesNotExist(_) => "DoesNotExistException",
+            ResumeSessionError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by SendAutomationSignal
 #[derive(Debug, PartialEq)]
 pub enum SendAutomationSignalError {
@@ -14626,6 +15789,22 @@ impl fmt::Display for SendAutomationSignalError {
     }
 }
 impl Error for SendAutomationSignalError {}
+impl ServiceErrorCode for SendAutomationSignalError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendAutomationSignalError::AutomationExecutionNotFound(_) => {
+                "AutomationExecutionNotFoundException"
+            }
+            SendAutomationSignalError::AutomationStepNotFound(_) => {
+                "AutomationStepNotFoundException"
+            }
+            SendAutomationSignalError::InternalServerError(_) => "InternalServerError",
+            SendAutomationSignalError::InvalidAutomationSignal(_) => {
+                "InvalidAutomationSignalException"
+            }
+        }
+    }
+}
 /// Errors returned by SendCommand
 #[derive(Debug, PartialEq)]
 pub enum SendCommandError {
@@ -14718,6 +15897,23 @@ impl fmt::Display for SendCommandError {
     }
 }
 impl Error for SendCommandError {}
+impl ServiceErrorCode for SendCommandError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendCommandError::DuplicateInstanceId(_) => "DuplicateInstanceId",
+            SendCommandError::InternalServerError(_) => "InternalServerError",
+            SendCommandError::InvalidDocument(_) => "InvalidDocument",
+            SendCommandError::InvalidDocumentVersion(_) => "InvalidDocumentVersion",
+            SendCommandError::InvalidInstanceId(_) => "InvalidInstanceId",
+            SendCommandError::InvalidNotificationConfig(_) => "InvalidNotificationConfig",
+            SendCommandError::InvalidOutputFolder(_) => "InvalidOutputFolder",
+            SendCommandError::InvalidParameters(_) => "InvalidParameters",
+            SendCommandError::InvalidRole(_) => "InvalidRole",
+            SendCommandError::MaxDocumentSizeExceeded(_) => "MaxDocumentSizeExceeded",
+            SendCommandError::UnsupportedPlatformType(_) => "UnsupportedPlatformType",
+        }
+    }
+}
 /// Errors returned by StartAssociationsOnce
 #[derive(Debug, PartialEq)]
 pub enum StartAssociationsOnceError {
@@ -14760,6 +15956,14 @@ impl fmt::Display for StartAssociationsOnceError {
     }
 }
 impl Error for StartAssociationsOnceError {}
+impl ServiceErrorCode for StartAssociationsOnceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartAssociationsOnceError::AssociationDoesNotExist(_) => "AssociationDoesNotExist",
+            StartAssociationsOnceError::InvalidAssociation(_) => "InvalidAssociation",
+        }
+    }
+}
 /// Errors returned by StartAutomationExecution
 #[derive(Debug, PartialEq)]
 pub enum StartAutomationExecutionError {
@@ -14852,6 +16056,29 @@ impl fmt::Display for StartAutomationExecutionError {
     }
 }
 impl Error for StartAutomationExecutionError {}
+impl ServiceErrorCode for StartAutomationExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartAutomationExecutionError::AutomationDefinitionNotFound(_) => {
+                "AutomationDefinitionNotFoundException"
+            }
+            StartAutomationExecutionError::AutomationDefinitionVersionNotFound(_) => {
+                "AutomationDefinitionVersionNotFoundException"
+            }
+            StartAutomationExecutionError::AutomationExecutionLimitExceeded(_) => {
+                "AutomationExecutionLimitExceededException"
+            }
+            StartAutomationExecutionError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatch"
+            }
+            StartAutomationExecutionError::InternalServerError(_) => "InternalServerError",
+            StartAutomationExecutionError::InvalidAutomationExecutionParameters(_) => {
+                "InvalidAutomationExecutionParametersException"
+            }
+            StartAutomationExecutionError::InvalidTarget(_) => "InvalidTarget",
+        }
+    }
+}
 /// Errors returned by StartChangeRequestExecution
 #[derive(Debug, PartialEq)]
 pub enum StartChangeRequestExecutionError {
@@ -14952,6 +16179,31 @@ impl fmt::Display for StartChangeRequestExecutionError {
     }
 }
 impl Error for StartChangeRequestExecutionError {}
+impl ServiceErrorCode for StartChangeRequestExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartChangeRequestExecutionError::AutomationDefinitionNotApproved(_) => {
+                "AutomationDefinitionNotApprovedException"
+            }
+            StartChangeRequestExecutionError::AutomationDefinitionNotFound(_) => {
+                "AutomationDefinitionNotFoundException"
+            }
+            StartChangeRequestExecutionError::AutomationDefinitionVersionNotFound(_) => {
+                "AutomationDefinitionVersionNotFoundException"
+            }
+            StartChangeRequestExecutionError::AutomationExecutionLimitExceeded(_) => {
+                "AutomationExecutionLimitExceededException"
+            }
+            StartChangeRequestExecutionError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatch"
+            }
+            StartChangeRequestExecutionError::InternalServerError(_) => "InternalServerError",
+            StartChangeRequestExecutionError::InvalidAutomationExecutionParameters(_) => {
+                "InvalidAutomationExecutionParametersException"
+            }
+        }
+    }
+}
 /// Errors returned by StartSession
 #[derive(Debug, PartialEq)]
 pub enum StartSessionError {
@@ -14994,6 +16246,15 @@ impl fmt::Display for StartSessionError {
     }
 }
 impl Error for StartSessionError {}
+impl ServiceErrorCode for StartSessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartSessionError::InternalServerError(_) => "InternalServerError",
+            StartSessionError::InvalidDocument(_) => "InvalidDocument",
+            StartSessionError::TargetNotConnected(_) => "TargetNotConnected",
+        }
+    }
+}
 /// Errors returned by StopAutomationExecution
 #[derive(Debug, PartialEq)]
 pub enum StopAutomationExecutionError {
@@ -15046,6 +16307,19 @@ impl fmt::Display for StopAutomationExecutionError {
     }
 }
 impl Error for StopAutomationExecutionError {}
+impl ServiceErrorCode for StopAutomationExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopAutomationExecutionError::AutomationExecutionNotFound(_) => {
+                "AutomationExecutionNotFoundException"
+            }
+            StopAutomationExecutionError::InternalServerError(_) => "InternalServerError",
+            StopAutomationExecutionError::InvalidAutomationStatusUpdate(_) => {
+                "InvalidAutomationStatusUpdateException"
+            }
+        }
+    }
+}
 /// Errors returned by TerminateSession
 #[derive(Debug, PartialEq)]
 pub enum TerminateSessionError {
@@ -15084,6 +16358,14 @@ impl fmt::Display for TerminateSessionError {
     }
 }
 impl Error for TerminateSessionError {}
+impl ServiceErrorCode for TerminateSessionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TerminateSessionError::DoesNotExist(_) => "DoesNotExistException",
+            TerminateSessionError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by UpdateAssociation
 #[derive(Debug, PartialEq)]
 pub enum UpdateAssociationError {
@@ -15194,6 +16476,26 @@ impl fmt::Display for UpdateAssociationError {
     }
 }
 impl Error for UpdateAssociationError {}
+impl ServiceErrorCode for UpdateAssociationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAssociationError::AssociationDoesNotExist(_) => "AssociationDoesNotExist",
+            UpdateAssociationError::AssociationVersionLimitExceeded(_) => {
+                "AssociationVersionLimitExceeded"
+            }
+            UpdateAssociationError::InternalServerError(_) => "InternalServerError",
+            UpdateAssociationError::InvalidAssociationVersion(_) => "InvalidAssociationVersion",
+            UpdateAssociationError::InvalidDocument(_) => "InvalidDocument",
+            UpdateAssociationError::InvalidDocumentVersion(_) => "InvalidDocumentVersion",
+            UpdateAssociationError::InvalidOutputLocation(_) => "InvalidOutputLocation",
+            UpdateAssociationError::InvalidParameters(_) => "InvalidParameters",
+            UpdateAssociationError::InvalidSchedule(_) => "InvalidSchedule",
+            UpdateAssociationError::InvalidTarget(_) => "InvalidTarget",
+            UpdateAssociationError::InvalidUpdate(_) => "InvalidUpdate",
+            UpdateAssociationError::TooManyUpdates(_) => "TooManyUpdates",
+        }
+    }
+}
 /// Errors returned by UpdateAssociationStatus
 #[derive(Debug, PartialEq)]
 pub enum UpdateAssociationStatusError {
@@ -15268,6 +16570,18 @@ impl fmt::Display for UpdateAssociationStatusError {
     }
 }
 impl Error for UpdateAssociationStatusError {}
+impl ServiceErrorCode for UpdateAssociationStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAssociationStatusError::AssociationDoesNotExist(_) => "AssociationDoesNotExist",
+            UpdateAssociationStatusError::InternalServerError(_) => "InternalServerError",
+            UpdateAssociationStatusError::InvalidDocument(_) => "InvalidDocument",
+            UpdateAssociationStatusError::InvalidInstanceId(_) => "InvalidInstanceId",
+            UpdateAssociationStatusError::StatusUnchanged(_) => "StatusUnchanged",
+            UpdateAssociationStatusError::TooManyUpdates(_) => "TooManyUpdates",
+        }
+    }
+}
 /// Errors returned by UpdateDocument
 #[derive(Debug, PartialEq)]
 pub enum UpdateDocumentError {
@@ -15368,6 +16682,22 @@ impl fmt::Display for UpdateDocumentError {
     }
 }
 impl Error for UpdateDocumentError {}
+impl ServiceErrorCode for UpdateDocumentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDocumentError::DocumentVersionLimitExceeded(_) => "DocumentVersionLimitExceeded",
+            UpdateDocumentError::DuplicateDocumentContent(_) => "DuplicateDocumentContent",
+            UpdateDocumentError::DuplicateDocumentVersionName(_) => "DuplicateDocumentVersionName",
+            UpdateDocumentError::InternalServerError(_) => "InternalServerError",
+            UpdateDocumentError::InvalidDocument(_) => "InvalidDocument",
+            UpdateDocumentError::InvalidDocumentContent(_) => "InvalidDocumentContent",
+            UpdateDocumentError::InvalidDocumentOperation(_) => "InvalidDocumentOperation",
+            UpdateDocumentError::InvalidDocumentSchemaVersion(_) => "InvalidDocumentSchemaVersion",
+            UpdateDocumentError::InvalidDocumentVersion(_) => "InvalidDocumentVersion",
+            UpdateDocumentError::MaxDocumentSizeExceeded(_) => "MaxDocumentSizeExceeded",
+        }
+    }
+}
 /// Errors returned by UpdateDocumentDefaultVersion
 #[derive(Debug, PartialEq)]
 pub enum UpdateDocumentDefaultVersionError {
@@ -15432,6 +16762,20 @@ impl fmt::Display for UpdateDocumentDefaultVersionError {
     }
 }
 impl Error for UpdateDocumentDefaultVersionError {}
+impl ServiceErrorCode for UpdateDocumentDefaultVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDocumentDefaultVersionError::InternalServerError(_) => "InternalServerError",
+            UpdateDocumentDefaultVersionError::InvalidDocument(_) => "InvalidDocument",
+            UpdateDocumentDefaultVersionError::InvalidDocumentSchemaVersion(_) => {
+                "InvalidDocumentSchemaVersion"
+            }
+            UpdateDocumentDefaultVersionError::InvalidDocumentVersion(_) => {
+                "InvalidDocumentVersion"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateDocumentMetadata
 #[derive(Debug, PartialEq)]
 pub enum UpdateDocumentMetadataError {
@@ -15492,6 +16836,16 @@ impl fmt::Display for UpdateDocumentMetadataError {
     }
 }
 impl Error for UpdateDocumentMetadataError {}
+impl ServiceErrorCode for UpdateDocumentMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDocumentMetadataError::InternalServerError(_) => "InternalServerError",
+            UpdateDocumentMetadataError::InvalidDocument(_) => "InvalidDocument",
+            UpdateDocumentMetadataError::InvalidDocumentOperation(_) => "InvalidDocumentOperation",
+            UpdateDocumentMetadataError::InvalidDocumentVersion(_) => "InvalidDocumentVersion",
+        }
+    }
+}
 /// Errors returned by UpdateMaintenanceWindow
 #[derive(Debug, PartialEq)]
 pub enum UpdateMaintenanceWindowError {
@@ -15532,6 +16886,14 @@ impl fmt::Display for UpdateMaintenanceWindowError {
     }
 }
 impl Error for UpdateMaintenanceWindowError {}
+impl ServiceErrorCode for UpdateMaintenanceWindowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMaintenanceWindowError::DoesNotExist(_) => "DoesNotExistException",
+            UpdateMaintenanceWindowError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by UpdateMaintenanceWindowTarget
 #[derive(Debug, PartialEq)]
 pub enum UpdateMaintenanceWindowTargetError {
@@ -15576,6 +16938,14 @@ impl fmt::Display for UpdateMaintenanceWindowTargetError {
     }
 }
 impl Error for UpdateMaintenanceWindowTargetError {}
+impl ServiceErrorCode for UpdateMaintenanceWindowTargetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMaintenanceWindowTargetError::DoesNotExist(_) => "DoesNotExistException",
+            UpdateMaintenanceWindowTargetError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by UpdateMaintenanceWindowTask
 #[derive(Debug, PartialEq)]
 pub enum UpdateMaintenanceWindowTaskError {
@@ -15620,6 +16990,14 @@ impl fmt::Display for UpdateMaintenanceWindowTaskError {
     }
 }
 impl Error for UpdateMaintenanceWindowTaskError {}
+impl ServiceErrorCode for UpdateMaintenanceWindowTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMaintenanceWindowTaskError::DoesNotExist(_) => "DoesNotExistException",
+            UpdateMaintenanceWindowTaskError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by UpdateManagedInstanceRole
 #[derive(Debug, PartialEq)]
 pub enum UpdateManagedInstanceRoleError {
@@ -15662,6 +17040,14 @@ impl fmt::Display for UpdateManagedInstanceRoleError {
     }
 }
 impl Error for UpdateManagedInstanceRoleError {}
+impl ServiceErrorCode for UpdateManagedInstanceRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateManagedInstanceRoleError::InternalServerError(_) => "InternalServerError",
+            UpdateManagedInstanceRoleError::InvalidInstanceId(_) => "InvalidInstanceId",
+        }
+    }
+}
 /// Errors returned by UpdateOpsItem
 #[derive(Debug, PartialEq)]
 pub enum UpdateOpsItemError {
@@ -15718,6 +17104,17 @@ impl fmt::Display for UpdateOpsItemError {
     }
 }
 impl Error for UpdateOpsItemError {}
+impl ServiceErrorCode for UpdateOpsItemError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateOpsItemError::InternalServerError(_) => "InternalServerError",
+            UpdateOpsItemError::OpsItemAlreadyExists(_) => "OpsItemAlreadyExistsException",
+            UpdateOpsItemError::OpsItemInvalidParameter(_) => "OpsItemInvalidParameterException",
+            UpdateOpsItemError::OpsItemLimitExceeded(_) => "OpsItemLimitExceededException",
+            UpdateOpsItemError::OpsItemNotFound(_) => "OpsItemNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateOpsMetadata
 #[derive(Debug, PartialEq)]
 pub enum UpdateOpsMetadataError {
@@ -15784,6 +17181,23 @@ impl fmt::Display for UpdateOpsMetadataError {
     }
 }
 impl Error for UpdateOpsMetadataError {}
+impl ServiceErrorCode for UpdateOpsMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateOpsMetadataError::InternalServerError(_) => "InternalServerError",
+            UpdateOpsMetadataError::OpsMetadataInvalidArgument(_) => {
+                "OpsMetadataInvalidArgumentException"
+            }
+            UpdateOpsMetadataError::OpsMetadataKeyLimitExceeded(_) => {
+                "OpsMetadataKeyLimitExceededException"
+            }
+            UpdateOpsMetadataError::OpsMetadataNotFound(_) => "OpsMetadataNotFoundException",
+            UpdateOpsMetadataError::OpsMetadataTooManyUpdates(_) => {
+                "OpsMetadataTooManyUpdatesException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdatePatchBaseline
 #[derive(Debug, PartialEq)]
 pub enum UpdatePatchBaselineError {
@@ -15822,6 +17236,14 @@ impl fmt::Display for UpdatePatchBaselineError {
     }
 }
 impl Error for UpdatePatchBaselineError {}
+impl ServiceErrorCode for UpdatePatchBaselineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePatchBaselineError::DoesNotExist(_) => "DoesNotExistException",
+            UpdatePatchBaselineError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by UpdateResourceDataSync
 #[derive(Debug, PartialEq)]
 pub enum UpdateResourceDataSyncError {
@@ -15884,6 +17306,22 @@ impl fmt::Display for UpdateResourceDataSyncError {
     }
 }
 impl Error for UpdateResourceDataSyncError {}
+impl ServiceErrorCode for UpdateResourceDataSyncError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateResourceDataSyncError::InternalServerError(_) => "InternalServerError",
+            UpdateResourceDataSyncError::ResourceDataSyncConflict(_) => {
+                "ResourceDataSyncConflictException"
+            }
+            UpdateResourceDataSyncError::ResourceDataSyncInvalidConfiguration(_) => {
+                "ResourceDataSyncInvalidConfigurationException"
+            }
+            UpdateResourceDataSyncError::ResourceDataSyncNotFound(_) => {
+                "ResourceDataSyncNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateServiceSetting
 #[derive(Debug, PartialEq)]
 pub enum UpdateServiceSettingError {
@@ -15930,6 +17368,15 @@ impl fmt::Display for UpdateServiceSettingError {
     }
 }
 impl Error for UpdateServiceSettingError {}
+impl ServiceErrorCode for UpdateServiceSettingError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateServiceSettingError::InternalServerError(_) => "InternalServerError",
+            UpdateServiceSettingError::ServiceSettingNotFound(_) => "ServiceSettingNotFound",
+            UpdateServiceSettingError::TooManyUpdates(_) => "TooManyUpdates",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon SSM API. Amazon SSM clients implement this trait.
 #[async_trait]
 pub trait Ssm {
@@ -16970,6 +18417,10 @@ impl Ssm for SsmClient {
         &self,
         input: CreateMaintenanceWindowRequest,
     ) -> Result<CreateMaintenanceWindowResult, RusotoError<CreateMaintenanceWindowError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AmazonSSM.CreateMaintenanceWindow");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -17025,6 +18476,10 @@ impl Ssm for SsmClient {
         &self,
         input: CreatePatchBaselineRequest,
     ) -> Result<CreatePatchBaselineResult, RusotoError<CreatePatchBaselineError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AmazonSSM.CreatePatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -17116,6 +18571,10 @@ impl Ssm for SsmClient {
         &self,
         input: DeleteInventoryRequest,
     ) -> Result<DeleteInventoryResult, RusotoError<DeleteInventoryError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "AmazonSSM.DeleteInventory");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -18950,6 +20409,10 @@ impl Ssm for SsmClient {
         RegisterTargetWithMaintenanceWindowResult,
         RusotoError<RegisterTargetWithMaintenanceWindowError>,
     > {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -18978,6 +20441,10 @@ impl Ssm for SsmClient {
         RegisterTaskWithMaintenanceWindowResult,
         RusotoError<RegisterTaskWithMaintenanceWindowError>,
     > {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -259,6 +259,23 @@ impl fmt::Display for CreateTokenError {
     }
 }
 impl Error for CreateTokenError {}
+impl ServiceErrorCode for CreateTokenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTokenError::AccessDenied(_) => "AccessDeniedException",
+            CreateTokenError::AuthorizationPending(_) => "AuthorizationPendingException",
+            CreateTokenError::ExpiredToken(_) => "ExpiredTokenException",
+            CreateTokenError::InternalServer(_) => "InternalServerException",
+            CreateTokenError::InvalidClient(_) => "InvalidClientException",
+            CreateTokenError::InvalidGrant(_) => "InvalidGrantException",
+            CreateTokenError::InvalidRequest(_) => "InvalidRequestException",
+            CreateTokenError::InvalidScope(_) => "InvalidScopeException",
+            CreateTokenError::SlowDown(_) => "SlowDownException",
+            CreateTokenError::UnauthorizedClient(_) => "UnauthorizedClientException",
+            CreateTokenError::UnsupportedGrantType(_) => "UnsupportedGrantTypeException",
+        }
+    }
+}
 /// Errors returned by RegisterClient
 #[derive(Debug, PartialEq)]
 pub enum RegisterClientError {
@@ -309,6 +326,16 @@ impl fmt::Display for RegisterClientError {
     }
 }
 impl Error for RegisterClientError {}
+impl ServiceErrorCode for RegisterClientError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterClientError::InternalServer(_) => "InternalServerException",
+            RegisterClientError::InvalidClientMetadata(_) => "InvalidClientMetadataException",
+            RegisterClientError::InvalidRequest(_) => "InvalidRequestException",
+            RegisterClientError::InvalidScope(_) => "InvalidScopeException",
+        }
+    }
+}
 /// Errors returned by StartDeviceAuthorization
 #[derive(Debug, PartialEq)]
 pub enum StartDeviceAuthorizationError {
@@ -371,6 +398,17 @@ impl fmt::Display for StartDeviceAuthorizationError {
     }
 }
 impl Error for StartDeviceAuthorizationError {}
+impl ServiceErrorCode for StartDeviceAuthorizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDeviceAuthorizationError::InternalServer(_) => "InternalServerException",
+            StartDeviceAuthorizationError::InvalidClient(_) => "InvalidClientException",
+            StartDeviceAuthorizationError::InvalidRequest(_) => "InvalidRequestException",
+            StartDeviceAuthorizationError::SlowDown(_) => "SlowDownException",
+            StartDeviceAuthorizationError::UnauthorizedClient(_) => "UnauthorizedClientException",
+        }
+    }
+}
 /// Trait representing the capabilities of the SSO OIDC API. SSO OIDC clients implement this trait.
 #[async_trait]
 pub trait SsoOidc {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -218,6 +218,16 @@ impl fmt::Display for GetRoleCredentialsError {
     }
 }
 impl Error for GetRoleCredentialsError {}
+impl ServiceErrorCode for GetRoleCredentialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRoleCredentialsError::InvalidRequest(_) => "InvalidRequestException",
+            GetRoleCredentialsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetRoleCredentialsError::TooManyRequests(_) => "TooManyRequestsException",
+            GetRoleCredentialsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListAccountRoles
 #[derive(Debug, PartialEq)]
 pub enum ListAccountRolesError {
@@ -266,6 +276,16 @@ impl fmt::Display for ListAccountRolesError {
     }
 }
 impl Error for ListAccountRolesError {}
+impl ServiceErrorCode for ListAccountRolesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAccountRolesError::InvalidRequest(_) => "InvalidRequestException",
+            ListAccountRolesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListAccountRolesError::TooManyRequests(_) => "TooManyRequestsException",
+            ListAccountRolesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListAccounts
 #[derive(Debug, PartialEq)]
 pub enum ListAccountsError {
@@ -314,6 +334,16 @@ impl fmt::Display for ListAccountsError {
     }
 }
 impl Error for ListAccountsError {}
+impl ServiceErrorCode for ListAccountsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAccountsError::InvalidRequest(_) => "InvalidRequestException",
+            ListAccountsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListAccountsError::TooManyRequests(_) => "TooManyRequestsException",
+            ListAccountsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by Logout
 #[derive(Debug, PartialEq)]
 pub enum LogoutError {
@@ -356,6 +386,15 @@ impl fmt::Display for LogoutError {
     }
 }
 impl Error for LogoutError {}
+impl ServiceErrorCode for LogoutError {
+    fn error_code(&self) -> &str {
+        match *self {
+            LogoutError::InvalidRequest(_) => "InvalidRequestException",
+            LogoutError::TooManyRequests(_) => "TooManyRequestsException",
+            LogoutError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Trait representing the capabilities of the SSO API. SSO clients implement this trait.
 #[async_trait]
 pub trait Sso {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1483,6 +1483,15 @@ impl fmt::Display for CreateActivityError {
     }
 }
 impl Error for CreateActivityError {}
+impl ServiceErrorCode for CreateActivityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateActivityError::ActivityLimitExceeded(_) => "ActivityLimitExceeded",
+            CreateActivityError::InvalidName(_) => "InvalidName",
+            CreateActivityError::TooManyTags(_) => "TooManyTags",
+        }
+    }
+}
 /// Errors returned by CreateStateMachine
 #[derive(Debug, PartialEq)]
 pub enum CreateStateMachineError {
@@ -1587,6 +1596,28 @@ impl fmt::Display for CreateStateMachineError {
     }
 }
 impl Error for CreateStateMachineError {}
+impl ServiceErrorCode for CreateStateMachineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStateMachineError::InvalidArn(_) => "InvalidArn",
+            CreateStateMachineError::InvalidDefinition(_) => "InvalidDefinition",
+            CreateStateMachineError::InvalidLoggingConfiguration(_) => {
+                "InvalidLoggingConfiguration"
+            }
+            CreateStateMachineError::InvalidName(_) => "InvalidName",
+            CreateStateMachineError::InvalidTracingConfiguration(_) => {
+                "InvalidTracingConfiguration"
+            }
+            CreateStateMachineError::StateMachineAlreadyExists(_) => "StateMachineAlreadyExists",
+            CreateStateMachineError::StateMachineDeleting(_) => "StateMachineDeleting",
+            CreateStateMachineError::StateMachineLimitExceeded(_) => "StateMachineLimitExceeded",
+            CreateStateMachineError::StateMachineTypeNotSupported(_) => {
+                "StateMachineTypeNotSupported"
+            }
+            CreateStateMachineError::TooManyTags(_) => "TooManyTags",
+        }
+    }
+}
 /// Errors returned by DeleteActivity
 #[derive(Debug, PartialEq)]
 pub enum DeleteActivityError {
@@ -1617,6 +1648,13 @@ impl fmt::Display for DeleteActivityError {
     }
 }
 impl Error for DeleteActivityError {}
+impl ServiceErrorCode for DeleteActivityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteActivityError::InvalidArn(_) => "InvalidArn",
+        }
+    }
+}
 /// Errors returned by DeleteStateMachine
 #[derive(Debug, PartialEq)]
 pub enum DeleteStateMachineError {
@@ -1647,6 +1685,13 @@ impl fmt::Display for DeleteStateMachineError {
     }
 }
 impl Error for DeleteStateMachineError {}
+impl ServiceErrorCode for DeleteStateMachineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteStateMachineError::InvalidArn(_) => "InvalidArn",
+        }
+    }
+}
 /// Errors returned by DescribeActivity
 #[derive(Debug, PartialEq)]
 pub enum DescribeActivityError {
@@ -1685,6 +1730,14 @@ impl fmt::Display for DescribeActivityError {
     }
 }
 impl Error for DescribeActivityError {}
+impl ServiceErrorCode for DescribeActivityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeActivityError::ActivityDoesNotExist(_) => "ActivityDoesNotExist",
+            DescribeActivityError::InvalidArn(_) => "InvalidArn",
+        }
+    }
+}
 /// Errors returned by DescribeExecution
 #[derive(Debug, PartialEq)]
 pub enum DescribeExecutionError {
@@ -1723,6 +1776,14 @@ impl fmt::Display for DescribeExecutionError {
     }
 }
 impl Error for DescribeExecutionError {}
+impl ServiceErrorCode for DescribeExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeExecutionError::ExecutionDoesNotExist(_) => "ExecutionDoesNotExist",
+            DescribeExecutionError::InvalidArn(_) => "InvalidArn",
+        }
+    }
+}
 /// Errors returned by DescribeStateMachine
 #[derive(Debug, PartialEq)]
 pub enum DescribeStateMachineError {
@@ -1763,6 +1824,14 @@ impl fmt::Display for DescribeStateMachineError {
     }
 }
 impl Error for DescribeStateMachineError {}
+impl ServiceErrorCode for DescribeStateMachineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStateMachineError::InvalidArn(_) => "InvalidArn",
+            DescribeStateMachineError::StateMachineDoesNotExist(_) => "StateMachineDoesNotExist",
+        }
+    }
+}
 /// Errors returned by DescribeStateMachineForExecution
 #[derive(Debug, PartialEq)]
 pub enum DescribeStateMachineForExecutionError {
@@ -1807,6 +1876,16 @@ impl fmt::Display for DescribeStateMachineForExecutionError {
     }
 }
 impl Error for DescribeStateMachineForExecutionError {}
+impl ServiceErrorCode for DescribeStateMachineForExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStateMachineForExecutionError::ExecutionDoesNotExist(_) => {
+                "ExecutionDoesNotExist"
+            }
+            DescribeStateMachineForExecutionError::InvalidArn(_) => "InvalidArn",
+        }
+    }
+}
 /// Errors returned by GetActivityTask
 #[derive(Debug, PartialEq)]
 pub enum GetActivityTaskError {
@@ -1853,6 +1932,15 @@ impl fmt::Display for GetActivityTaskError {
     }
 }
 impl Error for GetActivityTaskError {}
+impl ServiceErrorCode for GetActivityTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetActivityTaskError::ActivityDoesNotExist(_) => "ActivityDoesNotExist",
+            GetActivityTaskError::ActivityWorkerLimitExceeded(_) => "ActivityWorkerLimitExceeded",
+            GetActivityTaskError::InvalidArn(_) => "InvalidArn",
+        }
+    }
+}
 /// Errors returned by GetExecutionHistory
 #[derive(Debug, PartialEq)]
 pub enum GetExecutionHistoryError {
@@ -1897,6 +1985,15 @@ impl fmt::Display for GetExecutionHistoryError {
     }
 }
 impl Error for GetExecutionHistoryError {}
+impl ServiceErrorCode for GetExecutionHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetExecutionHistoryError::ExecutionDoesNotExist(_) => "ExecutionDoesNotExist",
+            GetExecutionHistoryError::InvalidArn(_) => "InvalidArn",
+            GetExecutionHistoryError::InvalidToken(_) => "InvalidToken",
+        }
+    }
+}
 /// Errors returned by ListActivities
 #[derive(Debug, PartialEq)]
 pub enum ListActivitiesError {
@@ -1927,6 +2024,13 @@ impl fmt::Display for ListActivitiesError {
     }
 }
 impl Error for ListActivitiesError {}
+impl ServiceErrorCode for ListActivitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListActivitiesError::InvalidToken(_) => "InvalidToken",
+        }
+    }
+}
 /// Errors returned by ListExecutions
 #[derive(Debug, PartialEq)]
 pub enum ListExecutionsError {
@@ -1979,6 +2083,16 @@ impl fmt::Display for ListExecutionsError {
     }
 }
 impl Error for ListExecutionsError {}
+impl ServiceErrorCode for ListExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListExecutionsError::InvalidArn(_) => "InvalidArn",
+            ListExecutionsError::InvalidToken(_) => "InvalidToken",
+            ListExecutionsError::StateMachineDoesNotExist(_) => "StateMachineDoesNotExist",
+            ListExecutionsError::StateMachineTypeNotSupported(_) => "StateMachineTypeNotSupported",
+        }
+    }
+}
 /// Errors returned by ListStateMachines
 #[derive(Debug, PartialEq)]
 pub enum ListStateMachinesError {
@@ -2009,6 +2123,13 @@ impl fmt::Display for ListStateMachinesError {
     }
 }
 impl Error for ListStateMachinesError {}
+impl ServiceErrorCode for ListStateMachinesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListStateMachinesError::InvalidToken(_) => "InvalidToken",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2047,6 +2168,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InvalidArn(_) => "InvalidArn",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by SendTaskFailure
 #[derive(Debug, PartialEq)]
 pub enum SendTaskFailureError {
@@ -2089,6 +2218,15 @@ impl fmt::Display for SendTaskFailureError {
     }
 }
 impl Error for SendTaskFailureError {}
+impl ServiceErrorCode for SendTaskFailureError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendTaskFailureError::InvalidToken(_) => "InvalidToken",
+            SendTaskFailureError::TaskDoesNotExist(_) => "TaskDoesNotExist",
+            SendTaskFailureError::TaskTimedOut(_) => "TaskTimedOut",
+        }
+    }
+}
 /// Errors returned by SendTaskHeartbeat
 #[derive(Debug, PartialEq)]
 pub enum SendTaskHeartbeatError {
@@ -2131,6 +2269,15 @@ impl fmt::Display for SendTaskHeartbeatError {
     }
 }
 impl Error for SendTaskHeartbeatError {}
+impl ServiceErrorCode for SendTaskHeartbeatError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendTaskHeartbeatError::InvalidToken(_) => "InvalidToken",
+            SendTaskHeartbeatError::TaskDoesNotExist(_) => "TaskDoesNotExist",
+            SendTaskHeartbeatError::TaskTimedOut(_) => "TaskTimedOut",
+        }
+    }
+}
 /// Errors returned by SendTaskSuccess
 #[derive(Debug, PartialEq)]
 pub enum SendTaskSuccessError {
@@ -2179,6 +2326,16 @@ impl fmt::Display for SendTaskSuccessError {
     }
 }
 impl Error for SendTaskSuccessError {}
+impl ServiceErrorCode for SendTaskSuccessError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SendTaskSuccessError::InvalidOutput(_) => "InvalidOutput",
+            SendTaskSuccessError::InvalidToken(_) => "InvalidToken",
+            SendTaskSuccessError::TaskDoesNotExist(_) => "TaskDoesNotExist",
+            SendTaskSuccessError::TaskTimedOut(_) => "TaskTimedOut",
+        }
+    }
+}
 /// Errors returned by StartExecution
 #[derive(Debug, PartialEq)]
 pub enum StartExecutionError {
@@ -2253,6 +2410,19 @@ impl fmt::Display for StartExecutionError {
     }
 }
 impl Error for StartExecutionError {}
+impl ServiceErrorCode for StartExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartExecutionError::ExecutionAlreadyExists(_) => "ExecutionAlreadyExists",
+            StartExecutionError::ExecutionLimitExceeded(_) => "ExecutionLimitExceeded",
+            StartExecutionError::InvalidArn(_) => "InvalidArn",
+            StartExecutionError::InvalidExecutionInput(_) => "InvalidExecutionInput",
+            StartExecutionError::InvalidName(_) => "InvalidName",
+            StartExecutionError::StateMachineDeleting(_) => "StateMachineDeleting",
+            StartExecutionError::StateMachineDoesNotExist(_) => "StateMachineDoesNotExist",
+        }
+    }
+}
 /// Errors returned by StartSyncExecution
 #[derive(Debug, PartialEq)]
 pub enum StartSyncExecutionError {
@@ -2323,6 +2493,20 @@ impl fmt::Display for StartSyncExecutionError {
     }
 }
 impl Error for StartSyncExecutionError {}
+impl ServiceErrorCode for StartSyncExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartSyncExecutionError::InvalidArn(_) => "InvalidArn",
+            StartSyncExecutionError::InvalidExecutionInput(_) => "InvalidExecutionInput",
+            StartSyncExecutionError::InvalidName(_) => "InvalidName",
+            StartSyncExecutionError::StateMachineDeleting(_) => "StateMachineDeleting",
+            StartSyncExecutionError::StateMachineDoesNotExist(_) => "StateMachineDoesNotExist",
+            StartSyncExecutionError::StateMachineTypeNotSupported(_) => {
+                "StateMachineTypeNotSupported"
+            }
+        }
+    }
+}
 /// Errors returned by StopExecution
 #[derive(Debug, PartialEq)]
 pub enum StopExecutionError {
@@ -2359,6 +2543,14 @@ impl fmt::Display for StopExecutionError {
     }
 }
 impl Error for StopExecutionError {}
+impl ServiceErrorCode for StopExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopExecutionError::ExecutionDoesNotExist(_) => "ExecutionDoesNotExist",
+            StopExecutionError::InvalidArn(_) => "InvalidArn",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2399,6 +2591,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InvalidArn(_) => "InvalidArn",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFound",
+            TagResourceError::TooManyTags(_) => "TooManyTags",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2435,6 +2636,14 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InvalidArn(_) => "InvalidArn",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFound",
+        }
+    }
+}
 /// Errors returned by UpdateStateMachine
 #[derive(Debug, PartialEq)]
 pub enum UpdateStateMachineError {
@@ -2517,6 +2726,23 @@ impl fmt::Display for UpdateStateMachineError {
     }
 }
 impl Error for UpdateStateMachineError {}
+impl ServiceErrorCode for UpdateStateMachineError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateStateMachineError::InvalidArn(_) => "InvalidArn",
+            UpdateStateMachineError::InvalidDefinition(_) => "InvalidDefinition",
+            UpdateStateMachineError::InvalidLoggingConfiguration(_) => {
+                "InvalidLoggingConfiguration"
+            }
+            UpdateStateMachineError::InvalidTracingConfiguration(_) => {
+                "InvalidTracingConfiguration"
+            }
+            UpdateStateMachineError::MissingRequiredParameter(_) => "MissingRequiredParameter",
+            UpdateStateMachineError::StateMachineDeleting(_) => "StateMachineDeleting",
+            UpdateStateMachineError::StateMachineDoesNotExist(_) => "StateMachineDoesNotExist",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS SFN API. AWS SFN clients implement this trait.
 #[async_trait]
 pub trait StepFunctions {
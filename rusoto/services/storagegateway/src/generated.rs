@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3451,6 +3451,14 @@ impl fmt::Display for ActivateGatewayError {
     }
 }
 impl Error for ActivateGatewayError {}
+impl ServiceErrorCode for ActivateGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ActivateGatewayError::InternalServerError(_) => "InternalServerError",
+            ActivateGatewayError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by AddCache
 #[derive(Debug, PartialEq)]
 pub enum AddCacheError {
@@ -3487,6 +3495,14 @@ impl fmt::Display for AddCacheError {
     }
 }
 impl Error for AddCacheError {}
+impl ServiceErrorCode for AddCacheError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddCacheError::InternalServerError(_) => "InternalServerError",
+            AddCacheError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by AddTagsToResource
 #[derive(Debug, PartialEq)]
 pub enum AddTagsToResourceError {
@@ -3527,6 +3543,14 @@ impl fmt::Display for AddTagsToResourceError {
     }
 }
 impl Error for AddTagsToResourceError {}
+impl ServiceErrorCode for AddTagsToResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddTagsToResourceError::InternalServerError(_) => "InternalServerError",
+            AddTagsToResourceError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by AddUploadBuffer
 #[derive(Debug, PartialEq)]
 pub enum AddUploadBufferError {
@@ -3565,6 +3589,14 @@ impl fmt::Display for AddUploadBufferError {
     }
 }
 impl Error for AddUploadBufferError {}
+impl ServiceErrorCode for AddUploadBufferError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddUploadBufferError::InternalServerError(_) => "InternalServerError",
+            AddUploadBufferError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by AddWorkingStorage
 #[derive(Debug, PartialEq)]
 pub enum AddWorkingStorageError {
@@ -3605,6 +3637,14 @@ impl fmt::Display for AddWorkingStorageError {
     }
 }
 impl Error for AddWorkingStorageError {}
+impl ServiceErrorCode for AddWorkingStorageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddWorkingStorageError::InternalServerError(_) => "InternalServerError",
+            AddWorkingStorageError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by AssignTapePool
 #[derive(Debug, PartialEq)]
 pub enum AssignTapePoolError {
@@ -3643,6 +3683,14 @@ impl fmt::Display for AssignTapePoolError {
     }
 }
 impl Error for AssignTapePoolError {}
+impl ServiceErrorCode for AssignTapePoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssignTapePoolError::InternalServerError(_) => "InternalServerError",
+            AssignTapePoolError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by AttachVolume
 #[derive(Debug, PartialEq)]
 pub enum AttachVolumeError {
@@ -3679,6 +3727,14 @@ impl fmt::Display for AttachVolumeError {
     }
 }
 impl Error for AttachVolumeError {}
+impl ServiceErrorCode for AttachVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AttachVolumeError::InternalServerError(_) => "InternalServerError",
+            AttachVolumeError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by CancelArchival
 #[derive(Debug, PartialEq)]
 pub enum CancelArchivalError {
@@ -3717,6 +3773,14 @@ impl fmt::Display for CancelArchivalError {
     }
 }
 impl Error for CancelArchivalError {}
+impl ServiceErrorCode for CancelArchivalError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelArchivalError::InternalServerError(_) => "InternalServerError",
+            CancelArchivalError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by CancelRetrieval
 #[derive(Debug, PartialEq)]
 pub enum CancelRetrievalError {
@@ -3755,6 +3819,14 @@ impl fmt::Display for CancelRetrievalError {
     }
 }
 impl Error for CancelRetrievalError {}
+impl ServiceErrorCode for CancelRetrievalError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelRetrievalError::InternalServerError(_) => "InternalServerError",
+            CancelRetrievalError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by CreateCachediSCSIVolume
 #[derive(Debug, PartialEq)]
 pub enum CreateCachediSCSIVolumeError {
@@ -3797,6 +3869,16 @@ impl fmt::Display for CreateCachediSCSIVolumeError {
     }
 }
 impl Error for CreateCachediSCSIVolumeError {}
+impl ServiceErrorCode for CreateCachediSCSIVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCachediSCSIVolumeError::InternalServerError(_) => "InternalServerError",
+            CreateCachediSCSIVolumeError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateNFSFileShare
 #[derive(Debug, PartialEq)]
 pub enum CreateNFSFileShareError {
@@ -3837,6 +3919,14 @@ impl fmt::Display for CreateNFSFileShareError {
     }
 }
 impl Error for CreateNFSFileShareError {}
+impl ServiceErrorCode for CreateNFSFileShareError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateNFSFileShareError::InternalServerError(_) => "InternalServerError",
+            CreateNFSFileShareError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by CreateSMBFileShare
 #[derive(Debug, PartialEq)]
 pub enum CreateSMBFileShareError {
@@ -3877,6 +3967,14 @@ impl fmt::Display for CreateSMBFileShareError {
     }
 }
 impl Error for CreateSMBFileShareError {}
+impl ServiceErrorCode for CreateSMBFileShareError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSMBFileShareError::InternalServerError(_) => "InternalServerError",
+            CreateSMBFileShareError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by CreateSnapshot
 #[derive(Debug, PartialEq)]
 pub enum CreateSnapshotError {
@@ -3923,6 +4021,15 @@ impl fmt::Display for CreateSnapshotError {
     }
 }
 impl Error for CreateSnapshotError {}
+impl ServiceErrorCode for CreateSnapshotError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSnapshotError::InternalServerError(_) => "InternalServerError",
+            CreateSnapshotError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+            CreateSnapshotError::ServiceUnavailableError(_) => "ServiceUnavailableError",
+        }
+    }
+}
 /// Errors returned by CreateSnapshotFromVolumeRecoveryPoint
 #[derive(Debug, PartialEq)]
 pub enum CreateSnapshotFromVolumeRecoveryPointError {
@@ -3981,6 +4088,21 @@ impl fmt::Display for CreateSnapshotFromVolumeRecoveryPointError {
     }
 }
 impl Error for CreateSnapshotFromVolumeRecoveryPointError {}
+impl ServiceErrorCode for CreateSnapshotFromVolumeRecoveryPointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSnapshotFromVolumeRecoveryPointError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+            CreateSnapshotFromVolumeRecoveryPointError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+            CreateSnapshotFromVolumeRecoveryPointError::ServiceUnavailableError(_) => {
+                "ServiceUnavailableError"
+            }
+        }
+    }
+}
 /// Errors returned by CreateStorediSCSIVolume
 #[derive(Debug, PartialEq)]
 pub enum CreateStorediSCSIVolumeError {
@@ -4023,6 +4145,16 @@ impl fmt::Display for CreateStorediSCSIVolumeError {
     }
 }
 impl Error for CreateStorediSCSIVolumeError {}
+impl ServiceErrorCode for CreateStorediSCSIVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateStorediSCSIVolumeError::InternalServerError(_) => "InternalServerError",
+            CreateStorediSCSIVolumeError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateTapePool
 #[derive(Debug, PartialEq)]
 pub enum CreateTapePoolError {
@@ -4061,6 +4193,14 @@ impl fmt::Display for CreateTapePoolError {
     }
 }
 impl Error for CreateTapePoolError {}
+impl ServiceErrorCode for CreateTapePoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTapePoolError::InternalServerError(_) => "InternalServerError",
+            CreateTapePoolError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by CreateTapeWithBarcode
 #[derive(Debug, PartialEq)]
 pub enum CreateTapeWithBarcodeError {
@@ -4101,6 +4241,16 @@ impl fmt::Display for CreateTapeWithBarcodeError {
     }
 }
 impl Error for CreateTapeWithBarcodeError {}
+impl ServiceErrorCode for CreateTapeWithBarcodeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTapeWithBarcodeError::InternalServerError(_) => "InternalServerError",
+            CreateTapeWithBarcodeError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateTapes
 #[derive(Debug, PartialEq)]
 pub enum CreateTapesError {
@@ -4137,6 +4287,14 @@ impl fmt::Display for CreateTapesError {
     }
 }
 impl Error for CreateTapesError {}
+impl ServiceErrorCode for CreateTapesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTapesError::InternalServerError(_) => "InternalServerError",
+            CreateTapesError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteAutomaticTapeCreationPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteAutomaticTapeCreationPolicyError {
@@ -4183,6 +4341,16 @@ impl fmt::Display for DeleteAutomaticTapeCreationPolicyError {
     }
 }
 impl Error for DeleteAutomaticTapeCreationPolicyError {}
+impl ServiceErrorCode for DeleteAutomaticTapeCreationPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAutomaticTapeCreationPolicyError::InternalServerError(_) => "InternalServerError",
+            DeleteAutomaticTapeCreationPolicyError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteBandwidthRateLimit
 #[derive(Debug, PartialEq)]
 pub enum DeleteBandwidthRateLimitError {
@@ -4225,6 +4393,16 @@ impl fmt::Display for DeleteBandwidthRateLimitError {
     }
 }
 impl Error for DeleteBandwidthRateLimitError {}
+impl ServiceErrorCode for DeleteBandwidthRateLimitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteBandwidthRateLimitError::InternalServerError(_) => "InternalServerError",
+            DeleteBandwidthRateLimitError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteChapCredentials
 #[derive(Debug, PartialEq)]
 pub enum DeleteChapCredentialsError {
@@ -4265,6 +4443,16 @@ impl fmt::Display for DeleteChapCredentialsError {
     }
 }
 impl Error for DeleteChapCredentialsError {}
+impl ServiceErrorCode for DeleteChapCredentialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteChapCredentialsError::InternalServerError(_) => "InternalServerError",
+            DeleteChapCredentialsError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteFileShare
 #[derive(Debug, PartialEq)]
 pub enum DeleteFileShareError {
@@ -4303,6 +4491,14 @@ impl fmt::Display for DeleteFileShareError {
     }
 }
 impl Error for DeleteFileShareError {}
+impl ServiceErrorCode for DeleteFileShareError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFileShareError::InternalServerError(_) => "InternalServerError",
+            DeleteFileShareError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteGateway
 #[derive(Debug, PartialEq)]
 pub enum DeleteGatewayError {
@@ -4339,6 +4535,14 @@ impl fmt::Display for DeleteGatewayError {
     }
 }
 impl Error for DeleteGatewayError {}
+impl ServiceErrorCode for DeleteGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGatewayError::InternalServerError(_) => "InternalServerError",
+            DeleteGatewayError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteSnapshotSchedule
 #[derive(Debug, PartialEq)]
 pub enum DeleteSnapshotScheduleError {
@@ -4379,6 +4583,16 @@ impl fmt::Display for DeleteSnapshotScheduleError {
     }
 }
 impl Error for DeleteSnapshotScheduleError {}
+impl ServiceErrorCode for DeleteSnapshotScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSnapshotScheduleError::InternalServerError(_) => "InternalServerError",
+            DeleteSnapshotScheduleError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteTape
 #[derive(Debug, PartialEq)]
 pub enum DeleteTapeError {
@@ -4415,6 +4629,14 @@ impl fmt::Display for DeleteTapeError {
     }
 }
 impl Error for DeleteTapeError {}
+impl ServiceErrorCode for DeleteTapeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTapeError::InternalServerError(_) => "InternalServerError",
+            DeleteTapeError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteTapeArchive
 #[derive(Debug, PartialEq)]
 pub enum DeleteTapeArchiveError {
@@ -4455,6 +4677,14 @@ impl fmt::Display for DeleteTapeArchiveError {
     }
 }
 impl Error for DeleteTapeArchiveError {}
+impl ServiceErrorCode for DeleteTapeArchiveError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTapeArchiveError::InternalServerError(_) => "InternalServerError",
+            DeleteTapeArchiveError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteTapePool
 #[derive(Debug, PartialEq)]
 pub enum DeleteTapePoolError {
@@ -4493,6 +4723,14 @@ impl fmt::Display for DeleteTapePoolError {
     }
 }
 impl Error for DeleteTapePoolError {}
+impl ServiceErrorCode for DeleteTapePoolError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTapePoolError::InternalServerError(_) => "InternalServerError",
+            DeleteTapePoolError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by DeleteVolume
 #[derive(Debug, PartialEq)]
 pub enum DeleteVolumeError {
@@ -4529,6 +4767,14 @@ impl fmt::Display for DeleteVolumeError {
     }
 }
 impl Error for DeleteVolumeError {}
+impl ServiceErrorCode for DeleteVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVolumeError::InternalServerError(_) => "InternalServerError",
+            DeleteVolumeError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeAvailabilityMonitorTest
 #[derive(Debug, PartialEq)]
 pub enum DescribeAvailabilityMonitorTestError {
@@ -4575,6 +4821,16 @@ impl fmt::Display for DescribeAvailabilityMonitorTestError {
     }
 }
 impl Error for DescribeAvailabilityMonitorTestError {}
+impl ServiceErrorCode for DescribeAvailabilityMonitorTestError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAvailabilityMonitorTestError::InternalServerError(_) => "InternalServerError",
+            DescribeAvailabilityMonitorTestError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeBandwidthRateLimit
 #[derive(Debug, PartialEq)]
 pub enum DescribeBandwidthRateLimitError {
@@ -4621,6 +4877,16 @@ impl fmt::Display for DescribeBandwidthRateLimitError {
     }
 }
 impl Error for DescribeBandwidthRateLimitError {}
+impl ServiceErrorCode for DescribeBandwidthRateLimitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBandwidthRateLimitError::InternalServerError(_) => "InternalServerError",
+            DescribeBandwidthRateLimitError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeBandwidthRateLimitSchedule
 #[derive(Debug, PartialEq)]
 pub enum DescribeBandwidthRateLimitScheduleError {
@@ -4667,6 +4933,18 @@ impl fmt::Display for DescribeBandwidthRateLimitScheduleError {
     }
 }
 impl Error for DescribeBandwidthRateLimitScheduleError {}
+impl ServiceErrorCode for DescribeBandwidthRateLimitScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeBandwidthRateLimitScheduleError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+            DescribeBandwidthRateLimitScheduleError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeCache
 #[derive(Debug, PartialEq)]
 pub enum DescribeCacheError {
@@ -4703,6 +4981,14 @@ impl fmt::Display for DescribeCacheError {
     }
 }
 impl Error for DescribeCacheError {}
+impl ServiceErrorCode for DescribeCacheError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCacheError::InternalServerError(_) => "InternalServerError",
+            DescribeCacheError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeCachediSCSIVolumes
 #[derive(Debug, PartialEq)]
 pub enum DescribeCachediSCSIVolumesError {
@@ -4749,6 +5035,16 @@ impl fmt::Display for DescribeCachediSCSIVolumesError {
     }
 }
 impl Error for DescribeCachediSCSIVolumesError {}
+impl ServiceErrorCode for DescribeCachediSCSIVolumesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCachediSCSIVolumesError::InternalServerError(_) => "InternalServerError",
+            DescribeCachediSCSIVolumesError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeChapCredentials
 #[derive(Debug, PartialEq)]
 pub enum DescribeChapCredentialsError {
@@ -4791,6 +5087,16 @@ impl fmt::Display for DescribeChapCredentialsError {
     }
 }
 impl Error for DescribeChapCredentialsError {}
+impl ServiceErrorCode for DescribeChapCredentialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeChapCredentialsError::InternalServerError(_) => "InternalServerError",
+            DescribeChapCredentialsError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeGatewayInformation
 #[derive(Debug, PartialEq)]
 pub enum DescribeGatewayInformationError {
@@ -4837,6 +5143,16 @@ impl fmt::Display for DescribeGatewayInformationError {
     }
 }
 impl Error for DescribeGatewayInformationError {}
+impl ServiceErrorCode for DescribeGatewayInformationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGatewayInformationError::InternalServerError(_) => "InternalServerError",
+            DescribeGatewayInformationError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeMaintenanceStartTime
 #[derive(Debug, PartialEq)]
 pub enum DescribeMaintenanceStartTimeError {
@@ -4883,6 +5199,16 @@ impl fmt::Display for DescribeMaintenanceStartTimeError {
     }
 }
 impl Error for DescribeMaintenanceStartTimeError {}
+impl ServiceErrorCode for DescribeMaintenanceStartTimeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMaintenanceStartTimeError::InternalServerError(_) => "InternalServerError",
+            DescribeMaintenanceStartTimeError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeNFSFileShares
 #[derive(Debug, PartialEq)]
 pub enum DescribeNFSFileSharesError {
@@ -4923,6 +5249,16 @@ impl fmt::Display for DescribeNFSFileSharesError {
     }
 }
 impl Error for DescribeNFSFileSharesError {}
+impl ServiceErrorCode for DescribeNFSFileSharesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeNFSFileSharesError::InternalServerError(_) => "InternalServerError",
+            DescribeNFSFileSharesError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeSMBFileShares
 #[derive(Debug, PartialEq)]
 pub enum DescribeSMBFileSharesError {
@@ -4963,6 +5299,16 @@ impl fmt::Display for DescribeSMBFileSharesError {
     }
 }
 impl Error for DescribeSMBFileSharesError {}
+impl ServiceErrorCode for DescribeSMBFileSharesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSMBFileSharesError::InternalServerError(_) => "InternalServerError",
+            DescribeSMBFileSharesError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeSMBSettings
 #[derive(Debug, PartialEq)]
 pub enum DescribeSMBSettingsError {
@@ -5003,6 +5349,14 @@ impl fmt::Display for DescribeSMBSettingsError {
     }
 }
 impl Error for DescribeSMBSettingsError {}
+impl ServiceErrorCode for DescribeSMBSettingsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSMBSettingsError::InternalServerError(_) => "InternalServerError",
+            DescribeSMBSettingsError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeSnapshotSchedule
 #[derive(Debug, PartialEq)]
 pub enum DescribeSnapshotScheduleError {
@@ -5045,6 +5399,16 @@ impl fmt::Display for DescribeSnapshotScheduleError {
     }
 }
 impl Error for DescribeSnapshotScheduleError {}
+impl ServiceErrorCode for DescribeSnapshotScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSnapshotScheduleError::InternalServerError(_) => "InternalServerError",
+            DescribeSnapshotScheduleError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeStorediSCSIVolumes
 #[derive(Debug, PartialEq)]
 pub enum DescribeStorediSCSIVolumesError {
@@ -5091,6 +5455,16 @@ impl fmt::Display for DescribeStorediSCSIVolumesError {
     }
 }
 impl Error for DescribeStorediSCSIVolumesError {}
+impl ServiceErrorCode for DescribeStorediSCSIVolumesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeStorediSCSIVolumesError::InternalServerError(_) => "InternalServerError",
+            DescribeStorediSCSIVolumesError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeTapeArchives
 #[derive(Debug, PartialEq)]
 pub enum DescribeTapeArchivesError {
@@ -5131,6 +5505,14 @@ impl fmt::Display for DescribeTapeArchivesError {
     }
 }
 impl Error for DescribeTapeArchivesError {}
+impl ServiceErrorCode for DescribeTapeArchivesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTapeArchivesError::InternalServerError(_) => "InternalServerError",
+            DescribeTapeArchivesError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeTapeRecoveryPoints
 #[derive(Debug, PartialEq)]
 pub enum DescribeTapeRecoveryPointsError {
@@ -5177,6 +5559,16 @@ impl fmt::Display for DescribeTapeRecoveryPointsError {
     }
 }
 impl Error for DescribeTapeRecoveryPointsError {}
+impl ServiceErrorCode for DescribeTapeRecoveryPointsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTapeRecoveryPointsError::InternalServerError(_) => "InternalServerError",
+            DescribeTapeRecoveryPointsError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeTapes
 #[derive(Debug, PartialEq)]
 pub enum DescribeTapesError {
@@ -5213,6 +5605,14 @@ impl fmt::Display for DescribeTapesError {
     }
 }
 impl Error for DescribeTapesError {}
+impl ServiceErrorCode for DescribeTapesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTapesError::InternalServerError(_) => "InternalServerError",
+            DescribeTapesError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeUploadBuffer
 #[derive(Debug, PartialEq)]
 pub enum DescribeUploadBufferError {
@@ -5253,6 +5653,14 @@ impl fmt::Display for DescribeUploadBufferError {
     }
 }
 impl Error for DescribeUploadBufferError {}
+impl ServiceErrorCode for DescribeUploadBufferError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUploadBufferError::InternalServerError(_) => "InternalServerError",
+            DescribeUploadBufferError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeVTLDevices
 #[derive(Debug, PartialEq)]
 pub enum DescribeVTLDevicesError {
@@ -5293,6 +5701,14 @@ impl fmt::Display for DescribeVTLDevicesError {
     }
 }
 impl Error for DescribeVTLDevicesError {}
+impl ServiceErrorCode for DescribeVTLDevicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeVTLDevicesError::InternalServerError(_) => "InternalServerError",
+            DescribeVTLDevicesError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by DescribeWorkingStorage
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorkingStorageError {
@@ -5333,6 +5749,16 @@ impl fmt::Display for DescribeWorkingStorageError {
     }
 }
 impl Error for DescribeWorkingStorageError {}
+impl ServiceErrorCode for DescribeWorkingStorageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeWorkingStorageError::InternalServerError(_) => "InternalServerError",
+            DescribeWorkingStorageError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by DetachVolume
 #[derive(Debug, PartialEq)]
 pub enum DetachVolumeError {
@@ -5369,6 +5795,14 @@ impl fmt::Display for DetachVolumeError {
     }
 }
 impl Error for DetachVolumeError {}
+impl ServiceErrorCode for DetachVolumeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetachVolumeError::InternalServerError(_) => "InternalServerError",
+            DetachVolumeError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by DisableGateway
 #[derive(Debug, PartialEq)]
 pub enum DisableGatewayError {
@@ -5407,6 +5841,14 @@ impl fmt::Display for DisableGatewayError {
     }
 }
 impl Error for DisableGatewayError {}
+impl ServiceErrorCode for DisableGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisableGatewayError::InternalServerError(_) => "InternalServerError",
+            DisableGatewayError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by JoinDomain
 #[derive(Debug, PartialEq)]
 pub enum JoinDomainError {
@@ -5443,6 +5885,14 @@ impl fmt::Display for JoinDomainError {
     }
 }
 impl Error for JoinDomainError {}
+impl ServiceErrorCode for JoinDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            JoinDomainError::InternalServerError(_) => "InternalServerError",
+            JoinDomainError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by ListAutomaticTapeCreationPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListAutomaticTapeCreationPoliciesError {
@@ -5489,6 +5939,16 @@ impl fmt::Display for ListAutomaticTapeCreationPoliciesError {
     }
 }
 impl Error for ListAutomaticTapeCreationPoliciesError {}
+impl ServiceErrorCode for ListAutomaticTapeCreationPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAutomaticTapeCreationPoliciesError::InternalServerError(_) => "InternalServerError",
+            ListAutomaticTapeCreationPoliciesError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by ListFileShares
 #[derive(Debug, PartialEq)]
 pub enum ListFileSharesError {
@@ -5527,6 +5987,14 @@ impl fmt::Display for ListFileSharesError {
     }
 }
 impl Error for ListFileSharesError {}
+impl ServiceErrorCode for ListFileSharesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFileSharesError::InternalServerError(_) => "InternalServerError",
+            ListFileSharesError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by ListGateways
 #[derive(Debug, PartialEq)]
 pub enum ListGatewaysError {
@@ -5563,6 +6031,14 @@ impl fmt::Display for ListGatewaysError {
     }
 }
 impl Error for ListGatewaysError {}
+impl ServiceErrorCode for ListGatewaysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGatewaysError::InternalServerError(_) => "InternalServerError",
+            ListGatewaysError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by ListLocalDisks
 #[derive(Debug, PartialEq)]
 pub enum ListLocalDisksError {
@@ -5601,6 +6077,14 @@ impl fmt::Display for ListLocalDisksError {
     }
 }
 impl Error for ListLocalDisksError {}
+impl ServiceErrorCode for ListLocalDisksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLocalDisksError::InternalServerError(_) => "InternalServerError",
+            ListLocalDisksError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -5641,6 +6125,14 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalServerError(_) => "InternalServerError",
+            ListTagsForResourceError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by ListTapePools
 #[derive(Debug, PartialEq)]
 pub enum ListTapePoolsError {
@@ -5677,6 +6169,14 @@ impl fmt::Display for ListTapePoolsError {
     }
 }
 impl Error for ListTapePoolsError {}
+impl ServiceErrorCode for ListTapePoolsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTapePoolsError::InternalServerError(_) => "InternalServerError",
+            ListTapePoolsError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by ListTapes
 #[derive(Debug, PartialEq)]
 pub enum ListTapesError {
@@ -5713,6 +6213,14 @@ impl fmt::Display for ListTapesError {
     }
 }
 impl Error for ListTapesError {}
+impl ServiceErrorCode for ListTapesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTapesError::InternalServerError(_) => "InternalServerError",
+            ListTapesError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by ListVolumeInitiators
 #[derive(Debug, PartialEq)]
 pub enum ListVolumeInitiatorsError {
@@ -5753,6 +6261,14 @@ impl fmt::Display for ListVolumeInitiatorsError {
     }
 }
 impl Error for ListVolumeInitiatorsError {}
+impl ServiceErrorCode for ListVolumeInitiatorsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVolumeInitiatorsError::InternalServerError(_) => "InternalServerError",
+            ListVolumeInitiatorsError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by ListVolumeRecoveryPoints
 #[derive(Debug, PartialEq)]
 pub enum ListVolumeRecoveryPointsError {
@@ -5795,6 +6311,16 @@ impl fmt::Display for ListVolumeRecoveryPointsError {
     }
 }
 impl Error for ListVolumeRecoveryPointsError {}
+impl ServiceErrorCode for ListVolumeRecoveryPointsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVolumeRecoveryPointsError::InternalServerError(_) => "InternalServerError",
+            ListVolumeRecoveryPointsError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by ListVolumes
 #[derive(Debug, PartialEq)]
 pub enum ListVolumesError {
@@ -5831,6 +6357,14 @@ impl fmt::Display for ListVolumesError {
     }
 }
 impl Error for ListVolumesError {}
+impl ServiceErrorCode for ListVolumesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVolumesError::InternalServerError(_) => "InternalServerError",
+            ListVolumesError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by NotifyWhenUploaded
 #[derive(Debug, PartialEq)]
 pub enum NotifyWhenUploadedError {
@@ -5871,6 +6405,14 @@ impl fmt::Display for NotifyWhenUploadedError {
     }
 }
 impl Error for NotifyWhenUploadedError {}
+impl ServiceErrorCode for NotifyWhenUploadedError {
+    fn error_code(&self) -> &str {
+        match *self {
+            NotifyWhenUploadedError::InternalServerError(_) => "InternalServerError",
+            NotifyWhenUploadedError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by RefreshCache
 #[derive(Debug, PartialEq)]
 pub enum RefreshCacheError {
@@ -5907,6 +6449,14 @@ impl fmt::Display for RefreshCacheError {
     }
 }
 impl Error for RefreshCacheError {}
+impl ServiceErrorCode for RefreshCacheError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RefreshCacheError::InternalServerError(_) => "InternalServerError",
+            RefreshCacheError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
 pub enum RemoveTagsFromResourceError {
@@ -5947,6 +6497,16 @@ impl fmt::Display for RemoveTagsFromResourceError {
     }
 }
 impl Error for RemoveTagsFromResourceError {}
+impl ServiceErrorCode for RemoveTagsFromResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveTagsFromResourceError::InternalServerError(_) => "InternalServerError",
+            RemoveTagsFromResourceError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by ResetCache
 #[derive(Debug, PartialEq)]
 pub enum ResetCacheError {
@@ -5983,6 +6543,14 @@ impl fmt::Display for ResetCacheError {
     }
 }
 impl Error for ResetCacheError {}
+impl ServiceErrorCode for ResetCacheError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetCacheError::InternalServerError(_) => "InternalServerError",
+            ResetCacheError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by RetrieveTapeArchive
 #[derive(Debug, PartialEq)]
 pub enum RetrieveTapeArchiveError {
@@ -6023,6 +6591,14 @@ impl fmt::Display for RetrieveTapeArchiveError {
     }
 }
 impl Error for RetrieveTapeArchiveError {}
+impl ServiceErrorCode for RetrieveTapeArchiveError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RetrieveTapeArchiveError::InternalServerError(_) => "InternalServerError",
+            RetrieveTapeArchiveError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by RetrieveTapeRecoveryPoint
 #[derive(Debug, PartialEq)]
 pub enum RetrieveTapeRecoveryPointError {
@@ -6067,6 +6643,16 @@ impl fmt::Display for RetrieveTapeRecoveryPointError {
     }
 }
 impl Error for RetrieveTapeRecoveryPointError {}
+impl ServiceErrorCode for RetrieveTapeRecoveryPointError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RetrieveTapeRecoveryPointError::InternalServerError(_) => "InternalServerError",
+            RetrieveTapeRecoveryPointError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by SetLocalConsolePassword
 #[derive(Debug, PartialEq)]
 pub enum SetLocalConsolePasswordError {
@@ -6109,6 +6695,16 @@ impl fmt::Display for SetLocalConsolePasswordError {
     }
 }
 impl Error for SetLocalConsolePasswordError {}
+impl ServiceErrorCode for SetLocalConsolePasswordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetLocalConsolePasswordError::InternalServerError(_) => "InternalServerError",
+            SetLocalConsolePasswordError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by SetSMBGuestPassword
 #[derive(Debug, PartialEq)]
 pub enum SetSMBGuestPasswordError {
@@ -6149,6 +6745,14 @@ impl fmt::Display for SetSMBGuestPasswordError {
     }
 }
 impl Error for SetSMBGuestPasswordError {}
+impl ServiceErrorCode for SetSMBGuestPasswordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SetSMBGuestPasswordError::InternalServerError(_) => "InternalServerError",
+            SetSMBGuestPasswordError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by ShutdownGateway
 #[derive(Debug, PartialEq)]
 pub enum ShutdownGatewayError {
@@ -6187,6 +6791,14 @@ impl fmt::Display for ShutdownGatewayError {
     }
 }
 impl Error for ShutdownGatewayError {}
+impl ServiceErrorCode for ShutdownGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ShutdownGatewayError::InternalServerError(_) => "InternalServerError",
+            ShutdownGatewayError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by StartAvailabilityMonitorTest
 #[derive(Debug, PartialEq)]
 pub enum StartAvailabilityMonitorTestError {
@@ -6233,6 +6845,16 @@ impl fmt::Display for StartAvailabilityMonitorTestError {
     }
 }
 impl Error for StartAvailabilityMonitorTestError {}
+impl ServiceErrorCode for StartAvailabilityMonitorTestError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartAvailabilityMonitorTestError::InternalServerError(_) => "InternalServerError",
+            StartAvailabilityMonitorTestError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by StartGateway
 #[derive(Debug, PartialEq)]
 pub enum StartGatewayError {
@@ -6269,6 +6891,14 @@ impl fmt::Display for StartGatewayError {
     }
 }
 impl Error for StartGatewayError {}
+impl ServiceErrorCode for StartGatewayError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartGatewayError::InternalServerError(_) => "InternalServerError",
+            StartGatewayError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateAutomaticTapeCreationPolicy
 #[derive(Debug, PartialEq)]
 pub enum UpdateAutomaticTapeCreationPolicyError {
@@ -6315,6 +6945,16 @@ impl fmt::Display for UpdateAutomaticTapeCreationPolicyError {
     }
 }
 impl Error for UpdateAutomaticTapeCreationPolicyError {}
+impl ServiceErrorCode for UpdateAutomaticTapeCreationPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAutomaticTapeCreationPolicyError::InternalServerError(_) => "InternalServerError",
+            UpdateAutomaticTapeCreationPolicyError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateBandwidthRateLimit
 #[derive(Debug, PartialEq)]
 pub enum UpdateBandwidthRateLimitError {
@@ -6357,6 +6997,16 @@ impl fmt::Display for UpdateBandwidthRateLimitError {
     }
 }
 impl Error for UpdateBandwidthRateLimitError {}
+impl ServiceErrorCode for UpdateBandwidthRateLimitError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateBandwidthRateLimitError::InternalServerError(_) => "InternalServerError",
+            UpdateBandwidthRateLimitError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateBandwidthRateLimitSchedule
 #[derive(Debug, PartialEq)]
 pub enum UpdateBandwidthRateLimitScheduleError {
@@ -6403,6 +7053,16 @@ impl fmt::Display for UpdateBandwidthRateLimitScheduleError {
     }
 }
 impl Error for UpdateBandwidthRateLimitScheduleError {}
+impl ServiceErrorCode for UpdateBandwidthRateLimitScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateBandwidthRateLimitScheduleError::InternalServerError(_) => "InternalServerError",
+            UpdateBandwidthRateLimitScheduleError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateChapCredentials
 #[derive(Debug, PartialEq)]
 pub enum UpdateChapCredentialsError {
@@ -6443,6 +7103,16 @@ impl fmt::Display for UpdateChapCredentialsError {
     }
 }
 impl Error for UpdateChapCredentialsError {}
+impl ServiceErrorCode for UpdateChapCredentialsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateChapCredentialsError::InternalServerError(_) => "InternalServerError",
+            UpdateChapCredentialsError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateGatewayInformation
 #[derive(Debug, PartialEq)]
 pub enum UpdateGatewayInformationError {
@@ -6485,6 +7155,16 @@ impl fmt::Display for UpdateGatewayInformationError {
     }
 }
 impl Error for UpdateGatewayInformationError {}
+impl ServiceErrorCode for UpdateGatewayInformationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGatewayInformationError::InternalServerError(_) => "InternalServerError",
+            UpdateGatewayInformationError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateGatewaySoftwareNow
 #[derive(Debug, PartialEq)]
 pub enum UpdateGatewaySoftwareNowError {
@@ -6527,6 +7207,16 @@ impl fmt::Display for UpdateGatewaySoftwareNowError {
     }
 }
 impl Error for UpdateGatewaySoftwareNowError {}
+impl ServiceErrorCode for UpdateGatewaySoftwareNowError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGatewaySoftwareNowError::InternalServerError(_) => "InternalServerError",
+            UpdateGatewaySoftwareNowError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateMaintenanceStartTime
 #[derive(Debug, PartialEq)]
 pub enum UpdateMaintenanceStartTimeError {
@@ -6573,6 +7263,16 @@ impl fmt::Display for UpdateMaintenanceStartTimeError {
     }
 }
 impl Error for UpdateMaintenanceStartTimeError {}
+impl ServiceErrorCode for UpdateMaintenanceStartTimeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMaintenanceStartTimeError::InternalServerError(_) => "InternalServerError",
+            UpdateMaintenanceStartTimeError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateNFSFileShare
 #[derive(Debug, PartialEq)]
 pub enum UpdateNFSFileShareError {
@@ -6613,6 +7313,14 @@ impl fmt::Display for UpdateNFSFileShareError {
     }
 }
 impl Error for UpdateNFSFileShareError {}
+impl ServiceErrorCode for UpdateNFSFileShareError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateNFSFileShareError::InternalServerError(_) => "InternalServerError",
+            UpdateNFSFileShareError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateSMBFileShare
 #[derive(Debug, PartialEq)]
 pub enum UpdateSMBFileShareError {
@@ -6653,6 +7361,14 @@ impl fmt::Display for UpdateSMBFileShareError {
     }
 }
 impl Error for UpdateSMBFileShareError {}
+impl ServiceErrorCode for UpdateSMBFileShareError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSMBFileShareError::InternalServerError(_) => "InternalServerError",
+            UpdateSMBFileShareError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateSMBFileShareVisibility
 #[derive(Debug, PartialEq)]
 pub enum UpdateSMBFileShareVisibilityError {
@@ -6699,6 +7415,16 @@ impl fmt::Display for UpdateSMBFileShareVisibilityError {
     }
 }
 impl Error for UpdateSMBFileShareVisibilityError {}
+impl ServiceErrorCode for UpdateSMBFileShareVisibilityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSMBFileShareVisibilityError::InternalServerError(_) => "InternalServerError",
+            UpdateSMBFileShareVisibilityError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateSMBSecurityStrategy
 #[derive(Debug, PartialEq)]
 pub enum UpdateSMBSecurityStrategyError {
@@ -6743,6 +7469,16 @@ impl fmt::Display for UpdateSMBSecurityStrategyError {
     }
 }
 impl Error for UpdateSMBSecurityStrategyError {}
+impl ServiceErrorCode for UpdateSMBSecurityStrategyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSMBSecurityStrategyError::InternalServerError(_) => "InternalServerError",
+            UpdateSMBSecurityStrategyError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateSnapshotSchedule
 #[derive(Debug, PartialEq)]
 pub enum UpdateSnapshotScheduleError {
@@ -6783,6 +7519,16 @@ impl fmt::Display for UpdateSnapshotScheduleError {
     }
 }
 impl Error for UpdateSnapshotScheduleError {}
+impl ServiceErrorCode for UpdateSnapshotScheduleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSnapshotScheduleError::InternalServerError(_) => "InternalServerError",
+            UpdateSnapshotScheduleError::InvalidGatewayRequest(_) => {
+                "InvalidGatewayRequestException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateVTLDeviceType
 #[derive(Debug, PartialEq)]
 pub enum UpdateVTLDeviceTypeError {
@@ -6823,6 +7569,14 @@ impl fmt::Display for UpdateVTLDeviceTypeError {
     }
 }
 impl Error for UpdateVTLDeviceTypeError {}
+impl ServiceErrorCode for UpdateVTLDeviceTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateVTLDeviceTypeError::InternalServerError(_) => "InternalServerError",
+            UpdateVTLDeviceTypeError::InvalidGatewayRequest(_) => "InvalidGatewayRequestException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Storage Gateway API. AWS Storage Gateway clients implement this trait.
 #[async_trait]
 pub trait StorageGateway {
@@ -15,7 +15,9 @@ use crate::{
     GetFederationTokenResponse, GetSessionTokenError, GetSessionTokenRequest,
     GetSessionTokenResponse, Sts, StsClient,
 };
-use rusoto_core::credential::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
+use rusoto_core::credential::{
+    AwsCredentials, CredentialsError, CredentialsErrorKind, ProvideAwsCredentials,
+};
 
 pub const DEFAULT_DURATION_SECONDS: i32 = 3600;
 pub const DEFAULT_ROLE_DURATION_SECONDS: i32 = 900;
@@ -49,6 +51,28 @@ impl NewAwsCredsForStsCreds for AwsCredentials {
     }
 }
 
+/// Wraps an error from an STS call made to fetch credentials, keeping track of whether
+/// STS couldn't be reached or refused the request.
+pub(crate) fn sts_credentials_error<E: std::fmt::Debug>(
+    context: &str,
+    err: RusotoError<E>,
+) -> CredentialsError {
+    let kind = match err {
+        RusotoError::Credentials(ref err) => err.kind(),
+        RusotoError::HttpDispatch(_) => CredentialsErrorKind::Network,
+        RusotoError::ParseError(_) => CredentialsErrorKind::Parse,
+        RusotoError::Unknown(ref response) if matches!(response.status.as_u16(), 401 | 403) => {
+            CredentialsErrorKind::Denied
+        }
+        _ => CredentialsErrorKind::Other,
+    };
+    CredentialsError::with_kind(kind, format!("{}: {:?}", context, err))
+}
+
+fn no_credentials_in_response() -> CredentialsError {
+    CredentialsError::with_kind(CredentialsErrorKind::Parse, "no credentials in response")
+}
+
 // Trait that defines the STS Client API without any type parameters or assumptions about implementation.
 // This is an internal type used to box the [StsClient](struct.StsClient.html) provided in the session token providers' constructors.
 #[async_trait]
@@ -210,12 +234,11 @@ impl StsSessionCredentialsProvider {
 #[async_trait]
 impl ProvideAwsCredentials for StsSessionCredentialsProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        let resp = self.get_session_token().await.map_err(|err| {
-            CredentialsError::new(format!("StsProvider get_session_token error: {:?}", err))
-        })?;
-        let creds = resp
-            .credentials
-            .ok_or_else(|| CredentialsError::new("no credentials in response"))?;
+        let resp = self
+            .get_session_token()
+            .await
+            .map_err(|err| sts_credentials_error("StsProvider get_session_token error", err))?;
+        let creds = resp.credentials.ok_or_else(no_credentials_in_response)?;
 
         AwsCredentials::new_for_credentials(creds)
     }
@@ -298,9 +321,7 @@ impl StsAssumeRoleSessionCredentialsProvider {
         };
         let resp = self.sts_client.assume_role(request).await?;
 
-        let creds = resp
-            .credentials
-            .ok_or(CredentialsError::new("no credentials in response"))?;
+        let creds = resp.credentials.ok_or_else(no_credentials_in_response)?;
 
         Ok(AwsCredentials::new_for_credentials(creds)?)
     }
@@ -309,9 +330,71 @@ impl StsAssumeRoleSessionCredentialsProvider {
 #[async_trait]
 impl ProvideAwsCredentials for StsAssumeRoleSessionCredentialsProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        self.assume_role().await.map_err(|err| {
-            CredentialsError::new(format!("StsProvider get_session_token error: {:?}", err))
-        })
+        self.assume_role()
+            .await
+            .map_err(|err| sts_credentials_error("StsProvider get_session_token error", err))
+    }
+}
+
+/// [AwsCredentials](../rusoto_credential/struct.AwsCredentials.html) provider that calls
+/// `GetFederationToken` using the provided [StsClient](struct.StsClient.html).
+/// The returned credentials are limited to the intersection of the caller's permissions
+/// and the given policy, which makes them suitable for e.g. presigning S3 URLs that
+/// must only grant access to a single bucket or prefix.
+pub struct StsFederationTokenCredentialsProvider {
+    sts_client: Box<dyn StsSessionCredentialsClient + Send + Sync>,
+    name: String,
+    session_duration: Duration,
+    scope_down_policy: Option<String>,
+}
+
+impl StsFederationTokenCredentialsProvider {
+    /// Creates a new `StsFederationTokenCredentialsProvider` with the given
+    /// [StsClient](struct.StsClient.html) and session parameters.
+    ///
+    /// * `sts_client` - The [StsClient](struct.StsClient.html) to use to acquire federation tokens.
+    /// * `name` - The name of the federated user. Minimum length of 2. Maximum length of 32. Pattern: `[\w+=,.@-]*`
+    /// * `session_duration` - Duration of session tokens. Default 1 hour.
+    /// * `scope_down_policy` - Optional inline IAM policy in JSON format to restrict the access granted to the federated user.
+    pub fn new(
+        sts_client: StsClient,
+        name: String,
+        session_duration: Option<Duration>,
+        scope_down_policy: Option<String>,
+    ) -> StsFederationTokenCredentialsProvider {
+        StsFederationTokenCredentialsProvider {
+            sts_client: Box::new(sts_client),
+            name,
+            session_duration: session_duration
+                .unwrap_or(Duration::seconds(DEFAULT_DURATION_SECONDS as i64)),
+            scope_down_policy,
+        }
+    }
+
+    /// Calls `GetFederationToken` to get scoped-down credentials from the STS Api.
+    pub async fn get_federation_token(
+        &self,
+    ) -> Result<AwsCredentials, RusotoError<GetFederationTokenError>> {
+        let request = GetFederationTokenRequest {
+            name: self.name.clone(),
+            duration_seconds: Some(self.session_duration.num_seconds() as i64),
+            policy: self.scope_down_policy.clone(),
+            ..Default::default()
+        };
+        let resp = self.sts_client.get_federation_token(request).await?;
+
+        let creds = resp.credentials.ok_or_else(no_credentials_in_response)?;
+
+        Ok(AwsCredentials::new_for_credentials(creds)?)
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for StsFederationTokenCredentialsProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        self.get_federation_token()
+            .await
+            .map_err(|err| sts_credentials_error("StsProvider get_federation_token error", err))
     }
 }
 
@@ -378,9 +461,7 @@ impl StsWebIdentityFederationSessionCredentialsProvider {
             .assume_role_with_web_identity(request)
             .await?;
 
-        let creds = resp
-            .credentials
-            .ok_or(CredentialsError::new("no credentials in response"))?;
+        let creds = resp.credentials.ok_or_else(no_credentials_in_response)?;
 
         let mut aws_creds = AwsCredentials::new_for_credentials(creds)?;
 
@@ -415,4 +496,70 @@ fn sts_futures_are_send() {
     is_send::<StsSessionCredentialsProvider>();
     is_send::<StsAssumeRoleSessionCredentialsProvider>();
     is_send::<StsWebIdentityFederationSessionCredentialsProvider>();
+    is_send::<StsFederationTokenCredentialsProvider>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::Region;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+
+    #[tokio::test]
+    async fn federation_token_provider_returns_scoped_credentials() {
+        let mock = MockRequestDispatcher::with_status(200).with_body(
+            r#"<GetFederationTokenResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+                  <GetFederationTokenResult>
+                    <Credentials>
+                      <SessionToken>session_token</SessionToken>
+                      <SecretAccessKey>secret_access_key</SecretAccessKey>
+                      <Expiration>2021-01-01T00:00:00Z</Expiration>
+                      <AccessKeyId>access_key_id</AccessKeyId>
+                    </Credentials>
+                  </GetFederationTokenResult>
+                  <ResponseMetadata>
+                    <RequestId>58c5dbae-abef-11e0-8cfe-09039844ac7d</RequestId>
+                  </ResponseMetadata>
+                </GetFederationTokenResponse>"#,
+        );
+        let sts_client = StsClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let provider = StsFederationTokenCredentialsProvider::new(
+            sts_client,
+            "uploader".to_owned(),
+            None,
+            Some("{}".to_owned()),
+        );
+
+        let creds = provider.credentials().await.unwrap();
+        assert_eq!(creds.aws_access_key_id(), "access_key_id");
+        assert_eq!(creds.aws_secret_access_key(), "secret_access_key");
+        assert_eq!(creds.token(), &Some("session_token".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn access_denied_is_a_denied_credentials_error() {
+        let mock = MockRequestDispatcher::with_status(403).with_body(
+            r#"<ErrorResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+                  <Error>
+                    <Type>Sender</Type>
+                    <Code>AccessDenied</Code>
+                    <Message>Not authorized to perform sts:AssumeRole</Message>
+                  </Error>
+                  <RequestId>58c5dbae-abef-11e0-8cfe-09039844ac7d</RequestId>
+                </ErrorResponse>"#,
+        );
+        let sts_client = StsClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let provider = StsAssumeRoleSessionCredentialsProvider::new(
+            sts_client,
+            "arn:aws:iam::123456789012:role/role".to_owned(),
+            "session".to_owned(),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let err = provider.credentials().await.unwrap_err();
+        assert_eq!(err.kind(), CredentialsErrorKind::Denied, "{}", err);
+    }
 }
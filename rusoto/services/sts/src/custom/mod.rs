@@ -1,7 +1,8 @@
 mod credential;
 
 pub use self::credential::{
-    NewAwsCredsForStsCreds, StsAssumeRoleSessionCredentialsProvider, StsSessionCredentialsProvider,
+    NewAwsCredsForStsCreds, StsAssumeRoleSessionCredentialsProvider,
+    StsFederationTokenCredentialsProvider, StsSessionCredentialsProvider,
     StsWebIdentityFederationSessionCredentialsProvider,
 };
 
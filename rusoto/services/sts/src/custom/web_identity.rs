@@ -1,7 +1,7 @@
-use crate::custom::credential::NewAwsCredsForStsCreds;
+use crate::custom::credential::{sts_credentials_error, NewAwsCredsForStsCreds};
 use crate::{AssumeRoleWithWebIdentityRequest, Sts, StsClient, PolicyDescriptorType};
 use rusoto_core::credential::{
-    AwsCredentials, CredentialsError, ProvideAwsCredentials, Secret, Variable,
+    AwsCredentials, CredentialsError, CredentialsErrorKind, ProvideAwsCredentials, Secret, Variable,
 };
 use rusoto_core::request::HttpClient;
 use rusoto_core::{Client, Region};
@@ -131,12 +131,18 @@ impl ProvideAwsCredentials for WebIdentityProvider {
 
         let assume_role = sts.assume_role_with_web_identity(req).await;
         match assume_role {
-            Err(e) => Err(CredentialsError::new(e)),
+            Err(e) => Err(sts_credentials_error(
+                "WebIdentityProvider assume_role_with_web_identity error",
+                e,
+            )),
             Ok(role) => match role.credentials {
-                None => Err(CredentialsError::new(format!(
-                    "No credentials found in AssumeRoleWithWebIdentityResponse: {:?}",
-                    role
-                ))),
+                None => Err(CredentialsError::with_kind(
+                    CredentialsErrorKind::Parse,
+                    format!(
+                        "No credentials found in AssumeRoleWithWebIdentityResponse: {:?}",
+                        role
+                    ),
+                )),
                 Some(c) => AwsCredentials::new_for_credentials(c),
             },
         }
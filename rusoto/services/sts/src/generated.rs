@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -1143,6 +1143,16 @@ impl fmt::Display for AssumeRoleError {
     }
 }
 impl Error for AssumeRoleError {}
+impl ServiceErrorCode for AssumeRoleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssumeRoleError::ExpiredToken(_) => "ExpiredTokenException",
+            AssumeRoleError::MalformedPolicyDocument(_) => "MalformedPolicyDocument",
+            AssumeRoleError::PackedPolicyTooLarge(_) => "PackedPolicyTooLarge",
+            AssumeRoleError::RegionDisabled(_) => "RegionDisabledException",
+        }
+    }
+}
 /// Errors returned by AssumeRoleWithSAML
 #[derive(Debug, PartialEq)]
 pub enum AssumeRoleWithSAMLError {
@@ -1227,6 +1237,18 @@ impl fmt::Display for AssumeRoleWithSAMLError {
     }
 }
 impl Error for AssumeRoleWithSAMLError {}
+impl ServiceErrorCode for AssumeRoleWithSAMLError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssumeRoleWithSAMLError::ExpiredToken(_) => "ExpiredTokenException",
+            AssumeRoleWithSAMLError::IDPRejectedClaim(_) => "IDPRejectedClaim",
+            AssumeRoleWithSAMLError::InvalidIdentityToken(_) => "InvalidIdentityToken",
+            AssumeRoleWithSAMLError::MalformedPolicyDocument(_) => "MalformedPolicyDocument",
+            AssumeRoleWithSAMLError::PackedPolicyTooLarge(_) => "PackedPolicyTooLarge",
+            AssumeRoleWithSAMLError::RegionDisabled(_) => "RegionDisabledException",
+        }
+    }
+}
 /// Errors returned by AssumeRoleWithWebIdentity
 #[derive(Debug, PartialEq)]
 pub enum AssumeRoleWithWebIdentityError {
@@ -1335,6 +1357,19 @@ impl fmt::Display for AssumeRoleWithWebIdentityError {
     }
 }
 impl Error for AssumeRoleWithWebIdentityError {}
+impl ServiceErrorCode for AssumeRoleWithWebIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssumeRoleWithWebIdentityError::ExpiredToken(_) => "ExpiredTokenException",
+            AssumeRoleWithWebIdentityError::IDPCommunicationError(_) => "IDPCommunicationError",
+            AssumeRoleWithWebIdentityError::IDPRejectedClaim(_) => "IDPRejectedClaim",
+            AssumeRoleWithWebIdentityError::InvalidIdentityToken(_) => "InvalidIdentityToken",
+            AssumeRoleWithWebIdentityError::MalformedPolicyDocument(_) => "MalformedPolicyDocument",
+            AssumeRoleWithWebIdentityError::PackedPolicyTooLarge(_) => "PackedPolicyTooLarge",
+            AssumeRoleWithWebIdentityError::RegionDisabled(_) => "RegionDisabledException",
+        }
+    }
+}
 /// Errors returned by DecodeAuthorizationMessage
 #[derive(Debug, PartialEq)]
 pub enum DecodeAuthorizationMessageError {
@@ -1385,6 +1420,15 @@ impl fmt::Display for DecodeAuthorizationMessageError {
     }
 }
 impl Error for DecodeAuthorizationMessageError {}
+impl ServiceErrorCode for DecodeAuthorizationMessageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DecodeAuthorizationMessageError::InvalidAuthorizationMessage(_) => {
+                "InvalidAuthorizationMessageException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAccessKeyInfo
 #[derive(Debug, PartialEq)]
 pub enum GetAccessKeyInfoError {}
@@ -1419,6 +1463,11 @@ impl fmt::Display for GetAccessKeyInfoError {
     }
 }
 impl Error for GetAccessKeyInfoError {}
+impl ServiceErrorCode for GetAccessKeyInfoError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetCallerIdentity
 #[derive(Debug, PartialEq)]
 pub enum GetCallerIdentityError {}
@@ -1453,6 +1502,11 @@ impl fmt::Display for GetCallerIdentityError {
     }
 }
 impl Error for GetCallerIdentityError {}
+impl ServiceErrorCode for GetCallerIdentityError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by GetFederationToken
 #[derive(Debug, PartialEq)]
 pub enum GetFederationTokenError {
@@ -1513,6 +1567,15 @@ impl fmt::Display for GetFederationTokenError {
     }
 }
 impl Error for GetFederationTokenError {}
+impl ServiceErrorCode for GetFederationTokenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFederationTokenError::MalformedPolicyDocument(_) => "MalformedPolicyDocument",
+            GetFederationTokenError::PackedPolicyTooLarge(_) => "PackedPolicyTooLarge",
+            GetFederationTokenError::RegionDisabled(_) => "RegionDisabledException",
+        }
+    }
+}
 /// Errors returned by GetSessionToken
 #[derive(Debug, PartialEq)]
 pub enum GetSessionTokenError {
@@ -1557,6 +1620,13 @@ impl fmt::Display for GetSessionTokenError {
     }
 }
 impl Error for GetSessionTokenError {}
+impl ServiceErrorCode for GetSessionTokenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSessionTokenError::RegionDisabled(_) => "RegionDisabledException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS STS API. AWS STS clients implement this trait.
 #[async_trait]
 pub trait Sts {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -807,6 +807,19 @@ impl fmt::Display for AddAttachmentsToSetError {
     }
 }
 impl Error for AddAttachmentsToSetError {}
+impl ServiceErrorCode for AddAttachmentsToSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddAttachmentsToSetError::AttachmentLimitExceeded(_) => "AttachmentLimitExceeded",
+            AddAttachmentsToSetError::AttachmentSetExpired(_) => "AttachmentSetExpired",
+            AddAttachmentsToSetError::AttachmentSetIdNotFound(_) => "AttachmentSetIdNotFound",
+            AddAttachmentsToSetError::AttachmentSetSizeLimitExceeded(_) => {
+                "AttachmentSetSizeLimitExceeded"
+            }
+            AddAttachmentsToSetError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by AddCommunicationToCase
 #[derive(Debug, PartialEq)]
 pub enum AddCommunicationToCaseError {
@@ -865,6 +878,16 @@ impl fmt::Display for AddCommunicationToCaseError {
     }
 }
 impl Error for AddCommunicationToCaseError {}
+impl ServiceErrorCode for AddCommunicationToCaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddCommunicationToCaseError::AttachmentSetExpired(_) => "AttachmentSetExpired",
+            AddCommunicationToCaseError::AttachmentSetIdNotFound(_) => "AttachmentSetIdNotFound",
+            AddCommunicationToCaseError::CaseIdNotFound(_) => "CaseIdNotFound",
+            AddCommunicationToCaseError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by CreateCase
 #[derive(Debug, PartialEq)]
 pub enum CreateCaseError {
@@ -915,6 +938,16 @@ impl fmt::Display for CreateCaseError {
     }
 }
 impl Error for CreateCaseError {}
+impl ServiceErrorCode for CreateCaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCaseError::AttachmentSetExpired(_) => "AttachmentSetExpired",
+            CreateCaseError::AttachmentSetIdNotFound(_) => "AttachmentSetIdNotFound",
+            CreateCaseError::CaseCreationLimitExceeded(_) => "CaseCreationLimitExceeded",
+            CreateCaseError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeAttachment
 #[derive(Debug, PartialEq)]
 pub enum DescribeAttachmentError {
@@ -965,6 +998,17 @@ impl fmt::Display for DescribeAttachmentError {
     }
 }
 impl Error for DescribeAttachmentError {}
+impl ServiceErrorCode for DescribeAttachmentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAttachmentError::AttachmentIdNotFound(_) => "AttachmentIdNotFound",
+            DescribeAttachmentError::DescribeAttachmentLimitExceeded(_) => {
+                "DescribeAttachmentLimitExceeded"
+            }
+            DescribeAttachmentError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeCases
 #[derive(Debug, PartialEq)]
 pub enum DescribeCasesError {
@@ -1001,6 +1045,14 @@ impl fmt::Display for DescribeCasesError {
     }
 }
 impl Error for DescribeCasesError {}
+impl ServiceErrorCode for DescribeCasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCasesError::CaseIdNotFound(_) => "CaseIdNotFound",
+            DescribeCasesError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeCommunications
 #[derive(Debug, PartialEq)]
 pub enum DescribeCommunicationsError {
@@ -1041,6 +1093,14 @@ impl fmt::Display for DescribeCommunicationsError {
     }
 }
 impl Error for DescribeCommunicationsError {}
+impl ServiceErrorCode for DescribeCommunicationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCommunicationsError::CaseIdNotFound(_) => "CaseIdNotFound",
+            DescribeCommunicationsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeServices
 #[derive(Debug, PartialEq)]
 pub enum DescribeServicesError {
@@ -1073,6 +1133,13 @@ impl fmt::Display for DescribeServicesError {
     }
 }
 impl Error for DescribeServicesError {}
+impl ServiceErrorCode for DescribeServicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeServicesError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeSeverityLevels
 #[derive(Debug, PartialEq)]
 pub enum DescribeSeverityLevelsError {
@@ -1105,6 +1172,13 @@ impl fmt::Display for DescribeSeverityLevelsError {
     }
 }
 impl Error for DescribeSeverityLevelsError {}
+impl ServiceErrorCode for DescribeSeverityLevelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSeverityLevelsError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeTrustedAdvisorCheckRefreshStatuses
 #[derive(Debug, PartialEq)]
 pub enum DescribeTrustedAdvisorCheckRefreshStatusesError {
@@ -1143,6 +1217,15 @@ impl fmt::Display for DescribeTrustedAdvisorCheckRefreshStatusesError {
     }
 }
 impl Error for DescribeTrustedAdvisorCheckRefreshStatusesError {}
+impl ServiceErrorCode for DescribeTrustedAdvisorCheckRefreshStatusesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTrustedAdvisorCheckRefreshStatusesError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeTrustedAdvisorCheckResult
 #[derive(Debug, PartialEq)]
 pub enum DescribeTrustedAdvisorCheckResultError {
@@ -1179,6 +1262,13 @@ impl fmt::Display for DescribeTrustedAdvisorCheckResultError {
     }
 }
 impl Error for DescribeTrustedAdvisorCheckResultError {}
+impl ServiceErrorCode for DescribeTrustedAdvisorCheckResultError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTrustedAdvisorCheckResultError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by DescribeTrustedAdvisorCheckSummaries
 #[derive(Debug, PartialEq)]
 pub enum DescribeTrustedAdvisorCheckSummariesError {
@@ -1215,6 +1305,15 @@ impl fmt::Display for DescribeTrustedAdvisorCheckSummariesError {
     }
 }
 impl Error for DescribeTrustedAdvisorCheckSummariesError {}
+impl ServiceErrorCode for DescribeTrustedAdvisorCheckSummariesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTrustedAdvisorCheckSummariesError::InternalServerError(_) => {
+                "InternalServerError"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeTrustedAdvisorChecks
 #[derive(Debug, PartialEq)]
 pub enum DescribeTrustedAdvisorChecksError {
@@ -1251,6 +1350,13 @@ impl fmt::Display for DescribeTrustedAdvisorChecksError {
     }
 }
 impl Error for DescribeTrustedAdvisorChecksError {}
+impl ServiceErrorCode for DescribeTrustedAdvisorChecksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTrustedAdvisorChecksError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by RefreshTrustedAdvisorCheck
 #[derive(Debug, PartialEq)]
 pub enum RefreshTrustedAdvisorCheckError {
@@ -1287,6 +1393,13 @@ impl fmt::Display for RefreshTrustedAdvisorCheckError {
     }
 }
 impl Error for RefreshTrustedAdvisorCheckError {}
+impl ServiceErrorCode for RefreshTrustedAdvisorCheckError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RefreshTrustedAdvisorCheckError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Errors returned by ResolveCase
 #[derive(Debug, PartialEq)]
 pub enum ResolveCaseError {
@@ -1323,6 +1436,14 @@ impl fmt::Display for ResolveCaseError {
     }
 }
 impl Error for ResolveCaseError {}
+impl ServiceErrorCode for ResolveCaseError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResolveCaseError::CaseIdNotFound(_) => "CaseIdNotFound",
+            ResolveCaseError::InternalServerError(_) => "InternalServerError",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Support API. AWS Support clients implement this trait.
 #[async_trait]
 pub trait AWSSupport {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3026,6 +3026,16 @@ impl fmt::Display for CountClosedWorkflowExecutionsError {
     }
 }
 impl Error for CountClosedWorkflowExecutionsError {}
+impl ServiceErrorCode for CountClosedWorkflowExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CountClosedWorkflowExecutionsError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            CountClosedWorkflowExecutionsError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by CountOpenWorkflowExecutions
 #[derive(Debug, PartialEq)]
 pub enum CountOpenWorkflowExecutionsError {
@@ -3072,6 +3082,16 @@ impl fmt::Display for CountOpenWorkflowExecutionsError {
     }
 }
 impl Error for CountOpenWorkflowExecutionsError {}
+impl ServiceErrorCode for CountOpenWorkflowExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CountOpenWorkflowExecutionsError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            CountOpenWorkflowExecutionsError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by CountPendingActivityTasks
 #[derive(Debug, PartialEq)]
 pub enum CountPendingActivityTasksError {
@@ -3116,6 +3136,16 @@ impl fmt::Display for CountPendingActivityTasksError {
     }
 }
 impl Error for CountPendingActivityTasksError {}
+impl ServiceErrorCode for CountPendingActivityTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CountPendingActivityTasksError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            CountPendingActivityTasksError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by CountPendingDecisionTasks
 #[derive(Debug, PartialEq)]
 pub enum CountPendingDecisionTasksError {
@@ -3160,6 +3190,16 @@ impl fmt::Display for CountPendingDecisionTasksError {
     }
 }
 impl Error for CountPendingDecisionTasksError {}
+impl ServiceErrorCode for CountPendingDecisionTasksError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CountPendingDecisionTasksError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            CountPendingDecisionTasksError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by DeprecateActivityType
 #[derive(Debug, PartialEq)]
 pub enum DeprecateActivityTypeError {
@@ -3210,6 +3250,17 @@ impl fmt::Display for DeprecateActivityTypeError {
     }
 }
 impl Error for DeprecateActivityTypeError {}
+impl ServiceErrorCode for DeprecateActivityTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeprecateActivityTypeError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            DeprecateActivityTypeError::TypeDeprecatedFault(_) => "TypeDeprecatedFault",
+            DeprecateActivityTypeError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by DeprecateDomain
 #[derive(Debug, PartialEq)]
 pub enum DeprecateDomainError {
@@ -3258,6 +3309,15 @@ impl fmt::Display for DeprecateDomainError {
     }
 }
 impl Error for DeprecateDomainError {}
+impl ServiceErrorCode for DeprecateDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeprecateDomainError::DomainDeprecatedFault(_) => "DomainDeprecatedFault",
+            DeprecateDomainError::OperationNotPermittedFault(_) => "OperationNotPermittedFault",
+            DeprecateDomainError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by DeprecateWorkflowType
 #[derive(Debug, PartialEq)]
 pub enum DeprecateWorkflowTypeError {
@@ -3308,6 +3368,17 @@ impl fmt::Display for DeprecateWorkflowTypeError {
     }
 }
 impl Error for DeprecateWorkflowTypeError {}
+impl ServiceErrorCode for DeprecateWorkflowTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeprecateWorkflowTypeError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            DeprecateWorkflowTypeError::TypeDeprecatedFault(_) => "TypeDeprecatedFault",
+            DeprecateWorkflowTypeError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by DescribeActivityType
 #[derive(Debug, PartialEq)]
 pub enum DescribeActivityTypeError {
@@ -3350,6 +3421,16 @@ impl fmt::Display for DescribeActivityTypeError {
     }
 }
 impl Error for DescribeActivityTypeError {}
+impl ServiceErrorCode for DescribeActivityTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeActivityTypeError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            DescribeActivityTypeError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by DescribeDomain
 #[derive(Debug, PartialEq)]
 pub enum DescribeDomainError {
@@ -3388,6 +3469,14 @@ impl fmt::Display for DescribeDomainError {
     }
 }
 impl Error for DescribeDomainError {}
+impl ServiceErrorCode for DescribeDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDomainError::OperationNotPermittedFault(_) => "OperationNotPermittedFault",
+            DescribeDomainError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by DescribeWorkflowExecution
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorkflowExecutionError {
@@ -3432,6 +3521,16 @@ impl fmt::Display for DescribeWorkflowExecutionError {
     }
 }
 impl Error for DescribeWorkflowExecutionError {}
+impl ServiceErrorCode for DescribeWorkflowExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeWorkflowExecutionError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            DescribeWorkflowExecutionError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by DescribeWorkflowType
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorkflowTypeError {
@@ -3474,6 +3573,16 @@ impl fmt::Display for DescribeWorkflowTypeError {
     }
 }
 impl Error for DescribeWorkflowTypeError {}
+impl ServiceErrorCode for DescribeWorkflowTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeWorkflowTypeError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            DescribeWorkflowTypeError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by GetWorkflowExecutionHistory
 #[derive(Debug, PartialEq)]
 pub enum GetWorkflowExecutionHistoryError {
@@ -3520,6 +3629,16 @@ impl fmt::Display for GetWorkflowExecutionHistoryError {
     }
 }
 impl Error for GetWorkflowExecutionHistoryError {}
+impl ServiceErrorCode for GetWorkflowExecutionHistoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetWorkflowExecutionHistoryError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            GetWorkflowExecutionHistoryError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by ListActivityTypes
 #[derive(Debug, PartialEq)]
 pub enum ListActivityTypesError {
@@ -3560,6 +3679,14 @@ impl fmt::Display for ListActivityTypesError {
     }
 }
 impl Error for ListActivityTypesError {}
+impl ServiceErrorCode for ListActivityTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListActivityTypesError::OperationNotPermittedFault(_) => "OperationNotPermittedFault",
+            ListActivityTypesError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by ListClosedWorkflowExecutions
 #[derive(Debug, PartialEq)]
 pub enum ListClosedWorkflowExecutionsError {
@@ -3606,6 +3733,16 @@ impl fmt::Display for ListClosedWorkflowExecutionsError {
     }
 }
 impl Error for ListClosedWorkflowExecutionsError {}
+impl ServiceErrorCode for ListClosedWorkflowExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListClosedWorkflowExecutionsError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            ListClosedWorkflowExecutionsError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by ListDomains
 #[derive(Debug, PartialEq)]
 pub enum ListDomainsError {
@@ -3638,6 +3775,13 @@ impl fmt::Display for ListDomainsError {
     }
 }
 impl Error for ListDomainsError {}
+impl ServiceErrorCode for ListDomainsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDomainsError::OperationNotPermittedFault(_) => "OperationNotPermittedFault",
+        }
+    }
+}
 /// Errors returned by ListOpenWorkflowExecutions
 #[derive(Debug, PartialEq)]
 pub enum ListOpenWorkflowExecutionsError {
@@ -3684,6 +3828,16 @@ impl fmt::Display for ListOpenWorkflowExecutionsError {
     }
 }
 impl Error for ListOpenWorkflowExecutionsError {}
+impl ServiceErrorCode for ListOpenWorkflowExecutionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOpenWorkflowExecutionsError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            ListOpenWorkflowExecutionsError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3734,6 +3888,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::LimitExceededFault(_) => "LimitExceededFault",
+            ListTagsForResourceError::OperationNotPermittedFault(_) => "OperationNotPermittedFault",
+            ListTagsForResourceError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by ListWorkflowTypes
 #[derive(Debug, PartialEq)]
 pub enum ListWorkflowTypesError {
@@ -3774,6 +3937,14 @@ impl fmt::Display for ListWorkflowTypesError {
     }
 }
 impl Error for ListWorkflowTypesError {}
+impl ServiceErrorCode for ListWorkflowTypesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListWorkflowTypesError::OperationNotPermittedFault(_) => "OperationNotPermittedFault",
+            ListWorkflowTypesError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by PollForActivityTask
 #[derive(Debug, PartialEq)]
 pub enum PollForActivityTaskError {
@@ -3824,6 +3995,15 @@ impl fmt::Display for PollForActivityTaskError {
     }
 }
 impl Error for PollForActivityTaskError {}
+impl ServiceErrorCode for PollForActivityTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PollForActivityTaskError::LimitExceededFault(_) => "LimitExceededFault",
+            PollForActivityTaskError::OperationNotPermittedFault(_) => "OperationNotPermittedFault",
+            PollForActivityTaskError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by PollForDecisionTask
 #[derive(Debug, PartialEq)]
 pub enum PollForDecisionTaskError {
@@ -3874,6 +4054,15 @@ impl fmt::Display for PollForDecisionTaskError {
     }
 }
 impl Error for PollForDecisionTaskError {}
+impl ServiceErrorCode for PollForDecisionTaskError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PollForDecisionTaskError::LimitExceededFault(_) => "LimitExceededFault",
+            PollForDecisionTaskError::OperationNotPermittedFault(_) => "OperationNotPermittedFault",
+            PollForDecisionTaskError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by RecordActivityTaskHeartbeat
 #[derive(Debug, PartialEq)]
 pub enum RecordActivityTaskHeartbeatError {
@@ -3920,6 +4109,16 @@ impl fmt::Display for RecordActivityTaskHeartbeatError {
     }
 }
 impl Error for RecordActivityTaskHeartbeatError {}
+impl ServiceErrorCode for RecordActivityTaskHeartbeatError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RecordActivityTaskHeartbeatError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            RecordActivityTaskHeartbeatError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by RegisterActivityType
 #[derive(Debug, PartialEq)]
 pub enum RegisterActivityTypeError {
@@ -3978,6 +4177,18 @@ impl fmt::Display for RegisterActivityTypeError {
     }
 }
 impl Error for RegisterActivityTypeError {}
+impl ServiceErrorCode for RegisterActivityTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterActivityTypeError::LimitExceededFault(_) => "LimitExceededFault",
+            RegisterActivityTypeError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            RegisterActivityTypeError::TypeAlreadyExistsFault(_) => "TypeAlreadyExistsFault",
+            RegisterActivityTypeError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by RegisterDomain
 #[derive(Debug, PartialEq)]
 pub enum RegisterDomainError {
@@ -4030,6 +4241,16 @@ impl fmt::Display for RegisterDomainError {
     }
 }
 impl Error for RegisterDomainError {}
+impl ServiceErrorCode for RegisterDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterDomainError::DomainAlreadyExistsFault(_) => "DomainAlreadyExistsFault",
+            RegisterDomainError::LimitExceededFault(_) => "LimitExceededFault",
+            RegisterDomainError::OperationNotPermittedFault(_) => "OperationNotPermittedFault",
+            RegisterDomainError::TooManyTagsFault(_) => "TooManyTagsFault",
+        }
+    }
+}
 /// Errors returned by RegisterWorkflowType
 #[derive(Debug, PartialEq)]
 pub enum RegisterWorkflowTypeError {
@@ -4088,6 +4309,18 @@ impl fmt::Display for RegisterWorkflowTypeError {
     }
 }
 impl Error for RegisterWorkflowTypeError {}
+impl ServiceErrorCode for RegisterWorkflowTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterWorkflowTypeError::LimitExceededFault(_) => "LimitExceededFault",
+            RegisterWorkflowTypeError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            RegisterWorkflowTypeError::TypeAlreadyExistsFault(_) => "TypeAlreadyExistsFault",
+            RegisterWorkflowTypeError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by RequestCancelWorkflowExecution
 #[derive(Debug, PartialEq)]
 pub enum RequestCancelWorkflowExecutionError {
@@ -4134,6 +4367,16 @@ impl fmt::Display for RequestCancelWorkflowExecutionError {
     }
 }
 impl Error for RequestCancelWorkflowExecutionError {}
+impl ServiceErrorCode for RequestCancelWorkflowExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RequestCancelWorkflowExecutionError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            RequestCancelWorkflowExecutionError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by RespondActivityTaskCanceled
 #[derive(Debug, PartialEq)]
 pub enum RespondActivityTaskCanceledError {
@@ -4180,6 +4423,16 @@ impl fmt::Display for RespondActivityTaskCanceledError {
     }
 }
 impl Error for RespondActivityTaskCanceledError {}
+impl ServiceErrorCode for RespondActivityTaskCanceledError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RespondActivityTaskCanceledError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            RespondActivityTaskCanceledError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by RespondActivityTaskCompleted
 #[derive(Debug, PartialEq)]
 pub enum RespondActivityTaskCompletedError {
@@ -4226,6 +4479,16 @@ impl fmt::Display for RespondActivityTaskCompletedError {
     }
 }
 impl Error for RespondActivityTaskCompletedError {}
+impl ServiceErrorCode for RespondActivityTaskCompletedError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RespondActivityTaskCompletedError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            RespondActivityTaskCompletedError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by RespondActivityTaskFailed
 #[derive(Debug, PartialEq)]
 pub enum RespondActivityTaskFailedError {
@@ -4270,6 +4533,16 @@ impl fmt::Display for RespondActivityTaskFailedError {
     }
 }
 impl Error for RespondActivityTaskFailedError {}
+impl ServiceErrorCode for RespondActivityTaskFailedError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RespondActivityTaskFailedError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            RespondActivityTaskFailedError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by RespondDecisionTaskCompleted
 #[derive(Debug, PartialEq)]
 pub enum RespondDecisionTaskCompletedError {
@@ -4316,6 +4589,16 @@ impl fmt::Display for RespondDecisionTaskCompletedError {
     }
 }
 impl Error for RespondDecisionTaskCompletedError {}
+impl ServiceErrorCode for RespondDecisionTaskCompletedError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RespondDecisionTaskCompletedError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            RespondDecisionTaskCompletedError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by SignalWorkflowExecution
 #[derive(Debug, PartialEq)]
 pub enum SignalWorkflowExecutionError {
@@ -4358,6 +4641,16 @@ impl fmt::Display for SignalWorkflowExecutionError {
     }
 }
 impl Error for SignalWorkflowExecutionError {}
+impl ServiceErrorCode for SignalWorkflowExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SignalWorkflowExecutionError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            SignalWorkflowExecutionError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by StartWorkflowExecution
 #[derive(Debug, PartialEq)]
 pub enum StartWorkflowExecutionError {
@@ -4434,6 +4727,22 @@ impl fmt::Display for StartWorkflowExecutionError {
     }
 }
 impl Error for StartWorkflowExecutionError {}
+impl ServiceErrorCode for StartWorkflowExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartWorkflowExecutionError::DefaultUndefinedFault(_) => "DefaultUndefinedFault",
+            StartWorkflowExecutionError::LimitExceededFault(_) => "LimitExceededFault",
+            StartWorkflowExecutionError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            StartWorkflowExecutionError::TypeDeprecatedFault(_) => "TypeDeprecatedFault",
+            StartWorkflowExecutionError::UnknownResourceFault(_) => "UnknownResourceFault",
+            StartWorkflowExecutionError::WorkflowExecutionAlreadyStartedFault(_) => {
+                "WorkflowExecutionAlreadyStartedFault"
+            }
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -4484,6 +4793,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::LimitExceededFault(_) => "LimitExceededFault",
+            TagResourceError::OperationNotPermittedFault(_) => "OperationNotPermittedFault",
+            TagResourceError::TooManyTagsFault(_) => "TooManyTagsFault",
+            TagResourceError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by TerminateWorkflowExecution
 #[derive(Debug, PartialEq)]
 pub enum TerminateWorkflowExecutionError {
@@ -4530,6 +4849,16 @@ impl fmt::Display for TerminateWorkflowExecutionError {
     }
 }
 impl Error for TerminateWorkflowExecutionError {}
+impl ServiceErrorCode for TerminateWorkflowExecutionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TerminateWorkflowExecutionError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            TerminateWorkflowExecutionError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by UndeprecateActivityType
 #[derive(Debug, PartialEq)]
 pub enum UndeprecateActivityTypeError {
@@ -4582,6 +4911,17 @@ impl fmt::Display for UndeprecateActivityTypeError {
     }
 }
 impl Error for UndeprecateActivityTypeError {}
+impl ServiceErrorCode for UndeprecateActivityTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UndeprecateActivityTypeError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            UndeprecateActivityTypeError::TypeAlreadyExistsFault(_) => "TypeAlreadyExistsFault",
+            UndeprecateActivityTypeError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by UndeprecateDomain
 #[derive(Debug, PartialEq)]
 pub enum UndeprecateDomainError {
@@ -4630,6 +4970,15 @@ impl fmt::Display for UndeprecateDomainError {
     }
 }
 impl Error for UndeprecateDomainError {}
+impl ServiceErrorCode for UndeprecateDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UndeprecateDomainError::DomainAlreadyExistsFault(_) => "DomainAlreadyExistsFault",
+            UndeprecateDomainError::OperationNotPermittedFault(_) => "OperationNotPermittedFault",
+            UndeprecateDomainError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by UndeprecateWorkflowType
 #[derive(Debug, PartialEq)]
 pub enum UndeprecateWorkflowTypeError {
@@ -4682,6 +5031,17 @@ impl fmt::Display for UndeprecateWorkflowTypeError {
     }
 }
 impl Error for UndeprecateWorkflowTypeError {}
+impl ServiceErrorCode for UndeprecateWorkflowTypeError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UndeprecateWorkflowTypeError::OperationNotPermittedFault(_) => {
+                "OperationNotPermittedFault"
+            }
+            UndeprecateWorkflowTypeError::TypeAlreadyExistsFault(_) => "TypeAlreadyExistsFault",
+            UndeprecateWorkflowTypeError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -4726,6 +5086,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::LimitExceededFault(_) => "LimitExceededFault",
+            UntagResourceError::OperationNotPermittedFault(_) => "OperationNotPermittedFault",
+            UntagResourceError::UnknownResourceFault(_) => "UnknownResourceFault",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon SWF API. Amazon SWF clients implement this trait.
 #[async_trait]
 pub trait Swf {
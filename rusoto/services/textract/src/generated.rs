@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -642,6 +642,24 @@ impl fmt::Display for AnalyzeDocumentError {
     }
 }
 impl Error for AnalyzeDocumentError {}
+impl ServiceErrorCode for AnalyzeDocumentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AnalyzeDocumentError::AccessDenied(_) => "AccessDeniedException",
+            AnalyzeDocumentError::BadDocument(_) => "BadDocumentException",
+            AnalyzeDocumentError::DocumentTooLarge(_) => "DocumentTooLargeException",
+            AnalyzeDocumentError::HumanLoopQuotaExceeded(_) => "HumanLoopQuotaExceededException",
+            AnalyzeDocumentError::InternalServerError(_) => "InternalServerError",
+            AnalyzeDocumentError::InvalidParameter(_) => "InvalidParameterException",
+            AnalyzeDocumentError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            AnalyzeDocumentError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            AnalyzeDocumentError::Throttling(_) => "ThrottlingException",
+            AnalyzeDocumentError::UnsupportedDocument(_) => "UnsupportedDocumentException",
+        }
+    }
+}
 /// Errors returned by DetectDocumentText
 #[derive(Debug, PartialEq)]
 pub enum DetectDocumentTextError {
@@ -728,6 +746,23 @@ impl fmt::Display for DetectDocumentTextError {
     }
 }
 impl Error for DetectDocumentTextError {}
+impl ServiceErrorCode for DetectDocumentTextError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DetectDocumentTextError::AccessDenied(_) => "AccessDeniedException",
+            DetectDocumentTextError::BadDocument(_) => "BadDocumentException",
+            DetectDocumentTextError::DocumentTooLarge(_) => "DocumentTooLargeException",
+            DetectDocumentTextError::InternalServerError(_) => "InternalServerError",
+            DetectDocumentTextError::InvalidParameter(_) => "InvalidParameterException",
+            DetectDocumentTextError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            DetectDocumentTextError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            DetectDocumentTextError::Throttling(_) => "ThrottlingException",
+            DetectDocumentTextError::UnsupportedDocument(_) => "UnsupportedDocumentException",
+        }
+    }
+}
 /// Errors returned by GetDocumentAnalysis
 #[derive(Debug, PartialEq)]
 pub enum GetDocumentAnalysisError {
@@ -802,6 +837,21 @@ impl fmt::Display for GetDocumentAnalysisError {
     }
 }
 impl Error for GetDocumentAnalysisError {}
+impl ServiceErrorCode for GetDocumentAnalysisError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDocumentAnalysisError::AccessDenied(_) => "AccessDeniedException",
+            GetDocumentAnalysisError::InternalServerError(_) => "InternalServerError",
+            GetDocumentAnalysisError::InvalidJobId(_) => "InvalidJobIdException",
+            GetDocumentAnalysisError::InvalidParameter(_) => "InvalidParameterException",
+            GetDocumentAnalysisError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            GetDocumentAnalysisError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            GetDocumentAnalysisError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by GetDocumentTextDetection
 #[derive(Debug, PartialEq)]
 pub enum GetDocumentTextDetectionError {
@@ -882,6 +932,21 @@ impl fmt::Display for GetDocumentTextDetectionError {
     }
 }
 impl Error for GetDocumentTextDetectionError {}
+impl ServiceErrorCode for GetDocumentTextDetectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDocumentTextDetectionError::AccessDenied(_) => "AccessDeniedException",
+            GetDocumentTextDetectionError::InternalServerError(_) => "InternalServerError",
+            GetDocumentTextDetectionError::InvalidJobId(_) => "InvalidJobIdException",
+            GetDocumentTextDetectionError::InvalidParameter(_) => "InvalidParameterException",
+            GetDocumentTextDetectionError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            GetDocumentTextDetectionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            GetDocumentTextDetectionError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StartDocumentAnalysis
 #[derive(Debug, PartialEq)]
 pub enum StartDocumentAnalysisError {
@@ -996,6 +1061,28 @@ impl fmt::Display for StartDocumentAnalysisError {
     }
 }
 impl Error for StartDocumentAnalysisError {}
+impl ServiceErrorCode for StartDocumentAnalysisError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDocumentAnalysisError::AccessDenied(_) => "AccessDeniedException",
+            StartDocumentAnalysisError::BadDocument(_) => "BadDocumentException",
+            StartDocumentAnalysisError::DocumentTooLarge(_) => "DocumentTooLargeException",
+            StartDocumentAnalysisError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            StartDocumentAnalysisError::InternalServerError(_) => "InternalServerError",
+            StartDocumentAnalysisError::InvalidKMSKey(_) => "InvalidKMSKeyException",
+            StartDocumentAnalysisError::InvalidParameter(_) => "InvalidParameterException",
+            StartDocumentAnalysisError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            StartDocumentAnalysisError::LimitExceeded(_) => "LimitExceededException",
+            StartDocumentAnalysisError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            StartDocumentAnalysisError::Throttling(_) => "ThrottlingException",
+            StartDocumentAnalysisError::UnsupportedDocument(_) => "UnsupportedDocumentException",
+        }
+    }
+}
 /// Errors returned by StartDocumentTextDetection
 #[derive(Debug, PartialEq)]
 pub enum StartDocumentTextDetectionError {
@@ -1126,6 +1213,30 @@ impl fmt::Display for StartDocumentTextDetectionError {
     }
 }
 impl Error for StartDocumentTextDetectionError {}
+impl ServiceErrorCode for StartDocumentTextDetectionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartDocumentTextDetectionError::AccessDenied(_) => "AccessDeniedException",
+            StartDocumentTextDetectionError::BadDocument(_) => "BadDocumentException",
+            StartDocumentTextDetectionError::DocumentTooLarge(_) => "DocumentTooLargeException",
+            StartDocumentTextDetectionError::IdempotentParameterMismatch(_) => {
+                "IdempotentParameterMismatchException"
+            }
+            StartDocumentTextDetectionError::InternalServerError(_) => "InternalServerError",
+            StartDocumentTextDetectionError::InvalidKMSKey(_) => "InvalidKMSKeyException",
+            StartDocumentTextDetectionError::InvalidParameter(_) => "InvalidParameterException",
+            StartDocumentTextDetectionError::InvalidS3Object(_) => "InvalidS3ObjectException",
+            StartDocumentTextDetectionError::LimitExceeded(_) => "LimitExceededException",
+            StartDocumentTextDetectionError::ProvisionedThroughputExceeded(_) => {
+                "ProvisionedThroughputExceededException"
+            }
+            StartDocumentTextDetectionError::Throttling(_) => "ThrottlingException",
+            StartDocumentTextDetectionError::UnsupportedDocument(_) => {
+                "UnsupportedDocumentException"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Textract API. Amazon Textract clients implement this trait.
 #[async_trait]
 pub trait Textract {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1372,6 +1372,16 @@ impl fmt::Display for CreateLanguageModelError {
     }
 }
 impl Error for CreateLanguageModelError {}
+impl ServiceErrorCode for CreateLanguageModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLanguageModelError::BadRequest(_) => "BadRequestException",
+            CreateLanguageModelError::Conflict(_) => "ConflictException",
+            CreateLanguageModelError::InternalFailure(_) => "InternalFailureException",
+            CreateLanguageModelError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateMedicalVocabulary
 #[derive(Debug, PartialEq)]
 pub enum CreateMedicalVocabularyError {
@@ -1424,6 +1434,16 @@ impl fmt::Display for CreateMedicalVocabularyError {
     }
 }
 impl Error for CreateMedicalVocabularyError {}
+impl ServiceErrorCode for CreateMedicalVocabularyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateMedicalVocabularyError::BadRequest(_) => "BadRequestException",
+            CreateMedicalVocabularyError::Conflict(_) => "ConflictException",
+            CreateMedicalVocabularyError::InternalFailure(_) => "InternalFailureException",
+            CreateMedicalVocabularyError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateVocabulary
 #[derive(Debug, PartialEq)]
 pub enum CreateVocabularyError {
@@ -1472,6 +1492,16 @@ impl fmt::Display for CreateVocabularyError {
     }
 }
 impl Error for CreateVocabularyError {}
+impl ServiceErrorCode for CreateVocabularyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVocabularyError::BadRequest(_) => "BadRequestException",
+            CreateVocabularyError::Conflict(_) => "ConflictException",
+            CreateVocabularyError::InternalFailure(_) => "InternalFailureException",
+            CreateVocabularyError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateVocabularyFilter
 #[derive(Debug, PartialEq)]
 pub enum CreateVocabularyFilterError {
@@ -1524,6 +1554,16 @@ impl fmt::Display for CreateVocabularyFilterError {
     }
 }
 impl Error for CreateVocabularyFilterError {}
+impl ServiceErrorCode for CreateVocabularyFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateVocabularyFilterError::BadRequest(_) => "BadRequestException",
+            CreateVocabularyFilterError::Conflict(_) => "ConflictException",
+            CreateVocabularyFilterError::InternalFailure(_) => "InternalFailureException",
+            CreateVocabularyFilterError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DeleteLanguageModel
 #[derive(Debug, PartialEq)]
 pub enum DeleteLanguageModelError {
@@ -1566,6 +1606,15 @@ impl fmt::Display for DeleteLanguageModelError {
     }
 }
 impl Error for DeleteLanguageModelError {}
+impl ServiceErrorCode for DeleteLanguageModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLanguageModelError::BadRequest(_) => "BadRequestException",
+            DeleteLanguageModelError::InternalFailure(_) => "InternalFailureException",
+            DeleteLanguageModelError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DeleteMedicalTranscriptionJob
 #[derive(Debug, PartialEq)]
 pub enum DeleteMedicalTranscriptionJobError {
@@ -1618,6 +1667,15 @@ impl fmt::Display for DeleteMedicalTranscriptionJobError {
     }
 }
 impl Error for DeleteMedicalTranscriptionJobError {}
+impl ServiceErrorCode for DeleteMedicalTranscriptionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMedicalTranscriptionJobError::BadRequest(_) => "BadRequestException",
+            DeleteMedicalTranscriptionJobError::InternalFailure(_) => "InternalFailureException",
+            DeleteMedicalTranscriptionJobError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DeleteMedicalVocabulary
 #[derive(Debug, PartialEq)]
 pub enum DeleteMedicalVocabularyError {
@@ -1670,6 +1728,16 @@ impl fmt::Display for DeleteMedicalVocabularyError {
     }
 }
 impl Error for DeleteMedicalVocabularyError {}
+impl ServiceErrorCode for DeleteMedicalVocabularyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMedicalVocabularyError::BadRequest(_) => "BadRequestException",
+            DeleteMedicalVocabularyError::InternalFailure(_) => "InternalFailureException",
+            DeleteMedicalVocabularyError::LimitExceeded(_) => "LimitExceededException",
+            DeleteMedicalVocabularyError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteTranscriptionJob
 #[derive(Debug, PartialEq)]
 pub enum DeleteTranscriptionJobError {
@@ -1716,6 +1784,15 @@ impl fmt::Display for DeleteTranscriptionJobError {
     }
 }
 impl Error for DeleteTranscriptionJobError {}
+impl ServiceErrorCode for DeleteTranscriptionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTranscriptionJobError::BadRequest(_) => "BadRequestException",
+            DeleteTranscriptionJobError::InternalFailure(_) => "InternalFailureException",
+            DeleteTranscriptionJobError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by DeleteVocabulary
 #[derive(Debug, PartialEq)]
 pub enum DeleteVocabularyError {
@@ -1764,6 +1841,16 @@ impl fmt::Display for DeleteVocabularyError {
     }
 }
 impl Error for DeleteVocabularyError {}
+impl ServiceErrorCode for DeleteVocabularyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVocabularyError::BadRequest(_) => "BadRequestException",
+            DeleteVocabularyError::InternalFailure(_) => "InternalFailureException",
+            DeleteVocabularyError::LimitExceeded(_) => "LimitExceededException",
+            DeleteVocabularyError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteVocabularyFilter
 #[derive(Debug, PartialEq)]
 pub enum DeleteVocabularyFilterError {
@@ -1816,6 +1903,16 @@ impl fmt::Display for DeleteVocabularyFilterError {
     }
 }
 impl Error for DeleteVocabularyFilterError {}
+impl ServiceErrorCode for DeleteVocabularyFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteVocabularyFilterError::BadRequest(_) => "BadRequestException",
+            DeleteVocabularyFilterError::InternalFailure(_) => "InternalFailureException",
+            DeleteVocabularyFilterError::LimitExceeded(_) => "LimitExceededException",
+            DeleteVocabularyFilterError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeLanguageModel
 #[derive(Debug, PartialEq)]
 pub enum DescribeLanguageModelError {
@@ -1866,6 +1963,16 @@ impl fmt::Display for DescribeLanguageModelError {
     }
 }
 impl Error for DescribeLanguageModelError {}
+impl ServiceErrorCode for DescribeLanguageModelError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeLanguageModelError::BadRequest(_) => "BadRequestException",
+            DescribeLanguageModelError::InternalFailure(_) => "InternalFailureException",
+            DescribeLanguageModelError::LimitExceeded(_) => "LimitExceededException",
+            DescribeLanguageModelError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetMedicalTranscriptionJob
 #[derive(Debug, PartialEq)]
 pub enum GetMedicalTranscriptionJobError {
@@ -1922,6 +2029,16 @@ impl fmt::Display for GetMedicalTranscriptionJobError {
     }
 }
 impl Error for GetMedicalTranscriptionJobError {}
+impl ServiceErrorCode for GetMedicalTranscriptionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMedicalTranscriptionJobError::BadRequest(_) => "BadRequestException",
+            GetMedicalTranscriptionJobError::InternalFailure(_) => "InternalFailureException",
+            GetMedicalTranscriptionJobError::LimitExceeded(_) => "LimitExceededException",
+            GetMedicalTranscriptionJobError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetMedicalVocabulary
 #[derive(Debug, PartialEq)]
 pub enum GetMedicalVocabularyError {
@@ -1972,6 +2089,16 @@ impl fmt::Display for GetMedicalVocabularyError {
     }
 }
 impl Error for GetMedicalVocabularyError {}
+impl ServiceErrorCode for GetMedicalVocabularyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMedicalVocabularyError::BadRequest(_) => "BadRequestException",
+            GetMedicalVocabularyError::InternalFailure(_) => "InternalFailureException",
+            GetMedicalVocabularyError::LimitExceeded(_) => "LimitExceededException",
+            GetMedicalVocabularyError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetTranscriptionJob
 #[derive(Debug, PartialEq)]
 pub enum GetTranscriptionJobError {
@@ -2020,6 +2147,16 @@ impl fmt::Display for GetTranscriptionJobError {
     }
 }
 impl Error for GetTranscriptionJobError {}
+impl ServiceErrorCode for GetTranscriptionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTranscriptionJobError::BadRequest(_) => "BadRequestException",
+            GetTranscriptionJobError::InternalFailure(_) => "InternalFailureException",
+            GetTranscriptionJobError::LimitExceeded(_) => "LimitExceededException",
+            GetTranscriptionJobError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetVocabulary
 #[derive(Debug, PartialEq)]
 pub enum GetVocabularyError {
@@ -2068,6 +2205,16 @@ impl fmt::Display for GetVocabularyError {
     }
 }
 impl Error for GetVocabularyError {}
+impl ServiceErrorCode for GetVocabularyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVocabularyError::BadRequest(_) => "BadRequestException",
+            GetVocabularyError::InternalFailure(_) => "InternalFailureException",
+            GetVocabularyError::LimitExceeded(_) => "LimitExceededException",
+            GetVocabularyError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by GetVocabularyFilter
 #[derive(Debug, PartialEq)]
 pub enum GetVocabularyFilterError {
@@ -2116,6 +2263,16 @@ impl fmt::Display for GetVocabularyFilterError {
     }
 }
 impl Error for GetVocabularyFilterError {}
+impl ServiceErrorCode for GetVocabularyFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetVocabularyFilterError::BadRequest(_) => "BadRequestException",
+            GetVocabularyFilterError::InternalFailure(_) => "InternalFailureException",
+            GetVocabularyFilterError::LimitExceeded(_) => "LimitExceededException",
+            GetVocabularyFilterError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by ListLanguageModels
 #[derive(Debug, PartialEq)]
 pub enum ListLanguageModelsError {
@@ -2158,6 +2315,15 @@ impl fmt::Display for ListLanguageModelsError {
     }
 }
 impl Error for ListLanguageModelsError {}
+impl ServiceErrorCode for ListLanguageModelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLanguageModelsError::BadRequest(_) => "BadRequestException",
+            ListLanguageModelsError::InternalFailure(_) => "InternalFailureException",
+            ListLanguageModelsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by ListMedicalTranscriptionJobs
 #[derive(Debug, PartialEq)]
 pub enum ListMedicalTranscriptionJobsError {
@@ -2208,6 +2374,15 @@ impl fmt::Display for ListMedicalTranscriptionJobsError {
     }
 }
 impl Error for ListMedicalTranscriptionJobsError {}
+impl ServiceErrorCode for ListMedicalTranscriptionJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMedicalTranscriptionJobsError::BadRequest(_) => "BadRequestException",
+            ListMedicalTranscriptionJobsError::InternalFailure(_) => "InternalFailureException",
+            ListMedicalTranscriptionJobsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by ListMedicalVocabularies
 #[derive(Debug, PartialEq)]
 pub enum ListMedicalVocabulariesError {
@@ -2254,6 +2429,15 @@ impl fmt::Display for ListMedicalVocabulariesError {
     }
 }
 impl Error for ListMedicalVocabulariesError {}
+impl ServiceErrorCode for ListMedicalVocabulariesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMedicalVocabulariesError::BadRequest(_) => "BadRequestException",
+            ListMedicalVocabulariesError::InternalFailure(_) => "InternalFailureException",
+            ListMedicalVocabulariesError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by ListTranscriptionJobs
 #[derive(Debug, PartialEq)]
 pub enum ListTranscriptionJobsError {
@@ -2298,6 +2482,15 @@ impl fmt::Display for ListTranscriptionJobsError {
     }
 }
 impl Error for ListTranscriptionJobsError {}
+impl ServiceErrorCode for ListTranscriptionJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTranscriptionJobsError::BadRequest(_) => "BadRequestException",
+            ListTranscriptionJobsError::InternalFailure(_) => "InternalFailureException",
+            ListTranscriptionJobsError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by ListVocabularies
 #[derive(Debug, PartialEq)]
 pub enum ListVocabulariesError {
@@ -2340,6 +2533,15 @@ impl fmt::Display for ListVocabulariesError {
     }
 }
 impl Error for ListVocabulariesError {}
+impl ServiceErrorCode for ListVocabulariesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVocabulariesError::BadRequest(_) => "BadRequestException",
+            ListVocabulariesError::InternalFailure(_) => "InternalFailureException",
+            ListVocabulariesError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by ListVocabularyFilters
 #[derive(Debug, PartialEq)]
 pub enum ListVocabularyFiltersError {
@@ -2384,6 +2586,15 @@ impl fmt::Display for ListVocabularyFiltersError {
     }
 }
 impl Error for ListVocabularyFiltersError {}
+impl ServiceErrorCode for ListVocabularyFiltersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListVocabularyFiltersError::BadRequest(_) => "BadRequestException",
+            ListVocabularyFiltersError::InternalFailure(_) => "InternalFailureException",
+            ListVocabularyFiltersError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by StartMedicalTranscriptionJob
 #[derive(Debug, PartialEq)]
 pub enum StartMedicalTranscriptionJobError {
@@ -2442,6 +2653,16 @@ impl fmt::Display for StartMedicalTranscriptionJobError {
     }
 }
 impl Error for StartMedicalTranscriptionJobError {}
+impl ServiceErrorCode for StartMedicalTranscriptionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartMedicalTranscriptionJobError::BadRequest(_) => "BadRequestException",
+            StartMedicalTranscriptionJobError::Conflict(_) => "ConflictException",
+            StartMedicalTranscriptionJobError::InternalFailure(_) => "InternalFailureException",
+            StartMedicalTranscriptionJobError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by StartTranscriptionJob
 #[derive(Debug, PartialEq)]
 pub enum StartTranscriptionJobError {
@@ -2492,6 +2713,16 @@ impl fmt::Display for StartTranscriptionJobError {
     }
 }
 impl Error for StartTranscriptionJobError {}
+impl ServiceErrorCode for StartTranscriptionJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartTranscriptionJobError::BadRequest(_) => "BadRequestException",
+            StartTranscriptionJobError::Conflict(_) => "ConflictException",
+            StartTranscriptionJobError::InternalFailure(_) => "InternalFailureException",
+            StartTranscriptionJobError::LimitExceeded(_) => "LimitExceededException",
+        }
+    }
+}
 /// Errors returned by UpdateMedicalVocabulary
 #[derive(Debug, PartialEq)]
 pub enum UpdateMedicalVocabularyError {
@@ -2550,6 +2781,17 @@ impl fmt::Display for UpdateMedicalVocabularyError {
     }
 }
 impl Error for UpdateMedicalVocabularyError {}
+impl ServiceErrorCode for UpdateMedicalVocabularyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMedicalVocabularyError::BadRequest(_) => "BadRequestException",
+            UpdateMedicalVocabularyError::Conflict(_) => "ConflictException",
+            UpdateMedicalVocabularyError::InternalFailure(_) => "InternalFailureException",
+            UpdateMedicalVocabularyError::LimitExceeded(_) => "LimitExceededException",
+            UpdateMedicalVocabularyError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateVocabulary
 #[derive(Debug, PartialEq)]
 pub enum UpdateVocabularyError {
@@ -2604,6 +2846,17 @@ impl fmt::Display for UpdateVocabularyError {
     }
 }
 impl Error for UpdateVocabularyError {}
+impl ServiceErrorCode for UpdateVocabularyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateVocabularyError::BadRequest(_) => "BadRequestException",
+            UpdateVocabularyError::Conflict(_) => "ConflictException",
+            UpdateVocabularyError::InternalFailure(_) => "InternalFailureException",
+            UpdateVocabularyError::LimitExceeded(_) => "LimitExceededException",
+            UpdateVocabularyError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateVocabularyFilter
 #[derive(Debug, PartialEq)]
 pub enum UpdateVocabularyFilterError {
@@ -2656,6 +2909,16 @@ impl fmt::Display for UpdateVocabularyFilterError {
     }
 }
 impl Error for UpdateVocabularyFilterError {}
+impl ServiceErrorCode for UpdateVocabularyFilterError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateVocabularyFilterError::BadRequest(_) => "BadRequestException",
+            UpdateVocabularyFilterError::InternalFailure(_) => "InternalFailureException",
+            UpdateVocabularyFilterError::LimitExceeded(_) => "LimitExceededException",
+            UpdateVocabularyFilterError::NotFound(_) => "NotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Transcribe Service API. Amazon Transcribe Service clients implement this trait.
 #[async_trait]
 pub trait Transcribe {
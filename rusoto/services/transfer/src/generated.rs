@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -881,6 +881,18 @@ impl fmt::Display for CreateServerError {
     }
 }
 impl Error for CreateServerError {}
+impl ServiceErrorCode for CreateServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateServerError::AccessDenied(_) => "AccessDeniedException",
+            CreateServerError::InternalServiceError(_) => "InternalServiceError",
+            CreateServerError::InvalidRequest(_) => "InvalidRequestException",
+            CreateServerError::ResourceExists(_) => "ResourceExistsException",
+            CreateServerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateServerError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
 pub enum CreateUserError {
@@ -935,6 +947,17 @@ impl fmt::Display for CreateUserError {
     }
 }
 impl Error for CreateUserError {}
+impl ServiceErrorCode for CreateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserError::InternalServiceError(_) => "InternalServiceError",
+            CreateUserError::InvalidRequest(_) => "InvalidRequestException",
+            CreateUserError::ResourceExists(_) => "ResourceExistsException",
+            CreateUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteServer
 #[derive(Debug, PartialEq)]
 pub enum DeleteServerError {
@@ -989,6 +1012,17 @@ impl fmt::Display for DeleteServerError {
     }
 }
 impl Error for DeleteServerError {}
+impl ServiceErrorCode for DeleteServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteServerError::AccessDenied(_) => "AccessDeniedException",
+            DeleteServerError::InternalServiceError(_) => "InternalServiceError",
+            DeleteServerError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteServerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteServerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DeleteSshPublicKey
 #[derive(Debug, PartialEq)]
 pub enum DeleteSshPublicKeyError {
@@ -1047,6 +1081,17 @@ impl fmt::Display for DeleteSshPublicKeyError {
     }
 }
 impl Error for DeleteSshPublicKeyError {}
+impl ServiceErrorCode for DeleteSshPublicKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSshPublicKeyError::InternalServiceError(_) => "InternalServiceError",
+            DeleteSshPublicKeyError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteSshPublicKeyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteSshPublicKeyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteSshPublicKeyError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserError {
@@ -1095,6 +1140,16 @@ impl fmt::Display for DeleteUserError {
     }
 }
 impl Error for DeleteUserError {}
+impl ServiceErrorCode for DeleteUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserError::InternalServiceError(_) => "InternalServiceError",
+            DeleteUserError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeSecurityPolicy
 #[derive(Debug, PartialEq)]
 pub enum DescribeSecurityPolicyError {
@@ -1151,6 +1206,16 @@ impl fmt::Display for DescribeSecurityPolicyError {
     }
 }
 impl Error for DescribeSecurityPolicyError {}
+impl ServiceErrorCode for DescribeSecurityPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeSecurityPolicyError::InternalServiceError(_) => "InternalServiceError",
+            DescribeSecurityPolicyError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeSecurityPolicyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeSecurityPolicyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeServer
 #[derive(Debug, PartialEq)]
 pub enum DescribeServerError {
@@ -1199,6 +1264,16 @@ impl fmt::Display for DescribeServerError {
     }
 }
 impl Error for DescribeServerError {}
+impl ServiceErrorCode for DescribeServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeServerError::InternalServiceError(_) => "InternalServiceError",
+            DescribeServerError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeServerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeServerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeUser
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserError {
@@ -1247,6 +1322,16 @@ impl fmt::Display for DescribeUserError {
     }
 }
 impl Error for DescribeUserError {}
+impl ServiceErrorCode for DescribeUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserError::InternalServiceError(_) => "InternalServiceError",
+            DescribeUserError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ImportSshPublicKey
 #[derive(Debug, PartialEq)]
 pub enum ImportSshPublicKeyError {
@@ -1311,6 +1396,18 @@ impl fmt::Display for ImportSshPublicKeyError {
     }
 }
 impl Error for ImportSshPublicKeyError {}
+impl ServiceErrorCode for ImportSshPublicKeyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportSshPublicKeyError::InternalServiceError(_) => "InternalServiceError",
+            ImportSshPublicKeyError::InvalidRequest(_) => "InvalidRequestException",
+            ImportSshPublicKeyError::ResourceExists(_) => "ResourceExistsException",
+            ImportSshPublicKeyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ImportSshPublicKeyError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ImportSshPublicKeyError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by ListSecurityPolicies
 #[derive(Debug, PartialEq)]
 pub enum ListSecurityPoliciesError {
@@ -1365,6 +1462,16 @@ impl fmt::Display for ListSecurityPoliciesError {
     }
 }
 impl Error for ListSecurityPoliciesError {}
+impl ServiceErrorCode for ListSecurityPoliciesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSecurityPoliciesError::InternalServiceError(_) => "InternalServiceError",
+            ListSecurityPoliciesError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListSecurityPoliciesError::InvalidRequest(_) => "InvalidRequestException",
+            ListSecurityPoliciesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListServers
 #[derive(Debug, PartialEq)]
 pub enum ListServersError {
@@ -1413,6 +1520,16 @@ impl fmt::Display for ListServersError {
     }
 }
 impl Error for ListServersError {}
+impl ServiceErrorCode for ListServersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListServersError::InternalServiceError(_) => "InternalServiceError",
+            ListServersError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListServersError::InvalidRequest(_) => "InvalidRequestException",
+            ListServersError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -1467,6 +1584,16 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InternalServiceError(_) => "InternalServiceError",
+            ListTagsForResourceError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+            ListTagsForResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ListUsers
 #[derive(Debug, PartialEq)]
 pub enum ListUsersError {
@@ -1521,6 +1648,17 @@ impl fmt::Display for ListUsersError {
     }
 }
 impl Error for ListUsersError {}
+impl ServiceErrorCode for ListUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUsersError::InternalServiceError(_) => "InternalServiceError",
+            ListUsersError::InvalidNextToken(_) => "InvalidNextTokenException",
+            ListUsersError::InvalidRequest(_) => "InvalidRequestException",
+            ListUsersError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListUsersError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by StartServer
 #[derive(Debug, PartialEq)]
 pub enum StartServerError {
@@ -1575,6 +1713,17 @@ impl fmt::Display for StartServerError {
     }
 }
 impl Error for StartServerError {}
+impl ServiceErrorCode for StartServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartServerError::InternalServiceError(_) => "InternalServiceError",
+            StartServerError::InvalidRequest(_) => "InvalidRequestException",
+            StartServerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartServerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            StartServerError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by StopServer
 #[derive(Debug, PartialEq)]
 pub enum StopServerError {
@@ -1629,6 +1778,17 @@ impl fmt::Display for StopServerError {
     }
 }
 impl Error for StopServerError {}
+impl ServiceErrorCode for StopServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopServerError::InternalServiceError(_) => "InternalServiceError",
+            StopServerError::InvalidRequest(_) => "InvalidRequestException",
+            StopServerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StopServerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            StopServerError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -1677,6 +1837,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InternalServiceError(_) => "InternalServiceError",
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by TestIdentityProvider
 #[derive(Debug, PartialEq)]
 pub enum TestIdentityProviderError {
@@ -1731,6 +1901,16 @@ impl fmt::Display for TestIdentityProviderError {
     }
 }
 impl Error for TestIdentityProviderError {}
+impl ServiceErrorCode for TestIdentityProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TestIdentityProviderError::InternalServiceError(_) => "InternalServiceError",
+            TestIdentityProviderError::InvalidRequest(_) => "InvalidRequestException",
+            TestIdentityProviderError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TestIdentityProviderError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -1779,6 +1959,16 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InternalServiceError(_) => "InternalServiceError",
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::ServiceUnavailable(_) => "ServiceUnavailableException",
+        }
+    }
+}
 /// Errors returned by UpdateServer
 #[derive(Debug, PartialEq)]
 pub enum UpdateServerError {
@@ -1851,6 +2041,20 @@ impl fmt::Display for UpdateServerError {
     }
 }
 impl Error for UpdateServerError {}
+impl ServiceErrorCode for UpdateServerError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateServerError::AccessDenied(_) => "AccessDeniedException",
+            UpdateServerError::Conflict(_) => "ConflictException",
+            UpdateServerError::InternalServiceError(_) => "InternalServiceError",
+            UpdateServerError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateServerError::ResourceExists(_) => "ResourceExistsException",
+            UpdateServerError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateServerError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateServerError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Errors returned by UpdateUser
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserError {
@@ -1905,6 +2109,17 @@ impl fmt::Display for UpdateUserError {
     }
 }
 impl Error for UpdateUserError {}
+impl ServiceErrorCode for UpdateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserError::InternalServiceError(_) => "InternalServiceError",
+            UpdateUserError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateUserError::Throttling(_) => "ThrottlingException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS Transfer API. AWS Transfer clients implement this trait.
 #[async_trait]
 pub trait Transfer {
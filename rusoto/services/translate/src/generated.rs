@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -68,6 +68,7 @@ pub struct AppliedTerminology {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CreateParallelDataRequest {
     /// <p>A unique identifier for the request. This token is automatically generated when you use Amazon Translate through an AWS SDK.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     pub client_token: String,
     /// <p>A custom description for the parallel data resource in Amazon Translate.</p>
@@ -458,6 +459,7 @@ pub struct ParallelDataProperties {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartTextTranslationJobRequest {
     /// <p>A unique identifier for the request. This token is auto-generated when using the Amazon Translate SDK.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     pub client_token: String,
     /// <p>The Amazon Resource Name (ARN) of an AWS Identity Access and Management (IAM) role that grants Amazon Translate read access to your input data. For more nformation, see <a>identity-and-access-management</a>.</p>
@@ -736,6 +738,7 @@ pub struct TranslateTextResponse {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct UpdateParallelDataRequest {
     /// <p>A unique identifier for the request. This token is automatically generated when you use Amazon Translate through an AWS SDK.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     pub client_token: String,
     /// <p>A custom description for the parallel data resource in Amazon Translate.</p>
@@ -833,6 +836,18 @@ impl fmt::Display for CreateParallelDataError {
     }
 }
 impl Error for CreateParallelDataError {}
+impl ServiceErrorCode for CreateParallelDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateParallelDataError::Conflict(_) => "ConflictException",
+            CreateParallelDataError::InternalServer(_) => "InternalServerException",
+            CreateParallelDataError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            CreateParallelDataError::InvalidRequest(_) => "InvalidRequestException",
+            CreateParallelDataError::LimitExceeded(_) => "LimitExceededException",
+            CreateParallelDataError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteParallelData
 #[derive(Debug, PartialEq)]
 pub enum DeleteParallelDataError {
@@ -883,6 +898,16 @@ impl fmt::Display for DeleteParallelDataError {
     }
 }
 impl Error for DeleteParallelDataError {}
+impl ServiceErrorCode for DeleteParallelDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteParallelDataError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteParallelDataError::InternalServer(_) => "InternalServerException",
+            DeleteParallelDataError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteParallelDataError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DeleteTerminology
 #[derive(Debug, PartialEq)]
 pub enum DeleteTerminologyError {
@@ -933,6 +958,16 @@ impl fmt::Display for DeleteTerminologyError {
     }
 }
 impl Error for DeleteTerminologyError {}
+impl ServiceErrorCode for DeleteTerminologyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTerminologyError::InternalServer(_) => "InternalServerException",
+            DeleteTerminologyError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            DeleteTerminologyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteTerminologyError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by DescribeTextTranslationJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeTextTranslationJobError {
@@ -983,6 +1018,15 @@ impl fmt::Display for DescribeTextTranslationJobError {
     }
 }
 impl Error for DescribeTextTranslationJobError {}
+impl ServiceErrorCode for DescribeTextTranslationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTextTranslationJobError::InternalServer(_) => "InternalServerException",
+            DescribeTextTranslationJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeTextTranslationJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetParallelData
 #[derive(Debug, PartialEq)]
 pub enum GetParallelDataError {
@@ -1033,6 +1077,16 @@ impl fmt::Display for GetParallelDataError {
     }
 }
 impl Error for GetParallelDataError {}
+impl ServiceErrorCode for GetParallelDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetParallelDataError::InternalServer(_) => "InternalServerException",
+            GetParallelDataError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetParallelDataError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetParallelDataError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by GetTerminology
 #[derive(Debug, PartialEq)]
 pub enum GetTerminologyError {
@@ -1083,6 +1137,16 @@ impl fmt::Display for GetTerminologyError {
     }
 }
 impl Error for GetTerminologyError {}
+impl ServiceErrorCode for GetTerminologyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTerminologyError::InternalServer(_) => "InternalServerException",
+            GetTerminologyError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            GetTerminologyError::ResourceNotFound(_) => "ResourceNotFoundException",
+            GetTerminologyError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ImportTerminology
 #[derive(Debug, PartialEq)]
 pub enum ImportTerminologyError {
@@ -1133,6 +1197,16 @@ impl fmt::Display for ImportTerminologyError {
     }
 }
 impl Error for ImportTerminologyError {}
+impl ServiceErrorCode for ImportTerminologyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportTerminologyError::InternalServer(_) => "InternalServerException",
+            ImportTerminologyError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ImportTerminologyError::LimitExceeded(_) => "LimitExceededException",
+            ImportTerminologyError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListParallelData
 #[derive(Debug, PartialEq)]
 pub enum ListParallelDataError {
@@ -1177,6 +1251,15 @@ impl fmt::Display for ListParallelDataError {
     }
 }
 impl Error for ListParallelDataError {}
+impl ServiceErrorCode for ListParallelDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListParallelDataError::InternalServer(_) => "InternalServerException",
+            ListParallelDataError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListParallelDataError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTerminologies
 #[derive(Debug, PartialEq)]
 pub enum ListTerminologiesError {
@@ -1221,6 +1304,15 @@ impl fmt::Display for ListTerminologiesError {
     }
 }
 impl Error for ListTerminologiesError {}
+impl ServiceErrorCode for ListTerminologiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTerminologiesError::InternalServer(_) => "InternalServerException",
+            ListTerminologiesError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            ListTerminologiesError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by ListTextTranslationJobs
 #[derive(Debug, PartialEq)]
 pub enum ListTextTranslationJobsError {
@@ -1277,6 +1369,16 @@ impl fmt::Display for ListTextTranslationJobsError {
     }
 }
 impl Error for ListTextTranslationJobsError {}
+impl ServiceErrorCode for ListTextTranslationJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTextTranslationJobsError::InternalServer(_) => "InternalServerException",
+            ListTextTranslationJobsError::InvalidFilter(_) => "InvalidFilterException",
+            ListTextTranslationJobsError::InvalidRequest(_) => "InvalidRequestException",
+            ListTextTranslationJobsError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by StartTextTranslationJob
 #[derive(Debug, PartialEq)]
 pub enum StartTextTranslationJobError {
@@ -1343,6 +1445,19 @@ impl fmt::Display for StartTextTranslationJobError {
     }
 }
 impl Error for StartTextTranslationJobError {}
+impl ServiceErrorCode for StartTextTranslationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartTextTranslationJobError::InternalServer(_) => "InternalServerException",
+            StartTextTranslationJobError::InvalidRequest(_) => "InvalidRequestException",
+            StartTextTranslationJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StartTextTranslationJobError::TooManyRequests(_) => "TooManyRequestsException",
+            StartTextTranslationJobError::UnsupportedLanguagePair(_) => {
+                "UnsupportedLanguagePairException"
+            }
+        }
+    }
+}
 /// Errors returned by StopTextTranslationJob
 #[derive(Debug, PartialEq)]
 pub enum StopTextTranslationJobError {
@@ -1391,6 +1506,15 @@ impl fmt::Display for StopTextTranslationJobError {
     }
 }
 impl Error for StopTextTranslationJobError {}
+impl ServiceErrorCode for StopTextTranslationJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StopTextTranslationJobError::InternalServer(_) => "InternalServerException",
+            StopTextTranslationJobError::ResourceNotFound(_) => "ResourceNotFoundException",
+            StopTextTranslationJobError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Errors returned by TranslateText
 #[derive(Debug, PartialEq)]
 pub enum TranslateTextError {
@@ -1467,6 +1591,22 @@ impl fmt::Display for TranslateTextError {
     }
 }
 impl Error for TranslateTextError {}
+impl ServiceErrorCode for TranslateTextError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TranslateTextError::DetectedLanguageLowConfidence(_) => {
+                "DetectedLanguageLowConfidenceException"
+            }
+            TranslateTextError::InternalServer(_) => "InternalServerException",
+            TranslateTextError::InvalidRequest(_) => "InvalidRequestException",
+            TranslateTextError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TranslateTextError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            TranslateTextError::TextSizeLimitExceeded(_) => "TextSizeLimitExceededException",
+            TranslateTextError::TooManyRequests(_) => "TooManyRequestsException",
+            TranslateTextError::UnsupportedLanguagePair(_) => "UnsupportedLanguagePairException",
+        }
+    }
+}
 /// Errors returned by UpdateParallelData
 #[derive(Debug, PartialEq)]
 pub enum UpdateParallelDataError {
@@ -1543,6 +1683,20 @@ impl fmt::Display for UpdateParallelDataError {
     }
 }
 impl Error for UpdateParallelDataError {}
+impl ServiceErrorCode for UpdateParallelDataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateParallelDataError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateParallelDataError::Conflict(_) => "ConflictException",
+            UpdateParallelDataError::InternalServer(_) => "InternalServerException",
+            UpdateParallelDataError::InvalidParameterValue(_) => "InvalidParameterValueException",
+            UpdateParallelDataError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateParallelDataError::LimitExceeded(_) => "LimitExceededException",
+            UpdateParallelDataError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateParallelDataError::TooManyRequests(_) => "TooManyRequestsException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon Translate API. Amazon Translate clients implement this trait.
 #[async_trait]
 pub trait Translate {
@@ -1675,6 +1829,12 @@ impl Translate for TranslateClient {
         &self,
         input: CreateParallelDataRequest,
     ) -> Result<CreateParallelDataResponse, RusotoError<CreateParallelDataError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -1887,6 +2047,12 @@ impl Translate for TranslateClient {
         &self,
         input: StartTextTranslationJobRequest,
     ) -> Result<StartTextTranslationJobResponse, RusotoError<StartTextTranslationJobError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -1952,6 +2118,12 @@ impl Translate for TranslateClient {
         &self,
         input: UpdateParallelDataRequest,
     ) -> Result<UpdateParallelDataResponse, RusotoError<UpdateParallelDataError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header(
             "x-amz-target",
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2691,6 +2691,17 @@ impl fmt::Display for AssociateWebACLError {
     }
 }
 impl Error for AssociateWebACLError {}
+impl ServiceErrorCode for AssociateWebACLError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateWebACLError::WAFInternalError(_) => "WAFInternalErrorException",
+            AssociateWebACLError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            AssociateWebACLError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            AssociateWebACLError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            AssociateWebACLError::WAFUnavailableEntity(_) => "WAFUnavailableEntityException",
+        }
+    }
+}
 /// Errors returned by CreateByteMatchSet
 #[derive(Debug, PartialEq)]
 pub enum CreateByteMatchSetError {
@@ -2759,6 +2770,18 @@ impl fmt::Display for CreateByteMatchSetError {
     }
 }
 impl Error for CreateByteMatchSetError {}
+impl ServiceErrorCode for CreateByteMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateByteMatchSetError::WAFDisallowedName(_) => "WAFDisallowedNameException",
+            CreateByteMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            CreateByteMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            CreateByteMatchSetError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            CreateByteMatchSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            CreateByteMatchSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by CreateGeoMatchSet
 #[derive(Debug, PartialEq)]
 pub enum CreateGeoMatchSetError {
@@ -2821,6 +2844,18 @@ impl fmt::Display for CreateGeoMatchSetError {
     }
 }
 impl Error for CreateGeoMatchSetError {}
+impl ServiceErrorCode for CreateGeoMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGeoMatchSetError::WAFDisallowedName(_) => "WAFDisallowedNameException",
+            CreateGeoMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            CreateGeoMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            CreateGeoMatchSetError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            CreateGeoMatchSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            CreateGeoMatchSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by CreateIPSet
 #[derive(Debug, PartialEq)]
 pub enum CreateIPSetError {
@@ -2881,6 +2916,18 @@ impl fmt::Display for CreateIPSetError {
     }
 }
 impl Error for CreateIPSetError {}
+impl ServiceErrorCode for CreateIPSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateIPSetError::WAFDisallowedName(_) => "WAFDisallowedNameException",
+            CreateIPSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            CreateIPSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            CreateIPSetError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            CreateIPSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            CreateIPSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by CreateRateBasedRule
 #[derive(Debug, PartialEq)]
 pub enum CreateRateBasedRuleError {
@@ -2965,6 +3012,22 @@ impl fmt::Display for CreateRateBasedRuleError {
     }
 }
 impl Error for CreateRateBasedRuleError {}
+impl ServiceErrorCode for CreateRateBasedRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRateBasedRuleError::WAFBadRequest(_) => "WAFBadRequestException",
+            CreateRateBasedRuleError::WAFDisallowedName(_) => "WAFDisallowedNameException",
+            CreateRateBasedRuleError::WAFInternalError(_) => "WAFInternalErrorException",
+            CreateRateBasedRuleError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            CreateRateBasedRuleError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            CreateRateBasedRuleError::WAFStaleData(_) => "WAFStaleDataException",
+            CreateRateBasedRuleError::WAFTagOperation(_) => "WAFTagOperationException",
+            CreateRateBasedRuleError::WAFTagOperationInternalError(_) => {
+                "WAFTagOperationInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateRegexMatchSet
 #[derive(Debug, PartialEq)]
 pub enum CreateRegexMatchSetError {
@@ -3019,6 +3082,16 @@ impl fmt::Display for CreateRegexMatchSetError {
     }
 }
 impl Error for CreateRegexMatchSetError {}
+impl ServiceErrorCode for CreateRegexMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRegexMatchSetError::WAFDisallowedName(_) => "WAFDisallowedNameException",
+            CreateRegexMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            CreateRegexMatchSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            CreateRegexMatchSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by CreateRegexPatternSet
 #[derive(Debug, PartialEq)]
 pub enum CreateRegexPatternSetError {
@@ -3073,6 +3146,16 @@ impl fmt::Display for CreateRegexPatternSetError {
     }
 }
 impl Error for CreateRegexPatternSetError {}
+impl ServiceErrorCode for CreateRegexPatternSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRegexPatternSetError::WAFDisallowedName(_) => "WAFDisallowedNameException",
+            CreateRegexPatternSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            CreateRegexPatternSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            CreateRegexPatternSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by CreateRule
 #[derive(Debug, PartialEq)]
 pub enum CreateRuleError {
@@ -3147,6 +3230,22 @@ impl fmt::Display for CreateRuleError {
     }
 }
 impl Error for CreateRuleError {}
+impl ServiceErrorCode for CreateRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRuleError::WAFBadRequest(_) => "WAFBadRequestException",
+            CreateRuleError::WAFDisallowedName(_) => "WAFDisallowedNameException",
+            CreateRuleError::WAFInternalError(_) => "WAFInternalErrorException",
+            CreateRuleError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            CreateRuleError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            CreateRuleError::WAFStaleData(_) => "WAFStaleDataException",
+            CreateRuleError::WAFTagOperation(_) => "WAFTagOperationException",
+            CreateRuleError::WAFTagOperationInternalError(_) => {
+                "WAFTagOperationInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateRuleGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateRuleGroupError {
@@ -3215,6 +3314,21 @@ impl fmt::Display for CreateRuleGroupError {
     }
 }
 impl Error for CreateRuleGroupError {}
+impl ServiceErrorCode for CreateRuleGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateRuleGroupError::WAFBadRequest(_) => "WAFBadRequestException",
+            CreateRuleGroupError::WAFDisallowedName(_) => "WAFDisallowedNameException",
+            CreateRuleGroupError::WAFInternalError(_) => "WAFInternalErrorException",
+            CreateRuleGroupError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            CreateRuleGroupError::WAFStaleData(_) => "WAFStaleDataException",
+            CreateRuleGroupError::WAFTagOperation(_) => "WAFTagOperationException",
+            CreateRuleGroupError::WAFTagOperationInternalError(_) => {
+                "WAFTagOperationInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateSizeConstraintSet
 #[derive(Debug, PartialEq)]
 pub enum CreateSizeConstraintSetError {
@@ -3287,6 +3401,18 @@ impl fmt::Display for CreateSizeConstraintSetError {
     }
 }
 impl Error for CreateSizeConstraintSetError {}
+impl ServiceErrorCode for CreateSizeConstraintSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSizeConstraintSetError::WAFDisallowedName(_) => "WAFDisallowedNameException",
+            CreateSizeConstraintSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            CreateSizeConstraintSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            CreateSizeConstraintSetError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            CreateSizeConstraintSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            CreateSizeConstraintSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by CreateSqlInjectionMatchSet
 #[derive(Debug, PartialEq)]
 pub enum CreateSqlInjectionMatchSetError {
@@ -3363,6 +3489,20 @@ impl fmt::Display for CreateSqlInjectionMatchSetError {
     }
 }
 impl Error for CreateSqlInjectionMatchSetError {}
+impl ServiceErrorCode for CreateSqlInjectionMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSqlInjectionMatchSetError::WAFDisallowedName(_) => "WAFDisallowedNameException",
+            CreateSqlInjectionMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            CreateSqlInjectionMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            CreateSqlInjectionMatchSetError::WAFInvalidParameter(_) => {
+                "WAFInvalidParameterException"
+            }
+            CreateSqlInjectionMatchSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            CreateSqlInjectionMatchSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by CreateWebACL
 #[derive(Debug, PartialEq)]
 pub enum CreateWebACLError {
@@ -3443,6 +3583,23 @@ impl fmt::Display for CreateWebACLError {
     }
 }
 impl Error for CreateWebACLError {}
+impl ServiceErrorCode for CreateWebACLError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateWebACLError::WAFBadRequest(_) => "WAFBadRequestException",
+            CreateWebACLError::WAFDisallowedName(_) => "WAFDisallowedNameException",
+            CreateWebACLError::WAFInternalError(_) => "WAFInternalErrorException",
+            CreateWebACLError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            CreateWebACLError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            CreateWebACLError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            CreateWebACLError::WAFStaleData(_) => "WAFStaleDataException",
+            CreateWebACLError::WAFTagOperation(_) => "WAFTagOperationException",
+            CreateWebACLError::WAFTagOperationInternalError(_) => {
+                "WAFTagOperationInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateWebACLMigrationStack
 #[derive(Debug, PartialEq)]
 pub enum CreateWebACLMigrationStackError {
@@ -3517,6 +3674,21 @@ impl fmt::Display for CreateWebACLMigrationStackError {
     }
 }
 impl Error for CreateWebACLMigrationStackError {}
+impl ServiceErrorCode for CreateWebACLMigrationStackError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateWebACLMigrationStackError::WAFEntityMigration(_) => "WAFEntityMigrationException",
+            CreateWebACLMigrationStackError::WAFInternalError(_) => "WAFInternalErrorException",
+            CreateWebACLMigrationStackError::WAFInvalidOperation(_) => {
+                "WAFInvalidOperationException"
+            }
+            CreateWebACLMigrationStackError::WAFInvalidParameter(_) => {
+                "WAFInvalidParameterException"
+            }
+            CreateWebACLMigrationStackError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by CreateXssMatchSet
 #[derive(Debug, PartialEq)]
 pub enum CreateXssMatchSetError {
@@ -3579,6 +3751,18 @@ impl fmt::Display for CreateXssMatchSetError {
     }
 }
 impl Error for CreateXssMatchSetError {}
+impl ServiceErrorCode for CreateXssMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateXssMatchSetError::WAFDisallowedName(_) => "WAFDisallowedNameException",
+            CreateXssMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            CreateXssMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            CreateXssMatchSetError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            CreateXssMatchSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            CreateXssMatchSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by DeleteByteMatchSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteByteMatchSetError {
@@ -3647,6 +3831,18 @@ impl fmt::Display for DeleteByteMatchSetError {
     }
 }
 impl Error for DeleteByteMatchSetError {}
+impl ServiceErrorCode for DeleteByteMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteByteMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            DeleteByteMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            DeleteByteMatchSetError::WAFNonEmptyEntity(_) => "WAFNonEmptyEntityException",
+            DeleteByteMatchSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            DeleteByteMatchSetError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            DeleteByteMatchSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by DeleteGeoMatchSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteGeoMatchSetError {
@@ -3709,6 +3905,18 @@ impl fmt::Display for DeleteGeoMatchSetError {
     }
 }
 impl Error for DeleteGeoMatchSetError {}
+impl ServiceErrorCode for DeleteGeoMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGeoMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            DeleteGeoMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            DeleteGeoMatchSetError::WAFNonEmptyEntity(_) => "WAFNonEmptyEntityException",
+            DeleteGeoMatchSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            DeleteGeoMatchSetError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            DeleteGeoMatchSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by DeleteIPSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteIPSetError {
@@ -3769,6 +3977,18 @@ impl fmt::Display for DeleteIPSetError {
     }
 }
 impl Error for DeleteIPSetError {}
+impl ServiceErrorCode for DeleteIPSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIPSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            DeleteIPSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            DeleteIPSetError::WAFNonEmptyEntity(_) => "WAFNonEmptyEntityException",
+            DeleteIPSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            DeleteIPSetError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            DeleteIPSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by DeleteLoggingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeleteLoggingConfigurationError {
@@ -3821,6 +4041,15 @@ impl fmt::Display for DeleteLoggingConfigurationError {
     }
 }
 impl Error for DeleteLoggingConfigurationError {}
+impl ServiceErrorCode for DeleteLoggingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLoggingConfigurationError::WAFInternalError(_) => "WAFInternalErrorException",
+            DeleteLoggingConfigurationError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            DeleteLoggingConfigurationError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by DeletePermissionPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeletePermissionPolicyError {
@@ -3867,6 +4096,15 @@ impl fmt::Display for DeletePermissionPolicyError {
     }
 }
 impl Error for DeletePermissionPolicyError {}
+impl ServiceErrorCode for DeletePermissionPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeletePermissionPolicyError::WAFInternalError(_) => "WAFInternalErrorException",
+            DeletePermissionPolicyError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            DeletePermissionPolicyError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by DeleteRateBasedRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteRateBasedRuleError {
@@ -3953,6 +4191,22 @@ impl fmt::Display for DeleteRateBasedRuleError {
     }
 }
 impl Error for DeleteRateBasedRuleError {}
+impl ServiceErrorCode for DeleteRateBasedRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRateBasedRuleError::WAFInternalError(_) => "WAFInternalErrorException",
+            DeleteRateBasedRuleError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            DeleteRateBasedRuleError::WAFNonEmptyEntity(_) => "WAFNonEmptyEntityException",
+            DeleteRateBasedRuleError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            DeleteRateBasedRuleError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            DeleteRateBasedRuleError::WAFStaleData(_) => "WAFStaleDataException",
+            DeleteRateBasedRuleError::WAFTagOperation(_) => "WAFTagOperationException",
+            DeleteRateBasedRuleError::WAFTagOperationInternalError(_) => {
+                "WAFTagOperationInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteRegexMatchSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteRegexMatchSetError {
@@ -4023,6 +4277,18 @@ impl fmt::Display for DeleteRegexMatchSetError {
     }
 }
 impl Error for DeleteRegexMatchSetError {}
+impl ServiceErrorCode for DeleteRegexMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRegexMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            DeleteRegexMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            DeleteRegexMatchSetError::WAFNonEmptyEntity(_) => "WAFNonEmptyEntityException",
+            DeleteRegexMatchSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            DeleteRegexMatchSetError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            DeleteRegexMatchSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by DeleteRegexPatternSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteRegexPatternSetError {
@@ -4093,6 +4359,18 @@ impl fmt::Display for DeleteRegexPatternSetError {
     }
 }
 impl Error for DeleteRegexPatternSetError {}
+impl ServiceErrorCode for DeleteRegexPatternSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRegexPatternSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            DeleteRegexPatternSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            DeleteRegexPatternSetError::WAFNonEmptyEntity(_) => "WAFNonEmptyEntityException",
+            DeleteRegexPatternSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            DeleteRegexPatternSetError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            DeleteRegexPatternSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by DeleteRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteRuleError {
@@ -4167,6 +4445,22 @@ impl fmt::Display for DeleteRuleError {
     }
 }
 impl Error for DeleteRuleError {}
+impl ServiceErrorCode for DeleteRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRuleError::WAFInternalError(_) => "WAFInternalErrorException",
+            DeleteRuleError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            DeleteRuleError::WAFNonEmptyEntity(_) => "WAFNonEmptyEntityException",
+            DeleteRuleError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            DeleteRuleError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            DeleteRuleError::WAFStaleData(_) => "WAFStaleDataException",
+            DeleteRuleError::WAFTagOperation(_) => "WAFTagOperationException",
+            DeleteRuleError::WAFTagOperationInternalError(_) => {
+                "WAFTagOperationInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteRuleGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteRuleGroupError {
@@ -4241,6 +4535,22 @@ impl fmt::Display for DeleteRuleGroupError {
     }
 }
 impl Error for DeleteRuleGroupError {}
+impl ServiceErrorCode for DeleteRuleGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRuleGroupError::WAFInternalError(_) => "WAFInternalErrorException",
+            DeleteRuleGroupError::WAFInvalidOperation(_) => "WAFInvalidOperationException",
+            DeleteRuleGroupError::WAFNonEmptyEntity(_) => "WAFNonEmptyEntityException",
+            DeleteRuleGroupError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            DeleteRuleGroupError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            DeleteRuleGroupError::WAFStaleData(_) => "WAFStaleDataException",
+            DeleteRuleGroupError::WAFTagOperation(_) => "WAFTagOperationException",
+            DeleteRuleGroupError::WAFTagOperationInternalError(_) => {
+                "WAFTagOperationInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteSizeConstraintSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteSizeConstraintSetError {
@@ -4313,6 +4623,18 @@ impl fmt::Display for DeleteSizeConstraintSetError {
     }
 }
 impl Error for DeleteSizeConstraintSetError {}
+impl ServiceErrorCode for DeleteSizeConstraintSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSizeConstraintSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            DeleteSizeConstraintSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            DeleteSizeConstraintSetError::WAFNonEmptyEntity(_) => "WAFNonEmptyEntityException",
+            DeleteSizeConstraintSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            DeleteSizeConstraintSetError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            DeleteSizeConstraintSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by DeleteSqlInjectionMatchSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteSqlInjectionMatchSetError {
@@ -4389,6 +4711,18 @@ impl fmt::Display for DeleteSqlInjectionMatchSetError {
     }
 }
 impl Error for DeleteSqlInjectionMatchSetError {}
+impl ServiceErrorCode for DeleteSqlInjectionMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSqlInjectionMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            DeleteSqlInjectionMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            DeleteSqlInjectionMatchSetError::WAFNonEmptyEntity(_) => "WAFNonEmptyEntityException",
+            DeleteSqlInjectionMatchSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            DeleteSqlInjectionMatchSetError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            DeleteSqlInjectionMatchSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by DeleteWebACL
 #[derive(Debug, PartialEq)]
 pub enum DeleteWebACLError {
@@ -4463,6 +4797,22 @@ impl fmt::Display for DeleteWebACLError {
     }
 }
 impl Error for DeleteWebACLError {}
+impl ServiceErrorCode for DeleteWebACLError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteWebACLError::WAFInternalError(_) => "WAFInternalErrorException",
+            DeleteWebACLError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            DeleteWebACLError::WAFNonEmptyEntity(_) => "WAFNonEmptyEntityException",
+            DeleteWebACLError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            DeleteWebACLError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            DeleteWebACLError::WAFStaleData(_) => "WAFStaleDataException",
+            DeleteWebACLError::WAFTagOperation(_) => "WAFTagOperationException",
+            DeleteWebACLError::WAFTagOperationInternalError(_) => {
+                "WAFTagOperationInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteXssMatchSet
 #[derive(Debug, PartialEq)]
 pub enum DeleteXssMatchSetError {
@@ -4525,6 +4875,18 @@ impl fmt::Display for DeleteXssMatchSetError {
     }
 }
 impl Error for DeleteXssMatchSetError {}
+impl ServiceErrorCode for DeleteXssMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteXssMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            DeleteXssMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            DeleteXssMatchSetError::WAFNonEmptyEntity(_) => "WAFNonEmptyEntityException",
+            DeleteXssMatchSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            DeleteXssMatchSetError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            DeleteXssMatchSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by DisassociateWebACL
 #[derive(Debug, PartialEq)]
 pub enum DisassociateWebACLError {
@@ -4579,6 +4941,16 @@ impl fmt::Display for DisassociateWebACLError {
     }
 }
 impl Error for DisassociateWebACLError {}
+impl ServiceErrorCode for DisassociateWebACLError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateWebACLError::WAFInternalError(_) => "WAFInternalErrorException",
+            DisassociateWebACLError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            DisassociateWebACLError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            DisassociateWebACLError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetByteMatchSet
 #[derive(Debug, PartialEq)]
 pub enum GetByteMatchSetError {
@@ -4621,6 +4993,15 @@ impl fmt::Display for GetByteMatchSetError {
     }
 }
 impl Error for GetByteMatchSetError {}
+impl ServiceErrorCode for GetByteMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetByteMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetByteMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            GetByteMatchSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetChangeToken
 #[derive(Debug, PartialEq)]
 pub enum GetChangeTokenError {
@@ -4651,6 +5032,13 @@ impl fmt::Display for GetChangeTokenError {
     }
 }
 impl Error for GetChangeTokenError {}
+impl ServiceErrorCode for GetChangeTokenError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetChangeTokenError::WAFInternalError(_) => "WAFInternalErrorException",
+        }
+    }
+}
 /// Errors returned by GetChangeTokenStatus
 #[derive(Debug, PartialEq)]
 pub enum GetChangeTokenStatusError {
@@ -4691,6 +5079,14 @@ impl fmt::Display for GetChangeTokenStatusError {
     }
 }
 impl Error for GetChangeTokenStatusError {}
+impl ServiceErrorCode for GetChangeTokenStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetChangeTokenStatusError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetChangeTokenStatusError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetGeoMatchSet
 #[derive(Debug, PartialEq)]
 pub enum GetGeoMatchSetError {
@@ -4733,6 +5129,15 @@ impl fmt::Display for GetGeoMatchSetError {
     }
 }
 impl Error for GetGeoMatchSetError {}
+impl ServiceErrorCode for GetGeoMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGeoMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetGeoMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            GetGeoMatchSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetIPSet
 #[derive(Debug, PartialEq)]
 pub enum GetIPSetError {
@@ -4775,6 +5180,15 @@ impl fmt::Display for GetIPSetError {
     }
 }
 impl Error for GetIPSetError {}
+impl ServiceErrorCode for GetIPSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetIPSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetIPSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            GetIPSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetLoggingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum GetLoggingConfigurationError {
@@ -4815,6 +5229,14 @@ impl fmt::Display for GetLoggingConfigurationError {
     }
 }
 impl Error for GetLoggingConfigurationError {}
+impl ServiceErrorCode for GetLoggingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetLoggingConfigurationError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetLoggingConfigurationError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetPermissionPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetPermissionPolicyError {
@@ -4855,6 +5277,14 @@ impl fmt::Display for GetPermissionPolicyError {
     }
 }
 impl Error for GetPermissionPolicyError {}
+impl ServiceErrorCode for GetPermissionPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetPermissionPolicyError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetPermissionPolicyError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetRateBasedRule
 #[derive(Debug, PartialEq)]
 pub enum GetRateBasedRuleError {
@@ -4897,6 +5327,15 @@ impl fmt::Display for GetRateBasedRuleError {
     }
 }
 impl Error for GetRateBasedRuleError {}
+impl ServiceErrorCode for GetRateBasedRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRateBasedRuleError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetRateBasedRuleError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            GetRateBasedRuleError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetRateBasedRuleManagedKeys
 #[derive(Debug, PartialEq)]
 pub enum GetRateBasedRuleManagedKeysError {
@@ -4961,6 +5400,20 @@ impl fmt::Display for GetRateBasedRuleManagedKeysError {
     }
 }
 impl Error for GetRateBasedRuleManagedKeysError {}
+impl ServiceErrorCode for GetRateBasedRuleManagedKeysError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRateBasedRuleManagedKeysError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetRateBasedRuleManagedKeysError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            GetRateBasedRuleManagedKeysError::WAFInvalidParameter(_) => {
+                "WAFInvalidParameterException"
+            }
+            GetRateBasedRuleManagedKeysError::WAFNonexistentItem(_) => {
+                "WAFNonexistentItemException"
+            }
+        }
+    }
+}
 /// Errors returned by GetRegexMatchSet
 #[derive(Debug, PartialEq)]
 pub enum GetRegexMatchSetError {
@@ -5003,6 +5456,15 @@ impl fmt::Display for GetRegexMatchSetError {
     }
 }
 impl Error for GetRegexMatchSetError {}
+impl ServiceErrorCode for GetRegexMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRegexMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetRegexMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            GetRegexMatchSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetRegexPatternSet
 #[derive(Debug, PartialEq)]
 pub enum GetRegexPatternSetError {
@@ -5049,6 +5511,15 @@ impl fmt::Display for GetRegexPatternSetError {
     }
 }
 impl Error for GetRegexPatternSetError {}
+impl ServiceErrorCode for GetRegexPatternSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRegexPatternSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetRegexPatternSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            GetRegexPatternSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetRule
 #[derive(Debug, PartialEq)]
 pub enum GetRuleError {
@@ -5091,6 +5562,15 @@ impl fmt::Display for GetRuleError {
     }
 }
 impl Error for GetRuleError {}
+impl ServiceErrorCode for GetRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRuleError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetRuleError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            GetRuleError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetRuleGroup
 #[derive(Debug, PartialEq)]
 pub enum GetRuleGroupError {
@@ -5127,6 +5607,14 @@ impl fmt::Display for GetRuleGroupError {
     }
 }
 impl Error for GetRuleGroupError {}
+impl ServiceErrorCode for GetRuleGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRuleGroupError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetRuleGroupError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetSampledRequests
 #[derive(Debug, PartialEq)]
 pub enum GetSampledRequestsError {
@@ -5165,6 +5653,14 @@ impl fmt::Display for GetSampledRequestsError {
     }
 }
 impl Error for GetSampledRequestsError {}
+impl ServiceErrorCode for GetSampledRequestsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSampledRequestsError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetSampledRequestsError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetSizeConstraintSet
 #[derive(Debug, PartialEq)]
 pub enum GetSizeConstraintSetError {
@@ -5213,6 +5709,15 @@ impl fmt::Display for GetSizeConstraintSetError {
     }
 }
 impl Error for GetSizeConstraintSetError {}
+impl ServiceErrorCode for GetSizeConstraintSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSizeConstraintSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetSizeConstraintSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            GetSizeConstraintSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetSqlInjectionMatchSet
 #[derive(Debug, PartialEq)]
 pub enum GetSqlInjectionMatchSetError {
@@ -5261,6 +5766,15 @@ impl fmt::Display for GetSqlInjectionMatchSetError {
     }
 }
 impl Error for GetSqlInjectionMatchSetError {}
+impl ServiceErrorCode for GetSqlInjectionMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSqlInjectionMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetSqlInjectionMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            GetSqlInjectionMatchSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetWebACL
 #[derive(Debug, PartialEq)]
 pub enum GetWebACLError {
@@ -5303,6 +5817,15 @@ impl fmt::Display for GetWebACLError {
     }
 }
 impl Error for GetWebACLError {}
+impl ServiceErrorCode for GetWebACLError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetWebACLError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetWebACLError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            GetWebACLError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by GetWebACLForResource
 #[derive(Debug, PartialEq)]
 pub enum GetWebACLForResourceError {
@@ -5367,6 +5890,17 @@ impl fmt::Display for GetWebACLForResourceError {
     }
 }
 impl Error for GetWebACLForResourceError {}
+impl ServiceErrorCode for GetWebACLForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetWebACLForResourceError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetWebACLForResourceError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            GetWebACLForResourceError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            GetWebACLForResourceError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            GetWebACLForResourceError::WAFUnavailableEntity(_) => "WAFUnavailableEntityException",
+        }
+    }
+}
 /// Errors returned by GetXssMatchSet
 #[derive(Debug, PartialEq)]
 pub enum GetXssMatchSetError {
@@ -5409,6 +5943,15 @@ impl fmt::Display for GetXssMatchSetError {
     }
 }
 impl Error for GetXssMatchSetError {}
+impl ServiceErrorCode for GetXssMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetXssMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            GetXssMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            GetXssMatchSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by ListActivatedRulesInRuleGroup
 #[derive(Debug, PartialEq)]
 pub enum ListActivatedRulesInRuleGroupError {
@@ -5465,6 +6008,19 @@ impl fmt::Display for ListActivatedRulesInRuleGroupError {
     }
 }
 impl Error for ListActivatedRulesInRuleGroupError {}
+impl ServiceErrorCode for ListActivatedRulesInRuleGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListActivatedRulesInRuleGroupError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListActivatedRulesInRuleGroupError::WAFInvalidParameter(_) => {
+                "WAFInvalidParameterException"
+            }
+            ListActivatedRulesInRuleGroupError::WAFNonexistentItem(_) => {
+                "WAFNonexistentItemException"
+            }
+        }
+    }
+}
 /// Errors returned by ListByteMatchSets
 #[derive(Debug, PartialEq)]
 pub enum ListByteMatchSetsError {
@@ -5501,6 +6057,14 @@ impl fmt::Display for ListByteMatchSetsError {
     }
 }
 impl Error for ListByteMatchSetsError {}
+impl ServiceErrorCode for ListByteMatchSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListByteMatchSetsError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListByteMatchSetsError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+        }
+    }
+}
 /// Errors returned by ListGeoMatchSets
 #[derive(Debug, PartialEq)]
 pub enum ListGeoMatchSetsError {
@@ -5537,6 +6101,14 @@ impl fmt::Display for ListGeoMatchSetsError {
     }
 }
 impl Error for ListGeoMatchSetsError {}
+impl ServiceErrorCode for ListGeoMatchSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGeoMatchSetsError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListGeoMatchSetsError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+        }
+    }
+}
 /// Errors returned by ListIPSets
 #[derive(Debug, PartialEq)]
 pub enum ListIPSetsError {
@@ -5573,6 +6145,14 @@ impl fmt::Display for ListIPSetsError {
     }
 }
 impl Error for ListIPSetsError {}
+impl ServiceErrorCode for ListIPSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListIPSetsError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListIPSetsError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+        }
+    }
+}
 /// Errors returned by ListLoggingConfigurations
 #[derive(Debug, PartialEq)]
 pub enum ListLoggingConfigurationsError {
@@ -5623,6 +6203,17 @@ impl fmt::Display for ListLoggingConfigurationsError {
     }
 }
 impl Error for ListLoggingConfigurationsError {}
+impl ServiceErrorCode for ListLoggingConfigurationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListLoggingConfigurationsError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListLoggingConfigurationsError::WAFInvalidParameter(_) => {
+                "WAFInvalidParameterException"
+            }
+            ListLoggingConfigurationsError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by ListRateBasedRules
 #[derive(Debug, PartialEq)]
 pub enum ListRateBasedRulesError {
@@ -5661,6 +6252,14 @@ impl fmt::Display for ListRateBasedRulesError {
     }
 }
 impl Error for ListRateBasedRulesError {}
+impl ServiceErrorCode for ListRateBasedRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRateBasedRulesError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListRateBasedRulesError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+        }
+    }
+}
 /// Errors returned by ListRegexMatchSets
 #[derive(Debug, PartialEq)]
 pub enum ListRegexMatchSetsError {
@@ -5699,6 +6298,14 @@ impl fmt::Display for ListRegexMatchSetsError {
     }
 }
 impl Error for ListRegexMatchSetsError {}
+impl ServiceErrorCode for ListRegexMatchSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRegexMatchSetsError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListRegexMatchSetsError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+        }
+    }
+}
 /// Errors returned by ListRegexPatternSets
 #[derive(Debug, PartialEq)]
 pub enum ListRegexPatternSetsError {
@@ -5739,6 +6346,14 @@ impl fmt::Display for ListRegexPatternSetsError {
     }
 }
 impl Error for ListRegexPatternSetsError {}
+impl ServiceErrorCode for ListRegexPatternSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRegexPatternSetsError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListRegexPatternSetsError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+        }
+    }
+}
 /// Errors returned by ListResourcesForWebACL
 #[derive(Debug, PartialEq)]
 pub enum ListResourcesForWebACLError {
@@ -5795,6 +6410,16 @@ impl fmt::Display for ListResourcesForWebACLError {
     }
 }
 impl Error for ListResourcesForWebACLError {}
+impl ServiceErrorCode for ListResourcesForWebACLError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourcesForWebACLError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListResourcesForWebACLError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            ListResourcesForWebACLError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            ListResourcesForWebACLError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by ListRuleGroups
 #[derive(Debug, PartialEq)]
 pub enum ListRuleGroupsError {
@@ -5825,6 +6450,13 @@ impl fmt::Display for ListRuleGroupsError {
     }
 }
 impl Error for ListRuleGroupsError {}
+impl ServiceErrorCode for ListRuleGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRuleGroupsError::WAFInternalError(_) => "WAFInternalErrorException",
+        }
+    }
+}
 /// Errors returned by ListRules
 #[derive(Debug, PartialEq)]
 pub enum ListRulesError {
@@ -5861,6 +6493,14 @@ impl fmt::Display for ListRulesError {
     }
 }
 impl Error for ListRulesError {}
+impl ServiceErrorCode for ListRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListRulesError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListRulesError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+        }
+    }
+}
 /// Errors returned by ListSizeConstraintSets
 #[derive(Debug, PartialEq)]
 pub enum ListSizeConstraintSetsError {
@@ -5901,6 +6541,14 @@ impl fmt::Display for ListSizeConstraintSetsError {
     }
 }
 impl Error for ListSizeConstraintSetsError {}
+impl ServiceErrorCode for ListSizeConstraintSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSizeConstraintSetsError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListSizeConstraintSetsError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+        }
+    }
+}
 /// Errors returned by ListSqlInjectionMatchSets
 #[derive(Debug, PartialEq)]
 pub enum ListSqlInjectionMatchSetsError {
@@ -5941,6 +6589,14 @@ impl fmt::Display for ListSqlInjectionMatchSetsError {
     }
 }
 impl Error for ListSqlInjectionMatchSetsError {}
+impl ServiceErrorCode for ListSqlInjectionMatchSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSqlInjectionMatchSetsError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListSqlInjectionMatchSetsError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+        }
+    }
+}
 /// Errors returned by ListSubscribedRuleGroups
 #[derive(Debug, PartialEq)]
 pub enum ListSubscribedRuleGroupsError {
@@ -5981,6 +6637,14 @@ impl fmt::Display for ListSubscribedRuleGroupsError {
     }
 }
 impl Error for ListSubscribedRuleGroupsError {}
+impl ServiceErrorCode for ListSubscribedRuleGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListSubscribedRuleGroupsError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListSubscribedRuleGroupsError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -6051,6 +6715,20 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::WAFBadRequest(_) => "WAFBadRequestException",
+            ListTagsForResourceError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListTagsForResourceError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            ListTagsForResourceError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            ListTagsForResourceError::WAFTagOperation(_) => "WAFTagOperationException",
+            ListTagsForResourceError::WAFTagOperationInternalError(_) => {
+                "WAFTagOperationInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by ListWebACLs
 #[derive(Debug, PartialEq)]
 pub enum ListWebACLsError {
@@ -6087,6 +6765,14 @@ impl fmt::Display for ListWebACLsError {
     }
 }
 impl Error for ListWebACLsError {}
+impl ServiceErrorCode for ListWebACLsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListWebACLsError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListWebACLsError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+        }
+    }
+}
 /// Errors returned by ListXssMatchSets
 #[derive(Debug, PartialEq)]
 pub enum ListXssMatchSetsError {
@@ -6123,6 +6809,14 @@ impl fmt::Display for ListXssMatchSetsError {
     }
 }
 impl Error for ListXssMatchSetsError {}
+impl ServiceErrorCode for ListXssMatchSetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListXssMatchSetsError::WAFInternalError(_) => "WAFInternalErrorException",
+            ListXssMatchSetsError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+        }
+    }
+}
 /// Errors returned by PutLoggingConfiguration
 #[derive(Debug, PartialEq)]
 pub enum PutLoggingConfigurationError {
@@ -6181,6 +6875,18 @@ impl fmt::Display for PutLoggingConfigurationError {
     }
 }
 impl Error for PutLoggingConfigurationError {}
+impl ServiceErrorCode for PutLoggingConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutLoggingConfigurationError::WAFInternalError(_) => "WAFInternalErrorException",
+            PutLoggingConfigurationError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            PutLoggingConfigurationError::WAFServiceLinkedRoleError(_) => {
+                "WAFServiceLinkedRoleErrorException"
+            }
+            PutLoggingConfigurationError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by PutPermissionPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutPermissionPolicyError {
@@ -6237,6 +6943,18 @@ impl fmt::Display for PutPermissionPolicyError {
     }
 }
 impl Error for PutPermissionPolicyError {}
+impl ServiceErrorCode for PutPermissionPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutPermissionPolicyError::WAFInternalError(_) => "WAFInternalErrorException",
+            PutPermissionPolicyError::WAFInvalidPermissionPolicy(_) => {
+                "WAFInvalidPermissionPolicyException"
+            }
+            PutPermissionPolicyError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            PutPermissionPolicyError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -6305,6 +7023,21 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::WAFBadRequest(_) => "WAFBadRequestException",
+            TagResourceError::WAFInternalError(_) => "WAFInternalErrorException",
+            TagResourceError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            TagResourceError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            TagResourceError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            TagResourceError::WAFTagOperation(_) => "WAFTagOperationException",
+            TagResourceError::WAFTagOperationInternalError(_) => {
+                "WAFTagOperationInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -6367,6 +7100,20 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::WAFBadRequest(_) => "WAFBadRequestException",
+            UntagResourceError::WAFInternalError(_) => "WAFInternalErrorException",
+            UntagResourceError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            UntagResourceError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            UntagResourceError::WAFTagOperation(_) => "WAFTagOperationException",
+            UntagResourceError::WAFTagOperationInternalError(_) => {
+                "WAFTagOperationInternalErrorException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateByteMatchSet
 #[derive(Debug, PartialEq)]
 pub enum UpdateByteMatchSetError {
@@ -6451,6 +7198,22 @@ impl fmt::Display for UpdateByteMatchSetError {
     }
 }
 impl Error for UpdateByteMatchSetError {}
+impl ServiceErrorCode for UpdateByteMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateByteMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            UpdateByteMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            UpdateByteMatchSetError::WAFInvalidOperation(_) => "WAFInvalidOperationException",
+            UpdateByteMatchSetError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            UpdateByteMatchSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            UpdateByteMatchSetError::WAFNonexistentContainer(_) => {
+                "WAFNonexistentContainerException"
+            }
+            UpdateByteMatchSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            UpdateByteMatchSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by UpdateGeoMatchSet
 #[derive(Debug, PartialEq)]
 pub enum UpdateGeoMatchSetError {
@@ -6537,6 +7300,23 @@ impl fmt::Display for UpdateGeoMatchSetError {
     }
 }
 impl Error for UpdateGeoMatchSetError {}
+impl ServiceErrorCode for UpdateGeoMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGeoMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            UpdateGeoMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            UpdateGeoMatchSetError::WAFInvalidOperation(_) => "WAFInvalidOperationException",
+            UpdateGeoMatchSetError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            UpdateGeoMatchSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            UpdateGeoMatchSetError::WAFNonexistentContainer(_) => {
+                "WAFNonexistentContainerException"
+            }
+            UpdateGeoMatchSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            UpdateGeoMatchSetError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            UpdateGeoMatchSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by UpdateIPSet
 #[derive(Debug, PartialEq)]
 pub enum UpdateIPSetError {
@@ -6615,6 +7395,21 @@ impl fmt::Display for UpdateIPSetError {
     }
 }
 impl Error for UpdateIPSetError {}
+impl ServiceErrorCode for UpdateIPSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateIPSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            UpdateIPSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            UpdateIPSetError::WAFInvalidOperation(_) => "WAFInvalidOperationException",
+            UpdateIPSetError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            UpdateIPSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            UpdateIPSetError::WAFNonexistentContainer(_) => "WAFNonexistentContainerException",
+            UpdateIPSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            UpdateIPSetError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            UpdateIPSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by UpdateRateBasedRule
 #[derive(Debug, PartialEq)]
 pub enum UpdateRateBasedRuleError {
@@ -6709,6 +7504,23 @@ impl fmt::Display for UpdateRateBasedRuleError {
     }
 }
 impl Error for UpdateRateBasedRuleError {}
+impl ServiceErrorCode for UpdateRateBasedRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRateBasedRuleError::WAFInternalError(_) => "WAFInternalErrorException",
+            UpdateRateBasedRuleError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            UpdateRateBasedRuleError::WAFInvalidOperation(_) => "WAFInvalidOperationException",
+            UpdateRateBasedRuleError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            UpdateRateBasedRuleError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            UpdateRateBasedRuleError::WAFNonexistentContainer(_) => {
+                "WAFNonexistentContainerException"
+            }
+            UpdateRateBasedRuleError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            UpdateRateBasedRuleError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            UpdateRateBasedRuleError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by UpdateRegexMatchSet
 #[derive(Debug, PartialEq)]
 pub enum UpdateRegexMatchSetError {
@@ -6795,6 +7607,22 @@ impl fmt::Display for UpdateRegexMatchSetError {
     }
 }
 impl Error for UpdateRegexMatchSetError {}
+impl ServiceErrorCode for UpdateRegexMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRegexMatchSetError::WAFDisallowedName(_) => "WAFDisallowedNameException",
+            UpdateRegexMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            UpdateRegexMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            UpdateRegexMatchSetError::WAFInvalidOperation(_) => "WAFInvalidOperationException",
+            UpdateRegexMatchSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            UpdateRegexMatchSetError::WAFNonexistentContainer(_) => {
+                "WAFNonexistentContainerException"
+            }
+            UpdateRegexMatchSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            UpdateRegexMatchSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by UpdateRegexPatternSet
 #[derive(Debug, PartialEq)]
 pub enum UpdateRegexPatternSetError {
@@ -6883,6 +7711,24 @@ impl fmt::Display for UpdateRegexPatternSetError {
     }
 }
 impl Error for UpdateRegexPatternSetError {}
+impl ServiceErrorCode for UpdateRegexPatternSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRegexPatternSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            UpdateRegexPatternSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            UpdateRegexPatternSetError::WAFInvalidOperation(_) => "WAFInvalidOperationException",
+            UpdateRegexPatternSetError::WAFInvalidRegexPattern(_) => {
+                "WAFInvalidRegexPatternException"
+            }
+            UpdateRegexPatternSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            UpdateRegexPatternSetError::WAFNonexistentContainer(_) => {
+                "WAFNonexistentContainerException"
+            }
+            UpdateRegexPatternSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            UpdateRegexPatternSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by UpdateRule
 #[derive(Debug, PartialEq)]
 pub enum UpdateRuleError {
@@ -6961,6 +7807,21 @@ impl fmt::Display for UpdateRuleError {
     }
 }
 impl Error for UpdateRuleError {}
+impl ServiceErrorCode for UpdateRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRuleError::WAFInternalError(_) => "WAFInternalErrorException",
+            UpdateRuleError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            UpdateRuleError::WAFInvalidOperation(_) => "WAFInvalidOperationException",
+            UpdateRuleError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            UpdateRuleError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            UpdateRuleError::WAFNonexistentContainer(_) => "WAFNonexistentContainerException",
+            UpdateRuleError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            UpdateRuleError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            UpdateRuleError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by UpdateRuleGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateRuleGroupError {
@@ -7029,6 +7890,19 @@ impl fmt::Display for UpdateRuleGroupError {
     }
 }
 impl Error for UpdateRuleGroupError {}
+impl ServiceErrorCode for UpdateRuleGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRuleGroupError::WAFInternalError(_) => "WAFInternalErrorException",
+            UpdateRuleGroupError::WAFInvalidOperation(_) => "WAFInvalidOperationException",
+            UpdateRuleGroupError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            UpdateRuleGroupError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            UpdateRuleGroupError::WAFNonexistentContainer(_) => "WAFNonexistentContainerException",
+            UpdateRuleGroupError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            UpdateRuleGroupError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by UpdateSizeConstraintSet
 #[derive(Debug, PartialEq)]
 pub enum UpdateSizeConstraintSetError {
@@ -7127,6 +8001,23 @@ impl fmt::Display for UpdateSizeConstraintSetError {
     }
 }
 impl Error for UpdateSizeConstraintSetError {}
+impl ServiceErrorCode for UpdateSizeConstraintSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSizeConstraintSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            UpdateSizeConstraintSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            UpdateSizeConstraintSetError::WAFInvalidOperation(_) => "WAFInvalidOperationException",
+            UpdateSizeConstraintSetError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            UpdateSizeConstraintSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            UpdateSizeConstraintSetError::WAFNonexistentContainer(_) => {
+                "WAFNonexistentContainerException"
+            }
+            UpdateSizeConstraintSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            UpdateSizeConstraintSetError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            UpdateSizeConstraintSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by UpdateSqlInjectionMatchSet
 #[derive(Debug, PartialEq)]
 pub enum UpdateSqlInjectionMatchSetError {
@@ -7225,6 +8116,26 @@ impl fmt::Display for UpdateSqlInjectionMatchSetError {
     }
 }
 impl Error for UpdateSqlInjectionMatchSetError {}
+impl ServiceErrorCode for UpdateSqlInjectionMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSqlInjectionMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            UpdateSqlInjectionMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            UpdateSqlInjectionMatchSetError::WAFInvalidOperation(_) => {
+                "WAFInvalidOperationException"
+            }
+            UpdateSqlInjectionMatchSetError::WAFInvalidParameter(_) => {
+                "WAFInvalidParameterException"
+            }
+            UpdateSqlInjectionMatchSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            UpdateSqlInjectionMatchSetError::WAFNonexistentContainer(_) => {
+                "WAFNonexistentContainerException"
+            }
+            UpdateSqlInjectionMatchSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            UpdateSqlInjectionMatchSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Errors returned by UpdateWebACL
 #[derive(Debug, PartialEq)]
 pub enum UpdateWebACLError {
@@ -7313,6 +8224,22 @@ impl fmt::Display for UpdateWebACLError {
     }
 }
 impl Error for UpdateWebACLError {}
+impl ServiceErrorCode for UpdateWebACLError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateWebACLError::WAFInternalError(_) => "WAFInternalErrorException",
+            UpdateWebACLError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            UpdateWebACLError::WAFInvalidOperation(_) => "WAFInvalidOperationException",
+            UpdateWebACLError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            UpdateWebACLError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            UpdateWebACLError::WAFNonexistentContainer(_) => "WAFNonexistentContainerException",
+            UpdateWebACLError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            UpdateWebACLError::WAFReferencedItem(_) => "WAFReferencedItemException",
+            UpdateWebACLError::WAFStaleData(_) => "WAFStaleDataException",
+            UpdateWebACLError::WAFSubscriptionNotFound(_) => "WAFSubscriptionNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateXssMatchSet
 #[derive(Debug, PartialEq)]
 pub enum UpdateXssMatchSetError {
@@ -7393,6 +8320,22 @@ impl fmt::Display for UpdateXssMatchSetError {
     }
 }
 impl Error for UpdateXssMatchSetError {}
+impl ServiceErrorCode for UpdateXssMatchSetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateXssMatchSetError::WAFInternalError(_) => "WAFInternalErrorException",
+            UpdateXssMatchSetError::WAFInvalidAccount(_) => "WAFInvalidAccountException",
+            UpdateXssMatchSetError::WAFInvalidOperation(_) => "WAFInvalidOperationException",
+            UpdateXssMatchSetError::WAFInvalidParameter(_) => "WAFInvalidParameterException",
+            UpdateXssMatchSetError::WAFLimitsExceeded(_) => "WAFLimitsExceededException",
+            UpdateXssMatchSetError::WAFNonexistentContainer(_) => {
+                "WAFNonexistentContainerException"
+            }
+            UpdateXssMatchSetError::WAFNonexistentItem(_) => "WAFNonexistentItemException",
+            UpdateXssMatchSetError::WAFStaleData(_) => "WAFStaleDataException",
+        }
+    }
+}
 /// Trait representing the capabilities of the WAF Regional API. WAF Regional clients implement this trait.
 #[async_trait]
 pub trait WAFRegional {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1868,6 +1868,22 @@ impl fmt::Display for AbortDocumentVersionUploadError {
     }
 }
 impl Error for AbortDocumentVersionUploadError {}
+impl ServiceErrorCode for AbortDocumentVersionUploadError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AbortDocumentVersionUploadError::EntityNotExists(_) => "EntityNotExistsException",
+            AbortDocumentVersionUploadError::FailedDependency(_) => "FailedDependencyException",
+            AbortDocumentVersionUploadError::ProhibitedState(_) => "ProhibitedStateException",
+            AbortDocumentVersionUploadError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            AbortDocumentVersionUploadError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+            AbortDocumentVersionUploadError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by ActivateUser
 #[derive(Debug, PartialEq)]
 pub enum ActivateUserError {
@@ -1924,6 +1940,19 @@ impl fmt::Display for ActivateUserError {
     }
 }
 impl Error for ActivateUserError {}
+impl ServiceErrorCode for ActivateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ActivateUserError::EntityNotExists(_) => "EntityNotExistsException",
+            ActivateUserError::FailedDependency(_) => "FailedDependencyException",
+            ActivateUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            ActivateUserError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            ActivateUserError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by AddResourcePermissions
 #[derive(Debug, PartialEq)]
 pub enum AddResourcePermissionsError {
@@ -1982,6 +2011,20 @@ impl fmt::Display for AddResourcePermissionsError {
     }
 }
 impl Error for AddResourcePermissionsError {}
+impl ServiceErrorCode for AddResourcePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AddResourcePermissionsError::FailedDependency(_) => "FailedDependencyException",
+            AddResourcePermissionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            AddResourcePermissionsError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+            AddResourcePermissionsError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateComment
 #[derive(Debug, PartialEq)]
 pub enum CreateCommentError {
@@ -2060,6 +2103,24 @@ impl fmt::Display for CreateCommentError {
     }
 }
 impl Error for CreateCommentError {}
+impl ServiceErrorCode for CreateCommentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCommentError::DocumentLockedForComments(_) => {
+                "DocumentLockedForCommentsException"
+            }
+            CreateCommentError::EntityNotExists(_) => "EntityNotExistsException",
+            CreateCommentError::FailedDependency(_) => "FailedDependencyException",
+            CreateCommentError::InvalidCommentOperation(_) => "InvalidCommentOperationException",
+            CreateCommentError::ProhibitedState(_) => "ProhibitedStateException",
+            CreateCommentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateCommentError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            CreateCommentError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateCustomMetadata
 #[derive(Debug, PartialEq)]
 pub enum CreateCustomMetadataError {
@@ -2144,6 +2205,23 @@ impl fmt::Display for CreateCustomMetadataError {
     }
 }
 impl Error for CreateCustomMetadataError {}
+impl ServiceErrorCode for CreateCustomMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateCustomMetadataError::CustomMetadataLimitExceeded(_) => {
+                "CustomMetadataLimitExceededException"
+            }
+            CreateCustomMetadataError::EntityNotExists(_) => "EntityNotExistsException",
+            CreateCustomMetadataError::FailedDependency(_) => "FailedDependencyException",
+            CreateCustomMetadataError::ProhibitedState(_) => "ProhibitedStateException",
+            CreateCustomMetadataError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateCustomMetadataError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            CreateCustomMetadataError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateFolder
 #[derive(Debug, PartialEq)]
 pub enum CreateFolderError {
@@ -2224,6 +2302,23 @@ impl fmt::Display for CreateFolderError {
     }
 }
 impl Error for CreateFolderError {}
+impl ServiceErrorCode for CreateFolderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFolderError::ConflictingOperation(_) => "ConflictingOperationException",
+            CreateFolderError::EntityAlreadyExists(_) => "EntityAlreadyExistsException",
+            CreateFolderError::EntityNotExists(_) => "EntityNotExistsException",
+            CreateFolderError::FailedDependency(_) => "FailedDependencyException",
+            CreateFolderError::LimitExceeded(_) => "LimitExceededException",
+            CreateFolderError::ProhibitedState(_) => "ProhibitedStateException",
+            CreateFolderError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateFolderError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            CreateFolderError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateLabels
 #[derive(Debug, PartialEq)]
 pub enum CreateLabelsError {
@@ -2286,6 +2381,20 @@ impl fmt::Display for CreateLabelsError {
     }
 }
 impl Error for CreateLabelsError {}
+impl ServiceErrorCode for CreateLabelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateLabelsError::EntityNotExists(_) => "EntityNotExistsException",
+            CreateLabelsError::FailedDependency(_) => "FailedDependencyException",
+            CreateLabelsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateLabelsError::TooManyLabels(_) => "TooManyLabelsException",
+            CreateLabelsError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            CreateLabelsError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateNotificationSubscription
 #[derive(Debug, PartialEq)]
 pub enum CreateNotificationSubscriptionError {
@@ -2342,6 +2451,21 @@ impl fmt::Display for CreateNotificationSubscriptionError {
     }
 }
 impl Error for CreateNotificationSubscriptionError {}
+impl ServiceErrorCode for CreateNotificationSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateNotificationSubscriptionError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            CreateNotificationSubscriptionError::TooManySubscriptions(_) => {
+                "TooManySubscriptionsException"
+            }
+            CreateNotificationSubscriptionError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
 pub enum CreateUserError {
@@ -2398,6 +2522,17 @@ impl fmt::Display for CreateUserError {
     }
 }
 impl Error for CreateUserError {}
+impl ServiceErrorCode for CreateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserError::EntityAlreadyExists(_) => "EntityAlreadyExistsException",
+            CreateUserError::FailedDependency(_) => "FailedDependencyException",
+            CreateUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CreateUserError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            CreateUserError::UnauthorizedResourceAccess(_) => "UnauthorizedResourceAccessException",
+        }
+    }
+}
 /// Errors returned by DeactivateUser
 #[derive(Debug, PartialEq)]
 pub enum DeactivateUserError {
@@ -2456,6 +2591,19 @@ impl fmt::Display for DeactivateUserError {
     }
 }
 impl Error for DeactivateUserError {}
+impl ServiceErrorCode for DeactivateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeactivateUserError::EntityNotExists(_) => "EntityNotExistsException",
+            DeactivateUserError::FailedDependency(_) => "FailedDependencyException",
+            DeactivateUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeactivateUserError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            DeactivateUserError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteComment
 #[derive(Debug, PartialEq)]
 pub enum DeleteCommentError {
@@ -2526,6 +2674,23 @@ impl fmt::Display for DeleteCommentError {
     }
 }
 impl Error for DeleteCommentError {}
+impl ServiceErrorCode for DeleteCommentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCommentError::DocumentLockedForComments(_) => {
+                "DocumentLockedForCommentsException"
+            }
+            DeleteCommentError::EntityNotExists(_) => "EntityNotExistsException",
+            DeleteCommentError::FailedDependency(_) => "FailedDependencyException",
+            DeleteCommentError::ProhibitedState(_) => "ProhibitedStateException",
+            DeleteCommentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteCommentError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            DeleteCommentError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteCustomMetadata
 #[derive(Debug, PartialEq)]
 pub enum DeleteCustomMetadataError {
@@ -2600,6 +2765,20 @@ impl fmt::Display for DeleteCustomMetadataError {
     }
 }
 impl Error for DeleteCustomMetadataError {}
+impl ServiceErrorCode for DeleteCustomMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteCustomMetadataError::EntityNotExists(_) => "EntityNotExistsException",
+            DeleteCustomMetadataError::FailedDependency(_) => "FailedDependencyException",
+            DeleteCustomMetadataError::ProhibitedState(_) => "ProhibitedStateException",
+            DeleteCustomMetadataError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteCustomMetadataError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            DeleteCustomMetadataError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteDocument
 #[derive(Debug, PartialEq)]
 pub enum DeleteDocumentError {
@@ -2678,6 +2857,22 @@ impl fmt::Display for DeleteDocumentError {
     }
 }
 impl Error for DeleteDocumentError {}
+impl ServiceErrorCode for DeleteDocumentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteDocumentError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteDocumentError::ConflictingOperation(_) => "ConflictingOperationException",
+            DeleteDocumentError::EntityNotExists(_) => "EntityNotExistsException",
+            DeleteDocumentError::FailedDependency(_) => "FailedDependencyException",
+            DeleteDocumentError::ProhibitedState(_) => "ProhibitedStateException",
+            DeleteDocumentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteDocumentError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            DeleteDocumentError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteFolder
 #[derive(Debug, PartialEq)]
 pub enum DeleteFolderError {
@@ -2752,6 +2947,22 @@ impl fmt::Display for DeleteFolderError {
     }
 }
 impl Error for DeleteFolderError {}
+impl ServiceErrorCode for DeleteFolderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFolderError::ConcurrentModification(_) => "ConcurrentModificationException",
+            DeleteFolderError::ConflictingOperation(_) => "ConflictingOperationException",
+            DeleteFolderError::EntityNotExists(_) => "EntityNotExistsException",
+            DeleteFolderError::FailedDependency(_) => "FailedDependencyException",
+            DeleteFolderError::ProhibitedState(_) => "ProhibitedStateException",
+            DeleteFolderError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteFolderError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            DeleteFolderError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteFolderContents
 #[derive(Debug, PartialEq)]
 pub enum DeleteFolderContentsError {
@@ -2834,6 +3045,21 @@ impl fmt::Display for DeleteFolderContentsError {
     }
 }
 impl Error for DeleteFolderContentsError {}
+impl ServiceErrorCode for DeleteFolderContentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFolderContentsError::ConflictingOperation(_) => "ConflictingOperationException",
+            DeleteFolderContentsError::EntityNotExists(_) => "EntityNotExistsException",
+            DeleteFolderContentsError::FailedDependency(_) => "FailedDependencyException",
+            DeleteFolderContentsError::ProhibitedState(_) => "ProhibitedStateException",
+            DeleteFolderContentsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteFolderContentsError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            DeleteFolderContentsError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteLabels
 #[derive(Debug, PartialEq)]
 pub enum DeleteLabelsError {
@@ -2890,6 +3116,19 @@ impl fmt::Display for DeleteLabelsError {
     }
 }
 impl Error for DeleteLabelsError {}
+impl ServiceErrorCode for DeleteLabelsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteLabelsError::EntityNotExists(_) => "EntityNotExistsException",
+            DeleteLabelsError::FailedDependency(_) => "FailedDependencyException",
+            DeleteLabelsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteLabelsError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            DeleteLabelsError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteNotificationSubscription
 #[derive(Debug, PartialEq)]
 pub enum DeleteNotificationSubscriptionError {
@@ -2956,6 +3195,20 @@ impl fmt::Display for DeleteNotificationSubscriptionError {
     }
 }
 impl Error for DeleteNotificationSubscriptionError {}
+impl ServiceErrorCode for DeleteNotificationSubscriptionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteNotificationSubscriptionError::EntityNotExists(_) => "EntityNotExistsException",
+            DeleteNotificationSubscriptionError::ProhibitedState(_) => "ProhibitedStateException",
+            DeleteNotificationSubscriptionError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DeleteNotificationSubscriptionError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserError {
@@ -3012,6 +3265,17 @@ impl fmt::Display for DeleteUserError {
     }
 }
 impl Error for DeleteUserError {}
+impl ServiceErrorCode for DeleteUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserError::EntityNotExists(_) => "EntityNotExistsException",
+            DeleteUserError::FailedDependency(_) => "FailedDependencyException",
+            DeleteUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DeleteUserError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            DeleteUserError::UnauthorizedResourceAccess(_) => "UnauthorizedResourceAccessException",
+        }
+    }
+}
 /// Errors returned by DescribeActivities
 #[derive(Debug, PartialEq)]
 pub enum DescribeActivitiesError {
@@ -3074,6 +3338,19 @@ impl fmt::Display for DescribeActivitiesError {
     }
 }
 impl Error for DescribeActivitiesError {}
+impl ServiceErrorCode for DescribeActivitiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeActivitiesError::FailedDependency(_) => "FailedDependencyException",
+            DescribeActivitiesError::InvalidArgument(_) => "InvalidArgumentException",
+            DescribeActivitiesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeActivitiesError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            DescribeActivitiesError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeComments
 #[derive(Debug, PartialEq)]
 pub enum DescribeCommentsError {
@@ -3138,6 +3415,20 @@ impl fmt::Display for DescribeCommentsError {
     }
 }
 impl Error for DescribeCommentsError {}
+impl ServiceErrorCode for DescribeCommentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCommentsError::EntityNotExists(_) => "EntityNotExistsException",
+            DescribeCommentsError::FailedDependency(_) => "FailedDependencyException",
+            DescribeCommentsError::ProhibitedState(_) => "ProhibitedStateException",
+            DescribeCommentsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeCommentsError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            DescribeCommentsError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeDocumentVersions
 #[derive(Debug, PartialEq)]
 pub enum DescribeDocumentVersionsError {
@@ -3222,6 +3513,23 @@ impl fmt::Display for DescribeDocumentVersionsError {
     }
 }
 impl Error for DescribeDocumentVersionsError {}
+impl ServiceErrorCode for DescribeDocumentVersionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDocumentVersionsError::EntityNotExists(_) => "EntityNotExistsException",
+            DescribeDocumentVersionsError::FailedDependency(_) => "FailedDependencyException",
+            DescribeDocumentVersionsError::InvalidArgument(_) => "InvalidArgumentException",
+            DescribeDocumentVersionsError::ProhibitedState(_) => "ProhibitedStateException",
+            DescribeDocumentVersionsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeDocumentVersionsError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+            DescribeDocumentVersionsError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeFolderContents
 #[derive(Debug, PartialEq)]
 pub enum DescribeFolderContentsError {
@@ -3296,6 +3604,20 @@ impl fmt::Display for DescribeFolderContentsError {
     }
 }
 impl Error for DescribeFolderContentsError {}
+impl ServiceErrorCode for DescribeFolderContentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFolderContentsError::EntityNotExists(_) => "EntityNotExistsException",
+            DescribeFolderContentsError::FailedDependency(_) => "FailedDependencyException",
+            DescribeFolderContentsError::InvalidArgument(_) => "InvalidArgumentException",
+            DescribeFolderContentsError::ProhibitedState(_) => "ProhibitedStateException",
+            DescribeFolderContentsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeFolderContentsError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeGroupsError {
@@ -3348,6 +3670,18 @@ impl fmt::Display for DescribeGroupsError {
     }
 }
 impl Error for DescribeGroupsError {}
+impl ServiceErrorCode for DescribeGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGroupsError::FailedDependency(_) => "FailedDependencyException",
+            DescribeGroupsError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeGroupsError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            DescribeGroupsError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeNotificationSubscriptions
 #[derive(Debug, PartialEq)]
 pub enum DescribeNotificationSubscriptionsError {
@@ -3404,6 +3738,21 @@ impl fmt::Display for DescribeNotificationSubscriptionsError {
     }
 }
 impl Error for DescribeNotificationSubscriptionsError {}
+impl ServiceErrorCode for DescribeNotificationSubscriptionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeNotificationSubscriptionsError::EntityNotExists(_) => {
+                "EntityNotExistsException"
+            }
+            DescribeNotificationSubscriptionsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DescribeNotificationSubscriptionsError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeResourcePermissions
 #[derive(Debug, PartialEq)]
 pub enum DescribeResourcePermissionsError {
@@ -3468,6 +3817,22 @@ impl fmt::Display for DescribeResourcePermissionsError {
     }
 }
 impl Error for DescribeResourcePermissionsError {}
+impl ServiceErrorCode for DescribeResourcePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeResourcePermissionsError::FailedDependency(_) => "FailedDependencyException",
+            DescribeResourcePermissionsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            DescribeResourcePermissionsError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+            DescribeResourcePermissionsError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeRootFolders
 #[derive(Debug, PartialEq)]
 pub enum DescribeRootFoldersError {
@@ -3532,6 +3897,19 @@ impl fmt::Display for DescribeRootFoldersError {
     }
 }
 impl Error for DescribeRootFoldersError {}
+impl ServiceErrorCode for DescribeRootFoldersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeRootFoldersError::FailedDependency(_) => "FailedDependencyException",
+            DescribeRootFoldersError::InvalidArgument(_) => "InvalidArgumentException",
+            DescribeRootFoldersError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeRootFoldersError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            DescribeRootFoldersError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeUsers
 #[derive(Debug, PartialEq)]
 pub enum DescribeUsersError {
@@ -3602,6 +3980,21 @@ impl fmt::Display for DescribeUsersError {
     }
 }
 impl Error for DescribeUsersError {}
+impl ServiceErrorCode for DescribeUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUsersError::EntityNotExists(_) => "EntityNotExistsException",
+            DescribeUsersError::FailedDependency(_) => "FailedDependencyException",
+            DescribeUsersError::InvalidArgument(_) => "InvalidArgumentException",
+            DescribeUsersError::RequestedEntityTooLarge(_) => "RequestedEntityTooLargeException",
+            DescribeUsersError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            DescribeUsersError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            DescribeUsersError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by GetCurrentUser
 #[derive(Debug, PartialEq)]
 pub enum GetCurrentUserError {
@@ -3660,6 +4053,19 @@ impl fmt::Display for GetCurrentUserError {
     }
 }
 impl Error for GetCurrentUserError {}
+impl ServiceErrorCode for GetCurrentUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetCurrentUserError::EntityNotExists(_) => "EntityNotExistsException",
+            GetCurrentUserError::FailedDependency(_) => "FailedDependencyException",
+            GetCurrentUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetCurrentUserError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            GetCurrentUserError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by GetDocument
 #[derive(Debug, PartialEq)]
 pub enum GetDocumentError {
@@ -3728,6 +4134,21 @@ impl fmt::Display for GetDocumentError {
     }
 }
 impl Error for GetDocumentError {}
+impl ServiceErrorCode for GetDocumentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDocumentError::EntityNotExists(_) => "EntityNotExistsException",
+            GetDocumentError::FailedDependency(_) => "FailedDependencyException",
+            GetDocumentError::InvalidArgument(_) => "InvalidArgumentException",
+            GetDocumentError::InvalidPassword(_) => "InvalidPasswordException",
+            GetDocumentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetDocumentError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            GetDocumentError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by GetDocumentPath
 #[derive(Debug, PartialEq)]
 pub enum GetDocumentPathError {
@@ -3786,6 +4207,19 @@ impl fmt::Display for GetDocumentPathError {
     }
 }
 impl Error for GetDocumentPathError {}
+impl ServiceErrorCode for GetDocumentPathError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDocumentPathError::EntityNotExists(_) => "EntityNotExistsException",
+            GetDocumentPathError::FailedDependency(_) => "FailedDependencyException",
+            GetDocumentPathError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetDocumentPathError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            GetDocumentPathError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by GetDocumentVersion
 #[derive(Debug, PartialEq)]
 pub enum GetDocumentVersionError {
@@ -3860,6 +4294,21 @@ impl fmt::Display for GetDocumentVersionError {
     }
 }
 impl Error for GetDocumentVersionError {}
+impl ServiceErrorCode for GetDocumentVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDocumentVersionError::EntityNotExists(_) => "EntityNotExistsException",
+            GetDocumentVersionError::FailedDependency(_) => "FailedDependencyException",
+            GetDocumentVersionError::InvalidPassword(_) => "InvalidPasswordException",
+            GetDocumentVersionError::ProhibitedState(_) => "ProhibitedStateException",
+            GetDocumentVersionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetDocumentVersionError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            GetDocumentVersionError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by GetFolder
 #[derive(Debug, PartialEq)]
 pub enum GetFolderError {
@@ -3928,6 +4377,19 @@ impl fmt::Display for GetFolderError {
     }
 }
 impl Error for GetFolderError {}
+impl ServiceErrorCode for GetFolderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFolderError::EntityNotExists(_) => "EntityNotExistsException",
+            GetFolderError::FailedDependency(_) => "FailedDependencyException",
+            GetFolderError::InvalidArgument(_) => "InvalidArgumentException",
+            GetFolderError::ProhibitedState(_) => "ProhibitedStateException",
+            GetFolderError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetFolderError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            GetFolderError::UnauthorizedResourceAccess(_) => "UnauthorizedResourceAccessException",
+        }
+    }
+}
 /// Errors returned by GetFolderPath
 #[derive(Debug, PartialEq)]
 pub enum GetFolderPathError {
@@ -3984,6 +4446,19 @@ impl fmt::Display for GetFolderPathError {
     }
 }
 impl Error for GetFolderPathError {}
+impl ServiceErrorCode for GetFolderPathError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetFolderPathError::EntityNotExists(_) => "EntityNotExistsException",
+            GetFolderPathError::FailedDependency(_) => "FailedDependencyException",
+            GetFolderPathError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetFolderPathError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            GetFolderPathError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by GetResources
 #[derive(Debug, PartialEq)]
 pub enum GetResourcesError {
@@ -4040,6 +4515,19 @@ impl fmt::Display for GetResourcesError {
     }
 }
 impl Error for GetResourcesError {}
+impl ServiceErrorCode for GetResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetResourcesError::FailedDependency(_) => "FailedDependencyException",
+            GetResourcesError::InvalidArgument(_) => "InvalidArgumentException",
+            GetResourcesError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            GetResourcesError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            GetResourcesError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by InitiateDocumentVersionUpload
 #[derive(Debug, PartialEq)]
 pub enum InitiateDocumentVersionUploadError {
@@ -4176,6 +4664,39 @@ impl fmt::Display for InitiateDocumentVersionUploadError {
     }
 }
 impl Error for InitiateDocumentVersionUploadError {}
+impl ServiceErrorCode for InitiateDocumentVersionUploadError {
+    fn error_code(&self) -> &str {
+        match *self {
+            InitiateDocumentVersionUploadError::DraftUploadOutOfSync(_) => {
+                "DraftUploadOutOfSyncException"
+            }
+            InitiateDocumentVersionUploadError::EntityAlreadyExists(_) => {
+                "EntityAlreadyExistsException"
+            }
+            InitiateDocumentVersionUploadError::EntityNotExists(_) => "EntityNotExistsException",
+            InitiateDocumentVersionUploadError::FailedDependency(_) => "FailedDependencyException",
+            InitiateDocumentVersionUploadError::ProhibitedState(_) => "ProhibitedStateException",
+            InitiateDocumentVersionUploadError::ResourceAlreadyCheckedOut(_) => {
+                "ResourceAlreadyCheckedOutException"
+            }
+            InitiateDocumentVersionUploadError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            InitiateDocumentVersionUploadError::StorageLimitExceeded(_) => {
+                "StorageLimitExceededException"
+            }
+            InitiateDocumentVersionUploadError::StorageLimitWillExceed(_) => {
+                "StorageLimitWillExceedException"
+            }
+            InitiateDocumentVersionUploadError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+            InitiateDocumentVersionUploadError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by RemoveAllResourcePermissions
 #[derive(Debug, PartialEq)]
 pub enum RemoveAllResourcePermissionsError {
@@ -4242,6 +4763,22 @@ impl fmt::Display for RemoveAllResourcePermissionsError {
     }
 }
 impl Error for RemoveAllResourcePermissionsError {}
+impl ServiceErrorCode for RemoveAllResourcePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveAllResourcePermissionsError::FailedDependency(_) => "FailedDependencyException",
+            RemoveAllResourcePermissionsError::ServiceUnavailable(_) => {
+                "ServiceUnavailableException"
+            }
+            RemoveAllResourcePermissionsError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+            RemoveAllResourcePermissionsError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by RemoveResourcePermission
 #[derive(Debug, PartialEq)]
 pub enum RemoveResourcePermissionError {
@@ -4302,6 +4839,20 @@ impl fmt::Display for RemoveResourcePermissionError {
     }
 }
 impl Error for RemoveResourcePermissionError {}
+impl ServiceErrorCode for RemoveResourcePermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RemoveResourcePermissionError::FailedDependency(_) => "FailedDependencyException",
+            RemoveResourcePermissionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            RemoveResourcePermissionError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+            RemoveResourcePermissionError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateDocument
 #[derive(Debug, PartialEq)]
 pub enum UpdateDocumentError {
@@ -4392,6 +4943,24 @@ impl fmt::Display for UpdateDocumentError {
     }
 }
 impl Error for UpdateDocumentError {}
+impl ServiceErrorCode for UpdateDocumentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDocumentError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateDocumentError::ConflictingOperation(_) => "ConflictingOperationException",
+            UpdateDocumentError::EntityAlreadyExists(_) => "EntityAlreadyExistsException",
+            UpdateDocumentError::EntityNotExists(_) => "EntityNotExistsException",
+            UpdateDocumentError::FailedDependency(_) => "FailedDependencyException",
+            UpdateDocumentError::LimitExceeded(_) => "LimitExceededException",
+            UpdateDocumentError::ProhibitedState(_) => "ProhibitedStateException",
+            UpdateDocumentError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateDocumentError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            UpdateDocumentError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateDocumentVersion
 #[derive(Debug, PartialEq)]
 pub enum UpdateDocumentVersionError {
@@ -4482,6 +5051,26 @@ impl fmt::Display for UpdateDocumentVersionError {
     }
 }
 impl Error for UpdateDocumentVersionError {}
+impl ServiceErrorCode for UpdateDocumentVersionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDocumentVersionError::ConcurrentModification(_) => {
+                "ConcurrentModificationException"
+            }
+            UpdateDocumentVersionError::EntityNotExists(_) => "EntityNotExistsException",
+            UpdateDocumentVersionError::FailedDependency(_) => "FailedDependencyException",
+            UpdateDocumentVersionError::InvalidOperation(_) => "InvalidOperationException",
+            UpdateDocumentVersionError::ProhibitedState(_) => "ProhibitedStateException",
+            UpdateDocumentVersionError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateDocumentVersionError::UnauthorizedOperation(_) => {
+                "UnauthorizedOperationException"
+            }
+            UpdateDocumentVersionError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateFolder
 #[derive(Debug, PartialEq)]
 pub enum UpdateFolderError {
@@ -4568,6 +5157,24 @@ impl fmt::Display for UpdateFolderError {
     }
 }
 impl Error for UpdateFolderError {}
+impl ServiceErrorCode for UpdateFolderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFolderError::ConcurrentModification(_) => "ConcurrentModificationException",
+            UpdateFolderError::ConflictingOperation(_) => "ConflictingOperationException",
+            UpdateFolderError::EntityAlreadyExists(_) => "EntityAlreadyExistsException",
+            UpdateFolderError::EntityNotExists(_) => "EntityNotExistsException",
+            UpdateFolderError::FailedDependency(_) => "FailedDependencyException",
+            UpdateFolderError::LimitExceeded(_) => "LimitExceededException",
+            UpdateFolderError::ProhibitedState(_) => "ProhibitedStateException",
+            UpdateFolderError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateFolderError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            UpdateFolderError::UnauthorizedResourceAccess(_) => {
+                "UnauthorizedResourceAccessException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateUser
 #[derive(Debug, PartialEq)]
 pub enum UpdateUserError {
@@ -4644,6 +5251,20 @@ impl fmt::Display for UpdateUserError {
     }
 }
 impl Error for UpdateUserError {}
+impl ServiceErrorCode for UpdateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateUserError::DeactivatingLastSystemUser(_) => "DeactivatingLastSystemUserException",
+            UpdateUserError::EntityNotExists(_) => "EntityNotExistsException",
+            UpdateUserError::FailedDependency(_) => "FailedDependencyException",
+            UpdateUserError::IllegalUserState(_) => "IllegalUserStateException",
+            UpdateUserError::InvalidArgument(_) => "InvalidArgumentException",
+            UpdateUserError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            UpdateUserError::UnauthorizedOperation(_) => "UnauthorizedOperationException",
+            UpdateUserError::UnauthorizedResourceAccess(_) => "UnauthorizedResourceAccessException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon WorkDocs API. Amazon WorkDocs clients implement this trait.
 #[async_trait]
 pub trait Workdocs {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -943,6 +943,18 @@ impl fmt::Display for AssociateDomainError {
     }
 }
 impl Error for AssociateDomainError {}
+impl ServiceErrorCode for AssociateDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateDomainError::InternalServerError(_) => "InternalServerErrorException",
+            AssociateDomainError::InvalidRequest(_) => "InvalidRequestException",
+            AssociateDomainError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            AssociateDomainError::ResourceNotFound(_) => "ResourceNotFoundException",
+            AssociateDomainError::TooManyRequests(_) => "TooManyRequestsException",
+            AssociateDomainError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by AssociateWebsiteAuthorizationProvider
 #[derive(Debug, PartialEq)]
 pub enum AssociateWebsiteAuthorizationProviderError {
@@ -1029,6 +1041,28 @@ impl fmt::Display for AssociateWebsiteAuthorizationProviderError {
     }
 }
 impl Error for AssociateWebsiteAuthorizationProviderError {}
+impl ServiceErrorCode for AssociateWebsiteAuthorizationProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateWebsiteAuthorizationProviderError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            AssociateWebsiteAuthorizationProviderError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            AssociateWebsiteAuthorizationProviderError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            AssociateWebsiteAuthorizationProviderError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            AssociateWebsiteAuthorizationProviderError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+            AssociateWebsiteAuthorizationProviderError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by AssociateWebsiteCertificateAuthority
 #[derive(Debug, PartialEq)]
 pub enum AssociateWebsiteCertificateAuthorityError {
@@ -1115,6 +1149,28 @@ impl fmt::Display for AssociateWebsiteCertificateAuthorityError {
     }
 }
 impl Error for AssociateWebsiteCertificateAuthorityError {}
+impl ServiceErrorCode for AssociateWebsiteCertificateAuthorityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateWebsiteCertificateAuthorityError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            AssociateWebsiteCertificateAuthorityError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            AssociateWebsiteCertificateAuthorityError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            AssociateWebsiteCertificateAuthorityError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            AssociateWebsiteCertificateAuthorityError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+            AssociateWebsiteCertificateAuthorityError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by CreateFleet
 #[derive(Debug, PartialEq)]
 pub enum CreateFleetError {
@@ -1175,6 +1231,18 @@ impl fmt::Display for CreateFleetError {
     }
 }
 impl Error for CreateFleetError {}
+impl ServiceErrorCode for CreateFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateFleetError::InternalServerError(_) => "InternalServerErrorException",
+            CreateFleetError::InvalidRequest(_) => "InvalidRequestException",
+            CreateFleetError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateFleetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CreateFleetError::TooManyRequests(_) => "TooManyRequestsException",
+            CreateFleetError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DeleteFleet
 #[derive(Debug, PartialEq)]
 pub enum DeleteFleetError {
@@ -1229,6 +1297,17 @@ impl fmt::Display for DeleteFleetError {
     }
 }
 impl Error for DeleteFleetError {}
+impl ServiceErrorCode for DeleteFleetError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteFleetError::InternalServerError(_) => "InternalServerErrorException",
+            DeleteFleetError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteFleetError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DeleteFleetError::TooManyRequests(_) => "TooManyRequestsException",
+            DeleteFleetError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeAuditStreamConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribeAuditStreamConfigurationError {
@@ -1305,6 +1384,21 @@ impl fmt::Display for DescribeAuditStreamConfigurationError {
     }
 }
 impl Error for DescribeAuditStreamConfigurationError {}
+impl ServiceErrorCode for DescribeAuditStreamConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAuditStreamConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DescribeAuditStreamConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeAuditStreamConfigurationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DescribeAuditStreamConfigurationError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribeAuditStreamConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeCompanyNetworkConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribeCompanyNetworkConfigurationError {
@@ -1381,6 +1475,25 @@ impl fmt::Display for DescribeCompanyNetworkConfigurationError {
     }
 }
 impl Error for DescribeCompanyNetworkConfigurationError {}
+impl ServiceErrorCode for DescribeCompanyNetworkConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeCompanyNetworkConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DescribeCompanyNetworkConfigurationError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            DescribeCompanyNetworkConfigurationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DescribeCompanyNetworkConfigurationError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+            DescribeCompanyNetworkConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeDevice
 #[derive(Debug, PartialEq)]
 pub enum DescribeDeviceError {
@@ -1435,6 +1548,17 @@ impl fmt::Display for DescribeDeviceError {
     }
 }
 impl Error for DescribeDeviceError {}
+impl ServiceErrorCode for DescribeDeviceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDeviceError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeDeviceError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeDeviceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDeviceError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribeDeviceError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeDevicePolicyConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribeDevicePolicyConfigurationError {
@@ -1511,6 +1635,23 @@ impl fmt::Display for DescribeDevicePolicyConfigurationError {
     }
 }
 impl Error for DescribeDevicePolicyConfigurationError {}
+impl ServiceErrorCode for DescribeDevicePolicyConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDevicePolicyConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DescribeDevicePolicyConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeDevicePolicyConfigurationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DescribeDevicePolicyConfigurationError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+            DescribeDevicePolicyConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeDomain
 #[derive(Debug, PartialEq)]
 pub enum DescribeDomainError {
@@ -1565,6 +1706,17 @@ impl fmt::Display for DescribeDomainError {
     }
 }
 impl Error for DescribeDomainError {}
+impl ServiceErrorCode for DescribeDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeDomainError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeDomainError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeDomainError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeDomainError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribeDomainError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeFleetMetadata
 #[derive(Debug, PartialEq)]
 pub enum DescribeFleetMetadataError {
@@ -1627,6 +1779,17 @@ impl fmt::Display for DescribeFleetMetadataError {
     }
 }
 impl Error for DescribeFleetMetadataError {}
+impl ServiceErrorCode for DescribeFleetMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeFleetMetadataError::InternalServerError(_) => "InternalServerErrorException",
+            DescribeFleetMetadataError::InvalidRequest(_) => "InvalidRequestException",
+            DescribeFleetMetadataError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DescribeFleetMetadataError::TooManyRequests(_) => "TooManyRequestsException",
+            DescribeFleetMetadataError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeIdentityProviderConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DescribeIdentityProviderConfigurationError {
@@ -1703,6 +1866,25 @@ impl fmt::Display for DescribeIdentityProviderConfigurationError {
     }
 }
 impl Error for DescribeIdentityProviderConfigurationError {}
+impl ServiceErrorCode for DescribeIdentityProviderConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeIdentityProviderConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DescribeIdentityProviderConfigurationError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            DescribeIdentityProviderConfigurationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DescribeIdentityProviderConfigurationError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+            DescribeIdentityProviderConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DescribeWebsiteCertificateAuthority
 #[derive(Debug, PartialEq)]
 pub enum DescribeWebsiteCertificateAuthorityError {
@@ -1779,6 +1961,25 @@ impl fmt::Display for DescribeWebsiteCertificateAuthorityError {
     }
 }
 impl Error for DescribeWebsiteCertificateAuthorityError {}
+impl ServiceErrorCode for DescribeWebsiteCertificateAuthorityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeWebsiteCertificateAuthorityError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DescribeWebsiteCertificateAuthorityError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            DescribeWebsiteCertificateAuthorityError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DescribeWebsiteCertificateAuthorityError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+            DescribeWebsiteCertificateAuthorityError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DisassociateDomain
 #[derive(Debug, PartialEq)]
 pub enum DisassociateDomainError {
@@ -1835,6 +2036,17 @@ impl fmt::Display for DisassociateDomainError {
     }
 }
 impl Error for DisassociateDomainError {}
+impl ServiceErrorCode for DisassociateDomainError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateDomainError::InternalServerError(_) => "InternalServerErrorException",
+            DisassociateDomainError::InvalidRequest(_) => "InvalidRequestException",
+            DisassociateDomainError::ResourceNotFound(_) => "ResourceNotFoundException",
+            DisassociateDomainError::TooManyRequests(_) => "TooManyRequestsException",
+            DisassociateDomainError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by DisassociateWebsiteAuthorizationProvider
 #[derive(Debug, PartialEq)]
 pub enum DisassociateWebsiteAuthorizationProviderError {
@@ -1923,6 +2135,30 @@ impl fmt::Display for DisassociateWebsiteAuthorizationProviderError {
     }
 }
 impl Error for DisassociateWebsiteAuthorizationProviderError {}
+impl ServiceErrorCode for DisassociateWebsiteAuthorizationProviderError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateWebsiteAuthorizationProviderError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DisassociateWebsiteAuthorizationProviderError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            DisassociateWebsiteAuthorizationProviderError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            DisassociateWebsiteAuthorizationProviderError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DisassociateWebsiteAuthorizationProviderError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+            DisassociateWebsiteAuthorizationProviderError::Unauthorized(_) => {
+                "UnauthorizedException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateWebsiteCertificateAuthority
 #[derive(Debug, PartialEq)]
 pub enum DisassociateWebsiteCertificateAuthorityError {
@@ -1999,6 +2235,27 @@ impl fmt::Display for DisassociateWebsiteCertificateAuthorityError {
     }
 }
 impl Error for DisassociateWebsiteCertificateAuthorityError {}
+impl ServiceErrorCode for DisassociateWebsiteCertificateAuthorityError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateWebsiteCertificateAuthorityError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            DisassociateWebsiteCertificateAuthorityError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            DisassociateWebsiteCertificateAuthorityError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            DisassociateWebsiteCertificateAuthorityError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+            DisassociateWebsiteCertificateAuthorityError::Unauthorized(_) => {
+                "UnauthorizedException"
+            }
+        }
+    }
+}
 /// Errors returned by ListDevices
 #[derive(Debug, PartialEq)]
 pub enum ListDevicesError {
@@ -2053,6 +2310,17 @@ impl fmt::Display for ListDevicesError {
     }
 }
 impl Error for ListDevicesError {}
+impl ServiceErrorCode for ListDevicesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDevicesError::InternalServerError(_) => "InternalServerErrorException",
+            ListDevicesError::InvalidRequest(_) => "InvalidRequestException",
+            ListDevicesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListDevicesError::TooManyRequests(_) => "TooManyRequestsException",
+            ListDevicesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListDomains
 #[derive(Debug, PartialEq)]
 pub enum ListDomainsError {
@@ -2107,6 +2375,17 @@ impl fmt::Display for ListDomainsError {
     }
 }
 impl Error for ListDomainsError {}
+impl ServiceErrorCode for ListDomainsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListDomainsError::InternalServerError(_) => "InternalServerErrorException",
+            ListDomainsError::InvalidRequest(_) => "InvalidRequestException",
+            ListDomainsError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListDomainsError::TooManyRequests(_) => "TooManyRequestsException",
+            ListDomainsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListFleets
 #[derive(Debug, PartialEq)]
 pub enum ListFleetsError {
@@ -2155,6 +2434,16 @@ impl fmt::Display for ListFleetsError {
     }
 }
 impl Error for ListFleetsError {}
+impl ServiceErrorCode for ListFleetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListFleetsError::InternalServerError(_) => "InternalServerErrorException",
+            ListFleetsError::InvalidRequest(_) => "InvalidRequestException",
+            ListFleetsError::TooManyRequests(_) => "TooManyRequestsException",
+            ListFleetsError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2185,6 +2474,13 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by ListWebsiteAuthorizationProviders
 #[derive(Debug, PartialEq)]
 pub enum ListWebsiteAuthorizationProvidersError {
@@ -2261,6 +2557,23 @@ impl fmt::Display for ListWebsiteAuthorizationProvidersError {
     }
 }
 impl Error for ListWebsiteAuthorizationProvidersError {}
+impl ServiceErrorCode for ListWebsiteAuthorizationProvidersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListWebsiteAuthorizationProvidersError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            ListWebsiteAuthorizationProvidersError::InvalidRequest(_) => "InvalidRequestException",
+            ListWebsiteAuthorizationProvidersError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            ListWebsiteAuthorizationProvidersError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+            ListWebsiteAuthorizationProvidersError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by ListWebsiteCertificateAuthorities
 #[derive(Debug, PartialEq)]
 pub enum ListWebsiteCertificateAuthoritiesError {
@@ -2327,6 +2640,20 @@ impl fmt::Display for ListWebsiteCertificateAuthoritiesError {
     }
 }
 impl Error for ListWebsiteCertificateAuthoritiesError {}
+impl ServiceErrorCode for ListWebsiteCertificateAuthoritiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListWebsiteCertificateAuthoritiesError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            ListWebsiteCertificateAuthoritiesError::InvalidRequest(_) => "InvalidRequestException",
+            ListWebsiteCertificateAuthoritiesError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+            ListWebsiteCertificateAuthoritiesError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by RestoreDomainAccess
 #[derive(Debug, PartialEq)]
 pub enum RestoreDomainAccessError {
@@ -2385,6 +2712,17 @@ impl fmt::Display for RestoreDomainAccessError {
     }
 }
 impl Error for RestoreDomainAccessError {}
+impl ServiceErrorCode for RestoreDomainAccessError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreDomainAccessError::InternalServerError(_) => "InternalServerErrorException",
+            RestoreDomainAccessError::InvalidRequest(_) => "InvalidRequestException",
+            RestoreDomainAccessError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RestoreDomainAccessError::TooManyRequests(_) => "TooManyRequestsException",
+            RestoreDomainAccessError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by RevokeDomainAccess
 #[derive(Debug, PartialEq)]
 pub enum RevokeDomainAccessError {
@@ -2441,6 +2779,17 @@ impl fmt::Display for RevokeDomainAccessError {
     }
 }
 impl Error for RevokeDomainAccessError {}
+impl ServiceErrorCode for RevokeDomainAccessError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RevokeDomainAccessError::InternalServerError(_) => "InternalServerErrorException",
+            RevokeDomainAccessError::InvalidRequest(_) => "InvalidRequestException",
+            RevokeDomainAccessError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RevokeDomainAccessError::TooManyRequests(_) => "TooManyRequestsException",
+            RevokeDomainAccessError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by SignOutUser
 #[derive(Debug, PartialEq)]
 pub enum SignOutUserError {
@@ -2495,6 +2844,17 @@ impl fmt::Display for SignOutUserError {
     }
 }
 impl Error for SignOutUserError {}
+impl ServiceErrorCode for SignOutUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            SignOutUserError::InternalServerError(_) => "InternalServerErrorException",
+            SignOutUserError::InvalidRequest(_) => "InvalidRequestException",
+            SignOutUserError::ResourceNotFound(_) => "ResourceNotFoundException",
+            SignOutUserError::TooManyRequests(_) => "TooManyRequestsException",
+            SignOutUserError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2525,6 +2885,13 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2555,6 +2922,13 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+        }
+    }
+}
 /// Errors returned by UpdateAuditStreamConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateAuditStreamConfigurationError {
@@ -2629,6 +3003,19 @@ impl fmt::Display for UpdateAuditStreamConfigurationError {
     }
 }
 impl Error for UpdateAuditStreamConfigurationError {}
+impl ServiceErrorCode for UpdateAuditStreamConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateAuditStreamConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            UpdateAuditStreamConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateAuditStreamConfigurationError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateAuditStreamConfigurationError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateAuditStreamConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateCompanyNetworkConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateCompanyNetworkConfigurationError {
@@ -2705,6 +3092,23 @@ impl fmt::Display for UpdateCompanyNetworkConfigurationError {
     }
 }
 impl Error for UpdateCompanyNetworkConfigurationError {}
+impl ServiceErrorCode for UpdateCompanyNetworkConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateCompanyNetworkConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            UpdateCompanyNetworkConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateCompanyNetworkConfigurationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            UpdateCompanyNetworkConfigurationError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+            UpdateCompanyNetworkConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateDevicePolicyConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateDevicePolicyConfigurationError {
@@ -2779,6 +3183,21 @@ impl fmt::Display for UpdateDevicePolicyConfigurationError {
     }
 }
 impl Error for UpdateDevicePolicyConfigurationError {}
+impl ServiceErrorCode for UpdateDevicePolicyConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDevicePolicyConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            UpdateDevicePolicyConfigurationError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateDevicePolicyConfigurationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            UpdateDevicePolicyConfigurationError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateDevicePolicyConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateDomainMetadata
 #[derive(Debug, PartialEq)]
 pub enum UpdateDomainMetadataError {
@@ -2839,6 +3258,17 @@ impl fmt::Display for UpdateDomainMetadataError {
     }
 }
 impl Error for UpdateDomainMetadataError {}
+impl ServiceErrorCode for UpdateDomainMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateDomainMetadataError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateDomainMetadataError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateDomainMetadataError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateDomainMetadataError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateDomainMetadataError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateFleetMetadata
 #[derive(Debug, PartialEq)]
 pub enum UpdateFleetMetadataError {
@@ -2897,6 +3327,17 @@ impl fmt::Display for UpdateFleetMetadataError {
     }
 }
 impl Error for UpdateFleetMetadataError {}
+impl ServiceErrorCode for UpdateFleetMetadataError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateFleetMetadataError::InternalServerError(_) => "InternalServerErrorException",
+            UpdateFleetMetadataError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateFleetMetadataError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateFleetMetadataError::TooManyRequests(_) => "TooManyRequestsException",
+            UpdateFleetMetadataError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Errors returned by UpdateIdentityProviderConfiguration
 #[derive(Debug, PartialEq)]
 pub enum UpdateIdentityProviderConfigurationError {
@@ -2973,6 +3414,25 @@ impl fmt::Display for UpdateIdentityProviderConfigurationError {
     }
 }
 impl Error for UpdateIdentityProviderConfigurationError {}
+impl ServiceErrorCode for UpdateIdentityProviderConfigurationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateIdentityProviderConfigurationError::InternalServerError(_) => {
+                "InternalServerErrorException"
+            }
+            UpdateIdentityProviderConfigurationError::InvalidRequest(_) => {
+                "InvalidRequestException"
+            }
+            UpdateIdentityProviderConfigurationError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+            UpdateIdentityProviderConfigurationError::TooManyRequests(_) => {
+                "TooManyRequestsException"
+            }
+            UpdateIdentityProviderConfigurationError::Unauthorized(_) => "UnauthorizedException",
+        }
+    }
+}
 /// Trait representing the capabilities of the WorkLink API. WorkLink clients implement this trait.
 #[async_trait]
 pub trait Worklink {
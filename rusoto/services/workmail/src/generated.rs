@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -157,6 +157,7 @@ pub struct BookingOptions {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct CancelMailboxExportJobRequest {
     /// <p>The idempotency token for the client request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     pub client_token: String,
     /// <p>The job ID.</p>
@@ -216,6 +217,7 @@ pub struct CreateOrganizationRequest {
     #[serde(rename = "Alias")]
     pub alias: String,
     /// <p>The idempotency token associated with the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -377,6 +379,7 @@ pub struct DeleteMailboxPermissionsResponse {}
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct DeleteOrganizationRequest {
     /// <p>The idempotency token associated with the request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_token: Option<String>,
@@ -1471,6 +1474,7 @@ pub struct Resource {
 #[cfg_attr(feature = "deserialize_structs", derive(Deserialize))]
 pub struct StartMailboxExportJobRequest {
     /// <p>The idempotency token for the client request.</p>
+    /// <p>Filled in with a random token if left empty and the client is set up to, see <code>Client::with_idempotency_tokens</code>.</p>
     #[serde(rename = "ClientToken")]
     pub client_token: String,
     /// <p>The mailbox export job description.</p>
@@ -1714,6 +1718,19 @@ impl fmt::Display for AssociateDelegateToResourceError {
     }
 }
 impl Error for AssociateDelegateToResourceError {}
+impl ServiceErrorCode for AssociateDelegateToResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateDelegateToResourceError::EntityNotFound(_) => "EntityNotFoundException",
+            AssociateDelegateToResourceError::EntityState(_) => "EntityStateException",
+            AssociateDelegateToResourceError::InvalidParameter(_) => "InvalidParameterException",
+            AssociateDelegateToResourceError::OrganizationNotFound(_) => {
+                "OrganizationNotFoundException"
+            }
+            AssociateDelegateToResourceError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by AssociateMemberToGroup
 #[derive(Debug, PartialEq)]
 pub enum AssociateMemberToGroupError {
@@ -1802,6 +1819,22 @@ impl fmt::Display for AssociateMemberToGroupError {
     }
 }
 impl Error for AssociateMemberToGroupError {}
+impl ServiceErrorCode for AssociateMemberToGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateMemberToGroupError::DirectoryServiceAuthenticationFailed(_) => {
+                "DirectoryServiceAuthenticationFailedException"
+            }
+            AssociateMemberToGroupError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            AssociateMemberToGroupError::EntityNotFound(_) => "EntityNotFoundException",
+            AssociateMemberToGroupError::EntityState(_) => "EntityStateException",
+            AssociateMemberToGroupError::InvalidParameter(_) => "InvalidParameterException",
+            AssociateMemberToGroupError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            AssociateMemberToGroupError::OrganizationState(_) => "OrganizationStateException",
+            AssociateMemberToGroupError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by CancelMailboxExportJob
 #[derive(Debug, PartialEq)]
 pub enum CancelMailboxExportJobError {
@@ -1858,6 +1891,16 @@ impl fmt::Display for CancelMailboxExportJobError {
     }
 }
 impl Error for CancelMailboxExportJobError {}
+impl ServiceErrorCode for CancelMailboxExportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CancelMailboxExportJobError::EntityNotFound(_) => "EntityNotFoundException",
+            CancelMailboxExportJobError::InvalidParameter(_) => "InvalidParameterException",
+            CancelMailboxExportJobError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            CancelMailboxExportJobError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by CreateAlias
 #[derive(Debug, PartialEq)]
 pub enum CreateAliasError {
@@ -1936,6 +1979,21 @@ impl fmt::Display for CreateAliasError {
     }
 }
 impl Error for CreateAliasError {}
+impl ServiceErrorCode for CreateAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateAliasError::EmailAddressInUse(_) => "EmailAddressInUseException",
+            CreateAliasError::EntityNotFound(_) => "EntityNotFoundException",
+            CreateAliasError::EntityState(_) => "EntityStateException",
+            CreateAliasError::InvalidParameter(_) => "InvalidParameterException",
+            CreateAliasError::LimitExceeded(_) => "LimitExceededException",
+            CreateAliasError::MailDomainNotFound(_) => "MailDomainNotFoundException",
+            CreateAliasError::MailDomainState(_) => "MailDomainStateException",
+            CreateAliasError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            CreateAliasError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by CreateGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateGroupError {
@@ -2012,6 +2070,22 @@ impl fmt::Display for CreateGroupError {
     }
 }
 impl Error for CreateGroupError {}
+impl ServiceErrorCode for CreateGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGroupError::DirectoryServiceAuthenticationFailed(_) => {
+                "DirectoryServiceAuthenticationFailedException"
+            }
+            CreateGroupError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            CreateGroupError::InvalidParameter(_) => "InvalidParameterException",
+            CreateGroupError::NameAvailability(_) => "NameAvailabilityException",
+            CreateGroupError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            CreateGroupError::OrganizationState(_) => "OrganizationStateException",
+            CreateGroupError::ReservedName(_) => "ReservedNameException",
+            CreateGroupError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by CreateOrganization
 #[derive(Debug, PartialEq)]
 pub enum CreateOrganizationError {
@@ -2068,6 +2142,17 @@ impl fmt::Display for CreateOrganizationError {
     }
 }
 impl Error for CreateOrganizationError {}
+impl ServiceErrorCode for CreateOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateOrganizationError::DirectoryInUse(_) => "DirectoryInUseException",
+            CreateOrganizationError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            CreateOrganizationError::InvalidParameter(_) => "InvalidParameterException",
+            CreateOrganizationError::LimitExceeded(_) => "LimitExceededException",
+            CreateOrganizationError::NameAvailability(_) => "NameAvailabilityException",
+        }
+    }
+}
 /// Errors returned by CreateResource
 #[derive(Debug, PartialEq)]
 pub enum CreateResourceError {
@@ -2138,6 +2223,21 @@ impl fmt::Display for CreateResourceError {
     }
 }
 impl Error for CreateResourceError {}
+impl ServiceErrorCode for CreateResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateResourceError::DirectoryServiceAuthenticationFailed(_) => {
+                "DirectoryServiceAuthenticationFailedException"
+            }
+            CreateResourceError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            CreateResourceError::InvalidParameter(_) => "InvalidParameterException",
+            CreateResourceError::NameAvailability(_) => "NameAvailabilityException",
+            CreateResourceError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            CreateResourceError::OrganizationState(_) => "OrganizationStateException",
+            CreateResourceError::ReservedName(_) => "ReservedNameException",
+        }
+    }
+}
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
 pub enum CreateUserError {
@@ -2220,6 +2320,23 @@ impl fmt::Display for CreateUserError {
     }
 }
 impl Error for CreateUserError {}
+impl ServiceErrorCode for CreateUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateUserError::DirectoryServiceAuthenticationFailed(_) => {
+                "DirectoryServiceAuthenticationFailedException"
+            }
+            CreateUserError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            CreateUserError::InvalidParameter(_) => "InvalidParameterException",
+            CreateUserError::InvalidPassword(_) => "InvalidPasswordException",
+            CreateUserError::NameAvailability(_) => "NameAvailabilityException",
+            CreateUserError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            CreateUserError::OrganizationState(_) => "OrganizationStateException",
+            CreateUserError::ReservedName(_) => "ReservedNameException",
+            CreateUserError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DeleteAccessControlRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteAccessControlRuleError {
@@ -2260,6 +2377,16 @@ impl fmt::Display for DeleteAccessControlRuleError {
     }
 }
 impl Error for DeleteAccessControlRuleError {}
+impl ServiceErrorCode for DeleteAccessControlRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAccessControlRuleError::OrganizationNotFound(_) => {
+                "OrganizationNotFoundException"
+            }
+            DeleteAccessControlRuleError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by DeleteAlias
 #[derive(Debug, PartialEq)]
 pub enum DeleteAliasError {
@@ -2314,6 +2441,17 @@ impl fmt::Display for DeleteAliasError {
     }
 }
 impl Error for DeleteAliasError {}
+impl ServiceErrorCode for DeleteAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteAliasError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteAliasError::EntityState(_) => "EntityStateException",
+            DeleteAliasError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteAliasError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            DeleteAliasError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by DeleteGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteGroupError {
@@ -2384,6 +2522,21 @@ impl fmt::Display for DeleteGroupError {
     }
 }
 impl Error for DeleteGroupError {}
+impl ServiceErrorCode for DeleteGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGroupError::DirectoryServiceAuthenticationFailed(_) => {
+                "DirectoryServiceAuthenticationFailedException"
+            }
+            DeleteGroupError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            DeleteGroupError::EntityState(_) => "EntityStateException",
+            DeleteGroupError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteGroupError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            DeleteGroupError::OrganizationState(_) => "OrganizationStateException",
+            DeleteGroupError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DeleteMailboxPermissions
 #[derive(Debug, PartialEq)]
 pub enum DeleteMailboxPermissionsError {
@@ -2450,6 +2603,19 @@ impl fmt::Display for DeleteMailboxPermissionsError {
     }
 }
 impl Error for DeleteMailboxPermissionsError {}
+impl ServiceErrorCode for DeleteMailboxPermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteMailboxPermissionsError::EntityNotFound(_) => "EntityNotFoundException",
+            DeleteMailboxPermissionsError::EntityState(_) => "EntityStateException",
+            DeleteMailboxPermissionsError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteMailboxPermissionsError::OrganizationNotFound(_) => {
+                "OrganizationNotFoundException"
+            }
+            DeleteMailboxPermissionsError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by DeleteOrganization
 #[derive(Debug, PartialEq)]
 pub enum DeleteOrganizationError {
@@ -2496,6 +2662,15 @@ impl fmt::Display for DeleteOrganizationError {
     }
 }
 impl Error for DeleteOrganizationError {}
+impl ServiceErrorCode for DeleteOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteOrganizationError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteOrganizationError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            DeleteOrganizationError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by DeleteResource
 #[derive(Debug, PartialEq)]
 pub enum DeleteResourceError {
@@ -2544,6 +2719,16 @@ impl fmt::Display for DeleteResourceError {
     }
 }
 impl Error for DeleteResourceError {}
+impl ServiceErrorCode for DeleteResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteResourceError::EntityState(_) => "EntityStateException",
+            DeleteResourceError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteResourceError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            DeleteResourceError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by DeleteRetentionPolicy
 #[derive(Debug, PartialEq)]
 pub enum DeleteRetentionPolicyError {
@@ -2592,6 +2777,15 @@ impl fmt::Display for DeleteRetentionPolicyError {
     }
 }
 impl Error for DeleteRetentionPolicyError {}
+impl ServiceErrorCode for DeleteRetentionPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteRetentionPolicyError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteRetentionPolicyError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            DeleteRetentionPolicyError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
 pub enum DeleteUserError {
@@ -2662,6 +2856,21 @@ impl fmt::Display for DeleteUserError {
     }
 }
 impl Error for DeleteUserError {}
+impl ServiceErrorCode for DeleteUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteUserError::DirectoryServiceAuthenticationFailed(_) => {
+                "DirectoryServiceAuthenticationFailedException"
+            }
+            DeleteUserError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            DeleteUserError::EntityState(_) => "EntityStateException",
+            DeleteUserError::InvalidParameter(_) => "InvalidParameterException",
+            DeleteUserError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            DeleteUserError::OrganizationState(_) => "OrganizationStateException",
+            DeleteUserError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by DeregisterFromWorkMail
 #[derive(Debug, PartialEq)]
 pub enum DeregisterFromWorkMailError {
@@ -2724,6 +2933,17 @@ impl fmt::Display for DeregisterFromWorkMailError {
     }
 }
 impl Error for DeregisterFromWorkMailError {}
+impl ServiceErrorCode for DeregisterFromWorkMailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterFromWorkMailError::EntityNotFound(_) => "EntityNotFoundException",
+            DeregisterFromWorkMailError::EntityState(_) => "EntityStateException",
+            DeregisterFromWorkMailError::InvalidParameter(_) => "InvalidParameterException",
+            DeregisterFromWorkMailError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            DeregisterFromWorkMailError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by DescribeGroup
 #[derive(Debug, PartialEq)]
 pub enum DescribeGroupError {
@@ -2772,6 +2992,16 @@ impl fmt::Display for DescribeGroupError {
     }
 }
 impl Error for DescribeGroupError {}
+impl ServiceErrorCode for DescribeGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeGroupError::EntityNotFound(_) => "EntityNotFoundException",
+            DescribeGroupError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeGroupError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            DescribeGroupError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by DescribeMailboxExportJob
 #[derive(Debug, PartialEq)]
 pub enum DescribeMailboxExportJobError {
@@ -2830,6 +3060,18 @@ impl fmt::Display for DescribeMailboxExportJobError {
     }
 }
 impl Error for DescribeMailboxExportJobError {}
+impl ServiceErrorCode for DescribeMailboxExportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeMailboxExportJobError::EntityNotFound(_) => "EntityNotFoundException",
+            DescribeMailboxExportJobError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeMailboxExportJobError::OrganizationNotFound(_) => {
+                "OrganizationNotFoundException"
+            }
+            DescribeMailboxExportJobError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by DescribeOrganization
 #[derive(Debug, PartialEq)]
 pub enum DescribeOrganizationError {
@@ -2870,6 +3112,14 @@ impl fmt::Display for DescribeOrganizationError {
     }
 }
 impl Error for DescribeOrganizationError {}
+impl ServiceErrorCode for DescribeOrganizationError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeOrganizationError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeOrganizationError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeResource
 #[derive(Debug, PartialEq)]
 pub enum DescribeResourceError {
@@ -2920,6 +3170,16 @@ impl fmt::Display for DescribeResourceError {
     }
 }
 impl Error for DescribeResourceError {}
+impl ServiceErrorCode for DescribeResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeResourceError::EntityNotFound(_) => "EntityNotFoundException",
+            DescribeResourceError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeResourceError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            DescribeResourceError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by DescribeUser
 #[derive(Debug, PartialEq)]
 pub enum DescribeUserError {
@@ -2968,6 +3228,16 @@ impl fmt::Display for DescribeUserError {
     }
 }
 impl Error for DescribeUserError {}
+impl ServiceErrorCode for DescribeUserError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeUserError::EntityNotFound(_) => "EntityNotFoundException",
+            DescribeUserError::InvalidParameter(_) => "InvalidParameterException",
+            DescribeUserError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            DescribeUserError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by DisassociateDelegateFromResource
 #[derive(Debug, PartialEq)]
 pub enum DisassociateDelegateFromResourceError {
@@ -3042,6 +3312,23 @@ impl fmt::Display for DisassociateDelegateFromResourceError {
     }
 }
 impl Error for DisassociateDelegateFromResourceError {}
+impl ServiceErrorCode for DisassociateDelegateFromResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateDelegateFromResourceError::EntityNotFound(_) => "EntityNotFoundException",
+            DisassociateDelegateFromResourceError::EntityState(_) => "EntityStateException",
+            DisassociateDelegateFromResourceError::InvalidParameter(_) => {
+                "InvalidParameterException"
+            }
+            DisassociateDelegateFromResourceError::OrganizationNotFound(_) => {
+                "OrganizationNotFoundException"
+            }
+            DisassociateDelegateFromResourceError::OrganizationState(_) => {
+                "OrganizationStateException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateMemberFromGroup
 #[derive(Debug, PartialEq)]
 pub enum DisassociateMemberFromGroupError {
@@ -3144,6 +3431,28 @@ impl fmt::Display for DisassociateMemberFromGroupError {
     }
 }
 impl Error for DisassociateMemberFromGroupError {}
+impl ServiceErrorCode for DisassociateMemberFromGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateMemberFromGroupError::DirectoryServiceAuthenticationFailed(_) => {
+                "DirectoryServiceAuthenticationFailedException"
+            }
+            DisassociateMemberFromGroupError::DirectoryUnavailable(_) => {
+                "DirectoryUnavailableException"
+            }
+            DisassociateMemberFromGroupError::EntityNotFound(_) => "EntityNotFoundException",
+            DisassociateMemberFromGroupError::EntityState(_) => "EntityStateException",
+            DisassociateMemberFromGroupError::InvalidParameter(_) => "InvalidParameterException",
+            DisassociateMemberFromGroupError::OrganizationNotFound(_) => {
+                "OrganizationNotFoundException"
+            }
+            DisassociateMemberFromGroupError::OrganizationState(_) => "OrganizationStateException",
+            DisassociateMemberFromGroupError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by GetAccessControlEffect
 #[derive(Debug, PartialEq)]
 pub enum GetAccessControlEffectError {
@@ -3200,6 +3509,16 @@ impl fmt::Display for GetAccessControlEffectError {
     }
 }
 impl Error for GetAccessControlEffectError {}
+impl ServiceErrorCode for GetAccessControlEffectError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetAccessControlEffectError::EntityNotFound(_) => "EntityNotFoundException",
+            GetAccessControlEffectError::InvalidParameter(_) => "InvalidParameterException",
+            GetAccessControlEffectError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            GetAccessControlEffectError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by GetDefaultRetentionPolicy
 #[derive(Debug, PartialEq)]
 pub enum GetDefaultRetentionPolicyError {
@@ -3258,6 +3577,18 @@ impl fmt::Display for GetDefaultRetentionPolicyError {
     }
 }
 impl Error for GetDefaultRetentionPolicyError {}
+impl ServiceErrorCode for GetDefaultRetentionPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetDefaultRetentionPolicyError::EntityNotFound(_) => "EntityNotFoundException",
+            GetDefaultRetentionPolicyError::InvalidParameter(_) => "InvalidParameterException",
+            GetDefaultRetentionPolicyError::OrganizationNotFound(_) => {
+                "OrganizationNotFoundException"
+            }
+            GetDefaultRetentionPolicyError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by GetMailboxDetails
 #[derive(Debug, PartialEq)]
 pub enum GetMailboxDetailsError {
@@ -3302,6 +3633,15 @@ impl fmt::Display for GetMailboxDetailsError {
     }
 }
 impl Error for GetMailboxDetailsError {}
+impl ServiceErrorCode for GetMailboxDetailsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetMailboxDetailsError::EntityNotFound(_) => "EntityNotFoundException",
+            GetMailboxDetailsError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            GetMailboxDetailsError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by ListAccessControlRules
 #[derive(Debug, PartialEq)]
 pub enum ListAccessControlRulesError {
@@ -3342,6 +3682,14 @@ impl fmt::Display for ListAccessControlRulesError {
     }
 }
 impl Error for ListAccessControlRulesError {}
+impl ServiceErrorCode for ListAccessControlRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAccessControlRulesError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            ListAccessControlRulesError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by ListAliases
 #[derive(Debug, PartialEq)]
 pub enum ListAliasesError {
@@ -3396,6 +3744,17 @@ impl fmt::Display for ListAliasesError {
     }
 }
 impl Error for ListAliasesError {}
+impl ServiceErrorCode for ListAliasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAliasesError::EntityNotFound(_) => "EntityNotFoundException",
+            ListAliasesError::EntityState(_) => "EntityStateException",
+            ListAliasesError::InvalidParameter(_) => "InvalidParameterException",
+            ListAliasesError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            ListAliasesError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by ListGroupMembers
 #[derive(Debug, PartialEq)]
 pub enum ListGroupMembersError {
@@ -3452,6 +3811,17 @@ impl fmt::Display for ListGroupMembersError {
     }
 }
 impl Error for ListGroupMembersError {}
+impl ServiceErrorCode for ListGroupMembersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGroupMembersError::EntityNotFound(_) => "EntityNotFoundException",
+            ListGroupMembersError::EntityState(_) => "EntityStateException",
+            ListGroupMembersError::InvalidParameter(_) => "InvalidParameterException",
+            ListGroupMembersError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            ListGroupMembersError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by ListGroups
 #[derive(Debug, PartialEq)]
 pub enum ListGroupsError {
@@ -3500,6 +3870,16 @@ impl fmt::Display for ListGroupsError {
     }
 }
 impl Error for ListGroupsError {}
+impl ServiceErrorCode for ListGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListGroupsError::EntityNotFound(_) => "EntityNotFoundException",
+            ListGroupsError::InvalidParameter(_) => "InvalidParameterException",
+            ListGroupsError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            ListGroupsError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by ListMailboxExportJobs
 #[derive(Debug, PartialEq)]
 pub enum ListMailboxExportJobsError {
@@ -3548,6 +3928,15 @@ impl fmt::Display for ListMailboxExportJobsError {
     }
 }
 impl Error for ListMailboxExportJobsError {}
+impl ServiceErrorCode for ListMailboxExportJobsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMailboxExportJobsError::InvalidParameter(_) => "InvalidParameterException",
+            ListMailboxExportJobsError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            ListMailboxExportJobsError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by ListMailboxPermissions
 #[derive(Debug, PartialEq)]
 pub enum ListMailboxPermissionsError {
@@ -3604,6 +3993,16 @@ impl fmt::Display for ListMailboxPermissionsError {
     }
 }
 impl Error for ListMailboxPermissionsError {}
+impl ServiceErrorCode for ListMailboxPermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListMailboxPermissionsError::EntityNotFound(_) => "EntityNotFoundException",
+            ListMailboxPermissionsError::InvalidParameter(_) => "InvalidParameterException",
+            ListMailboxPermissionsError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            ListMailboxPermissionsError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by ListOrganizations
 #[derive(Debug, PartialEq)]
 pub enum ListOrganizationsError {
@@ -3634,6 +4033,13 @@ impl fmt::Display for ListOrganizationsError {
     }
 }
 impl Error for ListOrganizationsError {}
+impl ServiceErrorCode for ListOrganizationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListOrganizationsError::InvalidParameter(_) => "InvalidParameterException",
+        }
+    }
+}
 /// Errors returned by ListResourceDelegates
 #[derive(Debug, PartialEq)]
 pub enum ListResourceDelegatesError {
@@ -3696,6 +4102,17 @@ impl fmt::Display for ListResourceDelegatesError {
     }
 }
 impl Error for ListResourceDelegatesError {}
+impl ServiceErrorCode for ListResourceDelegatesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourceDelegatesError::EntityNotFound(_) => "EntityNotFoundException",
+            ListResourceDelegatesError::EntityState(_) => "EntityStateException",
+            ListResourceDelegatesError::InvalidParameter(_) => "InvalidParameterException",
+            ListResourceDelegatesError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            ListResourceDelegatesError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by ListResources
 #[derive(Debug, PartialEq)]
 pub enum ListResourcesError {
@@ -3738,6 +4155,15 @@ impl fmt::Display for ListResourcesError {
     }
 }
 impl Error for ListResourcesError {}
+impl ServiceErrorCode for ListResourcesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListResourcesError::InvalidParameter(_) => "InvalidParameterException",
+            ListResourcesError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            ListResourcesError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -3770,6 +4196,13 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListUsers
 #[derive(Debug, PartialEq)]
 pub enum ListUsersError {
@@ -3812,6 +4245,15 @@ impl fmt::Display for ListUsersError {
     }
 }
 impl Error for ListUsersError {}
+impl ServiceErrorCode for ListUsersError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListUsersError::InvalidParameter(_) => "InvalidParameterException",
+            ListUsersError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            ListUsersError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by PutAccessControlRule
 #[derive(Debug, PartialEq)]
 pub enum PutAccessControlRuleError {
@@ -3872,6 +4314,17 @@ impl fmt::Display for PutAccessControlRuleError {
     }
 }
 impl Error for PutAccessControlRuleError {}
+impl ServiceErrorCode for PutAccessControlRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutAccessControlRuleError::EntityNotFound(_) => "EntityNotFoundException",
+            PutAccessControlRuleError::InvalidParameter(_) => "InvalidParameterException",
+            PutAccessControlRuleError::LimitExceeded(_) => "LimitExceededException",
+            PutAccessControlRuleError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            PutAccessControlRuleError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by PutMailboxPermissions
 #[derive(Debug, PartialEq)]
 pub enum PutMailboxPermissionsError {
@@ -3934,6 +4387,17 @@ impl fmt::Display for PutMailboxPermissionsError {
     }
 }
 impl Error for PutMailboxPermissionsError {}
+impl ServiceErrorCode for PutMailboxPermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutMailboxPermissionsError::EntityNotFound(_) => "EntityNotFoundException",
+            PutMailboxPermissionsError::EntityState(_) => "EntityStateException",
+            PutMailboxPermissionsError::InvalidParameter(_) => "InvalidParameterException",
+            PutMailboxPermissionsError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            PutMailboxPermissionsError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by PutRetentionPolicy
 #[derive(Debug, PartialEq)]
 pub enum PutRetentionPolicyError {
@@ -3986,6 +4450,16 @@ impl fmt::Display for PutRetentionPolicyError {
     }
 }
 impl Error for PutRetentionPolicyError {}
+impl ServiceErrorCode for PutRetentionPolicyError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutRetentionPolicyError::InvalidParameter(_) => "InvalidParameterException",
+            PutRetentionPolicyError::LimitExceeded(_) => "LimitExceededException",
+            PutRetentionPolicyError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            PutRetentionPolicyError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by RegisterToWorkMail
 #[derive(Debug, PartialEq)]
 pub enum RegisterToWorkMailError {
@@ -4092,6 +4566,27 @@ impl fmt::Display for RegisterToWorkMailError {
     }
 }
 impl Error for RegisterToWorkMailError {}
+impl ServiceErrorCode for RegisterToWorkMailError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterToWorkMailError::DirectoryServiceAuthenticationFailed(_) => {
+                "DirectoryServiceAuthenticationFailedException"
+            }
+            RegisterToWorkMailError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            RegisterToWorkMailError::EmailAddressInUse(_) => "EmailAddressInUseException",
+            RegisterToWorkMailError::EntityAlreadyRegistered(_) => {
+                "EntityAlreadyRegisteredException"
+            }
+            RegisterToWorkMailError::EntityNotFound(_) => "EntityNotFoundException",
+            RegisterToWorkMailError::EntityState(_) => "EntityStateException",
+            RegisterToWorkMailError::InvalidParameter(_) => "InvalidParameterException",
+            RegisterToWorkMailError::MailDomainNotFound(_) => "MailDomainNotFoundException",
+            RegisterToWorkMailError::MailDomainState(_) => "MailDomainStateException",
+            RegisterToWorkMailError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            RegisterToWorkMailError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by ResetPassword
 #[derive(Debug, PartialEq)]
 pub enum ResetPasswordError {
@@ -4174,6 +4669,23 @@ impl fmt::Display for ResetPasswordError {
     }
 }
 impl Error for ResetPasswordError {}
+impl ServiceErrorCode for ResetPasswordError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ResetPasswordError::DirectoryServiceAuthenticationFailed(_) => {
+                "DirectoryServiceAuthenticationFailedException"
+            }
+            ResetPasswordError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            ResetPasswordError::EntityNotFound(_) => "EntityNotFoundException",
+            ResetPasswordError::EntityState(_) => "EntityStateException",
+            ResetPasswordError::InvalidParameter(_) => "InvalidParameterException",
+            ResetPasswordError::InvalidPassword(_) => "InvalidPasswordException",
+            ResetPasswordError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            ResetPasswordError::OrganizationState(_) => "OrganizationStateException",
+            ResetPasswordError::UnsupportedOperation(_) => "UnsupportedOperationException",
+        }
+    }
+}
 /// Errors returned by StartMailboxExportJob
 #[derive(Debug, PartialEq)]
 pub enum StartMailboxExportJobError {
@@ -4236,6 +4748,17 @@ impl fmt::Display for StartMailboxExportJobError {
     }
 }
 impl Error for StartMailboxExportJobError {}
+impl ServiceErrorCode for StartMailboxExportJobError {
+    fn error_code(&self) -> &str {
+        match *self {
+            StartMailboxExportJobError::EntityNotFound(_) => "EntityNotFoundException",
+            StartMailboxExportJobError::InvalidParameter(_) => "InvalidParameterException",
+            StartMailboxExportJobError::LimitExceeded(_) => "LimitExceededException",
+            StartMailboxExportJobError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            StartMailboxExportJobError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -4278,6 +4801,15 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::OrganizationState(_) => "OrganizationStateException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -4308,6 +4840,13 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateMailboxQuota
 #[derive(Debug, PartialEq)]
 pub enum UpdateMailboxQuotaError {
@@ -4366,6 +4905,17 @@ impl fmt::Display for UpdateMailboxQuotaError {
     }
 }
 impl Error for UpdateMailboxQuotaError {}
+impl ServiceErrorCode for UpdateMailboxQuotaError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateMailboxQuotaError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateMailboxQuotaError::EntityState(_) => "EntityStateException",
+            UpdateMailboxQuotaError::InvalidParameter(_) => "InvalidParameterException",
+            UpdateMailboxQuotaError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            UpdateMailboxQuotaError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Errors returned by UpdatePrimaryEmailAddress
 #[derive(Debug, PartialEq)]
 pub enum UpdatePrimaryEmailAddressError {
@@ -4488,6 +5038,31 @@ impl fmt::Display for UpdatePrimaryEmailAddressError {
     }
 }
 impl Error for UpdatePrimaryEmailAddressError {}
+impl ServiceErrorCode for UpdatePrimaryEmailAddressError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdatePrimaryEmailAddressError::DirectoryServiceAuthenticationFailed(_) => {
+                "DirectoryServiceAuthenticationFailedException"
+            }
+            UpdatePrimaryEmailAddressError::DirectoryUnavailable(_) => {
+                "DirectoryUnavailableException"
+            }
+            UpdatePrimaryEmailAddressError::EmailAddressInUse(_) => "EmailAddressInUseException",
+            UpdatePrimaryEmailAddressError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdatePrimaryEmailAddressError::EntityState(_) => "EntityStateException",
+            UpdatePrimaryEmailAddressError::InvalidParameter(_) => "InvalidParameterException",
+            UpdatePrimaryEmailAddressError::MailDomainNotFound(_) => "MailDomainNotFoundException",
+            UpdatePrimaryEmailAddressError::MailDomainState(_) => "MailDomainStateException",
+            UpdatePrimaryEmailAddressError::OrganizationNotFound(_) => {
+                "OrganizationNotFoundException"
+            }
+            UpdatePrimaryEmailAddressError::OrganizationState(_) => "OrganizationStateException",
+            UpdatePrimaryEmailAddressError::UnsupportedOperation(_) => {
+                "UnsupportedOperationException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateResource
 #[derive(Debug, PartialEq)]
 pub enum UpdateResourceError {
@@ -4572,6 +5147,22 @@ impl fmt::Display for UpdateResourceError {
     }
 }
 impl Error for UpdateResourceError {}
+impl ServiceErrorCode for UpdateResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateResourceError::DirectoryUnavailable(_) => "DirectoryUnavailableException",
+            UpdateResourceError::EmailAddressInUse(_) => "EmailAddressInUseException",
+            UpdateResourceError::EntityNotFound(_) => "EntityNotFoundException",
+            UpdateResourceError::EntityState(_) => "EntityStateException",
+            UpdateResourceError::InvalidConfiguration(_) => "InvalidConfigurationException",
+            UpdateResourceError::MailDomainNotFound(_) => "MailDomainNotFoundException",
+            UpdateResourceError::MailDomainState(_) => "MailDomainStateException",
+            UpdateResourceError::NameAvailability(_) => "NameAvailabilityException",
+            UpdateResourceError::OrganizationNotFound(_) => "OrganizationNotFoundException",
+            UpdateResourceError::OrganizationState(_) => "OrganizationStateException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon WorkMail API. Amazon WorkMail clients implement this trait.
 #[async_trait]
 pub trait Workmail {
@@ -4959,6 +5550,12 @@ impl Workmail for WorkmailClient {
         &self,
         input: CancelMailboxExportJobRequest,
     ) -> Result<CancelMailboxExportJobResponse, RusotoError<CancelMailboxExportJobError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "WorkMailService.CancelMailboxExportJob");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -5014,6 +5611,10 @@ impl Workmail for WorkmailClient {
         &self,
         input: CreateOrganizationRequest,
     ) -> Result<CreateOrganizationResponse, RusotoError<CreateOrganizationError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "WorkMailService.CreateOrganization");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -5142,6 +5743,10 @@ impl Workmail for WorkmailClient {
         &self,
         input: DeleteOrganizationRequest,
     ) -> Result<DeleteOrganizationResponse, RusotoError<DeleteOrganizationError>> {
+        let mut input = input;
+        if input.client_token.is_none() {
+            input.client_token = self.client.idempotency_token();
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "WorkMailService.DeleteOrganization");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -5728,6 +6333,12 @@ impl Workmail for WorkmailClient {
         &self,
         input: StartMailboxExportJobRequest,
     ) -> Result<StartMailboxExportJobResponse, RusotoError<StartMailboxExportJobError>> {
+        let mut input = input;
+        if input.client_token.is_empty() {
+            if let Some(token) = self.client.idempotency_token() {
+                input.client_token = token;
+            }
+        }
         let mut request = self.new_signed_request("POST", "/");
         request.add_header("x-amz-target", "WorkMailService.StartMailboxExportJob");
         let encoded = serde_json::to_string(&input).unwrap();
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -69,6 +69,13 @@ impl fmt::Display for GetRawMessageContentError {
     }
 }
 impl Error for GetRawMessageContentError {}
+impl ServiceErrorCode for GetRawMessageContentError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetRawMessageContentError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon WorkMail Message Flow API. Amazon WorkMail Message Flow clients implement this trait.
 #[async_trait]
 pub trait WorkmailMessageFlow {
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1895,6 +1895,24 @@ impl fmt::Display for AssociateConnectionAliasError {
     }
 }
 impl Error for AssociateConnectionAliasError {}
+impl ServiceErrorCode for AssociateConnectionAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateConnectionAliasError::AccessDenied(_) => "AccessDeniedException",
+            AssociateConnectionAliasError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            AssociateConnectionAliasError::InvalidResourceState(_) => {
+                "InvalidResourceStateException"
+            }
+            AssociateConnectionAliasError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            AssociateConnectionAliasError::ResourceAssociated(_) => "ResourceAssociatedException",
+            AssociateConnectionAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by AssociateIpGroups
 #[derive(Debug, PartialEq)]
 pub enum AssociateIpGroupsError {
@@ -1963,6 +1981,18 @@ impl fmt::Display for AssociateIpGroupsError {
     }
 }
 impl Error for AssociateIpGroupsError {}
+impl ServiceErrorCode for AssociateIpGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AssociateIpGroupsError::AccessDenied(_) => "AccessDeniedException",
+            AssociateIpGroupsError::InvalidParameterValues(_) => "InvalidParameterValuesException",
+            AssociateIpGroupsError::InvalidResourceState(_) => "InvalidResourceStateException",
+            AssociateIpGroupsError::OperationNotSupported(_) => "OperationNotSupportedException",
+            AssociateIpGroupsError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            AssociateIpGroupsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by AuthorizeIpRules
 #[derive(Debug, PartialEq)]
 pub enum AuthorizeIpRulesError {
@@ -2023,6 +2053,17 @@ impl fmt::Display for AuthorizeIpRulesError {
     }
 }
 impl Error for AuthorizeIpRulesError {}
+impl ServiceErrorCode for AuthorizeIpRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            AuthorizeIpRulesError::AccessDenied(_) => "AccessDeniedException",
+            AuthorizeIpRulesError::InvalidParameterValues(_) => "InvalidParameterValuesException",
+            AuthorizeIpRulesError::InvalidResourceState(_) => "InvalidResourceStateException",
+            AuthorizeIpRulesError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            AuthorizeIpRulesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CopyWorkspaceImage
 #[derive(Debug, PartialEq)]
 pub enum CopyWorkspaceImageError {
@@ -2099,6 +2140,19 @@ impl fmt::Display for CopyWorkspaceImageError {
     }
 }
 impl Error for CopyWorkspaceImageError {}
+impl ServiceErrorCode for CopyWorkspaceImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CopyWorkspaceImageError::AccessDenied(_) => "AccessDeniedException",
+            CopyWorkspaceImageError::InvalidParameterValues(_) => "InvalidParameterValuesException",
+            CopyWorkspaceImageError::OperationNotSupported(_) => "OperationNotSupportedException",
+            CopyWorkspaceImageError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CopyWorkspaceImageError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CopyWorkspaceImageError::ResourceNotFound(_) => "ResourceNotFoundException",
+            CopyWorkspaceImageError::ResourceUnavailable(_) => "ResourceUnavailableException",
+        }
+    }
+}
 /// Errors returned by CreateConnectionAlias
 #[derive(Debug, PartialEq)]
 pub enum CreateConnectionAliasError {
@@ -2169,6 +2223,26 @@ impl fmt::Display for CreateConnectionAliasError {
     }
 }
 impl Error for CreateConnectionAliasError {}
+impl ServiceErrorCode for CreateConnectionAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateConnectionAliasError::AccessDenied(_) => "AccessDeniedException",
+            CreateConnectionAliasError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            CreateConnectionAliasError::InvalidResourceState(_) => "InvalidResourceStateException",
+            CreateConnectionAliasError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            CreateConnectionAliasError::ResourceAlreadyExists(_) => {
+                "ResourceAlreadyExistsException"
+            }
+            CreateConnectionAliasError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+        }
+    }
+}
 /// Errors returned by CreateIpGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateIpGroupError {
@@ -2227,6 +2301,17 @@ impl fmt::Display for CreateIpGroupError {
     }
 }
 impl Error for CreateIpGroupError {}
+impl ServiceErrorCode for CreateIpGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateIpGroupError::AccessDenied(_) => "AccessDeniedException",
+            CreateIpGroupError::InvalidParameterValues(_) => "InvalidParameterValuesException",
+            CreateIpGroupError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            CreateIpGroupError::ResourceCreationFailed(_) => "ResourceCreationFailedException",
+            CreateIpGroupError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+        }
+    }
+}
 /// Errors returned by CreateTags
 #[derive(Debug, PartialEq)]
 pub enum CreateTagsError {
@@ -2269,6 +2354,15 @@ impl fmt::Display for CreateTagsError {
     }
 }
 impl Error for CreateTagsError {}
+impl ServiceErrorCode for CreateTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateTagsError::InvalidParameterValues(_) => "InvalidParameterValuesException",
+            CreateTagsError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            CreateTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by CreateWorkspaces
 #[derive(Debug, PartialEq)]
 pub enum CreateWorkspacesError {
@@ -2309,6 +2403,14 @@ impl fmt::Display for CreateWorkspacesError {
     }
 }
 impl Error for CreateWorkspacesError {}
+impl ServiceErrorCode for CreateWorkspacesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateWorkspacesError::InvalidParameterValues(_) => "InvalidParameterValuesException",
+            CreateWorkspacesError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+        }
+    }
+}
 /// Errors returned by DeleteConnectionAlias
 #[derive(Debug, PartialEq)]
 pub enum DeleteConnectionAliasError {
@@ -2379,6 +2481,22 @@ impl fmt::Display for DeleteConnectionAliasError {
     }
 }
 impl Error for DeleteConnectionAliasError {}
+impl ServiceErrorCode for DeleteConnectionAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteConnectionAliasError::AccessDenied(_) => "AccessDeniedException",
+            DeleteConnectionAliasError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            DeleteConnectionAliasError::InvalidResourceState(_) => "InvalidResourceStateException",
+            DeleteConnectionAliasError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            DeleteConnectionAliasError::ResourceAssociated(_) => "ResourceAssociatedException",
+            DeleteConnectionAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteIpGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteIpGroupError {
@@ -2429,6 +2547,16 @@ impl fmt::Display for DeleteIpGroupError {
     }
 }
 impl Error for DeleteIpGroupError {}
+impl ServiceErrorCode for DeleteIpGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteIpGroupError::AccessDenied(_) => "AccessDeniedException",
+            DeleteIpGroupError::InvalidParameterValues(_) => "InvalidParameterValuesException",
+            DeleteIpGroupError::ResourceAssociated(_) => "ResourceAssociatedException",
+            DeleteIpGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
 pub enum DeleteTagsError {
@@ -2465,6 +2593,14 @@ impl fmt::Display for DeleteTagsError {
     }
 }
 impl Error for DeleteTagsError {}
+impl ServiceErrorCode for DeleteTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteTagsError::InvalidParameterValues(_) => "InvalidParameterValuesException",
+            DeleteTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DeleteWorkspaceImage
 #[derive(Debug, PartialEq)]
 pub enum DeleteWorkspaceImageError {
@@ -2511,6 +2647,15 @@ impl fmt::Display for DeleteWorkspaceImageError {
     }
 }
 impl Error for DeleteWorkspaceImageError {}
+impl ServiceErrorCode for DeleteWorkspaceImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteWorkspaceImageError::AccessDenied(_) => "AccessDeniedException",
+            DeleteWorkspaceImageError::InvalidResourceState(_) => "InvalidResourceStateException",
+            DeleteWorkspaceImageError::ResourceAssociated(_) => "ResourceAssociatedException",
+        }
+    }
+}
 /// Errors returned by DeregisterWorkspaceDirectory
 #[derive(Debug, PartialEq)]
 pub enum DeregisterWorkspaceDirectoryError {
@@ -2585,6 +2730,23 @@ impl fmt::Display for DeregisterWorkspaceDirectoryError {
     }
 }
 impl Error for DeregisterWorkspaceDirectoryError {}
+impl ServiceErrorCode for DeregisterWorkspaceDirectoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeregisterWorkspaceDirectoryError::AccessDenied(_) => "AccessDeniedException",
+            DeregisterWorkspaceDirectoryError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            DeregisterWorkspaceDirectoryError::InvalidResourceState(_) => {
+                "InvalidResourceStateException"
+            }
+            DeregisterWorkspaceDirectoryError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            DeregisterWorkspaceDirectoryError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeAccount
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountError {
@@ -2615,6 +2777,13 @@ impl fmt::Display for DescribeAccountError {
     }
 }
 impl Error for DescribeAccountError {}
+impl ServiceErrorCode for DescribeAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAccountError::AccessDenied(_) => "AccessDeniedException",
+        }
+    }
+}
 /// Errors returned by DescribeAccountModifications
 #[derive(Debug, PartialEq)]
 pub enum DescribeAccountModificationsError {
@@ -2649,6 +2818,13 @@ impl fmt::Display for DescribeAccountModificationsError {
     }
 }
 impl Error for DescribeAccountModificationsError {}
+impl ServiceErrorCode for DescribeAccountModificationsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeAccountModificationsError::AccessDenied(_) => "AccessDeniedException",
+        }
+    }
+}
 /// Errors returned by DescribeClientProperties
 #[derive(Debug, PartialEq)]
 pub enum DescribeClientPropertiesError {
@@ -2699,6 +2875,17 @@ impl fmt::Display for DescribeClientPropertiesError {
     }
 }
 impl Error for DescribeClientPropertiesError {}
+impl ServiceErrorCode for DescribeClientPropertiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeClientPropertiesError::AccessDenied(_) => "AccessDeniedException",
+            DescribeClientPropertiesError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            DescribeClientPropertiesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeConnectionAliasPermissions
 #[derive(Debug, PartialEq)]
 pub enum DescribeConnectionAliasPermissionsError {
@@ -2765,6 +2952,22 @@ impl fmt::Display for DescribeConnectionAliasPermissionsError {
     }
 }
 impl Error for DescribeConnectionAliasPermissionsError {}
+impl ServiceErrorCode for DescribeConnectionAliasPermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConnectionAliasPermissionsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeConnectionAliasPermissionsError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            DescribeConnectionAliasPermissionsError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            DescribeConnectionAliasPermissionsError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeConnectionAliases
 #[derive(Debug, PartialEq)]
 pub enum DescribeConnectionAliasesError {
@@ -2817,6 +3020,19 @@ impl fmt::Display for DescribeConnectionAliasesError {
     }
 }
 impl Error for DescribeConnectionAliasesError {}
+impl ServiceErrorCode for DescribeConnectionAliasesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeConnectionAliasesError::AccessDenied(_) => "AccessDeniedException",
+            DescribeConnectionAliasesError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            DescribeConnectionAliasesError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeIpGroups
 #[derive(Debug, PartialEq)]
 pub enum DescribeIpGroupsError {
@@ -2855,6 +3071,14 @@ impl fmt::Display for DescribeIpGroupsError {
     }
 }
 impl Error for DescribeIpGroupsError {}
+impl ServiceErrorCode for DescribeIpGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeIpGroupsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeIpGroupsError::InvalidParameterValues(_) => "InvalidParameterValuesException",
+        }
+    }
+}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
 pub enum DescribeTagsError {
@@ -2885,6 +3109,13 @@ impl fmt::Display for DescribeTagsError {
     }
 }
 impl Error for DescribeTagsError {}
+impl ServiceErrorCode for DescribeTagsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeTagsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeWorkspaceBundles
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorkspaceBundlesError {
@@ -2919,6 +3150,15 @@ impl fmt::Display for DescribeWorkspaceBundlesError {
     }
 }
 impl Error for DescribeWorkspaceBundlesError {}
+impl ServiceErrorCode for DescribeWorkspaceBundlesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeWorkspaceBundlesError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeWorkspaceDirectories
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorkspaceDirectoriesError {
@@ -2955,6 +3195,15 @@ impl fmt::Display for DescribeWorkspaceDirectoriesError {
     }
 }
 impl Error for DescribeWorkspaceDirectoriesError {}
+impl ServiceErrorCode for DescribeWorkspaceDirectoriesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeWorkspaceDirectoriesError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeWorkspaceImagePermissions
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorkspaceImagePermissionsError {
@@ -3011,6 +3260,19 @@ impl fmt::Display for DescribeWorkspaceImagePermissionsError {
     }
 }
 impl Error for DescribeWorkspaceImagePermissionsError {}
+impl ServiceErrorCode for DescribeWorkspaceImagePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeWorkspaceImagePermissionsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeWorkspaceImagePermissionsError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            DescribeWorkspaceImagePermissionsError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by DescribeWorkspaceImages
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorkspaceImagesError {
@@ -3043,6 +3305,13 @@ impl fmt::Display for DescribeWorkspaceImagesError {
     }
 }
 impl Error for DescribeWorkspaceImagesError {}
+impl ServiceErrorCode for DescribeWorkspaceImagesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeWorkspaceImagesError::AccessDenied(_) => "AccessDeniedException",
+        }
+    }
+}
 /// Errors returned by DescribeWorkspaceSnapshots
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorkspaceSnapshotsError {
@@ -3095,6 +3364,17 @@ impl fmt::Display for DescribeWorkspaceSnapshotsError {
     }
 }
 impl Error for DescribeWorkspaceSnapshotsError {}
+impl ServiceErrorCode for DescribeWorkspaceSnapshotsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeWorkspaceSnapshotsError::AccessDenied(_) => "AccessDeniedException",
+            DescribeWorkspaceSnapshotsError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            DescribeWorkspaceSnapshotsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DescribeWorkspaces
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorkspacesError {
@@ -3135,6 +3415,14 @@ impl fmt::Display for DescribeWorkspacesError {
     }
 }
 impl Error for DescribeWorkspacesError {}
+impl ServiceErrorCode for DescribeWorkspacesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeWorkspacesError::InvalidParameterValues(_) => "InvalidParameterValuesException",
+            DescribeWorkspacesError::ResourceUnavailable(_) => "ResourceUnavailableException",
+        }
+    }
+}
 /// Errors returned by DescribeWorkspacesConnectionStatus
 #[derive(Debug, PartialEq)]
 pub enum DescribeWorkspacesConnectionStatusError {
@@ -3171,6 +3459,15 @@ impl fmt::Display for DescribeWorkspacesConnectionStatusError {
     }
 }
 impl Error for DescribeWorkspacesConnectionStatusError {}
+impl ServiceErrorCode for DescribeWorkspacesConnectionStatusError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DescribeWorkspacesConnectionStatusError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+        }
+    }
+}
 /// Errors returned by DisassociateConnectionAlias
 #[derive(Debug, PartialEq)]
 pub enum DisassociateConnectionAliasError {
@@ -3243,6 +3540,23 @@ impl fmt::Display for DisassociateConnectionAliasError {
     }
 }
 impl Error for DisassociateConnectionAliasError {}
+impl ServiceErrorCode for DisassociateConnectionAliasError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateConnectionAliasError::AccessDenied(_) => "AccessDeniedException",
+            DisassociateConnectionAliasError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            DisassociateConnectionAliasError::InvalidResourceState(_) => {
+                "InvalidResourceStateException"
+            }
+            DisassociateConnectionAliasError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            DisassociateConnectionAliasError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by DisassociateIpGroups
 #[derive(Debug, PartialEq)]
 pub enum DisassociateIpGroupsError {
@@ -3297,6 +3611,18 @@ impl fmt::Display for DisassociateIpGroupsError {
     }
 }
 impl Error for DisassociateIpGroupsError {}
+impl ServiceErrorCode for DisassociateIpGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DisassociateIpGroupsError::AccessDenied(_) => "AccessDeniedException",
+            DisassociateIpGroupsError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            DisassociateIpGroupsError::InvalidResourceState(_) => "InvalidResourceStateException",
+            DisassociateIpGroupsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ImportWorkspaceImage
 #[derive(Debug, PartialEq)]
 pub enum ImportWorkspaceImageError {
@@ -3367,6 +3693,20 @@ impl fmt::Display for ImportWorkspaceImageError {
     }
 }
 impl Error for ImportWorkspaceImageError {}
+impl ServiceErrorCode for ImportWorkspaceImageError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ImportWorkspaceImageError::AccessDenied(_) => "AccessDeniedException",
+            ImportWorkspaceImageError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            ImportWorkspaceImageError::OperationNotSupported(_) => "OperationNotSupportedException",
+            ImportWorkspaceImageError::ResourceAlreadyExists(_) => "ResourceAlreadyExistsException",
+            ImportWorkspaceImageError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            ImportWorkspaceImageError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ListAvailableManagementCidrRanges
 #[derive(Debug, PartialEq)]
 pub enum ListAvailableManagementCidrRangesError {
@@ -3413,6 +3753,16 @@ impl fmt::Display for ListAvailableManagementCidrRangesError {
     }
 }
 impl Error for ListAvailableManagementCidrRangesError {}
+impl ServiceErrorCode for ListAvailableManagementCidrRangesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListAvailableManagementCidrRangesError::AccessDenied(_) => "AccessDeniedException",
+            ListAvailableManagementCidrRangesError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+        }
+    }
+}
 /// Errors returned by MigrateWorkspace
 #[derive(Debug, PartialEq)]
 pub enum MigrateWorkspaceError {
@@ -3481,6 +3831,18 @@ impl fmt::Display for MigrateWorkspaceError {
     }
 }
 impl Error for MigrateWorkspaceError {}
+impl ServiceErrorCode for MigrateWorkspaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            MigrateWorkspaceError::AccessDenied(_) => "AccessDeniedException",
+            MigrateWorkspaceError::InvalidParameterValues(_) => "InvalidParameterValuesException",
+            MigrateWorkspaceError::OperationInProgress(_) => "OperationInProgressException",
+            MigrateWorkspaceError::OperationNotSupported(_) => "OperationNotSupportedException",
+            MigrateWorkspaceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            MigrateWorkspaceError::ResourceUnavailable(_) => "ResourceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ModifyAccount
 #[derive(Debug, PartialEq)]
 pub enum ModifyAccountError {
@@ -3537,6 +3899,17 @@ impl fmt::Display for ModifyAccountError {
     }
 }
 impl Error for ModifyAccountError {}
+impl ServiceErrorCode for ModifyAccountError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyAccountError::AccessDenied(_) => "AccessDeniedException",
+            ModifyAccountError::InvalidParameterValues(_) => "InvalidParameterValuesException",
+            ModifyAccountError::InvalidResourceState(_) => "InvalidResourceStateException",
+            ModifyAccountError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ModifyAccountError::ResourceUnavailable(_) => "ResourceUnavailableException",
+        }
+    }
+}
 /// Errors returned by ModifyClientProperties
 #[derive(Debug, PartialEq)]
 pub enum ModifyClientPropertiesError {
@@ -3585,6 +3958,17 @@ impl fmt::Display for ModifyClientPropertiesError {
     }
 }
 impl Error for ModifyClientPropertiesError {}
+impl ServiceErrorCode for ModifyClientPropertiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyClientPropertiesError::AccessDenied(_) => "AccessDeniedException",
+            ModifyClientPropertiesError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            ModifyClientPropertiesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ModifySelfservicePermissions
 #[derive(Debug, PartialEq)]
 pub enum ModifySelfservicePermissionsError {
@@ -3639,6 +4023,17 @@ impl fmt::Display for ModifySelfservicePermissionsError {
     }
 }
 impl Error for ModifySelfservicePermissionsError {}
+impl ServiceErrorCode for ModifySelfservicePermissionsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifySelfservicePermissionsError::AccessDenied(_) => "AccessDeniedException",
+            ModifySelfservicePermissionsError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            ModifySelfservicePermissionsError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by ModifyWorkspaceAccessProperties
 #[derive(Debug, PartialEq)]
 pub enum ModifyWorkspaceAccessPropertiesError {
@@ -3683,6 +4078,16 @@ impl fmt::Display for ModifyWorkspaceAccessPropertiesError {
     }
 }
 impl Error for ModifyWorkspaceAccessPropertiesError {}
+impl ServiceErrorCode for ModifyWorkspaceAccessPropertiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyWorkspaceAccessPropertiesError::AccessDenied(_) => "AccessDeniedException",
+            ModifyWorkspaceAccessPropertiesError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyWorkspaceCreationProperties
 #[derive(Debug, PartialEq)]
 pub enum ModifyWorkspaceCreationPropertiesError {
@@ -3749,6 +4154,22 @@ impl fmt::Display for ModifyWorkspaceCreationPropertiesError {
     }
 }
 impl Error for ModifyWorkspaceCreationPropertiesError {}
+impl ServiceErrorCode for ModifyWorkspaceCreationPropertiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyWorkspaceCreationPropertiesError::AccessDenied(_) => "AccessDeniedException",
+            ModifyWorkspaceCreationPropertiesError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            ModifyWorkspaceCreationPropertiesError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            ModifyWorkspaceCreationPropertiesError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyWorkspaceProperties
 #[derive(Debug, PartialEq)]
 pub enum ModifyWorkspacePropertiesError {
@@ -3839,6 +4260,29 @@ impl fmt::Display for ModifyWorkspacePropertiesError {
     }
 }
 impl Error for ModifyWorkspacePropertiesError {}
+impl ServiceErrorCode for ModifyWorkspacePropertiesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyWorkspacePropertiesError::AccessDenied(_) => "AccessDeniedException",
+            ModifyWorkspacePropertiesError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            ModifyWorkspacePropertiesError::InvalidResourceState(_) => {
+                "InvalidResourceStateException"
+            }
+            ModifyWorkspacePropertiesError::OperationInProgress(_) => {
+                "OperationInProgressException"
+            }
+            ModifyWorkspacePropertiesError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ModifyWorkspacePropertiesError::ResourceUnavailable(_) => {
+                "ResourceUnavailableException"
+            }
+            ModifyWorkspacePropertiesError::UnsupportedWorkspaceConfiguration(_) => {
+                "UnsupportedWorkspaceConfigurationException"
+            }
+        }
+    }
+}
 /// Errors returned by ModifyWorkspaceState
 #[derive(Debug, PartialEq)]
 pub enum ModifyWorkspaceStateError {
@@ -3887,6 +4331,17 @@ impl fmt::Display for ModifyWorkspaceStateError {
     }
 }
 impl Error for ModifyWorkspaceStateError {}
+impl ServiceErrorCode for ModifyWorkspaceStateError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ModifyWorkspaceStateError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            ModifyWorkspaceStateError::InvalidResourceState(_) => "InvalidResourceStateException",
+            ModifyWorkspaceStateError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RebootWorkspaces
 #[derive(Debug, PartialEq)]
 pub enum RebootWorkspacesError {}
@@ -3909,6 +4364,11 @@ impl fmt::Display for RebootWorkspacesError {
     }
 }
 impl Error for RebootWorkspacesError {}
+impl ServiceErrorCode for RebootWorkspacesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RebuildWorkspaces
 #[derive(Debug, PartialEq)]
 pub enum RebuildWorkspacesError {}
@@ -3931,6 +4391,11 @@ impl fmt::Display for RebuildWorkspacesError {
     }
 }
 impl Error for RebuildWorkspacesError {}
+impl ServiceErrorCode for RebuildWorkspacesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by RegisterWorkspaceDirectory
 #[derive(Debug, PartialEq)]
 pub enum RegisterWorkspaceDirectoryError {
@@ -4033,6 +4498,32 @@ impl fmt::Display for RegisterWorkspaceDirectoryError {
     }
 }
 impl Error for RegisterWorkspaceDirectoryError {}
+impl ServiceErrorCode for RegisterWorkspaceDirectoryError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RegisterWorkspaceDirectoryError::AccessDenied(_) => "AccessDeniedException",
+            RegisterWorkspaceDirectoryError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            RegisterWorkspaceDirectoryError::InvalidResourceState(_) => {
+                "InvalidResourceStateException"
+            }
+            RegisterWorkspaceDirectoryError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            RegisterWorkspaceDirectoryError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            RegisterWorkspaceDirectoryError::ResourceNotFound(_) => "ResourceNotFoundException",
+            RegisterWorkspaceDirectoryError::UnsupportedNetworkConfiguration(_) => {
+                "UnsupportedNetworkConfigurationException"
+            }
+            RegisterWorkspaceDirectoryError::WorkspacesDefaultRoleNotFound(_) => {
+                "WorkspacesDefaultRoleNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by RestoreWorkspace
 #[derive(Debug, PartialEq)]
 pub enum RestoreWorkspaceError {
@@ -4077,6 +4568,15 @@ impl fmt::Display for RestoreWorkspaceError {
     }
 }
 impl Error for RestoreWorkspaceError {}
+impl ServiceErrorCode for RestoreWorkspaceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RestoreWorkspaceError::AccessDenied(_) => "AccessDeniedException",
+            RestoreWorkspaceError::InvalidParameterValues(_) => "InvalidParameterValuesException",
+            RestoreWorkspaceError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by RevokeIpRules
 #[derive(Debug, PartialEq)]
 pub enum RevokeIpRulesError {
@@ -4127,6 +4627,16 @@ impl fmt::Display for RevokeIpRulesError {
     }
 }
 impl Error for RevokeIpRulesError {}
+impl ServiceErrorCode for RevokeIpRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            RevokeIpRulesError::AccessDenied(_) => "AccessDeniedException",
+            RevokeIpRulesError::InvalidParameterValues(_) => "InvalidParameterValuesException",
+            RevokeIpRulesError::InvalidResourceState(_) => "InvalidResourceStateException",
+            RevokeIpRulesError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by StartWorkspaces
 #[derive(Debug, PartialEq)]
 pub enum StartWorkspacesError {}
@@ -4149,6 +4659,11 @@ impl fmt::Display for StartWorkspacesError {
     }
 }
 impl Error for StartWorkspacesError {}
+impl ServiceErrorCode for StartWorkspacesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by StopWorkspaces
 #[derive(Debug, PartialEq)]
 pub enum StopWorkspacesError {}
@@ -4171,6 +4686,11 @@ impl fmt::Display for StopWorkspacesError {
     }
 }
 impl Error for StopWorkspacesError {}
+impl ServiceErrorCode for StopWorkspacesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by TerminateWorkspaces
 #[derive(Debug, PartialEq)]
 pub enum TerminateWorkspacesError {}
@@ -4193,6 +4713,11 @@ impl fmt::Display for TerminateWorkspacesError {
     }
 }
 impl Error for TerminateWorkspacesError {}
+impl ServiceErrorCode for TerminateWorkspacesError {
+    fn error_code(&self) -> &str {
+        match *self {}
+    }
+}
 /// Errors returned by UpdateConnectionAliasPermission
 #[derive(Debug, PartialEq)]
 pub enum UpdateConnectionAliasPermissionError {
@@ -4287,6 +4812,31 @@ impl fmt::Display for UpdateConnectionAliasPermissionError {
     }
 }
 impl Error for UpdateConnectionAliasPermissionError {}
+impl ServiceErrorCode for UpdateConnectionAliasPermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateConnectionAliasPermissionError::AccessDenied(_) => "AccessDeniedException",
+            UpdateConnectionAliasPermissionError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            UpdateConnectionAliasPermissionError::InvalidResourceState(_) => {
+                "InvalidResourceStateException"
+            }
+            UpdateConnectionAliasPermissionError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            UpdateConnectionAliasPermissionError::ResourceAssociated(_) => {
+                "ResourceAssociatedException"
+            }
+            UpdateConnectionAliasPermissionError::ResourceLimitExceeded(_) => {
+                "ResourceLimitExceededException"
+            }
+            UpdateConnectionAliasPermissionError::ResourceNotFound(_) => {
+                "ResourceNotFoundException"
+            }
+        }
+    }
+}
 /// Errors returned by UpdateRulesOfIpGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateRulesOfIpGroupError {
@@ -4349,6 +4899,19 @@ impl fmt::Display for UpdateRulesOfIpGroupError {
     }
 }
 impl Error for UpdateRulesOfIpGroupError {}
+impl ServiceErrorCode for UpdateRulesOfIpGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateRulesOfIpGroupError::AccessDenied(_) => "AccessDeniedException",
+            UpdateRulesOfIpGroupError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            UpdateRulesOfIpGroupError::InvalidResourceState(_) => "InvalidResourceStateException",
+            UpdateRulesOfIpGroupError::ResourceLimitExceeded(_) => "ResourceLimitExceededException",
+            UpdateRulesOfIpGroupError::ResourceNotFound(_) => "ResourceNotFoundException",
+        }
+    }
+}
 /// Errors returned by UpdateWorkspaceImagePermission
 #[derive(Debug, PartialEq)]
 pub enum UpdateWorkspaceImagePermissionError {
@@ -4423,6 +4986,23 @@ impl fmt::Display for UpdateWorkspaceImagePermissionError {
     }
 }
 impl Error for UpdateWorkspaceImagePermissionError {}
+impl ServiceErrorCode for UpdateWorkspaceImagePermissionError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateWorkspaceImagePermissionError::AccessDenied(_) => "AccessDeniedException",
+            UpdateWorkspaceImagePermissionError::InvalidParameterValues(_) => {
+                "InvalidParameterValuesException"
+            }
+            UpdateWorkspaceImagePermissionError::OperationNotSupported(_) => {
+                "OperationNotSupportedException"
+            }
+            UpdateWorkspaceImagePermissionError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UpdateWorkspaceImagePermissionError::ResourceUnavailable(_) => {
+                "ResourceUnavailableException"
+            }
+        }
+    }
+}
 /// Trait representing the capabilities of the Amazon WorkSpaces API. Amazon WorkSpaces clients implement this trait.
 #[async_trait]
 pub trait Workspaces {
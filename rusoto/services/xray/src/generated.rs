@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{Client, RusotoError, ServiceErrorCode};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -2068,6 +2068,14 @@ impl fmt::Display for BatchGetTracesError {
     }
 }
 impl Error for BatchGetTracesError {}
+impl ServiceErrorCode for BatchGetTracesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            BatchGetTracesError::InvalidRequest(_) => "InvalidRequestException",
+            BatchGetTracesError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by CreateGroup
 #[derive(Debug, PartialEq)]
 pub enum CreateGroupError {
@@ -2104,6 +2112,14 @@ impl fmt::Display for CreateGroupError {
     }
 }
 impl Error for CreateGroupError {}
+impl ServiceErrorCode for CreateGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateGroupError::InvalidRequest(_) => "InvalidRequestException",
+            CreateGroupError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by CreateSamplingRule
 #[derive(Debug, PartialEq)]
 pub enum CreateSamplingRuleError {
@@ -2148,6 +2164,15 @@ impl fmt::Display for CreateSamplingRuleError {
     }
 }
 impl Error for CreateSamplingRuleError {}
+impl ServiceErrorCode for CreateSamplingRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            CreateSamplingRuleError::InvalidRequest(_) => "InvalidRequestException",
+            CreateSamplingRuleError::RuleLimitExceeded(_) => "RuleLimitExceededException",
+            CreateSamplingRuleError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by DeleteGroup
 #[derive(Debug, PartialEq)]
 pub enum DeleteGroupError {
@@ -2184,6 +2209,14 @@ impl fmt::Display for DeleteGroupError {
     }
 }
 impl Error for DeleteGroupError {}
+impl ServiceErrorCode for DeleteGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteGroupError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteGroupError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by DeleteSamplingRule
 #[derive(Debug, PartialEq)]
 pub enum DeleteSamplingRuleError {
@@ -2220,6 +2253,14 @@ impl fmt::Display for DeleteSamplingRuleError {
     }
 }
 impl Error for DeleteSamplingRuleError {}
+impl ServiceErrorCode for DeleteSamplingRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            DeleteSamplingRuleError::InvalidRequest(_) => "InvalidRequestException",
+            DeleteSamplingRuleError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetEncryptionConfig
 #[derive(Debug, PartialEq)]
 pub enum GetEncryptionConfigError {
@@ -2256,6 +2297,14 @@ impl fmt::Display for GetEncryptionConfigError {
     }
 }
 impl Error for GetEncryptionConfigError {}
+impl ServiceErrorCode for GetEncryptionConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetEncryptionConfigError::InvalidRequest(_) => "InvalidRequestException",
+            GetEncryptionConfigError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetGroup
 #[derive(Debug, PartialEq)]
 pub enum GetGroupError {
@@ -2292,6 +2341,14 @@ impl fmt::Display for GetGroupError {
     }
 }
 impl Error for GetGroupError {}
+impl ServiceErrorCode for GetGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGroupError::InvalidRequest(_) => "InvalidRequestException",
+            GetGroupError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetGroups
 #[derive(Debug, PartialEq)]
 pub enum GetGroupsError {
@@ -2328,6 +2385,14 @@ impl fmt::Display for GetGroupsError {
     }
 }
 impl Error for GetGroupsError {}
+impl ServiceErrorCode for GetGroupsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetGroupsError::InvalidRequest(_) => "InvalidRequestException",
+            GetGroupsError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetInsight
 #[derive(Debug, PartialEq)]
 pub enum GetInsightError {
@@ -2364,6 +2429,14 @@ impl fmt::Display for GetInsightError {
     }
 }
 impl Error for GetInsightError {}
+impl ServiceErrorCode for GetInsightError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInsightError::InvalidRequest(_) => "InvalidRequestException",
+            GetInsightError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetInsightEvents
 #[derive(Debug, PartialEq)]
 pub enum GetInsightEventsError {
@@ -2400,6 +2473,14 @@ impl fmt::Display for GetInsightEventsError {
     }
 }
 impl Error for GetInsightEventsError {}
+impl ServiceErrorCode for GetInsightEventsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInsightEventsError::InvalidRequest(_) => "InvalidRequestException",
+            GetInsightEventsError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetInsightImpactGraph
 #[derive(Debug, PartialEq)]
 pub enum GetInsightImpactGraphError {
@@ -2438,6 +2519,14 @@ impl fmt::Display for GetInsightImpactGraphError {
     }
 }
 impl Error for GetInsightImpactGraphError {}
+impl ServiceErrorCode for GetInsightImpactGraphError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInsightImpactGraphError::InvalidRequest(_) => "InvalidRequestException",
+            GetInsightImpactGraphError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetInsightSummaries
 #[derive(Debug, PartialEq)]
 pub enum GetInsightSummariesError {
@@ -2474,6 +2563,14 @@ impl fmt::Display for GetInsightSummariesError {
     }
 }
 impl Error for GetInsightSummariesError {}
+impl ServiceErrorCode for GetInsightSummariesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetInsightSummariesError::InvalidRequest(_) => "InvalidRequestException",
+            GetInsightSummariesError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetSamplingRules
 #[derive(Debug, PartialEq)]
 pub enum GetSamplingRulesError {
@@ -2510,6 +2607,14 @@ impl fmt::Display for GetSamplingRulesError {
     }
 }
 impl Error for GetSamplingRulesError {}
+impl ServiceErrorCode for GetSamplingRulesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSamplingRulesError::InvalidRequest(_) => "InvalidRequestException",
+            GetSamplingRulesError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetSamplingStatisticSummaries
 #[derive(Debug, PartialEq)]
 pub enum GetSamplingStatisticSummariesError {
@@ -2552,6 +2657,14 @@ impl fmt::Display for GetSamplingStatisticSummariesError {
     }
 }
 impl Error for GetSamplingStatisticSummariesError {}
+impl ServiceErrorCode for GetSamplingStatisticSummariesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSamplingStatisticSummariesError::InvalidRequest(_) => "InvalidRequestException",
+            GetSamplingStatisticSummariesError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetSamplingTargets
 #[derive(Debug, PartialEq)]
 pub enum GetSamplingTargetsError {
@@ -2588,6 +2701,14 @@ impl fmt::Display for GetSamplingTargetsError {
     }
 }
 impl Error for GetSamplingTargetsError {}
+impl ServiceErrorCode for GetSamplingTargetsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetSamplingTargetsError::InvalidRequest(_) => "InvalidRequestException",
+            GetSamplingTargetsError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetServiceGraph
 #[derive(Debug, PartialEq)]
 pub enum GetServiceGraphError {
@@ -2624,6 +2745,14 @@ impl fmt::Display for GetServiceGraphError {
     }
 }
 impl Error for GetServiceGraphError {}
+impl ServiceErrorCode for GetServiceGraphError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetServiceGraphError::InvalidRequest(_) => "InvalidRequestException",
+            GetServiceGraphError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetTimeSeriesServiceStatistics
 #[derive(Debug, PartialEq)]
 pub enum GetTimeSeriesServiceStatisticsError {
@@ -2668,6 +2797,14 @@ impl fmt::Display for GetTimeSeriesServiceStatisticsError {
     }
 }
 impl Error for GetTimeSeriesServiceStatisticsError {}
+impl ServiceErrorCode for GetTimeSeriesServiceStatisticsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTimeSeriesServiceStatisticsError::InvalidRequest(_) => "InvalidRequestException",
+            GetTimeSeriesServiceStatisticsError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetTraceGraph
 #[derive(Debug, PartialEq)]
 pub enum GetTraceGraphError {
@@ -2704,6 +2841,14 @@ impl fmt::Display for GetTraceGraphError {
     }
 }
 impl Error for GetTraceGraphError {}
+impl ServiceErrorCode for GetTraceGraphError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTraceGraphError::InvalidRequest(_) => "InvalidRequestException",
+            GetTraceGraphError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by GetTraceSummaries
 #[derive(Debug, PartialEq)]
 pub enum GetTraceSummariesError {
@@ -2740,6 +2885,14 @@ impl fmt::Display for GetTraceSummariesError {
     }
 }
 impl Error for GetTraceSummariesError {}
+impl ServiceErrorCode for GetTraceSummariesError {
+    fn error_code(&self) -> &str {
+        match *self {
+            GetTraceSummariesError::InvalidRequest(_) => "InvalidRequestException",
+            GetTraceSummariesError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
 pub enum ListTagsForResourceError {
@@ -2784,6 +2937,15 @@ impl fmt::Display for ListTagsForResourceError {
     }
 }
 impl Error for ListTagsForResourceError {}
+impl ServiceErrorCode for ListTagsForResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            ListTagsForResourceError::InvalidRequest(_) => "InvalidRequestException",
+            ListTagsForResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            ListTagsForResourceError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by PutEncryptionConfig
 #[derive(Debug, PartialEq)]
 pub enum PutEncryptionConfigError {
@@ -2820,6 +2982,14 @@ impl fmt::Display for PutEncryptionConfigError {
     }
 }
 impl Error for PutEncryptionConfigError {}
+impl ServiceErrorCode for PutEncryptionConfigError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutEncryptionConfigError::InvalidRequest(_) => "InvalidRequestException",
+            PutEncryptionConfigError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by PutTelemetryRecords
 #[derive(Debug, PartialEq)]
 pub enum PutTelemetryRecordsError {
@@ -2856,6 +3026,14 @@ impl fmt::Display for PutTelemetryRecordsError {
     }
 }
 impl Error for PutTelemetryRecordsError {}
+impl ServiceErrorCode for PutTelemetryRecordsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutTelemetryRecordsError::InvalidRequest(_) => "InvalidRequestException",
+            PutTelemetryRecordsError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by PutTraceSegments
 #[derive(Debug, PartialEq)]
 pub enum PutTraceSegmentsError {
@@ -2892,6 +3070,14 @@ impl fmt::Display for PutTraceSegmentsError {
     }
 }
 impl Error for PutTraceSegmentsError {}
+impl ServiceErrorCode for PutTraceSegmentsError {
+    fn error_code(&self) -> &str {
+        match *self {
+            PutTraceSegmentsError::InvalidRequest(_) => "InvalidRequestException",
+            PutTraceSegmentsError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
 pub enum TagResourceError {
@@ -2940,6 +3126,16 @@ impl fmt::Display for TagResourceError {
     }
 }
 impl Error for TagResourceError {}
+impl ServiceErrorCode for TagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            TagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            TagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            TagResourceError::Throttled(_) => "ThrottledException",
+            TagResourceError::TooManyTags(_) => "TooManyTagsException",
+        }
+    }
+}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
 pub enum UntagResourceError {
@@ -2982,6 +3178,15 @@ impl fmt::Display for UntagResourceError {
     }
 }
 impl Error for UntagResourceError {}
+impl ServiceErrorCode for UntagResourceError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UntagResourceError::InvalidRequest(_) => "InvalidRequestException",
+            UntagResourceError::ResourceNotFound(_) => "ResourceNotFoundException",
+            UntagResourceError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by UpdateGroup
 #[derive(Debug, PartialEq)]
 pub enum UpdateGroupError {
@@ -3018,6 +3223,14 @@ impl fmt::Display for UpdateGroupError {
     }
 }
 impl Error for UpdateGroupError {}
+impl ServiceErrorCode for UpdateGroupError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateGroupError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateGroupError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Errors returned by UpdateSamplingRule
 #[derive(Debug, PartialEq)]
 pub enum UpdateSamplingRuleError {
@@ -3054,6 +3267,14 @@ impl fmt::Display for UpdateSamplingRuleError {
     }
 }
 impl Error for UpdateSamplingRuleError {}
+impl ServiceErrorCode for UpdateSamplingRuleError {
+    fn error_code(&self) -> &str {
+        match *self {
+            UpdateSamplingRuleError::InvalidRequest(_) => "InvalidRequestException",
+            UpdateSamplingRuleError::Throttled(_) => "ThrottledException",
+        }
+    }
+}
 /// Trait representing the capabilities of the AWS X-Ray API. AWS X-Ray clients implement this trait.
 #[async_trait]
 pub trait XRay {
@@ -0,0 +1,156 @@
+//! The hash and HMAC primitives used for signing, from OpenSSL with the `openssl` feature
+//! or from pure Rust crates with the `rust-crypto` feature, which wins if both are enabled.
+
+use std::io::Error;
+
+#[cfg(not(any(feature = "openssl", feature = "rust-crypto")))]
+compile_error!("rusoto_signature needs either the `openssl` or the `rust-crypto` feature");
+
+#[cfg(feature = "rust-crypto")]
+pub(crate) use self::rust_crypto::{hmac_sha256, md5, md5_and_sha256, sha256};
+
+#[cfg(all(feature = "openssl", not(feature = "rust-crypto")))]
+pub(crate) use self::openssl::{hmac_sha256, md5, md5_and_sha256, sha256};
+
+/// How much of the data `md5_and_sha256` feeds to one hash before the other, small enough
+/// for the chunk to still be in the CPU cache when the second hash reads it.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+#[cfg(feature = "openssl")]
+#[cfg_attr(feature = "rust-crypto", allow(dead_code))]
+mod openssl {
+    use super::{Error, CHUNK_SIZE};
+    use openssl::hash::{hash, Hasher, MessageDigest};
+    use openssl::pkey::PKey;
+    use openssl::sign::Signer;
+
+    pub(crate) fn sha256(data: &[u8]) -> Result<[u8; 32], Error> {
+        let mut digest = [0; 32];
+        digest.copy_from_slice(&hash(MessageDigest::sha256(), data)?);
+        Ok(digest)
+    }
+
+    pub(crate) fn md5(data: &[u8]) -> Result<[u8; 16], Error> {
+        let mut digest = [0; 16];
+        digest.copy_from_slice(&hash(MessageDigest::md5(), data)?);
+        Ok(digest)
+    }
+
+    pub(crate) fn md5_and_sha256(data: &[u8]) -> Result<([u8; 16], [u8; 32]), Error> {
+        let mut md5 = Hasher::new(MessageDigest::md5())?;
+        let mut sha256 = Hasher::new(MessageDigest::sha256())?;
+        for chunk in data.chunks(CHUNK_SIZE) {
+            md5.update(chunk)?;
+            sha256.update(chunk)?;
+        }
+        let mut digests = ([0; 16], [0; 32]);
+        digests.0.copy_from_slice(&md5.finish()?);
+        digests.1.copy_from_slice(&sha256.finish()?);
+        Ok(digests)
+    }
+
+    pub(crate) fn hmac_sha256(key: &[u8], message: &[u8]) -> Result<[u8; 32], Error> {
+        let key = PKey::hmac(key)?;
+        let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+        signer.update(message)?;
+        let mut mac = [0; 32];
+        mac.copy_from_slice(&signer.sign_to_vec()?);
+        Ok(mac)
+    }
+}
+
+#[cfg(feature = "rust-crypto")]
+mod rust_crypto {
+    use super::{Error, CHUNK_SIZE};
+    use hmac::{Hmac, Mac, NewMac};
+    use md5::Md5;
+    use sha2::{Digest, Sha256};
+    use std::io::ErrorKind;
+
+    pub(crate) fn sha256(data: &[u8]) -> Result<[u8; 32], Error> {
+        Ok(Sha256::digest(data).into())
+    }
+
+    pub(crate) fn md5(data: &[u8]) -> Result<[u8; 16], Error> {
+        Ok(Md5::digest(data).into())
+    }
+
+    pub(crate) fn md5_and_sha256(data: &[u8]) -> Result<([u8; 16], [u8; 32]), Error> {
+        let mut md5 = Md5::new();
+        let mut sha256 = Sha256::new();
+        for chunk in data.chunks(CHUNK_SIZE) {
+            Digest::update(&mut md5, chunk);
+            Digest::update(&mut sha256, chunk);
+        }
+        Ok((md5.finalize().into(), sha256.finalize().into()))
+    }
+
+    pub(crate) fn hmac_sha256(key: &[u8], message: &[u8]) -> Result<[u8; 32], Error> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key)
+            .map_err(|err| Error::new(ErrorKind::InvalidInput, err.to_string()))?;
+        mac.update(message);
+        Ok(mac.finalize().into_bytes().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // RFC 4231, test case 2
+    const HMAC_KEY: &[u8] = b"Jefe";
+    const HMAC_MESSAGE: &[u8] = b"what do ya want for nothing?";
+    const HMAC_SHA256: &str = "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843";
+
+    #[test]
+    fn known_vectors() {
+        assert_eq!(
+            hex::encode(sha256(b"").unwrap()),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex::encode(md5(b"hello").unwrap()),
+            "5d41402abc4b2a76b9719d911017c592"
+        );
+        assert_eq!(
+            hex::encode(hmac_sha256(HMAC_KEY, HMAC_MESSAGE).unwrap()),
+            HMAC_SHA256
+        );
+    }
+
+    #[test]
+    fn md5_and_sha256_match_separate_digests() {
+        for len in &[0, 1, CHUNK_SIZE, CHUNK_SIZE * 2 + 7] {
+            let data: Vec<u8> = (0..*len).map(|i| (i % 251) as u8).collect();
+            assert_eq!(
+                md5_and_sha256(&data).unwrap(),
+                (md5(&data).unwrap(), sha256(&data).unwrap())
+            );
+        }
+    }
+
+    #[cfg(all(feature = "openssl", feature = "rust-crypto"))]
+    #[test]
+    fn backends_agree() {
+        let data = b"The quick brown fox jumps over the lazy dog";
+        assert_eq!(
+            openssl::sha256(data).unwrap(),
+            rust_crypto::sha256(data).unwrap()
+        );
+        assert_eq!(openssl::md5(data).unwrap(), rust_crypto::md5(data).unwrap());
+        assert_eq!(
+            openssl::md5_and_sha256(data).unwrap(),
+            rust_crypto::md5_and_sha256(data).unwrap()
+        );
+        assert_eq!(
+            openssl::hmac_sha256(HMAC_KEY, data).unwrap(),
+            rust_crypto::hmac_sha256(HMAC_KEY, data).unwrap()
+        );
+        // Keys longer than a block are hashed first.
+        let long_key = [0xaa; 131];
+        assert_eq!(
+            openssl::hmac_sha256(&long_key, data).unwrap(),
+            rust_crypto::hmac_sha256(&long_key, data).unwrap()
+        );
+    }
+}
@@ -4,13 +4,14 @@
 //!
 //! For example: `UsEast1` to "us-east-1"
 
-use crate::credential::ProfileProvider;
+use crate::credential::{InstanceMetadataProvider, ProfileProvider};
 use serde::ser::SerializeTuple;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std;
 use std::error::Error;
 use std::fmt::{self, Display, Error as FmtError, Formatter};
 use std::str::FromStr;
+use std::time::Duration;
 
 /// An AWS region.
 ///
@@ -127,7 +128,128 @@ pub enum Region {
     },
 }
 
+// Every region except `Custom`, in declaration order.
+static VARIANTS: [Region; 26] = [
+    Region::ApEast1,
+    Region::ApNortheast1,
+    Region::ApNortheast2,
+    Region::ApNortheast3,
+    Region::ApSouth1,
+    Region::ApSoutheast1,
+    Region::ApSoutheast2,
+    Region::ApSoutheast3,
+    Region::CaCentral1,
+    Region::EuCentral1,
+    Region::EuWest1,
+    Region::EuWest2,
+    Region::EuWest3,
+    Region::EuNorth1,
+    Region::EuSouth1,
+    Region::MeSouth1,
+    Region::SaEast1,
+    Region::UsEast1,
+    Region::UsEast2,
+    Region::UsWest1,
+    Region::UsWest2,
+    Region::UsGovEast1,
+    Region::UsGovWest1,
+    Region::CnNorth1,
+    Region::CnNorthwest1,
+    Region::AfSouth1,
+];
+
 impl Region {
+    /// The region `code` of `partition`, e.g. from configuration that names both.
+    ///
+    /// Fails if `code` isn't a known region, or is one of another partition.
+    ///
+    /// ```
+    ///     # use rusoto_signature::region::{Partition, Region};
+    ///     assert_eq!(
+    ///         Region::new(Partition::AwsUsGov, "us-gov-west-1"),
+    ///         Ok(Region::UsGovWest1)
+    ///     );
+    ///     assert!(Region::new(Partition::Aws, "cn-north-1").is_err());
+    /// ```
+    pub fn new(partition: Partition, code: &str) -> Result<Region, ParseRegionError> {
+        let region: Region = code.parse()?;
+        if region.partition() != Some(partition) {
+            return Err(ParseRegionError {
+                message: format!(
+                    "AWS region {} is not in the {} partition",
+                    region.name(),
+                    partition
+                ),
+            });
+        }
+        Ok(region)
+    }
+
+    /// Every known region, i.e. all variants except `Region::Custom`.
+    ///
+    /// ```
+    ///     # use rusoto_signature::Region;
+    ///     assert!(Region::variants().contains(&Region::EuWest1));
+    /// ```
+    pub fn variants() -> &'static [Region] {
+        &VARIANTS
+    }
+
+    /// Iterates over every known region, i.e. all variants except `Region::Custom`.
+    pub fn iter() -> impl Iterator<Item = &'static Region> {
+        VARIANTS.iter()
+    }
+
+    /// Resolves the region from the environment, in the same order as the official AWS SDKs:
+    ///
+    /// 1. the `AWS_REGION` environment variable,
+    /// 2. the `AWS_DEFAULT_REGION` environment variable,
+    /// 3. the `region` of the current profile in `~/.aws/config`, or in the file specified by
+    ///    the `AWS_CONFIG_FILE` environment variable.
+    ///
+    /// Empty values are skipped. Unlike `Region::default()`, this returns an error instead of
+    /// falling back to `Region::UsEast1` if no region is set or the first one found is invalid.
+    pub fn from_env() -> Result<Region, ParseRegionError> {
+        let from_env_var = ["AWS_REGION", "AWS_DEFAULT_REGION"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty());
+        match from_env_var {
+            Some(region) => region.parse(),
+            None => match ProfileProvider::region() {
+                Ok(Some(region)) => region.parse(),
+                _ => Err(ParseRegionError {
+                    message:
+                        "No region found in AWS_REGION, AWS_DEFAULT_REGION or the AWS config file"
+                            .to_owned(),
+                }),
+            },
+        }
+    }
+
+    /// Resolves the region like `from_env`, falling back to the region of the EC2 instance
+    /// from the instance metadata service, as the official AWS SDKs do.
+    ///
+    /// The instance metadata service is given a second to answer, so this fails quickly
+    /// when not running on EC2.
+    pub async fn from_env_or_instance_metadata() -> Result<Region, ParseRegionError> {
+        if let Ok(region) = Region::from_env() {
+            return Ok(region);
+        }
+        let mut provider = InstanceMetadataProvider::new();
+        provider.set_timeout(Duration::from_secs(1));
+        match provider.region().await {
+            Ok(region) => region.parse(),
+            Err(err) => Err(ParseRegionError {
+                message: format!(
+                    "No region found in AWS_REGION, AWS_DEFAULT_REGION, the AWS config file \
+                     or instance metadata: {}",
+                    err
+                ),
+            }),
+        }
+    }
+
     /// Name of the region
     ///
     /// ```
@@ -169,6 +291,65 @@ impl Region {
             Region::Custom { ref name, .. } => name,
         }
     }
+
+    /// The partition the region is in, or `None` for `Region::Custom`.
+    ///
+    /// ```
+    ///     # use rusoto_signature::region::{Partition, Region};
+    ///     assert_eq!(Region::CnNorthwest1.partition(), Some(Partition::AwsCn));
+    /// ```
+    pub fn partition(&self) -> Option<Partition> {
+        match *self {
+            Region::UsGovEast1 | Region::UsGovWest1 => Some(Partition::AwsUsGov),
+            Region::CnNorth1 | Region::CnNorthwest1 => Some(Partition::AwsCn),
+            Region::Custom { .. } => None,
+            _ => Some(Partition::Aws),
+        }
+    }
+}
+
+/// A partition of AWS, i.e. a group of regions that are isolated from the others, with
+/// their own accounts and credentials.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Partition {
+    /// The commercial regions, `aws`
+    Aws,
+    /// The regions in China, `aws-cn`
+    AwsCn,
+    /// The AWS GovCloud (US) regions, `aws-us-gov`
+    AwsUsGov,
+}
+
+impl Partition {
+    /// Name of the partition, as used in ARNs, e.g. `aws-us-gov`
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Partition::Aws => "aws",
+            Partition::AwsCn => "aws-cn",
+            Partition::AwsUsGov => "aws-us-gov",
+        }
+    }
+}
+
+impl Display for Partition {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Partition {
+    type Err = ParseRegionError;
+
+    fn from_str(s: &str) -> Result<Partition, ParseRegionError> {
+        match s {
+            "aws" => Ok(Partition::Aws),
+            "aws-cn" => Ok(Partition::AwsCn),
+            "aws-us-gov" => Ok(Partition::AwsUsGov),
+            s => Err(ParseRegionError {
+                message: format!("Not a valid AWS partition: {}", s),
+            }),
+        }
+    }
 }
 
 /// An error produced when attempting to convert a `str` into a `Region` fails.
@@ -345,6 +526,92 @@ mod tests {
         assert_eq!("af-south-1".parse(), Ok(Region::AfSouth1));
     }
 
+    #[test]
+    fn new_checks_the_partition() {
+        assert_eq!(
+            Region::new(Partition::Aws, "eu-west-1"),
+            Ok(Region::EuWest1)
+        );
+        assert_eq!(
+            Region::new(Partition::AwsCn, "cn-north-1"),
+            Ok(Region::CnNorth1)
+        );
+        assert_eq!(
+            Region::new("aws-us-gov".parse().unwrap(), "us-gov-east-1"),
+            Ok(Region::UsGovEast1)
+        );
+        assert_eq!(
+            Region::new(Partition::Aws, "us-gov-west-1")
+                .unwrap_err()
+                .to_string(),
+            "AWS region us-gov-west-1 is not in the aws partition"
+        );
+        assert!(Region::new(Partition::AwsUsGov, "us-east-1").is_err());
+        assert!(Region::new(Partition::AwsCn, "ap-east-1").is_err());
+        assert_eq!(
+            Region::new(Partition::Aws, "moon-1")
+                .unwrap_err()
+                .to_string(),
+            "Not a valid AWS region: moon-1"
+        );
+        assert_eq!(
+            "aws-iso".parse::<Partition>().unwrap_err().to_string(),
+            "Not a valid AWS partition: aws-iso"
+        );
+
+        for region in Region::iter() {
+            let partition = region.partition().unwrap();
+            assert_eq!(partition.to_string().parse(), Ok(partition));
+            assert_eq!(Region::new(partition, region.name()).as_ref(), Ok(region));
+        }
+        let custom = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: "localhost:9000".to_owned(),
+        };
+        assert_eq!(custom.partition(), None);
+    }
+
+    #[test]
+    fn variants() {
+        assert_eq!(Region::variants().len(), Region::iter().count());
+        for region in Region::iter() {
+            assert_eq!(region.name().parse::<Region>().as_ref(), Ok(region));
+        }
+        assert!(!Region::iter().any(|region| matches!(region, Region::Custom { .. })));
+    }
+
+    #[test]
+    fn from_env() {
+        std::env::set_var(
+            "AWS_CONFIG_FILE",
+            "../credential/tests/sample-data/default_config",
+        );
+        std::env::set_var("AWS_REGION", "eu-west-1");
+        std::env::set_var("AWS_DEFAULT_REGION", "us-west-2");
+        assert_eq!(Region::from_env(), Ok(Region::EuWest1));
+
+        std::env::set_var("AWS_REGION", "");
+        assert_eq!(Region::from_env(), Ok(Region::UsWest2));
+
+        std::env::remove_var("AWS_REGION");
+        std::env::remove_var("AWS_DEFAULT_REGION");
+        assert_eq!(Region::from_env(), Ok(Region::UsEast2));
+
+        std::env::set_var("AWS_REGION", "foo");
+        assert_eq!(Region::from_env(), Err(ParseRegionError::new("foo")));
+
+        // instance metadata is only asked if the environment has no valid region
+        std::env::set_var("AWS_REGION", "ap-south-1");
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        assert_eq!(
+            runtime.block_on(Region::from_env_or_instance_metadata()),
+            Ok(Region::ApSouth1)
+        );
+
+        std::env::remove_var("AWS_REGION");
+        std::env::remove_var("AWS_CONFIG_FILE");
+    }
+
     #[test]
     fn region_serialize_deserialize() {
         assert_tokens(&Region::ApEast1, &tokens_for_region("ap-east-1"));
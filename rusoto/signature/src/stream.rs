@@ -4,7 +4,7 @@ use std::pin::Pin;
 use std::task::{Context, Poll};
 
 use bytes::{BufMut, Bytes, BytesMut};
-use futures::{future, stream, Stream, StreamExt};
+use futures::{future, stream, Stream, StreamExt, TryStreamExt};
 use pin_project_lite::pin_project;
 use tokio::io::{AsyncRead, ReadBuf};
 
@@ -41,10 +41,46 @@ impl ByteStream {
         }
     }
 
-    pub(crate) fn size_hint(&self) -> Option<usize> {
+    /// The size of the stream, if it was created with `new_with_size` or from a buffer.
+    pub fn size_hint(&self) -> Option<usize> {
         self.size_hint
     }
 
+    /// Calls `callback` with the total number of bytes that have flowed through the stream
+    /// each time a chunk is read, e.g. to report the progress of an upload or download.
+    ///
+    /// Chunks are passed through as they are read, so nothing is buffered and a slow
+    /// consumer still slows down the producer. The size hint is kept.
+    pub fn inspect_progress<F>(self, mut callback: F) -> ByteStream
+    where
+        F: FnMut(u64) + Send + 'static,
+    {
+        let mut transferred = 0u64;
+        ByteStream {
+            size_hint: self.size_hint,
+            inner: Box::pin(self.inner.inspect(move |chunk| {
+                if let Ok(bytes) = chunk {
+                    transferred += bytes.len() as u64;
+                    callback(transferred);
+                }
+            })),
+        }
+    }
+
+    /// Transforms each chunk with `f` as it is read, e.g. to encrypt or decrypt a body.
+    ///
+    /// The size hint is kept, so `f` must return chunks of the same length as it is
+    /// given, or the body no longer matches its `Content-Length`.
+    pub fn map_chunks<F>(self, f: F) -> ByteStream
+    where
+        F: FnMut(Bytes) -> Bytes + Send + 'static,
+    {
+        ByteStream {
+            size_hint: self.size_hint,
+            inner: Box::pin(self.inner.map_ok(f)),
+        }
+    }
+
     /// Return an implementation of `AsyncRead` that uses async i/o to consume the stream.
     pub fn into_async_read(self) -> impl AsyncRead + Send {
         ImplAsyncRead::new(self.inner)
@@ -224,3 +260,55 @@ async fn test_new_with_size_read() {
     assert_eq!(&buf[..1], b"8");
     assert_eq!(async_read.read(&mut buf).await.unwrap(), 0);
 }
+
+#[tokio::test]
+async fn test_inspect_progress() {
+    use bytes::Bytes;
+    use std::sync::{Arc, Mutex};
+
+    let chunks = vec![
+        Ok(Bytes::from_static(b"1234")),
+        Ok(Bytes::from_static(b"56")),
+        Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated")),
+        Ok(Bytes::from_static(b"789")),
+    ];
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let recorded = progress.clone();
+    let mut stream = ByteStream::new_with_size(stream::iter(chunks), 9)
+        .inspect_progress(move |transferred| recorded.lock().unwrap().push(transferred));
+
+    assert_eq!(stream.size_hint, Some(9));
+    assert!(progress.lock().unwrap().is_empty());
+    assert_eq!(
+        stream.next().await.unwrap().unwrap(),
+        Bytes::from_static(b"1234")
+    );
+    assert_eq!(*progress.lock().unwrap(), vec![4]);
+    while stream.next().await.is_some() {}
+    assert_eq!(*progress.lock().unwrap(), vec![4, 6, 9]);
+}
+
+#[tokio::test]
+async fn test_map_chunks() {
+    use bytes::Bytes;
+
+    let chunks = vec![
+        Ok(Bytes::from_static(b"abcd")),
+        Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated")),
+        Ok(Bytes::from_static(b"ef")),
+    ];
+    let mut stream = ByteStream::new_with_size(stream::iter(chunks), 6)
+        .map_chunks(|chunk| chunk.to_ascii_uppercase().into());
+
+    assert_eq!(stream.size_hint, Some(6));
+    assert_eq!(
+        stream.next().await.unwrap().unwrap(),
+        Bytes::from_static(b"ABCD")
+    );
+    assert!(stream.next().await.unwrap().is_err());
+    assert_eq!(
+        stream.next().await.unwrap().unwrap(),
+        Bytes::from_static(b"EF")
+    );
+    assert!(stream.next().await.is_none());
+}
@@ -129,6 +129,8 @@ pub struct Member {
     pub deprecated: Option<bool>,
     pub documentation: Option<String>,
     pub flattened: Option<bool>,
+    #[serde(rename = "idempotencyToken")]
+    pub idempotency_token: Option<bool>,
     pub location: Option<String>,
     #[serde(rename = "locationName")]
     pub location_name: Option<String>,
@@ -149,6 +151,10 @@ impl Member {
     pub fn streaming(&self) -> bool {
         self.streaming.unwrap_or(false)
     }
+
+    pub fn idempotency_token(&self) -> bool {
+        self.idempotency_token.unwrap_or(false)
+    }
 }
 
 #[derive(Debug, Deserialize)]
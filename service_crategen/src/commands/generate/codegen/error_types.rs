@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 use std::io::Write;
 
 use super::{error_type_name, FileWriter, IoResult};
-use crate::botocore::Operation;
+use crate::botocore::{Error, Operation};
 use crate::Service;
 
 /// Examines the error types described in the botocore definition for an operation
@@ -61,7 +61,14 @@ pub trait GenerateErrorTypes {
                         }}
                     }}
                 }}
-                impl Error for {type_name} {{}}",
+                impl Error for {type_name} {{}}
+                impl ServiceErrorCode for {type_name} {{
+                    fn error_code(&self) -> &str {{
+                        match *self {{
+                            {error_code_matchers}
+                        }}
+                    }}
+                }}",
             operation = operation_name,
             type_name = error_type_name(service, operation_name),
             error_from_body_impl =
@@ -71,10 +78,47 @@ pub trait GenerateErrorTypes {
                 .unwrap_or_else(|| String::from("")),
             display_matchers = self
                 .generate_error_display_matchers(operation_name, operation, service)
-                .unwrap_or_else(|| String::from(""))
+                .unwrap_or_else(|| String::from("")),
+            error_code_matchers =
+                self.generate_error_code_matchers(operation_name, operation, service)
         )
     }
 
+    /// generate the matcher arms for an error type's implementation of `ServiceErrorCode`,
+    /// which return the code each variant is parsed from
+    fn generate_error_code_matchers(
+        &self,
+        operation_name: &str,
+        operation: &Operation,
+        service: &Service<'_>,
+    ) -> String {
+        let mut code_matchers: Vec<String> = Vec::new();
+
+        if operation.errors.is_some() {
+            for error in operation.errors() {
+                // the same errors as in the enum, see generate_error_enum_types
+                if error.idiomatic_error_name() != "Validation"
+                    || service.service_id() == Some("CloudSearch")
+                {
+                    code_matchers.push(format!(
+                        "{error_type}::{error_name}(_) => \"{error_code}\"",
+                        error_type = error_type_name(service, operation_name),
+                        error_name = error.idiomatic_error_name(),
+                        error_code = self.error_code(service, error)
+                    ))
+                }
+            }
+        }
+
+        code_matchers.join(",\n")
+    }
+
+    /// the code AWS returns for an error, which is the name of its shape unless the
+    /// protocol says otherwise
+    fn error_code(&self, _service: &Service<'_>, error: &Error) -> String {
+        error.shape.clone()
+    }
+
     /// generate an enum of all possible errors output by this operation
     fn generate_error_enum_types(
         &self,
@@ -151,12 +195,40 @@ pub struct JsonErrorTypes;
 pub struct XmlErrorTypes;
 
 impl GenerateErrorTypes for XmlErrorTypes {
+    fn error_code(&self, service: &Service<'_>, error: &Error) -> String {
+        let shape = service.get_shape(&error.shape).unwrap();
+        shape
+            .error
+            .as_ref()
+            .and_then(|http_error| http_error.code.clone())
+            .unwrap_or_else(|| error.shape.clone())
+    }
+
     fn generate_error_from_body_impl(
         &self,
         operation_name: &str,
         operation: &Operation,
         service: &Service<'_>,
     ) -> String {
+        if service.service_id() == Some("S3") {
+            // https://docs.aws.amazon.com/AmazonS3/latest/API/ErrorResponses.html
+            // Most S3 errors are XML, but some newer surfaces (e.g. Object Lambda) return JSON.
+            return format!(
+                "
+                impl {type_name} {{
+                    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<{type_name}> {{
+                        if let Some(parsed_error) = parse_error_response(&res) {{
+                            match &parsed_error.code[..] {{
+                                {type_matchers}
+                            }}
+                        }}
+                        RusotoError::Unknown(res)
+                    }}
+                }}",
+                type_name = error_type_name(service, operation_name),
+                type_matchers = self.generate_error_type_matchers(operation_name, operation, service)
+            );
+        }
         format!("
                 impl {type_name} {{
                     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<{type_name}> {{
@@ -193,9 +265,6 @@ impl XmlErrorTypes {
             XmlErrorDeserializer::deserialize(\"Error\", stack)
             "
             .to_owned()
-        } else if service.service_id() == Some("S3") {
-            // https://docs.aws.amazon.com/AmazonS3/latest/API/ErrorResponses.html
-            "XmlErrorDeserializer::deserialize(\"Error\", stack)".to_owned()
         } else {
             "
             xml_util::start_element(\"ErrorResponse\", stack)?;
@@ -218,12 +287,7 @@ impl XmlErrorTypes {
 
         if operation.errors.is_some() {
             for error in operation.errors() {
-                let shape = service.get_shape(&error.shape).unwrap();
-                let error_code = shape
-                    .error
-                    .as_ref()
-                    .and_then(|http_error| http_error.code.as_ref())
-                    .unwrap_or(&error.shape);
+                let error_code = self.error_code(service, error);
                 type_matchers.push(format!("\"{error_code}\" => return RusotoError::Service({error_type}::{error_name}(parsed_error.message))",
                     error_code = error_code,
                     error_type = error_type,
@@ -2,7 +2,8 @@ use inflector::Inflector;
 use std::io::Write;
 
 use super::{
-    error_type_name, eventstream_field_name, get_rust_type, FileWriter, GenerateProtocol, IoResult,
+    error_type_name, eventstream_field_name, generate_idempotency_tokens, get_rust_type,
+    FileWriter, GenerateProtocol, IoResult,
 };
 use crate::botocore::{Operation, Shape};
 use crate::Service;
@@ -42,6 +43,7 @@ impl GenerateProtocol for JsonGenerator {
                 "
                 {documentation}
                 {method_signature} -> Result<{output_type}, RusotoError<{error_type}>> {{
+                    {idempotency_tokens}
                     let mut request = self.new_signed_request(\"{http_method}\", \"{request_uri}\");
                     request.add_header(\"x-amz-target\", \"{target_prefix}.{name}\");
                     {payload}
@@ -52,6 +54,7 @@ impl GenerateProtocol for JsonGenerator {
                 ",
                 documentation = generate_documentation(operation).unwrap_or_else(|| "".to_owned()),
                 method_signature = generate_method_signature(service, operation),
+                idempotency_tokens = generate_idempotency_tokens(service, operation),
                 payload = generate_payload(service, operation),
                 http_method = operation.http.method,
                 name = operation.name,
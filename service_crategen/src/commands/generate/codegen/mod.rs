@@ -11,7 +11,7 @@ use self::rest_json::RestJsonGenerator;
 use self::rest_xml::RestXmlGenerator;
 use self::tests::generate_tests;
 use self::type_filter::filter_types;
-use crate::botocore::{Member, Shape, ShapeType};
+use crate::botocore::{Member, Operation, Shape, ShapeType};
 use crate::util;
 use crate::Service;
 
@@ -125,6 +125,51 @@ pub fn generate_field_name(member_name: &str) -> String {
     }
 }
 
+/// Fill in the idempotency tokens of the input that are left empty, if the client is set
+/// up to (see `Client::with_idempotency_tokens`). Retries resend the serialized request,
+/// so they reuse the tokens.
+pub fn generate_idempotency_tokens(service: &Service<'_>, operation: &Operation) -> String {
+    let shape = match operation
+        .input
+        .as_ref()
+        .and_then(|input| service.get_shape(&input.shape))
+    {
+        Some(shape) => shape,
+        None => return "".to_owned(),
+    };
+    let fill_tokens = shape
+        .members
+        .iter()
+        .flatten()
+        .filter(|(_, member)| member.idempotency_token() && !member.deprecated())
+        .map(|(member_name, _)| {
+            let name = generate_field_name(member_name);
+            if shape.required(member_name) {
+                format!(
+                    "if input.{name}.is_empty() {{
+                        if let Some(token) = self.client.idempotency_token() {{
+                            input.{name} = token;
+                        }}
+                    }}",
+                    name = name
+                )
+            } else {
+                format!(
+                    "if input.{name}.is_none() {{
+                        input.{name} = self.client.idempotency_token();
+                    }}",
+                    name = name
+                )
+            }
+        })
+        .collect::<Vec<String>>();
+    if fill_tokens.is_empty() {
+        "".to_owned()
+    } else {
+        format!("let mut input = input;\n{}", fill_tokens.join("\n"))
+    }
+}
+
 /// The quick brown fox jumps over the lazy dog
 fn generate<P, E>(
     writer: &mut FileWriter,
@@ -158,7 +203,7 @@ where
         use rusoto_core::request::{{BufferedHttpResponse, DispatchSignedRequest}};
         use rusoto_core::region;
         use rusoto_core::credential::ProvideAwsCredentials;
-        use rusoto_core::{{Client, RusotoError}};
+        use rusoto_core::{{Client, RusotoError, ServiceErrorCode}};
     "
     )?;
 
@@ -219,7 +264,7 @@ where
 fn generate_s3_client_struct_and_impl(service_name: &str, client_type_name: &str) -> String {
     format!(
         "
-        use crate::util::S3Config;
+        use crate::util::{S3Config, S3Endpoint};
 
         /// A client for the {service_name} API.
         #[derive(Clone)]
@@ -275,10 +320,23 @@ fn generate_s3_client_struct_and_impl(service_name: &str, client_type_name: &str
         
             pub fn build_s3_hostname<T>(&self, bucket: &str) -> Result<(bool, String), RusotoError<T>> {{
                 self.config
-                    .addressing_style
+                    .validate_bucket_name(bucket)
+                    .map_err(RusotoError::Validation)?;
+                self.config
                     .build_s3_hostname(&self.region, bucket)
                     .map_err(|e| RusotoError::InvalidDnsName(e))
             }}
+
+            /// Resolves where to send the given operation on `bucket`, with the
+            /// `endpoint_resolver` of the config if set.
+            pub fn resolve_s3_endpoint<T>(&self, operation: &str, bucket: &str) -> Result<S3Endpoint, RusotoError<T>> {{
+                self.config
+                    .validate_bucket_name(bucket)
+                    .map_err(RusotoError::Validation)?;
+                self.config
+                    .resolve_endpoint(&self.region, bucket, Some(operation))
+                    .map_err(RusotoError::InvalidDnsName)
+            }}
         }}
         ",
         service_name = service_name,
@@ -784,6 +842,13 @@ fn generate_struct_fields<P: GenerateProtocol>(
         if let Some(ref docs) = member.documentation {
             lines.push(crate::doco::Item(docs).to_string());
         }
+        if member.idempotency_token() {
+            lines.push(
+                "/// <p>Filled in with a random token if left empty and the client is set up to, see \
+                 <code>Client::with_idempotency_tokens</code>.</p>"
+                    .to_owned(),
+            );
+        }
 
         if serde_attrs {
             lines.push(format!("#[serde(rename=\"{}\")]", member_name));
@@ -7,7 +7,8 @@ use crate::Service;
 
 use super::xml_payload_parser;
 use super::{
-    error_type_name, generate_field_name, get_rust_type, FileWriter, GenerateProtocol, IoResult,
+    error_type_name, generate_field_name, generate_idempotency_tokens, get_rust_type, FileWriter,
+    GenerateProtocol, IoResult,
 };
 
 pub struct QueryGenerator;
@@ -38,6 +39,7 @@ impl GenerateProtocol for QueryGenerator {
                      "
                 {documentation}
                 {method_signature} {{
+                    {idempotency_tokens}
                     let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, \"{request_uri}\");
                     let params = self.new_params(\"{operation_name}\");
                     {serialize_input}
@@ -55,6 +57,7 @@ impl GenerateProtocol for QueryGenerator {
                      parse_payload =
                          xml_payload_parser::generate_response_parser(service, operation, false, "drop(response);"),
                      method_signature = generate_method_signature(operation_name, operation, service),
+                     idempotency_tokens = generate_idempotency_tokens(service, operation),
                      operation_name = &operation.name,
                      request_uri = &operation.http.request_uri,
                      serialize_input = generate_method_input_serialization(operation),
@@ -3,8 +3,8 @@ use std::io::Write;
 use inflector::Inflector;
 
 use super::{
-    error_type_name, generate_field_name, rest_request_generator, rest_response_parser, FileWriter,
-    GenerateProtocol, IoResult,
+    error_type_name, generate_field_name, generate_idempotency_tokens, rest_request_generator,
+    rest_response_parser, FileWriter, GenerateProtocol, IoResult,
 };
 use crate::botocore::{Operation, Shape, ShapeType};
 use crate::Service;
@@ -58,6 +58,7 @@ impl GenerateProtocol for RestJsonGenerator {
                 {documentation}
                 #[allow(unused_mut)]
                 {method_signature} -> Result<{output_type}, RusotoError<{error_type}>> {{
+                    {idempotency_tokens}
                     {request_uri_formatter}
 
                     let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, &request_uri);
@@ -83,6 +84,7 @@ impl GenerateProtocol for RestJsonGenerator {
                 ",
                 documentation = generate_documentation(operation).unwrap_or_else(|| "".to_owned()),
                 method_signature = generate_method_signature(operation, *input_shape),
+                idempotency_tokens = generate_idempotency_tokens(service, operation),
                 endpoint_prefix = service.signing_name(),
                 modify_endpoint_prefix = generate_endpoint_modification(service).unwrap_or_else(|| "".to_owned()),
                 http_method = operation.http.method,
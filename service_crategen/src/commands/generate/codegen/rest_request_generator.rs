@@ -143,13 +143,14 @@ pub fn generate_uri_formatter(
                 generate_uri_format_expression(&request_uri, &uri_strings, "");
 
             return Some(format!(
-                r#"let (is_virtual, hostname) = self.build_s3_hostname(&input.bucket)?;
+                r#"let endpoint = self.resolve_s3_endpoint("{operation_name}", &input.bucket)?;
 
-                let request_uri: std::borrow::Cow<'_, str> = if is_virtual {{
+                let request_uri: std::borrow::Cow<'_, str> = if endpoint.virtual_hosted {{
                     {short_uri_formatter}.into()
                 }} else {{
                     {uri_formatter}.into()
                 }};"#,
+                operation_name = operation.name,
                 short_uri_formatter = short_uri_formatter,
                 uri_formatter = uri_formatter,
             ));
@@ -358,7 +359,7 @@ pub fn generate_hostname_setter(service: &Service<'_>, operation: &Operation) ->
         && !operation.name.eq_ignore_ascii_case("ListBuckets")
         && !operation.name.eq_ignore_ascii_case("GetBucketLocation")
     {
-        "request.set_hostname(Some(hostname));"
+        "endpoint.apply_to(&mut request);"
     } else {
         ""
     }
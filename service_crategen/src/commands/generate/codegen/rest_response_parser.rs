@@ -84,15 +84,23 @@ fn parse_headers_map(member_name: &str, member: &Member, required: bool) -> Stri
         format!("result.{} = Some(values);", member_name.to_snake_case())
     };
 
+    // Header names are lowercase, and the values of a header sent more than once are
+    // joined with commas, as HTTP allows.
     format!(
         "let mut values = ::std::collections::HashMap::new();
     for (key, value) in response.headers.iter() {{
-        if key.as_str().starts_with(\"{location_name}\") {{
-            values.insert(key.as_str()[\"{location_name}\".len()..].to_owned(), value.to_owned());
+        if let Some(name) = key.as_str().strip_prefix(\"{prefix}\") {{
+            values
+                .entry(name.to_owned())
+                .and_modify(|values: &mut String| {{
+                    values.push(',');
+                    values.push_str(value);
+                }})
+                .or_insert_with(|| value.to_owned());
         }}
     }}
     {set_statement}",
-        location_name = member.location_name.as_ref().unwrap(),
+        prefix = member.location_name.as_ref().unwrap().to_ascii_lowercase(),
         set_statement = set_statement
     )
 }
@@ -1,7 +1,7 @@
 use inflector::Inflector;
 use std::io::Write;
 
-use super::{error_type_name, generate_field_name, GenerateProtocol};
+use super::{error_type_name, generate_field_name, generate_idempotency_tokens, GenerateProtocol};
 use super::{
     get_rust_type, mutate_type_name, rest_request_generator, rest_response_parser,
     xml_payload_parser,
@@ -42,10 +42,39 @@ impl GenerateProtocol for RestXmlGenerator {
             let (request_uri, _) = rest_gen::parse_query_string(&operation.http.request_uri);
             let parse_non_payload = rest_par::generate_response_headers_parser(service, operation)
                 .unwrap_or_else(|| "".to_owned());
+            let mut parse_response_body =
+                xml_par::generate_response_parser(service, operation, true, &parse_non_payload);
+
+            // S3 object bodies pass through the body transform of the client's config, and
+            // large uploads are split into parts if the config sets a threshold
+            let is_s3 = service.service_id() == Some("S3");
+            let transform_input = if is_s3 && operation_name == "PutObject" {
+                "let input = self.config.transform_upload(input);
+                let input = match self.config.multipart_threshold {
+                    Some(threshold) => match self.put_object_in_parts_if_large(input, threshold).await? {
+                        AutoMultipart::PutObject(input) => input,
+                        AutoMultipart::Uploaded(output) => return Ok(output),
+                    },
+                    None => input,
+                };"
+            } else {
+                ""
+            };
+            if is_s3 && operation_name == "GetObject" {
+                if let Some(index) = parse_response_body.rfind("Ok(result)") {
+                    parse_response_body.replace_range(
+                        index..index + "Ok(result)".len(),
+                        "Ok(self.config.transform_download(result))",
+                    );
+                }
+            }
+
             writeln!(writer,
                      "{documentation}
                     #[allow(unused_variables, warnings)]
                     {method_signature} {{
+                        {idempotency_tokens}
+                        {transform_input}
                         {modify_uri}
 
                         let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, &request_uri);
@@ -75,8 +104,9 @@ impl GenerateProtocol for RestXmlGenerator {
                          rest_gen::generate_params_loading_string(service, operation)
                              .unwrap_or_else(|| "".to_string()),
                      set_hostname = rest_gen::generate_hostname_setter(service, operation),
-                     parse_response_body =
-                         xml_par::generate_response_parser(service, operation, true, &parse_non_payload))?;
+                     idempotency_tokens = generate_idempotency_tokens(service, operation),
+                     transform_input = transform_input,
+                     parse_response_body = parse_response_body)?;
         }
         Ok(())
     }
@@ -88,12 +118,10 @@ impl GenerateProtocol for RestXmlGenerator {
             use rusoto_core::param::{Params, ServiceParams};
             use rusoto_core::signature::SignedRequest;
             use xml;
-            use xml::EventReader;
             use xml::EventWriter;
             use rusoto_core::request::HttpResponse;
-            use rusoto_core::proto::xml::error::*;
-            use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
-            use rusoto_core::proto::xml::util::{self as xml_util, find_start_element, skip_tree, deserialize_elements, write_characters_element};
+            use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError};
+            use rusoto_core::proto::xml::util::{self as xml_util, skip_tree, deserialize_elements, write_characters_element};
             #[cfg(feature = \"serialize_structs\")]
             use serde::Serialize;
             #[cfg(feature = \"deserialize_structs\")]
@@ -102,6 +130,26 @@ impl GenerateProtocol for RestXmlGenerator {
 
         writeln!(writer, "{}", imports)?;
 
+        // S3 errors are parsed by a shared helper that also understands JSON error bodies
+        if service.service_id() == Some("S3") {
+            writeln!(writer, "use crate::custom::error::parse_error_response;")?;
+            writeln!(writer, "use crate::custom::multipart::AutoMultipart;")?;
+        } else {
+            writeln!(writer, "use rusoto_core::proto::xml::error::*;")?;
+            writeln!(
+                writer,
+                "use rusoto_core::proto::xml::util::{{find_start_element, XmlResponse}};"
+            )?;
+            writeln!(writer, "use xml::EventReader;")?;
+        }
+
+        // S3 requests are checked before they're signed, e.g. for bodies without a length
+        let validate_request = if service.service_id() == Some("S3") {
+            "self.config.validate_request(&request).map_err(RusotoError::Validation)?;"
+        } else {
+            ""
+        };
+
         writeln!(
             writer,
             "
@@ -111,6 +159,7 @@ impl GenerateProtocol for RestXmlGenerator {
                     request: SignedRequest,
                     from_response: fn (BufferedHttpResponse) -> RusotoError<E>,
                 ) -> Result<HttpResponse, RusotoError<E>> {{
+                    {validate_request}
                     let mut response = self.client.sign_and_dispatch(request).await?;
                     if !response.status.is_success() {{
                         let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
@@ -121,6 +170,7 @@ impl GenerateProtocol for RestXmlGenerator {
                 }}
             }}",
             type_name = service.client_type_name(),
+            validate_request = validate_request,
         )?;
 
         if service.has_event_streams() {
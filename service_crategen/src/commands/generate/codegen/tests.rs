@@ -105,10 +105,23 @@ fn generate_response_parse_test(
     let request_params;
     let request_constructor;
     if operation.input.is_some() {
-        request_constructor = format!(
-            "let request = {request_type}::default();",
-            request_type = operation.input_shape()
-        );
+        // The S3 client checks bucket names before sending requests, so give it a valid one
+        let has_bucket = service.service_type_name() == "S3"
+            && service
+                .get_shape(operation.input_shape())
+                .and_then(|shape| shape.members.as_ref())
+                .map_or(false, |members| members.contains_key("Bucket"));
+        request_constructor = if has_bucket {
+            format!(
+                "let request = {request_type} {{ bucket: \"bucket\".to_owned(), ..Default::default() }};",
+                request_type = operation.input_shape()
+            )
+        } else {
+            format!(
+                "let request = {request_type}::default();",
+                request_type = operation.input_shape()
+            )
+        };
         request_params = "request".to_string();
     } else {
         request_constructor = "".to_string();
@@ -105,6 +105,10 @@ pub fn generate_services(
         features.insert("serialize_structs".into(), serialize_feature_dependencies.clone());
         features.insert("deserialize_structs".into(), serialize_feature_dependencies.clone());
 
+        if let Some(ref custom_features) = service_config.custom_features {
+            features.extend(custom_features.clone());
+        }
+
         let mut cargo_manifest = BufWriter::new(
             OpenOptions::new()
                 .write(true)